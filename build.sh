@@ -175,189 +175,227 @@ echo "Step 84. Checking all malachite-float targets with bin_build, 32_bit_limbs
 cargo check --all-targets --features bin_build --features 32_bit_limbs --features enable_serde --features random --no-default-features &&
 echo "Step 85. Checking all malachite-float targets with bin_build, serde, and random and no std" &&
 cargo check --all-targets --features bin_build --features enable_serde --features random --no-default-features &&
+cd ../malachite-complex &&
+echo "Step 86. Formatting malachite-complex" &&
+bash ../superfmt.sh &&
+echo "Step 87. Checking malachite-complex lib with 32_bit_limbs" &&
+cargo check --lib --features 32_bit_limbs &&
+echo "Step 88. Checking malachite-complex lib" &&
+cargo check --lib &&
+echo "Step 89. Checking malachite-complex lib with 32_bit_limbs and random" &&
+cargo check --lib --features 32_bit_limbs --features random &&
+echo "Step 90. Checking malachite-complex lib with random" &&
+cargo check --lib --features random &&
+echo "Step 91. Checking all malachite-complex targets with test_build and 32_bit_limbs" &&
+cargo check --all-targets --features test_build --features 32_bit_limbs &&
+echo "Step 92. Checking all malachite-complex targets with test_build" &&
+cargo check --all-targets --features test_build &&
+echo "Step 93. Checking malachite-complex lib with no std" &&
+cargo check --lib --no-default-features &&
+echo "Step 94. Checking all malachite-complex targets with test_build and no std" &&
+cargo check --all-targets --features test_build --no-default-features &&
 cd ../malachite-criterion-bench &&
-echo "Step 86. Formatting malachite-criterion-bench" &&
+echo "Step 95. Formatting malachite-criterion-bench" &&
 bash ../superfmt.sh &&
-echo "Step 87. Checking malachite-criterion-bench" &&
+echo "Step 96. Checking malachite-criterion-bench" &&
 cargo check &&
 cd ../malachite-bigint &&
-echo "Step 88. Formatting malachite-bigint" &&
+echo "Step 97. Formatting malachite-bigint" &&
 bash ../superfmt.sh &&
-echo "Step 89. Checking all malachite-bigint targets" &&
+echo "Step 98. Checking all malachite-bigint targets" &&
 cargo check --all-targets &&
-echo "Step 90. Checking all malachite-bigint targets with no std" &&
+echo "Step 99. Checking all malachite-bigint targets with no std" &&
 cargo check --all-targets --no-default-features &&
-echo "Step 91. Checking the malachite meta-crate" &&
+echo "Step 100. Checking the malachite meta-crate" &&
 cd ../malachite &&
 cargo check --all-targets &&
-echo "Step 92. Checking the malachite meta-crate with serde" &&
+echo "Step 101. Checking the malachite meta-crate with serde" &&
 cargo check --all-targets --features enable_serde &&
 cd .. &&
-echo "Step 93. Checking and testing malachite-lints" &&
+echo "Step 102. Checking and testing malachite-lints" &&
 cd malachite-lints &&
 cargo fmt --check &&
 cargo test -- --test-threads 1 &&
 cd .. &&
-echo "Step 94. Running additional-lints" &&
+echo "Step 103. Running additional-lints" &&
 bash additional-lints.sh &&
 cd malachite-base &&
-echo "Step 95. Updating malachite-base" &&
+echo "Step 104. Updating malachite-base" &&
 cargo update &&
-echo "Step 96. Formatting malachite-base" &&
+echo "Step 105. Formatting malachite-base" &&
 bash ../superfmt.sh &&
-echo "Step 97. Running clippy on malachite-base" &&
+echo "Step 106. Running clippy on malachite-base" &&
 cargo clippy --all-targets --features bin_build &&
-echo "Step 98. Running clippy on malachite-base with no std" &&
+echo "Step 107. Running clippy on malachite-base with no std" &&
 cargo clippy --all-targets --features bin_build --no-default-features &&
-echo "Step 99. Testing malachite-base" &&
+echo "Step 108. Testing malachite-base" &&
 cargo test --release --tests --features bin_build &&
-echo "Step 100. Testing malachite-base with no std" &&
+echo "Step 109. Testing malachite-base with no std" &&
 cargo test --release --tests --features bin_build --no-default-features &&
-echo "Step 101. Testing malachite-base doctests" &&
+echo "Step 110. Testing malachite-base doctests" &&
 bash ../rundoc.sh --features test_build &&
-echo "Step 102. Testing malachite-base doctests with random" &&
+echo "Step 111. Testing malachite-base doctests with random" &&
 bash ../rundoc.sh --features test_build --features random &&
-echo "Step 103. Documenting malachite-base" &&
+echo "Step 112. Documenting malachite-base" &&
 RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps --features random &&
-echo "Step 104. Building malachite-base lib for wasm" &&
+echo "Step 113. Building malachite-base lib for wasm" &&
 cargo build --lib --release --target wasm32-unknown-unknown &&
 cd ../malachite-nz &&
-echo "Step 105. Updating malachite-nz" &&
+echo "Step 114. Updating malachite-nz" &&
 cargo update &&
-echo "Step 106. Formatting malachite-nz" &&
+echo "Step 115. Formatting malachite-nz" &&
 bash ../superfmt.sh &&
-echo "Step 107. Running clippy on malachite-nz" &&
+echo "Step 116. Running clippy on malachite-nz" &&
 cargo clippy --all-targets --features bin_build --features enable_serde &&
-echo "Step 108. Running clippy on malachite-nz with 32_bit_limbs" &&
+echo "Step 117. Running clippy on malachite-nz with 32_bit_limbs" &&
 cargo clippy --all-targets --features bin_build --features 32_bit_limbs --features enable_serde &&
-echo "Step 109. Running clippy on malachite-nz with no std" &&
+echo "Step 118. Running clippy on malachite-nz with no std" &&
 cargo clippy --all-targets --features bin_build --features enable_serde --no-default-features &&
-echo "Step 110. Running clippy on malachite-nz with 32_bit_limbs and no std" &&
+echo "Step 119. Running clippy on malachite-nz with 32_bit_limbs and no std" &&
 cargo clippy --all-targets --features bin_build --features 32_bit_limbs --features enable_serde --no-default-features &&
-echo "Step 111. Testing malachite-nz" &&
+echo "Step 120. Testing malachite-nz" &&
 cargo test --release --tests --features test_build --features enable_serde &&
-echo "Step 112. Testing malachite-nz with no std" &&
+echo "Step 121. Testing malachite-nz with no std" &&
 cargo test --release --tests --features test_build --features enable_serde --no-default-features &&
-echo "Step 113. Testing malachite-nz doctests" &&
+echo "Step 122. Testing malachite-nz doctests" &&
 bash ../rundoc.sh --features test_build &&
-echo "Step 114. Testing malachite-nz with 32_bit_limbs" &&
+echo "Step 123. Testing malachite-nz with 32_bit_limbs" &&
 cargo test --release --tests --features test_build --features 32_bit_limbs --features enable_serde &&
-echo "Step 115. Testing malachite-nz with 32_bit_limbs and no std" &&
+echo "Step 124. Testing malachite-nz with 32_bit_limbs and no std" &&
 cargo test --release --tests --features test_build --features 32_bit_limbs --features enable_serde --no-default-features &&
-echo "Step 116. Testing malachite-nz doctests with 32_bit_limbs" &&
+echo "Step 125. Testing malachite-nz doctests with 32_bit_limbs" &&
 bash ../rundoc.sh --features test_build --features 32_bit_limbs&&
-echo "Step 117. Testing malachite-nz doctests with 32_bit_limbs and random" &&
+echo "Step 126. Testing malachite-nz doctests with 32_bit_limbs and random" &&
 bash ../rundoc.sh --features test_build --features 32_bit_limbs --features random &&
-echo "Step 118. Running extra tests for malachite-nz" &&
+echo "Step 127. Running extra tests for malachite-nz" &&
 python3 extra-tests.py &&
-echo "Step 119. Documenting malachite-nz" &&
+echo "Step 128. Documenting malachite-nz" &&
 RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps --features doc-images --features random &&
-echo "Step 120. Building malachite-nz lib for wasm with 32_bit_limbs" &&
+echo "Step 129. Building malachite-nz lib for wasm with 32_bit_limbs" &&
 cargo build --lib --release --features 32_bit_limbs --target wasm32-unknown-unknown &&
-echo "Step 121. Building malachite-nz lib for wasm" &&
+echo "Step 130. Building malachite-nz lib for wasm" &&
 cargo build --lib --release --target wasm32-unknown-unknown &&
 cd ../malachite-q &&
-echo "Step 122. Updating malachite-q" &&
+echo "Step 131. Updating malachite-q" &&
 cargo update &&
-echo "Step 123. Formatting malachite-q" &&
+echo "Step 132. Formatting malachite-q" &&
 bash ../superfmt.sh &&
-echo "Step 124. Running clippy on malachite-q" &&
+echo "Step 133. Running clippy on malachite-q" &&
 cargo clippy --all-targets --features bin_build --features enable_serde &&
-echo "Step 125. Running clippy on malachite-q with 32_bit_limbs" &&
+echo "Step 134. Running clippy on malachite-q with 32_bit_limbs" &&
 cargo clippy --all-targets --features bin_build --features enable_serde --features 32_bit_limbs &&
-echo "Step 126. Running clippy on malachite-q with no std" &&
+echo "Step 135. Running clippy on malachite-q with no std" &&
 cargo clippy --all-targets --features bin_build --features enable_serde --no-default-features &&
-echo "Step 127. Running clippy on malachite-q with 32_bit_limbs and no std" &&
+echo "Step 136. Running clippy on malachite-q with 32_bit_limbs and no std" &&
 cargo clippy --all-targets --features bin_build --features enable_serde --features 32_bit_limbs --no-default-features &&
-echo "Step 128. Testing malachite-q" &&
+echo "Step 137. Testing malachite-q" &&
 cargo test --release --tests --features bin_build --features enable_serde &&
-echo "Step 129. Testing malachite-q with no std" &&
+echo "Step 138. Testing malachite-q with no std" &&
 cargo test --release --tests --features bin_build --features enable_serde --no-default-features &&
-echo "Step 130. Testing malachite-q doctests" &&
+echo "Step 139. Testing malachite-q doctests" &&
 bash ../rundoc.sh --features test_build &&
-echo "Step 131. Testing malachite-q with 32_bit_limbs" &&
+echo "Step 140. Testing malachite-q with 32_bit_limbs" &&
 cargo test --release --tests --features bin_build --features enable_serde --features 32_bit_limbs &&
-echo "Step 132. Testing malachite-q with 32_bit_limbs and no std" &&
+echo "Step 141. Testing malachite-q with 32_bit_limbs and no std" &&
 cargo test --release --tests --features bin_build --features enable_serde --features 32_bit_limbs --no-default-features &&
-echo "Step 133. Testing malachite-q doctests with 32_bit_limbs" &&
+echo "Step 142. Testing malachite-q doctests with 32_bit_limbs" &&
 bash ../rundoc.sh --features test_build --features 32_bit_limbs &&
-echo "Step 134. Testing malachite-q doctests with random" &&
+echo "Step 143. Testing malachite-q doctests with random" &&
 bash ../rundoc.sh --features test_build --features random &&
-echo "Step 135. Testing malachite-q doctests with random and 32_bit_limbs" &&
+echo "Step 144. Testing malachite-q doctests with random and 32_bit_limbs" &&
 bash ../rundoc.sh --features test_build --features random --features 32_bit_limbs &&
-echo "Step 136. Documenting malachite-q" &&
+echo "Step 145. Documenting malachite-q" &&
 RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps --features random &&
-echo "Step 137. Building malachite-q lib for wasm" &&
+echo "Step 146. Building malachite-q lib for wasm" &&
 cargo build --lib --release --target wasm32-unknown-unknown &&
-echo "Step 138. Building malachite-q lib for wasm with 32_bit_limbs" &&
+echo "Step 147. Building malachite-q lib for wasm with 32_bit_limbs" &&
 cargo build --lib --release --features 32_bit_limbs --target wasm32-unknown-unknown &&
 cd ../malachite-float &&
-echo "Step 139. Updating malachite-float" &&
+echo "Step 148. Updating malachite-float" &&
 cargo update &&
-echo "Step 140. Formatting malachite-float" &&
+echo "Step 149. Formatting malachite-float" &&
 bash ../superfmt.sh &&
-echo "Step 141. Running clippy on malachite-float" &&
+echo "Step 150. Running clippy on malachite-float" &&
 cargo clippy --all-targets --features bin_build --features enable_serde &&
-echo "Step 142. Running clippy on malachite-float with 32_bit_limbs" &&
+echo "Step 151. Running clippy on malachite-float with 32_bit_limbs" &&
 cargo clippy --all-targets --features bin_build --features enable_serde --features 32_bit_limbs &&
-echo "Step 143. Running clippy on malachite-float with no std" &&
+echo "Step 152. Running clippy on malachite-float with no std" &&
 cargo clippy --all-targets --features bin_build --features enable_serde --no-default-features &&
-echo "Step 144. Running clippy on malachite-float with 32_bit_limbs and no std" &&
+echo "Step 153. Running clippy on malachite-float with 32_bit_limbs and no std" &&
 cargo clippy --all-targets --features bin_build --features enable_serde --features 32_bit_limbs --no-default-features &&
-echo "Step 145. Testing malachite-float" &&
+echo "Step 154. Testing malachite-float" &&
 cargo test --release --tests --features bin_build --features enable_serde &&
-echo "Step 146. Testing malachite-float with no std" &&
+echo "Step 155. Testing malachite-float with no std" &&
 cargo test --release --tests --features bin_build --features enable_serde --no-default-features &&
-echo "Step 147. Testing malachite-float doctests" &&
+echo "Step 156. Testing malachite-float doctests" &&
 bash ../rundoc.sh --features test_build &&
-echo "Step 148. Testing malachite-float with 32_bit_limbs" &&
+echo "Step 157. Testing malachite-float with 32_bit_limbs" &&
 cargo test --release --tests --features bin_build --features 32_bit_limbs &&
-echo "Step 149. Testing malachite-float with 32_bit_limbs and no std" &&
+echo "Step 158. Testing malachite-float with 32_bit_limbs and no std" &&
 cargo test --release --tests --features bin_build --features 32_bit_limbs --no-default-features &&
-echo "Step 150. Testing malachite-float doctests with 32_bit_limbs" &&
+echo "Step 159. Testing malachite-float doctests with 32_bit_limbs" &&
 bash ../rundoc.sh --features test_build --features 32_bit_limbs &&
-echo "Step 151. Testing malachite-float doctests with random" &&
+echo "Step 160. Testing malachite-float doctests with random" &&
 bash ../rundoc.sh --features test_build --features random &&
-echo "Step 152. Testing malachite-float doctests with random and 32_bit_limbs" &&
+echo "Step 161. Testing malachite-float doctests with random and 32_bit_limbs" &&
 bash ../rundoc.sh --features test_build --features random --features 32_bit_limbs &&
-echo "Step 153. Documenting malachite-float" &&
+echo "Step 162. Documenting malachite-float" &&
 RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps --features random &&
-echo "Step 154. Building malachite-float lib for wasm" &&
+echo "Step 163. Building malachite-float lib for wasm" &&
 cargo build --lib --release --target wasm32-unknown-unknown &&
-echo "Step 155. Building malachite-float lib for wasm with 32_bit_limbs" &&
+echo "Step 164. Building malachite-float lib for wasm with 32_bit_limbs" &&
 cargo build --lib --release --features 32_bit_limbs --target wasm32-unknown-unknown &&
+cd ../malachite-complex &&
+echo "Step 165. Updating malachite-complex" &&
+cargo update &&
+echo "Step 166. Formatting malachite-complex" &&
+bash ../superfmt.sh &&
+echo "Step 167. Running clippy on malachite-complex" &&
+cargo clippy --all-targets --features test_build &&
+echo "Step 168. Running clippy on malachite-complex with 32_bit_limbs" &&
+cargo clippy --all-targets --features test_build --features 32_bit_limbs &&
+echo "Step 169. Testing malachite-complex" &&
+cargo test --release --tests --features test_build &&
+echo "Step 170. Testing malachite-complex with 32_bit_limbs" &&
+cargo test --release --tests --features test_build --features 32_bit_limbs &&
+echo "Step 171. Testing malachite-complex doctests" &&
+bash ../rundoc.sh --features test_build &&
+echo "Step 172. Documenting malachite-complex" &&
+RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps --features random &&
+echo "Step 173. Building malachite-complex lib for wasm" &&
+cargo build --lib --release --target wasm32-unknown-unknown &&
 cd ../malachite-bigint &&
-echo "Step 156. Updating malachite-bigint" &&
+echo "Step 174. Updating malachite-bigint" &&
 cargo update &&
-echo "Step 157. Formatting malachite-bigint" &&
+echo "Step 175. Formatting malachite-bigint" &&
 bash ../superfmt.sh &&
-echo "Step 158. Running clippy on malachite-bigint" &&
+echo "Step 176. Running clippy on malachite-bigint" &&
 cargo clippy --all-targets &&
-echo "Step 159. Running clippy on malachite-bigint with no std" &&
+echo "Step 177. Running clippy on malachite-bigint with no std" &&
 cargo clippy --all-targets --no-default-features &&
-echo "Step 160. Testing malachite-bigint" &&
+echo "Step 178. Testing malachite-bigint" &&
 cargo test --release &&
-echo "Step 161. Testing malachite-bigint with no std" &&
+echo "Step 179. Testing malachite-bigint with no std" &&
 cargo test --release --no-default-features &&
-echo "Step 162. Documenting malachite-bigint" &&
+echo "Step 180. Documenting malachite-bigint" &&
 cargo doc --lib --no-deps &&
-echo "Step 163. Building malachite-bigint lib for wasm" &&
+echo "Step 181. Building malachite-bigint lib for wasm" &&
 cargo build --lib --release --target wasm32-unknown-unknown &&
 cd ../malachite &&
-echo "Step 164. Documenting malachite" &&
+echo "Step 182. Documenting malachite" &&
 RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps --features random &&
 cd ../malachite-criterion-bench &&
-echo "Step 165. Updating malachite-criterion-bench" &&
+echo "Step 183. Updating malachite-criterion-bench" &&
 cargo update &&
-echo "Step 166. Formatting malachite-criterion-bench" &&
+echo "Step 184. Formatting malachite-criterion-bench" &&
 bash ../superfmt.sh &&
 cd .. &&
-echo "Step 167. Running additional-lints" &&
+echo "Step 185. Running additional-lints" &&
 bash additional-lints.sh &&
-echo "Step 168. Testing against FLINT" &&
+echo "Step 186. Testing against FLINT" &&
 cd cpp-test &&
 cargo run --release &&
 cd .. &&
-echo "Step 169. Checking links" &&
+echo "Step 187. Checking links" &&
 cd ../check-malachite-links &&
 cargo run --release
@@ -20,7 +20,7 @@ import os
 import re
 import sys
 
-CRATES = ["malachite-base", "malachite-nz", "malachite-q", "malachite-float"]
+CRATES = ["malachite-base", "malachite-nz", "malachite-q", "malachite-float", "malachite-complex"]
 
 HEADERS = {
     "# Worst-case complexity",
@@ -18,6 +18,7 @@ pub(crate) fn register(runner: &mut Runner) {
     balanced_mod::register(runner);
     bell_number::register(runner);
     binomial_coefficient::register(runner);
+    catalan_number::register(runner);
     ceiling::register(runner);
     checked_add_mul::register(runner);
     checked_square::register(runner);
@@ -46,11 +47,13 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base_power_of_2::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_add::register(runner);
     mod_div::register(runner);
     mod_div_list::register(runner);
     mod_euclidean::register(runner);
     mod_mul::register(runner);
+    mod_multiplicative_order::register(runner);
     mod_neg::register(runner);
     mod_op::register(runner);
     mod_pow::register(runner);
@@ -73,6 +76,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mul_add_mul::register(runner);
     mul_shr_round::register(runner);
     mul_sub_mul::register(runner);
+    multinomial_coefficient::register(runner);
     checked_mul_add_mul::register(runner);
     checked_mul_sub_mul::register(runner);
     overflowing_mul_add_mul::register(runner);
@@ -95,6 +99,7 @@ pub(crate) fn register(runner: &mut Runner) {
     overflowing_sub::register(runner);
     overflowing_sub_mul::register(runner);
     parity::register(runner);
+    partition_number::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
     primorial::register(runner);
@@ -117,6 +122,7 @@ pub(crate) fn register(runner: &mut Runner) {
     sign::register(runner);
     sqrt::register(runner);
     square::register(runner);
+    stirling_number::register(runner);
     sub_mul::register(runner);
     wrapping_abs::register(runner);
     wrapping_add::register(runner);
@@ -146,6 +152,7 @@ mod average;
 mod balanced_mod;
 mod bell_number;
 mod binomial_coefficient;
+mod catalan_number;
 mod ceiling;
 mod checked_add_mul;
 mod checked_mul_add_mul;
@@ -180,7 +187,9 @@ mod mod_div_list;
 mod mod_euclidean;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
+mod mod_multiplicative_order;
 mod mod_neg;
 mod mod_op;
 mod mod_pow;
@@ -203,6 +212,7 @@ mod mod_sub;
 mod mul_add_mul;
 mod mul_shr_round;
 mod mul_sub_mul;
+mod multinomial_coefficient;
 mod neg;
 mod next_power_of_2;
 mod overflowing_abs;
@@ -218,6 +228,7 @@ mod overflowing_square;
 mod overflowing_sub;
 mod overflowing_sub_mul;
 mod parity;
+mod partition_number;
 mod pow;
 mod power_of_2;
 mod primorial;
@@ -243,6 +254,7 @@ mod shr_round;
 mod sign;
 mod sqrt;
 mod square;
+mod stirling_number;
 mod sub_mul;
 mod wrapping_abs;
 mod wrapping_add;
@@ -0,0 +1,53 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModLog;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_mod_log, u8, u16, u32, u64, usize);
+    register_generic_benches!(runner, benchmark_mod_log, u8, u16, u32, u64, usize);
+}
+
+fn demo_mod_log<T: ModLog<T, Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, b, m) in unsigned_triple_gen_var_12::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!("{x}.mod_log({b}, {m}) = {:?}", x.mod_log(b, m));
+    }
+}
+
+fn benchmark_mod_log<T: ModLog<T, Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.mod_log({}, {})", T::NAME, T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_triple_gen_var_12::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(x, b, m)| {
+            no_out!(x.mod_log(b, m));
+        })],
+    );
+}
@@ -0,0 +1,53 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_multinomial_coefficient);
+    register_unsigned_benches!(runner, benchmark_checked_multinomial_coefficient);
+}
+
+fn demo_checked_multinomial_coefficient<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for ks in unsigned_vec_gen::<u64>().get(gm, config).take(limit) {
+        println!(
+            "multinomial_coefficient({:?}) = {:?}",
+            ks,
+            T::checked_multinomial_coefficient(&ks)
+        );
+    }
+}
+
+fn benchmark_checked_multinomial_coefficient<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_multinomial_coefficient(&[u64])", T::NAME),
+        BenchmarkType::Single,
+        unsigned_vec_gen::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |ks| {
+            no_out!(T::checked_multinomial_coefficient(&ks));
+        })],
+    );
+}
@@ -0,0 +1,170 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::pair_1_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_28;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_stirling_number_1_unsigned);
+    register_signed_demos!(runner, demo_checked_stirling_number_1_signed);
+    register_unsigned_demos!(runner, demo_checked_stirling_number_2_unsigned);
+    register_signed_demos!(runner, demo_checked_stirling_number_2_signed);
+    register_unsigned_benches!(runner, benchmark_checked_stirling_number_1_unsigned);
+    register_signed_benches!(runner, benchmark_checked_stirling_number_1_signed);
+    register_unsigned_benches!(runner, benchmark_checked_stirling_number_2_unsigned);
+    register_signed_benches!(runner, benchmark_checked_stirling_number_2_signed);
+}
+
+fn demo_checked_stirling_number_1_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (n, k) in unsigned_pair_gen_var_28().get(gm, config).take(limit) {
+        println!(
+            "{}::checked_stirling_number_1({}, {}) = {:?}",
+            T::NAME,
+            n,
+            k,
+            T::checked_stirling_number_1(n, k)
+        );
+    }
+}
+
+fn demo_checked_stirling_number_1_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (n, k) in unsigned_pair_gen_var_28().get(gm, config).take(limit) {
+        println!(
+            "{}::checked_stirling_number_1({}, {}) = {:?}",
+            T::NAME,
+            n,
+            k,
+            T::checked_stirling_number_1(n, k)
+        );
+    }
+}
+
+fn demo_checked_stirling_number_2_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (n, k) in unsigned_pair_gen_var_28().get(gm, config).take(limit) {
+        println!(
+            "{}::checked_stirling_number_2({}, {}) = {:?}",
+            T::NAME,
+            n,
+            k,
+            T::checked_stirling_number_2(n, k)
+        );
+    }
+}
+
+fn demo_checked_stirling_number_2_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (n, k) in unsigned_pair_gen_var_28().get(gm, config).take(limit) {
+        println!(
+            "{}::checked_stirling_number_2({}, {}) = {:?}",
+            T::NAME,
+            n,
+            k,
+            T::checked_stirling_number_2(n, k)
+        );
+    }
+}
+
+fn benchmark_checked_stirling_number_1_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_stirling_number_1(u64, u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [("Malachite", &mut |(n, k)| {
+            no_out!(T::checked_stirling_number_1(n, k));
+        })],
+    );
+}
+
+fn benchmark_checked_stirling_number_1_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_stirling_number_1(u64, u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [("Malachite", &mut |(n, k)| {
+            no_out!(T::checked_stirling_number_1(n, k));
+        })],
+    );
+}
+
+fn benchmark_checked_stirling_number_2_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_stirling_number_2(u64, u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [("Malachite", &mut |(n, k)| {
+            no_out!(T::checked_stirling_number_2(n, k));
+        })],
+    );
+}
+
+fn benchmark_checked_stirling_number_2_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_stirling_number_2(u64, u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [("Malachite", &mut |(n, k)| {
+            no_out!(T::checked_stirling_number_2(n, k));
+        })],
+    );
+}
@@ -9,21 +9,33 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    divisor_sigma::register(runner);
+    divisors::register(runner);
     factor::register(runner);
     is_power::register(runner);
     remove_power::register(runner);
     is_prime::register(runner);
     is_square::register(runner);
+    moebius_mu::register(runner);
+    next_prime::register(runner);
     primes::register(runner);
     prime_sieve::register(runner);
+    primitive_root::register(runner);
     primitive_root_prime::register(runner);
+    totient::register(runner);
 }
 
+mod divisor_sigma;
+mod divisors;
 mod factor;
 mod is_power;
 mod is_prime;
 mod is_square;
+mod moebius_mu;
+mod next_prime;
 mod prime_sieve;
 mod primes;
+mod primitive_root;
 mod primitive_root_prime;
 mod remove_power;
+mod totient;
@@ -0,0 +1,228 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::{NextPrime, PrevPrime};
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_u8_next_prime);
+    register_demo!(runner, demo_u16_next_prime);
+    register_demo!(runner, demo_u32_next_prime);
+    register_demo!(runner, demo_u64_next_prime);
+    register_demo!(runner, demo_usize_next_prime);
+    register_demo!(runner, demo_u8_prev_prime);
+    register_demo!(runner, demo_u16_prev_prime);
+    register_demo!(runner, demo_u32_prev_prime);
+    register_demo!(runner, demo_u64_prev_prime);
+    register_demo!(runner, demo_usize_prev_prime);
+
+    register_bench!(runner, benchmark_u8_next_prime);
+    register_bench!(runner, benchmark_u16_next_prime);
+    register_bench!(runner, benchmark_u32_next_prime);
+    register_bench!(runner, benchmark_u64_next_prime);
+    register_bench!(runner, benchmark_usize_next_prime);
+    register_bench!(runner, benchmark_u8_prev_prime);
+    register_bench!(runner, benchmark_u16_prev_prime);
+    register_bench!(runner, benchmark_u32_prev_prime);
+    register_bench!(runner, benchmark_u64_prev_prime);
+    register_bench!(runner, benchmark_usize_prev_prime);
+}
+
+fn demo_u8_next_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u8>().get(gm, config).take(limit) {
+        println!("next_prime({}) = {:?}", u, u.next_prime());
+    }
+}
+
+fn demo_u16_next_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u16>().get(gm, config).take(limit) {
+        println!("next_prime({}) = {:?}", u, u.next_prime());
+    }
+}
+
+fn demo_u32_next_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u32>().get(gm, config).take(limit) {
+        println!("next_prime({}) = {:?}", u, u.next_prime());
+    }
+}
+
+fn demo_u64_next_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u64>().get(gm, config).take(limit) {
+        println!("next_prime({}) = {:?}", u, u.next_prime());
+    }
+}
+
+fn demo_usize_next_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<usize>().get(gm, config).take(limit) {
+        println!("next_prime({}) = {:?}", u, u.next_prime());
+    }
+}
+
+fn demo_u8_prev_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u8>().get(gm, config).take(limit) {
+        println!("prev_prime({}) = {:?}", u, u.prev_prime());
+    }
+}
+
+fn demo_u16_prev_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u16>().get(gm, config).take(limit) {
+        println!("prev_prime({}) = {:?}", u, u.prev_prime());
+    }
+}
+
+fn demo_u32_prev_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u32>().get(gm, config).take(limit) {
+        println!("prev_prime({}) = {:?}", u, u.prev_prime());
+    }
+}
+
+fn demo_u64_prev_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<u64>().get(gm, config).take(limit) {
+        println!("prev_prime({}) = {:?}", u, u.prev_prime());
+    }
+}
+
+fn demo_usize_prev_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for u in unsigned_gen::<usize>().get(gm, config).take(limit) {
+        println!("prev_prime({}) = {:?}", u, u.prev_prime());
+    }
+}
+
+fn benchmark_u8_next_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u8.next_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.next_prime()))],
+    );
+}
+
+fn benchmark_u16_next_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u16.next_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u16>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.next_prime()))],
+    );
+}
+
+fn benchmark_u32_next_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u32.next_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u32>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.next_prime()))],
+    );
+}
+
+fn benchmark_u64_next_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u64.next_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.next_prime()))],
+    );
+}
+
+fn benchmark_usize_next_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "usize.next_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<usize>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.next_prime()))],
+    );
+}
+
+fn benchmark_u8_prev_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u8.prev_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.prev_prime()))],
+    );
+}
+
+fn benchmark_u16_prev_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u16.prev_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u16>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.prev_prime()))],
+    );
+}
+
+fn benchmark_u32_prev_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u32.prev_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u32>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.prev_prime()))],
+    );
+}
+
+fn benchmark_u64_prev_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u64.prev_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.prev_prime()))],
+    );
+}
+
+fn benchmark_usize_prev_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "usize.prev_prime()",
+        BenchmarkType::Single,
+        unsigned_gen::<usize>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |u| no_out!(u.prev_prime()))],
+    );
+}
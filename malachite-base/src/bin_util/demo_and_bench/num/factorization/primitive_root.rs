@@ -0,0 +1,89 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{IsPrimitiveRoot, PrimitiveRoot};
+use malachite_base::test_util::bench::bucketers::{pair_2_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_16};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_primitive_root, u8, u16, u32, u64, usize);
+    register_generic_demos!(runner, demo_is_primitive_root, u8, u16, u32, u64, usize);
+    register_generic_benches!(runner, benchmark_primitive_root, u8, u16, u32, u64, usize);
+    register_generic_benches!(
+        runner,
+        benchmark_is_primitive_root,
+        u8,
+        u16,
+        u32,
+        u64,
+        usize
+    );
+}
+
+fn demo_primitive_root<T: PrimitiveRoot<Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for m in unsigned_gen_var_1::<T>().get(gm, config).take(limit) {
+        println!("{m}.primitive_root() = {:?}", m.primitive_root());
+    }
+}
+
+fn demo_is_primitive_root<T: IsPrimitiveRoot<T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, m) in unsigned_pair_gen_var_16::<T>().get(gm, config).take(limit) {
+        if x.is_primitive_root(m) {
+            println!("{x} is a primitive root mod {m}");
+        } else {
+            println!("{x} is not a primitive root mod {m}");
+        }
+    }
+}
+
+fn benchmark_primitive_root<T: PrimitiveRoot<Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.primitive_root()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |m| no_out!(m.primitive_root()))],
+    );
+}
+
+fn benchmark_is_primitive_root<T: IsPrimitiveRoot<T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_primitive_root({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_16::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(x, m)| no_out!(x.is_primitive_root(m)))],
+    );
+}
@@ -0,0 +1,85 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Totient};
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_totient, u8, u16, u32, u64, usize);
+    register_generic_demos!(runner, demo_carmichael_lambda, u8, u16, u32, u64, usize);
+    register_generic_benches!(runner, benchmark_totient, u8, u16, u32, u64, usize);
+    register_generic_benches!(
+        runner,
+        benchmark_carmichael_lambda,
+        u8,
+        u16,
+        u32,
+        u64,
+        usize
+    );
+}
+
+fn demo_totient<T: Totient<Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen_var_1::<T>().get(gm, config).take(limit) {
+        println!("{n}.totient() = {}", n.totient());
+    }
+}
+
+fn demo_carmichael_lambda<T: CarmichaelLambda<Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen_var_1::<T>().get(gm, config).take(limit) {
+        println!("{n}.carmichael_lambda() = {}", n.carmichael_lambda());
+    }
+}
+
+fn benchmark_totient<T: Totient<Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.totient()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.totient()))],
+    );
+}
+
+fn benchmark_carmichael_lambda<T: CarmichaelLambda<Output = T> + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.carmichael_lambda()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.carmichael_lambda()))],
+    );
+}
@@ -45,6 +45,20 @@ fn average_round_primitive<T: PrimitiveInt>(x: T, y: T, rm: RoundingMode) -> (T,
                 (floor + T::ONE, Greater)
             }
         }
+        NearestTiesAway => {
+            if floor < T::ZERO {
+                (floor, Less)
+            } else {
+                (floor + T::ONE, Greater)
+            }
+        }
+        Odd => {
+            if floor.odd() {
+                (floor, Less)
+            } else {
+                (floor + T::ONE, Greater)
+            }
+        }
         Exact => {
             panic!("Average is not exact: ({x} + {y}) / 2");
         }
@@ -93,6 +107,20 @@ macro_rules! impl_average {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// g(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(a) \lfloor |a| + 1/2 \rfloor,
+            /// $$
+            ///
+            /// $$
+            /// g(x, y, \mathrm{Odd}) = \begin{cases}
+            ///     a & \text{if} \\quad a \in \Z, \\\\
+            ///     \lfloor a \rfloor & \text{if} \\quad a \notin \Z
+            ///     \\ \text{and} \\ \lfloor a \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil a \rceil & \text{if} \\quad a \notin \Z
+            ///     \\ \text{and} \\ \lfloor a \rfloor \\ \text{is even,}
+            /// \end{cases}
+            /// $$
+            ///
             /// and $g(x, y, \mathrm{Exact}) = a$, but panics if $a \notin \Z$.
             ///
             /// Then
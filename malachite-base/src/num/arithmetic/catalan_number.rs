@@ -0,0 +1,130 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{CatalanNumber, CheckedCatalanNumber};
+
+// The Catalan numbers grow like 4^n, so all of them that fit in a u128, C_0 through C_69, form a
+// short table, checked against OEIS A000108. Narrower types use a prefix of it.
+const CATALAN_NUMBERS: [u128; 70] = [
+    1,
+    1,
+    2,
+    5,
+    14,
+    42,
+    132,
+    429,
+    1430,
+    4862,
+    16796,
+    58786,
+    208012,
+    742900,
+    2674440,
+    9694845,
+    35357670,
+    129644790,
+    477638700,
+    1767263190,
+    6564120420,
+    24466267020,
+    91482563640,
+    343059613650,
+    1289904147324,
+    4861946401452,
+    18367353072152,
+    69533550916004,
+    263747951750360,
+    1002242216651368,
+    3814986502092304,
+    14544636039226909,
+    55534064877048198,
+    212336130412243110,
+    812944042149730764,
+    3116285494907301262,
+    11959798385860453492,
+    45950804324621742364,
+    176733862787006701400,
+    680425371729975800390,
+    2622127042276492108820,
+    10113918591637898134020,
+    39044429911904443959240,
+    150853479205085351660700,
+    583300119592996693088040,
+    2257117854077248073253720,
+    8740328711533173390046320,
+    33868773757191046886429490,
+    131327898242169365477991900,
+    509552245179617138054608572,
+    1978261657756160653623774456,
+    7684785670514316385230816156,
+    29869166945772625950142417512,
+    116157871455782434250553845880,
+    451959718027953471447609509424,
+    1759414616608818870992479875972,
+    6852456927844873497549658464312,
+    26700952856774851904245220912664,
+    104088460289122304033498318812080,
+    405944995127576985730643443367112,
+    1583850964596120042686772779038896,
+    6182127958584855650487080847216336,
+    24139737743045626825711458546273312,
+    94295850558771979787935384946380125,
+    368479169875816659479009042713546950,
+    1440418573150919668872489894243865350,
+    5632681584560312734993915705849145100,
+    22033725021956517463358552614056949950,
+    86218923998960285726185640663701108500,
+    337485502510215975556783793455058624700,
+];
+
+macro_rules! impl_catalan_number {
+    ($t:ident) => {
+        impl CheckedCatalanNumber for $t {
+            /// Computes the $n$th Catalan number, $C_n = \binom{2n}{n}/(n+1)$.
+            ///
+            /// $C_n$ counts, among many other things, the binary trees with $n$ internal nodes and
+            /// the ways to balance $n$ pairs of parentheses.
+            ///
+            /// If the result is too large to be represented, the function returns `None`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::catalan_number#checked_catalan_number).
+            #[inline]
+            fn checked_catalan_number(n: u64) -> Option<$t> {
+                CATALAN_NUMBERS
+                    .get(usize::try_from(n).ok()?)
+                    .and_then(|&c| $t::try_from(c).ok())
+            }
+        }
+
+        impl CatalanNumber for $t {
+            /// Computes the $n$th Catalan number, $C_n = \binom{2n}{n}/(n+1)$.
+            ///
+            /// $C_n$ counts, among many other things, the binary trees with $n$ internal nodes and
+            /// the ways to balance $n$ pairs of parentheses.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the result is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::catalan_number#catalan_number).
+            #[inline]
+            fn catalan_number(n: u64) -> $t {
+                $t::checked_catalan_number(n).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_catalan_number);
@@ -30,6 +30,21 @@ fn div_round_unsigned<T: PrimitiveUnsigned>(x: T, other: T, rm: RoundingMode) ->
                 (quotient, Less)
             }
         }
+        NearestTiesAway => {
+            let shifted_other = other >> 1;
+            if remainder > shifted_other || remainder == shifted_other && other.even() {
+                (quotient + T::ONE, Greater)
+            } else {
+                (quotient, Less)
+            }
+        }
+        Odd => {
+            if quotient.odd() {
+                (quotient, Less)
+            } else {
+                (quotient + T::ONE, Greater)
+            }
+        }
         Exact => {
             panic!("Division is not exact: {x} / {other}");
         }
@@ -69,6 +84,21 @@ macro_rules! impl_div_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// g(x, y, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// g(x, y, \mathrm{Odd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \Z, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -176,6 +206,21 @@ macro_rules! impl_div_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// g(x, y, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// g(x, y, \mathrm{Odd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \Z, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -208,6 +208,7 @@ pub mod arithmetic_checked_shr;
 /// assert_eq!(4u8.average_round(7, Ceiling), (6, Greater));
 /// assert_eq!(4u8.average_round(7, Down), (5, Less));
 /// assert_eq!(4u8.average_round(6, Exact), (5, Equal));
+/// assert_eq!(4u8.average_round(7, Odd), (5, Less));
 ///
 /// // the exact average is -5.5
 /// assert_eq!((-4i8).average_round(-7, Down), (-5, Greater));
@@ -216,6 +217,7 @@ pub mod arithmetic_checked_shr;
 /// // the exact average is -0.5
 /// assert_eq!(i8::MIN.average_round(i8::MAX, Floor), (-1, Less));
 /// assert_eq!(i8::MIN.average_round(i8::MAX, Nearest), (0, Greater));
+/// assert_eq!(i8::MIN.average_round(i8::MAX, NearestTiesAway), (-1, Less));
 /// ```
 ///
 /// # average_round_assign
@@ -326,6 +328,31 @@ pub mod balanced_mod;
 /// ```
 pub mod bell_number;
 pub mod binomial_coefficient;
+/// [`CatalanNumber`](traits::CatalanNumber) and
+/// [`CheckedCatalanNumber`](traits::CheckedCatalanNumber), traits for computing Catalan numbers.
+/// The first panics if the result cannot be represented; the second returns `None` in that case.
+///
+/// # catalan_number
+/// ```
+/// use malachite_base::num::arithmetic::traits::CatalanNumber;
+///
+/// assert_eq!(u8::catalan_number(0), 1);
+/// assert_eq!(u8::catalan_number(4), 14);
+/// assert_eq!(u8::catalan_number(6), 132);
+/// assert_eq!(u32::catalan_number(10), 16796);
+/// assert_eq!(u64::catalan_number(30), 3814986502092304);
+/// ```
+///
+/// # checked_catalan_number
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedCatalanNumber;
+///
+/// assert_eq!(u8::checked_catalan_number(6), Some(132));
+/// assert_eq!(u8::checked_catalan_number(7), None);
+/// assert_eq!(u32::checked_catalan_number(19), Some(1767263190));
+/// assert_eq!(u32::checked_catalan_number(20), None);
+/// ```
+pub mod catalan_number;
 /// [`Ceiling`](traits::Ceiling) and [`CeilingAssign`](traits::CeilingAssign), traits for computing
 /// the ceiling of a number.
 ///
@@ -848,6 +875,9 @@ pub mod div_mod_euclidean;
 /// assert_eq!(20u128.div_round(3, Nearest), (7, Greater));
 /// assert_eq!(10usize.div_round(4, Nearest), (2, Less));
 /// assert_eq!(14u8.div_round(4, Nearest), (4, Greater));
+/// assert_eq!(10u16.div_round(4, NearestTiesAway), (3, Greater));
+/// assert_eq!(14u32.div_round(4, Odd), (3, Less));
+/// assert_eq!(10u64.div_round(4, Odd), (3, Greater));
 ///
 /// assert_eq!((-10i8).div_round(4, Down), (-2, Greater));
 /// assert_eq!((-10i16).div_round(4, Up), (-3, Less));
@@ -856,6 +886,8 @@ pub mod div_mod_euclidean;
 /// assert_eq!((-20i128).div_round(3, Nearest), (-7, Less));
 /// assert_eq!((-10isize).div_round(4, Nearest), (-2, Greater));
 /// assert_eq!((-14i8).div_round(4, Nearest), (-4, Less));
+/// assert_eq!((-10i16).div_round(4, NearestTiesAway), (-3, Less));
+/// assert_eq!((-14i32).div_round(4, Odd), (-3, Greater));
 ///
 /// assert_eq!((-10i16).div_round(-4, Down), (2, Less));
 /// assert_eq!((-10i32).div_round(-4, Up), (3, Greater));
@@ -1669,6 +1701,37 @@ pub mod mod_inverse;
 /// assert_eq!(100u16.mod_is_reduced(&101), true);
 /// ```
 pub mod mod_is_reduced;
+/// [`ModLog`](traits::ModLog) and
+/// [`ModLogWithOrderFactorization`](traits::ModLogWithOrderFactorization), traits for computing the
+/// discrete logarithm of a number to a base, modulo another number.
+///
+/// # mod_log
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLog;
+///
+/// assert_eq!(13u8.mod_log(3, 17), Some(4));
+/// assert_eq!(0u8.mod_log(6, 8), Some(3));
+/// assert_eq!(3u8.mod_log(2, 8), None);
+/// assert_eq!(12345u32.mod_log(5, 65537), Some(51713));
+/// ```
+///
+/// # mod_log_with_order_factorization
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLogWithOrderFactorization;
+///
+/// // 65537 - 1 = 2 ^ 16
+/// assert_eq!(
+///     12345u32.mod_log_with_order_factorization(5, 65537, &[(2, 16)]),
+///     Some(51713)
+/// );
+/// assert_eq!(
+///     12345u128.mod_log_with_order_factorization(5, 65537, &[(2, 16)]),
+///     Some(51713)
+/// );
+/// // The powers of 9 modulo 17 are the nonzero squares, and 5 is not a square
+/// assert_eq!(5u8.mod_log_with_order_factorization(9, 17, &[(2, 4)]), None);
+/// ```
+pub mod mod_log;
 /// Traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](traits::ModMul), [`ModMulAssign`](traits::ModMulAssign),
@@ -1767,6 +1830,22 @@ pub mod mod_is_reduced;
 /// assert_eq!(x, 0);
 /// ```
 pub mod mod_mul;
+/// [`ModMultiplicativeOrder`](traits::ModMultiplicativeOrder), a trait for computing the
+/// multiplicative order of a number modulo another number.
+///
+/// # mod_multiplicative_order
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModMultiplicativeOrder;
+///
+/// assert_eq!(2u8.mod_multiplicative_order(7), Some(3));
+/// assert_eq!(3u8.mod_multiplicative_order(7), Some(6));
+/// assert_eq!(0u8.mod_multiplicative_order(1), Some(1));
+/// assert_eq!(2u8.mod_multiplicative_order(4), None);
+/// assert_eq!(3u16.mod_multiplicative_order(8), Some(2));
+/// assert_eq!(10u32.mod_multiplicative_order(13), Some(6));
+/// assert_eq!(2u64.mod_multiplicative_order(2305843009213693951), Some(61));
+/// ```
+pub mod mod_multiplicative_order;
 /// [`ModNeg`](traits::ModNeg) and [`ModNegAssign`](traits::ModNegAssign), traits for negating a
 /// number modulo another number.
 ///
@@ -2697,6 +2776,32 @@ pub mod mul_shr_round;
 /// assert_eq!(x, -14);
 /// ```
 pub mod mul_sub_mul;
+/// [`MultinomialCoefficient`](traits::MultinomialCoefficient) and
+/// [`CheckedMultinomialCoefficient`](traits::CheckedMultinomialCoefficient), traits for computing
+/// multinomial coefficients. The first panics if the result cannot be represented; the second
+/// returns `None` in that case.
+///
+/// # multinomial_coefficient
+/// ```
+/// use malachite_base::num::arithmetic::traits::MultinomialCoefficient;
+///
+/// assert_eq!(u8::multinomial_coefficient(&[]), 1);
+/// assert_eq!(u8::multinomial_coefficient(&[3]), 1);
+/// assert_eq!(u8::multinomial_coefficient(&[2, 3]), 10);
+/// assert_eq!(u16::multinomial_coefficient(&[5, 3, 2]), 2520);
+/// assert_eq!(u32::multinomial_coefficient(&[4, 4, 4]), 34650);
+/// ```
+///
+/// # checked_multinomial_coefficient
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedMultinomialCoefficient;
+///
+/// assert_eq!(u16::checked_multinomial_coefficient(&[5, 3, 2]), Some(2520));
+/// assert_eq!(u8::checked_multinomial_coefficient(&[5, 3, 2]), None);
+/// assert_eq!(u8::checked_multinomial_coefficient(&[1000, 0]), Some(1));
+/// assert_eq!(u8::checked_multinomial_coefficient(&[1000, 1]), None);
+/// ```
+pub mod multinomial_coefficient;
 /// [`NegAssign`](traits::NegAssign), a trait for negating a number in place.
 ///
 /// # neg_assign
@@ -3066,6 +3171,31 @@ pub mod overflowing_sub_mul;
 /// assert_eq!(4u32.odd(), false);
 /// ```
 pub mod parity;
+/// [`PartitionNumber`](traits::PartitionNumber) and
+/// [`CheckedPartitionNumber`](traits::CheckedPartitionNumber), traits for computing the number of
+/// partitions of an integer. The first panics if the result cannot be represented; the second
+/// returns `None` in that case.
+///
+/// # partition_number
+/// ```
+/// use malachite_base::num::arithmetic::traits::PartitionNumber;
+///
+/// assert_eq!(u8::partition_number(0), 1);
+/// assert_eq!(u8::partition_number(5), 7);
+/// assert_eq!(u8::partition_number(16), 231);
+/// assert_eq!(u32::partition_number(100), 190569292);
+/// ```
+///
+/// # checked_partition_number
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedPartitionNumber;
+///
+/// assert_eq!(u8::checked_partition_number(16), Some(231));
+/// assert_eq!(u8::checked_partition_number(17), None);
+/// assert_eq!(u32::checked_partition_number(100), Some(190569292));
+/// assert_eq!(u64::checked_partition_number(1000), None);
+/// ```
+pub mod partition_number;
 /// [`Pow`](traits::Pow) and [`PowAssign`](traits::PowAssign), traits for raising a number to a
 /// power.
 ///
@@ -3397,6 +3527,8 @@ pub mod rotate;
 /// assert_eq!(20u128.round_to_multiple(3, Nearest), (21, Greater));
 /// assert_eq!(10usize.round_to_multiple(4, Nearest), (8, Less));
 /// assert_eq!(14u8.round_to_multiple(4, Nearest), (16, Greater));
+/// assert_eq!(10u16.round_to_multiple(4, NearestTiesAway), (12, Greater));
+/// assert_eq!(14u32.round_to_multiple(4, Odd), (12, Less));
 ///
 /// assert_eq!((-5i32).round_to_multiple(0, Down), (0, Greater));
 ///
@@ -3407,6 +3539,8 @@ pub mod rotate;
 /// assert_eq!((-20i128).round_to_multiple(3, Nearest), (-21, Less));
 /// assert_eq!((-10isize).round_to_multiple(4, Nearest), (-8, Greater));
 /// assert_eq!((-14i8).round_to_multiple(4, Nearest), (-16, Less));
+/// assert_eq!((-10i16).round_to_multiple(4, NearestTiesAway), (-12, Less));
+/// assert_eq!((-10i32).round_to_multiple(4, Odd), (-12, Less));
 ///
 /// assert_eq!((-10i16).round_to_multiple(-4, Down), (-8, Greater));
 /// assert_eq!((-10i32).round_to_multiple(-4, Up), (-12, Less));
@@ -3974,6 +4108,9 @@ pub mod shl_round;
 /// assert_eq!(0x101u16.shr_round(9u8, Nearest), (1, Greater));
 /// assert_eq!(0xffu8.shr_round(9u16, Nearest), (0, Less));
 /// assert_eq!(0x100u32.shr_round(9u32, Nearest), (0, Less));
+/// assert_eq!(0x100u32.shr_round(9u32, NearestTiesAway), (1, Greater));
+/// assert_eq!(0x100u64.shr_round(9u32, Odd), (1, Greater));
+/// assert_eq!(0x300u16.shr_round(9u8, Odd), (1, Less));
 ///
 /// assert_eq!(0x100u32.shr_round(8u64, Exact), (1, Equal));
 ///
@@ -3985,6 +4122,8 @@ pub mod shl_round;
 /// assert_eq!((-0x101i16).shr_round(9u8, Nearest), (-1, Less));
 /// assert_eq!((-0xffi32).shr_round(9u16, Nearest), (0, Greater));
 /// assert_eq!((-0x100i64).shr_round(9u32, Nearest), (0, Greater));
+/// assert_eq!((-0x100i64).shr_round(9u32, NearestTiesAway), (-1, Less));
+/// assert_eq!((-0x300i32).shr_round(9u32, Odd), (-1, Greater));
 ///
 /// assert_eq!(0x100i32.shr_round(8u64, Exact), (1, Equal));
 ///
@@ -4294,6 +4433,58 @@ pub mod sqrt;
 /// assert_eq!(x, 2.25);
 /// ```
 pub mod square;
+/// [`StirlingNumber1`](traits::StirlingNumber1),
+/// [`CheckedStirlingNumber1`](traits::CheckedStirlingNumber1),
+/// [`StirlingNumber2`](traits::StirlingNumber2), and
+/// [`CheckedStirlingNumber2`](traits::CheckedStirlingNumber2), traits for computing Stirling
+/// numbers of the first and second kinds. The unchecked functions panic if the result cannot be
+/// represented; the checked ones return `None` in that case.
+///
+/// # stirling_number_1
+/// ```
+/// use malachite_base::num::arithmetic::traits::StirlingNumber1;
+///
+/// assert_eq!(u8::stirling_number_1(0, 0), 1);
+/// assert_eq!(u8::stirling_number_1(3, 5), 0);
+/// assert_eq!(u8::stirling_number_1(5, 2), 50);
+/// assert_eq!(u32::stirling_number_1(10, 3), 1172700);
+///
+/// assert_eq!(i8::stirling_number_1(4, 2), 11);
+/// assert_eq!(i16::stirling_number_1(5, 2), -50);
+/// assert_eq!(i32::stirling_number_1(6, 3), -225);
+/// ```
+///
+/// # checked_stirling_number_1
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedStirlingNumber1;
+///
+/// assert_eq!(u8::checked_stirling_number_1(5, 2), Some(50));
+/// assert_eq!(u8::checked_stirling_number_1(6, 2), None);
+/// assert_eq!(i8::checked_stirling_number_1(5, 2), Some(-50));
+/// assert_eq!(i8::checked_stirling_number_1(6, 3), None);
+/// ```
+///
+/// # stirling_number_2
+/// ```
+/// use malachite_base::num::arithmetic::traits::StirlingNumber2;
+///
+/// assert_eq!(u8::stirling_number_2(0, 0), 1);
+/// assert_eq!(u8::stirling_number_2(5, 2), 15);
+/// assert_eq!(u16::stirling_number_2(10, 3), 9330);
+/// assert_eq!(u16::stirling_number_2(10, 5), 42525);
+/// assert_eq!(i8::stirling_number_2(5, 2), 15);
+/// ```
+///
+/// # checked_stirling_number_2
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedStirlingNumber2;
+///
+/// assert_eq!(u8::checked_stirling_number_2(10, 3), None);
+/// assert_eq!(u16::checked_stirling_number_2(10, 3), Some(9330));
+/// assert_eq!(u64::checked_stirling_number_2(20, 10), Some(5917584964655));
+/// assert_eq!(i16::checked_stirling_number_2(10, 5), None);
+/// ```
+pub mod stirling_number;
 /// [`SubMul`](traits::SubMul) and [`SubMulAssign`](traits::SubMulAssign), traits for subtracting
 /// the product of two numbers from a number.
 ///
@@ -0,0 +1,403 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::mod_sqrt::mod_pow_full_width;
+use crate::num::arithmetic::traits::{ModLog, ModLogWithOrderFactorization, ModMulPrecomputed};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Factor;
+use alloc::vec::Vec;
+
+// A prime-order subproblem whose order has at most this many bits is solved by baby-step
+// giant-step, which stores about $\sqrt q$ values; larger ones are solved by Pollard rho, which
+// stores none.
+const MAX_BSGS_ORDER_BITS: u64 = 32;
+
+// When the group may not be cyclic, the number of times Pollard rho is restarted after a collision
+// that gives no information before the target is taken to lie outside the subgroup.
+const RHO_ATTEMPTS: u64 = 8;
+
+// Finds the $d < q$ with $\gamma^d = h$, where $\gamma$ has prime order $q$, by baby-step
+// giant-step, returning `None` if there is none.
+fn bsgs<T: PrimitiveUnsigned>(
+    gamma: T,
+    h: T,
+    q: T,
+    m: T,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> Option<T> {
+    let s = q.ceiling_sqrt();
+    let mut baby = Vec::with_capacity(s.exact_into());
+    let mut y = T::ONE;
+    let mut j = T::ZERO;
+    while j < s {
+        if y == h {
+            return Some(j);
+        }
+        baby.push((y, j));
+        y.mod_mul_precomputed_assign(gamma, m, data);
+        j += T::ONE;
+    }
+    baby.sort_unstable();
+    // gamma ^ (-s) = gamma ^ (q - s), since s <= q
+    let giant = mod_pow_full_width(gamma, q - s, m, data);
+    let mut z = h;
+    let mut i = T::ZERO;
+    while i < s {
+        z.mod_mul_precomputed_assign(giant, m, data);
+        i += T::ONE;
+        if let Ok(index) = baby.binary_search_by(|&(y, _)| y.cmp(&z)) {
+            return Some(i * s + baby[index].1);
+        }
+    }
+    None
+}
+
+// Finds the $d < q$ with $\gamma^d = h$, where $\gamma$ has prime order $q$ and $h^q = 1$, by
+// Pollard rho with Floyd cycle detection.
+//
+// The walk multiplies by $\gamma$, multiplies by $h$, or squares, depending on the current element
+// modulo 3, keeping track of the exponents $a$ and $b$ in $\gamma^a h^b$. A collision $\gamma^{a_1}
+// h^{b_1} = \gamma^{a_2} h^{b_2}$ with $b_1 \neq b_2$ determines $d$. If $h$ is outside the
+// subgroup generated by $\gamma$, every collision has $a_1 = a_2$ and $b_1 = b_2$, and if $h$ is
+// inside, such a collision is a coincidence with probability about $1/q$; in a cyclic group $h$ is
+// always inside, so the walk is simply restarted, and otherwise it is given up after
+// `RHO_ATTEMPTS` restarts.
+fn rho<T: PrimitiveUnsigned>(
+    gamma: T,
+    h: T,
+    q: T,
+    m: T,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+    cyclic: bool,
+) -> Option<T> {
+    let three = T::from(3u8);
+    let step = |(y, a, b): (T, T, T)| -> (T, T, T) {
+        match (y % three).wrapping_into() {
+            0u8 => (
+                y.mod_mul_precomputed(y, m, data),
+                a.mod_add(a, q),
+                b.mod_add(b, q),
+            ),
+            1 => (
+                y.mod_mul_precomputed(gamma, m, data),
+                a.mod_add(T::ONE, q),
+                b,
+            ),
+            _ => (y.mod_mul_precomputed(h, m, data), a, b.mod_add(T::ONE, q)),
+        }
+    };
+    let mut attempt = 0u64;
+    loop {
+        attempt += 1;
+        // Since q > 2 ^ 32, the starting exponents differ between attempts.
+        let a = T::wrapping_from(attempt);
+        let start = (
+            mod_pow_full_width(gamma, a, m, data).mod_mul_precomputed(h, m, data),
+            a,
+            T::ONE,
+        );
+        let mut tortoise = step(start);
+        let mut hare = step(tortoise);
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        let db = hare.2.mod_sub(tortoise.2, q);
+        if db != T::ZERO {
+            let da = tortoise.1.mod_sub(hare.1, q);
+            return Some(da.mod_mul(db.mod_inverse(q).unwrap(), q));
+        }
+        if !cyclic && attempt == RHO_ATTEMPTS {
+            return None;
+        }
+    }
+}
+
+// Combines $x \equiv x_1 \pmod {n_1}$ and $x \equiv x_2 \pmod {n_2}$, where the moduli need not be
+// coprime, into a congruence modulo $\operatorname{lcm}(n_1, n_2)$, or returns `None` if they are
+// inconsistent. The least common multiple must be representable.
+fn combine<T: PrimitiveUnsigned>(x_1: T, n_1: T, x_2: T, n_2: T) -> Option<(T, T)> {
+    let g = n_1.gcd(n_2);
+    if x_1 % g != x_2 % g {
+        return None;
+    }
+    let n_2_g = n_2 / g;
+    if n_2_g == T::ONE {
+        return Some((x_1, n_1));
+    }
+    // Solve (n_1 / g) * k = (x_2 - x_1) / g mod n_2 / g; then x = x_1 + n_1 * k.
+    let d = if x_2 >= x_1 {
+        ((x_2 - x_1) / g) % n_2_g
+    } else {
+        (((x_1 - x_2) / g) % n_2_g).mod_neg(n_2_g)
+    };
+    let k = d.mod_mul(
+        ((n_1 / g) % n_2_g).mod_inverse(n_2_g).unwrap(),
+        n_2_g,
+    );
+    Some((x_1 + n_1 * k, n_1 * n_2_g))
+}
+
+// Computes the discrete logarithm of `x` to the base `b` modulo `m`, where `b` is coprime to `m`
+// and `b ^ N = 1`, with `N` the product described by `order_factors`. Returns the logarithm, which
+// is less than the order of `b`, together with that order.
+//
+// The order of `b` is found by removing prime factors from `N` while `b` raised to the quotient is
+// still 1. The logarithm is then found by Pohlig-Hellman: for each prime power $q^e$ exactly
+// dividing the order $n$, the logarithm modulo $q^e$ is found one base-$q$ digit at a time, in the
+// subgroup of order $q$ generated by $b^{n/q}$, and the results are combined by the Chinese
+// remainder theorem. A final check discards a result when `x` is not a power of `b`, which in a
+// group that is not cyclic can go undetected by the digit computations.
+fn mod_log_coprime<T: PrimitiveUnsigned>(
+    x: T,
+    b: T,
+    m: T,
+    order_factors: &[(T, u64)],
+    cyclic: bool,
+) -> Option<(T, T)> {
+    if m == T::ONE {
+        return Some((T::ZERO, T::ONE));
+    }
+    let data = T::precompute_mod_mul_data(&m);
+    let mut n = T::ONE;
+    for &(q, e) in order_factors {
+        n = q
+            .checked_pow(e)
+            .and_then(|qe| n.checked_mul(qe))
+            .expect("the product of order_factors must be representable");
+    }
+    assert_eq!(
+        mod_pow_full_width(b, n, m, &data),
+        T::ONE,
+        "order_factors must describe a multiple of the order of the base"
+    );
+    let mut factors = Vec::with_capacity(order_factors.len());
+    for &(q, e) in order_factors {
+        let mut e = e;
+        while e != 0 && mod_pow_full_width(b, n / q, m, &data) == T::ONE {
+            n /= q;
+            e -= 1;
+        }
+        if e != 0 {
+            factors.push((q, e));
+        }
+    }
+    let mut log = T::ZERO;
+    let mut modulus = T::ONE;
+    for (q, e) in factors {
+        let qe = q.pow(e);
+        let cofactor = n / qe;
+        let g = mod_pow_full_width(b, cofactor, m, &data);
+        let h = mod_pow_full_width(x, cofactor, m, &data);
+        let gamma = mod_pow_full_width(g, qe / q, m, &data);
+        let g_inverse = g.mod_inverse(m).unwrap();
+        let mut log_q = T::ZERO;
+        let mut q_power = T::ONE;
+        for k in 0..e {
+            // (g ^ (-log_q) * h) ^ (q ^ (e - 1 - k)) has order dividing q if x is a power of b
+            let y = mod_pow_full_width(g_inverse, log_q, m, &data).mod_mul_precomputed(h, m, &data);
+            let h_k = mod_pow_full_width(y, q.pow(e - 1 - k), m, &data);
+            let d = if q.significant_bits() <= MAX_BSGS_ORDER_BITS {
+                bsgs(gamma, h_k, q, m, &data)?
+            } else {
+                if mod_pow_full_width(h_k, q, m, &data) != T::ONE {
+                    return None;
+                }
+                rho(gamma, h_k, q, m, &data, cyclic)?
+            };
+            log_q += d * q_power;
+            if k != e - 1 {
+                q_power *= q;
+            }
+        }
+        (log, modulus) = combine(log, modulus, log_q, qe).unwrap();
+    }
+    if mod_pow_full_width(b, log, m, &data) == x {
+        Some((log, n))
+    } else {
+        None
+    }
+}
+
+// Computes the smallest $k$ with $b^k \equiv x \pmod m$, where `x` and `b` must be reduced modulo
+// `m`.
+//
+// While $g = \gcd(b, m) > 1$, the congruence $b^k \equiv x$ with $k \geq 1$ is equivalent to $(b/g)
+// b^{k-1} \equiv x/g \pmod {m/g}$, and has no solutions unless $g \mid x$; each such step removes a
+// factor from $m$, after checking whether the exponent reached so far is itself a solution. Once
+// $b$ is coprime to $m$, the modulus is split into prime powers $p^e$, whose multiplicative groups
+// are cyclic for odd $p$, with orders dividing $p^{e-1}(p-1)$; the logarithm is found modulo the
+// order of $b$ in each, and these congruences are combined. For $p = 2$ the group need not be
+// cyclic, but all its prime-order subproblems have order 2, and are solved exactly by baby-step
+// giant-step.
+//
+// # Worst-case complexity
+// $T(n) = O(2^{n/2} n^2)$
+//
+// $M(n) = O(2^{n/4})$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`. The bounds are
+// reached when the order of `b` has a large prime factor; the time bound for Pollard rho is
+// heuristic.
+private_test_fn! {mod_log_unsigned<T: Factor + PrimitiveUnsigned>(x: T, b: T, m: T) -> Option<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    assert!(b < m, "b must be reduced mod m, but {b} >= {m}");
+    if m == T::ONE {
+        return Some(T::ZERO);
+    }
+    let mut x = x;
+    let mut m = m;
+    // k = b ^ add / (the product of the gs removed so far), modulo m
+    let mut k = T::ONE;
+    let mut add = T::ZERO;
+    loop {
+        let g = b.gcd(m);
+        if g == T::ONE {
+            break;
+        }
+        if x == k {
+            return Some(add);
+        }
+        if x % g != T::ZERO {
+            return None;
+        }
+        x /= g;
+        m /= g;
+        add += T::ONE;
+        k = (k % m).mod_mul((b / g) % m, m);
+    }
+    if m == T::ONE {
+        return Some(add);
+    }
+    let b = b % m;
+    let x = x.mod_mul(k.mod_inverse(m).unwrap(), m);
+    let mut log = T::ZERO;
+    let mut order = T::ONE;
+    for (p, e) in m.factor() {
+        let e = u64::from(e);
+        let pe = p.pow(e);
+        let mut order_factors: Vec<(T, u64)> = (p - T::ONE)
+            .factor()
+            .into_iter()
+            .map(|(q, f)| (q, u64::from(f)))
+            .collect();
+        if e > 1 {
+            order_factors.push((p, e - 1));
+        }
+        let (log_p, order_p) = mod_log_coprime(x % pe, b % pe, pe, &order_factors, true)?;
+        (log, order) = combine(log, order, log_p, order_p)?;
+    }
+    Some(log + add)
+}}
+
+// Computes the smallest $k$ with $b^k \equiv x \pmod m$, given the prime factorization of a
+// multiple of the order of `b`, where `x` and `b` must be reduced modulo `m`.
+private_test_fn! {mod_log_with_order_factorization_unsigned<T: PrimitiveUnsigned>(
+    x: T,
+    b: T,
+    m: T,
+    order_factors: &[(T, u64)],
+) -> Option<T> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    assert!(b < m, "b must be reduced mod m, but {b} >= {m}");
+    mod_log_coprime(x, b, m, order_factors, false).map(|(log, _)| log)
+}}
+
+macro_rules! impl_mod_log {
+    ($t:ident) => {
+        impl ModLog<$t> for $t {
+            type Output = $t;
+
+            /// Computes the discrete logarithm of a number to a base $b$, modulo another number
+            /// $m$: the smallest $k$ with $b^k \equiv x \pmod m$, or `None` if there is none. The
+            /// number and the base must be already reduced modulo $m$.
+            ///
+            /// The base need not be coprime to $m$. The modulus is factored, and the logarithm is
+            /// found in the multiplicative group modulo each prime-power factor by the
+            /// Pohlig-Hellman algorithm, with baby-step giant-step or Pollard rho for the
+            /// subproblems of prime order. The cost is dominated by the largest prime factor of the
+            /// order of $b$.
+            ///
+            /// $f(x, b, m) = \min \\{k \in \N : b^k \equiv x \pmod m\\}$, if the set is nonempty.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/2} n^2)$
+            ///
+            /// $M(n) = O(2^{n/4})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` or `base` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_log#mod_log).
+            #[inline]
+            fn mod_log(self, base: $t, m: $t) -> Option<$t> {
+                mod_log_unsigned(self, base, m)
+            }
+        }
+    };
+}
+impl_mod_log!(u8);
+impl_mod_log!(u16);
+impl_mod_log!(u32);
+impl_mod_log!(u64);
+impl_mod_log!(usize);
+
+macro_rules! impl_mod_log_with_order_factorization {
+    ($t:ident) => {
+        impl ModLogWithOrderFactorization<$t> for $t {
+            type Output = $t;
+
+            /// Computes the discrete logarithm of a number to a base $b$, modulo another number
+            /// $m$, given the prime factorization of a multiple of the multiplicative order of $b$:
+            /// the smallest $k$ with $b^k \equiv x \pmod m$, or `None` if there is none. The number
+            /// and the base must be already reduced modulo $m$.
+            ///
+            /// The factorization is given as pairs $(q, e)$ of distinct primes and their exponents.
+            /// Their product $N$ must satisfy $b^N \equiv 1 \pmod m$; if $m$ is prime, $m - 1$
+            /// always works. The logarithm is found by the Pohlig-Hellman algorithm, with baby-step
+            /// giant-step or Pollard rho for the subproblems of prime order.
+            ///
+            /// If the multiplicative group modulo $m$ is cyclic, which it is when $m$ is 2, 4, or a
+            /// power or twice a power of an odd prime, the result is exact. Otherwise, when the
+            /// order of $b$ has a prime factor $q$ greater than $2^{32}$, a logarithm may be missed
+            /// with probability about $q^{-8}$. [`ModLog`] has no such caveat.
+            ///
+            /// $f(x, b, m) = \min \\{k \in \N : b^k \equiv x \pmod m\\}$, if the set is nonempty.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/2} n^2)$
+            ///
+            /// $M(n) = O(2^{n/4})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` or `base` is greater than or equal to `m`, if the product of the
+            /// factors is not representable, or if `base` raised to it is not 1 modulo $m$.
+            ///
+            /// # Examples
+            /// See [here](super::mod_log#mod_log_with_order_factorization).
+            #[inline]
+            fn mod_log_with_order_factorization(
+                self,
+                base: $t,
+                m: $t,
+                order_factors: &[($t, u64)],
+            ) -> Option<$t> {
+                mod_log_with_order_factorization_unsigned(self, base, m, order_factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_log_with_order_factorization);
@@ -0,0 +1,127 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::mod_sqrt::mod_pow_full_width;
+use crate::num::arithmetic::traits::ModMultiplicativeOrder;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Factor;
+use alloc::vec::Vec;
+
+// Returns the prime factorization of $m$, with the exponents widened to `u64`.
+pub(crate) fn factor_unsigned<T: Factor + PrimitiveUnsigned>(m: T) -> Vec<(T, u64)>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    m.factor()
+        .into_iter()
+        .map(|(p, e)| (p, u64::from(e)))
+        .collect()
+}
+
+// Given the prime factorization of $m$, returns the prime factorization of $\varphi(m)$, Euler's
+// totient function, which is the product of $p^{e-1}(p-1)$ over the prime powers $p^e$ exactly
+// dividing $m$. The primes are not sorted.
+pub(crate) fn totient_factors<T: Factor + PrimitiveUnsigned>(
+    m_factors: &[(T, u64)],
+) -> Vec<(T, u64)>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    let mut factors: Vec<(T, u64)> = Vec::new();
+    let mut insert = |q: T, e: u64| {
+        if let Some((_, f)) = factors.iter_mut().find(|(r, _)| *r == q) {
+            *f += e;
+        } else {
+            factors.push((q, e));
+        }
+    };
+    for &(p, e) in m_factors {
+        if e > 1 {
+            insert(p, e - 1);
+        }
+        for (q, f) in (p - T::ONE).factor() {
+            insert(q, u64::from(f));
+        }
+    }
+    factors
+}
+
+// Computes the multiplicative order of `x` modulo `m`, where `x` must be reduced modulo `m`.
+//
+// The order divides $\varphi(m)$, so starting from $\varphi(m)$, each prime factor is divided out
+// for as long as `x` raised to the quotient is still 1.
+fn mod_multiplicative_order_unsigned<T: Factor + PrimitiveUnsigned>(x: T, m: T) -> Option<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    if x.gcd(m) != T::ONE {
+        return None;
+    }
+    if m == T::ONE {
+        return Some(T::ONE);
+    }
+    let phi_factors = totient_factors(&factor_unsigned(m));
+    let mut order = T::ONE;
+    for &(q, e) in &phi_factors {
+        order *= q.pow(e);
+    }
+    let data = T::precompute_mod_mul_data(&m);
+    for (q, e) in phi_factors {
+        for _ in 0..e {
+            let quotient = order / q;
+            if mod_pow_full_width(x, quotient, m, &data) != T::ONE {
+                break;
+            }
+            order = quotient;
+        }
+    }
+    Some(order)
+}
+
+macro_rules! impl_mod_multiplicative_order {
+    ($t:ident) => {
+        impl ModMultiplicativeOrder<$t> for $t {
+            type Output = $t;
+
+            /// Computes the multiplicative order of a number modulo another number $m$: the
+            /// smallest positive $k$ with $x^k \equiv 1 \pmod m$. Returns `None` if $x$ is not
+            /// coprime to $m$, since then no such $k$ exists. The input must be already reduced
+            /// modulo $m$.
+            ///
+            /// The modulus is factored to find $\varphi(m)$, which the order divides, and each of
+            /// the prime factors of $\varphi(m)$ is then removed for as long as the remaining
+            /// exponent still takes $x$ to 1. Modulo 1, the order of 0 is 1.
+            ///
+            /// $f(x, m) = \min \\{k \in \Z^+ : x^k \equiv 1 \pmod m\\}$, if $\gcd(x, m) = 1$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`: the
+            /// cost is dominated by factoring $m$ and $p - 1$ for each prime $p$ dividing $m$.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_multiplicative_order#mod_multiplicative_order).
+            #[inline]
+            fn mod_multiplicative_order(self, m: $t) -> Option<$t> {
+                mod_multiplicative_order_unsigned(self, m)
+            }
+        }
+    };
+}
+impl_mod_multiplicative_order!(u8);
+impl_mod_multiplicative_order!(u16);
+impl_mod_multiplicative_order!(u32);
+impl_mod_multiplicative_order!(u64);
+impl_mod_multiplicative_order!(usize);
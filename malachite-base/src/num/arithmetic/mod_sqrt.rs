@@ -20,7 +20,7 @@ use crate::num::conversion::traits::WrappingFrom;
 // Modular exponentiation by binary exponentiation, with an exponent of the full width of `T`. The
 // standard `ModPow` implementations take a `u64` exponent, which is too narrow for `u128` moduli.
 // The multiplications share the caller's precomputed data.
-fn mod_pow_full_width<T: PrimitiveUnsigned>(
+pub(crate) fn mod_pow_full_width<T: PrimitiveUnsigned>(
     x: T,
     exp: T,
     m: T,
@@ -102,6 +102,20 @@ fn mul_shr_round_unsigned<T: PrimitiveUnsigned>(
                 (k, Less)
             }
         }
+        NearestTiesAway => {
+            if top {
+                round_up(k)
+            } else {
+                (k, Less)
+            }
+        }
+        Odd => {
+            if k.odd() {
+                (k, Less)
+            } else {
+                round_up(k)
+            }
+        }
     }
 }
 
@@ -205,8 +219,11 @@ macro_rules! impl_mul_shr_round_unsigned_unsigned {
                     /// Let $q = \frac{xy}{2^k}$. Then $f(x, y, k, \mathrm{Down}) = f(x, y, k,
                     /// \mathrm{Floor}) = \lfloor q \rfloor$ and $f(x, y, k, \mathrm{Up}) = f(x, y,
                     /// k, \mathrm{Ceiling}) = \lceil q \rceil$; $\mathrm{Nearest}$ rounds to the
-                    /// integer closest to $q$, breaking ties toward the even integer; and $f(x, y,
-                    /// k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
+                    /// integer closest to $q$, breaking ties toward the even integer;
+                    /// $\mathrm{NearestTiesAway}$ does the same but breaks ties away from zero;
+                    /// $\mathrm{Odd}$ rounds an inexact $q$ to whichever of $\lfloor q \rfloor$
+                    /// and $\lceil q \rceil$ is odd; and $f(x, y, k, \mathrm{Exact}) = q$, but
+                    /// panics if $q \notin \Z$.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -287,8 +304,10 @@ macro_rules! impl_mul_shr_round_signed_unsigned {
                     /// \rfloor$, $f(x, y, k, \mathrm{Ceiling}) = \lceil q \rceil$, and
                     /// $\mathrm{Down}$ and $\mathrm{Up}$ round toward and away from zero,
                     /// respectively; $\mathrm{Nearest}$ rounds to the integer closest to $q$,
-                    /// breaking ties toward the even integer; and $f(x, y, k, \mathrm{Exact}) = q$,
-                    /// but panics if $q \notin \Z$.
+                    /// breaking ties toward the even integer; $\mathrm{NearestTiesAway}$ does the
+                    /// same but breaks ties away from zero; $\mathrm{Odd}$ rounds an inexact $q$
+                    /// to whichever of $\lfloor q \rfloor$ and $\lceil q \rceil$ is odd; and $f(x,
+                    /// y, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
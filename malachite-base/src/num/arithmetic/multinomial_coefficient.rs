@@ -0,0 +1,99 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{CheckedMultinomialCoefficient, MultinomialCoefficient};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use alloc::vec::Vec;
+
+// Computes the multinomial coefficient as a product of binomial coefficients,
+//
+// (k_1 + ... + k_m)! / (k_1! ... k_m!) = prod_{i = 2}^m C(k_1 + ... + k_i, k_i),
+//
+// with the largest part taken first so that the binomial coefficients stay small. Every factor is
+// at most the result, so a checked operation fails only if the result itself overflows. Zero parts
+// contribute nothing, and once they are dropped, two or more remaining parts make the result at
+// least their sum, so a sum that doesn't fit means a result that doesn't fit.
+fn checked_multinomial_coefficient_unsigned<T: PrimitiveUnsigned>(ks: &[u64]) -> Option<T> {
+    let mut ks: Vec<u64> = ks.iter().copied().filter(|&k| k != 0).collect();
+    if ks.len() < 2 {
+        return Some(T::ONE);
+    }
+    ks.sort_unstable();
+    let mut sum = T::try_from(ks.pop().unwrap()).ok()?;
+    let mut product = T::ONE;
+    for k in ks.into_iter().rev() {
+        let k = T::try_from(k).ok()?;
+        sum = sum.checked_add(k)?;
+        product = product.checked_mul(T::checked_binomial_coefficient(sum, k)?)?;
+    }
+    Some(product)
+}
+
+macro_rules! impl_multinomial_coefficient {
+    ($t:ident) => {
+        impl CheckedMultinomialCoefficient for $t {
+            /// Computes the multinomial coefficient of $k_1, \ldots, k_m$: the number of ways to
+            /// divide $k_1 + \cdots + k_m$ distinct objects into groups of sizes $k_1, \ldots,
+            /// k_m$.
+            ///
+            /// If the result is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(k_1, \ldots, k_m) = \binom{k_1 + \cdots + k_m}{k_1, \ldots, k_m} =
+            /// \frac{(k_1 + \cdots + k_m)!}{k_1! \cdots k_m!}.
+            /// $$
+            ///
+            /// The empty list has multinomial coefficient 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `ks.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::multinomial_coefficient#checked_multinomial_coefficient).
+            #[inline]
+            fn checked_multinomial_coefficient(ks: &[u64]) -> Option<$t> {
+                checked_multinomial_coefficient_unsigned(ks)
+            }
+        }
+
+        impl MultinomialCoefficient for $t {
+            /// Computes the multinomial coefficient of $k_1, \ldots, k_m$: the number of ways to
+            /// divide $k_1 + \cdots + k_m$ distinct objects into groups of sizes $k_1, \ldots,
+            /// k_m$.
+            ///
+            /// $$
+            /// f(k_1, \ldots, k_m) = \binom{k_1 + \cdots + k_m}{k_1, \ldots, k_m} =
+            /// \frac{(k_1 + \cdots + k_m)!}{k_1! \cdots k_m!}.
+            /// $$
+            ///
+            /// The empty list has multinomial coefficient 1.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `ks.len()`.
+            ///
+            /// # Panics
+            /// Panics if the result is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::multinomial_coefficient#multinomial_coefficient).
+            #[inline]
+            fn multinomial_coefficient(ks: &[u64]) -> $t {
+                $t::checked_multinomial_coefficient(ks).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_multinomial_coefficient);
@@ -0,0 +1,113 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{CheckedPartitionNumber, PartitionNumber};
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::ExactFrom;
+use alloc::vec;
+
+// The largest n for which p(n) fits in an unsigned type of width 8, 16, 32, 64, and 128, checked
+// against OEIS A000041.
+const fn max_partition_number_index(width: u64) -> u64 {
+    match width {
+        8 => 16,
+        16 => 43,
+        32 => 127,
+        64 => 416,
+        128 => 1458,
+        _ => panic!(),
+    }
+}
+
+// Computes p(0) through p(n) with Euler's pentagonal number theorem,
+//
+// p(i) = sum_{j >= 1} (-1)^(j + 1) (p(i - j(3j - 1) / 2) + p(i - j(3j + 1) / 2)),
+//
+// and returns p(n). The partial sums may exceed the width of `T`, but the final value of each p(i)
+// is no larger than p(n), so if p(n) fits, wrapping arithmetic gives every value exactly.
+fn partition_number_pentagonal<T: PrimitiveUnsigned>(n: u64) -> T {
+    let n = usize::exact_from(n);
+    let mut ps = vec![T::ZERO; n + 1];
+    ps[0] = T::ONE;
+    for i in 1..=n {
+        let mut sum = T::ZERO;
+        let mut j = 1;
+        loop {
+            let g = j * (3 * j - 1) / 2;
+            if g > i {
+                break;
+            }
+            let mut term = ps[i - g];
+            let h = g + j;
+            if h <= i {
+                term.wrapping_add_assign(ps[i - h]);
+            }
+            if j & 1 == 1 {
+                sum.wrapping_add_assign(term);
+            } else {
+                sum.wrapping_sub_assign(term);
+            }
+            j += 1;
+        }
+        ps[i] = sum;
+    }
+    ps[n]
+}
+
+macro_rules! impl_partition_number {
+    ($t:ident) => {
+        impl CheckedPartitionNumber for $t {
+            /// Computes the number of partitions of $n$: the number of ways to write $n$ as a sum
+            /// of positive integers, disregarding order.
+            ///
+            /// If the result is too large to be represented, the function returns `None`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{3/2})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Examples
+            /// See [here](super::partition_number#checked_partition_number).
+            #[inline]
+            fn checked_partition_number(n: u64) -> Option<$t> {
+                if n > const { max_partition_number_index(<$t as PrimitiveInt>::WIDTH) } {
+                    None
+                } else {
+                    Some(partition_number_pentagonal(n))
+                }
+            }
+        }
+
+        impl PartitionNumber for $t {
+            /// Computes the number of partitions of $n$: the number of ways to write $n$ as a sum
+            /// of positive integers, disregarding order.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{3/2})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if the result is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::partition_number#partition_number).
+            #[inline]
+            fn partition_number(n: u64) -> $t {
+                $t::checked_partition_number(n).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_partition_number);
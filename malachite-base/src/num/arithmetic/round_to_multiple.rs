@@ -21,7 +21,7 @@ fn round_to_multiple_unsigned<T: PrimitiveUnsigned>(
     match (x, other) {
         (x, y) if x == y => (x, Equal),
         (x, y) if y == T::ZERO => match rm {
-            Down | Floor | Nearest => (T::ZERO, Less),
+            Down | Floor | Nearest | NearestTiesAway => (T::ZERO, Less),
             _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
         },
         (x, y) => {
@@ -54,6 +54,20 @@ fn round_to_multiple_unsigned<T: PrimitiveUnsigned>(
                             }
                         }
                     }
+                    NearestTiesAway => {
+                        if r < y - r {
+                            (floor, Less)
+                        } else {
+                            (floor.checked_add(y).unwrap(), Greater)
+                        }
+                    }
+                    Odd => {
+                        if (x / y).odd() {
+                            (floor, Less)
+                        } else {
+                            (floor.checked_add(y).unwrap(), Greater)
+                        }
+                    }
                     Exact => {
                         panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                     }
@@ -96,6 +110,21 @@ macro_rules! impl_round_to_multiple_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, y, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, y, \mathrm{Odd}) = \begin{cases}
+            ///     x & \text{if} \\quad q \in \Z, \\\\
+            ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
             ///
             /// The following two expressions are equivalent:
@@ -110,7 +139,8 @@ macro_rules! impl_round_to_multiple_unsigned {
             /// # Panics
             /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
             /// - If the multiple is outside the representable range.
-            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero
+            ///   or is `Odd`.
             ///
             /// # Examples
             /// See [here](super::round_to_multiple#round_to_multiple).
@@ -142,7 +172,8 @@ macro_rules! impl_round_to_multiple_unsigned {
             /// # Panics
             /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
             /// - If the multiple is outside the representable range.
-            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero
+            ///   or is `Odd`.
             ///
             /// # Examples
             /// See [here](super::round_to_multiple#round_to_multiple_assign).
@@ -219,6 +250,21 @@ macro_rules! impl_round_to_multiple_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, y, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, y, \mathrm{Odd}) = \begin{cases}
+            ///     x & \text{if} \\quad q \in \Z, \\\\
+            ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// The following two expressions are equivalent:
@@ -233,7 +279,8 @@ macro_rules! impl_round_to_multiple_signed {
             /// # Panics
             /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
             /// - If the multiple is outside the representable range.
-            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero
+            ///   or is `Odd`.
             ///
             /// # Examples
             /// See [here](super::round_to_multiple#round_to_multiple).
@@ -264,7 +311,8 @@ macro_rules! impl_round_to_multiple_signed {
             /// # Panics
             /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
             /// - If the multiple is outside the representable range.
-            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+            /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero
+            ///   or is `Odd`.
             ///
             /// # Examples
             /// See [here](super::round_to_multiple#round_to_multiple_assign).
@@ -56,6 +56,21 @@ macro_rules! impl_round_to_multiple_of_power_of_2 {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) =
+            ///     2^k \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{Odd}) = \begin{cases}
+            ///     x & \text{if} \\quad q \in \Z, \\\\
+            ///     2^k \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     2^k \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
             ///
             /// The following two expressions are equivalent:
@@ -115,7 +130,8 @@ macro_rules! impl_round_to_multiple_of_power_of_2 {
             ///
             /// # Examples
             /// See
-            /// [here](super::round_to_multiple_of_power_of_2#round_to_multiple_of_power_of_2_assign).
+            /// [here](super::round_to_multiple_of_power_of_2#
+            /// round_to_multiple_of_power_of_2_assign).
             #[inline]
             fn round_to_multiple_of_power_of_2_assign(
                 &mut self,
@@ -101,6 +101,21 @@ macro_rules! impl_shl_round {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $$
+                    /// g(x, k, \mathrm{NearestTiesAway}) =
+                    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+                    /// $$
+                    ///
+                    /// $$
+                    /// g(x, k, \mathrm{Odd}) = \begin{cases}
+                    ///     q & \text{if} \\quad q \in \Z, \\\\
+                    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+                    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+                    /// \end{cases}
+                    /// $$
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
                     ///
                     /// Then
@@ -64,6 +64,37 @@ fn shr_round_unsigned_unsigned<
                 }
             }
         }
+        NearestTiesAway if bits == width && x >= T::power_of_2(T::WIDTH - 1) => (T::ONE, Greater),
+        NearestTiesAway if bits >= width => (T::ZERO, Less),
+        NearestTiesAway => {
+            let bm1 = bits - U::ONE;
+            let mostly_shifted = x >> bm1;
+            if mostly_shifted.even() {
+                // round down
+                (
+                    mostly_shifted >> 1,
+                    if mostly_shifted << bm1 == x {
+                        Equal
+                    } else {
+                        Less
+                    },
+                )
+            } else {
+                // round up, including when the result is half-integer
+                ((mostly_shifted >> 1) + T::ONE, Greater)
+            }
+        }
+        Odd if bits >= width => (T::ONE, Greater),
+        Odd => {
+            let shifted = x >> bits;
+            if shifted << bits == x {
+                (shifted, Equal)
+            } else if shifted.odd() {
+                (shifted, Less)
+            } else {
+                (shifted + T::ONE, Greater)
+            }
+        }
         Exact if bits >= width => {
             panic!("Right shift is not exact: {x} >> {bits}");
         }
@@ -146,6 +177,46 @@ fn shr_round_assign_unsigned_unsigned<
                 }
             }
         }
+        NearestTiesAway if bits == width && *x >= T::power_of_2(T::WIDTH - 1) => {
+            *x = T::ONE;
+            Greater
+        }
+        NearestTiesAway if bits >= width => {
+            *x = T::ZERO;
+            Less
+        }
+        NearestTiesAway => {
+            let original = *x;
+            let bm1 = bits - U::ONE;
+            *x >>= bm1;
+            let bm1_zeros = *x << bm1 == original;
+            let old_x = *x;
+            *x >>= 1;
+            if old_x.even() {
+                // round down
+                if bm1_zeros { Equal } else { Less }
+            } else {
+                // round up, including when the result is half-integer
+                *x += T::ONE;
+                Greater
+            }
+        }
+        Odd if bits >= width => {
+            *x = T::ONE;
+            Greater
+        }
+        Odd => {
+            let original = *x;
+            *x >>= bits;
+            if *x << bits == original {
+                Equal
+            } else if x.odd() {
+                Less
+            } else {
+                *x += T::ONE;
+                Greater
+            }
+        }
         Exact if bits >= width => {
             panic!("Right shift is not exact: {} >>= {}", *x, bits);
         }
@@ -198,6 +269,21 @@ macro_rules! impl_shr_round_unsigned_unsigned {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $$
+                    /// g(x, k, \mathrm{NearestTiesAway}) =
+                    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+                    /// $$
+                    ///
+                    /// $$
+                    /// g(x, k, \mathrm{Odd}) = \begin{cases}
+                    ///     q & \text{if} \\quad q \in \Z, \\\\
+                    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+                    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+                    /// \end{cases}
+                    /// $$
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
                     ///
                     /// Then
@@ -314,6 +400,21 @@ macro_rules! impl_shr_round_signed_unsigned {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $$
+                    /// g(x, k, \mathrm{NearestTiesAway}) =
+                    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+                    /// $$
+                    ///
+                    /// $$
+                    /// g(x, k, \mathrm{Odd}) = \begin{cases}
+                    ///     q & \text{if} \\quad q \in \Z, \\\\
+                    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+                    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+                    /// \end{cases}
+                    /// $$
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
                     ///
                     /// Then
@@ -454,6 +555,21 @@ macro_rules! impl_shr_round_primitive_signed {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $$
+                    /// g(x, k, \mathrm{NearestTiesAway}) =
+                    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+                    /// $$
+                    ///
+                    /// $$
+                    /// g(x, k, \mathrm{Odd}) = \begin{cases}
+                    ///     q & \text{if} \\quad q \in \Z, \\\\
+                    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+                    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+                    /// \end{cases}
+                    /// $$
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
                     ///
                     /// Then
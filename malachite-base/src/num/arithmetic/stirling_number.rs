@@ -0,0 +1,275 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{
+    CheckedStirlingNumber1, CheckedStirlingNumber2, Parity, StirlingNumber1, StirlingNumber2,
+    UnsignedAbs,
+};
+use crate::num::basic::signeds::PrimitiveSigned;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::{ExactFrom, OverflowingFrom};
+use alloc::vec;
+
+// Computes an unsigned Stirling number of the first kind (if `first_kind` is true) or a Stirling
+// number of the second kind, returning `None` on overflow.
+//
+// Both triangles satisfy X(i + 1, j) = f * X(i, j) + X(i, j - 1), with f = i for the first kind and
+// f = j for the second, and both are nondecreasing along the steps (i, j) -> (i + 1, j) and (i, j)
+// -> (i + 1, j + 1) away from column 0. Every value that the band recurrence below computes is
+// therefore at most the result, so a checked operation fails only if the result itself overflows.
+fn checked_stirling_number_unsigned<T: PrimitiveUnsigned>(
+    n: u64,
+    k: u64,
+    first_kind: bool,
+) -> Option<T> {
+    if k > n {
+        return Some(T::ZERO);
+    } else if k == n {
+        return Some(T::ONE);
+    } else if k == 0 {
+        return Some(T::ZERO);
+    } else if k == 1 {
+        // c(n, 1) = (n - 1)! and S(n, 1) = 1
+        return if first_kind {
+            T::checked_factorial(n - 1)
+        } else {
+            Some(T::ONE)
+        };
+    }
+    // From here on 2 <= k < n. The rows of both triangles are unimodal, so the result is at least
+    // min(X(n, 2), X(n, n - 1)) >= min(2^(n - 1) - 1, C(n, 2)), which exceeds n once n >= 4; if n
+    // doesn't fit, neither does the result.
+    let d = n - k;
+    let n_t = T::try_from(n).ok()?;
+    let binomial = |i: u64| T::checked_binomial_coefficient(n_t, T::exact_from(i));
+    match d {
+        1 => return binomial(2),
+        2 => {
+            // c(n, n - 2) = 2C(n, 3) + 3C(n, 4) and S(n, n - 2) = C(n, 3) + 3C(n, 4)
+            let b3 = binomial(3)?;
+            let b3 = if first_kind {
+                b3.checked_mul(T::TWO)?
+            } else {
+                b3
+            };
+            return b3.checked_add(binomial(4)?.checked_mul(T::exact_from(3u8))?);
+        }
+        3 => {
+            // c(n, n - 3) = C(n, 2)C(n, 4) and S(n, n - 3) = C(n, 4) + 10C(n, 5) + 15C(n, 6)
+            return if first_kind {
+                binomial(2)?.checked_mul(binomial(4)?)
+            } else {
+                binomial(4)?
+                    .checked_add(binomial(5)?.checked_mul(T::exact_from(10u8))?)?
+                    .checked_add(binomial(6)?.checked_mul(T::exact_from(15u8))?)
+            };
+        }
+        _ => {}
+    }
+    // Cheap lower bounds that keep the recurrence short. If 2d <= n, counting only the partitions
+    // into d pairs and n - 2d singletons (or the permutations made of d transpositions) shows that
+    // the result is at least C(n, 2d) (2d - 1)!!, so both factors must fit. Otherwise 2 <= k < n /
+    // 2, and by unimodality the result is at least min(X(n, 2), X(n, ceil(n / 2))), which is at
+    // least min(2^(n - 1) - 1, (n - 1)!!) = 2^(n - 1) - 1 once n >= 10.
+    if d << 1 <= n {
+        binomial(d << 1)?;
+        T::checked_double_factorial((d << 1) - 1)?;
+    } else if n > T::WIDTH + 1 {
+        return None;
+    }
+    // row[j] holds X(j + t, j) for the current diagonal offset t, starting from X(j, j) = 1.
+    let k = usize::exact_from(k);
+    let mut row = vec![T::ONE; k + 1];
+    for t in 1..=d {
+        row[0] = T::ZERO;
+        for j in 1..=k {
+            let f = if first_kind {
+                T::try_from(u64::exact_from(j) + t - 1).ok()?
+            } else {
+                T::exact_from(j)
+            };
+            row[j] = f.checked_mul(row[j])?.checked_add(row[j - 1])?;
+        }
+    }
+    Some(row[k])
+}
+
+fn checked_stirling_number_1_signed<
+    U: PrimitiveUnsigned,
+    S: OverflowingFrom<U> + PrimitiveSigned + TryFrom<U> + UnsignedAbs<Output = U>,
+>(
+    n: u64,
+    k: u64,
+) -> Option<S> {
+    let c = U::checked_stirling_number_1(n, k)?;
+    if k > n || (n - k).even() {
+        S::try_from(c).ok()
+    } else {
+        let (c, overflow) = S::overflowing_from(c);
+        if overflow {
+            if c == S::MIN { Some(S::MIN) } else { None }
+        } else {
+            Some(-c)
+        }
+    }
+}
+
+fn checked_stirling_number_2_signed<
+    U: PrimitiveUnsigned,
+    S: PrimitiveSigned + TryFrom<U> + UnsignedAbs<Output = U>,
+>(
+    n: u64,
+    k: u64,
+) -> Option<S> {
+    S::try_from(U::checked_stirling_number_2(n, k)?).ok()
+}
+
+macro_rules! impl_stirling_number_unsigned {
+    ($t:ident) => {
+        impl CheckedStirlingNumber1 for $t {
+            /// Computes the unsigned Stirling number of the first kind $\left[{n \atop k}\right]$:
+            /// the number of permutations of $n$ elements with exactly $k$ cycles.
+            ///
+            /// If the result is too large to be represented, the function returns `None`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Examples
+            /// See [here](super::stirling_number#checked_stirling_number_1).
+            #[inline]
+            fn checked_stirling_number_1(n: u64, k: u64) -> Option<$t> {
+                checked_stirling_number_unsigned(n, k, true)
+            }
+        }
+
+        impl CheckedStirlingNumber2 for $t {
+            /// Computes the Stirling number of the second kind $\left\\{{n \atop k}\right\\}$: the
+            /// number of ways to partition a set of $n$ elements into $k$ nonempty subsets.
+            ///
+            /// If the result is too large to be represented, the function returns `None`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Examples
+            /// See [here](super::stirling_number#checked_stirling_number_2).
+            #[inline]
+            fn checked_stirling_number_2(n: u64, k: u64) -> Option<$t> {
+                checked_stirling_number_unsigned(n, k, false)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_stirling_number_unsigned);
+
+macro_rules! impl_stirling_number_signed {
+    ($t:ident) => {
+        impl CheckedStirlingNumber1 for $t {
+            /// Computes the signed Stirling number of the first kind $s(n, k) = (-1)^{n-k}
+            /// \left[{n \atop k}\right]$.
+            ///
+            /// If the result is too large to be represented, the function returns `None`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Examples
+            /// See [here](super::stirling_number#checked_stirling_number_1).
+            #[inline]
+            fn checked_stirling_number_1(n: u64, k: u64) -> Option<$t> {
+                checked_stirling_number_1_signed(n, k)
+            }
+        }
+
+        impl CheckedStirlingNumber2 for $t {
+            /// Computes the Stirling number of the second kind $\left\\{{n \atop k}\right\\}$: the
+            /// number of ways to partition a set of $n$ elements into $k$ nonempty subsets.
+            ///
+            /// If the result is too large to be represented, the function returns `None`.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Examples
+            /// See [here](super::stirling_number#checked_stirling_number_2).
+            #[inline]
+            fn checked_stirling_number_2(n: u64, k: u64) -> Option<$t> {
+                checked_stirling_number_2_signed(n, k)
+            }
+        }
+    };
+}
+apply_to_signeds!(impl_stirling_number_signed);
+
+macro_rules! impl_stirling_number_primitive_int {
+    ($t:ident) => {
+        impl StirlingNumber1 for $t {
+            /// Computes a Stirling number of the first kind: the unsigned one, $\left[{n \atop
+            /// k}\right]$, for unsigned types, and the signed one, $s(n, k) = (-1)^{n-k} \left[{n
+            /// \atop k}\right]$, for signed types. $\left[{n \atop k}\right]$ is the number of
+            /// permutations of $n$ elements with exactly $k$ cycles.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable by this type.
+            ///
+            /// # Examples
+            /// See [here](super::stirling_number#stirling_number_1).
+            #[inline]
+            fn stirling_number_1(n: u64, k: u64) -> $t {
+                $t::checked_stirling_number_1(n, k).unwrap()
+            }
+        }
+
+        impl StirlingNumber2 for $t {
+            /// Computes the Stirling number of the second kind $\left\\{{n \atop k}\right\\}$: the
+            /// number of ways to partition a set of $n$ elements into $k$ nonempty subsets.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable by this type.
+            ///
+            /// # Examples
+            /// See [here](super::stirling_number#stirling_number_2).
+            #[inline]
+            fn stirling_number_2(n: u64, k: u64) -> $t {
+                $t::checked_stirling_number_2(n, k).unwrap()
+            }
+        }
+    };
+}
+apply_to_primitive_ints!(impl_stirling_number_primitive_int);
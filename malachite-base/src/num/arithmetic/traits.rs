@@ -580,6 +580,67 @@ pub trait CheckedBellNumber: Sized {
     fn checked_bell_number(n: u64) -> Option<Self>;
 }
 
+/// Computes the $n$th Catalan number, $C_n = \binom{2n}{n}/(n+1)$.
+pub trait CatalanNumber {
+    fn catalan_number(n: u64) -> Self;
+}
+
+/// Computes the $n$th Catalan number, returning `None` if the result is too large to be
+/// represented.
+pub trait CheckedCatalanNumber: Sized {
+    fn checked_catalan_number(n: u64) -> Option<Self>;
+}
+
+/// Computes the multinomial coefficient of a list of `u64`s $k_1, \ldots, k_m$: the number of ways
+/// to divide $k_1 + \cdots + k_m$ distinct objects into groups of sizes $k_1, \ldots, k_m$.
+pub trait MultinomialCoefficient {
+    fn multinomial_coefficient(ks: &[u64]) -> Self;
+}
+
+/// Computes the multinomial coefficient of a list of `u64`s, returning `None` if the result is too
+/// large to be represented.
+pub trait CheckedMultinomialCoefficient: Sized {
+    fn checked_multinomial_coefficient(ks: &[u64]) -> Option<Self>;
+}
+
+/// Computes the number of partitions of a `u64` $n$: the number of ways to write $n$ as a sum of
+/// positive integers, disregarding order.
+pub trait PartitionNumber {
+    fn partition_number(n: u64) -> Self;
+}
+
+/// Computes the number of partitions of a `u64`, returning `None` if the result is too large to be
+/// represented.
+pub trait CheckedPartitionNumber: Sized {
+    fn checked_partition_number(n: u64) -> Option<Self>;
+}
+
+/// Computes a Stirling number of the first kind. For unsigned types this is the unsigned Stirling
+/// number $\left[{n \atop k}\right]$, the number of permutations of $n$ elements with $k$ cycles;
+/// for signed types it is the signed Stirling number $s(n, k) = (-1)^{n-k}\left[{n \atop
+/// k}\right]$.
+pub trait StirlingNumber1 {
+    fn stirling_number_1(n: u64, k: u64) -> Self;
+}
+
+/// Computes a Stirling number of the first kind, returning `None` if the result is too large to be
+/// represented.
+pub trait CheckedStirlingNumber1: Sized {
+    fn checked_stirling_number_1(n: u64, k: u64) -> Option<Self>;
+}
+
+/// Computes a Stirling number of the second kind, $\left\\{{n \atop k}\right\\}$: the number of
+/// ways to partition a set of $n$ elements into $k$ nonempty subsets.
+pub trait StirlingNumber2 {
+    fn stirling_number_2(n: u64, k: u64) -> Self;
+}
+
+/// Computes a Stirling number of the second kind, returning `None` if the result is too large to be
+/// represented.
+pub trait CheckedStirlingNumber2: Sized {
+    fn checked_stirling_number_2(n: u64, k: u64) -> Option<Self>;
+}
+
 /// Computes the factorial of a `u64`.
 pub trait Factorial {
     fn factorial(n: u64) -> Self;
@@ -773,6 +834,416 @@ pub trait Ln1PlusXAssign {
     fn ln_1_plus_x_assign(&mut self);
 }
 
+/// Computes the sine of a number.
+pub trait Sin {
+    type Output;
+
+    fn sin(self) -> Self::Output;
+}
+
+/// Replaces a number with its sine.
+pub trait SinAssign {
+    fn sin_assign(&mut self);
+}
+
+/// Computes the cosine of a number.
+pub trait Cos {
+    type Output;
+
+    fn cos(self) -> Self::Output;
+}
+
+/// Replaces a number with its cosine.
+pub trait CosAssign {
+    fn cos_assign(&mut self);
+}
+
+/// Computes the tangent of a number.
+pub trait Tan {
+    type Output;
+
+    fn tan(self) -> Self::Output;
+}
+
+/// Replaces a number with its tangent.
+pub trait TanAssign {
+    fn tan_assign(&mut self);
+}
+
+/// Computes the sine and cosine of a number simultaneously.
+pub trait SinCos {
+    type Output;
+
+    fn sin_cos(self) -> (Self::Output, Self::Output);
+}
+
+/// Computes the arcsine of a number.
+pub trait Asin {
+    type Output;
+
+    fn asin(self) -> Self::Output;
+}
+
+/// Replaces a number with its arcsine.
+pub trait AsinAssign {
+    fn asin_assign(&mut self);
+}
+
+/// Computes the arccosine of a number.
+pub trait Acos {
+    type Output;
+
+    fn acos(self) -> Self::Output;
+}
+
+/// Replaces a number with its arccosine.
+pub trait AcosAssign {
+    fn acos_assign(&mut self);
+}
+
+/// Computes the arctangent of a number.
+pub trait Atan {
+    type Output;
+
+    fn atan(self) -> Self::Output;
+}
+
+/// Replaces a number with its arctangent.
+pub trait AtanAssign {
+    fn atan_assign(&mut self);
+}
+
+/// Computes the two-argument arctangent of two numbers $y$ and $x$: the angle of the point $(x, y)$
+/// in the plane.
+pub trait Atan2<RHS = Self> {
+    type Output;
+
+    fn atan2(self, x: RHS) -> Self::Output;
+}
+
+/// Replaces a number $y$ with the two-argument arctangent of it and another number $x$.
+pub trait Atan2Assign<RHS = Self> {
+    fn atan2_assign(&mut self, x: RHS);
+}
+
+/// Computes the hyperbolic sine of a number.
+pub trait Sinh {
+    type Output;
+
+    fn sinh(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic sine.
+pub trait SinhAssign {
+    fn sinh_assign(&mut self);
+}
+
+/// Computes the hyperbolic cosine of a number.
+pub trait Cosh {
+    type Output;
+
+    fn cosh(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic cosine.
+pub trait CoshAssign {
+    fn cosh_assign(&mut self);
+}
+
+/// Computes the hyperbolic tangent of a number.
+pub trait Tanh {
+    type Output;
+
+    fn tanh(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic tangent.
+pub trait TanhAssign {
+    fn tanh_assign(&mut self);
+}
+
+/// Computes the hyperbolic secant of a number.
+pub trait Sech {
+    type Output;
+
+    fn sech(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic secant.
+pub trait SechAssign {
+    fn sech_assign(&mut self);
+}
+
+/// Computes the hyperbolic cosecant of a number.
+pub trait Csch {
+    type Output;
+
+    fn csch(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic cosecant.
+pub trait CschAssign {
+    fn csch_assign(&mut self);
+}
+
+/// Computes the hyperbolic cotangent of a number.
+pub trait Coth {
+    type Output;
+
+    fn coth(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic cotangent.
+pub trait CothAssign {
+    fn coth_assign(&mut self);
+}
+
+/// Computes the hyperbolic sine and hyperbolic cosine of a number simultaneously.
+pub trait SinhCosh {
+    type Output;
+
+    fn sinh_cosh(self) -> (Self::Output, Self::Output);
+}
+
+/// Computes the inverse hyperbolic sine of a number.
+pub trait Asinh {
+    type Output;
+
+    fn asinh(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse hyperbolic sine.
+pub trait AsinhAssign {
+    fn asinh_assign(&mut self);
+}
+
+/// Computes the inverse hyperbolic cosine of a number.
+pub trait Acosh {
+    type Output;
+
+    fn acosh(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse hyperbolic cosine.
+pub trait AcoshAssign {
+    fn acosh_assign(&mut self);
+}
+
+/// Computes the inverse hyperbolic tangent of a number.
+pub trait Atanh {
+    type Output;
+
+    fn atanh(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse hyperbolic tangent.
+pub trait AtanhAssign {
+    fn atanh_assign(&mut self);
+}
+
+/// Computes the gamma function of a number.
+pub trait Gamma {
+    type Output;
+
+    fn gamma(self) -> Self::Output;
+}
+
+/// Replaces a number with the gamma function of it.
+pub trait GammaAssign {
+    fn gamma_assign(&mut self);
+}
+
+/// Computes the natural logarithm of the absolute value of the gamma function of a number. The sign
+/// of the gamma function is also returned, as `Greater` if it is positive and `Less` if it is
+/// negative.
+pub trait LnGamma {
+    type Output;
+
+    fn ln_gamma(self) -> (Self::Output, Ordering);
+}
+
+/// Replaces a number with the natural logarithm of the absolute value of the gamma function of it,
+/// returning the sign of the gamma function as `Greater` if it is positive and `Less` if it is
+/// negative.
+pub trait LnGammaAssign {
+    fn ln_gamma_assign(&mut self) -> Ordering;
+}
+
+/// Computes the digamma function of a number, the logarithmic derivative of the gamma function.
+pub trait Digamma {
+    type Output;
+
+    fn digamma(self) -> Self::Output;
+}
+
+/// Replaces a number with the digamma function of it.
+pub trait DigammaAssign {
+    fn digamma_assign(&mut self);
+}
+
+/// Computes the beta function of two numbers, $B(x,y)=\Gamma(x)\Gamma(y)/\Gamma(x+y)$.
+pub trait Beta<RHS = Self> {
+    type Output;
+
+    fn beta(self, other: RHS) -> Self::Output;
+}
+
+/// Replaces a number $x$ with the beta function of it and another number $y$,
+/// $B(x,y)=\Gamma(x)\Gamma(y)/\Gamma(x+y)$.
+pub trait BetaAssign<RHS = Self> {
+    fn beta_assign(&mut self, other: RHS);
+}
+
+/// Computes the error function of a number, $\operatorname{erf}
+/// x=\frac{2}{\sqrt\pi}\int_0^xe^{-t^2}\\,dt$.
+pub trait Erf {
+    type Output;
+
+    fn erf(self) -> Self::Output;
+}
+
+/// Replaces a number with the error function of it.
+pub trait ErfAssign {
+    fn erf_assign(&mut self);
+}
+
+/// Computes the complementary error function of a number, $\operatorname{erfc}
+/// x=1-\operatorname{erf} x$.
+pub trait Erfc {
+    type Output;
+
+    fn erfc(self) -> Self::Output;
+}
+
+/// Replaces a number with the complementary error function of it.
+pub trait ErfcAssign {
+    fn erfc_assign(&mut self);
+}
+
+/// Computes the lower incomplete gamma function of two numbers $s$ and $x$,
+/// $\gamma(s,x)=\int_0^xt^{s-1}e^{-t}\\,dt$.
+pub trait LowerIncompleteGamma<RHS = Self> {
+    type Output;
+
+    fn lower_incomplete_gamma(self, x: RHS) -> Self::Output;
+}
+
+/// Replaces a number $s$ with the lower incomplete gamma function of it and another number $x$,
+/// $\gamma(s,x)=\int_0^xt^{s-1}e^{-t}\\,dt$.
+pub trait LowerIncompleteGammaAssign<RHS = Self> {
+    fn lower_incomplete_gamma_assign(&mut self, x: RHS);
+}
+
+/// Computes the upper incomplete gamma function of two numbers $s$ and $x$,
+/// $\Gamma(s,x)=\int_x^\infty t^{s-1}e^{-t}\\,dt$.
+pub trait UpperIncompleteGamma<RHS = Self> {
+    type Output;
+
+    fn upper_incomplete_gamma(self, x: RHS) -> Self::Output;
+}
+
+/// Replaces a number $s$ with the upper incomplete gamma function of it and another number $x$,
+/// $\Gamma(s,x)=\int_x^\infty t^{s-1}e^{-t}\\,dt$.
+pub trait UpperIncompleteGammaAssign<RHS = Self> {
+    fn upper_incomplete_gamma_assign(&mut self, x: RHS);
+}
+
+/// Computes the Riemann zeta function of a number, $\zeta(s)$.
+pub trait Zeta {
+    type Output;
+
+    fn zeta(self) -> Self::Output;
+}
+
+/// Replaces a number with the Riemann zeta function of it, $\zeta(s)$.
+pub trait ZetaAssign {
+    fn zeta_assign(&mut self);
+}
+
+/// Computes the Bessel function of the first kind of order 0 of a number, $J_0(x)$.
+pub trait BesselJ0 {
+    type Output;
+
+    fn bessel_j0(self) -> Self::Output;
+}
+
+/// Replaces a number with the Bessel function of the first kind of order 0 of it, $J_0(x)$.
+pub trait BesselJ0Assign {
+    fn bessel_j0_assign(&mut self);
+}
+
+/// Computes the Bessel function of the first kind of order 1 of a number, $J_1(x)$.
+pub trait BesselJ1 {
+    type Output;
+
+    fn bessel_j1(self) -> Self::Output;
+}
+
+/// Replaces a number with the Bessel function of the first kind of order 1 of it, $J_1(x)$.
+pub trait BesselJ1Assign {
+    fn bessel_j1_assign(&mut self);
+}
+
+/// Computes the Bessel function of the first kind of order $n$ of a number, $J_n(x)$.
+pub trait BesselJn {
+    type Output;
+
+    fn bessel_jn(self, n: i64) -> Self::Output;
+}
+
+/// Replaces a number with the Bessel function of the first kind of order $n$ of it, $J_n(x)$.
+pub trait BesselJnAssign {
+    fn bessel_jn_assign(&mut self, n: i64);
+}
+
+/// Computes the Bessel function of the second kind of order 0 of a number, $Y_0(x)$.
+pub trait BesselY0 {
+    type Output;
+
+    fn bessel_y0(self) -> Self::Output;
+}
+
+/// Replaces a number with the Bessel function of the second kind of order 0 of it, $Y_0(x)$.
+pub trait BesselY0Assign {
+    fn bessel_y0_assign(&mut self);
+}
+
+/// Computes the Bessel function of the second kind of order 1 of a number, $Y_1(x)$.
+pub trait BesselY1 {
+    type Output;
+
+    fn bessel_y1(self) -> Self::Output;
+}
+
+/// Replaces a number with the Bessel function of the second kind of order 1 of it, $Y_1(x)$.
+pub trait BesselY1Assign {
+    fn bessel_y1_assign(&mut self);
+}
+
+/// Computes the Bessel function of the second kind of order $n$ of a number, $Y_n(x)$.
+pub trait BesselYn {
+    type Output;
+
+    fn bessel_yn(self, n: i64) -> Self::Output;
+}
+
+/// Replaces a number with the Bessel function of the second kind of order $n$ of it, $Y_n(x)$.
+pub trait BesselYnAssign {
+    fn bessel_yn_assign(&mut self, n: i64);
+}
+
+/// Computes the Airy function of the first kind of a number, $\operatorname{Ai}(x)$.
+pub trait AiryAi {
+    type Output;
+
+    fn airy_ai(self) -> Self::Output;
+}
+
+/// Replaces a number with the Airy function of the first kind of it, $\operatorname{Ai}(x)$.
+pub trait AiryAiAssign {
+    fn airy_ai_assign(&mut self);
+}
+
 /// Calculates the LCM (least common multiple) of two numbers, returning `None` if the result is not
 /// representable.
 pub trait CheckedLcm<RHS = Self> {
@@ -1025,6 +1496,32 @@ pub trait ModIsReduced<M = Self> {
     fn mod_is_reduced(&self, m: &M) -> bool;
 }
 
+/// Computes the discrete logarithm of a number to a base, modulo another number $m$: the smallest
+/// exponent $k$ with $b^k \equiv x \pmod m$, or `None` if there is none. The number and the base
+/// must be already reduced modulo $m$.
+pub trait ModLog<B = Self, M = Self> {
+    type Output;
+
+    fn mod_log(self, base: B, m: M) -> Option<Self::Output>;
+}
+
+/// Computes the discrete logarithm of a number to a base, modulo another number $m$, given the
+/// prime factorization of a multiple of the multiplicative order of the base, such as the order of
+/// the multiplicative group modulo $m$. The number and the base must be already reduced modulo $m$.
+///
+/// If many logarithms modulo the same $m$ are needed, this avoids factoring the group order each
+/// time.
+pub trait ModLogWithOrderFactorization<B = Self, M = Self> {
+    type Output;
+
+    fn mod_log_with_order_factorization(
+        self,
+        base: B,
+        m: M,
+        order_factors: &[(Self::Output, u64)],
+    ) -> Option<Self::Output>;
+}
+
 /// Multiplies two numbers modulo a third number $m$. The inputs must be already reduced modulo $m$.
 pub trait ModMul<RHS = Self, M = Self> {
     type Output;
@@ -1065,6 +1562,15 @@ pub trait ModMulPrecomputedAssign<RHS = Self, M = Self>: ModMulPrecomputed<RHS,
     fn mod_mul_precomputed_assign(&mut self, other: RHS, m: M, data: &Self::Data);
 }
 
+/// Computes the multiplicative order of a number modulo another number $m$: the smallest positive
+/// $k$ with $x^k \equiv 1 \pmod m$, or `None` if $x$ is not coprime to $m$. The input must be
+/// already reduced modulo $m$.
+pub trait ModMultiplicativeOrder<M = Self> {
+    type Output;
+
+    fn mod_multiplicative_order(self, m: M) -> Option<Self::Output>;
+}
+
 /// Negates a number modulo another number $m$. The input must be already reduced modulo $m$.
 pub trait ModNeg<M = Self> {
     type Output;
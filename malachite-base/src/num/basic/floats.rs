@@ -16,12 +16,13 @@ use crate::num::arithmetic::traits::{
     SqrtAssign, Square, SquareAssign, SubMul, SubMulAssign,
 };
 use crate::num::basic::traits::{
-    ChampernowneConstant, CopelandErdosConstant, GaussConstant, GelfondSchneiderConstant,
-    GelfondsConstant, Infinity, LemniscateConstant, LiouvillesConstant, Ln2, Ln10, Log2E, Log10E,
-    Log102, Log210, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, OneOverPi,
-    OneOverSqrtPi, OneOverSqrtTau, Phi, Pi, PiOver2, PiOver3, PiOver4, PiOver6, PiOver8,
-    PrimeConstant, ProuhetThueMorseConstant, RamanujansConstant, Sqrt2, Sqrt2Over2, Sqrt3,
-    Sqrt3Over3, Sqrt5, Sqrt5Over5, SqrtPi, Tau, Two, TwoOverPi, TwoOverSqrtPi, Zero,
+    Apery, Catalan, ChampernowneConstant, CopelandErdosConstant, EulerGamma, GaussConstant,
+    GelfondSchneiderConstant, GelfondsConstant, Infinity, LemniscateConstant, LiouvillesConstant,
+    Ln2, Ln10, Log2E, Log10E, Log102, Log210, NaN, NegativeInfinity, NegativeOne, NegativeZero,
+    One, OneHalf, OneOverPi, OneOverSqrtPi, OneOverSqrtTau, Phi, Pi, PiOver2, PiOver3, PiOver4,
+    PiOver6, PiOver8, PrimeConstant, ProuhetThueMorseConstant, RamanujansConstant, Sqrt2,
+    Sqrt2Over2, Sqrt3, Sqrt3Over3, Sqrt5, Sqrt5Over5, SqrtPi, Tau, Two, TwoOverPi, TwoOverSqrtPi,
+    Zero,
 };
 use crate::num::comparison::traits::{EqAbs, PartialOrdAbs};
 use crate::num::conversion::traits::{
@@ -141,6 +142,7 @@ pub trait PrimitiveFloat:
     + FmtRyuString
     + From<f32>
     + FromStr
+    + EulerGamma
     + GaussConstant
     + GelfondSchneiderConstant
     + GelfondsConstant
@@ -153,6 +155,8 @@ pub trait PrimitiveFloat:
     + LiouvillesConstant
     + ChampernowneConstant
     + CopelandErdosConstant
+    + Apery
+    + Catalan
     + Log2E
     + Log10E
     + Log210
@@ -650,7 +654,10 @@ macro_rules! impl_basic_traits_primitive_float {
         $ramanujans_constant: expr,
         $liouvilles_constant: expr,
         $champernowne_constant: expr,
-        $copeland_erdos_constant: expr
+        $copeland_erdos_constant: expr,
+        $euler_gamma: expr,
+        $catalan: expr,
+        $apery: expr
     ) => {
         impl PrimitiveFloat for $t {
             const WIDTH: u64 = $width;
@@ -948,6 +955,21 @@ macro_rules! impl_basic_traits_primitive_float {
         impl CopelandErdosConstant for $t {
             const COPELAND_ERDOS_CONSTANT: $t = $copeland_erdos_constant;
         }
+
+        /// $\gamma$.
+        impl EulerGamma for $t {
+            const EULER_GAMMA: $t = $euler_gamma;
+        }
+
+        /// $G=\sum_{k=0}^\infty (-1)^k/(2k+1)^2$.
+        impl Catalan for $t {
+            const CATALAN: $t = $catalan;
+        }
+
+        /// $\zeta(3)$.
+        impl Apery for $t {
+            const APERY: $t = $apery;
+        }
     };
 }
 impl_basic_traits_primitive_float!(
@@ -973,7 +995,10 @@ impl_basic_traits_primitive_float!(
     2.6253742e17,
     0.110001,
     0.12345679,
-    0.23571113
+    0.23571113,
+    0.5772157,
+    0.9159656,
+    1.2020569
 );
 impl_basic_traits_primitive_float!(
     f64,
@@ -998,5 +1023,8 @@ impl_basic_traits_primitive_float!(
     2.6253741264076874e17,
     0.110001,
     0.12345678910111213,
-    0.23571113171923294
+    0.23571113171923294,
+    0.5772156649015329,
+    0.915965594177219,
+    1.2020569031595942
 );
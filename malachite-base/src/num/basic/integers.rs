@@ -13,31 +13,32 @@ use crate::num::arithmetic::traits::{
     AverageAssign, AverageRound, AverageRoundAssign, BalancedMod, BinomialCoefficient, CeilingRoot,
     CeilingRootAssign, CeilingSqrt, CeilingSqrtAssign, CheckedAdd, CheckedAddMul,
     CheckedBinomialCoefficient, CheckedDiv, CheckedMul, CheckedMulAddMul, CheckedMulSubMul,
-    CheckedNeg, CheckedPow, CheckedRoot, CheckedSqrt, CheckedSquare, CheckedSub, CheckedSubMul,
-    DivAssignMod, DivAssignModEuclidean, DivAssignModPrecomputed, DivAssignRem, DivEuclidean,
-    DivEuclideanAssign, DivExact, DivExactAssign, DivMod, DivModEuclidean, DivModPrecomputed,
-    DivRem, DivRound, DivRoundAssign, DivisibleBy, DivisibleByPowerOf2, EqMod, EqModPowerOf2,
-    ExtendedGcd, FloorRoot, FloorRootAssign, FloorSqrt, FloorSqrtAssign, JacobiSymbol,
-    KroneckerSymbol, LegendreSymbol, Mod, ModAssign, ModEuclidean, ModEuclideanAssign, ModPowerOf2,
-    ModPowerOf2Assign, MulAddMul, MulAddMulAssign, MulSubMul, MulSubMulAssign, OverflowingAdd,
-    OverflowingAddAssign, OverflowingAddMul, OverflowingAddMulAssign, OverflowingDiv,
-    OverflowingDivAssign, OverflowingMul, OverflowingMulAddMul, OverflowingMulAddMulAssign,
-    OverflowingMulAssign, OverflowingMulSubMul, OverflowingMulSubMulAssign, OverflowingNeg,
-    OverflowingNegAssign, OverflowingPow, OverflowingPowAssign, OverflowingSquare,
-    OverflowingSquareAssign, OverflowingSub, OverflowingSubAssign, OverflowingSubMul,
-    OverflowingSubMulAssign, Parity, Pow, PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign,
-    RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign, RoundToMultiple,
-    RoundToMultipleAssign, RoundToMultipleOfPowerOf2, RoundToMultipleOfPowerOf2Assign,
-    SaturatingAdd, SaturatingAddAssign, SaturatingAddMul, SaturatingAddMulAssign, SaturatingMul,
-    SaturatingMulAddMul, SaturatingMulAddMulAssign, SaturatingMulAssign, SaturatingMulSubMul,
-    SaturatingMulSubMulAssign, SaturatingPow, SaturatingPowAssign, SaturatingSquare,
-    SaturatingSquareAssign, SaturatingSub, SaturatingSubAssign, SaturatingSubMul,
-    SaturatingSubMulAssign, ShlRound, ShlRoundAssign, ShrRound, ShrRoundAssign, Sign, Square,
-    SquareAssign, SubMul, SubMulAssign, WrappingAdd, WrappingAddAssign, WrappingAddMul,
-    WrappingAddMulAssign, WrappingDiv, WrappingDivAssign, WrappingMul, WrappingMulAddMul,
-    WrappingMulAddMulAssign, WrappingMulAssign, WrappingMulSubMul, WrappingMulSubMulAssign,
-    WrappingNeg, WrappingNegAssign, WrappingPow, WrappingPowAssign, WrappingSquare,
-    WrappingSquareAssign, WrappingSub, WrappingSubAssign, WrappingSubMul, WrappingSubMulAssign,
+    CheckedNeg, CheckedPow, CheckedRoot, CheckedSqrt, CheckedSquare, CheckedStirlingNumber1,
+    CheckedStirlingNumber2, CheckedSub, CheckedSubMul, DivAssignMod, DivAssignModEuclidean,
+    DivAssignModPrecomputed, DivAssignRem, DivEuclidean, DivEuclideanAssign, DivExact,
+    DivExactAssign, DivMod, DivModEuclidean, DivModPrecomputed, DivRem, DivRound, DivRoundAssign,
+    DivisibleBy, DivisibleByPowerOf2, EqMod, EqModPowerOf2, ExtendedGcd, FloorRoot,
+    FloorRootAssign, FloorSqrt, FloorSqrtAssign, JacobiSymbol, KroneckerSymbol, LegendreSymbol,
+    Mod, ModAssign, ModEuclidean, ModEuclideanAssign, ModPowerOf2, ModPowerOf2Assign, MulAddMul,
+    MulAddMulAssign, MulSubMul, MulSubMulAssign, OverflowingAdd, OverflowingAddAssign,
+    OverflowingAddMul, OverflowingAddMulAssign, OverflowingDiv, OverflowingDivAssign,
+    OverflowingMul, OverflowingMulAddMul, OverflowingMulAddMulAssign, OverflowingMulAssign,
+    OverflowingMulSubMul, OverflowingMulSubMulAssign, OverflowingNeg, OverflowingNegAssign,
+    OverflowingPow, OverflowingPowAssign, OverflowingSquare, OverflowingSquareAssign,
+    OverflowingSub, OverflowingSubAssign, OverflowingSubMul, OverflowingSubMulAssign, Parity, Pow,
+    PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign, RotateLeft, RotateLeftAssign, RotateRight,
+    RotateRightAssign, RoundToMultiple, RoundToMultipleAssign, RoundToMultipleOfPowerOf2,
+    RoundToMultipleOfPowerOf2Assign, SaturatingAdd, SaturatingAddAssign, SaturatingAddMul,
+    SaturatingAddMulAssign, SaturatingMul, SaturatingMulAddMul, SaturatingMulAddMulAssign,
+    SaturatingMulAssign, SaturatingMulSubMul, SaturatingMulSubMulAssign, SaturatingPow,
+    SaturatingPowAssign, SaturatingSquare, SaturatingSquareAssign, SaturatingSub,
+    SaturatingSubAssign, SaturatingSubMul, SaturatingSubMulAssign, ShlRound, ShlRoundAssign,
+    ShrRound, ShrRoundAssign, Sign, Square, SquareAssign, StirlingNumber1, StirlingNumber2, SubMul,
+    SubMulAssign, WrappingAdd, WrappingAddAssign, WrappingAddMul, WrappingAddMulAssign,
+    WrappingDiv, WrappingDivAssign, WrappingMul, WrappingMulAddMul, WrappingMulAddMulAssign,
+    WrappingMulAssign, WrappingMulSubMul, WrappingMulSubMulAssign, WrappingNeg, WrappingNegAssign,
+    WrappingPow, WrappingPowAssign, WrappingSquare, WrappingSquareAssign, WrappingSub,
+    WrappingSubAssign, WrappingSubMul, WrappingSubMulAssign,
 };
 use crate::num::basic::traits::{One, Two, Zero};
 use crate::num::conversion::traits::{
@@ -167,6 +168,8 @@ pub trait PrimitiveInt:
     + CheckedRoot<u64, Output = Self>
     + CheckedSqrt<Output = Self>
     + CheckedSquare<Output = Self>
+    + CheckedStirlingNumber1
+    + CheckedStirlingNumber2
     + CheckedSub<Self, Output = Self>
     + CheckedSubMul<Self, Self, Output = Self>
     + Clone
@@ -473,6 +476,8 @@ pub trait PrimitiveInt:
     + Sized
     + Square<Output = Self>
     + SquareAssign
+    + StirlingNumber1
+    + StirlingNumber2
     + Sub<Self, Output = Self>
     + SubAssign<Self>
     + SubMul<Self, Self, Output = Self>
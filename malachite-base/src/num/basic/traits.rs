@@ -217,6 +217,22 @@ pub trait CopelandErdosConstant {
     const COPELAND_ERDOS_CONSTANT: Self;
 }
 
+/// Provides Apéry's constant, $\zeta(3)=\sum_{k=1}^\infty k^{-3}$.
+pub trait Apery {
+    const APERY: Self;
+}
+
+/// Provides Catalan's constant, $G=\sum_{k=0}^\infty (-1)^k/(2k+1)^2$.
+pub trait Catalan {
+    const CATALAN: Self;
+}
+
+/// Provides the Euler–Mascheroni constant, $\gamma=\lim_{n\to\infty}(H_n-\ln n)$, where $H_n$ is
+/// the $n$th harmonic number.
+pub trait EulerGamma {
+    const EULER_GAMMA: Self;
+}
+
 /// Provides Gauss's constant, $G=1/\mathrm{AGM}(1,\sqrt{2})$.
 ///
 /// Having three consecutive esses in an identifier is awkward, so let's pretend that we sometimes
@@ -7,11 +7,12 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::num::arithmetic::traits::{
-    AbsDiff, AbsDiffAssign, BalancedMod, BellNumber, CeilingDivAssignNegMod, CeilingDivNegMod,
-    CeilingLogBase, CeilingLogBase2, CeilingLogBasePowerOf2, CheckedBellNumber,
-    CheckedDoubleFactorial, CheckedFactorial, CheckedFibonacci, CheckedLcm, CheckedLogBase,
-    CheckedLogBase2, CheckedLogBasePowerOf2, CheckedLucasNumber, CheckedMultifactorial,
-    CheckedNextPowerOf2, CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial,
+    AbsDiff, AbsDiffAssign, BalancedMod, BellNumber, CatalanNumber, CeilingDivAssignNegMod,
+    CeilingDivNegMod, CeilingLogBase, CeilingLogBase2, CeilingLogBasePowerOf2, CheckedBellNumber,
+    CheckedCatalanNumber, CheckedDoubleFactorial, CheckedFactorial, CheckedFibonacci, CheckedLcm,
+    CheckedLogBase, CheckedLogBase2, CheckedLogBasePowerOf2, CheckedLucasNumber,
+    CheckedMultifactorial, CheckedMultinomialCoefficient, CheckedNextPowerOf2,
+    CheckedPartitionNumber, CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial,
     ExtendedGcd, Factorial, Fibonacci, FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd,
     GcdAssign, IsPowerOf2, Lcm, LcmAssign, LucasNumber, ModAdd, ModAddAssign, ModInverse,
     ModIsReduced, ModMul, ModMulAssign, ModMulPrecomputed, ModMulPrecomputedAssign, ModNeg,
@@ -21,10 +22,11 @@ use crate::num::arithmetic::traits::{
     ModPowerOf2PowAssign, ModPowerOf2Shl, ModPowerOf2ShlAssign, ModPowerOf2Shr,
     ModPowerOf2ShrAssign, ModPowerOf2Square, ModPowerOf2SquareAssign, ModPowerOf2Sub,
     ModPowerOf2SubAssign, ModSquare, ModSquareAssign, ModSquarePrecomputed,
-    ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, NegMod, NegModAssign,
-    NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign, Primorial,
-    RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, UnsignedAbs, XMulYToZZ,
-    XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
+    ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, MultinomialCoefficient,
+    NegMod, NegModAssign, NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign,
+    PartitionNumber, Primorial, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial,
+    UnsignedAbs, XMulYToZZ, XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ,
+    XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::signeds::PrimitiveSigned;
@@ -50,7 +52,11 @@ pub trait PrimitiveUnsigned:
     + CeilingDivNegMod<Self, DivOutput = Self, ModOutput = Self>
     + CheckedDoubleFactorial
     + BellNumber
+    + CatalanNumber
     + CheckedBellNumber
+    + CheckedCatalanNumber
+    + CheckedMultinomialCoefficient
+    + CheckedPartitionNumber
     + CheckedFactorial
     + CheckedFibonacci
     + CheckedMultifactorial
@@ -160,6 +166,7 @@ pub trait PrimitiveUnsigned:
     + ModSub<Self, Self, Output = Self>
     + ModSubAssign<Self, Self>
     + Multifactorial
+    + MultinomialCoefficient
     + NegMod<Self, Output = Self>
     + NegModAssign<Self>
     + NegModPowerOf2<Output = Self>
@@ -181,6 +188,7 @@ pub trait PrimitiveUnsigned:
     + PowerOf2DigitIterable<usize>
     + Primes<I = PrimesIterator<Self>, LI = PrimesLessThanIterator<Self>>
     + PrimitiveInt
+    + PartitionNumber
     + Primorial
     + RootRem<u64, RootOutput = Self, RemOutput = Self>
     + RootAssignRem<u64, RemOutput = Self>
@@ -405,7 +405,7 @@ fn primitive_float_rounding_from_unsigned<T: PrimitiveFloat, U: PrimitiveUnsigne
             Exact => {
                 panic!("Value cannot be represented exactly as an {}", T::NAME)
             }
-            Floor | Down | Nearest => (T::MAX_FINITE, Less),
+            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX_FINITE, Less),
             _ => (T::INFINITY, Greater),
         }
     }
@@ -422,7 +422,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", T::NAME)
                 }
-                Down | Floor | Nearest => (T::MAX, Less),
+                Down | Floor | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                 _ => panic!("Cannot round away from positive infinity"),
             }
         } else {
@@ -430,7 +430,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", T::NAME)
                 }
-                Down | Ceiling | Nearest => (T::ZERO, Greater),
+                Down | Ceiling | Nearest | NearestTiesAway => (T::ZERO, Greater),
                 _ => panic!("Cannot round away from negative infinity"),
             }
         };
@@ -443,7 +443,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
             Exact => {
                 panic!("Value cannot be represented exactly as a {}", T::NAME)
             }
-            Ceiling | Down | Nearest => (T::ZERO, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
             _ => panic!("Value is less than 0 and rounding mode is {rm}"),
         };
     }
@@ -466,7 +466,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
             Exact => {
                 panic!("Value cannot be represented exactly as a {}", T::NAME)
             }
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
             _ => panic!(
                 "Value is greater than {}::MAX and rounding mode is {}",
                 T::NAME,
@@ -565,6 +565,15 @@ macro_rules! impl_from_float_unsigned {
                     ///   least-significant bit in its representation is selected. If the value is
                     ///   larger than the maximum finite float (which can only happen when
                     ///   converting a `u128` to an `f32`), the maximum finite float is returned.
+                    /// - If the rounding mode is `NearestTiesAway`, then the nearest float is
+                    ///   returned. If the value is exactly between two floats, the one with the
+                    ///   larger absolute value is selected. Values larger than the maximum finite
+                    ///   float are handled as with `Nearest`.
+                    /// - If the rounding mode is `Odd`, then an exactly representable value is
+                    ///   returned unchanged; otherwise, of the two floats surrounding the value,
+                    ///   the one with the odd least-significant bit in its representation is
+                    ///   selected. Values larger than the maximum finite float are handled as with
+                    ///   `Nearest`.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -605,6 +614,14 @@ macro_rules! impl_from_float_unsigned {
                     ///   float is greater than the maximum representable unsigned value, the
                     ///   maximum unsigned value is returned. If the float is negative, zero is
                     ///   returned.
+                    /// - If the rounding mode is `NearestTiesAway`, then the rounding proceeds as
+                    ///   with `Nearest`, except that if the value is exactly between two numbers,
+                    ///   the one with the larger absolute value is selected.
+                    /// - If the rounding mode is `Odd`, then an integer value is returned
+                    ///   unchanged; otherwise, the odd one of the two numbers surrounding the value
+                    ///   is returned. If the float is greater than the maximum representable
+                    ///   unsigned value, the maximum unsigned value is returned. If the float is
+                    ///   negative, the function panics.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -615,7 +632,7 @@ macro_rules! impl_from_float_unsigned {
                     ///   unsigned type.
                     /// - If `value` is greater than the maximum value of the unsigned type and `rm`
                     ///   is `Ceiling` or `Up`.
-                    /// - If `value` is negative and `rm` is `Floor` or `Up`.
+                    /// - If `value` is negative and `rm` is `Floor`, `Up`, or `Odd`.
                     ///
                     /// # Examples
                     /// See [here](super::from#rounding_from).
@@ -741,7 +758,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", S::NAME)
                 }
-                Down | Floor | Nearest => (S::MAX, Less),
+                Down | Floor | Nearest | NearestTiesAway | Odd => (S::MAX, Less),
                 _ => panic!("Cannot round away from extreme value"),
             }
         } else {
@@ -749,7 +766,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", S::NAME)
                 }
-                Down | Nearest | Ceiling => (S::MIN, Greater),
+                Down | Nearest | NearestTiesAway | Ceiling => (S::MIN, Greater),
                 _ => panic!("Cannot round away from extreme value"),
             }
         };
@@ -766,7 +783,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as an {}", S::NAME)
                 }
-                Floor | Down | Nearest => (S::MAX, Less),
+                Floor | Down | Nearest | NearestTiesAway | Odd => (S::MAX, Less),
                 _ => panic!(
                     "Value is greater than {}::MAX and rounding mode is {}",
                     S::NAME,
@@ -788,7 +805,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as an {}", S::NAME)
                 }
-                Ceiling | Down | Nearest => (S::MIN, Greater),
+                Ceiling | Down | Nearest | NearestTiesAway => (S::MIN, Greater),
                 _ => panic!(
                     "Value is smaller than {}::MIN and rounding mode is {}",
                     S::NAME,
@@ -911,6 +928,13 @@ macro_rules! impl_from_float_signed {
                     /// - If the rounding mode is `Nearest`, then the nearest float is returned. If
                     ///   the value is exactly between two floats, the float with the zero
                     ///   least-significant bit in its representation is selected.
+                    /// - If the rounding mode is `NearestTiesAway`, then the nearest float is
+                    ///   returned. If the value is exactly between two floats, the one with the
+                    ///   larger absolute value is selected.
+                    /// - If the rounding mode is `Odd`, then an exactly representable value is
+                    ///   returned unchanged; otherwise, of the two floats surrounding the value,
+                    ///   the one with the odd least-significant bit in its representation is
+                    ///   selected.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -953,6 +977,14 @@ macro_rules! impl_from_float_signed {
                     ///   float is greater than the maximum representable signed value, the maximum
                     ///   signed value is returned. If the float is smaller than the minimum
                     ///   representable signed value, the minimum signed value is returned.
+                    /// - If the rounding mode is `NearestTiesAway`, then the rounding proceeds as
+                    ///   with `Nearest`, except that if the value is exactly between two numbers,
+                    ///   the one with the larger absolute value is selected.
+                    /// - If the rounding mode is `Odd`, then an integer value is returned
+                    ///   unchanged; otherwise, the odd one of the two numbers surrounding the value
+                    ///   is returned. If the float is greater than the maximum representable signed
+                    ///   value, the maximum signed value is returned. If the float is smaller than
+                    ///   the minimum representable signed value, the function panics.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -964,7 +996,7 @@ macro_rules! impl_from_float_signed {
                     /// - If `value` is greater than the maximum value of the signed type and `rm`
                     ///   is `Ceiling` or `Up`.
                     /// - If `value` is smaller than the minimum value of the signed type and `rm`
-                    ///   is `Floor` or `Up`.
+                    ///   is `Floor`, `Up`, or `Odd`.
                     ///
                     /// # Examples
                     /// See [here](super::from#rounding_from).
@@ -213,11 +213,11 @@ fn from_sci_string_with_options_primitive_int<T: PrimitiveInt>(
         if neg_exponent > sig_len {
             let s = if sign { &s[1..] } else { &s[..] };
             return match rm {
-                Down | Floor | Nearest => {
+                Down | Floor | Nearest | NearestTiesAway => {
                     validate_helper(s, options.base)?;
                     Some(T::ZERO)
                 }
-                Up | Ceiling => {
+                Up | Ceiling | Odd => {
                     if is_zero_helper(s, options.base)? {
                         Some(T::ZERO)
                     } else {
@@ -265,6 +265,17 @@ fn from_sci_string_with_options_primitive_int<T: PrimitiveInt>(
                     }
                 }
             },
+            NearestTiesAway => match cmp_half_helper(after_e, options.base)? {
+                Less => Some(x),
+                Greater | Equal => up_1(x, neg),
+            },
+            Odd => {
+                if is_zero_helper(after_e, options.base)? || x.odd() {
+                    Some(x)
+                } else {
+                    up_1(x, neg)
+                }
+            }
         }
     }
 }
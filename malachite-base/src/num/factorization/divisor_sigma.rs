@@ -0,0 +1,216 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::factor::Factors;
+use crate::num::factorization::traits::{CheckedDivisorSigma, DivisorCount, DivisorSigma, Factor};
+
+impl<T: PrimitiveUnsigned, const N: usize> Factors<T, N> {
+    /// Computes the divisor function $\sigma_k(n)$ of the number $n$ whose prime factorization this
+    /// is, returning `None` if the result is not representable as a `T`.
+    ///
+    /// $$
+    /// \sigma_k(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m \sum_{j=0}^{e_i} p_i^{jk}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(12u8.factor().checked_divisor_sigma(1), Some(28));
+    /// assert_eq!(16u8.factor().checked_divisor_sigma(2), None);
+    /// assert_eq!(12u16.factor().checked_divisor_sigma(2), Some(210));
+    /// ```
+    pub fn checked_divisor_sigma(&self, k: u64) -> Option<T> {
+        let mut sigma = T::ONE;
+        for (p, e) in self.pairs() {
+            let pk = p.checked_pow(k)?;
+            let mut s = T::ONE;
+            for _ in 0..e {
+                s = s.checked_mul(pk)?.checked_add(T::ONE)?;
+            }
+            sigma = sigma.checked_mul(s)?;
+        }
+        Some(sigma)
+    }
+
+    /// Computes the divisor function $\sigma_k(n)$ of the number $n$ whose prime factorization this
+    /// is.
+    ///
+    /// $$
+    /// \sigma_k(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m \sum_{j=0}^{e_i} p_i^{jk}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+    ///
+    /// # Panics
+    /// Panics if the result is not representable as a `T`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u8.factor().divisor_sigma(5), 1);
+    /// assert_eq!(12u8.factor().divisor_sigma(0), 6);
+    /// assert_eq!(12u8.factor().divisor_sigma(1), 28);
+    /// assert_eq!(12u16.factor().divisor_sigma(2), 210);
+    /// ```
+    #[inline]
+    pub fn divisor_sigma(&self, k: u64) -> T {
+        self.checked_divisor_sigma(k).unwrap()
+    }
+
+    /// Counts the divisors of the number $n$ whose prime factorization this is; this is
+    /// $\tau(n) = \sigma_0(n)$.
+    ///
+    /// $$
+    /// \tau(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m (e_i + 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u8.factor().divisor_count(), 1);
+    /// assert_eq!(12u8.factor().divisor_count(), 6);
+    /// assert_eq!(1000000u32.factor().divisor_count(), 49);
+    /// ```
+    pub fn divisor_count(&self) -> T {
+        let mut tau = T::ONE;
+        for (_, e) in self.pairs() {
+            tau *= T::wrapping_from(e + 1);
+        }
+        tau
+    }
+}
+
+macro_rules! impl_divisor_sigma {
+    ($t:ident) => {
+        impl DivisorSigma for $t {
+            type Output = $t;
+
+            /// Computes the divisor function $\sigma_k(n)$: the sum of the $k$th powers of the
+            /// divisors of $n$.
+            ///
+            /// $$
+            /// \sigma_k(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m \sum_{j=0}^{e_i} p_i^{jk}.
+            /// $$
+            ///
+            /// $\sigma_0(n)$ is the number of divisors of $n$, and $\sigma_1(n)$ is their sum.
+            ///
+            /// If the factorization of $n$ is already known, use
+            /// [`Factors::divisor_sigma`](super::factor::Factors::divisor_sigma) instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0, or if the result is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#divisor_sigma).
+            #[inline]
+            fn divisor_sigma(&self, k: u64) -> $t {
+                self.factor().divisor_sigma(k)
+            }
+        }
+
+        impl CheckedDivisorSigma for $t {
+            type Output = $t;
+
+            /// Computes the divisor function $\sigma_k(n)$: the sum of the $k$th powers of the
+            /// divisors of $n$. Returns `None` if the result is too large to be represented.
+            ///
+            /// $$
+            /// \sigma_k(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m \sum_{j=0}^{e_i} p_i^{jk}.
+            /// $$
+            ///
+            /// If the factorization of $n$ is already known, use
+            /// [`Factors::checked_divisor_sigma`](super::factor::Factors::checked_divisor_sigma)
+            /// instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#checked_divisor_sigma).
+            #[inline]
+            fn checked_divisor_sigma(&self, k: u64) -> Option<$t> {
+                self.factor().checked_divisor_sigma(k)
+            }
+        }
+
+        impl DivisorCount for $t {
+            type Output = $t;
+
+            /// Counts the divisors of $n$; this is $\tau(n) = \sigma_0(n)$.
+            ///
+            /// $$
+            /// \tau(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m (e_i + 1).
+            /// $$
+            ///
+            /// If the factorization of $n$ is already known, use
+            /// [`Factors::divisor_count`](super::factor::Factors::divisor_count) instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#divisor_count).
+            #[inline]
+            fn divisor_count(&self) -> $t {
+                self.factor().divisor_count()
+            }
+        }
+    };
+}
+impl_divisor_sigma!(u8);
+impl_divisor_sigma!(u16);
+impl_divisor_sigma!(u32);
+impl_divisor_sigma!(u64);
+impl_divisor_sigma!(usize);
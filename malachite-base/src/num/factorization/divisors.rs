@@ -0,0 +1,154 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::traits::One;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::factor::Factors;
+use crate::num::factorization::traits::{Divisors, Factor};
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::ops::Mul;
+
+/// An iterator over the divisors of a number, in ascending order.
+///
+/// The divisors are generated lazily from the number's prime factorization, so that the smallest
+/// few divisors of a number with very many divisors may be obtained cheaply.
+///
+/// This `struct` is created by [`Divisors::divisors`] and by
+/// [`Factors::divisors`](super::factor::Factors::divisors); see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct DivisorsIterator<T> {
+    primes: Vec<T>,
+    exponents: Vec<u64>,
+    // Each entry is a divisor $d$ that has not been produced yet, together with $j$ and $c$: the
+    // largest prime dividing $d$ is `primes[j - 1]` and its exponent in $d$ is $c$ (or $j = 0$ if
+    // $d = 1$). A divisor's successors are obtained by raising the exponent of its largest prime,
+    // or by multiplying it by a larger prime; this way, every divisor is reached exactly once.
+    heap: BinaryHeap<Reverse<(T, usize, u64)>>,
+}
+
+impl<T: Clone + Ord + One> DivisorsIterator<T> {
+    /// Creates an iterator over the divisors of the number with the given prime factorization,
+    /// which is given as pairs $(p, e)$ where $p$ is prime and $e$ is the exponent of $p$. The
+    /// primes must be distinct, and the exponents nonzero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of pairs.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::factorization::divisors::DivisorsIterator;
+    ///
+    /// assert_eq!(
+    ///     DivisorsIterator::from_prime_factors([(2u32, 2), (3, 1)]).collect_vec(),
+    ///     &[1, 2, 3, 4, 6, 12]
+    /// );
+    /// ```
+    pub fn from_prime_factors<I: IntoIterator<Item = (T, u64)>>(factors: I) -> Self {
+        let (primes, exponents) = factors.into_iter().unzip();
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((T::ONE, 0, 0)));
+        Self {
+            primes,
+            exponents,
+            heap,
+        }
+    }
+}
+
+impl<T: Clone + Ord> Iterator for DivisorsIterator<T>
+where
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let Reverse((d, j, c)) = self.heap.pop()?;
+        if j != 0 && c < self.exponents[j - 1] {
+            self.heap
+                .push(Reverse((&d * &self.primes[j - 1], j, c + 1)));
+        }
+        for (i, p) in self.primes.iter().enumerate().skip(j) {
+            self.heap.push(Reverse((&d * p, i + 1, 1)));
+        }
+        Some(d)
+    }
+}
+
+impl<T: PrimitiveUnsigned, const N: usize> Factors<T, N> {
+    /// Returns an iterator over the divisors of the number whose prime factorization this is, in
+    /// ascending order.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u8.factor().divisors().collect_vec(), &[1]);
+    /// assert_eq!(
+    ///     60u16.factor().divisors().collect_vec(),
+    ///     &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]
+    /// );
+    /// ```
+    pub fn divisors(&self) -> DivisorsIterator<T> {
+        DivisorsIterator::from_prime_factors(self.pairs().map(|(p, e)| (p, u64::from(e))))
+    }
+}
+
+macro_rules! impl_divisors {
+    ($t:ident) => {
+        impl Divisors for $t {
+            type I = DivisorsIterator<$t>;
+
+            /// Returns an iterator over the divisors of a number, in ascending order.
+            ///
+            /// The divisors are generated lazily, so it is cheap to find the smallest few divisors
+            /// of a number with many divisors. Each iteration takes time logarithmic in the number
+            /// of divisors.
+            ///
+            /// If the factorization of $n$ is already known, use
+            /// [`Factors::divisors`](super::factor::Factors::divisors) instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors).
+            #[inline]
+            fn divisors(&self) -> DivisorsIterator<$t> {
+                self.factor().divisors()
+            }
+        }
+    };
+}
+impl_divisors!(u8);
+impl_divisors!(u16);
+impl_divisors!(u32);
+impl_divisors!(u64);
+impl_divisors!(usize);
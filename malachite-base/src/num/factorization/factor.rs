@@ -82,6 +82,15 @@ impl<T: PrimitiveUnsigned, const N: usize> Factors<T, N> {
         }
     }
 
+    // The prime factors and their exponents, in ascending order of the primes.
+    pub(crate) fn pairs(&self) -> impl Iterator<Item = (T, u8)> + '_ {
+        self.factors
+            .iter()
+            .copied()
+            .zip(self.exponents.iter().copied())
+            .take_while(|&(_, e)| e != 0)
+    }
+
     // This takes linear time in the number of factors, but that's ok because the number of factors
     // is small.
     //
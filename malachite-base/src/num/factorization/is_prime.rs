@@ -29,7 +29,7 @@ use crate::num::arithmetic::traits::{
 use crate::num::basic::integers::{PrimitiveInt, USIZE_IS_U32};
 use crate::num::comparison::traits::PartialOrdAbs;
 use crate::num::conversion::traits::WrappingFrom;
-use crate::num::factorization::traits::IsPrime;
+use crate::num::factorization::traits::{IsPrime, ProvePrime};
 use crate::num::logic::traits::{BitAccess, LeadingZeros, SignificantBits, TrailingZeros};
 
 // This is FLINT_ODD_PRIME_LOOKUP when FLINT64 is true, from ulong_extras/is_oddprime.c, FLINT
@@ -863,3 +863,30 @@ impl IsPrime for usize {
         }
     }
 }
+
+macro_rules! impl_prove_prime {
+    ($t: ident) => {
+        impl ProvePrime for $t {
+            /// Determines, with a proof, whether a primitive unsigned integer is prime.
+            ///
+            /// The primality test used by [`is_prime`](IsPrime::is_prime) is deterministic for every
+            /// value of this type, so the answer is always known, and this function never returns
+            /// `None`.
+            ///
+            /// # Worst-case complexity
+            /// Same as [`is_prime`](IsPrime::is_prime).
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#prove_prime).
+            #[inline]
+            fn prove_prime(&self) -> Option<bool> {
+                Some(self.is_prime())
+            }
+        }
+    };
+}
+impl_prove_prime!(u8);
+impl_prove_prime!(u16);
+impl_prove_prime!(u32);
+impl_prove_prime!(u64);
+impl_prove_prime!(usize);
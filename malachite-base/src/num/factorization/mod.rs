@@ -12,6 +12,61 @@ use crate::num::basic::integers::PrimitiveInt;
 // (`is_square`) and the prime sieve.
 pub(crate) const TWICE_U64_WIDTH: u64 = u64::WIDTH << 1;
 
+/// [`DivisorSigma`](traits::DivisorSigma), [`CheckedDivisorSigma`](traits::CheckedDivisorSigma),
+/// and [`DivisorCount`](traits::DivisorCount), traits for computing sums of powers of divisors and
+/// counting divisors.
+///
+/// # divisor_sigma
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorSigma;
+///
+/// assert_eq!(1u8.divisor_sigma(1), 1);
+/// assert_eq!(12u8.divisor_sigma(0), 6);
+/// assert_eq!(12u8.divisor_sigma(1), 28);
+/// assert_eq!(12u16.divisor_sigma(2), 210);
+/// assert_eq!(1000000u32.divisor_sigma(1), 2480437);
+/// ```
+///
+/// # checked_divisor_sigma
+/// ```
+/// use malachite_base::num::factorization::traits::CheckedDivisorSigma;
+///
+/// assert_eq!(12u8.checked_divisor_sigma(1), Some(28));
+/// assert_eq!(16u8.checked_divisor_sigma(2), None);
+/// assert_eq!(12u16.checked_divisor_sigma(2), Some(210));
+/// assert_eq!(240u8.checked_divisor_sigma(1), None);
+/// ```
+///
+/// # divisor_count
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorCount;
+///
+/// assert_eq!(1u8.divisor_count(), 1);
+/// assert_eq!(7u8.divisor_count(), 2);
+/// assert_eq!(12u8.divisor_count(), 6);
+/// assert_eq!(1000000u32.divisor_count(), 49);
+/// ```
+pub mod divisor_sigma;
+/// [`Divisors`](traits::Divisors), a trait for iterating over the divisors of a number in
+/// ascending order.
+///
+/// # divisors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::Divisors;
+///
+/// assert_eq!(1u8.divisors().collect_vec(), &[1]);
+/// assert_eq!(13u8.divisors().collect_vec(), &[1, 13]);
+/// assert_eq!(
+///     60u16.divisors().collect_vec(),
+///     &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]
+/// );
+/// assert_eq!(
+///     1000000u32.divisors().take(10).collect_vec(),
+///     &[1, 2, 4, 5, 8, 10, 16, 20, 25, 32]
+/// );
+/// ```
+pub mod divisors;
 /// [`Factor`](traits::Factor), a trait for computing the prime factorization of a number.
 pub mod factor;
 /// [`IsPower`](traits::IsPower) and [`ExpressAsPower`](traits::ExpressAsPower), traits for testing
@@ -51,7 +106,19 @@ pub mod factor;
 /// assert!(3u64.express_as_power().is_none());
 /// ```
 pub mod is_power;
-/// [`IsPrime`](traits::IsPrime), a trait for testing a number for primality.
+/// [`IsPrime`](traits::IsPrime) and [`ProvePrime`](traits::ProvePrime), traits for testing a
+/// number for primality.
+///
+/// # prove_prime
+/// ```
+/// use malachite_base::num::factorization::traits::ProvePrime;
+///
+/// assert_eq!(5u8.prove_prime(), Some(true));
+/// assert_eq!(6u16.prove_prime(), Some(false));
+/// assert_eq!(4294967291u32.prove_prime(), Some(true));
+/// assert_eq!(5509785649208481923u64.prove_prime(), Some(true));
+/// assert_eq!(5509785649208481925u64.prove_prime(), Some(false));
+/// ```
 pub mod is_prime;
 /// [`IsSquare`](traits::IsSquare), a trait for testing if a number if a perfect square.
 ///
@@ -70,6 +137,43 @@ pub mod is_prime;
 /// assert!(!128u64.is_square());
 /// ```
 pub mod is_square;
+/// [`MoebiusMu`](traits::MoebiusMu), a trait for computing the Möbius function of a number.
+///
+/// # moebius_mu
+/// ```
+/// use malachite_base::num::factorization::traits::MoebiusMu;
+///
+/// assert_eq!(1u8.moebius_mu(), 1);
+/// assert_eq!(2u8.moebius_mu(), -1);
+/// assert_eq!(6u8.moebius_mu(), 1);
+/// assert_eq!(12u8.moebius_mu(), 0);
+/// assert_eq!(30u16.moebius_mu(), -1);
+/// ```
+pub mod moebius_mu;
+/// [`NextPrime`](traits::NextPrime) and [`PrevPrime`](traits::PrevPrime), traits for finding the
+/// nearest prime above or below a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NextPrime;
+///
+/// assert_eq!(0u8.next_prime(), Some(2));
+/// assert_eq!(7u16.next_prime(), Some(11));
+/// assert_eq!(1000000u32.next_prime(), Some(1000003));
+/// assert_eq!(251u8.next_prime(), None);
+/// assert_eq!(18446744073709551556u64.next_prime(), Some(18446744073709551557));
+/// ```
+///
+/// # prev_prime
+/// ```
+/// use malachite_base::num::factorization::traits::PrevPrime;
+///
+/// assert_eq!(2u8.prev_prime(), None);
+/// assert_eq!(3u16.prev_prime(), Some(2));
+/// assert_eq!(1000000u32.prev_prime(), Some(999983));
+/// assert_eq!(u64::MAX.prev_prime(), Some(18446744073709551557));
+/// ```
+pub mod next_prime;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
@@ -127,6 +231,34 @@ pub mod prime_sieve;
 /// );
 /// ```
 pub mod primes;
+/// [`PrimitiveRoot`](traits::PrimitiveRoot) and [`IsPrimitiveRoot`](traits::IsPrimitiveRoot),
+/// traits for finding and recognizing primitive roots modulo a number.
+///
+/// # primitive_root
+/// ```
+/// use malachite_base::num::factorization::traits::PrimitiveRoot;
+///
+/// assert_eq!(1u8.primitive_root(), Some(0));
+/// assert_eq!(4u8.primitive_root(), Some(3));
+/// assert_eq!(7u8.primitive_root(), Some(3));
+/// assert_eq!(8u8.primitive_root(), None);
+/// assert_eq!(9u8.primitive_root(), Some(2));
+/// assert_eq!(18u16.primitive_root(), Some(5));
+/// assert_eq!(12u16.primitive_root(), None);
+/// assert_eq!(4294967291u32.primitive_root(), Some(2));
+/// ```
+///
+/// # is_primitive_root
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrimitiveRoot;
+///
+/// assert_eq!(3u8.is_primitive_root(7), true);
+/// assert_eq!(2u8.is_primitive_root(7), false);
+/// assert_eq!(5u8.is_primitive_root(18), true);
+/// assert_eq!(3u8.is_primitive_root(8), false);
+/// assert_eq!(0u8.is_primitive_root(1), true);
+/// ```
+pub mod primitive_root;
 /// [`PrimitiveRootPrime`](traits::PrimitiveRootPrime), a trait for finding a primitive root modulo
 /// a prime number.
 ///
@@ -172,5 +304,29 @@ pub mod primitive_root_prime;
 /// assert_eq!(x, 1);
 /// ```
 pub mod remove_power;
+/// [`Totient`](traits::Totient) and [`CarmichaelLambda`](traits::CarmichaelLambda), traits for
+/// computing Euler's totient function and the Carmichael function of a number.
+///
+/// # totient
+/// ```
+/// use malachite_base::num::factorization::traits::Totient;
+///
+/// assert_eq!(1u8.totient(), 1);
+/// assert_eq!(7u8.totient(), 6);
+/// assert_eq!(36u8.totient(), 12);
+/// assert_eq!(1000000u32.totient(), 400000);
+/// ```
+///
+/// # carmichael_lambda
+/// ```
+/// use malachite_base::num::factorization::traits::CarmichaelLambda;
+///
+/// assert_eq!(1u8.carmichael_lambda(), 1);
+/// assert_eq!(8u8.carmichael_lambda(), 2);
+/// assert_eq!(15u8.carmichael_lambda(), 4);
+/// assert_eq!(561u16.carmichael_lambda(), 80);
+/// assert_eq!(1000000u32.carmichael_lambda(), 50000);
+/// ```
+pub mod totient;
 /// Various traits for generating primes, primality testing, and factorization.
 pub mod traits;
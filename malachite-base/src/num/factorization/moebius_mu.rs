@@ -0,0 +1,78 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::factor::Factors;
+use crate::num::factorization::traits::{Factor, MoebiusMu};
+
+impl<T: PrimitiveUnsigned, const N: usize> Factors<T, N> {
+    /// Computes the Möbius function $\mu(n)$ of the number $n$ whose prime factorization this is.
+    ///
+    /// $\mu(n)$ is 0 if $n$ is divisible by the square of a prime, and $(-1)^k$ if $n$ is the
+    /// product of $k$ distinct primes.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u8.factor().moebius_mu(), 1);
+    /// assert_eq!(30u8.factor().moebius_mu(), -1);
+    /// assert_eq!(12u16.factor().moebius_mu(), 0);
+    /// assert_eq!(1001u32.factor().moebius_mu(), -1);
+    /// ```
+    pub fn moebius_mu(&self) -> i8 {
+        let mut mu = 1;
+        for (_, e) in self.pairs() {
+            if e > 1 {
+                return 0;
+            }
+            mu = -mu;
+        }
+        mu
+    }
+}
+
+macro_rules! impl_moebius_mu {
+    ($t:ident) => {
+        impl MoebiusMu for $t {
+            /// Computes the Möbius function $\mu(n)$.
+            ///
+            /// $\mu(n)$ is 0 if $n$ is divisible by the square of a prime, and $(-1)^k$ if $n$ is
+            /// the product of $k$ distinct primes. In particular, $\mu(1) = 1$.
+            ///
+            /// If the factorization of $n$ is already known, use
+            /// [`Factors::moebius_mu`](super::factor::Factors::moebius_mu) instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::moebius_mu#moebius_mu).
+            #[inline]
+            fn moebius_mu(&self) -> i8 {
+                self.factor().moebius_mu()
+            }
+        }
+    };
+}
+impl_moebius_mu!(u8);
+impl_moebius_mu!(u16);
+impl_moebius_mu!(u32);
+impl_moebius_mu!(u64);
+impl_moebius_mu!(usize);
@@ -0,0 +1,96 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+
+fn next_prime<T: PrimitiveUnsigned + IsPrime>(n: T) -> Option<T> {
+    if n < T::TWO {
+        return Some(T::TWO);
+    }
+    // only odd candidates need to be tested
+    let mut p = n.checked_add(if n.even() { T::ONE } else { T::TWO })?;
+    while !p.is_prime() {
+        p = p.checked_add(T::TWO)?;
+    }
+    Some(p)
+}
+
+fn prev_prime<T: PrimitiveUnsigned + IsPrime>(n: T) -> Option<T> {
+    let three = T::from(3u8);
+    if n <= three {
+        return if n == three { Some(T::TWO) } else { None };
+    }
+    // only odd candidates need to be tested; the loop stops at 3 at the latest
+    let mut p = n - if n.even() { T::ONE } else { T::TWO };
+    while !p.is_prime() {
+        p -= T::TWO;
+    }
+    Some(p)
+}
+
+macro_rules! impl_next_prime {
+    ($t: ident) => {
+        impl NextPrime for $t {
+            type Output = Option<$t>;
+
+            /// Finds the smallest prime greater than a primitive unsigned integer.
+            ///
+            /// Returns `None` if there is no such prime representable by the type.
+            ///
+            /// Odd candidates are tested one at a time with [`is_prime`](IsPrime::is_prime),
+            /// which is deterministic for primitive integers, so the result is always a prime.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// heuristically, $O(n)$ candidates are tested, each in $O(n)$ time.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#next_prime).
+            #[inline]
+            fn next_prime(&self) -> Option<$t> {
+                next_prime(*self)
+            }
+        }
+
+        impl PrevPrime for $t {
+            type Output = Option<$t>;
+
+            /// Finds the largest prime less than a primitive unsigned integer.
+            ///
+            /// Returns `None` if `self` is less than or equal to 2.
+            ///
+            /// Odd candidates are tested one at a time with [`is_prime`](IsPrime::is_prime),
+            /// which is deterministic for primitive integers, so the result is always a prime.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// heuristically, $O(n)$ candidates are tested, each in $O(n)$ time.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#prev_prime).
+            #[inline]
+            fn prev_prime(&self) -> Option<$t> {
+                prev_prime(*self)
+            }
+        }
+    };
+}
+impl_next_prime!(u8);
+impl_next_prime!(u16);
+impl_next_prime!(u32);
+impl_next_prime!(u64);
+impl_next_prime!(usize);
@@ -0,0 +1,159 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::mod_multiplicative_order::{factor_unsigned, totient_factors};
+use crate::num::arithmetic::mod_sqrt::mod_pow_full_width;
+use crate::num::arithmetic::traits::ModMulPrecomputed;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{Factor, IsPrimitiveRoot, PrimitiveRoot};
+
+// Whether `x`, which must be coprime to `m`, has multiplicative order $\varphi(m)$, given
+// $\varphi(m)$ and its prime factors: this holds exactly when no $x^{\varphi(m)/q}$ is 1.
+fn has_full_order<T: PrimitiveUnsigned>(
+    x: T,
+    m: T,
+    phi: T,
+    phi_factors: &[(T, u64)],
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> bool {
+    phi_factors
+        .iter()
+        .all(|&(q, _)| mod_pow_full_width(x, phi / q, m, data) != T::ONE)
+}
+
+fn product<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    let mut n = T::ONE;
+    for &(q, e) in factors {
+        n *= q.pow(e);
+    }
+    n
+}
+
+// Finds the smallest primitive root modulo `m`, if the multiplicative group modulo `m` is cyclic;
+// that is, if `m` is 1, 2, 4, $p^k$, or $2p^k$ for an odd prime $p$.
+//
+// Every candidate from 2 upwards that is coprime to `m` is tested by checking that its order is
+// $\varphi(m)$. For prime `m` this gives the same result as `primitive_root_prime`.
+fn primitive_root_unsigned<T: Factor + PrimitiveUnsigned>(m: T) -> Option<T>
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    assert_ne!(m, T::ZERO);
+    if m == T::ONE {
+        return Some(T::ZERO);
+    } else if m == T::TWO {
+        return Some(T::ONE);
+    } else if m == T::from(4u8) {
+        return Some(T::from(3u8));
+    }
+    let m_factors = factor_unsigned(m);
+    let odd_factors = if m_factors[0].0 == T::TWO {
+        if m_factors[0].1 != 1 {
+            return None;
+        }
+        &m_factors[1..]
+    } else {
+        &m_factors[..]
+    };
+    if odd_factors.len() != 1 {
+        return None;
+    }
+    let phi_factors = totient_factors(&m_factors);
+    let phi = product(&phi_factors);
+    let data = T::precompute_mod_mul_data(&m);
+    let mut a = T::TWO;
+    loop {
+        if a.gcd(m) == T::ONE && has_full_order(a, m, phi, &phi_factors, &data) {
+            return Some(a);
+        }
+        a += T::ONE;
+    }
+}
+
+fn is_primitive_root_unsigned<T: Factor + PrimitiveUnsigned>(x: T, m: T) -> bool
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+{
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    if m == T::ONE {
+        return true;
+    }
+    if x.gcd(m) != T::ONE {
+        return false;
+    }
+    let phi_factors = totient_factors(&factor_unsigned(m));
+    let phi = product(&phi_factors);
+    has_full_order(x, m, phi, &phi_factors, &T::precompute_mod_mul_data(&m))
+}
+
+macro_rules! impl_primitive_root {
+    ($t:ident) => {
+        impl PrimitiveRoot for $t {
+            type Output = $t;
+
+            /// Finds a primitive root modulo a number $m$: a generator of the multiplicative group
+            /// $(\mathbb{Z}/m\mathbb{Z})^\times$. Returns `None` if that group is not cyclic, which
+            /// is the case unless $m$ is 1, 2, 4, $p^k$, or $2p^k$ for an odd prime $p$.
+            ///
+            /// The smallest primitive root is returned. Modulo 1, this is 0. For prime $m$, the
+            /// result is the same as that of
+            /// [`primitive_root_prime`](super::traits::PrimitiveRootPrime::primitive_root_prime).
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $m$ and $p - 1$; each candidate root is then
+            /// tested with one modular powering per prime factor of $\varphi(m)$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_root#primitive_root).
+            #[inline]
+            fn primitive_root(&self) -> Option<$t> {
+                primitive_root_unsigned(*self)
+            }
+        }
+
+        impl IsPrimitiveRoot<$t> for $t {
+            /// Determines whether a number is a primitive root modulo another number $m$; that is,
+            /// whether its multiplicative order modulo $m$ is $\varphi(m)$. The number must be
+            /// already reduced modulo $m$.
+            ///
+            /// If the multiplicative group modulo $m$ is not cyclic, there are no primitive roots
+            /// and this function always returns `false`. Modulo 1, 0 is a primitive root.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`: the
+            /// cost is dominated by factoring $m$ and $p - 1$ for each prime $p$ dividing $m$.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_root#is_primitive_root).
+            #[inline]
+            fn is_primitive_root(self, m: $t) -> bool {
+                is_primitive_root_unsigned(self, m)
+            }
+        }
+    };
+}
+impl_primitive_root!(u8);
+impl_primitive_root!(u16);
+impl_primitive_root!(u32);
+impl_primitive_root!(u64);
+impl_primitive_root!(usize);
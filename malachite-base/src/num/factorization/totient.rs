@@ -0,0 +1,152 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::factor::Factors;
+use crate::num::factorization::traits::{CarmichaelLambda, Factor, Totient};
+
+impl<T: PrimitiveUnsigned, const N: usize> Factors<T, N> {
+    /// Computes Euler's totient function $\varphi(n)$ of the number $n$ whose prime factorization
+    /// this is.
+    ///
+    /// $$
+    /// \varphi(p_1^{e_1} \cdots p_k^{e_k}) = \prod_{i=1}^k p_i^{e_i - 1}(p_i - 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u8.factor().totient(), 1);
+    /// assert_eq!(36u16.factor().totient(), 12);
+    /// assert_eq!(1000000u32.factor().totient(), 400000);
+    /// ```
+    pub fn totient(&self) -> T {
+        let mut phi = T::ONE;
+        for (p, e) in self.pairs() {
+            phi *= p.pow(u64::from(e - 1)) * (p - T::ONE);
+        }
+        phi
+    }
+
+    /// Computes the Carmichael function $\lambda(n)$ of the number $n$ whose prime factorization
+    /// this is: the exponent of the multiplicative group $(\mathbb{Z}/n\mathbb{Z})^\times$.
+    ///
+    /// $\lambda(n)$ is the least common multiple of $\lambda(p^e)$ over the prime powers $p^e$
+    /// exactly dividing $n$, where $\lambda(p^e) = \varphi(p^e)$, except that $\lambda(2^e) =
+    /// 2^{e-2}$ when $e \geq 3$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u8.factor().carmichael_lambda(), 1);
+    /// assert_eq!(8u8.factor().carmichael_lambda(), 2);
+    /// assert_eq!(561u16.factor().carmichael_lambda(), 80);
+    /// assert_eq!(1000000u32.factor().carmichael_lambda(), 50000);
+    /// ```
+    pub fn carmichael_lambda(&self) -> T {
+        let mut lambda = T::ONE;
+        for (p, e) in self.pairs() {
+            let l = if p == T::TWO && e >= 3 {
+                T::power_of_2(u64::from(e - 2))
+            } else {
+                p.pow(u64::from(e - 1)) * (p - T::ONE)
+            };
+            lambda.lcm_assign(l);
+        }
+        lambda
+    }
+}
+
+macro_rules! impl_totient {
+    ($t:ident) => {
+        impl Totient for $t {
+            type Output = $t;
+
+            /// Computes Euler's totient function $\varphi(n)$: the number of integers in $[1, n]$
+            /// that are coprime to $n$.
+            ///
+            /// $$
+            /// \varphi(p_1^{e_1} \cdots p_k^{e_k}) = \prod_{i=1}^k p_i^{e_i - 1}(p_i - 1).
+            /// $$
+            ///
+            /// If the factorization of $n$ is already known, use
+            /// [`Factors::totient`](super::factor::Factors::totient) instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient).
+            #[inline]
+            fn totient(&self) -> $t {
+                self.factor().totient()
+            }
+        }
+
+        impl CarmichaelLambda for $t {
+            type Output = $t;
+
+            /// Computes the Carmichael function $\lambda(n)$: the smallest positive $m$ such that
+            /// $a^m \equiv 1 \pmod n$ for every $a$ coprime to $n$.
+            ///
+            /// $\lambda(n)$ is the least common multiple of $\lambda(p^e)$ over the prime powers
+            /// $p^e$ exactly dividing $n$, where $\lambda(p^e) = \varphi(p^e)$, except that
+            /// $\lambda(2^e) = 2^{e-2}$ when $e \geq 3$. It always divides $\varphi(n)$.
+            ///
+            /// If the factorization of $n$ is already known, use
+            /// [`Factors::carmichael_lambda`](super::factor::Factors::carmichael_lambda) instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+            /// the cost is dominated by factoring $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#carmichael_lambda).
+            #[inline]
+            fn carmichael_lambda(&self) -> $t {
+                self.factor().carmichael_lambda()
+            }
+        }
+    };
+}
+impl_totient!(u8);
+impl_totient!(u16);
+impl_totient!(u32);
+impl_totient!(u64);
+impl_totient!(usize);
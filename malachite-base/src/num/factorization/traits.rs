@@ -11,6 +11,28 @@ pub trait IsPrime {
     fn is_prime(&self) -> bool;
 }
 
+/// A trait for determining, with a proof, whether a number is prime.
+///
+/// The result is `Some(true)` if the number was proven prime, `Some(false)` if it was proven
+/// composite, and `None` if the implementation's proof method did not apply to it.
+pub trait ProvePrime {
+    fn prove_prime(&self) -> Option<bool>;
+}
+
+/// A trait for finding the smallest prime greater than a number.
+pub trait NextPrime {
+    type Output;
+
+    fn next_prime(&self) -> Self::Output;
+}
+
+/// A trait for finding the largest prime less than a number.
+pub trait PrevPrime {
+    type Output;
+
+    fn prev_prime(&self) -> Self::Output;
+}
+
 /// A trait for testing whether a number is a square.
 pub trait IsSquare {
     fn is_square(&self) -> bool;
@@ -48,6 +70,57 @@ pub trait Factor {
     fn factor(&self) -> Self::FACTORS;
 }
 
+/// A trait for computing Euler's totient function $\varphi(n)$, the number of integers in $[1, n]$
+/// that are coprime to $n$.
+pub trait Totient {
+    type Output;
+
+    fn totient(&self) -> Self::Output;
+}
+
+/// A trait for computing the Carmichael function $\lambda(n)$, the smallest positive $m$ such that
+/// $a^m \equiv 1 \pmod n$ for every $a$ coprime to $n$.
+pub trait CarmichaelLambda {
+    type Output;
+
+    fn carmichael_lambda(&self) -> Self::Output;
+}
+
+/// A trait for computing the Möbius function $\mu(n)$.
+pub trait MoebiusMu {
+    fn moebius_mu(&self) -> i8;
+}
+
+/// A trait for computing the divisor function $\sigma_k(n)$, the sum of the $k$th powers of the
+/// divisors of $n$.
+pub trait DivisorSigma {
+    type Output;
+
+    fn divisor_sigma(&self, k: u64) -> Self::Output;
+}
+
+/// A trait for computing the divisor function $\sigma_k(n)$, the sum of the $k$th powers of the
+/// divisors of $n$, returning `None` if the result is not representable.
+pub trait CheckedDivisorSigma {
+    type Output;
+
+    fn checked_divisor_sigma(&self, k: u64) -> Option<Self::Output>;
+}
+
+/// A trait for counting the divisors of a number.
+pub trait DivisorCount {
+    type Output;
+
+    fn divisor_count(&self) -> Self::Output;
+}
+
+/// A trait for producing an iterator over the divisors of a number, in ascending order.
+pub trait Divisors {
+    type I: Iterator<Item = Self>;
+
+    fn divisors(&self) -> Self::I;
+}
+
 /// A trait for producing iterators of primes.
 pub trait Primes {
     type I: Iterator<Item = Self>;
@@ -66,3 +139,18 @@ pub trait PrimitiveRootPrime {
 
     fn primitive_root_prime(&self) -> Self::Output;
 }
+
+/// A trait for finding a primitive root modulo a number: a generator of the multiplicative group of
+/// residues coprime to the number, if that group is cyclic.
+pub trait PrimitiveRoot {
+    type Output;
+
+    fn primitive_root(&self) -> Option<Self::Output>;
+}
+
+/// A trait for determining whether a number is a primitive root modulo another number $m$. The
+/// number must be already reduced modulo $m$.
+pub trait IsPrimitiveRoot<M = Self> {
+    #[allow(clippy::wrong_self_convention)]
+    fn is_primitive_root(self, m: M) -> bool;
+}
@@ -14,7 +14,7 @@ pub type ExhaustiveRoundingModes = Copied<Iter<'static, RoundingMode>>;
 
 /// Generates all [`RoundingMode`]s.
 ///
-/// The output length is 6.
+/// The output length is 8.
 ///
 /// # Complexity per iteration
 /// Constant time and additional memory.
@@ -27,7 +27,7 @@ pub type ExhaustiveRoundingModes = Copied<Iter<'static, RoundingMode>>;
 ///
 /// assert_eq!(
 ///     exhaustive_rounding_modes().collect_vec(),
-///     &[Down, Up, Floor, Ceiling, Nearest, Exact,]
+///     &[Down, Up, Floor, Ceiling, Nearest, NearestTiesAway, Odd, Exact]
 /// );
 /// ```
 #[inline]
@@ -37,6 +37,11 @@ impl FromStr for RoundingMode {
     /// assert_eq!(RoundingMode::from_str("Floor"), Ok(Floor));
     /// assert_eq!(RoundingMode::from_str("Ceiling"), Ok(Ceiling));
     /// assert_eq!(RoundingMode::from_str("Nearest"), Ok(Nearest));
+    /// assert_eq!(
+    ///     RoundingMode::from_str("NearestTiesAway"),
+    ///     Ok(NearestTiesAway)
+    /// );
+    /// assert_eq!(RoundingMode::from_str("Odd"), Ok(Odd));
     /// assert_eq!(RoundingMode::from_str("Exact"), Ok(Exact));
     /// assert_eq!(RoundingMode::from_str("abc"), Err("abc".to_string()));
     /// ```
@@ -48,6 +53,8 @@ impl FromStr for RoundingMode {
             "Floor" => Ok(Floor),
             "Ceiling" => Ok(Ceiling),
             "Nearest" => Ok(Nearest),
+            "NearestTiesAway" => Ok(NearestTiesAway),
+            "Odd" => Ok(Odd),
             "Exact" => Ok(Exact),
             _ => Err(src.to_string()),
         }
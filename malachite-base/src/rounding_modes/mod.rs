@@ -32,6 +32,20 @@ use crate::rounding_modes::RoundingMode::*;
 /// | -3.5 |      -4 |        -3 |     -3 |   -4 |        -4 | `panic!()` |
 /// | -4.5 |      -5 |        -4 |     -4 |   -5 |        -4 | `panic!()` |
 ///
+/// The two remaining modes round the same values as follows.
+///
+/// | x    | `NearestTiesAway` | `Odd` |
+/// |------|-------------------|-------|
+/// |  3.0 |                 3 |     3 |
+/// |  3.2 |                 3 |     3 |
+/// |  3.8 |                 4 |     3 |
+/// |  3.5 |                 4 |     3 |
+/// |  4.5 |                 5 |     5 |
+/// | -3.2 |                -3 |    -3 |
+/// | -3.8 |                -4 |    -3 |
+/// | -3.5 |                -4 |    -3 |
+/// | -4.5 |                -5 |    -5 |
+///
 /// Sometimes a `RoundingMode` is used in an unusual context, such as rounding an integer to a
 /// floating-point number, in which case further explanation of its behavior is provided at the
 /// usage site.
@@ -67,14 +81,39 @@ pub enum RoundingMode {
     /// nearest even integer. This is also called _bankers' rounding_ and is often used as a
     /// default.
     Nearest,
+    /// Applies the function
+    /// $$
+    ///   x \mapsto \operatorname{sgn}(x) \left \lfloor |x| + \frac{1}{2} \right \rfloor.
+    /// $$
+    /// In other words, it rounds to the nearest integer, and when there's a tie, it rounds away
+    /// from 0. This is the tie-breaking rule taught in school, and the one used by C's `round`
+    /// function and by IEEE 754's roundTiesToAway.
+    NearestTiesAway,
+    /// Applies the function
+    /// $$
+    ///   x \mapsto \\begin{cases}
+    ///       x & x \in \Z \\\\
+    ///       \lfloor x \rfloor &
+    ///  x \notin \Z \\ \text{and} \\ \lfloor x \rfloor \\ \text{is odd} \\\\
+    ///       \lceil x \rceil &
+    ///  x \notin \Z \\ \text{and} \\ \lceil x \rceil \\ \text{is odd.}
+    ///   \\end{cases}
+    /// $$
+    /// In other words, a value that is not already rounded is rounded to whichever of its two
+    /// neighbors is odd. When rounding to a floating-point number, "odd" refers to the last bit of
+    /// the significand. A value rounded to odd with at least two extra bits of precision may then
+    /// be rounded to the target precision, with any other rounding mode, without any
+    /// double-rounding error, so this mode is useful for intermediate results.
+    Odd,
     /// Panics if the value is not already rounded.
     Exact,
 }
 
 impl_named!(RoundingMode);
 
-/// A list of all six rounding modes.
-pub const ROUNDING_MODES: [RoundingMode; 6] = [Down, Up, Floor, Ceiling, Nearest, Exact];
+/// A list of all eight rounding modes.
+pub const ROUNDING_MODES: [RoundingMode; 8] =
+    [Down, Up, Floor, Ceiling, Nearest, NearestTiesAway, Odd, Exact];
 
 /// Iterators that generate [`RoundingMode`]s without repetition.
 pub mod exhaustive;
@@ -28,6 +28,8 @@ use core::ops::Neg;
 /// assert_eq!(-Floor, Ceiling);
 /// assert_eq!(-Ceiling, Floor);
 /// assert_eq!(-Nearest, Nearest);
+/// assert_eq!(-NearestTiesAway, NearestTiesAway);
+/// assert_eq!(-Odd, Odd);
 /// assert_eq!(-Exact, Exact);
 /// ```
 impl Neg for RoundingMode {
@@ -24,6 +24,8 @@ impl Display for RoundingMode {
     /// assert_eq!(Floor.to_string(), "Floor");
     /// assert_eq!(Ceiling.to_string(), "Ceiling");
     /// assert_eq!(Nearest.to_string(), "Nearest");
+    /// assert_eq!(NearestTiesAway.to_string(), "NearestTiesAway");
+    /// assert_eq!(Odd.to_string(), "Odd");
     /// assert_eq!(Exact.to_string(), "Exact");
     /// ```
     #[inline]
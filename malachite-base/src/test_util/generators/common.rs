@@ -137,6 +137,17 @@ impl<T> Generator<T> {
         );
     }
 
+    pub fn test_properties_with_config_and_limit<F: FnMut(T)>(
+        &self,
+        config: &GenConfig,
+        limit: usize,
+        test: F,
+    ) {
+        self.test_properties_with_config_with_limit_and_optional_exhaustive_limit(
+            config, test, limit, true,
+        );
+    }
+
     pub fn test_properties_with_config<F: FnMut(T)>(&self, config: &GenConfig, test: F) {
         self.test_properties_with_config_optional_exhaustive_limit(config, test, true);
     }
@@ -6,4 +6,4 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
-pub const ROUNDING_MODE_CHARS: &str = "CDEFNUacegilnoprstwx";
+pub const ROUNDING_MODE_CHARS: &str = "ACDEFNOTUacdegilnoprstwxy";
@@ -198,6 +198,7 @@ pub mod num {
         pub mod balanced_mod;
         pub mod bell_number;
         pub mod binomial_coefficient;
+        pub mod catalan_number;
         pub mod ceiling;
         pub mod checked_abs;
         pub mod checked_add_mul;
@@ -233,7 +234,9 @@ pub mod num {
         pub mod mod_euclidean;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_log;
         pub mod mod_mul;
+        pub mod mod_multiplicative_order;
         pub mod mod_neg;
         pub mod mod_op;
         pub mod mod_pow;
@@ -256,6 +259,7 @@ pub mod num {
         pub mod mul_add_mul;
         pub mod mul_shr_round;
         pub mod mul_sub_mul;
+        pub mod multinomial_coefficient;
         pub mod neg;
         pub mod next_power_of_2;
         pub mod overflowing_abs;
@@ -269,6 +273,7 @@ pub mod num {
         pub mod overflowing_sub;
         pub mod overflowing_sub_mul;
         pub mod parity;
+        pub mod partition_number;
         pub mod pow;
         pub mod power_of_2;
         pub mod primorial;
@@ -292,6 +297,7 @@ pub mod num {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling_number;
         pub mod sub_mul;
         pub mod wrapping_abs;
         pub mod wrapping_add;
@@ -407,16 +413,22 @@ pub mod num {
         pub mod primitive_int_increasing_range;
     }
     pub mod factorization {
+        pub mod divisor_sigma;
+        pub mod divisors;
         pub mod factor;
         pub mod is_power;
         pub mod is_prime;
         pub mod is_square;
+        pub mod moebius_mu;
+        pub mod next_prime;
         pub mod prime_indicator_sequence;
         pub mod prime_indicator_sequence_less_than;
         pub mod prime_sieve;
         pub mod primes;
+        pub mod primitive_root;
         pub mod primitive_root_prime;
         pub mod remove_power;
+        pub mod totient;
     }
     pub mod float {
         pub mod basic {
@@ -0,0 +1,56 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, CatalanNumber, CheckedCatalanNumber,
+};
+
+#[test]
+fn test_catalan_number() {
+    assert_eq!(u8::catalan_number(0), 1);
+    assert_eq!(u8::catalan_number(1), 1);
+    assert_eq!(u8::catalan_number(4), 14);
+    assert_eq!(u8::catalan_number(6), 132);
+    assert_eq!(u16::catalan_number(11), 58786);
+    assert_eq!(u32::catalan_number(19), 1767263190);
+    assert_eq!(u64::catalan_number(36), 11959798385860453492);
+    assert_eq!(
+        u128::catalan_number(69),
+        337485502510215975556783793455058624700
+    );
+}
+
+#[test]
+fn checked_catalan_number_properties() {
+    // C_n = C(2n, n) / (n + 1), checked in u128 as far as C(2n, n) fits.
+    for n in 0..=64u64 {
+        assert_eq!(
+            u128::catalan_number(n),
+            u128::binomial_coefficient(u128::from(n) << 1, u128::from(n)) / u128::from(n + 1)
+        );
+    }
+    fn check<T: CheckedCatalanNumber + Copy + Into<u128>>(last: u64) {
+        for n in 0..=last {
+            let x = T::checked_catalan_number(n).unwrap();
+            assert_eq!(x.into(), u128::catalan_number(n));
+        }
+        assert!(T::checked_catalan_number(last + 1).is_none());
+    }
+    check::<u8>(6);
+    check::<u16>(11);
+    check::<u32>(19);
+    check::<u64>(36);
+    check::<u128>(69);
+    assert!(u8::checked_catalan_number(1 << 40).is_none());
+}
+
+#[test]
+#[should_panic]
+fn catalan_number_fail() {
+    u8::catalan_number(7);
+}
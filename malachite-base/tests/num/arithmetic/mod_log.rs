@@ -0,0 +1,310 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{ModLog, ModLogWithOrderFactorization};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_triple_gen_var_12};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_log() {
+    fn test<T: ModLog<T, Output = T> + PrimitiveUnsigned>(x: T, b: T, m: T, out: Option<T>) {
+        assert_eq!(x.mod_log(b, m), out);
+    }
+    // - m == 1
+    test(0u8, 0, 1, Some(0));
+    // - x == 1
+    test(1u8, 0, 5, Some(0));
+    test(1u16, 7, 10, Some(0));
+    test(1u32, 1, 100, Some(0));
+    test(1u64, 2, 15, Some(0));
+    // - b shares a factor with m, and a solution is found while removing it
+    test(0u8, 0, 5, Some(1));
+    test(0u16, 6, 8, Some(3));
+    test(4u32, 6, 8, Some(2));
+    test(0u64, 2, 16, Some(4));
+    test(8usize, 2, 16, Some(3));
+    test(10u8, 10, 100, Some(1));
+    test(0u16, 10, 100, Some(2));
+    test(0u32, 12, 18, Some(2));
+    // - b shares a factor with m, and x does not have it
+    test(3u8, 0, 5, None);
+    test(3u16, 2, 8, None);
+    test(18u32, 12, 36, None);
+    test(72u64, 6, 216, None);
+    // - b shares a factor with m, and the solution is larger
+    test(3u32, 3, 1024, Some(1));
+    // - b coprime to m
+    test(13u8, 3, 17, Some(4));
+    test(2u8, 4, 7, Some(2));
+    test(9u16, 3, 10, Some(2));
+    test(4u32, 2, 15, Some(2));
+    test(5u64, 2, 15, None);
+    test(7usize, 3, 100, Some(15));
+    test(12345u32, 5, 65537, Some(51713));
+    test(12345u64, 5, 65537, Some(51713));
+    // - prime m whose group order has a large prime factor, found by baby-step giant-step
+    test(3u32, 2, 4294967291, Some(4210018482));
+    test(1000000007u32, 3, 4294967291, Some(540595726));
+    test(4294967290u32, 7, 4294967291, None);
+    // - prime m = 2 ^ 61 - 1, whose group order is smooth
+    test(
+        2305843009213693950u64,
+        7,
+        2305843009213693951,
+        Some(343642773355245),
+    );
+    test(3u64, 2, 2305843009213693951, None);
+    // - a safe prime, whose group order has a prime factor above 2 ^ 32, found by Pollard rho
+    test(123456789u64, 5, 137438954447, Some(90926588829));
+    test(2u64, 5, 137438954447, Some(40656219268));
+    test(987654321u64, 3, 137438954447, None);
+    // - the largest prime below 2 ^ 64
+    test(3u64, 2, 18446744073709551557, Some(13738032082084024111));
+    test(
+        18446744073709551556u64,
+        7,
+        18446744073709551557,
+        Some(9223372036854775778),
+    );
+    // - a product of two primes
+    test(
+        699486311075180979u64,
+        7,
+        4611685975477714963,
+        Some(123456789012),
+    );
+    test(5u64, 3, 4611685975477714963, None);
+}
+
+fn mod_log_fail_helper<T: ModLog<T, Output = T> + PrimitiveUnsigned>() {
+    assert_panic!(T::from(3u8).mod_log(T::ONE, T::from(3u8)));
+    assert_panic!(T::ONE.mod_log(T::from(3u8), T::from(3u8)));
+    assert_panic!(T::ZERO.mod_log(T::ZERO, T::ZERO));
+}
+
+#[test]
+fn mod_log_fail() {
+    mod_log_fail_helper::<u8>();
+    mod_log_fail_helper::<u16>();
+    mod_log_fail_helper::<u32>();
+    mod_log_fail_helper::<u64>();
+    mod_log_fail_helper::<usize>();
+}
+
+#[test]
+fn test_mod_log_with_order_factorization() {
+    fn test<T: ModLogWithOrderFactorization<T, Output = T> + PrimitiveUnsigned>(
+        x: T,
+        b: T,
+        m: T,
+        order_factors: &[(T, u64)],
+        out: Option<T>,
+    ) {
+        assert_eq!(x.mod_log_with_order_factorization(b, m, order_factors), out);
+    }
+    test(0u8, 0, 1, &[], Some(0));
+    test(1u8, 1, 5, &[], Some(0));
+    test(3u8, 1, 5, &[], None);
+    test(13u8, 3, 17, &[(2, 4)], Some(4));
+    // 3 ^ 4 = 81 = 1 mod 10, so 4 = 2 ^ 2 is a multiple of the order
+    test(9u16, 3, 10, &[(2, 2)], Some(2));
+    // the order of 9 modulo 17 is 8, and 5 is not a power of 9
+    test(5u32, 9, 17, &[(2, 4)], None);
+    test(12345u32, 5, 65537, &[(2, 16)], Some(51713));
+    test(12345u64, 5, 65537, &[(2, 16)], Some(51713));
+    test(12345u128, 5, 65537, &[(2, 16)], Some(51713));
+    test(
+        123456789u64,
+        5,
+        137438954447,
+        &[(2, 1), (68719477223, 1)],
+        Some(90926588829),
+    );
+    test(
+        123456789u128,
+        5,
+        137438954447,
+        &[(2, 1), (68719477223, 1)],
+        Some(90926588829),
+    );
+    test(
+        987654321u128,
+        3,
+        137438954447,
+        &[(2, 1), (68719477223, 1)],
+        None,
+    );
+    // - a modulus beyond u64, with a smooth group order
+    test(
+        3u128,
+        3,
+        170141183460469231731687303715884105727,
+        &[
+            (2, 1),
+            (3, 3),
+            (7, 2),
+            (19, 1),
+            (43, 1),
+            (73, 1),
+            (127, 1),
+            (337, 1),
+            (5419, 1),
+            (92737, 1),
+            (649657, 1),
+            (77158673929, 1),
+        ],
+        Some(1),
+    );
+}
+
+fn mod_log_with_order_factorization_fail_helper<
+    T: ModLogWithOrderFactorization<T, Output = T> + PrimitiveUnsigned,
+>() {
+    assert_panic!(T::from(3u8).mod_log_with_order_factorization(
+        T::ONE,
+        T::from(3u8),
+        &[]
+    ));
+    assert_panic!(T::ONE.mod_log_with_order_factorization(
+        T::from(3u8),
+        T::from(3u8),
+        &[]
+    ));
+    // 3 ^ 2 != 1 mod 5
+    assert_panic!(T::ONE.mod_log_with_order_factorization(
+        T::from(3u8),
+        T::from(5u8),
+        &[(T::TWO, 1)]
+    ));
+    // not coprime, so no power of the base is 1
+    assert_panic!(T::ONE.mod_log_with_order_factorization(
+        T::TWO,
+        T::from(4u8),
+        &[(T::TWO, 1)]
+    ));
+    assert_panic!(T::ONE.mod_log_with_order_factorization(
+        T::ONE,
+        T::from(5u8),
+        &[(T::TWO, T::WIDTH)]
+    ));
+}
+
+#[test]
+fn mod_log_with_order_factorization_fail() {
+    apply_fn_to_unsigneds!(mod_log_with_order_factorization_fail_helper);
+}
+
+// The smallest k with b ^ k = x mod m, by trying every exponent. The powers of b modulo m repeat
+// after at most m steps.
+fn mod_log_naive<T: PrimitiveUnsigned>(x: T, b: T, m: T) -> Option<T> {
+    let mut y = T::ONE % m;
+    let mut k = T::ZERO;
+    loop {
+        if y == x {
+            return Some(k);
+        }
+        if k == m {
+            return None;
+        }
+        y.mod_mul_assign(b, m);
+        k += T::ONE;
+    }
+}
+
+fn mod_log_properties_helper<
+    T: Factor
+        + IsPrime
+        + ModLog<T, Output = T>
+        + ModLogWithOrderFactorization<T, Output = T>
+        + PrimitiveUnsigned,
+>()
+where
+    <T as Factor>::FACTORS: IntoIterator<Item = (T, u8)>,
+    u64: ExactFrom<T>,
+{
+    unsigned_triple_gen_var_12::<T>().test_properties(|(x, b, m)| {
+        let log = x.mod_log(b, m);
+        if let Some(k) = log {
+            assert!(k < m);
+            assert_eq!(b.mod_pow(u64::exact_from(k), m), x);
+        }
+        if m < T::from(u8::MAX) {
+            assert_eq!(log, mod_log_naive(x, b, m));
+        }
+        if m.is_prime() && b != T::ZERO {
+            let order_factors: Vec<(T, u64)> = (m - T::ONE)
+                .factor()
+                .into_iter()
+                .map(|(q, e)| (q, u64::from(e)))
+                .collect();
+            assert_eq!(
+                x.mod_log_with_order_factorization(b, m, &order_factors),
+                log
+            );
+        }
+
+        // Taking the logarithm of a power of b gives an exponent that is no larger.
+        let y = b.mod_pow(u64::exact_from(x), m);
+        let k = y.mod_log(b, m).unwrap();
+        assert!(k <= x);
+        assert_eq!(b.mod_pow(u64::exact_from(k), m), y);
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        if m == T::ONE {
+            assert_eq!(T::ZERO.mod_log(T::ZERO, m), Some(T::ZERO));
+        } else {
+            assert_eq!(T::ONE.mod_log(T::ZERO, m), Some(T::ZERO));
+            assert_eq!(T::ZERO.mod_log(T::ZERO, m), Some(T::ONE));
+            assert_eq!(T::ONE.mod_log(T::ONE, m), Some(T::ZERO));
+        }
+    });
+}
+
+#[test]
+fn mod_log_properties() {
+    mod_log_properties_helper::<u8>();
+    mod_log_properties_helper::<u16>();
+    mod_log_properties_helper::<u32>();
+
+    // widths are consistent with each other
+    unsigned_triple_gen_var_12::<u8>().test_properties(|(x, b, m)| {
+        let log = x.mod_log(b, m);
+        assert_eq!(
+            u16::from(x)
+                .mod_log(u16::from(b), u16::from(m))
+                .map(u8::exact_from),
+            log
+        );
+        assert_eq!(
+            u32::from(x)
+                .mod_log(u32::from(b), u32::from(m))
+                .map(u8::exact_from),
+            log
+        );
+    });
+    unsigned_triple_gen_var_12::<u32>().test_properties(|(x, b, m)| {
+        let log = x.mod_log(b, m);
+        assert_eq!(
+            u64::from(x)
+                .mod_log(u64::from(b), u64::from(m))
+                .map(u32::exact_from),
+            log
+        );
+        assert_eq!(
+            usize::exact_from(x)
+                .mod_log(usize::exact_from(b), usize::exact_from(m))
+                .map(u32::exact_from),
+            log
+        );
+    });
+}
@@ -0,0 +1,150 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::ModMultiplicativeOrder;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, PrimitiveRootPrime};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_29, unsigned_pair_gen_var_16};
+use std::panic::catch_unwind;
+
+fn mod_multiplicative_order_helper<T: ModMultiplicativeOrder<T, Output = T> + PrimitiveUnsigned>() {
+    let test = |x: u64, m: u64, out: Option<u64>| {
+        if let (Ok(x), Ok(m)) = (T::try_from(x), T::try_from(m)) {
+            assert_eq!(x.mod_multiplicative_order(m), out.map(|k| T::exact_from(k)));
+        }
+    };
+    // - m == 1
+    test(0, 1, Some(1));
+    // - x == 1
+    test(1, 2, Some(1));
+    test(1, 100, Some(1));
+    // - x not coprime to m
+    test(0, 5, None);
+    test(2, 4, None);
+    test(6, 9, None);
+    test(10, 100, None);
+    // - x coprime to m
+    test(2, 7, Some(3));
+    test(3, 7, Some(6));
+    test(6, 7, Some(2));
+    test(3, 8, Some(2));
+    test(10, 13, Some(6));
+    test(3, 10, Some(4));
+    test(7, 15, Some(4));
+    test(2, 255, Some(8));
+    test(2, 65537, Some(32));
+    test(3, 65537, Some(65536));
+    test(2, 4294967291, Some(4294967290));
+    test(2, 2305843009213693951, Some(61));
+}
+
+#[test]
+fn test_mod_multiplicative_order() {
+    mod_multiplicative_order_helper::<u8>();
+    mod_multiplicative_order_helper::<u16>();
+    mod_multiplicative_order_helper::<u32>();
+    mod_multiplicative_order_helper::<u64>();
+    mod_multiplicative_order_helper::<usize>();
+}
+
+fn mod_multiplicative_order_fail_helper<
+    T: ModMultiplicativeOrder<T, Output = T> + PrimitiveUnsigned,
+>() {
+    assert_panic!(T::ZERO.mod_multiplicative_order(T::ZERO));
+    assert_panic!(T::from(3u8).mod_multiplicative_order(T::from(3u8)));
+    assert_panic!(T::from(5u8).mod_multiplicative_order(T::from(3u8)));
+}
+
+#[test]
+fn mod_multiplicative_order_fail() {
+    mod_multiplicative_order_fail_helper::<u8>();
+    mod_multiplicative_order_fail_helper::<u16>();
+    mod_multiplicative_order_fail_helper::<u32>();
+    mod_multiplicative_order_fail_helper::<u64>();
+    mod_multiplicative_order_fail_helper::<usize>();
+}
+
+// The smallest positive k with x ^ k = 1 mod m, by repeated multiplication, or `None` if no power
+// of x is 1, which is detected when the powers return to x without reaching 1.
+fn mod_multiplicative_order_naive<T: PrimitiveUnsigned>(x: T, m: T) -> Option<T> {
+    let one = T::ONE % m;
+    let mut y = x;
+    let mut k = T::ONE;
+    loop {
+        if y == one {
+            return Some(k);
+        }
+        y.mod_mul_assign(x, m);
+        k += T::ONE;
+        if y == x || k > m {
+            return None;
+        }
+    }
+}
+
+fn mod_multiplicative_order_properties_helper<
+    T: IsPrime
+        + ModMultiplicativeOrder<T, Output = T>
+        + PrimitiveRootPrime<Output = T>
+        + PrimitiveUnsigned,
+>()
+where
+    u64: ExactFrom<T>,
+{
+    unsigned_pair_gen_var_16::<T>().test_properties_with_limit(TINY_LIMIT, |(x, m)| {
+        let order = x.mod_multiplicative_order(m);
+        assert_eq!(order.is_some(), x.gcd(m) == T::ONE);
+        if let Some(k) = order {
+            assert_ne!(k, T::ZERO);
+            assert!(k < m || m == T::ONE);
+            assert_eq!(x.mod_pow(u64::exact_from(k), m), T::ONE % m);
+            if m < T::from(u8::MAX) {
+                assert_eq!(mod_multiplicative_order_naive(x, m), order);
+            }
+        }
+    });
+
+    unsigned_gen_var_29::<T>().test_properties_with_limit(TINY_LIMIT, |p| {
+        assert_eq!(
+            p.primitive_root_prime().mod_multiplicative_order(p),
+            Some(p - T::ONE)
+        );
+        assert_eq!(
+            (p - T::ONE).mod_multiplicative_order(p),
+            Some(if p == T::TWO { T::ONE } else { T::TWO })
+        );
+    });
+}
+
+#[test]
+fn mod_multiplicative_order_properties() {
+    mod_multiplicative_order_properties_helper::<u8>();
+    mod_multiplicative_order_properties_helper::<u16>();
+    mod_multiplicative_order_properties_helper::<u32>();
+    mod_multiplicative_order_properties_helper::<u64>();
+    mod_multiplicative_order_properties_helper::<usize>();
+
+    unsigned_pair_gen_var_16::<u8>().test_properties(|(x, m)| {
+        let order = x.mod_multiplicative_order(m);
+        assert_eq!(
+            u16::from(x)
+                .mod_multiplicative_order(u16::from(m))
+                .map(u8::exact_from),
+            order
+        );
+        assert_eq!(
+            u64::from(x)
+                .mod_multiplicative_order(u64::from(m))
+                .map(u8::exact_from),
+            order
+        );
+    });
+}
@@ -210,7 +210,7 @@ where
                 Down | Floor => assert_ne!(o, Greater),
                 Up | Ceiling => assert_ne!(o, Less),
                 Exact => assert_eq!(o, Equal),
-                Nearest => {}
+                Nearest | NearestTiesAway | Odd => {}
             }
 
             // all modes relate to the floor as they should
@@ -224,6 +224,17 @@ where
                 let (nearest, o_nearest) = x.mul_shr_round(y, bits, Nearest);
                 assert!(nearest == down || nearest == down + T::ONE);
                 assert_eq!(o_nearest, if nearest == down { Less } else { Greater });
+                let (nearest, o_nearest) = x.mul_shr_round(y, bits, NearestTiesAway);
+                assert!(nearest == down || nearest == down + T::ONE);
+                assert_eq!(o_nearest, if nearest == down { Less } else { Greater });
+                assert_eq!(
+                    x.mul_shr_round(y, bits, Odd),
+                    if down.odd() {
+                        (down, Less)
+                    } else {
+                        (down + T::ONE, Greater)
+                    }
+                );
             }
 
             // A multiplier of 1 turns the operation into shr_round. `Exact` must be skipped: the
@@ -275,7 +286,7 @@ where
                 Down => assert_ne!(o, if negative { Less } else { Greater }),
                 Up => assert_ne!(o, if negative { Greater } else { Less }),
                 Exact => assert_eq!(o, Equal),
-                Nearest => {}
+                Nearest | NearestTiesAway | Odd => {}
             }
 
             // negating one factor negates the result, with the mode and Ordering mirrored
@@ -0,0 +1,81 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, CheckedMultinomialCoefficient, Factorial, MultinomialCoefficient,
+};
+
+#[test]
+fn test_multinomial_coefficient() {
+    assert_eq!(u8::multinomial_coefficient(&[]), 1);
+    assert_eq!(u8::multinomial_coefficient(&[5]), 1);
+    assert_eq!(u8::multinomial_coefficient(&[0, 0, 3]), 1);
+    assert_eq!(u8::multinomial_coefficient(&[2, 2]), 6);
+    assert_eq!(u16::multinomial_coefficient(&[2, 3, 4]), 1260);
+    assert_eq!(u16::multinomial_coefficient(&[5, 3, 2]), 2520);
+    assert_eq!(u32::multinomial_coefficient(&[4, 4, 4]), 34650);
+    assert_eq!(u64::multinomial_coefficient(&[10, 10, 10]), 5550996791340);
+    assert_eq!(u64::multinomial_coefficient(&[1; 20]), 2432902008176640000);
+}
+
+#[test]
+fn checked_multinomial_coefficient_properties() {
+    // Compare every composition with at most 3 parts, each at most 11, against the factorial
+    // formula evaluated in u128; the sum is at most 33, and 33! fits in a u128.
+    fn naive(ks: &[u64]) -> u128 {
+        ks.iter().fold(u128::factorial(ks.iter().sum()), |q, &k| {
+            q / u128::factorial(k)
+        })
+    }
+    for a in 0..12 {
+        for b in 0..12 {
+            for c in 0..12 {
+                let ks = [a, b, c];
+                let x = naive(&ks);
+                assert_eq!(u128::multinomial_coefficient(&ks), x);
+                assert_eq!(
+                    u64::checked_multinomial_coefficient(&ks),
+                    u64::try_from(x).ok()
+                );
+                assert_eq!(
+                    u32::checked_multinomial_coefficient(&ks),
+                    u32::try_from(x).ok()
+                );
+                assert_eq!(
+                    u16::checked_multinomial_coefficient(&ks),
+                    u16::try_from(x).ok()
+                );
+                assert_eq!(
+                    u8::checked_multinomial_coefficient(&ks),
+                    u8::try_from(x).ok()
+                );
+                // - permuting the parts doesn't change the result
+                assert_eq!(u128::multinomial_coefficient(&[c, a, b]), x);
+                // - two parts give a binomial coefficient
+                assert_eq!(
+                    u128::multinomial_coefficient(&[a, b]),
+                    u128::binomial_coefficient(u128::from(a + b), u128::from(a))
+                );
+            }
+        }
+    }
+    // - zero parts are ignored even when the other part is huge, but two huge parts overflow
+    assert_eq!(u8::checked_multinomial_coefficient(&[1000, 0]), Some(1));
+    assert_eq!(u8::checked_multinomial_coefficient(&[1000, 1]), None);
+    assert_eq!(u128::checked_multinomial_coefficient(&[30, 30, 30]), None);
+    assert_eq!(
+        u64::checked_multinomial_coefficient(&[u64::MAX, u64::MAX]),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn multinomial_coefficient_fail() {
+    u8::multinomial_coefficient(&[6, 6]);
+}
@@ -0,0 +1,61 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{CheckedPartitionNumber, PartitionNumber};
+
+#[test]
+fn test_partition_number() {
+    assert_eq!(u8::partition_number(0), 1);
+    assert_eq!(u8::partition_number(1), 1);
+    assert_eq!(u8::partition_number(5), 7);
+    assert_eq!(u8::partition_number(10), 42);
+    assert_eq!(u32::partition_number(50), 204226);
+    assert_eq!(u64::partition_number(200), 3972999029388);
+    assert_eq!(u64::partition_number(416), 17873792969689876004);
+    assert_eq!(
+        u128::partition_number(1458),
+        336988065393447621514574974879775699372
+    );
+}
+
+// Counts the partitions of n into parts no larger than m.
+fn partition_number_naive(n: u64, m: u64) -> u128 {
+    if n == 0 {
+        1
+    } else {
+        (1..=m.min(n))
+            .map(|part| partition_number_naive(n - part, part))
+            .sum()
+    }
+}
+
+#[test]
+fn checked_partition_number_properties() {
+    for n in 0..=40 {
+        assert_eq!(u128::partition_number(n), partition_number_naive(n, n));
+    }
+    fn check<T: CheckedPartitionNumber + Copy + Into<u128>>(last: u64) {
+        for n in 0..=last {
+            let x = T::checked_partition_number(n).unwrap();
+            assert_eq!(x.into(), u128::partition_number(n));
+        }
+        assert!(T::checked_partition_number(last + 1).is_none());
+    }
+    check::<u8>(16);
+    check::<u16>(43);
+    check::<u32>(127);
+    check::<u64>(416);
+    check::<u128>(1458);
+    assert!(u8::checked_partition_number(1 << 40).is_none());
+}
+
+#[test]
+#[should_panic]
+fn partition_number_fail() {
+    u8::partition_number(17);
+}
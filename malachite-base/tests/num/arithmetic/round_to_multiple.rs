@@ -1242,6 +1242,20 @@ fn round_to_multiple_properties_helper_unsigned<T: PrimitiveUnsigned>() {
                     }
                 }
             }
+            NearestTiesAway => {
+                if y == T::ZERO {
+                    assert_eq!(rounded, T::ZERO);
+                } else if rounded < x {
+                    assert!(x - rounded < y - (x - rounded));
+                } else if rounded > x {
+                    assert!(rounded - x <= y - (rounded - x));
+                }
+            }
+            Odd => {
+                if o != Equal {
+                    assert!(rounded.div_exact(y).odd());
+                }
+            }
         }
 
         if o == Equal {
@@ -1279,7 +1293,7 @@ fn round_to_multiple_properties_helper_unsigned<T: PrimitiveUnsigned>() {
     });
 
     unsigned_rounding_mode_pair_gen::<T>().test_properties(|(n, rm)| {
-        if rm == Floor || rm == Down || rm == Nearest {
+        if rm == Floor || rm == Down || rm == Nearest || rm == NearestTiesAway {
             assert_eq!(
                 n.round_to_multiple(T::ZERO, rm),
                 (T::ZERO, if n == T::ZERO { Equal } else { Less })
@@ -1348,6 +1362,30 @@ fn round_to_multiple_properties_helper_signed<
                     }
                 }
             }
+            NearestTiesAway => {
+                if y == S::ZERO {
+                    assert_eq!(rounded, S::ZERO);
+                } else {
+                    // x and rounded have the same sign, unless rounded is zero
+                    let (x_abs, rounded_abs) = (x.unsigned_abs(), rounded.unsigned_abs());
+                    let y = y.unsigned_abs();
+                    let diff = if x_abs >= rounded_abs {
+                        x_abs - rounded_abs
+                    } else {
+                        rounded_abs - x_abs
+                    };
+                    if rounded_abs < x_abs {
+                        assert!(diff < y - diff);
+                    } else {
+                        assert!(diff <= y - diff);
+                    }
+                }
+            }
+            Odd => {
+                if o != Equal {
+                    assert!(rounded.div_exact(y).odd());
+                }
+            }
         }
 
         if o == Equal {
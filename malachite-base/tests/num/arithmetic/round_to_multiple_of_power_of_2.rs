@@ -154,6 +154,11 @@ fn round_to_multiple_of_power_of_2_properties_helper_unsigned<T: PrimitiveUnsign
                     }
                 }
             }
+            NearestTiesAway | Odd => {
+                if pow < T::WIDTH {
+                    assert_eq!(n.shr_round(pow, rm), (rounded >> pow, o));
+                }
+            }
         }
 
         if o == Equal {
@@ -275,6 +280,11 @@ fn round_to_multiple_of_power_of_2_properties_helper_signed<
                     }
                 }
             }
+            NearestTiesAway | Odd => {
+                if pow < S::WIDTH {
+                    assert_eq!(n.shr_round(pow, rm), (rounded >> pow, o));
+                }
+            }
         }
 
         if o == Equal {
@@ -47,6 +47,8 @@ fn test_shr_round() {
     test::<u8, u8>(0, 0, Floor, 0, Equal);
     test::<u8, u8>(0, 0, Ceiling, 0, Equal);
     test::<u8, u8>(0, 0, Nearest, 0, Equal);
+    test::<u8, u8>(0, 0, NearestTiesAway, 0, Equal);
+    test::<u8, u8>(0, 0, Odd, 0, Equal);
     test::<u8, u8>(0, 0, Exact, 0, Equal);
 
     test::<u8, i16>(0, 10, Down, 0, Equal);
@@ -54,6 +56,8 @@ fn test_shr_round() {
     test::<u8, i16>(0, 10, Floor, 0, Equal);
     test::<u8, i16>(0, 10, Ceiling, 0, Equal);
     test::<u8, i16>(0, 10, Nearest, 0, Equal);
+    test::<u8, i16>(0, 10, NearestTiesAway, 0, Equal);
+    test::<u8, i16>(0, 10, Odd, 0, Equal);
     test::<u8, i16>(0, 10, Exact, 0, Equal);
 
     test::<i8, u32>(123, 0, Down, 123, Equal);
@@ -61,6 +65,8 @@ fn test_shr_round() {
     test::<i8, u32>(123, 0, Floor, 123, Equal);
     test::<i8, u32>(123, 0, Ceiling, 123, Equal);
     test::<i8, u32>(123, 0, Nearest, 123, Equal);
+    test::<i8, u32>(123, 0, NearestTiesAway, 123, Equal);
+    test::<i8, u32>(123, 0, Odd, 123, Equal);
     test::<i8, u32>(123, 0, Exact, 123, Equal);
 
     test::<u8, u64>(245, 1, Down, 122, Less);
@@ -68,12 +74,16 @@ fn test_shr_round() {
     test::<u8, u64>(245, 1, Floor, 122, Less);
     test::<u8, u64>(245, 1, Ceiling, 123, Greater);
     test::<u8, u64>(245, 1, Nearest, 122, Less);
+    test::<u8, u64>(245, 1, NearestTiesAway, 123, Greater);
+    test::<u8, u64>(245, 1, Odd, 123, Greater);
 
     test::<u8, u128>(246, 1, Down, 123, Equal);
     test::<u8, u128>(246, 1, Up, 123, Equal);
     test::<u8, u128>(246, 1, Floor, 123, Equal);
     test::<u8, u128>(246, 1, Ceiling, 123, Equal);
     test::<u8, u128>(246, 1, Nearest, 123, Equal);
+    test::<u8, u128>(246, 1, NearestTiesAway, 123, Equal);
+    test::<u8, u128>(246, 1, Odd, 123, Equal);
     test::<u8, u128>(246, 1, Exact, 123, Equal);
 
     test::<u8, usize>(247, 1, Down, 123, Less);
@@ -81,18 +91,24 @@ fn test_shr_round() {
     test::<u8, usize>(247, 1, Floor, 123, Less);
     test::<u8, usize>(247, 1, Ceiling, 124, Greater);
     test::<u8, usize>(247, 1, Nearest, 124, Greater);
+    test::<u8, usize>(247, 1, NearestTiesAway, 124, Greater);
+    test::<u8, usize>(247, 1, Odd, 123, Less);
 
     test::<i16, i8>(491, 2, Down, 122, Less);
     test::<i16, i8>(491, 2, Up, 123, Greater);
     test::<i16, i8>(491, 2, Floor, 122, Less);
     test::<i16, i8>(491, 2, Ceiling, 123, Greater);
     test::<i16, i8>(491, 2, Nearest, 123, Greater);
+    test::<i16, i8>(491, 2, NearestTiesAway, 123, Greater);
+    test::<i16, i8>(491, 2, Odd, 123, Greater);
 
     test::<u16, i16>(492, 2, Down, 123, Equal);
     test::<u16, i16>(492, 2, Up, 123, Equal);
     test::<u16, i16>(492, 2, Floor, 123, Equal);
     test::<u16, i16>(492, 2, Ceiling, 123, Equal);
     test::<u16, i16>(492, 2, Nearest, 123, Equal);
+    test::<u16, i16>(492, 2, NearestTiesAway, 123, Equal);
+    test::<u16, i16>(492, 2, Odd, 123, Equal);
     test::<u16, i16>(492, 2, Exact, 123, Equal);
 
     test::<i16, u32>(493, 2, Down, 123, Less);
@@ -100,18 +116,24 @@ fn test_shr_round() {
     test::<i16, u32>(493, 2, Floor, 123, Less);
     test::<i16, u32>(493, 2, Ceiling, 124, Greater);
     test::<i16, u32>(493, 2, Nearest, 123, Less);
+    test::<i16, u32>(493, 2, NearestTiesAway, 123, Less);
+    test::<i16, u32>(493, 2, Odd, 123, Less);
 
     test::<u32, i8>(4127195135, 25, Down, 122, Less);
     test::<u32, i8>(4127195135, 25, Up, 123, Greater);
     test::<u32, i8>(4127195135, 25, Floor, 122, Less);
     test::<u32, i8>(4127195135, 25, Ceiling, 123, Greater);
     test::<u32, i8>(4127195135, 25, Nearest, 123, Greater);
+    test::<u32, i8>(4127195135, 25, NearestTiesAway, 123, Greater);
+    test::<u32, i8>(4127195135, 25, Odd, 123, Greater);
 
     test::<u32, u16>(4127195136, 25, Down, 123, Equal);
     test::<u32, u16>(4127195136, 25, Up, 123, Equal);
     test::<u32, u16>(4127195136, 25, Floor, 123, Equal);
     test::<u32, u16>(4127195136, 25, Ceiling, 123, Equal);
     test::<u32, u16>(4127195136, 25, Nearest, 123, Equal);
+    test::<u32, u16>(4127195136, 25, NearestTiesAway, 123, Equal);
+    test::<u32, u16>(4127195136, 25, Odd, 123, Equal);
     test::<u32, u16>(4127195136, 25, Exact, 123, Equal);
 
     test::<u32, i32>(4127195137, 25, Down, 123, Less);
@@ -119,18 +141,24 @@ fn test_shr_round() {
     test::<u32, i32>(4127195137, 25, Floor, 123, Less);
     test::<u32, i32>(4127195137, 25, Ceiling, 124, Greater);
     test::<u32, i32>(4127195137, 25, Nearest, 123, Less);
+    test::<u32, i32>(4127195137, 25, NearestTiesAway, 123, Less);
+    test::<u32, i32>(4127195137, 25, Odd, 123, Less);
 
     test::<i64, u8>(8254390271, 26, Down, 122, Less);
     test::<i64, u8>(8254390271, 26, Up, 123, Greater);
     test::<i64, u8>(8254390271, 26, Floor, 122, Less);
     test::<i64, u8>(8254390271, 26, Ceiling, 123, Greater);
     test::<i64, u8>(8254390271, 26, Nearest, 123, Greater);
+    test::<i64, u8>(8254390271, 26, NearestTiesAway, 123, Greater);
+    test::<i64, u8>(8254390271, 26, Odd, 123, Greater);
 
     test::<u64, i16>(8254390272, 26, Down, 123, Equal);
     test::<u64, i16>(8254390272, 26, Up, 123, Equal);
     test::<u64, i16>(8254390272, 26, Floor, 123, Equal);
     test::<u64, i16>(8254390272, 26, Ceiling, 123, Equal);
     test::<u64, i16>(8254390272, 26, Nearest, 123, Equal);
+    test::<u64, i16>(8254390272, 26, NearestTiesAway, 123, Equal);
+    test::<u64, i16>(8254390272, 26, Odd, 123, Equal);
     test::<u64, i16>(8254390272, 26, Exact, 123, Equal);
 
     test::<i64, u32>(8254390273, 26, Down, 123, Less);
@@ -138,6 +166,8 @@ fn test_shr_round() {
     test::<i64, u32>(8254390273, 26, Floor, 123, Less);
     test::<i64, u32>(8254390273, 26, Ceiling, 124, Greater);
     test::<i64, u32>(8254390273, 26, Nearest, 123, Less);
+    test::<i64, u32>(8254390273, 26, NearestTiesAway, 123, Less);
+    test::<i64, u32>(8254390273, 26, Odd, 123, Less);
 
     test::<i64, i64>(0xffffffff, 1, Down, 0x7fffffff, Less);
     test::<i64, i64>(0xffffffff, 1, Up, 0x80000000, Greater);
@@ -163,6 +193,8 @@ fn test_shr_round() {
     test::<i64, usize>(1000000000000, 0, Floor, 1000000000000, Equal);
     test::<i64, usize>(1000000000000, 0, Ceiling, 1000000000000, Equal);
     test::<i64, usize>(1000000000000, 0, Nearest, 1000000000000, Equal);
+    test::<i64, usize>(1000000000000, 0, NearestTiesAway, 1000000000000, Equal);
+    test::<i64, usize>(1000000000000, 0, Odd, 1000000000000, Equal);
     test::<i64, usize>(1000000000000, 0, Exact, 1000000000000, Equal);
 
     test::<i128, i8>(7999999999999, 3, Down, 999999999999, Less);
@@ -170,12 +202,16 @@ fn test_shr_round() {
     test::<i128, i8>(7999999999999, 3, Floor, 999999999999, Less);
     test::<i128, i8>(7999999999999, 3, Ceiling, 1000000000000, Greater);
     test::<i128, i8>(7999999999999, 3, Nearest, 1000000000000, Greater);
+    test::<i128, i8>(7999999999999, 3, NearestTiesAway, 1000000000000, Greater);
+    test::<i128, i8>(7999999999999, 3, Odd, 999999999999, Less);
 
     test::<u128, u16>(8000000000000, 3, Down, 1000000000000, Equal);
     test::<u128, u16>(8000000000000, 3, Up, 1000000000000, Equal);
     test::<u128, u16>(8000000000000, 3, Floor, 1000000000000, Equal);
     test::<u128, u16>(8000000000000, 3, Ceiling, 1000000000000, Equal);
     test::<u128, u16>(8000000000000, 3, Nearest, 1000000000000, Equal);
+    test::<u128, u16>(8000000000000, 3, NearestTiesAway, 1000000000000, Equal);
+    test::<u128, u16>(8000000000000, 3, Odd, 1000000000000, Equal);
     test::<u128, u16>(8000000000000, 3, Exact, 1000000000000, Equal);
 
     test::<u128, i32>(8000000000001, 3, Down, 1000000000000, Less);
@@ -183,12 +219,16 @@ fn test_shr_round() {
     test::<u128, i32>(8000000000001, 3, Floor, 1000000000000, Less);
     test::<u128, i32>(8000000000001, 3, Ceiling, 1000000000001, Greater);
     test::<u128, i32>(8000000000001, 3, Nearest, 1000000000000, Less);
+    test::<u128, i32>(8000000000001, 3, NearestTiesAway, 1000000000000, Less);
+    test::<u128, i32>(8000000000001, 3, Odd, 1000000000001, Greater);
 
     test::<i128, u64>(1000000000000, 10, Down, 976562500, Equal);
     test::<i128, u64>(1000000000000, 10, Up, 976562500, Equal);
     test::<i128, u64>(1000000000000, 10, Floor, 976562500, Equal);
     test::<i128, u64>(1000000000000, 10, Ceiling, 976562500, Equal);
     test::<i128, u64>(1000000000000, 10, Nearest, 976562500, Equal);
+    test::<i128, u64>(1000000000000, 10, NearestTiesAway, 976562500, Equal);
+    test::<i128, u64>(1000000000000, 10, Odd, 976562500, Equal);
     test::<i128, u64>(1000000000000, 10, Exact, 976562500, Equal);
 
     test::<u128, i128>(980657949, 72, Down, 0, Less);
@@ -196,6 +236,8 @@ fn test_shr_round() {
     test::<u128, i128>(980657949, 72, Floor, 0, Less);
     test::<u128, i128>(980657949, 72, Ceiling, 1, Greater);
     test::<u128, i128>(980657949, 72, Nearest, 0, Less);
+    test::<u128, i128>(980657949, 72, NearestTiesAway, 0, Less);
+    test::<u128, i128>(980657949, 72, Odd, 1, Greater);
 
     test::<i128, isize>(0xffffffff, 31, Down, 1, Less);
     test::<i128, isize>(0xffffffff, 31, Up, 2, Greater);
@@ -243,6 +285,8 @@ fn test_shr_round() {
     test::<i8, u8>(-123, 0, Floor, -123, Equal);
     test::<i8, u8>(-123, 0, Ceiling, -123, Equal);
     test::<i8, u8>(-123, 0, Nearest, -123, Equal);
+    test::<i8, u8>(-123, 0, NearestTiesAway, -123, Equal);
+    test::<i8, u8>(-123, 0, Odd, -123, Equal);
     test::<i8, u8>(-123, 0, Exact, -123, Equal);
 
     test::<i16, i8>(-245, 1, Down, -122, Greater);
@@ -250,12 +294,16 @@ fn test_shr_round() {
     test::<i16, i8>(-245, 1, Floor, -123, Less);
     test::<i16, i8>(-245, 1, Ceiling, -122, Greater);
     test::<i16, i8>(-245, 1, Nearest, -122, Greater);
+    test::<i16, i8>(-245, 1, NearestTiesAway, -123, Less);
+    test::<i16, i8>(-245, 1, Odd, -123, Less);
 
     test::<i16, u16>(-246, 1, Down, -123, Equal);
     test::<i16, u16>(-246, 1, Up, -123, Equal);
     test::<i16, u16>(-246, 1, Floor, -123, Equal);
     test::<i16, u16>(-246, 1, Ceiling, -123, Equal);
     test::<i16, u16>(-246, 1, Nearest, -123, Equal);
+    test::<i16, u16>(-246, 1, NearestTiesAway, -123, Equal);
+    test::<i16, u16>(-246, 1, Odd, -123, Equal);
     test::<i16, u16>(-246, 1, Exact, -123, Equal);
 
     test::<i16, i32>(-247, 1, Down, -123, Greater);
@@ -263,18 +311,24 @@ fn test_shr_round() {
     test::<i16, i32>(-247, 1, Floor, -124, Less);
     test::<i16, i32>(-247, 1, Ceiling, -123, Greater);
     test::<i16, i32>(-247, 1, Nearest, -124, Less);
+    test::<i16, i32>(-247, 1, NearestTiesAway, -124, Less);
+    test::<i16, i32>(-247, 1, Odd, -123, Greater);
 
     test::<i16, u64>(-491, 2, Down, -122, Greater);
     test::<i16, u64>(-491, 2, Up, -123, Less);
     test::<i16, u64>(-491, 2, Floor, -123, Less);
     test::<i16, u64>(-491, 2, Ceiling, -122, Greater);
     test::<i16, u64>(-491, 2, Nearest, -123, Less);
+    test::<i16, u64>(-491, 2, NearestTiesAway, -123, Less);
+    test::<i16, u64>(-491, 2, Odd, -123, Less);
 
     test::<i16, i128>(-492, 2, Down, -123, Equal);
     test::<i16, i128>(-492, 2, Up, -123, Equal);
     test::<i16, i128>(-492, 2, Floor, -123, Equal);
     test::<i16, i128>(-492, 2, Ceiling, -123, Equal);
     test::<i16, i128>(-492, 2, Nearest, -123, Equal);
+    test::<i16, i128>(-492, 2, NearestTiesAway, -123, Equal);
+    test::<i16, i128>(-492, 2, Odd, -123, Equal);
     test::<i16, i128>(-492, 2, Exact, -123, Equal);
 
     test::<i16, usize>(-493, 2, Down, -123, Greater);
@@ -282,18 +336,24 @@ fn test_shr_round() {
     test::<i16, usize>(-493, 2, Floor, -124, Less);
     test::<i16, usize>(-493, 2, Ceiling, -123, Greater);
     test::<i16, usize>(-493, 2, Nearest, -123, Greater);
+    test::<i16, usize>(-493, 2, NearestTiesAway, -123, Greater);
+    test::<i16, usize>(-493, 2, Odd, -123, Greater);
 
     test::<i64, i8>(-4127195135, 25, Down, -122, Greater);
     test::<i64, i8>(-4127195135, 25, Up, -123, Less);
     test::<i64, i8>(-4127195135, 25, Floor, -123, Less);
     test::<i64, i8>(-4127195135, 25, Ceiling, -122, Greater);
     test::<i64, i8>(-4127195135, 25, Nearest, -123, Less);
+    test::<i64, i8>(-4127195135, 25, NearestTiesAway, -123, Less);
+    test::<i64, i8>(-4127195135, 25, Odd, -123, Less);
 
     test::<i64, u16>(-4127195136, 25, Down, -123, Equal);
     test::<i64, u16>(-4127195136, 25, Up, -123, Equal);
     test::<i64, u16>(-4127195136, 25, Floor, -123, Equal);
     test::<i64, u16>(-4127195136, 25, Ceiling, -123, Equal);
     test::<i64, u16>(-4127195136, 25, Nearest, -123, Equal);
+    test::<i64, u16>(-4127195136, 25, NearestTiesAway, -123, Equal);
+    test::<i64, u16>(-4127195136, 25, Odd, -123, Equal);
     test::<i64, u16>(-4127195136, 25, Exact, -123, Equal);
 
     test::<i64, i32>(-4127195137, 25, Down, -123, Greater);
@@ -301,18 +361,24 @@ fn test_shr_round() {
     test::<i64, i32>(-4127195137, 25, Floor, -124, Less);
     test::<i64, i32>(-4127195137, 25, Ceiling, -123, Greater);
     test::<i64, i32>(-4127195137, 25, Nearest, -123, Greater);
+    test::<i64, i32>(-4127195137, 25, NearestTiesAway, -123, Greater);
+    test::<i64, i32>(-4127195137, 25, Odd, -123, Greater);
 
     test::<i64, u64>(-8254390271, 26, Down, -122, Greater);
     test::<i64, u64>(-8254390271, 26, Up, -123, Less);
     test::<i64, u64>(-8254390271, 26, Floor, -123, Less);
     test::<i64, u64>(-8254390271, 26, Ceiling, -122, Greater);
     test::<i64, u64>(-8254390271, 26, Nearest, -123, Less);
+    test::<i64, u64>(-8254390271, 26, NearestTiesAway, -123, Less);
+    test::<i64, u64>(-8254390271, 26, Odd, -123, Less);
 
     test::<i64, i128>(-8254390272, 26, Down, -123, Equal);
     test::<i64, i128>(-8254390272, 26, Up, -123, Equal);
     test::<i64, i128>(-8254390272, 26, Floor, -123, Equal);
     test::<i64, i128>(-8254390272, 26, Ceiling, -123, Equal);
     test::<i64, i128>(-8254390272, 26, Nearest, -123, Equal);
+    test::<i64, i128>(-8254390272, 26, NearestTiesAway, -123, Equal);
+    test::<i64, i128>(-8254390272, 26, Odd, -123, Equal);
     test::<i64, i128>(-8254390272, 26, Exact, -123, Equal);
 
     test::<i64, usize>(-8254390273, 26, Down, -123, Greater);
@@ -320,6 +386,8 @@ fn test_shr_round() {
     test::<i64, usize>(-8254390273, 26, Floor, -124, Less);
     test::<i64, usize>(-8254390273, 26, Ceiling, -123, Greater);
     test::<i64, usize>(-8254390273, 26, Nearest, -123, Greater);
+    test::<i64, usize>(-8254390273, 26, NearestTiesAway, -123, Greater);
+    test::<i64, usize>(-8254390273, 26, Odd, -123, Greater);
 
     test::<i128, i8>(-0xffffffff, 1, Down, -0x7fffffff, Greater);
     test::<i128, i8>(-0xffffffff, 1, Up, -0x80000000, Less);
@@ -345,6 +413,8 @@ fn test_shr_round() {
     test::<i128, u64>(-1000000000000, 0, Floor, -1000000000000, Equal);
     test::<i128, u64>(-1000000000000, 0, Ceiling, -1000000000000, Equal);
     test::<i128, u64>(-1000000000000, 0, Nearest, -1000000000000, Equal);
+    test::<i128, u64>(-1000000000000, 0, NearestTiesAway, -1000000000000, Equal);
+    test::<i128, u64>(-1000000000000, 0, Odd, -1000000000000, Equal);
     test::<i128, u64>(-1000000000000, 0, Exact, -1000000000000, Equal);
 
     test::<i128, i128>(-7999999999999, 3, Down, -999999999999, Greater);
@@ -352,12 +422,16 @@ fn test_shr_round() {
     test::<i128, i128>(-7999999999999, 3, Floor, -1000000000000, Less);
     test::<i128, i128>(-7999999999999, 3, Ceiling, -999999999999, Greater);
     test::<i128, i128>(-7999999999999, 3, Nearest, -1000000000000, Less);
+    test::<i128, i128>(-7999999999999, 3, NearestTiesAway, -1000000000000, Less);
+    test::<i128, i128>(-7999999999999, 3, Odd, -999999999999, Greater);
 
     test::<i128, usize>(-8000000000000, 3, Down, -1000000000000, Equal);
     test::<i128, usize>(-8000000000000, 3, Up, -1000000000000, Equal);
     test::<i128, usize>(-8000000000000, 3, Floor, -1000000000000, Equal);
     test::<i128, usize>(-8000000000000, 3, Ceiling, -1000000000000, Equal);
     test::<i128, usize>(-8000000000000, 3, Nearest, -1000000000000, Equal);
+    test::<i128, usize>(-8000000000000, 3, NearestTiesAway, -1000000000000, Equal);
+    test::<i128, usize>(-8000000000000, 3, Odd, -1000000000000, Equal);
     test::<i128, usize>(-8000000000000, 3, Exact, -1000000000000, Equal);
 
     test::<i64, i8>(-8000000000001, 3, Down, -1000000000000, Greater);
@@ -365,12 +439,16 @@ fn test_shr_round() {
     test::<i64, i8>(-8000000000001, 3, Floor, -1000000000001, Less);
     test::<i64, i8>(-8000000000001, 3, Ceiling, -1000000000000, Greater);
     test::<i64, i8>(-8000000000001, 3, Nearest, -1000000000000, Greater);
+    test::<i64, i8>(-8000000000001, 3, NearestTiesAway, -1000000000000, Greater);
+    test::<i64, i8>(-8000000000001, 3, Odd, -1000000000001, Less);
 
     test::<i128, u16>(-16777216000000000000, 24, Down, -1000000000000, Equal);
     test::<i128, u16>(-16777216000000000000, 24, Up, -1000000000000, Equal);
     test::<i128, u16>(-16777216000000000000, 24, Floor, -1000000000000, Equal);
     test::<i128, u16>(-16777216000000000000, 24, Ceiling, -1000000000000, Equal);
     test::<i128, u16>(-16777216000000000000, 24, Nearest, -1000000000000, Equal);
+    test::<i128, u16>(-16777216000000000000, 24, NearestTiesAway, -1000000000000, Equal);
+    test::<i128, u16>(-16777216000000000000, 24, Odd, -1000000000000, Equal);
     test::<i128, u16>(-16777216000000000000, 24, Exact, -1000000000000, Equal);
 
     test::<i128, i32>(-33554432000000000000, 25, Down, -1000000000000, Equal);
@@ -378,6 +456,8 @@ fn test_shr_round() {
     test::<i128, i32>(-33554432000000000000, 25, Floor, -1000000000000, Equal);
     test::<i128, i32>(-33554432000000000000, 25, Ceiling, -1000000000000, Equal);
     test::<i128, i32>(-33554432000000000000, 25, Nearest, -1000000000000, Equal);
+    test::<i128, i32>(-33554432000000000000, 25, NearestTiesAway, -1000000000000, Equal);
+    test::<i128, i32>(-33554432000000000000, 25, Odd, -1000000000000, Equal);
     test::<i128, i32>(-33554432000000000000, 25, Exact, -1000000000000, Equal);
 
     test::<i128, u64>(-2147483648000000000000, 31, Down, -1000000000000, Equal);
@@ -385,6 +465,8 @@ fn test_shr_round() {
     test::<i128, u64>(-2147483648000000000000, 31, Floor, -1000000000000, Equal);
     test::<i128, u64>(-2147483648000000000000, 31, Ceiling, -1000000000000, Equal);
     test::<i128, u64>(-2147483648000000000000, 31, Nearest, -1000000000000, Equal);
+    test::<i128, u64>(-2147483648000000000000, 31, NearestTiesAway, -1000000000000, Equal);
+    test::<i128, u64>(-2147483648000000000000, 31, Odd, -1000000000000, Equal);
     test::<i128, u64>(-2147483648000000000000, 31, Exact, -1000000000000, Equal);
 
     test::<i128, i128>(-4294967296000000000000, 32, Down, -1000000000000, Equal);
@@ -392,6 +474,8 @@ fn test_shr_round() {
     test::<i128, i128>(-4294967296000000000000, 32, Floor, -1000000000000, Equal);
     test::<i128, i128>(-4294967296000000000000, 32, Ceiling, -1000000000000, Equal);
     test::<i128, i128>(-4294967296000000000000, 32, Nearest, -1000000000000, Equal);
+    test::<i128, i128>(-4294967296000000000000, 32, NearestTiesAway, -1000000000000, Equal);
+    test::<i128, i128>(-4294967296000000000000, 32, Odd, -1000000000000, Equal);
     test::<i128, i128>(-4294967296000000000000, 32, Exact, -1000000000000, Equal);
 
     test::<i128, usize>(-8589934592000000000000, 33, Down, -1000000000000, Equal);
@@ -399,6 +483,8 @@ fn test_shr_round() {
     test::<i128, usize>(-8589934592000000000000, 33, Floor, -1000000000000, Equal);
     test::<i128, usize>(-8589934592000000000000, 33, Ceiling, -1000000000000, Equal);
     test::<i128, usize>(-8589934592000000000000, 33, Nearest, -1000000000000, Equal);
+    test::<i128, usize>(-8589934592000000000000, 33, NearestTiesAway, -1000000000000, Equal);
+    test::<i128, usize>(-8589934592000000000000, 33, Odd, -1000000000000, Equal);
     test::<i128, usize>(-8589934592000000000000, 33, Exact, -1000000000000, Equal);
 
     test::<i64, i8>(-1000000000000, 10, Down, -976562500, Equal);
@@ -406,6 +492,8 @@ fn test_shr_round() {
     test::<i64, i8>(-1000000000000, 10, Floor, -976562500, Equal);
     test::<i64, i8>(-1000000000000, 10, Ceiling, -976562500, Equal);
     test::<i64, i8>(-1000000000000, 10, Nearest, -976562500, Equal);
+    test::<i64, i8>(-1000000000000, 10, NearestTiesAway, -976562500, Equal);
+    test::<i64, i8>(-1000000000000, 10, Odd, -976562500, Equal);
     test::<i64, i8>(-1000000000000, 10, Exact, -976562500, Equal);
 
     test::<i64, u16>(-980657949, 72, Down, 0, Greater);
@@ -413,6 +501,8 @@ fn test_shr_round() {
     test::<i64, u16>(-980657949, 72, Floor, -1, Less);
     test::<i64, u16>(-980657949, 72, Ceiling, 0, Greater);
     test::<i64, u16>(-980657949, 72, Nearest, 0, Greater);
+    test::<i64, u16>(-980657949, 72, NearestTiesAway, 0, Greater);
+    test::<i64, u16>(-980657949, 72, Odd, -1, Less);
 
     test::<i64, i32>(-0xffffffff, 31, Down, -1, Greater);
     test::<i64, i32>(-0xffffffff, 31, Up, -2, Less);
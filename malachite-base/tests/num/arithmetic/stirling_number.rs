@@ -0,0 +1,161 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, CheckedStirlingNumber1, CheckedStirlingNumber2, StirlingNumber1,
+    StirlingNumber2,
+};
+
+#[test]
+fn test_stirling_number_1() {
+    assert_eq!(u8::stirling_number_1(0, 0), 1);
+    assert_eq!(u8::stirling_number_1(3, 0), 0);
+    assert_eq!(u8::stirling_number_1(3, 5), 0);
+    assert_eq!(u8::stirling_number_1(5, 2), 50);
+    assert_eq!(u32::stirling_number_1(10, 3), 1172700);
+    assert_eq!(
+        u128::stirling_number_1(30, 10),
+        215760462268683520394805979744
+    );
+    assert_eq!(u64::stirling_number_1(25, 23), 42550);
+    assert_eq!(i8::stirling_number_1(4, 2), 11);
+    assert_eq!(i16::stirling_number_1(5, 2), -50);
+    assert_eq!(i32::stirling_number_1(6, 3), -225);
+}
+
+#[test]
+fn test_stirling_number_2() {
+    assert_eq!(u8::stirling_number_2(0, 0), 1);
+    assert_eq!(u8::stirling_number_2(3, 0), 0);
+    assert_eq!(u8::stirling_number_2(3, 5), 0);
+    assert_eq!(u8::stirling_number_2(5, 2), 15);
+    assert_eq!(u16::stirling_number_2(10, 3), 9330);
+    assert_eq!(u32::stirling_number_2(25, 2), 16777215);
+    assert_eq!(u32::stirling_number_2(30, 28), 86275);
+    assert_eq!(u128::stirling_number_2(30, 10), 173373343599189364594756);
+    assert_eq!(
+        u128::stirling_number_2(40, 20),
+        162188909527975750487887236507181
+    );
+    assert_eq!(i16::stirling_number_2(10, 3), 9330);
+}
+
+const TRIANGLE_SIZE: usize = 70;
+
+// Builds the triangle of unsigned Stirling numbers of the first or second kind with the defining
+// recurrence, using None for values that overflow a u128.
+fn stirling_triangle(first_kind: bool) -> Vec<Vec<Option<u128>>> {
+    let mut rows = vec![vec![Some(1u128)]];
+    for i in 1..TRIANGLE_SIZE {
+        let previous = &rows[i - 1];
+        let mut row = vec![Some(0)];
+        for j in 1..=i {
+            let f = u128::try_from(if first_kind { i - 1 } else { j }).unwrap();
+            let above = previous.get(j).copied().unwrap_or(Some(0));
+            row.push(
+                above
+                    .and_then(|x| x.checked_mul(f))
+                    .and_then(|x| x.checked_add(previous[j - 1]?)),
+            );
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+#[test]
+fn checked_stirling_number_1_properties() {
+    let triangle = stirling_triangle(true);
+    for (n, row) in triangle.iter().enumerate() {
+        let n = u64::try_from(n).unwrap();
+        for (k, &x) in row.iter().enumerate() {
+            let k = u64::try_from(k).unwrap();
+            assert_eq!(u128::checked_stirling_number_1(n, k), x);
+            assert_eq!(
+                u64::checked_stirling_number_1(n, k),
+                x.and_then(|x| u64::try_from(x).ok())
+            );
+            assert_eq!(
+                u16::checked_stirling_number_1(n, k),
+                x.and_then(|x| u16::try_from(x).ok())
+            );
+            // - the signed numbers have sign (-1)^(n - k)
+            let s = x.and_then(|x| {
+                let x = i64::try_from(x).ok()?;
+                Some(if (n - k) & 1 == 0 { x } else { -x })
+            });
+            assert_eq!(i64::checked_stirling_number_1(n, k), s);
+            assert_eq!(
+                i8::checked_stirling_number_1(n, k),
+                s.and_then(|s| i8::try_from(s).ok())
+            );
+        }
+        assert_eq!(u8::checked_stirling_number_1(n, n + 1), Some(0));
+    }
+    // - c(n, n - 1) = C(n, 2), far beyond the triangle
+    let n = 1 << 40;
+    assert_eq!(
+        u128::checked_stirling_number_1(n, n - 1),
+        Some(u128::binomial_coefficient(u128::from(n), 2))
+    );
+    assert_eq!(u64::checked_stirling_number_1(n, n - 1), None);
+    assert_eq!(u128::checked_stirling_number_1(n, n - 10), None);
+    assert_eq!(u128::checked_stirling_number_1(n, n >> 1), None);
+    assert_eq!(u128::checked_stirling_number_1(n, 1), None);
+}
+
+#[test]
+fn checked_stirling_number_2_properties() {
+    let triangle = stirling_triangle(false);
+    for (n, row) in triangle.iter().enumerate() {
+        let n = u64::try_from(n).unwrap();
+        for (k, &x) in row.iter().enumerate() {
+            let k = u64::try_from(k).unwrap();
+            assert_eq!(u128::checked_stirling_number_2(n, k), x);
+            assert_eq!(
+                u64::checked_stirling_number_2(n, k),
+                x.and_then(|x| u64::try_from(x).ok())
+            );
+            assert_eq!(
+                u16::checked_stirling_number_2(n, k),
+                x.and_then(|x| u16::try_from(x).ok())
+            );
+            assert_eq!(
+                i64::checked_stirling_number_2(n, k),
+                x.and_then(|x| i64::try_from(x).ok())
+            );
+            assert_eq!(
+                i8::checked_stirling_number_2(n, k),
+                x.and_then(|x| i8::try_from(x).ok())
+            );
+        }
+        assert_eq!(u8::checked_stirling_number_2(n, n + 1), Some(0));
+    }
+    // - S(n, 1) = 1 and S(n, n - 1) = C(n, 2), far beyond the triangle
+    let n = 1 << 40;
+    assert_eq!(u8::checked_stirling_number_2(n, 1), Some(1));
+    assert_eq!(
+        u128::checked_stirling_number_2(n, n - 1),
+        Some(u128::binomial_coefficient(u128::from(n), 2))
+    );
+    assert_eq!(u64::checked_stirling_number_2(n, n - 1), None);
+    assert_eq!(u128::checked_stirling_number_2(n, n - 10), None);
+    assert_eq!(u128::checked_stirling_number_2(n, 2), None);
+}
+
+#[test]
+#[should_panic]
+fn stirling_number_1_fail() {
+    u8::stirling_number_1(6, 2);
+}
+
+#[test]
+#[should_panic]
+fn stirling_number_2_fail() {
+    u8::stirling_number_2(10, 3);
+}
@@ -0,0 +1,175 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::Gcd;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{
+    CheckedDivisorSigma, DivisorCount, DivisorSigma, Divisors, Factor,
+};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_12, unsigned_pair_gen_var_27,
+};
+use std::panic::catch_unwind;
+
+fn checked_divisor_sigma_helper<
+    T: CheckedDivisorSigma<Output = T> + DivisorSigma<Output = T> + PrimitiveUnsigned,
+>() {
+    let test = |n: u64, k: u64, out: Option<u64>| {
+        if let Ok(n) = T::try_from(n) {
+            let out = out.and_then(|x| T::try_from(x).ok());
+            assert_eq!(n.checked_divisor_sigma(k), out);
+            if let Some(out) = out {
+                assert_eq!(n.divisor_sigma(k), out);
+            }
+        }
+    };
+    test(1, 0, Some(1));
+    test(1, 1, Some(1));
+    test(1, 100, Some(1));
+    test(2, 0, Some(2));
+    test(2, 1, Some(3));
+    test(2, 7, Some(129));
+    test(2, 8, Some(257));
+    test(6, 1, Some(12));
+    test(12, 0, Some(6));
+    test(12, 1, Some(28));
+    test(12, 2, Some(210));
+    test(100, 3, Some(1149823));
+    test(240, 1, Some(744));
+    test(65535, 1, Some(111456));
+    test(720720, 0, Some(240));
+    test(720720, 1, Some(3249792));
+    test(4294967295, 1, Some(7304603328));
+    test(4294967295, 2, None);
+    test(u64::MAX, 0, Some(128));
+    test(u64::MAX, 1, None);
+    test(3, 64, None);
+}
+
+#[test]
+fn test_checked_divisor_sigma() {
+    checked_divisor_sigma_helper::<u8>();
+    checked_divisor_sigma_helper::<u16>();
+    checked_divisor_sigma_helper::<u32>();
+    checked_divisor_sigma_helper::<u64>();
+    checked_divisor_sigma_helper::<usize>();
+}
+
+fn divisor_count_helper<T: DivisorCount<Output = T> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: u64| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.divisor_count(), T::exact_from(out));
+        }
+    };
+    test(1, 1);
+    test(2, 2);
+    test(12, 6);
+    test(128, 8);
+    test(240, 20);
+    test(720720, 240);
+    test(1000000, 49);
+    test(4294967291, 2);
+    test(u64::MAX, 128);
+}
+
+#[test]
+fn test_divisor_count() {
+    divisor_count_helper::<u8>();
+    divisor_count_helper::<u16>();
+    divisor_count_helper::<u32>();
+    divisor_count_helper::<u64>();
+    divisor_count_helper::<usize>();
+}
+
+fn divisor_sigma_fail_helper<
+    T: CheckedDivisorSigma<Output = T>
+        + DivisorCount<Output = T>
+        + DivisorSigma<Output = T>
+        + PrimitiveUnsigned,
+>() {
+    assert_panic!(T::ZERO.divisor_sigma(1));
+    assert_panic!(T::ZERO.checked_divisor_sigma(1));
+    assert_panic!(T::ZERO.divisor_count());
+    assert_panic!(T::from(3u8).divisor_sigma(64));
+}
+
+#[test]
+pub fn divisor_sigma_fail() {
+    divisor_sigma_fail_helper::<u8>();
+    divisor_sigma_fail_helper::<u16>();
+    divisor_sigma_fail_helper::<u32>();
+    divisor_sigma_fail_helper::<u64>();
+    divisor_sigma_fail_helper::<usize>();
+}
+
+fn divisor_sigma_properties_helper<
+    T: CheckedDivisorSigma<Output = T>
+        + DivisorCount<Output = T>
+        + DivisorSigma<Output = T>
+        + Divisors
+        + Factor
+        + PrimitiveUnsigned,
+>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(TINY_LIMIT, |n| {
+        let tau = n.divisor_count();
+        assert_eq!(n.divisor_sigma(0), tau);
+        assert!(tau <= n);
+        let sigma = n.checked_divisor_sigma(1);
+        if let Some(sigma) = sigma {
+            assert!(sigma >= n);
+        }
+        if n <= T::from(u8::MAX) {
+            assert_eq!(tau, T::exact_from(n.divisors().count()));
+            assert_eq!(sigma, n.divisors().try_fold(T::ZERO, T::checked_add));
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u8>().test_properties_with_limit(TINY_LIMIT, |(n, k)| {
+        let n = n.saturating_add(1);
+        let k = u64::from(k % 4);
+        let sum = n
+            .divisors()
+            .map(|d| u128::from(d).pow(u32::exact_from(k)))
+            .sum::<u128>();
+        assert_eq!(
+            u32::from(n).checked_divisor_sigma(k).map(u128::from),
+            u32::try_from(sum).ok().map(u128::from)
+        );
+        assert_eq!(
+            n.checked_divisor_sigma(k).map(u128::from),
+            u8::try_from(sum).ok().map(u128::from)
+        );
+    });
+
+    unsigned_pair_gen_var_12::<u16, u16>().test_properties_with_limit(TINY_LIMIT, |(a, b)| {
+        if a == 0 || a.gcd(b) != 1 {
+            return;
+        }
+        let ab = u64::from(a) * u64::from(b);
+        assert_eq!(
+            ab.divisor_sigma(1),
+            u64::from(a).divisor_sigma(1) * u64::from(b).divisor_sigma(1)
+        );
+        assert_eq!(
+            ab.divisor_count(),
+            u64::from(a.divisor_count()) * u64::from(b.divisor_count())
+        );
+    });
+}
+
+#[test]
+fn divisor_sigma_properties() {
+    divisor_sigma_properties_helper::<u8>();
+    divisor_sigma_properties_helper::<u16>();
+    divisor_sigma_properties_helper::<u32>();
+    divisor_sigma_properties_helper::<u64>();
+    divisor_sigma_properties_helper::<usize>();
+}
@@ -0,0 +1,126 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::DivisibleBy;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::divisors::DivisorsIterator;
+use malachite_base::num::factorization::traits::{DivisorCount, Divisors, Factor};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use std::panic::catch_unwind;
+
+fn divisors_helper<T: Divisors + PrimitiveUnsigned>() {
+    let test = |n: u64, out: &[u64]| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(
+                n.divisors().collect_vec(),
+                out.iter().map(|&d| T::exact_from(d)).collect_vec()
+            );
+        }
+    };
+    test(1, &[1]);
+    test(2, &[1, 2]);
+    test(4, &[1, 2, 4]);
+    test(12, &[1, 2, 3, 4, 6, 12]);
+    test(30, &[1, 2, 3, 5, 6, 10, 15, 30]);
+    test(60, &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]);
+    test(128, &[1, 2, 4, 8, 16, 32, 64, 128]);
+    test(255, &[1, 3, 5, 15, 17, 51, 85, 255]);
+    test(
+        65535,
+        &[1, 3, 5, 15, 17, 51, 85, 255, 257, 771, 1285, 3855, 4369, 13107, 21845, 65535],
+    );
+    test(4294967291, &[1, 4294967291]);
+    test(
+        4294967295,
+        &[
+            1, 3, 5, 15, 17, 51, 85, 255, 257, 771, 1285, 3855, 4369, 13107, 21845, 65535, 65537,
+            196611, 327685, 983055, 1114129, 3342387, 5570645, 16711935, 16843009, 50529027,
+            84215045, 252645135, 286331153, 858993459, 1431655765, 4294967295,
+        ],
+    );
+}
+
+#[test]
+fn test_divisors() {
+    divisors_helper::<u8>();
+    divisors_helper::<u16>();
+    divisors_helper::<u32>();
+    divisors_helper::<u64>();
+    divisors_helper::<usize>();
+
+    assert_eq!(
+        u64::MAX.divisors().take(10).collect_vec(),
+        &[1, 3, 5, 15, 17, 51, 85, 255, 257, 641]
+    );
+    assert_eq!(u64::MAX.divisors().last(), Some(u64::MAX));
+}
+
+#[test]
+fn test_divisors_iterator_from_prime_factors() {
+    let test = |factors: &[(u32, u64)], out: &[u32]| {
+        assert_eq!(
+            DivisorsIterator::from_prime_factors(factors.iter().copied()).collect_vec(),
+            out
+        );
+    };
+    test(&[], &[1]);
+    test(&[(2, 3)], &[1, 2, 4, 8]);
+    test(&[(2, 1), (3, 1)], &[1, 2, 3, 6]);
+    // the primes need not be in ascending order
+    test(&[(5, 1), (2, 2)], &[1, 2, 4, 5, 10, 20]);
+}
+
+fn divisors_fail_helper<T: Divisors + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisors());
+}
+
+#[test]
+pub fn divisors_fail() {
+    divisors_fail_helper::<u8>();
+    divisors_fail_helper::<u16>();
+    divisors_fail_helper::<u32>();
+    divisors_fail_helper::<u64>();
+    divisors_fail_helper::<usize>();
+}
+
+fn divisors_properties_helper<
+    T: DivisorCount<Output = T> + Divisors + Factor + PrimitiveUnsigned,
+>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(TINY_LIMIT, |n| {
+        let mut divisors = n.divisors();
+        assert_eq!(divisors.next(), Some(T::ONE));
+        let divisors = n.divisors().take(1000).collect_vec();
+        assert!(divisors.iter().tuple_windows().all(|(d, e)| d < e));
+        assert!(divisors.iter().all(|&d| n.divisible_by(d)));
+        if T::exact_from(divisors.len()) == n.divisor_count() {
+            assert_eq!(*divisors.last().unwrap(), n);
+        }
+        if n <= T::from(u8::MAX) {
+            let n: u64 = n.wrapping_into();
+            assert_eq!(
+                divisors
+                    .iter()
+                    .map(|&d| -> u64 { d.wrapping_into() })
+                    .collect_vec(),
+                (1..=n).filter(|&d| n.divisible_by(d)).collect_vec()
+            );
+        }
+    });
+}
+
+#[test]
+fn divisors_properties() {
+    divisors_properties_helper::<u8>();
+    divisors_properties_helper::<u16>();
+    divisors_properties_helper::<u32>();
+    divisors_properties_helper::<u64>();
+    divisors_properties_helper::<usize>();
+}
@@ -10,14 +10,15 @@ use malachite_base::num::arithmetic::traits::PowerOf2;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::num::exhaustive::exhaustive_unsigneds;
-use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::factorization::traits::{IsPrime, ProvePrime};
 use malachite_base::test_util::generators::{unsigned_gen, unsigned_pair_gen_var_50};
 use malachite_base::test_util::num::factorization::is_prime::is_prime_naive;
 
-fn is_prime_helper<T: IsPrime + PrimitiveUnsigned>() {
+fn is_prime_helper<T: IsPrime + PrimitiveUnsigned + ProvePrime>() {
     let test = |n: u64, out| {
         if let Ok(n) = T::try_from(n) {
             assert_eq!(n.is_prime(), out);
+            assert_eq!(n.prove_prime(), Some(out));
             assert_eq!(is_prime_naive(n), out);
         }
     };
@@ -504,12 +505,13 @@ fn test_is_prime() {
     is_prime_helper::<usize>();
 }
 
-fn is_prime_properties_helper_helper<T: IsPrime + PrimitiveUnsigned>(n: T) {
+fn is_prime_properties_helper_helper<T: IsPrime + PrimitiveUnsigned + ProvePrime>(n: T) {
     let is_prime = n.is_prime();
     assert_eq!(is_prime_naive(n), is_prime);
+    assert_eq!(n.prove_prime(), Some(is_prime));
 }
 
-fn is_prime_properties_helper_1<T: IsPrime + PrimitiveUnsigned>() {
+fn is_prime_properties_helper_1<T: IsPrime + PrimitiveUnsigned + ProvePrime>() {
     if T::WIDTH < u32::WIDTH {
         for n in exhaustive_unsigneds::<T>() {
             is_prime_properties_helper_helper(n);
@@ -0,0 +1,98 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Gcd};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{Divisors, IsPrime, MoebiusMu};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_29, unsigned_pair_gen_var_12,
+};
+use std::panic::catch_unwind;
+
+fn moebius_mu_helper<T: MoebiusMu + PrimitiveUnsigned>() {
+    let test = |n: u64, out: i8| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.moebius_mu(), out);
+        }
+    };
+    test(1, 1);
+    test(2, -1);
+    test(3, -1);
+    test(4, 0);
+    test(6, 1);
+    test(12, 0);
+    test(30, -1);
+    test(210, 1);
+    test(255, -1);
+    test(1001, -1);
+    test(65536, 0);
+    test(4294967291, -1);
+    test(4294967295, -1);
+    test(u64::MAX, -1);
+}
+
+#[test]
+fn test_moebius_mu() {
+    moebius_mu_helper::<u8>();
+    moebius_mu_helper::<u16>();
+    moebius_mu_helper::<u32>();
+    moebius_mu_helper::<u64>();
+    moebius_mu_helper::<usize>();
+}
+
+fn moebius_mu_fail_helper<T: MoebiusMu + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.moebius_mu());
+}
+
+#[test]
+pub fn moebius_mu_fail() {
+    moebius_mu_fail_helper::<u8>();
+    moebius_mu_fail_helper::<u16>();
+    moebius_mu_fail_helper::<u32>();
+    moebius_mu_fail_helper::<u64>();
+    moebius_mu_fail_helper::<usize>();
+}
+
+fn moebius_mu_properties_helper<T: Divisors + IsPrime + MoebiusMu + PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(TINY_LIMIT, |n| {
+        let mu = n.moebius_mu();
+        assert!((-1..=1).contains(&mu));
+        if n <= T::from(u8::MAX) {
+            let m: u64 = n.wrapping_into();
+            assert_eq!(mu != 0, (2..=m).all(|d| !m.divisible_by(d * d)));
+            // the sum of mu(d) over the divisors d of n is 0, unless n is 1
+            let sum: i64 = n.divisors().map(|d| i64::from(d.moebius_mu())).sum();
+            assert_eq!(sum, i64::from(n == T::ONE));
+        }
+    });
+
+    unsigned_gen_var_29::<T>().test_properties_with_limit(TINY_LIMIT, |p| {
+        assert_eq!(p.moebius_mu(), -1);
+    });
+
+    unsigned_pair_gen_var_12::<u16, u16>().test_properties_with_limit(TINY_LIMIT, |(a, b)| {
+        if a == 0 || a.gcd(b) != 1 {
+            return;
+        }
+        assert_eq!(
+            (u32::from(a) * u32::from(b)).moebius_mu(),
+            a.moebius_mu() * b.moebius_mu()
+        );
+    });
+}
+
+#[test]
+fn moebius_mu_properties() {
+    moebius_mu_properties_helper::<u8>();
+    moebius_mu_properties_helper::<u16>();
+    moebius_mu_properties_helper::<u32>();
+    moebius_mu_properties_helper::<u64>();
+    moebius_mu_properties_helper::<usize>();
+}
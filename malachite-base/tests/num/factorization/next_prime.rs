@@ -0,0 +1,104 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::primitive_int_increasing_range;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+use malachite_base::test_util::generators::unsigned_gen;
+
+fn next_prime_helper<
+    T: IsPrime + NextPrime<Output = Option<T>> + PrevPrime<Output = Option<T>> + PrimitiveUnsigned,
+>() {
+    let test = |n: u64, next: Option<u64>, prev: Option<u64>| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(
+                n.next_prime(),
+                next.and_then(|p| T::try_from(p).ok()),
+                "next_prime({n})"
+            );
+            assert_eq!(n.prev_prime(), prev.map(T::exact_from));
+        }
+    };
+    test(0, Some(2), None);
+    test(1, Some(2), None);
+    test(2, Some(3), None);
+    test(3, Some(5), Some(2));
+    test(4, Some(5), Some(3));
+    test(5, Some(7), Some(3));
+    test(10, Some(11), Some(7));
+    test(100, Some(101), Some(97));
+    test(250, Some(251), Some(241));
+    test(251, Some(257), Some(241));
+    test(255, Some(257), Some(251));
+    test(65520, Some(65521), Some(65519));
+    test(65535, Some(65537), Some(65521));
+    test(1000000, Some(1000003), Some(999983));
+    test(4294967291, Some(4294967311), Some(4294967279));
+    test(4294967295, Some(4294967311), Some(4294967291));
+    test(
+        18446744073709551556,
+        Some(18446744073709551557),
+        Some(18446744073709551533),
+    );
+    test(18446744073709551557, None, Some(18446744073709551533));
+    test(u64::MAX, None, Some(18446744073709551557));
+}
+
+#[test]
+fn test_next_prime() {
+    next_prime_helper::<u8>();
+    next_prime_helper::<u16>();
+    next_prime_helper::<u32>();
+    next_prime_helper::<u64>();
+    next_prime_helper::<usize>();
+}
+
+fn next_prime_properties_helper<
+    T: IsPrime + NextPrime<Output = Option<T>> + PrevPrime<Output = Option<T>> + PrimitiveUnsigned,
+>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        if let Some(p) = n.next_prime() {
+            assert!(p > n);
+            assert!(p.is_prime());
+            assert!(primitive_int_increasing_range(n + T::ONE, p).all(|k| !k.is_prime()));
+            assert!(p.prev_prime().is_none_or(|q| q <= n));
+        } else {
+            assert!(
+                primitive_int_increasing_range(n, T::MAX)
+                    .skip(1)
+                    .all(|k| !k.is_prime())
+            );
+        }
+        if let Some(p) = n.prev_prime() {
+            assert!(p < n);
+            assert!(p.is_prime());
+            assert!(primitive_int_increasing_range(p + T::ONE, n).all(|k| !k.is_prime()));
+            assert!(p.next_prime().is_none_or(|q| q >= n));
+        } else {
+            assert!(n <= T::TWO);
+        }
+    });
+
+    let mut previous = T::ZERO;
+    for p in T::primes().take(1000) {
+        assert_eq!(previous.next_prime(), Some(p));
+        if previous != T::ZERO {
+            assert_eq!(p.prev_prime(), Some(previous));
+        }
+        previous = p;
+    }
+}
+
+#[test]
+fn next_prime_properties() {
+    next_prime_properties_helper::<u8>();
+    next_prime_properties_helper::<u16>();
+    next_prime_properties_helper::<u32>();
+    next_prime_properties_helper::<u64>();
+    next_prime_properties_helper::<usize>();
+}
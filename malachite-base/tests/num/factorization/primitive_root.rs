@@ -0,0 +1,196 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::ModMultiplicativeOrder;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{
+    IsPrime, IsPrimitiveRoot, PrimitiveRoot, PrimitiveRootPrime,
+};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_29, unsigned_pair_gen_var_16,
+};
+use std::panic::catch_unwind;
+
+fn primitive_root_helper<T: PrimitiveRoot<Output = T> + PrimitiveUnsigned>() {
+    let test = |m: u64, out: Option<u64>| {
+        if let Ok(m) = T::try_from(m) {
+            assert_eq!(m.primitive_root(), out.map(|g| T::exact_from(g)));
+        }
+    };
+    // - m is 1, 2, or 4
+    test(1, Some(0));
+    test(2, Some(1));
+    test(4, Some(3));
+    // - m is an odd prime
+    test(3, Some(2));
+    test(5, Some(2));
+    test(7, Some(3));
+    test(191, Some(19));
+    test(65537, Some(3));
+    test(4294967291, Some(2));
+    test(8760810010780182161, Some(3));
+    // - m is a power of an odd prime
+    test(9, Some(2));
+    test(25, Some(2));
+    test(27, Some(2));
+    test(49, Some(3));
+    test(121, Some(2));
+    // - m is twice a power of an odd prime
+    test(6, Some(5));
+    test(10, Some(3));
+    test(18, Some(5));
+    test(50, Some(3));
+    test(54, Some(5));
+    test(6250, Some(3));
+    // - the multiplicative group is not cyclic
+    test(8, None);
+    test(12, None);
+    test(15, None);
+    test(16, None);
+    test(100, None);
+    test(4294967295, None);
+}
+
+#[test]
+fn test_primitive_root() {
+    primitive_root_helper::<u8>();
+    primitive_root_helper::<u16>();
+    primitive_root_helper::<u32>();
+    primitive_root_helper::<u64>();
+    primitive_root_helper::<usize>();
+}
+
+fn primitive_root_fail_helper<T: PrimitiveRoot + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.primitive_root());
+}
+
+#[test]
+pub fn primitive_root_fail() {
+    primitive_root_fail_helper::<u8>();
+    primitive_root_fail_helper::<u16>();
+    primitive_root_fail_helper::<u32>();
+    primitive_root_fail_helper::<u64>();
+    primitive_root_fail_helper::<usize>();
+}
+
+fn is_primitive_root_helper<T: IsPrimitiveRoot<T> + PrimitiveUnsigned>() {
+    let test = |x: u64, m: u64, out: bool| {
+        if let (Ok(x), Ok(m)) = (T::try_from(x), T::try_from(m)) {
+            assert_eq!(x.is_primitive_root(m), out);
+        }
+    };
+    test(0, 1, true);
+    test(1, 2, true);
+    test(0, 2, false);
+    test(3, 4, true);
+    test(1, 4, false);
+    test(3, 7, true);
+    test(5, 7, true);
+    test(2, 7, false);
+    test(6, 7, false);
+    test(2, 9, true);
+    test(4, 9, false);
+    test(5, 18, true);
+    test(3, 18, false);
+    test(3, 8, false);
+    test(7, 15, false);
+    test(3, 65537, true);
+    test(2, 65537, false);
+    test(2, 4294967291, true);
+}
+
+#[test]
+fn test_is_primitive_root() {
+    is_primitive_root_helper::<u8>();
+    is_primitive_root_helper::<u16>();
+    is_primitive_root_helper::<u32>();
+    is_primitive_root_helper::<u64>();
+    is_primitive_root_helper::<usize>();
+}
+
+fn is_primitive_root_fail_helper<T: IsPrimitiveRoot<T> + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.is_primitive_root(T::ZERO));
+    assert_panic!(T::from(3u8).is_primitive_root(T::from(3u8)));
+}
+
+#[test]
+pub fn is_primitive_root_fail() {
+    is_primitive_root_fail_helper::<u8>();
+    is_primitive_root_fail_helper::<u16>();
+    is_primitive_root_fail_helper::<u32>();
+    is_primitive_root_fail_helper::<u64>();
+    is_primitive_root_fail_helper::<usize>();
+}
+
+// The number of residues modulo m that are coprime to m.
+fn totient_naive<T: PrimitiveUnsigned>(m: T) -> T {
+    let mut count = T::ZERO;
+    let mut x = T::ZERO;
+    while x < m {
+        if x.gcd(m) == T::ONE {
+            count += T::ONE;
+        }
+        x += T::ONE;
+    }
+    count
+}
+
+fn primitive_root_properties_helper<
+    T: IsPrime
+        + IsPrimitiveRoot<T>
+        + ModMultiplicativeOrder<T, Output = T>
+        + PrimitiveRoot<Output = T>
+        + PrimitiveRootPrime<Output = T>
+        + PrimitiveUnsigned,
+>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(TINY_LIMIT, |m| {
+        let root = m.primitive_root();
+        if let Some(g) = root {
+            assert!(g < m);
+            assert!(g.is_primitive_root(m));
+        }
+        if m <= T::from(u8::MAX) {
+            let mut x = T::ZERO;
+            while x < m {
+                let is_root = x.is_primitive_root(m);
+                if root.is_none_or(|g| x < g) {
+                    assert!(!is_root);
+                }
+                x += T::ONE;
+            }
+        }
+    });
+
+    unsigned_pair_gen_var_16::<T>().test_properties_with_limit(TINY_LIMIT, |(x, m)| {
+        let is_root = x.is_primitive_root(m);
+        if is_root {
+            assert!(m.primitive_root().is_some());
+        }
+        if m <= T::from(u8::MAX) {
+            assert_eq!(
+                is_root,
+                x.mod_multiplicative_order(m) == Some(totient_naive(m))
+            );
+        }
+    });
+
+    unsigned_gen_var_29::<T>().test_properties_with_limit(TINY_LIMIT, |p| {
+        assert_eq!(p.primitive_root(), Some(p.primitive_root_prime()));
+    });
+}
+
+#[test]
+fn primitive_root_properties() {
+    primitive_root_properties_helper::<u8>();
+    primitive_root_properties_helper::<u16>();
+    primitive_root_properties_helper::<u32>();
+    primitive_root_properties_helper::<u64>();
+    primitive_root_properties_helper::<usize>();
+}
@@ -0,0 +1,169 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{Gcd, Lcm, ModPow};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor, IsPrime, Totient};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_29, unsigned_pair_gen_var_12,
+};
+use std::panic::catch_unwind;
+
+fn totient_helper<T: Totient<Output = T> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: u64| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.totient(), T::exact_from(out));
+        }
+    };
+    test(1, 1);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(6, 2);
+    test(7, 6);
+    test(12, 4);
+    test(36, 12);
+    test(128, 64);
+    test(255, 128);
+    test(1000, 400);
+    test(65535, 32768);
+    test(65536, 32768);
+    test(1000000, 400000);
+    test(4294967291, 4294967290);
+    test(4294967295, 2147483648);
+    test(u64::MAX, 9208981628670443520);
+}
+
+#[test]
+fn test_totient() {
+    totient_helper::<u8>();
+    totient_helper::<u16>();
+    totient_helper::<u32>();
+    totient_helper::<u64>();
+    totient_helper::<usize>();
+}
+
+fn carmichael_lambda_helper<T: CarmichaelLambda<Output = T> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: u64| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.carmichael_lambda(), T::exact_from(out));
+        }
+    };
+    test(1, 1);
+    test(2, 1);
+    test(4, 2);
+    test(8, 2);
+    test(16, 4);
+    test(7, 6);
+    test(15, 4);
+    test(24, 2);
+    test(128, 32);
+    test(561, 80);
+    test(1000, 100);
+    test(65536, 16384);
+    test(1000000, 50000);
+    test(4294967291, 4294967290);
+    test(4294967295, 65536);
+    test(u64::MAX, 17153064960);
+}
+
+#[test]
+fn test_carmichael_lambda() {
+    carmichael_lambda_helper::<u8>();
+    carmichael_lambda_helper::<u16>();
+    carmichael_lambda_helper::<u32>();
+    carmichael_lambda_helper::<u64>();
+    carmichael_lambda_helper::<usize>();
+}
+
+fn totient_fail_helper<
+    T: CarmichaelLambda<Output = T> + Totient<Output = T> + PrimitiveUnsigned,
+>() {
+    assert_panic!(T::ZERO.totient());
+    assert_panic!(T::ZERO.carmichael_lambda());
+}
+
+#[test]
+pub fn totient_fail() {
+    totient_fail_helper::<u8>();
+    totient_fail_helper::<u16>();
+    totient_fail_helper::<u32>();
+    totient_fail_helper::<u64>();
+    totient_fail_helper::<usize>();
+}
+
+// The number of residues modulo n that are coprime to n.
+fn totient_naive<T: PrimitiveUnsigned>(n: T) -> T {
+    let mut count = T::ZERO;
+    let mut x = T::ZERO;
+    while x < n {
+        if x.gcd(n) == T::ONE {
+            count += T::ONE;
+        }
+        x += T::ONE;
+    }
+    count
+}
+
+fn totient_properties_helper<
+    T: CarmichaelLambda<Output = T>
+        + Factor
+        + IsPrime
+        + ModPow<u64, T, Output = T>
+        + Totient<Output = T>
+        + PrimitiveUnsigned,
+>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(TINY_LIMIT, |n| {
+        let phi = n.totient();
+        let lambda = n.carmichael_lambda();
+        assert!(phi <= n);
+        assert_ne!(lambda, T::ZERO);
+        assert!(phi.divisible_by(lambda));
+        if n <= T::from(u8::MAX) {
+            assert_eq!(phi, totient_naive(n));
+            let mut x = T::ONE;
+            while x < n {
+                if x.gcd(n) == T::ONE {
+                    assert_eq!(x.mod_pow(lambda.wrapping_into(), n), T::ONE);
+                }
+                x += T::ONE;
+            }
+        }
+    });
+
+    unsigned_gen_var_29::<T>().test_properties_with_limit(TINY_LIMIT, |p| {
+        assert_eq!(p.totient(), p - T::ONE);
+        assert_eq!(p.carmichael_lambda(), p - T::ONE);
+    });
+
+    unsigned_pair_gen_var_12::<u16, u16>().test_properties_with_limit(TINY_LIMIT, |(a, b)| {
+        if a == 0 || a.gcd(b) != 1 {
+            return;
+        }
+        let ab = u32::from(a) * u32::from(b);
+        assert_eq!(
+            ab.totient(),
+            u32::from(a.totient()) * u32::from(b.totient())
+        );
+        assert_eq!(
+            ab.carmichael_lambda(),
+            u32::from(a.carmichael_lambda()).lcm(u32::from(b.carmichael_lambda()))
+        );
+    });
+}
+
+#[test]
+fn totient_properties() {
+    totient_properties_helper::<u8>();
+    totient_properties_helper::<u16>();
+    totient_properties_helper::<u32>();
+    totient_properties_helper::<u64>();
+    totient_properties_helper::<usize>();
+}
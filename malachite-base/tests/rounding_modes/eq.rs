@@ -14,7 +14,16 @@ use malachite_base::test_util::generators::{
 
 #[test]
 fn test_eq() {
-    test_eq_helper::<RoundingMode>(&["Down", "Up", "Floor", "Ceiling", "Nearest", "Exact"]);
+    test_eq_helper::<RoundingMode>(&[
+        "Down",
+        "Up",
+        "Floor",
+        "Ceiling",
+        "Nearest",
+        "NearestTiesAway",
+        "Odd",
+        "Exact",
+    ]);
 }
 
 #[test]
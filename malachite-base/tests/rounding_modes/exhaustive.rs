@@ -14,6 +14,6 @@ use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
 fn test_exhaustive_rounding_modes() {
     assert_eq!(
         exhaustive_rounding_modes().collect_vec(),
-        &[Down, Up, Floor, Ceiling, Nearest, Exact]
+        &[Down, Up, Floor, Ceiling, Nearest, NearestTiesAway, Odd, Exact]
     );
 }
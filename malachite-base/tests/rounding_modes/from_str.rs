@@ -21,11 +21,14 @@ fn test_from_str() {
     test("Floor", Ok(Floor));
     test("Ceiling", Ok(Ceiling));
     test("Nearest", Ok(Nearest));
+    test("NearestTiesAway", Ok(NearestTiesAway));
+    test("Odd", Ok(Odd));
     test("Exact", Ok(Exact));
 
     test("", Err("".to_string()));
     test("abc", Err("abc".to_string()));
     test("Uptown", Err("Uptown".to_string()));
+    test("nearestTiesAway", Err("nearestTiesAway".to_string()));
 }
 
 #[allow(clippy::needless_pass_by_value)]
@@ -22,6 +22,8 @@ fn test_neg() {
     test(Floor, Ceiling);
     test(Ceiling, Floor);
     test(Nearest, Nearest);
+    test(NearestTiesAway, NearestTiesAway);
+    test(Odd, Odd);
     test(Exact, Exact);
 }
 
@@ -21,16 +21,36 @@ fn test_random_rounding_modes() {
         (values.as_slice(), common_values.as_slice()),
         (
             &[
-                Up, Exact, Ceiling, Up, Floor, Nearest, Exact, Up, Floor, Exact, Nearest, Down,
-                Exact, Down, Floor, Exact, Floor, Down, Nearest, Down
+                Up,
+                Odd,
+                NearestTiesAway,
+                Exact,
+                Odd,
+                Ceiling,
+                Up,
+                Floor,
+                Nearest,
+                NearestTiesAway,
+                Up,
+                Floor,
+                Odd,
+                NearestTiesAway,
+                Nearest,
+                Odd,
+                Down,
+                NearestTiesAway,
+                Odd,
+                Down
             ][..],
             &[
-                (Ceiling, 167408),
-                (Down, 167104),
-                (Nearest, 166935),
-                (Exact, 166549),
-                (Floor, 166068),
-                (Up, 165936)
+                (Ceiling, 125437),
+                (Odd, 125372),
+                (Exact, 125322),
+                (Nearest, 125116),
+                (Down, 125049),
+                (NearestTiesAway, 124759),
+                (Floor, 124542),
+                (Up, 124403)
             ][..]
         )
     );
@@ -0,0 +1,437 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+// This is equivalent to `mpc_abs` from `abs.c`, MPC 1.4.1.
+pub(crate) fn abs_helper(z: &Complex, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    z.real.hypot_prec_round_ref_ref(&z.imag, prec, rm)
+}
+
+impl Complex {
+    /// Computes the absolute value of a [`Complex`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Complex`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded absolute value is less than, equal to, or
+    /// greater than the exact absolute value. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN`, it also returns `Equal`.
+    ///
+    /// The absolute value, or modulus, of $a+bi$ is $\sqrt{a^2+b^2}$, computed with a single
+    /// rounding, as by [`Float::hypot_prec_round`].
+    ///
+    /// $$
+    /// f(x,p,m) = |x|+\varepsilon.
+    /// $$
+    /// - If $|x|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $|x|$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x|\rfloor-p+1}$.
+    /// - If $|x|$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - If either part is infinite, the result is $\infty$, even if the other part is `NaN`.
+    /// - Otherwise, if either part is `NaN`, the result is `NaN`.
+    /// - If both parts are zero, the result is $0.0$.
+    ///
+    /// The result may overflow or underflow as by [`Float::hypot_prec_round`].
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Complex::abs_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the input's parts,
+    /// consider using [`Complex::abs_round`] instead. If both of these things are true, consider
+    /// using [`Complex::abs`](Abs::abs) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the absolute value can't be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().abs_prec_round(5, Floor);
+    /// assert_eq!(z.to_string(), "4.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.clone().abs_prec_round(5, Ceiling);
+    /// assert_eq!(z.to_string(), "4.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.clone().abs_prec_round(20, Nearest);
+    /// assert_eq!(z.to_string(), "4.1543579");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn abs_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.abs_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the absolute value of a [`Complex`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Complex`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded absolute value is less than, equal to, or
+    /// greater than the exact absolute value. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN`, it also returns `Equal`.
+    ///
+    /// The absolute value, or modulus, of $a+bi$ is $\sqrt{a^2+b^2}$, computed with a single
+    /// rounding, as by [`Float::hypot_prec_round`].
+    ///
+    /// $$
+    /// f(x,p,m) = |x|+\varepsilon.
+    /// $$
+    /// - If $|x|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $|x|$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x|\rfloor-p+1}$.
+    /// - If $|x|$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - If either part is infinite, the result is $\infty$, even if the other part is `NaN`.
+    /// - Otherwise, if either part is `NaN`, the result is `NaN`.
+    /// - If both parts are zero, the result is $0.0$.
+    ///
+    /// The result may overflow or underflow as by [`Float::hypot_prec_round`].
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Complex::abs_prec_ref`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the input's parts,
+    /// consider using [`Complex::abs_round_ref`] instead. If both of these things are true,
+    /// consider using [`Complex::abs`](Abs::abs) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the absolute value can't be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.abs_prec_round_ref(5, Floor);
+    /// assert_eq!(z.to_string(), "4.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.abs_prec_round_ref(5, Ceiling);
+    /// assert_eq!(z.to_string(), "4.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.abs_prec_round_ref(20, Nearest);
+    /// assert_eq!(z.to_string(), "4.1543579");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn abs_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        abs_helper(self, prec, rm)
+    }
+
+    /// Computes the absolute value of a [`Complex`], rounding the result to the nearest value of
+    /// the specified precision. The [`Complex`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded absolute value is less than, equal to, or greater
+    /// than the exact absolute value. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN`, it also returns `Equal`.
+    ///
+    /// If the absolute value is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::abs_prec_round`] with `Nearest`; see its documentation for error bounds
+    /// and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using [`Complex::abs_prec_round`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::abs`](Abs::abs) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().abs_prec(5);
+    /// assert_eq!(z.to_string(), "4.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.clone().abs_prec(20);
+    /// assert_eq!(z.to_string(), "4.1543579");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn abs_prec(self, prec: u64) -> (Float, Ordering) {
+        self.abs_prec_round(prec, Nearest)
+    }
+
+    /// Computes the absolute value of a [`Complex`], rounding the result to the nearest value of
+    /// the specified precision. The [`Complex`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded absolute value is less than, equal to, or greater
+    /// than the exact absolute value. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN`, it also returns `Equal`.
+    ///
+    /// If the absolute value is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::abs_prec_round_ref`] with `Nearest`; see its documentation for error
+    /// bounds and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using [`Complex::abs_prec_round_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::abs`](Abs::abs) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.abs_prec_ref(5);
+    /// assert_eq!(z.to_string(), "4.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.abs_prec_ref(20);
+    /// assert_eq!(z.to_string(), "4.1543579");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn abs_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.abs_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the absolute value of a [`Complex`], rounding the result with the specified
+    /// rounding mode. The [`Complex`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded absolute value is less than, equal to, or greater than the
+    /// exact absolute value. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN`, it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts. This is
+    /// [`Complex::abs_prec_round`] with that precision; see its documentation for error bounds and
+    /// special cases.
+    ///
+    /// If you want to specify the output precision, consider using [`Complex::abs_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Complex::abs`](Abs::abs) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the absolute value can't be represented exactly with the
+    /// output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().abs_round(Floor);
+    /// assert_eq!(z.to_string(), "4.1543544023133130");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.clone().abs_round(Ceiling);
+    /// assert_eq!(z.to_string(), "4.1543544023133139");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.clone().abs_round(Nearest);
+    /// assert_eq!(z.to_string(), "4.1543544023133130");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn abs_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.max_prec();
+        self.abs_prec_round(prec, rm)
+    }
+
+    /// Computes the absolute value of a [`Complex`], rounding the result with the specified
+    /// rounding mode. The [`Complex`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded absolute value is less than, equal to, or greater than the
+    /// exact absolute value. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN`, it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts. This is
+    /// [`Complex::abs_prec_round_ref`] with that precision; see its documentation for error bounds
+    /// and special cases.
+    ///
+    /// If you want to specify the output precision, consider using [`Complex::abs_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Complex::abs`](Abs::abs) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the absolute value can't be represented exactly with the
+    /// output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.abs_round_ref(Floor);
+    /// assert_eq!(z.to_string(), "4.1543544023133130");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.abs_round_ref(Ceiling);
+    /// assert_eq!(z.to_string(), "4.1543544023133139");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.abs_round_ref(Nearest);
+    /// assert_eq!(z.to_string(), "4.1543544023133130");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn abs_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.max_prec();
+        self.abs_prec_round_ref(prec, rm)
+    }
+}
+
+impl Abs for Complex {
+    type Output = Float;
+
+    /// Computes the absolute value of a [`Complex`], taking it by value.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts, and the
+    /// result is rounded to the nearest value of that precision. This is [`Complex::abs_round`]
+    /// with `Nearest`; see [`Complex::abs_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using [`Complex::abs_round`] instead.
+    /// If you want to specify the output precision, consider using [`Complex::abs_prec`]. If you
+    /// want both of these things, consider using [`Complex::abs_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!(x.clone().abs().to_string(), "4.1543544023133130");
+    /// ```
+    #[inline]
+    fn abs(self) -> Float {
+        let prec = self.max_prec();
+        self.abs_prec_round(prec, Nearest).0
+    }
+}
+
+impl Abs for &Complex {
+    type Output = Float;
+
+    /// Computes the absolute value of a [`Complex`], taking it by reference.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts, and the
+    /// result is rounded to the nearest value of that precision. This is [`Complex::abs_round_ref`]
+    /// with `Nearest`; see [`Complex::abs_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using [`Complex::abs_round_ref`]
+    /// instead. If you want to specify the output precision, consider using
+    /// [`Complex::abs_prec_ref`]. If you want both of these things, consider using
+    /// [`Complex::abs_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!((&x).abs().to_string(), "4.1543544023133130");
+    /// ```
+    #[inline]
+    fn abs(self) -> Float {
+        let prec = self.max_prec();
+        self.abs_prec_round_ref(prec, Nearest).0
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl Complex {
+    /// Computes the complex conjugate of a [`Complex`], taking it by value.
+    ///
+    /// $$
+    /// f(a+bi) = a-bi.
+    /// $$
+    ///
+    /// The imaginary part is negated as by [`Float`](malachite_float::Float)'s `Neg`, so the
+    /// result is exact, and the sign of a zero or infinite imaginary part is flipped.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::ZERO.conj().to_string(), "(0.0 -0.0)");
+    /// assert_eq!(Complex::ONE.conj().to_string(), "(1.0 -0.0)");
+    /// assert_eq!(Complex::I.conj().to_string(), "(0.0 -1.0)");
+    /// assert_eq!(
+    ///     Complex::from((Float::from(1.5), Float::from(-2.5)))
+    ///         .conj()
+    ///         .to_string(),
+    ///     "(1.5 2.5)"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn conj(mut self) -> Self {
+        self.conj_assign();
+        self
+    }
+
+    /// Computes the complex conjugate of a [`Complex`], taking it by reference.
+    ///
+    /// $$
+    /// f(a+bi) = a-bi.
+    /// $$
+    ///
+    /// The imaginary part is negated as by [`Float`](malachite_float::Float)'s `Neg`, so the
+    /// result is exact, and the sign of a zero or infinite imaginary part is flipped.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::ZERO.conj_ref().to_string(), "(0.0 -0.0)");
+    /// assert_eq!(Complex::ONE.conj_ref().to_string(), "(1.0 -0.0)");
+    /// assert_eq!(Complex::I.conj_ref().to_string(), "(0.0 -1.0)");
+    /// assert_eq!(
+    ///     Complex::from((Float::from(1.5), Float::from(-2.5)))
+    ///         .conj_ref()
+    ///         .to_string(),
+    ///     "(1.5 2.5)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn conj_ref(&self) -> Self {
+        Self {
+            real: self.real.clone(),
+            imag: -&self.imag,
+        }
+    }
+
+    /// Replaces a [`Complex`] with its complex conjugate.
+    ///
+    /// $$
+    /// a+bi \gets a-bi.
+    /// $$
+    ///
+    /// The imaginary part is negated as by [`Float`](malachite_float::Float)'s `NegAssign`, so the
+    /// result is exact, and the sign of a zero or infinite imaginary part is flipped.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::ONE;
+    /// x.conj_assign();
+    /// assert_eq!(x.to_string(), "(1.0 -0.0)");
+    ///
+    /// let mut x = Complex::from((Float::from(1.5), Float::from(-2.5)));
+    /// x.conj_assign();
+    /// assert_eq!(x.to_string(), "(1.5 2.5)");
+    /// ```
+    #[inline]
+    pub fn conj_assign(&mut self) {
+        self.imag.neg_assign();
+    }
+}
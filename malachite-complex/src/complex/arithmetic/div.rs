@@ -0,0 +1,1691 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use crate::complex::arithmetic::mul::{mul_helper, signed_zero};
+use crate::complex::arithmetic::norm::norm_helper;
+use crate::complex::arithmetic::{
+    can_round_prec, check_exact, exact_to_down, shl_round_with_ordering,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use core::ops::{Div, DivAssign};
+use malachite_base::num::arithmetic::traits::CeilingLogBase2;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+// The sign of a nonzero `Float`, or 0 for zero.
+const fn sign(x: &Float) -> i8 {
+    if x.is_zero() {
+        0
+    } else if x.is_sign_negative() {
+        -1
+    } else {
+        1
+    }
+}
+
+// The sign of $x + y$, or 0 if the sum is zero.
+fn sign_of_sum(x: &Float, y: &Float) -> i8 {
+    match x.partial_cmp(&-y).unwrap() {
+        Less => -1,
+        Equal => 0,
+        Greater => 1,
+    }
+}
+
+// This is equivalent to `mpc_div_zero` from `div.c`, MPC 1.4.1, where `w` is zero.
+fn div_zero(
+    z: &Complex,
+    w: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> Complex {
+    let infinity = if w.real.is_sign_negative() {
+        Float::NEGATIVE_INFINITY
+    } else {
+        Float::INFINITY
+    };
+    Complex {
+        real: infinity.mul_prec_round_ref_ref(&z.real, prec.0, rm.0).0,
+        imag: infinity.mul_prec_round_ref_ref(&z.imag, prec.1, rm.1).0,
+    }
+}
+
+// This is equivalent to `mpc_div_inf_fin` from `div.c`, MPC 1.4.1, where `z` is infinite and `w` is
+// finite and nonzero.
+fn div_infinite_finite(z: &Complex, w: &Complex) -> Complex {
+    let infinite_sign = |f: &Float| {
+        if !f.is_infinite() {
+            0
+        } else if f.is_sign_negative() {
+            -1i8
+        } else {
+            1
+        }
+    };
+    let a = infinite_sign(&z.real);
+    let b = infinite_sign(&z.imag);
+    let neg_w_real = -&w.real;
+    let (x, y) = if a == 0 || b == 0 {
+        // Only one of `a` or `b` can be zero, since `z` is infinite
+        (
+            a * sign(&w.real) + b * sign(&w.imag),
+            b * sign(&w.real) - a * sign(&w.imag),
+        )
+    } else {
+        let sum = sign_of_sum(&w.real, &w.imag);
+        let difference = sign_of_sum(&w.real, &-&w.imag);
+        match (a, b) {
+            (1, 1) => (sum, difference),
+            (1, _) => (difference, -sum),
+            (_, 1) => (sign_of_sum(&w.imag, &neg_w_real), sum),
+            _ => (-sum, sign_of_sum(&w.imag, &neg_w_real)),
+        }
+    };
+    let infinity = |s: i8| match s {
+        0 => Float::NAN,
+        1 => Float::INFINITY,
+        _ => Float::NEGATIVE_INFINITY,
+    };
+    Complex {
+        real: infinity(x),
+        imag: infinity(y),
+    }
+}
+
+// This is equivalent to `mpc_div_fin_inf` from `div.c`, MPC 1.4.1, where `z` is finite and `w` is
+// infinite.
+fn div_finite_infinite(z: &Complex, w: &Complex) -> Complex {
+    let unit = |f: &Float| {
+        let u = if f.is_infinite() {
+            Float::ONE
+        } else {
+            Float::ZERO
+        };
+        if f.is_sign_negative() { -u } else { u }
+    };
+    let c = unit(&w.real);
+    let d = unit(&w.imag);
+    let x = &z.real * &c + &z.imag * &d;
+    let y = &z.imag * &c - &z.real * &d;
+    Complex {
+        real: signed_zero(x.is_sign_negative()),
+        imag: signed_zero(y.is_sign_negative()),
+    }
+}
+
+// This is equivalent to `mpc_div_real` from `div.c`, MPC 1.4.1, where `z` and `w` are finite and
+// the imaginary part of `w` is zero.
+fn div_real(
+    z: &Complex,
+    w: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    let zrs = z.real.is_sign_negative();
+    let zis = z.imag.is_sign_negative();
+    let wrs = w.real.is_sign_negative();
+    let wis = w.imag.is_sign_negative();
+    let (mut real, o_r) = z.real.div_prec_round_ref_ref(&w.real, prec.0, rm.0);
+    let (mut imag, o_i) = z.imag.div_prec_round_ref_ref(&w.real, prec.1, rm.1);
+    // Correct the signs of zeros if necessary, which does not affect the `Ordering`s
+    if real.is_zero() {
+        real = signed_zero(zrs != wrs && zis != wis);
+    }
+    if imag.is_zero() {
+        imag = signed_zero(zis != wrs && zrs == wis);
+    }
+    (Complex { real, imag }, (o_r, o_i))
+}
+
+// This is equivalent to `mpc_div_imag` from `div.c`, MPC 1.4.1, where `z` and `w` are finite and
+// the real part of `w` is zero.
+fn div_imag(
+    z: &Complex,
+    w: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    let zrs = z.real.is_sign_negative();
+    let zis = z.imag.is_sign_negative();
+    let wrs = w.real.is_sign_negative();
+    let wis = w.imag.is_sign_negative();
+    let (mut real, o_r) = z.imag.div_prec_round_ref_ref(&w.imag, prec.0, rm.0);
+    let (mut imag, o_i) = z.real.div_prec_round_ref_ref(&-&w.imag, prec.1, rm.1);
+    // Correct the signs of zeros if necessary, which does not affect the `Ordering`s
+    if real.is_zero() {
+        real = signed_zero(zrs != wrs && zis != wis);
+    }
+    if z.real.is_zero() {
+        imag = signed_zero(zis != wrs && zrs == wis);
+    }
+    (Complex { real, imag }, (o_r, o_i))
+}
+
+// The largest exponent of the nonzero parts of a finite `Complex`, or 0 if it is zero.
+fn max_exponent(z: &Complex) -> i64 {
+    [&z.real, &z.imag]
+        .into_iter()
+        .filter_map(Float::get_exponent)
+        .map(i64::from)
+        .max()
+        .unwrap_or(0)
+}
+
+// This is equivalent to `mpc_div` from `div.c`, MPC 1.4.1, except that instead of widening the
+// exponent range, the inputs are scaled by powers of 2 so that intermediate computations don't
+// overflow or underflow. The quotient is only scaled back at the end, so that its parts overflow
+// or underflow correctly.
+pub(crate) fn div_helper(
+    z: &Complex,
+    w: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    assert_ne!(prec.0, 0);
+    assert_ne!(prec.1, 0);
+    // According to the C standard G.3, there are three types of numbers: finite (both parts are
+    // usual real numbers; contains 0), infinite (at least one part is a real infinity) and all
+    // others; the latter are numbers containing a NaN, but no infinity. By G.5.1.4,
+    // infinite/finite = infinite; finite/infinite = 0; all other divisions that are not
+    // finite/finite return NaN + NaN * i.
+    if w.is_zero() {
+        return (div_zero(z, w, prec, rm), (Equal, Equal));
+    } else if z.is_infinite() && w.is_finite() {
+        return (div_infinite_finite(z, w), (Equal, Equal));
+    } else if z.is_finite() && w.is_infinite() {
+        return (div_finite_infinite(z, w), (Equal, Equal));
+    } else if !z.is_finite() || !w.is_finite() {
+        return (
+            Complex {
+                real: Float::NAN,
+                imag: Float::NAN,
+            },
+            (Equal, Equal),
+        );
+    } else if w.imag.is_zero() {
+        return div_real(z, w, prec, rm);
+    } else if w.real.is_zero() {
+        return div_imag(z, w, prec, rm);
+    }
+    let rm_r = exact_to_down(rm.0);
+    let rm_i = exact_to_down(rm.1);
+    let ez = max_exponent(z);
+    let ew = max_exponent(w);
+    let z = Complex {
+        real: &z.real << -ez,
+        imag: &z.imag << -ez,
+    };
+    // The conjugate of `w`, scaled
+    let w = Complex {
+        real: &w.real << -ew,
+        imag: -(&w.imag << -ew),
+    };
+    let mut working_prec = max(prec.0, prec.1);
+    let mut loops = 0;
+    loop {
+        loops += 1;
+        working_prec += if loops <= 2 {
+            working_prec.ceiling_log_base_2() + 5
+        } else {
+            working_prec >> 1
+        };
+        let wp = working_prec;
+        let err = i64::exact_from(wp) - 4;
+        let (q, o_q) = norm_helper(&w, wp, Up);
+        let (res, (o_re, o_im)) = mul_helper(&z, &w, (wp, wp), (Down, Down));
+        let mut inexact_re = o_re != Equal;
+        let mut inexact_im = o_im != Equal;
+        let (real, imag);
+        if o_q == Equal && (!inexact_re || !inexact_im) {
+            // The division has good chances to be exact in at least one part.
+            let o;
+            (real, o) = res.real.div_prec_round_ref_ref(&q, wp, Down);
+            inexact_re |= o != Equal;
+            if inexact_re && !real.can_round(err, Nearest, Down, can_round_prec(prec.0, rm_r)) {
+                continue;
+            }
+            let o;
+            (imag, o) = res.imag.div_prec_round_ref_ref(&q, wp, Down);
+            inexact_im |= o != Equal;
+        } else {
+            // The division is inexact, so for efficiency reasons we invert `q` only once and
+            // multiply by the inverse.
+            let (q_inverse, o) = q.reciprocal_prec_round(wp, Down);
+            if o != Equal || o_q != Equal {
+                inexact_re |= !res.real.is_zero();
+                inexact_im |= !res.imag.is_zero();
+            }
+            let o;
+            (real, o) = res.real.mul_prec_round_ref_ref(&q_inverse, wp, Down);
+            inexact_re |= o != Equal;
+            if inexact_re && !real.can_round(err, Nearest, Down, can_round_prec(prec.0, rm_r)) {
+                continue;
+            }
+            let o;
+            (imag, o) = res.imag.mul_prec_round_ref_ref(&q_inverse, wp, Down);
+            inexact_im |= o != Equal;
+        }
+        if inexact_im && !imag.can_round(err, Nearest, Down, can_round_prec(prec.1, rm_i)) {
+            continue;
+        }
+        let (real, o_r) = Float::from_float_prec_round(real, prec.0, rm_r);
+        let (imag, o_i) = Float::from_float_prec_round(imag, prec.1, rm_i);
+        check_exact(rm.0, o_r, "division");
+        check_exact(rm.1, o_i, "division");
+        let (real, o_r) = shl_round_with_ordering(real, o_r, ez - ew, prec.0, rm.0);
+        let (imag, o_i) = shl_round_with_ordering(imag, o_i, ez - ew, prec.1, rm.1);
+        return (Complex { real, imag }, (o_r, o_i));
+    }
+}
+
+impl Complex {
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. Both [`Complex`]s
+    /// are taken by value. A pair of [`Ordering`]s is also returned, indicating whether the rounded
+    /// real and imaginary parts are less than, equal to, or greater than the real and imaginary
+    /// parts of the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x/y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_div`. In particular:
+    /// - If $y$ is zero and $x$ is neither zero nor `NaN`, the quotient is infinite, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - If $y$ is zero and $x$ is zero, or if $x$ and $y$ are both infinite, both parts of the
+    ///   quotient are `NaN`.
+    /// - If $x$ is infinite and $y$ is finite and not `NaN`, the quotient is infinite.
+    /// - If $x$ is finite and not `NaN` and $y$ is infinite, the quotient is zero, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - Otherwise, if either input has a `NaN` part, both parts of the quotient are `NaN`, except
+    ///   that when $y$ is real or imaginary (with a zero part) the quotient is computed part by
+    ///   part, as by [`Float`] division.
+    ///
+    /// The result is correctly rounded even when intermediate values would overflow or underflow.
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using [`Complex::div_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::div_round`] instead. If both of these things are
+    /// true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the quotient can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().div_prec_round(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.00 2.4297)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().div_prec_round(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().div_prec_round(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn div_prec_round(
+        self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        div_helper(&self, &other, prec, rm)
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The first
+    /// [`Complex`] is taken by value and the second by reference. A pair of [`Ordering`]s is also
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact quotient. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` part, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x/y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_div`. In particular:
+    /// - If $y$ is zero and $x$ is neither zero nor `NaN`, the quotient is infinite, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - If $y$ is zero and $x$ is zero, or if $x$ and $y$ are both infinite, both parts of the
+    ///   quotient are `NaN`.
+    /// - If $x$ is infinite and $y$ is finite and not `NaN`, the quotient is infinite.
+    /// - If $x$ is finite and not `NaN` and $y$ is infinite, the quotient is zero, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - Otherwise, if either input has a `NaN` part, both parts of the quotient are `NaN`, except
+    ///   that when $y$ is real or imaginary (with a zero part) the quotient is computed part by
+    ///   part, as by [`Float`] division.
+    ///
+    /// The result is correctly rounded even when intermediate values would overflow or underflow.
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::div_prec_val_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::div_round_val_ref`]
+    /// instead. If both of these things are true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the quotient can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().div_prec_round_val_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.00 2.4297)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().div_prec_round_val_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().div_prec_round_val_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_prec_round_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        div_helper(&self, other, prec, rm)
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The first
+    /// [`Complex`] is taken by reference and the second by value. A pair of [`Ordering`]s is also
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact quotient. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` part, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x/y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_div`. In particular:
+    /// - If $y$ is zero and $x$ is neither zero nor `NaN`, the quotient is infinite, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - If $y$ is zero and $x$ is zero, or if $x$ and $y$ are both infinite, both parts of the
+    ///   quotient are `NaN`.
+    /// - If $x$ is infinite and $y$ is finite and not `NaN`, the quotient is infinite.
+    /// - If $x$ is finite and not `NaN` and $y$ is infinite, the quotient is zero, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - Otherwise, if either input has a `NaN` part, both parts of the quotient are `NaN`, except
+    ///   that when $y$ is real or imaginary (with a zero part) the quotient is computed part by
+    ///   part, as by [`Float`] division.
+    ///
+    /// The result is correctly rounded even when intermediate values would overflow or underflow.
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::div_prec_ref_val`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::div_round_ref_val`]
+    /// instead. If both of these things are true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the quotient can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.div_prec_round_ref_val(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.00 2.4297)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.div_prec_round_ref_val(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.div_prec_round_ref_val(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn div_prec_round_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        div_helper(self, &other, prec, rm)
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. Both [`Complex`]s
+    /// are taken by reference. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x/y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_div`. In particular:
+    /// - If $y$ is zero and $x$ is neither zero nor `NaN`, the quotient is infinite, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - If $y$ is zero and $x$ is zero, or if $x$ and $y$ are both infinite, both parts of the
+    ///   quotient are `NaN`.
+    /// - If $x$ is infinite and $y$ is finite and not `NaN`, the quotient is infinite.
+    /// - If $x$ is finite and not `NaN` and $y$ is infinite, the quotient is zero, with the signs
+    ///   of its parts determined by those of $x$ and $y$.
+    /// - Otherwise, if either input has a `NaN` part, both parts of the quotient are `NaN`, except
+    ///   that when $y$ is real or imaginary (with a zero part) the quotient is computed part by
+    ///   part, as by [`Float`] division.
+    ///
+    /// The result is correctly rounded even when intermediate values would overflow or underflow.
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::div_prec_ref_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::div_round_ref_ref`]
+    /// instead. If both of these things are true, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the quotient can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.div_prec_round_ref_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.00 2.4297)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.div_prec_round_ref_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.div_prec_round_ref_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_prec_round_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        div_helper(self, other, prec, rm)
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. Both [`Complex`]s are taken by
+    /// value. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the quotient is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::div_prec_round`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_prec_round`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the inputs'
+    /// parts, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().div_prec(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().div_prec(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_prec(self, other: Complex, prec: (u64, u64)) -> (Self, (Ordering, Ordering)) {
+        self.div_prec_round(other, prec, (Nearest, Nearest))
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The first [`Complex`] is taken by
+    /// value and the second by reference. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact quotient. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` part, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// If a part of the quotient is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::div_prec_round_val_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_prec_round_val_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().div_prec_val_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().div_prec_val_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_prec_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        self.div_prec_round_val_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The first [`Complex`] is taken by
+    /// reference and the second by value. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact quotient. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` part, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// If a part of the quotient is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::div_prec_round_ref_val`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_prec_round_ref_val`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.div_prec_ref_val(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.div_prec_ref_val(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.div_prec_round_ref_val(other, prec, (Nearest, Nearest))
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. Both [`Complex`]s are taken by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the quotient is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::div_prec_round_ref_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_prec_round_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.div_prec_ref_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.div_prec_ref_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.div_prec_round_ref_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. Both [`Complex`]s are taken by value. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact quotient.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::div_prec_round`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::div_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the quotient can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().div_round(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.0315509055955958 2.4277086018857772)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().div_round(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().div_round(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_round(
+        self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round(other, (prec, prec), rm)
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The first [`Complex`] is taken by value and the
+    /// second by reference. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::div_prec_round_val_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the quotient can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().div_round_val_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.0315509055955958 2.4277086018857772)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().div_round_val_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().div_round_val_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_round_val_ref(
+        self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_val_ref(other, (prec, prec), rm)
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The first [`Complex`] is taken by reference and
+    /// the second by value. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::div_prec_round_ref_val`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the quotient can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.div_round_ref_val(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.0315509055955958 2.4277086018857772)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.div_round_ref_val(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.div_round_ref_val(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_round_ref_val(
+        &self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_ref_val(other, (prec, prec), rm)
+    }
+
+    /// Divides a [`Complex`] by another [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. Both [`Complex`]s are taken by reference. A pair
+    /// of [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts
+    /// are less than, equal to, or greater than the real and imaginary parts of the exact quotient.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::div_prec_round_ref_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the quotient can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.div_round_ref_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.0315509055955958 2.4277086018857772)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.div_round_ref_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.div_round_ref_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn div_round_ref_ref(
+        &self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_ref_ref(other, (prec, prec), rm)
+    }
+
+    /// Divides a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by value. A pair of [`Ordering`]s is returned,
+    /// indicating whether the rounded real and imaginary parts are less than, equal to, or greater
+    /// than the real and imaginary parts of the exact quotient. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::div_prec_assign`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::div_round_assign`]
+    /// instead. If both of these things are true, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the quotient can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_prec_round_assign(y.clone(), (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.00 2.4297)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(
+    ///     z.div_prec_round_assign(y.clone(), (20, 20), (Nearest, Nearest)),
+    ///     (Greater, Less)
+    /// );
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn div_prec_round_assign(
+        &mut self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.div_prec_round_ref_ref(&other, prec, rm);
+        o
+    }
+
+    /// Divides a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by reference. A pair of [`Ordering`]s is
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact quotient. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::div_prec_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the inputs' parts, consider using
+    /// [`Complex::div_round_assign_ref`] instead. If both of these things are true, consider using
+    /// `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the quotient can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_prec_round_assign_ref(&y, (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.00 2.4297)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_prec_round_assign_ref(&y, (20, 20), (Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// ```
+    #[inline]
+    pub fn div_prec_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.div_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+
+    /// Divides a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by value. A pair of [`Ordering`]s is returned, indicating whether
+    /// the rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the quotient is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_prec_round_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_prec_assign(y.clone(), (5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_prec_assign(y.clone(), (20, 20)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// ```
+    #[inline]
+    pub fn div_prec_assign(&mut self, other: Complex, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.div_prec_round_assign(other, prec, (Nearest, Nearest))
+    }
+
+    /// Divides a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by reference. A pair of [`Ordering`]s is returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact quotient. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets a part to `NaN`, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// If a part of the quotient is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using
+    /// [`Complex::div_prec_round_assign_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the inputs' parts, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_prec_assign_ref(&y, (5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.06 2.4258)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_prec_assign_ref(&y, (20, 20)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.0315514 2.4277077)");
+    /// ```
+    #[inline]
+    pub fn div_prec_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Ordering, Ordering) {
+        self.div_prec_round_assign_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Divides a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by value. A pair of [`Ordering`]s is returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the quotient can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_round_assign(y.clone(), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.0315509055955958 2.4277086018857772)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_round_assign(y.clone(), (Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// ```
+    #[inline]
+    pub fn div_round_assign(
+        &mut self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_assign(other, (prec, prec), rm)
+    }
+
+    /// Divides a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by reference. A pair of [`Ordering`]s is returned, indicating whether the rounded real
+    /// and imaginary parts are less than, equal to, or greater than the real and imaginary parts of
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the quotient can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_round_assign_ref(&y, (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.0315509055955958 2.4277086018857772)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.div_round_assign_ref(&y, (Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// ```
+    #[inline]
+    pub fn div_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_assign_ref(other, (prec, prec), rm)
+    }
+}
+
+impl Div<Self> for Complex {
+    type Output = Self;
+
+    /// Divides a [`Complex`] by another [`Complex`], taking both by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::div_round`] with both rounding modes `Nearest`; see [`Complex::div_prec_round`]
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_round`] instead. If
+    /// you want to specify the output precisions, consider using [`Complex::div_prec`]. If you want
+    /// both of these things, consider using [`Complex::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((x.clone() / y.clone()).to_string(), "(1.0315509055955960 2.4277086018857768)");
+    ///
+    /// assert_eq!((Complex::ONE / Complex::I).to_string(), "(0.0 -1.0)");
+    /// ```
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Div<&Self> for Complex {
+    type Output = Self;
+
+    /// Divides a [`Complex`] by another [`Complex`], taking the first by value and the second by
+    /// reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::div_round_val_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_round_val_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_val_ref`]. If you want both of these things, consider using
+    /// [`Complex::div_prec_round_val_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((x.clone() / &y).to_string(), "(1.0315509055955960 2.4277086018857768)");
+    ///
+    /// assert_eq!((Complex::ONE / Complex::I).to_string(), "(0.0 -1.0)");
+    /// ```
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_val_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Div<Complex> for &Complex {
+    type Output = Complex;
+
+    /// Divides a [`Complex`] by another [`Complex`], taking the first by reference and the second
+    /// by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::div_round_ref_val`] with both rounding modes `Nearest`; see
+    /// [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_round_ref_val`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_ref_val`]. If you want both of these things, consider using
+    /// [`Complex::div_prec_round_ref_val`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x / y.clone()).to_string(), "(1.0315509055955960 2.4277086018857768)");
+    ///
+    /// assert_eq!((Complex::ONE / Complex::I).to_string(), "(0.0 -1.0)");
+    /// ```
+    #[inline]
+    fn div(self, other: Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_ref_val(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Div<&Complex> for &Complex {
+    type Output = Complex;
+
+    /// Divides a [`Complex`] by another [`Complex`], taking both by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::div_round_ref_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_round_ref_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_ref_ref`]. If you want both of these things, consider using
+    /// [`Complex::div_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x / &y).to_string(), "(1.0315509055955960 2.4277086018857768)");
+    ///
+    /// assert_eq!((Complex::ONE / Complex::I).to_string(), "(0.0 -1.0)");
+    /// ```
+    #[inline]
+    fn div(self, other: &Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_ref_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl DivAssign<Self> for Complex {
+    /// Divides a [`Complex`] by a [`Complex`] in place, taking the [`Complex`] on the right-hand
+    /// side by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::div_round_assign`] with both rounding modes `Nearest`; see
+    /// [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_round_assign`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_assign`]. If you want both of these things, consider using
+    /// [`Complex::div_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z /= y.clone();
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_assign(other, (prec, prec), (Nearest, Nearest));
+    }
+}
+
+impl DivAssign<&Self> for Complex {
+    /// Divides a [`Complex`] by a [`Complex`] in place, taking the [`Complex`] on the right-hand
+    /// side by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::div_round_assign_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::div_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::div_round_assign_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::div_prec_assign_ref`]. If you want both of these things, consider using
+    /// [`Complex::div_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z /= &y;
+    /// assert_eq!(z.to_string(), "(1.0315509055955960 2.4277086018857768)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.div_prec_round_assign_ref(other, (prec, prec), (Nearest, Nearest));
+    }
+}
@@ -0,0 +1,870 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use crate::complex::arithmetic::mul::signed_zero;
+use crate::complex::arithmetic::{
+    can_round_prec, check_exact, exact_to_down, overflow, shl_round_with_ordering, underflow,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use core::f64::consts::LN_2;
+use malachite_base::num::arithmetic::traits::{CeilingLogBase2, Exp, ExpAssign};
+use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+// Whether multiplying a nonzero finite `Float` by $2^k$ overflows or underflows.
+fn shl_out_of_range(x: &Float, k: i64) -> bool {
+    x.get_exponent().is_some_and(|e| {
+        !(i64::from(Float::MIN_EXPONENT)..=i64::from(Float::MAX_EXPONENT))
+            .contains(&(i64::from(e) + k))
+    })
+}
+
+// This is equivalent to `mpc_exp` from `exp.c`, MPC 1.4.1, except that instead of widening the
+// exponent range, the real part of the input is reduced modulo $\ln 2$, and the cosine and sine of
+// the imaginary part are scaled to have an exponent of 0; everything is scaled back at the end, so
+// that the parts of the result overflow or underflow correctly. The last element of the returned
+// tuple is whether the result overflowed or underflowed.
+pub(crate) fn exp_helper(
+    z: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering), bool) {
+    assert_ne!(prec.0, 0);
+    assert_ne!(prec.1, 0);
+    let x = &z.real;
+    let y = &z.imag;
+    let nan = || {
+        (
+            Complex {
+                real: Float::NAN,
+                imag: Float::NAN,
+            },
+            (Equal, Equal),
+            false,
+        )
+    };
+    // Special values
+    if x.is_nan() || y.is_nan() {
+        // exp(NaN + i * y) = NaN ± i * 0 if y = ±0, and NaN + i * NaN otherwise; exp(x + i * NaN)
+        // = ±0 ± i * 0 if x = -Inf, ±Inf + i * NaN if x = +Inf, and NaN + i * NaN otherwise
+        return if y.is_zero() {
+            (z.clone(), (Equal, Equal), false)
+        } else if x.is_infinite() {
+            if x.is_sign_negative() {
+                (
+                    Complex {
+                        real: Float::ZERO,
+                        imag: Float::ZERO,
+                    },
+                    (Equal, Equal),
+                    false,
+                )
+            } else {
+                (
+                    Complex {
+                        real: Float::INFINITY,
+                        imag: Float::NAN,
+                    },
+                    (Equal, Equal),
+                    false,
+                )
+            }
+        } else {
+            nan()
+        };
+    }
+    if y.is_zero() {
+        // When the input is real, exp(x ± i * 0) = exp(x) ± i * 0
+        let (real, o) = x.exp_prec_round_ref(prec.0, rm.0);
+        return (
+            Complex {
+                real,
+                imag: y.clone(),
+            },
+            (o, Equal),
+            false,
+        );
+    }
+    if x.is_zero() {
+        // When the input is imaginary, exp(i * y) = cos(y) + i * sin(y)
+        let ((imag, o_i), (real, o_r)) = if prec.0 == prec.1 && rm.0 == rm.1 {
+            y.sin_cos_prec_round_ref(prec.0, rm.0)
+        } else {
+            (
+                y.sin_prec_round_ref(prec.1, rm.1),
+                y.cos_prec_round_ref(prec.0, rm.0),
+            )
+        };
+        return (Complex { real, imag }, (o_r, o_i), false);
+    }
+    if x.is_infinite() {
+        // exp(-Inf + i * y) = 0 * (cos(y) + i * sin(y)); exp(+Inf + i * y) = ±Inf + i * NaN if y =
+        // ±Inf, and +Inf * (cos(y) + i * sin(y)) if 0 < |y| < Inf
+        let negative = x.is_sign_negative();
+        let scale = |positive: bool| {
+            if negative {
+                signed_zero(!positive)
+            } else if positive {
+                Float::INFINITY
+            } else {
+                -Float::INFINITY
+            }
+        };
+        return if y.is_infinite() {
+            (
+                Complex {
+                    real: scale(true),
+                    imag: if negative { Float::ZERO } else { Float::NAN },
+                },
+                (Equal, Equal),
+                false,
+            )
+        } else {
+            let ((sin, _), (cos, _)) = y.sin_cos_prec_round_ref(1, Nearest);
+            (
+                Complex {
+                    real: scale(cos > 0u32),
+                    imag: scale(sin > 0u32),
+                },
+                (Equal, Equal),
+                false,
+            )
+        };
+    }
+    if y.is_infinite() {
+        // The real part is a finite nonzero number, and the imaginary part is infinite
+        return nan();
+    }
+    // From now on, both parts of the input are nonzero finite numbers
+    let ex = i64::from(x.get_exponent().unwrap());
+    let ey = i64::from(y.get_exponent().unwrap());
+    if ex > 40 {
+        // |x| > 2^40, so exp(x) is far outside the range of `Float`s, and so is the result, unless
+        // cos(y) or sin(y) is unimaginably small
+        let ((sin, _), (cos, _)) = y.sin_cos_prec_round_ref(1, Nearest);
+        let (real, o_r, imag, o_i) = if *x > 0u32 {
+            let (real, o_r) = overflow(cos > 0u32, prec.0, rm.0);
+            let (imag, o_i) = overflow(sin > 0u32, prec.1, rm.1);
+            (real, o_r, imag, o_i)
+        } else {
+            let (real, o_r) = underflow(cos > 0u32, prec.0, rm.0);
+            let (imag, o_i) = underflow(sin > 0u32, prec.1, rm.1);
+            (real, o_r, imag, o_i)
+        };
+        check_exact(rm.0, o_r, "exponential");
+        check_exact(rm.1, o_i, "exponential");
+        return (Complex { real, imag }, (o_r, o_i), true);
+    }
+    let rm_re = exact_to_down(rm.0);
+    let rm_im = exact_to_down(rm.1);
+    // exp(x) = 2^k * exp(x - k * ln(2)). The reduction is only done when |x| >= 1.
+    let k = if ex > 0 {
+        (f64::rounding_from(x, Nearest).0 / LN_2).round() as i64
+    } else {
+        0
+    };
+    // When z is close to 0, exp(x) is close to 1 + x, while cos(y) is close to 1 - y ^ 2 / 2; to
+    // decide on the `Ordering` of exp(x) * cos(y), we need a high enough precision so that neither
+    // of them is computed as 1.
+    let mut working_prec = max(prec.0, prec.1) + u64::exact_from(max(max(-ex, 0), -ey));
+    loop {
+        working_prec += (working_prec >> 1) + working_prec.ceiling_log_base_2() + 5;
+        let wp = working_prec;
+        // With the reduction, the error on the reduced argument is less than 2 ^ (-wp - 22), so
+        // the error on exp(x) increases by less than 2 ^ -20 ulp, and one more bit is discarded
+        let err = i64::exact_from(wp) - if k == 0 { 2 } else { 3 };
+        let r = if k == 0 {
+            x.clone()
+        } else {
+            let reduction_prec = wp + 64;
+            let ln_2 = Float::ln_2_prec_round(reduction_prec, Nearest).0;
+            let k_ln_2 = ln_2
+                .mul_prec_round(Float::from(k), reduction_prec + 64, Nearest)
+                .0;
+            x.sub_prec_round_ref_ref(&k_ln_2, reduction_prec, Nearest).0
+        };
+        // The errors are at most 0.5 ulp each
+        let (exp_r, _) = r.exp_prec_round(wp, Nearest);
+        let ((sin, _), (cos, _)) = y.sin_cos_prec_round_ref(wp, Nearest);
+        let e_cos = i64::from(cos.get_exponent().unwrap());
+        let e_sin = i64::from(sin.get_exponent().unwrap());
+        // The error is at most 2 ulp
+        let (real, _) = exp_r.mul_prec_round_ref_ref(&(cos << -e_cos), wp, Nearest);
+        if !real.can_round(err, Nearest, Down, can_round_prec(prec.0, rm_re)) {
+            continue;
+        }
+        let (imag, _) = exp_r.mul_prec_round(sin << -e_sin, wp, Nearest);
+        if !imag.can_round(err, Nearest, Down, can_round_prec(prec.1, rm_im)) {
+            continue;
+        }
+        let (real, o_r) = Float::from_float_prec_round(real, prec.0, rm_re);
+        let (imag, o_i) = Float::from_float_prec_round(imag, prec.1, rm_im);
+        let out_of_range = shl_out_of_range(&real, k + e_cos) || shl_out_of_range(&imag, k + e_sin);
+        let (real, o_r) = shl_round_with_ordering(real, o_r, k + e_cos, prec.0, rm_re);
+        let (imag, o_i) = shl_round_with_ordering(imag, o_i, k + e_sin, prec.1, rm_im);
+        check_exact(rm.0, o_r, "exponential");
+        check_exact(rm.1, o_i, "exponential");
+        return (Complex { real, imag }, (o_r, o_i), out_of_range);
+    }
+}
+
+impl Complex {
+    /// Computes the exponential of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The [`Complex`] is
+    /// taken by value. A pair of [`Ordering`]s is also returned, indicating whether the rounded
+    /// real and imaginary parts are less than, equal to, or greater than the real and imaginary
+    /// parts of the exact exponential. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// $e^{a+bi} = e^a(\cos b+i\sin b)$.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $e^x$. Then
+    /// $$
+    /// f(x,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_exp`. In particular:
+    /// - $e^{x\pm0i} = e^x\pm0i$ for every real $x$, including `NaN` and infinities, with the real
+    ///   part computed as by [`Float::exp_prec_round`].
+    /// - $e^{\pm0+yi} = \cos y+i\sin y$ for every $y$.
+    /// - If $y$ is finite and nonzero, $e^{\infty+yi}$ has infinite parts and $e^{-\infty+yi}$ has
+    ///   zero parts, whose signs are those of $\cos y$ and $\sin y$.
+    /// - If $y$ is infinite or `NaN`, $e^{\infty+yi} = \infty+\mathrm{NaN}i$ and $e^{-\infty+yi} =
+    ///   0+0i$.
+    /// - Otherwise, if a part of the input is `NaN`, both parts of the result are `NaN`.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using [`Complex::exp_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::exp_round`] instead. If both of these things are
+    /// true, consider using [`Complex::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the exponential can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().exp_prec_round((5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(-22.0 9.5156)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().exp_prec_round((5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(-21.0 9.5000)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().exp_prec_round((20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(-21.098145 9.5057678)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn exp_prec_round(
+        self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        self.exp_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The [`Complex`] is
+    /// taken by reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded
+    /// real and imaginary parts are less than, equal to, or greater than the real and imaginary
+    /// parts of the exact exponential. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// $e^{a+bi} = e^a(\cos b+i\sin b)$.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $e^x$. Then
+    /// $$
+    /// f(x,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_exp`. In particular:
+    /// - $e^{x\pm0i} = e^x\pm0i$ for every real $x$, including `NaN` and infinities, with the real
+    ///   part computed as by [`Float::exp_prec_round`].
+    /// - $e^{\pm0+yi} = \cos y+i\sin y$ for every $y$.
+    /// - If $y$ is finite and nonzero, $e^{\infty+yi}$ has infinite parts and $e^{-\infty+yi}$ has
+    ///   zero parts, whose signs are those of $\cos y$ and $\sin y$.
+    /// - If $y$ is infinite or `NaN`, $e^{\infty+yi} = \infty+\mathrm{NaN}i$ and $e^{-\infty+yi} =
+    ///   0+0i$.
+    /// - Otherwise, if a part of the input is `NaN`, both parts of the result are `NaN`.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::exp_prec_ref`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the input's parts, consider using [`Complex::exp_round_ref`] instead. If
+    /// both of these things are true, consider using [`Complex::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the exponential can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.exp_prec_round_ref((5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(-22.0 9.5156)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.exp_prec_round_ref((5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(-21.0 9.5000)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.exp_prec_round_ref((20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(-21.098145 9.5057678)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn exp_prec_round_ref(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let (z, o, _) = exp_helper(self, prec, rm);
+        (z, o)
+    }
+
+    /// Computes the exponential of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The [`Complex`] is taken by value.
+    /// A pair of [`Ordering`]s is also returned, indicating whether the rounded real and imaginary
+    /// parts are less than, equal to, or greater than the real and imaginary parts of the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the exponential is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::exp_prec_round`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::exp_prec_round`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the input's
+    /// parts, consider using [`Complex::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().exp_prec((5, 10));
+    /// assert_eq!(z.to_string(), "(-21.0 9.5000)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().exp_prec((20, 20));
+    /// assert_eq!(z.to_string(), "(-21.098145 9.5057678)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn exp_prec(self, prec: (u64, u64)) -> (Self, (Ordering, Ordering)) {
+        self.exp_prec_round(prec, (Nearest, Nearest))
+    }
+
+    /// Computes the exponential of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The [`Complex`] is taken by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the exponential is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::exp_prec_round_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::exp_prec_round_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.exp_prec_ref((5, 10));
+    /// assert_eq!(z.to_string(), "(-21.0 9.5000)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.exp_prec_ref((20, 20));
+    /// assert_eq!(z.to_string(), "(-21.098145 9.5057678)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn exp_prec_ref(&self, prec: (u64, u64)) -> (Complex, (Ordering, Ordering)) {
+        self.exp_prec_round_ref(prec, (Nearest, Nearest))
+    }
+
+    /// Computes the exponential of a [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The [`Complex`] is taken by value. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact exponential.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. This is [`Complex::exp_prec_round`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::exp_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using [`Complex::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the exponential can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().exp_round((Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(-21.098154284965723 9.5057635828242226)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().exp_round((Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(-21.098154284965720 9.5057635828242208)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().exp_round((Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(-21.098154284965720 9.5057635828242226)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn exp_round(self, rm: (RoundingMode, RoundingMode)) -> (Self, (Ordering, Ordering)) {
+        let prec = self.max_prec();
+        self.exp_prec_round((prec, prec), rm)
+    }
+
+    /// Computes the exponential of a [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The [`Complex`] is taken by reference. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact exponential.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. This is [`Complex::exp_prec_round_ref`] with that precision; see its documentation
+    /// for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::exp_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using [`Complex::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the exponential can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.exp_round_ref((Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(-21.098154284965723 9.5057635828242226)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.exp_round_ref((Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(-21.098154284965720 9.5057635828242208)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.exp_round_ref((Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(-21.098154284965720 9.5057635828242226)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn exp_round_ref(
+        &self,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = self.max_prec();
+        self.exp_prec_round_ref((prec, prec), rm)
+    }
+
+    /// Replaces a [`Complex`] with its exponential in place, rounding the real and imaginary parts
+    /// of the result to the specified precisions and with the specified rounding modes. A pair of
+    /// [`Ordering`]s is returned, indicating whether the rounded real and imaginary parts are less
+    /// than, equal to, or greater than the real and imaginary parts of the exact exponential.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets a part to
+    /// `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::exp_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::exp_prec_assign`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the input's parts, consider using [`Complex::exp_round_assign`]
+    /// instead. If both of these things are true, consider using [`Complex::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the exponential can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.exp_prec_round_assign((5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(-22.0 9.5156)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.exp_prec_round_assign((20, 20), (Nearest, Nearest)), (Greater, Greater));
+    /// assert_eq!(z.to_string(), "(-21.098145 9.5057678)");
+    /// ```
+    #[inline]
+    pub fn exp_prec_round_assign(
+        &mut self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.exp_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Replaces a [`Complex`] with its exponential in place, rounding the real and imaginary parts
+    /// of the result to the nearest value of the specified precisions. A pair of [`Ordering`]s is
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact exponential. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets a part to `NaN`, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the exponential is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::exp_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::exp_prec_round_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.exp_prec_assign((5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(-21.0 9.5000)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.exp_prec_assign((20, 20)), (Greater, Greater));
+    /// assert_eq!(z.to_string(), "(-21.098145 9.5057678)");
+    /// ```
+    #[inline]
+    pub fn exp_prec_assign(&mut self, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.exp_prec_round_assign(prec, (Nearest, Nearest))
+    }
+
+    /// Replaces a [`Complex`] with its exponential in place, rounding the real and imaginary parts
+    /// of the result with the specified rounding modes. A pair of [`Ordering`]s is returned,
+    /// indicating whether the rounded real and imaginary parts are less than, equal to, or greater
+    /// than the real and imaginary parts of the exact exponential. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. See [`Complex::exp_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::exp_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the exponential can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.exp_round_assign((Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(-21.098154284965723 9.5057635828242226)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.exp_round_assign((Nearest, Nearest)), (Greater, Greater));
+    /// assert_eq!(z.to_string(), "(-21.098154284965720 9.5057635828242226)");
+    /// ```
+    #[inline]
+    pub fn exp_round_assign(&mut self, rm: (RoundingMode, RoundingMode)) -> (Ordering, Ordering) {
+        let prec = self.max_prec();
+        self.exp_prec_round_assign((prec, prec), rm)
+    }
+}
+
+impl Exp for Complex {
+    type Output = Self;
+
+    /// Computes the exponential of a [`Complex`], taking it by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::exp_round`] with both rounding modes `Nearest`; see [`Complex::exp_prec_round`]
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::exp_round`] instead. If
+    /// you want to specify the output precisions, consider using [`Complex::exp_prec`]. If you want
+    /// both of these things, consider using [`Complex::exp_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!(x.clone().exp().to_string(), "(-21.098154284965720 9.5057635828242226)");
+    ///
+    /// assert_eq!(Complex::I.exp().to_string(), "(0.50 1.0)");
+    /// ```
+    #[inline]
+    fn exp(self) -> Self {
+        let prec = self.max_prec();
+        self.exp_prec_round((prec, prec), (Nearest, Nearest)).0
+    }
+}
+
+impl Exp for &Complex {
+    type Output = Complex;
+
+    /// Computes the exponential of a [`Complex`], taking it by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::exp_round_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::exp_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::exp_round_ref`] instead.
+    /// If you want to specify the output precisions, consider using [`Complex::exp_prec_ref`]. If
+    /// you want both of these things, consider using [`Complex::exp_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!((&x).exp().to_string(), "(-21.098154284965720 9.5057635828242226)");
+    ///
+    /// assert_eq!(Complex::I.exp().to_string(), "(0.50 1.0)");
+    /// ```
+    #[inline]
+    fn exp(self) -> Complex {
+        let prec = self.max_prec();
+        self.exp_prec_round_ref((prec, prec), (Nearest, Nearest)).0
+    }
+}
+
+impl ExpAssign for Complex {
+    /// Replaces a [`Complex`] with its exponential in place.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::exp_round_assign`] with both rounding modes `Nearest`; see
+    /// [`Complex::exp_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::exp_round_assign`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::exp_prec_assign`]. If you want both of these things, consider using
+    /// [`Complex::exp_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::ExpAssign;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// z.exp_assign();
+    /// assert_eq!(z.to_string(), "(-21.098154284965720 9.5057635828242226)");
+    /// ```
+    #[inline]
+    fn exp_assign(&mut self) {
+        let prec = self.max_prec();
+        self.exp_prec_round_assign((prec, prec), (Nearest, Nearest));
+    }
+}
@@ -0,0 +1,853 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use crate::complex::arithmetic::{can_round_prec, check_exact, exact_to_down, underflow};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Abs, CeilingLogBase2, Ln, LnAssign};
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+// This is equivalent to `mpc_log` from `log.c`, MPC 1.4.1.
+pub(crate) fn ln_helper(
+    z: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    assert_ne!(prec.0, 0);
+    assert_ne!(prec.1, 0);
+    let x = &z.real;
+    let y = &z.imag;
+    // Special values: NaN and infinities
+    if !z.is_finite() {
+        let (real, (imag, o_i)) = if x.is_nan() {
+            (
+                if y.is_infinite() {
+                    Float::INFINITY
+                } else {
+                    Float::NAN
+                },
+                (Float::NAN, Equal),
+            )
+        } else if y.is_nan() {
+            (
+                if x.is_infinite() {
+                    Float::INFINITY
+                } else {
+                    Float::NAN
+                },
+                (Float::NAN, Equal),
+            )
+        } else {
+            // There is an infinity in at least one part
+            (Float::INFINITY, y.atan2_prec_round_ref_ref(x, prec.1, rm.1))
+        };
+        return (Complex { real, imag }, (Equal, o_i));
+    }
+    // Special cases: real and purely imaginary numbers
+    if y.is_zero() {
+        return if x.is_zero() {
+            let (imag, o_i) = y.atan2_prec_round_ref_ref(x, prec.1, rm.1);
+            (
+                Complex {
+                    real: Float::NEGATIVE_INFINITY,
+                    imag,
+                },
+                (Equal, o_i),
+            )
+        } else if *x > 0u32 {
+            let (real, o_r) = x.ln_prec_round_ref(prec.0, rm.0);
+            (
+                Complex {
+                    real,
+                    imag: y.clone(),
+                },
+                (o_r, Equal),
+            )
+        } else {
+            // z = x ± 0 * i, with x < 0
+            let (real, o_r) = (-x).ln_prec_round(prec.0, rm.0);
+            let (imag, o_i) = if y.is_sign_negative() {
+                let (pi, o) = Float::pi_prec_round(prec.1, -rm.1);
+                (-pi, o.reverse())
+            } else {
+                Float::pi_prec_round(prec.1, rm.1)
+            };
+            (Complex { real, imag }, (o_r, o_i))
+        };
+    } else if x.is_zero() {
+        let (real, o_r) = y.abs().ln_prec_round(prec.0, rm.0);
+        // Division by 2 does not change the `Ordering`
+        let (imag, o_i) = if *y > 0u32 {
+            let (pi, o) = Float::pi_prec_round(prec.1, rm.1);
+            (pi >> 1u32, o)
+        } else {
+            let (pi, o) = Float::pi_prec_round(prec.1, -rm.1);
+            (-(pi >> 1u32), o.reverse())
+        };
+        return (Complex { real, imag }, (o_r, o_i));
+    }
+    let rm_re = exact_to_down(rm.0);
+    let target_prec = can_round_prec(prec.0, rm_re);
+    // Let z = x + iy; ln(z) = ln(x ^ 2 + y ^ 2) / 2 + i * atan2(y, x). The first algorithm
+    // computes the real part as ln(|z|). It only requires one real logarithm, but |z| may overflow
+    // although the logarithm is always representable, which is why a second algorithm is needed.
+    let mut w = None;
+    let mut working_prec = prec.0;
+    for _ in 0..2 {
+        working_prec += working_prec.ceiling_log_base_2() + 4;
+        // The error is at most 0.5 ulp
+        let (abs, _) = x.hypot_prec_round_ref_ref(y, working_prec, Nearest);
+        if abs.is_infinite() {
+            // Intermediate overflow; the logarithm may be representable. Intermediate underflow is
+            // impossible.
+            break;
+        }
+        // The generic error of ln is (2 ^ (-exp(w)) + 0.5) ulp
+        let (ln, _) = abs.ln_prec_round(working_prec, Nearest);
+        if ln.is_zero() {
+            // Impossible to round; switch to the second algorithm
+            break;
+        }
+        // The number of lost bits
+        let err = max(-i64::from(ln.get_exponent().unwrap()), 0) + 1;
+        if ln.can_round(
+            i64::exact_from(working_prec) - err,
+            Nearest,
+            Down,
+            target_prec,
+        ) {
+            w = Some(ln);
+            break;
+        }
+    }
+    let mut is_underflow = false;
+    if w.is_none() {
+        // Compute ln(x ^ 2 + y ^ 2) / 2 = ln|x| + ln(1 + (y / x) ^ 2) / 2 if |x| >= |y|; otherwise,
+        // exchange x and y
+        let (x, y) = if x.ge_abs(y) { (x, y) } else { (y, x) };
+        let abs_x = x.abs();
+        let mut working_prec = prec.0;
+        let mut loop_count = 0;
+        loop {
+            loop_count += 1;
+            working_prec += if loop_count <= 2 {
+                working_prec.ceiling_log_base_2() + 4
+            } else {
+                working_prec >> 1
+            };
+            let wp = working_prec;
+            // The error is at most 1 ulp
+            let (v, _) = y.div_prec_round_ref_ref(x, wp, Floor);
+            // The generic error of multiplication is at most 1 + 2 * 1 * (2 + 1 * 2 ^ (1 - wp))
+            // <= 5.0625 ulp, since wp >= 6
+            let (v, _) = v.square_prec_round(wp, Floor);
+            // The error is at most 1 + 4 * 5.0625 = 21.25 ulp. If the result is 0, then there has
+            // been an underflow somewhere.
+            let (v, _) = v.ln_1_plus_x_prec_round(wp, Floor);
+            let v = v >> 1u32;
+            // The error is at most 0.5 ulp
+            let (ln_abs_x, _) = abs_x.ln_prec_round_ref(wp, Nearest);
+            let exp_ln_abs_x = ln_abs_x.get_exponent().map_or(i64::MIN, i64::from);
+            let ln_abs_x_negative = ln_abs_x.is_sign_negative();
+            let (sum, _) = ln_abs_x.add_prec_round_ref_ref(&v, wp, Nearest);
+            let err = if ln_abs_x_negative {
+                // The 21.25 ulp of v and the 0.5 ulp of ln|x|, rewritten in terms of the ulp of the
+                // result
+                let exp_v = v.get_exponent().map_or(i64::MIN, i64::from);
+                let exp_sum = sum.get_exponent().map_or(i64::MIN, i64::from);
+                max(
+                    5i64.saturating_add(exp_v),
+                    (-1 + exp_ln_abs_x).saturating_sub(exp_sum),
+                ) + 2
+            } else {
+                // v is positive, so there's no cancellation; the error is at most 22.25 ulp
+                5
+            };
+            // Handle one special case: |x| = 1, and (y / x) ^ 2 underflows; then ln(x ^ 2 + y ^ 2)
+            // / 2 ~ y ^ 2 / 2 also underflows.
+            if sum.is_zero() && abs_x == 1u32 {
+                is_underflow = true;
+                break;
+            }
+            if sum.can_round(
+                i64::exact_from(wp).saturating_sub(err),
+                Nearest,
+                Down,
+                target_prec,
+            ) {
+                w = Some(sum);
+                break;
+            }
+        }
+    }
+    let (real, o_r) = if is_underflow {
+        underflow(true, prec.0, rm_re)
+    } else {
+        Float::from_float_prec_round(w.unwrap(), prec.0, rm_re)
+    };
+    check_exact(rm.0, o_r, "logarithm");
+    let (imag, o_i) = y.atan2_prec_round_ref_ref(x, prec.1, rm.1);
+    (Complex { real, imag }, (o_r, o_i))
+}
+
+impl Complex {
+    /// Computes the natural logarithm of a [`Complex`], rounding the real and imaginary parts of
+    /// the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] is taken by value. A pair of [`Ordering`]s is also returned, indicating whether
+    /// the rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact logarithm. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The principal branch is used: $\log x = \log|x| + i\arg x$, where $-\pi \leq \arg x \leq
+    /// \pi$ is computed as by [`Complex::arg_prec_round`]. The branch cut lies along the negative
+    /// real axis, and the sign of the (zero) imaginary part of the input determines whether the
+    /// imaginary part of the result is $\pi$ or $-\pi$ there.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $\log x$.
+    /// Then
+    /// $$
+    /// f(x,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_log`. In particular:
+    /// - $\log(\pm0+0i) = -\infty+\arg(\pm0+0i)i$, and similarly for a negative zero imaginary
+    ///   part.
+    /// - If either part of the input is infinite, the real part of the result is $\infty$, and the
+    ///   imaginary part is the argument of the input, or `NaN` if the other part is `NaN`.
+    /// - Otherwise, if a part of the input is `NaN`, both parts of the result are `NaN`.
+    ///
+    /// The real part of the result cannot overflow; if it would underflow, it is rounded as
+    /// [`Float::ln_prec_round`] would round it.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using [`Complex::ln_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::ln_round`] instead. If both of these things are
+    /// true, consider using [`Complex::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the logarithm can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().ln_prec_round((5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.38 0.71387)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().ln_prec_round((5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.44 0.71289)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().ln_prec_round((20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.4241562 0.71328449)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn ln_prec_round(
+        self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        self.ln_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Complex`], rounding the real and imaginary parts of
+    /// the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] is taken by reference. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` part, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// The principal branch is used: $\log x = \log|x| + i\arg x$, where $-\pi \leq \arg x \leq
+    /// \pi$ is computed as by [`Complex::arg_prec_round`]. The branch cut lies along the negative
+    /// real axis, and the sign of the (zero) imaginary part of the input determines whether the
+    /// imaginary part of the result is $\pi$ or $-\pi$ there.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $\log x$.
+    /// Then
+    /// $$
+    /// f(x,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_log`. In particular:
+    /// - $\log(\pm0+0i) = -\infty+\arg(\pm0+0i)i$, and similarly for a negative zero imaginary
+    ///   part.
+    /// - If either part of the input is infinite, the real part of the result is $\infty$, and the
+    ///   imaginary part is the argument of the input, or `NaN` if the other part is `NaN`.
+    /// - Otherwise, if a part of the input is `NaN`, both parts of the result are `NaN`.
+    ///
+    /// The real part of the result cannot overflow; if it would underflow, it is rounded as
+    /// [`Float::ln_prec_round`] would round it.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::ln_prec_ref`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the input's parts, consider using [`Complex::ln_round_ref`] instead. If
+    /// both of these things are true, consider using [`Complex::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the logarithm can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.ln_prec_round_ref((5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.38 0.71387)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.ln_prec_round_ref((5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.44 0.71289)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.ln_prec_round_ref((20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.4241562 0.71328449)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn ln_prec_round_ref(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        ln_helper(self, prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Complex`], rounding the real and imaginary parts of
+    /// the result to the nearest value of the specified precisions. The [`Complex`] is taken by
+    /// value. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the logarithm is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::ln_prec_round`] with both rounding modes `Nearest`; see its documentation
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::ln_prec_round`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the input's
+    /// parts, consider using [`Complex::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().ln_prec((5, 10));
+    /// assert_eq!(z.to_string(), "(1.44 0.71289)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().ln_prec((20, 20));
+    /// assert_eq!(z.to_string(), "(1.4241562 0.71328449)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn ln_prec(self, prec: (u64, u64)) -> (Self, (Ordering, Ordering)) {
+        self.ln_prec_round(prec, (Nearest, Nearest))
+    }
+
+    /// Computes the natural logarithm of a [`Complex`], rounding the real and imaginary parts of
+    /// the result to the nearest value of the specified precisions. The [`Complex`] is taken by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the logarithm is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::ln_prec_round_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::ln_prec_round_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.ln_prec_ref((5, 10));
+    /// assert_eq!(z.to_string(), "(1.44 0.71289)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.ln_prec_ref((20, 20));
+    /// assert_eq!(z.to_string(), "(1.4241562 0.71328449)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn ln_prec_ref(&self, prec: (u64, u64)) -> (Complex, (Ordering, Ordering)) {
+        self.ln_prec_round_ref(prec, (Nearest, Nearest))
+    }
+
+    /// Computes the natural logarithm of a [`Complex`], rounding the real and imaginary parts of
+    /// the result with the specified rounding modes. The [`Complex`] is taken by value. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. This is [`Complex::ln_prec_round`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::ln_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using [`Complex::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the logarithm can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().ln_round((Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.4241570377303032 0.71328454043905043)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().ln_round((Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.4241570377303034 0.71328454043905032)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().ln_round((Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.4241570377303034 0.71328454043905032)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn ln_round(self, rm: (RoundingMode, RoundingMode)) -> (Self, (Ordering, Ordering)) {
+        let prec = self.max_prec();
+        self.ln_prec_round((prec, prec), rm)
+    }
+
+    /// Computes the natural logarithm of a [`Complex`], rounding the real and imaginary parts of
+    /// the result with the specified rounding modes. The [`Complex`] is taken by reference. A pair
+    /// of [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts
+    /// are less than, equal to, or greater than the real and imaginary parts of the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. This is [`Complex::ln_prec_round_ref`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::ln_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using [`Complex::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the logarithm can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.ln_round_ref((Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.4241570377303032 0.71328454043905043)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.ln_round_ref((Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.4241570377303034 0.71328454043905032)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.ln_round_ref((Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.4241570377303034 0.71328454043905032)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn ln_round_ref(
+        &self,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = self.max_prec();
+        self.ln_prec_round_ref((prec, prec), rm)
+    }
+
+    /// Replaces a [`Complex`] with its natural logarithm in place, rounding the real and imaginary
+    /// parts of the result to the specified precisions and with the specified rounding modes. A
+    /// pair of [`Ordering`]s is returned, indicating whether the rounded real and imaginary parts
+    /// are less than, equal to, or greater than the real and imaginary parts of the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::ln_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::ln_prec_assign`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the input's parts, consider using [`Complex::ln_round_assign`] instead.
+    /// If both of these things are true, consider using [`Complex::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the logarithm can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.ln_prec_round_assign((5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.38 0.71387)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.ln_prec_round_assign((20, 20), (Nearest, Nearest)), (Less, Less));
+    /// assert_eq!(z.to_string(), "(1.4241562 0.71328449)");
+    /// ```
+    #[inline]
+    pub fn ln_prec_round_assign(
+        &mut self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.ln_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Replaces a [`Complex`] with its natural logarithm in place, rounding the real and imaginary
+    /// parts of the result to the nearest value of the specified precisions. A pair of
+    /// [`Ordering`]s is returned, indicating whether the rounded real and imaginary parts are less
+    /// than, equal to, or greater than the real and imaginary parts of the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets a part to
+    /// `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the logarithm is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::ln_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::ln_prec_round_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.ln_prec_assign((5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.44 0.71289)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.ln_prec_assign((20, 20)), (Less, Less));
+    /// assert_eq!(z.to_string(), "(1.4241562 0.71328449)");
+    /// ```
+    #[inline]
+    pub fn ln_prec_assign(&mut self, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.ln_prec_round_assign(prec, (Nearest, Nearest))
+    }
+
+    /// Replaces a [`Complex`] with its natural logarithm in place, rounding the real and imaginary
+    /// parts of the result with the specified rounding modes. A pair of [`Ordering`]s is returned,
+    /// indicating whether the rounded real and imaginary parts are less than, equal to, or greater
+    /// than the real and imaginary parts of the exact logarithm. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. See [`Complex::ln_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::ln_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the logarithm can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.ln_round_assign((Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.4241570377303032 0.71328454043905043)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.ln_round_assign((Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.4241570377303034 0.71328454043905032)");
+    /// ```
+    #[inline]
+    pub fn ln_round_assign(&mut self, rm: (RoundingMode, RoundingMode)) -> (Ordering, Ordering) {
+        let prec = self.max_prec();
+        self.ln_prec_round_assign((prec, prec), rm)
+    }
+}
+
+impl Ln for Complex {
+    type Output = Self;
+
+    /// Computes the natural logarithm of a [`Complex`], taking it by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::ln_round`] with both rounding modes `Nearest`; see [`Complex::ln_prec_round`] for
+    /// error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::ln_round`] instead. If
+    /// you want to specify the output precisions, consider using [`Complex::ln_prec`]. If you want
+    /// both of these things, consider using [`Complex::ln_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!(x.clone().ln().to_string(), "(1.4241570377303034 0.71328454043905032)");
+    ///
+    /// assert_eq!((-Complex::ONE).ln().to_string(), "(0.0 -4.0)");
+    /// ```
+    #[inline]
+    fn ln(self) -> Self {
+        let prec = self.max_prec();
+        self.ln_prec_round((prec, prec), (Nearest, Nearest)).0
+    }
+}
+
+impl Ln for &Complex {
+    type Output = Complex;
+
+    /// Computes the natural logarithm of a [`Complex`], taking it by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::ln_round_ref`] with both rounding modes `Nearest`; see [`Complex::ln_prec_round`]
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::ln_round_ref`] instead.
+    /// If you want to specify the output precisions, consider using [`Complex::ln_prec_ref`]. If
+    /// you want both of these things, consider using [`Complex::ln_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!((&x).ln().to_string(), "(1.4241570377303034 0.71328454043905032)");
+    ///
+    /// assert_eq!((-Complex::ONE).ln().to_string(), "(0.0 -4.0)");
+    /// ```
+    #[inline]
+    fn ln(self) -> Complex {
+        let prec = self.max_prec();
+        self.ln_prec_round_ref((prec, prec), (Nearest, Nearest)).0
+    }
+}
+
+impl LnAssign for Complex {
+    /// Replaces a [`Complex`] with its natural logarithm in place.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::ln_round_assign`] with both rounding modes `Nearest`; see
+    /// [`Complex::ln_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::ln_round_assign`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::ln_prec_assign`]. If you want both of these things, consider using
+    /// [`Complex::ln_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::LnAssign;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// z.ln_assign();
+    /// assert_eq!(z.to_string(), "(1.4241570377303034 0.71328454043905032)");
+    /// ```
+    #[inline]
+    fn ln_assign(&mut self) {
+        let prec = self.max_prec();
+        self.ln_prec_round_assign((prec, prec), (Nearest, Nearest));
+    }
+}
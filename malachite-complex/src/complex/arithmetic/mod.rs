@@ -0,0 +1,149 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{IsPowerOf2, NegAssign};
+use malachite_base::num::basic::traits::{Infinity, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+// `Float::can_round` doesn't accept `Exact`, so functions that need a Ziv loop compute with `Down`
+// instead, and then check that the result was exact.
+pub(crate) const fn exact_to_down(rm: RoundingMode) -> RoundingMode {
+    if let Exact = rm { Down } else { rm }
+}
+
+// The precision to pass to `Float::can_round`. As in MPC, an extra bit is requested when rounding
+// to nearest, so that the `Ordering` of the rounded result is also correct.
+pub(crate) fn can_round_prec(prec: u64, rm: RoundingMode) -> u64 {
+    prec + u64::from(rm == Nearest || rm == NearestTiesAway)
+}
+
+// Whether a part of a result that was computed with `Down` and then rounded is inexact, even
+// though `Exact` was requested.
+pub(crate) fn check_exact(rm: RoundingMode, o: Ordering, name: &str) {
+    assert!(rm != Exact || o == Equal, "Inexact complex {name}");
+}
+
+// Equivalent to MPC's `mpc_fix_inf`: the value of an overflowing part whose sign is given by
+// `positive`. Returns infinity unless `rm` rounds toward zero, in which case the largest finite
+// value with precision `prec` is returned.
+pub(crate) fn overflow(positive: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if positive {
+        match rm {
+            Floor | Down | Odd => (Float::max_finite_value_with_prec(prec), Less),
+            _ => (Float::INFINITY, Greater),
+        }
+    } else {
+        match rm {
+            Ceiling | Down | Odd => (-Float::max_finite_value_with_prec(prec), Greater),
+            _ => (Float::NEGATIVE_INFINITY, Less),
+        }
+    }
+}
+
+// Equivalent to MPC's `mpc_fix_zero`: the value of an underflowing part whose sign is given by
+// `positive`. Returns zero unless `rm` rounds away from zero, in which case the smallest positive
+// value with precision `prec` (or its negative) is returned.
+pub(crate) fn underflow(positive: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if positive {
+        match rm {
+            Ceiling | Up | Odd => (Float::min_positive_value_prec(prec), Greater),
+            _ => (Float::ZERO, Less),
+        }
+    } else {
+        match rm {
+            Floor | Up | Odd => (-Float::min_positive_value_prec(prec), Less),
+            _ => (Float::NEGATIVE_ZERO, Greater),
+        }
+    }
+}
+
+// Multiplies `x` by $2^k$, where `x` was obtained by rounding some exact value to `prec` bits using
+// `rm`, with `o` the resulting `Ordering`. If the product overflows or underflows, the result is
+// what rounding the exact value times $2^k$ would have given. The only case where the `Ordering`
+// matters is that of a power of 2 that lands exactly halfway between zero and the smallest
+// positive `Float`: rounding with `Nearest` then gives zero unless the exact value was larger, and
+// rounding with `NearestTiesAway` gives the smallest positive `Float` unless the exact value was
+// smaller.
+pub(crate) fn shl_round_with_ordering(
+    x: Float,
+    o: Ordering,
+    k: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if k == 0 || !x.is_normal() {
+        return (x, o);
+    }
+    let exponent = i64::from(x.get_exponent().unwrap()) + k;
+    if (i64::from(Float::MIN_EXPONENT)..=i64::from(Float::MAX_EXPONENT)).contains(&exponent) {
+        return (x << k, o);
+    }
+    let positive = x > 0u32;
+    if rm == Nearest
+        && exponent == i64::from(Float::MIN_EXPONENT) - 1
+        && x.significand_ref().unwrap().is_power_of_2()
+        && o == if positive { Less } else { Greater }
+    {
+        let mut y = Float::min_positive_value_prec(prec);
+        if !positive {
+            y.neg_assign();
+        }
+        return (y, if positive { Greater } else { Less });
+    }
+    if rm == NearestTiesAway
+        && exponent == i64::from(Float::MIN_EXPONENT) - 1
+        && x.significand_ref().unwrap().is_power_of_2()
+        && o == if positive { Greater } else { Less }
+    {
+        return if positive {
+            (Float::ZERO, Less)
+        } else {
+            (Float::NEGATIVE_ZERO, Greater)
+        };
+    }
+    x.shl_prec_round(k, prec, rm)
+}
+
+/// [`Abs`](malachite_base::num::arithmetic::traits::Abs), a trait for computing the absolute value,
+/// or modulus, of [`Complex`](super::Complex)s, and the associated precision- and
+/// rounding-mode-aware functions.
+pub mod abs;
+/// Addition of [`Complex`](super::Complex)s.
+pub mod add;
+/// Functions for computing the argument of [`Complex`](super::Complex)s.
+pub mod arg;
+/// Functions for computing the complex conjugate of [`Complex`](super::Complex)s.
+pub mod conj;
+/// Division of [`Complex`](super::Complex)s.
+pub mod div;
+/// [`Exp`](malachite_base::num::arithmetic::traits::Exp) and
+/// [`ExpAssign`](malachite_base::num::arithmetic::traits::ExpAssign), traits for computing $e^z$
+/// for [`Complex`](super::Complex)s.
+pub mod exp;
+/// [`Ln`](malachite_base::num::arithmetic::traits::Ln) and
+/// [`LnAssign`](malachite_base::num::arithmetic::traits::LnAssign), traits for computing the
+/// principal natural logarithm of [`Complex`](super::Complex)s.
+pub mod ln;
+/// Multiplication of [`Complex`](super::Complex)s.
+pub mod mul;
+/// Negation of [`Complex`](super::Complex)s.
+pub mod neg;
+/// Functions for computing the norm, or squared absolute value, of [`Complex`](super::Complex)s.
+pub mod norm;
+/// [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
+/// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a
+/// [`Complex`](super::Complex) to a [`Complex`](super::Complex) power.
+pub mod pow;
+/// [`Sqrt`](malachite_base::num::arithmetic::traits::Sqrt) and
+/// [`SqrtAssign`](malachite_base::num::arithmetic::traits::SqrtAssign), traits for computing the
+/// principal square root of [`Complex`](super::Complex)s.
+pub mod sqrt;
+/// Subtraction of [`Complex`](super::Complex)s.
+pub mod sub;
@@ -0,0 +1,1586 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use core::ops::{Mul, MulAssign};
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+const fn signed_infinity(sign: i8) -> Float {
+    match sign {
+        0 => Float::NAN,
+        1 => Float::INFINITY,
+        _ => Float::NEGATIVE_INFINITY,
+    }
+}
+
+pub(crate) const fn signed_zero(negative: bool) -> Float {
+    if negative {
+        Float::NEGATIVE_ZERO
+    } else {
+        Float::ZERO
+    }
+}
+
+// This is equivalent to `mul_infinite` from `mul.c`, MPC 1.4.1, where `x` is infinite.
+fn mul_infinite(x: &Complex, y: &Complex) -> Complex {
+    let sign = |f: &Float| if f.is_sign_negative() { -1i8 } else { 1 };
+    let xrs = sign(&x.real);
+    let xis = sign(&x.imag);
+    let yrs = sign(&y.real);
+    let yis = sign(&y.imag);
+    let mut u;
+    let mut v;
+    if x.real.is_nan() || x.imag.is_nan() || y.real.is_nan() || y.imag.is_nan() {
+        u = 0;
+        v = 0;
+    } else if x.real.is_infinite() {
+        u = if y.real.is_zero()
+            || (x.imag.is_infinite() && y.imag.is_zero())
+            || (x.imag.is_zero() && y.imag.is_infinite())
+            || ((x.imag.is_infinite() || y.imag.is_infinite()) && xrs * yrs == xis * yis)
+        {
+            0
+        } else {
+            xrs * yrs
+        };
+        v = if y.imag.is_zero()
+            || (x.imag.is_infinite() && y.real.is_zero())
+            || (x.imag.is_zero() && y.real.is_infinite())
+            // MPC checks whether Im(x) is infinite twice here; checking Re(y) instead, as in the
+            // computation of u, makes the product commutative.
+            || ((x.imag.is_infinite() || y.real.is_infinite()) && xrs * yis != xis * yrs)
+        {
+            0
+        } else {
+            xrs * yis
+        };
+    } else {
+        u = if y.imag.is_zero()
+            || (x.real.is_zero() && y.real.is_infinite())
+            || (y.real.is_infinite() && xrs * yrs == xis * yis)
+        {
+            0
+        } else {
+            -xis * yis
+        };
+        v = if y.real.is_zero()
+            || (x.real.is_zero() && y.imag.is_infinite())
+            || (y.imag.is_infinite() && xrs * yis != xis * yrs)
+        {
+            0
+        } else {
+            xis * yrs
+        };
+    }
+    if u == 0 && v == 0 {
+        // The naive result is NaN + NaN * i. Obtain an infinity using the algorithm given in Annex
+        // G.5.1 of the ISO C99 standard.
+        let xr = i8::from(x.real.is_infinite());
+        let xi = i8::from(x.imag.is_infinite());
+        let (yr, yi) = if y.is_infinite() {
+            (
+                i8::from(y.real.is_infinite()),
+                i8::from(y.imag.is_infinite()),
+            )
+        } else {
+            (
+                i8::from(!y.real.is_zero() && !y.real.is_nan()),
+                i8::from(!y.imag.is_zero() && !y.imag.is_nan()),
+            )
+        };
+        u = xrs * xr * yrs * yr - xis * xi * yis * yi;
+        v = xrs * xr * yis * yi + xis * xi * yrs * yr;
+    }
+    Complex {
+        real: signed_infinity(u.signum()),
+        imag: signed_infinity(v.signum()),
+    }
+}
+
+// This is equivalent to `mul_real` from `mul.c`, MPC 1.4.1, where the imaginary part of `y` is
+// zero.
+fn mul_real(
+    x: &Complex,
+    y: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    let xrs = x.real.is_sign_negative();
+    let xis = x.imag.is_sign_negative();
+    let yrs = y.real.is_sign_negative();
+    let yis = y.imag.is_sign_negative();
+    let (mut real, o_r) = x.real.mul_prec_round_ref_ref(&y.real, prec.0, rm.0);
+    let (mut imag, o_i) = x.imag.mul_prec_round_ref_ref(&y.real, prec.1, rm.1);
+    // Signs of zeros may be wrong. Their correction does not change the `Ordering`s.
+    if real.is_zero() {
+        real = signed_zero(rm.0 == Floor || (xrs != yrs && xis == yis));
+    }
+    if imag.is_zero() {
+        imag = signed_zero(rm.1 == Floor || (xrs != yis && xis != yrs));
+    }
+    (Complex { real, imag }, (o_r, o_i))
+}
+
+// This is equivalent to `mul_imag` from `mul.c`, MPC 1.4.1, where the real part of `y` is zero.
+fn mul_imag(
+    x: &Complex,
+    y: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    let sign = y.real.is_sign_negative() != x.imag.is_sign_negative()
+        && y.imag.is_sign_negative() != x.real.is_sign_negative();
+    let (real, o_r) = x.imag.mul_prec_round_ref_ref(&y.imag, prec.0, -rm.0);
+    let (mut imag, o_i) = x.real.mul_prec_round_ref_ref(&y.imag, prec.1, rm.1);
+    // The sign of a zero imaginary part may be wrong (the real part can't be zero).
+    if imag.is_zero() {
+        imag = signed_zero(rm.1 == Floor || sign);
+    }
+    (Complex { real: -real, imag }, (o_r.reverse(), o_i))
+}
+
+pub(crate) fn mul_helper(
+    x: &Complex,
+    y: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    assert_ne!(prec.0, 0);
+    assert_ne!(prec.1, 0);
+    // As in ISO C99 (G.5.1 multiplicative operators), infinities are treated specially if both
+    // parts would be NaN when computed naively.
+    if x.is_infinite() {
+        return (mul_infinite(x, y), (Equal, Equal));
+    }
+    if y.is_infinite() {
+        return (mul_infinite(y, x), (Equal, Equal));
+    }
+    if x.is_nan() || y.is_nan() {
+        return (
+            Complex {
+                real: Float::NAN,
+                imag: Float::NAN,
+            },
+            (Equal, Equal),
+        );
+    }
+    if x.imag.is_zero() {
+        return mul_real(y, x, prec, rm);
+    }
+    if y.imag.is_zero() {
+        return mul_real(x, y, prec, rm);
+    }
+    if x.real.is_zero() {
+        return mul_imag(y, x, prec, rm);
+    }
+    if y.real.is_zero() {
+        return mul_imag(x, y, prec, rm);
+    }
+    let (real, o_r) = x
+        .real
+        .mul_sub_mul_prec_round_ref_ref_ref_ref(&y.real, &x.imag, &y.imag, prec.0, rm.0);
+    let (imag, o_i) = x
+        .real
+        .mul_add_mul_prec_round_ref_ref_ref_ref(&y.imag, &x.imag, &y.real, prec.1, rm.1);
+    (Complex { real, imag }, (o_r, o_i))
+}
+
+impl Complex {
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. Both [`Complex`]s are taken by
+    /// value. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $xy$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_mul`. In particular:
+    /// - If one factor is infinite (that is, has an infinite part) and the other is neither zero
+    ///   nor `NaN`, the product is infinite, even if the exact formula would produce `NaN`s: for
+    ///   example, $(\infty+0i)(1+\infty i)$ has infinite parts.
+    /// - If one factor is infinite and the other is zero, both parts of the product are `NaN`.
+    /// - Otherwise, if either factor has a `NaN` part, both parts of the product are `NaN`, except
+    ///   that when one factor is real or imaginary (with a zero part) the product is computed part
+    ///   by part, as by [`Float`] multiplication.
+    /// - When one factor is real or imaginary, the signs of the zero parts of the product are those
+    ///   given by multiplying the parts separately. Otherwise, the signs of exact zero parts follow
+    ///   the rules for [`Float`] sums and differences.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using [`Complex::mul_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::mul_round`] instead. If both of these things are
+    /// true, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the product can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().mul_prec_round(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.25 1.6680)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().mul_prec_round(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.50 1.6660)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().mul_prec_round(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn mul_prec_round(
+        self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        mul_helper(&self, &other, prec, rm)
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. The first [`Complex`] is taken
+    /// by value and the second by reference. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` part, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $xy$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_mul`. In particular:
+    /// - If one factor is infinite (that is, has an infinite part) and the other is neither zero
+    ///   nor `NaN`, the product is infinite, even if the exact formula would produce `NaN`s: for
+    ///   example, $(\infty+0i)(1+\infty i)$ has infinite parts.
+    /// - If one factor is infinite and the other is zero, both parts of the product are `NaN`.
+    /// - Otherwise, if either factor has a `NaN` part, both parts of the product are `NaN`, except
+    ///   that when one factor is real or imaginary (with a zero part) the product is computed part
+    ///   by part, as by [`Float`] multiplication.
+    /// - When one factor is real or imaginary, the signs of the zero parts of the product are those
+    ///   given by multiplying the parts separately. Otherwise, the signs of exact zero parts follow
+    ///   the rules for [`Float`] sums and differences.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::mul_prec_val_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::mul_round_val_ref`]
+    /// instead. If both of these things are true, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the product can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().mul_prec_round_val_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.25 1.6680)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().mul_prec_round_val_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.50 1.6660)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().mul_prec_round_val_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        mul_helper(&self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. The first [`Complex`] is taken
+    /// by reference and the second by value. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` part, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $xy$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_mul`. In particular:
+    /// - If one factor is infinite (that is, has an infinite part) and the other is neither zero
+    ///   nor `NaN`, the product is infinite, even if the exact formula would produce `NaN`s: for
+    ///   example, $(\infty+0i)(1+\infty i)$ has infinite parts.
+    /// - If one factor is infinite and the other is zero, both parts of the product are `NaN`.
+    /// - Otherwise, if either factor has a `NaN` part, both parts of the product are `NaN`, except
+    ///   that when one factor is real or imaginary (with a zero part) the product is computed part
+    ///   by part, as by [`Float`] multiplication.
+    /// - When one factor is real or imaginary, the signs of the zero parts of the product are those
+    ///   given by multiplying the parts separately. Otherwise, the signs of exact zero parts follow
+    ///   the rules for [`Float`] sums and differences.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::mul_prec_ref_val`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::mul_round_ref_val`]
+    /// instead. If both of these things are true, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the product can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.mul_prec_round_ref_val(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.25 1.6680)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.mul_prec_round_ref_val(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.50 1.6660)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.mul_prec_round_ref_val(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn mul_prec_round_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        mul_helper(self, &other, prec, rm)
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. Both [`Complex`]s are taken by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $xy$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_mul`. In particular:
+    /// - If one factor is infinite (that is, has an infinite part) and the other is neither zero
+    ///   nor `NaN`, the product is infinite, even if the exact formula would produce `NaN`s: for
+    ///   example, $(\infty+0i)(1+\infty i)$ has infinite parts.
+    /// - If one factor is infinite and the other is zero, both parts of the product are `NaN`.
+    /// - Otherwise, if either factor has a `NaN` part, both parts of the product are `NaN`, except
+    ///   that when one factor is real or imaginary (with a zero part) the product is computed part
+    ///   by part, as by [`Float`] multiplication.
+    /// - When one factor is real or imaginary, the signs of the zero parts of the product are those
+    ///   given by multiplying the parts separately. Otherwise, the signs of exact zero parts follow
+    ///   the rules for [`Float`] sums and differences.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::mul_prec_ref_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::mul_round_ref_ref`]
+    /// instead. If both of these things are true, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the product can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.mul_prec_round_ref_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.25 1.6680)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.mul_prec_round_ref_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.50 1.6660)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.mul_prec_round_ref_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        mul_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. Both [`Complex`]s are taken by value. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact product.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the product is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::mul_prec_round`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_prec_round`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the inputs'
+    /// parts, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().mul_prec(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(6.25 1.6660)");
+    /// assert_eq!(o, (Less, Less));
+    ///
+    /// let (z, o) = x.clone().mul_prec(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn mul_prec(self, other: Complex, prec: (u64, u64)) -> (Self, (Ordering, Ordering)) {
+        self.mul_prec_round(other, prec, (Nearest, Nearest))
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. The first [`Complex`] is taken by value and the
+    /// second by reference. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the product is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::mul_prec_round_val_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_prec_round_val_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().mul_prec_val_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(6.25 1.6660)");
+    /// assert_eq!(o, (Less, Less));
+    ///
+    /// let (z, o) = x.clone().mul_prec_val_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn mul_prec_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        self.mul_prec_round_val_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. The first [`Complex`] is taken by reference and
+    /// the second by value. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the product is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::mul_prec_round_ref_val`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_prec_round_ref_val`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.mul_prec_ref_val(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(6.25 1.6660)");
+    /// assert_eq!(o, (Less, Less));
+    ///
+    /// let (z, o) = x.mul_prec_ref_val(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.mul_prec_round_ref_val(other, prec, (Nearest, Nearest))
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. Both [`Complex`]s are taken by reference. A pair
+    /// of [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts
+    /// are less than, equal to, or greater than the real and imaginary parts of the exact product.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the product is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::mul_prec_round_ref_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_prec_round_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.mul_prec_ref_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(6.25 1.6660)");
+    /// assert_eq!(o, (Less, Less));
+    ///
+    /// let (z, o) = x.mul_prec_ref_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.mul_prec_round_ref_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. Both [`Complex`]s are taken by value. A pair of [`Ordering`]s is
+    /// also returned, indicating whether the rounded real and imaginary parts are less than, equal
+    /// to, or greater than the real and imaginary parts of the exact product. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::mul_prec_round`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::mul_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the product can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().mul_round(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.3270523235220857 1.6666449378555150)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().mul_round(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().mul_round(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn mul_round(
+        self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round(other, (prec, prec), rm)
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. The first [`Complex`] is taken by value and the second by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::mul_prec_round_val_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the product can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().mul_round_val_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.3270523235220857 1.6666449378555150)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().mul_round_val_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().mul_round_val_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn mul_round_val_ref(
+        self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_val_ref(other, (prec, prec), rm)
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. The first [`Complex`] is taken by reference and the second by
+    /// value. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::mul_prec_round_ref_val`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the product can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.mul_round_ref_val(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.3270523235220857 1.6666449378555150)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.mul_round_ref_val(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.mul_round_ref_val(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn mul_round_ref_val(
+        &self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_ref_val(other, (prec, prec), rm)
+    }
+
+    /// Multiplies two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. Both [`Complex`]s are taken by reference. A pair of [`Ordering`]s
+    /// is also returned, indicating whether the rounded real and imaginary parts are less than,
+    /// equal to, or greater than the real and imaginary parts of the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::mul_prec_round_ref_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the product can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.mul_round_ref_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(6.3270523235220857 1.6666449378555150)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.mul_round_ref_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.mul_round_ref_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn mul_round_ref_ref(
+        &self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_ref_ref(other, (prec, prec), rm)
+    }
+
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by value. A pair of [`Ordering`]s is returned,
+    /// indicating whether the rounded real and imaginary parts are less than, equal to, or greater
+    /// than the real and imaginary parts of the exact product. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::mul_prec_assign`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::mul_round_assign`]
+    /// instead. If both of these things are true, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the product can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_prec_round_assign(y.clone(), (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(6.25 1.6680)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(
+    ///     z.mul_prec_round_assign(y.clone(), (20, 20), (Nearest, Nearest)),
+    ///     (Less, Greater)
+    /// );
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn mul_prec_round_assign(
+        &mut self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.mul_prec_round_ref_ref(&other, prec, rm);
+        o
+    }
+
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by reference. A pair of [`Ordering`]s is
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact product. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::mul_prec_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the inputs' parts, consider using
+    /// [`Complex::mul_round_assign_ref`] instead. If both of these things are true, consider using
+    /// `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the product can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_prec_round_assign_ref(&y, (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(6.25 1.6680)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_prec_round_assign_ref(&y, (20, 20), (Nearest, Nearest)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.mul_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by value. A pair of [`Ordering`]s is returned, indicating whether
+    /// the rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the product is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_prec_round_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_prec_assign(y.clone(), (5, 10)), (Less, Less));
+    /// assert_eq!(z.to_string(), "(6.25 1.6660)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_prec_assign(y.clone(), (20, 20)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// ```
+    #[inline]
+    pub fn mul_prec_assign(&mut self, other: Complex, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.mul_prec_round_assign(other, prec, (Nearest, Nearest))
+    }
+
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by reference. A pair of [`Ordering`]s is returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets a part to `NaN`, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// If a part of the product is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using
+    /// [`Complex::mul_prec_round_assign_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the inputs' parts, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_prec_assign_ref(&y, (5, 10)), (Less, Less));
+    /// assert_eq!(z.to_string(), "(6.25 1.6660)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_prec_assign_ref(&y, (20, 20)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(6.3270493 1.6666451)");
+    /// ```
+    #[inline]
+    pub fn mul_prec_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Ordering, Ordering) {
+        self.mul_prec_round_assign_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by value. A pair of [`Ordering`]s is returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the product can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_round_assign(y.clone(), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(6.3270523235220857 1.6666449378555150)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_round_assign(y.clone(), (Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// ```
+    #[inline]
+    pub fn mul_round_assign(
+        &mut self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_assign(other, (prec, prec), rm)
+    }
+
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, rounding the real and imaginary parts of
+    /// the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by reference. A pair of [`Ordering`]s is returned, indicating whether the rounded real
+    /// and imaginary parts are less than, equal to, or greater than the real and imaginary parts of
+    /// the exact product. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the product can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_round_assign_ref(&y, (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(6.3270523235220857 1.6666449378555150)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.mul_round_assign_ref(&y, (Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// ```
+    #[inline]
+    pub fn mul_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_assign_ref(other, (prec, prec), rm)
+    }
+}
+
+impl Mul<Self> for Complex {
+    type Output = Self;
+
+    /// Multiplies two [`Complex`]s, taking both by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::mul_round`] with both rounding modes `Nearest`; see [`Complex::mul_prec_round`]
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_round`] instead. If
+    /// you want to specify the output precisions, consider using [`Complex::mul_prec`]. If you want
+    /// both of these things, consider using [`Complex::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((x.clone() * y.clone()).to_string(), "(6.3270523235220866 1.6666449378555148)");
+    ///
+    /// assert_eq!((Complex::I * Complex::I).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Mul<&Self> for Complex {
+    type Output = Self;
+
+    /// Multiplies two [`Complex`]s, taking the first by value and the second by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::mul_round_val_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_round_val_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_val_ref`]. If you want both of these things, consider using
+    /// [`Complex::mul_prec_round_val_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((x.clone() * &y).to_string(), "(6.3270523235220866 1.6666449378555148)");
+    ///
+    /// assert_eq!((Complex::I * Complex::I).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_val_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Mul<Complex> for &Complex {
+    type Output = Complex;
+
+    /// Multiplies two [`Complex`]s, taking the first by reference and the second by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::mul_round_ref_val`] with both rounding modes `Nearest`; see
+    /// [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_round_ref_val`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_ref_val`]. If you want both of these things, consider using
+    /// [`Complex::mul_prec_round_ref_val`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x * y.clone()).to_string(), "(6.3270523235220866 1.6666449378555148)");
+    ///
+    /// assert_eq!((Complex::I * Complex::I).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_ref_val(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Mul<&Complex> for &Complex {
+    type Output = Complex;
+
+    /// Multiplies two [`Complex`]s, taking both by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::mul_round_ref_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_round_ref_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_ref_ref`]. If you want both of these things, consider using
+    /// [`Complex::mul_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x * &y).to_string(), "(6.3270523235220866 1.6666449378555148)");
+    ///
+    /// assert_eq!((Complex::I * Complex::I).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn mul(self, other: &Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_ref_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl MulAssign<Self> for Complex {
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, taking the [`Complex`] on the right-hand
+    /// side by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::mul_round_assign`] with both rounding modes `Nearest`; see
+    /// [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_round_assign`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_assign`]. If you want both of these things, consider using
+    /// [`Complex::mul_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z *= y.clone();
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_assign(other, (prec, prec), (Nearest, Nearest));
+    }
+}
+
+impl MulAssign<&Self> for Complex {
+    /// Multiplies a [`Complex`] by a [`Complex`] in place, taking the [`Complex`] on the right-hand
+    /// side by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::mul_round_assign_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::mul_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::mul_round_assign_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::mul_prec_assign_ref`]. If you want both of these things, consider using
+    /// [`Complex::mul_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z *= &y;
+    /// assert_eq!(z.to_string(), "(6.3270523235220866 1.6666449378555148)");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.mul_prec_round_assign_ref(other, (prec, prec), (Nearest, Nearest));
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl Neg for Complex {
+    type Output = Self;
+
+    /// Negates a [`Complex`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// Both parts are negated, as by [`Float`](malachite_float::Float)'s [`Neg`], so the result
+    /// is exact, `NaN` parts stay `NaN`, and the signs of zero and infinite parts are flipped.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((-Complex::NAN).to_string(), "(NaN NaN)");
+    /// assert_eq!((-Complex::ZERO).to_string(), "(-0.0 -0.0)");
+    /// assert_eq!((-Complex::ONE).to_string(), "(-1.0 -0.0)");
+    /// assert_eq!(
+    ///     (-Complex::from((Float::from(1.5), Float::from(-2.5)))).to_string(),
+    ///     "(-1.5 2.5)"
+    /// );
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &Complex {
+    type Output = Complex;
+
+    /// Negates a [`Complex`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// Both parts are negated, as by [`Float`](malachite_float::Float)'s [`Neg`], so the result
+    /// is exact, `NaN` parts stay `NaN`, and the signs of zero and infinite parts are flipped.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((-&Complex::NAN).to_string(), "(NaN NaN)");
+    /// assert_eq!((-&Complex::ZERO).to_string(), "(-0.0 -0.0)");
+    /// assert_eq!((-&Complex::ONE).to_string(), "(-1.0 -0.0)");
+    /// assert_eq!(
+    ///     (-&Complex::from((Float::from(1.5), Float::from(-2.5)))).to_string(),
+    ///     "(-1.5 2.5)"
+    /// );
+    /// ```
+    #[inline]
+    fn neg(self) -> Complex {
+        Complex {
+            real: -&self.real,
+            imag: -&self.imag,
+        }
+    }
+}
+
+impl NegAssign for Complex {
+    /// Negates a [`Complex`] in place.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// Both parts are negated, as by [`Float`](malachite_float::Float)'s [`NegAssign`], so the
+    /// result is exact, `NaN` parts stay `NaN`, and the signs of zero and infinite parts are
+    /// flipped.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Complex::ZERO;
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "(-0.0 -0.0)");
+    ///
+    /// let mut x = Complex::ONE;
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "(-1.0 -0.0)");
+    ///
+    /// let mut x = Complex::from((Float::from(1.5), Float::from(-2.5)));
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "(-1.5 2.5)");
+    /// ```
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.real.neg_assign();
+        self.imag.neg_assign();
+    }
+}
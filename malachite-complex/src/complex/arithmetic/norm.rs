@@ -0,0 +1,436 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+// This is equivalent to `mpc_norm` from `norm.c`, MPC 1.4.1, except that the sum of squares is
+// computed with a single rounding.
+pub(crate) fn norm_helper(z: &Complex, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    if z.is_finite() {
+        z.real
+            .mul_add_mul_prec_round_ref_ref_ref_ref(&z.real, &z.imag, &z.imag, prec, rm)
+    } else {
+        // Infinities take precedence over NaNs, and the norm of an infinite `Complex` is infinity
+        z.real.hypot_prec_round_ref_ref(&z.imag, prec, rm)
+    }
+}
+
+impl Complex {
+    /// Computes the norm of a [`Complex`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Complex`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded norm is less than, equal to, or greater than the
+    /// exact norm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN`, it also returns `Equal`.
+    ///
+    /// The norm of $a+bi$ is the square of its absolute value, $a^2+b^2$. It is computed with a
+    /// single rounding.
+    ///
+    /// $$
+    /// f(x,p,m) = a^2+b^2+\varepsilon.
+    /// $$
+    /// - If $a^2+b^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $a^2+b^2$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 (a^2+b^2)\rfloor-p+1}$.
+    /// - If $a^2+b^2$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 (a^2+b^2)\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - If either part is infinite, the result is $\infty$, even if the other part is `NaN`.
+    /// - Otherwise, if either part is `NaN`, the result is `NaN`.
+    /// - If both parts are zero, the result is $0.0$.
+    ///
+    /// The result may overflow or underflow as the corresponding [`Float`] function would.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Complex::norm_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the input's parts,
+    /// consider using [`Complex::norm_round`] instead. If both of these things are true, consider
+    /// using [`Complex::norm`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the norm can't be represented exactly
+    /// with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().norm_prec_round(5, Floor);
+    /// assert_eq!(z.to_string(), "17.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.clone().norm_prec_round(5, Ceiling);
+    /// assert_eq!(z.to_string(), "18.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.clone().norm_prec_round(20, Nearest);
+    /// assert_eq!(z.to_string(), "17.258667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn norm_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.norm_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the norm of a [`Complex`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Complex`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded norm is less than, equal to, or greater than the
+    /// exact norm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN`, it also returns `Equal`.
+    ///
+    /// The norm of $a+bi$ is the square of its absolute value, $a^2+b^2$. It is computed with a
+    /// single rounding.
+    ///
+    /// $$
+    /// f(x,p,m) = a^2+b^2+\varepsilon.
+    /// $$
+    /// - If $a^2+b^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $a^2+b^2$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 (a^2+b^2)\rfloor-p+1}$.
+    /// - If $a^2+b^2$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 (a^2+b^2)\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - If either part is infinite, the result is $\infty$, even if the other part is `NaN`.
+    /// - Otherwise, if either part is `NaN`, the result is `NaN`.
+    /// - If both parts are zero, the result is $0.0$.
+    ///
+    /// The result may overflow or underflow as the corresponding [`Float`] function would.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Complex::norm_prec_ref`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the input's parts,
+    /// consider using [`Complex::norm_round_ref`] instead. If both of these things are true,
+    /// consider using [`Complex::norm_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the norm can't be represented exactly
+    /// with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.norm_prec_round_ref(5, Floor);
+    /// assert_eq!(z.to_string(), "17.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.norm_prec_round_ref(5, Ceiling);
+    /// assert_eq!(z.to_string(), "18.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.norm_prec_round_ref(20, Nearest);
+    /// assert_eq!(z.to_string(), "17.258667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn norm_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        norm_helper(self, prec, rm)
+    }
+
+    /// Computes the norm of a [`Complex`], rounding the result to the nearest value of the
+    /// specified precision. The [`Complex`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded norm is less than, equal to, or greater than the exact norm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`,
+    /// it also returns `Equal`.
+    ///
+    /// If the norm is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::norm_prec_round`] with `Nearest`; see its documentation for error bounds
+    /// and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using [`Complex::norm_prec_round`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::norm`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().norm_prec(5);
+    /// assert_eq!(z.to_string(), "17.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.clone().norm_prec(20);
+    /// assert_eq!(z.to_string(), "17.258667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn norm_prec(self, prec: u64) -> (Float, Ordering) {
+        self.norm_prec_round(prec, Nearest)
+    }
+
+    /// Computes the norm of a [`Complex`], rounding the result to the nearest value of the
+    /// specified precision. The [`Complex`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded norm is less than, equal to, or greater than the
+    /// exact norm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN`, it also returns `Equal`.
+    ///
+    /// If the norm is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::norm_prec_round_ref`] with `Nearest`; see its documentation for error
+    /// bounds and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using
+    /// [`Complex::norm_prec_round_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the input's parts, consider using [`Complex::norm_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.norm_prec_ref(5);
+    /// assert_eq!(z.to_string(), "17.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.norm_prec_ref(20);
+    /// assert_eq!(z.to_string(), "17.258667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn norm_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.norm_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the norm of a [`Complex`], rounding the result with the specified rounding mode.
+    /// The [`Complex`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded norm is less than, equal to, or greater than the exact norm. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN`, it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts. This is
+    /// [`Complex::norm_prec_round`] with that precision; see its documentation for error bounds and
+    /// special cases.
+    ///
+    /// If you want to specify the output precision, consider using [`Complex::norm_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Complex::norm`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the norm can't be represented exactly with the output
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().norm_round(Floor);
+    /// assert_eq!(z.to_string(), "17.258660500020007");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.clone().norm_round(Ceiling);
+    /// assert_eq!(z.to_string(), "17.258660500020010");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.clone().norm_round(Nearest);
+    /// assert_eq!(z.to_string(), "17.258660500020007");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn norm_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.max_prec();
+        self.norm_prec_round(prec, rm)
+    }
+
+    /// Computes the norm of a [`Complex`], rounding the result with the specified rounding mode.
+    /// The [`Complex`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded norm is less than, equal to, or greater than the exact norm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN`, it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts. This is
+    /// [`Complex::norm_prec_round_ref`] with that precision; see its documentation for error bounds
+    /// and special cases.
+    ///
+    /// If you want to specify the output precision, consider using [`Complex::norm_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Complex::norm_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the norm can't be represented exactly with the output
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.norm_round_ref(Floor);
+    /// assert_eq!(z.to_string(), "17.258660500020007");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = x.norm_round_ref(Ceiling);
+    /// assert_eq!(z.to_string(), "17.258660500020010");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (z, o) = x.norm_round_ref(Nearest);
+    /// assert_eq!(z.to_string(), "17.258660500020007");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn norm_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.max_prec();
+        self.norm_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the norm of a [`Complex`], taking it by value.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts, and the
+    /// result is rounded to the nearest value of that precision. This is [`Complex::norm_round`]
+    /// with `Nearest`; see [`Complex::norm_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using [`Complex::norm_round`]
+    /// instead. If you want to specify the output precision, consider using [`Complex::norm_prec`].
+    /// If you want both of these things, consider using [`Complex::norm_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!(x.clone().norm().to_string(), "17.258660500020007");
+    /// ```
+    #[inline]
+    pub fn norm(self) -> Float {
+        let prec = self.max_prec();
+        self.norm_prec_round(prec, Nearest).0
+    }
+
+    /// Computes the norm of a [`Complex`], taking it by reference.
+    ///
+    /// The precision of the output is the maximum of the precisions of the input's parts, and the
+    /// result is rounded to the nearest value of that precision. This is
+    /// [`Complex::norm_round_ref`] with `Nearest`; see [`Complex::norm_prec_round`] for error
+    /// bounds and special cases.
+    ///
+    /// If you want to use a different rounding mode, consider using [`Complex::norm_round_ref`]
+    /// instead. If you want to specify the output precision, consider using
+    /// [`Complex::norm_prec_ref`]. If you want both of these things, consider using
+    /// [`Complex::norm_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!(x.norm_ref().to_string(), "17.258660500020007");
+    /// ```
+    #[inline]
+    pub fn norm_ref(&self) -> Float {
+        let prec = self.max_prec();
+        self.norm_prec_round_ref(prec, Nearest).0
+    }
+}
@@ -0,0 +1,2061 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use crate::complex::arithmetic::exp::exp_helper;
+use crate::complex::arithmetic::ln::ln_helper;
+use crate::complex::arithmetic::mul::{mul_helper, signed_zero};
+use crate::complex::arithmetic::norm::norm_helper;
+use crate::complex::arithmetic::{check_exact, exact_to_down, shl_round_with_ordering};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, Parity, Pow, PowAssign, UnsignedAbs};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::comparison::traits::{EqAbs, PartialOrdAbs};
+use malachite_base::num::conversion::traits::{ExactFrom, IntegerMantissaAndExponent, IsInteger};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+
+// Returns $m$ and $e$ such that the nonzero finite `Float` `x` is equal to $m2^e$, with $m$ odd.
+fn odd_mantissa_and_exponent(x: &Float) -> (Integer, i64) {
+    let (m, e) = x.integer_mantissa_and_exponent();
+    let m = Integer::from(m);
+    (if *x < 0u32 { -m } else { m }, e)
+}
+
+// Removes the largest power of 2 dividing both `a` and `b`, which are not both zero, and returns
+// its exponent.
+fn remove_common_power_of_2(a: &mut Integer, b: &mut Integer) -> i64 {
+    let t = match (a.trailing_zeros(), b.trailing_zeros()) {
+        (Some(t), Some(u)) => t.min(u),
+        (Some(t), None) | (None, Some(t)) => t,
+        (None, None) => unreachable!(),
+    };
+    *a >>= t;
+    *b >>= t;
+    i64::exact_from(t)
+}
+
+// If c + d * i is an exact square (a + b * i) ^ 2, where a and b are integers, returns a + b * i,
+// with a >= 0, along with the signs of a and b (used in case they are zero). `sc` and `sd` are the
+// signs of c and d.
+//
+// This is equivalent to `mpc_perfect_square_p` from `pow.c`, MPC 1.4.1.
+fn perfect_square(c: &Integer, d: &Integer, sd: i8) -> Option<(Integer, Integer, i8)> {
+    if *d == 0u32 {
+        // Necessarily c < 0 here, since the case where x is real and non-negative and y is real has
+        // already been handled
+        let mut b = (-c).checked_sqrt()?;
+        if sd < 0 {
+            // sqrt(-b ^ 2 - 0 * i) = +0 - b * i
+            b = -b;
+        }
+        Some((Integer::ZERO, b, 1))
+    } else {
+        // d must be even
+        if d.odd() {
+            return None;
+        }
+        let a = (c * c + d * d).checked_sqrt()?;
+        // c + sqrt(c ^ 2 + d ^ 2)
+        let a = c + a;
+        if a.odd() {
+            return None;
+        }
+        let a = (a >> 1u32).checked_sqrt()?;
+        // The sign of b is that of d
+        let b = (d >> 1u32) / &a;
+        Some((a, b, 1))
+    }
+}
+
+enum PowExactError {
+    // x ^ y is not exactly representable
+    NotRepresentable,
+    // Intermediate computations led to numbers with more than `max_prec` bits
+    TooLarge,
+}
+
+// If x ^ y, where y is real, is exactly representable (possibly with a larger precision than the
+// target precision), rounds it and returns it. Assumes one of the parts of x is nonzero, and that y
+// is nonzero.
+//
+// This is equivalent to `mpc_pow_exact` from `pow.c`, MPC 1.4.1.
+fn pow_exact(
+    x: &Complex,
+    y: &Float,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+    max_prec: u64,
+) -> Result<(Complex, (Ordering, Ordering)), PowExactError> {
+    let x_imag = x.real.is_zero();
+    // y = my * 2 ^ ey, with my odd
+    let (mut my, mut ey) = odd_mantissa_and_exponent(y);
+    let (mut c, mut ec) = if x_imag {
+        (Integer::ZERO, 0)
+    } else {
+        odd_mantissa_and_exponent(&x.real)
+    };
+    let mut sc: i8 = if x.real.is_sign_negative() { -1 } else { 1 };
+    let mut d;
+    let ed;
+    if x.imag.is_zero() {
+        d = Integer::ZERO;
+        ed = ec;
+    } else {
+        (d, ed) = odd_mantissa_and_exponent(&x.imag);
+        if x_imag {
+            ec = ed;
+        }
+    }
+    let mut sd: i8 = if x.imag.is_sign_negative() { -1 } else { 1 };
+    // x = c * 2 ^ ec + d * 2 ^ ed * i, where sc and sd are the signs of c and d (only useful when c
+    // or d is zero). Equalize the exponents of x.
+    if ec < ed {
+        d <<= u64::exact_from(ed - ec);
+        if d.significant_bits() > max_prec {
+            return Err(PowExactError::TooLarge);
+        }
+    } else if ed < ec {
+        c <<= u64::exact_from(ec - ed);
+        if c.significant_bits() > max_prec {
+            return Err(PowExactError::TooLarge);
+        }
+        ec = ed;
+    }
+    // Now x = (c + d * i) * 2 ^ ec. Divide by 2 if possible.
+    ec += remove_common_power_of_2(&mut c, &mut d);
+    // Now one of c and d is odd
+    while ey < 0 {
+        // Check whether x is a square
+        if ec.odd() {
+            c <<= 1u32;
+            d <<= 1u32;
+            ec -= 1;
+        }
+        // Now ec is even
+        let Some((a, b, sa)) = perfect_square(&c, &d, sd) else {
+            break;
+        };
+        if b == 0u32 {
+            sd = if d < 0u32 || (d == 0u32 && sd < 0) {
+                -1
+            } else {
+                1
+            };
+        }
+        c = a;
+        d = b;
+        sc = sa;
+        ec >>= 1;
+        ey += 1;
+    }
+    if ey < 0 {
+        return Err(PowExactError::NotRepresentable);
+    }
+    // Now ey >= 0, so it suffices to check that x ^ my is representable. If my > 0, this is always
+    // true. If my < 0, first try to invert (c + d * i) * 2 ^ ec.
+    if my < 0u32 {
+        // If my < 0, 1 / (c + d * i) = (c - d * i) / (c ^ 2 + d ^ 2), and a necessary and
+        // sufficient condition for this to be exact is that c ^ 2 + d ^ 2 is a power of 2.
+        let a = &c * &c + &d * &d;
+        let t = a.trailing_zeros().unwrap();
+        if a.significant_bits() != t + 1 {
+            return Err(PowExactError::NotRepresentable);
+        }
+        // Replace (c, d) by (c / (c ^ 2 + d ^ 2), -d / (c ^ 2 + d ^ 2))
+        d = -d;
+        ec = -ec - i64::exact_from(t);
+        // Only the sign of d changes
+        sd = -sd;
+        my = -my;
+    }
+    // Now ey >= 0 and my >= 0, and we want to compute [(c + d * i) * 2 ^ ec] ^ (my * 2 ^ ey). We
+    // first compute [(c + d * i) * 2 ^ ec] ^ my, then square ey times.
+    let my = my.unsigned_abs();
+    let mut t = my.significant_bits() - 1;
+    let mut a = c.clone();
+    let mut sa = sc;
+    let mut b = d.clone();
+    let mut sb = sd;
+    let mut ed = ec;
+    // Invariant: (a + b * i) * 2 ^ ed = ((c + d * i) * 2 ^ ec) ^ floor(my / 2 ^ t)
+    while t > 0 {
+        t -= 1;
+        // Square a + b * i. The case a = 0 cannot happen when the initial x is real or imaginary,
+        // which is the only case where the sign of zeros matters.
+        let u = &a * &b;
+        a = &a * &a - &b * &b;
+        b = u << 1u32;
+        sb *= sa;
+        sa = if a >= 0u32 { 1 } else { -1 };
+        ed <<= 1;
+        if my.get_bit(t) {
+            // Multiply by c + d * i
+            let ta = sa * sc > 0 && sb * sd < 0;
+            let tb = sb * sc > 0 && sa * sd < 0;
+            let u = &a * &c - &b * &d;
+            b = &b * &c + &a * &d;
+            a = u;
+            sa = if a != 0u32 {
+                if a > 0u32 { 1 } else { -1 }
+            } else if ta {
+                1
+            } else {
+                -1
+            };
+            sb = if b != 0u32 {
+                if b > 0u32 { 1 } else { -1 }
+            } else if tb {
+                1
+            } else {
+                -1
+            };
+            ed += ec;
+        }
+        // Remove powers of 2 in (a, b)
+        ed += remove_common_power_of_2(&mut a, &mut b);
+        if a.significant_bits() > max_prec || b.significant_bits() > max_prec {
+            return Err(PowExactError::TooLarge);
+        }
+    }
+    // Now a + b * i = (c + d * i) ^ my
+    while ey > 0 {
+        ey -= 1;
+        // Square a + b * i. Again, a cannot be zero here when the initial x is real or imaginary.
+        let u = &a * &b;
+        a = &a * &a - &b * &b;
+        b = u << 1u32;
+        sb *= sa;
+        sa = if a >= 0u32 { 1 } else { -1 };
+        ed <<= 1;
+        // Divide by the largest possible power of 2, to avoid many loops for, e.g., (2 + 2 * i) ^
+        // 16777216
+        ed += remove_common_power_of_2(&mut a, &mut b);
+        if a.significant_bits() > max_prec || b.significant_bits() > max_prec {
+            return Err(PowExactError::TooLarge);
+        }
+    }
+    let a_is_zero = a == 0u32;
+    let b_is_zero = b == 0u32;
+    let (mut real, o_r) = Float::from_integer_prec_round(a, prec.0, rm.0);
+    if a_is_zero && sa < 0 {
+        real = signed_zero(true);
+    }
+    let (mut imag, o_i) = Float::from_integer_prec_round(b, prec.1, rm.1);
+    if b_is_zero && sb < 0 {
+        imag = signed_zero(true);
+    }
+    let (real, o_r) = shl_round_with_ordering(real, o_r, ed, prec.0, rm.0);
+    let (imag, o_i) = shl_round_with_ordering(imag, o_i, ed, prec.1, rm.1);
+    Ok((Complex { real, imag }, (o_r, o_i)))
+}
+
+// Whether y * 2 ^ k is an odd integer.
+//
+// This is equivalent to `is_odd` from `pow.c`, MPC 1.4.1.
+fn is_odd(y: &Float, k: i64) -> bool {
+    y.is_finite() && !y.is_zero() && y.integer_mantissa_and_exponent().1 + k == 0
+}
+
+// Returns y mod 4 if the real number y is an integer, following MPC's conventions for negative y.
+fn mod_4(y: &Float) -> Option<u8> {
+    if !y.is_finite() || y.is_zero() {
+        return None;
+    }
+    let (my, ey) = odd_mantissa_and_exponent(y);
+    match ey {
+        e if e >= 2 => Some(0),
+        1 => Some(2),
+        0 => {
+            let r = u8::from(my.get_bit(1)) * 2 + u8::from(my.get_bit(0));
+            Some(if my < 0u32 { 4 - r } else { r })
+        }
+        _ => None,
+    }
+}
+
+// Fixes the sign of the real or imaginary part of z in case it is zero, and the real part of x is
+// zero. `sign_eps` is the sign bit of the real part of x, and `sign_a` is the sign bit of the
+// imaginary part of x. Assumes y is an integer (does nothing otherwise).
+//
+// This is equivalent to `fix_sign` from `pow.c`, MPC 1.4.1.
+fn fix_sign(z: &mut Complex, sign_eps: bool, sign_a: bool, y: &Float) {
+    let Some(ymod4) = mod_4(y) else {
+        return;
+    };
+    if z.real.is_zero() {
+        // (eps + a * i) ^ y = +0 + a ^ y * i for y = 1 mod 4 and sign_eps = 0, and
+        // (eps + a * i) ^ y = -0 - a ^ y * i for y = 3 mod 4 and sign_eps = 0
+        if (ymod4 == 3 && !sign_eps) || (ymod4 == 1 && sign_eps) {
+            z.real = -&z.real;
+        }
+    } else if z.imag.is_zero() {
+        // (eps + a * i) ^ y = a ^ y - 0 * i for y = 0 mod 4 and sign_a = sign_eps, and
+        // (eps + a * i) ^ y = -a ^ y + 0 * i for y = 2 mod 4 and sign_a = sign_eps
+        if (ymod4 == 0 && sign_a == sign_eps) || (ymod4 == 2 && sign_a != sign_eps) {
+            z.imag = -&z.imag;
+        }
+    }
+}
+
+// Fixes the sign of the imaginary part of z when both x and y are real. `s1`, `s2`, `s3`, and `s4`
+// are the sign bits of the real part of x, the imaginary part of x, the real part of y, and the
+// imaginary part of y; `cmp` is the comparison of |Re(x)| with 1; and `even` is whether Re(y) is
+// even (only used when Re(x) < 0).
+//
+// This is equivalent to `fix_sign_real` from `pow.c`, MPC 1.4.1.
+fn fix_sign_real(
+    z: &mut Complex,
+    s1: bool,
+    s2: bool,
+    s3: bool,
+    s4: bool,
+    cmp: Ordering,
+    even: bool,
+) {
+    let s = if !s1 {
+        if s2 == s3 {
+            false
+        } else if s4 {
+            cmp != Less
+        } else {
+            cmp != Greater
+        }
+    } else if even {
+        if s4 { cmp != Less } else { cmp != Greater }
+    } else if s4 {
+        cmp != Greater
+    } else {
+        cmp != Less
+    };
+    z.imag = signed_zero(s);
+}
+
+// Compares |x| with 1, using a 53-bit approximation of the norm of x, as MPC does.
+fn cmp_abs_1(x: &Complex) -> Ordering {
+    let (n, o) = norm_helper(x, 53, Nearest);
+    match n.partial_cmp(&1u32) {
+        Some(Equal) => o.reverse(),
+        Some(c) => c,
+        None => Equal,
+    }
+}
+
+// The sign of the zero imaginary part of x ^ y when it's known to be real, when x is not
+// ramified: +sign(Im(y)) * 0 if |x| > 1, +sign(Im(x)) * sign(Re(y)) * 0 if |x| = 1, and
+// -sign(Im(y)) * 0 if |x| < 1.
+fn zero_imag_sign(x: &Complex, y: &Complex) -> bool {
+    match cmp_abs_1(x) {
+        Less => !y.imag.is_sign_negative(),
+        Equal => x.imag.is_sign_negative() != y.real.is_sign_negative(),
+        Greater => y.imag.is_sign_negative(),
+    }
+}
+
+// exp(y * ln(x)), with the intermediate values computed with precision 2.
+fn pow_special(
+    x: &Complex,
+    y: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    let u = ln_helper(x, (2, 2), (Nearest, Nearest)).0;
+    let u = mul_helper(&u, y, (2, 2), (Nearest, Nearest)).0;
+    let (z, o, _) = exp_helper(&u, prec, rm);
+    (z, o)
+}
+
+fn exponent_or_min(x: &Float) -> i64 {
+    x.get_exponent().map_or(i64::MIN, i64::from)
+}
+
+// This is equivalent to `mpc_pow` from `pow.c`, MPC 1.4.1.
+pub(crate) fn pow_helper(
+    x: &Complex,
+    y: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    assert_ne!(prec.0, 0);
+    assert_ne!(prec.1, 0);
+    let x_real = x.imag.is_zero();
+    let y_real = y.imag.is_zero();
+    if y_real && y.real.is_zero() {
+        // y is zero
+        return if x_real && x.real.is_zero() {
+            // 0 ^ 0 is defined to be (1, +0), since the real part is coherent with MPFR, where
+            // 0 ^ 0 gives 1, and the sign of the imaginary part cannot be determined
+            (
+                Complex {
+                    real: Float::one_prec(prec.0),
+                    imag: Float::ZERO,
+                },
+                (Equal, Equal),
+            )
+        } else {
+            // x ^ 0 = 1 ± 0 * i, even for x = NaN
+            let negative = rm.1 == Floor || zero_imag_sign(x, y);
+            (
+                Complex {
+                    real: Float::one_prec(prec.0),
+                    imag: signed_zero(negative),
+                },
+                (Equal, Equal),
+            )
+        };
+    }
+    if !x.is_finite() || !y.is_finite() {
+        return pow_special(x, y, prec, rm);
+    }
+    let mut z_real = false;
+    let mut z_imag = false;
+    let mut ramified = false;
+    if x_real {
+        if x.real.is_zero() {
+            return pow_special(x, y, prec, rm);
+        }
+        // Special case: 1 ^ y = 1
+        if x.real == 1u32 {
+            // The sign of the zero imaginary part is known in some cases: (x + s * 0 * i) ^ (y ± 0
+            // * i) = x ^ y + s * sign(y) * 0 * i, where s = ±1. This rule is extended here to fix
+            // the sign of the zero part.
+            let negative = rm.1 == Floor || y.real.is_sign_negative() != x.imag.is_sign_negative();
+            return (
+                Complex {
+                    real: Float::one_prec(prec.0),
+                    imag: signed_zero(negative),
+                },
+                (Equal, Equal),
+            );
+        }
+        // x ^ y is real when x is real and y is an integer, or when x is real and non-negative and
+        // y is real
+        let s1 = x.real.is_sign_negative();
+        if y_real && ((&y.real).is_integer() || !s1) {
+            let even = s1 && !is_odd(&y.real, 0);
+            let cmp = x.real.partial_cmp_abs(&1u32).unwrap();
+            let (real, o) = x.real.pow_prec_round_ref_ref(&y.real, prec.0, rm.0);
+            let mut z = Complex {
+                real,
+                imag: Float::ZERO,
+            };
+            fix_sign_real(
+                &mut z,
+                s1,
+                x.imag.is_sign_negative(),
+                y.real.is_sign_negative(),
+                y.imag.is_sign_negative(),
+                cmp,
+                even,
+            );
+            return (z, (o, Equal));
+        }
+        // (-1) ^ (n + t * i) is real for n an integer and t real
+        if x.real == -1i32 && (&y.real).is_integer() {
+            z_real = true;
+        }
+        // For x real, x ^ y is imaginary when x is negative and y is half an integer, or when x =
+        // -1 and Re(y) is half an integer
+        if x.real < 0u32 && is_odd(&y.real, 1) && (y_real || x.real == -1i32) {
+            z_imag = true;
+        }
+    } else if (x.real.is_zero() && (x.imag == 1u32 || x.imag == -1i32 || y_real))
+        && (&y.real).is_integer()
+    {
+        // i ^ (t * i) and (-i) ^ (t * i) are real for t real, i ^ (n + t * i) and (-i) ^ (n + t *
+        // i) are real for n even and t real, and i ^ (n + t * i) and (-i) ^ (n + t * i) are
+        // imaginary for n odd and t real; (s * i) ^ n is real for n even and imaginary for n odd.
+        if is_odd(&y.real, 0) {
+            z_imag = true;
+        } else {
+            z_real = true;
+        }
+    } else if x.real.eq_abs(&x.imag) && y_real && (&y.real).is_integer() && !is_odd(&y.real, 0) {
+        // (t ± t * i) ^ (2 * n) is imaginary for n odd and real for n even
+        ramified = true;
+        if is_odd(&y.real, -1) {
+            z_imag = true;
+        } else {
+            z_real = true;
+        }
+    }
+    let rm_re = exact_to_down(rm.0);
+    let rm_im = exact_to_down(rm.1);
+    let pr = prec.0 + u64::from(rm_re == Nearest || rm_re == NearestTiesAway);
+    let pi = prec.1 + u64::from(rm_im == Nearest || rm_im == NearestTiesAway);
+    // Experimentally, adding 12 bits seems to give less than 10% of failures in Ziv's strategy
+    let mut p = max(max(prec.0, prec.1) + 12, 64);
+    let mut max_prec = max(prec.0, prec.1);
+    let x_imag = x.real.is_zero();
+    // Set once `pow_exact` has shown that the result is not exactly representable
+    let mut known_not_exact = false;
+    let u = loop {
+        let t = ln_helper(x, (p, p), (Nearest, Nearest)).0;
+        let t = mul_helper(&t, y, (p, p), (Nearest, Nearest)).0;
+        // Compute q such that |Re(y ln(x))|, |Im(y ln(x))| < 2 ^ q. It is recomputed in each loop,
+        // since different bounds might be obtained if the precision is not high enough.
+        let q = max(max(exponent_or_min(&t.real), exponent_or_min(&t.imag)), 0);
+        let q = u64::exact_from(q);
+        // The signs of the real and imaginary parts of exp(t) are determined by the quadrant of
+        // exp(i * Im(t)), which depends on Im(t) mod 2π. Ensure that p >= q + 64 to get enough
+        // precision, although this might not be enough in corner cases.
+        if p < q + 64 {
+            p = q + 64;
+            continue;
+        }
+        let (u, _, out_of_range) = exp_helper(&t, (p, p), (Nearest, Nearest));
+        if out_of_range {
+            // Redo the exponential with the target precision and rounding modes, so that the parts
+            // that overflow or underflow get the appropriate values
+            let (mut z, (o_r, mut o_i), _) = exp_helper(&t, prec, rm);
+            if z_real && z.imag.is_infinite() {
+                z.imag = Float::ZERO;
+                o_i = Equal;
+            }
+            return (z, (o_r, o_i));
+        }
+        // Since the error bound is global, the exponent difference between the real and imaginary
+        // parts has to be taken into account. Either the real or the imaginary part of u is
+        // assumed to be nonzero.
+        let mut dr = if u.real.is_zero() {
+            exponent_or_min(&u.imag)
+        } else {
+            exponent_or_min(&u.real)
+        };
+        let mut di = if u.imag.is_zero() {
+            dr
+        } else {
+            exponent_or_min(&u.imag)
+        };
+        if dr > di {
+            di = dr - di;
+            dr = 0;
+        } else {
+            dr = di - dr;
+            di = 0;
+        }
+        let dr = u64::exact_from(dr);
+        let di = u64::exact_from(di);
+        // The term -3 takes into account the factor 4 in the complex error, plus one due to the
+        // exponent difference: if z = a + b * i, where the relative error on z is at most 2 ^ -p,
+        // and EXP(a) = EXP(b) + k, the relative error on b is at most 2 ^ (k - p).
+        if (z_imag
+            || (p > q + 3 + dr
+                && u.real
+                    .can_round(i64::exact_from(p - q - 3 - dr), Nearest, Down, pr)))
+            && (z_real
+                || (p > q + 3 + di
+                    && u.imag
+                        .can_round(i64::exact_from(p - q - 3 - di), Nearest, Down, pi)))
+        {
+            break u;
+        }
+        if known_not_exact {
+            p += p >> 1;
+        } else {
+            // Check the exact cases
+            if y_real {
+                max_prec <<= 1;
+                match pow_exact(x, &y.real, prec, rm, max_prec) {
+                    Ok(result) => return result,
+                    Err(PowExactError::NotRepresentable) => known_not_exact = true,
+                    Err(PowExactError::TooLarge) => {}
+                }
+            }
+            p += dr + di + 64;
+        }
+    };
+    let (z, o) = if z_real {
+        // When the result is real and x = x_1 * (1 ± i), with y a positive integer divisible by 4,
+        // the sign of the zero imaginary part cannot be determined, and is chosen to be +.
+        // Otherwise, it is given by `zero_imag_sign`.
+        let (real, o_r) = Float::from_float_prec_round(u.real, prec.0, rm_re);
+        if ramified {
+            (
+                Complex {
+                    real,
+                    imag: Float::ZERO,
+                },
+                (o_r, Equal),
+            )
+        } else if y_real && (x_real || x_imag) {
+            // Here it is assumed that the imaginary part of y is really 0; if it's +0 or -0,
+            // different results might be obtained
+            let mut z = Complex {
+                real,
+                imag: Float::ZERO,
+            };
+            fix_sign(
+                &mut z,
+                x.real.is_sign_negative(),
+                x.imag.is_sign_negative(),
+                &y.real,
+            );
+            (z, (o_r, Equal))
+        } else {
+            let negative = rm.1 == Floor || zero_imag_sign(x, y);
+            (
+                Complex {
+                    real,
+                    imag: signed_zero(negative),
+                },
+                (o_r, Equal),
+            )
+        }
+    } else if z_imag {
+        let (imag, o_i) = Float::from_float_prec_round(u.imag, prec.1, rm_im);
+        let mut z = Complex {
+            real: Float::ZERO,
+            imag,
+        };
+        if !ramified && y_real && x_imag {
+            // If z is imaginary and y is real, then x cannot be real. `fix_sign` only does
+            // something when y is an integer, and then necessarily y = 1 or 3 (mod 4), and in that
+            // case the sign of Im(x) is irrelevant.
+            fix_sign(&mut z, x.real.is_sign_negative(), false, &y.real);
+        }
+        (z, (Equal, o_i))
+    } else {
+        let (real, o_r) = Float::from_float_prec_round(u.real, prec.0, rm_re);
+        let (imag, o_i) = Float::from_float_prec_round(u.imag, prec.1, rm_im);
+        (Complex { real, imag }, (o_r, o_i))
+    };
+    check_exact(rm.0, o.0, "power");
+    check_exact(rm.1, o.1, "power");
+    (z, o)
+}
+
+impl Complex {
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. Both [`Complex`]s
+    /// are taken by value. A pair of [`Ordering`]s is also returned, indicating whether the rounded
+    /// real and imaginary parts are less than, equal to, or greater than the real and imaginary
+    /// parts of the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x^y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The power is defined as $x^y = e^{y\log x}$, using the principal branch of the logarithm
+    /// (see [`Complex::ln_prec_round`]). The special values follow MPC's `mpc_pow`. In particular:
+    /// - $x^0 = 1+0i$ for every $x$, including `NaN`, and $0^y = 0$ when the real part of $y$ is
+    ///   positive.
+    /// - If $x$ is real and nonnegative and $y$ is real, or if $x$ is real and $y$ is an integer,
+    ///   the real part of the result is the [`Float`] power of the real parts, and the imaginary
+    ///   part is zero.
+    /// - When the result is known to be real or imaginary (for example, $(-1)^{1/2} = i$ or $i^2 =
+    ///   -1$), the other part is an exact zero.
+    /// - Exactly representable powers, such as $(1+i)^4 = -4$ or $(-4)^{1/2} = 2i$, are detected
+    ///   and returned exactly.
+    /// - Otherwise, if either input has a `NaN` or infinite part, the result is computed as by
+    ///   $e^{y\log x}$ with low-precision intermediate values, so that the special values of
+    ///   [`Complex::exp_prec_round`] and [`Complex::ln_prec_round`] apply.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using [`Complex::pow_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::pow_round`] instead. If both of these things are
+    /// true, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the power can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().pow_prec_round(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.0 0.26562)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().pow_prec_round(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().pow_prec_round(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn pow_prec_round(
+        self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        pow_helper(&self, &other, prec, rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The first
+    /// [`Complex`] is taken by value and the second by reference. A pair of [`Ordering`]s is also
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact power. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` part, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x^y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The power is defined as $x^y = e^{y\log x}$, using the principal branch of the logarithm
+    /// (see [`Complex::ln_prec_round`]). The special values follow MPC's `mpc_pow`. In particular:
+    /// - $x^0 = 1+0i$ for every $x$, including `NaN`, and $0^y = 0$ when the real part of $y$ is
+    ///   positive.
+    /// - If $x$ is real and nonnegative and $y$ is real, or if $x$ is real and $y$ is an integer,
+    ///   the real part of the result is the [`Float`] power of the real parts, and the imaginary
+    ///   part is zero.
+    /// - When the result is known to be real or imaginary (for example, $(-1)^{1/2} = i$ or $i^2 =
+    ///   -1$), the other part is an exact zero.
+    /// - Exactly representable powers, such as $(1+i)^4 = -4$ or $(-4)^{1/2} = 2i$, are detected
+    ///   and returned exactly.
+    /// - Otherwise, if either input has a `NaN` or infinite part, the result is computed as by
+    ///   $e^{y\log x}$ with low-precision intermediate values, so that the special values of
+    ///   [`Complex::exp_prec_round`] and [`Complex::ln_prec_round`] apply.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::pow_prec_val_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::pow_round_val_ref`]
+    /// instead. If both of these things are true, consider using [`Complex::pow`](Pow::pow)
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the power can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().pow_prec_round_val_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.0 0.26562)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().pow_prec_round_val_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().pow_prec_round_val_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn pow_prec_round_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        pow_helper(&self, other, prec, rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The first
+    /// [`Complex`] is taken by reference and the second by value. A pair of [`Ordering`]s is also
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact power. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` part, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x^y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The power is defined as $x^y = e^{y\log x}$, using the principal branch of the logarithm
+    /// (see [`Complex::ln_prec_round`]). The special values follow MPC's `mpc_pow`. In particular:
+    /// - $x^0 = 1+0i$ for every $x$, including `NaN`, and $0^y = 0$ when the real part of $y$ is
+    ///   positive.
+    /// - If $x$ is real and nonnegative and $y$ is real, or if $x$ is real and $y$ is an integer,
+    ///   the real part of the result is the [`Float`] power of the real parts, and the imaginary
+    ///   part is zero.
+    /// - When the result is known to be real or imaginary (for example, $(-1)^{1/2} = i$ or $i^2 =
+    ///   -1$), the other part is an exact zero.
+    /// - Exactly representable powers, such as $(1+i)^4 = -4$ or $(-4)^{1/2} = 2i$, are detected
+    ///   and returned exactly.
+    /// - Otherwise, if either input has a `NaN` or infinite part, the result is computed as by
+    ///   $e^{y\log x}$ with low-precision intermediate values, so that the special values of
+    ///   [`Complex::exp_prec_round`] and [`Complex::ln_prec_round`] apply.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::pow_prec_ref_val`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::pow_round_ref_val`]
+    /// instead. If both of these things are true, consider using [`Complex::pow`](Pow::pow)
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the power can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.pow_prec_round_ref_val(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.0 0.26562)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.pow_prec_round_ref_val(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.pow_prec_round_ref_val(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn pow_prec_round_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        pow_helper(self, &other, prec, rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. Both [`Complex`]s
+    /// are taken by reference. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x^y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The power is defined as $x^y = e^{y\log x}$, using the principal branch of the logarithm
+    /// (see [`Complex::ln_prec_round`]). The special values follow MPC's `mpc_pow`. In particular:
+    /// - $x^0 = 1+0i$ for every $x$, including `NaN`, and $0^y = 0$ when the real part of $y$ is
+    ///   positive.
+    /// - If $x$ is real and nonnegative and $y$ is real, or if $x$ is real and $y$ is an integer,
+    ///   the real part of the result is the [`Float`] power of the real parts, and the imaginary
+    ///   part is zero.
+    /// - When the result is known to be real or imaginary (for example, $(-1)^{1/2} = i$ or $i^2 =
+    ///   -1$), the other part is an exact zero.
+    /// - Exactly representable powers, such as $(1+i)^4 = -4$ or $(-4)^{1/2} = 2i$, are detected
+    ///   and returned exactly.
+    /// - Otherwise, if either input has a `NaN` or infinite part, the result is computed as by
+    ///   $e^{y\log x}$ with low-precision intermediate values, so that the special values of
+    ///   [`Complex::exp_prec_round`] and [`Complex::ln_prec_round`] apply.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`] function would round it: to infinity or to the largest finite [`Float`] of the
+    /// part's precision, or to zero or to the smallest positive [`Float`] of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::pow_prec_ref_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::pow_round_ref_ref`]
+    /// instead. If both of these things are true, consider using [`Complex::pow`](Pow::pow)
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the power can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.pow_prec_round_ref_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.0 0.26562)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.pow_prec_round_ref_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.pow_prec_round_ref_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn pow_prec_round_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        pow_helper(self, other, prec, rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. Both [`Complex`]s are taken by
+    /// value. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the power is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::pow_prec_round`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_prec_round`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the inputs'
+    /// parts, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().pow_prec(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().pow_prec(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn pow_prec(self, other: Complex, prec: (u64, u64)) -> (Self, (Ordering, Ordering)) {
+        self.pow_prec_round(other, prec, (Nearest, Nearest))
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The first [`Complex`] is taken by
+    /// value and the second by reference. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` part, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// If a part of the power is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::pow_prec_round_val_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_prec_round_val_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().pow_prec_val_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().pow_prec_val_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn pow_prec_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        self.pow_prec_round_val_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The first [`Complex`] is taken by
+    /// reference and the second by value. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` part, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// If a part of the power is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::pow_prec_round_ref_val`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_prec_round_ref_val`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.pow_prec_ref_val(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.pow_prec_ref_val(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn pow_prec_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.pow_prec_round_ref_val(other, prec, (Nearest, Nearest))
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. Both [`Complex`]s are taken by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the power is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::pow_prec_round_ref_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_prec_round_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.pow_prec_ref_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.pow_prec_ref_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn pow_prec_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.pow_prec_round_ref_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. Both [`Complex`]s are taken by value. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact power.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::pow_prec_round`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::pow_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the power can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().pow_round(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052084)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().pow_round(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.283296913353336 0.26519133170052078)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().pow_round(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn pow_round(
+        self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round(other, (prec, prec), rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The first [`Complex`] is taken by value and the
+    /// second by reference. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::pow_prec_round_val_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the power can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().pow_round_val_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052084)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().pow_round_val_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.283296913353336 0.26519133170052078)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().pow_round_val_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn pow_round_val_ref(
+        self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_val_ref(other, (prec, prec), rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The first [`Complex`] is taken by reference and
+    /// the second by value. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::pow_prec_round_ref_val`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the power can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.pow_round_ref_val(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052084)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.pow_round_ref_val(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.283296913353336 0.26519133170052078)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.pow_round_ref_val(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn pow_round_ref_val(
+        &self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_ref_val(other, (prec, prec), rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power, rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. Both [`Complex`]s are taken by reference. A pair
+    /// of [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts
+    /// are less than, equal to, or greater than the real and imaginary parts of the exact power.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::pow_prec_round_ref_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the power can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.pow_round_ref_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052084)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.pow_round_ref_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(12.283296913353336 0.26519133170052078)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.pow_round_ref_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// assert_eq!(o, (Less, Less));
+    /// ```
+    #[inline]
+    pub fn pow_round_ref_ref(
+        &self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_ref_ref(other, (prec, prec), rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power in place, rounding the real and imaginary parts
+    /// of the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by value. A pair of [`Ordering`]s is returned,
+    /// indicating whether the rounded real and imaginary parts are less than, equal to, or greater
+    /// than the real and imaginary parts of the exact power. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets a part to `NaN`, the corresponding [`Ordering`]
+    /// is `Equal`.
+    ///
+    /// See [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::pow_prec_assign`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::pow_round_assign`]
+    /// instead. If both of these things are true, consider using
+    /// [`Complex::pow_assign`](PowAssign::pow_assign) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the power can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_prec_round_assign(y.clone(), (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(12.0 0.26562)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(
+    ///     z.pow_prec_round_assign(y.clone(), (20, 20), (Nearest, Nearest)),
+    ///     (Less, Greater)
+    /// );
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn pow_prec_round_assign(
+        &mut self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.pow_prec_round_ref_ref(&other, prec, rm);
+        o
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power in place, rounding the real and imaginary parts
+    /// of the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by reference. A pair of [`Ordering`]s is
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact power. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::pow_prec_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the inputs' parts, consider using
+    /// [`Complex::pow_round_assign_ref`] instead. If both of these things are true, consider using
+    /// [`Complex::pow_assign`](PowAssign::pow_assign) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the power can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_prec_round_assign_ref(&y, (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(12.0 0.26562)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_prec_round_assign_ref(&y, (20, 20), (Nearest, Nearest)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// ```
+    #[inline]
+    pub fn pow_prec_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.pow_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power in place, rounding the real and imaginary parts
+    /// of the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by value. A pair of [`Ordering`]s is returned, indicating whether
+    /// the rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the power is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_prec_round_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::pow_assign`](PowAssign::pow_assign) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_prec_assign(y.clone(), (5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_prec_assign(y.clone(), (20, 20)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// ```
+    #[inline]
+    pub fn pow_prec_assign(&mut self, other: Complex, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.pow_prec_round_assign(other, prec, (Nearest, Nearest))
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power in place, rounding the real and imaginary parts
+    /// of the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by reference. A pair of [`Ordering`]s is returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets a part to `NaN`, the corresponding [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// If a part of the power is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using
+    /// [`Complex::pow_prec_round_assign_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the inputs' parts, consider using
+    /// [`Complex::pow_assign`](PowAssign::pow_assign) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_prec_assign_ref(&y, (5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(12.5 0.26514)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_prec_assign_ref(&y, (20, 20)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(12.283295 0.26519156)");
+    /// ```
+    #[inline]
+    pub fn pow_prec_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Ordering, Ordering) {
+        self.pow_prec_round_assign_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power in place, rounding the real and imaginary parts
+    /// of the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by value. A pair of [`Ordering`]s is returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::pow_assign`](PowAssign::pow_assign)
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the power can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_round_assign(y.clone(), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052084)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_round_assign(y.clone(), (Nearest, Nearest)), (Less, Less));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// ```
+    #[inline]
+    pub fn pow_round_assign(
+        &mut self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_assign(other, (prec, prec), rm)
+    }
+
+    /// Raises a [`Complex`] to a [`Complex`] power in place, rounding the real and imaginary parts
+    /// of the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by reference. A pair of [`Ordering`]s is returned, indicating whether the rounded real
+    /// and imaginary parts are less than, equal to, or greater than the real and imaginary parts of
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets a part to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::pow_assign`](PowAssign::pow_assign)
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the power can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_round_assign_ref(&y, (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052084)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.pow_round_assign_ref(&y, (Nearest, Nearest)), (Less, Less));
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// ```
+    #[inline]
+    pub fn pow_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_assign_ref(other, (prec, prec), rm)
+    }
+}
+
+impl Pow<Self> for Complex {
+    type Output = Self;
+
+    /// Raises a [`Complex`] to a [`Complex`] power, taking both by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::pow_round`] with both rounding modes `Nearest`; see [`Complex::pow_prec_round`]
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_round`] instead. If
+    /// you want to specify the output precisions, consider using [`Complex::pow_prec`]. If you want
+    /// both of these things, consider using [`Complex::pow_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Two;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!(
+    ///     x.clone().pow(y.clone()).to_string(),
+    ///     "(12.283296913353334 0.26519133170052078)"
+    /// );
+    ///
+    /// assert_eq!(Complex::I.pow(Complex::TWO).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn pow(self, other: Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Pow<&Self> for Complex {
+    type Output = Self;
+
+    /// Raises a [`Complex`] to a [`Complex`] power, taking the first by value and the second by
+    /// reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::pow_round_val_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_round_val_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_val_ref`]. If you want both of these things, consider using
+    /// [`Complex::pow_prec_round_val_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Two;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!(x.clone().pow(&y).to_string(), "(12.283296913353334 0.26519133170052078)");
+    ///
+    /// assert_eq!(Complex::I.pow(Complex::TWO).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn pow(self, other: &Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_val_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Pow<Complex> for &Complex {
+    type Output = Complex;
+
+    /// Raises a [`Complex`] to a [`Complex`] power, taking the first by reference and the second by
+    /// value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::pow_round_ref_val`] with both rounding modes `Nearest`; see
+    /// [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_round_ref_val`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_ref_val`]. If you want both of these things, consider using
+    /// [`Complex::pow_prec_round_ref_val`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Two;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x).pow(y.clone()).to_string(), "(12.283296913353334 0.26519133170052078)");
+    ///
+    /// assert_eq!(Complex::I.pow(Complex::TWO).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn pow(self, other: Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_ref_val(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Pow<&Complex> for &Complex {
+    type Output = Complex;
+
+    /// Raises a [`Complex`] to a [`Complex`] power, taking both by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::pow_round_ref_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_round_ref_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_ref_ref`]. If you want both of these things, consider using
+    /// [`Complex::pow_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Two;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x).pow(&y).to_string(), "(12.283296913353334 0.26519133170052078)");
+    ///
+    /// assert_eq!(Complex::I.pow(Complex::TWO).to_string(), "(-1.0 0.0)");
+    /// ```
+    #[inline]
+    fn pow(self, other: &Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_ref_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl PowAssign<Self> for Complex {
+    /// Raises a [`Complex`] to a [`Complex`] power in place, taking the [`Complex`] on the
+    /// right-hand side by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::pow_round_assign`] with both rounding modes `Nearest`; see
+    /// [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_round_assign`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_assign`]. If you want both of these things, consider using
+    /// [`Complex::pow_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z.pow_assign(y.clone());
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, other: Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_assign(other, (prec, prec), (Nearest, Nearest));
+    }
+}
+
+impl PowAssign<&Self> for Complex {
+    /// Raises a [`Complex`] to a [`Complex`] power in place, taking the [`Complex`] on the
+    /// right-hand side by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::pow_round_assign_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::pow_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::pow_round_assign_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::pow_prec_assign_ref`]. If you want both of these things, consider using
+    /// [`Complex::pow_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z.pow_assign(&y);
+    /// assert_eq!(z.to_string(), "(12.283296913353334 0.26519133170052078)");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, other: &Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.pow_prec_round_assign_ref(other, (prec, prec), (Nearest, Nearest));
+    }
+}
@@ -0,0 +1,1012 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use crate::complex::arithmetic::mul::signed_zero;
+use crate::complex::arithmetic::{check_exact, exact_to_down, shl_round_with_ordering};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{CeilingLogBase2, Sqrt, SqrtAssign};
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+// Increases the absolute value of a nonzero finite `Float` by one unit in the last place, with the
+// given precision (equivalent to MPFR's `MPFR_ADD_ONE_ULP`).
+fn add_one_ulp(x: &mut Float, prec: u64) {
+    let tiny = Float::min_positive_value_prec(1);
+    *x = if *x > 0u32 {
+        x.add_prec_round_ref_ref(&tiny, prec, Ceiling).0
+    } else {
+        x.sub_prec_round_ref_ref(&tiny, prec, Floor).0
+    };
+}
+
+// Decreases the absolute value of a nonzero finite `Float` by one unit in the last place, with the
+// given precision (equivalent to MPFR's `MPFR_SUB_ONE_ULP`).
+fn sub_one_ulp(x: &mut Float, prec: u64) {
+    let tiny = Float::min_positive_value_prec(1);
+    *x = if *x > 0u32 {
+        x.sub_prec_round_ref_ref(&tiny, prec, Floor).0
+    } else {
+        x.add_prec_round_ref_ref(&tiny, prec, Ceiling).0
+    };
+}
+
+// Whether the last bit of a nonzero finite `Float`, at the given precision, is 1. With a precision
+// of 1, the only bit is the leading 1.
+fn last_bit_is_one(x: &Float, prec: u64) -> bool {
+    prec == 1 || Float::from_float_prec_round_ref(x, prec - 1, Down).1 != Equal
+}
+
+// sqrt(|y| / 2), computed without letting |y| / 2 underflow or 2|y| overflow.
+fn sqrt_half(y: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let y = if *y < 0u32 { -y } else { y.clone() };
+    if y.get_exponent().unwrap() > 0 {
+        (y >> 1u32).sqrt_prec_round(prec, rm)
+    } else {
+        let (s, o) = (y << 1u32).sqrt_prec_round(prec, rm);
+        shl_round_with_ordering(s, o, -1, prec, rm)
+    }
+}
+
+// This is equivalent to `mpc_sqrt` from `sqrt.c`, MPC 1.4.1, except that instead of widening the
+// exponent range, the input is scaled by an even power of 2 so that intermediate computations don't
+// overflow or underflow. The result is only scaled back at the end, so that its parts overflow or
+// underflow correctly.
+pub(crate) fn sqrt_helper(
+    b: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    assert_ne!(prec.0, 0);
+    assert_ne!(prec.1, 0);
+    let x = &b.real;
+    let y = &b.imag;
+    let im_sgn = y.is_sign_negative();
+    // Special values
+    if !b.is_finite() {
+        let signed_infinity = if im_sgn {
+            Float::NEGATIVE_INFINITY
+        } else {
+            Float::INFINITY
+        };
+        if y.is_infinite() {
+            // sqrt(x ± i * Inf) = +Inf ± i * Inf, even if x is NaN
+            return (
+                Complex {
+                    real: Float::INFINITY,
+                    imag: signed_infinity,
+                },
+                (Equal, Equal),
+            );
+        }
+        if x.is_infinite() {
+            let real = if x.is_sign_positive() {
+                if y.is_nan() {
+                    // sqrt(+Inf + i * NaN) = +Inf + i * NaN
+                    return (b.clone(), (Equal, Equal));
+                }
+                // sqrt(+Inf ± i * y) = +Inf ± i * 0, when y is finite
+                return (
+                    Complex {
+                        real: Float::INFINITY,
+                        imag: signed_zero(im_sgn),
+                    },
+                    (Equal, Equal),
+                );
+            } else if y.is_nan() {
+                // sqrt(-Inf + i * NaN) = NaN ± i * Inf
+                Float::NAN
+            } else {
+                // sqrt(-Inf ± i * y) = +0 ± i * Inf, when y is finite
+                Float::ZERO
+            };
+            return (
+                Complex {
+                    real,
+                    imag: signed_infinity,
+                },
+                (Equal, Equal),
+            );
+        }
+        // sqrt(x + i * NaN) = NaN + i * NaN, if x is not infinite, and sqrt(NaN + i * y) = NaN +
+        // i * NaN, if y is not infinite
+        return (
+            Complex {
+                real: Float::NAN,
+                imag: Float::NAN,
+            },
+            (Equal, Equal),
+        );
+    }
+    // Purely real
+    if y.is_zero() {
+        return if x.is_zero() {
+            (
+                Complex {
+                    real: Float::ZERO,
+                    imag: signed_zero(im_sgn),
+                },
+                (Equal, Equal),
+            )
+        } else if *x > 0u32 {
+            let (real, o) = x.sqrt_prec_round_ref(prec.0, rm.0);
+            (
+                Complex {
+                    real,
+                    imag: signed_zero(im_sgn),
+                },
+                (o, Equal),
+            )
+        } else if im_sgn {
+            let (imag, o) = (-x).sqrt_prec_round(prec.1, -rm.1);
+            (
+                Complex {
+                    real: Float::ZERO,
+                    imag: -imag,
+                },
+                (Equal, o.reverse()),
+            )
+        } else {
+            let (imag, o) = (-x).sqrt_prec_round(prec.1, rm.1);
+            (
+                Complex {
+                    real: Float::ZERO,
+                    imag,
+                },
+                (Equal, o),
+            )
+        };
+    }
+    // Purely imaginary
+    if x.is_zero() {
+        let (real, o_r) = sqrt_half(y, prec.0, rm.0);
+        let (imag, o_i) = if *y > 0u32 {
+            sqrt_half(y, prec.1, rm.1)
+        } else {
+            let (imag, o_i) = sqrt_half(y, prec.1, -rm.1);
+            (-imag, o_i.reverse())
+        };
+        return (Complex { real, imag }, (o_r, o_i));
+    }
+    let re_positive = *x > 0u32;
+    let im_positive = *y > 0u32;
+    let rm_re = exact_to_down(rm.0);
+    let rm_im = exact_to_down(rm.1);
+    // The rounding modes and precisions required for w and t, which can be either the real or the
+    // imaginary part of the result
+    let (rnd_w, prec_w, mut rnd_t, prec_t) = if re_positive {
+        (rm_re, prec.0, rm_im, prec.1)
+    } else if im_positive {
+        (rm_im, prec.1, rm_re, prec.0)
+    } else {
+        (-rm_im, prec.1, -rm_re, prec.0)
+    };
+    // Force `Floor` or `Ceiling`, using sign(t) = sign(y)
+    match rnd_t {
+        Down => rnd_t = if im_positive { Floor } else { Ceiling },
+        Up => rnd_t = if im_positive { Ceiling } else { Floor },
+        _ => {}
+    }
+    // The rounding mode used when computing t, which rounds t away from zero
+    let r = if im_sgn { Floor } else { Ceiling };
+    // Scale b by 2^(-2k) so that its larger part has an exponent of 0 or 1
+    let k = max(x.get_exponent().unwrap(), y.get_exponent().unwrap()) >> 1;
+    let k = i64::from(k);
+    let x_scaled = x << (-2 * k);
+    let y_scaled = y << (-2 * k);
+    // If a part underflowed, it's still too small to affect w except by making it inexact
+    let scaled_underflow = x_scaled.is_zero() || y_scaled.is_zero();
+    let ey = i64::from(y.get_exponent().unwrap());
+    let y_normalized = y << -ey;
+    let abs_x_scaled = if re_positive {
+        x_scaled.clone()
+    } else {
+        -&x_scaled
+    };
+    let mut working_prec = max(prec.0, prec.1);
+    let mut loop_count = 0;
+    let (w, inex_w, repr_w, t, inex_t, repr_t) = loop {
+        loop_count += 1;
+        working_prec += if loop_count <= 2 {
+            working_prec.ceiling_log_base_2() + 4
+        } else {
+            working_prec >> 1
+        };
+        let wp = working_prec;
+        let err = i64::exact_from(wp) - 4;
+        // w = sqrt((|x| + sqrt(x ^ 2 + y ^ 2)) / 2), rounded down; the final error on w is bounded
+        // by 10 ulps
+        let (w, o_1) = x_scaled
+            .mul_add_mul_prec_round_ref_ref_ref_ref(&x_scaled, &y_scaled, &y_scaled, wp, Floor);
+        let (w, o_2) = w.sqrt_prec_round(wp, Floor);
+        let (w, o_3) = w.add_prec_round_ref_ref(&abs_x_scaled, wp, Floor);
+        let (w, o_4) = (w >> 1u32).sqrt_prec_round(wp, Floor);
+        let inex_w =
+            scaled_underflow || o_1 != Equal || o_2 != Equal || o_3 != Equal || o_4 != Equal;
+        // Is w representable in the target precision?
+        let repr_w = w.get_min_prec().unwrap() <= prec_w;
+        let ok_w = if !repr_w {
+            // Use the usual trick for obtaining the ternary value
+            w.can_round(
+                err,
+                Floor,
+                Ceiling,
+                prec_w + u64::from(rnd_w == Nearest || rnd_w == NearestTiesAway),
+            )
+        } else if rnd_w == Nearest || rnd_w == NearestTiesAway {
+            // If w can be rounded to nearest, then actually no rounding occurs, and the ternary
+            // value is known from inex_w
+            w.can_round(err, Floor, Nearest, prec_w)
+        } else {
+            // If w can be rounded down, then any direct rounding and the ternary value can be
+            // determined from inex_w
+            w.can_round(err, Floor, Floor, prec_w)
+        };
+        if inex_w && !ok_w {
+            continue;
+        }
+        // t = y / 2w, rounded away; the total error is bounded by 16 ulps. The division by 2 is
+        // deferred until t is scaled back.
+        let (t, o) = y_normalized.div_prec_round_ref_ref(&w, wp, r);
+        // If the division was exact but w was not, t is still rounded away from zero
+        let inex_t = o != Equal || inex_w;
+        let repr_t = t.get_min_prec().unwrap() <= prec_t;
+        let ok_t = if !repr_t {
+            // As for w; since t was rounded away, check whether rounding toward zero is possible
+            t.can_round(
+                err,
+                r,
+                Down,
+                prec_t + u64::from(rnd_t == Nearest || rnd_t == NearestTiesAway),
+            )
+        } else if rnd_t == Nearest || rnd_t == NearestTiesAway {
+            t.can_round(err, r, Nearest, prec_t)
+        } else {
+            t.can_round(err, r, r, prec_t)
+        };
+        if !inex_t || ok_t {
+            break (w, inex_w, repr_w, t, inex_t, repr_t);
+        }
+    };
+    // When inexact, w is less than the exact value, and t is greater than the exact value in
+    // absolute value
+    let o_w = if inex_w { Less } else { Equal };
+    let o_t = if !inex_t {
+        Equal
+    } else if im_sgn {
+        Less
+    } else {
+        Greater
+    };
+    let (mut real, mut o_r, mut imag, mut o_i) = if re_positive {
+        let (real, o_r) = Float::from_float_prec_round(w, prec.0, rm_re);
+        let (imag, o_i) = Float::from_float_prec_round(t, prec.1, rm_im);
+        (real, o_r, imag, o_i)
+    } else if im_positive {
+        let (real, o_r) = Float::from_float_prec_round(t, prec.0, rm_re);
+        let (imag, o_i) = Float::from_float_prec_round(w, prec.1, rm_im);
+        (real, o_r, imag, o_i)
+    } else {
+        let (real, o_r) = Float::from_float_prec_round(-t, prec.0, rm_re);
+        let (imag, o_i) = Float::from_float_prec_round(-w, prec.1, rm_im);
+        (real, o_r, imag, o_i)
+    };
+    if repr_w && inex_w {
+        let (w_part, o_w_part, w_prec) = if re_positive {
+            (&mut real, &mut o_r, prec.0)
+        } else {
+            (&mut imag, &mut o_i, prec.1)
+        };
+        if rnd_w == Nearest || rnd_w == NearestTiesAway {
+            // w has not actually been rounded, so the `Ordering` comes from `o_w` instead
+            *o_w_part = if re_positive || im_positive {
+                o_w
+            } else {
+                o_w.reverse()
+            };
+        } else {
+            // Determine the `Ordering`, but also potentially add 1 ulp; this can only be done now,
+            // when we are in the target precision
+            let up =
+                rnd_w == Ceiling || rnd_w == Up || rnd_w == Odd && !last_bit_is_one(w_part, w_prec);
+            if up {
+                add_one_ulp(w_part, w_prec);
+            }
+            *o_w_part = if re_positive || im_positive {
+                if up { Greater } else { Less }
+            } else if up {
+                Less
+            } else {
+                Greater
+            };
+        }
+    }
+    if repr_t && inex_t {
+        let (t_part, o_t_part, t_prec) = if re_positive {
+            (&mut imag, &mut o_i, prec.1)
+        } else {
+            (&mut real, &mut o_r, prec.0)
+        };
+        let same_sign = re_positive || im_positive;
+        if rnd_t == Nearest
+            || rnd_t == NearestTiesAway
+            || rnd_t == r
+            || rnd_t == Odd && last_bit_is_one(t_part, t_prec)
+        {
+            *o_t_part = if same_sign { o_t } else { o_t.reverse() };
+        } else {
+            sub_one_ulp(t_part, t_prec);
+            *o_t_part = if same_sign { o_t.reverse() } else { o_t };
+        }
+    }
+    check_exact(rm.0, o_r, "square root");
+    check_exact(rm.1, o_i, "square root");
+    let (w_shift, t_shift) = (k, ey - k - 1);
+    let (real_shift, imag_shift) = if re_positive {
+        (w_shift, t_shift)
+    } else {
+        (t_shift, w_shift)
+    };
+    let (real, o_r) = shl_round_with_ordering(real, o_r, real_shift, prec.0, rm.0);
+    let (imag, o_i) = shl_round_with_ordering(imag, o_i, imag_shift, prec.1, rm.1);
+    (Complex { real, imag }, (o_r, o_i))
+}
+
+impl Complex {
+    /// Computes the square root of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The [`Complex`] is
+    /// taken by value. A pair of [`Ordering`]s is also returned, indicating whether the rounded
+    /// real and imaginary parts are less than, equal to, or greater than the real and imaginary
+    /// parts of the exact square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The principal square root is used: the one whose real part is nonnegative, with the branch
+    /// cut along the negative real axis. On the branch cut, the sign of the imaginary part of the
+    /// result is that of the (zero) imaginary part of the input.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $\sqrt{x}$.
+    /// Then
+    /// $$
+    /// f(x,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_sqrt`. In particular:
+    /// - $\sqrt{x+\infty i} = \infty+\infty i$ and $\sqrt{x-\infty i} = \infty-\infty i$ for every
+    ///   $x$, including `NaN`.
+    /// - $\sqrt{-\infty+yi} = 0+\infty i$ and $\sqrt{\infty+yi} = \infty+0i$ for finite $y$, with
+    ///   the sign of the result's zero or infinite imaginary part being that of $y$.
+    /// - $\sqrt{\pm0+0i} = 0+0i$ and $\sqrt{\pm0-0i} = 0-0i$.
+    /// - Otherwise, if a part of the input is `NaN`, both parts of the result are `NaN`.
+    ///
+    /// This function cannot overflow. If a part of the result would underflow, it is rounded as
+    /// [`Float::sqrt_prec_round`] would round it.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using [`Complex::sqrt_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::sqrt_round`] instead. If both of these things are
+    /// true, consider using [`Complex::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the square root can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().sqrt_prec_round((5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.88 0.71191)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().sqrt_prec_round((5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.94 0.71094)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().sqrt_prec_round((20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.9099674 0.71160412)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round(
+        self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        self.sqrt_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the square root of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the specified precisions and with the specified rounding modes. The [`Complex`] is
+    /// taken by reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded
+    /// real and imaginary parts are less than, equal to, or greater than the real and imaginary
+    /// parts of the exact square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The principal square root is used: the one whose real part is nonnegative, with the branch
+    /// cut along the negative real axis. On the branch cut, the sign of the imaginary part of the
+    /// result is that of the (zero) imaginary part of the input.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $\sqrt{x}$.
+    /// Then
+    /// $$
+    /// f(x,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The special values follow C99 Annex G and MPC's `mpc_sqrt`. In particular:
+    /// - $\sqrt{x+\infty i} = \infty+\infty i$ and $\sqrt{x-\infty i} = \infty-\infty i$ for every
+    ///   $x$, including `NaN`.
+    /// - $\sqrt{-\infty+yi} = 0+\infty i$ and $\sqrt{\infty+yi} = \infty+0i$ for finite $y$, with
+    ///   the sign of the result's zero or infinite imaginary part being that of $y$.
+    /// - $\sqrt{\pm0+0i} = 0+0i$ and $\sqrt{\pm0-0i} = 0-0i$.
+    /// - Otherwise, if a part of the input is `NaN`, both parts of the result are `NaN`.
+    ///
+    /// This function cannot overflow. If a part of the result would underflow, it is rounded as
+    /// [`Float::sqrt_prec_round`] would round it.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::sqrt_prec_ref`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the input's parts, consider using [`Complex::sqrt_round_ref`] instead. If
+    /// both of these things are true, consider using [`Complex::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the square root can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.sqrt_prec_round_ref((5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.88 0.71191)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.sqrt_prec_round_ref((5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.94 0.71094)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.sqrt_prec_round_ref((20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.9099674 0.71160412)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round_ref(
+        &self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        sqrt_helper(self, prec, rm)
+    }
+
+    /// Computes the square root of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The [`Complex`] is taken by value.
+    /// A pair of [`Ordering`]s is also returned, indicating whether the rounded real and imaginary
+    /// parts are less than, equal to, or greater than the real and imaginary parts of the exact
+    /// square root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::sqrt_prec_round`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sqrt_prec_round`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().sqrt_prec((5, 10));
+    /// assert_eq!(z.to_string(), "(1.94 0.71191)");
+    /// assert_eq!(o, (Greater, Greater));
+    ///
+    /// let (z, o) = x.clone().sqrt_prec((20, 20));
+    /// assert_eq!(z.to_string(), "(1.9099674 0.71160412)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn sqrt_prec(self, prec: (u64, u64)) -> (Self, (Ordering, Ordering)) {
+        self.sqrt_prec_round(prec, (Nearest, Nearest))
+    }
+
+    /// Computes the square root of a [`Complex`], rounding the real and imaginary parts of the
+    /// result to the nearest value of the specified precisions. The [`Complex`] is taken by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// This is [`Complex::sqrt_prec_round_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sqrt_prec_round_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.sqrt_prec_ref((5, 10));
+    /// assert_eq!(z.to_string(), "(1.94 0.71191)");
+    /// assert_eq!(o, (Greater, Greater));
+    ///
+    /// let (z, o) = x.sqrt_prec_ref((20, 20));
+    /// assert_eq!(z.to_string(), "(1.9099674 0.71160412)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_ref(&self, prec: (u64, u64)) -> (Complex, (Ordering, Ordering)) {
+        self.sqrt_prec_round_ref(prec, (Nearest, Nearest))
+    }
+
+    /// Computes the square root of a [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The [`Complex`] is taken by value. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact square root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. This is [`Complex::sqrt_prec_round`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::sqrt_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using [`Complex::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the square root can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.clone().sqrt_round((Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.9099668918469641 0.71160443672152596)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().sqrt_round((Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.9099668918469643 0.71160443672152585)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().sqrt_round((Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.9099668918469643 0.71160443672152585)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn sqrt_round(self, rm: (RoundingMode, RoundingMode)) -> (Self, (Ordering, Ordering)) {
+        let prec = self.max_prec();
+        self.sqrt_prec_round((prec, prec), rm)
+    }
+
+    /// Computes the square root of a [`Complex`], rounding the real and imaginary parts of the
+    /// result with the specified rounding modes. The [`Complex`] is taken by reference. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact square root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. This is [`Complex::sqrt_prec_round_ref`] with that precision; see its documentation
+    /// for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::sqrt_prec_round_ref`] instead. If you know you'll be using the `Nearest` rounding
+    /// mode for both parts, consider using [`Complex::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the square root can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let (z, o) = x.sqrt_round_ref((Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.9099668918469641 0.71160443672152596)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.sqrt_round_ref((Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.9099668918469643 0.71160443672152585)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.sqrt_round_ref((Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.9099668918469643 0.71160443672152585)");
+    /// assert_eq!(o, (Greater, Less));
+    /// ```
+    #[inline]
+    pub fn sqrt_round_ref(
+        &self,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = self.max_prec();
+        self.sqrt_prec_round_ref((prec, prec), rm)
+    }
+
+    /// Replaces a [`Complex`] with its square root in place, rounding the real and imaginary parts
+    /// of the result to the specified precisions and with the specified rounding modes. A pair of
+    /// [`Ordering`]s is returned, indicating whether the rounded real and imaginary parts are less
+    /// than, equal to, or greater than the real and imaginary parts of the exact square root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets a part to
+    /// `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::sqrt_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::sqrt_prec_assign`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the input's parts, consider using [`Complex::sqrt_round_assign`]
+    /// instead. If both of these things are true, consider using [`Complex::sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the square root can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sqrt_prec_round_assign((5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.88 0.71191)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sqrt_prec_round_assign((20, 20), (Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.9099674 0.71160412)");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round_assign(
+        &mut self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.sqrt_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Replaces a [`Complex`] with its square root in place, rounding the real and imaginary parts
+    /// of the result to the nearest value of the specified precisions. A pair of [`Ordering`]s is
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact square root. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets a part to `NaN`, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See [`Complex::sqrt_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sqrt_prec_round_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// input's parts, consider using [`Complex::sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sqrt_prec_assign((5, 10)), (Greater, Greater));
+    /// assert_eq!(z.to_string(), "(1.94 0.71191)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sqrt_prec_assign((20, 20)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.9099674 0.71160412)");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_assign(&mut self, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.sqrt_prec_round_assign(prec, (Nearest, Nearest))
+    }
+
+    /// Replaces a [`Complex`] with its square root in place, rounding the real and imaginary parts
+    /// of the result with the specified rounding modes. A pair of [`Ordering`]s is returned,
+    /// indicating whether the rounded real and imaginary parts are less than, equal to, or greater
+    /// than the real and imaginary parts of the exact square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets a part to `NaN`, the corresponding
+    /// [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts. See [`Complex::sqrt_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::sqrt_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using [`Complex::sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the square root can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sqrt_round_assign((Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.9099668918469641 0.71160443672152596)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sqrt_round_assign((Nearest, Nearest)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.9099668918469643 0.71160443672152585)");
+    /// ```
+    #[inline]
+    pub fn sqrt_round_assign(&mut self, rm: (RoundingMode, RoundingMode)) -> (Ordering, Ordering) {
+        let prec = self.max_prec();
+        self.sqrt_prec_round_assign((prec, prec), rm)
+    }
+}
+
+impl Sqrt for Complex {
+    type Output = Self;
+
+    /// Computes the square root of a [`Complex`], taking it by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sqrt_round`] with both rounding modes `Nearest`; see [`Complex::sqrt_prec_round`]
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sqrt_round`] instead. If
+    /// you want to specify the output precisions, consider using [`Complex::sqrt_prec`]. If you
+    /// want both of these things, consider using [`Complex::sqrt_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!(x.clone().sqrt().to_string(), "(1.9099668918469643 0.71160443672152585)");
+    ///
+    /// assert_eq!((-Complex::ONE).sqrt().to_string(), "(0.0 -1.0)");
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Self {
+        let prec = self.max_prec();
+        self.sqrt_prec_round((prec, prec), (Nearest, Nearest)).0
+    }
+}
+
+impl Sqrt for &Complex {
+    type Output = Complex;
+
+    /// Computes the square root of a [`Complex`], taking it by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sqrt_round_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::sqrt_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sqrt_round_ref`] instead.
+    /// If you want to specify the output precisions, consider using [`Complex::sqrt_prec_ref`]. If
+    /// you want both of these things, consider using [`Complex::sqrt_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// assert_eq!((&x).sqrt().to_string(), "(1.9099668918469643 0.71160443672152585)");
+    ///
+    /// assert_eq!((-Complex::ONE).sqrt().to_string(), "(0.0 -1.0)");
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Complex {
+        let prec = self.max_prec();
+        self.sqrt_prec_round_ref((prec, prec), (Nearest, Nearest)).0
+    }
+}
+
+impl SqrtAssign for Complex {
+    /// Replaces a [`Complex`] with its square root in place.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the input's
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sqrt_round_assign`] with both rounding modes `Nearest`; see
+    /// [`Complex::sqrt_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sqrt_round_assign`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::sqrt_prec_assign`]. If you want both of these things, consider using
+    /// [`Complex::sqrt_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::SqrtAssign;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let mut z = x.clone();
+    /// z.sqrt_assign();
+    /// assert_eq!(z.to_string(), "(1.9099668918469643 0.71160443672152585)");
+    /// ```
+    #[inline]
+    fn sqrt_assign(&mut self) {
+        let prec = self.max_prec();
+        self.sqrt_prec_round_assign((prec, prec), (Nearest, Nearest));
+    }
+}
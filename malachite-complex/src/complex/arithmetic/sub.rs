@@ -0,0 +1,1427 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use core::cmp::max;
+use core::ops::{Sub, SubAssign};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// This is equivalent to `mpc_sub` from `sub.c`, MPC 1.4.1.
+pub(crate) fn sub_helper(
+    x: &Complex,
+    y: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> (Complex, (Ordering, Ordering)) {
+    let (real, o_r) = x.real.sub_prec_round_ref_ref(&y.real, prec.0, rm.0);
+    let (imag, o_i) = x.imag.sub_prec_round_ref_ref(&y.imag, prec.1, rm.1);
+    (Complex { real, imag }, (o_r, o_i))
+}
+
+impl Complex {
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. Both [`Complex`]s are taken by
+    /// value. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x-y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The real and imaginary parts are computed independently, as by
+    /// [`Float::sub_prec_round`](malachite_float::Float::sub_prec_round), so their special
+    /// cases are those of [`Float`](malachite_float::Float) differences. In particular:
+    /// - A part of the result is `NaN` if the corresponding part of either input is `NaN`, or if
+    ///   the corresponding parts of the inputs are infinities of the same sign.
+    /// - The difference of zero parts with different signs is a zero with the sign of the first;
+    ///   otherwise, an exact zero part is positive, unless the rounding mode of that part is
+    ///   `Floor`, in which case it is negative.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`](malachite_float::Float) function would round it: to infinity or to the largest
+    /// finite [`Float`](malachite_float::Float) of the part's precision, or to zero or to the
+    /// smallest positive [`Float`](malachite_float::Float) of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using [`Complex::sub_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using [`Complex::sub_round`] instead. If both of these things are
+    /// true, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the difference can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().sub_prec_round(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.69 3.4141)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().sub_prec_round(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().sub_prec_round(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn sub_prec_round(
+        self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        sub_helper(&self, &other, prec, rm)
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. The first [`Complex`] is taken
+    /// by value and the second by reference. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x-y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The real and imaginary parts are computed independently, as by
+    /// [`Float::sub_prec_round`](malachite_float::Float::sub_prec_round), so their special
+    /// cases are those of [`Float`](malachite_float::Float) differences. In particular:
+    /// - A part of the result is `NaN` if the corresponding part of either input is `NaN`, or if
+    ///   the corresponding parts of the inputs are infinities of the same sign.
+    /// - The difference of zero parts with different signs is a zero with the sign of the first;
+    ///   otherwise, an exact zero part is positive, unless the rounding mode of that part is
+    ///   `Floor`, in which case it is negative.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`](malachite_float::Float) function would round it: to infinity or to the largest
+    /// finite [`Float`](malachite_float::Float) of the part's precision, or to zero or to the
+    /// smallest positive [`Float`](malachite_float::Float) of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::sub_prec_val_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::sub_round_val_ref`]
+    /// instead. If both of these things are true, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the difference can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().sub_prec_round_val_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.69 3.4141)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.clone().sub_prec_round_val_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().sub_prec_round_val_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_prec_round_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        sub_helper(&self, other, prec, rm)
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. The first [`Complex`] is taken
+    /// by reference and the second by value. A pair of [`Ordering`]s is also returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x-y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The real and imaginary parts are computed independently, as by
+    /// [`Float::sub_prec_round`](malachite_float::Float::sub_prec_round), so their special
+    /// cases are those of [`Float`](malachite_float::Float) differences. In particular:
+    /// - A part of the result is `NaN` if the corresponding part of either input is `NaN`, or if
+    ///   the corresponding parts of the inputs are infinities of the same sign.
+    /// - The difference of zero parts with different signs is a zero with the sign of the first;
+    ///   otherwise, an exact zero part is positive, unless the rounding mode of that part is
+    ///   `Floor`, in which case it is negative.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`](malachite_float::Float) function would round it: to infinity or to the largest
+    /// finite [`Float`](malachite_float::Float) of the part's precision, or to zero or to the
+    /// smallest positive [`Float`](malachite_float::Float) of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::sub_prec_ref_val`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::sub_round_ref_val`]
+    /// instead. If both of these things are true, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the difference can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.sub_prec_round_ref_val(y.clone(), (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.69 3.4141)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.sub_prec_round_ref_val(y.clone(), (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.sub_prec_round_ref_val(y.clone(), (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn sub_prec_round_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        sub_helper(self, &other, prec, rm)
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// specified precisions and with the specified rounding modes. Both [`Complex`]s are taken by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// Each part of the result is correctly rounded. Let $a+bi$ be the exact value of $x-y$. Then
+    /// $$
+    /// f(x,y,(p_r,p_i),(m_r,m_i)) = (a+\varepsilon_r)+(b+\varepsilon_i)i.
+    /// $$
+    /// - If $a$ is infinite, zero, or `NaN`, $\varepsilon_r$ may be ignored or assumed to be 0.
+    /// - If $a$ is finite and nonzero, and $m_r$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_r| < 2^{\lfloor\log_2 |a|\rfloor-p_r+1}$.
+    /// - If $a$ is finite and nonzero, and $m_r$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon_r| \leq 2^{\lfloor\log_2 |a|\rfloor-p_r}$.
+    /// - The same bounds hold for $\varepsilon_i$, with $b$, $p_i$, and $m_i$ in place of $a$,
+    ///   $p_r$, and $m_r$.
+    ///
+    /// If a part of the output has a precision, it is the corresponding element of `prec`.
+    ///
+    /// The real and imaginary parts are computed independently, as by
+    /// [`Float::sub_prec_round`](malachite_float::Float::sub_prec_round), so their special
+    /// cases are those of [`Float`](malachite_float::Float) differences. In particular:
+    /// - A part of the result is `NaN` if the corresponding part of either input is `NaN`, or if
+    ///   the corresponding parts of the inputs are infinities of the same sign.
+    /// - The difference of zero parts with different signs is a zero with the sign of the first;
+    ///   otherwise, an exact zero part is positive, unless the rounding mode of that part is
+    ///   `Floor`, in which case it is negative.
+    ///
+    /// If a part of the result overflows or underflows, it is rounded as the corresponding
+    /// [`Float`](malachite_float::Float) function would round it: to infinity or to the largest
+    /// finite [`Float`](malachite_float::Float) of the part's precision, or to zero or to the
+    /// smallest positive [`Float`](malachite_float::Float) of the part's precision,
+    /// depending on the rounding mode.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::sub_prec_ref_ref`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::sub_round_ref_ref`]
+    /// instead. If both of these things are true, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the difference can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.sub_prec_round_ref_ref(&y, (5, 10), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.69 3.4141)");
+    /// assert_eq!(o, (Less, Greater));
+    ///
+    /// let (z, o) = x.sub_prec_round_ref_ref(&y, (5, 10), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.sub_prec_round_ref_ref(&y, (20, 20), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_prec_round_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        sub_helper(self, other, prec, rm)
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. Both [`Complex`]s are taken by value. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the real and imaginary parts of the exact difference.
+    /// Although `NaN`s are not comparable to any [`Float`](malachite_float::Float), whenever this
+    /// function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the difference is equidistant from two [`Float`](malachite_float::Float)s with
+    /// the specified precision, the [`Float`](malachite_float::Float) with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// This is [`Complex::sub_prec_round`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_prec_round`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the inputs'
+    /// parts, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().sub_prec(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().sub_prec(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_prec(self, other: Complex, prec: (u64, u64)) -> (Self, (Ordering, Ordering)) {
+        self.sub_prec_round(other, prec, (Nearest, Nearest))
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. The first [`Complex`] is taken by value and the
+    /// second by reference. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the difference is equidistant from two [`Float`](malachite_float::Float)s with
+    /// the specified precision, the [`Float`](malachite_float::Float) with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// This is [`Complex::sub_prec_round_val_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_prec_round_val_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().sub_prec_val_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.clone().sub_prec_val_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_prec_val_ref(
+        self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        self.sub_prec_round_val_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. The first [`Complex`] is taken by reference and
+    /// the second by value. A pair of [`Ordering`]s is also returned, indicating whether the
+    /// rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the difference is equidistant from two [`Float`](malachite_float::Float)s with
+    /// the specified precision, the [`Float`](malachite_float::Float) with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// This is [`Complex::sub_prec_round_ref_val`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_prec_round_ref_val`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.sub_prec_ref_val(y.clone(), (5, 10));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.sub_prec_ref_val(y.clone(), (20, 20));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_prec_ref_val(
+        &self,
+        other: Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.sub_prec_round_ref_val(other, prec, (Nearest, Nearest))
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result to the
+    /// nearest value of the specified precisions. Both [`Complex`]s are taken by reference. A pair
+    /// of [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts
+    /// are less than, equal to, or greater than the real and imaginary parts of the exact
+    /// difference. Although `NaN`s are not comparable to any [`Float`](malachite_float::Float),
+    /// whenever this function returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the difference is equidistant from two [`Float`](malachite_float::Float)s with
+    /// the specified precision, the [`Float`](malachite_float::Float) with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// This is [`Complex::sub_prec_round_ref_ref`] with both rounding modes `Nearest`; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_prec_round_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.sub_prec_ref_ref(&y, (5, 10));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    /// assert_eq!(o, (Greater, Less));
+    ///
+    /// let (z, o) = x.sub_prec_ref_ref(&y, (20, 20));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_prec_ref_ref(
+        &self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Complex, (Ordering, Ordering)) {
+        self.sub_prec_round_ref_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. Both [`Complex`]s are taken by value. A pair of [`Ordering`]s is
+    /// also returned, indicating whether the rounded real and imaginary parts are less than, equal
+    /// to, or greater than the real and imaginary parts of the exact difference. Although `NaN`s
+    /// are not comparable to any [`Float`](malachite_float::Float), whenever this function returns
+    /// a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::sub_prec_round`] with that precision; see its documentation for
+    /// error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using [`Complex::sub_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode for both parts, consider
+    /// using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the difference can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().sub_round(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    ///
+    /// let (z, o) = x.clone().sub_round(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189900)");
+    /// assert_eq!(o, (Equal, Less));
+    ///
+    /// let (z, o) = x.clone().sub_round(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_round(
+        self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round(other, (prec, prec), rm)
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. The first [`Complex`] is taken by value and the second by
+    /// reference. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::sub_prec_round_val_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the difference can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.clone().sub_round_val_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    ///
+    /// let (z, o) = x.clone().sub_round_val_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189900)");
+    /// assert_eq!(o, (Equal, Less));
+    ///
+    /// let (z, o) = x.clone().sub_round_val_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_round_val_ref(
+        self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_val_ref(other, (prec, prec), rm)
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. The first [`Complex`] is taken by reference and the second by
+    /// value. A pair of [`Ordering`]s is also returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function returns a `NaN` part, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::sub_prec_round_ref_val`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the difference can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.sub_round_ref_val(y.clone(), (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    ///
+    /// let (z, o) = x.sub_round_ref_val(y.clone(), (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189900)");
+    /// assert_eq!(o, (Equal, Less));
+    ///
+    /// let (z, o) = x.sub_round_ref_val(y.clone(), (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_round_ref_val(
+        &self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_ref_val(other, (prec, prec), rm)
+    }
+
+    /// Subtracts two [`Complex`]s, rounding the real and imaginary parts of the result with the
+    /// specified rounding modes. Both [`Complex`]s are taken by reference. A pair of [`Ordering`]s
+    /// is also returned, indicating whether the rounded real and imaginary parts are less than,
+    /// equal to, or greater than the real and imaginary parts of the exact difference. Although
+    /// `NaN`s are not comparable to any [`Float`](malachite_float::Float), whenever this function
+    /// returns a `NaN` part, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. This is [`Complex::sub_prec_round_ref_ref`] with that precision; see its
+    /// documentation for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the difference can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let (z, o) = x.sub_round_ref_ref(&y, (Floor, Ceiling));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    ///
+    /// let (z, o) = x.sub_round_ref_ref(&y, (Ceiling, Floor));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189900)");
+    /// assert_eq!(o, (Equal, Less));
+    ///
+    /// let (z, o) = x.sub_round_ref_ref(&y, (Nearest, Nearest));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// assert_eq!(o, (Equal, Greater));
+    /// ```
+    #[inline]
+    pub fn sub_round_ref_ref(
+        &self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Complex, (Ordering, Ordering)) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_ref_ref(other, (prec, prec), rm)
+    }
+
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, rounding the real and imaginary parts
+    /// of the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by value. A pair of [`Ordering`]s is returned,
+    /// indicating whether the rounded real and imaginary parts are less than, equal to, or greater
+    /// than the real and imaginary parts of the exact difference. Although `NaN`s are not
+    /// comparable to any [`Float`](malachite_float::Float), whenever this function sets a part to
+    /// `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::sub_prec_assign`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the inputs' parts, consider using [`Complex::sub_round_assign`]
+    /// instead. If both of these things are true, consider using `-=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the difference can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_prec_round_assign(y.clone(), (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.69 3.4141)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(
+    ///     z.sub_prec_round_assign(y.clone(), (20, 20), (Nearest, Nearest)),
+    ///     (Less, Greater)
+    /// );
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// ```
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn sub_prec_round_assign(
+        &mut self,
+        other: Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.sub_prec_round_ref_ref(&other, prec, rm);
+        o
+    }
+
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, rounding the real and imaginary parts
+    /// of the result to the specified precisions and with the specified rounding modes. The
+    /// [`Complex`] on the right-hand side is taken by reference. A pair of [`Ordering`]s is
+    /// returned, indicating whether the rounded real and imaginary parts are less than, equal to,
+    /// or greater than the real and imaginary parts of the exact difference. Although `NaN`s are
+    /// not comparable to any [`Float`](malachite_float::Float), whenever this function sets a part
+    /// to `NaN`, the corresponding [`Ordering`] is `Equal`.
+    ///
+    /// See [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you know you'll be using `Nearest` for both parts, consider using
+    /// [`Complex::sub_prec_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the inputs' parts, consider using
+    /// [`Complex::sub_round_assign_ref`] instead. If both of these things are true, consider using
+    /// `-=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part of the difference can't be represented exactly with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_prec_round_assign_ref(&y, (5, 10), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.69 3.4141)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_prec_round_assign_ref(&y, (20, 20), (Nearest, Nearest)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// ```
+    #[inline]
+    pub fn sub_prec_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let o;
+        (*self, o) = self.sub_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, rounding the real and imaginary parts
+    /// of the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by value. A pair of [`Ordering`]s is returned, indicating whether
+    /// the rounded real and imaginary parts are less than, equal to, or greater than the real and
+    /// imaginary parts of the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function sets a part to `NaN`, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the difference is equidistant from two [`Float`](malachite_float::Float)s with
+    /// the specified precision, the [`Float`](malachite_float::Float) with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// See [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_prec_round_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// inputs' parts, consider using `-=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_prec_assign(y.clone(), (5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_prec_assign(y.clone(), (20, 20)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// ```
+    #[inline]
+    pub fn sub_prec_assign(&mut self, other: Complex, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.sub_prec_round_assign(other, prec, (Nearest, Nearest))
+    }
+
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, rounding the real and imaginary parts
+    /// of the result to the nearest value of the specified precisions. The [`Complex`] on the
+    /// right-hand side is taken by reference. A pair of [`Ordering`]s is returned, indicating
+    /// whether the rounded real and imaginary parts are less than, equal to, or greater than the
+    /// real and imaginary parts of the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function sets a part to `NaN`, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// If a part of the difference is equidistant from two [`Float`](malachite_float::Float)s with
+    /// the specified precision, the [`Float`](malachite_float::Float) with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// See [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using
+    /// [`Complex::sub_prec_round_assign_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the inputs' parts, consider using `-=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_prec_assign_ref(&y, (5, 10)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(1.75 3.4102)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_prec_assign_ref(&y, (20, 20)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(1.7273788 3.4114304)");
+    /// ```
+    #[inline]
+    pub fn sub_prec_assign_ref(
+        &mut self,
+        other: &Complex,
+        prec: (u64, u64),
+    ) -> (Ordering, Ordering) {
+        self.sub_prec_round_assign_ref(other, prec, (Nearest, Nearest))
+    }
+
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, rounding the real and imaginary parts
+    /// of the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by value. A pair of [`Ordering`]s is returned, indicating whether the rounded real and
+    /// imaginary parts are less than, equal to, or greater than the real and imaginary parts of the
+    /// exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function sets a part to `NaN`, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `-=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the difference can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_round_assign(y.clone(), (Floor, Ceiling)), (Equal, Greater));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_round_assign(y.clone(), (Nearest, Nearest)), (Equal, Greater));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// ```
+    #[inline]
+    pub fn sub_round_assign(
+        &mut self,
+        other: Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_assign(other, (prec, prec), rm)
+    }
+
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, rounding the real and imaginary parts
+    /// of the result with the specified rounding modes. The [`Complex`] on the right-hand side is
+    /// taken by reference. A pair of [`Ordering`]s is returned, indicating whether the rounded real
+    /// and imaginary parts are less than, equal to, or greater than the real and imaginary parts of
+    /// the exact difference. Although `NaN`s are not comparable to any
+    /// [`Float`](malachite_float::Float), whenever this function sets a part to `NaN`, the
+    /// corresponding [`Ordering`] is `Equal`.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts. See [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode for both parts, consider using `-=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if a rounding mode is `Exact` but the corresponding part of the difference can't be
+    /// represented exactly with the output precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_round_assign_ref(&y, (Floor, Ceiling)), (Equal, Greater));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    ///
+    /// let mut z = x.clone();
+    /// assert_eq!(z.sub_round_assign_ref(&y, (Nearest, Nearest)), (Equal, Greater));
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// ```
+    #[inline]
+    pub fn sub_round_assign_ref(
+        &mut self,
+        other: &Complex,
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_assign_ref(other, (prec, prec), rm)
+    }
+}
+
+impl Sub<Self> for Complex {
+    type Output = Self;
+
+    /// Subtracts two [`Complex`]s, taking both by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sub_round`] with both rounding modes `Nearest`; see [`Complex::sub_prec_round`]
+    /// for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_round`] instead. If
+    /// you want to specify the output precisions, consider using [`Complex::sub_prec`]. If you want
+    /// both of these things, consider using [`Complex::sub_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((x.clone() - y.clone()).to_string(), "(1.7273790912166980 3.4114290090189905)");
+    ///
+    /// assert_eq!((Complex::ONE - Complex::I).to_string(), "(1.0 -1.0)");
+    /// ```
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Sub<&Self> for Complex {
+    type Output = Self;
+
+    /// Subtracts two [`Complex`]s, taking the first by value and the second by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sub_round_val_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_round_val_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_val_ref`]. If you want both of these things, consider using
+    /// [`Complex::sub_prec_round_val_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((x.clone() - &y).to_string(), "(1.7273790912166980 3.4114290090189905)");
+    ///
+    /// assert_eq!((Complex::ONE - Complex::I).to_string(), "(1.0 -1.0)");
+    /// ```
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_val_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Sub<Complex> for &Complex {
+    type Output = Complex;
+
+    /// Subtracts two [`Complex`]s, taking the first by reference and the second by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sub_round_ref_val`] with both rounding modes `Nearest`; see
+    /// [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_round_ref_val`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_ref_val`]. If you want both of these things, consider using
+    /// [`Complex::sub_prec_round_ref_val`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x - y.clone()).to_string(), "(1.7273790912166980 3.4114290090189905)");
+    ///
+    /// assert_eq!((Complex::ONE - Complex::I).to_string(), "(1.0 -1.0)");
+    /// ```
+    #[inline]
+    fn sub(self, other: Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_ref_val(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl Sub<&Complex> for &Complex {
+    type Output = Complex;
+
+    /// Subtracts two [`Complex`]s, taking both by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sub_round_ref_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_round_ref_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_ref_ref`]. If you want both of these things, consider using
+    /// [`Complex::sub_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// assert_eq!((&x - &y).to_string(), "(1.7273790912166980 3.4114290090189905)");
+    ///
+    /// assert_eq!((Complex::ONE - Complex::I).to_string(), "(1.0 -1.0)");
+    /// ```
+    #[inline]
+    fn sub(self, other: &Complex) -> Complex {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_ref_ref(other, (prec, prec), (Nearest, Nearest))
+            .0
+    }
+}
+
+impl SubAssign<Self> for Complex {
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, taking the [`Complex`] on the
+    /// right-hand side by value.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sub_round_assign`] with both rounding modes `Nearest`; see
+    /// [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_round_assign`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_assign`]. If you want both of these things, consider using
+    /// [`Complex::sub_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z -= y.clone();
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_assign(other, (prec, prec), (Nearest, Nearest));
+    }
+}
+
+impl SubAssign<&Self> for Complex {
+    /// Subtracts a [`Complex`] from a [`Complex`] in place, taking the [`Complex`] on the
+    /// right-hand side by reference.
+    ///
+    /// The precision of both parts of the output is the maximum of the precisions of the inputs'
+    /// parts, and each part is rounded to the nearest value of that precision. This is
+    /// [`Complex::sub_round_assign_ref`] with both rounding modes `Nearest`; see
+    /// [`Complex::sub_prec_round`] for error bounds and special cases.
+    ///
+    /// If you want to use other rounding modes, consider using [`Complex::sub_round_assign_ref`]
+    /// instead. If you want to specify the output precisions, consider using
+    /// [`Complex::sub_prec_assign_ref`]. If you want both of these things, consider using
+    /// [`Complex::sub_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, LN_2, PI, SQRT_2};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Complex::from((Float::from(PI), Float::from(E)));
+    /// let y = Complex::from((Float::from(SQRT_2), Float::from(-LN_2)));
+    /// let mut z = x.clone();
+    /// z -= &y;
+    /// assert_eq!(z.to_string(), "(1.7273790912166980 3.4114290090189905)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        let prec = max(self.max_prec(), other.max_prec());
+        self.sub_prec_round_assign_ref(other, (prec, prec), (Nearest, Nearest));
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+
+impl Complex {
+    /// Determines whether a [`Complex`] is NaN.
+    ///
+    /// Following Annex G of the C99 standard, a [`Complex`] is NaN if at least one of its parts is
+    /// NaN and neither part is infinite. A [`Complex`] with one infinite part is infinite, whatever
+    /// the other part is.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::NAN.is_nan(), true);
+    /// assert_eq!(Complex::ONE.is_nan(), false);
+    /// assert_eq!(Complex::from_real_imag(Float::ONE, Float::NAN).is_nan(), true);
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::INFINITY, Float::NAN).is_nan(),
+    ///     false
+    /// );
+    /// ```
+    #[inline]
+    pub const fn is_nan(&self) -> bool {
+        (self.real.is_nan() || self.imag.is_nan()) && !self.is_infinite()
+    }
+
+    /// Determines whether a [`Complex`] is finite, that is, whether both of its parts are finite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::NAN.is_finite(), false);
+    /// assert_eq!(Complex::ZERO.is_finite(), true);
+    /// assert_eq!(Complex::I.is_finite(), true);
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::ONE, Float::INFINITY).is_finite(),
+    ///     false
+    /// );
+    /// ```
+    #[inline]
+    pub const fn is_finite(&self) -> bool {
+        self.real.is_finite() && self.imag.is_finite()
+    }
+
+    /// Determines whether a [`Complex`] is infinite, that is, whether at least one of its parts is
+    /// infinite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::NAN.is_infinite(), false);
+    /// assert_eq!(Complex::ONE.is_infinite(), false);
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::NAN, Float::INFINITY).is_infinite(),
+    ///     true
+    /// );
+    /// ```
+    #[inline]
+    pub const fn is_infinite(&self) -> bool {
+        self.real.is_infinite() || self.imag.is_infinite()
+    }
+
+    /// Determines whether a [`Complex`] is zero, that is, whether both of its parts are zero. The
+    /// signs of the zeros don't matter.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeZero, One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::ZERO.is_zero(), true);
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::NEGATIVE_ZERO, Float::ZERO).is_zero(),
+    ///     true
+    /// );
+    /// assert_eq!(Complex::ONE.is_zero(), false);
+    /// assert_eq!(Complex::I.is_zero(), false);
+    /// ```
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.imag.is_zero()
+    }
+
+    /// Determines whether a [`Complex`] is real, that is, whether its imaginary part is zero. The
+    /// sign of the zero doesn't matter, and the real part may be anything, including NaN.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    ///
+    /// assert_eq!(Complex::ZERO.is_real(), true);
+    /// assert_eq!(Complex::ONE.is_real(), true);
+    /// assert_eq!(Complex::I.is_real(), false);
+    /// ```
+    #[inline]
+    pub const fn is_real(&self) -> bool {
+        self.imag.is_zero()
+    }
+
+    /// Determines whether a [`Complex`] is purely imaginary, that is, whether its real part is
+    /// zero. The sign of the zero doesn't matter, and the imaginary part may be anything, including
+    /// NaN.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    ///
+    /// assert_eq!(Complex::ZERO.is_imaginary(), true);
+    /// assert_eq!(Complex::ONE.is_imaginary(), false);
+    /// assert_eq!(Complex::I.is_imaginary(), true);
+    /// ```
+    #[inline]
+    pub const fn is_imaginary(&self) -> bool {
+        self.real.is_zero()
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use malachite_base::num::logic::traits::SignificantBits;
+
+impl Complex {
+    /// Determines a [`Complex`]'s complexity, the sum of the
+    /// [`complexity`](malachite_float::Float::complexity)s of its real and imaginary parts.
+    ///
+    /// Informally, the complexity is proportional to the number of characters you would need to
+    /// write the [`Complex`] out without using exponents.
+    ///
+    /// See also the [`Complex`] implementation of [`SignificantBits`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::{NaN, One};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::NAN.complexity(), 2);
+    /// assert_eq!(Complex::ONE.complexity(), 2);
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::one_prec(100), Float::power_of_2(-50i64)).complexity(),
+    ///     150
+    /// );
+    /// ```
+    pub fn complexity(&self) -> u64 {
+        self.real.complexity() + self.imag.complexity()
+    }
+}
+
+impl SignificantBits for &Complex {
+    /// Returns the number of significant bits of a [`Complex`], the sum of the numbers of
+    /// significant bits of its real and imaginary parts. A part without a precision counts as 1
+    /// bit.
+    ///
+    /// See also the [`complexity`](Complex::complexity) function.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One};
+    /// use malachite_base::num::logic::traits::SignificantBits;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::NAN.significant_bits(), 2);
+    /// assert_eq!(Complex::ONE.significant_bits(), 2);
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::one_prec(100), Float::one_prec(50)).significant_bits(),
+    ///     150
+    /// );
+    /// ```
+    fn significant_bits(self) -> u64 {
+        self.real.significant_bits() + self.imag.significant_bits()
+    }
+}
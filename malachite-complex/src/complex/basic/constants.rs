@@ -0,0 +1,66 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use malachite_base::num::basic::traits::{NaN, NegativeOne, One, Two, Zero};
+use malachite_float::Float;
+
+/// The constant 0.0 + 0.0i (both parts positive zero).
+impl Zero for Complex {
+    const ZERO: Self = Self {
+        real: Float::ZERO,
+        imag: Float::ZERO,
+    };
+}
+
+/// The constant 1.0 + 0.0i, with a real part of precision 1.
+impl One for Complex {
+    const ONE: Self = Self {
+        real: Float::ONE,
+        imag: Float::ZERO,
+    };
+}
+
+/// The constant 2.0 + 0.0i, with a real part of precision 1.
+impl Two for Complex {
+    const TWO: Self = Self {
+        real: Float::TWO,
+        imag: Float::ZERO,
+    };
+}
+
+/// The constant -1.0 + 0.0i, with a real part of precision 1.
+impl NegativeOne for Complex {
+    const NEGATIVE_ONE: Self = Self {
+        real: Float::NEGATIVE_ONE,
+        imag: Float::ZERO,
+    };
+}
+
+/// The constant NaN + NaNi.
+impl NaN for Complex {
+    const NAN: Self = Self {
+        real: Float::NAN,
+        imag: Float::NAN,
+    };
+}
+
+impl Default for Complex {
+    /// The default value of a [`Complex`], NaN + NaNi.
+    fn default() -> Self {
+        Self::NAN
+    }
+}
+
+impl Complex {
+    /// The imaginary unit, 0.0 + 1.0i, with an imaginary part of precision 1.
+    pub const I: Self = Self {
+        real: Float::ZERO,
+        imag: Float::ONE,
+    };
+}
@@ -0,0 +1,259 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+impl Complex {
+    /// Returns a reference to the real part of a [`Complex`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(*Complex::ONE.real_ref(), Float::ONE);
+    /// assert_eq!(*Complex::I.real_ref(), Float::ZERO);
+    /// ```
+    #[inline]
+    pub const fn real_ref(&self) -> &Float {
+        &self.real
+    }
+
+    /// Returns a reference to the imaginary part of a [`Complex`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(*Complex::ONE.imag_ref(), Float::ZERO);
+    /// assert_eq!(*Complex::I.imag_ref(), Float::ONE);
+    /// ```
+    #[inline]
+    pub const fn imag_ref(&self) -> &Float {
+        &self.imag
+    }
+
+    /// Returns a mutable reference to the real part of a [`Complex`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Two};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut z = Complex::ONE;
+    /// *z.real_mut() = Float::TWO;
+    /// assert_eq!(z, Complex::TWO);
+    /// ```
+    #[inline]
+    pub const fn real_mut(&mut self) -> &mut Float {
+        &mut self.real
+    }
+
+    /// Returns a mutable reference to the imaginary part of a [`Complex`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let mut z = Complex::ZERO;
+    /// *z.imag_mut() = Float::ONE;
+    /// assert_eq!(z, Complex::I);
+    /// ```
+    #[inline]
+    pub const fn imag_mut(&mut self) -> &mut Float {
+        &mut self.imag
+    }
+
+    /// Returns the real part of a [`Complex`], taking the [`Complex`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::ONE.into_real(), Float::ONE);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_real(self) -> Float {
+        self.real
+    }
+
+    /// Returns the imaginary part of a [`Complex`], taking the [`Complex`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::I.into_imag(), Float::ONE);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_imag(self) -> Float {
+        self.imag
+    }
+
+    /// Returns the real and imaginary parts of a [`Complex`], taking the [`Complex`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::I.into_real_imag(), (Float::ZERO, Float::ONE));
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_real_imag(self) -> (Float, Float) {
+        (self.real, self.imag)
+    }
+
+    /// Returns the precisions of the real and imaginary parts of a [`Complex`].
+    ///
+    /// As with [`Float::get_prec`], only parts that are finite and nonzero have a precision; for
+    /// other parts, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::NAN.get_prec(), (None, None));
+    /// assert_eq!(Complex::ONE.get_prec(), (Some(1), None));
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::one_prec(10), Float::from(1.5)).get_prec(),
+    ///     (Some(10), Some(2))
+    /// );
+    /// ```
+    #[inline]
+    pub const fn get_prec(&self) -> (Option<u64>, Option<u64>) {
+        (self.real.get_prec(), self.imag.get_prec())
+    }
+
+    /// Changes the precisions of the parts of a [`Complex`]. If a precision decreases, rounding may
+    /// be necessary, and will use the corresponding [`RoundingMode`].
+    ///
+    /// Returns a pair of [`Ordering`]s, indicating whether the final real and imaginary parts are
+    /// less than, greater than, or equal to the original ones.
+    ///
+    /// See [`Float::set_prec_round`] for details; each part is handled by that function.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger of the two precisions, and
+    /// $m$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is [`Exact`] but setting the
+    /// corresponding precision requires rounding.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut z = Complex::from_real_imag(Float::from(1.0f64 / 3.0), Float::from(2.0f64 / 3.0));
+    /// assert_eq!(z.set_prec_round((10, 5), (Floor, Ceiling)), (Less, Greater));
+    /// assert_eq!(z.to_string(), "(0.33301 0.688)");
+    /// assert_eq!(z.get_prec(), (Some(10), Some(5)));
+    /// ```
+    pub fn set_prec_round(
+        &mut self,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Ordering, Ordering) {
+        (
+            self.real.set_prec_round(prec.0, rm.0),
+            self.imag.set_prec_round(prec.1, rm.1),
+        )
+    }
+
+    /// Changes the precisions of the parts of a [`Complex`]. If a precision decreases, the
+    /// corresponding part is rounded to the nearest value of the new precision.
+    ///
+    /// Returns a pair of [`Ordering`]s, indicating whether the final real and imaginary parts are
+    /// less than, greater than, or equal to the original ones.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the larger of the two precisions, and
+    /// $m$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut z = Complex::from_real_imag(Float::from(1.0f64 / 3.0), Float::from(2.0f64 / 3.0));
+    /// assert_eq!(z.set_prec((10, 5)), (Greater, Less));
+    /// assert_eq!(z.to_string(), "(0.33350 0.656)");
+    /// ```
+    #[inline]
+    pub fn set_prec(&mut self, prec: (u64, u64)) -> (Ordering, Ordering) {
+        self.set_prec_round(prec, (Nearest, Nearest))
+    }
+
+    // The precision used for both parts of the output of a function that doesn't take a precision:
+    // the largest precision among the parts of the inputs that have one, or 1 if none do.
+    pub(crate) fn max_prec(&self) -> u64 {
+        core::cmp::max(
+            self.real.get_prec().unwrap_or(1),
+            self.imag.get_prec().unwrap_or(1),
+        )
+    }
+}
@@ -0,0 +1,16 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Classification of a [`Complex`](super::Complex) into several kinds.
+pub mod classification;
+/// Measuring the complexity of a [`Complex`](super::Complex).
+pub mod complexity;
+/// Various [`Complex`](super::Complex) constants.
+pub mod constants;
+/// Getting and setting the components of a [`Complex`](super::Complex).
+pub mod get_and_set;
@@ -0,0 +1,100 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{ComparableComplex, ComparableComplexRef};
+use core::cmp::Ordering;
+use malachite_float::ComparableFloatRef;
+
+impl Ord for ComparableComplexRef<'_> {
+    /// Compares two [`ComparableComplexRef`]s.
+    ///
+    /// The complex numbers are ordered lexicographically: first by their real parts, and then by
+    /// their imaginary parts, each compared as a
+    /// [`ComparableFloatRef`]. The order is total and
+    /// compatible with [`ComparableComplexRef`] equality, but has no arithmetic meaning; it exists
+    /// so that [`Complex`](crate::Complex)es can be kept in ordered collections.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_complex::{ComparableComplexRef, Complex};
+    ///
+    /// assert!(ComparableComplexRef(&Complex::ZERO) < ComparableComplexRef(&Complex::I));
+    /// assert!(ComparableComplexRef(&Complex::I) < ComparableComplexRef(&Complex::ONE));
+    /// assert!(ComparableComplexRef(&Complex::NAN) < ComparableComplexRef(&Complex::ZERO));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        ComparableFloatRef(&self.0.real)
+            .cmp(&ComparableFloatRef(&other.0.real))
+            .then_with(|| ComparableFloatRef(&self.0.imag).cmp(&ComparableFloatRef(&other.0.imag)))
+    }
+}
+
+impl PartialOrd for ComparableComplexRef<'_> {
+    /// Compares two [`ComparableComplexRef`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ComparableComplex {
+    /// Compares two [`ComparableComplex`]es.
+    ///
+    /// The complex numbers are ordered lexicographically: first by their real parts, and then by
+    /// their imaginary parts, each compared as a
+    /// [`ComparableFloat`](malachite_float::ComparableFloat). The order is total and compatible
+    /// with [`ComparableComplex`] equality, but has no arithmetic meaning; it exists so that
+    /// [`Complex`](crate::Complex)es can be kept in ordered collections.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_complex::{ComparableComplex, Complex};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(ComparableComplex(Complex::ZERO) < ComparableComplex(Complex::I));
+    /// assert!(ComparableComplex(Complex::I) < ComparableComplex(Complex::ONE));
+    /// assert!(
+    ///     ComparableComplex(Complex::ONE) < ComparableComplex(Complex::from(Float::one_prec(100)))
+    /// );
+    /// ```
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(&other.as_ref())
+    }
+}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for ComparableComplex {
+    /// Compares two [`ComparableComplex`]es.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.as_ref().cmp(&other.as_ref()))
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::ComparableFloatRef;
+
+impl PartialEq for Complex {
+    /// Compares two [`Complex`]es for equality.
+    ///
+    /// The real parts and the imaginary parts are compared as [`Float`](malachite_float::Float)s,
+    /// following the IEEE 754 standard. So a [`Complex`] with a `NaN` part is not equal to
+    /// anything, not even itself; positive zero is equal to negative zero; and parts with different
+    /// precisions are equal if they represent the same numeric value.
+    ///
+    /// For different equality behavior, consider using [`ComparableComplex`] or
+    /// [`ComparableComplexRef`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_ne!(Complex::NAN, Complex::NAN);
+    /// assert_ne!(
+    ///     Complex::from_real_imag(Float::ONE, Float::NAN),
+    ///     Complex::from_real_imag(Float::ONE, Float::NAN)
+    /// );
+    /// assert_eq!(Complex::ZERO, Complex::ZERO);
+    /// assert_eq!(
+    ///     Complex::ZERO,
+    ///     Complex::from_real_imag(Float::NEGATIVE_ZERO, Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!(Complex::ONE, Complex::ONE);
+    /// assert_ne!(Complex::ONE, Complex::I);
+    /// assert_eq!(Complex::ONE, Complex::from(Float::one_prec(100)));
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.real == other.real && self.imag == other.imag
+    }
+}
+
+impl PartialEq for ComparableComplex {
+    /// Compares two [`ComparableComplex`]es for equality.
+    ///
+    /// The real parts and the imaginary parts are compared as
+    /// [`ComparableFloat`](malachite_float::ComparableFloat)s. This equality respects the expected
+    /// properties of symmetry, reflexivity, and transitivity: `NaN` parts are equal to each other,
+    /// positive and negative zero parts are not, and parts with different precisions are unequal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One, Zero};
+    /// use malachite_complex::{ComparableComplex, Complex};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(ComparableComplex(Complex::NAN), ComparableComplex(Complex::NAN));
+    /// assert_eq!(ComparableComplex(Complex::ZERO), ComparableComplex(Complex::ZERO));
+    /// assert_ne!(
+    ///     ComparableComplex(Complex::ZERO),
+    ///     ComparableComplex(Complex::from_real_imag(Float::ZERO, Float::NEGATIVE_ZERO))
+    /// );
+    ///
+    /// assert_eq!(ComparableComplex(Complex::ONE), ComparableComplex(Complex::ONE));
+    /// assert_ne!(ComparableComplex(Complex::ONE), ComparableComplex(Complex::I));
+    /// assert_ne!(
+    ///     ComparableComplex(Complex::ONE),
+    ///     ComparableComplex(Complex::from(Float::one_prec(100)))
+    /// );
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for ComparableComplex {}
+
+impl<'a> PartialEq<ComparableComplexRef<'a>> for ComparableComplexRef<'_> {
+    /// Compares two [`ComparableComplexRef`]s for equality.
+    ///
+    /// See the documentation of the [`ComparableComplex`] implementation of [`PartialEq`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_complex::{ComparableComplexRef, Complex};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     ComparableComplexRef(&Complex::NAN),
+    ///     ComparableComplexRef(&Complex::NAN)
+    /// );
+    /// assert_ne!(
+    ///     ComparableComplexRef(&Complex::ONE),
+    ///     ComparableComplexRef(&Complex::from(Float::one_prec(100)))
+    /// );
+    /// ```
+    fn eq(&self, other: &ComparableComplexRef<'a>) -> bool {
+        ComparableFloatRef(&self.0.real) == ComparableFloatRef(&other.0.real)
+            && ComparableFloatRef(&self.0.imag) == ComparableFloatRef(&other.0.imag)
+    }
+}
+
+impl Eq for ComparableComplexRef<'_> {}
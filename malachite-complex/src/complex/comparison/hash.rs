@@ -0,0 +1,47 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{ComparableComplex, ComparableComplexRef};
+use core::hash::{Hash, Hasher};
+use malachite_float::ComparableFloatRef;
+
+impl Hash for ComparableComplex {
+    /// Computes a hash of a [`ComparableComplex`].
+    ///
+    /// The hash is compatible with [`ComparableComplex`] equality: all `NaN` parts hash to the same
+    /// value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state);
+    }
+}
+
+impl Hash for ComparableComplexRef<'_> {
+    /// Computes a hash of a [`ComparableComplexRef`].
+    ///
+    /// The hash is compatible with [`ComparableComplexRef`] equality: all `NaN` parts hash to the
+    /// same value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ComparableFloatRef(&self.0.real).hash(state);
+        ComparableFloatRef(&self.0.imag).hash(state);
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Comparison of [`ComparableComplex`](crate::ComparableComplex)s and
+/// [`ComparableComplexRef`](crate::ComparableComplexRef)s.
+pub mod cmp;
+/// Equality of [`Complex`](crate::Complex)es, [`ComparableComplex`](crate::ComparableComplex)es,
+/// and [`ComparableComplexRef`](crate::ComparableComplexRef)s.
+pub mod eq;
+/// Hashing of [`ComparableComplex`](crate::ComparableComplex)es and
+/// [`ComparableComplexRef`](crate::ComparableComplexRef)s.
+pub mod hash;
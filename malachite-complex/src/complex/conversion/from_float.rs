@@ -0,0 +1,433 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+
+impl Complex {
+    /// Creates a [`Complex`] from its real and imaginary parts, taking both [`Float`]s by value.
+    /// The parts keep their precisions.
+    ///
+    /// $f(x, y) = x + yi$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::ONE, Float::from(1.5)).to_string(),
+    ///     "(1.0 1.5)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::NAN, Float::ONE).to_string(),
+    ///     "(NaN 1.0)"
+    /// );
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn from_real_imag(real: Float, imag: Float) -> Self {
+        Self { real, imag }
+    }
+
+    /// Creates a [`Complex`] from its real and imaginary parts, taking both [`Float`]s by value
+    /// and rounding them to the specified precisions with the specified rounding modes. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the original ones.
+    ///
+    /// Each part is rounded as by [`Float::from_float_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part cannot be represented exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_real_imag_prec_round(
+    ///     Float::from(std::f64::consts::PI),
+    ///     Float::from(std::f64::consts::E),
+    ///     (10, 5),
+    ///     (Floor, Ceiling),
+    /// );
+    /// assert_eq!(z.to_string(), "(3.1406 2.75)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    pub fn from_real_imag_prec_round(
+        real: Float,
+        imag: Float,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let (real, o_real) = Float::from_float_prec_round(real, prec.0, rm.0);
+        let (imag, o_imag) = Float::from_float_prec_round(imag, prec.1, rm.1);
+        (Self { real, imag }, (o_real, o_imag))
+    }
+
+    /// Creates a [`Complex`] from its real and imaginary parts, taking both [`Float`]s by reference
+    /// and rounding them to the specified precisions with the specified rounding modes. A pair of
+    /// [`Ordering`]s is also returned, indicating whether the rounded real and imaginary parts are
+    /// less than, equal to, or greater than the original ones.
+    ///
+    /// Each part is rounded as by [`Float::from_float_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is `Exact` but the corresponding
+    /// part cannot be represented exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_real_imag_prec_round_ref(
+    ///     &Float::from(std::f64::consts::PI),
+    ///     &Float::from(std::f64::consts::E),
+    ///     (10, 5),
+    ///     (Floor, Ceiling),
+    /// );
+    /// assert_eq!(z.to_string(), "(3.1406 2.75)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    pub fn from_real_imag_prec_round_ref(
+        real: &Float,
+        imag: &Float,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let (real, o_real) = Float::from_float_prec_round_ref(real, prec.0, rm.0);
+        let (imag, o_imag) = Float::from_float_prec_round_ref(imag, prec.1, rm.1);
+        (Self { real, imag }, (o_real, o_imag))
+    }
+
+    /// Creates a [`Complex`] from its real and imaginary parts, taking both [`Float`]s by value
+    /// and rounding them to the nearest values of the specified precisions. A pair of [`Ordering`]s
+    /// is also returned, indicating whether the rounded real and imaginary parts are less than,
+    /// equal to, or greater than the original ones.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_real_imag_prec(
+    ///     Float::from(std::f64::consts::PI),
+    ///     Float::from(std::f64::consts::E),
+    ///     (10, 5),
+    /// );
+    /// assert_eq!(z.to_string(), "(3.1406 2.75)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn from_real_imag_prec(
+        real: Float,
+        imag: Float,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        Self::from_real_imag_prec_round(real, imag, prec, (Nearest, Nearest))
+    }
+
+    /// Creates a [`Complex`] from its real and imaginary parts, taking both [`Float`]s by reference
+    /// and rounding them to the nearest values of the specified precisions. A pair of [`Ordering`]s
+    /// is also returned, indicating whether the rounded real and imaginary parts are less than,
+    /// equal to, or greater than the original ones.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_real_imag_prec_ref(
+    ///     &Float::from(std::f64::consts::PI),
+    ///     &Float::from(std::f64::consts::E),
+    ///     (10, 5),
+    /// );
+    /// assert_eq!(z.to_string(), "(3.1406 2.75)");
+    /// assert_eq!(o, (Less, Greater));
+    /// ```
+    #[inline]
+    pub fn from_real_imag_prec_ref(
+        real: &Float,
+        imag: &Float,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        Self::from_real_imag_prec_round_ref(real, imag, prec, (Nearest, Nearest))
+    }
+
+    /// Converts a [`Float`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Float`] by value and rounding it to the specified precision with the specified rounding
+    /// mode. An [`Ordering`] is also returned, indicating whether the real part is less than, equal
+    /// to, or greater than the original [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the [`Float`] cannot be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_float_prec_round(Float::from(std::f64::consts::PI), 10, Ceiling);
+    /// assert_eq!(z.to_string(), "(3.1445 0.0)");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn from_float_prec_round(x: Float, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let (real, o) = Float::from_float_prec_round(x, prec, rm);
+        (
+            Self {
+                real,
+                imag: Float::ZERO,
+            },
+            o,
+        )
+    }
+
+    /// Converts a [`Float`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Float`] by reference and rounding it to the specified precision with the specified
+    /// rounding mode. An [`Ordering`] is also returned, indicating whether the real part is less
+    /// than, equal to, or greater than the original [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the [`Float`] cannot be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) =
+    ///     Complex::from_float_prec_round_ref(&Float::from(std::f64::consts::PI), 10, Ceiling);
+    /// assert_eq!(z.to_string(), "(3.1445 0.0)");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn from_float_prec_round_ref(x: &Float, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let (real, o) = Float::from_float_prec_round_ref(x, prec, rm);
+        (
+            Self {
+                real,
+                imag: Float::ZERO,
+            },
+            o,
+        )
+    }
+
+    /// Converts a [`Float`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Float`] by value and rounding it to the nearest value of the specified precision. An
+    /// [`Ordering`] is also returned, indicating whether the real part is less than, equal to, or
+    /// greater than the original [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_float_prec(Float::from(std::f64::consts::PI), 10);
+    /// assert_eq!(z.to_string(), "(3.1406 0.0)");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn from_float_prec(x: Float, prec: u64) -> (Self, Ordering) {
+        Self::from_float_prec_round(x, prec, Nearest)
+    }
+
+    /// Converts a [`Float`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Float`] by reference and rounding it to the nearest value of the specified precision. An
+    /// [`Ordering`] is also returned, indicating whether the real part is less than, equal to, or
+    /// greater than the original [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_float_prec_ref(&Float::from(std::f64::consts::PI), 10);
+    /// assert_eq!(z.to_string(), "(3.1406 0.0)");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn from_float_prec_ref(x: &Float, prec: u64) -> (Self, Ordering) {
+        Self::from_float_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl From<Float> for Complex {
+    /// Converts a [`Float`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Float`] by value. The real part keeps the [`Float`]'s precision.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NegativeZero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::from(Float::from(1.5)).to_string(), "(1.5 0.0)");
+    /// assert_eq!(
+    ///     Complex::from(Float::NEGATIVE_ZERO).to_string(),
+    ///     "(-0.0 0.0)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::from(Float::INFINITY).to_string(),
+    ///     "(Infinity 0.0)"
+    /// );
+    /// ```
+    #[inline]
+    fn from(x: Float) -> Self {
+        Self {
+            real: x,
+            imag: Float::ZERO,
+        }
+    }
+}
+
+impl From<&Float> for Complex {
+    /// Converts a [`Float`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Float`] by reference. The real part keeps the [`Float`]'s precision.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::from(&Float::from(1.5)).to_string(), "(1.5 0.0)");
+    /// ```
+    #[inline]
+    fn from(x: &Float) -> Self {
+        Self {
+            real: x.clone(),
+            imag: Float::ZERO,
+        }
+    }
+}
+
+impl From<(Float, Float)> for Complex {
+    /// Converts a pair of [`Float`]s, the real and imaginary parts, to a [`Complex`]. The parts
+    /// keep their precisions.
+    ///
+    /// This is the same as [`Complex::from_real_imag`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Complex::from((Float::ONE, Float::from(-1.5))).to_string(),
+    ///     "(1.0 -1.5)"
+    /// );
+    /// ```
+    #[inline]
+    fn from((real, imag): (Float, Float)) -> Self {
+        Self { real, imag }
+    }
+}
+
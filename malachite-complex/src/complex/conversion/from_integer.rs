@@ -0,0 +1,301 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_q::rational::conversion::primitive_float_from_rational::FloatConversionError;
+
+impl Complex {
+    /// Converts an [`Integer`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Integer`] by value. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, the specified rounding mode is used. An [`Ordering`] is also returned,
+    /// indicating whether the real part is less than, equal to, or greater than the original
+    /// value.
+    ///
+    /// The real part is computed by [`Float::from_integer_prec_round`], and overflows in the same
+    /// way.
+    ///
+    /// If you're only using [`Nearest`], try using [`Complex::from_integer_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(m,n) = O(\max(m,n))$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $m$ is `x.significant_bits()`, and $n$ is
+    /// `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is exact and the [`Integer`] cannot be exactly
+    /// represented with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_integer_prec_round(Integer::from(-123), 20, Exact);
+    /// assert_eq!(z.to_string(), "(-123.00000 0.0)");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (z, o) = Complex::from_integer_prec_round(Integer::from(-123), 4, Floor);
+    /// assert_eq!(z.to_string(), "(-128.0 0.0)");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = Complex::from_integer_prec_round(Integer::from(-123), 4, Ceiling);
+    /// assert_eq!(z.to_string(), "(-120.0 0.0)");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn from_integer_prec_round(x: Integer, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let (real, o) = Float::from_integer_prec_round(x, prec, rm);
+        (
+            Self {
+                real,
+                imag: Float::ZERO,
+            },
+            o,
+        )
+    }
+
+    /// Converts an [`Integer`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Integer`] by reference. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, the specified rounding mode is used. An [`Ordering`] is also returned,
+    /// indicating whether the real part is less than, equal to, or greater than the original
+    /// value.
+    ///
+    /// The real part is computed by [`Float::from_integer_prec_round_ref`], and overflows in the
+    /// same way.
+    ///
+    /// If you're only using [`Nearest`], try using [`Complex::from_integer_prec_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(m,n) = O(\max(m,n))$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $m$ is `x.significant_bits()`, and $n$ is
+    /// `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is exact and the [`Integer`] cannot be exactly
+    /// represented with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_integer_prec_round_ref(&Integer::from(-123), 20, Exact);
+    /// assert_eq!(z.to_string(), "(-123.00000 0.0)");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (z, o) = Complex::from_integer_prec_round_ref(&Integer::from(-123), 4, Floor);
+    /// assert_eq!(z.to_string(), "(-128.0 0.0)");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = Complex::from_integer_prec_round_ref(&Integer::from(-123), 4, Ceiling);
+    /// assert_eq!(z.to_string(), "(-120.0 0.0)");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn from_integer_prec_round_ref(
+        x: &Integer,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let (real, o) = Float::from_integer_prec_round_ref(x, prec, rm);
+        (
+            Self {
+                real,
+                imag: Float::ZERO,
+            },
+            o,
+        )
+    }
+
+    /// Converts an [`Integer`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Integer`] by value. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, [`Nearest`] is used. An [`Ordering`] is also returned, indicating
+    /// whether the real part is less than, equal to, or greater than the original value.
+    ///
+    /// If you want the real part to be rounded with a different rounding mode, try
+    /// [`Complex::from_integer_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(m,n) = O(\max(m,n))$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $m$ is `x.significant_bits()`, and $n$ is
+    /// `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_integer_prec(Integer::from(123), 4);
+    /// assert_eq!(z.to_string(), "(120.0 0.0)");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn from_integer_prec(x: Integer, prec: u64) -> (Self, Ordering) {
+        Self::from_integer_prec_round(x, prec, Nearest)
+    }
+
+    /// Converts an [`Integer`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Integer`] by reference. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, [`Nearest`] is used. An [`Ordering`] is also returned, indicating
+    /// whether the real part is less than, equal to, or greater than the original value.
+    ///
+    /// If you want the real part to be rounded with a different rounding mode, try
+    /// [`Complex::from_integer_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(m,n) = O(\max(m,n))$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $m$ is `x.significant_bits()`, and $n$ is
+    /// `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_nz::integer::Integer;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_integer_prec_ref(&Integer::from(123), 4);
+    /// assert_eq!(z.to_string(), "(120.0 0.0)");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn from_integer_prec_ref(x: &Integer, prec: u64) -> (Self, Ordering) {
+        Self::from_integer_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl TryFrom<Integer> for Complex {
+    type Error = FloatConversionError;
+
+    /// Converts an [`Integer`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Integer`] by value.
+    ///
+    /// If the [`Integer`] is nonzero, the precision of the real part is the minimum possible
+    /// precision to represent the [`Integer`] exactly. If the absolute value of the [`Integer`] is
+    /// greater than or equal to $2^{2^{30}-1}$, this function returns an overflow error.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_complex::Complex;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Complex::try_from(Integer::ZERO).unwrap().to_string(),
+    ///     "(0.0 0.0)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::try_from(Integer::from(-123)).unwrap().to_string(),
+    ///     "(-123.0 0.0)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::try_from(Integer::from(-123)).unwrap().get_prec(),
+    ///     (Some(7), None)
+    /// );
+    /// ```
+    #[inline]
+    fn try_from(x: Integer) -> Result<Self, Self::Error> {
+        Float::try_from(x).map(Self::from)
+    }
+}
+
+impl TryFrom<&Integer> for Complex {
+    type Error = FloatConversionError;
+
+    /// Converts an [`Integer`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Integer`] by reference.
+    ///
+    /// If the [`Integer`] is nonzero, the precision of the real part is the minimum possible
+    /// precision to represent the [`Integer`] exactly. If the absolute value of the [`Integer`] is
+    /// greater than or equal to $2^{2^{30}-1}$, this function returns an overflow error.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Complex::try_from(&Integer::from(10)).unwrap().to_string(),
+    ///     "(10.0 0.0)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::try_from(&Integer::from(10)).unwrap().get_prec(),
+    ///     (Some(3), None)
+    /// );
+    /// ```
+    #[inline]
+    fn try_from(x: &Integer) -> Result<Self, Self::Error> {
+        Float::try_from(x).map(Self::from)
+    }
+}
+
+impl ConvertibleFrom<&Integer> for Complex {
+    /// Determines whether an [`Integer`] can be converted to a [`Complex`], taking the [`Integer`]
+    /// by reference.
+    ///
+    /// The [`Integer`]s that are convertible to [`Complex`]es are those whose absolute values are
+    /// less than $2^{2^{30}-1}$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_complex::Complex;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Complex::convertible_from(&Integer::ZERO), true);
+    /// assert_eq!(Complex::convertible_from(&Integer::from(-123)), true);
+    /// ```
+    #[inline]
+    fn convertible_from(x: &Integer) -> bool {
+        Float::convertible_from(x)
+    }
+}
@@ -0,0 +1,492 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+use malachite_q::Rational;
+use malachite_q::rational::conversion::primitive_float_from_rational::FloatConversionError;
+
+impl Complex {
+    /// Converts a [`Rational`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Rational`] by value. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, the specified rounding mode is used. An [`Ordering`] is also returned,
+    /// indicating whether the real part is less than, equal to, or greater than the original
+    /// value.
+    ///
+    /// The real part is computed by [`Float::from_rational_prec_round`], and overflows and
+    /// underflows in the same way.
+    ///
+    /// If you're only using [`Nearest`], try using [`Complex::from_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is exact and the [`Rational`] cannot be exactly
+    /// represented with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_rational_prec_round(Rational::from_signeds(1, 3), 10, Floor);
+    /// assert_eq!(z.to_string(), "(0.33301 0.0)");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) = Complex::from_rational_prec_round(Rational::from_signeds(1, 3), 10, Ceiling);
+    /// assert_eq!(z.to_string(), "(0.33350 0.0)");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn from_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let (real, o) = Float::from_rational_prec_round(x, prec, rm);
+        (
+            Self {
+                real,
+                imag: Float::ZERO,
+            },
+            o,
+        )
+    }
+
+    /// Converts a [`Rational`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Rational`] by reference. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, the specified rounding mode is used. An [`Ordering`] is also returned,
+    /// indicating whether the real part is less than, equal to, or greater than the original
+    /// value.
+    ///
+    /// The real part is computed by [`Float::from_rational_prec_round_ref`], and overflows and
+    /// underflows in the same way.
+    ///
+    /// If you're only using [`Nearest`], try using [`Complex::from_rational_prec_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is exact and the [`Rational`] cannot be exactly
+    /// represented with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) =
+    ///     Complex::from_rational_prec_round_ref(&Rational::from_signeds(-1, 3), 10, Floor);
+    /// assert_eq!(z.to_string(), "(-0.33350 0.0)");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (z, o) =
+    ///     Complex::from_rational_prec_round_ref(&Rational::from_signeds(-1, 3), 10, Ceiling);
+    /// assert_eq!(z.to_string(), "(-0.33301 0.0)");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn from_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let (real, o) = Float::from_rational_prec_round_ref(x, prec, rm);
+        (
+            Self {
+                real,
+                imag: Float::ZERO,
+            },
+            o,
+        )
+    }
+
+    /// Converts a [`Rational`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Rational`] by value. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, [`Nearest`] is used. An [`Ordering`] is also returned, indicating
+    /// whether the real part is less than, equal to, or greater than the original value.
+    ///
+    /// If you want the real part to be rounded with a different rounding mode, try
+    /// [`Complex::from_rational_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// assert_eq!(z.to_string(), "(0.33350 0.0)");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn from_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::from_rational_prec_round(x, prec, Nearest)
+    }
+
+    /// Converts a [`Rational`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Rational`] by reference. If the real part is nonzero, it has the specified precision. If
+    /// rounding is needed, [`Nearest`] is used. An [`Ordering`] is also returned, indicating
+    /// whether the real part is less than, equal to, or greater than the original value.
+    ///
+    /// If you want the real part to be rounded with a different rounding mode, try
+    /// [`Complex::from_rational_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_rational_prec_ref(&Rational::from_signeds(-1, 3), 10);
+    /// assert_eq!(z.to_string(), "(-0.33350 0.0)");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn from_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::from_rational_prec_round_ref(x, prec, Nearest)
+    }
+
+    /// Converts a pair of [`Rational`]s to a [`Complex`] whose real part is rounded from the first
+    /// and whose imaginary part is rounded from the second, taking both [`Rational`]s by value. The
+    /// nonzero parts have the specified precisions, and are rounded using the specified rounding
+    /// modes. A pair of [`Ordering`]s is also returned, indicating whether the real and imaginary
+    /// parts are less than, equal to, or greater than the original values.
+    ///
+    /// Each part is computed by [`Float::from_rational_prec_round`], and overflows and underflows
+    /// in the same way.
+    ///
+    /// If you're only using [`Nearest`], try using [`Complex::from_rationals_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(re.significant_bits(),
+    /// im.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is [`Exact`] and the
+    /// corresponding [`Rational`] cannot be exactly represented with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_rationals_prec_round(
+    ///     Rational::from_signeds(1, 3),
+    ///     Rational::from_signeds(3, 4),
+    ///     (10, 5),
+    ///     (Floor, Exact),
+    /// );
+    /// assert_eq!(z.to_string(), "(0.33301 0.750)");
+    /// assert_eq!(o, (Less, Equal));
+    /// ```
+    pub fn from_rationals_prec_round(
+        re: Rational,
+        im: Rational,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let (real, o_real) = Float::from_rational_prec_round(re, prec.0, rm.0);
+        let (imag, o_imag) = Float::from_rational_prec_round(im, prec.1, rm.1);
+        (Self { real, imag }, (o_real, o_imag))
+    }
+
+    /// Converts a pair of [`Rational`]s to a [`Complex`] whose real part is rounded from the first
+    /// and whose imaginary part is rounded from the second, taking both [`Rational`]s by reference.
+    /// The nonzero parts have the specified precisions, and are rounded using the specified
+    /// rounding modes. A pair of [`Ordering`]s is also returned, indicating whether the real and
+    /// imaginary parts are less than, equal to, or greater than the original values.
+    ///
+    /// Each part is computed by [`Float::from_rational_prec_round_ref`], and overflows and
+    /// underflows in the same way.
+    ///
+    /// If you're only using [`Nearest`], try using [`Complex::from_rationals_prec_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(re.significant_bits(),
+    /// im.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero, or if a rounding mode is [`Exact`] and the
+    /// corresponding [`Rational`] cannot be exactly represented with the corresponding precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_rationals_prec_round_ref(
+    ///     &Rational::from_signeds(1, 3),
+    ///     &Rational::from_signeds(-1, 3),
+    ///     (10, 10),
+    ///     (Ceiling, Ceiling),
+    /// );
+    /// assert_eq!(z.to_string(), "(0.33350 -0.33301)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    pub fn from_rationals_prec_round_ref(
+        re: &Rational,
+        im: &Rational,
+        prec: (u64, u64),
+        rm: (RoundingMode, RoundingMode),
+    ) -> (Self, (Ordering, Ordering)) {
+        let (real, o_real) = Float::from_rational_prec_round_ref(re, prec.0, rm.0);
+        let (imag, o_imag) = Float::from_rational_prec_round_ref(im, prec.1, rm.1);
+        (Self { real, imag }, (o_real, o_imag))
+    }
+
+    /// Converts a pair of [`Rational`]s to a [`Complex`] whose real part is rounded from the first
+    /// and whose imaginary part is rounded from the second, taking both [`Rational`]s by value. The
+    /// nonzero parts have the specified precisions, and are rounded to the nearest representable
+    /// values. A pair of [`Ordering`]s is also returned, indicating whether the real and imaginary
+    /// parts are less than, equal to, or greater than the original values.
+    ///
+    /// If you want the parts to be rounded with different rounding modes, try
+    /// [`Complex::from_rationals_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(re.significant_bits(),
+    /// im.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_rationals_prec(
+    ///     Rational::from_signeds(1, 3),
+    ///     Rational::from_signeds(2, 3),
+    ///     (10, 10),
+    /// );
+    /// assert_eq!(z.to_string(), "(0.33350 0.66699)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn from_rationals_prec(
+        re: Rational,
+        im: Rational,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        Self::from_rationals_prec_round(re, im, prec, (Nearest, Nearest))
+    }
+
+    /// Converts a pair of [`Rational`]s to a [`Complex`] whose real part is rounded from the first
+    /// and whose imaginary part is rounded from the second, taking both [`Rational`]s by reference.
+    /// The nonzero parts have the specified precisions, and are rounded to the nearest
+    /// representable values. A pair of [`Ordering`]s is also returned, indicating whether the real
+    /// and imaginary parts are less than, equal to, or greater than the original values.
+    ///
+    /// If you want the parts to be rounded with different rounding modes, try
+    /// [`Complex::from_rationals_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(re.significant_bits(),
+    /// im.significant_bits(), prec.0, prec.1)`.
+    ///
+    /// # Panics
+    /// Panics if either precision is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (z, o) = Complex::from_rationals_prec_ref(
+    ///     &Rational::from_signeds(1, 3),
+    ///     &Rational::from_signeds(2, 3),
+    ///     (10, 10),
+    /// );
+    /// assert_eq!(z.to_string(), "(0.33350 0.66699)");
+    /// assert_eq!(o, (Greater, Greater));
+    /// ```
+    #[inline]
+    pub fn from_rationals_prec_ref(
+        re: &Rational,
+        im: &Rational,
+        prec: (u64, u64),
+    ) -> (Self, (Ordering, Ordering)) {
+        Self::from_rationals_prec_round_ref(re, im, prec, (Nearest, Nearest))
+    }
+}
+
+impl TryFrom<Rational> for Complex {
+    type Error = FloatConversionError;
+
+    /// Converts a [`Rational`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Rational`] by value. If the [`Rational`]'s denominator is not a power of 2, or if the
+    /// [`Rational`] is too far from zero or too close to zero to be represented as a [`Float`], an
+    /// error is returned.
+    ///
+    /// The precision of the real part is the minimum number of bits needed to exactly represent
+    /// the [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_q::rational::conversion::primitive_float_from_rational::FloatConversionError;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Complex::try_from(Rational::from_signeds(-1, 8))
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "(-0.12 0.0)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::try_from(Rational::from_signeds(1, 3)).unwrap_err(),
+    ///     FloatConversionError::Inexact
+    /// );
+    /// ```
+    #[inline]
+    fn try_from(x: Rational) -> Result<Self, Self::Error> {
+        Float::try_from(x).map(Self::from)
+    }
+}
+
+impl TryFrom<&Rational> for Complex {
+    type Error = FloatConversionError;
+
+    /// Converts a [`Rational`] to a [`Complex`] with an imaginary part of positive zero, taking the
+    /// [`Rational`] by reference. If the [`Rational`]'s denominator is not a power of 2, or if the
+    /// [`Rational`] is too far from zero or too close to zero to be represented as a [`Float`], an
+    /// error is returned.
+    ///
+    /// The precision of the real part is the minimum number of bits needed to exactly represent
+    /// the [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use malachite_q::rational::conversion::primitive_float_from_rational::FloatConversionError;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Complex::try_from(&Rational::from_signeds(3, 8))
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "(0.38 0.0)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::try_from(&Rational::from_signeds(-1, 3)).unwrap_err(),
+    ///     FloatConversionError::Inexact
+    /// );
+    /// ```
+    #[inline]
+    fn try_from(x: &Rational) -> Result<Self, Self::Error> {
+        Float::try_from(x).map(Self::from)
+    }
+}
+
+impl ConvertibleFrom<&Rational> for Complex {
+    /// Determines whether a [`Rational`] can be converted to a [`Complex`], taking the [`Rational`]
+    /// by reference.
+    ///
+    /// The [`Rational`]s that are convertible to [`Complex`]es are those whose denominators are
+    /// powers of 2, and whose absolute values are either zero, or greater than or equal to
+    /// $2^{-2^{30}}$ and less than $2^{2^{30}-1}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_complex::Complex;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Complex::convertible_from(&Rational::from_signeds(3, 8)),
+    ///     true
+    /// );
+    /// assert_eq!(
+    ///     Complex::convertible_from(&Rational::from_signeds(1, 3)),
+    ///     false
+    /// );
+    /// ```
+    #[inline]
+    fn convertible_from(x: &Rational) -> bool {
+        Float::convertible_from(x)
+    }
+}
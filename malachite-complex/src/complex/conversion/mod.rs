@@ -0,0 +1,18 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Functions for creating a [`Complex`](crate::Complex) from [`Float`](malachite_float::Float)s.
+pub mod from_float;
+/// Functions for converting an [`Integer`](malachite_nz::integer::Integer) to a
+/// [`Complex`](crate::Complex).
+pub mod from_integer;
+/// Functions for converting a [`Rational`](malachite_q::Rational), or a pair of them, to a
+/// [`Complex`](crate::Complex).
+pub mod from_rational;
+/// Functions for converting [`Complex`](crate::Complex)es to and from strings.
+pub mod string;
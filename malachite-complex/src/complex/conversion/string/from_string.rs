@@ -0,0 +1,151 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+//
+// The inverse of `to_string.rs`. As in MPC's `mpc_strtoc`, a complex number is either a single
+// real number, whose imaginary part is then +0, or two numbers in parentheses separated by
+// whitespace. Each number is parsed by `Float`'s `FromStringBase`, so the `#` precision suffix that
+// `ComparableComplex` writes is accepted on either part.
+
+use crate::{ComparableComplex, Complex};
+use core::str::FromStr;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::FromStringBase;
+use malachite_float::Float;
+
+fn complex_from_string_base(base: u8, s: &str) -> Option<Complex> {
+    assert!((2..=36).contains(&base), "base out of range");
+    if let Some(inner) = s.strip_prefix('(') {
+        let inner = inner.strip_suffix(')')?;
+        let mut parts = inner.split_ascii_whitespace();
+        let real = Float::from_string_base(base, parts.next()?)?;
+        let imag = Float::from_string_base(base, parts.next()?)?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Complex { real, imag })
+    } else {
+        Some(Complex {
+            real: Float::from_string_base(base, s)?,
+            imag: Float::ZERO,
+        })
+    }
+}
+
+impl FromStringBase for Complex {
+    /// Converts a string, in a specified base, to a [`Complex`].
+    ///
+    /// The string is either `"(re im)"`, where `re` and `im` are separated by ASCII whitespace, or
+    /// a lone `re`, in which case the imaginary part is positive zero. Each of `re` and `im` is read
+    /// by [`Float`]'s
+    /// [`from_string_base`](malachite_base::num::conversion::traits::FromStringBase::from_string_base),
+    /// so this accepts what [`Complex`] and [`ComparableComplex`] write, including the `#`
+    /// precision suffix that makes the round trip exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::FromStringBase;
+    /// use malachite_complex::Complex;
+    ///
+    /// let s = |base, s| Complex::from_string_base(base, s).map(|z| z.to_string());
+    ///
+    /// assert_eq!(s(10, "(1.5#10 -2.0)"), Some("(1.5000 -2.0)".to_string()));
+    /// assert_eq!(s(16, "(0xff.0#8 1.8)"), Some("(255.0 1.5)".to_string()));
+    /// assert_eq!(s(10, "-0.5"), Some("(-0.50 0.0)".to_string()));
+    /// assert_eq!(s(10, "(NaN -Infinity)"), Some("(NaN -Infinity)".to_string()));
+    ///
+    /// assert_eq!(s(10, "(1.0)"), None);
+    /// assert_eq!(s(10, "(1.0 2.0 3.0)"), None);
+    /// assert_eq!(s(10, "(1.0 2.0"), None);
+    /// ```
+    #[inline]
+    fn from_string_base(base: u8, s: &str) -> Option<Self> {
+        complex_from_string_base(base, s)
+    }
+}
+
+impl FromStr for Complex {
+    type Err = ();
+
+    /// Converts a string to a [`Complex`].
+    ///
+    /// This is [`from_string_base`](Complex::from_string_base) in base 10, so it reads what
+    /// [`Display`](std::fmt::Display) writes, and also accepts the `#` precision suffixes that
+    /// [`ComparableComplex`]'s [`Display`](std::fmt::Display) adds. Without those suffixes the
+    /// precisions are inferred from the digits.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::Complex;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Complex::from_str("(1.5#10 -0.0)").unwrap().to_string(),
+    ///     "(1.5000 -0.0)"
+    /// );
+    /// assert_eq!(Complex::from_str("3.0").unwrap().to_string(), "(3.0 0.0)");
+    /// assert!(Complex::from_str("(abc 1.0)").is_err());
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ()> {
+        complex_from_string_base(10, s).ok_or(())
+    }
+}
+
+impl FromStr for ComparableComplex {
+    type Err = ();
+
+    /// Converts a string to a [`ComparableComplex`].
+    ///
+    /// This is [`Complex`]'s [`FromStr`] with the result wrapped, so it accepts exactly the same
+    /// strings. It is the inverse of [`ComparableComplex`]'s [`Display`](std::fmt::Display), which
+    /// writes the `#` precision suffixes, so the round trip preserves both precisions.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::{ComparableComplex, Complex};
+    /// use malachite_float::Float;
+    /// use std::str::FromStr;
+    ///
+    /// let z = ComparableComplex(Complex::from_real_imag(
+    ///     Float::from(1.5),
+    ///     Float::one_prec(20),
+    /// ));
+    /// assert_eq!(z.to_string(), "(1.5#2 1.0000000#20)");
+    /// assert_eq!(ComparableComplex::from_str(&z.to_string()).unwrap(), z);
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, ()> {
+        complex_from_string_base(10, s)
+            .map(ComparableComplex)
+            .ok_or(())
+    }
+}
@@ -0,0 +1,16 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Implementations of [`FromStr`](core::str::FromStr) and
+/// [`FromStringBase`](malachite_base::num::conversion::traits::FromStringBase) for
+/// [`Complex`](crate::Complex).
+pub mod from_string;
+/// Implementations of [`Display`](core::fmt::Display), [`Debug`](core::fmt::Debug), and the other
+/// formatting traits, and of [`ToStringBase`](malachite_base::num::conversion::traits::ToStringBase),
+/// for [`Complex`](crate::Complex).
+pub mod to_string;
@@ -0,0 +1,414 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+//
+// A `Complex` is written the way MPC's `mpc_get_str` writes one: the two parts in parentheses,
+// separated by a single space. Each part is written exactly as `Float` writes it, so everything
+// `Float`'s formats promise about digit counts, special values, and signed zeros carries over.
+
+use crate::{ComparableComplex, ComparableComplexRef, Complex};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::{Debug, Display, Formatter, LowerHex, Result, UpperHex};
+use malachite_base::num::conversion::traits::ToStringBase;
+use malachite_float::ComparableFloatRef;
+
+impl ToStringBase for Complex {
+    /// Converts a [`Complex`] to a [`String`] using a specified base.
+    ///
+    /// The output is `"(re im)"`, where `re` and `im` are the real and imaginary parts written by
+    /// [`Float`](malachite_float::Float)'s
+    /// [`to_string_base`](malachite_base::num::conversion::traits::ToStringBase::to_string_base).
+    /// Digits from 10 to 35 become the lowercase [`char`]s `'a'` to `'z'`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.complexity()`.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ToStringBase;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::from_real_imag(Float::from(255), Float::from(-1.5));
+    /// assert_eq!(z.to_string_base(10), "(255.0 -1.5)");
+    /// assert_eq!(z.to_string_base(16), "(ff.0 -1.8)");
+    /// assert_eq!(z.to_string_base(2), "(11111111.0 -1.1)");
+    /// ```
+    fn to_string_base(&self, base: u8) -> String {
+        format!(
+            "({} {})",
+            self.real.to_string_base(base),
+            self.imag.to_string_base(base)
+        )
+    }
+
+    /// Converts a [`Complex`] to a [`String`] using a specified base, with digits being uppercase.
+    ///
+    /// The output is `"(re im)"`, where `re` and `im` are the real and imaginary parts written by
+    /// [`Float`](malachite_float::Float)'s
+    /// [`to_string_base_upper`](malachite_base::num::conversion::traits::ToStringBase::to_string_base_upper).
+    /// Digits from 10 to 35 become the uppercase [`char`]s `'A'` to `'Z'`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.complexity()`.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ToStringBase;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::from_real_imag(Float::from(255), Float::from(-1.5));
+    /// assert_eq!(z.to_string_base_upper(16), "(FF.0 -1.8)");
+    /// ```
+    fn to_string_base_upper(&self, base: u8) -> String {
+        format!(
+            "({} {})",
+            self.real.to_string_base_upper(base),
+            self.imag.to_string_base_upper(base)
+        )
+    }
+}
+
+impl Display for Complex {
+    /// Converts a [`Complex`] to a [`String`].
+    ///
+    /// The output is `"(re im)"`, where `re` and `im` are the real and imaginary parts written by
+    /// [`Float`](malachite_float::Float)'s [`Display`]. In particular, each part has as many digits
+    /// as its own precision calls for, and special values and zeros are written as `NaN`,
+    /// `Infinity`, `-Infinity`, `0.0`, and `-0.0`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One, Zero};
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Complex::NAN.to_string(), "(NaN NaN)");
+    /// assert_eq!(Complex::ZERO.to_string(), "(0.0 0.0)");
+    /// assert_eq!(Complex::ONE.to_string(), "(1.0 0.0)");
+    /// assert_eq!(Complex::I.to_string(), "(0.0 1.0)");
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::NEGATIVE_ZERO, Float::from(1.5)).to_string(),
+    ///     "(-0.0 1.5)"
+    /// );
+    /// assert_eq!(
+    ///     Complex::from_real_imag(Float::from(core::f64::consts::PI), Float::one_prec(10))
+    ///         .to_string(),
+    ///     "(3.1415926535897931 1.0000)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "({} {})", self.real, self.imag)
+    }
+}
+
+impl Debug for Complex {
+    /// Converts a [`Complex`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_complex::Complex;
+    ///
+    /// assert_eq!(Complex::NAN.to_debug_string(), "(NaN NaN)");
+    /// assert_eq!(Complex::ONE.to_debug_string(), "(1.0 0.0)");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl LowerHex for Complex {
+    /// Converts a [`Complex`] to a hexadecimal [`String`], using lowercase digits.
+    ///
+    /// The output is `"(re im)"`, where `re` and `im` are the real and imaginary parts written by
+    /// [`Float`](malachite_float::Float)'s [`LowerHex`], so both parts are exact. Using the `#`
+    /// format flag prepends `"0x"` to each part, after any sign.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToLowerHexString;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::from_real_imag(Float::from(255), Float::from(-1.5));
+    /// assert_eq!(z.to_lower_hex_string(), "(ff.0 -1.8)");
+    /// assert_eq!(format!("{z:#x}"), "(0xff.0 -0x1.8)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.alternate() {
+            write!(f, "({:#x} {:#x})", self.real, self.imag)
+        } else {
+            write!(f, "({:x} {:x})", self.real, self.imag)
+        }
+    }
+}
+
+impl UpperHex for Complex {
+    /// Converts a [`Complex`] to a hexadecimal [`String`], using uppercase digits.
+    ///
+    /// This is the same as [`LowerHex`] apart from the case of the digits. Using the `#` format
+    /// flag prepends `"0x"` to each part, after any sign.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToUpperHexString;
+    /// use malachite_complex::Complex;
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::from_real_imag(Float::from(255), Float::from(-1.5));
+    /// assert_eq!(z.to_upper_hex_string(), "(FF.0 -1.8)");
+    /// assert_eq!(format!("{z:#X}"), "(0xFF.0 -0x1.8)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.alternate() {
+            write!(f, "({:#X} {:#X})", self.real, self.imag)
+        } else {
+            write!(f, "({:X} {:X})", self.real, self.imag)
+        }
+    }
+}
+
+impl Display for ComparableComplexRef<'_> {
+    /// Converts a [`ComparableComplexRef`] to a [`String`].
+    ///
+    /// The output is `"(re im)"`, where `re` and `im` are the real and imaginary parts written by
+    /// [`ComparableFloatRef`]'s [`Display`]: each finite nonzero part is followed by `#` and its
+    /// precision. This makes the output identify the value that [`ComparableComplexRef`]'s [`Eq`]
+    /// compares.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.0.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, One};
+    /// use malachite_complex::{ComparableComplexRef, Complex};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(ComparableComplexRef(&Complex::ONE).to_string(), "(1.0#1 0.0)");
+    /// assert_eq!(ComparableComplexRef(&Complex::NAN).to_string(), "(NaN NaN)");
+    /// assert_eq!(
+    ///     ComparableComplexRef(&Complex::from_real_imag(Float::from(255), Float::from(1.5)))
+    ///         .to_string(),
+    ///     "(255.0#8 1.5#2)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(
+            f,
+            "({} {})",
+            ComparableFloatRef(&self.0.real),
+            ComparableFloatRef(&self.0.imag)
+        )
+    }
+}
+
+impl Debug for ComparableComplexRef<'_> {
+    /// Converts a [`ComparableComplexRef`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.0.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_complex::{ComparableComplexRef, Complex};
+    ///
+    /// assert_eq!(
+    ///     ComparableComplexRef(&Complex::I).to_debug_string(),
+    ///     "(0.0 1.0#1)"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl LowerHex for ComparableComplexRef<'_> {
+    /// Converts a [`ComparableComplexRef`] to a hexadecimal [`String`].
+    ///
+    /// The output is `"(re im)"`, where `re` and `im` are the real and imaginary parts written by
+    /// [`ComparableFloatRef`]'s [`LowerHex`]. Using the `#` format flag prepends `"0x"` to each
+    /// part, after any sign. This is the form that identifies a [`Complex`] exactly, and it is what
+    /// a base-16 [`FromStringBase`](malachite_base::num::conversion::traits::FromStringBase) parse
+    /// accepts, so the two round-trip.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.0.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::{ComparableComplexRef, Complex};
+    /// use malachite_float::Float;
+    ///
+    /// let z = Complex::from_real_imag(Float::from(255), Float::from(-1.5));
+    /// assert_eq!(
+    ///     format!("{:#x}", ComparableComplexRef(&z)),
+    ///     "(0xff.0#8 -0x1.8#2)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let real = ComparableFloatRef(&self.0.real);
+        let imag = ComparableFloatRef(&self.0.imag);
+        if f.alternate() {
+            write!(f, "({real:#x} {imag:#x})")
+        } else {
+            write!(f, "({real:x} {imag:x})")
+        }
+    }
+}
+
+impl Display for ComparableComplex {
+    /// Converts a [`ComparableComplex`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`ComparableComplexRef`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.0.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_complex::{ComparableComplex, Complex};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     ComparableComplex(Complex::from_real_imag(Float::from(1.5), Float::from(-3)))
+    ///         .to_string(),
+    ///     "(1.5#2 -3.0#2)"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.as_ref(), f)
+    }
+}
+
+impl Debug for ComparableComplex {
+    /// Converts a [`ComparableComplex`] to a [`String`].
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.0.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_complex::{ComparableComplex, Complex};
+    ///
+    /// assert_eq!(
+    ///     ComparableComplex(Complex::ONE).to_debug_string(),
+    ///     "(1.0#1 0.0)"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.as_ref(), f)
+    }
+}
+
+impl LowerHex for ComparableComplex {
+    /// Converts a [`ComparableComplex`] to a hexadecimal [`String`].
+    ///
+    /// This is the same implementation as for [`ComparableComplexRef`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.0.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::{ComparableComplex, Complex};
+    ///
+    /// assert_eq!(
+    ///     format!("{:#x}", ComparableComplex(Complex::ONE)),
+    ///     "(0x1.0#1 0x0.0)"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        LowerHex::fmt(&self.as_ref(), f)
+    }
+}
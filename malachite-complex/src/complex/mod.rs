@@ -0,0 +1,127 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::ops::Deref;
+use malachite_float::Float;
+
+/// An arbitrary-precision complex number.
+///
+/// A `Complex` is a pair of [`Float`]s, its real part and its imaginary part. Each part has its own
+/// precision, and each may be `NaN`, infinite, or a signed zero, so a `Complex` can represent
+/// everything an IEEE 754 or C99 complex number can. As with [`Float`]s, zeros and special values
+/// carry no precision.
+///
+/// Functions that round take a pair of precisions and a pair of
+/// [`RoundingMode`](malachite_base::rounding_modes::RoundingMode)s, the first of each applying to
+/// the real part and the second to the imaginary part, and return a pair of
+/// [`Ordering`](core::cmp::Ordering)s that compare the rounded real and imaginary parts with the
+/// exact ones. Each part is correctly rounded, independently of the other. The rounding mode
+/// `Exact` may be used for either part; the function then panics if that part of the result is
+/// not exactly representable.
+///
+/// Functions that don't take a precision use, for both parts of the output, the largest precision
+/// among the parts of the inputs that have one, or 1 if none do. Functions that don't take a
+/// rounding mode round both parts to nearest.
+///
+/// The `Complex` type is designed to be very similar to the `mpc_t` type in
+/// [MPC](https://www.multiprecision.org/mpc/), and the functions that MPC also has follow MPC's
+/// conventions for special values, signed zeros, and branch cuts. The one structural difference is
+/// that, like [`Float`] zeros, the zero parts of a `Complex` have no precision.
+///
+/// Equality of `Complex`es follows that of [`Float`]s: the parts are compared separately, so a
+/// `Complex` with a `NaN` part is not equal to itself, and positive and negative zeros are equal.
+/// The [`ComparableComplex`] and [`ComparableComplexRef`] wrappers provide a finer equality.
+#[derive(Clone)]
+pub struct Complex {
+    pub(crate) real: Float,
+    pub(crate) imag: Float,
+}
+
+impl Complex {
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.real.is_valid() && self.imag.is_valid()
+    }
+}
+
+/// `ComparableComplex` is a wrapper around a [`Complex`], taking the [`Complex`] by value.
+///
+/// `ComparableComplex` compares its real and imaginary parts the way
+/// [`ComparableFloat`](malachite_float::ComparableFloat) compares [`Float`]s. So when a
+/// [`Complex`] is wrapped in a `ComparableComplex`,
+/// - a part that is `NaN` is equal to a `NaN` part;
+/// - positive and negative zero parts are not equal to each other;
+/// - parts with different precisions but representing the same value are unequal;
+/// - the hashing function is compatible with equality.
+///
+/// `ComparableComplex` is also totally ordered: by real part, and then by imaginary part, each
+/// compared as [`ComparableFloat`](malachite_float::ComparableFloat)s. The order has no arithmetic
+/// meaning, but it allows [`Complex`]es to be kept in ordered collections.
+///
+/// `ComparableComplex` owns its complex number. This is useful in many cases, for example if you
+/// want to use [`Complex`]es as keys in a hash map. In other situations, it is better to use
+/// [`ComparableComplexRef`], which only has a reference to its complex number.
+#[derive(Clone)]
+pub struct ComparableComplex(pub Complex);
+
+/// `ComparableComplexRef` is a wrapper around a [`Complex`], taking the [`Complex`] by reference.
+///
+/// See the [`ComparableComplex`] documentation for details.
+#[derive(Clone)]
+pub struct ComparableComplexRef<'a>(pub &'a Complex);
+
+impl ComparableComplex {
+    pub const fn as_ref(&self) -> ComparableComplexRef<'_> {
+        ComparableComplexRef(&self.0)
+    }
+}
+
+impl Deref for ComparableComplex {
+    type Target = Complex;
+
+    /// Allows a [`ComparableComplex`] to dereference to a [`Complex`].
+    ///
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::{ComparableComplex, Complex};
+    ///
+    /// let x = ComparableComplex(Complex::ONE);
+    /// assert_eq!(*x, Complex::ONE);
+    /// ```
+    fn deref(&self) -> &Complex {
+        &self.0
+    }
+}
+
+impl Deref for ComparableComplexRef<'_> {
+    type Target = Complex;
+
+    /// Allows a [`ComparableComplexRef`] to dereference to a [`Complex`].
+    ///
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_complex::{ComparableComplexRef, Complex};
+    ///
+    /// let x = Complex::ONE;
+    /// let y = ComparableComplexRef(&x);
+    /// assert_eq!(*y, Complex::ONE);
+    /// ```
+    fn deref(&self) -> &Complex {
+        self.0
+    }
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Basic traits for working with [`Complex`]es.
+pub mod basic;
+/// Traits for comparing [`Complex`]es for equality or order.
+pub mod comparison;
+/// Traits for converting to and from [`Complex`]es, including converting [`Complex`]es to and from
+/// strings.
+pub mod conversion;
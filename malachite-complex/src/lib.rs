@@ -0,0 +1,94 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+//! This crate defines [`Complex`]es, which are arbitrary-precision complex numbers whose real and
+//! imaginary parts are [`Float`](malachite_float::Float)s.
+//!
+//! [`Complex`]es are modeled on the `mpc_t` type of [MPC](https://www.multiprecision.org/mpc/),
+//! in the same way that [`Float`](malachite_float::Float)s are modeled on MPFR's `mpfr_t`. Each
+//! part has its own precision, and each function that rounds takes a pair of
+//! [`RoundingMode`](malachite_base::rounding_modes::RoundingMode)s, one per part, and returns a
+//! pair of [`Ordering`](core::cmp::Ordering)s saying how each rounded part compares with the exact
+//! one. Each part of every result is correctly rounded.
+//!
+//! # Complexity conventions
+//! Functions in this crate are documented with worst-case time and additional-memory bounds,
+//! following the conventions described in the `malachite-base`
+//! [docs](https://docs.rs/malachite-base/latest/malachite_base/#complexity-conventions).
+//!
+//! # Features
+//! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
+//!   default, [`u64`].
+//! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
+//!   typical user, building this code would result in an unnecessarily long compilation time and
+//!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
+//!   feature is enabled. If you want to run unit tests, you must enable `test_build`. However,
+//!   doctests don't require it, since they only test the public interface.
+
+#![forbid(unsafe_code)]
+#![allow(
+    unstable_name_collisions,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::upper_case_acronyms,
+    clippy::multiple_bound_locations
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::comparison_chain,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::uninlined_format_args,
+    clippy::unused_self,
+    clippy::if_not_else,
+    clippy::manual_assert,
+    clippy::range_plus_one,
+    clippy::redundant_else,
+    clippy::semicolon_if_nothing_returned,
+    clippy::cloned_instead_of_copied,
+    clippy::flat_map_option,
+    clippy::unnecessary_wraps,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref
+)]
+#![cfg_attr(
+    not(any(feature = "test_build", feature = "random", feature = "std")),
+    no_std
+)]
+
+extern crate alloc;
+
+/// [`Complex`], the crate's complex type, and everything defined on it.
+pub mod complex;
+pub use complex::{ComparableComplex, ComparableComplexRef, Complex};
+
+#[cfg(feature = "test_build")]
+pub mod test_util;
@@ -0,0 +1,20 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{ComparableComplexRef, Complex};
+use malachite_base::num::conversion::traits::FromStringBase;
+
+pub fn parse_hex_string(s_hex: &str) -> Complex {
+    let x = Complex::from_string_base(16, s_hex).unwrap();
+    assert_eq!(format!("{:#x}", ComparableComplexRef(&x)), s_hex);
+    x
+}
+
+pub fn to_hex_string(x: &Complex) -> String {
+    format!("{:#x}", ComparableComplexRef(x))
+}
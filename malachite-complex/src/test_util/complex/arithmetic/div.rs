@@ -0,0 +1,35 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use core::cmp::Ordering;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::Float;
+use malachite_q::Rational;
+
+// Computes the quotient of two `Complex`es with finite parts exactly, using `Rational`s, and then
+// rounds each part. Returns `None` if either input has a non-finite part or if the divisor is zero.
+// The signs of zero parts are not meaningful.
+pub fn div_prec_round_naive(
+    x: &Complex,
+    y: &Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) -> Option<(Complex, (Ordering, Ordering))> {
+    if !x.is_finite() || !y.is_finite() || y.is_zero() {
+        return None;
+    }
+    let a = Rational::try_from(&x.real).unwrap();
+    let b = Rational::try_from(&x.imag).unwrap();
+    let c = Rational::try_from(&y.real).unwrap();
+    let d = Rational::try_from(&y.imag).unwrap();
+    let n = &c * &c + &d * &d;
+    let (real, o_r) = Float::from_rational_prec_round((&a * &c + &b * &d) / &n, prec.0, rm.0);
+    let (imag, o_i) = Float::from_rational_prec_round((b * c - a * d) / n, prec.1, rm.1);
+    Some((Complex { real, imag }, (o_r, o_i)))
+}
@@ -0,0 +1,171 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use malachite_base::num::exhaustive::exhaustive_positive_primitive_ints;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::common::It;
+use malachite_base::test_util::generators::exhaustive_pairs_big_tiny;
+use malachite_base::tuples::exhaustive::{exhaustive_pairs_from_single, lex_pairs};
+use malachite_float::float::exhaustive::{exhaustive_finite_floats, exhaustive_floats};
+
+fn exhaustive_complexes() -> impl Clone + Iterator<Item = Complex> {
+    exhaustive_pairs_from_single(exhaustive_floats()).map(Complex::from)
+}
+
+fn exhaustive_finite_complexes() -> impl Clone + Iterator<Item = Complex> {
+    exhaustive_pairs_from_single(exhaustive_finite_floats()).map(Complex::from)
+}
+
+fn exhaustive_positive_precision_pairs() -> impl Clone + Iterator<Item = (u64, u64)> {
+    exhaustive_pairs_from_single(exhaustive_positive_primitive_ints())
+}
+
+fn exhaustive_inexact_rounding_modes() -> impl Clone + Iterator<Item = RoundingMode> {
+    exhaustive_rounding_modes().filter(|&rm| rm != Exact)
+}
+
+fn exhaustive_inexact_rounding_mode_pairs()
+-> impl Clone + Iterator<Item = (RoundingMode, RoundingMode)> {
+    exhaustive_pairs_from_single(exhaustive_inexact_rounding_modes())
+}
+
+// -- Complex --
+
+pub fn exhaustive_complex_gen() -> It<Complex> {
+    Box::new(exhaustive_complexes())
+}
+
+pub fn exhaustive_complex_gen_var_1() -> It<Complex> {
+    Box::new(exhaustive_finite_complexes())
+}
+
+// -- (Complex, Complex) --
+
+pub fn exhaustive_complex_pair_gen() -> It<(Complex, Complex)> {
+    Box::new(exhaustive_pairs_from_single(exhaustive_complexes()))
+}
+
+pub fn exhaustive_complex_pair_gen_var_1() -> It<(Complex, Complex)> {
+    Box::new(exhaustive_pairs_from_single(exhaustive_finite_complexes()))
+}
+
+// -- (Complex, Complex, RoundingMode, RoundingMode) --
+
+pub fn exhaustive_complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1()
+-> It<(Complex, Complex, RoundingMode, RoundingMode)> {
+    Box::new(
+        lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_complexes()),
+            exhaustive_inexact_rounding_mode_pairs(),
+        )
+        .map(|((x, y), (rm_r, rm_i))| (x, y, rm_r, rm_i)),
+    )
+}
+
+// -- (Complex, Complex, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+pub fn exhaustive_complex_complex_unsigned_unsigned_quadruple_gen_var_1()
+-> It<(Complex, Complex, u64, u64)> {
+    Box::new(
+        exhaustive_pairs_big_tiny(
+            exhaustive_pairs_from_single(exhaustive_complexes()),
+            exhaustive_positive_precision_pairs(),
+        )
+        .map(|((x, y), (p_r, p_i))| (x, y, p_r, p_i)),
+    )
+}
+
+// -- (Complex, Complex, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode, RoundingMode) --
+
+pub fn exhaustive_complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+-> It<(Complex, Complex, u64, u64, RoundingMode, RoundingMode)> {
+    Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(
+                exhaustive_pairs_from_single(exhaustive_complexes()),
+                exhaustive_positive_precision_pairs(),
+            ),
+            exhaustive_inexact_rounding_mode_pairs(),
+        )
+        .map(|(((x, y), (p_r, p_i)), (rm_r, rm_i))| (x, y, p_r, p_i, rm_r, rm_i)),
+    )
+}
+
+// -- (Complex, RoundingMode) --
+
+pub fn exhaustive_complex_rounding_mode_pair_gen_var_1() -> It<(Complex, RoundingMode)> {
+    Box::new(lex_pairs(
+        exhaustive_complexes(),
+        exhaustive_inexact_rounding_modes(),
+    ))
+}
+
+// -- (Complex, RoundingMode, RoundingMode) --
+
+pub fn exhaustive_complex_rounding_mode_rounding_mode_triple_gen_var_1()
+-> It<(Complex, RoundingMode, RoundingMode)> {
+    Box::new(
+        lex_pairs(
+            exhaustive_complexes(),
+            exhaustive_inexact_rounding_mode_pairs(),
+        )
+        .map(|(x, (rm_r, rm_i))| (x, rm_r, rm_i)),
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned) --
+
+pub fn exhaustive_complex_unsigned_pair_gen_var_1() -> It<(Complex, u64)> {
+    Box::new(exhaustive_pairs_big_tiny(
+        exhaustive_complexes(),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
+// -- (Complex, PrimitiveUnsigned, RoundingMode) --
+
+pub fn exhaustive_complex_unsigned_rounding_mode_triple_gen_var_1()
+-> It<(Complex, u64, RoundingMode)> {
+    Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_complexes(), exhaustive_positive_primitive_ints()),
+            exhaustive_inexact_rounding_modes(),
+        )
+        .map(|((x, p), rm)| (x, p, rm)),
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+pub fn exhaustive_complex_unsigned_unsigned_triple_gen_var_1() -> It<(Complex, u64, u64)> {
+    Box::new(
+        exhaustive_pairs_big_tiny(
+            exhaustive_complexes(),
+            exhaustive_positive_precision_pairs(),
+        )
+        .map(|(x, (p_r, p_i))| (x, p_r, p_i)),
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode, RoundingMode) --
+
+pub fn exhaustive_complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1()
+-> It<(Complex, u64, u64, RoundingMode, RoundingMode)> {
+    Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(
+                exhaustive_complexes(),
+                exhaustive_positive_precision_pairs(),
+            ),
+            exhaustive_inexact_rounding_mode_pairs(),
+        )
+        .map(|((x, (p_r, p_i)), (rm_r, rm_i))| (x, p_r, p_i, rm_r, rm_i)),
+    )
+}
@@ -0,0 +1,164 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use crate::test_util::generators::exhaustive::*;
+use crate::test_util::generators::random::*;
+use crate::test_util::generators::special_random::*;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::common::Generator;
+
+// -- Complex --
+
+pub fn complex_gen() -> Generator<Complex> {
+    Generator::new(
+        &exhaustive_complex_gen,
+        &random_complex_gen,
+        &special_random_complex_gen,
+    )
+}
+
+// All `Complex`es with finite parts.
+pub fn complex_gen_var_1() -> Generator<Complex> {
+    Generator::new(
+        &exhaustive_complex_gen_var_1,
+        &random_complex_gen_var_1,
+        &special_random_complex_gen_var_1,
+    )
+}
+
+// -- (Complex, Complex) --
+
+pub fn complex_pair_gen() -> Generator<(Complex, Complex)> {
+    Generator::new(
+        &exhaustive_complex_pair_gen,
+        &random_complex_pair_gen,
+        &special_random_complex_pair_gen,
+    )
+}
+
+// All pairs of `Complex`es with finite parts.
+pub fn complex_pair_gen_var_1() -> Generator<(Complex, Complex)> {
+    Generator::new(
+        &exhaustive_complex_pair_gen_var_1,
+        &random_complex_pair_gen_var_1,
+        &special_random_complex_pair_gen_var_1,
+    )
+}
+
+// -- (Complex, Complex, RoundingMode, RoundingMode) --
+
+// All `(Complex, Complex, RoundingMode, RoundingMode)` where neither `RoundingMode` is `Exact`.
+pub fn complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1()
+-> Generator<(Complex, Complex, RoundingMode, RoundingMode)> {
+    Generator::new(
+        &exhaustive_complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+        &random_complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+        &special_random_complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
+// -- (Complex, Complex, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+// All `(Complex, Complex, u64, u64)` where both `u64`s are positive.
+pub fn complex_complex_unsigned_unsigned_quadruple_gen_var_1()
+-> Generator<(Complex, Complex, u64, u64)> {
+    Generator::new(
+        &exhaustive_complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+        &random_complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+        &special_random_complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+    )
+}
+
+// -- (Complex, Complex, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode, RoundingMode) --
+
+// All `(Complex, Complex, u64, u64, RoundingMode, RoundingMode)` where both `u64`s are positive and
+// neither `RoundingMode` is `Exact`.
+pub fn complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+-> Generator<(Complex, Complex, u64, u64, RoundingMode, RoundingMode)> {
+    Generator::new(
+        &exhaustive_complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1,
+        &random_complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1,
+        &special_random_complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1,
+    )
+}
+
+// -- (Complex, RoundingMode) --
+
+// All `(Complex, RoundingMode)` where the `RoundingMode` is not `Exact`.
+pub fn complex_rounding_mode_pair_gen_var_1() -> Generator<(Complex, RoundingMode)> {
+    Generator::new(
+        &exhaustive_complex_rounding_mode_pair_gen_var_1,
+        &random_complex_rounding_mode_pair_gen_var_1,
+        &special_random_complex_rounding_mode_pair_gen_var_1,
+    )
+}
+
+// -- (Complex, RoundingMode, RoundingMode) --
+
+// All `(Complex, RoundingMode, RoundingMode)` where neither `RoundingMode` is `Exact`.
+pub fn complex_rounding_mode_rounding_mode_triple_gen_var_1()
+-> Generator<(Complex, RoundingMode, RoundingMode)> {
+    Generator::new(
+        &exhaustive_complex_rounding_mode_rounding_mode_triple_gen_var_1,
+        &random_complex_rounding_mode_rounding_mode_triple_gen_var_1,
+        &special_random_complex_rounding_mode_rounding_mode_triple_gen_var_1,
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned) --
+
+// All `(Complex, u64)` where the `u64` is positive.
+pub fn complex_unsigned_pair_gen_var_1() -> Generator<(Complex, u64)> {
+    Generator::new(
+        &exhaustive_complex_unsigned_pair_gen_var_1,
+        &random_complex_unsigned_pair_gen_var_1,
+        &special_random_complex_unsigned_pair_gen_var_1,
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Complex, u64, RoundingMode)` where the `u64` is positive and the `RoundingMode` is not
+// `Exact`.
+pub fn complex_unsigned_rounding_mode_triple_gen_var_1() -> Generator<(Complex, u64, RoundingMode)>
+{
+    Generator::new(
+        &exhaustive_complex_unsigned_rounding_mode_triple_gen_var_1,
+        &random_complex_unsigned_rounding_mode_triple_gen_var_1,
+        &special_random_complex_unsigned_rounding_mode_triple_gen_var_1,
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+// All `(Complex, u64, u64)` where both `u64`s are positive.
+pub fn complex_unsigned_unsigned_triple_gen_var_1() -> Generator<(Complex, u64, u64)> {
+    Generator::new(
+        &exhaustive_complex_unsigned_unsigned_triple_gen_var_1,
+        &random_complex_unsigned_unsigned_triple_gen_var_1,
+        &special_random_complex_unsigned_unsigned_triple_gen_var_1,
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode, RoundingMode) --
+
+// All `(Complex, u64, u64, RoundingMode, RoundingMode)` where both `u64`s are positive and neither
+// `RoundingMode` is `Exact`.
+pub fn complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1()
+-> Generator<(Complex, u64, u64, RoundingMode, RoundingMode)> {
+    Generator::new(
+        &exhaustive_complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+        &random_complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+        &special_random_complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+    )
+}
+
+pub mod exhaustive;
+pub mod random;
+pub mod special_random;
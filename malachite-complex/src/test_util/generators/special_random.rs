@@ -0,0 +1,256 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Complex;
+use malachite_base::num::random::geometric::geometric_random_positive_unsigneds;
+use malachite_base::random::{EXAMPLE_SEED, Seed};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::random::random_rounding_modes;
+use malachite_base::test_util::generators::common::{GenConfig, It};
+use malachite_base::tuples::random::{random_pairs, random_pairs_from_single};
+use malachite_float::float::random::{striped_random_finite_floats, striped_random_floats};
+
+fn special_random_complexes(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = Complex> + use<> {
+    random_pairs_from_single(striped_random_floats(
+        seed,
+        config.get_or("mean_exponent_n", 64),
+        config.get_or("mean_exponent_d", 1),
+        config.get_or("mean_stripe_n", 32),
+        config.get_or("mean_stripe_d", 1),
+        config.get_or("mean_precision_n", 64),
+        config.get_or("mean_precision_d", 1),
+        config.get_or("mean_zero_p_n", 1),
+        config.get_or("mean_zero_p_d", 64),
+    ))
+    .map(Complex::from)
+}
+
+fn special_random_finite_complexes(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = Complex> + use<> {
+    random_pairs_from_single(striped_random_finite_floats(
+        seed,
+        config.get_or("mean_exponent_n", 64),
+        config.get_or("mean_exponent_d", 1),
+        config.get_or("mean_stripe_n", 32),
+        config.get_or("mean_stripe_d", 1),
+        config.get_or("mean_precision_n", 64),
+        config.get_or("mean_precision_d", 1),
+        config.get_or("mean_zero_p_n", 1),
+        config.get_or("mean_zero_p_d", 64),
+    ))
+    .map(Complex::from)
+}
+
+fn random_positive_precision_pairs(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = (u64, u64)> + use<> {
+    random_pairs_from_single(geometric_random_positive_unsigneds(
+        seed,
+        config.get_or("mean_small_n", 64),
+        config.get_or("mean_small_d", 1),
+    ))
+}
+
+fn random_inexact_rounding_modes(seed: Seed) -> impl Iterator<Item = RoundingMode> {
+    random_rounding_modes(seed).filter(|&rm| rm != Exact)
+}
+
+fn random_inexact_rounding_mode_pairs(
+    seed: Seed,
+) -> impl Iterator<Item = (RoundingMode, RoundingMode)> {
+    random_pairs_from_single(random_inexact_rounding_modes(seed))
+}
+
+// -- Complex --
+
+pub fn special_random_complex_gen(config: &GenConfig) -> It<Complex> {
+    Box::new(special_random_complexes(EXAMPLE_SEED, config))
+}
+
+pub fn special_random_complex_gen_var_1(config: &GenConfig) -> It<Complex> {
+    Box::new(special_random_finite_complexes(EXAMPLE_SEED, config))
+}
+
+// -- (Complex, Complex) --
+
+pub fn special_random_complex_pair_gen(config: &GenConfig) -> It<(Complex, Complex)> {
+    Box::new(random_pairs_from_single(special_random_complexes(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn special_random_complex_pair_gen_var_1(config: &GenConfig) -> It<(Complex, Complex)> {
+    Box::new(random_pairs_from_single(special_random_finite_complexes(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+// -- (Complex, Complex, RoundingMode, RoundingMode) --
+
+pub fn special_random_complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, Complex, RoundingMode, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| random_pairs_from_single(special_random_complexes(seed, config)),
+            &random_inexact_rounding_mode_pairs,
+        )
+        .map(|((x, y), (rm_r, rm_i))| (x, y, rm_r, rm_i)),
+    )
+}
+
+// -- (Complex, Complex, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+pub fn special_random_complex_complex_unsigned_unsigned_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, Complex, u64, u64)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| random_pairs_from_single(special_random_complexes(seed, config)),
+            &|seed| random_positive_precision_pairs(seed, config),
+        )
+        .map(|((x, y), (p_r, p_i))| (x, y, p_r, p_i)),
+    )
+}
+
+// -- (Complex, Complex, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode, RoundingMode) --
+
+pub fn special_random_complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, Complex, u64, u64, RoundingMode, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_pairs(
+                    seed,
+                    &|seed| random_pairs_from_single(special_random_complexes(seed, config)),
+                    &|seed| random_positive_precision_pairs(seed, config),
+                )
+            },
+            &random_inexact_rounding_mode_pairs,
+        )
+        .map(|(((x, y), (p_r, p_i)), (rm_r, rm_i))| (x, y, p_r, p_i, rm_r, rm_i)),
+    )
+}
+
+// -- (Complex, RoundingMode) --
+
+pub fn special_random_complex_rounding_mode_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, RoundingMode)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| special_random_complexes(seed, config),
+        &random_inexact_rounding_modes,
+    ))
+}
+
+// -- (Complex, RoundingMode, RoundingMode) --
+
+pub fn special_random_complex_rounding_mode_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, RoundingMode, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| special_random_complexes(seed, config),
+            &random_inexact_rounding_mode_pairs,
+        )
+        .map(|(x, (rm_r, rm_i))| (x, rm_r, rm_i)),
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned) --
+
+pub fn special_random_complex_unsigned_pair_gen_var_1(config: &GenConfig) -> It<(Complex, u64)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| special_random_complexes(seed, config),
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Complex, PrimitiveUnsigned, RoundingMode) --
+
+pub fn special_random_complex_unsigned_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, u64, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_pairs(
+                    seed,
+                    &|seed| special_random_complexes(seed, config),
+                    &|seed| {
+                        geometric_random_positive_unsigneds(
+                            seed,
+                            config.get_or("mean_small_n", 64),
+                            config.get_or("mean_small_d", 1),
+                        )
+                    },
+                )
+            },
+            &random_inexact_rounding_modes,
+        )
+        .map(|((x, p), rm)| (x, p, rm)),
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+pub fn special_random_complex_unsigned_unsigned_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, u64, u64)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| special_random_complexes(seed, config),
+            &|seed| random_positive_precision_pairs(seed, config),
+        )
+        .map(|(x, (p_r, p_i))| (x, p_r, p_i)),
+    )
+}
+
+// -- (Complex, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode, RoundingMode) --
+
+pub fn special_random_complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Complex, u64, u64, RoundingMode, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_pairs(
+                    seed,
+                    &|seed| special_random_complexes(seed, config),
+                    &|seed| random_positive_precision_pairs(seed, config),
+                )
+            },
+            &random_inexact_rounding_mode_pairs,
+        )
+        .map(|((x, (p_r, p_i)), (rm_r, rm_i))| (x, p_r, p_i, rm_r, rm_i)),
+    )
+}
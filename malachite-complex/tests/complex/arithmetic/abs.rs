@@ -0,0 +1,504 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::Complex;
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::{
+    complex_gen, complex_rounding_mode_pair_gen_var_1, complex_unsigned_pair_gen_var_1,
+    complex_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::test_util::common::to_hex_string;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_abs() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let abs = x.clone().abs();
+        assert!(abs.is_valid());
+
+        assert_eq!(abs.to_string(), out);
+        assert_eq!(to_hex_string(&abs), out_hex);
+
+        let abs_alt = (&x).abs();
+        assert!(abs_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+    };
+    test("(NaN NaN)", "(NaN NaN)", "NaN", "NaN");
+    test("(Infinity 0.0)", "(Infinity 0x0.0)", "Infinity", "Infinity");
+    test(
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "Infinity",
+        "Infinity",
+    );
+    test("(NaN Infinity)", "(NaN Infinity)", "Infinity", "Infinity");
+    test(
+        "(1.0 Infinity)",
+        "(0x1.0#1 Infinity)",
+        "Infinity",
+        "Infinity",
+    );
+    test("(0.0 0.0)", "(0x0.0 0x0.0)", "0.0", "0x0.0");
+    test("(-0.0 -0.0)", "(-0x0.0 -0x0.0)", "0.0", "0x0.0");
+    test("(-0.0 0.0)", "(-0x0.0 0x0.0)", "0.0", "0x0.0");
+    test("(1.0 0.0)", "(0x1.0#1 0x0.0)", "1.0", "0x1.0#1");
+    test("(-1.0 0.0)", "(-0x1.0#1 0x0.0)", "1.0", "0x1.0#1");
+    test("(-1.0 -0.0)", "(-0x1.0#1 -0x0.0)", "1.0", "0x1.0#1");
+    test("(0.0 1.0)", "(0x0.0 0x1.0#1)", "1.0", "0x1.0#1");
+    test("(1.0 1.0)", "(0x1.0#1 0x1.0#1)", "1.0", "0x1.0#1");
+    test("(3.0 4.0)", "(0x3.0#2 0x4.0#3)", "5.0", "0x5.0#3");
+    test("(1.0 -2.0)", "(0x1.0#1 -0x2.0#1)", "2.0", "0x2.0#1");
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "4.1543544023133130",
+        "0x4.2783c525ede70#53",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "1.5749454002974839",
+        "0x1.932f9f2b43691#53",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "472.0",
+        "0x1d8.0#9",
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "3.0184617127124722088579360556625",
+        "0x3.04b9e8245405efd20440b6604#100",
+    );
+}
+
+#[test]
+fn test_abs_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (abs, o) = x.clone().abs_prec(prec);
+        assert!(abs.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(abs.to_string(), out);
+        assert_eq!(to_hex_string(&abs), out_hex);
+
+        let (abs_alt, o_alt) = x.abs_prec_ref(prec);
+        assert!(abs_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+        assert_eq!(o_alt, o_out);
+    };
+    test("(3.0 4.0)", "(0x3.0#2 0x4.0#3)", 1, "4.0", "0x4.0#1", Less);
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        10,
+        "5.0000",
+        "0x5.00#10",
+        Equal,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        "4.1562",
+        "0x4.28#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        100,
+        "4.1543544023133133857465418597750",
+        "0x4.2783c525ede71ca31c15ea050#100",
+        Greater,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        20,
+        "1.5749454",
+        "0x1.932fa#20",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        5,
+        "480.0",
+        "0x1.eE+2#5",
+        Greater,
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        20,
+        "3.0184631",
+        "0x3.04ba0#20",
+        Greater,
+    );
+}
+
+#[test]
+fn test_abs_round() {
+    let test = |s, s_hex, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (abs, o) = x.clone().abs_round(rm);
+        assert!(abs.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(abs.to_string(), out);
+        assert_eq!(to_hex_string(&abs), out_hex);
+
+        let (abs_alt, o_alt) = x.abs_round_ref(rm);
+        assert!(abs_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Floor,
+        "4.1543544023133130",
+        "0x4.2783c525ede70#53",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Ceiling,
+        "4.1543544023133139",
+        "0x4.2783c525ede74#53",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Down,
+        "4.1543544023133130",
+        "0x4.2783c525ede70#53",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Up,
+        "4.1543544023133139",
+        "0x4.2783c525ede74#53",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Nearest,
+        "4.1543544023133130",
+        "0x4.2783c525ede70#53",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        Floor,
+        "1.5749454002974836",
+        "0x1.932f9f2b43690#53",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        Ceiling,
+        "1.5749454002974839",
+        "0x1.932f9f2b43691#53",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        Floor,
+        "472.0",
+        "0x1d8.0#9",
+        Less,
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        Exact,
+        "5.0",
+        "0x5.0#3",
+        Equal,
+    );
+}
+
+#[test]
+fn test_abs_prec_round() {
+    let test =
+        |s, s_hex, prec: u64, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.to_string(), s);
+
+            let (abs, o) = x.clone().abs_prec_round(prec, rm);
+            assert!(abs.is_valid());
+            assert_eq!(o, o_out);
+
+            assert_eq!(abs.to_string(), out);
+            assert_eq!(to_hex_string(&abs), out_hex);
+
+            let (abs_alt, o_alt) = x.abs_prec_round_ref(prec, rm);
+            assert!(abs_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+            assert_eq!(o_alt, o_out);
+        };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Floor,
+        "4.1484",
+        "0x4.26#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Ceiling,
+        "4.1562",
+        "0x4.28#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Down,
+        "4.1484",
+        "0x4.26#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Up,
+        "4.1562",
+        "0x4.28#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Nearest,
+        "4.1562",
+        "0x4.28#10",
+        Greater,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        20,
+        Up,
+        "1.5749454",
+        "0x1.932fa#20",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        5,
+        Down,
+        "464.0",
+        "0x1.dE+2#5",
+        Less,
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        20,
+        Ceiling,
+        "3.0184631",
+        "0x3.04ba0#20",
+        Greater,
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        10,
+        Exact,
+        "5.0000",
+        "0x5.00#10",
+        Equal,
+    );
+}
+
+#[test]
+fn abs_prec_fail() {
+    assert_panic!(Complex::ONE.abs_prec(0));
+    assert_panic!(Complex::ONE.abs_prec_ref(0));
+}
+
+#[test]
+fn abs_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    assert_panic!(x.clone().abs_round(Exact));
+    assert_panic!(x.abs_round_ref(Exact));
+}
+
+#[test]
+fn abs_prec_round_fail() {
+    assert_panic!(Complex::ONE.abs_prec_round(0, Floor));
+    assert_panic!(Complex::ONE.abs_prec_round_ref(0, Floor));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    assert_panic!(x.clone().abs_prec_round(1, Exact));
+    assert_panic!(x.abs_prec_round_ref(1, Exact));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn abs_prec_round_properties_helper(x: Complex, prec: u64, rm: RoundingMode) {
+    let (abs, o) = x.clone().abs_prec_round(prec, rm);
+    assert!(abs.is_valid());
+
+    let (abs_alt, o_alt) = x.abs_prec_round_ref(prec, rm);
+    assert!(abs_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+    assert_eq!(o_alt, o);
+
+    let (abs_alt, o_alt) = x
+        .real_ref()
+        .hypot_prec_round_ref_ref(x.imag_ref(), prec, rm);
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+    assert_eq!(o_alt, o);
+
+    if abs.is_normal() {
+        assert_eq!(abs.get_prec(), Some(prec));
+    }
+
+    if o == Equal {
+        let (abs_alt, o_alt) = x.abs_prec_round_ref(prec, Exact);
+        assert_eq!(
+            ComparableFloat(abs_alt.abs_negative_zero_ref()),
+            ComparableFloat(abs.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, Equal);
+    } else {
+        assert_panic!(x.abs_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn abs_prec_round_properties() {
+    complex_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        abs_prec_round_properties_helper(x, prec, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn abs_prec_properties_helper(x: Complex, prec: u64) {
+    let (abs, o) = x.clone().abs_prec(prec);
+    assert!(abs.is_valid());
+
+    let (abs_alt, o_alt) = x.abs_prec_ref(prec);
+    assert!(abs_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+    assert_eq!(o_alt, o);
+
+    let (abs_alt, o_alt) = x.abs_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn abs_prec_properties() {
+    complex_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        abs_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn abs_round_properties_helper(x: Complex, rm: RoundingMode) {
+    let (abs, o) = x.clone().abs_round(rm);
+    assert!(abs.is_valid());
+
+    let (abs_alt, o_alt) = x.abs_round_ref(rm);
+    assert!(abs_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits()
+    );
+    let (abs_alt, o_alt) = x.abs_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn abs_round_properties() {
+    complex_rounding_mode_pair_gen_var_1().test_properties(|(x, rm)| {
+        abs_round_properties_helper(x, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn abs_properties_helper(x: Complex) {
+    let abs = x.clone().abs();
+    assert!(abs.is_valid());
+
+    let abs_alt = (&x).abs();
+    assert!(abs_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+
+    let (abs_alt, _) = x.abs_round_ref(Nearest);
+    assert_eq!(ComparableFloatRef(&abs_alt), ComparableFloatRef(&abs));
+
+    assert_eq!(
+        ComparableFloat(x.conj_ref().abs()),
+        ComparableFloat(abs.clone())
+    );
+    assert_eq!(ComparableFloat((-&x).abs()), ComparableFloat(abs.clone()));
+    assert!(!abs.is_sign_negative());
+}
+
+#[test]
+fn abs_properties() {
+    complex_gen().test_properties(|x| {
+        abs_properties_helper(x);
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableFloat(Complex::from(&x).abs()),
+            ComparableFloat(x.abs_negative_zero().abs())
+        );
+    });
+}
@@ -0,0 +1,868 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{NaN, One, Two, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::{
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1, complex_gen,
+    complex_gen_var_1, complex_pair_gen,
+};
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_add() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let sum = x.clone() + y.clone();
+        assert!(sum.is_valid());
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let sum_alt = x.clone() + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+        let sum_alt = &x + y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+        let sum_alt = &x + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+        let mut sum_alt = x.clone();
+        sum_alt += y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+        let mut sum_alt = x.clone();
+        sum_alt += &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    };
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(NaN 0.0)",
+        "(NaN 0x0.0)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(2.0 2.0)",
+        "(0x2.0#1 0x2.0#1)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        "(4.0 2.0)",
+        "(0x4.0#3 0x2.0#3)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(1.7273790912166980 3.4114290090189905)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbca#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(1.7273790912166980 3.4114290090189905)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbca#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(123.33333333333333333333333333337 -459.00000000000000000000000000000)",
+        "(0x7b.555555555555555555555558#100 -0x1cb.00000000000000000000000#100)",
+    );
+}
+
+#[test]
+fn test_add_prec() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_prec_val_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_val(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_assign(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_assign_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (1, 1),
+        "(4.0 2.0)",
+        "(0x4.0#1 0x2.0#1)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (10, 10),
+        "(4.0000 2.0000)",
+        "(0x4.00#10 0x2.00#10)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (1, 1),
+        "(2.0 4.0)",
+        "(0x2.0#1 0x4.0#1)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 20),
+        "(1.7266 3.4114304)",
+        "(0x1.ba0#10 0x3.69538#20)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (100, 100),
+        "(1.7273790912166979705233416098054 3.4114290090189903770223622814228)",
+        "(0x1.ba35842091e63000000000000#100 0x3.6953695a5cbc9800000000000#100)",
+        (Equal, Equal),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 10),
+        "(1.7273788 3.4102)",
+        "(0x1.ba358#20 0x3.69#10)",
+        (Less, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 5),
+        "(123.33337 -464.0)",
+        "(0x7b.5558#20 -0x1.dE+2#5)",
+        (Greater, Less),
+    );
+}
+
+#[test]
+fn test_add_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_round(y.clone(), rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_round_val_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_round_ref_val(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_round_ref_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_round_assign(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_round_assign_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Floor),
+        "(1.7273790912166980 3.4114290090189900)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbc8#53)",
+        (Equal, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Ceiling, Ceiling),
+        "(1.7273790912166980 3.4114290090189905)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbca#53)",
+        (Equal, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Down, Up),
+        "(1.7273790912166980 3.4114290090189905)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbca#53)",
+        (Equal, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Up, Down),
+        "(1.7273790912166980 3.4114290090189900)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbc8#53)",
+        (Equal, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Nearest, Nearest),
+        "(1.7273790912166980 3.4114290090189905)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbca#53)",
+        (Equal, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Ceiling),
+        "(1.7273790912166980 3.4114290090189905)",
+        "(0x1.ba35842091e63#53 0x3.6953695a5cbca#53)",
+        (Equal, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Floor, Ceiling),
+        "(123.33333333333333333333333333327 -459.00000000000000000000000000000)",
+        "(0x7b.555555555555555555555550#100 -0x1cb.00000000000000000000000#100)",
+        (Less, Equal),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Ceiling, Floor),
+        "(123.33333333333333333333333333337 -459.00000000000000000000000000000)",
+        "(0x7b.555555555555555555555558#100 -0x1cb.00000000000000000000000#100)",
+        (Greater, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (Exact, Exact),
+        "(4.0 2.0)",
+        "(0x4.0#3 0x2.0#3)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_add_prec_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_prec_round(y.clone(), prec, rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_prec_round_val_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_round_assign(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_round_assign_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(1.7266 3.4141)",
+        "(0x1.ba0#10 0x3.6a#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(1.7285 3.4102)",
+        "(0x1.ba8#10 0x3.69#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Down, Down),
+        "(1.7266 3.4102)",
+        "(0x1.ba0#10 0x3.69#10)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Up, Up),
+        "(1.7285 3.4141)",
+        "(0x1.ba8#10 0x3.6a#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(1.7266 3.4102)",
+        "(0x1.ba0#10 0x3.69#10)",
+        (Less, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 5),
+        (Up, Down),
+        "(1.7273808 3.38)",
+        "(0x1.ba35a#20 0x3.6#5)",
+        (Greater, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        (Down, Up),
+        "(120.0 -459.00000)",
+        "(0x78.0#5 -0x1cb.000#20)",
+        (Less, Equal),
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        (1, 1),
+        (Floor, Floor),
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        (Equal, Equal),
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        (1, 1),
+        (Ceiling, Ceiling),
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn add_prec_fail() {
+    assert_panic!(Complex::ONE.add_prec(Complex::ONE, (0, 1)));
+    assert_panic!(Complex::ONE.add_prec(Complex::ONE, (1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.add_prec_assign(Complex::ONE, (0, 1))
+    });
+}
+
+#[test]
+fn add_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    let y = parse_hex_string("(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)");
+    assert_panic!(x.clone().add_round(y.clone(), (Floor, Exact)));
+    assert_panic!(x.clone().add_round_val_ref(&y, (Floor, Exact)));
+    assert_panic!(x.add_round_ref_val(y.clone(), (Floor, Exact)));
+    assert_panic!(x.add_round_ref_ref(&y, (Floor, Exact)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.add_round_assign(y.clone(), (Floor, Exact))
+    });
+    assert_panic!({
+        let mut x = x.clone();
+        x.add_round_assign_ref(&y, (Floor, Exact))
+    });
+}
+
+#[test]
+fn add_prec_round_fail() {
+    assert_panic!(Complex::ONE.add_prec_round(Complex::ONE, (0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.add_prec_round(Complex::ONE, (1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    let y = parse_hex_string("(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)");
+    assert_panic!(x.clone().add_prec_round(y.clone(), (1, 1), (Exact, Floor)));
+    assert_panic!(x.add_prec_round_ref_ref(&y, (1, 1), (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.add_prec_round_assign(y.clone(), (1, 1), (Exact, Floor))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_prec_round_properties_helper(
+    x: Complex,
+    y: Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) {
+    let (sum, o) = x.clone().add_prec_round(y.clone(), prec, rm);
+    assert!(sum.is_valid());
+
+    let (sum_alt, o_alt) = x.clone().add_prec_round_val_ref(&y, prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut sum_alt = x.clone();
+    let o_alt = sum_alt.add_prec_round_assign(y.clone(), prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut sum_alt = x.clone();
+    let o_alt = sum_alt.add_prec_round_assign_ref(&y, prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (real, o_r) = x
+        .real_ref()
+        .add_prec_round_ref_ref(y.real_ref(), prec.0, rm.0);
+    assert_eq!(
+        ComparableFloatRef(sum.real_ref()),
+        ComparableFloatRef(&real)
+    );
+    assert_eq!(o.0, o_r);
+    let (imag, o_i) = x
+        .imag_ref()
+        .add_prec_round_ref_ref(y.imag_ref(), prec.1, rm.1);
+    assert_eq!(
+        ComparableFloatRef(sum.imag_ref()),
+        ComparableFloatRef(&imag)
+    );
+    assert_eq!(o.1, o_i);
+
+    let (sum_alt, o_alt) = y.add_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    if sum.real_ref().is_normal() {
+        assert_eq!(sum.real_ref().get_prec(), Some(prec.0));
+    }
+    if sum.imag_ref().is_normal() {
+        assert_eq!(sum.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(sum_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(sum.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.add_prec_round_ref_ref(&y, prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(sum_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(sum.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.add_prec_round_ref_ref(&y, prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn add_prec_round_properties() {
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+        .test_properties(|(x, y, p_r, p_i, rm_r, rm_i)| {
+            add_prec_round_properties_helper(x, y, (p_r, p_i), (rm_r, rm_i));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_prec_properties_helper(x: Complex, y: Complex, prec: (u64, u64)) {
+    let (sum, o) = x.clone().add_prec(y.clone(), prec);
+    assert!(sum.is_valid());
+
+    let (sum_alt, o_alt) = x.clone().add_prec_val_ref(&y, prec);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_prec_ref_val(y.clone(), prec);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_prec_ref_ref(&y, prec);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut sum_alt = x.clone();
+    let o_alt = sum_alt.add_prec_assign(y.clone(), prec);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut sum_alt = x.clone();
+    let o_alt = sum_alt.add_prec_assign_ref(&y, prec);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, (Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn add_prec_properties() {
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1().test_properties(|(x, y, p_r, p_i)| {
+        add_prec_properties_helper(x, y, (p_r, p_i));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_round_properties_helper(x: Complex, y: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (sum, o) = x.clone().add_round(y.clone(), rm);
+    assert!(sum.is_valid());
+
+    let (sum_alt, o_alt) = x.clone().add_round_val_ref(&y, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_round_ref_val(y.clone(), rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_round_ref_ref(&y, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut sum_alt = x.clone();
+    let o_alt = sum_alt.add_round_assign(y.clone(), rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut sum_alt = x.clone();
+    let o_alt = sum_alt.add_round_assign_ref(&y, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits(),
+        y.real_ref().significant_bits(),
+        y.imag_ref().significant_bits()
+    );
+    let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, (prec, prec), rm);
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn add_round_properties() {
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, y, rm_r, rm_i)| {
+            add_round_properties_helper(x, y, (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_properties_helper(x: Complex, y: Complex) {
+    let sum = x.clone() + y.clone();
+    assert!(sum.is_valid());
+
+    let sum_alt = x.clone() + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+    let sum_alt = &x + y.clone();
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+    let sum_alt = &x + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+    let mut sum_alt = x.clone();
+    sum_alt += y.clone();
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+    let mut sum_alt = x.clone();
+    sum_alt += &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+    let (sum_alt, _) = x.add_round_ref_ref(&y, (Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&sum_alt), ComparableComplexRef(&sum));
+
+    assert_eq!(ComparableComplex(&y + &x), ComparableComplex(sum));
+}
+
+#[test]
+fn add_properties() {
+    complex_pair_gen().test_properties(|(x, y)| {
+        add_properties_helper(x, y);
+    });
+
+    complex_gen().test_properties(|x| {
+        assert!((&x + Complex::NAN).is_nan());
+        assert!((Complex::NAN + &x).is_nan());
+    });
+
+    complex_gen_var_1().test_properties(|x| {
+        assert_eq!(&x + Complex::ZERO, x);
+        assert_eq!(&x + &x, &x * Complex::TWO);
+    });
+}
@@ -0,0 +1,480 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::Complex;
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::{
+    complex_gen, complex_rounding_mode_pair_gen_var_1, complex_unsigned_pair_gen_var_1,
+    complex_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::test_util::common::to_hex_string;
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_arg() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let arg = x.clone().arg();
+        assert!(arg.is_valid());
+
+        assert_eq!(arg.to_string(), out);
+        assert_eq!(to_hex_string(&arg), out_hex);
+
+        let arg_alt = x.arg_ref();
+        assert!(arg_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+    };
+    test("(NaN NaN)", "(NaN NaN)", "NaN", "NaN");
+    test("(Infinity 0.0)", "(Infinity 0x0.0)", "0.0", "0x0.0");
+    test("(-Infinity 0.0)", "(-Infinity 0x0.0)", "4.0", "0x4.0#1");
+    test("(NaN Infinity)", "(NaN Infinity)", "NaN", "NaN");
+    test("(1.0 Infinity)", "(0x1.0#1 Infinity)", "2.0", "0x2.0#1");
+    test("(0.0 0.0)", "(0x0.0 0x0.0)", "0.0", "0x0.0");
+    test("(-0.0 -0.0)", "(-0x0.0 -0x0.0)", "-4.0", "-0x4.0#1");
+    test("(-0.0 0.0)", "(-0x0.0 0x0.0)", "4.0", "0x4.0#1");
+    test("(1.0 0.0)", "(0x1.0#1 0x0.0)", "0.0", "0x0.0");
+    test("(-1.0 0.0)", "(-0x1.0#1 0x0.0)", "4.0", "0x4.0#1");
+    test("(-1.0 -0.0)", "(-0x1.0#1 -0x0.0)", "-4.0", "-0x4.0#1");
+    test("(0.0 1.0)", "(0x0.0 0x1.0#1)", "2.0", "0x2.0#1");
+    test("(1.0 1.0)", "(0x1.0#1 0x1.0#1)", "1.0", "0x1.0#1");
+    test("(3.0 4.0)", "(0x3.0#2 0x4.0#3)", "0.88", "0x0.e#3");
+    test("(1.0 -2.0)", "(0x1.0#1 -0x2.0#1)", "-1.0", "-0x1.0#1");
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "0.71328454043905032",
+        "0x0.b699d0cded98a8#53",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "2.6858729239722470",
+        "0x2.af955e31ac35c#53",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "-1.309",
+        "-0x1.4f#9",
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "-1.4601391056210009726721818194302",
+        "-0x1.75cbad2a40bd5482f22bcd714#100",
+    );
+}
+
+#[test]
+fn test_arg_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (arg, o) = x.clone().arg_prec(prec);
+        assert!(arg.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(arg.to_string(), out);
+        assert_eq!(to_hex_string(&arg), out_hex);
+
+        let (arg_alt, o_alt) = x.arg_prec_ref(prec);
+        assert!(arg_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        10,
+        "0.92773",
+        "0x0.ed8#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        "0.71289",
+        "0x0.b68#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        100,
+        "0.71328454043905034719784539053977",
+        "0x0.b699d0cded98aa1041bd2131c#100",
+        Greater,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        20,
+        "2.6858711",
+        "0x2.af954#20",
+        Less,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        5,
+        "-1.31",
+        "-0x1.5#5",
+        Less,
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        20,
+        "-1.4601383",
+        "-0x1.75cba#20",
+        Greater,
+    );
+}
+
+#[test]
+fn test_arg_round() {
+    let test = |s, s_hex, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (arg, o) = x.clone().arg_round(rm);
+        assert!(arg.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(arg.to_string(), out);
+        assert_eq!(to_hex_string(&arg), out_hex);
+
+        let (arg_alt, o_alt) = x.arg_round_ref(rm);
+        assert!(arg_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Floor,
+        "0.71328454043905032",
+        "0x0.b699d0cded98a8#53",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Ceiling,
+        "0.71328454043905043",
+        "0x0.b699d0cded98b0#53",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Down,
+        "0.71328454043905032",
+        "0x0.b699d0cded98a8#53",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Up,
+        "0.71328454043905043",
+        "0x0.b699d0cded98b0#53",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Nearest,
+        "0.71328454043905032",
+        "0x0.b699d0cded98a8#53",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        Floor,
+        "2.6858729239722470",
+        "0x2.af955e31ac35c#53",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        Ceiling,
+        "2.6858729239722474",
+        "0x2.af955e31ac35e#53",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        Floor,
+        "-1.309",
+        "-0x1.4f#9",
+        Less,
+    );
+    test("(1.0 0.0)", "(0x1.0#1 0x0.0)", Exact, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+fn test_arg_prec_round() {
+    let test =
+        |s, s_hex, prec: u64, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.to_string(), s);
+
+            let (arg, o) = x.clone().arg_prec_round(prec, rm);
+            assert!(arg.is_valid());
+            assert_eq!(o, o_out);
+
+            assert_eq!(arg.to_string(), out);
+            assert_eq!(to_hex_string(&arg), out_hex);
+
+            let (arg_alt, o_alt) = x.arg_prec_round_ref(prec, rm);
+            assert!(arg_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+            assert_eq!(o_alt, o_out);
+        };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Floor,
+        "0.71289",
+        "0x0.b68#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Ceiling,
+        "0.71387",
+        "0x0.b6c#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Down,
+        "0.71289",
+        "0x0.b68#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Up,
+        "0.71387",
+        "0x0.b6c#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Nearest,
+        "0.71289",
+        "0x0.b68#10",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        20,
+        Up,
+        "2.6858749",
+        "0x2.af958#20",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        5,
+        Down,
+        "-1.25",
+        "-0x1.4#5",
+        Greater,
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        20,
+        Ceiling,
+        "-1.4601383",
+        "-0x1.75cba#20",
+        Greater,
+    );
+    test(
+        "(-1.0 -0.0)",
+        "(-0x1.0#1 -0x0.0)",
+        10,
+        Floor,
+        "-3.1445",
+        "-0x3.25#10",
+        Less,
+    );
+}
+
+#[test]
+fn arg_prec_fail() {
+    assert_panic!(Complex::ONE.arg_prec(0));
+    assert_panic!(Complex::ONE.arg_prec_ref(0));
+}
+
+#[test]
+fn arg_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    assert_panic!(x.clone().arg_round(Exact));
+    assert_panic!(x.arg_round_ref(Exact));
+}
+
+#[test]
+fn arg_prec_round_fail() {
+    assert_panic!(Complex::ONE.arg_prec_round(0, Floor));
+    assert_panic!(Complex::ONE.arg_prec_round_ref(0, Floor));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    assert_panic!(x.clone().arg_prec_round(1, Exact));
+    assert_panic!(x.arg_prec_round_ref(1, Exact));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn arg_prec_round_properties_helper(x: Complex, prec: u64, rm: RoundingMode) {
+    let (arg, o) = x.clone().arg_prec_round(prec, rm);
+    assert!(arg.is_valid());
+
+    let (arg_alt, o_alt) = x.arg_prec_round_ref(prec, rm);
+    assert!(arg_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+    assert_eq!(o_alt, o);
+
+    let (arg_alt, o_alt) = x
+        .imag_ref()
+        .atan2_prec_round_ref_ref(x.real_ref(), prec, rm);
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+    assert_eq!(o_alt, o);
+
+    if arg.is_normal() {
+        assert_eq!(arg.get_prec(), Some(prec));
+    }
+
+    if o == Equal {
+        let (arg_alt, o_alt) = x.arg_prec_round_ref(prec, Exact);
+        assert_eq!(
+            ComparableFloat(arg_alt.abs_negative_zero_ref()),
+            ComparableFloat(arg.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, Equal);
+    } else {
+        assert_panic!(x.arg_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn arg_prec_round_properties() {
+    complex_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        arg_prec_round_properties_helper(x, prec, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn arg_prec_properties_helper(x: Complex, prec: u64) {
+    let (arg, o) = x.clone().arg_prec(prec);
+    assert!(arg.is_valid());
+
+    let (arg_alt, o_alt) = x.arg_prec_ref(prec);
+    assert!(arg_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+    assert_eq!(o_alt, o);
+
+    let (arg_alt, o_alt) = x.arg_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn arg_prec_properties() {
+    complex_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        arg_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn arg_round_properties_helper(x: Complex, rm: RoundingMode) {
+    let (arg, o) = x.clone().arg_round(rm);
+    assert!(arg.is_valid());
+
+    let (arg_alt, o_alt) = x.arg_round_ref(rm);
+    assert!(arg_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits()
+    );
+    let (arg_alt, o_alt) = x.arg_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn arg_round_properties() {
+    complex_rounding_mode_pair_gen_var_1().test_properties(|(x, rm)| {
+        arg_round_properties_helper(x, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn arg_properties_helper(x: Complex) {
+    let arg = x.clone().arg();
+    assert!(arg.is_valid());
+
+    let arg_alt = x.arg_ref();
+    assert!(arg_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+
+    let (arg_alt, _) = x.arg_round_ref(Nearest);
+    assert_eq!(ComparableFloatRef(&arg_alt), ComparableFloatRef(&arg));
+
+    assert_eq!(ComparableFloat(x.conj_ref().arg()), ComparableFloat(-&arg));
+}
+
+#[test]
+fn arg_properties() {
+    complex_gen().test_properties(|x| {
+        arg_properties_helper(x);
+    });
+}
@@ -0,0 +1,159 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_complex::ComparableComplexRef;
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::complex_gen;
+use malachite_float::ComparableFloatRef;
+
+#[test]
+fn test_conj() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let conj = x.clone().conj();
+        assert!(conj.is_valid());
+
+        assert_eq!(conj.to_string(), out);
+        assert_eq!(to_hex_string(&conj), out_hex);
+
+        let conj_alt = x.conj_ref();
+        assert!(conj_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&conj_alt), ComparableComplexRef(&conj));
+
+        let mut conj_alt = x.clone();
+        conj_alt.conj_assign();
+        assert!(conj_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&conj_alt), ComparableComplexRef(&conj));
+    };
+    test("(NaN NaN)", "(NaN NaN)", "(NaN NaN)", "(NaN NaN)");
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity -0.0)",
+        "(Infinity -0x0.0)",
+    );
+    test(
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(-Infinity -0.0)",
+        "(-Infinity -0x0.0)",
+    );
+    test(
+        "(NaN Infinity)",
+        "(NaN Infinity)",
+        "(NaN -Infinity)",
+        "(NaN -Infinity)",
+    );
+    test("(0.0 0.0)", "(0x0.0 0x0.0)", "(0.0 -0.0)", "(0x0.0 -0x0.0)");
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(-0.0 0.0)",
+        "(-0x0.0 0x0.0)",
+    );
+    test(
+        "(-0.0 0.0)",
+        "(-0x0.0 0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(1.0 -0.0)",
+        "(0x1.0#1 -0x0.0)",
+    );
+    test(
+        "(-1.0 0.0)",
+        "(-0x1.0#1 0x0.0)",
+        "(-1.0 -0.0)",
+        "(-0x1.0#1 -0x0.0)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.0 -1.0)",
+        "(0x0.0 -0x1.0#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 -1.0)",
+        "(0x1.0#1 -0x1.0#1)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(3.0 -4.0)",
+        "(0x3.0#2 -0x4.0#3)",
+    );
+    test(
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        "(1.0 2.0)",
+        "(0x1.0#1 0x2.0#1)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(3.1415926535897931 -2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 -0x2.b7e151628aed2#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(-1.4142135623730951 -0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 -0x0.b17217f7d1cf78#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(123.0 456.0)",
+        "(0x7b.0#7 0x1c8.0#9)",
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(0.33333333333333333333333333333346 3.0)",
+        "(0x0.55555555555555555555555558#100 0x3.0#2)",
+    );
+}
+
+#[test]
+fn conj_properties() {
+    complex_gen().test_properties(|x| {
+        let conj = x.clone().conj();
+        assert!(conj.is_valid());
+
+        let conj_alt = x.conj_ref();
+        assert!(conj_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&conj_alt), ComparableComplexRef(&conj));
+
+        let mut conj_alt = x.clone();
+        conj_alt.conj_assign();
+        assert!(conj_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&conj_alt), ComparableComplexRef(&conj));
+
+        assert_eq!(
+            ComparableFloatRef(conj.real_ref()),
+            ComparableFloatRef(x.real_ref())
+        );
+        assert_eq!(
+            ComparableFloatRef(conj.imag_ref()),
+            ComparableFloatRef(&-x.imag_ref())
+        );
+        assert_eq!(
+            ComparableComplexRef(&conj.conj_ref()),
+            ComparableComplexRef(&x)
+        );
+        assert_eq!(conj.is_real(), x.is_real());
+        assert_eq!(conj.is_nan(), x.is_nan());
+    });
+}
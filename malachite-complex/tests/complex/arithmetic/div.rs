@@ -0,0 +1,1003 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{NaN, One};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::complex::arithmetic::div::div_prec_round_naive;
+use malachite_complex::test_util::generators::{
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1, complex_gen,
+    complex_gen_var_1, complex_pair_gen,
+};
+use malachite_complex::{ComparableComplexRef, Complex};
+use malachite_float::ComparableFloat;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_div() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+
+        let mut quotient_alt = x.clone();
+        quotient_alt /= y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+
+        let mut quotient_alt = x.clone();
+        quotient_alt /= &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+    };
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.0 -1.0)",
+        "(0x0.0 -0x1.0#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        "(-1.0 2.0)",
+        "(-0x1.0#3 0x2.0#3)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(-1.0315509055955960 -2.4277086018857768)",
+        "(-0x1.0813b85bb1388#53 -0x2.6d7e4f99513a4#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-0.14825678695004635 0.34891567155259462)",
+        "(-0x0.25f428235c48fc#53 0x0.59528996615978#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(154.64634146341463414634146341455 23.817073170731707317073170731711)",
+        "(0x9a.a576a2576a2576a2576a257#100 0x17.d12bb512bb512bb512bb512c#100)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 0.0)",
+        "(0x1.0#3 0x0.0)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(0.50 0.50)",
+        "(0x0.8#1 0x0.8#1)",
+    );
+}
+
+#[test]
+fn test_div_prec() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_val_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_val(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_assign(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_assign_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (1, 1),
+        "(-1.0 2.0)",
+        "(-0x1.0#1 0x2.0#1)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (10, 10),
+        "(-1.0000 2.0000)",
+        "(-0x1.000#10 0x2.00#10)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (1, 1),
+        "(-1.0 -2.0)",
+        "(-0x1.0#1 -0x2.0#1)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 20),
+        "(-1.0312 -2.4277077)",
+        "(-0x1.080#10 -0x2.6d7e4#20)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (100, 100),
+        "(-1.0315509055955960052856526189581 -2.4277086018857768918632759733375)",
+        "(-0x1.0813b85bb1387ddd750b907c8#100 -0x2.6d7e4f99513a4a209fd8c8fe4#100)",
+        (Less, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 10),
+        "(-0.14825678 0.34912)",
+        "(-0x0.25f428#20 0x0.596#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 5),
+        "(154.64624 24.0)",
+        "(0x9a.a57#20 0x18.0#5)",
+        (Less, Greater),
+    );
+}
+
+#[test]
+fn test_div_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_round(y.clone(), rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_round_val_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_val(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_round_assign(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_round_assign_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Floor),
+        "(-1.0315509055955960 -2.4277086018857772)",
+        "(-0x1.0813b85bb1388#53 -0x2.6d7e4f99513a6#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Ceiling, Ceiling),
+        "(-1.0315509055955958 -2.4277086018857768)",
+        "(-0x1.0813b85bb1387#53 -0x2.6d7e4f99513a4#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Down, Up),
+        "(-1.0315509055955958 -2.4277086018857772)",
+        "(-0x1.0813b85bb1387#53 -0x2.6d7e4f99513a6#53)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Up, Down),
+        "(-1.0315509055955960 -2.4277086018857768)",
+        "(-0x1.0813b85bb1388#53 -0x2.6d7e4f99513a4#53)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Nearest, Nearest),
+        "(-1.0315509055955960 -2.4277086018857768)",
+        "(-0x1.0813b85bb1388#53 -0x2.6d7e4f99513a4#53)",
+        (Less, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Ceiling),
+        "(-0.14825678695004635 0.34891567155259462)",
+        "(-0x0.25f428235c48fc#53 0x0.59528996615978#53)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Floor, Ceiling),
+        "(154.64634146341463414634146341455 23.817073170731707317073170731711)",
+        "(0x9a.a576a2576a2576a2576a257#100 0x17.d12bb512bb512bb512bb512c#100)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Ceiling, Floor),
+        "(154.64634146341463414634146341475 23.817073170731707317073170731686)",
+        "(0x9a.a576a2576a2576a2576a258#100 0x17.d12bb512bb512bb512bb512a#100)",
+        (Greater, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(2.0 0.0)",
+        "(0x2.0#1 0x0.0)",
+        (Exact, Exact),
+        "(1.5 2.0)",
+        "(0x1.8#3 0x2.0#3)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_div_prec_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_prec_round(y.clone(), prec, rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_round_val_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_round_assign(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_round_assign_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&quotient_alt),
+            ComparableComplexRef(&quotient)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(-1.0332 -2.4258)",
+        "(-0x1.088#10 -0x2.6d#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(-1.0312 -2.4297)",
+        "(-0x1.080#10 -0x2.6e#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Down, Down),
+        "(-1.0312 -2.4258)",
+        "(-0x1.080#10 -0x2.6d#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Up, Up),
+        "(-1.0332 -2.4297)",
+        "(-0x1.088#10 -0x2.6e#10)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(-1.0312 -2.4258)",
+        "(-0x1.080#10 -0x2.6d#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 5),
+        (Up, Down),
+        "(-0.14825702 0.344)",
+        "(-0x0.25f42c#20 0x0.58#5)",
+        (Less, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        (Down, Up),
+        "(152.0 23.817078)",
+        "(0x98.0#5 0x17.d12c#20)",
+        (Less, Greater),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (10, 10),
+        (Exact, Floor),
+        "(-1.0000 2.0000)",
+        "(-0x1.000#10 0x2.00#10)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn div_prec_fail() {
+    assert_panic!(Complex::ONE.div_prec(Complex::ONE, (0, 1)));
+    assert_panic!(Complex::ONE.div_prec(Complex::ONE, (1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.div_prec_assign(Complex::ONE, (0, 1))
+    });
+}
+
+#[test]
+fn div_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    let y = parse_hex_string("(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)");
+    assert_panic!(x.clone().div_round(y.clone(), (Exact, Floor)));
+    assert_panic!(x.clone().div_round_val_ref(&y, (Exact, Floor)));
+    assert_panic!(x.div_round_ref_val(y.clone(), (Exact, Floor)));
+    assert_panic!(x.div_round_ref_ref(&y, (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.div_round_assign(y.clone(), (Exact, Floor))
+    });
+    assert_panic!({
+        let mut x = x.clone();
+        x.div_round_assign_ref(&y, (Exact, Floor))
+    });
+}
+
+#[test]
+fn div_prec_round_fail() {
+    assert_panic!(Complex::ONE.div_prec_round(Complex::ONE, (0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.div_prec_round(Complex::ONE, (1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    let y = parse_hex_string("(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)");
+    assert_panic!(x.clone().div_prec_round(y.clone(), (1, 1), (Exact, Floor)));
+    assert_panic!(x.div_prec_round_ref_ref(&y, (1, 1), (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.div_prec_round_assign(y.clone(), (1, 1), (Exact, Floor))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_prec_round_properties_helper(
+    x: Complex,
+    y: Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) {
+    let (quotient, o) = x.clone().div_prec_round(y.clone(), prec, rm);
+    assert!(quotient.is_valid());
+
+    let (quotient_alt, o_alt) = x.clone().div_prec_round_val_ref(&y, prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut quotient_alt = x.clone();
+    let o_alt = quotient_alt.div_prec_round_assign(y.clone(), prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut quotient_alt = x.clone();
+    let o_alt = quotient_alt.div_prec_round_assign_ref(&y, prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    if let Some((quotient_alt, o_alt)) = div_prec_round_naive(&x, &y, prec, rm) {
+        assert_eq!(quotient_alt, quotient);
+        assert_eq!(o_alt, o);
+    }
+
+    if quotient.real_ref().is_normal() {
+        assert_eq!(quotient.real_ref().get_prec(), Some(prec.0));
+    }
+    if quotient.imag_ref().is_normal() {
+        assert_eq!(quotient.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(quotient_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(quotient.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.div_prec_round_ref_ref(&y, prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(quotient_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(quotient.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.div_prec_round_ref_ref(&y, prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn div_prec_round_properties() {
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+        .test_properties(|(x, y, p_r, p_i, rm_r, rm_i)| {
+            div_prec_round_properties_helper(x, y, (p_r, p_i), (rm_r, rm_i));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_prec_properties_helper(x: Complex, y: Complex, prec: (u64, u64)) {
+    let (quotient, o) = x.clone().div_prec(y.clone(), prec);
+    assert!(quotient.is_valid());
+
+    let (quotient_alt, o_alt) = x.clone().div_prec_val_ref(&y, prec);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_prec_ref_val(y.clone(), prec);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_prec_ref_ref(&y, prec);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut quotient_alt = x.clone();
+    let o_alt = quotient_alt.div_prec_assign(y.clone(), prec);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut quotient_alt = x.clone();
+    let o_alt = quotient_alt.div_prec_assign_ref(&y, prec);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, (Nearest, Nearest));
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn div_prec_properties() {
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1().test_properties(|(x, y, p_r, p_i)| {
+        div_prec_properties_helper(x, y, (p_r, p_i));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_round_properties_helper(x: Complex, y: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (quotient, o) = x.clone().div_round(y.clone(), rm);
+    assert!(quotient.is_valid());
+
+    let (quotient_alt, o_alt) = x.clone().div_round_val_ref(&y, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_round_ref_val(y.clone(), rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_round_ref_ref(&y, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut quotient_alt = x.clone();
+    let o_alt = quotient_alt.div_round_assign(y.clone(), rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut quotient_alt = x.clone();
+    let o_alt = quotient_alt.div_round_assign_ref(&y, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits(),
+        y.real_ref().significant_bits(),
+        y.imag_ref().significant_bits()
+    );
+    let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, (prec, prec), rm);
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn div_round_properties() {
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, y, rm_r, rm_i)| {
+            div_round_properties_helper(x, y, (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_properties_helper(x: Complex, y: Complex) {
+    let quotient = x.clone() / y.clone();
+    assert!(quotient.is_valid());
+
+    let quotient_alt = x.clone() / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+
+    let quotient_alt = &x / y.clone();
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+
+    let quotient_alt = &x / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+
+    let mut quotient_alt = x.clone();
+    quotient_alt /= y.clone();
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+
+    let mut quotient_alt = x.clone();
+    quotient_alt /= &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+
+    let (quotient_alt, _) = x.div_round_ref_ref(&y, (Nearest, Nearest));
+    assert_eq!(
+        ComparableComplexRef(&quotient_alt),
+        ComparableComplexRef(&quotient)
+    );
+}
+
+#[test]
+fn div_properties() {
+    complex_pair_gen().test_properties(|(x, y)| {
+        div_properties_helper(x, y);
+    });
+
+    complex_gen().test_properties(|x| {
+        assert!((&x / Complex::NAN).is_nan());
+        assert!((Complex::NAN / &x).is_nan());
+    });
+
+    complex_gen_var_1().test_properties(|x| {
+        assert_eq!(&x / Complex::ONE, x);
+        if !x.is_zero() {
+            assert_eq!(&x / &x, Complex::ONE);
+        }
+    });
+}
@@ -0,0 +1,669 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::{
+    complex_gen, complex_rounding_mode_rounding_mode_triple_gen_var_1,
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+    complex_unsigned_unsigned_triple_gen_var_1,
+};
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::panic::catch_unwind;
+
+const LIMIT: usize = 1000;
+
+#[test]
+fn test_exp() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let exp = x.clone().exp();
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let exp_alt = (&x).exp();
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+
+        let mut exp_alt = x.clone();
+        exp_alt.exp_assign();
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    };
+    test("(NaN NaN)", "(NaN NaN)", "(NaN NaN)", "(NaN NaN)");
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+    );
+    test(
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(Infinity 1.0)",
+        "(Infinity 0x1.0#1)",
+        "(Infinity Infinity)",
+        "(Infinity Infinity)",
+    );
+    test(
+        "(-Infinity 1.0)",
+        "(-Infinity 0x1.0#1)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(1.0 Infinity)",
+        "(0x1.0#1 Infinity)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test("(NaN 0.0)", "(NaN 0x0.0)", "(NaN 0.0)", "(NaN 0x0.0)");
+    test("(0.0 0.0)", "(0x0.0 0x0.0)", "(1.0 0.0)", "(0x1.0#1 0x0.0)");
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(1.0 -0.0)",
+        "(0x1.0#1 -0x0.0)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(2.0 0.0)",
+        "(0x2.0#1 0x0.0)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.50 1.0)",
+        "(0x0.8#1 0x1.0#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 2.0)",
+        "(0x1.0#1 0x2.0#1)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(-14.0 -16.0)",
+        "(-0xe.0#3 -0x10.0#3)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-21.098154284965720 9.5057635828242226)",
+        "(-0x15.1920a3a3e3d9#53 0x9.8179b8dfbce20#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(0.18701484969937149 0.15534217892728849)",
+        "(0x0.2fe03487533d6c#53 0x0.27c4814a2e9d16#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(-2.335e53 1.182e53)",
+        "(-0x2.70E+44#9 0x1.3cE+44#9)",
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(-1.3816458289975799280807268989570 -0.19694883667659979398790526361154)",
+        "(-0x1.61b38a82330cd0045f2ec9dfc#100 -0x0.326b3d2c82e0b820df63a006b4#100)",
+    );
+}
+
+#[test]
+fn test_exp_prec() {
+    let test =
+        |s, s_hex, prec: (u64, u64), out: &str, out_hex: &str, o_out: (Ordering, Ordering)| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.to_string(), s);
+
+            let (exp, o) = x.clone().exp_prec(prec);
+            assert!(exp.is_valid());
+            assert_eq!(o, o_out);
+
+            assert_eq!(exp.to_string(), out);
+            assert_eq!(to_hex_string(&exp), out_hex);
+
+            let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+            assert!(exp_alt.is_valid());
+            assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+            assert_eq!(o_alt, o_out);
+
+            let mut exp_alt = x.clone();
+            let o_alt = exp_alt.exp_prec_assign(prec);
+            assert!(exp_alt.is_valid());
+            assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+            assert_eq!(o_alt, o_out);
+        };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (1, 1),
+        "(-16.0 -16.0)",
+        "(-0x1.0E+1#1 -0x1.0E+1#1)",
+        (Less, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (10, 10),
+        "(-13.125 -15.203)",
+        "(-0xd.20#10 -0xf.34#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (1, 1),
+        "(-16.0 8.0)",
+        "(-0x1.0E+1#1 0x8.0#1)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 20),
+        "(-21.094 9.5057678)",
+        "(-0x15.18#10 0x9.817a#20)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (100, 100),
+        "(-21.098154284965721113759359900192 9.5057635828242218377205590453195)",
+        "(-0x15.1920a3a3e3d9715eed1a52b4#100 0x9.8179b8dfbce1c75950d6189f#100)",
+        (Less, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (20, 10),
+        "(0.18701482 0.15527)",
+        "(0x0.2fe034#20 0x0.27c#10)",
+        (Less, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (20, 5),
+        "(-2.3365867e53 1.20e53)",
+        "(-0x2.7083cE+44#20 0x1.4E+44#5)",
+        (Less, Greater),
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        "(-1.38 -0.19694877)",
+        "(-0x1.6#5 -0x0.326b3c#20)",
+        (Greater, Greater),
+    );
+}
+
+#[test]
+fn test_exp_round() {
+    let test = |s,
+                s_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_round(rm);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_round_ref(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_round_assign(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Floor),
+        "(-21.098154284965723 9.5057635828242208)",
+        "(-0x15.1920a3a3e3da#53 0x9.8179b8dfbce18#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Ceiling, Ceiling),
+        "(-21.098154284965720 9.5057635828242226)",
+        "(-0x15.1920a3a3e3d9#53 0x9.8179b8dfbce20#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Down, Up),
+        "(-21.098154284965720 9.5057635828242226)",
+        "(-0x15.1920a3a3e3d9#53 0x9.8179b8dfbce20#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Up, Down),
+        "(-21.098154284965723 9.5057635828242208)",
+        "(-0x15.1920a3a3e3da#53 0x9.8179b8dfbce18#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Nearest, Nearest),
+        "(-21.098154284965720 9.5057635828242226)",
+        "(-0x15.1920a3a3e3d9#53 0x9.8179b8dfbce20#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Ceiling),
+        "(0.18701484969937149 0.15534217892728852)",
+        "(0x0.2fe03487533d6c#53 0x0.27c4814a2e9d18#53)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (Ceiling, Floor),
+        "(-2.335e53 1.182e53)",
+        "(-0x2.70E+44#9 0x1.3cE+44#9)",
+        (Greater, Less),
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (Exact, Exact),
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_exp_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_round_assign(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(-21.125 9.5156)",
+        "(-0x15.20#10 0x9.84#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(-21.094 9.5000)",
+        "(-0x15.18#10 0x9.80#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Down, Down),
+        "(-21.094 9.5000)",
+        "(-0x15.18#10 0x9.80#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Up, Up),
+        "(-21.125 9.5156)",
+        "(-0x15.20#10 0x9.84#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(-21.094 9.5000)",
+        "(-0x15.18#10 0x9.80#10)",
+        (Greater, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (20, 5),
+        (Up, Down),
+        "(0.18701506 0.148)",
+        "(0x0.2fe038#20 0x0.26#5)",
+        (Greater, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (5, 20),
+        (Down, Up),
+        "(-2.27e53 1.1841616e53)",
+        "(-0x2.6E+44#5 0x1.3c7faE+44#20)",
+        (Greater, Greater),
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 20),
+        (Nearest, Floor),
+        "(-1.3816452 -0.19694901)",
+        "(-0x1.61b38#20 -0x0.326b40#20)",
+        (Greater, Less),
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (10, 10),
+        (Exact, Exact),
+        "(1.0000 0.0)",
+        "(0x1.000#10 0x0.0)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn exp_prec_fail() {
+    assert_panic!(Complex::ONE.exp_prec((0, 1)));
+    assert_panic!(Complex::ONE.exp_prec_ref((1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.exp_prec_assign((0, 1))
+    });
+}
+
+#[test]
+fn exp_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    assert_panic!(x.clone().exp_round((Exact, Floor)));
+    assert_panic!(x.exp_round_ref((Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.exp_round_assign((Exact, Floor))
+    });
+}
+
+#[test]
+fn exp_prec_round_fail() {
+    assert_panic!(Complex::ONE.exp_prec_round((0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.exp_prec_round_ref((1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    assert_panic!(x.clone().exp_prec_round((1, 1), (Exact, Floor)));
+    assert_panic!(x.exp_prec_round_ref((1, 1), (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.exp_prec_round_assign((1, 1), (Exact, Floor))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_prec_round_properties_helper(
+    x: Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) {
+    let (exp, o) = x.clone().exp_prec_round(prec, rm);
+    assert!(exp.is_valid());
+
+    let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut exp_alt = x.clone();
+    let o_alt = exp_alt.exp_prec_round_assign(prec, rm);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+
+    // Annex G of the ISO C99 standard leaves the signs of the zeros in exp(-Infinity + y * i)
+    // unspecified when y is not finite
+    if !(x.real_ref().is_infinite() && x.real_ref().is_sign_negative() && !x.imag_ref().is_finite())
+    {
+        let (conj_exp, o_alt) = x.conj_ref().exp_prec_round(prec, (rm.0, -rm.1));
+        assert_eq!(
+            ComparableComplex(conj_exp),
+            ComparableComplex(exp.conj_ref())
+        );
+        assert_eq!(o_alt, (o.0, o.1.reverse()));
+    }
+
+    if x.imag_ref().is_zero() && x.imag_ref().is_sign_positive() && x.real_ref().is_finite() {
+        let (real, o_r) = x.real_ref().exp_prec_round_ref(prec.0, rm.0);
+        assert_eq!(
+            ComparableFloatRef(exp.real_ref()),
+            ComparableFloatRef(&real)
+        );
+        assert_eq!(o.0, o_r);
+        assert!(exp.imag_ref().is_zero());
+        assert_eq!(o.1, Equal);
+    }
+    if x.real_ref().is_zero() && x.imag_ref().is_finite() && !x.imag_ref().is_zero() {
+        let (real, o_r) = x.imag_ref().cos_prec_round_ref(prec.0, rm.0);
+        assert_eq!(
+            ComparableFloatRef(exp.real_ref()),
+            ComparableFloatRef(&real)
+        );
+        assert_eq!(o.0, o_r);
+        let (imag, o_i) = x.imag_ref().sin_prec_round_ref(prec.1, rm.1);
+        assert_eq!(
+            ComparableFloatRef(exp.imag_ref()),
+            ComparableFloatRef(&imag)
+        );
+        assert_eq!(o.1, o_i);
+    }
+
+    if exp.real_ref().is_normal() {
+        assert_eq!(exp.real_ref().get_prec(), Some(prec.0));
+    }
+    if exp.imag_ref().is_normal() {
+        assert_eq!(exp.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(exp_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(exp.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.exp_prec_round_ref(prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(exp_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(exp.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.exp_prec_round_ref(prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn exp_prec_round_properties() {
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1()
+        .test_properties_with_limit(LIMIT, |(x, p_r, p_i, rm_r, rm_i)| {
+            exp_prec_round_properties_helper(x, (p_r, p_i), (rm_r, rm_i));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_prec_properties_helper(x: Complex, prec: (u64, u64)) {
+    let (exp, o) = x.clone().exp_prec(prec);
+    assert!(exp.is_valid());
+
+    let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut exp_alt = x.clone();
+    let o_alt = exp_alt.exp_prec_assign(prec);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, (Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn exp_prec_properties() {
+    complex_unsigned_unsigned_triple_gen_var_1().test_properties_with_limit(
+        LIMIT,
+        |(x, p_r, p_i)| {
+            exp_prec_properties_helper(x, (p_r, p_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_round_properties_helper(x: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (exp, o) = x.clone().exp_round(rm);
+    assert!(exp.is_valid());
+
+    let (exp_alt, o_alt) = x.exp_round_ref(rm);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut exp_alt = x.clone();
+    let o_alt = exp_alt.exp_round_assign(rm);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits()
+    );
+    let (exp_alt, o_alt) = x.exp_prec_round_ref((prec, prec), rm);
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn exp_round_properties() {
+    complex_rounding_mode_rounding_mode_triple_gen_var_1().test_properties_with_limit(
+        LIMIT,
+        |(x, rm_r, rm_i)| {
+            exp_round_properties_helper(x, (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_properties_helper(x: Complex) {
+    let exp = x.clone().exp();
+    assert!(exp.is_valid());
+
+    let exp_alt = (&x).exp();
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+
+    let mut exp_alt = x.clone();
+    exp_alt.exp_assign();
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+
+    let (exp_alt, _) = x.exp_round_ref((Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&exp_alt), ComparableComplexRef(&exp));
+
+    // Annex G of the ISO C99 standard leaves the signs of the zeros in exp(-Infinity + y * i)
+    // unspecified when y is not finite
+    if !(x.real_ref().is_infinite() && x.real_ref().is_sign_negative() && !x.imag_ref().is_finite())
+    {
+        assert_eq!(
+            ComparableComplex(x.conj_ref().exp()),
+            ComparableComplex(exp.conj_ref())
+        );
+    }
+}
+
+#[test]
+fn exp_properties() {
+    complex_gen().test_properties_with_limit(LIMIT, |x| {
+        exp_properties_helper(x);
+    });
+
+    float_gen().test_properties_with_limit(LIMIT, |x| {
+        let (real, imag) = Complex::from(&x).exp().into_real_imag();
+        assert_eq!(ComparableFloat(real), ComparableFloat(x.exp()));
+        assert!(imag.is_zero());
+    });
+}
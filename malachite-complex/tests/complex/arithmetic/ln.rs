@@ -0,0 +1,661 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Ln, LnAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::{
+    complex_gen, complex_rounding_mode_rounding_mode_triple_gen_var_1,
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+    complex_unsigned_unsigned_triple_gen_var_1,
+};
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+const LIMIT: usize = 1000;
+
+#[test]
+fn test_ln() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ln = x.clone().ln();
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+
+        let ln_alt = (&x).ln();
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+
+        let mut ln_alt = x.clone();
+        ln_alt.ln_assign();
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    };
+    test("(NaN NaN)", "(NaN NaN)", "(NaN NaN)", "(NaN NaN)");
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+    );
+    test(
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(Infinity 4.0)",
+        "(Infinity 0x4.0#1)",
+    );
+    test(
+        "(1.0 Infinity)",
+        "(0x1.0#1 Infinity)",
+        "(Infinity 2.0)",
+        "(Infinity 0x2.0#1)",
+    );
+    test(
+        "(NaN Infinity)",
+        "(NaN Infinity)",
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+    );
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(-Infinity -4.0)",
+        "(-Infinity -0x4.0#1)",
+    );
+    test(
+        "(-0.0 0.0)",
+        "(-0x0.0 0x0.0)",
+        "(-Infinity 4.0)",
+        "(-Infinity 0x4.0#1)",
+    );
+    test("(1.0 0.0)", "(0x1.0#1 0x0.0)", "(0.0 0.0)", "(0x0.0 0x0.0)");
+    test(
+        "(-1.0 0.0)",
+        "(-0x1.0#1 0x0.0)",
+        "(0.0 4.0)",
+        "(0x0.0 0x4.0#1)",
+    );
+    test(
+        "(-1.0 -0.0)",
+        "(-0x1.0#1 -0x0.0)",
+        "(0.0 -4.0)",
+        "(0x0.0 -0x4.0#1)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.0 2.0)",
+        "(0x0.0 0x2.0#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(0.25 1.0)",
+        "(0x0.4#1 0x1.0#1)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.5 0.88)",
+        "(0x1.8#3 0x0.e#3)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(1.4241570377303034 0.71328454043905032)",
+        "(0x1.6c958e3d6b7e0#53 0x0.b699d0cded98a8#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(0.45422060519891211 2.6858729239722470)",
+        "(0x0.7447cd347fa7dc#53 0x2.af955e31ac35c#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(6.156 -1.309)",
+        "(0x6.28#9 -0x1.4f#9)",
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(1.1047473349640168652467525103255 -1.4601391056210009726721818194302)",
+        "(0x1.1ad0b8aa037bee1a0ed7f9aaa#100 -0x1.75cbad2a40bd5482f22bcd714#100)",
+    );
+}
+
+#[test]
+fn test_ln_prec() {
+    let test =
+        |s, s_hex, prec: (u64, u64), out: &str, out_hex: &str, o_out: (Ordering, Ordering)| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.to_string(), s);
+
+            let (ln, o) = x.clone().ln_prec(prec);
+            assert!(ln.is_valid());
+            assert_eq!(o, o_out);
+
+            assert_eq!(ln.to_string(), out);
+            assert_eq!(to_hex_string(&ln), out_hex);
+
+            let (ln_alt, o_alt) = x.ln_prec_ref(prec);
+            assert!(ln_alt.is_valid());
+            assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+            assert_eq!(o_alt, o_out);
+
+            let mut ln_alt = x.clone();
+            let o_alt = ln_alt.ln_prec_assign(prec);
+            assert!(ln_alt.is_valid());
+            assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+            assert_eq!(o_alt, o_out);
+        };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (1, 1),
+        "(2.0 1.0)",
+        "(0x2.0#1 0x1.0#1)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (10, 10),
+        "(1.6094 0.92773)",
+        "(0x1.9c0#10 0x0.ed8#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (1, 1),
+        "(1.0 0.50)",
+        "(0x1.0#1 0x0.8#1)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 20),
+        "(1.4238 0.71328449)",
+        "(0x1.6c8#10 0x0.b699d#20)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (100, 100),
+        "(1.4241570377303033949666419345434 0.71328454043905034719784539053977)",
+        "(0x1.6c958e3d6b7dfc8326e8cf20a#100 0x0.b699d0cded98aa1041bd2131c#100)",
+        (Less, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (20, 10),
+        "(0.45422077 2.6875)",
+        "(0x0.7447d0#20 0x2.b0#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (20, 5),
+        "(6.1576080 -1.31)",
+        "(0x6.28590#20 -0x1.5#5)",
+        (Less, Less),
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        "(1.12 -1.4601383)",
+        "(0x1.2#5 -0x1.75cba#20)",
+        (Greater, Greater),
+    );
+}
+
+#[test]
+fn test_ln_round() {
+    let test = |s,
+                s_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_round(rm);
+        assert!(ln.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+
+        let (ln_alt, o_alt) = x.ln_round_ref(rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_round_assign(rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Floor),
+        "(1.4241570377303032 0.71328454043905032)",
+        "(0x1.6c958e3d6b7df#53 0x0.b699d0cded98a8#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Ceiling, Ceiling),
+        "(1.4241570377303034 0.71328454043905043)",
+        "(0x1.6c958e3d6b7e0#53 0x0.b699d0cded98b0#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Down, Up),
+        "(1.4241570377303032 0.71328454043905043)",
+        "(0x1.6c958e3d6b7df#53 0x0.b699d0cded98b0#53)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Up, Down),
+        "(1.4241570377303034 0.71328454043905032)",
+        "(0x1.6c958e3d6b7e0#53 0x0.b699d0cded98a8#53)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Nearest, Nearest),
+        "(1.4241570377303034 0.71328454043905032)",
+        "(0x1.6c958e3d6b7e0#53 0x0.b699d0cded98a8#53)",
+        (Greater, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Ceiling),
+        "(0.45422060519891211 2.6858729239722474)",
+        "(0x0.7447cd347fa7dc#53 0x2.af955e31ac35e#53)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (Ceiling, Floor),
+        "(6.172 -1.309)",
+        "(0x6.2c#9 -0x1.4f#9)",
+        (Greater, Less),
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        (Exact, Exact),
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_ln_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_prec_round(prec, rm);
+        assert!(ln.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+
+        let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_prec_round_assign(prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(1.4238 0.71387)",
+        "(0x1.6c8#10 0x0.b6c#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(1.4258 0.71289)",
+        "(0x1.6d0#10 0x0.b68#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Down, Down),
+        "(1.4238 0.71289)",
+        "(0x1.6c8#10 0x0.b68#10)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Up, Up),
+        "(1.4258 0.71387)",
+        "(0x1.6d0#10 0x0.b6c#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(1.4238 0.71289)",
+        "(0x1.6c8#10 0x0.b68#10)",
+        (Less, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (20, 5),
+        (Up, Down),
+        "(0.45422077 2.62)",
+        "(0x0.7447d0#20 0x2.a#5)",
+        (Greater, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (5, 20),
+        (Down, Up),
+        "(6.00 -1.3073311)",
+        "(0x6.0#5 -0x1.4ead4#20)",
+        (Less, Less),
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 20),
+        (Nearest, Floor),
+        "(1.1047478 -1.4601402)",
+        "(0x1.1ad0c#20 -0x1.75cbc#20)",
+        (Greater, Less),
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        (10, 10),
+        (Exact, Exact),
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn ln_prec_fail() {
+    assert_panic!(Complex::ONE.ln_prec((0, 1)));
+    assert_panic!(Complex::ONE.ln_prec_ref((1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.ln_prec_assign((0, 1))
+    });
+}
+
+#[test]
+fn ln_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    assert_panic!(x.clone().ln_round((Exact, Floor)));
+    assert_panic!(x.ln_round_ref((Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.ln_round_assign((Exact, Floor))
+    });
+}
+
+#[test]
+fn ln_prec_round_fail() {
+    assert_panic!(Complex::ONE.ln_prec_round((0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.ln_prec_round_ref((1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    assert_panic!(x.clone().ln_prec_round((1, 1), (Exact, Floor)));
+    assert_panic!(x.ln_prec_round_ref((1, 1), (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.ln_prec_round_assign((1, 1), (Exact, Floor))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_prec_round_properties_helper(x: Complex, prec: (u64, u64), rm: (RoundingMode, RoundingMode)) {
+    let (ln, o) = x.clone().ln_prec_round(prec, rm);
+    assert!(ln.is_valid());
+
+    let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, rm);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut ln_alt = x.clone();
+    let o_alt = ln_alt.ln_prec_round_assign(prec, rm);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let (conj_ln, o_alt) = x.conj_ref().ln_prec_round(prec, (rm.0, -rm.1));
+    assert_eq!(ComparableComplex(conj_ln), ComparableComplex(ln.conj_ref()));
+    assert_eq!(o_alt, (o.0, o.1.reverse()));
+
+    if x.imag_ref().is_zero()
+        && x.imag_ref().is_sign_positive()
+        && x.real_ref().is_finite()
+        && *x.real_ref() > 0u32
+    {
+        let (real, o_r) = x.real_ref().ln_prec_round_ref(prec.0, rm.0);
+        assert_eq!(ComparableFloatRef(ln.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(o.0, o_r);
+        assert!(ln.imag_ref().is_zero());
+        assert_eq!(o.1, Equal);
+    }
+    if x.is_finite() && !x.is_zero() {
+        let (imag, o_i) = x.arg_prec_round_ref(prec.1, rm.1);
+        assert_eq!(ComparableFloatRef(ln.imag_ref()), ComparableFloatRef(&imag));
+        assert_eq!(o.1, o_i);
+    }
+
+    if ln.real_ref().is_normal() {
+        assert_eq!(ln.real_ref().get_prec(), Some(prec.0));
+    }
+    if ln.imag_ref().is_normal() {
+        assert_eq!(ln.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(ln_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(ln.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.ln_prec_round_ref(prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(ln_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(ln.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.ln_prec_round_ref(prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn ln_prec_round_properties() {
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1()
+        .test_properties_with_limit(LIMIT, |(x, p_r, p_i, rm_r, rm_i)| {
+            ln_prec_round_properties_helper(x, (p_r, p_i), (rm_r, rm_i));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_prec_properties_helper(x: Complex, prec: (u64, u64)) {
+    let (ln, o) = x.clone().ln_prec(prec);
+    assert!(ln.is_valid());
+
+    let (ln_alt, o_alt) = x.ln_prec_ref(prec);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut ln_alt = x.clone();
+    let o_alt = ln_alt.ln_prec_assign(prec);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, (Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn ln_prec_properties() {
+    complex_unsigned_unsigned_triple_gen_var_1().test_properties_with_limit(
+        LIMIT,
+        |(x, p_r, p_i)| {
+            ln_prec_properties_helper(x, (p_r, p_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_round_properties_helper(x: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (ln, o) = x.clone().ln_round(rm);
+    assert!(ln.is_valid());
+
+    let (ln_alt, o_alt) = x.ln_round_ref(rm);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut ln_alt = x.clone();
+    let o_alt = ln_alt.ln_round_assign(rm);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits()
+    );
+    let (ln_alt, o_alt) = x.ln_prec_round_ref((prec, prec), rm);
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn ln_round_properties() {
+    complex_rounding_mode_rounding_mode_triple_gen_var_1().test_properties_with_limit(
+        LIMIT,
+        |(x, rm_r, rm_i)| {
+            ln_round_properties_helper(x, (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_properties_helper(x: Complex) {
+    let ln = x.clone().ln();
+    assert!(ln.is_valid());
+
+    let ln_alt = (&x).ln();
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+
+    let mut ln_alt = x.clone();
+    ln_alt.ln_assign();
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+
+    let (ln_alt, _) = x.ln_round_ref((Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&ln_alt), ComparableComplexRef(&ln));
+
+    assert_eq!(
+        ComparableComplex(x.conj_ref().ln()),
+        ComparableComplex(ln.conj_ref())
+    );
+}
+
+#[test]
+fn ln_properties() {
+    complex_gen().test_properties_with_limit(LIMIT, |x| {
+        ln_properties_helper(x);
+    });
+
+    float_gen().test_properties_with_limit(LIMIT, |x| {
+        if x < 0u32 {
+            let prec = x.significant_bits();
+            let (real, imag) = Complex::from(&x).ln().into_real_imag();
+            assert_eq!(ComparableFloat(real), ComparableFloat((-x).ln()));
+            assert_eq!(
+                ComparableFloat(imag),
+                ComparableFloat(Float::pi_prec(prec).0)
+            );
+        }
+    });
+}
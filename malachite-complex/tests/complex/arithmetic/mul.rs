@@ -0,0 +1,1012 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{NaN, One};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::complex::arithmetic::mul::mul_prec_round_naive;
+use malachite_complex::test_util::generators::{
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1, complex_gen,
+    complex_gen_var_1, complex_pair_gen,
+};
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::ComparableFloat;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mul() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+
+        let mut product_alt = x.clone();
+        product_alt *= y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+
+        let mut product_alt = x.clone();
+        product_alt *= &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+    };
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(-Infinity NaN)",
+        "(-Infinity NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(0.0 -0.0)",
+        "(0x0.0 -0x0.0)",
+    );
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(0.0 2.0)",
+        "(0x0.0 0x2.0#1)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        "(12.0 -2.0)",
+        "(0xc.0#3 -0x2.0#3)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(-6.3270523235220866 -1.6666449378555148)",
+        "(-0x6.53b9b3799bb14#53 -0x1.aaa93e1e2225c#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-6.3270523235220866 -1.6666449378555148)",
+        "(-0x6.53b9b3799bb14#53 -0x1.aaa93e1e2225c#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(-1327.0000000000000000000000000000 -521.00000000000000000000000000000)",
+        "(-0x52f.00000000000000000000000#100 -0x209.00000000000000000000000#100)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(-1.0 0.0)",
+        "(-0x1.0#1 0x0.0)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(NaN Infinity)",
+        "(NaN Infinity)",
+    );
+    test(
+        "(Infinity -1.0)",
+        "(Infinity -0x1.0#1)",
+        "(Infinity 1.0)",
+        "(Infinity 0x1.0#1)",
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+    test(
+        "(Infinity 1.0)",
+        "(Infinity 0x1.0#1)",
+        "(Infinity -1.0)",
+        "(Infinity -0x1.0#1)",
+        "(Infinity NaN)",
+        "(Infinity NaN)",
+    );
+}
+
+#[test]
+fn test_mul_prec() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_prec(y.clone(), prec);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_prec_val_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_val(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_assign(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_assign_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (1, 1),
+        "(8.0 -2.0)",
+        "(0x8.0#1 -0x2.0#1)",
+        (Less, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (10, 10),
+        "(11.000 -2.0000)",
+        "(0xb.00#10 -0x2.00#10)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (1, 1),
+        "(-8.0 -2.0)",
+        "(-0x8.0#1 -0x2.0#1)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 20),
+        "(-6.3281 -1.6666451)",
+        "(-0x6.54#10 -0x1.aaa94#20)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (100, 100),
+        "(-6.3270523235220863241909705533216 -1.6666449378555149104429680521924)",
+        "(-0x6.53b9b3799bb12dfa0929c7448#100 -0x1.aaa93e1e2225c69d87d1ebf2c#100)",
+        (Less, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 10),
+        "(-6.3270493 -1.6660)",
+        "(-0x6.53b98#20 -0x1.aa8#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 5),
+        "(-1327.0000 -512.0)",
+        "(-0x52f.000#20 -0x2.0E+2#5)",
+        (Less, Greater),
+    );
+}
+
+#[test]
+fn test_mul_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_round(y.clone(), rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_round_val_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_round_ref_val(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_round_assign(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_round_assign_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Floor),
+        "(-6.3270523235220866 -1.6666449378555150)",
+        "(-0x6.53b9b3799bb14#53 -0x1.aaa93e1e2225d#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Ceiling, Ceiling),
+        "(-6.3270523235220857 -1.6666449378555148)",
+        "(-0x6.53b9b3799bb10#53 -0x1.aaa93e1e2225c#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Down, Up),
+        "(-6.3270523235220857 -1.6666449378555150)",
+        "(-0x6.53b9b3799bb10#53 -0x1.aaa93e1e2225d#53)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Up, Down),
+        "(-6.3270523235220866 -1.6666449378555148)",
+        "(-0x6.53b9b3799bb14#53 -0x1.aaa93e1e2225c#53)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Nearest, Nearest),
+        "(-6.3270523235220866 -1.6666449378555148)",
+        "(-0x6.53b9b3799bb14#53 -0x1.aaa93e1e2225c#53)",
+        (Less, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Ceiling),
+        "(-6.3270523235220866 -1.6666449378555148)",
+        "(-0x6.53b9b3799bb14#53 -0x1.aaa93e1e2225c#53)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Floor, Ceiling),
+        "(-1327.0000000000000000000000000000 -521.00000000000000000000000000000)",
+        "(-0x52f.00000000000000000000000#100 -0x209.00000000000000000000000#100)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Ceiling, Floor),
+        "(-1326.9999999999999999999999999984 -521.00000000000000000000000000081)",
+        "(-0x52e.ffffffffffffffffffffff8#100 -0x209.00000000000000000000004#100)",
+        (Greater, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(2.0 0.0)",
+        "(0x2.0#1 0x0.0)",
+        (Exact, Exact),
+        "(6.0 8.0)",
+        "(0x6.0#3 0x8.0#3)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_mul_prec_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_prec_round(y.clone(), prec, rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_prec_round_val_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_round_assign(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_round_assign_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&product_alt),
+            ComparableComplexRef(&product)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(-6.3281 -1.6660)",
+        "(-0x6.54#10 -0x1.aa8#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(-6.3203 -1.6680)",
+        "(-0x6.52#10 -0x1.ab0#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Down, Down),
+        "(-6.3203 -1.6660)",
+        "(-0x6.52#10 -0x1.aa8#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Up, Up),
+        "(-6.3281 -1.6680)",
+        "(-0x6.54#10 -0x1.ab0#10)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(-6.3281 -1.6660)",
+        "(-0x6.54#10 -0x1.aa8#10)",
+        (Less, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 5),
+        (Up, Down),
+        "(-6.3270569 -1.62)",
+        "(-0x6.53ba0#20 -0x1.a#5)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        (Down, Up),
+        "(-1.28e3 -521.00098)",
+        "(-0x5.0E+2#5 -0x209.004#20)",
+        (Greater, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (10, 10),
+        (Exact, Exact),
+        "(11.000 -2.0000)",
+        "(0xb.00#10 -0x2.00#10)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn mul_prec_fail() {
+    assert_panic!(Complex::ONE.mul_prec(Complex::ONE, (0, 1)));
+    assert_panic!(Complex::ONE.mul_prec(Complex::ONE, (1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.mul_prec_assign(Complex::ONE, (0, 1))
+    });
+}
+
+#[test]
+fn mul_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    let y = parse_hex_string("(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)");
+    assert_panic!(x.clone().mul_round(y.clone(), (Exact, Floor)));
+    assert_panic!(x.clone().mul_round_val_ref(&y, (Exact, Floor)));
+    assert_panic!(x.mul_round_ref_val(y.clone(), (Exact, Floor)));
+    assert_panic!(x.mul_round_ref_ref(&y, (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.mul_round_assign(y.clone(), (Exact, Floor))
+    });
+    assert_panic!({
+        let mut x = x.clone();
+        x.mul_round_assign_ref(&y, (Exact, Floor))
+    });
+}
+
+#[test]
+fn mul_prec_round_fail() {
+    assert_panic!(Complex::ONE.mul_prec_round(Complex::ONE, (0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.mul_prec_round(Complex::ONE, (1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    let y = parse_hex_string("(0x1.0#1 -0x2.0#1)");
+    assert_panic!(x.clone().mul_prec_round(y.clone(), (1, 1), (Exact, Floor)));
+    assert_panic!(x.mul_prec_round_ref_ref(&y, (1, 1), (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.mul_prec_round_assign(y.clone(), (1, 1), (Exact, Floor))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_prec_round_properties_helper(
+    x: Complex,
+    y: Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) {
+    let (product, o) = x.clone().mul_prec_round(y.clone(), prec, rm);
+    assert!(product.is_valid());
+
+    let (product_alt, o_alt) = x.clone().mul_prec_round_val_ref(&y, prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut product_alt = x.clone();
+    let o_alt = product_alt.mul_prec_round_assign(y.clone(), prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut product_alt = x.clone();
+    let o_alt = product_alt.mul_prec_round_assign_ref(&y, prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    if let Some((product_alt, o_alt)) = mul_prec_round_naive(&x, &y, prec, rm) {
+        assert_eq!(product_alt, product);
+        assert_eq!(o_alt, o);
+    }
+
+    let (product_alt, o_alt) = y.mul_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    if product.real_ref().is_normal() {
+        assert_eq!(product.real_ref().get_prec(), Some(prec.0));
+    }
+    if product.imag_ref().is_normal() {
+        assert_eq!(product.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(product_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(product.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.mul_prec_round_ref_ref(&y, prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(product_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(product.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.mul_prec_round_ref_ref(&y, prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn mul_prec_round_properties() {
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+        .test_properties(|(x, y, p_r, p_i, rm_r, rm_i)| {
+            mul_prec_round_properties_helper(x, y, (p_r, p_i), (rm_r, rm_i));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_prec_properties_helper(x: Complex, y: Complex, prec: (u64, u64)) {
+    let (product, o) = x.clone().mul_prec(y.clone(), prec);
+    assert!(product.is_valid());
+
+    let (product_alt, o_alt) = x.clone().mul_prec_val_ref(&y, prec);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_prec_ref_val(y.clone(), prec);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_prec_ref_ref(&y, prec);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut product_alt = x.clone();
+    let o_alt = product_alt.mul_prec_assign(y.clone(), prec);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut product_alt = x.clone();
+    let o_alt = product_alt.mul_prec_assign_ref(&y, prec);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, (Nearest, Nearest));
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn mul_prec_properties() {
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1().test_properties(|(x, y, p_r, p_i)| {
+        mul_prec_properties_helper(x, y, (p_r, p_i));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_round_properties_helper(x: Complex, y: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (product, o) = x.clone().mul_round(y.clone(), rm);
+    assert!(product.is_valid());
+
+    let (product_alt, o_alt) = x.clone().mul_round_val_ref(&y, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_round_ref_val(y.clone(), rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut product_alt = x.clone();
+    let o_alt = product_alt.mul_round_assign(y.clone(), rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut product_alt = x.clone();
+    let o_alt = product_alt.mul_round_assign_ref(&y, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits(),
+        y.real_ref().significant_bits(),
+        y.imag_ref().significant_bits()
+    );
+    let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, (prec, prec), rm);
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn mul_round_properties() {
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, y, rm_r, rm_i)| {
+            mul_round_properties_helper(x, y, (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_properties_helper(x: Complex, y: Complex) {
+    let product = x.clone() * y.clone();
+    assert!(product.is_valid());
+
+    let product_alt = x.clone() * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+
+    let product_alt = &x * y.clone();
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+
+    let product_alt = &x * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+
+    let mut product_alt = x.clone();
+    product_alt *= y.clone();
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+
+    let mut product_alt = x.clone();
+    product_alt *= &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+
+    let (product_alt, _) = x.mul_round_ref_ref(&y, (Nearest, Nearest));
+    assert_eq!(
+        ComparableComplexRef(&product_alt),
+        ComparableComplexRef(&product)
+    );
+
+    assert_eq!(ComparableComplex(&y * &x), ComparableComplex(product));
+}
+
+#[test]
+fn mul_properties() {
+    complex_pair_gen().test_properties(|(x, y)| {
+        mul_properties_helper(x, y);
+    });
+
+    complex_gen().test_properties(|x| {
+        assert!((&x * Complex::NAN).is_nan());
+        assert!((Complex::NAN * &x).is_nan());
+    });
+
+    complex_gen_var_1().test_properties(|x| {
+        assert_eq!(&x * Complex::ONE, x);
+        let (real, imag) = (&x * Complex::I).into_real_imag();
+        assert_eq!(real, -x.imag_ref());
+        assert_eq!(&imag, x.real_ref());
+    });
+}
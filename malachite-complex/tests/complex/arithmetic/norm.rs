@@ -0,0 +1,509 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::Complex;
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::{
+    complex_gen, complex_rounding_mode_pair_gen_var_1, complex_unsigned_pair_gen_var_1,
+    complex_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::test_util::common::to_hex_string;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_norm() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let norm = x.clone().norm();
+        assert!(norm.is_valid());
+
+        assert_eq!(norm.to_string(), out);
+        assert_eq!(to_hex_string(&norm), out_hex);
+
+        let norm_alt = x.norm_ref();
+        assert!(norm_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+    };
+    test("(NaN NaN)", "(NaN NaN)", "NaN", "NaN");
+    test("(Infinity 0.0)", "(Infinity 0x0.0)", "Infinity", "Infinity");
+    test(
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "Infinity",
+        "Infinity",
+    );
+    test("(NaN Infinity)", "(NaN Infinity)", "Infinity", "Infinity");
+    test(
+        "(1.0 Infinity)",
+        "(0x1.0#1 Infinity)",
+        "Infinity",
+        "Infinity",
+    );
+    test("(0.0 0.0)", "(0x0.0 0x0.0)", "0.0", "0x0.0");
+    test("(-0.0 -0.0)", "(-0x0.0 -0x0.0)", "0.0", "0x0.0");
+    test("(-0.0 0.0)", "(-0x0.0 0x0.0)", "0.0", "0x0.0");
+    test("(1.0 0.0)", "(0x1.0#1 0x0.0)", "1.0", "0x1.0#1");
+    test("(-1.0 0.0)", "(-0x1.0#1 0x0.0)", "1.0", "0x1.0#1");
+    test("(-1.0 -0.0)", "(-0x1.0#1 -0x0.0)", "1.0", "0x1.0#1");
+    test("(0.0 1.0)", "(0x0.0 0x1.0#1)", "1.0", "0x1.0#1");
+    test("(1.0 1.0)", "(0x1.0#1 0x1.0#1)", "2.0", "0x2.0#1");
+    test("(3.0 4.0)", "(0x3.0#2 0x4.0#3)", "24.0", "0x18.0#3");
+    test("(1.0 -2.0)", "(0x1.0#1 -0x2.0#1)", "4.0", "0x4.0#1");
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "17.258660500020007",
+        "0x11.423793145a5a#53",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "2.4804530139182015",
+        "0x2.7afef7fe0b164#53",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "2.232e5",
+        "0x3.68E+4#9",
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "9.1111111111111111111111111111097",
+        "0x9.1c71c71c71c71c71c71c71c7#100",
+    );
+}
+
+#[test]
+fn test_norm_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (norm, o) = x.clone().norm_prec(prec);
+        assert!(norm.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(norm.to_string(), out);
+        assert_eq!(to_hex_string(&norm), out_hex);
+
+        let (norm_alt, o_alt) = x.norm_prec_ref(prec);
+        assert!(norm_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        1,
+        "32.0",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        10,
+        "25.000",
+        "0x19.00#10",
+        Equal,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        1,
+        "16.0",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        "17.250",
+        "0x11.40#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        100,
+        "17.258660500020007290661505740560",
+        "0x11.423793145a5a301e3623c7ee#100",
+        Greater,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        20,
+        "2.4804535",
+        "0x2.7aff0#20",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        5,
+        "2.21e5",
+        "0x3.6E+4#5",
+        Less,
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        20,
+        "9.1111145",
+        "0x9.1c72#20",
+        Greater,
+    );
+}
+
+#[test]
+fn test_norm_round() {
+    let test = |s, s_hex, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (norm, o) = x.clone().norm_round(rm);
+        assert!(norm.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(norm.to_string(), out);
+        assert_eq!(to_hex_string(&norm), out_hex);
+
+        let (norm_alt, o_alt) = x.norm_round_ref(rm);
+        assert!(norm_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Floor,
+        "17.258660500020007",
+        "0x11.423793145a5a#53",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Ceiling,
+        "17.258660500020010",
+        "0x11.423793145a5b#53",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Down,
+        "17.258660500020007",
+        "0x11.423793145a5a#53",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Up,
+        "17.258660500020010",
+        "0x11.423793145a5b#53",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        Nearest,
+        "17.258660500020007",
+        "0x11.423793145a5a#53",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        Floor,
+        "2.4804530139182015",
+        "0x2.7afef7fe0b164#53",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        Ceiling,
+        "2.4804530139182019",
+        "0x2.7afef7fe0b166#53",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        Floor,
+        "2.227e5",
+        "0x3.66E+4#9",
+        Less,
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        Exact,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+}
+
+#[test]
+fn test_norm_prec_round() {
+    let test =
+        |s, s_hex, prec: u64, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.to_string(), s);
+
+            let (norm, o) = x.clone().norm_prec_round(prec, rm);
+            assert!(norm.is_valid());
+            assert_eq!(o, o_out);
+
+            assert_eq!(norm.to_string(), out);
+            assert_eq!(to_hex_string(&norm), out_hex);
+
+            let (norm_alt, o_alt) = x.norm_prec_round_ref(prec, rm);
+            assert!(norm_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+            assert_eq!(o_alt, o_out);
+        };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Floor,
+        "17.250",
+        "0x11.40#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Ceiling,
+        "17.281",
+        "0x11.48#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Down,
+        "17.250",
+        "0x11.40#10",
+        Less,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Up,
+        "17.281",
+        "0x11.48#10",
+        Greater,
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        10,
+        Nearest,
+        "17.250",
+        "0x11.40#10",
+        Less,
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        20,
+        Up,
+        "2.4804535",
+        "0x2.7aff0#20",
+        Greater,
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        5,
+        Down,
+        "2.21e5",
+        "0x3.6E+4#5",
+        Less,
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        20,
+        Ceiling,
+        "9.1111145",
+        "0x9.1c72#20",
+        Greater,
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        10,
+        Exact,
+        "25.000",
+        "0x19.00#10",
+        Equal,
+    );
+}
+
+#[test]
+fn norm_prec_fail() {
+    assert_panic!(Complex::ONE.norm_prec(0));
+    assert_panic!(Complex::ONE.norm_prec_ref(0));
+}
+
+#[test]
+fn norm_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    assert_panic!(x.clone().norm_round(Exact));
+    assert_panic!(x.norm_round_ref(Exact));
+}
+
+#[test]
+fn norm_prec_round_fail() {
+    assert_panic!(Complex::ONE.norm_prec_round(0, Floor));
+    assert_panic!(Complex::ONE.norm_prec_round_ref(0, Floor));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    assert_panic!(x.clone().norm_prec_round(1, Exact));
+    assert_panic!(x.norm_prec_round_ref(1, Exact));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn norm_prec_round_properties_helper(x: Complex, prec: u64, rm: RoundingMode) {
+    let (norm, o) = x.clone().norm_prec_round(prec, rm);
+    assert!(norm.is_valid());
+
+    let (norm_alt, o_alt) = x.norm_prec_round_ref(prec, rm);
+    assert!(norm_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() {
+        let re = Rational::exact_from(x.real_ref());
+        let im = Rational::exact_from(x.imag_ref());
+        let (norm_alt, o_alt) = Float::from_rational_prec_round(&re * &re + &im * &im, prec, rm);
+        assert_eq!(
+            ComparableFloat(norm_alt.abs_negative_zero()),
+            ComparableFloat(norm.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, o);
+    }
+
+    if norm.is_normal() {
+        assert_eq!(norm.get_prec(), Some(prec));
+    }
+
+    if o == Equal {
+        let (norm_alt, o_alt) = x.norm_prec_round_ref(prec, Exact);
+        assert_eq!(
+            ComparableFloat(norm_alt.abs_negative_zero_ref()),
+            ComparableFloat(norm.abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt, Equal);
+    } else {
+        assert_panic!(x.norm_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn norm_prec_round_properties() {
+    complex_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        norm_prec_round_properties_helper(x, prec, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn norm_prec_properties_helper(x: Complex, prec: u64) {
+    let (norm, o) = x.clone().norm_prec(prec);
+    assert!(norm.is_valid());
+
+    let (norm_alt, o_alt) = x.norm_prec_ref(prec);
+    assert!(norm_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+    assert_eq!(o_alt, o);
+
+    let (norm_alt, o_alt) = x.norm_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn norm_prec_properties() {
+    complex_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        norm_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn norm_round_properties_helper(x: Complex, rm: RoundingMode) {
+    let (norm, o) = x.clone().norm_round(rm);
+    assert!(norm.is_valid());
+
+    let (norm_alt, o_alt) = x.norm_round_ref(rm);
+    assert!(norm_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits()
+    );
+    let (norm_alt, o_alt) = x.norm_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn norm_round_properties() {
+    complex_rounding_mode_pair_gen_var_1().test_properties(|(x, rm)| {
+        norm_round_properties_helper(x, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn norm_properties_helper(x: Complex) {
+    let norm = x.clone().norm();
+    assert!(norm.is_valid());
+
+    let norm_alt = x.norm_ref();
+    assert!(norm_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+
+    let (norm_alt, _) = x.norm_round_ref(Nearest);
+    assert_eq!(ComparableFloatRef(&norm_alt), ComparableFloatRef(&norm));
+
+    assert_eq!(
+        ComparableFloat(x.conj_ref().norm()),
+        ComparableFloat(norm.clone())
+    );
+    assert_eq!(ComparableFloat((-&x).norm()), ComparableFloat(norm.clone()));
+    assert!(!norm.is_sign_negative());
+}
+
+#[test]
+fn norm_properties() {
+    complex_gen().test_properties(|x| {
+        norm_properties_helper(x);
+    });
+}
@@ -0,0 +1,1072 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Abs, Pow, PowAssign};
+use malachite_base::num::basic::traits::{NaN, One, Two, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::{
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1, complex_gen,
+    complex_gen_var_1, complex_pair_gen,
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+};
+use malachite_complex::{ComparableComplexRef, Complex};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::panic::catch_unwind;
+
+const LIMIT: usize = 1000;
+
+#[test]
+fn test_pow() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let power = x.clone().pow(y.clone());
+        assert!(power.is_valid());
+
+        assert_eq!(power.to_string(), out);
+        assert_eq!(to_hex_string(&power), out_hex);
+
+        let power_alt = x.clone().pow(&y);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+
+        let power_alt = (&x).pow(y.clone());
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+
+        let power_alt = (&x).pow(&y);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+
+        let mut power_alt = x.clone();
+        power_alt.pow_assign(y.clone());
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+
+        let mut power_alt = x.clone();
+        power_alt.pow_assign(&y);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+    };
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(0.50 0.0)",
+        "(0x0.8#1 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(1.0000000000000000 0.0)",
+        "(0x1.0000000000000#53 0x0.0)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.25 0.0)",
+        "(0x0.4#1 0x0.0)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(2.0 0.0)",
+        "(0x2.0#1 0x0.0)",
+        "(-1.0 0.0)",
+        "(-0x1.0#1 0x0.0)",
+    );
+    test(
+        "(-1.0 0.0)",
+        "(-0x1.0#1 0x0.0)",
+        "(0.50 0.0)",
+        "(0x0.8#1 0x0.0)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+    );
+    test(
+        "(-1.0 -0.0)",
+        "(-0x1.0#1 -0x0.0)",
+        "(0.50 0.0)",
+        "(0x0.8#1 0x0.0)",
+        "(0.0 -1.0)",
+        "(0x0.0 -0x1.0#1)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(2.0 0.0)",
+        "(0x2.0#1 0x0.0)",
+        "(-7.0 24.0)",
+        "(-0x7.0#3 0x18.0#3)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(0.50 0.0)",
+        "(0x0.8#1 0x0.0)",
+        "(2.0 1.0)",
+        "(0x2.0#3 0x1.0#3)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        "(-20.0 -24.0)",
+        "(-0x14.0#3 -0x18.0#3)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(0.081373438569137896 -0.0017568190927421778)",
+        "(0x0.14d4e3c16ae216#53 -0x0.007322888c5e70b8#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-0.0027257394777267475 -0.00068972841508346404)",
+        "(-0x0.00b2a251ea0c73b0#53 -0x0.002d33b8fc644bb4#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(0.15393923785983271939747122125872 -0.0091004458681945431659584399598486)",
+        "(0x0.27688fd82ddc8c904e57ca3f1c#100 -0x0.025468256206a5086dffcd2517c#100)",
+    );
+}
+
+#[test]
+fn test_pow_prec() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (power, o) = x.clone().pow_prec(y.clone(), prec);
+        assert!(power.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(power.to_string(), out);
+        assert_eq!(to_hex_string(&power), out_hex);
+
+        let (power_alt, o_alt) = x.clone().pow_prec_val_ref(&y, prec);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (power_alt, o_alt) = x.pow_prec_ref_val(y.clone(), prec);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (power_alt, o_alt) = x.pow_prec_ref_ref(&y, prec);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut power_alt = x.clone();
+        let o_alt = power_alt.pow_prec_assign(y.clone(), prec);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut power_alt = x.clone();
+        let o_alt = power_alt.pow_prec_assign_ref(&y, prec);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (1, 1),
+        "(-16.0 -32.0)",
+        "(-0x1.0E+1#1 -0x2.0E+1#1)",
+        (Greater, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (10, 10),
+        "(-21.094 -24.000)",
+        "(-0x15.18#10 -0x18.00#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (1, 1),
+        "(0.062 -0.0020)",
+        "(0x0.1#1 -0x0.008#1)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 20),
+        "(0.081421 -0.0017568190)",
+        "(0x0.14d8#10 -0x0.00732288#20)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (100, 100),
+        "(0.081373438569137900177958612605164 -0.0017568190927421779143801294465969)",
+        "(0x0.14d4e3c16ae2164fa9e3cce21e#100 -0x0.007322888c5e70b995e1e336c858#100)",
+        (Greater, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 10),
+        "(-0.0027257390 -0.00068951)",
+        "(-0x0.00b2a25#20 -0x0.002d3#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 5),
+        "(0.15393925 -0.00928)",
+        "(0x0.276890#20 -0x0.026#5)",
+        (Greater, Less),
+    );
+}
+
+#[test]
+fn test_pow_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (power, o) = x.clone().pow_round(y.clone(), rm);
+        assert!(power.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(power.to_string(), out);
+        assert_eq!(to_hex_string(&power), out_hex);
+
+        let (power_alt, o_alt) = x.clone().pow_round_val_ref(&y, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (power_alt, o_alt) = x.pow_round_ref_val(y.clone(), rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (power_alt, o_alt) = x.pow_round_ref_ref(&y, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut power_alt = x.clone();
+        let o_alt = power_alt.pow_round_assign(y.clone(), rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut power_alt = x.clone();
+        let o_alt = power_alt.pow_round_assign_ref(&y, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Floor),
+        "(0.081373438569137896 -0.0017568190927421780)",
+        "(0x0.14d4e3c16ae216#53 -0x0.007322888c5e70bc#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Ceiling, Ceiling),
+        "(0.081373438569137910 -0.0017568190927421778)",
+        "(0x0.14d4e3c16ae217#53 -0x0.007322888c5e70b8#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Down, Up),
+        "(0.081373438569137896 -0.0017568190927421780)",
+        "(0x0.14d4e3c16ae216#53 -0x0.007322888c5e70bc#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Up, Down),
+        "(0.081373438569137910 -0.0017568190927421778)",
+        "(0x0.14d4e3c16ae217#53 -0x0.007322888c5e70b8#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Nearest, Nearest),
+        "(0.081373438569137896 -0.0017568190927421778)",
+        "(0x0.14d4e3c16ae216#53 -0x0.007322888c5e70b8#53)",
+        (Less, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Ceiling),
+        "(-0.0027257394777267475 -0.00068972841508346393)",
+        "(-0x0.00b2a251ea0c73b0#53 -0x0.002d33b8fc644bb2#53)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Floor, Ceiling),
+        "(0.15393923785983271939747122125872 -0.0091004458681945431659584399598363)",
+        "(0x0.27688fd82ddc8c904e57ca3f1c#100 -0x0.025468256206a5086dffcd25178#100)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Ceiling, Floor),
+        "(0.15393923785983271939747122125892 -0.0091004458681945431659584399598486)",
+        "(0x0.27688fd82ddc8c904e57ca3f20#100 -0x0.025468256206a5086dffcd2517c#100)",
+        (Greater, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(2.0 0.0)",
+        "(0x2.0#1 0x0.0)",
+        (Exact, Exact),
+        "(-7.0 24.0)",
+        "(-0x7.0#3 0x18.0#3)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_pow_prec_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (power, o) = x.clone().pow_prec_round(y.clone(), prec, rm);
+        assert!(power.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(power.to_string(), out);
+        assert_eq!(to_hex_string(&power), out_hex);
+
+        let (power_alt, o_alt) = x.clone().pow_prec_round_val_ref(&y, prec, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (power_alt, o_alt) = x.pow_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (power_alt, o_alt) = x.pow_prec_round_ref_ref(&y, prec, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut power_alt = x.clone();
+        let o_alt = power_alt.pow_prec_round_assign(y.clone(), prec, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut power_alt = x.clone();
+        let o_alt = power_alt.pow_prec_round_assign_ref(&y, prec, rm);
+        assert!(power_alt.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&power_alt),
+            ComparableComplexRef(&power)
+        );
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(0.081299 -0.0017567)",
+        "(0x0.14d0#10 -0x0.00732#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(0.081421 -0.0017586)",
+        "(0x0.14d8#10 -0x0.00734#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Down, Down),
+        "(0.081299 -0.0017567)",
+        "(0x0.14d0#10 -0x0.00732#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Up, Up),
+        "(0.081421 -0.0017586)",
+        "(0x0.14d8#10 -0x0.00734#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(0.081421 -0.0017567)",
+        "(0x0.14d8#10 -0x0.00732#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 5),
+        (Up, Down),
+        "(-0.0027257428 -0.000671)",
+        "(-0x0.00b2a26#20 -0x0.002c#5)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        (Down, Up),
+        "(0.148 -0.0091004521)",
+        "(0x0.26#5 -0x0.0254684#20)",
+        (Less, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(2.0 0.0)",
+        "(0x2.0#1 0x0.0)",
+        (10, 10),
+        (Exact, Exact),
+        "(-7.0000 24.000)",
+        "(-0x7.00#10 0x18.00#10)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(0.50 0.0)",
+        "(0x0.8#1 0x0.0)",
+        (1, 2),
+        (Exact, Exact),
+        "(2.0 1.0)",
+        "(0x2.0#1 0x1.0#2)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn pow_prec_fail() {
+    assert_panic!(Complex::ONE.pow_prec(Complex::ONE, (0, 1)));
+    assert_panic!(Complex::ONE.pow_prec(Complex::ONE, (1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.pow_prec_assign(Complex::ONE, (0, 1))
+    });
+}
+
+#[test]
+fn pow_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    let y = parse_hex_string("(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)");
+    assert_panic!(x.clone().pow_round(y.clone(), (Exact, Floor)));
+    assert_panic!(x.clone().pow_round_val_ref(&y, (Exact, Floor)));
+    assert_panic!(x.pow_round_ref_val(y.clone(), (Exact, Floor)));
+    assert_panic!(x.pow_round_ref_ref(&y, (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.pow_round_assign(y.clone(), (Exact, Floor))
+    });
+    assert_panic!({
+        let mut x = x.clone();
+        x.pow_round_assign_ref(&y, (Exact, Floor))
+    });
+}
+
+#[test]
+fn pow_prec_round_fail() {
+    assert_panic!(Complex::ONE.pow_prec_round(Complex::ONE, (0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.pow_prec_round(Complex::ONE, (1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    let y = parse_hex_string("(0x1.0#1 -0x2.0#1)");
+    assert_panic!(x.clone().pow_prec_round(y.clone(), (1, 1), (Exact, Floor)));
+    assert_panic!(x.pow_prec_round_ref_ref(&y, (1, 1), (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.pow_prec_round_assign(y.clone(), (1, 1), (Exact, Floor))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn pow_prec_round_properties_helper(
+    x: Complex,
+    y: Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) {
+    let (power, o) = x.clone().pow_prec_round(y.clone(), prec, rm);
+    assert!(power.is_valid());
+
+    let (power_alt, o_alt) = x.clone().pow_prec_round_val_ref(&y, prec, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let (power_alt, o_alt) = x.pow_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let (power_alt, o_alt) = x.pow_prec_round_ref_ref(&y, prec, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut power_alt = x.clone();
+    let o_alt = power_alt.pow_prec_round_assign(y.clone(), prec, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut power_alt = x.clone();
+    let o_alt = power_alt.pow_prec_round_assign_ref(&y, prec, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() && y == Complex::TWO {
+        let (power_alt, o_alt) = x.mul_prec_round_ref_ref(&x, prec, rm);
+        assert_eq!(power_alt, power);
+        assert_eq!(o_alt, o);
+    }
+
+    if power.real_ref().is_normal() {
+        assert_eq!(power.real_ref().get_prec(), Some(prec.0));
+    }
+    if power.imag_ref().is_normal() {
+        assert_eq!(power.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (power_alt, o_alt) = x.pow_prec_round_ref_ref(&y, prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(power_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(power.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.pow_prec_round_ref_ref(&y, prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (power_alt, o_alt) = x.pow_prec_round_ref_ref(&y, prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(power_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(power.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.pow_prec_round_ref_ref(&y, prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn pow_prec_round_properties() {
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+        .test_properties_with_limit(LIMIT, |(x, y, p_r, p_i, rm_r, rm_i)| {
+            pow_prec_round_properties_helper(x, y, (p_r, p_i), (rm_r, rm_i));
+        });
+}
+
+#[test]
+fn pow_prec_round_properties_2() {
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1()
+        .test_properties_with_limit(LIMIT, |(x, p_r, p_i, rm_r, rm_i)| {
+            let prec = (p_r, p_i);
+            let rm = (rm_r, rm_i);
+            if x.is_finite() {
+                let (square, o) = x.pow_prec_round_ref_ref(&Complex::TWO, prec, rm);
+                let (square_alt, o_alt) = x.mul_prec_round_ref_ref(&x, prec, rm);
+                assert_eq!(square_alt, square);
+                assert_eq!(o_alt, o);
+
+                let (power, o) = x.pow_prec_round_ref_ref(&Complex::ONE, prec, rm);
+                let mut x_alt = x.clone();
+                let o_alt = x_alt.set_prec_round(prec, rm);
+                assert_eq!(x_alt, power);
+                assert_eq!(o_alt, o);
+            }
+        });
+
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+        .test_properties_with_limit(LIMIT, |(x, y, p_r, p_i, rm_r, rm_i)| {
+            // A positive real number raised to a real power is real, and agrees with the `Float`
+            // power. The imaginary part is +0 for positive exponents and a base other than 1;
+            // otherwise, its sign follows MPC's rules.
+            let a = x.into_real().abs();
+            let b = y.into_real();
+            let b_positive = b > 0u32;
+            let a_ne_1 = a != 1u32;
+            if a.is_finite() && a > 0u32 && b.is_finite() {
+                let (power, o) = Complex::from(a.clone()).pow_prec_round(
+                    Complex::from(b.clone()),
+                    (p_r, p_i),
+                    (rm_r, rm_i),
+                );
+                let (real, o_r) = a.pow_prec_round(b, p_r, rm_r);
+                assert_eq!(
+                    ComparableFloatRef(power.real_ref()),
+                    ComparableFloatRef(&real)
+                );
+                assert_eq!(o.0, o_r);
+                assert!(power.imag_ref().is_zero());
+                assert_eq!(o.1, Equal);
+                if b_positive && a_ne_1 {
+                    assert!(power.imag_ref().is_sign_positive());
+                }
+            }
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn pow_prec_properties_helper(x: Complex, y: Complex, prec: (u64, u64)) {
+    let (power, o) = x.clone().pow_prec(y.clone(), prec);
+    assert!(power.is_valid());
+
+    let (power_alt, o_alt) = x.clone().pow_prec_val_ref(&y, prec);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let (power_alt, o_alt) = x.pow_prec_ref_val(y.clone(), prec);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let (power_alt, o_alt) = x.pow_prec_ref_ref(&y, prec);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut power_alt = x.clone();
+    let o_alt = power_alt.pow_prec_assign(y.clone(), prec);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut power_alt = x.clone();
+    let o_alt = power_alt.pow_prec_assign_ref(&y, prec);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let (power_alt, o_alt) = x.pow_prec_round_ref_ref(&y, prec, (Nearest, Nearest));
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn pow_prec_properties() {
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1().test_properties_with_limit(
+        LIMIT,
+        |(x, y, p_r, p_i)| {
+            pow_prec_properties_helper(x, y, (p_r, p_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn pow_round_properties_helper(x: Complex, y: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (power, o) = x.clone().pow_round(y.clone(), rm);
+    assert!(power.is_valid());
+
+    let (power_alt, o_alt) = x.clone().pow_round_val_ref(&y, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let (power_alt, o_alt) = x.pow_round_ref_val(y.clone(), rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let (power_alt, o_alt) = x.pow_round_ref_ref(&y, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut power_alt = x.clone();
+    let o_alt = power_alt.pow_round_assign(y.clone(), rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut power_alt = x.clone();
+    let o_alt = power_alt.pow_round_assign_ref(&y, rm);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits(),
+        y.real_ref().significant_bits(),
+        y.imag_ref().significant_bits()
+    );
+    let (power_alt, o_alt) = x.pow_prec_round_ref_ref(&y, (prec, prec), rm);
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn pow_round_properties() {
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1().test_properties_with_limit(
+        LIMIT,
+        |(x, y, rm_r, rm_i)| {
+            pow_round_properties_helper(x, y, (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn pow_properties_helper(x: Complex, y: Complex) {
+    let power = x.clone().pow(y.clone());
+    assert!(power.is_valid());
+
+    let power_alt = x.clone().pow(&y);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+
+    let power_alt = (&x).pow(y.clone());
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+
+    let power_alt = (&x).pow(&y);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+
+    let mut power_alt = x.clone();
+    power_alt.pow_assign(y.clone());
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+
+    let mut power_alt = x.clone();
+    power_alt.pow_assign(&y);
+    assert!(power_alt.is_valid());
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+
+    let (power_alt, _) = x.pow_round_ref_ref(&y, (Nearest, Nearest));
+    assert_eq!(
+        ComparableComplexRef(&power_alt),
+        ComparableComplexRef(&power)
+    );
+}
+
+#[test]
+fn pow_properties() {
+    complex_pair_gen().test_properties_with_limit(LIMIT, |(x, y)| {
+        pow_properties_helper(x, y);
+    });
+
+    complex_gen().test_properties(|x| {
+        assert_eq!((&x).pow(Complex::ZERO), Complex::ONE);
+        assert!((&x).pow(Complex::NAN).is_nan());
+    });
+
+    complex_gen_var_1().test_properties(|x| {
+        assert_eq!((&x).pow(Complex::ONE), x);
+        if !x.is_zero() {
+            assert_eq!(Complex::ONE.pow(&x), Complex::ONE);
+        }
+    });
+}
@@ -0,0 +1,667 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::{
+    complex_gen, complex_rounding_mode_rounding_mode_triple_gen_var_1,
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+    complex_unsigned_unsigned_triple_gen_var_1,
+};
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sqrt() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sqrt = x.clone().sqrt();
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let sqrt_alt = (&x).sqrt();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+
+        let mut sqrt_alt = x.clone();
+        sqrt_alt.sqrt_assign();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    };
+    test("(NaN NaN)", "(NaN NaN)", "(NaN NaN)", "(NaN NaN)");
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+    );
+    test(
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(0.0 Infinity)",
+        "(0x0.0 Infinity)",
+    );
+    test(
+        "(-Infinity -0.0)",
+        "(-Infinity -0x0.0)",
+        "(0.0 -Infinity)",
+        "(0x0.0 -Infinity)",
+    );
+    test(
+        "(1.0 Infinity)",
+        "(0x1.0#1 Infinity)",
+        "(Infinity Infinity)",
+        "(Infinity Infinity)",
+    );
+    test("(NaN 1.0)", "(NaN 0x1.0#1)", "(NaN NaN)", "(NaN NaN)");
+    test("(0.0 0.0)", "(0x0.0 0x0.0)", "(0.0 0.0)", "(0x0.0 0x0.0)");
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(0.0 -0.0)",
+        "(0x0.0 -0x0.0)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+    );
+    test(
+        "(-1.0 0.0)",
+        "(-0x1.0#1 0x0.0)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+    );
+    test(
+        "(-1.0 -0.0)",
+        "(-0x1.0#1 -0x0.0)",
+        "(0.0 -1.0)",
+        "(0x0.0 -0x1.0#1)",
+    );
+    test(
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(0.50 0.50)",
+        "(0x0.8#1 0x0.8#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 0.50)",
+        "(0x1.0#1 0x0.8#1)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(2.0 1.0)",
+        "(0x2.0#3 0x1.0#3)",
+    );
+    test(
+        "(-5.0 12.0)",
+        "(-0x5.0#3 0xc.0#4)",
+        "(2.00 3.00)",
+        "(0x2.0#4 0x3.0#4)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(1.9099668918469643 0.71160443672152585)",
+        "(0x1.e8f39718ecec5#53 0x0.b62bb557684ef8#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(0.28348883392859470 1.2225299510994769)",
+        "(0x0.4892b966812638#53 0x1.38f7b90e5a4cb#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(17.25 -13.22)",
+        "(0x11.4#9 -0xd.38#9)",
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(1.2945646075120788544134089482945 -1.1586907221901664289524440027675)",
+        "(0x1.4b68960bd2cdebc4d5f5dae04#100 -0x1.289ff485fc4246e2894bb87e8#100)",
+    );
+}
+
+#[test]
+fn test_sqrt_prec() {
+    let test =
+        |s, s_hex, prec: (u64, u64), out: &str, out_hex: &str, o_out: (Ordering, Ordering)| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.to_string(), s);
+
+            let (sqrt, o) = x.clone().sqrt_prec(prec);
+            assert!(sqrt.is_valid());
+            assert_eq!(o, o_out);
+
+            assert_eq!(sqrt.to_string(), out);
+            assert_eq!(to_hex_string(&sqrt), out_hex);
+
+            let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+            assert!(sqrt_alt.is_valid());
+            assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+            assert_eq!(o_alt, o_out);
+
+            let mut sqrt_alt = x.clone();
+            let o_alt = sqrt_alt.sqrt_prec_assign(prec);
+            assert!(sqrt_alt.is_valid());
+            assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+            assert_eq!(o_alt, o_out);
+        };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (1, 1),
+        "(2.0 1.0)",
+        "(0x2.0#1 0x1.0#1)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (10, 10),
+        "(2.0000 1.0000)",
+        "(0x2.00#10 0x1.000#10)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (1, 1),
+        "(2.0 0.50)",
+        "(0x2.0#1 0x0.8#1)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 20),
+        "(1.9102 0.71160412)",
+        "(0x1.e90#10 0x0.b62bb#20)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (100, 100),
+        "(1.9099668918469642548256361935034 0.71160443672152588320408502588487)",
+        "(0x1.e8f39718ecec4a715d3ab10c6#100 0x0.b62bb557684efaaf8a2187c73#100)",
+        (Less, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (20, 10),
+        "(0.28348875 1.2227)",
+        "(0x0.4892b8#20 0x1.390#10)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (20, 5),
+        "(17.252502 -13.0)",
+        "(0x11.40a4#20 -0xd.0#5)",
+        (Greater, Greater),
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        "(1.31 -1.1586914)",
+        "(0x1.5#5 -0x1.28a00#20)",
+        (Greater, Less),
+    );
+}
+
+#[test]
+fn test_sqrt_round() {
+    let test = |s,
+                s_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_round(rm);
+        assert!(sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_round_assign(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Floor),
+        "(1.9099668918469641 0.71160443672152585)",
+        "(0x1.e8f39718ecec4#53 0x0.b62bb557684ef8#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Ceiling, Ceiling),
+        "(1.9099668918469643 0.71160443672152596)",
+        "(0x1.e8f39718ecec5#53 0x0.b62bb557684f00#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Down, Up),
+        "(1.9099668918469641 0.71160443672152596)",
+        "(0x1.e8f39718ecec4#53 0x0.b62bb557684f00#53)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Up, Down),
+        "(1.9099668918469643 0.71160443672152585)",
+        "(0x1.e8f39718ecec5#53 0x0.b62bb557684ef8#53)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Nearest, Nearest),
+        "(1.9099668918469643 0.71160443672152585)",
+        "(0x1.e8f39718ecec5#53 0x0.b62bb557684ef8#53)",
+        (Greater, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Ceiling),
+        "(0.28348883392859470 1.2225299510994772)",
+        "(0x0.4892b966812638#53 0x1.38f7b90e5a4cc#53)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (Ceiling, Floor),
+        "(17.31 -13.22)",
+        "(0x11.5#9 -0xd.38#9)",
+        (Greater, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (Exact, Exact),
+        "(2.0 1.0)",
+        "(0x2.0#3 0x1.0#3)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_sqrt_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_prec_round_assign(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(1.9082 0.71191)",
+        "(0x1.e88#10 0x0.b64#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(1.9102 0.71094)",
+        "(0x1.e90#10 0x0.b60#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Down, Down),
+        "(1.9082 0.71094)",
+        "(0x1.e88#10 0x0.b60#10)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Up, Up),
+        "(1.9102 0.71191)",
+        "(0x1.e90#10 0x0.b64#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(1.9102 0.71191)",
+        "(0x1.e90#10 0x0.b64#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (20, 5),
+        (Up, Down),
+        "(0.28348923 1.19)",
+        "(0x0.4892c0#20 0x1.3#5)",
+        (Greater, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (5, 20),
+        (Down, Up),
+        "(17.0 -13.215485)",
+        "(0x11.0#5 -0xd.372a#20)",
+        (Less, Less),
+    );
+    test(
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 20),
+        (Nearest, Floor),
+        "(1.2945652 -1.1586914)",
+        "(0x1.4b68a#20 -0x1.28a00#20)",
+        (Greater, Less),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (10, 10),
+        (Exact, Exact),
+        "(2.0000 1.0000)",
+        "(0x2.00#10 0x1.000#10)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn sqrt_prec_fail() {
+    assert_panic!(Complex::ONE.sqrt_prec((0, 1)));
+    assert_panic!(Complex::ONE.sqrt_prec_ref((1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.sqrt_prec_assign((0, 1))
+    });
+}
+
+#[test]
+fn sqrt_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    assert_panic!(x.clone().sqrt_round((Exact, Floor)));
+    assert_panic!(x.sqrt_round_ref((Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.sqrt_round_assign((Exact, Floor))
+    });
+}
+
+#[test]
+fn sqrt_prec_round_fail() {
+    assert_panic!(Complex::ONE.sqrt_prec_round((0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.sqrt_prec_round_ref((1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    assert_panic!(x.clone().sqrt_prec_round((1, 1), (Exact, Floor)));
+    assert_panic!(x.sqrt_prec_round_ref((1, 1), (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.sqrt_prec_round_assign((1, 1), (Exact, Floor))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_prec_round_properties_helper(
+    x: Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) {
+    let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+    assert!(sqrt.is_valid());
+
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let mut sqrt_alt = x.clone();
+    let o_alt = sqrt_alt.sqrt_prec_round_assign(prec, rm);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    // Annex G of the ISO C99 standard leaves the sign of the imaginary part of sqrt(x + NaN * i)
+    // unspecified when x is -Infinity
+    if !x.imag_ref().is_nan() {
+        let (conj_sqrt, o_alt) = x.conj_ref().sqrt_prec_round(prec, (rm.0, -rm.1));
+        assert_eq!(
+            ComparableComplex(conj_sqrt),
+            ComparableComplex(sqrt.conj_ref())
+        );
+        assert_eq!(o_alt, (o.0, o.1.reverse()));
+    }
+
+    if x.imag_ref().is_zero() && x.imag_ref().is_sign_positive() && *x.real_ref() >= 0u32 {
+        let (real, o_r) = x.real_ref().sqrt_prec_round_ref(prec.0, rm.0);
+        assert_eq!(
+            ComparableFloatRef(sqrt.real_ref()),
+            ComparableFloatRef(&real)
+        );
+        assert_eq!(o.0, o_r);
+        assert_eq!(
+            ComparableFloatRef(sqrt.imag_ref()),
+            ComparableFloatRef(&Float::ZERO)
+        );
+        assert_eq!(o.1, Equal);
+    }
+
+    if sqrt.real_ref().is_normal() {
+        assert_eq!(sqrt.real_ref().get_prec(), Some(prec.0));
+    }
+    if sqrt.imag_ref().is_normal() {
+        assert_eq!(sqrt.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(sqrt_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(sqrt.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.sqrt_prec_round_ref(prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(sqrt_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(sqrt.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.sqrt_prec_round_ref(prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn sqrt_prec_round_properties() {
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1().test_properties(
+        |(x, p_r, p_i, rm_r, rm_i)| {
+            sqrt_prec_round_properties_helper(x, (p_r, p_i), (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_prec_properties_helper(x: Complex, prec: (u64, u64)) {
+    let (sqrt, o) = x.clone().sqrt_prec(prec);
+    assert!(sqrt.is_valid());
+
+    let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let mut sqrt_alt = x.clone();
+    let o_alt = sqrt_alt.sqrt_prec_assign(prec);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, (Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn sqrt_prec_properties() {
+    complex_unsigned_unsigned_triple_gen_var_1().test_properties(|(x, p_r, p_i)| {
+        sqrt_prec_properties_helper(x, (p_r, p_i));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_round_properties_helper(x: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (sqrt, o) = x.clone().sqrt_round(rm);
+    assert!(sqrt.is_valid());
+
+    let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let mut sqrt_alt = x.clone();
+    let o_alt = sqrt_alt.sqrt_round_assign(rm);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits()
+    );
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref((prec, prec), rm);
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn sqrt_round_properties() {
+    complex_rounding_mode_rounding_mode_triple_gen_var_1().test_properties(|(x, rm_r, rm_i)| {
+        sqrt_round_properties_helper(x, (rm_r, rm_i));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_properties_helper(x: Complex) {
+    let sqrt = x.clone().sqrt();
+    assert!(sqrt.is_valid());
+
+    let sqrt_alt = (&x).sqrt();
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+
+    let mut sqrt_alt = x.clone();
+    sqrt_alt.sqrt_assign();
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+
+    let (sqrt_alt, _) = x.sqrt_round_ref((Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&sqrt_alt), ComparableComplexRef(&sqrt));
+
+    // Annex G of the ISO C99 standard leaves the sign of the imaginary part of sqrt(x + NaN * i)
+    // unspecified when x is -Infinity
+    if !x.imag_ref().is_nan() {
+        assert_eq!(
+            ComparableComplex(x.conj_ref().sqrt()),
+            ComparableComplex(sqrt.conj_ref())
+        );
+    }
+
+    if x.is_finite() {
+        assert!(sqrt.real_ref().is_sign_positive());
+    }
+}
+
+#[test]
+fn sqrt_properties() {
+    complex_gen().test_properties(|x| {
+        sqrt_properties_helper(x);
+    });
+
+    float_gen().test_properties(|x| {
+        if x < 0u32 {
+            let (real, imag) = Complex::from(&x).sqrt().into_real_imag();
+            assert!(real.is_zero());
+            assert_eq!(ComparableFloat(imag), ComparableFloat((-x).sqrt()));
+        }
+    });
+}
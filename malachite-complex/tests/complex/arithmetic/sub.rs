@@ -0,0 +1,868 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{NaN, One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::{
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1,
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1, complex_gen,
+    complex_gen_var_1, complex_pair_gen,
+};
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sub() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let diff = x.clone() - y.clone();
+        assert!(diff.is_valid());
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let diff_alt = x.clone() - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+        let diff_alt = &x - y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+        let diff_alt = &x - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+        let mut diff_alt = x.clone();
+        diff_alt -= y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+        let mut diff_alt = x.clone();
+        diff_alt -= &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    };
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        "(NaN 0.0)",
+        "(NaN 0x0.0)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(1.0 0.0)",
+        "(0x1.0#1 0x0.0)",
+        "(0.0 1.0)",
+        "(0x0.0 0x1.0#1)",
+        "(1.0 -1.0)",
+        "(0x1.0#1 -0x1.0#1)",
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        "(2.0 6.0)",
+        "(0x2.0#3 0x6.0#3)",
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(4.5558062159628880 2.0251346478990997)",
+        "(0x4.8e4950f0795fc#53 0x2.066f396ab91da#53)",
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-4.5558062159628880 -2.0251346478990997)",
+        "(-0x4.8e4950f0795fc#53 -0x2.066f396ab91da#53)",
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        "(122.66666666666666666666666666663 -453.00000000000000000000000000000)",
+        "(0x7a.aaaaaaaaaaaaaaaaaaaaaaa8#100 -0x1c5.00000000000000000000000#100)",
+    );
+}
+
+#[test]
+fn test_sub_prec() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (diff, o) = x.clone().sub_prec(y.clone(), prec);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_prec_val_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let (diff_alt, o_alt) = x.sub_prec_ref_val(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let (diff_alt, o_alt) = x.sub_prec_ref_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_assign(y.clone(), prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_assign_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (1, 1),
+        "(2.0 8.0)",
+        "(0x2.0#1 0x8.0#1)",
+        (Equal, Greater),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (10, 10),
+        "(2.0000 6.0000)",
+        "(0x2.00#10 0x6.00#10)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (1, 1),
+        "(4.0 2.0)",
+        "(0x4.0#1 0x2.0#1)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 20),
+        "(4.5547 2.0251350)",
+        "(0x4.8e#10 0x2.066f4#20)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (100, 100),
+        "(4.5558062159628882614725853272830 2.0251346478990998045688343154325)",
+        "(0x4.8e4950f0795fd000000000000#100 0x2.066f396ab91da800000000000#100)",
+        (Equal, Equal),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 10),
+        "(-4.5558090 -2.0234)",
+        "(-0x4.8e498#20 -0x2.06#10)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (20, 5),
+        "(122.66663 -448.0)",
+        "(0x7a.aaa8#20 -0x1.cE+2#5)",
+        (Less, Greater),
+    );
+}
+
+#[test]
+fn test_sub_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (diff, o) = x.clone().sub_round(y.clone(), rm);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_round_val_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let (diff_alt, o_alt) = x.sub_round_ref_val(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let (diff_alt, o_alt) = x.sub_round_ref_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_round_assign(y.clone(), rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_round_assign_ref(&y, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Floor, Floor),
+        "(4.5558062159628880 2.0251346478990997)",
+        "(0x4.8e4950f0795fc#53 0x2.066f396ab91da#53)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Ceiling, Ceiling),
+        "(4.5558062159628889 2.0251346478991001)",
+        "(0x4.8e4950f079600#53 0x2.066f396ab91dc#53)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Down, Up),
+        "(4.5558062159628880 2.0251346478991001)",
+        "(0x4.8e4950f0795fc#53 0x2.066f396ab91dc#53)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Up, Down),
+        "(4.5558062159628889 2.0251346478990997)",
+        "(0x4.8e4950f079600#53 0x2.066f396ab91da#53)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (Nearest, Nearest),
+        "(4.5558062159628880 2.0251346478990997)",
+        "(0x4.8e4950f0795fc#53 0x2.066f396ab91da#53)",
+        (Less, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (Floor, Ceiling),
+        "(-4.5558062159628889 -2.0251346478990997)",
+        "(-0x4.8e4950f079600#53 -0x2.066f396ab91da#53)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Floor, Ceiling),
+        "(122.66666666666666666666666666663 -453.00000000000000000000000000000)",
+        "(0x7a.aaaaaaaaaaaaaaaaaaaaaaa8#100 -0x1c5.00000000000000000000000#100)",
+        (Less, Equal),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Ceiling, Floor),
+        "(122.66666666666666666666666666673 -453.00000000000000000000000000000)",
+        "(0x7a.aaaaaaaaaaaaaaaaaaaaaab0#100 -0x1c5.00000000000000000000000#100)",
+        (Greater, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        "(1.0 -2.0)",
+        "(0x1.0#1 -0x2.0#1)",
+        (Exact, Exact),
+        "(2.0 6.0)",
+        "(0x2.0#3 0x6.0#3)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn test_sub_prec_round() {
+    let test = |s,
+                s_hex,
+                t,
+                t_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (diff, o) = x.clone().sub_prec_round(y.clone(), prec, rm);
+        assert!(diff.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(to_hex_string(&diff), out_hex);
+
+        let (diff_alt, o_alt) = x.clone().sub_prec_round_val_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_round_assign(y.clone(), prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+
+        let mut diff_alt = x.clone();
+        let o_alt = diff_alt.sub_prec_round_assign_ref(&y, prec, rm);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+        assert_eq!(o_alt, o_out);
+    };
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(4.5547 2.0273)",
+        "(0x4.8e#10 0x2.07#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Ceiling, Floor),
+        "(4.5625 2.0234)",
+        "(0x4.90#10 0x2.06#10)",
+        (Greater, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Down, Down),
+        "(4.5547 2.0234)",
+        "(0x4.8e#10 0x2.06#10)",
+        (Less, Less),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Up, Up),
+        "(4.5625 2.0273)",
+        "(0x4.90#10 0x2.07#10)",
+        (Greater, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (10, 10),
+        (Nearest, Nearest),
+        "(4.5547 2.0234)",
+        "(0x4.8e#10 0x2.06#10)",
+        (Less, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (20, 5),
+        (Up, Down),
+        "(-4.5558090 -2.00)",
+        "(-0x4.8e498#20 -0x2.0#5)",
+        (Less, Greater),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        "(0.33333333333333333333333333333346 -3.0)",
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (5, 20),
+        (Down, Up),
+        "(120.0 -453.00000)",
+        "(0x78.0#5 -0x1c5.000#20)",
+        (Less, Equal),
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (1, 1),
+        (Floor, Floor),
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        (Equal, Equal),
+    );
+    test(
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (1, 1),
+        (Ceiling, Ceiling),
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (Equal, Equal),
+    );
+}
+
+#[test]
+fn sub_prec_fail() {
+    assert_panic!(Complex::ONE.sub_prec(Complex::ONE, (0, 1)));
+    assert_panic!(Complex::ONE.sub_prec(Complex::ONE, (1, 0)));
+    assert_panic!({
+        let mut x = Complex::ONE;
+        x.sub_prec_assign(Complex::ONE, (0, 1))
+    });
+}
+
+#[test]
+fn sub_round_fail() {
+    let x = parse_hex_string("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)");
+    let y = parse_hex_string("(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)");
+    assert_panic!(x.clone().sub_round(y.clone(), (Exact, Floor)));
+    assert_panic!(x.clone().sub_round_val_ref(&y, (Exact, Floor)));
+    assert_panic!(x.sub_round_ref_val(y.clone(), (Exact, Floor)));
+    assert_panic!(x.sub_round_ref_ref(&y, (Exact, Floor)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.sub_round_assign(y.clone(), (Exact, Floor))
+    });
+    assert_panic!({
+        let mut x = x.clone();
+        x.sub_round_assign_ref(&y, (Exact, Floor))
+    });
+}
+
+#[test]
+fn sub_prec_round_fail() {
+    assert_panic!(Complex::ONE.sub_prec_round(Complex::ONE, (0, 1), (Floor, Floor)));
+    assert_panic!(Complex::ONE.sub_prec_round(Complex::ONE, (1, 0), (Floor, Floor)));
+    let x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    let y = parse_hex_string("(0x1.0#1 -0x2.0#1)");
+    assert_panic!(x.clone().sub_prec_round(y.clone(), (1, 1), (Floor, Exact)));
+    assert_panic!(x.sub_prec_round_ref_ref(&y, (1, 1), (Floor, Exact)));
+    assert_panic!({
+        let mut x = x.clone();
+        x.sub_prec_round_assign(y.clone(), (1, 1), (Floor, Exact))
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_prec_round_properties_helper(
+    x: Complex,
+    y: Complex,
+    prec: (u64, u64),
+    rm: (RoundingMode, RoundingMode),
+) {
+    let (diff, o) = x.clone().sub_prec_round(y.clone(), prec, rm);
+    assert!(diff.is_valid());
+
+    let (diff_alt, o_alt) = x.clone().sub_prec_round_val_ref(&y, prec, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (diff_alt, o_alt) = x.sub_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let mut diff_alt = x.clone();
+    let o_alt = diff_alt.sub_prec_round_assign(y.clone(), prec, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let mut diff_alt = x.clone();
+    let o_alt = diff_alt.sub_prec_round_assign_ref(&y, prec, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (real, o_r) = x
+        .real_ref()
+        .sub_prec_round_ref_ref(y.real_ref(), prec.0, rm.0);
+    assert_eq!(
+        ComparableFloatRef(diff.real_ref()),
+        ComparableFloatRef(&real)
+    );
+    assert_eq!(o.0, o_r);
+    let (imag, o_i) = x
+        .imag_ref()
+        .sub_prec_round_ref_ref(y.imag_ref(), prec.1, rm.1);
+    assert_eq!(
+        ComparableFloatRef(diff.imag_ref()),
+        ComparableFloatRef(&imag)
+    );
+    assert_eq!(o.1, o_i);
+
+    let (diff_alt, o_alt) = x.add_prec_round_ref_ref(&-&y, prec, rm);
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    if diff.real_ref().is_normal() {
+        assert_eq!(diff.real_ref().get_prec(), Some(prec.0));
+    }
+    if diff.imag_ref().is_normal() {
+        assert_eq!(diff.imag_ref().get_prec(), Some(prec.1));
+    }
+
+    if o.0 == Equal {
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, (Exact, rm.1));
+        assert_eq!(
+            ComparableFloat(diff_alt.real_ref().abs_negative_zero_ref()),
+            ComparableFloat(diff.real_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.0, Equal);
+    } else {
+        assert_panic!(x.sub_prec_round_ref_ref(&y, prec, (Exact, rm.1)));
+    }
+    if o.1 == Equal {
+        let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, (rm.0, Exact));
+        assert_eq!(
+            ComparableFloat(diff_alt.imag_ref().abs_negative_zero_ref()),
+            ComparableFloat(diff.imag_ref().abs_negative_zero_ref())
+        );
+        assert_eq!(o_alt.1, Equal);
+    } else {
+        assert_panic!(x.sub_prec_round_ref_ref(&y, prec, (rm.0, Exact)));
+    }
+}
+
+#[test]
+fn sub_prec_round_properties() {
+    complex_complex_unsigned_unsigned_rounding_mode_rounding_mode_sextuple_gen_var_1()
+        .test_properties(|(x, y, p_r, p_i, rm_r, rm_i)| {
+            sub_prec_round_properties_helper(x, y, (p_r, p_i), (rm_r, rm_i));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_prec_properties_helper(x: Complex, y: Complex, prec: (u64, u64)) {
+    let (diff, o) = x.clone().sub_prec(y.clone(), prec);
+    assert!(diff.is_valid());
+
+    let (diff_alt, o_alt) = x.clone().sub_prec_val_ref(&y, prec);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (diff_alt, o_alt) = x.sub_prec_ref_val(y.clone(), prec);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (diff_alt, o_alt) = x.sub_prec_ref_ref(&y, prec);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let mut diff_alt = x.clone();
+    let o_alt = diff_alt.sub_prec_assign(y.clone(), prec);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let mut diff_alt = x.clone();
+    let o_alt = diff_alt.sub_prec_assign_ref(&y, prec);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, prec, (Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn sub_prec_properties() {
+    complex_complex_unsigned_unsigned_quadruple_gen_var_1().test_properties(|(x, y, p_r, p_i)| {
+        sub_prec_properties_helper(x, y, (p_r, p_i));
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_round_properties_helper(x: Complex, y: Complex, rm: (RoundingMode, RoundingMode)) {
+    let (diff, o) = x.clone().sub_round(y.clone(), rm);
+    assert!(diff.is_valid());
+
+    let (diff_alt, o_alt) = x.clone().sub_round_val_ref(&y, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (diff_alt, o_alt) = x.sub_round_ref_val(y.clone(), rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let (diff_alt, o_alt) = x.sub_round_ref_ref(&y, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let mut diff_alt = x.clone();
+    let o_alt = diff_alt.sub_round_assign(y.clone(), rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let mut diff_alt = x.clone();
+    let o_alt = diff_alt.sub_round_assign_ref(&y, rm);
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+
+    let prec = max!(
+        x.real_ref().significant_bits(),
+        x.imag_ref().significant_bits(),
+        y.real_ref().significant_bits(),
+        y.imag_ref().significant_bits()
+    );
+    let (diff_alt, o_alt) = x.sub_prec_round_ref_ref(&y, (prec, prec), rm);
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn sub_round_properties() {
+    complex_complex_rounding_mode_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, y, rm_r, rm_i)| {
+            sub_round_properties_helper(x, y, (rm_r, rm_i));
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_properties_helper(x: Complex, y: Complex) {
+    let diff = x.clone() - y.clone();
+    assert!(diff.is_valid());
+
+    let diff_alt = x.clone() - &y;
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+    let diff_alt = &x - y.clone();
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+    let diff_alt = &x - &y;
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+    let mut diff_alt = x.clone();
+    diff_alt -= y.clone();
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+    let mut diff_alt = x.clone();
+    diff_alt -= &y;
+    assert!(diff_alt.is_valid());
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+    let (diff_alt, _) = x.sub_round_ref_ref(&y, (Nearest, Nearest));
+    assert_eq!(ComparableComplexRef(&diff_alt), ComparableComplexRef(&diff));
+
+    assert_eq!(ComparableComplex(&x + -&y), ComparableComplex(diff));
+}
+
+#[test]
+fn sub_properties() {
+    complex_pair_gen().test_properties(|(x, y)| {
+        sub_properties_helper(x, y);
+    });
+
+    complex_gen().test_properties(|x| {
+        assert!((&x - Complex::NAN).is_nan());
+        assert!((Complex::NAN - &x).is_nan());
+    });
+
+    complex_gen_var_1().test_properties(|x| {
+        assert_eq!(&x - Complex::ZERO, x);
+        assert!((&x - &x).is_zero());
+    });
+}
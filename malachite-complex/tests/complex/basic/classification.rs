@@ -0,0 +1,74 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_complex::Complex;
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::complex_gen;
+
+#[test]
+fn test_classification() {
+    let test =
+        |s_hex, nan: bool, finite: bool, infinite: bool, zero: bool, real: bool, imaginary| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.is_nan(), nan);
+            assert_eq!(x.is_finite(), finite);
+            assert_eq!(x.is_infinite(), infinite);
+            assert_eq!(x.is_zero(), zero);
+            assert_eq!(x.is_real(), real);
+            assert_eq!(x.is_imaginary(), imaginary);
+        };
+    test("(NaN NaN)", true, false, false, false, false, false);
+    test("(NaN 0x0.0)", true, false, false, false, true, false);
+    test("(0x1.0#1 NaN)", true, false, false, false, false, false);
+    test("(Infinity NaN)", false, false, true, false, false, false);
+    test("(NaN -Infinity)", false, false, true, false, false, false);
+    test("(Infinity 0x0.0)", false, false, true, false, true, false);
+    test("(-0x0.0 -Infinity)", false, false, true, false, false, true);
+    test(
+        "(Infinity -Infinity)",
+        false,
+        false,
+        true,
+        false,
+        false,
+        false,
+    );
+    test("(0x0.0 0x0.0)", false, true, false, true, true, true);
+    test("(-0x0.0 0x0.0)", false, true, false, true, true, true);
+    test("(0x1.0#1 0x0.0)", false, true, false, false, true, false);
+    test("(0x0.0 0x1.0#1)", false, true, false, false, false, true);
+    test("(0x3.0#2 0x4.0#3)", false, true, false, false, false, false);
+}
+
+#[test]
+fn classification_properties() {
+    complex_gen().test_properties(|x| {
+        let nan = x.is_nan();
+        let finite = x.is_finite();
+        let infinite = x.is_infinite();
+        assert_eq!(u8::from(nan) + u8::from(finite) + u8::from(infinite), 1);
+        assert_eq!(finite, x.real_ref().is_finite() && x.imag_ref().is_finite());
+        assert_eq!(
+            infinite,
+            x.real_ref().is_infinite() || x.imag_ref().is_infinite()
+        );
+        assert_eq!(x.is_zero(), x.is_real() && x.is_imaginary());
+        if x.is_zero() {
+            assert!(finite);
+        }
+        let neg = -&x;
+        assert_eq!(neg.is_nan(), nan);
+        assert_eq!(neg.is_finite(), finite);
+        assert_eq!(neg.is_infinite(), infinite);
+        assert_eq!(neg.is_zero(), x.is_zero());
+        assert_eq!(neg.is_real(), x.is_real());
+        assert_eq!(neg.is_imaginary(), x.is_imaginary());
+        let swapped = Complex::from_real_imag(x.imag_ref().clone(), x.real_ref().clone());
+        assert_eq!(swapped.is_real(), x.is_imaginary());
+        assert_eq!(swapped.is_imaginary(), x.is_real());
+    });
+}
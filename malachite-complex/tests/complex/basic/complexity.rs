@@ -0,0 +1,69 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::complex_gen;
+
+#[test]
+fn test_complexity() {
+    let test = |s_hex, out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.complexity(), out);
+    };
+    test("(NaN NaN)", 2);
+    test("(Infinity -Infinity)", 2);
+    test("(0x0.0 -0x0.0)", 2);
+    test("(0x1.0#1 0x0.0)", 2);
+    test("(0x3.0#2 0x4.0#3)", 5);
+    test("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)", 106);
+    test("(0x1.0#1 0x1.0E-100#1)", 401);
+    test("(0x1.0E+100#1 0x1.0000000000000000000000000#100)", 500);
+}
+
+#[test]
+fn test_significant_bits() {
+    let test = |s_hex, out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.significant_bits(), out);
+    };
+    test("(NaN NaN)", 2);
+    test("(Infinity -Infinity)", 2);
+    test("(0x0.0 -0x0.0)", 2);
+    test("(0x1.0#1 0x0.0)", 2);
+    test("(0x3.0#2 0x4.0#3)", 5);
+    test("(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)", 106);
+    test("(0x1.0#1 0x1.0E-100#1)", 2);
+    test("(0x1.0E+100#1 0x1.0000000000000000000000000#100)", 101);
+}
+
+#[test]
+fn complexity_properties() {
+    complex_gen().test_properties(|x| {
+        let complexity = x.complexity();
+        assert_eq!(
+            complexity,
+            x.real_ref().complexity() + x.imag_ref().complexity()
+        );
+        assert!(complexity >= x.significant_bits());
+        assert_eq!((-&x).complexity(), complexity);
+        assert_eq!(x.conj_ref().complexity(), complexity);
+    });
+}
+
+#[test]
+fn significant_bits_properties() {
+    complex_gen().test_properties(|x| {
+        let bits = x.significant_bits();
+        assert_eq!(
+            bits,
+            x.real_ref().significant_bits() + x.imag_ref().significant_bits()
+        );
+        assert!(bits >= 2);
+        assert_eq!((-&x).significant_bits(), bits);
+    });
+}
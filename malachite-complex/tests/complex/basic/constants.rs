@@ -0,0 +1,38 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::basic::traits::{NaN, NegativeOne, One, Two, Zero};
+use malachite_complex::Complex;
+use malachite_complex::test_util::common::to_hex_string;
+
+#[test]
+fn test_constants() {
+    let test = |x: Complex, out: &str, out_hex: &str| {
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+    };
+    test(Complex::ZERO, "(0.0 0.0)", "(0x0.0 0x0.0)");
+    test(Complex::ONE, "(1.0 0.0)", "(0x1.0#1 0x0.0)");
+    test(Complex::TWO, "(2.0 0.0)", "(0x2.0#1 0x0.0)");
+    test(Complex::NEGATIVE_ONE, "(-1.0 0.0)", "(-0x1.0#1 0x0.0)");
+    test(Complex::NAN, "(NaN NaN)", "(NaN NaN)");
+    test(Complex::I, "(0.0 1.0)", "(0x0.0 0x1.0#1)");
+    test(Complex::default(), "(NaN NaN)", "(NaN NaN)");
+}
+
+#[test]
+fn test_constant_relations() {
+    assert_eq!(Complex::ONE + Complex::ONE, Complex::TWO);
+    assert_eq!(-Complex::ONE, Complex::NEGATIVE_ONE);
+    assert_eq!(Complex::I * Complex::I, Complex::NEGATIVE_ONE);
+    assert_eq!(Complex::ONE - Complex::ONE, Complex::ZERO);
+    assert!(Complex::ZERO.is_zero());
+    assert!(Complex::NAN.is_nan());
+    assert!(Complex::I.is_imaginary());
+    assert!(Complex::ONE.is_real());
+}
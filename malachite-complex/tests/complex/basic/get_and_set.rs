@@ -0,0 +1,326 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{NaN, One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_complex::test_util::generators::{
+    complex_gen, complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1,
+    complex_unsigned_unsigned_triple_gen_var_1,
+};
+use malachite_complex::{ComparableComplexRef, Complex};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+
+#[test]
+fn test_parts() {
+    let test = |s_hex, real_hex: &str, imag_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        let real = malachite_float::test_util::common::parse_hex_string(real_hex);
+        let imag = malachite_float::test_util::common::parse_hex_string(imag_hex);
+        assert_eq!(ComparableFloatRef(x.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(ComparableFloatRef(x.imag_ref()), ComparableFloatRef(&imag));
+        assert_eq!(
+            ComparableFloat(x.clone().into_real()),
+            ComparableFloat(real.clone())
+        );
+        assert_eq!(
+            ComparableFloat(x.clone().into_imag()),
+            ComparableFloat(imag.clone())
+        );
+        let (real_alt, imag_alt) = x.into_real_imag();
+        assert_eq!(ComparableFloat(real_alt), ComparableFloat(real));
+        assert_eq!(ComparableFloat(imag_alt), ComparableFloat(imag));
+    };
+    test("(NaN NaN)", "NaN", "NaN");
+    test("(Infinity -0x0.0)", "Infinity", "-0x0.0");
+    test("(0x3.0#2 0x4.0#3)", "0x3.0#2", "0x4.0#3");
+    test(
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        "-0x1.6a09e667f3bcd#53",
+        "0x0.b17217f7d1cf78#53",
+    );
+}
+
+#[test]
+fn test_parts_mut() {
+    let mut x = Complex::ONE;
+    *x.real_mut() = Float::from(3);
+    assert_eq!(to_hex_string(&x), "(0x3.0#2 0x0.0)");
+    *x.imag_mut() = -Float::from(0.5);
+    assert_eq!(to_hex_string(&x), "(0x3.0#2 -0x0.8#1)");
+    x.imag_mut().set_prec(10);
+    assert_eq!(to_hex_string(&x), "(0x3.0#2 -0x0.800#10)");
+    *x.real_mut() = Float::NAN;
+    assert!(x.is_nan());
+}
+
+#[test]
+fn test_get_prec() {
+    let test = |s_hex, out: (Option<u64>, Option<u64>)| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.get_prec(), out);
+        assert_eq!((x.real_ref().get_prec(), x.imag_ref().get_prec()), out);
+    };
+    test("(NaN NaN)", (None, None));
+    test("(Infinity -0x0.0)", (None, None));
+    test("(0x1.0#1 0x0.0)", (Some(1), None));
+    test("(0x0.0 0x1.0#1)", (None, Some(1)));
+    test("(0x3.0#2 0x4.0#3)", (Some(2), Some(3)));
+    test("(0x7b.0#7 -0x1c8.0#9)", (Some(7), Some(9)));
+    test(
+        "(0x0.55555555555555555555555558#100 -0x3.0#2)",
+        (Some(100), Some(2)),
+    );
+}
+
+#[test]
+fn test_set_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let mut x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let o = x.set_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+    };
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        (10, 10),
+        (Floor, Floor),
+        "(NaN NaN)",
+        "(NaN NaN)",
+        (Equal, Equal),
+    );
+    test(
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        (10, 10),
+        (Floor, Floor),
+        "(Infinity 0.0)",
+        "(Infinity 0x0.0)",
+        (Equal, Equal),
+    );
+    test(
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        (10, 10),
+        (Floor, Floor),
+        "(-0.0 -0.0)",
+        "(-0x0.0 -0x0.0)",
+        (Equal, Equal),
+    );
+    test(
+        "(1.0 1.0)",
+        "(0x1.0#1 0x1.0#1)",
+        (10, 20),
+        (Exact, Exact),
+        "(1.0000 1.0000000)",
+        "(0x1.000#10 0x1.00000#20)",
+        (Equal, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (1, 1),
+        (Floor, Ceiling),
+        "(2.0 4.0)",
+        "(0x2.0#1 0x4.0#1)",
+        (Less, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (1, 1),
+        (Ceiling, Floor),
+        "(4.0 4.0)",
+        "(0x4.0#1 0x4.0#1)",
+        (Greater, Equal),
+    );
+    test(
+        "(3.0 4.0)",
+        "(0x3.0#2 0x4.0#3)",
+        (1, 2),
+        (Nearest, Nearest),
+        "(4.0 4.0)",
+        "(0x4.0#1 0x4.0#2)",
+        (Greater, Equal),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Floor, Ceiling),
+        "(3.1406 2.7188)",
+        "(0x3.24#10 0x2.b8#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (10, 10),
+        (Down, Up),
+        "(3.1406 2.7188)",
+        "(0x3.24#10 0x2.b8#10)",
+        (Less, Greater),
+    );
+    test(
+        "(3.1415926535897931 2.7182818284590451)",
+        "(0x3.243f6a8885a30#53 0x2.b7e151628aed2#53)",
+        (100, 1),
+        (Exact, Nearest),
+        "(3.1415926535897931159979634685442 2.0)",
+        "(0x3.243f6a8885a30000000000000#100 0x2.0#1)",
+        (Equal, Less),
+    );
+    test(
+        "(-1.4142135623730951 0.69314718055994529)",
+        "(-0x1.6a09e667f3bcd#53 0x0.b17217f7d1cf78#53)",
+        (20, 5),
+        (Up, Down),
+        "(-1.4142151 0.688)",
+        "(-0x1.6a0a0#20 0x0.b0#5)",
+        (Less, Less),
+    );
+    test(
+        "(123.0 -456.0)",
+        "(0x7b.0#7 -0x1c8.0#9)",
+        (3, 4),
+        (Nearest, Nearest),
+        "(1.3e2 -448.0)",
+        "(0x8.0E+1#3 -0x1.cE+2#4)",
+        (Greater, Greater),
+    );
+}
+
+#[test]
+#[should_panic]
+fn set_prec_round_fail_1() {
+    let mut x = Complex::ONE;
+    x.set_prec_round((0, 1), (Floor, Floor));
+}
+
+#[test]
+#[should_panic]
+fn set_prec_round_fail_2() {
+    let mut x = Complex::ONE;
+    x.set_prec_round((1, 0), (Floor, Floor));
+}
+
+#[test]
+#[should_panic]
+fn set_prec_round_fail_3() {
+    let mut x = parse_hex_string("(0x3.0#2 0x4.0#3)");
+    x.set_prec_round((1, 10), (Exact, Floor));
+}
+
+#[test]
+#[should_panic]
+fn set_prec_round_fail_4() {
+    let mut x = parse_hex_string("(0x3.0#2 0x5.0#3)");
+    x.set_prec_round((10, 1), (Floor, Exact));
+}
+
+#[test]
+#[should_panic]
+fn set_prec_fail_1() {
+    let mut x = Complex::ONE;
+    x.set_prec((0, 1));
+}
+
+#[test]
+#[should_panic]
+fn set_prec_fail_2() {
+    let mut x = Complex::ONE;
+    x.set_prec((1, 0));
+}
+
+#[test]
+fn parts_properties() {
+    complex_gen().test_properties(|x| {
+        let (real, imag) = x.clone().into_real_imag();
+        assert_eq!(ComparableFloatRef(x.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(ComparableFloatRef(x.imag_ref()), ComparableFloatRef(&imag));
+        assert_eq!(
+            ComparableFloat(x.clone().into_real()),
+            ComparableFloat(real.clone())
+        );
+        assert_eq!(
+            ComparableFloat(x.clone().into_imag()),
+            ComparableFloat(imag.clone())
+        );
+        assert_eq!(
+            ComparableComplexRef(&Complex::from_real_imag(real, imag)),
+            ComparableComplexRef(&x)
+        );
+
+        let mut y = Complex::ZERO;
+        *y.real_mut() = x.real_ref().clone();
+        *y.imag_mut() = x.imag_ref().clone();
+        assert_eq!(ComparableComplexRef(&y), ComparableComplexRef(&x));
+
+        assert_eq!(
+            x.get_prec(),
+            (x.real_ref().get_prec(), x.imag_ref().get_prec())
+        );
+    });
+}
+
+#[test]
+fn set_prec_round_properties() {
+    complex_unsigned_unsigned_rounding_mode_rounding_mode_quintuple_gen_var_1().test_properties(
+        |(x, p_r, p_i, rm_r, rm_i)| {
+            let mut y = x.clone();
+            let o = y.set_prec_round((p_r, p_i), (rm_r, rm_i));
+            assert!(y.is_valid());
+            let mut real = x.real_ref().clone();
+            let o_r = real.set_prec_round(p_r, rm_r);
+            let mut imag = x.imag_ref().clone();
+            let o_i = imag.set_prec_round(p_i, rm_i);
+            assert_eq!(ComparableFloatRef(y.real_ref()), ComparableFloatRef(&real));
+            assert_eq!(ComparableFloatRef(y.imag_ref()), ComparableFloatRef(&imag));
+            assert_eq!(o, (o_r, o_i));
+            if y.real_ref().is_normal() {
+                assert_eq!(y.real_ref().get_prec(), Some(p_r));
+            }
+            if y.imag_ref().is_normal() {
+                assert_eq!(y.imag_ref().get_prec(), Some(p_i));
+            }
+
+            // Raising the precisions back loses nothing more.
+            let old_y = y.clone();
+            let p = max!(p_r, p_i, x.significant_bits());
+            assert_eq!(y.set_prec_round((p, p), (Exact, Exact)), (Equal, Equal));
+            if !y.real_ref().is_nan() && !y.imag_ref().is_nan() {
+                assert_eq!(y, old_y);
+            }
+        },
+    );
+}
+
+#[test]
+fn set_prec_properties() {
+    complex_unsigned_unsigned_triple_gen_var_1().test_properties(|(x, p_r, p_i)| {
+        let mut y = x.clone();
+        let o = y.set_prec((p_r, p_i));
+        assert!(y.is_valid());
+        let mut y_alt = x.clone();
+        let o_alt = y_alt.set_prec_round((p_r, p_i), (Nearest, Nearest));
+        assert_eq!(ComparableComplexRef(&y_alt), ComparableComplexRef(&y));
+        assert_eq!(o_alt, o);
+    });
+}
@@ -0,0 +1,87 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use core::cmp::Ordering::*;
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::{complex_gen, complex_pair_gen};
+use malachite_complex::{ComparableComplex, ComparableComplexRef};
+use malachite_float::ComparableFloatRef;
+
+const ORDERED_COMPLEX_HEX_STRINGS: [&str; 14] = [
+    "(-Infinity NaN)",
+    "(-Infinity 0x1.0#1)",
+    "(-0x3.0#2 0x4.0#3)",
+    "(-0x1.0#1 -0x0.0)",
+    "(-0x1.0#1 0x0.0)",
+    "(-0x0.0 0x0.0)",
+    "(NaN -0x0.0)",
+    "(NaN NaN)",
+    "(0x0.0 -0x1.0#1)",
+    "(0x0.0 0x0.0)",
+    "(0x0.0 0x1.0#1)",
+    "(0x1.0#1 0x0.0)",
+    "(0x1.0#2 0x0.0)",
+    "(Infinity -Infinity)",
+];
+
+#[test]
+fn test_cmp() {
+    for (i, sx) in ORDERED_COMPLEX_HEX_STRINGS.iter().enumerate() {
+        let x = parse_hex_string(sx);
+        for (j, sy) in ORDERED_COMPLEX_HEX_STRINGS.iter().enumerate() {
+            let y = parse_hex_string(sy);
+            let ord = i.cmp(&j);
+            assert_eq!(ComparableComplexRef(&x).cmp(&ComparableComplexRef(&y)), ord);
+            assert_eq!(
+                ComparableComplex(x.clone()).cmp(&ComparableComplex(y.clone())),
+                ord
+            );
+            assert_eq!(
+                ComparableComplexRef(&x).partial_cmp(&ComparableComplexRef(&y)),
+                Some(ord)
+            );
+            assert_eq!(
+                ComparableComplex(x.clone()).partial_cmp(&ComparableComplex(y)),
+                Some(ord)
+            );
+        }
+    }
+}
+
+#[test]
+fn cmp_properties() {
+    complex_pair_gen().test_properties(|(x, y)| {
+        let ord = ComparableComplexRef(&x).cmp(&ComparableComplexRef(&y));
+        assert_eq!(
+            ComparableComplex(x.clone()).cmp(&ComparableComplex(y.clone())),
+            ord
+        );
+        assert_eq!(
+            ComparableComplexRef(&y).cmp(&ComparableComplexRef(&x)),
+            ord.reverse()
+        );
+        assert_eq!(
+            ord == Equal,
+            ComparableComplexRef(&x) == ComparableComplexRef(&y)
+        );
+        assert_eq!(
+            ComparableFloatRef(x.real_ref())
+                .cmp(&ComparableFloatRef(y.real_ref()))
+                .then_with(
+                    || ComparableFloatRef(x.imag_ref()).cmp(&ComparableFloatRef(y.imag_ref()))
+                ),
+            ord
+        );
+    });
+
+    complex_gen().test_properties(|x| {
+        assert_eq!(
+            ComparableComplexRef(&x).cmp(&ComparableComplexRef(&x)),
+            Equal
+        );
+    });
+}
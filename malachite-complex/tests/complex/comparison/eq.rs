@@ -0,0 +1,72 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::{complex_gen, complex_pair_gen};
+use malachite_complex::{ComparableComplex, ComparableComplexRef};
+use malachite_float::ComparableFloatRef;
+
+#[test]
+fn test_eq() {
+    let test = |s_hex, t_hex, eq: bool, comparable_eq: bool| {
+        let x = parse_hex_string(s_hex);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(x == y, eq);
+        assert_eq!(y == x, eq);
+        assert_eq!(x != y, !eq);
+        assert_eq!(
+            ComparableComplexRef(&x) == ComparableComplexRef(&y),
+            comparable_eq
+        );
+        assert_eq!(ComparableComplex(x) == ComparableComplex(y), comparable_eq);
+    };
+    test("(NaN NaN)", "(NaN NaN)", false, true);
+    test("(0x1.0#1 NaN)", "(0x1.0#1 NaN)", false, true);
+    test("(Infinity NaN)", "(Infinity NaN)", false, true);
+    test("(Infinity 0x0.0)", "(Infinity 0x0.0)", true, true);
+    test("(0x0.0 0x0.0)", "(-0x0.0 -0x0.0)", true, false);
+    test("(0x0.0 0x0.0)", "(0x0.0 -0x0.0)", true, false);
+    test("(0x1.0#1 0x0.0)", "(0x1.0#1 0x0.0)", true, true);
+    test("(0x1.0#1 0x0.0)", "(0x1.0#2 0x0.0)", true, false);
+    test("(0x1.0#1 0x0.0)", "(0x0.0 0x1.0#1)", false, false);
+    test("(0x3.0#2 0x4.0#3)", "(0x3.0#2 0x4.0#3)", true, true);
+    test("(0x3.0#2 0x4.0#3)", "(0x3.0#2 -0x4.0#3)", false, false);
+}
+
+#[allow(clippy::eq_op)]
+#[test]
+fn eq_properties() {
+    complex_pair_gen().test_properties(|(x, y)| {
+        let eq = x == y;
+        assert_eq!(y == x, eq);
+        assert_eq!(
+            eq,
+            x.real_ref() == y.real_ref() && x.imag_ref() == y.imag_ref()
+        );
+        let comparable_eq = ComparableComplexRef(&x) == ComparableComplexRef(&y);
+        assert_eq!(
+            comparable_eq,
+            ComparableFloatRef(x.real_ref()) == ComparableFloatRef(y.real_ref())
+                && ComparableFloatRef(x.imag_ref()) == ComparableFloatRef(y.imag_ref())
+        );
+        assert_eq!(
+            ComparableComplex(x.clone()) == ComparableComplex(y.clone()),
+            comparable_eq
+        );
+        if comparable_eq && !x.is_nan() && !x.is_infinite() {
+            assert!(eq);
+        }
+        assert_eq!(-&x == -&y, eq);
+        assert_eq!(x.conj_ref() == y.conj_ref(), eq);
+    });
+
+    complex_gen().test_properties(|x| {
+        assert_eq!(x == x, !x.real_ref().is_nan() && !x.imag_ref().is_nan());
+        assert_eq!(ComparableComplexRef(&x), ComparableComplexRef(&x));
+        assert_eq!(ComparableComplex(x.clone()), ComparableComplex(x.clone()));
+    });
+}
@@ -0,0 +1,39 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::test_util::hash::hash;
+use malachite_complex::Complex;
+use malachite_complex::test_util::generators::{complex_gen, complex_pair_gen};
+use malachite_complex::{ComparableComplex, ComparableComplexRef};
+
+#[allow(clippy::needless_pass_by_value)]
+fn hash_properties_helper(x: Complex) {
+    assert_eq!(
+        hash(&ComparableComplexRef(&x)),
+        hash(&ComparableComplexRef(&x.clone()))
+    );
+    assert_eq!(
+        hash(&ComparableComplex(x.clone())),
+        hash(&ComparableComplexRef(&x))
+    );
+}
+
+#[test]
+fn hash_properties() {
+    complex_gen().test_properties(|x| {
+        hash_properties_helper(x);
+    });
+
+    complex_pair_gen().test_properties(|(x, y)| {
+        if ComparableComplexRef(&x) == ComparableComplexRef(&y) {
+            assert_eq!(
+                hash(&ComparableComplexRef(&x)),
+                hash(&ComparableComplexRef(&y))
+            );
+        }
+    });
+}
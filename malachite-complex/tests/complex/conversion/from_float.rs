@@ -0,0 +1,316 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::to_hex_string;
+use malachite_complex::{ComparableComplexRef, Complex};
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::generators::{
+    float_gen, float_pair_gen, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_from_real_imag() {
+    let test = |s_hex, t_hex, out: &str, out_hex: &str| {
+        let real = parse_hex_string(s_hex);
+        let imag = parse_hex_string(t_hex);
+
+        let z = Complex::from_real_imag(real.clone(), imag.clone());
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+
+        let z_alt = Complex::from((real, imag));
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+    };
+    test("NaN", "NaN", "(NaN NaN)", "(NaN NaN)");
+    test("Infinity", "-0x0.0", "(Infinity -0.0)", "(Infinity -0x0.0)");
+    test("0x1.0#1", "0x0.0", "(1.0 0.0)", "(0x1.0#1 0x0.0)");
+    test("0x3.0#2", "0x4.0#3", "(3.0 4.0)", "(0x3.0#2 0x4.0#3)");
+    test(
+        "0x3.243f6a8885a30#53",
+        "-0x0.8#1",
+        "(3.1415926535897931 -0.50)",
+        "(0x3.243f6a8885a30#53 -0x0.8#1)",
+    );
+}
+
+#[test]
+fn test_from_real_imag_prec_round() {
+    let test = |s_hex,
+                t_hex,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let real = parse_hex_string(s_hex);
+        let imag = parse_hex_string(t_hex);
+
+        let (z, o) = Complex::from_real_imag_prec_round(real.clone(), imag.clone(), prec, rm);
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+        assert_eq!(o, o_out);
+
+        let (z_alt, o_alt) = Complex::from_real_imag_prec_round_ref(&real, &imag, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    };
+    test(
+        "NaN",
+        "Infinity",
+        (10, 10),
+        (Floor, Floor),
+        "(NaN Infinity)",
+        "(NaN Infinity)",
+        (Equal, Equal),
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#3",
+        (10, 10),
+        (Exact, Exact),
+        "(3.0000 4.0000)",
+        "(0x3.00#10 0x4.00#10)",
+        (Equal, Equal),
+    );
+    test(
+        "0x3.0#2",
+        "0x5.0#3",
+        (1, 2),
+        (Floor, Ceiling),
+        "(2.0 6.0)",
+        "(0x2.0#1 0x6.0#2)",
+        (Less, Greater),
+    );
+    test(
+        "0x3.243f6a8885a30#53",
+        "0x2.b7e151628aed2#53",
+        (10, 5),
+        (Floor, Ceiling),
+        "(3.1406 2.75)",
+        "(0x3.24#10 0x2.c#5)",
+        (Less, Greater),
+    );
+    test(
+        "0x3.243f6a8885a30#53",
+        "0x2.b7e151628aed2#53",
+        (10, 5),
+        (Nearest, Nearest),
+        "(3.1406 2.75)",
+        "(0x3.24#10 0x2.c#5)",
+        (Less, Greater),
+    );
+}
+
+#[test]
+fn from_real_imag_prec_round_fail() {
+    assert_panic!(Complex::from_real_imag_prec_round(
+        Float::ONE,
+        Float::ONE,
+        (0, 1),
+        (Floor, Floor)
+    ));
+    assert_panic!(Complex::from_real_imag_prec_round(
+        Float::ONE,
+        Float::from(3),
+        (1, 1),
+        (Floor, Exact)
+    ));
+}
+
+#[test]
+fn test_from_float_prec_round() {
+    let test = |s_hex, prec: u64, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+
+        let (z, o) = Complex::from_float_prec_round(x.clone(), prec, rm);
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+        assert_eq!(o, o_out);
+
+        let (z_alt, o_alt) = Complex::from_float_prec_round_ref(&x, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    };
+    test("NaN", 10, Floor, "(NaN 0.0)", "(NaN 0x0.0)", Equal);
+    test("-0x0.0", 10, Floor, "(-0.0 0.0)", "(-0x0.0 0x0.0)", Equal);
+    test(
+        "-Infinity",
+        10,
+        Floor,
+        "(-Infinity 0.0)",
+        "(-Infinity 0x0.0)",
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        10,
+        Exact,
+        "(3.0000 0.0)",
+        "(0x3.00#10 0x0.0)",
+        Equal,
+    );
+    test("0x3.0#2", 1, Floor, "(2.0 0.0)", "(0x2.0#1 0x0.0)", Less);
+    test(
+        "0x3.0#2",
+        1,
+        Ceiling,
+        "(4.0 0.0)",
+        "(0x4.0#1 0x0.0)",
+        Greater,
+    );
+    test(
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "(3.1406 0.0)",
+        "(0x3.24#10 0x0.0)",
+        Less,
+    );
+}
+
+#[test]
+fn from_float_prec_round_fail() {
+    assert_panic!(Complex::from_float_prec_round(Float::ONE, 0, Floor));
+    assert_panic!(Complex::from_float_prec_round(Float::from(3), 1, Exact));
+}
+
+#[test]
+fn test_from_float() {
+    let test = |s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+
+        let z = Complex::from(x.clone());
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+
+        let z_alt = Complex::from(&x);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+    };
+    test("NaN", "(NaN 0.0)", "(NaN 0x0.0)");
+    test("Infinity", "(Infinity 0.0)", "(Infinity 0x0.0)");
+    test("-0x0.0", "(-0.0 0.0)", "(-0x0.0 0x0.0)");
+    test("0x1.0#1", "(1.0 0.0)", "(0x1.0#1 0x0.0)");
+    test(
+        "-0x1.6a09e667f3bcd#53",
+        "(-1.4142135623730951 0.0)",
+        "(-0x1.6a09e667f3bcd#53 0x0.0)",
+    );
+}
+
+#[test]
+fn from_real_imag_properties() {
+    float_pair_gen().test_properties(|(real, imag)| {
+        let z = Complex::from_real_imag(real.clone(), imag.clone());
+        assert!(z.is_valid());
+        assert_eq!(ComparableFloatRef(z.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(ComparableFloatRef(z.imag_ref()), ComparableFloatRef(&imag));
+        assert_eq!(
+            ComparableComplexRef(&Complex::from((real.clone(), imag.clone()))),
+            ComparableComplexRef(&z)
+        );
+
+        let (z_alt, o) =
+            Complex::from_real_imag_prec_round_ref(&real, &imag, (10, 20), (Floor, Ceiling));
+        assert!(z_alt.is_valid());
+        let (real_alt, o_r) = Float::from_float_prec_round_ref(&real, 10, Floor);
+        let (imag_alt, o_i) = Float::from_float_prec_round_ref(&imag, 20, Ceiling);
+        assert_eq!(
+            ComparableFloat(z_alt.real_ref().clone()),
+            ComparableFloat(real_alt)
+        );
+        assert_eq!(
+            ComparableFloat(z_alt.imag_ref().clone()),
+            ComparableFloat(imag_alt)
+        );
+        assert_eq!(o, (o_r, o_i));
+
+        let (z_alt_2, o_alt) = Complex::from_real_imag_prec(real, imag, (10, 20));
+        let (z_alt_3, o_alt_3) = Complex::from_real_imag_prec_round_ref(
+            z.real_ref(),
+            z.imag_ref(),
+            (10, 20),
+            (Nearest, Nearest),
+        );
+        assert_eq!(
+            ComparableComplexRef(&z_alt_2),
+            ComparableComplexRef(&z_alt_3)
+        );
+        assert_eq!(o_alt, o_alt_3);
+    });
+}
+
+#[test]
+fn from_float_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (z, o) = Complex::from_float_prec_round(x.clone(), prec, rm);
+        assert!(z.is_valid());
+
+        let (z_alt, o_alt) = Complex::from_float_prec_round_ref(&x, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+
+        let (real, o_alt) = Float::from_float_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(z.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(o_alt, o);
+        assert_eq!(
+            ComparableFloatRef(z.imag_ref()),
+            ComparableFloatRef(&Float::ZERO)
+        );
+    });
+}
+
+#[test]
+fn from_float_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (z, o) = Complex::from_float_prec(x.clone(), prec);
+        assert!(z.is_valid());
+
+        let (z_alt, o_alt) = Complex::from_float_prec_ref(&x, prec);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+
+        let (z_alt, o_alt) = Complex::from_float_prec_round_ref(&x, prec, Nearest);
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[test]
+fn from_float_properties() {
+    float_gen().test_properties(|x| {
+        let z = Complex::from(x.clone());
+        assert!(z.is_valid());
+        assert_eq!(
+            ComparableComplexRef(&Complex::from(&x)),
+            ComparableComplexRef(&z)
+        );
+        assert_eq!(ComparableFloatRef(z.real_ref()), ComparableFloatRef(&x));
+        assert_eq!(
+            ComparableFloatRef(z.imag_ref()),
+            ComparableFloatRef(&Float::ZERO)
+        );
+        assert!(z.is_real());
+        assert_eq!(ComparableFloat(z.into_real()), ComparableFloat(x));
+    });
+}
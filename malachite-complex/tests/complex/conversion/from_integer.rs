@@ -0,0 +1,177 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::to_hex_string;
+use malachite_complex::{ComparableComplexRef, Complex};
+use malachite_float::test_util::generators::integer_unsigned_rounding_mode_triple_gen_var_3;
+use malachite_float::{ComparableFloatRef, Float};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, integer_unsigned_pair_gen_var_6};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_integer_prec_round() {
+    let test = |s, prec: u64, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = Integer::from_str(s).unwrap();
+
+        let (z, o) = Complex::from_integer_prec_round(x.clone(), prec, rm);
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+        assert_eq!(o, o_out);
+
+        let (z_alt, o_alt) = Complex::from_integer_prec_round_ref(&x, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    };
+    test("0", 1, Floor, "(0.0 0.0)", "(0x0.0 0x0.0)", Equal);
+    test("0", 10, Exact, "(0.0 0.0)", "(0x0.0 0x0.0)", Equal);
+    test("1", 1, Exact, "(1.0 0.0)", "(0x1.0#1 0x0.0)", Equal);
+    test("1", 10, Exact, "(1.0000 0.0)", "(0x1.000#10 0x0.0)", Equal);
+    test(
+        "-123",
+        20,
+        Exact,
+        "(-123.00000 0.0)",
+        "(-0x7b.0000#20 0x0.0)",
+        Equal,
+    );
+    test(
+        "-123",
+        4,
+        Floor,
+        "(-128.0 0.0)",
+        "(-0x8.0E+1#4 0x0.0)",
+        Less,
+    );
+    test(
+        "-123",
+        4,
+        Ceiling,
+        "(-120.0 0.0)",
+        "(-0x78.0#4 0x0.0)",
+        Greater,
+    );
+    test(
+        "-123",
+        4,
+        Nearest,
+        "(-120.0 0.0)",
+        "(-0x78.0#4 0x0.0)",
+        Greater,
+    );
+    test(
+        "1000000000000",
+        10,
+        Down,
+        "(9.9965e11 0.0)",
+        "(0xe.8cE+9#10 0x0.0)",
+        Less,
+    );
+}
+
+#[test]
+fn from_integer_prec_round_fail() {
+    assert_panic!(Complex::from_integer_prec_round(Integer::ONE, 0, Floor));
+    assert_panic!(Complex::from_integer_prec_round(
+        Integer::from(123),
+        1,
+        Exact
+    ));
+}
+
+#[test]
+fn test_try_from_integer() {
+    let test = |s, out: &str, out_hex: &str| {
+        let x = Integer::from_str(s).unwrap();
+
+        let z = Complex::try_from(x.clone()).unwrap();
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+
+        let z_alt = Complex::try_from(&x).unwrap();
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert!(Complex::convertible_from(&x));
+    };
+    test("0", "(0.0 0.0)", "(0x0.0 0x0.0)");
+    test("1", "(1.0 0.0)", "(0x1.0#1 0x0.0)");
+    test("10", "(10.0 0.0)", "(0xa.0#3 0x0.0)");
+    test("-123", "(-123.0 0.0)", "(-0x7b.0#7 0x0.0)");
+    test(
+        "1000000000000",
+        "(1.000000000e12 0.0)",
+        "(0xe.8d4a51E+9#28 0x0.0)",
+    );
+}
+
+#[test]
+fn from_integer_prec_round_properties() {
+    integer_unsigned_rounding_mode_triple_gen_var_3().test_properties(|(n, prec, rm)| {
+        let (z, o) = Complex::from_integer_prec_round(n.clone(), prec, rm);
+        assert!(z.is_valid());
+
+        let (z_alt, o_alt) = Complex::from_integer_prec_round_ref(&n, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+
+        let (real, o_alt) = Float::from_integer_prec_round_ref(&n, prec, rm);
+        assert_eq!(ComparableFloatRef(z.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(o_alt, o);
+        assert_eq!(
+            ComparableFloatRef(z.imag_ref()),
+            ComparableFloatRef(&Float::ZERO)
+        );
+    });
+}
+
+#[test]
+fn from_integer_prec_properties() {
+    integer_unsigned_pair_gen_var_6().test_properties(|(n, prec)| {
+        let (z, o) = Complex::from_integer_prec(n.clone(), prec);
+        assert!(z.is_valid());
+
+        let (z_alt, o_alt) = Complex::from_integer_prec_ref(&n, prec);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+
+        let (z_alt, o_alt) = Complex::from_integer_prec_round_ref(&n, prec, Nearest);
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[test]
+fn try_from_integer_properties() {
+    integer_gen().test_properties(|n| {
+        let oz = Complex::try_from(&n);
+        assert_eq!(oz.is_ok(), Complex::convertible_from(&n));
+        if let Ok(z) = oz {
+            assert!(z.is_valid());
+            assert!(z.is_real());
+            assert_eq!(
+                ComparableComplexRef(&Complex::try_from(n.clone()).unwrap()),
+                ComparableComplexRef(&z)
+            );
+            assert_eq!(
+                ComparableFloatRef(z.real_ref()),
+                ComparableFloatRef(&Float::try_from(&n).unwrap())
+            );
+            assert_eq!(Integer::try_from(z.real_ref()).unwrap(), n);
+        }
+    });
+}
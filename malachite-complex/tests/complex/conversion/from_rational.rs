@@ -0,0 +1,269 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_complex::test_util::common::to_hex_string;
+use malachite_complex::{ComparableComplexRef, Complex};
+use malachite_float::test_util::generators::rational_unsigned_rounding_mode_triple_gen_var_1;
+use malachite_float::{ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::rational::conversion::primitive_float_from_rational::FloatConversionError;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_pair_gen, rational_unsigned_pair_gen_var_3,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_rational_prec_round() {
+    let test = |s, prec: u64, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (z, o) = Complex::from_rational_prec_round(x.clone(), prec, rm);
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+        assert_eq!(o, o_out);
+
+        let (z_alt, o_alt) = Complex::from_rational_prec_round_ref(&x, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    };
+    test("0", 1, Floor, "(0.0 0.0)", "(0x0.0 0x0.0)", Equal);
+    test("1", 10, Exact, "(1.0000 0.0)", "(0x1.000#10 0x0.0)", Equal);
+    test("-1/8", 1, Exact, "(-0.12 0.0)", "(-0x0.2#1 0x0.0)", Equal);
+    test(
+        "1/3",
+        10,
+        Floor,
+        "(0.33301 0.0)",
+        "(0x0.554#10 0x0.0)",
+        Less,
+    );
+    test(
+        "1/3",
+        10,
+        Ceiling,
+        "(0.33350 0.0)",
+        "(0x0.556#10 0x0.0)",
+        Greater,
+    );
+    test(
+        "1/3",
+        10,
+        Nearest,
+        "(0.33350 0.0)",
+        "(0x0.556#10 0x0.0)",
+        Greater,
+    );
+    test("-22/7", 5, Down, "(-3.12 0.0)", "(-0x3.2#5 0x0.0)", Greater);
+}
+
+#[test]
+fn from_rational_prec_round_fail() {
+    assert_panic!(Complex::from_rational_prec_round(Rational::ONE, 0, Floor));
+    assert_panic!(Complex::from_rational_prec_round(
+        Rational::from_signeds(1, 3),
+        10,
+        Exact
+    ));
+}
+
+#[test]
+fn test_from_rationals_prec_round() {
+    let test = |s,
+                t,
+                prec: (u64, u64),
+                rm: (RoundingMode, RoundingMode),
+                out: &str,
+                out_hex: &str,
+                o_out: (Ordering, Ordering)| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+
+        let (z, o) = Complex::from_rationals_prec_round(x.clone(), y.clone(), prec, rm);
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+        assert_eq!(to_hex_string(&z), out_hex);
+        assert_eq!(o, o_out);
+
+        let (z_alt, o_alt) = Complex::from_rationals_prec_round_ref(&x, &y, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    };
+    test(
+        "0",
+        "0",
+        (1, 1),
+        (Exact, Exact),
+        "(0.0 0.0)",
+        "(0x0.0 0x0.0)",
+        (Equal, Equal),
+    );
+    test(
+        "3",
+        "-4",
+        (10, 10),
+        (Exact, Exact),
+        "(3.0000 -4.0000)",
+        "(0x3.00#10 -0x4.00#10)",
+        (Equal, Equal),
+    );
+    test(
+        "1/3",
+        "3/4",
+        (10, 2),
+        (Floor, Exact),
+        "(0.33301 0.75)",
+        "(0x0.554#10 0x0.c#2)",
+        (Less, Equal),
+    );
+    test(
+        "1/3",
+        "-1/3",
+        (10, 10),
+        (Ceiling, Ceiling),
+        "(0.33350 -0.33301)",
+        "(0x0.556#10 -0x0.554#10)",
+        (Greater, Greater),
+    );
+}
+
+#[test]
+fn from_rationals_prec_round_fail() {
+    assert_panic!(Complex::from_rationals_prec_round(
+        Rational::ONE,
+        Rational::ONE,
+        (1, 0),
+        (Floor, Floor)
+    ));
+    assert_panic!(Complex::from_rationals_prec_round(
+        Rational::ONE,
+        Rational::from_signeds(1, 3),
+        (10, 10),
+        (Floor, Exact)
+    ));
+}
+
+#[test]
+fn test_try_from_rational() {
+    let test = |s, out: Result<(&str, &str), FloatConversionError>| {
+        let x = Rational::from_str(s).unwrap();
+
+        let oz = Complex::try_from(x.clone());
+        let oz_alt = Complex::try_from(&x);
+        assert_eq!(oz.is_ok(), Complex::convertible_from(&x));
+        match (oz, oz_alt) {
+            (Ok(z), Ok(z_alt)) => {
+                assert!(z.is_valid());
+                assert!(z_alt.is_valid());
+                assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+                let (out, out_hex) = out.unwrap();
+                assert_eq!(z.to_string(), out);
+                assert_eq!(to_hex_string(&z), out_hex);
+            }
+            (Err(e), Err(e_alt)) => {
+                assert_eq!(e_alt, e);
+                assert_eq!(Err(e), out);
+            }
+            _ => panic!(),
+        }
+    };
+    test("0", Ok(("(0.0 0.0)", "(0x0.0 0x0.0)")));
+    test("-1/8", Ok(("(-0.12 0.0)", "(-0x0.2#1 0x0.0)")));
+    test("3/8", Ok(("(0.38 0.0)", "(0x0.6#2 0x0.0)")));
+    test("1/3", Err(FloatConversionError::Inexact));
+}
+
+#[test]
+fn from_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (z, o) = Complex::from_rational_prec_round(x.clone(), prec, rm);
+        assert!(z.is_valid());
+
+        let (z_alt, o_alt) = Complex::from_rational_prec_round_ref(&x, prec, rm);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+
+        let (real, o_alt) = Float::from_rational_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(z.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(o_alt, o);
+        assert_eq!(
+            ComparableFloatRef(z.imag_ref()),
+            ComparableFloatRef(&Float::ZERO)
+        );
+
+        let (z_alt, o_alt) =
+            Complex::from_rationals_prec_round_ref(&x, &Rational::ZERO, (prec, 1), (rm, Exact));
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, (o, Equal));
+    });
+}
+
+#[test]
+fn from_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        let (z, o) = Complex::from_rational_prec(x.clone(), prec);
+        assert!(z.is_valid());
+
+        let (z_alt, o_alt) = Complex::from_rational_prec_ref(&x, prec);
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+
+        let (z_alt, o_alt) = Complex::from_rational_prec_round_ref(&x, prec, Nearest);
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[test]
+fn from_rationals_prec_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let (z, o) = Complex::from_rationals_prec(x.clone(), y.clone(), (20, 30));
+        assert!(z.is_valid());
+
+        let (z_alt, o_alt) = Complex::from_rationals_prec_ref(&x, &y, (20, 30));
+        assert!(z_alt.is_valid());
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+
+        let (real, o_r) = Float::from_rational_prec_ref(&x, 20);
+        let (imag, o_i) = Float::from_rational_prec_ref(&y, 30);
+        assert_eq!(ComparableFloatRef(z.real_ref()), ComparableFloatRef(&real));
+        assert_eq!(ComparableFloatRef(z.imag_ref()), ComparableFloatRef(&imag));
+        assert_eq!(o, (o_r, o_i));
+
+        let (z_alt, o_alt) = Complex::from_rationals_prec_round(x, y, (20, 30), (Nearest, Nearest));
+        assert_eq!(ComparableComplexRef(&z_alt), ComparableComplexRef(&z));
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[test]
+fn try_from_rational_properties() {
+    rational_gen().test_properties(|x| {
+        let oz = Complex::try_from(&x);
+        assert_eq!(oz.is_ok(), Complex::convertible_from(&x));
+        if let Ok(z) = oz {
+            assert!(z.is_valid());
+            assert!(z.is_real());
+            assert_eq!(
+                ComparableComplexRef(&Complex::try_from(x.clone()).unwrap()),
+                ComparableComplexRef(&z)
+            );
+            assert_eq!(Rational::exact_from(z.real_ref()), x);
+        }
+    });
+}
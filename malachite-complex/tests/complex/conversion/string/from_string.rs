@@ -0,0 +1,137 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::FromStringBase;
+use malachite_complex::test_util::common::to_hex_string;
+use malachite_complex::test_util::generators::complex_gen;
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::{ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_string_base() {
+    let test = |base, s, out: Option<(&str, &str)>| {
+        let oz = Complex::from_string_base(base, s);
+        match out {
+            None => assert!(oz.is_none()),
+            Some((out, out_hex)) => {
+                let z = oz.unwrap();
+                assert!(z.is_valid());
+                assert_eq!(z.to_string(), out);
+                assert_eq!(to_hex_string(&z), out_hex);
+            }
+        }
+    };
+    test(
+        10,
+        "(NaN -Infinity)",
+        Some(("(NaN -Infinity)", "(NaN -Infinity)")),
+    );
+    test(10, "(0.0 -0.0)", Some(("(0.0 -0.0)", "(0x0.0 -0x0.0)")));
+    test(
+        10,
+        "(1.5#10 -2.0)",
+        Some(("(1.5000 -2.0)", "(0x1.800#10 -0x2.0#1)")),
+    );
+    test(
+        16,
+        "(0xff.0#8 1.8)",
+        Some(("(255.0 1.5)", "(0xff.0#8 0x1.8#2)")),
+    );
+    test(
+        16,
+        "(ff.0 -0x1.8)",
+        Some(("(255.0 -1.5)", "(0xff.0#8 -0x1.8#2)")),
+    );
+    test(2, "(1.1 -11.0)", Some(("(1.5 -3.0)", "(0x1.8#2 -0x3.0#2)")));
+    // A bare Float is the real part, with an imaginary part of positive zero.
+    test(10, "-0.5", Some(("(-0.50 0.0)", "(-0x0.8#1 0x0.0)")));
+    test(10, "NaN", Some(("(NaN 0.0)", "(NaN 0x0.0)")));
+    // As in MPC, any amount of whitespace may separate the parts.
+    test(10, "( 1.0  2.0)", Some(("(1.0 2.0)", "(0x1.0#1 0x2.0#1)")));
+
+    test(10, "", None);
+    test(10, "()", None);
+    test(10, "(1.0)", None);
+    test(10, "(1.0 2.0 3.0)", None);
+    test(10, "(1.0 2.0", None);
+    test(10, "1.0 2.0)", None);
+    test(10, "(abc 1.0)", None);
+    test(10, "(1.0 ff)", None);
+}
+
+#[test]
+fn from_string_base_fail() {
+    assert_panic!(Complex::from_string_base(1, "(1.0 2.0)"));
+    assert_panic!(Complex::from_string_base(37, "(1.0 2.0)"));
+    let _ = Float::ZERO;
+}
+
+#[test]
+fn test_from_str() {
+    let test = |s, out: &str, out_comparable: &str| {
+        let z = Complex::from_str(s).unwrap();
+        assert!(z.is_valid());
+        assert_eq!(z.to_string(), out);
+
+        let z_alt = ComparableComplex::from_str(s).unwrap();
+        assert_eq!(ComparableComplexRef(&z_alt.0), ComparableComplexRef(&z));
+        assert_eq!(z_alt.to_string(), out_comparable);
+    };
+    test("(1.5#2 1.0#20)", "(1.5 1.0000000)", "(1.5#2 1.0000000#20)");
+    test("3.0", "(3.0 0.0)", "(3.0#2 0.0)");
+    test("(NaN Infinity)", "(NaN Infinity)", "(NaN Infinity)");
+    assert!(Complex::from_str("(abc 1.0)").is_err());
+    assert!(ComparableComplex::from_str("(1.0 2.0 3.0)").is_err());
+}
+
+#[test]
+fn from_string_base_properties() {
+    complex_gen().test_properties(|z| {
+        // What ComparableComplex writes in base 10, FromStr reads back exactly, precision and all.
+        let s = ComparableComplexRef(&z).to_string();
+        assert_eq!(
+            ComparableComplex::from_str(&s).unwrap(),
+            ComparableComplex(z.clone())
+        );
+        assert_eq!(
+            ComparableComplex(Complex::from_str(&s).unwrap()),
+            ComparableComplex(z.clone())
+        );
+        assert_eq!(
+            ComparableComplex(Complex::from_string_base(10, &s).unwrap()),
+            ComparableComplex(z.clone())
+        );
+
+        // The same holds in base 16, with or without the prefix.
+        let s = to_hex_string(&z);
+        assert_eq!(
+            ComparableComplex(Complex::from_string_base(16, &s).unwrap()),
+            ComparableComplex(z.clone())
+        );
+        let s = format!("{:x}", ComparableComplexRef(&z));
+        assert_eq!(
+            ComparableComplex(Complex::from_string_base(16, &s).unwrap()),
+            ComparableComplex(z.clone())
+        );
+
+        // A real part on its own parses as it does for Float.
+        let s = format!("{:#x}", ComparableFloatRef(z.real_ref()));
+        let w = Complex::from_string_base(16, &s).unwrap();
+        assert_eq!(
+            ComparableFloatRef(w.real_ref()),
+            ComparableFloatRef(z.real_ref())
+        );
+        assert_eq!(
+            ComparableFloatRef(w.imag_ref()),
+            ComparableFloatRef(&Float::ZERO)
+        );
+    });
+}
@@ -0,0 +1,148 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ToStringBase;
+use malachite_base::strings::{ToDebugString, ToLowerHexString, ToUpperHexString};
+use malachite_complex::test_util::common::parse_hex_string;
+use malachite_complex::test_util::generators::complex_gen;
+use malachite_complex::{ComparableComplex, ComparableComplexRef, Complex};
+use malachite_float::ComparableFloatRef;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_to_string() {
+    let test = |s_hex, out: &str, out_comparable: &str, out_lower: &str, out_upper: &str| {
+        let z = parse_hex_string(s_hex);
+        assert_eq!(z.to_string(), out);
+        assert_eq!(z.to_debug_string(), out);
+        assert_eq!(z.to_string_base(10), out);
+        assert_eq!(ComparableComplexRef(&z).to_string(), out_comparable);
+        assert_eq!(ComparableComplex(z.clone()).to_string(), out_comparable);
+        assert_eq!(ComparableComplexRef(&z).to_debug_string(), out_comparable);
+        assert_eq!(z.to_lower_hex_string(), out_lower);
+        assert_eq!(z.to_string_base(16), out_lower);
+        assert_eq!(z.to_upper_hex_string(), out_upper);
+        assert_eq!(z.to_string_base_upper(16), out_upper);
+        assert_eq!(format!("{:#x}", ComparableComplexRef(&z)), s_hex);
+        assert_eq!(format!("{:#x}", ComparableComplex(z)), s_hex);
+    };
+    test(
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+        "(NaN NaN)",
+    );
+    test(
+        "(Infinity -Infinity)",
+        "(Infinity -Infinity)",
+        "(Infinity -Infinity)",
+        "(Infinity -Infinity)",
+        "(Infinity -Infinity)",
+    );
+    test(
+        "(0x0.0 -0x0.0)",
+        "(0.0 -0.0)",
+        "(0.0 -0.0)",
+        "(0.0 -0.0)",
+        "(0.0 -0.0)",
+    );
+    test(
+        "(0x1.0#1 -0x0.8#1)",
+        "(1.0 -0.50)",
+        "(1.0#1 -0.50#1)",
+        "(1.0 -0.8)",
+        "(1.0 -0.8)",
+    );
+    test(
+        "(0xff.0#8 -0x1.8#2)",
+        "(255.0 -1.5)",
+        "(255.0#8 -1.5#2)",
+        "(ff.0 -1.8)",
+        "(FF.0 -1.8)",
+    );
+    test(
+        "(0x3.243f6a8885a30#53 NaN)",
+        "(3.1415926535897931 NaN)",
+        "(3.1415926535897931#53 NaN)",
+        "(3.243f6a8885a30 NaN)",
+        "(3.243F6A8885A30 NaN)",
+    );
+}
+
+#[test]
+fn test_to_string_base() {
+    let test = |s_hex, base: u8, out: &str, out_upper: &str| {
+        let z = parse_hex_string(s_hex);
+        assert_eq!(z.to_string_base(base), out);
+        assert_eq!(z.to_string_base_upper(base), out_upper);
+    };
+    test(
+        "(0xff.0#8 -0x1.8#2)",
+        2,
+        "(11111111.0 -1.1)",
+        "(11111111.0 -1.1)",
+    );
+    test("(0xff.0#8 -0x1.8#2)", 8, "(377.0 -1.4)", "(377.0 -1.4)");
+    test("(0xff.0#8 -0x1.8#2)", 36, "(73.0 -1.i)", "(73.0 -1.I)");
+    test("(NaN -0x0.0)", 3, "(NaN -0.0)", "(NaN -0.0)");
+}
+
+#[test]
+fn to_string_base_fail() {
+    assert_panic!(Complex::ONE.to_string_base(1));
+    assert_panic!(Complex::ONE.to_string_base(37));
+    assert_panic!(Complex::ONE.to_string_base_upper(1));
+    assert_panic!(Complex::ONE.to_string_base_upper(37));
+}
+
+#[test]
+fn to_string_properties() {
+    complex_gen().test_properties(|z| {
+        let s = z.to_string();
+        assert_eq!(z.to_debug_string(), s);
+        assert_eq!(z.to_string_base(10), s);
+        assert!(s.starts_with('('));
+        assert!(s.ends_with(')'));
+        assert_eq!(s, format!("({} {})", z.real_ref(), z.imag_ref()));
+        assert_eq!(
+            ComparableComplexRef(&z).to_string(),
+            format!(
+                "({} {})",
+                ComparableFloatRef(z.real_ref()),
+                ComparableFloatRef(z.imag_ref())
+            )
+        );
+        assert_eq!(
+            format!("{:#x}", ComparableComplexRef(&z)),
+            format!(
+                "({:#x} {:#x})",
+                ComparableFloatRef(z.real_ref()),
+                ComparableFloatRef(z.imag_ref())
+            )
+        );
+        for base in 2..=36 {
+            let s = z.to_string_base(base);
+            assert_eq!(
+                s,
+                format!(
+                    "({} {})",
+                    z.real_ref().to_string_base(base),
+                    z.imag_ref().to_string_base(base)
+                )
+            );
+            assert_eq!(
+                z.to_string_base_upper(base).to_lowercase(),
+                s.to_lowercase()
+            );
+        }
+        assert_eq!(z.to_lower_hex_string(), z.to_string_base(16));
+        assert_eq!(z.to_upper_hex_string(), z.to_string_base_upper(16));
+    });
+}
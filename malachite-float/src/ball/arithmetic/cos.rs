@@ -0,0 +1,201 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::arithmetic::trig_rad;
+use crate::ball::{Ball, from_mid_and_rad};
+use malachite_base::num::arithmetic::traits::{Cos, CosAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+
+fn cos_helper(x: &Ball, prec: u64) -> Ball {
+    let (mid, o) = x.mid.cos_prec_ref(prec);
+    from_mid_and_rad(mid, o, trig_rad(x))
+}
+
+impl Ball {
+    /// Takes the cosine of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by value.
+    ///
+    /// The result contains the cosine of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\cos m) \pm \Delta_{30}(\min(r, 2) + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// The radius follows from $|\cos x - \cos y| \leq \min(|x - y|, 2)$. In particular, the cosine
+    /// of an indeterminate [`Ball`] is $1 \pm 2$.
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.clone().cos_prec(5).to_string(), "[0.938 +/- 0.031250476837]");
+    /// assert_eq!(x.cos_prec(20).to_string(), "[0.94495678 +/- 1.4305114746e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn cos_prec(self, prec: u64) -> Self {
+        cos_helper(&self, prec)
+    }
+
+    /// Takes the cosine of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by reference.
+    ///
+    /// The result contains the cosine of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\cos m) \pm \Delta_{30}(\min(r, 2) + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// The radius follows from $|\cos x - \cos y| \leq \min(|x - y|, 2)$. In particular, the cosine
+    /// of an indeterminate [`Ball`] is $1 \pm 2$.
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.cos_prec_ref(5).to_string(), "[0.938 +/- 0.031250476837]");
+    /// assert_eq!(x.cos_prec_ref(20).to_string(), "[0.94495678 +/- 1.4305114746e-6]");
+    /// ```
+    #[inline]
+    pub fn cos_prec_ref(&self, prec: u64) -> Self {
+        cos_helper(self, prec)
+    }
+}
+
+impl Cos for Ball {
+    type Output = Self;
+
+    /// Takes the cosine of a [`Ball`], computing the midpoint of the result with the precision of
+    /// the input's midpoint. The [`Ball`] is taken by value.
+    ///
+    /// See [`Ball::cos_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.cos().to_string(), "[0.94495678 +/- 1.4305114746e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn cos(self) -> Self {
+        let prec = self.significant_bits();
+        cos_helper(&self, prec)
+    }
+}
+
+impl Cos for &Ball {
+    type Output = Ball;
+
+    /// Takes the cosine of a [`Ball`], computing the midpoint of the result with the precision of
+    /// the input's midpoint. The [`Ball`] is taken by reference.
+    ///
+    /// See [`Ball::cos_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!((&x).cos().to_string(), "[0.94495678 +/- 1.4305114746e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn cos(self) -> Ball {
+        let prec = self.significant_bits();
+        cos_helper(self, prec)
+    }
+}
+
+impl CosAssign for Ball {
+    /// Takes the cosine of a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the input's midpoint.
+    ///
+    /// See [`Ball::cos_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CosAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// x.cos_assign();
+    /// assert_eq!(x.to_string(), "[0.94495678 +/- 1.4305114746e-6]");
+    /// ```
+    #[inline]
+    fn cos_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = cos_helper(self, prec);
+    }
+}
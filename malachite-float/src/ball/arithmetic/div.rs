@@ -0,0 +1,333 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::{
+    Ball, RADIUS_PREC, abs_lower, abs_upper, add_upper, div_upper, from_mid_and_rad, mul_upper,
+};
+use core::cmp::max;
+use core::ops::{Div, DivAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn div_helper(x: &Ball, y: &Ball, prec: u64) -> Ball {
+    let y_mid_lower = abs_lower(&y.mid);
+    if y_mid_lower <= y.rad {
+        return Ball::INDETERMINATE;
+    }
+    // If x = m + a and y = n + b, then |x/y - m/n| = |na - mb| / |yn|, and |y| >= |n| - s > 0
+    let denominator = y_mid_lower
+        .mul_prec_round_ref_ref(
+            &y_mid_lower
+                .sub_prec_round_ref_ref(&y.rad, RADIUS_PREC, Floor)
+                .0,
+            RADIUS_PREC,
+            Floor,
+        )
+        .0;
+    let numerator = add_upper(
+        &mul_upper(&abs_upper(&x.mid), &y.rad),
+        &mul_upper(&abs_upper(&y.mid), &x.rad),
+    );
+    let (mid, o) = x.mid.div_prec_ref_ref(&y.mid, prec);
+    from_mid_and_rad(mid, o, div_upper(&numerator, &denominator))
+}
+
+impl Ball {
+    /// Divides two [`Ball`]s, computing the midpoint of the result with the specified precision.
+    /// Both [`Ball`]s are taken by value.
+    ///
+    /// The result contains $x / y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \operatorname{round}_p\left(\frac{m}{n}\right)
+    ///     \pm \Delta_{30}\left(\frac{|m|s + |n|r}{|n|(|n| - s)} + \epsilon\right),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, the result is [`Ball::INDETERMINATE`]. If the
+    /// divisor contains zero, that is, if $|n| \leq s$, the quotient is unbounded and the result is
+    /// also [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!(x.clone().div_prec(y.clone(), 5).to_string(), "[1.19 +/- 0.062503616093]");
+    /// assert_eq!(x.div_prec(y, 20).to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn div_prec(self, other: Self, prec: u64) -> Self {
+        div_helper(&self, &other, prec)
+    }
+
+    /// Divides two [`Ball`]s, computing the midpoint of the result with the specified precision.
+    /// Both [`Ball`]s are taken by reference.
+    ///
+    /// The result contains $x / y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \operatorname{round}_p\left(\frac{m}{n}\right)
+    ///     \pm \Delta_{30}\left(\frac{|m|s + |n|r}{|n|(|n| - s)} + \epsilon\right),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, the result is [`Ball::INDETERMINATE`]. If the
+    /// divisor contains zero, that is, if $|n| \leq s$, the quotient is unbounded and the result is
+    /// also [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!(x.div_prec_ref_ref(&y, 5).to_string(), "[1.19 +/- 0.062503616093]");
+    /// assert_eq!(x.div_prec_ref_ref(&y, 20).to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        div_helper(self, other, prec)
+    }
+}
+
+impl Div<Self> for Ball {
+    type Output = Self;
+
+    /// Divides two [`Ball`]s, computing the midpoint of the result with the precision of the inputs
+    /// (the larger precision of their midpoints). Both [`Ball`]s are taken by value.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((x / y).to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(&self, &other, prec)
+    }
+}
+
+impl Div<&Self> for Ball {
+    type Output = Self;
+
+    /// Divides two [`Ball`]s, computing the midpoint of the result with the precision of the inputs
+    /// (the larger precision of their midpoints). The first [`Ball`] is taken by value and the
+    /// second by reference.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((x / &y).to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(&self, other, prec)
+    }
+}
+
+impl Div<Ball> for &Ball {
+    type Output = Ball;
+
+    /// Divides two [`Ball`]s, computing the midpoint of the result with the precision of the inputs
+    /// (the larger precision of their midpoints). The first [`Ball`] is taken by reference and the
+    /// second by value.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((&x / y).to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(self, &other, prec)
+    }
+}
+
+impl Div<&Ball> for &Ball {
+    type Output = Ball;
+
+    /// Divides two [`Ball`]s, computing the midpoint of the result with the precision of the inputs
+    /// (the larger precision of their midpoints). Both [`Ball`]s are taken by reference.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((&x / &y).to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: &Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(self, other, prec)
+    }
+}
+
+impl DivAssign<Self> for Ball {
+    /// Divides a [`Ball`] by a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the inputs (the larger precision of their midpoints). The [`Ball`] on the
+    /// right-hand side is taken by value.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// let mut z = x.clone();
+    /// z /= y;
+    /// assert_eq!(z.to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = div_helper(self, &other, prec);
+    }
+}
+
+impl DivAssign<&Self> for Ball {
+    /// Divides a [`Ball`] by a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the inputs (the larger precision of their midpoints). The [`Ball`] on the
+    /// right-hand side is taken by reference.
+    ///
+    /// See [`Ball::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// let mut z = x.clone();
+    /// z /= &y;
+    /// assert_eq!(z.to_string(), "[1.1666679 +/- 5.5233733676e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = div_helper(self, other, prec);
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::{Ball, RADIUS_PREC, from_mid_and_rad, mul_upper};
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn exp_helper(x: &Ball, prec: u64) -> Ball {
+    if !x.is_bounded() {
+        return Ball::INDETERMINATE;
+    }
+    // If x = m + a, then |e^x - e^m| = e^m |e^a - 1| <= e^m (e^r - 1)
+    let rad = mul_upper(
+        &x.mid.exp_prec_round_ref(RADIUS_PREC, Ceiling).0,
+        &x.rad.exp_x_minus_1_prec_round_ref(RADIUS_PREC, Ceiling).0,
+    );
+    let (mid, o) = x.mid.exp_prec_ref(prec);
+    from_mid_and_rad(mid, o, rad)
+}
+
+impl Ball {
+    /// Takes the exponential of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by value.
+    ///
+    /// The result contains the exponential of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(e^m) \pm \Delta_{30}(e^m(e^r - 1) + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, or the [`Ball`] is indeterminate, the result is
+    /// [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.clone().exp_prec(5).to_string(), "[1.38 +/- 0.062500665546]");
+    /// assert_eq!(x.exp_prec(20).to_string(), "[1.3956127 +/- 2.5728287625e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn exp_prec(self, prec: u64) -> Self {
+        exp_helper(&self, prec)
+    }
+
+    /// Takes the exponential of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by reference.
+    ///
+    /// The result contains the exponential of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(e^m) \pm \Delta_{30}(e^m(e^r - 1) + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, or the [`Ball`] is indeterminate, the result is
+    /// [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.exp_prec_ref(5).to_string(), "[1.38 +/- 0.062500665546]");
+    /// assert_eq!(x.exp_prec_ref(20).to_string(), "[1.3956127 +/- 2.5728287625e-6]");
+    /// ```
+    #[inline]
+    pub fn exp_prec_ref(&self, prec: u64) -> Self {
+        exp_helper(self, prec)
+    }
+}
+
+impl Exp for Ball {
+    type Output = Self;
+
+    /// Takes the exponential of a [`Ball`], computing the midpoint of the result with the precision
+    /// of the input's midpoint. The [`Ball`] is taken by value.
+    ///
+    /// See [`Ball::exp_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.exp().to_string(), "[1.3956127 +/- 2.5728287625e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn exp(self) -> Self {
+        let prec = self.significant_bits();
+        exp_helper(&self, prec)
+    }
+}
+
+impl Exp for &Ball {
+    type Output = Ball;
+
+    /// Takes the exponential of a [`Ball`], computing the midpoint of the result with the precision
+    /// of the input's midpoint. The [`Ball`] is taken by reference.
+    ///
+    /// See [`Ball::exp_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!((&x).exp().to_string(), "[1.3956127 +/- 2.5728287625e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn exp(self) -> Ball {
+        let prec = self.significant_bits();
+        exp_helper(self, prec)
+    }
+}
+
+impl ExpAssign for Ball {
+    /// Takes the exponential of a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the input's midpoint.
+    ///
+    /// See [`Ball::exp_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// x.exp_assign();
+    /// assert_eq!(x.to_string(), "[1.3956127 +/- 2.5728287625e-6]");
+    /// ```
+    #[inline]
+    fn exp_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = exp_helper(self, prec);
+    }
+}
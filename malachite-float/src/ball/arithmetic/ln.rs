@@ -0,0 +1,208 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::{Ball, RADIUS_PREC, div_upper, from_mid_and_rad};
+use malachite_base::num::arithmetic::traits::{Ln, LnAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn ln_helper(x: &Ball, prec: u64) -> Ball {
+    if x.mid <= x.rad {
+        return Ball::INDETERMINATE;
+    }
+    // If x = m + a, then |ln x - ln m| <= -ln(1 - r/m) <= r / (m - r)
+    let rad = div_upper(
+        &x.rad,
+        &x.mid.sub_prec_round_ref_ref(&x.rad, RADIUS_PREC, Floor).0,
+    );
+    let (mid, o) = x.mid.ln_prec_ref(prec);
+    from_mid_and_rad(mid, o, rad)
+}
+
+impl Ball {
+    /// Takes the natural logarithm of a [`Ball`], computing the midpoint of the result with the
+    /// specified precision. The [`Ball`] is taken by value.
+    ///
+    /// The result contains the natural logarithm of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\ln m)
+    ///     \pm \Delta_{30}\left(\frac{r}{m - r} + \epsilon\right),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the [`Ball`] contains a number that is not positive, that is, if $m \leq r$, the result
+    /// is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n (\log n)^2 \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!(x.clone().ln_prec(5).to_string(), "[0.844 +/- 0.031251634879]");
+    /// assert_eq!(x.ln_prec(20).to_string(), "[0.84729767 +/- 2.5885481385e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn ln_prec(self, prec: u64) -> Self {
+        ln_helper(&self, prec)
+    }
+
+    /// Takes the natural logarithm of a [`Ball`], computing the midpoint of the result with the
+    /// specified precision. The [`Ball`] is taken by reference.
+    ///
+    /// The result contains the natural logarithm of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\ln m)
+    ///     \pm \Delta_{30}\left(\frac{r}{m - r} + \epsilon\right),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the [`Ball`] contains a number that is not positive, that is, if $m \leq r$, the result
+    /// is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n (\log n)^2 \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!(x.ln_prec_ref(5).to_string(), "[0.844 +/- 0.031251634879]");
+    /// assert_eq!(x.ln_prec_ref(20).to_string(), "[0.84729767 +/- 2.5885481385e-6]");
+    /// ```
+    #[inline]
+    pub fn ln_prec_ref(&self, prec: u64) -> Self {
+        ln_helper(self, prec)
+    }
+}
+
+impl Ln for Ball {
+    type Output = Self;
+
+    /// Takes the natural logarithm of a [`Ball`], computing the midpoint of the result with the
+    /// precision of the input's midpoint. The [`Ball`] is taken by value.
+    ///
+    /// See [`Ball::ln_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!(x.ln().to_string(), "[0.84729767 +/- 2.5885481385e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn ln(self) -> Self {
+        let prec = self.significant_bits();
+        ln_helper(&self, prec)
+    }
+}
+
+impl Ln for &Ball {
+    type Output = Ball;
+
+    /// Takes the natural logarithm of a [`Ball`], computing the midpoint of the result with the
+    /// precision of the input's midpoint. The [`Ball`] is taken by reference.
+    ///
+    /// See [`Ball::ln_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!((&x).ln().to_string(), "[0.84729767 +/- 2.5885481385e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn ln(self) -> Ball {
+        let prec = self.significant_bits();
+        ln_helper(self, prec)
+    }
+}
+
+impl LnAssign for Ball {
+    /// Takes the natural logarithm of a [`Ball`] in place, computing the midpoint of the result
+    /// with the precision of the input's midpoint.
+    ///
+    /// See [`Ball::ln_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LnAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// x.ln_assign();
+    /// assert_eq!(x.to_string(), "[0.84729767 +/- 2.5885481385e-6]");
+    /// ```
+    #[inline]
+    fn ln_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = ln_helper(self, prec);
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use crate::Float;
+use crate::ball::Ball;
+use malachite_base::num::basic::traits::Two;
+
+// A bound for how far a radius of r can move the sine or cosine: min(r, 2).
+pub(crate) fn trig_rad(x: &Ball) -> Float {
+    if x.rad > 2u32 {
+        Float::TWO
+    } else {
+        x.rad.clone()
+    }
+}
+
+/// Addition of [`Ball`](super::Ball)s.
+pub mod add;
+/// Cosines of [`Ball`](super::Ball)s.
+pub mod cos;
+/// Division of [`Ball`](super::Ball)s.
+pub mod div;
+/// Exponentials of [`Ball`](super::Ball)s.
+pub mod exp;
+/// Natural logarithms of [`Ball`](super::Ball)s.
+pub mod ln;
+/// Multiplication of [`Ball`](super::Ball)s.
+pub mod mul;
+/// Negation of [`Ball`](super::Ball)s.
+pub mod neg;
+/// Powers of [`Ball`](super::Ball)s.
+pub mod pow;
+/// Sines of [`Ball`](super::Ball)s.
+pub mod sin;
+/// Square roots of [`Ball`](super::Ball)s.
+pub mod sqrt;
+/// Subtraction of [`Ball`](super::Ball)s.
+pub mod sub;
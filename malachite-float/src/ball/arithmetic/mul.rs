@@ -0,0 +1,318 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::{Ball, abs_upper, add_upper, from_mid_and_rad, mul_upper};
+use core::cmp::max;
+use core::ops::{Mul, MulAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+
+fn mul_helper(x: &Ball, y: &Ball, prec: u64) -> Ball {
+    // If x = m + a and y = n + b, then |xy - mn| = |mb + na + ab| <= |m|s + |n|r + rs
+    let rad = add_upper(
+        &add_upper(
+            &mul_upper(&abs_upper(&x.mid), &y.rad),
+            &mul_upper(&abs_upper(&y.mid), &x.rad),
+        ),
+        &mul_upper(&x.rad, &y.rad),
+    );
+    let (mid, o) = x.mid.mul_prec_ref_ref(&y.mid, prec);
+    from_mid_and_rad(mid, o, rad)
+}
+
+impl Ball {
+    /// Multiplies two [`Ball`]s, computing the midpoint of the result with the specified precision.
+    /// Both [`Ball`]s are taken by value.
+    ///
+    /// The result contains $x \cdot y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \operatorname{round}_p(mn)
+    ///     \pm \Delta_{30}(|m|s + |n|r + rs + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, the result is [`Ball::INDETERMINATE`]. A product of
+    /// an exact zero and an indeterminate [`Ball`] is an exact zero.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!(x.clone().mul_prec(y.clone(), 5).to_string(), "[0.0938 +/- 0.0039065451856]");
+    /// assert_eq!(x.mul_prec(y, 20).to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn mul_prec(self, other: Self, prec: u64) -> Self {
+        mul_helper(&self, &other, prec)
+    }
+
+    /// Multiplies two [`Ball`]s, computing the midpoint of the result with the specified precision.
+    /// Both [`Ball`]s are taken by reference.
+    ///
+    /// The result contains $x \cdot y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \operatorname{round}_p(mn)
+    ///     \pm \Delta_{30}(|m|s + |n|r + rs + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, the result is [`Ball::INDETERMINATE`]. A product of
+    /// an exact zero and an indeterminate [`Ball`] is an exact zero.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!(x.mul_prec_ref_ref(&y, 5).to_string(), "[0.0938 +/- 0.0039065451856]");
+    /// assert_eq!(x.mul_prec_ref_ref(&y, 20).to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        mul_helper(self, other, prec)
+    }
+}
+
+impl Mul<Self> for Ball {
+    type Output = Self;
+
+    /// Multiplies two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). Both [`Ball`]s are taken by value.
+    ///
+    /// See [`Ball::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((x * y).to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(&self, &other, prec)
+    }
+}
+
+impl Mul<&Self> for Ball {
+    type Output = Self;
+
+    /// Multiplies two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). The first [`Ball`] is taken by value and
+    /// the second by reference.
+    ///
+    /// See [`Ball::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((x * &y).to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(&self, other, prec)
+    }
+}
+
+impl Mul<Ball> for &Ball {
+    type Output = Ball;
+
+    /// Multiplies two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). The first [`Ball`] is taken by reference
+    /// and the second by value.
+    ///
+    /// See [`Ball::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((&x * y).to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(self, &other, prec)
+    }
+}
+
+impl Mul<&Ball> for &Ball {
+    type Output = Ball;
+
+    /// Multiplies two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). Both [`Ball`]s are taken by reference.
+    ///
+    /// See [`Ball::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((&x * &y).to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: &Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(self, other, prec)
+    }
+}
+
+impl MulAssign<Self> for Ball {
+    /// Multiplies a [`Ball`] by a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the inputs (the larger precision of their midpoints). The [`Ball`] on the
+    /// right-hand side is taken by value.
+    ///
+    /// See [`Ball::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// let mut z = x.clone();
+    /// z *= y;
+    /// assert_eq!(z.to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = mul_helper(self, &other, prec);
+    }
+}
+
+impl MulAssign<&Self> for Ball {
+    /// Multiplies a [`Ball`] by a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the inputs (the larger precision of their midpoints). The [`Ball`] on the
+    /// right-hand side is taken by reference.
+    ///
+    /// See [`Ball::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// let mut z = x.clone();
+    /// z *= &y;
+    /// assert_eq!(z.to_string(), "[0.095238090 +/- 4.1439443521e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = mul_helper(self, other, prec);
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::Ball;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl Neg for Ball {
+    type Output = Self;
+
+    /// Negates a [`Ball`], taking it by value.
+    ///
+    /// Negation is exact, so the radius is unchanged. The negative of [`Ball::INDETERMINATE`] is
+    /// [`Ball::INDETERMINATE`].
+    ///
+    /// $$
+    /// f(m \pm r) = -m \pm r.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((-Ball::ONE).to_string(), "[-1.0 +/- 0.0]");
+    /// assert_eq!(
+    ///     (-Ball::new(Float::from(1.5), Float::from(0.25))).to_string(),
+    ///     "[-1.5 +/- 0.25]"
+    /// );
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &Ball {
+    type Output = Ball;
+
+    /// Negates a [`Ball`], taking it by reference.
+    ///
+    /// Negation is exact, so the radius is unchanged. The negative of [`Ball::INDETERMINATE`] is
+    /// [`Ball::INDETERMINATE`].
+    ///
+    /// $$
+    /// f(m \pm r) = -m \pm r.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((-&Ball::ONE).to_string(), "[-1.0 +/- 0.0]");
+    /// assert_eq!(
+    ///     (-&Ball::new(Float::from(1.5), Float::from(0.25))).to_string(),
+    ///     "[-1.5 +/- 0.25]"
+    /// );
+    /// ```
+    fn neg(self) -> Ball {
+        if !self.is_bounded() {
+            return Ball::INDETERMINATE;
+        }
+        Ball {
+            mid: -&self.mid,
+            rad: self.rad.clone(),
+        }
+    }
+}
+
+impl NegAssign for Ball {
+    /// Negates a [`Ball`] in place.
+    ///
+    /// Negation is exact, so the radius is unchanged. The negative of [`Ball::INDETERMINATE`] is
+    /// [`Ball::INDETERMINATE`].
+    ///
+    /// $$
+    /// m \pm r \gets -m \pm r.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "[-1.5 +/- 0.25]");
+    /// ```
+    #[inline]
+    fn neg_assign(&mut self) {
+        if self.is_bounded() {
+            self.mid.neg_assign();
+        }
+    }
+}
@@ -0,0 +1,538 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::{Ball, RADIUS_PREC, abs_upper, add_upper, from_mid_and_rad, mul_upper};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Pow, PowAssign};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn pow_helper(x: &Ball, y: &Ball, prec: u64) -> Ball {
+    if x.is_exact() && y.is_exact() {
+        let (mid, o) = x.mid.pow_prec_ref_ref(&y.mid, prec);
+        return from_mid_and_rad(mid, o, Float::ZERO);
+    }
+    y.mul_prec_ref_ref(&x.ln_prec_ref(prec), prec)
+        .exp_prec(prec)
+}
+
+fn pow_u_helper(x: &Ball, n: u64, prec: u64) -> Ball {
+    if n == 0 {
+        return Ball {
+            mid: Float::one_prec(prec),
+            rad: Float::ZERO,
+        };
+    }
+    // If x = m + a, then |x^n - m^n| <= (|m| + r)^n - |m|^n <= nr(|m| + r)^(n - 1)
+    let rad = mul_upper(
+        &mul_upper(
+            &Float::from_unsigned_prec_round(n, RADIUS_PREC, Ceiling).0,
+            &x.rad,
+        ),
+        &add_upper(&abs_upper(&x.mid), &x.rad)
+            .pow_u_prec_round(n - 1, RADIUS_PREC, Ceiling)
+            .0,
+    );
+    let (mid, o) = x.mid.pow_u_prec_ref(n, prec);
+    from_mid_and_rad(mid, o, rad)
+}
+
+impl Ball {
+    /// Raises a [`Ball`] to the power of another [`Ball`], computing the midpoint of the result
+    /// with the specified precision. Both [`Ball`]s are taken by value.
+    ///
+    /// The result contains $x^y$ for every $x$ in `self` and $y$ in `other`. If both [`Ball`]s are
+    /// exact, the midpoint of the result is the correctly rounded power of the midpoints.
+    /// Otherwise, the result is computed as $e^{y \ln x}$, using [`Ball::ln_prec`],
+    /// [`Ball::mul_prec`], and [`Ball::exp_prec`] with precision $p$:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \\begin{cases}
+    ///     \operatorname{round}_p(m^n) \pm \epsilon & \text{if} \\quad r = s = 0, \\\\
+    ///     e^{(n \pm s) \ln (m \pm r)} & \\text{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the power of exact [`Ball`]s is not a finite real number, as with $0^{-1}$ or
+    /// $(-1)^{1/2}$, or if a [`Ball`] that is not exact has a base that contains a number that is
+    /// not positive, the result is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using [`Ball::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.clone().pow_prec(y.clone(), 5).to_string(), "[1.31 +/- 0.097454056842]");
+    /// assert_eq!(x.pow_prec(y, 20).to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn pow_prec(self, other: Self, prec: u64) -> Self {
+        pow_helper(&self, &other, prec)
+    }
+
+    /// Raises a [`Ball`] to the power of another [`Ball`], computing the midpoint of the result
+    /// with the specified precision. Both [`Ball`]s are taken by reference.
+    ///
+    /// The result contains $x^y$ for every $x$ in `self` and $y$ in `other`. If both [`Ball`]s are
+    /// exact, the midpoint of the result is the correctly rounded power of the midpoints.
+    /// Otherwise, the result is computed as $e^{y \ln x}$, using [`Ball::ln_prec`],
+    /// [`Ball::mul_prec`], and [`Ball::exp_prec`] with precision $p$:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \\begin{cases}
+    ///     \operatorname{round}_p(m^n) \pm \epsilon & \text{if} \\quad r = s = 0, \\\\
+    ///     e^{(n \pm s) \ln (m \pm r)} & \\text{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the power of exact [`Ball`]s is not a finite real number, as with $0^{-1}$ or
+    /// $(-1)^{1/2}$, or if a [`Ball`] that is not exact has a base that contains a number that is
+    /// not positive, the result is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using [`Ball::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.pow_prec_ref_ref(&y, 5).to_string(), "[1.31 +/- 0.097454056842]");
+    /// assert_eq!(x.pow_prec_ref_ref(&y, 20).to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[inline]
+    pub fn pow_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        pow_helper(self, other, prec)
+    }
+
+    /// Raises a [`Ball`] to the power of a [`u64`], computing the midpoint of the result with the
+    /// specified precision. The [`Ball`] is taken by value.
+    ///
+    /// The result contains $x^n$ for every $x$ in the [`Ball`]:
+    /// $$
+    /// f(m \pm r, n, p) = \operatorname{round}_p(m^n)
+    ///     \pm \Delta_{30}(nr(|m| + r)^{n - 1} + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If $n$ is zero, the result is exactly 1, even if the [`Ball`] is indeterminate. If the
+    /// midpoint of the result overflows, the result is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(prec, self.significant_bits())`,
+    /// and $m$ is `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(-4, 3), 20);
+    /// assert_eq!(x.clone().pow_u_prec(2, 10).to_string(), "[1.7773 +/- 0.0019582112727]");
+    /// assert_eq!(x.pow_u_prec(3, 10).to_string(), "[-2.3711 +/- 0.0039164225673]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn pow_u_prec(self, n: u64, prec: u64) -> Self {
+        pow_u_helper(&self, n, prec)
+    }
+
+    /// Raises a [`Ball`] to the power of a [`u64`], computing the midpoint of the result with the
+    /// specified precision. The [`Ball`] is taken by reference.
+    ///
+    /// The result contains $x^n$ for every $x$ in the [`Ball`]:
+    /// $$
+    /// f(m \pm r, n, p) = \operatorname{round}_p(m^n)
+    ///     \pm \Delta_{30}(nr(|m| + r)^{n - 1} + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If $n$ is zero, the result is exactly 1, even if the [`Ball`] is indeterminate. If the
+    /// midpoint of the result overflows, the result is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(prec, self.significant_bits())`,
+    /// and $m$ is `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(-4, 3), 20);
+    /// assert_eq!(x.pow_u_prec_ref(2, 10).to_string(), "[1.7773 +/- 0.0019582112727]");
+    /// assert_eq!(x.pow_u_prec_ref(3, 10).to_string(), "[-2.3711 +/- 0.0039164225673]");
+    /// ```
+    #[inline]
+    pub fn pow_u_prec_ref(&self, n: u64, prec: u64) -> Self {
+        pow_u_helper(self, n, prec)
+    }
+}
+
+impl Pow<Self> for Ball {
+    type Output = Self;
+
+    /// Raises a [`Ball`] to the power of another [`Ball`], computing the midpoint of the result
+    /// with the precision of the inputs (the larger precision of their midpoints). Both [`Ball`]s
+    /// are taken by value.
+    ///
+    /// See [`Ball::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.pow(y).to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(&self, &other, prec)
+    }
+}
+
+impl Pow<&Self> for Ball {
+    type Output = Self;
+
+    /// Raises a [`Ball`] to the power of another [`Ball`], computing the midpoint of the result
+    /// with the precision of the inputs (the larger precision of their midpoints). The first
+    /// [`Ball`] is taken by value and the second by reference.
+    ///
+    /// See [`Ball::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.pow(&y).to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(&self, other, prec)
+    }
+}
+
+impl Pow<Ball> for &Ball {
+    type Output = Ball;
+
+    /// Raises a [`Ball`] to the power of another [`Ball`], computing the midpoint of the result
+    /// with the precision of the inputs (the larger precision of their midpoints). The first
+    /// [`Ball`] is taken by reference and the second by value.
+    ///
+    /// See [`Ball::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!((&x).pow(y).to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow(self, other: Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(self, &other, prec)
+    }
+}
+
+impl Pow<&Ball> for &Ball {
+    type Output = Ball;
+
+    /// Raises a [`Ball`] to the power of another [`Ball`], computing the midpoint of the result
+    /// with the precision of the inputs (the larger precision of their midpoints). Both [`Ball`]s
+    /// are taken by reference.
+    ///
+    /// See [`Ball::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!((&x).pow(&y).to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow(self, other: &Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(self, other, prec)
+    }
+}
+
+impl PowAssign<Self> for Ball {
+    /// Raises a [`Ball`] to the power of another [`Ball`] in place, computing the midpoint of the
+    /// result with the precision of the inputs (the larger precision of their midpoints). The
+    /// [`Ball`] on the right-hand side is taken by value.
+    ///
+    /// See [`Ball::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// x.pow_assign(y);
+    /// assert_eq!(x.to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = pow_helper(self, &other, prec);
+    }
+}
+
+impl PowAssign<&Self> for Ball {
+    /// Raises a [`Ball`] to the power of another [`Ball`] in place, computing the midpoint of the
+    /// result with the precision of the inputs (the larger precision of their midpoints). The
+    /// [`Ball`] on the right-hand side is taken by reference.
+    ///
+    /// See [`Ball::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// x.pow_assign(&y);
+    /// assert_eq!(x.to_string(), "[1.3263531 +/- 4.2201260442e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = pow_helper(self, other, prec);
+    }
+}
+
+impl Pow<u64> for Ball {
+    type Output = Self;
+
+    /// Raises a [`Ball`] to the power of a [`u64`], computing the midpoint of the result with the
+    /// precision of the input's midpoint. The [`Ball`] is taken by value.
+    ///
+    /// See [`Ball::pow_u_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(-4, 3), 20);
+    /// assert_eq!(x.pow(2u64).to_string(), "[1.7777786 +/- 6.9936213549e-6]");
+    /// ```
+    #[inline]
+    fn pow(self, n: u64) -> Self {
+        let prec = self.significant_bits();
+        pow_u_helper(&self, n, prec)
+    }
+}
+
+impl Pow<u64> for &Ball {
+    type Output = Ball;
+
+    /// Raises a [`Ball`] to the power of a [`u64`], computing the midpoint of the result with the
+    /// precision of the input's midpoint. The [`Ball`] is taken by reference.
+    ///
+    /// See [`Ball::pow_u_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(-4, 3), 20);
+    /// assert_eq!((&x).pow(2u64).to_string(), "[1.7777786 +/- 6.9936213549e-6]");
+    /// ```
+    #[inline]
+    fn pow(self, n: u64) -> Ball {
+        let prec = self.significant_bits();
+        pow_u_helper(self, n, prec)
+    }
+}
+
+impl PowAssign<u64> for Ball {
+    /// Raises a [`Ball`] to the power of a [`u64`] in place, computing the midpoint of the result
+    /// with the precision of the input's midpoint.
+    ///
+    /// See [`Ball::pow_u_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(-4, 3), 20);
+    /// x.pow_assign(2u64);
+    /// assert_eq!(x.to_string(), "[1.7777786 +/- 6.9936213549e-6]");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, n: u64) {
+        let prec = self.significant_bits();
+        *self = pow_u_helper(self, n, prec);
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::arithmetic::trig_rad;
+use crate::ball::{Ball, from_mid_and_rad};
+use malachite_base::num::arithmetic::traits::{Sin, SinAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+
+fn sin_helper(x: &Ball, prec: u64) -> Ball {
+    let (mid, o) = x.mid.sin_prec_ref(prec);
+    from_mid_and_rad(mid, o, trig_rad(x))
+}
+
+impl Ball {
+    /// Takes the sine of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by value.
+    ///
+    /// The result contains the sine of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\sin m) \pm \Delta_{30}(\min(r, 2) + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// The radius follows from $|\sin x - \sin y| \leq \min(|x - y|, 2)$. In particular, the sine
+    /// of an indeterminate [`Ball`] is $0 \pm 2$.
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.clone().sin_prec(5).to_string(), "[0.328 +/- 0.015625476837]");
+    /// assert_eq!(x.sin_prec(20).to_string(), "[0.32719469 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sin_prec(self, prec: u64) -> Self {
+        sin_helper(&self, prec)
+    }
+
+    /// Takes the sine of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by reference.
+    ///
+    /// The result contains the sine of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\sin m) \pm \Delta_{30}(\min(r, 2) + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// The radius follows from $|\sin x - \sin y| \leq \min(|x - y|, 2)$. In particular, the sine
+    /// of an indeterminate [`Ball`] is $0 \pm 2$.
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.sin_prec_ref(5).to_string(), "[0.328 +/- 0.015625476837]");
+    /// assert_eq!(x.sin_prec_ref(20).to_string(), "[0.32719469 +/- 9.5367431641e-7]");
+    /// ```
+    #[inline]
+    pub fn sin_prec_ref(&self, prec: u64) -> Self {
+        sin_helper(self, prec)
+    }
+}
+
+impl Sin for Ball {
+    type Output = Self;
+
+    /// Takes the sine of a [`Ball`], computing the midpoint of the result with the precision of the
+    /// input's midpoint. The [`Ball`] is taken by value.
+    ///
+    /// See [`Ball::sin_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sin;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!(x.sin().to_string(), "[0.32719469 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sin(self) -> Self {
+        let prec = self.significant_bits();
+        sin_helper(&self, prec)
+    }
+}
+
+impl Sin for &Ball {
+    type Output = Ball;
+
+    /// Takes the sine of a [`Ball`], computing the midpoint of the result with the precision of the
+    /// input's midpoint. The [`Ball`] is taken by reference.
+    ///
+    /// See [`Ball::sin_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sin;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// assert_eq!((&x).sin().to_string(), "[0.32719469 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sin(self) -> Ball {
+        let prec = self.significant_bits();
+        sin_helper(self, prec)
+    }
+}
+
+impl SinAssign for Ball {
+    /// Takes the sine of a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the input's midpoint.
+    ///
+    /// See [`Ball::sin_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of the midpoint if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// x.sin_assign();
+    /// assert_eq!(x.to_string(), "[0.32719469 +/- 9.5367431641e-7]");
+    /// ```
+    #[inline]
+    fn sin_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = sin_helper(self, prec);
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::{Ball, RADIUS_PREC, div_upper, from_mid_and_rad};
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn sqrt_helper(x: &Ball, prec: u64) -> Ball {
+    if x.mid < x.rad {
+        return Ball::INDETERMINATE;
+    }
+    // If x = m + a, then |sqrt(x) - sqrt(m)| = |a| / (sqrt(x) + sqrt(m))
+    let rad = if x.is_exact() {
+        Float::ZERO
+    } else {
+        let denominator = x
+            .mid
+            .sub_prec_round_ref_ref(&x.rad, RADIUS_PREC, Floor)
+            .0
+            .sqrt_prec_round(RADIUS_PREC, Floor)
+            .0
+            .add_prec_round(
+                x.mid.sqrt_prec_round_ref(RADIUS_PREC, Floor).0,
+                RADIUS_PREC,
+                Floor,
+            )
+            .0;
+        div_upper(&x.rad, &denominator)
+    };
+    let (mid, o) = x.mid.sqrt_prec_ref(prec);
+    from_mid_and_rad(mid, o, rad)
+}
+
+impl Ball {
+    /// Takes the square root of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by value.
+    ///
+    /// The result contains the square root of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\sqrt{m})
+    ///     \pm \Delta_{30}\left(\frac{r}{\sqrt{m - r} + \sqrt{m}} + \epsilon\right),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the [`Ball`] contains a negative number, that is, if $m < r$, the result is
+    /// [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!(x.clone().sqrt_prec(5).to_string(), "[1.50 +/- 0.062501248671]");
+    /// assert_eq!(x.sqrt_prec(20).to_string(), "[1.5275249 +/- 3.1560022684e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sqrt_prec(self, prec: u64) -> Self {
+        sqrt_helper(&self, prec)
+    }
+
+    /// Takes the square root of a [`Ball`], computing the midpoint of the result with the specified
+    /// precision. The [`Ball`] is taken by reference.
+    ///
+    /// The result contains the square root of every element of the [`Ball`]:
+    /// $$
+    /// f(m \pm r, p) = \operatorname{round}_p(\sqrt{m})
+    ///     \pm \Delta_{30}\left(\frac{r}{\sqrt{m - r} + \sqrt{m}} + \epsilon\right),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the [`Ball`] contains a negative number, that is, if $m < r$, the result is
+    /// [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the precision of the input's midpoint, consider
+    /// using [`Ball::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!(x.sqrt_prec_ref(5).to_string(), "[1.50 +/- 0.062501248671]");
+    /// assert_eq!(x.sqrt_prec_ref(20).to_string(), "[1.5275249 +/- 3.1560022684e-6]");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_ref(&self, prec: u64) -> Self {
+        sqrt_helper(self, prec)
+    }
+}
+
+impl Sqrt for Ball {
+    type Output = Self;
+
+    /// Takes the square root of a [`Ball`], computing the midpoint of the result with the precision
+    /// of the input's midpoint. The [`Ball`] is taken by value.
+    ///
+    /// See [`Ball::sqrt_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!(x.sqrt().to_string(), "[1.5275249 +/- 3.1560022684e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sqrt(self) -> Self {
+        let prec = self.significant_bits();
+        sqrt_helper(&self, prec)
+    }
+}
+
+impl Sqrt for &Ball {
+    type Output = Ball;
+
+    /// Takes the square root of a [`Ball`], computing the midpoint of the result with the precision
+    /// of the input's midpoint. The [`Ball`] is taken by reference.
+    ///
+    /// See [`Ball::sqrt_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// assert_eq!((&x).sqrt().to_string(), "[1.5275249 +/- 3.1560022684e-6]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sqrt(self) -> Ball {
+        let prec = self.significant_bits();
+        sqrt_helper(self, prec)
+    }
+}
+
+impl SqrtAssign for Ball {
+    /// Takes the square root of a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the input's midpoint.
+    ///
+    /// See [`Ball::sqrt_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SqrtAssign;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let mut x = Ball::from_rational_prec(Rational::from_signeds(7, 3), 20);
+    /// x.sqrt_assign();
+    /// assert_eq!(x.to_string(), "[1.5275249 +/- 3.1560022684e-6]");
+    /// ```
+    #[inline]
+    fn sqrt_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = sqrt_helper(self, prec);
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::{Ball, add_upper, from_mid_and_rad};
+use core::cmp::max;
+use core::ops::{Sub, SubAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+
+fn sub_helper(x: &Ball, y: &Ball, prec: u64) -> Ball {
+    let (mid, o) = x.mid.sub_prec_ref_ref(&y.mid, prec);
+    from_mid_and_rad(mid, o, add_upper(&x.rad, &y.rad))
+}
+
+impl Ball {
+    /// Subtracts two [`Ball`]s, computing the midpoint of the result with the specified precision.
+    /// Both [`Ball`]s are taken by value.
+    ///
+    /// The result contains $x - y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \operatorname{round}_p(m - n) \pm \Delta_{30}(r + s + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, the result is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!(x.clone().sub_prec(y.clone(), 5).to_string(), "[0.0469 +/- 0.0019540786743]");
+    /// assert_eq!(x.sub_prec(y, 20).to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sub_prec(self, other: Self, prec: u64) -> Self {
+        sub_helper(&self, &other, prec)
+    }
+
+    /// Subtracts two [`Ball`]s, computing the midpoint of the result with the specified precision.
+    /// Both [`Ball`]s are taken by reference.
+    ///
+    /// The result contains $x - y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f(m \pm r, n \pm s, p) = \operatorname{round}_p(m - n) \pm \Delta_{30}(r + s + \epsilon),
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`](crate::Float) of precision
+    /// $p$, $\Delta_{30}$ rounds up to precision 30, and $\epsilon$ is zero if the midpoint is
+    /// computed exactly and one ulp of the midpoint otherwise.
+    ///
+    /// If the midpoint of the result overflows, the result is [`Ball::INDETERMINATE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs'
+    /// midpoints, consider using `-` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!(x.sub_prec_ref_ref(&y, 5).to_string(), "[0.0469 +/- 0.0019540786743]");
+    /// assert_eq!(x.sub_prec_ref_ref(&y, 20).to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[inline]
+    pub fn sub_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        sub_helper(self, other, prec)
+    }
+}
+
+impl Sub<Self> for Ball {
+    type Output = Self;
+
+    /// Subtracts two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). Both [`Ball`]s are taken by value.
+    ///
+    /// See [`Ball::sub_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((x - y).to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        sub_helper(&self, &other, prec)
+    }
+}
+
+impl Sub<&Self> for Ball {
+    type Output = Self;
+
+    /// Subtracts two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). The first [`Ball`] is taken by value and
+    /// the second by reference.
+    ///
+    /// See [`Ball::sub_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((x - &y).to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        sub_helper(&self, other, prec)
+    }
+}
+
+impl Sub<Ball> for &Ball {
+    type Output = Ball;
+
+    /// Subtracts two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). The first [`Ball`] is taken by reference
+    /// and the second by value.
+    ///
+    /// See [`Ball::sub_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((&x - y).to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sub(self, other: Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        sub_helper(self, &other, prec)
+    }
+}
+
+impl Sub<&Ball> for &Ball {
+    type Output = Ball;
+
+    /// Subtracts two [`Ball`]s, computing the midpoint of the result with the precision of the
+    /// inputs (the larger precision of their midpoints). Both [`Ball`]s are taken by reference.
+    ///
+    /// See [`Ball::sub_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// assert_eq!((&x - &y).to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sub(self, other: &Ball) -> Ball {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        sub_helper(self, other, prec)
+    }
+}
+
+impl SubAssign<Self> for Ball {
+    /// Subtracts a [`Ball`] from a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the inputs (the larger precision of their midpoints). The [`Ball`] on the
+    /// right-hand side is taken by value.
+    ///
+    /// See [`Ball::sub_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// let mut z = x.clone();
+    /// z -= y;
+    /// assert_eq!(z.to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = sub_helper(self, &other, prec);
+    }
+}
+
+impl SubAssign<&Self> for Ball {
+    /// Subtracts a [`Ball`] from a [`Ball`] in place, computing the midpoint of the result with the
+    /// precision of the inputs (the larger precision of their midpoints). The [`Ball`] on the
+    /// right-hand side is taken by reference.
+    ///
+    /// See [`Ball::sub_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::from_rational_prec(Rational::from_signeds(1, 3), 20);
+    /// let y = Ball::from_rational_prec(Rational::from_signeds(2, 7), 20);
+    /// let mut z = x.clone();
+    /// z -= &y;
+    /// assert_eq!(z.to_string(), "[0.047619343 +/- 9.5367431641e-7]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = sub_helper(self, other, prec);
+    }
+}
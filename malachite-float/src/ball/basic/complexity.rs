@@ -0,0 +1,43 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::Ball;
+use malachite_base::num::logic::traits::SignificantBits;
+
+impl SignificantBits for &Ball {
+    /// Returns the number of significant bits of a [`Ball`]: the number of significant bits of its
+    /// midpoint.
+    ///
+    /// This is the precision that the operators use for their results. The radius, whose precision
+    /// is small and fixed, is not counted.
+    ///
+    /// $$
+    /// f(m \pm r) = \operatorname{bits}(m).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::num::logic::traits::SignificantBits;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Ball::ZERO.significant_bits(), 1);
+    /// assert_eq!(Ball::ONE.significant_bits(), 1);
+    ///
+    /// let x = Ball::new(Float::from_unsigned_prec(3u32, 10).0, Float::from(0.5));
+    /// assert_eq!(x.significant_bits(), 10);
+    /// ```
+    #[inline]
+    fn significant_bits(self) -> u64 {
+        self.mid.significant_bits()
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::Ball;
+use malachite_base::num::basic::traits::{Infinity, One, Zero};
+
+/// The exact ball $0 \pm 0$, whose midpoint and radius are positive zeros.
+impl Zero for Ball {
+    const ZERO: Self = Self {
+        mid: Float::ZERO,
+        rad: Float::ZERO,
+    };
+}
+
+/// The exact ball $1 \pm 0$, whose midpoint has precision 1.
+impl One for Ball {
+    const ONE: Self = Self {
+        mid: Float::ONE,
+        rad: Float::ZERO,
+    };
+}
+
+impl Ball {
+    /// The indeterminate ball $0 \pm \infty$, which contains every real number.
+    pub const INDETERMINATE: Self = Self {
+        mid: Float::ZERO,
+        rad: Float::INFINITY,
+    };
+}
@@ -0,0 +1,174 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::{Ball, RADIUS_PREC};
+use malachite_base::num::basic::traits::{Infinity, Zero};
+use malachite_base::rounding_modes::RoundingMode::*;
+
+impl Ball {
+    /// Creates a [`Ball`] from its midpoint and radius, taking both by value.
+    ///
+    /// If the radius has more than 30 bits of precision, it is rounded up to 30 bits, so the result
+    /// may be slightly larger than requested. A negative-zero radius becomes a positive zero, and
+    /// an infinite radius produces [`Ball::INDETERMINATE`].
+    ///
+    /// $$
+    /// f(m, r) = m \pm \Delta_{30}(r),
+    /// $$
+    /// where $\Delta_{30}$ rounds up to precision 30 (or leaves $r$ unchanged if its precision is
+    /// lower).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `rad.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `mid` is not finite, or if `rad` is `NaN` or negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Ball::new(Float::from(1.5), Float::from(0.25)).to_string(),
+    ///     "[1.5 +/- 0.25]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::new(Float::from(1.5), Float::ZERO).to_string(),
+    ///     "[1.5 +/- 0.0]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::new(Float::from(1.5), Float::INFINITY).to_string(),
+    ///     "[0.0 +/- Infinity]"
+    /// );
+    /// ```
+    pub fn new(mid: Float, rad: Float) -> Self {
+        assert!(mid.is_finite(), "Ball midpoint {mid} is not finite");
+        assert!(
+            !rad.is_nan() && rad >= 0u32,
+            "Ball radius {rad} is not a non-negative number"
+        );
+        if rad == Float::INFINITY {
+            Self::INDETERMINATE
+        } else if rad == 0u32 {
+            Self {
+                mid,
+                rad: Float::ZERO,
+            }
+        } else if rad.get_prec().unwrap() > RADIUS_PREC {
+            Self {
+                mid,
+                rad: Float::from_float_prec_round(rad, RADIUS_PREC, Ceiling).0,
+            }
+        } else {
+            Self { mid, rad }
+        }
+    }
+
+    /// Returns a reference to the midpoint of a [`Ball`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert_eq!(x.mid().to_string(), "1.5");
+    /// ```
+    #[inline]
+    pub const fn mid(&self) -> &Float {
+        &self.mid
+    }
+
+    /// Returns a reference to the radius of a [`Ball`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.25));
+    /// assert_eq!(x.rad().to_string(), "0.25");
+    /// ```
+    #[inline]
+    pub const fn rad(&self) -> &Float {
+        &self.rad
+    }
+
+    /// Consumes a [`Ball`], returning its midpoint and radius.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let (mid, rad) = Ball::new(Float::from(1.5), Float::from(0.25)).into_mid_rad();
+    /// assert_eq!(mid.to_string(), "1.5");
+    /// assert_eq!(rad.to_string(), "0.25");
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_mid_rad(self) -> (Float, Float) {
+        (self.mid, self.rad)
+    }
+
+    /// Determines whether a [`Ball`] contains a single point; that is, whether its radius is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Ball::ONE.is_exact());
+    /// assert!(!Ball::new(Float::from(1.5), Float::from(0.25)).is_exact());
+    /// ```
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        self.rad == 0u32
+    }
+
+    /// Determines whether the radius of a [`Ball`] is finite; that is, whether the [`Ball`] is not
+    /// indeterminate.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Ball::ONE.is_bounded());
+    /// assert!(Ball::new(Float::from(1.5), Float::from(0.25)).is_bounded());
+    /// assert!(!Ball::INDETERMINATE.is_bounded());
+    /// ```
+    #[inline]
+    pub const fn is_bounded(&self) -> bool {
+        self.rad.is_finite()
+    }
+}
@@ -0,0 +1,13 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+/// Measuring the complexity of a [`Ball`](super::Ball).
+pub mod complexity;
+/// Various [`Ball`](super::Ball) constants.
+pub mod constants;
+/// Creating [`Ball`](super::Ball)s and getting their midpoints and radii.
+pub mod get_and_set;
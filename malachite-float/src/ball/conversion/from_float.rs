@@ -0,0 +1,88 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::Ball;
+use malachite_base::num::basic::traits::Zero;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BallFromFloatError;
+
+impl TryFrom<Float> for Ball {
+    type Error = BallFromFloatError;
+
+    /// Converts a [`Float`] to the exact [`Ball`] containing only that [`Float`], taking the
+    /// [`Float`] by value. If the [`Float`] is not finite, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN};
+    /// use malachite_float::ball::conversion::from_float::BallFromFloatError;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Ball::try_from(Float::from(1.5)).unwrap().to_string(),
+    ///     "[1.5 +/- 0.0]"
+    /// );
+    /// assert_eq!(Ball::try_from(Float::INFINITY).unwrap_err(), BallFromFloatError);
+    /// assert_eq!(Ball::try_from(Float::NAN).unwrap_err(), BallFromFloatError);
+    /// ```
+    fn try_from(x: Float) -> Result<Self, Self::Error> {
+        if x.is_finite() {
+            Ok(Self {
+                mid: x,
+                rad: Float::ZERO,
+            })
+        } else {
+            Err(BallFromFloatError)
+        }
+    }
+}
+
+impl TryFrom<&Float> for Ball {
+    type Error = BallFromFloatError;
+
+    /// Converts a [`Float`] to the exact [`Ball`] containing only that [`Float`], taking the
+    /// [`Float`] by reference. If the [`Float`] is not finite, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN};
+    /// use malachite_float::ball::conversion::from_float::BallFromFloatError;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Ball::try_from(&Float::from(1.5)).unwrap().to_string(),
+    ///     "[1.5 +/- 0.0]"
+    /// );
+    /// assert_eq!(Ball::try_from(&Float::INFINITY).unwrap_err(), BallFromFloatError);
+    /// assert_eq!(Ball::try_from(&Float::NAN).unwrap_err(), BallFromFloatError);
+    /// ```
+    fn try_from(x: &Float) -> Result<Self, Self::Error> {
+        if x.is_finite() {
+            Ok(Self {
+                mid: x.clone(),
+                rad: Float::ZERO,
+            })
+        } else {
+            Err(BallFromFloatError)
+        }
+    }
+}
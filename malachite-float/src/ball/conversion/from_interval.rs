@@ -0,0 +1,118 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::{Ball, RADIUS_PREC};
+use crate::interval::Interval;
+use malachite_base::num::basic::traits::{Infinity, Zero};
+use malachite_base::rounding_modes::RoundingMode::*;
+
+impl From<Interval> for Ball {
+    /// Converts an [`Interval`] to a [`Ball`] that contains it, taking the [`Interval`] by value.
+    ///
+    /// The midpoint of the result is the [`Interval`]'s midpoint, as computed by
+    /// [`Interval::midpoint`], and the radius is the larger of its distances to the endpoints,
+    /// rounded up.
+    ///
+    /// $$
+    /// f([a, b]) = m \pm \Delta_{30}(\max(m - a, b - m)),
+    /// $$
+    /// where $m$ is the rounded midpoint of $[a, b]$ and $\Delta_{30}$ rounds up to precision 30.
+    ///
+    /// An unbounded [`Interval`] gives [`Ball::INDETERMINATE`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Ball::from(Interval::new(Float::from(1.5), Float::from(2.5))).to_string(),
+    ///     "[2.0 +/- 0.50000000000]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::from(Interval::ENTIRE).to_string(),
+    ///     "[0.0 +/- Infinity]"
+    /// );
+    /// ```
+    #[inline]
+    fn from(x: Interval) -> Self {
+        Self::from(&x)
+    }
+}
+
+impl From<&Interval> for Ball {
+    /// Converts an [`Interval`] to a [`Ball`] that contains it, taking the [`Interval`] by
+    /// reference.
+    ///
+    /// The midpoint of the result is the [`Interval`]'s midpoint, as computed by
+    /// [`Interval::midpoint`], and the radius is the larger of its distances to the endpoints,
+    /// rounded up.
+    ///
+    /// $$
+    /// f([a, b]) = m \pm \Delta_{30}(\max(m - a, b - m)),
+    /// $$
+    /// where $m$ is the rounded midpoint of $[a, b]$ and $\Delta_{30}$ rounds up to precision 30.
+    ///
+    /// An unbounded [`Interval`] gives [`Ball::INDETERMINATE`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Ball::from(&Interval::new(Float::from(1.5), Float::from(2.5))).to_string(),
+    ///     "[2.0 +/- 0.50000000000]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::from(&Interval::ENTIRE).to_string(),
+    ///     "[0.0 +/- Infinity]"
+    /// );
+    /// ```
+    fn from(x: &Interval) -> Self {
+        if !x.is_bounded() {
+            return Self::INDETERMINATE;
+        }
+        let mid = x.midpoint();
+        let lower_rad = mid.sub_prec_round_ref_ref(&x.lower, RADIUS_PREC, Ceiling).0;
+        let upper_rad = x.upper.sub_prec_round_ref_ref(&mid, RADIUS_PREC, Ceiling).0;
+        let rad = if lower_rad > upper_rad {
+            lower_rad
+        } else {
+            upper_rad
+        };
+        if rad == Float::INFINITY {
+            Self::INDETERMINATE
+        } else if rad == 0u32 {
+            // The endpoints may be zeros of different signs, making the difference -0.0
+            Self {
+                mid,
+                rad: Float::ZERO,
+            }
+        } else {
+            Self { mid, rad }
+        }
+    }
+}
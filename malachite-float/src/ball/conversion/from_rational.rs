@@ -0,0 +1,117 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::{Ball, from_mid_and_rad};
+use malachite_base::num::basic::traits::Zero;
+use malachite_q::Rational;
+
+impl Ball {
+    /// Converts a [`Rational`] to a [`Ball`] whose midpoint has the specified precision, taking the
+    /// [`Rational`] by value.
+    ///
+    /// The midpoint is the [`Rational`] rounded to the nearest [`Float`] of the given precision. If
+    /// the rounding is exact, the radius is zero; otherwise, it is one ulp of the midpoint. In
+    /// either case, the [`Rational`] is guaranteed to lie in the result.
+    ///
+    /// $$
+    /// f(x, p) = \operatorname{round}_p(x) \pm \epsilon,
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`] of precision $p$, and
+    /// $\epsilon$ is zero if $x$ is representable with precision $p$ and one ulp of
+    /// $\operatorname{round}_p(x)$ otherwise.
+    ///
+    /// If the [`Rational`] is too large in magnitude to be represented by a [`Float`], the result
+    /// is [`Ball::INDETERMINATE`]; if it is too small, the midpoint may be zero and the radius is
+    /// the smallest positive [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Ball::from_rational_prec(Rational::from_signeds(1, 3), 10).to_string(),
+    ///     "[0.33350 +/- 0.00049]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::from_rational_prec(Rational::from_signeds(-1, 3), 10).to_string(),
+    ///     "[-0.33350 +/- 0.00049]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::from_rational_prec(Rational::from_signeds(3, 8), 10).to_string(),
+    ///     "[0.37500 +/- 0.0]"
+    /// );
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn from_rational_prec(x: Rational, prec: u64) -> Self {
+        Self::from_rational_prec_ref(&x, prec)
+    }
+
+    /// Converts a [`Rational`] to a [`Ball`] whose midpoint has the specified precision, taking the
+    /// [`Rational`] by reference.
+    ///
+    /// The midpoint is the [`Rational`] rounded to the nearest [`Float`] of the given precision. If
+    /// the rounding is exact, the radius is zero; otherwise, it is one ulp of the midpoint. In
+    /// either case, the [`Rational`] is guaranteed to lie in the result.
+    ///
+    /// $$
+    /// f(x, p) = \operatorname{round}_p(x) \pm \epsilon,
+    /// $$
+    /// where $\operatorname{round}_p$ rounds to the nearest [`Float`] of precision $p$, and
+    /// $\epsilon$ is zero if $x$ is representable with precision $p$ and one ulp of
+    /// $\operatorname{round}_p(x)$ otherwise.
+    ///
+    /// If the [`Rational`] is too large in magnitude to be represented by a [`Float`], the result
+    /// is [`Ball::INDETERMINATE`]; if it is too small, the midpoint may be zero and the radius is
+    /// the smallest positive [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Ball::from_rational_prec_ref(&Rational::from_signeds(1, 3), 10).to_string(),
+    ///     "[0.33350 +/- 0.00049]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::from_rational_prec_ref(&Rational::from_signeds(-1, 3), 10).to_string(),
+    ///     "[-0.33350 +/- 0.00049]"
+    /// );
+    /// assert_eq!(
+    ///     Ball::from_rational_prec_ref(&Rational::from_signeds(3, 8), 10).to_string(),
+    ///     "[0.37500 +/- 0.0]"
+    /// );
+    /// ```
+    pub fn from_rational_prec_ref(x: &Rational, prec: u64) -> Self {
+        let (mid, o) = Float::from_rational_prec_ref(x, prec);
+        from_mid_and_rad(mid, o, Float::ZERO)
+    }
+}
@@ -0,0 +1,23 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+/// Implementations of traits for converting a [`Float`](crate::Float) to a [`Ball`](super::Ball).
+pub mod from_float;
+/// Implementations of [`From`] for converting an [`Interval`](crate::interval::Interval) to the
+/// [`Ball`](super::Ball) that encloses it.
+pub mod from_interval;
+/// Functions for converting a [`Rational`](malachite_q::Rational) to a [`Ball`](super::Ball) whose
+/// midpoint has a given precision.
+pub mod from_rational;
+/// Implementations of [`From`] for converting a [`Ball`](super::Ball) to the
+/// [`Interval`](crate::interval::Interval) that encloses it.
+pub mod to_interval;
+/// Implementations of [`Display`](core::fmt::Display) and [`Debug`] for [`Ball`](super::Ball)s.
+pub mod to_string;
+/// A function for rounding a [`Ball`](super::Ball) to a correctly rounded [`Float`](crate::Float),
+/// when the [`Ball`](super::Ball) is narrow enough to allow it.
+pub mod try_round;
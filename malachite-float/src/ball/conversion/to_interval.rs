@@ -0,0 +1,98 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::Ball;
+use crate::interval::{Interval, from_rounded_endpoints};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+impl From<Ball> for Interval {
+    /// Converts a [`Ball`] to the narrowest [`Interval`] that contains it and whose endpoints have
+    /// the precision of the [`Ball`]'s midpoint, taking the [`Ball`] by value.
+    ///
+    /// $$
+    /// f(m \pm r) = [\nabla_p(m - r), \Delta_p(m + r)],
+    /// $$
+    /// where $p$ is the precision of $m$, and $\nabla_p$ and $\Delta_p$ round down and up to
+    /// precision $p$.
+    ///
+    /// An indeterminate [`Ball`] gives [`Interval::ENTIRE`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::from(Ball::new(Float::from(1.5), Float::from(0.25))).to_string(),
+    ///     "[1.0, 2.0]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from(Ball::INDETERMINATE).to_string(),
+    ///     "[-Infinity, Infinity]"
+    /// );
+    /// ```
+    #[inline]
+    fn from(x: Ball) -> Self {
+        Self::from(&x)
+    }
+}
+
+impl From<&Ball> for Interval {
+    /// Converts a [`Ball`] to the narrowest [`Interval`] that contains it and whose endpoints have
+    /// the precision of the [`Ball`]'s midpoint, taking the [`Ball`] by reference.
+    ///
+    /// $$
+    /// f(m \pm r) = [\nabla_p(m - r), \Delta_p(m + r)],
+    /// $$
+    /// where $p$ is the precision of $m$, and $\nabla_p$ and $\Delta_p$ round down and up to
+    /// precision $p$.
+    ///
+    /// An indeterminate [`Ball`] gives [`Interval::ENTIRE`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::from(&Ball::new(Float::from(1.5), Float::from(0.25))).to_string(),
+    ///     "[1.0, 2.0]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from(&Ball::INDETERMINATE).to_string(),
+    ///     "[-Infinity, Infinity]"
+    /// );
+    /// ```
+    fn from(x: &Ball) -> Self {
+        if !x.is_bounded() {
+            return Self::ENTIRE;
+        }
+        let prec = x.significant_bits();
+        from_rounded_endpoints(
+            x.mid.sub_prec_round_ref_ref(&x.rad, prec, Floor).0,
+            x.mid.add_prec_round_ref_ref(&x.rad, prec, Ceiling).0,
+        )
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ball::Ball;
+use core::fmt::{Debug, Display, Formatter, Result};
+
+impl Display for Ball {
+    /// Converts a [`Ball`] to a [`String`](alloc::string::String).
+    ///
+    /// The output is `"[m +/- r]"`, where `m` and `r` are the midpoint and radius written as
+    /// [`Float`](crate::Float)'s [`Display`] implementation writes them. Note that this is not an
+    /// enclosure: each number is written with just enough digits to identify it among the
+    /// [`Float`](crate::Float)s of its precision, so the decimal strings themselves may describe a
+    /// slightly different ball.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.mid().complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Ball::ONE.to_string(), "[1.0 +/- 0.0]");
+    /// assert_eq!(Ball::INDETERMINATE.to_string(), "[0.0 +/- Infinity]");
+    /// assert_eq!(
+    ///     Ball::new(Float::from(-1.5), Float::from(0.25)).to_string(),
+    ///     "[-1.5 +/- 0.25]"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "[{} +/- {}]", self.mid, self.rad)
+    }
+}
+
+impl Debug for Ball {
+    /// Converts a [`Ball`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.mid().complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_float::ball::Ball;
+    ///
+    /// assert_eq!(Ball::ONE.to_debug_string(), "[1.0 +/- 0.0]");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
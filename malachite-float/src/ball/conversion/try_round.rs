@@ -0,0 +1,93 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::Ball;
+use core::cmp::Ordering::*;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+impl Ball {
+    /// Rounds every element of a [`Ball`] to the specified precision, using the specified rounding
+    /// mode, and returns the result if it is the same for all of them.
+    ///
+    /// A [`Ball`] typically encloses some real number that could not be computed exactly. If this
+    /// function returns `Some(y)`, then $y$ is that number correctly rounded, whichever element of
+    /// the [`Ball`] it is. If it returns `None`, either the [`Ball`] is too wide for the requested
+    /// precision, or its elements straddle a rounding boundary; in the usual Ziv-style loop, the
+    /// computation should be repeated with more precision.
+    ///
+    /// $$
+    /// f(B, p, m) = \\begin{cases}
+    ///     \operatorname{Some}(y) & \text{if} \\quad \operatorname{round}_{p, m}(x) = y \\quad
+    ///     \text{for every} \\quad x \in B, \\\\
+    ///     \operatorname{None} & \\text{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $\operatorname{round}_{p, m}$ rounds to precision $p$ with rounding mode $m$.
+    ///
+    /// This function is conservative: it may return `None` even when every element rounds to the
+    /// same value, if that cannot be established cheaply from the midpoint and radius. It always
+    /// returns `None` for an indeterminate [`Ball`], for a [`Ball`] of nonzero radius centered at
+    /// zero, and, unless the [`Ball`] is exact, when the rounding mode is `Exact`. When the
+    /// [`Ball`] is exact and the rounding mode is `Exact`, the result is `None` if the midpoint
+    /// cannot be represented with the specified precision.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Two;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// // sqrt(2), computed with 100 bits of precision, can be rounded to 53 bits...
+    /// let x = Ball::try_from(Float::TWO).unwrap().sqrt_prec(100);
+    /// assert_eq!(
+    ///     x.try_round(53, Nearest).unwrap().to_string(),
+    ///     "1.4142135623730951"
+    /// );
+    /// // ...but not to 100 bits.
+    /// assert_eq!(x.try_round(100, Nearest), None);
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.125));
+    /// assert_eq!(x.try_round(1, Floor).unwrap().to_string(), "1.0");
+    /// assert_eq!(x.try_round(1, Nearest), None);
+    /// ```
+    pub fn try_round(&self, prec: u64, rm: RoundingMode) -> Option<Float> {
+        assert_ne!(prec, 0);
+        if self.is_exact() {
+            return if rm == Exact {
+                let (y, o) = Float::from_float_prec_round_ref(&self.mid, prec, Floor);
+                if o == Equal { Some(y) } else { None }
+            } else {
+                Some(Float::from_float_prec_round_ref(&self.mid, prec, rm).0)
+            };
+        }
+        if rm == Exact || !self.is_bounded() {
+            return None;
+        }
+        // The radius is less than 2^e, where e is its raw exponent, so that bounds the distance
+        // between the midpoint and any element
+        let err = i64::from(self.mid.get_exponent()?) - i64::from(self.rad.get_exponent().unwrap());
+        if self.mid.can_round(err, Nearest, rm, prec) {
+            Some(Float::from_float_prec_round_ref(&self.mid, prec, rm).0)
+        } else {
+            None
+        }
+    }
+}
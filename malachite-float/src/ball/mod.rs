@@ -0,0 +1,157 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::AbsAssign;
+use malachite_base::num::basic::traits::{Infinity, Zero};
+use malachite_base::rounding_modes::RoundingMode::*;
+
+/// A real number known to lie within some distance of a [`Float`].
+///
+/// A `Ball` $m \pm r$, with midpoint $m$ and radius $r$, represents every real number $x$ with
+/// $|x - m| \leq r$. The midpoint is a finite [`Float`] of any precision. The radius is a
+/// non-negative [`Float`] with a small, fixed precision of 30 bits, so that keeping track of the
+/// error costs little compared to computing the midpoint. The radius may be $\infty$, in which case
+/// the `Ball` contains every real number and is said to be indeterminate; this is how functions
+/// report results that they cannot bound, such as quotients by a `Ball` that contains zero.
+///
+/// Every function on `Ball`s is an enclosure: if $x_1, x_2, \ldots$ lie in the input balls, then
+/// $f(x_1, x_2, \ldots)$ lies in the output ball. Functions compute the midpoint of the result by
+/// evaluating $f$ at the input midpoints, rounding to the nearest [`Float`] of the target
+/// precision. The radius of the result is then an upper bound for the distance that the inputs'
+/// radii can move $f$, plus an upper bound for the error of that rounding, with every radius
+/// computation rounded up. Functions that take a precision use it for the midpoint of the result;
+/// the operators, like those of [`Float`], use the largest precision among the midpoints of the
+/// inputs.
+///
+/// The `Ball` type is designed to be similar to the `arb_t` type in [Arb](https://arblib.org/) (now
+/// part of [FLINT](https://flintlib.org/)). Compared to an [`Interval`](crate::interval::Interval),
+/// a `Ball` stores only one high-precision number, so arithmetic on it is roughly twice as fast, at
+/// the cost of slightly looser bounds. To get a correctly rounded [`Float`] out of a `Ball`, use
+/// [`Ball::try_round`], which succeeds when the `Ball` is narrow enough that all of its elements
+/// round to the same value.
+///
+/// `Ball`s implement neither [`PartialEq`] nor [`PartialOrd`]: two `Ball`s with different midpoints
+/// can represent the same unknown number, so neither relation would mean much.
+#[derive(Clone)]
+pub struct Ball {
+    pub(crate) mid: Float,
+    pub(crate) rad: Float,
+}
+
+// The precision with which radii are computed.
+pub(crate) const RADIUS_PREC: u64 = 30;
+
+impl Ball {
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.mid.is_valid()
+            && self.rad.is_valid()
+            && self.mid.is_finite()
+            && !self.rad.is_nan()
+            && self.rad.is_sign_positive()
+            && (self.rad.is_finite() || self.mid == 0u32)
+            && self.rad.get_prec().is_none_or(|p| p <= RADIUS_PREC)
+    }
+}
+
+// An upper bound for `|x|`, with the radius precision.
+pub(crate) fn abs_upper(x: &Float) -> Float {
+    let rm = if x.is_sign_negative() { Floor } else { Ceiling };
+    let mut y = Float::from_float_prec_round_ref(x, RADIUS_PREC, rm).0;
+    y.abs_assign();
+    y
+}
+
+// A lower bound for `|x|`, with the radius precision.
+pub(crate) fn abs_lower(x: &Float) -> Float {
+    let rm = if x.is_sign_negative() { Ceiling } else { Floor };
+    let mut y = Float::from_float_prec_round_ref(x, RADIUS_PREC, rm).0;
+    y.abs_assign();
+    y
+}
+
+// `x` rounded up to the radius precision, if its precision is higher.
+pub(crate) fn round_up(x: &Float) -> Float {
+    if x.get_prec().is_some_and(|p| p > RADIUS_PREC) {
+        Float::from_float_prec_round_ref(x, RADIUS_PREC, Ceiling).0
+    } else {
+        x.clone()
+    }
+}
+
+// An upper bound for `x + y`, where `x` and `y` are non-negative, with at most the radius
+// precision.
+pub(crate) fn add_upper(x: &Float, y: &Float) -> Float {
+    if *x == 0u32 {
+        round_up(y)
+    } else if *y == 0u32 {
+        round_up(x)
+    } else {
+        x.add_prec_round_ref_ref(y, RADIUS_PREC, Ceiling).0
+    }
+}
+
+// An upper bound for `x * y`, where `x` and `y` are non-negative, with the radius precision. If
+// either factor is zero, so is the product, even if the other factor is infinite: an infinite
+// radius only means that nothing is known, and zero times an unknown finite number is still zero.
+pub(crate) fn mul_upper(x: &Float, y: &Float) -> Float {
+    if *x == 0u32 || *y == 0u32 {
+        Float::ZERO
+    } else {
+        x.mul_prec_round_ref_ref(y, RADIUS_PREC, Ceiling).0
+    }
+}
+
+// An upper bound for `x / y`, where `x` and `y` are non-negative, with the radius precision. A
+// zero numerator gives zero, whatever the denominator.
+pub(crate) fn div_upper(x: &Float, y: &Float) -> Float {
+    if *x == 0u32 {
+        Float::ZERO
+    } else {
+        x.div_prec_round_ref_ref(y, RADIUS_PREC, Ceiling).0
+    }
+}
+
+// The ball around `mid`, a midpoint that was computed by rounding to nearest, with `o` the
+// ordering of `mid` relative to the exact value. Its radius is `rad` plus a bound for the rounding
+// error: one ulp of `mid` if it is inexact. If `mid` underflowed, or its ulp is too small to
+// represent, the smallest positive `Float` is used instead. If `mid` overflowed or the radius is
+// infinite, the result is indeterminate.
+pub(crate) fn from_mid_and_rad(mid: Float, o: Ordering, rad: Float) -> Ball {
+    if !mid.is_finite() || rad == Float::INFINITY {
+        return Ball::INDETERMINATE;
+    }
+    let rad = if o == Equal {
+        rad
+    } else if mid == 0u32 || mid.abs_is_min_positive_value() {
+        add_upper(&rad, &Float::min_positive_value_prec(1))
+    } else {
+        add_upper(
+            &rad,
+            &mid.ulp()
+                .unwrap_or_else(|| Float::min_positive_value_prec(1)),
+        )
+    };
+    if rad == Float::INFINITY {
+        Ball::INDETERMINATE
+    } else {
+        Ball { mid, rad }
+    }
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Basic traits for working with [`Ball`]s.
+pub mod basic;
+/// Traits for converting to and from [`Ball`]s, including converting [`Ball`]s to strings and
+/// rounding them to [`Float`]s.
+pub mod conversion;
+/// Determining whether a [`Ball`] contains a number or overlaps another [`Ball`].
+pub mod set_operations;
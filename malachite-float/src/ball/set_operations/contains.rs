@@ -0,0 +1,151 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::Ball;
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_q::Rational;
+
+impl Ball {
+    /// Determines whether a [`Ball`] contains a [`Float`].
+    ///
+    /// The comparison is exact. A `NaN` is not contained in any [`Ball`], and an infinity is
+    /// contained only in an indeterminate [`Ball`].
+    ///
+    /// $f(m \pm r, x) = (|x - m| \leq r)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One};
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(2), Float::from(0.5));
+    /// assert!(x.contains(&Float::from(2)));
+    /// assert!(x.contains(&Float::from(2.5)));
+    /// assert!(!x.contains(&Float::ONE));
+    /// assert!(!x.contains(&Float::NAN));
+    /// assert!(!x.contains(&Float::INFINITY));
+    /// assert!(Ball::INDETERMINATE.contains(&Float::INFINITY));
+    /// ```
+    pub fn contains(&self, x: &Float) -> bool {
+        if x.is_nan() {
+            false
+        } else if !self.is_bounded() {
+            true
+        } else if !x.is_finite() {
+            false
+        } else {
+            self.contains_rational(&Rational::exact_from(x))
+        }
+    }
+
+    /// Determines whether a [`Ball`] contains a [`Rational`].
+    ///
+    /// $f(m \pm r, x) = (|x - m| \leq r)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Ball::new(Float::from(0.375), Float::from(0.125));
+    /// assert!(x.contains_rational(&Rational::from_signeds(1, 3)));
+    /// assert!(x.contains_rational(&Rational::from_signeds(1, 2)));
+    /// assert!(!x.contains_rational(&Rational::from_signeds(2, 3)));
+    /// ```
+    pub fn contains_rational(&self, x: &Rational) -> bool {
+        !self.is_bounded()
+            || (Rational::exact_from(&self.mid) - x).abs() <= Rational::exact_from(&self.rad)
+    }
+
+    /// Determines whether a [`Ball`] contains every element of another [`Ball`].
+    ///
+    /// $f(m \pm r, n \pm s) = (|m - n| + s \leq r)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.5));
+    /// assert!(x.contains_ball(&Ball::ONE));
+    /// assert!(x.contains_ball(&x));
+    /// assert!(!x.contains_ball(&Ball::new(Float::from(2), Float::from(0.25))));
+    /// assert!(Ball::INDETERMINATE.contains_ball(&x));
+    /// ```
+    pub fn contains_ball(&self, other: &Self) -> bool {
+        if !self.is_bounded() {
+            true
+        } else if !other.is_bounded() {
+            false
+        } else {
+            (Rational::exact_from(&self.mid) - Rational::exact_from(&other.mid)).abs()
+                + Rational::exact_from(&other.rad)
+                <= Rational::exact_from(&self.rad)
+        }
+    }
+
+    /// Determines whether two [`Ball`]s have an element in common.
+    ///
+    /// $f(m \pm r, n \pm s) = (|m - n| \leq r + s)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::ball::Ball;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Ball::new(Float::from(1.5), Float::from(0.5));
+    /// assert!(x.overlaps(&Ball::ONE));
+    /// assert!(x.overlaps(&Ball::new(Float::from(2.5), Float::from(0.5))));
+    /// assert!(!x.overlaps(&Ball::new(Float::from(3), Float::from(0.5))));
+    /// assert!(Ball::INDETERMINATE.overlaps(&x));
+    /// ```
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.is_bounded()
+            || !other.is_bounded()
+            || (Rational::exact_from(&self.mid) - Rational::exact_from(&other.mid)).abs()
+                <= Rational::exact_from(&self.rad) + Rational::exact_from(&other.rad)
+    }
+}
@@ -0,0 +1,10 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+/// Determining whether a [`Ball`](super::Ball) contains a number or another [`Ball`](super::Ball),
+/// or whether two [`Ball`](super::Ball)s overlap.
+pub mod contains;
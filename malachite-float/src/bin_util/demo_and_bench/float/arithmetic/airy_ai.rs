@@ -0,0 +1,521 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::bench::bucketers::primitive_float_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::primitive_float_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_float::ComparableFloat;
+use malachite_float::Float;
+use malachite_float::float::arithmetic::airy_ai::{
+    primitive_float_airy_ai, primitive_float_airy_ai_rational,
+};
+use malachite_float::test_util::bench::bucketers::{
+    pair_1_float_complexity_bucketer, pair_2_pair_1_float_complexity_bucketer,
+    pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::float::arithmetic::airy_ai::{
+    rug_airy_ai_prec_round, rug_airy_ai_round,
+};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen_var_54, float_rounding_mode_pair_gen_var_54_rm,
+    float_unsigned_rounding_mode_triple_gen_var_43,
+    float_unsigned_rounding_mode_triple_gen_var_43_rm,
+    rational_unsigned_rounding_mode_triple_gen_var_15,
+};
+use malachite_q::test_util::bench::bucketers::{
+    rational_bit_bucketer, triple_1_2_rational_bit_u64_max_bucketer,
+};
+use malachite_q::test_util::generators::rational_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_airy_ai_round);
+    register_demo!(runner, demo_float_airy_ai_round_debug);
+    register_demo!(runner, demo_float_airy_ai_round_ref);
+    register_demo!(runner, demo_float_airy_ai_round_assign);
+    register_demo!(runner, demo_float_airy_ai_prec_round);
+    register_demo!(runner, demo_float_airy_ai_prec_round_debug);
+    register_demo!(runner, demo_float_airy_ai_prec_round_ref);
+    register_demo!(runner, demo_float_airy_ai_prec_round_assign);
+    register_demo!(runner, demo_float_airy_ai_rational_prec_round);
+    register_demo!(runner, demo_float_airy_ai_rational_prec_round_debug);
+    register_demo!(runner, demo_float_airy_ai_rational_prec_round_ref);
+    register_demo!(runner, demo_float_airy_ai_rational_prec_round_ref_debug);
+    register_primitive_float_demos!(runner, demo_primitive_float_airy_ai);
+    register_primitive_float_demos!(runner, demo_primitive_float_airy_ai_rational);
+
+    register_bench!(runner, benchmark_float_airy_ai_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_airy_ai_round_library_comparison);
+    register_bench!(runner, benchmark_float_airy_ai_round_assign);
+    register_bench!(
+        runner,
+        benchmark_float_airy_ai_prec_round_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_airy_ai_prec_round_library_comparison
+    );
+    register_bench!(runner, benchmark_float_airy_ai_prec_round_assign);
+    register_bench!(
+        runner,
+        benchmark_float_airy_ai_rational_prec_round_evaluation_strategy
+    );
+    register_primitive_float_benches!(runner, benchmark_primitive_float_airy_ai);
+    register_primitive_float_benches!(runner, benchmark_primitive_float_airy_ai_rational);
+}
+
+fn demo_float_airy_ai_rational_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p, rm) in rational_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::airy_ai_rational_prec_round({}, {}, {:?}) = {:?}",
+            n.clone(),
+            p,
+            rm,
+            Float::airy_ai_rational_prec_round(n, p, rm)
+        );
+    }
+}
+
+fn demo_float_airy_ai_rational_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p, rm) in rational_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (f, o) = Float::airy_ai_rational_prec_round(n.clone(), p, rm);
+        println!(
+            "Float::airy_ai_rational_prec_round({}, {}, {:?}) = {:x?}",
+            n,
+            p,
+            rm,
+            (ComparableFloat(f), o)
+        );
+    }
+}
+
+fn demo_float_airy_ai_rational_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p, rm) in rational_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::airy_ai_rational_prec_round_ref(&{}, {}, {:?}) = {:?}",
+            n,
+            p,
+            rm,
+            Float::airy_ai_rational_prec_round_ref(&n, p, rm)
+        );
+    }
+}
+
+fn demo_float_airy_ai_rational_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p, rm) in rational_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (f, o) = Float::airy_ai_rational_prec_round_ref(&n, p, rm);
+        println!(
+            "Float::airy_ai_rational_prec_round_ref(&{}, {}, {:?}) = {:x?}",
+            n,
+            p,
+            rm,
+            (ComparableFloat(f), o)
+        );
+    }
+}
+
+fn benchmark_float_airy_ai_rational_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::airy_ai_rational_prec_round(Rational, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        rational_unsigned_rounding_mode_triple_gen_var_15().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_rational_bit_u64_max_bucketer("n", "prec"),
+        &mut [
+            (
+                "Float::airy_ai_rational_prec_round(Rational, u64, RoundingMode)",
+                &mut |(n, prec, rm)| no_out!(Float::airy_ai_rational_prec_round(n, prec, rm)),
+            ),
+            (
+                "Float::airy_ai_rational_prec_round_ref(&Rational, u64, RoundingMode)",
+                &mut |(n, prec, rm)| no_out!(Float::airy_ai_rational_prec_round_ref(&n, prec, rm)),
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_airy_ai<T: PrimitiveFloat>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for x in primitive_float_gen::<T>()
+        .get(gm, config)
+        .filter(|&x| x.is_nan() || x >= -T::power_of_2(6u64))
+        .take(limit)
+    {
+        println!(
+            "primitive_float_airy_ai({}) = {}",
+            NiceFloat(x),
+            NiceFloat(primitive_float_airy_ai(x))
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_airy_ai<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!("primitive_float_airy_ai({})", T::NAME),
+        BenchmarkType::Single,
+        primitive_float_gen::<T>()
+            .get(gm, config)
+            .filter(|&x| x.is_nan() || x >= -T::power_of_2(6u64)),
+        gm.name(),
+        limit,
+        file_name,
+        &primitive_float_bucketer("x"),
+        &mut [("malachite", &mut |x| {
+            no_out!(primitive_float_airy_ai(x));
+        })],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_airy_ai_rational<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for x in rational_gen()
+        .get(gm, config)
+        .filter(|x| *x >= -64i32)
+        .take(limit)
+    {
+        println!(
+            "primitive_float_airy_ai_rational({}) = {:?}",
+            x,
+            NiceFloat(primitive_float_airy_ai_rational::<T>(&x))
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_airy_ai_rational<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!("primitive_float_airy_ai_rational::<{}>(Rational)", T::NAME),
+        BenchmarkType::Single,
+        rational_gen().get(gm, config).filter(|x| *x >= -64i32),
+        gm.name(),
+        limit,
+        file_name,
+        &rational_bit_bucketer("x"),
+        &mut [("Malachite", &mut |x| {
+            no_out!(primitive_float_airy_ai_rational::<T>(&x));
+        })],
+    );
+}
+
+fn demo_float_airy_ai_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_54()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).airy_ai_round({}) = {:?}",
+            x_old,
+            rm,
+            x.airy_ai_round(rm)
+        );
+    }
+}
+
+fn demo_float_airy_ai_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_54()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (e, o) = x.airy_ai_round(rm);
+        println!(
+            "({:#x}).airy_ai_round({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            rm,
+            ComparableFloat(e),
+            o
+        );
+    }
+}
+
+fn demo_float_airy_ai_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_54()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).airy_ai_round_ref({}) = {:?}",
+            x,
+            rm,
+            x.airy_ai_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_airy_ai_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_54()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.airy_ai_round_assign(rm);
+        println!("x := {x_old}; x.airy_ai_round_assign({rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_airy_ai_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_43()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).airy_ai_prec_round({}, {}) = {:?}",
+            x_old,
+            prec,
+            rm,
+            x.airy_ai_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_airy_ai_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_43()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (e, o) = x.airy_ai_prec_round(prec, rm);
+        println!(
+            "({:#x}).airy_ai_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            ComparableFloat(e),
+            o
+        );
+    }
+}
+
+fn demo_float_airy_ai_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_43()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).airy_ai_prec_round_ref({}, {}) = {:?}",
+            x,
+            prec,
+            rm,
+            x.airy_ai_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_airy_ai_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_43()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.airy_ai_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.airy_ai_prec_round_assign({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn benchmark_float_airy_ai_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.airy_ai_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_54().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.airy_ai_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.airy_ai_round(rm));
+            }),
+            (
+                "(&Float).airy_ai_round_ref(RoundingMode)",
+                &mut |(x, rm)| {
+                    no_out!(x.airy_ai_round_ref(rm));
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_airy_ai_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.airy_ai_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_54_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| {
+                no_out!(x.airy_ai_round_ref(rm));
+            }),
+            ("rug", &mut |((x, rm), _)| {
+                no_out!(rug_airy_ai_round(&x, rm));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_airy_ai_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.airy_ai_round_assign(RoundingMode)",
+        BenchmarkType::Single,
+        float_rounding_mode_pair_gen_var_54().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.airy_ai_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.airy_ai_round_assign(rm));
+        })],
+    );
+}
+
+fn benchmark_float_airy_ai_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.airy_ai_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_43().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.airy_ai_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.airy_ai_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).airy_ai_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.airy_ai_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_airy_ai_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.airy_ai_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_43_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.airy_ai_prec_round_ref(prec, rm));
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug_airy_ai_prec_round(&x, prec, rm));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_airy_ai_prec_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.airy_ai_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::Single,
+        float_unsigned_rounding_mode_triple_gen_var_43().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [(
+            "Float.airy_ai_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.airy_ai_prec_round_assign(prec, rm)),
+        )],
+    );
+}
@@ -0,0 +1,463 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::bench::bucketers::pair_1_primitive_float_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::primitive_float_signed_pair_gen_var_4;
+use malachite_base::test_util::runner::Runner;
+use malachite_float::ComparableFloat;
+use malachite_float::Float;
+use malachite_float::float::arithmetic::bessel_yn::primitive_float_bessel_yn;
+use malachite_float::test_util::bench::bucketers::{
+    pair_2_quadruple_1_3_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_float_complexity_bucketer,
+    quadruple_1_3_float_primitive_int_max_complexity_bucketer, triple_1_float_complexity_bucketer,
+};
+use malachite_float::test_util::float::arithmetic::bessel_yn::{
+    rug_bessel_yn_prec_round, rug_bessel_yn_round,
+};
+use malachite_float::test_util::generators::{
+    float_signed_rounding_mode_triple_gen_var_11, float_signed_rounding_mode_triple_gen_var_11_rm,
+    float_signed_unsigned_rounding_mode_quadruple_gen_var_17,
+    float_signed_unsigned_rounding_mode_quadruple_gen_var_17_rm,
+    rational_signed_unsigned_rounding_mode_quadruple_gen_var_3,
+};
+use malachite_q::test_util::bench::bucketers::quadruple_1_2_3_rational_bit_i64_u64_max_bucketer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_bessel_yn_round);
+    register_demo!(runner, demo_float_bessel_yn_round_debug);
+    register_demo!(runner, demo_float_bessel_yn_round_ref);
+    register_demo!(runner, demo_float_bessel_yn_round_assign);
+    register_demo!(runner, demo_float_bessel_yn_prec_round);
+    register_demo!(runner, demo_float_bessel_yn_prec_round_debug);
+    register_demo!(runner, demo_float_bessel_yn_prec_round_ref);
+    register_demo!(runner, demo_float_bessel_yn_prec_round_assign);
+    register_demo!(runner, demo_float_bessel_yn_rational_prec_round);
+    register_demo!(runner, demo_float_bessel_yn_rational_prec_round_debug);
+    register_demo!(runner, demo_float_bessel_yn_rational_prec_round_ref);
+    register_primitive_float_demos!(runner, demo_primitive_float_bessel_yn);
+
+    register_bench!(runner, benchmark_float_bessel_yn_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_bessel_yn_round_library_comparison);
+    register_bench!(runner, benchmark_float_bessel_yn_round_assign);
+    register_bench!(
+        runner,
+        benchmark_float_bessel_yn_prec_round_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_bessel_yn_prec_round_library_comparison
+    );
+    register_bench!(runner, benchmark_float_bessel_yn_prec_round_assign);
+    register_bench!(
+        runner,
+        benchmark_float_bessel_yn_rational_prec_round_evaluation_strategy
+    );
+    register_primitive_float_benches!(runner, benchmark_primitive_float_bessel_yn);
+}
+
+fn demo_float_bessel_yn_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, rm) in float_signed_rounding_mode_triple_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).bessel_yn_round({}, {}) = {:?}",
+            x_old,
+            n,
+            rm,
+            x.bessel_yn_round(n, rm)
+        );
+    }
+}
+
+fn demo_float_bessel_yn_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, rm) in float_signed_rounding_mode_triple_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (y, o) = x.bessel_yn_round(n, rm);
+        println!(
+            "({:#x}).bessel_yn_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            n,
+            rm,
+            ComparableFloat(y),
+            o
+        );
+    }
+}
+
+fn demo_float_bessel_yn_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, rm) in float_signed_rounding_mode_triple_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).bessel_yn_round_ref({}, {}) = {:?}",
+            x,
+            n,
+            rm,
+            x.bessel_yn_round_ref(n, rm)
+        );
+    }
+}
+
+fn demo_float_bessel_yn_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, n, rm) in float_signed_rounding_mode_triple_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.bessel_yn_round_assign(n, rm);
+        println!("x := {x_old}; x.bessel_yn_round_assign({n}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_bessel_yn_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, prec, rm) in float_signed_unsigned_rounding_mode_quadruple_gen_var_17()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).bessel_yn_prec_round({}, {}, {}) = {:?}",
+            x_old,
+            n,
+            prec,
+            rm,
+            x.bessel_yn_prec_round(n, prec, rm)
+        );
+    }
+}
+
+fn demo_float_bessel_yn_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, prec, rm) in float_signed_unsigned_rounding_mode_quadruple_gen_var_17()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (y, o) = x.bessel_yn_prec_round(n, prec, rm);
+        println!(
+            "({:#x}).bessel_yn_prec_round({}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            n,
+            prec,
+            rm,
+            ComparableFloat(y),
+            o
+        );
+    }
+}
+
+fn demo_float_bessel_yn_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, prec, rm) in float_signed_unsigned_rounding_mode_quadruple_gen_var_17()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).bessel_yn_prec_round_ref({}, {}, {}) = {:?}",
+            x,
+            n,
+            prec,
+            rm,
+            x.bessel_yn_prec_round_ref(n, prec, rm)
+        );
+    }
+}
+
+fn demo_float_bessel_yn_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, n, prec, rm) in float_signed_unsigned_rounding_mode_quadruple_gen_var_17()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.bessel_yn_prec_round_assign(n, prec, rm);
+        println!("x := {x_old}; x.bessel_yn_prec_round_assign({n}, {prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_bessel_yn_rational_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, prec, rm) in rational_signed_unsigned_rounding_mode_quadruple_gen_var_3()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::bessel_yn_rational_prec_round({}, {}, {}, {:?}) = {:?}",
+            x.clone(),
+            n,
+            prec,
+            rm,
+            Float::bessel_yn_rational_prec_round(x, n, prec, rm)
+        );
+    }
+}
+
+fn demo_float_bessel_yn_rational_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, prec, rm) in rational_signed_unsigned_rounding_mode_quadruple_gen_var_3()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (f, o) = Float::bessel_yn_rational_prec_round(x.clone(), n, prec, rm);
+        println!(
+            "Float::bessel_yn_rational_prec_round({}, {}, {}, {:?}) = {:x?}",
+            x,
+            n,
+            prec,
+            rm,
+            (ComparableFloat(f), o)
+        );
+    }
+}
+
+fn demo_float_bessel_yn_rational_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, n, prec, rm) in rational_signed_unsigned_rounding_mode_quadruple_gen_var_3()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::bessel_yn_rational_prec_round_ref(&{}, {}, {}, {:?}) = {:?}",
+            x,
+            n,
+            prec,
+            rm,
+            Float::bessel_yn_rational_prec_round_ref(&x, n, prec, rm)
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_bessel_yn<T: PrimitiveFloat>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for (x, n) in primitive_float_signed_pair_gen_var_4::<T, i64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "primitive_float_bessel_yn({}, {}) = {}",
+            NiceFloat(x),
+            n,
+            NiceFloat(primitive_float_bessel_yn(x, n))
+        );
+    }
+}
+
+fn benchmark_float_bessel_yn_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.bessel_yn_round(i64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_signed_rounding_mode_triple_gen_var_11().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_float_complexity_bucketer("x"),
+        &mut [
+            (
+                "Float.bessel_yn_round(i64, RoundingMode)",
+                &mut |(x, n, rm)| {
+                    no_out!(x.bessel_yn_round(n, rm));
+                },
+            ),
+            (
+                "(&Float).bessel_yn_round_ref(i64, RoundingMode)",
+                &mut |(x, n, rm)| {
+                    no_out!(x.bessel_yn_round_ref(n, rm));
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_bessel_yn_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.bessel_yn_round(i64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_signed_rounding_mode_triple_gen_var_11_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, n, rm))| {
+                no_out!(x.bessel_yn_round_ref(n, rm));
+            }),
+            ("rug", &mut |((x, n, rm), _)| {
+                no_out!(rug_bessel_yn_round(&x, n, rm));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_bessel_yn_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.bessel_yn_round_assign(i64, RoundingMode)",
+        BenchmarkType::Single,
+        float_signed_rounding_mode_triple_gen_var_11().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_float_complexity_bucketer("x"),
+        &mut [(
+            "Float.bessel_yn_round_assign(i64, RoundingMode)",
+            &mut |(mut x, n, rm)| no_out!(x.bessel_yn_round_assign(n, rm)),
+        )],
+    );
+}
+
+fn benchmark_float_bessel_yn_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.bessel_yn_prec_round(i64, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_signed_unsigned_rounding_mode_quadruple_gen_var_17().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_3_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.bessel_yn_prec_round(i64, u64, RoundingMode)",
+                &mut |(x, n, prec, rm)| no_out!(x.bessel_yn_prec_round(n, prec, rm)),
+            ),
+            (
+                "(&Float).bessel_yn_prec_round_ref(i64, u64, RoundingMode)",
+                &mut |(x, n, prec, rm)| no_out!(x.bessel_yn_prec_round_ref(n, prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_bessel_yn_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.bessel_yn_prec_round(i64, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_signed_unsigned_rounding_mode_quadruple_gen_var_17_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_3_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, n, prec, rm))| {
+                no_out!(x.bessel_yn_prec_round_ref(n, prec, rm));
+            }),
+            ("rug", &mut |((x, n, prec, rm), _)| {
+                no_out!(rug_bessel_yn_prec_round(&x, n, prec, rm));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_bessel_yn_prec_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.bessel_yn_prec_round_assign(i64, u64, RoundingMode)",
+        BenchmarkType::Single,
+        float_signed_unsigned_rounding_mode_quadruple_gen_var_17().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_3_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [(
+            "Float.bessel_yn_prec_round_assign(i64, u64, RoundingMode)",
+            &mut |(mut x, n, prec, rm)| no_out!(x.bessel_yn_prec_round_assign(n, prec, rm)),
+        )],
+    );
+}
+
+fn benchmark_float_bessel_yn_rational_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::bessel_yn_rational_prec_round(Rational, i64, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        rational_signed_unsigned_rounding_mode_quadruple_gen_var_3().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_rational_bit_i64_u64_max_bucketer("x", "n", "prec"),
+        &mut [
+            (
+                "Float::bessel_yn_rational_prec_round(Rational, i64, u64, RoundingMode)",
+                &mut |(x, n, prec, rm)| {
+                    no_out!(Float::bessel_yn_rational_prec_round(x, n, prec, rm));
+                },
+            ),
+            (
+                "Float::bessel_yn_rational_prec_round_ref(&Rational, i64, u64, RoundingMode)",
+                &mut |(x, n, prec, rm)| {
+                    no_out!(Float::bessel_yn_rational_prec_round_ref(&x, n, prec, rm));
+                },
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_bessel_yn<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!("primitive_float_bessel_yn({}, i64)", T::NAME),
+        BenchmarkType::Single,
+        primitive_float_signed_pair_gen_var_4::<T, i64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_primitive_float_bucketer("x"),
+        &mut [("malachite", &mut |(x, n)| {
+            no_out!(primitive_float_bessel_yn(x, n));
+        })],
+    );
+}
@@ -0,0 +1,1541 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Beta, BetaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::bench::bucketers::pair_max_primitive_float_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::primitive_float_pair_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_float::float::arithmetic::beta::{
+    primitive_float_beta, primitive_float_beta_rational,
+};
+use malachite_float::test_util::bench::bucketers::{
+    pair_float_max_complexity_bucketer,
+    quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_max_complexity_bucketer,
+    triple_float_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_46,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_27, float_float_unsigned_triple_gen_var_1,
+    float_pair_gen, rational_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::test_util::bench::bucketers::{
+    pair_rational_max_bit_bucketer,
+    quadruple_1_2_3_rational_rational_primitive_int_max_bit_bucketer,
+    triple_rational_rational_primitive_int_max_bit_bucketer,
+};
+use malachite_q::test_util::generators::{
+    rational_pair_gen, rational_rational_unsigned_triple_gen_var_2,
+};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_beta);
+    register_demo!(runner, demo_float_beta_debug);
+    register_demo!(runner, demo_float_beta_val_ref);
+    register_demo!(runner, demo_float_beta_val_ref_debug);
+    register_demo!(runner, demo_float_beta_ref_val);
+    register_demo!(runner, demo_float_beta_ref_val_debug);
+    register_demo!(runner, demo_float_beta_ref_ref);
+    register_demo!(runner, demo_float_beta_ref_ref_debug);
+    register_demo!(runner, demo_float_beta_assign);
+    register_demo!(runner, demo_float_beta_assign_debug);
+    register_demo!(runner, demo_float_beta_assign_ref);
+    register_demo!(runner, demo_float_beta_assign_ref_debug);
+    register_demo!(runner, demo_float_beta_prec);
+    register_demo!(runner, demo_float_beta_prec_debug);
+    register_demo!(runner, demo_float_beta_prec_val_ref);
+    register_demo!(runner, demo_float_beta_prec_val_ref_debug);
+    register_demo!(runner, demo_float_beta_prec_ref_val);
+    register_demo!(runner, demo_float_beta_prec_ref_val_debug);
+    register_demo!(runner, demo_float_beta_prec_ref_ref);
+    register_demo!(runner, demo_float_beta_prec_ref_ref_debug);
+    register_demo!(runner, demo_float_beta_prec_assign);
+    register_demo!(runner, demo_float_beta_prec_assign_debug);
+    register_demo!(runner, demo_float_beta_prec_assign_ref);
+    register_demo!(runner, demo_float_beta_prec_assign_ref_debug);
+    register_demo!(runner, demo_float_beta_round);
+    register_demo!(runner, demo_float_beta_round_debug);
+    register_demo!(runner, demo_float_beta_round_val_ref);
+    register_demo!(runner, demo_float_beta_round_val_ref_debug);
+    register_demo!(runner, demo_float_beta_round_ref_val);
+    register_demo!(runner, demo_float_beta_round_ref_val_debug);
+    register_demo!(runner, demo_float_beta_round_ref_ref);
+    register_demo!(runner, demo_float_beta_round_ref_ref_debug);
+    register_demo!(runner, demo_float_beta_round_assign);
+    register_demo!(runner, demo_float_beta_round_assign_debug);
+    register_demo!(runner, demo_float_beta_round_assign_ref);
+    register_demo!(runner, demo_float_beta_round_assign_ref_debug);
+    register_demo!(runner, demo_float_beta_prec_round);
+    register_demo!(runner, demo_float_beta_prec_round_debug);
+    register_demo!(runner, demo_float_beta_prec_round_val_ref);
+    register_demo!(runner, demo_float_beta_prec_round_val_ref_debug);
+    register_demo!(runner, demo_float_beta_prec_round_ref_val);
+    register_demo!(runner, demo_float_beta_prec_round_ref_val_debug);
+    register_demo!(runner, demo_float_beta_prec_round_ref_ref);
+    register_demo!(runner, demo_float_beta_prec_round_ref_ref_debug);
+    register_demo!(runner, demo_float_beta_prec_round_assign);
+    register_demo!(runner, demo_float_beta_prec_round_assign_debug);
+    register_demo!(runner, demo_float_beta_prec_round_assign_ref);
+    register_demo!(runner, demo_float_beta_prec_round_assign_ref_debug);
+    register_primitive_float_demos!(runner, demo_primitive_float_beta);
+    register_demo!(runner, demo_beta_rational_prec);
+    register_demo!(runner, demo_beta_rational_prec_debug);
+    register_demo!(runner, demo_beta_rational_prec_val_ref);
+    register_demo!(runner, demo_beta_rational_prec_val_ref_debug);
+    register_demo!(runner, demo_beta_rational_prec_ref_val);
+    register_demo!(runner, demo_beta_rational_prec_ref_val_debug);
+    register_demo!(runner, demo_beta_rational_prec_ref_ref);
+    register_demo!(runner, demo_beta_rational_prec_ref_ref_debug);
+    register_demo!(runner, demo_beta_rational_prec_round);
+    register_demo!(runner, demo_beta_rational_prec_round_debug);
+    register_demo!(runner, demo_beta_rational_prec_round_val_ref);
+    register_demo!(runner, demo_beta_rational_prec_round_val_ref_debug);
+    register_demo!(runner, demo_beta_rational_prec_round_ref_val);
+    register_demo!(runner, demo_beta_rational_prec_round_ref_val_debug);
+    register_demo!(runner, demo_beta_rational_prec_round_ref_ref);
+    register_demo!(runner, demo_beta_rational_prec_round_ref_ref_debug);
+    register_primitive_float_demos!(runner, demo_primitive_float_beta_rational);
+
+    register_bench!(runner, benchmark_float_beta_evaluation_strategy);
+    register_bench!(runner, benchmark_float_beta_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_beta_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_beta_prec_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_beta_round_evaluation_strategy);
+    register_bench!(
+        runner,
+        benchmark_float_beta_round_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_beta_prec_round_evaluation_strategy);
+    register_bench!(
+        runner,
+        benchmark_float_beta_prec_round_assign_evaluation_strategy
+    );
+    register_primitive_float_benches!(runner, benchmark_primitive_float_beta);
+    register_bench!(runner, benchmark_beta_rational_prec_evaluation_strategy);
+    register_bench!(
+        runner,
+        benchmark_beta_rational_prec_round_evaluation_strategy
+    );
+    register_primitive_float_benches!(runner, benchmark_primitive_float_beta_rational);
+}
+
+fn demo_float_beta(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!("beta({}, {}) = {}", x_old, y_old, x.beta(y));
+    }
+}
+
+fn demo_float_beta_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "beta({:#x}, {:#x}) = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            ComparableFloat(x.beta(y))
+        );
+    }
+}
+
+fn demo_float_beta_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("beta({}, &{}) = {}", x_old, y, x.beta(&y));
+    }
+}
+
+fn demo_float_beta_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!(
+            "beta({:#x}, &{:#x}) = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloatRef(&y),
+            ComparableFloat(x.beta(&y))
+        );
+    }
+}
+
+fn demo_float_beta_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        println!("beta(&{}, {}) = {}", x, y_old, (&x).beta(y));
+    }
+}
+
+fn demo_float_beta_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        println!(
+            "beta(&{:#x}, {:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat(y_old),
+            ComparableFloat((&x).beta(y))
+        );
+    }
+}
+
+fn demo_float_beta_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!("beta(&{}, &{}) = {}", x, y, (&x).beta(&y));
+    }
+}
+
+fn demo_float_beta_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "beta(&{:#x}, &{:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat((&x).beta(&y))
+        );
+    }
+}
+
+fn demo_float_beta_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.beta_assign(y.clone());
+        println!("x := {x_old}; x.beta_assign({y}); x = {x}");
+    }
+}
+
+fn demo_float_beta_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.beta_assign(y.clone());
+        println!(
+            "x := {:#x}; x.beta_assign({:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_beta_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.beta_assign(&y);
+        println!("x := {x_old}; x.beta_assign(&{y}); x = {x}");
+    }
+}
+
+fn demo_float_beta_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.beta_assign(&y);
+        println!(
+            "x := {:#x}; x.beta_assign(&{:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_beta_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).beta_prec({}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            x.beta_prec(y, prec)
+        );
+    }
+}
+
+fn demo_float_beta_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (beta, o) = x.beta_prec(y, prec);
+        println!(
+            "({:#x}).beta_prec({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).beta_prec_val_ref(&{}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            x.beta_prec_val_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_beta_prec_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (beta, o) = x.beta_prec_val_ref(&y, prec);
+        println!(
+            "({:#x}).beta_prec_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).beta_prec_ref_val({}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            x.beta_prec_ref_val(y, prec)
+        );
+    }
+}
+
+fn demo_float_beta_prec_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (beta, o) = x.beta_prec_ref_val(y, prec);
+        println!(
+            "(&{:#x}).beta_prec_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).beta_prec_ref_ref(&{}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            x.beta_prec_ref_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_beta_prec_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (beta, o) = x.beta_prec_ref_ref(&y, prec);
+        println!(
+            "(&{:#x}).beta_prec_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.beta_prec_assign(y, prec);
+        println!("x := {x_old}; x.beta_prec_assign({y_old}, {prec}); x = {x}");
+    }
+}
+
+fn demo_float_beta_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.beta_prec_assign(y, prec);
+        println!(
+            "x := {:#x}; x.beta_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_beta_prec_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.beta_prec_assign_ref(&y, prec);
+        println!("x := {x_old}; x.beta_prec_assign({y}, {prec}); x = {x}");
+    }
+}
+
+fn demo_float_beta_prec_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.beta_prec_assign_ref(&y, prec);
+        println!(
+            "x := {:#x}; x.beta_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_beta_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).beta_round({}, {}) = {:?}",
+            x_old,
+            y_old,
+            rm,
+            x.beta_round(y, rm)
+        );
+    }
+}
+
+fn demo_float_beta_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (beta, o) = x.beta_round(y, rm);
+        println!(
+            "({:#x}).beta_round({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).beta_round_val_ref(&{}, {}) = {:?}",
+            x_old,
+            y,
+            rm,
+            x.beta_round_val_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_beta_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (beta, o) = x.beta_round_val_ref(&y, rm);
+        println!(
+            "({:#x}).beta_round_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).beta_round_ref_val({}, {}) = {:?}",
+            x,
+            y_old,
+            rm,
+            x.beta_round_ref_val(y, rm)
+        );
+    }
+}
+
+fn demo_float_beta_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (beta, o) = x.beta_round_ref_val(y, rm);
+        println!(
+            "(&{:#x}).beta_round_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).beta_round_ref_ref(&{}, {}) = {:?}",
+            x,
+            y,
+            rm,
+            x.beta_round_ref_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_beta_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (beta, o) = x.beta_round_ref_ref(&y, rm);
+        println!(
+            "(&{:#x}).beta_round_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.beta_round_assign(y, rm);
+        println!("x := {x_old}; x.beta_round_assign({y_old}, {rm}); x = {x}");
+    }
+}
+
+fn demo_float_beta_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.beta_round_assign(y, rm);
+        println!(
+            "x := {:#x}; x.beta_round_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_beta_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.beta_round_assign_ref(&y, rm);
+        println!("x := {x_old}; x.beta_round_assign({y}, {rm}); x = {x}");
+    }
+}
+
+fn demo_float_beta_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_46()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.beta_round_assign_ref(&y, rm);
+        println!(
+            "x := {:#x}; x.beta_round_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_beta_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).beta_prec_round({}, {}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            rm,
+            x.beta_prec_round(y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (beta, o) = x.beta_prec_round(y, prec, rm);
+        println!(
+            "({:#x}).beta_prec_round({:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).beta_prec_round(&{}, {}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            rm,
+            x.beta_prec_round_val_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (beta, o) = x.beta_prec_round_val_ref(&y, prec, rm);
+        println!(
+            "({:#x}).beta_prec_round_val_ref(&{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).beta_prec_round_ref_val({}, {}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            rm,
+            x.beta_prec_round_ref_val(y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (beta, o) = x.beta_prec_round_ref_val(y, prec, rm);
+        println!(
+            "(&{:#x}).beta_prec_round_ref_val({:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).beta_prec_round({}, {}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            rm,
+            x.beta_prec_round_ref_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (beta, o) = x.beta_prec_round_ref_ref(&y, prec, rm);
+        println!(
+            "({:#x}).beta_prec_round_ref_ref(&{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.beta_prec_round_assign(y, prec, rm);
+        println!("x := {x_old}; x.beta_prec_round({y_old}, {prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_beta_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.beta_prec_round_assign(y, prec, rm);
+        println!(
+            "x := {:#x}; x.beta_prec_round({:#x}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_beta_prec_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.beta_prec_round_assign_ref(&y, prec, rm);
+        println!("x := {x_old}; x.beta_prec_round_ref(&{y}, {prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_beta_prec_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.beta_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {:#x}; x.beta_prec_round_ref(&{:#x}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_beta<T: PrimitiveFloat>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for (x, y) in primitive_float_pair_gen::<T>().get(gm, config).take(limit) {
+        println!(
+            "primitive_float_beta({}, {}) = {}",
+            NiceFloat(x),
+            NiceFloat(y),
+            NiceFloat(primitive_float_beta(x, y))
+        );
+    }
+}
+
+fn demo_beta_rational_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "beta_rational_prec({}, {}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            Float::beta_rational_prec(x, y, prec)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (beta, o) = Float::beta_rational_prec(x, y, prec);
+        println!(
+            "beta_rational_prec({}, {}, {}) = ({:#x}, {:?})",
+            x_old,
+            y_old,
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_beta_rational_prec_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "beta_rational_prec_val_ref({}, {}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            Float::beta_rational_prec_val_ref(x, &y, prec)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (beta, o) = Float::beta_rational_prec_val_ref(x, &y, prec);
+        println!(
+            "beta_rational_prec_val_ref({}, {}, {}) = ({:#x}, {:?})",
+            x_old,
+            y,
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_beta_rational_prec_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "beta_rational_prec_ref_val({}, {}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            Float::beta_rational_prec_ref_val(&x, y, prec)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (beta, o) = Float::beta_rational_prec_ref_val(&x, y, prec);
+        println!(
+            "beta_rational_prec_ref_val({}, {}, {}) = ({:#x}, {:?})",
+            x,
+            y_old,
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_beta_rational_prec_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "beta_rational_prec_ref_ref({}, {}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            Float::beta_rational_prec_ref_ref(&x, &y, prec)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (beta, o) = Float::beta_rational_prec(x, y, prec);
+        println!(
+            "beta_rational_prec({}, {}, {}) = ({:#x}, {:?})",
+            x_old,
+            y_old,
+            prec,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "beta_rational_prec_round({}, {}, {}, {:?}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            rm,
+            Float::beta_rational_prec_round(x, y, prec, rm)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (beta, o) = Float::beta_rational_prec_round(x, y, prec, rm);
+        println!(
+            "beta_rational_prec_round({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x_old,
+            y_old,
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "beta_rational_prec_round_val_ref({}, {}, {}, {:?}) = {:?}",
+            x_old,
+            y,
+            prec,
+            rm,
+            Float::beta_rational_prec_round_val_ref(x, &y, prec, rm)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (beta, o) = Float::beta_rational_prec_round_val_ref(x, &y, prec, rm);
+        println!(
+            "beta_rational_prec_round_val_ref({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x_old,
+            y,
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "beta_rational_prec_round_ref_val({}, {}, {}, {:?}) = {:?}",
+            x,
+            y_old,
+            prec,
+            rm,
+            Float::beta_rational_prec_round_ref_val(&x, y, prec, rm)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (beta, o) = Float::beta_rational_prec_round_ref_val(&x, y, prec, rm);
+        println!(
+            "beta_rational_prec_round_ref_val({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x,
+            y_old,
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "beta_rational_prec_round_ref_ref({}, {}, {}, {:?}) = {:?}",
+            x,
+            y,
+            prec,
+            rm,
+            Float::beta_rational_prec_round_ref_ref(&x, &y, prec, rm)
+        );
+    }
+}
+
+fn demo_beta_rational_prec_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (beta, o) = Float::beta_rational_prec_round_ref_ref(&x, &y, prec, rm);
+        println!(
+            "beta_rational_prec_round_ref_ref({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x,
+            y,
+            prec,
+            rm,
+            ComparableFloat(beta),
+            o
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_beta_rational<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for (x, y) in rational_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "primitive_float_beta_rational({}, {}) = {}",
+            x,
+            y,
+            NiceFloat(primitive_float_beta_rational::<T>(&x, &y))
+        );
+    }
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_float_beta_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float.beta(Float)", &mut |(x, y)| no_out!(x.beta(y))),
+            ("Float.beta(&Float)", &mut |(x, y)| no_out!(x.beta(&y))),
+            ("(&Float).beta(Float)", &mut |(x, y)| no_out!((&x).beta(y))),
+            ("(&Float).beta(&Float)", &mut |(x, y)| {
+                no_out!((&x).beta(&y));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_beta_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta_assign(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float.beta_assign(Float)", &mut |(mut x, y)| {
+                x.beta_assign(y);
+            }),
+            ("Float.beta_assign(&Float)", &mut |(mut x, y)| {
+                x.beta_assign(&y);
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_beta_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta_prec(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.beta_prec(Float, u64)", &mut |(x, y, prec)| {
+                no_out!(x.beta_prec(y, prec));
+            }),
+            ("Float.beta_prec_val_ref(&Float, u64)", &mut |(
+                x,
+                y,
+                prec,
+            )| {
+                no_out!(x.beta_prec_val_ref(&y, prec));
+            }),
+            (
+                "(&Float).beta_prec_ref_val(Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.beta_prec_ref_val(y, prec)),
+            ),
+            (
+                "(&Float).beta_prec_ref_ref(&Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.beta_prec_ref_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_beta_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta_prec_assign(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.beta_prec_assign(Float, u64)", &mut |(
+                mut x,
+                y,
+                prec,
+            )| {
+                no_out!(x.beta_prec_assign(y, prec));
+            }),
+            (
+                "Float.beta_prec_assign_ref(&Float, u64)",
+                &mut |(mut x, y, prec)| no_out!(x.beta_prec_assign_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_beta_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta_round(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_46().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.beta_round(Float, RoundingMode)",
+                &mut |(x, y, rm)| {
+                    no_out!(x.beta_round(y, rm));
+                },
+            ),
+            (
+                "Float.beta_round_val_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.beta_round_val_ref(&y, rm)),
+            ),
+            (
+                "(&Float).beta_round_ref_val(Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.beta_round_ref_val(y, rm)),
+            ),
+            (
+                "(&Float).beta_round_ref_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.beta_round_ref_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_beta_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta_round_assign(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_46().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.beta_round_assign(Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.beta_round_assign(y, rm)),
+            ),
+            (
+                "Float.beta_round_assign_ref(&Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.beta_round_assign_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_beta_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_27().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.beta_prec_round(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.beta_prec_round(y, prec, rm)),
+            ),
+            (
+                "Float.beta_prec_round_val_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.beta_prec_round_val_ref(&y, prec, rm)),
+            ),
+            (
+                "(&Float).beta_prec_round_ref_val(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.beta_prec_round_ref_val(y, prec, rm)),
+            ),
+            (
+                "(&Float).beta_prec_round_ref_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.beta_prec_round_ref_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_beta_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.beta_prec_round_assign(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_27().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.beta_prec_round_assign(Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.beta_prec_round_assign(y, prec, rm)),
+            ),
+            (
+                "Float.beta_prec_round_assign_ref(&Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.beta_prec_round_assign_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_beta<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!("primitive_float_beta({})", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        primitive_float_pair_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_max_primitive_float_bucketer("x", "y"),
+        &mut [("malachite", &mut |(x, y)| {
+            no_out!(primitive_float_beta(x, y));
+        })],
+    );
+}
+
+fn benchmark_beta_rational_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::beta_rational_prec(Rational, Rational, u64)",
+        BenchmarkType::EvaluationStrategy,
+        rational_rational_unsigned_triple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_rational_rational_primitive_int_max_bit_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float::beta_rational_prec(Rational, Rational, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(Float::beta_rational_prec(x, y, prec));
+                },
+            ),
+            (
+                "Float::beta_rational_prec_val_ref(Rational, &Rational, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(Float::beta_rational_prec_val_ref(x, &y, prec));
+                },
+            ),
+            (
+                "Float::beta_rational_prec_ref_val(&Rational, Rational, u64)",
+                &mut |(x, y, prec)| no_out!(Float::beta_rational_prec_ref_val(&x, y, prec)),
+            ),
+            (
+                "Float::beta_rational_prec_ref_ref(&Rational, &Rational, u64)",
+                &mut |(x, y, prec)| no_out!(Float::beta_rational_prec_ref_ref(&x, &y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_beta_rational_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::beta_rational_prec_round(Rational, Rational, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        rational_rational_unsigned_rounding_mode_quadruple_gen_var_4().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_rational_rational_primitive_int_max_bit_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float::beta_rational_prec_round(Rational, Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::beta_rational_prec_round(x, y, prec, rm));
+                },
+            ),
+            (
+                "Float::beta_rational_prec_round_val_ref(Rational, &Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::beta_rational_prec_round_val_ref(x, &y, prec, rm));
+                },
+            ),
+            (
+                "Float::beta_rational_prec_round_ref_val(&Rational, Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::beta_rational_prec_round_ref_val(&x, y, prec, rm));
+                },
+            ),
+            (
+                "Float::beta_rational_prec_round_ref_ref(&Rational, &Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::beta_rational_prec_round_ref_ref(&x, &y, prec, rm));
+                },
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_beta_rational<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!(
+            "primitive_float_beta_rational_prec::<{}>(Rational, Rational)",
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        rational_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_rational_max_bit_bucketer("x", "y"),
+        &mut [("Malachite", &mut |(x, y)| {
+            no_out!(primitive_float_beta_rational::<T>(&x, &y));
+        })],
+    );
+}
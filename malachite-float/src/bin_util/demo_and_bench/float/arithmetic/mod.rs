@@ -10,18 +10,42 @@ use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
     abs::register(runner);
+    acos::register(runner);
+    acosh::register(runner);
     add::register(runner);
     add_mul::register(runner);
     agm::register(runner);
+    airy_ai::register(runner);
+    asin::register(runner);
+    asinh::register(runner);
+    atan::register(runner);
+    atan2::register(runner);
+    atanh::register(runner);
     average::register(runner);
+    bessel_j0::register(runner);
+    bessel_j1::register(runner);
+    bessel_jn::register(runner);
+    bessel_y0::register(runner);
+    bessel_y1::register(runner);
+    bessel_yn::register(runner);
+    beta::register(runner);
     cbrt::register(runner);
+    cos::register(runner);
+    cosh::register(runner);
+    coth::register(runner);
+    csch::register(runner);
+    digamma::register(runner);
     div::register(runner);
+    erf::register(runner);
+    erfc::register(runner);
     exp::register(runner);
     exp_x_minus_1::register(runner);
+    gamma::register(runner);
     hypot::register(runner);
     is_power_of_2::register(runner);
     ln::register(runner);
     ln_1_plus_x::register(runner);
+    ln_gamma::register(runner);
     log_base::register(runner);
     log_base_10::register(runner);
     log_base_10_1_plus_x::register(runner);
@@ -36,6 +60,7 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base_rational_base_1_plus_x::register(runner);
     log_base_rational_float_base::register(runner);
     log_base_rational_rational_base::register(runner);
+    lower_incomplete_gamma::register(runner);
     mul::register(runner);
     mul_add_mul::register(runner);
     mul_sub_mul::register(runner);
@@ -49,30 +74,63 @@ pub(crate) fn register(runner: &mut Runner) {
     reciprocal::register(runner);
     reciprocal_sqrt::register(runner);
     root::register(runner);
+    sech::register(runner);
     shl::register(runner);
     shl_round::register(runner);
     shr::register(runner);
     shr_round::register(runner);
     sign::register(runner);
+    sin::register(runner);
+    sin_cos::register(runner);
+    sinh::register(runner);
+    sinh_cosh::register(runner);
     sqrt::register(runner);
     square::register(runner);
     sub::register(runner);
     sub_mul::register(runner);
+    tan::register(runner);
+    tanh::register(runner);
+    upper_incomplete_gamma::register(runner);
+    zeta::register(runner);
 }
 
 mod abs;
+mod acos;
+mod acosh;
 mod add;
 mod add_mul;
 mod agm;
+mod airy_ai;
+mod asin;
+mod asinh;
+mod atan;
+mod atan2;
+mod atanh;
 mod average;
+mod bessel_j0;
+mod bessel_j1;
+mod bessel_jn;
+mod bessel_y0;
+mod bessel_y1;
+mod bessel_yn;
+mod beta;
 mod cbrt;
+mod cos;
+mod cosh;
+mod coth;
+mod csch;
+mod digamma;
 mod div;
+mod erf;
+mod erfc;
 mod exp;
 mod exp_x_minus_1;
+mod gamma;
 mod hypot;
 mod is_power_of_2;
 mod ln;
 mod ln_1_plus_x;
+mod ln_gamma;
 mod log_base;
 mod log_base_10;
 mod log_base_10_1_plus_x;
@@ -87,6 +145,7 @@ mod log_base_rational_base;
 mod log_base_rational_base_1_plus_x;
 mod log_base_rational_float_base;
 mod log_base_rational_rational_base;
+mod lower_incomplete_gamma;
 mod mul;
 mod mul_add_mul;
 mod mul_sub_mul;
@@ -100,12 +159,21 @@ mod power_of_2_x_minus_1;
 mod reciprocal;
 mod reciprocal_sqrt;
 mod root;
+mod sech;
 mod shl;
 mod shl_round;
 mod shr;
 mod shr_round;
 mod sign;
+mod sin;
+mod sin_cos;
+mod sinh;
+mod sinh_cosh;
 mod sqrt;
 mod square;
 mod sub;
 mod sub_mul;
+mod tan;
+mod tanh;
+mod upper_incomplete_gamma;
+mod zeta;
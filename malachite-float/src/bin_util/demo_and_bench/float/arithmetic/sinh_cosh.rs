@@ -0,0 +1,530 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::SinhCosh;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::bench::bucketers::primitive_float_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::primitive_float_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_float::Float;
+use malachite_float::float::arithmetic::sinh_cosh::primitive_float_sinh_cosh;
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_float_complexity_bucketer, pair_2_float_complexity_bucketer,
+    pair_2_pair_1_float_complexity_bucketer,
+    pair_2_pair_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer,
+    pair_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::float::arithmetic::sinh_cosh::{
+    rug_sinh_cosh, rug_sinh_cosh_prec, rug_sinh_cosh_prec_round, rug_sinh_cosh_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_rm, float_rounding_mode_pair_gen_var_44_rm,
+    float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_pair_gen_var_1_rm, float_unsigned_rounding_mode_triple_gen_var_31_rm,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_q::test_util::bench::bucketers::{
+    pair_rational_bit_u64_max_bucketer, triple_1_2_rational_bit_u64_max_bucketer,
+};
+use malachite_q::test_util::generators::rational_unsigned_pair_gen_var_3;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_sinh_cosh);
+    register_demo!(runner, demo_float_sinh_cosh_ref);
+    register_demo!(runner, demo_float_sinh_cosh_prec);
+    register_demo!(runner, demo_float_sinh_cosh_prec_ref);
+    register_demo!(runner, demo_float_sinh_cosh_round);
+    register_demo!(runner, demo_float_sinh_cosh_round_ref);
+    register_demo!(runner, demo_float_sinh_cosh_prec_round);
+    register_demo!(runner, demo_float_sinh_cosh_prec_round_ref);
+    register_demo!(runner, demo_float_sinh_cosh_rational_prec);
+    register_demo!(runner, demo_float_sinh_cosh_rational_prec_ref);
+    register_demo!(runner, demo_float_sinh_cosh_rational_prec_round);
+    register_demo!(runner, demo_float_sinh_cosh_rational_prec_round_ref);
+    register_primitive_float_demos!(runner, demo_primitive_float_sinh_cosh);
+
+    register_bench!(runner, benchmark_float_sinh_cosh_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sinh_cosh_library_comparison);
+    register_bench!(runner, benchmark_float_sinh_cosh_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sinh_cosh_prec_library_comparison);
+    register_bench!(runner, benchmark_float_sinh_cosh_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sinh_cosh_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_sinh_cosh_prec_round_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_sinh_cosh_prec_round_library_comparison
+    );
+    register_bench!(
+        runner,
+        benchmark_float_sinh_cosh_rational_prec_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_sinh_cosh_rational_prec_round_evaluation_strategy
+    );
+    register_primitive_float_benches!(runner, benchmark_primitive_float_sinh_cosh);
+}
+
+fn demo_float_sinh_cosh_rational_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p) in rational_unsigned_pair_gen_var_3()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::sinh_cosh_rational_prec({}, {}) = {:?}",
+            n.clone(),
+            p,
+            Float::sinh_cosh_rational_prec(n, p)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_rational_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p) in rational_unsigned_pair_gen_var_3()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::sinh_cosh_rational_prec_ref(&{}, {}) = {:?}",
+            n,
+            p,
+            Float::sinh_cosh_rational_prec_ref(&n, p)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_rational_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p, rm) in rational_unsigned_rounding_mode_triple_gen_var_10()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::sinh_cosh_rational_prec_round({}, {}, {:?}) = {:?}",
+            n.clone(),
+            p,
+            rm,
+            Float::sinh_cosh_rational_prec_round(n, p, rm)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_rational_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, p, rm) in rational_unsigned_rounding_mode_triple_gen_var_10()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Float::sinh_cosh_rational_prec_round_ref(&{}, {}, {:?}) = {:?}",
+            n,
+            p,
+            rm,
+            Float::sinh_cosh_rational_prec_round_ref(&n, p, rm)
+        );
+    }
+}
+
+fn benchmark_float_sinh_cosh_rational_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::sinh_cosh_rational_prec(Rational, u64)",
+        BenchmarkType::EvaluationStrategy,
+        rational_unsigned_pair_gen_var_3().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_rational_bit_u64_max_bucketer("n", "prec"),
+        &mut [
+            (
+                "Float::sinh_cosh_rational_prec(Rational, u64)",
+                &mut |(n, prec)| no_out!(Float::sinh_cosh_rational_prec(n, prec)),
+            ),
+            (
+                "Float::sinh_cosh_rational_prec_ref(&Rational, u64)",
+                &mut |(n, prec)| no_out!(Float::sinh_cosh_rational_prec_ref(&n, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_rational_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::sinh_cosh_rational_prec_round(Rational, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        rational_unsigned_rounding_mode_triple_gen_var_10().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_rational_bit_u64_max_bucketer("n", "prec"),
+        &mut [
+            (
+                "Float::sinh_cosh_rational_prec_round(Rational, u64, RoundingMode)",
+                &mut |(n, prec, rm)| no_out!(Float::sinh_cosh_rational_prec_round(n, prec, rm)),
+            ),
+            (
+                "Float::sinh_cosh_rational_prec_round_ref(&Rational, u64, RoundingMode)",
+                &mut |(n, prec, rm)| {
+                    no_out!(Float::sinh_cosh_rational_prec_round_ref(&n, prec, rm));
+                },
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_sinh_cosh<T: PrimitiveFloat>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for x in primitive_float_gen::<T>().get(gm, config).take(limit) {
+        let (sinh, cosh) = primitive_float_sinh_cosh(x);
+        println!(
+            "primitive_float_sinh_cosh({}) = ({}, {})",
+            NiceFloat(x),
+            NiceFloat(sinh),
+            NiceFloat(cosh)
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_sinh_cosh<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!("primitive_float_sinh_cosh({})", T::NAME),
+        BenchmarkType::Single,
+        primitive_float_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &primitive_float_bucketer("x"),
+        &mut [("malachite", &mut |x| {
+            no_out!(primitive_float_sinh_cosh(x));
+        })],
+    );
+}
+
+fn demo_float_sinh_cosh(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).sinh_cosh() = {:?}", x_old, x.sinh_cosh());
+    }
+}
+
+fn demo_float_sinh_cosh_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("(&{}).sinh_cosh() = {:?}", x, (&x).sinh_cosh());
+    }
+}
+
+fn demo_float_sinh_cosh_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!(
+            "({}).sinh_cosh_prec({}) = {:?}",
+            x_old,
+            prec,
+            x.sinh_cosh_prec(prec)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        println!(
+            "(&{}).sinh_cosh_prec_ref({}) = {:?}",
+            x,
+            prec,
+            x.sinh_cosh_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).sinh_cosh_round({}) = {:?}",
+            x_old,
+            rm,
+            x.sinh_cosh_round(rm)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sinh_cosh_round_ref({}) = {:?}",
+            x,
+            rm,
+            x.sinh_cosh_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_36()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).sinh_cosh_prec_round({}, {}) = {:?}",
+            x_old,
+            prec,
+            rm,
+            x.sinh_cosh_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_sinh_cosh_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_36()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sinh_cosh_prec_round_ref({}, {}) = {:?}",
+            x,
+            prec,
+            rm,
+            x.sinh_cosh_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_float_sinh_cosh_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sinh_cosh()", &mut |x| no_out!(x.sinh_cosh())),
+            ("(&Float).sinh_cosh()", &mut |x| no_out!((&x).sinh_cosh())),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh()",
+        BenchmarkType::LibraryComparison,
+        float_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!((&x).sinh_cosh())),
+            ("rug", &mut |(x, _)| no_out!(rug_sinh_cosh(&x))),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Float.sinh_cosh_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.sinh_cosh_prec(prec));
+            }),
+            ("(&Float).sinh_cosh_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.sinh_cosh_prec_ref(prec));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| {
+                no_out!(x.sinh_cosh_prec_ref(prec));
+            }),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug_sinh_cosh_prec(&x, prec));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_47().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sinh_cosh_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.sinh_cosh_round(rm));
+            }),
+            (
+                "(&Float).sinh_cosh_round_ref(RoundingMode)",
+                &mut |(x, rm)| {
+                    no_out!(x.sinh_cosh_round_ref(rm));
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_44_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| {
+                no_out!(x.sinh_cosh_round_ref(rm));
+            }),
+            ("rug", &mut |((x, rm), _)| {
+                no_out!(rug_sinh_cosh_round(&x, rm));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_36().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.sinh_cosh_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sinh_cosh_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).sinh_cosh_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sinh_cosh_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sinh_cosh_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sinh_cosh_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_31_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.sinh_cosh_prec_round_ref(prec, rm));
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug_sinh_cosh_prec_round(&x, prec, rm));
+            }),
+        ],
+    );
+}
@@ -0,0 +1,1850 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{UpperIncompleteGamma, UpperIncompleteGammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::bench::bucketers::pair_max_primitive_float_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::primitive_float_pair_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_float::float::arithmetic::upper_incomplete_gamma::{
+    primitive_float_upper_incomplete_gamma, primitive_float_upper_incomplete_gamma_rational,
+};
+use malachite_float::test_util::bench::bucketers::{
+    pair_float_max_complexity_bucketer,
+    quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_max_complexity_bucketer,
+    triple_float_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_47,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_28, float_float_unsigned_triple_gen_var_1,
+    float_pair_gen, rational_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::test_util::bench::bucketers::{
+    pair_rational_max_bit_bucketer,
+    quadruple_1_2_3_rational_rational_primitive_int_max_bit_bucketer,
+    triple_rational_rational_primitive_int_max_bit_bucketer,
+};
+use malachite_q::test_util::generators::{
+    rational_pair_gen, rational_rational_unsigned_triple_gen_var_2,
+};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_upper_incomplete_gamma);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_val_ref);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_val_ref_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_ref_val);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_ref_val_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_ref_ref);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_ref_ref_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_assign);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_assign_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_assign_ref);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_assign_ref_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_val_ref);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_val_ref_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_ref_val);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_ref_val_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_ref_ref);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_ref_ref_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_assign);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_assign_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_assign_ref);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_prec_assign_ref_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round_val_ref);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_round_val_ref_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round_ref_val);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_round_ref_val_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round_ref_ref);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_round_ref_ref_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round_assign);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round_assign_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_round_assign_ref);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_round_assign_ref_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_round);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_round_debug);
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_round_val_ref);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_prec_round_val_ref_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_round_ref_val);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_prec_round_ref_val_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_round_ref_ref);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_prec_round_ref_ref_debug
+    );
+    register_demo!(runner, demo_float_upper_incomplete_gamma_prec_round_assign);
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_prec_round_assign_debug
+    );
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_prec_round_assign_ref
+    );
+    register_demo!(
+        runner,
+        demo_float_upper_incomplete_gamma_prec_round_assign_ref_debug
+    );
+    register_primitive_float_demos!(runner, demo_primitive_float_upper_incomplete_gamma);
+    register_demo!(runner, demo_upper_incomplete_gamma_rational_prec);
+    register_demo!(runner, demo_upper_incomplete_gamma_rational_prec_debug);
+    register_demo!(runner, demo_upper_incomplete_gamma_rational_prec_val_ref);
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_val_ref_debug
+    );
+    register_demo!(runner, demo_upper_incomplete_gamma_rational_prec_ref_val);
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_ref_val_debug
+    );
+    register_demo!(runner, demo_upper_incomplete_gamma_rational_prec_ref_ref);
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_ref_ref_debug
+    );
+    register_demo!(runner, demo_upper_incomplete_gamma_rational_prec_round);
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_round_debug
+    );
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_round_val_ref
+    );
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_round_val_ref_debug
+    );
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_round_ref_val
+    );
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_round_ref_val_debug
+    );
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_round_ref_ref
+    );
+    register_demo!(
+        runner,
+        demo_upper_incomplete_gamma_rational_prec_round_ref_ref_debug
+    );
+    register_primitive_float_demos!(runner, demo_primitive_float_upper_incomplete_gamma_rational);
+
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_assign_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_prec_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_prec_assign_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_round_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_round_assign_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_prec_round_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_upper_incomplete_gamma_prec_round_assign_evaluation_strategy
+    );
+    register_primitive_float_benches!(runner, benchmark_primitive_float_upper_incomplete_gamma);
+    register_bench!(
+        runner,
+        benchmark_upper_incomplete_gamma_rational_prec_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_upper_incomplete_gamma_rational_prec_round_evaluation_strategy
+    );
+    register_primitive_float_benches!(
+        runner,
+        benchmark_primitive_float_upper_incomplete_gamma_rational
+    );
+}
+
+fn demo_float_upper_incomplete_gamma(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma({}, {}) = {}",
+            x_old,
+            y_old,
+            x.upper_incomplete_gamma(y)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma({:#x}, {:#x}) = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            ComparableFloat(x.upper_incomplete_gamma(y))
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!(
+            "upper_incomplete_gamma({}, &{}) = {}",
+            x_old,
+            y,
+            x.upper_incomplete_gamma(&y)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!(
+            "upper_incomplete_gamma({:#x}, &{:#x}) = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloatRef(&y),
+            ComparableFloat(x.upper_incomplete_gamma(&y))
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma(&{}, {}) = {}",
+            x,
+            y_old,
+            (&x).upper_incomplete_gamma(y)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma(&{:#x}, {:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat(y_old),
+            ComparableFloat((&x).upper_incomplete_gamma(y))
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "upper_incomplete_gamma(&{}, &{}) = {}",
+            x,
+            y,
+            (&x).upper_incomplete_gamma(&y)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "upper_incomplete_gamma(&{:#x}, &{:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat((&x).upper_incomplete_gamma(&y))
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.upper_incomplete_gamma_assign(y.clone());
+        println!("x := {x_old}; x.upper_incomplete_gamma_assign({y}); x = {x}");
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.upper_incomplete_gamma_assign(y.clone());
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_assign({:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.upper_incomplete_gamma_assign(&y);
+        println!("x := {x_old}; x.upper_incomplete_gamma_assign(&{y}); x = {x}");
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_assign_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.upper_incomplete_gamma_assign(&y);
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_assign(&{:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).upper_incomplete_gamma_prec({}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            x.upper_incomplete_gamma_prec(y, prec)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec(y, prec);
+        println!(
+            "({:#x}).upper_incomplete_gamma_prec({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).upper_incomplete_gamma_prec_val_ref(&{}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            x.upper_incomplete_gamma_prec_val_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_val_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec_val_ref(&y, prec);
+        println!(
+            "({:#x}).upper_incomplete_gamma_prec_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).upper_incomplete_gamma_prec_ref_val({}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            x.upper_incomplete_gamma_prec_ref_val(y, prec)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_ref_val_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec_ref_val(y, prec);
+        println!(
+            "(&{:#x}).upper_incomplete_gamma_prec_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).upper_incomplete_gamma_prec_ref_ref(&{}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            x.upper_incomplete_gamma_prec_ref_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_ref_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec_ref_ref(&y, prec);
+        println!(
+            "(&{:#x}).upper_incomplete_gamma_prec_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.upper_incomplete_gamma_prec_assign(y, prec);
+        println!("x := {x_old}; x.upper_incomplete_gamma_prec_assign({y_old}, {prec}); x = {x}");
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_assign_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.upper_incomplete_gamma_prec_assign(y, prec);
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_assign_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.upper_incomplete_gamma_prec_assign_ref(&y, prec);
+        println!("x := {x_old}; x.upper_incomplete_gamma_prec_assign({y}, {prec}); x = {x}");
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_assign_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.upper_incomplete_gamma_prec_assign_ref(&y, prec);
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).upper_incomplete_gamma_round({}, {}) = {:?}",
+            x_old,
+            y_old,
+            rm,
+            x.upper_incomplete_gamma_round(y, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_round(y, rm);
+        println!(
+            "({:#x}).upper_incomplete_gamma_round({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).upper_incomplete_gamma_round_val_ref(&{}, {}) = {:?}",
+            x_old,
+            y,
+            rm,
+            x.upper_incomplete_gamma_round_val_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_val_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_round_val_ref(&y, rm);
+        println!(
+            "({:#x}).upper_incomplete_gamma_round_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).upper_incomplete_gamma_round_ref_val({}, {}) = {:?}",
+            x,
+            y_old,
+            rm,
+            x.upper_incomplete_gamma_round_ref_val(y, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_ref_val_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_round_ref_val(y, rm);
+        println!(
+            "(&{:#x}).upper_incomplete_gamma_round_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).upper_incomplete_gamma_round_ref_ref(&{}, {}) = {:?}",
+            x,
+            y,
+            rm,
+            x.upper_incomplete_gamma_round_ref_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_ref_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_round_ref_ref(&y, rm);
+        println!(
+            "(&{:#x}).upper_incomplete_gamma_round_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.upper_incomplete_gamma_round_assign(y, rm);
+        println!("x := {x_old}; x.upper_incomplete_gamma_round_assign({y_old}, {rm}); x = {x}");
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_assign_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.upper_incomplete_gamma_round_assign(y, rm);
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_round_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_assign_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.upper_incomplete_gamma_round_assign_ref(&y, rm);
+        println!("x := {x_old}; x.upper_incomplete_gamma_round_assign({y}, {rm}); x = {x}");
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_round_assign_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_47()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.upper_incomplete_gamma_round_assign_ref(&y, rm);
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_round_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).upper_incomplete_gamma_prec_round({}, {}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            rm,
+            x.upper_incomplete_gamma_prec_round(y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec_round(y, prec, rm);
+        println!(
+            "({:#x}).upper_incomplete_gamma_prec_round({:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_val_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).upper_incomplete_gamma_prec_round(&{}, {}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            rm,
+            x.upper_incomplete_gamma_prec_round_val_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_val_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec_round_val_ref(&y, prec, rm);
+        println!(
+            "({:#x}).upper_incomplete_gamma_prec_round_val_ref(&{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_ref_val(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).upper_incomplete_gamma_prec_round_ref_val({}, {}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            rm,
+            x.upper_incomplete_gamma_prec_round_ref_val(y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_ref_val_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec_round_ref_val(y, prec, rm);
+        println!(
+            "(&{:#x}).upper_incomplete_gamma_prec_round_ref_val({:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_ref_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).upper_incomplete_gamma_prec_round({}, {}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            rm,
+            x.upper_incomplete_gamma_prec_round_ref_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_ref_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (upper_incomplete_gamma, o) = x.upper_incomplete_gamma_prec_round_ref_ref(&y, prec, rm);
+        println!(
+            "({:#x}).upper_incomplete_gamma_prec_round_ref_ref(&{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.upper_incomplete_gamma_prec_round_assign(y, prec, rm);
+        println!(
+            "x := {x_old}; x.upper_incomplete_gamma_prec_round({y_old}, {prec}, {rm}) = {o:?}; x = {x}"
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_assign_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.upper_incomplete_gamma_prec_round_assign(y, prec, rm);
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_prec_round({:#x}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_assign_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.upper_incomplete_gamma_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {x_old}; x.upper_incomplete_gamma_prec_round_ref(&{y}, {prec}, {rm}) = {o:?}; x = {x}"
+        );
+    }
+}
+
+fn demo_float_upper_incomplete_gamma_prec_round_assign_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.upper_incomplete_gamma_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {:#x}; x.upper_incomplete_gamma_prec_round_ref(&{:#x}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_upper_incomplete_gamma<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for (x, y) in primitive_float_pair_gen::<T>().get(gm, config).take(limit) {
+        println!(
+            "primitive_float_upper_incomplete_gamma({}, {}) = {}",
+            NiceFloat(x),
+            NiceFloat(y),
+            NiceFloat(primitive_float_upper_incomplete_gamma(x, y))
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma_rational_prec({}, {}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            Float::upper_incomplete_gamma_rational_prec(x, y, prec)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = Float::upper_incomplete_gamma_rational_prec(x, y, prec);
+        println!(
+            "upper_incomplete_gamma_rational_prec({}, {}, {}) = ({:#x}, {:?})",
+            x_old,
+            y_old,
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_val_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "upper_incomplete_gamma_rational_prec_val_ref({}, {}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            Float::upper_incomplete_gamma_rational_prec_val_ref(x, &y, prec)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_val_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (upper_incomplete_gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_val_ref(x, &y, prec);
+        println!(
+            "upper_incomplete_gamma_rational_prec_val_ref({}, {}, {}) = ({:#x}, {:?})",
+            x_old,
+            y,
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_ref_val(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma_rational_prec_ref_val({}, {}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            Float::upper_incomplete_gamma_rational_prec_ref_val(&x, y, prec)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_ref_val_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_ref_val(&x, y, prec);
+        println!(
+            "upper_incomplete_gamma_rational_prec_ref_val({}, {}, {}) = ({:#x}, {:?})",
+            x,
+            y_old,
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_ref_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "upper_incomplete_gamma_rational_prec_ref_ref({}, {}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            Float::upper_incomplete_gamma_rational_prec_ref_ref(&x, &y, prec)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_ref_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec) in rational_rational_unsigned_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) = Float::upper_incomplete_gamma_rational_prec(x, y, prec);
+        println!(
+            "upper_incomplete_gamma_rational_prec({}, {}, {}) = ({:#x}, {:?})",
+            x_old,
+            y_old,
+            prec,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma_rational_prec_round({}, {}, {}, {:?}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            rm,
+            Float::upper_incomplete_gamma_rational_prec_round(x, y, prec, rm)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round(x, y, prec, rm);
+        println!(
+            "upper_incomplete_gamma_rational_prec_round({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x_old,
+            y_old,
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round_val_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "upper_incomplete_gamma_rational_prec_round_val_ref({}, {}, {}, {:?}) = {:?}",
+            x_old,
+            y,
+            prec,
+            rm,
+            Float::upper_incomplete_gamma_rational_prec_round_val_ref(x, &y, prec, rm)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round_val_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (upper_incomplete_gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round_val_ref(x, &y, prec, rm);
+        println!(
+            "upper_incomplete_gamma_rational_prec_round_val_ref({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x_old,
+            y,
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round_ref_val(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "upper_incomplete_gamma_rational_prec_round_ref_val({}, {}, {}, {:?}) = {:?}",
+            x,
+            y_old,
+            prec,
+            rm,
+            Float::upper_incomplete_gamma_rational_prec_round_ref_val(&x, y, prec, rm)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round_ref_val_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (upper_incomplete_gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round_ref_val(&x, y, prec, rm);
+        println!(
+            "upper_incomplete_gamma_rational_prec_round_ref_val({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x,
+            y_old,
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round_ref_ref(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "upper_incomplete_gamma_rational_prec_round_ref_ref({}, {}, {}, {:?}) = {:?}",
+            x,
+            y,
+            prec,
+            rm,
+            Float::upper_incomplete_gamma_rational_prec_round_ref_ref(&x, &y, prec, rm)
+        );
+    }
+}
+
+fn demo_upper_incomplete_gamma_rational_prec_round_ref_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (x, y, prec, rm) in rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (upper_incomplete_gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round_ref_ref(&x, &y, prec, rm);
+        println!(
+            "upper_incomplete_gamma_rational_prec_round_ref_ref({}, {}, {}, {:?}) = ({:#x}, {:?})",
+            x,
+            y,
+            prec,
+            rm,
+            ComparableFloat(upper_incomplete_gamma),
+            o
+        );
+    }
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn demo_primitive_float_upper_incomplete_gamma_rational<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    for (x, y) in rational_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "primitive_float_upper_incomplete_gamma_rational({}, {}) = {}",
+            x,
+            y,
+            NiceFloat(primitive_float_upper_incomplete_gamma_rational::<T>(&x, &y))
+        );
+    }
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_float_upper_incomplete_gamma_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float.upper_incomplete_gamma(Float)", &mut |(x, y)| {
+                no_out!(x.upper_incomplete_gamma(y));
+            }),
+            ("Float.upper_incomplete_gamma(&Float)", &mut |(x, y)| {
+                no_out!(x.upper_incomplete_gamma(&y));
+            }),
+            ("(&Float).upper_incomplete_gamma(Float)", &mut |(x, y)| {
+                no_out!((&x).upper_incomplete_gamma(y));
+            }),
+            ("(&Float).upper_incomplete_gamma(&Float)", &mut |(x, y)| {
+                no_out!((&x).upper_incomplete_gamma(&y));
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_upper_incomplete_gamma_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma_assign(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.upper_incomplete_gamma_assign(Float)",
+                &mut |(mut x, y)| {
+                    x.upper_incomplete_gamma_assign(y);
+                },
+            ),
+            (
+                "Float.upper_incomplete_gamma_assign(&Float)",
+                &mut |(mut x, y)| {
+                    x.upper_incomplete_gamma_assign(&y);
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_upper_incomplete_gamma_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma_prec(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.upper_incomplete_gamma_prec(Float, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(x.upper_incomplete_gamma_prec(y, prec));
+                },
+            ),
+            (
+                "Float.upper_incomplete_gamma_prec_val_ref(&Float, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(x.upper_incomplete_gamma_prec_val_ref(&y, prec));
+                },
+            ),
+            (
+                "(&Float).upper_incomplete_gamma_prec_ref_val(Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.upper_incomplete_gamma_prec_ref_val(y, prec)),
+            ),
+            (
+                "(&Float).upper_incomplete_gamma_prec_ref_ref(&Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.upper_incomplete_gamma_prec_ref_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_upper_incomplete_gamma_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma_prec_assign(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.upper_incomplete_gamma_prec_assign(Float, u64)",
+                &mut |(mut x, y, prec)| {
+                    no_out!(x.upper_incomplete_gamma_prec_assign(y, prec));
+                },
+            ),
+            (
+                "Float.upper_incomplete_gamma_prec_assign_ref(&Float, u64)",
+                &mut |(mut x, y, prec)| no_out!(x.upper_incomplete_gamma_prec_assign_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_upper_incomplete_gamma_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma_round(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_47().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.upper_incomplete_gamma_round(Float, RoundingMode)",
+                &mut |(x, y, rm)| {
+                    no_out!(x.upper_incomplete_gamma_round(y, rm));
+                },
+            ),
+            (
+                "Float.upper_incomplete_gamma_round_val_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.upper_incomplete_gamma_round_val_ref(&y, rm)),
+            ),
+            (
+                "(&Float).upper_incomplete_gamma_round_ref_val(Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.upper_incomplete_gamma_round_ref_val(y, rm)),
+            ),
+            (
+                "(&Float).upper_incomplete_gamma_round_ref_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.upper_incomplete_gamma_round_ref_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_upper_incomplete_gamma_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma_round_assign(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_47().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.upper_incomplete_gamma_round_assign(Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.upper_incomplete_gamma_round_assign(y, rm)),
+            ),
+            (
+                "Float.upper_incomplete_gamma_round_assign_ref(&Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.upper_incomplete_gamma_round_assign_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_upper_incomplete_gamma_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.upper_incomplete_gamma_prec_round(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.upper_incomplete_gamma_prec_round(y, prec, rm)),
+            ),
+            (
+                "Float.upper_incomplete_gamma_prec_round_val_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(x.upper_incomplete_gamma_prec_round_val_ref(&y, prec, rm));
+                },
+            ),
+            (
+                "(&Float).upper_incomplete_gamma_prec_round_ref_val(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(x.upper_incomplete_gamma_prec_round_ref_val(y, prec, rm));
+                },
+            ),
+            (
+                "(&Float).upper_incomplete_gamma_prec_round_ref_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(x.upper_incomplete_gamma_prec_round_ref_ref(&y, prec, rm));
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_upper_incomplete_gamma_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.upper_incomplete_gamma_prec_round_assign(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.upper_incomplete_gamma_prec_round_assign(Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| {
+                    no_out!(x.upper_incomplete_gamma_prec_round_assign(y, prec, rm));
+                },
+            ),
+            (
+                "Float.upper_incomplete_gamma_prec_round_assign_ref(&Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| {
+                    no_out!(x.upper_incomplete_gamma_prec_round_assign_ref(&y, prec, rm));
+                },
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_upper_incomplete_gamma<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!("primitive_float_upper_incomplete_gamma({})", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        primitive_float_pair_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_max_primitive_float_bucketer("x", "y"),
+        &mut [("malachite", &mut |(x, y)| {
+            no_out!(primitive_float_upper_incomplete_gamma(x, y));
+        })],
+    );
+}
+
+fn benchmark_upper_incomplete_gamma_rational_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::upper_incomplete_gamma_rational_prec(Rational, Rational, u64)",
+        BenchmarkType::EvaluationStrategy,
+        rational_rational_unsigned_triple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_rational_rational_primitive_int_max_bit_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float::upper_incomplete_gamma_rational_prec(Rational, Rational, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec(x, y, prec));
+                },
+            ),
+            (
+                "Float::upper_incomplete_gamma_rational_prec_val_ref(Rational, &Rational, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec_val_ref(
+                        x, &y, prec
+                    ));
+                },
+            ),
+            (
+                "Float::upper_incomplete_gamma_rational_prec_ref_val(&Rational, Rational, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec_ref_val(
+                        &x, y, prec
+                    ));
+                },
+            ),
+            (
+                "Float::upper_incomplete_gamma_rational_prec_ref_ref(&Rational, &Rational, u64)",
+                &mut |(x, y, prec)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec_ref_ref(
+                        &x, &y, prec
+                    ));
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_upper_incomplete_gamma_rational_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::upper_incomplete_gamma_rational_prec_round(Rational, Rational, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        rational_rational_unsigned_rounding_mode_quadruple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_rational_rational_primitive_int_max_bit_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float::upper_incomplete_gamma_rational_prec_round(Rational, Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec_round(
+                        x, y, prec, rm
+                    ));
+                },
+            ),
+            (
+                "Float::upper_incomplete_gamma_rational_prec_round_val_ref(Rational, &Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec_round_val_ref(
+                        x, &y, prec, rm
+                    ));
+                },
+            ),
+            (
+                "Float::upper_incomplete_gamma_rational_prec_round_ref_val(&Rational, Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec_round_ref_val(
+                        &x, y, prec, rm
+                    ));
+                },
+            ),
+            (
+                "Float::upper_incomplete_gamma_rational_prec_round_ref_ref(&Rational, &Rational, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| {
+                    no_out!(Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+                        &x, &y, prec, rm
+                    ));
+                },
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn benchmark_primitive_float_upper_incomplete_gamma_rational<T: PrimitiveFloat>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    run_benchmark(
+        &format!(
+            "primitive_float_upper_incomplete_gamma_rational_prec::<{}>(Rational, Rational)",
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        rational_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_rational_max_bit_bucketer("x", "y"),
+        &mut [("Malachite", &mut |(x, y)| {
+            no_out!(primitive_float_upper_incomplete_gamma_rational::<T>(&x, &y));
+        })],
+    );
+}
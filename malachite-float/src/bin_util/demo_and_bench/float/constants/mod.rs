@@ -9,10 +9,13 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    apery::register(runner);
+    catalan::register(runner);
     cbrt_2::register(runner);
     champernowne_constant::register(runner);
     copeland_erdos_constant::register(runner);
     e::register(runner);
+    euler_gamma::register(runner);
     gauss_constant::register(runner);
     gelfond_schneider_constant::register(runner);
     gelfonds_constant::register(runner);
@@ -49,10 +52,13 @@ pub(crate) fn register(runner: &mut Runner) {
     two_over_sqrt_pi::register(runner);
 }
 
+mod apery;
+mod catalan;
 mod cbrt_2;
 mod champernowne_constant;
 mod copeland_erdos_constant;
 mod e;
+mod euler_gamma;
 mod gauss_constant;
 mod gelfond_schneider_constant;
 mod gelfonds_constant;
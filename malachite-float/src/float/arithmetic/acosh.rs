@@ -0,0 +1,1069 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::sqrt_bounds;
+use crate::float::arithmetic::sin_cos::{
+    initial_working_prec, rational_exponent, round_with_bounds,
+};
+use crate::float::arithmetic::sinh_cosh::ln_1_plus_x_bounds;
+use crate::floor_and_ceiling;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Acosh, AcoshAssign, PowerOf2};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, One, Two, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+// Returns bounds on acosh(x), for a rational x > 1 with exponent `exp_x`, with a relative width of
+// about `2 ^ (-w)`.
+fn acosh_bounds(x: &Rational, exp_x: i64, w: u64) -> (Rational, Rational) {
+    if u64::exact_from(exp_x << 1) > w + 16 {
+        // acosh(x) = log(2x) - ε, with 0 < ε < 1 / x ^ 2 <= 2 ^ (2 - 2 * exp_x) < 2 ^ (-w - 14).
+        // This also keeps the intermediate values of the general case below from overflowing.
+        let (lo, hi) = floor_and_ceiling(Float::ln_rational_prec_round_ref(&(x << 1u32), w, Floor));
+        (
+            Rational::exact_from(lo) - Rational::power_of_2(-i64::exact_from(w + 14)),
+            Rational::exact_from(hi),
+        )
+    } else {
+        // acosh(x) = log(1 + e), where e = d + sqrt(d * (d + 2)) and d = x - 1
+        let d = x - Rational::ONE;
+        let (s_lo, s_hi) = sqrt_bounds(&(&d * (&d + Rational::TWO)), w + 8);
+        ln_1_plus_x_bounds(&(&d + s_lo), &(&d + s_hi), w)
+    }
+}
+
+// Computes acosh(x) for a rational x.
+fn acosh_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match x.cmp(&Rational::ONE) {
+        Less => return (Float::NAN, Equal),
+        Equal => return (Float::ZERO, Equal),
+        Greater => {}
+    }
+    assert_ne!(rm, Exact, "Inexact acosh");
+    let exp_x = rational_exponent(x);
+    round_with_bounds(prec, rm, |w| Some(acosh_bounds(x, exp_x, w)))
+}
+
+// Computes acosh(x) for a finite, nonzero `Float` x. A huge x is not converted to a `Rational`,
+// whose numerator would have as many bits as x's exponent; instead, acosh(x) = log(x) + log(2) - ε,
+// as in `acosh_bounds`, is computed from x directly.
+fn acosh_float_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exp_x = i64::from(x.get_exponent().unwrap());
+    if exp_x <= 0 || u64::exact_from(exp_x << 1) <= initial_working_prec(prec) + 16 {
+        return acosh_rational_helper(&Rational::exact_from(x), prec, rm);
+    }
+    if *x < 0u32 {
+        return (Float::NAN, Equal);
+    }
+    assert_ne!(rm, Exact, "Inexact acosh");
+    round_with_bounds(prec, rm, |w| {
+        Some(if u64::exact_from(exp_x << 1) > w + 16 {
+            let (lo, hi) = floor_and_ceiling(x.ln_prec_round_ref(w, Floor));
+            let (ln_2_lo, ln_2_hi) = floor_and_ceiling(Float::ln_2_prec_round(w, Floor));
+            (
+                Rational::exact_from(lo) + Rational::exact_from(ln_2_lo)
+                    - Rational::power_of_2(-i64::exact_from(w + 14)),
+                Rational::exact_from(hi) + Rational::exact_from(ln_2_hi),
+            )
+        } else {
+            // Now `w` is at least 2 * exp_x - 16, so the conversion is cheap by comparison
+            acosh_bounds(&Rational::exact_from(x), exp_x, w)
+        })
+    })
+}
+
+impl Float {
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], rounding
+    /// the result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic cosine is less than, equal to, or greater than the exact inverse hyperbolic
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{acosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{acosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{acosh}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{acosh}
+    ///   x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(-\infty,p,m)=\text{NaN}$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<1$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(1.0,p,m)=0.0$
+    ///
+    /// The result is never negative, and is zero only when $x=1$. Neither overflow nor underflow is
+    /// possible.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round`] instead. If both of these things are true, consider using
+    /// [`Float::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than 1 and inputs that
+    /// produce `NaN` or $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round(5, Floor);
+    /// assert_eq!(acosh.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round(5, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round(5, Nearest);
+    /// assert_eq!(acosh.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round(20, Floor);
+    /// assert_eq!(acosh.to_string(), "0.96242332");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round(20, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.96242428");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round(20, Nearest);
+    /// assert_eq!(acosh.to_string(), "0.96242332");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.acosh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], rounding
+    /// the result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic cosine is less than, equal to, or greater than the exact inverse hyperbolic
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).acosh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than 1 and inputs that
+    /// produce `NaN` or $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round_ref(5, Floor);
+    /// assert_eq!(acosh.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round_ref(5, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round_ref(5, Nearest);
+    /// assert_eq!(acosh.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round_ref(20, Floor);
+    /// assert_eq!(acosh.to_string(), "0.96242332");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round_ref(20, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.96242428");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_round_ref(20, Nearest);
+    /// assert_eq!(acosh.to_string(), "0.96242332");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn acosh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { sign: false } | Zero { .. } => (Self::NAN, Equal),
+            Infinity { sign: true } => (Self::INFINITY, Equal),
+            Finite { .. } => acosh_float_helper(self, prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], rounding
+    /// the result to the nearest value of the specified precision. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic cosine
+    /// is less than, equal to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec(5);
+    /// assert_eq!(acosh.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec(20);
+    /// assert_eq!(acosh.to_string(), "0.96242332");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec(self, prec: u64) -> (Self, Ordering) {
+        self.acosh_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], rounding
+    /// the result to the nearest value of the specified precision. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic cosine is less than, equal to, or greater than the exact inverse hyperbolic
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).acosh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_ref(5);
+    /// assert_eq!(acosh.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(1.5).acosh_prec_ref(20);
+    /// assert_eq!(acosh.to_string(), "0.96242332");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.acosh_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], rounding
+    /// the result to the precision of the input and with the specified rounding mode. The [`Float`]
+    /// is taken by value. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic cosine is less than, equal to, or greater than the exact inverse hyperbolic
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acosh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than 1 and inputs that produce `NaN` or
+    /// $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::from(PI).acosh_round(Floor);
+    /// assert_eq!(acosh.to_string(), "1.8115262724608527");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::from(PI).acosh_round(Ceiling);
+    /// assert_eq!(acosh.to_string(), "1.8115262724608545");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(PI).acosh_round(Nearest);
+    /// assert_eq!(acosh.to_string(), "1.8115262724608527");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acosh_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], rounding
+    /// the result to the precision of the input and with the specified rounding mode. The [`Float`]
+    /// is taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// inverse hyperbolic cosine is less than, equal to, or greater than the exact inverse
+    /// hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acosh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).acosh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than 1 and inputs that produce `NaN` or
+    /// $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::from(PI).acosh_round_ref(Floor);
+    /// assert_eq!(acosh.to_string(), "1.8115262724608527");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::from(PI).acosh_round_ref(Ceiling);
+    /// assert_eq!(acosh.to_string(), "1.8115262724608545");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::from(PI).acosh_round_ref(Nearest);
+    /// assert_eq!(acosh.to_string(), "1.8115262724608527");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acosh_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], in place,
+    /// rounding the result to the specified precision and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded inverse hyperbolic cosine is less
+    /// than, equal to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::acosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than 1 and inputs that
+    /// produce `NaN` or $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// assert_eq!(x.acosh_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.96242332");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// assert_eq!(x.acosh_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.96242428");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// assert_eq!(x.acosh_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.96242332");
+    /// ```
+    #[inline]
+    pub fn acosh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.acosh_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], in place,
+    /// rounding the result to the nearest value of the specified precision. An [`Ordering`] is
+    /// returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal to,
+    /// or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::acosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// assert_eq!(x.acosh_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.969");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// assert_eq!(x.acosh_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.96242332");
+    /// ```
+    #[inline]
+    pub fn acosh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.acosh_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], in place,
+    /// rounding the result to the precision of the input and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded inverse hyperbolic cosine is less
+    /// than, equal to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::acosh_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::acosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than 1 and inputs that produce `NaN` or
+    /// $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.8115262724608527");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.8115262724608545");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.8115262724608527");
+    /// ```
+    #[inline]
+    pub fn acosh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Rational`], rounding
+    /// the result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic cosine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic cosine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{acosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{acosh}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{acosh}
+    ///   x\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x<1$
+    /// - $f(1,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than 1 and inputs that
+    /// produce `NaN` or $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round(Rational::from_unsigneds(4u8, 3), 5, Floor);
+    /// assert_eq!(acosh.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round(Rational::from_unsigneds(4u8, 3), 5, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.812");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round(Rational::from_unsigneds(4u8, 3), 20, Floor);
+    /// assert_eq!(acosh.to_string(), "0.79536533");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round(Rational::from_unsigneds(4u8, 3), 20, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.79536629");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn acosh_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::acosh_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Rational`], rounding
+    /// the result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal to,
+    /// or greater than the exact inverse hyperbolic cosine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{acosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{acosh}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{acosh}
+    ///   x\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x<1$
+    /// - $f(1,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than 1 and inputs that
+    /// produce `NaN` or $\infty$).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round_ref(&Rational::from_unsigneds(4u8, 3), 5, Floor);
+    /// assert_eq!(acosh.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round_ref(&Rational::from_unsigneds(4u8, 3), 5, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.812");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round_ref(&Rational::from_unsigneds(4u8, 3), 20, Floor);
+    /// assert_eq!(acosh.to_string(), "0.79536533");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_round_ref(&Rational::from_unsigneds(4u8, 3), 20, Ceiling);
+    /// assert_eq!(acosh.to_string(), "0.79536629");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn acosh_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        acosh_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Rational`], rounding
+    /// the result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded inverse hyperbolic cosine is less than, equal to, or greater than the
+    /// exact inverse hyperbolic cosine.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::acosh_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec(Rational::from_unsigneds(4u8, 3), 5);
+    /// assert_eq!(acosh.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec(Rational::from_unsigneds(4u8, 3), 20);
+    /// assert_eq!(acosh.to_string(), "0.79536533");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn acosh_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::acosh_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Rational`], rounding
+    /// the result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic cosine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic cosine.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::acosh_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_ref(&Rational::from_unsigneds(4u8, 3), 5);
+    /// assert_eq!(acosh.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acosh, o) = Float::acosh_rational_prec_ref(&Rational::from_unsigneds(4u8, 3), 20);
+    /// assert_eq!(acosh.to_string(), "0.79536533");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acosh_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::acosh_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Acosh for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], taking it
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// cosine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{acosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{acosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{acosh} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x<1$
+    /// - $f(\infty)=\infty$
+    /// - $f(1.0)=0.0$
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::acosh_round`]. If you want both of these things, consider using
+    /// [`Float::acosh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acosh;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.acosh().is_nan());
+    /// assert!(Float::ZERO.acosh().is_nan());
+    /// assert_eq!(Float::INFINITY.acosh(), Float::INFINITY);
+    /// assert_eq!(Float::ONE.acosh(), 0.0);
+    /// assert_eq!(Float::from(PI).acosh().to_string(), "1.8115262724608527");
+    /// assert_eq!(Float::from(1.5).acosh().to_string(), "1.0");
+    /// ```
+    #[inline]
+    fn acosh(self) -> Self {
+        let prec = self.significant_bits();
+        self.acosh_prec_round(prec, Nearest).0
+    }
+}
+
+impl Acosh for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], taking it
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// cosine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{acosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{acosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{acosh} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x<1$
+    /// - $f(\infty)=\infty$
+    /// - $f(1.0)=0.0$
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::acosh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::acosh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acosh;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).acosh().is_nan());
+    /// assert!((&Float::ZERO).acosh().is_nan());
+    /// assert_eq!((&Float::INFINITY).acosh(), Float::INFINITY);
+    /// assert_eq!((&Float::ONE).acosh(), 0.0);
+    /// assert_eq!((&Float::from(PI)).acosh().to_string(), "1.8115262724608527");
+    /// assert_eq!((&Float::from(1.5)).acosh().to_string(), "1.0");
+    /// ```
+    #[inline]
+    fn acosh(self) -> Float {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AcoshAssign for Float {
+    /// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// cosine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{acosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{acosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{acosh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{acosh} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AcoshAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.acosh_assign();
+    /// assert_eq!(x.to_string(), "1.8115262724608527");
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.acosh_assign();
+    /// assert_eq!(x.to_string(), "1.0");
+    /// ```
+    #[inline]
+    fn acosh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a primitive float, returning
+/// a correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{acosh} x+\varepsilon.
+/// $$
+/// - If $\operatorname{acosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{acosh} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   \operatorname{acosh} x\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+/// - $f(x)=\text{NaN}$ if $x<1$
+/// - $f(\infty)=\infty$
+/// - $f(1.0)=0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::acosh::primitive_float_acosh;
+///
+/// assert!(primitive_float_acosh(f64::NAN).is_nan());
+/// assert!(primitive_float_acosh(0.5f64).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_acosh(1.0f64)), NiceFloat(0.0));
+/// assert_eq!(NiceFloat(primitive_float_acosh(2.0f64)), NiceFloat(1.3169578969248168));
+/// assert_eq!(NiceFloat(primitive_float_acosh(1.5f32)), NiceFloat(0.9624236));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_acosh<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::acosh_prec, x)
+}
+
+/// Computes $\operatorname{acosh} x$, the inverse hyperbolic cosine of a [`Rational`], returning
+/// the result as a primitive float.
+///
+/// $$
+/// f(x) = \operatorname{acosh} x+\varepsilon.
+/// $$
+/// - If $\operatorname{acosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{acosh} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   \operatorname{acosh} x\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x)=\text{NaN}$ if $x<1$
+/// - $f(1)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::acosh::primitive_float_acosh_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_acosh_rational::<f64>(&Rational::from_unsigneds(4u8, 3))), NiceFloat(0.7953654612239056));
+/// assert_eq!(NiceFloat(primitive_float_acosh_rational::<f64>(&Rational::from(10000))), NiceFloat(9.903487550036129));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_acosh_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::acosh_rational_prec_ref, x)
+}
@@ -223,7 +223,7 @@ impl Float {
                     if *x_exp > Self::MAX_EXPONENT {
                         return match (*x_sign, rm) {
                             (_, Exact) => panic!("Inexact float addition"),
-                            (true, Ceiling | Up | Nearest) => {
+                            (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                                 *self = float_infinity!();
                                 Greater
                             }
@@ -231,7 +231,7 @@ impl Float {
                                 *self = Self::max_finite_value_with_prec(prec);
                                 Less
                             }
-                            (false, Floor | Up | Nearest) => {
+                            (false, Floor | Up | Nearest | NearestTiesAway) => {
                                 *self = float_negative_infinity!();
                                 Less
                             }
@@ -255,9 +255,10 @@ impl Float {
                     );
                     if *x_exp < Self::MIN_EXPONENT {
                         let sign = *x_sign != neg;
-                        return if rm == Nearest
+                        return if (rm == Nearest || rm == NearestTiesAway)
                             && *x_exp == Self::MIN_EXPONENT_MINUS_1
                             && (o == Less
+                                || rm == NearestTiesAway && o == Equal
                                 || !(if swapped {
                                     y.is_power_of_2()
                                 } else {
@@ -274,7 +275,7 @@ impl Float {
                         } else {
                             match (sign, rm) {
                                 (_, Exact) => panic!("Inexact float subtraction"),
-                                (true, Ceiling | Up) => {
+                                (true, Ceiling | Up | Odd) => {
                                     *self = Self::min_positive_value_prec(prec);
                                     Greater
                                 }
@@ -282,7 +283,7 @@ impl Float {
                                     *self = float_zero!();
                                     Less
                                 }
-                                (false, Floor | Up) => {
+                                (false, Floor | Up | Odd) => {
                                     *self = -Self::min_positive_value_prec(prec);
                                     Less
                                 }
@@ -296,11 +297,11 @@ impl Float {
                     if *x_exp > Self::MAX_EXPONENT {
                         return match (*x_sign != neg, rm) {
                             (_, Exact) => panic!("Inexact float subtraction"),
-                            (true, Ceiling | Up | Nearest) => {
+                            (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                                 *self = float_infinity!();
                                 Greater
                             }
-                            (false, Floor | Up | Nearest) => {
+                            (false, Floor | Up | Nearest | NearestTiesAway) => {
                                 *self = float_negative_infinity!();
                                 Less
                             }
@@ -414,7 +415,7 @@ impl Float {
                     if *x_exp > Self::MAX_EXPONENT {
                         return match (x_sign, rm) {
                             (_, Exact) => panic!("Inexact float addition"),
-                            (true, Ceiling | Up | Nearest) => {
+                            (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                                 *self = float_infinity!();
                                 Greater
                             }
@@ -422,7 +423,7 @@ impl Float {
                                 *self = Self::max_finite_value_with_prec(prec);
                                 Less
                             }
-                            (false, Floor | Up | Nearest) => {
+                            (false, Floor | Up | Nearest | NearestTiesAway) => {
                                 *self = float_negative_infinity!();
                                 Less
                             }
@@ -446,9 +447,11 @@ impl Float {
                     );
                     if *x_exp < Self::MIN_EXPONENT {
                         let sign = *x_sign != neg;
-                        return if rm == Nearest
+                        return if (rm == Nearest || rm == NearestTiesAway)
                             && *x_exp == Self::MIN_EXPONENT_MINUS_1
-                            && (o == Less || !x.is_power_of_2())
+                            && (o == Less
+                                || rm == NearestTiesAway && o == Equal
+                                || !x.is_power_of_2())
                         {
                             if sign {
                                 *self = Self::min_positive_value_prec(prec);
@@ -460,7 +463,7 @@ impl Float {
                         } else {
                             match (sign, rm) {
                                 (_, Exact) => panic!("Inexact float subtraction"),
-                                (true, Ceiling | Up) => {
+                                (true, Ceiling | Up | Odd) => {
                                     *self = Self::min_positive_value_prec(prec);
                                     Greater
                                 }
@@ -468,7 +471,7 @@ impl Float {
                                     *self = float_zero!();
                                     Less
                                 }
-                                (false, Floor | Up) => {
+                                (false, Floor | Up | Odd) => {
                                     *self = -Self::min_positive_value_prec(prec);
                                     Less
                                 }
@@ -482,11 +485,11 @@ impl Float {
                     if *x_exp > Self::MAX_EXPONENT {
                         return match (*x_sign != neg, rm) {
                             (_, Exact) => panic!("Inexact float subtraction"),
-                            (true, Ceiling | Up | Nearest) => {
+                            (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                                 *self = float_infinity!();
                                 Greater
                             }
-                            (false, Floor | Up | Nearest) => {
+                            (false, Floor | Up | Nearest | NearestTiesAway) => {
                                 *self = float_negative_infinity!();
                                 Less
                             }
@@ -589,9 +592,13 @@ impl Float {
                     if sum_exp > Self::MAX_EXPONENT {
                         return match (*x_sign, rm) {
                             (_, Exact) => panic!("Inexact float addition"),
-                            (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
+                            (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                                (float_infinity!(), Greater)
+                            }
                             (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-                            (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                            (false, Floor | Up | Nearest | NearestTiesAway) => {
+                                (float_negative_infinity!(), Less)
+                            }
                             (false, _) => (-Self::max_finite_value_with_prec(prec), Greater),
                         };
                     }
@@ -615,9 +622,11 @@ impl Float {
                     );
                     if diff_exp < Self::MIN_EXPONENT {
                         let sign = *x_sign != neg;
-                        return if rm == Nearest
+                        return if (rm == Nearest || rm == NearestTiesAway)
                             && diff_exp == Self::MIN_EXPONENT_MINUS_1
-                            && (o == Less || !diff.is_power_of_2())
+                            && (o == Less
+                                || rm == NearestTiesAway && o == Equal
+                                || !diff.is_power_of_2())
                         {
                             if sign {
                                 (Self::min_positive_value_prec(prec), Greater)
@@ -627,11 +636,13 @@ impl Float {
                         } else {
                             match (sign, rm) {
                                 (_, Exact) => panic!("Inexact float subtraction"),
-                                (true, Ceiling | Up) => {
+                                (true, Ceiling | Up | Odd) => {
                                     (Self::min_positive_value_prec(prec), Greater)
                                 }
                                 (true, _) => (float_zero!(), Less),
-                                (false, Floor | Up) => (-Self::min_positive_value_prec(prec), Less),
+                                (false, Floor | Up | Odd) => {
+                                    (-Self::min_positive_value_prec(prec), Less)
+                                }
                                 (false, _) => (float_negative_zero!(), Greater),
                             }
                         };
@@ -639,8 +650,12 @@ impl Float {
                     if diff_exp > Self::MAX_EXPONENT {
                         return match (*x_sign != neg, rm) {
                             (_, Exact) => panic!("Inexact float subtraction"),
-                            (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
-                            (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                            (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                                (float_infinity!(), Greater)
+                            }
+                            (false, Floor | Up | Nearest | NearestTiesAway) => {
+                                (float_negative_infinity!(), Less)
+                            }
                             _ => panic!("Invalid state"),
                         };
                     }
@@ -679,10 +694,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -699,27 +714,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_prec`] instead. If you
     /// know that your target precision is the maximum of the precisions of the two inputs, consider
@@ -786,10 +810,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -806,27 +830,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_val_ref`] instead.
     /// If you know that your target precision is the maximum of the precisions of the two inputs,
@@ -898,10 +931,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -918,27 +951,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_ref_val`] instead.
     /// If you know that your target precision is the maximum of the precisions of the two inputs,
@@ -1010,10 +1052,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1030,27 +1072,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_ref_ref`] instead.
     /// If you know that your target precision is the maximum of the precisions of the two inputs,
@@ -1384,10 +1435,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1404,26 +1457,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
@@ -1479,10 +1540,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1499,26 +1562,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_prec_round_val_ref`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
@@ -1574,10 +1645,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1594,26 +1667,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_prec_round_ref_val`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
@@ -1668,10 +1749,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1688,26 +1771,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_prec_round_ref_ref`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
@@ -1762,10 +1853,10 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1839,10 +1930,10 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2038,10 +2129,12 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2102,10 +2195,12 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2165,10 +2260,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2182,27 +2277,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec`] instead.
     /// If you know that your target precision is the precision of the [`Float`] input, consider
@@ -2281,10 +2385,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2298,27 +2402,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec_val_ref`]
     /// instead. If you know that your target precision is the precision of the [`Float`] input,
@@ -2415,10 +2528,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2432,27 +2545,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec_ref_val`]
     /// instead. If you know that your target precision is the precision of the [`Float`] input,
@@ -2562,9 +2684,13 @@ impl Float {
                 if min_exponent >= Self::MAX_EXPONENT_I64 {
                     assert!(rm != Exact, "Inexact Float addition");
                     return match (float_rational_sum_sign(x, &y), rm) {
-                        (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                            (float_infinity!(), Greater)
+                        }
                         (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-                        (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
+                            (float_negative_infinity!(), Less)
+                        }
                         (false, _) => (-Self::max_finite_value_with_prec(prec), Greater),
                     };
                 }
@@ -2630,10 +2756,10 @@ impl Float {
     /// f(x,y,p,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2647,27 +2773,36 @@ impl Float {
     /// - $f(x,-x,p,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_rational_prec_ref_ref`]
     /// instead. If you know that your target precision is the precision of the [`Float`] input,
@@ -2777,9 +2912,13 @@ impl Float {
                 if min_exponent >= Self::MAX_EXPONENT_I64 {
                     assert!(rm != Exact, "Inexact Float addition");
                     return match (float_rational_sum_sign(x, y), rm) {
-                        (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                            (float_infinity!(), Greater)
+                        }
                         (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-                        (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
+                            (float_negative_infinity!(), Less)
+                        }
                         (false, _) => (-Self::max_finite_value_with_prec(prec), Greater),
                     };
                 }
@@ -3114,10 +3253,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -3132,26 +3273,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -3210,10 +3359,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -3228,26 +3379,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_rational_prec_round_val_ref`] instead. If you know you'll be using the
@@ -3311,10 +3470,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -3329,26 +3490,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_rational_prec_round_ref_val`] instead. If you know you'll be using the
@@ -3412,10 +3581,12 @@ impl Float {
     /// f(x,y,m) = x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -3430,26 +3601,34 @@ impl Float {
     /// - $f(x,-x,m)=-0.0$ if $x$ is nonzero and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_rational_prec_round_ref_ref`] instead. If you know you'll be using the
@@ -3512,10 +3691,10 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -3628,7 +3807,7 @@ impl Float {
                 if min_exponent >= Self::MAX_EXPONENT_I64 {
                     assert!(rm != Exact, "Inexact Float addition");
                     return match (float_rational_sum_sign(x, &y), rm) {
-                        (true, Ceiling | Up | Nearest) => {
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                             *self = float_infinity!();
                             Greater
                         }
@@ -3636,7 +3815,7 @@ impl Float {
                             *self = Self::max_finite_value_with_prec(prec);
                             Less
                         }
-                        (false, Floor | Up | Nearest) => {
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
                             *self = float_negative_infinity!();
                             Less
                         }
@@ -3709,10 +3888,10 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -3824,7 +4003,7 @@ impl Float {
                 if min_exponent >= Self::MAX_EXPONENT_I64 {
                     assert!(rm != Exact, "Inexact Float addition");
                     return match (float_rational_sum_sign(x, y), rm) {
-                        (true, Ceiling | Up | Nearest) => {
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                             *self = float_infinity!();
                             Greater
                         }
@@ -3832,7 +4011,7 @@ impl Float {
                             *self = Self::max_finite_value_with_prec(prec);
                             Less
                         }
-                        (false, Floor | Up | Nearest) => {
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
                             *self = float_negative_infinity!();
                             Less
                         }
@@ -4027,10 +4206,12 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the input [`Float`].
     ///
@@ -4101,10 +4282,12 @@ impl Float {
     /// x \gets x+y+\varepsilon.
     /// $$
     /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x+y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the input [`Float`].
     ///
@@ -174,9 +174,9 @@ pub(crate) fn add_scaled_round(
 fn overflow_result(sp: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
     match (sp, rm) {
         (_, Exact) => panic!("Inexact Float addition"),
-        (true, Floor | Down) => (Float::max_finite_value_with_prec(prec), Less),
+        (true, Floor | Down | Odd) => (Float::max_finite_value_with_prec(prec), Less),
         (true, _) => (float_infinity!(), Greater),
-        (false, Ceiling | Down) => (-Float::max_finite_value_with_prec(prec), Greater),
+        (false, Ceiling | Down | Odd) => (-Float::max_finite_value_with_prec(prec), Greater),
         (false, _) => (float_negative_infinity!(), Less),
     }
 }
@@ -455,9 +455,10 @@ pub(crate) fn add_mul_helper(
                 // an ulp of both the addend and the result, the product can be replaced by a
                 // minimal-value sentinel with its sign; this is even true in case of equality for
                 // Nearest thanks to the even-rounding rule. The + 1 on prec is necessary because
-                // the exponent of the result can be one less than the addend's.
+                // the exponent of the result can be one less than the addend's. NearestTiesAway has
+                // no such rule, so it needs one more bit of room.
                 if u64::exact_from(i64::from(*a_exponent) - Float::MIN_EXPONENT_I64)
-                    >= max(*a_precision, prec.saturating_add(1))
+                    >= max(*a_precision, prec.saturating_add(1)) + u64::from(rm == NearestTiesAway)
                 {
                     let sent = if sp {
                         Float::MIN_POSITIVE
@@ -521,7 +522,9 @@ pub(crate) fn add_mul_val_helper(
         if sp == sa || e >= SURE_OVERFLOW_EXPONENT {
             return overflow_result(sp, prec, rm);
         }
-    } else if u64::exact_from(ae - Float::MIN_EXPONENT_I64) >= max(ap, prec.saturating_add(1)) {
+    } else if u64::exact_from(ae - Float::MIN_EXPONENT_I64)
+        >= max(ap, prec.saturating_add(1)) + u64::from(rm == NearestTiesAway)
+    {
         let sent = if sp {
             Float::MIN_POSITIVE
         } else {
@@ -545,10 +548,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -571,27 +574,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -680,10 +693,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -706,27 +719,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -817,10 +840,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -843,27 +866,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -954,10 +987,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -980,27 +1013,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -1082,10 +1125,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1108,27 +1151,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -1207,10 +1260,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1233,27 +1286,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -1332,10 +1395,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1358,27 +1421,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -1456,10 +1529,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1482,27 +1555,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
     /// you know that your target precision is the maximum of the precisions of the inputs, consider
@@ -1578,10 +1661,10 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -1662,10 +1745,10 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -1746,10 +1829,10 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -1829,10 +1912,10 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -2793,10 +2876,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2819,27 +2904,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -2904,10 +2998,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2930,27 +3026,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -3020,10 +3125,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3046,27 +3153,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -3136,10 +3252,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3162,27 +3280,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -3251,10 +3378,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3277,27 +3406,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -3367,10 +3505,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3393,27 +3533,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -3483,10 +3632,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3509,27 +3660,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -3599,10 +3759,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3625,27 +3787,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
@@ -3713,10 +3884,12 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -3787,10 +3960,12 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -3866,10 +4041,12 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -3945,10 +4122,12 @@ impl Float {
     /// $$
     /// x \gets x+yz+\varepsilon.
     /// $$
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// See the [`Float::add_mul_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -4841,10 +5020,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -4864,27 +5043,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -4982,10 +5171,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5005,27 +5194,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -5123,10 +5322,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5146,27 +5345,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -5264,10 +5473,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5287,27 +5496,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -5405,10 +5624,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5428,27 +5647,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -5534,10 +5763,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5557,27 +5786,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -5663,10 +5902,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5686,27 +5925,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -5792,10 +6041,10 @@ impl Float {
     /// f(x,y,z,p,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5815,27 +6064,37 @@ impl Float {
     /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,p,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
+    /// - If $0<f(x,y,z,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
+    /// - If $2^{-2^{30}-1}<f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$
+    ///   is returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_rational_prec`]
     /// instead. If you know that your target precision is the maximum of the precisions of the
@@ -5920,10 +6179,10 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
@@ -6012,10 +6271,10 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
@@ -6104,10 +6363,10 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
@@ -6196,10 +6455,10 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
@@ -7330,12 +7589,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -7355,27 +7614,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -7453,12 +7721,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -7478,27 +7746,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -7577,12 +7854,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -7602,27 +7879,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -7701,12 +7987,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -7726,27 +8012,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -7823,12 +8118,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -7848,27 +8143,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -7941,12 +8245,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -7966,27 +8270,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -8058,12 +8371,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -8083,27 +8396,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -8175,12 +8497,12 @@ impl Float {
     /// f(x,y,z,m) = x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the input [`Float`]s.
     ///
@@ -8200,27 +8522,36 @@ impl Float {
     /// - $f(x,y,z,m)=-0.0$ if $x=-yz$, $x$ is finite and nonzero, and $m$ is `Floor`
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,z,m)\leq -2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,z,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,z,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,z,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,z,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,z,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::add_mul_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -8291,12 +8622,12 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
@@ -8380,12 +8711,12 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
@@ -8469,12 +8800,12 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
@@ -8559,12 +8890,12 @@ impl Float {
     /// x \gets x+yz+\varepsilon.
     /// $$
     /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
-    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the input
-    ///   [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
+    /// - If $x+yz$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the input [`Float`]s.
     ///
     /// See the [`Float::add_mul_rational_prec_round`] documentation for information on special
     /// cases, overflow, and underflow.
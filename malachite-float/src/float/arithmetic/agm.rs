@@ -471,10 +471,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -563,10 +563,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -652,10 +652,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -741,10 +741,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1085,12 +1085,12 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1165,12 +1165,12 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1245,12 +1245,12 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1322,12 +1322,12 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1402,10 +1402,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1490,10 +1490,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1703,12 +1703,12 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1772,12 +1772,12 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -1840,10 +1840,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1852,27 +1852,36 @@ impl Float {
     /// - $f(x,y,p,m)=\text{NaN}$ if $x<0$ or $y<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::agm_rational_prec`] instead.
     ///
@@ -1949,10 +1958,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1961,27 +1970,36 @@ impl Float {
     /// - $f(x,y,p,m)=\text{NaN}$ if $x<0$ or $y<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::agm_rational_prec_val_ref`]
     /// instead.
@@ -2057,7 +2075,7 @@ impl Float {
         match (x_overflow, y_overflow, x_underflow, y_underflow) {
             (true, true, _, _) => Self::from_rational_prec_round(x, prec, rm),
             (_, _, true, true)
-                if rm != Nearest
+                if rm != Nearest && rm != NearestTiesAway
                     || x_exp < Self::MIN_EXPONENT_MINUS_1 && y_exp < Self::MIN_EXPONENT_MINUS_1 =>
             {
                 Self::from_rational_prec_round(x, prec, rm)
@@ -2087,10 +2105,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2099,27 +2117,36 @@ impl Float {
     /// - $f(x,y,p,m)=\text{NaN}$ if $x<0$ or $y<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::agm_rational_prec_ref_val`]
     /// instead.
@@ -2195,7 +2222,7 @@ impl Float {
         match (x_overflow, y_overflow, x_underflow, y_underflow) {
             (true, true, _, _) => Self::from_rational_prec_round(y, prec, rm),
             (_, _, true, true)
-                if rm != Nearest
+                if rm != Nearest && rm != NearestTiesAway
                     || x_exp < Self::MIN_EXPONENT_MINUS_1 && y_exp < Self::MIN_EXPONENT_MINUS_1 =>
             {
                 Self::from_rational_prec_round(y, prec, rm)
@@ -2225,10 +2252,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2237,27 +2264,36 @@ impl Float {
     /// - $f(x,y,p,m)=\text{NaN}$ if $x<0$ or $y<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,t,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::agm_rational_prec_ref_ref`]
     /// instead.
@@ -2333,7 +2369,7 @@ impl Float {
         match (x_overflow, y_overflow, x_underflow, y_underflow) {
             (true, true, _, _) => Self::from_rational_prec_round_ref(x, prec, rm),
             (_, _, true, true)
-                if rm != Nearest
+                if rm != Nearest && rm != NearestTiesAway
                     || x_exp < Self::MIN_EXPONENT_MINUS_1 && y_exp < Self::MIN_EXPONENT_MINUS_1 =>
             {
                 Self::from_rational_prec_round_ref(x, prec, rm)
@@ -2362,10 +2398,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2432,10 +2468,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2501,10 +2537,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2570,10 +2606,10 @@ impl Float {
     /// $$
     /// - If $\text{AGM}(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon|
-    ///   < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
-    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p+1}$.
+    /// - If $\text{AGM}(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   then $|\varepsilon| \leq 2^{\lfloor\log_2 \text{AGM}(x,y)\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
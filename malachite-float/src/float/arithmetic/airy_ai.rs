@@ -0,0 +1,1327 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The Airy function Ai is computed from an exact rational argument x. Near zero, the power series
+//
+// Ai(x) = c_1 f(x) - c_2 g(x),
+//
+// is used, where c_1 = Ai(0) = 1 / (3 ^ (2/3) Γ(2/3)), c_2 = -Ai'(0) = 1 / (3 ^ (1/3) Γ(1/3)),
+// f(x) = Σ a_k, with a_0 = 1 and a_k = a_(k - 1) x ^ 3 / ((3k - 1)(3k)), and g(x) = Σ b_k, with
+// b_0 = x and b_k = b_(k - 1) x ^ 3 / ((3k)(3k + 1)). Both f(x) and g(x) are about e ^ ζ, where
+// ζ = (2/3) |x| ^ (3/2); for x > 0, Ai(x) is about e ^ (-ζ), so there is catastrophic
+// cancellation, which is made up for by adding about 2 |x| ^ (3/2) bits of precision (or
+// |x| ^ (3/2) bits for x < 0, when the terms alternate in sign).
+//
+// For large positive x, the asymptotic expansion
+//
+// Ai(x) = e ^ (-ζ) / (2 √π x ^ (1/4)) Σ (-1) ^ k u_k / ζ ^ k,
+//
+// is used instead, where u_0 = 1 and u_k = u_(k - 1) (6k - 5)(6k - 3)(6k - 1) / ((2k - 1) 216k).
+// The remainder after any number of terms is smaller than the first omitted term. As with the
+// Bessel functions, the factor e ^ (-ζ) is computed with a power of 2 split off, so that Ai(x) can
+// be computed even when it is far below the smallest positive `Float`.
+//
+// No asymptotic expansion is used for large negative x, so the running time grows like |x| ^ (3/2)
+// as x goes to negative infinity. Every step produces rational bounds, and the result is rounded
+// using Ziv's strategy.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::{mul_bounds, sqrt_bounds};
+use crate::float::arithmetic::exp::exp_underflow;
+use crate::float::arithmetic::gamma::{
+    Argument, constant_bounds, exp_scaled_bounds, exp_shift, ln_bounds,
+};
+use crate::float::arithmetic::sinh_cosh::round_with_scaled_bounds;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    Abs, AiryAi, AiryAiAssign, DivRound, Parity, PowerOf2, Reciprocal, Square,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Returns bounds on x ^ (1 / k), for a positive rational `x`, with a relative width of about
+// `2 ^ (-w)`.
+fn root_bounds(x: u32, k: u64, w: u64) -> (Rational, Rational) {
+    constant_bounds(Float::root_u_rational_prec_round(
+        Rational::from(x),
+        k,
+        w,
+        Floor,
+    ))
+}
+
+// Returns bounds on Γ(1/3), with a relative width of about `2 ^ (-w)`. Computing Γ(1/3) directly is
+// slow at high precision, so the formula
+//
+// Γ(1/3) ^ 3 = 2 ^ (4/3) π ^ 2 / (3 ^ (1/4) AGM(1, (√6 + √2) / 4)),
+//
+// which follows from the value of the complete elliptic integral of the first kind at the third
+// singular value, is used instead.
+fn gamma_one_third_bounds(w: u64) -> (Rational, Rational) {
+    let w = w + 8;
+    let (s_6_lo, s_6_hi) = root_bounds(6, 2, w);
+    let (s_2_lo, s_2_hi) = root_bounds(2, 2, w);
+    let k_lo = Float::from_rational_prec_round((s_6_lo + s_2_lo) >> 2u32, w, Floor).0;
+    let k_hi = Float::from_rational_prec_round((s_6_hi + s_2_hi) >> 2u32, w, Ceiling).0;
+    // AGM is increasing in both arguments
+    let agm_lo = Rational::exact_from(Float::ONE.agm_prec_round(k_lo, w, Floor).0);
+    let agm_hi = Rational::exact_from(Float::ONE.agm_prec_round(k_hi, w, Ceiling).0);
+    let (pi_lo, pi_hi) = constant_bounds(Float::pi_prec_round(w, Floor));
+    let (c_lo, c_hi) = root_bounds(2, 3, w);
+    let (r_lo, r_hi) = root_bounds(3, 4, w);
+    let g_lo = (c_lo << 1u32) * pi_lo.square() / (r_hi * agm_hi);
+    let g_hi = (c_hi << 1u32) * pi_hi.square() / (r_lo * agm_lo);
+    (
+        Rational::exact_from(Float::cbrt_rational_prec_round(g_lo, w, Floor).0),
+        Rational::exact_from(Float::cbrt_rational_prec_round(g_hi, w, Ceiling).0),
+    )
+}
+
+// Returns bounds on c_1 = Ai(0) = 3 ^ (-1/6) Γ(1/3) / (2π) and c_2 = -Ai'(0) = 1 / (3 ^ (1/3)
+// Γ(1/3)), each with a relative width of about `2 ^ (-w)`.
+fn airy_constant_bounds(w: u64) -> ((Rational, Rational), (Rational, Rational)) {
+    let w = w + 4;
+    let (g_lo, g_hi) = gamma_one_third_bounds(w);
+    let (pi_lo, pi_hi) = constant_bounds(Float::pi_prec_round(w, Floor));
+    let (s_lo, s_hi) = root_bounds(3, 6, w);
+    let (t_lo, t_hi) = root_bounds(3, 3, w);
+    let c_1 = (
+        &g_lo / ((pi_hi << 1u32) * s_hi),
+        &g_hi / ((pi_lo << 1u32) * s_lo),
+    );
+    let c_2 = ((t_hi * g_hi).reciprocal(), (t_lo * g_lo).reciprocal());
+    (c_1, c_2)
+}
+
+// Returns an upper bound on |x| ^ (3/2), rounded up to an integer.
+fn three_halves_power_ceiling(x: &Rational) -> u64 {
+    let cube = x.abs() * x * x;
+    u64::exact_from(&Natural::rounding_from(sqrt_bounds(&cube, 32).1, Ceiling).0)
+}
+
+// Returns bounds on Ai(x), for a nonzero rational `x`, using the power series, with an absolute
+// width of about `2 ^ (-w)` times the largest term.
+fn ai_series_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let power = three_halves_power_ceiling(x);
+    let extra = if *x > 0u32 { power << 1 } else { power };
+    let cube = x * x * x;
+    let negative = cube < 0u32;
+    let (p, q) = cube.abs().into_numerator_and_denominator();
+    let scale = w + 32 + extra;
+    let one = Natural::power_of_2(scale);
+    let small = |t: &Natural| t << (w + 8 + extra) <= one;
+    // Bounds on the a_k and b_k, and on their sums. For x < 0, the terms alternate in sign.
+    let mut a_lo = one.clone();
+    let mut a_hi = one.clone();
+    let x_abs = x.abs();
+    let mut b_lo = Natural::rounding_from(&x_abs << scale, Floor).0;
+    let mut b_hi = Natural::rounding_from(x_abs << scale, Ceiling).0;
+    let mut f_lo = Integer::from(&a_lo);
+    let mut f_hi = f_lo.clone();
+    let mut g_lo = Integer::from(&b_lo);
+    let mut g_hi = Integer::from(&b_hi);
+    if negative {
+        (g_lo, g_hi) = (-g_hi, -g_lo);
+    }
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        let d_a = &q * Natural::from((3 * k - 1) * 3 * k);
+        let d_b = &q * Natural::from(3 * k * (3 * k + 1));
+        a_lo = &a_lo * &p / &d_a;
+        a_hi = (&a_hi * &p).div_round(&d_a, Ceiling).0;
+        b_lo = &b_lo * &p / &d_b;
+        b_hi = (&b_hi * &p).div_round(&d_b, Ceiling).0;
+        if negative && k.odd() {
+            f_lo -= Integer::from(&a_hi);
+            f_hi -= Integer::from(&a_lo);
+        } else {
+            f_lo += Integer::from(&a_lo);
+            f_hi += Integer::from(&a_hi);
+        }
+        // b_k has the sign of x ^ (3k + 1)
+        if negative && k.even() {
+            g_lo -= Integer::from(&b_hi);
+            g_hi -= Integer::from(&b_lo);
+        } else {
+            g_lo += Integer::from(&b_lo);
+            g_hi += Integer::from(&b_hi);
+        }
+        // Once the ratio of consecutive terms is at most 1/2, the rest of each sum is smaller than
+        // its last term.
+        if &p << 1u32 <= &q * Natural::from((3 * k + 2) * (3 * k + 3))
+            && small(&a_hi)
+            && small(&b_hi)
+        {
+            let a_hi = Integer::from(a_hi);
+            let b_hi = Integer::from(b_hi);
+            f_lo -= &a_hi;
+            f_hi += a_hi;
+            g_lo -= &b_hi;
+            g_hi += b_hi;
+            break;
+        }
+    }
+    let f = (Rational::from(f_lo) >> scale, Rational::from(f_hi) >> scale);
+    let g = (Rational::from(g_lo) >> scale, Rational::from(g_hi) >> scale);
+    let (c_1, c_2) = airy_constant_bounds(scale);
+    let (cf_lo, cf_hi) = mul_bounds(&c_1, &f);
+    let (cg_lo, cg_hi) = mul_bounds(&c_2, &g);
+    (cf_lo - cg_hi, cf_hi - cg_lo)
+}
+
+// Returns bounds on ζ = (2/3) x ^ (3/2), for a positive rational `x`, with a relative width of
+// about `2 ^ (-w)`.
+fn zeta_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let two_thirds = Rational::from_unsigneds(2u8, 3);
+    let (lo, hi) = sqrt_bounds(&(x * x * x), w);
+    (lo * &two_thirds, hi * two_thirds)
+}
+
+// Returns bounds on Σ (-1) ^ k u_k / ζ ^ k, given bounds on ζ, with an absolute width of about
+// `2 ^ (-w)`, or `None` if the terms start growing before they get that small.
+fn ai_asymptotic_sum_bounds(
+    (zeta_lo, zeta_hi): &(Rational, Rational),
+    w: u64,
+) -> Option<(Rational, Rational)> {
+    let (p_lo, q_lo) = zeta_lo.numerator_and_denominator_ref();
+    let (p_hi, q_hi) = zeta_hi.numerator_and_denominator_ref();
+    let scale = w + 32;
+    let one = Natural::power_of_2(scale);
+    let mut t_lo = one.clone();
+    let mut t_hi = one.clone();
+    let mut s_lo = Integer::from(&one);
+    let mut s_hi = s_lo.clone();
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        let n = Natural::from((6 * k - 5) * (6 * k - 3)) * Natural::from(6 * k - 1);
+        let d = Natural::from((2 * k - 1) * 216) * Natural::from(k);
+        let previous_hi = t_hi.clone();
+        t_lo = (&t_lo * &n * q_hi) / (&d * p_hi);
+        t_hi = (&t_hi * n * q_lo).div_round(d * p_lo, Ceiling).0;
+        if &t_hi << (w + 8) <= one {
+            // the remainder is smaller than the first omitted term
+            let t_hi = Integer::from(t_hi);
+            s_lo -= &t_hi;
+            s_hi += t_hi;
+            break;
+        }
+        if t_lo > previous_hi {
+            return None;
+        }
+        if k.odd() {
+            s_lo -= Integer::from(&t_hi);
+            s_hi -= Integer::from(&t_lo);
+        } else {
+            s_lo += Integer::from(&t_lo);
+            s_hi += Integer::from(&t_hi);
+        }
+    }
+    Some((Rational::from(s_lo) >> scale, Rational::from(s_hi) >> scale))
+}
+
+// Returns bounds on log(Ai(x) / Σ (-1) ^ k u_k / ζ ^ k) = -ζ - log(2 √π) - log(x) / 4, for a
+// positive `Argument` x, with an absolute width of about `2 ^ (-w)`, together with the bounds on ζ
+// used.
+fn ln_ai_factor_bounds(
+    x: &Argument,
+    x_rational: &Rational,
+    w: u64,
+) -> ((Rational, Rational), (Rational, Rational)) {
+    // ζ < 2 ^ (3e / 2)
+    let zeta = zeta_bounds(
+        x_rational,
+        w + 2 + ((u64::exact_from(x.exponent().max(0)) * 3) >> 1),
+    );
+    let (l_lo, l_hi) = x.ln_abs_bounds(w + 4);
+    let (pi_lo, pi_hi) = constant_bounds(Float::pi_prec_round(w + 4, Floor));
+    let (l_pi_lo, _) = ln_bounds(&pi_lo, w + 4);
+    let (_, l_pi_hi) = ln_bounds(&pi_hi, w + 4);
+    let (ln_2_lo, ln_2_hi) = constant_bounds(Float::ln_2_prec_round(w + 4, Floor));
+    // log(2 √π) = log(2) + log(π) / 2
+    let lo = -&zeta.1 - ln_2_hi - (l_pi_hi >> 1u32) - (l_hi >> 2u32);
+    let hi = -&zeta.0 - ln_2_lo - (l_pi_lo >> 1u32) - (l_lo >> 2u32);
+    ((lo, hi), zeta)
+}
+
+// Computes Ai(0) = c_1.
+fn ai_helper_zero(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact Airy function");
+    round_with_scaled_bounds(0, prec, rm, |w| airy_constant_bounds(w).0)
+}
+
+// Computes Ai(x), for x != 0.
+pub(crate) fn ai_helper(x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact Airy function");
+    let exp_x = x.exponent();
+    let x_rational = x.to_rational();
+    let large = x.is_positive() && exp_x > 3;
+    let mut shift = 0;
+    if large {
+        // Ai(x) < e ^ (-ζ), and when x >= 2 ^ 21, ζ > 2 ^ 31 > 2 ^ 30 log(2) + 2
+        if exp_x > 21 {
+            return exp_underflow(prec, rm);
+        }
+        let ((l_lo, l_hi), _) = ln_ai_factor_bounds(x, &x_rational, 16);
+        // ln(2) < 7/10
+        if l_hi < Rational::from(Float::MIN_EXPONENT_I64 - 2) * Rational::from_unsigneds(7u8, 10) {
+            return exp_underflow(prec, rm);
+        }
+        shift = exp_shift(&l_lo);
+    }
+    round_with_scaled_bounds(shift, prec, rm, |w| {
+        if exp_x < 0 && -exp_x > i64::exact_from(w + 8) {
+            // |Ai(x) - c_1| < c_2 |x| (1 + |x| ^ 2) < |x| < 2 ^ e
+            let ((c_lo, c_hi), _) = airy_constant_bounds(w + 8);
+            let error = Rational::power_of_2(exp_x);
+            return (c_lo - &error, c_hi + error);
+        }
+        if large {
+            let (l, zeta) = ln_ai_factor_bounds(x, &x_rational, w + 8);
+            // The smallest term of the asymptotic sum is about e ^ (-2ζ) < 2 ^ (-2.88ζ)
+            if &zeta.0 << 1u32 >= w + 8
+                && let Some(s) = ai_asymptotic_sum_bounds(&zeta, w + 8)
+            {
+                return mul_bounds(&exp_scaled_bounds(l, shift, true, w + 8), &s);
+            }
+        }
+        let (lo, hi) = ai_series_bounds(&x_rational, w);
+        (lo >> shift, hi >> shift)
+    })
+}
+
+impl Float {
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Airy function is less than, equal to, or greater than the exact Airy function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{Ai}(x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{Ai}(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p+1}$.
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=0.0$
+    ///
+    /// Overflow is not possible. The result underflows when $x$ is greater than about $1076162$:
+    ///
+    /// Underflow:
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::airy_ai_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::airy_ai_round`] instead. If both of these things are true, consider using
+    /// [`Float::airy_ai`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round(5, Floor);
+    /// assert_eq!(ai.to_string(), "0.00488");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round(5, Ceiling);
+    /// assert_eq!(ai.to_string(), "0.00513");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round(5, Nearest);
+    /// assert_eq!(ai.to_string(), "0.00513");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round(20, Floor);
+    /// assert_eq!(ai.to_string(), "0.0050893500");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round(20, Ceiling);
+    /// assert_eq!(ai.to_string(), "0.0050893575");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round(20, Nearest);
+    /// assert_eq!(ai.to_string(), "0.0050893500");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn airy_ai_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.airy_ai_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Airy function is less than, equal to, or greater than the exact Airy function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::airy_ai_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::airy_ai_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).airy_ai()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round_ref(5, Floor);
+    /// assert_eq!(ai.to_string(), "0.00488");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round_ref(5, Ceiling);
+    /// assert_eq!(ai.to_string(), "0.00513");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round_ref(5, Nearest);
+    /// assert_eq!(ai.to_string(), "0.00513");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round_ref(20, Floor);
+    /// assert_eq!(ai.to_string(), "0.0050893500");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round_ref(20, Ceiling);
+    /// assert_eq!(ai.to_string(), "0.0050893575");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_round_ref(20, Nearest);
+    /// assert_eq!(ai.to_string(), "0.0050893500");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn airy_ai_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { .. } => (Self::ZERO, Equal),
+            Zero { .. } => ai_helper_zero(prec, rm),
+            Finite { .. } => ai_helper(&Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded Airy function is
+    /// less than, equal to, or greater than the exact Airy function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the Airy function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::airy_ai_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::airy_ai`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec(5);
+    /// assert_eq!(ai.to_string(), "0.00513");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec(20);
+    /// assert_eq!(ai.to_string(), "0.0050893500");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn airy_ai_prec(self, prec: u64) -> (Self, Ordering) {
+        self.airy_ai_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded Airy function
+    /// is less than, equal to, or greater than the exact Airy function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the Airy function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::airy_ai_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).airy_ai()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_ref(5);
+    /// assert_eq!(ai.to_string(), "0.00513");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(PI).airy_ai_prec_ref(20);
+    /// assert_eq!(ai.to_string(), "0.0050893500");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn airy_ai_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.airy_ai_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Airy function is less than, equal to, or greater than the exact Airy function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::airy_ai_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::airy_ai`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::from(-PI).airy_ai_round(Floor);
+    /// assert_eq!(ai.to_string(), "-0.41138935391144082");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::from(-PI).airy_ai_round(Ceiling);
+    /// assert_eq!(ai.to_string(), "-0.41138935391144038");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(-PI).airy_ai_round(Nearest);
+    /// assert_eq!(ai.to_string(), "-0.41138935391144082");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn airy_ai_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.airy_ai_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Airy function is less than, equal to, or greater than the exact Airy function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::airy_ai_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).airy_ai()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::from(-PI).airy_ai_round_ref(Floor);
+    /// assert_eq!(ai.to_string(), "-0.41138935391144082");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::from(-PI).airy_ai_round_ref(Ceiling);
+    /// assert_eq!(ai.to_string(), "-0.41138935391144038");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::from(-PI).airy_ai_round_ref(Nearest);
+    /// assert_eq!(ai.to_string(), "-0.41138935391144082");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn airy_ai_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.airy_ai_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`], in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded Airy function is less than,
+    /// equal to, or greater than the exact Airy function. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::airy_ai_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::airy_ai_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::airy_ai_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.airy_ai_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.0050893500");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.airy_ai_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.0050893575");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.airy_ai_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.0050893500");
+    /// ```
+    #[inline]
+    pub fn airy_ai_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.airy_ai_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`], in
+    /// place, rounding the result to the nearest value of the specified precision. An [`Ordering`]
+    /// is returned, indicating whether the rounded Airy function is less than, equal to, or greater
+    /// than the exact Airy function. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the Airy function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::airy_ai_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::airy_ai_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.airy_ai_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.00513");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.airy_ai_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.0050893500");
+    /// ```
+    #[inline]
+    pub fn airy_ai_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.airy_ai_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`], in
+    /// place, rounding the result to the precision of the input and with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded Airy function is less
+    /// than, equal to, or greater than the exact Airy function. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::airy_ai_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::airy_ai_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.airy_ai_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-0.41138935391144082");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.airy_ai_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.41138935391144038");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.airy_ai_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-0.41138935391144082");
+    /// ```
+    #[inline]
+    pub fn airy_ai_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.airy_ai_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded Airy function is less than, equal to, or
+    /// greater than the exact Airy function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{Ai}(x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p+1}$.
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::airy_ai_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) =
+    ///     Float::airy_ai_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(ai.to_string(), "0.266");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) =
+    ///     Float::airy_ai_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(ai.to_string(), "0.281");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) =
+    ///     Float::airy_ai_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(ai.to_string(), "0.27068233");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) =
+    ///     Float::airy_ai_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(ai.to_string(), "0.27068281");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn airy_ai_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::airy_ai_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded Airy function is less than, equal to, or
+    /// greater than the exact Airy function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{Ai}(x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p+1}$.
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::airy_ai_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) =
+    ///     Float::airy_ai_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(ai.to_string(), "0.266");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::airy_ai_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(ai.to_string(), "0.281");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ai, o) = Float::airy_ai_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(ai.to_string(), "0.27068233");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::airy_ai_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(ai.to_string(), "0.27068281");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn airy_ai_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return ai_helper_zero(prec, rm);
+        }
+        ai_helper(&Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Airy function is less than, equal to, or greater than the
+    /// exact Airy function.
+    ///
+    /// If the Airy function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::airy_ai_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::airy_ai_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(ai.to_string(), "0.266");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::airy_ai_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(ai.to_string(), "0.27068281");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn airy_ai_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::airy_ai_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Airy function is less than, equal to, or greater than the
+    /// exact Airy function.
+    ///
+    /// If the Airy function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::airy_ai_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ai, o) = Float::airy_ai_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(ai.to_string(), "0.266");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ai, o) = Float::airy_ai_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(ai.to_string(), "0.27068281");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn airy_ai_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::airy_ai_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl AiryAi for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`], taking
+    /// it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Airy function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{Ai}(x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{Ai}(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::airy_ai_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::airy_ai_round`]. If you want both of these things, consider using
+    /// [`Float::airy_ai_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::AiryAi;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.airy_ai().is_nan());
+    /// assert_eq!(Float::INFINITY.airy_ai(), Float::ZERO);
+    /// assert_eq!(Float::NEGATIVE_INFINITY.airy_ai(), Float::ZERO);
+    /// assert_eq!(
+    ///     Float::from(PI).airy_ai().to_string(),
+    ///     "0.0050893534795944939"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-PI).airy_ai().to_string(),
+    ///     "-0.41138935391144082"
+    /// );
+    /// ```
+    #[inline]
+    fn airy_ai(self) -> Self {
+        let prec = self.significant_bits();
+        self.airy_ai_prec_round(prec, Nearest).0
+    }
+}
+
+impl AiryAi for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`], taking
+    /// it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Airy function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{Ai}(x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{Ai}(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::airy_ai_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::airy_ai_round_ref`]. If you want both of these things, consider using
+    /// [`Float::airy_ai_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::AiryAi;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).airy_ai().is_nan());
+    /// assert_eq!((&Float::INFINITY).airy_ai(), Float::ZERO);
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).airy_ai(), Float::ZERO);
+    /// assert_eq!(
+    ///     (&Float::from(PI)).airy_ai().to_string(),
+    ///     "0.0050893534795944939"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-PI)).airy_ai().to_string(),
+    ///     "-0.41138935391144082"
+    /// );
+    /// ```
+    #[inline]
+    fn airy_ai(self) -> Float {
+        let prec = self.significant_bits();
+        self.airy_ai_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AiryAiAssign for Float {
+    /// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Float`], in
+    /// place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Airy function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{Ai}(x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{Ai}(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{Ai}(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{Ai}(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::airy_ai_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::AiryAiAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.airy_ai_assign();
+    /// assert_eq!(x.to_string(), "0.0050893534795944939");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// x.airy_ai_assign();
+    /// assert_eq!(x.to_string(), "-0.41138935391144082");
+    /// ```
+    #[inline]
+    fn airy_ai_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.airy_ai_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{Ai}(x)+\varepsilon.
+/// $$
+/// - If $\operatorname{Ai}(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+///   to be 0.
+/// - If $\operatorname{Ai}(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{Ai}(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\pm\infty)=0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::airy_ai::primitive_float_airy_ai;
+///
+/// assert!(primitive_float_airy_ai(f64::NAN).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_airy_ai(f64::INFINITY)),
+///     NiceFloat(0.0)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_airy_ai(PI)),
+///     NiceFloat(0.005089353479594493)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_airy_ai(-1.5f32)),
+///     NiceFloat(0.46425658)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_airy_ai<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::airy_ai_prec, x)
+}
+
+/// Computes $\operatorname{Ai}(x)$, the Airy function of the first kind, of a [`Rational`],
+/// returning the result as a primitive float.
+///
+/// $$
+/// f(x) = \operatorname{Ai}(x)+\varepsilon.
+/// $$
+/// - If $\operatorname{Ai}(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+///   to be 0.
+/// - If $\operatorname{Ai}(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{Ai}(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::airy_ai::primitive_float_airy_ai_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_airy_ai_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.2706826474593453)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_airy_ai_rational::<f64>(&Rational::from(10))),
+///     NiceFloat(1.1047532552898686e-10)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_airy_ai_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::airy_ai_rational_prec_ref, x)
+}
@@ -0,0 +1,1058 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::{angle_bounds, sqrt_bounds};
+use crate::float::arithmetic::round_near_x::rational_round_near_x;
+use crate::float::arithmetic::sin_cos::{rational_exponent, round_pi_multiple, round_with_bounds};
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Asin, AsinAssign, Square};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+// Computes asin(x) for a rational x.
+fn asin_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        let (y, o) = asin_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    match x.cmp(&Rational::ONE) {
+        Greater => return (Float::NAN, Equal),
+        Equal => return round_pi_multiple(1, 1, false, prec, rm),
+        Less => {}
+    }
+    if *x == 0u32 {
+        return (Float::ZERO, Equal);
+    }
+    assert_ne!(rm, Exact, "Inexact asin");
+    let exp_x = rational_exponent(x);
+    // For |x| <= 1/2, |asin(x) - x| < |x| ^ 3 / 4
+    if exp_x < 0
+        && let Some(result) =
+            rational_round_near_x(x, u64::exact_from(2 - (exp_x << 1)), true, prec, rm)
+    {
+        return result;
+    }
+    // asin(x) = atan2(x, sqrt(1 - x ^ 2))
+    let one_minus_square = Rational::ONE - x.square();
+    let y = (x.clone(), x.clone());
+    round_with_bounds(prec, rm, |w| {
+        angle_bounds(&y, &sqrt_bounds(&one_minus_square, w + 16), w)
+    })
+}
+
+impl Float {
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arcsine is less than, equal
+    /// to, or greater than the exact arcsine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    /// - $f(1.0,p,m)=\pi/2$
+    /// - $f(-1.0,p,m)=-\pi/2$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Neither overflow nor underflow is possible, since $|x|\leq|\arcsin x|\leq\pi/2$.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round`] instead. If both of these things are true, consider using
+    /// [`Float::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(5, Floor);
+    /// assert_eq!(asin.to_string(), "0.500");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.531");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(5, Nearest);
+    /// assert_eq!(asin.to_string(), "0.531");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(20, Floor);
+    /// assert_eq!(asin.to_string(), "0.52359867");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.52359962");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(20, Nearest);
+    /// assert_eq!(asin.to_string(), "0.52359867");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.asin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arcsine is less than, equal
+    /// to, or greater than the exact arcsine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).asin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(5, Floor);
+    /// assert_eq!(asin.to_string(), "0.500");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.531");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(5, Nearest);
+    /// assert_eq!(asin.to_string(), "0.531");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(20, Floor);
+    /// assert_eq!(asin.to_string(), "0.52359867");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.52359962");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(20, Nearest);
+    /// assert_eq!(asin.to_string(), "0.52359867");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn asin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { .. } => (Self::NAN, Equal),
+            Zero { .. } => (self.clone(), Equal),
+            Finite { .. } => asin_rational_helper(&Rational::exact_from(self), prec, rm),
+        }
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec(5);
+    /// assert_eq!(asin.to_string(), "0.531");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec(20);
+    /// assert_eq!(asin.to_string(), "0.52359867");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec(self, prec: u64) -> (Self, Ordering) {
+        self.asin_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).asin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_ref(5);
+    /// assert_eq!(asin.to_string(), "0.531");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_ref(20);
+    /// assert_eq!(asin.to_string(), "0.52359867");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.asin_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], rounding the result to the precision of
+    /// the input and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arcsine is less than, equal
+    /// to, or greater than the exact arcsine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::FRAC_1_SQRT_2;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(FRAC_1_SQRT_2).asin_round(Floor);
+    /// assert_eq!(asin.to_string(), "0.78539816339744828");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(FRAC_1_SQRT_2).asin_round(Ceiling);
+    /// assert_eq!(asin.to_string(), "0.78539816339744839");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(FRAC_1_SQRT_2).asin_round(Nearest);
+    /// assert_eq!(asin.to_string(), "0.78539816339744839");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asin_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round(prec, rm)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], rounding the result to the precision of
+    /// the input and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arcsine is less than, equal
+    /// to, or greater than the exact arcsine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).asin()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::FRAC_1_SQRT_2;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(FRAC_1_SQRT_2).asin_round_ref(Floor);
+    /// assert_eq!(asin.to_string(), "0.78539816339744828");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(FRAC_1_SQRT_2).asin_round_ref(Ceiling);
+    /// assert_eq!(asin.to_string(), "0.78539816339744839");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(FRAC_1_SQRT_2).asin_round_ref(Nearest);
+    /// assert_eq!(asin.to_string(), "0.78539816339744839");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asin_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.52359867");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.52359962");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.52359867");
+    /// ```
+    #[inline]
+    pub fn asin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.asin_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded arcsine is less than, equal to, or greater than the exact arcsine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.531");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.52359867");
+    /// ```
+    #[inline]
+    pub fn asin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asin_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], in place, rounding the result to the
+    /// precision of the input and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::FRAC_1_SQRT_2;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.78539816339744828");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.78539816339744839");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.78539816339744839");
+    /// ```
+    #[inline]
+    pub fn asin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    /// - $f(1,p,m)=\pi/2$
+    /// - $f(-1,p,m)=-\pi/2$
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(asin.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.344");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(asin.to_string(), "0.33983660");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.33983707");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn asin_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::asin_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    /// - $f(1,p,m)=\pi/2$
+    /// - $f(-1,p,m)=-\pi/2$
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(asin.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.344");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(asin.to_string(), "0.33983660");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) =
+    ///     Float::asin_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.33983707");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn asin_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        asin_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Rational`], rounding the result to the nearest
+    /// value of the specified precision and returning the result as a [`Float`]. The [`Rational`]
+    /// is taken by value. An [`Ordering`] is also returned, indicating whether the rounded arcsine
+    /// is less than, equal to, or greater than the exact arcsine.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asin_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::asin_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(asin.to_string(), "0.344");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::asin_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(asin.to_string(), "0.33983707");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn asin_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::asin_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\arcsin x$, the arcsine of a [`Rational`], rounding the result to the nearest
+    /// value of the specified precision and returning the result as a [`Float`]. The [`Rational`]
+    /// is taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// arcsine is less than, equal to, or greater than the exact arcsine.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asin_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::asin_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(asin.to_string(), "0.344");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::asin_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(asin.to_string(), "0.33983707");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asin_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::asin_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Asin for Float {
+    type Output = Self;
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    /// - $f(1.0)=\pi/2$
+    /// - $f(-1.0)=-\pi/2$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::asin_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::asin_round`].
+    /// If you want both of these things, consider using [`Float::asin_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::FRAC_1_SQRT_2;
+    /// use malachite_base::num::arithmetic::traits::Asin;
+    /// use malachite_base::num::basic::traits::{NaN, One, Two, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.asin().is_nan());
+    /// assert!(Float::TWO.asin().is_nan());
+    /// assert_eq!(Float::ONE.asin().to_string(), "2.0");
+    /// assert_eq!(Float::ZERO.asin(), 0.0);
+    /// assert_eq!(Float::from(0.3).asin().to_string(), "0.30469265401539752");
+    /// assert_eq!(
+    ///     Float::from(FRAC_1_SQRT_2).asin().to_string(),
+    ///     "0.78539816339744839"
+    /// );
+    /// ```
+    #[inline]
+    fn asin(self) -> Self {
+        let prec = self.significant_bits();
+        self.asin_prec_round(prec, Nearest).0
+    }
+}
+
+impl Asin for &Float {
+    type Output = Float;
+
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    /// - $f(1.0)=\pi/2$
+    /// - $f(-1.0)=-\pi/2$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asin_round_ref`]. If you want both of these things, consider using
+    /// [`Float::asin_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::FRAC_1_SQRT_2;
+    /// use malachite_base::num::arithmetic::traits::Asin;
+    /// use malachite_base::num::basic::traits::{NaN, One, Two, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).asin().is_nan());
+    /// assert!((&Float::TWO).asin().is_nan());
+    /// assert_eq!((&Float::ONE).asin().to_string(), "2.0");
+    /// assert_eq!((&Float::ZERO).asin(), 0.0);
+    /// assert_eq!(
+    ///     (&Float::from(0.3)).asin().to_string(),
+    ///     "0.30469265401539752"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(FRAC_1_SQRT_2)).asin().to_string(),
+    ///     "0.78539816339744839"
+    /// );
+    /// ```
+    #[inline]
+    fn asin(self) -> Float {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AsinAssign for Float {
+    /// Computes $\arcsin x$, the arcsine of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::FRAC_1_SQRT_2;
+    /// use malachite_base::num::arithmetic::traits::AsinAssign;
+    /// use malachite_base::num::basic::traits::{NaN, One, Two, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(0.3);
+    /// x.asin_assign();
+    /// assert_eq!(x.to_string(), "0.30469265401539752");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// x.asin_assign();
+    /// assert_eq!(x.to_string(), "0.78539816339744839");
+    /// ```
+    #[inline]
+    fn asin_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\arcsin x$, the arcsine of a primitive float, returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = \arcsin x+\varepsilon.
+/// $$
+/// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+/// - $f(x)=\text{NaN}$ if $|x|>1$
+/// - $f(1.0)=\pi/2$
+/// - $f(-1.0)=-\pi/2$
+/// - $f(0.0)=0.0$
+/// - $f(-0.0)=-0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::FRAC_PI_2;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::asin::primitive_float_asin;
+///
+/// assert!(primitive_float_asin(f64::NAN).is_nan());
+/// assert!(primitive_float_asin(2.0f64).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_asin(1.0f64)),
+///     NiceFloat(FRAC_PI_2)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_asin(0.5f64)),
+///     NiceFloat(0.5235987755982989)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_asin(0.3f32)),
+///     NiceFloat(0.30469266)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_asin<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::asin_prec, x)
+}
+
+/// Computes $\arcsin x$, the arcsine of a [`Rational`], returning the result as a primitive float.
+///
+/// $$
+/// f(x) = \arcsin x+\varepsilon.
+/// $$
+/// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arcsin
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x)=\text{NaN}$ if $|x|>1$
+/// - $f(1)=\pi/2$
+/// - $f(-1)=-\pi/2$
+/// - $f(0)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::asin::primitive_float_asin_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_asin_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.3398369094541219)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_asin_rational::<f64>(&Rational::from(2))),
+///     NiceFloat(f64::NAN)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_asin_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::asin_rational_prec_ref, x)
+}
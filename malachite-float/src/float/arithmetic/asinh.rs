@@ -0,0 +1,1086 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::sqrt_bounds;
+use crate::float::arithmetic::round_near_x::rational_round_near_x;
+use crate::float::arithmetic::sin_cos::{
+    initial_working_prec, rational_exponent, round_with_bounds,
+};
+use crate::float::arithmetic::sinh_cosh::ln_1_plus_x_bounds;
+use crate::floor_and_ceiling;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Asinh, AsinhAssign, PowerOf2, Square};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+// Returns bounds on asinh(x), for a positive rational x with exponent `exp_x`, with a relative
+// width of about `2 ^ (-w)`.
+fn asinh_bounds(x: &Rational, exp_x: i64, w: u64) -> (Rational, Rational) {
+    if exp_x > 0 && u64::exact_from(exp_x << 1) > w + 16 {
+        // asinh(x) = log(2x) + ε, with 0 < ε < 1 / (4 * x ^ 2) <= 2 ^ (-2 * exp_x) < 2 ^ (-w - 16).
+        // This also keeps the intermediate values of the general case below from overflowing.
+        let (lo, hi) = floor_and_ceiling(Float::ln_rational_prec_round_ref(&(x << 1u32), w, Floor));
+        (
+            Rational::exact_from(lo),
+            Rational::exact_from(hi) + Rational::power_of_2(-i64::exact_from(w + 16)),
+        )
+    } else {
+        // asinh(x) = log(1 + d), where d = x + x ^ 2 / (1 + sqrt(1 + x ^ 2))
+        let square = x.square();
+        let (s_lo, s_hi) = sqrt_bounds(&(&square + Rational::ONE), w + 8);
+        let d_lo = x + &square / (s_hi + Rational::ONE);
+        let d_hi = x + &square / (s_lo + Rational::ONE);
+        ln_1_plus_x_bounds(&d_lo, &d_hi, w)
+    }
+}
+
+// Computes asinh(x) for a nonzero rational x.
+fn asinh_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        let (y, o) = asinh_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact asinh");
+    let exp_x = rational_exponent(x);
+    // For 0 < x <= 1/2, x - x ^ 3 / 6 < asinh(x) < x
+    if exp_x < 0
+        && let Some(result) =
+            rational_round_near_x(x, u64::exact_from(2 - (exp_x << 1)), false, prec, rm)
+    {
+        return result;
+    }
+    round_with_bounds(prec, rm, |w| Some(asinh_bounds(x, exp_x, w)))
+}
+
+// Computes asinh(x) for a finite, nonzero `Float` x. A huge x is not converted to a `Rational`,
+// whose numerator would have as many bits as x's exponent; instead, asinh(x) = log(x) + log(2) + ε,
+// as in `asinh_bounds`, is computed from x directly.
+fn asinh_float_helper(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exp_x = i64::from(x.get_exponent().unwrap());
+    if exp_x <= 0 || u64::exact_from(exp_x << 1) <= initial_working_prec(prec) + 16 {
+        return asinh_rational_helper(&Rational::exact_from(x), prec, rm);
+    }
+    if *x < 0u32 {
+        let (y, o) = asinh_float_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact asinh");
+    round_with_bounds(prec, rm, |w| {
+        Some(if u64::exact_from(exp_x << 1) > w + 16 {
+            let (lo, hi) = floor_and_ceiling(x.ln_prec_round_ref(w, Floor));
+            let (ln_2_lo, ln_2_hi) = floor_and_ceiling(Float::ln_2_prec_round(w, Floor));
+            (
+                Rational::exact_from(lo) + Rational::exact_from(ln_2_lo),
+                Rational::exact_from(hi)
+                    + Rational::exact_from(ln_2_hi)
+                    + Rational::power_of_2(-i64::exact_from(w + 16)),
+            )
+        } else {
+            // Now `w` is at least 2 * exp_x - 16, so the conversion is cheap by comparison
+            asinh_bounds(&Rational::exact_from(x), exp_x, w)
+        })
+    })
+}
+
+impl Float {
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], rounding the
+    /// result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic sine is less than, equal to, or greater than the exact inverse hyperbolic sine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{asinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{asinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{asinh}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{asinh}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Overflow is not possible. Since $|\operatorname{asinh} x|<|x|$ for nonzero $x$, the result
+    /// can underflow, but only if $|x|$ is the smallest positive [`Float`], $2^{-2^{30}}$:
+    ///
+    /// Underflow:
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round`] instead. If both of these things are true, consider using
+    /// [`Float::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round(5, Floor);
+    /// assert_eq!(asinh.to_string(), "0.875");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round(5, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.906");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round(5, Nearest);
+    /// assert_eq!(asinh.to_string(), "0.875");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round(20, Floor);
+    /// assert_eq!(asinh.to_string(), "0.88137341");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round(20, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.88137436");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round(20, Nearest);
+    /// assert_eq!(asinh.to_string(), "0.88137341");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.asinh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], rounding the
+    /// result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic sine is less than, equal to, or greater than the exact inverse hyperbolic sine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).asinh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round_ref(5, Floor);
+    /// assert_eq!(asinh.to_string(), "0.875");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round_ref(5, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.906");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round_ref(5, Nearest);
+    /// assert_eq!(asinh.to_string(), "0.875");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round_ref(20, Floor);
+    /// assert_eq!(asinh.to_string(), "0.88137341");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round_ref(20, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.88137436");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_round_ref(20, Nearest);
+    /// assert_eq!(asinh.to_string(), "0.88137341");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn asinh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { .. } | Zero { .. } => (self.clone(), Equal),
+            Finite { .. } => asinh_float_helper(self, prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], rounding the
+    /// result to the nearest value of the specified precision. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic sine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec(5);
+    /// assert_eq!(asinh.to_string(), "0.875");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec(20);
+    /// assert_eq!(asinh.to_string(), "0.88137341");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec(self, prec: u64) -> (Self, Ordering) {
+        self.asinh_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], rounding the
+    /// result to the nearest value of the specified precision. The [`Float`] is taken by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic sine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).asinh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_ref(5);
+    /// assert_eq!(asinh.to_string(), "0.875");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from_unsigned_prec(1u32, 100).0.asinh_prec_ref(20);
+    /// assert_eq!(asinh.to_string(), "0.88137341");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.asinh_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], rounding the
+    /// result to the precision of the input and with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic sine is less than, equal to, or greater than the exact inverse hyperbolic sine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asinh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::from(PI).asinh_round(Floor);
+    /// assert_eq!(asinh.to_string(), "1.8622957433108471");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from(PI).asinh_round(Ceiling);
+    /// assert_eq!(asinh.to_string(), "1.8622957433108489");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::from(PI).asinh_round(Nearest);
+    /// assert_eq!(asinh.to_string(), "1.8622957433108489");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asinh_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], rounding the
+    /// result to the precision of the input and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic sine is less than, equal to, or greater than the exact inverse hyperbolic sine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asinh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).asinh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::from(PI).asinh_round_ref(Floor);
+    /// assert_eq!(asinh.to_string(), "1.8622957433108471");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::from(PI).asinh_round_ref(Ceiling);
+    /// assert_eq!(asinh.to_string(), "1.8622957433108489");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::from(PI).asinh_round_ref(Nearest);
+    /// assert_eq!(asinh.to_string(), "1.8622957433108489");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asinh_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], in place,
+    /// rounding the result to the specified precision and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded inverse hyperbolic sine is less
+    /// than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::asinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.asinh_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.88137341");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.asinh_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.88137436");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.asinh_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.88137341");
+    /// ```
+    #[inline]
+    pub fn asinh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.asinh_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], in place,
+    /// rounding the result to the nearest value of the specified precision. An [`Ordering`] is
+    /// returned, indicating whether the rounded inverse hyperbolic sine is less than, equal to, or
+    /// greater than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::asinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.asinh_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "0.875");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.asinh_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.88137341");
+    /// ```
+    #[inline]
+    pub fn asinh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asinh_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], in place,
+    /// rounding the result to the precision of the input and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded inverse hyperbolic sine is less
+    /// than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::asinh_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::asinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.8622957433108471");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.8622957433108489");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.8622957433108489");
+    /// ```
+    #[inline]
+    pub fn asinh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Rational`], rounding
+    /// the result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic sine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic sine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{asinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{asinh}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{asinh}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(asinh.to_string(), "0.312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(asinh.to_string(), "0.32744980");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.32745028");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn asinh_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::asinh_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Rational`], rounding
+    /// the result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic sine is less than, equal to, or
+    /// greater than the exact inverse hyperbolic sine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{asinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{asinh}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{asinh}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(asinh.to_string(), "0.312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(asinh.to_string(), "0.32744980");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(asinh.to_string(), "0.32745028");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn asinh_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::ZERO, Equal);
+        }
+        asinh_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Rational`], rounding
+    /// the result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded inverse hyperbolic sine is less than, equal to, or greater than the
+    /// exact inverse hyperbolic sine.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asinh_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(asinh.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(asinh.to_string(), "0.32745028");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn asinh_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::asinh_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Rational`], rounding
+    /// the result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic sine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic sine.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::asinh_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(asinh.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asinh, o) = Float::asinh_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(asinh.to_string(), "0.32745028");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asinh_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::asinh_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Asinh for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], taking it by
+    /// value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// sine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{asinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{asinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{asinh} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::asinh_round`]. If you want both of these things, consider using
+    /// [`Float::asinh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asinh;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.asinh().is_nan());
+    /// assert_eq!(Float::INFINITY.asinh(), Float::INFINITY);
+    /// assert_eq!(Float::ZERO.asinh(), 0.0);
+    /// assert_eq!(Float::from(PI).asinh().to_string(), "1.8622957433108489");
+    /// assert_eq!(Float::from_unsigned_prec(1u32, 100).0.asinh().to_string(), "0.88137358701954302523260932497968");
+    /// ```
+    #[inline]
+    fn asinh(self) -> Self {
+        let prec = self.significant_bits();
+        self.asinh_prec_round(prec, Nearest).0
+    }
+}
+
+impl Asinh for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], taking it by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// sine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{asinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{asinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{asinh} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asinh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::asinh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asinh;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).asinh().is_nan());
+    /// assert_eq!((&Float::INFINITY).asinh(), Float::INFINITY);
+    /// assert_eq!((&Float::ZERO).asinh(), 0.0);
+    /// assert_eq!((&Float::from(PI)).asinh().to_string(), "1.8622957433108489");
+    /// assert_eq!((&Float::from_unsigned_prec(1u32, 100).0).asinh().to_string(), "0.88137358701954302523260932497968");
+    /// ```
+    #[inline]
+    fn asinh(self) -> Float {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AsinhAssign for Float {
+    /// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// sine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{asinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{asinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{asinh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{asinh} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AsinhAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.asinh_assign();
+    /// assert_eq!(x.to_string(), "1.8622957433108489");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// x.asinh_assign();
+    /// assert_eq!(x.to_string(), "0.88137358701954302523260932497968");
+    /// ```
+    #[inline]
+    fn asinh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a primitive float, returning a
+/// correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{asinh} x+\varepsilon.
+/// $$
+/// - If $\operatorname{asinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{asinh} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{asinh} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\infty)=\infty$
+/// - $f(-\infty)=-\infty$
+/// - $f(0.0)=0.0$
+/// - $f(-0.0)=-0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::asinh::primitive_float_asinh;
+///
+/// assert!(primitive_float_asinh(f64::NAN).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_asinh(f64::NEGATIVE_INFINITY)), NiceFloat(f64::NEGATIVE_INFINITY));
+/// assert_eq!(NiceFloat(primitive_float_asinh(-0.0f64)), NiceFloat(-0.0));
+/// assert_eq!(NiceFloat(primitive_float_asinh(PI)), NiceFloat(1.8622957433108482));
+/// assert_eq!(NiceFloat(primitive_float_asinh(1.0f32)), NiceFloat(0.8813736));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_asinh<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::asinh_prec, x)
+}
+
+/// Computes $\operatorname{asinh} x$, the inverse hyperbolic sine of a [`Rational`], returning the
+/// result as a primitive float.
+///
+/// $$
+/// f(x) = \operatorname{asinh} x+\varepsilon.
+/// $$
+/// - If $\operatorname{asinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{asinh} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{asinh} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::asinh::primitive_float_asinh_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_asinh_rational::<f64>(&Rational::from_unsigneds(1u8, 3))), NiceFloat(0.32745015023725843));
+/// assert_eq!(NiceFloat(primitive_float_asinh_rational::<f64>(&Rational::from(10000))), NiceFloat(9.903487555036127));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_asinh_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::asinh_rational_prec_ref, x)
+}
@@ -0,0 +1,1076 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::angle_bounds;
+use crate::float::arithmetic::round_near_x::rational_round_near_x;
+use crate::float::arithmetic::sin_cos::{
+    rational_exponent, round_near_pi, round_pi_multiple, round_with_bounds,
+};
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Atan, AtanAssign, PowerOf2};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+// Computes atan(x) for a nonzero rational x.
+fn atan_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        let (y, o) = atan_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact atan");
+    let exp_x = rational_exponent(x);
+    if exp_x < 0 {
+        // |atan(x) - x| < |x| ^ 3 / 3
+        if let Some(result) =
+            rational_round_near_x(x, u64::exact_from(1 - (exp_x << 1)), false, prec, rm)
+        {
+            return result;
+        }
+    } else if exp_x > 1
+        && let Some(result) = round_near_pi(true, 1 - exp_x, prec, rm)
+    {
+        // |atan(x) - π / 2| = atan(1 / x) < 2 ^ (1 - exp_x)
+        return result;
+    }
+    // atan(x) = atan2(x, 1), with both arguments scaled down so that neither exceeds 1
+    let e = exp_x.max(0);
+    let y = x >> e;
+    let x = Rational::power_of_2(-e);
+    let y = (y.clone(), y);
+    let x = (x.clone(), x);
+    round_with_bounds(prec, rm, |w| angle_bounds(&y, &x, w))
+}
+
+impl Float {
+    /// Computes $\arctan x$, the arctangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\pi/2$
+    /// - $f(-\infty,p,m)=-\pi/2$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Overflow is not possible. Since $|\arctan x|<|x|$, the result can underflow, but only if
+    /// $|x|$ is the smallest positive [`Float`], $2^{-2^{30}}$:
+    ///
+    /// Underflow:
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round`] instead. If both of these things are true, consider using
+    /// [`Float::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round(5, Floor);
+    /// assert_eq!(atan.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round(5, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.812");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round(5, Nearest);
+    /// assert_eq!(atan.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round(20, Floor);
+    /// assert_eq!(atan.to_string(), "0.78539753");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round(20, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.78539848");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round(20, Nearest);
+    /// assert_eq!(atan.to_string(), "0.78539848");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn atan_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.atan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).atan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round_ref(5, Floor);
+    /// assert_eq!(atan.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round_ref(5, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.812");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round_ref(5, Nearest);
+    /// assert_eq!(atan.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round_ref(20, Floor);
+    /// assert_eq!(atan.to_string(), "0.78539753");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round_ref(20, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.78539848");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .atan_prec_round_ref(20, Nearest);
+    /// assert_eq!(atan.to_string(), "0.78539848");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn atan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { sign } => round_pi_multiple(1, 1, !sign, prec, rm),
+            Zero { .. } => (self.clone(), Equal),
+            Finite { .. } => atan_rational_helper(&Rational::exact_from(self), prec, rm),
+        }
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100).0.atan_prec(5);
+    /// assert_eq!(atan.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100).0.atan_prec(20);
+    /// assert_eq!(atan.to_string(), "0.78539848");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn atan_prec(self, prec: u64) -> (Self, Ordering) {
+        self.atan_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arctangent is less than, equal to, or greater
+    /// than the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).atan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100).0.atan_prec_ref(5);
+    /// assert_eq!(atan.to_string(), "0.781");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from_unsigned_prec(1u32, 100).0.atan_prec_ref(20);
+    /// assert_eq!(atan.to_string(), "0.78539848");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn atan_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.atan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], rounding the result to the precision of
+    /// the input and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Floor);
+    /// assert_eq!(atan.to_string(), "1.2626272556789111");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Ceiling);
+    /// assert_eq!(atan.to_string(), "1.2626272556789129");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Nearest);
+    /// assert_eq!(atan.to_string(), "1.2626272556789111");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round(prec, rm)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], rounding the result to the precision of
+    /// the input and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).atan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Floor);
+    /// assert_eq!(atan.to_string(), "1.2626272556789111");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Ceiling);
+    /// assert_eq!(atan.to_string(), "1.2626272556789129");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Nearest);
+    /// assert_eq!(atan.to_string(), "1.2626272556789111");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.atan_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.78539753");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.atan_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.78539848");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.atan_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.78539848");
+    /// ```
+    #[inline]
+    pub fn atan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.atan_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.atan_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "0.781");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.atan_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "0.78539848");
+    /// ```
+    #[inline]
+    pub fn atan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atan_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], in place, rounding the result to the
+    /// precision of the input and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.2626272556789111");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.2626272556789129");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.2626272556789111");
+    /// ```
+    #[inline]
+    pub fn atan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(atan.to_string(), "0.312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(atan.to_string(), "0.32175016");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.32175064");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::atan_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(atan.to_string(), "0.312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(atan.to_string(), "0.32175016");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) =
+    ///     Float::atan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(atan.to_string(), "0.32175064");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn atan_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::ZERO, Equal);
+        }
+        atan_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Rational`], rounding the result to the nearest
+    /// value of the specified precision and returning the result as a [`Float`]. The [`Rational`]
+    /// is taken by value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::atan_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(atan.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::atan_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(atan.to_string(), "0.32175064");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::atan_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\arctan x$, the arctangent of a [`Rational`], rounding the result to the nearest
+    /// value of the specified precision and returning the result as a [`Float`]. The [`Rational`]
+    /// is taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::atan_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(atan.to_string(), "0.328");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::atan_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(atan.to_string(), "0.32175064");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn atan_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::atan_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Atan for Float {
+    type Output = Self;
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\pi/2$
+    /// - $f(-\infty)=-\pi/2$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::atan_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::atan_round`].
+    /// If you want both of these things, consider using [`Float::atan_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Atan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.atan().is_nan());
+    /// assert_eq!(Float::INFINITY.atan().to_string(), "2.0");
+    /// assert_eq!(Float::ZERO.atan(), 0.0);
+    /// assert_eq!(Float::from(PI).atan().to_string(), "1.2626272556789111");
+    /// assert_eq!(Float::from(0.3).atan().to_string(), "0.29145679447786710");
+    /// ```
+    #[inline]
+    fn atan(self) -> Self {
+        let prec = self.significant_bits();
+        self.atan_prec_round(prec, Nearest).0
+    }
+}
+
+impl Atan for &Float {
+    type Output = Float;
+
+    /// Computes $\arctan x$, the arctangent of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\pi/2$
+    /// - $f(-\infty)=-\pi/2$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan_round_ref`]. If you want both of these things, consider using
+    /// [`Float::atan_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Atan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).atan().is_nan());
+    /// assert_eq!((&Float::INFINITY).atan().to_string(), "2.0");
+    /// assert_eq!((&Float::ZERO).atan(), 0.0);
+    /// assert_eq!((&Float::from(PI)).atan().to_string(), "1.2626272556789111");
+    /// assert_eq!(
+    ///     (&Float::from(0.3)).atan().to_string(),
+    ///     "0.29145679447786710"
+    /// );
+    /// ```
+    #[inline]
+    fn atan(self) -> Float {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AtanAssign for Float {
+    /// Computes $\arctan x$, the arctangent of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::AtanAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.atan_assign();
+    /// assert_eq!(x.to_string(), "1.2626272556789111");
+    ///
+    /// let mut x = Float::from(0.3);
+    /// x.atan_assign();
+    /// assert_eq!(x.to_string(), "0.29145679447786710");
+    /// ```
+    #[inline]
+    fn atan_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\arctan x$, the arctangent of a primitive float, returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = \arctan x+\varepsilon.
+/// $$
+/// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\infty)=\pi/2$
+/// - $f(-\infty)=-\pi/2$
+/// - $f(0.0)=0.0$
+/// - $f(-0.0)=-0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::{FRAC_PI_2, PI};
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::atan::primitive_float_atan;
+///
+/// assert!(primitive_float_atan(f64::NAN).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan(f64::INFINITY)),
+///     NiceFloat(FRAC_PI_2)
+/// );
+/// assert_eq!(NiceFloat(primitive_float_atan(-0.0f64)), NiceFloat(-0.0));
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan(PI)),
+///     NiceFloat(1.2626272556789118)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan(1.0f32)),
+///     NiceFloat(0.7853982)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_atan<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::atan_prec, x)
+}
+
+/// Computes $\arctan x$, the arctangent of a [`Rational`], returning the result as a primitive
+/// float.
+///
+/// $$
+/// f(x) = \arctan x+\varepsilon.
+/// $$
+/// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\arctan
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::atan::primitive_float_atan_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.3217505543966422)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan_rational::<f64>(&Rational::from(10000))),
+///     NiceFloat(1.5706963267952299)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_atan_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::atan_rational_prec_ref, x)
+}
@@ -0,0 +1,1635 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The inverse trigonometric functions are all computed as angles: atan2(y, x) is the angle θ in
+// (0, π) (for y > 0) at which g(θ) = x sin(θ) - y cos(θ) changes sign from negative to positive;
+// atan(x) = atan2(x, 1), asin(x) = atan2(x, sqrt(1 - x ^ 2)), and acos(x) = atan2(sqrt(1 - x ^ 2),
+// x). The angle is found by Newton's method, using the sine and cosine from `sin_cos`, and is then
+// certified by evaluating g, with rigorous bounds, slightly to either side of it. Since
+// g(θ) = r sin(θ - θ*), where r = sqrt(x ^ 2 + y ^ 2) and θ* is the exact angle, Newton's method
+// converges cubically.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::round_near_x::rational_round_near_x;
+use crate::float::arithmetic::sin_cos::{
+    rational_exponent, round_near_pi, round_pi_multiple, round_with_bounds, sin_cos_bounds,
+};
+use crate::{Float, emulate_float_float_to_float_fn, floor_and_ceiling};
+use core::cmp::{
+    Ordering::{self, *},
+    max,
+};
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use malachite_base::num::arithmetic::traits::{Abs, Atan2, Atan2Assign, PowerOf2};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, NegativeZero, One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+// Approximates atan(t) for 0 <= t <= 1, with an absolute error less than 0.002.
+fn atan_estimate(t: f64) -> f64 {
+    t * (FRAC_PI_4 + 0.273 * (1.0 - t))
+}
+
+// Returns an approximation of atan2(y, x), for y > 0, with an absolute error less than 0.002.
+fn initial_angle(y: &Rational, x: &Rational) -> Rational {
+    let x_abs = x.abs();
+    let positive = *x > 0u32;
+    let theta = if x_abs >= *y {
+        let a = atan_estimate(f64::rounding_from(&(y / x_abs), Nearest).0);
+        if positive { a } else { PI - a }
+    } else {
+        let a = atan_estimate(f64::rounding_from(&(x_abs / y), Nearest).0);
+        if positive {
+            FRAC_PI_2 - a
+        } else {
+            FRAC_PI_2 + a
+        }
+    };
+    Rational::exact_from(theta)
+}
+
+fn midpoint((lo, hi): &(Rational, Rational)) -> Rational {
+    (lo + hi) >> 1u32
+}
+
+pub(crate) fn mul_bounds(
+    (a_lo, a_hi): &(Rational, Rational),
+    (b_lo, b_hi): &(Rational, Rational),
+) -> (Rational, Rational) {
+    let mut ps = [a_lo * b_lo, a_lo * b_hi, a_hi * b_lo, a_hi * b_hi];
+    ps.sort_unstable();
+    let [lo, _, _, hi] = ps;
+    (lo, hi)
+}
+
+fn sin_cos_bounds_or_zero(
+    theta: &Rational,
+    w: u64,
+) -> ((Rational, Rational), (Rational, Rational)) {
+    if *theta == 0u32 {
+        (
+            (Rational::ZERO, Rational::ZERO),
+            (Rational::ONE, Rational::ONE),
+        )
+    } else {
+        sin_cos_bounds(theta, w)
+    }
+}
+
+// Bounds on g(θ) = x sin(θ) - y cos(θ), where x and y are themselves given by bounds.
+fn g_bounds(
+    y: &(Rational, Rational),
+    x: &(Rational, Rational),
+    theta: &Rational,
+    w: u64,
+) -> (Rational, Rational) {
+    let (sin, cos) = sin_cos_bounds_or_zero(theta, w);
+    let (xs_lo, xs_hi) = mul_bounds(x, &sin);
+    let (yc_lo, yc_hi) = mul_bounds(y, &cos);
+    (xs_lo - yc_hi, xs_hi - yc_lo)
+}
+
+// Returns bounds on the angle atan2(y, x), with a relative width of about `2 ^ (-w)`, given bounds
+// on y and x. The bounds on y must be positive, and those on x must have the same sign (or both be
+// zero). Both x and y must be at most 1 in absolute value. Returns `None` if the angle could not be
+// certified, which happens only when the bounds on x and y are too wide.
+pub(crate) fn angle_bounds(
+    y: &(Rational, Rational),
+    x: &(Rational, Rational),
+    w: u64,
+) -> Option<(Rational, Rational)> {
+    let y_mid = midpoint(y);
+    let x_mid = midpoint(x);
+    let mut theta = initial_angle(&y_mid, &x_mid);
+    // Each Newton step roughly triples the number of correct bits, starting from about 9.
+    let mut precs = Vec::new();
+    let mut p = w + 16;
+    while p > 24 {
+        precs.push(p);
+        p = p / 3 + 2;
+    }
+    precs.push(p);
+    for &p in precs.iter().rev() {
+        let (sin, cos) = sin_cos_bounds_or_zero(&theta, p + 8);
+        let sin = midpoint(&sin);
+        let cos = midpoint(&cos);
+        let g = &x_mid * &sin - &y_mid * &cos;
+        let dg = &x_mid * cos + &y_mid * sin;
+        if dg <= 0u32 {
+            return None;
+        }
+        theta -= g / dg;
+        if theta <= 0u32 {
+            return None;
+        }
+        theta = Rational::exact_from(Float::from_rational_prec_round(theta, p, Nearest).0);
+    }
+    let delta = Rational::power_of_2(rational_exponent(&theta) - i64::exact_from(w));
+    let lo = &theta - &delta;
+    let hi = theta + delta;
+    if lo <= 0u32 || hi >= 4u32 {
+        return None;
+    }
+    let w = w + 8;
+    if g_bounds(y, x, &lo, w).1 < 0u32 && g_bounds(y, x, &hi, w).0 > 0u32 {
+        Some((lo, hi))
+    } else {
+        None
+    }
+}
+
+// Returns bounds on sqrt(x), for a positive rational x, with a relative width of about
+// `2 ^ (-prec)`.
+pub(crate) fn sqrt_bounds(x: &Rational, prec: u64) -> (Rational, Rational) {
+    let (lo, hi) = floor_and_ceiling(Float::sqrt_rational_prec_round_ref(x, prec, Floor));
+    (Rational::exact_from(lo), Rational::exact_from(hi))
+}
+
+// Computes atan2(y, x) for finite, nonzero `y` and `x`.
+fn atan2_finite(y: &Float, x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if y.is_sign_negative() {
+        let (theta, o) = atan2_finite(&-y, x, prec, -rm);
+        return (-theta, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact atan2");
+    let exp_y = i64::from(y.get_exponent().unwrap());
+    let exp_x = i64::from(x.get_exponent().unwrap());
+    let d = exp_y - exp_x;
+    let positive = x.is_sign_positive();
+    if d < 0 {
+        if positive {
+            // atan2(y, x) = atan(y / x), and |atan(t) - t| < |t| ^ 3 / 3
+            let t = Rational::exact_from(y) / Rational::exact_from(x);
+            let exp_t = rational_exponent(&t);
+            if let Some(result) =
+                rational_round_near_x(&t, u64::exact_from(1 - (exp_t << 1)), false, prec, rm)
+            {
+                return result;
+            }
+        } else if let Some(result) = round_near_pi(false, d + 1, prec, rm) {
+            // |atan2(y, x) - π| = atan(y / |x|) < 2 ^ (d + 1)
+            return result;
+        }
+    } else if d > 0
+        && let Some(result) = round_near_pi(true, 1 - d, prec, rm)
+    {
+        // |atan2(y, x) - π / 2| = atan(|x| / y) < 2 ^ (1 - d)
+        return result;
+    }
+    let e = exp_y.max(exp_x);
+    let y = Rational::exact_from(y) >> e;
+    let x = Rational::exact_from(x) >> e;
+    let y = (y.clone(), y);
+    let x = (x.clone(), x);
+    round_with_bounds(prec, rm, |w| angle_bounds(&y, &x, w))
+}
+
+fn atan2_prec_round_helper(y: &Float, x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (&y.0, &x.0) {
+        (NaN, _) | (_, NaN) => (Float::NAN, Equal),
+        (Zero { sign }, _) => {
+            if x.is_sign_positive() {
+                (y.clone(), Equal)
+            } else {
+                round_pi_multiple(1, 0, !*sign, prec, rm)
+            }
+        }
+        (_, Zero { .. }) => round_pi_multiple(1, 1, y.is_sign_negative(), prec, rm),
+        (Infinity { sign: y_sign }, Infinity { sign: x_sign }) => {
+            round_pi_multiple(if *x_sign { 1 } else { 3 }, 2, !*y_sign, prec, rm)
+        }
+        (Infinity { sign }, _) => round_pi_multiple(1, 1, !*sign, prec, rm),
+        (_, Infinity { sign }) => {
+            if *sign {
+                (
+                    if y.is_sign_positive() {
+                        Float::ZERO
+                    } else {
+                        Float::NEGATIVE_ZERO
+                    },
+                    Equal,
+                )
+            } else {
+                round_pi_multiple(1, 0, y.is_sign_negative(), prec, rm)
+            }
+        }
+        (Finite { .. }, Finite { .. }) => atan2_finite(y, x, prec, rm),
+    }
+}
+
+impl Float {
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the specified precision and with the specified rounding mode. Both
+    /// [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// This is the angle of the point $(x,y)$, measured counterclockwise from the positive
+    /// $x$-axis, in radians; when $x>0$ it equals $\arctan(y/x)$. The [`Float`] on which the
+    /// function is called is $y$, and the argument is $x$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x$ is positive or $0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x$ is negative or $-0.0$
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y>0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y<0$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and positive
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and negative
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and positive
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and negative
+    ///
+    /// The result is in $[-\pi,\pi]$, so overflow is not possible. The result can underflow when
+    /// $y/x$ is extremely small and $x$ is positive:
+    ///
+    /// Underflow:
+    /// - If $0<f(y,x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(y,x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(y,x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(y,x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(y,x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(y,x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::atan2_round`] instead. If both of these things are true, consider using
+    /// [`Float::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.875");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751247");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round(self, x: Self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        atan2_prec_round_helper(&self, &x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.875");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751247");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_val_ref(
+        self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        atan2_prec_round_helper(&self, x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.875");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751247");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round_ref_val(
+        &self,
+        x: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        atan2_prec_round_helper(self, &x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the specified precision and with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.875");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751247");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_ref_ref(
+        &self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        atan2_prec_round_helper(self, x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the nearest value of the specified precision. Both [`Float`]s are
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec(Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec(Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec(self, x: Self, prec: u64) -> (Self, Ordering) {
+        self.atan2_prec_round(x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the nearest value of the specified precision. The first [`Float`] is
+    /// taken by value and the second by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_val_ref(self, x: &Self, prec: u64) -> (Self, Ordering) {
+        self.atan2_prec_round_val_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the nearest value of the specified precision. The first [`Float`] is
+    /// taken by reference and the second by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_val(&self, x: Self, prec: u64) -> (Self, Ordering) {
+        self.atan2_prec_round_ref_val(x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the nearest value of the specified precision. Both [`Float`]s are
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the exact result. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.85751152");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_ref(&self, x: &Self, prec: u64) -> (Self, Ordering) {
+        self.atan2_prec_round_ref_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the maximum of the precisions of the inputs and with the specified
+    /// rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan2_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584635");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round(self, x: Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the maximum of the precisions of the inputs and with the specified
+    /// rounding mode. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584635");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_val_ref(self, x: &Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_val_ref(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the maximum of the precisions of the inputs and with the specified
+    /// rounding mode. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584635");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_val(&self, x: Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_ref_val(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// rounding the result to the maximum of the precisions of the inputs and with the specified
+    /// rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584635");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.85751178635584624");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_ref(&self, x: &Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_ref_ref(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// mutating the first one ($y$) in place, and rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// value. An [`Ordering`] is returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 20, Floor), Less);
+    /// assert_eq!(y.to_string(), "0.85751152");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(
+    ///     y.atan2_prec_round_assign(Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(y.to_string(), "0.85751247");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 20, Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.85751152");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_assign(&mut self, x: Self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_val(x, prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// mutating the first one ($y$) in place, and rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(
+    ///     y.atan2_prec_round_assign_ref(&Float::from(E), 20, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(y.to_string(), "0.85751152");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(
+    ///     y.atan2_prec_round_assign_ref(&Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(y.to_string(), "0.85751247");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(
+    ///     y.atan2_prec_round_assign_ref(&Float::from(E), 20, Nearest),
+    ///     Less
+    /// );
+    /// assert_eq!(y.to_string(), "0.85751152");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_assign_ref(
+        &mut self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_ref(x, prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// mutating the first one ($y$) in place, and rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by value. An [`Ordering`]
+    /// is returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign(Float::from(E), 5), Less);
+    /// assert_eq!(y.to_string(), "0.844");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign(Float::from(E), 20), Less);
+    /// assert_eq!(y.to_string(), "0.85751152");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign(&mut self, x: Self, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign(x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// mutating the first one ($y$) in place, and rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded result is less than, equal to, or
+    /// greater than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign_ref(&Float::from(E), 5), Less);
+    /// assert_eq!(y.to_string(), "0.844");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign_ref(&Float::from(E), 20), Less);
+    /// assert_eq!(y.to_string(), "0.85751152");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign_ref(&mut self, x: &Self, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// mutating the first one ($y$) in place, and rounding the result to the maximum of the
+    /// precisions of the inputs and with the specified rounding mode. The [`Float`] on the
+    /// right-hand side is taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign(Float::from(E), Floor), Less);
+    /// assert_eq!(y.to_string(), "0.85751178635584624");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign(Float::from(E), Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.85751178635584635");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign(Float::from(E), Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.85751178635584624");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign(&mut self, x: Self, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_assign(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s,
+    /// mutating the first one ($y$) in place, and rounding the result to the maximum of the
+    /// precisions of the inputs and with the specified rounding mode. The [`Float`] on the
+    /// right-hand side is taken by reference. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case unless the result is zero or `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign_ref(&Float::from(E), Floor), Less);
+    /// assert_eq!(y.to_string(), "0.85751178635584624");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign_ref(&Float::from(E), Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.85751178635584635");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign_ref(&Float::from(E), Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.85751178635584624");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign_ref(&mut self, x: &Self, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_assign_ref(x, prec, rm)
+    }
+}
+
+impl Atan2<Self> for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s. Both
+    /// [`Float`]s are taken by value.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. If the result is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is positive or $0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is negative or $-0.0$
+    /// - $f(y,\pm0.0)=\pi/2$ if $y>0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y<0$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and positive
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and negative
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and positive
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and negative
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(PI).atan2(Float::from(E)).to_string(),
+    ///     "0.85751178635584624"
+    /// );
+    /// assert_eq!(Float::ZERO.atan2(Float::NEGATIVE_ONE).to_string(), "4.0");
+    /// ```
+    #[inline]
+    fn atan2(self, x: Self) -> Self {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round(x, prec, Nearest).0
+    }
+}
+
+impl Atan2<&Self> for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s. The
+    /// first [`Float`] is taken by value and the second by reference.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. If the result is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is positive or $0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is negative or $-0.0$
+    /// - $f(y,\pm0.0)=\pi/2$ if $y>0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y<0$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and positive
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and negative
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and positive
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and negative
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(PI).atan2(&Float::from(E)).to_string(),
+    ///     "0.85751178635584624"
+    /// );
+    /// assert_eq!(Float::ZERO.atan2(&Float::NEGATIVE_ONE).to_string(), "4.0");
+    /// ```
+    #[inline]
+    fn atan2(self, x: &Self) -> Self {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_val_ref(x, prec, Nearest).0
+    }
+}
+
+impl Atan2<Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s. The
+    /// first [`Float`] is taken by reference and the second by value.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. If the result is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is positive or $0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is negative or $-0.0$
+    /// - $f(y,\pm0.0)=\pi/2$ if $y>0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y<0$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and positive
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and negative
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and positive
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and negative
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(PI)).atan2(Float::from(E)).to_string(),
+    ///     "0.85751178635584624"
+    /// );
+    /// assert_eq!((&Float::ZERO).atan2(Float::NEGATIVE_ONE).to_string(), "4.0");
+    /// ```
+    #[inline]
+    fn atan2(self, x: Float) -> Float {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_ref_val(x, prec, Nearest).0
+    }
+}
+
+impl Atan2<&Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two [`Float`]s. Both
+    /// [`Float`]s are taken by reference.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. If the result is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is positive or $0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is negative or $-0.0$
+    /// - $f(y,\pm0.0)=\pi/2$ if $y>0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y<0$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and positive
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and negative
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and positive
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and negative
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(PI)).atan2(&Float::from(E)).to_string(),
+    ///     "0.85751178635584624"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::ZERO).atan2(&Float::NEGATIVE_ONE).to_string(),
+    ///     "4.0"
+    /// );
+    /// ```
+    #[inline]
+    fn atan2(self, x: &Float) -> Float {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_ref_ref(x, prec, Nearest).0
+    }
+}
+
+impl Atan2Assign<Self> for Float {
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the two-argument arctangent of it
+    /// and another [`Float`] $x$. The [`Float`] on the right-hand side is taken by value.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. If the result is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Atan2Assign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut y = Float::from(PI);
+    /// y.atan2_assign(Float::from(E));
+    /// assert_eq!(y.to_string(), "0.85751178635584624");
+    /// ```
+    #[inline]
+    fn atan2_assign(&mut self, x: Self) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_assign(x, prec, Nearest);
+    }
+}
+
+impl Atan2Assign<&Self> for Float {
+    /// Replaces a [`Float`] $y$ with $\operatorname{atan2}(y,x)$, the two-argument arctangent of it
+    /// and another [`Float`] $x$. The [`Float`] on the right-hand side is taken by reference.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. If the result is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Atan2Assign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut y = Float::from(PI);
+    /// y.atan2_assign(&Float::from(E));
+    /// assert_eq!(y.to_string(), "0.85751178635584624");
+    /// ```
+    #[inline]
+    fn atan2_assign(&mut self, x: &Self) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.atan2_prec_round_assign_ref(x, prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{atan2}(y,x)$, the two-argument arctangent of two primitive floats,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+/// $$
+/// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| \leq
+///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the precision of the
+///   output (typically 24 if `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is
+///   subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+/// - $f(\pm0.0,x)=\pm0.0$ if $x$ is positive or $0.0$
+/// - $f(\pm0.0,x)=\pm\pi$ if $x$ is negative or $-0.0$
+/// - $f(y,\pm0.0)=\pi/2$ if $y>0$
+/// - $f(y,\pm0.0)=-\pi/2$ if $y<0$
+/// - $f(\pm\infty,\infty)=\pm\pi/4$
+/// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+/// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+/// - $f(y,\infty)=0.0$ if $y$ is finite and positive
+/// - $f(y,\infty)=-0.0$ if $y$ is finite and negative
+/// - $f(y,-\infty)=\pi$ if $y$ is finite and positive
+/// - $f(y,-\infty)=-\pi$ if $y$ is finite and negative
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::{E, PI};
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::atan2::primitive_float_atan2;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan2(PI, E)),
+///     NiceFloat(0.8575117863558462)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan2(1.0f32, -1.0f32)),
+///     NiceFloat(2.3561945)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_atan2(-0.0f64, -1.0f64)),
+///     NiceFloat(-3.141592653589793)
+/// );
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+#[inline]
+pub fn primitive_float_atan2<T: PrimitiveFloat>(y: T, x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_float_to_float_fn(Float::atan2_prec, y, x)
+}
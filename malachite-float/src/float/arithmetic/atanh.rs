@@ -0,0 +1,1073 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::round_near_x::rational_round_near_x;
+use crate::float::arithmetic::sin_cos::{rational_exponent, round_with_bounds};
+use crate::float::arithmetic::sinh_cosh::ln_1_plus_x_bounds;
+use crate::floor_and_ceiling;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Atanh, AtanhAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, One, Zero as ZeroTrait,
+};
+use malachite_base::num::comparison::traits::OrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+// Computes atanh(x) for a nonzero rational x.
+fn atanh_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match x.cmp_abs(&Rational::ONE) {
+        Greater => return (Float::NAN, Equal),
+        Equal => {
+            return (
+                if *x > 0u32 {
+                    Float::INFINITY
+                } else {
+                    Float::NEGATIVE_INFINITY
+                },
+                Equal,
+            );
+        }
+        Less => {}
+    }
+    if *x < 0u32 {
+        let (y, o) = atanh_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact atanh");
+    let exp_x = rational_exponent(x);
+    // For 0 < x <= 1/2, x < atanh(x) < x + x ^ 3 / 2
+    if exp_x < 0
+        && let Some(result) =
+            rational_round_near_x(x, u64::exact_from(1 - (exp_x << 1)), true, prec, rm)
+    {
+        return result;
+    }
+    // atanh(x) = log(1 + d) / 2, where d = 2x / (1 - x)
+    let one_minus_x = Rational::ONE - x;
+    let d = (x << 1u32) / &one_minus_x;
+    if rational_exponent(&d) < Float::MAX_EXPONENT_I64 {
+        round_with_bounds(prec, rm, |w| {
+            let (lo, hi) = ln_1_plus_x_bounds(&d, &d, w);
+            Some((lo >> 1u32, hi >> 1u32))
+        })
+    } else {
+        // Only possible for a `Rational` extremely close to 1; then atanh(x) = (log(1 + x) -
+        // log(1 - x)) / 2
+        let one_plus_x = x + Rational::ONE;
+        round_with_bounds(prec, rm, |w| {
+            let (p_lo, p_hi) =
+                floor_and_ceiling(Float::ln_rational_prec_round_ref(&one_plus_x, w, Floor));
+            let (m_lo, m_hi) =
+                floor_and_ceiling(Float::ln_rational_prec_round_ref(&one_minus_x, w, Floor));
+            Some((
+                (Rational::exact_from(p_lo) - Rational::exact_from(m_hi)) >> 1u32,
+                (Rational::exact_from(p_hi) - Rational::exact_from(m_lo)) >> 1u32,
+            ))
+        })
+    }
+}
+
+impl Float {
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], rounding
+    /// the result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic tangent is less than, equal to, or greater than the exact inverse hyperbolic
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{atanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atanh}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{atanh}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    /// - $f(1.0,p,m)=\infty$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Since $|\operatorname{atanh} x|>|x|$ for nonzero $x$, underflow is not possible. Overflow is
+    /// not possible either, since $|x|$ is at least $2^{-p}$ away from 1 when $x$ has precision
+    /// $p$.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round`] instead. If both of these things are true, consider using
+    /// [`Float::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN` or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round(5, Floor);
+    /// assert_eq!(atanh.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round(5, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round(5, Nearest);
+    /// assert_eq!(atanh.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round(20, Floor);
+    /// assert_eq!(atanh.to_string(), "0.54930592");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round(20, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.54930687");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round(20, Nearest);
+    /// assert_eq!(atanh.to_string(), "0.54930592");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.atanh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], rounding
+    /// the result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic tangent is less than, equal to, or greater than the exact inverse hyperbolic
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).atanh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN` or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round_ref(5, Floor);
+    /// assert_eq!(atanh.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round_ref(5, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round_ref(5, Nearest);
+    /// assert_eq!(atanh.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round_ref(20, Floor);
+    /// assert_eq!(atanh.to_string(), "0.54930592");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round_ref(20, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.54930687");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_round_ref(20, Nearest);
+    /// assert_eq!(atanh.to_string(), "0.54930592");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn atanh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { .. } => (Self::NAN, Equal),
+            Zero { .. } => (self.clone(), Equal),
+            Finite { .. } => atanh_rational_helper(&Rational::exact_from(self), prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], rounding
+    /// the result to the nearest value of the specified precision. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic tangent
+    /// is less than, equal to, or greater than the exact inverse hyperbolic tangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec(5);
+    /// assert_eq!(atanh.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec(20);
+    /// assert_eq!(atanh.to_string(), "0.54930592");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atanh_prec(self, prec: u64) -> (Self, Ordering) {
+        self.atanh_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], rounding
+    /// the result to the nearest value of the specified precision. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic tangent is less than, equal to, or greater than the exact inverse hyperbolic
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).atanh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_ref(5);
+    /// assert_eq!(atanh.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.5).atanh_prec_ref(20);
+    /// assert_eq!(atanh.to_string(), "0.54930592");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.atanh_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], rounding
+    /// the result to the precision of the input and with the specified rounding mode. The [`Float`]
+    /// is taken by value. An [`Ordering`] is also returned, indicating whether the rounded inverse
+    /// hyperbolic tangent is less than, equal to, or greater than the exact inverse hyperbolic
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atanh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and inputs that produce `NaN`
+    /// or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::from(0.3).atanh_round(Floor);
+    /// assert_eq!(atanh.to_string(), "0.30951960420311170");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::from(0.3).atanh_round(Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.30951960420311175");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.3).atanh_round(Nearest);
+    /// assert_eq!(atanh.to_string(), "0.30951960420311170");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atanh_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], rounding
+    /// the result to the precision of the input and with the specified rounding mode. The [`Float`]
+    /// is taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// inverse hyperbolic tangent is less than, equal to, or greater than the exact inverse
+    /// hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atanh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).atanh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and inputs that produce `NaN`
+    /// or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::from(0.3).atanh_round_ref(Floor);
+    /// assert_eq!(atanh.to_string(), "0.30951960420311170");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::from(0.3).atanh_round_ref(Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.30951960420311175");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::from(0.3).atanh_round_ref(Nearest);
+    /// assert_eq!(atanh.to_string(), "0.30951960420311170");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atanh_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], in place,
+    /// rounding the result to the specified precision and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded inverse hyperbolic tangent is less
+    /// than, equal to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::atanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN` or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.atanh_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.54930592");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.atanh_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.54930687");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.atanh_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.54930592");
+    /// ```
+    #[inline]
+    pub fn atanh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.atanh_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], in place,
+    /// rounding the result to the nearest value of the specified precision. An [`Ordering`] is
+    /// returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal to,
+    /// or greater than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::atanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.atanh_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.562");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.atanh_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.54930592");
+    /// ```
+    #[inline]
+    pub fn atanh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atanh_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], in place,
+    /// rounding the result to the precision of the input and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded inverse hyperbolic tangent is less
+    /// than, equal to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atanh_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::atanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every input other than zero and inputs that produce `NaN`
+    /// or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.3);
+    /// assert_eq!(x.atanh_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.30951960420311170");
+    ///
+    /// let mut x = Float::from(0.3);
+    /// assert_eq!(x.atanh_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.30951960420311175");
+    ///
+    /// let mut x = Float::from(0.3);
+    /// assert_eq!(x.atanh_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.30951960420311170");
+    /// ```
+    #[inline]
+    pub fn atanh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal
+    /// to, or greater than the exact inverse hyperbolic tangent.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{atanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atanh}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{atanh}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    /// - $f(1,p,m)=\infty$
+    /// - $f(-1,p,m)=-\infty$
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN` or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(atanh.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(atanh.to_string(), "0.34657335");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.34657383");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atanh_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::atanh_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded inverse hyperbolic tangent is less than,
+    /// equal to, or greater than the exact inverse hyperbolic tangent.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{atanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atanh}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{atanh}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    /// - $f(1,p,m)=\infty$
+    /// - $f(-1,p,m)=-\infty$
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every input other than zero and inputs that
+    /// produce `NaN` or an infinity).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(atanh.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(atanh.to_string(), "0.34657335");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(atanh.to_string(), "0.34657383");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn atanh_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::ZERO, Equal);
+        }
+        atanh_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic tangent is less than, equal to, or greater
+    /// than the exact inverse hyperbolic tangent.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atanh_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(atanh.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(atanh.to_string(), "0.34657335");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atanh_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::atanh_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic tangent is less than, equal to, or greater
+    /// than the exact inverse hyperbolic tangent.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::atanh_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(atanh.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atanh, o) = Float::atanh_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(atanh.to_string(), "0.34657335");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atanh_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::atanh_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Atanh for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], taking it
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// tangent is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{atanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atanh} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    /// - $f(1.0)=\infty$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atanh_round`]. If you want both of these things, consider using
+    /// [`Float::atanh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atanh;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.atanh().is_nan());
+    /// assert_eq!(Float::ONE.atanh(), Float::INFINITY);
+    /// assert_eq!(Float::ZERO.atanh(), 0.0);
+    /// assert_eq!(Float::from(0.5).atanh().to_string(), "0.50");
+    /// assert_eq!(Float::from(0.3).atanh().to_string(), "0.30951960420311170");
+    /// ```
+    #[inline]
+    fn atanh(self) -> Self {
+        let prec = self.significant_bits();
+        self.atanh_prec_round(prec, Nearest).0
+    }
+}
+
+impl Atanh for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], taking it
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// tangent is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{atanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atanh} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $|x|>1$
+    /// - $f(1.0)=\infty$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atanh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::atanh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atanh;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).atanh().is_nan());
+    /// assert_eq!((&Float::ONE).atanh(), Float::INFINITY);
+    /// assert_eq!((&Float::ZERO).atanh(), 0.0);
+    /// assert_eq!((&Float::from(0.5)).atanh().to_string(), "0.50");
+    /// assert_eq!((&Float::from(0.3)).atanh().to_string(), "0.30951960420311170");
+    /// ```
+    #[inline]
+    fn atanh(self) -> Float {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl AtanhAssign for Float {
+    /// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// tangent is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{atanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{atanh} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{atanh} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AtanhAssign;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// x.atanh_assign();
+    /// assert_eq!(x.to_string(), "0.50");
+    ///
+    /// let mut x = Float::from(0.3);
+    /// x.atanh_assign();
+    /// assert_eq!(x.to_string(), "0.30951960420311170");
+    /// ```
+    #[inline]
+    fn atanh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{atanh} x+\varepsilon.
+/// $$
+/// - If $\operatorname{atanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{atanh} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{atanh} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+/// - $f(x)=\text{NaN}$ if $|x|>1$
+/// - $f(1.0)=\infty$
+/// - $f(-1.0)=-\infty$
+/// - $f(0.0)=0.0$
+/// - $f(-0.0)=-0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::atanh::primitive_float_atanh;
+///
+/// assert!(primitive_float_atanh(f64::NAN).is_nan());
+/// assert!(primitive_float_atanh(2.0f64).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_atanh(-1.0f64)), NiceFloat(f64::NEGATIVE_INFINITY));
+/// assert_eq!(NiceFloat(primitive_float_atanh(0.5f64)), NiceFloat(0.5493061443340549));
+/// assert_eq!(NiceFloat(primitive_float_atanh(0.3f32)), NiceFloat(0.30951962));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_atanh<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::atanh_prec, x)
+}
+
+/// Computes $\operatorname{atanh} x$, the inverse hyperbolic tangent of a [`Rational`], returning
+/// the result as a primitive float.
+///
+/// $$
+/// f(x) = \operatorname{atanh} x+\varepsilon.
+/// $$
+/// - If $\operatorname{atanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{atanh} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{atanh} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x)=\text{NaN}$ if $|x|>1$
+/// - $f(1)=\infty$
+/// - $f(-1)=-\infty$
+/// - $f(0)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::atanh::primitive_float_atanh_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_atanh_rational::<f64>(&Rational::from_unsigneds(1u8, 3))), NiceFloat(0.34657359027997264));
+/// assert_eq!(NiceFloat(primitive_float_atanh_rational::<f64>(&Rational::from(2))), NiceFloat(f64::NAN));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_atanh_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::atanh_rational_prec_ref, x)
+}
@@ -0,0 +1,1039 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::bessel_jn::jn_helper;
+use crate::float::arithmetic::gamma::Argument;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{BesselJ0, BesselJ0Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = J_0(x)+\varepsilon.
+    /// $$
+    /// - If $J_0(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_0(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_0(x)|\rfloor-p+1}$.
+    /// - If $J_0(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_0(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// Neither overflow nor underflow is possible.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j0_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_j0_round`] instead. If both of these things are true, consider using
+    /// [`Float::bessel_j0`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round(5, Floor);
+    /// assert_eq!(j0.to_string(), "-0.312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round(5, Ceiling);
+    /// assert_eq!(j0.to_string(), "-0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round(5, Nearest);
+    /// assert_eq!(j0.to_string(), "-0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round(20, Floor);
+    /// assert_eq!(j0.to_string(), "-0.30424261");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round(20, Ceiling);
+    /// assert_eq!(j0.to_string(), "-0.30424213");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round(20, Nearest);
+    /// assert_eq!(j0.to_string(), "-0.30424213");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j0_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.bessel_j0_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j0_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_j0_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).bessel_j0()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round_ref(5, Floor);
+    /// assert_eq!(j0.to_string(), "-0.312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round_ref(5, Ceiling);
+    /// assert_eq!(j0.to_string(), "-0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round_ref(5, Nearest);
+    /// assert_eq!(j0.to_string(), "-0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round_ref(20, Floor);
+    /// assert_eq!(j0.to_string(), "-0.30424261");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round_ref(20, Ceiling);
+    /// assert_eq!(j0.to_string(), "-0.30424213");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_round_ref(20, Nearest);
+    /// assert_eq!(j0.to_string(), "-0.30424213");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_j0_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { .. } => (Self::ZERO, Equal),
+            Zero { .. } => (Self::one_prec(prec), Equal),
+            Finite { .. } => jn_helper(0, &Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded Bessel function
+    /// is less than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j0_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::bessel_j0`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec(5);
+    /// assert_eq!(j0.to_string(), "-0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec(20);
+    /// assert_eq!(j0.to_string(), "-0.30424213");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j0_prec(self, prec: u64) -> (Self, Ordering) {
+        self.bessel_j0_prec_round(prec, Nearest)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded Bessel
+    /// function is less than, equal to, or greater than the exact Bessel function. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j0_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).bessel_j0()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_ref(5);
+    /// assert_eq!(j0.to_string(), "-0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(PI).bessel_j0_prec_ref(20);
+    /// assert_eq!(j0.to_string(), "-0.30424213");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j0_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.bessel_j0_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::bessel_j0_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::bessel_j0`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::from(-PI).bessel_j0_round(Floor);
+    /// assert_eq!(j0.to_string(), "-0.30424217764409400");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::from(-PI).bessel_j0_round(Ceiling);
+    /// assert_eq!(j0.to_string(), "-0.30424217764409356");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(-PI).bessel_j0_round(Nearest);
+    /// assert_eq!(j0.to_string(), "-0.30424217764409400");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_j0_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_j0_prec_round(prec, rm)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_j0_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float).bessel_j0()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::from(-PI).bessel_j0_round_ref(Floor);
+    /// assert_eq!(j0.to_string(), "-0.30424217764409400");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::from(-PI).bessel_j0_round_ref(Ceiling);
+    /// assert_eq!(j0.to_string(), "-0.30424217764409356");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::from(-PI).bessel_j0_round_ref(Nearest);
+    /// assert_eq!(j0.to_string(), "-0.30424217764409400");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_j0_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_j0_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`], in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded Bessel function is less than,
+    /// equal to, or greater than the exact Bessel function. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j0_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::bessel_j0_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::bessel_j0_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j0_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "-0.30424261");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j0_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.30424213");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j0_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-0.30424213");
+    /// ```
+    #[inline]
+    pub fn bessel_j0_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.bessel_j0_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`], in
+    /// place, rounding the result to the nearest value of the specified precision. An [`Ordering`]
+    /// is returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j0_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the input, consider using [`Float::bessel_j0_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j0_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "-0.297");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j0_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "-0.30424213");
+    /// ```
+    #[inline]
+    pub fn bessel_j0_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.bessel_j0_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`], in
+    /// place, rounding the result to the precision of the input and with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded Bessel function is less
+    /// than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_j0_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::bessel_j0_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_j0_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-0.30424217764409400");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_j0_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.30424217764409356");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_j0_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-0.30424217764409400");
+    /// ```
+    #[inline]
+    pub fn bessel_j0_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.bessel_j0_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = J_0(x)+\varepsilon.
+    /// $$
+    /// - If $J_0(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_0(x)|\rfloor-p+1}$.
+    /// - If $J_0(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_0(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j0_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) =
+    ///     Float::bessel_j0_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(j0.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) =
+    ///     Float::bessel_j0_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(j0.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) =
+    ///     Float::bessel_j0_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(j0.to_string(), "0.97241402");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) =
+    ///     Float::bessel_j0_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(j0.to_string(), "0.97241497");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_j0_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::bessel_j0_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = J_0(x)+\varepsilon.
+    /// $$
+    /// - If $J_0(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_0(x)|\rfloor-p+1}$.
+    /// - If $J_0(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_0(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j0_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(j0.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(j0.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(j0.to_string(), "0.97241402");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(j0.to_string(), "0.97241497");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_j0_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::one_prec(prec), Equal);
+        }
+        jn_helper(0, &Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j0_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(j0.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(j0.to_string(), "0.97241497");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_j0_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::bessel_j0_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j0_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(j0.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j0, o) = Float::bessel_j0_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(j0.to_string(), "0.97241497");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j0_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::bessel_j0_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl BesselJ0 for Float {
+    type Output = Self;
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`], taking
+    /// it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = J_0(x)+\varepsilon.
+    /// $$
+    /// - If $J_0(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_0(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_0(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j0_prec`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_j0_round`]. If you want both of these things, consider using
+    /// [`Float::bessel_j0_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJ0;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.bessel_j0().is_nan());
+    /// assert_eq!(Float::INFINITY.bessel_j0(), Float::ZERO);
+    /// assert_eq!(Float::ZERO.bessel_j0(), Float::ONE);
+    /// assert_eq!(
+    ///     Float::from(PI).bessel_j0().to_string(),
+    ///     "-0.30424217764409400"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-PI).bessel_j0().to_string(),
+    ///     "-0.30424217764409400"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_j0(self) -> Self {
+        let prec = self.significant_bits();
+        self.bessel_j0_prec_round(prec, Nearest).0
+    }
+}
+
+impl BesselJ0 for &Float {
+    type Output = Float;
+
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`], taking
+    /// it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = J_0(x)+\varepsilon.
+    /// $$
+    /// - If $J_0(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_0(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_0(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j0_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_j0_round_ref`]. If you want both of these things, consider using
+    /// [`Float::bessel_j0_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJ0;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).bessel_j0().is_nan());
+    /// assert_eq!((&Float::INFINITY).bessel_j0(), Float::ZERO);
+    /// assert_eq!((&Float::ZERO).bessel_j0(), Float::ONE);
+    /// assert_eq!(
+    ///     (&Float::from(PI)).bessel_j0().to_string(),
+    ///     "-0.30424217764409400"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-PI)).bessel_j0().to_string(),
+    ///     "-0.30424217764409400"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_j0(self) -> Float {
+        let prec = self.significant_bits();
+        self.bessel_j0_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl BesselJ0Assign for Float {
+    /// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Float`], in
+    /// place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = J_0(x)+\varepsilon.
+    /// $$
+    /// - If $J_0(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_0(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_0(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::bessel_j0_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJ0Assign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.bessel_j0_assign();
+    /// assert_eq!(x.to_string(), "-0.30424217764409400");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// x.bessel_j0_assign();
+    /// assert_eq!(x.to_string(), "-0.30424217764409400");
+    /// ```
+    #[inline]
+    fn bessel_j0_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.bessel_j0_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = J_0(x)+\varepsilon.
+/// $$
+/// - If $J_0(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $J_0(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |J_0(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\pm\infty)=0.0$
+/// - $f(\pm0.0)=1.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_j0::primitive_float_bessel_j0;
+///
+/// assert!(primitive_float_bessel_j0(f64::NAN).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j0(f64::INFINITY)),
+///     NiceFloat(0.0)
+/// );
+/// assert_eq!(NiceFloat(primitive_float_bessel_j0(0.0f64)), NiceFloat(1.0));
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j0(PI)),
+///     NiceFloat(-0.30424217764409384)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j0(-1.5f32)),
+///     NiceFloat(0.51182765)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_j0<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::bessel_j0_prec, x)
+}
+
+/// Computes $J_0(x)$, the Bessel function of the first kind of order 0, of a [`Rational`],
+/// returning the result as a primitive float.
+///
+/// $$
+/// f(x) = J_0(x)+\varepsilon.
+/// $$
+/// - If $J_0(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $J_0(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |J_0(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=1.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_j0::primitive_float_bessel_j0_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j0_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.9724145291150975)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j0_rational::<f64>(&Rational::from(
+///         10
+///     ))),
+///     NiceFloat(-0.24593576445134835)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_j0_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::bessel_j0_rational_prec_ref, x)
+}
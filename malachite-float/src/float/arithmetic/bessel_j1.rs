@@ -0,0 +1,1060 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::bessel_jn::jn_helper;
+use crate::float::arithmetic::gamma::Argument;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{BesselJ1, BesselJ1Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = J_1(x)+\varepsilon.
+    /// $$
+    /// - If $J_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_1(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_1(x)|\rfloor-p+1}$.
+    /// - If $J_1(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_1(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Overflow is not possible. Since $|J_1(x)|<|x|/2$, the result can underflow, but only if
+    /// $|x|<2^{-2^{30}+1}$, which is only a few times the smallest positive [`Float`]:
+    ///
+    /// Underflow:
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j1_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_j1_round`] instead. If both of these things are true, consider using
+    /// [`Float::bessel_j1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round(5, Floor);
+    /// assert_eq!(j1.to_string(), "0.281");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round(5, Ceiling);
+    /// assert_eq!(j1.to_string(), "0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round(5, Nearest);
+    /// assert_eq!(j1.to_string(), "0.281");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round(20, Floor);
+    /// assert_eq!(j1.to_string(), "0.28461504");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round(20, Ceiling);
+    /// assert_eq!(j1.to_string(), "0.28461552");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round(20, Nearest);
+    /// assert_eq!(j1.to_string(), "0.28461552");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j1_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.bessel_j1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j1_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_j1_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).bessel_j1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round_ref(5, Floor);
+    /// assert_eq!(j1.to_string(), "0.281");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round_ref(5, Ceiling);
+    /// assert_eq!(j1.to_string(), "0.297");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round_ref(5, Nearest);
+    /// assert_eq!(j1.to_string(), "0.281");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round_ref(20, Floor);
+    /// assert_eq!(j1.to_string(), "0.28461504");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round_ref(20, Ceiling);
+    /// assert_eq!(j1.to_string(), "0.28461552");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_round_ref(20, Nearest);
+    /// assert_eq!(j1.to_string(), "0.28461552");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_j1_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { .. } => (Self::ZERO, Equal),
+            Zero { .. } => (self.clone(), Equal),
+            Finite { .. } => jn_helper(1, &Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded Bessel function
+    /// is less than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j1_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::bessel_j1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec(5);
+    /// assert_eq!(j1.to_string(), "0.281");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec(20);
+    /// assert_eq!(j1.to_string(), "0.28461552");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j1_prec(self, prec: u64) -> (Self, Ordering) {
+        self.bessel_j1_prec_round(prec, Nearest)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded Bessel
+    /// function is less than, equal to, or greater than the exact Bessel function. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j1_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).bessel_j1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_ref(5);
+    /// assert_eq!(j1.to_string(), "0.281");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(PI).bessel_j1_prec_ref(20);
+    /// assert_eq!(j1.to_string(), "0.28461552");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j1_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.bessel_j1_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::bessel_j1_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::bessel_j1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::from(-PI).bessel_j1_round(Floor);
+    /// assert_eq!(j1.to_string(), "-0.28461534317975312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(-PI).bessel_j1_round(Ceiling);
+    /// assert_eq!(j1.to_string(), "-0.28461534317975268");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) = Float::from(-PI).bessel_j1_round(Nearest);
+    /// assert_eq!(j1.to_string(), "-0.28461534317975268");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j1_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_j1_prec_round(prec, rm)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_j1_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float).bessel_j1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::from(-PI).bessel_j1_round_ref(Floor);
+    /// assert_eq!(j1.to_string(), "-0.28461534317975312");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::from(-PI).bessel_j1_round_ref(Ceiling);
+    /// assert_eq!(j1.to_string(), "-0.28461534317975268");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) = Float::from(-PI).bessel_j1_round_ref(Nearest);
+    /// assert_eq!(j1.to_string(), "-0.28461534317975268");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_j1_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_j1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`], in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded Bessel function is less than,
+    /// equal to, or greater than the exact Bessel function. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j1_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::bessel_j1_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::bessel_j1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j1_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.28461504");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j1_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.28461552");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j1_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.28461552");
+    /// ```
+    #[inline]
+    pub fn bessel_j1_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.bessel_j1_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`], in
+    /// place, rounding the result to the nearest value of the specified precision. An [`Ordering`]
+    /// is returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j1_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the input, consider using [`Float::bessel_j1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j1_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "0.281");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_j1_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "0.28461552");
+    /// ```
+    #[inline]
+    pub fn bessel_j1_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.bessel_j1_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`], in
+    /// place, rounding the result to the precision of the input and with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded Bessel function is less
+    /// than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_j1_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::bessel_j1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_j1_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-0.28461534317975312");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_j1_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.28461534317975268");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_j1_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-0.28461534317975268");
+    /// ```
+    #[inline]
+    pub fn bessel_j1_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.bessel_j1_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = J_1(x)+\varepsilon.
+    /// $$
+    /// - If $J_1(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_1(x)|\rfloor-p+1}$.
+    /// - If $J_1(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_1(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j1_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) =
+    ///     Float::bessel_j1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(j1.to_string(), "0.164");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) =
+    ///     Float::bessel_j1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(j1.to_string(), "0.172");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) =
+    ///     Float::bessel_j1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(j1.to_string(), "0.16436243");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) =
+    ///     Float::bessel_j1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(j1.to_string(), "0.16436267");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_j1_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::bessel_j1_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = J_1(x)+\varepsilon.
+    /// $$
+    /// - If $J_1(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_1(x)|\rfloor-p+1}$.
+    /// - If $J_1(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_1(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_j1_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(j1.to_string(), "0.164");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(j1.to_string(), "0.172");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(j1.to_string(), "0.16436243");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(j1.to_string(), "0.16436267");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_j1_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::ZERO, Equal);
+        }
+        jn_helper(1, &Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j1_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(j1.to_string(), "0.164");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(j1.to_string(), "0.16436243");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_j1_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::bessel_j1_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_j1_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(j1.to_string(), "0.164");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (j1, o) = Float::bessel_j1_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(j1.to_string(), "0.16436243");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_j1_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::bessel_j1_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl BesselJ1 for Float {
+    type Output = Self;
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`], taking
+    /// it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = J_1(x)+\varepsilon.
+    /// $$
+    /// - If $J_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_1(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j1_prec`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_j1_round`]. If you want both of these things, consider using
+    /// [`Float::bessel_j1_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJ1;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.bessel_j1().is_nan());
+    /// assert_eq!(Float::INFINITY.bessel_j1(), Float::ZERO);
+    /// assert_eq!(Float::NEGATIVE_ZERO.bessel_j1(), Float::NEGATIVE_ZERO);
+    /// assert_eq!(
+    ///     Float::from(PI).bessel_j1().to_string(),
+    ///     "0.28461534317975268"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-PI).bessel_j1().to_string(),
+    ///     "-0.28461534317975268"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_j1(self) -> Self {
+        let prec = self.significant_bits();
+        self.bessel_j1_prec_round(prec, Nearest).0
+    }
+}
+
+impl BesselJ1 for &Float {
+    type Output = Float;
+
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`], taking
+    /// it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = J_1(x)+\varepsilon.
+    /// $$
+    /// - If $J_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_1(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_j1_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_j1_round_ref`]. If you want both of these things, consider using
+    /// [`Float::bessel_j1_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJ1;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).bessel_j1().is_nan());
+    /// assert_eq!((&Float::INFINITY).bessel_j1(), Float::ZERO);
+    /// assert_eq!((&Float::NEGATIVE_ZERO).bessel_j1(), Float::NEGATIVE_ZERO);
+    /// assert_eq!(
+    ///     (&Float::from(PI)).bessel_j1().to_string(),
+    ///     "0.28461534317975268"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-PI)).bessel_j1().to_string(),
+    ///     "-0.28461534317975268"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_j1(self) -> Float {
+        let prec = self.significant_bits();
+        self.bessel_j1_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl BesselJ1Assign for Float {
+    /// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Float`], in
+    /// place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = J_1(x)+\varepsilon.
+    /// $$
+    /// - If $J_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_1(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::bessel_j1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJ1Assign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.bessel_j1_assign();
+    /// assert_eq!(x.to_string(), "0.28461534317975268");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// x.bessel_j1_assign();
+    /// assert_eq!(x.to_string(), "-0.28461534317975268");
+    /// ```
+    #[inline]
+    fn bessel_j1_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.bessel_j1_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = J_1(x)+\varepsilon.
+/// $$
+/// - If $J_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $J_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |J_1(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\pm\infty)=0.0$
+/// - $f(0.0)=0.0$
+/// - $f(-0.0)=-0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_j1::primitive_float_bessel_j1;
+///
+/// assert!(primitive_float_bessel_j1(f64::NAN).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j1(f64::INFINITY)),
+///     NiceFloat(0.0)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j1(-0.0f64)),
+///     NiceFloat(-0.0)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j1(PI)),
+///     NiceFloat(0.2846153431797528)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j1(-1.5f32)),
+///     NiceFloat(-0.5579365)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_j1<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::bessel_j1_prec, x)
+}
+
+/// Computes $J_1(x)$, the Bessel function of the first kind of order 1, of a [`Rational`],
+/// returning the result as a primitive float.
+///
+/// $$
+/// f(x) = J_1(x)+\varepsilon.
+/// $$
+/// - If $J_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $J_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |J_1(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_j1::primitive_float_bessel_j1_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j1_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.16436254381427073)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_j1_rational::<f64>(&Rational::from(
+///         10
+///     ))),
+///     NiceFloat(0.04347274616886144)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_j1_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::bessel_j1_rational_prec_ref, x)
+}
@@ -0,0 +1,1429 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The Bessel functions of the first kind are computed from an exact rational argument x > 0 (J_n is
+// even or odd, like x ^ n, and J_(-n) = (-1) ^ n J_n). For small and moderate x, the power series
+//
+// J_n(x) = (x / 2) ^ n / n! * Σ (-x ^ 2 / 4) ^ k n! / (k!(n + k)!)
+//
+// is used. The sum S is at most 1 in absolute value, and its terms are at most about e ^ x times
+// |S|, so about 1.45x extra bits are needed to make up for cancellation. For large x, Hankel's
+// expansion
+//
+// J_n(x) = √(2 / (πx)) (P(n, x) cos(ω) - Q(n, x) sin(ω)), ω = x - (2n + 1)π / 4,
+//
+// is used instead, where P(n, x) = Σ (-1) ^ k a_(2k)(n) / x ^ (2k), Q(n, x) = Σ (-1) ^ k a_(2k +
+// 1)(n) / x ^ (2k + 1), and a_k(n) = (4n ^ 2 - 1)(4n ^ 2 - 9)...(4n ^ 2 - (2k - 1) ^ 2) / (k! 8 ^
+// k). For real x > 0, once at least n / 2 + 1 terms of either sum have been taken, the remainder is
+// smaller than the first omitted term. The expansion gives up about 2.9x bits of accuracy, so it is
+// tried only when x is large compared to the working precision; if it fails, the power series is
+// used. Since √2 cos(ω) and √2 sin(ω) are ±cos(x) ± sin(x), no multiple of π needs to be
+// subtracted from x.
+//
+// The factor (x / 2) ^ n / n! is computed as e raised to the power of its logarithm, with a power
+// of 2 split off, so that J_n(x) can be computed even when it is far below the smallest positive
+// `Float`. Every step produces rational bounds, and the result is rounded using Ziv's strategy.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::{mul_bounds, sqrt_bounds};
+use crate::float::arithmetic::exp::exp_underflow;
+use crate::float::arithmetic::gamma::{
+    Argument, constant_bounds, exp_scaled_bounds, exp_shift, ln_abs_gamma_bounds,
+    ln_bounds_absolute,
+};
+use crate::float::arithmetic::round_near_x::rational_round_near_x;
+use crate::float::arithmetic::sin_cos::sin_cos_bounds;
+use crate::float::arithmetic::sinh_cosh::round_with_scaled_bounds;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    BesselJn, BesselJnAssign, DivRound, Factorial, Parity, Pow, PowerOf2, Reciprocal, Square,
+    UnsignedAbs,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, NegativeZero, One, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Returns an upper bound on 1.45x, which is greater than log_2(e ^ x), for a positive rational `x`.
+pub(crate) fn cancellation_bits(x: &Rational) -> u64 {
+    u64::exact_from(&Natural::rounding_from(x * Rational::from_unsigneds(29u8, 20), Ceiling).0)
+}
+
+// Returns bounds on log(x / 2), for a positive `Argument` x, with an absolute width of about
+// `2 ^ (-w)`.
+pub(crate) fn ln_half_bounds(x: &Argument, w: u64) -> (Rational, Rational) {
+    let (l_lo, l_hi) = x.ln_abs_bounds(w + 1);
+    let (ln_2_lo, ln_2_hi) = constant_bounds(Float::ln_2_prec_round(w + 1, Floor));
+    (l_lo - ln_2_hi, l_hi - ln_2_lo)
+}
+
+// For n up to this, log(n!) is computed from n! itself; for larger n, from Stirling's series.
+const FACTORIAL_LIMIT: u64 = 1 << 10;
+
+// Returns bounds on log(n!), with an absolute width of about `2 ^ (-w)`.
+pub(crate) fn ln_factorial_bounds(n: u64, w: u64) -> (Rational, Rational) {
+    if n <= 1 {
+        (Rational::ZERO, Rational::ZERO)
+    } else if n <= FACTORIAL_LIMIT {
+        ln_bounds_absolute(&Rational::from(Natural::factorial(n)), w)
+    } else {
+        ln_abs_gamma_bounds(&Rational::from(n + 1), w)
+    }
+}
+
+// Returns bounds on log((x / 2) ^ n / n!), for a positive `Argument` x, with an absolute width of
+// about `2 ^ (-w)`.
+pub(crate) fn ln_j_factor_bounds(n: u64, x: &Argument, w: u64) -> (Rational, Rational) {
+    if n == 0 {
+        return (Rational::ZERO, Rational::ZERO);
+    }
+    let (l_lo, l_hi) = ln_half_bounds(x, w + 2 + n.significant_bits());
+    let (g_lo, g_hi) = ln_factorial_bounds(n, w + 2);
+    let n = Rational::from(n);
+    (&n * l_lo - g_hi, n * l_hi - g_lo)
+}
+
+// Returns bounds on S = Σ (-x ^ 2 / 4) ^ k n! / (k!(n + k)!), for a positive rational `x`, with an
+// absolute width of about `2 ^ (-w - 1.45x)`, which is a relative width of about `2 ^ (-w)` unless
+// x is near a zero of J_n.
+//
+// The terms are computed in fixed point, each both rounded down and rounded up from the previous
+// term's bounds. Once the ratio of consecutive terms, x ^ 2 / (4(k + 1)(n + k + 1)), drops to 1/2,
+// the tail is smaller than the last term.
+pub(crate) fn j_series_bounds(x: &Rational, n: u64, w: u64) -> (Rational, Rational) {
+    let (p, q) = (x.square() >> 2u32).into_numerator_and_denominator();
+    let extra = cancellation_bits(x);
+    let scale = w + 32 + extra;
+    let one = Natural::power_of_2(scale);
+    let mut t_lo = one.clone();
+    let mut t_hi = one.clone();
+    let mut s_lo = Integer::from(&one);
+    let mut s_hi = s_lo.clone();
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        let d = &q * Natural::from(k) * Natural::from(n + k);
+        t_lo = &t_lo * &p / &d;
+        t_hi = (&t_hi * &p).div_round(&d, Ceiling).0;
+        if k.odd() {
+            s_lo -= Integer::from(&t_hi);
+            s_hi -= Integer::from(&t_lo);
+        } else {
+            s_lo += Integer::from(&t_lo);
+            s_hi += Integer::from(&t_hi);
+        }
+        if &p << 1u32 <= &q * Natural::from(k + 1) * Natural::from(n + k + 1)
+            && &t_hi << (w + 8 + extra) <= one
+        {
+            let t_hi = Integer::from(t_hi);
+            s_lo -= &t_hi;
+            s_hi += t_hi;
+            break;
+        }
+    }
+    (Rational::from(s_lo) >> scale, Rational::from(s_hi) >> scale)
+}
+
+// Returns bounds on P(n, x) and Q(n, x) from Hankel's expansion, for a positive rational `x`, each
+// with an absolute width of about `2 ^ (-w)`, or `None` if the terms start growing before they get
+// that small.
+//
+// The terms b_k = a_k(n) / x ^ k are computed in fixed point, like those of `j_series_bounds`. They
+// are taken in pairs (b_(2i), b_(2i + 1)), the first of which contributes to P and the second to Q.
+fn hankel_bounds(
+    x: &Rational,
+    n: u64,
+    w: u64,
+) -> Option<((Rational, Rational), (Rational, Rational))> {
+    let (p, q) = x.numerator_and_denominator_ref();
+    let four_n_squared = Integer::from(n).square() << 2u32;
+    let scale = w + 32;
+    let one = Natural::power_of_2(scale);
+    let mut t_lo = one.clone();
+    let mut t_hi = one.clone();
+    let mut b_negative = false;
+    let mut p_lo = Integer::ZERO;
+    let mut p_hi = Integer::ZERO;
+    let mut q_lo = Integer::ZERO;
+    let mut q_hi = Integer::ZERO;
+    // The bounds on the magnitudes of b_(2i) and b_(2i + 1), and the sign of b_(2i)
+    let mut even = (one.clone(), one.clone(), false);
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        let f = &four_n_squared - Integer::from((k << 1) - 1).square();
+        if f < 0u32 {
+            b_negative = !b_negative;
+        }
+        let f = f.unsigned_abs() * q;
+        let d = p * Natural::from(k << 3);
+        let previous_hi = t_hi.clone();
+        t_lo = &t_lo * &f / &d;
+        t_hi = (&t_hi * &f).div_round(&d, Ceiling).0;
+        if k > n && t_lo > previous_hi && &previous_hi << (w + 8) > one {
+            return None;
+        }
+        if k.even() {
+            even = (t_lo.clone(), t_hi.clone(), b_negative);
+            continue;
+        }
+        // b_(k - 1) and b_k contribute to P and Q with the sign (-1) ^ ((k - 1) / 2)
+        let alternate = (k >> 1).odd();
+        let (e_lo, e_hi, e_negative) = &even;
+        if k >= n + 3 && e_hi << (w + 8) <= one && &t_hi << (w + 8) <= one {
+            // the remainders are smaller than the first omitted terms
+            let e_hi = Integer::from(e_hi);
+            p_lo -= &e_hi;
+            p_hi += e_hi;
+            let t_hi = Integer::from(&t_hi);
+            q_lo -= &t_hi;
+            q_hi += t_hi;
+            break;
+        }
+        if *e_negative == alternate {
+            p_lo += Integer::from(e_lo);
+            p_hi += Integer::from(e_hi);
+        } else {
+            p_lo -= Integer::from(e_hi);
+            p_hi -= Integer::from(e_lo);
+        }
+        if b_negative == alternate {
+            q_lo += Integer::from(&t_lo);
+            q_hi += Integer::from(&t_hi);
+        } else {
+            q_lo -= Integer::from(&t_hi);
+            q_hi -= Integer::from(&t_lo);
+        }
+    }
+    Some((
+        (Rational::from(p_lo) >> scale, Rational::from(p_hi) >> scale),
+        (Rational::from(q_lo) >> scale, Rational::from(q_hi) >> scale),
+    ))
+}
+
+fn add_bounds(
+    (a_lo, a_hi): (Rational, Rational),
+    (b_lo, b_hi): (Rational, Rational),
+) -> (Rational, Rational) {
+    (a_lo + b_lo, a_hi + b_hi)
+}
+
+fn negate_bounds((lo, hi): (Rational, Rational)) -> (Rational, Rational) {
+    (-hi, -lo)
+}
+
+// Returns bounds on J_n(x) (if `second_kind` is false) or Y_n(x) (if it is true), for a positive
+// rational `x`, using Hankel's expansion, or `None` if the expansion is not accurate enough. The
+// bounds have an absolute width of about `2 ^ (-w)`, which, unless x is near a zero of the
+// function, is a relative width of about `2 ^ (-w) √x`.
+pub(crate) fn hankel_j_y_bounds(
+    x: &Rational,
+    n: u64,
+    second_kind: bool,
+    w: u64,
+) -> Option<(Rational, Rational)> {
+    // Don't bother unless the expansion can plausibly reach the working precision: its terms start
+    // growing after about 2x of them, by which point they are around e ^ (-2x), and they don't
+    // start out shrinking unless 2x > n ^ 2.
+    if x * Rational::from(3u32) < w || x << 1u32 <= Rational::from(n).square() {
+        return None;
+    }
+    let (ps, qs) = hankel_bounds(x, n, w + 8)?;
+    let ((s_lo, s_hi), (c_lo, c_hi)) = sin_cos_bounds(x, w + 8);
+    let sin = (s_lo, s_hi);
+    let cos = (c_lo, c_hi);
+    // √2 cos(ω) = c cos(x) + s sin(x) and √2 sin(ω) = c sin(x) - s cos(x), where c = √2 cos((2n +
+    // 1)π / 4) and s = √2 sin((2n + 1)π / 4) are ±1.
+    let (c_positive, s_positive) = match n % 4 {
+        0 => (true, true),
+        1 => (false, true),
+        2 => (false, false),
+        _ => (true, false),
+    };
+    let signed = |positive: bool, b: &(Rational, Rational)| {
+        if positive {
+            b.clone()
+        } else {
+            negate_bounds(b.clone())
+        }
+    };
+    let cos_omega = add_bounds(signed(c_positive, &cos), signed(s_positive, &sin));
+    let sin_omega = add_bounds(signed(c_positive, &sin), signed(!s_positive, &cos));
+    let sum = if second_kind {
+        add_bounds(mul_bounds(&ps, &sin_omega), mul_bounds(&qs, &cos_omega))
+    } else {
+        add_bounds(
+            mul_bounds(&ps, &cos_omega),
+            negate_bounds(mul_bounds(&qs, &sin_omega)),
+        )
+    };
+    // 1 / √(πx)
+    let (pi_lo, pi_hi) = constant_bounds(Float::pi_prec_round(w + 8, Floor));
+    let r_lo = sqrt_bounds(&(pi_hi * x), w + 8).1.reciprocal();
+    let r_hi = sqrt_bounds(&(pi_lo * x), w + 8).0.reciprocal();
+    Some(mul_bounds(&(r_lo, r_hi), &sum))
+}
+
+// Returns J_n(x) rounded, for a positive x.
+fn jn_positive(n: u64, x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exp_x = x.exponent();
+    // |J_n(x)| <= (x / 2) ^ n / n!, so J_n(x) underflows if the logarithm of this is small enough
+    let (l_lo, l_hi) = ln_j_factor_bounds(n, x, 16);
+    let shift = if l_lo < 0u32 { exp_shift(&l_lo) } else { 0 };
+    // ln(2) < 7/10
+    if l_hi < Rational::from(Float::MIN_EXPONENT_I64 - 2) * Rational::from_unsigneds(7u8, 10) {
+        return exp_underflow(prec, rm);
+    }
+    let tiny = |w: u64| exp_x < 0 && -(exp_x << 1) > i64::exact_from(w + 8);
+    if n <= 2 && tiny(prec) {
+        // J_n(x) = (x / 2) ^ n / n! (1 - δ), with 0 < δ < x ^ 2 / 4 < 2 ^ (2e - 2), where e is the
+        // exponent of x. Write x = m * 2 ^ e and round (m / 2) ^ n / n! instead, then shift the
+        // result; this is exact unless it underflows.
+        let v = (x.mantissa() >> 1u32).pow(n) / Rational::from(if n == 2 { 2u32 } else { 1 });
+        if let Some((mut y, o)) =
+            rational_round_near_x(&v, u64::exact_from(2 - (exp_x << 1)), false, prec, rm)
+        {
+            let o = y.shl_prec_round_assign_helper(i64::exact_from(n) * exp_x, prec, rm, o);
+            return (y, o);
+        }
+    }
+    let mut x_rational = None;
+    round_with_scaled_bounds(shift, prec, rm, |w| {
+        let (f_lo, f_hi) = exp_scaled_bounds(ln_j_factor_bounds(n, x, w + 4), shift, true, w + 4);
+        if tiny(w) {
+            // 1 - 2 ^ (2e) < S <= 1
+            let s_lo = Rational::ONE - Rational::power_of_2(exp_x << 1);
+            return (f_lo * s_lo, f_hi);
+        }
+        let x = x_rational.get_or_insert_with(|| x.to_rational());
+        if let Some((lo, hi)) = hankel_j_y_bounds(x, n, false, w) {
+            return (lo >> shift, hi >> shift);
+        }
+        mul_bounds(&(f_lo, f_hi), &j_series_bounds(x, n, w))
+    })
+}
+
+// Computes J_n(x), for x != 0.
+pub(crate) fn jn_helper(n: i64, x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact Bessel function");
+    // J_n(-x) = J_(-n)(x) = (-1) ^ n J_n(x)
+    let negative = n.odd() && (x.is_positive() == (n < 0));
+    if negative {
+        let (y, o) = jn_positive(n.unsigned_abs(), &x.abs(), prec, -rm);
+        (-y, o.reverse())
+    } else {
+        jn_positive(n.unsigned_abs(), &x.abs(), prec, rm)
+    }
+}
+
+impl Float {
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = J_n(x)+\varepsilon.
+    /// $$
+    /// - If $J_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_n(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_n(x)|\rfloor-p+1}$.
+    /// - If $J_n(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_n(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$
+    /// - $f(\pm\infty,n,p,m)=0.0$
+    /// - $f(\pm0.0,0,p,m)=1.0$
+    /// - $f(\pm0.0,n,p,m)=0.0$ if $n\neq 0$ is even
+    /// - $f(0.0,n,p,m)=0.0$ and $f(-0.0,n,p,m)=-0.0$ if $n$ is odd and positive
+    /// - $f(0.0,n,p,m)=-0.0$ and $f(-0.0,n,p,m)=0.0$ if $n$ is odd and negative
+    ///
+    /// Overflow is not possible. The result underflows when $|x|$ is small compared to $|n|$, since
+    /// $|J_n(x)|$ is then close to $|x/2|^{|n|}/|n|!$:
+    ///
+    /// Underflow:
+    /// - If $0<f(x,n,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,n,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,n,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,n,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,n,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,n,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_jn_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_jn_round`] instead. If both of these things are true, consider using
+    /// [`Float::bessel_jn`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round(3, 5, Floor);
+    /// assert_eq!(jn.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round(3, 5, Ceiling);
+    /// assert_eq!(jn.to_string(), "0.344");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round(3, 5, Nearest);
+    /// assert_eq!(jn.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round(3, 20, Floor);
+    /// assert_eq!(jn.to_string(), "0.33345795");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round(3, 20, Ceiling);
+    /// assert_eq!(jn.to_string(), "0.33345842");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round(3, 20, Nearest);
+    /// assert_eq!(jn.to_string(), "0.33345842");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_jn_prec_round(self, n: i64, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.bessel_jn_prec_round_ref(n, prec, rm)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_jn_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_jn_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).bessel_jn(n)` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round_ref(3, 5, Floor);
+    /// assert_eq!(jn.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round_ref(3, 5, Ceiling);
+    /// assert_eq!(jn.to_string(), "0.344");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round_ref(3, 5, Nearest);
+    /// assert_eq!(jn.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round_ref(3, 20, Floor);
+    /// assert_eq!(jn.to_string(), "0.33345795");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round_ref(3, 20, Ceiling);
+    /// assert_eq!(jn.to_string(), "0.33345842");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_round_ref(3, 20, Nearest);
+    /// assert_eq!(jn.to_string(), "0.33345842");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_jn_prec_round_ref(
+        &self,
+        n: i64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { .. } => (Self::ZERO, Equal),
+            Zero { sign } => {
+                if n == 0 {
+                    (Self::one_prec(prec), Equal)
+                } else if n.even() || (*sign != (n < 0)) {
+                    (Self::ZERO, Equal)
+                } else {
+                    (Self::NEGATIVE_ZERO, Equal)
+                }
+            }
+            Finite { .. } => jn_helper(n, &Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded Bessel function
+    /// is less than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_jn_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::bessel_jn`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec(3, 5);
+    /// assert_eq!(jn.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec(3, 20);
+    /// assert_eq!(jn.to_string(), "0.33345842");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_jn_prec(self, n: i64, prec: u64) -> (Self, Ordering) {
+        self.bessel_jn_prec_round(n, prec, Nearest)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded Bessel
+    /// function is less than, equal to, or greater than the exact Bessel function. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_jn_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).bessel_jn(n)` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_ref(3, 5);
+    /// assert_eq!(jn.to_string(), "0.328");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(PI).bessel_jn_prec_ref(3, 20);
+    /// assert_eq!(jn.to_string(), "0.33345842");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_jn_prec_ref(&self, n: i64, prec: u64) -> (Self, Ordering) {
+        self.bessel_jn_prec_round_ref(n, prec, Nearest)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::bessel_jn_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::bessel_jn`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::from(-PI).bessel_jn_round(3, Floor);
+    /// assert_eq!(jn.to_string(), "-0.33345833620298970");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(-PI).bessel_jn_round(3, Ceiling);
+    /// assert_eq!(jn.to_string(), "-0.33345833620298926");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::from(-PI).bessel_jn_round(3, Nearest);
+    /// assert_eq!(jn.to_string(), "-0.33345833620298970");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_jn_round(self, n: i64, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_jn_prec_round(n, prec, rm)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_jn_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float).bessel_jn(n)` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::from(-PI).bessel_jn_round_ref(3, Floor);
+    /// assert_eq!(jn.to_string(), "-0.33345833620298970");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::from(-PI).bessel_jn_round_ref(3, Ceiling);
+    /// assert_eq!(jn.to_string(), "-0.33345833620298926");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::from(-PI).bessel_jn_round_ref(3, Nearest);
+    /// assert_eq!(jn.to_string(), "-0.33345833620298970");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_jn_round_ref(&self, n: i64, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_jn_prec_round_ref(n, prec, rm)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`], in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded Bessel function is less than,
+    /// equal to, or greater than the exact Bessel function. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_jn_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::bessel_jn_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::bessel_jn_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_jn_prec_round_assign(3, 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.33345795");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_jn_prec_round_assign(3, 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.33345842");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_jn_prec_round_assign(3, 20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.33345842");
+    /// ```
+    #[inline]
+    pub fn bessel_jn_prec_round_assign(&mut self, n: i64, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.bessel_jn_prec_round(n, prec, rm);
+        o
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`], in
+    /// place, rounding the result to the nearest value of the specified precision. An [`Ordering`]
+    /// is returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_jn_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the input, consider using [`Float::bessel_jn_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_jn_prec_assign(3, 5), Less);
+    /// assert_eq!(x.to_string(), "0.328");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_jn_prec_assign(3, 20), Greater);
+    /// assert_eq!(x.to_string(), "0.33345842");
+    /// ```
+    #[inline]
+    pub fn bessel_jn_prec_assign(&mut self, n: i64, prec: u64) -> Ordering {
+        self.bessel_jn_prec_round_assign(n, prec, Nearest)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`], in
+    /// place, rounding the result to the precision of the input and with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded Bessel function is less
+    /// than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_jn_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::bessel_jn_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_jn_round_assign(3, Floor), Less);
+    /// assert_eq!(x.to_string(), "-0.33345833620298970");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_jn_round_assign(3, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.33345833620298926");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.bessel_jn_round_assign(3, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-0.33345833620298970");
+    /// ```
+    #[inline]
+    pub fn bessel_jn_round_assign(&mut self, n: i64, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.bessel_jn_prec_round_assign(n, prec, rm)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = J_n(x)+\varepsilon.
+    /// $$
+    /// - If $J_n(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_n(x)|\rfloor-p+1}$.
+    /// - If $J_n(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_n(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,0,p,m)=1.0$
+    /// - $f(0,n,p,m)=0.0$ if $n\neq 0$ is even or positive
+    /// - $f(0,n,p,m)=-0.0$ if $n$ is odd and negative
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_jn_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) =
+    ///     Float::bessel_jn_rational_prec_round(Rational::from_unsigneds(1u8, 3), 3, 5, Floor);
+    /// assert_eq!(jn.to_string(), "0.000763");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(jn.to_string(), "0.000793");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(jn.to_string(), "0.00076626055");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(jn.to_string(), "0.00076626148");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_jn_rational_prec_round(
+        x: Rational,
+        n: i64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::bessel_jn_rational_prec_round_ref(&x, n, prec, rm)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = J_n(x)+\varepsilon.
+    /// $$
+    /// - If $J_n(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |J_n(x)|\rfloor-p+1}$.
+    /// - If $J_n(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |J_n(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,0,p,m)=1.0$
+    /// - $f(0,n,p,m)=0.0$ if $n\neq 0$ is even or positive
+    /// - $f(0,n,p,m)=-0.0$ if $n$ is odd and negative
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_jn_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(jn.to_string(), "0.000763");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(jn.to_string(), "0.000793");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(jn.to_string(), "0.00076626055");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(jn.to_string(), "0.00076626148");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_jn_rational_prec_round_ref(
+        x: &Rational,
+        n: i64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return if n == 0 {
+                (Self::one_prec(prec), Equal)
+            } else if n < 0 && n.odd() {
+                (Self::NEGATIVE_ZERO, Equal)
+            } else {
+                (Self::ZERO, Equal)
+            };
+        }
+        jn_helper(n, &Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_jn_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec(Rational::from_unsigneds(1u8, 3), 3, 5);
+    /// assert_eq!(jn.to_string(), "0.000763");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) = Float::bessel_jn_rational_prec(Rational::from_unsigneds(1u8, 3), 3, 20);
+    /// assert_eq!(jn.to_string(), "0.00076626148");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_jn_rational_prec(x: Rational, n: i64, prec: u64) -> (Self, Ordering) {
+        Self::bessel_jn_rational_prec_round_ref(&x, n, prec, Nearest)
+    }
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_jn_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (jn, o) =
+    ///     Float::bessel_jn_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 3, 5);
+    /// assert_eq!(jn.to_string(), "0.000763");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (jn, o) =
+    ///     Float::bessel_jn_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 3, 20);
+    /// assert_eq!(jn.to_string(), "0.00076626148");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_jn_rational_prec_ref(x: &Rational, n: i64, prec: u64) -> (Self, Ordering) {
+        Self::bessel_jn_rational_prec_round_ref(x, n, prec, Nearest)
+    }
+}
+
+impl BesselJn for Float {
+    type Output = Self;
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x,n) = J_n(x)+\varepsilon.
+    /// $$
+    /// - If $J_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_n(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n)=\text{NaN}$
+    /// - $f(\pm\infty,n)=0.0$
+    /// - $f(\pm0.0,0)=1.0$
+    /// - $f(\pm0.0,n)=0.0$ if $n\neq 0$ is even
+    /// - $f(0.0,n)=0.0$ and $f(-0.0,n)=-0.0$ if $n$ is odd and positive
+    /// - $f(0.0,n)=-0.0$ and $f(-0.0,n)=0.0$ if $n$ is odd and negative
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_jn_prec`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_jn_round`]. If you want both of these things, consider using
+    /// [`Float::bessel_jn_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJn;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.bessel_jn(3).is_nan());
+    /// assert_eq!(Float::INFINITY.bessel_jn(3), Float::ZERO);
+    /// assert_eq!(Float::ZERO.bessel_jn(3), Float::ZERO);
+    /// assert_eq!(
+    ///     Float::from(PI).bessel_jn(3).to_string(),
+    ///     "0.33345833620298970"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(-PI).bessel_jn(3).to_string(),
+    ///     "-0.33345833620298970"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_jn(self, n: i64) -> Self {
+        let prec = self.significant_bits();
+        self.bessel_jn_prec_round(n, prec, Nearest).0
+    }
+}
+
+impl BesselJn for &Float {
+    type Output = Float;
+
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`],
+    /// taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x,n) = J_n(x)+\varepsilon.
+    /// $$
+    /// - If $J_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_n(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n)=\text{NaN}$
+    /// - $f(\pm\infty,n)=0.0$
+    /// - $f(\pm0.0,0)=1.0$
+    /// - $f(\pm0.0,n)=0.0$ if $n\neq 0$ is even
+    /// - $f(0.0,n)=0.0$ and $f(-0.0,n)=-0.0$ if $n$ is odd and positive
+    /// - $f(0.0,n)=-0.0$ and $f(-0.0,n)=0.0$ if $n$ is odd and negative
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_jn_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_jn_round_ref`]. If you want both of these things, consider using
+    /// [`Float::bessel_jn_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJn;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).bessel_jn(3).is_nan());
+    /// assert_eq!((&Float::INFINITY).bessel_jn(3), Float::ZERO);
+    /// assert_eq!((&Float::ZERO).bessel_jn(3), Float::ZERO);
+    /// assert_eq!(
+    ///     (&Float::from(PI)).bessel_jn(3).to_string(),
+    ///     "0.33345833620298970"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-PI)).bessel_jn(3).to_string(),
+    ///     "-0.33345833620298970"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_jn(self, n: i64) -> Float {
+        let prec = self.significant_bits();
+        self.bessel_jn_prec_round_ref(n, prec, Nearest).0
+    }
+}
+
+impl BesselJnAssign for Float {
+    /// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Float`], in
+    /// place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = J_n(x)+\varepsilon.
+    /// $$
+    /// - If $J_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $J_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |J_n(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::bessel_jn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::BesselJnAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.bessel_jn_assign(3);
+    /// assert_eq!(x.to_string(), "0.33345833620298970");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// x.bessel_jn_assign(3);
+    /// assert_eq!(x.to_string(), "-0.33345833620298970");
+    /// ```
+    #[inline]
+    fn bessel_jn_assign(&mut self, n: i64) {
+        let prec = self.significant_bits();
+        self.bessel_jn_prec_round_assign(n, prec, Nearest);
+    }
+}
+
+/// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x,n) = J_n(x)+\varepsilon.
+/// $$
+/// - If $J_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $J_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |J_n(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN},n)=\text{NaN}$
+/// - $f(\pm\infty,n)=0.0$
+/// - $f(\pm0.0,0)=1.0$
+/// - $f(\pm0.0,n)=0.0$ if $n\neq 0$ is even
+/// - $f(0.0,n)=0.0$ and $f(-0.0,n)=-0.0$ if $n$ is odd and positive
+/// - $f(0.0,n)=-0.0$ and $f(-0.0,n)=0.0$ if $n$ is odd and negative
+///
+/// # Worst-case complexity
+/// $T(k) = O(k^2 \log k)$
+///
+/// $M(k) = O(k \log k)$
+///
+/// where $T$ is time, $M$ is additional memory, and $k$ is `n.unsigned_abs()`.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_jn::primitive_float_bessel_jn;
+///
+/// assert!(primitive_float_bessel_jn(f64::NAN, 3).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_jn(0.0f64, 3)),
+///     NiceFloat(0.0)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_jn(PI, 3)),
+///     NiceFloat(0.33345833620298954)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_jn(-1.5f32, 3)),
+///     NiceFloat(-0.06096395)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_jn<T: PrimitiveFloat>(x: T, n: i64) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(|f, prec| Float::bessel_jn_prec(f, n, prec), x)
+}
+
+/// Computes $J_n(x)$, the Bessel function of the first kind of order $n$, of a [`Rational`],
+/// returning the result as a primitive float.
+///
+/// $$
+/// f(x,n) = J_n(x)+\varepsilon.
+/// $$
+/// - If $J_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $J_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |J_n(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0,0)=1.0$
+/// - $f(0,n)=0.0$ if $n\neq 0$ is even or positive
+/// - $f(0,n)=-0.0$ if $n$ is odd and negative
+///
+/// # Worst-case complexity
+/// $T(n, k) = O(n + k^2 \log k)$
+///
+/// $M(n, k) = O(n + k \log k)$
+///
+/// where $T$ is time, $M$ is additional memory, $n$ is `x.significant_bits()`, and $k$ is
+/// `n.unsigned_abs()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_jn::primitive_float_bessel_jn_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_jn_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3),
+///         3
+///     )),
+///     NiceFloat(0.0007662614320523266)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_jn_rational::<f64>(
+///         &Rational::from(10),
+///         3
+///     )),
+///     NiceFloat(0.058379379305186815)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_jn_rational<T: PrimitiveFloat>(x: &Rational, n: i64) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(|q, prec| Float::bessel_jn_rational_prec_ref(q, n, prec), x)
+}
@@ -0,0 +1,1062 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::bessel_yn::yn_helper;
+use crate::float::arithmetic::gamma::Argument;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{BesselY1, BesselY1Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, NegativeInfinity, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = Y_1(x)+\varepsilon.
+    /// $$
+    /// - If $Y_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_1(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |Y_1(x)|\rfloor-p+1}$.
+    /// - If $Y_1(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |Y_1(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(-\infty,p,m)=\text{NaN}$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    ///
+    /// Underflow is not possible. Since $Y_1(x)$ is close to $-2/(\pi x)$ for small $x$, the result
+    /// can overflow, but only if $x<2^{-2^{30}+1}$, which is only a few times the smallest positive
+    /// [`Float`]:
+    ///
+    /// Overflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_y1_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_y1_round`] instead. If both of these things are true, consider using
+    /// [`Float::bessel_y1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round(5, Floor);
+    /// assert_eq!(_y1.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round(5, Ceiling);
+    /// assert_eq!(_y1.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round(5, Nearest);
+    /// assert_eq!(_y1.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round(20, Floor);
+    /// assert_eq!(_y1.to_string(), "0.35887289");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round(20, Ceiling);
+    /// assert_eq!(_y1.to_string(), "0.35887337");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round(20, Nearest);
+    /// assert_eq!(_y1.to_string(), "0.35887289");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_y1_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.bessel_y1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_y1_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_y1_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).bessel_y1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round_ref(5, Floor);
+    /// assert_eq!(_y1.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round_ref(5, Ceiling);
+    /// assert_eq!(_y1.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round_ref(5, Nearest);
+    /// assert_eq!(_y1.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round_ref(20, Floor);
+    /// assert_eq!(_y1.to_string(), "0.35887289");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round_ref(20, Ceiling);
+    /// assert_eq!(_y1.to_string(), "0.35887337");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_round_ref(20, Nearest);
+    /// assert_eq!(_y1.to_string(), "0.35887289");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn bessel_y1_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { sign: false } => (Self::NAN, Equal),
+            Infinity { sign: true } => (Self::ZERO, Equal),
+            Zero { .. } => (Self::NEGATIVE_INFINITY, Equal),
+            Finite { .. } => yn_helper(1, &Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded Bessel function
+    /// is less than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_y1_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::bessel_y1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec(5);
+    /// assert_eq!(_y1.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec(20);
+    /// assert_eq!(_y1.to_string(), "0.35887289");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_y1_prec(self, prec: u64) -> (Self, Ordering) {
+        self.bessel_y1_prec_round(prec, Nearest)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded Bessel
+    /// function is less than, equal to, or greater than the exact Bessel function. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_y1_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).bessel_y1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_ref(5);
+    /// assert_eq!(_y1.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(PI).bessel_y1_prec_ref(20);
+    /// assert_eq!(_y1.to_string(), "0.35887289");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_y1_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.bessel_y1_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::bessel_y1_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::bessel_y1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::E;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::from(E).bessel_y1_round(Floor);
+    /// assert_eq!(_y1.to_string(), "0.23445370234309520");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::from(E).bessel_y1_round(Ceiling);
+    /// assert_eq!(_y1.to_string(), "0.23445370234309523");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(E).bessel_y1_round(Nearest);
+    /// assert_eq!(_y1.to_string(), "0.23445370234309523");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_y1_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_y1_prec_round(prec, rm)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_y1_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float).bessel_y1()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::E;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::from(E).bessel_y1_round_ref(Floor);
+    /// assert_eq!(_y1.to_string(), "0.23445370234309520");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::from(E).bessel_y1_round_ref(Ceiling);
+    /// assert_eq!(_y1.to_string(), "0.23445370234309523");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::from(E).bessel_y1_round_ref(Nearest);
+    /// assert_eq!(_y1.to_string(), "0.23445370234309523");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_y1_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_y1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`], in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded Bessel function is less than,
+    /// equal to, or greater than the exact Bessel function. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_y1_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::bessel_y1_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::bessel_y1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_y1_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.35887289");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_y1_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.35887337");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_y1_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.35887289");
+    /// ```
+    #[inline]
+    pub fn bessel_y1_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.bessel_y1_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`], in
+    /// place, rounding the result to the nearest value of the specified precision. An [`Ordering`]
+    /// is returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_y1_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the input, consider using [`Float::bessel_y1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_y1_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.359");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_y1_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.35887289");
+    /// ```
+    #[inline]
+    pub fn bessel_y1_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.bessel_y1_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`], in
+    /// place, rounding the result to the precision of the input and with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded Bessel function is less
+    /// than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_y1_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::bessel_y1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::E;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(E);
+    /// assert_eq!(x.bessel_y1_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.23445370234309520");
+    ///
+    /// let mut x = Float::from(E);
+    /// assert_eq!(x.bessel_y1_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.23445370234309523");
+    ///
+    /// let mut x = Float::from(E);
+    /// assert_eq!(x.bessel_y1_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.23445370234309523");
+    /// ```
+    #[inline]
+    pub fn bessel_y1_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.bessel_y1_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = Y_1(x)+\varepsilon.
+    /// $$
+    /// - If $Y_1(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |Y_1(x)|\rfloor-p+1}$.
+    /// - If $Y_1(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |Y_1(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    /// - $f(0,p,m)=-\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_y1_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) =
+    ///     Float::bessel_y1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(_y1.to_string(), "-2.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) =
+    ///     Float::bessel_y1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(_y1.to_string(), "-2.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) =
+    ///     Float::bessel_y1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(_y1.to_string(), "-2.0881691");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) =
+    ///     Float::bessel_y1_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(_y1.to_string(), "-2.0881653");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_y1_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::bessel_y1_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = Y_1(x)+\varepsilon.
+    /// $$
+    /// - If $Y_1(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |Y_1(x)|\rfloor-p+1}$.
+    /// - If $Y_1(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |Y_1(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    /// - $f(0,p,m)=-\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_y1_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::bessel_y1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(_y1.to_string(), "-2.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::bessel_y1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(_y1.to_string(), "-2.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_y1, o) = Float::bessel_y1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(_y1.to_string(), "-2.0881691");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::bessel_y1_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(_y1.to_string(), "-2.0881653");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_y1_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::NEGATIVE_INFINITY, Equal);
+        }
+        yn_helper(1, &Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_y1_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::bessel_y1_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(_y1.to_string(), "-2.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) = Float::bessel_y1_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(_y1.to_string(), "-2.0881653");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_y1_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::bessel_y1_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_y1_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_y1, o) = Float::bessel_y1_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(_y1.to_string(), "-2.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_y1, o) =
+    ///     Float::bessel_y1_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(_y1.to_string(), "-2.0881653");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_y1_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::bessel_y1_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl BesselY1 for Float {
+    type Output = Self;
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`], taking
+    /// it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = Y_1(x)+\varepsilon.
+    /// $$
+    /// - If $Y_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |Y_1(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    /// - $f(\infty)=0.0$
+    /// - $f(\pm0.0)=-\infty$
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_y1_prec`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_y1_round`]. If you want both of these things, consider using
+    /// [`Float::bessel_y1_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BesselY1;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.bessel_y1().is_nan());
+    /// assert!(Float::NEGATIVE_ONE.bessel_y1().is_nan());
+    /// assert_eq!(Float::INFINITY.bessel_y1(), Float::ZERO);
+    /// assert_eq!(Float::ZERO.bessel_y1(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     Float::from(PI).bessel_y1().to_string(),
+    ///     "0.35887291677671884"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(E).bessel_y1().to_string(),
+    ///     "0.23445370234309523"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_y1(self) -> Self {
+        let prec = self.significant_bits();
+        self.bessel_y1_prec_round(prec, Nearest).0
+    }
+}
+
+impl BesselY1 for &Float {
+    type Output = Float;
+
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`], taking
+    /// it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = Y_1(x)+\varepsilon.
+    /// $$
+    /// - If $Y_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |Y_1(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    /// - $f(\infty)=0.0$
+    /// - $f(\pm0.0)=-\infty$
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_y1_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_y1_round_ref`]. If you want both of these things, consider using
+    /// [`Float::bessel_y1_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BesselY1;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).bessel_y1().is_nan());
+    /// assert!((&Float::NEGATIVE_ONE).bessel_y1().is_nan());
+    /// assert_eq!((&Float::INFINITY).bessel_y1(), Float::ZERO);
+    /// assert_eq!((&Float::ZERO).bessel_y1(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     (&Float::from(PI)).bessel_y1().to_string(),
+    ///     "0.35887291677671884"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(E)).bessel_y1().to_string(),
+    ///     "0.23445370234309523"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_y1(self) -> Float {
+        let prec = self.significant_bits();
+        self.bessel_y1_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl BesselY1Assign for Float {
+    /// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Float`], in
+    /// place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = Y_1(x)+\varepsilon.
+    /// $$
+    /// - If $Y_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |Y_1(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::bessel_y1_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BesselY1Assign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.bessel_y1_assign();
+    /// assert_eq!(x.to_string(), "0.35887291677671884");
+    ///
+    /// let mut x = Float::from(E);
+    /// x.bessel_y1_assign();
+    /// assert_eq!(x.to_string(), "0.23445370234309523");
+    /// ```
+    #[inline]
+    fn bessel_y1_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.bessel_y1_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = Y_1(x)+\varepsilon.
+/// $$
+/// - If $Y_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $Y_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |Y_1(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+/// - $f(x)=\text{NaN}$ if $x<0$
+/// - $f(\infty)=0.0$
+/// - $f(\pm0.0)=-\infty$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_y1::primitive_float_bessel_y1;
+///
+/// assert!(primitive_float_bessel_y1(f64::NAN).is_nan());
+/// assert!(primitive_float_bessel_y1(-1.0f64).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_y1(0.0f64)),
+///     NiceFloat(f64::NEG_INFINITY)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_y1(PI)),
+///     NiceFloat(0.35887291677671895)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_y1(1.5f32)),
+///     NiceFloat(-0.41230863)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_y1<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::bessel_y1_prec, x)
+}
+
+/// Computes $Y_1(x)$, the Bessel function of the second kind of order 1, of a [`Rational`],
+/// returning the result as a primitive float.
+///
+/// $$
+/// f(x) = Y_1(x)+\varepsilon.
+/// $$
+/// - If $Y_1(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $Y_1(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |Y_1(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x)=\text{NaN}$ if $x<0$
+/// - $f(0)=-\infty$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_y1::primitive_float_bessel_y1_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_y1_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(-2.0881657798883864)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_y1_rational::<f64>(&Rational::from(
+///         10
+///     ))),
+///     NiceFloat(0.24901542420695388)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_y1_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::bessel_y1_rational_prec_ref, x)
+}
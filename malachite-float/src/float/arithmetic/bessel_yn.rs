@@ -0,0 +1,1384 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The Bessel functions of the second kind are computed from an exact rational argument x > 0 (they
+// are undefined for x < 0, and Y_(-n) = (-1) ^ n Y_n). For small and moderate x, the series
+//
+// Y_n(x) = -G A + F ((2 / π) log(x / 2) S - B / π),
+//
+// is used, where
+// - G = (2 / x) ^ n (n - 1)! / π and A = Σ_(k = 0) ^ (n - 1) (x ^ 2 / 4) ^ k (n - k - 1)! / (k!(n -
+//   1)!), a finite sum of positive terms (for n = 0, both are 0);
+// - F = (x / 2) ^ n / n! and S = Σ (-x ^ 2 / 4) ^ k n! / (k!(n + k)!), the factor and sum from the
+//   series for J_n(x); and
+// - B = Σ (ψ(k + 1) + ψ(n + k + 1)) (-x ^ 2 / 4) ^ k n! / (k!(n + k)!).
+//
+// As for J_n, the terms of S and B are computed in fixed point, with about 1.45x extra bits to make
+// up for cancellation. For large x, Hankel's expansion is used instead (see `bessel_jn`).
+//
+// For small x, Y_n(x) is close to -G, which is computed as e raised to the power of its logarithm,
+// with a power of 2 split off; and when x is small enough, Y_n(x) is bounded using only -G and an
+// error term, so that x need not be used exactly. Y_0(x) is instead close to (2 / π)(log(x / 2) +
+// γ). Every step produces rational bounds, and the result is rounded using Ziv's strategy.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::mul_bounds;
+use crate::float::arithmetic::bessel_jn::{
+    cancellation_bits, hankel_j_y_bounds, ln_factorial_bounds, ln_half_bounds, ln_j_factor_bounds,
+};
+use crate::float::arithmetic::exp::exp_overflow;
+use crate::float::arithmetic::gamma::{
+    Argument, constant_bounds, digamma_bounds, exp_scaled_bounds, exp_shift, ln_bounds,
+    signed_extreme,
+};
+use crate::float::arithmetic::sinh_cosh::round_with_scaled_bounds;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::cmp::{max, min};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    BesselYn, BesselYnAssign, DivRound, Parity, PowerOf2, Reciprocal, ShrRound, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, One, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// For n up to this, H_n is computed by summing 1 / k; for larger n, from ψ(n + 1).
+const HARMONIC_SUM_LIMIT: u64 = 1 << 16;
+
+fn pi_bounds(w: u64) -> (Rational, Rational) {
+    constant_bounds(Float::pi_prec_round(w, Floor))
+}
+
+// Returns bounds on log(G) = log((n - 1)!) - n log(x / 2) - log(π), for n > 0 and a positive
+// `Argument` x, with an absolute width of about `2 ^ (-w)`.
+fn ln_y_factor_bounds(n: u64, x: &Argument, w: u64) -> (Rational, Rational) {
+    let (l_lo, l_hi) = ln_half_bounds(x, w + 2 + n.significant_bits());
+    let (g_lo, g_hi) = ln_factorial_bounds(n - 1, w + 2);
+    let (pi_lo, pi_hi) = pi_bounds(w + 4);
+    let l_pi_lo = ln_bounds(&pi_lo, w + 4).0;
+    let l_pi_hi = ln_bounds(&pi_hi, w + 4).1;
+    let n = Rational::from(n);
+    (g_lo - &n * l_hi - l_pi_hi, g_hi - n * l_lo - l_pi_lo)
+}
+
+// Converts bounds to fixed point with `scale` fractional bits.
+fn to_fixed((lo, hi): &(Rational, Rational), scale: u64) -> (Integer, Integer) {
+    (
+        Integer::rounding_from(lo << scale, Floor).0,
+        Integer::rounding_from(hi << scale, Ceiling).0,
+    )
+}
+
+// Returns bounds on A = Σ_(k = 0) ^ (n - 1) (x ^ 2 / 4) ^ k (n - k - 1)! / (k!(n - 1)!), for n > 0
+// and a positive rational `x`, with a relative width of about `2 ^ (-w)`.
+//
+// The ratio of consecutive terms is x ^ 2 / (4k(n - k)), and since k(n - k) is concave, once the
+// ratio is at most 1/2 at both k and n - 1, it stays there and the rest of the sum is less than the
+// last term.
+fn a_series_bounds(x: &Rational, n: u64, w: u64) -> (Rational, Rational) {
+    let (p, q) = (x.square() >> 2u32).into_numerator_and_denominator();
+    let scale = w + 64;
+    let one = Natural::power_of_2(scale);
+    let mut t_lo = one.clone();
+    let mut t_hi = one.clone();
+    let mut s_lo = one.clone();
+    let mut s_hi = one.clone();
+    for k in 1..n {
+        let d = &q * Natural::from(k) * Natural::from(n - k);
+        t_lo = &t_lo * &p / &d;
+        t_hi = (&t_hi * &p).div_round(&d, Ceiling).0;
+        s_lo += &t_lo;
+        s_hi += &t_hi;
+        if k + 1 < n
+            && &p << 1u32 <= &q * Natural::from(min((k + 1) * (n - k - 1), n - 1))
+            && &t_hi << (w + 8) <= one
+        {
+            s_hi += t_hi;
+            break;
+        }
+    }
+    (Rational::from(s_lo) >> scale, Rational::from(s_hi) >> scale)
+}
+
+// Returns bounds on S and B, for a positive rational `x`, each with an absolute width of about
+// `2 ^ (-w - 1.45x)`.
+//
+// The ψ values are tracked in fixed point along with the terms of S, using ψ(k + 1) = ψ(k) + 1 / k.
+// Once the ratio of consecutive terms, x ^ 2 / (4(k + 1)(n + k + 1)), drops to 1/4, the tail of B
+// is at most the last term of S times |ψ(k + 1) + ψ(n + k + 1)| + 1.
+fn y_series_bounds(x: &Rational, n: u64, w: u64) -> ((Rational, Rational), (Rational, Rational)) {
+    let (p, q) = (x.square() >> 2u32).into_numerator_and_denominator();
+    let extra = cancellation_bits(x);
+    let scale = w + 32 + extra;
+    let one = Natural::power_of_2(scale);
+    // ψ(1) + ψ(n + 1) = H_n - 2γ, where H_n is the nth harmonic number
+    let (gamma_lo, gamma_hi) = constant_bounds(Float::euler_gamma_prec_round(scale + 2, Floor));
+    let (mut h_lo, mut h_hi) = if n <= HARMONIC_SUM_LIMIT {
+        let (mut h_lo, mut h_hi) = to_fixed(&(-(gamma_hi << 1u32), -(gamma_lo << 1u32)), scale);
+        for j in 1..=n {
+            let j = Natural::from(j);
+            h_lo += Integer::from(&one / &j);
+            h_hi += Integer::from((&one).div_round(j, Ceiling).0);
+        }
+        (h_lo, h_hi)
+    } else {
+        let (psi_lo, psi_hi) = digamma_bounds(&Rational::from(n + 1), scale + 2);
+        to_fixed(&(psi_lo - gamma_hi, psi_hi - gamma_lo), scale)
+    };
+    let mut t_lo = one.clone();
+    let mut t_hi = one.clone();
+    let mut s_lo = Integer::from(&one);
+    let mut s_hi = s_lo.clone();
+    let mut b_lo = h_lo.clone();
+    let mut b_hi = h_hi.clone();
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        let d = &q * Natural::from(k) * Natural::from(n + k);
+        t_lo = &t_lo * &p / &d;
+        t_hi = (&t_hi * &p).div_round(&d, Ceiling).0;
+        // ψ(k + 1) + ψ(n + k + 1) = ψ(k) + ψ(n + k) + 1 / k + 1 / (n + k)
+        h_lo += Integer::from(&one / Natural::from(k) + &one / Natural::from(n + k));
+        h_hi += Integer::from(
+            (&one).div_round(Natural::from(k), Ceiling).0
+                + (&one).div_round(Natural::from(n + k), Ceiling).0,
+        );
+        // bounds on the product of h and t, using t >= 0
+        let t_lo_i = Integer::from(&t_lo);
+        let t_hi_i = Integer::from(&t_hi);
+        let u_lo = (if h_lo >= 0u32 {
+            &h_lo * &t_lo_i
+        } else {
+            &h_lo * &t_hi_i
+        })
+        .shr_round(scale, Floor)
+        .0;
+        let u_hi = (if h_hi >= 0u32 {
+            &h_hi * &t_hi_i
+        } else {
+            &h_hi * &t_lo_i
+        })
+        .shr_round(scale, Ceiling)
+        .0;
+        if k.odd() {
+            s_lo -= t_hi_i;
+            s_hi -= t_lo_i;
+            b_lo -= u_hi;
+            b_hi -= u_lo;
+        } else {
+            s_lo += t_lo_i;
+            s_hi += t_hi_i;
+            b_lo += u_lo;
+            b_hi += u_hi;
+        }
+        if &p << 2u32 <= &q * Natural::from(k + 1) * Natural::from(n + k + 1)
+            && &t_hi << (w + 8 + extra) <= one
+        {
+            let t_hi = Integer::from(t_hi);
+            let h = Integer::from(max((&h_lo).unsigned_abs(), (&h_hi).unsigned_abs()) + &one);
+            let b_tail = (&h * &t_hi).shr_round(scale, Ceiling).0;
+            s_lo -= &t_hi;
+            s_hi += t_hi;
+            b_lo -= &b_tail;
+            b_hi += b_tail;
+            break;
+        }
+    }
+    (
+        (Rational::from(s_lo) >> scale, Rational::from(s_hi) >> scale),
+        (Rational::from(b_lo) >> scale, Rational::from(b_hi) >> scale),
+    )
+}
+
+// Returns Y_n(x) rounded, for a positive x.
+fn yn_positive(n: u64, x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exp_x = x.exponent();
+    let e_bits = exp_x.unsigned_abs().significant_bits();
+    // For n > 0 and small x, Y_n(x) is close to -G
+    let shift = if n == 0 {
+        0
+    } else {
+        let (l_lo, _) = ln_y_factor_bounds(n, x, 16);
+        // ln(2) < 7/10. If x < 2, -Y_n(x) > G - 1.
+        if exp_x <= 1
+            && l_lo
+                > Rational::from(Float::MAX_EXPONENT_I64 + 2) * Rational::from_unsigneds(7u8, 10)
+        {
+            return signed_extreme(exp_overflow, false, prec, rm);
+        }
+        if l_lo > 0u32 { exp_shift(&l_lo) } else { 0 }
+    };
+    // When 2 ^ (2e + k + 4) < 2 ^ (-w - 8), where e is the exponent of x and k is the number of
+    // significant bits of |e|, x is tiny.
+    let tiny = |w: u64| {
+        exp_x < 0 && -((exp_x << 1) + i64::exact_from(e_bits) + 4) > i64::exact_from(w + 8)
+    };
+    let mut x_rational = None;
+    round_with_scaled_bounds(shift, prec, rm, |w| {
+        let wl = w + 16 + e_bits;
+        if tiny(w) {
+            let delta = Rational::power_of_2((exp_x << 1) + i64::exact_from(e_bits) + 4);
+            if n == 0 {
+                // Y_0(x) = (2 / π)(log(x / 2) + γ) + ε, with |ε| < δ
+                let (l_lo, l_hi) = ln_half_bounds(x, wl);
+                let (g_lo, g_hi) = constant_bounds(Float::euler_gamma_prec_round(wl, Floor));
+                let (pi_lo, pi_hi) = pi_bounds(wl);
+                let (lo, hi) = mul_bounds(
+                    &(l_lo + g_lo, l_hi + g_hi),
+                    &((pi_hi >> 1u32).reciprocal(), (pi_lo >> 1u32).reciprocal()),
+                );
+                return (lo - &delta, hi + delta);
+            }
+            // Y_n(x) = -G(1 + ε), with |ε| < δ
+            let (g_lo, g_hi) = exp_scaled_bounds(ln_y_factor_bounds(n, x, wl), shift, true, wl);
+            return (
+                -g_hi * (Rational::ONE + &delta),
+                -g_lo * (Rational::ONE - delta),
+            );
+        }
+        let xr = x_rational.get_or_insert_with(|| x.to_rational());
+        if let Some((lo, hi)) = hankel_j_y_bounds(xr, n, true, w) {
+            return (lo >> shift, hi >> shift);
+        }
+        let wx = wl + cancellation_bits(xr);
+        let (s, b) = y_series_bounds(xr, n, wl);
+        let (f_lo, f_hi) = exp_scaled_bounds(ln_j_factor_bounds(n, x, wx), shift, true, wx);
+        let (l_lo, l_hi) = ln_half_bounds(x, wx);
+        let (pi_lo, pi_hi) = pi_bounds(wx);
+        // (2 log(x / 2) S - B) / π
+        let (ls_lo, ls_hi) = mul_bounds(&(l_lo << 1u32, l_hi << 1u32), &s);
+        let (c_lo, c_hi) = mul_bounds(
+            &(ls_lo - b.1, ls_hi - b.0),
+            &(pi_hi.reciprocal(), pi_lo.reciprocal()),
+        );
+        let (lo, hi) = mul_bounds(&(f_lo, f_hi), &(c_lo, c_hi));
+        if n == 0 {
+            return (lo, hi);
+        }
+        let (g_lo, g_hi) = exp_scaled_bounds(ln_y_factor_bounds(n, x, wx), shift, true, wx);
+        let (a_lo, a_hi) = a_series_bounds(xr, n, wx);
+        (lo - g_hi * a_hi, hi - g_lo * a_lo)
+    })
+}
+
+// Computes Y_n(x), for x != 0.
+pub(crate) fn yn_helper(n: i64, x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if !x.is_positive() {
+        return (Float::NAN, Equal);
+    }
+    assert_ne!(rm, Exact, "Inexact Bessel function");
+    // Y_(-n)(x) = (-1) ^ n Y_n(x)
+    if n < 0 && n.odd() {
+        let (y, o) = yn_positive(n.unsigned_abs(), x, prec, -rm);
+        (-y, o.reverse())
+    } else {
+        yn_positive(n.unsigned_abs(), x, prec, rm)
+    }
+}
+
+impl Float {
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = Y_n(x)+\varepsilon.
+    /// $$
+    /// - If $Y_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_n(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |Y_n(x)|\rfloor-p+1}$.
+    /// - If $Y_n(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |Y_n(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,p,m)=f(-\infty,n,p,m)=\text{NaN}$
+    /// - $f(x,n,p,m)=\text{NaN}$ if $x<0$
+    /// - $f(\infty,n,p,m)=0.0$
+    /// - $f(\pm0.0,n,p,m)=\infty$ if $n$ is odd and negative
+    /// - $f(\pm0.0,n,p,m)=-\infty$ otherwise
+    ///
+    /// Underflow is not possible. The result overflows when $x$ is small compared to $|n|$, since
+    /// $|Y_n(x)|$ is then close to $(|n|-1)!(2/x)^{|n|}/\pi$:
+    ///
+    /// Overflow:
+    /// - If $f(x,n,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,n,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_yn_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_yn_round`] instead. If both of these things are true, consider using
+    /// [`Float::bessel_yn`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round(3, 5, Floor);
+    /// assert_eq!(_yn.to_string(), "-0.500");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round(3, 5, Ceiling);
+    /// assert_eq!(_yn.to_string(), "-0.484");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round(3, 5, Nearest);
+    /// assert_eq!(_yn.to_string(), "-0.484");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round(3, 20, Floor);
+    /// assert_eq!(_yn.to_string(), "-0.48607063");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round(3, 20, Ceiling);
+    /// assert_eq!(_yn.to_string(), "-0.48607016");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round(3, 20, Nearest);
+    /// assert_eq!(_yn.to_string(), "-0.48607063");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_yn_prec_round(self, n: i64, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.bessel_yn_prec_round_ref(n, prec, rm)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_yn_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::bessel_yn_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).bessel_yn(n)` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round_ref(3, 5, Floor);
+    /// assert_eq!(_yn.to_string(), "-0.500");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round_ref(3, 5, Ceiling);
+    /// assert_eq!(_yn.to_string(), "-0.484");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round_ref(3, 5, Nearest);
+    /// assert_eq!(_yn.to_string(), "-0.484");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round_ref(3, 20, Floor);
+    /// assert_eq!(_yn.to_string(), "-0.48607063");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round_ref(3, 20, Ceiling);
+    /// assert_eq!(_yn.to_string(), "-0.48607016");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_round_ref(3, 20, Nearest);
+    /// assert_eq!(_yn.to_string(), "-0.48607063");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn bessel_yn_prec_round_ref(
+        &self,
+        n: i64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { sign: false } => (Self::NAN, Equal),
+            Infinity { sign: true } => (Self::ZERO, Equal),
+            Zero { .. } => {
+                if n < 0 && n.odd() {
+                    (Self::INFINITY, Equal)
+                } else {
+                    (Self::NEGATIVE_INFINITY, Equal)
+                }
+            }
+            Finite { .. } => yn_helper(n, &Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded Bessel function
+    /// is less than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_yn_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::bessel_yn`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec(3, 5);
+    /// assert_eq!(_yn.to_string(), "-0.484");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec(3, 20);
+    /// assert_eq!(_yn.to_string(), "-0.48607063");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_yn_prec(self, n: i64, prec: u64) -> (Self, Ordering) {
+        self.bessel_yn_prec_round(n, prec, Nearest)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded Bessel
+    /// function is less than, equal to, or greater than the exact Bessel function. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_yn_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).bessel_yn(n)` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_ref(3, 5);
+    /// assert_eq!(_yn.to_string(), "-0.484");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(PI).bessel_yn_prec_ref(3, 20);
+    /// assert_eq!(_yn.to_string(), "-0.48607063");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn bessel_yn_prec_ref(&self, n: i64, prec: u64) -> (Self, Ordering) {
+        self.bessel_yn_prec_round_ref(n, prec, Nearest)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::bessel_yn_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::bessel_yn`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::E;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::from(E).bessel_yn_round(3, Floor);
+    /// assert_eq!(_yn.to_string(), "-0.65203820813027891");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::from(E).bessel_yn_round(3, Ceiling);
+    /// assert_eq!(_yn.to_string(), "-0.65203820813027880");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(E).bessel_yn_round(3, Nearest);
+    /// assert_eq!(_yn.to_string(), "-0.65203820813027880");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_yn_round(self, n: i64, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_yn_prec_round(n, prec, rm)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// rounding the result to the precision of the input and with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded Bessel function is less than, equal to, or greater than the exact Bessel function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_yn_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float).bessel_yn(n)` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::E;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::from(E).bessel_yn_round_ref(3, Floor);
+    /// assert_eq!(_yn.to_string(), "-0.65203820813027891");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::from(E).bessel_yn_round_ref(3, Ceiling);
+    /// assert_eq!(_yn.to_string(), "-0.65203820813027880");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::from(E).bessel_yn_round_ref(3, Nearest);
+    /// assert_eq!(_yn.to_string(), "-0.65203820813027880");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_yn_round_ref(&self, n: i64, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.bessel_yn_prec_round_ref(n, prec, rm)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`], in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// An [`Ordering`] is returned, indicating whether the rounded Bessel function is less than,
+    /// equal to, or greater than the exact Bessel function. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_yn_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::bessel_yn_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::bessel_yn_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_yn_prec_round_assign(3, 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "-0.48607063");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_yn_prec_round_assign(3, 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.48607016");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_yn_prec_round_assign(3, 20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-0.48607063");
+    /// ```
+    #[inline]
+    pub fn bessel_yn_prec_round_assign(&mut self, n: i64, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.bessel_yn_prec_round(n, prec, rm);
+        o
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`], in
+    /// place, rounding the result to the nearest value of the specified precision. An [`Ordering`]
+    /// is returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_yn_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the input, consider using [`Float::bessel_yn_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is
+    /// `self.significant_bits()`, and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_yn_prec_assign(3, 5), Greater);
+    /// assert_eq!(x.to_string(), "-0.484");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.bessel_yn_prec_assign(3, 20), Less);
+    /// assert_eq!(x.to_string(), "-0.48607063");
+    /// ```
+    #[inline]
+    pub fn bessel_yn_prec_assign(&mut self, n: i64, prec: u64) -> Ordering {
+        self.bessel_yn_prec_round_assign(n, prec, Nearest)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`], in
+    /// place, rounding the result to the precision of the input and with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded Bessel function is less
+    /// than, equal to, or greater than the exact Bessel function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::bessel_yn_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::bessel_yn_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::E;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(E);
+    /// assert_eq!(x.bessel_yn_round_assign(3, Floor), Less);
+    /// assert_eq!(x.to_string(), "-0.65203820813027891");
+    ///
+    /// let mut x = Float::from(E);
+    /// assert_eq!(x.bessel_yn_round_assign(3, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.65203820813027880");
+    ///
+    /// let mut x = Float::from(E);
+    /// assert_eq!(x.bessel_yn_round_assign(3, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-0.65203820813027880");
+    /// ```
+    #[inline]
+    pub fn bessel_yn_round_assign(&mut self, n: i64, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.bessel_yn_prec_round_assign(n, prec, rm)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = Y_n(x)+\varepsilon.
+    /// $$
+    /// - If $Y_n(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |Y_n(x)|\rfloor-p+1}$.
+    /// - If $Y_n(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |Y_n(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,n,p,m)=\text{NaN}$ if $x<0$
+    /// - $f(0,n,p,m)=\infty$ if $n$ is odd and negative
+    /// - $f(0,n,p,m)=-\infty$ otherwise
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_yn_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) =
+    ///     Float::bessel_yn_rational_prec_round(Rational::from_unsigneds(1u8, 3), 3, 5, Floor);
+    /// assert_eq!(_yn.to_string(), "-144.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(_yn.to_string(), "-136.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(_yn.to_string(), "-139.44751");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(_yn.to_string(), "-139.44727");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_yn_rational_prec_round(
+        x: Rational,
+        n: i64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::bessel_yn_rational_prec_round_ref(&x, n, prec, rm)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded Bessel function is less than, equal to, or
+    /// greater than the exact Bessel function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = Y_n(x)+\varepsilon.
+    /// $$
+    /// - If $Y_n(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |Y_n(x)|\rfloor-p+1}$.
+    /// - If $Y_n(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |Y_n(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,n,p,m)=\text{NaN}$ if $x<0$
+    /// - $f(0,n,p,m)=\infty$ if $n$ is odd and negative
+    /// - $f(0,n,p,m)=-\infty$ otherwise
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::bessel_yn_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every positive input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(_yn.to_string(), "-144.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(_yn.to_string(), "-136.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(_yn.to_string(), "-139.44751");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     3,
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(_yn.to_string(), "-139.44727");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn bessel_yn_rational_prec_round_ref(
+        x: &Rational,
+        n: i64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return if n < 0 && n.odd() {
+                (Self::INFINITY, Equal)
+            } else {
+                (Self::NEGATIVE_INFINITY, Equal)
+            };
+        }
+        yn_helper(n, &Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_yn_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec(Rational::from_unsigneds(1u8, 3), 3, 5);
+    /// assert_eq!(_yn.to_string(), "-136.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) = Float::bessel_yn_rational_prec(Rational::from_unsigneds(1u8, 3), 3, 20);
+    /// assert_eq!(_yn.to_string(), "-139.44727");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn bessel_yn_rational_prec(x: Rational, n: i64, prec: u64) -> (Self, Ordering) {
+        Self::bessel_yn_rational_prec_round_ref(&x, n, prec, Nearest)
+    }
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded Bessel function is less than, equal to, or greater than the
+    /// exact Bessel function.
+    ///
+    /// If the Bessel function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::bessel_yn_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O(n^3 \log n + n^2 m + kn + k^2 \log k)$
+    ///
+    /// $M(n, m, k) = O(n^2 \log n + nm + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, $m$ is `x.significant_bits()`,
+    /// and $k$ is `n.unsigned_abs()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (_yn, o) =
+    ///     Float::bessel_yn_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 3, 5);
+    /// assert_eq!(_yn.to_string(), "-136.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (_yn, o) =
+    ///     Float::bessel_yn_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 3, 20);
+    /// assert_eq!(_yn.to_string(), "-139.44727");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn bessel_yn_rational_prec_ref(x: &Rational, n: i64, prec: u64) -> (Self, Ordering) {
+        Self::bessel_yn_rational_prec_round_ref(x, n, prec, Nearest)
+    }
+}
+
+impl BesselYn for Float {
+    type Output = Self;
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x,n) = Y_n(x)+\varepsilon.
+    /// $$
+    /// - If $Y_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |Y_n(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n)=f(-\infty,n)=\text{NaN}$
+    /// - $f(x,n)=\text{NaN}$ if $x<0$
+    /// - $f(\infty,n)=0.0$
+    /// - $f(\pm0.0,n)=\infty$ if $n$ is odd and negative
+    /// - $f(\pm0.0,n)=-\infty$ otherwise
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_yn_prec`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_yn_round`]. If you want both of these things, consider using
+    /// [`Float::bessel_yn_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BesselYn;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.bessel_yn(3).is_nan());
+    /// assert!(Float::NEGATIVE_ONE.bessel_yn(3).is_nan());
+    /// assert_eq!(Float::INFINITY.bessel_yn(3), Float::ZERO);
+    /// assert_eq!(Float::ZERO.bessel_yn(3), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     Float::from(PI).bessel_yn(3).to_string(),
+    ///     "-0.48607045629843215"
+    /// );
+    /// assert_eq!(
+    ///     Float::from(E).bessel_yn(3).to_string(),
+    ///     "-0.65203820813027880"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_yn(self, n: i64) -> Self {
+        let prec = self.significant_bits();
+        self.bessel_yn_prec_round(n, prec, Nearest).0
+    }
+}
+
+impl BesselYn for &Float {
+    type Output = Float;
+
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`],
+    /// taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x,n) = Y_n(x)+\varepsilon.
+    /// $$
+    /// - If $Y_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |Y_n(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n)=f(-\infty,n)=\text{NaN}$
+    /// - $f(x,n)=\text{NaN}$ if $x<0$
+    /// - $f(\infty,n)=0.0$
+    /// - $f(\pm0.0,n)=\infty$ if $n$ is odd and negative
+    /// - $f(\pm0.0,n)=-\infty$ otherwise
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::bessel_yn_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::bessel_yn_round_ref`]. If you want both of these things, consider using
+    /// [`Float::bessel_yn_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BesselYn;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).bessel_yn(3).is_nan());
+    /// assert!((&Float::NEGATIVE_ONE).bessel_yn(3).is_nan());
+    /// assert_eq!((&Float::INFINITY).bessel_yn(3), Float::ZERO);
+    /// assert_eq!((&Float::ZERO).bessel_yn(3), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     (&Float::from(PI)).bessel_yn(3).to_string(),
+    ///     "-0.48607045629843215"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(E)).bessel_yn(3).to_string(),
+    ///     "-0.65203820813027880"
+    /// );
+    /// ```
+    #[inline]
+    fn bessel_yn(self, n: i64) -> Float {
+        let prec = self.significant_bits();
+        self.bessel_yn_prec_round_ref(n, prec, Nearest).0
+    }
+}
+
+impl BesselYnAssign for Float {
+    /// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Float`], in
+    /// place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the Bessel function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = Y_n(x)+\varepsilon.
+    /// $$
+    /// - If $Y_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $Y_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |Y_n(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::bessel_yn_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 \log n + kn + k^2 \log k)$
+    ///
+    /// $M(n, k) = O(n^2 \log n + k \log k)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $k$ is
+    /// `n.unsigned_abs()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BesselYnAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.bessel_yn_assign(3);
+    /// assert_eq!(x.to_string(), "-0.48607045629843215");
+    ///
+    /// let mut x = Float::from(E);
+    /// x.bessel_yn_assign(3);
+    /// assert_eq!(x.to_string(), "-0.65203820813027880");
+    /// ```
+    #[inline]
+    fn bessel_yn_assign(&mut self, n: i64) {
+        let prec = self.significant_bits();
+        self.bessel_yn_prec_round_assign(n, prec, Nearest);
+    }
+}
+
+/// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x,n) = Y_n(x)+\varepsilon.
+/// $$
+/// - If $Y_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $Y_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |Y_n(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN},n)=f(-\infty,n)=\text{NaN}$
+/// - $f(x,n)=\text{NaN}$ if $x<0$
+/// - $f(\infty,n)=0.0$
+/// - $f(\pm0.0,n)=\infty$ if $n$ is odd and negative
+/// - $f(\pm0.0,n)=-\infty$ otherwise
+///
+/// # Worst-case complexity
+/// $T(k) = O(k^2 \log k)$
+///
+/// $M(k) = O(k \log k)$
+///
+/// where $T$ is time, $M$ is additional memory, and $k$ is `n.unsigned_abs()`.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_yn::primitive_float_bessel_yn;
+///
+/// assert!(primitive_float_bessel_yn(f64::NAN, 3).is_nan());
+/// assert!(primitive_float_bessel_yn(-1.0f64, 3).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_yn(0.0f64, 3)),
+///     NiceFloat(f64::NEG_INFINITY)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_yn(PI, 3)),
+///     NiceFloat(-0.4860704562984322)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_yn(1.5f32, 3)),
+///     NiceFloat(-2.0735414)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_yn<T: PrimitiveFloat>(x: T, n: i64) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(|f, prec| Float::bessel_yn_prec(f, n, prec), x)
+}
+
+/// Computes $Y_n(x)$, the Bessel function of the second kind of order $n$, of a [`Rational`],
+/// returning the result as a primitive float.
+///
+/// $$
+/// f(x,n) = Y_n(x)+\varepsilon.
+/// $$
+/// - If $Y_n(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $Y_n(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |Y_n(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x,n)=\text{NaN}$ if $x<0$
+/// - $f(0,n)=\infty$ if $n$ is odd and negative
+/// - $f(0,n)=-\infty$ otherwise
+///
+/// # Worst-case complexity
+/// $T(n, k) = O(n + k^2 \log k)$
+///
+/// $M(n, k) = O(n + k \log k)$
+///
+/// where $T$ is time, $M$ is additional memory, $n$ is `x.significant_bits()`, and $k$ is
+/// `n.unsigned_abs()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::bessel_yn::primitive_float_bessel_yn_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_yn_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3),
+///         3
+///     )),
+///     NiceFloat(-139.44729349062175)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_bessel_yn_rational::<f64>(
+///         &Rational::from(10),
+///         3
+///     )),
+///     NiceFloat(-0.2513626571838373)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_bessel_yn_rational<T: PrimitiveFloat>(x: &Rational, n: i64) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(|q, prec| Float::bessel_yn_rational_prec_ref(q, n, prec), x)
+}
@@ -0,0 +1,2053 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::gamma::{beta_near_pole, beta_rational_helper};
+use crate::{
+    Float, emulate_float_float_to_float_fn, emulate_rational_rational_to_float_fn, float_finite,
+    float_infinity, float_nan, float_negative_infinity, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Beta, BetaAssign, Parity};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{Infinity as InfinityTrait, NaN as NaNTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+fn is_odd_integer(x: &Float) -> bool {
+    x.is_integer() && !(x >> 1u32).is_integer()
+}
+
+fn beta_prec_round_helper(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (float_nan!(), _) | (_, float_nan!()) => return (float_nan!(), Equal),
+        (float_finite!(), float_finite!()) => {
+            if let Some(result) = beta_near_pole(x, y, prec, rm) {
+                return result;
+            }
+            return beta_rational_helper(
+                &Rational::exact_from(x),
+                &Rational::exact_from(y),
+                prec,
+                rm,
+            );
+        }
+        (Float(Zero { sign: x_sign }), Float(Zero { sign: y_sign })) => {
+            return if x_sign == y_sign {
+                (Float(Infinity { sign: *x_sign }), Equal)
+            } else {
+                (float_nan!(), Equal)
+            };
+        }
+        _ => {}
+    }
+    // From here on, at least one argument is infinite, or one is zero and the other is finite and
+    // nonzero. Let z be the larger argument and w the smaller.
+    let (z, w) = if x < y { (y, x) } else { (x, y) };
+    match (z, w) {
+        (float_infinity!(), float_finite!()) => {
+            if *w > 0.0 {
+                (float_zero!(), Equal)
+            } else if w.is_integer() {
+                (float_nan!(), Equal)
+            } else {
+                // B(∞, w) has the sign of Γ(w), which is negative iff trunc(w) is odd
+                let sign = Integer::rounding_from(w, Down).0.even();
+                (Float(Infinity { sign }), Equal)
+            }
+        }
+        (float_infinity!(), float_infinity!()) => (float_zero!(), Equal),
+        (float_infinity!(), _) => (float_nan!(), Equal),
+        (_, float_negative_infinity!()) => {
+            if *z > 0.0 && z.is_integer() {
+                (
+                    Float(Zero {
+                        sign: !is_odd_integer(z),
+                    }),
+                    Equal,
+                )
+            } else {
+                (float_nan!(), Equal)
+            }
+        }
+        (Float(Zero { sign }), other) | (other, Float(Zero { sign })) => {
+            if *other < 0.0 && other.is_integer() {
+                (float_nan!(), Equal)
+            } else {
+                (Float(Infinity { sign: *sign }), Equal)
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+impl Float {
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. Both [`Float`]s are taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |B(x,y)|\rfloor-p+1}$.
+    /// - If $B(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |B(x,y)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},y,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,y,p,m)=f(y,\infty,p,m)=0.0$ if $y>0$
+    /// - $f(\infty,y,p,m)=f(y,\infty,p,m)=(-1)^{\lceil y\rceil}\infty$ if $y<0$ and $y$ is not an
+    ///   integer
+    /// - $f(\infty,y,p,m)=f(y,\infty,p,m)=\text{NaN}$ if $y$ is $\pm0.0$, $-\infty$, or a negative
+    ///   integer
+    /// - $f(-\infty,y,p,m)=f(y,-\infty,p,m)=(-1)^y0.0$ if $y$ is a positive integer
+    /// - $f(-\infty,y,p,m)=f(y,-\infty,p,m)=\text{NaN}$ if $y$ is finite and not a positive integer
+    /// - $f(\pm0.0,y,p,m)=f(y,\pm0.0,p,m)=\pm\infty$ if $y$ is finite and nonzero, and not a
+    ///   negative integer
+    /// - $f(\pm0.0,y,p,m)=f(y,\pm0.0,p,m)=\text{NaN}$ if $y$ is a negative integer
+    /// - $f(\pm0.0,\pm0.0,p,m)=\pm\infty$
+    /// - $f(\pm0.0,\mp0.0,p,m)=\text{NaN}$
+    /// - $f(x,y,p,m)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer
+    ///   with $x\leq -y$; and symmetrically
+    /// - $f(x,y,p,m)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an
+    ///   integer
+    ///
+    /// The result overflows when $x$ or $y$ is extremely close to zero or, when the other argument
+    /// is a suitable positive integer, to a negative integer; it underflows when $x$ and $y$ are
+    /// both very large:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::beta_round`] instead. If both of these things are true, consider using
+    /// [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round(Float::from(E), 5, Floor);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round(Float::from(E), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.0391");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round(Float::from(E), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round(Float::from(E), 20, Floor);
+    /// assert_eq!(beta.to_string(), "0.037890255");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round(Float::from(E), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round(Float::from(E), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_prec_round(self, other: Self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        beta_prec_round_helper(&self, &other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. The first [`Float`] is taken by value and
+    /// the second by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// result is less than, equal to, or greater than the exact result. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::beta_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_val_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_val_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.0391");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_val_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_val_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(beta.to_string(), "0.037890255");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_val_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_val_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn beta_prec_round_val_ref(
+        self,
+        other: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        beta_prec_round_helper(&self, other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. The first [`Float`] is taken by reference
+    /// and the second by value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// result is less than, equal to, or greater than the exact result. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::beta_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_val(Float::from(E), 5, Floor);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_val(Float::from(E), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.0391");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_val(Float::from(E), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_val(Float::from(E), 20, Floor);
+    /// assert_eq!(beta.to_string(), "0.037890255");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_val(Float::from(E), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_val(Float::from(E), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_prec_round_ref_val(
+        &self,
+        other: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        beta_prec_round_helper(self, &other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. Both [`Float`]s are taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::beta_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.0391");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(beta.to_string(), "0.037890255");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_round_ref_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn beta_prec_round_ref_ref(
+        &self,
+        other: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        beta_prec_round_helper(self, other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. Both [`Float`]s are taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec(Float::from(E), 5);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec(Float::from(E), 20);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn beta_prec(self, other: Self, prec: u64) -> (Self, Ordering) {
+        self.beta_prec_round(other, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. The first [`Float`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn beta_prec_val_ref(self, other: &Self, prec: u64) -> (Self, Ordering) {
+        self.beta_prec_round_val_ref(other, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. The first [`Float`] is taken by reference and the second
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn beta_prec_ref_val(&self, other: Self, prec: u64) -> (Self, Ordering) {
+        self.beta_prec_round_ref_val(other, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. Both [`Float`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(beta.to_string(), "0.0371");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(beta.to_string(), "0.037890315");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn beta_prec_ref_ref(&self, other: &Self, prec: u64) -> (Self, Ordering) {
+        self.beta_prec_round_ref_ref(other, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the maximum
+    /// of the precisions of the inputs and with the specified rounding mode. Both [`Float`]s are
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::beta_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round(Float::from(E), Floor);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round(Float::from(E), Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890298781212216");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round(Float::from(E), Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn beta_round(self, other: Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round(other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the maximum
+    /// of the precisions of the inputs and with the specified rounding mode. The first [`Float`] is
+    /// taken by value and the second by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::beta_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_val_ref(&Float::from(E), Floor);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_val_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890298781212216");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_val_ref(&Float::from(E), Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn beta_round_val_ref(self, other: &Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the maximum
+    /// of the precisions of the inputs and with the specified rounding mode. The first [`Float`] is
+    /// taken by reference and the second by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::beta_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_ref_val(Float::from(E), Floor);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_ref_val(Float::from(E), Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890298781212216");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_ref_val(Float::from(E), Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn beta_round_ref_val(&self, other: Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, rounding the result to the maximum
+    /// of the precisions of the inputs and with the specified rounding mode. Both [`Float`]s are
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the exact result. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::beta_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::beta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_ref_ref(&Float::from(E), Floor);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_ref_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(beta.to_string(), "0.037890298781212216");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::from(PI).beta_round_ref_ref(&Float::from(E), Nearest);
+    /// assert_eq!(beta.to_string(), "0.037890298781212209");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn beta_round_ref_ref(&self, other: &Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_round_assign(Float::from(E), 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.037890255");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_round_assign(Float::from(E), 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.037890315");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_round_assign(Float::from(E), 20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.037890315");
+    /// ```
+    #[inline]
+    pub fn beta_prec_round_assign(&mut self, other: Self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.beta_prec_round_ref_val(other, prec, rm);
+        o
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_round_assign_ref(&Float::from(E), 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.037890255");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_round_assign_ref(&Float::from(E), 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.037890315");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_round_assign_ref(&Float::from(E), 20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.037890315");
+    /// ```
+    #[inline]
+    pub fn beta_prec_round_assign_ref(
+        &mut self,
+        other: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.beta_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] on the
+    /// right-hand side is taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_assign(Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "0.0371");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_assign(Float::from(E), 20), Greater);
+    /// assert_eq!(x.to_string(), "0.037890315");
+    /// ```
+    #[inline]
+    pub fn beta_prec_assign(&mut self, other: Self, prec: u64) -> Ordering {
+        self.beta_prec_round_assign(other, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] on the
+    /// right-hand side is taken by reference. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_assign_ref(&Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "0.0371");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_prec_assign_ref(&Float::from(E), 20), Greater);
+    /// assert_eq!(x.to_string(), "0.037890315");
+    /// ```
+    #[inline]
+    pub fn beta_prec_assign_ref(&mut self, other: &Self, prec: u64) -> Ordering {
+        self.beta_prec_round_assign_ref(other, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// rounding the result to the maximum of the precisions of the inputs and with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_round_assign(Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "0.037890298781212209");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_round_assign(Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.037890298781212216");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_round_assign(Float::from(E), Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.037890298781212209");
+    /// ```
+    #[inline]
+    pub fn beta_round_assign(&mut self, other: Self, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_assign(other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// rounding the result to the maximum of the precisions of the inputs and with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`]
+    /// is returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_round_assign_ref(&Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "0.037890298781212209");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_round_assign_ref(&Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.037890298781212216");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.beta_round_assign_ref(&Float::from(E), Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.037890298781212209");
+    /// ```
+    #[inline]
+    pub fn beta_round_assign_ref(&mut self, other: &Self, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_assign_ref(other, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode, and returning the result as a
+    /// [`Float`]. Both [`Rational`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |B(x,y)|\rfloor-p+1}$.
+    /// - If $B(x,y)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |B(x,y)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,y,p,m)=f(y,0,p,m)=\text{NaN}$ if $y$ is a negative integer
+    /// - $f(0,y,p,m)=f(y,0,p,m)=\infty$ if $y$ is not a negative integer
+    /// - $f(x,y,p,m)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer
+    ///   with $x\leq -y$; and symmetrically
+    /// - $f(x,y,p,m)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an
+    ///   integer
+    ///
+    /// The result overflows when $x$ or $y$ is extremely close to zero or, when the other argument
+    /// is a suitable positive integer, to a negative integer; it underflows when $x$ and $y$ are
+    /// both very large:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5, Floor);
+    /// assert_eq!(beta.to_string(), "2.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20, Floor);
+    /// assert_eq!(beta.to_string(), "2.0650291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_rational_prec_round(
+        x: Rational,
+        y: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::beta_rational_prec_round_ref_ref(&x, &y, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode, and returning the result as a
+    /// [`Float`]. The first [`Rational`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_rational_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_rational_prec_val_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5, Floor);
+    /// assert_eq!(beta.to_string(), "2.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20, Floor);
+    /// assert_eq!(beta.to_string(), "2.0650291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_rational_prec_round_val_ref(
+        x: Rational,
+        y: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::beta_rational_prec_round_ref_ref(&x, y, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode, and returning the result as a
+    /// [`Float`]. The first [`Rational`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_rational_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_rational_prec_ref_val`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5, Floor);
+    /// assert_eq!(beta.to_string(), "2.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20, Floor);
+    /// assert_eq!(beta.to_string(), "2.0650291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_rational_prec_round_ref_val(
+        x: &Rational,
+        y: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::beta_rational_prec_round_ref_ref(x, &y, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode, and returning the result as a
+    /// [`Float`]. Both [`Rational`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::beta_rational_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::beta_rational_prec_ref_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5, Floor);
+    /// assert_eq!(beta.to_string(), "2.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5, Nearest);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20, Floor);
+    /// assert_eq!(beta.to_string(), "2.0650291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20, Ceiling);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_round_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20, Nearest);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn beta_rational_prec_round_ref_ref(
+        x: &Rational,
+        y: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 || *y == 0u32 {
+            // a zero argument is treated as 0.0
+            let other = if *x == 0u32 { y } else { x };
+            return if *other < 0u32 && other.is_integer() {
+                (Self::NAN, Equal)
+            } else {
+                (Self::INFINITY, Equal)
+            };
+        }
+        beta_rational_helper(x, y, prec, rm)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// nearest value of the specified precision, and returning the result as a [`Float`]. Both
+    /// [`Rational`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_rational_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_rational_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec(Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_rational_prec(x: Rational, y: Rational, prec: u64) -> (Self, Ordering) {
+        Self::beta_rational_prec_round_ref_ref(&x, &y, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// nearest value of the specified precision, and returning the result as a [`Float`]. The first
+    /// [`Rational`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_rational_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_rational_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_val_ref(Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_rational_prec_val_ref(x: Rational, y: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::beta_rational_prec_round_ref_ref(&x, y, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// nearest value of the specified precision, and returning the result as a [`Float`]. The first
+    /// [`Rational`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_rational_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_rational_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 5);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_ref_val(&Rational::from_unsigneds(1u8, 3), Rational::from_unsigneds(5u8, 2), 20);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn beta_rational_prec_ref_val(x: &Rational, y: Rational, prec: u64) -> (Self, Ordering) {
+        Self::beta_rational_prec_round_ref_ref(x, &y, prec, Nearest)
+    }
+
+    /// Computes $B(x,y)$, the beta function of two [`Rational`]s, rounding the result to the
+    /// nearest value of the specified precision, and returning the result as a [`Float`]. Both
+    /// [`Rational`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::beta_rational_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_rational_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(x.significant_bits(), y.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 5);
+    /// assert_eq!(beta.to_string(), "2.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (beta, o) = Float::beta_rational_prec_ref_ref(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2), 20);
+    /// assert_eq!(beta.to_string(), "2.0650330");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn beta_rational_prec_ref_ref(x: &Rational, y: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::beta_rational_prec_round_ref_ref(x, y, prec, Nearest)
+    }
+}
+
+impl Beta<Self> for Float {
+    type Output = Self;
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, taking both by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |B(x,y)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},y)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,y)=f(y,\infty)=0.0$ if $y>0$
+    /// - $f(\infty,y)=f(y,\infty)=(-1)^{\lceil y\rceil}\infty$ if $y<0$ and $y$ is not an integer
+    /// - $f(\infty,y)=f(y,\infty)=\text{NaN}$ if $y$ is $\pm0.0$, $-\infty$, or a negative integer
+    /// - $f(-\infty,y)=f(y,-\infty)=(-1)^y0.0$ if $y$ is a positive integer
+    /// - $f(-\infty,y)=f(y,-\infty)=\text{NaN}$ if $y$ is finite and not a positive integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\pm\infty$ if $y$ is finite and nonzero, and not a negative
+    ///   integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\text{NaN}$ if $y$ is a negative integer
+    /// - $f(\pm0.0,\pm0.0)=\pm\infty$
+    /// - $f(\pm0.0,\mp0.0)=\text{NaN}$
+    /// - $f(x,y)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer with
+    ///   $x\leq -y$; and symmetrically
+    /// - $f(x,y)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an integer
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::beta_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::beta_round`].
+    /// If you want both of these things, consider using [`Float::beta_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Beta;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(PI).beta(Float::from(E)).to_string(), "0.037890298781212209");
+    /// assert!(Float::NAN.beta(Float::from(E)).is_nan());
+    /// assert!(Float::ZERO.beta(Float::NEGATIVE_ONE).is_nan());
+    /// ```
+    #[inline]
+    fn beta(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round(other, prec, Nearest).0
+    }
+}
+
+impl Beta<&Self> for Float {
+    type Output = Self;
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, taking the first by value and the
+    /// second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |B(x,y)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},y)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,y)=f(y,\infty)=0.0$ if $y>0$
+    /// - $f(\infty,y)=f(y,\infty)=(-1)^{\lceil y\rceil}\infty$ if $y<0$ and $y$ is not an integer
+    /// - $f(\infty,y)=f(y,\infty)=\text{NaN}$ if $y$ is $\pm0.0$, $-\infty$, or a negative integer
+    /// - $f(-\infty,y)=f(y,-\infty)=(-1)^y0.0$ if $y$ is a positive integer
+    /// - $f(-\infty,y)=f(y,-\infty)=\text{NaN}$ if $y$ is finite and not a positive integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\pm\infty$ if $y$ is finite and nonzero, and not a negative
+    ///   integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\text{NaN}$ if $y$ is a negative integer
+    /// - $f(\pm0.0,\pm0.0)=\pm\infty$
+    /// - $f(\pm0.0,\mp0.0)=\text{NaN}$
+    /// - $f(x,y)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer with
+    ///   $x\leq -y$; and symmetrically
+    /// - $f(x,y)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an integer
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_val_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::beta_round_val_ref`]. If you want both of these things, consider using
+    /// [`Float::beta_prec_round_val_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Beta;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(PI).beta(&Float::from(E)).to_string(), "0.037890298781212209");
+    /// assert!(Float::NAN.beta(&Float::from(E)).is_nan());
+    /// assert!(Float::ZERO.beta(&Float::NEGATIVE_ONE).is_nan());
+    /// ```
+    #[inline]
+    fn beta(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_val_ref(other, prec, Nearest).0
+    }
+}
+
+impl Beta<Float> for &Float {
+    type Output = Float;
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, taking the first by reference and
+    /// the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |B(x,y)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},y)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,y)=f(y,\infty)=0.0$ if $y>0$
+    /// - $f(\infty,y)=f(y,\infty)=(-1)^{\lceil y\rceil}\infty$ if $y<0$ and $y$ is not an integer
+    /// - $f(\infty,y)=f(y,\infty)=\text{NaN}$ if $y$ is $\pm0.0$, $-\infty$, or a negative integer
+    /// - $f(-\infty,y)=f(y,-\infty)=(-1)^y0.0$ if $y$ is a positive integer
+    /// - $f(-\infty,y)=f(y,-\infty)=\text{NaN}$ if $y$ is finite and not a positive integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\pm\infty$ if $y$ is finite and nonzero, and not a negative
+    ///   integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\text{NaN}$ if $y$ is a negative integer
+    /// - $f(\pm0.0,\pm0.0)=\pm\infty$
+    /// - $f(\pm0.0,\mp0.0)=\text{NaN}$
+    /// - $f(x,y)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer with
+    ///   $x\leq -y$; and symmetrically
+    /// - $f(x,y)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an integer
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_ref_val`] instead. If you want to specify the output precision, consider
+    /// using [`Float::beta_round_ref_val`]. If you want both of these things, consider using
+    /// [`Float::beta_prec_round_ref_val`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Beta;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::from(PI)).beta(Float::from(E)).to_string(), "0.037890298781212209");
+    /// assert!((&Float::NAN).beta(Float::from(E)).is_nan());
+    /// assert!((&Float::ZERO).beta(Float::NEGATIVE_ONE).is_nan());
+    /// ```
+    #[inline]
+    fn beta(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_ref_val(other, prec, Nearest).0
+    }
+}
+
+impl Beta<&Float> for &Float {
+    type Output = Float;
+
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, taking both by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |B(x,y)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},y)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,y)=f(y,\infty)=0.0$ if $y>0$
+    /// - $f(\infty,y)=f(y,\infty)=(-1)^{\lceil y\rceil}\infty$ if $y<0$ and $y$ is not an integer
+    /// - $f(\infty,y)=f(y,\infty)=\text{NaN}$ if $y$ is $\pm0.0$, $-\infty$, or a negative integer
+    /// - $f(-\infty,y)=f(y,-\infty)=(-1)^y0.0$ if $y$ is a positive integer
+    /// - $f(-\infty,y)=f(y,-\infty)=\text{NaN}$ if $y$ is finite and not a positive integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\pm\infty$ if $y$ is finite and nonzero, and not a negative
+    ///   integer
+    /// - $f(\pm0.0,y)=f(y,\pm0.0)=\text{NaN}$ if $y$ is a negative integer
+    /// - $f(\pm0.0,\pm0.0)=\pm\infty$
+    /// - $f(\pm0.0,\mp0.0)=\text{NaN}$
+    /// - $f(x,y)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer with
+    ///   $x\leq -y$; and symmetrically
+    /// - $f(x,y)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an integer
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_ref_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::beta_round_ref_ref`]. If you want both of these things, consider using
+    /// [`Float::beta_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Beta;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::from(PI)).beta(&Float::from(E)).to_string(), "0.037890298781212209");
+    /// assert!((&Float::NAN).beta(&Float::from(E)).is_nan());
+    /// assert!((&Float::ZERO).beta(&Float::NEGATIVE_ONE).is_nan());
+    /// ```
+    #[inline]
+    fn beta(self, other: &Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_ref_ref(other, prec, Nearest).0
+    }
+}
+
+impl BetaAssign<Self> for Float {
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// taking the [`Float`] on the right-hand side by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |B(x,y)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::beta_round_assign`]. If you want both of these things, consider using
+    /// [`Float::beta_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BetaAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.beta_assign(Float::from(E));
+    /// assert_eq!(x.to_string(), "0.037890298781212209");
+    /// ```
+    #[inline]
+    fn beta_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_assign(other, prec, Nearest);
+    }
+}
+
+impl BetaAssign<&Self> for Float {
+    /// Computes $B(x,y)$, the beta function of two [`Float`]s, mutating the first one in place, and
+    /// taking the [`Float`] on the right-hand side by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = B(x,y)+\varepsilon
+    /// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+    /// $$
+    /// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |B(x,y)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::beta_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::beta_prec_assign_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::beta_round_assign_ref`]. If you want both of these things, consider
+    /// using [`Float::beta_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::BetaAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.beta_assign(&Float::from(E));
+    /// assert_eq!(x.to_string(), "0.037890298781212209");
+    /// ```
+    #[inline]
+    fn beta_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.beta_prec_round_assign_ref(other, prec, Nearest);
+    }
+}
+
+/// Computes $B(x,y)$, the beta function of two primitive floats, returning a correctly-rounded
+/// result.
+///
+/// $$
+/// f(x,y) = B(x,y)+\varepsilon
+/// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+/// $$
+/// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |B(x,y)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN},y)=f(y,\text{NaN})=\text{NaN}$
+/// - $f(\infty,y)=f(y,\infty)=0.0$ if $y>0$
+/// - $f(\infty,y)=f(y,\infty)=(-1)^{\lceil y\rceil}\infty$ if $y<0$ and $y$ is not an integer
+/// - $f(\infty,y)=f(y,\infty)=\text{NaN}$ if $y$ is $\pm0.0$, $-\infty$, or a negative integer
+/// - $f(-\infty,y)=f(y,-\infty)=(-1)^y0.0$ if $y$ is a positive integer
+/// - $f(-\infty,y)=f(y,-\infty)=\text{NaN}$ if $y$ is finite and not a positive integer
+/// - $f(\pm0.0,y)=f(y,\pm0.0)=\pm\infty$ if $y$ is finite and nonzero, and not a negative integer
+/// - $f(\pm0.0,y)=f(y,\pm0.0)=\text{NaN}$ if $y$ is a negative integer
+/// - $f(\pm0.0,\pm0.0)=\pm\infty$
+/// - $f(\pm0.0,\mp0.0)=\text{NaN}$
+/// - $f(x,y)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer with $x\leq
+///   -y$; and symmetrically
+/// - $f(x,y)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an integer
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::{E, PI};
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::beta::primitive_float_beta;
+///
+/// assert!(primitive_float_beta(f64::NAN, 1.0).is_nan());
+/// assert!(primitive_float_beta(-1.0f64, 0.5).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_beta(PI, E)), NiceFloat(0.03789029878121221));
+/// assert_eq!(NiceFloat(primitive_float_beta(0.5f32, 0.5f32)), NiceFloat(3.1415927));
+/// assert_eq!(NiceFloat(primitive_float_beta(-0.5f64, -0.25f64)), NiceFloat(-3.594420704206777));
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+#[inline]
+pub fn primitive_float_beta<T: PrimitiveFloat>(x: T, y: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_float_to_float_fn(Float::beta_prec, x, y)
+}
+
+/// Computes $B(x,y)$, the beta function of two [`Rational`]s, returning the result as a primitive
+/// float.
+///
+/// $$
+/// f(x,y) = B(x,y)+\varepsilon
+/// =\frac{\Gamma(x)\Gamma(y)}{\Gamma(x+y)}+\varepsilon.
+/// $$
+/// - If $B(x,y)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $B(x,y)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |B(x,y)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0,y)=f(y,0)=\text{NaN}$ if $y$ is a negative integer
+/// - $f(0,y)=f(y,0)=\infty$ if $y$ is not a negative integer
+/// - $f(x,y)=\text{NaN}$ if $y$ is a negative integer, unless $x$ is a positive integer with $x\leq
+///   -y$; and symmetrically
+/// - $f(x,y)=0.0$ if $x+y$ is zero or a negative integer, but neither $x$ nor $y$ is an integer
+///
+/// # Worst-case complexity
+/// $T(m) = O(m)$
+///
+/// $M(m) = O(m)$
+///
+/// where $T$ is time, $M$ is additional memory, and $m$ is `max(x.significant_bits(),
+/// y.significant_bits())`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::beta::primitive_float_beta_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_beta_rational::<f64>(&Rational::from_unsigneds(1u8, 3), &Rational::from_unsigneds(5u8, 2))), NiceFloat(2.0650318278429416));
+/// assert_eq!(NiceFloat(primitive_float_beta_rational::<f64>(&Rational::from(3), &Rational::from_signeds(-7i8, 2))), NiceFloat(-0.1523809523809524));
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+#[inline]
+pub fn primitive_float_beta_rational<T: PrimitiveFloat>(x: &Rational, y: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_rational_to_float_fn(Float::beta_rational_prec_ref_ref, x, y)
+}
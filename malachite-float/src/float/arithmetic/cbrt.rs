@@ -31,10 +31,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[3\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[3\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[3\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[3\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[3\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[3\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[3\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[3\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[3\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -0,0 +1,1008 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::sin_cos::cos_rational_helper;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Cos, CosAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $\cos x$, the cosine of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded cosine is less than, equal to, or greater than
+    /// the exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The argument is in radians. Arguments of any size are reduced modulo $\pi/2$ using as many
+    /// bits of $\pi$ as are needed, so the result is correctly rounded even when $x$ is huge or
+    /// very close to a multiple of $\pi/2$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// Neither overflow nor underflow is possible.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round`] instead. If both of these things are true, consider using
+    /// [`Float::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round(5, Floor);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round(5, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round(5, Nearest);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round(20, Floor);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round(20, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.54030323");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round(20, Nearest);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded cosine is less than, equal to, or greater
+    /// than the exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round_ref(5, Floor);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.562");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round_ref(5, Nearest);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round_ref(20, Floor);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.54030323");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .cos_prec_round_ref(20, Nearest);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn cos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { .. } => (Self::NAN, Equal),
+            Zero { .. } => (Self::one_prec(prec), Equal),
+            Finite { .. } => cos_rational_helper(&Rational::exact_from(self), prec, rm),
+        }
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100).0.cos_prec(5);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100).0.cos_prec(20);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec(self, prec: u64) -> (Self, Ordering) {
+        self.cos_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100).0.cos_prec_ref(5);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from_unsigned_prec(1u32, 100).0.cos_prec_ref(20);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], rounding the result to the precision of the
+    /// input and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`]
+    /// is also returned, indicating whether the rounded cosine is less than, equal to, or greater
+    /// than the exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Floor);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.99999999999999911");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round(prec, rm)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], rounding the result to the precision of the
+    /// input and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded cosine is less than, equal to,
+    /// or greater than the exact cosine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Floor);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.99999999999999911");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded cosine is less than, equal to, or greater than the exact cosine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.cos_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.54030228");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.cos_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.54030323");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.cos_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.54030228");
+    /// ```
+    #[inline]
+    pub fn cos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.cos_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.cos_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "0.531");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.cos_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.54030228");
+    /// ```
+    #[inline]
+    pub fn cos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.cos_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Float`], in place, rounding the result to the precision
+    /// of the input and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded cosine is less than, equal to, or greater than the exact cosine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0000000000000000");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.99999999999999911");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.0000000000000000");
+    /// ```
+    #[inline]
+    pub fn cos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.94495773");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn cos_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::cos_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) =
+    ///     Float::cos_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(cos.to_string(), "0.94495773");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn cos_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::one_prec(prec), Equal);
+        }
+        cos_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Rational`], rounding the result to the nearest value of
+    /// the specified precision and returning the result as a [`Float`]. The [`Rational`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded cosine is less
+    /// than, equal to, or greater than the exact cosine.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::cos_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::cos_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::cos_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn cos_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::cos_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\cos x$, the cosine of a [`Rational`], rounding the result to the nearest value of
+    /// the specified precision and returning the result as a [`Float`]. The [`Rational`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded cosine is
+    /// less than, equal to, or greater than the exact cosine.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::cos_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::cos_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::cos_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::cos_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Cos for Float {
+    type Output = Self;
+
+    /// Computes $\cos x$, the cosine of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::cos_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::cos_round`].
+    /// If you want both of these things, consider using [`Float::cos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.cos().is_nan());
+    /// assert!(Float::INFINITY.cos().is_nan());
+    /// assert_eq!(Float::ZERO.cos(), 1.0);
+    /// assert_eq!(Float::from(PI).cos().to_string(), "-1.0000000000000000");
+    /// assert_eq!(
+    ///     Float::from_unsigned_prec(1u32, 100).0.cos().to_string(),
+    ///     "0.54030230586813971740093660744335"
+    /// );
+    /// ```
+    #[inline]
+    fn cos(self) -> Self {
+        let prec = self.significant_bits();
+        self.cos_prec_round(prec, Nearest).0
+    }
+}
+
+impl Cos for &Float {
+    type Output = Float;
+
+    /// Computes $\cos x$, the cosine of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::cos_round_ref`]. If you want both of these things, consider using
+    /// [`Float::cos_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).cos().is_nan());
+    /// assert!((&Float::INFINITY).cos().is_nan());
+    /// assert_eq!((&Float::ZERO).cos(), 1.0);
+    /// assert_eq!((&Float::from(PI)).cos().to_string(), "-1.0000000000000000");
+    /// assert_eq!(
+    ///     (&Float::from_unsigned_prec(1u32, 100).0).cos().to_string(),
+    ///     "0.54030230586813971740093660744335"
+    /// );
+    /// ```
+    #[inline]
+    fn cos(self) -> Float {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl CosAssign for Float {
+    /// Computes $\cos x$, the cosine of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::CosAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.cos_assign();
+    /// assert_eq!(x.to_string(), "-1.0000000000000000");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// x.cos_assign();
+    /// assert_eq!(x.to_string(), "0.54030230586813971740093660744335");
+    /// ```
+    #[inline]
+    fn cos_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\cos x$, the cosine of a primitive float, returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = \cos x+\varepsilon.
+/// $$
+/// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\cos x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+/// - $f(\pm0.0)=1.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::cos::primitive_float_cos;
+///
+/// assert!(primitive_float_cos(f64::NAN).is_nan());
+/// assert!(primitive_float_cos(f64::INFINITY).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_cos(0.0f64)), NiceFloat(1.0));
+/// assert_eq!(NiceFloat(primitive_float_cos(PI)), NiceFloat(-1.0));
+/// assert_eq!(NiceFloat(primitive_float_cos(1.0f32)), NiceFloat(0.5403023));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_cos<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::cos_prec, x)
+}
+
+/// Computes $\cos x$, the cosine of a [`Rational`], returning the result as a primitive float.
+///
+/// $$
+/// f(x) = \cos x+\varepsilon.
+/// $$
+/// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\cos x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\cos
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=1.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::cos::primitive_float_cos_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_cos_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.9449569463147377)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_cos_rational::<f64>(&Rational::from(10000))),
+///     NiceFloat(-0.9521553682590148)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_cos_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::cos_rational_prec_ref, x)
+}
@@ -0,0 +1,1028 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::sinh_cosh::{csch_rational_helper, hyperbolic_argument};
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Csch, CschAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, NegativeZero, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], rounding the
+    /// result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded hyperbolic
+    /// cosecant is less than, equal to, or greater than the exact hyperbolic cosecant. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{csch} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{csch} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{csch} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{csch}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{csch} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{csch}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=-0.0$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    ///
+    /// The result overflows when $|x|$ is less than about $2^{-2^{30}+1}$, which is only a few
+    /// times the smallest positive [`Float`], and underflows when $|x|$ is greater than about
+    /// $7.44\times 10^8$:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::csch_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::csch_round`] instead. If both of these things are true, consider using
+    /// [`Float::csch`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round(5, Floor);
+    /// assert_eq!(csch.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round(5, Ceiling);
+    /// assert_eq!(csch.to_string(), "0.875");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round(5, Nearest);
+    /// assert_eq!(csch.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round(20, Floor);
+    /// assert_eq!(csch.to_string(), "0.85091782");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round(20, Ceiling);
+    /// assert_eq!(csch.to_string(), "0.85091877");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round(20, Nearest);
+    /// assert_eq!(csch.to_string(), "0.85091782");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn csch_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.csch_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], rounding the
+    /// result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// hyperbolic cosecant is less than, equal to, or greater than the exact hyperbolic cosecant.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::csch_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::csch_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).csch()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round_ref(5, Floor);
+    /// assert_eq!(csch.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round_ref(5, Ceiling);
+    /// assert_eq!(csch.to_string(), "0.875");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round_ref(5, Nearest);
+    /// assert_eq!(csch.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round_ref(20, Floor);
+    /// assert_eq!(csch.to_string(), "0.85091782");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round_ref(20, Ceiling);
+    /// assert_eq!(csch.to_string(), "0.85091877");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_round_ref(20, Nearest);
+    /// assert_eq!(csch.to_string(), "0.85091782");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn csch_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { sign } => (
+                if *sign {
+                    Self::ZERO
+                } else {
+                    Self::NEGATIVE_ZERO
+                },
+                Equal,
+            ),
+            Zero { sign } => (
+                if *sign {
+                    Self::INFINITY
+                } else {
+                    Self::NEGATIVE_INFINITY
+                },
+                Equal,
+            ),
+            Finite { .. } => csch_rational_helper(&hyperbolic_argument(self), prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], rounding the
+    /// result to the nearest value of the specified precision. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic cosecant is less
+    /// than, equal to, or greater than the exact hyperbolic cosecant. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic cosecant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::csch_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::csch`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec(5);
+    /// assert_eq!(csch.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec(20);
+    /// assert_eq!(csch.to_string(), "0.85091782");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn csch_prec(self, prec: u64) -> (Self, Ordering) {
+        self.csch_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], rounding the
+    /// result to the nearest value of the specified precision. The [`Float`] is taken by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded hyperbolic cosecant is less
+    /// than, equal to, or greater than the exact hyperbolic cosecant. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic cosecant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::csch_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).csch()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_ref(5);
+    /// assert_eq!(csch.to_string(), "0.844");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from_unsigned_prec(1u32, 100).0.csch_prec_ref(20);
+    /// assert_eq!(csch.to_string(), "0.85091782");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn csch_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.csch_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], rounding the
+    /// result to the precision of the input and with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded hyperbolic
+    /// cosecant is less than, equal to, or greater than the exact hyperbolic cosecant. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::csch_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::csch`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::from(PI).csch_round(Floor);
+    /// assert_eq!(csch.to_string(), "0.086589537530046945");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from(PI).csch_round(Ceiling);
+    /// assert_eq!(csch.to_string(), "0.086589537530047056");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::from(PI).csch_round(Nearest);
+    /// assert_eq!(csch.to_string(), "0.086589537530046945");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn csch_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.csch_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], rounding the
+    /// result to the precision of the input and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// hyperbolic cosecant is less than, equal to, or greater than the exact hyperbolic cosecant.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::csch_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).csch()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::from(PI).csch_round_ref(Floor);
+    /// assert_eq!(csch.to_string(), "0.086589537530046945");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::from(PI).csch_round_ref(Ceiling);
+    /// assert_eq!(csch.to_string(), "0.086589537530047056");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::from(PI).csch_round_ref(Nearest);
+    /// assert_eq!(csch.to_string(), "0.086589537530046945");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn csch_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.csch_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], in place, rounding
+    /// the result to the specified precision and with the specified rounding mode. An [`Ordering`]
+    /// is returned, indicating whether the rounded hyperbolic cosecant is less than, equal to, or
+    /// greater than the exact hyperbolic cosecant. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::csch_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::csch_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::csch_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.csch_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.85091782");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.csch_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.85091877");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.csch_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.85091782");
+    /// ```
+    #[inline]
+    pub fn csch_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.csch_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], in place, rounding
+    /// the result to the nearest value of the specified precision. An [`Ordering`] is returned,
+    /// indicating whether the rounded hyperbolic cosecant is less than, equal to, or greater than
+    /// the exact hyperbolic cosecant. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic cosecant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::csch_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::csch_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.csch_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "0.844");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.csch_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.85091782");
+    /// ```
+    #[inline]
+    pub fn csch_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.csch_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], in place, rounding
+    /// the result to the precision of the input and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded hyperbolic cosecant is less than,
+    /// equal to, or greater than the exact hyperbolic cosecant. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::csch_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::csch_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.csch_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.086589537530046945");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.csch_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.086589537530047056");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.csch_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.086589537530046945");
+    /// ```
+    #[inline]
+    pub fn csch_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.csch_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Rational`], rounding the
+    /// result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic cosecant is less than, equal to, or greater than
+    /// the exact hyperbolic cosecant.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{csch} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{csch} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{csch}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{csch} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{csch}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::csch_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(csch.to_string(), "2.88");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(csch.to_string(), "3.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(csch.to_string(), "2.9451561");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(csch.to_string(), "2.9451599");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn csch_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::csch_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Rational`], rounding the
+    /// result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded hyperbolic cosecant is less than, equal to, or
+    /// greater than the exact hyperbolic cosecant.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{csch} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{csch} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{csch}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{csch} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{csch}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::csch_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(csch.to_string(), "2.88");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(csch.to_string(), "3.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(csch.to_string(), "2.9451561");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(csch.to_string(), "2.9451599");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn csch_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::INFINITY, Equal);
+        }
+        csch_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Rational`], rounding the
+    /// result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic cosecant is less than, equal to, or greater than the exact
+    /// hyperbolic cosecant.
+    ///
+    /// If the hyperbolic cosecant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::csch_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::csch_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(csch.to_string(), "3.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(csch.to_string(), "2.9451561");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn csch_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::csch_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Rational`], rounding the
+    /// result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic cosecant is less than, equal to, or greater than
+    /// the exact hyperbolic cosecant.
+    ///
+    /// If the hyperbolic cosecant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::csch_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(csch.to_string(), "3.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (csch, o) = Float::csch_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(csch.to_string(), "2.9451561");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn csch_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::csch_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Csch for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], taking it by
+    /// value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic cosecant
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{csch} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{csch} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{csch} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{csch} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=-0.0$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=-\infty$
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::csch_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::csch_round`].
+    /// If you want both of these things, consider using [`Float::csch_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Csch;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.csch().is_nan());
+    /// assert_eq!(Float::INFINITY.csch(), 0.0);
+    /// assert_eq!(Float::ZERO.csch(), Float::INFINITY);
+    /// assert_eq!(Float::from(PI).csch().to_string(), "0.086589537530046945");
+    /// assert_eq!(Float::from_unsigned_prec(1u32, 100).0.csch().to_string(), "0.85091812823932154513384276328721");
+    /// ```
+    #[inline]
+    fn csch(self) -> Self {
+        let prec = self.significant_bits();
+        self.csch_prec_round(prec, Nearest).0
+    }
+}
+
+impl Csch for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], taking it by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic cosecant
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{csch} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{csch} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{csch} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{csch} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=-0.0$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=-\infty$
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::csch_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::csch_round_ref`]. If you want both of these things, consider using
+    /// [`Float::csch_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Csch;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).csch().is_nan());
+    /// assert_eq!((&Float::INFINITY).csch(), 0.0);
+    /// assert_eq!((&Float::ZERO).csch(), Float::INFINITY);
+    /// assert_eq!((&Float::from(PI)).csch().to_string(), "0.086589537530046945");
+    /// assert_eq!((&Float::from_unsigned_prec(1u32, 100).0).csch().to_string(), "0.85091812823932154513384276328721");
+    /// ```
+    #[inline]
+    fn csch(self) -> Float {
+        let prec = self.significant_bits();
+        self.csch_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl CschAssign for Float {
+    /// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic cosecant
+    /// is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{csch} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{csch} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{csch} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 |\operatorname{csch} x|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// See the [`Float::csch_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CschAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.csch_assign();
+    /// assert_eq!(x.to_string(), "0.086589537530046945");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// x.csch_assign();
+    /// assert_eq!(x.to_string(), "0.85091812823932154513384276328721");
+    /// ```
+    #[inline]
+    fn csch_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.csch_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a primitive float, returning a
+/// correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{csch} x+\varepsilon.
+/// $$
+/// - If $\operatorname{csch} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{csch} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{csch} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\infty)=0.0$
+/// - $f(-\infty)=-0.0$
+/// - $f(0.0)=\infty$
+/// - $f(-0.0)=-\infty$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::csch::primitive_float_csch;
+///
+/// assert!(primitive_float_csch(f64::NAN).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_csch(f64::NEGATIVE_INFINITY)), NiceFloat(-0.0));
+/// assert_eq!(NiceFloat(primitive_float_csch(-0.0f64)), NiceFloat(f64::NEGATIVE_INFINITY));
+/// assert_eq!(NiceFloat(primitive_float_csch(PI)), NiceFloat(0.08658953753004696));
+/// assert_eq!(NiceFloat(primitive_float_csch(1.0f32)), NiceFloat(0.8509181));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_csch<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::csch_prec, x)
+}
+
+/// Computes $\operatorname{csch} x$, the hyperbolic cosecant of a [`Rational`], returning the
+/// result as a primitive float.
+///
+/// $$
+/// f(x) = \operatorname{csch} x+\varepsilon.
+/// $$
+/// - If $\operatorname{csch} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{csch} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{csch} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=\infty$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::csch::primitive_float_csch_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_csch_rational::<f64>(&Rational::from_unsigneds(1u8, 3))), NiceFloat(2.9451562666948146));
+/// assert_eq!(NiceFloat(primitive_float_csch_rational::<f64>(&Rational::from(10000))), NiceFloat(0.0));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_csch_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::csch_rational_prec_ref, x)
+}
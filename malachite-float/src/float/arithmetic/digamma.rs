@@ -0,0 +1,1010 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::gamma::{Argument, digamma_helper};
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Digamma, DigammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded digamma function is less
+    /// than, equal to, or greater than the exact digamma function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \psi(x)+\varepsilon.
+    /// $$
+    /// - If $\psi(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\psi(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\psi(x)|\rfloor-p+1}$.
+    /// - If $\psi(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\psi(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(-\infty,p,m)=\text{NaN}$
+    /// - $f(x,p,m)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=\infty$
+    ///
+    /// The result overflows when $|x|$ is less than about $2^{-2^{30}+1}$, which is only a few
+    /// times the smallest positive [`Float`]:
+    ///
+    /// Overflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::digamma_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::digamma_round`] instead. If both of these things are true, consider using
+    /// [`Float::digamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round(5, Floor);
+    /// assert_eq!(digamma.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round(5, Ceiling);
+    /// assert_eq!(digamma.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round(5, Nearest);
+    /// assert_eq!(digamma.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round(20, Floor);
+    /// assert_eq!(digamma.to_string(), "0.97721291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round(20, Ceiling);
+    /// assert_eq!(digamma.to_string(), "0.97721386");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round(20, Nearest);
+    /// assert_eq!(digamma.to_string(), "0.97721291");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn digamma_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.digamma_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded digamma function
+    /// is less than, equal to, or greater than the exact digamma function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::digamma_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::digamma_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).digamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round_ref(5, Floor);
+    /// assert_eq!(digamma.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round_ref(5, Ceiling);
+    /// assert_eq!(digamma.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round_ref(5, Nearest);
+    /// assert_eq!(digamma.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round_ref(20, Floor);
+    /// assert_eq!(digamma.to_string(), "0.97721291");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round_ref(20, Ceiling);
+    /// assert_eq!(digamma.to_string(), "0.97721386");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_round_ref(20, Nearest);
+    /// assert_eq!(digamma.to_string(), "0.97721291");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn digamma_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { sign: false } => (Self::NAN, Equal),
+            Infinity { sign: true } => (Self::INFINITY, Equal),
+            Zero { sign } => (
+                if *sign {
+                    Self::NEGATIVE_INFINITY
+                } else {
+                    Self::INFINITY
+                },
+                Equal,
+            ),
+            Finite { .. } => digamma_helper(&Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded digamma function is less than, equal to, or greater
+    /// than the exact digamma function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the digamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::digamma_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::digamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec(5);
+    /// assert_eq!(digamma.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec(20);
+    /// assert_eq!(digamma.to_string(), "0.97721291");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn digamma_prec(self, prec: u64) -> (Self, Ordering) {
+        self.digamma_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded digamma function is less than, equal to, or
+    /// greater than the exact digamma function. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the digamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::digamma_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).digamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_ref(5);
+    /// assert_eq!(digamma.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(PI).digamma_prec_ref(20);
+    /// assert_eq!(digamma.to_string(), "0.97721291");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn digamma_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.digamma_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], rounding the result to the
+    /// precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded digamma function is
+    /// less than, equal to, or greater than the exact digamma function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::digamma_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::digamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::from(-PI).digamma_round(Floor);
+    /// assert_eq!(digamma.to_string(), "7.8859523853854938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(-PI).digamma_round(Ceiling);
+    /// assert_eq!(digamma.to_string(), "7.8859523853855009");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::from(-PI).digamma_round(Nearest);
+    /// assert_eq!(digamma.to_string(), "7.8859523853854938");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn digamma_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.digamma_prec_round(prec, rm)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], rounding the result to the
+    /// precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded digamma function
+    /// is less than, equal to, or greater than the exact digamma function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::digamma_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).digamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::from(-PI).digamma_round_ref(Floor);
+    /// assert_eq!(digamma.to_string(), "7.8859523853854938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::from(-PI).digamma_round_ref(Ceiling);
+    /// assert_eq!(digamma.to_string(), "7.8859523853855009");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::from(-PI).digamma_round_ref(Nearest);
+    /// assert_eq!(digamma.to_string(), "7.8859523853854938");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn digamma_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.digamma_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], in place, rounding the result to
+    /// the specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded digamma function is less than, equal to, or greater than the
+    /// exact digamma function. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::digamma_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::digamma_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::digamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.digamma_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.97721291");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.digamma_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.97721386");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.digamma_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.97721291");
+    /// ```
+    #[inline]
+    pub fn digamma_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.digamma_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], in place, rounding the result to
+    /// the nearest value of the specified precision. An [`Ordering`] is returned, indicating
+    /// whether the rounded digamma function is less than, equal to, or greater than the exact
+    /// digamma function. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the digamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::digamma_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::digamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.digamma_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "0.969");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.digamma_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.97721291");
+    /// ```
+    #[inline]
+    pub fn digamma_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.digamma_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], in place, rounding the result to
+    /// the precision of the input and with the specified rounding mode. An [`Ordering`] is
+    /// returned, indicating whether the rounded digamma function is less than, equal to, or greater
+    /// than the exact digamma function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::digamma_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::digamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.digamma_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "7.8859523853854938");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.digamma_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "7.8859523853855009");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.digamma_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "7.8859523853854938");
+    /// ```
+    #[inline]
+    pub fn digamma_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.digamma_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Rational`], rounding the result to the
+    /// specified precision and with the specified rounding mode and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded digamma function is less than, equal to, or greater than the exact
+    /// digamma function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \psi(x)+\varepsilon.
+    /// $$
+    /// - If $\psi(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\psi(x)|\rfloor-p+1}$.
+    /// - If $\psi(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\psi(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(0,p,m)=-\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::digamma_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(digamma.to_string(), "-3.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(digamma.to_string(), "-3.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(digamma.to_string(), "-3.1320343");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(digamma.to_string(), "-3.1320305");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn digamma_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::digamma_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Rational`], rounding the result to the
+    /// specified precision and with the specified rounding mode and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded digamma function is less than, equal to, or greater than the
+    /// exact digamma function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \psi(x)+\varepsilon.
+    /// $$
+    /// - If $\psi(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\psi(x)|\rfloor-p+1}$.
+    /// - If $\psi(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\psi(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(0,p,m)=-\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::digamma_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(digamma.to_string(), "-3.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(digamma.to_string(), "-3.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(digamma.to_string(), "-3.1320343");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(digamma.to_string(), "-3.1320305");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn digamma_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::NEGATIVE_INFINITY, Equal);
+        }
+        digamma_helper(&Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Rational`], rounding the result to the
+    /// nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded digamma function is less than, equal to, or greater than the exact digamma function.
+    ///
+    /// If the digamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::digamma_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(digamma.to_string(), "-3.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(digamma.to_string(), "-3.1320343");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn digamma_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::digamma_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\psi(x)$, the digamma function of a [`Rational`], rounding the result to the
+    /// nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded digamma function is less than, equal to, or greater than the exact digamma function.
+    ///
+    /// If the digamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::digamma_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(digamma.to_string(), "-3.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (digamma, o) = Float::digamma_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(digamma.to_string(), "-3.1320343");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn digamma_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::digamma_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Digamma for Float {
+    type Output = Self;
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the digamma function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \psi(x)+\varepsilon.
+    /// $$
+    /// - If $\psi(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\psi(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\psi(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=-\infty$
+    /// - $f(-0.0)=\infty$
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::digamma_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::digamma_round`]. If you want both of these things, consider using
+    /// [`Float::digamma_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Digamma;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.digamma().is_nan());
+    /// assert_eq!(Float::INFINITY.digamma(), Float::INFINITY);
+    /// assert_eq!(Float::ZERO.digamma(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::from(PI).digamma().to_string(), "0.97721330794200689");
+    /// assert_eq!(Float::from(-PI).digamma().to_string(), "7.8859523853854938");
+    /// ```
+    #[inline]
+    fn digamma(self) -> Self {
+        let prec = self.significant_bits();
+        self.digamma_prec_round(prec, Nearest).0
+    }
+}
+
+impl Digamma for &Float {
+    type Output = Float;
+
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the digamma function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \psi(x)+\varepsilon.
+    /// $$
+    /// - If $\psi(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\psi(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\psi(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=-\infty$
+    /// - $f(-0.0)=\infty$
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::digamma_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::digamma_round_ref`]. If you want both of these things, consider using
+    /// [`Float::digamma_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Digamma;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).digamma().is_nan());
+    /// assert_eq!((&Float::INFINITY).digamma(), Float::INFINITY);
+    /// assert_eq!((&Float::ZERO).digamma(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!((&Float::from(PI)).digamma().to_string(), "0.97721330794200689");
+    /// assert_eq!((&Float::from(-PI)).digamma().to_string(), "7.8859523853854938");
+    /// ```
+    #[inline]
+    fn digamma(self) -> Float {
+        let prec = self.significant_bits();
+        self.digamma_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl DigammaAssign for Float {
+    /// Computes $\psi(x)$, the digamma function of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the digamma function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \psi(x)+\varepsilon.
+    /// $$
+    /// - If $\psi(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\psi(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\psi(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::digamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DigammaAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.digamma_assign();
+    /// assert_eq!(x.to_string(), "0.97721330794200689");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// x.digamma_assign();
+    /// assert_eq!(x.to_string(), "7.8859523853854938");
+    /// ```
+    #[inline]
+    fn digamma_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.digamma_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\psi(x)$, the digamma function of a primitive float, returning a correctly-rounded
+/// result.
+///
+/// $$
+/// f(x) = \psi(x)+\varepsilon.
+/// $$
+/// - If $\psi(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\psi(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\psi(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+/// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+/// - $f(\infty)=\infty$
+/// - $f(0.0)=-\infty$
+/// - $f(-0.0)=\infty$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::digamma::primitive_float_digamma;
+///
+/// assert!(primitive_float_digamma(f64::NAN).is_nan());
+/// assert!(primitive_float_digamma(-1.0f64).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_digamma(-0.0f64)), NiceFloat(f64::INFINITY));
+/// assert_eq!(NiceFloat(primitive_float_digamma(PI)), NiceFloat(0.9772133079420067));
+/// assert_eq!(NiceFloat(primitive_float_digamma(-1.5f32)), NiceFloat(0.70315665));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_digamma<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::digamma_prec, x)
+}
+
+/// Computes $\psi(x)$, the digamma function of a [`Rational`], returning the result as a primitive
+/// float.
+///
+/// $$
+/// f(x) = \psi(x)+\varepsilon.
+/// $$
+/// - If $\psi(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\psi(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\psi(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+/// - $f(0)=-\infty$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::digamma::primitive_float_digamma_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_digamma_rational::<f64>(&Rational::from_unsigneds(1u8, 3))), NiceFloat(-3.1320337800208065));
+/// assert_eq!(NiceFloat(primitive_float_digamma_rational::<f64>(&Rational::from(100))), NiceFloat(4.600161852738087));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_digamma_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::digamma_rational_prec_ref, x)
+}
@@ -1066,10 +1066,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1094,27 +1094,36 @@ impl Float {
     /// - $f(x,-\infty,p,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_prec`] instead. If you
     /// know that your target precision is the maximum of the precisions of the two inputs, consider
@@ -1181,10 +1190,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1209,27 +1218,36 @@ impl Float {
     /// - $f(x,-\infty,p,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_val_ref`] instead.
     /// If you know that your target precision is the maximum of the precisions of the two inputs,
@@ -1301,10 +1319,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1329,27 +1347,36 @@ impl Float {
     /// - $f(x,-\infty,p,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_ref_val`] instead.
     /// If you know that your target precision is the maximum of the precisions of the two inputs,
@@ -1459,17 +1486,23 @@ impl Float {
                 if exp_diff > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                            (float_infinity!(), Greater)
+                        }
                         (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-                        (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
+                            (float_negative_infinity!(), Less)
+                        }
                         (false, _) => (-Self::max_finite_value_with_prec(prec), Greater),
                     };
                 } else if exp_diff + 2 < Self::MIN_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up) => (Self::min_positive_value_prec(prec), Greater),
+                        (true, Ceiling | Up | Odd) => {
+                            (Self::min_positive_value_prec(prec), Greater)
+                        }
                         (true, _) => (float_zero!(), Less),
-                        (false, Floor | Up) => (-Self::min_positive_value_prec(prec), Less),
+                        (false, Floor | Up | Odd) => (-Self::min_positive_value_prec(prec), Less),
                         (false, _) => (float_negative_zero!(), Greater),
                     };
                 }
@@ -1485,15 +1518,21 @@ impl Float {
                 if exp > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                            (float_infinity!(), Greater)
+                        }
                         (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-                        (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
+                            (float_negative_infinity!(), Less)
+                        }
                         (false, _) => (-Self::max_finite_value_with_prec(prec), Greater),
                     };
                 } else if exp < Self::MIN_EXPONENT {
-                    return if rm == Nearest
+                    return if (rm == Nearest || rm == NearestTiesAway)
                         && exp == Self::MIN_EXPONENT_MINUS_1
-                        && (o == Less || !quotient.is_power_of_2())
+                        && (o == Less
+                            || rm == NearestTiesAway && o == Equal
+                            || !quotient.is_power_of_2())
                     {
                         if sign {
                             (Self::min_positive_value_prec(prec), Greater)
@@ -1503,9 +1542,13 @@ impl Float {
                     } else {
                         match (sign, rm) {
                             (_, Exact) => panic!("Inexact Float division"),
-                            (true, Ceiling | Up) => (Self::min_positive_value_prec(prec), Greater),
+                            (true, Ceiling | Up | Odd) => {
+                                (Self::min_positive_value_prec(prec), Greater)
+                            }
                             (true, _) => (float_zero!(), Less),
-                            (false, Floor | Up) => (-Self::min_positive_value_prec(prec), Less),
+                            (false, Floor | Up | Odd) => {
+                                (-Self::min_positive_value_prec(prec), Less)
+                            }
                             (false, _) => (float_negative_zero!(), Greater),
                         }
                     };
@@ -1535,10 +1578,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1563,27 +1606,36 @@ impl Float {
     /// - $f(x,-\infty,p,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_ref_ref`] instead.
     /// If you know that your target precision is the maximum of the precisions of the two inputs,
@@ -1693,17 +1745,23 @@ impl Float {
                 if exp_diff > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                            (float_infinity!(), Greater)
+                        }
                         (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-                        (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
+                            (float_negative_infinity!(), Less)
+                        }
                         (false, _) => (-Self::max_finite_value_with_prec(prec), Greater),
                     };
                 } else if exp_diff + 2 < Self::MIN_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up) => (Self::min_positive_value_prec(prec), Greater),
+                        (true, Ceiling | Up | Odd) => {
+                            (Self::min_positive_value_prec(prec), Greater)
+                        }
                         (true, _) => (float_zero!(), Less),
-                        (false, Floor | Up) => (-Self::min_positive_value_prec(prec), Less),
+                        (false, Floor | Up | Odd) => (-Self::min_positive_value_prec(prec), Less),
                         (false, _) => (float_negative_zero!(), Greater),
                     };
                 }
@@ -1719,15 +1777,21 @@ impl Float {
                 if exp > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => (float_infinity!(), Greater),
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
+                            (float_infinity!(), Greater)
+                        }
                         (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-                        (false, Floor | Up | Nearest) => (float_negative_infinity!(), Less),
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
+                            (float_negative_infinity!(), Less)
+                        }
                         (false, _) => (-Self::max_finite_value_with_prec(prec), Greater),
                     };
                 } else if exp < Self::MIN_EXPONENT {
-                    return if rm == Nearest
+                    return if (rm == Nearest || rm == NearestTiesAway)
                         && exp == Self::MIN_EXPONENT_MINUS_1
-                        && (o == Less || !quotient.is_power_of_2())
+                        && (o == Less
+                            || rm == NearestTiesAway && o == Equal
+                            || !quotient.is_power_of_2())
                     {
                         if sign {
                             (Self::min_positive_value_prec(prec), Greater)
@@ -1737,9 +1801,13 @@ impl Float {
                     } else {
                         match (sign, rm) {
                             (_, Exact) => panic!("Inexact Float division"),
-                            (true, Ceiling | Up) => (Self::min_positive_value_prec(prec), Greater),
+                            (true, Ceiling | Up | Odd) => {
+                                (Self::min_positive_value_prec(prec), Greater)
+                            }
                             (true, _) => (float_zero!(), Less),
-                            (false, Floor | Up) => (-Self::min_positive_value_prec(prec), Less),
+                            (false, Floor | Up | Odd) => {
+                                (-Self::min_positive_value_prec(prec), Less)
+                            }
                             (false, _) => (float_negative_zero!(), Greater),
                         }
                     };
@@ -2078,10 +2146,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2106,26 +2176,34 @@ impl Float {
     /// - $f(x,-\infty,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::div_prec_round`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
@@ -2181,10 +2259,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2209,26 +2289,34 @@ impl Float {
     /// - $f(x,-\infty,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::div_prec_round_val_ref`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
@@ -2284,10 +2372,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2312,26 +2402,34 @@ impl Float {
     /// - $f(x,-\infty,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::div_prec_round_ref_val`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
@@ -2387,10 +2485,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -2415,26 +2515,34 @@ impl Float {
     /// - $f(x,-\infty,m)=0.0$ if $x$ is not NaN or $\pm\infty$, and if $x=-0.0$ or $x<0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using [`Float::div_prec_round_ref_ref`]
     /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
@@ -2489,10 +2597,10 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2627,7 +2735,7 @@ impl Float {
                 if exp_diff > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => {
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                             *self = float_infinity!();
                             Greater
                         }
@@ -2635,7 +2743,7 @@ impl Float {
                             *self = Self::max_finite_value_with_prec(prec);
                             Less
                         }
-                        (false, Floor | Up | Nearest) => {
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
                             *self = float_negative_infinity!();
                             Less
                         }
@@ -2647,7 +2755,7 @@ impl Float {
                 } else if exp_diff + 2 < Self::MIN_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up) => {
+                        (true, Ceiling | Up | Odd) => {
                             *self = Self::min_positive_value_prec(prec);
                             Greater
                         }
@@ -2655,7 +2763,7 @@ impl Float {
                             *self = float_zero!();
                             Less
                         }
-                        (false, Floor | Up) => {
+                        (false, Floor | Up | Odd) => {
                             *self = -Self::min_positive_value_prec(prec);
                             Less
                         }
@@ -2677,7 +2785,7 @@ impl Float {
                 if *x_exp > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => {
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                             *self = float_infinity!();
                             Greater
                         }
@@ -2685,7 +2793,7 @@ impl Float {
                             *self = Self::max_finite_value_with_prec(prec);
                             Less
                         }
-                        (false, Floor | Up | Nearest) => {
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
                             *self = float_negative_infinity!();
                             Less
                         }
@@ -2695,9 +2803,9 @@ impl Float {
                         }
                     };
                 } else if *x_exp < Self::MIN_EXPONENT {
-                    return if rm == Nearest
+                    return if (rm == Nearest || rm == NearestTiesAway)
                         && *x_exp == Self::MIN_EXPONENT_MINUS_1
-                        && (o == Less || !x.is_power_of_2())
+                        && (o == Less || rm == NearestTiesAway && o == Equal || !x.is_power_of_2())
                     {
                         if sign {
                             *self = Self::min_positive_value_prec(prec);
@@ -2709,7 +2817,7 @@ impl Float {
                     } else {
                         match (sign, rm) {
                             (_, Exact) => panic!("Inexact Float division"),
-                            (true, Ceiling | Up) => {
+                            (true, Ceiling | Up | Odd) => {
                                 *self = Self::min_positive_value_prec(prec);
                                 Greater
                             }
@@ -2717,7 +2825,7 @@ impl Float {
                                 *self = float_zero!();
                                 Less
                             }
-                            (false, Floor | Up) => {
+                            (false, Floor | Up | Odd) => {
                                 *self = -Self::min_positive_value_prec(prec);
                                 Less
                             }
@@ -2747,10 +2855,10 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -2890,7 +2998,7 @@ impl Float {
                 if exp_diff > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => {
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                             *self = float_infinity!();
                             Greater
                         }
@@ -2898,7 +3006,7 @@ impl Float {
                             *self = Self::max_finite_value_with_prec(prec);
                             Less
                         }
-                        (false, Floor | Up | Nearest) => {
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
                             *self = float_negative_infinity!();
                             Less
                         }
@@ -2910,7 +3018,7 @@ impl Float {
                 } else if exp_diff + 2 < Self::MIN_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up) => {
+                        (true, Ceiling | Up | Odd) => {
                             *self = Self::min_positive_value_prec(prec);
                             Greater
                         }
@@ -2918,7 +3026,7 @@ impl Float {
                             *self = float_zero!();
                             Less
                         }
-                        (false, Floor | Up) => {
+                        (false, Floor | Up | Odd) => {
                             *self = -Self::min_positive_value_prec(prec);
                             Less
                         }
@@ -2940,7 +3048,7 @@ impl Float {
                 if *x_exp > Self::MAX_EXPONENT {
                     return match (sign, rm) {
                         (_, Exact) => panic!("Inexact Float division"),
-                        (true, Ceiling | Up | Nearest) => {
+                        (true, Ceiling | Up | Nearest | NearestTiesAway) => {
                             *self = float_infinity!();
                             Greater
                         }
@@ -2948,7 +3056,7 @@ impl Float {
                             *self = Self::max_finite_value_with_prec(prec);
                             Less
                         }
-                        (false, Floor | Up | Nearest) => {
+                        (false, Floor | Up | Nearest | NearestTiesAway) => {
                             *self = float_negative_infinity!();
                             Less
                         }
@@ -2958,9 +3066,9 @@ impl Float {
                         }
                     };
                 } else if *x_exp < Self::MIN_EXPONENT {
-                    return if rm == Nearest
+                    return if (rm == Nearest || rm == NearestTiesAway)
                         && *x_exp == Self::MIN_EXPONENT_MINUS_1
-                        && (o == Less || !x.is_power_of_2())
+                        && (o == Less || rm == NearestTiesAway && o == Equal || !x.is_power_of_2())
                     {
                         if sign {
                             *self = Self::min_positive_value_prec(prec);
@@ -2972,7 +3080,7 @@ impl Float {
                     } else {
                         match (sign, rm) {
                             (_, Exact) => panic!("Inexact Float division"),
-                            (true, Ceiling | Up) => {
+                            (true, Ceiling | Up | Odd) => {
                                 *self = Self::min_positive_value_prec(prec);
                                 Greater
                             }
@@ -2980,7 +3088,7 @@ impl Float {
                                 *self = float_zero!();
                                 Less
                             }
-                            (false, Floor | Up) => {
+                            (false, Floor | Up | Odd) => {
                                 *self = -Self::min_positive_value_prec(prec);
                                 Less
                             }
@@ -3115,10 +3223,12 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3179,10 +3289,12 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision
+    ///   of the inputs.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision
+    ///   of the inputs.
     ///
     /// If the output has a precision, it is the maximum of the precisions of the inputs.
     ///
@@ -3242,10 +3354,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -3261,27 +3373,36 @@ impl Float {
     /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec`] instead.
     /// If you know that your target precision is the precision of the [`Float`] input, consider
@@ -3360,10 +3481,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -3379,27 +3500,36 @@ impl Float {
     /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec_val_ref`]
     /// instead. If you know that your target precision is the precision of the [`Float`] input,
@@ -3496,10 +3626,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -3515,27 +3645,36 @@ impl Float {
     /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec_ref_val`]
     /// instead. If you know that your target precision is the precision of the [`Float`] input,
@@ -3637,10 +3776,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -3656,27 +3795,36 @@ impl Float {
     /// - $f(-0.0,x,p,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::div_rational_prec_ref_ref`]
     /// instead. If you know that your target precision is the precision of the [`Float`] input,
@@ -4064,10 +4212,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -4083,26 +4233,34 @@ impl Float {
     /// - $f(-0.0,x,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::div_rational_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -4162,10 +4320,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -4181,26 +4341,34 @@ impl Float {
     /// - $f(-0.0,x,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::div_rational_prec_round_val_ref`] instead. If you know you'll be using the
@@ -4264,10 +4432,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -4283,26 +4453,34 @@ impl Float {
     /// - $f(-0.0,x,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::div_rational_prec_round_ref_val`] instead. If you know you'll be using the
@@ -4366,10 +4544,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -4385,26 +4565,34 @@ impl Float {
     /// - $f(-0.0,x,m)=0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::div_rational_prec_round_ref_ref`] instead. If you know you'll be using the
@@ -4467,10 +4655,10 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -4571,10 +4759,10 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -4797,10 +4985,12 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the input [`Float`].
     ///
@@ -4871,10 +5061,12 @@ impl Float {
     /// x \gets x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the input [`Float`].
     ///
@@ -4948,10 +5140,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -4965,27 +5157,36 @@ impl Float {
     /// - $f(0,x,p,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::rational_div_float_prec`]
     /// instead. If you know that your target precision is the precision of the [`Float`] input,
@@ -5067,10 +5268,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5084,27 +5285,36 @@ impl Float {
     /// - $f(0,x,p,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using
     /// [`Float::rational_div_float_prec_val_ref`] instead. If you know that your target precision
@@ -5211,10 +5421,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5228,27 +5438,36 @@ impl Float {
     /// - $f(0,x,p,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using
     /// [`Float::rational_div_float_prec_ref_val`] instead. If you know that your target precision
@@ -5355,10 +5574,10 @@ impl Float {
     /// f(x,y,p,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -5372,27 +5591,36 @@ impl Float {
     /// - $f(0,x,p,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
     /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,y,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using
     /// [`Float::rational_div_float_prec_ref_ref`] instead. If you know that your target precision
@@ -5774,10 +6002,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -5791,26 +6021,34 @@ impl Float {
     /// - $f(0,x,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::rational_div_float_prec_round`] instead. If you know you'll be using the `Nearest`
@@ -5870,10 +6108,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -5887,26 +6127,34 @@ impl Float {
     /// - $f(0,x,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::rational_div_float_prec_round_val_ref`] instead. If you know you'll be using the
@@ -5970,10 +6218,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -5987,26 +6237,34 @@ impl Float {
     /// - $f(0,x,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::rational_div_float_prec_round_ref_val`] instead. If you know you'll be using the
@@ -6070,10 +6328,12 @@ impl Float {
     /// f(x,y,m) = x/y+\varepsilon.
     /// $$
     /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $x/y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the input [`Float`].
-    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input [`Float`].
+    /// - If $x/y$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the precision of the
+    ///   input [`Float`].
     ///
     /// If the output has a precision, it is the precision of the [`Float`] input.
     ///
@@ -6087,26 +6347,34 @@ impl Float {
     /// - $f(0,x,m)=-0.0$ if $x<0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $-\infty$ is returned instead.
+    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
+    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead, where `p` is the precision of the input.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
+    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
     ///   returned instead.
-    /// - If $f(x,y,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`, $-(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,y,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,y,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,y,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,y,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,y,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,y,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,y,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,y,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// If you want to specify an output precision, consider using
     /// [`Float::rational_div_float_prec_round_ref_ref`] instead. If you know you'll be using the
@@ -0,0 +1,1236 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The error function and the complementary error function are computed from an exact rational
+// argument x > 0 (erf is odd, and erfc(-x) = 2 - erfc(x)). The series
+//
+// erf(x) = 2x e ^ (-x ^ 2) / √π * Σ (2x ^ 2) ^ n / (1 * 3 * ... * (2n + 1)),
+//
+// whose terms are all positive, gives erf(x) with no cancellation. erfc(x) = 1 - erf(x) loses about
+// x ^ 2 * log_2(e) bits to cancellation, so once that exceeds the working precision, the asymptotic
+// series
+//
+// erfc(x) = e ^ (-x ^ 2) / (x√π) * Σ (-1) ^ n (1 * 3 * ... * (2n - 1)) / (2x ^ 2) ^ n
+//
+// is used instead. For x > 0 this series envelops erfc(x): each remainder has the sign of, and is
+// smaller in absolute value than, the first omitted term; and by the time the cancellation would
+// be a problem, its terms get small enough. e ^ (-x ^ 2) is computed with a power of 2 split off,
+// so that erfc(x) can be computed even when it is far below the smallest positive `Float`. Every
+// step produces rational bounds, and the result is rounded using Ziv's strategy.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::exp::exp_underflow;
+use crate::float::arithmetic::gamma::{Argument, constant_bounds, exp_scaled_bounds, exp_shift};
+use crate::float::arithmetic::round_near_x::float_round_near_x;
+use crate::float::arithmetic::sin_cos::round_with_bounds;
+use crate::float::arithmetic::sinh_cosh::round_with_scaled_bounds;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{DivRound, Erf, ErfAssign, PowerOf2, Square};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, One, Two, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Arguments with an exponent greater than this, that is, arguments at least 2 ^ 15 in absolute
+// value, are huge: for x >= 2 ^ 15, erfc(x) < e ^ (-x ^ 2) <= e ^ (-2 ^ 30), far below the smallest
+// positive `Float`.
+const HUGE_EXPONENT: i64 = 15;
+
+// Returns bounds on Σ (2x ^ 2) ^ n / (1 * 3 * ... * (2n + 1)) = √π e ^ (x ^ 2) erf(x) / (2x), for a
+// positive rational `x`, with a relative width of about `2 ^ (-w)`.
+//
+// The terms are computed in fixed point, each both rounded down and rounded up from the previous
+// term's bounds. Once the ratio of consecutive terms, 2x ^ 2 / (2n + 3), drops to 1/2, the tail is
+// smaller than the last term.
+fn erf_series_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let (p, q) = (x.square() << 1u32).into_numerator_and_denominator();
+    let scale = w + 32 + (p.significant_bits().saturating_sub(q.significant_bits()) << 1);
+    let one = Natural::power_of_2(scale);
+    let mut t_lo = one.clone();
+    let mut t_hi = one.clone();
+    let mut s_lo = one.clone();
+    let mut s_hi = one;
+    let mut k = 1u64;
+    loop {
+        k += 2;
+        let d = &q * Natural::from(k);
+        t_lo = &t_lo * &p / &d;
+        t_hi = (&t_hi * &p).div_round(&d, Ceiling).0;
+        s_lo += &t_lo;
+        s_hi += &t_hi;
+        if &p << 1u32 <= &q * Natural::from(k + 2) && &t_hi << (w + 8) <= s_lo {
+            s_hi += t_hi;
+            break;
+        }
+    }
+    (Rational::from(s_lo) >> scale, Rational::from(s_hi) >> scale)
+}
+
+// Returns bounds on erf(x), for a positive rational `x`, with a relative width of about `2 ^
+// (-w)`.
+fn erf_positive_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let (s_lo, s_hi) = erf_series_bounds(x, w + 4);
+    let (c_lo, c_hi) = constant_bounds(Float::two_over_sqrt_pi_prec_round(w + 4, Floor));
+    let minus_x_2 = -x.square();
+    let e_lo = Rational::exact_from(Float::exp_rational_prec_round_ref(&minus_x_2, w + 4, Floor).0);
+    let e_hi =
+        Rational::exact_from(Float::exp_rational_prec_round_ref(&minus_x_2, w + 4, Ceiling).0);
+    (c_lo * e_lo * s_lo * x, c_hi * e_hi * s_hi * x)
+}
+
+// Returns bounds on Σ (-1) ^ n (1 * 3 * ... * (2n - 1)) / (2x ^ 2) ^ n = x√π e ^ (x ^ 2) erfc(x),
+// for a positive rational `x`, with an absolute width of about `2 ^ (-w)`, or `None` if the terms
+// start growing before they get that small. The terms are computed in fixed point, like those of
+// `erf_series_bounds`; since the series is enveloping, the remainder is between 0 and the first
+// omitted term.
+fn erfc_asymptotic_bounds(x: &Rational, w: u64) -> Option<(Rational, Rational)> {
+    let (p, q) = (x.square() << 1u32).into_numerator_and_denominator();
+    let scale = w + 32;
+    let one = Natural::power_of_2(scale);
+    let mut t_lo = one.clone();
+    let mut t_hi = one.clone();
+    let mut s_lo = Rational::from(&one);
+    let mut s_hi = Rational::from(one);
+    let mut k = 1u64;
+    let mut negative = false;
+    loop {
+        let n = &q * Natural::from(k);
+        if n > p {
+            return None;
+        }
+        t_lo = &t_lo * &n / &p;
+        t_hi = (&t_hi * &n).div_round(&p, Ceiling).0;
+        negative = !negative;
+        if &t_hi << (w + 8) <= Natural::power_of_2(scale) {
+            // the omitted remainder is between 0 and the term just computed
+            if negative {
+                s_lo -= Rational::from(t_hi);
+            } else {
+                s_hi += Rational::from(t_hi);
+            }
+            break;
+        }
+        if negative {
+            s_lo -= Rational::from(&t_hi);
+            s_hi -= Rational::from(&t_lo);
+        } else {
+            s_lo += Rational::from(&t_lo);
+            s_hi += Rational::from(&t_hi);
+        }
+        k += 2;
+    }
+    Some((s_lo >> scale, s_hi >> scale))
+}
+
+// A lower bound on -log_2(erfc(x)) for x >= 1: erfc(x) < e ^ (-x ^ 2) for x > 1 / √π, and 1.44 <
+// log_2(e). The bound is floor(1.44x ^ 2), capped at 2 ^ 30 for huge x.
+fn erfc_err(x: &Argument) -> u64 {
+    if x.exponent() > HUGE_EXPONENT {
+        1 << 30
+    } else {
+        let y = x.to_rational().square() * Rational::from_unsigneds(36u8, 25);
+        u64::exact_from(&Integer::rounding_from(y, Floor).0)
+    }
+}
+
+// Returns erf(x) rounded, for a positive x.
+fn erf_positive(x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exp_x = x.exponent();
+    if exp_x < 0 {
+        // For 0 < x < 1/2, 2x(1 - x ^ 2 / 3) / √π < erf(x) < 2x / √π. Once x is small enough, these
+        // bounds suffice, and they don't require the exact x. The bounds are on erf(x) / 2 ^ e,
+        // where x = m * 2 ^ e.
+        return round_with_scaled_bounds(exp_x, prec, rm, |w| {
+            if exp_x < -i64::exact_from(w + 9) {
+                let (c_lo, c_hi) =
+                    constant_bounds(Float::two_over_sqrt_pi_prec_round(w + 4, Floor));
+                let m = x.mantissa();
+                (
+                    c_lo * m * (Rational::ONE - Rational::power_of_2(-i64::exact_from(w + 8))),
+                    c_hi * m,
+                )
+            } else {
+                let (lo, hi) = erf_positive_bounds(&x.to_rational(), w);
+                (lo >> exp_x, hi >> exp_x)
+            }
+        });
+    }
+    if exp_x > 0
+        && let Some(result) = float_round_near_x(&Float::ONE, erfc_err(x), false, prec, rm)
+    {
+        return result;
+    }
+    let x = x.to_rational();
+    round_with_bounds(prec, rm, |w| Some(erf_positive_bounds(&x, w)))
+}
+
+// Computes erf(x).
+pub(crate) fn erf_helper(x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact erf");
+    if x.is_positive() {
+        erf_positive(x, prec, rm)
+    } else {
+        let (y, o) = erf_positive(&x.abs(), prec, -rm);
+        (-y, o.reverse())
+    }
+}
+
+// Returns bounds on erfc(x) / 2 ^ n, for a positive rational x less than 2 ^ 15, with a relative
+// width of about `2 ^ (-w)`.
+fn erfc_positive_scaled_bounds(
+    x: &Rational,
+    x_2: &Rational,
+    n: i64,
+    w: u64,
+) -> (Rational, Rational) {
+    // Cancellation in 1 - erf(x) loses about x ^ 2 * log_2(e) bits, which is at most 1.45x ^ 2.
+    let lost = u64::exact_from(
+        &Integer::rounding_from(x_2 * Rational::from_unsigneds(29u8, 20), Ceiling).0,
+    );
+    if lost > w + 16
+        && let Some(a) = erfc_asymptotic_bounds(x, w + 4)
+    {
+        let (e_lo, e_hi) = exp_scaled_bounds((-x_2, -x_2), n, true, w + 4);
+        let (c_lo, c_hi) = constant_bounds(Float::one_over_sqrt_pi_prec_round(w + 4, Floor));
+        return ((e_lo * c_lo * a.0) / x, (e_hi * c_hi * a.1) / x);
+    }
+    let (lo, hi) = erf_positive_bounds(x, w + lost + 8);
+    ((Rational::ONE - hi) >> n, (Rational::ONE - lo) >> n)
+}
+
+// Computes erfc(x).
+pub(crate) fn erfc_helper(x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact erfc");
+    let exp_x = x.exponent();
+    let positive = x.is_positive();
+    if exp_x < 0 {
+        // erfc(x) = 1 - erf(x), and |erf(x)| < 2 |x| / √π < 2 ^ (e + 1).
+        if let Some(result) =
+            float_round_near_x(&Float::ONE, exp_x.unsigned_abs(), !positive, prec, rm)
+        {
+            return result;
+        }
+    }
+    if !positive {
+        // erfc(x) = 2 - erfc(-x), and erfc(-x) < 2 ^ (-err) < 2 ^ (2 - err)
+        if exp_x > 0
+            && let Some(result) = float_round_near_x(&Float::TWO, erfc_err(x), false, prec, rm)
+        {
+            return result;
+        }
+        let x = -x.to_rational();
+        return round_with_bounds(prec, rm, |w| {
+            let (lo, hi) = erf_positive_bounds(&x, w);
+            Some((Rational::ONE + lo, Rational::ONE + hi))
+        });
+    }
+    if exp_x > HUGE_EXPONENT {
+        return exp_underflow(prec, rm);
+    }
+    let x = x.to_rational();
+    let x_2 = (&x).square();
+    // For x >= 1, erfc(x) is a little less than e ^ (-x ^ 2) / (x√π), and for 0 < x < 1 it is
+    // between 1/8 and 1.
+    let n = if exp_x > 0 {
+        let l = -&x_2
+            - Rational::exact_from(Float::ln_rational_prec_round_ref(&(&x << 1u32), 32, Ceiling).0);
+        exp_shift(&l)
+    } else {
+        0
+    };
+    round_with_scaled_bounds(n, prec, rm, |w| erfc_positive_scaled_bounds(&x, &x_2, n, w))
+}
+
+impl Float {
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], rounding the result to
+    /// the specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded error function is
+    /// less than, equal to, or greater than the exact error function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{erf} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erf} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erf} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{erf}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{erf} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{erf}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=1.0$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Overflow and underflow are not possible, since $|\operatorname{erf} x|$ is greater than
+    /// $|x|$ for $0<|x|<1/2$ and at most 1 for all $x$.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erf_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::erf_round`] instead. If both of these things are true, consider using
+    /// [`Float::erf`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round(5, Floor);
+    /// assert_eq!(erf.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round(5, Ceiling);
+    /// assert_eq!(erf.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round(5, Nearest);
+    /// assert_eq!(erf.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round(20, Floor);
+    /// assert_eq!(erf.to_string(), "0.99999046");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round(20, Ceiling);
+    /// assert_eq!(erf.to_string(), "0.99999142");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round(20, Nearest);
+    /// assert_eq!(erf.to_string(), "0.99999142");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erf_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.erf_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], rounding the result to
+    /// the specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded error function
+    /// is less than, equal to, or greater than the exact error function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erf_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::erf_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).erf()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round_ref(5, Floor);
+    /// assert_eq!(erf.to_string(), "0.969");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round_ref(5, Ceiling);
+    /// assert_eq!(erf.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round_ref(5, Nearest);
+    /// assert_eq!(erf.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round_ref(20, Floor);
+    /// assert_eq!(erf.to_string(), "0.99999046");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round_ref(20, Ceiling);
+    /// assert_eq!(erf.to_string(), "0.99999142");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_round_ref(20, Nearest);
+    /// assert_eq!(erf.to_string(), "0.99999142");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn erf_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { sign } => {
+                let one = Self::one_prec(prec);
+                (if *sign { one } else { -one }, Equal)
+            }
+            Zero { .. } => (self.clone(), Equal),
+            Finite { .. } => erf_helper(&Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], rounding the result to
+    /// the nearest value of the specified precision. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded error function is less than,
+    /// equal to, or greater than the exact error function. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the error function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erf_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::erf`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec(5);
+    /// assert_eq!(erf.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec(20);
+    /// assert_eq!(erf.to_string(), "0.99999142");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erf_prec(self, prec: u64) -> (Self, Ordering) {
+        self.erf_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], rounding the result to
+    /// the nearest value of the specified precision. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded error function is less than,
+    /// equal to, or greater than the exact error function. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the error function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erf_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).erf()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_ref(5);
+    /// assert_eq!(erf.to_string(), "1.00");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(PI).erf_prec_ref(20);
+    /// assert_eq!(erf.to_string(), "0.99999142");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erf_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.erf_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], rounding the result to
+    /// the precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded error function is
+    /// less than, equal to, or greater than the exact error function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::erf_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::erf`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::from(-0.5).erf_round(Floor);
+    /// assert_eq!(erf.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::from(-0.5).erf_round(Ceiling);
+    /// assert_eq!(erf.to_string(), "-0.50");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(-0.5).erf_round(Nearest);
+    /// assert_eq!(erf.to_string(), "-0.50");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erf_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.erf_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], rounding the result to
+    /// the precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded error function
+    /// is less than, equal to, or greater than the exact error function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::erf_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).erf()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::from(-0.5).erf_round_ref(Floor);
+    /// assert_eq!(erf.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::from(-0.5).erf_round_ref(Ceiling);
+    /// assert_eq!(erf.to_string(), "-0.50");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::from(-0.5).erf_round_ref(Nearest);
+    /// assert_eq!(erf.to_string(), "-0.50");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erf_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.erf_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], in place, rounding the
+    /// result to the specified precision and with the specified rounding mode. An [`Ordering`] is
+    /// returned, indicating whether the rounded error function is less than, equal to, or greater
+    /// than the exact error function. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erf_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::erf_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::erf_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erf_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.99999046");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erf_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.99999142");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erf_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.99999142");
+    /// ```
+    #[inline]
+    pub fn erf_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.erf_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], in place, rounding the
+    /// result to the nearest value of the specified precision. An [`Ordering`] is returned,
+    /// indicating whether the rounded error function is less than, equal to, or greater than the
+    /// exact error function. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the error function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erf_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::erf_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erf_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.00");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erf_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "0.99999142");
+    /// ```
+    #[inline]
+    pub fn erf_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.erf_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], in place, rounding the
+    /// result to the precision of the input and with the specified rounding mode. An [`Ordering`]
+    /// is returned, indicating whether the rounded error function is less than, equal to, or
+    /// greater than the exact error function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::erf_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::erf_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// assert_eq!(x.erf_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// assert_eq!(x.erf_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.50");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// assert_eq!(x.erf_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-0.50");
+    /// ```
+    #[inline]
+    pub fn erf_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.erf_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Rational`], rounding the result
+    /// to the specified precision and with the specified rounding mode and returning the result as
+    /// a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded error function is less than, equal to, or greater than the
+    /// exact error function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{erf} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erf} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{erf}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{erf} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{erf}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erf_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::erf_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(erf.to_string(), "0.359");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::erf_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(erf.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) = Float::erf_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(erf.to_string(), "0.36264801");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) =
+    ///     Float::erf_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(erf.to_string(), "0.36264849");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn erf_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::erf_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Rational`], rounding the result
+    /// to the specified precision and with the specified rounding mode and returning the result as
+    /// a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded error function is less than, equal to, or greater than the
+    /// exact error function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{erf} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erf} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{erf}
+    ///   x|\rfloor-p+1}$.
+    /// - If $\operatorname{erf} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\operatorname{erf}
+    ///   x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erf_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) =
+    ///     Float::erf_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(erf.to_string(), "0.359");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) =
+    ///     Float::erf_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(erf.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erf, o) =
+    ///     Float::erf_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(erf.to_string(), "0.36264801");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) =
+    ///     Float::erf_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(erf.to_string(), "0.36264849");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn erf_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::ZERO, Equal);
+        }
+        erf_helper(&Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Rational`], rounding the result
+    /// to the nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded error function is less than, equal to, or greater than the exact error function.
+    ///
+    /// If the error function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erf_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::erf_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(erf.to_string(), "0.359");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::erf_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(erf.to_string(), "0.36264801");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn erf_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::erf_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Rational`], rounding the result
+    /// to the nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded error function is less than, equal to, or greater than the exact error function.
+    ///
+    /// If the error function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erf_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erf, o) = Float::erf_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(erf.to_string(), "0.359");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erf, o) = Float::erf_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(erf.to_string(), "0.36264801");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn erf_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::erf_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Erf for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the error function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{erf} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erf} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erf} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{erf} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=1.0$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::erf_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::erf_round`].
+    /// If you want both of these things, consider using [`Float::erf_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Erf;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.erf().is_nan());
+    /// assert_eq!(Float::INFINITY.erf(), 1.0);
+    /// assert_eq!(Float::ZERO.erf(), 0.0);
+    /// assert_eq!(Float::from(PI).erf().to_string(), "0.99999112385363276");
+    /// assert_eq!(Float::from(-0.5).erf().to_string(), "-0.50");
+    /// ```
+    #[inline]
+    fn erf(self) -> Self {
+        let prec = self.significant_bits();
+        self.erf_prec_round(prec, Nearest).0
+    }
+}
+
+impl Erf for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the error function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{erf} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erf} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erf} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{erf} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=1.0$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erf_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::erf_round_ref`]. If you want both of these things, consider using
+    /// [`Float::erf_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Erf;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).erf().is_nan());
+    /// assert_eq!((&Float::INFINITY).erf(), 1.0);
+    /// assert_eq!((&Float::ZERO).erf(), 0.0);
+    /// assert_eq!((&Float::from(PI)).erf().to_string(), "0.99999112385363276");
+    /// assert_eq!((&Float::from(-0.5)).erf().to_string(), "-0.50");
+    /// ```
+    #[inline]
+    fn erf(self) -> Float {
+        let prec = self.significant_bits();
+        self.erf_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl ErfAssign for Float {
+    /// Computes $\operatorname{erf} x$, the error function of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the error function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{erf} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erf} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erf} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\operatorname{erf} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::erf_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ErfAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.erf_assign();
+    /// assert_eq!(x.to_string(), "0.99999112385363276");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// x.erf_assign();
+    /// assert_eq!(x.to_string(), "-0.50");
+    /// ```
+    #[inline]
+    fn erf_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.erf_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{erf} x$, the error function of a primitive float, returning a
+/// correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{erf} x+\varepsilon.
+/// $$
+/// - If $\operatorname{erf} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+///   to be 0.
+/// - If $\operatorname{erf} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{erf} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\infty)=1.0$
+/// - $f(-\infty)=-1.0$
+/// - $f(0.0)=0.0$
+/// - $f(-0.0)=-0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::erf::primitive_float_erf;
+///
+/// assert!(primitive_float_erf(f64::NAN).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_erf(f64::NEG_INFINITY)), NiceFloat(-1.0));
+/// assert_eq!(NiceFloat(primitive_float_erf(-0.0f64)), NiceFloat(-0.0));
+/// assert_eq!(NiceFloat(primitive_float_erf(PI)), NiceFloat(0.9999911238536323));
+/// assert_eq!(NiceFloat(primitive_float_erf(-0.5f32)), NiceFloat(-0.5204999));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_erf<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::erf_prec, x)
+}
+
+/// Computes $\operatorname{erf} x$, the error function of a [`Rational`], returning the result as a
+/// primitive float.
+///
+/// $$
+/// f(x) = \operatorname{erf} x+\varepsilon.
+/// $$
+/// - If $\operatorname{erf} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+///   to be 0.
+/// - If $\operatorname{erf} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\operatorname{erf} x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::erf::primitive_float_erf_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_erf_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.36264811176606293)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_erf_rational::<f64>(
+///         &Rational::from_signeds(-5i8, 2)
+///     )),
+///     NiceFloat(-0.999593047982555)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_erf_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::erf_rational_prec_ref, x)
+}
@@ -0,0 +1,1026 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::erf::erfc_helper;
+use crate::float::arithmetic::gamma::Argument;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Erfc, ErfcAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], rounding
+    /// the result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// complementary error function is less than, equal to, or greater than the exact complementary
+    /// error function. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{erfc} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erfc} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{erfc}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{erfc}
+    ///   x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=2.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// Since $0<\operatorname{erfc} x<2$, overflow is not possible. The result underflows when $x$
+    /// is greater than about $27282$:
+    ///
+    /// Underflow:
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erfc_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::erfc_round`] instead. If both of these things are true, consider using
+    /// [`Float::erfc`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round(5, Floor);
+    /// assert_eq!(erfc.to_string(), "8.58e-6");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round(5, Ceiling);
+    /// assert_eq!(erfc.to_string(), "9.06e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round(5, Nearest);
+    /// assert_eq!(erfc.to_string(), "9.06e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round(20, Floor);
+    /// assert_eq!(erfc.to_string(), "8.8761444e-6");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round(20, Ceiling);
+    /// assert_eq!(erfc.to_string(), "8.8761590e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round(20, Nearest);
+    /// assert_eq!(erfc.to_string(), "8.8761444e-6");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn erfc_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.erfc_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], rounding
+    /// the result to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// complementary error function is less than, equal to, or greater than the exact complementary
+    /// error function. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erfc_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::erfc_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).erfc()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round_ref(5, Floor);
+    /// assert_eq!(erfc.to_string(), "8.58e-6");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round_ref(5, Ceiling);
+    /// assert_eq!(erfc.to_string(), "9.06e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round_ref(5, Nearest);
+    /// assert_eq!(erfc.to_string(), "9.06e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round_ref(20, Floor);
+    /// assert_eq!(erfc.to_string(), "8.8761444e-6");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round_ref(20, Ceiling);
+    /// assert_eq!(erfc.to_string(), "8.8761590e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_round_ref(20, Nearest);
+    /// assert_eq!(erfc.to_string(), "8.8761444e-6");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn erfc_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { sign: true } => (Self::ZERO, Equal),
+            Infinity { sign: false } => (Self::from_unsigned_prec(2u32, prec).0, Equal),
+            Zero { .. } => (Self::one_prec(prec), Equal),
+            Finite { .. } => erfc_helper(&Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], rounding
+    /// the result to the nearest value of the specified precision. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded complementary error
+    /// function is less than, equal to, or greater than the exact complementary error function.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the complementary error function is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erfc_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::erfc`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec(5);
+    /// assert_eq!(erfc.to_string(), "9.06e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec(20);
+    /// assert_eq!(erfc.to_string(), "8.8761444e-6");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn erfc_prec(self, prec: u64) -> (Self, Ordering) {
+        self.erfc_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], rounding
+    /// the result to the nearest value of the specified precision. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded complementary
+    /// error function is less than, equal to, or greater than the exact complementary error
+    /// function. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the complementary error function is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erfc_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).erfc()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_ref(5);
+    /// assert_eq!(erfc.to_string(), "9.06e-6");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(PI).erfc_prec_ref(20);
+    /// assert_eq!(erfc.to_string(), "8.8761444e-6");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn erfc_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.erfc_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], rounding
+    /// the result to the precision of the input and with the specified rounding mode. The [`Float`]
+    /// is taken by value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// complementary error function is less than, equal to, or greater than the exact complementary
+    /// error function. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::erfc_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::erfc`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::from(-0.5).erfc_round(Floor);
+    /// assert_eq!(erfc.to_string(), "1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::from(-0.5).erfc_round(Ceiling);
+    /// assert_eq!(erfc.to_string(), "2.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(-0.5).erfc_round(Nearest);
+    /// assert_eq!(erfc.to_string(), "2.0");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erfc_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.erfc_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], rounding
+    /// the result to the precision of the input and with the specified rounding mode. The [`Float`]
+    /// is taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// complementary error function is less than, equal to, or greater than the exact complementary
+    /// error function. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::erfc_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).erfc()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::from(-0.5).erfc_round_ref(Floor);
+    /// assert_eq!(erfc.to_string(), "1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::from(-0.5).erfc_round_ref(Ceiling);
+    /// assert_eq!(erfc.to_string(), "2.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) = Float::from(-0.5).erfc_round_ref(Nearest);
+    /// assert_eq!(erfc.to_string(), "2.0");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erfc_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.erfc_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], in place,
+    /// rounding the result to the specified precision and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded complementary error function is
+    /// less than, equal to, or greater than the exact complementary error function. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erfc_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::erfc_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::erfc_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erfc_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "8.8761444e-6");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erfc_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "8.8761590e-6");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erfc_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "8.8761444e-6");
+    /// ```
+    #[inline]
+    pub fn erfc_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.erfc_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], in place,
+    /// rounding the result to the nearest value of the specified precision. An [`Ordering`] is
+    /// returned, indicating whether the rounded complementary error function is less than, equal
+    /// to, or greater than the exact complementary error function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the complementary error function is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erfc_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::erfc_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erfc_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "9.06e-6");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.erfc_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "8.8761444e-6");
+    /// ```
+    #[inline]
+    pub fn erfc_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.erfc_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], in place,
+    /// rounding the result to the precision of the input and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded complementary error function is
+    /// less than, equal to, or greater than the exact complementary error function. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::erfc_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::erfc_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// assert_eq!(x.erfc_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// assert_eq!(x.erfc_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "2.0");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// assert_eq!(x.erfc_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "2.0");
+    /// ```
+    #[inline]
+    pub fn erfc_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.erfc_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded complementary error function is less than,
+    /// equal to, or greater than the exact complementary error function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{erfc} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{erfc}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{erfc}
+    ///   x\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erfc_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::erfc_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(erfc.to_string(), "0.625");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) =
+    ///     Float::erfc_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(erfc.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) =
+    ///     Float::erfc_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(erfc.to_string(), "0.63735104");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) =
+    ///     Float::erfc_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(erfc.to_string(), "0.63735199");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn erfc_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::erfc_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Rational`],
+    /// rounding the result to the specified precision and with the specified rounding mode and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded complementary error function is less than,
+    /// equal to, or greater than the exact complementary error function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{erfc} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{erfc}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{erfc}
+    ///   x\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::erfc_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) =
+    ///     Float::erfc_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(erfc.to_string(), "0.625");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) =
+    ///     Float::erfc_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(erfc.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (erfc, o) =
+    ///     Float::erfc_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(erfc.to_string(), "0.63735104");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) =
+    ///     Float::erfc_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(erfc.to_string(), "0.63735199");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn erfc_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::one_prec(prec), Equal);
+        }
+        erfc_helper(&Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded complementary error function is less than, equal to, or
+    /// greater than the exact complementary error function.
+    ///
+    /// If the complementary error function is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erfc_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::erfc_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(erfc.to_string(), "0.625");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::erfc_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(erfc.to_string(), "0.63735199");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn erfc_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::erfc_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Rational`],
+    /// rounding the result to the nearest value of the specified precision and returning the result
+    /// as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded complementary error function is less than, equal to, or
+    /// greater than the exact complementary error function.
+    ///
+    /// If the complementary error function is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::erfc_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (erfc, o) = Float::erfc_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(erfc.to_string(), "0.625");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (erfc, o) = Float::erfc_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(erfc.to_string(), "0.63735199");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn erfc_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::erfc_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Erfc for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], taking it
+    /// by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the complementary error
+    /// function is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{erfc} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erfc} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{erfc} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=2.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::erfc_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::erfc_round`].
+    /// If you want both of these things, consider using [`Float::erfc_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Erfc;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.erfc().is_nan());
+    /// assert_eq!(Float::NEGATIVE_INFINITY.erfc(), 2.0);
+    /// assert_eq!(Float::ZERO.erfc(), 1.0);
+    /// assert_eq!(Float::from(PI).erfc().to_string(), "8.8761463676416137e-6");
+    /// assert_eq!(Float::from(-0.5).erfc().to_string(), "2.0");
+    /// ```
+    #[inline]
+    fn erfc(self) -> Self {
+        let prec = self.significant_bits();
+        self.erfc_prec_round(prec, Nearest).0
+    }
+}
+
+impl Erfc for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], taking it
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the complementary error
+    /// function is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{erfc} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erfc} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{erfc} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=2.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::erfc_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::erfc_round_ref`]. If you want both of these things, consider using
+    /// [`Float::erfc_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Erfc;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).erfc().is_nan());
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).erfc(), 2.0);
+    /// assert_eq!((&Float::ZERO).erfc(), 1.0);
+    /// assert_eq!((&Float::from(PI)).erfc().to_string(), "8.8761463676416137e-6");
+    /// assert_eq!((&Float::from(-0.5)).erfc().to_string(), "2.0");
+    /// ```
+    #[inline]
+    fn erfc(self) -> Float {
+        let prec = self.significant_bits();
+        self.erfc_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl ErfcAssign for Float {
+    /// Computes $\operatorname{erfc} x$, the complementary error function of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the complementary error
+    /// function is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{erfc} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{erfc} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{erfc} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{erfc} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// See the [`Float::erfc_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ErfcAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.erfc_assign();
+    /// assert_eq!(x.to_string(), "8.8761463676416137e-6");
+    ///
+    /// let mut x = Float::from(-0.5);
+    /// x.erfc_assign();
+    /// assert_eq!(x.to_string(), "2.0");
+    /// ```
+    #[inline]
+    fn erfc_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.erfc_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{erfc} x$, the complementary error function of a primitive float,
+/// returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{erfc} x+\varepsilon.
+/// $$
+/// - If $\operatorname{erfc} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{erfc} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   \operatorname{erfc} x\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\infty)=0.0$
+/// - $f(-\infty)=2.0$
+/// - $f(\pm0.0)=1.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::erfc::primitive_float_erfc;
+///
+/// assert!(primitive_float_erfc(f64::NAN).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_erfc(f64::INFINITY)), NiceFloat(0.0));
+/// assert_eq!(NiceFloat(primitive_float_erfc(-0.0f64)), NiceFloat(1.0));
+/// assert_eq!(NiceFloat(primitive_float_erfc(PI)), NiceFloat(8.876146367641612e-6));
+/// assert_eq!(NiceFloat(primitive_float_erfc(-0.5f32)), NiceFloat(1.5204998));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_erfc<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::erfc_prec, x)
+}
+
+/// Computes $\operatorname{erfc} x$, the complementary error function of a [`Rational`], returning
+/// the result as a primitive float.
+///
+/// $$
+/// f(x) = \operatorname{erfc} x+\varepsilon.
+/// $$
+/// - If $\operatorname{erfc} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{erfc} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   \operatorname{erfc} x\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=1.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::erfc::primitive_float_erfc_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_erfc_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.6373518882339371)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_erfc_rational::<f64>(&Rational::from(100))),
+///     NiceFloat(0.0)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_erfc_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::erfc_rational_prec_ref, x)
+}
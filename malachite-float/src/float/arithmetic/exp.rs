@@ -42,7 +42,7 @@ use malachite_base::num::basic::traits::{
 use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom, WrappingFrom};
 use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::RoundingMode::{
-    self, Ceiling, Down, Exact, Floor, Nearest, Up,
+    self, Ceiling, Down, Exact, Floor, Nearest, NearestTiesAway, Odd, Up,
 };
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
@@ -482,7 +482,14 @@ pub(crate) fn exp_3(x: &Float, precy: u64, rm: RoundingMode) -> (Float, Ordering
                 t = tmp.square_prec_round_ref(prec, Floor).0;
                 if matches!(t.0, Zero { .. }) {
                     // exact result < 2^(emin - 2): genuine underflow.
-                    return exp_underflow(precy, if rm == Nearest { Down } else { rm });
+                    return exp_underflow(
+                        precy,
+                        if rm == Nearest || rm == NearestTiesAway {
+                            Down
+                        } else {
+                            rm
+                        },
+                    );
                 }
                 scaled = true;
             }
@@ -496,6 +503,7 @@ pub(crate) fn exp_3(x: &Float, precy: u64, rm: RoundingMode) -> (Float, Ordering
             if scaled && y.is_normal() {
                 // Undo the *2 scaling: y /= 4.
                 let ey = i64::from(y.get_exponent().unwrap());
+                let y_pow2 = y.significand_ref().unwrap().is_power_of_2();
                 let inex2 = y.shr_round_assign(2, rm);
                 if inex2 != Equal {
                     // Underflow while unscaling.
@@ -507,6 +515,15 @@ pub(crate) fn exp_3(x: &Float, precy: u64, rm: RoundingMode) -> (Float, Ordering
                         // Double rounding: RNDN rounded the scaled result down to 2^emin, but the
                         // exact result is > 2^(emin - 2), so round up instead.
                         (y, inexact) = (Float::min_positive_value_prec(precy), Greater);
+                    } else if rm == NearestTiesAway
+                        && inexact == Greater
+                        && y_pow2
+                        && ey == Float::MIN_EXPONENT_PLUS_1_I64
+                    {
+                        // Double rounding the other way: the scaled result was rounded up to
+                        // 2^emin and then away from zero at the underflow tie point, but the exact
+                        // result is < 2^(emin - 2), so round down instead.
+                        (y, inexact) = (Float::ZERO, Less);
                     } else {
                         inexact = inex2;
                     }
@@ -632,20 +649,20 @@ pub(crate) fn exp_2(x: &Float, precy: u64, rm: RoundingMode) -> (Float, Ordering
 // This is `mpfr_overflow` (with positive sign) as used by `mpfr_exp`, MPFR 4.2.2.
 pub(crate) fn exp_overflow(precy: u64, rm: RoundingMode) -> (Float, Ordering) {
     match rm {
-        Nearest | Up | Ceiling => (Float::INFINITY, Greater),
-        Down | Floor => (Float::max_finite_value_with_prec(precy), Less),
+        Nearest | NearestTiesAway | Up | Ceiling => (Float::INFINITY, Greater),
+        Down | Floor | Odd => (Float::max_finite_value_with_prec(precy), Less),
         Exact => panic!("exp: Exact rounding was requested, but the result overflows"),
     }
 }
 
 // The underflow result of exp (the value, which is positive, is below the minimum positive Float).
-// MPFR maps Nearest to toward-zero here, so Nearest joins Down/Floor.
+// MPFR maps Nearest to toward-zero here, so Nearest and NearestTiesAway join Down/Floor.
 //
 // This is `mpfr_underflow` (with positive sign) as used by `mpfr_exp`, MPFR 4.2.2.
 pub(crate) fn exp_underflow(precy: u64, rm: RoundingMode) -> (Float, Ordering) {
     match rm {
-        Nearest | Down | Floor => (Float::ZERO, Less),
-        Up | Ceiling => (Float::min_positive_value_prec(precy), Greater),
+        Nearest | NearestTiesAway | Down | Floor => (Float::ZERO, Less),
+        Up | Ceiling | Odd => (Float::min_positive_value_prec(precy), Greater),
         Exact => panic!("exp: Exact rounding was requested, but the result underflows"),
     }
 }
@@ -713,9 +730,9 @@ fn exp_prec_round_normal_ref(x: &Float, precy: u64, rm: RoundingMode) -> (Float,
     let expx = i64::from(x.get_exponent().unwrap());
     // tiny x: if x < 2^(-precy), then exp(x) = 1 +/- ulp(1)
     if expx < 0 && u64::exact_from(-expx) > precy {
-        return if x.is_sign_negative() && (rm == Down || rm == Floor) {
+        return if x.is_sign_negative() && (rm == Down || rm == Floor || rm == Odd) {
             (one_neighbor(precy, false), Less) // 1 - ulp
-        } else if x.is_sign_positive() && (rm == Up || rm == Ceiling) {
+        } else if x.is_sign_positive() && (rm == Up || rm == Ceiling || rm == Odd && precy != 1) {
             (one_neighbor(precy, true), Greater) // 1 + ulp
         } else {
             (
@@ -826,8 +843,9 @@ fn exp_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ord
     // path.
     if -exp_x > i64::exact_from(prec) {
         return match (positive, rm) {
-            (false, Down | Floor) => (one_neighbor(prec, false), Less), // 1 - ulp
-            (true, Up | Ceiling) => (one_neighbor(prec, true), Greater), // 1 + ulp
+            (false, Down | Floor | Odd) => (one_neighbor(prec, false), Less), // 1 - ulp
+            (true, Up | Ceiling) => (one_neighbor(prec, true), Greater),      // 1 + ulp
+            (true, Odd) if prec != 1 => (one_neighbor(prec, true), Greater),  // 1 + ulp
             (true, _) => (Float::one_prec(prec), Less),
             (false, _) => (Float::one_prec(prec), Greater),
         };
@@ -879,10 +897,10 @@ impl Float {
     /// f(x,p,m) = e^x+\varepsilon.
     /// $$
     /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
-    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 e^x\rfloor-p}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -893,15 +911,20 @@ impl Float {
     /// - $f(\pm0.0,p,m)=1.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
     /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned instead.
-    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is returned
     ///   instead.
+    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec`] instead. If you
     /// know that your target precision is the precision of the input, consider using
@@ -979,10 +1002,10 @@ impl Float {
     /// f(x,p,m) = e^x+\varepsilon.
     /// $$
     /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
-    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 e^x\rfloor-p}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -993,15 +1016,20 @@ impl Float {
     /// - $f(\pm0.0,p,m)=1.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
     /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned instead.
-    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is returned
     ///   instead.
+    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_ref`] instead. If
     /// you know that your target precision is the precision of the input, consider using
@@ -1211,10 +1239,12 @@ impl Float {
     /// f(x,m) = e^x+\varepsilon.
     /// $$
     /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1278,10 +1308,12 @@ impl Float {
     /// f(x,m) = e^x+\varepsilon.
     /// $$
     /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1348,10 +1380,10 @@ impl Float {
     /// x \gets e^x+\varepsilon.
     /// $$
     /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
-    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 e^x\rfloor-p}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1478,10 +1510,12 @@ impl Float {
     /// x \gets e^x+\varepsilon.
     /// $$
     /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1538,8 +1572,10 @@ impl Float {
     /// $$
     /// f(x,p,m) = e^x+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$.
     ///
     /// These bounds do not apply when the result overflows or underflows; see below.
     ///
@@ -1549,15 +1585,20 @@ impl Float {
     /// - $f(0,p,m)=1$.
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
     /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned instead.
-    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is returned
     ///   instead.
+    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::exp_rational_prec`] instead.
     ///
@@ -1611,8 +1652,10 @@ impl Float {
     /// $$
     /// f(x,p,m) = e^x+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| \leq 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   e^x\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   e^x\rfloor-p}$.
     ///
     /// These bounds do not apply when the result overflows or underflows; see below.
     ///
@@ -1622,15 +1665,20 @@ impl Float {
     /// - $f(0,p,m)=1$.
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
     /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned instead.
-    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
+    /// - If $f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is returned
     ///   instead.
+    /// - If $f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::exp_rational_prec_ref`]
     /// instead.
@@ -180,8 +180,9 @@ fn exp_x_minus_1_deep_negative(
     // which prec meets or exceeds here.)
     if s_est >= prec.saturating_add(2) {
         return match rm {
-            Ceiling | Down => (-one_neighbor(prec, false), Greater), // -1 + ulp (toward zero)
-            _ => (-Float::one_prec(prec), Less),                     // -1
+            // -1 + ulp (toward zero)
+            Ceiling | Down | Odd => (-one_neighbor(prec, false), Greater),
+            _ => (-Float::one_prec(prec), Less), // -1
         };
     }
     let xr = Rational::exact_from(x);
@@ -297,8 +298,9 @@ fn exp_x_minus_1_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (
         // `prec` is enormous (>= MAX_EXPONENT), so `float_round_near_x` cannot resolve the
         // rounding; but exp(x) is still far below ulp(-1), so -1 rounds the same way.
         return match rm {
-            Ceiling | Down => (-one_neighbor(prec, false), Greater), // -1 + ulp (toward zero)
-            _ => (-Float::one_prec(prec), Less),                     // -1
+            // -1 + ulp (toward zero)
+            Ceiling | Down | Odd => (-one_neighbor(prec, false), Greater),
+            _ => (-Float::one_prec(prec), Less), // -1
         };
     }
     // General case: bracket x between the Floats x_lo <= x <= x_hi, apply expm1 to both, and
@@ -339,10 +341,10 @@ impl Float {
     /// f(x,p,m) = e^x-1+\varepsilon.
     /// $$
     /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -353,16 +355,20 @@ impl Float {
     /// - $f(\pm0.0,p,m)=\pm0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $-2^{-2^{30}-1}<f(x,p,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// (A positive result never underflows: $e^x-1>x$ for positive $x$.)
     ///
@@ -419,10 +425,10 @@ impl Float {
     /// f(x,p,m) = e^x-1+\varepsilon.
     /// $$
     /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -433,16 +439,20 @@ impl Float {
     /// - $f(\pm0.0,p,m)=\pm0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $-2^{-2^{30}-1}<f(x,p,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// (A positive result never underflows: $e^x-1>x$ for positive $x$.)
     ///
@@ -633,8 +643,10 @@ impl Float {
     /// $$
     /// f(x,p,m) = e^x-1+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
     ///
     /// These bounds do not apply when the result overflows or underflows; see below.
     ///
@@ -644,22 +656,30 @@ impl Float {
     /// - $f(0,p,m)=0$.
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
     /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,p,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// Unlike $e^x$, $e^x-1$ never underflows to zero for large negative $x$: it instead tends to
     /// $-1$.
@@ -725,8 +745,10 @@ impl Float {
     /// $$
     /// f(x,p,m) = e^x-1+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
     ///
     /// These bounds do not apply when the result overflows or underflows; see below.
     ///
@@ -736,22 +758,30 @@ impl Float {
     /// - $f(0,p,m)=0$.
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
     /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,p,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// Unlike $e^x$, $e^x-1$ never underflows to zero for large negative $x$: it instead tends to
     /// $-1$.
@@ -981,10 +1011,12 @@ impl Float {
     /// f(x,m) = e^x-1+\varepsilon.
     /// $$
     /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -995,15 +1027,20 @@ impl Float {
     /// - $f(\pm0.0,m)=\pm0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $\infty$ is returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
     /// - If $-2^{-2^{30}}<f(x,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
+    /// - If $-2^{-2^{30}}<f(x,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is returned
     ///   instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$ is
+    ///   returned instead.
     ///
     /// (A positive result never underflows: $e^x-1>x$ for positive $x$.)
     ///
@@ -1060,10 +1097,12 @@ impl Float {
     /// f(x,m) = e^x-1+\varepsilon.
     /// $$
     /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1074,15 +1113,20 @@ impl Float {
     /// - $f(\pm0.0,m)=\pm0.0$
     ///
     /// Overflow and underflow:
-    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
-    ///   returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   $\infty$ is returned instead.
+    /// - If $f(x,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
     /// - If $-2^{-2^{30}}<f(x,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
+    /// - If $-2^{-2^{30}}<f(x,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is returned
     ///   instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$ is
+    ///   returned instead.
     ///
     /// (A positive result never underflows: $e^x-1>x$ for positive $x$.)
     ///
@@ -1137,10 +1181,10 @@ impl Float {
     /// x \gets e^x-1+\varepsilon.
     /// $$
     /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1247,10 +1291,12 @@ impl Float {
     /// x \gets e^x-1+\varepsilon.
     /// $$
     /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the
+    ///   input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
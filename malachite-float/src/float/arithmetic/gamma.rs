@@ -0,0 +1,1898 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The gamma function and its relatives are computed from an exact rational argument x, using
+// Stirling's series with a rigorous bound on the remainder. For x > 0, the argument is first
+// shifted to z = x + n, large enough that the series for log Γ(z) or ψ(z) reaches the working
+// precision long before its terms start to grow, and the shift is undone using Γ(x + 1) = xΓ(x) or
+// ψ(x + 1) = ψ(x) + 1 / x. For x < 0, the reflection formulas Γ(x)Γ(1 - x) = π / sin(πx) and ψ(1 -
+// x) - ψ(x) = π cot(πx) reduce the computation to a positive argument. Γ(x) itself is e raised to
+// the power log |Γ(x)|, with a power of 2 split off so that intermediate values stay small even when the result
+// is far outside the range of a `Float`. Every step produces rational bounds, and the result is
+// rounded using Ziv's strategy.
+//
+// Arguments whose exponent is very large or very small are kept in the form m * 2 ^ e, so that, for
+// example, Γ(x) ~ 1 / x for tiny x and log Γ(x) ~ x(log(x) - 1) for huge x can be computed without
+// building a `Rational` with an enormous numerator or denominator.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::mul_bounds;
+use crate::float::arithmetic::exp::{exp_overflow, exp_underflow};
+use crate::float::arithmetic::round_near_x::float_round_near_x;
+use crate::float::arithmetic::sin_cos::{
+    div_bounds, rational_exponent, round_with_bounds, sin_cos_bounds,
+};
+use crate::float::arithmetic::sinh_cosh::{round_scaled_bracket, round_with_scaled_bounds};
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn, floor_and_ceiling};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    Abs, Factorial, FloorLogBase2, Gamma, GammaAssign, IsPowerOf2, Parity, Pow, PowerOf2,
+    Reciprocal,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, One, OneHalf, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom, WrappingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Arguments with an exponent greater than this, that is, arguments at least 2 ^ 26 in absolute
+// value, are huge: log_2 Γ(2 ^ 26) is about 1.6 * 10 ^ 9, so Γ overflows for huge positive
+// arguments and, unless the argument is very close to an integer, underflows for huge negative
+// ones.
+const HUGE_EXPONENT: i64 = 26;
+
+// A nonzero rational argument x = m * 2 ^ e, with 1/2 <= |m| < 1.
+pub(crate) struct Argument {
+    m: Rational,
+    e: i64,
+}
+
+impl Argument {
+    pub(crate) fn from_rational(x: &Rational) -> Self {
+        let e = rational_exponent(x);
+        Self { m: x >> e, e }
+    }
+
+    // For a finite, nonzero `Float`.
+    pub(crate) fn from_float(x: &Float) -> Self {
+        let e = i64::from(x.get_exponent().unwrap());
+        Self {
+            m: Rational::exact_from(x >> e),
+            e,
+        }
+    }
+
+    pub(crate) fn to_rational(&self) -> Rational {
+        &self.m << self.e
+    }
+
+    pub(crate) const fn exponent(&self) -> i64 {
+        self.e
+    }
+
+    pub(crate) const fn mantissa(&self) -> &Rational {
+        &self.m
+    }
+
+    pub(crate) fn is_positive(&self) -> bool {
+        self.m > 0u32
+    }
+
+    pub(crate) fn abs(&self) -> Self {
+        Self {
+            m: (&self.m).abs(),
+            e: self.e,
+        }
+    }
+
+    // x is an integer if and only if m = a / 2 ^ k with k <= e.
+    fn is_integer(&self) -> bool {
+        let d = self.m.denominator_ref();
+        self.e > 0 && d.is_power_of_2() && d.significant_bits() <= u64::exact_from(self.e) + 1
+    }
+
+    // Bounds on log |x|, with an absolute width of about 2 ^ (-w). This is log |m| + e * log(2),
+    // and |log |m|| < 1.
+    pub(crate) fn ln_abs_bounds(&self, w: u64) -> (Rational, Rational) {
+        let (m_lo, m_hi) = ln_bounds(&(&self.m).abs(), w + 2);
+        let (ln_2_lo, ln_2_hi) = constant_bounds(Float::ln_2_prec_round(
+            w + 2 + self.e.unsigned_abs().significant_bits(),
+            Floor,
+        ));
+        let e = Rational::from(self.e);
+        if self.e >= 0 {
+            (m_lo + &e * ln_2_lo, m_hi + e * ln_2_hi)
+        } else {
+            (m_lo + &e * ln_2_hi, m_hi + e * ln_2_lo)
+        }
+    }
+}
+
+// Returns the bounds `(floor, ceiling)` on a constant, given the constant rounded down.
+pub(crate) fn constant_bounds(c: (Float, Ordering)) -> (Rational, Rational) {
+    let (lo, hi) = floor_and_ceiling(c);
+    (Rational::exact_from(lo), Rational::exact_from(hi))
+}
+
+#[inline]
+fn pi_bounds(w: u64) -> (Rational, Rational) {
+    constant_bounds(Float::pi_prec_round(w, Floor))
+}
+
+// Returns bounds on log(x), for a positive rational `x`, with a relative width of about
+// `2 ^ (-w)`.
+#[inline]
+pub(crate) fn ln_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    constant_bounds(Float::ln_rational_prec_round_ref(x, w, Floor))
+}
+
+// Returns bounds on log(x), for a positive rational `x`, with an absolute width of about
+// `2 ^ (-w)`. If e is the exponent of x, then |log(x)| < 2 ^ k, where k is the number of
+// significant bits of |e|.
+pub(crate) fn ln_bounds_absolute(x: &Rational, w: u64) -> (Rational, Rational) {
+    ln_bounds(
+        x,
+        w + rational_exponent(x).unsigned_abs().significant_bits(),
+    )
+}
+
+// Returns the bounds on e ^ l / 2 ^ n, given bounds on l, with a relative width of about
+// `2 ^ (-w)`; or, if e ^ l / 2 ^ n is less than `2 ^ (-w - 16)`, the bounds 0 and `2 ^ (-w - 16)`,
+// since such a value is negligible next to the values of about 1 that it is combined with, and
+// computing it exactly could produce a huge `Rational`. The bounds are negated if `positive` is
+// false.
+pub(crate) fn exp_scaled_bounds(
+    (l_lo, l_hi): (Rational, Rational),
+    n: i64,
+    positive: bool,
+    w: u64,
+) -> (Rational, Rational) {
+    let (ln_2_lo, ln_2_hi) = constant_bounds(Float::ln_2_prec_round(
+        w + 4 + n.unsigned_abs().significant_bits(),
+        Floor,
+    ));
+    let n = Rational::from(n);
+    let (r_lo, r_hi) = if n >= 0u32 {
+        (l_lo - &n * ln_2_hi, l_hi - n * ln_2_lo)
+    } else {
+        (l_lo - &n * ln_2_lo, l_hi - n * ln_2_hi)
+    };
+    // e ^ (-w - 16) < 2 ^ (-w - 16)
+    if r_hi < -Rational::from(w + 16) {
+        let tiny = Rational::power_of_2(-i64::exact_from(w + 16));
+        return if positive {
+            (Rational::ZERO, tiny)
+        } else {
+            (-tiny, Rational::ZERO)
+        };
+    }
+    let lo = Rational::exact_from(Float::exp_rational_prec_round_ref(&r_lo, w + 4, Floor).0);
+    let hi = Rational::exact_from(Float::exp_rational_prec_round_ref(&r_hi, w + 4, Ceiling).0);
+    if positive { (lo, hi) } else { (-hi, -lo) }
+}
+
+// Returns the n for which e ^ l / 2 ^ n is between 1 and about 2, given a lower bound on l that is
+// accurate to within 2 ^ (-10) or so.
+pub(crate) fn exp_shift(l_lo: &Rational) -> i64 {
+    let ln_2 = Rational::exact_from(Float::ln_2_prec_round(64, Floor).0);
+    i64::exact_from(&Integer::rounding_from(l_lo / ln_2, Floor).0)
+}
+
+// Returns the tangent numbers T_1, ..., T_n (1, 2, 16, 272, ...), using the algorithm of Brent and
+// Harvey.
+pub(crate) fn tangent_numbers(n: usize) -> Vec<Natural> {
+    let mut ts: Vec<Natural> = Vec::with_capacity(n);
+    if n == 0 {
+        return ts;
+    }
+    ts.push(Natural::ONE);
+    for k in 1..n {
+        let t = &ts[k - 1] * Natural::from(k);
+        ts.push(t);
+    }
+    for k in 1..n {
+        for j in k..n {
+            ts[j] = &ts[j - 1] * Natural::from(j - k) + &ts[j] * Natural::from(j - k + 2);
+        }
+    }
+    ts
+}
+
+// The smallest argument for which Stirling's series is used at working precision `w`.
+#[inline]
+const fn stirling_threshold(w: u64) -> u64 {
+    (w >> 2) + 4
+}
+
+// Returns bounds on the sum S of Stirling's series for log Γ(z) (if `digamma` is false) or ψ(z) (if
+// it is true), for a rational z >= `stirling_threshold(w)`. The k-th term is B_(2k) / (2k(2k - 1)z
+// ^ (2k - 1)) or B_(2k) / (2kz ^ (2k)), respectively, where B_(2k) is a Bernoulli number. These
+// series diverge, but the error of each partial sum is smaller than the first omitted term and has
+// the same sign, so a partial sum and the first omitted term together give bounds on S. For z this
+// large, the terms fall below 2 ^ (-w - 8) after roughly w / 8 of them.
+//
+// The Bernoulli numbers are obtained from the tangent numbers T_k, since |B_(2k)| = 2kT_k / (4 ^
+// k(4 ^ k - 1)) and the signs of the B_(2k) alternate, starting with B_2 = 1/6 > 0. The powers of 1
+// / z are computed as `Float`s rounded in the appropriate directions.
+fn stirling_series_bounds(z: &Rational, digamma: bool, w: u64) -> (Rational, Rational) {
+    let n = usize::exact_from(w / 6) + 8;
+    let wp = w + 16 + u64::wrapping_from(n).significant_bits();
+    let target = Rational::power_of_2(-i64::exact_from(w + 8));
+    let (y_lo, y_hi) = floor_and_ceiling(Float::from_rational_prec_round_ref(
+        &z.reciprocal(),
+        wp,
+        Floor,
+    ));
+    let y2_lo = y_lo.square_prec_round_ref(wp, Floor).0;
+    let y2_hi = y_hi.square_prec_round_ref(wp, Ceiling).0;
+    // p_lo <= z ^ (-2k + 1) <= p_hi, or p_lo <= z ^ (-2k) <= p_hi
+    let (mut p_lo, mut p_hi) = if digamma {
+        (y2_lo.clone(), y2_hi.clone())
+    } else {
+        (y_lo, y_hi)
+    };
+    let mut s_lo = Rational::ZERO;
+    let mut s_hi = Rational::ZERO;
+    let mut four_k = Natural::ONE;
+    for (k, t) in (1u64..).zip(tangent_numbers(n)) {
+        four_k <<= 2;
+        // |c_k| = T_k / (4 ^ k(4 ^ k - 1)(2k - 1)) or T_k / (4 ^ k(4 ^ k - 1))
+        let mut d = &four_k * (&four_k - Natural::ONE);
+        if !digamma {
+            d *= Natural::from((k << 1) - 1);
+        }
+        let (c_lo, c_hi) = floor_and_ceiling(Float::from_rational_prec_round(
+            Rational::from_naturals(t, d),
+            wp,
+            Floor,
+        ));
+        let a_lo = Rational::exact_from(c_lo.mul_prec_round_ref_ref(&p_lo, wp, Floor).0);
+        let a_hi = Rational::exact_from(c_hi.mul_prec_round_ref_ref(&p_hi, wp, Ceiling).0);
+        let positive = k.odd();
+        if a_hi < target || k == u64::wrapping_from(n) {
+            // this is the first omitted term
+            if positive {
+                s_hi += a_hi;
+            } else {
+                s_lo -= a_hi;
+            }
+            break;
+        }
+        if positive {
+            s_lo += a_lo;
+            s_hi += a_hi;
+        } else {
+            s_lo -= a_hi;
+            s_hi -= a_lo;
+        }
+        p_lo = p_lo.mul_prec_round_ref_ref(&y2_lo, wp, Floor).0;
+        p_hi = p_hi.mul_prec_round_ref_ref(&y2_hi, wp, Ceiling).0;
+    }
+    (s_lo, s_hi)
+}
+
+// Returns the number n of steps needed to shift a positive rational `x` to at least
+// `stirling_threshold(w)`.
+fn stirling_shift(x: &Rational, w: u64) -> u64 {
+    let t = Rational::from(stirling_threshold(w));
+    if *x >= t {
+        0
+    } else {
+        u64::exact_from(&Natural::rounding_from(&(t - x), Ceiling).0)
+    }
+}
+
+// Returns x(x + 1)...(x + n - 1).
+fn rising_factorial(x: &Rational, n: u64) -> Rational {
+    let (numerator, denominator) = x.numerator_and_denominator_ref();
+    let d = Integer::from(denominator);
+    let mut a = Integer::from_sign_and_abs_ref(*x >= 0u32, numerator);
+    let mut p = Integer::ONE;
+    for _ in 0..n {
+        p *= &a;
+        a += &d;
+    }
+    Rational::from_integers(p, Integer::from(denominator.pow(n)))
+}
+
+// Returns bounds on log Γ(z), for a rational z >= `stirling_threshold(w)`, with an absolute width
+// of about `2 ^ (-w)`. This is (z - 1/2)log(z) - z + log(2π) / 2 plus Stirling's series.
+fn ln_gamma_stirling_bounds(z: &Rational, w: u64) -> (Rational, Rational) {
+    // (z - 1/2)log(z) < 2 ^ (e + k), where e is the exponent of z and k the number of significant
+    // bits of e
+    let e = u64::exact_from(rational_exponent(z));
+    let (l_lo, l_hi) = ln_bounds(z, w + 8 + e + e.significant_bits());
+    let h = z - Rational::ONE_HALF;
+    let (pi_lo, pi_hi) = pi_bounds(w + 8);
+    let c_lo = ln_bounds(&(pi_lo << 1u32), w + 8).0 >> 1u32;
+    let c_hi = ln_bounds(&(pi_hi << 1u32), w + 8).1 >> 1u32;
+    let (s_lo, s_hi) = stirling_series_bounds(z, false, w);
+    (&h * l_lo - z + c_lo + s_lo, h * l_hi - z + c_hi + s_hi)
+}
+
+// Returns bounds on log Γ(x), for a positive rational `x`, with an absolute width of about
+// `2 ^ (-w)`.
+fn ln_gamma_positive_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let n = stirling_shift(x, w);
+    if n == 0 {
+        return ln_gamma_stirling_bounds(x, w);
+    }
+    // log Γ(x) = log Γ(x + n) - log(x(x + 1)...(x + n - 1))
+    let (g_lo, g_hi) = ln_gamma_stirling_bounds(&(x + Rational::from(n)), w + 1);
+    let (p_lo, p_hi) = ln_bounds_absolute(&rising_factorial(x, n), w + 1);
+    (g_lo - p_hi, g_hi - p_lo)
+}
+
+// Returns the distance g from a non-integer rational `x` to the nearest integer, together with
+// whether x - floor(x) > 1/2.
+pub(crate) fn distance_to_integer(x: &Rational) -> (Rational, bool) {
+    let f = x - Rational::from(Integer::rounding_from(x, Floor).0);
+    if f > Rational::ONE_HALF {
+        (Rational::ONE - f, true)
+    } else {
+        (f, false)
+    }
+}
+
+// Returns bounds on |sin(πx)|, for a non-integer rational `x`, with a relative width of about
+// `2 ^ (-w)`.
+pub(crate) fn abs_sin_pi_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    // |sin(πx)| = sin(πg), where 0 < g <= 1/2 is the distance from x to the nearest integer
+    let g = distance_to_integer(x).0;
+    if g == Rational::ONE_HALF {
+        return (Rational::ONE, Rational::ONE);
+    }
+    let (pi_lo, pi_hi) = pi_bounds(w + 8);
+    let lo = sin_cos_bounds(&(&g * &pi_lo), w).0.0;
+    // sin is increasing on [0, π/2], so sin(gπ_hi) is an upper bound only if gπ_hi <= π/2
+    let g_pi_hi = g * pi_hi;
+    let hi = if (&g_pi_hi << 1u32) <= pi_lo {
+        sin_cos_bounds(&g_pi_hi, w).0.1
+    } else {
+        Rational::ONE
+    };
+    (lo, hi)
+}
+
+// Returns bounds on log |Γ(x)|, for a rational `x` that is positive or not an integer, with an
+// absolute width of about `2 ^ (-w)`.
+pub(crate) fn ln_abs_gamma_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    if *x > 0u32 {
+        return ln_gamma_positive_bounds(x, w);
+    }
+    // log |Γ(x)| = log(π) - log |sin(πx)| - log Γ(1 - x)
+    let (pi_lo, pi_hi) = pi_bounds(w + 4);
+    let l_pi_lo = ln_bounds(&pi_lo, w + 4).0;
+    let l_pi_hi = ln_bounds(&pi_hi, w + 4).1;
+    let (s_lo, s_hi) = abs_sin_pi_bounds(x, w + 4);
+    let l_s_lo = ln_bounds_absolute(&s_lo, w + 4).0;
+    let l_s_hi = ln_bounds_absolute(&s_hi, w + 4).1;
+    let (g_lo, g_hi) = ln_gamma_positive_bounds(&(Rational::ONE - x), w + 2);
+    (l_pi_lo - l_s_hi - g_hi, l_pi_hi - l_s_lo - g_lo)
+}
+
+// Returns whether Γ(x) > 0, for a rational `x` that is positive or not an integer. For x < 0, this
+// is the case when floor(x) is even.
+pub(crate) fn gamma_is_positive(x: &Rational) -> bool {
+    *x > 0u32 || Integer::rounding_from(x, Floor).0.even()
+}
+
+// Returns Γ(x) rounded, or overflow or underflow values with the appropriate sign.
+pub(crate) fn signed_extreme(
+    f: fn(u64, RoundingMode) -> (Float, Ordering),
+    positive: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if positive {
+        f(prec, rm)
+    } else {
+        let (y, o) = f(prec, -rm);
+        (-y, o.reverse())
+    }
+}
+
+// Near a pole at 0, Γ(x) = 1 / x + g and ψ(x) = -1 / x + g, with |g| < 2 and g < 0. When x = ±2 ^
+// (e - 1) is a power of 2, the reciprocal is itself a possible rounding boundary, so bracketing
+// would never terminate; instead, the sign of g determines the rounding. `negate` is `true` for ψ.
+fn round_near_power_of_2_pole(
+    x: &Argument,
+    negate: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let e = x.exponent();
+    if e >= -i64::exact_from(prec) - 8 || (&x.m).abs() != Rational::ONE_HALF {
+        return None;
+    }
+    let positive = x.is_positive() != negate;
+    // |v| = 2 ^ (1 - e), so EXP(v) = 2 - e
+    if 2 - e > Float::MAX_EXPONENT_I64 {
+        return Some(signed_extreme(exp_overflow, positive, prec, rm));
+    }
+    let v = Float::power_of_2(1 - e);
+    let v = if positive { v } else { -v };
+    // g < 0 brings v toward zero iff v > 0
+    float_round_near_x(&v, u64::exact_from(1 - e), !positive, prec, rm)
+}
+
+// Computes Γ(x).
+pub(crate) fn gamma_helper(x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let positive = x.is_positive();
+    if x.is_integer() {
+        if !positive {
+            return (Float::NAN, Equal);
+        }
+        // Γ(n) = (n - 1)!. If n - 1 > prec + 64, the odd part of (n - 1)! has more than prec + 1
+        // bits, so Γ(n) is neither exactly representable nor halfway between two representable
+        // values, and it may be rounded like the irrational values of Γ.
+        if x.exponent() <= HUGE_EXPONENT {
+            let n = u64::exact_from(&x.to_rational()) - 1;
+            if n <= prec + 64 {
+                return Float::factorial_prec_round(n, prec, rm);
+            }
+        }
+    }
+    assert_ne!(rm, Exact, "Inexact gamma");
+    let exp_x = x.exponent();
+    if exp_x > HUGE_EXPONENT {
+        if positive {
+            return exp_overflow(prec, rm);
+        }
+        // x is not an integer, so x has no more significant bits than the input
+        let x = x.to_rational();
+        // |Γ(x)| = π / (|sin(πx)|Γ(1 - x)), where |sin(πx)| >= 2 / d, d being the denominator of
+        // x, and Γ(1 - x) > Γ(2 ^ 26) > 2 ^ (1.6 * 10 ^ 9). Unless d is enormous, this is far below
+        // the smallest positive `Float`.
+        if x.denominator_ref().significant_bits() <= 1 << 28 {
+            return signed_extreme(exp_underflow, gamma_is_positive(&x), prec, rm);
+        }
+        let positive = gamma_is_positive(&x);
+        let n = exp_shift(&ln_abs_gamma_bounds(&x, 16).0);
+        return round_with_scaled_bounds(n, prec, rm, |w| {
+            exp_scaled_bounds(ln_abs_gamma_bounds(&x, w + 4), n, positive, w)
+        });
+    }
+    if exp_x < 0 {
+        // For 0 < |x| < 1/2, 1 / x - 2 < Γ(x) < 1 / x. Once |x| is small enough, these bounds
+        // suffice, and they don't require the exact x. The bounds are on Γ(x) / 2 ^ (-e) =
+        // Γ(x) * 2 ^ e, where x = m * 2 ^ e.
+        if let Some(result) = round_near_power_of_2_pole(x, false, prec, rm) {
+            return result;
+        }
+        let m_inv = (&x.m).reciprocal();
+        return round_with_scaled_bounds(-exp_x, prec, rm, |w| {
+            if exp_x < -i64::exact_from(w + 9) {
+                (
+                    &m_inv - Rational::power_of_2(-i64::exact_from(w + 8)),
+                    m_inv.clone(),
+                )
+            } else {
+                let x = x.to_rational();
+                exp_scaled_bounds(
+                    ln_abs_gamma_bounds(&x, w + 4),
+                    -exp_x,
+                    gamma_is_positive(&x),
+                    w,
+                )
+            }
+        });
+    }
+    let x = x.to_rational();
+    let positive = gamma_is_positive(&x);
+    let n = exp_shift(&ln_abs_gamma_bounds(&x, 16).0);
+    round_with_scaled_bounds(n, prec, rm, |w| {
+        exp_scaled_bounds(ln_abs_gamma_bounds(&x, w + 4), n, positive, w)
+    })
+}
+
+// Computes log |Γ(x)|, returning the sign of Γ(x) as well: `Greater` if it is positive and `Less`
+// if it is negative.
+pub(crate) fn ln_gamma_helper(
+    x: &Argument,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering, Ordering) {
+    let positive = x.is_positive();
+    let exp_x = x.exponent();
+    if x.is_integer() {
+        if !positive {
+            return (Float::INFINITY, Greater, Equal);
+        }
+        // log Γ(1) = log Γ(2) = 0
+        if exp_x <= 2 && x.to_rational() <= 2u32 {
+            return (Float::ZERO, Greater, Equal);
+        }
+    }
+    assert_ne!(rm, Exact, "Inexact ln_gamma");
+    // If x is negative and not an integer, x has no more significant bits than the input. If also
+    // |x| < 1, then Γ(x) < 0.
+    let sign = if positive || exp_x > 0 && gamma_is_positive(&x.to_rational()) {
+        Greater
+    } else {
+        Less
+    };
+    let (y, o) = if exp_x < 0 {
+        // log |Γ(x)| = -log |x| + log Γ(1 + x), and |log Γ(1 + x)| < |x| for 0 < |x| < 1/2. Once
+        // |x| is small enough, the bounds -log |x| ± 2 ^ (-w - 8) suffice, and they don't require
+        // the exact x.
+        round_with_bounds(prec, rm, |w| {
+            Some(if exp_x < -i64::exact_from(w + 8) {
+                let (l_lo, l_hi) = x.ln_abs_bounds(w + 8);
+                let t = Rational::power_of_2(-i64::exact_from(w + 8));
+                (-l_hi - &t, t - l_lo)
+            } else {
+                ln_abs_gamma_bounds(&x.to_rational(), w)
+            })
+        })
+    } else if positive && exp_x > 80 {
+        // For huge x, log Γ(x) = x(log(x) - 1) + r, with |r| < 2 ^ k, where k is the number of
+        // significant bits of e. The bounds are on log Γ(x) / 2 ^ e = m(log(x) - 1) + r / 2 ^ e,
+        // and once e is large enough, they don't require the exact x.
+        round_with_scaled_bounds(exp_x, prec, rm, |w| {
+            if exp_x > i64::exact_from(w + 80) {
+                let (l_lo, l_hi) = x.ln_abs_bounds(w + 9);
+                let t = Rational::power_of_2(-i64::exact_from(w + 8));
+                (
+                    &x.m * (l_lo - Rational::ONE) - &t,
+                    &x.m * (l_hi - Rational::ONE) + t,
+                )
+            } else {
+                let (lo, hi) = ln_abs_gamma_bounds(&x.to_rational(), w + 8);
+                (lo >> exp_x, hi >> exp_x)
+            }
+        })
+    } else {
+        let x = x.to_rational();
+        round_with_bounds(prec, rm, |w| Some(ln_abs_gamma_bounds(&x, w)))
+    };
+    (y, sign, o)
+}
+
+// Returns bounds on ψ(z), for a rational z >= `stirling_threshold(w)`, with an absolute width of
+// about `2 ^ (-w)`. This is log(z) - 1 / (2z) minus Stirling's series.
+fn digamma_stirling_bounds(z: &Rational, w: u64) -> (Rational, Rational) {
+    let (l_lo, l_hi) = ln_bounds_absolute(z, w + 2);
+    let (s_lo, s_hi) = stirling_series_bounds(z, true, w);
+    let h = (z << 1u32).reciprocal();
+    (l_lo - &h - s_hi, l_hi - h - s_lo)
+}
+
+// Returns bounds on ψ(x), for a positive rational `x`, with an absolute width of about `2 ^ (-w)`.
+fn digamma_positive_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let n = stirling_shift(x, w);
+    if n == 0 {
+        return digamma_stirling_bounds(x, w);
+    }
+    // ψ(x) = ψ(x + n) - (1 / x + 1 / (x + 1) + ... + 1 / (x + n - 1)). Each term of the sum is
+    // less than 1 / x, so computing them with a relative error of 2 ^ (-wp) gives an absolute
+    // error of less than 2 ^ (-w - 2).
+    let (g_lo, g_hi) = digamma_stirling_bounds(&(x + Rational::from(n)), w + 1);
+    let exp_x = rational_exponent(x);
+    let wp = w + 4 + n.significant_bits() + u64::exact_from((1 - exp_x).max(0));
+    let mut h_lo = Rational::ZERO;
+    let mut h_hi = Rational::ZERO;
+    let mut y = x.clone();
+    for _ in 0..n {
+        let (r_lo, r_hi) = floor_and_ceiling(Float::from_rational_prec_round_ref(
+            &(&y).reciprocal(),
+            wp,
+            Floor,
+        ));
+        h_lo += Rational::exact_from(r_lo);
+        h_hi += Rational::exact_from(r_hi);
+        y += Rational::ONE;
+    }
+    (g_lo - h_hi, g_hi - h_lo)
+}
+
+// Returns bounds on π cot(πx), for a non-integer rational `x`, with an absolute width of about
+// `2 ^ (-w)`.
+fn pi_cot_pi_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    // cot(πx) = cot(πg) if x - floor(x) <= 1/2, and -cot(πg) otherwise, where g is the distance
+    // from x to the nearest integer
+    let (g, negative) = distance_to_integer(x);
+    if g == Rational::ONE_HALF {
+        return (Rational::ZERO, Rational::ZERO);
+    }
+    // |π cot(πg)| < 1 / g < 2 ^ (1 - e), where e is the exponent of g
+    let wc = w + 4 + u64::exact_from(1 - rational_exponent(&g));
+    let (pi_lo, pi_hi) = pi_bounds(wc);
+    let cot = |y: &Rational| {
+        let (sin, cos) = sin_cos_bounds(y, wc);
+        div_bounds(&cos, &sin).unwrap()
+    };
+    // cot is decreasing on (0, π)
+    let c_lo = cot(&(&g * &pi_hi)).0;
+    let c_hi = cot(&(&g * &pi_lo)).1;
+    let (lo, hi) = mul_bounds(&(pi_lo, pi_hi), &(c_lo, c_hi));
+    if negative { (-hi, -lo) } else { (lo, hi) }
+}
+
+// Returns bounds on ψ(x), for a rational `x` that is positive or not an integer, with an absolute
+// width of about `2 ^ (-w)`.
+pub(crate) fn digamma_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    if *x > 0u32 {
+        return digamma_positive_bounds(x, w);
+    }
+    // ψ(x) = ψ(1 - x) - π cot(πx)
+    let (g_lo, g_hi) = digamma_positive_bounds(&(Rational::ONE - x), w + 1);
+    let (c_lo, c_hi) = pi_cot_pi_bounds(x, w + 1);
+    (g_lo - c_hi, g_hi - c_lo)
+}
+
+// Computes ψ(x).
+pub(crate) fn digamma_helper(x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let positive = x.is_positive();
+    if !positive && x.is_integer() {
+        return (Float::NAN, Equal);
+    }
+    assert_ne!(rm, Exact, "Inexact digamma");
+    let exp_x = x.exponent();
+    if exp_x < 0 {
+        // ψ(x) = -1 / x + ψ(1 + x), and |ψ(1 + x)| < 2 for 0 < |x| < 1/2. Once |x| is small enough,
+        // the bounds -1 / x ± 2 ^ (-w - 8) on ψ(x) * 2 ^ e suffice, where x = m * 2 ^ e, and they
+        // don't require the exact x.
+        if let Some(result) = round_near_power_of_2_pole(x, true, prec, rm) {
+            return result;
+        }
+        let m_inv = -(&x.m).reciprocal();
+        round_with_scaled_bounds(-exp_x, prec, rm, |w| {
+            if exp_x < -i64::exact_from(w + 9) {
+                let t = Rational::power_of_2(-i64::exact_from(w + 8));
+                (&m_inv - &t, &m_inv + t)
+            } else {
+                let (lo, hi) = digamma_bounds(&x.to_rational(), w + 8);
+                (lo << exp_x, hi << exp_x)
+            }
+        })
+    } else if positive && exp_x > 16 {
+        // For x > 0, log(x) - 1 / x < ψ(x) < log(x). Once x is large enough, these bounds suffice,
+        // and they don't require the exact x.
+        round_with_bounds(prec, rm, |w| {
+            Some(if exp_x > i64::exact_from(w + 10) {
+                let (l_lo, l_hi) = x.ln_abs_bounds(w + 8);
+                (l_lo - Rational::power_of_2(-i64::exact_from(w + 8)), l_hi)
+            } else {
+                digamma_bounds(&x.to_rational(), w)
+            })
+        })
+    } else {
+        // If x is negative, it is not an integer, so x has no more significant bits than the input
+        let x = x.to_rational();
+        round_with_bounds(prec, rm, |w| Some(digamma_bounds(&x, w)))
+    }
+}
+
+// Returns the sign of ψ(x) + γ = ψ(x) - ψ(1), for a `Float` x that is not 1 and not an integer
+// that is not positive, if its absolute value is at least 2 ^ `threshold`; otherwise, returns
+// `None`. ψ is increasing on the positive reals, so for x > 0 the sign is that of x - 1, and for -1
+// / 2 < x < 0, ψ(x) + γ = ψ(1 + x) + γ - 1 / x > ψ(1 / 2) + γ + 2 > 0.
+fn digamma_plus_gamma_is_positive(x: &Float, threshold: i64) -> Option<bool> {
+    if *x > 0u32 {
+        return Some(*x > 1u32);
+    }
+    if x.get_exponent().unwrap() < 0 {
+        return Some(true);
+    }
+    let x = Rational::exact_from(x);
+    let mut w = 64;
+    loop {
+        let (lo, hi) = digamma_bounds(&x, w);
+        let (c_lo, c_hi) = digamma_bounds(&Rational::ONE, w);
+        let (lo, hi) = (lo - c_hi, hi - c_lo);
+        if lo > 0u32 && (&lo).floor_log_base_2() >= threshold {
+            return Some(true);
+        } else if hi < 0u32 && (-hi).floor_log_base_2() >= threshold {
+            return Some(false);
+        } else if i64::exact_from(w) > -threshold {
+            return None;
+        }
+        w <<= 1;
+    }
+}
+
+// Handles B(x, y) for finite, nonzero `Float`s x and y when |y| is much smaller than |x| and tiny,
+// or when both are tiny. Converting such arguments to `Rational`s, and adding them, may produce
+// enormous denominators, so only their significands and exponents are used here. Returns `None` if
+// neither case applies, or if the larger argument is an integer that is not positive.
+//
+// B(x, y) = (1 / x + 1 / y) * F, where F = Γ(1 + x)Γ(1 + y) / Γ(1 + x + y).
+// - If y is tiny and |y| is much smaller than the distance from x to the poles of ψ, then B(x, y) *
+//   y = Γ(1 + y)Γ(x) / Γ(x + y) = exp(-γy - yψ(ξ) + O(y ^ 2)) for some ξ between x and x + y, and
+//   |yψ(ξ)| is small; so B(x, y) is close to 1 / y.
+// - If |x| and |y| are both less than 1/8, log F = -xy(ζ(2) + δ) with |δ| < 4max(|x|, |y|), so F is
+//   close to 1 when xy is tiny.
+pub(crate) fn beta_near_pole(
+    x: &Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let (x, y) = if x.get_exponent() >= y.get_exponent() {
+        (x, y)
+    } else {
+        (y, x)
+    };
+    let x_arg = Argument::from_float(x);
+    let y_arg = Argument::from_float(y);
+    let ex = x_arg.exponent();
+    let ey = y_arg.exponent();
+    let px = i64::exact_from(x.get_prec().unwrap());
+    let py = i64::exact_from(y.get_prec().unwrap());
+    let p = i64::exact_from(prec);
+    if ey > -p - py - 24 || x_arg.is_integer() && !x_arg.is_positive() {
+        return None;
+    }
+    let y_positive = y_arg.is_positive();
+    // |ψ(ξ)| < 2 ^ k for all ξ within |y| of x
+    let mut k = if ex > 0 {
+        i64::exact_from(ex.significant_bits()) + 2
+    } else {
+        3 - ex
+    };
+    if !x_arg.is_positive() && ex >= 0 {
+        // ψ(ξ) = ψ(1 - ξ) - π cot(πξ), and |π cot(πξ)| is at most the reciprocal of the distance
+        // from ξ to the nearest integer
+        let d = distance_to_integer(&Rational::exact_from(x)).0;
+        k = max!(k, 1 - d.floor_log_base_2()) + 1;
+    }
+    if ey + k <= -p - py - 25 {
+        // |log(B(x, y) * y)| < 2 ^ (ey + k + 1), which is below 2 ^ (-w - 8)
+        if *x == 1u32 {
+            return Some(y.reciprocal_prec_round_ref(prec, rm));
+        }
+        assert_ne!(rm, Exact, "Inexact beta");
+        if (&y_arg.m).abs() == Rational::ONE_HALF {
+            // 1 / y = ±2 ^ (1 - ey) is itself a possible rounding boundary. log(B(x, y) * y) =
+            // -y(ψ(x) + γ) + ε with |ε| < 2 ^ (2ey + 2k + 1), so the sign of ψ(x) + γ determines
+            // whether |B(x, y)| > |1 / y|.
+            let away = y_positive != digamma_plus_gamma_is_positive(x, ey + 2 * k + 2)?;
+            if 2 - ey > Float::MAX_EXPONENT_I64 {
+                return Some(signed_extreme(exp_overflow, y_positive, prec, rm));
+            }
+            let v = Float::power_of_2(1 - ey);
+            let v = if y_positive { v } else { -v };
+            return float_round_near_x(&v, u64::exact_from(-ey - k - 1), away, prec, rm);
+        }
+        // Otherwise, 1 / m_y is at a relative distance of at least 2 ^ (-prec - py - 1) from any
+        // rounding boundary
+        let w = u64::exact_from(p + py + 16);
+        let m_inv = (&y_arg.m).reciprocal();
+        let lo = &m_inv * (Rational::ONE - Rational::power_of_2(-i64::exact_from(w + 8)));
+        let hi = &m_inv * (Rational::ONE + Rational::power_of_2(-i64::exact_from(w + 7)));
+        let (lo, hi) = if y_positive { (lo, hi) } else { (hi, lo) };
+        return Some(round_scaled_bracket(&lo, &hi, -ey, prec, rm).unwrap());
+    }
+    if ex > -3 {
+        return None;
+    }
+    // Now k = 3 - ex, so d = ex - ey < prec + py + 28, and 1 / x + 1 / y = 2 ^ (-ey) * c, where c =
+    // 2 ^ (-d) / m_x + 1 / m_y has a moderate size
+    let d = ex - ey;
+    if ex + ey > -p - px - py - d - 26 {
+        return None;
+    }
+    let c = (Rational::power_of_2(-d) / &x_arg.m) + (&y_arg.m).reciprocal();
+    if c == 0u32 {
+        // x + y = 0, and x is not an integer, so Γ(x + y) is infinite
+        return Some((Float::ZERO, Equal));
+    }
+    assert_ne!(rm, Exact, "Inexact beta");
+    let c_positive = c > 0u32;
+    if c.denominator_ref().is_power_of_2() {
+        // 1 / x + 1 / y is a possible rounding boundary, and F < 1 exactly when xy > 0
+        let exp_c = (&c).abs().floor_log_base_2() + 1 - ey;
+        if exp_c > Float::MAX_EXPONENT_I64 {
+            return Some(signed_extreme(exp_overflow, c_positive, prec, rm));
+        }
+        let v = Float::exact_from(c) << -ey;
+        let away = x_arg.is_positive() != y_positive;
+        return float_round_near_x(&v, u64::exact_from(-ex - ey - 3), away, prec, rm);
+    }
+    // c is at a relative distance of at least 2 ^ (-prec - px - py - d - 1) from any rounding
+    // boundary, and |log F| < 2 ^ (ex + ey + 2)
+    let w = u64::exact_from(p + px + py + d + 16);
+    let lo = &c * (Rational::ONE - Rational::power_of_2(-i64::exact_from(w + 8)));
+    let hi = &c * (Rational::ONE + Rational::power_of_2(-i64::exact_from(w + 7)));
+    let (lo, hi) = if c_positive { (lo, hi) } else { (hi, lo) };
+    Some(round_scaled_bracket(&lo, &hi, -ey, prec, rm).unwrap())
+}
+
+// Computes B(x, y) = Γ(x)Γ(y) / Γ(x + y), for nonzero rationals `x` and `y`. At the poles of Γ,
+// this follows MPFR's conventions.
+pub(crate) fn beta_rational_helper(
+    x: &Rational,
+    y: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if x < y {
+        return beta_rational_helper(y, x, prec, rm);
+    }
+    let x_integer = x.is_integer();
+    let y_integer = y.is_integer();
+    if y_integer && *y < 0u32 {
+        // B(x, y) is only defined when x is a positive integer with x + y <= 0; then B(x, y) = 1 /
+        // x if x + y = 0, and (-1) ^ x * B(x, 1 - x - y) otherwise
+        if *x < 0u32 || !x_integer {
+            return (Float::NAN, Equal);
+        }
+        let s = x + y;
+        if s > 0u32 {
+            return (Float::NAN, Equal);
+        }
+        if s == 0u32 {
+            return Float::from_rational_prec_round(x.reciprocal(), prec, rm);
+        }
+        let t = Rational::ONE - s;
+        return if x.numerator_ref().odd() {
+            let (b, o) = beta_rational_helper(x, &t, prec, -rm);
+            (-b, o.reverse())
+        } else {
+            beta_rational_helper(x, &t, prec, rm)
+        };
+    }
+    if x_integer && *x < 0u32 {
+        return (Float::NAN, Equal);
+    }
+    let s = x + y;
+    // If x + y is 0 or a negative integer, but x and y are not integers, then Γ(x + y) is infinite
+    // while Γ(x) and Γ(y) are finite
+    if !y_integer && s <= 0u32 && s.is_integer() {
+        return (Float::ZERO, Equal);
+    }
+    // If one argument is a positive integer n, then B(n, z) = (n - 1)! / (z(z + 1)...(z + n - 1)),
+    // a rational number. When n > prec + 64, it is not a dyadic rational with at most prec + 1
+    // significant bits, so it may be rounded like the irrational values of B.
+    let n_z = if y_integer && *y > 0u32 {
+        Some((y, x))
+    } else if x_integer {
+        Some((x, y))
+    } else {
+        None
+    };
+    if let Some((n, z)) = n_z
+        && *n <= prec + 64
+    {
+        let n = u64::exact_from(n);
+        return Float::from_rational_prec_round(
+            Rational::from(Natural::factorial(n - 1)) / rising_factorial(z, n),
+            prec,
+            rm,
+        );
+    }
+    assert_ne!(rm, Exact, "Inexact beta");
+    // x, y, and x + y are each positive or not an integer
+    let positive = (gamma_is_positive(x) == gamma_is_positive(y)) == gamma_is_positive(&s);
+    let ln_bounds = |w| {
+        let (a_lo, a_hi) = ln_abs_gamma_bounds(x, w + 2);
+        let (b_lo, b_hi) = ln_abs_gamma_bounds(y, w + 2);
+        let (c_lo, c_hi) = ln_abs_gamma_bounds(&s, w + 2);
+        (a_lo + b_lo - c_hi, a_hi + b_hi - c_lo)
+    };
+    // Unlike Γ, B may overflow or underflow far beyond the exponent range, for example when x and
+    // y are both huge, so the scale is checked before it is converted to an i64
+    let ln_2 = Rational::exact_from(Float::ln_2_prec_round(64, Floor).0);
+    let k = Integer::rounding_from(ln_bounds(16).0 / ln_2, Floor).0;
+    if k >= Float::MAX_EXPONENT_I64 + 2 {
+        return signed_extreme(exp_overflow, positive, prec, rm);
+    } else if k < Float::MIN_EXPONENT_I64 - 4 {
+        return signed_extreme(exp_underflow, positive, prec, rm);
+    }
+    let n = i64::exact_from(&k);
+    round_with_scaled_bounds(n, prec, rm, |w| {
+        exp_scaled_bounds(ln_bounds(w + 4), n, positive, w)
+    })
+}
+
+impl Float {
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded gamma function is less
+    /// than, equal to, or greater than the exact gamma function. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \Gamma(x)+\varepsilon.
+    /// $$
+    /// - If $\Gamma(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\Gamma(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\Gamma(x)|\rfloor-p+1}$.
+    /// - If $\Gamma(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\Gamma(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(-\infty,p,m)=\text{NaN}$
+    /// - $f(x,p,m)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    ///
+    /// The result overflows when $x$ is greater than about $4.48\times 10^7$, or when $|x|$ is less
+    /// than about $2^{-2^{30}+1}$, which is only a few times the smallest positive [`Float`]. It
+    /// underflows when $x$ is less than about $-4.48\times 10^7$, unless $x$ is extremely close to
+    /// an integer:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::gamma_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::gamma_round`] instead. If both of these things are true, consider using
+    /// [`Float::gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than positive
+    /// integers $n$ for which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round(5, Floor);
+    /// assert_eq!(gamma.to_string(), "2.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round(5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.38");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round(5, Nearest);
+    /// assert_eq!(gamma.to_string(), "2.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round(20, Floor);
+    /// assert_eq!(gamma.to_string(), "2.2880363");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round(20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.2880402");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round(20, Nearest);
+    /// assert_eq!(gamma.to_string(), "2.2880363");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn gamma_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.gamma_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded gamma function
+    /// is less than, equal to, or greater than the exact gamma function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::gamma_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::gamma_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).gamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than positive
+    /// integers $n$ for which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round_ref(5, Floor);
+    /// assert_eq!(gamma.to_string(), "2.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round_ref(5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.38");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round_ref(5, Nearest);
+    /// assert_eq!(gamma.to_string(), "2.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round_ref(20, Floor);
+    /// assert_eq!(gamma.to_string(), "2.2880363");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round_ref(20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.2880402");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_round_ref(20, Nearest);
+    /// assert_eq!(gamma.to_string(), "2.2880363");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn gamma_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { sign: false } => (Self::NAN, Equal),
+            Infinity { sign: true } => (Self::INFINITY, Equal),
+            Zero { sign } => (
+                if *sign {
+                    Self::INFINITY
+                } else {
+                    Self::NEGATIVE_INFINITY
+                },
+                Equal,
+            ),
+            Finite { .. } => gamma_helper(&Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded gamma function is less than, equal to, or greater
+    /// than the exact gamma function. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the gamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::gamma_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec(5);
+    /// assert_eq!(gamma.to_string(), "2.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec(20);
+    /// assert_eq!(gamma.to_string(), "2.2880363");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn gamma_prec(self, prec: u64) -> (Self, Ordering) {
+        self.gamma_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded gamma function is less than, equal to, or
+    /// greater than the exact gamma function. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the gamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::gamma_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).gamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_ref(5);
+    /// assert_eq!(gamma.to_string(), "2.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).gamma_prec_ref(20);
+    /// assert_eq!(gamma.to_string(), "2.2880363");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn gamma_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.gamma_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], rounding the result to the
+    /// precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded gamma function is
+    /// less than, equal to, or greater than the exact gamma function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::gamma_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than positive integers $n$ for
+    /// which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(-PI).gamma_round(Floor);
+    /// assert_eq!(gamma.to_string(), "1.0156971444602192");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(-PI).gamma_round(Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0156971444602210");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(-PI).gamma_round(Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0156971444602192");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn gamma_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.gamma_prec_round(prec, rm)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], rounding the result to the
+    /// precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded gamma function
+    /// is less than, equal to, or greater than the exact gamma function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::gamma_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).gamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than positive integers $n$ for
+    /// which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(-PI).gamma_round_ref(Floor);
+    /// assert_eq!(gamma.to_string(), "1.0156971444602192");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(-PI).gamma_round_ref(Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0156971444602210");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(-PI).gamma_round_ref(Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0156971444602192");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn gamma_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.gamma_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], in place, rounding the result to
+    /// the specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded gamma function is less than, equal to, or greater than the
+    /// exact gamma function. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::gamma_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::gamma_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::gamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than positive
+    /// integers $n$ for which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.gamma_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "2.2880363");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.gamma_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "2.2880402");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.gamma_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "2.2880363");
+    /// ```
+    #[inline]
+    pub fn gamma_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.gamma_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], in place, rounding the result to
+    /// the nearest value of the specified precision. An [`Ordering`] is returned, indicating
+    /// whether the rounded gamma function is less than, equal to, or greater than the exact gamma
+    /// function. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the gamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::gamma_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::gamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.gamma_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "2.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.gamma_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "2.2880363");
+    /// ```
+    #[inline]
+    pub fn gamma_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.gamma_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], in place, rounding the result to
+    /// the precision of the input and with the specified rounding mode. An [`Ordering`] is
+    /// returned, indicating whether the rounded gamma function is less than, equal to, or greater
+    /// than the exact gamma function. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::gamma_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::gamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than positive integers $n$ for
+    /// which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.gamma_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.0156971444602192");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.gamma_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.0156971444602210");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.gamma_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.0156971444602192");
+    /// ```
+    #[inline]
+    pub fn gamma_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.gamma_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Rational`], rounding the result to the
+    /// specified precision and with the specified rounding mode and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded gamma function is less than, equal to, or greater than the exact gamma
+    /// function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \Gamma(x)+\varepsilon.
+    /// $$
+    /// - If $\Gamma(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\Gamma(x)|\rfloor-p+1}$.
+    /// - If $\Gamma(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\Gamma(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(0,p,m)=\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::gamma_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than positive
+    /// integers $n$ for which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.75");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "2.6789360");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.6789398");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn gamma_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::gamma_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Rational`], rounding the result to the
+    /// specified precision and with the specified rounding mode and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded gamma function is less than, equal to, or greater than the
+    /// exact gamma function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \Gamma(x)+\varepsilon.
+    /// $$
+    /// - If $\Gamma(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\Gamma(x)|\rfloor-p+1}$.
+    /// - If $\Gamma(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\Gamma(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(0,p,m)=\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::gamma_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than positive
+    /// integers $n$ for which $(n-1)!$ is representable, and inputs that produce `NaN`).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.75");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "2.6789360");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "2.6789398");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn gamma_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::INFINITY, Equal);
+        }
+        gamma_helper(&Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Rational`], rounding the result to the
+    /// nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded gamma function is less than, equal to, or greater than the exact gamma function.
+    ///
+    /// If the gamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::gamma_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(gamma.to_string(), "2.6789398");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn gamma_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::gamma_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Rational`], rounding the result to the
+    /// nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded gamma function is less than, equal to, or greater than the exact gamma function.
+    ///
+    /// If the gamma function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::gamma_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::gamma_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(gamma.to_string(), "2.6789398");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn gamma_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::gamma_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Gamma for Float {
+    type Output = Self;
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the gamma function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \Gamma(x)+\varepsilon.
+    /// $$
+    /// - If $\Gamma(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\Gamma(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=-\infty$
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::gamma_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::gamma_round`]. If you want both of these things, consider using
+    /// [`Float::gamma_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gamma;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.gamma().is_nan());
+    /// assert!(Float::NEGATIVE_INFINITY.gamma().is_nan());
+    /// assert_eq!(Float::ZERO.gamma(), Float::INFINITY);
+    /// assert_eq!(Float::from(PI).gamma().to_string(), "2.2880377953400313");
+    /// assert_eq!(Float::from(-PI).gamma().to_string(), "1.0156971444602192");
+    /// ```
+    #[inline]
+    fn gamma(self) -> Self {
+        let prec = self.significant_bits();
+        self.gamma_prec_round(prec, Nearest).0
+    }
+}
+
+impl Gamma for &Float {
+    type Output = Float;
+
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the gamma function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \Gamma(x)+\varepsilon.
+    /// $$
+    /// - If $\Gamma(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\Gamma(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+    /// - $f(\infty)=\infty$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=-\infty$
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::gamma_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::gamma_round_ref`]. If you want both of these things, consider using
+    /// [`Float::gamma_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gamma;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).gamma().is_nan());
+    /// assert!((&Float::NEGATIVE_INFINITY).gamma().is_nan());
+    /// assert_eq!((&Float::ZERO).gamma(), Float::INFINITY);
+    /// assert_eq!((&Float::from(PI)).gamma().to_string(), "2.2880377953400313");
+    /// assert_eq!((&Float::from(-PI)).gamma().to_string(), "1.0156971444602192");
+    /// ```
+    #[inline]
+    fn gamma(self) -> Float {
+        let prec = self.significant_bits();
+        self.gamma_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl GammaAssign for Float {
+    /// Computes $\Gamma(x)$, the gamma function of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the gamma function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \Gamma(x)+\varepsilon.
+    /// $$
+    /// - If $\Gamma(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\Gamma(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GammaAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.gamma_assign();
+    /// assert_eq!(x.to_string(), "2.2880377953400313");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// x.gamma_assign();
+    /// assert_eq!(x.to_string(), "1.0156971444602192");
+    /// ```
+    #[inline]
+    fn gamma_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.gamma_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\Gamma(x)$, the gamma function of a primitive float, returning a correctly-rounded
+/// result.
+///
+/// $$
+/// f(x) = \Gamma(x)+\varepsilon.
+/// $$
+/// - If $\Gamma(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\Gamma(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\Gamma(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+/// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+/// - $f(\infty)=\infty$
+/// - $f(0.0)=\infty$
+/// - $f(-0.0)=-\infty$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::basic::traits::NegativeInfinity;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::gamma::primitive_float_gamma;
+///
+/// assert!(primitive_float_gamma(f64::NAN).is_nan());
+/// assert!(primitive_float_gamma(-1.0f64).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_gamma(-0.0f64)), NiceFloat(f64::NEGATIVE_INFINITY));
+/// assert_eq!(NiceFloat(primitive_float_gamma(PI)), NiceFloat(2.288037795340032));
+/// assert_eq!(NiceFloat(primitive_float_gamma(-1.5f32)), NiceFloat(2.3632717));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_gamma<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::gamma_prec, x)
+}
+
+/// Computes $\Gamma(x)$, the gamma function of a [`Rational`], returning the result as a primitive
+/// float.
+///
+/// $$
+/// f(x) = \Gamma(x)+\varepsilon.
+/// $$
+/// - If $\Gamma(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\Gamma(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\Gamma(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x)=\text{NaN}$ if $x$ is a negative integer
+/// - $f(0)=\infty$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::gamma::primitive_float_gamma_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_gamma_rational::<f64>(&Rational::from_unsigneds(1u8, 3))), NiceFloat(2.6789385347077475));
+/// assert_eq!(NiceFloat(primitive_float_gamma_rational::<f64>(&Rational::from(100))), NiceFloat(9.332621544394415e155));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_gamma_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::gamma_rational_prec_ref, x)
+}
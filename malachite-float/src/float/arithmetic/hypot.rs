@@ -142,7 +142,7 @@ fn hypot_prec_round_helper(x: &Float, y: &Float, prec: u64, rm: RoundingMode) ->
     // and `Exact` always panics. The C code hand-rolls the rounding (including the round-up-on-tie
     // behavior under Nearest and the add-one-ulp adjustments); float_round_near_x is the same
     // computation.
-    let threshold = (max(px, prec) + u64::from(rm == Nearest)) << 1;
+    let threshold = (max(px, prec) + u64::from(rm == Nearest || rm == NearestTiesAway)) << 1;
     if diff_exp > threshold {
         assert_ne!(rm, Exact, "Inexact Float hypot");
         // Only take the absolute value if it actually changes anything: `Abs` on a reference copies
@@ -222,10 +222,10 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -237,10 +237,10 @@ impl Float {
     /// The result is never negative, and a zero result is always positive.
     ///
     /// Overflow:
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the output.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,y,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the output.
     ///
     /// Underflow is not possible, since the hypotenuse is at least as large as the absolute value
     /// of each argument.
@@ -321,10 +321,10 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -412,10 +412,10 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -502,10 +502,10 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -822,12 +822,12 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
     ///
     /// See the [`Float::hypot_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -887,12 +887,12 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
     ///
     /// See the [`Float::hypot_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -952,12 +952,12 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
     ///
     /// See the [`Float::hypot_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -1017,12 +1017,12 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
     ///
     /// See the [`Float::hypot_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -1082,10 +1082,10 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1167,10 +1167,10 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1382,12 +1382,12 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
     ///
     /// See the [`Float::hypot_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
@@ -1448,12 +1448,12 @@ impl Float {
     /// $$
     /// - If $\sqrt{x^2+y^2}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
     ///   to be 0.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$, where $p$ is the maximum precision of the
-    ///   inputs.
-    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum precision of the
-    ///   inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p+1}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    /// - If $\sqrt{x^2+y^2}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \sqrt{x^2+y^2}\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
     ///
     /// See the [`Float::hypot_prec_round`] documentation for information on special cases,
     /// overflow, and underflow.
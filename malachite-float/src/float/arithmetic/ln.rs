@@ -101,10 +101,11 @@ pub(crate) enum SliverOfOne {
     No,
     // `x = 1 + d` with `d` representable; compute the logarithm via the `1_plus_x` form.
     Representable(Float),
-    // `x` is so close to 1 that its logarithm falls at or below the smallest positive `Float`. The
-    // subtraction `x - 1` cannot be represented (it flushes to zero for `x > 1`, or clamps to the
-    // minimum magnitude for `x < 1`), so the caller computes the underflowing result via the
-    // exact-`Rational` logarithm of `x` (whose Rational-argument path has no exponent range).
+    // `x` is so close to 1 that its logarithm falls at or below the smallest positive `Float`.
+    // The subtraction `x - 1` cannot be represented (it flushes to zero for `x > 1`, or
+    // clamps to the minimum magnitude for `x < 1`), so the caller computes the underflowing
+    // result via the exact-`Rational` logarithm of `x` (whose Rational-argument path has no
+    // exponent range).
     Underflow,
 }
 
@@ -545,10 +546,10 @@ impl Float {
     /// f(x,p,m) = \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -646,10 +647,10 @@ impl Float {
     /// f(x,p,m) = \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -866,10 +867,12 @@ impl Float {
     /// f(x,m) = \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$, where $p$ is the precision
+    ///   of the input.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$, where $p$ is the precision of
+    ///   the input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -935,10 +938,12 @@ impl Float {
     /// f(x,m) = \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$, where $p$ is the precision
+    ///   of the input.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$, where $p$ is the precision of
+    ///   the input.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1007,10 +1012,10 @@ impl Float {
     /// x \gets \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |xy|\rfloor-p+1}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1142,10 +1147,12 @@ impl Float {
     /// x \gets \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 \|ln{x}|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 \|ln{x}|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1206,10 +1213,10 @@ impl Float {
     /// f(x,p,m) = \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\ln{x}|\rfloor-p+1}$.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\ln{x}|\rfloor-p}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\ln{x}|\rfloor-p+1}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\ln{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1284,10 +1291,10 @@ impl Float {
     /// f(x,p,m) = \ln{x}+\varepsilon.
     /// $$
     /// - If $\ln{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\ln{x}|\rfloor-p+1}$.
-    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\ln{x}|\rfloor-p}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\ln{x}|\rfloor-p+1}$.
+    /// - If $\ln{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\ln{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1505,10 +1512,10 @@ impl Float {
     /// f(n,p,m) = \ln{n}+\varepsilon.
     /// $$
     /// - If $\ln{n}$ is infinite or zero, $\varepsilon$ may be ignored or assumed to be 0.
-    /// - If $\ln{n}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 (\ln{n})\rfloor-p+1}$.
-    /// - If $\ln{n}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 (\ln{n})\rfloor-p}$.
+    /// - If $\ln{n}$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 (\ln{n})\rfloor-p+1}$.
+    /// - If $\ln{n}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 (\ln{n})\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
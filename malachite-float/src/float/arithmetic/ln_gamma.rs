@@ -0,0 +1,1115 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::gamma::{Argument, ln_gamma_helper};
+use crate::{Float, emulate_float_to_float_and_sign_fn, emulate_rational_to_float_and_sign_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{LnGamma, LnGammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], rounding the result to the specified precision and with the specified
+    /// rounding mode. The [`Float`] is taken by value. Two [`Ordering`]s are also returned: the
+    /// first is the sign of $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative,
+    /// and the second indicates whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln|\Gamma(x)|+\varepsilon.
+    /// $$
+    /// - If $\ln|\Gamma(x)|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln|\Gamma(x)||\rfloor-p+1}$.
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\ln|\Gamma(x)||\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\infty$
+    /// - $f(\pm0.0,p,m)=\infty$
+    /// - $f(x,p,m)=\infty$ if $x$ is a negative integer
+    /// - $f(1.0,p,m)=f(2.0,p,m)=0.0$
+    ///
+    /// When $\Gamma(x)$ is infinite, the returned sign is that of the limit of $\Gamma$ from the
+    /// direction of $x$: `Greater` for $\infty$ and $0.0$, and `Less` for $-\infty$ and $-0.0$. It
+    /// is `Greater` for the negative integers.
+    ///
+    /// The result overflows when $|x|$ is greater than about $2^{2^{30}-31}$:
+    ///
+    /// Overflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_gamma_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_gamma_round`] instead. If both of these things are true, consider using
+    /// [`Float::ln_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than 1, 2, and the
+    /// negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round(5, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.812");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round(5, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "0.844");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round(5, Nearest);
+    /// assert_eq!(ln_gamma.to_string(), "0.812");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round(20, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769394");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round(20, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769489");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round(20, Nearest);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769489");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_gamma_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering, Ordering) {
+        self.ln_gamma_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], rounding the result to the specified precision and with the specified
+    /// rounding mode. The [`Float`] is taken by reference. Two [`Ordering`]s are also returned: the
+    /// first is the sign of $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative,
+    /// and the second indicates whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_gamma_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_gamma_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).ln_gamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than 1, 2, and the
+    /// negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round_ref(5, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.812");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round_ref(5, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "0.844");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round_ref(5, Nearest);
+    /// assert_eq!(ln_gamma.to_string(), "0.812");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round_ref(20, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769394");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round_ref(20, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769489");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_round_ref(20, Nearest);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769489");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn ln_gamma_prec_round_ref(
+        &self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal, Equal),
+            Infinity { sign } | Zero { sign } => {
+                (Self::INFINITY, if *sign { Greater } else { Less }, Equal)
+            }
+            Finite { .. } => ln_gamma_helper(&Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], rounding the result to the nearest value of the specified precision. The
+    /// [`Float`] is taken by value. Two [`Ordering`]s are also returned: the first is the sign of
+    /// $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative, and the second
+    /// indicates whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_gamma_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::ln_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec(5);
+    /// assert_eq!(ln_gamma.to_string(), "0.812");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec(20);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769489");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_gamma_prec(self, prec: u64) -> (Self, Ordering, Ordering) {
+        self.ln_gamma_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], rounding the result to the nearest value of the specified precision. The
+    /// [`Float`] is taken by reference. Two [`Ordering`]s are also returned: the first is the sign
+    /// of $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative, and the second
+    /// indicates whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_gamma_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).ln_gamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_ref(5);
+    /// assert_eq!(ln_gamma.to_string(), "0.812");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(PI).ln_gamma_prec_ref(20);
+    /// assert_eq!(ln_gamma.to_string(), "0.82769489");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_gamma_prec_ref(&self, prec: u64) -> (Self, Ordering, Ordering) {
+        self.ln_gamma_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], rounding the result to the precision of the input and with the specified
+    /// rounding mode. The [`Float`] is taken by value. Two [`Ordering`]s are also returned: the
+    /// first is the sign of $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative,
+    /// and the second indicates whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_gamma_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::ln_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than 1, 2, and the negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(-0.3).ln_gamma_round(Floor);
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576025");
+    /// assert_eq!(sign, Less);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(-0.3).ln_gamma_round(Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576027");
+    /// assert_eq!(sign, Less);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(-0.3).ln_gamma_round(Nearest);
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576025");
+    /// assert_eq!(sign, Less);
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_gamma_round(self, rm: RoundingMode) -> (Self, Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_gamma_prec_round(prec, rm)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], rounding the result to the precision of the input and with the specified
+    /// rounding mode. The [`Float`] is taken by reference. Two [`Ordering`]s are also returned: the
+    /// first is the sign of $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative,
+    /// and the second indicates whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::ln_gamma_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float).ln_gamma()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than 1, 2, and the negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(-0.3).ln_gamma_round_ref(Floor);
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576025");
+    /// assert_eq!(sign, Less);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(-0.3).ln_gamma_round_ref(Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576027");
+    /// assert_eq!(sign, Less);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::from(-0.3).ln_gamma_round_ref(Nearest);
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576025");
+    /// assert_eq!(sign, Less);
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_gamma_round_ref(&self, rm: RoundingMode) -> (Self, Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_gamma_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], in place, rounding the result to the specified precision and with the
+    /// specified rounding mode. Two [`Ordering`]s are returned: the first is the sign of
+    /// $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative, and the second
+    /// indicates whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_gamma_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::ln_gamma_round_assign`] instead. If both of these things are true, consider
+    /// using [`Float::ln_gamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than 1, 2, and the
+    /// negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_gamma_prec_round_assign(20, Floor), (Greater, Less));
+    /// assert_eq!(x.to_string(), "0.82769394");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_gamma_prec_round_assign(20, Ceiling), (Greater, Greater));
+    /// assert_eq!(x.to_string(), "0.82769489");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_gamma_prec_round_assign(20, Nearest), (Greater, Greater));
+    /// assert_eq!(x.to_string(), "0.82769489");
+    /// ```
+    #[inline]
+    pub fn ln_gamma_prec_round_assign(
+        &mut self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Ordering, Ordering) {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let sign;
+        let o;
+        (*self, sign, o) = x.ln_gamma_prec_round(prec, rm);
+        (sign, o)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], in place, rounding the result to the nearest value of the specified
+    /// precision. Two [`Ordering`]s are returned: the first is the sign of $\Gamma(x)$, `Greater`
+    /// if it is positive and `Less` if it is negative, and the second indicates whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal` for both [`Ordering`]s.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_gamma_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::ln_gamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_gamma_prec_assign(5), (Greater, Less));
+    /// assert_eq!(x.to_string(), "0.812");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_gamma_prec_assign(20), (Greater, Greater));
+    /// assert_eq!(x.to_string(), "0.82769489");
+    /// ```
+    #[inline]
+    pub fn ln_gamma_prec_assign(&mut self, prec: u64) -> (Ordering, Ordering) {
+        self.ln_gamma_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], in place, rounding the result to the precision of the input and with the
+    /// specified rounding mode. Two [`Ordering`]s are returned: the first is the sign of
+    /// $\Gamma(x)$, `Greater` if it is positive and `Less` if it is negative, and the second
+    /// indicates whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal` for both [`Ordering`]s.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::ln_gamma_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::ln_gamma_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than 1, 2, and the negative
+    /// integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-0.3);
+    /// assert_eq!(x.ln_gamma_round_assign(Floor), (Less, Less));
+    /// assert_eq!(x.to_string(), "1.4648400508576025");
+    ///
+    /// let mut x = Float::from(-0.3);
+    /// assert_eq!(x.ln_gamma_round_assign(Ceiling), (Less, Greater));
+    /// assert_eq!(x.to_string(), "1.4648400508576027");
+    ///
+    /// let mut x = Float::from(-0.3);
+    /// assert_eq!(x.ln_gamma_round_assign(Nearest), (Less, Less));
+    /// assert_eq!(x.to_string(), "1.4648400508576025");
+    /// ```
+    #[inline]
+    pub fn ln_gamma_round_assign(&mut self, rm: RoundingMode) -> (Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_gamma_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Rational`], rounding the result to the specified precision and with the specified
+    /// rounding mode and returning the result as a [`Float`]. The [`Rational`] is taken by value.
+    /// Two [`Ordering`]s are also returned: the first is the sign of $\Gamma(x)$, `Greater` if it
+    /// is positive and `Less` if it is negative, and the second indicates whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln|\Gamma(x)|+\varepsilon.
+    /// $$
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln|\Gamma(x)||\rfloor-p+1}$.
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\ln|\Gamma(x)||\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\infty$ if $x$ is 0 or a negative integer
+    /// - $f(1,p,m)=f(2,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_gamma_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than 1, 2, and the
+    /// negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.969");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "1.00");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.98542023");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "0.98542118");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn ln_gamma_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering, Ordering) {
+        Self::ln_gamma_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Rational`], rounding the result to the specified precision and with the specified
+    /// rounding mode and returning the result as a [`Float`]. The [`Rational`] is taken by
+    /// reference. Two [`Ordering`]s are also returned: the first is the sign of $\Gamma(x)$,
+    /// `Greater` if it is positive and `Less` if it is negative, and the second indicates whether
+    /// the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln|\Gamma(x)|+\varepsilon.
+    /// $$
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln|\Gamma(x)||\rfloor-p+1}$.
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\ln|\Gamma(x)||\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,p,m)=\infty$ if $x$ is 0 or a negative integer
+    /// - $f(1,p,m)=f(2,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_gamma_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than 1, 2, and the
+    /// negative integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.969");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "1.00");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(ln_gamma.to_string(), "0.98542023");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(ln_gamma.to_string(), "0.98542118");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn ln_gamma_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::INFINITY, Greater, Equal);
+        }
+        ln_gamma_helper(&Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Rational`], rounding the result to the nearest value of the specified precision and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by value. Two [`Ordering`]s
+    /// are also returned: the first is the sign of $\Gamma(x)$, `Greater` if it is positive and
+    /// `Less` if it is negative, and the second indicates whether the rounded logarithm is less
+    /// than, equal to, or greater than the exact logarithm.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::ln_gamma_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(ln_gamma.to_string(), "1.00");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(ln_gamma.to_string(), "0.98542023");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn ln_gamma_rational_prec(x: Rational, prec: u64) -> (Self, Ordering, Ordering) {
+        Self::ln_gamma_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Rational`], rounding the result to the nearest value of the specified precision and
+    /// returning the result as a [`Float`]. The [`Rational`] is taken by reference. Two
+    /// [`Ordering`]s are also returned: the first is the sign of $\Gamma(x)$, `Greater` if it is
+    /// positive and `Less` if it is negative, and the second indicates whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::ln_gamma_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(ln_gamma.to_string(), "1.00");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(ln_gamma.to_string(), "0.98542023");
+    /// assert_eq!(sign, Greater);
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_gamma_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering, Ordering) {
+        Self::ln_gamma_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl LnGamma for Float {
+    type Output = Self;
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], taking it by value. The sign of $\Gamma(x)$ is also returned, as `Greater`
+    /// if it is positive and `Less` if it is negative.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \ln|\Gamma(x)|+\varepsilon.
+    /// $$
+    /// - If $\ln|\Gamma(x)|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\ln|\Gamma(x)||\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\infty$
+    /// - $f(\pm0.0)=\infty$
+    /// - $f(x)=\infty$ if $x$ is a negative integer
+    /// - $f(1.0)=f(2.0)=0.0$
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_gamma_prec`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln_gamma_round`]. If you want both of these things, consider using
+    /// [`Float::ln_gamma_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LnGamma;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use std::cmp::Ordering::*;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.ln_gamma().0.is_nan());
+    /// assert_eq!(Float::NEGATIVE_ZERO.ln_gamma(), (Float::INFINITY, Less));
+    ///
+    /// let (ln_gamma, sign) = Float::from(PI).ln_gamma();
+    /// assert_eq!(ln_gamma.to_string(), "0.82769459232343667");
+    /// assert_eq!(sign, Greater);
+    ///
+    /// let (ln_gamma, sign) = Float::from(-0.3).ln_gamma();
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576025");
+    /// assert_eq!(sign, Less);
+    /// ```
+    #[inline]
+    fn ln_gamma(self) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        let (ln_gamma, sign, _) = self.ln_gamma_prec_round(prec, Nearest);
+        (ln_gamma, sign)
+    }
+}
+
+impl LnGamma for &Float {
+    type Output = Float;
+
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], taking it by reference. The sign of $\Gamma(x)$ is also returned, as
+    /// `Greater` if it is positive and `Less` if it is negative.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \ln|\Gamma(x)|+\varepsilon.
+    /// $$
+    /// - If $\ln|\Gamma(x)|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\ln|\Gamma(x)||\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\infty$
+    /// - $f(\pm0.0)=\infty$
+    /// - $f(x)=\infty$ if $x$ is a negative integer
+    /// - $f(1.0)=f(2.0)=0.0$
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on overflow and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_gamma_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln_gamma_round_ref`]. If you want both of these things, consider using
+    /// [`Float::ln_gamma_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LnGamma;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use std::cmp::Ordering::*;
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).ln_gamma().0.is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO).ln_gamma(), (Float::INFINITY, Less));
+    ///
+    /// let (ln_gamma, sign) = (&Float::from(PI)).ln_gamma();
+    /// assert_eq!(ln_gamma.to_string(), "0.82769459232343667");
+    /// assert_eq!(sign, Greater);
+    ///
+    /// let (ln_gamma, sign) = (&Float::from(-0.3)).ln_gamma();
+    /// assert_eq!(ln_gamma.to_string(), "1.4648400508576025");
+    /// assert_eq!(sign, Less);
+    /// ```
+    #[inline]
+    fn ln_gamma(self) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        let (ln_gamma, sign, _) = self.ln_gamma_prec_round_ref(prec, Nearest);
+        (ln_gamma, sign)
+    }
+}
+
+impl LnGammaAssign for Float {
+    /// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function
+    /// of a [`Float`], in place. The sign of $\Gamma(x)$ is returned, as `Greater` if it is
+    /// positive and `Less` if it is negative.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \ln|\Gamma(x)|+\varepsilon.
+    /// $$
+    /// - If $\ln|\Gamma(x)|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\ln|\Gamma(x)|$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\ln|\Gamma(x)||\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::ln_gamma_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LnGammaAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use std::cmp::Ordering::*;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_gamma_assign(), Greater);
+    /// assert_eq!(x.to_string(), "0.82769459232343667");
+    ///
+    /// let mut x = Float::from(-0.3);
+    /// assert_eq!(x.ln_gamma_assign(), Less);
+    /// assert_eq!(x.to_string(), "1.4648400508576025");
+    /// ```
+    #[inline]
+    fn ln_gamma_assign(&mut self) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_gamma_prec_round_assign(prec, Nearest).0
+    }
+}
+
+/// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function of
+/// a primitive float, returning a correctly-rounded result. The sign of $\Gamma(x)$ is also
+/// returned, as `Greater` if it is positive and `Less` if it is negative.
+///
+/// $$
+/// f(x) = \ln|\Gamma(x)|+\varepsilon.
+/// $$
+/// - If $\ln|\Gamma(x)|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+///   0.
+/// - If $\ln|\Gamma(x)|$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\ln|\Gamma(x)||\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is
+///   a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\pm\infty)=\infty$
+/// - $f(\pm0.0)=\infty$
+/// - $f(x)=\infty$ if $x$ is a negative integer
+/// - $f(1.0)=f(2.0)=0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::ln_gamma::primitive_float_ln_gamma;
+/// use std::cmp::Ordering::*;
+///
+/// assert!(primitive_float_ln_gamma(f64::NAN).0.is_nan());
+/// assert_eq!(NiceFloat(primitive_float_ln_gamma(-1.0f64).0), NiceFloat(f64::INFINITY));
+///
+/// let (ln_gamma, sign) = primitive_float_ln_gamma(PI);
+/// assert_eq!(NiceFloat(ln_gamma), NiceFloat(0.827694592323437));
+/// assert_eq!(sign, Greater);
+///
+/// let (ln_gamma, sign) = primitive_float_ln_gamma(-0.3f32);
+/// assert_eq!(NiceFloat(ln_gamma), NiceFloat(1.46484));
+/// assert_eq!(sign, Less);
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_ln_gamma<T: PrimitiveFloat>(x: T) -> (T, Ordering)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_and_sign_fn(Float::ln_gamma_prec, x)
+}
+
+/// Computes $\ln|\Gamma(x)|$, the natural logarithm of the absolute value of the gamma function of
+/// a [`Rational`], returning the result as a primitive float. The sign of $\Gamma(x)$ is also
+/// returned, as `Greater` if it is positive and `Less` if it is negative.
+///
+/// $$
+/// f(x) = \ln|\Gamma(x)|+\varepsilon.
+/// $$
+/// - If $\ln|\Gamma(x)|$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+///   0.
+/// - If $\ln|\Gamma(x)|$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\ln|\Gamma(x)||\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is
+///   a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(x)=\infty$ if $x$ is 0 or a negative integer
+/// - $f(1)=f(2)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::ln_gamma::primitive_float_ln_gamma_rational;
+/// use malachite_q::Rational;
+/// use std::cmp::Ordering::*;
+///
+/// let (ln_gamma, sign) = primitive_float_ln_gamma_rational::<f64>(&Rational::from_unsigneds(1u8, 3));
+/// assert_eq!(NiceFloat(ln_gamma), NiceFloat(0.9854206469277671));
+/// assert_eq!(sign, Greater);
+///
+/// let (ln_gamma, sign) = primitive_float_ln_gamma_rational::<f64>(&Rational::from_signeds(-1i8, 3));
+/// assert_eq!(NiceFloat(ln_gamma), NiceFloat(1.4017625638156332));
+/// assert_eq!(sign, Less);
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_ln_gamma_rational<T: PrimitiveFloat>(x: &Rational) -> (T, Ordering)
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_and_sign_fn(Float::ln_gamma_rational_prec_ref, x)
+}
@@ -459,10 +459,10 @@ impl Float {
     /// $$
     /// - If $\log_b x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
     ///   0.
-    /// - If $\log_b x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\log_b x|\rfloor-p+1}$.
-    /// - If $\log_b x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\log_b x|\rfloor-p}$.
+    /// - If $\log_b x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\log_b x|\rfloor-p+1}$.
+    /// - If $\log_b x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\log_b x|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -0,0 +1,2297 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::gamma::{Argument, gamma_helper};
+use crate::float::arithmetic::upper_incomplete_gamma::lower_incomplete_gamma_helper;
+use crate::{
+    Float, emulate_float_float_to_float_fn, emulate_rational_rational_to_float_fn,
+    float_either_zero, float_infinity, float_nan, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{LowerIncompleteGamma, LowerIncompleteGammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+fn lower_incomplete_gamma_prec_round_helper(
+    s: &Float,
+    x: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (s, x) {
+        (Float(NaN | Zero { .. } | Infinity { sign: false } | Finite { sign: false, .. }), _)
+        | (_, Float(NaN | Infinity { sign: false } | Finite { sign: false, .. }))
+        | (float_infinity!(), float_infinity!()) => (float_nan!(), Equal),
+        (_, float_either_zero!()) => (float_zero!(), Equal),
+        (float_infinity!(), _) => {
+            if *x <= 1u32 {
+                (float_zero!(), Equal)
+            } else {
+                (float_infinity!(), Equal)
+            }
+        }
+        (_, float_infinity!()) => gamma_helper(&Argument::from_float(s), prec, rm),
+        _ => lower_incomplete_gamma_helper(
+            &Argument::from_float(s),
+            &Argument::from_float(x),
+            prec,
+            rm,
+        ),
+    }
+}
+
+impl Float {
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. Both [`Float`]s are
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(s,x,p,m) = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\gamma(s,x)|\rfloor-p+1}$.
+    /// - If $\gamma(s,x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\gamma(s,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(s,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(s,x,p,m)=\text{NaN}$ if $s$ is $\pm0.0$, negative, or $-\infty$
+    /// - $f(s,x,p,m)=\text{NaN}$ if $x<0$, including when $x=-\infty$
+    /// - $f(\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(s,\pm0.0,p,m)=0.0$ if $s>0$
+    /// - $f(\infty,x,p,m)=0.0$ if $0<x\leq 1$
+    /// - $f(\infty,x,p,m)=\infty$ if $x>1$
+    /// - $f(s,\infty,p,m)=\Gamma(s)$ if $0<s<\infty$
+    ///
+    /// The result overflows when $s$ is very large and $x$ is not much smaller than $s$; it
+    /// underflows when $x$ is very close to zero, or when $s$ is very large and $x$ is much smaller
+    /// than $s$:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::lower_incomplete_gamma_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::lower_incomplete_gamma_round`] instead. If both of these
+    /// things are true, consider using [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round(Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round(Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round(Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round(Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round(Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901089");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round(Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_round(
+        self,
+        x: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        lower_incomplete_gamma_prec_round_helper(&self, &x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. The first [`Float`]
+    /// is taken by value and the second by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_val_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::lower_incomplete_gamma_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_val_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_val_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_val_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_val_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_val_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901089");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_val_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_round_val_ref(
+        self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        lower_incomplete_gamma_prec_round_helper(&self, x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. The first [`Float`]
+    /// is taken by reference and the second by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_ref_val`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::lower_incomplete_gamma_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_val(Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_val(Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_val(Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_val(Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_val(Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901089");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_val(Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_round_ref_val(
+        &self,
+        x: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        lower_incomplete_gamma_prec_round_helper(self, &x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. Both [`Float`]s are
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the exact result. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_ref_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::lower_incomplete_gamma_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.12");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901089");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_round_ref_ref(
+        &self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        lower_incomplete_gamma_prec_round_helper(self, x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. Both [`Float`]s are taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded result is less than, equal
+    /// to, or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round`] instead. If you know that your target precision
+    /// is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec(Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec(Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec(self, x: Self, prec: u64) -> (Self, Ordering) {
+        self.lower_incomplete_gamma_prec_round(x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. The first [`Float`] is taken by
+    /// value and the second by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round_val_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_val_ref(self, x: &Self, prec: u64) -> (Self, Ordering) {
+        self.lower_incomplete_gamma_prec_round_val_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. The first [`Float`] is taken by
+    /// reference and the second by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round_ref_val`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_ref_val(&self, x: Self, prec: u64) -> (Self, Ordering) {
+        self.lower_incomplete_gamma_prec_round_ref_val(x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. Both [`Float`]s are taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round_ref_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.06");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.0901070");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_ref_ref(&self, x: &Self, prec: u64) -> (Self, Ordering) {
+        self.lower_incomplete_gamma_prec_round_ref_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_round(Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_round(Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069497");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(PI).lower_incomplete_gamma_round(Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_round(self, x: Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round(x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// The first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round_val_ref`] instead. If you know you'll be using
+    /// the `Nearest` rounding mode, consider using [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_val_ref(&Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_val_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069497");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_val_ref(&Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_round_val_ref(
+        self,
+        x: &Self,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_val_ref(x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// The first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round_ref_val`] instead. If you know you'll be using
+    /// the `Nearest` rounding mode, consider using [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_ref_val(Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_ref_val(Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069497");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_ref_val(Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_round_ref_val(
+        &self,
+        x: Self,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_ref_val(x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round_ref_ref`] instead. If you know you'll be using
+    /// the `Nearest` rounding mode, consider using [`Float::lower_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_ref_ref(&Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_ref_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069497");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).lower_incomplete_gamma_round_ref_ref(&Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.0901075872069494");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_round_ref_ref(
+        &self,
+        x: &Self,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_ref_ref(x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the specified precision and with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded result is less than, equal to, or
+    /// greater than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.lower_incomplete_gamma_prec_round_assign(Float::from(E), 20, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0901070");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.lower_incomplete_gamma_prec_round_assign(Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.0901089");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.lower_incomplete_gamma_prec_round_assign(Float::from(E), 20, Nearest),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0901070");
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_round_assign(
+        &mut self,
+        x: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.lower_incomplete_gamma_prec_round_ref_val(x, prec, rm);
+        o
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the specified precision and with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded result is less than, equal to, or
+    /// greater than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.lower_incomplete_gamma_prec_round_assign_ref(&Float::from(E), 20, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0901070");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.lower_incomplete_gamma_prec_round_assign_ref(&Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.0901089");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.lower_incomplete_gamma_prec_round_assign_ref(&Float::from(E), 20, Nearest),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.0901070");
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_round_assign_ref(
+        &mut self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.lower_incomplete_gamma_prec_round_ref_ref(x, prec, rm);
+        o
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the nearest value of the specified precision.
+    /// The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_prec_assign(Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_prec_assign(Float::from(E), 20), Less);
+    /// assert_eq!(x.to_string(), "1.0901070");
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_assign(&mut self, x: Self, prec: u64) -> Ordering {
+        self.lower_incomplete_gamma_prec_round_assign(x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the nearest value of the specified precision.
+    /// The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_prec_assign_ref(&Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_prec_assign_ref(&Float::from(E), 20), Less);
+    /// assert_eq!(x.to_string(), "1.0901070");
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_prec_assign_ref(&mut self, x: &Self, prec: u64) -> Ordering {
+        self.lower_incomplete_gamma_prec_round_assign_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the maximum of the precisions of the inputs
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// value. An [`Ordering`] is returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_round_assign(Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "1.0901075872069494");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_round_assign(Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.0901075872069497");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_round_assign(Float::from(E), Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.0901075872069494");
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_round_assign(&mut self, x: Self, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_assign(x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the maximum of the precisions of the inputs
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_round_assign_ref(&Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "1.0901075872069494");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_round_assign_ref(&Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.0901075872069497");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.lower_incomplete_gamma_round_assign_ref(&Float::from(E), Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.0901075872069494");
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_round_assign_ref(
+        &mut self,
+        x: &Self,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_assign_ref(x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. Both [`Rational`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(s,x,p,m) = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\gamma(s,x)|\rfloor-p+1}$.
+    /// - If $\gamma(s,x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\gamma(s,x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(s,x,p,m)=\text{NaN}$ if $s\leq 0$ or $x<0$
+    /// - $f(s,0,p,m)=0.0$ if $s>0$
+    ///
+    /// The result overflows when $s$ is very large and $x$ is not much smaller than $s$; it
+    /// underflows when $x$ is very close to zero, or when $s$ is very large and $x$ is much smaller
+    /// than $s$:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.75");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420746");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_rational_prec_round(
+        s: Rational,
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. The first [`Rational`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.75");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420746");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_rational_prec_round_val_ref(
+        s: Rational,
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. The first [`Rational`] is taken by reference and the second by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.75");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420746");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_rational_prec_round_ref_val(
+        s: &Rational,
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::lower_incomplete_gamma_rational_prec_round_ref_ref(s, &x, prec, rm)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. Both [`Rational`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.75");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420746");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn lower_incomplete_gamma_rational_prec_round_ref_ref(
+        s: &Rational,
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *s <= 0u32 || *x < 0u32 {
+            (Self::NAN, Equal)
+        } else if *x == 0u32 {
+            (Self::ZERO, Equal)
+        } else {
+            lower_incomplete_gamma_helper(
+                &Argument::from_rational(s),
+                &Argument::from_rational(x),
+                prec,
+                rm,
+            )
+        }
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. Both [`Rational`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_rational_prec(
+        s: Rational,
+        x: Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. The first [`Rational`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_rational_prec_val_ref(
+        s: Rational,
+        x: &Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. The first [`Rational`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn lower_incomplete_gamma_rational_prec_ref_val(
+        s: &Rational,
+        x: Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::lower_incomplete_gamma_rational_prec_round_ref_ref(s, &x, prec, Nearest)
+    }
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. Both [`Rational`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_rational_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "2.6420784");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn lower_incomplete_gamma_rational_prec_ref_ref(
+        s: &Rational,
+        x: &Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::lower_incomplete_gamma_rational_prec_round_ref_ref(s, x, prec, Nearest)
+    }
+}
+
+impl LowerIncompleteGamma<Self> for Float {
+    type Output = Self;
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, taking both
+    /// by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $s$ is $\pm0.0$, negative, or $-\infty$
+    /// - $f(s,x)=\text{NaN}$ if $x<0$, including when $x=-\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\pm0.0)=0.0$ if $s>0$
+    /// - $f(\infty,x)=0.0$ if $0<x\leq 1$
+    /// - $f(\infty,x)=\infty$ if $x>1$
+    /// - $f(s,\infty)=\Gamma(s)$ if $0<s<\infty$
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec`] instead. If you want to specify the output precision,
+    /// consider using [`Float::lower_incomplete_gamma_round`]. If you want both of these things,
+    /// consider using [`Float::lower_incomplete_gamma_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::LowerIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(PI)
+    ///         .lower_incomplete_gamma(Float::from(E))
+    ///         .to_string(),
+    ///     "1.0901075872069494"
+    /// );
+    /// assert!(Float::NAN.lower_incomplete_gamma(Float::from(E)).is_nan());
+    /// assert!(Float::ZERO.lower_incomplete_gamma(Float::ONE).is_nan());
+    /// ```
+    #[inline]
+    fn lower_incomplete_gamma(self, x: Self) -> Self {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round(x, prec, Nearest).0
+    }
+}
+
+impl LowerIncompleteGamma<&Self> for Float {
+    type Output = Self;
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, taking the
+    /// first by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $s$ is $\pm0.0$, negative, or $-\infty$
+    /// - $f(s,x)=\text{NaN}$ if $x<0$, including when $x=-\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\pm0.0)=0.0$ if $s>0$
+    /// - $f(\infty,x)=0.0$ if $0<x\leq 1$
+    /// - $f(\infty,x)=\infty$ if $x>1$
+    /// - $f(s,\infty)=\Gamma(s)$ if $0<s<\infty$
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_val_ref`] instead. If you want to specify the output
+    /// precision, consider using [`Float::lower_incomplete_gamma_round_val_ref`]. If you want both
+    /// of these things, consider using [`Float::lower_incomplete_gamma_prec_round_val_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::LowerIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(PI)
+    ///         .lower_incomplete_gamma(&Float::from(E))
+    ///         .to_string(),
+    ///     "1.0901075872069494"
+    /// );
+    /// assert!(Float::NAN.lower_incomplete_gamma(&Float::from(E)).is_nan());
+    /// assert!(Float::ZERO.lower_incomplete_gamma(&Float::ONE).is_nan());
+    /// ```
+    #[inline]
+    fn lower_incomplete_gamma(self, x: &Self) -> Self {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_val_ref(x, prec, Nearest)
+            .0
+    }
+}
+
+impl LowerIncompleteGamma<Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, taking the
+    /// first by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $s$ is $\pm0.0$, negative, or $-\infty$
+    /// - $f(s,x)=\text{NaN}$ if $x<0$, including when $x=-\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\pm0.0)=0.0$ if $s>0$
+    /// - $f(\infty,x)=0.0$ if $0<x\leq 1$
+    /// - $f(\infty,x)=\infty$ if $x>1$
+    /// - $f(s,\infty)=\Gamma(s)$ if $0<s<\infty$
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_ref_val`] instead. If you want to specify the output
+    /// precision, consider using [`Float::lower_incomplete_gamma_round_ref_val`]. If you want both
+    /// of these things, consider using [`Float::lower_incomplete_gamma_prec_round_ref_val`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::LowerIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(PI))
+    ///         .lower_incomplete_gamma(Float::from(E))
+    ///         .to_string(),
+    ///     "1.0901075872069494"
+    /// );
+    /// assert!(
+    ///     (&Float::NAN)
+    ///         .lower_incomplete_gamma(Float::from(E))
+    ///         .is_nan()
+    /// );
+    /// assert!((&Float::ZERO).lower_incomplete_gamma(Float::ONE).is_nan());
+    /// ```
+    #[inline]
+    fn lower_incomplete_gamma(self, x: Float) -> Float {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_ref_val(x, prec, Nearest)
+            .0
+    }
+}
+
+impl LowerIncompleteGamma<&Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, taking both
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $s$ is $\pm0.0$, negative, or $-\infty$
+    /// - $f(s,x)=\text{NaN}$ if $x<0$, including when $x=-\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\pm0.0)=0.0$ if $s>0$
+    /// - $f(\infty,x)=0.0$ if $0<x\leq 1$
+    /// - $f(\infty,x)=\infty$ if $x>1$
+    /// - $f(s,\infty)=\Gamma(s)$ if $0<s<\infty$
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_ref_ref`] instead. If you want to specify the output
+    /// precision, consider using [`Float::lower_incomplete_gamma_round_ref_ref`]. If you want both
+    /// of these things, consider using [`Float::lower_incomplete_gamma_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::LowerIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(PI))
+    ///         .lower_incomplete_gamma(&Float::from(E))
+    ///         .to_string(),
+    ///     "1.0901075872069494"
+    /// );
+    /// assert!(
+    ///     (&Float::NAN)
+    ///         .lower_incomplete_gamma(&Float::from(E))
+    ///         .is_nan()
+    /// );
+    /// assert!((&Float::ZERO).lower_incomplete_gamma(&Float::ONE).is_nan());
+    /// ```
+    #[inline]
+    fn lower_incomplete_gamma(self, x: &Float) -> Float {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_ref_ref(x, prec, Nearest)
+            .0
+    }
+}
+
+impl LowerIncompleteGammaAssign<Self> for Float {
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and taking the [`Float`] on the right-hand side by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_assign`] instead. If you want to specify the output
+    /// precision, consider using [`Float::lower_incomplete_gamma_round_assign`]. If you want both
+    /// of these things, consider using [`Float::lower_incomplete_gamma_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::LowerIncompleteGammaAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut s = Float::from(PI);
+    /// s.lower_incomplete_gamma_assign(Float::from(E));
+    /// assert_eq!(s.to_string(), "1.0901075872069494");
+    /// ```
+    #[inline]
+    fn lower_incomplete_gamma_assign(&mut self, x: Self) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_assign(x, prec, Nearest);
+    }
+}
+
+impl LowerIncompleteGammaAssign<&Self> for Float {
+    /// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and taking the [`Float`] on the right-hand side by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \gamma(s,x)+\varepsilon
+    /// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::lower_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::lower_incomplete_gamma_prec_assign_ref`] instead. If you want to specify the output
+    /// precision, consider using [`Float::lower_incomplete_gamma_round_assign_ref`]. If you want
+    /// both of these things, consider using
+    /// [`Float::lower_incomplete_gamma_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::LowerIncompleteGammaAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut s = Float::from(PI);
+    /// s.lower_incomplete_gamma_assign(&Float::from(E));
+    /// assert_eq!(s.to_string(), "1.0901075872069494");
+    /// ```
+    #[inline]
+    fn lower_incomplete_gamma_assign(&mut self, x: &Self) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.lower_incomplete_gamma_prec_round_assign_ref(x, prec, Nearest);
+    }
+}
+
+/// Computes $\gamma(s,x)$, the lower incomplete gamma function of two primitive floats, returning a
+/// correctly-rounded result.
+///
+/// $$
+/// f(s,x) = \gamma(s,x)+\varepsilon
+/// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+/// $$
+/// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+/// - $f(s,x)=\text{NaN}$ if $s$ is $\pm0.0$, negative, or $-\infty$
+/// - $f(s,x)=\text{NaN}$ if $x<0$, including when $x=-\infty$
+/// - $f(\infty,\infty)=\text{NaN}$
+/// - $f(s,\pm0.0)=0.0$ if $s>0$
+/// - $f(\infty,x)=0.0$ if $0<x\leq 1$
+/// - $f(\infty,x)=\infty$ if $x>1$
+/// - $f(s,\infty)=\Gamma(s)$ if $0<s<\infty$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::{E, PI};
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::lower_incomplete_gamma::primitive_float_lower_incomplete_gamma;
+///
+/// assert!(primitive_float_lower_incomplete_gamma(f64::NAN, 1.0).is_nan());
+/// assert!(primitive_float_lower_incomplete_gamma(-1.0f64, 0.5).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_lower_incomplete_gamma(PI, E)),
+///     NiceFloat(1.0901075872069494)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_lower_incomplete_gamma(0.5f32, 0.5f32)),
+///     NiceFloat(1.2100356)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_lower_incomplete_gamma(2.5f64, 10.0f64)),
+///     NiceFloat(1.3276790708673576)
+/// );
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+#[inline]
+pub fn primitive_float_lower_incomplete_gamma<T: PrimitiveFloat>(s: T, x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_float_to_float_fn(Float::lower_incomplete_gamma_prec, s, x)
+}
+
+/// Computes $\gamma(s,x)$, the lower incomplete gamma function of two [`Rational`]s, returning the
+/// result as a primitive float.
+///
+/// $$
+/// f(s,x) = \gamma(s,x)+\varepsilon
+/// =\int_0^x t^{s-1}e^{-t}\,dt+\varepsilon.
+/// $$
+/// - If $\gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\gamma(s,x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(s,x)=\text{NaN}$ if $s\leq 0$ or $x<0$
+/// - $f(s,0)=0.0$ if $s>0$
+///
+/// # Worst-case complexity
+/// $T(m) = O(m)$
+///
+/// $M(m) = O(m)$
+///
+/// where $T$ is time, $M$ is additional memory, and $m$ is `max(s.significant_bits(),
+/// x.significant_bits())`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::lower_incomplete_gamma::primitive_float_lower_incomplete_gamma_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_lower_incomplete_gamma_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3),
+///         &Rational::from_unsigneds(5u8, 2)
+///     )),
+///     NiceFloat(2.642078013789221)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_lower_incomplete_gamma_rational::<f64>(
+///         &Rational::from(3),
+///         &Rational::from_unsigneds(7u8, 2)
+///     )),
+///     NiceFloat(1.3583056022757318)
+/// );
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+#[inline]
+pub fn primitive_float_lower_incomplete_gamma_rational<T: PrimitiveFloat>(
+    s: &Rational,
+    x: &Rational,
+) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_rational_to_float_fn(Float::lower_incomplete_gamma_rational_prec_ref_ref, s, x)
+}
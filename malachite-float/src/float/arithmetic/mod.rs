@@ -8,6 +8,14 @@
 
 /// Absolute value of [`Float`](super::Float)s.
 pub mod abs;
+/// [`Acos`](malachite_base::num::arithmetic::traits::Acos) and
+/// [`AcosAssign`](malachite_base::num::arithmetic::traits::AcosAssign), traits for computing the
+/// arccosine of [`Float`](super::Float)s.
+pub mod acos;
+/// [`Acosh`](malachite_base::num::arithmetic::traits::Acosh) and
+/// [`AcoshAssign`](malachite_base::num::arithmetic::traits::AcoshAssign), traits for computing the
+/// inverse hyperbolic cosine of [`Float`](super::Float)s.
+pub mod acosh;
 /// Addition of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
 /// [`Rational`](malachite_q::Rational)s.
 pub mod add;
@@ -20,6 +28,30 @@ pub mod add_mul;
 /// Taking the AGM (arithmetic-geometric mean) of two [`Float`](super::Float)s, and of
 /// [`Float`](super::Float)s with [`Rational`](malachite_q::Rational)s.
 pub mod agm;
+/// [`AiryAi`](malachite_base::num::arithmetic::traits::AiryAi) and
+/// [`AiryAiAssign`](malachite_base::num::arithmetic::traits::AiryAiAssign), traits for computing
+/// the Airy function Ai of [`Float`](super::Float)s.
+pub mod airy_ai;
+/// [`Asin`](malachite_base::num::arithmetic::traits::Asin) and
+/// [`AsinAssign`](malachite_base::num::arithmetic::traits::AsinAssign), traits for computing the
+/// arcsine of [`Float`](super::Float)s.
+pub mod asin;
+/// [`Asinh`](malachite_base::num::arithmetic::traits::Asinh) and
+/// [`AsinhAssign`](malachite_base::num::arithmetic::traits::AsinhAssign), traits for computing the
+/// inverse hyperbolic sine of [`Float`](super::Float)s.
+pub mod asinh;
+/// [`Atan`](malachite_base::num::arithmetic::traits::Atan) and
+/// [`AtanAssign`](malachite_base::num::arithmetic::traits::AtanAssign), traits for computing the
+/// arctangent of [`Float`](super::Float)s.
+pub mod atan;
+/// [`Atan2`](malachite_base::num::arithmetic::traits::Atan2) and
+/// [`Atan2Assign`](malachite_base::num::arithmetic::traits::Atan2Assign), traits for computing the
+/// two-argument arctangent of [`Float`](super::Float)s.
+pub mod atan2;
+/// [`Atanh`](malachite_base::num::arithmetic::traits::Atanh) and
+/// [`AtanhAssign`](malachite_base::num::arithmetic::traits::AtanhAssign), traits for computing the
+/// inverse hyperbolic tangent of [`Float`](super::Float)s.
+pub mod atanh;
 /// [`Average`](malachite_base::num::arithmetic::traits::Average) and
 /// [`AverageAssign`](malachite_base::num::arithmetic::traits::AverageAssign), traits for computing
 /// the average (arithmetic mean) of two numbers, and the associated precision- and
@@ -145,12 +177,68 @@ pub mod agm;
 /// assert_eq!(x, 2.0);
 /// ```
 pub mod average;
+/// [`BesselJ0`](malachite_base::num::arithmetic::traits::BesselJ0) and
+/// [`BesselJ0Assign`](malachite_base::num::arithmetic::traits::BesselJ0Assign), traits for
+/// computing the Bessel function of the first kind of order 0 of [`Float`](super::Float)s.
+pub mod bessel_j0;
+/// [`BesselJ1`](malachite_base::num::arithmetic::traits::BesselJ1) and
+/// [`BesselJ1Assign`](malachite_base::num::arithmetic::traits::BesselJ1Assign), traits for
+/// computing the Bessel function of the first kind of order 1 of [`Float`](super::Float)s.
+pub mod bessel_j1;
+/// [`BesselJn`](malachite_base::num::arithmetic::traits::BesselJn) and
+/// [`BesselJnAssign`](malachite_base::num::arithmetic::traits::BesselJnAssign), traits for
+/// computing the Bessel functions of the first kind of any order of [`Float`](super::Float)s.
+pub mod bessel_jn;
+/// [`BesselY0`](malachite_base::num::arithmetic::traits::BesselY0) and
+/// [`BesselY0Assign`](malachite_base::num::arithmetic::traits::BesselY0Assign), traits for
+/// computing the Bessel function of the second kind of order 0 of [`Float`](super::Float)s.
+pub mod bessel_y0;
+/// [`BesselY1`](malachite_base::num::arithmetic::traits::BesselY1) and
+/// [`BesselY1Assign`](malachite_base::num::arithmetic::traits::BesselY1Assign), traits for
+/// computing the Bessel function of the second kind of order 1 of [`Float`](super::Float)s.
+pub mod bessel_y1;
+/// [`BesselYn`](malachite_base::num::arithmetic::traits::BesselYn) and
+/// [`BesselYnAssign`](malachite_base::num::arithmetic::traits::BesselYnAssign), traits for
+/// computing the Bessel functions of the second kind of any order of [`Float`](super::Float)s.
+pub mod bessel_yn;
+/// [`Beta`](malachite_base::num::arithmetic::traits::Beta) and
+/// [`BetaAssign`](malachite_base::num::arithmetic::traits::BetaAssign), traits for computing the
+/// beta function of [`Float`](super::Float)s.
+pub mod beta;
 /// Cube root of [`Float`](super::Float)s and of [`Rational`](malachite_q::Rational)s.
 pub mod cbrt;
+/// [`Cos`](malachite_base::num::arithmetic::traits::Cos) and
+/// [`CosAssign`](malachite_base::num::arithmetic::traits::CosAssign), traits for computing the
+/// cosine of [`Float`](super::Float)s.
+pub mod cos;
+/// [`Cosh`](malachite_base::num::arithmetic::traits::Cosh) and
+/// [`CoshAssign`](malachite_base::num::arithmetic::traits::CoshAssign), traits for computing the
+/// hyperbolic cosine of [`Float`](super::Float)s.
+pub mod cosh;
+/// [`Coth`](malachite_base::num::arithmetic::traits::Coth) and
+/// [`CothAssign`](malachite_base::num::arithmetic::traits::CothAssign), traits for computing the
+/// hyperbolic cotangent of [`Float`](super::Float)s.
+pub mod coth;
+/// [`Csch`](malachite_base::num::arithmetic::traits::Csch) and
+/// [`CschAssign`](malachite_base::num::arithmetic::traits::CschAssign), traits for computing the
+/// hyperbolic cosecant of [`Float`](super::Float)s.
+pub mod csch;
+/// [`Digamma`](malachite_base::num::arithmetic::traits::Digamma) and
+/// [`DigammaAssign`](malachite_base::num::arithmetic::traits::DigammaAssign), traits for computing
+/// the digamma function of [`Float`](super::Float)s.
+pub mod digamma;
 /// Division of [`Float`](super::Float)s, of [`Float`](super::Float)s by
 /// [`Rational`](malachite_q::Rational)s, and of [`Rational`](malachite_q::Rational)s by
 /// [`Float`](super::Float)s.
 pub mod div;
+/// [`Erf`](malachite_base::num::arithmetic::traits::Erf) and
+/// [`ErfAssign`](malachite_base::num::arithmetic::traits::ErfAssign), traits for computing the
+/// error function of [`Float`](super::Float)s.
+pub mod erf;
+/// [`Erfc`](malachite_base::num::arithmetic::traits::Erfc) and
+/// [`ErfcAssign`](malachite_base::num::arithmetic::traits::ErfcAssign), traits for computing the
+/// complementary error function of [`Float`](super::Float)s.
+pub mod erfc;
 /// [`Exp`](malachite_base::num::arithmetic::traits::Exp) and
 /// [`ExpAssign`](malachite_base::num::arithmetic::traits::ExpAssign), traits for computing $e^x$
 /// for [`Float`](super::Float)s.
@@ -165,6 +253,10 @@ pub mod factorial;
 /// Fractional parts of [`Float`](super::Float)s: the `fractional_part` and
 /// `integer_and_fractional_parts` families.
 pub mod fractional_part;
+/// [`Gamma`](malachite_base::num::arithmetic::traits::Gamma) and
+/// [`GammaAssign`](malachite_base::num::arithmetic::traits::GammaAssign), traits for computing the
+/// gamma function of [`Float`](super::Float)s.
+pub mod gamma;
 /// [`Hypot`](malachite_base::num::arithmetic::traits::Hypot) and
 /// [`HypotAssign`](malachite_base::num::arithmetic::traits::HypotAssign), traits for computing the
 /// hypotenuse of two numbers, $\sqrt{x^2+y^2}$.
@@ -192,6 +284,10 @@ pub mod ln;
 /// [`Ln1PlusXAssign`](malachite_base::num::arithmetic::traits::Ln1PlusXAssign), traits for
 /// computing $\ln(1+x)$ for [`Float`](super::Float)s.
 pub mod ln_1_plus_x;
+/// [`LnGamma`](malachite_base::num::arithmetic::traits::LnGamma) and
+/// [`LnGammaAssign`](malachite_base::num::arithmetic::traits::LnGammaAssign), traits for computing
+/// the logarithm of the absolute value of the gamma function of [`Float`](super::Float)s.
+pub mod ln_gamma;
 /// [`LogBase`](malachite_base::num::arithmetic::traits::LogBase) and
 /// [`LogBaseAssign`](malachite_base::num::arithmetic::traits::LogBaseAssign), traits for computing
 /// the base-$b$ logarithm of [`Float`](super::Float)s for an arbitrary integer base $b>1$.
@@ -253,6 +349,10 @@ pub mod log_base_rational_float_base;
 /// Functions for computing $\log_b x$ of a [`Rational`](malachite_q::Rational) $x$ with an
 /// arbitrary [`Rational`](malachite_q::Rational) base $b>1$, returning a [`Float`](super::Float).
 pub mod log_base_rational_rational_base;
+/// [`LowerIncompleteGamma`](malachite_base::num::arithmetic::traits::LowerIncompleteGamma) and
+/// [`LowerIncompleteGammaAssign`](malachite_base::num::arithmetic::traits::LowerIncompleteGammaAssign),
+/// traits for computing the lower incomplete gamma function of [`Float`](super::Float)s.
+pub mod lower_incomplete_gamma;
 /// Multiplication of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
 /// [`Rational`](malachite_q::Rational)s.
 pub mod mul;
@@ -268,6 +368,11 @@ pub mod mul_add_mul;
 pub mod mul_sub_mul;
 /// Negation of [`Float`](super::Float)s.
 pub mod neg;
+/// [`partition_number_prec_round`](super::Float::partition_number_prec_round),
+/// [`partition_number_prec`](super::Float::partition_number_prec), and
+/// [`partition_number_hrr`](partition_number::partition_number_hrr), for computing the number of
+/// partitions of an integer with the Hardy–Ramanujan–Rademacher formula.
+pub mod partition_number;
 /// [`positive_difference_prec_round`](super::Float::positive_difference_prec_round) and related
 /// functions, for computing positive differences of [`Float`](super::Float)s.
 pub mod positive_difference;
@@ -300,6 +405,10 @@ pub mod rem;
 pub mod root;
 pub(crate) mod round_near_x;
 pub mod round_to_integer;
+/// [`Sech`](malachite_base::num::arithmetic::traits::Sech) and
+/// [`SechAssign`](malachite_base::num::arithmetic::traits::SechAssign), traits for computing the
+/// hyperbolic secant of [`Float`](super::Float)s.
+pub mod sech;
 /// Left-shifting a [`Float`](super::Float) (multiplying it by a power of 2).
 ///
 /// # shl
@@ -767,6 +876,20 @@ pub mod shr_round;
 /// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign), a trait for
 /// determining the sign of a number.
 pub mod sign;
+/// [`Sin`](malachite_base::num::arithmetic::traits::Sin) and
+/// [`SinAssign`](malachite_base::num::arithmetic::traits::SinAssign), traits for computing the sine
+/// of [`Float`](super::Float)s.
+pub mod sin;
+/// [`SinCos`](malachite_base::num::arithmetic::traits::SinCos), a trait for computing the sine and
+/// cosine of a [`Float`](super::Float) simultaneously.
+pub mod sin_cos;
+/// [`Sinh`](malachite_base::num::arithmetic::traits::Sinh) and
+/// [`SinhAssign`](malachite_base::num::arithmetic::traits::SinhAssign), traits for computing the
+/// hyperbolic sine of [`Float`](super::Float)s.
+pub mod sinh;
+/// [`SinhCosh`](malachite_base::num::arithmetic::traits::SinhCosh), a trait for computing the
+/// hyperbolic sine and hyperbolic cosine of a [`Float`](super::Float) simultaneously.
+pub mod sinh_cosh;
 /// [`Sqrt`](malachite_base::num::arithmetic::traits::Sqrt) and
 /// [`SqrtAssign`](malachite_base::num::arithmetic::traits::SqrtAssign), traits for computing the
 /// square root of [`Float`](super::Float)s.
@@ -784,3 +907,19 @@ pub mod sub;
 /// rounding (fused multiply-subtract), and the associated precision- and rounding-mode-aware
 /// functions.
 pub mod sub_mul;
+/// [`Tan`](malachite_base::num::arithmetic::traits::Tan) and
+/// [`TanAssign`](malachite_base::num::arithmetic::traits::TanAssign), traits for computing the
+/// tangent of [`Float`](super::Float)s.
+pub mod tan;
+/// [`Tanh`](malachite_base::num::arithmetic::traits::Tanh) and
+/// [`TanhAssign`](malachite_base::num::arithmetic::traits::TanhAssign), traits for computing the
+/// hyperbolic tangent of [`Float`](super::Float)s.
+pub mod tanh;
+/// [`UpperIncompleteGamma`](malachite_base::num::arithmetic::traits::UpperIncompleteGamma) and
+/// [`UpperIncompleteGammaAssign`](malachite_base::num::arithmetic::traits::UpperIncompleteGammaAssign),
+/// traits for computing the upper incomplete gamma function of [`Float`](super::Float)s.
+pub mod upper_incomplete_gamma;
+/// [`Zeta`](malachite_base::num::arithmetic::traits::Zeta) and
+/// [`ZetaAssign`](malachite_base::num::arithmetic::traits::ZetaAssign), traits for computing the
+/// Riemann zeta function of [`Float`](super::Float)s.
+pub mod zeta;
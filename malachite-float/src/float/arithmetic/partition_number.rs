@@ -0,0 +1,287 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use core::cmp::Ordering;
+use core::f64::consts::{LN_2, PI};
+use malachite_base::num::arithmetic::traits::{PartitionNumber, SinhCosh, Sqrt};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::{self, Nearest};
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// The largest n for which p(n) fits in a u128; below it, p(n) is computed exactly with the
+// pentagonal recurrence instead.
+const MAX_U128_PARTITION_INDEX: u64 = 1458;
+
+// The smallest number of terms N for which Rademacher's bound on the remainder of the series,
+//
+// |R(n, N)| < 44 pi^2 / (225 sqrt(3)) N^(-1/2) + pi sqrt(2) / 75 (N / (n - 1))^(1/2) sinh(pi / N
+// sqrt(2n / 3)),
+//
+// is below 1/4. The bound is evaluated in double precision, which is ample for choosing N; the
+// result is checked afterwards in any case.
+fn hrr_term_count(n: u64) -> u64 {
+    let nf = n as f64;
+    let m = (nf - 1.0).max(1.0);
+    let c = PI * libm::sqrt(2.0 * nf / 3.0);
+    let first = 44.0 * PI * PI / (225.0 * libm::sqrt(3.0));
+    let second = PI * libm::sqrt(2.0) / 75.0;
+    let mut terms = 1;
+    loop {
+        let t = terms as f64;
+        if first / libm::sqrt(t) + second * libm::sqrt(t / m) * libm::sinh(c / t) < 0.25 {
+            return terms;
+        }
+        terms += 1;
+    }
+}
+
+// Sums cos(pi (6l + 1) / (6k)) (-1)^l over the l in [0, 2k) with (3l^2 + l) / 2 = -n mod k, at
+// precision `prec`. By Selberg's formula, this sum times sqrt(k / 3) is the Kloosterman-type sum
+// A_k(n) of the Rademacher series. Returns `None` if no l qualifies, in which case A_k(n) = 0.
+fn selberg_sum(n: u64, k: u64, pi: &Float, prec: u64) -> Option<Float> {
+    let target = (k - n % k) % k;
+    let mut sum: Option<Float> = None;
+    // f = (3l^2 + l) / 2 mod k, updated with f(l + 1) = f(l) + 3l + 2
+    let mut f = 0;
+    for l in 0..k << 1 {
+        if f == target {
+            let angle = Float::from_float_prec_ref(pi, prec).0
+                * Float::from_unsigned_prec(6 * l + 1, prec).0
+                / Float::from_unsigned_prec(6 * k, prec).0;
+            let c = angle.cos_prec(prec).0;
+            let c = if l & 1 == 0 { c } else { -c };
+            sum = Some(match sum {
+                None => c,
+                Some(s) => s + c,
+            });
+        }
+        f = u64::exact_from((u128::from(f) + 3 * u128::from(l) + 2) % u128::from(k));
+    }
+    sum
+}
+
+// Evaluates the Hardy–Ramanujan–Rademacher series for p(n) with `terms` terms and `guard` extra
+// bits of absolute accuracy per term. Requires n > 0.
+//
+// The kth term is
+//
+// A_k(n) sqrt(k) / (pi sqrt(2)) d/dn (sinh(C lambda / k) / lambda)
+//     = (k / sqrt(3)) S_k / (2 sqrt(2) pi lambda^2) ((C / k) cosh(C lambda / k) - sinh(C lambda /
+// k)     / lambda),
+//
+// where C = pi sqrt(2/3), lambda = sqrt(n - 1/24), and S_k is the Selberg sum. Since the kth term
+// has magnitude about exp(C lambda / k), the terms are computed at decreasing precisions.
+fn partition_number_hrr_sum(n: u64, terms: u64, guard: u64) -> Float {
+    let lambda_f64 = libm::sqrt(n as f64 - 1.0 / 24.0);
+    let c_f64 = PI * libm::sqrt(2.0 / 3.0);
+    // An estimate of the number of bits, above the binary point, needed for the kth term, including
+    // the bits lost to cancellation in the bracketed difference when C lambda / k is small.
+    let term_bits = |k: u64| {
+        let x = c_f64 * lambda_f64 / k as f64;
+        let size = (x / LN_2).max(0.0);
+        let cancellation = libm::log2(3.0 / (x * x)).max(0.0);
+        (size + cancellation) as u64 + 1
+    };
+    let top_prec = term_bits(1) + guard;
+    let pi = Float::pi_prec(top_prec).0;
+    let lambda = Float::from_rational_prec(
+        Rational::from_unsigneds(24 * u128::from(n) - 1, 24),
+        top_prec,
+    )
+    .0
+    .sqrt();
+    let c = &pi
+        * Float::from_rational_prec(Rational::from_unsigneds(2u8, 3), top_prec)
+            .0
+            .sqrt();
+    let sqrt_3 = Float::from_unsigned_prec(3u8, top_prec).0.sqrt();
+    // 2 sqrt(2) pi lambda^2 sqrt(3)
+    let denominator = Float::from_unsigned_prec(8u8, top_prec).0.sqrt()
+        * &pi
+        * Float::from_rational_prec(
+            Rational::from_unsigneds(24 * u128::from(n) - 1, 24),
+            top_prec,
+        )
+        .0
+        * &sqrt_3;
+    let mut sum = Float::from_unsigned_prec(0u8, top_prec).0;
+    for k in 1..=terms {
+        let prec = term_bits(k) + guard;
+        let Some(s) = selberg_sum(n, k, &pi, prec) else {
+            continue;
+        };
+        let k_float = Float::from_unsigned_prec(k, prec).0;
+        let lambda_k = Float::from_float_prec_ref(&lambda, prec).0;
+        let c_over_k = Float::from_float_prec_ref(&c, prec).0 / &k_float;
+        let (sinh, cosh) = (&c_over_k * &lambda_k).sinh_cosh();
+        let bracket = c_over_k * cosh - sinh / lambda_k;
+        sum += k_float * s * bracket / Float::from_float_prec_ref(&denominator, prec).0;
+    }
+    sum
+}
+
+/// Computes the number of partitions of $n$ with the Hardy–Ramanujan–Rademacher formula.
+///
+/// $$
+/// p(n) = \frac{1}{\pi \sqrt 2} \sum_{k=1}^\infty A_k(n) \sqrt{k} \frac{d}{dn} \left (
+/// \frac{\sinh \left ( \frac{\pi}{k} \sqrt{\frac{2}{3} \left ( n - \frac{1}{24} \right )}
+/// \right )}{\sqrt{n - \frac{1}{24}}} \right ).
+/// $$
+///
+/// The series is truncated where Rademacher's error bound falls below $1/4$, each term is evaluated
+/// with [`Float`]s at a precision just large enough for its magnitude, and the sum is rounded to
+/// the nearest integer. Only $O(\sqrt n)$ terms are needed, and all but the first few are computed
+/// at low precision, so for large $n$ this is much faster than
+/// [`Natural::partition_number`](malachite_base::num::arithmetic::traits::PartitionNumber), which
+/// uses a recurrence over all the smaller partition numbers.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(\sqrt n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Examples
+/// ```
+/// use malachite_float::float::arithmetic::partition_number::partition_number_hrr;
+///
+/// assert_eq!(partition_number_hrr(0), 1);
+/// assert_eq!(partition_number_hrr(5), 7);
+/// assert_eq!(partition_number_hrr(100), 190569292);
+/// assert_eq!(
+///     partition_number_hrr(2000).to_string(),
+///     "4720819175619413888601432406799959512200344166"
+/// );
+/// ```
+///
+/// This is equivalent to `arith_number_of_partitions` from FLINT 3.6.0, without the factored
+/// exponential sums.
+pub fn partition_number_hrr(n: u64) -> Natural {
+    if n == 0 {
+        return Natural::from(1u32);
+    }
+    let mut terms = hrr_term_count(n);
+    let mut guard = 20 + u64::exact_from(terms.ilog2()) + 1;
+    loop {
+        let sum = partition_number_hrr_sum(n, terms, guard);
+        let (p, _) = Natural::rounding_from(&sum, Nearest);
+        // The truncation error is below 1/4 and the rounding errors are far smaller, so the sum is
+        // within 1/4 of p(n); anything else means the estimates above were too optimistic.
+        let error = sum - Float::exact_from(&p);
+        if error == 0u32 || error.get_exponent().unwrap() < -1 {
+            return p;
+        }
+        terms <<= 1;
+        guard <<= 1;
+    }
+}
+
+impl Float {
+    /// Computes the number of partitions of $n$, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is also returned, indicating whether
+    /// the rounded value is less than, equal to, or greater than the exact value.
+    ///
+    /// The exact value is computed with the Hardy–Ramanujan–Rademacher formula (see
+    /// [`partition_number_hrr`]) and then rounded, so the result is identical to
+    /// `Float::from_natural_prec_round(Natural::partition_number(n), prec, rm)`.
+    ///
+    /// $$
+    /// f(n,p,m) = p(n)+\varepsilon.
+    /// $$
+    /// - If $p(n)$ is representable with $p$ bits, $\varepsilon$ is 0.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   p(n)\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   p(n)\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, p) = O(n + p)$
+    ///
+    /// $M(n, p) = O(\sqrt n + p)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $p$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and $p(n)$ is not exactly representable
+    /// with `prec` bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering::*;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    ///
+    /// let (f, o) = Float::partition_number_prec_round(10, 10, Exact);
+    /// assert_eq!(f.to_string(), "42.000");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (f, o) = Float::partition_number_prec_round(1000, 20, Floor);
+    /// assert_eq!(f.to_string(), "2.4061454e31");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (f, o) = Float::partition_number_prec_round(1000, 20, Ceiling);
+    /// assert_eq!(f.to_string(), "2.4061492e31");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn partition_number_prec_round(n: u64, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let p = if n <= MAX_U128_PARTITION_INDEX {
+            Natural::from(u128::partition_number(n))
+        } else {
+            partition_number_hrr(n)
+        };
+        Self::from_natural_prec_round(p, prec, rm)
+    }
+
+    /// Computes the number of partitions of $n$, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is also returned, indicating whether the rounded value
+    /// is less than, equal to, or greater than the exact value.
+    ///
+    /// If the value is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(n,p) = p(n)+\varepsilon.
+    /// $$
+    /// - If $p(n)$ is representable with $p$ bits, $\varepsilon$ is 0.
+    /// - Otherwise, $|\varepsilon| < 2^{\lfloor\log_2 p(n)\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::partition_number_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, p) = O(n + p)$
+    ///
+    /// $M(n, p) = O(\sqrt n + p)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $p$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::cmp::Ordering::*;
+    /// use malachite_float::Float;
+    ///
+    /// let (f, o) = Float::partition_number_prec(100, 10);
+    /// assert_eq!(f.to_string(), "1.9058e8");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn partition_number_prec(n: u64, prec: u64) -> (Self, Ordering) {
+        Self::partition_number_prec_round(n, prec, Nearest)
+    }
+}
@@ -121,12 +121,12 @@ fn power_of_10_x_minus_1_prec_round_normal(
     rm: RoundingMode,
 ) -> (Float, Ordering) {
     // Huge negative x: if |x| > 2 + (prec - 1) / 3 then 3 |x| >= prec + 3, so 10^x < 8^x <=
-    // 2^(-prec-3) <= (1/2) ulp(-1); thus 10^x - 1 rounds to -1 (for Floor, Up, and Nearest) or to
-    // nextabove(-1) (for Ceiling and Down).
+    // 2^(-prec-3) <= (1/2) ulp(-1); thus 10^x - 1 rounds to -1 (for Floor, Up, Nearest, and
+    // NearestTiesAway) or to nextabove(-1) (for Ceiling, Down, and Odd).
     if x.is_sign_negative() && x.gt_abs(&(2 + (prec - 1) / 3)) {
         return match rm {
-            Ceiling | Down => (-one_neighbor(prec, false), Greater),
-            Floor | Up | Nearest => (Float::negative_one_prec(prec), Less),
+            Ceiling | Down | Odd => (-one_neighbor(prec, false), Greater),
+            Floor | Up | Nearest | NearestTiesAway => (Float::negative_one_prec(prec), Less),
             Exact => panic!("Inexact power_of_10_x_minus_1"),
         };
     }
@@ -382,8 +382,9 @@ fn power_of_10_x_minus_1_rational_helper(
         // `prec` is enormous (>= MAX_EXPONENT), so `float_round_near_x` cannot resolve the
         // rounding; but 10^x is still far below ulp(-1), so -1 rounds the same way.
         return match rm {
-            Ceiling | Down => (-one_neighbor(prec, false), Greater), // -1 + ulp (toward zero)
-            _ => (-Float::one_prec(prec), Less),                     // -1
+            // -1 + ulp (toward zero)
+            Ceiling | Down | Odd => (-one_neighbor(prec, false), Greater),
+            _ => (-Float::one_prec(prec), Less), // -1
         };
     }
     // General case: bracket x between the Floats x_lo <= x <= x_hi, apply 10^x - 1 to both, and
@@ -507,8 +508,10 @@ impl Float {
     /// $$
     /// f(x,p,m) = 10^x-1+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x-1|\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |10^x-1|\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |10^x-1|\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |10^x-1|\rfloor-p}$.
     ///
     /// These bounds do not apply when the result overflows or underflows; see below.
     ///
@@ -518,22 +521,30 @@ impl Float {
     /// - $f(0,p,m)=0$.
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
     /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,p,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// Unlike $10^x$, $10^x-1$ never underflows to zero for large negative $x$: it instead tends to
     /// $-1$.
@@ -611,8 +622,10 @@ impl Float {
     /// $$
     /// f(x,p,m) = 10^x-1+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x-1|\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| \leq 2^{\lfloor\log_2 |10^x-1|\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |10^x-1|\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |10^x-1|\rfloor-p}$.
     ///
     /// These bounds do not apply when the result overflows or underflows; see below.
     ///
@@ -622,22 +635,30 @@ impl Float {
     /// - $f(0,p,m)=0$.
     ///
     /// Overflow and underflow:
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$ is
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
     ///   returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
     /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Ceiling` or `Down`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest`, $-2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<0$ and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,p,m)<0$ and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,p,m)<0$ and $m$ is `NearestTiesAway`, $-0.0$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)<-2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,p,m)\leq-2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $-2^{-2^{30}}$
+    ///   is returned instead.
     ///
     /// Unlike $10^x$, $10^x-1$ never underflows to zero for large negative $x$: it instead tends to
     /// $-1$.
@@ -24,7 +24,7 @@ impl Float {
     ///
     /// $f(k) = 2^k$, and the result has precision `prec`.
     ///
-    /// - If `pow` is greater than $2^{30}-2$ and `rm` is `Floor` or `Down`, the largest
+    /// - If `pow` is greater than $2^{30}-2$ and `rm` is `Floor`, `Down`, or `Odd`, the largest
     ///   representable `Float` with the given precision is returned.
     /// - If `pow` is greater than $2^{30}-2$ and `rm` is `Ceiling` or `Up`, or `Nearest`, $\infty$
     ///   is returned.
@@ -119,10 +119,14 @@ impl Float {
         }
         match (pow > 0, rm) {
             (_, Exact) => panic!("Inexact power_of_2"),
-            (true, Ceiling | Up | Nearest) => (Self::INFINITY, Greater),
+            (true, Ceiling | Up | Nearest | NearestTiesAway) => (Self::INFINITY, Greater),
             (true, _) => (Self::max_finite_value_with_prec(prec), Less),
-            (false, Floor | Down | Nearest) => (Self::ZERO, Less),
-            (false, Ceiling | Up) => (Self::min_positive_value_prec(prec), Greater),
+            // 2 ^ (MIN_EXPONENT - 2) is exactly half of the least positive value
+            (false, NearestTiesAway) if pow == Self::MIN_EXPONENT_MINUS_2_I64 => {
+                (Self::min_positive_value_prec(prec), Greater)
+            }
+            (false, Floor | Down | Nearest | NearestTiesAway) => (Self::ZERO, Less),
+            (false, Ceiling | Up | Odd) => (Self::min_positive_value_prec(prec), Greater),
         }
     }
 
@@ -88,11 +88,11 @@ fn power_of_2_x_minus_1_prec_round_normal(
     rm: RoundingMode,
 ) -> (Float, Ordering) {
     // Huge negative x: if |x| > prec + 1 then 2^x < (1/4) ulp(-1), so 2^x - 1 rounds to -1 (for
-    // Floor, Up, and Nearest) or to nextabove(-1) (for Ceiling and Down).
+    // Floor, Up, Nearest, and NearestTiesAway) or to nextabove(-1) (for Ceiling, Down, and Odd).
     if x.is_sign_negative() && x.gt_abs(&(prec + 1)) {
         return match rm {
-            Ceiling | Down => (-one_neighbor(prec, false), Greater),
-            Floor | Up | Nearest => (-Float::one_prec(prec), Less),
+            Ceiling | Down | Odd => (-one_neighbor(prec, false), Greater),
+            Floor | Up | Nearest | NearestTiesAway => (-Float::one_prec(prec), Less),
             Exact => panic!("Inexact power_of_2_x_minus_1"),
         };
     }
@@ -133,7 +133,7 @@ fn power_of_2_x_minus_1_prec_round_normal(
             // A magnitude in (min_positive / 2, min_positive) rounds either away from zero (to
             // +/-min_positive) or toward zero (to +/-0), depending on the rounding mode.
             let away = match rm {
-                Up | Nearest => true,
+                Up | Nearest | NearestTiesAway | Odd => true,
                 Ceiling => !neg,
                 Floor => neg,
                 Down => false,
@@ -409,8 +409,9 @@ fn power_of_2_x_minus_1_rational_helper(
         // `prec` is enormous (>= MAX_EXPONENT), so `float_round_near_x` cannot resolve the
         // rounding; but 2^x is still far below ulp(-1), so -1 rounds the same way.
         return match rm {
-            Ceiling | Down => (-one_neighbor(prec, false), Greater), // -1 + ulp (toward zero)
-            _ => (-Float::one_prec(prec), Less),                     // -1
+            // -1 + ulp (toward zero)
+            Ceiling | Down | Odd => (-one_neighbor(prec, false), Greater),
+            _ => (-Float::one_prec(prec), Less), // -1
         };
     }
     // General case: bracket x between the Floats x_lo <= x <= x_hi, apply 2^x - 1 to both, and
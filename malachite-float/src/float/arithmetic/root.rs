@@ -73,7 +73,7 @@ fn root_u_integer(x: &Float, k: u64, prec: u64, rm: RoundingMode) -> (Float, Ord
     let ki = i128::from(k);
     let r = e.rem_euclid(ki); // r = e mod k with 0 <= r < k
     // For rounding to nearest, we want the round bit to be in the root.
-    let n = i128::from(prec) + i128::from(rm == Nearest);
+    let n = i128::from(prec) + i128::from(rm == Nearest || rm == NearestTiesAway);
     let size_m = i128::from(m.significant_bits());
     // Shift m by t = k * f + r bits (leftwards for positive t, with truncation for negative t) so
     // that the root of m has exactly n bits: we want k * (n - 1) + 1 <= size_m + t <= k * n, so f =
@@ -115,7 +115,12 @@ fn root_u_integer(x: &Float, k: u64, prec: u64, rm: RoundingMode) -> (Float, Ord
         // Rounding modes are inverted for negative x, since the rounding decision below is made on
         // the magnitude.
         let rm_abs = if negative { -rm } else { rm };
-        o = if rm_abs == Ceiling || rm_abs == Up || (rm_abs == Nearest && root.odd()) {
+        // Since the root is inexact, it is never exactly halfway, so the two nearest modes agree.
+        o = if rm_abs == Ceiling
+            || rm_abs == Up
+            || ((rm_abs == Nearest || rm_abs == NearestTiesAway) && root.odd())
+            || (rm_abs == Odd && root.even())
+        {
             root += Natural::ONE;
             Greater
         } else {
@@ -123,7 +128,7 @@ fn root_u_integer(x: &Float, k: u64, prec: u64, rm: RoundingMode) -> (Float, Ord
         };
     }
     // Either o is not `Equal` and the conversion is exact, or o is `Equal` and the conversion
-    // rounds only when rm is `Nearest` and the exact root has n = prec + 1 bits (a midpoint).
+    // rounds only when rm is a nearest mode and the exact root has n = prec + 1 bits (a midpoint).
     let (y, o2) = Float::from_natural_prec(root, prec);
     debug_assert!(o == Equal || o2 == Equal);
     if o == Equal {
@@ -191,9 +196,13 @@ fn root_u_aux(x: &Float, k: u64, prec: u64, rm: RoundingMode) -> (Float, Orderin
             };
         }
         // If we fail to round correctly, check for an exact result or a midpoint result with
-        // `Nearest` (regarded as hard-to-round in all precisions in order to determine the ternary
-        // value).
-        let z = Float::from_float_prec_ref(&t, prec + u64::from(rm == Nearest)).0;
+        // `Nearest` or `NearestTiesAway` (regarded as hard-to-round in all precisions in order to
+        // determine the ternary value).
+        let z = Float::from_float_prec_ref(
+            &t,
+            prec + u64::from(rm == Nearest || rm == NearestTiesAway),
+        )
+        .0;
         let (zk, o_pow) = z.pow_u_prec_ref(k, x.significant_bits());
         if o_pow == Equal && zk == abs_x {
             // z is the exact root, so round z directly.
@@ -287,10 +296,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -355,10 +364,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -598,10 +607,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -660,10 +669,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -719,10 +728,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// Special cases: see [`Float::root_u_prec_round`].
     ///
@@ -834,10 +843,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -900,10 +909,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1171,10 +1180,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1231,10 +1240,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is the precision of the input.
     ///
@@ -1392,10 +1401,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1464,10 +1473,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1682,10 +1691,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -1748,10 +1757,10 @@ impl Float {
     /// $$
     /// - If $\sqrt\[k\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
     ///   be 0.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
-    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| \leq
-    ///   2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[k\]{x}$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\sqrt\[k\]{x}|\rfloor-p}$.
     ///
     /// If the output has a precision, it is `prec`.
     ///
@@ -14,10 +14,14 @@
 
 use crate::Float;
 use core::cmp::Ordering::{self, *};
-use malachite_base::num::arithmetic::traits::PowerOf2;
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{IsPowerOf2, NegAssign, PowerOf2};
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_nz::natural::arithmetic::float::round::float_can_round;
+use malachite_q::Rational;
 
 // Steps `y` to the next `prec`-precision value away from zero, like `mpfr_nexttoinf` (note that
 // `Float::increment` is not suitable: it does not preserve precision when crossing a power of 2).
@@ -42,6 +46,12 @@ fn step_toward_zero(y: Float, prec: u64) -> Float {
     y.mul_prec_round(mult, prec, Down).0
 }
 
+// Returns whether the last bit of `y`'s `prec`-bit significand is set; that is, whether `y` is not
+// representable with `prec - 1` bits. `prec` must be greater than 1.
+fn float_is_odd(y: &Float, prec: u64) -> bool {
+    Float::from_float_prec_round_ref(y, prec - 1, Down).1 != Equal
+}
+
 // Helper for fast rounding when a function's value is known to be very close to its argument (or to
 // another easily-computed value). Assuming the true value is f(x) = v + g(x) with |g(x)| <
 // 2^(EXP(v) - err), and that f(x) is not exactly representable, tries to determine round(f(x),
@@ -74,6 +84,8 @@ crate_test_fn! {float_round_near_x(
     rm: RoundingMode
 ) -> Option<(Float, Ordering)> {
     assert_ne!(rm, Exact, "Inexact float_round_near_x");
+    // At precision 1 every nonzero value has an odd significand, so `Odd` is the same as `Down`
+    let rm = if rm == Odd && prec == 1 { Down } else { rm };
     let sign = if v > &0u32 { Greater } else { Less };
     let prec_v = v.get_prec().unwrap();
     // First check if we can round. The test is more restrictive than necessary. (The C version
@@ -88,7 +100,7 @@ crate_test_fn! {float_round_near_x(
     // Round v to the target precision. In C this is MPFR_RNDRAW_GEN with a custom halfway-case
     // hook; here, the halfway case is detected separately: v rounds to a tie at `prec` iff it is
     // exactly representable in prec + 1 bits but not in prec bits.
-    if rm == Nearest {
+    if rm == Nearest || rm == NearestTiesAway {
         let (y_wide, o_wide) = Float::from_float_prec_round_ref(v, prec + 1, Down);
         if o_wide == Equal {
             let (y_trunc, o_trunc) = Float::from_float_prec_round(y_wide, prec, Down);
@@ -115,6 +127,8 @@ crate_test_fn! {float_round_near_x(
                 Floor => sign == Less,
                 Ceiling => sign == Greater,
                 Up => true,
+                // An even y is incremented to the odd value just beyond it
+                Odd => !float_is_odd(&y, prec),
                 _ => false,
             };
             if rounds_away {
@@ -128,6 +142,8 @@ crate_test_fn! {float_round_near_x(
                 Floor => sign == Greater,
                 Ceiling => sign == Less,
                 Down => true,
+                // An odd y is kept, since its predecessor is even and gets incremented back to y
+                Odd => !float_is_odd(&y, prec),
                 _ => false,
             };
             if rounds_to_zero {
@@ -139,3 +155,71 @@ crate_test_fn! {float_round_near_x(
     assert_ne!(o, Equal);
     Some((y, o))
 }}
+
+// The analogue of `float_round_near_x` for a nonzero `Rational` `v`, which need not be
+// representable as a `Float`: it may not be dyadic, or it may lie below the `Float` exponent range.
+// Assuming the true value is f(x) = v + g(x) with |g(x)| < 2^(EXP(v) - err), and that f(x) is not
+// exactly representable, tries to determine round(f(x), prec, rm) from v alone; `dir` has the same
+// meaning as in `float_round_near_x`.
+//
+// A dyadic `v` in the `Float` range is passed on to `float_round_near_x`. Otherwise `v` is not
+// itself a rounding boundary (with one exception, handled separately: half of the smallest positive
+// `Float`, which is the underflow boundary for `Nearest` and `NearestTiesAway`), and since every
+// boundary is a dyadic rational with at most `prec + 2` significant bits, its distance from `v` is
+// at least 2^(EXP(v) - b), where `b` is `prec + 3` plus the number of bits of `v`'s numerator (if
+// `v` is dyadic) or denominator (if it is not). If `err > b`, f(x) therefore rounds exactly as `v`
+// does.
+//
+// This has no analogue in MPFR, which has no rational inputs.
+pub(crate) fn rational_round_near_x(
+    v: &Rational,
+    err: u64,
+    dir: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    assert_ne!(rm, Exact, "Inexact rational_round_near_x");
+    let exp_v = v.floor_log_base_2_abs() + 1;
+    let numerator = v.numerator_ref();
+    let dyadic = v.denominator_ref().is_power_of_2();
+    if exp_v > Float::MAX_EXPONENT_I64 {
+        return None;
+    }
+    if dyadic && exp_v >= Float::MIN_EXPONENT_I64 {
+        let v =
+            Float::from_rational_prec_round_ref(v, max(1, numerator.significant_bits()), Exact).0;
+        return float_round_near_x(&v, err, dir, prec, rm);
+    }
+    let b = prec
+        + 3
+        + if dyadic {
+            numerator.significant_bits()
+        } else {
+            v.denominator_ref().significant_bits()
+        };
+    if err <= b {
+        return None;
+    }
+    let positive = *v > 0u32;
+    if (rm == Nearest || rm == NearestTiesAway)
+        && dyadic
+        && *numerator == 1u32
+        && exp_v == Float::MIN_EXPONENT_I64 - 1
+    {
+        // |v| is half the smallest positive Float, so the direction of the error term decides
+        // whether f(x) rounds to zero or to the smallest positive Float.
+        let (mut y, mut o) = if dir {
+            (Float::min_positive_value_prec(prec), Greater)
+        } else {
+            (Float::ZERO, Less)
+        };
+        if !positive {
+            y.neg_assign();
+            o = o.reverse();
+        }
+        return Some((y, o));
+    }
+    let (y, o) = Float::from_rational_prec_round_ref(v, prec, rm);
+    assert_ne!(o, Equal);
+    Some((y, o))
+}
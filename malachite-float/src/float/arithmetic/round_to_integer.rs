@@ -38,11 +38,11 @@ fn signed_one(sign: bool, prec: u64) -> Float {
 
 impl Float {
     // This is mpfr_rint from rint.c, MPFR 4.2.2, with the result's precision passed explicitly and
-    // with MPFR_RNDNA (round to nearest, ties away from zero) selected by the `ties_away` flag
-    // alongside `Nearest` rather than by a distinct rounding mode. Returns the rounded value, an
-    // `Ordering` comparing it to the exact input, and whether the input was an integer; the pair is
-    // a bijection with MPFR's refined ternary. The rounding is a single rounding to an integer
-    // representable at `prec`: no double rounding is performed.
+    // with MPFR_RNDNA (round to nearest, ties away from zero) selected by `NearestTiesAway`.
+    // Returns the rounded value, an `Ordering` comparing it to the exact input, and whether the
+    // input was an integer; the pair is a bijection with MPFR's refined ternary. The rounding
+    // is a single rounding to an integer representable at `prec`: no double rounding is
+    // performed.
 
     // The maximum finite value with the given sign at the given precision, for overflow in the
     // directed modes that cannot produce an infinity.
@@ -58,7 +58,6 @@ impl Float {
     fn round_to_integer_then_helper(
         &self,
         irm: RoundingMode,
-        ties_away: bool,
         prec: u64,
         rm: RoundingMode,
     ) -> (Self, Ordering) {
@@ -67,17 +66,15 @@ impl Float {
         }
         // Rounding to an integer at self's own precision is exact, unless the carry at the maximum
         // exponent overflows.
-        let t = self
-            .round_to_integer_helper(self.significant_bits(), irm, ties_away)
-            .0;
+        let t = self.round_to_integer_helper(self.significant_bits(), irm).0;
         if let Self(Infinity { sign }) = t {
             // The integer exceeds the exponent range; apply the final rounding mode's overflow
             // behavior, as mpfr_overflow does.
             let away = match rm {
                 Floor => !sign,
                 Ceiling => sign,
-                Down => false,
-                Up | Nearest => true,
+                Down | Odd => false,
+                Up | Nearest | NearestTiesAway => true,
                 Exact => panic!("overflow in round_to_integer_then with the Exact mode"),
             };
             return if away {
@@ -103,7 +100,9 @@ impl Float {
     /// precision of 2 bits is $12$: not first $10$, and then $10$ rounded again. The rounding mode
     /// gives the integer-rounding direction: `Floor` and `Ceiling` are the floor and ceiling
     /// functions, `Down` is truncation, `Up` rounds away from zero, and `Nearest` rounds to the
-    /// nearest integer with ties to even. `Exact` is not allowed.
+    /// nearest integer with ties to even, `NearestTiesAway` rounds to the nearest integer with ties
+    /// away from zero, and `Odd` truncates and then, if that was inexact and left an even result,
+    /// rounds away from zero instead. `Exact` is not allowed.
     ///
     /// The pair of the [`Ordering`] and the `bool` carries the same information as `mpfr_rint`'s
     /// ternary value: `(Equal, true)` means the input was an integer representable at the target
@@ -169,7 +168,7 @@ impl Float {
         prec: u64,
         rm: RoundingMode,
     ) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(prec, rm, false)
+        self.round_to_integer_helper(prec, rm)
     }
 
     /// Rounds a [`Float`] to an integer, representable at the specified precision, in the direction
@@ -183,7 +182,9 @@ impl Float {
     /// precision of 2 bits is $12$: not first $10$, and then $10$ rounded again. The rounding mode
     /// gives the integer-rounding direction: `Floor` and `Ceiling` are the floor and ceiling
     /// functions, `Down` is truncation, `Up` rounds away from zero, and `Nearest` rounds to the
-    /// nearest integer with ties to even. `Exact` is not allowed.
+    /// nearest integer with ties to even, `NearestTiesAway` rounds to the nearest integer with ties
+    /// away from zero, and `Odd` truncates and then, if that was inexact and left an even result,
+    /// rounds away from zero instead. `Exact` is not allowed.
     ///
     /// The pair of the [`Ordering`] and the `bool` carries the same information as `mpfr_rint`'s
     /// ternary value: `(Equal, true)` means the input was an integer representable at the target
@@ -249,7 +250,7 @@ impl Float {
         prec: u64,
         rm: RoundingMode,
     ) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(prec, rm, false)
+        self.round_to_integer_helper(prec, rm)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the specified precision, with
@@ -300,7 +301,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_prec(self, prec: u64) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(prec, Nearest, false)
+        self.round_to_integer_helper(prec, Nearest)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the specified precision, with
@@ -351,7 +352,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_prec_ref(&self, prec: u64) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(prec, Nearest, false)
+        self.round_to_integer_helper(prec, Nearest)
     }
 
     /// Rounds a [`Float`] to an integer, representable at the input's own precision, in the
@@ -361,7 +362,9 @@ impl Float {
     ///
     /// The rounding mode gives the integer-rounding direction: `Floor` and `Ceiling` are the floor
     /// and ceiling functions, `Down` is truncation, `Up` rounds away from zero, and `Nearest`
-    /// rounds to the nearest integer with ties to even. `Exact` is not allowed.
+    /// rounds to the nearest integer with ties to even, `NearestTiesAway` rounds to the nearest
+    /// integer with ties away from zero, and `Odd` truncates and then, if that was inexact and left
+    /// an even result, rounds away from zero instead. `Exact` is not allowed.
     ///
     /// The pair of the [`Ordering`] and the `bool` carries the same information as `mpfr_rint`'s
     /// ternary value: `(Equal, true)` means the input was an integer representable at the target
@@ -404,7 +407,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_round(self, rm: RoundingMode) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(self.significant_bits(), rm, false)
+        self.round_to_integer_helper(self.significant_bits(), rm)
     }
 
     /// Rounds a [`Float`] to an integer, representable at the input's own precision, in the
@@ -414,7 +417,9 @@ impl Float {
     ///
     /// The rounding mode gives the integer-rounding direction: `Floor` and `Ceiling` are the floor
     /// and ceiling functions, `Down` is truncation, `Up` rounds away from zero, and `Nearest`
-    /// rounds to the nearest integer with ties to even. `Exact` is not allowed.
+    /// rounds to the nearest integer with ties to even, `NearestTiesAway` rounds to the nearest
+    /// integer with ties away from zero, and `Odd` truncates and then, if that was inexact and left
+    /// an even result, rounds away from zero instead. `Exact` is not allowed.
     ///
     /// The pair of the [`Ordering`] and the `bool` carries the same information as `mpfr_rint`'s
     /// ternary value: `(Equal, true)` means the input was an integer representable at the target
@@ -457,7 +462,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_round_ref(&self, rm: RoundingMode) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(self.significant_bits(), rm, false)
+        self.round_to_integer_helper(self.significant_bits(), rm)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the input's own precision, with
@@ -499,7 +504,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer(self) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(self.significant_bits(), Nearest, false)
+        self.round_to_integer_helper(self.significant_bits(), Nearest)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the input's own precision, with
@@ -541,7 +546,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_ref(&self) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(self.significant_bits(), Nearest, false)
+        self.round_to_integer_helper(self.significant_bits(), Nearest)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the specified precision, with
@@ -593,7 +598,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_ties_away_prec(self, prec: u64) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(prec, Nearest, true)
+        self.round_to_integer_helper(prec, NearestTiesAway)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the specified precision, with
@@ -645,7 +650,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_ties_away_prec_ref(&self, prec: u64) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(prec, Nearest, true)
+        self.round_to_integer_helper(prec, NearestTiesAway)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the input's own precision, with
@@ -693,7 +698,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_ties_away(self) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(self.significant_bits(), Nearest, true)
+        self.round_to_integer_helper(self.significant_bits(), NearestTiesAway)
     }
 
     /// Rounds a [`Float`] to the nearest integer representable at the input's own precision, with
@@ -741,7 +746,7 @@ impl Float {
     /// ```
     #[inline]
     pub fn round_to_integer_ties_away_ref(&self) -> (Self, Ordering, bool) {
-        self.round_to_integer_helper(self.significant_bits(), Nearest, true)
+        self.round_to_integer_helper(self.significant_bits(), NearestTiesAway)
     }
 
     /// Rounds a [`Float`] to an integer in the direction `irm`, and then correctly rounds that
@@ -795,7 +800,7 @@ impl Float {
         rm: RoundingMode,
     ) -> (Self, Ordering) {
         assert_ne!(irm, Exact);
-        self.round_to_integer_then_helper(irm, false, prec, rm)
+        self.round_to_integer_then_helper(irm, prec, rm)
     }
 
     /// Rounds a [`Float`] to an integer in the direction `irm`, and then correctly rounds that
@@ -849,7 +854,7 @@ impl Float {
         rm: RoundingMode,
     ) -> (Self, Ordering) {
         assert_ne!(irm, Exact);
-        self.round_to_integer_then_helper(irm, false, prec, rm)
+        self.round_to_integer_then_helper(irm, prec, rm)
     }
 
     /// Rounds a [`Float`] to the nearest integer with ties going away from zero, and then correctly
@@ -897,7 +902,7 @@ impl Float {
         prec: u64,
         rm: RoundingMode,
     ) -> (Self, Ordering) {
-        self.round_to_integer_then_helper(Nearest, true, prec, rm)
+        self.round_to_integer_then_helper(NearestTiesAway, prec, rm)
     }
 
     /// Rounds a [`Float`] to the nearest integer with ties going away from zero, and then correctly
@@ -945,14 +950,13 @@ impl Float {
         prec: u64,
         rm: RoundingMode,
     ) -> (Self, Ordering) {
-        self.round_to_integer_then_helper(Nearest, true, prec, rm)
+        self.round_to_integer_then_helper(NearestTiesAway, prec, rm)
     }
 
     pub(crate) fn round_to_integer_helper(
         &self,
         prec: u64,
         rm: RoundingMode,
-        ties_away: bool,
     ) -> (Self, Ordering, bool) {
         assert_ne!(prec, 0);
         let Self(Finite {
@@ -976,16 +980,18 @@ impl Float {
             Ceiling => Some(sign),
             Down => Some(false),
             Up => Some(true),
-            Nearest => None,
+            Nearest | NearestTiesAway | Odd => None,
             Exact => panic!("round_to_integer with the Exact rounding mode"),
         };
         if exp <= 0 {
             // 0 < |u| < 1, so the result is 0 or +/-1, and the input is never an integer. In the
             // Nearest mode, 1/2 rounds to 0 by the even rule, but to +/-1 when ties go away from
-            // zero.
+            // zero. The Odd mode always gives +/-1, since 0 is even.
             let away = match rnd_away {
                 Some(away) => away,
-                None => exp == 0 && (ties_away || !significand.is_power_of_2()),
+                None => {
+                    rm == Odd || exp == 0 && (rm == NearestTiesAway || !significand.is_power_of_2())
+                }
             };
             return if away {
                 (
@@ -1004,7 +1010,7 @@ impl Float {
         // Now exp > 0, so |u| >= 1.
         let (rp, exp_increment, uflags, rnd_away) =
             with_float_significand_limbs(significand, |up| {
-                limbs_float_round_to_integer(up, u64::exact_from(exp), prec, rnd_away, ties_away)
+                limbs_float_round_to_integer(up, u64::exact_from(exp), prec, rnd_away, rm)
             });
         if uflags == 0 {
             return (
@@ -0,0 +1,994 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::sinh_cosh::{hyperbolic_argument, sech_rational_helper};
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Sech, SechAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], rounding the result
+    /// to the specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded hyperbolic secant is
+    /// less than, equal to, or greater than the exact hyperbolic secant. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{sech} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{sech} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{sech} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{sech}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{sech} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{sech}
+    ///   x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// Since $0<\operatorname{sech} x\leq 1$, overflow is not possible. The result underflows when
+    /// $|x|$ is greater than about $7.44\times 10^8$:
+    ///
+    /// Underflow:
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sech_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sech_round`] instead. If both of these things are true, consider using
+    /// [`Float::sech`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round(5, Floor);
+    /// assert_eq!(sech.to_string(), "0.625");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round(5, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round(5, Nearest);
+    /// assert_eq!(sech.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round(20, Floor);
+    /// assert_eq!(sech.to_string(), "0.64805412");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round(20, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.64805508");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round(20, Nearest);
+    /// assert_eq!(sech.to_string(), "0.64805412");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sech_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.sech_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], rounding the result
+    /// to the specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded hyperbolic
+    /// secant is less than, equal to, or greater than the exact hyperbolic secant. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sech_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sech_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).sech()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round_ref(5, Floor);
+    /// assert_eq!(sech.to_string(), "0.625");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round_ref(5, Nearest);
+    /// assert_eq!(sech.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round_ref(20, Floor);
+    /// assert_eq!(sech.to_string(), "0.64805412");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.64805508");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_round_ref(20, Nearest);
+    /// assert_eq!(sech.to_string(), "0.64805412");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn sech_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => (Self::NAN, Equal),
+            Infinity { .. } => (Self::ZERO, Equal),
+            Zero { .. } => (Self::one_prec(prec), Equal),
+            Finite { .. } => sech_rational_helper(&hyperbolic_argument(self), prec, rm),
+        }
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], rounding the result
+    /// to the nearest value of the specified precision. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic secant is less
+    /// than, equal to, or greater than the exact hyperbolic secant. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic secant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sech_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::sech`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec(5);
+    /// assert_eq!(sech.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec(20);
+    /// assert_eq!(sech.to_string(), "0.64805412");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sech_prec(self, prec: u64) -> (Self, Ordering) {
+        self.sech_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], rounding the result
+    /// to the nearest value of the specified precision. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic secant is less
+    /// than, equal to, or greater than the exact hyperbolic secant. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic secant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sech_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).sech()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_ref(5);
+    /// assert_eq!(sech.to_string(), "0.656");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from_unsigned_prec(1u32, 100).0.sech_prec_ref(20);
+    /// assert_eq!(sech.to_string(), "0.64805412");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sech_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.sech_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], rounding the result
+    /// to the precision of the input and with the specified rounding mode. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded hyperbolic secant
+    /// is less than, equal to, or greater than the exact hyperbolic secant. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sech_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::sech`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::from(PI).sech_round(Floor);
+    /// assert_eq!(sech.to_string(), "0.086266738334054405");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::from(PI).sech_round(Ceiling);
+    /// assert_eq!(sech.to_string(), "0.086266738334054516");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from(PI).sech_round(Nearest);
+    /// assert_eq!(sech.to_string(), "0.086266738334054405");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sech_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.sech_prec_round(prec, rm)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], rounding the result
+    /// to the precision of the input and with the specified rounding mode. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded hyperbolic
+    /// secant is less than, equal to, or greater than the exact hyperbolic secant. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sech_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).sech()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::from(PI).sech_round_ref(Floor);
+    /// assert_eq!(sech.to_string(), "0.086266738334054405");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::from(PI).sech_round_ref(Ceiling);
+    /// assert_eq!(sech.to_string(), "0.086266738334054516");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::from(PI).sech_round_ref(Nearest);
+    /// assert_eq!(sech.to_string(), "0.086266738334054405");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sech_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.sech_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], in place, rounding
+    /// the result to the specified precision and with the specified rounding mode. An [`Ordering`]
+    /// is returned, indicating whether the rounded hyperbolic secant is less than, equal to, or
+    /// greater than the exact hyperbolic secant. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sech_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sech_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::sech_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.sech_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.64805412");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.sech_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.64805508");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.sech_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.64805412");
+    /// ```
+    #[inline]
+    pub fn sech_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.sech_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], in place, rounding
+    /// the result to the nearest value of the specified precision. An [`Ordering`] is returned,
+    /// indicating whether the rounded hyperbolic secant is less than, equal to, or greater than the
+    /// exact hyperbolic secant. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic secant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sech_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::sech_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.sech_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.656");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.sech_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.64805412");
+    /// ```
+    #[inline]
+    pub fn sech_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sech_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], in place, rounding
+    /// the result to the precision of the input and with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded hyperbolic secant is less than,
+    /// equal to, or greater than the exact hyperbolic secant. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sech_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::sech_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sech_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.086266738334054405");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sech_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.086266738334054516");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sech_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.086266738334054405");
+    /// ```
+    #[inline]
+    pub fn sech_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sech_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Rational`], rounding the
+    /// result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic secant is less than, equal to, or greater than the
+    /// exact hyperbolic secant.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{sech} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{sech} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{sech}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{sech} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{sech}
+    ///   x\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sech_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(sech.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(sech.to_string(), "0.94690514");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.94690609");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sech_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::sech_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Rational`], rounding the
+    /// result to the specified precision and with the specified rounding mode and returning the
+    /// result as a [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded hyperbolic secant is less than, equal to, or
+    /// greater than the exact hyperbolic secant.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{sech} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{sech} x$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 \operatorname{sech}
+    ///   x\rfloor-p+1}$.
+    /// - If $\operatorname{sech} x$ is finite and nonzero, and $m$ is `Nearest` or
+    ///   `NearestTiesAway`, then $|\varepsilon| \leq 2^{\lfloor\log_2 \operatorname{sech}
+    ///   x\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sech_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(sech.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(sech.to_string(), "0.94690514");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(sech.to_string(), "0.94690609");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn sech_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::one_prec(prec), Equal);
+        }
+        sech_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Rational`], rounding the
+    /// result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic secant is less than, equal to, or greater than the exact
+    /// hyperbolic secant.
+    ///
+    /// If the hyperbolic secant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sech_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::sech_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(sech.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(sech.to_string(), "0.94690514");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sech_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::sech_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Rational`], rounding the
+    /// result to the nearest value of the specified precision and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic secant is less than, equal to, or greater than the
+    /// exact hyperbolic secant.
+    ///
+    /// If the hyperbolic secant is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sech_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(sech.to_string(), "0.938");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sech, o) = Float::sech_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(sech.to_string(), "0.94690514");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sech_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::sech_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Sech for Float {
+    type Output = Self;
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic secant is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{sech} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{sech} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{sech} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{sech} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::sech_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::sech_round`].
+    /// If you want both of these things, consider using [`Float::sech_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sech;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.sech().is_nan());
+    /// assert_eq!(Float::INFINITY.sech(), 0.0);
+    /// assert_eq!(Float::ZERO.sech(), 1.0);
+    /// assert_eq!(Float::from(PI).sech().to_string(), "0.086266738334054405");
+    /// assert_eq!(Float::from_unsigned_prec(1u32, 100).0.sech().to_string(), "0.64805427366388539957497735322643");
+    /// ```
+    #[inline]
+    fn sech(self) -> Self {
+        let prec = self.significant_bits();
+        self.sech_prec_round(prec, Nearest).0
+    }
+}
+
+impl Sech for &Float {
+    type Output = Float;
+
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], taking it by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic secant is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{sech} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{sech} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{sech} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{sech} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sech_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sech_round_ref`]. If you want both of these things, consider using
+    /// [`Float::sech_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sech;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).sech().is_nan());
+    /// assert_eq!((&Float::INFINITY).sech(), 0.0);
+    /// assert_eq!((&Float::ZERO).sech(), 1.0);
+    /// assert_eq!((&Float::from(PI)).sech().to_string(), "0.086266738334054405");
+    /// assert_eq!((&Float::from_unsigned_prec(1u32, 100).0).sech().to_string(), "0.64805427366388539957497735322643");
+    /// ```
+    #[inline]
+    fn sech(self) -> Float {
+        let prec = self.significant_bits();
+        self.sech_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl SechAssign for Float {
+    /// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic secant is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \operatorname{sech} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{sech} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{sech} x$ is finite and nonzero, then $|\varepsilon| \leq
+    ///   2^{\lfloor\log_2 \operatorname{sech} x\rfloor-p}$, where $p$ is the precision of the
+    ///   input.
+    ///
+    /// See the [`Float::sech_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SechAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.sech_assign();
+    /// assert_eq!(x.to_string(), "0.086266738334054405");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// x.sech_assign();
+    /// assert_eq!(x.to_string(), "0.64805427366388539957497735322643");
+    /// ```
+    #[inline]
+    fn sech_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.sech_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\operatorname{sech} x$, the hyperbolic secant of a primitive float, returning a
+/// correctly-rounded result.
+///
+/// $$
+/// f(x) = \operatorname{sech} x+\varepsilon.
+/// $$
+/// - If $\operatorname{sech} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{sech} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   \operatorname{sech} x\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=\text{NaN}$
+/// - $f(\pm\infty)=0.0$
+/// - $f(\pm0.0)=1.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::sech::primitive_float_sech;
+///
+/// assert!(primitive_float_sech(f64::NAN).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_sech(f64::NEGATIVE_INFINITY)), NiceFloat(0.0));
+/// assert_eq!(NiceFloat(primitive_float_sech(-0.0f64)), NiceFloat(1.0));
+/// assert_eq!(NiceFloat(primitive_float_sech(PI)), NiceFloat(0.08626673833405442));
+/// assert_eq!(NiceFloat(primitive_float_sech(1.0f32)), NiceFloat(0.6480543));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_sech<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::sech_prec, x)
+}
+
+/// Computes $\operatorname{sech} x$, the hyperbolic secant of a [`Rational`], returning the result
+/// as a primitive float.
+///
+/// $$
+/// f(x) = \operatorname{sech} x+\varepsilon.
+/// $$
+/// - If $\operatorname{sech} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\operatorname{sech} x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   \operatorname{sech} x\rfloor-p}$, where $p$ is the precision of the output (typically 24 if
+///   `T` is a [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=1.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::sech::primitive_float_sech_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_sech_rational::<f64>(&Rational::from_unsigneds(1u8, 3))), NiceFloat(0.9469052537634979));
+/// assert_eq!(NiceFloat(primitive_float_sech_rational::<f64>(&Rational::from(10000))), NiceFloat(0.0));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_sech_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::sech_rational_prec_ref, x)
+}
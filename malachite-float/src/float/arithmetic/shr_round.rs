@@ -44,29 +44,30 @@ impl Float {
             assert!(rm != Exact, "Inexact Float right-shift");
             if bits < T::ZERO {
                 match (*sign, rm) {
-                    (true, Up | Ceiling | Nearest) => {
+                    (true, Up | Ceiling | Nearest | NearestTiesAway) => {
                         *self = Self::INFINITY;
                         Greater
                     }
-                    (true, Floor | Down) => {
+                    (true, Floor | Down | Odd) => {
                         *self = Self::max_finite_value_with_prec(prec);
                         Less
                     }
-                    (false, Up | Floor | Nearest) => {
+                    (false, Up | Floor | Nearest | NearestTiesAway) => {
                         *self = Self::NEGATIVE_INFINITY;
                         Less
                     }
-                    (false, Ceiling | Down) => {
+                    (false, Ceiling | Down | Odd) => {
                         *self = -Self::max_finite_value_with_prec(prec);
                         Greater
                     }
                     (_, Exact) => unreachable!(),
                 }
-            } else if rm == Nearest
+            } else if (rm == Nearest || rm == NearestTiesAway)
                 && possibly_just_under_min
                 && *exponent - <T as SaturatingInto<i32>>::saturating_into(bits)
                     == Self::MIN_EXPONENT_MINUS_1
                 && (previous_o == if *sign { Less } else { Greater }
+                    || rm == NearestTiesAway && previous_o == Equal
                     || !significand.is_power_of_2())
             {
                 if *sign {
@@ -78,19 +79,19 @@ impl Float {
                 }
             } else {
                 match (*sign, rm) {
-                    (true, Up | Ceiling) => {
+                    (true, Up | Ceiling | Odd) => {
                         *self = Self::min_positive_value_prec(prec);
                         Greater
                     }
-                    (true, Floor | Down | Nearest) => {
+                    (true, Floor | Down | Nearest | NearestTiesAway) => {
                         *self = Self::ZERO;
                         Less
                     }
-                    (false, Up | Floor) => {
+                    (false, Up | Floor | Odd) => {
                         *self = -Self::min_positive_value_prec(prec);
                         Less
                     }
-                    (false, Ceiling | Down | Nearest) => {
+                    (false, Ceiling | Down | Nearest | NearestTiesAway) => {
                         *self = Self::NEGATIVE_ZERO;
                         Greater
                     }
@@ -111,27 +112,36 @@ impl Float {
     /// f(x,k,p,m) = x/2^k.
     /// $$
     ///
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is `prec`.
     /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,k,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,k,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,k,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,k,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,k,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,k,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,k,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::shr_prec`] instead. If you
     /// know that your target precision is the precision of the input, consider using
@@ -170,27 +180,36 @@ impl Float {
     /// f(x,k,p,m) = x/2^k.
     /// $$
     ///
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is `prec`.
     /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,k,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,k,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,k,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,k,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,k,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,k,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,k,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::shr_prec_ref`] instead. If
     /// you know that your target precision is the precision of the input, consider using
@@ -230,27 +249,36 @@ impl Float {
     /// x \gets x/2^k.
     /// $$
     ///
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$ is
-    ///   returned instead.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`, $(1-(1/2)^p)2^{2^{30}-1}$
-    ///   is returned instead, where `p` is the precision of the input.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$ is
-    ///   returned instead.
-    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the input.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $-\infty$ is returned instead.
+    /// - If $f(x,k,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
     ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is `prec`.
     /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
-    /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $0<f(x,k,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-    /// - If $2^{-2^{30}-1}<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is returned
-    ///   instead.
+    /// - If $0<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,k,p,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,k,p,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,k,p,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
     /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
     ///   instead.
-    /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is returned
-    ///   instead.
-    /// - If $-2^{-2^{30}-1}\leq f(x,k,p,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned instead.
-    /// - If $-2^{-2^{30}}<f(x,k,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $-2^{-2^{30}-1}\leq f(x,k,p,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`, $-0.0$ is
     ///   returned instead.
+    /// - If $-2^{-2^{30}-1}<f(x,k,p,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
+    ///   instead.
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
+    /// - If $-2^{-2^{30}}<f(x,k,p,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+    ///   $-2^{-2^{30}}$ is returned instead.
     ///
     /// If you know you'll be using `Nearest`, consider using [`Float::shr_prec_assign`] instead. If
     /// you know that your target precision is the precision of the input, consider using
@@ -423,19 +451,19 @@ fn shr_round_primitive_int_ref<T: PrimitiveInt>(
         assert!(rm != Exact, "Inexact Float right-shift");
         if bits < T::ZERO {
             match (*sign, rm) {
-                (true, Up | Ceiling | Nearest) => (Float::INFINITY, Greater),
-                (true, Floor | Down) => (Float::max_finite_value_with_prec(*precision), Less),
-                (false, Up | Floor | Nearest) => (Float::NEGATIVE_INFINITY, Less),
-                (false, Ceiling | Down) => {
+                (true, Up | Ceiling | Nearest | NearestTiesAway) => (Float::INFINITY, Greater),
+                (true, Floor | Down | Odd) => (Float::max_finite_value_with_prec(*precision), Less),
+                (false, Up | Floor | Nearest | NearestTiesAway) => (Float::NEGATIVE_INFINITY, Less),
+                (false, Ceiling | Down | Odd) => {
                     (-Float::max_finite_value_with_prec(*precision), Greater)
                 }
                 (_, Exact) => unreachable!(),
             }
-        } else if rm == Nearest
+        } else if (rm == Nearest || rm == NearestTiesAway)
             && possibly_just_under_min
             && *exponent - <T as SaturatingInto<i32>>::saturating_into(bits)
                 == Float::MIN_EXPONENT_MINUS_1
-            && !significand.is_power_of_2()
+            && (rm == NearestTiesAway || !significand.is_power_of_2())
         {
             if *sign {
                 (Float::min_positive_value_prec(*precision), Greater)
@@ -444,10 +472,12 @@ fn shr_round_primitive_int_ref<T: PrimitiveInt>(
             }
         } else {
             match (*sign, rm) {
-                (true, Up | Ceiling) => (Float::min_positive_value_prec(*precision), Greater),
-                (true, Floor | Down | Nearest) => (Float::ZERO, Less),
-                (false, Up | Floor) => (-Float::min_positive_value_prec(*precision), Less),
-                (false, Ceiling | Down | Nearest) => (Float::NEGATIVE_ZERO, Greater),
+                (true, Up | Ceiling | Odd) => (Float::min_positive_value_prec(*precision), Greater),
+                (true, Floor | Down | Nearest | NearestTiesAway) => (Float::ZERO, Less),
+                (false, Up | Floor | Odd) => (-Float::min_positive_value_prec(*precision), Less),
+                (false, Ceiling | Down | Nearest | NearestTiesAway) => {
+                    (Float::NEGATIVE_ZERO, Greater)
+                }
                 (_, Exact) => unreachable!(),
             }
         }
@@ -479,31 +509,40 @@ macro_rules! impl_natural_shr_round {
             /// f(x,k,m) = x/2^k.
             /// $$
             ///
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$
-            ///   is returned instead.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`,
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+            ///   `NearestTiesAway`, $\infty$ is returned instead.
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
             ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the
             ///   input.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$
-            ///   is returned instead.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+            ///   `NearestTiesAway`, $-\infty$ is returned instead.
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
             ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the
             ///   input.
             /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned
             ///   instead.
-            /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is
-            ///   returned instead.
-            /// - If $0<f(x,k,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-            /// - If $2^{-2^{30}-1}<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
-            ///   returned instead.
+            /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$
+            ///   is returned instead.
+            /// - If $0<f(x,k,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$
+            ///   is returned instead.
+            /// - If $0<f(x,k,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
+            ///   instead.
+            /// - If $2^{-2^{30}-1}<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Nearest` or
+            ///   `NearestTiesAway`, $2^{-2^{30}}$ is returned instead.
+            /// - If $2^{-2^{30}-1}\leq f(x,k,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+            ///   $2^{-2^{30}}$ is returned instead.
             /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
             ///   instead.
-            /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is
-            ///   returned instead.
-            /// - If $-2^{-2^{30}-1}\leq f(x,k,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned
+            /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$
+            ///   is returned instead.
+            /// - If $-2^{-2^{30}-1}\leq f(x,k,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`,
+            ///   $-0.0$ is returned instead.
+            /// - If $-2^{-2^{30}-1}<f(x,k,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
             ///   instead.
-            /// - If $-2^{-2^{30}}<f(x,k,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
-            ///   returned instead.
+            /// - If $-2^{-2^{30}}<f(x,k,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or
+            ///   `NearestTiesAway`, $-2^{-2^{30}}$ is returned instead.
+            /// - If $-2^{-2^{30}}<f(x,k,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+            ///   $-2^{-2^{30}}$ is returned instead.
             ///
             /// If you don't care about overflow or underflow behavior, or only want the behavior of
             /// the `Nearest` rounding mode, you can just use `>>` instead.
@@ -540,31 +579,40 @@ macro_rules! impl_natural_shr_round {
             /// f(x,k,m) = x/2^k.
             /// $$
             ///
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$
-            ///   is returned instead.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`,
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+            ///   `NearestTiesAway`, $\infty$ is returned instead.
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
             ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the
             ///   input.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$
-            ///   is returned instead.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+            ///   `NearestTiesAway`, $-\infty$ is returned instead.
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
             ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the
             ///   input.
             /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned
             ///   instead.
-            /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is
-            ///   returned instead.
-            /// - If $0<f(x,k,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-            /// - If $2^{-2^{30}-1}<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
-            ///   returned instead.
+            /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$
+            ///   is returned instead.
+            /// - If $0<f(x,k,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$
+            ///   is returned instead.
+            /// - If $0<f(x,k,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
+            ///   instead.
+            /// - If $2^{-2^{30}-1}<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Nearest` or
+            ///   `NearestTiesAway`, $2^{-2^{30}}$ is returned instead.
+            /// - If $2^{-2^{30}-1}\leq f(x,k,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+            ///   $2^{-2^{30}}$ is returned instead.
             /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
             ///   instead.
-            /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is
-            ///   returned instead.
-            /// - If $-2^{-2^{30}-1}\leq f(x,k,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned
+            /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$
+            ///   is returned instead.
+            /// - If $-2^{-2^{30}-1}\leq f(x,k,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`,
+            ///   $-0.0$ is returned instead.
+            /// - If $-2^{-2^{30}-1}<f(x,k,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
             ///   instead.
-            /// - If $-2^{-2^{30}}<f(x,k,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
-            ///   returned instead.
+            /// - If $-2^{-2^{30}}<f(x,k,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or
+            ///   `NearestTiesAway`, $-2^{-2^{30}}$ is returned instead.
+            /// - If $-2^{-2^{30}}<f(x,k,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+            ///   $-2^{-2^{30}}$ is returned instead.
             ///
             /// If you don't care about overflow or underflow behavior, or only want the behavior of
             /// the `Nearest` rounding mode, you can just use `>>` instead.
@@ -598,31 +646,40 @@ macro_rules! impl_natural_shr_round {
             /// x\gets x/2^k.
             /// $$
             ///
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, or `Nearest`, $\infty$
-            ///   is returned instead.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor` or `Down`,
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+            ///   `NearestTiesAway`, $\infty$ is returned instead.
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
             ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the
             ///   input.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, or `Nearest`, $-\infty$
-            ///   is returned instead.
-            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling` or `Down`,
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Up`, `Nearest`, or
+            ///   `NearestTiesAway`, $-\infty$ is returned instead.
+            /// - If $f(x,k,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Down`, or `Odd`,
             ///   $-(1-(1/2)^p)2^{2^{30}-1}$ is returned instead, where `p` is the precision of the
             ///   input.
             /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Floor` or `Down`, $0.0$ is returned
             ///   instead.
-            /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Ceiling` or `Up`, $2^{-2^{30}}$ is
-            ///   returned instead.
-            /// - If $0<f(x,k,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest`, $0.0$ is returned instead.
-            /// - If $2^{-2^{30}-1}<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Nearest`, $2^{-2^{30}}$ is
-            ///   returned instead.
+            /// - If $0<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$
+            ///   is returned instead.
+            /// - If $0<f(x,k,m)\leq2^{-2^{30}-1}$, and $m$ is `Nearest` or `NearestTiesAway`, $0.0$
+            ///   is returned instead.
+            /// - If $0<f(x,k,m)<2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`, $0.0$ is returned
+            ///   instead.
+            /// - If $2^{-2^{30}-1}<f(x,k,m)<2^{-2^{30}}$, and $m$ is `Nearest` or
+            ///   `NearestTiesAway`, $2^{-2^{30}}$ is returned instead.
+            /// - If $2^{-2^{30}-1}\leq f(x,k,m)<2^{-2^{30}}$, and $m$ is `NearestTiesAway`,
+            ///   $2^{-2^{30}}$ is returned instead.
             /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Ceiling` or `Down`, $-0.0$ is returned
             ///   instead.
-            /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Floor` or `Up`, $-2^{-2^{30}}$ is
-            ///   returned instead.
-            /// - If $-2^{-2^{30}-1}\leq f(x,k,m)<0$, and $m$ is `Nearest`, $-0.0$ is returned
+            /// - If $-2^{-2^{30}}<f(x,k,m)<0$, and $m$ is `Floor`, `Up`, or `Odd`, $-2^{-2^{30}}$
+            ///   is returned instead.
+            /// - If $-2^{-2^{30}-1}\leq f(x,k,m)<0$, and $m$ is `Nearest` or `NearestTiesAway`,
+            ///   $-0.0$ is returned instead.
+            /// - If $-2^{-2^{30}-1}<f(x,k,m)<0$, and $m$ is `NearestTiesAway`, $-0.0$ is returned
             ///   instead.
-            /// - If $-2^{-2^{30}}<f(x,k,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest`, $-2^{-2^{30}}$ is
-            ///   returned instead.
+            /// - If $-2^{-2^{30}}<f(x,k,m)<-2^{-2^{30}-1}$, and $m$ is `Nearest` or
+            ///   `NearestTiesAway`, $-2^{-2^{30}}$ is returned instead.
+            /// - If $-2^{-2^{30}}<f(x,k,m)\leq-2^{-2^{30}-1}$, and $m$ is `NearestTiesAway`,
+            ///   $-2^{-2^{30}}$ is returned instead.
             ///
             /// If you don't care about overflow or underflow behavior, or only want the behavior of
             /// the `Nearest` rounding mode, you can just use `>>=` instead.
@@ -0,0 +1,1312 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The sine and cosine are computed together, from an exact rational argument. The argument is
+// reduced modulo pi/2 using as many bits of pi as the size of the argument requires, so that even
+// huge arguments are reduced correctly; the reduced argument r, with |r| <= pi/4, is halved K
+// times; the Taylor series of sin and cos are summed in fixed point; and K double-angle steps
+// recover sin(r) and cos(r). Every step carries a rigorous error bound, so the result is a pair of
+// rational intervals containing the exact values. Rounding is then a matter of checking that both
+// ends of an interval round the same way, increasing the working precision until they do (Ziv's
+// strategy).
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::cos::primitive_float_cos;
+use crate::float::arithmetic::round_near_x::{float_round_near_x, rational_round_near_x};
+use crate::float::arithmetic::sin::primitive_float_sin;
+use crate::{Float, floor_and_ceiling};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{
+    CeilingLogBase2, DivRound, FloorSqrt, ModPowerOf2, Parity, PowerOf2, SinCos, Square,
+    UnsignedAbs,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, One, Two, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_q::Rational;
+
+// An approximation of a real number v by `m * 2 ^ e`, with `|v - m * 2 ^ e| <= err * 2 ^ e`.
+struct Approximation {
+    m: Integer,
+    e: i64,
+    err: Natural,
+}
+
+impl Approximation {
+    // Drops low bits of `m` so that it has at most `bits` significant bits. The error grows by at
+    // most 2 units of the new last place: one for the truncation of `m`, and one for rounding the
+    // error itself down.
+    fn truncate(&mut self, bits: u64) {
+        let m_bits = self.m.significant_bits();
+        if m_bits > bits {
+            let shift = m_bits - bits;
+            self.m >>= shift;
+            self.err >>= shift;
+            self.err += Natural::TWO;
+            self.e += i64::exact_from(shift);
+        }
+    }
+
+    // Returns rational bounds `(lo, hi)` with `lo <= v <= hi`, after widening the error by the
+    // absolute amount `extra`.
+    fn bounds(&self, extra: &Rational) -> (Rational, Rational) {
+        let center = Rational::from(&self.m) << self.e;
+        let radius = (Rational::from(&self.err) << self.e) + extra;
+        (&center - &radius, center + radius)
+    }
+}
+
+fn negate_bounds((lo, hi): (Rational, Rational)) -> (Rational, Rational) {
+    (-hi, -lo)
+}
+
+// Reduces a nonzero `x` modulo pi/2. Returns `(k mod 4, r, s, err)`, where `x = k * pi / 2 + t`
+// with `|t|` at most slightly more than `pi / 4` (or with `k = 0` and `|t| < 1` when `|x| < 1`),
+// and `|t - r * 2 ^ (-s)| <= err * 2 ^ (-s)`. The error is small enough that `r * 2 ^ (-s)`
+// approximates `t` with a relative error below `2 ^ (-w - 2)`.
+//
+// `k` is `round(x / (pi / 2))`, found from an `s`-bit approximation of pi/2. If `x` is close to a
+// multiple of pi/2, `t` is small and many of the bits of the reduced argument cancel; in that case
+// `s` is increased and the reduction repeated. Since `x` is rational and pi is not, this
+// terminates.
+fn reduce(x: &Rational, w: u64) -> (u64, Integer, u64, Natural) {
+    let exp_x = x.floor_log_base_2_abs() + 1;
+    if exp_x <= 0 {
+        let s = u64::exact_from(i64::exact_from(w) + 4 - exp_x);
+        let (r, o) = Integer::rounding_from(x << s, Floor);
+        return (
+            0,
+            r,
+            s,
+            if o == Equal {
+                Natural::ZERO
+            } else {
+                Natural::ONE
+            },
+        );
+    }
+    let mut s = w + u64::exact_from(exp_x) + 8;
+    loop {
+        // 0 <= pi / 2 - p * 2 ^ (-s) < 2 ^ (-s)
+        let p = Integer::exact_from(
+            Rational::exact_from(&Float::pi_over_2_prec_round(s + 1, Floor).0) << s,
+        );
+        let (scaled_x, o) = Integer::rounding_from(x << s, Floor);
+        let k = (&scaled_x).div_round(&p, Nearest).0;
+        let r = scaled_x - &k * p;
+        let mut err = (&k).unsigned_abs();
+        if o != Equal {
+            err += Natural::ONE;
+        }
+        let r_bits = r.significant_bits();
+        let needed = err.significant_bits() + w + 2;
+        if r_bits >= needed {
+            let quadrant = u64::exact_from(&(&k).mod_power_of_2(2));
+            return (quadrant, r, s, err);
+        }
+        s += (needed - r_bits).max(Limb::WIDTH);
+    }
+}
+
+// Computes approximations of sin(t) and cos(t) for `t = r * 2 ^ (-s)`, with `0 < |t| < 1`, each
+// with a relative error of about `2 ^ (-w)`.
+//
+// The argument is divided by `2 ^ k`, with `k` about `sqrt(w / 2)`, which makes the Taylor series
+// converge quickly; the series are summed in fixed point with `ww` fractional bits, and then `k`
+// applications of `sin(2a) = 2 sin(a) cos(a)` and `cos(2a) = 1 - 2 sin(a) ^ 2` undo the division.
+// Since `|t| < 1`, `cos(t) > 1/2` throughout, so neither the cosines nor the sines suffer any
+// cancellation, and the relative errors grow only linearly with `k`.
+fn sin_cos_small(r: &Integer, s: u64, w: u64) -> (Approximation, Approximation) {
+    let exp_t = i64::exact_from(r.significant_bits()) - i64::exact_from(s);
+    let k = u64::try_from(i64::exact_from((w >> 1).floor_sqrt()) + exp_t).unwrap_or(0);
+    let ww = w + (k << 1) + 32;
+    let ww_i = i64::exact_from(ww);
+    // a = t / 2^k, and u = a^2, in fixed point with an error of less than 1 unit
+    let u = r.unsigned_abs().square() << (ww_i - (i64::exact_from(s + k) << 1));
+    // With T_0 = 1 and T_i = T_(i - 1) * u / ((2i - 1)(2i)), so that T_i = a^(2i) / (2i)!, cos(a) =
+    // sum (-1)^i T_i and sin(a) = a * sum (-1)^i T_i / (2i + 1). Each computed T_i is within 4
+    // units of the exact one, and each T_i / (2i + 1) within 5; once a computed term is 0, the
+    // exact remaining terms add up to less than 5 units, since the terms decrease by a factor
+    // of at least 12.
+    let one = Natural::power_of_2(ww);
+    let mut term = one.clone();
+    let mut cos_sum = Integer::from(&one);
+    let mut sin_sum = Integer::from(&one);
+    let mut i = 1u64;
+    loop {
+        term *= &u;
+        term >>= ww;
+        term /= Natural::from((i << 1) - 1) * Natural::from(i << 1);
+        if term == 0u32 {
+            break;
+        }
+        let sin_term = Integer::from(&term / Natural::from((i << 1) + 1));
+        if i.odd() {
+            cos_sum -= Integer::from(&term);
+            sin_sum -= sin_term;
+        } else {
+            cos_sum += Integer::from(&term);
+            sin_sum += sin_term;
+        }
+        i += 1;
+    }
+    let err = Natural::from(5 * (i + 1));
+    let mut sin = Approximation {
+        m: r * sin_sum,
+        e: -i64::exact_from(s + k) - ww_i,
+        err: r.unsigned_abs() * &err,
+    };
+    sin.truncate(ww + 2);
+    let mut cos = Approximation {
+        m: cos_sum,
+        e: -ww_i,
+        err,
+    };
+    for _ in 0..k {
+        let sin_abs = (&sin.m).unsigned_abs();
+        // 2 sin(a)^2, in fixed point with ww fractional bits
+        let shift = (sin.e << 1) + 1 + ww_i;
+        let square_err = ((&sin_abs * &sin.err) << 1u32) + (&sin.err).square();
+        let square = (&sin_abs).square();
+        let (square, square_err) = if shift >= 0 {
+            (square << shift, square_err << shift)
+        } else {
+            (square >> -shift, (square_err >> -shift) + Natural::TWO)
+        };
+        let mut new_sin = Approximation {
+            m: &sin.m * &cos.m,
+            e: sin.e + cos.e + 1,
+            err: sin_abs * &cos.err + cos.m.unsigned_abs() * &sin.err + &sin.err * &cos.err,
+        };
+        new_sin.truncate(ww + 2);
+        sin = new_sin;
+        cos = Approximation {
+            m: Integer::from(&one) - Integer::from(square),
+            e: -ww_i,
+            err: square_err,
+        };
+    }
+    (sin, cos)
+}
+
+// Returns rational bounds on sin(x) and cos(x), for a nonzero rational `x`, each with a relative
+// width of about `2 ^ (-w)`.
+pub(crate) fn sin_cos_bounds(x: &Rational, w: u64) -> ((Rational, Rational), (Rational, Rational)) {
+    let (quadrant, r, s, err) = reduce(x, w);
+    let (sin, cos) = sin_cos_small(&r, s, w);
+    // sin and cos are 1-Lipschitz, so the error of the reduced argument carries over unchanged
+    let extra = Rational::from(err) >> s;
+    let sin = sin.bounds(&extra);
+    let cos = cos.bounds(&extra);
+    match quadrant {
+        0 => (sin, cos),
+        1 => (cos, negate_bounds(sin)),
+        2 => (negate_bounds(sin), negate_bounds(cos)),
+        _ => (negate_bounds(cos), sin),
+    }
+}
+
+// Given rational bounds `lo < v < hi` on an irrational number v, returns v rounded to `prec` bits
+// with rounding mode `rm`, together with the `Ordering` of the result relative to v, if both bounds
+// round to the same `Float`; otherwise returns `None`. Since v is strictly between the bounds, a
+// rounded bound that is exact is still on one side of v.
+pub(crate) fn round_bracket(
+    lo: &Rational,
+    hi: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    if *lo <= 0u32 && *hi >= 0u32 {
+        return None;
+    }
+    let (y_lo, o_lo) = Float::from_rational_prec_round_ref(lo, prec, rm);
+    let (y_hi, o_hi) = Float::from_rational_prec_round_ref(hi, prec, rm);
+    if y_lo != y_hi {
+        None
+    } else if o_lo != Greater {
+        Some((y_lo, Less))
+    } else if o_hi != Less {
+        Some((y_hi, Greater))
+    } else {
+        None
+    }
+}
+
+// Rounds an irrational number, given a function returning rational bounds on it that become tighter
+// as the working precision `w` increases, or `None` if the bounds at that precision are useless.
+pub(crate) fn round_with_bounds<F: FnMut(u64) -> Option<(Rational, Rational)>>(
+    prec: u64,
+    rm: RoundingMode,
+    mut bounds: F,
+) -> (Float, Ordering) {
+    let mut working_prec = initial_working_prec(prec);
+    let mut increment = Limb::WIDTH;
+    loop {
+        if let Some((lo, hi)) = bounds(working_prec)
+            && let Some(result) = round_bracket(&lo, &hi, prec, rm)
+        {
+            return result;
+        }
+        working_prec += increment;
+        increment = working_prec >> 1;
+    }
+}
+
+// Rounds `n * pi / 2 ^ k` (with `n` odd), negated if `negative` is true.
+pub(crate) fn round_pi_multiple(
+    n: u64,
+    k: u64,
+    negative: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let (y, o) = if negative {
+        round_pi_multiple(n, k, false, prec, -rm)
+    } else if n == 1 {
+        let (y, o) = Float::pi_prec_round(prec, rm);
+        return (y >> k, o);
+    } else {
+        let n = Rational::from(n);
+        return round_with_bounds(prec, rm, |w| {
+            let (pi_lo, pi_hi) = floor_and_ceiling(Float::pi_prec_round(w, Floor));
+            Some((
+                (Rational::exact_from(pi_lo) * &n) >> k,
+                (Rational::exact_from(pi_hi) * &n) >> k,
+            ))
+        });
+    };
+    (-y, o.reverse())
+}
+
+// Rounds a number v that is known to satisfy `|v - c| < 2 ^ e`, where c is pi (if `half` is false)
+// or pi/2 (if `half` is true), without computing v. This succeeds when `e` is very negative:
+// brackets for c are computed at increasing precisions `w`, each widened by an ulp of c at that
+// precision (which is at least `2 ^ e`), and if they ever round consistently the result is known.
+// Returns `None` if this fails while `w` is still small enough for the widening to be valid.
+pub(crate) fn round_near_pi(
+    half: bool,
+    e: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let mut w = prec + 32;
+    // an ulp of pi or pi/2 at precision w is 2^(2 - w) or 2^(1 - w), respectively
+    while i64::exact_from(w) < -e {
+        let (c, o) = if half {
+            Float::pi_over_2_prec_round(w, Floor)
+        } else {
+            Float::pi_prec_round(w, Floor)
+        };
+        let c = Rational::exact_from(c);
+        let ulp = Rational::power_of_2(i64::from(!half) + 1 - i64::exact_from(w));
+        debug_assert_ne!(o, Equal);
+        // c_lo <= c < c_lo + ulp, so c_lo - ulp < v < c_lo + 2 * ulp
+        let lo = &c - &ulp;
+        let hi = c + (ulp << 1u32);
+        if let Some(result) = round_bracket(&lo, &hi, prec, rm) {
+            return Some(result);
+        }
+        w <<= 1;
+    }
+    None
+}
+
+pub(crate) fn initial_working_prec(prec: u64) -> u64 {
+    prec + prec.ceiling_log_base_2() + 16
+}
+
+// Bounds on the quotient of two intervals, the second of which does not contain zero.
+pub(crate) fn div_bounds(
+    (n_lo, n_hi): &(Rational, Rational),
+    (d_lo, d_hi): &(Rational, Rational),
+) -> Option<(Rational, Rational)> {
+    if *d_lo <= 0u32 && *d_hi >= 0u32 {
+        return None;
+    }
+    let mut qs = [n_lo / d_lo, n_lo / d_hi, n_hi / d_lo, n_hi / d_hi];
+    qs.sort_unstable();
+    let [lo, _, _, hi] = qs;
+    Some((lo, hi))
+}
+
+// The exponent of `x`, in the sense of `Float::get_exponent`: `floor(log_2(|x|)) + 1`.
+#[inline]
+pub(crate) fn rational_exponent(x: &Rational) -> i64 {
+    x.floor_log_base_2_abs() + 1
+}
+
+// For `|x| < 1`, `|sin(x) - x| < |x| ^ 3 / 6`.
+fn sin_near_zero(x: &Rational, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+    let exp_x = rational_exponent(x);
+    if exp_x < 0 {
+        rational_round_near_x(x, u64::exact_from(2 - (exp_x << 1)), false, prec, rm)
+    } else {
+        None
+    }
+}
+
+// For `|x| < 1`, `1 - x ^ 2 / 2 < cos(x) < 1`.
+fn cos_near_zero(x: &Rational, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+    let exp_x = rational_exponent(x);
+    if exp_x < 0 {
+        float_round_near_x(
+            &Float::ONE,
+            u64::exact_from(2 - (exp_x << 1)),
+            false,
+            prec,
+            rm,
+        )
+    } else {
+        None
+    }
+}
+
+pub(crate) fn sin_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact sin");
+    if let Some(result) = sin_near_zero(x, prec, rm) {
+        return result;
+    }
+    round_with_bounds(prec, rm, |w| Some(sin_cos_bounds(x, w).0))
+}
+
+pub(crate) fn cos_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact cos");
+    if let Some(result) = cos_near_zero(x, prec, rm) {
+        return result;
+    }
+    round_with_bounds(prec, rm, |w| Some(sin_cos_bounds(x, w).1))
+}
+
+pub(crate) fn tan_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact tan");
+    // For `|x| <= 1/2`, `x < tan(x) < x + |x| ^ 3 / 2` when x is positive.
+    let exp_x = rational_exponent(x);
+    if exp_x < 0
+        && let Some(result) =
+            rational_round_near_x(x, u64::exact_from(1 - (exp_x << 1)), true, prec, rm)
+    {
+        return result;
+    }
+    round_with_bounds(prec, rm, |w| {
+        let (sin, cos) = sin_cos_bounds(x, w);
+        div_bounds(&sin, &cos)
+    })
+}
+
+pub(crate) fn sin_cos_rational_helper(
+    x: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> ((Float, Ordering), (Float, Ordering)) {
+    assert_ne!(rm, Exact, "Inexact sin_cos");
+    let mut sin = sin_near_zero(x, prec, rm);
+    let mut cos = cos_near_zero(x, prec, rm);
+    let mut working_prec = initial_working_prec(prec);
+    let mut increment = Limb::WIDTH;
+    while sin.is_none() || cos.is_none() {
+        let ((sin_lo, sin_hi), (cos_lo, cos_hi)) = sin_cos_bounds(x, working_prec);
+        if sin.is_none() {
+            sin = round_bracket(&sin_lo, &sin_hi, prec, rm);
+        }
+        if cos.is_none() {
+            cos = round_bracket(&cos_lo, &cos_hi, prec, rm);
+        }
+        working_prec += increment;
+        increment = working_prec >> 1;
+    }
+    (sin.unwrap(), cos.unwrap())
+}
+
+impl Float {
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`], rounding both results to
+    /// the specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// value. Two [`Ordering`]s are also returned, indicating whether the rounded sine and cosine
+    /// are less than, equal to, or greater than the exact values. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// Computing both values together is about as fast as computing either one of them alone.
+    ///
+    /// The argument is in radians, and is reduced modulo $\pi/2$ using as many bits of $\pi$ as are
+    /// needed, so both results are correctly rounded even when $x$ is huge.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ or $\cos x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sin x$ is nonzero and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, and similarly for $\cos x$ and
+    ///   $\varepsilon_2$.
+    /// - If $\sin x$ is nonzero and $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon_1|
+    ///   \leq 2^{\lfloor\log_2 |\sin x|\rfloor-p}$, and similarly for $\cos x$ and $\varepsilon_2$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// Overflow is not possible. The sine can underflow, but only if $|x|$ is the smallest positive
+    /// [`Float`], $2^{-2^{30}}$; see [`Float::sin_prec_round`].
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round`] instead. If both of these things are true, consider using
+    /// [`Float::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round(5, Floor);
+    /// assert_eq!(sin.to_string(), "0.812");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "0.844");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.562");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round(5, Nearest);
+    /// assert_eq!(sin.to_string(), "0.844");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round(20, Floor);
+    /// assert_eq!(sin.to_string(), "0.84147072");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "0.84147167");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.54030323");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round(20, Nearest);
+    /// assert_eq!(sin.to_string(), "0.84147072");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_round(
+        self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`], rounding both results to
+    /// the specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. Two [`Ordering`]s are also returned, indicating whether the rounded sine and
+    /// cosine are less than, equal to, or greater than the exact values. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases and
+    /// underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).sin_cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round_ref(5, Floor);
+    /// assert_eq!(sin.to_string(), "0.812");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "0.844");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.562");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round_ref(5, Nearest);
+    /// assert_eq!(sin.to_string(), "0.844");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round_ref(20, Floor);
+    /// assert_eq!(sin.to_string(), "0.84147072");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "0.84147167");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.54030323");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .sin_cos_prec_round_ref(20, Nearest);
+    /// assert_eq!(sin.to_string(), "0.84147072");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    pub fn sin_cos_prec_round_ref(
+        &self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { .. } => ((Self::NAN, Equal), (Self::NAN, Equal)),
+            Zero { .. } => ((self.clone(), Equal), (Self::one_prec(prec), Equal)),
+            Finite { .. } => sin_cos_rational_helper(&Rational::exact_from(self), prec, rm),
+        }
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`], rounding both results to
+    /// the nearest value of the specified precision. The [`Float`] is taken by value. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact values. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::from_unsigned_prec(1u32, 100).0.sin_cos_prec(5);
+    /// assert_eq!(sin.to_string(), "0.844");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::from_unsigned_prec(1u32, 100).0.sin_cos_prec(20);
+    /// assert_eq!(sin.to_string(), "0.84147072");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec(self, prec: u64) -> ((Self, Ordering), (Self, Ordering)) {
+        self.sin_cos_prec_round(prec, Nearest)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`], rounding both results to
+    /// the nearest value of the specified precision. The [`Float`] is taken by reference. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact values. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases and
+    /// underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).sin_cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::from_unsigned_prec(1u32, 100).0.sin_cos_prec_ref(5);
+    /// assert_eq!(sin.to_string(), "0.844");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.531");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::from_unsigned_prec(1u32, 100).0.sin_cos_prec_ref(20);
+    /// assert_eq!(sin.to_string(), "0.84147072");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.54030228");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_ref(&self, prec: u64) -> ((Self, Ordering), (Self, Ordering)) {
+        self.sin_cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`], rounding both results to
+    /// the precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// value. Two [`Ordering`]s are also returned, indicating whether the rounded sine and cosine
+    /// are less than, equal to, or greater than the exact values. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases and
+    /// underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but either result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round(Floor);
+    /// assert_eq!(sin.to_string(), "1.2246467991473515e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473535e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.99999999999999911");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round(Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473535e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round(self, rm: RoundingMode) -> ((Self, Ordering), (Self, Ordering)) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round(prec, rm)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`], rounding both results to
+    /// the precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. Two [`Ordering`]s are also returned, indicating whether the rounded sine and
+    /// cosine are less than, equal to, or greater than the exact values. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases and
+    /// underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).sin_cos()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but either result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round_ref(Floor);
+    /// assert_eq!(sin.to_string(), "1.2246467991473515e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round_ref(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473535e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.99999999999999911");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round_ref(Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473535e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round_ref(&self, rm: RoundingMode) -> ((Self, Ordering), (Self, Ordering)) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Rational`], rounding both results to
+    /// the specified precision and with the specified rounding mode and returning the results as
+    /// [`Float`]s. The [`Rational`] is taken by value. Two [`Ordering`]s are also returned,
+    /// indicating whether the rounded sine and cosine are less than, equal to, or greater than the
+    /// exact values.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ or $\cos x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sin x$ is nonzero and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, and similarly for $\cos x$ and
+    ///   $\varepsilon_2$.
+    /// - If $\sin x$ is nonzero and $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon_1|
+    ///   \leq 2^{\lfloor\log_2 |\sin x|\rfloor-p}$, and similarly for $\cos x$ and $\varepsilon_2$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=(0.0,1.0)$
+    ///
+    /// Neither overflow nor underflow is possible.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(sin.to_string(), "0.312");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(sin.to_string(), "0.328");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.969");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Nearest);
+    /// assert_eq!(sin.to_string(), "0.328");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(sin.to_string(), "0.32719469");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(sin.to_string(), "0.32719517");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.94495773");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Nearest);
+    /// assert_eq!(sin.to_string(), "0.32719469");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sin_cos_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        Self::sin_cos_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Rational`], rounding both results to
+    /// the specified precision and with the specified rounding mode and returning the results as
+    /// [`Float`]s. The [`Rational`] is taken by reference. Two [`Ordering`]s are also returned,
+    /// indicating whether the rounded sine and cosine are less than, equal to, or greater than the
+    /// exact values.
+    ///
+    /// See the [`Float::sin_cos_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(sin.to_string(), "0.312");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::sin_cos_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(sin.to_string(), "0.328");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.969");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::sin_cos_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(sin.to_string(), "0.328");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::sin_cos_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(sin.to_string(), "0.32719469");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::sin_cos_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(sin.to_string(), "0.32719517");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.94495773");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::sin_cos_rational_prec_round_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(sin.to_string(), "0.32719469");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    pub fn sin_cos_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return ((Self::ZERO, Equal), (Self::one_prec(prec), Equal));
+        }
+        sin_cos_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Rational`], rounding both results to
+    /// the nearest value of the specified precision and returning the results as [`Float`]s. The
+    /// [`Rational`] is taken by value. Two [`Ordering`]s are also returned, indicating whether the
+    /// rounded sine and cosine are less than, equal to, or greater than the exact values.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sin_cos_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(sin.to_string(), "0.328");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(sin.to_string(), "0.32719469");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_rational_prec(x: Rational, prec: u64) -> ((Self, Ordering), (Self, Ordering)) {
+        Self::sin_cos_rational_prec_round(x, prec, Nearest)
+    }
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Rational`], rounding both results to
+    /// the nearest value of the specified precision and returning the results as [`Float`]s. The
+    /// [`Rational`] is taken by reference. Two [`Ordering`]s are also returned, indicating whether
+    /// the rounded sine and cosine are less than, equal to, or greater than the exact values.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sin_cos_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(sin.to_string(), "0.328");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "0.938");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) =
+    ///     Float::sin_cos_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(sin.to_string(), "0.32719469");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "0.94495678");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_rational_prec_ref(
+        x: &Rational,
+        prec: u64,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        Self::sin_cos_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl SinCos for Float {
+    type Output = Self;
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`]. The [`Float`] is taken by
+    /// value.
+    ///
+    /// The precision of both outputs is the precision of the input. If the sine or cosine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ or $\cos x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sin x$ is nonzero, then $|\varepsilon_1| \leq 2^{\lfloor\log_2 |\sin x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input, and similarly for $\cos x$ and $\varepsilon_2$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::SinCos;
+    /// use malachite_float::Float;
+    ///
+    /// let (sin, cos) = Float::from(PI).sin_cos();
+    /// assert_eq!(sin.to_string(), "1.2246467991473535e-16");
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    ///
+    /// let (sin, cos) = Float::from_unsigned_prec(1u32, 100).0.sin_cos();
+    /// assert_eq!(sin.to_string(), "0.84147098480789650665250232163005");
+    /// assert_eq!(cos.to_string(), "0.54030230586813971740093660744335");
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let prec = self.significant_bits();
+        let ((sin, _), (cos, _)) = self.sin_cos_prec_round(prec, Nearest);
+        (sin, cos)
+    }
+}
+
+impl SinCos for &Float {
+    type Output = Float;
+
+    /// Computes $(\sin x, \cos x)$, the sine and cosine of a [`Float`]. The [`Float`] is taken by
+    /// reference.
+    ///
+    /// The precision of both outputs is the precision of the input. If the sine or cosine is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ or $\cos x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sin x$ is nonzero, then $|\varepsilon_1| \leq 2^{\lfloor\log_2 |\sin x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input, and similarly for $\cos x$ and $\varepsilon_2$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::SinCos;
+    /// use malachite_float::Float;
+    ///
+    /// let (sin, cos) = (&Float::from(PI)).sin_cos();
+    /// assert_eq!(sin.to_string(), "1.2246467991473535e-16");
+    /// assert_eq!(cos.to_string(), "-1.0000000000000000");
+    ///
+    /// let (sin, cos) = (&Float::from_unsigned_prec(1u32, 100).0).sin_cos();
+    /// assert_eq!(sin.to_string(), "0.84147098480789650665250232163005");
+    /// assert_eq!(cos.to_string(), "0.54030230586813971740093660744335");
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (Float, Float) {
+        let prec = self.significant_bits();
+        let ((sin, _), (cos, _)) = self.sin_cos_prec_round_ref(prec, Nearest);
+        (sin, cos)
+    }
+}
+
+/// Computes $(\sin x, \cos x)$, the sine and cosine of a primitive float, returning
+/// correctly-rounded results.
+///
+/// $$
+/// f(x) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+/// $$
+/// - If $\sin x$ or $\cos x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\sin x$ is nonzero, then $|\varepsilon_1| \leq 2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where
+///   $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and 53 if `T` is a
+///   [`f64`], but less if the output is subnormal), and similarly for $\cos x$ and $\varepsilon_2$.
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(\pm\infty)=(\text{NaN},\text{NaN})$
+/// - $f(0.0)=(0.0,1.0)$
+/// - $f(-0.0)=(-0.0,1.0)$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::sin_cos::primitive_float_sin_cos;
+///
+/// let (sin, cos) = primitive_float_sin_cos(PI);
+/// assert_eq!(NiceFloat(sin), NiceFloat(1.2246467991473532e-16));
+/// assert_eq!(NiceFloat(cos), NiceFloat(-1.0));
+///
+/// let (sin, cos) = primitive_float_sin_cos(1.0f32);
+/// assert_eq!(NiceFloat(sin), NiceFloat(0.84147096));
+/// assert_eq!(NiceFloat(cos), NiceFloat(0.5403023));
+///
+/// let (sin, cos) = primitive_float_sin_cos(-0.0f64);
+/// assert_eq!(NiceFloat(sin), NiceFloat(-0.0));
+/// assert_eq!(NiceFloat(cos), NiceFloat(1.0));
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_sin_cos<T: PrimitiveFloat>(x: T) -> (T, T)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    (primitive_float_sin(x), primitive_float_cos(x))
+}
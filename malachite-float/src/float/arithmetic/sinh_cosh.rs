@@ -0,0 +1,1266 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The hyperbolic sine and cosine, and the functions derived from them, are computed from an exact
+// rational argument x > 0 (the functions are odd or even, so negative arguments are handled by
+// symmetry). When x < 16, everything is expressed in terms of u = e ^ x - 1, which is computed
+// accurately even when x is tiny, so there is no cancellation: sinh(x) = (u + u / (1 + u)) / 2 and
+// cosh(x) = 1 + u ^ 2 / (2 * (1 + u)). When x >= 16, e ^ x is written as m * 2 ^ n with 1 < m < 4,
+// so that sinh(x) = 2 ^ (n - 1) * (m - 2 ^ (-2n) / m) and cosh(x) = 2 ^ (n - 1) * (m + 2 ^ (-2n) /
+// m); the power of 2 is applied only after rounding, so intermediate values stay small even when
+// the result is far outside the range of a `Float`. In both cases the result is a rational interval
+// containing the exact value, which is rounded using Ziv's strategy.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::cosh::primitive_float_cosh;
+use crate::float::arithmetic::exp::{exp_overflow, exp_underflow};
+use crate::float::arithmetic::round_near_x::{float_round_near_x, rational_round_near_x};
+use crate::float::arithmetic::sin_cos::{
+    initial_working_prec, rational_exponent, round_bracket, round_with_bounds,
+};
+use crate::float::arithmetic::sinh::primitive_float_sinh;
+use crate::{Float, floor_and_ceiling};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{PowerOf2, Reciprocal, SinhCosh, Square};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, One, Two, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::Limb;
+use malachite_q::Rational;
+
+// Arguments with an exponent greater than this, that is, arguments at least 2 ^ 32 in absolute
+// value, are huge: sinh and cosh overflow, sech and csch underflow, and tanh and coth are within
+// 2 ^ (-2 ^ 33) of 1.
+const HUGE_EXPONENT: i64 = 32;
+
+// Arguments with an exponent at least this large, that is, arguments at least 16 in absolute
+// value, are handled by writing e ^ x as m * 2 ^ n.
+const SCALED_EXPONENT: i64 = 5;
+
+// Converts a finite, nonzero `Float` to a `Rational` for the functions in this module. When the
+// `Float` is huge, the results of those functions depend only on its sign, so rather than building
+// a `Rational` with as many as 2 ^ 30 bits, a power of 2 with the same sign that is also huge is
+// returned.
+pub(crate) fn hyperbolic_argument(x: &Float) -> Rational {
+    if i64::from(x.get_exponent().unwrap()) > HUGE_EXPONENT {
+        let y = Rational::power_of_2(HUGE_EXPONENT);
+        if x.is_sign_positive() { y } else { -y }
+    } else {
+        Rational::exact_from(x)
+    }
+}
+
+// Returns bounds on e ^ x - 1, for a positive rational `x` less than about 2 ^ 30, with a relative
+// width of about `2 ^ (-w)`.
+fn exp_x_minus_1_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let (lo, hi) = floor_and_ceiling(Float::exp_x_minus_1_rational_prec_round_ref(x, w, Floor));
+    (Rational::exact_from(lo), Rational::exact_from(hi))
+}
+
+// Returns the n such that e ^ x = m * 2 ^ n with 1 < m < 4, for `x` >= 16, or 0 for smaller
+// positive `x`. A 64-bit lower bound L on log(2) is good enough for this: for x < 2 ^ 32, x / L
+// exceeds x / log(2) by less than 2 ^ (-30), so n = floor(x / L) - 1 satisfies 0 < x - n * log(2) <
+// 2 * log(2).
+fn exp_shift(x: &Rational) -> i64 {
+    if rational_exponent(x) < SCALED_EXPONENT {
+        return 0;
+    }
+    let ln_2 = Rational::exact_from(Float::ln_2_prec_round(64, Floor).0);
+    i64::exact_from(&Integer::rounding_from(x / ln_2, Floor).0) - 1
+}
+
+// Returns bounds on sinh(x) / 2 ^ s and cosh(x) / 2 ^ s for a positive rational `x` with exponent
+// at most `HUGE_EXPONENT`, where n is `exp_shift(x)` and s is `max(n - 1, 0)`. Each pair of bounds
+// has a relative width of about `2 ^ (-w)`.
+fn sinh_cosh_bounds(x: &Rational, n: i64, w: u64) -> ((Rational, Rational), (Rational, Rational)) {
+    if n == 0 {
+        let (u_lo, u_hi) = exp_x_minus_1_bounds(x, w);
+        // sinh(x) = (u + u / (1 + u)) / 2 and cosh(x) = 1 + u ^ 2 / (2 * (1 + u)), both increasing
+        // in u
+        let sinh = |u: &Rational| (u + u / (u + Rational::ONE)) >> 1u32;
+        let cosh = |u: &Rational| u.square() / ((u + Rational::ONE) << 1u32) + Rational::ONE;
+        return ((sinh(&u_lo), sinh(&u_hi)), (cosh(&u_lo), cosh(&u_hi)));
+    }
+    // m = e ^ r, where r = x - n * log(2) is between 0 and 2 * log(2)
+    let (ln_2_lo, ln_2_hi) =
+        floor_and_ceiling(Float::ln_2_prec_round(w + n.significant_bits() + 4, Floor));
+    let n_q = Rational::from(n);
+    let r_lo = x - &n_q * Rational::exact_from(ln_2_hi);
+    let r_hi = x - n_q * Rational::exact_from(ln_2_lo);
+    let m_lo = Rational::exact_from(Float::exp_rational_prec_round_ref(&r_lo, w, Floor).0);
+    let m_hi = Rational::exact_from(Float::exp_rational_prec_round_ref(&r_hi, w, Ceiling).0);
+    // t = 2 ^ (-2n) / m = e ^ (-x) / 2 ^ n. Once t is far below the working precision, only an
+    // upper bound is needed.
+    let (t_lo, t_hi) = if u64::exact_from(n << 1) <= w + 32 {
+        let p = Rational::power_of_2(-(n << 1));
+        (&p / &m_hi, p / &m_lo)
+    } else {
+        (
+            Rational::ZERO,
+            Rational::power_of_2(-i64::exact_from(w + 32)),
+        )
+    };
+    ((&m_lo - &t_hi, &m_hi - &t_lo), (m_lo + t_lo, m_hi + t_hi))
+}
+
+#[inline]
+const fn result_shift(n: i64) -> i64 {
+    if n == 0 { 0 } else { n - 1 }
+}
+
+// Like `round_bracket`, but rounds v * 2 ^ `shift` given bounds `lo < v < hi`, overflowing or
+// underflowing if necessary.
+pub(crate) fn round_scaled_bracket(
+    lo: &Rational,
+    hi: &Rational,
+    shift: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let (mut y, o) = round_bracket(lo, hi, prec, rm)?;
+    let o = y.shl_prec_round_assign_helper(shift, prec, rm, o);
+    Some((y, o))
+}
+
+// Like `round_with_bounds`, but the bounds are on v / 2 ^ `shift` rather than on v.
+pub(crate) fn round_with_scaled_bounds<F: FnMut(u64) -> (Rational, Rational)>(
+    shift: i64,
+    prec: u64,
+    rm: RoundingMode,
+    mut bounds: F,
+) -> (Float, Ordering) {
+    let mut working_prec = initial_working_prec(prec);
+    let mut increment = Limb::WIDTH;
+    loop {
+        let (lo, hi) = bounds(working_prec);
+        if let Some(result) = round_scaled_bracket(&lo, &hi, shift, prec, rm) {
+            return result;
+        }
+        working_prec += increment;
+        increment = working_prec >> 1;
+    }
+}
+
+// Bounds on 1 / v, given positive bounds on v.
+fn reciprocal_bounds((lo, hi): (Rational, Rational)) -> (Rational, Rational) {
+    (hi.reciprocal(), lo.reciprocal())
+}
+
+// For 0 < x <= 1/2, x < sinh(x) < x + x ^ 3 / 5.
+fn sinh_near_zero(
+    x: &Rational,
+    exp_x: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    if exp_x < 0 {
+        rational_round_near_x(x, u64::exact_from(2 - (exp_x << 1)), true, prec, rm)
+    } else {
+        None
+    }
+}
+
+// For 0 < x <= 1/2, 1 < cosh(x) < 1 + x ^ 2 / 2 * 1.02.
+fn cosh_near_zero(exp_x: i64, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+    if exp_x < 0 {
+        float_round_near_x(
+            &Float::ONE,
+            u64::exact_from(1 - (exp_x << 1)),
+            true,
+            prec,
+            rm,
+        )
+    } else {
+        None
+    }
+}
+
+// The overflow result of csch and coth at a positive `x` whose reciprocal is at least 2 ^
+// `Float::MAX_EXPONENT`. Both functions are then within x / 3 of 1 / x, which is far above the
+// largest finite `Float` of any precision.
+fn reciprocal_overflow(x: &Rational, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+    if rational_exponent(&x.reciprocal()) > Float::MAX_EXPONENT_I64 {
+        Some(exp_overflow(prec, rm))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn sinh_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        let (y, o) = sinh_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact sinh");
+    let exp_x = rational_exponent(x);
+    if exp_x > HUGE_EXPONENT {
+        return exp_overflow(prec, rm);
+    }
+    if let Some(result) = sinh_near_zero(x, exp_x, prec, rm) {
+        return result;
+    }
+    let n = exp_shift(x);
+    round_with_scaled_bounds(result_shift(n), prec, rm, |w| sinh_cosh_bounds(x, n, w).0)
+}
+
+pub(crate) fn cosh_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        return cosh_rational_helper(&-x, prec, rm);
+    }
+    assert_ne!(rm, Exact, "Inexact cosh");
+    let exp_x = rational_exponent(x);
+    if exp_x > HUGE_EXPONENT {
+        return exp_overflow(prec, rm);
+    }
+    if let Some(result) = cosh_near_zero(exp_x, prec, rm) {
+        return result;
+    }
+    let n = exp_shift(x);
+    round_with_scaled_bounds(result_shift(n), prec, rm, |w| sinh_cosh_bounds(x, n, w).1)
+}
+
+pub(crate) fn tanh_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        let (y, o) = tanh_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact tanh");
+    let exp_x = rational_exponent(x);
+    if exp_x < 0 {
+        // For 0 < x <= 1/2, x - x ^ 3 / 3 < tanh(x) < x
+        if let Some(result) =
+            rational_round_near_x(x, u64::exact_from(1 - (exp_x << 1)), false, prec, rm)
+        {
+            return result;
+        }
+    } else if exp_x > 0 {
+        // For x >= 1, 0 < 1 - tanh(x) = 2 / (e ^ (2x) + 1) < 2 ^ (1 - 2x). If this fails for a huge
+        // x, then `prec` is at least 2 ^ 33, and the general case below overflows; but such a
+        // precision is not practical anyway.
+        if let Some(result) =
+            float_round_near_x(&Float::ONE, near_one_err(x, exp_x), false, prec, rm)
+        {
+            return result;
+        }
+    }
+    // tanh(x) = v / (v + 2), where v = e ^ (2x) - 1
+    let two_x = x << 1u32;
+    round_with_bounds(prec, rm, |w| {
+        let (v_lo, v_hi) = exp_x_minus_1_bounds(&two_x, w);
+        let tanh = |v: Rational| {
+            let d = &v + Rational::TWO;
+            v / d
+        };
+        Some((tanh(v_lo), tanh(v_hi)))
+    })
+}
+
+// floor(2x), for x >= 1, capped at 2 ^ 33 for huge x.
+fn near_one_err(x: &Rational, exp_x: i64) -> u64 {
+    if exp_x > HUGE_EXPONENT {
+        1 << (HUGE_EXPONENT + 1)
+    } else {
+        u64::exact_from(&Integer::rounding_from(x << 1u32, Floor).0)
+    }
+}
+
+pub(crate) fn sech_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        return sech_rational_helper(&-x, prec, rm);
+    }
+    assert_ne!(rm, Exact, "Inexact sech");
+    let exp_x = rational_exponent(x);
+    if exp_x > HUGE_EXPONENT {
+        return exp_underflow(prec, rm);
+    }
+    // For 0 < x <= 1/2, 1 - x ^ 2 / 2 < sech(x) < 1
+    if exp_x < 0
+        && let Some(result) = float_round_near_x(
+            &Float::ONE,
+            u64::exact_from(2 - (exp_x << 1)),
+            false,
+            prec,
+            rm,
+        )
+    {
+        return result;
+    }
+    let n = exp_shift(x);
+    round_with_scaled_bounds(-result_shift(n), prec, rm, |w| {
+        reciprocal_bounds(sinh_cosh_bounds(x, n, w).1)
+    })
+}
+
+pub(crate) fn csch_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        let (y, o) = csch_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact csch");
+    if let Some(result) = reciprocal_overflow(x, prec, rm) {
+        return result;
+    }
+    let exp_x = rational_exponent(x);
+    if exp_x > HUGE_EXPONENT {
+        return exp_underflow(prec, rm);
+    }
+    // For 0 < x <= 1/2, 1 / x - x / 6 < csch(x) < 1 / x
+    if exp_x < 0
+        && let Some(result) = rational_round_near_x(
+            &x.reciprocal(),
+            u64::exact_from(3 - (exp_x << 1)),
+            false,
+            prec,
+            rm,
+        )
+    {
+        return result;
+    }
+    let n = exp_shift(x);
+    round_with_scaled_bounds(-result_shift(n), prec, rm, |w| {
+        reciprocal_bounds(sinh_cosh_bounds(x, n, w).0)
+    })
+}
+
+pub(crate) fn coth_rational_helper(x: &Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if *x < 0u32 {
+        let (y, o) = coth_rational_helper(&-x, prec, -rm);
+        return (-y, o.reverse());
+    }
+    assert_ne!(rm, Exact, "Inexact coth");
+    if let Some(result) = reciprocal_overflow(x, prec, rm) {
+        return result;
+    }
+    let exp_x = rational_exponent(x);
+    if exp_x < 0 {
+        // For 0 < x <= 1/2, 1 / x < coth(x) < 1 / x + x / 3
+        if let Some(result) = rational_round_near_x(
+            &x.reciprocal(),
+            u64::exact_from(2 - (exp_x << 1)),
+            true,
+            prec,
+            rm,
+        ) {
+            return result;
+        }
+    } else if exp_x > 0 {
+        // For x >= 1, 0 < coth(x) - 1 = 2 / (e ^ (2x) - 1) < 2 ^ (2 - 2x)
+        if let Some(result) =
+            float_round_near_x(&Float::ONE, near_one_err(x, exp_x) - 1, true, prec, rm)
+        {
+            return result;
+        }
+    }
+    // coth(x) = 1 + 2 / v, where v = e ^ (2x) - 1
+    let two_x = x << 1u32;
+    round_with_bounds(prec, rm, |w| {
+        let (v_lo, v_hi) = exp_x_minus_1_bounds(&two_x, w);
+        let coth = |v: Rational| (Rational::TWO / v) + Rational::ONE;
+        Some((coth(v_hi), coth(v_lo)))
+    })
+}
+
+// Computes sinh(x) and cosh(x) for a positive rational `x`, rounding them with `sinh_rm` and
+// `cosh_rm`, respectively.
+fn sinh_cosh_positive_helper(
+    x: &Rational,
+    prec: u64,
+    sinh_rm: RoundingMode,
+    cosh_rm: RoundingMode,
+) -> ((Float, Ordering), (Float, Ordering)) {
+    let exp_x = rational_exponent(x);
+    if exp_x > HUGE_EXPONENT {
+        return (exp_overflow(prec, sinh_rm), exp_overflow(prec, cosh_rm));
+    }
+    let mut sinh = sinh_near_zero(x, exp_x, prec, sinh_rm);
+    let mut cosh = cosh_near_zero(exp_x, prec, cosh_rm);
+    let n = exp_shift(x);
+    let shift = result_shift(n);
+    let mut working_prec = initial_working_prec(prec);
+    let mut increment = Limb::WIDTH;
+    while sinh.is_none() || cosh.is_none() {
+        let ((sinh_lo, sinh_hi), (cosh_lo, cosh_hi)) = sinh_cosh_bounds(x, n, working_prec);
+        if sinh.is_none() {
+            sinh = round_scaled_bracket(&sinh_lo, &sinh_hi, shift, prec, sinh_rm);
+        }
+        if cosh.is_none() {
+            cosh = round_scaled_bracket(&cosh_lo, &cosh_hi, shift, prec, cosh_rm);
+        }
+        working_prec += increment;
+        increment = working_prec >> 1;
+    }
+    (sinh.unwrap(), cosh.unwrap())
+}
+
+pub(crate) fn sinh_cosh_rational_helper(
+    x: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> ((Float, Ordering), (Float, Ordering)) {
+    assert_ne!(rm, Exact, "Inexact sinh_cosh");
+    if *x < 0u32 {
+        let ((sinh, o), cosh) = sinh_cosh_positive_helper(&-x, prec, -rm, rm);
+        ((-sinh, o.reverse()), cosh)
+    } else {
+        sinh_cosh_positive_helper(x, prec, rm, rm)
+    }
+}
+
+// Returns bounds on log(1 + d), given positive bounds on d, with a relative width of about `2 ^
+// (-w)`. The bounds on d must be within the range of a `Float`.
+pub(crate) fn ln_1_plus_x_bounds(d_lo: &Rational, d_hi: &Rational, w: u64) -> (Rational, Rational) {
+    let d_lo = Float::from_rational_prec_round_ref(d_lo, w, Floor).0;
+    let d_hi = Float::from_rational_prec_round_ref(d_hi, w, Ceiling).0;
+    (
+        Rational::exact_from(d_lo.ln_1_plus_x_prec_round(w, Floor).0),
+        Rational::exact_from(d_hi.ln_1_plus_x_prec_round(w, Ceiling).0),
+    )
+}
+
+impl Float {
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`], rounding both
+    /// results to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by value. Two [`Ordering`]s are also returned, indicating whether the rounded
+    /// hyperbolic sine and cosine are less than, equal to, or greater than the exact values.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// Computing both values together is about as fast as computing either one of them alone.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sinh x+\varepsilon_1, \cosh x+\varepsilon_2).
+    /// $$
+    /// - If $\sinh x$ or $\cosh x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sinh x$ is nonzero and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_1| < 2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$, and similarly for $\cosh x$
+    ///   and $\varepsilon_2$.
+    /// - If $\sinh x$ is nonzero and $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon_1|
+    ///   \leq 2^{\lfloor\log_2 |\sinh x|\rfloor-p}$, and similarly for $\cosh x$ and
+    ///   $\varepsilon_2$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(\infty,p,m)=(\infty,\infty)$
+    /// - $f(-\infty,p,m)=(-\infty,\infty)$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// Overflow is not possible. The sine can underflow, but only if $|x|$ is the smallest positive
+    /// [`Float`], $2^{-2^{30}}$; see [`Float::sin_prec_round`].
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_cosh_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_cosh_round`] instead. If both of these things are true, consider using
+    /// [`Float::sinh_cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round(5, Floor);
+    /// assert_eq!(sinh.to_string(), "1.12");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.50");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round(5, Ceiling);
+    /// assert_eq!(sinh.to_string(), "1.19");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.56");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round(5, Nearest);
+    /// assert_eq!(sinh.to_string(), "1.19");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.56");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round(20, Floor);
+    /// assert_eq!(sinh.to_string(), "1.1751995");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.5430794");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round(20, Ceiling);
+    /// assert_eq!(sinh.to_string(), "1.1752014");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.5430813");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round(20, Nearest);
+    /// assert_eq!(sinh.to_string(), "1.1752014");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.5430813");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    #[inline]
+    pub fn sinh_cosh_prec_round(
+        self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        self.sinh_cosh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`], rounding both
+    /// results to the specified precision and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. Two [`Ordering`]s are also returned, indicating whether the rounded
+    /// hyperbolic sine and cosine are less than, equal to, or greater than the exact values.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::sinh_cosh_prec_round`] documentation for information on special cases and
+    /// overflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_cosh_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_cosh_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).sinh_cosh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round_ref(5, Floor);
+    /// assert_eq!(sinh.to_string(), "1.12");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.50");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sinh.to_string(), "1.19");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.56");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round_ref(5, Nearest);
+    /// assert_eq!(sinh.to_string(), "1.19");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.56");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round_ref(20, Floor);
+    /// assert_eq!(sinh.to_string(), "1.1751995");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.5430794");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sinh.to_string(), "1.1752014");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.5430813");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_round_ref(20, Nearest);
+    /// assert_eq!(sinh.to_string(), "1.1752014");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.5430813");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    pub fn sinh_cosh_prec_round_ref(
+        &self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN => ((Self::NAN, Equal), (Self::NAN, Equal)),
+            Infinity { .. } => ((self.clone(), Equal), (Self::INFINITY, Equal)),
+            Zero { .. } => ((self.clone(), Equal), (Self::one_prec(prec), Equal)),
+            Finite { .. } => sinh_cosh_rational_helper(&hyperbolic_argument(self), prec, rm),
+        }
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`], rounding both
+    /// results to the nearest value of the specified precision. The [`Float`] is taken by value.
+    /// Two [`Ordering`]s are also returned, indicating whether the rounded hyperbolic sine and
+    /// cosine are less than, equal to, or greater than the exact values. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic sine or cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sinh_cosh_prec_round`] documentation for information on special cases and
+    /// overflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_cosh_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::sinh_cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec(5);
+    /// assert_eq!(sinh.to_string(), "1.19");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.56");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec(20);
+    /// assert_eq!(sinh.to_string(), "1.1752014");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.5430813");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    #[inline]
+    pub fn sinh_cosh_prec(self, prec: u64) -> ((Self, Ordering), (Self, Ordering)) {
+        self.sinh_cosh_prec_round(prec, Nearest)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`], rounding both
+    /// results to the nearest value of the specified precision. The [`Float`] is taken by
+    /// reference. Two [`Ordering`]s are also returned, indicating whether the rounded hyperbolic
+    /// sine and cosine are less than, equal to, or greater than the exact values. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the hyperbolic sine or cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sinh_cosh_prec_round`] documentation for information on special cases and
+    /// overflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_cosh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).sinh_cosh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_ref(5);
+    /// assert_eq!(sinh.to_string(), "1.19");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.56");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh_prec_ref(20);
+    /// assert_eq!(sinh.to_string(), "1.1752014");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.5430813");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    #[inline]
+    pub fn sinh_cosh_prec_ref(&self, prec: u64) -> ((Self, Ordering), (Self, Ordering)) {
+        self.sinh_cosh_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`], rounding both
+    /// results to the precision of the input and with the specified rounding mode. The [`Float`] is
+    /// taken by value. Two [`Ordering`]s are also returned, indicating whether the rounded
+    /// hyperbolic sine and cosine are less than, equal to, or greater than the exact values.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::sinh_cosh_prec_round`] documentation for information on special cases and
+    /// overflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sinh_cosh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::sinh_cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but either result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from(PI).sinh_cosh_round(Floor);
+    /// assert_eq!(sinh.to_string(), "11.548739357257745");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "11.591953275521519");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from(PI).sinh_cosh_round(Ceiling);
+    /// assert_eq!(sinh.to_string(), "11.548739357257759");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "11.591953275521533");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from(PI).sinh_cosh_round(Nearest);
+    /// assert_eq!(sinh.to_string(), "11.548739357257745");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "11.591953275521519");
+    /// assert_eq!(o_cosh, Less);
+    /// ```
+    #[inline]
+    pub fn sinh_cosh_round(self, rm: RoundingMode) -> ((Self, Ordering), (Self, Ordering)) {
+        let prec = self.significant_bits();
+        self.sinh_cosh_prec_round(prec, rm)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`], rounding both
+    /// results to the precision of the input and with the specified rounding mode. The [`Float`] is
+    /// taken by reference. Two [`Ordering`]s are also returned, indicating whether the rounded
+    /// hyperbolic sine and cosine are less than, equal to, or greater than the exact values.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::sinh_cosh_prec_round`] documentation for information on special cases and
+    /// overflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::sinh_cosh_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `(&Float).sinh_cosh()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but either result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from(PI).sinh_cosh_round_ref(Floor);
+    /// assert_eq!(sinh.to_string(), "11.548739357257745");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "11.591953275521519");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from(PI).sinh_cosh_round_ref(Ceiling);
+    /// assert_eq!(sinh.to_string(), "11.548739357257759");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "11.591953275521533");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::from(PI).sinh_cosh_round_ref(Nearest);
+    /// assert_eq!(sinh.to_string(), "11.548739357257745");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "11.591953275521519");
+    /// assert_eq!(o_cosh, Less);
+    /// ```
+    #[inline]
+    pub fn sinh_cosh_round_ref(&self, rm: RoundingMode) -> ((Self, Ordering), (Self, Ordering)) {
+        let prec = self.significant_bits();
+        self.sinh_cosh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Rational`], rounding
+    /// both results to the specified precision and with the specified rounding mode and returning
+    /// the results as [`Float`]s. The [`Rational`] is taken by value. Two [`Ordering`]s are also
+    /// returned, indicating whether the rounded hyperbolic sine and cosine are less than, equal to,
+    /// or greater than the exact values.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sinh x+\varepsilon_1, \cosh x+\varepsilon_2).
+    /// $$
+    /// - If $\sinh x$ or $\cosh x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sinh x$ is nonzero and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon_1| < 2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$, and similarly for $\cosh x$
+    ///   and $\varepsilon_2$.
+    /// - If $\sinh x$ is nonzero and $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon_1|
+    ///   \leq 2^{\lfloor\log_2 |\sinh x|\rfloor-p}$, and similarly for $\cosh x$ and
+    ///   $\varepsilon_2$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=(0.0,1.0)$
+    ///
+    /// Underflow is not possible. Both results overflow when $|x|$ is greater than about
+    /// $7.44\times 10^8$.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_cosh_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(sinh.to_string(), "0.328");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.00");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(sinh.to_string(), "0.344");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.06");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Nearest);
+    /// assert_eq!(sinh.to_string(), "0.344");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.06");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(sinh.to_string(), "0.33954048");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.0560703");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(sinh.to_string(), "0.33954096");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.0560722");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Nearest);
+    /// assert_eq!(sinh.to_string(), "0.33954048");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.0560722");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sinh_cosh_rational_prec_round(
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        Self::sinh_cosh_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Rational`], rounding
+    /// both results to the specified precision and with the specified rounding mode and returning
+    /// the results as [`Float`]s. The [`Rational`] is taken by reference. Two [`Ordering`]s are
+    /// also returned, indicating whether the rounded hyperbolic sine and cosine are less than,
+    /// equal to, or greater than the exact values.
+    ///
+    /// See the [`Float::sinh_cosh_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_cosh_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but either result cannot be represented
+    /// exactly with the given precision (which is the case for every nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(sinh.to_string(), "0.328");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.00");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(sinh.to_string(), "0.344");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.06");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Nearest);
+    /// assert_eq!(sinh.to_string(), "0.344");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.06");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(sinh.to_string(), "0.33954048");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.0560703");
+    /// assert_eq!(o_cosh, Less);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(sinh.to_string(), "0.33954096");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.0560722");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Nearest);
+    /// assert_eq!(sinh.to_string(), "0.33954048");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.0560722");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    pub fn sinh_cosh_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return ((Self::ZERO, Equal), (Self::one_prec(prec), Equal));
+        }
+        sinh_cosh_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Rational`], rounding
+    /// both results to the nearest value of the specified precision and returning the results as
+    /// [`Float`]s. The [`Rational`] is taken by value. Two [`Ordering`]s are also returned,
+    /// indicating whether the rounded hyperbolic sine and cosine are less than, equal to, or
+    /// greater than the exact values.
+    ///
+    /// If the hyperbolic sine or cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sinh_cosh_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(sinh.to_string(), "0.344");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.06");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(sinh.to_string(), "0.33954048");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.0560722");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    #[inline]
+    pub fn sinh_cosh_rational_prec(x: Rational, prec: u64) -> ((Self, Ordering), (Self, Ordering)) {
+        Self::sinh_cosh_rational_prec_round(x, prec, Nearest)
+    }
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Rational`], rounding
+    /// both results to the nearest value of the specified precision and returning the results as
+    /// [`Float`]s. The [`Rational`] is taken by reference. Two [`Ordering`]s are also returned,
+    /// indicating whether the rounded hyperbolic sine and cosine are less than, equal to, or
+    /// greater than the exact values.
+    ///
+    /// If the hyperbolic sine or cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::sinh_cosh_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(sinh.to_string(), "0.344");
+    /// assert_eq!(o_sinh, Greater);
+    /// assert_eq!(cosh.to_string(), "1.06");
+    /// assert_eq!(o_cosh, Greater);
+    ///
+    /// let ((sinh, o_sinh), (cosh, o_cosh)) = Float::sinh_cosh_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(sinh.to_string(), "0.33954048");
+    /// assert_eq!(o_sinh, Less);
+    /// assert_eq!(cosh.to_string(), "1.0560722");
+    /// assert_eq!(o_cosh, Greater);
+    /// ```
+    #[inline]
+    pub fn sinh_cosh_rational_prec_ref(
+        x: &Rational,
+        prec: u64,
+    ) -> ((Self, Ordering), (Self, Ordering)) {
+        Self::sinh_cosh_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl SinhCosh for Float {
+    type Output = Self;
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`]. The [`Float`]
+    /// is taken by value.
+    ///
+    /// The precision of both outputs is the precision of the input. If the hyperbolic sine or
+    /// cosine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = (\sinh x+\varepsilon_1, \cosh x+\varepsilon_2).
+    /// $$
+    /// - If $\sinh x$ or $\cosh x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sinh x$ is nonzero, then $|\varepsilon_1| \leq 2^{\lfloor\log_2 |\sinh x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input, and similarly for $\cosh x$ and $\varepsilon_2$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=(\text{NaN},\text{NaN})$
+    /// - $f(\infty)=(\infty,\infty)$
+    /// - $f(-\infty)=(-\infty,\infty)$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// See the [`Float::sinh_cosh_prec_round`] documentation for information on overflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::SinhCosh;
+    /// use malachite_float::Float;
+    ///
+    /// let (sinh, cosh) = Float::from(PI).sinh_cosh();
+    /// assert_eq!(sinh.to_string(), "11.548739357257745");
+    /// assert_eq!(cosh.to_string(), "11.591953275521519");
+    ///
+    /// let (sinh, cosh) = Float::from_unsigned_prec(1u32, 100).0.sinh_cosh();
+    /// assert_eq!(sinh.to_string(), "1.1752011936438014568823818505953");
+    /// assert_eq!(cosh.to_string(), "1.5430806348152437784779056207575");
+    /// ```
+    #[inline]
+    fn sinh_cosh(self) -> (Self, Self) {
+        let prec = self.significant_bits();
+        let ((sinh, _), (cosh, _)) = self.sinh_cosh_prec_round(prec, Nearest);
+        (sinh, cosh)
+    }
+}
+
+impl SinhCosh for &Float {
+    type Output = Float;
+
+    /// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a [`Float`]. The [`Float`]
+    /// is taken by reference.
+    ///
+    /// The precision of both outputs is the precision of the input. If the hyperbolic sine or
+    /// cosine is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = (\sinh x+\varepsilon_1, \cosh x+\varepsilon_2).
+    /// $$
+    /// - If $\sinh x$ or $\cosh x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\sinh x$ is nonzero, then $|\varepsilon_1| \leq 2^{\lfloor\log_2 |\sinh x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input, and similarly for $\cosh x$ and $\varepsilon_2$.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=(\text{NaN},\text{NaN})$
+    /// - $f(\infty)=(\infty,\infty)$
+    /// - $f(-\infty)=(-\infty,\infty)$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// See the [`Float::sinh_cosh_prec_round`] documentation for information on overflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::SinhCosh;
+    /// use malachite_float::Float;
+    ///
+    /// let (sinh, cosh) = (&Float::from(PI)).sinh_cosh();
+    /// assert_eq!(sinh.to_string(), "11.548739357257745");
+    /// assert_eq!(cosh.to_string(), "11.591953275521519");
+    ///
+    /// let (sinh, cosh) = (&Float::from_unsigned_prec(1u32, 100).0).sinh_cosh();
+    /// assert_eq!(sinh.to_string(), "1.1752011936438014568823818505953");
+    /// assert_eq!(cosh.to_string(), "1.5430806348152437784779056207575");
+    /// ```
+    #[inline]
+    fn sinh_cosh(self) -> (Float, Float) {
+        let prec = self.significant_bits();
+        let ((sinh, _), (cosh, _)) = self.sinh_cosh_prec_round_ref(prec, Nearest);
+        (sinh, cosh)
+    }
+}
+
+/// Computes $(\sinh x, \cosh x)$, the hyperbolic sine and cosine of a primitive float, returning
+/// correctly-rounded results.
+///
+/// $$
+/// f(x) = (\sinh x+\varepsilon_1, \cosh x+\varepsilon_2).
+/// $$
+/// - If $\sinh x$ or $\cosh x$ is zero or `NaN`, the corresponding $\varepsilon$ may be ignored or
+///   assumed to be 0.
+/// - If $\sinh x$ is nonzero, then $|\varepsilon_1| \leq 2^{\lfloor\log_2 |\sinh x|\rfloor-p}$,
+///   where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and 53 if `T` is a
+///   [`f64`], but less if the output is subnormal), and similarly for $\cosh x$ and
+///   $\varepsilon_2$.
+///
+/// Special cases:
+/// - $f(\text{NaN})=(\text{NaN},\text{NaN})$
+/// - $f(\infty)=(\infty,\infty)$
+/// - $f(-\infty)=(-\infty,\infty)$
+/// - $f(0.0)=(0.0,1.0)$
+/// - $f(-0.0)=(-0.0,1.0)$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::sinh_cosh::primitive_float_sinh_cosh;
+///
+/// let (sinh, cosh) = primitive_float_sinh_cosh(PI);
+/// assert_eq!(NiceFloat(sinh), NiceFloat(11.548739357257746));
+/// assert_eq!(NiceFloat(cosh), NiceFloat(11.591953275521519));
+///
+/// let (sinh, cosh) = primitive_float_sinh_cosh(1.0f32);
+/// assert_eq!(NiceFloat(sinh), NiceFloat(1.1752012));
+/// assert_eq!(NiceFloat(cosh), NiceFloat(1.5430807));
+///
+/// let (sinh, cosh) = primitive_float_sinh_cosh(-0.0f64);
+/// assert_eq!(NiceFloat(sinh), NiceFloat(-0.0));
+/// assert_eq!(NiceFloat(cosh), NiceFloat(1.0));
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_sinh_cosh<T: PrimitiveFloat>(x: T) -> (T, T)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    (primitive_float_sinh(x), primitive_float_cosh(x))
+}
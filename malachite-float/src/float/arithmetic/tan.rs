@@ -0,0 +1,1020 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::sin_cos::tan_rational_helper;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{Tan, TanAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN as NaNTrait, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+impl Float {
+    /// Computes $\tan x$, the tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded tangent is less than, equal
+    /// to, or greater than the exact tangent. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The argument is in radians. Arguments of any size are reduced modulo $\pi/2$ using as many
+    /// bits of $\pi$ as are needed, so the result is correctly rounded even when $x$ is huge or
+    /// very close to a multiple of $\pi/2$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// Since $|\tan x|>|x|$ for nonzero $x$, underflow is not possible. Overflow would require $x$
+    /// to be within about $2^{-2^{30}}$ of an odd multiple of $\pi/2$, which is only possible if
+    /// its precision is greater than $2^{30}$.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round`] instead. If both of these things are true, consider using
+    /// [`Float::tan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round(5, Floor);
+    /// assert_eq!(tan.to_string(), "1.50");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round(5, Ceiling);
+    /// assert_eq!(tan.to_string(), "1.56");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round(5, Nearest);
+    /// assert_eq!(tan.to_string(), "1.56");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round(20, Floor);
+    /// assert_eq!(tan.to_string(), "1.5574074");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round(20, Ceiling);
+    /// assert_eq!(tan.to_string(), "1.5574093");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round(20, Nearest);
+    /// assert_eq!(tan.to_string(), "1.5574074");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.tan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded tangent is less than, equal
+    /// to, or greater than the exact tangent. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).tan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round_ref(5, Floor);
+    /// assert_eq!(tan.to_string(), "1.50");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round_ref(5, Ceiling);
+    /// assert_eq!(tan.to_string(), "1.56");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round_ref(5, Nearest);
+    /// assert_eq!(tan.to_string(), "1.56");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round_ref(20, Floor);
+    /// assert_eq!(tan.to_string(), "1.5574074");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round_ref(20, Ceiling);
+    /// assert_eq!(tan.to_string(), "1.5574093");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100)
+    ///     .0
+    ///     .tan_prec_round_ref(20, Nearest);
+    /// assert_eq!(tan.to_string(), "1.5574074");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn tan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { .. } => (Self::NAN, Equal),
+            Zero { .. } => (self.clone(), Equal),
+            Finite { .. } => tan_rational_helper(&Rational::exact_from(self), prec, rm),
+        }
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::tan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100).0.tan_prec(5);
+    /// assert_eq!(tan.to_string(), "1.56");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100).0.tan_prec(20);
+    /// assert_eq!(tan.to_string(), "1.5574074");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_prec(self, prec: u64) -> (Self, Ordering) {
+        self.tan_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded tangent is less than, equal to, or greater than the
+    /// exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).tan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100).0.tan_prec_ref(5);
+    /// assert_eq!(tan.to_string(), "1.56");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from_unsigned_prec(1u32, 100).0.tan_prec_ref(20);
+    /// assert_eq!(tan.to_string(), "1.5574074");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.tan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], rounding the result to the precision of the
+    /// input and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`]
+    /// is also returned, indicating whether the rounded tangent is less than, equal to, or greater
+    /// than the exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::tan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Floor);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473535e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473515e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Nearest);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473535e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.tan_prec_round(prec, rm)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], rounding the result to the precision of the
+    /// input and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded tangent is less than, equal
+    /// to, or greater than the exact tangent. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).tan()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Floor);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473535e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473515e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Nearest);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473535e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.tan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::tan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.tan_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.5574074");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.tan_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.5574093");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.tan_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.5574074");
+    /// ```
+    #[inline]
+    pub fn tan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.tan_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::tan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`, plus the exponent of `self` if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.tan_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.56");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// assert_eq!(x.tan_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.5574074");
+    /// ```
+    #[inline]
+    pub fn tan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.tan_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Float`], in place, rounding the result to the
+    /// precision of the input and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::tan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.2246467991473535e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-1.2246467991473515e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.2246467991473535e-16");
+    /// ```
+    #[inline]
+    pub fn tan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.tan_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(tan.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(tan.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(tan.to_string(), "0.34625340");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(tan.to_string(), "0.34625387");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn tan_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::tan_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Rational`], rounding the result to the specified
+    /// precision and with the specified rounding mode and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite nonzero input).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(tan.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(tan.to_string(), "0.359");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(tan.to_string(), "0.34625340");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) =
+    ///     Float::tan_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(tan.to_string(), "0.34625387");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn tan_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (Self::ZERO, Equal);
+        }
+        tan_rational_helper(x, prec, rm)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Rational`], rounding the result to the nearest value
+    /// of the specified precision and returning the result as a [`Float`]. The [`Rational`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded tangent is
+    /// less than, equal to, or greater than the exact tangent.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::tan_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::tan_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(tan.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::tan_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(tan.to_string(), "0.34625340");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn tan_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::tan_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\tan x$, the tangent of a [`Rational`], rounding the result to the nearest value
+    /// of the specified precision and returning the result as a [`Float`]. The [`Rational`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded tangent
+    /// is less than, equal to, or greater than the exact tangent.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::tan_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::tan_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(tan.to_string(), "0.344");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::tan_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(tan.to_string(), "0.34625340");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::tan_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Tan for Float {
+    type Output = Self;
+
+    /// Computes $\tan x$, the tangent of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the tangent is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::tan_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::tan_round`].
+    /// If you want both of these things, consider using [`Float::tan_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Tan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.tan().is_nan());
+    /// assert!(Float::INFINITY.tan().is_nan());
+    /// assert_eq!(Float::ZERO.tan(), 0.0);
+    /// assert_eq!(Float::from(PI).tan().to_string(), "-1.2246467991473535e-16");
+    /// assert_eq!(
+    ///     Float::from_unsigned_prec(1u32, 100).0.tan().to_string(),
+    ///     "1.5574077246549022305069748074591"
+    /// );
+    /// ```
+    #[inline]
+    fn tan(self) -> Self {
+        let prec = self.significant_bits();
+        self.tan_prec_round(prec, Nearest).0
+    }
+}
+
+impl Tan for &Float {
+    type Output = Float;
+
+    /// Computes $\tan x$, the tangent of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the tangent is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::tan_round_ref`]. If you want both of these things, consider using
+    /// [`Float::tan_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Tan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).tan().is_nan());
+    /// assert!((&Float::INFINITY).tan().is_nan());
+    /// assert_eq!((&Float::ZERO).tan(), 0.0);
+    /// assert_eq!(
+    ///     (&Float::from(PI)).tan().to_string(),
+    ///     "-1.2246467991473535e-16"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from_unsigned_prec(1u32, 100).0).tan().to_string(),
+    ///     "1.5574077246549022305069748074591"
+    /// );
+    /// ```
+    #[inline]
+    fn tan(self) -> Float {
+        let prec = self.significant_bits();
+        self.tan_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl TanAssign for Float {
+    /// Computes $\tan x$, the tangent of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the tangent is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases, overflow,
+    /// and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m (\log m)^2 \log\log m)$
+    ///
+    /// $M(n, m) = O(n \log n + m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// the exponent of `self` if it is positive, and 0 otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::TanAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.tan_assign();
+    /// assert_eq!(x.to_string(), "-1.2246467991473535e-16");
+    ///
+    /// let mut x = Float::from_unsigned_prec(1u32, 100).0;
+    /// x.tan_assign();
+    /// assert_eq!(x.to_string(), "1.5574077246549022305069748074591");
+    /// ```
+    #[inline]
+    fn tan_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.tan_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\tan x$, the tangent of a primitive float, returning a correctly-rounded result.
+///
+/// $$
+/// f(x) = \tan x+\varepsilon.
+/// $$
+/// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\tan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(\pm\infty)=\text{NaN}$
+/// - $f(0.0)=0.0$
+/// - $f(-0.0)=-0.0$
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::tan::primitive_float_tan;
+///
+/// assert!(primitive_float_tan(f64::NAN).is_nan());
+/// assert!(primitive_float_tan(f64::INFINITY).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_tan(-0.0f64)), NiceFloat(-0.0));
+/// assert_eq!(
+///     NiceFloat(primitive_float_tan(PI)),
+///     NiceFloat(-1.2246467991473532e-16)
+/// );
+/// assert_eq!(NiceFloat(primitive_float_tan(1.0f32)), NiceFloat(1.5574077));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_tan<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::tan_prec, x)
+}
+
+/// Computes $\tan x$, the tangent of a [`Rational`], returning the result as a primitive float.
+///
+/// $$
+/// f(x) = \tan x+\varepsilon.
+/// $$
+/// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\tan x$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2 |\tan
+///   x|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a [`f32`] and
+///   53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=0.0$
+///
+/// # Worst-case complexity
+/// $T(n) = O(n (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::tan::primitive_float_tan_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_tan_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3)
+///     )),
+///     NiceFloat(0.34625354951057546)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_tan_rational::<f64>(&Rational::from(10000))),
+///     NiceFloat(0.3209711346238147)
+/// );
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_tan_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::tan_rational_prec_ref, x)
+}
@@ -0,0 +1,3378 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+// The incomplete gamma functions are computed from an exact rational s and a positive rational x,
+// using whichever of four expansions converges quickly:
+//
+// - the series γ(s, x) = x ^ s e ^ (-x) Σ x ^ k / (s(s + 1)...(s + k)), for x that is not much
+//   larger than |s| and the working precision; Γ(s, x) is then Γ(s) - γ(s, x), and the cancellation
+//   is dealt with by increasing the precision until the difference is accurate enough;
+// - the asymptotic expansion Γ(s, x) = x ^ (s - 1) e ^ (-x) (Σ_{k<N} u_k + θu_N), where u_k = (s -
+//   1)(s - 2)...(s - k) / x ^ k and 0 <= θ <= 1 as long as N >= s - 1, for large x;
+// - the continued fraction Γ(s, x) = x ^ s e ^ (-x) / (x + (1 - s) / (1 + 1 / (x + (2 - s) / (1 + 2
+//   / (x + ...))))), for s < 1 and large x - s. All its elements are positive, so the true value
+//   lies between any two consecutive convergents;
+// - for s = -m a nonpositive integer, where Γ(s) is undefined, the identity Γ(-m, x) = e ^ (-x) x ^
+//   (-m) Σ_{k<m} (-x) ^ k / (m(m - 1)...(m - k)) + (-1) ^ m E₁(x) / m!, together with the series
+//   E₁(x) = -γ - log(x) + Σ_{k>0} (-1) ^ (k + 1) x ^ k / (k k!).
+//
+// Powers of x and e are computed with a power of 2 split off, so that the result can be computed
+// even when it is far outside the range of a `Float`, and when an argument is very large or very
+// small, simple bounds are used instead, avoiding building a `Rational` with an enormous numerator
+// or denominator. Every step produces rational bounds, and the result is rounded using Ziv's
+// strategy.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::atan2::mul_bounds;
+use crate::float::arithmetic::exp::{exp_overflow, exp_underflow};
+use crate::float::arithmetic::gamma::{
+    Argument, digamma_bounds, distance_to_integer, exp_scaled_bounds, exp_shift, gamma_helper,
+    gamma_is_positive, ln_abs_gamma_bounds, ln_bounds, ln_bounds_absolute,
+};
+use crate::float::arithmetic::round_near_x::float_round_near_x;
+use crate::float::arithmetic::sin_cos::rational_exponent;
+use crate::float::arithmetic::sinh_cosh::round_with_scaled_bounds;
+use crate::{
+    Float, emulate_float_float_to_float_fn, emulate_rational_rational_to_float_fn,
+    float_either_zero, float_infinity, float_nan, float_negative_infinity, float_zero,
+};
+use core::cmp::Ordering::{self, *};
+use core::cmp::{max, min};
+use malachite_base::num::arithmetic::traits::{
+    Abs, CheckedRoot, DivRound, Factorial, IsPowerOf2, Parity, Pow, PowerOf2, ShlRound, Square,
+    UpperIncompleteGamma, UpperIncompleteGammaAssign,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, One, Two, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Arguments x with an exponent greater than this, that is, x >= 2 ^ 48, are huge: e ^ (-x) is far
+// below the smallest positive `Float`. Below this, the logarithms used to scale the results,
+// divided by log(2), fit in an `i64`.
+const HUGE_X_EXPONENT: i64 = 48;
+
+// Arguments s with an exponent greater than this, that is, |s| >= 2 ^ 26, are huge, as they are for
+// Γ.
+const HUGE_S_EXPONENT: i64 = 26;
+
+// If log(v) > 2 ^ 30, v is above the largest finite `Float`, and if log(v) < -2 ^ 30, v is below
+// the smallest positive `Float`.
+const LN_LIMIT_EXPONENT: i64 = 30;
+
+// Compares two positive `Argument`s.
+fn cmp_positive(a: &Argument, b: &Argument) -> Ordering {
+    a.exponent()
+        .cmp(&b.exponent())
+        .then_with(|| a.mantissa().cmp(b.mantissa()))
+}
+
+// Returns 64-bit `Float` bounds on an `Argument`.
+fn float_bounds(x: &Argument) -> (Float, Float) {
+    let e = x.exponent();
+    (
+        Float::from_rational_prec_round_ref(x.mantissa(), 64, Floor)
+            .0
+            .shl_round(e, Floor)
+            .0,
+        Float::from_rational_prec_round_ref(x.mantissa(), 64, Ceiling)
+            .0
+            .shl_round(e, Ceiling)
+            .0,
+    )
+}
+
+// Returns 64-bit `Float` bounds on a `Rational`.
+fn rational_float_bounds((lo, hi): (Rational, Rational)) -> (Float, Float) {
+    (
+        Float::from_rational_prec_round(lo, 64, Floor).0,
+        Float::from_rational_prec_round(hi, 64, Ceiling).0,
+    )
+}
+
+// Returns bounds on log(x), for a positive x, with a relative width of about `2 ^ (-w)`.
+// `Argument::ln_abs_bounds` only has an absolute width of about `2 ^ (-w)`, which is too coarse
+// when x is close to 1 and the logarithm is multiplied by a huge s.
+fn ln_relative_bounds(x: &Argument, w: u64) -> (Rational, Rational) {
+    if (0..=1).contains(&x.exponent()) {
+        ln_bounds(&x.to_rational(), w)
+    } else {
+        x.ln_abs_bounds(w)
+    }
+}
+
+// Returns 64-bit `Float` bounds on log(x), for a positive x.
+fn ln_float_bounds(x: &Argument) -> (Float, Float) {
+    rational_float_bounds(ln_relative_bounds(x, 64))
+}
+
+fn float_add_bounds(
+    (a_lo, a_hi): &(Float, Float),
+    (b_lo, b_hi): &(Float, Float),
+) -> (Float, Float) {
+    (
+        a_lo.add_prec_round_ref_ref(b_lo, 64, Floor).0,
+        a_hi.add_prec_round_ref_ref(b_hi, 64, Ceiling).0,
+    )
+}
+
+fn float_sub_bounds(
+    (a_lo, a_hi): &(Float, Float),
+    (b_lo, b_hi): &(Float, Float),
+) -> (Float, Float) {
+    (
+        a_lo.sub_prec_round_ref_ref(b_hi, 64, Floor).0,
+        a_hi.sub_prec_round_ref_ref(b_lo, 64, Ceiling).0,
+    )
+}
+
+fn float_mul_bounds(
+    (a_lo, a_hi): &(Float, Float),
+    (b_lo, b_hi): &(Float, Float),
+) -> (Float, Float) {
+    let ps = [(a_lo, b_lo), (a_lo, b_hi), (a_hi, b_lo), (a_hi, b_hi)];
+    let mut lo = Float::INFINITY;
+    let mut hi = Float::NEGATIVE_INFINITY;
+    for (a, b) in ps {
+        let p = a.mul_prec_round_ref_ref(b, 64, Floor).0;
+        if p < lo {
+            lo = p;
+        }
+        let p = a.mul_prec_round_ref_ref(b, 64, Ceiling).0;
+        if p > hi {
+            hi = p;
+        }
+    }
+    (lo, hi)
+}
+
+// Returns `Some(true)` if a value whose logarithm is at least `lower` overflows, `Some(false)` if a
+// value whose logarithm is at most `upper` underflows, and `None` otherwise.
+fn ln_screen(lower: Option<&Float>, upper: Option<&Float>) -> Option<bool> {
+    let limit = Float::power_of_2(LN_LIMIT_EXPONENT);
+    if lower.is_some_and(|l| *l > limit) {
+        Some(true)
+    } else if upper.is_some_and(|u| *u < -limit) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+// Returns a lower bound on -log_2(r), given bounds (of which only the upper one is used) on log(r),
+// capped at 2 ^ 62.
+fn ln_to_bits(ln_r_hi: &Float) -> u64 {
+    let bits = -ln_r_hi
+        .div_prec_round_ref_val(Float::ln_2_prec_round(64, Ceiling).0, 64, Floor)
+        .0;
+    if bits > Float::power_of_2(62u64) {
+        1 << 62
+    } else if bits < 0u32 {
+        0
+    } else {
+        u64::rounding_from(&bits, Floor).0
+    }
+}
+
+// Bounds, scaled by the same power of 2, on t * num / den, given scaled bounds on t; den > 0.
+fn mul_div_fixed((lo, hi): (Integer, Integer), num: &Integer, den: &Integer) -> (Integer, Integer) {
+    let (lo, hi) = if *num >= 0u32 {
+        (lo * num, hi * num)
+    } else {
+        (hi * num, lo * num)
+    };
+    (lo.div_round(den, Floor).0, hi.div_round(den, Ceiling).0)
+}
+
+// Returns the signed numerator and the denominator of a `Rational`.
+fn integer_fraction(x: &Rational) -> (Integer, Integer) {
+    (
+        Integer::from_sign_and_abs_ref(*x >= 0u32, x.numerator_ref()),
+        Integer::from(x.denominator_ref()),
+    )
+}
+
+// Returns whether a `Rational` is zero or a negative integer.
+fn is_nonpositive_integer(s: &Rational) -> bool {
+    *s <= 0u32 && s.is_integer()
+}
+
+// Returns bounds on S = Σ x ^ k / (s(s + 1)...(s + k)), for a positive rational `x` and a rational
+// `s` that is not zero or a negative integer, with an absolute width of about `2 ^ (-w)` times the
+// largest term.
+//
+// The terms are computed in fixed point, each both rounded down and rounded up from the previous
+// term's bounds. When s < 0 the terms may change sign, but once s + k + 1 >= 2x, the ratios of
+// consecutive terms are at most 1/2 in absolute value, so the tail is smaller than the last term.
+fn lower_series_bounds(s: &Rational, x: &Rational, w: u64) -> (Rational, Rational) {
+    let (a, b) = integer_fraction(s);
+    let (c, d) = integer_fraction(x);
+    let scale = w + 48 + u64::exact_from(rational_exponent(s).max(0));
+    let cb = &c * &b;
+    let cb_2 = &cb << 1u32;
+    let t0 = Rational::from(&b << scale) / Rational::from(&a);
+    let mut t = (
+        Integer::rounding_from(&t0, Floor).0,
+        Integer::rounding_from(&t0, Ceiling).0,
+    );
+    let (mut sum_lo, mut sum_hi) = t.clone();
+    let mut max = (&t.1).abs().max((&t.0).abs());
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        // s + k = (a + kb) / b
+        let sk = &a + Integer::from(k) * &b;
+        let num = if sk >= 0u32 { cb.clone() } else { -&cb };
+        t = mul_div_fixed(t, &num, &(&d * (&sk).abs()));
+        sum_lo += &t.0;
+        sum_hi += &t.1;
+        let t_max = (&t.0).abs().max((&t.1).abs());
+        if t_max > max {
+            max = t_max.clone();
+        }
+        // s + k + 1 >= 2x
+        if (sk + &b) * &d >= cb_2 && (&t_max << (w + 8)) <= max {
+            sum_lo -= &t_max;
+            sum_hi += t_max;
+            break;
+        }
+    }
+    (
+        Rational::from(sum_lo) >> scale,
+        Rational::from(sum_hi) >> scale,
+    )
+}
+
+// Returns bounds on F = Σ_{k<N} u_k + θu_N = Γ(s, x) x ^ (1 - s) e ^ x, for a positive rational `x`
+// with x >= s, with an absolute width of about `2 ^ (-w)`, or `None` if the terms u_k start growing
+// before they get that small. For N >= s - 1, θ is between 0 and 1; for N < s - 1, θ is between 0
+// and x / (x + 1 - s + N), since Γ(a, x) < x ^ a e ^ (-x) / (x + 1 - a) for a > 1 and x > a - 1.
+fn asymptotic_bounds(s: &Rational, x: &Rational, w: u64) -> Option<(Rational, Rational)> {
+    let (a, b) = integer_fraction(s);
+    let (c, d) = integer_fraction(x);
+    let scale = w + 48;
+    let one = Integer::power_of_2(scale);
+    let bc = &b * &c;
+    let mut u = (one.clone(), one.clone());
+    let (mut sum_lo, mut sum_hi) = u.clone();
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        // u_k = u_{k-1} (s - k) / x, where s - k = (a - kb) / b
+        let skb = &a - Integer::from(k) * &b;
+        u = mul_div_fixed(u, &(&skb * &d), &bc);
+        if skb <= b {
+            // N = k >= s - 1
+            let u_max = (&u.0).abs().max((&u.1).abs());
+            if (u_max << (w + 8)) <= one {
+                if u.0 < 0u32 {
+                    sum_lo += u.0;
+                }
+                if u.1 > 0u32 {
+                    sum_hi += u.1;
+                }
+                break;
+            }
+            // k + 1 - s >= x: the next terms are no smaller
+            if (Integer::from(k + 1) * &b - &a) * &d >= bc {
+                return None;
+            }
+        } else {
+            // N = k < s - 1, where 0 < θu_N <= u_N x / (x + 1 - s + N), and
+            // x + 1 - s + N = (bc + (b - a + kb)d) / bd
+            let r = (&u.1 * &bc).div_round(&bc + (&b - &skb) * &d, Ceiling).0;
+            if (&r << (w + 8)) <= one {
+                sum_hi += r;
+                break;
+            }
+        }
+        sum_lo += &u.0;
+        sum_hi += &u.1;
+    }
+    Some((
+        Rational::from(sum_lo) >> scale,
+        Rational::from(sum_hi) >> scale,
+    ))
+}
+
+// Returns bounds on the n-th convergent of the continued fraction for G = Γ(s, x) x ^ (-s) e ^ x,
+// for s < 1 and x > 0, computed backward in fixed point with `scale` fractional bits. The k-th
+// partial numerator and denominator are a_1 = 1, b_1 = x, a_{2j} = j - s, b_{2j} = 1, a_{2j + 1} =
+// j, and b_{2j + 1} = x. The tail t_k = b_k + a_{k + 1} / t_{k + 1} is decreasing in t_{k + 1}, so
+// its bounds swap at each step.
+fn convergent_bounds(s: &Rational, x: &Rational, n: u64, scale: u64) -> (Rational, Rational) {
+    let (p, q) = integer_fraction(s);
+    let (c, d) = x.numerator_and_denominator_ref();
+    let x_lo = (c << scale) / d;
+    let x_hi = (c << scale).div_round(d, Ceiling).0;
+    let one = Natural::power_of_2(scale);
+    let b = |k: u64| {
+        if k.odd() {
+            (x_lo.clone(), x_hi.clone())
+        } else {
+            (one.clone(), one.clone())
+        }
+    };
+    // a_k as a fraction
+    let a = |k: u64| {
+        let j = k >> 1;
+        if k.odd() {
+            (Natural::from(j), Natural::ONE)
+        } else {
+            (
+                Natural::exact_from(Integer::from(j) * &q - &p),
+                Natural::exact_from(&q),
+            )
+        }
+    };
+    let (mut lo, mut hi) = b(n);
+    let scale_2 = scale << 1;
+    for k in (1..n).rev() {
+        let (b_lo, b_hi) = b(k);
+        let (a_n, a_d) = a(k + 1);
+        let a_n = a_n << scale_2;
+        let new_lo = b_lo + &a_n / (&a_d * &hi);
+        let new_hi = b_hi + a_n.div_round(a_d * &lo, Ceiling).0;
+        lo = new_lo;
+        hi = new_hi;
+    }
+    // f_n = a_1 / t_1 = 1 / t_1
+    let one = Rational::from(one);
+    (&one / Rational::from(hi), one / Rational::from(lo))
+}
+
+// Returns bounds on G = Γ(s, x) x ^ (-s) e ^ x, for s < 1 and x > 0, with a relative width of about
+// `2 ^ (-w)`, using pairs of consecutive convergents of ever greater depth.
+fn continued_fraction_bounds(s: &Rational, x: &Rational, w: u64) -> (Rational, Rational) {
+    let mut n = 16;
+    loop {
+        let scale = w + 40 + n.significant_bits() + rational_exponent(x).unsigned_abs();
+        let (f_lo, f_hi) = convergent_bounds(s, x, n, scale);
+        let (g_lo, g_hi) = convergent_bounds(s, x, n + 1, scale);
+        let lo = min(f_lo, g_lo);
+        let hi = max(f_hi, g_hi);
+        if ((&hi - &lo) << (w + 8)) <= lo {
+            return (lo, hi);
+        }
+        n <<= 1;
+    }
+}
+
+// Returns bounds on Ein(x) = Σ_{k>0} (-1) ^ (k + 1) x ^ k / (k k!), for a positive rational `x`,
+// with an absolute width of about `2 ^ (-w)`. Once k >= 2x, the terms decrease by at least half at
+// each step, so the tail is smaller than the last term.
+fn ein_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let (c, d) = integer_fraction(x);
+    let scale = w + 48 + u64::exact_from(rational_exponent(x).max(0)) * 2;
+    let one = Integer::power_of_2(scale);
+    // v = x ^ k / k!
+    let mut v = (one.clone(), one.clone());
+    let mut sum_lo = Integer::ZERO;
+    let mut sum_hi = Integer::ZERO;
+    let mut k = 0u64;
+    loop {
+        k += 1;
+        let big_k = Integer::from(k);
+        v = mul_div_fixed(v, &c, &(&d * &big_k));
+        let t_lo = (&v.0).div_round(&big_k, Floor).0;
+        let t_hi = (&v.1).div_round(&big_k, Ceiling).0;
+        if k.odd() {
+            sum_lo += &t_lo;
+            sum_hi += &t_hi;
+        } else {
+            sum_lo -= &t_hi;
+            sum_hi -= &t_lo;
+        }
+        if k >= (x << 1u32) && (&t_hi << (w + 8)) <= one {
+            sum_lo -= &t_hi;
+            sum_hi += t_hi;
+            break;
+        }
+    }
+    (
+        Rational::from(sum_lo) >> scale,
+        Rational::from(sum_hi) >> scale,
+    )
+}
+
+// Returns bounds on Euler's constant γ = -ψ(1), with an absolute width of about `2 ^ (-w)`.
+fn euler_gamma_bounds(w: u64) -> (Rational, Rational) {
+    let (lo, hi) = digamma_bounds(&Rational::ONE, w);
+    (-hi, -lo)
+}
+
+// Returns bounds on E₁(x) = Γ(0, x), for a positive rational `x`, with an absolute width of about
+// `2 ^ (-w)`.
+fn e1_bounds(x: &Rational, w: u64) -> (Rational, Rational) {
+    let (g_lo, g_hi) = euler_gamma_bounds(w + 2);
+    let (l_lo, l_hi) = ln_bounds_absolute(x, w + 2);
+    let (e_lo, e_hi) = ein_bounds(x, w + 2);
+    (e_lo - g_hi - l_hi, e_hi - g_lo - l_lo)
+}
+
+// Returns bounds on e ^ (s log(x) - x) / 2 ^ n, for a positive rational `x`, with a relative width
+// of about `2 ^ (-w)`.
+fn power_exp_scaled_bounds(s: &Rational, x: &Rational, n: i64, w: u64) -> (Rational, Rational) {
+    let s_bits = if *s == 0u32 {
+        0
+    } else {
+        u64::exact_from(rational_exponent(s).max(0))
+    };
+    let (l_lo, l_hi) = ln_bounds_absolute(x, w + 8 + s_bits);
+    let (l_lo, l_hi) = if *s >= 0u32 {
+        (s * l_lo, s * l_hi)
+    } else {
+        (s * l_hi, s * l_lo)
+    };
+    exp_scaled_bounds((l_lo - x, l_hi - x), n, true, w)
+}
+
+// Returns bounds on Γ(s) / 2 ^ n, for a rational `s` that is not zero or a negative integer, with a
+// relative width of about `2 ^ (-w)`.
+fn gamma_scaled_bounds(s: &Rational, n: i64, w: u64) -> (Rational, Rational) {
+    exp_scaled_bounds(ln_abs_gamma_bounds(s, w + 4), n, gamma_is_positive(s), w)
+}
+
+// Returns bounds on γ(s, x) / 2 ^ n, for a positive rational `x` and a rational `s` that is not
+// zero or a negative integer, using the series.
+fn lower_series_scaled_bounds(s: &Rational, x: &Rational, n: i64, w: u64) -> (Rational, Rational) {
+    let p = power_exp_scaled_bounds(s, x, n, w + 4);
+    mul_bounds(&p, &lower_series_bounds(s, x, w + 4))
+}
+
+// Returns bounds on Γ(s, x) / 2 ^ n, for a positive rational `x` and a rational `s`, using the
+// asymptotic expansion, or `None` if it does not converge far enough.
+fn asymptotic_scaled_bounds(
+    s: &Rational,
+    x: &Rational,
+    n: i64,
+    w: u64,
+) -> Option<(Rational, Rational)> {
+    let f = asymptotic_bounds(s, x, w + 4)?;
+    let p = power_exp_scaled_bounds(&(s - Rational::ONE), x, n, w + 4);
+    Some(mul_bounds(&p, &f))
+}
+
+// Returns bounds on Γ(s, x) / 2 ^ n, for a positive rational `x` and a rational `s < 1`, using the
+// continued fraction.
+fn continued_fraction_scaled_bounds(
+    s: &Rational,
+    x: &Rational,
+    n: i64,
+    w: u64,
+) -> (Rational, Rational) {
+    let (g_lo, g_hi) = continued_fraction_bounds(s, x, w + 4);
+    let (p_lo, p_hi) = power_exp_scaled_bounds(s, x, n, w + 4);
+    (p_lo * g_lo, p_hi * g_hi)
+}
+
+// Returns bounds on Γ(-m, x) / 2 ^ n, for a positive rational `x`.
+fn nonpositive_integer_scaled_bounds(m: u64, x: &Rational, n: i64, w: u64) -> (Rational, Rational) {
+    let (e_lo, e_hi) = e1_bounds(x, w + 4);
+    let (e_lo, e_hi) = if m == 0 {
+        (e_lo, e_hi)
+    } else {
+        let f = Rational::from(Natural::factorial(m));
+        if m.even() {
+            (e_lo / &f, e_hi / f)
+        } else {
+            (-e_hi / &f, -e_lo / f)
+        }
+    };
+    // |E₁(x)| < 1 + |log(x)| < 2 ^ (k + 1), where k is the number of significant bits of the
+    // exponent of x, so for a large n the term is negligible, and shifting it exactly could produce
+    // a huge `Rational`
+    let k = rational_exponent(x).unsigned_abs().significant_bits();
+    let (e_lo, e_hi) = if n > i64::exact_from(w + k + 24) {
+        let tiny = Rational::power_of_2(-i64::exact_from(w + 16));
+        (-&tiny, tiny)
+    } else {
+        (e_lo >> n, e_hi >> n)
+    };
+    if m == 0 {
+        return (e_lo, e_hi);
+    }
+    // Σ_{k<m} (-x) ^ k / (m(m - 1)...(m - k)), computed exactly
+    let mut t = Rational::from_unsigneds(1u64, m);
+    let mut sum = t.clone();
+    for k in 1..m {
+        t = -t * x / Rational::from(m - k);
+        sum += &t;
+    }
+    let m = Rational::from(m);
+    let p = power_exp_scaled_bounds(&-m, x, n, w + 4);
+    let (p_lo, p_hi) = mul_bounds(&p, &(sum.clone(), sum));
+    (p_lo + e_lo, p_hi + e_hi)
+}
+
+// For a tiny positive `x`, returns a number d such that the relative error in approximating γ(s, x)
+// by x ^ s / s is less than 2 ^ (e + d), where e is the exponent of x, or, for a negative integer s
+// = -m, in approximating Γ(-m, x) by x ^ (-m) / m.
+//
+// For s not an integer that is not positive, γ(s, x) = x ^ s e ^ (-x) / s * (1 + r), where |r| <=
+// 2x / d₁ if x / d₁ <= 1/2, d₁ being the smallest of |s + 1|, |s + 2|, ..., and 1 - x < e ^ (-x) <=
+// 1. For s = -m, Γ(-m, x) = e ^ (-x) x ^ (-m) / m * (1 + r) + (-1) ^ m E₁(x) / m!, where |r| <= 2x
+// and |E₁(x)| < 1 - log(x), which is less than (2 - e) x relative to x ^ (-m) / m.
+fn tiny_x_error_exponent(s: &Rational, e: i64) -> i64 {
+    if is_nonpositive_integer(s) {
+        assert_ne!(*s, 0u32);
+        i64::exact_from(e.unsigned_abs().significant_bits()) + 3
+    } else {
+        let d_1 = if *s < -1i32 {
+            distance_to_integer(s).0
+        } else {
+            s + Rational::ONE
+        };
+        3 - min(rational_exponent(&d_1) - 1, 0)
+    }
+}
+
+// Returns x ^ s / |s|, for a rational `s` that is not zero and a positive x, if it is a dyadic
+// rational that might be a rounding boundary at precision `prec`, as a `Float` and a power of 2 by
+// which it must be multiplied; the product may be outside the `Float` range. Writing x = r 2 ^ f,
+// where r is the ratio of two odd integers, and s = p / q in lowest terms, x ^ s is rational if and
+// only if r has a rational q-th root and q divides f.
+fn dyadic_power_over_s(s: &Rational, x: &Argument, prec: u64) -> Option<(Float, i64)> {
+    let (a, d) = x.mantissa().numerator_and_denominator_ref();
+    let a_zeros = a.trailing_zeros().unwrap();
+    let d_zeros = d.trailing_zeros().unwrap();
+    let r = Rational::from_naturals(a >> a_zeros, d >> d_zeros);
+    let f = x.exponent() + i64::exact_from(a_zeros) - i64::exact_from(d_zeros);
+    let q = i64::try_from(s.denominator_ref()).ok()?;
+    if f % q != 0 {
+        return None;
+    }
+    let r = (&r).checked_root(q.unsigned_abs())?;
+    let mut p = i64::try_from(s.numerator_ref()).ok()?;
+    if *s < 0u32 {
+        p = -p;
+    }
+    let shift = (f / q).checked_mul(p)?;
+    // If r != 1, the odd part of the numerator or denominator of r ^ p has more than |p|(b - 1)
+    // bits, where b is the number of bits of the larger one in r. Dividing by |s| removes at most
+    // 64 of them from the numerator, and a denominator that is not a power of 2 makes x ^ s / |s|
+    // non-dyadic unless q cancels it. Either way, once |p|(b - 1) > prec + 128, x ^ s / |s| is
+    // not a rounding boundary, and computing r ^ p would be wasteful.
+    if r != 1u32 {
+        let b = max(
+            r.numerator_ref().significant_bits(),
+            r.denominator_ref().significant_bits(),
+        );
+        if p.unsigned_abs().saturating_mul(b - 1) > prec + 128 {
+            return None;
+        }
+    }
+    let v = r.pow(p) / s.abs();
+    if !v.denominator_ref().is_power_of_2() {
+        return None;
+    }
+    let bits = max(1, v.numerator_ref().significant_bits());
+    Some((Float::from_rational_prec_round(v, bits, Exact).0, shift))
+}
+
+// For a tiny positive x, rounds γ(s, x) for s > 0, or Γ(s, x) for s < 0, using x ^ s / |s|, when
+// that is a dyadic rational (see `dyadic_power_over_s`). Since γ(s, x) and Γ(s, x) are then within
+// a tiny relative distance of a value that may be a rounding boundary, bounds on them might never
+// be narrow enough to round.
+//
+// - For s > 0, γ(s, x) < x ^ s / s, with the relative error bounded by `tiny_x_error_exponent`.
+// - For s = -m a negative integer, Γ(-m, x) < x ^ (-m) / m, since Γ(1 - m, x) = -m Γ(-m, x) + x ^
+//   (-m) e ^ (-x) is positive; the relative error is again bounded by `tiny_x_error_exponent`.
+// - For other s < 0, Γ(s, x) = Γ(s) - γ(s, x) also differs from -x ^ s / s by Γ(s), and the
+//   direction of the error depends on which of the terms is larger.
+fn tiny_x_round_near_power(
+    s: &Rational,
+    x: &Argument,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let e = x.exponent();
+    if e >= 0 {
+        return None;
+    }
+    let d = e + tiny_x_error_exponent(s, e);
+    if d >= 0 {
+        return None;
+    }
+    let (v, shift) = dyadic_power_over_s(s, x, prec)?;
+    let exp_v = i64::from(v.get_exponent().unwrap()).checked_add(shift)?;
+    let one_sided = *s > 0u32 || s.is_integer();
+    if !(Float::MIN_EXPONENT_I64..=Float::MAX_EXPONENT_I64).contains(&exp_v) {
+        // The result is slightly below v, which is outside the `Float` range. If v is too large,
+        // the result overflows, and otherwise it underflows, in which case it is closer to the
+        // smallest positive `Float` than to zero exactly when v is greater than half of that.
+        if !one_sided || d >= -i64::exact_from(max(prec, v.get_prec().unwrap()) + 2) {
+            return None;
+        }
+        return Some(if exp_v > Float::MAX_EXPONENT_I64 {
+            exp_overflow(prec, rm)
+        } else if exp_v == Float::MIN_EXPONENT_I64 - 1
+            && (rm == Nearest || rm == NearestTiesAway)
+            && !v.is_power_of_2()
+        {
+            (Float::min_positive_value_prec(prec), Greater)
+        } else {
+            exp_underflow(prec, rm)
+        });
+    }
+    let v = v << shift;
+    if one_sided {
+        return float_round_near_x(&v, d.unsigned_abs(), false, prec, rm);
+    }
+    // Γ(s, x) - v = Γ(s) + v (e ^ (-x) S - 1), where S = 1 - x / (s + 1) + x ^ 2 / ((s + 1)(s +
+    // 2)) - ..., so the second term is -v x c, with c = (s + 2) / (s + 1), plus terms of order x
+    // ^ 2, which are less than 6v(x / d₁) ^ 2 in absolute value (see `tiny_x_error_exponent`). If
+    // 24x < |c| d₁ ^ 2, the second term is within a factor of 5/4 of -v x c. The direction of the
+    // error is that of whichever of the two terms is much larger, if either is.
+    let d_1 = if *s < -1i32 {
+        distance_to_integer(s).0
+    } else {
+        s + Rational::ONE
+    };
+    let c = (s + Rational::TWO) / (s + Rational::ONE);
+    if e + 6 >= rational_exponent(&((&c).abs() * d_1.square())) - 1 {
+        return None;
+    }
+    // 2 ^ (b - 4) < |v x c| * 3/4 and |v x c| * 5/4 < 2 ^ (b + 1)
+    let b = exp_v + e + rational_exponent(&c);
+    // 2 ^ g_lo < |Γ(s)| < 2 ^ g_hi
+    let (l_lo, l_hi) = ln_abs_gamma_bounds(s, 16);
+    let g_lo = exp_shift(&l_lo) - 1;
+    let g_hi = exp_shift(&l_hi) + 2;
+    let dir = if g_hi < b - 6 {
+        c < 0u32
+    } else if b + 3 < g_lo {
+        gamma_is_positive(s)
+    } else {
+        return None;
+    };
+    let err = exp_v - max(b + 1, g_hi) - 1;
+    if err <= 0 {
+        return None;
+    }
+    float_round_near_x(&v, err.unsigned_abs(), dir, prec, rm)
+}
+
+// Returns bounds on γ(s, x) / 2 ^ n for s not zero or a negative integer, or on Γ(-m, x) / 2 ^ n
+// for s = -m a negative integer, when x is so small that x ^ s / s, or x ^ (-m) / m, is a good
+// enough approximation; that is, when the error bound from `tiny_x_error_exponent` is less than 2 ^
+// (-w - 8). Returns `None` if x is not that small. x is not needed exactly.
+fn tiny_x_scaled_bounds(
+    s: &Rational,
+    x: &Argument,
+    n: i64,
+    w: u64,
+) -> Option<(Rational, Rational)> {
+    let e = x.exponent();
+    let d = e + tiny_x_error_exponent(s, e);
+    if d >= -i64::exact_from(w + 8) {
+        return None;
+    }
+    let (l_lo, l_hi) = x.ln_abs_bounds(w + 8 + u64::exact_from(rational_exponent(s).max(0)));
+    let (l_lo, l_hi) = if *s >= 0u32 {
+        (s * l_lo, s * l_hi)
+    } else {
+        (s * l_hi, s * l_lo)
+    };
+    let (p_lo, p_hi) = exp_scaled_bounds((l_lo, l_hi), n, true, w + 4);
+    // d may be hugely negative, so the weaker bound 2 ^ (-w - 8) is used instead of 2 ^ d
+    let delta = Rational::power_of_2(-i64::exact_from(w + 8));
+    let p_lo = p_lo * (Rational::ONE - &delta);
+    let p_hi = p_hi * (Rational::ONE + delta);
+    Some(if *s > 0u32 {
+        (p_lo / s, p_hi / s)
+    } else {
+        // the bounds on x ^ s / s are negated for s < 0, but for s = -m the approximation is of x ^
+        // (-m) / m
+        let s_abs = s.abs();
+        if s.is_integer() {
+            (p_lo / &s_abs, p_hi / s_abs)
+        } else {
+            (-p_hi / &s_abs, -p_lo / s_abs)
+        }
+    })
+}
+
+// Returns bounds on E₁(x) = Γ(0, x) / 2 ^ n when x is so small that E₁(x) = -γ - log(x) + Ein(x),
+// where 0 < Ein(x) < x, is close enough to -γ - log(x), or `None` if x is not that small. x is not
+// needed exactly.
+fn tiny_x_e1_scaled_bounds(x: &Argument, n: i64, w: u64) -> Option<(Rational, Rational)> {
+    let e = x.exponent();
+    if e >= -i64::exact_from(w + 8) {
+        return None;
+    }
+    let (g_lo, g_hi) = euler_gamma_bounds(w + 8);
+    let (l_lo, l_hi) = x.ln_abs_bounds(w + 8);
+    // Ein(x) < x < 2 ^ (-w - 8)
+    Some((
+        (-g_hi - l_hi) >> n,
+        (Rational::power_of_2(-i64::exact_from(w + 8)) - g_lo - l_lo) >> n,
+    ))
+}
+
+// Calls `f` at precision `w` plus the number of extra bits stored in `extra`. If the resulting
+// bounds do not have a relative width of about `2 ^ (-w)`, because of cancellation, `extra` is
+// increased by about the number of bits that were lost, so that the next call, with a higher `w`,
+// succeeds.
+fn with_cancellation<F: FnMut(u64) -> (Rational, Rational)>(
+    w: u64,
+    extra: &mut u64,
+    mut f: F,
+) -> (Rational, Rational) {
+    let (lo, hi) = f(w + *extra);
+    if lo <= 0u32 {
+        *extra = (*extra << 1) + w;
+    } else if hi != lo {
+        let lost = rational_exponent(&(&hi - &lo)) + i64::exact_from(w) - rational_exponent(&lo);
+        if lost > 0 {
+            *extra += u64::exact_from(lost) + 8;
+        }
+    }
+    (lo, hi)
+}
+
+// Returns `Some(true)` if Γ(s, x) is known to overflow, `Some(false)` if it is known to underflow,
+// and `None` otherwise, for s given as an `Argument` (or `None` for s = 0) and a positive x. This
+// only looks at the arguments closely if one of them is huge or x is tiny, using these bounds:
+//
+// - Γ(s, x) <= x ^ (s - 1) e ^ (-x) for s <= 1, and Γ(s, x) <= x ^ s e ^ (-x) for 1 < s <= x;
+// - Γ(s, x) >= x ^ (s - 1) e ^ (-x) for s >= 1, and Γ(s, x) >= x ^ s e ^ (-x) / (x + 1 - s) for s <
+//   1;
+// - Γ(s, x) > Γ(s) / 2 for x <= s - 1, since the median of the gamma distribution with shape s is
+//   greater than s - 1.
+fn upper_screen(s: Option<&Argument>, x: &Argument) -> Option<bool> {
+    let ex = x.exponent();
+    let Some(s) = s else {
+        // Γ(0, x) = E₁(x) < e ^ (-x)
+        return if ex > HUGE_X_EXPONENT {
+            Some(false)
+        } else {
+            None
+        };
+    };
+    let es = s.exponent();
+    let positive = s.is_positive();
+    let one = Argument::from_rational(&Rational::ONE);
+    let s_vs_1 = if positive {
+        cmp_positive(s, &one)
+    } else {
+        Less
+    };
+    if ex > HUGE_X_EXPONENT && s_vs_1 != Greater {
+        return Some(false);
+    }
+    if positive && es > HUGE_S_EXPONENT && ex < es - 1 {
+        return Some(true);
+    }
+    if ex <= HUGE_X_EXPONENT && es <= HUGE_S_EXPONENT && (ex >= -HUGE_X_EXPONENT || positive) {
+        return None;
+    }
+    let s_f = float_bounds(s);
+    let x_f = float_bounds(x);
+    let ln_x = ln_float_bounds(x);
+    let one = Float::ONE;
+    let s_minus_1 = float_sub_bounds(&s_f, &(one.clone(), one.clone()));
+    let l = float_sub_bounds(&float_mul_bounds(&s_minus_1, &ln_x), &x_f);
+    let lower = if s_vs_1 == Less {
+        let d = float_sub_bounds(&float_add_bounds(&x_f, &(one.clone(), one)), &s_f);
+        let ln_d = d.1.ln_prec_round(64, Ceiling).0;
+        Some(
+            l.0.add_prec_round_ref_ref(&ln_x.0, 64, Floor)
+                .0
+                .sub_prec_round(ln_d, 64, Floor)
+                .0,
+        )
+    } else {
+        Some(l.0)
+    };
+    let upper = if s_vs_1 != Greater {
+        Some(l.1)
+    } else if cmp_positive(s, x) != Greater {
+        Some(l.1.add_prec_round(ln_x.1, 64, Ceiling).0)
+    } else {
+        None
+    };
+    ln_screen(lower.as_ref(), upper.as_ref())
+}
+
+// Returns `Some(true)` if γ(s, x) is known to overflow, `Some(false)` if it is known to underflow,
+// and `None` otherwise, for a positive s and a positive x. This only looks at the arguments closely
+// if s is huge or x is tiny, using these bounds:
+//
+// - γ(s, x) > Γ(s) / 2 for x >= s, since the median of the gamma distribution with shape s is less
+//   than s;
+// - x ^ s e ^ (-x) / s < γ(s, x) < x ^ s e ^ (-x) / (s - x) for x < s.
+fn lower_screen(s: &Argument, x: &Argument) -> Option<bool> {
+    let es = s.exponent();
+    let ex = x.exponent();
+    let x_vs_s = cmp_positive(x, s);
+    if es > HUGE_S_EXPONENT && x_vs_s != Less {
+        return Some(true);
+    }
+    if es <= HUGE_S_EXPONENT && ex >= -HUGE_X_EXPONENT {
+        return None;
+    }
+    let s_f = float_bounds(s);
+    let x_f = float_bounds(x);
+    let ln_x = ln_float_bounds(x);
+    let ln_s = ln_float_bounds(s);
+    let l = float_sub_bounds(
+        &float_sub_bounds(&float_mul_bounds(&s_f, &ln_x), &x_f),
+        &ln_s,
+    );
+    // for x < s / 2, s - x > s / 2
+    let upper = if ex < es - 1 {
+        Some(l.1.add_prec_round(Float::ONE, 64, Ceiling).0)
+    } else {
+        None
+    };
+    ln_screen(Some(&l.0), upper.as_ref())
+}
+
+// Returns the n for which Γ(s, x) / 2 ^ n is roughly between 1 and 2, using the same bounds as
+// `upper_screen`, or, when they are not informative, 0. x is only needed exactly if it is at least
+// 1.
+fn upper_shift(s: &Rational, x: &Argument) -> i64 {
+    let (l_lo, l_hi) = ln_relative_bounds(x, 16);
+    if *s >= 1u32 && cmp_positive(x, &Argument::from_rational(s)) == Less {
+        exp_shift(&ln_abs_gamma_bounds(s, 16).0) - 1
+    } else if x.exponent() > 0 {
+        let x = x.to_rational();
+        let s_minus_1 = s - Rational::ONE;
+        let mut l = if s_minus_1 >= 0u32 {
+            &s_minus_1 * l_lo.clone()
+        } else {
+            &s_minus_1 * l_hi
+        } - &x;
+        if s_minus_1 < 0u32 {
+            l += l_lo - ln_bounds(&(x - s_minus_1), 16).1;
+        }
+        exp_shift(&l)
+    } else if *s < 0u32 {
+        // Γ(s, x) > x ^ s e ^ (-x) / (x + 1 - s) > x ^ s / (e(2 - s))
+        let l = s * l_hi - Rational::ONE - ln_bounds(&(Rational::TWO - s), 16).1;
+        max(exp_shift(&l), 0)
+    } else {
+        0
+    }
+}
+
+// Returns the n for which γ(s, x) / 2 ^ n is roughly between 1 and 2, for s > 0. x is only needed
+// exactly if it is at least 1.
+fn lower_shift(s: &Rational, x: &Argument) -> i64 {
+    if cmp_positive(x, &Argument::from_rational(s)) == Less {
+        // γ(s, x) > x ^ s e ^ (-x) / s
+        let x_hi = if x.exponent() > 0 {
+            x.to_rational()
+        } else {
+            Rational::ONE
+        };
+        let l = s * ln_relative_bounds(x, 16).0 - x_hi - ln_bounds(s, 16).1;
+        exp_shift(&l)
+    } else {
+        exp_shift(&ln_abs_gamma_bounds(s, 16).0) - 1
+    }
+}
+
+// Returns bounds on Γ(s, x) / 2 ^ n, for a rational `s` and a positive x.
+fn upper_scaled_bounds(s: &Rational, x: &Argument, n: i64, w: u64) -> (Rational, Rational) {
+    let integer = is_nonpositive_integer(s);
+    if *s == 0u32 {
+        if let Some(bounds) = tiny_x_e1_scaled_bounds(x, n, w) {
+            return bounds;
+        }
+    } else if let Some((g_lo, g_hi)) = tiny_x_scaled_bounds(s, x, n, w) {
+        if integer {
+            return (g_lo, g_hi);
+        }
+        let (c_lo, c_hi) = gamma_scaled_bounds(s, n, w);
+        return (c_lo - g_hi, c_hi - g_lo);
+    }
+    let x = x.to_rational();
+    if *s > 0u32
+        && x >= *s
+        && let Some(bounds) = asymptotic_scaled_bounds(s, &x, n, w)
+    {
+        return bounds;
+    }
+    if *s < 1u32 && &x - s >= (w >> 1) + 16 {
+        return continued_fraction_scaled_bounds(s, &x, n, w);
+    }
+    if integer {
+        // -s < (w >> 1) + 16 here, since otherwise the continued fraction would have been used
+        return nonpositive_integer_scaled_bounds(u64::exact_from(&-s), &x, n, w);
+    }
+    let (c_lo, c_hi) = gamma_scaled_bounds(s, n, w);
+    let (g_lo, g_hi) = lower_series_scaled_bounds(s, &x, n, w);
+    (c_lo - g_hi, c_hi - g_lo)
+}
+
+// For a positive integer s = k and x < 1, Γ(k, x) = (k - 1)! - γ(k, x), where 0 < γ(k, x) < x ^ k /
+// k < (k - 1)! 2 ^ (ke), e being the exponent of x. If (k - 1)! is exactly representable, or nearly
+// so, this determines the rounding as soon as x is small enough.
+fn upper_near_factorial(
+    s: &Rational,
+    x: &Argument,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let ex = x.exponent();
+    if ex >= 0 || *s <= 0u32 || !s.is_integer() || *s > prec + 65 {
+        return None;
+    }
+    let k = u64::exact_from(s);
+    let err = k.saturating_mul(ex.unsigned_abs());
+    float_round_near_x(
+        &Float::exact_from(Natural::factorial(k - 1)),
+        err,
+        false,
+        prec,
+        rm,
+    )
+}
+
+// Computes Γ(s, x), for s given as an `Argument` (or `None` for s = 0) and a positive x.
+pub(crate) fn upper_incomplete_gamma_helper(
+    s: Option<&Argument>,
+    x: &Argument,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact upper incomplete gamma");
+    match upper_screen(s, x) {
+        Some(true) => return exp_overflow(prec, rm),
+        Some(false) => return exp_underflow(prec, rm),
+        None => {}
+    }
+    let s = s.map_or(Rational::ZERO, Argument::to_rational);
+    if let Some(result) = upper_near_factorial(&s, x, prec, rm) {
+        return result;
+    }
+    if s < 0u32
+        && let Some(result) = tiny_x_round_near_power(&s, x, prec, rm)
+    {
+        return result;
+    }
+    let n = upper_shift(&s, x);
+    let mut extra = 0;
+    round_with_scaled_bounds(n, prec, rm, |w| {
+        with_cancellation(w, &mut extra, |w| upper_scaled_bounds(&s, x, n, w))
+    })
+}
+
+// Returns bounds on γ(s, x) / 2 ^ n, for a positive rational `s` and a positive x, given a lower
+// bound `bits` on -log_2(Γ(s, x) / Γ(s)) for x >= s.
+fn lower_scaled_bounds(
+    s: &Rational,
+    x: &Argument,
+    bits: u64,
+    n: i64,
+    w: u64,
+) -> (Rational, Rational) {
+    if let Some(bounds) = tiny_x_scaled_bounds(s, x, n, w) {
+        return bounds;
+    }
+    if bits > w + 8 {
+        // γ(s, x) = Γ(s) - Γ(s, x), where 0 < Γ(s, x) < Γ(s) 2 ^ (-w - 8)
+        let (c_lo, c_hi) = gamma_scaled_bounds(s, n, w + 1);
+        let c_lo = &c_lo - (&c_lo >> (w + 8));
+        return (c_lo, c_hi);
+    }
+    let x = x.to_rational();
+    if x >= *s
+        && let Some((u_lo, u_hi)) = asymptotic_scaled_bounds(s, &x, n, w)
+    {
+        let (c_lo, c_hi) = gamma_scaled_bounds(s, n, w);
+        return (c_lo - u_hi, c_hi - u_lo);
+    }
+    lower_series_scaled_bounds(s, &x, n, w)
+}
+
+// Returns a lower bound on -log_2(Γ(s, x) / Γ(s)), for s > 0 and x >= max(s, 1), where Γ(s, x) <= x
+// ^ s e ^ (-x); or 0 if x < max(s, 1).
+fn upper_to_gamma_bits(s: &Rational, x: &Argument) -> u64 {
+    let s_arg = Argument::from_rational(s);
+    if x.exponent() <= 0 || cmp_positive(x, &s_arg) == Less {
+        return 0;
+    }
+    let s_f = float_bounds(&s_arg);
+    let l = float_sub_bounds(
+        &float_mul_bounds(&s_f, &ln_float_bounds(x)),
+        &float_bounds(x),
+    );
+    let g = rational_float_bounds(ln_abs_gamma_bounds(s, 64));
+    ln_to_bits(&float_sub_bounds(&l, &g).1)
+}
+
+// Computes γ(s, x), for a positive s and a positive x.
+pub(crate) fn lower_incomplete_gamma_helper(
+    s: &Argument,
+    x: &Argument,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact lower incomplete gamma");
+    match lower_screen(s, x) {
+        Some(true) => return exp_overflow(prec, rm),
+        Some(false) => return exp_underflow(prec, rm),
+        None => {}
+    }
+    let s = s.to_rational();
+    let bits = upper_to_gamma_bits(&s, x);
+    if s.is_integer()
+        && s <= prec + 65
+        && let Some(result) = float_round_near_x(
+            &Float::exact_from(Natural::factorial(u64::exact_from(&s) - 1)),
+            bits,
+            false,
+            prec,
+            rm,
+        )
+    {
+        return result;
+    }
+    if let Some(result) = tiny_x_round_near_power(&s, x, prec, rm) {
+        return result;
+    }
+    let n = lower_shift(&s, x);
+    let mut extra = 0;
+    round_with_scaled_bounds(n, prec, rm, |w| {
+        with_cancellation(w, &mut extra, |w| lower_scaled_bounds(&s, x, bits, n, w))
+    })
+}
+
+fn upper_incomplete_gamma_prec_round_helper(
+    s: &Float,
+    x: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (s, x) {
+        (float_infinity!(), Float(Finite { sign: false, .. })) => (float_infinity!(), Equal),
+        (float_nan!(), _)
+        | (_, Float(NaN | Infinity { sign: false } | Finite { sign: false, .. }))
+        | (float_infinity!(), float_infinity!())
+        | (float_negative_infinity!(), float_either_zero!()) => (float_nan!(), Equal),
+        (_, float_infinity!()) => (float_zero!(), Equal),
+        (float_infinity!(), _) => (float_infinity!(), Equal),
+        (float_negative_infinity!(), _) => {
+            if *x < 1u32 {
+                (float_infinity!(), Equal)
+            } else {
+                (float_zero!(), Equal)
+            }
+        }
+        (Float(Zero { sign }), float_either_zero!()) => (Float(Infinity { sign: *sign }), Equal),
+        (float_either_zero!(), _) => {
+            upper_incomplete_gamma_helper(None, &Argument::from_float(x), prec, rm)
+        }
+        (_, float_either_zero!()) => {
+            if *s < 0u32 && s.is_integer() {
+                (float_nan!(), Equal)
+            } else {
+                gamma_helper(&Argument::from_float(s), prec, rm)
+            }
+        }
+        _ => upper_incomplete_gamma_helper(
+            Some(&Argument::from_float(s)),
+            &Argument::from_float(x),
+            prec,
+            rm,
+        ),
+    }
+}
+
+impl Float {
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. Both [`Float`]s are
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(s,x,p,m) = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\Gamma(s,x)|\rfloor-p+1}$.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\Gamma(s,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(s,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(s,x,p,m)=\text{NaN}$ if $x=-\infty$, or if $x<0$ and $s\neq\infty$
+    /// - $f(\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(s,\infty,p,m)=0.0$ if $s\neq\infty$
+    /// - $f(\infty,x,p,m)=\infty$ if $x$ is finite
+    /// - $f(-\infty,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(-\infty,x,p,m)=\infty$ if $0<x<1$
+    /// - $f(-\infty,x,p,m)=0.0$ if $x\geq 1$
+    /// - $f(\pm0.0,\pm0.0,p,m)=f(\pm0.0,\mp0.0,p,m)=\pm\infty$
+    /// - $f(s,\pm0.0,p,m)=\Gamma(s)$ if $s$ is finite and nonzero; in particular,
+    ///   $f(s,\pm0.0,p,m)=\text{NaN}$ if $s$ is a negative integer
+    ///
+    /// The result overflows when $s$ is very large and $x$ is much smaller than $s$, or when $s$ is
+    /// negative and $x$ is very close to zero; it underflows when $x$ is very large:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::upper_incomplete_gamma_prec`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::upper_incomplete_gamma_round`] instead. If both of these
+    /// things are true, consider using [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round(Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round(Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round(Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round(Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round(Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979313");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round(Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_round(
+        self,
+        x: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        upper_incomplete_gamma_prec_round_helper(&self, &x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. The first [`Float`]
+    /// is taken by value and the second by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_val_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::upper_incomplete_gamma_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_val_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_val_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_val_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_val_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_val_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979313");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_val_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_round_val_ref(
+        self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        upper_incomplete_gamma_prec_round_helper(&self, x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. The first [`Float`]
+    /// is taken by reference and the second by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_ref_val`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::upper_incomplete_gamma_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_val(Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_val(Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_val(Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_val(Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_val(Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979313");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_val(Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_round_ref_val(
+        &self,
+        x: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        upper_incomplete_gamma_prec_round_helper(self, &x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the specified precision and with the specified rounding mode. Both [`Float`]s are
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the exact result. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_ref_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::upper_incomplete_gamma_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979313");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_prec_round_ref_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_round_ref_ref(
+        &self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        upper_incomplete_gamma_prec_round_helper(self, x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. Both [`Float`]s are taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded result is less than, equal
+    /// to, or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round`] instead. If you know that your target precision
+    /// is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec(Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec(Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec(self, x: Self, prec: u64) -> (Self, Ordering) {
+        self.upper_incomplete_gamma_prec_round(x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. The first [`Float`] is taken by
+    /// value and the second by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round_val_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_val_ref(self, x: &Self, prec: u64) -> (Self, Ordering) {
+        self.upper_incomplete_gamma_prec_round_val_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. The first [`Float`] is taken by
+    /// reference and the second by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round_ref_val`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_ref_val(&self, x: Self, prec: u64) -> (Self, Ordering) {
+        self.upper_incomplete_gamma_prec_round_ref_val(x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the nearest value of the specified precision. Both [`Float`]s are taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round_ref_ref`] instead. If you know that your target
+    /// precision is the maximum of the precisions of the two inputs, consider using
+    /// [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(gamma.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(gamma.to_string(), "1.1979294");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_ref_ref(&self, x: &Self, prec: u64) -> (Self, Ordering) {
+        self.upper_incomplete_gamma_prec_round_ref_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_round(Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330825");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_round(Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::from(PI).upper_incomplete_gamma_round(Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_round(self, x: Self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round(x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// The first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round_val_ref`] instead. If you know you'll be using
+    /// the `Nearest` rounding mode, consider using [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_val_ref(&Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330825");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_val_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_val_ref(&Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_round_val_ref(
+        self,
+        x: &Self,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_val_ref(x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// The first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round_ref_val`] instead. If you know you'll be using
+    /// the `Nearest` rounding mode, consider using [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_ref_val(Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330825");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_ref_val(Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_ref_val(Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_round_ref_val(
+        &self,
+        x: Self,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_ref_val(x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, rounding the
+    /// result to the maximum of the precisions of the inputs and with the specified rounding mode.
+    /// Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round_ref_ref`] instead. If you know you'll be using
+    /// the `Nearest` rounding mode, consider using [`Float::upper_incomplete_gamma`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_ref_ref(&Float::from(E), Floor);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330825");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_ref_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) =
+    ///     Float::from(PI).upper_incomplete_gamma_round_ref_ref(&Float::from(E), Nearest);
+    /// assert_eq!(gamma.to_string(), "1.1979302081330827");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_round_ref_ref(
+        &self,
+        x: &Self,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_ref_ref(x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the specified precision and with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded result is less than, equal to, or
+    /// greater than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.upper_incomplete_gamma_prec_round_assign(Float::from(E), 20, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1979294");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.upper_incomplete_gamma_prec_round_assign(Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.1979313");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.upper_incomplete_gamma_prec_round_assign(Float::from(E), 20, Nearest),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1979294");
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_round_assign(
+        &mut self,
+        x: Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.upper_incomplete_gamma_prec_round_ref_val(x, prec, rm);
+        o
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the specified precision and with the
+    /// specified rounding mode. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded result is less than, equal to, or
+    /// greater than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.upper_incomplete_gamma_prec_round_assign_ref(&Float::from(E), 20, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1979294");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.upper_incomplete_gamma_prec_round_assign_ref(&Float::from(E), 20, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.1979313");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.upper_incomplete_gamma_prec_round_assign_ref(&Float::from(E), 20, Nearest),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1979294");
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_round_assign_ref(
+        &mut self,
+        x: &Self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.upper_incomplete_gamma_prec_round_ref_ref(x, prec, rm);
+        o
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the nearest value of the specified precision.
+    /// The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_prec_assign(Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_prec_assign(Float::from(E), 20), Less);
+    /// assert_eq!(x.to_string(), "1.1979294");
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_assign(&mut self, x: Self, prec: u64) -> Ordering {
+        self.upper_incomplete_gamma_prec_round_assign(x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the nearest value of the specified precision.
+    /// The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_prec_assign_ref(&Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_prec_assign_ref(&Float::from(E), 20), Less);
+    /// assert_eq!(x.to_string(), "1.1979294");
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_prec_assign_ref(&mut self, x: &Self, prec: u64) -> Ordering {
+        self.upper_incomplete_gamma_prec_round_assign_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the maximum of the precisions of the inputs
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// value. An [`Ordering`] is returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_round_assign(Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "1.1979302081330825");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_round_assign(Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.1979302081330827");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_round_assign(Float::from(E), Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.1979302081330827");
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_round_assign(&mut self, x: Self, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_assign(x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and rounding the result to the maximum of the precisions of the inputs
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_round_assign_ref(&Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "1.1979302081330825");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_round_assign_ref(&Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.1979302081330827");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.upper_incomplete_gamma_round_assign_ref(&Float::from(E), Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.1979302081330827");
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_round_assign_ref(
+        &mut self,
+        x: &Self,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_assign_ref(x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. Both [`Rational`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(s,x,p,m) = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, and $m$ is neither `Nearest` nor
+    ///   `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2 |\Gamma(s,x)|\rfloor-p+1}$.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\Gamma(s,x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(s,x,p,m)=\text{NaN}$ if $x<0$
+    /// - $f(0,0,p,m)=\infty$
+    /// - $f(s,0,p,m)=\Gamma(s)$ if $s\neq 0$; in particular, $f(s,0,p,m)=\text{NaN}$ if $s$ is a
+    ///   negative integer
+    ///
+    /// The result overflows when $s$ is very large and $x$ is much smaller than $s$, or when $s$ is
+    /// negative and $x$ is very close to zero; it underflows when $x$ is very large:
+    ///
+    /// Overflow and underflow:
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(s,x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(s,x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(s,x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$
+    ///   is returned instead.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0352");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860466");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_rational_prec_round(
+        s: Rational,
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. The first [`Rational`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0352");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860466");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_rational_prec_round_val_ref(
+        s: Rational,
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. The first [`Rational`] is taken by reference and the second by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0352");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860466");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_rational_prec_round_ref_val(
+        s: &Rational,
+        x: Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        Self::upper_incomplete_gamma_rational_prec_round_ref_ref(s, &x, prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the specified precision and with the specified rounding mode, and returning
+    /// the result as a [`Float`]. Both [`Rational`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0352");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Floor,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860466");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn upper_incomplete_gamma_rational_prec_round_ref_ref(
+        s: &Rational,
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x < 0u32 {
+            return (Self::NAN, Equal);
+        }
+        if *x == 0u32 {
+            return if *s == 0u32 {
+                (Self::INFINITY, Equal)
+            } else if *s < 0u32 && s.is_integer() {
+                (Self::NAN, Equal)
+            } else {
+                gamma_helper(&Argument::from_rational(s), prec, rm)
+            };
+        }
+        let s = (*s != 0u32).then(|| Argument::from_rational(s));
+        upper_incomplete_gamma_helper(s.as_ref(), &Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. Both [`Rational`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_rational_prec(
+        s: Rational,
+        x: Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. The first [`Rational`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_val_ref(
+    ///     Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_rational_prec_val_ref(
+        s: Rational,
+        x: &Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. The first [`Rational`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_ref_val(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn upper_incomplete_gamma_rational_prec_ref_val(
+        s: &Rational,
+        x: Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::upper_incomplete_gamma_rational_prec_round_ref_ref(s, &x, prec, Nearest)
+    }
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, rounding
+    /// the result to the nearest value of the specified precision, and returning the result as a
+    /// [`Float`]. Both [`Rational`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_rational_prec_round`] documentation for information
+    /// on special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_rational_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(s.significant_bits(), x.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     5,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.0371");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_ref_ref(
+    ///     &Rational::from_unsigneds(1u8, 3),
+    ///     &Rational::from_unsigneds(5u8, 2),
+    ///     20,
+    /// );
+    /// assert_eq!(gamma.to_string(), "0.036860526");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn upper_incomplete_gamma_rational_prec_ref_ref(
+        s: &Rational,
+        x: &Rational,
+        prec: u64,
+    ) -> (Self, Ordering) {
+        Self::upper_incomplete_gamma_rational_prec_round_ref_ref(s, x, prec, Nearest)
+    }
+}
+
+impl UpperIncompleteGamma<Self> for Float {
+    type Output = Self;
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, taking both
+    /// by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $x=-\infty$, or if $x<0$ and $s\neq\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\infty)=0.0$ if $s\neq\infty$
+    /// - $f(\infty,x)=\infty$ if $x$ is finite
+    /// - $f(-\infty,\pm0.0)=\text{NaN}$
+    /// - $f(-\infty,x)=\infty$ if $0<x<1$
+    /// - $f(-\infty,x)=0.0$ if $x\geq 1$
+    /// - $f(\pm0.0,\pm0.0)=f(\pm0.0,\mp0.0)=\pm\infty$
+    /// - $f(s,\pm0.0)=\Gamma(s)$ if $s$ is finite and nonzero; in particular,
+    ///   $f(s,\pm0.0)=\text{NaN}$ if $s$ is a negative integer
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec`] instead. If you want to specify the output precision,
+    /// consider using [`Float::upper_incomplete_gamma_round`]. If you want both of these things,
+    /// consider using [`Float::upper_incomplete_gamma_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::UpperIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, One};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(PI)
+    ///         .upper_incomplete_gamma(Float::from(E))
+    ///         .to_string(),
+    ///     "1.1979302081330827"
+    /// );
+    /// assert!(Float::NAN.upper_incomplete_gamma(Float::from(E)).is_nan());
+    /// assert!(
+    ///     Float::ONE
+    ///         .upper_incomplete_gamma(Float::NEGATIVE_ONE)
+    ///         .is_nan()
+    /// );
+    /// ```
+    #[inline]
+    fn upper_incomplete_gamma(self, x: Self) -> Self {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round(x, prec, Nearest).0
+    }
+}
+
+impl UpperIncompleteGamma<&Self> for Float {
+    type Output = Self;
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, taking the
+    /// first by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $x=-\infty$, or if $x<0$ and $s\neq\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\infty)=0.0$ if $s\neq\infty$
+    /// - $f(\infty,x)=\infty$ if $x$ is finite
+    /// - $f(-\infty,\pm0.0)=\text{NaN}$
+    /// - $f(-\infty,x)=\infty$ if $0<x<1$
+    /// - $f(-\infty,x)=0.0$ if $x\geq 1$
+    /// - $f(\pm0.0,\pm0.0)=f(\pm0.0,\mp0.0)=\pm\infty$
+    /// - $f(s,\pm0.0)=\Gamma(s)$ if $s$ is finite and nonzero; in particular,
+    ///   $f(s,\pm0.0)=\text{NaN}$ if $s$ is a negative integer
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_val_ref`] instead. If you want to specify the output
+    /// precision, consider using [`Float::upper_incomplete_gamma_round_val_ref`]. If you want both
+    /// of these things, consider using [`Float::upper_incomplete_gamma_prec_round_val_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::UpperIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, One};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(PI)
+    ///         .upper_incomplete_gamma(&Float::from(E))
+    ///         .to_string(),
+    ///     "1.1979302081330827"
+    /// );
+    /// assert!(Float::NAN.upper_incomplete_gamma(&Float::from(E)).is_nan());
+    /// assert!(
+    ///     Float::ONE
+    ///         .upper_incomplete_gamma(&Float::NEGATIVE_ONE)
+    ///         .is_nan()
+    /// );
+    /// ```
+    #[inline]
+    fn upper_incomplete_gamma(self, x: &Self) -> Self {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_val_ref(x, prec, Nearest)
+            .0
+    }
+}
+
+impl UpperIncompleteGamma<Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, taking the
+    /// first by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $x=-\infty$, or if $x<0$ and $s\neq\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\infty)=0.0$ if $s\neq\infty$
+    /// - $f(\infty,x)=\infty$ if $x$ is finite
+    /// - $f(-\infty,\pm0.0)=\text{NaN}$
+    /// - $f(-\infty,x)=\infty$ if $0<x<1$
+    /// - $f(-\infty,x)=0.0$ if $x\geq 1$
+    /// - $f(\pm0.0,\pm0.0)=f(\pm0.0,\mp0.0)=\pm\infty$
+    /// - $f(s,\pm0.0)=\Gamma(s)$ if $s$ is finite and nonzero; in particular,
+    ///   $f(s,\pm0.0)=\text{NaN}$ if $s$ is a negative integer
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_ref_val`] instead. If you want to specify the output
+    /// precision, consider using [`Float::upper_incomplete_gamma_round_ref_val`]. If you want both
+    /// of these things, consider using [`Float::upper_incomplete_gamma_prec_round_ref_val`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::UpperIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, One};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(PI))
+    ///         .upper_incomplete_gamma(Float::from(E))
+    ///         .to_string(),
+    ///     "1.1979302081330827"
+    /// );
+    /// assert!(
+    ///     (&Float::NAN)
+    ///         .upper_incomplete_gamma(Float::from(E))
+    ///         .is_nan()
+    /// );
+    /// assert!(
+    ///     (&Float::ONE)
+    ///         .upper_incomplete_gamma(Float::NEGATIVE_ONE)
+    ///         .is_nan()
+    /// );
+    /// ```
+    #[inline]
+    fn upper_incomplete_gamma(self, x: Float) -> Float {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_ref_val(x, prec, Nearest)
+            .0
+    }
+}
+
+impl UpperIncompleteGamma<&Float> for &Float {
+    type Output = Float;
+
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, taking both
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(s,x) = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+    /// - $f(s,x)=\text{NaN}$ if $x=-\infty$, or if $x<0$ and $s\neq\infty$
+    /// - $f(\infty,\infty)=\text{NaN}$
+    /// - $f(s,\infty)=0.0$ if $s\neq\infty$
+    /// - $f(\infty,x)=\infty$ if $x$ is finite
+    /// - $f(-\infty,\pm0.0)=\text{NaN}$
+    /// - $f(-\infty,x)=\infty$ if $0<x<1$
+    /// - $f(-\infty,x)=0.0$ if $x\geq 1$
+    /// - $f(\pm0.0,\pm0.0)=f(\pm0.0,\mp0.0)=\pm\infty$
+    /// - $f(s,\pm0.0)=\Gamma(s)$ if $s$ is finite and nonzero; in particular,
+    ///   $f(s,\pm0.0)=\text{NaN}$ if $s$ is a negative integer
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_ref_ref`] instead. If you want to specify the output
+    /// precision, consider using [`Float::upper_incomplete_gamma_round_ref_ref`]. If you want both
+    /// of these things, consider using [`Float::upper_incomplete_gamma_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::UpperIncompleteGamma;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeOne, One};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(PI))
+    ///         .upper_incomplete_gamma(&Float::from(E))
+    ///         .to_string(),
+    ///     "1.1979302081330827"
+    /// );
+    /// assert!(
+    ///     (&Float::NAN)
+    ///         .upper_incomplete_gamma(&Float::from(E))
+    ///         .is_nan()
+    /// );
+    /// assert!(
+    ///     (&Float::ONE)
+    ///         .upper_incomplete_gamma(&Float::NEGATIVE_ONE)
+    ///         .is_nan()
+    /// );
+    /// ```
+    #[inline]
+    fn upper_incomplete_gamma(self, x: &Float) -> Float {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_ref_ref(x, prec, Nearest)
+            .0
+    }
+}
+
+impl UpperIncompleteGammaAssign<Self> for Float {
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and taking the [`Float`] on the right-hand side by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_assign`] instead. If you want to specify the output
+    /// precision, consider using [`Float::upper_incomplete_gamma_round_assign`]. If you want both
+    /// of these things, consider using [`Float::upper_incomplete_gamma_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::UpperIncompleteGammaAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut s = Float::from(PI);
+    /// s.upper_incomplete_gamma_assign(Float::from(E));
+    /// assert_eq!(s.to_string(), "1.1979302081330827");
+    /// ```
+    #[inline]
+    fn upper_incomplete_gamma_assign(&mut self, x: Self) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_assign(x, prec, Nearest);
+    }
+}
+
+impl UpperIncompleteGammaAssign<&Self> for Float {
+    /// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Float`]s, mutating the
+    /// first one in place, and taking the [`Float`] on the right-hand side by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \Gamma(s,x)+\varepsilon
+    /// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+    /// $$
+    /// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::upper_incomplete_gamma_prec_round`] documentation for information on
+    /// special cases, overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::upper_incomplete_gamma_prec_assign_ref`] instead. If you want to specify the output
+    /// precision, consider using [`Float::upper_incomplete_gamma_round_assign_ref`]. If you want
+    /// both of these things, consider using
+    /// [`Float::upper_incomplete_gamma_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::UpperIncompleteGammaAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut s = Float::from(PI);
+    /// s.upper_incomplete_gamma_assign(&Float::from(E));
+    /// assert_eq!(s.to_string(), "1.1979302081330827");
+    /// ```
+    #[inline]
+    fn upper_incomplete_gamma_assign(&mut self, x: &Self) {
+        let prec = max(self.significant_bits(), x.significant_bits());
+        self.upper_incomplete_gamma_prec_round_assign_ref(x, prec, Nearest);
+    }
+}
+
+/// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two primitive floats, returning a
+/// correctly-rounded result.
+///
+/// $$
+/// f(s,x) = \Gamma(s,x)+\varepsilon
+/// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+/// $$
+/// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN},x)=f(s,\text{NaN})=\text{NaN}$
+/// - $f(s,x)=\text{NaN}$ if $x=-\infty$, or if $x<0$ and $s\neq\infty$
+/// - $f(\infty,\infty)=\text{NaN}$
+/// - $f(s,\infty)=0.0$ if $s\neq\infty$
+/// - $f(\infty,x)=\infty$ if $x$ is finite
+/// - $f(-\infty,\pm0.0)=\text{NaN}$
+/// - $f(-\infty,x)=\infty$ if $0<x<1$
+/// - $f(-\infty,x)=0.0$ if $x\geq 1$
+/// - $f(\pm0.0,\pm0.0)=f(\pm0.0,\mp0.0)=\pm\infty$
+/// - $f(s,\pm0.0)=\Gamma(s)$ if $s$ is finite and nonzero; in particular, $f(s,\pm0.0)=\text{NaN}$
+///   if $s$ is a negative integer
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::{E, PI};
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::upper_incomplete_gamma::primitive_float_upper_incomplete_gamma;
+///
+/// assert!(primitive_float_upper_incomplete_gamma(f64::NAN, 1.0).is_nan());
+/// assert!(primitive_float_upper_incomplete_gamma(1.0f64, -1.0).is_nan());
+/// assert_eq!(
+///     NiceFloat(primitive_float_upper_incomplete_gamma(PI, E)),
+///     NiceFloat(1.1979302081330827)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_upper_incomplete_gamma(0.5f32, 0.5f32)),
+///     NiceFloat(0.5624182)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_upper_incomplete_gamma(-0.5f64, 0.25f64)),
+///     NiceFloat(1.4154194561257571)
+/// );
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+#[inline]
+pub fn primitive_float_upper_incomplete_gamma<T: PrimitiveFloat>(s: T, x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_float_to_float_fn(Float::upper_incomplete_gamma_prec, s, x)
+}
+
+/// Computes $\Gamma(s,x)$, the upper incomplete gamma function of two [`Rational`]s, returning the
+/// result as a primitive float.
+///
+/// $$
+/// f(s,x) = \Gamma(s,x)+\varepsilon
+/// =\int_x^\infty t^{s-1}e^{-t}\,dt+\varepsilon.
+/// $$
+/// - If $\Gamma(s,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\Gamma(s,x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\Gamma(s,x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(s,x)=\text{NaN}$ if $x<0$
+/// - $f(0,0)=\infty$
+/// - $f(s,0)=\Gamma(s)$ if $s\neq 0$; in particular, $f(s,0)=\text{NaN}$ if $s$ is a negative
+///   integer
+///
+/// # Worst-case complexity
+/// $T(m) = O(m)$
+///
+/// $M(m) = O(m)$
+///
+/// where $T$ is time, $M$ is additional memory, and $m$ is `max(s.significant_bits(),
+/// x.significant_bits())`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::upper_incomplete_gamma::primitive_float_upper_incomplete_gamma_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     NiceFloat(primitive_float_upper_incomplete_gamma_rational::<f64>(
+///         &Rational::from_unsigneds(1u8, 3),
+///         &Rational::from_unsigneds(5u8, 2)
+///     )),
+///     NiceFloat(0.03686052091852648)
+/// );
+/// assert_eq!(
+///     NiceFloat(primitive_float_upper_incomplete_gamma_rational::<f64>(
+///         &Rational::from_signeds(-7, 2),
+///         &Rational::from(3)
+///     )),
+///     NiceFloat(0.0001529088984213892)
+/// );
+/// ```
+#[allow(clippy::type_repetition_in_bounds)]
+#[inline]
+pub fn primitive_float_upper_incomplete_gamma_rational<T: PrimitiveFloat>(
+    s: &Rational,
+    x: &Rational,
+) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_rational_to_float_fn(Float::upper_incomplete_gamma_rational_prec_ref_ref, s, x)
+}
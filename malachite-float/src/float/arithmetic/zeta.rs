@@ -0,0 +1,1377 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The Riemann zeta function is computed from an exact rational argument s. For s >= 0, the
+// Euler–Maclaurin formula
+//
+// ζ(s) = Σ_{k < N} k ^ (-s) + N ^ (-s) * (N / (s - 1) + 1/2 + Σ_{j = 1}^M c_j N ^ (1 - 2j)) + R,
+//
+// where c_j = B_(2j) / (2j)! * s(s + 1)...(s + 2j - 2) and B_(2j) is a Bernoulli number, is used;
+// for real s, |R| is at most the absolute value of the first omitted term. For s < 0, the
+// functional equation ζ(s) = 2 ^ s π ^ (s - 1) sin(πs / 2) Γ(1 - s) ζ(1 - s) reduces the
+// computation to a positive argument. ζ(s) is then e raised to the power log |ζ(s)|, with a power
+// of 2 split off so that intermediate values stay small even when the result is far outside the
+// range of a `Float`. Every step produces rational bounds, and the result is rounded using Ziv's
+// strategy.
+//
+// Near 0, where ζ(s) ~ -1/2 - s log(2π) / 2, near the pole at 1, where ζ(s) ~ 1 / (s - 1) + γ, and
+// for large s, where ζ(s) ~ 1 + 2 ^ (-s), simpler bounds are used.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::float::arithmetic::exp::exp_overflow;
+use crate::float::arithmetic::gamma::{
+    Argument, abs_sin_pi_bounds, constant_bounds, exp_scaled_bounds, exp_shift,
+    ln_abs_gamma_bounds, ln_bounds, ln_bounds_absolute, signed_extreme, tangent_numbers,
+};
+use crate::float::arithmetic::round_near_x::float_round_near_x;
+use crate::float::arithmetic::sin_cos::{rational_exponent, round_with_bounds};
+use crate::float::arithmetic::sinh_cosh::round_with_scaled_bounds;
+use crate::{Float, emulate_float_to_float_fn, emulate_rational_to_float_fn};
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    Abs, IsPowerOf2, Parity, Pow, PowerOf2, Reciprocal, Square, Zeta, ZetaAssign,
+};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, One, OneHalf, Two, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Arguments with an exponent greater than this, that is, arguments at least 2 ^ 26 in absolute
+// value, are huge: for s <= -2 ^ 26, log_2 |ζ(s)| is greater than 1.4 * 10 ^ 9 unless s is very
+// close to an even integer, so ζ overflows.
+const HUGE_EXPONENT: i64 = 26;
+
+// Returns whether x is an even integer. x = m * 2 ^ e is one if and only if m = a / 2 ^ k with k <
+// e.
+fn is_even_integer(x: &Argument) -> bool {
+    let d = x.mantissa().denominator_ref();
+    x.exponent() > 0 && d.is_power_of_2() && d.significant_bits() <= u64::exact_from(x.exponent())
+}
+
+// Returns bounds on k ^ (-s), for an integer k >= 2 and a positive rational s, with a relative
+// width of about `2 ^ (-w)`.
+fn power_bounds(k: u64, s: &Rational, w: u64) -> (Rational, Rational) {
+    if s.is_integer() {
+        let p = Natural::from(k).pow(u64::exact_from(s));
+        return constant_bounds(Float::from_rational_prec_round(
+            Rational::from_naturals(Natural::ONE, p),
+            w,
+            Floor,
+        ));
+    }
+    // |s log(k)| < 2 ^ b
+    let b = Natural::rounding_from(s, Ceiling).0.significant_bits()
+        + k.significant_bits().significant_bits();
+    let (l_lo, l_hi) = ln_bounds(&Rational::from(k), w + 4 + b);
+    let lo = Float::exp_rational_prec_round(-s * l_hi, w + 4, Floor).0;
+    let hi = Float::exp_rational_prec_round(-s * l_lo, w + 4, Ceiling).0;
+    (Rational::exact_from(lo), Rational::exact_from(hi))
+}
+
+// Returns bounds on ζ(s), for a rational s >= 0 with s != 1, using the Euler–Maclaurin formula,
+// with an absolute width of about `2 ^ (-w)`. There are M = w / 4 + 2 correction terms, and N >=
+// 2(s + 2M + 2) / 3, so that the ratio of consecutive correction terms, which is about ((s + 2j) /
+// (2πN)) ^ 2, is less than 1/16 and the remainder is less than 2 ^ (-w - 8).
+//
+// The Bernoulli numbers are obtained from the tangent numbers T_j, since |B_(2j)| = 2jT_j / (4 ^
+// j(4 ^ j - 1)) and the signs of the B_(2j) alternate, starting with B_2 = 1/6 > 0.
+fn euler_maclaurin_bounds(s: &Rational, w: u64) -> (Rational, Rational) {
+    let m = w / 4 + 2;
+    let n = u64::exact_from(
+        &Natural::rounding_from(
+            &((s + Rational::from((m << 1) + 2)) * Rational::from_unsigneds(2u8, 3)),
+            Ceiling,
+        )
+        .0,
+    ) + 1;
+    let wp = w + 8 + n.significant_bits();
+    let mut sum_lo = Rational::ONE;
+    let mut sum_hi = Rational::ONE;
+    for k in 2..n {
+        let (p_lo, p_hi) = power_bounds(k, s, wp);
+        sum_lo += p_lo;
+        sum_hi += p_hi;
+    }
+    let (p_lo, p_hi) = power_bounds(n, s, wp);
+    let n = Rational::from(n);
+    let n_squared = (&n).square();
+    // e = N / (s - 1) + 1/2 + Σ_{j = 1}^M c_j N ^ (1 - 2j)
+    let mut e = &n / (s - Rational::ONE) + Rational::ONE_HALF;
+    // r = s(s + 1)...(s + 2j - 2) / ((2j)! N ^ (2j - 1))
+    let mut r = s / (n << 1u32);
+    let mut four_j = Natural::ONE;
+    let mut error = Rational::ZERO;
+    for (j, t) in (1u64..).zip(tangent_numbers(usize::exact_from(m) + 1)) {
+        four_j <<= 2;
+        let b = Rational::from_naturals(
+            Natural::from(j << 1) * t,
+            &four_j * (&four_j - Natural::ONE),
+        );
+        let term = b * &r;
+        if j > m {
+            error = term;
+            break;
+        }
+        if j.odd() {
+            e += term;
+        } else {
+            e -= term;
+        }
+        let i = Rational::from(j << 1);
+        r *= (s + &i - Rational::ONE) * (s + &i);
+        r /= (&i + Rational::ONE) * (i + Rational::TWO) * &n_squared;
+    }
+    // N ^ (-s) > 0, so the product's bounds depend on the signs of e's bounds
+    let e_lo = &e - &error;
+    let e_hi = e + error;
+    let lo = if e_lo >= 0u32 {
+        &p_lo * e_lo
+    } else {
+        &p_hi * e_lo
+    };
+    let hi = if e_hi >= 0u32 {
+        p_hi * e_hi
+    } else {
+        p_lo * e_hi
+    };
+    (sum_lo + lo, sum_hi + hi)
+}
+
+// Returns bounds on ζ(s), for a positive rational s != 1, with an absolute width of about
+// `2 ^ (-w)`.
+fn zeta_positive_bounds(s: &Rational, w: u64) -> (Rational, Rational) {
+    // For s >= 3, 1 < ζ(s) < 1 + 2 ^ (1 - s)
+    if *s >= 3u32 && *s >= w + 4 {
+        return (
+            Rational::ONE,
+            Rational::ONE + Rational::power_of_2(-i64::exact_from(w + 3)),
+        );
+    }
+    let t = s - Rational::ONE;
+    if rational_exponent(&t) < -i64::exact_from(w + 4) {
+        // Near the pole, |ζ(s) - 1 / (s - 1) - γ| < |s - 1|
+        let (g_lo, g_hi) = constant_bounds(Float::euler_gamma_prec_round(w + 4, Floor));
+        let r = (&t).reciprocal();
+        let t = t.abs();
+        return (&r + g_lo - &t, r + g_hi + t);
+    }
+    if rational_exponent(s) < -i64::exact_from((w >> 1) + 2) {
+        // Near 0, |ζ(s) + 1/2 + s log(2π) / 2| < 2s ^ 2
+        let (pi_lo, pi_hi) = constant_bounds(Float::pi_prec_round(w + 4, Floor));
+        let c_lo = ln_bounds(&(pi_lo << 1u32), w + 4).0 >> 1u32;
+        let c_hi = ln_bounds(&(pi_hi << 1u32), w + 4).1 >> 1u32;
+        let d = s.square() << 1u32;
+        return (
+            -Rational::ONE_HALF - s * c_hi - &d,
+            -Rational::ONE_HALF - s * c_lo + d,
+        );
+    }
+    euler_maclaurin_bounds(s, w)
+}
+
+// Returns bounds on log |ζ(s)|, for a negative rational s that is not an even integer, with an
+// absolute width of about `2 ^ (-w)`. By the functional equation, this is s log(2) + (s - 1)log(π)
+// + log |sin(πs / 2)| + log Γ(1 - s) + log ζ(1 - s).
+fn ln_abs_zeta_negative_bounds(s: &Rational, w: u64) -> (Rational, Rational) {
+    let t = Rational::ONE - s;
+    // |s| < t < 2 ^ k
+    let k = u64::exact_from(rational_exponent(&t));
+    let wp = w + 8 + k;
+    let (ln_2_lo, ln_2_hi) = constant_bounds(Float::ln_2_prec_round(wp, Floor));
+    let (pi_lo, pi_hi) = constant_bounds(Float::pi_prec_round(wp, Floor));
+    let ln_pi_lo = ln_bounds(&pi_lo, wp).0;
+    let ln_pi_hi = ln_bounds(&pi_hi, wp).1;
+    let (sin_lo, sin_hi) = abs_sin_pi_bounds(&(s >> 1u32), w + 4);
+    let ln_sin_lo = ln_bounds_absolute(&sin_lo, w + 4).0;
+    let ln_sin_hi = ln_bounds_absolute(&sin_hi, w + 4).1;
+    let (g_lo, g_hi) = ln_abs_gamma_bounds(&t, w + 4);
+    let (z_lo, z_hi) = if t >= w + 20 {
+        // 0 < log ζ(t) < ζ(t) - 1 < 2 ^ (1 - t)
+        (
+            Rational::ZERO,
+            Rational::power_of_2(-i64::exact_from(w + 16)),
+        )
+    } else {
+        let (z_lo, z_hi) = zeta_positive_bounds(&t, w + 4);
+        (ln_bounds(&z_lo, w + 4).0, ln_bounds(&z_hi, w + 4).1)
+    };
+    (
+        s * ln_2_hi - &t * ln_pi_hi + ln_sin_lo + g_lo + z_lo,
+        s * ln_2_lo - t * ln_pi_lo + ln_sin_hi + g_hi + z_hi,
+    )
+}
+
+// Computes ζ(x), for x != 0.
+pub(crate) fn zeta_helper(x: &Argument, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let positive = x.is_positive();
+    if !positive && is_even_integer(x) {
+        return (Float::ZERO, Equal);
+    }
+    if x.exponent() == 1 && *x.mantissa() == Rational::ONE_HALF {
+        return (Float::INFINITY, Equal);
+    }
+    assert_ne!(rm, Exact, "Inexact zeta");
+    let exp_x = x.exponent();
+    // Near 0, ζ(x) = -1/2 - x log(2π) / 2 + O(x ^ 2), so |ζ(x) + 1/2| < |x| < 2 ^ e, and ζ(x) <
+    // -1/2 iff x > 0
+    if exp_x < -i64::exact_from(prec) - 4
+        && let Some(result) = float_round_near_x(
+            &-Float::ONE_HALF,
+            u64::exact_from(-exp_x),
+            positive,
+            prec,
+            rm,
+        )
+    {
+        return result;
+    }
+    if positive {
+        // For x >= 4, ζ(x) = 1 + g, with 0 < g < 2 ^ (1 - floor(x)). If floor(x) > prec + 64, the
+        // weaker bound 2 ^ (-prec - 63) is used instead.
+        if exp_x > 2 {
+            let err = if exp_x > 62 {
+                prec + 64
+            } else {
+                u64::rounding_from(&x.to_rational(), Floor).0.min(prec + 64)
+            };
+            if let Some(result) = float_round_near_x(&Float::ONE, err, true, prec, rm) {
+                return result;
+            }
+        }
+        let x = x.to_rational();
+        return round_with_bounds(prec, rm, |w| Some(zeta_positive_bounds(&x, w)));
+    }
+    // x is not an even integer, so x has no more significant bits than the input
+    let x = x.to_rational();
+    // sin(πx / 2) > 0 iff floor(x / 2) is even
+    let positive = Integer::rounding_from(&(&x >> 1u32), Floor).0.even();
+    // |ζ(x)| = 2(2π) ^ (x - 1)|sin(πx / 2)|Γ(1 - x)ζ(1 - x), where |sin(πx / 2)| >= 2 / d, d being
+    // the denominator of x, and Γ(1 - x) / (2π) ^ (1 - x) > 2 ^ (1.4 * 10 ^ 9). Unless d is
+    // enormous, this is far above the largest finite `Float`.
+    if exp_x > HUGE_EXPONENT && x.denominator_ref().significant_bits() <= 1 << 28 {
+        return signed_extreme(exp_overflow, positive, prec, rm);
+    }
+    let n = exp_shift(&ln_abs_zeta_negative_bounds(&x, 16).0);
+    round_with_scaled_bounds(n, prec, rm, |w| {
+        exp_scaled_bounds(ln_abs_zeta_negative_bounds(&x, w + 4), n, positive, w)
+    })
+}
+
+impl Float {
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded zeta function is less
+    /// than, equal to, or greater than the exact zeta function. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \zeta(x)+\varepsilon.
+    /// $$
+    /// - If $\zeta(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\zeta(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\zeta(x)|\rfloor-p+1}$.
+    /// - If $\zeta(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\zeta(x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=1.0$
+    /// - $f(0.0,p,m)=f(-0.0,p,m)=-0.5$
+    /// - $f(1.0,p,m)=\infty$
+    /// - $f(x,p,m)=0.0$ if $x$ is a negative even integer
+    ///
+    /// The result overflows when $x$ is less than about $-5.0\times 10^7$, unless $x$ is extremely
+    /// close to an even integer, or when $x$ is extremely close to 1. It underflows only when $x$
+    /// is extremely close to a negative even integer.
+    ///
+    /// Overflow and underflow:
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, $\infty$ is returned instead.
+    /// - If $f(x,p,m)\geq 2^{2^{30}-1}$ and $m$ is `Floor`, `Down`, or `Odd`,
+    ///   $(1-(1/2)^p)2^{2^{30}-1}$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Floor` or `Down`, $0.0$ is returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Ceiling`, `Up`, or `Odd`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)\leq2^{-2^{30}-1}$ and $m$ is `Nearest` or `NearestTiesAway`, $0.0$ is
+    ///   returned instead.
+    /// - If $0<f(x,p,m)<2^{-2^{30}-1}$ and $m$ is `NearestTiesAway`, $0.0$ is returned instead.
+    /// - If $2^{-2^{30}-1}<f(x,p,m)<2^{-2^{30}}$ and $m$ is `Nearest` or `NearestTiesAway`,
+    ///   $2^{-2^{30}}$ is returned instead.
+    /// - If $2^{-2^{30}-1}\leq f(x,p,m)<2^{-2^{30}}$ and $m$ is `NearestTiesAway`, $2^{-2^{30}}$ is
+    ///   returned instead.
+    /// - Negative results are handled symmetrically.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::zeta_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::zeta_round`] instead. If both of these things are true, consider using
+    /// [`Float::zeta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than zero, one, and
+    /// negative even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round(5, Floor);
+    /// assert_eq!(zeta.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round(5, Ceiling);
+    /// assert_eq!(zeta.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round(5, Nearest);
+    /// assert_eq!(zeta.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round(20, Floor);
+    /// assert_eq!(zeta.to_string(), "1.1762409");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round(20, Ceiling);
+    /// assert_eq!(zeta.to_string(), "1.1762428");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round(20, Nearest);
+    /// assert_eq!(zeta.to_string(), "1.1762409");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn zeta_prec_round(self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        self.zeta_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded zeta function
+    /// is less than, equal to, or greater than the exact zeta function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::zeta_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::zeta_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).zeta()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than zero, one, and
+    /// negative even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round_ref(5, Floor);
+    /// assert_eq!(zeta.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round_ref(5, Ceiling);
+    /// assert_eq!(zeta.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round_ref(5, Nearest);
+    /// assert_eq!(zeta.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round_ref(20, Floor);
+    /// assert_eq!(zeta.to_string(), "1.1762409");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round_ref(20, Ceiling);
+    /// assert_eq!(zeta.to_string(), "1.1762428");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_round_ref(20, Nearest);
+    /// assert_eq!(zeta.to_string(), "1.1762409");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn zeta_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match &self.0 {
+            NaN | Infinity { sign: false } => (Self::NAN, Equal),
+            Infinity { sign: true } => (Self::one_prec(prec), Equal),
+            Zero { .. } => (-Self::one_half_prec(prec), Equal),
+            Finite { .. } => zeta_helper(&Argument::from_float(self), prec, rm),
+        }
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], rounding the result to the
+    /// nearest value of the specified precision. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded zeta function is less
+    /// than, equal to, or greater than the exact zeta function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the zeta function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::zeta_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::zeta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec(5);
+    /// assert_eq!(zeta.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec(20);
+    /// assert_eq!(zeta.to_string(), "1.1762409");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn zeta_prec(self, prec: u64) -> (Self, Ordering) {
+        self.zeta_prec_round(prec, Nearest)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], rounding the result to the
+    /// nearest value of the specified precision. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded zeta function is less
+    /// than, equal to, or greater than the exact zeta function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the zeta function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::zeta_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).zeta()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_ref(5);
+    /// assert_eq!(zeta.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(PI).zeta_prec_ref(20);
+    /// assert_eq!(zeta.to_string(), "1.1762409");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn zeta_prec_ref(&self, prec: u64) -> (Self, Ordering) {
+        self.zeta_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], rounding the result to the
+    /// precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded zeta function is
+    /// less than, equal to, or greater than the exact zeta function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::zeta_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::zeta`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than zero, one, and negative
+    /// even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::from(-PI).zeta_round(Floor);
+    /// assert_eq!(zeta.to_string(), "0.0074430404784667226");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::from(-PI).zeta_round(Ceiling);
+    /// assert_eq!(zeta.to_string(), "0.0074430404784667295");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(-PI).zeta_round(Nearest);
+    /// assert_eq!(zeta.to_string(), "0.0074430404784667295");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn zeta_round(self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.zeta_prec_round(prec, rm)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], rounding the result to the
+    /// precision of the input and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded zeta function
+    /// is less than, equal to, or greater than the exact zeta function. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::zeta_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).zeta()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than zero, one, and negative
+    /// even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::from(-PI).zeta_round_ref(Floor);
+    /// assert_eq!(zeta.to_string(), "0.0074430404784667226");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::from(-PI).zeta_round_ref(Ceiling);
+    /// assert_eq!(zeta.to_string(), "0.0074430404784667295");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::from(-PI).zeta_round_ref(Nearest);
+    /// assert_eq!(zeta.to_string(), "0.0074430404784667295");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn zeta_round_ref(&self, rm: RoundingMode) -> (Self, Ordering) {
+        let prec = self.significant_bits();
+        self.zeta_prec_round_ref(prec, rm)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], in place, rounding the result
+    /// to the specified precision and with the specified rounding mode. An [`Ordering`] is
+    /// returned, indicating whether the rounded zeta function is less than, equal to, or
+    /// greater than the exact zeta function. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::zeta_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::zeta_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::zeta_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than zero, one, and
+    /// negative even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.zeta_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.1762409");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.zeta_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.1762428");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.zeta_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.1762409");
+    /// ```
+    #[inline]
+    pub fn zeta_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let mut x = Self::ZERO;
+        swap(self, &mut x);
+        let o;
+        (*self, o) = x.zeta_prec_round(prec, rm);
+        o
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], in place, rounding the result
+    /// to the nearest value of the specified precision. An [`Ordering`] is returned, indicating
+    /// whether the rounded zeta function is less than, equal to, or greater than the exact zeta
+    /// function. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the zeta function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::zeta_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::zeta_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.zeta_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.zeta_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.1762409");
+    /// ```
+    #[inline]
+    pub fn zeta_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.zeta_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], in place, rounding the result
+    /// to the precision of the input and with the specified rounding mode. An [`Ordering`] is
+    /// returned, indicating whether the rounded zeta function is less than, equal to, or greater
+    /// than the exact zeta function. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::zeta_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::zeta_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result cannot be represented exactly with the given
+    /// precision (which is the case for every finite input other than zero, one, and negative
+    /// even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.zeta_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.0074430404784667226");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.zeta_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.0074430404784667295");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// assert_eq!(x.zeta_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.0074430404784667295");
+    /// ```
+    #[inline]
+    pub fn zeta_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.zeta_prec_round_assign(prec, rm)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Rational`], rounding the result to the
+    /// specified precision and with the specified rounding mode and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded zeta function is less than, equal to, or greater than the exact zeta
+    /// function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \zeta(x)+\varepsilon.
+    /// $$
+    /// - If $\zeta(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\zeta(x)|\rfloor-p+1}$.
+    /// - If $\zeta(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\zeta(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=-0.5$
+    /// - $f(1,p,m)=\infty$
+    /// - $f(x,p,m)=0.0$ if $x$ is a negative even integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::zeta_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than zero, one, and
+    /// negative even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(zeta.to_string(), "-1.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round(Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(zeta.to_string(), "-0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(zeta.to_string(), "-0.97336102");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round(Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(zeta.to_string(), "-0.97336006");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn zeta_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        Self::zeta_rational_prec_round_ref(&x, prec, rm)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Rational`], rounding the result to the
+    /// specified precision and with the specified rounding mode and returning the result as a
+    /// [`Float`]. The [`Rational`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded zeta function is less than, equal to, or greater than the
+    /// exact zeta function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \zeta(x)+\varepsilon.
+    /// $$
+    /// - If $\zeta(x)$ is finite and nonzero, and $m$ is neither `Nearest` nor `NearestTiesAway`,
+    ///   then $|\varepsilon| < 2^{\lfloor\log_2 |\zeta(x)|\rfloor-p+1}$.
+    /// - If $\zeta(x)$ is finite and nonzero, and $m$ is `Nearest` or `NearestTiesAway`, then
+    ///   $|\varepsilon| \leq 2^{\lfloor\log_2 |\zeta(x)|\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=-0.5$
+    /// - $f(1,p,m)=\infty$
+    /// - $f(x,p,m)=0.0$ if $x$ is a negative even integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::zeta_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result cannot be represented exactly
+    /// with the given precision (which is the case for every finite input other than zero, one, and
+    /// negative even integers).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Floor);
+    /// assert_eq!(zeta.to_string(), "-1.00");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 5, Ceiling);
+    /// assert_eq!(zeta.to_string(), "-0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Floor);
+    /// assert_eq!(zeta.to_string(), "-0.97336102");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_round_ref(&Rational::from_unsigneds(1u8, 3), 20, Ceiling);
+    /// assert_eq!(zeta.to_string(), "-0.97336006");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn zeta_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            return (-Self::one_half_prec(prec), Equal);
+        }
+        zeta_helper(&Argument::from_rational(x), prec, rm)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Rational`], rounding the result to the
+    /// nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded zeta function is less than, equal to, or greater than the exact zeta function.
+    ///
+    /// If the zeta function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::zeta_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec(Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(zeta.to_string(), "-0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec(Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(zeta.to_string(), "-0.97336006");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn zeta_rational_prec(x: Rational, prec: u64) -> (Self, Ordering) {
+        Self::zeta_rational_prec_round_ref(&x, prec, Nearest)
+    }
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Rational`], rounding the result to the
+    /// nearest value of the specified precision and returning the result as a [`Float`]. The
+    /// [`Rational`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded zeta function is less than, equal to, or greater than the exact zeta function.
+    ///
+    /// If the zeta function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// See the [`Float::zeta_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 \log n + n^2 m)$
+    ///
+    /// $M(n, m) = O(n^2 \log n + nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 5);
+    /// assert_eq!(zeta.to_string(), "-0.969");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::zeta_rational_prec_ref(&Rational::from_unsigneds(1u8, 3), 20);
+    /// assert_eq!(zeta.to_string(), "-0.97336006");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn zeta_rational_prec_ref(x: &Rational, prec: u64) -> (Self, Ordering) {
+        Self::zeta_rational_prec_round_ref(x, prec, Nearest)
+    }
+
+    /// Computes $\zeta(n)$, the Riemann zeta function of a [`u64`], rounding the result to the
+    /// specified precision and with the specified rounding mode and returning the result as a
+    /// [`Float`]. An [`Ordering`] is also returned, indicating whether the rounded zeta function is
+    /// less than, equal to, or greater than the exact zeta function.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(n,p,m) = \zeta(n)+\varepsilon=\sum_{k=1}^\infty \frac{1}{k^n}+\varepsilon.
+    /// $$
+    /// - If $\zeta(n)$ is finite, and $m$ is neither `Nearest` nor `NearestTiesAway`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 \zeta(n)\rfloor-p+1}$.
+    /// - If $\zeta(n)$ is finite, and $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon|
+    ///   \leq 2^{\lfloor\log_2 \zeta(n)\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=-0.5$
+    /// - $f(1,p,m)=\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::zeta_u64_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `n` is greater than 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::zeta_u64_prec_round(3, 5, Floor);
+    /// assert_eq!(zeta.to_string(), "1.19");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::zeta_u64_prec_round(3, 5, Ceiling);
+    /// assert_eq!(zeta.to_string(), "1.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (zeta, o) = Float::zeta_u64_prec_round(3, 20, Floor);
+    /// assert_eq!(zeta.to_string(), "1.2020569");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::zeta_u64_prec_round(3, 20, Ceiling);
+    /// assert_eq!(zeta.to_string(), "1.2020588");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn zeta_u64_prec_round(n: u64, prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        assert_ne!(prec, 0);
+        match n {
+            0 => (-Self::one_half_prec(prec), Equal),
+            1 => (Self::INFINITY, Equal),
+            n => zeta_helper(&Argument::from_rational(&Rational::from(n)), prec, rm),
+        }
+    }
+
+    /// Computes $\zeta(n)$, the Riemann zeta function of a [`u64`], rounding the result to the
+    /// nearest value of the specified precision and returning the result as a [`Float`]. An
+    /// [`Ordering`] is also returned, indicating whether the rounded zeta function is less than,
+    /// equal to, or greater than the exact zeta function.
+    ///
+    /// If the zeta function is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(n,p) = \zeta(n)+\varepsilon=\sum_{k=1}^\infty \frac{1}{k^n}+\varepsilon.
+    /// $$
+    /// - If $\zeta(n)$ is finite, then $|\varepsilon| \leq 2^{\lfloor\log_2 \zeta(n)\rfloor-p}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// See the [`Float::zeta_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (zeta, o) = Float::zeta_u64_prec(2, 5);
+    /// assert_eq!(zeta.to_string(), "1.62");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (zeta, o) = Float::zeta_u64_prec(3, 20);
+    /// assert_eq!(zeta.to_string(), "1.2020569");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn zeta_u64_prec(n: u64, prec: u64) -> (Self, Ordering) {
+        Self::zeta_u64_prec_round(n, prec, Nearest)
+    }
+}
+
+impl Zeta for Float {
+    type Output = Self;
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the zeta function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \zeta(x)+\varepsilon.
+    /// $$
+    /// - If $\zeta(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\zeta(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\zeta(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(\infty)=1.0$
+    /// - $f(0.0)=f(-0.0)=-0.5$
+    /// - $f(1.0)=\infty$
+    /// - $f(x)=0.0$ if $x$ is a negative even integer
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::zeta_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::zeta_round`]. If you want both of these things, consider using
+    /// [`Float::zeta_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Zeta;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.zeta().is_nan());
+    /// assert!(Float::NEGATIVE_INFINITY.zeta().is_nan());
+    /// assert_eq!(Float::ZERO.zeta(), -0.5);
+    /// assert_eq!(Float::from(PI).zeta().to_string(), "1.1762417383825827");
+    /// assert_eq!(Float::from(-PI).zeta().to_string(), "0.0074430404784667295");
+    /// ```
+    #[inline]
+    fn zeta(self) -> Self {
+        let prec = self.significant_bits();
+        self.zeta_prec_round(prec, Nearest).0
+    }
+}
+
+impl Zeta for &Float {
+    type Output = Float;
+
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the zeta function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// f(x) = \zeta(x)+\varepsilon.
+    /// $$
+    /// - If $\zeta(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\zeta(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\zeta(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+    /// - $f(\infty)=1.0$
+    /// - $f(0.0)=f(-0.0)=-0.5$
+    /// - $f(1.0)=\infty$
+    /// - $f(x)=0.0$ if $x$ is a negative even integer
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on overflow and underflow.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::zeta_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::zeta_round_ref`]. If you want both of these things, consider using
+    /// [`Float::zeta_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Zeta;
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).zeta().is_nan());
+    /// assert!((&Float::NEGATIVE_INFINITY).zeta().is_nan());
+    /// assert_eq!((&Float::ZERO).zeta(), -0.5);
+    /// assert_eq!((&Float::from(PI)).zeta().to_string(), "1.1762417383825827");
+    /// assert_eq!((&Float::from(-PI)).zeta().to_string(), "0.0074430404784667295");
+    /// ```
+    #[inline]
+    fn zeta(self) -> Float {
+        let prec = self.significant_bits();
+        self.zeta_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl ZetaAssign for Float {
+    /// Computes $\zeta(x)$, the Riemann zeta function of a [`Float`], in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the zeta function is
+    /// equidistant from two [`Float`]s with that precision, the [`Float`] with fewer 1s in its
+    /// binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding
+    /// mode.
+    ///
+    /// $$
+    /// x\gets = \zeta(x)+\varepsilon.
+    /// $$
+    /// - If $\zeta(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\zeta(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+    ///   |\zeta(x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::zeta_prec_round`] documentation for information on special cases,
+    /// overflow, and underflow.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ZetaAssign;
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.zeta_assign();
+    /// assert_eq!(x.to_string(), "1.1762417383825827");
+    ///
+    /// let mut x = Float::from(-PI);
+    /// x.zeta_assign();
+    /// assert_eq!(x.to_string(), "0.0074430404784667295");
+    /// ```
+    #[inline]
+    fn zeta_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.zeta_prec_round_assign(prec, Nearest);
+    }
+}
+
+/// Computes $\zeta(x)$, the Riemann zeta function of a primitive float, returning a
+/// correctly-rounded result.
+///
+/// $$
+/// f(x) = \zeta(x)+\varepsilon.
+/// $$
+/// - If $\zeta(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\zeta(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\zeta(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(\text{NaN})=f(-\infty)=\text{NaN}$
+/// - $f(\infty)=1.0$
+/// - $f(0.0)=f(-0.0)=-0.5$
+/// - $f(1.0)=\infty$
+/// - $f(x)=0.0$ if $x$ is a negative even integer
+///
+/// # Worst-case complexity
+/// Constant time and additional memory.
+///
+/// # Examples
+/// ```
+/// use core::f64::consts::PI;
+/// use malachite_base::num::basic::traits::NegativeInfinity;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::zeta::primitive_float_zeta;
+///
+/// assert!(primitive_float_zeta(f64::NAN).is_nan());
+/// assert!(primitive_float_zeta(f64::NEGATIVE_INFINITY).is_nan());
+/// assert_eq!(NiceFloat(primitive_float_zeta(-0.0f64)), NiceFloat(-0.5));
+/// assert_eq!(NiceFloat(primitive_float_zeta(-2.0f64)), NiceFloat(0.0));
+/// assert_eq!(NiceFloat(primitive_float_zeta(PI)), NiceFloat(1.1762417383825827));
+/// assert_eq!(NiceFloat(primitive_float_zeta(-1.5f32)), NiceFloat(-0.025485203));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_zeta<T: PrimitiveFloat>(x: T) -> T
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_float_to_float_fn(Float::zeta_prec, x)
+}
+
+/// Computes $\zeta(x)$, the Riemann zeta function of a [`Rational`], returning the result as a
+/// primitive float.
+///
+/// $$
+/// f(x) = \zeta(x)+\varepsilon.
+/// $$
+/// - If $\zeta(x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+/// - If $\zeta(x)$ is finite and nonzero, then $|\varepsilon| \leq 2^{\lfloor\log_2
+///   |\zeta(x)|\rfloor-p}$, where $p$ is the precision of the output (typically 24 if `T` is a
+///   [`f32`] and 53 if `T` is a [`f64`], but less if the output is subnormal).
+///
+/// Special cases:
+/// - $f(0)=-0.5$
+/// - $f(1)=\infty$
+/// - $f(x)=0.0$ if $x$ is a negative even integer
+///
+/// # Worst-case complexity
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_float::float::arithmetic::zeta::primitive_float_zeta_rational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(NiceFloat(primitive_float_zeta_rational::<f64>(&Rational::from_unsigneds(1u8, 3))), NiceFloat(-0.9733602483507827));
+/// assert_eq!(NiceFloat(primitive_float_zeta_rational::<f64>(&Rational::from_signeds(-7, 2))), NiceFloat(0.004441011335479432));
+/// ```
+#[inline]
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn primitive_float_zeta_rational<T: PrimitiveFloat>(x: &Rational) -> T
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    emulate_rational_to_float_fn(Float::zeta_rational_prec_ref, x)
+}
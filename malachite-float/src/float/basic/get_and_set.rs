@@ -278,8 +278,9 @@ impl Float {
     /// MPFR's `mpfr_prec_round`.
     ///
     /// If the [`Float`] originally had the maximum exponent, it is possible for this function to
-    /// overflow. This is even possible if `rm` is `Nearest`, even though infinity is never nearer
-    /// to the exact result than any finite [`Float`] is. This is to match the behavior of MPFR.
+    /// overflow. This is even possible if `rm` is `Nearest` or `NearestTiesAway`, even though
+    /// infinity is never nearer to the exact result than any finite [`Float`] is. This is to
+    /// match the behavior of MPFR.
     ///
     /// This function never underflows.
     ///
@@ -424,8 +425,9 @@ impl Float {
     /// equal to the original value.
     ///
     /// If the input [`Float`] has the maximum exponent, it is possible for this function to
-    /// overflow. This is even possible if `rm` is `Nearest`, even though infinity is never nearer
-    /// to the exact result than any finite [`Float`] is. This is to match the behavior of MPFR.
+    /// overflow. This is even possible if `rm` is `Nearest` or `NearestTiesAway`, even though
+    /// infinity is never nearer to the exact result than any finite [`Float`] is. This is to
+    /// match the behavior of MPFR.
     ///
     /// This function never underflows.
     ///
@@ -481,8 +483,9 @@ impl Float {
     /// equal to the original value.
     ///
     /// If the input [`Float`] has the maximum exponent, it is possible for this function to
-    /// overflow. This is even possible if `rm` is `Nearest`, even though infinity is never nearer
-    /// to the exact result than any finite [`Float`] is. This is to match the behavior of MPFR.
+    /// overflow. This is even possible if `rm` is `Nearest` or `NearestTiesAway`, even though
+    /// infinity is never nearer to the exact result than any finite [`Float`] is. This is to
+    /// match the behavior of MPFR.
     ///
     /// This function never underflows.
     ///
@@ -164,13 +164,18 @@ impl Float {
                 Floor => !sign,
                 Ceiling => sign,
                 Down => false,
-                Up => true,
-                Nearest => {
+                // truncation would give 0, which is even
+                Up | Odd => true,
+                Nearest | NearestTiesAway => {
                     if exp == sub_exp_min - 1 {
                         if significand.is_power_of_2() {
                             // exactly at the tie; round away only if the exact value is beyond the
-                            // approximation
-                            if sign { o == Less } else { o == Greater }
+                            // approximation, or, for NearestTiesAway, equal to it
+                            if sign {
+                                o == Less || rm == NearestTiesAway && o == Equal
+                            } else {
+                                o == Greater || rm == NearestTiesAway && o == Equal
+                            }
                         } else {
                             true
                         }
@@ -214,9 +219,10 @@ impl Float {
             let away = match rm {
                 Floor => !sign,
                 Ceiling => sign,
-                Down => false,
+                // the smallest subnormal is odd
+                Down | Odd => false,
                 Up => true,
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     let sig_bits = prec.neg_mod_power_of_2(Limb::LOG_WIDTH) + prec;
                     if !significand.get_bit(sig_bits - 2) {
                         false
@@ -246,8 +252,10 @@ impl Float {
         // ternary needs to be replaced by the first rounding's. The correction applies when the
         // second rounding hit an exact midpoint and applied the even rule, in the same direction
         // that the first rounding had already taken: the result has then drifted a full ulp from
-        // the exact value, so step back and reverse the reported direction.
-        if o != Equal && rm == Nearest && min_prec == q + 1 && o2 == o {
+        // the exact value, so step back and reverse the reported direction. With NearestTiesAway,
+        // the second rounding always goes away from zero at a midpoint, so the same test applies.
+        // Rounding to odd twice is the same as rounding to odd once, so Odd needs no correction.
+        if o != Equal && (rm == Nearest || rm == NearestTiesAway) && min_prec == q + 1 && o2 == o {
             if (o2 == Greater) == sign {
                 // the result was rounded away from zero; step toward zero
                 magnitude_step_toward_zero(&mut rounded);
@@ -0,0 +1,162 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::float::quotient_floor_and_ceiling;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// Returns (P, Q, T) for the terms from n1 to n2 (excluded) of the series sum(a(k)b(k), k = 0...),
+// where a(k) = 205k ^ 2 + 250k + 77 and b(k) = (-1) ^ k * k! ^ 10 / ((2k + 1)!) ^ 5, relative to
+// b(n1 - 1). The ratio b(k) / b(k - 1) is p(k) / q(k) = -k ^ 5 / (32(2k + 1) ^ 5); P and Q are the
+// products of the p(k) and q(k), and the sum of the terms, divided by b(n1 - 1), is T / Q.
+fn sum(n1: u64, n2: u64) -> (Integer, Integer, Integer) {
+    if n2 == n1 + 1 {
+        let k = Integer::from(n1);
+        let a = (Integer::from(205u32) * &k + Integer::from(250u32)) * &k + Integer::from(77u32);
+        let p = -(&k).pow(5);
+        let q = ((k << 1u32) + Integer::ONE).pow(5) << 5u32;
+        let t = a * &p;
+        (p, q, t)
+    } else {
+        let m = (n1 + n2) >> 1;
+        let (p_1, q_1, t_1) = sum(n1, m);
+        let (p_2, q_2, t_2) = sum(m, n2);
+        let t = t_1 * &q_2 + &p_1 * t_2;
+        (p_1 * p_2, q_1 * q_2, t)
+    }
+}
+
+impl Float {
+    /// Returns an approximation of Apéry's constant, $\zeta(3)$, with the given precision and
+    /// rounded using the given [`RoundingMode`]. An [`Ordering`] is also returned, indicating
+    /// whether the rounded value is less than or greater than the exact value of the constant.
+    /// (Since the constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \zeta(3)+\varepsilon=\sum_{k=1}^\infty \frac{1}{k^3}+\varepsilon.
+    /// $$
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The constant is irrational. It is not known whether it is transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (apery, o) = Float::apery_prec_round(100, Floor);
+    /// assert_eq!(apery.to_string(), "1.2020569031595942853997381615111");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (apery, o) = Float::apery_prec_round(100, Ceiling);
+    /// assert_eq!(apery.to_string(), "1.2020569031595942853997381615127");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn apery_prec_round(prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let mut working_prec = prec + 10;
+        let mut increment = Limb::WIDTH;
+        loop {
+            // ζ(3) = sum(a(k)b(k), k = 0...) / 64. The terms alternate in sign and decrease in
+            // absolute value, so the error from omitting those with k >= n is less than a(n)b(n) <
+            // 2 ^ (8 + 2 * bits(n + 1) - 10n) <= 2 ^ (-working_prec).
+            let n = (working_prec + (working_prec.significant_bits() << 1) + 16) / 10 + 1;
+            let (_, q, t) = sum(1, n);
+            let (z_lo, z_hi) = quotient_floor_and_ceiling(
+                &Natural::exact_from(&q * Integer::from(77u32) + t),
+                &(Natural::exact_from(q) << 6u32),
+                working_prec,
+            );
+            let error = Self::power_of_2(-i64::exact_from(working_prec + 6));
+            let lo = z_lo.sub_prec_round_ref_ref(&error, working_prec, Floor).0;
+            let hi = z_hi.add_prec_round(error, working_prec, Ceiling).0;
+            let (apery_lo, mut o_lo) = Self::from_float_prec_round(lo, prec, rm);
+            let (apery_hi, mut o_hi) = Self::from_float_prec_round(hi, prec, rm);
+            if o_lo == Equal {
+                o_lo = o_hi;
+            }
+            if o_hi == Equal {
+                o_hi = o_lo;
+            }
+            if o_lo == o_hi && apery_lo == apery_hi {
+                return (apery_lo, o_lo);
+            }
+            working_prec += increment;
+            increment = working_prec >> 1;
+        }
+    }
+
+    /// Returns an approximation of Apéry's constant, $\zeta(3)$, with the given precision and
+    /// rounded to the nearest [`Float`] of that precision. An [`Ordering`] is also returned,
+    /// indicating whether the rounded value is less than or greater than the exact value of the
+    /// constant. (Since the constant is irrational, the rounded value is never equal to the exact
+    /// value.)
+    ///
+    /// $$
+    /// x = \zeta(3)+\varepsilon=\sum_{k=1}^\infty \frac{1}{k^3}+\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The constant is irrational. It is not known whether it is transcendental.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (apery, o) = Float::apery_prec(1);
+    /// assert_eq!(apery.to_string(), "1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (apery, o) = Float::apery_prec(10);
+    /// assert_eq!(apery.to_string(), "1.2012");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (apery, o) = Float::apery_prec(100);
+    /// assert_eq!(apery.to_string(), "1.2020569031595942853997381615111");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn apery_prec(prec: u64) -> (Self, Ordering) {
+        Self::apery_prec_round(prec, Nearest)
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::float::{floor_and_ceiling, quotient_floor_and_ceiling};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{PowerOf2, Square};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// Returns (P, Q, T) for the terms from n1 to n2 (excluded) of the series sum(k! ^ 2 / ((2k)!(2k +
+// 1) ^ 2), k = 0...), relative to the term for n1 - 1. The ratio of the term for k to the term for
+// k - 1 is p(k) / q(k) = k(2k - 1) / (2(2k + 1) ^ 2); P and Q are the products of the p(k) and
+// q(k), and the sum of the terms, divided by the term for n1 - 1, is T / Q.
+fn sum(n1: u64, n2: u64) -> (Natural, Natural, Natural) {
+    if n2 == n1 + 1 {
+        let k = Natural::from(n1);
+        let p = ((&k << 1u32) - Natural::ONE) * &k;
+        let q = ((k << 1u32) + Natural::ONE).square() << 1u32;
+        (p.clone(), q, p)
+    } else {
+        let m = (n1 + n2) >> 1;
+        let (p_1, q_1, t_1) = sum(n1, m);
+        let (p_2, q_2, t_2) = sum(m, n2);
+        let t = t_1 * &q_2 + &p_1 * t_2;
+        (p_1 * p_2, q_1 * q_2, t)
+    }
+}
+
+impl Float {
+    /// Returns an approximation of Catalan's constant, with the given precision and rounded using
+    /// the given [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded
+    /// value is less than or greater than the exact value of the constant. (The constant is not
+    /// equal to any [`Float`] of a practical precision, so the rounded value is never equal to the
+    /// exact value.)
+    ///
+    /// $$
+    /// x = G+\varepsilon=\sum_{k=0}^\infty \frac{(-1)^k}{(2k+1)^2}+\varepsilon.
+    /// $$
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// It is not known whether the constant is irrational.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(100, Floor);
+    /// assert_eq!(catalan.to_string(), "0.91596559417721901505460351493173");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (catalan, o) = Float::catalan_prec_round(100, Ceiling);
+    /// assert_eq!(catalan.to_string(), "0.91596559417721901505460351493252");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn catalan_prec_round(prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let mut working_prec = prec + 10;
+        let mut increment = Limb::WIDTH;
+        loop {
+            // G = π / 8 * log(2 + √3) + 3 / 8 * S, where S = sum(k! ^ 2 / ((2k)!(2k + 1) ^ 2), k =
+            // 0...). The terms of S are less than 4 ^ (-k), so the terms with k >= n add up to less
+            // than 4 ^ (1 - n) / 3 < 2 ^ (-working_prec - 2).
+            let n = (working_prec >> 1) + 2;
+            let (_, q, t) = sum(1, n);
+            let (s_lo, s_hi) = quotient_floor_and_ceiling(&(&q + t), &q, working_prec);
+            let s_hi = s_hi
+                .add_prec_round(
+                    Self::power_of_2(-i64::exact_from(working_prec + 2)),
+                    working_prec,
+                    Ceiling,
+                )
+                .0;
+            let (sqrt_3_lo, sqrt_3_hi) =
+                floor_and_ceiling(Self::sqrt_3_prec_round(working_prec, Floor));
+            let l_lo = sqrt_3_lo
+                .add_prec_round(Self::TWO, working_prec, Floor)
+                .0
+                .ln_prec_round(working_prec, Floor)
+                .0;
+            let l_hi = sqrt_3_hi
+                .add_prec_round(Self::TWO, working_prec, Ceiling)
+                .0
+                .ln_prec_round(working_prec, Ceiling)
+                .0;
+            let (pi_lo, pi_hi) = floor_and_ceiling(Self::pi_prec_round(working_prec, Floor));
+            let lo = (pi_lo.mul_prec_round(l_lo, working_prec, Floor).0 >> 3u32)
+                .add_prec_round(
+                    s_lo.mul_prec_round(Self::from(3u32), working_prec, Floor).0 >> 3u32,
+                    working_prec,
+                    Floor,
+                )
+                .0;
+            let hi = (pi_hi.mul_prec_round(l_hi, working_prec, Ceiling).0 >> 3u32)
+                .add_prec_round(
+                    s_hi.mul_prec_round(Self::from(3u32), working_prec, Ceiling)
+                        .0
+                        >> 3u32,
+                    working_prec,
+                    Ceiling,
+                )
+                .0;
+            let (catalan_lo, mut o_lo) = Self::from_float_prec_round(lo, prec, rm);
+            let (catalan_hi, mut o_hi) = Self::from_float_prec_round(hi, prec, rm);
+            if o_lo == Equal {
+                o_lo = o_hi;
+            }
+            if o_hi == Equal {
+                o_hi = o_lo;
+            }
+            if o_lo == o_hi && catalan_lo == catalan_hi {
+                return (catalan_lo, o_lo);
+            }
+            working_prec += increment;
+            increment = working_prec >> 1;
+        }
+    }
+
+    /// Returns an approximation of Catalan's constant, with the given precision and rounded to the
+    /// nearest [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether
+    /// the rounded value is less than or greater than the exact value of the constant. (The
+    /// constant is not equal to any [`Float`] of a practical precision, so the rounded value is
+    /// never equal to the exact value.)
+    ///
+    /// $$
+    /// x = G+\varepsilon=\sum_{k=0}^\infty \frac{(-1)^k}{(2k+1)^2}+\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// It is not known whether the constant is irrational.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (catalan, o) = Float::catalan_prec(1);
+    /// assert_eq!(catalan.to_string(), "1.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (catalan, o) = Float::catalan_prec(10);
+    /// assert_eq!(catalan.to_string(), "0.91602");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (catalan, o) = Float::catalan_prec(100);
+    /// assert_eq!(catalan.to_string(), "0.91596559417721901505460351493252");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn catalan_prec(prec: u64) -> (Self, Ordering) {
+        Self::catalan_prec_round(prec, Nearest)
+    }
+}
@@ -31,8 +31,10 @@ impl Float {
     /// $$
     /// C_b = \sum_{n=1}^\infty n b^{-(n + \sum_{k=1}^n \lfloor\log_b k\rfloor)}+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 C_b\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 C_b\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   C_b\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   C_b\rfloor-p}$.
     ///
     /// Base 10 gives the classical constant, $0.123456789101112\ldots$. The constant is normal in
     /// its base, by construction, and transcendental in every base, by Mahler's theorem.
@@ -125,8 +127,8 @@ impl Float {
     /// $$
     /// x = C = 0.123456789101112\ldots+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
     ///
     /// The constant is irrational and transcendental.
     ///
@@ -32,8 +32,10 @@ impl Float {
     /// CE_b = 0.\overline{p_1 p_2 p_3 \ldots}_b+\varepsilon,
     /// $$
     /// where $p_i$ is the $i$th prime written in base $b$.
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 CE_b\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 CE_b\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   CE_b\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   CE_b\rfloor-p}$.
     ///
     /// Base 10 gives the classical constant, $0.235711131719\ldots$. The Copeland–Erdős theorem
     /// says that it is normal in its base, which in particular makes it irrational.
@@ -128,8 +130,8 @@ impl Float {
     /// $$
     /// x = CE = 0.235711131719\ldots+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
     ///
     /// The constant is irrational and transcendental.
     ///
@@ -0,0 +1,201 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::float::{floor_and_ceiling, quotient_floor_and_ceiling};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{PowerOf2, Square};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+
+// The binary-splitting state for the terms from n1 to n2 (excluded) of the Brent–McMillan series
+// A = sum(b(k)H(k), k = 0...) and B = sum(b(k), k = 0...), where b(k) = (m ^ k / k!) ^ 2 and H(k)
+// is the kth harmonic number. The ratio b(k) / b(k - 1) is m ^ 2 / k ^ 2; P and Q are the products
+// of the numerators and denominators of these ratios, and D is the product of the k. Relative to
+// b(n1 - 1), the sum of the b(k) is T / Q and the sum of the b(k)(H(k) - H(n1 - 1)) is V / (DQ),
+// while H(n2
+// - 1) - H(n1 - 1) is C / D.
+struct Terms {
+    p: Natural,
+    q: Natural,
+    t: Natural,
+    d: Natural,
+    c: Natural,
+    v: Natural,
+}
+
+fn sum(m_squared: &Natural, n1: u64, n2: u64) -> Terms {
+    if n2 == n1 + 1 {
+        let k = Natural::from(n1);
+        Terms {
+            p: m_squared.clone(),
+            q: (&k).square(),
+            t: m_squared.clone(),
+            d: k,
+            c: Natural::ONE,
+            v: m_squared.clone(),
+        }
+    } else {
+        let mid = (n1 + n2) >> 1;
+        let left = sum(m_squared, n1, mid);
+        let right = sum(m_squared, mid, n2);
+        let v = &left.v * &right.d * &right.q
+            + &left.p * (&left.c * &right.d * &right.t + &right.v * &left.d);
+        Terms {
+            t: &left.t * &right.q + &left.p * right.t,
+            c: &left.c * &right.d + right.c * &left.d,
+            p: left.p * right.p,
+            q: left.q * right.q,
+            d: left.d * right.d,
+            v,
+        }
+    }
+}
+
+impl Float {
+    /// Returns an approximation of the Euler–Mascheroni constant, $\gamma$, with the given
+    /// precision and rounded using the given [`RoundingMode`]. An [`Ordering`] is also returned,
+    /// indicating whether the rounded value is less than or greater than the exact value of the
+    /// constant. (The constant is not equal to any [`Float`] of a practical precision, so the
+    /// rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \gamma+\varepsilon=\lim_{n\to\infty}\left (\sum_{k=1}^n \frac{1}{k}-\ln n\right )
+    /// +\varepsilon.
+    /// $$
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// It is not known whether the constant is irrational.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (euler_gamma, o) = Float::euler_gamma_prec_round(100, Floor);
+    /// assert_eq!(euler_gamma.to_string(), "0.57721566490153286060651209008234");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (euler_gamma, o) = Float::euler_gamma_prec_round(100, Ceiling);
+    /// assert_eq!(euler_gamma.to_string(), "0.57721566490153286060651209008313");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn euler_gamma_prec_round(prec: u64, rm: RoundingMode) -> (Self, Ordering) {
+        let mut working_prec = prec + 10;
+        let mut increment = Limb::WIDTH;
+        loop {
+            // By Brent and McMillan, 0 < A / B - log(m) - γ < πe ^ (-4m) < 2 ^ (-working_prec - 2).
+            let m = working_prec.div_ceil(5) + 1;
+            // For k >= n - 1, b(k + 1) / b(k) <= 1/16, so the omitted terms of B add up to less
+            // than b(n - 1) / 15, and, since H(k + 1) <= 2H(k) and H(n) <= n, those of A add up to
+            // less than b(n - 1)n / 14.
+            let n = m << 2;
+            let Terms { p, q, t, d, v, .. } = sum(&Natural::from(m).square(), 1, n);
+            // A = V / (DQ) and B = 1 + T / Q, up to the omitted terms, and b(n - 1) = P / Q
+            let q_plus_t = q + t;
+            let (ratio_lo, _) = quotient_floor_and_ceiling(
+                &(&v * Natural::from(15u32)),
+                &(&d * (&q_plus_t * Natural::from(15u32) + &p)),
+                working_prec,
+            );
+            let (_, ratio_hi) = quotient_floor_and_ceiling(
+                &(v * Natural::from(14u32) + p * &d * Natural::from(n)),
+                &(d * q_plus_t * Natural::from(14u32)),
+                working_prec,
+            );
+            let (ln_m_lo, ln_m_hi) =
+                floor_and_ceiling(Self::from(m).ln_prec_round(working_prec, Floor));
+            let error = Self::power_of_2(-i64::exact_from(working_prec + 2));
+            let lo = ratio_lo
+                .sub_prec_round(ln_m_hi, working_prec, Floor)
+                .0
+                .sub_prec_round_ref_ref(&error, working_prec, Floor)
+                .0;
+            let hi = ratio_hi.sub_prec_round(ln_m_lo, working_prec, Ceiling).0;
+            let (euler_gamma_lo, mut o_lo) = Self::from_float_prec_round(lo, prec, rm);
+            let (euler_gamma_hi, mut o_hi) = Self::from_float_prec_round(hi, prec, rm);
+            if o_lo == Equal {
+                o_lo = o_hi;
+            }
+            if o_hi == Equal {
+                o_hi = o_lo;
+            }
+            if o_lo == o_hi && euler_gamma_lo == euler_gamma_hi {
+                return (euler_gamma_lo, o_lo);
+            }
+            working_prec += increment;
+            increment = working_prec >> 1;
+        }
+    }
+
+    /// Returns an approximation of the Euler–Mascheroni constant, $\gamma$, with the given
+    /// precision and rounded to the nearest [`Float`] of that precision. An [`Ordering`] is also
+    /// returned, indicating whether the rounded value is less than or greater than the exact value
+    /// of the constant. (The constant is not equal to any [`Float`] of a practical precision, so
+    /// the rounded value is never equal to the exact value.)
+    ///
+    /// $$
+    /// x = \gamma+\varepsilon=\lim_{n\to\infty}\left (\sum_{k=1}^n \frac{1}{k}-\ln n\right )
+    /// +\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// It is not known whether the constant is irrational.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (euler_gamma, o) = Float::euler_gamma_prec(1);
+    /// assert_eq!(euler_gamma.to_string(), "0.50");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (euler_gamma, o) = Float::euler_gamma_prec(10);
+    /// assert_eq!(euler_gamma.to_string(), "0.57715");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (euler_gamma, o) = Float::euler_gamma_prec(100);
+    /// assert_eq!(euler_gamma.to_string(), "0.57721566490153286060651209008234");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn euler_gamma_prec(prec: u64) -> (Self, Ordering) {
+        Self::euler_gamma_prec_round(prec, Nearest)
+    }
+}
@@ -30,8 +30,8 @@ impl Float {
     /// x = G+\varepsilon=1/\mathrm{AGM}(1,\sqrt{2})+\varepsilon,
     /// $$
     /// where AGM is the arithmetic-geometric mean.
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
     ///
     /// The constant is irrational and transcendental.
     ///
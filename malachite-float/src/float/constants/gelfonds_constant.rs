@@ -21,8 +21,8 @@ impl Float {
     /// $$
     /// x = e^\pi+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p+5}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p+4}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p+5}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p+4}$.
     ///
     /// The constant is irrational and transcendental.
     ///
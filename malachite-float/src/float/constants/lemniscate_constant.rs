@@ -29,8 +29,8 @@ impl Float {
     /// x = \varpi+\varepsilon=\pi G+\varepsilon,
     /// $$
     /// where $G$ is Gauss's constant.
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p+2}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p+1}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p+2}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p+1}$.
     ///
     /// The constant is irrational and transcendental.
     ///
@@ -47,8 +47,10 @@ impl Float {
     /// $$
     /// L_b = \sum_{n=1}^\infty b^{-n!}+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 L_b\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 L_b\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   L_b\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   L_b\rfloor-p}$.
     ///
     /// Base 10 gives the classical constant, $0.110001000000000000000001\ldots$, the first number
     /// proven transcendental. The constant is transcendental in every base, being a Liouville
@@ -153,8 +155,8 @@ impl Float {
     /// $$
     /// x = L = \sum_{n=1}^{\infty} 10^{-n!}+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
     ///
     /// The constant is irrational and transcendental.
     ///
@@ -6,6 +6,10 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// Functions for approximating Apéry's constant, $\zeta(3)$.
+pub mod apery;
+/// Functions for approximating Catalan's constant, $G=\sum_{k=0}^\infty (-1)^k/(2k+1)^2$.
+pub mod catalan;
 /// Functions for approximating $\sqrt\[3\]{2}$.
 pub mod cbrt_2;
 /// Functions for approximating the Champernowne constant in a given base.
@@ -14,6 +18,8 @@ pub mod champernowne_constant;
 pub mod copeland_erdos_constant;
 /// Functions for approximating $e$, Euler's number.
 pub mod e;
+/// Functions for approximating the Euler–Mascheroni constant, $\gamma$.
+pub mod euler_gamma;
 /// Functions for approximating Gauss's constant, $G=1/\mathrm{AGM}(1,\sqrt{2})$.
 pub mod gauss_constant;
 /// Functions for approximating the Gelfond–Schneider constant, $2^{\sqrt 2}$.
@@ -29,8 +29,8 @@ impl Float {
     /// $$
     /// x = 1/\sqrt{\tau}+\varepsilon=1/\sqrt{2\pi}+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-2}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-2}$.
     ///
     /// The constant is irrational and transcendental.
     ///
@@ -20,8 +20,8 @@ impl Float {
     /// $$
     /// \varphi = \frac{1+\sqrt{2}}{2}+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
     ///
     /// The constant is irrational and algebraic.
     ///
@@ -55,8 +55,8 @@ impl Float {
     pub fn phi_prec_round(prec: u64, rm: RoundingMode) -> (Self, Ordering) {
         if prec == 1 {
             match rm {
-                Floor | Down => (Self::ONE, Less),
-                Ceiling | Up | Nearest => (Self::TWO, Greater),
+                Floor | Down | Odd => (Self::ONE, Less),
+                Ceiling | Up | Nearest | NearestTiesAway => (Self::TWO, Greater),
                 Exact => panic!("Inexact float square root"),
             }
         } else {
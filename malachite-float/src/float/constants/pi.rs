@@ -30,8 +30,8 @@ impl Float {
     /// $$
     /// x = \pi+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p+2}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p+1}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p+2}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p+1}$.
     ///
     /// The constant is irrational and transcendental.
     ///
@@ -63,7 +63,6 @@ impl Float {
     /// assert_eq!(pi.to_string(), "3.1415926535897932384626433832825");
     /// assert_eq!(o, Greater);
     /// ```
-    ///
     // This is mpfr_const_pi_internal from const_pi.c, MPFR 4.2.0.
     #[inline]
     pub fn pi_prec_round(prec: u64, rm: RoundingMode) -> (Self, Ordering) {
@@ -22,8 +22,8 @@ impl Float {
     /// $$
     /// \rho = \sum_{p\ \text{prime}\}2^{-p}+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-2}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-2}$.
     ///
     /// The constant is irrational. It is unknown whether it is transcendental; see
     /// <https://mathoverflow.net/questions/114905>.
@@ -61,11 +61,13 @@ impl Float {
         // know exactly how many bits `non_dyadic_from_bits_prec_round` will read, we can get away
         // with this.
         Self::non_dyadic_from_bits_prec_round(
-            prime_indicator_sequence_less_than_or_equal_to(if rm == Nearest {
-                prec + 2
-            } else {
-                prec + 1
-            }),
+            prime_indicator_sequence_less_than_or_equal_to(
+                if rm == Nearest || rm == NearestTiesAway {
+                    prec + 2
+                } else {
+                    prec + 1
+                },
+            ),
             prec,
             rm,
         )
@@ -40,8 +40,8 @@ impl Float {
     /// \tau = \sum_{k=0}^\infty\frac{t_n}{2^{n+1}}+\varepsilon,
     /// where $t_n$ is the Thue-Morse sequence.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-2}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p-1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p-2}$.
     ///
     /// An alternative expression, from <https://mathworld.wolfram.com/Thue-MorseConstant.html>, is
     /// $$
@@ -81,7 +81,7 @@ impl Float {
         assert_ne!(rm, Exact);
         // If the result is 1/2 then the exponent is 0 rather than -1, so we handle that case
         // separately.
-        if prec == 1 && (rm == Nearest || rm == Ceiling || rm == Up) {
+        if prec == 1 && (rm == Nearest || rm == NearestTiesAway || rm == Ceiling || rm == Up) {
             return (Self::ONE_HALF, Greater);
         } else if prec == 2 && (rm == Ceiling || rm == Up) {
             // TODO implement const_from_unsigned_prec_times_power_of_2
@@ -109,11 +109,13 @@ impl Float {
         let increment = match rm {
             Up | Ceiling => true,
             Down | Floor => false,
-            Nearest => match lsb {
+            Nearest | NearestTiesAway => match lsb {
                 1 => !next_tms,
                 2 => tms.next().unwrap(),
                 _ => limbs[0] & (lsb >> 1) != 0,
             },
+            // The constant is irrational, so the truncation is inexact and must be made odd
+            Odd => limbs[0] & lsb == 0,
             Exact => unreachable!(),
         };
         limbs[0] &= !(lsb - 1);
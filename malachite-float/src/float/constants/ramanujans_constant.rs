@@ -22,8 +22,8 @@ impl Float {
     /// $$
     /// x = e^{\pi\sqrt{163}}+\varepsilon.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p+58}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p+57}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{-p+58}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{-p+57}$.
     ///
     /// The constant is irrational and transcendental. It is famously close to an integer: $e^{\pi
     /// \sqrt{163}} \approx 262{,}537{,}412{,}640{,}768{,}744 - 7.5 \times 10^{-13}$.
@@ -12,7 +12,7 @@ use alloc::vec;
 use core::cmp::Ordering::{self, *};
 use malachite_base::num::arithmetic::traits::{DivisibleByPowerOf2, NegModPowerOf2, PowerOf2};
 use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_nz::natural::{LIMB_HIGH_BIT, Natural, bit_to_limb_count_ceiling};
 use malachite_nz::platform::Limb;
@@ -26,8 +26,8 @@ impl Float {
     /// The approximation has precision `prec` and is rounded according to the provided rounding
     /// mode.
     ///
-    /// This function reads `prec + z` bits, or `prec + z + 1` bits if `rm` is `Nearest`, where `z`
-    /// is the number of leading false bits in `bits`.
+    /// This function reads `prec + z` bits, or `prec + z + 1` bits if `rm` is `Nearest` or
+    /// `NearestTiesAway`, where `z` is the number of leading false bits in `bits`.
     ///
     /// If the first bit is set, this function produces a value in the interval $[1/2,1)$; each
     /// leading false bit halves that interval, so $z$ of them place the value in
@@ -41,8 +41,10 @@ impl Float {
     /// $$
     /// C=\sum_{k=0}^\infty x_k 2^{-(k+1)}.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 C\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 C\rfloor-p}$.
+    /// - If $m$ is neither `Nearest` nor `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   C\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   C\rfloor-p}$.
     ///
     /// The output has precision `prec`.
     ///
@@ -145,10 +147,17 @@ impl Float {
             }
         }
         let mut significand = Natural::from_owned_limbs_asc(limbs);
-        let increment = rm == Up || rm == Ceiling || (rm == Nearest && bits.next() == Some(true));
+        let shift = prec.neg_mod_power_of_2(Limb::LOG_WIDTH);
+        let increment = match rm {
+            Up | Ceiling => true,
+            Down | Floor => false,
+            Nearest | NearestTiesAway => bits.next() == Some(true),
+            // The number is not dyadic, so the truncation is inexact and must be made odd
+            Odd => !significand.get_bit(shift),
+            Exact => unreachable!(),
+        };
         if increment {
-            significand +=
-                Natural::from(Limb::power_of_2(prec.neg_mod_power_of_2(Limb::LOG_WIDTH)));
+            significand += Natural::from(Limb::power_of_2(shift));
             if !significand
                 .significant_bits()
                 .divisible_by_power_of_2(Limb::LOG_WIDTH)
@@ -43,11 +43,12 @@ impl Float {
     /// If you're only using [`Nearest`], try using [`Float::from_integer_prec`] instead.
     ///
     /// - If the [`Integer`] rounds to a value greater than or equal to $2^{2^{30}-1}$), this
-    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds down
-    ///   to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, and rounds down to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is
+    ///   `prec`.
     /// - If the [`Integer`] rounds to a value less than or equal to $-2^{2^{30}-1}$), this function
-    ///   overflows to $-\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds up to
-    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   overflows to $-\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or `NearestTiesAway`, and
+    ///   rounds up to $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
     ///
     /// # Worst-case complexity
     /// $T(m,n) = O(\max(m,n))$
@@ -119,11 +120,12 @@ impl Float {
     /// If you're only using [`Nearest`], try using [`Float::from_integer_prec_ref`] instead.
     ///
     /// - If the [`Integer`] rounds to a value greater than or equal to $2^{2^{30}-1}$), this
-    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds down
-    ///   to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, and rounds down to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is
+    ///   `prec`.
     /// - If the [`Integer`] rounds to a value less than or equal to $-2^{2^{30}-1}$), this function
-    ///   overflows to $-\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds up to
-    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   overflows to $-\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or `NearestTiesAway`, and
+    ///   rounds up to $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
     ///
     /// # Worst-case complexity
     /// $T(m,n) = O(\max(m,n))$
@@ -34,8 +34,8 @@ fn from_natural_prec_round_helper(
     let mut exponent = i32::saturating_from(bits);
     if exponent > Float::MAX_EXPONENT {
         return match rm {
-            Up | Ceiling | Nearest => (Float::INFINITY, Greater),
-            Floor | Down => (Float::max_finite_value_with_prec(prec), Less),
+            Up | Ceiling | Nearest | NearestTiesAway => (Float::INFINITY, Greater),
+            Floor | Down | Odd => (Float::max_finite_value_with_prec(prec), Less),
             Exact => panic!("Inexact conversion from Natural to Float"),
         };
     }
@@ -81,6 +81,23 @@ fn from_natural_prec_round_helper(
                 Equal
             }
         }
+        Odd => {
+            let inexact = erased_limb != 0
+                || !significand.divisible_by_power_of_2(mask_width)
+                || rev_limbs.any(|y| y != 0);
+            if inexact {
+                significand.round_to_multiple_of_power_of_2_assign(mask_width, Floor);
+                // Setting the lowest kept bit of an even truncation cannot carry
+                if significand.get_bit(mask_width) {
+                    Less
+                } else {
+                    significand.set_bit(mask_width);
+                    Greater
+                }
+            } else {
+                Equal
+            }
+        }
         Ceiling | Up => {
             let inexact = erased_limb != 0
                 || !significand.divisible_by_power_of_2(mask_width)
@@ -101,11 +118,11 @@ fn from_natural_prec_round_helper(
                 Equal
             }
         }
-        Nearest => {
+        Nearest | NearestTiesAway => {
             let half_bit = x.get_bit(bits - prec - 1);
             let inexact_after_half = !x.divisible_by_power_of_2(bits - prec - 1);
             let inexact = half_bit || inexact_after_half;
-            if half_bit && (inexact_after_half || x.get_bit(bits - prec)) {
+            if half_bit && (rm == NearestTiesAway || inexact_after_half || x.get_bit(bits - prec)) {
                 let original_limb_count = significand.limb_count();
                 significand.round_to_multiple_of_power_of_2_assign(mask_width, Floor);
                 significand += Natural::power_of_2(mask_width);
@@ -182,6 +199,23 @@ fn from_natural_prec_round_helper_zero_exponent(
                 Equal
             }
         }
+        Odd => {
+            let inexact = erased_limb != 0
+                || !significand.divisible_by_power_of_2(mask_width)
+                || rev_limbs.any(|y| y != 0);
+            if inexact {
+                significand.round_to_multiple_of_power_of_2_assign(mask_width, Floor);
+                // Setting the lowest kept bit of an even truncation cannot carry
+                if significand.get_bit(mask_width) {
+                    Less
+                } else {
+                    significand.set_bit(mask_width);
+                    Greater
+                }
+            } else {
+                Equal
+            }
+        }
         Ceiling | Up => {
             let inexact = erased_limb != 0
                 || !significand.divisible_by_power_of_2(mask_width)
@@ -199,11 +233,11 @@ fn from_natural_prec_round_helper_zero_exponent(
                 Equal
             }
         }
-        Nearest => {
+        Nearest | NearestTiesAway => {
             let half_bit = x.get_bit(bits - prec - 1);
             let inexact_after_half = !x.divisible_by_power_of_2(bits - prec - 1);
             let inexact = half_bit || inexact_after_half;
-            if half_bit && (inexact_after_half || x.get_bit(bits - prec)) {
+            if half_bit && (rm == NearestTiesAway || inexact_after_half || x.get_bit(bits - prec)) {
                 let original_limb_count = significand.limb_count();
                 significand.round_to_multiple_of_power_of_2_assign(mask_width, Floor);
                 significand += Natural::power_of_2(mask_width);
@@ -333,8 +367,9 @@ impl Float {
     /// If you're only using [`Nearest`], try using [`Float::from_natural_prec`] instead.
     ///
     /// - If the [`Natural`] rounds to a value greater than or equal to $2^{2^{30}-1}$), this
-    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds down
-    ///   to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, and rounds down to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is
+    ///   `prec`.
     ///
     /// # Worst-case complexity
     /// $T(m,n) = O(\max(m,n))$
@@ -394,8 +429,8 @@ impl Float {
             return (f, o);
         }
         match rm {
-            Up | Ceiling | Nearest => (Self::INFINITY, Greater),
-            Floor | Down => (Self::max_finite_value_with_prec(prec), Less),
+            Up | Ceiling | Nearest | NearestTiesAway => (Self::INFINITY, Greater),
+            Floor | Down | Odd => (Self::max_finite_value_with_prec(prec), Less),
             Exact => panic!("Inexact conversion from Natural to Float"),
         }
     }
@@ -408,8 +443,9 @@ impl Float {
     /// If you're only using [`Nearest`], try using [`Float::from_natural_prec_ref`] instead.
     ///
     /// - If the [`Natural`] rounds to a value greater than or equal to $2^{2^{30}-1}$), this
-    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds down
-    ///   to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, and rounds down to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is
+    ///   `prec`.
     ///
     /// # Worst-case complexity
     /// $T(m,n) = O(\max(m,n))$
@@ -474,8 +510,8 @@ impl Float {
                 return (f, o);
             }
             match rm {
-                Up | Ceiling | Nearest => (Self::INFINITY, Greater),
-                Floor | Down => (Self::max_finite_value_with_prec(prec), Less),
+                Up | Ceiling | Nearest | NearestTiesAway => (Self::INFINITY, Greater),
+                Floor | Down | Odd => (Self::max_finite_value_with_prec(prec), Less),
                 Exact => panic!("Inexact conversion from Natural to Float"),
             }
         } else {
@@ -51,10 +51,10 @@ private_test_fn! {from_rational_prec_round_direct(
         let mut exponent = i32::saturating_from(x.floor_log_base_2_abs());
         if exponent >= Float::MAX_EXPONENT {
             return match (sign, rm) {
-                (true, Up | Ceiling | Nearest) => (Float::INFINITY, Greater),
-                (true, Floor | Down) => (Float::max_finite_value_with_prec(prec), Less),
-                (false, Up | Floor | Nearest) => (Float::NEGATIVE_INFINITY, Less),
-                (false, Ceiling | Down) => (-Float::max_finite_value_with_prec(prec), Greater),
+                (true, Up | Ceiling | Nearest | NearestTiesAway) => (Float::INFINITY, Greater),
+                (true, Floor | Down | Odd) => (Float::max_finite_value_with_prec(prec), Less),
+                (false, Up | Floor | Nearest | NearestTiesAway) => (Float::NEGATIVE_INFINITY, Less),
+                (false, Ceiling | Down | Odd) => (-Float::max_finite_value_with_prec(prec), Greater),
                 (_, Exact) => panic!("Inexact conversion from Rational to Float"),
             };
         }
@@ -76,9 +76,10 @@ private_test_fn! {from_rational_prec_round_direct(
         exponent += 1;
         if exponent < Float::MIN_EXPONENT {
             assert!(rm != Exact, "Inexact conversion from Rational to Float");
-            return if rm == Nearest
+            return if (rm == Nearest || rm == NearestTiesAway)
                 && exponent == Float::MIN_EXPONENT_MINUS_1
-                && (o == away_from_0.reverse() || !significand.is_power_of_2())
+                && (o == away_from_0.reverse()
+                    || rm == NearestTiesAway && o == Equal || !significand.is_power_of_2())
             {
                 if sign {
                     (Float::min_positive_value_prec(prec), Greater)
@@ -87,10 +88,10 @@ private_test_fn! {from_rational_prec_round_direct(
                 }
             } else {
                 match (sign, rm) {
-                    (true, Up | Ceiling) => (Float::min_positive_value_prec(prec), Greater),
-                    (true, Floor | Down | Nearest) => (Float::ZERO, Less),
-                    (false, Up | Floor) => (-Float::min_positive_value_prec(prec), Less),
-                    (false, Ceiling | Down | Nearest) => (Float::NEGATIVE_ZERO, Greater),
+                    (true, Up | Ceiling | Odd) => (Float::min_positive_value_prec(prec), Greater),
+                    (true, Floor | Down | Nearest | NearestTiesAway) => (Float::ZERO, Less),
+                    (false, Up | Floor | Odd) => (-Float::min_positive_value_prec(prec), Less),
+                    (false, Ceiling | Down | Nearest | NearestTiesAway) => (Float::NEGATIVE_ZERO, Greater),
                     (_, Exact) => unreachable!(),
                 }
             };
@@ -216,10 +217,10 @@ private_test_fn! {from_rational_prec_round_ref_direct(
         let mut exponent = i32::saturating_from(x.floor_log_base_2_abs());
         if exponent >= Float::MAX_EXPONENT {
             return match (sign, rm) {
-                (true, Up | Ceiling | Nearest) => (Float::INFINITY, Greater),
-                (true, Floor | Down) => (Float::max_finite_value_with_prec(prec), Less),
-                (false, Up | Floor | Nearest) => (Float::NEGATIVE_INFINITY, Less),
-                (false, Ceiling | Down) => (-Float::max_finite_value_with_prec(prec), Greater),
+                (true, Up | Ceiling | Nearest | NearestTiesAway) => (Float::INFINITY, Greater),
+                (true, Floor | Down | Odd) => (Float::max_finite_value_with_prec(prec), Less),
+                (false, Up | Floor | Nearest | NearestTiesAway) => (Float::NEGATIVE_INFINITY, Less),
+                (false, Ceiling | Down | Odd) => (-Float::max_finite_value_with_prec(prec), Greater),
                 (_, Exact) => panic!("Inexact conversion from Rational to Float"),
             };
         }
@@ -241,9 +242,10 @@ private_test_fn! {from_rational_prec_round_ref_direct(
         exponent += 1;
         if exponent < Float::MIN_EXPONENT {
             assert!(rm != Exact, "Inexact conversion from Rational to Float");
-            return if rm == Nearest
+            return if (rm == Nearest || rm == NearestTiesAway)
                 && exponent == Float::MIN_EXPONENT_MINUS_1
-                && (o == away_from_0.reverse() || !significand.is_power_of_2())
+                && (o == away_from_0.reverse()
+                    || rm == NearestTiesAway && o == Equal || !significand.is_power_of_2())
             {
                 if sign {
                     (Float::min_positive_value_prec(prec), Greater)
@@ -252,10 +254,10 @@ private_test_fn! {from_rational_prec_round_ref_direct(
                 }
             } else {
                 match (sign, rm) {
-                    (true, Up | Ceiling) => (Float::min_positive_value_prec(prec), Greater),
-                    (true, Floor | Down | Nearest) => (Float::ZERO, Less),
-                    (false, Up | Floor) => (-Float::min_positive_value_prec(prec), Less),
-                    (false, Ceiling | Down | Nearest) => (Float::NEGATIVE_ZERO, Greater),
+                    (true, Up | Ceiling | Odd) => (Float::min_positive_value_prec(prec), Greater),
+                    (true, Floor | Down | Nearest | NearestTiesAway) => (Float::ZERO, Less),
+                    (false, Up | Floor | Odd) => (-Float::min_positive_value_prec(prec), Less),
+                    (false, Ceiling | Down | Nearest | NearestTiesAway) => (Float::NEGATIVE_ZERO, Greater),
                     (_, Exact) => unreachable!(),
                 }
             };
@@ -360,23 +362,26 @@ impl Float {
     /// If you're only using [`Nearest`], try using [`Float::from_rational_prec`] instead.
     ///
     /// - If the [`Rational`] rounds to a value greater than or equal to $2^{2^{30}-1}$), this
-    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds down
-    ///   to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, and rounds down to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is
+    ///   `prec`.
     /// - If the [`Rational`] rounds to a value less than or equal to $-2^{2^{30}-1}$), this
-    ///   function overflows to $-\infty$ if `rm` is `Floor`, `Up`, or `Nearest`, and rounds up to
-    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $-\infty$ if `rm` is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   and rounds up to $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
     /// - If the [`Rational`] rounds to a positive value less than $2^{-2^{30}}$), this function
     ///   underflows to positive zero if `rm` is `Floor` or `Down`, rounds up to $2^{-2^{30}}$ if
-    ///   `rm` is `Ceiling` or `Up`, underflows to positive zero if `rm` is `Nearest` and the
-    ///   [`Rational`] rounds to a value less than or equal to $2^{-2^{30}-1}$, and rounds up to
+    ///   `rm` is `Ceiling`, `Up`, or `Odd`, underflows to positive zero if `rm` is `Nearest` and
+    ///   the [`Rational`] rounds to a value less than or equal to $2^{-2^{30}-1}$, and rounds up to
     ///   $2^{-2^{30}}$ if `rm` is `Nearest` and the [`Rational`] rounds to a value greater than
-    ///   $2^{-2^{30}-1}$.
+    ///   $2^{-2^{30}-1}$; `NearestTiesAway` behaves like `Nearest`, except that it rounds up to
+    ///   $2^{-2^{30}}$ when the [`Rational`] rounds to exactly $2^{-2^{30}-1}$.
     /// - If the [`Rational`] rounds to a negative value greater than $-2^{-2^{30}}$), this function
     ///   underflows to negative zero if `rm` is `Ceiling` or `Down`, rounds down to $-2^{-2^{30}}$
-    ///   if `rm` is `Floor` or `Up`, underflows to negative zero if `rm` is `Nearest` and the
-    ///   [`Rational`] rounds to a value greater than or equal to $-2^{-2^{30}-1}$, and rounds down
-    ///   to $-2^{-2^{30}}$ if `rm` is `Nearest` and the [`Rational`] rounds to a value less than
-    ///   $-2^{-2^{30}-1}$.
+    ///   if `rm` is `Floor`, `Up`, or `Odd`, underflows to negative zero if `rm` is `Nearest` and
+    ///   the [`Rational`] rounds to a value greater than or equal to $-2^{-2^{30}-1}$, and rounds
+    ///   down to $-2^{-2^{30}}$ if `rm` is `Nearest` and the [`Rational`] rounds to a value less
+    ///   than $-2^{-2^{30}-1}$; `NearestTiesAway` behaves like `Nearest`, except that it rounds
+    ///   down to $-2^{-2^{30}}$ when the [`Rational`] rounds to exactly $-2^{-2^{30}-1}$.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n \log n \log\log n)$
@@ -513,23 +518,26 @@ impl Float {
     /// If you're only using [`Nearest`], try using [`Float::from_rational_prec_ref`] instead.
     ///
     /// - If the [`Rational`] rounds to a value greater than or equal to $2^{2^{30}-1}$), this
-    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, or `Nearest`, and rounds down
-    ///   to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $\infty$ if `rm` is `Ceiling`, `Up`, `Nearest`, or
+    ///   `NearestTiesAway`, and rounds down to $(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is
+    ///   `prec`.
     /// - If the [`Rational`] rounds to a value less than or equal to $-2^{2^{30}-1}$), this
-    ///   function overflows to $-\infty$ if `rm` is `Floor`, `Up`, or `Nearest`, and rounds up to
-    ///   $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
+    ///   function overflows to $-\infty$ if `rm` is `Floor`, `Up`, `Nearest`, or `NearestTiesAway`,
+    ///   and rounds up to $-(1-(1/2)^p)2^{2^{30}-1}$ otherwise, where $p$ is `prec`.
     /// - If the [`Rational`] rounds to a positive value less than $2^{-2^{30}}$), this function
     ///   underflows to positive zero if `rm` is `Floor` or `Down`, rounds up to $2^{-2^{30}}$ if
-    ///   `rm` is `Ceiling` or `Up`, underflows to positive zero if `rm` is `Nearest` and the
-    ///   [`Rational`] rounds to a value less than or equal to $2^{-2^{30}-1}$, and rounds up to
+    ///   `rm` is `Ceiling`, `Up`, or `Odd`, underflows to positive zero if `rm` is `Nearest` and
+    ///   the [`Rational`] rounds to a value less than or equal to $2^{-2^{30}-1}$, and rounds up to
     ///   $2^{-2^{30}}$ if `rm` is `Nearest` and the [`Rational`] rounds to a value greater than
-    ///   $2^{-2^{30}-1}$.
+    ///   $2^{-2^{30}-1}$; `NearestTiesAway` behaves like `Nearest`, except that it rounds up to
+    ///   $2^{-2^{30}}$ when the [`Rational`] rounds to exactly $2^{-2^{30}-1}$.
     /// - If the [`Rational`] rounds to a negative value greater than $-2^{-2^{30}}$), this function
     ///   underflows to negative zero if `rm` is `Ceiling` or `Down`, rounds down to $-2^{-2^{30}}$
-    ///   if `rm` is `Floor` or `Up`, underflows to negative zero if `rm` is `Nearest` and the
-    ///   [`Rational`] rounds to a value greater than or equal to $-2^{-2^{30}-1}$, and rounds down
-    ///   to $-2^{-2^{30}}$ if `rm` is `Nearest` and the [`Rational`] rounds to a value less than
-    ///   $-2^{-2^{30}-1}$.
+    ///   if `rm` is `Floor`, `Up`, or `Odd`, underflows to negative zero if `rm` is `Nearest` and
+    ///   the [`Rational`] rounds to a value greater than or equal to $-2^{-2^{30}-1}$, and rounds
+    ///   down to $-2^{-2^{30}}$ if `rm` is `Nearest` and the [`Rational`] rounds to a value less
+    ///   than $-2^{-2^{30}-1}$; `NearestTiesAway` behaves like `Nearest`, except that it rounds
+    ///   down to $-2^{-2^{30}}$ when the [`Rational`] rounds to exactly $-2^{-2^{30}-1}$.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n \log n \log\log n)$
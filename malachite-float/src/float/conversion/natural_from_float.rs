@@ -22,7 +22,8 @@ impl RoundingFrom<Float> for Natural {
     /// is less than, equal to, or greater than the original value.
     ///
     /// If the [`Float`] is negative (including $-\infty$), then it will be rounded to zero when the
-    /// [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+    /// [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise, this
+    /// function will panic.
     ///
     /// If the [`Float`] is NaN or $\infty$, the function will panic regardless of the rounding
     /// mode.
@@ -36,8 +37,8 @@ impl RoundingFrom<Float> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`] is less
-    /// than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, or if the [`Float`] is NaN or
-    /// $\infty$.
+    /// than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, or if the
+    /// [`Float`] is NaN or $\infty$.
     ///
     /// # Examples
     /// ```
@@ -78,7 +79,7 @@ impl RoundingFrom<Float> for Natural {
         match f {
             float_either_zero!() => (Self::ZERO, Equal),
             float_negative_infinity!() => match rm {
-                Ceiling | Down | Nearest => (Self::ZERO, Greater),
+                Ceiling | Down | Nearest | NearestTiesAway => (Self::ZERO, Greater),
                 _ => panic!("Can't convert -Infinity to Natural using {rm}"),
             },
             Float(Finite {
@@ -89,13 +90,13 @@ impl RoundingFrom<Float> for Natural {
             }) => {
                 if !sign {
                     match rm {
-                        Ceiling | Down | Nearest => (Self::ZERO, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (Self::ZERO, Greater),
                         _ => panic!("Cannot convert negative number to Natural using {rm}"),
                     }
                 } else if exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (Self::ZERO, Less),
-                        Ceiling | Up => (Self::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (Self::ZERO, Less),
+                        Ceiling | Up | Odd => (Self::ONE, Greater),
                         Exact => panic!("Cannot convert Float to Natural using {rm}"),
                     }
                 } else {
@@ -119,7 +120,8 @@ impl RoundingFrom<&Float> for Natural {
     /// value is less than, equal to, or greater than the original value.
     ///
     /// If the [`Float`] is negative (including $-\infty$), then it will be rounded to zero when the
-    /// [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+    /// [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise, this
+    /// function will panic.
     ///
     /// If the [`Float`] is NaN or $\infty$, the function will panic regardless of the rounding
     /// mode.
@@ -133,8 +135,8 @@ impl RoundingFrom<&Float> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`] is less
-    /// than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, or if the [`Float`] is NaN or
-    /// $\infty$.
+    /// than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, or if the
+    /// [`Float`] is NaN or $\infty$.
     ///
     /// # Examples
     /// ```
@@ -175,7 +177,7 @@ impl RoundingFrom<&Float> for Natural {
         match f {
             float_either_zero!() => (Self::ZERO, Equal),
             float_negative_infinity!() => match rm {
-                Ceiling | Down | Nearest => (Self::ZERO, Greater),
+                Ceiling | Down | Nearest | NearestTiesAway => (Self::ZERO, Greater),
                 _ => panic!("Can't convert -Infinity to Natural using {rm}"),
             },
             Float(Finite {
@@ -186,13 +188,13 @@ impl RoundingFrom<&Float> for Natural {
             }) => {
                 if !sign {
                     match rm {
-                        Ceiling | Down | Nearest => (Self::ZERO, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (Self::ZERO, Greater),
                         _ => panic!("Cannot convert -Infinity to Natural using {rm}"),
                     }
                 } else if *exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (Self::ZERO, Less),
-                        Ceiling | Up => (Self::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (Self::ZERO, Less),
+                        Ceiling | Up | Odd => (Self::ONE, Greater),
                         Exact => panic!("Cannot convert Float to Natural using {rm}"),
                     }
                 } else {
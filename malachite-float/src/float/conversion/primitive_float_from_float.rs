@@ -37,9 +37,11 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                 if exponent < T::MIN_EXPONENT {
                     match abs_rm {
                         Floor | Down => (T::ZERO, Less),
-                        Ceiling | Up => (T::MIN_POSITIVE_SUBNORMAL, Greater),
-                        Nearest => {
-                            if exponent == T::MIN_EXPONENT - 1 && !significand.is_power_of_2() {
+                        Ceiling | Up | Odd => (T::MIN_POSITIVE_SUBNORMAL, Greater),
+                        Nearest | NearestTiesAway => {
+                            if exponent == T::MIN_EXPONENT - 1
+                                && (abs_rm == NearestTiesAway || !significand.is_power_of_2())
+                            {
                                 (T::MIN_POSITIVE_SUBNORMAL, Greater)
                             } else {
                                 (T::ZERO, Less)
@@ -49,8 +51,8 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                     }
                 } else if exponent > T::MAX_EXPONENT {
                     match abs_rm {
-                        Floor | Down => (T::MAX_FINITE, Less),
-                        Ceiling | Up | Nearest => (T::INFINITY, Greater),
+                        Floor | Down | Odd => (T::MAX_FINITE, Less),
+                        Ceiling | Up | Nearest | NearestTiesAway => (T::INFINITY, Greater),
                         Exact => panic!("Float too large for exact conversion"),
                     }
                 } else {
@@ -62,8 +64,8 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                     if mantissa.significant_bits() > target_prec {
                         if exponent == T::MAX_EXPONENT {
                             match abs_rm {
-                                Floor | Down => (T::MAX_FINITE, Less),
-                                Ceiling | Up | Nearest => (T::INFINITY, Greater),
+                                Floor | Down | Odd => (T::MAX_FINITE, Less),
+                                Ceiling | Up | Nearest | NearestTiesAway => (T::INFINITY, Greater),
                                 Exact => {
                                     panic!("Float too large for exact conversion")
                                 }
@@ -117,9 +119,11 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                 if exponent < T::MIN_EXPONENT {
                     match abs_rm {
                         Floor | Down => (T::ZERO, Less),
-                        Ceiling | Up => (T::MIN_POSITIVE_SUBNORMAL, Greater),
-                        Nearest => {
-                            if exponent == T::MIN_EXPONENT - 1 && !significand.is_power_of_2() {
+                        Ceiling | Up | Odd => (T::MIN_POSITIVE_SUBNORMAL, Greater),
+                        Nearest | NearestTiesAway => {
+                            if exponent == T::MIN_EXPONENT - 1
+                                && (abs_rm == NearestTiesAway || !significand.is_power_of_2())
+                            {
                                 (T::MIN_POSITIVE_SUBNORMAL, Greater)
                             } else {
                                 (T::ZERO, Less)
@@ -129,8 +133,8 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                     }
                 } else if exponent > T::MAX_EXPONENT {
                     match abs_rm {
-                        Floor | Down => (T::MAX_FINITE, Less),
-                        Ceiling | Up | Nearest => (T::INFINITY, Greater),
+                        Floor | Down | Odd => (T::MAX_FINITE, Less),
+                        Ceiling | Up | Nearest | NearestTiesAway => (T::INFINITY, Greater),
                         Exact => panic!("Float too large for exact conversion"),
                     }
                 } else {
@@ -142,8 +146,8 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                     if mantissa.significant_bits() > target_prec {
                         if exponent == T::MAX_EXPONENT {
                             match abs_rm {
-                                Floor | Down => (T::MAX_FINITE, Less),
-                                Ceiling | Up | Nearest => (T::INFINITY, Greater),
+                                Floor | Down | Odd => (T::MAX_FINITE, Less),
+                                Ceiling | Up | Nearest | NearestTiesAway => (T::INFINITY, Greater),
 
                                 Exact => {
                                     panic!("Float too large for exact conversion")
@@ -321,6 +325,17 @@ macro_rules! impl_primitive_float_from {
             ///   is returned. If the [`Float`] is closer to zero than to any other primitive float
             ///   (or if there is a tie between zero and another float), then positive or negative
             ///   zero is returned, depending on the [`Float`]'s sign.
+            /// - If the rounding mode is `NearestTiesAway`, then the rounding proceeds as with
+            ///   `Nearest`, except that if the [`Float`] is exactly between two primitive floats,
+            ///   the one with the larger absolute value is selected. In particular, a tie between
+            ///   zero and another float is resolved in favor of the nonzero float.
+            /// - If the rounding mode is `Odd`, then the rounding proceeds as with `Down`, except
+            ///   that if the result is inexact, the primitive float with the one least-significant
+            ///   bit in its representation is selected. If the [`Float`] is greater than the
+            ///   maximum finite primitive float, then the maximum finite primitive float is
+            ///   returned, and if it is between zero and the minimum positive primitive float, then
+            ///   the minimum positive primitive float is returned (and similarly for negative
+            ///   [`Float`]s).
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n)$
@@ -378,6 +393,17 @@ macro_rules! impl_primitive_float_from {
             ///   is returned. If the [`Float`] is closer to zero than to any other primitive float
             ///   (or if there is a tie between zero and another float), then positive or negative
             ///   zero is returned, depending on the [`Float`]'s sign.
+            /// - If the rounding mode is `NearestTiesAway`, then the rounding proceeds as with
+            ///   `Nearest`, except that if the [`Float`] is exactly between two primitive floats,
+            ///   the one with the larger absolute value is selected. In particular, a tie between
+            ///   zero and another float is resolved in favor of the nonzero float.
+            /// - If the rounding mode is `Odd`, then the rounding proceeds as with `Down`, except
+            ///   that if the result is inexact, the primitive float with the one least-significant
+            ///   bit in its representation is selected. If the [`Float`] is greater than the
+            ///   maximum finite primitive float, then the maximum finite primitive float is
+            ///   returned, and if it is between zero and the minimum positive primitive float, then
+            ///   the minimum positive primitive float is returned (and similarly for negative
+            ///   [`Float`]s).
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n)$
@@ -27,11 +27,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::ZERO, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -44,20 +44,20 @@ where
             let exponent = i64::from(exponent);
             if !sign {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
                     _ => panic!("Cannot convert negative Float to {} using {}", T::NAME, rm),
                 }
             } else if exponent < 0 {
                 match rm {
-                    Floor | Down | Nearest => (T::ZERO, Less),
-                    Ceiling | Up => (T::ONE, Greater),
+                    Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                    Ceiling | Up | Odd => (T::ONE, Greater),
                     Exact => {
                         panic!("Cannot convert Float to {} using {}", T::NAME, rm)
                     }
                 }
             } else if exponent > i64::wrapping_from(T::WIDTH) {
                 match rm {
-                    Floor | Down | Nearest => (T::MAX, Less),
+                    Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                     _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                 }
             } else {
@@ -72,7 +72,7 @@ where
                     (n, o)
                 } else {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                         _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                     }
                 }
@@ -92,11 +92,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::ZERO, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -109,20 +109,20 @@ where
             let exponent = i64::from(*exponent);
             if !sign {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
                     _ => panic!("Cannot convert negative Float to {} using {}", T::NAME, rm),
                 }
             } else if exponent < 0 {
                 match rm {
-                    Floor | Down | Nearest => (T::ZERO, Less),
-                    Ceiling | Up => (T::ONE, Greater),
+                    Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                    Ceiling | Up | Odd => (T::ONE, Greater),
                     Exact => {
                         panic!("Cannot convert Float to {} using {}", T::NAME, rm)
                     }
                 }
             } else if exponent > i64::wrapping_from(T::WIDTH) {
                 match rm {
-                    Floor | Down | Nearest => (T::MAX, Less),
+                    Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                     _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                 }
             } else {
@@ -137,7 +137,7 @@ where
                     (n, o)
                 } else {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                         _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                     }
                 }
@@ -251,13 +251,13 @@ macro_rules! impl_unsigned_from {
             /// than the original value.
             ///
             /// If the [`Float`] is negative (including $-\infty$), then it will be rounded to zero
-            /// when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this
-            /// function will panic.
+            /// when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the unsigned
             /// type (including $\infty$), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `Odd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -271,9 +271,10 @@ macro_rules! impl_unsigned_from {
             ///
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
-            /// is less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, if the [`Float`]
-            /// is greater than the maximum representable value of the unsigned type and `rm` is not
-            /// `Down`, `Floor`, or `Nearest`, or if the [`Float`] is NaN.
+            /// is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or
+            /// `NearestTiesAway`, if the [`Float`] is greater than the maximum representable
+            /// value of the unsigned type and `rm` is not `Down`, `Floor`, `Nearest`,
+            /// `NearestTiesAway`, or `Odd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -290,13 +291,13 @@ macro_rules! impl_unsigned_from {
             /// than the original value.
             ///
             /// If the [`Float`] is negative (including $-\infty$), then it will be rounded to zero
-            /// when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this
-            /// function will panic.
+            /// when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the unsigned
             /// type (including $\infty$), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `Odd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -310,9 +311,10 @@ macro_rules! impl_unsigned_from {
             ///
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
-            /// is less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, if the [`Float`]
-            /// is greater than the maximum representable value of the unsigned type and `rm` is not
-            /// `Down`, `Floor`, or `Nearest`, or if the [`Float`] is NaN.
+            /// is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or
+            /// `NearestTiesAway`, if the [`Float`] is greater than the maximum representable
+            /// value of the unsigned type and `rm` is not `Down`, `Floor`, `Nearest`,
+            /// `NearestTiesAway`, or `Odd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -407,11 +409,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::MIN, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -425,15 +427,15 @@ where
             if sign {
                 if exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (T::ZERO, Less),
-                        Ceiling | Up => (T::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                        Ceiling | Up | Odd => (T::ONE, Greater),
                         Exact => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
                     }
                 } else if exponent >= i64::wrapping_from(T::WIDTH) {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                         _ => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
@@ -450,7 +452,7 @@ where
                         (n, o)
                     } else {
                         match rm {
-                            Floor | Down | Nearest => (T::MAX, Less),
+                            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                             _ => {
                                 panic!("Cannot convert large Float to {} using {}", T::NAME, rm)
                             }
@@ -459,8 +461,8 @@ where
                 }
             } else if exponent < 0 {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
-                    Floor | Up => (T::NEGATIVE_ONE, Less),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
+                    Floor | Up | Odd => (T::NEGATIVE_ONE, Less),
                     Exact => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -469,7 +471,7 @@ where
                 // This doesn't catch the case where -2^(W+1) < x < -2^W, but that's ok because the
                 // next else block handles it.
                 match rm {
-                    Ceiling | Down | Nearest => (T::MIN, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                     _ => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -486,7 +488,7 @@ where
                     (n, o.reverse())
                 } else {
                     match rm {
-                        Ceiling | Down | Nearest => (T::MIN, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                         _ => panic!(
                             "Cannot convert large negative Float to {} using {}",
                             T::NAME,
@@ -507,11 +509,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::MIN, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -525,15 +527,15 @@ where
             if *sign {
                 if exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (T::ZERO, Less),
-                        Ceiling | Up => (T::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                        Ceiling | Up | Odd => (T::ONE, Greater),
                         Exact => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
                     }
                 } else if exponent >= i64::wrapping_from(T::WIDTH) {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                         _ => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
@@ -550,7 +552,7 @@ where
                         (n, o)
                     } else {
                         match rm {
-                            Floor | Down | Nearest => (T::MAX, Less),
+                            Floor | Down | Nearest | NearestTiesAway | Odd => (T::MAX, Less),
                             _ => {
                                 panic!("Cannot convert large Float to {} using {}", T::NAME, rm)
                             }
@@ -559,8 +561,8 @@ where
                 }
             } else if exponent < 0 {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
-                    Floor | Up => (T::NEGATIVE_ONE, Less),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
+                    Floor | Up | Odd => (T::NEGATIVE_ONE, Less),
                     Exact => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -569,7 +571,7 @@ where
                 // This doesn't catch the case where -2^(W+1) < x < -2^W, but that's ok because the
                 // next else block handles it.
                 match rm {
-                    Ceiling | Down | Nearest => (T::MIN, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                     _ => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -586,7 +588,7 @@ where
                     (n, o.reverse())
                 } else {
                     match rm {
-                        Ceiling | Down | Nearest => (T::MIN, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                         _ => panic!(
                             "Cannot convert large negative Float to {} using {}",
                             T::NAME,
@@ -717,12 +719,13 @@ macro_rules! impl_signed_from {
             ///
             /// If the [`Float`] is less than the minimum representable value of the signed type
             /// (including $-\infty$), then it will be rounded to zero when the [`RoundingMode`] is
-            /// `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+            /// `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise, this function will
+            /// panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the signed type
             /// (including $\infty$), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `Odd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -737,9 +740,9 @@ macro_rules! impl_signed_from {
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
             /// is smaller than the minimum representable value of the signed type and `rm` is not
-            /// `Down`, `Ceiling`, or `Nearest`, if the [`Float`] is greater than the maximum
-            /// representable value of the signed type and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`, or if the [`Float`] is NaN.
+            /// `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, if the [`Float`] is greater than
+            /// the maximum representable value of the signed type and `rm` is not `Down`,
+            /// `Floor`, `Nearest`, `NearestTiesAway`, or `Odd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -757,12 +760,13 @@ macro_rules! impl_signed_from {
             ///
             /// If the [`Float`] is less than the minimum representable value of the signed type
             /// (including $-\infty$), then it will be rounded to zero when the [`RoundingMode`] is
-            /// `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+            /// `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise, this function will
+            /// panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the signed type
             /// (including $\infty$), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `Odd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -777,9 +781,9 @@ macro_rules! impl_signed_from {
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
             /// is smaller than the minimum representable value of the signed type and `rm` is not
-            /// `Down`, `Ceiling`, or `Nearest`, if the [`Float`] is greater than the maximum
-            /// representable value of the signed type and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`, or if the [`Float`] is NaN.
+            /// `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, if the [`Float`] is greater than
+            /// the maximum representable value of the signed type and `rm` is not `Down`,
+            /// `Floor`, `Nearest`, `NearestTiesAway`, or `Odd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
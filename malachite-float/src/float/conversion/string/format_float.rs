@@ -60,7 +60,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::Ordering::{Equal, Greater, Less};
 use malachite_base::fail_on_untested_path;
-use malachite_base::num::arithmetic::traits::SaturatingNegAssign;
+use malachite_base::num::arithmetic::traits::{Parity, SaturatingNegAssign};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, OneHalf};
 use malachite_base::num::comparison::traits::PartialOrdAbs;
@@ -68,7 +68,7 @@ use malachite_base::num::conversion::string::to_string::digit_to_display_byte_lo
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode::{
-    self, Ceiling, Down, Exact, Floor, Nearest, Up,
+    self, Ceiling, Down, Exact, Floor, Nearest, NearestTiesAway, Odd, Up,
 };
 use malachite_base::strings::gmp_format::{GmpConversionSpec, GmpFormatArg};
 use malachite_nz::natural::Natural;
@@ -541,9 +541,11 @@ fn regular_fg(
             let round_up = match spec.rnd_mode {
                 Floor => p.is_sign_negative(),
                 Ceiling => p.is_sign_positive(),
-                Up => true,
+                // 1 is the odd option
+                Up | Odd => true,
                 // note that 0.5 rounds to 0 with Nearest (round ties to even)
                 Nearest => p.partial_cmp_abs(&Float::ONE_HALF).unwrap() == Greater,
+                NearestTiesAway => p.partial_cmp_abs(&Float::ONE_HALF).unwrap() != Less,
                 _ => false,
             };
             if round_up {
@@ -558,7 +560,7 @@ fn regular_fg(
                 // (0 or 10^-prec in absolute value) is never |p| itself, so this
                 // `get_str`-bypassing branch is always inexact and must reject `Exact`.
                 let round_away = match spec.rnd_mode {
-                    Up => true,
+                    Up | Odd => true,
                     Down => false,
                     Floor => p.is_sign_negative(),
                     Ceiling => p.is_sign_positive(),
@@ -567,7 +569,9 @@ fn regular_fg(
                         representable with {} fractional digits",
                         spec.prec
                     ),
-                    Nearest => {
+                    // 0.5 * 10^(-spec.prec) is not dyadic, so |p| is never exactly halfway and
+                    // the two nearest modes agree
+                    Nearest | NearestTiesAway => {
                         // compare |p| to y = 0.5 * 10^(-spec.prec), increasing the precision of y
                         // until it differs from |p| so that the comparison is decisive
                         let mut e = p.get_prec().unwrap().max(56);
@@ -729,7 +733,9 @@ fn one_digit_is_inexact(sig: &Natural, nbits: u64) -> bool {
 fn next_base_power_p(x: &Float, base: i64, rnd: RoundingMode) -> bool {
     // the decimal point is after the first digit in this representation
     let nbits: u64 = if base == 2 { 1 } else { 4 };
+    // with `Odd`, the all-ones digit is already odd, so it is kept
     if rnd == Down
+        || rnd == Odd
         || (rnd == Floor && x.is_sign_positive())
         || (rnd == Ceiling && x.is_sign_negative())
         || x.get_prec().unwrap() <= nbits
@@ -746,8 +752,9 @@ fn next_base_power_p(x: &Float, base: i64, rnd: RoundingMode) -> bool {
         // don't round up if some of the first nbits bits are 0
         return false;
     }
-    if rnd == Nearest {
-        // round up if the rounding bit is 1
+    if rnd == Nearest || rnd == NearestTiesAway {
+        // round up if the rounding bit is 1 (a tie rounds up in both modes, since the all-ones
+        // digit is odd)
         xm.get_bit(Limb::WIDTH - nbits - 1)
     } else {
         // an away-from-zero-like rounding mode: round up if any remaining bit is 1
@@ -804,7 +811,9 @@ fn regular_ab(np: &mut NumberParts, p: &Float, spec: &PrintfSpec) -> Option<()>
             // all-ones nibble with remaining bits always lands in `next_base_power_p` first, so
             // digit <= 15 here.
             if (is_like_rnda(spec.rnd_mode, p.is_sign_negative()) && one_digit_is_inexact(sig, 4))
-                || (spec.rnd_mode == Nearest && (msl & const { Limb::ONE << RND_BIT }) != 0)
+                || ((spec.rnd_mode == Nearest || spec.rnd_mode == NearestTiesAway)
+                    && (msl & const { Limb::ONE << RND_BIT }) != 0)
+                || (spec.rnd_mode == Odd && digit.even() && one_digit_is_inexact(sig, 4))
             {
                 digit += 1;
             }
@@ -1399,8 +1408,8 @@ crate_test_fn! {format_mpfr_str(fmt: &[u8], args: &[PrintfArg]) -> Option<Vec<u8
 ///   their hexadecimal/binary analogues) or the number of significant digits (for `g`); it defaults
 ///   to 6.
 /// - **`R`**: marks the argument as a [`Float`] (MPFR's length modifier).
-/// - **rounding**: an optional MPFR rounding character — `N` (to nearest, the default), `D`
-///   (toward $-\infty$), `U` (toward $+\infty$), `Y` (away from zero), or `Z` (toward zero).
+/// - **rounding**: an optional MPFR rounding character — `N` (to nearest, the default), `D` (toward
+///   $-\infty$), `U` (toward $+\infty$), `Y` (away from zero), or `Z` (toward zero).
 /// - **conv**: the conversion — `e`/`E` (scientific), `f`/`F` (fixed-point), `g`/`G` (general),
 ///   `a`/`A` (hexadecimal significand with a binary exponent), or `b` (binary significand with a
 ///   binary exponent).
@@ -27,24 +27,28 @@ use malachite_nz::natural::arithmetic::float::set_str::{SetStrResult, limbs_set_
 // This is `mpfr_overflow` from `exceptions.c`, MPFR 4.3.0.
 pub(crate) fn overflow(sign: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
     match (sign, rm) {
-        (true, Up | Ceiling | Nearest) => (Float::INFINITY, Greater),
-        (true, Floor | Down) => (Float::max_finite_value_with_prec(prec), Less),
-        (false, Up | Floor | Nearest) => (Float::NEGATIVE_INFINITY, Less),
-        (false, Ceiling | Down) => (-Float::max_finite_value_with_prec(prec), Greater),
+        (true, Up | Ceiling | Nearest | NearestTiesAway) => (Float::INFINITY, Greater),
+        (true, Floor | Down | Odd) => (Float::max_finite_value_with_prec(prec), Less),
+        (false, Up | Floor | Nearest | NearestTiesAway) => (Float::NEGATIVE_INFINITY, Less),
+        (false, Ceiling | Down | Odd) => (-Float::max_finite_value_with_prec(prec), Greater),
         (_, Exact) => panic!("Inexact conversion from string to Float"),
     }
 }
 
 // The value is smaller in magnitude than the least positive `Float` of precision `prec`. Under
-// `Nearest` the result is the least positive value; the caller substitutes `Down` in the cases
-// where `mpfr_check_range` does.
+// `Nearest`, `NearestTiesAway`, and `Odd` the result is the least positive value; the caller
+// substitutes `Down` in the cases where `mpfr_check_range` does.
 //
 // This is `mpfr_underflow` from `exceptions.c`, MPFR 4.3.0.
 fn underflow(sign: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
     match (sign, rm) {
-        (true, Up | Ceiling | Nearest) => (Float::min_positive_value_prec(prec), Greater),
+        (true, Up | Ceiling | Nearest | NearestTiesAway | Odd) => {
+            (Float::min_positive_value_prec(prec), Greater)
+        }
         (true, Floor | Down) => (Float::ZERO, Less),
-        (false, Up | Floor | Nearest) => (-Float::min_positive_value_prec(prec), Less),
+        (false, Up | Floor | Nearest | NearestTiesAway | Odd) => {
+            (-Float::min_positive_value_prec(prec), Less)
+        }
         (false, Ceiling | Down) => (Float::NEGATIVE_ZERO, Greater),
         (_, Exact) => panic!("Inexact conversion from string to Float"),
     }
@@ -76,7 +80,15 @@ set_str_helper(
     let away_from_0 = if sign { Greater } else { Less };
     match limbs_set_str(digits, u64::from(base), exp_base, exp_bin, prec, mag_rm) {
         SetStrResult::Overflow => overflow(sign, prec, rm),
-        SetStrResult::Underflow => underflow(sign, prec, if rm == Nearest { Down } else { rm }),
+        SetStrResult::Underflow => underflow(
+            sign,
+            prec,
+            if rm == Nearest || rm == NearestTiesAway {
+                Down
+            } else {
+                rm
+            },
+        ),
         SetStrResult::Finite(significand, exp, dir) => {
             // `round_helper_raw` rounds under `Exact` as if it were `Down` rather than complaining,
             // so the contract is enforced here, as it is at the other call sites.
@@ -94,10 +106,13 @@ set_str_helper(
                 // Under `Nearest` the result rounds away from zero, to the least positive value,
                 // only when it is at least half of it: the exponent must be exactly one below the
                 // minimum, and at exactly half (a power of two) the discarded part must have been
-                // nonzero.
-                let rm = if rm == Nearest
+                // nonzero. Under `NearestTiesAway`, exactly half is enough, so the significand
+                // must only not have been rounded up to it.
+                let rm = if (rm == Nearest || rm == NearestTiesAway)
                     && !(exp == Float::MIN_EXPONENT_MINUS_1_I64
-                        && (o == away_from_0.reverse() || !significand.is_power_of_2()))
+                        && (o == away_from_0.reverse()
+                            || (rm == NearestTiesAway && o == Equal)
+                            || !significand.is_power_of_2()))
                 {
                     Down
                 } else {
@@ -160,7 +160,7 @@ crate_test_fn! {
 // necessary.
 //
 // The digits are computed by `get_str`, which rounds the value directly, so this function never
-// materializes the `Float` as a `Rational` (except in one corner case: deciding a `Nearest` tie
+// materializes the `Float` as a `Rational` (except in one corner case: deciding a tie in a nearest mode
 // when the value's magnitude lies within one base-power of a `Scale` boundary).
 //
 // Panics if the rounding mode is `Exact` but the size options are such that the input must be
@@ -203,7 +203,8 @@ to_sci_string(x: &Float, options: ToSciOptions) -> String {
     let (digits, log) = if precision <= 0 {
         // 0 < |x| * base^scale < 1: the value rounds to 0 or to 1 in the last place.
         let round_up_to_one = match rm {
-            Up => true,
+            // 1 is the odd option
+            Up | Odd => true,
             Down => false,
             Floor => neg,
             Ceiling => !neg,
@@ -213,11 +214,12 @@ to_sci_string(x: &Float, options: ToSciOptions) -> String {
             ),
             // |x| < base^(log + 1) <= base^(-scale); it rounds up iff it exceeds base^-scale / 2,
             // which requires log + 1 == -scale (one base-power below the boundary and it is already
-            // at most half). A tie rounds to the even option, 0.
-            Nearest => {
+            // at most half). A tie rounds to the even option, 0, or, with `NearestTiesAway`, to 1.
+            Nearest | NearestTiesAway => {
                 log + 1 == -scale && {
                     let two_x = Rational::exact_from(x).abs() << 1u64;
-                    two_x > Rational::from(base).pow(-scale)
+                    let half_cmp = two_x.cmp(&Rational::from(base).pow(-scale));
+                    half_cmp == Greater || rm == NearestTiesAway && half_cmp == Equal
                 }
             }
         };
@@ -11,11 +11,12 @@ use crate::InnerFloat::Finite;
 use alloc::string::String;
 use core::cmp::Ordering::{self, *};
 use core::ops::Deref;
+use malachite_base::num::arithmetic::traits::DivRound;
 #[cfg(feature = "test_build")]
 use malachite_base::num::arithmetic::traits::DivisibleByPowerOf2;
 use malachite_base::num::basic::integers::PrimitiveInt;
-#[cfg(feature = "test_build")]
 use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
 
@@ -123,6 +124,17 @@ pub(crate) fn floor_and_ceiling((floor, o): (Float, Ordering)) -> (Float, Float)
     (floor, ceiling)
 }
 
+// Returns the floor and ceiling of `n / d` at precision `prec`, where `d` is nonzero. The integer
+// quotient is taken after shifting `n` left far enough that it has more than `prec` bits, so rounding
+// it down gives the floor of the exact quotient.
+pub(crate) fn quotient_floor_and_ceiling(n: &Natural, d: &Natural, prec: u64) -> (Float, Float) {
+    let shift = (prec + 1 + d.significant_bits()).saturating_sub(n.significant_bits());
+    let (q, o) = (n << shift).div_round(d, Floor);
+    let (f, o_f) = Float::from_natural_prec_round(q, prec, Floor);
+    let (lo, hi) = floor_and_ceiling((f, if o == Equal { o_f } else { Less }));
+    (lo >> shift, hi >> shift)
+}
+
 // `Limb::WIDTH`-derived bit counts, shared across the crate so each is written out only once.
 pub(crate) const WIDTH_MINUS_1: u64 = Limb::WIDTH - 1;
 pub(crate) const TWICE_WIDTH: u64 = Limb::WIDTH << 1;
@@ -13,7 +13,7 @@ use malachite_base::bools::random::{
 };
 use malachite_base::iterators::{WithSpecialValues, with_special_values};
 use malachite_base::num::arithmetic::traits::{
-    DivRound, IsPowerOf2, ModPowerOf2, NegAssign, NegModPowerOf2, PowerOf2,
+    DivRound, IsPowerOf2, ModPowerOf2, NegAssign, NegModPowerOf2, Parity, PowerOf2,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{
@@ -1898,7 +1898,10 @@ impl<I: Iterator<Item = u64>> Iterator for UniformRandomNonNegativeFloatsAtMostO
         let up = match self.rm {
             Ceiling | Up => true,
             Floor | Down => false,
-            Nearest => rbit != 0,
+            // ties have measure zero, so the two nearest modes agree
+            Nearest | NearestTiesAway => rbit != 0,
+            // the exact value is never representable, so it is rounded to the odd neighbor
+            Odd => mantissa.even(),
             // the constructor rejects Exact
             Exact => unreachable!(),
         };
@@ -1913,12 +1916,12 @@ impl<I: Iterator<Item = u64>> Iterator for UniformRandomNonNegativeFloatsAtMostO
         // Underflow handling, as in mpfr_check_range: unreachable by sampling, since reaching it
         // requires on the order of 2^27 consecutive all-zero 8-bit blocks.
         if exponent < Float::MIN_EXPONENT_I64 {
-            // In the Nearest mode, round toward zero if the value is below half of the minimum
+            // In the nearest modes, round toward zero if the value is below half of the minimum
             // positive Float, or equal to that half with the exact value below it.
             let down = match self.rm {
                 Floor | Down => true,
-                Ceiling | Up => false,
-                Nearest => {
+                Ceiling | Up | Odd => false,
+                Nearest | NearestTiesAway => {
                     exponent < Float::MIN_EXPONENT_MINUS_1_I64 || up && mantissa.is_power_of_2()
                 }
                 Exact => unreachable!(),
@@ -1978,10 +1981,10 @@ crate_test_fn! {
 /// The distribution is that of a continuous uniform random variable on the unit interval, correctly
 /// rounded: each output is a precision-`prec` [`Float`], and the probability of any output equals
 /// the measure of the set of real numbers that round to it. Every output has precision `prec`. The
-/// rounded value can be $1$ (under `Ceiling`, `Up`, or `Nearest`), but under `Floor` or `Down` it
-/// is always less than $1$. It can be $0$ only via underflow, which requires the drawn exponent to
-/// fall below the minimum exponent; this is unreachable in practice, since its probability is
-/// roughly $2^{-2^{30}}$.
+/// rounded value can be $1$ (under `Ceiling`, `Up`, `Nearest`, or `NearestTiesAway`), but under
+/// `Floor`, `Down`, or `Odd` it is always less than $1$. It can be $0$ only via underflow, which
+/// requires the drawn exponent to fall below the minimum exponent; this is unreachable in practice,
+/// since its probability is roughly $2^{-2^{30}}$.
 ///
 /// This function samples the same distribution as `mpfr_urandom`, and consumes randomness from the
 /// underlying stream in the same pattern, including the fact that the amount consumed depends on
@@ -2190,7 +2193,7 @@ fn random_deviate_less<I: Iterator<Item = u64>>(
 // many more bits of x as the precision requires. This is mpfr_random_deviate_value, with the sign
 // applied before the rounding, since the directed rounding modes do not commute with negation. The
 // trailing bit of the assembled integer is set, so the result is always inexact, and there are
-// never ties to break in the Nearest mode.
+// never ties to break in the nearest modes.
 fn random_deviate_value<I: Iterator<Item = u64>>(
     neg: bool,
     n: u64,
@@ -0,0 +1,310 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::{Interval, from_rounded_endpoints};
+use core::cmp::max;
+use core::ops::{Add, AddAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn add_helper(x: &Interval, y: &Interval, prec: u64) -> Interval {
+    from_rounded_endpoints(
+        x.lower.add_prec_round_ref_ref(&y.lower, prec, Floor).0,
+        x.upper.add_prec_round_ref_ref(&y.upper, prec, Ceiling).0,
+    )
+}
+
+impl Interval {
+    /// Adds two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by value.
+    ///
+    /// The result contains $x + y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(a + c), \Delta_p(b + d)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.clone().add_prec(y.clone(), 5).to_string(), "[1.81, 2.88]");
+    /// assert_eq!(x.add_prec(y, 20).to_string(), "[1.8330078, 2.8334961]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn add_prec(self, other: Self, prec: u64) -> Self {
+        add_helper(&self, &other, prec)
+    }
+
+    /// Adds two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $x + y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(a + c), \Delta_p(b + d)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.add_prec_ref_ref(&y, 5).to_string(), "[1.81, 2.88]");
+    /// assert_eq!(x.add_prec_ref_ref(&y, 20).to_string(), "[1.8330078, 2.8334961]");
+    /// ```
+    #[inline]
+    pub fn add_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        add_helper(self, other, prec)
+    }
+}
+
+impl Add<Self> for Interval {
+    type Output = Self;
+
+    /// Adds two [`Interval`]s, rounding the endpoints of the result outward to the precision of the
+    /// inputs (the largest precision among their endpoints). Both [`Interval`]s are taken by value.
+    ///
+    /// See [`Interval::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((x + y).to_string(), "[1.8320, 2.8359]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        add_helper(&self, &other, prec)
+    }
+}
+
+impl Add<&Self> for Interval {
+    type Output = Self;
+
+    /// Adds two [`Interval`]s, rounding the endpoints of the result outward to the precision of the
+    /// inputs (the largest precision among their endpoints). The first [`Interval`] is taken by
+    /// value and the second by reference.
+    ///
+    /// See [`Interval::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((x + &y).to_string(), "[1.8320, 2.8359]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        add_helper(&self, other, prec)
+    }
+}
+
+impl Add<Interval> for &Interval {
+    type Output = Interval;
+
+    /// Adds two [`Interval`]s, rounding the endpoints of the result outward to the precision of the
+    /// inputs (the largest precision among their endpoints). The first [`Interval`] is taken by
+    /// reference and the second by value.
+    ///
+    /// See [`Interval::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((&x + y).to_string(), "[1.8320, 2.8359]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn add(self, other: Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        add_helper(self, &other, prec)
+    }
+}
+
+impl Add<&Interval> for &Interval {
+    type Output = Interval;
+
+    /// Adds two [`Interval`]s, rounding the endpoints of the result outward to the precision of the
+    /// inputs (the largest precision among their endpoints). Both [`Interval`]s are taken by
+    /// reference.
+    ///
+    /// See [`Interval::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((&x + &y).to_string(), "[1.8320, 2.8359]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn add(self, other: &Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        add_helper(self, other, prec)
+    }
+}
+
+impl AddAssign<Self> for Interval {
+    /// Adds an [`Interval`] to an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the inputs (the largest precision among their endpoints). The
+    /// [`Interval`] on the right-hand side is taken by value.
+    ///
+    /// See [`Interval::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// let mut z = x.clone();
+    /// z += y;
+    /// assert_eq!(z.to_string(), "[1.8320, 2.8359]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = add_helper(self, &other, prec);
+    }
+}
+
+impl AddAssign<&Self> for Interval {
+    /// Adds an [`Interval`] to an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the inputs (the largest precision among their endpoints). The
+    /// [`Interval`] on the right-hand side is taken by reference.
+    ///
+    /// See [`Interval::add_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// let mut z = x.clone();
+    /// z += &y;
+    /// assert_eq!(z.to_string(), "[1.8320, 2.8359]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = add_helper(self, other, prec);
+    }
+}
@@ -0,0 +1,350 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::Interval;
+use crate::interval::arithmetic::corner_hull;
+use core::cmp::max;
+use core::ops::{Div, DivAssign};
+use malachite_base::num::basic::traits::{NegativeZero, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+
+fn div_helper(x: &Interval, y: &Interval, prec: u64) -> Interval {
+    if y.lower < 0u32 && y.upper > 0u32 || y.lower == 0u32 && y.upper == 0u32 {
+        return Interval::ENTIRE;
+    }
+    // Give a zero endpoint of the divisor the sign of the divisor's other elements, so that
+    // dividing by it produces an infinity of the right sign
+    let lower_zero = y.lower == 0u32;
+    let upper_zero = y.upper == 0u32;
+    if lower_zero || upper_zero {
+        let y = Interval {
+            lower: if lower_zero {
+                Float::ZERO
+            } else {
+                y.lower.clone()
+            },
+            upper: if upper_zero {
+                Float::NEGATIVE_ZERO
+            } else {
+                y.upper.clone()
+            },
+        };
+        corner_hull(x, &y, false, |a, b, rm| {
+            a.div_prec_round_ref_ref(b, prec, rm).0
+        })
+    } else {
+        corner_hull(x, y, false, |a, b, rm| {
+            a.div_prec_round_ref_ref(b, prec, rm).0
+        })
+    }
+    .unwrap_or(Interval::ENTIRE)
+}
+
+impl Interval {
+    /// Divides two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by value.
+    ///
+    /// The result contains $x / y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(\min Q), \Delta_p(\max Q)],
+    /// $$
+    /// where $Q = \{a/c, a/d, b/c, b/d\}$ and $\nabla_p$ and $\Delta_p$ round down and up to
+    /// precision $p$.
+    ///
+    /// At the corners, a zero endpoint of the divisor is given the sign of the divisor's other
+    /// elements, and indeterminate forms such as $0/0$ are ignored. If the divisor contains zero in
+    /// its interior, or is $[0, 0]$, the quotient is unbounded in both directions and the result is
+    /// [`Interval::ENTIRE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.clone().div_prec(y.clone(), 5).to_string(), "[0.133, 0.227]");
+    /// assert_eq!(x.div_prec(y, 20).to_string(), "[0.13320303, 0.22233081]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn div_prec(self, other: Self, prec: u64) -> Self {
+        div_helper(&self, &other, prec)
+    }
+
+    /// Divides two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $x / y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(\min Q), \Delta_p(\max Q)],
+    /// $$
+    /// where $Q = \{a/c, a/d, b/c, b/d\}$ and $\nabla_p$ and $\Delta_p$ round down and up to
+    /// precision $p$.
+    ///
+    /// At the corners, a zero endpoint of the divisor is given the sign of the divisor's other
+    /// elements, and indeterminate forms such as $0/0$ are ignored. If the divisor contains zero in
+    /// its interior, or is $[0, 0]$, the quotient is unbounded in both directions and the result is
+    /// [`Interval::ENTIRE`].
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.div_prec_ref_ref(&y, 5).to_string(), "[0.133, 0.227]");
+    /// assert_eq!(x.div_prec_ref_ref(&y, 20).to_string(), "[0.13320303, 0.22233081]");
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        div_helper(self, other, prec)
+    }
+}
+
+impl Div<Self> for Interval {
+    type Output = Self;
+
+    /// Divides two [`Interval`]s, rounding the endpoints of the result outward to the precision of
+    /// the inputs (the largest precision among their endpoints). Both [`Interval`]s are taken by
+    /// value.
+    ///
+    /// See [`Interval::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((x / y).to_string(), "[0.13306, 0.22241]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(&self, &other, prec)
+    }
+}
+
+impl Div<&Self> for Interval {
+    type Output = Self;
+
+    /// Divides two [`Interval`]s, rounding the endpoints of the result outward to the precision of
+    /// the inputs (the largest precision among their endpoints). The first [`Interval`] is taken by
+    /// value and the second by reference.
+    ///
+    /// See [`Interval::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((x / &y).to_string(), "[0.13306, 0.22241]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(&self, other, prec)
+    }
+}
+
+impl Div<Interval> for &Interval {
+    type Output = Interval;
+
+    /// Divides two [`Interval`]s, rounding the endpoints of the result outward to the precision of
+    /// the inputs (the largest precision among their endpoints). The first [`Interval`] is taken by
+    /// reference and the second by value.
+    ///
+    /// See [`Interval::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((&x / y).to_string(), "[0.13306, 0.22241]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(self, &other, prec)
+    }
+}
+
+impl Div<&Interval> for &Interval {
+    type Output = Interval;
+
+    /// Divides two [`Interval`]s, rounding the endpoints of the result outward to the precision of
+    /// the inputs (the largest precision among their endpoints). Both [`Interval`]s are taken by
+    /// reference.
+    ///
+    /// See [`Interval::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((&x / &y).to_string(), "[0.13306, 0.22241]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div(self, other: &Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        div_helper(self, other, prec)
+    }
+}
+
+impl DivAssign<Self> for Interval {
+    /// Divides an [`Interval`] by an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the inputs (the largest precision among their endpoints). The
+    /// [`Interval`] on the right-hand side is taken by value.
+    ///
+    /// See [`Interval::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// let mut z = x.clone();
+    /// z /= y;
+    /// assert_eq!(z.to_string(), "[0.13306, 0.22241]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = div_helper(self, &other, prec);
+    }
+}
+
+impl DivAssign<&Self> for Interval {
+    /// Divides an [`Interval`] by an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the inputs (the largest precision among their endpoints). The
+    /// [`Interval`] on the right-hand side is taken by reference.
+    ///
+    /// See [`Interval::div_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// let mut z = x.clone();
+    /// z /= &y;
+    /// assert_eq!(z.to_string(), "[0.13306, 0.22241]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = div_helper(self, other, prec);
+    }
+}
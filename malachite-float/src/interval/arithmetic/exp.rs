@@ -0,0 +1,202 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::{Interval, from_rounded_endpoints};
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn exp_helper(x: &Interval, prec: u64) -> Interval {
+    from_rounded_endpoints(
+        x.lower.exp_prec_round_ref(prec, Floor).0,
+        x.upper.exp_prec_round_ref(prec, Ceiling).0,
+    )
+}
+
+impl Interval {
+    /// Takes the exponential of an [`Interval`], rounding the endpoints of the result outward to
+    /// the specified precision. The [`Interval`] is taken by value.
+    ///
+    /// The result contains the exponential of every element of the [`Interval`]:
+    /// $$
+    /// f([a, b], p) = [\nabla_p(e^a), \Delta_p(e^b)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If an endpoint's exponential is too large to represent, the lower endpoint of the result
+    /// becomes the largest finite [`Float`](crate::Float) of the given precision and the upper
+    /// endpoint becomes $\infty$; if it is too small, the lower endpoint becomes zero and the upper
+    /// endpoint becomes the smallest positive [`Float`](crate::Float) of the given precision.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2));
+    /// assert_eq!(x.clone().exp_prec(5).to_string(), "[2.62, 7.50]");
+    /// assert_eq!(x.exp_prec(20).to_string(), "[2.7182808, 7.3890610]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn exp_prec(self, prec: u64) -> Self {
+        exp_helper(&self, prec)
+    }
+
+    /// Takes the exponential of an [`Interval`], rounding the endpoints of the result outward to
+    /// the specified precision. The [`Interval`] is taken by reference.
+    ///
+    /// The result contains the exponential of every element of the [`Interval`]:
+    /// $$
+    /// f([a, b], p) = [\nabla_p(e^a), \Delta_p(e^b)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If an endpoint's exponential is too large to represent, the lower endpoint of the result
+    /// becomes the largest finite [`Float`](crate::Float) of the given precision and the upper
+    /// endpoint becomes $\infty$; if it is too small, the lower endpoint becomes zero and the upper
+    /// endpoint becomes the smallest positive [`Float`](crate::Float) of the given precision.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2));
+    /// assert_eq!(x.exp_prec_ref(5).to_string(), "[2.62, 7.50]");
+    /// assert_eq!(x.exp_prec_ref(20).to_string(), "[2.7182808, 7.3890610]");
+    /// ```
+    #[inline]
+    pub fn exp_prec_ref(&self, prec: u64) -> Self {
+        exp_helper(self, prec)
+    }
+}
+
+impl Exp for Interval {
+    type Output = Self;
+
+    /// Takes the exponential of an [`Interval`], rounding the endpoints of the result outward to
+    /// the precision of the input (the larger precision of its endpoints). The [`Interval`] is
+    /// taken by value.
+    ///
+    /// See [`Interval::exp_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2));
+    /// assert_eq!(x.exp().to_string(), "[2.0, 8.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn exp(self) -> Self {
+        let prec = self.significant_bits();
+        exp_helper(&self, prec)
+    }
+}
+
+impl Exp for &Interval {
+    type Output = Interval;
+
+    /// Takes the exponential of an [`Interval`], rounding the endpoints of the result outward to
+    /// the precision of the input (the larger precision of its endpoints). The [`Interval`] is
+    /// taken by reference.
+    ///
+    /// See [`Interval::exp_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2));
+    /// assert_eq!((&x).exp().to_string(), "[2.0, 8.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn exp(self) -> Interval {
+        let prec = self.significant_bits();
+        exp_helper(self, prec)
+    }
+}
+
+impl ExpAssign for Interval {
+    /// Takes the exponential of an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the input (the larger precision of its endpoints).
+    ///
+    /// See [`Interval::exp_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpAssign;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Interval::new(Float::from(1), Float::from(2));
+    /// x.exp_assign();
+    /// assert_eq!(x.to_string(), "[2.0, 8.0]");
+    /// ```
+    #[inline]
+    fn exp_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = exp_helper(self, prec);
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::{Interval, from_rounded_endpoints};
+use malachite_base::num::arithmetic::traits::{Ln, LnAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn ln_helper(x: &Interval, prec: u64) -> Interval {
+    assert!(
+        x.lower >= 0u32 && x.upper > 0u32,
+        "Cannot take the logarithm of {x}"
+    );
+    from_rounded_endpoints(
+        x.lower.ln_prec_round_ref(prec, Floor).0,
+        x.upper.ln_prec_round_ref(prec, Ceiling).0,
+    )
+}
+
+impl Interval {
+    /// Takes the natural logarithm of an [`Interval`], rounding the endpoints of the result outward
+    /// to the specified precision. The [`Interval`] is taken by value.
+    ///
+    /// The result contains the natural logarithm of every element of the [`Interval`]:
+    /// $$
+    /// f([a, b], p) = [\nabla_p(\ln a), \Delta_p(\ln b)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If the lower endpoint is zero, the lower endpoint of the result is $-\infty$.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n (\log n)^2 \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the [`Interval`] contains a negative number or is $[0, 0]$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!(x.clone().ln_prec(5).to_string(), "[0.688, 1.12]");
+    /// assert_eq!(x.ln_prec(20).to_string(), "[0.69314671, 1.0986137]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn ln_prec(self, prec: u64) -> Self {
+        ln_helper(&self, prec)
+    }
+
+    /// Takes the natural logarithm of an [`Interval`], rounding the endpoints of the result outward
+    /// to the specified precision. The [`Interval`] is taken by reference.
+    ///
+    /// The result contains the natural logarithm of every element of the [`Interval`]:
+    /// $$
+    /// f([a, b], p) = [\nabla_p(\ln a), \Delta_p(\ln b)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If the lower endpoint is zero, the lower endpoint of the result is $-\infty$.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n (\log n)^2 \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the [`Interval`] contains a negative number or is $[0, 0]$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!(x.ln_prec_ref(5).to_string(), "[0.688, 1.12]");
+    /// assert_eq!(x.ln_prec_ref(20).to_string(), "[0.69314671, 1.0986137]");
+    /// ```
+    #[inline]
+    pub fn ln_prec_ref(&self, prec: u64) -> Self {
+        ln_helper(self, prec)
+    }
+}
+
+impl Ln for Interval {
+    type Output = Self;
+
+    /// Takes the natural logarithm of an [`Interval`], rounding the endpoints of the result outward
+    /// to the precision of the input (the larger precision of its endpoints). The [`Interval`] is
+    /// taken by value.
+    ///
+    /// See [`Interval::ln_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Interval`] contains a negative number or is $[0, 0]$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!(x.ln().to_string(), "[0.50, 1.5]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn ln(self) -> Self {
+        let prec = self.significant_bits();
+        ln_helper(&self, prec)
+    }
+}
+
+impl Ln for &Interval {
+    type Output = Interval;
+
+    /// Takes the natural logarithm of an [`Interval`], rounding the endpoints of the result outward
+    /// to the precision of the input (the larger precision of its endpoints). The [`Interval`] is
+    /// taken by reference.
+    ///
+    /// See [`Interval::ln_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Interval`] contains a negative number or is $[0, 0]$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!((&x).ln().to_string(), "[0.50, 1.5]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn ln(self) -> Interval {
+        let prec = self.significant_bits();
+        ln_helper(self, prec)
+    }
+}
+
+impl LnAssign for Interval {
+    /// Takes the natural logarithm of an [`Interval`] in place, rounding the endpoints of the
+    /// result outward to the precision of the input (the larger precision of its endpoints).
+    ///
+    /// See [`Interval::ln_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Interval`] contains a negative number or is $[0, 0]$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LnAssign;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Interval::new(Float::from(2), Float::from(3));
+    /// x.ln_assign();
+    /// assert_eq!(x.to_string(), "[0.50, 1.5]");
+    /// ```
+    #[inline]
+    fn ln_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = ln_helper(self, prec);
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::{Interval, from_rounded_endpoints};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Evaluates `f` at each of the four pairs of endpoints of `x` and `y`, once rounding down and once
+// rounding up, and returns the interval from the smallest rounded-down value to the largest
+// rounded-up one. This encloses the image of `x` and `y` under any function that is monotonic in
+// each argument separately.
+//
+// A `NaN` value arises from an indeterminate form at an endpoint, such as $0 \cdot \infty$. If
+// `nan_to_zero` is true, it is replaced by zero; otherwise it is skipped. If every value is
+// skipped, `None` is returned.
+pub(crate) fn corner_hull<F: Fn(&Float, &Float, RoundingMode) -> Float>(
+    x: &Interval,
+    y: &Interval,
+    nan_to_zero: bool,
+    f: F,
+) -> Option<Interval> {
+    let mut lower: Option<Float> = None;
+    let mut upper: Option<Float> = None;
+    for a in [&x.lower, &x.upper] {
+        for b in [&y.lower, &y.upper] {
+            let lo = f(a, b, Floor);
+            let (lo, hi) = if lo.is_nan() {
+                if nan_to_zero {
+                    (Float::ZERO, Float::ZERO)
+                } else {
+                    continue;
+                }
+            } else {
+                (lo, f(a, b, Ceiling))
+            };
+            if lower.as_ref().is_none_or(|lower| lo < *lower) {
+                lower = Some(lo);
+            }
+            if upper.as_ref().is_none_or(|upper| hi > *upper) {
+                upper = Some(hi);
+            }
+        }
+    }
+    Some(from_rounded_endpoints(lower?, upper?))
+}
+
+/// Addition of [`Interval`](super::Interval)s.
+pub mod add;
+/// Division of [`Interval`](super::Interval)s.
+pub mod div;
+/// Exponentials of [`Interval`](super::Interval)s.
+pub mod exp;
+/// Natural logarithms of [`Interval`](super::Interval)s.
+pub mod ln;
+/// Multiplication of [`Interval`](super::Interval)s.
+pub mod mul;
+/// Negation of [`Interval`](super::Interval)s.
+pub mod neg;
+/// Powers of [`Interval`](super::Interval)s.
+pub mod pow;
+/// Square roots of [`Interval`](super::Interval)s.
+pub mod sqrt;
+/// Subtraction of [`Interval`](super::Interval)s.
+pub mod sub;
@@ -0,0 +1,317 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+use crate::interval::arithmetic::corner_hull;
+use core::cmp::max;
+use core::ops::{Mul, MulAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+
+fn mul_helper(x: &Interval, y: &Interval, prec: u64) -> Interval {
+    corner_hull(x, y, true, |a, b, rm| {
+        a.mul_prec_round_ref_ref(b, prec, rm).0
+    })
+    .unwrap()
+}
+
+impl Interval {
+    /// Multiplies two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by value.
+    ///
+    /// The result contains $x \cdot y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(\min(ac, ad, bc, bd)), \Delta_p(\max(ac, ad, bc, bd))],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// At the corners, the product of zero and an infinity is taken to be zero, since infinite
+    /// endpoints are limits rather than elements.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.clone().mul_prec(y.clone(), 5).to_string(), "[0.484, 0.844]");
+    /// assert_eq!(x.mul_prec(y, 20).to_string(), "[0.49951172, 0.83374023]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn mul_prec(self, other: Self, prec: u64) -> Self {
+        mul_helper(&self, &other, prec)
+    }
+
+    /// Multiplies two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $x \cdot y$ for every $x$ in `self` and $y$ in `other`:
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(\min(ac, ad, bc, bd)), \Delta_p(\max(ac, ad, bc, bd))],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// At the corners, the product of zero and an infinity is taken to be zero, since infinite
+    /// endpoints are limits rather than elements.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.mul_prec_ref_ref(&y, 5).to_string(), "[0.484, 0.844]");
+    /// assert_eq!(x.mul_prec_ref_ref(&y, 20).to_string(), "[0.49951172, 0.83374023]");
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        mul_helper(self, other, prec)
+    }
+}
+
+impl Mul<Self> for Interval {
+    type Output = Self;
+
+    /// Multiplies two [`Interval`]s, rounding the endpoints of the result outward to the precision
+    /// of the inputs (the largest precision among their endpoints). Both [`Interval`]s are taken by
+    /// value.
+    ///
+    /// See [`Interval::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((x * y).to_string(), "[0.49951, 0.83398]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(&self, &other, prec)
+    }
+}
+
+impl Mul<&Self> for Interval {
+    type Output = Self;
+
+    /// Multiplies two [`Interval`]s, rounding the endpoints of the result outward to the precision
+    /// of the inputs (the largest precision among their endpoints). The first [`Interval`] is taken
+    /// by value and the second by reference.
+    ///
+    /// See [`Interval::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((x * &y).to_string(), "[0.49951, 0.83398]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(&self, other, prec)
+    }
+}
+
+impl Mul<Interval> for &Interval {
+    type Output = Interval;
+
+    /// Multiplies two [`Interval`]s, rounding the endpoints of the result outward to the precision
+    /// of the inputs (the largest precision among their endpoints). The first [`Interval`] is taken
+    /// by reference and the second by value.
+    ///
+    /// See [`Interval::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((&x * y).to_string(), "[0.49951, 0.83398]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(self, &other, prec)
+    }
+}
+
+impl Mul<&Interval> for &Interval {
+    type Output = Interval;
+
+    /// Multiplies two [`Interval`]s, rounding the endpoints of the result outward to the precision
+    /// of the inputs (the largest precision among their endpoints). Both [`Interval`]s are taken by
+    /// reference.
+    ///
+    /// See [`Interval::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!((&x * &y).to_string(), "[0.49951, 0.83398]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul(self, other: &Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        mul_helper(self, other, prec)
+    }
+}
+
+impl MulAssign<Self> for Interval {
+    /// Multiplies an [`Interval`] by an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the inputs (the largest precision among their endpoints). The
+    /// [`Interval`] on the right-hand side is taken by value.
+    ///
+    /// See [`Interval::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// let mut z = x.clone();
+    /// z *= y;
+    /// assert_eq!(z.to_string(), "[0.49951, 0.83398]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = mul_helper(self, &other, prec);
+    }
+}
+
+impl MulAssign<&Self> for Interval {
+    /// Multiplies an [`Interval`] by an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the inputs (the largest precision among their endpoints). The
+    /// [`Interval`] on the right-hand side is taken by reference.
+    ///
+    /// See [`Interval::mul_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(Rational::from_signeds(1, 3), 10);
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// let mut z = x.clone();
+    /// z *= &y;
+    /// assert_eq!(z.to_string(), "[0.49951, 0.83398]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = mul_helper(self, other, prec);
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+use core::mem::swap;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl Neg for Interval {
+    type Output = Self;
+
+    /// Negates an [`Interval`], taking it by value.
+    ///
+    /// Negation is exact, so no rounding takes place.
+    ///
+    /// $$
+    /// f([a, b]) = [-b, -a].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((-Interval::ONE).to_string(), "[-1.0, -1.0]");
+    /// assert_eq!(
+    ///     (-Interval::new(Float::from(1.5), Float::INFINITY)).to_string(),
+    ///     "[-Infinity, -1.5]"
+    /// );
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &Interval {
+    type Output = Interval;
+
+    /// Negates an [`Interval`], taking it by reference.
+    ///
+    /// Negation is exact, so no rounding takes place.
+    ///
+    /// $$
+    /// f([a, b]) = [-b, -a].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((-&Interval::ONE).to_string(), "[-1.0, -1.0]");
+    /// assert_eq!(
+    ///     (-&Interval::new(Float::from(1.5), Float::INFINITY)).to_string(),
+    ///     "[-Infinity, -1.5]"
+    /// );
+    /// ```
+    fn neg(self) -> Interval {
+        Interval {
+            lower: -&self.upper,
+            upper: -&self.lower,
+        }
+    }
+}
+
+impl NegAssign for Interval {
+    /// Negates an [`Interval`] in place.
+    ///
+    /// Negation is exact, so no rounding takes place.
+    ///
+    /// $$
+    /// [a, b] \gets [-b, -a].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_base::num::basic::traits::Infinity;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Interval::new(Float::from(1.5), Float::INFINITY);
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "[-Infinity, -1.5]");
+    /// ```
+    fn neg_assign(&mut self) {
+        swap(&mut self.lower, &mut self.upper);
+        self.lower.neg_assign();
+        self.upper.neg_assign();
+    }
+}
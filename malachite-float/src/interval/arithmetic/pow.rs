@@ -0,0 +1,582 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::arithmetic::corner_hull;
+use crate::interval::{Interval, from_rounded_endpoints};
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Parity, Pow, PowAssign};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn pow_helper(x: &Interval, y: &Interval, prec: u64) -> Interval {
+    assert!(
+        x.lower >= 0u32,
+        "Cannot raise {x} to an interval power, since it contains a negative number"
+    );
+    assert!(
+        x.upper > 0u32 || y.upper >= 0u32,
+        "Cannot raise zero to the negative interval power {y}"
+    );
+    // Powers of a negative zero may be negative or $-\infty$, so zero endpoints are made positive.
+    // Since the base is non-negative, $x^y$ is monotonic in each of $x$ and $y$, and its extrema
+    // are at the corners.
+    if x.lower == 0u32 {
+        let x = Interval {
+            lower: Float::ZERO,
+            upper: if x.upper == 0u32 {
+                Float::ZERO
+            } else {
+                x.upper.clone()
+            },
+        };
+        corner_hull(&x, y, false, |a, b, rm| {
+            a.pow_prec_round_ref_ref(b, prec, rm).0
+        })
+    } else {
+        corner_hull(x, y, false, |a, b, rm| {
+            a.pow_prec_round_ref_ref(b, prec, rm).0
+        })
+    }
+    .unwrap()
+}
+
+fn pow_u_helper(x: &Interval, n: u64, prec: u64) -> Interval {
+    if n == 0 {
+        return Interval {
+            lower: Float::one_prec(prec),
+            upper: Float::one_prec(prec),
+        };
+    }
+    let pow = |a: &Float, rm| a.pow_u_prec_round_ref(n, prec, rm).0;
+    if n.odd() || x.lower >= 0u32 {
+        // $x^n$ is increasing on the interval
+        from_rounded_endpoints(pow(&x.lower, Floor), pow(&x.upper, Ceiling))
+    } else if x.upper <= 0u32 {
+        // $x^n$ is decreasing on the interval
+        from_rounded_endpoints(pow(&x.upper, Floor), pow(&x.lower, Ceiling))
+    } else {
+        // The interval contains zero in its interior, where $x^n$ has its minimum
+        let lower_pow = pow(&x.lower, Ceiling);
+        let upper_pow = pow(&x.upper, Ceiling);
+        from_rounded_endpoints(
+            Float::ZERO,
+            if lower_pow > upper_pow {
+                lower_pow
+            } else {
+                upper_pow
+            },
+        )
+    }
+}
+
+impl Interval {
+    /// Raises an [`Interval`] to the power of another [`Interval`], rounding the endpoints of the
+    /// result outward to the specified precision. Both [`Interval`]s are taken by value.
+    ///
+    /// The result contains $x^y$ for every $x$ in `self` and $y$ in `other`. Since the base is
+    /// non-negative, $x^y$ is monotonic in each of $x$ and $y$, so
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(\min Q), \Delta_p(\max Q)],
+    /// $$
+    /// where $Q = \{a^c, a^d, b^c, b^d\}$ and $\nabla_p$ and $\Delta_p$ round down and up to
+    /// precision $p$.
+    ///
+    /// A zero endpoint of the base is treated as positive zero, and the corners follow the
+    /// conventions of [`Float::pow_prec_round`]; in particular, $0^0 = 1$.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using [`Interval::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, if `self` contains a negative number, or if `self` is $[0, 0]$ and
+    /// `other` contains only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// let y = Interval::new(Float::from(0.5), Float::from(1.5));
+    /// assert_eq!(x.clone().pow_prec(y.clone(), 5).to_string(), "[1.38, 5.25]");
+    /// assert_eq!(x.pow_prec(y, 20).to_string(), "[1.4142132, 5.1961594]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn pow_prec(self, other: Self, prec: u64) -> Self {
+        pow_helper(&self, &other, prec)
+    }
+
+    /// Raises an [`Interval`] to the power of another [`Interval`], rounding the endpoints of the
+    /// result outward to the specified precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $x^y$ for every $x$ in `self` and $y$ in `other`. Since the base is
+    /// non-negative, $x^y$ is monotonic in each of $x$ and $y$, so
+    /// $$
+    /// f([a, b], [c, d], p) = [\nabla_p(\min Q), \Delta_p(\max Q)],
+    /// $$
+    /// where $Q = \{a^c, a^d, b^c, b^d\}$ and $\nabla_p$ and $\Delta_p$ round down and up to
+    /// precision $p$.
+    ///
+    /// A zero endpoint of the base is treated as positive zero, and the corners follow the
+    /// conventions of [`Float::pow_prec_round`]; in particular, $0^0 = 1$.
+    ///
+    /// If you know that your target precision is the larger of the precisions of the inputs,
+    /// consider using [`Interval::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^{3/2} \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, if `self` contains a negative number, or if `self` is $[0, 0]$ and
+    /// `other` contains only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// let y = Interval::new(Float::from(0.5), Float::from(1.5));
+    /// assert_eq!(x.pow_prec_ref_ref(&y, 5).to_string(), "[1.38, 5.25]");
+    /// assert_eq!(x.pow_prec_ref_ref(&y, 20).to_string(), "[1.4142132, 5.1961594]");
+    /// ```
+    #[inline]
+    pub fn pow_prec_ref_ref(&self, other: &Self, prec: u64) -> Self {
+        pow_helper(self, other, prec)
+    }
+
+    /// Raises an [`Interval`] to the power of a [`u64`], rounding the endpoints of the result
+    /// outward to the specified precision. The [`Interval`] is taken by value.
+    ///
+    /// The result contains $x^n$ for every $x$ in the [`Interval`]. Unlike repeated multiplication,
+    /// this accounts for the dependency between the factors, so even powers of an [`Interval`]
+    /// containing zero have a lower endpoint of zero:
+    /// $$
+    /// f([a, b], n, p) = \\begin{cases}
+    ///     [1, 1] & \text{if} \\quad n = 0, \\\\
+    ///     [\nabla_p(a^n), \Delta_p(b^n)] & \text{if} \\quad n \\ \text{odd or} \\ a \geq 0, \\\\
+    ///     [\nabla_p(b^n), \Delta_p(a^n)] & \text{if} \\quad n \\ \text{even and} \\ b \leq 0, \\\\
+    ///     [0, \Delta_p(\max(a^n, b^n))] & \\text{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(prec, self.significant_bits())`,
+    /// and $m$ is `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(-1.5), Float::from(1.25));
+    /// assert_eq!(x.clone().pow_u_prec(2, 10).to_string(), "[0.0, 2.2500]");
+    /// assert_eq!(x.pow_u_prec(3, 10).to_string(), "[-3.3750, 1.9531]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn pow_u_prec(self, n: u64, prec: u64) -> Self {
+        pow_u_helper(&self, n, prec)
+    }
+
+    /// Raises an [`Interval`] to the power of a [`u64`], rounding the endpoints of the result
+    /// outward to the specified precision. The [`Interval`] is taken by reference.
+    ///
+    /// The result contains $x^n$ for every $x$ in the [`Interval`]. Unlike repeated multiplication,
+    /// this accounts for the dependency between the factors, so even powers of an [`Interval`]
+    /// containing zero have a lower endpoint of zero:
+    /// $$
+    /// f([a, b], n, p) = \\begin{cases}
+    ///     [1, 1] & \text{if} \\quad n = 0, \\\\
+    ///     [\nabla_p(a^n), \Delta_p(b^n)] & \text{if} \\quad n \\ \text{odd or} \\ a \geq 0, \\\\
+    ///     [\nabla_p(b^n), \Delta_p(a^n)] & \text{if} \\quad n \\ \text{even and} \\ b \leq 0, \\\\
+    ///     [0, \Delta_p(\max(a^n, b^n))] & \\text{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::pow`](Pow::pow) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(prec, self.significant_bits())`,
+    /// and $m$ is `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(-1.5), Float::from(1.25));
+    /// assert_eq!(x.pow_u_prec_ref(2, 10).to_string(), "[0.0, 2.2500]");
+    /// assert_eq!(x.pow_u_prec_ref(3, 10).to_string(), "[-3.3750, 1.9531]");
+    /// ```
+    #[inline]
+    pub fn pow_u_prec_ref(&self, n: u64, prec: u64) -> Self {
+        pow_u_helper(self, n, prec)
+    }
+}
+
+impl Pow<Self> for Interval {
+    type Output = Self;
+
+    /// Raises an [`Interval`] to the power of another [`Interval`], rounding the endpoints of the
+    /// result outward to the precision of the inputs (the largest precision among their endpoints).
+    /// Both [`Interval`]s are taken by value.
+    ///
+    /// See [`Interval::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `self` contains a negative number, or if `self` is $[0, 0]$ and `other` contains
+    /// only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// let y = Interval::new(Float::from(0.5), Float::from(1.5));
+    /// assert_eq!(x.pow(y).to_string(), "[1.0, 6.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow(self, other: Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(&self, &other, prec)
+    }
+}
+
+impl Pow<&Self> for Interval {
+    type Output = Self;
+
+    /// Raises an [`Interval`] to the power of another [`Interval`], rounding the endpoints of the
+    /// result outward to the precision of the inputs (the largest precision among their endpoints).
+    /// The first [`Interval`] is taken by value and the second by reference.
+    ///
+    /// See [`Interval::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `self` contains a negative number, or if `self` is $[0, 0]$ and `other` contains
+    /// only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// let y = Interval::new(Float::from(0.5), Float::from(1.5));
+    /// assert_eq!(x.pow(&y).to_string(), "[1.0, 6.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow(self, other: &Self) -> Self {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(&self, other, prec)
+    }
+}
+
+impl Pow<Interval> for &Interval {
+    type Output = Interval;
+
+    /// Raises an [`Interval`] to the power of another [`Interval`], rounding the endpoints of the
+    /// result outward to the precision of the inputs (the largest precision among their endpoints).
+    /// The first [`Interval`] is taken by reference and the second by value.
+    ///
+    /// See [`Interval::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `self` contains a negative number, or if `self` is $[0, 0]$ and `other` contains
+    /// only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// let y = Interval::new(Float::from(0.5), Float::from(1.5));
+    /// assert_eq!((&x).pow(y).to_string(), "[1.0, 6.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow(self, other: Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(self, &other, prec)
+    }
+}
+
+impl Pow<&Interval> for &Interval {
+    type Output = Interval;
+
+    /// Raises an [`Interval`] to the power of another [`Interval`], rounding the endpoints of the
+    /// result outward to the precision of the inputs (the largest precision among their endpoints).
+    /// Both [`Interval`]s are taken by reference.
+    ///
+    /// See [`Interval::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `self` contains a negative number, or if `self` is $[0, 0]$ and `other` contains
+    /// only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// let y = Interval::new(Float::from(0.5), Float::from(1.5));
+    /// assert_eq!((&x).pow(&y).to_string(), "[1.0, 6.0]");
+    /// ```
+    #[inline]
+    fn pow(self, other: &Interval) -> Interval {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        pow_helper(self, other, prec)
+    }
+}
+
+impl PowAssign<Self> for Interval {
+    /// Raises an [`Interval`] to the power of another [`Interval`] in place, rounding the endpoints
+    /// of the result outward to the precision of the inputs (the largest precision among their
+    /// endpoints). The [`Interval`] on the right-hand side is taken by value.
+    ///
+    /// See [`Interval::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `self` contains a negative number, or if `self` is $[0, 0]$ and `other` contains
+    /// only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Interval::new(Float::from(2), Float::from(3));
+    /// x.pow_assign(Interval::new(Float::from(0.5), Float::from(1.5)));
+    /// assert_eq!(x.to_string(), "[1.0, 6.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn pow_assign(&mut self, other: Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = pow_helper(self, &other, prec);
+    }
+}
+
+impl PowAssign<&Self> for Interval {
+    /// Raises an [`Interval`] to the power of another [`Interval`] in place, rounding the endpoints
+    /// of the result outward to the precision of the inputs (the largest precision among their
+    /// endpoints). The [`Interval`] on the right-hand side is taken by reference.
+    ///
+    /// See [`Interval::pow_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `self` contains a negative number, or if `self` is $[0, 0]$ and `other` contains
+    /// only negative numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Interval::new(Float::from(2), Float::from(3));
+    /// x.pow_assign(&Interval::new(Float::from(0.5), Float::from(1.5)));
+    /// assert_eq!(x.to_string(), "[1.0, 6.0]");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, other: &Self) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        *self = pow_helper(self, other, prec);
+    }
+}
+
+impl Pow<u64> for Interval {
+    type Output = Self;
+
+    /// Raises an [`Interval`] to the power of a [`u64`], rounding the endpoints of the result
+    /// outward to the precision of the input (the larger precision of its endpoints). The
+    /// [`Interval`] is taken by value.
+    ///
+    /// See [`Interval::pow_u_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(-1.5), Float::from(1.25));
+    /// assert_eq!(x.pow(2u64).to_string(), "[0.0, 2.5]");
+    /// ```
+    #[inline]
+    fn pow(self, n: u64) -> Self {
+        let prec = self.significant_bits();
+        pow_u_helper(&self, n, prec)
+    }
+}
+
+impl Pow<u64> for &Interval {
+    type Output = Interval;
+
+    /// Raises an [`Interval`] to the power of a [`u64`], rounding the endpoints of the result
+    /// outward to the precision of the input (the larger precision of its endpoints). The
+    /// [`Interval`] is taken by reference.
+    ///
+    /// See [`Interval::pow_u_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(-1.5), Float::from(1.25));
+    /// assert_eq!((&x).pow(2u64).to_string(), "[0.0, 2.5]");
+    /// ```
+    #[inline]
+    fn pow(self, n: u64) -> Interval {
+        let prec = self.significant_bits();
+        pow_u_helper(self, n, prec)
+    }
+}
+
+impl PowAssign<u64> for Interval {
+    /// Raises an [`Interval`] to the power of a [`u64`] in place, rounding the endpoints of the
+    /// result outward to the precision of the input (the larger precision of its endpoints).
+    ///
+    /// See [`Interval::pow_u_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Interval::new(Float::from(-1.5), Float::from(1.25));
+    /// x.pow_assign(2u64);
+    /// assert_eq!(x.to_string(), "[0.0, 2.5]");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, n: u64) {
+        let prec = self.significant_bits();
+        *self = pow_u_helper(self, n, prec);
+    }
+}
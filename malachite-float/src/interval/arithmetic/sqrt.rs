@@ -0,0 +1,202 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::{Interval, from_rounded_endpoints};
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+fn sqrt_helper(x: &Interval, prec: u64) -> Interval {
+    assert!(x.lower >= 0u32, "Cannot take the square root of {x}");
+    from_rounded_endpoints(
+        x.lower.sqrt_prec_round_ref(prec, Floor).0,
+        x.upper.sqrt_prec_round_ref(prec, Ceiling).0,
+    )
+}
+
+impl Interval {
+    /// Takes the square root of an [`Interval`], rounding the endpoints of the result outward to
+    /// the specified precision. The [`Interval`] is taken by value.
+    ///
+    /// The result contains the square root of every element of the [`Interval`]:
+    /// $$
+    /// f([a, b], p) = [\nabla_p(\sqrt{a}), \Delta_p(\sqrt{b})],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the [`Interval`] contains a negative number.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!(x.clone().sqrt_prec(5).to_string(), "[1.38, 1.75]");
+    /// assert_eq!(x.sqrt_prec(20).to_string(), "[1.4142132, 1.7320518]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn sqrt_prec(self, prec: u64) -> Self {
+        sqrt_helper(&self, prec)
+    }
+
+    /// Takes the square root of an [`Interval`], rounding the endpoints of the result outward to
+    /// the specified precision. The [`Interval`] is taken by reference.
+    ///
+    /// The result contains the square root of every element of the [`Interval`]:
+    /// $$
+    /// f([a, b], p) = [\nabla_p(\sqrt{a}), \Delta_p(\sqrt{b})],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Interval::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n + m)$
+    ///
+    /// $M(n, m) = O(n \log n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the [`Interval`] contains a negative number.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!(x.sqrt_prec_ref(5).to_string(), "[1.38, 1.75]");
+    /// assert_eq!(x.sqrt_prec_ref(20).to_string(), "[1.4142132, 1.7320518]");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_ref(&self, prec: u64) -> Self {
+        sqrt_helper(self, prec)
+    }
+}
+
+impl Sqrt for Interval {
+    type Output = Self;
+
+    /// Takes the square root of an [`Interval`], rounding the endpoints of the result outward to
+    /// the precision of the input (the larger precision of its endpoints). The [`Interval`] is
+    /// taken by value.
+    ///
+    /// See [`Interval::sqrt_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Interval`] contains a negative number.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!(x.sqrt().to_string(), "[1.0, 2.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sqrt(self) -> Self {
+        let prec = self.significant_bits();
+        sqrt_helper(&self, prec)
+    }
+}
+
+impl Sqrt for &Interval {
+    type Output = Interval;
+
+    /// Takes the square root of an [`Interval`], rounding the endpoints of the result outward to
+    /// the precision of the input (the larger precision of its endpoints). The [`Interval`] is
+    /// taken by reference.
+    ///
+    /// See [`Interval::sqrt_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Interval`] contains a negative number.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(2), Float::from(3));
+    /// assert_eq!((&x).sqrt().to_string(), "[1.0, 2.0]");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    fn sqrt(self) -> Interval {
+        let prec = self.significant_bits();
+        sqrt_helper(self, prec)
+    }
+}
+
+impl SqrtAssign for Interval {
+    /// Takes the square root of an [`Interval`] in place, rounding the endpoints of the result
+    /// outward to the precision of the input (the larger precision of its endpoints).
+    ///
+    /// See [`Interval::sqrt_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Interval`] contains a negative number.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SqrtAssign;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Interval::new(Float::from(2), Float::from(3));
+    /// x.sqrt_assign();
+    /// assert_eq!(x.to_string(), "[1.0, 2.0]");
+    /// ```
+    #[inline]
+    fn sqrt_assign(&mut self) {
+        let prec = self.significant_bits();
+        *self = sqrt_helper(self, prec);
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+use core::cmp::max;
+use malachite_base::num::logic::traits::SignificantBits;
+
+impl SignificantBits for &Interval {
+    /// Returns the number of significant bits of an [`Interval`]: the larger of the numbers of
+    /// significant bits of its endpoints.
+    ///
+    /// This is the precision that the operators use for their results. As with
+    /// [`Float`](crate::Float)s, a zero or infinite endpoint counts as having 1 significant bit.
+    ///
+    /// $$
+    /// f([a, b]) = \max(\operatorname{bits}(a), \operatorname{bits}(b)).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::num::logic::traits::SignificantBits;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Interval::ZERO.significant_bits(), 1);
+    /// assert_eq!(Interval::ONE.significant_bits(), 1);
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from_unsigned_prec(3u32, 10).0);
+    /// assert_eq!(x.significant_bits(), 10);
+    /// ```
+    fn significant_bits(self) -> u64 {
+        max(self.lower.significant_bits(), self.upper.significant_bits())
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::Interval;
+use malachite_base::num::basic::traits::{Infinity, NegativeInfinity, One, Zero};
+
+/// The point interval $[0, 0]$, whose endpoints are positive zeros.
+impl Zero for Interval {
+    const ZERO: Self = Self {
+        lower: Float::ZERO,
+        upper: Float::ZERO,
+    };
+}
+
+/// The point interval $[1, 1]$, whose endpoints have precision 1.
+impl One for Interval {
+    const ONE: Self = Self {
+        lower: Float::ONE,
+        upper: Float::ONE,
+    };
+}
+
+impl Interval {
+    /// The interval $[-\infty, \infty]$, which contains every real number.
+    pub const ENTIRE: Self = Self {
+        lower: Float::NEGATIVE_INFINITY,
+        upper: Float::INFINITY,
+    };
+}
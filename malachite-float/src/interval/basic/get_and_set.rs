@@ -0,0 +1,281 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::Interval;
+use malachite_base::num::basic::traits::{Infinity, NegativeInfinity, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+impl Interval {
+    /// Creates an [`Interval`] from its endpoints, taking both by value.
+    ///
+    /// $$
+    /// f(a, b) = [a, b].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if either endpoint is `NaN`, if `lower` is greater than `upper`, if `lower` is
+    /// $\infty$, or if `upper` is $-\infty$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::new(Float::from(1.5), Float::from(2.5)).to_string(),
+    ///     "[1.5, 2.5]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::new(Float::ZERO, Float::INFINITY).to_string(),
+    ///     "[0.0, Infinity]"
+    /// );
+    /// ```
+    pub fn new(lower: Float, upper: Float) -> Self {
+        assert!(
+            !lower.is_nan() && !upper.is_nan(),
+            "Interval endpoints cannot be NaN"
+        );
+        assert!(
+            lower <= upper,
+            "Interval lower endpoint {lower} is greater than upper endpoint {upper}"
+        );
+        assert!(
+            lower != Float::INFINITY && upper != Float::NEGATIVE_INFINITY,
+            "Interval cannot lie entirely at infinity"
+        );
+        Self { lower, upper }
+    }
+
+    /// Returns a reference to the lower endpoint of an [`Interval`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.lower().to_string(), "1.5");
+    /// ```
+    #[inline]
+    pub const fn lower(&self) -> &Float {
+        &self.lower
+    }
+
+    /// Returns a reference to the upper endpoint of an [`Interval`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.upper().to_string(), "2.5");
+    /// ```
+    #[inline]
+    pub const fn upper(&self) -> &Float {
+        &self.upper
+    }
+
+    /// Consumes an [`Interval`], returning its lower and upper endpoints.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let (lower, upper) = Interval::new(Float::from(1.5), Float::from(2.5)).into_endpoints();
+    /// assert_eq!(lower.to_string(), "1.5");
+    /// assert_eq!(upper.to_string(), "2.5");
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    #[inline]
+    pub fn into_endpoints(self) -> (Float, Float) {
+        (self.lower, self.upper)
+    }
+
+    /// Determines whether an [`Interval`] contains a single point; that is, whether its endpoints
+    /// are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Interval::ONE.is_point());
+    /// assert!(!Interval::new(Float::from(1.5), Float::from(2.5)).is_point());
+    /// ```
+    #[inline]
+    pub fn is_point(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Determines whether both endpoints of an [`Interval`] are finite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Interval::ONE.is_bounded());
+    /// assert!(!Interval::new(Float::from(1.5), Float::INFINITY).is_bounded());
+    /// assert!(!Interval::ENTIRE.is_bounded());
+    /// ```
+    #[inline]
+    pub const fn is_bounded(&self) -> bool {
+        self.lower.is_finite() && self.upper.is_finite()
+    }
+
+    /// Returns the width of an [`Interval`], rounded up to the specified precision.
+    ///
+    /// The result is never smaller than the exact width, so it may be used as an upper bound for
+    /// the distance between any two elements of the interval.
+    ///
+    /// $$
+    /// f([a, b], p) = \Delta_p(b - a),
+    /// $$
+    /// where $\Delta_p$ rounds up to precision $p$.
+    ///
+    /// If the interval is unbounded, the width is $\infty$. If it is a point, the width is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n + m)$
+    ///
+    /// $M(n, m) = O(n + m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `prec`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from_unsigned_prec(1000u32, 10).0);
+    /// assert_eq!(x.width_prec(2).to_string(), "1.0e3");
+    /// assert_eq!(x.width_prec(20).to_string(), "998.50000");
+    /// ```
+    pub fn width_prec(&self, prec: u64) -> Float {
+        self.upper
+            .sub_prec_round_ref_ref(&self.lower, prec, Ceiling)
+            .0
+    }
+
+    /// Returns the width of an [`Interval`], rounded up to the precision of the interval (the
+    /// larger of its endpoints' precisions).
+    ///
+    /// See [`Interval::width_prec`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Interval::ONE.width().to_string(), "0.0");
+    /// assert_eq!(Interval::ENTIRE.width().to_string(), "Infinity");
+    /// assert_eq!(
+    ///     Interval::new(Float::from(1.5), Float::from(2.5)).width().to_string(),
+    ///     "1.0"
+    /// );
+    /// ```
+    #[inline]
+    pub fn width(&self) -> Float {
+        self.width_prec(self.significant_bits())
+    }
+
+    /// Returns the midpoint of an [`Interval`], rounded to the nearest [`Float`] whose precision
+    /// is that of the interval (the larger of its endpoints' precisions).
+    ///
+    /// Since both endpoints can be represented with this precision, the rounded midpoint always
+    /// lies in the interval.
+    ///
+    /// $$
+    /// f([a, b]) = \operatorname{round}\left(\frac{a+b}{2}\right).
+    /// $$
+    ///
+    /// If exactly one endpoint is infinite, the midpoint is that infinity. If both are, the
+    /// midpoint is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Infinity;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::new(Float::from(1.5), Float::from(2.5)).midpoint().to_string(),
+    ///     "2.0"
+    /// );
+    /// assert_eq!(
+    ///     Interval::new(Float::from(1.5), Float::INFINITY).midpoint().to_string(),
+    ///     "Infinity"
+    /// );
+    /// assert_eq!(Interval::ENTIRE.midpoint().to_string(), "0.0");
+    /// ```
+    pub fn midpoint(&self) -> Float {
+        if self.lower == Float::NEGATIVE_INFINITY && self.upper == Float::INFINITY {
+            return Float::ZERO;
+        }
+        // Halving first keeps the sum from overflowing. Halving is exact unless it underflows, in
+        // which case the sum may land just outside a very narrow interval, so it is clamped.
+        let midpoint = (&self.lower >> 1u32)
+            .add_prec_round(&self.upper >> 1u32, self.significant_bits(), Nearest)
+            .0;
+        if midpoint < self.lower {
+            self.lower.clone()
+        } else if midpoint > self.upper {
+            self.upper.clone()
+        } else {
+            midpoint
+        }
+    }
+}
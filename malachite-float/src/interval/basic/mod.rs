@@ -0,0 +1,14 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Measuring the complexity of an [`Interval`](super::Interval).
+pub mod complexity;
+/// Various [`Interval`](super::Interval) constants.
+pub mod constants;
+/// Creating [`Interval`](super::Interval)s and getting their endpoints, widths, and midpoints.
+pub mod get_and_set;
@@ -0,0 +1,232 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+
+impl Interval {
+    /// Determines whether the elements of one [`Interval`] are less than those of another.
+    ///
+    /// Returns `Some(true)` if every element of `self` is less than every element of `other`,
+    /// `Some(false)` if no element of `self` is less than any element of `other`, and `None` if
+    /// the answer depends on which elements are chosen.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = \\begin{cases}
+    ///     \operatorname{Some}(\text{true}) & \text{if} \\quad b < c, \\\\
+    ///     \operatorname{Some}(\text{false}) & \text{if} \\quad a \geq d, \\\\
+    ///     \operatorname{None} & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(Interval::ZERO.less_than(&x), Some(true));
+    /// assert_eq!(x.less_than(&Interval::ONE), Some(false));
+    /// assert_eq!(x.less_than(&Interval::try_from(Float::from(2)).unwrap()), None);
+    /// ```
+    pub fn less_than(&self, other: &Self) -> Option<bool> {
+        if self.upper < other.lower {
+            Some(true)
+        } else if self.lower >= other.upper {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Determines whether the elements of one [`Interval`] are less than or equal to those of
+    /// another.
+    ///
+    /// Returns `Some(true)` if every element of `self` is less than or equal to every element of
+    /// `other`, `Some(false)` if no element of `self` is less than or equal to any element of
+    /// `other`, and `None` if the answer depends on which elements are chosen.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = \\begin{cases}
+    ///     \operatorname{Some}(\text{true}) & \text{if} \\quad b \leq c, \\\\
+    ///     \operatorname{Some}(\text{false}) & \text{if} \\quad a > d, \\\\
+    ///     \operatorname{None} & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(Interval::ZERO.less_than_or_equal(&x), Some(true));
+    /// assert_eq!(x.less_than_or_equal(&Interval::ONE), Some(false));
+    /// assert_eq!(
+    ///     x.less_than_or_equal(&Interval::try_from(Float::from(2.5)).unwrap()),
+    ///     Some(true)
+    /// );
+    /// assert_eq!(
+    ///     x.less_than_or_equal(&Interval::try_from(Float::from(2)).unwrap()),
+    ///     None
+    /// );
+    /// ```
+    pub fn less_than_or_equal(&self, other: &Self) -> Option<bool> {
+        if self.upper <= other.lower {
+            Some(true)
+        } else if self.lower > other.upper {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Determines whether the elements of one [`Interval`] are greater than those of another.
+    ///
+    /// Returns `Some(true)` if every element of `self` is greater than every element of `other`,
+    /// `Some(false)` if no element of `self` is greater than any element of `other`, and `None`
+    /// if the answer depends on which elements are chosen.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = \\begin{cases}
+    ///     \operatorname{Some}(\text{true}) & \text{if} \\quad a > d, \\\\
+    ///     \operatorname{Some}(\text{false}) & \text{if} \\quad b \leq c, \\\\
+    ///     \operatorname{None} & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(Interval::ZERO.greater_than(&x), Some(false));
+    /// assert_eq!(x.greater_than(&Interval::ONE), Some(true));
+    /// assert_eq!(x.greater_than(&Interval::try_from(Float::from(2)).unwrap()), None);
+    /// ```
+    #[inline]
+    pub fn greater_than(&self, other: &Self) -> Option<bool> {
+        other.less_than(self)
+    }
+
+    /// Determines whether the elements of one [`Interval`] are greater than or equal to those of
+    /// another.
+    ///
+    /// Returns `Some(true)` if every element of `self` is greater than or equal to every element
+    /// of `other`, `Some(false)` if no element of `self` is greater than or equal to any element of
+    /// `other`, and `None` if the answer depends on which elements are chosen.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = \\begin{cases}
+    ///     \operatorname{Some}(\text{true}) & \text{if} \\quad a \geq d, \\\\
+    ///     \operatorname{Some}(\text{false}) & \text{if} \\quad b < c, \\\\
+    ///     \operatorname{None} & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(Interval::ZERO.greater_than_or_equal(&x), Some(false));
+    /// assert_eq!(x.greater_than_or_equal(&Interval::ONE), Some(true));
+    /// assert_eq!(
+    ///     x.greater_than_or_equal(&Interval::try_from(Float::from(1.5)).unwrap()),
+    ///     Some(true)
+    /// );
+    /// assert_eq!(
+    ///     x.greater_than_or_equal(&Interval::try_from(Float::from(2)).unwrap()),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    pub fn greater_than_or_equal(&self, other: &Self) -> Option<bool> {
+        other.less_than_or_equal(self)
+    }
+
+    /// Determines whether the elements of one [`Interval`] are equal to those of another.
+    ///
+    /// Returns `Some(true)` if both [`Interval`]s are the same point, `Some(false)` if they are
+    /// disjoint, and `None` otherwise. To compare [`Interval`]s as sets, use `==` instead.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = \\begin{cases}
+    ///     \operatorname{Some}(\text{true}) & \text{if} \\quad a = b = c = d, \\\\
+    ///     \operatorname{Some}(\text{false}) & \text{if} \\quad b < c \\ \text{or} \\ d < a, \\\\
+    ///     \operatorname{None} & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(Interval::ONE.equal(&Interval::ONE), Some(true));
+    /// assert_eq!(Interval::ZERO.equal(&x), Some(false));
+    /// assert_eq!(x.equal(&x), None);
+    /// ```
+    pub fn equal(&self, other: &Self) -> Option<bool> {
+        if self.upper < other.lower || other.upper < self.lower {
+            Some(false)
+        } else if self.is_point() && other.is_point() {
+            // The intervals intersect, so if both are points they are the same point
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
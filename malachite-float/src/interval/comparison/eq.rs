@@ -0,0 +1,47 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+
+impl PartialEq for Interval {
+    /// Compares two [`Interval`]s for equality, as sets.
+    ///
+    /// Two [`Interval`]s are equal if their lower endpoints are equal and their upper endpoints are
+    /// equal, as [`Float`](crate::Float)s; precisions and the signs of zero endpoints are ignored.
+    /// Since an [`Interval`] never has a `NaN` endpoint, this is an equivalence relation.
+    ///
+    /// To determine whether the elements of two [`Interval`]s are equal, use
+    /// [`Interval::equal`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Interval::ONE, Interval::ONE);
+    /// assert_ne!(Interval::ONE, Interval::ZERO);
+    /// assert_eq!(
+    ///     Interval::new(Float::from(1.5), Float::from_unsigned_prec(2u32, 100).0),
+    ///     Interval::new(Float::from(1.5), Float::from(2))
+    /// );
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.lower == other.lower && self.upper == other.upper
+    }
+}
+
+impl Eq for Interval {}
@@ -0,0 +1,13 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Comparisons of [`Interval`](super::Interval)s that return `Some` answer when it is the same
+/// for every choice of elements, and `None` otherwise.
+pub mod cmp;
+/// Equality of [`Interval`](super::Interval)s, as sets.
+pub mod eq;
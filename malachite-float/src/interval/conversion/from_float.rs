@@ -0,0 +1,97 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::Interval;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IntervalFromFloatError;
+
+impl TryFrom<Float> for Interval {
+    type Error = IntervalFromFloatError;
+
+    /// Converts a [`Float`] to the point [`Interval`] containing only that [`Float`], taking the
+    /// [`Float`] by value. If the [`Float`] is not finite, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN};
+    /// use malachite_float::interval::conversion::from_float::IntervalFromFloatError;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::try_from(Float::from(1.5)).unwrap().to_string(),
+    ///     "[1.5, 1.5]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::try_from(Float::INFINITY),
+    ///     Err(IntervalFromFloatError)
+    /// );
+    /// assert_eq!(Interval::try_from(Float::NAN), Err(IntervalFromFloatError));
+    /// ```
+    fn try_from(x: Float) -> Result<Self, Self::Error> {
+        if x.is_finite() {
+            Ok(Self {
+                lower: x.clone(),
+                upper: x,
+            })
+        } else {
+            Err(IntervalFromFloatError)
+        }
+    }
+}
+
+impl TryFrom<&Float> for Interval {
+    type Error = IntervalFromFloatError;
+
+    /// Converts a [`Float`] to the point [`Interval`] containing only that [`Float`], taking the
+    /// [`Float`] by reference. If the [`Float`] is not finite, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN};
+    /// use malachite_float::interval::conversion::from_float::IntervalFromFloatError;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::try_from(&Float::from(1.5)).unwrap().to_string(),
+    ///     "[1.5, 1.5]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::try_from(&Float::INFINITY),
+    ///     Err(IntervalFromFloatError)
+    /// );
+    /// assert_eq!(Interval::try_from(&Float::NAN), Err(IntervalFromFloatError));
+    /// ```
+    fn try_from(x: &Float) -> Result<Self, Self::Error> {
+        if x.is_finite() {
+            Ok(Self {
+                lower: x.clone(),
+                upper: x.clone(),
+            })
+        } else {
+            Err(IntervalFromFloatError)
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::Interval;
+use core::cmp::Ordering::*;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_q::Rational;
+
+impl Interval {
+    /// Converts a [`Rational`] to the narrowest [`Interval`] with endpoints of the specified
+    /// precision that contains it, taking the [`Rational`] by value.
+    ///
+    /// If the [`Rational`] can be represented exactly with the specified precision, the result is
+    /// a point interval; otherwise, its endpoints are the [`Rational`] rounded down and rounded
+    /// up, so they are adjacent [`Float`]s of the given precision. In either case, the
+    /// [`Rational`] is guaranteed to lie in the result.
+    ///
+    /// $$
+    /// f(x, p) = [\nabla_p(x), \Delta_p(x)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If the [`Rational`] is too large or too small in magnitude to be represented by a
+    /// [`Float`], one endpoint is the largest finite [`Float`] or the smallest positive
+    /// [`Float`] of the given precision (or its negative), and the other is an infinity or a zero,
+    /// so the enclosure still holds.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Interval::from_rational_prec(Rational::from_signeds(1, 3), 10).to_string(),
+    ///     "[0.33301, 0.33350]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_rational_prec(Rational::from_signeds(-1, 3), 10).to_string(),
+    ///     "[-0.33350, -0.33301]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_rational_prec(Rational::from_signeds(3, 8), 10).to_string(),
+    ///     "[0.37500, 0.37500]"
+    /// );
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn from_rational_prec(x: Rational, prec: u64) -> Self {
+        Self::from_rational_prec_ref(&x, prec)
+    }
+
+    /// Converts a [`Rational`] to the narrowest [`Interval`] with endpoints of the specified
+    /// precision that contains it, taking the [`Rational`] by reference.
+    ///
+    /// If the [`Rational`] can be represented exactly with the specified precision, the result is
+    /// a point interval; otherwise, its endpoints are the [`Rational`] rounded down and rounded
+    /// up, so they are adjacent [`Float`]s of the given precision. In either case, the
+    /// [`Rational`] is guaranteed to lie in the result.
+    ///
+    /// $$
+    /// f(x, p) = [\nabla_p(x), \Delta_p(x)],
+    /// $$
+    /// where $\nabla_p$ and $\Delta_p$ round down and up to precision $p$.
+    ///
+    /// If the [`Rational`] is too large or too small in magnitude to be represented by a
+    /// [`Float`], one endpoint is the largest finite [`Float`] or the smallest positive
+    /// [`Float`] of the given precision (or its negative), and the other is an infinity or a zero,
+    /// so the enclosure still holds.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Interval::from_rational_prec_ref(&Rational::from_signeds(1, 3), 10).to_string(),
+    ///     "[0.33301, 0.33350]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_rational_prec_ref(&Rational::from_signeds(-1, 3), 10).to_string(),
+    ///     "[-0.33350, -0.33301]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_rational_prec_ref(&Rational::from_signeds(3, 8), 10).to_string(),
+    ///     "[0.37500, 0.37500]"
+    /// );
+    /// ```
+    pub fn from_rational_prec_ref(x: &Rational, prec: u64) -> Self {
+        let (lower, o) = Float::from_rational_prec_round_ref(x, prec, Floor);
+        if o == Equal {
+            Self {
+                upper: lower.clone(),
+                lower,
+            }
+        } else {
+            Self {
+                lower,
+                upper: Float::from_rational_prec_round_ref(x, prec, Ceiling).0,
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Implementations of [`TryFrom`] for converting a [`Float`](crate::Float) to a point
+/// [`Interval`](super::Interval).
+pub mod from_float;
+/// Functions for converting a [`Rational`](malachite_q::Rational) to the narrowest
+/// [`Interval`](super::Interval) of a given precision that contains it.
+pub mod from_rational;
+/// Implementations of [`Display`](core::fmt::Display) and [`Debug`] for
+/// [`Interval`](super::Interval)s.
+pub mod to_string;
@@ -0,0 +1,72 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+use core::fmt::{Debug, Display, Formatter, Result};
+
+impl Display for Interval {
+    /// Converts an [`Interval`] to a [`String`](alloc::string::String).
+    ///
+    /// The output is `"[a, b]"`, where `a` and `b` are the endpoints written as
+    /// [`Float`](crate::Float)'s [`Display`] implementation writes them. Note that this is not an
+    /// enclosure: each endpoint is written with just enough digits to identify it among the
+    /// [`Float`](crate::Float)s of its precision, so the decimal strings themselves may lie
+    /// slightly inside the interval.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lower().complexity(),
+    /// self.upper().complexity())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Interval::ONE.to_string(), "[1.0, 1.0]");
+    /// assert_eq!(Interval::ENTIRE.to_string(), "[-Infinity, Infinity]");
+    /// assert_eq!(
+    ///     Interval::new(Float::from(-1.5), Float::from(0.25)).to_string(),
+    ///     "[-1.5, 0.25]"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "[{}, {}]", self.lower, self.upper)
+    }
+}
+
+impl Debug for Interval {
+    /// Converts an [`Interval`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lower().complexity(),
+    /// self.upper().complexity())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_float::interval::Interval;
+    ///
+    /// assert_eq!(Interval::ONE.to_debug_string(), "[1.0, 1.0]");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+#[cfg(feature = "test_build")]
+use malachite_base::num::basic::traits::{Infinity, NegativeInfinity};
+
+/// A closed interval of real numbers whose endpoints are [`Float`]s.
+///
+/// An `Interval` $[a, b]$ represents every real number $x$ with $a \leq x \leq b$. The endpoints
+/// are never `NaN`, the lower endpoint is never greater than the upper one, and infinite endpoints
+/// are allowed only on their own side: the lower endpoint may be $-\infty$ and the upper endpoint
+/// may be $\infty$, but an `Interval` never lies entirely at infinity. Each endpoint has its own
+/// precision, and, as with [`Float`]s, zero and infinite endpoints have none.
+///
+/// Every function on `Interval`s is an enclosure: if $x_1, x_2, \ldots$ lie in the input
+/// intervals, then $f(x_1, x_2, \ldots)$ lies in the output interval. This is achieved by rounding
+/// lower endpoints with `Floor` and upper endpoints with `Ceiling`, so the lower endpoint of a
+/// result is as large, and the upper endpoint as small, as the precision allows. Functions that
+/// take a precision round both endpoints of the result to it; the operators, like those of
+/// [`Float`], use the largest precision among the endpoints of the inputs.
+///
+/// The `Interval` type is designed to be similar to the `mpfi_t` type in
+/// [MPFI](https://gitlab.inria.fr/mpfi/mpfi). Since an `Interval` cannot have a `NaN` endpoint,
+/// functions whose MPFI counterparts would produce one either panic, if the input is outside the
+/// function's domain, or return the whole real line, if the result is unbounded in both directions
+/// (as happens when dividing by an interval that contains zero in its interior).
+///
+/// Equality of `Interval`s is equality of sets: two `Interval`s are equal if their endpoints are
+/// equal as [`Float`]s, so precisions and the signs of zero endpoints are ignored. Since "is $x$
+/// less than $y$" has no single answer for intervals, `Interval`s are not ordered; instead,
+/// functions such as [`Interval::less_than`] return `Some(true)` if the comparison holds for
+/// every pair of elements, `Some(false)` if it holds for none, and `None` otherwise.
+#[derive(Clone)]
+pub struct Interval {
+    pub(crate) lower: Float,
+    pub(crate) upper: Float,
+}
+
+impl Interval {
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.lower.is_valid()
+            && self.upper.is_valid()
+            && !self.lower.is_nan()
+            && !self.upper.is_nan()
+            && self.lower != Float::INFINITY
+            && self.upper != Float::NEGATIVE_INFINITY
+            && self.lower <= self.upper
+    }
+}
+
+// The interval whose endpoints are `lower` and `upper`, each computed by a directed rounding that
+// moved it outward. Directed rounding never produces a `NaN` or an infinity on the wrong side from
+// non-`NaN` inputs, so this only checks the invariant in debug builds.
+pub(crate) fn from_rounded_endpoints(lower: Float, upper: Float) -> Interval {
+    let x = Interval { lower, upper };
+    debug_assert!(!x.lower.is_nan() && !x.upper.is_nan() && x.lower <= x.upper);
+    x
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Basic traits for working with [`Interval`]s.
+pub mod basic;
+/// Traits for comparing [`Interval`]s for equality or order.
+pub mod comparison;
+/// Traits for converting to and from [`Interval`]s, including converting [`Interval`]s to
+/// strings.
+pub mod conversion;
+/// Set operations on [`Interval`]s: hulls, intersections, and containment.
+pub mod set_operations;
@@ -0,0 +1,100 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::Interval;
+use malachite_q::Rational;
+
+impl Interval {
+    /// Determines whether an [`Interval`] contains a [`Float`].
+    ///
+    /// A `NaN` is not contained in any [`Interval`], and an infinity is contained only in an
+    /// [`Interval`] that has it as an endpoint.
+    ///
+    /// $f([a, b], x) = (a \leq x \leq b)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert!(x.contains(&Float::from(2)));
+    /// assert!(x.contains(&Float::from(2.5)));
+    /// assert!(!x.contains(&Float::ONE));
+    /// assert!(!x.contains(&Float::NAN));
+    /// assert!(Interval::ENTIRE.contains(&Float::INFINITY));
+    /// ```
+    pub fn contains(&self, x: &Float) -> bool {
+        self.lower <= *x && *x <= self.upper
+    }
+
+    /// Determines whether an [`Interval`] contains a [`Rational`].
+    ///
+    /// $f([a, b], x) = (a \leq x \leq b)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::new(Float::from(0.25), Float::from(0.5));
+    /// assert!(x.contains_rational(&Rational::from_signeds(1, 3)));
+    /// assert!(x.contains_rational(&Rational::from_signeds(1, 2)));
+    /// assert!(!x.contains_rational(&Rational::from_signeds(2, 3)));
+    /// ```
+    pub fn contains_rational(&self, x: &Rational) -> bool {
+        self.lower <= *x && self.upper >= *x
+    }
+
+    /// Determines whether an [`Interval`] contains every element of another [`Interval`].
+    ///
+    /// $f([a, b], [c, d]) = (a \leq c \\ \text{and} \\ d \leq b)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `min(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::ZERO, Float::from(2));
+    /// assert!(x.contains_interval(&Interval::ONE));
+    /// assert!(x.contains_interval(&x));
+    /// assert!(!x.contains_interval(&Interval::new(Float::from(1.5), Float::from(2.5))));
+    /// assert!(Interval::ENTIRE.contains_interval(&x));
+    /// ```
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+}
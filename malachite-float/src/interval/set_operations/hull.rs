@@ -0,0 +1,56 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+
+impl Interval {
+    /// Returns the smallest [`Interval`] containing two [`Interval`]s, taking both by reference.
+    ///
+    /// The endpoints of the result are copied from the inputs, so no rounding takes place.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = [\min(a, c), \max(b, d)].
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Interval::ZERO.hull(&Interval::ONE).to_string(), "[0.0, 1.0]");
+    /// assert_eq!(
+    ///     Interval::new(Float::from(1.5), Float::from(2.5))
+    ///         .hull(&Interval::ONE)
+    ///         .to_string(),
+    ///     "[1.0, 2.5]"
+    /// );
+    /// ```
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            lower: if other.lower < self.lower {
+                other.lower.clone()
+            } else {
+                self.lower.clone()
+            },
+            upper: if other.upper > self.upper {
+                other.upper.clone()
+            } else {
+                self.upper.clone()
+            },
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+
+impl Interval {
+    /// Returns the intersection of two [`Interval`]s, taking both by reference. If the
+    /// [`Interval`]s are disjoint, `None` is returned.
+    ///
+    /// The endpoints of the result are copied from the inputs, so no rounding takes place.
+    ///
+    /// $$
+    /// f([a, b], [c, d]) = \\begin{cases}
+    ///     \operatorname{Some}([\max(a, c), \min(b, d)]) & \text{if} \\quad \max(a, c) \leq
+    ///     \min(b, d), \\\\
+    ///     \operatorname{None} & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::ZERO, Float::from(2));
+    /// let y = Interval::new(Float::from(1.5), Float::from(2.5));
+    /// assert_eq!(x.intersection(&y).unwrap().to_string(), "[1.5, 2.0]");
+    /// assert_eq!(x.intersection(&Interval::ONE).unwrap().to_string(), "[1.0, 1.0]");
+    /// assert_eq!(Interval::ZERO.intersection(&y), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lower = if other.lower > self.lower {
+            &other.lower
+        } else {
+            &self.lower
+        };
+        let upper = if other.upper < self.upper {
+            &other.upper
+        } else {
+            &self.upper
+        };
+        if lower <= upper {
+            Some(Self {
+                lower: lower.clone(),
+                upper: upper.clone(),
+            })
+        } else {
+            None
+        }
+    }
+}
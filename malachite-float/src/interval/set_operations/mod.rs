@@ -0,0 +1,15 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Determining whether an [`Interval`](super::Interval) contains a number or another
+/// [`Interval`](super::Interval).
+pub mod contains;
+/// The smallest [`Interval`](super::Interval) containing two others.
+pub mod hull;
+/// The intersection of two [`Interval`](super::Interval)s.
+pub mod intersection;
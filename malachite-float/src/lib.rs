@@ -287,6 +287,41 @@ where
     (emulate_finish(result, o), quo)
 }
 
+#[allow(clippy::type_repetition_in_bounds)]
+#[doc(hidden)]
+pub fn emulate_float_to_float_and_sign_fn<
+    T: PrimitiveFloat,
+    F: Fn(Float, u64) -> (Float, Ordering, Ordering),
+>(
+    f: F,
+    x: T,
+) -> (T, Ordering)
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float>,
+{
+    let x = Float::from(x);
+    let (result, sign, o) = f(x, T::MANTISSA_WIDTH + 1);
+    (emulate_finish(result, o), sign)
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+#[doc(hidden)]
+pub fn emulate_rational_to_float_and_sign_fn<
+    T: PrimitiveFloat,
+    F: Fn(&Rational, u64) -> (Float, Ordering, Ordering),
+>(
+    f: F,
+    x: &Rational,
+) -> (T, Ordering)
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float>,
+{
+    let (result, sign, o) = f(x, T::MANTISSA_WIDTH + 1);
+    (emulate_finish(result, o), sign)
+}
+
 #[allow(clippy::type_repetition_in_bounds)]
 #[doc(hidden)]
 pub fn emulate_rational_to_float_fn<T: PrimitiveFloat, F: Fn(&Rational, u64) -> (Float, Ordering)>(
@@ -413,5 +448,12 @@ pub(crate) use float::{
     InnerFloat, TWICE_WIDTH, WIDTH_MINUS_1, floor_and_ceiling, significand_bits,
 };
 
+/// [`Interval`](interval::Interval), closed intervals with [`Float`] endpoints, for interval
+/// arithmetic.
+pub mod interval;
+
+/// [`Ball`](ball::Ball), midpoint-radius balls with [`Float`] midpoints, for ball arithmetic.
+pub mod ball;
+
 #[cfg(feature = "test_build")]
 pub mod test_util;
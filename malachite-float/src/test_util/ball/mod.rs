@@ -0,0 +1,67 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::ball::Ball;
+use crate::test_util::common::{parse_hex_string, to_hex_string};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, Ceiling, Floor};
+use malachite_q::Rational;
+
+pub fn parse_ball_hex(mid_hex: &str, rad_hex: &str) -> Ball {
+    Ball::new(parse_hex_string(mid_hex), parse_hex_string(rad_hex))
+}
+
+// A few elements of a bounded `Ball`: its midpoint, and its endpoints rounded towards the midpoint
+// to a precision high enough that they usually stay exact. Every function on `Ball`s must map these
+// to elements of its result.
+pub fn ball_sample_floats(x: &Ball) -> Vec<Float> {
+    let mut xs = vec![x.mid().clone()];
+    if x.is_bounded() && !x.is_exact() {
+        let prec = x.mid().get_prec().unwrap_or(1) + 64;
+        xs.push(x.mid().sub_prec_round_ref_ref(x.rad(), prec, Ceiling).0);
+        xs.push(x.mid().add_prec_round_ref_ref(x.rad(), prec, Floor).0);
+    }
+    xs.into_iter().filter(Float::is_finite).collect()
+}
+
+// Same as `ball_sample_floats`, but returning `Rational`s.
+pub fn ball_sample_points(x: &Ball) -> Vec<Rational> {
+    ball_sample_floats(x)
+        .iter()
+        .map(Rational::exact_from)
+        .collect()
+}
+
+// The hexadecimal representations of a `Ball`'s midpoint and radius, so that tests can check their
+// precisions and the signs of zeros.
+pub fn ball_to_hex_string(x: &Ball) -> String {
+    format!(
+        "[{} +/- {}]",
+        to_hex_string(x.mid()),
+        to_hex_string(x.rad())
+    )
+}
+
+// Whether the exact value of a function could lie in a `Ball`, given the function's value rounded
+// down and rounded up. If the value is in the `Ball`, this returns `true`. The `Ball`'s endpoints
+// are rounded outward rather than computed exactly, since converting `Float`s with extreme
+// exponents to `Rational`s is very slow.
+pub fn ball_may_contain<F: Fn(RoundingMode) -> Float>(x: &Ball, f: F) -> bool {
+    if !x.is_bounded() {
+        return true;
+    }
+    let prec = x.mid().significant_bits() + 64;
+    f(Floor) <= x.mid().add_prec_round_ref_ref(x.rad(), prec, Ceiling).0
+        && f(Ceiling) >= x.mid().sub_prec_round_ref_ref(x.rad(), prec, Floor).0
+}
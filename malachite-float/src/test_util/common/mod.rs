@@ -8,6 +8,7 @@
 
 use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{ComparableFloatRef, Float, significand_bits};
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{
     Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, NegativeZero, Zero as ZeroTrait,
@@ -17,6 +18,7 @@ use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
+use malachite_q::Rational;
 use rug::float::{Round, Special};
 use std::cmp::Ordering;
 
@@ -41,7 +43,7 @@ pub const fn rug_round_try_from_rounding_mode(rm: RoundingMode) -> Result<Round,
         Down => Ok(Round::Zero),
         Up => Ok(Round::AwayZero),
         Nearest => Ok(Round::Nearest),
-        Exact => Err(()),
+        NearestTiesAway | Odd | Exact => Err(()),
     }
 }
 
@@ -102,6 +104,33 @@ pub fn rug_float_significant_bits(x: &rug::Float) -> u64 {
     }
 }
 
+// Evaluates `f` at rug `Float` approximations of a `Rational` `x`, starting at `prec` bits and
+// doubling the precision until two successive results agree. This is needed when the function is
+// ill-conditioned at `x`, as sin is near a multiple of pi, so that no fixed amount of extra
+// precision is enough. If `x` is dyadic and `prec` is at least its numerator's bit count, the first
+// approximation is exact, so it is used directly; results that don't compare equal to themselves,
+// like `NaN`, would otherwise never converge.
+pub fn rug_rational_converged<T: PartialEq, F: Fn(&rug::Float) -> T>(
+    x: &Rational,
+    mut prec: u64,
+    f: F,
+) -> T {
+    let exact = x.denominator_ref().is_power_of_2() && prec >= x.numerator_ref().significant_bits();
+    let x = rug::Rational::exact_from(x);
+    let mut previous = f(&rug::Float::with_val(u32::exact_from(prec), &x));
+    if exact {
+        return previous;
+    }
+    loop {
+        prec <<= 1;
+        let result = f(&rug::Float::with_val(u32::exact_from(prec), &x));
+        if result == previous {
+            return result;
+        }
+        previous = result;
+    }
+}
+
 impl TryFrom<&Float> for rug::Float {
     type Error = ();
 
@@ -0,0 +1,67 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::common::{rug_float_significant_bits, rug_rational_converged};
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_q::Rational;
+use rug::float::Round;
+use rug::ops::AssignRound;
+
+pub fn rug_bessel_jn_prec_round(
+    x: &rug::Float,
+    n: i64,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    // MPFR's `mpfr_jn` skips its asymptotic expansion when n is negative, and the power series it
+    // falls back to needs an enormous amount of memory when x is large, so the identity
+    // J_(-n)(x) = (-1) ^ n J_n(x) is applied first. (MPFR returns +0 for infinite x regardless of
+    // n, so infinities are left alone.)
+    if n < 0 && n.odd() && !x.is_infinite() {
+        let rm = match rm {
+            Round::Up => Round::Down,
+            Round::Down => Round::Up,
+            rm => rm,
+        };
+        let (jn, o) = rug_bessel_jn_prec_round(x, -n, prec, rm);
+        return (-jn, o.reverse());
+    }
+    let mut jn = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = jn.assign_round(x.jn_ref(i32::exact_from(n.unsigned_abs())), rm);
+    (jn, o)
+}
+
+pub fn rug_bessel_jn_prec(x: &rug::Float, n: i64, prec: u64) -> (rug::Float, Ordering) {
+    rug_bessel_jn_prec_round(x, n, prec, Round::Nearest)
+}
+
+pub fn rug_bessel_jn_rational_prec_round(
+    x: &Rational,
+    n: i64,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_rational_converged(x, prec + 128 + x.numerator_ref().significant_bits(), |rx| {
+        rug_bessel_jn_prec_round(rx, n, prec, rm)
+    })
+}
+
+pub fn rug_bessel_jn_rational_prec(x: &Rational, n: i64, prec: u64) -> (rug::Float, Ordering) {
+    rug_bessel_jn_rational_prec_round(x, n, prec, Round::Nearest)
+}
+
+pub fn rug_bessel_jn_round(x: &rug::Float, n: i64, rm: Round) -> (rug::Float, Ordering) {
+    rug_bessel_jn_prec_round(x, n, rug_float_significant_bits(x), rm)
+}
+
+pub fn rug_bessel_jn(x: &rug::Float, n: i64) -> rug::Float {
+    rug_bessel_jn_prec_round(x, n, rug_float_significant_bits(x), Round::Nearest).0
+}
@@ -0,0 +1,106 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::common::rug_float_significant_bits;
+use crate::test_util::float::arithmetic::upper_incomplete_gamma::rug_rational_pair_converged;
+use core::cmp::{Ordering, max};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_q::Rational;
+use rug::float::Round;
+use rug::ops::AssignRound;
+
+// rug doesn't provide the lower incomplete gamma function, so γ(s, x) = Γ(s) - Γ(s, x) is bracketed
+// by computing Γ(s) and Γ(s, x) with directed rounding at increasing working precisions, until both
+// ends of the bracket round to the same value in the same direction. `s` and `x` must be finite and
+// positive.
+pub fn rug_lower_incomplete_gamma_prec_round(
+    s: &rug::Float,
+    x: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    assert!(s.is_sign_positive() && s.is_normal() && x.is_sign_positive() && x.is_normal());
+    let target_prec = u32::exact_from(prec);
+    let mut working_prec = prec + 64;
+    loop {
+        let wp = u32::exact_from(working_prec);
+        let mut g_lo = rug::Float::with_val(wp, 0);
+        g_lo.assign_round(s.gamma_ref(), Round::Down);
+        let mut g_hi = rug::Float::with_val(wp, 0);
+        g_hi.assign_round(s.gamma_ref(), Round::Up);
+        let mut u_lo = rug::Float::with_val(wp, 0);
+        u_lo.assign_round(s.gamma_inc_ref(x), Round::Down);
+        let mut u_hi = rug::Float::with_val(wp, 0);
+        u_hi.assign_round(s.gamma_inc_ref(x), Round::Up);
+        let mut lo = rug::Float::with_val(wp, 0);
+        lo.assign_round(&g_lo - &u_hi, Round::Down);
+        let mut hi = rug::Float::with_val(wp, 0);
+        hi.assign_round(&g_hi - &u_lo, Round::Up);
+        let mut lower_lo = rug::Float::with_val(target_prec, 0);
+        let o_lo = lower_lo.assign_round(&lo, rm);
+        let mut lower_hi = rug::Float::with_val(target_prec, 0);
+        let o_hi = lower_hi.assign_round(&hi, rm);
+        if lower_lo == lower_hi && o_lo == o_hi && lo.is_sign_positive() && lo.is_normal() {
+            return (lower_lo, o_lo);
+        }
+        working_prec <<= 1;
+    }
+}
+
+pub fn rug_lower_incomplete_gamma_prec(
+    s: &rug::Float,
+    x: &rug::Float,
+    prec: u64,
+) -> (rug::Float, Ordering) {
+    rug_lower_incomplete_gamma_prec_round(s, x, prec, Round::Nearest)
+}
+
+pub fn rug_lower_incomplete_gamma_round(
+    s: &rug::Float,
+    x: &rug::Float,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_lower_incomplete_gamma_prec_round(
+        s,
+        x,
+        max(rug_float_significant_bits(s), rug_float_significant_bits(x)),
+        rm,
+    )
+}
+
+pub fn rug_lower_incomplete_gamma(s: &rug::Float, x: &rug::Float) -> rug::Float {
+    rug_lower_incomplete_gamma_round(s, x, Round::Nearest).0
+}
+
+// Computes γ(s, x) for positive Rationals s and x, rounded to `prec` with mode `rm`.
+pub fn rug_lower_incomplete_gamma_rational_prec_round(
+    s: &Rational,
+    x: &Rational,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_rational_pair_converged(
+        s,
+        x,
+        prec + 128
+            + max(
+                s.numerator_ref().significant_bits(),
+                x.numerator_ref().significant_bits(),
+            ),
+        |rs, rx| rug_lower_incomplete_gamma_prec_round(rs, rx, prec, rm),
+    )
+}
+
+pub fn rug_lower_incomplete_gamma_rational_prec(
+    s: &Rational,
+    x: &Rational,
+    prec: u64,
+) -> (rug::Float, Ordering) {
+    rug_lower_incomplete_gamma_rational_prec_round(s, x, prec, Round::Nearest)
+}
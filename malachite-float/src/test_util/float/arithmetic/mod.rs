@@ -5,17 +5,39 @@
 // Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
-
+pub mod acos;
+pub mod acosh;
 pub mod add;
 pub mod add_mul;
 pub mod agm;
+pub mod airy_ai;
+pub mod asin;
+pub mod asinh;
+pub mod atan;
+pub mod atan2;
+pub mod atanh;
+pub mod bessel_j0;
+pub mod bessel_j1;
+pub mod bessel_jn;
+pub mod bessel_y0;
+pub mod bessel_y1;
+pub mod bessel_yn;
+pub mod cos;
+pub mod cosh;
+pub mod coth;
+pub mod csch;
+pub mod digamma;
 pub mod div;
+pub mod erf;
+pub mod erfc;
 pub mod exp;
 pub mod exp_x_minus_1;
 pub mod factorial;
+pub mod gamma;
 pub mod hypot;
 pub mod ln;
 pub mod ln_1_plus_x;
+pub mod ln_gamma;
 pub mod log_base;
 pub mod log_base_10;
 pub mod log_base_10_1_plus_x;
@@ -30,6 +52,7 @@ pub mod log_base_rational_base;
 pub mod log_base_rational_base_1_plus_x;
 pub mod log_base_rational_float_base;
 pub mod log_base_rational_rational_base;
+pub mod lower_incomplete_gamma;
 pub mod mul;
 pub mod mul_add_mul;
 pub mod mul_sub_mul;
@@ -44,11 +67,20 @@ pub mod reciprocal;
 pub mod reciprocal_sqrt;
 pub mod rem;
 pub mod root;
+pub mod sech;
 pub mod shl;
 pub mod shl_round;
 pub mod shr;
 pub mod shr_round;
+pub mod sin;
+pub mod sin_cos;
+pub mod sinh;
+pub mod sinh_cosh;
 pub mod sqrt;
 pub mod square;
 pub mod sub;
 pub mod sub_mul;
+pub mod tan;
+pub mod tanh;
+pub mod upper_incomplete_gamma;
+pub mod zeta;
@@ -0,0 +1,45 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::common::{rug_float_significant_bits, rug_rational_converged};
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_q::Rational;
+use rug::float::Round;
+use rug::ops::AssignRound;
+
+pub fn rug_tanh_prec_round(x: &rug::Float, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut tanh = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = tanh.assign_round(x.tanh_ref(), rm);
+    (tanh, o)
+}
+
+pub fn rug_tanh_prec(x: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_tanh_prec_round(x, prec, Round::Nearest)
+}
+
+// Computes tanh(x) for a Rational x, rounded to `prec` with mode `rm`. The Rational is converted to
+// rug `Float`s of increasing precision until the rounded results agree.
+pub fn rug_tanh_rational_prec_round(x: &Rational, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    rug_rational_converged(x, prec + 128 + x.numerator_ref().significant_bits(), |rx| {
+        rug_tanh_prec_round(rx, prec, rm)
+    })
+}
+
+pub fn rug_tanh_rational_prec(x: &Rational, prec: u64) -> (rug::Float, Ordering) {
+    rug_tanh_rational_prec_round(x, prec, Round::Nearest)
+}
+
+pub fn rug_tanh_round(x: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_tanh_prec_round(x, rug_float_significant_bits(x), rm)
+}
+
+pub fn rug_tanh(x: &rug::Float) -> rug::Float {
+    rug_tanh_prec_round(x, rug_float_significant_bits(x), Round::Nearest).0
+}
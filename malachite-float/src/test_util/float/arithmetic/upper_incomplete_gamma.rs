@@ -0,0 +1,118 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::common::rug_float_significant_bits;
+use core::cmp::{Ordering, max};
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_q::Rational;
+use rug::float::Round;
+use rug::ops::AssignRound;
+
+pub fn rug_upper_incomplete_gamma_prec_round(
+    s: &rug::Float,
+    x: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let mut gamma = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = gamma.assign_round(s.gamma_inc_ref(x), rm);
+    (gamma, o)
+}
+
+pub fn rug_upper_incomplete_gamma_prec(
+    s: &rug::Float,
+    x: &rug::Float,
+    prec: u64,
+) -> (rug::Float, Ordering) {
+    rug_upper_incomplete_gamma_prec_round(s, x, prec, Round::Nearest)
+}
+
+pub fn rug_upper_incomplete_gamma_round(
+    s: &rug::Float,
+    x: &rug::Float,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_upper_incomplete_gamma_prec_round(
+        s,
+        x,
+        max(rug_float_significant_bits(s), rug_float_significant_bits(x)),
+        rm,
+    )
+}
+
+pub fn rug_upper_incomplete_gamma(s: &rug::Float, x: &rug::Float) -> rug::Float {
+    rug_upper_incomplete_gamma_round(s, x, Round::Nearest).0
+}
+
+// Converts the Rationals `s` and `x` to rug `Float`s of increasing precision, starting at `prec`,
+// until the results of `f` agree. Both are converted exactly if their denominators are powers of 2
+// and `prec` is large enough. A NaN result is returned immediately.
+pub(crate) fn rug_rational_pair_converged<
+    F: Fn(&rug::Float, &rug::Float) -> (rug::Float, Ordering),
+>(
+    s: &Rational,
+    x: &Rational,
+    mut prec: u64,
+    f: F,
+) -> (rug::Float, Ordering) {
+    let exact = s.denominator_ref().is_power_of_2()
+        && x.denominator_ref().is_power_of_2()
+        && prec
+            >= max(
+                s.numerator_ref().significant_bits(),
+                x.numerator_ref().significant_bits(),
+            );
+    let s = rug::Rational::exact_from(s);
+    let x = rug::Rational::exact_from(x);
+    let g = |prec: u64| {
+        let p = u32::exact_from(prec);
+        f(&rug::Float::with_val(p, &s), &rug::Float::with_val(p, &x))
+    };
+    let mut previous = g(prec);
+    if exact || previous.0.is_nan() {
+        return previous;
+    }
+    loop {
+        prec <<= 1;
+        let result = g(prec);
+        if result == previous || result.0.is_nan() {
+            return result;
+        }
+        previous = result;
+    }
+}
+
+// Computes Γ(s, x) for Rationals s and x, rounded to `prec` with mode `rm`. This does not terminate
+// if s is a nonpositive integer and x is 0, so callers must exclude that case.
+pub fn rug_upper_incomplete_gamma_rational_prec_round(
+    s: &Rational,
+    x: &Rational,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_rational_pair_converged(
+        s,
+        x,
+        prec + 128
+            + max(
+                s.numerator_ref().significant_bits(),
+                x.numerator_ref().significant_bits(),
+            ),
+        |rs, rx| rug_upper_incomplete_gamma_prec_round(rs, rx, prec, rm),
+    )
+}
+
+pub fn rug_upper_incomplete_gamma_rational_prec(
+    s: &Rational,
+    x: &Rational,
+    prec: u64,
+) -> (rug::Float, Ordering) {
+    rug_upper_incomplete_gamma_rational_prec_round(s, x, prec, Round::Nearest)
+}
@@ -0,0 +1,49 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::common::{rug_float_significant_bits, rug_rational_converged};
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_q::Rational;
+use rug::float::Round;
+use rug::ops::AssignRound;
+
+pub fn rug_zeta_prec_round(x: &rug::Float, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut zeta = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = zeta.assign_round(x.zeta_ref(), rm);
+    (zeta, o)
+}
+
+pub fn rug_zeta_prec(x: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_zeta_prec_round(x, prec, Round::Nearest)
+}
+
+pub fn rug_zeta_rational_prec_round(x: &Rational, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    rug_rational_converged(x, prec + 128 + x.numerator_ref().significant_bits(), |rx| {
+        rug_zeta_prec_round(rx, prec, rm)
+    })
+}
+
+pub fn rug_zeta_rational_prec(x: &Rational, prec: u64) -> (rug::Float, Ordering) {
+    rug_zeta_rational_prec_round(x, prec, Round::Nearest)
+}
+
+pub fn rug_zeta_round(x: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_zeta_prec_round(x, rug_float_significant_bits(x), rm)
+}
+
+pub fn rug_zeta(x: &rug::Float) -> rug::Float {
+    rug_zeta_prec_round(x, rug_float_significant_bits(x), Round::Nearest).0
+}
+
+pub fn rug_zeta_u64_prec_round(n: u64, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut zeta = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = zeta.assign_round(rug::Float::zeta_u(u32::exact_from(n)), rm);
+    (zeta, o)
+}
@@ -6,9 +6,12 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub mod apery;
+pub mod catalan;
 pub mod cbrt_2;
 pub mod digit_constants;
 pub mod e;
+pub mod euler_gamma;
 pub mod lemniscate_constant;
 pub mod ln_10;
 pub mod ln_2;
@@ -7,11 +7,16 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::Float;
+use crate::ball::Ball;
 use crate::float::conversion::string::get_str::get_str;
 use crate::float::conversion::string::strtofr::strtofr;
-use crate::test_util::common::rug_round_exact_from_rounding_mode;
+use crate::interval::Interval;
+use crate::test_util::common::{
+    rug_round_exact_from_rounding_mode, rug_round_try_from_rounding_mode,
+};
 use alloc::string::{String, ToString};
 use core::cmp::Ordering::Equal;
+use malachite_base::num::arithmetic::traits::Abs;
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::string::options::FromSciStringOptions;
@@ -362,16 +367,19 @@ pub fn float_primitive_float_pair_rm<T: PrimitiveFloat>(
 pub fn float_t_rounding_mode_triple_rm<T: Clone + 'static>(
     xs: It<(Float, T, RoundingMode)>,
 ) -> It<((rug::Float, T, rug::float::Round), (Float, T, RoundingMode))> {
-    Box::new(xs.filter(|(_, _, rm)| *rm != Exact).map(|(x, p, rm)| {
-        (
-            (
-                rug::Float::exact_from(&x),
-                p.clone(),
-                rug_round_exact_from_rounding_mode(rm),
-            ),
-            (x, p, rm),
-        )
-    }))
+    Box::new(
+        xs.filter(|(_, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
+            .map(|(x, p, rm)| {
+                (
+                    (
+                        rug::Float::exact_from(&x),
+                        p.clone(),
+                        rug_round_exact_from_rounding_mode(rm),
+                    ),
+                    (x, p, rm),
+                )
+            }),
+    )
 }
 
 pub fn float_t_u_triple_rm<T: Clone + 'static, U: Clone + 'static>(
@@ -394,17 +402,20 @@ pub fn string_u_u_rounding_mode_quadruple_rm(
     (String, i32, u32, rug::float::Round),
     (String, u8, u64, RoundingMode),
 )> {
-    Box::new(xs.map(|(s, base, prec, rm)| {
-        (
-            (
-                s.clone(),
-                i32::from(base),
-                u32::exact_from(prec),
-                rug_round_exact_from_rounding_mode(rm),
-            ),
-            (s, base, prec, rm),
-        )
-    }))
+    Box::new(
+        xs.filter(|(_, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
+            .map(|(s, base, prec, rm)| {
+                (
+                    (
+                        s.clone(),
+                        i32::from(base),
+                        u32::exact_from(prec),
+                        rug_round_exact_from_rounding_mode(rm),
+                    ),
+                    (s, base, prec, rm),
+                )
+            }),
+    )
 }
 
 pub fn float_t_u_rounding_mode_quadruple_rm<T: Clone + 'static, U: Clone + 'static>(
@@ -414,7 +425,7 @@ pub fn float_t_u_rounding_mode_quadruple_rm<T: Clone + 'static, U: Clone + 'stat
     (Float, T, U, RoundingMode),
 )> {
     Box::new(
-        xs.filter(|(_, _, _, rm)| *rm != Exact)
+        xs.filter(|(_, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
             .map(|(x, p, q, rm)| {
                 (
                     (
@@ -432,15 +443,18 @@ pub fn float_t_u_rounding_mode_quadruple_rm<T: Clone + 'static, U: Clone + 'stat
 pub fn float_rounding_mode_pair_rm(
     xs: It<(Float, RoundingMode)>,
 ) -> It<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
-    Box::new(xs.filter(|(_, rm)| *rm != Exact).map(|(x, rm)| {
-        (
-            (
-                rug::Float::exact_from(&x),
-                rug_round_exact_from_rounding_mode(rm),
-            ),
-            (x, rm),
-        )
-    }))
+    Box::new(
+        xs.filter(|(_, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
+            .map(|(x, rm)| {
+                (
+                    (
+                        rug::Float::exact_from(&x),
+                        rug_round_exact_from_rounding_mode(rm),
+                    ),
+                    (x, rm),
+                )
+            }),
+    )
 }
 
 pub fn float_float_rounding_mode_triple_rm(
@@ -449,16 +463,19 @@ pub fn float_float_rounding_mode_triple_rm(
     (rug::Float, rug::Float, rug::float::Round),
     (Float, Float, RoundingMode),
 )> {
-    Box::new(xs.filter(|(_, _, rm)| *rm != Exact).map(|(x, y, rm)| {
-        (
-            (
-                rug::Float::exact_from(&x),
-                rug::Float::exact_from(&y),
-                rug_round_exact_from_rounding_mode(rm),
-            ),
-            (x, y, rm),
-        )
-    }))
+    Box::new(
+        xs.filter(|(_, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
+            .map(|(x, y, rm)| {
+                (
+                    (
+                        rug::Float::exact_from(&x),
+                        rug::Float::exact_from(&y),
+                        rug_round_exact_from_rounding_mode(rm),
+                    ),
+                    (x, y, rm),
+                )
+            }),
+    )
 }
 
 pub fn float_float_anything_triple_rm<T: Clone + 'static>(
@@ -497,16 +514,19 @@ pub fn float_rational_rounding_mode_triple_rm(
     (rug::Float, rug::Rational, rug::float::Round),
     (Float, Rational, RoundingMode),
 )> {
-    Box::new(xs.filter(|(_, _, rm)| *rm != Exact).map(|(x, y, rm)| {
-        (
-            (
-                rug::Float::exact_from(&x),
-                rug::Rational::exact_from(&y),
-                rug_round_exact_from_rounding_mode(rm),
-            ),
-            (x, y, rm),
-        )
-    }))
+    Box::new(
+        xs.filter(|(_, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
+            .map(|(x, y, rm)| {
+                (
+                    (
+                        rug::Float::exact_from(&x),
+                        rug::Rational::exact_from(&y),
+                        rug_round_exact_from_rounding_mode(rm),
+                    ),
+                    (x, y, rm),
+                )
+            }),
+    )
 }
 
 #[allow(clippy::type_complexity)]
@@ -564,7 +584,7 @@ pub fn float_float_float_float_rounding_mode_quintuple_rm(
     (Float, Float, Float, Float, RoundingMode),
 )> {
     Box::new(
-        xs.filter(|(_, _, _, _, rm)| *rm != Exact)
+        xs.filter(|(_, _, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
             .map(|(a, b, c, d, rm)| {
                 (
                     (
@@ -595,7 +615,7 @@ pub fn float_float_float_float_anything_rounding_mode_sextuple_rm<T: Clone + 'st
     (Float, Float, Float, Float, T, RoundingMode),
 )> {
     Box::new(
-        xs.filter(|(_, _, _, _, _, rm)| *rm != Exact)
+        xs.filter(|(_, _, _, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
             .map(|(a, b, c, d, w, rm)| {
                 (
                     (
@@ -655,7 +675,7 @@ pub fn float_float_float_rounding_mode_quadruple_rm(
     (Float, Float, Float, RoundingMode),
 )> {
     Box::new(
-        xs.filter(|(_, _, _, rm)| *rm != Exact)
+        xs.filter(|(_, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
             .map(|(x, y, z, rm)| {
                 (
                     (
@@ -678,7 +698,7 @@ pub fn float_float_float_anything_rounding_mode_quintuple_rm<T: Clone + 'static>
     (Float, Float, Float, T, RoundingMode),
 )> {
     Box::new(
-        xs.filter(|(_, _, _, _, rm)| *rm != Exact)
+        xs.filter(|(_, _, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
             .map(|(x, y, z, w, rm)| {
                 (
                     (
@@ -701,7 +721,7 @@ pub fn float_float_anything_rounding_mode_quadruple_rm<T: Clone + 'static>(
     (Float, Float, T, RoundingMode),
 )> {
     Box::new(
-        xs.filter(|(_, _, _, rm)| *rm != Exact)
+        xs.filter(|(_, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
             .map(|(x, y, z, rm)| {
                 (
                     (
@@ -723,7 +743,7 @@ pub fn float_rational_anything_rounding_mode_quadruple_rm<T: Clone + 'static>(
     (Float, Rational, T, RoundingMode),
 )> {
     Box::new(
-        xs.filter(|(_, _, _, rm)| *rm != Exact)
+        xs.filter(|(_, _, _, rm)| rug_round_try_from_rounding_mode(*rm).is_ok())
             .map(|(x, y, z, rm)| {
                 (
                     (
@@ -737,3 +757,65 @@ pub fn float_rational_anything_rounding_mode_quadruple_rm<T: Clone + 'static>(
             }),
     )
 }
+
+// The `Interval` whose endpoints are `x` and `y`, in increasing order, or `None` if either is `NaN`
+// or if both are the same infinity.
+fn interval_from_floats(x: Float, y: Float) -> Option<Interval> {
+    if x.is_nan() || y.is_nan() || x == y && x.is_infinite() {
+        None
+    } else if x <= y {
+        Some(Interval::new(x, y))
+    } else {
+        Some(Interval::new(y, x))
+    }
+}
+
+pub fn float_pair_to_interval(xs: It<(Float, Float)>) -> It<Interval> {
+    Box::new(xs.filter_map(|(x, y)| interval_from_floats(x, y)))
+}
+
+pub fn float_triple_to_interval_float_pair(xs: It<(Float, Float, Float)>) -> It<(Interval, Float)> {
+    Box::new(xs.filter_map(|(x, y, z)| Some((interval_from_floats(x, y)?, z))))
+}
+
+pub fn float_quadruple_to_interval_pair(
+    xs: It<(Float, Float, Float, Float)>,
+) -> It<(Interval, Interval)> {
+    Box::new(xs.filter_map(|(x, y, z, w)| {
+        Some((interval_from_floats(x, y)?, interval_from_floats(z, w)?))
+    }))
+}
+
+pub fn float_float_anything_triple_to_interval_anything_pair<T: 'static>(
+    xs: It<(Float, Float, T)>,
+) -> It<(Interval, T)> {
+    Box::new(xs.filter_map(|(x, y, z)| Some((interval_from_floats(x, y)?, z))))
+}
+
+// The `Ball` whose midpoint is `x` and whose radius is `|y|`, or `None` if `x` is not finite or `y`
+// is `NaN`.
+fn ball_from_floats(x: Float, y: Float) -> Option<Ball> {
+    if !x.is_finite() || y.is_nan() {
+        None
+    } else {
+        Some(Ball::new(x, y.abs()))
+    }
+}
+
+pub fn float_pair_to_ball(xs: It<(Float, Float)>) -> It<Ball> {
+    Box::new(xs.filter_map(|(x, y)| ball_from_floats(x, y)))
+}
+
+pub fn float_triple_to_ball_float_pair(xs: It<(Float, Float, Float)>) -> It<(Ball, Float)> {
+    Box::new(xs.filter_map(|(x, y, z)| Some((ball_from_floats(x, y)?, z))))
+}
+
+pub fn float_quadruple_to_ball_pair(xs: It<(Float, Float, Float, Float)>) -> It<(Ball, Ball)> {
+    Box::new(xs.filter_map(|(x, y, z, w)| Some((ball_from_floats(x, y)?, ball_from_floats(z, w)?))))
+}
+
+pub fn float_float_anything_triple_to_ball_anything_pair<T: 'static>(
+    xs: It<(Float, Float, T)>,
+) -> It<(Ball, T)> {
+    Box::new(xs.filter_map(|(x, y, z)| Some((ball_from_floats(x, y)?, z))))
+}
@@ -5140,8 +5140,10 @@ pub fn exhaustive_float_rounding_mode_pair_gen() -> It<(Float, RoundingMode)> {
 
 pub(crate) fn natural_rounding_from_float_valid(f: &Float, rm: RoundingMode) -> bool {
     match rm {
-        Down | Ceiling | Nearest => f.is_finite() || *f == Float::NEGATIVE_INFINITY,
-        Up | Floor => f.is_finite() && (f.is_sign_positive() || f.is_negative_zero()),
+        Down | Ceiling | Nearest | NearestTiesAway => {
+            f.is_finite() || *f == Float::NEGATIVE_INFINITY
+        }
+        Up | Floor | Odd => f.is_finite() && (f.is_sign_positive() || f.is_negative_zero()),
         Exact => Natural::convertible_from(f),
     }
 }
@@ -5185,9 +5187,9 @@ where
     for<'a> T: ConvertibleFrom<&'a Float>,
 {
     match rm {
-        Floor => f.is_sign_positive() || f.is_negative_zero(),
+        Floor | Odd => f.is_sign_positive() || f.is_negative_zero(),
         Ceiling => *f <= T::MAX,
-        Down | Nearest => !f.is_nan(),
+        Down | Nearest | NearestTiesAway => !f.is_nan(),
         Up => (f.is_sign_positive() || f.is_negative_zero()) && *f <= T::MAX,
         Exact => T::convertible_from(f),
     }
@@ -5216,9 +5218,9 @@ where
     for<'a> T: ConvertibleFrom<&'a Float>,
 {
     match rm {
-        Floor => *f >= T::MIN,
+        Floor | Odd => *f >= T::MIN,
         Ceiling => *f <= T::MAX,
-        Down | Nearest => !f.is_nan(),
+        Down | Nearest | NearestTiesAway => !f.is_nan(),
         Up => *f >= T::MIN && *f <= T::MAX,
         Exact => T::convertible_from(f),
     }
@@ -6563,6 +6565,101 @@ pub fn exhaustive_rational_rational_unsigned_rounding_mode_quadruple_gen_var_3()
     )
 }
 
+// Whether `(x, y, prec, rm)` is a valid input to `Float::beta_rational_prec_round`.
+pub fn beta_rational_prec_round_valid(
+    x: &Rational,
+    y: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != Exact || Float::beta_rational_prec_round_ref_ref(x, y, prec, Floor).1 == Equal
+}
+
+// All `(Rational, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::beta_rational_prec_round`.
+pub fn exhaustive_rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+-> It<(Rational, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_rationals(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(x, y, prec, rm)| beta_rational_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+// Whether `(s, x, prec, rm)` is a valid input to
+// `Float::upper_incomplete_gamma_rational_prec_round`.
+pub fn upper_incomplete_gamma_rational_prec_round_valid(
+    s: &Rational,
+    x: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != Exact
+        || Float::upper_incomplete_gamma_rational_prec_round_ref_ref(s, x, prec, Floor).1 == Equal
+}
+
+// All `(Rational, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::upper_incomplete_gamma_rational_prec_round`.
+pub fn exhaustive_rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+-> It<(Rational, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_rationals(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(s, x, prec, rm)| {
+            upper_incomplete_gamma_rational_prec_round_valid(s, x, *prec, *rm)
+        }),
+    )
+}
+
+// Whether `(s, x, prec, rm)` is a valid input to
+// `Float::lower_incomplete_gamma_rational_prec_round`.
+pub fn lower_incomplete_gamma_rational_prec_round_valid(
+    s: &Rational,
+    x: &Rational,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != Exact
+        || Float::lower_incomplete_gamma_rational_prec_round_ref_ref(s, x, prec, Floor).1 == Equal
+}
+
+// All `(Rational, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::lower_incomplete_gamma_rational_prec_round`.
+pub fn exhaustive_rational_rational_unsigned_rounding_mode_quadruple_gen_var_6()
+-> It<(Rational, Rational, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_rationals(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(s, x, prec, rm)| {
+            lower_incomplete_gamma_rational_prec_round_valid(s, x, *prec, *rm)
+        }),
+    )
+}
+
 // -- (Rational, Float, PrimitiveUnsigned, RoundingMode) --
 
 pub fn log_base_rational_float_base_prec_round_valid(
@@ -6758,6 +6855,533 @@ pub fn exhaustive_float_rounding_mode_pair_gen_var_48() -> It<(Float, RoundingMo
     )
 }
 
+// Whether `(x, prec, rm)` is a valid input to `Float::asin_prec_round`.
+pub fn asin_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || x.asin_prec_round_ref(prec, Floor).1 == Equal
+}
+
+// Whether `(x, rm)` is a valid input to `Float::asin_round`.
+pub fn asin_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || x.asin_round_ref(Floor).1 == Equal
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::acos_prec_round`.
+pub fn acos_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || x.acos_prec_round_ref(prec, Floor).1 == Equal
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::atan_prec_round`.
+pub fn atan_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || x.atan_prec_round_ref(prec, Floor).1 == Equal
+}
+
+// Whether `(x, rm)` is a valid input to `Float::acos_round`.
+pub fn acos_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || x.acos_round_ref(Floor).1 == Equal
+}
+
+// Whether `(x, rm)` is a valid input to `Float::atan_round`.
+pub fn atan_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || x.atan_round_ref(Floor).1 == Equal
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::asin_rational_prec_round`.
+pub fn asin_rational_prec_round_valid(x: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || Float::asin_rational_prec_round_ref(x, prec, Floor).1 == Equal
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::acos_rational_prec_round`.
+pub fn acos_rational_prec_round_valid(x: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || Float::acos_rational_prec_round_ref(x, prec, Floor).1 == Equal
+}
+
+// Whether `(y, x, prec, rm)` is a valid input to `Float::atan2_prec_round`.
+pub fn atan2_prec_round_valid(y: &Float, x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || y.atan2_prec_round_ref_ref(x, prec, Floor).1 == Equal
+}
+
+// Whether `(y, x, rm)` is a valid input to `Float::atan2_round`.
+pub fn atan2_round_valid(y: &Float, x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || y.atan2_round_ref_ref(x, Floor).1 == Equal
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.asin_prec_round`.
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_38() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| asin_prec_round_valid(x, p, rm)),
+    ))
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.acos_prec_round`.
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_39() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| acos_prec_round_valid(x, p, rm)),
+    ))
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.atan_prec_round`.
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_40() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| atan_prec_round_valid(x, p, rm)),
+    ))
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.asin_round`.
+pub fn exhaustive_float_rounding_mode_pair_gen_var_49() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| asin_round_valid(f, *rm)),
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.acos_round`.
+pub fn exhaustive_float_rounding_mode_pair_gen_var_50() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| acos_round_valid(f, *rm)),
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.atan_round`.
+pub fn exhaustive_float_rounding_mode_pair_gen_var_51() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| atan_round_valid(f, *rm)),
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::asin_rational_prec_round`.
+pub fn exhaustive_rational_unsigned_rounding_mode_triple_gen_var_11()
+-> It<(Rational, u64, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_rationals(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, prec), rm)| asin_rational_prec_round_valid(x, prec, rm)),
+    ))
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::acos_rational_prec_round`.
+pub fn exhaustive_rational_unsigned_rounding_mode_triple_gen_var_12()
+-> It<(Rational, u64, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_rationals(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, prec), rm)| acos_rational_prec_round_valid(x, prec, rm)),
+    ))
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::atan2_prec_round`.
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_26()
+-> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_floats(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(y, x, prec, rm)| atan2_prec_round_valid(y, x, *prec, *rm)),
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::atan2_round`.
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_45() -> It<(Float, Float, RoundingMode)>
+{
+    Box::new(
+        reshape_2_1_to_3(Box::new(lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(y, x, rm)| atan2_round_valid(y, x, *rm)),
+    )
+}
+
+// Whether `(x, y, prec, rm)` is a valid input to `Float::beta_prec_round`.
+pub fn beta_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || x.beta_prec_round_ref_ref(y, prec, Floor).1 == Equal
+}
+
+// Whether `(x, y, rm)` is a valid input to `Float::beta_round`.
+pub fn beta_round_valid(x: &Float, y: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || x.beta_round_ref_ref(y, Floor).1 == Equal
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::beta_prec_round`.
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+-> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_floats(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(x, y, prec, rm)| beta_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::beta_round`.
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_46() -> It<(Float, Float, RoundingMode)>
+{
+    Box::new(
+        reshape_2_1_to_3(Box::new(lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(x, y, rm)| beta_round_valid(x, y, *rm)),
+    )
+}
+
+// Whether `(s, x, prec, rm)` is a valid input to `Float::upper_incomplete_gamma_prec_round`.
+pub fn upper_incomplete_gamma_prec_round_valid(
+    s: &Float,
+    x: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != Exact
+        || s.upper_incomplete_gamma_prec_round_ref_ref(x, prec, Floor)
+            .1
+            == Equal
+}
+
+// Whether `(s, x, rm)` is a valid input to `Float::upper_incomplete_gamma_round`.
+pub fn upper_incomplete_gamma_round_valid(s: &Float, x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || s.upper_incomplete_gamma_round_ref_ref(x, Floor).1 == Equal
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to
+// `Float::upper_incomplete_gamma_prec_round`.
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+-> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_floats(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(s, x, prec, rm)| upper_incomplete_gamma_prec_round_valid(s, x, *prec, *rm)),
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to
+// `Float::upper_incomplete_gamma_round`.
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_47() -> It<(Float, Float, RoundingMode)>
+{
+    Box::new(
+        reshape_2_1_to_3(Box::new(lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(s, x, rm)| upper_incomplete_gamma_round_valid(s, x, *rm)),
+    )
+}
+
+// Whether `(s, x, prec, rm)` is a valid input to `Float::lower_incomplete_gamma_prec_round`.
+pub fn lower_incomplete_gamma_prec_round_valid(
+    s: &Float,
+    x: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != Exact
+        || s.lower_incomplete_gamma_prec_round_ref_ref(x, prec, Floor)
+            .1
+            == Equal
+}
+
+// Whether `(s, x, rm)` is a valid input to `Float::lower_incomplete_gamma_round`.
+pub fn lower_incomplete_gamma_round_valid(s: &Float, x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || s.lower_incomplete_gamma_round_ref_ref(x, Floor).1 == Equal
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to
+// `Float::lower_incomplete_gamma_prec_round`.
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_29()
+-> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_floats(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(s, x, prec, rm)| lower_incomplete_gamma_prec_round_valid(s, x, *prec, *rm)),
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to
+// `Float::lower_incomplete_gamma_round`.
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_48() -> It<(Float, Float, RoundingMode)>
+{
+    Box::new(
+        reshape_2_1_to_3(Box::new(lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|(s, x, rm)| lower_incomplete_gamma_round_valid(s, x, *rm)),
+    )
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::acosh_prec_round`.
+pub fn acosh_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || x.acosh_prec_round_ref(prec, Floor).1 == Equal
+}
+
+// Whether `(x, rm)` is a valid input to `Float::acosh_round`.
+pub fn acosh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || x.acosh_round_ref(Floor).1 == Equal
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::acosh_rational_prec_round`.
+pub fn acosh_rational_prec_round_valid(x: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || Float::acosh_rational_prec_round_ref(x, prec, Floor).1 == Equal
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::atanh_prec_round`.
+pub fn atanh_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || x.atanh_prec_round_ref(prec, Floor).1 == Equal
+}
+
+// Whether `(x, rm)` is a valid input to `Float::atanh_round`.
+pub fn atanh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact || x.atanh_round_ref(Floor).1 == Equal
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::atanh_rational_prec_round`.
+pub fn atanh_rational_prec_round_valid(x: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact || Float::atanh_rational_prec_round_ref(x, prec, Floor).1 == Equal
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.acosh_prec_round`.
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_41() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| acosh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.acosh_round`.
+pub fn exhaustive_float_rounding_mode_pair_gen_var_52() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| acosh_round_valid(f, *rm)),
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::acosh_rational_prec_round`.
+pub fn exhaustive_rational_unsigned_rounding_mode_triple_gen_var_13()
+-> It<(Rational, u64, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_rationals(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, prec), rm)| acosh_rational_prec_round_valid(x, prec, rm)),
+    ))
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.atanh_prec_round`.
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_42() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| atanh_prec_round_valid(x, p, rm)),
+    ))
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.atanh_round`.
+pub fn exhaustive_float_rounding_mode_pair_gen_var_53() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| atanh_round_valid(f, *rm)),
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::atanh_rational_prec_round`.
+pub fn exhaustive_rational_unsigned_rounding_mode_triple_gen_var_14()
+-> It<(Rational, u64, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_rationals(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, prec), rm)| atanh_rational_prec_round_valid(x, prec, rm)),
+    ))
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::airy_ai_prec_round` that can be computed
+// quickly. Computing Ai(x) takes time proportional to |x| ^ (3/2) when x is negative, so x is
+// required to be at least -64.
+pub fn airy_ai_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    (x.is_nan() || *x >= -64i32)
+        && (rm != Exact || x.airy_ai_prec_round_ref(prec, Floor).1 == Equal)
+}
+
+// Whether `(x, rm)` is a valid input to `Float::airy_ai_round` that can be computed quickly.
+pub fn airy_ai_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    (x.is_nan() || *x >= -64i32) && (rm != Exact || x.airy_ai_round_ref(Floor).1 == Equal)
+}
+
+// Whether `(x, prec, rm)` is a valid input to `Float::airy_ai_rational_prec_round` that can be
+// computed quickly.
+pub fn airy_ai_rational_prec_round_valid(x: &Rational, prec: u64, rm: RoundingMode) -> bool {
+    *x >= -64i32
+        && (rm != Exact || Float::airy_ai_rational_prec_round_ref(x, prec, Floor).1 == Equal)
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.airy_ai_prec_round`.
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_43() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| airy_ai_prec_round_valid(x, p, rm)),
+    ))
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.airy_ai_round`.
+pub fn exhaustive_float_rounding_mode_pair_gen_var_54() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| airy_ai_round_valid(f, *rm)),
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::airy_ai_rational_prec_round`.
+pub fn exhaustive_rational_unsigned_rounding_mode_triple_gen_var_15()
+-> It<(Rational, u64, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_rationals(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, prec), rm)| airy_ai_rational_prec_round_valid(x, prec, rm)),
+    ))
+}
+
+// Whether `(x, n, prec, rm)` is a valid input to both `Float::bessel_jn_prec_round` and
+// `Float::bessel_yn_prec_round`.
+pub fn bessel_n_prec_round_valid(x: &Float, n: i64, prec: u64, rm: RoundingMode) -> bool {
+    rm != Exact
+        || x.bessel_jn_prec_round_ref(n, prec, Floor).1 == Equal
+            && x.bessel_yn_prec_round_ref(n, prec, Floor).1 == Equal
+}
+
+// Whether `(x, n, rm)` is a valid input to both `Float::bessel_jn_round` and
+// `Float::bessel_yn_round`.
+pub fn bessel_n_round_valid(x: &Float, n: i64, rm: RoundingMode) -> bool {
+    rm != Exact
+        || x.bessel_jn_round_ref(n, Floor).1 == Equal && x.bessel_yn_round_ref(n, Floor).1 == Equal
+}
+
+// Whether `(x, n, prec, rm)` is a valid input to both `Float::bessel_jn_rational_prec_round` and
+// `Float::bessel_yn_rational_prec_round`.
+pub fn bessel_n_rational_prec_round_valid(
+    x: &Rational,
+    n: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != Exact
+        || Float::bessel_jn_rational_prec_round_ref(x, n, prec, Floor).1 == Equal
+            && Float::bessel_yn_rational_prec_round_ref(x, n, prec, Floor).1 == Equal
+}
+
+// All `(Float, i64, u64, RoundingMode)` that are valid inputs to both `Float::bessel_jn_prec_round`
+// and `Float::bessel_yn_prec_round`.
+pub fn exhaustive_float_signed_unsigned_rounding_mode_quadruple_gen_var_17()
+-> It<(Float, i64, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples(
+                exhaustive_floats(),
+                exhaustive_signeds(),
+                exhaustive_positive_primitive_ints(),
+            ),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, n, prec), rm)| bessel_n_prec_round_valid(x, n, prec, rm)),
+    ))
+}
+
+// All `(Float, i64, RoundingMode)` that are valid inputs to both `Float::bessel_jn_round` and
+// `Float::bessel_yn_round`.
+pub fn exhaustive_float_signed_rounding_mode_triple_gen_var_11() -> It<(Float, i64, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_signeds()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, n), rm)| bessel_n_round_valid(x, n, rm)),
+    ))
+}
+
+// All `(Rational, i64, u64, RoundingMode)` that are valid inputs to both
+// `Float::bessel_jn_rational_prec_round` and `Float::bessel_yn_rational_prec_round`.
+pub fn exhaustive_rational_signed_unsigned_rounding_mode_quadruple_gen_var_3()
+-> It<(Rational, i64, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_custom_output(
+                exhaustive_rationals(),
+                exhaustive_signeds(),
+                exhaustive_positive_primitive_ints(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, n, prec), rm)| bessel_n_rational_prec_round_valid(x, n, prec, rm)),
+    ))
+}
+
 // Whether `(x, k, prec, rm)` is a valid input to `Float::root_u_prec_round`: `Exact` is only
 // allowed when the root really is exact at the given precision.
 pub fn root_u_prec_round_valid(x: &Float, k: u64, prec: u64, rm: RoundingMode) -> bool {
@@ -7,20 +7,25 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::Float;
+use crate::ball::Ball;
+use crate::interval::Interval;
 use crate::test_util::generators::common::{
     float_float_anything_rounding_mode_quadruple_rm, float_float_anything_triple_rm,
-    float_float_float_anything_quadruple_rm, float_float_float_anything_rounding_mode_quintuple_rm,
+    float_float_anything_triple_to_ball_anything_pair,
+    float_float_anything_triple_to_interval_anything_pair, float_float_float_anything_quadruple_rm,
+    float_float_float_anything_rounding_mode_quintuple_rm,
     float_float_float_float_anything_quintuple_rm,
     float_float_float_float_anything_rounding_mode_sextuple_rm,
     float_float_float_float_quadruple_rm, float_float_float_float_rounding_mode_quintuple_rm,
     float_float_float_rounding_mode_quadruple_rm, float_float_float_triple_rm,
     float_float_rounding_mode_triple_rm, float_integer_pair_rm, float_natural_pair_rm,
-    float_pair_rm, float_primitive_float_pair_rm, float_primitive_int_pair_rm,
+    float_pair_rm, float_pair_to_ball, float_pair_to_interval, float_primitive_float_pair_rm,
+    float_primitive_int_pair_rm, float_quadruple_to_ball_pair, float_quadruple_to_interval_pair,
     float_rational_anything_rounding_mode_quadruple_rm, float_rational_anything_triple_rm,
     float_rational_pair_rm, float_rational_rounding_mode_triple_rm, float_rm,
     float_rounding_mode_pair_rm, float_t_rounding_mode_triple_rm,
-    float_t_u_rounding_mode_quadruple_rm, float_t_u_triple_rm,
-    string_u_u_rounding_mode_quadruple_rm,
+    float_t_u_rounding_mode_quadruple_rm, float_t_u_triple_rm, float_triple_to_ball_float_pair,
+    float_triple_to_interval_float_pair, string_u_u_rounding_mode_quadruple_rm,
 };
 use crate::test_util::generators::exhaustive::*;
 use crate::test_util::generators::random::*;
@@ -5111,6 +5116,144 @@ pub fn float_to_sci_options_pair_gen_var_2() -> Generator<(Float, ToSciOptions)>
     )
 }
 
+// -- Ball --
+
+pub fn ball_gen() -> Generator<Ball> {
+    Generator::new(
+        &|| float_pair_to_ball(exhaustive_float_pair_gen()),
+        &|config| float_pair_to_ball(random_float_pair_gen(config)),
+        &|config| float_pair_to_ball(special_random_float_pair_gen(config)),
+    )
+}
+
+// -- (Ball, Float) --
+
+pub fn ball_float_pair_gen() -> Generator<(Ball, Float)> {
+    Generator::new(
+        &|| float_triple_to_ball_float_pair(exhaustive_float_triple_gen()),
+        &|config| float_triple_to_ball_float_pair(random_float_triple_gen(config)),
+        &|config| float_triple_to_ball_float_pair(special_random_float_triple_gen(config)),
+    )
+}
+
+// -- (Ball, Ball) --
+
+pub fn ball_pair_gen() -> Generator<(Ball, Ball)> {
+    Generator::new(
+        &|| float_quadruple_to_ball_pair(exhaustive_float_quadruple_gen()),
+        &|config| float_quadruple_to_ball_pair(random_float_quadruple_gen(config)),
+        &|config| float_quadruple_to_ball_pair(special_random_float_quadruple_gen(config)),
+    )
+}
+
+// -- (Ball, PrimitiveUnsigned) --
+
+// All `(Ball, T)` where `T` is unsigned, small, and positive.
+pub fn ball_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>() -> Generator<(Ball, T)> {
+    Generator::new(
+        &|| {
+            float_float_anything_triple_to_ball_anything_pair(
+                exhaustive_float_float_unsigned_triple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_float_anything_triple_to_ball_anything_pair(
+                random_float_float_unsigned_triple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_float_anything_triple_to_ball_anything_pair(
+                special_random_float_float_unsigned_triple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// -- Interval --
+
+pub fn interval_gen() -> Generator<Interval> {
+    Generator::new(
+        &|| float_pair_to_interval(exhaustive_float_pair_gen()),
+        &|config| float_pair_to_interval(random_float_pair_gen(config)),
+        &|config| float_pair_to_interval(special_random_float_pair_gen(config)),
+    )
+}
+
+// All bounded `Interval`s.
+pub fn interval_gen_var_1() -> Generator<Interval> {
+    Generator::new(
+        &|| float_pair_to_interval(exhaustive_float_pair_gen_var_1()),
+        &|config| float_pair_to_interval(random_float_pair_gen_var_1(config)),
+        &|config| float_pair_to_interval(special_random_float_pair_gen_var_1(config)),
+    )
+}
+
+// All `Interval`s whose lower endpoint is non-negative.
+pub fn interval_gen_var_2() -> Generator<Interval> {
+    Generator::new(
+        &|| {
+            Box::new(
+                float_pair_to_interval(exhaustive_float_pair_gen()).filter(|x| *x.lower() >= 0u32),
+            )
+        },
+        &|config| {
+            Box::new(
+                float_pair_to_interval(random_float_pair_gen(config))
+                    .filter(|x| *x.lower() >= 0u32),
+            )
+        },
+        &|config| {
+            Box::new(
+                float_pair_to_interval(special_random_float_pair_gen(config))
+                    .filter(|x| *x.lower() >= 0u32),
+            )
+        },
+    )
+}
+
+// -- (Interval, Float) --
+
+pub fn interval_float_pair_gen() -> Generator<(Interval, Float)> {
+    Generator::new(
+        &|| float_triple_to_interval_float_pair(exhaustive_float_triple_gen()),
+        &|config| float_triple_to_interval_float_pair(random_float_triple_gen(config)),
+        &|config| float_triple_to_interval_float_pair(special_random_float_triple_gen(config)),
+    )
+}
+
+// -- (Interval, Interval) --
+
+pub fn interval_pair_gen() -> Generator<(Interval, Interval)> {
+    Generator::new(
+        &|| float_quadruple_to_interval_pair(exhaustive_float_quadruple_gen()),
+        &|config| float_quadruple_to_interval_pair(random_float_quadruple_gen(config)),
+        &|config| float_quadruple_to_interval_pair(special_random_float_quadruple_gen(config)),
+    )
+}
+
+// -- (Interval, PrimitiveUnsigned) --
+
+// All `(Interval, T)` where `T` is unsigned, small, and positive.
+pub fn interval_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>() -> Generator<(Interval, T)> {
+    Generator::new(
+        &|| {
+            float_float_anything_triple_to_interval_anything_pair(
+                exhaustive_float_float_unsigned_triple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_float_anything_triple_to_interval_anything_pair(
+                random_float_float_unsigned_triple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_float_anything_triple_to_interval_anything_pair(
+                special_random_float_float_unsigned_triple_gen_var_1(config),
+            )
+        },
+    )
+}
+
 // -- (Integer, PrimitiveUnsigned, RoundingMode) --
 
 // vars 1 and 2 are in malachite-nz.
@@ -5466,6 +5609,39 @@ pub fn rational_rational_unsigned_rounding_mode_quadruple_gen_var_3()
     )
 }
 
+// All `(Rational, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::beta_rational_prec_round`.
+pub fn rational_rational_unsigned_rounding_mode_quadruple_gen_var_4()
+-> Generator<(Rational, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_rational_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+        &random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+        &special_random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+    )
+}
+
+// All `(Rational, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::upper_incomplete_gamma_rational_prec_round`.
+pub fn rational_rational_unsigned_rounding_mode_quadruple_gen_var_5()
+-> Generator<(Rational, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_rational_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+        &random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+        &special_random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+    )
+}
+
+// All `(Rational, Rational, u64, RoundingMode)` that are valid inputs to
+// `Float::lower_incomplete_gamma_rational_prec_round`.
+pub fn rational_rational_unsigned_rounding_mode_quadruple_gen_var_6()
+-> Generator<(Rational, Rational, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_rational_rational_unsigned_rounding_mode_quadruple_gen_var_6,
+        &random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_6,
+        &special_random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_6,
+    )
+}
+
 // -- (Rational, Float, PrimitiveUnsigned, RoundingMode) --
 
 // All `(Rational, Float, u64, RoundingMode)` that are valid inputs to
@@ -5655,6 +5831,399 @@ pub fn float_rounding_mode_pair_gen_var_48() -> Generator<(Float, RoundingMode)>
     )
 }
 
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.asin_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_38() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_38,
+        &random_float_unsigned_rounding_mode_triple_gen_var_38,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_38,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.acos_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_39() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_39,
+        &random_float_unsigned_rounding_mode_triple_gen_var_39,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_39,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.atan_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_40() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_40,
+        &random_float_unsigned_rounding_mode_triple_gen_var_40,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_40,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.asin_round`.
+pub fn float_rounding_mode_pair_gen_var_49() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_49,
+        &random_float_rounding_mode_pair_gen_var_49,
+        &special_random_float_rounding_mode_pair_gen_var_49,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.acos_round`.
+pub fn float_rounding_mode_pair_gen_var_50() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_50,
+        &random_float_rounding_mode_pair_gen_var_50,
+        &special_random_float_rounding_mode_pair_gen_var_50,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.atan_round`.
+pub fn float_rounding_mode_pair_gen_var_51() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_51,
+        &random_float_rounding_mode_pair_gen_var_51,
+        &special_random_float_rounding_mode_pair_gen_var_51,
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::asin_rational_prec_round`.
+pub fn rational_unsigned_rounding_mode_triple_gen_var_11() -> GT3 {
+    Generator::new(
+        &exhaustive_rational_unsigned_rounding_mode_triple_gen_var_11,
+        &random_rational_unsigned_rounding_mode_triple_gen_var_11,
+        &special_random_rational_unsigned_rounding_mode_triple_gen_var_11,
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::acos_rational_prec_round`.
+pub fn rational_unsigned_rounding_mode_triple_gen_var_12() -> GT3 {
+    Generator::new(
+        &exhaustive_rational_unsigned_rounding_mode_triple_gen_var_12,
+        &random_rational_unsigned_rounding_mode_triple_gen_var_12,
+        &special_random_rational_unsigned_rounding_mode_triple_gen_var_12,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::atan2_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_26()
+-> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_26,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_26,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_26,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_26_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_26(),
+            )
+        },
+        &|config| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_26(config),
+            )
+        },
+        &|config| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_26(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::atan2_round`.
+pub fn float_float_rounding_mode_triple_gen_var_45() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_45,
+        &random_float_float_rounding_mode_triple_gen_var_45,
+        &special_random_float_float_rounding_mode_triple_gen_var_45,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::beta_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_27()
+-> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_27,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_27,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_27,
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::beta_round`.
+pub fn float_float_rounding_mode_triple_gen_var_46() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_46,
+        &random_float_float_rounding_mode_triple_gen_var_46,
+        &special_random_float_float_rounding_mode_triple_gen_var_46,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to
+// `Float::upper_incomplete_gamma_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_28()
+-> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_28,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_28,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_28,
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to
+// `Float::upper_incomplete_gamma_round`.
+pub fn float_float_rounding_mode_triple_gen_var_47() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_47,
+        &random_float_float_rounding_mode_triple_gen_var_47,
+        &special_random_float_float_rounding_mode_triple_gen_var_47,
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to
+// `Float::lower_incomplete_gamma_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_29()
+-> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_29,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_29,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_29,
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to
+// `Float::lower_incomplete_gamma_round`.
+pub fn float_float_rounding_mode_triple_gen_var_48() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_48,
+        &random_float_float_rounding_mode_triple_gen_var_48,
+        &special_random_float_float_rounding_mode_triple_gen_var_48,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.acosh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_41() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_41,
+        &random_float_unsigned_rounding_mode_triple_gen_var_41,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_41,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.acosh_round`.
+pub fn float_rounding_mode_pair_gen_var_52() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_52,
+        &random_float_rounding_mode_pair_gen_var_52,
+        &special_random_float_rounding_mode_pair_gen_var_52,
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::acosh_rational_prec_round`.
+pub fn rational_unsigned_rounding_mode_triple_gen_var_13() -> GT3 {
+    Generator::new(
+        &exhaustive_rational_unsigned_rounding_mode_triple_gen_var_13,
+        &random_rational_unsigned_rounding_mode_triple_gen_var_13,
+        &special_random_rational_unsigned_rounding_mode_triple_gen_var_13,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.atanh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_42() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_42,
+        &random_float_unsigned_rounding_mode_triple_gen_var_42,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_42,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.atanh_round`.
+pub fn float_rounding_mode_pair_gen_var_53() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_53,
+        &random_float_rounding_mode_pair_gen_var_53,
+        &special_random_float_rounding_mode_pair_gen_var_53,
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::atanh_rational_prec_round`.
+pub fn rational_unsigned_rounding_mode_triple_gen_var_14() -> GT3 {
+    Generator::new(
+        &exhaustive_rational_unsigned_rounding_mode_triple_gen_var_14,
+        &random_rational_unsigned_rounding_mode_triple_gen_var_14,
+        &special_random_rational_unsigned_rounding_mode_triple_gen_var_14,
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.airy_ai_prec_round`, with x at least
+// -64.
+pub fn float_unsigned_rounding_mode_triple_gen_var_43() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_43,
+        &random_float_unsigned_rounding_mode_triple_gen_var_43,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_43,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float.airy_ai_round`, with x at least -64.
+pub fn float_rounding_mode_pair_gen_var_54() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_54,
+        &random_float_rounding_mode_pair_gen_var_54,
+        &special_random_float_rounding_mode_pair_gen_var_54,
+    )
+}
+
+// All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::airy_ai_rational_prec_round`,
+// with x at least -64.
+pub fn rational_unsigned_rounding_mode_triple_gen_var_15() -> GT3 {
+    Generator::new(
+        &exhaustive_rational_unsigned_rounding_mode_triple_gen_var_15,
+        &random_rational_unsigned_rounding_mode_triple_gen_var_15,
+        &special_random_rational_unsigned_rounding_mode_triple_gen_var_15,
+    )
+}
+
+// All `(Float, i64, u64, RoundingMode)` that are valid inputs to both
+// `Float::bessel_jn_prec_round` and `Float::bessel_yn_prec_round`.
+pub fn float_signed_unsigned_rounding_mode_quadruple_gen_var_17()
+-> Generator<(Float, i64, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_signed_unsigned_rounding_mode_quadruple_gen_var_17,
+        &random_float_signed_unsigned_rounding_mode_quadruple_gen_var_17,
+        &special_random_float_signed_unsigned_rounding_mode_quadruple_gen_var_17,
+    )
+}
+
+// All `(Float, i64, RoundingMode)` that are valid inputs to both `Float::bessel_jn_round` and
+// `Float::bessel_yn_round`.
+pub fn float_signed_rounding_mode_triple_gen_var_11() -> Generator<(Float, i64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_signed_rounding_mode_triple_gen_var_11,
+        &random_float_signed_rounding_mode_triple_gen_var_11,
+        &special_random_float_signed_rounding_mode_triple_gen_var_11,
+    )
+}
+
+// All `(Rational, i64, u64, RoundingMode)` that are valid inputs to both
+// `Float::bessel_jn_rational_prec_round` and `Float::bessel_yn_rational_prec_round`.
+pub fn rational_signed_unsigned_rounding_mode_quadruple_gen_var_3()
+-> Generator<(Rational, i64, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_rational_signed_unsigned_rounding_mode_quadruple_gen_var_3,
+        &random_rational_signed_unsigned_rounding_mode_quadruple_gen_var_3,
+        &special_random_rational_signed_unsigned_rounding_mode_quadruple_gen_var_3,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_43_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_t_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_43(),
+            )
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(random_float_unsigned_rounding_mode_triple_gen_var_43(
+                config,
+            ))
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_43(config),
+            )
+        },
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_54_rm()
+-> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_54()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_54(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_54(config))
+        },
+    )
+}
+
+pub fn float_signed_unsigned_rounding_mode_quadruple_gen_var_17_rm() -> Generator<(
+    (rug::Float, i64, u64, rug::float::Round),
+    (Float, i64, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_t_u_rounding_mode_quadruple_rm(
+                exhaustive_float_signed_unsigned_rounding_mode_quadruple_gen_var_17(),
+            )
+        },
+        &|config| {
+            float_t_u_rounding_mode_quadruple_rm(
+                random_float_signed_unsigned_rounding_mode_quadruple_gen_var_17(config),
+            )
+        },
+        &|config| {
+            float_t_u_rounding_mode_quadruple_rm(
+                special_random_float_signed_unsigned_rounding_mode_quadruple_gen_var_17(config),
+            )
+        },
+    )
+}
+
+pub fn float_signed_rounding_mode_triple_gen_var_11_rm() -> Generator<(
+    (rug::Float, i64, rug::float::Round),
+    (Float, i64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_t_rounding_mode_triple_rm(
+                exhaustive_float_signed_rounding_mode_triple_gen_var_11(),
+            )
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(random_float_signed_rounding_mode_triple_gen_var_11(
+                config,
+            ))
+        },
+        &|config| {
+            float_t_rounding_mode_triple_rm(
+                special_random_float_signed_rounding_mode_triple_gen_var_11(config),
+            )
+        },
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_45_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_45(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_45(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_45(config),
+            )
+        },
+    )
+}
+
 pub fn float_rounding_mode_pair_gen_var_47_rm()
 -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
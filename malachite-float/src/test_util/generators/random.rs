@@ -26,18 +26,26 @@ use crate::test_util::generators::common::{
     valid_strtofr_quadruple,
 };
 use crate::test_util::generators::exhaustive::{
+    acos_prec_round_valid, acos_rational_prec_round_valid, acos_round_valid,
+    acosh_prec_round_valid, acosh_rational_prec_round_valid, acosh_round_valid,
     add_mul_prec_round_valid, add_mul_rational_prec_round_valid, add_mul_rational_round_valid,
     add_mul_round_valid, add_prec_round_valid, add_rational_prec_round_valid,
     add_rational_round_valid, add_round_valid, agm_prec_round_valid, agm_rational_prec_round_valid,
-    agm_round_valid, average_prec_round_valid, cbrt_prec_round_valid, cbrt_round_valid,
-    div_prec_round_valid, div_rational_prec_round_valid, div_rational_round_valid, div_round_valid,
-    exp_prec_round_valid, exp_rational_prec_round_valid, exp_round_valid,
-    float_to_sci_options_valid, from_primitive_float_prec_round_valid, hypot_prec_round_valid,
-    hypot_round_valid, integer_rounding_from_float_valid, ln_1_plus_x_prec_round_valid,
-    ln_1_plus_x_round_valid, ln_prec_round_valid, ln_rational_prec_round_valid, ln_round_valid,
-    log_base_1_plus_x_prec_round_valid, log_base_1_plus_x_round_valid,
-    log_base_2_1_plus_x_prec_round_valid, log_base_2_1_plus_x_round_valid,
-    log_base_2_prec_round_valid, log_base_2_rational_prec_round_valid, log_base_2_round_valid,
+    agm_round_valid, airy_ai_prec_round_valid, airy_ai_rational_prec_round_valid,
+    airy_ai_round_valid, asin_prec_round_valid, asin_rational_prec_round_valid, asin_round_valid,
+    atan_prec_round_valid, atan_round_valid, atan2_prec_round_valid, atan2_round_valid,
+    atanh_prec_round_valid, atanh_rational_prec_round_valid, atanh_round_valid,
+    average_prec_round_valid, bessel_n_prec_round_valid, bessel_n_rational_prec_round_valid,
+    bessel_n_round_valid, beta_prec_round_valid, beta_rational_prec_round_valid, beta_round_valid,
+    cbrt_prec_round_valid, cbrt_round_valid, div_prec_round_valid, div_rational_prec_round_valid,
+    div_rational_round_valid, div_round_valid, exp_prec_round_valid, exp_rational_prec_round_valid,
+    exp_round_valid, float_to_sci_options_valid, from_primitive_float_prec_round_valid,
+    hypot_prec_round_valid, hypot_round_valid, integer_rounding_from_float_valid,
+    ln_1_plus_x_prec_round_valid, ln_1_plus_x_round_valid, ln_prec_round_valid,
+    ln_rational_prec_round_valid, ln_round_valid, log_base_1_plus_x_prec_round_valid,
+    log_base_1_plus_x_round_valid, log_base_2_1_plus_x_prec_round_valid,
+    log_base_2_1_plus_x_round_valid, log_base_2_prec_round_valid,
+    log_base_2_rational_prec_round_valid, log_base_2_round_valid,
     log_base_10_1_plus_x_prec_round_valid, log_base_10_1_plus_x_round_valid,
     log_base_10_prec_round_valid, log_base_10_rational_prec_round_valid, log_base_10_round_valid,
     log_base_float_base_1_plus_x_prec_round_valid, log_base_float_base_1_plus_x_round_valid,
@@ -48,27 +56,31 @@ use crate::test_util::generators::exhaustive::{
     log_base_rational_base_1_plus_x_prec_round_valid, log_base_rational_base_1_plus_x_round_valid,
     log_base_rational_base_prec_round_valid, log_base_rational_base_round_valid,
     log_base_rational_float_base_prec_round_valid, log_base_rational_prec_round_valid,
-    log_base_rational_rational_base_prec_round_valid, log_base_round_valid, max_prec_round_valid,
-    min_max_rational_prec_round_valid, min_max_rational_round_valid, min_prec_round_valid,
-    mul_add_mul_prec_round_valid, mul_add_mul_rational_prec_round_valid,
-    mul_add_mul_rational_round_valid, mul_add_mul_round_valid, mul_prec_round_valid,
-    mul_rational_prec_round_valid, mul_rational_round_valid, mul_round_valid,
-    natural_rounding_from_float_valid, positive_difference_prec_round_valid,
-    positive_difference_rational_prec_round_valid, positive_difference_rational_round_valid,
-    positive_difference_round_valid, pow_integer_prec_round_valid, pow_rational_prec_round_valid,
-    pow_s_prec_round_valid, pow_u_prec_round_valid, rational_div_float_prec_round_valid,
-    rational_div_float_round_valid, rational_pow_rational_prec_round_valid,
-    rational_rem_float_prec_round_valid, rational_rem_float_round_valid,
-    reciprocal_prec_round_valid, reciprocal_round_valid, reciprocal_sqrt_prec_round_valid,
-    reciprocal_sqrt_rational_prec_round_valid, reciprocal_sqrt_round_valid, rem_prec_round_valid,
-    rem_rational_prec_round_valid, rem_rational_round_valid, rem_round_valid,
-    root_s_prec_round_valid, root_s_rational_prec_round_valid, root_u_prec_round_valid,
-    root_u_rational_prec_round_valid, set_prec_round_valid, shl_prec_round_valid, shl_round_valid,
-    shr_prec_round_valid, shr_round_valid, signed_rounding_from_float_valid, sqrt_prec_round_valid,
+    log_base_rational_rational_base_prec_round_valid, log_base_round_valid,
+    lower_incomplete_gamma_prec_round_valid, lower_incomplete_gamma_rational_prec_round_valid,
+    lower_incomplete_gamma_round_valid, max_prec_round_valid, min_max_rational_prec_round_valid,
+    min_max_rational_round_valid, min_prec_round_valid, mul_add_mul_prec_round_valid,
+    mul_add_mul_rational_prec_round_valid, mul_add_mul_rational_round_valid,
+    mul_add_mul_round_valid, mul_prec_round_valid, mul_rational_prec_round_valid,
+    mul_rational_round_valid, mul_round_valid, natural_rounding_from_float_valid,
+    positive_difference_prec_round_valid, positive_difference_rational_prec_round_valid,
+    positive_difference_rational_round_valid, positive_difference_round_valid,
+    pow_integer_prec_round_valid, pow_rational_prec_round_valid, pow_s_prec_round_valid,
+    pow_u_prec_round_valid, rational_div_float_prec_round_valid, rational_div_float_round_valid,
+    rational_pow_rational_prec_round_valid, rational_rem_float_prec_round_valid,
+    rational_rem_float_round_valid, reciprocal_prec_round_valid, reciprocal_round_valid,
+    reciprocal_sqrt_prec_round_valid, reciprocal_sqrt_rational_prec_round_valid,
+    reciprocal_sqrt_round_valid, rem_prec_round_valid, rem_rational_prec_round_valid,
+    rem_rational_round_valid, rem_round_valid, root_s_prec_round_valid,
+    root_s_rational_prec_round_valid, root_u_prec_round_valid, root_u_rational_prec_round_valid,
+    set_prec_round_valid, shl_prec_round_valid, shl_round_valid, shr_prec_round_valid,
+    shr_round_valid, signed_rounding_from_float_valid, sqrt_prec_round_valid,
     sqrt_rational_prec_round_valid, sqrt_round_valid, square_prec_round_valid, square_round_valid,
     sub_prec_round_valid, sub_rational_prec_round_valid, sub_rational_round_valid, sub_round_valid,
     unsigned_pow_prec_round_valid, unsigned_pow_rational_prec_round_valid,
     unsigned_pow_unsigned_prec_round_valid, unsigned_rounding_from_float_valid,
+    upper_incomplete_gamma_prec_round_valid, upper_incomplete_gamma_rational_prec_round_valid,
+    upper_incomplete_gamma_round_valid,
 };
 use malachite_base::bools::random::{
     RandomBools, WeightedRandomBools, random_bools, weighted_random_bools,
@@ -10643,6 +10655,88 @@ pub fn random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_3(
     )
 }
 
+pub fn random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_4(
+    config: &GenConfig,
+) -> It<(Rational, Rational, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, y, prec, rm)| beta_rational_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_5(
+    config: &GenConfig,
+) -> It<(Rational, Rational, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(s, x, prec, rm)| {
+            upper_incomplete_gamma_rational_prec_round_valid(s, x, *prec, *rm)
+        }),
+    )
+}
+
+pub fn random_rational_rational_unsigned_rounding_mode_quadruple_gen_var_6(
+    config: &GenConfig,
+) -> It<(Rational, Rational, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(s, x, prec, rm)| {
+            lower_incomplete_gamma_rational_prec_round_valid(s, x, *prec, *rm)
+        }),
+    )
+}
+
 // -- (Rational, Float, PrimitiveUnsigned, RoundingMode) --
 
 pub fn random_rational_float_unsigned_rounding_mode_quadruple_gen_var_1(
@@ -10986,6 +11080,754 @@ pub fn random_float_rounding_mode_pair_gen_var_48(config: &GenConfig) -> It<(Flo
     )
 }
 
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_38(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| asin_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_39(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| acos_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_40(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| atan_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_49(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| asin_round_valid(f, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_50(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| acos_round_valid(f, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_51(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| atan_round_valid(f, *rm)),
+    )
+}
+
+pub fn random_rational_unsigned_rounding_mode_triple_gen_var_11(
+    config: &GenConfig,
+) -> It<(Rational, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, prec, rm)| asin_rational_prec_round_valid(x, prec, rm)),
+    )
+}
+
+pub fn random_rational_unsigned_rounding_mode_triple_gen_var_12(
+    config: &GenConfig,
+) -> It<(Rational, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, prec, rm)| acos_rational_prec_round_valid(x, prec, rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_26(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(y, x, prec, rm)| atan2_prec_round_valid(y, x, *prec, *rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_45(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples_xxy(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(y, x, rm)| atan2_round_valid(y, x, *rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_27(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, y, prec, rm)| beta_prec_round_valid(x, y, *prec, *rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_46(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples_xxy(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, y, rm)| beta_round_valid(x, y, *rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_28(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(s, x, prec, rm)| upper_incomplete_gamma_prec_round_valid(s, x, *prec, *rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_47(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples_xxy(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(s, x, rm)| upper_incomplete_gamma_round_valid(s, x, *rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_29(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(s, x, prec, rm)| lower_incomplete_gamma_prec_round_valid(s, x, *prec, *rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_48(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples_xxy(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(s, x, rm)| lower_incomplete_gamma_round_valid(s, x, *rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_41(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| acosh_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_52(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| acosh_round_valid(f, *rm)),
+    )
+}
+
+pub fn random_rational_unsigned_rounding_mode_triple_gen_var_13(
+    config: &GenConfig,
+) -> It<(Rational, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, prec, rm)| acosh_rational_prec_round_valid(x, prec, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_42(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| atanh_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_53(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| atanh_round_valid(f, *rm)),
+    )
+}
+
+pub fn random_rational_unsigned_rounding_mode_triple_gen_var_14(
+    config: &GenConfig,
+) -> It<(Rational, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, prec, rm)| atanh_rational_prec_round_valid(x, prec, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_43(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| airy_ai_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_54(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| airy_ai_round_valid(f, *rm)),
+    )
+}
+
+pub fn random_rational_unsigned_rounding_mode_triple_gen_var_15(
+    config: &GenConfig,
+) -> It<(Rational, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, prec, rm)| airy_ai_rational_prec_round_valid(x, prec, rm)),
+    )
+}
+
+pub fn random_float_signed_unsigned_rounding_mode_quadruple_gen_var_17(
+    config: &GenConfig,
+) -> It<(Float, i64, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_signeds(
+                    seed,
+                    config.get_or("small_signed_mean_n", 4),
+                    config.get_or("small_signed_mean_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, n, prec, rm)| bessel_n_prec_round_valid(x, n, prec, rm)),
+    )
+}
+
+pub fn random_float_signed_rounding_mode_triple_gen_var_11(
+    config: &GenConfig,
+) -> It<(Float, i64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_signeds(
+                    seed,
+                    config.get_or("small_signed_mean_n", 4),
+                    config.get_or("small_signed_mean_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, n, rm)| bessel_n_round_valid(x, n, rm)),
+    )
+}
+
+pub fn random_rational_signed_unsigned_rounding_mode_quadruple_gen_var_3(
+    config: &GenConfig,
+) -> It<(Rational, i64, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_signeds(
+                    seed,
+                    config.get_or("small_signed_mean_n", 4),
+                    config.get_or("small_signed_mean_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, n, prec, rm)| bessel_n_rational_prec_round_valid(x, n, prec, rm)),
+    )
+}
+
 pub fn random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_13(
     config: &GenConfig,
 ) -> It<(Float, u64, u64, RoundingMode)> {
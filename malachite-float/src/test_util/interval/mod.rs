@@ -0,0 +1,55 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use crate::interval::Interval;
+use crate::test_util::common::{parse_hex_string, to_hex_string};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, Ceiling, Floor};
+use malachite_q::Rational;
+
+pub fn parse_interval_hex(lower_hex: &str, upper_hex: &str) -> Interval {
+    Interval::new(parse_hex_string(lower_hex), parse_hex_string(upper_hex))
+}
+
+// A few finite elements of an `Interval`: whichever of its endpoints and its midpoint are finite.
+// Every function on `Interval`s must map these to elements of its result.
+pub fn interval_sample_points(x: &Interval) -> Vec<Rational> {
+    [x.lower().clone(), x.upper().clone(), x.midpoint()]
+        .iter()
+        .filter(|y| y.is_finite())
+        .map(Rational::exact_from)
+        .collect()
+}
+
+// Same as `interval_sample_points`, but returning `Float`s.
+pub fn interval_sample_floats(x: &Interval) -> Vec<Float> {
+    [x.lower().clone(), x.upper().clone(), x.midpoint()]
+        .into_iter()
+        .filter(Float::is_finite)
+        .collect()
+}
+
+// The hexadecimal representations of an `Interval`'s endpoints, so that tests can check their
+// precisions and the signs of zeros, which `Interval` equality ignores.
+pub fn interval_to_hex_string(x: &Interval) -> String {
+    format!(
+        "[{}, {}]",
+        to_hex_string(x.lower()),
+        to_hex_string(x.upper())
+    )
+}
+
+// Whether the exact value of a function could lie in an `Interval`, given the function's value
+// rounded down and rounded up. If the value is in the `Interval`, this returns `true`.
+pub fn interval_may_contain<F: Fn(RoundingMode) -> Float>(x: &Interval, f: F) -> bool {
+    f(Floor) <= *x.upper() && f(Ceiling) >= *x.lower()
+}
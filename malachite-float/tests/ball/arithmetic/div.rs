@@ -0,0 +1,207 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{ball_sample_points, ball_to_hex_string, parse_ball_hex};
+use malachite_float::test_util::generators::{ball_gen, ball_pair_gen};
+use malachite_q::Rational;
+
+#[test]
+fn test_div() {
+    let test = |xm, xr, ym, yr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(ball_to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&quotient_alt), out_hex);
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&quotient_alt), out_hex);
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&quotient_alt), out_hex);
+
+        let mut quotient_alt = x.clone();
+        quotient_alt /= y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&quotient_alt), out_hex);
+        let mut quotient_alt = x.clone();
+        quotient_alt /= &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&quotient_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x0.0",
+        "0x1.0#1",
+        "0x0.0",
+        "[1.0 +/- 0.0]",
+        "[0x1.0#1 +/- 0x0.0]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        "[4.5078 +/- 0.77400190849]",
+        "[0x4.82#10 +/- 0x0.c624fd34#30]",
+    );
+    test(
+        "-0x2.0#1",
+        "0x1.0#1",
+        "0x3.0#2",
+        "0x0.8#1",
+        "[-0.75 +/- 0.78333333414]",
+        "[-0x0.c#2 +/- 0x0.c888888c#30]",
+    );
+    test(
+        "0x1.0#1",
+        "0x0.0",
+        "0x1.0E-50#1",
+        "0x0.0",
+        "[1.6e60 +/- 0.0]",
+        "[0x1.0E+50#1 +/- 0x0.0]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+    test(
+        "0x1.0#1",
+        "0x0.0",
+        "0x1.0#1",
+        "0x2.0#1",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_div_prec() {
+    let test = |xm, xr, ym, yr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let quotient = x.clone().div_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(ball_to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.div_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&quotient_alt), out_hex);
+    };
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        3,
+        "[5.0 +/- 1.7661894094]",
+        "[0x5.0#3 +/- 0x1.c424fd38#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        20,
+        "[4.5044022 +/- 0.76619703788]",
+        "[0x4.81208#20 +/- 0x0.c4257d34#30]",
+    );
+    test(
+        "0x0.55555555555555555555555558#100",
+        "0x1.0E-25#1",
+        "0x0.49249249249249249249249248#100",
+        "0x1.0E-25#1",
+        100,
+        "[1.1666666666666666666666666666677 +/- 7.5599170515e-30]",
+        "[0x1.2aaaaaaaaaaaaaaaaaaaaaaac#100 +/- 0x9.9555564E-25#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_prec_fail() {
+    Ball::ONE.div_prec(Ball::ONE, 0);
+}
+
+#[test]
+fn div_prec_properties() {
+    ball_pair_gen().test_properties(|(x, y)| {
+        for prec in [1, 10, 100] {
+            let quotient = x.clone().div_prec(y.clone(), prec);
+            assert!(quotient.is_valid());
+            let quotient_alt = x.div_prec_ref_ref(&y, prec);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                ball_to_hex_string(&quotient_alt),
+                ball_to_hex_string(&quotient)
+            );
+            for a in ball_sample_points(&x) {
+                for b in ball_sample_points(&y) {
+                    if b != 0u32 {
+                        assert!(quotient.contains_rational(&(&a / &b)));
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn div_properties() {
+    ball_pair_gen().test_properties(|(x, y)| {
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ball_to_hex_string(&quotient_alt),
+            ball_to_hex_string(&quotient)
+        );
+        let quotient_alt = x.div_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(
+            ball_to_hex_string(&quotient_alt),
+            ball_to_hex_string(&quotient)
+        );
+        if *quotient.mid() != 0u32 {
+            assert_eq!(
+                ball_to_hex_string(&(-&x / &y)),
+                ball_to_hex_string(&-&quotient)
+            );
+        }
+        for a in ball_sample_points(&x) {
+            for b in ball_sample_points(&y) {
+                if b != 0u32 {
+                    assert!(quotient.contains_rational(&(&a / &b)));
+                }
+            }
+        }
+    });
+
+    ball_gen().test_properties(|x| {
+        assert!((&x / &Ball::ONE).contains_ball(&x));
+        if x.is_bounded() && !x.contains_rational(&Rational::ZERO) {
+            assert!((&x / &x).contains_rational(&Rational::ONE));
+        }
+    });
+}
@@ -0,0 +1,136 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{
+    ball_may_contain, ball_sample_floats, ball_to_hex_string, parse_ball_hex,
+};
+use malachite_float::test_util::generators::{ball_gen, ball_unsigned_pair_gen_var_1};
+
+#[test]
+fn test_exp() {
+    let test = |xm, xr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().exp();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = (&x).exp();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+
+        let mut y_alt = x.clone();
+        y_alt.exp_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x0.0", "0x0.0", "[1.0 +/- 0.0]", "[0x1.0#1 +/- 0x0.0]");
+    test("0x1.0#1", "0x0.0", "[2.0 +/- 2.0]", "[0x2.0#1 +/- 0x2.0#1]");
+    test(
+        "0x1.0#1",
+        "0x0.4#1",
+        "[2.0 +/- 2.7720611319]",
+        "[0x2.0#1 +/- 0x2.c5a5cc6#30]",
+    );
+    test(
+        "-0x0.554#10",
+        "0x0.004#1",
+        "[0.71680 +/- 0.0016768698169]",
+        "[0x0.b78#10 +/- 0x0.006de53506#30]",
+    );
+    test(
+        "0x1.0E+10#1",
+        "0x0.0",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_exp_prec() {
+    let test = |xm, xr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().exp_prec(prec);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = x.exp_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        1,
+        "[8.0 +/- 10.098679751]",
+        "[0x8.0#1 +/- 0xa.1943138#30]",
+    );
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        10,
+        "[7.3906 +/- 2.1064922400]",
+        "[0x7.64#10 +/- 0x2.1b43135#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x1.0E-25#1",
+        100,
+        "[4.4816890703380648226020554601204 +/- 9.8463165586e-30]",
+        "[0x4.7b4ff993f15055f6e4658d440#100 +/- 0xc.7b4ffa0E-25#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn exp_prec_fail() {
+    Ball::ONE.exp_prec(0);
+}
+
+#[test]
+fn exp_prec_properties() {
+    ball_unsigned_pair_gen_var_1().test_properties_with_limit(2000, |(x, prec)| {
+        let y = x.clone().exp_prec(prec);
+        assert!(y.is_valid());
+        let y_alt = x.exp_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a
+                .exp_prec_round_ref(prec + 10, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+fn exp_properties() {
+    ball_gen().test_properties_with_limit(2000, |x| {
+        let y = x.clone().exp();
+        assert!(y.is_valid());
+        let y_alt = (&x).exp();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let mut y_alt = x.clone();
+        y_alt.exp_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let y_alt = x.exp_prec_ref(x.significant_bits());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a.exp_prec_round_ref(100, rm).0));
+        }
+    });
+}
@@ -0,0 +1,147 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Ln, LnAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{
+    ball_may_contain, ball_sample_floats, ball_to_hex_string, parse_ball_hex,
+};
+use malachite_float::test_util::generators::{ball_gen, ball_unsigned_pair_gen_var_1};
+
+#[test]
+fn test_ln() {
+    let test = |xm, xr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().ln();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = (&x).ln();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+
+        let mut y_alt = x.clone();
+        y_alt.ln_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x1.0#1", "0x0.0", "[0.0 +/- 0.0]", "[0x0.0 +/- 0x0.0]");
+    test(
+        "0x2.0#1",
+        "0x0.0",
+        "[0.50 +/- 0.50]",
+        "[0x0.8#1 +/- 0x0.8#1]",
+    );
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        "[0.50 +/- 0.64285714366]",
+        "[0x0.8#1 +/- 0x0.a4924928#30]",
+    );
+    test(
+        "0x0.554#10",
+        "0x0.004#1",
+        "[-1.0996 +/- 0.0048943014772]",
+        "[-0x1.198#10 +/- 0x0.0140c0c0c8#30]",
+    );
+    test(
+        "-0x1.0#1",
+        "0x0.0",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_ln_prec() {
+    let test = |xm, xr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().ln_prec(prec);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = x.ln_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        1,
+        "[0.50 +/- 0.64285714366]",
+        "[0x0.8#1 +/- 0x0.a4924928#30]",
+    );
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        10,
+        "[0.69336 +/- 0.14383370546]",
+        "[0x0.b18#10 +/- 0x0.24d24925#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x1.0E-25#1",
+        100,
+        "[0.40546510810816438197801311546429 +/- 9.2033772374e-31]",
+        "[0x0.67cc8fb2fe612fcada35d9bd00#100 +/- 0x1.2aaaaab0E-25#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn ln_prec_fail() {
+    Ball::ONE.ln_prec(0);
+}
+
+#[test]
+fn ln_prec_properties() {
+    ball_unsigned_pair_gen_var_1().test_properties_with_limit(2000, |(x, prec)| {
+        let y = x.clone().ln_prec(prec);
+        assert!(y.is_valid());
+        let y_alt = x.ln_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a
+                .ln_prec_round_ref(prec + 10, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+fn ln_properties() {
+    ball_gen().test_properties_with_limit(2000, |x| {
+        let y = x.clone().ln();
+        assert!(y.is_valid());
+        let y_alt = (&x).ln();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let mut y_alt = x.clone();
+        y_alt.ln_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let y_alt = x.ln_prec_ref(x.significant_bits());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a.ln_prec_round_ref(100, rm).0));
+        }
+    });
+}
@@ -0,0 +1,193 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{ball_sample_points, ball_to_hex_string, parse_ball_hex};
+use malachite_float::test_util::generators::{ball_gen, ball_pair_gen};
+use malachite_q::Rational;
+
+#[test]
+fn test_mul() {
+    let test = |xm, xr, ym, yr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+        assert_eq!(product.to_string(), out);
+        assert_eq!(ball_to_hex_string(&product), out_hex);
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&product_alt), out_hex);
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&product_alt), out_hex);
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&product_alt), out_hex);
+
+        let mut product_alt = x.clone();
+        product_alt *= y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&product_alt), out_hex);
+        let mut product_alt = x.clone();
+        product_alt *= &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&product_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x0.0",
+        "0x1.0#1",
+        "0x0.0",
+        "[1.0 +/- 0.0]",
+        "[0x1.0#1 +/- 0x0.0]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        "[0.49951 +/- 0.084960937500]",
+        "[0x0.7fe#10 +/- 0x0.15c000000#30]",
+    );
+    test(
+        "-0x2.0#1",
+        "0x1.0#1",
+        "0x3.0#2",
+        "0x0.8#1",
+        "[-6.0 +/- 4.5000000000]",
+        "[-0x6.0#2 +/- 0x4.8000000#30]",
+    );
+    test(
+        "0x1.0#1",
+        "0x0.0",
+        "0x1.0E-50#1",
+        "0x0.0",
+        "[6.2e-61 +/- 0.0]",
+        "[0x1.0E-50#1 +/- 0x0.0]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_mul_prec() {
+    let test = |xm, xr, ym, yr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let product = x.clone().mul_prec(y.clone(), prec);
+        assert!(product.is_valid());
+        assert_eq!(product.to_string(), out);
+        assert_eq!(ball_to_hex_string(&product), out_hex);
+
+        let product_alt = x.mul_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&product_alt), out_hex);
+    };
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        3,
+        "[0.50 +/- 0.20996093750]",
+        "[0x0.8#3 +/- 0x0.35c00000#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        20,
+        "[0.49951172 +/- 0.084960937500]",
+        "[0x0.7fe000#20 +/- 0x0.15c000000#30]",
+    );
+    test(
+        "0x0.55555555555555555555555558#100",
+        "0x1.0E-25#1",
+        "0x0.49249249249249249249249248#100",
+        "0x1.0E-25#1",
+        100,
+        "[0.095238095238095238095238095238076 +/- 5.8695007930e-31]",
+        "[0x0.18618618618618618618618618#100 +/- 0xb.e79e7a4E-26#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_prec_fail() {
+    Ball::ONE.mul_prec(Ball::ONE, 0);
+}
+
+#[test]
+fn mul_prec_properties() {
+    ball_pair_gen().test_properties(|(x, y)| {
+        for prec in [1, 10, 100] {
+            let product = x.clone().mul_prec(y.clone(), prec);
+            assert!(product.is_valid());
+            let product_alt = x.mul_prec_ref_ref(&y, prec);
+            assert!(product_alt.is_valid());
+            assert_eq!(
+                ball_to_hex_string(&product_alt),
+                ball_to_hex_string(&product)
+            );
+            for a in ball_sample_points(&x) {
+                for b in ball_sample_points(&y) {
+                    assert!(product.contains_rational(&(&a * &b)));
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn mul_properties() {
+    ball_pair_gen().test_properties(|(x, y)| {
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ball_to_hex_string(&product_alt),
+            ball_to_hex_string(&product)
+        );
+        let product_alt = x.mul_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(
+            ball_to_hex_string(&product_alt),
+            ball_to_hex_string(&product)
+        );
+        if *product.mid() != 0u32 {
+            assert_eq!(
+                ball_to_hex_string(&(-&x * &y)),
+                ball_to_hex_string(&-&product)
+            );
+        }
+        for a in ball_sample_points(&x) {
+            for b in ball_sample_points(&y) {
+                assert!(product.contains_rational(&(&a * &b)));
+            }
+        }
+    });
+
+    ball_gen().test_properties(|x| {
+        assert!((&x * &Ball::ONE).contains_ball(&x));
+        assert!((&x * &Ball::ZERO).contains_rational(&Rational::ZERO));
+    });
+}
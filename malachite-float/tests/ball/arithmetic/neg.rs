@@ -0,0 +1,74 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_float::test_util::ball::{ball_sample_points, ball_to_hex_string, parse_ball_hex};
+use malachite_float::test_util::generators::ball_gen;
+
+#[test]
+fn test_neg() {
+    let test = |xm, xr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+        assert_eq!(ball_to_hex_string(&neg), out_hex);
+
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&neg_alt), out_hex);
+
+        let mut neg_alt = x.clone();
+        neg_alt.neg_assign();
+        assert!(neg_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&neg_alt), out_hex);
+    };
+    test("0x1.0#1", "0x0.0", "[-1.0 +/- 0.0]", "[-0x1.0#1 +/- 0x0.0]");
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "[-1.5 +/- 0.25]",
+        "[-0x1.8#2 +/- 0x0.4#1]",
+    );
+    test(
+        "-0x0.554#10",
+        "0x0.004#1",
+        "[0.33301 +/- 0.00098]",
+        "[0x0.554#10 +/- 0x0.004#1]",
+    );
+    test("0x0.0", "0x0.0", "[-0.0 +/- 0.0]", "[-0x0.0 +/- 0x0.0]");
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn neg_properties() {
+    ball_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&neg_alt), ball_to_hex_string(&neg));
+        let mut neg_alt = x.clone();
+        neg_alt.neg_assign();
+        assert!(neg_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&neg_alt), ball_to_hex_string(&neg));
+
+        assert_eq!(ball_to_hex_string(&-&neg), ball_to_hex_string(&x));
+        assert_eq!(neg.mid(), &-x.mid());
+        assert_eq!(neg.rad(), x.rad());
+        for a in ball_sample_points(&x) {
+            assert!(neg.contains_rational(&-a));
+        }
+    });
+}
@@ -0,0 +1,352 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Pow, PowAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::Float;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{
+    ball_may_contain, ball_sample_floats, ball_sample_points, ball_to_hex_string, parse_ball_hex,
+};
+use malachite_float::test_util::generators::{
+    ball_gen, ball_pair_gen, ball_unsigned_pair_gen_var_1,
+};
+
+#[test]
+fn test_pow() {
+    let test = |xm, xr, ym, yr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let p = x.clone().pow(y.clone());
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(ball_to_hex_string(&p), out_hex);
+
+        let p_alt = x.clone().pow(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+        let p_alt = (&x).pow(y.clone());
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+        let p_alt = (&x).pow(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(y.clone());
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+    };
+    test(
+        "0x2.0#1",
+        "0x0.0",
+        "0x3.0#2",
+        "0x0.0",
+        "[8.0 +/- 0.0]",
+        "[0x8.0#2 +/- 0x0.0]",
+    );
+    test(
+        "0x2.0#1",
+        "0x0.0",
+        "0x0.8#1",
+        "0x0.0",
+        "[1.0 +/- 1.0]",
+        "[0x1.0#1 +/- 0x1.0#1]",
+    );
+    test(
+        "-0x2.0#1",
+        "0x0.0",
+        "0x3.0#2",
+        "0x0.0",
+        "[-8.0 +/- 0.0]",
+        "[-0x8.0#2 +/- 0x0.0]",
+    );
+    test(
+        "-0x2.0#1",
+        "0x0.0",
+        "0x0.8#1",
+        "0x0.0",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        "0x3.0#2",
+        "0x0.0",
+        "[8.0 +/- 61.883484304]",
+        "[0x8.0#2 +/- 0x3d.e22c07#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        "[1.1445 +/- 0.081999499351]",
+        "[0x1.250#10 +/- 0x0.14fdeb500#30]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x2.0#1",
+        "0x0.0",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_pow_prec() {
+    let test = |xm, xr, ym, yr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let p = x.clone().pow_prec(y.clone(), prec);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(ball_to_hex_string(&p), out_hex);
+
+        let p_alt = x.pow_prec_ref_ref(&y, prec);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+    };
+    test(
+        "0x2.0#1",
+        "0x0.0",
+        "0x0.8#1",
+        "0x0.0",
+        10,
+        "[1.4141 +/- 0.0020]",
+        "[0x1.6a0#10 +/- 0x0.008#1]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        10,
+        "[1.1445 +/- 0.081999499351]",
+        "[0x1.250#10 +/- 0x0.14fdeb500#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x1.0E-25#1",
+        "0x0.554#10",
+        "0x0.0",
+        100,
+        "[1.1445631647359346346178369449440 +/- 2.1542324177e-30]",
+        "[0x1.25021770bf41ed92678ac02d2#100 +/- 0x2.bb16a0aE-25#30]",
+    );
+}
+
+#[test]
+fn test_pow_u() {
+    let test = |xm, xr, n: u64, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let p = x.clone().pow(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(ball_to_hex_string(&p), out_hex);
+
+        let p_alt = (&x).pow(n);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(n);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+    };
+    test(
+        "0x1.8#2",
+        "0x0.0",
+        0,
+        "[1.0 +/- 0.0]",
+        "[0x1.0#2 +/- 0x0.0]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.0",
+        3,
+        "[3.0 +/- 1.0]",
+        "[0x3.0#2 +/- 0x1.0#1]",
+    );
+    test(
+        "-0x1.8#2",
+        "0x0.4#1",
+        2,
+        "[2.0 +/- 1.8750000000]",
+        "[0x2.0#2 +/- 0x1.e0000000#30]",
+    );
+    test(
+        "-0x1.8#2",
+        "0x0.4#1",
+        3,
+        "[-3.0 +/- 3.2968750000]",
+        "[-0x3.0#2 +/- 0x3.4c00000#30]",
+    );
+    test(
+        "0x0.0",
+        "0x1.0#1",
+        2,
+        "[0.0 +/- 2.0000000000]",
+        "[0x0.0 +/- 0x2.0000000#30]",
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        2,
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_pow_u_prec() {
+    let test = |xm, xr, n, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let p = x.clone().pow_u_prec(n, prec);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(ball_to_hex_string(&p), out_hex);
+
+        let p_alt = x.pow_u_prec_ref(n, prec);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), out_hex);
+    };
+    test(
+        "0x1.8#2",
+        "0x0.0",
+        3,
+        1,
+        "[4.0 +/- 4.0]",
+        "[0x4.0#1 +/- 0x4.0#1]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.0",
+        3,
+        10,
+        "[3.3750 +/- 0.0]",
+        "[0x3.60#10 +/- 0x0.0]",
+    );
+    test(
+        "-0x0.554#10",
+        "0x0.004#1",
+        5,
+        20,
+        "[-0.0040951744 +/- 0.000060761418240]",
+        "[-0x0.010c61a#20 +/- 0x0.0003fb684de#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn pow_prec_fail() {
+    Ball::ONE.pow_prec(Ball::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn pow_u_prec_fail() {
+    Ball::ONE.pow_u_prec(2, 0);
+}
+
+#[test]
+fn pow_prec_properties() {
+    ball_pair_gen().test_properties_with_limit(2000, |(x, y)| {
+        for prec in [1, 10, 100] {
+            let p = x.clone().pow_prec(y.clone(), prec);
+            assert!(p.is_valid());
+            let p_alt = x.pow_prec_ref_ref(&y, prec);
+            assert!(p_alt.is_valid());
+            assert_eq!(ball_to_hex_string(&p_alt), ball_to_hex_string(&p));
+            for a in ball_sample_floats(&x) {
+                for b in ball_sample_floats(&y) {
+                    assert!(ball_may_contain(&p, |rm| a
+                        .pow_prec_round_ref_ref(&b, prec + 10, rm)
+                        .0));
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn pow_properties() {
+    ball_pair_gen().test_properties_with_limit(2000, |(x, y)| {
+        let p = x.clone().pow(y.clone());
+        assert!(p.is_valid());
+        let p_alt = (&x).pow(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), ball_to_hex_string(&p));
+        let p_alt = x.pow_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(ball_to_hex_string(&p_alt), ball_to_hex_string(&p));
+        for a in ball_sample_floats(&x) {
+            for b in ball_sample_floats(&y) {
+                assert!(ball_may_contain(&p, |rm| a
+                    .pow_prec_round_ref_ref(&b, 100, rm)
+                    .0));
+            }
+        }
+    });
+
+    ball_gen().test_properties_with_limit(2000, |x| {
+        assert!((&x).pow(&Ball::ZERO).contains(&Float::ONE));
+        assert!((&x).pow(&Ball::ONE).contains_ball(&x));
+    });
+}
+
+#[test]
+fn pow_u_prec_properties() {
+    ball_unsigned_pair_gen_var_1::<u64>().test_properties(|(x, n)| {
+        for prec in [1, 10, 100] {
+            let p = x.clone().pow_u_prec(n, prec);
+            assert!(p.is_valid());
+            let p_alt = x.pow_u_prec_ref(n, prec);
+            assert!(p_alt.is_valid());
+            assert_eq!(ball_to_hex_string(&p_alt), ball_to_hex_string(&p));
+            for a in ball_sample_points(&x) {
+                assert!(p.contains_rational(&(&a).pow(n)));
+            }
+        }
+    });
+}
+
+#[test]
+fn pow_u_properties() {
+    ball_unsigned_pair_gen_var_1::<u64>().test_properties(|(x, n)| {
+        let p = x.clone().pow(n);
+        assert!(p.is_valid());
+        let p_alt = (&x).pow(n);
+        assert!(p_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&p_alt), ball_to_hex_string(&p));
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(n);
+        assert_eq!(ball_to_hex_string(&p_alt), ball_to_hex_string(&p));
+        let p_alt = x.pow_u_prec_ref(n, x.significant_bits());
+        assert_eq!(ball_to_hex_string(&p_alt), ball_to_hex_string(&p));
+        for a in ball_sample_points(&x) {
+            assert!(p.contains_rational(&(&a).pow(n)));
+        }
+    });
+
+    ball_gen().test_properties(|x| {
+        assert!((&x).pow(1u64).contains_ball(&x));
+        assert!((&x).pow(0u64).contains(&Float::ONE));
+    });
+}
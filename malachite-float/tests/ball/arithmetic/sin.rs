@@ -0,0 +1,136 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Sin, SinAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{
+    ball_may_contain, ball_sample_floats, ball_to_hex_string, parse_ball_hex,
+};
+use malachite_float::test_util::generators::{ball_gen, ball_unsigned_pair_gen_var_1};
+
+#[test]
+fn test_sin() {
+    let test = |xm, xr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().sin();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = (&x).sin();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+
+        let mut y_alt = x.clone();
+        y_alt.sin_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x0.0", "0x0.0", "[0.0 +/- 0.0]", "[0x0.0 +/- 0x0.0]");
+    test("0x1.0#1", "0x0.0", "[1.0 +/- 1.0]", "[0x1.0#1 +/- 0x1.0#1]");
+    test(
+        "0x1.0#1",
+        "0x0.4#1",
+        "[1.0 +/- 1.2500000000]",
+        "[0x1.0#1 +/- 0x1.40000000#30]",
+    );
+    test(
+        "-0x0.554#10",
+        "0x0.004#1",
+        "[-0.32666 +/- 0.0014648437500]",
+        "[-0x0.53a#10 +/- 0x0.0060000000#30]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0E+10#1",
+        "[1.0 +/- 3.0000000000]",
+        "[0x1.0#1 +/- 0x3.0000000#30]",
+    );
+}
+
+#[test]
+fn test_sin_prec() {
+    let test = |xm, xr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().sin_prec(prec);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = x.sin_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        1,
+        "[1.0 +/- 1.2500000000]",
+        "[0x1.0#1 +/- 0x1.40000000#30]",
+    );
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        10,
+        "[0.90918 +/- 0.25097656250]",
+        "[0x0.e8c#10 +/- 0x0.40400000#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x1.0E-25#1",
+        100,
+        "[0.99749498660405443094172337114111 +/- 1.5777218104e-30]",
+        "[0x0.ff5bd4d9636c56f370bfed657#100 +/- 0x2.0000000E-25#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn sin_prec_fail() {
+    Ball::ONE.sin_prec(0);
+}
+
+#[test]
+fn sin_prec_properties() {
+    ball_unsigned_pair_gen_var_1().test_properties_with_limit(2000, |(x, prec)| {
+        let y = x.clone().sin_prec(prec);
+        assert!(y.is_valid());
+        let y_alt = x.sin_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a
+                .sin_prec_round_ref(prec + 10, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+fn sin_properties() {
+    ball_gen().test_properties_with_limit(2000, |x| {
+        let y = x.clone().sin();
+        assert!(y.is_valid());
+        let y_alt = (&x).sin();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let mut y_alt = x.clone();
+        y_alt.sin_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let y_alt = x.sin_prec_ref(x.significant_bits());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a.sin_prec_round_ref(100, rm).0));
+        }
+    });
+}
@@ -0,0 +1,142 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{
+    ball_may_contain, ball_sample_floats, ball_to_hex_string, parse_ball_hex,
+};
+use malachite_float::test_util::generators::{ball_gen, ball_unsigned_pair_gen_var_1};
+
+#[test]
+fn test_sqrt() {
+    let test = |xm, xr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().sqrt();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = (&x).sqrt();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+
+        let mut y_alt = x.clone();
+        y_alt.sqrt_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x1.0#1", "0x0.0", "[1.0 +/- 0.0]", "[0x1.0#1 +/- 0x0.0]");
+    test("0x2.0#1", "0x0.0", "[1.0 +/- 1.0]", "[0x1.0#1 +/- 0x1.0#1]");
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        "[1.0 +/- 1.0913379081]",
+        "[0x1.0#1 +/- 0x1.1761ebd0#30]",
+    );
+    test(
+        "0x0.554#10",
+        "0x0.004#1",
+        "[0.57715 +/- 0.0018233249393]",
+        "[0x0.93c#10 +/- 0x0.00777e50fc#30]",
+    );
+    test(
+        "-0x1.0#1",
+        "0x0.0",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_sqrt_prec() {
+    let test = |xm, xr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+
+        let y = x.clone().sqrt_prec(prec);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(ball_to_hex_string(&y), out_hex);
+
+        let y_alt = x.sqrt_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), out_hex);
+    };
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        1,
+        "[1.0 +/- 1.0913379081]",
+        "[0x1.0#1 +/- 0x1.1761ebd0#30]",
+    );
+    test(
+        "0x2.0#1",
+        "0x0.4#1",
+        10,
+        "[1.4141 +/- 0.093291032012]",
+        "[0x1.6a0#10 +/- 0x0.17e1ebcb8#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x1.0E-25#1",
+        100,
+        "[1.2247448713915890490986420373532 +/- 1.8997729293e-30]",
+        "[0x1.3988e1409212e7d0321914322#100 +/- 0x2.6882f5dE-25#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn sqrt_prec_fail() {
+    Ball::ONE.sqrt_prec(0);
+}
+
+#[test]
+fn sqrt_prec_properties() {
+    ball_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let y = x.clone().sqrt_prec(prec);
+        assert!(y.is_valid());
+        let y_alt = x.sqrt_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a
+                .sqrt_prec_round_ref(prec + 10, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+fn sqrt_properties() {
+    ball_gen().test_properties(|x| {
+        let y = x.clone().sqrt();
+        assert!(y.is_valid());
+        let y_alt = (&x).sqrt();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let mut y_alt = x.clone();
+        y_alt.sqrt_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        let y_alt = x.sqrt_prec_ref(x.significant_bits());
+        assert_eq!(ball_to_hex_string(&y_alt), ball_to_hex_string(&y));
+        for a in ball_sample_floats(&x) {
+            assert!(ball_may_contain(&y, |rm| a.sqrt_prec_round_ref(100, rm).0));
+        }
+    });
+}
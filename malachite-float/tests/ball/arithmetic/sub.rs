@@ -0,0 +1,182 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{ball_sample_points, ball_to_hex_string, parse_ball_hex};
+use malachite_float::test_util::generators::{ball_gen, ball_pair_gen};
+use malachite_q::Rational;
+
+#[test]
+fn test_sub() {
+    let test = |xm, xr, ym, yr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let diff = x.clone() - y.clone();
+        assert!(diff.is_valid());
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(ball_to_hex_string(&diff), out_hex);
+
+        let diff_alt = x.clone() - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&diff_alt), out_hex);
+        let diff_alt = &x - y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&diff_alt), out_hex);
+        let diff_alt = &x - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&diff_alt), out_hex);
+
+        let mut diff_alt = x.clone();
+        diff_alt -= y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&diff_alt), out_hex);
+        let mut diff_alt = x.clone();
+        diff_alt -= &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&diff_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x0.0",
+        "0x1.0#1",
+        "0x0.0",
+        "[0.0 +/- 0.0]",
+        "[0x0.0 +/- 0x0.0]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        "[1.1680 +/- 0.25292968750]",
+        "[0x1.2b0#10 +/- 0x0.40c00000#30]",
+    );
+    test(
+        "-0x2.0#1",
+        "0x1.0#1",
+        "0x3.0#2",
+        "0x0.8#1",
+        "[-4.0 +/- 3.5000000000]",
+        "[-0x4.0#2 +/- 0x3.8000000#30]",
+    );
+    test(
+        "0x1.0#1",
+        "0x0.0",
+        "0x1.0E-50#1",
+        "0x0.0",
+        "[1.0 +/- 1.0]",
+        "[0x1.0#1 +/- 0x1.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn test_sub_prec() {
+    let test = |xm, xr, ym, yr, prec, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+
+        let diff = x.clone().sub_prec(y.clone(), prec);
+        assert!(diff.is_valid());
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(ball_to_hex_string(&diff), out_hex);
+
+        let diff_alt = x.sub_prec_ref_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&diff_alt), out_hex);
+    };
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        3,
+        "[1.2 +/- 0.50097656250]",
+        "[0x1.4#3 +/- 0x0.80400000#30]",
+    );
+    test(
+        "0x1.8#2",
+        "0x0.4#1",
+        "0x0.554#10",
+        "0x0.004#1",
+        20,
+        "[1.1669922 +/- 0.25097656250]",
+        "[0x1.2ac00#20 +/- 0x0.40400000#30]",
+    );
+    test(
+        "0x0.55555555555555555555555558#100",
+        "0x1.0E-25#1",
+        "0x0.49249249249249249249249248#100",
+        "0x1.0E-25#1",
+        100,
+        "[0.047619047619047619047619047619235 +/- 1.5777218104e-30]",
+        "[0x0.0c30c30c30c30c30c30c30c310#100 +/- 0x2.0000000E-25#30]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_prec_fail() {
+    Ball::ONE.sub_prec(Ball::ONE, 0);
+}
+
+#[test]
+fn sub_prec_properties() {
+    ball_pair_gen().test_properties(|(x, y)| {
+        for prec in [1, 10, 100] {
+            let diff = x.clone().sub_prec(y.clone(), prec);
+            assert!(diff.is_valid());
+            let diff_alt = x.sub_prec_ref_ref(&y, prec);
+            assert!(diff_alt.is_valid());
+            assert_eq!(ball_to_hex_string(&diff_alt), ball_to_hex_string(&diff));
+            for a in ball_sample_points(&x) {
+                for b in ball_sample_points(&y) {
+                    assert!(diff.contains_rational(&(&a - &b)));
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn sub_properties() {
+    ball_pair_gen().test_properties(|(x, y)| {
+        let diff = x.clone() - y.clone();
+        assert!(diff.is_valid());
+        let diff_alt = &x - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&diff_alt), ball_to_hex_string(&diff));
+        let diff_alt = x.sub_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(ball_to_hex_string(&diff_alt), ball_to_hex_string(&diff));
+        assert_eq!(ball_to_hex_string(&(&x + -&y)), ball_to_hex_string(&diff));
+        if *diff.mid() != 0u32 {
+            assert_eq!(ball_to_hex_string(&(&y - &x)), ball_to_hex_string(&-&diff));
+        }
+        for a in ball_sample_points(&x) {
+            for b in ball_sample_points(&y) {
+                assert!(diff.contains_rational(&(&a - &b)));
+            }
+        }
+    });
+
+    ball_gen().test_properties(|x| {
+        assert!((&x - &Ball::ZERO).contains_ball(&x));
+        assert!((&x - &x).contains_rational(&Rational::ZERO));
+    });
+}
@@ -0,0 +1,38 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::ball_to_hex_string;
+
+#[test]
+fn test_zero() {
+    let zero = Ball::ZERO;
+    assert!(zero.is_valid());
+    assert_eq!(zero.to_string(), "[0.0 +/- 0.0]");
+    assert_eq!(ball_to_hex_string(&zero), "[0x0.0 +/- 0x0.0]");
+    assert!(zero.is_exact());
+}
+
+#[test]
+fn test_one() {
+    let one = Ball::ONE;
+    assert!(one.is_valid());
+    assert_eq!(one.to_string(), "[1.0 +/- 0.0]");
+    assert_eq!(ball_to_hex_string(&one), "[0x1.0#1 +/- 0x0.0]");
+    assert!(one.is_exact());
+}
+
+#[test]
+fn test_indeterminate() {
+    let indeterminate = Ball::INDETERMINATE;
+    assert!(indeterminate.is_valid());
+    assert_eq!(indeterminate.to_string(), "[0.0 +/- Infinity]");
+    assert_eq!(ball_to_hex_string(&indeterminate), "[0x0.0 +/- Infinity]");
+    assert!(!indeterminate.is_bounded());
+}
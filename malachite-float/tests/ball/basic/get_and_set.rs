@@ -0,0 +1,119 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeOne, One};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_float::Float;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::parse_ball_hex;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::float_pair_gen;
+use malachite_q::Rational;
+
+#[test]
+fn test_new() {
+    let test = |xm, xr, out: &str, out_mid: &str, out_rad: &str| {
+        let mid = parse_hex_string(xm);
+        let rad = parse_hex_string(xr);
+        let x = Ball::new(mid.clone(), rad.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(x.mid()), out_mid);
+        assert_eq!(to_hex_string(x.rad()), out_rad);
+        let (mid_alt, rad_alt) = x.into_mid_rad();
+        assert_eq!(to_hex_string(&mid_alt), out_mid);
+        assert_eq!(to_hex_string(&rad_alt), out_rad);
+    };
+    test("0x0.0", "0x0.0", "[0.0 +/- 0.0]", "0x0.0", "0x0.0");
+    test("-0x0.0", "-0x0.0", "[-0.0 +/- 0.0]", "-0x0.0", "0x0.0");
+    test("0x1.8#2", "0x0.4#1", "[1.5 +/- 0.25]", "0x1.8#2", "0x0.4#1");
+    test(
+        "0x1.8#2",
+        "0x0.55555555555555555555555558#100",
+        "[1.5 +/- 0.33333333349]",
+        "0x1.8#2",
+        "0x0.55555556#30",
+    );
+    test(
+        "0x1.8#2",
+        "Infinity",
+        "[0.0 +/- Infinity]",
+        "0x0.0",
+        "Infinity",
+    );
+}
+
+#[test]
+#[should_panic]
+fn new_fail_1() {
+    Ball::new(Float::NAN, Float::ONE);
+}
+
+#[test]
+#[should_panic]
+fn new_fail_2() {
+    Ball::new(Float::INFINITY, Float::ONE);
+}
+
+#[test]
+#[should_panic]
+fn new_fail_3() {
+    Ball::new(Float::ONE, Float::NAN);
+}
+
+#[test]
+#[should_panic]
+fn new_fail_4() {
+    Ball::new(Float::ONE, Float::NEGATIVE_ONE);
+}
+
+#[test]
+fn new_properties() {
+    float_pair_gen().test_properties(|(mid, rad)| {
+        if !mid.is_finite() || rad.is_nan() || rad < 0u32 {
+            return;
+        }
+        let x = Ball::new(mid.clone(), rad.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.is_exact(), rad == 0u32);
+        assert_eq!(x.is_bounded(), rad.is_finite());
+        if x.is_bounded() {
+            assert_eq!(to_hex_string(x.mid()), to_hex_string(&mid));
+            assert!(*x.rad() >= rad);
+            assert!(x.contains(&mid));
+            if rad.is_finite() {
+                let rad = Rational::exact_from(&rad);
+                let mid = Rational::exact_from(&mid);
+                assert!(x.contains_rational(&(&mid - &rad)));
+                assert!(x.contains_rational(&(mid + rad)));
+            }
+        }
+    });
+}
+
+#[test]
+fn test_is_exact() {
+    let test = |xm, xr, out| {
+        assert_eq!(parse_ball_hex(xm, xr).is_exact(), out);
+    };
+    test("0x0.0", "0x0.0", true);
+    test("0x1.8#2", "-0x0.0", true);
+    test("0x1.8#2", "0x0.4#1", false);
+    test("0x1.8#2", "Infinity", false);
+}
+
+#[test]
+fn test_is_bounded() {
+    let test = |xm, xr, out| {
+        assert_eq!(parse_ball_hex(xm, xr).is_bounded(), out);
+    };
+    test("0x0.0", "0x0.0", true);
+    test("0x1.8#2", "0x0.4#1", true);
+    test("0x1.8#2", "0x1.0E+100#1", true);
+    test("0x1.8#2", "Infinity", false);
+}
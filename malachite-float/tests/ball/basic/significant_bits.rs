@@ -0,0 +1,37 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::parse_ball_hex;
+use malachite_float::test_util::generators::ball_gen;
+
+#[test]
+fn test_significant_bits() {
+    let test = |xm, xr, out| {
+        assert_eq!(parse_ball_hex(xm, xr).significant_bits(), out);
+    };
+    test("0x0.0", "0x0.0", 1);
+    test("0x1.0#1", "0x0.0", 1);
+    test("0x2.8#3", "0x0.4#1", 3);
+    test("0x0.554#10", "0x1.0000000000000000000000000E-23#100", 10);
+    test("0x1.0#1", "Infinity", 1);
+    assert_eq!(Ball::ZERO.significant_bits(), 1);
+    assert_eq!(Ball::ONE.significant_bits(), 1);
+    assert_eq!(Ball::INDETERMINATE.significant_bits(), 1);
+}
+
+#[test]
+fn significant_bits_properties() {
+    ball_gen().test_properties(|x| {
+        let bits = x.significant_bits();
+        assert_ne!(bits, 0);
+        assert_eq!(bits, x.mid().significant_bits());
+    });
+}
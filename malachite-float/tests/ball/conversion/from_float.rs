@@ -0,0 +1,60 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+use malachite_float::Float;
+use malachite_float::ball::Ball;
+use malachite_float::ball::conversion::from_float::BallFromFloatError;
+use malachite_float::test_util::ball::ball_to_hex_string;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::float_gen;
+
+#[test]
+fn test_try_from_float() {
+    let test = |s_hex, out: &str| {
+        let x = parse_hex_string(s_hex);
+        let ball = Ball::try_from(x.clone()).unwrap();
+        assert!(ball.is_valid());
+        assert_eq!(ball.to_string(), out);
+        assert_eq!(to_hex_string(ball.mid()), s_hex);
+        assert_eq!(to_hex_string(ball.rad()), "0x0.0");
+        let ball_alt = Ball::try_from(&x).unwrap();
+        assert!(ball_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&ball_alt), ball_to_hex_string(&ball));
+    };
+    test("0x0.0", "[0.0 +/- 0.0]");
+    test("-0x0.0", "[-0.0 +/- 0.0]");
+    test("0x1.0#1", "[1.0 +/- 0.0]");
+    test("-0x1.8#2", "[-1.5 +/- 0.0]");
+    test("0x0.555555555555554#57", "[0.3333333333333333322 +/- 0.0]");
+
+    for x in [Float::NAN, Float::INFINITY, Float::NEGATIVE_INFINITY] {
+        assert_eq!(Ball::try_from(&x).unwrap_err(), BallFromFloatError);
+        assert_eq!(Ball::try_from(x).unwrap_err(), BallFromFloatError);
+    }
+}
+
+#[test]
+fn try_from_float_properties() {
+    float_gen().test_properties(|x| {
+        let ball = Ball::try_from(&x);
+        assert_eq!(ball.is_ok(), x.is_finite());
+        let ball_alt = Ball::try_from(x.clone());
+        assert_eq!(ball_alt.is_ok(), x.is_finite());
+        if let Ok(ball) = ball {
+            assert!(ball.is_valid());
+            assert!(ball.is_exact());
+            assert!(ball.contains(&x));
+            assert_eq!(to_hex_string(ball.mid()), to_hex_string(&x));
+            assert_eq!(
+                ball_to_hex_string(&ball_alt.unwrap()),
+                ball_to_hex_string(&ball)
+            );
+        }
+    });
+}
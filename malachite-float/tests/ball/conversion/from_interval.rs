@@ -0,0 +1,76 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::ball_to_hex_string;
+use malachite_float::test_util::generators::interval_gen;
+use malachite_float::test_util::interval::{interval_sample_points, parse_interval_hex};
+
+#[test]
+fn test_from_interval() {
+    let test = |xl, xu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let ball = Ball::from(x.clone());
+        assert!(ball.is_valid());
+        assert_eq!(ball.to_string(), out);
+        assert_eq!(ball_to_hex_string(&ball), out_hex);
+        let ball_alt = Ball::from(&x);
+        assert!(ball_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&ball_alt), out_hex);
+    };
+    test("0x0.0", "0x0.0", "[0.0 +/- 0.0]", "[0x0.0 +/- 0x0.0]");
+    test("0x1.0#1", "0x1.0#1", "[1.0 +/- 0.0]", "[0x1.0#1 +/- 0x0.0]");
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "[2.0 +/- 0.50000000000]",
+        "[0x2.0#3 +/- 0x0.80000000#30]",
+    );
+    test(
+        "0x0.554#10",
+        "0x0.556#10",
+        "[0.33301 +/- 0.00048828125000]",
+        "[0x0.554#10 +/- 0x0.0020000000#30]",
+    );
+    test(
+        "-0x2.0#1",
+        "0x1.0#1",
+        "[-0.50 +/- 1.5000000000]",
+        "[-0x0.8#1 +/- 0x1.80000000#30]",
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+    test(
+        "-Infinity",
+        "Infinity",
+        "[0.0 +/- Infinity]",
+        "[0x0.0 +/- Infinity]",
+    );
+}
+
+#[test]
+fn from_interval_properties() {
+    interval_gen().test_properties(|x| {
+        let ball = Ball::from(&x);
+        assert!(ball.is_valid());
+        let ball_alt = Ball::from(x.clone());
+        assert!(ball_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&ball_alt), ball_to_hex_string(&ball));
+        if !x.is_bounded() {
+            assert!(!ball.is_bounded());
+        }
+        assert_eq!(ball.is_exact(), x.is_point());
+        for a in interval_sample_points(&x) {
+            assert!(ball.contains_rational(&a));
+        }
+    });
+}
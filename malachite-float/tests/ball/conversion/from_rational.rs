@@ -0,0 +1,104 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::*;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_float::Float;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::ball_to_hex_string;
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::str::FromStr;
+
+#[test]
+fn test_from_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str| {
+        let x = Rational::from_str(s).unwrap();
+        let ball = Ball::from_rational_prec(x.clone(), prec);
+        assert!(ball.is_valid());
+        assert_eq!(ball.to_string(), out);
+        assert_eq!(ball_to_hex_string(&ball), out_hex);
+        let ball_alt = Ball::from_rational_prec_ref(&x, prec);
+        assert!(ball_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&ball_alt), out_hex);
+        assert!(ball.contains_rational(&x));
+    };
+    test("0", 1, "[0.0 +/- 0.0]", "[0x0.0 +/- 0x0.0]");
+    test("0", 10, "[0.0 +/- 0.0]", "[0x0.0 +/- 0x0.0]");
+    test("1", 1, "[1.0 +/- 0.0]", "[0x1.0#1 +/- 0x0.0]");
+    test("1/3", 1, "[0.25 +/- 0.25]", "[0x0.4#1 +/- 0x0.4#1]");
+    test(
+        "1/3",
+        10,
+        "[0.33350 +/- 0.00049]",
+        "[0x0.556#10 +/- 0x0.002#1]",
+    );
+    test(
+        "-1/3",
+        10,
+        "[-0.33350 +/- 0.00049]",
+        "[-0x0.556#10 +/- 0x0.002#1]",
+    );
+    test(
+        "22/7",
+        100,
+        "[3.1428571428571428571428571428585 +/- 3.2e-30]",
+        "[0x3.2492492492492492492492494#100 +/- 0x4.0E-25#1]",
+    );
+    test("3/8", 2, "[0.38 +/- 0.0]", "[0x0.6#2 +/- 0x0.0]");
+    test(
+        "1000000000000",
+        10,
+        "[9.9965e11 +/- 1.1e9]",
+        "[0xe.8cE+9#10 +/- 0x4.0E+7#1]",
+    );
+    test(
+        "-1/1000000000000",
+        10,
+        "[-1.0001e-12 +/- 1.8e-15]",
+        "[-0x1.198E-10#10 +/- 0x8.0E-13#1]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_rational_prec_fail() {
+    Ball::from_rational_prec(Rational::from(1), 0);
+}
+
+#[test]
+fn from_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        let ball = Ball::from_rational_prec(x.clone(), prec);
+        assert!(ball.is_valid());
+        let ball_alt = Ball::from_rational_prec_ref(&x, prec);
+        assert!(ball_alt.is_valid());
+        assert_eq!(ball_to_hex_string(&ball_alt), ball_to_hex_string(&ball));
+        assert!(ball.contains_rational(&x));
+        if ball.is_bounded() && x != 0u32 {
+            assert!(ball.mid().get_prec().is_none_or(|p| p == prec));
+            assert_eq!(
+                ball_to_hex_string(&Ball::from_rational_prec(-&x, prec)),
+                ball_to_hex_string(&-&ball)
+            );
+        }
+        let (mid, o) = Float::from_rational_prec_ref(&x, prec);
+        if mid.is_finite() {
+            assert_eq!(ball.mid(), &mid);
+        }
+        assert_eq!(ball.is_exact(), o == Equal);
+        if ball.is_exact() {
+            assert_eq!(Rational::exact_from(ball.mid()), x);
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        let ball = Ball::from_rational_prec_ref(&x, 100);
+        assert!(ball.contains_rational(&x));
+    });
+}
@@ -0,0 +1,63 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::interval::Interval;
+use malachite_float::test_util::ball::{ball_sample_points, parse_ball_hex};
+use malachite_float::test_util::generators::ball_gen;
+use malachite_float::test_util::interval::interval_to_hex_string;
+
+#[test]
+fn test_from_ball() {
+    let test = |xm, xr, out: &str, out_hex: &str| {
+        let x = parse_ball_hex(xm, xr);
+        let interval = Interval::from(x.clone());
+        assert!(interval.is_valid());
+        assert_eq!(interval.to_string(), out);
+        assert_eq!(interval_to_hex_string(&interval), out_hex);
+        let interval_alt = Interval::from(&x);
+        assert!(interval_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&interval_alt), out_hex);
+    };
+    test("0x0.0", "0x0.0", "[-0.0, 0.0]", "[-0x0.0, 0x0.0]");
+    test("0x1.0#1", "0x0.0", "[1.0, 1.0]", "[0x1.0#1, 0x1.0#1]");
+    test("0x1.8#2", "0x0.4#1", "[1.0, 2.0]", "[0x1.0#2, 0x2.0#2]");
+    test(
+        "0x0.554#10",
+        "0x0.004#1",
+        "[0.33203, 0.33398]",
+        "[0x0.550#10, 0x0.558#10]",
+    );
+    test("0x1.8#2", "0x0.01#1", "[1.0, 2.0]", "[0x1.0#2, 0x2.0#2]");
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "[-Infinity, Infinity]",
+        "[-Infinity, Infinity]",
+    );
+}
+
+#[test]
+fn from_ball_properties() {
+    ball_gen().test_properties(|x| {
+        let interval = Interval::from(&x);
+        assert!(interval.is_valid());
+        let interval_alt = Interval::from(x.clone());
+        assert!(interval_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&interval_alt),
+            interval_to_hex_string(&interval)
+        );
+        if !x.is_bounded() {
+            assert!(!interval.is_bounded());
+        }
+        for a in ball_sample_points(&x) {
+            assert!(interval.contains_rational(&a));
+        }
+        assert!(interval.contains(x.mid()));
+    });
+}
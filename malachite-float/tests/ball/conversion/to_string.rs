@@ -0,0 +1,35 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::test_util::ball::parse_ball_hex;
+use malachite_float::test_util::generators::ball_gen;
+
+#[test]
+fn test_to_string() {
+    let test = |xm, xr, out| {
+        let x = parse_ball_hex(xm, xr);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(format!("{x:?}"), out);
+    };
+    test("0x0.0", "0x0.0", "[0.0 +/- 0.0]");
+    test("-0x0.0", "0x0.0", "[-0.0 +/- 0.0]");
+    test("0x1.0#1", "0x0.0", "[1.0 +/- 0.0]");
+    test("0x1.8#2", "0x0.4#1", "[1.5 +/- 0.25]");
+    test("0x0.554#10", "0x0.004#1", "[0.33301 +/- 0.00098]");
+    test("0x1.0#1", "Infinity", "[0.0 +/- Infinity]");
+    test("0x4.0E+268435455#1", "0x1.0#1", "[1.0e323228496 +/- 1.0]");
+}
+
+#[test]
+fn to_string_properties() {
+    ball_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(format!("{x:?}"), s);
+        assert_eq!(s, format!("[{} +/- {}]", x.mid(), x.rad()));
+    });
+}
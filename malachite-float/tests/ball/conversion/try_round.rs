@@ -0,0 +1,100 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_float::Float;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{ball_sample_floats, parse_ball_hex};
+use malachite_float::test_util::common::to_hex_string;
+use malachite_float::test_util::generators::ball_unsigned_pair_gen_var_1;
+use malachite_q::Rational;
+
+#[test]
+fn test_try_round() {
+    let test = |xm, xr, prec, rm: RoundingMode, out: Option<&str>| {
+        let x = parse_ball_hex(xm, xr);
+        let y = x.try_round(prec, rm);
+        assert_eq!(y.as_ref().map(to_hex_string).as_deref(), out);
+        if let Some(y) = y {
+            assert!(y.is_valid());
+        }
+    };
+    test("0x1.8#2", "0x0.0", 1, Floor, Some("0x1.0#1"));
+    test("0x1.8#2", "0x0.0", 1, Nearest, Some("0x2.0#1"));
+    test("0x1.8#2", "0x0.0", 1, Exact, None);
+    test("0x1.8#2", "0x0.0", 2, Exact, Some("0x1.8#2"));
+    test("-0x0.0", "0x0.0", 1, Nearest, Some("-0x0.0"));
+    test("0x1.8#2", "0x0.2#1", 1, Floor, Some("0x1.0#1"));
+    test("0x1.8#2", "0x0.2#1", 1, Ceiling, Some("0x2.0#1"));
+    test("0x1.8#2", "0x0.2#1", 1, Nearest, None);
+    test("0x1.8#2", "0x0.2#1", 1, Exact, None);
+    test("0x1.8#2", "0x0.2#1", 2, Floor, None);
+    test("0x0.0", "0x1.0E-10#1", 1, Floor, None);
+    test("0x1.0#1", "Infinity", 1, Nearest, None);
+    test(
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        "0x1.0E-25#1",
+        53,
+        Nearest,
+        Some("0x1.6a09e667f3bcd#53"),
+    );
+    test(
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        "0x1.0E-25#1",
+        53,
+        Floor,
+        Some("0x1.6a09e667f3bcc#53"),
+    );
+    test(
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        None,
+    );
+}
+
+#[test]
+#[should_panic]
+fn try_round_fail() {
+    Ball::ONE.try_round(0, Nearest);
+}
+
+#[test]
+fn try_round_properties() {
+    ball_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        for rm in exhaustive_rounding_modes() {
+            let y = x.try_round(prec, rm);
+            if !x.is_bounded() || rm == Exact && !x.is_exact() {
+                assert!(y.is_none());
+            }
+            if let Some(y) = y {
+                assert!(y.is_valid());
+                assert!(y.get_prec().is_none_or(|p| p <= prec));
+                for a in ball_sample_floats(&x) {
+                    assert_eq!(Float::from_float_prec_round(a, prec, rm).0, y);
+                }
+            }
+        }
+    });
+
+    ball_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        // A Ball enclosing a Rational closely enough can always be rounded to nearest
+        if !x.is_bounded() {
+            return;
+        }
+        let q = Rational::exact_from(x.mid());
+        let ball = Ball::from_rational_prec_ref(&q, prec + 64);
+        if let Some(y) = ball.try_round(prec, Nearest) {
+            assert_eq!(y, Float::from_rational_prec_ref(&q, prec).0);
+        }
+    });
+}
@@ -0,0 +1,122 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_float::Float;
+use malachite_float::ball::Ball;
+use malachite_float::test_util::ball::{ball_sample_points, parse_ball_hex};
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::generators::{ball_float_pair_gen, ball_pair_gen};
+use malachite_q::Rational;
+
+#[test]
+fn test_contains() {
+    let test = |xm, xr, y, out| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_hex_string(y);
+        assert_eq!(x.contains(&y), out);
+        if y.is_finite() {
+            assert_eq!(x.contains_rational(&Rational::exact_from(&y)), out);
+        }
+    };
+    test("0x2.0#1", "0x0.8#1", "0x2.0#1", true);
+    test("0x2.0#1", "0x0.8#1", "0x1.800#10", true);
+    test("0x2.0#1", "0x0.8#1", "0x2.8#3", true);
+    test("0x2.0#1", "0x0.8#1", "0x1.0#1", false);
+    test("0x2.0#1", "0x0.8#1", "0x3.0#2", false);
+    test("0x2.0#1", "0x0.8#1", "NaN", false);
+    test("0x2.0#1", "0x0.8#1", "Infinity", false);
+    test("0x0.0", "0x0.0", "-0x0.0", true);
+    test("0x1.0#1", "Infinity", "0x1.0E+100#1", true);
+    test("0x1.0#1", "Infinity", "-Infinity", true);
+    test("0x1.0#1", "Infinity", "NaN", false);
+}
+
+#[test]
+fn test_contains_rational() {
+    let test = |xm, xr, y, out| {
+        let x = parse_ball_hex(xm, xr);
+        assert_eq!(x.contains_rational(&Rational::exact_from(y)), out);
+    };
+    test("0x0.554#10", "0x0.004#1", 1.0 / 3.0, true);
+    test("0x0.554#10", "0x0.004#1", 0.5, false);
+    test("0x1.0#1", "Infinity", -1.0e100, true);
+    assert!(
+        Ball::from_rational_prec(Rational::from_signeds(1, 3), 10)
+            .contains_rational(&Rational::from_signeds(1, 3))
+    );
+    assert!(
+        !Ball::from_rational_prec(Rational::from_signeds(1, 3), 10)
+            .contains_rational(&Rational::from_signeds(1, 2))
+    );
+}
+
+#[test]
+fn test_contains_ball() {
+    let test = |xm, xr, ym, yr, out| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+        assert_eq!(x.contains_ball(&y), out);
+    };
+    test("0x2.0#1", "0x0.8#1", "0x2.0#1", "0x0.8#1", true);
+    test("0x2.0#1", "0x0.8#1", "0x2.0#1", "0x0.0", true);
+    test("0x2.0#1", "0x0.8#1", "0x1.8#2", "0x0.4#1", false);
+    test("0x2.0#1", "0x0.8#1", "0x2.0#1", "0x1.0#1", false);
+    test("0x2.0#1", "Infinity", "0x2.0#1", "Infinity", true);
+    test("0x2.0#1", "0x0.8#1", "0x2.0#1", "Infinity", false);
+}
+
+#[test]
+fn test_overlaps() {
+    let test = |xm, xr, ym, yr, out| {
+        let x = parse_ball_hex(xm, xr);
+        let y = parse_ball_hex(ym, yr);
+        assert_eq!(x.overlaps(&y), out);
+        assert_eq!(y.overlaps(&x), out);
+    };
+    test("0x2.0#1", "0x0.8#1", "0x2.0#1", "0x0.8#1", true);
+    test("0x2.0#1", "0x0.8#1", "0x3.0#2", "0x0.8#1", true);
+    test("0x2.0#1", "0x0.8#1", "0x4.0#1", "0x0.8#1", false);
+    test("0x2.0#1", "0x0.0", "0x2.0#1", "0x0.0", true);
+    test("0x2.0#1", "0x0.8#1", "0x1.0E+100#1", "Infinity", true);
+}
+
+#[test]
+fn contains_properties() {
+    ball_float_pair_gen().test_properties(|(x, y)| {
+        let contains = x.contains(&y);
+        assert_eq!((-&x).contains(&-&y), contains);
+        if y.is_finite() {
+            assert_eq!(x.contains_rational(&Rational::exact_from(&y)), contains);
+            assert_eq!(x.contains_ball(&Ball::try_from(&y).unwrap()), contains);
+            assert_eq!(x.overlaps(&Ball::try_from(&y).unwrap()), contains);
+        }
+        assert!(!x.contains(&Float::NAN));
+        assert!(Ball::INDETERMINATE.contains(&Float::ZERO));
+        assert_eq!(x.contains(&Float::INFINITY), !x.is_bounded());
+    });
+
+    ball_pair_gen().test_properties(|(x, y)| {
+        let contains = x.contains_ball(&y);
+        if contains {
+            for a in ball_sample_points(&y) {
+                assert!(x.contains_rational(&a));
+            }
+            assert!(x.overlaps(&y));
+        }
+        let overlaps = x.overlaps(&y);
+        assert_eq!(y.overlaps(&x), overlaps);
+        if x.contains(y.mid()) {
+            assert!(overlaps);
+        }
+        assert!(x.contains_ball(&x));
+        assert!(x.overlaps(&x));
+        assert!(Ball::INDETERMINATE.contains_ball(&x));
+    });
+}
@@ -0,0 +1,2335 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Acos, AcosAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, One, OneHalf};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::acos::{
+    primitive_float_acos, primitive_float_acos_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::acos::{
+    rug_acos, rug_acos_prec, rug_acos_prec_round, rug_acos_rational_prec,
+    rug_acos_rational_prec_round, rug_acos_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_50, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_39,
+    rational_unsigned_rounding_mode_triple_gen_var_12,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_acos() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let acos = x.clone().acos();
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let acos_alt = (&x).acos();
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+
+        let mut acos_alt = x.clone();
+        acos_alt.acos_assign();
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&acos)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "4.0", "0x4.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.0",
+        "0x0.0",
+    );
+    test("0.50", "0x0.8#1", "1.0", "0x1.0#1");
+    test("-0.50", "-0x0.8#1", "2.0", "0x2.0#1");
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        "0.14153947332442729",
+        "0x0.243bee51057fb0#52",
+    );
+    test("123.0", "0x7b.0#7", "NaN", "NaN");
+    test("3.1415926535897931", "0x3.243f6a8885a3#50", "NaN", "NaN");
+    test("7.9e-31", "0x1.0E-25#1", "2.0", "0x2.0#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "1.9106332362490185563277142050314",
+        "0x1.e91f42805715cd225e7bff5f0#100",
+    );
+}
+
+#[test]
+fn test_acos_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec(prec);
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_assign(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "2.0", "0x2.0#1", Greater);
+    test("0.0", "0x0.0", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "3.1406", "0x3.24#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", 1, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", 10, "1.0469", "0x1.0c0#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "1.0471975511965977461542144610936",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, "2.0", "0x2.0#1", Less);
+    test("-0.50", "-0x0.8#1", 10, "2.0938", "0x2.18#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "2.0943951023931954923084289221873",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        "0.14160",
+        "0x0.244#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        "0.14153947332442728170708819891752",
+        "0x0.243bee51057faf6ef990df4d64#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "2.0", "0x2.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "1.2305",
+        "0x1.3b0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "1.9102",
+        "0x1.e90#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "1.9106332362490185563277142050314",
+        "0x1.e91f42805715cd225e7bff5f0#100",
+        Less,
+    );
+}
+
+#[test]
+fn acos_prec_fail() {
+    assert_panic!(Float::NAN.acos_prec(0));
+    assert_panic!(Float::NAN.acos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.acos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_acos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_round(rm);
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_round_ref(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_round_assign(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Less);
+    test("0.0", "0x0.0", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.0", "0x0.0", Down, "1.0", "0x1.0#1", Less);
+    test("0.0", "0x0.0", Up, "2.0", "0x2.0#1", Greater);
+    test("0.0", "0x0.0", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Less);
+    test("-0.0", "-0x0.0", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", Down, "1.0", "0x1.0#1", Less);
+    test("-0.0", "-0x0.0", Up, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "4.0", "0x4.0#1", Greater);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", Floor, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", Up, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", Floor, "2.0", "0x2.0#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "2.0", "0x2.0#1", Less);
+    test("-0.50", "-0x0.8#1", Up, "4.0", "0x4.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Nearest, "2.0", "0x2.0#1", Less);
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Floor,
+        "0.14153947332442723",
+        "0x0.243bee51057fac#52",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Ceiling,
+        "0.14153947332442729",
+        "0x0.243bee51057fb0#52",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Down,
+        "0.14153947332442723",
+        "0x0.243bee51057fac#52",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Up,
+        "0.14153947332442729",
+        "0x0.243bee51057fb0#52",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Nearest,
+        "0.14153947332442729",
+        "0x0.243bee51057fb0#52",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Floor, "1.0", "0x1.0#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Down, "1.0", "0x1.0#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Up, "2.0", "0x2.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "1.2309594173407746821349291782463",
+        "0x1.3b2028082e8d3bb0b49d8acee#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "1.2309594173407746821349291782463",
+        "0x1.3b2028082e8d3bb0b49d8acee#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "1.9106332362490185563277142050314",
+        "0x1.e91f42805715cd225e7bff5f0#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "1.9106332362490185563277142050330",
+        "0x1.e91f42805715cd225e7bff5f2#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "1.9106332362490185563277142050314",
+        "0x1.e91f42805715cd225e7bff5f0#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "1.9106332362490185563277142050330",
+        "0x1.e91f42805715cd225e7bff5f2#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "1.9106332362490185563277142050314",
+        "0x1.e91f42805715cd225e7bff5f0#100",
+        Less,
+    );
+}
+
+#[test]
+fn acos_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.acos_round(Exact));
+    assert_panic!(X.acos_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.acos_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_acos_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_round_assign(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("0.0", "0x0.0", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.0", "0x0.0", 1, Down, "1.0", "0x1.0#1", Less);
+    test("0.0", "0x0.0", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("0.0", "0x0.0", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-0.0", "-0x0.0", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-0.0", "-0x0.0", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-0.0", "-0x0.0", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, Floor, "3.1406", "0x3.24#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "3.1445",
+        "0x3.25#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "3.1406", "0x3.24#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "3.1445", "0x3.25#10", Greater);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "3.1406", "0x3.24#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "3.1415926535897932384626433832825",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", 10, Floor, "1.0469", "0x1.0c0#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "1.0488",
+        "0x1.0c8#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "1.0469", "0x1.0c0#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "1.0488", "0x1.0c8#10", Greater);
+    test("0.50", "0x0.8#1", 10, Nearest, "1.0469", "0x1.0c0#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "1.0471975511965977461542144610921",
+        "0x1.0c152382d73658465bb32e0f4#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "1.0471975511965977461542144610936",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "1.0471975511965977461542144610936",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Down, "2.0", "0x2.0#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("-0.50", "-0x0.8#1", 10, Floor, "2.0938", "0x2.18#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "2.0977",
+        "0x2.19#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Down, "2.0938", "0x2.18#10", Less);
+    test("-0.50", "-0x0.8#1", 10, Up, "2.0977", "0x2.19#10", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "2.0938",
+        "0x2.18#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "2.0943951023931954923084289221841",
+        "0x2.182a4705ae6cb08cb7665c1e8#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "2.0943951023931954923084289221873",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "2.0943951023931954923084289221873",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Nearest,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Floor,
+        "0.14136",
+        "0x0.243#10",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Ceiling,
+        "0.14160",
+        "0x0.244#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Down,
+        "0.14136",
+        "0x0.243#10",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Up,
+        "0.14160",
+        "0x0.244#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Nearest,
+        "0.14160",
+        "0x0.244#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        Floor,
+        "0.14153947332442728170708819891732",
+        "0x0.243bee51057faf6ef990df4d60#100",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        Ceiling,
+        "0.14153947332442728170708819891752",
+        "0x0.243bee51057faf6ef990df4d64#100",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        Nearest,
+        "0.14153947332442728170708819891752",
+        "0x0.243bee51057faf6ef990df4d64#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Exact, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Exact, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.5723",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.5723",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "1.5707963267948966192313216916381",
+        "0x1.921fb54442d18469898cc516e#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "1.2305",
+        "0x1.3b0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "1.2324",
+        "0x1.3b8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "1.2305",
+        "0x1.3b0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "1.2324",
+        "0x1.3b8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "1.2305",
+        "0x1.3b0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "1.2309594173407746821349291782463",
+        "0x1.3b2028082e8d3bb0b49d8acee#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "1.9102",
+        "0x1.e90#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "1.9121",
+        "0x1.e98#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "1.9102",
+        "0x1.e90#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "1.9121",
+        "0x1.e98#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "1.9102",
+        "0x1.e90#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "1.9106332362490185563277142050314",
+        "0x1.e91f42805715cd225e7bff5f0#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "1.9106332362490185563277142050330",
+        "0x1.e91f42805715cd225e7bff5f2#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "1.9106332362490185563277142050314",
+        "0x1.e91f42805715cd225e7bff5f0#100",
+        Less,
+    );
+}
+
+#[test]
+fn acos_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).acos_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).acos_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.acos_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.acos_prec_round(1, Exact));
+    assert_panic!(X.acos_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.acos_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_acos_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (acos, o) = Float::acos_rational_prec(x.clone(), prec);
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, out_o);
+
+        let (acos, o) = Float::acos_rational_prec_ref(&x, prec);
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_acos, rug_o) = rug_acos_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "2.0", "0x2.0#1", Greater);
+    test("0", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "0",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("1", 1, "0.0", "0x0.0", Equal);
+    test("1", 10, "0.0", "0x0.0", Equal);
+    test("1", 100, "0.0", "0x0.0", Equal);
+    test("-1", 1, "4.0", "0x4.0#1", Greater);
+    test("-1", 10, "3.1406", "0x3.24#10", Less);
+    test(
+        "-1",
+        100,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test("1/2", 1, "1.0", "0x1.0#1", Less);
+    test("1/2", 10, "1.0469", "0x1.0c0#10", Less);
+    test(
+        "1/2",
+        100,
+        "1.0471975511965977461542144610936",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test("-1/2", 1, "2.0", "0x2.0#1", Less);
+    test("-1/2", 10, "2.0938", "0x2.18#10", Less);
+    test(
+        "-1/2",
+        100,
+        "2.0943951023931954923084289221873",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test("1/3", 1, "1.0", "0x1.0#1", Less);
+    test("1/3", 10, "1.2305", "0x1.3b0#10", Less);
+    test(
+        "1/3",
+        100,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Less,
+    );
+    test("3/2", 1, "NaN", "NaN", Equal);
+    test("3/2", 10, "NaN", "NaN", Equal);
+    test("3/2", 100, "NaN", "NaN", Equal);
+    test("99/100", 1, "0.12", "0x0.2#1", Less);
+    test("99/100", 10, "0.14160", "0x0.244#10", Greater);
+    test(
+        "99/100",
+        100,
+        "0.14153947332442721874578935697508",
+        "0x0.243bee51057faae58b3d10d6d0#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn acos_rational_prec_fail() {
+    Float::acos_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn acos_rational_prec_ref_fail() {
+    Float::acos_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_acos_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (acos, o) = Float::acos_rational_prec_round(x.clone(), prec, rm);
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, out_o);
+
+        let (acos, o) = Float::acos_rational_prec_round_ref(&x, prec, rm);
+        assert!(acos.is_valid());
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("0", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0", 1, Down, "1.0", "0x1.0#1", Less);
+    test("0", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("0", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("0", 10, Floor, "1.5703", "0x1.920#10", Less);
+    test("0", 10, Ceiling, "1.5723", "0x1.928#10", Greater);
+    test("0", 10, Down, "1.5703", "0x1.920#10", Less);
+    test("0", 10, Up, "1.5723", "0x1.928#10", Greater);
+    test("0", 10, Nearest, "1.5703", "0x1.920#10", Less);
+    test(
+        "0",
+        100,
+        Nearest,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1", 1, Down, "0.0", "0x0.0", Equal);
+    test("1", 1, Up, "0.0", "0x0.0", Equal);
+    test("1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1", 10, Down, "0.0", "0x0.0", Equal);
+    test("1", 10, Up, "0.0", "0x0.0", Equal);
+    test("1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("-1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-1", 1, Down, "2.0", "0x2.0#1", Less);
+    test("-1", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("-1", 1, Nearest, "4.0", "0x4.0#1", Greater);
+    test("-1", 10, Floor, "3.1406", "0x3.24#10", Less);
+    test("-1", 10, Ceiling, "3.1445", "0x3.25#10", Greater);
+    test("-1", 10, Down, "3.1406", "0x3.24#10", Less);
+    test("-1", 10, Up, "3.1445", "0x3.25#10", Greater);
+    test("-1", 10, Nearest, "3.1406", "0x3.24#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test("1/2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1/2", 10, Floor, "1.0469", "0x1.0c0#10", Less);
+    test("1/2", 10, Ceiling, "1.0488", "0x1.0c8#10", Greater);
+    test("1/2", 10, Down, "1.0469", "0x1.0c0#10", Less);
+    test("1/2", 10, Up, "1.0488", "0x1.0c8#10", Greater);
+    test("1/2", 10, Nearest, "1.0469", "0x1.0c0#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "1.0471975511965977461542144610936",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test("-1/2", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("-1/2", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-1/2", 1, Down, "2.0", "0x2.0#1", Less);
+    test("-1/2", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("-1/2", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("-1/2", 10, Floor, "2.0938", "0x2.18#10", Less);
+    test("-1/2", 10, Ceiling, "2.0977", "0x2.19#10", Greater);
+    test("-1/2", 10, Down, "2.0938", "0x2.18#10", Less);
+    test("-1/2", 10, Up, "2.0977", "0x2.19#10", Greater);
+    test("-1/2", 10, Nearest, "2.0938", "0x2.18#10", Less);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "2.0943951023931954923084289221873",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1/3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1/3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1/3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1/3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1/3", 10, Floor, "1.2305", "0x1.3b0#10", Less);
+    test("1/3", 10, Ceiling, "1.2324", "0x1.3b8#10", Greater);
+    test("1/3", 10, Down, "1.2305", "0x1.3b0#10", Less);
+    test("1/3", 10, Up, "1.2324", "0x1.3b8#10", Greater);
+    test("1/3", 10, Nearest, "1.2305", "0x1.3b0#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "1.2309594173407746821349291782479",
+        "0x1.3b2028082e8d3bb0b49d8acf0#100",
+        Less,
+    );
+    test("3/2", 1, Floor, "NaN", "NaN", Equal);
+    test("3/2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("3/2", 1, Down, "NaN", "NaN", Equal);
+    test("3/2", 1, Up, "NaN", "NaN", Equal);
+    test("3/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("3/2", 1, Exact, "NaN", "NaN", Equal);
+    test("3/2", 10, Floor, "NaN", "NaN", Equal);
+    test("3/2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("3/2", 10, Down, "NaN", "NaN", Equal);
+    test("3/2", 10, Up, "NaN", "NaN", Equal);
+    test("3/2", 10, Nearest, "NaN", "NaN", Equal);
+    test("3/2", 10, Exact, "NaN", "NaN", Equal);
+    test("3/2", 100, Nearest, "NaN", "NaN", Equal);
+    test("99/100", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("99/100", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("99/100", 1, Down, "0.12", "0x0.2#1", Less);
+    test("99/100", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("99/100", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("99/100", 10, Floor, "0.14136", "0x0.243#10", Less);
+    test("99/100", 10, Ceiling, "0.14160", "0x0.244#10", Greater);
+    test("99/100", 10, Down, "0.14136", "0x0.243#10", Less);
+    test("99/100", 10, Up, "0.14160", "0x0.244#10", Greater);
+    test("99/100", 10, Nearest, "0.14160", "0x0.244#10", Greater);
+    test(
+        "99/100",
+        100,
+        Nearest,
+        "0.14153947332442721874578935697508",
+        "0x0.243bee51057faae58b3d10d6d0#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn acos_rational_prec_round_fail_1() {
+    Float::acos_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn acos_rational_prec_round_fail_2() {
+    Float::acos_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn acos_rational_prec_round_ref_fail() {
+    Float::acos_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn acos_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (acos, o) = x.clone().acos_prec_round(prec, rm);
+    assert!(acos.is_valid());
+
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(acos.is_nan(), !(x >= -1i32 && x <= 1u32));
+    assert!(acos.is_nan() || (acos >= 0u32 && acos <= 4u32));
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(prec));
+    }
+    if x.is_normal() {
+        let (acos_alt, o_alt) = Float::acos_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.acos_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(acos.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.acos_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn acos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_39().test_properties(|(x, prec, rm)| {
+        acos_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (acos, o) = Float::NAN.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acos, o) = Float::INFINITY.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acos, o) = Float::NEGATIVE_INFINITY.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn acos_prec_properties_helper(x: Float, prec: u64) {
+    let (acos, o) = x.clone().acos_prec(prec);
+    assert!(acos.is_valid());
+
+    let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acos)),
+        ComparableFloatRef(&acos)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(acos.is_nan() || (acos >= 0u32 && acos <= 4u32));
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn acos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        acos_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (acos, o) = Float::NAN.acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acos, o) = Float::INFINITY.acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acos, o) = Float::NEGATIVE_INFINITY.acos_prec(prec);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn acos_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (acos, o) = x.clone().acos_round(rm);
+    assert!(acos.is_valid());
+
+    let (acos_alt, o_alt) = x.acos_round_ref(rm);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    assert!(acos.is_nan() || (acos >= 0u32 && acos <= 4u32));
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.acos_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(acos.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.acos_round_ref(Exact));
+    }
+}
+
+#[test]
+fn acos_round_properties() {
+    float_rounding_mode_pair_gen_var_50().test_properties(|(x, rm)| {
+        acos_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (acos, o) = Float::NAN.acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acos, o) = Float::INFINITY.acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acos, o) = Float::NEGATIVE_INFINITY.acos_round(rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn acos_properties_helper(x: Float) {
+    let acos = x.clone().acos();
+    assert!(acos.is_valid());
+
+    let acos_alt = (&x).acos();
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+
+    let mut x_alt = x.clone();
+    x_alt.acos_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+
+    let acos_alt = x.acos_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    let acos_alt = x.acos_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+
+    assert_eq!(acos.is_nan(), !(x >= -1i32 && x <= 1u32));
+    assert!(acos.is_nan() || (acos >= 0u32 && acos <= 4u32));
+
+    let rug_acos = rug_acos(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acos)),
+        ComparableFloatRef(&acos),
+    );
+}
+
+#[test]
+fn acos_properties() {
+    float_gen().test_properties(|x| {
+        acos_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn acos_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (acos, o) = Float::acos_rational_prec_round(x.clone(), prec, rm);
+    assert!(acos.is_valid());
+
+    let (acos_alt, o_alt) = Float::acos_rational_prec_round_ref(&x, prec, rm);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acos, rug_o) = rug_acos_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(acos.is_nan(), x < -1i32 || x > 1u32);
+    assert!(acos.is_nan() || (acos >= 0u32 && acos <= 4u32));
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::acos_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(acos.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::acos_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn acos_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_12().test_properties(|(x, prec, rm)| {
+        acos_rational_prec_round_properties_helper(x, prec, rm);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn acos_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (acos, o) = Float::acos_rational_prec(x.clone(), prec);
+    assert!(acos.is_valid());
+
+    let (acos_alt, o_alt) = Float::acos_rational_prec_ref(&x, prec);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (acos_alt, o_alt) = Float::acos_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (rug_acos, rug_o) = rug_acos_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acos)),
+        ComparableFloatRef(&acos)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(acos.is_nan() || (acos >= 0u32 && acos <= 4u32));
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn acos_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        acos_rational_prec_properties_helper(x, prec);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_acos() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_acos(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::NAN);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, core::f32::consts::FRAC_PI_2);
+    test::<f32>(-0.0, core::f32::consts::FRAC_PI_2);
+    test::<f32>(1.0, 0.0);
+    test::<f32>(-1.0, core::f32::consts::PI);
+    test::<f32>(0.5, core::f32::consts::FRAC_PI_3);
+    test::<f32>(-0.5, 2.0943952);
+    test::<f32>(2.0, f32::NAN);
+    test::<f32>(10.0, f32::NAN);
+    test::<f32>(0.001, 1.5697963);
+    test::<f32>(10000000000.0, f32::NAN);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::NAN);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, core::f64::consts::FRAC_PI_2);
+    test::<f64>(-0.0, core::f64::consts::FRAC_PI_2);
+    test::<f64>(1.0, 0.0);
+    test::<f64>(-1.0, core::f64::consts::PI);
+    test::<f64>(0.5, core::f64::consts::FRAC_PI_3);
+    test::<f64>(-0.5, 2.0943951023931957);
+    test::<f64>(2.0, f64::NAN);
+    test::<f64>(10.0, f64::NAN);
+    test::<f64>(0.001, 1.56979632662823);
+    test::<f64>(10000000000.0, f64::NAN);
+    test::<f64>(1.0e300, f64::NAN);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_acos_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let acos = primitive_float_acos(x);
+        assert_eq!(acos.is_nan(), !(x >= -T::ONE && x <= T::ONE));
+        assert!(acos.is_nan() || acos >= T::ZERO);
+    });
+}
+
+#[test]
+fn primitive_float_acos_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_acos_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_acos_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_acos_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", core::f32::consts::FRAC_PI_2);
+    test::<f32>("1", 0.0);
+    test::<f32>("-1", core::f32::consts::PI);
+    test::<f32>("1/2", core::f32::consts::FRAC_PI_3);
+    test::<f32>("-1/2", 2.0943952);
+    test::<f32>("1/3", 1.2309594);
+    test::<f32>("22/7", f32::NAN);
+    test::<f32>("3/2", f32::NAN);
+    test::<f32>("1000000", f32::NAN);
+
+    test::<f64>("0", core::f64::consts::FRAC_PI_2);
+    test::<f64>("1", 0.0);
+    test::<f64>("-1", core::f64::consts::PI);
+    test::<f64>("1/2", core::f64::consts::FRAC_PI_3);
+    test::<f64>("-1/2", 2.0943951023931957);
+    test::<f64>("1/3", 1.2309594173407747);
+    test::<f64>("22/7", f64::NAN);
+    test::<f64>("3/2", f64::NAN);
+    test::<f64>("1000000", f64::NAN);
+}
+
+#[allow(clippy::manual_range_contains, clippy::type_repetition_in_bounds)]
+fn primitive_float_acos_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let acos = primitive_float_acos_rational::<T>(&x);
+        assert_eq!(acos.is_nan(), x < -1i32 || x > 1u32);
+        assert!(acos.is_nan() || acos >= T::ZERO);
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_acos_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_acos(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_acos_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_acos_rational_properties_helper);
+}
@@ -0,0 +1,2185 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Acosh, AcoshAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::acosh::{
+    primitive_float_acosh, primitive_float_acosh_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::acosh::{
+    rug_acosh, rug_acosh_prec, rug_acosh_prec_round, rug_acosh_rational_prec,
+    rug_acosh_rational_prec_round, rug_acosh_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_52, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_41,
+    rational_unsigned_rounding_mode_triple_gen_var_13,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_acosh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let acosh = x.clone().acosh();
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let acosh_alt = (&x).acosh();
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+
+        let mut acosh_alt = x.clone();
+        acosh_alt.acosh_assign();
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&acosh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.0",
+        "0x0.0",
+    );
+    test("1.5", "0x1.8#2", "1.0", "0x1.0#2");
+    test("123.0", "0x7b.0#7", "5.500", "0x5.8#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.8115262724608527",
+        "0x1.cfc02f90106c0#50",
+    );
+    test("1.3e30", "0x1.0E+25#1", "64.0", "0x4.0E+1#1");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN");
+    test("-2.0", "-0x2.0#2", "NaN", "NaN");
+}
+
+#[test]
+fn test_acosh_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_prec(prec);
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_ref(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_prec_assign(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acosh, rug_o) = rug_acosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 100, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 10, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 100, "NaN", "NaN", Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("1.5", "0x1.8#2", 1, "1.0", "0x1.0#1", Greater);
+    test("1.5", "0x1.8#2", 10, "0.96289", "0x0.f68#10", Greater);
+    test(
+        "1.5",
+        "0x1.8#2",
+        100,
+        "0.96242365011920689499551782684852",
+        "0x0.f661657628b04ca5f0210254b#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "5.5078", "0x5.82#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "5.5053150109672669048051005201632",
+        "0x5.815c53164844554fd7eab6b10#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.8125",
+        "0x1.d00#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "1.8115262724608530659013198626248",
+        "0x1.cfc02f90106c17a3fd778845e#100",
+        Greater,
+    );
+    test("1.3e30", "0x1.0E+25#1", 1, "64.0", "0x4.0E+1#1", Less);
+    test("1.3e30", "0x1.0E+25#1", 10, "70.000", "0x46.0#10", Less);
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        "70.007865236554476251140444267256",
+        "0x46.020374c5c6db00c6a6d5daf8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "691.46867507877365056731942878181",
+        "0x2b3.77fb1707c87628decba7f2c#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        1,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        10,
+        "1.7764e-15",
+        "0x8.00E-13#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        100,
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 10, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 100, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 1, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 10, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 100, "NaN", "NaN", Equal);
+}
+
+#[test]
+fn acosh_prec_fail() {
+    assert_panic!(Float::NAN.acosh_prec(0));
+    assert_panic!(Float::NAN.acosh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.acosh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_acosh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_round(rm);
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acosh_alt, o_alt) = x.acosh_round_ref(rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_round_assign(rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Down, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Ceiling, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Down, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Up, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", Down, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", Up, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("1.5", "0x1.8#2", Floor, "0.75", "0x0.c#2", Less);
+    test("1.5", "0x1.8#2", Ceiling, "1.0", "0x1.0#2", Greater);
+    test("1.5", "0x1.8#2", Down, "0.75", "0x0.c#2", Less);
+    test("1.5", "0x1.8#2", Up, "1.0", "0x1.0#2", Greater);
+    test("1.5", "0x1.8#2", Nearest, "1.0", "0x1.0#2", Greater);
+    test("123.0", "0x7b.0#7", Floor, "5.500", "0x5.8#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "5.562", "0x5.9#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "5.500", "0x5.8#7", Less);
+    test("123.0", "0x7b.0#7", Up, "5.562", "0x5.9#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "5.500", "0x5.8#7", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "1.8115262724608527",
+        "0x1.cfc02f90106c0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "1.8115262724608545",
+        "0x1.cfc02f90106c8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "1.8115262724608527",
+        "0x1.cfc02f90106c0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "1.8115262724608545",
+        "0x1.cfc02f90106c8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "1.8115262724608527",
+        "0x1.cfc02f90106c0#50",
+        Less,
+    );
+    test("1.3e30", "0x1.0E+25#1", Floor, "64.0", "0x4.0E+1#1", Less);
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        Ceiling,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test("1.3e30", "0x1.0E+25#1", Down, "64.0", "0x4.0E+1#1", Less);
+    test("1.3e30", "0x1.0E+25#1", Up, "1.3e2", "0x8.0E+1#1", Greater);
+    test("1.3e30", "0x1.0E+25#1", Nearest, "64.0", "0x4.0E+1#1", Less);
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "691.46867507877323",
+        "0x2b3.77fb1707c80#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "691.46867507877323",
+        "0x2b3.77fb1707c80#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Floor,
+        "1.7763568394002504646778106689439e-15",
+        "0x7.ffffffffffffffffffffffff8E-13#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Ceiling,
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Down,
+        "1.7763568394002504646778106689439e-15",
+        "0x7.ffffffffffffffffffffffff8E-13#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Up,
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Nearest,
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", Floor, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", Ceiling, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", Down, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", Up, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", Nearest, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", Exact, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", Floor, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", Ceiling, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", Down, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", Up, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", Nearest, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", Exact, "NaN", "NaN", Equal);
+}
+
+#[test]
+fn acosh_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(X.acosh_round(Exact));
+    assert_panic!(X.acosh_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.acosh_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_acosh_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_prec_round(prec, rm);
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_prec_round_assign(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Down, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Up, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("1.5", "0x1.8#2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.5", "0x1.8#2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.5", "0x1.8#2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.5", "0x1.8#2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.5", "0x1.8#2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.5", "0x1.8#2", 10, Floor, "0.96191", "0x0.f64#10", Less);
+    test(
+        "1.5",
+        "0x1.8#2",
+        10,
+        Ceiling,
+        "0.96289",
+        "0x0.f68#10",
+        Greater,
+    );
+    test("1.5", "0x1.8#2", 10, Down, "0.96191", "0x0.f64#10", Less);
+    test("1.5", "0x1.8#2", 10, Up, "0.96289", "0x0.f68#10", Greater);
+    test(
+        "1.5",
+        "0x1.8#2",
+        10,
+        Nearest,
+        "0.96289",
+        "0x0.f68#10",
+        Greater,
+    );
+    test(
+        "1.5",
+        "0x1.8#2",
+        100,
+        Floor,
+        "0.96242365011920689499551782684852",
+        "0x0.f661657628b04ca5f0210254b#100",
+        Less,
+    );
+    test(
+        "1.5",
+        "0x1.8#2",
+        100,
+        Ceiling,
+        "0.96242365011920689499551782684931",
+        "0x0.f661657628b04ca5f0210254c#100",
+        Greater,
+    );
+    test(
+        "1.5",
+        "0x1.8#2",
+        100,
+        Nearest,
+        "0.96242365011920689499551782684852",
+        "0x0.f661657628b04ca5f0210254b#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "8.0", "0x8.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "8.0", "0x8.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "5.5000", "0x5.80#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "5.5078",
+        "0x5.82#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "5.5000", "0x5.80#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "5.5078", "0x5.82#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "5.5078",
+        "0x5.82#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "5.5053150109672669048051005201569",
+        "0x5.815c53164844554fd7eab6b08#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "5.5053150109672669048051005201632",
+        "0x5.815c53164844554fd7eab6b10#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "5.5053150109672669048051005201632",
+        "0x5.815c53164844554fd7eab6b10#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "1.8105",
+        "0x1.cf8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.8125",
+        "0x1.d00#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "1.8105",
+        "0x1.cf8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.8125",
+        "0x1.d00#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.8125",
+        "0x1.d00#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "1.8115262724608530659013198626232",
+        "0x1.cfc02f90106c17a3fd778845c#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "1.8115262724608530659013198626248",
+        "0x1.cfc02f90106c17a3fd778845e#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "1.8115262724608530659013198626248",
+        "0x1.cfc02f90106c17a3fd778845e#100",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        1,
+        Floor,
+        "64.0",
+        "0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        1,
+        Ceiling,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test("1.3e30", "0x1.0E+25#1", 1, Down, "64.0", "0x4.0E+1#1", Less);
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        1,
+        Up,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        1,
+        Nearest,
+        "64.0",
+        "0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Floor,
+        "70.000",
+        "0x46.0#10",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Ceiling,
+        "70.125",
+        "0x46.2#10",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Down,
+        "70.000",
+        "0x46.0#10",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Up,
+        "70.125",
+        "0x46.2#10",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Nearest,
+        "70.000",
+        "0x46.0#10",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        Floor,
+        "70.007865236554476251140444267256",
+        "0x46.020374c5c6db00c6a6d5daf8#100",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        Ceiling,
+        "70.007865236554476251140444267357",
+        "0x46.020374c5c6db00c6a6d5db00#100",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        Nearest,
+        "70.007865236554476251140444267256",
+        "0x46.020374c5c6db00c6a6d5daf8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "692.00",
+        "0x2b4.0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "692.00",
+        "0x2b4.0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "691.46867507877365056731942878181",
+        "0x2b3.77fb1707c87628decba7f2c#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "691.46867507877365056731942878261",
+        "0x2b3.77fb1707c87628decba7f30#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "691.46867507877365056731942878181",
+        "0x2b3.77fb1707c87628decba7f2c#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        1,
+        Floor,
+        "8.9e-16",
+        "0x4.0E-13#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        1,
+        Ceiling,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        1,
+        Down,
+        "8.9e-16",
+        "0x4.0E-13#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        1,
+        Up,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        1,
+        Nearest,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        10,
+        Floor,
+        "1.7746e-15",
+        "0x7.feE-13#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        10,
+        Ceiling,
+        "1.7764e-15",
+        "0x8.00E-13#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        10,
+        Down,
+        "1.7746e-15",
+        "0x7.feE-13#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        10,
+        Up,
+        "1.7764e-15",
+        "0x8.00E-13#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        10,
+        Nearest,
+        "1.7764e-15",
+        "0x8.00E-13#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        100,
+        Floor,
+        "1.7763568394002504646778106689439e-15",
+        "0x7.ffffffffffffffffffffffff8E-13#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        100,
+        Ceiling,
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        100,
+        Nearest,
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 1, Down, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 1, Up, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 1, Exact, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 10, Floor, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 10, Down, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 10, Up, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 10, Exact, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 100, Floor, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 100, Ceiling, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", 100, Nearest, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 1, Floor, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 1, Down, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 1, Up, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 1, Exact, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 10, Floor, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 10, Down, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 10, Up, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 10, Nearest, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 10, Exact, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 100, Floor, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 100, Ceiling, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#2", 100, Nearest, "NaN", "NaN", Equal);
+}
+
+#[test]
+fn acosh_prec_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).acosh_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).acosh_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.acosh_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.acosh_prec_round(1, Exact));
+    assert_panic!(X.acosh_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.acosh_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_acosh_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (acosh, o) = Float::acosh_rational_prec(x.clone(), prec);
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (acosh, o) = Float::acosh_rational_prec_ref(&x, prec);
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_acosh, rug_o) = rug_acosh_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "NaN", "NaN", Equal);
+    test("0", 10, "NaN", "NaN", Equal);
+    test("0", 100, "NaN", "NaN", Equal);
+    test("1", 1, "0.0", "0x0.0", Equal);
+    test("1", 10, "0.0", "0x0.0", Equal);
+    test("1", 100, "0.0", "0x0.0", Equal);
+    test("-1", 1, "NaN", "NaN", Equal);
+    test("-1", 10, "NaN", "NaN", Equal);
+    test("-1", 100, "NaN", "NaN", Equal);
+    test("1/2", 1, "NaN", "NaN", Equal);
+    test("1/2", 10, "NaN", "NaN", Equal);
+    test("1/2", 100, "NaN", "NaN", Equal);
+    test("3/2", 1, "1.0", "0x1.0#1", Greater);
+    test("3/2", 10, "0.96289", "0x0.f68#10", Greater);
+    test(
+        "3/2",
+        100,
+        "0.96242365011920689499551782684852",
+        "0x0.f661657628b04ca5f0210254b#100",
+        Less,
+    );
+    test("4/3", 1, "1.0", "0x1.0#1", Greater);
+    test("4/3", 10, "0.79492", "0x0.cb8#10", Less);
+    test(
+        "4/3",
+        100,
+        "0.79536546122390563052789093314815",
+        "0x0.cb9d1224531b01542c78186b7#100",
+        Greater,
+    );
+    test("22/7", 1, "2.0", "0x2.0#1", Greater);
+    test("22/7", 10, "1.8125", "0x1.d00#10", Greater);
+    test(
+        "22/7",
+        100,
+        "1.8119507608214135562359728131092",
+        "0x1.cfdc014bb0b6cd086e591b3d6#100",
+        Less,
+    );
+    test("1000000", 1, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 10, "14.516", "0xe.84#10", Greater);
+    test(
+        "1000000",
+        100,
+        "14.508657738523969413525180755811",
+        "0xe.823764bfd1593d6bbd500264#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn acosh_rational_prec_fail() {
+    Float::acosh_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn acosh_rational_prec_ref_fail() {
+    Float::acosh_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_acosh_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (acosh, o) = Float::acosh_rational_prec_round(x.clone(), prec, rm);
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (acosh, o) = Float::acosh_rational_prec_round_ref(&x, prec, rm);
+        assert!(acosh.is_valid());
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "NaN", "NaN", Equal);
+    test("0", 1, Ceiling, "NaN", "NaN", Equal);
+    test("0", 1, Down, "NaN", "NaN", Equal);
+    test("0", 1, Up, "NaN", "NaN", Equal);
+    test("0", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", 1, Exact, "NaN", "NaN", Equal);
+    test("0", 10, Floor, "NaN", "NaN", Equal);
+    test("0", 10, Ceiling, "NaN", "NaN", Equal);
+    test("0", 10, Down, "NaN", "NaN", Equal);
+    test("0", 10, Up, "NaN", "NaN", Equal);
+    test("0", 10, Nearest, "NaN", "NaN", Equal);
+    test("0", 10, Exact, "NaN", "NaN", Equal);
+    test("0", 100, Nearest, "NaN", "NaN", Equal);
+    test("1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1", 1, Down, "0.0", "0x0.0", Equal);
+    test("1", 1, Up, "0.0", "0x0.0", Equal);
+    test("1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1", 10, Down, "0.0", "0x0.0", Equal);
+    test("1", 10, Up, "0.0", "0x0.0", Equal);
+    test("1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 1, Down, "NaN", "NaN", Equal);
+    test("-1", 1, Up, "NaN", "NaN", Equal);
+    test("-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 10, Down, "NaN", "NaN", Equal);
+    test("-1", 10, Up, "NaN", "NaN", Equal);
+    test("-1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1", 100, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 1, Floor, "NaN", "NaN", Equal);
+    test("1/2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("1/2", 1, Down, "NaN", "NaN", Equal);
+    test("1/2", 1, Up, "NaN", "NaN", Equal);
+    test("1/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 1, Exact, "NaN", "NaN", Equal);
+    test("1/2", 10, Floor, "NaN", "NaN", Equal);
+    test("1/2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("1/2", 10, Down, "NaN", "NaN", Equal);
+    test("1/2", 10, Up, "NaN", "NaN", Equal);
+    test("1/2", 10, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 10, Exact, "NaN", "NaN", Equal);
+    test("1/2", 100, Nearest, "NaN", "NaN", Equal);
+    test("3/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("3/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("3/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("3/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("3/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("3/2", 10, Floor, "0.96191", "0x0.f64#10", Less);
+    test("3/2", 10, Ceiling, "0.96289", "0x0.f68#10", Greater);
+    test("3/2", 10, Down, "0.96191", "0x0.f64#10", Less);
+    test("3/2", 10, Up, "0.96289", "0x0.f68#10", Greater);
+    test("3/2", 10, Nearest, "0.96289", "0x0.f68#10", Greater);
+    test(
+        "3/2",
+        100,
+        Nearest,
+        "0.96242365011920689499551782684852",
+        "0x0.f661657628b04ca5f0210254b#100",
+        Less,
+    );
+    test("4/3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("4/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("4/3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("4/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("4/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("4/3", 10, Floor, "0.79492", "0x0.cb8#10", Less);
+    test("4/3", 10, Ceiling, "0.79590", "0x0.cbc#10", Greater);
+    test("4/3", 10, Down, "0.79492", "0x0.cb8#10", Less);
+    test("4/3", 10, Up, "0.79590", "0x0.cbc#10", Greater);
+    test("4/3", 10, Nearest, "0.79492", "0x0.cb8#10", Less);
+    test(
+        "4/3",
+        100,
+        Nearest,
+        "0.79536546122390563052789093314815",
+        "0x0.cb9d1224531b01542c78186b7#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("22/7", 10, Floor, "1.8105", "0x1.cf8#10", Less);
+    test("22/7", 10, Ceiling, "1.8125", "0x1.d00#10", Greater);
+    test("22/7", 10, Down, "1.8105", "0x1.cf8#10", Less);
+    test("22/7", 10, Up, "1.8125", "0x1.d00#10", Greater);
+    test("22/7", 10, Nearest, "1.8125", "0x1.d00#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "1.8119507608214135562359728131092",
+        "0x1.cfdc014bb0b6cd086e591b3d6#100",
+        Less,
+    );
+    test("1000000", 1, Floor, "8.0", "0x8.0#1", Less);
+    test("1000000", 1, Ceiling, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 1, Down, "8.0", "0x8.0#1", Less);
+    test("1000000", 1, Up, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 1, Nearest, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 10, Floor, "14.500", "0xe.80#10", Less);
+    test("1000000", 10, Ceiling, "14.516", "0xe.84#10", Greater);
+    test("1000000", 10, Down, "14.500", "0xe.80#10", Less);
+    test("1000000", 10, Up, "14.516", "0xe.84#10", Greater);
+    test("1000000", 10, Nearest, "14.516", "0xe.84#10", Greater);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "14.508657738523969413525180755811",
+        "0xe.823764bfd1593d6bbd500264#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn acosh_rational_prec_round_fail_1() {
+    Float::acosh_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn acosh_rational_prec_round_fail_2() {
+    Float::acosh_rational_prec_round(Rational::from(3u32), 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn acosh_rational_prec_round_ref_fail() {
+    Float::acosh_rational_prec_round_ref(&Rational::from(3u32), 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::neg_cmp_op_on_partial_ord)]
+fn acosh_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (acosh, o) = x.clone().acosh_prec_round(prec, rm);
+    assert!(acosh.is_valid());
+
+    let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, rm);
+    assert!(acosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acosh_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acosh, rug_o) = rug_acosh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(acosh.is_nan(), !(x >= 1u32));
+    assert!(acosh.is_nan() || acosh >= 0u32);
+
+    if acosh.is_normal() {
+        assert_eq!(acosh.get_prec(), Some(prec));
+    }
+    if x.is_normal() {
+        let (acosh_alt, o_alt) =
+            Float::acosh_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.acosh_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(acosh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.acosh_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn acosh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_41().test_properties(|(x, prec, rm)| {
+        acosh_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (acosh, o) = Float::NAN.acosh_prec_round(prec, rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::INFINITY.acosh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::NEGATIVE_INFINITY.acosh_prec_round(prec, rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::ZERO.acosh_prec_round(prec, rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::NEGATIVE_ZERO.acosh_prec_round(prec, rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::ONE.acosh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acosh_prec_properties_helper(x: Float, prec: u64) {
+    let (acosh, o) = x.clone().acosh_prec(prec);
+    assert!(acosh.is_valid());
+
+    let (acosh_alt, o_alt) = x.acosh_prec_ref(prec);
+    assert!(acosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acosh_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let (rug_acosh, rug_o) = rug_acosh_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acosh)),
+        ComparableFloatRef(&acosh)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(acosh.is_nan() || acosh >= 0u32);
+
+    if acosh.is_normal() {
+        assert_eq!(acosh.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn acosh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        acosh_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (acosh, o) = Float::NAN.acosh_prec(prec);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::INFINITY.acosh_prec(prec);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::NEGATIVE_INFINITY.acosh_prec(prec);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::ZERO.acosh_prec(prec);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::NEGATIVE_ZERO.acosh_prec(prec);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::ONE.acosh_prec(prec);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acosh_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (acosh, o) = x.clone().acosh_round(rm);
+    assert!(acosh.is_valid());
+
+    let (acosh_alt, o_alt) = x.acosh_round_ref(rm);
+    assert!(acosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acosh_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let (acosh_alt, o_alt) = x.acosh_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    assert!(acosh.is_nan() || acosh >= 0u32);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acosh, rug_o) = rug_acosh_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.acosh_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(acosh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.acosh_round_ref(Exact));
+    }
+}
+
+#[test]
+fn acosh_round_properties() {
+    float_rounding_mode_pair_gen_var_52().test_properties(|(x, rm)| {
+        acosh_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (acosh, o) = Float::NAN.acosh_round(rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::INFINITY.acosh_round(rm);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::NEGATIVE_INFINITY.acosh_round(rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::ZERO.acosh_round(rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::NEGATIVE_ZERO.acosh_round(rm);
+        assert!(acosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (acosh, o) = Float::ONE.acosh_round(rm);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::neg_cmp_op_on_partial_ord)]
+fn acosh_properties_helper(x: Float) {
+    let acosh = x.clone().acosh();
+    assert!(acosh.is_valid());
+
+    let acosh_alt = (&x).acosh();
+    assert!(acosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+
+    let mut x_alt = x.clone();
+    x_alt.acosh_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+
+    let acosh_alt = x.acosh_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    let acosh_alt = x.acosh_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+
+    assert_eq!(acosh.is_nan(), !(x >= 1u32));
+    assert!(acosh.is_nan() || acosh >= 0u32);
+
+    let rug_acosh = rug_acosh(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acosh)),
+        ComparableFloatRef(&acosh),
+    );
+}
+
+#[test]
+fn acosh_properties() {
+    float_gen().test_properties(|x| {
+        acosh_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acosh_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (acosh, o) = Float::acosh_rational_prec_round(x.clone(), prec, rm);
+    assert!(acosh.is_valid());
+
+    let (acosh_alt, o_alt) = Float::acosh_rational_prec_round_ref(&x, prec, rm);
+    assert!(acosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acosh, rug_o) = rug_acosh_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(acosh.is_nan(), x < 1u32);
+    assert!(acosh.is_nan() || acosh >= 0u32);
+
+    if acosh.is_normal() {
+        assert_eq!(acosh.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::acosh_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(acosh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::acosh_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn acosh_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_13().test_properties(|(x, prec, rm)| {
+        acosh_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (acosh, o) = Float::acosh_rational_prec_round(Rational::ONE, prec, rm);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acosh_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (acosh, o) = Float::acosh_rational_prec(x.clone(), prec);
+    assert!(acosh.is_valid());
+
+    let (acosh_alt, o_alt) = Float::acosh_rational_prec_ref(&x, prec);
+    assert!(acosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let (acosh_alt, o_alt) = Float::acosh_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+    assert_eq!(o_alt, o);
+
+    let (rug_acosh, rug_o) = rug_acosh_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acosh)),
+        ComparableFloatRef(&acosh)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(acosh.is_nan() || acosh >= 0u32);
+
+    if acosh.is_normal() {
+        assert_eq!(acosh.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn acosh_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        acosh_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (acosh, o) = Float::acosh_rational_prec(Rational::ONE, prec);
+        assert_eq!(ComparableFloat(acosh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_acosh() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_acosh(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::INFINITY);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, f32::NAN);
+    test::<f32>(-0.0, f32::NAN);
+    test::<f32>(1.0, 0.0);
+    test::<f32>(-1.0, f32::NAN);
+    test::<f32>(0.5, f32::NAN);
+    test::<f32>(-0.5, f32::NAN);
+    test::<f32>(2.0, 1.316958);
+    test::<f32>(10.0, 2.993223);
+    test::<f32>(0.001, f32::NAN);
+    test::<f32>(10000000000.0, 23.718998);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::INFINITY);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, f64::NAN);
+    test::<f64>(-0.0, f64::NAN);
+    test::<f64>(1.0, 0.0);
+    test::<f64>(-1.0, f64::NAN);
+    test::<f64>(0.5, f64::NAN);
+    test::<f64>(-0.5, f64::NAN);
+    test::<f64>(2.0, 1.3169578969248168);
+    test::<f64>(10.0, 2.993222846126381);
+    test::<f64>(0.001, f64::NAN);
+    test::<f64>(10000000000.0, 23.7189981105004);
+    test::<f64>(1.0e300, 691.4686750787737);
+}
+
+#[allow(clippy::neg_cmp_op_on_partial_ord, clippy::type_repetition_in_bounds)]
+fn primitive_float_acosh_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let acosh = primitive_float_acosh(x);
+        assert_eq!(acosh.is_nan(), !(x >= T::ONE));
+        assert!(acosh.is_nan() || acosh >= T::ZERO);
+    });
+}
+
+#[test]
+fn primitive_float_acosh_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_acosh_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_acosh_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_acosh_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", f32::NAN);
+    test::<f32>("1", 0.0);
+    test::<f32>("-1", f32::NAN);
+    test::<f32>("1/2", f32::NAN);
+    test::<f32>("-1/2", f32::NAN);
+    test::<f32>("1/3", f32::NAN);
+    test::<f32>("22/7", 1.8119508);
+    test::<f32>("3/2", 0.9624236);
+    test::<f32>("1000000", 14.508657);
+
+    test::<f64>("0", f64::NAN);
+    test::<f64>("1", 0.0);
+    test::<f64>("-1", f64::NAN);
+    test::<f64>("1/2", f64::NAN);
+    test::<f64>("-1/2", f64::NAN);
+    test::<f64>("1/3", f64::NAN);
+    test::<f64>("22/7", 1.8119507608214136);
+    test::<f64>("3/2", 0.9624236501192069);
+    test::<f64>("1000000", 14.508657738523969);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_acosh_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let acosh = primitive_float_acosh_rational::<T>(&x);
+        assert_eq!(acosh.is_nan(), x < 1u32);
+        assert!(acosh.is_nan() || acosh >= T::ZERO);
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_acosh_rational::<T>(&Rational::exact_from(
+                    x
+                ))),
+                NiceFloat(primitive_float_acosh(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_acosh_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_acosh_rational_properties_helper);
+}
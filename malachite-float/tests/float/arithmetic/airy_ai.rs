@@ -0,0 +1,3187 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{AiryAi, AiryAiAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::airy_ai::{
+    primitive_float_airy_ai, primitive_float_airy_ai_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::airy_ai::{
+    rug_airy_ai, rug_airy_ai_prec, rug_airy_ai_prec_round, rug_airy_ai_rational_prec,
+    rug_airy_ai_rational_prec_round, rug_airy_ai_round,
+};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen_var_54, float_unsigned_rounding_mode_triple_gen_var_43,
+    rational_unsigned_rounding_mode_triple_gen_var_15,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::rational_gen;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_airy_ai() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ai = x.clone().airy_ai();
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+
+        let ai_alt = (&x).airy_ai();
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+
+        let mut ai_alt = x.clone();
+        ai_alt.airy_ai_assign();
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+
+        // MPFR's Ai runs out of memory for large arguments, so rug is only consulted for moderate
+        // ones
+        if x.get_exponent().is_none_or(|e| e <= 10) {
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_airy_ai(&rug::Float::exact_from(&x)))),
+                ComparableFloatRef(&ai)
+            );
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "0.25", "0x0.4#1");
+    test("-0.0", "-0x0.0", "0.25", "0x0.4#1");
+    test("1.0", "0x1.0#1", "0.12", "0x0.2#1");
+    test("-1.0", "-0x1.0#1", "0.50", "0x0.8#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+    );
+    test("3.0", "0x3.0#2", "0.0059", "0x0.018#2");
+    test("5.0", "0x5.0#3", "0.00011", "0x0.0007#3");
+    test("0.50", "0x0.8#1", "0.25", "0x0.4#1");
+    test("-0.50", "-0x0.8#1", "0.50", "0x0.8#1");
+    test("123.0", "0x7b.0#7", "9.285e-397", "0x1.54E-329#7");
+    test("-123.50", "-0x7b.8#10", "-0.16919", "-0x0.2b5#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0050893534795944939",
+        "0x0.014d892ec0ad280#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.010850240156858671",
+        "0x0.02c714d2a0a0d1a#53",
+    );
+    test("1.00000000e10", "0x2.540be4E+8#24", "0.0", "0x0.0");
+    test("27.0", "0x1b.0#5", "3.05e-42", "0x4.4E-35#5");
+    test("-6.0", "-0x6.0#2", "-0.38", "-0x0.6#2");
+    test("7.9e-31", "0x1.0E-25#1", "0.25", "0x0.4#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "0.43884632109838542727831385340851",
+        "0x0.70583b8516651a5ad3aaa9e998#100",
+    );
+}
+
+#[test]
+fn test_airy_ai_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ai, o) = x.clone().airy_ai_prec(prec);
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ai_alt, o_alt) = x.airy_ai_prec_ref(prec);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ai_alt = x.clone();
+        let o_alt = ai_alt.airy_ai_prec_assign(prec);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+        assert_eq!(o_alt, o_out);
+
+        if x.get_exponent().is_none_or(|e| e <= 10) {
+            let (rug_ai, rug_o) = rug_airy_ai_prec(&rug::Float::exact_from(&x), prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ai)),
+                ComparableFloatRef(&ai)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "0.25", "0x0.4#1", Less);
+    test("0.0", "0x0.0", 10, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "0.35502805388781723926006318600427",
+        "0x0.5ae31e589c56e17a96d7bb04e8#100",
+        Greater,
+    );
+    test("-0.0", "-0x0.0", 1, "0.25", "0x0.4#1", Less);
+    test("-0.0", "-0x0.0", 10, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "0.35502805388781723926006318600427",
+        "0x0.5ae31e589c56e17a96d7bb04e8#100",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 1, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.13525", "0x0.22a#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "0.53516", "0x0.890#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.53556088329235211879951656563882",
+        "0x0.891a849ec1ed2b2fe683e2f3e#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.13525",
+        "0x0.22a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, "0.0078", "0x0.02#1", Greater);
+    test("3.0", "0x3.0#2", 10, "0.0065918", "0x0.01b00#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.0065911393574607191442574484079638",
+        "0x0.01aff4f7fbd1f1aec979e455e14#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, "0.00012", "0x0.0008#1", Greater);
+    test("5.0", "0x5.0#3", 10, "0.00010836", "0x0.00071a#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "0.00010834442813607441734986502503343",
+        "0x0.000719b7c68a54b528e8ebe0c67d8#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 10, "0.23169", "0x0.3b5#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "0.23169360648083348976912525450988",
+        "0x0.3b5045ae870880aa64d4117058#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, "0.50", "0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", 10, "0.47559", "0x0.79c#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "0.47572809161053958879864377828113",
+        "0x0.79c950f34179746c2e9db19810#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "7.0e-397", "0x1.0E-329#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "9.3392e-397",
+        "0x1.560E-329#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "9.3339086601045108881884852874249e-397",
+        "0x1.55cdf53df6ac97d7897f40002E-329#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "-0.12", "-0x0.2#1", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        "-0.16919",
+        "-0x0.2b5#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "-0.16922537416657123068363717371096",
+        "-0x0.2b525aa7b2e3af460dc5830ab4#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.0050888",
+        "0x0.014d8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.0050893534795944933399599450097518",
+        "0x0.014d892ec0ad27f572bc4a60010#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.0078",
+        "0x0.02#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.010849",
+        "0x0.02c7#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.010850240156858670861752030471080",
+        "0x0.02c714d2a0a0d1a27478dd1c354#100",
+        Greater,
+    );
+    test("1.00000000e10", "0x2.540be4E+8#24", 1, "0.0", "0x0.0", Less);
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", 1, "2.9e-42", "0x4.0E-35#1", Less);
+    test("27.0", "0x1b.0#5", 10, "2.9651e-42", "0x4.22E-35#10", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        "2.9672049228631665179692569546071e-42",
+        "0x4.22bbeda6231dcc897f69862d8E-35#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-6.0", "-0x6.0#2", 10, "-0.32910", "-0x0.544#10", Greater);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        "-0.32914517362982310523144858252904",
+        "-0x0.5442dbac605748b05077575cb0#100",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "0.25", "0x0.4#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 10, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.35502805388781723926006318600387",
+        "0x0.5ae31e589c56e17a96d7bb04e0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.27051",
+        "0x0.454#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "0.43896",
+        "0x0.706#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "0.43884632109838542727831385340851",
+        "0x0.70583b8516651a5ad3aaa9e998#100",
+        Greater,
+    );
+}
+
+#[test]
+fn airy_ai_prec_fail() {
+    assert_panic!(Float::NAN.airy_ai_prec(0));
+    assert_panic!(Float::NAN.airy_ai_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.airy_ai_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_airy_ai_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ai, o) = x.clone().airy_ai_round(rm);
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ai_alt, o_alt) = x.airy_ai_round_ref(rm);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ai_alt = x.clone();
+        let o_alt = ai_alt.airy_ai_round_assign(rm);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+            && x.get_exponent().is_none_or(|e| e <= 10)
+        {
+            let (rug_ai, rug_o) = rug_airy_ai_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ai)),
+                ComparableFloatRef(&ai),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "0.25", "0x0.4#1", Less);
+    test("0.0", "0x0.0", Ceiling, "0.50", "0x0.8#1", Greater);
+    test("0.0", "0x0.0", Down, "0.25", "0x0.4#1", Less);
+    test("0.0", "0x0.0", Up, "0.50", "0x0.8#1", Greater);
+    test("0.0", "0x0.0", Nearest, "0.25", "0x0.4#1", Less);
+    test("-0.0", "-0x0.0", Floor, "0.25", "0x0.4#1", Less);
+    test("-0.0", "-0x0.0", Ceiling, "0.50", "0x0.8#1", Greater);
+    test("-0.0", "-0x0.0", Down, "0.25", "0x0.4#1", Less);
+    test("-0.0", "-0x0.0", Up, "0.50", "0x0.8#1", Greater);
+    test("-0.0", "-0x0.0", Nearest, "0.25", "0x0.4#1", Less);
+    test("1.0", "0x1.0#1", Floor, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", Up, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.12", "0x0.2#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "0.50", "0x0.8#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.13529241631288141552414742351557",
+        "0x0.22a2861775ecac1dffcfecf52c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.13529241631288141552414742351557",
+        "0x0.22a2861775ecac1dffcfecf52c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.0059", "0x0.018#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "0.0078", "0x0.02#2", Greater);
+    test("3.0", "0x3.0#2", Down, "0.0059", "0x0.018#2", Less);
+    test("3.0", "0x3.0#2", Up, "0.0078", "0x0.02#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "0.0059", "0x0.018#2", Less);
+    test("5.0", "0x5.0#3", Floor, "0.00011", "0x0.0007#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "0.00012", "0x0.0008#3", Greater);
+    test("5.0", "0x5.0#3", Down, "0.00011", "0x0.0007#3", Less);
+    test("5.0", "0x5.0#3", Up, "0.00012", "0x0.0008#3", Greater);
+    test("5.0", "0x5.0#3", Nearest, "0.00011", "0x0.0007#3", Less);
+    test("0.50", "0x0.8#1", Floor, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", Down, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", Up, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "0.25", "0x0.4#1", Greater);
+    test("-0.50", "-0x0.8#1", Floor, "0.25", "0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "0.50", "0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "0.25", "0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", Up, "0.50", "0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", Nearest, "0.50", "0x0.8#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "9.285e-397",
+        "0x1.54E-329#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "9.394e-397",
+        "0x1.58E-329#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "9.285e-397",
+        "0x1.54E-329#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "9.394e-397",
+        "0x1.58E-329#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "9.285e-397",
+        "0x1.54E-329#7",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Floor,
+        "-0.16943",
+        "-0x0.2b6#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "-0.16919",
+        "-0x0.2b5#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Down,
+        "-0.16919",
+        "-0x0.2b5#10",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", Up, "-0.16943", "-0x0.2b6#10", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "-0.16919",
+        "-0x0.2b5#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.0050893534795944870",
+        "0x0.014d892ec0ad278#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.0050893534795944939",
+        "0x0.014d892ec0ad280#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.0050893534795944870",
+        "0x0.014d892ec0ad278#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.0050893534795944939",
+        "0x0.014d892ec0ad280#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.0050893534795944939",
+        "0x0.014d892ec0ad280#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.010850240156858671",
+        "0x0.02c714d2a0a0d1a#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.010850240156858672",
+        "0x0.02c714d2a0a0d1c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.010850240156858671",
+        "0x0.02c714d2a0a0d1a#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.010850240156858672",
+        "0x0.02c714d2a0a0d1c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.010850240156858671",
+        "0x0.02c714d2a0a0d1a#53",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "2.38256490e-323228497",
+        "0x1.000000E-268435456#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "2.38256490e-323228497",
+        "0x1.000000E-268435456#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", Floor, "2.87e-42", "0x4.0E-35#5", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        Ceiling,
+        "3.05e-42",
+        "0x4.4E-35#5",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", Down, "2.87e-42", "0x4.0E-35#5", Less);
+    test("27.0", "0x1b.0#5", Up, "3.05e-42", "0x4.4E-35#5", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        Nearest,
+        "3.05e-42",
+        "0x4.4E-35#5",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", Floor, "-0.38", "-0x0.6#2", Less);
+    test("-6.0", "-0x6.0#2", Ceiling, "-0.25", "-0x0.4#2", Greater);
+    test("-6.0", "-0x6.0#2", Down, "-0.25", "-0x0.4#2", Greater);
+    test("-6.0", "-0x6.0#2", Up, "-0.38", "-0x0.6#2", Less);
+    test("-6.0", "-0x6.0#2", Nearest, "-0.38", "-0x0.6#2", Less);
+    test("7.9e-31", "0x1.0E-25#1", Floor, "0.25", "0x0.4#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Down, "0.25", "0x0.4#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Up, "0.50", "0x0.8#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Nearest, "0.25", "0x0.4#1", Less);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.27068264745934528288734631809603",
+        "0x0.454b753e6ebdec077d9802eac8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.27068264745934528288734631809603",
+        "0x0.454b753e6ebdec077d9802eac8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "0.43884632109838542727831385340811",
+        "0x0.70583b8516651a5ad3aaa9e990#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.43884632109838542727831385340851",
+        "0x0.70583b8516651a5ad3aaa9e998#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "0.43884632109838542727831385340811",
+        "0x0.70583b8516651a5ad3aaa9e990#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "0.43884632109838542727831385340851",
+        "0x0.70583b8516651a5ad3aaa9e998#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.43884632109838542727831385340851",
+        "0x0.70583b8516651a5ad3aaa9e998#100",
+        Greater,
+    );
+}
+
+#[test]
+fn airy_ai_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.airy_ai_round(Exact));
+    assert_panic!(X.airy_ai_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.airy_ai_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_airy_ai_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ai, o) = x.clone().airy_ai_prec_round(prec, rm);
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ai_alt, o_alt) = x.airy_ai_prec_round_ref(prec, rm);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ai_alt = x.clone();
+        let o_alt = ai_alt.airy_ai_prec_round_assign(prec, rm);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai), ComparableFloatRef(&ai_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+            && x.get_exponent().is_none_or(|e| e <= 10)
+        {
+            let (rug_ai, rug_o) = rug_airy_ai_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ai)),
+                ComparableFloatRef(&ai)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("0.0", "0x0.0", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("0.0", "0x0.0", 1, Down, "0.25", "0x0.4#1", Less);
+    test("0.0", "0x0.0", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("0.0", "0x0.0", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("0.0", "0x0.0", 10, Floor, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Ceiling,
+        "0.35547",
+        "0x0.5b0#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", 10, Down, "0.35498", "0x0.5ae#10", Less);
+    test("0.0", "0x0.0", 10, Up, "0.35547", "0x0.5b0#10", Greater);
+    test("0.0", "0x0.0", 10, Nearest, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Nearest,
+        "0.35502805388781723926006318600427",
+        "0x0.5ae31e589c56e17a96d7bb04e8#100",
+        Greater,
+    );
+    test("-0.0", "-0x0.0", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("-0.0", "-0x0.0", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("-0.0", "-0x0.0", 1, Down, "0.25", "0x0.4#1", Less);
+    test("-0.0", "-0x0.0", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("-0.0", "-0x0.0", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("-0.0", "-0x0.0", 10, Floor, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Ceiling,
+        "0.35547",
+        "0x0.5b0#10",
+        Greater,
+    );
+    test("-0.0", "-0x0.0", 10, Down, "0.35498", "0x0.5ae#10", Less);
+    test("-0.0", "-0x0.0", 10, Up, "0.35547", "0x0.5b0#10", Greater);
+    test("-0.0", "-0x0.0", 10, Nearest, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Nearest,
+        "0.35502805388781723926006318600427",
+        "0x0.5ae31e589c56e17a96d7bb04e8#100",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.13525", "0x0.22a#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.13550",
+        "0x0.22b#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.13525", "0x0.22a#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.13550", "0x0.22b#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.13525", "0x0.22a#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "0.53516", "0x0.890#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0.53613",
+        "0x0.894#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "0.53516", "0x0.890#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "0.53613", "0x0.894#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "0.53516",
+        "0x0.890#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "0.53556088329235211879951656563882",
+        "0x0.891a849ec1ed2b2fe683e2f3e#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.13525",
+        "0x0.22a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.13550",
+        "0x0.22b#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.13525",
+        "0x0.22a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.13550",
+        "0x0.22b#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.13525",
+        "0x0.22a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.0039", "0x0.01#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "0.0078", "0x0.02#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "0.0039", "0x0.01#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "0.0078", "0x0.02#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "0.0078", "0x0.02#1", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "0.0065842",
+        "0x0.01af8#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.0065918",
+        "0x0.01b00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Down,
+        "0.0065842",
+        "0x0.01af8#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Up,
+        "0.0065918",
+        "0x0.01b00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.0065918",
+        "0x0.01b00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.0065911393574607191442574484079638",
+        "0x0.01aff4f7fbd1f1aec979e455e14#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "0.000061", "0x0.0004#1", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        1,
+        Ceiling,
+        "0.00012",
+        "0x0.0008#1",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Down, "0.000061", "0x0.0004#1", Less);
+    test("5.0", "0x5.0#3", 1, Up, "0.00012", "0x0.0008#1", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        1,
+        Nearest,
+        "0.00012",
+        "0x0.0008#1",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Floor,
+        "0.00010824",
+        "0x0.000718#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "0.00010836",
+        "0x0.00071a#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Down,
+        "0.00010824",
+        "0x0.000718#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Up,
+        "0.00010836",
+        "0x0.00071a#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "0.00010836",
+        "0x0.00071a#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "0.00010834442813607441734986502503343",
+        "0x0.000719b7c68a54b528e8ebe0c67d8#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 10, Floor, "0.23169", "0x0.3b5#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.23193",
+        "0x0.3b6#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "0.23169", "0x0.3b5#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "0.23193", "0x0.3b6#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "0.23169",
+        "0x0.3b5#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.23169360648083348976912525450988",
+        "0x0.3b5045ae870880aa64d4117058#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Down, "0.25", "0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "0.47559",
+        "0x0.79c#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "0.47607",
+        "0x0.79e#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Down, "0.47559", "0x0.79c#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Up,
+        "0.47607",
+        "0x0.79e#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "0.47559",
+        "0x0.79c#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "0.47572809161053958879864377828113",
+        "0x0.79c950f34179746c2e9db19810#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "7.0e-397",
+        "0x1.0E-329#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.4e-396",
+        "0x2.0E-329#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "7.0e-397",
+        "0x1.0E-329#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "1.4e-396",
+        "0x2.0E-329#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "7.0e-397",
+        "0x1.0E-329#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "9.3256e-397",
+        "0x1.558E-329#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "9.3392e-397",
+        "0x1.560E-329#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "9.3256e-397",
+        "0x1.558E-329#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "9.3392e-397",
+        "0x1.560E-329#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "9.3392e-397",
+        "0x1.560E-329#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "9.3339086601045108881884852874249e-397",
+        "0x1.55cdf53df6ac97d7897f40002E-329#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Down,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "-0.16943",
+        "-0x0.2b6#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "-0.16919",
+        "-0x0.2b5#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "-0.16919",
+        "-0x0.2b5#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "-0.16943",
+        "-0x0.2b6#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "-0.16919",
+        "-0x0.2b5#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "-0.16922537416657123068363717371096",
+        "-0x0.2b525aa7b2e3af460dc5830ab4#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.0078",
+        "0x0.02#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.0078",
+        "0x0.02#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.0050888",
+        "0x0.014d8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.0050964",
+        "0x0.014e0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.0050888",
+        "0x0.014d8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.0050964",
+        "0x0.014e0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.0050888",
+        "0x0.014d8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.0050893534795944933399599450097518",
+        "0x0.014d892ec0ad27f572bc4a60010#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.0078",
+        "0x0.02#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.016",
+        "0x0.04#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.0078",
+        "0x0.02#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.016",
+        "0x0.04#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.0078",
+        "0x0.02#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.010849",
+        "0x0.02c7#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.010864",
+        "0x0.02c8#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.010849",
+        "0x0.02c7#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.010864",
+        "0x0.02c8#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.010849",
+        "0x0.02c7#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.010850240156858670861752030471080",
+        "0x0.02c714d2a0a0d1a27478dd1c354#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", 1, Floor, "2.9e-42", "0x4.0E-35#1", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        1,
+        Ceiling,
+        "5.7e-42",
+        "0x8.0E-35#1",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, Down, "2.9e-42", "0x4.0E-35#1", Less);
+    test("27.0", "0x1b.0#5", 1, Up, "5.7e-42", "0x8.0E-35#1", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        1,
+        Nearest,
+        "2.9e-42",
+        "0x4.0E-35#1",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Floor,
+        "2.9651e-42",
+        "0x4.22E-35#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Ceiling,
+        "2.9708e-42",
+        "0x4.24E-35#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Down,
+        "2.9651e-42",
+        "0x4.22E-35#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Up,
+        "2.9708e-42",
+        "0x4.24E-35#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Nearest,
+        "2.9651e-42",
+        "0x4.22E-35#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Nearest,
+        "2.9672049228631665179692569546071e-42",
+        "0x4.22bbeda6231dcc897f69862d8E-35#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Floor,
+        "-0.32959",
+        "-0x0.546#10",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Ceiling,
+        "-0.32910",
+        "-0x0.544#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Down,
+        "-0.32910",
+        "-0x0.544#10",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 10, Up, "-0.32959", "-0x0.546#10", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Nearest,
+        "-0.32910",
+        "-0x0.544#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Nearest,
+        "-0.32914517362982310523144858252904",
+        "-0x0.5442dbac605748b05077575cb0#100",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "0.25", "0x0.4#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Down, "0.25", "0x0.4#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "0.50", "0x0.8#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "0.35498",
+        "0x0.5ae#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "0.35547",
+        "0x0.5b0#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "0.35498",
+        "0x0.5ae#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "0.35547",
+        "0x0.5b0#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "0.35498",
+        "0x0.5ae#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "0.35502805388781723926006318600387",
+        "0x0.5ae31e589c56e17a96d7bb04e0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.27051",
+        "0x0.454#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.27100",
+        "0x0.456#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.27051",
+        "0x0.454#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.27100",
+        "0x0.456#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.27051",
+        "0x0.454#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.43848",
+        "0x0.704#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.43896",
+        "0x0.706#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.43848",
+        "0x0.704#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.43896",
+        "0x0.706#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.43896",
+        "0x0.706#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.43884632109838542727831385340851",
+        "0x0.70583b8516651a5ad3aaa9e998#100",
+        Greater,
+    );
+}
+
+#[test]
+fn airy_ai_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).airy_ai_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).airy_ai_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.airy_ai_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.airy_ai_prec_round(1, Exact));
+    assert_panic!(X.airy_ai_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.airy_ai_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_airy_ai_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (ai, o) = Float::airy_ai_rational_prec(x.clone(), prec);
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+        assert_eq!(o, out_o);
+
+        let (ai, o) = Float::airy_ai_rational_prec_ref(&x, prec);
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+        assert_eq!(o, out_o);
+
+        if x.lt_abs(&1024u32) {
+            let (rug_ai, rug_o) = rug_airy_ai_rational_prec(&x, prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ai)),
+                ComparableFloatRef(&ai)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, "0.25", "0x0.4#1", Less);
+    test("0", 10, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "0",
+        100,
+        "0.35502805388781723926006318600427",
+        "0x0.5ae31e589c56e17a96d7bb04e8#100",
+        Greater,
+    );
+    test("1", 1, "0.12", "0x0.2#1", Less);
+    test("1", 10, "0.13525", "0x0.22a#10", Less);
+    test(
+        "1",
+        100,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test("-1", 1, "0.50", "0x0.8#1", Less);
+    test("-1", 10, "0.53516", "0x0.890#10", Less);
+    test(
+        "-1",
+        100,
+        "0.53556088329235211879951656563882",
+        "0x0.891a849ec1ed2b2fe683e2f3e#100",
+        Less,
+    );
+    test("1/2", 1, "0.25", "0x0.4#1", Greater);
+    test("1/2", 10, "0.23169", "0x0.3b5#10", Less);
+    test(
+        "1/2",
+        100,
+        "0.23169360648083348976912525450988",
+        "0x0.3b5045ae870880aa64d4117058#100",
+        Less,
+    );
+    test("-1/2", 1, "0.50", "0x0.8#1", Greater);
+    test("-1/2", 10, "0.47559", "0x0.79c#10", Less);
+    test(
+        "-1/2",
+        100,
+        "0.47572809161053958879864377828113",
+        "0x0.79c950f34179746c2e9db19810#100",
+        Less,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.27051", "0x0.454#10", Less);
+    test(
+        "1/3",
+        100,
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+        Greater,
+    );
+    test("22/7", 1, "0.0039", "0x0.01#1", Less);
+    test("22/7", 10, "0.0050812", "0x0.014d0#10", Greater);
+    test(
+        "22/7",
+        100,
+        "0.0050774938728133775335694842213137",
+        "0x0.014cc236211ea4dbd5cda3eb404#100",
+        Less,
+    );
+    test("3/2", 1, "0.062", "0x0.1#1", Less);
+    test("3/2", 10, "0.071777", "0x0.1260#10", Greater);
+    test(
+        "3/2",
+        100,
+        "0.071749497008105409673555416489708",
+        "0x0.125e2ccf277dc0a66778939a52#100",
+        Greater,
+    );
+    test("-7/2", 1, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 10, "-0.37549", "-0x0.602#10", Greater);
+    test(
+        "-7/2",
+        100,
+        "-0.37553382314043191193439695158033",
+        "-0x0.6022fc10ee14ae8135356f2040#100",
+        Less,
+    );
+    test("1000000", 1, "1.9e-289529657", "0x8.0E-240449176#1", Less);
+    test(
+        "1000000",
+        10,
+        "2.2281e-289529657",
+        "0x9.9cE-240449176#10",
+        Less,
+    );
+    test(
+        "1000000",
+        100,
+        "2.2296011660898244345051714077433e-289529657",
+        "0x9.9d9b270f56f05376be7b069fE-240449176#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn airy_ai_rational_prec_fail() {
+    Float::airy_ai_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn airy_ai_rational_prec_ref_fail() {
+    Float::airy_ai_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_airy_ai_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (ai, o) = Float::airy_ai_rational_prec_round(x.clone(), prec, rm);
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+        assert_eq!(o, out_o);
+
+        let (ai, o) = Float::airy_ai_rational_prec_round_ref(&x, prec, rm);
+        assert!(ai.is_valid());
+        assert_eq!(ai.to_string(), out);
+        assert_eq!(to_hex_string(&ai), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+            && x.lt_abs(&1024u32)
+        {
+            let (rug_ai, rug_o) = rug_airy_ai_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ai)),
+                ComparableFloatRef(&ai)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("0", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("0", 1, Down, "0.25", "0x0.4#1", Less);
+    test("0", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("0", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("0", 10, Floor, "0.35498", "0x0.5ae#10", Less);
+    test("0", 10, Ceiling, "0.35547", "0x0.5b0#10", Greater);
+    test("0", 10, Down, "0.35498", "0x0.5ae#10", Less);
+    test("0", 10, Up, "0.35547", "0x0.5b0#10", Greater);
+    test("0", 10, Nearest, "0.35498", "0x0.5ae#10", Less);
+    test(
+        "0",
+        100,
+        Nearest,
+        "0.35502805388781723926006318600427",
+        "0x0.5ae31e589c56e17a96d7bb04e8#100",
+        Greater,
+    );
+    test("1", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("1", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1", 1, Down, "0.12", "0x0.2#1", Less);
+    test("1", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("1", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("1", 10, Floor, "0.13525", "0x0.22a#10", Less);
+    test("1", 10, Ceiling, "0.13550", "0x0.22b#10", Greater);
+    test("1", 10, Down, "0.13525", "0x0.22a#10", Less);
+    test("1", 10, Up, "0.13550", "0x0.22b#10", Greater);
+    test("1", 10, Nearest, "0.13525", "0x0.22a#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.13529241631288141552414742351538",
+        "0x0.22a2861775ecac1dffcfecf528#100",
+        Less,
+    );
+    test("-1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("-1", 10, Floor, "0.53516", "0x0.890#10", Less);
+    test("-1", 10, Ceiling, "0.53613", "0x0.894#10", Greater);
+    test("-1", 10, Down, "0.53516", "0x0.890#10", Less);
+    test("-1", 10, Up, "0.53613", "0x0.894#10", Greater);
+    test("-1", 10, Nearest, "0.53516", "0x0.890#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "0.53556088329235211879951656563882",
+        "0x0.891a849ec1ed2b2fe683e2f3e#100",
+        Less,
+    );
+    test("1/2", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("1/2", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1/2", 1, Down, "0.12", "0x0.2#1", Less);
+    test("1/2", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("1/2", 1, Nearest, "0.25", "0x0.4#1", Greater);
+    test("1/2", 10, Floor, "0.23169", "0x0.3b5#10", Less);
+    test("1/2", 10, Ceiling, "0.23193", "0x0.3b6#10", Greater);
+    test("1/2", 10, Down, "0.23169", "0x0.3b5#10", Less);
+    test("1/2", 10, Up, "0.23193", "0x0.3b6#10", Greater);
+    test("1/2", 10, Nearest, "0.23169", "0x0.3b5#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.23169360648083348976912525450988",
+        "0x0.3b5045ae870880aa64d4117058#100",
+        Less,
+    );
+    test("-1/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("-1/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("-1/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("-1/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("-1/2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("-1/2", 10, Floor, "0.47559", "0x0.79c#10", Less);
+    test("-1/2", 10, Ceiling, "0.47607", "0x0.79e#10", Greater);
+    test("-1/2", 10, Down, "0.47559", "0x0.79c#10", Less);
+    test("-1/2", 10, Up, "0.47607", "0x0.79e#10", Greater);
+    test("-1/2", 10, Nearest, "0.47559", "0x0.79c#10", Less);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "0.47572809161053958879864377828113",
+        "0x0.79c950f34179746c2e9db19810#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.27051", "0x0.454#10", Less);
+    test("1/3", 10, Ceiling, "0.27100", "0x0.456#10", Greater);
+    test("1/3", 10, Down, "0.27051", "0x0.454#10", Less);
+    test("1/3", 10, Up, "0.27100", "0x0.456#10", Greater);
+    test("1/3", 10, Nearest, "0.27051", "0x0.454#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.27068264745934528288734631809643",
+        "0x0.454b753e6ebdec077d9802ead0#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.0039", "0x0.01#1", Less);
+    test("22/7", 1, Ceiling, "0.0078", "0x0.02#1", Greater);
+    test("22/7", 1, Down, "0.0039", "0x0.01#1", Less);
+    test("22/7", 1, Up, "0.0078", "0x0.02#1", Greater);
+    test("22/7", 1, Nearest, "0.0039", "0x0.01#1", Less);
+    test("22/7", 10, Floor, "0.0050735", "0x0.014c8#10", Less);
+    test("22/7", 10, Ceiling, "0.0050812", "0x0.014d0#10", Greater);
+    test("22/7", 10, Down, "0.0050735", "0x0.014c8#10", Less);
+    test("22/7", 10, Up, "0.0050812", "0x0.014d0#10", Greater);
+    test("22/7", 10, Nearest, "0.0050812", "0x0.014d0#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.0050774938728133775335694842213137",
+        "0x0.014cc236211ea4dbd5cda3eb404#100",
+        Less,
+    );
+    test("3/2", 1, Floor, "0.062", "0x0.1#1", Less);
+    test("3/2", 1, Ceiling, "0.12", "0x0.2#1", Greater);
+    test("3/2", 1, Down, "0.062", "0x0.1#1", Less);
+    test("3/2", 1, Up, "0.12", "0x0.2#1", Greater);
+    test("3/2", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test("3/2", 10, Floor, "0.071655", "0x0.1258#10", Less);
+    test("3/2", 10, Ceiling, "0.071777", "0x0.1260#10", Greater);
+    test("3/2", 10, Down, "0.071655", "0x0.1258#10", Less);
+    test("3/2", 10, Up, "0.071777", "0x0.1260#10", Greater);
+    test("3/2", 10, Nearest, "0.071777", "0x0.1260#10", Greater);
+    test(
+        "3/2",
+        100,
+        Nearest,
+        "0.071749497008105409673555416489708",
+        "0x0.125e2ccf277dc0a66778939a52#100",
+        Greater,
+    );
+    test("-7/2", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-7/2", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-7/2", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 10, Floor, "-0.37598", "-0x0.604#10", Less);
+    test("-7/2", 10, Ceiling, "-0.37549", "-0x0.602#10", Greater);
+    test("-7/2", 10, Down, "-0.37549", "-0x0.602#10", Greater);
+    test("-7/2", 10, Up, "-0.37598", "-0x0.604#10", Less);
+    test("-7/2", 10, Nearest, "-0.37549", "-0x0.602#10", Greater);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "-0.37553382314043191193439695158033",
+        "-0x0.6022fc10ee14ae8135356f2040#100",
+        Less,
+    );
+    test(
+        "1000000",
+        1,
+        Floor,
+        "1.9e-289529657",
+        "0x8.0E-240449176#1",
+        Less,
+    );
+    test(
+        "1000000",
+        1,
+        Ceiling,
+        "3.7e-289529657",
+        "0x1.0E-240449175#1",
+        Greater,
+    );
+    test(
+        "1000000",
+        1,
+        Down,
+        "1.9e-289529657",
+        "0x8.0E-240449176#1",
+        Less,
+    );
+    test(
+        "1000000",
+        1,
+        Up,
+        "3.7e-289529657",
+        "0x1.0E-240449175#1",
+        Greater,
+    );
+    test(
+        "1000000",
+        1,
+        Nearest,
+        "1.9e-289529657",
+        "0x8.0E-240449176#1",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Floor,
+        "2.2281e-289529657",
+        "0x9.9cE-240449176#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "2.2318e-289529657",
+        "0x9.a0E-240449176#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Down,
+        "2.2281e-289529657",
+        "0x9.9cE-240449176#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Up,
+        "2.2318e-289529657",
+        "0x9.a0E-240449176#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "2.2281e-289529657",
+        "0x9.9cE-240449176#10",
+        Less,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "2.2296011660898244345051714077433e-289529657",
+        "0x9.9d9b270f56f05376be7b069fE-240449176#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn airy_ai_rational_prec_round_fail_1() {
+    Float::airy_ai_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn airy_ai_rational_prec_round_fail_2() {
+    Float::airy_ai_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn airy_ai_rational_prec_round_ref_fail() {
+    Float::airy_ai_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn airy_ai_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (ai, o) = x.clone().airy_ai_prec_round(prec, rm);
+    assert!(ai.is_valid());
+
+    let (ai_alt, o_alt) = x.airy_ai_prec_round_ref(prec, rm);
+    assert!(ai_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.airy_ai_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ai));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let (ai_alt, o_alt) = x.clone().airy_ai_prec(prec);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+        assert_eq!(o_alt, o);
+
+        let (ai_alt, o_alt) = x.airy_ai_prec_ref(prec);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.airy_ai_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ai));
+        assert_eq!(o_alt, o);
+    }
+
+    // MPFR's Ai is only practical for moderate arguments
+    if x <= 256u32
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_ai, rug_o) = rug_airy_ai_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ai)),
+            ComparableFloatRef(&ai)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(ai.is_nan(), x.is_nan());
+    assert!(ai.is_nan() || (ai >= -1i32 && ai <= 1u32));
+
+    if ai.is_normal() {
+        assert_eq!(ai.get_prec(), Some(prec));
+    }
+
+    if x >= 0u32 && x.is_finite() {
+        assert!(ai >= 0u32);
+    }
+
+    if x.is_normal() {
+        let (ai_alt, o_alt) =
+            Float::airy_ai_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.airy_ai_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(ai.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.airy_ai_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn airy_ai_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_43().test_properties(|(x, prec, rm)| {
+        airy_ai_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (ai, o) = Float::NAN.airy_ai_prec_round(prec, rm);
+        assert!(ai.is_nan());
+        assert_eq!(o, Equal);
+
+        let (ai, o) = Float::INFINITY.airy_ai_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ai), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (ai, o) = Float::NEGATIVE_INFINITY.airy_ai_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ai), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        // Ai(0) is irrational
+        if rm != Exact {
+            let (ai, o) = Float::ZERO.airy_ai_prec_round(prec, rm);
+            let (ai_alt, o_alt) = Float::NEGATIVE_ZERO.airy_ai_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(ai_alt), ComparableFloat(ai));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn airy_ai_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (ai, o) = x.clone().airy_ai_round(rm);
+    assert!(ai.is_valid());
+
+    let (ai_alt, o_alt) = x.airy_ai_round_ref(rm);
+    assert!(ai_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.airy_ai_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ai));
+    assert_eq!(o_alt, o);
+
+    let (ai_alt, o_alt) = x.airy_ai_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let ai_alt = x.clone().airy_ai();
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+
+        let ai_alt = (&x).airy_ai();
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+
+        let mut x_alt = x.clone();
+        x_alt.airy_ai_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ai));
+
+        if x <= 256u32 {
+            let rug_ai = rug_airy_ai(&rug::Float::exact_from(&x));
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ai)),
+                ComparableFloatRef(&ai),
+            );
+        }
+    }
+
+    assert_eq!(ai.is_nan(), x.is_nan());
+    assert!(ai.is_nan() || (ai >= -1i32 && ai <= 1u32));
+
+    if x >= 0u32 && x.is_finite() {
+        assert!(ai >= 0u32);
+    }
+
+    // MPFR's Ai is only practical for moderate arguments
+    if x <= 256u32
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_ai, rug_o) = rug_airy_ai_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ai)),
+            ComparableFloatRef(&ai)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.airy_ai_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(ai.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.airy_ai_round_ref(Exact));
+    }
+}
+
+#[test]
+fn airy_ai_round_properties() {
+    float_rounding_mode_pair_gen_var_54().test_properties(|(x, rm)| {
+        airy_ai_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (ai, o) = Float::NAN.airy_ai_round(rm);
+        assert!(ai.is_nan());
+        assert_eq!(o, Equal);
+
+        let (ai, o) = Float::INFINITY.airy_ai_round(rm);
+        assert_eq!(ComparableFloat(ai), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (ai, o) = Float::NEGATIVE_INFINITY.airy_ai_round(rm);
+        assert_eq!(ComparableFloat(ai), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        // Ai(0) is irrational
+        if rm != Exact {
+            let (ai, o) = Float::ZERO.airy_ai_round(rm);
+            let (ai_alt, o_alt) = Float::NEGATIVE_ZERO.airy_ai_round(rm);
+            assert_eq!(ComparableFloat(ai_alt), ComparableFloat(ai));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn airy_ai_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (ai, o) = Float::airy_ai_rational_prec_round(x.clone(), prec, rm);
+    assert!(ai.is_valid());
+
+    let (ai_alt, o_alt) = Float::airy_ai_rational_prec_round_ref(&x, prec, rm);
+    assert!(ai_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let (ai_alt, o_alt) = Float::airy_ai_rational_prec(x.clone(), prec);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+        assert_eq!(o_alt, o);
+
+        let (ai_alt, o_alt) = Float::airy_ai_rational_prec_ref(&x, prec);
+        assert!(ai_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ai_alt), ComparableFloatRef(&ai));
+        assert_eq!(o_alt, o);
+
+        if x <= 256u32 {
+            let (rug_ai, rug_o) = rug_airy_ai_rational_prec(&x, prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ai)),
+                ComparableFloatRef(&ai)
+            );
+            assert_eq!(rug_o, o);
+        }
+    }
+
+    // MPFR's Ai is only practical for moderate arguments
+    if x <= 256u32
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_ai, rug_o) = rug_airy_ai_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ai)),
+            ComparableFloatRef(&ai)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!ai.is_nan());
+    assert!(ai.is_nan() || (ai >= -1i32 && ai <= 1u32));
+
+    if ai.is_normal() {
+        assert_eq!(ai.get_prec(), Some(prec));
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::airy_ai_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(ai.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::airy_ai_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn airy_ai_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_15().test_properties(|(x, prec, rm)| {
+        airy_ai_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        // Ai(0) is irrational
+        if rm != Exact {
+            let (ai, o) = Float::airy_ai_rational_prec_round(Rational::ZERO, prec, rm);
+            let (ai_alt, o_alt) = Float::ZERO.airy_ai_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(ai_alt), ComparableFloat(ai));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_airy_ai() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_airy_ai(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, 0.0);
+    test::<f32>(0.0, 0.35502806);
+    test::<f32>(-0.0, 0.35502806);
+    test::<f32>(1.0, 0.13529241);
+    test::<f32>(-1.0, 0.5355609);
+    test::<f32>(0.5, 0.23169361);
+    test::<f32>(-0.5, 0.4757281);
+    test::<f32>(2.0, 0.03492413);
+    test::<f32>(5.0, 0.00010834443);
+    test::<f32>(-2.5, -0.112325065);
+    test::<f32>(10.0, 1.1047532e-10);
+    test::<f32>(0.001, 0.35476923);
+    test::<f32>(10000000000.0, 0.0);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, 0.0);
+    test::<f64>(0.0, 0.3550280538878172);
+    test::<f64>(-0.0, 0.3550280538878172);
+    test::<f64>(1.0, 0.13529241631288141);
+    test::<f64>(-1.0, 0.5355608832923521);
+    test::<f64>(0.5, 0.23169360648083348);
+    test::<f64>(-0.5, 0.4757280916105396);
+    test::<f64>(2.0, 0.03492413042327438);
+    test::<f64>(5.0, 0.00010834442813607442);
+    test::<f64>(-2.5, -0.11232506769296609);
+    test::<f64>(10.0, 1.1047532552898686e-10);
+    test::<f64>(0.001, 0.3547692345431742);
+    test::<f64>(10000000000.0, 0.0);
+    test::<f64>(1.0e300, 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_airy_ai_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x < -T::power_of_2(6u64) {
+            return;
+        }
+        let ai = primitive_float_airy_ai(x);
+        assert_eq!(ai.is_nan(), x.is_nan());
+        assert!(ai.is_nan() || ai >= -T::ONE && ai <= T::ONE);
+        if x >= T::ZERO {
+            assert!(ai >= T::ZERO);
+        }
+    });
+}
+
+#[test]
+fn primitive_float_airy_ai_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_airy_ai_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_airy_ai_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_airy_ai_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", 0.35502806);
+    test::<f32>("1", 0.13529241);
+    test::<f32>("-1", 0.5355609);
+    test::<f32>("1/2", 0.23169361);
+    test::<f32>("-1/2", 0.4757281);
+    test::<f32>("1/3", 0.27068263);
+    test::<f32>("22/7", 0.005077494);
+    test::<f32>("3/2", 0.07174949);
+    test::<f32>("-7/2", -0.37553382);
+    test::<f32>("1000000", 0.0);
+
+    test::<f64>("0", 0.3550280538878172);
+    test::<f64>("1", 0.13529241631288141);
+    test::<f64>("-1", 0.5355608832923521);
+    test::<f64>("1/2", 0.23169360648083348);
+    test::<f64>("-1/2", 0.4757280916105396);
+    test::<f64>("1/3", 0.2706826474593453);
+    test::<f64>("22/7", 0.005077493872813378);
+    test::<f64>("3/2", 0.07174949700810541);
+    test::<f64>("-7/2", -0.37553382314043193);
+    test::<f64>("1000000", 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_airy_ai_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        if x < -64i32 {
+            return;
+        }
+        let ai = primitive_float_airy_ai_rational::<T>(&x);
+        assert!(!ai.is_nan());
+        assert!(ai >= -T::ONE && ai <= T::ONE);
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO && x >= -T::power_of_2(6u64) {
+            assert_eq!(
+                NiceFloat(primitive_float_airy_ai_rational::<T>(
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_airy_ai(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_airy_ai_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_airy_ai_rational_properties_helper);
+}
@@ -0,0 +1,2518 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Asin, AsinAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::asin::{
+    primitive_float_asin, primitive_float_asin_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::asin::{
+    rug_asin, rug_asin_prec, rug_asin_prec_round, rug_asin_rational_prec,
+    rug_asin_rational_prec_round, rug_asin_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_49, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_38,
+    rational_unsigned_rounding_mode_triple_gen_var_11,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_asin() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let asin = x.clone().asin();
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let asin_alt = (&x).asin();
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+
+        let mut asin_alt = x.clone();
+        asin_alt.asin_assign();
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&asin)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+    );
+    test("0.50", "0x0.8#1", "0.50", "0x0.8#1");
+    test("-0.50", "-0x0.8#1", "-0.50", "-0x0.8#1");
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        "1.4292568534704695",
+        "0x1.6de3c6f33d51e#52",
+    );
+    test("123.0", "0x7b.0#7", "NaN", "NaN");
+    test("3.1415926535897931", "0x3.243f6a8885a3#50", "NaN", "NaN");
+    test("7.9e-31", "0x1.0E-25#1", "7.9e-31", "0x1.0E-25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "-0.33983690945412193709639251339178",
+        "-0x0.56ff8d3c144448b8d4ef3a4800#100",
+    );
+}
+
+#[test]
+fn test_asin_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec(prec);
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_assign(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-1.5703", "-0x1.920#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.5707963267948966192313216916397",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 10, "0.52344", "0x0.860#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "0.52359877559829887307710723054682",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", 10, "-0.52344", "-0x0.860#10", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "-0.52359877559829887307710723054682",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        "1.4297",
+        "0x1.6e0#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        "1.4292568534704693375242334927223",
+        "0x1.6de3c6f33d51d4fa8ffbe5c9a#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "7.9e-31", "0x1.0E-25#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "-0.33984",
+        "-0x0.570#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "-0.33983690945412193709639251339178",
+        "-0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+}
+
+#[test]
+fn asin_prec_fail() {
+    assert_panic!(Float::NAN.asin_prec(0));
+    assert_panic!(Float::NAN.asin_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.asin_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_asin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_round(rm);
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_round_ref(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_round_assign(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.5707963267948966192313216916412",
+        "0x1.921fb54442d18469898cc5172#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.5707963267948966192313216916412",
+        "0x1.921fb54442d18469898cc5172#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", Floor, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "0.50", "0x0.8#1", Less);
+    test("-0.50", "-0x0.8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", Nearest, "-0.50", "-0x0.8#1", Greater);
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Floor,
+        "1.4292568534704690",
+        "0x1.6de3c6f33d51c#52",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Ceiling,
+        "1.4292568534704695",
+        "0x1.6de3c6f33d51e#52",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Down,
+        "1.4292568534704690",
+        "0x1.6de3c6f33d51c#52",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Up,
+        "1.4292568534704695",
+        "0x1.6de3c6f33d51e#52",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        Nearest,
+        "1.4292568534704695",
+        "0x1.6de3c6f33d51e#52",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.33983690945412193709639251339217",
+        "0x0.56ff8d3c144448b8d4ef3a4808#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.33983690945412193709639251339217",
+        "0x0.56ff8d3c144448b8d4ef3a4808#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "-0.33983690945412193709639251339217",
+        "-0x0.56ff8d3c144448b8d4ef3a4808#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-0.33983690945412193709639251339178",
+        "-0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "-0.33983690945412193709639251339178",
+        "-0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "-0.33983690945412193709639251339217",
+        "-0x0.56ff8d3c144448b8d4ef3a4808#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "-0.33983690945412193709639251339178",
+        "-0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+}
+
+#[test]
+fn asin_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.asin_round(Exact));
+    assert_panic!(X.asin_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.asin_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_asin_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_round_assign(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "1.5703", "0x1.920#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.5723",
+        "0x1.928#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.5703", "0x1.920#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.5723", "0x1.928#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.5703", "0x1.920#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "1.5707963267948966192313216916412",
+        "0x1.921fb54442d18469898cc5172#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-1.5723",
+        "-0x1.928#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-1.5703",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-1.5703",
+        "-0x1.920#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-1.5723", "-0x1.928#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-1.5703",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-1.5707963267948966192313216916412",
+        "-0x1.921fb54442d18469898cc5172#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-1.5707963267948966192313216916397",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-1.5707963267948966192313216916397",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.5723",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.5723",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "1.5707963267948966192313216916412",
+        "0x1.921fb54442d18469898cc5172#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 10, Floor, "0.52344", "0x0.860#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.52441",
+        "0x0.864#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "0.52344", "0x0.860#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "0.52441", "0x0.864#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "0.52344",
+        "0x0.860#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "0.52359877559829887307710723054603",
+        "0x0.860a91c16b9b2c232dd99707a#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "0.52359877559829887307710723054682",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.52359877559829887307710723054682",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-0.50", "-0x0.8#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-0.50", "-0x0.8#1", 1, Nearest, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "-0.52441",
+        "-0x0.864#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-0.52344",
+        "-0x0.860#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Down,
+        "-0.52344",
+        "-0x0.860#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Up, "-0.52441", "-0x0.864#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-0.52344",
+        "-0x0.860#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "-0.52359877559829887307710723054682",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "-0.52359877559829887307710723054603",
+        "-0x0.860a91c16b9b2c232dd99707a#100",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "-0.52359877559829887307710723054682",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Floor,
+        "1.4277",
+        "0x1.6d8#10",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Ceiling,
+        "1.4297",
+        "0x1.6e0#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Down,
+        "1.4277",
+        "0x1.6d8#10",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Up,
+        "1.4297",
+        "0x1.6e0#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        10,
+        Nearest,
+        "1.4297",
+        "0x1.6e0#10",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        Floor,
+        "1.4292568534704693375242334927208",
+        "0x1.6de3c6f33d51d4fa8ffbe5c98#100",
+        Less,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        Ceiling,
+        "1.4292568534704693375242334927223",
+        "0x1.6de3c6f33d51d4fa8ffbe5c9a#100",
+        Greater,
+    );
+    test(
+        "0.98999999999999999",
+        "0x0.fd70a3d70a3d7#52",
+        100,
+        Nearest,
+        "1.4292568534704693375242334927223",
+        "0x1.6de3c6f33d51d4fa8ffbe5c9a#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Exact, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Down, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Up, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 10, Exact, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, Floor, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, Ceiling, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.9040e-31",
+        "0x1.008E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.9040e-31",
+        "0x1.008E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "7.8886090522101180541172856528403e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.33983690945412193709639251339217",
+        "0x0.56ff8d3c144448b8d4ef3a4808#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-0.33984",
+        "-0x0.570#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-0.33936",
+        "-0x0.56e#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-0.33936",
+        "-0x0.56e#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-0.33984",
+        "-0x0.570#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-0.33984",
+        "-0x0.570#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "-0.33983690945412193709639251339217",
+        "-0x0.56ff8d3c144448b8d4ef3a4808#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "-0.33983690945412193709639251339178",
+        "-0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-0.33983690945412193709639251339178",
+        "-0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+}
+
+#[test]
+fn asin_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).asin_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).asin_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.asin_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.asin_prec_round(1, Exact));
+    assert_panic!(X.asin_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.asin_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_asin_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (asin, o) = Float::asin_rational_prec(x.clone(), prec);
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, out_o);
+
+        let (asin, o) = Float::asin_rational_prec_ref(&x, prec);
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_asin, rug_o) = rug_asin_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "2.0", "0x2.0#1", Greater);
+    test("1", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "1",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1", 10, "-1.5703", "-0x1.920#10", Greater);
+    test(
+        "-1",
+        100,
+        "-1.5707963267948966192313216916397",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Less);
+    test("1/2", 10, "0.52344", "0x0.860#10", Less);
+    test(
+        "1/2",
+        100,
+        "0.52359877559829887307710723054682",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test("-1/2", 1, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 10, "-0.52344", "-0x0.860#10", Greater);
+    test(
+        "-1/2",
+        100,
+        "-0.52359877559829887307710723054682",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.33984", "0x0.570#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+    test("3/2", 1, "NaN", "NaN", Equal);
+    test("3/2", 10, "NaN", "NaN", Equal);
+    test("3/2", 100, "NaN", "NaN", Equal);
+    test("99/100", 1, "1.0", "0x1.0#1", Less);
+    test("99/100", 10, "1.4297", "0x1.6e0#10", Greater);
+    test(
+        "99/100",
+        100,
+        "1.4292568534704694004855323346654",
+        "0x1.6de3c6f33d51d983fe4fb4404#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn asin_rational_prec_fail() {
+    Float::asin_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn asin_rational_prec_ref_fail() {
+    Float::asin_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_asin_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (asin, o) = Float::asin_rational_prec_round(x.clone(), prec, rm);
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, out_o);
+
+        let (asin, o) = Float::asin_rational_prec_round_ref(&x, prec, rm);
+        assert!(asin.is_valid());
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1", 10, Floor, "1.5703", "0x1.920#10", Less);
+    test("1", 10, Ceiling, "1.5723", "0x1.928#10", Greater);
+    test("1", 10, Down, "1.5703", "0x1.920#10", Less);
+    test("1", 10, Up, "1.5723", "0x1.928#10", Greater);
+    test("1", 10, Nearest, "1.5703", "0x1.920#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test("-1", 10, Floor, "-1.5723", "-0x1.928#10", Less);
+    test("-1", 10, Ceiling, "-1.5703", "-0x1.920#10", Greater);
+    test("-1", 10, Down, "-1.5703", "-0x1.920#10", Greater);
+    test("-1", 10, Up, "-1.5723", "-0x1.928#10", Less);
+    test("-1", 10, Nearest, "-1.5703", "-0x1.920#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-1.5707963267948966192313216916397",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1/2", 10, Floor, "0.52344", "0x0.860#10", Less);
+    test("1/2", 10, Ceiling, "0.52441", "0x0.864#10", Greater);
+    test("1/2", 10, Down, "0.52344", "0x0.860#10", Less);
+    test("1/2", 10, Up, "0.52441", "0x0.864#10", Greater);
+    test("1/2", 10, Nearest, "0.52344", "0x0.860#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.52359877559829887307710723054682",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test("-1/2", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1/2", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1/2", 1, Nearest, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 10, Floor, "-0.52441", "-0x0.864#10", Less);
+    test("-1/2", 10, Ceiling, "-0.52344", "-0x0.860#10", Greater);
+    test("-1/2", 10, Down, "-0.52344", "-0x0.860#10", Greater);
+    test("-1/2", 10, Up, "-0.52441", "-0x0.864#10", Less);
+    test("-1/2", 10, Nearest, "-0.52344", "-0x0.860#10", Greater);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "-0.52359877559829887307710723054682",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.33936", "0x0.56e#10", Less);
+    test("1/3", 10, Ceiling, "0.33984", "0x0.570#10", Greater);
+    test("1/3", 10, Down, "0.33936", "0x0.56e#10", Less);
+    test("1/3", 10, Up, "0.33984", "0x0.570#10", Greater);
+    test("1/3", 10, Nearest, "0.33984", "0x0.570#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.33983690945412193709639251339178",
+        "0x0.56ff8d3c144448b8d4ef3a4800#100",
+        Greater,
+    );
+    test("3/2", 1, Floor, "NaN", "NaN", Equal);
+    test("3/2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("3/2", 1, Down, "NaN", "NaN", Equal);
+    test("3/2", 1, Up, "NaN", "NaN", Equal);
+    test("3/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("3/2", 1, Exact, "NaN", "NaN", Equal);
+    test("3/2", 10, Floor, "NaN", "NaN", Equal);
+    test("3/2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("3/2", 10, Down, "NaN", "NaN", Equal);
+    test("3/2", 10, Up, "NaN", "NaN", Equal);
+    test("3/2", 10, Nearest, "NaN", "NaN", Equal);
+    test("3/2", 10, Exact, "NaN", "NaN", Equal);
+    test("3/2", 100, Nearest, "NaN", "NaN", Equal);
+    test("99/100", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("99/100", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("99/100", 1, Down, "1.0", "0x1.0#1", Less);
+    test("99/100", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("99/100", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("99/100", 10, Floor, "1.4277", "0x1.6d8#10", Less);
+    test("99/100", 10, Ceiling, "1.4297", "0x1.6e0#10", Greater);
+    test("99/100", 10, Down, "1.4277", "0x1.6d8#10", Less);
+    test("99/100", 10, Up, "1.4297", "0x1.6e0#10", Greater);
+    test("99/100", 10, Nearest, "1.4297", "0x1.6e0#10", Greater);
+    test(
+        "99/100",
+        100,
+        Nearest,
+        "1.4292568534704694004855323346654",
+        "0x1.6de3c6f33d51d983fe4fb4404#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn asin_rational_prec_round_fail_1() {
+    Float::asin_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn asin_rational_prec_round_fail_2() {
+    Float::asin_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn asin_rational_prec_round_ref_fail() {
+    Float::asin_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn asin_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (asin, o) = x.clone().asin_prec_round(prec, rm);
+    assert!(asin.is_valid());
+
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(asin.is_nan(), !(x >= -1i32 && x <= 1u32));
+    assert!(asin.is_nan() || (asin >= -2i32 && asin <= 2u32));
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(prec));
+    }
+
+    let (asin_alt, o_alt) = (-&x).asin_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-asin_alt), ComparableFloat(asin.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (asin_alt, o_alt) = Float::asin_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.asin_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(asin.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.asin_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn asin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_38().test_properties(|(x, prec, rm)| {
+        asin_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (asin, o) = Float::NAN.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::INFINITY.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::NEGATIVE_INFINITY.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::ZERO.asin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::NEGATIVE_ZERO.asin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn asin_prec_properties_helper(x: Float, prec: u64) {
+    let (asin, o) = x.clone().asin_prec(prec);
+    assert!(asin.is_valid());
+
+    let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asin)),
+        ComparableFloatRef(&asin)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(asin.is_nan() || (asin >= -2i32 && asin <= 2u32));
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(prec));
+    }
+
+    let (asin_alt, o_alt) = (-&x).asin_prec(prec);
+    assert_eq!(ComparableFloat(-asin_alt), ComparableFloat(asin.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn asin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        asin_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (asin, o) = Float::NAN.asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::INFINITY.asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::NEGATIVE_INFINITY.asin_prec(prec);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::ZERO.asin_prec(prec);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::NEGATIVE_ZERO.asin_prec(prec);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn asin_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (asin, o) = x.clone().asin_round(rm);
+    assert!(asin.is_valid());
+
+    let (asin_alt, o_alt) = x.asin_round_ref(rm);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    assert!(asin.is_nan() || (asin >= -2i32 && asin <= 2u32));
+
+    let (asin_alt, o_alt) = (-&x).asin_round(-rm);
+    assert_eq!(ComparableFloat(-asin_alt), ComparableFloat(asin.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.asin_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(asin.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.asin_round_ref(Exact));
+    }
+}
+
+#[test]
+fn asin_round_properties() {
+    float_rounding_mode_pair_gen_var_49().test_properties(|(x, rm)| {
+        asin_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (asin, o) = Float::NAN.asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::INFINITY.asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::NEGATIVE_INFINITY.asin_round(rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::ZERO.asin_round(rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (asin, o) = Float::NEGATIVE_ZERO.asin_round(rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn asin_properties_helper(x: Float) {
+    let asin = x.clone().asin();
+    assert!(asin.is_valid());
+
+    let asin_alt = (&x).asin();
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+
+    let mut x_alt = x.clone();
+    x_alt.asin_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+
+    let asin_alt = x.asin_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    let asin_alt = x.asin_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+
+    assert_eq!(asin.is_nan(), !(x >= -1i32 && x <= 1u32));
+    assert!(asin.is_nan() || (asin >= -2i32 && asin <= 2u32));
+
+    let rug_asin = rug_asin(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asin)),
+        ComparableFloatRef(&asin),
+    );
+}
+
+#[test]
+fn asin_properties() {
+    float_gen().test_properties(|x| {
+        asin_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn asin_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (asin, o) = Float::asin_rational_prec_round(x.clone(), prec, rm);
+    assert!(asin.is_valid());
+
+    let (asin_alt, o_alt) = Float::asin_rational_prec_round_ref(&x, prec, rm);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asin, rug_o) = rug_asin_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(asin.is_nan(), x < -1i32 || x > 1u32);
+    assert!(asin.is_nan() || (asin >= -2i32 && asin <= 2u32));
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (asin_alt, o_alt) = Float::asin_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-asin_alt), ComparableFloat(asin.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::asin_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(asin.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::asin_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn asin_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_11().test_properties(|(x, prec, rm)| {
+        asin_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (asin, o) = Float::asin_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn asin_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (asin, o) = Float::asin_rational_prec(x.clone(), prec);
+    assert!(asin.is_valid());
+
+    let (asin_alt, o_alt) = Float::asin_rational_prec_ref(&x, prec);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (asin_alt, o_alt) = Float::asin_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (rug_asin, rug_o) = rug_asin_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asin)),
+        ComparableFloatRef(&asin)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(asin.is_nan() || (asin >= -2i32 && asin <= 2u32));
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn asin_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        asin_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (asin, o) = Float::asin_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_asin() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_asin(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::NAN);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, core::f32::consts::FRAC_PI_2);
+    test::<f32>(-1.0, -core::f32::consts::FRAC_PI_2);
+    test::<f32>(0.5, core::f32::consts::FRAC_PI_6);
+    test::<f32>(-0.5, -core::f32::consts::FRAC_PI_6);
+    test::<f32>(2.0, f32::NAN);
+    test::<f32>(10.0, f32::NAN);
+    test::<f32>(0.001, 0.0010000002);
+    test::<f32>(10000000000.0, f32::NAN);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::NAN);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, core::f64::consts::FRAC_PI_2);
+    test::<f64>(-1.0, -core::f64::consts::FRAC_PI_2);
+    test::<f64>(0.5, core::f64::consts::FRAC_PI_6);
+    test::<f64>(-0.5, -core::f64::consts::FRAC_PI_6);
+    test::<f64>(2.0, f64::NAN);
+    test::<f64>(10.0, f64::NAN);
+    test::<f64>(0.001, 0.0010000001666667416);
+    test::<f64>(10000000000.0, f64::NAN);
+    test::<f64>(1.0e300, f64::NAN);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_asin_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let asin = primitive_float_asin(x);
+        assert_eq!(asin.is_nan(), !(x >= -T::ONE && x <= T::ONE));
+        assert!(asin.is_nan() || (asin >= -T::TWO && asin <= T::TWO));
+        assert_eq!(NiceFloat(primitive_float_asin(-x)), NiceFloat(-asin));
+    });
+}
+
+#[test]
+fn primitive_float_asin_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_asin_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_asin_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_asin_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", core::f32::consts::FRAC_PI_2);
+    test::<f32>("-1", -core::f32::consts::FRAC_PI_2);
+    test::<f32>("1/2", core::f32::consts::FRAC_PI_6);
+    test::<f32>("-1/2", -core::f32::consts::FRAC_PI_6);
+    test::<f32>("1/3", 0.3398369);
+    test::<f32>("22/7", f32::NAN);
+    test::<f32>("3/2", f32::NAN);
+    test::<f32>("1000000", f32::NAN);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", core::f64::consts::FRAC_PI_2);
+    test::<f64>("-1", -core::f64::consts::FRAC_PI_2);
+    test::<f64>("1/2", core::f64::consts::FRAC_PI_6);
+    test::<f64>("-1/2", -core::f64::consts::FRAC_PI_6);
+    test::<f64>("1/3", 0.3398369094541219);
+    test::<f64>("22/7", f64::NAN);
+    test::<f64>("3/2", f64::NAN);
+    test::<f64>("1000000", f64::NAN);
+}
+
+#[allow(clippy::manual_range_contains, clippy::type_repetition_in_bounds)]
+fn primitive_float_asin_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let asin = primitive_float_asin_rational::<T>(&x);
+        assert_eq!(asin.is_nan(), x < -1i32 || x > 1u32);
+        assert!(asin.is_nan() || (asin >= -T::TWO && asin <= T::TWO));
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_asin_rational::<T>(&-&x)),
+                NiceFloat(-asin)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_asin_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_asin(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_asin_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_asin_rational_properties_helper);
+}
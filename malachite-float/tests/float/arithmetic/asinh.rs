@@ -0,0 +1,2724 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Asinh, AsinhAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::asinh::{
+    primitive_float_asinh, primitive_float_asinh_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::asinh::{
+    rug_asinh, rug_asinh_prec, rug_asinh_prec_round, rug_asinh_rational_prec,
+    rug_asinh_rational_prec_round, rug_asinh_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_asinh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let asinh = x.clone().asinh();
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let asinh_alt = (&x).asinh();
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+
+        let mut asinh_alt = x.clone();
+        asinh_alt.asinh_assign();
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&asinh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+    );
+    test("123.0", "0x7b.0#7", "5.500", "0x5.8#7");
+    test("-123.0", "-0x7b.0#7", "-5.500", "-0x5.8#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.8622957433108489",
+        "0x1.dcbf69f100070#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+    );
+    test("355.0", "0x163.0#9", "6.562", "0x6.90#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "7.9e-31", "0x1.0E-25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.32745015023725844332253525998852",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+    );
+}
+
+#[test]
+fn test_asinh_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_prec(prec);
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_ref(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_prec_assign(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asinh, rug_o) = rug_asinh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "-Infinity", "-Infinity", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.88184", "0x0.e1c#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.88184", "-0x0.e1c#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.88137358701954302523260932497968",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.88184",
+        "0x0.e1c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "5.5078", "0x5.82#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "5.5053480600782759008760776551637",
+        "0x5.815e7d8f221be5f7372cc2c08#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "-4.0", "-0x4.0#1", Greater);
+    test("-123.0", "-0x7b.0#7", 10, "-5.5078", "-0x5.82#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-5.5053480600782759008760776551637",
+        "-0x5.815e7d8f221be5f7372cc2c08#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.8613",
+        "0x1.dc8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "1.8622957433108481827430517141269",
+        "0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.7246",
+        "0x1.b98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.7253825588523150440330169640241",
+        "0x1.b9b2abdf5c076eabb258a2374#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, "8.0", "0x8.0#1", Greater);
+    test("355.0", "0x163.0#9", 10, "6.5625", "0x6.90#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "6.5652669537628445670374073399703",
+        "0x6.90b555c7ebc422f121a160678#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "691.46867507877365056731942878181",
+        "0x2b3.77fb1707c87628decba7f2c#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.32745015023725844332253525998852",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+        Greater,
+    );
+}
+
+#[test]
+fn asinh_prec_fail() {
+    assert_panic!(Float::NAN.asinh_prec(0));
+    assert_panic!(Float::NAN.asinh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.asinh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_asinh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_round(rm);
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asinh_alt, o_alt) = x.asinh_round_ref(rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_round_assign(rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Down, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Up,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.88137358701954302523260932498047",
+        "0x0.e1a1b30bcea13660d8f99e8de#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.88137358701954302523260932498047",
+        "0x0.e1a1b30bcea13660d8f99e8de#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", Floor, "5.500", "0x5.8#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "5.562", "0x5.9#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "5.500", "0x5.8#7", Less);
+    test("123.0", "0x7b.0#7", Up, "5.562", "0x5.9#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "5.500", "0x5.8#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-5.562", "-0x5.9#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-5.500",
+        "-0x5.8#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-5.500", "-0x5.8#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-5.562", "-0x5.9#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-5.500",
+        "-0x5.8#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "1.8622957433108471",
+        "0x1.dcbf69f100068#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "1.8622957433108489",
+        "0x1.dcbf69f100070#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "1.8622957433108471",
+        "0x1.dcbf69f100068#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "1.8622957433108489",
+        "0x1.dcbf69f100070#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "1.8622957433108489",
+        "0x1.dcbf69f100070#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.7253825588523148",
+        "0x1.b9b2abdf5c076#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.7253825588523148",
+        "0x1.b9b2abdf5c076#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", Floor, "6.562", "0x6.90#9", Less);
+    test("355.0", "0x163.0#9", Ceiling, "6.578", "0x6.94#9", Greater);
+    test("355.0", "0x163.0#9", Down, "6.562", "0x6.90#9", Less);
+    test("355.0", "0x163.0#9", Up, "6.578", "0x6.94#9", Greater);
+    test("355.0", "0x163.0#9", Nearest, "6.562", "0x6.90#9", Less);
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "691.46867507877323",
+        "0x2b3.77fb1707c80#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "691.46867507877323",
+        "0x2b3.77fb1707c80#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "691.46867507877369",
+        "0x2b3.77fb1707c88#51",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.32745015023725844332253525998813",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.32745015023725844332253525998852",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.32745015023725844332253525998813",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.32745015023725844332253525998852",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.32745015023725844332253525998852",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+        Greater,
+    );
+}
+
+#[test]
+fn asinh_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.asinh_round(Exact));
+    assert_panic!(X.asinh_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.asinh_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_asinh_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_prec_round(prec, rm);
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_prec_round_assign(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Up,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.88086", "0x0.e18#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.88184",
+        "0x0.e1c#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.88086", "0x0.e18#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.88184", "0x0.e1c#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.88184",
+        "0x0.e1c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.88137358701954302523260932498047",
+        "0x0.e1a1b30bcea13660d8f99e8de#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.88184",
+        "-0x0.e1c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.88086",
+        "-0x0.e18#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.88086",
+        "-0x0.e18#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.88184", "-0x0.e1c#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.88184",
+        "-0x0.e1c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.88137358701954302523260932498047",
+        "-0x0.e1a1b30bcea13660d8f99e8de#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.88137358701954302523260932497968",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.88137358701954302523260932497968",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.88086",
+        "0x0.e18#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.88184",
+        "0x0.e1c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.88086",
+        "0x0.e18#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.88184",
+        "0x0.e1c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.88184",
+        "0x0.e1c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.88137358701954302523260932498047",
+        "0x0.e1a1b30bcea13660d8f99e8de#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "8.0", "0x8.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "8.0", "0x8.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "5.5000", "0x5.80#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "5.5078",
+        "0x5.82#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "5.5000", "0x5.80#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "5.5078", "0x5.82#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "5.5078",
+        "0x5.82#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "5.5053480600782759008760776551637",
+        "0x5.815e7d8f221be5f7372cc2c08#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "5.5053480600782759008760776551700",
+        "0x5.815e7d8f221be5f7372cc2c10#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "5.5053480600782759008760776551637",
+        "0x5.815e7d8f221be5f7372cc2c08#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Floor, "-8.0", "-0x8.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Down, "-4.0", "-0x4.0#1", Greater);
+    test("-123.0", "-0x7b.0#7", 1, Up, "-8.0", "-0x8.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-5.5078",
+        "-0x5.82#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-5.5000",
+        "-0x5.80#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-5.5000",
+        "-0x5.80#10",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Up, "-5.5078", "-0x5.82#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-5.5078",
+        "-0x5.82#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "-5.5053480600782759008760776551700",
+        "-0x5.815e7d8f221be5f7372cc2c10#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "-5.5053480600782759008760776551637",
+        "-0x5.815e7d8f221be5f7372cc2c08#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "-5.5053480600782759008760776551637",
+        "-0x5.815e7d8f221be5f7372cc2c08#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "1.8613",
+        "0x1.dc8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.8633",
+        "0x1.dd0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "1.8613",
+        "0x1.dc8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.8633",
+        "0x1.dd0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.8613",
+        "0x1.dc8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "1.8622957433108481827430517141254",
+        "0x1.dcbf69f10006cbe9c11ca9a5c#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "1.8622957433108481827430517141269",
+        "0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "1.8622957433108481827430517141269",
+        "0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.7246",
+        "0x1.b98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.7266",
+        "0x1.ba0#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.7246",
+        "0x1.b98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.7266",
+        "0x1.ba0#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.7246",
+        "0x1.b98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "1.7253825588523150440330169640241",
+        "0x1.b9b2abdf5c076eabb258a2374#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "1.7253825588523150440330169640256",
+        "0x1.b9b2abdf5c076eabb258a2376#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "1.7253825588523150440330169640241",
+        "0x1.b9b2abdf5c076eabb258a2374#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, Floor, "4.0", "0x4.0#1", Less);
+    test("355.0", "0x163.0#9", 1, Ceiling, "8.0", "0x8.0#1", Greater);
+    test("355.0", "0x163.0#9", 1, Down, "4.0", "0x4.0#1", Less);
+    test("355.0", "0x163.0#9", 1, Up, "8.0", "0x8.0#1", Greater);
+    test("355.0", "0x163.0#9", 1, Nearest, "8.0", "0x8.0#1", Greater);
+    test("355.0", "0x163.0#9", 10, Floor, "6.5625", "0x6.90#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "6.5703",
+        "0x6.92#10",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 10, Down, "6.5625", "0x6.90#10", Less);
+    test("355.0", "0x163.0#9", 10, Up, "6.5703", "0x6.92#10", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "6.5625",
+        "0x6.90#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "6.5652669537628445670374073399640",
+        "0x6.90b555c7ebc422f121a160670#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "6.5652669537628445670374073399703",
+        "0x6.90b555c7ebc422f121a160678#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "6.5652669537628445670374073399703",
+        "0x6.90b555c7ebc422f121a160678#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "692.00",
+        "0x2b4.0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "692.00",
+        "0x2b4.0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "691.00",
+        "0x2b3.0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "691.46867507877365056731942878181",
+        "0x2b3.77fb1707c87628decba7f2c#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "691.46867507877365056731942878261",
+        "0x2b3.77fb1707c87628decba7f30#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "691.46867507877365056731942878181",
+        "0x2b3.77fb1707c87628decba7f2c#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "7.8886090522101180541172856528216e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.32745015023725844332253525998813",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.32745015023725844332253525998852",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.32745015023725844332253525998852",
+        "0x0.53d3c5e656dd20ec63b484c7f0#100",
+        Greater,
+    );
+}
+
+#[test]
+fn asinh_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).asinh_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).asinh_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.asinh_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.asinh_prec_round(1, Exact));
+    assert_panic!(X.asinh_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.asinh_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_asinh_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (asinh, o) = Float::asinh_rational_prec(x.clone(), prec);
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (asinh, o) = Float::asinh_rational_prec_ref(&x, prec);
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_asinh, rug_o) = rug_asinh_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Greater);
+    test("1", 10, "0.88184", "0x0.e1c#10", Greater);
+    test(
+        "1",
+        100,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test("-1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, "-0.88184", "-0x0.e1c#10", Less);
+    test(
+        "-1",
+        100,
+        "-0.88137358701954302523260932497968",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Greater,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, "0.48145", "0x0.7b4#10", Greater);
+    test(
+        "1/2",
+        100,
+        "0.48121182505960344749775891342426",
+        "0x0.7b30b2bb14582652f810812a58#100",
+        Less,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.32764", "0x0.53e#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.32745015023725844332253525998813",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Less,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.32764", "-0x0.53e#10", Less);
+    test(
+        "-1/3",
+        100,
+        "-0.32745015023725844332253525998813",
+        "-0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Greater,
+    );
+    test("22/7", 1, "2.0", "0x2.0#1", Greater);
+    test("22/7", 10, "1.8633", "0x1.dd0#10", Greater);
+    test(
+        "22/7",
+        100,
+        "1.8626792111346099850190014829502",
+        "0x1.dcd88b76c3216f8eaa82640e6#100",
+        Greater,
+    );
+    test("1000000", 1, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 10, "14.516", "0xe.84#10", Greater);
+    test(
+        "1000000",
+        100,
+        "14.508657738524469413525180755814",
+        "0xe.823764bfd1e5fa37c6bf52ed#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn asinh_rational_prec_fail() {
+    Float::asinh_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn asinh_rational_prec_ref_fail() {
+    Float::asinh_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_asinh_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (asinh, o) = Float::asinh_rational_prec_round(x.clone(), prec, rm);
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (asinh, o) = Float::asinh_rational_prec_round_ref(&x, prec, rm);
+        assert!(asinh.is_valid());
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", 10, Floor, "0.88086", "0x0.e18#10", Less);
+    test("1", 10, Ceiling, "0.88184", "0x0.e1c#10", Greater);
+    test("1", 10, Down, "0.88086", "0x0.e18#10", Less);
+    test("1", 10, Up, "0.88184", "0x0.e1c#10", Greater);
+    test("1", 10, Nearest, "0.88184", "0x0.e1c#10", Greater);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.88137358701954302523260932497968",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Less,
+    );
+    test("-1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, Floor, "-0.88184", "-0x0.e1c#10", Less);
+    test("-1", 10, Ceiling, "-0.88086", "-0x0.e18#10", Greater);
+    test("-1", 10, Down, "-0.88086", "-0x0.e18#10", Greater);
+    test("-1", 10, Up, "-0.88184", "-0x0.e1c#10", Less);
+    test("-1", 10, Nearest, "-0.88184", "-0x0.e1c#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.88137358701954302523260932497968",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, Floor, "0.48096", "0x0.7b2#10", Less);
+    test("1/2", 10, Ceiling, "0.48145", "0x0.7b4#10", Greater);
+    test("1/2", 10, Down, "0.48096", "0x0.7b2#10", Less);
+    test("1/2", 10, Up, "0.48145", "0x0.7b4#10", Greater);
+    test("1/2", 10, Nearest, "0.48145", "0x0.7b4#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.48121182505960344749775891342426",
+        "0x0.7b30b2bb14582652f810812a58#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.32715", "0x0.53c#10", Less);
+    test("1/3", 10, Ceiling, "0.32764", "0x0.53e#10", Greater);
+    test("1/3", 10, Down, "0.32715", "0x0.53c#10", Less);
+    test("1/3", 10, Up, "0.32764", "0x0.53e#10", Greater);
+    test("1/3", 10, Nearest, "0.32764", "0x0.53e#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.32745015023725844332253525998813",
+        "0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, Floor, "-0.32764", "-0x0.53e#10", Less);
+    test("-1/3", 10, Ceiling, "-0.32715", "-0x0.53c#10", Greater);
+    test("-1/3", 10, Down, "-0.32715", "-0x0.53c#10", Greater);
+    test("-1/3", 10, Up, "-0.32764", "-0x0.53e#10", Less);
+    test("-1/3", 10, Nearest, "-0.32764", "-0x0.53e#10", Less);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-0.32745015023725844332253525998813",
+        "-0x0.53d3c5e656dd20ec63b484c7e8#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("22/7", 10, Floor, "1.8613", "0x1.dc8#10", Less);
+    test("22/7", 10, Ceiling, "1.8633", "0x1.dd0#10", Greater);
+    test("22/7", 10, Down, "1.8613", "0x1.dc8#10", Less);
+    test("22/7", 10, Up, "1.8633", "0x1.dd0#10", Greater);
+    test("22/7", 10, Nearest, "1.8633", "0x1.dd0#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "1.8626792111346099850190014829502",
+        "0x1.dcd88b76c3216f8eaa82640e6#100",
+        Greater,
+    );
+    test("1000000", 1, Floor, "8.0", "0x8.0#1", Less);
+    test("1000000", 1, Ceiling, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 1, Down, "8.0", "0x8.0#1", Less);
+    test("1000000", 1, Up, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 1, Nearest, "16.0", "0x1.0E+1#1", Greater);
+    test("1000000", 10, Floor, "14.500", "0xe.80#10", Less);
+    test("1000000", 10, Ceiling, "14.516", "0xe.84#10", Greater);
+    test("1000000", 10, Down, "14.500", "0xe.80#10", Less);
+    test("1000000", 10, Up, "14.516", "0xe.84#10", Greater);
+    test("1000000", 10, Nearest, "14.516", "0xe.84#10", Greater);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "14.508657738524469413525180755814",
+        "0xe.823764bfd1e5fa37c6bf52ed#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn asinh_rational_prec_round_fail_1() {
+    Float::asinh_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn asinh_rational_prec_round_fail_2() {
+    Float::asinh_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn asinh_rational_prec_round_ref_fail() {
+    Float::asinh_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asinh_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (asinh, o) = x.clone().asinh_prec_round(prec, rm);
+    assert!(asinh.is_valid());
+
+    let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, rm);
+    assert!(asinh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asinh_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asinh, rug_o) = rug_asinh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(asinh.is_nan(), x.is_nan());
+
+    if asinh.is_normal() {
+        assert_eq!(asinh.get_prec(), Some(prec));
+    }
+
+    let (asinh_alt, o_alt) = (-&x).asinh_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-asinh_alt), ComparableFloat(asinh.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (asinh_alt, o_alt) =
+            Float::asinh_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.asinh_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(asinh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.asinh_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn asinh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        asinh_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (asinh, o) = Float::NAN.asinh_prec_round(prec, rm);
+        assert!(asinh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::INFINITY.asinh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::NEGATIVE_INFINITY.asinh_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(asinh),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::ZERO.asinh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::NEGATIVE_ZERO.asinh_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(asinh),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asinh_prec_properties_helper(x: Float, prec: u64) {
+    let (asinh, o) = x.clone().asinh_prec(prec);
+    assert!(asinh.is_valid());
+
+    let (asinh_alt, o_alt) = x.asinh_prec_ref(prec);
+    assert!(asinh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asinh_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let (rug_asinh, rug_o) = rug_asinh_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asinh)),
+        ComparableFloatRef(&asinh)
+    );
+    assert_eq!(rug_o, o);
+
+    if asinh.is_normal() {
+        assert_eq!(asinh.get_prec(), Some(prec));
+    }
+
+    let (asinh_alt, o_alt) = (-&x).asinh_prec(prec);
+    assert_eq!(ComparableFloat(-asinh_alt), ComparableFloat(asinh.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn asinh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        asinh_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (asinh, o) = Float::NAN.asinh_prec(prec);
+        assert!(asinh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::INFINITY.asinh_prec(prec);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::NEGATIVE_INFINITY.asinh_prec(prec);
+        assert_eq!(
+            ComparableFloat(asinh),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::ZERO.asinh_prec(prec);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::NEGATIVE_ZERO.asinh_prec(prec);
+        assert_eq!(
+            ComparableFloat(asinh),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asinh_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (asinh, o) = x.clone().asinh_round(rm);
+    assert!(asinh.is_valid());
+
+    let (asinh_alt, o_alt) = x.asinh_round_ref(rm);
+    assert!(asinh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asinh_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let (asinh_alt, o_alt) = x.asinh_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let (asinh_alt, o_alt) = (-&x).asinh_round(-rm);
+    assert_eq!(ComparableFloat(-asinh_alt), ComparableFloat(asinh.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asinh, rug_o) = rug_asinh_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.asinh_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(asinh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.asinh_round_ref(Exact));
+    }
+}
+
+#[test]
+fn asinh_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        asinh_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (asinh, o) = Float::NAN.asinh_round(rm);
+        assert!(asinh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::INFINITY.asinh_round(rm);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::NEGATIVE_INFINITY.asinh_round(rm);
+        assert_eq!(
+            ComparableFloat(asinh),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::ZERO.asinh_round(rm);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (asinh, o) = Float::NEGATIVE_ZERO.asinh_round(rm);
+        assert_eq!(
+            ComparableFloat(asinh),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asinh_properties_helper(x: Float) {
+    let asinh = x.clone().asinh();
+    assert!(asinh.is_valid());
+
+    let asinh_alt = (&x).asinh();
+    assert!(asinh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+
+    let mut x_alt = x.clone();
+    x_alt.asinh_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+
+    let asinh_alt = x.asinh_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    let asinh_alt = x.asinh_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+
+    assert_eq!(asinh.is_nan(), x.is_nan());
+
+    let rug_asinh = rug_asinh(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asinh)),
+        ComparableFloatRef(&asinh),
+    );
+}
+
+#[test]
+fn asinh_properties() {
+    float_gen().test_properties(|x| {
+        asinh_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asinh_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (asinh, o) = Float::asinh_rational_prec_round(x.clone(), prec, rm);
+    assert!(asinh.is_valid());
+
+    let (asinh_alt, o_alt) = Float::asinh_rational_prec_round_ref(&x, prec, rm);
+    assert!(asinh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asinh, rug_o) = rug_asinh_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!asinh.is_nan());
+
+    if asinh.is_normal() {
+        assert_eq!(asinh.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (asinh_alt, o_alt) = Float::asinh_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-asinh_alt), ComparableFloat(asinh.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::asinh_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(asinh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::asinh_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn asinh_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        asinh_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (asinh, o) = Float::asinh_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asinh_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (asinh, o) = Float::asinh_rational_prec(x.clone(), prec);
+    assert!(asinh.is_valid());
+
+    let (asinh_alt, o_alt) = Float::asinh_rational_prec_ref(&x, prec);
+    assert!(asinh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let (asinh_alt, o_alt) = Float::asinh_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+    assert_eq!(o_alt, o);
+
+    let (rug_asinh, rug_o) = rug_asinh_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asinh)),
+        ComparableFloatRef(&asinh)
+    );
+    assert_eq!(rug_o, o);
+
+    if asinh.is_normal() {
+        assert_eq!(asinh.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn asinh_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        asinh_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (asinh, o) = Float::asinh_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(asinh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_asinh() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_asinh(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::INFINITY);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NEGATIVE_INFINITY);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, 0.8813736);
+    test::<f32>(-1.0, -0.8813736);
+    test::<f32>(0.5, 0.4812118);
+    test::<f32>(-0.5, -0.4812118);
+    test::<f32>(2.0, 1.4436355);
+    test::<f32>(10.0, 2.998223);
+    test::<f32>(0.001, 0.0009999999);
+    test::<f32>(10000000000.0, 23.718998);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::INFINITY);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NEGATIVE_INFINITY);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, 0.881373587019543);
+    test::<f64>(-1.0, -0.881373587019543);
+    test::<f64>(0.5, 0.48121182505960347);
+    test::<f64>(-0.5, -0.48121182505960347);
+    test::<f64>(2.0, 1.4436354751788103);
+    test::<f64>(10.0, 2.99822295029797);
+    test::<f64>(0.001, 0.0009999998333334083);
+    test::<f64>(10000000000.0, 23.7189981105004);
+    test::<f64>(1.0e300, 691.4686750787737);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_asinh_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let asinh = primitive_float_asinh(x);
+        assert_eq!(asinh.is_nan(), x.is_nan());
+        assert_eq!(NiceFloat(primitive_float_asinh(-x)), NiceFloat(-asinh));
+    });
+}
+
+#[test]
+fn primitive_float_asinh_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_asinh_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_asinh_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_asinh_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", 0.8813736);
+    test::<f32>("-1", -0.8813736);
+    test::<f32>("1/2", 0.4812118);
+    test::<f32>("-1/2", -0.4812118);
+    test::<f32>("1/3", 0.32745016);
+    test::<f32>("22/7", 1.8626792);
+    test::<f32>("3/2", 1.1947632);
+    test::<f32>("1000000", 14.508657);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", 0.881373587019543);
+    test::<f64>("-1", -0.881373587019543);
+    test::<f64>("1/2", 0.48121182505960347);
+    test::<f64>("-1/2", -0.48121182505960347);
+    test::<f64>("1/3", 0.32745015023725843);
+    test::<f64>("22/7", 1.86267921113461);
+    test::<f64>("3/2", 1.1947632172871092);
+    test::<f64>("1000000", 14.50865773852447);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_asinh_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let asinh = primitive_float_asinh_rational::<T>(&x);
+        assert!(!asinh.is_nan());
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_asinh_rational::<T>(&-&x)),
+                NiceFloat(-asinh)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_asinh_rational::<T>(&Rational::exact_from(
+                    x
+                ))),
+                NiceFloat(primitive_float_asinh(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_asinh_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_asinh_rational_properties_helper);
+}
@@ -0,0 +1,2464 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Atan, AtanAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeZero, One, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::atan::{
+    primitive_float_atan, primitive_float_atan_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::atan::{
+    rug_atan, rug_atan_prec, rug_atan_prec_round, rug_atan_rational_prec,
+    rug_atan_rational_prec_round, rug_atan_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_51, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_40,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_atan() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let atan = x.clone().atan();
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let atan_alt = (&x).atan();
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+
+        let mut atan_alt = x.clone();
+        atan_alt.atan_assign();
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&atan)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "2.0", "0x2.0#1");
+    test("-Infinity", "-Infinity", "-2.0", "-0x2.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+    );
+    test("123.0", "0x7b.0#7", "1.562", "0x1.90#7");
+    test("-123.0", "-0x7b.0#7", "-1.562", "-0x1.90#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+    );
+    test("1.3e30", "0x1.0E+25#1", "2.0", "0x2.0#1");
+    test("7.9e-31", "0x1.0E-25#1", "7.9e-31", "0x1.0E-25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.32175055439664219340140461435892",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+    );
+}
+
+#[test]
+fn test_atan_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec(prec);
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_assign(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "2.0", "0x2.0#1", Greater);
+    test("Infinity", "Infinity", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Less,
+    );
+    test("-Infinity", "-Infinity", 1, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.5703",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.5707963267948966192313216916397",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.78516", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.78516", "-0x0.c90#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.78539816339744830961566084581983",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.78516",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 10, "1.5625", "0x1.900#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.5626664246149527076293428124711",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "-2.0", "-0x2.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 10, "-1.5625", "-0x1.900#10", Greater);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-1.5626664246149527076293428124711",
+        "-0x1.900ae8244749e4ad7dc9fbdac#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.2617",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "1.2626272556789116721776121468157",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.2188",
+        "0x1.380#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.2182829050172776045279283982834",
+        "0x1.37e1637253388ca9ebdfe2c3e#100",
+        Less,
+    );
+    test("1.3e30", "0x1.0E+25#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.3e30", "0x1.0E+25#1", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.32178",
+        "0x0.526#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.32175055439664219340140461435892",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+        Greater,
+    );
+}
+
+#[test]
+fn atan_prec_fail() {
+    assert_panic!(Float::NAN.atan_prec(0));
+    assert_panic!(Float::NAN.atan_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.atan_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_atan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_round(rm);
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_round_ref(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_round_assign(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "1.0", "0x1.0#1", Less);
+    test("Infinity", "Infinity", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("Infinity", "Infinity", Down, "1.0", "0x1.0#1", Less);
+    test("Infinity", "Infinity", Up, "2.0", "0x2.0#1", Greater);
+    test("Infinity", "Infinity", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-Infinity", "-Infinity", Floor, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test("-Infinity", "-Infinity", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-Infinity", "-Infinity", Up, "-2.0", "-0x2.0#1", Less);
+    test("-Infinity", "-Infinity", Nearest, "-2.0", "-0x2.0#1", Less);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.78539816339744830961566084582062",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.78539816339744830961566084582062",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", Floor, "1.562", "0x1.90#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "1.578", "0x1.94#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "1.562", "0x1.90#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.578", "0x1.94#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "1.562", "0x1.90#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-1.578", "-0x1.94#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-1.562",
+        "-0x1.90#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-1.562", "-0x1.90#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-1.578", "-0x1.94#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-1.562",
+        "-0x1.90#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "1.2626272556789129",
+        "0x1.433b8a322ddd8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "1.2626272556789129",
+        "0x1.433b8a322ddd8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Greater,
+    );
+    test("1.3e30", "0x1.0E+25#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.3e30", "0x1.0E+25#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.3e30", "0x1.0E+25#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.3e30", "0x1.0E+25#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.3e30", "0x1.0E+25#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.32175055439664219340140461435853",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.32175055439664219340140461435892",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.32175055439664219340140461435853",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.32175055439664219340140461435892",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.32175055439664219340140461435892",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+        Greater,
+    );
+}
+
+#[test]
+fn atan_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(X.atan_round(Exact));
+    assert_panic!(X.atan_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.atan_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_atan_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_round_assign(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test("Infinity", "Infinity", 1, Down, "1.0", "0x1.0#1", Less);
+    test("Infinity", "Infinity", 1, Up, "2.0", "0x2.0#1", Greater);
+    test(
+        "Infinity", "Infinity", 1, Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Down,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test("-Infinity", "-Infinity", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.78516", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.78613",
+        "0x0.c94#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.78516", "0x0.c90#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.78613", "0x0.c94#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.78516", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.78539816339744830961566084582062",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.78613",
+        "-0x0.c94#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.78516",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.78516",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.78613", "-0x0.c94#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.78516",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.78539816339744830961566084582062",
+        "-0x0.c90fdaa22168c234c4c6628b9#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.78539816339744830961566084581983",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.78539816339744830961566084581983",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.78516",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.78613",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.78516",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.78613",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.78516",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.78539816339744830961566084582062",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 10, Floor, "1.5625", "0x1.900#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.5645",
+        "0x1.908#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "1.5625", "0x1.900#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "1.5645", "0x1.908#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.5625",
+        "0x1.900#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "1.5626664246149527076293428124711",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "1.5626664246149527076293428124727",
+        "0x1.900ae8244749e4ad7dc9fbdae#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "1.5626664246149527076293428124711",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-123.0", "-0x7b.0#7", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-1.5645",
+        "-0x1.908#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-1.5625",
+        "-0x1.900#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-1.5625",
+        "-0x1.900#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "-1.5645",
+        "-0x1.908#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-1.5625",
+        "-0x1.900#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "-1.5626664246149527076293428124727",
+        "-0x1.900ae8244749e4ad7dc9fbdae#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "-1.5626664246149527076293428124711",
+        "-0x1.900ae8244749e4ad7dc9fbdac#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "-1.5626664246149527076293428124711",
+        "-0x1.900ae8244749e4ad7dc9fbdac#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "1.2617",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.2637",
+        "0x1.438#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "1.2617",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.2637",
+        "0x1.438#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.2617",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "1.2626272556789116721776121468141",
+        "0x1.433b8a322ddd29618168a21c8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "1.2626272556789116721776121468157",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "1.2626272556789116721776121468157",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.2168",
+        "0x1.378#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.2188",
+        "0x1.380#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.2168",
+        "0x1.378#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.2188",
+        "0x1.380#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.2188",
+        "0x1.380#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "1.2182829050172776045279283982834",
+        "0x1.37e1637253388ca9ebdfe2c3e#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "1.2182829050172776045279283982850",
+        "0x1.37e1637253388ca9ebdfe2c40#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "1.2182829050172776045279283982834",
+        "0x1.37e1637253388ca9ebdfe2c3e#100",
+        Less,
+    );
+    test("1.3e30", "0x1.0E+25#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("1.3e30", "0x1.0E+25#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.3e30", "0x1.0E+25#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Floor,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Ceiling,
+        "1.5723",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Down,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Up,
+        "1.5723",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        10,
+        Nearest,
+        "1.5703",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        Floor,
+        "1.5707963267948966192313216916381",
+        "0x1.921fb54442d18469898cc516e#100",
+        Less,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        Ceiling,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "1.3e30",
+        "0x1.0E+25#1",
+        100,
+        Nearest,
+        "1.5707963267948966192313216916397",
+        "0x1.921fb54442d18469898cc5170#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "7.8886090522101180541172856528216e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.32129",
+        "0x0.524#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.32178",
+        "0x0.526#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.32129",
+        "0x0.524#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.32178",
+        "0x0.526#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.32178",
+        "0x0.526#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.32175055439664219340140461435853",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.32175055439664219340140461435892",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.32175055439664219340140461435892",
+        "0x0.525e3e8c9a7b84920cd43f9528#100",
+        Greater,
+    );
+}
+
+#[test]
+fn atan_prec_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).atan_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).atan_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atan_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.atan_prec_round(1, Exact));
+    assert_panic!(X.atan_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.atan_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_atan_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (atan, o) = Float::atan_rational_prec(x.clone(), prec);
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, out_o);
+
+        let (atan, o) = Float::atan_rational_prec_ref(&x, prec);
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_atan, rug_o) = rug_atan_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Greater);
+    test("1", 10, "0.78516", "0x0.c90#10", Less);
+    test(
+        "1",
+        100,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("-1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, "-0.78516", "-0x0.c90#10", Greater);
+    test(
+        "-1",
+        100,
+        "-0.78539816339744830961566084581983",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, "0.46387", "0x0.76c#10", Greater);
+    test(
+        "1/2",
+        100,
+        "0.46364760900080611621425623146131",
+        "0x0.76b19c1586ed3da2b7f222f660#100",
+        Greater,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.32178", "0x0.526#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.32175055439664219340140461435853",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Less,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.32178", "-0x0.526#10", Less);
+    test(
+        "-1/3",
+        100,
+        "-0.32175055439664219340140461435853",
+        "-0x0.525e3e8c9a7b84920cd43f9520#100",
+        Greater,
+    );
+    test("22/7", 1, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, "1.2637", "0x1.438#10", Greater);
+    test(
+        "22/7",
+        100,
+        "1.2627435457711202143021320542352",
+        "0x1.4343293852713db73e6a80f00#100",
+        Greater,
+    );
+    test("1000000", 1, "2.0", "0x2.0#1", Greater);
+    test("1000000", 10, "1.5703", "0x1.920#10", Less);
+    test(
+        "1000000",
+        100,
+        "1.5707953267948966195646550249723",
+        "0x1.921fa47d4b30ce822275563fc#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn atan_rational_prec_fail() {
+    Float::atan_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn atan_rational_prec_ref_fail() {
+    Float::atan_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_atan_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (atan, o) = Float::atan_rational_prec_round(x.clone(), prec, rm);
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, out_o);
+
+        let (atan, o) = Float::atan_rational_prec_round_ref(&x, prec, rm);
+        assert!(atan.is_valid());
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", 10, Floor, "0.78516", "0x0.c90#10", Less);
+    test("1", 10, Ceiling, "0.78613", "0x0.c94#10", Greater);
+    test("1", 10, Down, "0.78516", "0x0.c90#10", Less);
+    test("1", 10, Up, "0.78613", "0x0.c94#10", Greater);
+    test("1", 10, Nearest, "0.78516", "0x0.c90#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("-1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, Floor, "-0.78613", "-0x0.c94#10", Less);
+    test("-1", 10, Ceiling, "-0.78516", "-0x0.c90#10", Greater);
+    test("-1", 10, Down, "-0.78516", "-0x0.c90#10", Greater);
+    test("-1", 10, Up, "-0.78613", "-0x0.c94#10", Less);
+    test("-1", 10, Nearest, "-0.78516", "-0x0.c90#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.78539816339744830961566084581983",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, Floor, "0.46338", "0x0.76a#10", Less);
+    test("1/2", 10, Ceiling, "0.46387", "0x0.76c#10", Greater);
+    test("1/2", 10, Down, "0.46338", "0x0.76a#10", Less);
+    test("1/2", 10, Up, "0.46387", "0x0.76c#10", Greater);
+    test("1/2", 10, Nearest, "0.46387", "0x0.76c#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.46364760900080611621425623146131",
+        "0x0.76b19c1586ed3da2b7f222f660#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.32129", "0x0.524#10", Less);
+    test("1/3", 10, Ceiling, "0.32178", "0x0.526#10", Greater);
+    test("1/3", 10, Down, "0.32129", "0x0.524#10", Less);
+    test("1/3", 10, Up, "0.32178", "0x0.526#10", Greater);
+    test("1/3", 10, Nearest, "0.32178", "0x0.526#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.32175055439664219340140461435853",
+        "0x0.525e3e8c9a7b84920cd43f9520#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, Floor, "-0.32178", "-0x0.526#10", Less);
+    test("-1/3", 10, Ceiling, "-0.32129", "-0x0.524#10", Greater);
+    test("-1/3", 10, Down, "-0.32129", "-0x0.524#10", Greater);
+    test("-1/3", 10, Up, "-0.32178", "-0x0.526#10", Less);
+    test("-1/3", 10, Nearest, "-0.32178", "-0x0.526#10", Less);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-0.32175055439664219340140461435853",
+        "-0x0.525e3e8c9a7b84920cd43f9520#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, Floor, "1.2617", "0x1.430#10", Less);
+    test("22/7", 10, Ceiling, "1.2637", "0x1.438#10", Greater);
+    test("22/7", 10, Down, "1.2617", "0x1.430#10", Less);
+    test("22/7", 10, Up, "1.2637", "0x1.438#10", Greater);
+    test("22/7", 10, Nearest, "1.2637", "0x1.438#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "1.2627435457711202143021320542352",
+        "0x1.4343293852713db73e6a80f00#100",
+        Greater,
+    );
+    test("1000000", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1000000", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1000000", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1000000", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1000000", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1000000", 10, Floor, "1.5703", "0x1.920#10", Less);
+    test("1000000", 10, Ceiling, "1.5723", "0x1.928#10", Greater);
+    test("1000000", 10, Down, "1.5703", "0x1.920#10", Less);
+    test("1000000", 10, Up, "1.5723", "0x1.928#10", Greater);
+    test("1000000", 10, Nearest, "1.5703", "0x1.920#10", Less);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "1.5707953267948966195646550249723",
+        "0x1.921fa47d4b30ce822275563fc#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn atan_rational_prec_round_fail_1() {
+    Float::atan_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn atan_rational_prec_round_fail_2() {
+    Float::atan_rational_prec_round(Rational::ONE, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn atan_rational_prec_round_ref_fail() {
+    Float::atan_rational_prec_round_ref(&Rational::ONE, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn atan_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (atan, o) = x.clone().atan_prec_round(prec, rm);
+    assert!(atan.is_valid());
+
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(atan.is_nan(), x.is_nan());
+    assert!(atan.is_nan() || (atan >= -2i32 && atan <= 2u32));
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(prec));
+    }
+
+    let (atan_alt, o_alt) = (-&x).atan_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-atan_alt), ComparableFloat(atan.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (atan_alt, o_alt) = Float::atan_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.atan_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(atan.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.atan_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn atan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_40().test_properties(|(x, prec, rm)| {
+        atan_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (atan, o) = Float::NAN.atan_prec_round(prec, rm);
+        assert!(atan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (atan, o) = Float::ZERO.atan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (atan, o) = Float::NEGATIVE_ZERO.atan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn atan_prec_properties_helper(x: Float, prec: u64) {
+    let (atan, o) = x.clone().atan_prec(prec);
+    assert!(atan.is_valid());
+
+    let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan)),
+        ComparableFloatRef(&atan)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(atan.is_nan() || (atan >= -2i32 && atan <= 2u32));
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(prec));
+    }
+
+    let (atan_alt, o_alt) = (-&x).atan_prec(prec);
+    assert_eq!(ComparableFloat(-atan_alt), ComparableFloat(atan.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn atan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        atan_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (atan, o) = Float::NAN.atan_prec(prec);
+        assert!(atan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (atan, o) = Float::ZERO.atan_prec(prec);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (atan, o) = Float::NEGATIVE_ZERO.atan_prec(prec);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn atan_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (atan, o) = x.clone().atan_round(rm);
+    assert!(atan.is_valid());
+
+    let (atan_alt, o_alt) = x.atan_round_ref(rm);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    assert!(atan.is_nan() || (atan >= -2i32 && atan <= 2u32));
+
+    let (atan_alt, o_alt) = (-&x).atan_round(-rm);
+    assert_eq!(ComparableFloat(-atan_alt), ComparableFloat(atan.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.atan_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(atan.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.atan_round_ref(Exact));
+    }
+}
+
+#[test]
+fn atan_round_properties() {
+    float_rounding_mode_pair_gen_var_51().test_properties(|(x, rm)| {
+        atan_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (atan, o) = Float::NAN.atan_round(rm);
+        assert!(atan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (atan, o) = Float::ZERO.atan_round(rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (atan, o) = Float::NEGATIVE_ZERO.atan_round(rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn atan_properties_helper(x: Float) {
+    let atan = x.clone().atan();
+    assert!(atan.is_valid());
+
+    let atan_alt = (&x).atan();
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+
+    let mut x_alt = x.clone();
+    x_alt.atan_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+
+    let atan_alt = x.atan_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    let atan_alt = x.atan_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+
+    assert_eq!(atan.is_nan(), x.is_nan());
+    assert!(atan.is_nan() || (atan >= -2i32 && atan <= 2u32));
+
+    let rug_atan = rug_atan(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan)),
+        ComparableFloatRef(&atan),
+    );
+}
+
+#[test]
+fn atan_properties() {
+    float_gen().test_properties(|x| {
+        atan_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn atan_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (atan, o) = Float::atan_rational_prec_round(x.clone(), prec, rm);
+    assert!(atan.is_valid());
+
+    let (atan_alt, o_alt) = Float::atan_rational_prec_round_ref(&x, prec, rm);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan, rug_o) = rug_atan_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!atan.is_nan());
+    assert!(atan.is_nan() || (atan >= -2i32 && atan <= 2u32));
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (atan_alt, o_alt) = Float::atan_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-atan_alt), ComparableFloat(atan.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::atan_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(atan.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::atan_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn atan_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        atan_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (atan, o) = Float::atan_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn atan_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (atan, o) = Float::atan_rational_prec(x.clone(), prec);
+    assert!(atan.is_valid());
+
+    let (atan_alt, o_alt) = Float::atan_rational_prec_ref(&x, prec);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (atan_alt, o_alt) = Float::atan_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (rug_atan, rug_o) = rug_atan_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan)),
+        ComparableFloatRef(&atan)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(atan.is_nan() || (atan >= -2i32 && atan <= 2u32));
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn atan_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        atan_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (atan, o) = Float::atan_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_atan() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_atan(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, core::f32::consts::FRAC_PI_2);
+    test::<f32>(f32::NEGATIVE_INFINITY, -core::f32::consts::FRAC_PI_2);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, core::f32::consts::FRAC_PI_4);
+    test::<f32>(-1.0, -core::f32::consts::FRAC_PI_4);
+    test::<f32>(0.5, 0.4636476);
+    test::<f32>(-0.5, -0.4636476);
+    test::<f32>(2.0, 1.1071488);
+    test::<f32>(10.0, 1.4711276);
+    test::<f32>(0.001, 0.0009999997);
+    test::<f32>(10000000000.0, core::f32::consts::FRAC_PI_2);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, core::f64::consts::FRAC_PI_2);
+    test::<f64>(f64::NEGATIVE_INFINITY, -core::f64::consts::FRAC_PI_2);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, core::f64::consts::FRAC_PI_4);
+    test::<f64>(-1.0, -core::f64::consts::FRAC_PI_4);
+    test::<f64>(0.5, 0.4636476090008061);
+    test::<f64>(-0.5, -0.4636476090008061);
+    test::<f64>(2.0, 1.1071487177940904);
+    test::<f64>(10.0, 1.4711276743037347);
+    test::<f64>(0.001, 0.0009999996666668668);
+    test::<f64>(10000000000.0, 1.5707963266948965);
+    test::<f64>(1.0e300, core::f64::consts::FRAC_PI_2);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_atan_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let atan = primitive_float_atan(x);
+        assert_eq!(atan.is_nan(), x.is_nan());
+        assert!(atan.is_nan() || (atan >= -T::TWO && atan <= T::TWO));
+        assert_eq!(NiceFloat(primitive_float_atan(-x)), NiceFloat(-atan));
+    });
+}
+
+#[test]
+fn primitive_float_atan_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_atan_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_atan_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_atan_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", core::f32::consts::FRAC_PI_4);
+    test::<f32>("-1", -core::f32::consts::FRAC_PI_4);
+    test::<f32>("1/2", 0.4636476);
+    test::<f32>("-1/2", -0.4636476);
+    test::<f32>("1/3", 0.32175055);
+    test::<f32>("22/7", 1.2627436);
+    test::<f32>("3/2", 0.98279375);
+    test::<f32>("1000000", 1.5707953);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", core::f64::consts::FRAC_PI_4);
+    test::<f64>("-1", -core::f64::consts::FRAC_PI_4);
+    test::<f64>("1/2", 0.4636476090008061);
+    test::<f64>("-1/2", -0.4636476090008061);
+    test::<f64>("1/3", 0.3217505543966422);
+    test::<f64>("22/7", 1.2627435457711202);
+    test::<f64>("3/2", 0.982793723247329);
+    test::<f64>("1000000", 1.5707953267948966);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_atan_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let atan = primitive_float_atan_rational::<T>(&x);
+        assert!(!atan.is_nan());
+        assert!(atan.is_nan() || (atan >= -T::TWO && atan <= T::TWO));
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_atan_rational::<T>(&-&x)),
+                NiceFloat(-atan)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_atan_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_atan(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_atan_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_atan_rational_properties_helper);
+}
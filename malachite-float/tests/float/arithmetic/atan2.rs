@@ -0,0 +1,5100 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Atan2, Atan2Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeZero, One, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_pair_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::atan2::primitive_float_atan2;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::atan2::{
+    rug_atan2, rug_atan2_prec, rug_atan2_prec_round, rug_atan2_round,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_45,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_26, float_float_unsigned_triple_gen_var_1,
+    float_pair_gen,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::max;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_atan2() {
+    let test = |s: &str, s_hex: &str, t: &str, t_hex: &str, out: &str, out_hex: &str| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let atan2 = y.clone().atan2(x.clone());
+        assert!(atan2.is_valid());
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let atan2_alt = y.clone().atan2(&x);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        let atan2_alt = (&y).atan2(x.clone());
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        let atan2_alt = (&y).atan2(&x);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+
+        let mut atan2_alt = y.clone();
+        atan2_alt.atan2_assign(x.clone());
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        let mut atan2_alt = y.clone();
+        atan2_alt.atan2_assign(&x);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2(
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&x)
+            ))),
+            ComparableFloatRef(&atan2),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "0.0", "0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("NaN", "NaN", "-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "1.0", "0x1.0#1",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "2.0",
+        "0x2.0#1",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("Infinity", "Infinity", "-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("Infinity", "Infinity", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        "1.578",
+        "0x1.94#7",
+    );
+    test("-Infinity", "-Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test("-Infinity", "-Infinity", "0.0", "0x0.0", "-2.0", "-0x2.0#1");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-1.5707963267948966",
+        "-0x1.921fb54442d18#53",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        "-1.578",
+        "-0x1.94#7",
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity", "4.0", "0x4.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "4.0", "0x4.0#1");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-1.0", "-0x1.0#1", "4.0", "0x4.0#1");
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.0",
+        "0x0.0",
+    );
+    test("0.0", "0x0.0", "-123.0", "-0x7b.0#7", "3.156", "0x3.28#7");
+    test("-0.0", "-0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        "-4.0",
+        "-0x4.0#1",
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "-4.0", "-0x4.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1", "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", "-4.0", "-0x4.0#1");
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.0",
+        "-0x0.0",
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        "-3.156",
+        "-0x3.28#7",
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "4.0", "0x4.0#1");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.35251342177761902",
+        "0x0.5a3e51d1ef98f8#53",
+    );
+    test("1.0", "0x1.0#1", "-123.0", "-0x7b.0#7", "3.125", "0x3.20#7");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "-4.0",
+        "-0x4.0#1",
+    );
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "-2.0", "-0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", "-2.0", "-0x2.0#1");
+    test("-1.0", "-0x1.0#1", "1.0", "0x1.0#1", "-1.0", "-0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.35251342177761902",
+        "-0x0.5a3e51d1ef98f8#53",
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        "-3.125",
+        "-0x3.20#7",
+    );
+    test("123.0", "0x7b.0#7", "NaN", "NaN", "NaN", "NaN");
+    test("123.0", "0x7b.0#7", "Infinity", "Infinity", "0.0", "0x0.0");
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        "3.156",
+        "0x3.28#7",
+    );
+    test("123.0", "0x7b.0#7", "0.0", "0x0.0", "1.578", "0x1.94#7");
+    test("123.0", "0x7b.0#7", "-0.0", "-0x0.0", "1.578", "0x1.94#7");
+    test("123.0", "0x7b.0#7", "1.0", "0x1.0#1", "1.562", "0x1.90#7");
+    test("123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", "1.578", "0x1.94#7");
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.5487000713585504",
+        "0x1.8c779b9dcc3f4#53",
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        "2.344",
+        "0x2.58#7",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        "1.5707963267948966",
+        "0x1.921fb54442d18#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        "1.5707963267948966",
+        "0x1.921fb54442d18#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        "1.8789653979108820",
+        "0x1.e103e05657c60#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.85751178635584624",
+        "0x0.db85e4765538d8#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        "3.1160568018205979",
+        "0x3.1db5e6084c3e#50",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-Infinity",
+        "-Infinity",
+        "4.0",
+        "0x4.0#1",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("7.9e-31", "0x1.0E-25#1", "-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        "4.0",
+        "0x4.0#1",
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "2.9020570897470396e-31",
+        "0x5.e2d58d8b3bce0E-26#53",
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        "3.156",
+        "0x3.28#7",
+    );
+}
+
+#[test]
+fn test_atan2_prec() {
+    let test = |s: &str,
+                s_hex: &str,
+                t: &str,
+                t_hex: &str,
+                prec: u64,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_prec(x.clone(), prec);
+        assert!(atan2.is_valid());
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_val_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_val(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_assign(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_assign_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, "4.0", "0x4.0#1", Greater,
+    );
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.78516",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084581983",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.3555",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "2.3561944901923449288469825374595",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.35254",
+        "0x0.5a4#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.35251342177761901470339329335586",
+        "0x0.5a3e51d1ef98f7bf9dace25318#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "3.1328",
+        "0x3.22#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "3.1334627514098493268606645041108",
+        "0x3.222a9d688a1b69170756c0f1c#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", 1, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", 1, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", 1, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "-0.78516",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "-0.78539816339744830961566084581983",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-2.3555",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-2.3561944901923449288469825374595",
+        "-0x2.5b2f8fe6643a469e4e5327a28#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.35254",
+        "-0x0.5a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.35251342177761901470339329335586",
+        "-0x0.5a3e51d1ef98f7bf9dace25318#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "-3.1328",
+        "-0x3.22#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-3.1334627514098493268606645041108",
+        "-0x3.222a9d688a1b69170756c0f1c#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.5625",
+        "0x1.900#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.5626664246149527076293428124711",
+        "0x1.900ae8244749e4ad7dc9fbdac#100",
+        Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "1.5781",
+        "0x1.940#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "1.5789262289748405308333005708082",
+        "0x1.943482643e592425954f8e534#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.5488",
+        "0x1.8c8#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.5487000713585502861908016676918",
+        "0x1.8c779b9dcc3f3a23fe90f02ea#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "2.3555",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "2.3561944901923449288469825374595",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.2617",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.2626272556789116721776121468157",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "1.8789",
+        "0x1.e10#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "1.8789653979108815662850312364637",
+        "0x1.e103e05657c5df7191b0e8116#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.85742",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.85751178635584627203347630109990",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "3.1172",
+        "0x3.1e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "3.1160568018205979321197738526219",
+        "0x3.1db5e6084c3e0594ed1457980#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "0.0",
+        "0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "3.1406",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "2.9005e-31",
+        "0x5.e2E-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "2.9020570897470396487013362315412e-31",
+        "0x5.e2d58d8b3bce083a29193a888E-26#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "3.1406",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+}
+
+#[test]
+fn atan2_prec_fail() {
+    assert_panic!(Float::from(3).atan2_prec(Float::from(4), 0));
+    assert_panic!(Float::from(3).atan2_prec_ref_ref(&Float::from(4), 0));
+}
+
+#[test]
+fn test_atan2_round() {
+    let test = |s: &str,
+                s_hex: &str,
+                t: &str,
+                t_hex: &str,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_round(x.clone(), rm);
+        assert!(atan2.is_valid());
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_round_val_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let (atan2_alt, o_alt) = y.atan2_round_ref_val(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let (atan2_alt, o_alt) = y.atan2_round_ref_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_round_assign(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_round_assign_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) =
+                rug_atan2_round(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", Floor, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "1.562",
+        "0x1.90#7",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-1.5707963267948968",
+        "-0x1.921fb54442d19#53",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "-1.578",
+        "-0x1.94#7",
+        Less,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "3.125",
+        "0x3.20#7",
+        Less,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "-3.156",
+        "-0x3.28#7",
+        Less,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Down, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Up, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Nearest, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.35251342177761896",
+        "0x0.5a3e51d1ef98f4#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.35251342177761902",
+        "0x0.5a3e51d1ef98f8#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.35251342177761896",
+        "0x0.5a3e51d1ef98f4#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.35251342177761902",
+        "0x0.5a3e51d1ef98f8#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.35251342177761902",
+        "0x0.5a3e51d1ef98f8#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "3.125",
+        "0x3.20#7",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "3.156",
+        "0x3.28#7",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "3.125",
+        "0x3.20#7",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "3.156",
+        "0x3.28#7",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "3.125",
+        "0x3.20#7",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", Floor, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", Floor, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Up, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Ceiling, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Down, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Up, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.35251342177761902",
+        "-0x0.5a3e51d1ef98f8#53",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "-0.35251342177761896",
+        "-0x0.5a3e51d1ef98f4#53",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "-0.35251342177761896",
+        "-0x0.5a3e51d1ef98f4#53",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "-0.35251342177761902",
+        "-0x0.5a3e51d1ef98f8#53",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "-0.35251342177761902",
+        "-0x0.5a3e51d1ef98f8#53",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "-3.156",
+        "-0x3.28#7",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-3.125",
+        "-0x3.20#7",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "-3.125",
+        "-0x3.20#7",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "-3.156",
+        "-0x3.28#7",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-3.125",
+        "-0x3.20#7",
+        Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "3.125",
+        "0x3.20#7",
+        Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", Floor, "1.562", "0x1.90#7", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", Floor, "1.562", "0x1.90#7", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Floor, "1.562", "0x1.90#7", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Ceiling, "1.578", "0x1.94#7", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Down, "1.562", "0x1.90#7", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Up, "1.578", "0x1.94#7", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Nearest, "1.562", "0x1.90#7", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", Floor, "1.578", "0x1.94#7", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", Ceiling, "1.594", "0x1.98#7", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", Down, "1.578", "0x1.94#7", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", Up, "1.594", "0x1.98#7", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", Nearest, "1.578", "0x1.94#7", Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.5487000713585501",
+        "0x1.8c779b9dcc3f3#53",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.5487000713585504",
+        "0x1.8c779b9dcc3f4#53",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.5487000713585501",
+        "0x1.8c779b9dcc3f3#53",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.5487000713585504",
+        "0x1.8c779b9dcc3f4#53",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.5487000713585504",
+        "0x1.8c779b9dcc3f4#53",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "2.344",
+        "0x2.58#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "2.375",
+        "0x2.60#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "2.344",
+        "0x2.58#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "2.375",
+        "0x2.60#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "2.344",
+        "0x2.58#7",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        Floor,
+        "1.5707963267948966",
+        "0x1.921fb54442d18#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "1.5707963267948966",
+        "0x1.921fb54442d18#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Ceiling,
+        "1.2626272556789129",
+        "0x1.433b8a322ddd8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Down,
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Up,
+        "1.2626272556789129",
+        "0x1.433b8a322ddd8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "1.2626272556789111",
+        "0x1.433b8a322ddd0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        Floor,
+        "1.8789653979108802",
+        "0x1.e103e05657c58#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        Ceiling,
+        "1.8789653979108820",
+        "0x1.e103e05657c60#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        Down,
+        "1.8789653979108802",
+        "0x1.e103e05657c58#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        Up,
+        "1.8789653979108820",
+        "0x1.e103e05657c60#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        Nearest,
+        "1.8789653979108820",
+        "0x1.e103e05657c60#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.85751178635584624",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.85751178635584635",
+        "0x0.db85e4765538e0#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.85751178635584624",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.85751178635584635",
+        "0x0.db85e4765538e0#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.85751178635584624",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "3.1160568018205979",
+        "0x3.1db5e6084c3e#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "3.1160568018206014",
+        "0x3.1db5e6084c3f#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "3.1160568018205979",
+        "0x3.1db5e6084c3e#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "3.1160568018206014",
+        "0x3.1db5e6084c3f#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "3.1160568018205979",
+        "0x3.1db5e6084c3e#50",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "NaN",
+        "NaN",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "Infinity",
+        "Infinity",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "0.0",
+        "0x0.0",
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "2.9020570897470396e-31",
+        "0x5.e2d58d8b3bce0E-26#53",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "2.9020570897470400e-31",
+        "0x5.e2d58d8b3bce4E-26#53",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "2.9020570897470396e-31",
+        "0x5.e2d58d8b3bce0E-26#53",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "2.9020570897470400e-31",
+        "0x5.e2d58d8b3bce4E-26#53",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "2.9020570897470396e-31",
+        "0x5.e2d58d8b3bce0E-26#53",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "3.125",
+        "0x3.20#7",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "3.156",
+        "0x3.28#7",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "3.125",
+        "0x3.20#7",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "3.156",
+        "0x3.28#7",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "3.156",
+        "0x3.28#7",
+        Greater,
+    );
+}
+
+#[test]
+fn atan2_round_fail() {
+    assert_panic!(Float::from(1).atan2_round(Float::from(1), Exact));
+    assert_panic!(Float::from(1).atan2_round_ref_ref(&Float::from(1), Exact));
+}
+
+#[test]
+fn test_atan2_prec_round() {
+    let test = |s: &str,
+                s_hex: &str,
+                t: &str,
+                t_hex: &str,
+                prec: u64,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_prec_round(x.clone(), prec, rm);
+        assert!(atan2.is_valid());
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_round_val_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(x.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign(x.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&x),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "-0.0", "-0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, Floor, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "-0.0", "-0x0.0", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, Ceiling, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Ceiling, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.78516",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.78613",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.78516",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "2.3555",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "2.3594",
+        "0x2.5c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "2.3555",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.35205",
+        "0x0.5a2#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.35254",
+        "0x0.5a4#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.35254",
+        "0x0.5a4#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "3.1328",
+        "0x3.22#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "3.1367",
+        "0x3.23#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "3.1328",
+        "0x3.22#10",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", 1, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", 1, Ceiling, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", 1, Floor, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", 1, Ceiling, "-1.0", "-0x1.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", 1, Floor, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", 1, Ceiling, "-1.0", "-0x1.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "-0.78613",
+        "-0x0.c94#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "-0.78516",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "-0.78516",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Ceiling, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Nearest, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-2.3594",
+        "-0x2.5c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-2.3555",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-2.3555",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-0.35254",
+        "-0x0.5a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-0.35205",
+        "-0x0.5a2#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-0.35254",
+        "-0x0.5a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-3.1367",
+        "-0x3.23#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-3.1328",
+        "-0x3.22#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-3.1328",
+        "-0x3.22#10",
+        Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "1.5625",
+        "0x1.900#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.5645",
+        "0x1.908#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "1.5625",
+        "0x1.900#10",
+        Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-1.0", "-0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "1.5781",
+        "0x1.940#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "1.5801",
+        "0x1.948#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "1.5781",
+        "0x1.940#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.5469",
+        "0x1.8c0#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.5488",
+        "0x1.8c8#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.5488",
+        "0x1.8c8#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "2.3555",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "2.3594",
+        "0x2.5c#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "2.3555",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "1.2617",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.2637",
+        "0x1.438#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "1.2617",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "1.8789",
+        "0x1.e10#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "1.8809",
+        "0x1.e18#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "1.8789",
+        "0x1.e10#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.85742",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.85840",
+        "0x0.dbc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.85742",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "3.1133",
+        "0x3.1d#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "3.1172",
+        "0x3.1e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "3.1172",
+        "0x3.1e#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "NaN",
+        "NaN",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "NaN",
+        "NaN",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "Infinity",
+        "Infinity",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "Infinity",
+        "Infinity",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "0.0",
+        "0x0.0",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "0.0",
+        "0x0.0",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "3.1406",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "3.1445",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "3.1406",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "2.9005e-31",
+        "0x5.e2E-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "2.9043e-31",
+        "0x5.e4E-26#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "2.9005e-31",
+        "0x5.e2E-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "3.1406",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "3.1445",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "3.1406",
+        "0x3.24#10",
+        Less,
+    );
+}
+
+#[test]
+fn atan2_prec_round_fail() {
+    assert_panic!(Float::from(3).atan2_prec_round(Float::from(4), 0, Floor));
+    assert_panic!(Float::from(3).atan2_prec_round_ref_ref(&Float::from(4), 0, Floor));
+    // atan2(1, 1) = pi / 4 is irrational
+    assert_panic!(Float::from(1).atan2_prec_round(Float::from(1), 10, Exact));
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn atan2_prec_round_properties_helper(y: Float, x: Float, prec: u64, rm: RoundingMode) {
+    let (atan2, o) = y.clone().atan2_prec_round(x.clone(), prec, rm);
+    assert!(atan2.is_valid());
+    let (atan2_alt, o_alt) = y.clone().atan2_prec_round_val_ref(&x, prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(x.clone(), prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_prec_round_assign(x.clone(), prec, rm);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_prec_round_assign_ref(&x, prec, rm);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan2, rug_o) = rug_atan2_prec_round(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(atan2.is_nan(), y.is_nan() || x.is_nan());
+    assert!(atan2.is_nan() || (atan2 >= -4i32 && atan2 <= 4u32));
+    if atan2.is_normal() {
+        assert_eq!(atan2.get_prec(), Some(prec));
+    }
+
+    // atan2 is odd in y
+    let (atan2_alt, o_alt) = (-&y).atan2_prec_round_ref_ref(&x, prec, -rm);
+    assert_eq!(ComparableFloat(-atan2_alt), ComparableFloat(atan2.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if rm != Exact {
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(Float::ONE, prec, rm);
+        let (atan, o_atan) = y.atan_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloat(atan2_alt), ComparableFloat(atan));
+        assert_eq!(o_alt, o_atan);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(atan2.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(y.atan2_prec_round_ref_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn atan2_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_26().test_properties(
+        |(y, x, prec, rm)| {
+            atan2_prec_round_properties_helper(y, x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (atan2, o) = Float::NAN.atan2_prec_round(Float::ONE, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Equal);
+        let (atan2, o) = Float::ONE.atan2_prec_round(Float::NAN, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Equal);
+
+        for y in [Float::ZERO, Float::NEGATIVE_ZERO] {
+            let (atan2, o) = y.atan2_prec_round_ref_val(Float::ZERO, prec, rm);
+            assert_eq!(ComparableFloat(atan2), ComparableFloat(y.clone()));
+            assert_eq!(o, Equal);
+            let (atan2, o) = y.atan2_prec_round_ref_val(Float::ONE, prec, rm);
+            assert_eq!(ComparableFloat(atan2), ComparableFloat(y));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_prec_properties_helper(y: Float, x: Float, prec: u64) {
+    let (atan2, o) = y.clone().atan2_prec(x.clone(), prec);
+    assert!(atan2.is_valid());
+    let (atan2_alt, o_alt) = y.clone().atan2_prec_val_ref(&x, prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let (atan2_alt, o_alt) = y.atan2_prec_ref_val(x.clone(), prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let (atan2_alt, o_alt) = y.atan2_prec_ref_ref(&x, prec);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_prec_assign(x.clone(), prec);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_prec_assign_ref(&x, prec);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (rug_atan2, rug_o) = rug_atan2_prec(
+        &rug::Float::exact_from(&y),
+        &rug::Float::exact_from(&x),
+        prec,
+    );
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan2)),
+        ComparableFloatRef(&atan2)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+fn atan2_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(y, x, prec)| {
+        atan2_prec_properties_helper(y, x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_round_properties_helper(y: Float, x: Float, rm: RoundingMode) {
+    let (atan2, o) = y.clone().atan2_round(x.clone(), rm);
+    assert!(atan2.is_valid());
+    let (atan2_alt, o_alt) = y.clone().atan2_round_val_ref(&x, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let (atan2_alt, o_alt) = y.atan2_round_ref_val(x.clone(), rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let (atan2_alt, o_alt) = y.atan2_round_ref_ref(&x, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_round_assign(x.clone(), rm);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_round_assign_ref(&x, rm);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let prec = max(y.significant_bits(), x.significant_bits());
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan2, rug_o) = rug_atan2_round(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn atan2_round_properties() {
+    float_float_rounding_mode_triple_gen_var_45().test_properties(|(y, x, rm)| {
+        atan2_round_properties_helper(y, x, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_properties_helper(y: Float, x: Float) {
+    let atan2 = y.clone().atan2(x.clone());
+    assert!(atan2.is_valid());
+    let atan2_alt = y.clone().atan2(&x);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    let atan2_alt = (&y).atan2(x.clone());
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    let atan2_alt = (&y).atan2(&x);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    let mut atan2_alt = y.clone();
+    atan2_alt.atan2_assign(x.clone());
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    let mut atan2_alt = y.clone();
+    atan2_alt.atan2_assign(&x);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    let prec = max(y.significant_bits(), x.significant_bits());
+    let (atan2_alt, _) = y.atan2_prec_round_ref_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    let (atan2_alt, _) = y.clone().atan2_prec(x.clone(), prec);
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    let (atan2_alt, _) = y.clone().atan2_round(x.clone(), Nearest);
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    let rug_atan2 = rug_atan2(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan2)),
+        ComparableFloatRef(&atan2)
+    );
+}
+
+#[test]
+fn atan2_properties() {
+    float_pair_gen().test_properties(|(y, x)| {
+        atan2_properties_helper(y, x);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_atan2() {
+    fn test<T: PrimitiveFloat>(y: T, x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_atan2(y, x)), NiceFloat(out));
+    }
+    test::<f32>(1.0, 1.0, core::f32::consts::FRAC_PI_4);
+    test::<f32>(1.0, -1.0, 2.3561945);
+    test::<f32>(-1.0, -1.0, -2.3561945);
+    test::<f32>(0.0, -1.0, core::f32::consts::PI);
+    test::<f32>(-0.0, -1.0, -core::f32::consts::PI);
+    test::<f32>(0.0, 0.0, 0.0);
+    test::<f32>(-0.0, -0.0, -core::f32::consts::PI);
+    test::<f32>(1.0, 0.0, core::f32::consts::FRAC_PI_2);
+    test::<f32>(f32::INFINITY, f32::NEGATIVE_INFINITY, 2.3561945);
+    test::<f32>(3.0, 4.0, 0.6435011);
+    test::<f32>(0.0, f32::INFINITY, 0.0);
+    test::<f32>(f32::NAN, 1.0, f32::NAN);
+
+    test::<f64>(1.0, 1.0, core::f64::consts::FRAC_PI_4);
+    test::<f64>(1.0, -1.0, 2.356194490192345);
+    test::<f64>(-1.0, -1.0, -2.356194490192345);
+    test::<f64>(0.0, -1.0, core::f64::consts::PI);
+    test::<f64>(-0.0, -1.0, -core::f64::consts::PI);
+    test::<f64>(0.0, 0.0, 0.0);
+    test::<f64>(-0.0, -0.0, -core::f64::consts::PI);
+    test::<f64>(1.0, 0.0, core::f64::consts::FRAC_PI_2);
+    test::<f64>(f64::INFINITY, f64::NEGATIVE_INFINITY, 2.356194490192345);
+    test::<f64>(3.0, 4.0, 0.6435011087932844);
+    test::<f64>(1.0e-300, 1.0e300, 0.0);
+    test::<f64>(f64::NAN, 1.0, f64::NAN);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_atan2_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_pair_gen::<T>().test_properties(|(y, x)| {
+        let atan2 = primitive_float_atan2(y, x);
+        assert_eq!(atan2.is_nan(), y.is_nan() || x.is_nan());
+        assert_eq!(NiceFloat(primitive_float_atan2(-y, x)), NiceFloat(-atan2));
+    });
+}
+
+#[test]
+fn primitive_float_atan2_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_atan2_properties_helper);
+}
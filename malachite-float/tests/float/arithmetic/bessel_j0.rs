@@ -0,0 +1,3036 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{BesselJ0, BesselJ0Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::bessel_j0::{
+    primitive_float_bessel_j0, primitive_float_bessel_j0_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::bessel_j0::{
+    rug_bessel_j0, rug_bessel_j0_prec, rug_bessel_j0_prec_round, rug_bessel_j0_rational_prec,
+    rug_bessel_j0_rational_prec_round, rug_bessel_j0_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_bessel_j0() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let j0 = x.clone().bessel_j0();
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+
+        let j0_alt = (&x).bessel_j0();
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+
+        let mut j0_alt = x.clone();
+        j0_alt.bessel_j0_assign();
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_bessel_j0(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&j0)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "1.0", "0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+    );
+    test("3.0", "0x3.0#2", "-0.25", "-0x0.4#2");
+    test("5.0", "0x5.0#3", "-0.19", "-0x0.30#3");
+    test("0.50", "0x0.8#1", "1.0", "0x1.0#1");
+    test("-0.50", "-0x0.8#1", "1.0", "0x1.0#1");
+    test("123.0", "0x7b.0#7", "-0.06836", "-0x0.118#7");
+    test("-123.50", "-0x7b.8#10", "-0.070435", "-0x0.1208#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.15047124201536768",
+        "-0x0.2685488771cb1a#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+    );
+    test("27.0", "0x1b.0#5", "0.0742", "0x0.13#5");
+    test("-6.0", "-0x6.0#2", "0.12", "0x0.2#2");
+    test("7.9e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+    );
+}
+
+#[test]
+fn test_bessel_j0_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (j0, o) = x.clone().bessel_j0_prec(prec);
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+        assert_eq!(o, o_out);
+
+        let (j0_alt, o_alt) = x.bessel_j0_prec_ref(prec);
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut j0_alt = x.clone();
+        let o_alt = j0_alt.bessel_j0_prec_assign(prec);
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_j0, rug_o) = rug_bessel_j0_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j0)),
+            ComparableFloatRef(&j0)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, "-0.25", "-0x0.4#1", Greater);
+    test("3.0", "0x3.0#2", 10, "-0.26025", "-0x0.42a#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "-0.26005195490193343762415469597741",
+        "-0x0.4292c3d1908e475d9419add8f8#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 1, "-0.12", "-0x0.2#1", Greater);
+    test("5.0", "0x5.0#3", 10, "-0.17749", "-0x0.2d7#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "-0.17759677131433830434739701307476",
+        "-0x0.2d76fb64ab9712e3b83d6b21f4#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, "1.0", "0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 10, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "-0.062", "-0x0.1#1", Greater);
+    test("123.0", "0x7b.0#7", 10, "-0.068604", "-0x0.1190#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-0.068545521193546547737230609609076",
+        "-0x0.118c3303d0454ab2a760527f40#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "-0.062", "-0x0.1#1", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        "-0.070435",
+        "-0x0.1208#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "-0.070484621663252288540298322254840",
+        "-0x0.120b47b8ea235658bd1e45adca#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "-0.30424217764409382934670801148677",
+        "-0x0.4de2d0bb0b92937ec28e65e940#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.15039",
+        "-0x0.268#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.15047124201536769288066414016639",
+        "-0x0.2685488771cb1a9c7ee015c8f4#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "2.1755917502468917268590552836393e-6",
+        "0x0.00002480186d3b8e3dab0c99aa4820#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, "0.062", "0x0.1#1", Less);
+    test("27.0", "0x1b.0#5", 10, "0.072754", "0x0.12a0#10", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        "0.072741918005887087584069883674339",
+        "0x0.129f36dee233842d787f1eb496#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, "0.12", "0x0.2#1", Less);
+    test("-6.0", "-0x6.0#2", 10, "0.15063", "0x0.269#10", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        "0.15064525725099693166232794894871",
+        "0x0.2690b00530c724ac355aaac6f0#100",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+}
+
+#[test]
+fn bessel_j0_prec_fail() {
+    assert_panic!(Float::NAN.bessel_j0_prec(0));
+    assert_panic!(Float::NAN.bessel_j0_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.bessel_j0_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_bessel_j0_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (j0, o) = x.clone().bessel_j0_round(rm);
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+        assert_eq!(o, o_out);
+
+        let (j0_alt, o_alt) = x.bessel_j0_round_ref(rm);
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut j0_alt = x.clone();
+        let o_alt = j0_alt.bessel_j0_round_assign(rm);
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_j0, rug_o) = rug_bessel_j0_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_j0)),
+                ComparableFloatRef(&j0),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.76519768655796655144971752610219",
+        "0x0.c3e3fedebdc77c1cf1a986115#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.76519768655796655144971752610219",
+        "0x0.c3e3fedebdc77c1cf1a986115#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", Floor, "-0.38", "-0x0.6#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "-0.25", "-0x0.4#2", Greater);
+    test("3.0", "0x3.0#2", Down, "-0.25", "-0x0.4#2", Greater);
+    test("3.0", "0x3.0#2", Up, "-0.38", "-0x0.6#2", Less);
+    test("3.0", "0x3.0#2", Nearest, "-0.25", "-0x0.4#2", Greater);
+    test("5.0", "0x5.0#3", Floor, "-0.19", "-0x0.30#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "-0.16", "-0x0.28#3", Greater);
+    test("5.0", "0x5.0#3", Down, "-0.16", "-0x0.28#3", Greater);
+    test("5.0", "0x5.0#3", Up, "-0.19", "-0x0.30#3", Less);
+    test("5.0", "0x5.0#3", Nearest, "-0.19", "-0x0.30#3", Less);
+    test("0.50", "0x0.8#1", Floor, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Floor, "0.50", "0x0.8#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "0.50", "0x0.8#1", Less);
+    test("-0.50", "-0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", Floor, "-0.06934", "-0x0.11c#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "-0.06836",
+        "-0x0.118#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "-0.06836", "-0x0.118#7", Greater);
+    test("123.0", "0x7b.0#7", Up, "-0.06934", "-0x0.11c#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "-0.06836",
+        "-0x0.118#7",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Floor,
+        "-0.070557",
+        "-0x0.1210#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "-0.070435",
+        "-0x0.1208#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Down,
+        "-0.070435",
+        "-0x0.1208#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Up,
+        "-0.070557",
+        "-0x0.1210#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "-0.070435",
+        "-0x0.1208#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "-0.30424217764409356",
+        "-0x0.4de2d0bb0b928#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "-0.30424217764409356",
+        "-0x0.4de2d0bb0b928#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.15047124201536771",
+        "-0x0.2685488771cb1c#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "-0.15047124201536768",
+        "-0x0.2685488771cb1a#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "-0.15047124201536768",
+        "-0x0.2685488771cb1a#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "-0.15047124201536771",
+        "-0x0.2685488771cb1c#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "-0.15047124201536768",
+        "-0x0.2685488771cb1a#53",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "2.17559159e-6",
+        "0x0.00002480184#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "2.17559159e-6",
+        "0x0.00002480184#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", Floor, "0.0703", "0x0.12#5", Less);
+    test("27.0", "0x1b.0#5", Ceiling, "0.0742", "0x0.13#5", Greater);
+    test("27.0", "0x1b.0#5", Down, "0.0703", "0x0.12#5", Less);
+    test("27.0", "0x1b.0#5", Up, "0.0742", "0x0.13#5", Greater);
+    test("27.0", "0x1b.0#5", Nearest, "0.0742", "0x0.13#5", Greater);
+    test("-6.0", "-0x6.0#2", Floor, "0.12", "0x0.2#2", Less);
+    test("-6.0", "-0x6.0#2", Ceiling, "0.19", "0x0.3#2", Greater);
+    test("-6.0", "-0x6.0#2", Down, "0.12", "0x0.2#2", Less);
+    test("-6.0", "-0x6.0#2", Up, "0.19", "0x0.3#2", Greater);
+    test("-6.0", "-0x6.0#2", Nearest, "0.12", "0x0.2#2", Less);
+    test("7.9e-31", "0x1.0E-25#1", Floor, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Up, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.97241452911509749035342949938995",
+        "0x0.f8f02898b45f96418cbf7b1f5#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.97241452911509749035342949938995",
+        "0x0.f8f02898b45f96418cbf7b1f5#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.97241452911509749035342949938995",
+        "0x0.f8f02898b45f96418cbf7b1f5#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "0.97241452911509749035342949938995",
+        "0x0.f8f02898b45f96418cbf7b1f5#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+}
+
+#[test]
+fn bessel_j0_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.bessel_j0_round(Exact));
+    assert_panic!(X.bessel_j0_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_j0_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_bessel_j0_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (j0, o) = x.clone().bessel_j0_prec_round(prec, rm);
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+        assert_eq!(o, o_out);
+
+        let (j0_alt, o_alt) = x.bessel_j0_prec_round_ref(prec, rm);
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut j0_alt = x.clone();
+        let o_alt = j0_alt.bessel_j0_prec_round_assign(prec, rm);
+        assert!(j0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j0), ComparableFloatRef(&j0_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_j0, rug_o) = rug_bessel_j0_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_j0)),
+                ComparableFloatRef(&j0)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.76465", "0x0.c3c#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.76465", "0x0.c3c#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, Floor, "0.76465", "0x0.c3c#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "0.76465", "0x0.c3c#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.76465",
+        "0x0.c3c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.76465",
+        "0x0.c3c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("3.0", "0x3.0#2", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("3.0", "0x3.0#2", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("3.0", "0x3.0#2", 10, Floor, "-0.26025", "-0x0.42a#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "-0.25977",
+        "-0x0.428#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Down,
+        "-0.25977",
+        "-0x0.428#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Up, "-0.26025", "-0x0.42a#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "-0.26025",
+        "-0x0.42a#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "-0.26005195490193343762415469597741",
+        "-0x0.4292c3d1908e475d9419add8f8#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "-0.12", "-0x0.2#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("5.0", "0x5.0#3", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test("5.0", "0x5.0#3", 1, Nearest, "-0.12", "-0x0.2#1", Greater);
+    test("5.0", "0x5.0#3", 10, Floor, "-0.17773", "-0x0.2d8#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "-0.17749",
+        "-0x0.2d7#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Down,
+        "-0.17749",
+        "-0x0.2d7#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 10, Up, "-0.17773", "-0x0.2d8#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "-0.17749",
+        "-0x0.2d7#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "-0.17759677131433830434739701307476",
+        "-0x0.2d76fb64ab9712e3b83d6b21f4#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, Floor, "0.93750", "0x0.f00#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.93848",
+        "0x0.f04#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "0.93750", "0x0.f00#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "0.93848",
+        "0x0.f04#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "0.93750",
+        "0x0.f00#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "0.93848",
+        "0x0.f04#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Down, "0.93750", "0x0.f00#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Up,
+        "0.93848",
+        "0x0.f04#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "0.93848",
+        "0x0.f04#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "-0.12", "-0x0.2#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 1, Ceiling, "-0.062", "-0x0.1#1", Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "-0.062", "-0x0.1#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Up, "-0.12", "-0x0.2#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 1, Nearest, "-0.062", "-0x0.1#1", Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "-0.068604",
+        "-0x0.1190#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.068481",
+        "-0x0.1188#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "-0.068481",
+        "-0x0.1188#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "-0.068604",
+        "-0x0.1190#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "-0.068604",
+        "-0x0.1190#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "-0.068545521193546547737230609609076",
+        "-0x0.118c3303d0454ab2a760527f40#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Floor, "-0.12", "-0x0.2#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "-0.062",
+        "-0x0.1#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Down,
+        "-0.062",
+        "-0x0.1#1",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Up, "-0.12", "-0x0.2#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "-0.062",
+        "-0x0.1#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "-0.070557",
+        "-0x0.1210#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "-0.070435",
+        "-0x0.1208#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "-0.070435",
+        "-0x0.1208#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "-0.070557",
+        "-0x0.1210#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "-0.070435",
+        "-0x0.1208#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "-0.070484621663252288540298322254840",
+        "-0x0.120b47b8ea235658bd1e45adca#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "-0.30469",
+        "-0x0.4e0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "-0.30469",
+        "-0x0.4e0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "-0.30424217764409382934670801148677",
+        "-0x0.4de2d0bb0b92937ec28e65e940#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-0.25",
+        "-0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "-0.25",
+        "-0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-0.15063",
+        "-0x0.269#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-0.15039",
+        "-0x0.268#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "-0.15039",
+        "-0x0.268#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "-0.15063",
+        "-0x0.269#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-0.15039",
+        "-0x0.268#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "-0.15047124201536769288066414016639",
+        "-0x0.2685488771cb1a9c7ee015c8f4#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "3.8e-6",
+        "0x0.00004#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "3.8e-6",
+        "0x0.00004#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "2.1793e-6",
+        "0x0.0000249#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "2.1793e-6",
+        "0x0.0000249#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "2.1755917502468917268590552836393e-6",
+        "0x0.00002480186d3b8e3dab0c99aa4820#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, Floor, "0.062", "0x0.1#1", Less);
+    test("27.0", "0x1b.0#5", 1, Ceiling, "0.12", "0x0.2#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Down, "0.062", "0x0.1#1", Less);
+    test("27.0", "0x1b.0#5", 1, Up, "0.12", "0x0.2#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Floor,
+        "0.072632",
+        "0x0.1298#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Ceiling,
+        "0.072754",
+        "0x0.12a0#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Down,
+        "0.072632",
+        "0x0.1298#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Up,
+        "0.072754",
+        "0x0.12a0#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Nearest,
+        "0.072754",
+        "0x0.12a0#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Nearest,
+        "0.072741918005887087584069883674339",
+        "0x0.129f36dee233842d787f1eb496#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Down, "0.12", "0x0.2#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("-6.0", "-0x6.0#2", 10, Floor, "0.15063", "0x0.269#10", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Ceiling,
+        "0.15088",
+        "0x0.26a#10",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 10, Down, "0.15063", "0x0.269#10", Less);
+    test("-6.0", "-0x6.0#2", 10, Up, "0.15088", "0x0.26a#10", Greater);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Nearest,
+        "0.15063",
+        "0x0.269#10",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Nearest,
+        "0.15064525725099693166232794894871",
+        "0x0.2690b00530c724ac355aaac6f0#100",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.97168",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.97168",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.97168",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.97168",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+}
+
+#[test]
+fn bessel_j0_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).bessel_j0_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).bessel_j0_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.bessel_j0_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.bessel_j0_prec_round(1, Exact));
+    assert_panic!(X.bessel_j0_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_j0_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_bessel_j0_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (j0, o) = Float::bessel_j0_rational_prec(x.clone(), prec);
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+        assert_eq!(o, out_o);
+
+        let (j0, o) = Float::bessel_j0_rational_prec_ref(&x, prec);
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_j0, rug_o) = rug_bessel_j0_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j0)),
+            ComparableFloatRef(&j0)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "1.0", "0x1.0#1", Equal);
+    test("0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, "1.0", "0x1.0#1", Greater);
+    test("1", 10, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "1",
+        100,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("-1", 1, "1.0", "0x1.0#1", Greater);
+    test("-1", 10, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "-1",
+        100,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("1/2", 1, "1.0", "0x1.0#1", Greater);
+    test("1/2", 10, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "1/2",
+        100,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("-1/2", 1, "1.0", "0x1.0#1", Greater);
+    test("-1/2", 10, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "-1/2",
+        100,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("1/3", 1, "1.0", "0x1.0#1", Greater);
+    test("1/3", 10, "0.97266", "0x0.f90#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test("22/7", 1, "-0.25", "-0x0.4#1", Greater);
+    test("22/7", 10, "-0.30469", "-0x0.4e0#10", Less);
+    test(
+        "22/7",
+        100,
+        "-0.30460175498724544660414036547866",
+        "-0x0.4dfa616ff973db703a92a88f70#100",
+        Less,
+    );
+    test("3/2", 1, "0.50", "0x0.8#1", Less);
+    test("3/2", 10, "0.51172", "0x0.830#10", Less);
+    test(
+        "3/2",
+        100,
+        "0.51182767173591812874905174428364",
+        "0x0.830723674b28d6ea4efdbfbb5#100",
+        Greater,
+    );
+    test("-7/2", 1, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 10, "-0.38037", "-0x0.616#10", Less);
+    test(
+        "-7/2",
+        100,
+        "-0.38012773998726337737874930438687",
+        "-0x0.61500d338c35423658861f81c0#100",
+        Greater,
+    );
+    test("1000000", 1, "0.00024", "0x0.001#1", Less);
+    test("1000000", 10, "0.00033092", "0x0.0015b0#10", Less);
+    test(
+        "1000000",
+        100,
+        "0.00033104301373987374098796304221960",
+        "0x0.0015b1faeae6a80599c7a6a5f02c#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_j0_rational_prec_fail() {
+    Float::bessel_j0_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn bessel_j0_rational_prec_ref_fail() {
+    Float::bessel_j0_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_bessel_j0_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (j0, o) = Float::bessel_j0_rational_prec_round(x.clone(), prec, rm);
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+        assert_eq!(o, out_o);
+
+        let (j0, o) = Float::bessel_j0_rational_prec_round_ref(&x, prec, rm);
+        assert!(j0.is_valid());
+        assert_eq!(j0.to_string(), out);
+        assert_eq!(to_hex_string(&j0), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_j0, rug_o) = rug_bessel_j0_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_j0)),
+                ComparableFloatRef(&j0)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", 10, Floor, "0.76465", "0x0.c3c#10", Less);
+    test("1", 10, Ceiling, "0.76562", "0x0.c40#10", Greater);
+    test("1", 10, Down, "0.76465", "0x0.c3c#10", Less);
+    test("1", 10, Up, "0.76562", "0x0.c40#10", Greater);
+    test("1", 10, Nearest, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1", 10, Floor, "0.76465", "0x0.c3c#10", Less);
+    test("-1", 10, Ceiling, "0.76562", "0x0.c40#10", Greater);
+    test("-1", 10, Down, "0.76465", "0x0.c3c#10", Less);
+    test("-1", 10, Up, "0.76562", "0x0.c40#10", Greater);
+    test("-1", 10, Nearest, "0.76562", "0x0.c40#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1/2", 10, Floor, "0.93750", "0x0.f00#10", Less);
+    test("1/2", 10, Ceiling, "0.93848", "0x0.f04#10", Greater);
+    test("1/2", 10, Down, "0.93750", "0x0.f00#10", Less);
+    test("1/2", 10, Up, "0.93848", "0x0.f04#10", Greater);
+    test("1/2", 10, Nearest, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("-1/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1/2", 10, Floor, "0.93750", "0x0.f00#10", Less);
+    test("-1/2", 10, Ceiling, "0.93848", "0x0.f04#10", Greater);
+    test("-1/2", 10, Down, "0.93750", "0x0.f00#10", Less);
+    test("-1/2", 10, Up, "0.93848", "0x0.f04#10", Greater);
+    test("-1/2", 10, Nearest, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1/3", 10, Floor, "0.97168", "0x0.f8c#10", Less);
+    test("1/3", 10, Ceiling, "0.97266", "0x0.f90#10", Greater);
+    test("1/3", 10, Down, "0.97168", "0x0.f8c#10", Less);
+    test("1/3", 10, Up, "0.97266", "0x0.f90#10", Greater);
+    test("1/3", 10, Nearest, "0.97266", "0x0.f90#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test("22/7", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("22/7", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("22/7", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("22/7", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("22/7", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("22/7", 10, Floor, "-0.30469", "-0x0.4e0#10", Less);
+    test("22/7", 10, Ceiling, "-0.30420", "-0x0.4de#10", Greater);
+    test("22/7", 10, Down, "-0.30420", "-0x0.4de#10", Greater);
+    test("22/7", 10, Up, "-0.30469", "-0x0.4e0#10", Less);
+    test("22/7", 10, Nearest, "-0.30469", "-0x0.4e0#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "-0.30460175498724544660414036547866",
+        "-0x0.4dfa616ff973db703a92a88f70#100",
+        Less,
+    );
+    test("3/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("3/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("3/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("3/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("3/2", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("3/2", 10, Floor, "0.51172", "0x0.830#10", Less);
+    test("3/2", 10, Ceiling, "0.51270", "0x0.834#10", Greater);
+    test("3/2", 10, Down, "0.51172", "0x0.830#10", Less);
+    test("3/2", 10, Up, "0.51270", "0x0.834#10", Greater);
+    test("3/2", 10, Nearest, "0.51172", "0x0.830#10", Less);
+    test(
+        "3/2",
+        100,
+        Nearest,
+        "0.51182767173591812874905174428364",
+        "0x0.830723674b28d6ea4efdbfbb5#100",
+        Greater,
+    );
+    test("-7/2", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-7/2", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-7/2", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 10, Floor, "-0.38037", "-0x0.616#10", Less);
+    test("-7/2", 10, Ceiling, "-0.37988", "-0x0.614#10", Greater);
+    test("-7/2", 10, Down, "-0.37988", "-0x0.614#10", Greater);
+    test("-7/2", 10, Up, "-0.38037", "-0x0.616#10", Less);
+    test("-7/2", 10, Nearest, "-0.38037", "-0x0.616#10", Less);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "-0.38012773998726337737874930438687",
+        "-0x0.61500d338c35423658861f81c0#100",
+        Greater,
+    );
+    test("1000000", 1, Floor, "0.00024", "0x0.001#1", Less);
+    test("1000000", 1, Ceiling, "0.00049", "0x0.002#1", Greater);
+    test("1000000", 1, Down, "0.00024", "0x0.001#1", Less);
+    test("1000000", 1, Up, "0.00049", "0x0.002#1", Greater);
+    test("1000000", 1, Nearest, "0.00024", "0x0.001#1", Less);
+    test("1000000", 10, Floor, "0.00033092", "0x0.0015b0#10", Less);
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "0.00033140",
+        "0x0.0015b8#10",
+        Greater,
+    );
+    test("1000000", 10, Down, "0.00033092", "0x0.0015b0#10", Less);
+    test("1000000", 10, Up, "0.00033140", "0x0.0015b8#10", Greater);
+    test("1000000", 10, Nearest, "0.00033092", "0x0.0015b0#10", Less);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "0.00033104301373987374098796304221960",
+        "0x0.0015b1faeae6a80599c7a6a5f02c#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_j0_rational_prec_round_fail_1() {
+    Float::bessel_j0_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn bessel_j0_rational_prec_round_fail_2() {
+    Float::bessel_j0_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn bessel_j0_rational_prec_round_ref_fail() {
+    Float::bessel_j0_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j0_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (j0, o) = x.clone().bessel_j0_prec_round(prec, rm);
+    assert!(j0.is_valid());
+
+    let (j0_alt, o_alt) = x.bessel_j0_prec_round_ref(prec, rm);
+    assert!(j0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_j0_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_j0, rug_o) = rug_bessel_j0_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j0)),
+            ComparableFloatRef(&j0)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(j0.is_nan(), x.is_nan());
+    assert!(j0.is_nan() || (j0 >= -1i32 && j0 <= 1u32));
+
+    if j0.is_normal() {
+        assert_eq!(j0.get_prec(), Some(prec));
+    }
+
+    let (j0_alt, o_alt) = (-&x).bessel_j0_prec_round(prec, rm);
+    assert_eq!(ComparableFloat(j0_alt), ComparableFloat(j0.clone()));
+    assert_eq!(o_alt, o);
+
+    if x.is_normal() {
+        let (j0_alt, o_alt) =
+            Float::bessel_j0_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_j0_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(j0.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_j0_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_j0_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        bessel_j0_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (j0, o) = Float::NAN.bessel_j0_prec_round(prec, rm);
+        assert!(j0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::INFINITY.bessel_j0_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::NEGATIVE_INFINITY.bessel_j0_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::ZERO.bessel_j0_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::NEGATIVE_ZERO.bessel_j0_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j0_prec_properties_helper(x: Float, prec: u64) {
+    let (j0, o) = x.clone().bessel_j0_prec(prec);
+    assert!(j0.is_valid());
+
+    let (j0_alt, o_alt) = x.bessel_j0_prec_ref(prec);
+    assert!(j0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_j0_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let (j0_alt, o_alt) = x.bessel_j0_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let (rug_j0, rug_o) = rug_bessel_j0_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_j0)),
+        ComparableFloatRef(&j0)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(j0.is_nan() || (j0 >= -1i32 && j0 <= 1u32));
+
+    if j0.is_normal() {
+        assert_eq!(j0.get_prec(), Some(prec));
+    }
+
+    let (j0_alt, o_alt) = (-&x).bessel_j0_prec(prec);
+    assert_eq!(ComparableFloat(j0_alt), ComparableFloat(j0.clone()));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn bessel_j0_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        bessel_j0_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (j0, o) = Float::NAN.bessel_j0_prec(prec);
+        assert!(j0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::INFINITY.bessel_j0_prec(prec);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::NEGATIVE_INFINITY.bessel_j0_prec(prec);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::ZERO.bessel_j0_prec(prec);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::NEGATIVE_ZERO.bessel_j0_prec(prec);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j0_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (j0, o) = x.clone().bessel_j0_round(rm);
+    assert!(j0.is_valid());
+
+    let (j0_alt, o_alt) = x.bessel_j0_round_ref(rm);
+    assert!(j0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_j0_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let (j0_alt, o_alt) = x.bessel_j0_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    assert_eq!(j0.is_nan(), x.is_nan());
+    assert!(j0.is_nan() || (j0 >= -1i32 && j0 <= 1u32));
+
+    let (j0_alt, o_alt) = (-&x).bessel_j0_round(rm);
+    assert_eq!(ComparableFloat(j0_alt), ComparableFloat(j0.clone()));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_j0, rug_o) = rug_bessel_j0_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j0)),
+            ComparableFloatRef(&j0)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_j0_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(j0.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_j0_round_ref(Exact));
+    }
+}
+
+#[test]
+fn bessel_j0_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        bessel_j0_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (j0, o) = Float::NAN.bessel_j0_round(rm);
+        assert!(j0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::INFINITY.bessel_j0_round(rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::NEGATIVE_INFINITY.bessel_j0_round(rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::ZERO.bessel_j0_round(rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+
+        let (j0, o) = Float::NEGATIVE_ZERO.bessel_j0_round(rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j0_properties_helper(x: Float) {
+    let j0 = x.clone().bessel_j0();
+    assert!(j0.is_valid());
+
+    let j0_alt = (&x).bessel_j0();
+    assert!(j0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+
+    let mut x_alt = x.clone();
+    x_alt.bessel_j0_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j0));
+
+    let j0_alt = x.bessel_j0_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    let j0_alt = x.bessel_j0_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+
+    assert_eq!(j0.is_nan(), x.is_nan());
+    assert!(j0.is_nan() || (j0 >= -1i32 && j0 <= 1u32));
+
+    let rug_j0 = rug_bessel_j0(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_j0)),
+        ComparableFloatRef(&j0),
+    );
+}
+
+#[test]
+fn bessel_j0_properties() {
+    float_gen().test_properties(|x| {
+        bessel_j0_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j0_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (j0, o) = Float::bessel_j0_rational_prec_round(x.clone(), prec, rm);
+    assert!(j0.is_valid());
+
+    let (j0_alt, o_alt) = Float::bessel_j0_rational_prec_round_ref(&x, prec, rm);
+    assert!(j0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_j0, rug_o) = rug_bessel_j0_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j0)),
+            ComparableFloatRef(&j0)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!j0.is_nan());
+    assert!(j0.is_nan() || (j0 >= -1i32 && j0 <= 1u32));
+
+    if j0.is_normal() {
+        assert_eq!(j0.get_prec(), Some(prec));
+    }
+
+    let (j0_alt, o_alt) = Float::bessel_j0_rational_prec_round(-&x, prec, rm);
+    assert_eq!(ComparableFloat(j0_alt), ComparableFloat(j0.clone()));
+    assert_eq!(o_alt, o);
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::bessel_j0_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(j0.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::bessel_j0_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_j0_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        bessel_j0_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (j0, o) = Float::bessel_j0_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j0_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (j0, o) = Float::bessel_j0_rational_prec(x.clone(), prec);
+    assert!(j0.is_valid());
+
+    let (j0_alt, o_alt) = Float::bessel_j0_rational_prec_ref(&x, prec);
+    assert!(j0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let (j0_alt, o_alt) = Float::bessel_j0_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&j0_alt), ComparableFloatRef(&j0));
+    assert_eq!(o_alt, o);
+
+    let (rug_j0, rug_o) = rug_bessel_j0_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_j0)),
+        ComparableFloatRef(&j0)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(!j0.is_nan());
+    assert!(j0.is_nan() || (j0 >= -1i32 && j0 <= 1u32));
+
+    if j0.is_normal() {
+        assert_eq!(j0.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn bessel_j0_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        bessel_j0_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (j0, o) = Float::bessel_j0_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(j0), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_j0() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_bessel_j0(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, 0.0);
+    test::<f32>(0.0, 1.0);
+    test::<f32>(-0.0, 1.0);
+    test::<f32>(1.0, 0.7651977);
+    test::<f32>(-1.0, 0.7651977);
+    test::<f32>(0.5, 0.9384698);
+    test::<f32>(-0.5, 0.9384698);
+    test::<f32>(2.0, 0.22389078);
+    test::<f32>(5.0, -0.17759678);
+    test::<f32>(-2.5, -0.048383776);
+    test::<f32>(10.0, -0.24593577);
+    test::<f32>(0.001, 0.99999976);
+    test::<f32>(10000000000.0, 2.1755918e-6);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, 0.0);
+    test::<f64>(0.0, 1.0);
+    test::<f64>(-0.0, 1.0);
+    test::<f64>(1.0, 0.7651976865579666);
+    test::<f64>(-1.0, 0.7651976865579666);
+    test::<f64>(0.5, 0.9384698072408129);
+    test::<f64>(-0.5, 0.9384698072408129);
+    test::<f64>(2.0, 0.22389077914123567);
+    test::<f64>(5.0, -0.1775967713143383);
+    test::<f64>(-2.5, -0.048383776468198);
+    test::<f64>(10.0, -0.24593576445134835);
+    test::<f64>(0.001, 0.9999997500000156);
+    test::<f64>(10000000000.0, 2.175591750246892e-6);
+    test::<f64>(1.0e300, -7.860673062724093e-151);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_j0_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let j0 = primitive_float_bessel_j0(x);
+        assert_eq!(j0.is_nan(), x.is_nan());
+        assert!(j0.is_nan() || j0 >= -T::ONE && j0 <= T::ONE);
+        assert_eq!(NiceFloat(primitive_float_bessel_j0(-x)), NiceFloat(j0));
+    });
+}
+
+#[test]
+fn primitive_float_bessel_j0_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_j0_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_j0_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_bessel_j0_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", 1.0);
+    test::<f32>("1", 0.7651977);
+    test::<f32>("-1", 0.7651977);
+    test::<f32>("1/2", 0.9384698);
+    test::<f32>("-1/2", 0.9384698);
+    test::<f32>("1/3", 0.97241455);
+    test::<f32>("22/7", -0.30460176);
+    test::<f32>("3/2", 0.51182765);
+    test::<f32>("-7/2", -0.38012773);
+    test::<f32>("1000000", 0.000331043);
+
+    test::<f64>("0", 1.0);
+    test::<f64>("1", 0.7651976865579666);
+    test::<f64>("-1", 0.7651976865579666);
+    test::<f64>("1/2", 0.9384698072408129);
+    test::<f64>("-1/2", 0.9384698072408129);
+    test::<f64>("1/3", 0.9724145291150975);
+    test::<f64>("22/7", -0.30460175498724545);
+    test::<f64>("3/2", 0.5118276717359181);
+    test::<f64>("-7/2", -0.3801277399872634);
+    test::<f64>("1000000", 0.00033104301373987376);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_j0_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let j0 = primitive_float_bessel_j0_rational::<T>(&x);
+        assert!(!j0.is_nan());
+        assert!(j0 >= -T::ONE && j0 <= T::ONE);
+        assert_eq!(
+            NiceFloat(primitive_float_bessel_j0_rational::<T>(&-&x)),
+            NiceFloat(j0)
+        );
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_bessel_j0_rational::<T>(
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_bessel_j0(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_j0_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_j0_rational_properties_helper);
+}
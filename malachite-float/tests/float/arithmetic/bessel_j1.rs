@@ -0,0 +1,3032 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{BesselJ1, BesselJ1Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::bessel_j1::{
+    primitive_float_bessel_j1, primitive_float_bessel_j1_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::bessel_j1::{
+    rug_bessel_j1, rug_bessel_j1_prec, rug_bessel_j1_prec_round, rug_bessel_j1_rational_prec,
+    rug_bessel_j1_rational_prec_round, rug_bessel_j1_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_bessel_j1() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let j1 = x.clone().bessel_j1();
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+
+        let j1_alt = (&x).bessel_j1();
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+
+        let mut j1_alt = x.clone();
+        j1_alt.bessel_j1_assign();
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_bessel_j1(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&j1)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "0.50", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "-0.50", "-0x0.8#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+    );
+    test("3.0", "0x3.0#2", "0.38", "0x0.6#2");
+    test("5.0", "0x5.0#3", "-0.31", "-0x0.5#3");
+    test("0.50", "0x0.8#1", "0.25", "0x0.4#1");
+    test("-0.50", "-0x0.8#1", "-0.25", "-0x0.4#1");
+    test("123.0", "0x7b.0#7", "0.02148", "0x0.058#7");
+    test("-123.50", "-0x7b.8#10", "0.013947", "0x0.0392#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.43596258821472644",
+        "0x0.6f9b3e82a96970#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+    );
+    test("27.0", "0x1b.0#5", "0.133", "0x0.22#5");
+    test("-6.0", "-0x6.0#2", "0.25", "0x0.4#2");
+    test("7.9e-31", "0x1.0E-25#1", "3.9e-31", "0x8.0E-26#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+    );
+}
+
+#[test]
+fn test_bessel_j1_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (j1, o) = x.clone().bessel_j1_prec(prec);
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (j1_alt, o_alt) = x.bessel_j1_prec_ref(prec);
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut j1_alt = x.clone();
+        let o_alt = j1_alt.bessel_j1_prec_assign(prec);
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_j1, rug_o) = rug_bessel_j1_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j1)),
+            ComparableFloatRef(&j1)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "0.50", "0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.43994", "0x0.70a#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-0.50", "-0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.43994", "-0x0.70a#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 10, "0.33887", "0x0.56c#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.33905895852593645892551459720651",
+        "0x0.56cc916248e33e4e66737a1298#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 10, "-0.32764", "-0x0.53e#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "-0.32757913759146522203773432191016",
+        "-0x0.53dc39f2cea6736674c3f53cc0#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 10, "0.24219", "0x0.3e0#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "0.24226845767487388638395457614144",
+        "0x0.3e054e3e90e1ad1053211a2530#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", 10, "-0.24219", "-0x0.3e0#10", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "-0.24226845767487388638395457614144",
+        "-0x0.3e054e3e90e1ad1053211a2530#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "0.016", "0x0.04#1", Less);
+    test("123.0", "0x7b.0#7", 10, "0.021576", "0x0.0586#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.021567351498906609400863280690370",
+        "0x0.0585701d596145080e06b7d9c98#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "0.016", "0x0.04#1", Greater);
+    test("-123.50", "-0x7b.8#10", 10, "0.013947", "0x0.0392#10", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "0.013948992295178034653492062532407",
+        "0x0.03922941b84e5b773fab5b2a28c#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.28461534317975280569902664029360",
+        "0x0.48dc8d16e5984936d3422c7758#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.43604",
+        "0x0.6fa#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.43596258821472644208511853710106",
+        "0x0.6f9b3e82a9696fedd555c27598#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "-7.6765081756841571029859963163006e-6",
+        "-0x0.000080ca59fff848a583b2aec2e02#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 10, "0.13647", "0x0.22f#10", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        "0.13658472451850766685204149587845",
+        "0x0.22f7376cf0ae4fa24f9c2fc788#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, "0.25", "0x0.4#1", Less);
+    test("-6.0", "-0x6.0#2", 10, "0.27686", "0x0.46e#10", Greater);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        "0.27668385812756560817277480304610",
+        "0x0.46d4c0d9fd2efa7722a3357778#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "3.9443e-31",
+        "0x8.00E-26#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "3.9443045261050590270586428264139e-31",
+        "0x8.000000000000000000000000E-26#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+}
+
+#[test]
+fn bessel_j1_prec_fail() {
+    assert_panic!(Float::NAN.bessel_j1_prec(0));
+    assert_panic!(Float::NAN.bessel_j1_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.bessel_j1_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_bessel_j1_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (j1, o) = x.clone().bessel_j1_round(rm);
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (j1_alt, o_alt) = x.bessel_j1_round_ref(rm);
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut j1_alt = x.clone();
+        let o_alt = j1_alt.bessel_j1_round_assign(rm);
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_j1, rug_o) = rug_bessel_j1_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_j1)),
+                ComparableFloatRef(&j1),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.25", "0x0.4#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.25", "0x0.4#1", Less);
+    test("1.0", "0x1.0#1", Up, "0.50", "0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.50", "0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-0.50", "-0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-0.50", "-0x0.8#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.44005058574493351595968220371931",
+        "0x0.70a727ba5c31b14d8b9e340198#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.44005058574493351595968220371931",
+        "0x0.70a727ba5c31b14d8b9e340198#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.25", "0x0.4#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "0.38", "0x0.6#2", Greater);
+    test("3.0", "0x3.0#2", Down, "0.25", "0x0.4#2", Less);
+    test("3.0", "0x3.0#2", Up, "0.38", "0x0.6#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "0.38", "0x0.6#2", Greater);
+    test("5.0", "0x5.0#3", Floor, "-0.38", "-0x0.6#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "-0.31", "-0x0.5#3", Greater);
+    test("5.0", "0x5.0#3", Down, "-0.31", "-0x0.5#3", Greater);
+    test("5.0", "0x5.0#3", Up, "-0.38", "-0x0.6#3", Less);
+    test("5.0", "0x5.0#3", Nearest, "-0.31", "-0x0.5#3", Greater);
+    test("0.50", "0x0.8#1", Floor, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", Down, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", Up, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "0.25", "0x0.4#1", Greater);
+    test("-0.50", "-0x0.8#1", Floor, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "-0.12", "-0x0.2#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "-0.12", "-0x0.2#1", Greater);
+    test("-0.50", "-0x0.8#1", Up, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", Nearest, "-0.25", "-0x0.4#1", Less);
+    test("123.0", "0x7b.0#7", Floor, "0.02148", "0x0.058#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "0.02173",
+        "0x0.059#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "0.02148", "0x0.058#7", Less);
+    test("123.0", "0x7b.0#7", Up, "0.02173", "0x0.059#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "0.02148", "0x0.058#7", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Floor,
+        "0.013947",
+        "0x0.0392#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "0.013962",
+        "0x0.0393#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Down,
+        "0.013947",
+        "0x0.0392#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Up,
+        "0.013962",
+        "0x0.0393#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "0.013947",
+        "0x0.0392#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.28461534317975312",
+        "0x0.48dc8d16e5986#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.28461534317975312",
+        "0x0.48dc8d16e5986#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.43596258821472639",
+        "0x0.6f9b3e82a9696c#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.43596258821472644",
+        "0x0.6f9b3e82a96970#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.43596258821472639",
+        "0x0.6f9b3e82a9696c#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.43596258821472644",
+        "0x0.6f9b3e82a96970#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.43596258821472644",
+        "0x0.6f9b3e82a96970#53",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "-7.67650727e-6",
+        "-0x0.000080ca59#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "-7.67650727e-6",
+        "-0x0.000080ca59#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", Floor, "0.133", "0x0.22#5", Less);
+    test("27.0", "0x1b.0#5", Ceiling, "0.141", "0x0.24#5", Greater);
+    test("27.0", "0x1b.0#5", Down, "0.133", "0x0.22#5", Less);
+    test("27.0", "0x1b.0#5", Up, "0.141", "0x0.24#5", Greater);
+    test("27.0", "0x1b.0#5", Nearest, "0.133", "0x0.22#5", Less);
+    test("-6.0", "-0x6.0#2", Floor, "0.25", "0x0.4#2", Less);
+    test("-6.0", "-0x6.0#2", Ceiling, "0.38", "0x0.6#2", Greater);
+    test("-6.0", "-0x6.0#2", Down, "0.25", "0x0.4#2", Less);
+    test("-6.0", "-0x6.0#2", Up, "0.38", "0x0.6#2", Greater);
+    test("-6.0", "-0x6.0#2", Nearest, "0.25", "0x0.4#2", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.16436254381427073536379869828606",
+        "0x0.2a13a9e65ea20818f67984fe5c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.16436254381427073536379869828606",
+        "0x0.2a13a9e65ea20818f67984fe5c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "-0.16436254381427073536379869828606",
+        "-0x0.2a13a9e65ea20818f67984fe5c#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "-0.16436254381427073536379869828606",
+        "-0x0.2a13a9e65ea20818f67984fe5c#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+}
+
+#[test]
+fn bessel_j1_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.bessel_j1_round(Exact));
+    assert_panic!(X.bessel_j1_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_j1_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_bessel_j1_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (j1, o) = x.clone().bessel_j1_prec_round(prec, rm);
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (j1_alt, o_alt) = x.bessel_j1_prec_round_ref(prec, rm);
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut j1_alt = x.clone();
+        let o_alt = j1_alt.bessel_j1_prec_round_assign(prec, rm);
+        assert!(j1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&j1), ComparableFloatRef(&j1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_j1, rug_o) = rug_bessel_j1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_j1)),
+                ComparableFloatRef(&j1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, Nearest, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.43994", "0x0.70a#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.44043",
+        "0x0.70c#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.43994", "0x0.70a#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.44043", "0x0.70c#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.43994", "0x0.70a#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.44043",
+        "-0x0.70c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.43994",
+        "-0x0.70a#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.43994",
+        "-0x0.70a#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.44043", "-0x0.70c#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.43994",
+        "-0x0.70a#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.44043",
+        "0x0.70c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.44043",
+        "0x0.70c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 10, Floor, "0.33887", "0x0.56c#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.33936",
+        "0x0.56e#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "0.33887", "0x0.56c#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "0.33936", "0x0.56e#10", Greater);
+    test("3.0", "0x3.0#2", 10, Nearest, "0.33887", "0x0.56c#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.33905895852593645892551459720651",
+        "0x0.56cc916248e33e4e66737a1298#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("5.0", "0x5.0#3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 10, Floor, "-0.32764", "-0x0.53e#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "-0.32715",
+        "-0x0.53c#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Down,
+        "-0.32715",
+        "-0x0.53c#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 10, Up, "-0.32764", "-0x0.53e#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "-0.32764",
+        "-0x0.53e#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "-0.32757913759146522203773432191016",
+        "-0x0.53dc39f2cea6736674c3f53cc0#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "0.12", "0x0.2#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "0.25", "0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 10, Floor, "0.24219", "0x0.3e0#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.24243",
+        "0x0.3e1#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "0.24219", "0x0.3e0#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "0.24243", "0x0.3e1#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "0.24219",
+        "0x0.3e0#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.24226845767487388638395457614144",
+        "0x0.3e054e3e90e1ad1053211a2530#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test(
+        "-0.50", "-0x0.8#1", 1, Ceiling, "-0.12", "-0x0.2#1", Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "-0.25", "-0x0.4#1", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "-0.24243",
+        "-0x0.3e1#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-0.24219",
+        "-0x0.3e0#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Down,
+        "-0.24219",
+        "-0x0.3e0#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Up, "-0.24243", "-0x0.3e1#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-0.24219",
+        "-0x0.3e0#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "-0.24226845767487388638395457614144",
+        "-0x0.3e054e3e90e1ad1053211a2530#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "0.016", "0x0.04#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 1, Ceiling, "0.031", "0x0.08#1", Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "0.016", "0x0.04#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "0.031", "0x0.08#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "0.016", "0x0.04#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "0.021545",
+        "0x0.0584#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "0.021576",
+        "0x0.0586#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "0.021545",
+        "0x0.0584#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "0.021576",
+        "0x0.0586#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "0.021576",
+        "0x0.0586#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "0.021567351498906609400863280690370",
+        "0x0.0585701d596145080e06b7d9c98#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Floor,
+        "0.0078",
+        "0x0.02#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "0.016",
+        "0x0.04#1",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Down, "0.0078", "0x0.02#1", Less);
+    test("-123.50", "-0x7b.8#10", 1, Up, "0.016", "0x0.04#1", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "0.016",
+        "0x0.04#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "0.013947",
+        "0x0.0392#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "0.013962",
+        "0x0.0393#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "0.013947",
+        "0x0.0392#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "0.013962",
+        "0x0.0393#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "0.013947",
+        "0x0.0392#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "0.013948992295178034653492062532407",
+        "0x0.03922941b84e5b773fab5b2a28c#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.28418",
+        "0x0.48c#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.28418",
+        "0x0.48c#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.28461534317975280569902664029360",
+        "0x0.48dc8d16e5984936d3422c7758#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.43555",
+        "0x0.6f8#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.43604",
+        "0x0.6fa#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.43555",
+        "0x0.6f8#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.43604",
+        "0x0.6fa#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.43604",
+        "0x0.6fa#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.43596258821472644208511853710106",
+        "0x0.6f9b3e82a9696fedd555c27598#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "-0.000015",
+        "-0x0.0001#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "-0.000015",
+        "-0x0.0001#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "-7.6890e-6",
+        "-0x0.0000810#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "-7.6890e-6",
+        "-0x0.0000810#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "-7.6765081756841571029859963163006e-6",
+        "-0x0.000080ca59fff848a583b2aec2e02#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Down, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 10, Floor, "0.13647", "0x0.22f#10", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Ceiling,
+        "0.13672",
+        "0x0.230#10",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 10, Down, "0.13647", "0x0.22f#10", Less);
+    test("27.0", "0x1b.0#5", 10, Up, "0.13672", "0x0.230#10", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Nearest,
+        "0.13647",
+        "0x0.22f#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Nearest,
+        "0.13658472451850766685204149587845",
+        "0x0.22f7376cf0ae4fa24f9c2fc788#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("-6.0", "-0x6.0#2", 10, Floor, "0.27637", "0x0.46c#10", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Ceiling,
+        "0.27686",
+        "0x0.46e#10",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 10, Down, "0.27637", "0x0.46c#10", Less);
+    test("-6.0", "-0x6.0#2", 10, Up, "0.27686", "0x0.46e#10", Greater);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Nearest,
+        "0.27686",
+        "0x0.46e#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Nearest,
+        "0.27668385812756560817277480304610",
+        "0x0.46d4c0d9fd2efa7722a3357778#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "2.0e-31",
+        "0x4.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "3.9405e-31",
+        "0x7.feE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "3.9443e-31",
+        "0x8.00E-26#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "3.9405e-31",
+        "0x7.feE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "3.9443e-31",
+        "0x8.00E-26#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "3.9443e-31",
+        "0x8.00E-26#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "3.9443045261050590270586428264139e-31",
+        "0x8.000000000000000000000000E-26#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.16455",
+        "0x0.2a2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.16455",
+        "0x0.2a2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-0.25",
+        "-0x0.4#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-0.25",
+        "-0x0.4#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-0.16455",
+        "-0x0.2a2#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-0.16455",
+        "-0x0.2a2#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+}
+
+#[test]
+fn bessel_j1_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).bessel_j1_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).bessel_j1_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.bessel_j1_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.bessel_j1_prec_round(1, Exact));
+    assert_panic!(X.bessel_j1_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_j1_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_bessel_j1_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (j1, o) = Float::bessel_j1_rational_prec(x.clone(), prec);
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+        assert_eq!(o, out_o);
+
+        let (j1, o) = Float::bessel_j1_rational_prec_ref(&x, prec);
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_j1, rug_o) = rug_bessel_j1_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j1)),
+            ComparableFloatRef(&j1)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "0.50", "0x0.8#1", Greater);
+    test("1", 10, "0.43994", "0x0.70a#10", Less);
+    test(
+        "1",
+        100,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test("-1", 1, "-0.50", "-0x0.8#1", Less);
+    test("-1", 10, "-0.43994", "-0x0.70a#10", Greater);
+    test(
+        "-1",
+        100,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test("1/2", 1, "0.25", "0x0.4#1", Greater);
+    test("1/2", 10, "0.24219", "0x0.3e0#10", Less);
+    test(
+        "1/2",
+        100,
+        "0.24226845767487388638395457614144",
+        "0x0.3e054e3e90e1ad1053211a2530#100",
+        Less,
+    );
+    test("-1/2", 1, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 10, "-0.24219", "-0x0.3e0#10", Greater);
+    test(
+        "-1/2",
+        100,
+        "-0.24226845767487388638395457614144",
+        "-0x0.3e054e3e90e1ad1053211a2530#100",
+        Greater,
+    );
+    test("1/3", 1, "0.12", "0x0.2#1", Less);
+    test("1/3", 10, "0.16431", "0x0.2a1#10", Less);
+    test(
+        "1/3",
+        100,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test("22/7", 1, "0.25", "0x0.4#1", Less);
+    test("22/7", 10, "0.28418", "0x0.48c#10", Greater);
+    test(
+        "22/7",
+        100,
+        "0.28411597080241516421768717733909",
+        "0x0.48bbd302de1f3a7ad50b650050#100",
+        Less,
+    );
+    test("3/2", 1, "0.50", "0x0.8#1", Less);
+    test("3/2", 10, "0.55762", "0x0.8ec#10", Less);
+    test(
+        "3/2",
+        100,
+        "0.55793650791009964199012121315609",
+        "0x0.8ed4ed4eb7e408aa88a075493#100",
+        Less,
+    );
+    test("-7/2", 1, "-0.12", "-0x0.2#1", Greater);
+    test("-7/2", 10, "-0.13745", "-0x0.233#10", Less);
+    test(
+        "-7/2",
+        100,
+        "-0.13737752736232718571613189718405",
+        "-0x0.232b2c7339fe8d4f234a1ffce0#100",
+        Less,
+    );
+    test("1000000", 1, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 10, "-0.00072575", "-0x0.002f9#10", Greater);
+    test(
+        "1000000",
+        100,
+        "-0.00072596835681376304185252338170845",
+        "-0x0.002f93ba59b6d3c093eebc281c40#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_j1_rational_prec_fail() {
+    Float::bessel_j1_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn bessel_j1_rational_prec_ref_fail() {
+    Float::bessel_j1_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_bessel_j1_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (j1, o) = Float::bessel_j1_rational_prec_round(x.clone(), prec, rm);
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+        assert_eq!(o, out_o);
+
+        let (j1, o) = Float::bessel_j1_rational_prec_round_ref(&x, prec, rm);
+        assert!(j1.is_valid());
+        assert_eq!(j1.to_string(), out);
+        assert_eq!(to_hex_string(&j1), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_j1, rug_o) = rug_bessel_j1_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_j1)),
+                ComparableFloatRef(&j1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("1", 10, Floor, "0.43994", "0x0.70a#10", Less);
+    test("1", 10, Ceiling, "0.44043", "0x0.70c#10", Greater);
+    test("1", 10, Down, "0.43994", "0x0.70a#10", Less);
+    test("1", 10, Up, "0.44043", "0x0.70c#10", Greater);
+    test("1", 10, Nearest, "0.43994", "0x0.70a#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test("-1", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test("-1", 10, Floor, "-0.44043", "-0x0.70c#10", Less);
+    test("-1", 10, Ceiling, "-0.43994", "-0x0.70a#10", Greater);
+    test("-1", 10, Down, "-0.43994", "-0x0.70a#10", Greater);
+    test("-1", 10, Up, "-0.44043", "-0x0.70c#10", Less);
+    test("-1", 10, Nearest, "-0.43994", "-0x0.70a#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("1/2", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1/2", 1, Down, "0.12", "0x0.2#1", Less);
+    test("1/2", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("1/2", 1, Nearest, "0.25", "0x0.4#1", Greater);
+    test("1/2", 10, Floor, "0.24219", "0x0.3e0#10", Less);
+    test("1/2", 10, Ceiling, "0.24243", "0x0.3e1#10", Greater);
+    test("1/2", 10, Down, "0.24219", "0x0.3e0#10", Less);
+    test("1/2", 10, Up, "0.24243", "0x0.3e1#10", Greater);
+    test("1/2", 10, Nearest, "0.24219", "0x0.3e0#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.24226845767487388638395457614144",
+        "0x0.3e054e3e90e1ad1053211a2530#100",
+        Less,
+    );
+    test("-1/2", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 1, Ceiling, "-0.12", "-0x0.2#1", Greater);
+    test("-1/2", 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("-1/2", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 1, Nearest, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 10, Floor, "-0.24243", "-0x0.3e1#10", Less);
+    test("-1/2", 10, Ceiling, "-0.24219", "-0x0.3e0#10", Greater);
+    test("-1/2", 10, Down, "-0.24219", "-0x0.3e0#10", Greater);
+    test("-1/2", 10, Up, "-0.24243", "-0x0.3e1#10", Less);
+    test("-1/2", 10, Nearest, "-0.24219", "-0x0.3e0#10", Greater);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "-0.24226845767487388638395457614144",
+        "-0x0.3e054e3e90e1ad1053211a2530#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("1/3", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1/3", 1, Down, "0.12", "0x0.2#1", Less);
+    test("1/3", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("1/3", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("1/3", 10, Floor, "0.16431", "0x0.2a1#10", Less);
+    test("1/3", 10, Ceiling, "0.16455", "0x0.2a2#10", Greater);
+    test("1/3", 10, Down, "0.16431", "0x0.2a1#10", Less);
+    test("1/3", 10, Up, "0.16455", "0x0.2a2#10", Greater);
+    test("1/3", 10, Nearest, "0.16431", "0x0.2a1#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("22/7", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("22/7", 1, Down, "0.25", "0x0.4#1", Less);
+    test("22/7", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("22/7", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("22/7", 10, Floor, "0.28369", "0x0.48a#10", Less);
+    test("22/7", 10, Ceiling, "0.28418", "0x0.48c#10", Greater);
+    test("22/7", 10, Down, "0.28369", "0x0.48a#10", Less);
+    test("22/7", 10, Up, "0.28418", "0x0.48c#10", Greater);
+    test("22/7", 10, Nearest, "0.28418", "0x0.48c#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.28411597080241516421768717733909",
+        "0x0.48bbd302de1f3a7ad50b650050#100",
+        Less,
+    );
+    test("3/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("3/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("3/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("3/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("3/2", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("3/2", 10, Floor, "0.55762", "0x0.8ec#10", Less);
+    test("3/2", 10, Ceiling, "0.55859", "0x0.8f0#10", Greater);
+    test("3/2", 10, Down, "0.55762", "0x0.8ec#10", Less);
+    test("3/2", 10, Up, "0.55859", "0x0.8f0#10", Greater);
+    test("3/2", 10, Nearest, "0.55762", "0x0.8ec#10", Less);
+    test(
+        "3/2",
+        100,
+        Nearest,
+        "0.55793650791009964199012121315609",
+        "0x0.8ed4ed4eb7e408aa88a075493#100",
+        Less,
+    );
+    test("-7/2", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test("-7/2", 1, Ceiling, "-0.12", "-0x0.2#1", Greater);
+    test("-7/2", 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("-7/2", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test("-7/2", 1, Nearest, "-0.12", "-0x0.2#1", Greater);
+    test("-7/2", 10, Floor, "-0.13745", "-0x0.233#10", Less);
+    test("-7/2", 10, Ceiling, "-0.13721", "-0x0.232#10", Greater);
+    test("-7/2", 10, Down, "-0.13721", "-0x0.232#10", Greater);
+    test("-7/2", 10, Up, "-0.13745", "-0x0.233#10", Less);
+    test("-7/2", 10, Nearest, "-0.13745", "-0x0.233#10", Less);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "-0.13737752736232718571613189718405",
+        "-0x0.232b2c7339fe8d4f234a1ffce0#100",
+        Less,
+    );
+    test("1000000", 1, Floor, "-0.00098", "-0x0.004#1", Less);
+    test("1000000", 1, Ceiling, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 1, Down, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 1, Up, "-0.00098", "-0x0.004#1", Less);
+    test("1000000", 1, Nearest, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 10, Floor, "-0.00072670", "-0x0.002fa#10", Less);
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "-0.00072575",
+        "-0x0.002f9#10",
+        Greater,
+    );
+    test("1000000", 10, Down, "-0.00072575", "-0x0.002f9#10", Greater);
+    test("1000000", 10, Up, "-0.00072670", "-0x0.002fa#10", Less);
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "-0.00072575",
+        "-0x0.002f9#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "-0.00072596835681376304185252338170845",
+        "-0x0.002f93ba59b6d3c093eebc281c40#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_j1_rational_prec_round_fail_1() {
+    Float::bessel_j1_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn bessel_j1_rational_prec_round_fail_2() {
+    Float::bessel_j1_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn bessel_j1_rational_prec_round_ref_fail() {
+    Float::bessel_j1_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j1_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (j1, o) = x.clone().bessel_j1_prec_round(prec, rm);
+    assert!(j1.is_valid());
+
+    let (j1_alt, o_alt) = x.bessel_j1_prec_round_ref(prec, rm);
+    assert!(j1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_j1_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_j1, rug_o) = rug_bessel_j1_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j1)),
+            ComparableFloatRef(&j1)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(j1.is_nan(), x.is_nan());
+    assert!(j1.is_nan() || (j1 >= -1i32 && j1 <= 1u32));
+
+    if j1.is_normal() {
+        assert_eq!(j1.get_prec(), Some(prec));
+    }
+
+    if x.is_finite() {
+        let (j1_alt, o_alt) = (-&x).bessel_j1_prec_round(prec, -rm);
+        assert_eq!(ComparableFloat(-j1_alt), ComparableFloat(j1.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if x.is_normal() {
+        let (j1_alt, o_alt) =
+            Float::bessel_j1_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_j1_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(j1.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_j1_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_j1_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        bessel_j1_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (j1, o) = Float::NAN.bessel_j1_prec_round(prec, rm);
+        assert!(j1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::INFINITY.bessel_j1_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::NEGATIVE_INFINITY.bessel_j1_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::ZERO.bessel_j1_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::NEGATIVE_ZERO.bessel_j1_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j1_prec_properties_helper(x: Float, prec: u64) {
+    let (j1, o) = x.clone().bessel_j1_prec(prec);
+    assert!(j1.is_valid());
+
+    let (j1_alt, o_alt) = x.bessel_j1_prec_ref(prec);
+    assert!(j1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_j1_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let (j1_alt, o_alt) = x.bessel_j1_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let (rug_j1, rug_o) = rug_bessel_j1_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_j1)),
+        ComparableFloatRef(&j1)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(j1.is_nan() || (j1 >= -1i32 && j1 <= 1u32));
+
+    if j1.is_normal() {
+        assert_eq!(j1.get_prec(), Some(prec));
+    }
+
+    if x.is_finite() {
+        let (j1_alt, o_alt) = (-&x).bessel_j1_prec(prec);
+        assert_eq!(ComparableFloat(-j1_alt), ComparableFloat(j1.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+}
+
+#[test]
+fn bessel_j1_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        bessel_j1_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (j1, o) = Float::NAN.bessel_j1_prec(prec);
+        assert!(j1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::INFINITY.bessel_j1_prec(prec);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::NEGATIVE_INFINITY.bessel_j1_prec(prec);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::ZERO.bessel_j1_prec(prec);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::NEGATIVE_ZERO.bessel_j1_prec(prec);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j1_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (j1, o) = x.clone().bessel_j1_round(rm);
+    assert!(j1.is_valid());
+
+    let (j1_alt, o_alt) = x.bessel_j1_round_ref(rm);
+    assert!(j1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_j1_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let (j1_alt, o_alt) = x.bessel_j1_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    assert_eq!(j1.is_nan(), x.is_nan());
+    assert!(j1.is_nan() || (j1 >= -1i32 && j1 <= 1u32));
+
+    if x.is_finite() {
+        let (j1_alt, o_alt) = (-&x).bessel_j1_round(-rm);
+        assert_eq!(ComparableFloat(-j1_alt), ComparableFloat(j1.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_j1, rug_o) = rug_bessel_j1_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j1)),
+            ComparableFloatRef(&j1)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_j1_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(j1.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_j1_round_ref(Exact));
+    }
+}
+
+#[test]
+fn bessel_j1_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        bessel_j1_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (j1, o) = Float::NAN.bessel_j1_round(rm);
+        assert!(j1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::INFINITY.bessel_j1_round(rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::NEGATIVE_INFINITY.bessel_j1_round(rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::ZERO.bessel_j1_round(rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (j1, o) = Float::NEGATIVE_ZERO.bessel_j1_round(rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j1_properties_helper(x: Float) {
+    let j1 = x.clone().bessel_j1();
+    assert!(j1.is_valid());
+
+    let j1_alt = (&x).bessel_j1();
+    assert!(j1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+
+    let mut x_alt = x.clone();
+    x_alt.bessel_j1_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&j1));
+
+    let j1_alt = x.bessel_j1_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    let j1_alt = x.bessel_j1_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+
+    assert_eq!(j1.is_nan(), x.is_nan());
+    assert!(j1.is_nan() || (j1 >= -1i32 && j1 <= 1u32));
+
+    let rug_j1 = rug_bessel_j1(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_j1)),
+        ComparableFloatRef(&j1),
+    );
+}
+
+#[test]
+fn bessel_j1_properties() {
+    float_gen().test_properties(|x| {
+        bessel_j1_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j1_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (j1, o) = Float::bessel_j1_rational_prec_round(x.clone(), prec, rm);
+    assert!(j1.is_valid());
+
+    let (j1_alt, o_alt) = Float::bessel_j1_rational_prec_round_ref(&x, prec, rm);
+    assert!(j1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_j1, rug_o) = rug_bessel_j1_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_j1)),
+            ComparableFloatRef(&j1)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!j1.is_nan());
+    assert!(j1.is_nan() || (j1 >= -1i32 && j1 <= 1u32));
+
+    if j1.is_normal() {
+        assert_eq!(j1.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (j1_alt, o_alt) = Float::bessel_j1_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-j1_alt), ComparableFloat(j1.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::bessel_j1_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(j1.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::bessel_j1_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_j1_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        bessel_j1_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (j1, o) = Float::bessel_j1_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_j1_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (j1, o) = Float::bessel_j1_rational_prec(x.clone(), prec);
+    assert!(j1.is_valid());
+
+    let (j1_alt, o_alt) = Float::bessel_j1_rational_prec_ref(&x, prec);
+    assert!(j1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let (j1_alt, o_alt) = Float::bessel_j1_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&j1_alt), ComparableFloatRef(&j1));
+    assert_eq!(o_alt, o);
+
+    let (rug_j1, rug_o) = rug_bessel_j1_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_j1)),
+        ComparableFloatRef(&j1)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(!j1.is_nan());
+    assert!(j1.is_nan() || (j1 >= -1i32 && j1 <= 1u32));
+
+    if j1.is_normal() {
+        assert_eq!(j1.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn bessel_j1_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        bessel_j1_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (j1, o) = Float::bessel_j1_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(j1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_j1() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_bessel_j1(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, 0.0);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, 0.44005057);
+    test::<f32>(-1.0, -0.44005057);
+    test::<f32>(0.5, 0.24226846);
+    test::<f32>(-0.5, -0.24226846);
+    test::<f32>(2.0, 0.5767248);
+    test::<f32>(5.0, -0.32757914);
+    test::<f32>(-2.5, -0.4970941);
+    test::<f32>(10.0, 0.043472745);
+    test::<f32>(0.001, 0.00049999997);
+    test::<f32>(10000000000.0, -7.676508e-6);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, 0.0);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, 0.4400505857449335);
+    test::<f64>(-1.0, -0.4400505857449335);
+    test::<f64>(0.5, 0.2422684576748739);
+    test::<f64>(-0.5, -0.2422684576748739);
+    test::<f64>(2.0, 0.5767248077568734);
+    test::<f64>(5.0, -0.32757913759146523);
+    test::<f64>(-2.5, -0.49709410246427405);
+    test::<f64>(10.0, 0.04347274616886144);
+    test::<f64>(0.001, 0.0004999999375000026);
+    test::<f64>(10000000000.0, -7.676508175684158e-6);
+    test::<f64>(1.0e300, -1.3681360450342481e-151);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_j1_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let j1 = primitive_float_bessel_j1(x);
+        assert_eq!(j1.is_nan(), x.is_nan());
+        assert!(j1.is_nan() || j1 >= -T::ONE && j1 <= T::ONE);
+        if x.is_finite() {
+            assert_eq!(NiceFloat(primitive_float_bessel_j1(-x)), NiceFloat(-j1));
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_j1_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_j1_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_j1_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_bessel_j1_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", 0.44005057);
+    test::<f32>("-1", -0.44005057);
+    test::<f32>("1/2", 0.24226846);
+    test::<f32>("-1/2", -0.24226846);
+    test::<f32>("1/3", 0.16436255);
+    test::<f32>("22/7", 0.28411597);
+    test::<f32>("3/2", 0.5579365);
+    test::<f32>("-7/2", -0.13737753);
+    test::<f32>("1000000", -0.00072596833);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", 0.4400505857449335);
+    test::<f64>("-1", -0.4400505857449335);
+    test::<f64>("1/2", 0.2422684576748739);
+    test::<f64>("-1/2", -0.2422684576748739);
+    test::<f64>("1/3", 0.16436254381427073);
+    test::<f64>("22/7", 0.2841159708024152);
+    test::<f64>("3/2", 0.5579365079100996);
+    test::<f64>("-7/2", -0.1373775273623272);
+    test::<f64>("1000000", -0.000725968356813763);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_j1_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let j1 = primitive_float_bessel_j1_rational::<T>(&x);
+        assert!(!j1.is_nan());
+        assert!(j1 >= -T::ONE && j1 <= T::ONE);
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_bessel_j1_rational::<T>(&-&x)),
+                NiceFloat(-j1)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_bessel_j1_rational::<T>(
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_bessel_j1(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_j1_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_j1_rational_properties_helper);
+}
@@ -0,0 +1,7896 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{BesselJn, BesselJnAssign, Parity};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_signed_pair_gen_var_4, rounding_mode_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::bessel_jn::{
+    primitive_float_bessel_jn, primitive_float_bessel_jn_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::bessel_jn::{
+    rug_bessel_jn, rug_bessel_jn_prec, rug_bessel_jn_prec_round, rug_bessel_jn_rational_prec,
+    rug_bessel_jn_rational_prec_round, rug_bessel_jn_round,
+};
+use malachite_float::test_util::generators::{
+    float_signed_rounding_mode_triple_gen_var_11,
+    float_signed_unsigned_rounding_mode_quadruple_gen_var_17,
+    rational_signed_unsigned_rounding_mode_quadruple_gen_var_3,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::rational_gen;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_bessel_jn() {
+    let test = |s, s_hex, n: i64, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let jn = x.clone().bessel_jn(n);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+
+        let jn_alt = (&x).bessel_jn(n);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+
+        let mut jn_alt = x.clone();
+        jn_alt.bessel_jn_assign(n);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_bessel_jn(&rug::Float::exact_from(&x), n))),
+            ComparableFloatRef(&jn)
+        );
+    };
+    test("NaN", "NaN", 3, "NaN", "NaN");
+    test("Infinity", "Infinity", 3, "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", 3, "0.0", "0x0.0");
+    test("0.0", "0x0.0", 3, "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", 3, "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", 3, "0.016", "0x0.04#1");
+    test("-1.0", "-0x1.0#1", 3, "-0.016", "-0x0.04#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+    );
+    test("3.0", "0x3.0#2", 3, "0.25", "0x0.4#2");
+    test("5.0", "0x5.0#3", 3, "0.38", "0x0.6#3");
+    test("0.50", "0x0.8#1", 3, "0.0020", "0x0.008#1");
+    test("-0.50", "-0x0.8#1", 3, "-0.0020", "-0x0.008#1");
+    test("123.0", "0x7b.0#7", 3, "-0.01929", "-0x0.04f#7");
+    test("-123.50", "-0x7b.8#10", 3, "-0.016235", "-0x0.0428#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        "0.33345833620298970",
+        "0x0.555d86889206a#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        "0.25746748033807287",
+        "0x0.41e96387d5e658#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        "7.67650818e-6",
+        "0x0.000080ca5a#24",
+    );
+    test("27.0", "0x1b.0#5", 3, "-0.148", "-0x0.26#5");
+    test("-6.0", "-0x6.0#2", 3, "-0.12", "-0x0.2#2");
+    test("7.9e-31", "0x1.0E-25#1", 3, "7.7e-93", "0x4.0E-77#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        "0.00076626143205232658855358562126801",
+        "0x0.003237bbca7533d9c2ba1d1305ec#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+    );
+    test("0.0", "0x0.0", 0, "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", 0, "1.0", "0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        "-0.28461534317975268",
+        "-0x0.48dc8d16e5984#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+    );
+    test("0.0", "0x0.0", -1, "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", -1, "0.0", "0x0.0");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+    );
+    test("0.0", "0x0.0", 2, "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", 2, "0.0", "0x0.0");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+    );
+    test("0.0", "0x0.0", -2, "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", -2, "0.0", "0x0.0");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        "-0.33345833620298970",
+        "-0x0.555d86889206a#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+    );
+    test("0.0", "0x0.0", -3, "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", -3, "0.0", "0x0.0");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+    );
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", 10, "0.0", "0x0.0");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+    );
+    test("0.0", "0x0.0", -10, "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", -10, "0.0", "0x0.0");
+}
+
+#[test]
+fn test_bessel_jn_prec() {
+    let test = |s, s_hex, n: i64, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (jn, o) = x.clone().bessel_jn_prec(n, prec);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+        assert_eq!(o, o_out);
+
+        let (jn_alt, o_alt) = x.bessel_jn_prec_ref(n, prec);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut jn_alt = x.clone();
+        let o_alt = jn_alt.bessel_jn_prec_assign(n, prec);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_jn, rug_o) = rug_bessel_jn_prec(&rug::Float::exact_from(&x), n, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_jn)),
+            ComparableFloatRef(&jn)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 3, 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 3, 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 3, 1, "0.016", "0x0.04#1", Less);
+    test("1.0", "0x1.0#1", 3, 10, "0.019562", "0x0.0502#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 3, 1, "-0.016", "-0x0.04#1", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        "0.019562",
+        "0x0.0502#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        100,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 3, 1, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 3, 10, "0.30908", "0x0.4f2#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        "0.30906272225525164361826019494672",
+        "0x0.4f1ebc0c7fc0e6740f6abdc9e0#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 3, 1, "0.25", "0x0.4#1", Less);
+    test("5.0", "0x5.0#3", 3, 10, "0.36475", "0x0.5d6#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        100,
+        "0.36483123061366699446357694935854",
+        "0x0.5d65945c0b8de2b42a78f23a38#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 3, 1, "0.0020", "0x0.008#1", Less);
+    test("0.50", "0x0.8#1", 3, 10, "0.0025635", "0x0.00a80#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        100,
+        "0.0025637299945872440753544715897785",
+        "0x0.00a804407b86dad17d356dde30e#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 3, 1, "-0.0020", "-0x0.008#1", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        10,
+        "-0.0025635",
+        "-0x0.00a80#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        100,
+        "-0.0025637299945872440753544715897785",
+        "-0x0.00a804407b86dad17d356dde30e#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 3, 1, "-0.016", "-0x0.04#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        "-0.019318",
+        "-0x0.04f2#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        100,
+        "-0.019326824349774733245008672575277",
+        "-0x0.04f29a4e848fc846d271e875cf8#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        1,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        10,
+        "-0.016235",
+        "-0x0.0428#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        100,
+        "-0.016224578596430978072442016453551",
+        "-0x0.04274b42768a9e0bac65dd90898#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        "0.33350",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        100,
+        "0.33345833620298951493804420742911",
+        "0x0.555d86889206927ce2d1d7f950#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        "0.25732",
+        "0x0.41e#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        "0.25746748033807289217707348042519",
+        "0x0.41e96387d5e65952c02f969510#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        "7.6741e-6",
+        "0x0.000080c#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        100,
+        "7.6765081748139204022731189715024e-6",
+        "0x0.000080ca59ffb9939ce307a9b2f3b#100",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", 3, 1, "-0.12", "-0x0.2#1", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        "-0.14575",
+        "-0x0.255#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        100,
+        "-0.14586243281547302230370571462975",
+        "-0x0.25573d8aa84ee4f9ca49ecde54#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 3, 1, "-0.12", "-0x0.2#1", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        10,
+        "-0.11475",
+        "-0x0.1d60#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        100,
+        "-0.11476838482077529635949510307008",
+        "-0x0.1d6175fb6b8355064cb445f4be#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        "7.7e-93",
+        "0x4.0E-77#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        "1.0232e-92",
+        "0x5.56E-77#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        "1.0227278052703596985616191572892e-92",
+        "0x5.5555555555555555555555558E-77#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        "0.00098",
+        "0x0.004#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        "0.00076580",
+        "0x0.00323#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        100,
+        "0.00076626143205232658855358562126801",
+        "0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        "-0.00098",
+        "-0x0.004#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        100,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        100,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        100,
+        "-0.30424217764409382934670801148677",
+        "-0x0.4de2d0bb0b92937ec28e65e940#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        100,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test("0.0", "0x0.0", 0, 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 0, 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 0, 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 0, 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        100,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        100,
+        "0.28461534317975280569902664029360",
+        "0x0.48dc8d16e5984936d3422c7758#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        100,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test("0.0", "0x0.0", 1, 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 100, "-0.0", "-0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        "-0.43994",
+        "-0x0.70a#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        100,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        "-0.28467",
+        "-0x0.48e#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        100,
+        "-0.28461534317975280569902664029360",
+        "-0x0.48dc8d16e5984936d3422c7758#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        1,
+        "-0.12",
+        "-0x0.2#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        100,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -1, 1, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, 10, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, 100, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 1, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 100, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        1,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        100,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        100,
+        "0.48543393263150911269626646951726",
+        "0x0.7c4565f105602e610907200188#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        1,
+        "0.016",
+        "0x0.04#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        100,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 2, 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 1, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 100, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        1,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        100,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        100,
+        "0.48543393263150911269626646951726",
+        "0x0.7c4565f105602e610907200188#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        1,
+        "0.016",
+        "0x0.04#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        100,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -2, 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 1, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 100, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        1,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        100,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        "-0.33350",
+        "-0x0.556#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        100,
+        "-0.33345833620298951493804420742911",
+        "-0x0.555d86889206927ce2d1d7f950#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        1,
+        "-0.00098",
+        "-0x0.004#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        100,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -3, 1, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, 10, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, 100, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 1, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 100, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        1,
+        "2.3e-10",
+        "0x1.0E-8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        100,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        1,
+        "0.000015",
+        "0x0.0001#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        100,
+        "0.000020094972255377464018941542334377",
+        "0x0.000151233fa7956a18c84edf0473a#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        1,
+        "3.6e-15",
+        "0x1.0E-12#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        100,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 10, 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 1, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 100, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        1,
+        "2.3e-10",
+        "0x1.0E-8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        100,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        1,
+        "0.000015",
+        "0x0.0001#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        100,
+        "0.000020094972255377464018941542334377",
+        "0x0.000151233fa7956a18c84edf0473a#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        1,
+        "3.6e-15",
+        "0x1.0E-12#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        100,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -10, 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 1, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 100, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+fn bessel_jn_prec_fail() {
+    assert_panic!(Float::NAN.bessel_jn_prec(3, 0));
+    assert_panic!(Float::NAN.bessel_jn_prec_ref(3, 0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.bessel_jn_prec_assign(3, 0)
+    });
+}
+
+#[test]
+fn test_bessel_jn_round() {
+    let test = |s, s_hex, n: i64, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (jn, o) = x.clone().bessel_jn_round(n, rm);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+        assert_eq!(o, o_out);
+
+        let (jn_alt, o_alt) = x.bessel_jn_round_ref(n, rm);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut jn_alt = x.clone();
+        let o_alt = jn_alt.bessel_jn_round_assign(n, rm);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_jn, rug_o) = rug_bessel_jn_round(&rug::Float::exact_from(&x), n, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_jn)),
+                ComparableFloatRef(&jn),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 3, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 3, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 3, Floor, "0.016", "0x0.04#1", Less);
+    test("1.0", "0x1.0#1", 3, Ceiling, "0.031", "0x0.08#1", Greater);
+    test("1.0", "0x1.0#1", 3, Down, "0.016", "0x0.04#1", Less);
+    test("1.0", "0x1.0#1", 3, Up, "0.031", "0x0.08#1", Greater);
+    test("1.0", "0x1.0#1", 3, Nearest, "0.016", "0x0.04#1", Less);
+    test("-1.0", "-0x1.0#1", 3, Floor, "-0.031", "-0x0.08#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        Ceiling,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 3, Down, "-0.016", "-0x0.04#1", Greater);
+    test("-1.0", "-0x1.0#1", 3, Up, "-0.031", "-0x0.08#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        Nearest,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Floor,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Ceiling,
+        "0.019563353982668405918905321621772",
+        "0x0.05021a9d8e3de8ab0aad53c58a0#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Down,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Up,
+        "0.019563353982668405918905321621772",
+        "0x0.05021a9d8e3de8ab0aad53c58a0#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Nearest,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 3, Floor, "0.25", "0x0.4#2", Less);
+    test("3.0", "0x3.0#2", 3, Ceiling, "0.38", "0x0.6#2", Greater);
+    test("3.0", "0x3.0#2", 3, Down, "0.25", "0x0.4#2", Less);
+    test("3.0", "0x3.0#2", 3, Up, "0.38", "0x0.6#2", Greater);
+    test("3.0", "0x3.0#2", 3, Nearest, "0.25", "0x0.4#2", Less);
+    test("5.0", "0x5.0#3", 3, Floor, "0.31", "0x0.5#3", Less);
+    test("5.0", "0x5.0#3", 3, Ceiling, "0.38", "0x0.6#3", Greater);
+    test("5.0", "0x5.0#3", 3, Down, "0.31", "0x0.5#3", Less);
+    test("5.0", "0x5.0#3", 3, Up, "0.38", "0x0.6#3", Greater);
+    test("5.0", "0x5.0#3", 3, Nearest, "0.38", "0x0.6#3", Greater);
+    test("0.50", "0x0.8#1", 3, Floor, "0.0020", "0x0.008#1", Less);
+    test("0.50", "0x0.8#1", 3, Ceiling, "0.0039", "0x0.01#1", Greater);
+    test("0.50", "0x0.8#1", 3, Down, "0.0020", "0x0.008#1", Less);
+    test("0.50", "0x0.8#1", 3, Up, "0.0039", "0x0.01#1", Greater);
+    test("0.50", "0x0.8#1", 3, Nearest, "0.0020", "0x0.008#1", Less);
+    test("-0.50", "-0x0.8#1", 3, Floor, "-0.0039", "-0x0.01#1", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        Ceiling,
+        "-0.0020",
+        "-0x0.008#1",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        Down,
+        "-0.0020",
+        "-0x0.008#1",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 3, Up, "-0.0039", "-0x0.01#1", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        Nearest,
+        "-0.0020",
+        "-0x0.008#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Floor,
+        "-0.01953",
+        "-0x0.050#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Ceiling,
+        "-0.01929",
+        "-0x0.04f#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Down,
+        "-0.01929",
+        "-0x0.04f#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 3, Up, "-0.01953", "-0x0.050#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Nearest,
+        "-0.01929",
+        "-0x0.04f#7",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        Floor,
+        "-0.016235",
+        "-0x0.0428#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        Ceiling,
+        "-0.016205",
+        "-0x0.0426#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        Down,
+        "-0.016205",
+        "-0x0.0426#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        Up,
+        "-0.016235",
+        "-0x0.0428#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        Nearest,
+        "-0.016235",
+        "-0x0.0428#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Floor,
+        "0.33345833620298926",
+        "0x0.555d868892068#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Ceiling,
+        "0.33345833620298970",
+        "0x0.555d86889206a#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Down,
+        "0.33345833620298926",
+        "0x0.555d868892068#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Up,
+        "0.33345833620298970",
+        "0x0.555d86889206a#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Nearest,
+        "0.33345833620298970",
+        "0x0.555d86889206a#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Floor,
+        "0.25746748033807287",
+        "0x0.41e96387d5e658#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Ceiling,
+        "0.25746748033807293",
+        "0x0.41e96387d5e65c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Down,
+        "0.25746748033807287",
+        "0x0.41e96387d5e658#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Up,
+        "0.25746748033807293",
+        "0x0.41e96387d5e65c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Nearest,
+        "0.25746748033807287",
+        "0x0.41e96387d5e658#53",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Floor,
+        "7.67650727e-6",
+        "0x0.000080ca59#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Ceiling,
+        "7.67650818e-6",
+        "0x0.000080ca5a#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Down,
+        "7.67650727e-6",
+        "0x0.000080ca59#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Up,
+        "7.67650818e-6",
+        "0x0.000080ca5a#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Nearest,
+        "7.67650818e-6",
+        "0x0.000080ca5a#24",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, Floor, "-0.148", "-0x0.26#5", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        Ceiling,
+        "-0.141",
+        "-0x0.24#5",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, Down, "-0.141", "-0x0.24#5", Greater);
+    test("27.0", "0x1b.0#5", 3, Up, "-0.148", "-0x0.26#5", Less);
+    test("27.0", "0x1b.0#5", 3, Nearest, "-0.148", "-0x0.26#5", Less);
+    test("-6.0", "-0x6.0#2", 3, Floor, "-0.12", "-0x0.2#2", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        Ceiling,
+        "-0.094",
+        "-0x0.18#2",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 3, Down, "-0.094", "-0x0.18#2", Greater);
+    test("-6.0", "-0x6.0#2", 3, Up, "-0.12", "-0x0.2#2", Less);
+    test("-6.0", "-0x6.0#2", 3, Nearest, "-0.12", "-0x0.2#2", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Floor,
+        "7.7e-93",
+        "0x4.0E-77#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Ceiling,
+        "1.5e-92",
+        "0x8.0E-77#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Down,
+        "7.7e-93",
+        "0x4.0E-77#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Up,
+        "1.5e-92",
+        "0x8.0E-77#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Nearest,
+        "7.7e-93",
+        "0x4.0E-77#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Floor,
+        "0.00076626143205232658855358562126801",
+        "0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Ceiling,
+        "0.00076626143205232658855358562126878",
+        "0x0.003237bbca7533d9c2ba1d1305f0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Down,
+        "0.00076626143205232658855358562126801",
+        "0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Up,
+        "0.00076626143205232658855358562126878",
+        "0x0.003237bbca7533d9c2ba1d1305f0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Nearest,
+        "0.00076626143205232658855358562126801",
+        "0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Floor,
+        "-0.00076626143205232658855358562126878",
+        "-0x0.003237bbca7533d9c2ba1d1305f0#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Ceiling,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Down,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Up,
+        "-0.00076626143205232658855358562126878",
+        "-0x0.003237bbca7533d9c2ba1d1305f0#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Nearest,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Floor,
+        "0.76519768655796655144971752610219",
+        "0x0.c3e3fedebdc77c1cf1a986115#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Ceiling,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Down,
+        "0.76519768655796655144971752610219",
+        "0x0.c3e3fedebdc77c1cf1a986115#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Up,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Nearest,
+        "0.76519768655796655144971752610298",
+        "0x0.c3e3fedebdc77c1cf1a986116#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Floor,
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Ceiling,
+        "-0.30424217764409356",
+        "-0x0.4de2d0bb0b928#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Down,
+        "-0.30424217764409356",
+        "-0x0.4de2d0bb0b928#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Up,
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Nearest,
+        "-0.30424217764409400",
+        "-0x0.4de2d0bb0b92a#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Floor,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Ceiling,
+        "0.97241452911509749035342949938995",
+        "0x0.f8f02898b45f96418cbf7b1f5#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Down,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Up,
+        "0.97241452911509749035342949938995",
+        "0x0.f8f02898b45f96418cbf7b1f5#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Nearest,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test("0.0", "0x0.0", 0, Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 0, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 0, Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 0, Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 0, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 0, Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 0, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 0, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 0, Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 0, Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 0, Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 0, Exact, "1.0", "0x1.0#1", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.44005058574493351595968220371931",
+        "0x0.70a727ba5c31b14d8b9e340198#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.44005058574493351595968220371931",
+        "0x0.70a727ba5c31b14d8b9e340198#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.44005058574493351595968220371891",
+        "0x0.70a727ba5c31b14d8b9e340190#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.28461534317975312",
+        "0x0.48dc8d16e5986#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.28461534317975312",
+        "0x0.48dc8d16e5986#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.28461534317975268",
+        "0x0.48dc8d16e5984#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.16436254381427073536379869828606",
+        "0x0.2a13a9e65ea20818f67984fe5c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.16436254381427073536379869828606",
+        "0x0.2a13a9e65ea20818f67984fe5c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Floor,
+        "-0.44005058574493351595968220371931",
+        "-0x0.70a727ba5c31b14d8b9e340198#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Ceiling,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Down,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Up,
+        "-0.44005058574493351595968220371931",
+        "-0x0.70a727ba5c31b14d8b9e340198#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Nearest,
+        "-0.44005058574493351595968220371891",
+        "-0x0.70a727ba5c31b14d8b9e340190#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Floor,
+        "-0.28461534317975312",
+        "-0x0.48dc8d16e5986#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Ceiling,
+        "-0.28461534317975268",
+        "-0x0.48dc8d16e5984#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Down,
+        "-0.28461534317975268",
+        "-0x0.48dc8d16e5984#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Up,
+        "-0.28461534317975312",
+        "-0x0.48dc8d16e5986#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Nearest,
+        "-0.28461534317975268",
+        "-0x0.48dc8d16e5984#50",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Floor,
+        "-0.16436254381427073536379869828606",
+        "-0x0.2a13a9e65ea20818f67984fe5c#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Ceiling,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Down,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Up,
+        "-0.16436254381427073536379869828606",
+        "-0x0.2a13a9e65ea20818f67984fe5c#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Nearest,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -1, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, Down, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, Up, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Floor,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Ceiling,
+        "0.11490348493190048046964688133525",
+        "0x0.1d6a5095fa9be67e2592e1f1c8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Down,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Up,
+        "0.11490348493190048046964688133525",
+        "0x0.1d6a5095fa9be67e2592e1f1c8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Nearest,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Floor,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Ceiling,
+        "0.48543393263150936",
+        "0x0.7c4565f105604#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Down,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Up,
+        "0.48543393263150936",
+        "0x0.7c4565f105604#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Nearest,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Floor,
+        "0.013760733770526921829362690325590",
+        "0x0.0385d2cd836c9a543a19a2d6c7c#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Ceiling,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Down,
+        "0.013760733770526921829362690325590",
+        "0x0.0385d2cd836c9a543a19a2d6c7c#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Up,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Nearest,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 2, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Floor,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Ceiling,
+        "0.11490348493190048046964688133525",
+        "0x0.1d6a5095fa9be67e2592e1f1c8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Down,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Up,
+        "0.11490348493190048046964688133525",
+        "0x0.1d6a5095fa9be67e2592e1f1c8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Nearest,
+        "0.11490348493190048046964688133515",
+        "0x0.1d6a5095fa9be67e2592e1f1c6#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Floor,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Ceiling,
+        "0.48543393263150936",
+        "0x0.7c4565f105604#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Down,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Up,
+        "0.48543393263150936",
+        "0x0.7c4565f105604#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Nearest,
+        "0.48543393263150891",
+        "0x0.7c4565f105602#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Floor,
+        "0.013760733770526921829362690325590",
+        "0x0.0385d2cd836c9a543a19a2d6c7c#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Ceiling,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Down,
+        "0.013760733770526921829362690325590",
+        "0x0.0385d2cd836c9a543a19a2d6c7c#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Up,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Nearest,
+        "0.013760733770526921829362690325603",
+        "0x0.0385d2cd836c9a543a19a2d6c80#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -2, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Floor,
+        "-0.019563353982668405918905321621772",
+        "-0x0.05021a9d8e3de8ab0aad53c58a0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Ceiling,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Down,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Up,
+        "-0.019563353982668405918905321621772",
+        "-0x0.05021a9d8e3de8ab0aad53c58a0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Nearest,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Floor,
+        "-0.33345833620298970",
+        "-0x0.555d86889206a#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Ceiling,
+        "-0.33345833620298926",
+        "-0x0.555d868892068#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Down,
+        "-0.33345833620298926",
+        "-0x0.555d868892068#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Up,
+        "-0.33345833620298970",
+        "-0x0.555d86889206a#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Nearest,
+        "-0.33345833620298970",
+        "-0x0.555d86889206a#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Floor,
+        "-0.00076626143205232658855358562126878",
+        "-0x0.003237bbca7533d9c2ba1d1305f0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Ceiling,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Down,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Up,
+        "-0.00076626143205232658855358562126878",
+        "-0x0.003237bbca7533d9c2ba1d1305f0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Nearest,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -3, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, Down, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, Up, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, Nearest, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "2.6306151236874532069978536877929e-10",
+        "0x1.213d3baa4f2958d9d02fd1c86E-8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "2.6306151236874532069978536877929e-10",
+        "0x1.213d3baa4f2958d9d02fd1c86E-8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.000020094972255377486",
+        "0x0.000151233fa7956a8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.000020094972255377486",
+        "0x0.000151233fa7956a8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "4.5459812970034469895213174977372e-15",
+        "0x1.47928db56ec345e7480c6693aE-12#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "4.5459812970034469895213174977372e-15",
+        "0x1.47928db56ec345e7480c6693aE-12#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Floor,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Ceiling,
+        "2.6306151236874532069978536877929e-10",
+        "0x1.213d3baa4f2958d9d02fd1c86E-8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Down,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Up,
+        "2.6306151236874532069978536877929e-10",
+        "0x1.213d3baa4f2958d9d02fd1c86E-8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Nearest,
+        "2.6306151236874532069978536877892e-10",
+        "0x1.213d3baa4f2958d9d02fd1c84E-8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Floor,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Ceiling,
+        "0.000020094972255377486",
+        "0x0.000151233fa7956a8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Down,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Up,
+        "0.000020094972255377486",
+        "0x0.000151233fa7956a8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Nearest,
+        "0.000020094972255377459",
+        "0x0.000151233fa7956a0#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Floor,
+        "4.5459812970034469895213174977372e-15",
+        "0x1.47928db56ec345e7480c6693aE-12#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Ceiling,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Down,
+        "4.5459812970034469895213174977372e-15",
+        "0x1.47928db56ec345e7480c6693aE-12#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Up,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Nearest,
+        "4.5459812970034469895213174977428e-15",
+        "0x1.47928db56ec345e7480c6693cE-12#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, Exact, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+fn bessel_jn_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.bessel_jn_round(3, Exact));
+    assert_panic!(X.bessel_jn_round_ref(3, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_jn_round_assign(3, Exact);
+    });
+}
+
+#[test]
+fn test_bessel_jn_prec_round() {
+    let test = |s, s_hex, n: i64, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (jn, o) = x.clone().bessel_jn_prec_round(n, prec, rm);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+        assert_eq!(o, o_out);
+
+        let (jn_alt, o_alt) = x.bessel_jn_prec_round_ref(n, prec, rm);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut jn_alt = x.clone();
+        let o_alt = jn_alt.bessel_jn_prec_round_assign(n, prec, rm);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn), ComparableFloatRef(&jn_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_jn, rug_o) =
+                rug_bessel_jn_prec_round(&rug::Float::exact_from(&x), n, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_jn)),
+                ComparableFloatRef(&jn)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 3, 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 10, Floor, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity", "Infinity", 3, 10, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test("Infinity", "Infinity", 3, 10, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 10, Up, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity", "Infinity", 3, 10, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test("Infinity", "Infinity", 3, 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity", "Infinity", 3, 100, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test("-Infinity", "-Infinity", 3, 1, Floor, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 3, 1, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, 1, Up, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 3, 1, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 3, 10, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, 10, Up, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", 3, 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 10, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 3, 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 100, Nearest, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 3, 1, Floor, "0.016", "0x0.04#1", Less);
+    test(
+        "1.0", "0x1.0#1", 3, 1, Ceiling, "0.031", "0x0.08#1", Greater,
+    );
+    test("1.0", "0x1.0#1", 3, 1, Down, "0.016", "0x0.04#1", Less);
+    test("1.0", "0x1.0#1", 3, 1, Up, "0.031", "0x0.08#1", Greater);
+    test("1.0", "0x1.0#1", 3, 1, Nearest, "0.016", "0x0.04#1", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Floor,
+        "0.019562",
+        "0x0.0502#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Ceiling,
+        "0.019592",
+        "0x0.0504#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Down,
+        "0.019562",
+        "0x0.0502#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Up,
+        "0.019592",
+        "0x0.0504#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Nearest,
+        "0.019562",
+        "0x0.0502#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        Nearest,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 3, 1, Floor, "-0.031", "-0x0.08#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        Ceiling,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        Down,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 3, 1, Up, "-0.031", "-0x0.08#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        Nearest,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        Floor,
+        "-0.019592",
+        "-0x0.0504#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        Ceiling,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        Down,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        Up,
+        "-0.019592",
+        "-0x0.0504#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        Nearest,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        Nearest,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Floor,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Ceiling,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Down,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Up,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Nearest,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Floor,
+        "0.019562",
+        "0x0.0502#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Ceiling,
+        "0.019592",
+        "0x0.0504#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Down,
+        "0.019562",
+        "0x0.0502#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Up,
+        "0.019592",
+        "0x0.0504#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Nearest,
+        "0.019562",
+        "0x0.0502#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        100,
+        Nearest,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 3, 1, Floor, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 3, 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 3, 1, Down, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 3, 1, Up, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 3, 1, Nearest, "0.25", "0x0.4#1", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Floor,
+        "0.30859",
+        "0x0.4f0#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Ceiling,
+        "0.30908",
+        "0x0.4f2#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 3, 10, Down, "0.30859", "0x0.4f0#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Up,
+        "0.30908",
+        "0x0.4f2#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Nearest,
+        "0.30908",
+        "0x0.4f2#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        Nearest,
+        "0.30906272225525164361826019494672",
+        "0x0.4f1ebc0c7fc0e6740f6abdc9e0#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 3, 1, Floor, "0.25", "0x0.4#1", Less);
+    test("5.0", "0x5.0#3", 3, 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("5.0", "0x5.0#3", 3, 1, Down, "0.25", "0x0.4#1", Less);
+    test("5.0", "0x5.0#3", 3, 1, Up, "0.50", "0x0.8#1", Greater);
+    test("5.0", "0x5.0#3", 3, 1, Nearest, "0.25", "0x0.4#1", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Floor,
+        "0.36475",
+        "0x0.5d6#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Ceiling,
+        "0.36523",
+        "0x0.5d8#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 3, 10, Down, "0.36475", "0x0.5d6#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Up,
+        "0.36523",
+        "0x0.5d8#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Nearest,
+        "0.36475",
+        "0x0.5d6#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        100,
+        Nearest,
+        "0.36483123061366699446357694935854",
+        "0x0.5d65945c0b8de2b42a78f23a38#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 3, 1, Floor, "0.0020", "0x0.008#1", Less);
+    test(
+        "0.50", "0x0.8#1", 3, 1, Ceiling, "0.0039", "0x0.01#1", Greater,
+    );
+    test("0.50", "0x0.8#1", 3, 1, Down, "0.0020", "0x0.008#1", Less);
+    test("0.50", "0x0.8#1", 3, 1, Up, "0.0039", "0x0.01#1", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        1,
+        Nearest,
+        "0.0020",
+        "0x0.008#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Floor,
+        "0.0025635",
+        "0x0.00a80#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Ceiling,
+        "0.0025673",
+        "0x0.00a84#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Down,
+        "0.0025635",
+        "0x0.00a80#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Up,
+        "0.0025673",
+        "0x0.00a84#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Nearest,
+        "0.0025635",
+        "0x0.00a80#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        100,
+        Nearest,
+        "0.0025637299945872440753544715897785",
+        "0x0.00a804407b86dad17d356dde30e#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        1,
+        Floor,
+        "-0.0039",
+        "-0x0.01#1",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        1,
+        Ceiling,
+        "-0.0020",
+        "-0x0.008#1",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        1,
+        Down,
+        "-0.0020",
+        "-0x0.008#1",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 3, 1, Up, "-0.0039", "-0x0.01#1", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        1,
+        Nearest,
+        "-0.0020",
+        "-0x0.008#1",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        10,
+        Floor,
+        "-0.0025673",
+        "-0x0.00a84#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        10,
+        Ceiling,
+        "-0.0025635",
+        "-0x0.00a80#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        10,
+        Down,
+        "-0.0025635",
+        "-0x0.00a80#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        10,
+        Up,
+        "-0.0025673",
+        "-0x0.00a84#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        10,
+        Nearest,
+        "-0.0025635",
+        "-0x0.00a80#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        3,
+        100,
+        Nearest,
+        "-0.0025637299945872440753544715897785",
+        "-0x0.00a804407b86dad17d356dde30e#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        1,
+        Floor,
+        "-0.031",
+        "-0x0.08#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        1,
+        Ceiling,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        1,
+        Down,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 3, 1, Up, "-0.031", "-0x0.08#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        1,
+        Nearest,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Floor,
+        "-0.019348",
+        "-0x0.04f4#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Ceiling,
+        "-0.019318",
+        "-0x0.04f2#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Down,
+        "-0.019318",
+        "-0x0.04f2#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Up,
+        "-0.019348",
+        "-0x0.04f4#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Nearest,
+        "-0.019318",
+        "-0x0.04f2#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        100,
+        Nearest,
+        "-0.019326824349774733245008672575277",
+        "-0x0.04f29a4e848fc846d271e875cf8#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        1,
+        Floor,
+        "-0.031",
+        "-0x0.08#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        1,
+        Ceiling,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        1,
+        Down,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        1,
+        Up,
+        "-0.031",
+        "-0x0.08#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        1,
+        Nearest,
+        "-0.016",
+        "-0x0.04#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        10,
+        Floor,
+        "-0.016235",
+        "-0x0.0428#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        10,
+        Ceiling,
+        "-0.016205",
+        "-0x0.0426#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        10,
+        Down,
+        "-0.016205",
+        "-0x0.0426#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        10,
+        Up,
+        "-0.016235",
+        "-0x0.0428#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        10,
+        Nearest,
+        "-0.016235",
+        "-0x0.0428#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        100,
+        Nearest,
+        "-0.016224578596430978072442016453551",
+        "-0x0.04274b42768a9e0bac65dd90898#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Floor,
+        "0.33301",
+        "0x0.554#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Ceiling,
+        "0.33350",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Down,
+        "0.33301",
+        "0x0.554#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Up,
+        "0.33350",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Nearest,
+        "0.33350",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        100,
+        Nearest,
+        "0.33345833620298951493804420742911",
+        "0x0.555d86889206927ce2d1d7f950#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Floor,
+        "0.25732",
+        "0x0.41e#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Ceiling,
+        "0.25781",
+        "0x0.420#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Down,
+        "0.25732",
+        "0x0.41e#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Up,
+        "0.25781",
+        "0x0.420#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Nearest,
+        "0.25732",
+        "0x0.41e#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        Nearest,
+        "0.25746748033807289217707348042519",
+        "0x0.41e96387d5e65952c02f969510#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Floor,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Ceiling,
+        "0.000015",
+        "0x0.0001#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Down,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Up,
+        "0.000015",
+        "0x0.0001#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Nearest,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Floor,
+        "7.6741e-6",
+        "0x0.000080c#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Ceiling,
+        "7.6890e-6",
+        "0x0.0000810#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Down,
+        "7.6741e-6",
+        "0x0.000080c#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Up,
+        "7.6890e-6",
+        "0x0.0000810#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Nearest,
+        "7.6741e-6",
+        "0x0.000080c#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        100,
+        Nearest,
+        "7.6765081748139204022731189715024e-6",
+        "0x0.000080ca59ffb9939ce307a9b2f3b#100",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", 3, 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test(
+        "27.0", "0x1b.0#5", 3, 1, Ceiling, "-0.12", "-0x0.2#1", Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("27.0", "0x1b.0#5", 3, 1, Up, "-0.25", "-0x0.4#1", Less);
+    test(
+        "27.0", "0x1b.0#5", 3, 1, Nearest, "-0.12", "-0x0.2#1", Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Floor,
+        "-0.14600",
+        "-0x0.256#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Ceiling,
+        "-0.14575",
+        "-0x0.255#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Down,
+        "-0.14575",
+        "-0x0.255#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Up,
+        "-0.14600",
+        "-0x0.256#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Nearest,
+        "-0.14575",
+        "-0x0.255#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        100,
+        Nearest,
+        "-0.14586243281547302230370571462975",
+        "-0x0.25573d8aa84ee4f9ca49ecde54#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 3, 1, Floor, "-0.12", "-0x0.2#1", Less);
+    test(
+        "-6.0", "-0x6.0#2", 3, 1, Ceiling, "-0.062", "-0x0.1#1", Greater,
+    );
+    test(
+        "-6.0", "-0x6.0#2", 3, 1, Down, "-0.062", "-0x0.1#1", Greater,
+    );
+    test("-6.0", "-0x6.0#2", 3, 1, Up, "-0.12", "-0x0.2#1", Less);
+    test("-6.0", "-0x6.0#2", 3, 1, Nearest, "-0.12", "-0x0.2#1", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        10,
+        Floor,
+        "-0.11487",
+        "-0x0.1d68#10",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        10,
+        Ceiling,
+        "-0.11475",
+        "-0x0.1d60#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        10,
+        Down,
+        "-0.11475",
+        "-0x0.1d60#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        10,
+        Up,
+        "-0.11487",
+        "-0x0.1d68#10",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        10,
+        Nearest,
+        "-0.11475",
+        "-0x0.1d60#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        3,
+        100,
+        Nearest,
+        "-0.11476838482077529635949510307008",
+        "-0x0.1d6175fb6b8355064cb445f4be#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Floor,
+        "7.7e-93",
+        "0x4.0E-77#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Ceiling,
+        "1.5e-92",
+        "0x8.0E-77#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Down,
+        "7.7e-93",
+        "0x4.0E-77#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Up,
+        "1.5e-92",
+        "0x8.0E-77#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Nearest,
+        "7.7e-93",
+        "0x4.0E-77#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Floor,
+        "1.0217e-92",
+        "0x5.54E-77#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Ceiling,
+        "1.0232e-92",
+        "0x5.56E-77#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Down,
+        "1.0217e-92",
+        "0x5.54E-77#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Up,
+        "1.0232e-92",
+        "0x5.56E-77#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Nearest,
+        "1.0232e-92",
+        "0x5.56E-77#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        Nearest,
+        "1.0227278052703596985616191572892e-92",
+        "0x5.5555555555555555555555558E-77#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Floor,
+        "0.00049",
+        "0x0.002#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Ceiling,
+        "0.00098",
+        "0x0.004#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Down,
+        "0.00049",
+        "0x0.002#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Up,
+        "0.00098",
+        "0x0.004#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Nearest,
+        "0.00098",
+        "0x0.004#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Floor,
+        "0.00076580",
+        "0x0.00323#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Ceiling,
+        "0.00076675",
+        "0x0.00324#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Down,
+        "0.00076580",
+        "0x0.00323#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Up,
+        "0.00076675",
+        "0x0.00324#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Nearest,
+        "0.00076580",
+        "0x0.00323#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        100,
+        Nearest,
+        "0.00076626143205232658855358562126801",
+        "0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Floor,
+        "-0.00098",
+        "-0x0.004#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Ceiling,
+        "-0.00049",
+        "-0x0.002#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Down,
+        "-0.00049",
+        "-0x0.002#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Up,
+        "-0.00098",
+        "-0x0.004#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Nearest,
+        "-0.00098",
+        "-0x0.004#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Floor,
+        "-0.00076675",
+        "-0x0.00324#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Ceiling,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Down,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Up,
+        "-0.00076675",
+        "-0x0.00324#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Nearest,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        100,
+        Nearest,
+        "-0.00076626143205232658855358562126801",
+        "-0x0.003237bbca7533d9c2ba1d1305ec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Floor,
+        "0.76465",
+        "0x0.c3c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Ceiling,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Down,
+        "0.76465",
+        "0x0.c3c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Up,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Nearest,
+        "0.76562",
+        "0x0.c40#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Floor,
+        "-0.30469",
+        "-0x0.4e0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Ceiling,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Down,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Up,
+        "-0.30469",
+        "-0x0.4e0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Nearest,
+        "-0.30420",
+        "-0x0.4de#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Floor,
+        "0.97168",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Ceiling,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Down,
+        "0.97168",
+        "0x0.f8c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Up,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Nearest,
+        "0.97266",
+        "0x0.f90#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", 0, 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test("0.0", "0x0.0", 0, 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 0, 10, Up, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test("0.0", "0x0.0", 0, 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Floor,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 0, 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 0, 10, Up, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Exact,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Floor,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Ceiling,
+        "0.44043",
+        "0x0.70c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Down,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Up,
+        "0.44043",
+        "0x0.70c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Nearest,
+        "0.43994",
+        "0x0.70a#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Floor,
+        "0.28418",
+        "0x0.48c#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Ceiling,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Down,
+        "0.28418",
+        "0x0.48c#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Up,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Nearest,
+        "0.28467",
+        "0x0.48e#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Floor,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Ceiling,
+        "0.16455",
+        "0x0.2a2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Down,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Up,
+        "0.16455",
+        "0x0.2a2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Nearest,
+        "0.16431",
+        "0x0.2a1#10",
+        Less,
+    );
+    test("0.0", "0x0.0", 1, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 10, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 10, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, 10, Exact, "-0.0", "-0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Floor,
+        "-0.44043",
+        "-0x0.70c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Ceiling,
+        "-0.43994",
+        "-0x0.70a#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Down,
+        "-0.43994",
+        "-0x0.70a#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Up,
+        "-0.44043",
+        "-0x0.70c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Nearest,
+        "-0.43994",
+        "-0x0.70a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Floor,
+        "-0.28467",
+        "-0x0.48e#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Ceiling,
+        "-0.28418",
+        "-0x0.48c#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Down,
+        "-0.28418",
+        "-0x0.48c#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Up,
+        "-0.28467",
+        "-0x0.48e#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Nearest,
+        "-0.28467",
+        "-0x0.48e#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Floor,
+        "-0.16455",
+        "-0x0.2a2#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Ceiling,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Down,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Up,
+        "-0.16455",
+        "-0x0.2a2#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Nearest,
+        "-0.16431",
+        "-0x0.2a1#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", -1, 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, 10, Down, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, 10, Up, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -1, 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -1, 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Floor,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Ceiling,
+        "0.11499",
+        "0x0.1d70#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Down,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Up,
+        "0.11499",
+        "0x0.1d70#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Nearest,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Floor,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Ceiling,
+        "0.48584",
+        "0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Down,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Up,
+        "0.48584",
+        "0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Nearest,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Floor,
+        "0.013748",
+        "0x0.0385#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Ceiling,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Down,
+        "0.013748",
+        "0x0.0385#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Up,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Nearest,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", 2, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 2, 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Floor,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Ceiling,
+        "0.11499",
+        "0x0.1d70#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Down,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Up,
+        "0.11499",
+        "0x0.1d70#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Nearest,
+        "0.11487",
+        "0x0.1d68#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Floor,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Ceiling,
+        "0.48584",
+        "0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Down,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Up,
+        "0.48584",
+        "0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Nearest,
+        "0.48535",
+        "0x0.7c4#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Floor,
+        "0.013748",
+        "0x0.0385#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Ceiling,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Down,
+        "0.013748",
+        "0x0.0385#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Up,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Nearest,
+        "0.013763",
+        "0x0.0386#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", -2, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -2, 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -2, 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Floor,
+        "-0.019592",
+        "-0x0.0504#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Ceiling,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Down,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Up,
+        "-0.019592",
+        "-0x0.0504#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Nearest,
+        "-0.019562",
+        "-0x0.0502#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Floor,
+        "-0.33350",
+        "-0x0.556#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Ceiling,
+        "-0.33301",
+        "-0x0.554#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Down,
+        "-0.33301",
+        "-0x0.554#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Up,
+        "-0.33350",
+        "-0x0.556#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Nearest,
+        "-0.33350",
+        "-0x0.556#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Floor,
+        "-0.00076675",
+        "-0x0.00324#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Ceiling,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Down,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Up,
+        "-0.00076675",
+        "-0x0.00324#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Nearest,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", -3, 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, 10, Down, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, 10, Up, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", -3, 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -3, 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Floor,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Ceiling,
+        "2.6330e-10",
+        "0x1.218E-8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Down,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Up,
+        "2.6330e-10",
+        "0x1.218E-8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Nearest,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Floor,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Ceiling,
+        "0.000020117",
+        "0x0.0001518#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Down,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Up,
+        "0.000020117",
+        "0x0.0001518#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Nearest,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Floor,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Ceiling,
+        "4.5519e-15",
+        "0x1.480E-12#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Down,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Up,
+        "4.5519e-15",
+        "0x1.480E-12#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Nearest,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test("0.0", "0x0.0", 10, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Floor,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Ceiling,
+        "2.6330e-10",
+        "0x1.218E-8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Down,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Up,
+        "2.6330e-10",
+        "0x1.218E-8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Nearest,
+        "2.6284e-10",
+        "0x1.210E-8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Floor,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Ceiling,
+        "0.000020117",
+        "0x0.0001518#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Down,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Up,
+        "0.000020117",
+        "0x0.0001518#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Nearest,
+        "0.000020087",
+        "0x0.0001510#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Floor,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Ceiling,
+        "4.5519e-15",
+        "0x1.480E-12#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Down,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Up,
+        "4.5519e-15",
+        "0x1.480E-12#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Nearest,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test("0.0", "0x0.0", -10, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", -10, 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 10, Down, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 10, Up, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", -10, 10, Exact, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+fn bessel_jn_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).bessel_jn_prec_round(3, 0, Floor));
+    assert_panic!(Float::one_prec(1).bessel_jn_prec_round_ref(3, 0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.bessel_jn_prec_round_assign(3, 0, Floor)
+    });
+
+    assert_panic!(X.bessel_jn_prec_round(3, 1, Exact));
+    assert_panic!(X.bessel_jn_prec_round_ref(3, 1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_jn_prec_round_assign(3, 1, Exact)
+    });
+}
+
+#[test]
+fn test_bessel_jn_rational_prec() {
+    let test = |s, n: i64, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (jn, o) = Float::bessel_jn_rational_prec(x.clone(), n, prec);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+        assert_eq!(o, out_o);
+
+        let (jn, o) = Float::bessel_jn_rational_prec_ref(&x, n, prec);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_jn, rug_o) = rug_bessel_jn_rational_prec(&x, n, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_jn)),
+            ComparableFloatRef(&jn)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 3, 1, "0.0", "0x0.0", Equal);
+    test("0", 3, 10, "0.0", "0x0.0", Equal);
+    test("0", 3, 100, "0.0", "0x0.0", Equal);
+    test("1", 3, 1, "0.016", "0x0.04#1", Less);
+    test("1", 3, 10, "0.019562", "0x0.0502#10", Less);
+    test(
+        "1",
+        3,
+        100,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test("-1", 3, 1, "-0.016", "-0x0.04#1", Greater);
+    test("-1", 3, 10, "-0.019562", "-0x0.0502#10", Greater);
+    test(
+        "-1",
+        3,
+        100,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test("1/2", 3, 1, "0.0020", "0x0.008#1", Less);
+    test("1/2", 3, 10, "0.0025635", "0x0.00a80#10", Less);
+    test(
+        "1/2",
+        3,
+        100,
+        "0.0025637299945872440753544715897785",
+        "0x0.00a804407b86dad17d356dde30e#100",
+        Less,
+    );
+    test("-1/2", 3, 1, "-0.0020", "-0x0.008#1", Greater);
+    test("-1/2", 3, 10, "-0.0025635", "-0x0.00a80#10", Greater);
+    test(
+        "-1/2",
+        3,
+        100,
+        "-0.0025637299945872440753544715897785",
+        "-0x0.00a804407b86dad17d356dde30e#100",
+        Greater,
+    );
+    test("1/3", 3, 1, "0.00098", "0x0.004#1", Greater);
+    test("1/3", 3, 10, "0.00076580", "0x0.00323#10", Less);
+    test(
+        "1/3",
+        3,
+        100,
+        "0.00076626143205232658855358562126724",
+        "0x0.003237bbca7533d9c2ba1d1305e8#100",
+        Greater,
+    );
+    test("22/7", 3, 1, "0.25", "0x0.4#1", Less);
+    test("22/7", 3, 10, "0.33350", "0x0.556#10", Less);
+    test(
+        "22/7",
+        3,
+        100,
+        "0.33366944578165495867794058847010",
+        "0x0.556b5c5d4e80d30b966a3594a0#100",
+        Less,
+    );
+    test("3/2", 3, 1, "0.062", "0x0.1#1", Greater);
+    test("3/2", 3, 10, "0.060974", "0x0.0f9c#10", Greater);
+    test(
+        "3/2",
+        3,
+        100,
+        "0.060963951141139630643949559976458",
+        "0x0.0f9b556062d9d90a18bc81c795#100",
+        Less,
+    );
+    test("-7/2", 3, 1, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 3, 10, "-0.38672", "-0x0.630#10", Greater);
+    test(
+        "-7/2",
+        3,
+        100,
+        "-0.38677011171688136685787181211294",
+        "-0x0.63035db4e4ef0085eb366c8078#100",
+        Greater,
+    );
+    test("1000000", 3, 1, "0.00049", "0x0.002#1", Less);
+    test("1000000", 3, 10, "0.00072575", "0x0.002f9#10", Less);
+    test(
+        "1000000",
+        3,
+        100,
+        "0.00072596703263590033550304932552189",
+        "0x0.002f93b4a9c3e4e329814b2c41a4#100",
+        Greater,
+    );
+    test("1/3", 0, 10, "0.97266", "0x0.f90#10", Greater);
+    test(
+        "1/3",
+        0,
+        100,
+        "0.97241452911509749035342949938916",
+        "0x0.f8f02898b45f96418cbf7b1f4#100",
+        Less,
+    );
+    test("-1/2", 0, 10, "0.93848", "0x0.f04#10", Greater);
+    test(
+        "-1/2",
+        0,
+        100,
+        "0.93846980724081290422840467359994",
+        "0x0.f03f8eaa61f9a345127997946#100",
+        Greater,
+    );
+    test("22/7", 0, 10, "-0.30469", "-0x0.4e0#10", Less);
+    test(
+        "22/7",
+        0,
+        100,
+        "-0.30460175498724544660414036547866",
+        "-0x0.4dfa616ff973db703a92a88f70#100",
+        Less,
+    );
+    test("0", 0, 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        0,
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1/3", 1, 10, "0.16431", "0x0.2a1#10", Less);
+    test(
+        "1/3",
+        1,
+        100,
+        "0.16436254381427073536379869828586",
+        "0x0.2a13a9e65ea20818f67984fe58#100",
+        Greater,
+    );
+    test("-1/2", 1, 10, "-0.24219", "-0x0.3e0#10", Greater);
+    test(
+        "-1/2",
+        1,
+        100,
+        "-0.24226845767487388638395457614144",
+        "-0x0.3e054e3e90e1ad1053211a2530#100",
+        Greater,
+    );
+    test("22/7", 1, 10, "0.28418", "0x0.48c#10", Greater);
+    test(
+        "22/7",
+        1,
+        100,
+        "0.28411597080241516421768717733909",
+        "0x0.48bbd302de1f3a7ad50b650050#100",
+        Less,
+    );
+    test("0", 1, 10, "0.0", "0x0.0", Equal);
+    test("0", 1, 100, "0.0", "0x0.0", Equal);
+    test("1/3", -1, 10, "-0.16431", "-0x0.2a1#10", Greater);
+    test(
+        "1/3",
+        -1,
+        100,
+        "-0.16436254381427073536379869828586",
+        "-0x0.2a13a9e65ea20818f67984fe58#100",
+        Less,
+    );
+    test("-1/2", -1, 10, "0.24219", "0x0.3e0#10", Less);
+    test(
+        "-1/2",
+        -1,
+        100,
+        "0.24226845767487388638395457614144",
+        "0x0.3e054e3e90e1ad1053211a2530#100",
+        Less,
+    );
+    test("22/7", -1, 10, "-0.28418", "-0x0.48c#10", Less);
+    test(
+        "22/7",
+        -1,
+        100,
+        "-0.28411597080241516421768717733909",
+        "-0x0.48bbd302de1f3a7ad50b650050#100",
+        Greater,
+    );
+    test("0", -1, 10, "-0.0", "-0x0.0", Equal);
+    test("0", -1, 100, "-0.0", "-0x0.0", Equal);
+    test("1/3", 2, 10, "0.013763", "0x0.0386#10", Greater);
+    test(
+        "1/3",
+        2,
+        100,
+        "0.013760733770526921829362690325590",
+        "0x0.0385d2cd836c9a543a19a2d6c7c#100",
+        Less,
+    );
+    test("-1/2", 2, 10, "0.030609", "0x0.07d6#10", Greater);
+    test(
+        "-1/2",
+        2,
+        100,
+        "0.030604023458682641307413630966421",
+        "0x0.07d5aa4fe18d10fc3a0ad1006c8#100",
+        Greater,
+    );
+    test("22/7", 2, 10, "0.48535", "0x0.7c4#10", Less);
+    test(
+        "22/7",
+        2,
+        100,
+        "0.48540282731605509656085038742179",
+        "0x0.7c435c14b5592f32c2258bbe30#100",
+        Greater,
+    );
+    test("0", 2, 10, "0.0", "0x0.0", Equal);
+    test("0", 2, 100, "0.0", "0x0.0", Equal);
+    test("1/3", -2, 10, "0.013763", "0x0.0386#10", Greater);
+    test(
+        "1/3",
+        -2,
+        100,
+        "0.013760733770526921829362690325590",
+        "0x0.0385d2cd836c9a543a19a2d6c7c#100",
+        Less,
+    );
+    test("-1/2", -2, 10, "0.030609", "0x0.07d6#10", Greater);
+    test(
+        "-1/2",
+        -2,
+        100,
+        "0.030604023458682641307413630966421",
+        "0x0.07d5aa4fe18d10fc3a0ad1006c8#100",
+        Greater,
+    );
+    test("22/7", -2, 10, "0.48535", "0x0.7c4#10", Less);
+    test(
+        "22/7",
+        -2,
+        100,
+        "0.48540282731605509656085038742179",
+        "0x0.7c435c14b5592f32c2258bbe30#100",
+        Greater,
+    );
+    test("0", -2, 10, "0.0", "0x0.0", Equal);
+    test("0", -2, 100, "0.0", "0x0.0", Equal);
+    test("1/3", -3, 10, "-0.00076580", "-0x0.00323#10", Greater);
+    test(
+        "1/3",
+        -3,
+        100,
+        "-0.00076626143205232658855358562126724",
+        "-0x0.003237bbca7533d9c2ba1d1305e8#100",
+        Less,
+    );
+    test("-1/2", -3, 10, "0.0025635", "0x0.00a80#10", Less);
+    test(
+        "-1/2",
+        -3,
+        100,
+        "0.0025637299945872440753544715897785",
+        "0x0.00a804407b86dad17d356dde30e#100",
+        Less,
+    );
+    test("22/7", -3, 10, "-0.33350", "-0x0.556#10", Greater);
+    test(
+        "22/7",
+        -3,
+        100,
+        "-0.33366944578165495867794058847010",
+        "-0x0.556b5c5d4e80d30b966a3594a0#100",
+        Greater,
+    );
+    test("0", -3, 10, "-0.0", "-0x0.0", Equal);
+    test("0", -3, 100, "-0.0", "-0x0.0", Equal);
+    test("1/3", 10, 10, "4.5450e-15", "0x1.478E-12#10", Less);
+    test(
+        "1/3",
+        10,
+        100,
+        "4.5459812970034469895213174977260e-15",
+        "0x1.47928db56ec345e7480c66936E-12#100",
+        Greater,
+    );
+    test("-1/2", 10, 10, "2.6112e-13", "0x4.98E-11#10", Less);
+    test(
+        "-1/2",
+        10,
+        100,
+        "2.6131773608228030862436154291210e-13",
+        "0x4.98ded66411c2e11a3630764b0E-11#100",
+        Less,
+    );
+    test("22/7", 10, 10, "0.000020176", "0x0.0001528#10", Greater);
+    test(
+        "22/7",
+        10,
+        100,
+        "0.000020172286922453472912948983725705",
+        "0x0.0001526f5007b2c14a75c3d282300#100",
+        Greater,
+    );
+    test("0", 10, 10, "0.0", "0x0.0", Equal);
+    test("0", 10, 100, "0.0", "0x0.0", Equal);
+    test("1/3", -10, 10, "4.5450e-15", "0x1.478E-12#10", Less);
+    test(
+        "1/3",
+        -10,
+        100,
+        "4.5459812970034469895213174977260e-15",
+        "0x1.47928db56ec345e7480c66936E-12#100",
+        Greater,
+    );
+    test("-1/2", -10, 10, "2.6112e-13", "0x4.98E-11#10", Less);
+    test(
+        "-1/2",
+        -10,
+        100,
+        "2.6131773608228030862436154291210e-13",
+        "0x4.98ded66411c2e11a3630764b0E-11#100",
+        Less,
+    );
+    test("22/7", -10, 10, "0.000020176", "0x0.0001528#10", Greater);
+    test(
+        "22/7",
+        -10,
+        100,
+        "0.000020172286922453472912948983725705",
+        "0x0.0001526f5007b2c14a75c3d282300#100",
+        Greater,
+    );
+    test("0", -10, 10, "0.0", "0x0.0", Equal);
+    test("0", -10, 100, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+#[should_panic]
+fn bessel_jn_rational_prec_fail() {
+    Float::bessel_jn_rational_prec(Rational::ONE, 3, 0);
+}
+
+#[test]
+#[should_panic]
+fn bessel_jn_rational_prec_ref_fail() {
+    Float::bessel_jn_rational_prec_ref(&Rational::ONE, 3, 0);
+}
+
+#[test]
+fn test_bessel_jn_rational_prec_round() {
+    let test = |s, n: i64, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (jn, o) = Float::bessel_jn_rational_prec_round(x.clone(), n, prec, rm);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+        assert_eq!(o, out_o);
+
+        let (jn, o) = Float::bessel_jn_rational_prec_round_ref(&x, n, prec, rm);
+        assert!(jn.is_valid());
+        assert_eq!(jn.to_string(), out);
+        assert_eq!(to_hex_string(&jn), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_jn, rug_o) = rug_bessel_jn_rational_prec_round(&x, n, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_jn)),
+                ComparableFloatRef(&jn)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 3, 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 3, 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 3, 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 3, 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 3, 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 3, 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 3, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 3, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 3, 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 3, 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 3, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 3, 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 3, 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 3, 1, Floor, "0.016", "0x0.04#1", Less);
+    test("1", 3, 1, Ceiling, "0.031", "0x0.08#1", Greater);
+    test("1", 3, 1, Down, "0.016", "0x0.04#1", Less);
+    test("1", 3, 1, Up, "0.031", "0x0.08#1", Greater);
+    test("1", 3, 1, Nearest, "0.016", "0x0.04#1", Less);
+    test("1", 3, 10, Floor, "0.019562", "0x0.0502#10", Less);
+    test("1", 3, 10, Ceiling, "0.019592", "0x0.0504#10", Greater);
+    test("1", 3, 10, Down, "0.019562", "0x0.0502#10", Less);
+    test("1", 3, 10, Up, "0.019592", "0x0.0504#10", Greater);
+    test("1", 3, 10, Nearest, "0.019562", "0x0.0502#10", Less);
+    test(
+        "1",
+        3,
+        100,
+        Nearest,
+        "0.019563353982668405918905321621747",
+        "0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Less,
+    );
+    test("-1", 3, 1, Floor, "-0.031", "-0x0.08#1", Less);
+    test("-1", 3, 1, Ceiling, "-0.016", "-0x0.04#1", Greater);
+    test("-1", 3, 1, Down, "-0.016", "-0x0.04#1", Greater);
+    test("-1", 3, 1, Up, "-0.031", "-0x0.08#1", Less);
+    test("-1", 3, 1, Nearest, "-0.016", "-0x0.04#1", Greater);
+    test("-1", 3, 10, Floor, "-0.019592", "-0x0.0504#10", Less);
+    test("-1", 3, 10, Ceiling, "-0.019562", "-0x0.0502#10", Greater);
+    test("-1", 3, 10, Down, "-0.019562", "-0x0.0502#10", Greater);
+    test("-1", 3, 10, Up, "-0.019592", "-0x0.0504#10", Less);
+    test("-1", 3, 10, Nearest, "-0.019562", "-0x0.0502#10", Greater);
+    test(
+        "-1",
+        3,
+        100,
+        Nearest,
+        "-0.019563353982668405918905321621747",
+        "-0x0.05021a9d8e3de8ab0aad53c5898#100",
+        Greater,
+    );
+    test("1/2", 3, 1, Floor, "0.0020", "0x0.008#1", Less);
+    test("1/2", 3, 1, Ceiling, "0.0039", "0x0.01#1", Greater);
+    test("1/2", 3, 1, Down, "0.0020", "0x0.008#1", Less);
+    test("1/2", 3, 1, Up, "0.0039", "0x0.01#1", Greater);
+    test("1/2", 3, 1, Nearest, "0.0020", "0x0.008#1", Less);
+    test("1/2", 3, 10, Floor, "0.0025635", "0x0.00a80#10", Less);
+    test("1/2", 3, 10, Ceiling, "0.0025673", "0x0.00a84#10", Greater);
+    test("1/2", 3, 10, Down, "0.0025635", "0x0.00a80#10", Less);
+    test("1/2", 3, 10, Up, "0.0025673", "0x0.00a84#10", Greater);
+    test("1/2", 3, 10, Nearest, "0.0025635", "0x0.00a80#10", Less);
+    test(
+        "1/2",
+        3,
+        100,
+        Nearest,
+        "0.0025637299945872440753544715897785",
+        "0x0.00a804407b86dad17d356dde30e#100",
+        Less,
+    );
+    test("-1/2", 3, 1, Floor, "-0.0039", "-0x0.01#1", Less);
+    test("-1/2", 3, 1, Ceiling, "-0.0020", "-0x0.008#1", Greater);
+    test("-1/2", 3, 1, Down, "-0.0020", "-0x0.008#1", Greater);
+    test("-1/2", 3, 1, Up, "-0.0039", "-0x0.01#1", Less);
+    test("-1/2", 3, 1, Nearest, "-0.0020", "-0x0.008#1", Greater);
+    test("-1/2", 3, 10, Floor, "-0.0025673", "-0x0.00a84#10", Less);
+    test(
+        "-1/2",
+        3,
+        10,
+        Ceiling,
+        "-0.0025635",
+        "-0x0.00a80#10",
+        Greater,
+    );
+    test("-1/2", 3, 10, Down, "-0.0025635", "-0x0.00a80#10", Greater);
+    test("-1/2", 3, 10, Up, "-0.0025673", "-0x0.00a84#10", Less);
+    test(
+        "-1/2",
+        3,
+        10,
+        Nearest,
+        "-0.0025635",
+        "-0x0.00a80#10",
+        Greater,
+    );
+    test(
+        "-1/2",
+        3,
+        100,
+        Nearest,
+        "-0.0025637299945872440753544715897785",
+        "-0x0.00a804407b86dad17d356dde30e#100",
+        Greater,
+    );
+    test("1/3", 3, 1, Floor, "0.00049", "0x0.002#1", Less);
+    test("1/3", 3, 1, Ceiling, "0.00098", "0x0.004#1", Greater);
+    test("1/3", 3, 1, Down, "0.00049", "0x0.002#1", Less);
+    test("1/3", 3, 1, Up, "0.00098", "0x0.004#1", Greater);
+    test("1/3", 3, 1, Nearest, "0.00098", "0x0.004#1", Greater);
+    test("1/3", 3, 10, Floor, "0.00076580", "0x0.00323#10", Less);
+    test("1/3", 3, 10, Ceiling, "0.00076675", "0x0.00324#10", Greater);
+    test("1/3", 3, 10, Down, "0.00076580", "0x0.00323#10", Less);
+    test("1/3", 3, 10, Up, "0.00076675", "0x0.00324#10", Greater);
+    test("1/3", 3, 10, Nearest, "0.00076580", "0x0.00323#10", Less);
+    test(
+        "1/3",
+        3,
+        100,
+        Nearest,
+        "0.00076626143205232658855358562126724",
+        "0x0.003237bbca7533d9c2ba1d1305e8#100",
+        Greater,
+    );
+    test("22/7", 3, 1, Floor, "0.25", "0x0.4#1", Less);
+    test("22/7", 3, 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("22/7", 3, 1, Down, "0.25", "0x0.4#1", Less);
+    test("22/7", 3, 1, Up, "0.50", "0x0.8#1", Greater);
+    test("22/7", 3, 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("22/7", 3, 10, Floor, "0.33350", "0x0.556#10", Less);
+    test("22/7", 3, 10, Ceiling, "0.33398", "0x0.558#10", Greater);
+    test("22/7", 3, 10, Down, "0.33350", "0x0.556#10", Less);
+    test("22/7", 3, 10, Up, "0.33398", "0x0.558#10", Greater);
+    test("22/7", 3, 10, Nearest, "0.33350", "0x0.556#10", Less);
+    test(
+        "22/7",
+        3,
+        100,
+        Nearest,
+        "0.33366944578165495867794058847010",
+        "0x0.556b5c5d4e80d30b966a3594a0#100",
+        Less,
+    );
+    test("3/2", 3, 1, Floor, "0.031", "0x0.08#1", Less);
+    test("3/2", 3, 1, Ceiling, "0.062", "0x0.1#1", Greater);
+    test("3/2", 3, 1, Down, "0.031", "0x0.08#1", Less);
+    test("3/2", 3, 1, Up, "0.062", "0x0.1#1", Greater);
+    test("3/2", 3, 1, Nearest, "0.062", "0x0.1#1", Greater);
+    test("3/2", 3, 10, Floor, "0.060913", "0x0.0f98#10", Less);
+    test("3/2", 3, 10, Ceiling, "0.060974", "0x0.0f9c#10", Greater);
+    test("3/2", 3, 10, Down, "0.060913", "0x0.0f98#10", Less);
+    test("3/2", 3, 10, Up, "0.060974", "0x0.0f9c#10", Greater);
+    test("3/2", 3, 10, Nearest, "0.060974", "0x0.0f9c#10", Greater);
+    test(
+        "3/2",
+        3,
+        100,
+        Nearest,
+        "0.060963951141139630643949559976458",
+        "0x0.0f9b556062d9d90a18bc81c795#100",
+        Less,
+    );
+    test("-7/2", 3, 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 3, 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-7/2", 3, 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-7/2", 3, 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 3, 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test("-7/2", 3, 10, Floor, "-0.38721", "-0x0.632#10", Less);
+    test("-7/2", 3, 10, Ceiling, "-0.38672", "-0x0.630#10", Greater);
+    test("-7/2", 3, 10, Down, "-0.38672", "-0x0.630#10", Greater);
+    test("-7/2", 3, 10, Up, "-0.38721", "-0x0.632#10", Less);
+    test("-7/2", 3, 10, Nearest, "-0.38672", "-0x0.630#10", Greater);
+    test(
+        "-7/2",
+        3,
+        100,
+        Nearest,
+        "-0.38677011171688136685787181211294",
+        "-0x0.63035db4e4ef0085eb366c8078#100",
+        Greater,
+    );
+    test("1000000", 3, 1, Floor, "0.00049", "0x0.002#1", Less);
+    test("1000000", 3, 1, Ceiling, "0.00098", "0x0.004#1", Greater);
+    test("1000000", 3, 1, Down, "0.00049", "0x0.002#1", Less);
+    test("1000000", 3, 1, Up, "0.00098", "0x0.004#1", Greater);
+    test("1000000", 3, 1, Nearest, "0.00049", "0x0.002#1", Less);
+    test("1000000", 3, 10, Floor, "0.00072575", "0x0.002f9#10", Less);
+    test(
+        "1000000",
+        3,
+        10,
+        Ceiling,
+        "0.00072670",
+        "0x0.002fa#10",
+        Greater,
+    );
+    test("1000000", 3, 10, Down, "0.00072575", "0x0.002f9#10", Less);
+    test("1000000", 3, 10, Up, "0.00072670", "0x0.002fa#10", Greater);
+    test(
+        "1000000",
+        3,
+        10,
+        Nearest,
+        "0.00072575",
+        "0x0.002f9#10",
+        Less,
+    );
+    test(
+        "1000000",
+        3,
+        100,
+        Nearest,
+        "0.00072596703263590033550304932552189",
+        "0x0.002f93b4a9c3e4e329814b2c41a4#100",
+        Greater,
+    );
+    test("1/3", 0, 10, Floor, "0.97168", "0x0.f8c#10", Less);
+    test("1/3", 0, 10, Ceiling, "0.97266", "0x0.f90#10", Greater);
+    test("1/3", 0, 10, Down, "0.97168", "0x0.f8c#10", Less);
+    test("1/3", 0, 10, Up, "0.97266", "0x0.f90#10", Greater);
+    test("1/3", 0, 10, Nearest, "0.97266", "0x0.f90#10", Greater);
+    test("-1/2", 0, 10, Floor, "0.93750", "0x0.f00#10", Less);
+    test("-1/2", 0, 10, Ceiling, "0.93848", "0x0.f04#10", Greater);
+    test("-1/2", 0, 10, Down, "0.93750", "0x0.f00#10", Less);
+    test("-1/2", 0, 10, Up, "0.93848", "0x0.f04#10", Greater);
+    test("-1/2", 0, 10, Nearest, "0.93848", "0x0.f04#10", Greater);
+    test("22/7", 0, 10, Floor, "-0.30469", "-0x0.4e0#10", Less);
+    test("22/7", 0, 10, Ceiling, "-0.30420", "-0x0.4de#10", Greater);
+    test("22/7", 0, 10, Down, "-0.30420", "-0x0.4de#10", Greater);
+    test("22/7", 0, 10, Up, "-0.30469", "-0x0.4e0#10", Less);
+    test("22/7", 0, 10, Nearest, "-0.30469", "-0x0.4e0#10", Less);
+    test("0", 0, 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("0", 0, 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("0", 0, 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0", 0, 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("0", 0, 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("0", 0, 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test("1/3", 1, 10, Floor, "0.16431", "0x0.2a1#10", Less);
+    test("1/3", 1, 10, Ceiling, "0.16455", "0x0.2a2#10", Greater);
+    test("1/3", 1, 10, Down, "0.16431", "0x0.2a1#10", Less);
+    test("1/3", 1, 10, Up, "0.16455", "0x0.2a2#10", Greater);
+    test("1/3", 1, 10, Nearest, "0.16431", "0x0.2a1#10", Less);
+    test("-1/2", 1, 10, Floor, "-0.24243", "-0x0.3e1#10", Less);
+    test("-1/2", 1, 10, Ceiling, "-0.24219", "-0x0.3e0#10", Greater);
+    test("-1/2", 1, 10, Down, "-0.24219", "-0x0.3e0#10", Greater);
+    test("-1/2", 1, 10, Up, "-0.24243", "-0x0.3e1#10", Less);
+    test("-1/2", 1, 10, Nearest, "-0.24219", "-0x0.3e0#10", Greater);
+    test("22/7", 1, 10, Floor, "0.28369", "0x0.48a#10", Less);
+    test("22/7", 1, 10, Ceiling, "0.28418", "0x0.48c#10", Greater);
+    test("22/7", 1, 10, Down, "0.28369", "0x0.48a#10", Less);
+    test("22/7", 1, 10, Up, "0.28418", "0x0.48c#10", Greater);
+    test("22/7", 1, 10, Nearest, "0.28418", "0x0.48c#10", Greater);
+    test("0", 1, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, 10, Exact, "0.0", "0x0.0", Equal);
+    test("1/3", -1, 10, Floor, "-0.16455", "-0x0.2a2#10", Less);
+    test("1/3", -1, 10, Ceiling, "-0.16431", "-0x0.2a1#10", Greater);
+    test("1/3", -1, 10, Down, "-0.16431", "-0x0.2a1#10", Greater);
+    test("1/3", -1, 10, Up, "-0.16455", "-0x0.2a2#10", Less);
+    test("1/3", -1, 10, Nearest, "-0.16431", "-0x0.2a1#10", Greater);
+    test("-1/2", -1, 10, Floor, "0.24219", "0x0.3e0#10", Less);
+    test("-1/2", -1, 10, Ceiling, "0.24243", "0x0.3e1#10", Greater);
+    test("-1/2", -1, 10, Down, "0.24219", "0x0.3e0#10", Less);
+    test("-1/2", -1, 10, Up, "0.24243", "0x0.3e1#10", Greater);
+    test("-1/2", -1, 10, Nearest, "0.24219", "0x0.3e0#10", Less);
+    test("22/7", -1, 10, Floor, "-0.28418", "-0x0.48c#10", Less);
+    test("22/7", -1, 10, Ceiling, "-0.28369", "-0x0.48a#10", Greater);
+    test("22/7", -1, 10, Down, "-0.28369", "-0x0.48a#10", Greater);
+    test("22/7", -1, 10, Up, "-0.28418", "-0x0.48c#10", Less);
+    test("22/7", -1, 10, Nearest, "-0.28418", "-0x0.48c#10", Less);
+    test("0", -1, 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("0", -1, 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("0", -1, 10, Down, "-0.0", "-0x0.0", Equal);
+    test("0", -1, 10, Up, "-0.0", "-0x0.0", Equal);
+    test("0", -1, 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test("0", -1, 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1/3", 2, 10, Floor, "0.013748", "0x0.0385#10", Less);
+    test("1/3", 2, 10, Ceiling, "0.013763", "0x0.0386#10", Greater);
+    test("1/3", 2, 10, Down, "0.013748", "0x0.0385#10", Less);
+    test("1/3", 2, 10, Up, "0.013763", "0x0.0386#10", Greater);
+    test("1/3", 2, 10, Nearest, "0.013763", "0x0.0386#10", Greater);
+    test("-1/2", 2, 10, Floor, "0.030579", "0x0.07d4#10", Less);
+    test("-1/2", 2, 10, Ceiling, "0.030609", "0x0.07d6#10", Greater);
+    test("-1/2", 2, 10, Down, "0.030579", "0x0.07d4#10", Less);
+    test("-1/2", 2, 10, Up, "0.030609", "0x0.07d6#10", Greater);
+    test("-1/2", 2, 10, Nearest, "0.030609", "0x0.07d6#10", Greater);
+    test("22/7", 2, 10, Floor, "0.48535", "0x0.7c4#10", Less);
+    test("22/7", 2, 10, Ceiling, "0.48584", "0x0.7c6#10", Greater);
+    test("22/7", 2, 10, Down, "0.48535", "0x0.7c4#10", Less);
+    test("22/7", 2, 10, Up, "0.48584", "0x0.7c6#10", Greater);
+    test("22/7", 2, 10, Nearest, "0.48535", "0x0.7c4#10", Less);
+    test("0", 2, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 2, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 2, 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 2, 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 2, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 2, 10, Exact, "0.0", "0x0.0", Equal);
+    test("1/3", -2, 10, Floor, "0.013748", "0x0.0385#10", Less);
+    test("1/3", -2, 10, Ceiling, "0.013763", "0x0.0386#10", Greater);
+    test("1/3", -2, 10, Down, "0.013748", "0x0.0385#10", Less);
+    test("1/3", -2, 10, Up, "0.013763", "0x0.0386#10", Greater);
+    test("1/3", -2, 10, Nearest, "0.013763", "0x0.0386#10", Greater);
+    test("-1/2", -2, 10, Floor, "0.030579", "0x0.07d4#10", Less);
+    test("-1/2", -2, 10, Ceiling, "0.030609", "0x0.07d6#10", Greater);
+    test("-1/2", -2, 10, Down, "0.030579", "0x0.07d4#10", Less);
+    test("-1/2", -2, 10, Up, "0.030609", "0x0.07d6#10", Greater);
+    test("-1/2", -2, 10, Nearest, "0.030609", "0x0.07d6#10", Greater);
+    test("22/7", -2, 10, Floor, "0.48535", "0x0.7c4#10", Less);
+    test("22/7", -2, 10, Ceiling, "0.48584", "0x0.7c6#10", Greater);
+    test("22/7", -2, 10, Down, "0.48535", "0x0.7c4#10", Less);
+    test("22/7", -2, 10, Up, "0.48584", "0x0.7c6#10", Greater);
+    test("22/7", -2, 10, Nearest, "0.48535", "0x0.7c4#10", Less);
+    test("0", -2, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", -2, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", -2, 10, Down, "0.0", "0x0.0", Equal);
+    test("0", -2, 10, Up, "0.0", "0x0.0", Equal);
+    test("0", -2, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", -2, 10, Exact, "0.0", "0x0.0", Equal);
+    test("1/3", -3, 10, Floor, "-0.00076675", "-0x0.00324#10", Less);
+    test(
+        "1/3",
+        -3,
+        10,
+        Ceiling,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test("1/3", -3, 10, Down, "-0.00076580", "-0x0.00323#10", Greater);
+    test("1/3", -3, 10, Up, "-0.00076675", "-0x0.00324#10", Less);
+    test(
+        "1/3",
+        -3,
+        10,
+        Nearest,
+        "-0.00076580",
+        "-0x0.00323#10",
+        Greater,
+    );
+    test("-1/2", -3, 10, Floor, "0.0025635", "0x0.00a80#10", Less);
+    test(
+        "-1/2",
+        -3,
+        10,
+        Ceiling,
+        "0.0025673",
+        "0x0.00a84#10",
+        Greater,
+    );
+    test("-1/2", -3, 10, Down, "0.0025635", "0x0.00a80#10", Less);
+    test("-1/2", -3, 10, Up, "0.0025673", "0x0.00a84#10", Greater);
+    test("-1/2", -3, 10, Nearest, "0.0025635", "0x0.00a80#10", Less);
+    test("22/7", -3, 10, Floor, "-0.33398", "-0x0.558#10", Less);
+    test("22/7", -3, 10, Ceiling, "-0.33350", "-0x0.556#10", Greater);
+    test("22/7", -3, 10, Down, "-0.33350", "-0x0.556#10", Greater);
+    test("22/7", -3, 10, Up, "-0.33398", "-0x0.558#10", Less);
+    test("22/7", -3, 10, Nearest, "-0.33350", "-0x0.556#10", Greater);
+    test("0", -3, 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("0", -3, 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("0", -3, 10, Down, "-0.0", "-0x0.0", Equal);
+    test("0", -3, 10, Up, "-0.0", "-0x0.0", Equal);
+    test("0", -3, 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test("0", -3, 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1/3", 10, 10, Floor, "4.5450e-15", "0x1.478E-12#10", Less);
+    test(
+        "1/3",
+        10,
+        10,
+        Ceiling,
+        "4.5519e-15",
+        "0x1.480E-12#10",
+        Greater,
+    );
+    test("1/3", 10, 10, Down, "4.5450e-15", "0x1.478E-12#10", Less);
+    test("1/3", 10, 10, Up, "4.5519e-15", "0x1.480E-12#10", Greater);
+    test("1/3", 10, 10, Nearest, "4.5450e-15", "0x1.478E-12#10", Less);
+    test("-1/2", 10, 10, Floor, "2.6112e-13", "0x4.98E-11#10", Less);
+    test(
+        "-1/2",
+        10,
+        10,
+        Ceiling,
+        "2.6157e-13",
+        "0x4.9aE-11#10",
+        Greater,
+    );
+    test("-1/2", 10, 10, Down, "2.6112e-13", "0x4.98E-11#10", Less);
+    test("-1/2", 10, 10, Up, "2.6157e-13", "0x4.9aE-11#10", Greater);
+    test("-1/2", 10, 10, Nearest, "2.6112e-13", "0x4.98E-11#10", Less);
+    test("22/7", 10, 10, Floor, "0.000020146", "0x0.0001520#10", Less);
+    test(
+        "22/7",
+        10,
+        10,
+        Ceiling,
+        "0.000020176",
+        "0x0.0001528#10",
+        Greater,
+    );
+    test("22/7", 10, 10, Down, "0.000020146", "0x0.0001520#10", Less);
+    test("22/7", 10, 10, Up, "0.000020176", "0x0.0001528#10", Greater);
+    test(
+        "22/7",
+        10,
+        10,
+        Nearest,
+        "0.000020176",
+        "0x0.0001528#10",
+        Greater,
+    );
+    test("0", 10, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, 10, Exact, "0.0", "0x0.0", Equal);
+    test("1/3", -10, 10, Floor, "4.5450e-15", "0x1.478E-12#10", Less);
+    test(
+        "1/3",
+        -10,
+        10,
+        Ceiling,
+        "4.5519e-15",
+        "0x1.480E-12#10",
+        Greater,
+    );
+    test("1/3", -10, 10, Down, "4.5450e-15", "0x1.478E-12#10", Less);
+    test("1/3", -10, 10, Up, "4.5519e-15", "0x1.480E-12#10", Greater);
+    test(
+        "1/3",
+        -10,
+        10,
+        Nearest,
+        "4.5450e-15",
+        "0x1.478E-12#10",
+        Less,
+    );
+    test("-1/2", -10, 10, Floor, "2.6112e-13", "0x4.98E-11#10", Less);
+    test(
+        "-1/2",
+        -10,
+        10,
+        Ceiling,
+        "2.6157e-13",
+        "0x4.9aE-11#10",
+        Greater,
+    );
+    test("-1/2", -10, 10, Down, "2.6112e-13", "0x4.98E-11#10", Less);
+    test("-1/2", -10, 10, Up, "2.6157e-13", "0x4.9aE-11#10", Greater);
+    test(
+        "-1/2",
+        -10,
+        10,
+        Nearest,
+        "2.6112e-13",
+        "0x4.98E-11#10",
+        Less,
+    );
+    test(
+        "22/7",
+        -10,
+        10,
+        Floor,
+        "0.000020146",
+        "0x0.0001520#10",
+        Less,
+    );
+    test(
+        "22/7",
+        -10,
+        10,
+        Ceiling,
+        "0.000020176",
+        "0x0.0001528#10",
+        Greater,
+    );
+    test("22/7", -10, 10, Down, "0.000020146", "0x0.0001520#10", Less);
+    test(
+        "22/7",
+        -10,
+        10,
+        Up,
+        "0.000020176",
+        "0x0.0001528#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        -10,
+        10,
+        Nearest,
+        "0.000020176",
+        "0x0.0001528#10",
+        Greater,
+    );
+    test("0", -10, 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", -10, 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", -10, 10, Down, "0.0", "0x0.0", Equal);
+    test("0", -10, 10, Up, "0.0", "0x0.0", Equal);
+    test("0", -10, 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", -10, 10, Exact, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+#[should_panic]
+fn bessel_jn_rational_prec_round_fail_1() {
+    Float::bessel_jn_rational_prec_round(Rational::ONE, 3, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn bessel_jn_rational_prec_round_fail_2() {
+    Float::bessel_jn_rational_prec_round(Rational::ONE_HALF, 3, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn bessel_jn_rational_prec_round_ref_fail() {
+    Float::bessel_jn_rational_prec_round_ref(&Rational::ONE_HALF, 3, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_jn_prec_round_properties_helper(x: Float, n: i64, prec: u64, rm: RoundingMode) {
+    let (jn, o) = x.clone().bessel_jn_prec_round(n, prec, rm);
+    assert!(jn.is_valid());
+
+    let (jn_alt, o_alt) = x.bessel_jn_prec_round_ref(n, prec, rm);
+    assert!(jn_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_jn_prec_round_assign(n, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&jn));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let (jn_alt, o_alt) = x.clone().bessel_jn_prec(n, prec);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+
+        let (jn_alt, o_alt) = x.bessel_jn_prec_ref(n, prec);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.bessel_jn_prec_assign(n, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_jn, rug_o) =
+            rug_bessel_jn_prec_round(&rug::Float::exact_from(&x), n, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_jn)),
+            ComparableFloatRef(&jn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(jn.is_nan(), x.is_nan());
+    assert!(jn.is_nan() || (jn >= -1i32 && jn <= 1u32));
+
+    if jn.is_normal() {
+        assert_eq!(jn.get_prec(), Some(prec));
+    }
+
+    if x.is_finite() {
+        let (jn_alt, o_alt) = (-&x).bessel_jn_prec_round(n, prec, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt, o);
+        }
+        let (jn_alt, o_alt) = x.bessel_jn_prec_round_ref(-n, prec, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt, o);
+        }
+    }
+
+    if n == 0 {
+        let (jn_alt, o_alt) = x.bessel_j0_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+    if n == 1 {
+        let (jn_alt, o_alt) = x.bessel_j1_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+
+    if x.is_normal() {
+        let (jn_alt, o_alt) =
+            Float::bessel_jn_rational_prec_round(Rational::exact_from(&x), n, prec, rm);
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_jn_prec_round_ref(n, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(jn.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_jn_prec_round_ref(n, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_jn_prec_round_properties() {
+    float_signed_unsigned_rounding_mode_quadruple_gen_var_17().test_properties_with_limit(
+        1000,
+        |(x, n, prec, rm)| {
+            bessel_jn_prec_round_properties_helper(x, n, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        for n in -3..=3 {
+            let (jn, o) = Float::NAN.bessel_jn_prec_round(n, prec, rm);
+            assert!(jn.is_nan());
+            assert_eq!(o, Equal);
+
+            let (jn, o) = Float::INFINITY.bessel_jn_prec_round(n, prec, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Equal);
+
+            let (jn, o) = Float::NEGATIVE_INFINITY.bessel_jn_prec_round(n, prec, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Equal);
+
+            let expected = if n == 0 {
+                Float::one_prec(prec)
+            } else if n > 0 || n.even() {
+                Float::ZERO
+            } else {
+                Float::NEGATIVE_ZERO
+            };
+            let (jn, o) = Float::ZERO.bessel_jn_prec_round(n, prec, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+
+            let expected = if n == 0 {
+                Float::one_prec(prec)
+            } else if n < 0 || n.even() {
+                Float::ZERO
+            } else {
+                Float::NEGATIVE_ZERO
+            };
+            let (jn, o) = Float::NEGATIVE_ZERO.bessel_jn_prec_round(n, prec, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_jn_round_properties_helper(x: Float, n: i64, rm: RoundingMode) {
+    let (jn, o) = x.clone().bessel_jn_round(n, rm);
+    assert!(jn.is_valid());
+
+    let (jn_alt, o_alt) = x.bessel_jn_round_ref(n, rm);
+    assert!(jn_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_jn_round_assign(n, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&jn));
+    assert_eq!(o_alt, o);
+
+    let (jn_alt, o_alt) = x.bessel_jn_prec_round_ref(n, x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let jn_alt = x.clone().bessel_jn(n);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+
+        let jn_alt = (&x).bessel_jn(n);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+
+        let mut x_alt = x.clone();
+        x_alt.bessel_jn_assign(n);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&jn));
+
+        let rug_jn = rug_bessel_jn(&rug::Float::exact_from(&x), n);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_jn)),
+            ComparableFloatRef(&jn),
+        );
+    }
+
+    assert_eq!(jn.is_nan(), x.is_nan());
+    assert!(jn.is_nan() || (jn >= -1i32 && jn <= 1u32));
+
+    if x.is_finite() {
+        let (jn_alt, o_alt) = (-&x).bessel_jn_round(n, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt, o);
+        }
+        let (jn_alt, o_alt) = x.bessel_jn_round_ref(-n, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt, o);
+        }
+    }
+
+    if n == 0 {
+        let (jn_alt, o_alt) = x.bessel_j0_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+    if n == 1 {
+        let (jn_alt, o_alt) = x.bessel_j1_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_jn, rug_o) = rug_bessel_jn_round(&rug::Float::exact_from(&x), n, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_jn)),
+            ComparableFloatRef(&jn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_jn_round_ref(n, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(jn.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_jn_round_ref(n, Exact));
+    }
+}
+
+#[test]
+fn bessel_jn_round_properties() {
+    float_signed_rounding_mode_triple_gen_var_11().test_properties_with_limit(
+        1000,
+        |(x, n, rm)| {
+            bessel_jn_round_properties_helper(x, n, rm);
+        },
+    );
+
+    rounding_mode_gen().test_properties(|rm| {
+        for n in -3..=3 {
+            let (jn, o) = Float::NAN.bessel_jn_round(n, rm);
+            assert!(jn.is_nan());
+            assert_eq!(o, Equal);
+
+            let (jn, o) = Float::INFINITY.bessel_jn_round(n, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Equal);
+
+            let (jn, o) = Float::NEGATIVE_INFINITY.bessel_jn_round(n, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Equal);
+
+            let expected = if n == 0 {
+                Float::ONE
+            } else if n > 0 || n.even() {
+                Float::ZERO
+            } else {
+                Float::NEGATIVE_ZERO
+            };
+            let (jn, o) = Float::ZERO.bessel_jn_round(n, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+
+            let expected = if n == 0 {
+                Float::ONE
+            } else if n < 0 || n.even() {
+                Float::ZERO
+            } else {
+                Float::NEGATIVE_ZERO
+            };
+            let (jn, o) = Float::NEGATIVE_ZERO.bessel_jn_round(n, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_jn_rational_prec_round_properties_helper(
+    x: Rational,
+    n: i64,
+    prec: u64,
+    rm: RoundingMode,
+) {
+    let (jn, o) = Float::bessel_jn_rational_prec_round(x.clone(), n, prec, rm);
+    assert!(jn.is_valid());
+
+    let (jn_alt, o_alt) = Float::bessel_jn_rational_prec_round_ref(&x, n, prec, rm);
+    assert!(jn_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let (jn_alt, o_alt) = Float::bessel_jn_rational_prec(x.clone(), n, prec);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+
+        let (jn_alt, o_alt) = Float::bessel_jn_rational_prec_ref(&x, n, prec);
+        assert!(jn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+
+        let (rug_jn, rug_o) = rug_bessel_jn_rational_prec(&x, n, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_jn)),
+            ComparableFloatRef(&jn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_jn, rug_o) = rug_bessel_jn_rational_prec_round(&x, n, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_jn)),
+            ComparableFloatRef(&jn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!jn.is_nan());
+    assert!(jn.is_nan() || (jn >= -1i32 && jn <= 1u32));
+
+    if jn.is_normal() {
+        assert_eq!(jn.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (jn_alt, o_alt) =
+            Float::bessel_jn_rational_prec_round(-&x, n, prec, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt, o);
+        }
+        let (jn_alt, o_alt) =
+            Float::bessel_jn_rational_prec_round_ref(&x, -n, prec, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(jn_alt), ComparableFloat(jn.clone()));
+            assert_eq!(o_alt, o);
+        }
+    }
+
+    if n == 0 {
+        let (jn_alt, o_alt) = Float::bessel_j0_rational_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+    if n == 1 {
+        let (jn_alt, o_alt) = Float::bessel_j1_rational_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&jn_alt), ComparableFloatRef(&jn));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::bessel_jn_rational_prec_round_ref(&x, n, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(jn.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::bessel_jn_rational_prec_round_ref(&x, n, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_jn_rational_prec_round_properties() {
+    rational_signed_unsigned_rounding_mode_quadruple_gen_var_3().test_properties_with_limit(
+        1000,
+        |(x, n, prec, rm)| {
+            bessel_jn_rational_prec_round_properties_helper(x, n, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        for n in -3..=3 {
+            let expected = if n == 0 {
+                Float::one_prec(prec)
+            } else if n > 0 || n.even() {
+                Float::ZERO
+            } else {
+                Float::NEGATIVE_ZERO
+            };
+            let (jn, o) = Float::bessel_jn_rational_prec_round(Rational::ZERO, n, prec, rm);
+            assert_eq!(ComparableFloat(jn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_jn() {
+    fn test<T: PrimitiveFloat>(x: T, n: i64, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_bessel_jn(x, n)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, 3, f32::NAN);
+    test::<f32>(f32::INFINITY, 3, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, 3, 0.0);
+    test::<f32>(0.0, 3, 0.0);
+    test::<f32>(-0.0, 3, -0.0);
+    test::<f32>(1.0, 3, 0.019563355);
+    test::<f32>(-1.0, 3, -0.019563355);
+    test::<f32>(0.5, 3, 0.0025637299);
+    test::<f32>(-0.5, 3, -0.0025637299);
+    test::<f32>(2.0, 3, 0.12894325);
+    test::<f32>(5.0, 3, 0.36483124);
+    test::<f32>(-2.5, 3, -0.21660039);
+    test::<f32>(10.0, 3, 0.05837938);
+    test::<f32>(0.001, 3, 2.0833335e-11);
+    test::<f32>(10000000000.0, 3, 7.676508e-6);
+    test::<f32>(1.0, 0, 0.7651977);
+    test::<f32>(-2.5, 0, -0.048383776);
+    test::<f32>(0.0, 0, 1.0);
+    test::<f32>(-0.0, 0, 1.0);
+    test::<f32>(0.001, 0, 0.99999976);
+    test::<f32>(1.0, 1, 0.44005057);
+    test::<f32>(-2.5, 1, -0.4970941);
+    test::<f32>(0.0, 1, 0.0);
+    test::<f32>(-0.0, 1, -0.0);
+    test::<f32>(0.001, 1, 0.00049999997);
+    test::<f32>(1.0, -1, -0.44005057);
+    test::<f32>(-2.5, -1, 0.4970941);
+    test::<f32>(0.0, -1, -0.0);
+    test::<f32>(-0.0, -1, 0.0);
+    test::<f32>(0.001, -1, -0.00049999997);
+    test::<f32>(1.0, 2, 0.11490349);
+    test::<f32>(-2.5, 2, 0.44605905);
+    test::<f32>(0.0, 2, 0.0);
+    test::<f32>(-0.0, 2, 0.0);
+    test::<f32>(0.001, 2, 1.25e-7);
+    test::<f32>(1.0, -2, 0.11490349);
+    test::<f32>(-2.5, -2, 0.44605905);
+    test::<f32>(0.0, -2, 0.0);
+    test::<f32>(-0.0, -2, 0.0);
+    test::<f32>(0.001, -2, 1.25e-7);
+    test::<f32>(1.0, -3, -0.019563355);
+    test::<f32>(-2.5, -3, 0.21660039);
+    test::<f32>(0.0, -3, -0.0);
+    test::<f32>(-0.0, -3, 0.0);
+    test::<f32>(0.001, -3, -2.0833335e-11);
+    test::<f32>(1.0, 10, 2.6306152e-10);
+    test::<f32>(-2.5, 10, 2.2247284e-6);
+    test::<f32>(0.0, 10, 0.0);
+    test::<f32>(-0.0, 10, 0.0);
+    test::<f32>(0.001, 10, 2.69115e-40);
+    test::<f32>(1.0, -10, 2.6306152e-10);
+    test::<f32>(-2.5, -10, 2.2247284e-6);
+    test::<f32>(0.0, -10, 0.0);
+    test::<f32>(-0.0, -10, 0.0);
+    test::<f32>(0.001, -10, 2.69115e-40);
+
+    test::<f64>(f64::NAN, 3, f64::NAN);
+    test::<f64>(f64::INFINITY, 3, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, 3, 0.0);
+    test::<f64>(0.0, 3, 0.0);
+    test::<f64>(-0.0, 3, -0.0);
+    test::<f64>(1.0, 3, 0.019563353982668407);
+    test::<f64>(-1.0, 3, -0.019563353982668407);
+    test::<f64>(0.5, 3, 0.002563729994587244);
+    test::<f64>(-0.5, 3, -0.002563729994587244);
+    test::<f64>(2.0, 3, 0.12894324947440206);
+    test::<f64>(5.0, 3, 0.364831230613667);
+    test::<f64>(-2.5, 3, -0.21660039103911352);
+    test::<f64>(10.0, 3, 0.058379379305186815);
+    test::<f64>(0.001, 3, 2.0833332031250035e-11);
+    test::<f64>(10000000000.0, 3, 7.676508174813921e-6);
+    test::<f64>(1.0e300, 3, 1.3681360450342481e-151);
+    test::<f64>(1.0, 0, 0.7651976865579666);
+    test::<f64>(-2.5, 0, -0.048383776468198);
+    test::<f64>(0.0, 0, 1.0);
+    test::<f64>(-0.0, 0, 1.0);
+    test::<f64>(0.001, 0, 0.9999997500000156);
+    test::<f64>(1.0, 1, 0.4400505857449335);
+    test::<f64>(-2.5, 1, -0.49709410246427405);
+    test::<f64>(0.0, 1, 0.0);
+    test::<f64>(-0.0, 1, -0.0);
+    test::<f64>(0.001, 1, 0.0004999999375000026);
+    test::<f64>(1.0, -1, -0.4400505857449335);
+    test::<f64>(-2.5, -1, 0.49709410246427405);
+    test::<f64>(0.0, -1, -0.0);
+    test::<f64>(-0.0, -1, 0.0);
+    test::<f64>(0.001, -1, -0.0004999999375000026);
+    test::<f64>(1.0, 2, 0.11490348493190047);
+    test::<f64>(-2.5, 2, 0.44605905843961724);
+    test::<f64>(0.0, 2, 0.0);
+    test::<f64>(-0.0, 2, 0.0);
+    test::<f64>(0.001, 2, 1.2499998958333365e-7);
+    test::<f64>(1.0, -2, 0.11490348493190047);
+    test::<f64>(-2.5, -2, 0.44605905843961724);
+    test::<f64>(0.0, -2, 0.0);
+    test::<f64>(-0.0, -2, 0.0);
+    test::<f64>(0.001, -2, 1.2499998958333365e-7);
+    test::<f64>(1.0, -3, -0.019563353982668407);
+    test::<f64>(-2.5, -3, 0.21660039103911352);
+    test::<f64>(0.0, -3, -0.0);
+    test::<f64>(-0.0, -3, 0.0);
+    test::<f64>(0.001, -3, -2.0833332031250035e-11);
+    test::<f64>(1.0, 10, 2.6306151236874534e-10);
+    test::<f64>(-2.5, 10, 2.2247284173983834e-6);
+    test::<f64>(0.0, 10, 0.0);
+    test::<f64>(-0.0, 10, 0.0);
+    test::<f64>(0.001, 10, 2.6911443943049994e-40);
+    test::<f64>(1.0, -10, 2.6306151236874534e-10);
+    test::<f64>(-2.5, -10, 2.2247284173983834e-6);
+    test::<f64>(0.0, -10, 0.0);
+    test::<f64>(-0.0, -10, 0.0);
+    test::<f64>(0.001, -10, 2.6911443943049994e-40);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_jn_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_signed_pair_gen_var_4::<T, i64>().test_properties_with_limit(1000, |(x, n)| {
+        let jn = primitive_float_bessel_jn(x, n);
+        assert_eq!(jn.is_nan(), x.is_nan());
+        assert!(jn.is_nan() || jn >= -T::ONE && jn <= T::ONE);
+        if x.is_finite() {
+            let (jn_neg_x, jn_neg_n) = (
+                primitive_float_bessel_jn(-x, n),
+                primitive_float_bessel_jn(x, -n),
+            );
+            if n.odd() {
+                assert_eq!(NiceFloat(jn_neg_x), NiceFloat(-jn));
+                assert_eq!(NiceFloat(jn_neg_n), NiceFloat(-jn));
+            } else {
+                assert_eq!(NiceFloat(jn_neg_x), NiceFloat(jn));
+                assert_eq!(NiceFloat(jn_neg_n), NiceFloat(jn));
+            }
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_jn_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_jn_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_jn_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, n: i64, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_bessel_jn_rational(&x, n)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", 3, 0.0);
+    test::<f32>("1", 3, 0.019563355);
+    test::<f32>("-1", 3, -0.019563355);
+    test::<f32>("1/2", 3, 0.0025637299);
+    test::<f32>("-1/2", 3, -0.0025637299);
+    test::<f32>("1/3", 3, 0.0007662614);
+    test::<f32>("22/7", 3, 0.33366945);
+    test::<f32>("3/2", 3, 0.06096395);
+    test::<f32>("-7/2", 3, -0.3867701);
+    test::<f32>("1000000", 3, 0.00072596705);
+    test::<f32>("1/3", 0, 0.97241455);
+    test::<f32>("-7/2", 0, -0.38012773);
+    test::<f32>("0", 0, 1.0);
+    test::<f32>("1/3", 1, 0.16436255);
+    test::<f32>("-7/2", 1, -0.13737753);
+    test::<f32>("0", 1, 0.0);
+    test::<f32>("1/3", -1, -0.16436255);
+    test::<f32>("-7/2", -1, 0.13737753);
+    test::<f32>("0", -1, -0.0);
+    test::<f32>("1/3", 2, 0.013760733);
+    test::<f32>("-7/2", 2, 0.4586292);
+    test::<f32>("0", 2, 0.0);
+    test::<f32>("1/3", -2, 0.013760733);
+    test::<f32>("-7/2", -2, 0.4586292);
+    test::<f32>("0", -2, 0.0);
+    test::<f32>("1/3", -3, -0.0007662614);
+    test::<f32>("-7/2", -3, 0.3867701);
+    test::<f32>("0", -3, -0.0);
+    test::<f32>("1/3", 10, 4.5459814e-15);
+    test::<f32>("-7/2", 10, 5.6009496e-5);
+    test::<f32>("0", 10, 0.0);
+    test::<f32>("1/3", -10, 4.5459814e-15);
+    test::<f32>("-7/2", -10, 5.6009496e-5);
+    test::<f32>("0", -10, 0.0);
+
+    test::<f64>("0", 3, 0.0);
+    test::<f64>("1", 3, 0.019563353982668407);
+    test::<f64>("-1", 3, -0.019563353982668407);
+    test::<f64>("1/2", 3, 0.002563729994587244);
+    test::<f64>("-1/2", 3, -0.002563729994587244);
+    test::<f64>("1/3", 3, 0.0007662614320523266);
+    test::<f64>("22/7", 3, 0.33366944578165497);
+    test::<f64>("3/2", 3, 0.06096395114113963);
+    test::<f64>("-7/2", 3, -0.38677011171688136);
+    test::<f64>("1000000", 3, 0.0007259670326359004);
+    test::<f64>("1/3", 0, 0.9724145291150975);
+    test::<f64>("-7/2", 0, -0.3801277399872634);
+    test::<f64>("0", 0, 1.0);
+    test::<f64>("1/3", 1, 0.16436254381427073);
+    test::<f64>("-7/2", 1, -0.1373775273623272);
+    test::<f64>("0", 1, 0.0);
+    test::<f64>("1/3", -1, -0.16436254381427073);
+    test::<f64>("-7/2", -1, 0.1373775273623272);
+    test::<f64>("0", -1, -0.0);
+    test::<f64>("1/3", 2, 0.013760733770526922);
+    test::<f64>("-7/2", 2, 0.4586291841943075);
+    test::<f64>("0", 2, 0.0);
+    test::<f64>("1/3", -2, 0.013760733770526922);
+    test::<f64>("-7/2", -2, 0.4586291841943075);
+    test::<f64>("0", -2, 0.0);
+    test::<f64>("1/3", -3, -0.0007662614320523266);
+    test::<f64>("-7/2", -3, 0.38677011171688136);
+    test::<f64>("0", -3, -0.0);
+    test::<f64>("1/3", 10, 4.545981297003447e-15);
+    test::<f64>("-7/2", 10, 5.600949587507884e-5);
+    test::<f64>("0", 10, 0.0);
+    test::<f64>("1/3", -10, 4.545981297003447e-15);
+    test::<f64>("-7/2", -10, 5.600949587507884e-5);
+    test::<f64>("0", -10, 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_jn_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties_with_limit(1000, |x| {
+        for n in -3..=3 {
+            let jn = primitive_float_bessel_jn_rational::<T>(&x, n);
+            assert!(!jn.is_nan());
+            assert!(jn >= -T::ONE && jn <= T::ONE);
+            if x != 0u32 {
+                let jn_alt = primitive_float_bessel_jn_rational::<T>(&-&x, n);
+                assert_eq!(NiceFloat(jn_alt), NiceFloat(if n.odd() { -jn } else { jn }));
+            }
+        }
+    });
+
+    primitive_float_signed_pair_gen_var_4::<T, i64>().test_properties_with_limit(1000, |(x, n)| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_bessel_jn_rational::<T>(
+                    &Rational::exact_from(x),
+                    n
+                )),
+                NiceFloat(primitive_float_bessel_jn(x, n))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_jn_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_jn_rational_properties_helper);
+}
@@ -0,0 +1,2836 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{BesselY0, BesselY0Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::bessel_y0::{
+    primitive_float_bessel_y0, primitive_float_bessel_y0_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::bessel_y0::{
+    rug_bessel_y0, rug_bessel_y0_prec, rug_bessel_y0_prec_round, rug_bessel_y0_rational_prec,
+    rug_bessel_y0_rational_prec_round, rug_bessel_y0_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_bessel_y0() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let y0 = x.clone().bessel_y0();
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+
+        let y0_alt = (&x).bessel_y0();
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+
+        let mut y0_alt = x.clone();
+        y0_alt.bessel_y0_assign();
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_bessel_y0(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&y0)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "0.062", "0x0.1#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+    );
+    test("3.0", "0x3.0#2", "0.38", "0x0.6#2");
+    test("5.0", "0x5.0#3", "-0.31", "-0x0.5#3");
+    test("0.50", "0x0.8#1", "-0.50", "-0x0.8#1");
+    test("-0.50", "-0x0.8#1", "NaN", "NaN");
+    test("123.0", "0x7b.0#7", "0.02173", "0x0.059#7");
+    test("-123.50", "-0x7b.8#10", "NaN", "NaN");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.45627948747894514",
+        "0x0.74cebb848ec624#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+    );
+    test("27.0", "0x1b.0#5", "0.133", "0x0.22#5");
+    test("-6.0", "-0x6.0#2", "NaN", "NaN");
+    test("7.9e-31", "0x1.0E-25#1", "-32.0", "-0x2.0E+1#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+    );
+}
+
+#[test]
+fn test_bessel_y0_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (y0, o) = x.clone().bessel_y0_prec(prec);
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+        assert_eq!(o, o_out);
+
+        let (y0_alt, o_alt) = x.bessel_y0_prec_ref(prec);
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut y0_alt = x.clone();
+        let o_alt = y0_alt.bessel_y0_prec_assign(prec);
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_y0, rug_o) = rug_bessel_y0_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y0)),
+            ComparableFloatRef(&y0)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "0.062", "0x0.1#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.088257", "0x0.1698#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 10, "0.37695", "0x0.608#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.37685001001279038196711019239667",
+        "0x0.60793e048090a736565f08f480#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 10, "-0.30859", "-0x0.4f0#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "-0.30851762524903378007364898421208",
+        "-0x0.4efb02d6af26adc31c3ce5cb10#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, "-0.50", "-0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 10, "-0.44434", "-0x0.71c#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "-0.44451873350670655714839847506820",
+        "-0x0.71cbfacedee287728787911ca8#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 100, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, "0.016", "0x0.04#1", Less);
+    test("123.0", "0x7b.0#7", 10, "0.021851", "0x0.0598#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.021845806748054526698892495022842",
+        "0x0.0597afd189a223bc24ff57186d0#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 100, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.32836630851631271034955265083786",
+        "0x0.540fd07c2f9133fe4d899152b8#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.45605",
+        "0x0.74c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.45627948747894515122113769251230",
+        "0x0.74cebb848ec6247444d9c25250#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "-7.6765081757929366904887452674293e-6",
+        "-0x0.000080ca5a00001f4697ad55316e4#100",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", 1, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 10, "0.13525", "0x0.22a#10", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        "0.13521497620787235374417503792111",
+        "0x0.229d72dcbe02c6a23f225512a8#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 100, "NaN", "NaN", Equal);
+    test("7.9e-31", "0x1.0E-25#1", 1, "-32.0", "-0x2.0E+1#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "-44.188",
+        "-0x2c.3#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "-44.200924325639005904565630351530",
+        "-0x2c.336fc6cf972372ff9be1ab7c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_y0_prec_fail() {
+    assert_panic!(Float::NAN.bessel_y0_prec(0));
+    assert_panic!(Float::NAN.bessel_y0_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.bessel_y0_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_bessel_y0_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (y0, o) = x.clone().bessel_y0_round(rm);
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+        assert_eq!(o, o_out);
+
+        let (y0_alt, o_alt) = x.bessel_y0_round_ref(rm);
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut y0_alt = x.clone();
+        let o_alt = y0_alt.bessel_y0_round_assign(rm);
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_y0, rug_o) = rug_bessel_y0_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_y0)),
+                ComparableFloatRef(&y0),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.062", "0x0.1#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "0.12", "0x0.2#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.062", "0x0.1#1", Less);
+    test("1.0", "0x1.0#1", Up, "0.12", "0x0.2#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.062", "0x0.1#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.088256964215676957982926766023597",
+        "0x0.16980226f358df29efcc5d7928#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.088256964215676957982926766023597",
+        "0x0.16980226f358df29efcc5d7928#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.38", "0x0.6#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "0.50", "0x0.8#2", Greater);
+    test("3.0", "0x3.0#2", Down, "0.38", "0x0.6#2", Less);
+    test("3.0", "0x3.0#2", Up, "0.50", "0x0.8#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "0.38", "0x0.6#2", Less);
+    test("5.0", "0x5.0#3", Floor, "-0.31", "-0x0.5#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "-0.25", "-0x0.4#3", Greater);
+    test("5.0", "0x5.0#3", Down, "-0.25", "-0x0.4#3", Greater);
+    test("5.0", "0x5.0#3", Up, "-0.31", "-0x0.5#3", Less);
+    test("5.0", "0x5.0#3", Nearest, "-0.31", "-0x0.5#3", Less);
+    test("0.50", "0x0.8#1", Floor, "-0.50", "-0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", Down, "-0.25", "-0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", Up, "-0.50", "-0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Nearest, "-0.50", "-0x0.8#1", Less);
+    test("-0.50", "-0x0.8#1", Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Exact, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Floor, "0.02173", "0x0.059#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "0.02197",
+        "0x0.05a#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "0.02173", "0x0.059#7", Less);
+    test("123.0", "0x7b.0#7", Up, "0.02197", "0x0.05a#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "0.02173", "0x0.059#7", Less);
+    test("-123.50", "-0x7b.8#10", Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.32836630851631243",
+        "0x0.540fd07c2f912#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.32836630851631243",
+        "0x0.540fd07c2f912#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.45627948747894514",
+        "0x0.74cebb848ec624#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.45627948747894520",
+        "0x0.74cebb848ec628#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.45627948747894514",
+        "0x0.74cebb848ec624#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.45627948747894520",
+        "0x0.74cebb848ec628#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.45627948747894514",
+        "0x0.74cebb848ec624#53",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "-7.67650909e-6",
+        "-0x0.000080ca5b#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "-7.67650909e-6",
+        "-0x0.000080ca5b#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "-7.67650818e-6",
+        "-0x0.000080ca5a#24",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", Floor, "0.133", "0x0.22#5", Less);
+    test("27.0", "0x1b.0#5", Ceiling, "0.141", "0x0.24#5", Greater);
+    test("27.0", "0x1b.0#5", Down, "0.133", "0x0.22#5", Less);
+    test("27.0", "0x1b.0#5", Up, "0.141", "0x0.24#5", Greater);
+    test("27.0", "0x1b.0#5", Nearest, "0.133", "0x0.22#5", Less);
+    test("-6.0", "-0x6.0#2", Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Exact, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "-64.0",
+        "-0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Up, "-64.0", "-0x4.0E+1#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "-0.73437307345447260776116547505685",
+        "-0x0.bbffdfad8cca8b95935d31802#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "-0.73437307345447260776116547505685",
+        "-0x0.bbffdfad8cca8b95935d31802#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_y0_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.bessel_y0_round(Exact));
+    assert_panic!(X.bessel_y0_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_y0_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_bessel_y0_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (y0, o) = x.clone().bessel_y0_prec_round(prec, rm);
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+        assert_eq!(o, o_out);
+
+        let (y0_alt, o_alt) = x.bessel_y0_prec_round_ref(prec, rm);
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut y0_alt = x.clone();
+        let o_alt = y0_alt.bessel_y0_prec_round_assign(prec, rm);
+        assert!(y0_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y0), ComparableFloatRef(&y0_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_y0, rug_o) = rug_bessel_y0_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_y0)),
+                ComparableFloatRef(&y0)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, Floor, "0.062", "0x0.1#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.12", "0x0.2#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.062", "0x0.1#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "0.12", "0x0.2#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.088257", "0x0.1698#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.088379",
+        "0x0.16a0#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.088257", "0x0.1698#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.088379", "0x0.16a0#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.088379",
+        "0x0.16a0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.088379",
+        "0x0.16a0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 10, Floor, "0.37646", "0x0.606#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.37695",
+        "0x0.608#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "0.37646", "0x0.606#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "0.37695", "0x0.608#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.37695",
+        "0x0.608#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.37685001001279038196711019239667",
+        "0x0.60793e048090a736565f08f480#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("5.0", "0x5.0#3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 10, Floor, "-0.30859", "-0x0.4f0#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "-0.30811",
+        "-0x0.4ee#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Down,
+        "-0.30811",
+        "-0x0.4ee#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 10, Up, "-0.30859", "-0x0.4f0#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "-0.30859",
+        "-0x0.4f0#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "-0.30851762524903378007364898421208",
+        "-0x0.4efb02d6af26adc31c3ce5cb10#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("0.50", "0x0.8#1", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Floor,
+        "-0.44482",
+        "-0x0.71e#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "-0.44434",
+        "-0x0.71c#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Down,
+        "-0.44434",
+        "-0x0.71c#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Up, "-0.44482", "-0x0.71e#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "-0.44434",
+        "-0x0.71c#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "-0.44451873350670655714839847506820",
+        "-0x0.71cbfacedee287728787911ca8#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Exact, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 100, Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Floor, "0.016", "0x0.04#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 1, Ceiling, "0.031", "0x0.08#1", Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "0.016", "0x0.04#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "0.031", "0x0.08#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "0.016", "0x0.04#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "0.021820",
+        "0x0.0596#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "0.021851",
+        "0x0.0598#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "0.021820",
+        "0x0.0596#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "0.021851",
+        "0x0.0598#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "0.021851",
+        "0x0.0598#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "0.021845806748054526698892495022842",
+        "0x0.0597afd189a223bc24ff57186d0#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Exact, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Exact, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.32861",
+        "0x0.542#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.32861",
+        "0x0.542#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.32836630851631271034955265083786",
+        "0x0.540fd07c2f9133fe4d899152b8#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.45605",
+        "0x0.74c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.45654",
+        "0x0.74e#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.45605",
+        "0x0.74c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.45654",
+        "0x0.74e#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.45605",
+        "0x0.74c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.45627948747894515122113769251230",
+        "0x0.74cebb848ec6247444d9c25250#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "-0.000015",
+        "-0x0.0001#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "-0.000015",
+        "-0x0.0001#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "-7.6e-6",
+        "-0x0.00008#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "-7.6890e-6",
+        "-0x0.0000810#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "-7.6890e-6",
+        "-0x0.0000810#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "-7.6741e-6",
+        "-0x0.000080c#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "-7.6765081757929366904887452674293e-6",
+        "-0x0.000080ca5a00001f4697ad55316e4#100",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Down, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("27.0", "0x1b.0#5", 10, Floor, "0.13501", "0x0.229#10", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Ceiling,
+        "0.13525",
+        "0x0.22a#10",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 10, Down, "0.13501", "0x0.229#10", Less);
+    test("27.0", "0x1b.0#5", 10, Up, "0.13525", "0x0.22a#10", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Nearest,
+        "0.13525",
+        "0x0.22a#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Nearest,
+        "0.13521497620787235374417503792111",
+        "0x0.229d72dcbe02c6a23f225512a8#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Exact, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Exact, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "-64.0",
+        "-0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "-64.0",
+        "-0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "-44.250",
+        "-0x2c.4#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "-44.188",
+        "-0x2c.3#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "-44.188",
+        "-0x2c.3#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "-44.250",
+        "-0x2c.4#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "-44.188",
+        "-0x2c.3#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "-44.200924325639005904565630351530",
+        "-0x2c.336fc6cf972372ff9be1ab7c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-0.73340",
+        "-0x0.bbc#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-0.73340",
+        "-0x0.bbc#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_y0_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).bessel_y0_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).bessel_y0_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.bessel_y0_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.bessel_y0_prec_round(1, Exact));
+    assert_panic!(X.bessel_y0_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_y0_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_bessel_y0_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (y0, o) = Float::bessel_y0_rational_prec(x.clone(), prec);
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+        assert_eq!(o, out_o);
+
+        let (y0, o) = Float::bessel_y0_rational_prec_ref(&x, prec);
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_y0, rug_o) = rug_bessel_y0_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y0)),
+            ComparableFloatRef(&y0)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "-Infinity", "-Infinity", Equal);
+    test("0", 10, "-Infinity", "-Infinity", Equal);
+    test("0", 100, "-Infinity", "-Infinity", Equal);
+    test("1", 1, "0.062", "0x0.1#1", Less);
+    test("1", 10, "0.088257", "0x0.1698#10", Less);
+    test(
+        "1",
+        100,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test("-1", 1, "NaN", "NaN", Equal);
+    test("-1", 10, "NaN", "NaN", Equal);
+    test("-1", 100, "NaN", "NaN", Equal);
+    test("1/2", 1, "-0.50", "-0x0.8#1", Less);
+    test("1/2", 10, "-0.44434", "-0x0.71c#10", Greater);
+    test(
+        "1/2",
+        100,
+        "-0.44451873350670655714839847506820",
+        "-0x0.71cbfacedee287728787911ca8#100",
+        Greater,
+    );
+    test("-1/2", 1, "NaN", "NaN", Equal);
+    test("-1/2", 10, "NaN", "NaN", Equal);
+    test("-1/2", 100, "NaN", "NaN", Equal);
+    test("1/3", 1, "-0.50", "-0x0.8#1", Greater);
+    test("1/3", 10, "-0.73438", "-0x0.bc0#10", Less);
+    test(
+        "1/3",
+        100,
+        "-0.73437307345447260776116547505685",
+        "-0x0.bbffdfad8cca8b95935d31802#100",
+        Less,
+    );
+    test("22/7", 1, "0.25", "0x0.4#1", Less);
+    test("22/7", 10, "0.32812", "0x0.540#10", Greater);
+    test(
+        "22/7",
+        100,
+        "0.32791234650377896109656411355009",
+        "0x0.53f210443037be561408306268#100",
+        Less,
+    );
+    test("3/2", 1, "0.50", "0x0.8#1", Greater);
+    test("3/2", 10, "0.38232", "0x0.61e#10", Less);
+    test(
+        "3/2",
+        100,
+        "0.38244892379775884395506855497819",
+        "0x0.61e82c341a0d9f3154642a2008#100",
+        Greater,
+    );
+    test("-7/2", 1, "NaN", "NaN", Equal);
+    test("-7/2", 10, "NaN", "NaN", Equal);
+    test("-7/2", 100, "NaN", "NaN", Equal);
+    test("1000000", 1, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 10, "-0.00072575", "-0x0.002f9#10", Greater);
+    test(
+        "1000000",
+        100,
+        "-0.00072596852233517916568272174336738",
+        "-0x0.002f93bb0fb4f6c2883a411cddec#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_y0_rational_prec_fail() {
+    Float::bessel_y0_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn bessel_y0_rational_prec_ref_fail() {
+    Float::bessel_y0_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_bessel_y0_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (y0, o) = Float::bessel_y0_rational_prec_round(x.clone(), prec, rm);
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+        assert_eq!(o, out_o);
+
+        let (y0, o) = Float::bessel_y0_rational_prec_round_ref(&x, prec, rm);
+        assert!(y0.is_valid());
+        assert_eq!(y0.to_string(), out);
+        assert_eq!(to_hex_string(&y0), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_y0, rug_o) = rug_bessel_y0_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_y0)),
+                ComparableFloatRef(&y0)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 100, Nearest, "-Infinity", "-Infinity", Equal);
+    test("1", 1, Floor, "0.062", "0x0.1#1", Less);
+    test("1", 1, Ceiling, "0.12", "0x0.2#1", Greater);
+    test("1", 1, Down, "0.062", "0x0.1#1", Less);
+    test("1", 1, Up, "0.12", "0x0.2#1", Greater);
+    test("1", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test("1", 10, Floor, "0.088257", "0x0.1698#10", Less);
+    test("1", 10, Ceiling, "0.088379", "0x0.16a0#10", Greater);
+    test("1", 10, Down, "0.088257", "0x0.1698#10", Less);
+    test("1", 10, Up, "0.088379", "0x0.16a0#10", Greater);
+    test("1", 10, Nearest, "0.088257", "0x0.1698#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test("-1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 1, Down, "NaN", "NaN", Equal);
+    test("-1", 1, Up, "NaN", "NaN", Equal);
+    test("-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 10, Down, "NaN", "NaN", Equal);
+    test("-1", 10, Up, "NaN", "NaN", Equal);
+    test("-1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1", 100, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("1/2", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("1/2", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("1/2", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("1/2", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test("1/2", 10, Floor, "-0.44482", "-0x0.71e#10", Less);
+    test("1/2", 10, Ceiling, "-0.44434", "-0x0.71c#10", Greater);
+    test("1/2", 10, Down, "-0.44434", "-0x0.71c#10", Greater);
+    test("1/2", 10, Up, "-0.44482", "-0x0.71e#10", Less);
+    test("1/2", 10, Nearest, "-0.44434", "-0x0.71c#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "-0.44451873350670655714839847506820",
+        "-0x0.71cbfacedee287728787911ca8#100",
+        Greater,
+    );
+    test("-1/2", 1, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 1, Down, "NaN", "NaN", Equal);
+    test("-1/2", 1, Up, "NaN", "NaN", Equal);
+    test("-1/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 1, Exact, "NaN", "NaN", Equal);
+    test("-1/2", 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 10, Exact, "NaN", "NaN", Equal);
+    test("-1/2", 100, Nearest, "NaN", "NaN", Equal);
+    test("1/3", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("1/3", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1/3", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("1/3", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("1/3", 1, Nearest, "-0.50", "-0x0.8#1", Greater);
+    test("1/3", 10, Floor, "-0.73438", "-0x0.bc0#10", Less);
+    test("1/3", 10, Ceiling, "-0.73340", "-0x0.bbc#10", Greater);
+    test("1/3", 10, Down, "-0.73340", "-0x0.bbc#10", Greater);
+    test("1/3", 10, Up, "-0.73438", "-0x0.bc0#10", Less);
+    test("1/3", 10, Nearest, "-0.73438", "-0x0.bc0#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "-0.73437307345447260776116547505685",
+        "-0x0.bbffdfad8cca8b95935d31802#100",
+        Less,
+    );
+    test("22/7", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("22/7", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("22/7", 1, Down, "0.25", "0x0.4#1", Less);
+    test("22/7", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("22/7", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("22/7", 10, Floor, "0.32764", "0x0.53e#10", Less);
+    test("22/7", 10, Ceiling, "0.32812", "0x0.540#10", Greater);
+    test("22/7", 10, Down, "0.32764", "0x0.53e#10", Less);
+    test("22/7", 10, Up, "0.32812", "0x0.540#10", Greater);
+    test("22/7", 10, Nearest, "0.32812", "0x0.540#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.32791234650377896109656411355009",
+        "0x0.53f210443037be561408306268#100",
+        Less,
+    );
+    test("3/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("3/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("3/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("3/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("3/2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("3/2", 10, Floor, "0.38232", "0x0.61e#10", Less);
+    test("3/2", 10, Ceiling, "0.38281", "0x0.620#10", Greater);
+    test("3/2", 10, Down, "0.38232", "0x0.61e#10", Less);
+    test("3/2", 10, Up, "0.38281", "0x0.620#10", Greater);
+    test("3/2", 10, Nearest, "0.38232", "0x0.61e#10", Less);
+    test(
+        "3/2",
+        100,
+        Nearest,
+        "0.38244892379775884395506855497819",
+        "0x0.61e82c341a0d9f3154642a2008#100",
+        Greater,
+    );
+    test("-7/2", 1, Floor, "NaN", "NaN", Equal);
+    test("-7/2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-7/2", 1, Down, "NaN", "NaN", Equal);
+    test("-7/2", 1, Up, "NaN", "NaN", Equal);
+    test("-7/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", 1, Exact, "NaN", "NaN", Equal);
+    test("-7/2", 10, Floor, "NaN", "NaN", Equal);
+    test("-7/2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-7/2", 10, Down, "NaN", "NaN", Equal);
+    test("-7/2", 10, Up, "NaN", "NaN", Equal);
+    test("-7/2", 10, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", 10, Exact, "NaN", "NaN", Equal);
+    test("-7/2", 100, Nearest, "NaN", "NaN", Equal);
+    test("1000000", 1, Floor, "-0.00098", "-0x0.004#1", Less);
+    test("1000000", 1, Ceiling, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 1, Down, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 1, Up, "-0.00098", "-0x0.004#1", Less);
+    test("1000000", 1, Nearest, "-0.00049", "-0x0.002#1", Greater);
+    test("1000000", 10, Floor, "-0.00072670", "-0x0.002fa#10", Less);
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "-0.00072575",
+        "-0x0.002f9#10",
+        Greater,
+    );
+    test("1000000", 10, Down, "-0.00072575", "-0x0.002f9#10", Greater);
+    test("1000000", 10, Up, "-0.00072670", "-0x0.002fa#10", Less);
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "-0.00072575",
+        "-0x0.002f9#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "-0.00072596852233517916568272174336738",
+        "-0x0.002f93bb0fb4f6c2883a411cddec#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_y0_rational_prec_round_fail_1() {
+    Float::bessel_y0_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn bessel_y0_rational_prec_round_fail_2() {
+    Float::bessel_y0_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn bessel_y0_rational_prec_round_ref_fail() {
+    Float::bessel_y0_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y0_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (y0, o) = x.clone().bessel_y0_prec_round(prec, rm);
+    assert!(y0.is_valid());
+
+    let (y0_alt, o_alt) = x.bessel_y0_prec_round_ref(prec, rm);
+    assert!(y0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_y0_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_y0, rug_o) = rug_bessel_y0_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y0)),
+            ComparableFloatRef(&y0)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(y0.is_nan(), x.is_nan() || x < 0u32);
+
+    if y0.is_normal() {
+        assert_eq!(y0.get_prec(), Some(prec));
+    }
+
+    if x.is_normal() {
+        let (y0_alt, o_alt) =
+            Float::bessel_y0_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_y0_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(y0.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_y0_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_y0_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        bessel_y0_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (y0, o) = Float::NAN.bessel_y0_prec_round(prec, rm);
+        assert!(y0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::INFINITY.bessel_y0_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(y0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::NEGATIVE_INFINITY.bessel_y0_prec_round(prec, rm);
+        assert!(y0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::ZERO.bessel_y0_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::NEGATIVE_ZERO.bessel_y0_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y0_prec_properties_helper(x: Float, prec: u64) {
+    let (y0, o) = x.clone().bessel_y0_prec(prec);
+    assert!(y0.is_valid());
+
+    let (y0_alt, o_alt) = x.bessel_y0_prec_ref(prec);
+    assert!(y0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_y0_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let (y0_alt, o_alt) = x.bessel_y0_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let (rug_y0, rug_o) = rug_bessel_y0_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_y0)),
+        ComparableFloatRef(&y0)
+    );
+    assert_eq!(rug_o, o);
+
+    if y0.is_normal() {
+        assert_eq!(y0.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn bessel_y0_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        bessel_y0_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (y0, o) = Float::NAN.bessel_y0_prec(prec);
+        assert!(y0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::INFINITY.bessel_y0_prec(prec);
+        assert_eq!(ComparableFloat(y0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::NEGATIVE_INFINITY.bessel_y0_prec(prec);
+        assert!(y0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::ZERO.bessel_y0_prec(prec);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::NEGATIVE_ZERO.bessel_y0_prec(prec);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y0_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (y0, o) = x.clone().bessel_y0_round(rm);
+    assert!(y0.is_valid());
+
+    let (y0_alt, o_alt) = x.bessel_y0_round_ref(rm);
+    assert!(y0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_y0_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let (y0_alt, o_alt) = x.bessel_y0_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    assert_eq!(y0.is_nan(), x.is_nan() || x < 0u32);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_y0, rug_o) = rug_bessel_y0_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y0)),
+            ComparableFloatRef(&y0)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_y0_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(y0.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_y0_round_ref(Exact));
+    }
+}
+
+#[test]
+fn bessel_y0_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        bessel_y0_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (y0, o) = Float::NAN.bessel_y0_round(rm);
+        assert!(y0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::INFINITY.bessel_y0_round(rm);
+        assert_eq!(ComparableFloat(y0), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::NEGATIVE_INFINITY.bessel_y0_round(rm);
+        assert!(y0.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::ZERO.bessel_y0_round(rm);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (y0, o) = Float::NEGATIVE_ZERO.bessel_y0_round(rm);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y0_properties_helper(x: Float) {
+    let y0 = x.clone().bessel_y0();
+    assert!(y0.is_valid());
+
+    let y0_alt = (&x).bessel_y0();
+    assert!(y0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+
+    let mut x_alt = x.clone();
+    x_alt.bessel_y0_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y0));
+
+    let y0_alt = x.bessel_y0_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    let y0_alt = x.bessel_y0_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+
+    assert_eq!(y0.is_nan(), x.is_nan() || x < 0u32);
+
+    let rug_y0 = rug_bessel_y0(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_y0)),
+        ComparableFloatRef(&y0),
+    );
+}
+
+#[test]
+fn bessel_y0_properties() {
+    float_gen().test_properties(|x| {
+        bessel_y0_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y0_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (y0, o) = Float::bessel_y0_rational_prec_round(x.clone(), prec, rm);
+    assert!(y0.is_valid());
+
+    let (y0_alt, o_alt) = Float::bessel_y0_rational_prec_round_ref(&x, prec, rm);
+    assert!(y0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_y0, rug_o) = rug_bessel_y0_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y0)),
+            ComparableFloatRef(&y0)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(y0.is_nan(), x < 0u32);
+
+    if y0.is_normal() {
+        assert_eq!(y0.get_prec(), Some(prec));
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::bessel_y0_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(y0.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::bessel_y0_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_y0_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        bessel_y0_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (y0, o) = Float::bessel_y0_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y0_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (y0, o) = Float::bessel_y0_rational_prec(x.clone(), prec);
+    assert!(y0.is_valid());
+
+    let (y0_alt, o_alt) = Float::bessel_y0_rational_prec_ref(&x, prec);
+    assert!(y0_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let (y0_alt, o_alt) = Float::bessel_y0_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&y0_alt), ComparableFloatRef(&y0));
+    assert_eq!(o_alt, o);
+
+    let (rug_y0, rug_o) = rug_bessel_y0_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_y0)),
+        ComparableFloatRef(&y0)
+    );
+    assert_eq!(rug_o, o);
+
+    assert_eq!(y0.is_nan(), x < 0u32);
+
+    if y0.is_normal() {
+        assert_eq!(y0.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn bessel_y0_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        bessel_y0_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (y0, o) = Float::bessel_y0_rational_prec(Rational::ZERO, prec);
+        assert_eq!(
+            ComparableFloat(y0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_y0() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_bessel_y0(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(1.0, 0.08825696);
+    test::<f32>(-1.0, f32::NAN);
+    test::<f32>(0.5, -0.44451874);
+    test::<f32>(-0.5, f32::NAN);
+    test::<f32>(2.0, 0.5103757);
+    test::<f32>(5.0, -0.30851763);
+    test::<f32>(-2.5, f32::NAN);
+    test::<f32>(10.0, 0.055671167);
+    test::<f32>(0.001, -4.4714165);
+    test::<f32>(10000000000.0, -7.676508e-6);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(1.0, 0.08825696421567696);
+    test::<f64>(-1.0, f64::NAN);
+    test::<f64>(0.5, -0.44451873350670656);
+    test::<f64>(-0.5, f64::NAN);
+    test::<f64>(2.0, 0.5103756726497451);
+    test::<f64>(5.0, -0.30851762524903376);
+    test::<f64>(-2.5, f64::NAN);
+    test::<f64>(10.0, 0.055671167283599395);
+    test::<f64>(0.001, -4.471416611375923);
+    test::<f64>(10000000000.0, -7.676508175792937e-6);
+    test::<f64>(1.0e300, -1.3681360450342481e-151);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_y0_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let y0 = primitive_float_bessel_y0(x);
+        assert_eq!(y0.is_nan(), x.is_nan() || x < T::ZERO);
+    });
+}
+
+#[test]
+fn primitive_float_bessel_y0_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_y0_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_y0_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_bessel_y0_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", f32::NEGATIVE_INFINITY);
+    test::<f32>("1", 0.08825696);
+    test::<f32>("-1", f32::NAN);
+    test::<f32>("1/2", -0.44451874);
+    test::<f32>("-1/2", f32::NAN);
+    test::<f32>("1/3", -0.7343731);
+    test::<f32>("22/7", 0.32791236);
+    test::<f32>("3/2", 0.3824489);
+    test::<f32>("-7/2", f32::NAN);
+    test::<f32>("1000000", -0.0007259685);
+
+    test::<f64>("0", f64::NEGATIVE_INFINITY);
+    test::<f64>("1", 0.08825696421567696);
+    test::<f64>("-1", f64::NAN);
+    test::<f64>("1/2", -0.44451873350670656);
+    test::<f64>("-1/2", f64::NAN);
+    test::<f64>("1/3", -0.7343730734544726);
+    test::<f64>("22/7", 0.327912346503779);
+    test::<f64>("3/2", 0.38244892379775886);
+    test::<f64>("-7/2", f64::NAN);
+    test::<f64>("1000000", -0.0007259685223351791);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_y0_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let y0 = primitive_float_bessel_y0_rational::<T>(&x);
+        assert_eq!(y0.is_nan(), x < 0u32);
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_bessel_y0_rational::<T>(
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_bessel_y0(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_y0_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_y0_rational_properties_helper);
+}
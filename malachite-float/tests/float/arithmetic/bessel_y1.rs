@@ -0,0 +1,2875 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{BesselY1, BesselY1Assign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::bessel_y1::{
+    primitive_float_bessel_y1, primitive_float_bessel_y1_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::bessel_y1::{
+    rug_bessel_y1, rug_bessel_y1_prec, rug_bessel_y1_prec_round, rug_bessel_y1_rational_prec,
+    rug_bessel_y1_rational_prec_round, rug_bessel_y1_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_bessel_y1() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let y1 = x.clone().bessel_y1();
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+
+        let y1_alt = (&x).bessel_y1();
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+
+        let mut y1_alt = x.clone();
+        y1_alt.bessel_y1_assign();
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_bessel_y1(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&y1)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+    );
+    test("3.0", "0x3.0#2", "0.38", "0x0.6#2");
+    test("5.0", "0x5.0#3", "0.16", "0x0.28#3");
+    test("0.50", "0x0.8#1", "-1.0", "-0x1.0#1");
+    test("-0.50", "-0x0.8#1", "NaN", "NaN");
+    test("123.0", "0x7b.0#7", "0.06836", "0x0.118#7");
+    test("-123.50", "-0x7b.8#10", "NaN", "NaN");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.23445370234309523",
+        "0x0.3c052867fd5dce#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "-2.17559182e-6",
+        "-0x0.00002480188#24",
+    );
+    test("27.0", "0x1b.0#5", "-0.0703", "-0x0.12#5");
+    test("-6.0", "-0x6.0#2", "NaN", "NaN");
+    test("7.9e-31", "0x1.0E-25#1", "-6.3e29", "-0x8.0E+24#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+    );
+}
+
+#[test]
+fn test_bessel_y1_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (y1, o) = x.clone().bessel_y1_prec(prec);
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (y1_alt, o_alt) = x.bessel_y1_prec_ref(prec);
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut y1_alt = x.clone();
+        let o_alt = y1_alt.bessel_y1_prec_assign(prec);
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_y1, rug_o) = rug_bessel_y1_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y1)),
+            ComparableFloatRef(&y1)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 10, "-0.78125", "-0x0.c80#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 10, "0.32471", "0x0.532#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.32467442479179997843701283928814",
+        "0x0.531ddcf45411882e04f68416b8#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, "0.12", "0x0.2#1", Less);
+    test("5.0", "0x5.0#3", 10, "0.14795", "0x0.25e#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "0.14786314339122684480105067548802",
+        "0x0.25da5be52629f6fbdf52cc2b84#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, "-1.4707", "-0x1.788#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "-1.4714723926702430691885846353239",
+        "-0x1.78b26a2b7c4dedb22474fc6f2#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 100, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, "0.062", "0x0.1#1", Less);
+    test("123.0", "0x7b.0#7", 10, "0.068604", "0x0.1190#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.068634890102549266520969993528965",
+        "0x0.11920e605ae17d21928bdf8c0a#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 100, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.35887291677671893324419080972445",
+        "0x0.5bdf1870f9e426903f28a49430#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.23438",
+        "0x0.3c0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.23445370234309522177472806173113",
+        "0x0.3c052867fd5dcd361f4fd06240#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "-1.9e-6",
+        "-0x0.00002#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "-2.1756e-6",
+        "-0x0.0000248#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "-2.1755917506307171356514216078492e-6",
+        "-0x0.00002480186d573691e9f6633550dc#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, "-0.062", "-0x0.1#1", Greater);
+    test("27.0", "0x1b.0#5", 10, "-0.070190", "-0x0.11f8#10", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        "-0.070251238235783235725601279514172",
+        "-0x0.11fbfc32b9e7d523c00cfdef5c#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 100, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "-6.3e29",
+        "-0x8.0E+24#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "-8.0714e29",
+        "-0xa.30E+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "-807011436558923259726928379167.00",
+        "-0xa2f9836e4e441529fc2757d1f.0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_y1_prec_fail() {
+    assert_panic!(Float::NAN.bessel_y1_prec(0));
+    assert_panic!(Float::NAN.bessel_y1_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.bessel_y1_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_bessel_y1_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (y1, o) = x.clone().bessel_y1_round(rm);
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (y1_alt, o_alt) = x.bessel_y1_round_ref(rm);
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut y1_alt = x.clone();
+        let o_alt = y1_alt.bessel_y1_round_assign(rm);
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_y1, rug_o) = rug_bessel_y1_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_y1)),
+                ComparableFloatRef(&y1),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "-0.78121282130028871654715000004868",
+        "-0x0.c7fd903eb35ca24ef2cdf8959#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "-0.78121282130028871654715000004868",
+        "-0x0.c7fd903eb35ca24ef2cdf8959#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.25", "0x0.4#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "0.38", "0x0.6#2", Greater);
+    test("3.0", "0x3.0#2", Down, "0.25", "0x0.4#2", Less);
+    test("3.0", "0x3.0#2", Up, "0.38", "0x0.6#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "0.38", "0x0.6#2", Greater);
+    test("5.0", "0x5.0#3", Floor, "0.12", "0x0.20#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "0.16", "0x0.28#3", Greater);
+    test("5.0", "0x5.0#3", Down, "0.12", "0x0.20#3", Less);
+    test("5.0", "0x5.0#3", Up, "0.16", "0x0.28#3", Greater);
+    test("5.0", "0x5.0#3", Nearest, "0.16", "0x0.28#3", Greater);
+    test("0.50", "0x0.8#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", Exact, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", Floor, "0.06836", "0x0.118#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "0.06934",
+        "0x0.11c#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "0.06836", "0x0.118#7", Less);
+    test("123.0", "0x7b.0#7", Up, "0.06934", "0x0.11c#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "0.06836", "0x0.118#7", Less);
+    test("-123.50", "-0x7b.8#10", Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.35887291677671929",
+        "0x0.5bdf1870f9e44#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.35887291677671929",
+        "0x0.5bdf1870f9e44#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.23445370234309520",
+        "0x0.3c052867fd5dcc#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.23445370234309523",
+        "0x0.3c052867fd5dce#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.23445370234309520",
+        "0x0.3c052867fd5dcc#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.23445370234309523",
+        "0x0.3c052867fd5dce#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.23445370234309523",
+        "0x0.3c052867fd5dce#53",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "-2.17559182e-6",
+        "-0x0.00002480188#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "-2.17559159e-6",
+        "-0x0.00002480184#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "-2.17559159e-6",
+        "-0x0.00002480184#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "-2.17559182e-6",
+        "-0x0.00002480188#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "-2.17559182e-6",
+        "-0x0.00002480188#24",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", Floor, "-0.0703", "-0x0.12#5", Less);
+    test("27.0", "0x1b.0#5", Ceiling, "-0.0664", "-0x0.11#5", Greater);
+    test("27.0", "0x1b.0#5", Down, "-0.0664", "-0x0.11#5", Greater);
+    test("27.0", "0x1b.0#5", Up, "-0.0703", "-0x0.12#5", Less);
+    test("27.0", "0x1b.0#5", Nearest, "-0.0703", "-0x0.12#5", Less);
+    test("-6.0", "-0x6.0#2", Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", Exact, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "-6.3e29",
+        "-0x8.0E+24#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "-6.3e29",
+        "-0x8.0E+24#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "-6.3e29",
+        "-0x8.0E+24#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "-2.0881657798883862661909621637473",
+        "-0x2.169208553f3858f0fd0a2a3b4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "-2.0881657798883862661909621637473",
+        "-0x2.169208553f3858f0fd0a2a3b4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_y1_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.bessel_y1_round(Exact));
+    assert_panic!(X.bessel_y1_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_y1_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_bessel_y1_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (y1, o) = x.clone().bessel_y1_prec_round(prec, rm);
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (y1_alt, o_alt) = x.bessel_y1_prec_round_ref(prec, rm);
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut y1_alt = x.clone();
+        let o_alt = y1_alt.bessel_y1_prec_round_assign(prec, rm);
+        assert!(y1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y1), ComparableFloatRef(&y1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_y1, rug_o) = rug_bessel_y1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_y1)),
+                ComparableFloatRef(&y1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "-0.78125", "-0x0.c80#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "-0.78027",
+        "-0x0.c7c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Down,
+        "-0.78027",
+        "-0x0.c7c#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Up, "-0.78125", "-0x0.c80#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "-0.78027",
+        "-0x0.c7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "-0.78027",
+        "-0x0.c7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("3.0", "0x3.0#2", 10, Floor, "0.32422", "0x0.530#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.32471",
+        "0x0.532#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "0.32422", "0x0.530#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "0.32471", "0x0.532#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.32471",
+        "0x0.532#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.32467442479179997843701283928814",
+        "0x0.531ddcf45411882e04f68416b8#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "0.12", "0x0.2#1", Less);
+    test("5.0", "0x5.0#3", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("5.0", "0x5.0#3", 10, Floor, "0.14771", "0x0.25d#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "0.14795",
+        "0x0.25e#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 10, Down, "0.14771", "0x0.25d#10", Less);
+    test("5.0", "0x5.0#3", 10, Up, "0.14795", "0x0.25e#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "0.14795",
+        "0x0.25e#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "0.14786314339122684480105067548802",
+        "0x0.25da5be52629f6fbdf52cc2b84#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, Floor, "-1.4727", "-0x1.790#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "-1.4707",
+        "-0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Down,
+        "-1.4707",
+        "-0x1.788#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Up, "-1.4727", "-0x1.790#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "-1.4707",
+        "-0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "-1.4714723926702430691885846353239",
+        "-0x1.78b26a2b7c4dedb22474fc6f2#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 10, Exact, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 100, Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 1, Floor, "0.062", "0x0.1#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "0.12", "0x0.2#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "0.062", "0x0.1#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "0.12", "0x0.2#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "0.068604",
+        "0x0.1190#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "0.068726",
+        "0x0.1198#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "0.068604",
+        "0x0.1190#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "0.068726",
+        "0x0.1198#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "0.068604",
+        "0x0.1190#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "0.068634890102549266520969993528965",
+        "0x0.11920e605ae17d21928bdf8c0a#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 1, Exact, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 10, Exact, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.35840",
+        "0x0.5bc#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.35840",
+        "0x0.5bc#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.35887291677671893324419080972445",
+        "0x0.5bdf1870f9e426903f28a49430#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.23438",
+        "0x0.3c0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.23462",
+        "0x0.3c1#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.23438",
+        "0x0.3c0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.23462",
+        "0x0.3c1#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.23438",
+        "0x0.3c0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.23445370234309522177472806173113",
+        "0x0.3c052867fd5dcd361f4fd06240#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "-3.8e-6",
+        "-0x0.00004#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "-1.9e-6",
+        "-0x0.00002#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "-1.9e-6",
+        "-0x0.00002#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "-3.8e-6",
+        "-0x0.00004#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "-1.9e-6",
+        "-0x0.00002#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "-2.1793e-6",
+        "-0x0.0000249#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "-2.1756e-6",
+        "-0x0.0000248#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "-2.1756e-6",
+        "-0x0.0000248#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "-2.1793e-6",
+        "-0x0.0000249#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "-2.1756e-6",
+        "-0x0.0000248#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "-2.1755917506307171356514216078492e-6",
+        "-0x0.00002480186d573691e9f6633550dc#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, Floor, "-0.12", "-0x0.2#1", Less);
+    test(
+        "27.0", "0x1b.0#5", 1, Ceiling, "-0.062", "-0x0.1#1", Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, Down, "-0.062", "-0x0.1#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Up, "-0.12", "-0x0.2#1", Less);
+    test(
+        "27.0", "0x1b.0#5", 1, Nearest, "-0.062", "-0x0.1#1", Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Floor,
+        "-0.070312",
+        "-0x0.1200#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Ceiling,
+        "-0.070190",
+        "-0x0.11f8#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Down,
+        "-0.070190",
+        "-0x0.11f8#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Up,
+        "-0.070312",
+        "-0x0.1200#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Nearest,
+        "-0.070190",
+        "-0x0.11f8#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Nearest,
+        "-0.070251238235783235725601279514172",
+        "-0x0.11fbfc32b9e7d523c00cfdef5c#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 1, Exact, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 10, Exact, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "-6.3e29",
+        "-0x8.0E+24#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "-6.3e29",
+        "-0x8.0E+24#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "-6.3e29",
+        "-0x8.0E+24#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "-8.0714e29",
+        "-0xa.30E+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "-8.0590e29",
+        "-0xa.2cE+24#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "-8.0590e29",
+        "-0xa.2cE+24#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "-8.0714e29",
+        "-0xa.30E+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "-8.0714e29",
+        "-0xa.30E+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "-807011436558923259726928379167.00",
+        "-0xa2f9836e4e441529fc2757d1f.0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-2.0859",
+        "-0x2.16#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-2.0859",
+        "-0x2.16#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_y1_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).bessel_y1_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).bessel_y1_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.bessel_y1_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.bessel_y1_prec_round(1, Exact));
+    assert_panic!(X.bessel_y1_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_y1_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_bessel_y1_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (y1, o) = Float::bessel_y1_rational_prec(x.clone(), prec);
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+        assert_eq!(o, out_o);
+
+        let (y1, o) = Float::bessel_y1_rational_prec_ref(&x, prec);
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_y1, rug_o) = rug_bessel_y1_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y1)),
+            ComparableFloatRef(&y1)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "-Infinity", "-Infinity", Equal);
+    test("0", 10, "-Infinity", "-Infinity", Equal);
+    test("0", 100, "-Infinity", "-Infinity", Equal);
+    test("1", 1, "-1.0", "-0x1.0#1", Less);
+    test("1", 10, "-0.78125", "-0x0.c80#10", Less);
+    test(
+        "1",
+        100,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test("-1", 1, "NaN", "NaN", Equal);
+    test("-1", 10, "NaN", "NaN", Equal);
+    test("-1", 100, "NaN", "NaN", Equal);
+    test("1/2", 1, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 10, "-1.4707", "-0x1.788#10", Greater);
+    test(
+        "1/2",
+        100,
+        "-1.4714723926702430691885846353239",
+        "-0x1.78b26a2b7c4dedb22474fc6f2#100",
+        Less,
+    );
+    test("-1/2", 1, "NaN", "NaN", Equal);
+    test("-1/2", 10, "NaN", "NaN", Equal);
+    test("-1/2", 100, "NaN", "NaN", Equal);
+    test("1/3", 1, "-2.0", "-0x2.0#1", Greater);
+    test("1/3", 10, "-2.0898", "-0x2.17#10", Less);
+    test(
+        "1/3",
+        100,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test("22/7", 1, "0.25", "0x0.4#1", Less);
+    test("22/7", 10, "0.35938", "0x0.5c0#10", Greater);
+    test(
+        "22/7",
+        100,
+        "0.35914337395059936965063275111205",
+        "0x0.5bf0d1f5b1636056433f619140#100",
+        Greater,
+    );
+    test("3/2", 1, "-0.50", "-0x0.8#1", Less);
+    test("3/2", 10, "-0.41211", "-0x0.698#10", Greater);
+    test(
+        "3/2",
+        100,
+        "-0.41230862697391129595282982063349",
+        "-0x0.698d0ee4b62c3bf8ce41da4548#100",
+        Less,
+    );
+    test("-7/2", 1, "NaN", "NaN", Equal);
+    test("-7/2", 10, "NaN", "NaN", Equal);
+    test("-7/2", 100, "NaN", "NaN", Equal);
+    test("1000000", 1, "-0.00024", "-0x0.001#1", Greater);
+    test("1000000", 10, "-0.00033092", "-0x0.0015b0#10", Greater);
+    test(
+        "1000000",
+        100,
+        "-0.00033104337672417628886351723784982",
+        "-0x0.0015b1fc7a01a78979aea2643a24#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_y1_rational_prec_fail() {
+    Float::bessel_y1_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn bessel_y1_rational_prec_ref_fail() {
+    Float::bessel_y1_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_bessel_y1_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (y1, o) = Float::bessel_y1_rational_prec_round(x.clone(), prec, rm);
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+        assert_eq!(o, out_o);
+
+        let (y1, o) = Float::bessel_y1_rational_prec_round_ref(&x, prec, rm);
+        assert!(y1.is_valid());
+        assert_eq!(y1.to_string(), out);
+        assert_eq!(to_hex_string(&y1), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_y1, rug_o) = rug_bessel_y1_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_y1)),
+                ComparableFloatRef(&y1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 100, Nearest, "-Infinity", "-Infinity", Equal);
+    test("1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("1", 10, Floor, "-0.78125", "-0x0.c80#10", Less);
+    test("1", 10, Ceiling, "-0.78027", "-0x0.c7c#10", Greater);
+    test("1", 10, Down, "-0.78027", "-0x0.c7c#10", Greater);
+    test("1", 10, Up, "-0.78125", "-0x0.c80#10", Less);
+    test("1", 10, Nearest, "-0.78125", "-0x0.c80#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 1, Down, "NaN", "NaN", Equal);
+    test("-1", 1, Up, "NaN", "NaN", Equal);
+    test("-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 10, Down, "NaN", "NaN", Equal);
+    test("-1", 10, Up, "NaN", "NaN", Equal);
+    test("-1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1", 100, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 1, Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 10, Floor, "-1.4727", "-0x1.790#10", Less);
+    test("1/2", 10, Ceiling, "-1.4707", "-0x1.788#10", Greater);
+    test("1/2", 10, Down, "-1.4707", "-0x1.788#10", Greater);
+    test("1/2", 10, Up, "-1.4727", "-0x1.790#10", Less);
+    test("1/2", 10, Nearest, "-1.4707", "-0x1.788#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "-1.4714723926702430691885846353239",
+        "-0x1.78b26a2b7c4dedb22474fc6f2#100",
+        Less,
+    );
+    test("-1/2", 1, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 1, Down, "NaN", "NaN", Equal);
+    test("-1/2", 1, Up, "NaN", "NaN", Equal);
+    test("-1/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 1, Exact, "NaN", "NaN", Equal);
+    test("-1/2", 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 10, Exact, "NaN", "NaN", Equal);
+    test("-1/2", 100, Nearest, "NaN", "NaN", Equal);
+    test("1/3", 1, Floor, "-4.0", "-0x4.0#1", Less);
+    test("1/3", 1, Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("1/3", 1, Down, "-2.0", "-0x2.0#1", Greater);
+    test("1/3", 1, Up, "-4.0", "-0x4.0#1", Less);
+    test("1/3", 1, Nearest, "-2.0", "-0x2.0#1", Greater);
+    test("1/3", 10, Floor, "-2.0898", "-0x2.17#10", Less);
+    test("1/3", 10, Ceiling, "-2.0859", "-0x2.16#10", Greater);
+    test("1/3", 10, Down, "-2.0859", "-0x2.16#10", Greater);
+    test("1/3", 10, Up, "-2.0898", "-0x2.17#10", Less);
+    test("1/3", 10, Nearest, "-2.0898", "-0x2.17#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("22/7", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("22/7", 1, Down, "0.25", "0x0.4#1", Less);
+    test("22/7", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("22/7", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("22/7", 10, Floor, "0.35889", "0x0.5be#10", Less);
+    test("22/7", 10, Ceiling, "0.35938", "0x0.5c0#10", Greater);
+    test("22/7", 10, Down, "0.35889", "0x0.5be#10", Less);
+    test("22/7", 10, Up, "0.35938", "0x0.5c0#10", Greater);
+    test("22/7", 10, Nearest, "0.35938", "0x0.5c0#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.35914337395059936965063275111205",
+        "0x0.5bf0d1f5b1636056433f619140#100",
+        Greater,
+    );
+    test("3/2", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("3/2", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("3/2", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("3/2", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("3/2", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test("3/2", 10, Floor, "-0.41260", "-0x0.69a#10", Less);
+    test("3/2", 10, Ceiling, "-0.41211", "-0x0.698#10", Greater);
+    test("3/2", 10, Down, "-0.41211", "-0x0.698#10", Greater);
+    test("3/2", 10, Up, "-0.41260", "-0x0.69a#10", Less);
+    test("3/2", 10, Nearest, "-0.41211", "-0x0.698#10", Greater);
+    test(
+        "3/2",
+        100,
+        Nearest,
+        "-0.41230862697391129595282982063349",
+        "-0x0.698d0ee4b62c3bf8ce41da4548#100",
+        Less,
+    );
+    test("-7/2", 1, Floor, "NaN", "NaN", Equal);
+    test("-7/2", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-7/2", 1, Down, "NaN", "NaN", Equal);
+    test("-7/2", 1, Up, "NaN", "NaN", Equal);
+    test("-7/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", 1, Exact, "NaN", "NaN", Equal);
+    test("-7/2", 10, Floor, "NaN", "NaN", Equal);
+    test("-7/2", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-7/2", 10, Down, "NaN", "NaN", Equal);
+    test("-7/2", 10, Up, "NaN", "NaN", Equal);
+    test("-7/2", 10, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", 10, Exact, "NaN", "NaN", Equal);
+    test("-7/2", 100, Nearest, "NaN", "NaN", Equal);
+    test("1000000", 1, Floor, "-0.00049", "-0x0.002#1", Less);
+    test("1000000", 1, Ceiling, "-0.00024", "-0x0.001#1", Greater);
+    test("1000000", 1, Down, "-0.00024", "-0x0.001#1", Greater);
+    test("1000000", 1, Up, "-0.00049", "-0x0.002#1", Less);
+    test("1000000", 1, Nearest, "-0.00024", "-0x0.001#1", Greater);
+    test("1000000", 10, Floor, "-0.00033140", "-0x0.0015b8#10", Less);
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "-0.00033092",
+        "-0x0.0015b0#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Down,
+        "-0.00033092",
+        "-0x0.0015b0#10",
+        Greater,
+    );
+    test("1000000", 10, Up, "-0.00033140", "-0x0.0015b8#10", Less);
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "-0.00033092",
+        "-0x0.0015b0#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "-0.00033104337672417628886351723784982",
+        "-0x0.0015b1fc7a01a78979aea2643a24#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn bessel_y1_rational_prec_round_fail_1() {
+    Float::bessel_y1_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn bessel_y1_rational_prec_round_fail_2() {
+    Float::bessel_y1_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn bessel_y1_rational_prec_round_ref_fail() {
+    Float::bessel_y1_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y1_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (y1, o) = x.clone().bessel_y1_prec_round(prec, rm);
+    assert!(y1.is_valid());
+
+    let (y1_alt, o_alt) = x.bessel_y1_prec_round_ref(prec, rm);
+    assert!(y1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_y1_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_y1, rug_o) = rug_bessel_y1_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y1)),
+            ComparableFloatRef(&y1)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(y1.is_nan(), x.is_nan() || x < 0u32);
+
+    if y1.is_normal() {
+        assert_eq!(y1.get_prec(), Some(prec));
+    }
+
+    if x.is_normal() {
+        let (y1_alt, o_alt) =
+            Float::bessel_y1_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_y1_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(y1.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_y1_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_y1_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        bessel_y1_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (y1, o) = Float::NAN.bessel_y1_prec_round(prec, rm);
+        assert!(y1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::INFINITY.bessel_y1_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(y1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::NEGATIVE_INFINITY.bessel_y1_prec_round(prec, rm);
+        assert!(y1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::ZERO.bessel_y1_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::NEGATIVE_ZERO.bessel_y1_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y1_prec_properties_helper(x: Float, prec: u64) {
+    let (y1, o) = x.clone().bessel_y1_prec(prec);
+    assert!(y1.is_valid());
+
+    let (y1_alt, o_alt) = x.bessel_y1_prec_ref(prec);
+    assert!(y1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_y1_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let (y1_alt, o_alt) = x.bessel_y1_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let (rug_y1, rug_o) = rug_bessel_y1_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_y1)),
+        ComparableFloatRef(&y1)
+    );
+    assert_eq!(rug_o, o);
+
+    if y1.is_normal() {
+        assert_eq!(y1.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn bessel_y1_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        bessel_y1_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (y1, o) = Float::NAN.bessel_y1_prec(prec);
+        assert!(y1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::INFINITY.bessel_y1_prec(prec);
+        assert_eq!(ComparableFloat(y1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::NEGATIVE_INFINITY.bessel_y1_prec(prec);
+        assert!(y1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::ZERO.bessel_y1_prec(prec);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::NEGATIVE_ZERO.bessel_y1_prec(prec);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y1_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (y1, o) = x.clone().bessel_y1_round(rm);
+    assert!(y1.is_valid());
+
+    let (y1_alt, o_alt) = x.bessel_y1_round_ref(rm);
+    assert!(y1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_y1_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let (y1_alt, o_alt) = x.bessel_y1_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    assert_eq!(y1.is_nan(), x.is_nan() || x < 0u32);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_y1, rug_o) = rug_bessel_y1_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y1)),
+            ComparableFloatRef(&y1)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_y1_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(y1.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_y1_round_ref(Exact));
+    }
+}
+
+#[test]
+fn bessel_y1_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        bessel_y1_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (y1, o) = Float::NAN.bessel_y1_round(rm);
+        assert!(y1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::INFINITY.bessel_y1_round(rm);
+        assert_eq!(ComparableFloat(y1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::NEGATIVE_INFINITY.bessel_y1_round(rm);
+        assert!(y1.is_nan());
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::ZERO.bessel_y1_round(rm);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (y1, o) = Float::NEGATIVE_ZERO.bessel_y1_round(rm);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y1_properties_helper(x: Float) {
+    let y1 = x.clone().bessel_y1();
+    assert!(y1.is_valid());
+
+    let y1_alt = (&x).bessel_y1();
+    assert!(y1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+
+    let mut x_alt = x.clone();
+    x_alt.bessel_y1_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&y1));
+
+    let y1_alt = x.bessel_y1_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    let y1_alt = x.bessel_y1_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+
+    assert_eq!(y1.is_nan(), x.is_nan() || x < 0u32);
+
+    let rug_y1 = rug_bessel_y1(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_y1)),
+        ComparableFloatRef(&y1),
+    );
+}
+
+#[test]
+fn bessel_y1_properties() {
+    float_gen().test_properties(|x| {
+        bessel_y1_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y1_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (y1, o) = Float::bessel_y1_rational_prec_round(x.clone(), prec, rm);
+    assert!(y1.is_valid());
+
+    let (y1_alt, o_alt) = Float::bessel_y1_rational_prec_round_ref(&x, prec, rm);
+    assert!(y1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_y1, rug_o) = rug_bessel_y1_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_y1)),
+            ComparableFloatRef(&y1)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(y1.is_nan(), x < 0u32);
+
+    if y1.is_normal() {
+        assert_eq!(y1.get_prec(), Some(prec));
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::bessel_y1_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(y1.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::bessel_y1_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_y1_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        bessel_y1_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (y1, o) = Float::bessel_y1_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_y1_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (y1, o) = Float::bessel_y1_rational_prec(x.clone(), prec);
+    assert!(y1.is_valid());
+
+    let (y1_alt, o_alt) = Float::bessel_y1_rational_prec_ref(&x, prec);
+    assert!(y1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let (y1_alt, o_alt) = Float::bessel_y1_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&y1_alt), ComparableFloatRef(&y1));
+    assert_eq!(o_alt, o);
+
+    let (rug_y1, rug_o) = rug_bessel_y1_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_y1)),
+        ComparableFloatRef(&y1)
+    );
+    assert_eq!(rug_o, o);
+
+    assert_eq!(y1.is_nan(), x < 0u32);
+
+    if y1.is_normal() {
+        assert_eq!(y1.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn bessel_y1_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        bessel_y1_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (y1, o) = Float::bessel_y1_rational_prec(Rational::ZERO, prec);
+        assert_eq!(
+            ComparableFloat(y1),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_y1() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_bessel_y1(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(1.0, -0.7812128);
+    test::<f32>(-1.0, f32::NAN);
+    test::<f32>(0.5, -1.4714724);
+    test::<f32>(-0.5, f32::NAN);
+    test::<f32>(2.0, -0.10703243);
+    test::<f32>(5.0, 0.14786315);
+    test::<f32>(-2.5, f32::NAN);
+    test::<f32>(10.0, 0.24901542);
+    test::<f32>(0.001, -636.62213);
+    test::<f32>(10000000000.0, -2.1755918e-6);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(1.0, -0.7812128213002887);
+    test::<f64>(-1.0, f64::NAN);
+    test::<f64>(0.5, -1.471472392670243);
+    test::<f64>(-0.5, f64::NAN);
+    test::<f64>(2.0, -0.10703243154093754);
+    test::<f64>(5.0, 0.14786314339122683);
+    test::<f64>(-2.5, f64::NAN);
+    test::<f64>(10.0, 0.24901542420695388);
+    test::<f64>(0.001, -636.6221672311394);
+    test::<f64>(10000000000.0, -2.175591750630717e-6);
+    test::<f64>(1.0e300, 7.860673062724093e-151);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_y1_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let y1 = primitive_float_bessel_y1(x);
+        assert_eq!(y1.is_nan(), x.is_nan() || x < T::ZERO);
+    });
+}
+
+#[test]
+fn primitive_float_bessel_y1_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_y1_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_y1_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_bessel_y1_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", f32::NEGATIVE_INFINITY);
+    test::<f32>("1", -0.7812128);
+    test::<f32>("-1", f32::NAN);
+    test::<f32>("1/2", -1.4714724);
+    test::<f32>("-1/2", f32::NAN);
+    test::<f32>("1/3", -2.0881658);
+    test::<f32>("22/7", 0.35914338);
+    test::<f32>("3/2", -0.41230863);
+    test::<f32>("-7/2", f32::NAN);
+    test::<f32>("1000000", -0.00033104338);
+
+    test::<f64>("0", f64::NEGATIVE_INFINITY);
+    test::<f64>("1", -0.7812128213002887);
+    test::<f64>("-1", f64::NAN);
+    test::<f64>("1/2", -1.471472392670243);
+    test::<f64>("-1/2", f64::NAN);
+    test::<f64>("1/3", -2.0881657798883864);
+    test::<f64>("22/7", 0.35914337395059936);
+    test::<f64>("3/2", -0.4123086269739113);
+    test::<f64>("-7/2", f64::NAN);
+    test::<f64>("1000000", -0.00033104337672417626);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_y1_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let y1 = primitive_float_bessel_y1_rational::<T>(&x);
+        assert_eq!(y1.is_nan(), x < 0u32);
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_bessel_y1_rational::<T>(
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_bessel_y1(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_y1_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_y1_rational_properties_helper);
+}
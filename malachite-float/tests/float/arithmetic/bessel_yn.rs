@@ -0,0 +1,7965 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{BesselYn, BesselYnAssign, Parity};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_signed_pair_gen_var_4, rounding_mode_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::bessel_yn::{
+    primitive_float_bessel_yn, primitive_float_bessel_yn_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::bessel_yn::{
+    rug_bessel_yn, rug_bessel_yn_prec, rug_bessel_yn_prec_round, rug_bessel_yn_rational_prec,
+    rug_bessel_yn_rational_prec_round, rug_bessel_yn_round,
+};
+use malachite_float::test_util::generators::{
+    float_signed_rounding_mode_triple_gen_var_11,
+    float_signed_unsigned_rounding_mode_quadruple_gen_var_17,
+    rational_signed_unsigned_rounding_mode_quadruple_gen_var_3,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::rational_gen;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_bessel_yn() {
+    let test = |s, s_hex, n: i64, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let yn = x.clone().bessel_yn(n);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+
+        let yn_alt = (&x).bessel_yn(n);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+
+        let mut yn_alt = x.clone();
+        yn_alt.bessel_yn_assign(n);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_bessel_yn(&rug::Float::exact_from(&x), n))),
+            ComparableFloatRef(&yn)
+        );
+    };
+    test("NaN", "NaN", 3, "NaN", "NaN");
+    test("Infinity", "Infinity", 3, "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", 3, "NaN", "NaN");
+    test("0.0", "0x0.0", 3, "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", 3, "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", 3, "-4.0", "-0x4.0#1");
+    test("-1.0", "-0x1.0#1", 3, "NaN", "NaN");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+    );
+    test("3.0", "0x3.0#2", 3, "-0.50", "-0x0.8#2");
+    test("5.0", "0x5.0#3", 3, "0.16", "0x0.28#3");
+    test("0.50", "0x0.8#1", 3, "-32.0", "-0x2.0E+1#1");
+    test("-0.50", "-0x0.8#1", 3, "NaN", "NaN");
+    test("123.0", "0x7b.0#7", 3, "-0.06934", "-0x0.11c#7");
+    test("-123.50", "-0x7b.8#10", 3, "NaN", "NaN");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        "-0.48607045629843215",
+        "-0x0.7c6f1d095a832#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        "-0.65203820813027880",
+        "-0x0.a6ebf9dba97818#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+    );
+    test("27.0", "0x1b.0#5", 3, "0.0488", "0x0.0c8#5");
+    test("-6.0", "-0x6.0#2", 3, "NaN", "NaN");
+    test("7.9e-31", "0x1.0E-25#1", 3, "-8.1e90", "-0x4.0E+75#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        "-139.44729349062175360642432792501",
+        "-0x8b.7281d381ef221fd34573645#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        "NaN",
+        "NaN",
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+    );
+    test("0.0", "0x0.0", 0, "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", 0, "-Infinity", "-Infinity");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+    );
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        "0.78121282130028871654715000004789",
+        "0x0.c7fd903eb35ca24ef2cdf8958#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        "-0.35887291677671884",
+        "-0x0.5bdf1870f9e42#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        "2.0881657798883862661909621637442",
+        "0x2.169208553f3858f0fd0a2a3b0#100",
+    );
+    test("0.0", "0x0.0", -1, "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", -1, "Infinity", "Infinity");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+    );
+    test("0.0", "0x0.0", 2, "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", 2, "-Infinity", "-Infinity");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+    );
+    test("0.0", "0x0.0", -2, "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", -2, "-Infinity", "-Infinity");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        "5.8215176059647288477617570644280",
+        "0x5.d24efa52b4ebecd062d341fb0#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        "0.48607045629843215",
+        "0x0.7c6f1d095a832#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        "139.44729349062175360642432792501",
+        "0x8b.7281d381ef221fd34573645#100",
+    );
+    test("0.0", "0x0.0", -3, "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", -3, "Infinity", "Infinity");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+    );
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+    );
+    test("0.0", "0x0.0", -10, "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", -10, "-Infinity", "-Infinity");
+}
+
+#[test]
+fn test_bessel_yn_prec() {
+    let test = |s, s_hex, n: i64, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (yn, o) = x.clone().bessel_yn_prec(n, prec);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+        assert_eq!(o, o_out);
+
+        let (yn_alt, o_alt) = x.bessel_yn_prec_ref(n, prec);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut yn_alt = x.clone();
+        let o_alt = yn_alt.bessel_yn_prec_assign(n, prec);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_yn, rug_o) = rug_bessel_yn_prec(&rug::Float::exact_from(&x), n, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_yn)),
+            ComparableFloatRef(&yn)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 3, 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 3, 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 3, 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 3, 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 3, 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 3, 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 3, 1, "-4.0", "-0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 3, 10, "-5.8203", "-0x5.d2#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 3, 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 100, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        "-5.8203",
+        "-0x5.d2#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        100,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 3, 1, "-0.50", "-0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 3, 10, "-0.53809", "-0x0.89c#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        "-0.53854161610503161800470390533873",
+        "-0x0.89dddd04b4c2d1a2e57da8d6c#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 3, 1, "0.12", "0x0.2#1", Less);
+    test("5.0", "0x5.0#3", 3, 10, "0.14624", "0x0.257#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        100,
+        "0.14626716269319276959420472803785",
+        "0x0.2571c3c83ef82abd9d734bb810#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 3, 1, "-32.0", "-0x2.0E+1#1", Greater);
+    test("0.50", "0x0.8#1", 3, 10, "-42.062", "-0x2a.1#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        100,
+        "-42.059494304723882687658935894499",
+        "-0x2a.0f3b04cd165b8cfe2dee0890#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 3, 1, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 10, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 100, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 3, 1, "-0.062", "-0x0.1#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        "-0.069336",
+        "-0x0.11c0#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        100,
+        "-0.069309029688723001269049003880134",
+        "-0x0.11be3c8fd49f78d89e1cc66d08#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 3, 1, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 10, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 100, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        "-0.48584",
+        "-0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        100,
+        "-0.48607045629843218601057843043357",
+        "-0x0.7c6f1d095a8322743d4ea25b48#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        "-0.65234",
+        "-0x0.a70#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        "-0.65203820813027884243994641323487",
+        "-0x0.a6ebf9dba9781b5ce39347674#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        100,
+        "2.1755917537013204057945489439947e-6",
+        "0x0.00002480186e347933e108166bb7c0#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, 1, "0.062", "0x0.1#1", Greater);
+    test("27.0", "0x1b.0#5", 3, 10, "0.049438", "0x0.0ca8#10", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        100,
+        "0.049448457253154319278172316096389",
+        "0x0.0ca8a772bd6d7b14739fd165ef#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 3, 1, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 10, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 100, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        "-8.1e90",
+        "-0x4.0E+75#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        "-1.0376e91",
+        "-0x5.18E+75#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        "-1.0374539036469074784412835673504e91",
+        "-0x5.17cc1b727220a94fe13abe8f8E+75#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        "-1.3e2",
+        "-0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        "-139.50",
+        "-0x8b.8#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        100,
+        "-139.44729349062175360642432792501",
+        "-0x8b.7281d381ef221fd34573645#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        1,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        100,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        100,
+        "0.32836630851631271034955265083786",
+        "0x0.540fd07c2f9133fe4d899152b8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        100,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 0, 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 0, 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 0, 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 0, 100, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        100,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        100,
+        "0.35887291677671893324419080972445",
+        "0x0.5bdf1870f9e426903f28a49430#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        100,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 1, 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, 100, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        "0.78125",
+        "0x0.c80#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        100,
+        "0.78121282130028871654715000004789",
+        "0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        "-0.35889",
+        "-0x0.5be#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        100,
+        "-0.35887291677671893324419080972445",
+        "-0x0.5bdf1870f9e426903f28a49430#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        "2.0898",
+        "0x2.17#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        100,
+        "2.0881657798883862661909621637442",
+        "0x2.169208553f3858f0fd0a2a3b0#100",
+        Less,
+    );
+    test("0.0", "0x0.0", -1, 1, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, 10, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, 100, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, 1, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, 10, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, 100, "Infinity", "Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        100,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        1,
+        "-0.12",
+        "-0x0.2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        100,
+        "-0.099900713929027930511350160446795",
+        "-0x0.199317db2c170b50d1d14cb454#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        1,
+        "-8.0",
+        "-0x8.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        100,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test("0.0", "0x0.0", 2, 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 2, 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 2, 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 2, 100, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        100,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        1,
+        "-0.12",
+        "-0x0.2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        100,
+        "-0.099900713929027930511350160446795",
+        "-0x0.199317db2c170b50d1d14cb454#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        1,
+        "-8.0",
+        "-0x8.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        100,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test("0.0", "0x0.0", -2, 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -2, 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -2, 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -2, 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -2, 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -2, 100, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        1,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        "5.8203",
+        "0x5.d2#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        100,
+        "5.8215176059647288477617570644280",
+        "0x5.d24efa52b4ebecd062d341fb0#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        "0.48584",
+        "0x0.7c6#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        100,
+        "0.48607045629843218601057843043357",
+        "0x0.7c6f1d095a8322743d4ea25b48#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        1,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        "139.50",
+        "0x8b.8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        100,
+        "139.44729349062175360642432792501",
+        "0x8b.7281d381ef221fd34573645#100",
+        Less,
+    );
+    test("0.0", "0x0.0", -3, 1, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, 10, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, 100, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, 1, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, 10, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, 100, "Infinity", "Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        1,
+        "-1.3e8",
+        "-0x8.0E+6#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        100,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        1,
+        "-2.0e3",
+        "-0x8.0E+2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        100,
+        "-1669.6885839042244953020127587364",
+        "-0x685.b04708e5323a6593d074ca0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        1,
+        "-8.8e12",
+        "-0x8.0E+10#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        100,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 10, 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, 100, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        1,
+        "-1.3e8",
+        "-0x8.0E+6#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        100,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        1,
+        "-2.0e3",
+        "-0x8.0E+2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        100,
+        "-1669.6885839042244953020127587364",
+        "-0x685.b04708e5323a6593d074ca0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        1,
+        "-8.8e12",
+        "-0x8.0E+10#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        100,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -10, 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -10, 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -10, 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -10, 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -10, 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -10, 100, "-Infinity", "-Infinity", Equal);
+}
+
+#[test]
+fn bessel_yn_prec_fail() {
+    assert_panic!(Float::NAN.bessel_yn_prec(3, 0));
+    assert_panic!(Float::NAN.bessel_yn_prec_ref(3, 0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.bessel_yn_prec_assign(3, 0)
+    });
+}
+
+#[test]
+fn test_bessel_yn_round() {
+    let test = |s, s_hex, n: i64, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (yn, o) = x.clone().bessel_yn_round(n, rm);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+        assert_eq!(o, o_out);
+
+        let (yn_alt, o_alt) = x.bessel_yn_round_ref(n, rm);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut yn_alt = x.clone();
+        let o_alt = yn_alt.bessel_yn_round_assign(n, rm);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_yn, rug_o) = rug_bessel_yn_round(&rug::Float::exact_from(&x), n, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_yn)),
+                ComparableFloatRef(&yn),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 3, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 3, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 3, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 3, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 3, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 3, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 3, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 3, Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 3, Floor, "-8.0", "-0x8.0#1", Less);
+    test("1.0", "0x1.0#1", 3, Ceiling, "-4.0", "-0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 3, Down, "-4.0", "-0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 3, Up, "-8.0", "-0x8.0#1", Less);
+    test("1.0", "0x1.0#1", 3, Nearest, "-4.0", "-0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 3, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Floor,
+        "-5.8215176059647288477617570644343",
+        "-0x5.d24efa52b4ebecd062d341fb8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Ceiling,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Down,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Up,
+        "-5.8215176059647288477617570644343",
+        "-0x5.d24efa52b4ebecd062d341fb8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        Nearest,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 3, Floor, "-0.75", "-0x0.c#2", Less);
+    test("3.0", "0x3.0#2", 3, Ceiling, "-0.50", "-0x0.8#2", Greater);
+    test("3.0", "0x3.0#2", 3, Down, "-0.50", "-0x0.8#2", Greater);
+    test("3.0", "0x3.0#2", 3, Up, "-0.75", "-0x0.c#2", Less);
+    test("3.0", "0x3.0#2", 3, Nearest, "-0.50", "-0x0.8#2", Greater);
+    test("5.0", "0x5.0#3", 3, Floor, "0.12", "0x0.20#3", Less);
+    test("5.0", "0x5.0#3", 3, Ceiling, "0.16", "0x0.28#3", Greater);
+    test("5.0", "0x5.0#3", 3, Down, "0.12", "0x0.20#3", Less);
+    test("5.0", "0x5.0#3", 3, Up, "0.16", "0x0.28#3", Greater);
+    test("5.0", "0x5.0#3", 3, Nearest, "0.16", "0x0.28#3", Greater);
+    test("0.50", "0x0.8#1", 3, Floor, "-64.0", "-0x4.0E+1#1", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        Ceiling,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 3, Down, "-32.0", "-0x2.0E+1#1", Greater);
+    test("0.50", "0x0.8#1", 3, Up, "-64.0", "-0x4.0E+1#1", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        Nearest,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 3, Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, Exact, "NaN", "NaN", Equal);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Floor,
+        "-0.06934",
+        "-0x0.11c#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Ceiling,
+        "-0.06836",
+        "-0x0.118#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Down,
+        "-0.06836",
+        "-0x0.118#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 3, Up, "-0.06934", "-0x0.11c#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        Nearest,
+        "-0.06934",
+        "-0x0.11c#7",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 3, Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Floor,
+        "-0.48607045629843260",
+        "-0x0.7c6f1d095a834#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Ceiling,
+        "-0.48607045629843215",
+        "-0x0.7c6f1d095a832#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Down,
+        "-0.48607045629843215",
+        "-0x0.7c6f1d095a832#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Up,
+        "-0.48607045629843260",
+        "-0x0.7c6f1d095a834#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        Nearest,
+        "-0.48607045629843215",
+        "-0x0.7c6f1d095a832#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Floor,
+        "-0.65203820813027891",
+        "-0x0.a6ebf9dba97820#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Ceiling,
+        "-0.65203820813027880",
+        "-0x0.a6ebf9dba97818#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Down,
+        "-0.65203820813027880",
+        "-0x0.a6ebf9dba97818#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Up,
+        "-0.65203820813027891",
+        "-0x0.a6ebf9dba97820#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        Nearest,
+        "-0.65203820813027880",
+        "-0x0.a6ebf9dba97818#53",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Floor,
+        "2.17559159e-6",
+        "0x0.00002480184#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Ceiling,
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Down,
+        "2.17559159e-6",
+        "0x0.00002480184#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Up,
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        Nearest,
+        "2.17559182e-6",
+        "0x0.00002480188#24",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, Floor, "0.0488", "0x0.0c8#5", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        Ceiling,
+        "0.0508",
+        "0x0.0d0#5",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, Down, "0.0488", "0x0.0c8#5", Less);
+    test("27.0", "0x1b.0#5", 3, Up, "0.0508", "0x0.0d0#5", Greater);
+    test("27.0", "0x1b.0#5", 3, Nearest, "0.0488", "0x0.0c8#5", Less);
+    test("-6.0", "-0x6.0#2", 3, Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, Exact, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Floor,
+        "-1.6e91",
+        "-0x8.0E+75#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Ceiling,
+        "-8.1e90",
+        "-0x4.0E+75#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Down,
+        "-8.1e90",
+        "-0x4.0E+75#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Up,
+        "-1.6e91",
+        "-0x8.0E+75#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        Nearest,
+        "-8.1e90",
+        "-0x4.0E+75#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Floor,
+        "-139.44729349062175360642432792521",
+        "-0x8b.7281d381ef221fd34573646#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Ceiling,
+        "-139.44729349062175360642432792501",
+        "-0x8b.7281d381ef221fd34573645#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Down,
+        "-139.44729349062175360642432792501",
+        "-0x8b.7281d381ef221fd34573645#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Up,
+        "-139.44729349062175360642432792521",
+        "-0x8b.7281d381ef221fd34573646#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        Nearest,
+        "-139.44729349062175360642432792501",
+        "-0x8b.7281d381ef221fd34573645#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Floor,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Ceiling,
+        "0.088256964215676957982926766023597",
+        "0x0.16980226f358df29efcc5d7928#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Down,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Up,
+        "0.088256964215676957982926766023597",
+        "0x0.16980226f358df29efcc5d7928#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        Nearest,
+        "0.088256964215676957982926766023499",
+        "0x0.16980226f358df29efcc5d7926#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Floor,
+        "0.32836630851631243",
+        "0x0.540fd07c2f912#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Ceiling,
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Down,
+        "0.32836630851631243",
+        "0x0.540fd07c2f912#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Up,
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        Nearest,
+        "0.32836630851631288",
+        "0x0.540fd07c2f914#50",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Floor,
+        "-0.73437307345447260776116547505685",
+        "-0x0.bbffdfad8cca8b95935d31802#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Ceiling,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Down,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Up,
+        "-0.73437307345447260776116547505685",
+        "-0x0.bbffdfad8cca8b95935d31802#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        Nearest,
+        "-0.73437307345447260776116547505606",
+        "-0x0.bbffdfad8cca8b95935d31801#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 0, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 0, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 0, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 0, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 0, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "-0.78121282130028871654715000004868",
+        "-0x0.c7fd903eb35ca24ef2cdf8959#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "-0.78121282130028871654715000004868",
+        "-0x0.c7fd903eb35ca24ef2cdf8959#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "-0.78121282130028871654715000004789",
+        "-0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.35887291677671929",
+        "0x0.5bdf1870f9e44#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.35887291677671929",
+        "0x0.5bdf1870f9e44#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.35887291677671884",
+        "0x0.5bdf1870f9e42#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-2.0881657798883862661909621637473",
+        "-0x2.169208553f3858f0fd0a2a3b4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-2.0881657798883862661909621637473",
+        "-0x2.169208553f3858f0fd0a2a3b4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Floor,
+        "0.78121282130028871654715000004789",
+        "0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Ceiling,
+        "0.78121282130028871654715000004868",
+        "0x0.c7fd903eb35ca24ef2cdf8959#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Down,
+        "0.78121282130028871654715000004789",
+        "0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Up,
+        "0.78121282130028871654715000004868",
+        "0x0.c7fd903eb35ca24ef2cdf8959#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        Nearest,
+        "0.78121282130028871654715000004789",
+        "0x0.c7fd903eb35ca24ef2cdf8958#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Floor,
+        "-0.35887291677671929",
+        "-0x0.5bdf1870f9e44#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Ceiling,
+        "-0.35887291677671884",
+        "-0x0.5bdf1870f9e42#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Down,
+        "-0.35887291677671884",
+        "-0x0.5bdf1870f9e42#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Up,
+        "-0.35887291677671929",
+        "-0x0.5bdf1870f9e44#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        Nearest,
+        "-0.35887291677671884",
+        "-0x0.5bdf1870f9e42#50",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Floor,
+        "2.0881657798883862661909621637442",
+        "0x2.169208553f3858f0fd0a2a3b0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Ceiling,
+        "2.0881657798883862661909621637473",
+        "0x2.169208553f3858f0fd0a2a3b4#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Down,
+        "2.0881657798883862661909621637442",
+        "0x2.169208553f3858f0fd0a2a3b0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Up,
+        "2.0881657798883862661909621637473",
+        "0x2.169208553f3858f0fd0a2a3b4#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        Nearest,
+        "2.0881657798883862661909621637442",
+        "0x2.169208553f3858f0fd0a2a3b0#100",
+        Less,
+    );
+    test("0.0", "0x0.0", -1, Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, Floor, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, Ceiling, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, Down, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, Up, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, Nearest, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -1, Exact, "Infinity", "Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Floor,
+        "-1.6506826068162543910772267661206",
+        "-0x1.a69322a45a1223c7d5684ea44#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Ceiling,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Down,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Up,
+        "-1.6506826068162543910772267661206",
+        "-0x1.a69322a45a1223c7d5684ea44#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        Nearest,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Floor,
+        "-0.099900713929027996",
+        "-0x0.199317db2c1710#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Ceiling,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Down,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Up,
+        "-0.099900713929027996",
+        "-0x0.199317db2c1710#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        Nearest,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Floor,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Ceiling,
+        "-11.794621605875844989384607507397",
+        "-0xb.cb6c5251ee878a105adfcbe1#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Down,
+        "-11.794621605875844989384607507397",
+        "-0xb.cb6c5251ee878a105adfcbe1#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Up,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        Nearest,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test("0.0", "0x0.0", 2, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 2, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 2, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 2, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 2, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Floor,
+        "-1.6506826068162543910772267661206",
+        "-0x1.a69322a45a1223c7d5684ea44#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Ceiling,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Down,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Up,
+        "-1.6506826068162543910772267661206",
+        "-0x1.a69322a45a1223c7d5684ea44#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        Nearest,
+        "-1.6506826068162543910772267661190",
+        "-0x1.a69322a45a1223c7d5684ea42#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Floor,
+        "-0.099900713929027996",
+        "-0x0.199317db2c1710#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Ceiling,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Down,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Up,
+        "-0.099900713929027996",
+        "-0x0.199317db2c1710#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        Nearest,
+        "-0.099900713929027884",
+        "-0x0.199317db2c1708#50",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Floor,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Ceiling,
+        "-11.794621605875844989384607507397",
+        "-0xb.cb6c5251ee878a105adfcbe1#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Down,
+        "-11.794621605875844989384607507397",
+        "-0xb.cb6c5251ee878a105adfcbe1#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Up,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        Nearest,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Less,
+    );
+    test("0.0", "0x0.0", -2, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -2, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -2, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -2, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -2, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -2, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -2, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", -2, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -2, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", -2, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Floor,
+        "5.8215176059647288477617570644280",
+        "0x5.d24efa52b4ebecd062d341fb0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Ceiling,
+        "5.8215176059647288477617570644343",
+        "0x5.d24efa52b4ebecd062d341fb8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Down,
+        "5.8215176059647288477617570644280",
+        "0x5.d24efa52b4ebecd062d341fb0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Up,
+        "5.8215176059647288477617570644343",
+        "0x5.d24efa52b4ebecd062d341fb8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        Nearest,
+        "5.8215176059647288477617570644280",
+        "0x5.d24efa52b4ebecd062d341fb0#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Floor,
+        "0.48607045629843215",
+        "0x0.7c6f1d095a832#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Ceiling,
+        "0.48607045629843260",
+        "0x0.7c6f1d095a834#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Down,
+        "0.48607045629843215",
+        "0x0.7c6f1d095a832#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Up,
+        "0.48607045629843260",
+        "0x0.7c6f1d095a834#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        Nearest,
+        "0.48607045629843215",
+        "0x0.7c6f1d095a832#50",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Floor,
+        "139.44729349062175360642432792501",
+        "0x8b.7281d381ef221fd34573645#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Ceiling,
+        "139.44729349062175360642432792521",
+        "0x8b.7281d381ef221fd34573646#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Down,
+        "139.44729349062175360642432792501",
+        "0x8b.7281d381ef221fd34573645#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Up,
+        "139.44729349062175360642432792521",
+        "0x8b.7281d381ef221fd34573646#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        Nearest,
+        "139.44729349062175360642432792501",
+        "0x8b.7281d381ef221fd34573645#100",
+        Less,
+    );
+    test("0.0", "0x0.0", -3, Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, Floor, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, Ceiling, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, Down, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, Up, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, Nearest, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", -3, Exact, "Infinity", "Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "-121618014.27868918928813042666788",
+        "-0x73fbe5e.47582cb9bdc41d0a020#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "-121618014.27868918928813042666788",
+        "-0x73fbe5e.47582cb9bdc41d0a020#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "-1669.6885839042261",
+        "-0x685.b04708e534#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "-1669.6885839042261",
+        "-0x685.b04708e534#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-7005938776839.9345413559482075652",
+        "-0x65f327dfb07.ef3e1a308e9a9a8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-7005938776839.9345413559482075652",
+        "-0x65f327dfb07.ef3e1a308e9a9a8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Floor,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Ceiling,
+        "-121618014.27868918928813042666788",
+        "-0x73fbe5e.47582cb9bdc41d0a020#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Down,
+        "-121618014.27868918928813042666788",
+        "-0x73fbe5e.47582cb9bdc41d0a020#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Up,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        Nearest,
+        "-121618014.27868918928813042666799",
+        "-0x73fbe5e.47582cb9bdc41d0a028#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Floor,
+        "-1669.6885839042261",
+        "-0x685.b04708e534#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Ceiling,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Down,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Up,
+        "-1669.6885839042261",
+        "-0x685.b04708e534#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        Nearest,
+        "-1669.6885839042243",
+        "-0x685.b04708e532#50",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Floor,
+        "-7005938776839.9345413559482075652",
+        "-0x65f327dfb07.ef3e1a308e9a9a8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Ceiling,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Down,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Up,
+        "-7005938776839.9345413559482075652",
+        "-0x65f327dfb07.ef3e1a308e9a9a8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        Nearest,
+        "-7005938776839.9345413559482075583",
+        "-0x65f327dfb07.ef3e1a308e9a9a0#100",
+        Greater,
+    );
+    test("0.0", "0x0.0", -10, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        -10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", -10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", -10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        -10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", -10, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", -10, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", -10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_yn_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.bessel_yn_round(3, Exact));
+    assert_panic!(X.bessel_yn_round_ref(3, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_yn_round_assign(3, Exact);
+    });
+}
+
+#[test]
+fn test_bessel_yn_prec_round() {
+    let test = |s, s_hex, n: i64, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (yn, o) = x.clone().bessel_yn_prec_round(n, prec, rm);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+        assert_eq!(o, o_out);
+
+        let (yn_alt, o_alt) = x.bessel_yn_prec_round_ref(n, prec, rm);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut yn_alt = x.clone();
+        let o_alt = yn_alt.bessel_yn_prec_round_assign(n, prec, rm);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn), ComparableFloatRef(&yn_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_yn, rug_o) =
+                rug_bessel_yn_prec_round(&rug::Float::exact_from(&x), n, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_yn)),
+                ComparableFloatRef(&yn)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 3, 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 1, Exact, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 10, Floor, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity", "Infinity", 3, 10, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test("Infinity", "Infinity", 3, 10, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 3, 10, Up, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity", "Infinity", 3, 10, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test("Infinity", "Infinity", 3, 10, Exact, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity", "Infinity", 3, 100, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test("-Infinity", "-Infinity", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 10, Floor, "NaN", "NaN", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 3, 10, Up, "NaN", "NaN", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 3, 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        100,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", 3, 1, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 3, 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 3, 1, Exact, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 3, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 3, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        100,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        1,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 3, 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        1,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 3, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        100,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 3, 1, Floor, "-8.0", "-0x8.0#1", Less);
+    test("1.0", "0x1.0#1", 3, 1, Ceiling, "-4.0", "-0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 3, 1, Down, "-4.0", "-0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 3, 1, Up, "-8.0", "-0x8.0#1", Less);
+    test("1.0", "0x1.0#1", 3, 1, Nearest, "-4.0", "-0x4.0#1", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Floor,
+        "-5.8281",
+        "-0x5.d4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Ceiling,
+        "-5.8203",
+        "-0x5.d2#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Down,
+        "-5.8203",
+        "-0x5.d2#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 3, 10, Up, "-5.8281", "-0x5.d4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        Nearest,
+        "-5.8203",
+        "-0x5.d2#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        Nearest,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Floor,
+        "-8.0",
+        "-0x8.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Down,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Up,
+        "-8.0",
+        "-0x8.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        1,
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Floor,
+        "-5.8281",
+        "-0x5.d4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Ceiling,
+        "-5.8203",
+        "-0x5.d2#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Down,
+        "-5.8203",
+        "-0x5.d2#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Up,
+        "-5.8281",
+        "-0x5.d4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        10,
+        Nearest,
+        "-5.8203",
+        "-0x5.d2#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        3,
+        100,
+        Nearest,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 3, 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "3.0", "0x3.0#2", 3, 1, Ceiling, "-0.50", "-0x0.8#1", Greater,
+    );
+    test("3.0", "0x3.0#2", 3, 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("3.0", "0x3.0#2", 3, 1, Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "3.0", "0x3.0#2", 3, 1, Nearest, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Floor,
+        "-0.53906",
+        "-0x0.8a0#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Ceiling,
+        "-0.53809",
+        "-0x0.89c#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Down,
+        "-0.53809",
+        "-0x0.89c#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 3, 10, Up, "-0.53906", "-0x0.8a0#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        Nearest,
+        "-0.53809",
+        "-0x0.89c#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        Nearest,
+        "-0.53854161610503161800470390533873",
+        "-0x0.89dddd04b4c2d1a2e57da8d6c#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 3, 1, Floor, "0.12", "0x0.2#1", Less);
+    test("5.0", "0x5.0#3", 3, 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 3, 1, Down, "0.12", "0x0.2#1", Less);
+    test("5.0", "0x5.0#3", 3, 1, Up, "0.25", "0x0.4#1", Greater);
+    test("5.0", "0x5.0#3", 3, 1, Nearest, "0.12", "0x0.2#1", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Floor,
+        "0.14624",
+        "0x0.257#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Ceiling,
+        "0.14648",
+        "0x0.258#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 3, 10, Down, "0.14624", "0x0.257#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Up,
+        "0.14648",
+        "0x0.258#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        10,
+        Nearest,
+        "0.14624",
+        "0x0.257#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        3,
+        100,
+        Nearest,
+        "0.14626716269319276959420472803785",
+        "0x0.2571c3c83ef82abd9d734bb810#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 3, 1, Floor, "-64.0", "-0x4.0E+1#1", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        1,
+        Ceiling,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        1,
+        Down,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 3, 1, Up, "-64.0", "-0x4.0E+1#1", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        1,
+        Nearest,
+        "-32.0",
+        "-0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Floor,
+        "-42.062",
+        "-0x2a.1#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Ceiling,
+        "-42.000",
+        "-0x2a.0#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Down,
+        "-42.000",
+        "-0x2a.0#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 3, 10, Up, "-42.062", "-0x2a.1#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        10,
+        Nearest,
+        "-42.062",
+        "-0x2a.1#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        3,
+        100,
+        Nearest,
+        "-42.059494304723882687658935894499",
+        "-0x2a.0f3b04cd165b8cfe2dee0890#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 10, Up, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("-0.50", "-0x0.8#1", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test("123.0", "0x7b.0#7", 3, 1, Floor, "-0.12", "-0x0.2#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 3, 1, Ceiling, "-0.062", "-0x0.1#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", 3, 1, Down, "-0.062", "-0x0.1#1", Greater,
+    );
+    test("123.0", "0x7b.0#7", 3, 1, Up, "-0.12", "-0x0.2#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 3, 1, Nearest, "-0.062", "-0x0.1#1", Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Floor,
+        "-0.069336",
+        "-0x0.11c0#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Ceiling,
+        "-0.069214",
+        "-0x0.11b8#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Down,
+        "-0.069214",
+        "-0x0.11b8#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Up,
+        "-0.069336",
+        "-0x0.11c0#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        10,
+        Nearest,
+        "-0.069336",
+        "-0x0.11c0#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        3,
+        100,
+        Nearest,
+        "-0.069309029688723001269049003880134",
+        "-0x0.11be3c8fd49f78d89e1cc66d08#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 10, Up, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-123.50", "-0x7b.8#10", 3, 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        3,
+        100,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Ceiling,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Down,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Up,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Floor,
+        "-0.48633",
+        "-0x0.7c8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Ceiling,
+        "-0.48584",
+        "-0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Down,
+        "-0.48584",
+        "-0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Up,
+        "-0.48633",
+        "-0x0.7c8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        10,
+        Nearest,
+        "-0.48584",
+        "-0x0.7c6#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        3,
+        100,
+        Nearest,
+        "-0.48607045629843218601057843043357",
+        "-0x0.7c6f1d095a8322743d4ea25b48#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Floor,
+        "-0.65234",
+        "-0x0.a70#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Ceiling,
+        "-0.65137",
+        "-0x0.a6c#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Down,
+        "-0.65137",
+        "-0x0.a6c#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Up,
+        "-0.65234",
+        "-0x0.a70#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        Nearest,
+        "-0.65234",
+        "-0x0.a70#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        Nearest,
+        "-0.65203820813027884243994641323487",
+        "-0x0.a6ebf9dba9781b5ce39347674#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Floor,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Ceiling,
+        "3.8e-6",
+        "0x0.00004#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Down,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Up,
+        "3.8e-6",
+        "0x0.00004#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        1,
+        Nearest,
+        "1.9e-6",
+        "0x0.00002#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Floor,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Ceiling,
+        "2.1793e-6",
+        "0x0.0000249#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Down,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Up,
+        "2.1793e-6",
+        "0x0.0000249#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        10,
+        Nearest,
+        "2.1756e-6",
+        "0x0.0000248#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        3,
+        100,
+        Nearest,
+        "2.1755917537013204057945489439947e-6",
+        "0x0.00002480186e347933e108166bb7c0#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, 1, Floor, "0.031", "0x0.08#1", Less);
+    test(
+        "27.0", "0x1b.0#5", 3, 1, Ceiling, "0.062", "0x0.1#1", Greater,
+    );
+    test("27.0", "0x1b.0#5", 3, 1, Down, "0.031", "0x0.08#1", Less);
+    test("27.0", "0x1b.0#5", 3, 1, Up, "0.062", "0x0.1#1", Greater);
+    test(
+        "27.0", "0x1b.0#5", 3, 1, Nearest, "0.062", "0x0.1#1", Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Floor,
+        "0.049438",
+        "0x0.0ca8#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Ceiling,
+        "0.049500",
+        "0x0.0cac#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Down,
+        "0.049438",
+        "0x0.0ca8#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Up,
+        "0.049500",
+        "0x0.0cac#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        Nearest,
+        "0.049438",
+        "0x0.0ca8#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        100,
+        Nearest,
+        "0.049448457253154319278172316096389",
+        "0x0.0ca8a772bd6d7b14739fd165ef#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 10, Up, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("-6.0", "-0x6.0#2", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Floor,
+        "-1.6e91",
+        "-0x8.0E+75#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Ceiling,
+        "-8.1e90",
+        "-0x4.0E+75#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Down,
+        "-8.1e90",
+        "-0x4.0E+75#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Up,
+        "-1.6e91",
+        "-0x8.0E+75#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        Nearest,
+        "-8.1e90",
+        "-0x4.0E+75#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Floor,
+        "-1.0376e91",
+        "-0x5.18E+75#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Ceiling,
+        "-1.0360e91",
+        "-0x5.16E+75#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Down,
+        "-1.0360e91",
+        "-0x5.16E+75#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Up,
+        "-1.0376e91",
+        "-0x5.18E+75#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        Nearest,
+        "-1.0376e91",
+        "-0x5.18E+75#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        Nearest,
+        "-1.0374539036469074784412835673504e91",
+        "-0x5.17cc1b727220a94fe13abe8f8E+75#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Floor,
+        "-2.6e2",
+        "-0x1.0E+2#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Ceiling,
+        "-1.3e2",
+        "-0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Down,
+        "-1.3e2",
+        "-0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Up,
+        "-2.6e2",
+        "-0x1.0E+2#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Nearest,
+        "-1.3e2",
+        "-0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Floor,
+        "-139.50",
+        "-0x8b.8#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Ceiling,
+        "-139.25",
+        "-0x8b.4#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Down,
+        "-139.25",
+        "-0x8b.4#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Up,
+        "-139.50",
+        "-0x8b.8#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Nearest,
+        "-139.50",
+        "-0x8b.8#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        3,
+        100,
+        Nearest,
+        "-139.44729349062175360642432792501",
+        "-0x8b.7281d381ef221fd34573645#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        3,
+        100,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Floor,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Ceiling,
+        "0.088379",
+        "0x0.16a0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Down,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Up,
+        "0.088379",
+        "0x0.16a0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        0,
+        10,
+        Nearest,
+        "0.088257",
+        "0x0.1698#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Floor,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Ceiling,
+        "0.32861",
+        "0x0.542#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Down,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Up,
+        "0.32861",
+        "0x0.542#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        0,
+        10,
+        Nearest,
+        "0.32812",
+        "0x0.540#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Floor,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Ceiling,
+        "-0.73340",
+        "-0x0.bbc#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Down,
+        "-0.73340",
+        "-0x0.bbc#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Up,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        0,
+        10,
+        Nearest,
+        "-0.73438",
+        "-0x0.bc0#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 0, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 0, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 0, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        0,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Floor,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Ceiling,
+        "-0.78027",
+        "-0x0.c7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Down,
+        "-0.78027",
+        "-0x0.c7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Up,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        10,
+        Nearest,
+        "-0.78125",
+        "-0x0.c80#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Floor,
+        "0.35840",
+        "0x0.5bc#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Ceiling,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Down,
+        "0.35840",
+        "0x0.5bc#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Up,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        10,
+        Nearest,
+        "0.35889",
+        "0x0.5be#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Floor,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Ceiling,
+        "-2.0859",
+        "-0x2.16#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Down,
+        "-2.0859",
+        "-0x2.16#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Up,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        10,
+        Nearest,
+        "-2.0898",
+        "-0x2.17#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Floor,
+        "0.78027",
+        "0x0.c7c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Ceiling,
+        "0.78125",
+        "0x0.c80#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Down,
+        "0.78027",
+        "0x0.c7c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Up,
+        "0.78125",
+        "0x0.c80#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -1,
+        10,
+        Nearest,
+        "0.78125",
+        "0x0.c80#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Floor,
+        "-0.35889",
+        "-0x0.5be#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Ceiling,
+        "-0.35840",
+        "-0x0.5bc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Down,
+        "-0.35840",
+        "-0x0.5bc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Up,
+        "-0.35889",
+        "-0x0.5be#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -1,
+        10,
+        Nearest,
+        "-0.35889",
+        "-0x0.5be#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Floor,
+        "2.0859",
+        "0x2.16#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Ceiling,
+        "2.0898",
+        "0x2.17#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Down,
+        "2.0859",
+        "0x2.16#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Up,
+        "2.0898",
+        "0x2.17#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -1,
+        10,
+        Nearest,
+        "2.0898",
+        "0x2.17#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", -1, 10, Floor, "Infinity", "Infinity", Equal);
+    test(
+        "0.0", "0x0.0", -1, 10, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("0.0", "0x0.0", -1, 10, Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -1, 10, Up, "Infinity", "Infinity", Equal);
+    test(
+        "0.0", "0x0.0", -1, 10, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("0.0", "0x0.0", -1, 10, Exact, "Infinity", "Infinity", Equal);
+    test(
+        "-0.0", "-0x0.0", -1, 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", -1, 10, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", -1, 10, Down, "Infinity", "Infinity", Equal,
+    );
+    test("-0.0", "-0x0.0", -1, 10, Up, "Infinity", "Infinity", Equal);
+    test(
+        "-0.0", "-0x0.0", -1, 10, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", -1, 10, Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Floor,
+        "-1.6523",
+        "-0x1.a70#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Ceiling,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Down,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Up,
+        "-1.6523",
+        "-0x1.a70#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        2,
+        10,
+        Nearest,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Floor,
+        "-0.099976",
+        "-0x0.1998#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Ceiling,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Down,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Up,
+        "-0.099976",
+        "-0x0.1998#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        2,
+        10,
+        Nearest,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Floor,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Ceiling,
+        "-11.781",
+        "-0xb.c8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Down,
+        "-11.781",
+        "-0xb.c8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Up,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        2,
+        10,
+        Nearest,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        2,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        2,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 2, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 2, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        2,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        2,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 2, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Floor,
+        "-1.6523",
+        "-0x1.a70#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Ceiling,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Down,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Up,
+        "-1.6523",
+        "-0x1.a70#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -2,
+        10,
+        Nearest,
+        "-1.6504",
+        "-0x1.a68#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Floor,
+        "-0.099976",
+        "-0x0.1998#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Ceiling,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Down,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Up,
+        "-0.099976",
+        "-0x0.1998#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -2,
+        10,
+        Nearest,
+        "-0.099854",
+        "-0x0.1990#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Floor,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Ceiling,
+        "-11.781",
+        "-0xb.c8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Down,
+        "-11.781",
+        "-0xb.c8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Up,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -2,
+        10,
+        Nearest,
+        "-11.797",
+        "-0xb.cc#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -2,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -2,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -2,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", -2, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        -2,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -2,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        10,
+        Up,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -2,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Floor,
+        "5.8203",
+        "0x5.d2#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Ceiling,
+        "5.8281",
+        "0x5.d4#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Down,
+        "5.8203",
+        "0x5.d2#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Up,
+        "5.8281",
+        "0x5.d4#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -3,
+        10,
+        Nearest,
+        "5.8203",
+        "0x5.d2#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Floor,
+        "0.48584",
+        "0x0.7c6#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Ceiling,
+        "0.48633",
+        "0x0.7c8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Down,
+        "0.48584",
+        "0x0.7c6#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Up,
+        "0.48633",
+        "0x0.7c8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -3,
+        10,
+        Nearest,
+        "0.48584",
+        "0x0.7c6#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Floor,
+        "139.25",
+        "0x8b.4#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Ceiling,
+        "139.50",
+        "0x8b.8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Down,
+        "139.25",
+        "0x8b.4#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Up,
+        "139.50",
+        "0x8b.8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -3,
+        10,
+        Nearest,
+        "139.50",
+        "0x8b.8#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", -3, 10, Floor, "Infinity", "Infinity", Equal);
+    test(
+        "0.0", "0x0.0", -3, 10, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("0.0", "0x0.0", -3, 10, Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", -3, 10, Up, "Infinity", "Infinity", Equal);
+    test(
+        "0.0", "0x0.0", -3, 10, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("0.0", "0x0.0", -3, 10, Exact, "Infinity", "Infinity", Equal);
+    test(
+        "-0.0", "-0x0.0", -3, 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", -3, 10, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", -3, 10, Down, "Infinity", "Infinity", Equal,
+    );
+    test("-0.0", "-0x0.0", -3, 10, Up, "Infinity", "Infinity", Equal);
+    test(
+        "-0.0", "-0x0.0", -3, 10, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", -3, 10, Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Floor,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Ceiling,
+        "-1.2150e8",
+        "-0x7.3eE+6#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Down,
+        "-1.2150e8",
+        "-0x7.3eE+6#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Up,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        10,
+        Nearest,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Floor,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Ceiling,
+        "-1668.0",
+        "-0x684.0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Down,
+        "-1668.0",
+        "-0x684.0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Up,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        10,
+        Nearest,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Floor,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Ceiling,
+        "-7.0008e12",
+        "-0x6.5eE+10#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Down,
+        "-7.0008e12",
+        "-0x6.5eE+10#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Up,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        10,
+        Nearest,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 10, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        10,
+        Up,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Floor,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Ceiling,
+        "-1.2150e8",
+        "-0x7.3eE+6#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Down,
+        "-1.2150e8",
+        "-0x7.3eE+6#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Up,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        -10,
+        10,
+        Nearest,
+        "-1.2163e8",
+        "-0x7.40E+6#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Floor,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Ceiling,
+        "-1668.0",
+        "-0x684.0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Down,
+        "-1668.0",
+        "-0x684.0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Up,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        -10,
+        10,
+        Nearest,
+        "-1670.0",
+        "-0x686.0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Floor,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Ceiling,
+        "-7.0008e12",
+        "-0x6.5eE+10#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Down,
+        "-7.0008e12",
+        "-0x6.5eE+10#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Up,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        -10,
+        10,
+        Nearest,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -10,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -10,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -10,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", -10, 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        -10,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        -10,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        10,
+        Up,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        -10,
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+}
+
+#[test]
+fn bessel_yn_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).bessel_yn_prec_round(3, 0, Floor));
+    assert_panic!(Float::one_prec(1).bessel_yn_prec_round_ref(3, 0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.bessel_yn_prec_round_assign(3, 0, Floor)
+    });
+
+    assert_panic!(X.bessel_yn_prec_round(3, 1, Exact));
+    assert_panic!(X.bessel_yn_prec_round_ref(3, 1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.bessel_yn_prec_round_assign(3, 1, Exact)
+    });
+}
+
+#[test]
+fn test_bessel_yn_rational_prec() {
+    let test = |s, n: i64, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (yn, o) = Float::bessel_yn_rational_prec(x.clone(), n, prec);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+        assert_eq!(o, out_o);
+
+        let (yn, o) = Float::bessel_yn_rational_prec_ref(&x, n, prec);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_yn, rug_o) = rug_bessel_yn_rational_prec(&x, n, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_yn)),
+            ComparableFloatRef(&yn)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 3, 1, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 10, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 100, "-Infinity", "-Infinity", Equal);
+    test("1", 3, 1, "-4.0", "-0x4.0#1", Greater);
+    test("1", 3, 10, "-5.8203", "-0x5.d2#10", Greater);
+    test(
+        "1",
+        3,
+        100,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test("-1", 3, 1, "NaN", "NaN", Equal);
+    test("-1", 3, 10, "NaN", "NaN", Equal);
+    test("-1", 3, 100, "NaN", "NaN", Equal);
+    test("1/2", 3, 1, "-32.0", "-0x2.0E+1#1", Greater);
+    test("1/2", 3, 10, "-42.062", "-0x2a.1#10", Less);
+    test(
+        "1/2",
+        3,
+        100,
+        "-42.059494304723882687658935894499",
+        "-0x2a.0f3b04cd165b8cfe2dee0890#100",
+        Less,
+    );
+    test("-1/2", 3, 1, "NaN", "NaN", Equal);
+    test("-1/2", 3, 10, "NaN", "NaN", Equal);
+    test("-1/2", 3, 100, "NaN", "NaN", Equal);
+    test("1/3", 3, 1, "-1.3e2", "-0x8.0E+1#1", Greater);
+    test("1/3", 3, 10, "-139.50", "-0x8b.8#10", Less);
+    test(
+        "1/3",
+        3,
+        100,
+        "-139.44729349062175360642432792521",
+        "-0x8b.7281d381ef221fd34573646#100",
+        Greater,
+    );
+    test("22/7", 3, 1, "-0.50", "-0x0.8#1", Less);
+    test("22/7", 3, 10, "-0.48584", "-0x0.7c6#10", Less);
+    test(
+        "22/7",
+        3,
+        100,
+        "-0.48560990878054335132921840299445",
+        "-0x0.7c50ee54d37339735f66dde0d0#100",
+        Less,
+    );
+    test("3/2", 3, 1, "-2.0", "-0x2.0#1", Greater);
+    test("3/2", 3, 10, "-2.0742", "-0x2.13#10", Less);
+    test(
+        "3/2",
+        3,
+        100,
+        "-2.0735413990606857846485256882261",
+        "-0x2.12d39befde23971bd3b3653f4#100",
+        Greater,
+    );
+    test("-7/2", 3, 1, "NaN", "NaN", Equal);
+    test("-7/2", 3, 10, "NaN", "NaN", Equal);
+    test("-7/2", 3, 100, "NaN", "NaN", Equal);
+    test("1000000", 3, 1, "0.00024", "0x0.001#1", Less);
+    test("1000000", 3, 10, "0.00033092", "0x0.0015b0#10", Less);
+    test(
+        "1000000",
+        3,
+        100,
+        "0.00033104628059561728256638655842571",
+        "0x0.0015b208f2d8ccf870b9281e0f20#100",
+        Less,
+    );
+    test("1/3", 0, 10, "-0.73438", "-0x0.bc0#10", Less);
+    test(
+        "1/3",
+        0,
+        100,
+        "-0.73437307345447260776116547505685",
+        "-0x0.bbffdfad8cca8b95935d31802#100",
+        Less,
+    );
+    test("-1/2", 0, 10, "NaN", "NaN", Equal);
+    test("-1/2", 0, 100, "NaN", "NaN", Equal);
+    test("22/7", 0, 10, "0.32812", "0x0.540#10", Greater);
+    test(
+        "22/7",
+        0,
+        100,
+        "0.32791234650377896109656411355009",
+        "0x0.53f210443037be561408306268#100",
+        Less,
+    );
+    test("0", 0, 10, "-Infinity", "-Infinity", Equal);
+    test("0", 0, 100, "-Infinity", "-Infinity", Equal);
+    test("1/3", 1, 10, "-2.0898", "-0x2.17#10", Less);
+    test(
+        "1/3",
+        1,
+        100,
+        "-2.0881657798883862661909621637442",
+        "-0x2.169208553f3858f0fd0a2a3b0#100",
+        Greater,
+    );
+    test("-1/2", 1, 10, "NaN", "NaN", Equal);
+    test("-1/2", 1, 100, "NaN", "NaN", Equal);
+    test("22/7", 1, 10, "0.35938", "0x0.5c0#10", Greater);
+    test(
+        "22/7",
+        1,
+        100,
+        "0.35914337395059936965063275111205",
+        "0x0.5bf0d1f5b1636056433f619140#100",
+        Greater,
+    );
+    test("0", 1, 10, "-Infinity", "-Infinity", Equal);
+    test("0", 1, 100, "-Infinity", "-Infinity", Equal);
+    test("1/3", -1, 10, "2.0898", "0x2.17#10", Greater);
+    test(
+        "1/3",
+        -1,
+        100,
+        "2.0881657798883862661909621637442",
+        "0x2.169208553f3858f0fd0a2a3b0#100",
+        Less,
+    );
+    test("-1/2", -1, 10, "NaN", "NaN", Equal);
+    test("-1/2", -1, 100, "NaN", "NaN", Equal);
+    test("22/7", -1, 10, "-0.35938", "-0x0.5c0#10", Less);
+    test(
+        "22/7",
+        -1,
+        100,
+        "-0.35914337395059936965063275111205",
+        "-0x0.5bf0d1f5b1636056433f619140#100",
+        Less,
+    );
+    test("0", -1, 10, "Infinity", "Infinity", Equal);
+    test("0", -1, 100, "Infinity", "Infinity", Equal);
+    test("1/3", 2, 10, "-11.797", "-0xb.cc#10", Less);
+    test(
+        "1/3",
+        2,
+        100,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Greater,
+    );
+    test("-1/2", 2, 10, "NaN", "NaN", Equal);
+    test("-1/2", 2, 100, "NaN", "NaN", Equal);
+    test("22/7", 2, 10, "-0.099365", "-0x0.1970#10", Greater);
+    test(
+        "22/7",
+        2,
+        100,
+        "-0.099366563080670271318888726479041",
+        "-0x0.1970164abf55984dbab14f6316#100",
+        Less,
+    );
+    test("0", 2, 10, "-Infinity", "-Infinity", Equal);
+    test("0", 2, 100, "-Infinity", "-Infinity", Equal);
+    test("1/3", -2, 10, "-11.797", "-0xb.cc#10", Less);
+    test(
+        "1/3",
+        -2,
+        100,
+        "-11.794621605875844989384607507410",
+        "-0xb.cb6c5251ee878a105adfcbe2#100",
+        Greater,
+    );
+    test("-1/2", -2, 10, "NaN", "NaN", Equal);
+    test("-1/2", -2, 100, "NaN", "NaN", Equal);
+    test("22/7", -2, 10, "-0.099365", "-0x0.1970#10", Greater);
+    test(
+        "22/7",
+        -2,
+        100,
+        "-0.099366563080670271318888726479041",
+        "-0x0.1970164abf55984dbab14f6316#100",
+        Less,
+    );
+    test("0", -2, 10, "-Infinity", "-Infinity", Equal);
+    test("0", -2, 100, "-Infinity", "-Infinity", Equal);
+    test("1/3", -3, 10, "139.50", "0x8b.8#10", Greater);
+    test(
+        "1/3",
+        -3,
+        100,
+        "139.44729349062175360642432792521",
+        "0x8b.7281d381ef221fd34573646#100",
+        Less,
+    );
+    test("-1/2", -3, 10, "NaN", "NaN", Equal);
+    test("-1/2", -3, 100, "NaN", "NaN", Equal);
+    test("22/7", -3, 10, "0.48584", "0x0.7c6#10", Greater);
+    test(
+        "22/7",
+        -3,
+        100,
+        "0.48560990878054335132921840299445",
+        "0x0.7c50ee54d37339735f66dde0d0#100",
+        Greater,
+    );
+    test("0", -3, 10, "Infinity", "Infinity", Equal);
+    test("0", -3, 100, "Infinity", "Infinity", Equal);
+    test("1/3", 10, 10, "-7.0094e12", "-0x6.60E+10#10", Less);
+    test(
+        "1/3",
+        10,
+        100,
+        "-7005938776839.9345413559482075860",
+        "-0x65f327dfb07.ef3e1a308e9a9c0#100",
+        Greater,
+    );
+    test("-1/2", 10, 10, "NaN", "NaN", Equal);
+    test("-1/2", 10, 100, "NaN", "NaN", Equal);
+    test("22/7", 10, 10, "-1664.0", "-0x680.0#10", Less);
+    test(
+        "22/7",
+        10,
+        100,
+        "-1663.3637804205158906565960268248",
+        "-0x67f.5d20b6b10a76f7fd2003030#100",
+        Less,
+    );
+    test("0", 10, 10, "-Infinity", "-Infinity", Equal);
+    test("0", 10, 100, "-Infinity", "-Infinity", Equal);
+    test("1/3", -10, 10, "-7.0094e12", "-0x6.60E+10#10", Less);
+    test(
+        "1/3",
+        -10,
+        100,
+        "-7005938776839.9345413559482075860",
+        "-0x65f327dfb07.ef3e1a308e9a9c0#100",
+        Greater,
+    );
+    test("-1/2", -10, 10, "NaN", "NaN", Equal);
+    test("-1/2", -10, 100, "NaN", "NaN", Equal);
+    test("22/7", -10, 10, "-1664.0", "-0x680.0#10", Less);
+    test(
+        "22/7",
+        -10,
+        100,
+        "-1663.3637804205158906565960268248",
+        "-0x67f.5d20b6b10a76f7fd2003030#100",
+        Less,
+    );
+    test("0", -10, 10, "-Infinity", "-Infinity", Equal);
+    test("0", -10, 100, "-Infinity", "-Infinity", Equal);
+}
+
+#[test]
+#[should_panic]
+fn bessel_yn_rational_prec_fail() {
+    Float::bessel_yn_rational_prec(Rational::ONE, 3, 0);
+}
+
+#[test]
+#[should_panic]
+fn bessel_yn_rational_prec_ref_fail() {
+    Float::bessel_yn_rational_prec_ref(&Rational::ONE, 3, 0);
+}
+
+#[test]
+fn test_bessel_yn_rational_prec_round() {
+    let test = |s, n: i64, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (yn, o) = Float::bessel_yn_rational_prec_round(x.clone(), n, prec, rm);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+        assert_eq!(o, out_o);
+
+        let (yn, o) = Float::bessel_yn_rational_prec_round_ref(&x, n, prec, rm);
+        assert!(yn.is_valid());
+        assert_eq!(yn.to_string(), out);
+        assert_eq!(to_hex_string(&yn), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_yn, rug_o) = rug_bessel_yn_rational_prec_round(&x, n, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_yn)),
+                ComparableFloatRef(&yn)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 3, 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 3, 100, Nearest, "-Infinity", "-Infinity", Equal);
+    test("1", 3, 1, Floor, "-8.0", "-0x8.0#1", Less);
+    test("1", 3, 1, Ceiling, "-4.0", "-0x4.0#1", Greater);
+    test("1", 3, 1, Down, "-4.0", "-0x4.0#1", Greater);
+    test("1", 3, 1, Up, "-8.0", "-0x8.0#1", Less);
+    test("1", 3, 1, Nearest, "-4.0", "-0x4.0#1", Greater);
+    test("1", 3, 10, Floor, "-5.8281", "-0x5.d4#10", Less);
+    test("1", 3, 10, Ceiling, "-5.8203", "-0x5.d2#10", Greater);
+    test("1", 3, 10, Down, "-5.8203", "-0x5.d2#10", Greater);
+    test("1", 3, 10, Up, "-5.8281", "-0x5.d4#10", Less);
+    test("1", 3, 10, Nearest, "-5.8203", "-0x5.d2#10", Greater);
+    test(
+        "1",
+        3,
+        100,
+        Nearest,
+        "-5.8215176059647288477617570644280",
+        "-0x5.d24efa52b4ebecd062d341fb0#100",
+        Greater,
+    );
+    test("-1", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-1", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-1", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-1", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-1", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-1", 3, 10, Up, "NaN", "NaN", Equal);
+    test("-1", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("-1", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 3, 1, Floor, "-64.0", "-0x4.0E+1#1", Less);
+    test("1/2", 3, 1, Ceiling, "-32.0", "-0x2.0E+1#1", Greater);
+    test("1/2", 3, 1, Down, "-32.0", "-0x2.0E+1#1", Greater);
+    test("1/2", 3, 1, Up, "-64.0", "-0x4.0E+1#1", Less);
+    test("1/2", 3, 1, Nearest, "-32.0", "-0x2.0E+1#1", Greater);
+    test("1/2", 3, 10, Floor, "-42.062", "-0x2a.1#10", Less);
+    test("1/2", 3, 10, Ceiling, "-42.000", "-0x2a.0#10", Greater);
+    test("1/2", 3, 10, Down, "-42.000", "-0x2a.0#10", Greater);
+    test("1/2", 3, 10, Up, "-42.062", "-0x2a.1#10", Less);
+    test("1/2", 3, 10, Nearest, "-42.062", "-0x2a.1#10", Less);
+    test(
+        "1/2",
+        3,
+        100,
+        Nearest,
+        "-42.059494304723882687658935894499",
+        "-0x2a.0f3b04cd165b8cfe2dee0890#100",
+        Less,
+    );
+    test("-1/2", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-1/2", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-1/2", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-1/2", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", 3, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("-1/2", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test("1/3", 3, 1, Floor, "-2.6e2", "-0x1.0E+2#1", Less);
+    test("1/3", 3, 1, Ceiling, "-1.3e2", "-0x8.0E+1#1", Greater);
+    test("1/3", 3, 1, Down, "-1.3e2", "-0x8.0E+1#1", Greater);
+    test("1/3", 3, 1, Up, "-2.6e2", "-0x1.0E+2#1", Less);
+    test("1/3", 3, 1, Nearest, "-1.3e2", "-0x8.0E+1#1", Greater);
+    test("1/3", 3, 10, Floor, "-139.50", "-0x8b.8#10", Less);
+    test("1/3", 3, 10, Ceiling, "-139.25", "-0x8b.4#10", Greater);
+    test("1/3", 3, 10, Down, "-139.25", "-0x8b.4#10", Greater);
+    test("1/3", 3, 10, Up, "-139.50", "-0x8b.8#10", Less);
+    test("1/3", 3, 10, Nearest, "-139.50", "-0x8b.8#10", Less);
+    test(
+        "1/3",
+        3,
+        100,
+        Nearest,
+        "-139.44729349062175360642432792521",
+        "-0x8b.7281d381ef221fd34573646#100",
+        Greater,
+    );
+    test("22/7", 3, 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("22/7", 3, 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("22/7", 3, 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("22/7", 3, 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("22/7", 3, 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test("22/7", 3, 10, Floor, "-0.48584", "-0x0.7c6#10", Less);
+    test("22/7", 3, 10, Ceiling, "-0.48535", "-0x0.7c4#10", Greater);
+    test("22/7", 3, 10, Down, "-0.48535", "-0x0.7c4#10", Greater);
+    test("22/7", 3, 10, Up, "-0.48584", "-0x0.7c6#10", Less);
+    test("22/7", 3, 10, Nearest, "-0.48584", "-0x0.7c6#10", Less);
+    test(
+        "22/7",
+        3,
+        100,
+        Nearest,
+        "-0.48560990878054335132921840299445",
+        "-0x0.7c50ee54d37339735f66dde0d0#100",
+        Less,
+    );
+    test("3/2", 3, 1, Floor, "-4.0", "-0x4.0#1", Less);
+    test("3/2", 3, 1, Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("3/2", 3, 1, Down, "-2.0", "-0x2.0#1", Greater);
+    test("3/2", 3, 1, Up, "-4.0", "-0x4.0#1", Less);
+    test("3/2", 3, 1, Nearest, "-2.0", "-0x2.0#1", Greater);
+    test("3/2", 3, 10, Floor, "-2.0742", "-0x2.13#10", Less);
+    test("3/2", 3, 10, Ceiling, "-2.0703", "-0x2.12#10", Greater);
+    test("3/2", 3, 10, Down, "-2.0703", "-0x2.12#10", Greater);
+    test("3/2", 3, 10, Up, "-2.0742", "-0x2.13#10", Less);
+    test("3/2", 3, 10, Nearest, "-2.0742", "-0x2.13#10", Less);
+    test(
+        "3/2",
+        3,
+        100,
+        Nearest,
+        "-2.0735413990606857846485256882261",
+        "-0x2.12d39befde23971bd3b3653f4#100",
+        Greater,
+    );
+    test("-7/2", 3, 1, Floor, "NaN", "NaN", Equal);
+    test("-7/2", 3, 1, Ceiling, "NaN", "NaN", Equal);
+    test("-7/2", 3, 1, Down, "NaN", "NaN", Equal);
+    test("-7/2", 3, 1, Up, "NaN", "NaN", Equal);
+    test("-7/2", 3, 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", 3, 1, Exact, "NaN", "NaN", Equal);
+    test("-7/2", 3, 10, Floor, "NaN", "NaN", Equal);
+    test("-7/2", 3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-7/2", 3, 10, Down, "NaN", "NaN", Equal);
+    test("-7/2", 3, 10, Up, "NaN", "NaN", Equal);
+    test("-7/2", 3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", 3, 10, Exact, "NaN", "NaN", Equal);
+    test("-7/2", 3, 100, Nearest, "NaN", "NaN", Equal);
+    test("1000000", 3, 1, Floor, "0.00024", "0x0.001#1", Less);
+    test("1000000", 3, 1, Ceiling, "0.00049", "0x0.002#1", Greater);
+    test("1000000", 3, 1, Down, "0.00024", "0x0.001#1", Less);
+    test("1000000", 3, 1, Up, "0.00049", "0x0.002#1", Greater);
+    test("1000000", 3, 1, Nearest, "0.00024", "0x0.001#1", Less);
+    test("1000000", 3, 10, Floor, "0.00033092", "0x0.0015b0#10", Less);
+    test(
+        "1000000",
+        3,
+        10,
+        Ceiling,
+        "0.00033140",
+        "0x0.0015b8#10",
+        Greater,
+    );
+    test("1000000", 3, 10, Down, "0.00033092", "0x0.0015b0#10", Less);
+    test("1000000", 3, 10, Up, "0.00033140", "0x0.0015b8#10", Greater);
+    test(
+        "1000000",
+        3,
+        10,
+        Nearest,
+        "0.00033092",
+        "0x0.0015b0#10",
+        Less,
+    );
+    test(
+        "1000000",
+        3,
+        100,
+        Nearest,
+        "0.00033104628059561728256638655842571",
+        "0x0.0015b208f2d8ccf870b9281e0f20#100",
+        Less,
+    );
+    test("1/3", 0, 10, Floor, "-0.73438", "-0x0.bc0#10", Less);
+    test("1/3", 0, 10, Ceiling, "-0.73340", "-0x0.bbc#10", Greater);
+    test("1/3", 0, 10, Down, "-0.73340", "-0x0.bbc#10", Greater);
+    test("1/3", 0, 10, Up, "-0.73438", "-0x0.bc0#10", Less);
+    test("1/3", 0, 10, Nearest, "-0.73438", "-0x0.bc0#10", Less);
+    test("-1/2", 0, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 0, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 0, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", 0, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", 0, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 0, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", 0, 10, Floor, "0.32764", "0x0.53e#10", Less);
+    test("22/7", 0, 10, Ceiling, "0.32812", "0x0.540#10", Greater);
+    test("22/7", 0, 10, Down, "0.32764", "0x0.53e#10", Less);
+    test("22/7", 0, 10, Up, "0.32812", "0x0.540#10", Greater);
+    test("22/7", 0, 10, Nearest, "0.32812", "0x0.540#10", Greater);
+    test("0", 0, 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 0, 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 0, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 0, 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 0, 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 0, 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("1/3", 1, 10, Floor, "-2.0898", "-0x2.17#10", Less);
+    test("1/3", 1, 10, Ceiling, "-2.0859", "-0x2.16#10", Greater);
+    test("1/3", 1, 10, Down, "-2.0859", "-0x2.16#10", Greater);
+    test("1/3", 1, 10, Up, "-2.0898", "-0x2.17#10", Less);
+    test("1/3", 1, 10, Nearest, "-2.0898", "-0x2.17#10", Less);
+    test("-1/2", 1, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 1, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 1, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", 1, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", 1, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 1, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", 1, 10, Floor, "0.35889", "0x0.5be#10", Less);
+    test("22/7", 1, 10, Ceiling, "0.35938", "0x0.5c0#10", Greater);
+    test("22/7", 1, 10, Down, "0.35889", "0x0.5be#10", Less);
+    test("22/7", 1, 10, Up, "0.35938", "0x0.5c0#10", Greater);
+    test("22/7", 1, 10, Nearest, "0.35938", "0x0.5c0#10", Greater);
+    test("0", 1, 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 1, 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 1, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 1, 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 1, 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 1, 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("1/3", -1, 10, Floor, "2.0859", "0x2.16#10", Less);
+    test("1/3", -1, 10, Ceiling, "2.0898", "0x2.17#10", Greater);
+    test("1/3", -1, 10, Down, "2.0859", "0x2.16#10", Less);
+    test("1/3", -1, 10, Up, "2.0898", "0x2.17#10", Greater);
+    test("1/3", -1, 10, Nearest, "2.0898", "0x2.17#10", Greater);
+    test("-1/2", -1, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", -1, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", -1, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", -1, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", -1, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", -1, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", -1, 10, Floor, "-0.35938", "-0x0.5c0#10", Less);
+    test("22/7", -1, 10, Ceiling, "-0.35889", "-0x0.5be#10", Greater);
+    test("22/7", -1, 10, Down, "-0.35889", "-0x0.5be#10", Greater);
+    test("22/7", -1, 10, Up, "-0.35938", "-0x0.5c0#10", Less);
+    test("22/7", -1, 10, Nearest, "-0.35938", "-0x0.5c0#10", Less);
+    test("0", -1, 10, Floor, "Infinity", "Infinity", Equal);
+    test("0", -1, 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", -1, 10, Down, "Infinity", "Infinity", Equal);
+    test("0", -1, 10, Up, "Infinity", "Infinity", Equal);
+    test("0", -1, 10, Nearest, "Infinity", "Infinity", Equal);
+    test("0", -1, 10, Exact, "Infinity", "Infinity", Equal);
+    test("1/3", 2, 10, Floor, "-11.797", "-0xb.cc#10", Less);
+    test("1/3", 2, 10, Ceiling, "-11.781", "-0xb.c8#10", Greater);
+    test("1/3", 2, 10, Down, "-11.781", "-0xb.c8#10", Greater);
+    test("1/3", 2, 10, Up, "-11.797", "-0xb.cc#10", Less);
+    test("1/3", 2, 10, Nearest, "-11.797", "-0xb.cc#10", Less);
+    test("-1/2", 2, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 2, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 2, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", 2, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", 2, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 2, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", 2, 10, Floor, "-0.099487", "-0x0.1978#10", Less);
+    test("22/7", 2, 10, Ceiling, "-0.099365", "-0x0.1970#10", Greater);
+    test("22/7", 2, 10, Down, "-0.099365", "-0x0.1970#10", Greater);
+    test("22/7", 2, 10, Up, "-0.099487", "-0x0.1978#10", Less);
+    test("22/7", 2, 10, Nearest, "-0.099365", "-0x0.1970#10", Greater);
+    test("0", 2, 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 2, 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 2, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 2, 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 2, 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 2, 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("1/3", -2, 10, Floor, "-11.797", "-0xb.cc#10", Less);
+    test("1/3", -2, 10, Ceiling, "-11.781", "-0xb.c8#10", Greater);
+    test("1/3", -2, 10, Down, "-11.781", "-0xb.c8#10", Greater);
+    test("1/3", -2, 10, Up, "-11.797", "-0xb.cc#10", Less);
+    test("1/3", -2, 10, Nearest, "-11.797", "-0xb.cc#10", Less);
+    test("-1/2", -2, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", -2, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", -2, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", -2, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", -2, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", -2, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", -2, 10, Floor, "-0.099487", "-0x0.1978#10", Less);
+    test(
+        "22/7",
+        -2,
+        10,
+        Ceiling,
+        "-0.099365",
+        "-0x0.1970#10",
+        Greater,
+    );
+    test("22/7", -2, 10, Down, "-0.099365", "-0x0.1970#10", Greater);
+    test("22/7", -2, 10, Up, "-0.099487", "-0x0.1978#10", Less);
+    test(
+        "22/7",
+        -2,
+        10,
+        Nearest,
+        "-0.099365",
+        "-0x0.1970#10",
+        Greater,
+    );
+    test("0", -2, 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", -2, 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", -2, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", -2, 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", -2, 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", -2, 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("1/3", -3, 10, Floor, "139.25", "0x8b.4#10", Less);
+    test("1/3", -3, 10, Ceiling, "139.50", "0x8b.8#10", Greater);
+    test("1/3", -3, 10, Down, "139.25", "0x8b.4#10", Less);
+    test("1/3", -3, 10, Up, "139.50", "0x8b.8#10", Greater);
+    test("1/3", -3, 10, Nearest, "139.50", "0x8b.8#10", Greater);
+    test("-1/2", -3, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", -3, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", -3, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", -3, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", -3, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", -3, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", -3, 10, Floor, "0.48535", "0x0.7c4#10", Less);
+    test("22/7", -3, 10, Ceiling, "0.48584", "0x0.7c6#10", Greater);
+    test("22/7", -3, 10, Down, "0.48535", "0x0.7c4#10", Less);
+    test("22/7", -3, 10, Up, "0.48584", "0x0.7c6#10", Greater);
+    test("22/7", -3, 10, Nearest, "0.48584", "0x0.7c6#10", Greater);
+    test("0", -3, 10, Floor, "Infinity", "Infinity", Equal);
+    test("0", -3, 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", -3, 10, Down, "Infinity", "Infinity", Equal);
+    test("0", -3, 10, Up, "Infinity", "Infinity", Equal);
+    test("0", -3, 10, Nearest, "Infinity", "Infinity", Equal);
+    test("0", -3, 10, Exact, "Infinity", "Infinity", Equal);
+    test("1/3", 10, 10, Floor, "-7.0094e12", "-0x6.60E+10#10", Less);
+    test(
+        "1/3",
+        10,
+        10,
+        Ceiling,
+        "-7.0008e12",
+        "-0x6.5eE+10#10",
+        Greater,
+    );
+    test("1/3", 10, 10, Down, "-7.0008e12", "-0x6.5eE+10#10", Greater);
+    test("1/3", 10, 10, Up, "-7.0094e12", "-0x6.60E+10#10", Less);
+    test("1/3", 10, 10, Nearest, "-7.0094e12", "-0x6.60E+10#10", Less);
+    test("-1/2", 10, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", 10, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", 10, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", 10, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", 10, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", 10, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", 10, 10, Floor, "-1664.0", "-0x680.0#10", Less);
+    test("22/7", 10, 10, Ceiling, "-1662.0", "-0x67e.0#10", Greater);
+    test("22/7", 10, 10, Down, "-1662.0", "-0x67e.0#10", Greater);
+    test("22/7", 10, 10, Up, "-1664.0", "-0x680.0#10", Less);
+    test("22/7", 10, 10, Nearest, "-1664.0", "-0x680.0#10", Less);
+    test("0", 10, 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 10, 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 10, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 10, 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 10, 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 10, 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("1/3", -10, 10, Floor, "-7.0094e12", "-0x6.60E+10#10", Less);
+    test(
+        "1/3",
+        -10,
+        10,
+        Ceiling,
+        "-7.0008e12",
+        "-0x6.5eE+10#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        -10,
+        10,
+        Down,
+        "-7.0008e12",
+        "-0x6.5eE+10#10",
+        Greater,
+    );
+    test("1/3", -10, 10, Up, "-7.0094e12", "-0x6.60E+10#10", Less);
+    test(
+        "1/3",
+        -10,
+        10,
+        Nearest,
+        "-7.0094e12",
+        "-0x6.60E+10#10",
+        Less,
+    );
+    test("-1/2", -10, 10, Floor, "NaN", "NaN", Equal);
+    test("-1/2", -10, 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1/2", -10, 10, Down, "NaN", "NaN", Equal);
+    test("-1/2", -10, 10, Up, "NaN", "NaN", Equal);
+    test("-1/2", -10, 10, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", -10, 10, Exact, "NaN", "NaN", Equal);
+    test("22/7", -10, 10, Floor, "-1664.0", "-0x680.0#10", Less);
+    test("22/7", -10, 10, Ceiling, "-1662.0", "-0x67e.0#10", Greater);
+    test("22/7", -10, 10, Down, "-1662.0", "-0x67e.0#10", Greater);
+    test("22/7", -10, 10, Up, "-1664.0", "-0x680.0#10", Less);
+    test("22/7", -10, 10, Nearest, "-1664.0", "-0x680.0#10", Less);
+    test("0", -10, 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", -10, 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", -10, 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", -10, 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", -10, 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", -10, 10, Exact, "-Infinity", "-Infinity", Equal);
+}
+
+#[test]
+#[should_panic]
+fn bessel_yn_rational_prec_round_fail_1() {
+    Float::bessel_yn_rational_prec_round(Rational::ONE, 3, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn bessel_yn_rational_prec_round_fail_2() {
+    Float::bessel_yn_rational_prec_round(Rational::ONE_HALF, 3, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn bessel_yn_rational_prec_round_ref_fail() {
+    Float::bessel_yn_rational_prec_round_ref(&Rational::ONE_HALF, 3, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_yn_prec_round_properties_helper(x: Float, n: i64, prec: u64, rm: RoundingMode) {
+    let (yn, o) = x.clone().bessel_yn_prec_round(n, prec, rm);
+    assert!(yn.is_valid());
+
+    let (yn_alt, o_alt) = x.bessel_yn_prec_round_ref(n, prec, rm);
+    assert!(yn_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_yn_prec_round_assign(n, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&yn));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let (yn_alt, o_alt) = x.clone().bessel_yn_prec(n, prec);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+
+        let (yn_alt, o_alt) = x.bessel_yn_prec_ref(n, prec);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.bessel_yn_prec_assign(n, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_yn, rug_o) =
+            rug_bessel_yn_prec_round(&rug::Float::exact_from(&x), n, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_yn)),
+            ComparableFloatRef(&yn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(yn.is_nan(), x.is_nan() || x < 0u32);
+
+    if yn.is_normal() {
+        assert_eq!(yn.get_prec(), Some(prec));
+    }
+
+    if x.is_finite() {
+        let (yn_alt, o_alt) = x.bessel_yn_prec_round_ref(-n, prec, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-yn_alt), ComparableFloat(yn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(yn_alt), ComparableFloat(yn.clone()));
+            assert_eq!(o_alt, o);
+        }
+    }
+
+    if n == 0 {
+        let (yn_alt, o_alt) = x.bessel_y0_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+    if n == 1 {
+        let (yn_alt, o_alt) = x.bessel_y1_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+
+    if x.is_normal() {
+        let (yn_alt, o_alt) =
+            Float::bessel_yn_rational_prec_round(Rational::exact_from(&x), n, prec, rm);
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_yn_prec_round_ref(n, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(yn.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_yn_prec_round_ref(n, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_yn_prec_round_properties() {
+    float_signed_unsigned_rounding_mode_quadruple_gen_var_17().test_properties_with_limit(
+        1000,
+        |(x, n, prec, rm)| {
+            bessel_yn_prec_round_properties_helper(x, n, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        for n in -3..=3 {
+            let (yn, o) = Float::NAN.bessel_yn_prec_round(n, prec, rm);
+            assert!(yn.is_nan());
+            assert_eq!(o, Equal);
+
+            let (yn, o) = Float::INFINITY.bessel_yn_prec_round(n, prec, rm);
+            assert_eq!(ComparableFloat(yn), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Equal);
+
+            let (yn, o) = Float::NEGATIVE_INFINITY.bessel_yn_prec_round(n, prec, rm);
+            assert!(yn.is_nan());
+            assert_eq!(o, Equal);
+
+            let expected = if n < 0 && n.odd() {
+                Float::INFINITY
+            } else {
+                Float::NEGATIVE_INFINITY
+            };
+            let (yn, o) = Float::ZERO.bessel_yn_prec_round(n, prec, rm);
+            assert_eq!(ComparableFloat(yn), ComparableFloat(expected.clone()));
+            assert_eq!(o, Equal);
+
+            let (yn, o) = Float::NEGATIVE_ZERO.bessel_yn_prec_round(n, prec, rm);
+            assert_eq!(ComparableFloat(yn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_yn_round_properties_helper(x: Float, n: i64, rm: RoundingMode) {
+    let (yn, o) = x.clone().bessel_yn_round(n, rm);
+    assert!(yn.is_valid());
+
+    let (yn_alt, o_alt) = x.bessel_yn_round_ref(n, rm);
+    assert!(yn_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.bessel_yn_round_assign(n, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&yn));
+    assert_eq!(o_alt, o);
+
+    let (yn_alt, o_alt) = x.bessel_yn_prec_round_ref(n, x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let yn_alt = x.clone().bessel_yn(n);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+
+        let yn_alt = (&x).bessel_yn(n);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+
+        let mut x_alt = x.clone();
+        x_alt.bessel_yn_assign(n);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&yn));
+
+        let rug_yn = rug_bessel_yn(&rug::Float::exact_from(&x), n);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_yn)),
+            ComparableFloatRef(&yn),
+        );
+    }
+
+    assert_eq!(yn.is_nan(), x.is_nan() || x < 0u32);
+
+    if x.is_finite() {
+        let (yn_alt, o_alt) = x.bessel_yn_round_ref(-n, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-yn_alt), ComparableFloat(yn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(yn_alt), ComparableFloat(yn.clone()));
+            assert_eq!(o_alt, o);
+        }
+    }
+
+    if n == 0 {
+        let (yn_alt, o_alt) = x.bessel_y0_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+    if n == 1 {
+        let (yn_alt, o_alt) = x.bessel_y1_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_yn, rug_o) = rug_bessel_yn_round(&rug::Float::exact_from(&x), n, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_yn)),
+            ComparableFloatRef(&yn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.bessel_yn_round_ref(n, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(yn.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.bessel_yn_round_ref(n, Exact));
+    }
+}
+
+#[test]
+fn bessel_yn_round_properties() {
+    float_signed_rounding_mode_triple_gen_var_11().test_properties_with_limit(
+        1000,
+        |(x, n, rm)| {
+            bessel_yn_round_properties_helper(x, n, rm);
+        },
+    );
+
+    rounding_mode_gen().test_properties(|rm| {
+        for n in -3..=3 {
+            let (yn, o) = Float::NAN.bessel_yn_round(n, rm);
+            assert!(yn.is_nan());
+            assert_eq!(o, Equal);
+
+            let (yn, o) = Float::INFINITY.bessel_yn_round(n, rm);
+            assert_eq!(ComparableFloat(yn), ComparableFloat(Float::ZERO));
+            assert_eq!(o, Equal);
+
+            let (yn, o) = Float::NEGATIVE_INFINITY.bessel_yn_round(n, rm);
+            assert!(yn.is_nan());
+            assert_eq!(o, Equal);
+
+            let expected = if n < 0 && n.odd() {
+                Float::INFINITY
+            } else {
+                Float::NEGATIVE_INFINITY
+            };
+            let (yn, o) = Float::ZERO.bessel_yn_round(n, rm);
+            assert_eq!(ComparableFloat(yn), ComparableFloat(expected.clone()));
+            assert_eq!(o, Equal);
+
+            let (yn, o) = Float::NEGATIVE_ZERO.bessel_yn_round(n, rm);
+            assert_eq!(ComparableFloat(yn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn bessel_yn_rational_prec_round_properties_helper(
+    x: Rational,
+    n: i64,
+    prec: u64,
+    rm: RoundingMode,
+) {
+    let (yn, o) = Float::bessel_yn_rational_prec_round(x.clone(), n, prec, rm);
+    assert!(yn.is_valid());
+
+    let (yn_alt, o_alt) = Float::bessel_yn_rational_prec_round_ref(&x, n, prec, rm);
+    assert!(yn_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let (yn_alt, o_alt) = Float::bessel_yn_rational_prec(x.clone(), n, prec);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+
+        let (yn_alt, o_alt) = Float::bessel_yn_rational_prec_ref(&x, n, prec);
+        assert!(yn_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+
+        let (rug_yn, rug_o) = rug_bessel_yn_rational_prec(&x, n, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_yn)),
+            ComparableFloatRef(&yn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_yn, rug_o) = rug_bessel_yn_rational_prec_round(&x, n, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_yn)),
+            ComparableFloatRef(&yn)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(yn.is_nan(), x < 0u32);
+
+    if yn.is_normal() {
+        assert_eq!(yn.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (yn_alt, o_alt) =
+            Float::bessel_yn_rational_prec_round_ref(&x, -n, prec, if n.odd() { -rm } else { rm });
+        if n.odd() {
+            assert_eq!(ComparableFloat(-yn_alt), ComparableFloat(yn.clone()));
+            assert_eq!(o_alt.reverse(), o);
+        } else {
+            assert_eq!(ComparableFloat(yn_alt), ComparableFloat(yn.clone()));
+            assert_eq!(o_alt, o);
+        }
+    }
+
+    if n == 0 {
+        let (yn_alt, o_alt) = Float::bessel_y0_rational_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+    if n == 1 {
+        let (yn_alt, o_alt) = Float::bessel_y1_rational_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&yn_alt), ComparableFloatRef(&yn));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::bessel_yn_rational_prec_round_ref(&x, n, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(yn.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::bessel_yn_rational_prec_round_ref(&x, n, prec, Exact));
+    }
+}
+
+#[test]
+fn bessel_yn_rational_prec_round_properties() {
+    rational_signed_unsigned_rounding_mode_quadruple_gen_var_3().test_properties_with_limit(
+        1000,
+        |(x, n, prec, rm)| {
+            bessel_yn_rational_prec_round_properties_helper(x, n, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        for n in -3..=3 {
+            let expected = if n < 0 && n.odd() {
+                Float::INFINITY
+            } else {
+                Float::NEGATIVE_INFINITY
+            };
+            let (yn, o) = Float::bessel_yn_rational_prec_round(Rational::ZERO, n, prec, rm);
+            assert_eq!(ComparableFloat(yn), ComparableFloat(expected));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_yn() {
+    fn test<T: PrimitiveFloat>(x: T, n: i64, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_bessel_yn(x, n)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, 3, f32::NAN);
+    test::<f32>(f32::INFINITY, 3, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, 3, f32::NAN);
+    test::<f32>(0.0, 3, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, 3, f32::NEGATIVE_INFINITY);
+    test::<f32>(1.0, 3, -5.8215175);
+    test::<f32>(-1.0, 3, f32::NAN);
+    test::<f32>(0.5, 3, -42.059494);
+    test::<f32>(-0.5, 3, f32::NAN);
+    test::<f32>(2.0, 3, -1.1277838);
+    test::<f32>(5.0, 3, 0.14626716);
+    test::<f32>(-2.5, 3, f32::NAN);
+    test::<f32>(10.0, 3, -0.25136265);
+    test::<f32>(0.001, 3, -5092958000.0);
+    test::<f32>(10000000000.0, 3, 2.1755918e-6);
+    test::<f32>(1.0, 0, 0.08825696);
+    test::<f32>(-2.5, 0, f32::NAN);
+    test::<f32>(0.0, 0, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, 0, f32::NEGATIVE_INFINITY);
+    test::<f32>(0.001, 0, -4.4714165);
+    test::<f32>(1.0, 1, -0.7812128);
+    test::<f32>(-2.5, 1, f32::NAN);
+    test::<f32>(0.0, 1, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, 1, f32::NEGATIVE_INFINITY);
+    test::<f32>(0.001, 1, -636.62213);
+    test::<f32>(1.0, -1, 0.7812128);
+    test::<f32>(-2.5, -1, f32::NAN);
+    test::<f32>(0.0, -1, f32::INFINITY);
+    test::<f32>(-0.0, -1, f32::INFINITY);
+    test::<f32>(0.001, -1, 636.62213);
+    test::<f32>(1.0, 2, -1.6506826);
+    test::<f32>(-2.5, 2, f32::NAN);
+    test::<f32>(0.0, 2, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, 2, f32::NEGATIVE_INFINITY);
+    test::<f32>(0.001, 2, -1273239.8);
+    test::<f32>(1.0, -2, -1.6506826);
+    test::<f32>(-2.5, -2, f32::NAN);
+    test::<f32>(0.0, -2, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, -2, f32::NEGATIVE_INFINITY);
+    test::<f32>(0.001, -2, -1273239.8);
+    test::<f32>(1.0, -3, 5.8215175);
+    test::<f32>(-2.5, -3, f32::NAN);
+    test::<f32>(0.0, -3, f32::INFINITY);
+    test::<f32>(-0.0, -3, f32::INFINITY);
+    test::<f32>(0.001, -3, 5092958000.0);
+    test::<f32>(1.0, 10, -121618020.0);
+    test::<f32>(-2.5, 10, f32::NAN);
+    test::<f32>(0.0, 10, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, 10, f32::NEGATIVE_INFINITY);
+    test::<f32>(0.001, 10, -1.1828044e38);
+    test::<f32>(1.0, -10, -121618020.0);
+    test::<f32>(-2.5, -10, f32::NAN);
+    test::<f32>(0.0, -10, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, -10, f32::NEGATIVE_INFINITY);
+    test::<f32>(0.001, -10, -1.1828044e38);
+
+    test::<f64>(f64::NAN, 3, f64::NAN);
+    test::<f64>(f64::INFINITY, 3, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, 3, f64::NAN);
+    test::<f64>(0.0, 3, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, 3, f64::NEGATIVE_INFINITY);
+    test::<f64>(1.0, 3, -5.821517605964729);
+    test::<f64>(-1.0, 3, f64::NAN);
+    test::<f64>(0.5, 3, -42.059494304723884);
+    test::<f64>(-0.5, 3, f64::NAN);
+    test::<f64>(2.0, 3, -1.1277837768404277);
+    test::<f64>(5.0, 3, 0.14626716269319276);
+    test::<f64>(-2.5, 3, f64::NAN);
+    test::<f64>(10.0, 3, -0.2513626571838373);
+    test::<f64>(0.001, 3, -5092958815.560502);
+    test::<f64>(10000000000.0, 3, 2.1755917537013204e-6);
+    test::<f64>(1.0e300, 3, -7.860673062724093e-151);
+    test::<f64>(1.0, 0, 0.08825696421567696);
+    test::<f64>(-2.5, 0, f64::NAN);
+    test::<f64>(0.0, 0, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, 0, f64::NEGATIVE_INFINITY);
+    test::<f64>(0.001, 0, -4.471416611375923);
+    test::<f64>(1.0, 1, -0.7812128213002887);
+    test::<f64>(-2.5, 1, f64::NAN);
+    test::<f64>(0.0, 1, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, 1, f64::NEGATIVE_INFINITY);
+    test::<f64>(0.001, 1, -636.6221672311394);
+    test::<f64>(1.0, -1, 0.7812128213002887);
+    test::<f64>(-2.5, -1, f64::NAN);
+    test::<f64>(0.0, -1, f64::INFINITY);
+    test::<f64>(-0.0, -1, f64::INFINITY);
+    test::<f64>(0.001, -1, 636.6221672311394);
+    test::<f64>(1.0, 2, -1.6506826068162543);
+    test::<f64>(-2.5, 2, f64::NAN);
+    test::<f64>(0.0, 2, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, 2, f64::NEGATIVE_INFINITY);
+    test::<f64>(0.001, 2, -1273239.8630456675);
+    test::<f64>(1.0, -2, -1.6506826068162543);
+    test::<f64>(-2.5, -2, f64::NAN);
+    test::<f64>(0.0, -2, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, -2, f64::NEGATIVE_INFINITY);
+    test::<f64>(0.001, -2, -1273239.8630456675);
+    test::<f64>(1.0, -3, 5.821517605964729);
+    test::<f64>(-2.5, -3, f64::NAN);
+    test::<f64>(0.0, -3, f64::INFINITY);
+    test::<f64>(-0.0, -3, f64::INFINITY);
+    test::<f64>(0.001, -3, 5092958815.560502);
+    test::<f64>(1.0, 10, -121618014.27868919);
+    test::<f64>(-2.5, 10, f64::NAN);
+    test::<f64>(0.0, 10, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, 10, f64::NEGATIVE_INFINITY);
+    test::<f64>(0.001, 10, -1.1828049377990414e38);
+    test::<f64>(1.0, -10, -121618014.27868919);
+    test::<f64>(-2.5, -10, f64::NAN);
+    test::<f64>(0.0, -10, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, -10, f64::NEGATIVE_INFINITY);
+    test::<f64>(0.001, -10, -1.1828049377990414e38);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_yn_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_signed_pair_gen_var_4::<T, i64>().test_properties_with_limit(1000, |(x, n)| {
+        let yn = primitive_float_bessel_yn(x, n);
+        assert_eq!(yn.is_nan(), x.is_nan() || x < T::ZERO);
+        if x.is_finite() {
+            let yn_neg_n = primitive_float_bessel_yn(x, -n);
+            if n.odd() {
+                assert_eq!(NiceFloat(yn_neg_n), NiceFloat(-yn));
+            } else {
+                assert_eq!(NiceFloat(yn_neg_n), NiceFloat(yn));
+            }
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_yn_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_yn_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_bessel_yn_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, n: i64, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_bessel_yn_rational(&x, n)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", 3, f32::NEGATIVE_INFINITY);
+    test::<f32>("1", 3, -5.8215175);
+    test::<f32>("-1", 3, f32::NAN);
+    test::<f32>("1/2", 3, -42.059494);
+    test::<f32>("-1/2", 3, f32::NAN);
+    test::<f32>("1/3", 3, -139.4473);
+    test::<f32>("22/7", 3, -0.48560992);
+    test::<f32>("3/2", 3, -2.0735414);
+    test::<f32>("-7/2", 3, f32::NAN);
+    test::<f32>("1000000", 3, 0.0003310463);
+    test::<f32>("1/3", 0, -0.7343731);
+    test::<f32>("-7/2", 0, f32::NAN);
+    test::<f32>("0", 0, f32::NEGATIVE_INFINITY);
+    test::<f32>("1/3", 1, -2.0881658);
+    test::<f32>("-7/2", 1, f32::NAN);
+    test::<f32>("0", 1, f32::NEGATIVE_INFINITY);
+    test::<f32>("1/3", -1, 2.0881658);
+    test::<f32>("-7/2", -1, f32::NAN);
+    test::<f32>("0", -1, f32::INFINITY);
+    test::<f32>("1/3", 2, -11.794621);
+    test::<f32>("-7/2", 2, f32::NAN);
+    test::<f32>("0", 2, f32::NEGATIVE_INFINITY);
+    test::<f32>("1/3", -2, -11.794621);
+    test::<f32>("-7/2", -2, f32::NAN);
+    test::<f32>("0", -2, f32::NEGATIVE_INFINITY);
+    test::<f32>("1/3", -3, 139.4473);
+    test::<f32>("-7/2", -3, f32::NAN);
+    test::<f32>("0", -3, f32::INFINITY);
+    test::<f32>("1/3", 10, -7005939000000.0);
+    test::<f32>("-7/2", 10, f32::NAN);
+    test::<f32>("0", 10, f32::NEGATIVE_INFINITY);
+    test::<f32>("1/3", -10, -7005939000000.0);
+    test::<f32>("-7/2", -10, f32::NAN);
+    test::<f32>("0", -10, f32::NEGATIVE_INFINITY);
+
+    test::<f64>("0", 3, f64::NEGATIVE_INFINITY);
+    test::<f64>("1", 3, -5.821517605964729);
+    test::<f64>("-1", 3, f64::NAN);
+    test::<f64>("1/2", 3, -42.059494304723884);
+    test::<f64>("-1/2", 3, f64::NAN);
+    test::<f64>("1/3", 3, -139.44729349062175);
+    test::<f64>("22/7", 3, -0.48560990878054333);
+    test::<f64>("3/2", 3, -2.073541399060686);
+    test::<f64>("-7/2", 3, f64::NAN);
+    test::<f64>("1000000", 3, 0.00033104628059561726);
+    test::<f64>("1/3", 0, -0.7343730734544726);
+    test::<f64>("-7/2", 0, f64::NAN);
+    test::<f64>("0", 0, f64::NEGATIVE_INFINITY);
+    test::<f64>("1/3", 1, -2.0881657798883864);
+    test::<f64>("-7/2", 1, f64::NAN);
+    test::<f64>("0", 1, f64::NEGATIVE_INFINITY);
+    test::<f64>("1/3", -1, 2.0881657798883864);
+    test::<f64>("-7/2", -1, f64::NAN);
+    test::<f64>("0", -1, f64::INFINITY);
+    test::<f64>("1/3", 2, -11.794621605875845);
+    test::<f64>("-7/2", 2, f64::NAN);
+    test::<f64>("0", 2, f64::NEGATIVE_INFINITY);
+    test::<f64>("1/3", -2, -11.794621605875845);
+    test::<f64>("-7/2", -2, f64::NAN);
+    test::<f64>("0", -2, f64::NEGATIVE_INFINITY);
+    test::<f64>("1/3", -3, 139.44729349062175);
+    test::<f64>("-7/2", -3, f64::NAN);
+    test::<f64>("0", -3, f64::INFINITY);
+    test::<f64>("1/3", 10, -7005938776839.935);
+    test::<f64>("-7/2", 10, f64::NAN);
+    test::<f64>("0", 10, f64::NEGATIVE_INFINITY);
+    test::<f64>("1/3", -10, -7005938776839.935);
+    test::<f64>("-7/2", -10, f64::NAN);
+    test::<f64>("0", -10, f64::NEGATIVE_INFINITY);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_bessel_yn_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties_with_limit(1000, |x| {
+        for n in -3..=3 {
+            let yn = primitive_float_bessel_yn_rational::<T>(&x, n);
+            assert_eq!(yn.is_nan(), x < 0u32);
+            let yn_alt = primitive_float_bessel_yn_rational::<T>(&x, -n);
+            assert_eq!(NiceFloat(yn_alt), NiceFloat(if n.odd() { -yn } else { yn }));
+        }
+    });
+
+    primitive_float_signed_pair_gen_var_4::<T, i64>().test_properties_with_limit(1000, |(x, n)| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_bessel_yn_rational::<T>(
+                    &Rational::exact_from(x),
+                    n
+                )),
+                NiceFloat(primitive_float_bessel_yn(x, n))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_bessel_yn_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_bessel_yn_rational_properties_helper);
+}
@@ -0,0 +1,5746 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use gmp_mpfr_sys::mpfr::{self, rnd_t};
+use malachite_base::num::arithmetic::traits::{Beta, BetaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_pair_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::beta::{
+    primitive_float_beta, primitive_float_beta_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_float_significant_bits, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_46,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_27, float_float_unsigned_triple_gen_var_1,
+    float_pair_gen, rational_rational_unsigned_rounding_mode_quadruple_gen_var_4,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{
+    rational_pair_gen, rational_rational_unsigned_triple_gen_var_2,
+};
+use std::cmp::max;
+use std::panic::catch_unwind;
+
+const fn mpfr_rnd(rm: rug::float::Round) -> rnd_t {
+    match rm {
+        rug::float::Round::Down => rnd_t::RNDD,
+        rug::float::Round::Up => rnd_t::RNDU,
+        rug::float::Round::Zero => rnd_t::RNDZ,
+        rug::float::Round::AwayZero => rnd_t::RNDA,
+        _ => rnd_t::RNDN,
+    }
+}
+
+// rug does not bind `mpfr_beta`, so these oracles call it directly. `forbid(unsafe_code)` keeps
+// them out of the crate's test_util, but this integration test crate may use unsafe.
+fn rug_beta_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    let mut beta = rug::Float::new(u32::exact_from(prec));
+    let o = unsafe { mpfr::beta(beta.as_raw_mut(), x.as_raw(), y.as_raw(), mpfr_rnd(rm)) };
+    (beta, o.cmp(&0))
+}
+
+fn rug_beta_round(x: &rug::Float, y: &rug::Float, rm: rug::float::Round) -> (rug::Float, Ordering) {
+    rug_beta_prec_round(
+        x,
+        y,
+        max(rug_float_significant_bits(x), rug_float_significant_bits(y)),
+        rm,
+    )
+}
+
+fn rug_beta_prec(x: &rug::Float, y: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_beta_prec_round(x, y, prec, rug::float::Round::Nearest)
+}
+
+fn rug_beta(x: &rug::Float, y: &rug::Float) -> rug::Float {
+    rug_beta_round(x, y, rug::float::Round::Nearest).0
+}
+
+// The Rationals are converted to rug `Float`s of increasing precision until the rounded results
+// agree. This does not terminate if B(x, y) is 0 but its approximations are not, so callers must
+// exclude the case where x + y is a nonpositive integer.
+fn rug_beta_rational_prec_round(
+    x: &Rational,
+    y: &Rational,
+    prec: u64,
+    rm: rug::float::Round,
+) -> (rug::Float, Ordering) {
+    let rx = rug::Rational::exact_from(x);
+    let ry = rug::Rational::exact_from(y);
+    let mut working_prec = prec
+        + 128
+        + max(
+            x.numerator_ref().significant_bits(),
+            y.numerator_ref().significant_bits(),
+        );
+    let mut previous = None;
+    loop {
+        let p = u32::exact_from(working_prec);
+        let result = rug_beta_prec_round(
+            &rug::Float::with_val(p, &rx),
+            &rug::Float::with_val(p, &ry),
+            prec,
+            rm,
+        );
+        // NaN only arises at integer arguments, which are converted exactly
+        if result.0.is_nan() || previous.as_ref() == Some(&result) {
+            return result;
+        }
+        previous = Some(result);
+        working_prec <<= 1;
+    }
+}
+
+// mpfr_beta computes Γ(x)Γ(y) / Γ(x + y) directly. When an argument is large, these may overflow
+// or underflow, and mpfr_beta then returns NaN or fails an assertion, so it is only used as an
+// oracle when both arguments are less than 2 ^ 26 in absolute value.
+fn mpfr_beta_applies(x: &Float, y: &Float) -> bool {
+    !x.get_exponent().is_some_and(|e| e > 26) && !y.get_exponent().is_some_and(|e| e > 26)
+}
+
+fn mpfr_beta_rational_applies(x: &Rational, y: &Rational) -> bool {
+    x.lt_abs(&(1u32 << 26)) && y.lt_abs(&(1u32 << 26))
+}
+
+// When one argument is a positive integer n and the other, x, is not an integer, B(x, n) = (n - 1)!
+// / (x(x + 1)...(x + n - 1)) is rational and may lie exactly halfway between two Floats. mpfr_beta
+// only sees approximations of x, which round such a tie in the direction they err in, so the exact
+// value is used instead when n is small.
+fn beta_rational_exact(x: &Rational, y: &Rational) -> Option<Rational> {
+    let (x, n) = if y.is_integer() && *y > 0u32 {
+        (x, y)
+    } else if x.is_integer() && *x > 0u32 {
+        (y, x)
+    } else {
+        return None;
+    };
+    if x.is_integer() || *n > 100u32 {
+        return None;
+    }
+    let n = u64::exact_from(n);
+    let mut numerator = Rational::ONE;
+    let mut denominator = x.clone();
+    for i in 1..n {
+        numerator *= Rational::from(i);
+        denominator *= x + Rational::from(i);
+    }
+    Some(numerator / denominator)
+}
+
+fn rug_beta_rational_prec(x: &Rational, y: &Rational, prec: u64) -> (rug::Float, Ordering) {
+    rug_beta_rational_prec_round(x, y, prec, rug::float::Round::Nearest)
+}
+
+#[test]
+fn test_beta() {
+    let test = |s: &str, s_hex: &str, t: &str, t_hex: &str, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let beta = x.clone().beta(y.clone());
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+
+        let beta_alt = x.clone().beta(&y);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        let beta_alt = (&x).beta(y.clone());
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        let beta_alt = (&x).beta(&y);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+
+        let mut beta_alt = x.clone();
+        beta_alt.beta_assign(y.clone());
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        let mut beta_alt = x.clone();
+        beta_alt.beta_assign(&y);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_beta(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y)
+            ))),
+            ComparableFloatRef(&beta),
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "0.0", "0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "3.0", "0x3.0#2", "NaN", "NaN");
+    test("NaN", "NaN", "-0.50", "-0x0.8#1", "NaN", "NaN");
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "NaN",
+        "NaN",
+    );
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "0.0", "0x0.0",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "NaN", "NaN");
+    test("Infinity", "Infinity", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("Infinity", "Infinity", "3.0", "0x3.0#2", "0.0", "0x0.0");
+    test(
+        "Infinity", "Infinity", "-0.50", "-0x0.8#1", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.0",
+        "0x0.0",
+    );
+    test("-Infinity", "-Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test("-Infinity", "-Infinity", "0.0", "0x0.0", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "3.0", "0x3.0#2", "-0.0", "-0x0.0");
+    test("-Infinity", "-Infinity", "-0.50", "-0x0.8#1", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "-0.0",
+        "-0x0.0",
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "Infinity", "Infinity");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "3.0", "0x3.0#2", "Infinity", "Infinity");
+    test("0.0", "0x0.0", "-0.50", "-0x0.8#1", "Infinity", "Infinity");
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "Infinity",
+        "Infinity",
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "3.0", "0x3.0#2", "-Infinity", "-Infinity");
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.50",
+        "-0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "Infinity", "Infinity");
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "3.0", "0x3.0#2", "0.38", "0x0.6#2");
+    test("1.0", "0x1.0#1", "-0.50", "-0x0.8#1", "-2.0", "-0x2.0#1");
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+    );
+    test("-2.0", "-0x2.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "Infinity", "Infinity", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "0.0", "0x0.0", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "3.0", "0x3.0#2", "NaN", "NaN");
+    test("-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", "NaN", "NaN");
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN", "NaN", "NaN");
+    test("0.50", "0x0.8#1", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("0.50", "0x0.8#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.50", "0x0.8#1", "0.0", "0x0.0", "Infinity", "Infinity");
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("0.50", "0x0.8#1", "3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test("0.50", "0x0.8#1", "-0.50", "-0x0.8#1", "0.0", "0x0.0");
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.1253568374680794",
+        "0x1.201762bd41612#53",
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+    );
+    test("-1.5", "-0x1.8#2", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("-1.5", "-0x1.8#2", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-1.5", "-0x1.8#2", "0.0", "0x0.0", "Infinity", "Infinity");
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("-1.5", "-0x1.8#2", "3.0", "0x3.0#2", "6.0", "0x6.0#2");
+    test("-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", "0.0", "0x0.0");
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "4.0550244637661867",
+        "0x4.0e1615505b0fc#53",
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "-0.66666666666666666666666666666693",
+        "-0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        "0.029896136997514311",
+        "0x0.07a745f2ae59ec#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        "-5.4967373793516714",
+        "-0x5.7f2a2e4f0424#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.037890298781212209",
+        "0x0.09b32dba19d9810#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.31830988618379068394603385008594",
+        "0x0.517cc1b727220b79e27534dae8#100",
+    );
+}
+
+#[test]
+fn test_beta_prec() {
+    let test = |s: &str,
+                s_hex: &str,
+                t: &str,
+                t_hex: &str,
+                prec: u64,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (beta, o) = x.clone().beta_prec(y.clone(), prec);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, o_out);
+
+        let (beta_alt, o_alt) = x.clone().beta_prec_val_ref(&y, prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = x.beta_prec_ref_val(y.clone(), prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = x.beta_prec_ref_ref(&y, prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+
+        let mut beta_alt = x.clone();
+        let o_alt = beta_alt.beta_prec_assign(y.clone(), prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let mut beta_alt = x.clone();
+        let o_alt = beta_alt.beta_prec_assign_ref(&y, prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+
+        let (rug_beta, rug_o) = rug_beta_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_beta)),
+            ComparableFloatRef(&beta)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "3.0", "0x3.0#2", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.50", "-0x0.8#1", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("Infinity", "Infinity", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "3.0", "0x3.0#2", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.50", "-0x0.8#1", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "3.0", "0x3.0#2", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.50", "-0x0.8#1", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", 1, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.33350",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", 1, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        "-2.0000",
+        "-0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "-2.0000000000000000000000000000000",
+        "-0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.36768",
+        "0x0.5e2#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.36787944117144234116022692539228",
+        "0x0.5e2d58d8b3bce083a29193a888#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-2.0", "-0x2.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-2.0", "-0x2.0#1", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-2.0", "-0x2.0#1", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 1, "NaN", "NaN", Equal);
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 10, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 100, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 10, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 100, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "-0.50000",
+        "-0x0.800#10",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.0664",
+        "0x1.110#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.0666666666666666666666666666674",
+        "0x1.1111111111111111111111112#100",
+        Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 10, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 100, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.1250",
+        "0x1.200#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.1253568374680792418254907536015",
+        "0x1.201762bd4161181370da400d2#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "2.0000",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1.5", "-0x1.8#2", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "Infinity",
+        "Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "0.0", "0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", 1, "4.0", "0x4.0#1", Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "5.3359",
+        "0x5.56#10",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "5.3333333333333333333333333333354",
+        "0x5.5555555555555555555555558#100",
+        Greater,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 10, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 100, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "4.0547",
+        "0x4.0e#10",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "4.0550244637661869547626938537084",
+        "0x4.0e1615505b0fcedac372fd980#100",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "-0.66699",
+        "-0x0.aac#10",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "-0.66666666666666666666666666666693",
+        "-0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.029907",
+        "0x0.07a8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.029896136997514320297825920153352",
+        "0x0.07a745f2ae59ecac015a868ef78#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        "-5.5000",
+        "-0x5.80#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "-5.4967373793516735630211317335251",
+        "-0x5.7f2a2e4f04249c5b899596a28#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.031",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.037903",
+        "0x0.09b4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.037890298781212209406411773955748",
+        "0x0.09b32dba19d9810a5a98be8a61#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.31836",
+        "0x0.518#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.31830988618379068394603385008594",
+        "0x0.517cc1b727220b79e27534dae8#100",
+        Less,
+    );
+}
+
+#[test]
+fn beta_prec_fail() {
+    assert_panic!(Float::from(3).beta_prec(Float::from(4), 0));
+    assert_panic!(Float::from(3).beta_prec_ref_ref(&Float::from(4), 0));
+}
+
+#[test]
+fn test_beta_round() {
+    let test = |s: &str,
+                s_hex: &str,
+                t: &str,
+                t_hex: &str,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (beta, o) = x.clone().beta_round(y.clone(), rm);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, o_out);
+
+        let (beta_alt, o_alt) = x.clone().beta_round_val_ref(&y, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = x.beta_round_ref_val(y.clone(), rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = x.beta_round_ref_ref(&y, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+
+        let mut beta_alt = x.clone();
+        let o_alt = beta_alt.beta_round_assign(y.clone(), rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let mut beta_alt = x.clone();
+        let o_alt = beta_alt.beta_round_assign_ref(&y, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_beta, rug_o) =
+                rug_beta_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_beta)),
+                ComparableFloatRef(&beta)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "3.0", "0x3.0#2", Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "-0.50", "-0x0.8#1", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "3.0", "0x3.0#2", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.50", "-0x0.8#1", Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "3.0",
+        "0x3.0#2",
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.50",
+        "-0x0.8#1",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal,
+    );
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "3.0", "0x3.0#2", Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.50", "-0x0.8#1", Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", Floor, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "3.0",
+        "0x3.0#2",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.50",
+        "-0x0.8#1",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", Floor, "0.25", "0x0.4#2", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", Ceiling, "0.38", "0x0.6#2", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", Down, "0.25", "0x0.4#2", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", Up, "0.38", "0x0.6#2", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", Nearest, "0.38", "0x0.6#2", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", Floor, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", Ceiling, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", Down, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", Up, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", Nearest, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", Exact, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.36787944117144239",
+        "0x0.5e2d58d8b3bce4#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.36787944117144239",
+        "0x0.5e2d58d8b3bce4#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.36787944117144233",
+        "0x0.5e2d58d8b3bce0#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-2.0", "-0x2.0#1", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "-2.0", "-0x2.0#1", "Infinity", "Infinity", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "0.0", "0x0.0", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", Down, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", Up, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", Down, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", Up, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", Floor, "1.0", "0x1.0#2", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", Ceiling, "1.5", "0x1.8#2", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", Down, "1.0", "0x1.0#2", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", Up, "1.5", "0x1.8#2", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", Nearest, "1.0", "0x1.0#2", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", Down, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", Up, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.1253568374680791",
+        "0x1.201762bd41611#53",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.1253568374680794",
+        "0x1.201762bd41612#53",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.1253568374680791",
+        "0x1.201762bd41611#53",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.1253568374680794",
+        "0x1.201762bd41612#53",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.1253568374680794",
+        "0x1.201762bd41612#53",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1.5", "-0x1.8#2", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "Infinity",
+        "Infinity",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", Floor, "4.0", "0x4.0#2", Less,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", Ceiling, "6.0", "0x6.0#2", Greater,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", Down, "4.0", "0x4.0#2", Less,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", Up, "6.0", "0x6.0#2", Greater,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", Nearest, "6.0", "0x6.0#2", Greater,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", Down, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", Up, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "4.0550244637661867",
+        "0x4.0e1615505b0fc#53",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "4.0550244637661876",
+        "0x4.0e1615505b100#53",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "4.0550244637661867",
+        "0x4.0e1615505b0fc#53",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "4.0550244637661876",
+        "0x4.0e1615505b100#53",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "4.0550244637661867",
+        "0x4.0e1615505b0fc#53",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "-0.66666666666666666666666666666693",
+        "-0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "-0.66666666666666666666666666666614",
+        "-0x0.aaaaaaaaaaaaaaaaaaaaaaaaa#100",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "-0.66666666666666666666666666666614",
+        "-0x0.aaaaaaaaaaaaaaaaaaaaaaaaa#100",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "-0.66666666666666666666666666666693",
+        "-0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "-0.66666666666666666666666666666693",
+        "-0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        Floor,
+        "0.029896136997514311",
+        "0x0.07a745f2ae59ec#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        Ceiling,
+        "0.029896136997514339",
+        "0x0.07a745f2ae59ee#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        Down,
+        "0.029896136997514311",
+        "0x0.07a745f2ae59ec#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        Up,
+        "0.029896136997514339",
+        "0x0.07a745f2ae59ee#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        Nearest,
+        "0.029896136997514311",
+        "0x0.07a745f2ae59ec#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        Floor,
+        "-5.4967373793516785",
+        "-0x5.7f2a2e4f0426#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        Ceiling,
+        "-5.4967373793516714",
+        "-0x5.7f2a2e4f0424#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        Down,
+        "-5.4967373793516714",
+        "-0x5.7f2a2e4f0424#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        Up,
+        "-5.4967373793516785",
+        "-0x5.7f2a2e4f0426#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        Nearest,
+        "-5.4967373793516714",
+        "-0x5.7f2a2e4f0424#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.037890298781212209",
+        "0x0.09b32dba19d9810#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.037890298781212216",
+        "0x0.09b32dba19d9818#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.037890298781212209",
+        "0x0.09b32dba19d9810#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.037890298781212216",
+        "0x0.09b32dba19d9818#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.037890298781212209",
+        "0x0.09b32dba19d9810#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.31830988618379068394603385008594",
+        "0x0.517cc1b727220b79e27534dae8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.31830988618379068394603385008634",
+        "0x0.517cc1b727220b79e27534daf0#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.31830988618379068394603385008594",
+        "0x0.517cc1b727220b79e27534dae8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.31830988618379068394603385008634",
+        "0x0.517cc1b727220b79e27534daf0#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.31830988618379068394603385008594",
+        "0x0.517cc1b727220b79e27534dae8#100",
+        Less,
+    );
+}
+
+#[test]
+fn beta_round_fail() {
+    assert_panic!(Float::ONE_HALF.beta_round(Float::ONE_HALF, Exact));
+    assert_panic!(Float::ONE_HALF.beta_round_ref_ref(&Float::ONE_HALF, Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.beta_round_assign(Float::ONE_HALF, Exact)
+    });
+}
+
+#[test]
+fn test_beta_prec_round() {
+    let test = |s: &str,
+                s_hex: &str,
+                t: &str,
+                t_hex: &str,
+                prec: u64,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (beta, o) = x.clone().beta_prec_round(y.clone(), prec, rm);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, o_out);
+
+        let (beta_alt, o_alt) = x.clone().beta_prec_round_val_ref(&y, prec, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = x.beta_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = x.beta_prec_round_ref_ref(&y, prec, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+
+        let mut beta_alt = x.clone();
+        let o_alt = beta_alt.beta_prec_round_assign(y.clone(), prec, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+        let mut beta_alt = x.clone();
+        let o_alt = beta_alt.beta_prec_round_assign_ref(&y, prec, rm);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta), ComparableFloatRef(&beta_alt));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_beta, rug_o) = rug_beta_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_beta)),
+                ComparableFloatRef(&beta)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "-0.0", "-0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "3.0", "0x3.0#2", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "3.0", "0x3.0#2", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "-0.50", "-0x0.8#1", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "-0.50", "-0x0.8#1", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "3.0", "0x3.0#2", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "3.0", "0x3.0#2", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.50", "-0x0.8#1", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.50", "-0x0.8#1", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "3.0", "0x3.0#2", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "3.0", "0x3.0#2", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.50", "-0x0.8#1", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.50", "-0x0.8#1", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", 1, Floor, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", 1, Ceiling, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "3.0", "0x3.0#2", 1, Nearest, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "0.33301",
+        "0x0.554#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.33350",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.33350",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", 1, Floor, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", 1, Ceiling, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.50", "-0x0.8#1", 1, Nearest, "-2.0", "-0x2.0#1", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "-2.0000",
+        "-0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-2.0000",
+        "-0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-2.0000",
+        "-0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.36768",
+        "0x0.5e2#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.36816",
+        "0x0.5e4#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.36768",
+        "0x0.5e2#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "Infinity", "Infinity", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "0.0", "0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "0.0", "0x0.0", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.0", "-0x0.0", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.0", "-0x0.0", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 1, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 10, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "3.0", "0x3.0#2", 10, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 1, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 10, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "-0.50", "-0x0.8#1", 10, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "-0.50000",
+        "-0x0.800#10",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "-0.50000",
+        "-0x0.800#10",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "-0.50000",
+        "-0x0.800#10",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "3.0", "0x3.0#2", 1, Nearest, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "1.0664",
+        "0x1.110#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "1.0684",
+        "0x1.118#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "1.0664",
+        "0x1.110#10",
+        Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 1, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 10, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.50", "-0x0.8#1", 10, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.1250",
+        "0x1.200#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.1270",
+        "0x1.208#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.1250",
+        "0x1.200#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "2.0000",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "2.0000",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "2.0000",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "Infinity",
+        "Infinity",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "Infinity",
+        "Infinity",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "0.0", "0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", 1, Floor, "4.0", "0x4.0#1", Less,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", 1, Ceiling, "8.0", "0x8.0#1", Greater,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "3.0", "0x3.0#2", 1, Nearest, "4.0", "0x4.0#1", Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "5.3281",
+        "0x5.54#10",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "5.3359",
+        "0x5.56#10",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "5.3359",
+        "0x5.56#10",
+        Greater,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 1, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 1, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 1, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 10, Ceiling, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5", "-0x1.8#2", "-0.50", "-0x0.8#1", 10, Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "4.0547",
+        "0x4.0e#10",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "4.0625",
+        "0x4.10#10",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "4.0547",
+        "0x4.0e#10",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "-0.66699",
+        "-0x0.aac#10",
+        Less,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "-0.66602",
+        "-0x0.aa8#10",
+        Greater,
+    );
+    test(
+        "-1.5",
+        "-0x1.8#2",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "-0.66699",
+        "-0x0.aac#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Floor,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Ceiling,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Nearest,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "0.029877",
+        "0x0.07a6#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.029907",
+        "0x0.07a8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.029907",
+        "0x0.07a8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        Floor,
+        "-8.0",
+        "-0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        1,
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "-5.5000",
+        "-0x5.80#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-5.4922",
+        "-0x5.7e#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-5.5000",
+        "-0x5.80#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.031",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.062",
+        "0x0.1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.031",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.037842",
+        "0x0.09b0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.037903",
+        "0x0.09b4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.037903",
+        "0x0.09b4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.31787",
+        "0x0.516#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.31836",
+        "0x0.518#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.31836",
+        "0x0.518#10",
+        Greater,
+    );
+}
+
+#[test]
+fn beta_prec_round_fail() {
+    assert_panic!(Float::from(3).beta_prec_round(Float::from(4), 0, Floor));
+    assert_panic!(Float::from(3).beta_prec_round_ref_ref(&Float::from(4), 0, Floor));
+    // beta(1/2, 1/2) = pi is irrational
+    assert_panic!(Float::ONE_HALF.beta_prec_round(Float::ONE_HALF, 10, Exact));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn beta_prec_round_properties_helper(x: Float, y: Float, prec: u64, rm: RoundingMode) {
+    let (beta, o) = x.clone().beta_prec_round(y.clone(), prec, rm);
+    assert!(beta.is_valid());
+    let (beta_alt, o_alt) = x.clone().beta_prec_round_val_ref(&y, prec, rm);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let (beta_alt, o_alt) = x.beta_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let (beta_alt, o_alt) = x.beta_prec_round_ref_ref(&y, prec, rm);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.beta_prec_round_assign(y.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.beta_prec_round_assign_ref(&y, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    if mpfr_beta_applies(&x, &y)
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_beta, rug_o) = rug_beta_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_beta)),
+            ComparableFloatRef(&beta)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if x.is_nan() || y.is_nan() {
+        assert!(beta.is_nan());
+    }
+    if beta.is_normal() {
+        assert_eq!(beta.get_prec(), Some(prec));
+    }
+
+    // beta is symmetric
+    let (beta_alt, o_alt) = y.beta_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(ComparableFloat(beta_alt), ComparableFloat(beta.clone()));
+    assert_eq!(o_alt, o);
+
+    // B(x, 1) = 1 / x, except at the poles of Γ(x)
+    if rm != Exact && !(x < 0u32 && x.is_integer()) {
+        let (beta_alt, o_alt) = x.beta_prec_round_ref_val(Float::ONE, prec, rm);
+        let (reciprocal, o_reciprocal) = x.reciprocal_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloat(beta_alt), ComparableFloat(reciprocal));
+        assert_eq!(o_alt, o_reciprocal);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.beta_prec_round_ref_ref(&y, prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(beta.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.beta_prec_round_ref_ref(&y, prec, Exact));
+    }
+}
+
+#[test]
+fn beta_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_27().test_properties(
+        |(x, y, prec, rm)| {
+            beta_prec_round_properties_helper(x, y, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (beta, o) = Float::NAN.beta_prec_round(Float::ONE, prec, rm);
+        assert!(beta.is_nan());
+        assert_eq!(o, Equal);
+        let (beta, o) = Float::ONE.beta_prec_round(Float::NAN, prec, rm);
+        assert!(beta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (beta, o) = Float::INFINITY.beta_prec_round(Float::ONE, prec, rm);
+        assert_eq!(ComparableFloat(beta), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (beta, o) = Float::NEGATIVE_INFINITY.beta_prec_round(Float::ONE, prec, rm);
+        assert_eq!(ComparableFloat(beta), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+
+        for (x, out) in
+            [(Float::ZERO, Float::INFINITY), (Float::NEGATIVE_ZERO, Float::NEGATIVE_INFINITY)]
+        {
+            let (beta, o) = x.beta_prec_round_ref_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(beta), ComparableFloat(out.clone()));
+            assert_eq!(o, Equal);
+            let (beta, o) = x.beta_prec_round_ref_val(Float::ONE, prec, rm);
+            assert_eq!(ComparableFloat(beta), ComparableFloat(out));
+            assert_eq!(o, Equal);
+        }
+        let (beta, o) = Float::ZERO.beta_prec_round(Float::NEGATIVE_ZERO, prec, rm);
+        assert!(beta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (beta, o) = Float::ONE.beta_prec_round(Float::ONE, prec, rm);
+        assert_eq!(
+            ComparableFloat(beta),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn beta_prec_properties_helper(x: Float, y: Float, prec: u64) {
+    let (beta, o) = x.clone().beta_prec(y.clone(), prec);
+    assert!(beta.is_valid());
+    let (beta_alt, o_alt) = x.clone().beta_prec_val_ref(&y, prec);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let (beta_alt, o_alt) = x.beta_prec_ref_val(y.clone(), prec);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let (beta_alt, o_alt) = x.beta_prec_ref_ref(&y, prec);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.beta_prec_assign(y.clone(), prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.beta_prec_assign_ref(&y, prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    let (beta_alt, o_alt) = x.beta_prec_round_ref_ref(&y, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    if mpfr_beta_applies(&x, &y) {
+        let (rug_beta, rug_o) = rug_beta_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_beta)),
+            ComparableFloatRef(&beta)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn beta_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        beta_prec_properties_helper(x, y, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn beta_round_properties_helper(x: Float, y: Float, rm: RoundingMode) {
+    let (beta, o) = x.clone().beta_round(y.clone(), rm);
+    assert!(beta.is_valid());
+    let (beta_alt, o_alt) = x.clone().beta_round_val_ref(&y, rm);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let (beta_alt, o_alt) = x.beta_round_ref_val(y.clone(), rm);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let (beta_alt, o_alt) = x.beta_round_ref_ref(&y, rm);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.beta_round_assign(y.clone(), rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.beta_round_assign_ref(&y, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    let prec = max(x.significant_bits(), y.significant_bits());
+    let (beta_alt, o_alt) = x.beta_prec_round_ref_ref(&y, prec, rm);
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    assert_eq!(o_alt, o);
+
+    if mpfr_beta_applies(&x, &y)
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_beta, rug_o) = rug_beta_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_beta)),
+            ComparableFloatRef(&beta)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn beta_round_properties() {
+    float_float_rounding_mode_triple_gen_var_46().test_properties(|(x, y, rm)| {
+        beta_round_properties_helper(x, y, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn beta_properties_helper(x: Float, y: Float) {
+    let beta = x.clone().beta(y.clone());
+    assert!(beta.is_valid());
+    let beta_alt = x.clone().beta(&y);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    let beta_alt = (&x).beta(y.clone());
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    let beta_alt = (&x).beta(&y);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+
+    let mut beta_alt = x.clone();
+    beta_alt.beta_assign(y.clone());
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    let mut beta_alt = x.clone();
+    beta_alt.beta_assign(&y);
+    assert!(beta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+
+    let prec = max(x.significant_bits(), y.significant_bits());
+    let (beta_alt, _) = x.beta_prec_round_ref_ref(&y, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    let (beta_alt, _) = x.clone().beta_prec(y.clone(), prec);
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+    let (beta_alt, _) = x.clone().beta_round(y.clone(), Nearest);
+    assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+
+    if mpfr_beta_applies(&x, &y) {
+        let rug_beta = rug_beta(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_beta)),
+            ComparableFloatRef(&beta)
+        );
+    }
+}
+
+#[test]
+fn beta_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        beta_properties_helper(x, y);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_beta() {
+    fn test<T: PrimitiveFloat>(x: T, y: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_beta(x, y)), NiceFloat(out));
+    }
+    test::<f32>(1.0, 1.0, 1.0);
+    test::<f32>(0.5, 0.5, core::f32::consts::PI);
+    test::<f32>(2.0, 3.0, 0.083333336);
+    test::<f32>(-0.5, -0.5, 0.0);
+    test::<f32>(-1.5, 0.25, 2.1850479);
+    test::<f32>(0.0, 1.0, f32::INFINITY);
+    test::<f32>(-0.0, -0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(f32::INFINITY, 0.5, 0.0);
+    test::<f32>(f32::INFINITY, -0.5, f32::INFINITY);
+    test::<f32>(f32::NEGATIVE_INFINITY, 3.0, -0.0);
+    test::<f32>(-2.0, 1.0, -0.5);
+    test::<f32>(-2.0, 0.5, f32::NAN);
+    test::<f32>(1000.0, 1000.0, 0.0);
+    test::<f32>(f32::NAN, 1.0, f32::NAN);
+
+    test::<f64>(1.0, 1.0, 1.0);
+    test::<f64>(0.5, 0.5, core::f64::consts::PI);
+    test::<f64>(2.0, 3.0, 0.08333333333333333);
+    test::<f64>(-0.5, -0.5, 0.0);
+    test::<f64>(-1.5, 0.25, 2.1850479619101);
+    test::<f64>(0.0, 1.0, f64::INFINITY);
+    test::<f64>(-0.0, -0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(f64::INFINITY, 0.5, 0.0);
+    test::<f64>(f64::INFINITY, -0.5, f64::INFINITY);
+    test::<f64>(f64::NEGATIVE_INFINITY, 3.0, -0.0);
+    test::<f64>(-2.0, 1.0, -0.5);
+    test::<f64>(-2.0, 0.5, f64::NAN);
+    test::<f64>(1.0e-300, 1.0, 9.999999999999999e299);
+    test::<f64>(1000.0, 1000.0, 0.0);
+    test::<f64>(f64::NAN, 1.0, f64::NAN);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_beta_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_pair_gen::<T>().test_properties(|(x, y)| {
+        let beta = primitive_float_beta(x, y);
+        if x.is_nan() || y.is_nan() {
+            assert!(beta.is_nan());
+        }
+        assert_eq!(NiceFloat(primitive_float_beta(y, x)), NiceFloat(beta));
+    });
+}
+
+#[test]
+fn primitive_float_beta_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_beta_properties_helper);
+}
+
+#[test]
+fn test_beta_rational_prec() {
+    let test = |s, t, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+
+        let (beta, o) = Float::beta_rational_prec(x.clone(), y.clone(), prec);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (beta, o) = Float::beta_rational_prec_val_ref(x.clone(), &y, prec);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (beta, o) = Float::beta_rational_prec_ref_val(&x, y.clone(), prec);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (beta, o) = Float::beta_rational_prec_ref_ref(&x, &y, prec);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_beta, rug_o) = rug_beta_rational_prec(&x, &y, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_beta)),
+            ComparableFloatRef(&beta)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", "1", 1, "Infinity", "Infinity", Equal);
+    test("0", "1", 10, "Infinity", "Infinity", Equal);
+    test("0", "1", 100, "Infinity", "Infinity", Equal);
+    test("0", "-1", 1, "NaN", "NaN", Equal);
+    test("0", "-1", 10, "NaN", "NaN", Equal);
+    test("0", "-1", 100, "NaN", "NaN", Equal);
+    test("1", "1", 1, "1.0", "0x1.0#1", Equal);
+    test("1", "1", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1",
+        "1",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1/2", "1/2", 1, "4.0", "0x4.0#1", Greater);
+    test("1/2", "1/2", 10, "3.1406", "0x3.24#10", Less);
+    test(
+        "1/2",
+        "1/2",
+        100,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test("1/3", "5/2", 1, "2.0", "0x2.0#1", Less);
+    test("1/3", "5/2", 10, "2.0664", "0x2.11#10", Greater);
+    test(
+        "1/3",
+        "5/2",
+        100,
+        "2.0650318278429417300941190073272",
+        "0x2.10a5ed05c8d769f50913294fc#100",
+        Less,
+    );
+    test("-1/2", "1/3", 1, "1.0", "0x1.0#1", Less);
+    test("-1/2", "1/3", 10, "1.4023", "0x1.670#10", Greater);
+    test(
+        "-1/2",
+        "1/3",
+        100,
+        "1.4021821053254542611750190790499",
+        "0x1.66f5680d68c4d62b1f7225886#100",
+        Less,
+    );
+    test("3", "-7/2", 1, "-0.12", "-0x0.2#1", Greater);
+    test("3", "-7/2", 10, "-0.15234", "-0x0.270#10", Greater);
+    test(
+        "3",
+        "-7/2",
+        100,
+        "-0.15238095238095238095238095238100",
+        "-0x0.27027027027027027027027028#100",
+        Less,
+    );
+    test("-3", "2", 1, "0.12", "0x0.2#1", Less);
+    test("-3", "2", 10, "0.16675", "0x0.2ab#10", Greater);
+    test(
+        "-3",
+        "2",
+        100,
+        "0.16666666666666666666666666666673",
+        "0x0.2aaaaaaaaaaaaaaaaaaaaaaaac#100",
+        Greater,
+    );
+    test("-3", "4", 1, "NaN", "NaN", Equal);
+    test("-3", "4", 10, "NaN", "NaN", Equal);
+    test("-3", "4", 100, "NaN", "NaN", Equal);
+    test("-1/2", "-1/2", 1, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 10, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 100, "0.0", "0x0.0", Equal);
+    test("22/7", "100", 1, "9.5e-7", "0x0.00001#1", Less);
+    test("22/7", "100", 10, "1.1474e-6", "0x0.00001340#10", Less);
+    test(
+        "22/7",
+        "100",
+        100,
+        "1.1475951355119358613367231102680e-6",
+        "0x0.00001340e2320a9eb6707a7e6fc2b0#100",
+        Greater,
+    );
+    test("-5/2", "-3/2", 1, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 10, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 100, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+fn beta_rational_prec_fail() {
+    assert_panic!(Float::beta_rational_prec(Rational::ONE, Rational::ONE, 0));
+    assert_panic!(Float::beta_rational_prec_val_ref(
+        Rational::ONE,
+        &Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::beta_rational_prec_ref_val(
+        &Rational::ONE,
+        Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::beta_rational_prec_ref_ref(
+        &Rational::ONE,
+        &Rational::ONE,
+        0
+    ));
+}
+
+#[test]
+fn test_beta_rational_prec_round() {
+    let test = |s, t, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+
+        let (beta, o) = Float::beta_rational_prec_round(x.clone(), y.clone(), prec, rm);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (beta, o) = Float::beta_rational_prec_round_val_ref(x.clone(), &y, prec, rm);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (beta, o) = Float::beta_rational_prec_round_ref_val(&x, y.clone(), prec, rm);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (beta, o) = Float::beta_rational_prec_round_ref_ref(&x, &y, prec, rm);
+        assert!(beta.is_valid());
+        assert_eq!(beta.to_string(), out);
+        assert_eq!(to_hex_string(&beta), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_beta, rug_o) = rug_beta_rational_prec_round(&x, &y, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_beta)),
+                ComparableFloatRef(&beta)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", "1", 1, Floor, "Infinity", "Infinity", Equal);
+    test("0", "1", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", "1", 1, Down, "Infinity", "Infinity", Equal);
+    test("0", "1", 1, Up, "Infinity", "Infinity", Equal);
+    test("0", "1", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0", "1", 1, Exact, "Infinity", "Infinity", Equal);
+    test("0", "1", 10, Floor, "Infinity", "Infinity", Equal);
+    test("0", "1", 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", "1", 10, Down, "Infinity", "Infinity", Equal);
+    test("0", "1", 10, Up, "Infinity", "Infinity", Equal);
+    test("0", "1", 10, Nearest, "Infinity", "Infinity", Equal);
+    test("0", "1", 10, Exact, "Infinity", "Infinity", Equal);
+    test("0", "1", 100, Nearest, "Infinity", "Infinity", Equal);
+    test("0", "-1", 1, Floor, "NaN", "NaN", Equal);
+    test("0", "-1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("0", "-1", 1, Down, "NaN", "NaN", Equal);
+    test("0", "-1", 1, Up, "NaN", "NaN", Equal);
+    test("0", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "-1", 1, Exact, "NaN", "NaN", Equal);
+    test("0", "-1", 10, Floor, "NaN", "NaN", Equal);
+    test("0", "-1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("0", "-1", 10, Down, "NaN", "NaN", Equal);
+    test("0", "-1", 10, Up, "NaN", "NaN", Equal);
+    test("0", "-1", 10, Nearest, "NaN", "NaN", Equal);
+    test("0", "-1", 10, Exact, "NaN", "NaN", Equal);
+    test("0", "-1", 100, Nearest, "NaN", "NaN", Equal);
+    test("1", "1", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1", "1", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("1", "1", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("1", "1", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("1", "1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("1", "1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1", "1", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("1", "1", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("1", "1", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("1", "1", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("1", "1", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("1", "1", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1",
+        "1",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1/2", "1/2", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1/2", "1/2", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1/2", "1/2", 1, Down, "2.0", "0x2.0#1", Less);
+    test("1/2", "1/2", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("1/2", "1/2", 1, Nearest, "4.0", "0x4.0#1", Greater);
+    test("1/2", "1/2", 10, Floor, "3.1406", "0x3.24#10", Less);
+    test("1/2", "1/2", 10, Ceiling, "3.1445", "0x3.25#10", Greater);
+    test("1/2", "1/2", 10, Down, "3.1406", "0x3.24#10", Less);
+    test("1/2", "1/2", 10, Up, "3.1445", "0x3.25#10", Greater);
+    test("1/2", "1/2", 10, Nearest, "3.1406", "0x3.24#10", Less);
+    test(
+        "1/2",
+        "1/2",
+        100,
+        Nearest,
+        "3.1415926535897932384626433832793",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Less,
+    );
+    test("1/3", "5/2", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1/3", "5/2", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1/3", "5/2", 1, Down, "2.0", "0x2.0#1", Less);
+    test("1/3", "5/2", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("1/3", "5/2", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/3", "5/2", 10, Floor, "2.0625", "0x2.10#10", Less);
+    test("1/3", "5/2", 10, Ceiling, "2.0664", "0x2.11#10", Greater);
+    test("1/3", "5/2", 10, Down, "2.0625", "0x2.10#10", Less);
+    test("1/3", "5/2", 10, Up, "2.0664", "0x2.11#10", Greater);
+    test("1/3", "5/2", 10, Nearest, "2.0664", "0x2.11#10", Greater);
+    test(
+        "1/3",
+        "5/2",
+        100,
+        Nearest,
+        "2.0650318278429417300941190073272",
+        "0x2.10a5ed05c8d769f50913294fc#100",
+        Less,
+    );
+    test("-1/2", "1/3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1/2", "1/3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1/2", "1/3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1/2", "1/3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1/2", "1/3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("-1/2", "1/3", 10, Floor, "1.4004", "0x1.668#10", Less);
+    test("-1/2", "1/3", 10, Ceiling, "1.4023", "0x1.670#10", Greater);
+    test("-1/2", "1/3", 10, Down, "1.4004", "0x1.668#10", Less);
+    test("-1/2", "1/3", 10, Up, "1.4023", "0x1.670#10", Greater);
+    test("-1/2", "1/3", 10, Nearest, "1.4023", "0x1.670#10", Greater);
+    test(
+        "-1/2",
+        "1/3",
+        100,
+        Nearest,
+        "1.4021821053254542611750190790499",
+        "0x1.66f5680d68c4d62b1f7225886#100",
+        Less,
+    );
+    test("3", "-7/2", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test("3", "-7/2", 1, Ceiling, "-0.12", "-0x0.2#1", Greater);
+    test("3", "-7/2", 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("3", "-7/2", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test("3", "-7/2", 1, Nearest, "-0.12", "-0x0.2#1", Greater);
+    test("3", "-7/2", 10, Floor, "-0.15259", "-0x0.271#10", Less);
+    test("3", "-7/2", 10, Ceiling, "-0.15234", "-0x0.270#10", Greater);
+    test("3", "-7/2", 10, Down, "-0.15234", "-0x0.270#10", Greater);
+    test("3", "-7/2", 10, Up, "-0.15259", "-0x0.271#10", Less);
+    test("3", "-7/2", 10, Nearest, "-0.15234", "-0x0.270#10", Greater);
+    test(
+        "3",
+        "-7/2",
+        100,
+        Nearest,
+        "-0.15238095238095238095238095238100",
+        "-0x0.27027027027027027027027028#100",
+        Less,
+    );
+    test("-3", "2", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("-3", "2", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("-3", "2", 1, Down, "0.12", "0x0.2#1", Less);
+    test("-3", "2", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("-3", "2", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("-3", "2", 10, Floor, "0.16650", "0x0.2aa#10", Less);
+    test("-3", "2", 10, Ceiling, "0.16675", "0x0.2ab#10", Greater);
+    test("-3", "2", 10, Down, "0.16650", "0x0.2aa#10", Less);
+    test("-3", "2", 10, Up, "0.16675", "0x0.2ab#10", Greater);
+    test("-3", "2", 10, Nearest, "0.16675", "0x0.2ab#10", Greater);
+    test(
+        "-3",
+        "2",
+        100,
+        Nearest,
+        "0.16666666666666666666666666666673",
+        "0x0.2aaaaaaaaaaaaaaaaaaaaaaaac#100",
+        Greater,
+    );
+    test("-3", "4", 1, Floor, "NaN", "NaN", Equal);
+    test("-3", "4", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-3", "4", 1, Down, "NaN", "NaN", Equal);
+    test("-3", "4", 1, Up, "NaN", "NaN", Equal);
+    test("-3", "4", 1, Nearest, "NaN", "NaN", Equal);
+    test("-3", "4", 1, Exact, "NaN", "NaN", Equal);
+    test("-3", "4", 10, Floor, "NaN", "NaN", Equal);
+    test("-3", "4", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-3", "4", 10, Down, "NaN", "NaN", Equal);
+    test("-3", "4", 10, Up, "NaN", "NaN", Equal);
+    test("-3", "4", 10, Nearest, "NaN", "NaN", Equal);
+    test("-3", "4", 10, Exact, "NaN", "NaN", Equal);
+    test("-3", "4", 100, Nearest, "NaN", "NaN", Equal);
+    test("-1/2", "-1/2", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 1, Down, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 1, Up, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 10, Down, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 10, Up, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-1/2", "-1/2", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("22/7", "100", 1, Floor, "9.5e-7", "0x0.00001#1", Less);
+    test("22/7", "100", 1, Ceiling, "1.9e-6", "0x0.00002#1", Greater);
+    test("22/7", "100", 1, Down, "9.5e-7", "0x0.00001#1", Less);
+    test("22/7", "100", 1, Up, "1.9e-6", "0x0.00002#1", Greater);
+    test("22/7", "100", 1, Nearest, "9.5e-7", "0x0.00001#1", Less);
+    test(
+        "22/7",
+        "100",
+        10,
+        Floor,
+        "1.1474e-6",
+        "0x0.00001340#10",
+        Less,
+    );
+    test(
+        "22/7",
+        "100",
+        10,
+        Ceiling,
+        "1.1493e-6",
+        "0x0.00001348#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        "100",
+        10,
+        Down,
+        "1.1474e-6",
+        "0x0.00001340#10",
+        Less,
+    );
+    test(
+        "22/7",
+        "100",
+        10,
+        Up,
+        "1.1493e-6",
+        "0x0.00001348#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        "100",
+        10,
+        Nearest,
+        "1.1474e-6",
+        "0x0.00001340#10",
+        Less,
+    );
+    test(
+        "22/7",
+        "100",
+        100,
+        Nearest,
+        "1.1475951355119358613367231102680e-6",
+        "0x0.00001340e2320a9eb6707a7e6fc2b0#100",
+        Greater,
+    );
+    test("-5/2", "-3/2", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 1, Down, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 1, Up, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 10, Down, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 10, Up, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-5/2", "-3/2", 100, Nearest, "0.0", "0x0.0", Equal);
+}
+
+#[test]
+fn beta_rational_prec_round_fail() {
+    assert_panic!(Float::beta_rational_prec_round(
+        Rational::ONE,
+        Rational::ONE,
+        0,
+        Floor
+    ));
+    assert_panic!(Float::beta_rational_prec_round_ref_ref(
+        &Rational::ONE,
+        &Rational::ONE,
+        0,
+        Floor
+    ));
+    assert_panic!(Float::beta_rational_prec_round(
+        Rational::ONE_HALF,
+        Rational::ONE_HALF,
+        10,
+        Exact
+    ));
+    assert_panic!(Float::beta_rational_prec_round_ref_ref(
+        &Rational::ONE_HALF,
+        &Rational::ONE_HALF,
+        10,
+        Exact
+    ));
+}
+
+// B(x, y) is 0 when x + y is a nonpositive integer and neither x nor y is an integer. The rug
+// oracle can't settle that case, since its approximations of x and y don't sum to an integer.
+fn beta_rational_is_zero(x: &Rational, y: &Rational) -> bool {
+    let s = x + y;
+    s <= 0u32 && s.is_integer() && !x.is_integer()
+}
+
+#[test]
+fn beta_rational_prec_round_properties() {
+    rational_rational_unsigned_rounding_mode_quadruple_gen_var_4().test_properties(
+        |(x, y, prec, rm)| {
+            let (beta, o) = Float::beta_rational_prec_round(x.clone(), y.clone(), prec, rm);
+            assert!(beta.is_valid());
+            let (beta_alt, o_alt) =
+                Float::beta_rational_prec_round_val_ref(x.clone(), &y, prec, rm);
+            assert!(beta_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+            assert_eq!(o_alt, o);
+            let (beta_alt, o_alt) =
+                Float::beta_rational_prec_round_ref_val(&x, y.clone(), prec, rm);
+            assert!(beta_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+            assert_eq!(o_alt, o);
+            let (beta_alt, o_alt) = Float::beta_rational_prec_round_ref_ref(&x, &y, prec, rm);
+            assert!(beta_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+            assert_eq!(o_alt, o);
+
+            if beta_rational_is_zero(&x, &y) {
+                assert_eq!(ComparableFloat(beta.clone()), ComparableFloat(Float::ZERO));
+                assert_eq!(o, Equal);
+            } else if let Some(exact) = beta_rational_exact(&x, &y) {
+                let (beta_alt, o_alt) = Float::from_rational_prec_round(exact, prec, rm);
+                assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+                assert_eq!(o_alt, o);
+            } else if mpfr_beta_rational_applies(&x, &y)
+                && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+            {
+                let (rug_beta, rug_o) = rug_beta_rational_prec_round(&x, &y, prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_beta)),
+                    ComparableFloatRef(&beta)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if beta.is_normal() {
+                assert_eq!(beta.get_prec(), Some(prec));
+            }
+
+            let (beta_alt, o_alt) = Float::beta_rational_prec_round_ref_ref(&y, &x, prec, rm);
+            assert_eq!(ComparableFloat(beta_alt), ComparableFloat(beta.clone()));
+            assert_eq!(o_alt, o);
+
+            if o == Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (s, oo) = Float::beta_rational_prec_round_ref_ref(&x, &y, prec, rm);
+                    assert_eq!(ComparableFloat(s), ComparableFloat(beta.clone()));
+                    assert_eq!(oo, Equal);
+                }
+            } else {
+                assert_panic!(Float::beta_rational_prec_round_ref_ref(&x, &y, prec, Exact));
+            }
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (beta, o) = Float::beta_rational_prec_round(Rational::ZERO, Rational::ONE, prec, rm);
+        assert_eq!(ComparableFloat(beta), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (beta, o) =
+            Float::beta_rational_prec_round(Rational::ZERO, Rational::NEGATIVE_ONE, prec, rm);
+        assert!(beta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (beta, o) = Float::beta_rational_prec_round(Rational::ONE, Rational::ONE, prec, rm);
+        assert_eq!(
+            ComparableFloat(beta),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+fn beta_rational_prec_properties() {
+    rational_rational_unsigned_triple_gen_var_2().test_properties(|(x, y, prec)| {
+        let (beta, o) = Float::beta_rational_prec(x.clone(), y.clone(), prec);
+        assert!(beta.is_valid());
+        let (beta_alt, o_alt) = Float::beta_rational_prec_val_ref(x.clone(), &y, prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = Float::beta_rational_prec_ref_val(&x, y.clone(), prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+        assert_eq!(o_alt, o);
+        let (beta_alt, o_alt) = Float::beta_rational_prec_ref_ref(&x, &y, prec);
+        assert!(beta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+        assert_eq!(o_alt, o);
+
+        let (beta_alt, o_alt) = Float::beta_rational_prec_round_ref_ref(&x, &y, prec, Nearest);
+        assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+        assert_eq!(o_alt, o);
+
+        if let Some(exact) = beta_rational_exact(&x, &y) {
+            let (beta_alt, o_alt) = Float::from_rational_prec(exact, prec);
+            assert_eq!(ComparableFloatRef(&beta_alt), ComparableFloatRef(&beta));
+            assert_eq!(o_alt, o);
+        } else if !beta_rational_is_zero(&x, &y) && mpfr_beta_rational_applies(&x, &y) {
+            let (rug_beta, rug_o) = rug_beta_rational_prec(&x, &y, prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_beta)),
+                ComparableFloatRef(&beta)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_beta_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, t: &str, out: T)
+    where
+        Float: PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_beta_rational::<T>(&x, &y)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", "1", f32::INFINITY);
+    test::<f32>("0", "-1", f32::NAN);
+    test::<f32>("1", "1", 1.0);
+    test::<f32>("1/2", "1/2", core::f32::consts::PI);
+    test::<f32>("1/3", "5/2", 2.0650318);
+    test::<f32>("-1/2", "1/3", 1.4021821);
+    test::<f32>("3", "-7/2", -0.15238096);
+    test::<f32>("-3", "2", 0.16666667);
+    test::<f32>("-3", "4", f32::NAN);
+    test::<f32>("-1/2", "-1/2", 0.0);
+    test::<f32>("22/7", "100", 0.0000011475952);
+    test::<f32>("-5/2", "-3/2", 0.0);
+
+    test::<f64>("0", "1", f64::INFINITY);
+    test::<f64>("0", "-1", f64::NAN);
+    test::<f64>("1", "1", 1.0);
+    test::<f64>("1/2", "1/2", core::f64::consts::PI);
+    test::<f64>("1/3", "5/2", 2.0650318278429416);
+    test::<f64>("-1/2", "1/3", 1.4021821053254542);
+    test::<f64>("3", "-7/2", -0.1523809523809524);
+    test::<f64>("-3", "2", 0.16666666666666666);
+    test::<f64>("-3", "4", f64::NAN);
+    test::<f64>("-1/2", "-1/2", 0.0);
+    test::<f64>("22/7", "100", 1.1475951355119358e-6);
+    test::<f64>("-5/2", "-3/2", 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_beta_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_pair_gen().test_properties(|(x, y)| {
+        let beta = primitive_float_beta_rational::<T>(&x, &y);
+        assert_eq!(
+            NiceFloat(primitive_float_beta_rational::<T>(&y, &x)),
+            NiceFloat(beta)
+        );
+    });
+
+    primitive_float_pair_gen::<T>().test_properties(|(x, y)| {
+        if x.is_finite() && y.is_finite() && x != T::ZERO && y != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_beta_rational::<T>(
+                    &Rational::exact_from(x),
+                    &Rational::exact_from(y)
+                )),
+                NiceFloat(primitive_float_beta(x, y))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_beta_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_beta_rational_properties_helper);
+}
@@ -0,0 +1,2577 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Cos, CosAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::cos::{primitive_float_cos, primitive_float_cos_rational};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::cos::{
+    rug_cos, rug_cos_prec, rug_cos_prec_round, rug_cos_rational_prec, rug_cos_rational_prec_round,
+    rug_cos_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_cos() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let cos = x.clone().cos();
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let cos_alt = (&x).cos();
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        let mut cos_alt = x.clone();
+        cos_alt.cos_assign();
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&cos)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "0.50", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "0.50", "0x0.8#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+    );
+    test("123.0", "0x7b.0#7", "-0.8906", "-0x0.e4#7");
+    test("-123.0", "-0x7b.0#7", "-0.8906", "-0x0.e4#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+    );
+    test("355.0", "0x163.0#9", "-1.000", "-0x1.00#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+    );
+}
+
+#[test]
+fn test_cos_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec(prec);
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_assign(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "-1.0", "-0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "-0.88770", "-0x0.e34#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.91173391478696503850924773819963",
+        "-0x0.e96764d2aa0034ce60b62d755#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, "-1.0", "-0x1.0#1", Less);
+    test("355.0", "0x163.0#9", 10, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "-0.99999999954565898016593584169260",
+        "-0x0.fffffffe0c725f3ceecd7f4cb#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "-0.57538611195754904668824427596616",
+        "-0x0.934c81156e2606122aa6b4a3b#100",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+}
+
+#[test]
+fn cos_prec_fail() {
+    assert_panic!(Float::NAN.cos_prec(0));
+    assert_panic!(Float::NAN.cos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.cos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_cos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_round(rm);
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_round_ref(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_round_assign(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "0.50", "0x0.8#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.54030230586813971740093660744256",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.54030230586813971740093660744256",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "-0.8906", "-0x0.e4#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "-0.8828",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "-0.8828", "-0x0.e2#7", Greater);
+    test("123.0", "0x7b.0#7", Up, "-0.8906", "-0x0.e4#7", Less);
+    test("123.0", "0x7b.0#7", Nearest, "-0.8906", "-0x0.e4#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-0.8906", "-0x0.e4#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.8828",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.8828", "-0x0.e2#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-0.8906", "-0x0.e4#7", Less);
+    test("-123.0", "-0x7b.0#7", Nearest, "-0.8906", "-0x0.e4#7", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "-0.99999999999999911",
+        "-0x0.ffffffffffffc#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "-0.99999999999999911",
+        "-0x0.ffffffffffffc#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "-0.91173391478696497",
+        "-0x0.e96764d2aa0030#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "-0.91173391478696497",
+        "-0x0.e96764d2aa0030#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test("355.0", "0x163.0#9", Floor, "-1.000", "-0x1.00#9", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "-0.9980",
+        "-0x0.ff8#9",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", Down, "-0.9980", "-0x0.ff8#9", Greater);
+    test("355.0", "0x163.0#9", Up, "-1.000", "-0x1.00#9", Less);
+    test("355.0", "0x163.0#9", Nearest, "-1.000", "-0x1.00#9", Less);
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "-0.57538611195754941",
+        "-0x0.934c81156e262#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "-0.57538611195754941",
+        "-0x0.934c81156e262#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Floor, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Up, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.94495694631473766438828400767662",
+        "0x0.f1e8b2cc8cc1656b6998d964e#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.94495694631473766438828400767662",
+        "0x0.f1e8b2cc8cc1656b6998d964e#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+}
+
+#[test]
+fn cos_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(X.cos_round(Exact));
+    assert_panic!(X.cos_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.cos_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_cos_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_round_assign(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.54004", "0x0.8a4#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.54102", "0x0.8a8#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.54030230586813971740093660744256",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "0.54004", "0x0.8a4#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "0.54102", "0x0.8a8#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "0.54030230586813971740093660744256",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.54030230586813971740093660744256",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 1, Ceiling, "-0.50", "-0x0.8#1", Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "-0.88867",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Up, "-0.88867", "-0x0.e38#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "-0.88796890669185542897832256944194",
+        "-0x0.e351ee261b3cad7894677374d#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-123.0", "-0x7b.0#7", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-0.88867",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "-0.88867",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "-0.88796890669185542897832256944194",
+        "-0x0.e351ee261b3cad7894677374d#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "-0.99999999999999999999999999999921",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-0.91113",
+        "-0x0.e94#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "-0.91113",
+        "-0x0.e94#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "-0.91173391478696503850924773819963",
+        "-0x0.e96764d2aa0034ce60b62d755#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "-0.91173391478696503850924773819884",
+        "-0x0.e96764d2aa0034ce60b62d754#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "-0.91173391478696503850924773819963",
+        "-0x0.e96764d2aa0034ce60b62d755#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("355.0", "0x163.0#9", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("355.0", "0x163.0#9", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 10, Up, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "-0.99999999954565898016593584169339",
+        "-0x0.fffffffe0c725f3ceecd7f4cc#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "-0.99999999954565898016593584169260",
+        "-0x0.fffffffe0c725f3ceecd7f4cb#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "-0.99999999954565898016593584169260",
+        "-0x0.fffffffe0c725f3ceecd7f4cb#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "-0.57617",
+        "-0x0.938#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "-0.57617",
+        "-0x0.938#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "-0.57538611195754904668824427596616",
+        "-0x0.934c81156e2606122aa6b4a3b#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "-0.57538611195754904668824427596537",
+        "-0x0.934c81156e2606122aa6b4a3a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "-0.57538611195754904668824427596616",
+        "-0x0.934c81156e2606122aa6b4a3b#100",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.94434",
+        "0x0.f1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.94434",
+        "0x0.f1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.94495694631473766438828400767662",
+        "0x0.f1e8b2cc8cc1656b6998d964e#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+}
+
+#[test]
+fn cos_prec_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).cos_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).cos_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.cos_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.cos_prec_round(1, Exact));
+    assert_panic!(X.cos_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.cos_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_cos_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (cos, o) = Float::cos_rational_prec(x.clone(), prec);
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, out_o);
+
+        let (cos, o) = Float::cos_rational_prec_ref(&x, prec);
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_cos, rug_o) = rug_cos_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "1.0", "0x1.0#1", Equal);
+    test("0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, "0.50", "0x0.8#1", Less);
+    test("1", 10, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "1",
+        100,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("-1", 1, "0.50", "0x0.8#1", Less);
+    test("-1", 10, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "-1",
+        100,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("1/2", 1, "1.0", "0x1.0#1", Greater);
+    test("1/2", 10, "0.87793", "0x0.e0c#10", Greater);
+    test(
+        "1/2",
+        100,
+        "0.87758256189037271611628158260408",
+        "0x0.e0a94032dbea7cedbddd9da30#100",
+        Greater,
+    );
+    test("1/3", 1, "1.0", "0x1.0#1", Greater);
+    test("1/3", 10, "0.94531", "0x0.f20#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test("-1/3", 1, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 10, "0.94531", "0x0.f20#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test("22/7", 1, "-1.0", "-0x1.0#1", Less);
+    test("22/7", 10, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "22/7",
+        100,
+        "-0.99999920053355290326833573965634",
+        "-0x0.fffff29651586c28bc01f9fb2#100",
+        Greater,
+    );
+    test("1000000", 1, "1.0", "0x1.0#1", Greater);
+    test("1000000", 10, "0.93652", "0x0.efc#10", Less);
+    test(
+        "1000000",
+        100,
+        "0.93675212753314478693853253507492",
+        "0x0.efcefcc836996357644d418cd#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn cos_rational_prec_fail() {
+    Float::cos_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn cos_rational_prec_ref_fail() {
+    Float::cos_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_cos_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (cos, o) = Float::cos_rational_prec_round(x.clone(), prec, rm);
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, out_o);
+
+        let (cos, o) = Float::cos_rational_prec_round_ref(&x, prec, rm);
+        assert!(cos.is_valid());
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1", 10, Floor, "0.54004", "0x0.8a4#10", Less);
+    test("1", 10, Ceiling, "0.54102", "0x0.8a8#10", Greater);
+    test("1", 10, Down, "0.54004", "0x0.8a4#10", Less);
+    test("1", 10, Up, "0.54102", "0x0.8a8#10", Greater);
+    test("1", 10, Nearest, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("-1", 10, Floor, "0.54004", "0x0.8a4#10", Less);
+    test("-1", 10, Ceiling, "0.54102", "0x0.8a8#10", Greater);
+    test("-1", 10, Down, "0.54004", "0x0.8a4#10", Less);
+    test("-1", 10, Up, "0.54102", "0x0.8a8#10", Greater);
+    test("-1", 10, Nearest, "0.54004", "0x0.8a4#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1/2", 10, Floor, "0.87695", "0x0.e08#10", Less);
+    test("1/2", 10, Ceiling, "0.87793", "0x0.e0c#10", Greater);
+    test("1/2", 10, Down, "0.87695", "0x0.e08#10", Less);
+    test("1/2", 10, Up, "0.87793", "0x0.e0c#10", Greater);
+    test("1/2", 10, Nearest, "0.87793", "0x0.e0c#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.87758256189037271611628158260408",
+        "0x0.e0a94032dbea7cedbddd9da30#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1/3", 10, Floor, "0.94434", "0x0.f1c#10", Less);
+    test("1/3", 10, Ceiling, "0.94531", "0x0.f20#10", Greater);
+    test("1/3", 10, Down, "0.94434", "0x0.f1c#10", Less);
+    test("1/3", 10, Up, "0.94531", "0x0.f20#10", Greater);
+    test("1/3", 10, Nearest, "0.94531", "0x0.f20#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 10, Floor, "0.94434", "0x0.f1c#10", Less);
+    test("-1/3", 10, Ceiling, "0.94531", "0x0.f20#10", Greater);
+    test("-1/3", 10, Down, "0.94434", "0x0.f1c#10", Less);
+    test("-1/3", 10, Up, "0.94531", "0x0.f20#10", Greater);
+    test("-1/3", 10, Nearest, "0.94531", "0x0.f20#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test("22/7", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("22/7", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("22/7", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("22/7", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("22/7", 10, Floor, "-1.0000", "-0x1.000#10", Less);
+    test("22/7", 10, Ceiling, "-0.99902", "-0x0.ffc#10", Greater);
+    test("22/7", 10, Down, "-0.99902", "-0x0.ffc#10", Greater);
+    test("22/7", 10, Up, "-1.0000", "-0x1.000#10", Less);
+    test("22/7", 10, Nearest, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "-0.99999920053355290326833573965634",
+        "-0x0.fffff29651586c28bc01f9fb2#100",
+        Greater,
+    );
+    test("1000000", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1000000", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1000000", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1000000", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1000000", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1000000", 10, Floor, "0.93652", "0x0.efc#10", Less);
+    test("1000000", 10, Ceiling, "0.93750", "0x0.f00#10", Greater);
+    test("1000000", 10, Down, "0.93652", "0x0.efc#10", Less);
+    test("1000000", 10, Up, "0.93750", "0x0.f00#10", Greater);
+    test("1000000", 10, Nearest, "0.93652", "0x0.efc#10", Less);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "0.93675212753314478693853253507492",
+        "0x0.efcefcc836996357644d418cd#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn cos_rational_prec_round_fail_1() {
+    Float::cos_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn cos_rational_prec_round_fail_2() {
+    Float::cos_rational_prec_round(Rational::ONE, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn cos_rational_prec_round_ref_fail() {
+    Float::cos_rational_prec_round_ref(&Rational::ONE, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn cos_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (cos, o) = x.clone().cos_prec_round(prec, rm);
+    assert!(cos.is_valid());
+
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(cos.is_nan(), !x.is_finite());
+    assert!(cos.is_nan() || (cos >= -1i32 && cos <= 1u32));
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+
+    let (cos_alt, o_alt) = (-&x).cos_prec_round(prec, rm);
+    assert_eq!(ComparableFloat(cos_alt), ComparableFloat(cos.clone()));
+    assert_eq!(o_alt, o);
+
+    if x.is_normal() {
+        let (cos_alt, o_alt) = Float::cos_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.cos_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(cos.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.cos_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        cos_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (cos, o) = Float::NAN.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cos, o) = Float::INFINITY.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cos, o) = Float::NEGATIVE_INFINITY.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::ZERO.cos_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+
+        assert_eq!(
+            Float::NEGATIVE_ZERO.cos_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn cos_prec_properties_helper(x: Float, prec: u64) {
+    let (cos, o) = x.clone().cos_prec(prec);
+    assert!(cos.is_valid());
+
+    let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(cos.is_nan() || (cos >= -1i32 && cos <= 1u32));
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+
+    let (cos_alt, o_alt) = (-&x).cos_prec(prec);
+    assert_eq!(ComparableFloat(cos_alt), ComparableFloat(cos.clone()));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        cos_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (cos, o) = Float::NAN.cos_prec(prec);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cos, o) = Float::INFINITY.cos_prec(prec);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cos, o) = Float::NEGATIVE_INFINITY.cos_prec(prec);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(Float::ZERO.cos_prec(prec), (Float::one_prec(prec), Equal));
+
+        assert_eq!(
+            Float::NEGATIVE_ZERO.cos_prec(prec),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn cos_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (cos, o) = x.clone().cos_round(rm);
+    assert!(cos.is_valid());
+
+    let (cos_alt, o_alt) = x.cos_round_ref(rm);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    assert!(cos.is_nan() || (cos >= -1i32 && cos <= 1u32));
+
+    let (cos_alt, o_alt) = (-&x).cos_round(rm);
+    assert_eq!(ComparableFloat(cos_alt), ComparableFloat(cos.clone()));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.cos_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(cos.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.cos_round_ref(Exact));
+    }
+}
+
+#[test]
+fn cos_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        cos_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (cos, o) = Float::NAN.cos_round(rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cos, o) = Float::INFINITY.cos_round(rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cos, o) = Float::NEGATIVE_INFINITY.cos_round(rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(Float::ZERO.cos_round(rm), (Float::ONE, Equal));
+
+        assert_eq!(Float::NEGATIVE_ZERO.cos_round(rm), (Float::ONE, Equal));
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn cos_properties_helper(x: Float) {
+    let cos = x.clone().cos();
+    assert!(cos.is_valid());
+
+    let cos_alt = (&x).cos();
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+    let mut x_alt = x.clone();
+    x_alt.cos_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+
+    let cos_alt = x.cos_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    let cos_alt = x.cos_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+    assert_eq!(cos.is_nan(), !x.is_finite());
+    assert!(cos.is_nan() || (cos >= -1i32 && cos <= 1u32));
+
+    let rug_cos = rug_cos(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos),
+    );
+}
+
+#[test]
+fn cos_properties() {
+    float_gen().test_properties(|x| {
+        cos_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn cos_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (cos, o) = Float::cos_rational_prec_round(x.clone(), prec, rm);
+    assert!(cos.is_valid());
+
+    let (cos_alt, o_alt) = Float::cos_rational_prec_round_ref(&x, prec, rm);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cos, rug_o) = rug_cos_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!cos.is_nan());
+    assert!(cos.is_nan() || (cos >= -1i32 && cos <= 1u32));
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+
+    let (cos_alt, o_alt) = Float::cos_rational_prec_round(-&x, prec, rm);
+    assert_eq!(ComparableFloat(cos_alt), ComparableFloat(cos.clone()));
+    assert_eq!(o_alt, o);
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::cos_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(cos.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::cos_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn cos_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        cos_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (cos, o) = Float::cos_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn cos_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (cos, o) = Float::cos_rational_prec(x.clone(), prec);
+    assert!(cos.is_valid());
+
+    let (cos_alt, o_alt) = Float::cos_rational_prec_ref(&x, prec);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (cos_alt, o_alt) = Float::cos_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (rug_cos, rug_o) = rug_cos_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(cos.is_nan() || (cos >= -1i32 && cos <= 1u32));
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn cos_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        cos_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (cos, o) = Float::cos_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_cos() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_cos(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::NAN);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, 1.0);
+    test::<f32>(-0.0, 1.0);
+    test::<f32>(1.0, 0.5403023);
+    test::<f32>(-1.0, 0.5403023);
+    test::<f32>(0.5, 0.87758255);
+    test::<f32>(-0.5, 0.87758255);
+    test::<f32>(2.0, -0.41614684);
+    test::<f32>(10.0, -0.8390715);
+    test::<f32>(0.001, 0.9999995);
+    test::<f32>(10000000000.0, 0.87311965);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::NAN);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, 1.0);
+    test::<f64>(-0.0, 1.0);
+    test::<f64>(1.0, 0.5403023058681398);
+    test::<f64>(-1.0, 0.5403023058681398);
+    test::<f64>(0.5, 0.8775825618903728);
+    test::<f64>(-0.5, 0.8775825618903728);
+    test::<f64>(2.0, -0.4161468365471424);
+    test::<f64>(10.0, -0.8390715290764524);
+    test::<f64>(0.001, 0.9999995000000417);
+    test::<f64>(10000000000.0, 0.873119622676856);
+    test::<f64>(1.0e300, -0.5753861119575491);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_cos_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let cos = primitive_float_cos(x);
+        assert_eq!(cos.is_nan(), !x.is_finite());
+        assert!(cos.is_nan() || (cos >= -T::ONE && cos <= T::ONE));
+        assert_eq!(NiceFloat(primitive_float_cos(-x)), NiceFloat(cos));
+    });
+}
+
+#[test]
+fn primitive_float_cos_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_cos_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_cos_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_cos_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 1.0);
+    test::<f32>("1", 0.5403023);
+    test::<f32>("-1", 0.5403023);
+    test::<f32>("1/2", 0.87758255);
+    test::<f32>("-1/2", 0.87758255);
+    test::<f32>("1/3", 0.94495696);
+    test::<f32>("22/7", -0.9999992);
+    test::<f32>("3/2", 0.0707372);
+    test::<f32>("1000000", 0.93675214);
+
+    test::<f64>("0", 1.0);
+    test::<f64>("1", 0.5403023058681398);
+    test::<f64>("-1", 0.5403023058681398);
+    test::<f64>("1/2", 0.8775825618903728);
+    test::<f64>("-1/2", 0.8775825618903728);
+    test::<f64>("1/3", 0.9449569463147377);
+    test::<f64>("22/7", -0.999999200533553);
+    test::<f64>("3/2", 0.0707372016677029);
+    test::<f64>("1000000", 0.9367521275331447);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_cos_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let cos = primitive_float_cos_rational::<T>(&x);
+        assert!(!cos.is_nan());
+        assert!(cos >= -T::ONE && cos <= T::ONE);
+        assert_eq!(
+            NiceFloat(primitive_float_cos_rational::<T>(&-&x)),
+            NiceFloat(cos)
+        );
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_cos_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_cos(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_cos_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_cos_rational_properties_helper);
+}
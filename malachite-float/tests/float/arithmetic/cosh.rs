@@ -0,0 +1,2888 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Cosh, CoshAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::cosh::{
+    primitive_float_cosh, primitive_float_cosh_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::cosh::{
+    rug_cosh, rug_cosh_prec, rug_cosh_prec_round, rug_cosh_rational_prec,
+    rug_cosh_rational_prec_round, rug_cosh_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_cosh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let cosh = x.clone().cosh();
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let cosh_alt = (&x).cosh();
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+
+        let mut cosh_alt = x.clone();
+        cosh_alt.cosh_assign();
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&cosh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "Infinity", "Infinity");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+    );
+    test("123.0", "0x7b.0#7", "1.317e53", "0x1.60E+44#7");
+    test("-123.0", "-0x7b.0#7", "1.317e53", "0x1.60E+44#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+    );
+    test("355.0", "0x163.0#9", "7.463e153", "0x8.e8E+127#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "Infinity",
+        "Infinity",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+    );
+}
+
+#[test]
+fn test_cosh_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_prec(prec);
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_ref(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_prec_assign(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_cosh, rug_o) = rug_cosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 10, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 100, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "9.6e52", "0x1.0E+44#1", Less);
+    test("123.0", "0x7b.0#7", 10, "1.3095e53", "0x1.5e0E+44#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "9.6e52", "0x1.0E+44#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "11.591953275521519213439083247288",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "7.6101251386622872728027748834452",
+        "0x7.9c31293d05a112a04c47df6f8#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, "6.7e153", "0x8.0E+127#1", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "7.4732770023626700693523672142916e153",
+        "0x8.eb09df85401ac1b9555c160fE+127#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "1.0", "0x1.0#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 10, "1.0000", "0x1.000#10", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+}
+
+#[test]
+fn cosh_prec_fail() {
+    assert_panic!(Float::NAN.cosh_prec(0));
+    assert_panic!(Float::NAN.cosh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.cosh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_cosh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_round(rm);
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cosh_alt, o_alt) = x.cosh_round_ref(rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_round_assign(rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cosh, rug_o) = rug_cosh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Down, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Down,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", Up, "Infinity", "Infinity", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.5430806348152437784779056207559",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.5430806348152437784779056207559",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "1.302e53", "0x1.5cE+44#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "1.302e53", "0x1.5cE+44#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.317e53", "0x1.60E+44#7", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "11.591953275521533",
+        "0xb.978a3ff72004#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "11.591953275521533",
+        "0xb.978a3ff72004#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "7.6101251386622879",
+        "0x7.9c31293d05a14#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "7.6101251386622879",
+        "0x7.9c31293d05a14#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "7.490e153",
+        "0x8.f0E+127#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "7.490e153",
+        "0x8.f0E+127#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "2.0985787164673868e323228496",
+        "0x7.ffffffffffffE+268435455#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "2.0985787164673868e323228496",
+        "0x7.ffffffffffffE+268435455#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Floor, "1.0", "0x1.0#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Down, "1.0", "0x1.0#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Up, "2.0", "0x2.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "1.0560718678299393895268647082654",
+        "0x1.0e5ab9d68e232bca22825de24#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "1.0560718678299393895268647082654",
+        "0x1.0e5ab9d68e232bca22825de24#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+}
+
+#[test]
+fn cosh_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.cosh_round(Exact));
+    assert_panic!(X.cosh_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.cosh_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_cosh_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_prec_round(prec, rm);
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_prec_round_assign(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cosh, rug_o) = rug_cosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Down,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Up,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.5430", "0x1.8b0#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.5449", "0x1.8b8#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "1.5430806348152437784779056207559",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, Floor, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "1.5430", "0x1.8b0#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "1.5449", "0x1.8b8#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "1.5430806348152437784779056207559",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "1.5430806348152437784779056207559",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "9.6e52", "0x1.0E+44#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "9.6e52", "0x1.0E+44#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "1.9e53", "0x2.0E+44#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "1.3097586593745313380944905126861e53",
+        "0x1.5e1152f9a8119393a111d9f32E+44#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Down,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Up,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "1.3097586593745313380944905126861e53",
+        "0x1.5e1152f9a8119393a111d9f32E+44#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "11.578",
+        "0xb.94#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "11.578",
+        "0xb.94#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "11.591953275521519213439083247275",
+        "0xb.978a3ff7200027b1b4b07db2#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "11.591953275521519213439083247288",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "11.591953275521519213439083247288",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "7.6172",
+        "0x7.9e#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "7.6172",
+        "0x7.9e#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "7.6101251386622872728027748834452",
+        "0x7.9c31293d05a112a04c47df6f8#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "7.6101251386622872728027748834515",
+        "0x7.9c31293d05a112a04c47df700#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "7.6101251386622872728027748834452",
+        "0x7.9c31293d05a112a04c47df6f8#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Floor,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "1.3e154",
+        "0x1.0E+128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Down,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Up,
+        "1.3e154",
+        "0x1.0E+128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Nearest,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "7.4633e153",
+        "0x8.e8E+127#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "7.4633e153",
+        "0x8.e8E+127#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "7.4732770023626700693523672142916e153",
+        "0x8.eb09df85401ac1b9555c160fE+127#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "7.4732770023626700693523672143022e153",
+        "0x8.eb09df85401ac1b9555c1610E+127#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "7.4732770023626700693523672142916e153",
+        "0x8.eb09df85401ac1b9555c160fE+127#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "2.0985787164673876924043581168822e323228496",
+        "0x7.ffffffffffffffffffffffff8E+268435455#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "1.0547",
+        "0x1.0e0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "1.0547",
+        "0x1.0e0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "1.0560718678299393895268647082654",
+        "0x1.0e5ab9d68e232bca22825de24#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+}
+
+#[test]
+fn cosh_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).cosh_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).cosh_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.cosh_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.cosh_prec_round(1, Exact));
+    assert_panic!(X.cosh_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.cosh_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_cosh_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (cosh, o) = Float::cosh_rational_prec(x.clone(), prec);
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (cosh, o) = Float::cosh_rational_prec_ref(&x, prec);
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_cosh, rug_o) = rug_cosh_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "1.0", "0x1.0#1", Equal);
+    test("0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, "2.0", "0x2.0#1", Greater);
+    test("1", 10, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "1",
+        100,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("-1", 1, "2.0", "0x2.0#1", Greater);
+    test("-1", 10, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "-1",
+        100,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("1/2", 1, "1.0", "0x1.0#1", Less);
+    test("1/2", 10, "1.1270", "0x1.208#10", Less);
+    test(
+        "1/2",
+        100,
+        "1.1276259652063807852262251614023",
+        "0x1.20ac1862ae8d0645823a4f060#100",
+        Less,
+    );
+    test("1/3", 1, "1.0", "0x1.0#1", Less);
+    test("1/3", 10, "1.0566", "0x1.0e8#10", Greater);
+    test(
+        "1/3",
+        100,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test("-1/3", 1, "1.0", "0x1.0#1", Less);
+    test("-1/3", 10, "1.0566", "0x1.0e8#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test("22/7", 1, "8.0", "0x8.0#1", Less);
+    test("22/7", 10, "11.609", "0xb.9c#10", Greater);
+    test(
+        "22/7",
+        100,
+        "11.606565803761966414401052945153",
+        "0xb.9b47e5820793d583f2840c93#100",
+        Greater,
+    );
+    test("1000000", 1, "1.5e434294", "0x4.0E+360673#1", Less);
+    test("1000000", 10, "1.5174e434294", "0x4.1eE+360673#10", Greater);
+    test(
+        "1000000",
+        100,
+        "1.5166076984010437725432010707093e434294",
+        "0x4.1d6ffdeb8a9180f8c69cf6170E+360673#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn cosh_rational_prec_fail() {
+    Float::cosh_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn cosh_rational_prec_ref_fail() {
+    Float::cosh_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_cosh_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (cosh, o) = Float::cosh_rational_prec_round(x.clone(), prec, rm);
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (cosh, o) = Float::cosh_rational_prec_round_ref(&x, prec, rm);
+        assert!(cosh.is_valid());
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cosh, rug_o) = rug_cosh_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1", 10, Floor, "1.5430", "0x1.8b0#10", Less);
+    test("1", 10, Ceiling, "1.5449", "0x1.8b8#10", Greater);
+    test("1", 10, Down, "1.5430", "0x1.8b0#10", Less);
+    test("1", 10, Up, "1.5449", "0x1.8b8#10", Greater);
+    test("1", 10, Nearest, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1", 10, Floor, "1.5430", "0x1.8b0#10", Less);
+    test("-1", 10, Ceiling, "1.5449", "0x1.8b8#10", Greater);
+    test("-1", 10, Down, "1.5430", "0x1.8b0#10", Less);
+    test("-1", 10, Up, "1.5449", "0x1.8b8#10", Greater);
+    test("-1", 10, Nearest, "1.5430", "0x1.8b0#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1/2", 10, Floor, "1.1270", "0x1.208#10", Less);
+    test("1/2", 10, Ceiling, "1.1289", "0x1.210#10", Greater);
+    test("1/2", 10, Down, "1.1270", "0x1.208#10", Less);
+    test("1/2", 10, Up, "1.1289", "0x1.210#10", Greater);
+    test("1/2", 10, Nearest, "1.1270", "0x1.208#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "1.1276259652063807852262251614023",
+        "0x1.20ac1862ae8d0645823a4f060#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1/3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1/3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1/3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1/3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1/3", 10, Floor, "1.0547", "0x1.0e0#10", Less);
+    test("1/3", 10, Ceiling, "1.0566", "0x1.0e8#10", Greater);
+    test("1/3", 10, Down, "1.0547", "0x1.0e0#10", Less);
+    test("1/3", 10, Up, "1.0566", "0x1.0e8#10", Greater);
+    test("1/3", 10, Nearest, "1.0566", "0x1.0e8#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1/3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1/3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("-1/3", 10, Floor, "1.0547", "0x1.0e0#10", Less);
+    test("-1/3", 10, Ceiling, "1.0566", "0x1.0e8#10", Greater);
+    test("-1/3", 10, Down, "1.0547", "0x1.0e0#10", Less);
+    test("-1/3", 10, Up, "1.0566", "0x1.0e8#10", Greater);
+    test("-1/3", 10, Nearest, "1.0566", "0x1.0e8#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test("22/7", 1, Floor, "8.0", "0x8.0#1", Less);
+    test("22/7", 1, Ceiling, "16.0", "0x1.0E+1#1", Greater);
+    test("22/7", 1, Down, "8.0", "0x8.0#1", Less);
+    test("22/7", 1, Up, "16.0", "0x1.0E+1#1", Greater);
+    test("22/7", 1, Nearest, "8.0", "0x8.0#1", Less);
+    test("22/7", 10, Floor, "11.594", "0xb.98#10", Less);
+    test("22/7", 10, Ceiling, "11.609", "0xb.9c#10", Greater);
+    test("22/7", 10, Down, "11.594", "0xb.98#10", Less);
+    test("22/7", 10, Up, "11.609", "0xb.9c#10", Greater);
+    test("22/7", 10, Nearest, "11.609", "0xb.9c#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "11.606565803761966414401052945153",
+        "0xb.9b47e5820793d583f2840c93#100",
+        Greater,
+    );
+    test("1000000", 1, Floor, "1.5e434294", "0x4.0E+360673#1", Less);
+    test(
+        "1000000",
+        1,
+        Ceiling,
+        "2.9e434294",
+        "0x8.0E+360673#1",
+        Greater,
+    );
+    test("1000000", 1, Down, "1.5e434294", "0x4.0E+360673#1", Less);
+    test("1000000", 1, Up, "2.9e434294", "0x8.0E+360673#1", Greater);
+    test("1000000", 1, Nearest, "1.5e434294", "0x4.0E+360673#1", Less);
+    test(
+        "1000000",
+        10,
+        Floor,
+        "1.5145e434294",
+        "0x4.1cE+360673#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Down,
+        "1.5145e434294",
+        "0x4.1cE+360673#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Up,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "1.5166076984010437725432010707093e434294",
+        "0x4.1d6ffdeb8a9180f8c69cf6170E+360673#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn cosh_rational_prec_round_fail_1() {
+    Float::cosh_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn cosh_rational_prec_round_fail_2() {
+    Float::cosh_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn cosh_rational_prec_round_ref_fail() {
+    Float::cosh_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cosh_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (cosh, o) = x.clone().cosh_prec_round(prec, rm);
+    assert!(cosh.is_valid());
+
+    let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, rm);
+    assert!(cosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cosh_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cosh, rug_o) = rug_cosh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(cosh.is_nan(), x.is_nan());
+    assert!(cosh.is_nan() || cosh >= 1u32);
+
+    if cosh.is_normal() {
+        assert_eq!(cosh.get_prec(), Some(prec));
+    }
+
+    let (cosh_alt, o_alt) = (-&x).cosh_prec_round(prec, rm);
+    assert_eq!(ComparableFloat(cosh_alt), ComparableFloat(cosh.clone()));
+    assert_eq!(o_alt, o);
+
+    if x.is_normal() {
+        let (cosh_alt, o_alt) = Float::cosh_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.cosh_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(cosh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.cosh_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn cosh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        cosh_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (cosh, o) = Float::NAN.cosh_prec_round(prec, rm);
+        assert!(cosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::INFINITY.cosh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::NEGATIVE_INFINITY.cosh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::ZERO.cosh_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(cosh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::NEGATIVE_ZERO.cosh_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(cosh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cosh_prec_properties_helper(x: Float, prec: u64) {
+    let (cosh, o) = x.clone().cosh_prec(prec);
+    assert!(cosh.is_valid());
+
+    let (cosh_alt, o_alt) = x.cosh_prec_ref(prec);
+    assert!(cosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cosh_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let (rug_cosh, rug_o) = rug_cosh_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cosh)),
+        ComparableFloatRef(&cosh)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(cosh.is_nan() || cosh >= 1u32);
+
+    if cosh.is_normal() {
+        assert_eq!(cosh.get_prec(), Some(prec));
+    }
+
+    let (cosh_alt, o_alt) = (-&x).cosh_prec(prec);
+    assert_eq!(ComparableFloat(cosh_alt), ComparableFloat(cosh.clone()));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn cosh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        cosh_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (cosh, o) = Float::NAN.cosh_prec(prec);
+        assert!(cosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::INFINITY.cosh_prec(prec);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::NEGATIVE_INFINITY.cosh_prec(prec);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::ZERO.cosh_prec(prec);
+        assert_eq!(
+            ComparableFloat(cosh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::NEGATIVE_ZERO.cosh_prec(prec);
+        assert_eq!(
+            ComparableFloat(cosh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cosh_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (cosh, o) = x.clone().cosh_round(rm);
+    assert!(cosh.is_valid());
+
+    let (cosh_alt, o_alt) = x.cosh_round_ref(rm);
+    assert!(cosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cosh_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let (cosh_alt, o_alt) = x.cosh_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    assert!(cosh.is_nan() || cosh >= 1u32);
+
+    let (cosh_alt, o_alt) = (-&x).cosh_round(rm);
+    assert_eq!(ComparableFloat(cosh_alt), ComparableFloat(cosh.clone()));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cosh, rug_o) = rug_cosh_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.cosh_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(cosh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.cosh_round_ref(Exact));
+    }
+}
+
+#[test]
+fn cosh_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        cosh_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (cosh, o) = Float::NAN.cosh_round(rm);
+        assert!(cosh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::INFINITY.cosh_round(rm);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::NEGATIVE_INFINITY.cosh_round(rm);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::ZERO.cosh_round(rm);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+
+        let (cosh, o) = Float::NEGATIVE_ZERO.cosh_round(rm);
+        assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cosh_properties_helper(x: Float) {
+    let cosh = x.clone().cosh();
+    assert!(cosh.is_valid());
+
+    let cosh_alt = (&x).cosh();
+    assert!(cosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+
+    let mut x_alt = x.clone();
+    x_alt.cosh_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+
+    let cosh_alt = x.cosh_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    let cosh_alt = x.cosh_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+
+    assert_eq!(cosh.is_nan(), x.is_nan());
+    assert!(cosh.is_nan() || cosh >= 1u32);
+
+    let rug_cosh = rug_cosh(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cosh)),
+        ComparableFloatRef(&cosh),
+    );
+}
+
+#[test]
+fn cosh_properties() {
+    float_gen().test_properties(|x| {
+        cosh_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cosh_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (cosh, o) = Float::cosh_rational_prec_round(x.clone(), prec, rm);
+    assert!(cosh.is_valid());
+
+    let (cosh_alt, o_alt) = Float::cosh_rational_prec_round_ref(&x, prec, rm);
+    assert!(cosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cosh, rug_o) = rug_cosh_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!cosh.is_nan());
+    assert!(cosh.is_nan() || cosh >= 1u32);
+
+    if cosh.is_normal() {
+        assert_eq!(cosh.get_prec(), Some(prec));
+    }
+
+    let (cosh_alt, o_alt) = Float::cosh_rational_prec_round(-&x, prec, rm);
+    assert_eq!(ComparableFloat(cosh_alt), ComparableFloat(cosh.clone()));
+    assert_eq!(o_alt, o);
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::cosh_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(cosh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::cosh_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn cosh_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        cosh_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (cosh, o) = Float::cosh_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(
+            ComparableFloat(cosh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cosh_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (cosh, o) = Float::cosh_rational_prec(x.clone(), prec);
+    assert!(cosh.is_valid());
+
+    let (cosh_alt, o_alt) = Float::cosh_rational_prec_ref(&x, prec);
+    assert!(cosh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let (cosh_alt, o_alt) = Float::cosh_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(o_alt, o);
+
+    let (rug_cosh, rug_o) = rug_cosh_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cosh)),
+        ComparableFloatRef(&cosh)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(cosh.is_nan() || cosh >= 1u32);
+
+    if cosh.is_normal() {
+        assert_eq!(cosh.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn cosh_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        cosh_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (cosh, o) = Float::cosh_rational_prec(Rational::ZERO, prec);
+        assert_eq!(
+            ComparableFloat(cosh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_cosh() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_cosh(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::INFINITY);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::INFINITY);
+    test::<f32>(0.0, 1.0);
+    test::<f32>(-0.0, 1.0);
+    test::<f32>(1.0, 1.5430807);
+    test::<f32>(-1.0, 1.5430807);
+    test::<f32>(0.5, 1.127626);
+    test::<f32>(-0.5, 1.127626);
+    test::<f32>(2.0, 3.7621956);
+    test::<f32>(10.0, 11013.233);
+    test::<f32>(0.001, 1.0000005);
+    test::<f32>(10000000000.0, f32::INFINITY);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::INFINITY);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::INFINITY);
+    test::<f64>(0.0, 1.0);
+    test::<f64>(-0.0, 1.0);
+    test::<f64>(1.0, 1.5430806348152437);
+    test::<f64>(-1.0, 1.5430806348152437);
+    test::<f64>(0.5, 1.1276259652063807);
+    test::<f64>(-0.5, 1.1276259652063807);
+    test::<f64>(2.0, 3.7621956910836314);
+    test::<f64>(10.0, 11013.232920103323);
+    test::<f64>(0.001, 1.0000005000000416);
+    test::<f64>(10000000000.0, f64::INFINITY);
+    test::<f64>(1.0e300, f64::INFINITY);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_cosh_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let cosh = primitive_float_cosh(x);
+        assert_eq!(cosh.is_nan(), x.is_nan());
+        assert!(cosh.is_nan() || cosh >= T::ONE);
+        assert_eq!(NiceFloat(primitive_float_cosh(-x)), NiceFloat(cosh));
+    });
+}
+
+#[test]
+fn primitive_float_cosh_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_cosh_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_cosh_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_cosh_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 1.0);
+    test::<f32>("1", 1.5430807);
+    test::<f32>("-1", 1.5430807);
+    test::<f32>("1/2", 1.127626);
+    test::<f32>("-1/2", 1.127626);
+    test::<f32>("1/3", 1.0560719);
+    test::<f32>("22/7", 11.606565);
+    test::<f32>("3/2", 2.3524096);
+    test::<f32>("1000000", f32::INFINITY);
+
+    test::<f64>("0", 1.0);
+    test::<f64>("1", 1.5430806348152437);
+    test::<f64>("-1", 1.5430806348152437);
+    test::<f64>("1/2", 1.1276259652063807);
+    test::<f64>("-1/2", 1.1276259652063807);
+    test::<f64>("1/3", 1.0560718678299394);
+    test::<f64>("22/7", 11.606565803761967);
+    test::<f64>("3/2", 2.352409615243247);
+    test::<f64>("1000000", f64::INFINITY);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_cosh_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let cosh = primitive_float_cosh_rational::<T>(&x);
+        assert!(!cosh.is_nan());
+        assert!(cosh.is_nan() || cosh >= T::ONE);
+        assert_eq!(
+            NiceFloat(primitive_float_cosh_rational::<T>(&-&x)),
+            NiceFloat(cosh)
+        );
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_cosh_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_cosh(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_cosh_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_cosh_rational_properties_helper);
+}
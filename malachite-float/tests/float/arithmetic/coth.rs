@@ -0,0 +1,2692 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Coth, CothAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::coth::{
+    primitive_float_coth, primitive_float_coth_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::coth::{
+    rug_coth, rug_coth_prec, rug_coth_prec_round, rug_coth_rational_prec,
+    rug_coth_rational_prec_round, rug_coth_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_coth() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let coth = x.clone().coth();
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+
+        let coth_alt = (&x).coth();
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+
+        let mut coth_alt = x.clone();
+        coth_alt.coth_assign();
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_coth(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&coth)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1");
+    test("-Infinity", "-Infinity", "-1.0", "-0x1.0#1");
+    test("0.0", "0x0.0", "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+    );
+    test("123.0", "0x7b.0#7", "1.000", "0x1.00#7");
+    test("-123.0", "-0x7b.0#7", "-1.000", "-0x1.00#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0037418731973204",
+        "0x1.00f53a37021e0#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.0087469295621194",
+        "0x1.023d3d2068e35#53",
+    );
+    test("355.0", "0x163.0#9", "1.000", "0x1.00#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "1.3e30", "0x1.0E+25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+    );
+}
+
+#[test]
+fn test_coth_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (coth, o) = x.clone().coth_prec(prec);
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+        assert_eq!(o, o_out);
+
+        let (coth_alt, o_alt) = x.coth_prec_ref(prec);
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut coth_alt = x.clone();
+        let o_alt = coth_alt.coth_prec_assign(prec);
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_coth, rug_o) = rug_coth_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_coth)),
+            ComparableFloatRef(&coth)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.0000",
+        "-0x1.000#10",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 10, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 100, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 10, "1.3125", "0x1.500#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "-1.3125", "-0x1.500#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.3130352854993313036361612469313",
+        "-0x1.50231499b6b1d49f813a188fa#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.3125",
+        "0x1.500#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "1.0000", "0x1.000#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "-1.0", "-0x1.0#1", Greater);
+    test("-123.0", "-0x7b.0#7", 10, "-1.0000", "-0x1.000#10", Greater);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.0039",
+        "0x1.010#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "1.0037418731973212891197620012805",
+        "0x1.00f53a37021e3cddb880cf7b4#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.0078",
+        "0x1.020#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.0087469295621195255197716792226",
+        "0x1.023d3d2068e35788b48bf4944#100",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 1, "1.0", "0x1.0#1", Less);
+    test("355.0", "0x163.0#9", 10, "1.0000", "0x1.000#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "1.3e30", "0x1.0E+25#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "3.1094",
+        "0x3.1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+}
+
+#[test]
+fn coth_prec_fail() {
+    assert_panic!(Float::NAN.coth_prec(0));
+    assert_panic!(Float::NAN.coth_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.coth_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_coth_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (coth, o) = x.clone().coth_round(rm);
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+        assert_eq!(o, o_out);
+
+        let (coth_alt, o_alt) = x.coth_round_ref(rm);
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut coth_alt = x.clone();
+        let o_alt = coth_alt.coth_round_assign(rm);
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_coth, rug_o) = rug_coth_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_coth)),
+                ComparableFloatRef(&coth),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Exact, "1.0", "0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Floor, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Down, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Up, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Nearest, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Exact, "-1.0", "-0x1.0#1", Equal);
+    test("0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Greater);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.3130352854993313036361612469298",
+        "0x1.50231499b6b1d49f813a188f8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.3130352854993313036361612469298",
+        "0x1.50231499b6b1d49f813a188f8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "1.000", "0x1.00#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "1.016", "0x1.04#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "1.000", "0x1.00#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.016", "0x1.04#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "1.000", "0x1.00#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-1.016", "-0x1.04#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-1.000",
+        "-0x1.00#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-1.000", "-0x1.00#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-1.016", "-0x1.04#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-1.000",
+        "-0x1.00#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "1.0037418731973204",
+        "0x1.00f53a37021e0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "1.0037418731973222",
+        "0x1.00f53a37021e8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "1.0037418731973204",
+        "0x1.00f53a37021e0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "1.0037418731973222",
+        "0x1.00f53a37021e8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "1.0037418731973204",
+        "0x1.00f53a37021e0#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.0087469295621194",
+        "0x1.023d3d2068e35#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.0087469295621196",
+        "0x1.023d3d2068e36#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.0087469295621194",
+        "0x1.023d3d2068e35#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.0087469295621196",
+        "0x1.023d3d2068e36#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.0087469295621194",
+        "0x1.023d3d2068e35#53",
+        Less,
+    );
+    test("355.0", "0x163.0#9", Floor, "1.000", "0x1.00#9", Less);
+    test("355.0", "0x163.0#9", Ceiling, "1.004", "0x1.01#9", Greater);
+    test("355.0", "0x163.0#9", Down, "1.000", "0x1.00#9", Less);
+    test("355.0", "0x163.0#9", Up, "1.004", "0x1.01#9", Greater);
+    test("355.0", "0x163.0#9", Nearest, "1.000", "0x1.00#9", Less);
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "1.0000000000000009",
+        "0x1.0000000000004#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "1.0000000000000009",
+        "0x1.0000000000004#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "2.5e30",
+        "0x2.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "2.5e30",
+        "0x2.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "3.1102966796194437013783752314146",
+        "0x3.1c3c6737d2a99a2538dde3dbc#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "3.1102966796194437013783752314146",
+        "0x3.1c3c6737d2a99a2538dde3dbc#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+}
+
+#[test]
+fn coth_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.coth_round(Exact));
+    assert_panic!(X.coth_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.coth_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_coth_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (coth, o) = x.clone().coth_prec_round(prec, rm);
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+        assert_eq!(o, o_out);
+
+        let (coth_alt, o_alt) = x.coth_prec_round_ref(prec, rm);
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut coth_alt = x.clone();
+        let o_alt = coth_alt.coth_prec_round_assign(prec, rm);
+        assert!(coth_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&coth), ComparableFloatRef(&coth_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_coth, rug_o) = rug_coth_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_coth)),
+                ComparableFloatRef(&coth)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Down, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "1.3125", "0x1.500#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.3145",
+        "0x1.508#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.3125", "0x1.500#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.3145", "0x1.508#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.3125", "0x1.500#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "1.3130352854993313036361612469298",
+        "0x1.50231499b6b1d49f813a188f8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-1.3145",
+        "-0x1.508#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-1.3125",
+        "-0x1.500#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-1.3125",
+        "-0x1.500#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-1.3145", "-0x1.508#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-1.3125",
+        "-0x1.500#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-1.3130352854993313036361612469313",
+        "-0x1.50231499b6b1d49f813a188fa#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-1.3130352854993313036361612469298",
+        "-0x1.50231499b6b1d49f813a188f8#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-1.3130352854993313036361612469313",
+        "-0x1.50231499b6b1d49f813a188fa#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.3125",
+        "0x1.500#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.3145",
+        "0x1.508#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.3125",
+        "0x1.500#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.3145",
+        "0x1.508#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.3125",
+        "0x1.500#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "1.3130352854993313036361612469298",
+        "0x1.50231499b6b1d49f813a188f8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "1.0000", "0x1.000#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "1.0000", "0x1.000#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "1.0020", "0x1.008#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-123.0", "-0x7b.0#7", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-1.0020",
+        "-0x1.008#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-1.0000",
+        "-0x1.000#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-1.0000",
+        "-0x1.000#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "-1.0020",
+        "-0x1.008#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-1.0000",
+        "-0x1.000#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "-1.0000000000000000000000000000016",
+        "-0x1.0000000000000000000000002#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "1.0020",
+        "0x1.008#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.0039",
+        "0x1.010#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "1.0020",
+        "0x1.008#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.0039",
+        "0x1.010#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.0039",
+        "0x1.010#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "1.0037418731973212891197620012789",
+        "0x1.00f53a37021e3cddb880cf7b2#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "1.0037418731973212891197620012805",
+        "0x1.00f53a37021e3cddb880cf7b4#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "1.0037418731973212891197620012805",
+        "0x1.00f53a37021e3cddb880cf7b4#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.0078",
+        "0x1.020#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.0098",
+        "0x1.028#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.0078",
+        "0x1.020#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.0098",
+        "0x1.028#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.0078",
+        "0x1.020#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "1.0087469295621195255197716792210",
+        "0x1.023d3d2068e35788b48bf4942#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "1.0087469295621195255197716792226",
+        "0x1.023d3d2068e35788b48bf4944#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "1.0087469295621195255197716792226",
+        "0x1.023d3d2068e35788b48bf4944#100",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("355.0", "0x163.0#9", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("355.0", "0x163.0#9", 1, Down, "1.0", "0x1.0#1", Less);
+    test("355.0", "0x163.0#9", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("355.0", "0x163.0#9", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 10, Down, "1.0000", "0x1.000#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "2.5e30",
+        "0x2.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "2.5e30",
+        "0x2.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.2701e30",
+        "0x1.008E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.2701e30",
+        "0x1.008E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "1267650600228229401496703205378.0",
+        "0x10000000000000000000000002.0#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "3.1094",
+        "0x3.1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "3.1133",
+        "0x3.1d#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "3.1094",
+        "0x3.1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "3.1133",
+        "0x3.1d#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "3.1094",
+        "0x3.1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "3.1102966796194437013783752314146",
+        "0x3.1c3c6737d2a99a2538dde3dbc#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+}
+
+#[test]
+fn coth_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).coth_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).coth_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.coth_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.coth_prec_round(1, Exact));
+    assert_panic!(X.coth_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.coth_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_coth_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (coth, o) = Float::coth_rational_prec(x.clone(), prec);
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+        assert_eq!(o, out_o);
+
+        let (coth, o) = Float::coth_rational_prec_ref(&x, prec);
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_coth, rug_o) = rug_coth_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_coth)),
+            ComparableFloatRef(&coth)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "Infinity", "Infinity", Equal);
+    test("0", 10, "Infinity", "Infinity", Equal);
+    test("0", 100, "Infinity", "Infinity", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Less);
+    test("1", 10, "1.3125", "0x1.500#10", Less);
+    test(
+        "1",
+        100,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test("-1", 1, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 10, "-1.3125", "-0x1.500#10", Greater);
+    test(
+        "-1",
+        100,
+        "-1.3130352854993313036361612469313",
+        "-0x1.50231499b6b1d49f813a188fa#100",
+        Less,
+    );
+    test("1/2", 1, "2.0", "0x2.0#1", Less);
+    test("1/2", 10, "2.1641", "0x2.2a#10", Greater);
+    test(
+        "1/2",
+        100,
+        "2.1639534137386528487700040102170",
+        "0x2.29f8d9d61337dbaab838a8ad8#100",
+        Less,
+    );
+    test("1/3", 1, "4.0", "0x4.0#1", Greater);
+    test("1/3", 10, "3.1094", "0x3.1c#10", Less);
+    test(
+        "1/3",
+        100,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+    test("-1/3", 1, "-4.0", "-0x4.0#1", Less);
+    test("-1/3", 10, "-3.1094", "-0x3.1c#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "-3.1102966796194437013783752314178",
+        "-0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Less,
+    );
+    test("22/7", 1, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, "1.0039", "0x1.010#10", Greater);
+    test(
+        "22/7",
+        100,
+        "1.0037324043984771186938350250959",
+        "0x1.00f49b5ad3b018d5106a69234#100",
+        Greater,
+    );
+    test("1000000", 1, "1.0", "0x1.0#1", Less);
+    test("1000000", 10, "1.0000", "0x1.000#10", Less);
+    test(
+        "1000000",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn coth_rational_prec_fail() {
+    Float::coth_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn coth_rational_prec_ref_fail() {
+    Float::coth_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_coth_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (coth, o) = Float::coth_rational_prec_round(x.clone(), prec, rm);
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+        assert_eq!(o, out_o);
+
+        let (coth, o) = Float::coth_rational_prec_round_ref(&x, prec, rm);
+        assert!(coth.is_valid());
+        assert_eq!(coth.to_string(), out);
+        assert_eq!(to_hex_string(&coth), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_coth, rug_o) = rug_coth_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_coth)),
+                ComparableFloatRef(&coth)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("0", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", 1, Down, "Infinity", "Infinity", Equal);
+    test("0", 1, Up, "Infinity", "Infinity", Equal);
+    test("0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("0", 10, Floor, "Infinity", "Infinity", Equal);
+    test("0", 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", 10, Down, "Infinity", "Infinity", Equal);
+    test("0", 10, Up, "Infinity", "Infinity", Equal);
+    test("0", 10, Nearest, "Infinity", "Infinity", Equal);
+    test("0", 10, Exact, "Infinity", "Infinity", Equal);
+    test("0", 100, Nearest, "Infinity", "Infinity", Equal);
+    test("1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1", 10, Floor, "1.3125", "0x1.500#10", Less);
+    test("1", 10, Ceiling, "1.3145", "0x1.508#10", Greater);
+    test("1", 10, Down, "1.3125", "0x1.500#10", Less);
+    test("1", 10, Up, "1.3145", "0x1.508#10", Greater);
+    test("1", 10, Nearest, "1.3125", "0x1.500#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "1.3130352854993313036361612469313",
+        "0x1.50231499b6b1d49f813a188fa#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1", 1, Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 10, Floor, "-1.3145", "-0x1.508#10", Less);
+    test("-1", 10, Ceiling, "-1.3125", "-0x1.500#10", Greater);
+    test("-1", 10, Down, "-1.3125", "-0x1.500#10", Greater);
+    test("-1", 10, Up, "-1.3145", "-0x1.508#10", Less);
+    test("-1", 10, Nearest, "-1.3125", "-0x1.500#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-1.3130352854993313036361612469313",
+        "-0x1.50231499b6b1d49f813a188fa#100",
+        Less,
+    );
+    test("1/2", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1/2", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1/2", 1, Down, "2.0", "0x2.0#1", Less);
+    test("1/2", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("1/2", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/2", 10, Floor, "2.1602", "0x2.29#10", Less);
+    test("1/2", 10, Ceiling, "2.1641", "0x2.2a#10", Greater);
+    test("1/2", 10, Down, "2.1602", "0x2.29#10", Less);
+    test("1/2", 10, Up, "2.1641", "0x2.2a#10", Greater);
+    test("1/2", 10, Nearest, "2.1641", "0x2.2a#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "2.1639534137386528487700040102170",
+        "0x2.29f8d9d61337dbaab838a8ad8#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1/3", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1/3", 1, Down, "2.0", "0x2.0#1", Less);
+    test("1/3", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("1/3", 1, Nearest, "4.0", "0x4.0#1", Greater);
+    test("1/3", 10, Floor, "3.1094", "0x3.1c#10", Less);
+    test("1/3", 10, Ceiling, "3.1133", "0x3.1d#10", Greater);
+    test("1/3", 10, Down, "3.1094", "0x3.1c#10", Less);
+    test("1/3", 10, Up, "3.1133", "0x3.1d#10", Greater);
+    test("1/3", 10, Nearest, "3.1094", "0x3.1c#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "3.1102966796194437013783752314178",
+        "0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Greater,
+    );
+    test("-1/3", 1, Floor, "-4.0", "-0x4.0#1", Less);
+    test("-1/3", 1, Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("-1/3", 1, Down, "-2.0", "-0x2.0#1", Greater);
+    test("-1/3", 1, Up, "-4.0", "-0x4.0#1", Less);
+    test("-1/3", 1, Nearest, "-4.0", "-0x4.0#1", Less);
+    test("-1/3", 10, Floor, "-3.1133", "-0x3.1d#10", Less);
+    test("-1/3", 10, Ceiling, "-3.1094", "-0x3.1c#10", Greater);
+    test("-1/3", 10, Down, "-3.1094", "-0x3.1c#10", Greater);
+    test("-1/3", 10, Up, "-3.1133", "-0x3.1d#10", Less);
+    test("-1/3", 10, Nearest, "-3.1094", "-0x3.1c#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-3.1102966796194437013783752314178",
+        "-0x3.1c3c6737d2a99a2538dde3dc0#100",
+        Less,
+    );
+    test("22/7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, Floor, "1.0020", "0x1.008#10", Less);
+    test("22/7", 10, Ceiling, "1.0039", "0x1.010#10", Greater);
+    test("22/7", 10, Down, "1.0020", "0x1.008#10", Less);
+    test("22/7", 10, Up, "1.0039", "0x1.010#10", Greater);
+    test("22/7", 10, Nearest, "1.0039", "0x1.010#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "1.0037324043984771186938350250959",
+        "0x1.00f49b5ad3b018d5106a69234#100",
+        Greater,
+    );
+    test("1000000", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1000000", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1000000", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1000000", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1000000", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1000000", 10, Floor, "1.0000", "0x1.000#10", Less);
+    test("1000000", 10, Ceiling, "1.0020", "0x1.008#10", Greater);
+    test("1000000", 10, Down, "1.0000", "0x1.000#10", Less);
+    test("1000000", 10, Up, "1.0020", "0x1.008#10", Greater);
+    test("1000000", 10, Nearest, "1.0000", "0x1.000#10", Less);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn coth_rational_prec_round_fail_1() {
+    Float::coth_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn coth_rational_prec_round_fail_2() {
+    Float::coth_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn coth_rational_prec_round_ref_fail() {
+    Float::coth_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn coth_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (coth, o) = x.clone().coth_prec_round(prec, rm);
+    assert!(coth.is_valid());
+
+    let (coth_alt, o_alt) = x.coth_prec_round_ref(prec, rm);
+    assert!(coth_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.coth_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_coth, rug_o) = rug_coth_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_coth)),
+            ComparableFloatRef(&coth)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(coth.is_nan(), x.is_nan());
+    assert!(coth.is_nan() || coth <= -1i32 || coth >= 1u32);
+
+    if coth.is_normal() {
+        assert_eq!(coth.get_prec(), Some(prec));
+    }
+
+    let (coth_alt, o_alt) = (-&x).coth_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-coth_alt), ComparableFloat(coth.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (coth_alt, o_alt) = Float::coth_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.coth_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(coth.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.coth_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn coth_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        coth_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (coth, o) = Float::NAN.coth_prec_round(prec, rm);
+        assert!(coth.is_nan());
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::INFINITY.coth_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(coth),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::NEGATIVE_INFINITY.coth_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(coth),
+            ComparableFloat(-Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::ZERO.coth_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(coth), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::NEGATIVE_ZERO.coth_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(coth),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn coth_prec_properties_helper(x: Float, prec: u64) {
+    let (coth, o) = x.clone().coth_prec(prec);
+    assert!(coth.is_valid());
+
+    let (coth_alt, o_alt) = x.coth_prec_ref(prec);
+    assert!(coth_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.coth_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let (coth_alt, o_alt) = x.coth_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let (rug_coth, rug_o) = rug_coth_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_coth)),
+        ComparableFloatRef(&coth)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(coth.is_nan() || coth <= -1i32 || coth >= 1u32);
+
+    if coth.is_normal() {
+        assert_eq!(coth.get_prec(), Some(prec));
+    }
+
+    let (coth_alt, o_alt) = (-&x).coth_prec(prec);
+    assert_eq!(ComparableFloat(-coth_alt), ComparableFloat(coth.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn coth_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        coth_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (coth, o) = Float::NAN.coth_prec(prec);
+        assert!(coth.is_nan());
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::INFINITY.coth_prec(prec);
+        assert_eq!(
+            ComparableFloat(coth),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::NEGATIVE_INFINITY.coth_prec(prec);
+        assert_eq!(
+            ComparableFloat(coth),
+            ComparableFloat(-Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::ZERO.coth_prec(prec);
+        assert_eq!(ComparableFloat(coth), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::NEGATIVE_ZERO.coth_prec(prec);
+        assert_eq!(
+            ComparableFloat(coth),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn coth_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (coth, o) = x.clone().coth_round(rm);
+    assert!(coth.is_valid());
+
+    let (coth_alt, o_alt) = x.coth_round_ref(rm);
+    assert!(coth_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.coth_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let (coth_alt, o_alt) = x.coth_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    assert!(coth.is_nan() || coth <= -1i32 || coth >= 1u32);
+
+    let (coth_alt, o_alt) = (-&x).coth_round(-rm);
+    assert_eq!(ComparableFloat(-coth_alt), ComparableFloat(coth.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_coth, rug_o) = rug_coth_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_coth)),
+            ComparableFloatRef(&coth)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.coth_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(coth.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.coth_round_ref(Exact));
+    }
+}
+
+#[test]
+fn coth_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        coth_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (coth, o) = Float::NAN.coth_round(rm);
+        assert!(coth.is_nan());
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::INFINITY.coth_round(rm);
+        assert_eq!(ComparableFloat(coth), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::NEGATIVE_INFINITY.coth_round(rm);
+        assert_eq!(ComparableFloat(coth), ComparableFloat(Float::NEGATIVE_ONE));
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::ZERO.coth_round(rm);
+        assert_eq!(ComparableFloat(coth), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (coth, o) = Float::NEGATIVE_ZERO.coth_round(rm);
+        assert_eq!(
+            ComparableFloat(coth),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn coth_properties_helper(x: Float) {
+    let coth = x.clone().coth();
+    assert!(coth.is_valid());
+
+    let coth_alt = (&x).coth();
+    assert!(coth_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+
+    let mut x_alt = x.clone();
+    x_alt.coth_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&coth));
+
+    let coth_alt = x.coth_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    let coth_alt = x.coth_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+
+    assert_eq!(coth.is_nan(), x.is_nan());
+    assert!(coth.is_nan() || coth <= -1i32 || coth >= 1u32);
+
+    let rug_coth = rug_coth(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_coth)),
+        ComparableFloatRef(&coth),
+    );
+}
+
+#[test]
+fn coth_properties() {
+    float_gen().test_properties(|x| {
+        coth_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn coth_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (coth, o) = Float::coth_rational_prec_round(x.clone(), prec, rm);
+    assert!(coth.is_valid());
+
+    let (coth_alt, o_alt) = Float::coth_rational_prec_round_ref(&x, prec, rm);
+    assert!(coth_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_coth, rug_o) = rug_coth_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_coth)),
+            ComparableFloatRef(&coth)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!coth.is_nan());
+    assert!(coth.is_nan() || coth <= -1i32 || coth >= 1u32);
+
+    if coth.is_normal() {
+        assert_eq!(coth.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (coth_alt, o_alt) = Float::coth_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-coth_alt), ComparableFloat(coth.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::coth_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(coth.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::coth_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn coth_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        coth_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (coth, o) = Float::coth_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(coth), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn coth_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (coth, o) = Float::coth_rational_prec(x.clone(), prec);
+    assert!(coth.is_valid());
+
+    let (coth_alt, o_alt) = Float::coth_rational_prec_ref(&x, prec);
+    assert!(coth_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let (coth_alt, o_alt) = Float::coth_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&coth_alt), ComparableFloatRef(&coth));
+    assert_eq!(o_alt, o);
+
+    let (rug_coth, rug_o) = rug_coth_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_coth)),
+        ComparableFloatRef(&coth)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(coth.is_nan() || coth <= -1i32 || coth >= 1u32);
+
+    if coth.is_normal() {
+        assert_eq!(coth.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn coth_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        coth_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (coth, o) = Float::coth_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(coth), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_coth() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_coth(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 1.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, -1.0);
+    test::<f32>(0.0, f32::INFINITY);
+    test::<f32>(-0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(1.0, 1.3130352);
+    test::<f32>(-1.0, -1.3130352);
+    test::<f32>(0.5, 2.1639533);
+    test::<f32>(-0.5, -2.1639533);
+    test::<f32>(2.0, 1.0373148);
+    test::<f32>(10.0, 1.0);
+    test::<f32>(0.001, 1000.0003);
+    test::<f32>(10000000000.0, 1.0);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 1.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, -1.0);
+    test::<f64>(0.0, f64::INFINITY);
+    test::<f64>(-0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(1.0, 1.3130352854993312);
+    test::<f64>(-1.0, -1.3130352854993312);
+    test::<f64>(0.5, 2.163953413738653);
+    test::<f64>(-0.5, -2.163953413738653);
+    test::<f64>(2.0, 1.0373147207275482);
+    test::<f64>(10.0, 1.0000000041223072);
+    test::<f64>(0.001, 1000.0003333333111);
+    test::<f64>(10000000000.0, 1.0);
+    test::<f64>(1.0e300, 1.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_coth_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let coth = primitive_float_coth(x);
+        assert_eq!(coth.is_nan(), x.is_nan());
+        assert!(coth.is_nan() || coth <= -T::ONE || coth >= T::ONE);
+        assert_eq!(NiceFloat(primitive_float_coth(-x)), NiceFloat(-coth));
+    });
+}
+
+#[test]
+fn primitive_float_coth_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_coth_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_coth_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_coth_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", f32::INFINITY);
+    test::<f32>("1", 1.3130352);
+    test::<f32>("-1", -1.3130352);
+    test::<f32>("1/2", 2.1639533);
+    test::<f32>("-1/2", -2.1639533);
+    test::<f32>("1/3", 3.1102967);
+    test::<f32>("22/7", 1.0037324);
+    test::<f32>("3/2", 1.1047914);
+    test::<f32>("1000000", 1.0);
+
+    test::<f64>("0", f64::INFINITY);
+    test::<f64>("1", 1.3130352854993312);
+    test::<f64>("-1", -1.3130352854993312);
+    test::<f64>("1/2", 2.163953413738653);
+    test::<f64>("-1/2", -2.163953413738653);
+    test::<f64>("1/3", 3.110296679619444);
+    test::<f64>("22/7", 1.0037324043984772);
+    test::<f64>("3/2", 1.104791392982512);
+    test::<f64>("1000000", 1.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_coth_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let coth = primitive_float_coth_rational::<T>(&x);
+        assert!(!coth.is_nan());
+        assert!(coth.is_nan() || coth <= -T::ONE || coth >= T::ONE);
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_coth_rational::<T>(&-&x)),
+                NiceFloat(-coth)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_coth_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_coth(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_coth_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_coth_rational_properties_helper);
+}
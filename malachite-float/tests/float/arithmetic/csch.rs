@@ -0,0 +1,2914 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Csch, CschAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::csch::{
+    primitive_float_csch, primitive_float_csch_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::csch::{
+    rug_csch, rug_csch_prec, rug_csch_prec_round, rug_csch_rational_prec,
+    rug_csch_rational_prec_round, rug_csch_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_csch() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let csch = x.clone().csch();
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+
+        let csch_alt = (&x).csch();
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+
+        let mut csch_alt = x.clone();
+        csch_alt.csch_assign();
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_csch(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&csch)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+    );
+    test("123.0", "0x7b.0#7", "7.667e-54", "0xb.cE-45#7");
+    test("-123.0", "-0x7b.0#7", "-7.667e-54", "-0xb.cE-45#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.086589537530046945",
+        "0x0.162abb5fde0870#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.13255326439210666",
+        "0x0.21ef02bf8ac9a6#53",
+    );
+    test("355.0", "0x163.0#9", "1.337e-154", "0x1.cbE-128#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "0.0",
+        "0x0.0",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "1.3e30", "0x1.0E+25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+    );
+}
+
+#[test]
+fn test_csch_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (csch, o) = x.clone().csch_prec(prec);
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+        assert_eq!(o, o_out);
+
+        let (csch_alt, o_alt) = x.csch_prec_ref(prec);
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut csch_alt = x.clone();
+        let o_alt = csch_alt.csch_prec_assign(prec);
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_csch, rug_o) = rug_csch_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_csch)),
+            ComparableFloatRef(&csch)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 10, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 100, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.85059", "0x0.d9c#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.85059", "-0x0.d9c#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.85091812823932154513384276328721",
+        "-0x0.d9d5c53c5c86070b36fe901e0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.85059",
+        "0x0.d9c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "5.2e-54", "0x8.0E-45#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "-5.2e-54",
+        "-0x8.0E-45#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "-7.6366e-54",
+        "-0xb.b4E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-7.6349943773423492514662264337825e-54",
+        "-0xb.b35a2eec278be3598774c212E-45#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.086548",
+        "0x0.1628#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.086589537530046952472299189409366",
+        "0x0.162abb5fde08708a3696958e62#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.13257",
+        "0x0.21f#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.13255326439210666046007116123203",
+        "0x0.21ef02bf8ac9a645c7f8821da0#100",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 1, "1.5e-154", "0x2.0E-128#1", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "1.3381010762532298264025680134556e-154",
+        "0x1.cb4a26f3c63f58179849f6458E-128#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "2.9453",
+        "0x2.f2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+}
+
+#[test]
+fn csch_prec_fail() {
+    assert_panic!(Float::NAN.csch_prec(0));
+    assert_panic!(Float::NAN.csch_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.csch_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_csch_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (csch, o) = x.clone().csch_round(rm);
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+        assert_eq!(o, o_out);
+
+        let (csch_alt, o_alt) = x.csch_round_ref(rm);
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut csch_alt = x.clone();
+        let o_alt = csch_alt.csch_round_assign(rm);
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_csch, rug_o) = rug_csch_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_csch)),
+                ComparableFloatRef(&csch),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", Down, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", Up, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", Exact, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.85091812823932154513384276328642",
+        "0x0.d9d5c53c5c86070b36fe901df#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.85091812823932154513384276328642",
+        "0x0.d9d5c53c5c86070b36fe901df#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "7.586e-54", "0xb.aE-45#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "7.667e-54",
+        "0xb.cE-45#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "7.586e-54", "0xb.aE-45#7", Less);
+    test("123.0", "0x7b.0#7", Up, "7.667e-54", "0xb.cE-45#7", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "7.667e-54",
+        "0xb.cE-45#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "-7.667e-54",
+        "-0xb.cE-45#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-7.586e-54",
+        "-0xb.aE-45#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "-7.586e-54",
+        "-0xb.aE-45#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "-7.667e-54",
+        "-0xb.cE-45#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-7.667e-54",
+        "-0xb.cE-45#7",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.086589537530046945",
+        "0x0.162abb5fde0870#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.086589537530047056",
+        "0x0.162abb5fde0878#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.086589537530046945",
+        "0x0.162abb5fde0870#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.086589537530047056",
+        "0x0.162abb5fde0878#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.086589537530046945",
+        "0x0.162abb5fde0870#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.13255326439210666",
+        "0x0.21ef02bf8ac9a6#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.13255326439210668",
+        "0x0.21ef02bf8ac9a8#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.13255326439210666",
+        "0x0.21ef02bf8ac9a6#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.13255326439210668",
+        "0x0.21ef02bf8ac9a8#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.13255326439210666",
+        "0x0.21ef02bf8ac9a6#53",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "1.337e-154",
+        "0x1.cbE-128#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "1.340e-154",
+        "0x1.ccE-128#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "1.337e-154",
+        "0x1.cbE-128#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "1.340e-154",
+        "0x1.ccE-128#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "1.337e-154",
+        "0x1.cbE-128#9",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "2.3825649048879511e-323228497",
+        "0x1.0000000000000E-268435456#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "2.3825649048879511e-323228497",
+        "0x1.0000000000000E-268435456#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "2.9451562666948143476455860034919",
+        "0x2.f1f5c2d7104d708fac22e7c68#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "2.9451562666948143476455860034919",
+        "0x2.f1f5c2d7104d708fac22e7c68#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+}
+
+#[test]
+fn csch_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.csch_round(Exact));
+    assert_panic!(X.csch_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.csch_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_csch_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (csch, o) = x.clone().csch_prec_round(prec, rm);
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+        assert_eq!(o, o_out);
+
+        let (csch_alt, o_alt) = x.csch_prec_round_ref(prec, rm);
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut csch_alt = x.clone();
+        let o_alt = csch_alt.csch_prec_round_assign(prec, rm);
+        assert!(csch_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&csch), ComparableFloatRef(&csch_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_csch, rug_o) = rug_csch_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_csch)),
+                ComparableFloatRef(&csch)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "-0.0", "-0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.85059", "0x0.d9c#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.85156",
+        "0x0.da0#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.85059", "0x0.d9c#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.85156", "0x0.da0#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.85059", "0x0.d9c#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.85091812823932154513384276328642",
+        "0x0.d9d5c53c5c86070b36fe901df#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.85156",
+        "-0x0.da0#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.85059",
+        "-0x0.d9c#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.85059",
+        "-0x0.d9c#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.85156", "-0x0.da0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.85059",
+        "-0x0.d9c#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.85091812823932154513384276328721",
+        "-0x0.d9d5c53c5c86070b36fe901e0#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.85091812823932154513384276328642",
+        "-0x0.d9d5c53c5c86070b36fe901df#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.85091812823932154513384276328721",
+        "-0x0.d9d5c53c5c86070b36fe901e0#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.85059",
+        "0x0.d9c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.85156",
+        "0x0.da0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.85059",
+        "0x0.d9c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.85156",
+        "0x0.da0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.85059",
+        "0x0.d9c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.85091812823932154513384276328642",
+        "0x0.d9d5c53c5c86070b36fe901df#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.0e-53",
+        "0x1.0E-44#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "5.2e-54", "0x8.0E-45#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "1.0e-53",
+        "0x1.0E-44#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "7.6264e-54",
+        "0xb.b0E-45#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "7.6264e-54",
+        "0xb.b0E-45#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "7.6349943773423492514662264337907e-54",
+        "0xb.b35a2eec278be3598774c213E-45#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "-1.0e-53",
+        "-0x1.0E-44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-5.2e-54",
+        "-0x8.0E-45#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Down,
+        "-5.2e-54",
+        "-0x8.0E-45#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Up,
+        "-1.0e-53",
+        "-0x1.0E-44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "-5.2e-54",
+        "-0x8.0E-45#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-7.6366e-54",
+        "-0xb.b4E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-7.6264e-54",
+        "-0xb.b0E-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-7.6264e-54",
+        "-0xb.b0E-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "-7.6366e-54",
+        "-0xb.b4E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-7.6366e-54",
+        "-0xb.b4E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "-7.6349943773423492514662264337907e-54",
+        "-0xb.b35a2eec278be3598774c213E-45#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "-7.6349943773423492514662264337825e-54",
+        "-0xb.b35a2eec278be3598774c212E-45#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "-7.6349943773423492514662264337825e-54",
+        "-0xb.b35a2eec278be3598774c212E-45#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.086548",
+        "0x0.1628#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.086670",
+        "0x0.1630#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.086548",
+        "0x0.1628#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.086670",
+        "0x0.1630#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.086548",
+        "0x0.1628#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "0.086589537530046952472299189409366",
+        "0x0.162abb5fde08708a3696958e62#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "0.086589537530046952472299189409465",
+        "0x0.162abb5fde08708a3696958e64#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.086589537530046952472299189409366",
+        "0x0.162abb5fde08708a3696958e62#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.13232",
+        "0x0.21e#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.13257",
+        "0x0.21f#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.13232",
+        "0x0.21e#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.13257",
+        "0x0.21f#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.13257",
+        "0x0.21f#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.13255326439210666046007116123183",
+        "0x0.21ef02bf8ac9a645c7f8821d9c#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.13255326439210666046007116123203",
+        "0x0.21ef02bf8ac9a645c7f8821da0#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.13255326439210666046007116123203",
+        "0x0.21ef02bf8ac9a645c7f8821da0#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Floor,
+        "7.5e-155",
+        "0x1.0E-128#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "1.5e-154",
+        "0x2.0E-128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Down,
+        "7.5e-155",
+        "0x1.0E-128#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Up,
+        "1.5e-154",
+        "0x2.0E-128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Nearest,
+        "1.5e-154",
+        "0x2.0E-128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "1.3373e-154",
+        "0x1.cb0E-128#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "1.3373e-154",
+        "0x1.cb0E-128#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "1.3381010762532298264025680134556e-154",
+        "0x1.cb4a26f3c63f58179849f6458E-128#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "1.3381010762532298264025680134568e-154",
+        "0x1.cb4a26f3c63f58179849f645aE-128#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "1.3381010762532298264025680134556e-154",
+        "0x1.cb4a26f3c63f58179849f6458E-128#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "2.3825649048879510732161697817327e-323228497",
+        "0x1.0000000000000000000000000E-268435456#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.2664e30",
+        "0xf.fcE+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "1.2664e30",
+        "0xf.fcE+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "1267650600228229401496703205375.0",
+        "0xfffffffffffffffffffffffff.0#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "2.9414",
+        "0x2.f1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "2.9453",
+        "0x2.f2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "2.9414",
+        "0x2.f1#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "2.9453",
+        "0x2.f2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "2.9453",
+        "0x2.f2#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "2.9451562666948143476455860034919",
+        "0x2.f1f5c2d7104d708fac22e7c68#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+}
+
+#[test]
+fn csch_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).csch_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).csch_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.csch_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.csch_prec_round(1, Exact));
+    assert_panic!(X.csch_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.csch_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_csch_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (csch, o) = Float::csch_rational_prec(x.clone(), prec);
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+        assert_eq!(o, out_o);
+
+        let (csch, o) = Float::csch_rational_prec_ref(&x, prec);
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_csch, rug_o) = rug_csch_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_csch)),
+            ComparableFloatRef(&csch)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "Infinity", "Infinity", Equal);
+    test("0", 10, "Infinity", "Infinity", Equal);
+    test("0", 100, "Infinity", "Infinity", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Greater);
+    test("1", 10, "0.85059", "0x0.d9c#10", Less);
+    test(
+        "1",
+        100,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test("-1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, "-0.85059", "-0x0.d9c#10", Greater);
+    test(
+        "-1",
+        100,
+        "-0.85091812823932154513384276328721",
+        "-0x0.d9d5c53c5c86070b36fe901e0#100",
+        Less,
+    );
+    test("1/2", 1, "2.0", "0x2.0#1", Greater);
+    test("1/2", 10, "1.9199", "0x1.eb8#10", Greater);
+    test(
+        "1/2",
+        100,
+        "1.9190347513349437194922028787267",
+        "0x1.eb45dc88defecfc84d1dc4fd0#100",
+        Less,
+    );
+    test("1/3", 1, "2.0", "0x2.0#1", Less);
+    test("1/3", 10, "2.9453", "0x2.f2#10", Greater);
+    test(
+        "1/3",
+        100,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Less,
+    );
+    test("-1/3", 1, "-2.0", "-0x2.0#1", Greater);
+    test("-1/3", 10, "-2.9453", "-0x2.f2#10", Less);
+    test(
+        "-1/3",
+        100,
+        "-2.9451562666948143476455860034951",
+        "-0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+    test("22/7", 1, "0.062", "0x0.1#1", Less);
+    test("22/7", 10, "0.086426", "0x0.1620#10", Less);
+    test(
+        "22/7",
+        100,
+        "0.086479706518627875891032052705382",
+        "0x0.162388b743e325b1326cd6dab8#100",
+        Less,
+    );
+    test("1000000", 1, "6.8e-434295", "0x4.0E-360674#1", Greater);
+    test("1000000", 10, "6.5911e-434295", "0x3.e3E-360674#10", Less);
+    test(
+        "1000000",
+        100,
+        "6.5936629561771171579378159382151e-434295",
+        "0x3.e362986bdaa297eea886313f4E-360674#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn csch_rational_prec_fail() {
+    Float::csch_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn csch_rational_prec_ref_fail() {
+    Float::csch_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_csch_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (csch, o) = Float::csch_rational_prec_round(x.clone(), prec, rm);
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+        assert_eq!(o, out_o);
+
+        let (csch, o) = Float::csch_rational_prec_round_ref(&x, prec, rm);
+        assert!(csch.is_valid());
+        assert_eq!(csch.to_string(), out);
+        assert_eq!(to_hex_string(&csch), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_csch, rug_o) = rug_csch_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_csch)),
+                ComparableFloatRef(&csch)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("0", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", 1, Down, "Infinity", "Infinity", Equal);
+    test("0", 1, Up, "Infinity", "Infinity", Equal);
+    test("0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("0", 10, Floor, "Infinity", "Infinity", Equal);
+    test("0", 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", 10, Down, "Infinity", "Infinity", Equal);
+    test("0", 10, Up, "Infinity", "Infinity", Equal);
+    test("0", 10, Nearest, "Infinity", "Infinity", Equal);
+    test("0", 10, Exact, "Infinity", "Infinity", Equal);
+    test("0", 100, Nearest, "Infinity", "Infinity", Equal);
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", 10, Floor, "0.85059", "0x0.d9c#10", Less);
+    test("1", 10, Ceiling, "0.85156", "0x0.da0#10", Greater);
+    test("1", 10, Down, "0.85059", "0x0.d9c#10", Less);
+    test("1", 10, Up, "0.85156", "0x0.da0#10", Greater);
+    test("1", 10, Nearest, "0.85059", "0x0.d9c#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.85091812823932154513384276328721",
+        "0x0.d9d5c53c5c86070b36fe901e0#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, Floor, "-0.85156", "-0x0.da0#10", Less);
+    test("-1", 10, Ceiling, "-0.85059", "-0x0.d9c#10", Greater);
+    test("-1", 10, Down, "-0.85059", "-0x0.d9c#10", Greater);
+    test("-1", 10, Up, "-0.85156", "-0x0.da0#10", Less);
+    test("-1", 10, Nearest, "-0.85059", "-0x0.d9c#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.85091812823932154513384276328721",
+        "-0x0.d9d5c53c5c86070b36fe901e0#100",
+        Less,
+    );
+    test("1/2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1/2", 10, Floor, "1.9180", "0x1.eb0#10", Less);
+    test("1/2", 10, Ceiling, "1.9199", "0x1.eb8#10", Greater);
+    test("1/2", 10, Down, "1.9180", "0x1.eb0#10", Less);
+    test("1/2", 10, Up, "1.9199", "0x1.eb8#10", Greater);
+    test("1/2", 10, Nearest, "1.9199", "0x1.eb8#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "1.9190347513349437194922028787267",
+        "0x1.eb45dc88defecfc84d1dc4fd0#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1/3", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1/3", 1, Down, "2.0", "0x2.0#1", Less);
+    test("1/3", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("1/3", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/3", 10, Floor, "2.9414", "0x2.f1#10", Less);
+    test("1/3", 10, Ceiling, "2.9453", "0x2.f2#10", Greater);
+    test("1/3", 10, Down, "2.9414", "0x2.f1#10", Less);
+    test("1/3", 10, Up, "2.9453", "0x2.f2#10", Greater);
+    test("1/3", 10, Nearest, "2.9453", "0x2.f2#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "2.9451562666948143476455860034951",
+        "0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "-4.0", "-0x4.0#1", Less);
+    test("-1/3", 1, Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("-1/3", 1, Down, "-2.0", "-0x2.0#1", Greater);
+    test("-1/3", 1, Up, "-4.0", "-0x4.0#1", Less);
+    test("-1/3", 1, Nearest, "-2.0", "-0x2.0#1", Greater);
+    test("-1/3", 10, Floor, "-2.9453", "-0x2.f2#10", Less);
+    test("-1/3", 10, Ceiling, "-2.9414", "-0x2.f1#10", Greater);
+    test("-1/3", 10, Down, "-2.9414", "-0x2.f1#10", Greater);
+    test("-1/3", 10, Up, "-2.9453", "-0x2.f2#10", Less);
+    test("-1/3", 10, Nearest, "-2.9453", "-0x2.f2#10", Less);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-2.9451562666948143476455860034951",
+        "-0x2.f1f5c2d7104d708fac22e7c6c#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.062", "0x0.1#1", Less);
+    test("22/7", 1, Ceiling, "0.12", "0x0.2#1", Greater);
+    test("22/7", 1, Down, "0.062", "0x0.1#1", Less);
+    test("22/7", 1, Up, "0.12", "0x0.2#1", Greater);
+    test("22/7", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test("22/7", 10, Floor, "0.086426", "0x0.1620#10", Less);
+    test("22/7", 10, Ceiling, "0.086548", "0x0.1628#10", Greater);
+    test("22/7", 10, Down, "0.086426", "0x0.1620#10", Less);
+    test("22/7", 10, Up, "0.086548", "0x0.1628#10", Greater);
+    test("22/7", 10, Nearest, "0.086426", "0x0.1620#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.086479706518627875891032052705382",
+        "0x0.162388b743e325b1326cd6dab8#100",
+        Less,
+    );
+    test("1000000", 1, Floor, "3.4e-434295", "0x2.0E-360674#1", Less);
+    test(
+        "1000000",
+        1,
+        Ceiling,
+        "6.8e-434295",
+        "0x4.0E-360674#1",
+        Greater,
+    );
+    test("1000000", 1, Down, "3.4e-434295", "0x2.0E-360674#1", Less);
+    test("1000000", 1, Up, "6.8e-434295", "0x4.0E-360674#1", Greater);
+    test(
+        "1000000",
+        1,
+        Nearest,
+        "6.8e-434295",
+        "0x4.0E-360674#1",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Floor,
+        "6.5911e-434295",
+        "0x3.e3E-360674#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "6.5977e-434295",
+        "0x3.e4E-360674#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Down,
+        "6.5911e-434295",
+        "0x3.e3E-360674#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Up,
+        "6.5977e-434295",
+        "0x3.e4E-360674#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "6.5911e-434295",
+        "0x3.e3E-360674#10",
+        Less,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "6.5936629561771171579378159382151e-434295",
+        "0x3.e362986bdaa297eea886313f4E-360674#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn csch_rational_prec_round_fail_1() {
+    Float::csch_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn csch_rational_prec_round_fail_2() {
+    Float::csch_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn csch_rational_prec_round_ref_fail() {
+    Float::csch_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn csch_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (csch, o) = x.clone().csch_prec_round(prec, rm);
+    assert!(csch.is_valid());
+
+    let (csch_alt, o_alt) = x.csch_prec_round_ref(prec, rm);
+    assert!(csch_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.csch_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_csch, rug_o) = rug_csch_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_csch)),
+            ComparableFloatRef(&csch)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(csch.is_nan(), x.is_nan());
+
+    if csch.is_normal() {
+        assert_eq!(csch.get_prec(), Some(prec));
+    }
+
+    let (csch_alt, o_alt) = (-&x).csch_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-csch_alt), ComparableFloat(csch.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (csch_alt, o_alt) = Float::csch_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.csch_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(csch.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.csch_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn csch_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        csch_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (csch, o) = Float::NAN.csch_prec_round(prec, rm);
+        assert!(csch.is_nan());
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::INFINITY.csch_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::NEGATIVE_INFINITY.csch_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::ZERO.csch_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::NEGATIVE_ZERO.csch_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(csch),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn csch_prec_properties_helper(x: Float, prec: u64) {
+    let (csch, o) = x.clone().csch_prec(prec);
+    assert!(csch.is_valid());
+
+    let (csch_alt, o_alt) = x.csch_prec_ref(prec);
+    assert!(csch_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.csch_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let (csch_alt, o_alt) = x.csch_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let (rug_csch, rug_o) = rug_csch_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_csch)),
+        ComparableFloatRef(&csch)
+    );
+    assert_eq!(rug_o, o);
+
+    if csch.is_normal() {
+        assert_eq!(csch.get_prec(), Some(prec));
+    }
+
+    let (csch_alt, o_alt) = (-&x).csch_prec(prec);
+    assert_eq!(ComparableFloat(-csch_alt), ComparableFloat(csch.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn csch_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        csch_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (csch, o) = Float::NAN.csch_prec(prec);
+        assert!(csch.is_nan());
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::INFINITY.csch_prec(prec);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::NEGATIVE_INFINITY.csch_prec(prec);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::ZERO.csch_prec(prec);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::NEGATIVE_ZERO.csch_prec(prec);
+        assert_eq!(
+            ComparableFloat(csch),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn csch_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (csch, o) = x.clone().csch_round(rm);
+    assert!(csch.is_valid());
+
+    let (csch_alt, o_alt) = x.csch_round_ref(rm);
+    assert!(csch_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.csch_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let (csch_alt, o_alt) = x.csch_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let (csch_alt, o_alt) = (-&x).csch_round(-rm);
+    assert_eq!(ComparableFloat(-csch_alt), ComparableFloat(csch.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_csch, rug_o) = rug_csch_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_csch)),
+            ComparableFloatRef(&csch)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.csch_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(csch.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.csch_round_ref(Exact));
+    }
+}
+
+#[test]
+fn csch_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        csch_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (csch, o) = Float::NAN.csch_round(rm);
+        assert!(csch.is_nan());
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::INFINITY.csch_round(rm);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::NEGATIVE_INFINITY.csch_round(rm);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::ZERO.csch_round(rm);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (csch, o) = Float::NEGATIVE_ZERO.csch_round(rm);
+        assert_eq!(
+            ComparableFloat(csch),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn csch_properties_helper(x: Float) {
+    let csch = x.clone().csch();
+    assert!(csch.is_valid());
+
+    let csch_alt = (&x).csch();
+    assert!(csch_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+
+    let mut x_alt = x.clone();
+    x_alt.csch_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&csch));
+
+    let csch_alt = x.csch_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    let csch_alt = x.csch_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+
+    assert_eq!(csch.is_nan(), x.is_nan());
+
+    let rug_csch = rug_csch(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_csch)),
+        ComparableFloatRef(&csch),
+    );
+}
+
+#[test]
+fn csch_properties() {
+    float_gen().test_properties(|x| {
+        csch_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn csch_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (csch, o) = Float::csch_rational_prec_round(x.clone(), prec, rm);
+    assert!(csch.is_valid());
+
+    let (csch_alt, o_alt) = Float::csch_rational_prec_round_ref(&x, prec, rm);
+    assert!(csch_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_csch, rug_o) = rug_csch_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_csch)),
+            ComparableFloatRef(&csch)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!csch.is_nan());
+
+    if csch.is_normal() {
+        assert_eq!(csch.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (csch_alt, o_alt) = Float::csch_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-csch_alt), ComparableFloat(csch.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::csch_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(csch.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::csch_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn csch_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        csch_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (csch, o) = Float::csch_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn csch_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (csch, o) = Float::csch_rational_prec(x.clone(), prec);
+    assert!(csch.is_valid());
+
+    let (csch_alt, o_alt) = Float::csch_rational_prec_ref(&x, prec);
+    assert!(csch_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let (csch_alt, o_alt) = Float::csch_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&csch_alt), ComparableFloatRef(&csch));
+    assert_eq!(o_alt, o);
+
+    let (rug_csch, rug_o) = rug_csch_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_csch)),
+        ComparableFloatRef(&csch)
+    );
+    assert_eq!(rug_o, o);
+
+    if csch.is_normal() {
+        assert_eq!(csch.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn csch_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        csch_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (csch, o) = Float::csch_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(csch), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_csch() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_csch(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, -0.0);
+    test::<f32>(0.0, f32::INFINITY);
+    test::<f32>(-0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(1.0, 0.8509181);
+    test::<f32>(-1.0, -0.8509181);
+    test::<f32>(0.5, 1.9190347);
+    test::<f32>(-0.5, -1.9190347);
+    test::<f32>(2.0, 0.27572057);
+    test::<f32>(10.0, 0.00009079986);
+    test::<f32>(0.001, 999.99976);
+    test::<f32>(10000000000.0, 0.0);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, -0.0);
+    test::<f64>(0.0, f64::INFINITY);
+    test::<f64>(-0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(1.0, 0.8509181282393216);
+    test::<f64>(-1.0, -0.8509181282393216);
+    test::<f64>(0.5, 1.9190347513349437);
+    test::<f64>(-0.5, -1.9190347513349437);
+    test::<f64>(2.0, 0.2757205647717832);
+    test::<f64>(10.0, 0.00009079985971212217);
+    test::<f64>(0.001, 999.9998333333527);
+    test::<f64>(10000000000.0, 0.0);
+    test::<f64>(1.0e300, 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_csch_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let csch = primitive_float_csch(x);
+        assert_eq!(csch.is_nan(), x.is_nan());
+        assert_eq!(NiceFloat(primitive_float_csch(-x)), NiceFloat(-csch));
+    });
+}
+
+#[test]
+fn primitive_float_csch_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_csch_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_csch_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_csch_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", f32::INFINITY);
+    test::<f32>("1", 0.8509181);
+    test::<f32>("-1", -0.8509181);
+    test::<f32>("1/2", 1.9190347);
+    test::<f32>("-1/2", -1.9190347);
+    test::<f32>("1/3", 2.9451563);
+    test::<f32>("22/7", 0.08647971);
+    test::<f32>("3/2", 0.46964243);
+    test::<f32>("1000000", 0.0);
+
+    test::<f64>("0", f64::INFINITY);
+    test::<f64>("1", 0.8509181282393216);
+    test::<f64>("-1", -0.8509181282393216);
+    test::<f64>("1/2", 1.9190347513349437);
+    test::<f64>("-1/2", -1.9190347513349437);
+    test::<f64>("1/3", 2.9451562666948146);
+    test::<f64>("22/7", 0.08647970651862788);
+    test::<f64>("3/2", 0.4696424405952246);
+    test::<f64>("1000000", 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_csch_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let csch = primitive_float_csch_rational::<T>(&x);
+        assert!(!csch.is_nan());
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_csch_rational::<T>(&-&x)),
+                NiceFloat(-csch)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_csch_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_csch(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_csch_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_csch_rational_properties_helper);
+}
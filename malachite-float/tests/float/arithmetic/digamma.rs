@@ -0,0 +1,3103 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Digamma, DigammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::digamma::{
+    primitive_float_digamma, primitive_float_digamma_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::digamma::{
+    rug_digamma, rug_digamma_prec, rug_digamma_prec_round, rug_digamma_rational_prec,
+    rug_digamma_rational_prec_round, rug_digamma_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_digamma() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let digamma = x.clone().digamma();
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+
+        let digamma_alt = (&x).digamma();
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+
+        let mut digamma_alt = x.clone();
+        digamma_alt.digamma_assign();
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_digamma(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&digamma)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity");
+    test("1.0", "0x1.0#1", "-0.50", "-0x0.8#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+    );
+    test("3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test("5.0", "0x5.0#3", "1.5", "0x1.8#3");
+    test("0.50", "0x0.8#1", "-2.0", "-0x2.0#1");
+    test("-0.50", "-0x0.8#1", "0.031", "0x0.08#1");
+    test("123.0", "0x7b.0#7", "4.812", "0x4.d#7");
+    test("-123.50", "-0x7b.8#10", "4.8203", "0x4.d2#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.97721330794200689",
+        "0x0.fa2aa6bed3af8#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.80492627446356646",
+        "0x0.ce0fa5f8831b78#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "23.0258503",
+        "0x17.069e2#24",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "-1.3e30", "-0x1.0E+25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "1.6817655842134115275976591833539",
+        "0x1.ae883077bc24caa7d5744a74e#100",
+    );
+}
+
+#[test]
+fn test_digamma_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (digamma, o) = x.clone().digamma_prec(prec);
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (digamma_alt, o_alt) = x.digamma_prec_ref(prec);
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut digamma_alt = x.clone();
+        let o_alt = digamma_alt.digamma_prec_assign(prec);
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_digamma, rug_o) = rug_digamma_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_digamma)),
+            ComparableFloatRef(&digamma)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 10, "-0.57715", "-0x0.93c#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "-0.57715",
+        "-0x0.93c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 10, "0.92285", "0x0.ec4#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.92278433509846713939348790991766",
+        "0x0.ec3b981c824f385b2e41c07f0#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, "2.0", "0x2.0#1", Greater);
+    test("5.0", "0x5.0#3", 10, "1.5059", "0x1.818#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "1.5061176684318004727268212432515",
+        "0x1.8190ed71d7a48db0839715d46#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 10, "-1.9629", "-0x1.f68#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "-1.9635100260214234794409763329983",
+        "-0x1.f6a897d3214fbafc6585a6b10#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, "0.031", "0x0.08#1", Less);
+    test("-0.50", "-0x0.8#1", 10, "0.036499", "0x0.0958#10", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "0.036489973978576520559023667001255",
+        "0x0.0957682cdeb045039a7a594ef7#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "4.8047", "0x4.ce#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "4.8081138065732548174236092495752",
+        "0x4.cee08be2ad9e77a0fe5e3c830#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "4.0", "0x4.0#1", Less);
+    test("-123.50", "-0x7b.8#10", 10, "4.8203", "0x4.d2#10", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "4.8202842754250459065793605358738",
+        "0x4.d1fe26785fa620de272875108#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.97754",
+        "0x0.fa4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.97721330794200668746042602562097",
+        "0x0.fa2aa6bed3af713b76c45bb82#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.80469",
+        "0x0.ce0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.80492627446356648723652685227926",
+        "0x0.ce0fa5f8831b79f3ada60d323#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "16.0",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "23.031",
+        "0x17.08#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "23.025850929890456840179081213510",
+        "0x17.069e2aa2736123e0ea269130#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "-1.2677e30",
+        "-0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "-1267650600228229401496703205376.0",
+        "-0x10000000000000000000000000.0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "-3.1328",
+        "-0x3.22#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "1.6816",
+        "0x1.ae8#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "1.6817655842134115275976591833539",
+        "0x1.ae883077bc24caa7d5744a74e#100",
+        Greater,
+    );
+}
+
+#[test]
+fn digamma_prec_fail() {
+    assert_panic!(Float::NAN.digamma_prec(0));
+    assert_panic!(Float::NAN.digamma_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.digamma_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_digamma_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (digamma, o) = x.clone().digamma_round(rm);
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (digamma_alt, o_alt) = x.digamma_round_ref(rm);
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut digamma_alt = x.clone();
+        let o_alt = digamma_alt.digamma_round_assign(rm);
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_digamma, rug_o) = rug_digamma_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_digamma)),
+                ComparableFloatRef(&digamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Down, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Down, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Up, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Nearest, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "-0.57721566490153286060651209008313",
+        "-0x0.93c467e37db0c7a4d1be3f811#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "-0.57721566490153286060651209008313",
+        "-0x0.93c467e37db0c7a4d1be3f811#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.75", "0x0.c#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "1.0", "0x1.0#2", Greater);
+    test("3.0", "0x3.0#2", Down, "0.75", "0x0.c#2", Less);
+    test("3.0", "0x3.0#2", Up, "1.0", "0x1.0#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.0", "0x1.0#2", Greater);
+    test("5.0", "0x5.0#3", Floor, "1.5", "0x1.8#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "1.8", "0x1.c#3", Greater);
+    test("5.0", "0x5.0#3", Down, "1.5", "0x1.8#3", Less);
+    test("5.0", "0x5.0#3", Up, "1.8", "0x1.c#3", Greater);
+    test("5.0", "0x5.0#3", Nearest, "1.5", "0x1.8#3", Less);
+    test("0.50", "0x0.8#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test("-0.50", "-0x0.8#1", Floor, "0.031", "0x0.08#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "0.062", "0x0.1#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "0.031", "0x0.08#1", Less);
+    test("-0.50", "-0x0.8#1", Up, "0.062", "0x0.1#1", Greater);
+    test("-0.50", "-0x0.8#1", Nearest, "0.031", "0x0.08#1", Less);
+    test("123.0", "0x7b.0#7", Floor, "4.750", "0x4.c#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "4.812", "0x4.d#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "4.750", "0x4.c#7", Less);
+    test("123.0", "0x7b.0#7", Up, "4.812", "0x4.d#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "4.812", "0x4.d#7", Greater);
+    test("-123.50", "-0x7b.8#10", Floor, "4.8125", "0x4.d0#10", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "4.8203",
+        "0x4.d2#10",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", Down, "4.8125", "0x4.d0#10", Less);
+    test("-123.50", "-0x7b.8#10", Up, "4.8203", "0x4.d2#10", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "4.8203",
+        "0x4.d2#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.97721330794200600",
+        "0x0.fa2aa6bed3af4#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.97721330794200689",
+        "0x0.fa2aa6bed3af8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.97721330794200600",
+        "0x0.fa2aa6bed3af4#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.97721330794200689",
+        "0x0.fa2aa6bed3af8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.97721330794200689",
+        "0x0.fa2aa6bed3af8#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.80492627446356646",
+        "0x0.ce0fa5f8831b78#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.80492627446356657",
+        "0x0.ce0fa5f8831b80#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.80492627446356646",
+        "0x0.ce0fa5f8831b78#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.80492627446356657",
+        "0x0.ce0fa5f8831b80#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.80492627446356646",
+        "0x0.ce0fa5f8831b78#53",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "23.0258503",
+        "0x17.069e2#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "23.0258522",
+        "0x17.069e4#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "23.0258503",
+        "0x17.069e2#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "23.0258522",
+        "0x17.069e4#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "23.0258503",
+        "0x17.069e2#24",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "-2.5e30",
+        "-0x2.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "-2.5e30",
+        "-0x2.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-3.1320337800208063229964190742838",
+        "-0x3.21ccf73f28185655653d7175c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "-3.1320337800208063229964190742838",
+        "-0x3.21ccf73f28185655653d7175c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "1.6817655842134115275976591833523",
+        "0x1.ae883077bc24caa7d5744a74c#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "1.6817655842134115275976591833539",
+        "0x1.ae883077bc24caa7d5744a74e#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "1.6817655842134115275976591833523",
+        "0x1.ae883077bc24caa7d5744a74c#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "1.6817655842134115275976591833539",
+        "0x1.ae883077bc24caa7d5744a74e#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "1.6817655842134115275976591833539",
+        "0x1.ae883077bc24caa7d5744a74e#100",
+        Greater,
+    );
+}
+
+#[test]
+fn digamma_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.digamma_round(Exact));
+    assert_panic!(X.digamma_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.digamma_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_digamma_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (digamma, o) = x.clone().digamma_prec_round(prec, rm);
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (digamma_alt, o_alt) = x.digamma_prec_round_ref(prec, rm);
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut digamma_alt = x.clone();
+        let o_alt = digamma_alt.digamma_prec_round_assign(prec, rm);
+        assert!(digamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&digamma),
+            ComparableFloatRef(&digamma_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_digamma, rug_o) =
+                rug_digamma_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_digamma)),
+                ComparableFloatRef(&digamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Nearest, "-0.50", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "-0.57812", "-0x0.940#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "-0.57715",
+        "-0x0.93c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Down,
+        "-0.57715",
+        "-0x0.93c#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Up, "-0.57812", "-0x0.940#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "-0.57715",
+        "-0x0.93c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "-0.57721566490153286060651209008313",
+        "-0x0.93c467e37db0c7a4d1be3f811#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "-0.57812",
+        "-0x0.940#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "-0.57715",
+        "-0x0.93c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "-0.57715",
+        "-0x0.93c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "-0.57812",
+        "-0x0.940#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "-0.57715",
+        "-0x0.93c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "-0.57721566490153286060651209008313",
+        "-0x0.93c467e37db0c7a4d1be3f811#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 10, Floor, "0.92188", "0x0.ec0#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.92285",
+        "0x0.ec4#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "0.92188", "0x0.ec0#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "0.92285", "0x0.ec4#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.92285",
+        "0x0.ec4#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Floor,
+        "0.92278433509846713939348790991687",
+        "0x0.ec3b981c824f385b2e41c07ef#100",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Ceiling,
+        "0.92278433509846713939348790991766",
+        "0x0.ec3b981c824f385b2e41c07f0#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.92278433509846713939348790991766",
+        "0x0.ec3b981c824f385b2e41c07f0#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("5.0", "0x5.0#3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("5.0", "0x5.0#3", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("5.0", "0x5.0#3", 10, Floor, "1.5059", "0x1.818#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "1.5078",
+        "0x1.820#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 10, Down, "1.5059", "0x1.818#10", Less);
+    test("5.0", "0x5.0#3", 10, Up, "1.5078", "0x1.820#10", Greater);
+    test("5.0", "0x5.0#3", 10, Nearest, "1.5059", "0x1.818#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Floor,
+        "1.5061176684318004727268212432499",
+        "0x1.8190ed71d7a48db0839715d44#100",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Ceiling,
+        "1.5061176684318004727268212432515",
+        "0x1.8190ed71d7a48db0839715d46#100",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "1.5061176684318004727268212432515",
+        "0x1.8190ed71d7a48db0839715d46#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 10, Floor, "-1.9648", "-0x1.f70#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "-1.9629",
+        "-0x1.f68#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Down,
+        "-1.9629",
+        "-0x1.f68#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Up, "-1.9648", "-0x1.f70#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "-1.9629",
+        "-0x1.f68#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "-1.9635100260214234794409763329999",
+        "-0x1.f6a897d3214fbafc6585a6b12#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "-1.9635100260214234794409763329983",
+        "-0x1.f6a897d3214fbafc6585a6b10#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "-1.9635100260214234794409763329983",
+        "-0x1.f6a897d3214fbafc6585a6b10#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "0.031", "0x0.08#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "0.062", "0x0.1#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Down, "0.031", "0x0.08#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Up, "0.062", "0x0.1#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "0.031", "0x0.08#1", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "0.036438",
+        "0x0.0954#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "0.036499",
+        "0x0.0958#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Down,
+        "0.036438",
+        "0x0.0954#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Up,
+        "0.036499",
+        "0x0.0958#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "0.036499",
+        "0x0.0958#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "0.036489973978576520559023667001206",
+        "0x0.0957682cdeb045039a7a594ef6#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "0.036489973978576520559023667001255",
+        "0x0.0957682cdeb045039a7a594ef7#100",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "0.036489973978576520559023667001255",
+        "0x0.0957682cdeb045039a7a594ef7#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "8.0", "0x8.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "8.0", "0x8.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "4.8047", "0x4.ce#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "4.8125",
+        "0x4.d0#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "4.8047", "0x4.ce#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "4.8125", "0x4.d0#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "4.8047",
+        "0x4.ce#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "4.8081138065732548174236092495689",
+        "0x4.cee08be2ad9e77a0fe5e3c828#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "4.8081138065732548174236092495752",
+        "0x4.cee08be2ad9e77a0fe5e3c830#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "4.8081138065732548174236092495752",
+        "0x4.cee08be2ad9e77a0fe5e3c830#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Floor, "4.0", "0x4.0#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Down, "4.0", "0x4.0#1", Less);
+    test("-123.50", "-0x7b.8#10", 1, Up, "8.0", "0x8.0#1", Greater);
+    test("-123.50", "-0x7b.8#10", 1, Nearest, "4.0", "0x4.0#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "4.8125",
+        "0x4.d0#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "4.8203",
+        "0x4.d2#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "4.8125",
+        "0x4.d0#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "4.8203",
+        "0x4.d2#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "4.8203",
+        "0x4.d2#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Floor,
+        "4.8202842754250459065793605358738",
+        "0x4.d1fe26785fa620de272875108#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Ceiling,
+        "4.8202842754250459065793605358801",
+        "0x4.d1fe26785fa620de272875110#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "4.8202842754250459065793605358738",
+        "0x4.d1fe26785fa620de272875108#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.97656",
+        "0x0.fa0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.97754",
+        "0x0.fa4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.97656",
+        "0x0.fa0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.97754",
+        "0x0.fa4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.97754",
+        "0x0.fa4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "0.97721330794200668746042602562018",
+        "0x0.fa2aa6bed3af713b76c45bb81#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "0.97721330794200668746042602562097",
+        "0x0.fa2aa6bed3af713b76c45bb82#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.97721330794200668746042602562097",
+        "0x0.fa2aa6bed3af713b76c45bb82#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.80469",
+        "0x0.ce0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.80566",
+        "0x0.ce4#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.80469",
+        "0x0.ce0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.80566",
+        "0x0.ce4#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.80469",
+        "0x0.ce0#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.80492627446356648723652685227926",
+        "0x0.ce0fa5f8831b79f3ada60d323#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.80492627446356648723652685228005",
+        "0x0.ce0fa5f8831b79f3ada60d324#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.80492627446356648723652685227926",
+        "0x0.ce0fa5f8831b79f3ada60d323#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "16.0",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "32.0",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "16.0",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "32.0",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "16.0",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "23.000",
+        "0x17.00#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "23.031",
+        "0x17.08#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "23.000",
+        "0x17.00#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "23.031",
+        "0x17.08#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "23.031",
+        "0x17.08#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Floor,
+        "23.025850929890456840179081213510",
+        "0x17.069e2aa2736123e0ea269130#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Ceiling,
+        "23.025850929890456840179081213536",
+        "0x17.069e2aa2736123e0ea269132#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "23.025850929890456840179081213510",
+        "0x17.069e2aa2736123e0ea269130#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "-2.5e30",
+        "-0x2.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "-2.5e30",
+        "-0x2.0E+25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "-1.3e30",
+        "-0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "-1.2701e30",
+        "-0x1.008E+25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "-1.2677e30",
+        "-0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "-1.2677e30",
+        "-0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "-1.2701e30",
+        "-0x1.008E+25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "-1.2677e30",
+        "-0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "-1267650600228229401496703205378.0",
+        "-0x10000000000000000000000002.0#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "-1267650600228229401496703205376.0",
+        "-0x10000000000000000000000000.0#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "-1267650600228229401496703205376.0",
+        "-0x10000000000000000000000000.0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-3.1328",
+        "-0x3.22#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-3.1289",
+        "-0x3.21#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-3.1289",
+        "-0x3.21#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-3.1328",
+        "-0x3.22#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-3.1328",
+        "-0x3.22#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "-3.1320337800208063229964190742838",
+        "-0x3.21ccf73f28185655653d7175c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "1.6816",
+        "0x1.ae8#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "1.6836",
+        "0x1.af0#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "1.6816",
+        "0x1.ae8#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "1.6836",
+        "0x1.af0#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "1.6816",
+        "0x1.ae8#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "1.6817655842134115275976591833523",
+        "0x1.ae883077bc24caa7d5744a74c#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "1.6817655842134115275976591833539",
+        "0x1.ae883077bc24caa7d5744a74e#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "1.6817655842134115275976591833539",
+        "0x1.ae883077bc24caa7d5744a74e#100",
+        Greater,
+    );
+}
+
+#[test]
+fn digamma_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).digamma_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).digamma_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.digamma_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.digamma_prec_round(1, Exact));
+    assert_panic!(X.digamma_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.digamma_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_digamma_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (digamma, o) = Float::digamma_rational_prec(x.clone(), prec);
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (digamma, o) = Float::digamma_rational_prec_ref(&x, prec);
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_digamma, rug_o) = rug_digamma_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_digamma)),
+            ComparableFloatRef(&digamma)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "-Infinity", "-Infinity", Equal);
+    test("0", 10, "-Infinity", "-Infinity", Equal);
+    test("0", 100, "-Infinity", "-Infinity", Equal);
+    test("1", 1, "-0.50", "-0x0.8#1", Greater);
+    test("1", 10, "-0.57715", "-0x0.93c#10", Greater);
+    test(
+        "1",
+        100,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test("-1", 1, "NaN", "NaN", Equal);
+    test("-1", 10, "NaN", "NaN", Equal);
+    test("-1", 100, "NaN", "NaN", Equal);
+    test("1/2", 1, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 10, "-1.9629", "-0x1.f68#10", Greater);
+    test(
+        "1/2",
+        100,
+        "-1.9635100260214234794409763329983",
+        "-0x1.f6a897d3214fbafc6585a6b10#100",
+        Greater,
+    );
+    test("-1/2", 1, "0.031", "0x0.08#1", Less);
+    test("-1/2", 10, "0.036499", "0x0.0958#10", Greater);
+    test(
+        "-1/2",
+        100,
+        "0.036489973978576520559023667001255",
+        "0x0.0957682cdeb045039a7a594ef7#100",
+        Greater,
+    );
+    test("1/3", 1, "-4.0", "-0x4.0#1", Less);
+    test("1/3", 10, "-3.1328", "-0x3.22#10", Less);
+    test(
+        "1/3",
+        100,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Greater,
+    );
+    test("-1/3", 1, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 10, "1.6816", "0x1.ae8#10", Less);
+    test(
+        "-1/3",
+        100,
+        "1.6817655842134115275976591833555",
+        "0x1.ae883077bc24caa7d5744a750#100",
+        Greater,
+    );
+    test("22/7", 1, "1.0", "0x1.0#1", Greater);
+    test("22/7", 10, "0.97754", "0x0.fa4#10", Less);
+    test(
+        "22/7",
+        100,
+        "0.97768642444232346811715136364963",
+        "0x0.fa49a852b91389043e40f8f9f#100",
+        Less,
+    );
+    test("5", 1, "2.0", "0x2.0#1", Greater);
+    test("5", 10, "1.5059", "0x1.818#10", Less);
+    test(
+        "5",
+        100,
+        "1.5061176684318004727268212432515",
+        "0x1.8190ed71d7a48db0839715d46#100",
+        Greater,
+    );
+    test("100", 1, "4.0", "0x4.0#1", Less);
+    test("100", 10, "4.6016", "0x4.9a#10", Greater);
+    test(
+        "100",
+        100,
+        "4.6001618527380874001986055855767",
+        "0x4.99a43509d11d4e8f710436be8#100",
+        Greater,
+    );
+    test("-7/2", 1, "1.0", "0x1.0#1", Less);
+    test("-7/2", 10, "1.3887", "0x1.638#10", Less);
+    test(
+        "-7/2",
+        100,
+        "1.3888709263595289015114046193829",
+        "0x1.638d0b8714539f393dd48ef26#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn digamma_rational_prec_fail() {
+    Float::digamma_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn digamma_rational_prec_ref_fail() {
+    Float::digamma_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_digamma_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (digamma, o) = Float::digamma_rational_prec_round(x.clone(), prec, rm);
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (digamma, o) = Float::digamma_rational_prec_round_ref(&x, prec, rm);
+        assert!(digamma.is_valid());
+        assert_eq!(digamma.to_string(), out);
+        assert_eq!(to_hex_string(&digamma), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_digamma, rug_o) = rug_digamma_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_digamma)),
+                ComparableFloatRef(&digamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Ceiling, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Down, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Up, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Nearest, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("0", 100, Nearest, "-Infinity", "-Infinity", Equal);
+    test("1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("1", 1, Nearest, "-0.50", "-0x0.8#1", Greater);
+    test("1", 10, Floor, "-0.57812", "-0x0.940#10", Less);
+    test("1", 10, Ceiling, "-0.57715", "-0x0.93c#10", Greater);
+    test("1", 10, Down, "-0.57715", "-0x0.93c#10", Greater);
+    test("1", 10, Up, "-0.57812", "-0x0.940#10", Less);
+    test("1", 10, Nearest, "-0.57715", "-0x0.93c#10", Greater);
+    test(
+        "1",
+        100,
+        Nearest,
+        "-0.57721566490153286060651209008234",
+        "-0x0.93c467e37db0c7a4d1be3f810#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 1, Down, "NaN", "NaN", Equal);
+    test("-1", 1, Up, "NaN", "NaN", Equal);
+    test("-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 10, Down, "NaN", "NaN", Equal);
+    test("-1", 10, Up, "NaN", "NaN", Equal);
+    test("-1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1", 100, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 10, Floor, "-1.9648", "-0x1.f70#10", Less);
+    test("1/2", 10, Ceiling, "-1.9629", "-0x1.f68#10", Greater);
+    test("1/2", 10, Down, "-1.9629", "-0x1.f68#10", Greater);
+    test("1/2", 10, Up, "-1.9648", "-0x1.f70#10", Less);
+    test("1/2", 10, Nearest, "-1.9629", "-0x1.f68#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "-1.9635100260214234794409763329983",
+        "-0x1.f6a897d3214fbafc6585a6b10#100",
+        Greater,
+    );
+    test("-1/2", 1, Floor, "0.031", "0x0.08#1", Less);
+    test("-1/2", 1, Ceiling, "0.062", "0x0.1#1", Greater);
+    test("-1/2", 1, Down, "0.031", "0x0.08#1", Less);
+    test("-1/2", 1, Up, "0.062", "0x0.1#1", Greater);
+    test("-1/2", 1, Nearest, "0.031", "0x0.08#1", Less);
+    test("-1/2", 10, Floor, "0.036438", "0x0.0954#10", Less);
+    test("-1/2", 10, Ceiling, "0.036499", "0x0.0958#10", Greater);
+    test("-1/2", 10, Down, "0.036438", "0x0.0954#10", Less);
+    test("-1/2", 10, Up, "0.036499", "0x0.0958#10", Greater);
+    test("-1/2", 10, Nearest, "0.036499", "0x0.0958#10", Greater);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "0.036489973978576520559023667001255",
+        "0x0.0957682cdeb045039a7a594ef7#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "-4.0", "-0x4.0#1", Less);
+    test("1/3", 1, Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("1/3", 1, Down, "-2.0", "-0x2.0#1", Greater);
+    test("1/3", 1, Up, "-4.0", "-0x4.0#1", Less);
+    test("1/3", 1, Nearest, "-4.0", "-0x4.0#1", Less);
+    test("1/3", 10, Floor, "-3.1328", "-0x3.22#10", Less);
+    test("1/3", 10, Ceiling, "-3.1289", "-0x3.21#10", Greater);
+    test("1/3", 10, Down, "-3.1289", "-0x3.21#10", Greater);
+    test("1/3", 10, Up, "-3.1328", "-0x3.22#10", Less);
+    test("1/3", 10, Nearest, "-3.1328", "-0x3.22#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "-3.1320337800208063229964190742869",
+        "-0x3.21ccf73f28185655653d71760#100",
+        Greater,
+    );
+    test("-1/3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1/3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1/3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 10, Floor, "1.6816", "0x1.ae8#10", Less);
+    test("-1/3", 10, Ceiling, "1.6836", "0x1.af0#10", Greater);
+    test("-1/3", 10, Down, "1.6816", "0x1.ae8#10", Less);
+    test("-1/3", 10, Up, "1.6836", "0x1.af0#10", Greater);
+    test("-1/3", 10, Nearest, "1.6816", "0x1.ae8#10", Less);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "1.6817655842134115275976591833555",
+        "0x1.ae883077bc24caa7d5744a750#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("22/7", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("22/7", 1, Down, "0.50", "0x0.8#1", Less);
+    test("22/7", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("22/7", 10, Floor, "0.97754", "0x0.fa4#10", Less);
+    test("22/7", 10, Ceiling, "0.97852", "0x0.fa8#10", Greater);
+    test("22/7", 10, Down, "0.97754", "0x0.fa4#10", Less);
+    test("22/7", 10, Up, "0.97852", "0x0.fa8#10", Greater);
+    test("22/7", 10, Nearest, "0.97754", "0x0.fa4#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.97768642444232346811715136364963",
+        "0x0.fa49a852b91389043e40f8f9f#100",
+        Less,
+    );
+    test("5", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("5", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("5", 1, Down, "1.0", "0x1.0#1", Less);
+    test("5", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("5", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("5", 10, Floor, "1.5059", "0x1.818#10", Less);
+    test("5", 10, Ceiling, "1.5078", "0x1.820#10", Greater);
+    test("5", 10, Down, "1.5059", "0x1.818#10", Less);
+    test("5", 10, Up, "1.5078", "0x1.820#10", Greater);
+    test("5", 10, Nearest, "1.5059", "0x1.818#10", Less);
+    test(
+        "5",
+        100,
+        Nearest,
+        "1.5061176684318004727268212432515",
+        "0x1.8190ed71d7a48db0839715d46#100",
+        Greater,
+    );
+    test("100", 1, Floor, "4.0", "0x4.0#1", Less);
+    test("100", 1, Ceiling, "8.0", "0x8.0#1", Greater);
+    test("100", 1, Down, "4.0", "0x4.0#1", Less);
+    test("100", 1, Up, "8.0", "0x8.0#1", Greater);
+    test("100", 1, Nearest, "4.0", "0x4.0#1", Less);
+    test("100", 10, Floor, "4.5938", "0x4.98#10", Less);
+    test("100", 10, Ceiling, "4.6016", "0x4.9a#10", Greater);
+    test("100", 10, Down, "4.5938", "0x4.98#10", Less);
+    test("100", 10, Up, "4.6016", "0x4.9a#10", Greater);
+    test("100", 10, Nearest, "4.6016", "0x4.9a#10", Greater);
+    test(
+        "100",
+        100,
+        Nearest,
+        "4.6001618527380874001986055855767",
+        "0x4.99a43509d11d4e8f710436be8#100",
+        Greater,
+    );
+    test("-7/2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-7/2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-7/2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-7/2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-7/2", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("-7/2", 10, Floor, "1.3887", "0x1.638#10", Less);
+    test("-7/2", 10, Ceiling, "1.3906", "0x1.640#10", Greater);
+    test("-7/2", 10, Down, "1.3887", "0x1.638#10", Less);
+    test("-7/2", 10, Up, "1.3906", "0x1.640#10", Greater);
+    test("-7/2", 10, Nearest, "1.3887", "0x1.638#10", Less);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "1.3888709263595289015114046193829",
+        "0x1.638d0b8714539f393dd48ef26#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn digamma_rational_prec_round_fail_1() {
+    Float::digamma_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn digamma_rational_prec_round_fail_2() {
+    Float::digamma_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn digamma_rational_prec_round_ref_fail() {
+    Float::digamma_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn digamma_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (digamma, o) = x.clone().digamma_prec_round(prec, rm);
+    assert!(digamma.is_valid());
+
+    let (digamma_alt, o_alt) = x.digamma_prec_round_ref(prec, rm);
+    assert!(digamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.digamma_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&digamma));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_digamma, rug_o) =
+            rug_digamma_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_digamma)),
+            ComparableFloatRef(&digamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(
+        digamma.is_nan(),
+        x.is_nan() || x == Float::NEGATIVE_INFINITY || (x < 0u32 && x.is_integer())
+    );
+
+    if digamma.is_normal() {
+        assert_eq!(digamma.get_prec(), Some(prec));
+    }
+    if x.is_normal() {
+        let (digamma_alt, o_alt) =
+            Float::digamma_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&digamma_alt),
+            ComparableFloatRef(&digamma)
+        );
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.digamma_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(digamma.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.digamma_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn digamma_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        digamma_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (digamma, o) = Float::NAN.digamma_prec_round(prec, rm);
+        assert!(digamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::INFINITY.digamma_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(digamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::NEGATIVE_INFINITY.digamma_prec_round(prec, rm);
+        assert!(digamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::ZERO.digamma_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(digamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::NEGATIVE_ZERO.digamma_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(digamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn digamma_prec_properties_helper(x: Float, prec: u64) {
+    let (digamma, o) = x.clone().digamma_prec(prec);
+    assert!(digamma.is_valid());
+
+    let (digamma_alt, o_alt) = x.digamma_prec_ref(prec);
+    assert!(digamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.digamma_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&digamma));
+    assert_eq!(o_alt, o);
+
+    let (digamma_alt, o_alt) = x.digamma_prec_round_ref(prec, Nearest);
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    let (rug_digamma, rug_o) = rug_digamma_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_digamma)),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(rug_o, o);
+
+    if digamma.is_normal() {
+        assert_eq!(digamma.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn digamma_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        digamma_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (digamma, o) = Float::NAN.digamma_prec(prec);
+        assert!(digamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::INFINITY.digamma_prec(prec);
+        assert_eq!(ComparableFloat(digamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::NEGATIVE_INFINITY.digamma_prec(prec);
+        assert!(digamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::ZERO.digamma_prec(prec);
+        assert_eq!(
+            ComparableFloat(digamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::NEGATIVE_ZERO.digamma_prec(prec);
+        assert_eq!(ComparableFloat(digamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn digamma_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (digamma, o) = x.clone().digamma_round(rm);
+    assert!(digamma.is_valid());
+
+    let (digamma_alt, o_alt) = x.digamma_round_ref(rm);
+    assert!(digamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.digamma_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&digamma));
+    assert_eq!(o_alt, o);
+
+    let (digamma_alt, o_alt) = x.digamma_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_digamma, rug_o) = rug_digamma_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_digamma)),
+            ComparableFloatRef(&digamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.digamma_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(digamma.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.digamma_round_ref(Exact));
+    }
+}
+
+#[test]
+fn digamma_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        digamma_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (digamma, o) = Float::NAN.digamma_round(rm);
+        assert!(digamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::INFINITY.digamma_round(rm);
+        assert_eq!(ComparableFloat(digamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::NEGATIVE_INFINITY.digamma_round(rm);
+        assert!(digamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::ZERO.digamma_round(rm);
+        assert_eq!(
+            ComparableFloat(digamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (digamma, o) = Float::NEGATIVE_ZERO.digamma_round(rm);
+        assert_eq!(ComparableFloat(digamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn digamma_properties_helper(x: Float) {
+    let digamma = x.clone().digamma();
+    assert!(digamma.is_valid());
+
+    let digamma_alt = (&x).digamma();
+    assert!(digamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt.digamma_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&digamma));
+
+    let digamma_alt = x.digamma_round_ref(Nearest).0;
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    let digamma_alt = x.digamma_prec_ref(x.significant_bits()).0;
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+
+    assert_eq!(
+        digamma.is_nan(),
+        x.is_nan() || x == Float::NEGATIVE_INFINITY || (x < 0u32 && x.is_integer())
+    );
+
+    let rug_digamma = rug_digamma(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_digamma)),
+        ComparableFloatRef(&digamma),
+    );
+}
+
+#[test]
+fn digamma_properties() {
+    float_gen().test_properties(|x| {
+        digamma_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn digamma_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (digamma, o) = Float::digamma_rational_prec_round(x.clone(), prec, rm);
+    assert!(digamma.is_valid());
+
+    let (digamma_alt, o_alt) = Float::digamma_rational_prec_round_ref(&x, prec, rm);
+    assert!(digamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_digamma, rug_o) = rug_digamma_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_digamma)),
+            ComparableFloatRef(&digamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(digamma.is_nan(), x < 0u32 && x.is_integer());
+
+    if digamma.is_normal() {
+        assert_eq!(digamma.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::digamma_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(digamma.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::digamma_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn digamma_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        digamma_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (digamma, o) = Float::digamma_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(
+            ComparableFloat(digamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn digamma_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (digamma, o) = Float::digamma_rational_prec(x.clone(), prec);
+    assert!(digamma.is_valid());
+
+    let (digamma_alt, o_alt) = Float::digamma_rational_prec_ref(&x, prec);
+    assert!(digamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    let (digamma_alt, o_alt) = Float::digamma_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(
+        ComparableFloatRef(&digamma_alt),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(o_alt, o);
+
+    let (rug_digamma, rug_o) = rug_digamma_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_digamma)),
+        ComparableFloatRef(&digamma)
+    );
+    assert_eq!(rug_o, o);
+
+    if digamma.is_normal() {
+        assert_eq!(digamma.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn digamma_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        digamma_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (digamma, o) = Float::digamma_rational_prec(Rational::ZERO, prec);
+        assert_eq!(
+            ComparableFloat(digamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_digamma() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_digamma(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::INFINITY);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(-0.0, f32::INFINITY);
+    test::<f32>(1.0, -0.5772157);
+    test::<f32>(-1.0, f32::NAN);
+    test::<f32>(0.5, -1.96351);
+    test::<f32>(-0.5, 0.036489975);
+    test::<f32>(2.0, 0.42278433);
+    test::<f32>(5.0, 1.5061177);
+    test::<f32>(-2.5, 1.1031567);
+    test::<f32>(10.0, 2.2517526);
+    test::<f32>(0.001, -1000.5755);
+    test::<f32>(10000000000.0, 23.02585);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::INFINITY);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(-0.0, f64::INFINITY);
+    test::<f64>(1.0, -0.5772156649015329);
+    test::<f64>(-1.0, f64::NAN);
+    test::<f64>(0.5, -1.9635100260214235);
+    test::<f64>(-0.5, 0.03648997397857652);
+    test::<f64>(2.0, 0.42278433509846713);
+    test::<f64>(5.0, 1.5061176684318005);
+    test::<f64>(-2.5, 1.103156640645243);
+    test::<f64>(10.0, 2.251752589066721);
+    test::<f64>(0.001, -1000.5755719318103);
+    test::<f64>(10000000000.0, 23.025850929890456);
+    test::<f64>(1.0e300, 690.7755278982137);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_digamma_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let digamma = primitive_float_digamma(x);
+        assert_eq!(
+            digamma.is_nan(),
+            x.is_nan() || x == T::NEGATIVE_INFINITY || (x < T::ZERO && x.is_integer())
+        );
+    });
+}
+
+#[test]
+fn primitive_float_digamma_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_digamma_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_digamma_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_digamma_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", f32::NEGATIVE_INFINITY);
+    test::<f32>("1", -0.5772157);
+    test::<f32>("-1", f32::NAN);
+    test::<f32>("1/2", -1.96351);
+    test::<f32>("-1/2", 0.036489975);
+    test::<f32>("1/3", -3.1320338);
+    test::<f32>("22/7", 0.9776864);
+    test::<f32>("3/2", 0.036489975);
+    test::<f32>("-7/2", 1.388871);
+    test::<f32>("1000000", 13.81551);
+
+    test::<f64>("0", f64::NEGATIVE_INFINITY);
+    test::<f64>("1", -0.5772156649015329);
+    test::<f64>("-1", f64::NAN);
+    test::<f64>("1/2", -1.9635100260214235);
+    test::<f64>("-1/2", 0.03648997397857652);
+    test::<f64>("1/3", -3.1320337800208065);
+    test::<f64>("22/7", 0.9776864244423235);
+    test::<f64>("3/2", 0.03648997397857652);
+    test::<f64>("-7/2", 1.388870926359529);
+    test::<f64>("1000000", 13.815510057964191);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_digamma_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let digamma = primitive_float_digamma_rational::<T>(&x);
+        assert_eq!(digamma.is_nan(), x < 0u32 && x.is_integer());
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_digamma_rational::<T>(
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_digamma(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_digamma_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_digamma_rational_properties_helper);
+}
@@ -0,0 +1,3336 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Erf, ErfAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::erf::{primitive_float_erf, primitive_float_erf_rational};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::erf::{
+    rug_erf, rug_erf_prec, rug_erf_prec_round, rug_erf_rational_prec, rug_erf_rational_prec_round,
+    rug_erf_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_erf() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let erf = x.clone().erf();
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+
+        let erf_alt = (&x).erf();
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+
+        let mut erf_alt = x.clone();
+        erf_alt.erf_assign();
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erf(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&erf)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1");
+    test("-Infinity", "-Infinity", "-1.0", "-0x1.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+    );
+    test("3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test("5.0", "0x5.0#3", "1.0", "0x1.0#3");
+    test("0.50", "0x0.8#1", "0.50", "0x0.8#1");
+    test("-0.50", "-0x0.8#1", "-0.50", "-0x0.8#1");
+    test("123.0", "0x7b.0#7", "1.000", "0x1.00#7");
+    test("-123.50", "-0x7b.8#10", "-1.0000", "-0x1.000#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.99999112385363276",
+        "0x0.ffff6b153ddc0#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.99987906895990719",
+        "0x0.fff8131d234248#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "1.00000000",
+        "0x1.000000#24",
+    );
+    test("27.0", "0x1b.0#5", "1.00", "0x1.0#5");
+    test("-6.0", "-0x6.0#2", "-1.0", "-0x1.0#2");
+    test("7.9e-31", "0x1.0E-25#1", "7.9e-31", "0x1.0E-25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+    );
+}
+
+#[test]
+fn test_erf_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (erf, o) = x.clone().erf_prec(prec);
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+        assert_eq!(o, o_out);
+
+        let (erf_alt, o_alt) = x.erf_prec_ref(prec);
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut erf_alt = x.clone();
+        let o_alt = erf_alt.erf_prec_assign(prec);
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_erf, rug_o) = rug_erf_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erf)),
+            ComparableFloatRef(&erf)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.0000",
+        "-0x1.000#10",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.84277", "0x0.d7c#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.84277", "-0x0.d7c#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.84270079294971486934122063508229",
+        "-0x0.d7bb3d3a0844563680887edd8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.84277",
+        "0x0.d7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.99997790950300141455862722387052",
+        "0x0.fffe8d6209afcbdd5f43d9ada#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, "1.0", "0x1.0#1", Greater);
+    test("5.0", "0x5.0#3", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "0.99999999999846254020557196515010",
+        "0x0.fffffffffe4f3e58a6088c76d#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 10, "0.52051", "0x0.854#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "0.52049987781304653768274665389184",
+        "0x0.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", 10, "-0.52051", "-0x0.854#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "-0.52049987781304653768274665389184",
+        "-0x0.853f7ae0c76e915e809f1a31a#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "-1.0", "-0x1.0#1", Less);
+    test("-123.50", "-0x7b.8#10", 10, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.99999112385363235838758416987405",
+        "0x0.ffff6b153ddbe34085ef327c6#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.99987906895990723732478699059508",
+        "0x0.fff8131d23424b51ef96e84b7#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, "1.0", "0x1.0#1", Greater);
+    test("27.0", "0x1b.0#5", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, "-1.0", "-0x1.0#1", Less);
+    test("-6.0", "-0x6.0#2", 10, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        "-0.99999999999999997848026328750086",
+        "-0x0.fffffffffffffe7307eaa82df#100",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "7.9e-31", "0x1.0E-25#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "8.9055e-31",
+        "0x1.210E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "8.9013421118749738739136506526079e-31",
+        "0x1.20dd750429b6d11ae3a914feeE-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.36279",
+        "0x0.5ce#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "-0.36279",
+        "-0x0.5ce#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+}
+
+#[test]
+fn erf_prec_fail() {
+    assert_panic!(Float::NAN.erf_prec(0));
+    assert_panic!(Float::NAN.erf_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.erf_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_erf_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (erf, o) = x.clone().erf_round(rm);
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+        assert_eq!(o, o_out);
+
+        let (erf_alt, o_alt) = x.erf_round_ref(rm);
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut erf_alt = x.clone();
+        let o_alt = erf_alt.erf_round_assign(rm);
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_erf, rug_o) = rug_erf_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_erf)),
+                ComparableFloatRef(&erf),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Exact, "1.0", "0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Floor, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Down, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Up, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Nearest, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Exact, "-1.0", "-0x1.0#1", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.84270079294971486934122063508307",
+        "0x0.d7bb3d3a0844563680887edd9#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.84270079294971486934122063508307",
+        "0x0.d7bb3d3a0844563680887edd9#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.75", "0x0.c#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "1.0", "0x1.0#2", Greater);
+    test("3.0", "0x3.0#2", Down, "0.75", "0x0.c#2", Less);
+    test("3.0", "0x3.0#2", Up, "1.0", "0x1.0#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.0", "0x1.0#2", Greater);
+    test("5.0", "0x5.0#3", Floor, "0.88", "0x0.e#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "1.0", "0x1.0#3", Greater);
+    test("5.0", "0x5.0#3", Down, "0.88", "0x0.e#3", Less);
+    test("5.0", "0x5.0#3", Up, "1.0", "0x1.0#3", Greater);
+    test("5.0", "0x5.0#3", Nearest, "1.0", "0x1.0#3", Greater);
+    test("0.50", "0x0.8#1", Floor, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "0.50", "0x0.8#1", Less);
+    test("-0.50", "-0x0.8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", Nearest, "-0.50", "-0x0.8#1", Greater);
+    test("123.0", "0x7b.0#7", Floor, "0.9922", "0x0.fe#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "1.000", "0x1.00#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "0.9922", "0x0.fe#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.000", "0x1.00#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "1.000", "0x1.00#7", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Floor,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Down,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", Up, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.99999112385363187",
+        "0x0.ffff6b153ddbc#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.99999112385363276",
+        "0x0.ffff6b153ddc0#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.99999112385363187",
+        "0x0.ffff6b153ddbc#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.99999112385363276",
+        "0x0.ffff6b153ddc0#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.99999112385363276",
+        "0x0.ffff6b153ddc0#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.99987906895990719",
+        "0x0.fff8131d234248#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.99987906895990730",
+        "0x0.fff8131d234250#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.99987906895990719",
+        "0x0.fff8131d234248#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.99987906895990730",
+        "0x0.fff8131d234250#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.99987906895990719",
+        "0x0.fff8131d234248#53",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "0.999999940",
+        "0x0.ffffff#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "1.00000000",
+        "0x1.000000#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "0.999999940",
+        "0x0.ffffff#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "1.00000000",
+        "0x1.000000#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "1.00000000",
+        "0x1.000000#24",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", Floor, "0.969", "0x0.f8#5", Less);
+    test("27.0", "0x1b.0#5", Ceiling, "1.00", "0x1.0#5", Greater);
+    test("27.0", "0x1b.0#5", Down, "0.969", "0x0.f8#5", Less);
+    test("27.0", "0x1b.0#5", Up, "1.00", "0x1.0#5", Greater);
+    test("27.0", "0x1b.0#5", Nearest, "1.00", "0x1.0#5", Greater);
+    test("-6.0", "-0x6.0#2", Floor, "-1.0", "-0x1.0#2", Less);
+    test("-6.0", "-0x6.0#2", Ceiling, "-0.75", "-0x0.c#2", Greater);
+    test("-6.0", "-0x6.0#2", Down, "-0.75", "-0x0.c#2", Greater);
+    test("-6.0", "-0x6.0#2", Up, "-1.0", "-0x1.0#2", Less);
+    test("-6.0", "-0x6.0#2", Nearest, "-1.0", "-0x1.0#2", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.36264811176606293340817864014831",
+        "0x0.5cd681b3fdcbf03522a128a790#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.36264811176606293340817864014831",
+        "0x0.5cd681b3fdcbf03522a128a790#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "-0.36264811176606293340817864014831",
+        "-0x0.5cd681b3fdcbf03522a128a790#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "-0.36264811176606293340817864014831",
+        "-0x0.5cd681b3fdcbf03522a128a790#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+}
+
+#[test]
+fn erf_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.erf_round(Exact));
+    assert_panic!(X.erf_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.erf_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_erf_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (erf, o) = x.clone().erf_prec_round(prec, rm);
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+        assert_eq!(o, o_out);
+
+        let (erf_alt, o_alt) = x.erf_prec_round_ref(prec, rm);
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut erf_alt = x.clone();
+        let o_alt = erf_alt.erf_prec_round_assign(prec, rm);
+        assert!(erf_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erf), ComparableFloatRef(&erf_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_erf, rug_o) = rug_erf_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_erf)),
+                ComparableFloatRef(&erf)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Down, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.84180", "0x0.d78#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.84277",
+        "0x0.d7c#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.84180", "0x0.d78#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.84277", "0x0.d7c#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.84277",
+        "0x0.d7c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.84270079294971486934122063508307",
+        "0x0.d7bb3d3a0844563680887edd9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.84277",
+        "-0x0.d7c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.84180",
+        "-0x0.d78#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.84180",
+        "-0x0.d78#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.84277", "-0x0.d7c#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.84277",
+        "-0x0.d7c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.84270079294971486934122063508307",
+        "-0x0.d7bb3d3a0844563680887edd9#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.84270079294971486934122063508229",
+        "-0x0.d7bb3d3a0844563680887edd8#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.84270079294971486934122063508229",
+        "-0x0.d7bb3d3a0844563680887edd8#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.84180",
+        "0x0.d78#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.84277",
+        "0x0.d7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.84180",
+        "0x0.d78#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.84277",
+        "0x0.d7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.84277",
+        "0x0.d7c#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.84270079294971486934122063508307",
+        "0x0.d7bb3d3a0844563680887edd9#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Floor,
+        "0.99997790950300141455862722386973",
+        "0x0.fffe8d6209afcbdd5f43d9ad9#100",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Ceiling,
+        "0.99997790950300141455862722387052",
+        "0x0.fffe8d6209afcbdd5f43d9ada#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.99997790950300141455862722387052",
+        "0x0.fffe8d6209afcbdd5f43d9ada#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("5.0", "0x5.0#3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("5.0", "0x5.0#3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("5.0", "0x5.0#3", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("5.0", "0x5.0#3", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Floor,
+        "0.99999999999846254020557196514931",
+        "0x0.fffffffffe4f3e58a6088c76c#100",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Ceiling,
+        "0.99999999999846254020557196515010",
+        "0x0.fffffffffe4f3e58a6088c76d#100",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "0.99999999999846254020557196515010",
+        "0x0.fffffffffe4f3e58a6088c76d#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("0.50", "0x0.8#1", 10, Floor, "0.51953", "0x0.850#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.52051",
+        "0x0.854#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "0.51953", "0x0.850#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "0.52051", "0x0.854#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "0.52051",
+        "0x0.854#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "0.52049987781304653768274665389184",
+        "0x0.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "0.52049987781304653768274665389263",
+        "0x0.853f7ae0c76e915e809f1a31b#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.52049987781304653768274665389184",
+        "0x0.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-0.50", "-0x0.8#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-0.50", "-0x0.8#1", 1, Nearest, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "-0.52051",
+        "-0x0.854#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-0.51953",
+        "-0x0.850#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Down,
+        "-0.51953",
+        "-0x0.850#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Up, "-0.52051", "-0x0.854#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-0.52051",
+        "-0x0.854#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "-0.52049987781304653768274665389263",
+        "-0x0.853f7ae0c76e915e809f1a31b#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "-0.52049987781304653768274665389184",
+        "-0x0.853f7ae0c76e915e809f1a31a#100",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "-0.52049987781304653768274665389184",
+        "-0x0.853f7ae0c76e915e809f1a31a#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "0.50", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Floor,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Ceiling,
+        "-0.99999999999999999999999999999921",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "0.99999112385363235838758416987326",
+        "0x0.ffff6b153ddbe34085ef327c5#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "0.99999112385363235838758416987405",
+        "0x0.ffff6b153ddbe34085ef327c6#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.99999112385363235838758416987405",
+        "0x0.ffff6b153ddbe34085ef327c6#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.99987906895990723732478699059429",
+        "0x0.fff8131d23424b51ef96e84b6#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.99987906895990723732478699059508",
+        "0x0.fff8131d23424b51ef96e84b7#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.99987906895990723732478699059508",
+        "0x0.fff8131d23424b51ef96e84b7#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("27.0", "0x1b.0#5", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Down, "0.50", "0x0.8#1", Less);
+    test("27.0", "0x1b.0#5", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("27.0", "0x1b.0#5", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("27.0", "0x1b.0#5", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("27.0", "0x1b.0#5", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Floor,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Ceiling,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Down,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 10, Up, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Nearest,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Floor,
+        "-0.99999999999999997848026328750165",
+        "-0x0.fffffffffffffe7307eaa82e0#100",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Ceiling,
+        "-0.99999999999999997848026328750086",
+        "-0x0.fffffffffffffe7307eaa82df#100",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Nearest,
+        "-0.99999999999999997848026328750086",
+        "-0x0.fffffffffffffe7307eaa82df#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "8.8901e-31",
+        "0x1.208E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "8.9055e-31",
+        "0x1.210E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "8.8901e-31",
+        "0x1.208E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "8.9055e-31",
+        "0x1.210E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "8.9055e-31",
+        "0x1.210E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "8.9013421118749738739136506525954e-31",
+        "0x1.20dd750429b6d11ae3a914fecE-25#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "8.9013421118749738739136506526079e-31",
+        "0x1.20dd750429b6d11ae3a914feeE-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "8.9013421118749738739136506526079e-31",
+        "0x1.20dd750429b6d11ae3a914feeE-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.36230",
+        "0x0.5cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.36279",
+        "0x0.5ce#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.36230",
+        "0x0.5cc#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.36279",
+        "0x0.5ce#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.36279",
+        "0x0.5ce#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.36264811176606293340817864014831",
+        "0x0.5cd681b3fdcbf03522a128a790#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-0.36279",
+        "-0x0.5ce#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-0.36230",
+        "-0x0.5cc#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-0.36230",
+        "-0x0.5cc#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-0.36279",
+        "-0x0.5ce#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-0.36279",
+        "-0x0.5ce#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "-0.36264811176606293340817864014831",
+        "-0x0.5cd681b3fdcbf03522a128a790#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+}
+
+#[test]
+fn erf_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).erf_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).erf_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.erf_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.erf_prec_round(1, Exact));
+    assert_panic!(X.erf_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.erf_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_erf_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (erf, o) = Float::erf_rational_prec(x.clone(), prec);
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+        assert_eq!(o, out_o);
+
+        let (erf, o) = Float::erf_rational_prec_ref(&x, prec);
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_erf, rug_o) = rug_erf_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erf)),
+            ComparableFloatRef(&erf)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Greater);
+    test("1", 10, "0.84277", "0x0.d7c#10", Greater);
+    test(
+        "1",
+        100,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("-1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, "-0.84277", "-0x0.d7c#10", Less);
+    test(
+        "-1",
+        100,
+        "-0.84270079294971486934122063508229",
+        "-0x0.d7bb3d3a0844563680887edd8#100",
+        Greater,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Less);
+    test("1/2", 10, "0.52051", "0x0.854#10", Greater);
+    test(
+        "1/2",
+        100,
+        "0.52049987781304653768274665389184",
+        "0x0.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test("-1/2", 1, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 10, "-0.52051", "-0x0.854#10", Less);
+    test(
+        "-1/2",
+        100,
+        "-0.52049987781304653768274665389184",
+        "-0x0.853f7ae0c76e915e809f1a31a#100",
+        Greater,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.36279", "0x0.5ce#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.36279", "-0x0.5ce#10", Less);
+    test(
+        "-1/3",
+        100,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test("22/7", 1, "1.0", "0x1.0#1", Greater);
+    test("22/7", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "22/7",
+        100,
+        "0.99999119736104709969501852267557",
+        "0x0.ffff6c50f41dbd75723c0f68e#100",
+        Less,
+    );
+    test("5", 1, "1.0", "0x1.0#1", Greater);
+    test("5", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "5",
+        100,
+        "0.99999999999846254020557196515010",
+        "0x0.fffffffffe4f3e58a6088c76d#100",
+        Greater,
+    );
+    test("100", 1, "1.0", "0x1.0#1", Greater);
+    test("100", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "100",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-7/2", 1, "-1.0", "-0x1.0#1", Less);
+    test("-7/2", 10, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "-7/2",
+        100,
+        "-0.99999925690162765858725447631622",
+        "-0x0.fffff3886ab2f393d4a5aedb8#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn erf_rational_prec_fail() {
+    Float::erf_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn erf_rational_prec_ref_fail() {
+    Float::erf_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_erf_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (erf, o) = Float::erf_rational_prec_round(x.clone(), prec, rm);
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+        assert_eq!(o, out_o);
+
+        let (erf, o) = Float::erf_rational_prec_round_ref(&x, prec, rm);
+        assert!(erf.is_valid());
+        assert_eq!(erf.to_string(), out);
+        assert_eq!(to_hex_string(&erf), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_erf, rug_o) = rug_erf_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_erf)),
+                ComparableFloatRef(&erf)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", 10, Floor, "0.84180", "0x0.d78#10", Less);
+    test("1", 10, Ceiling, "0.84277", "0x0.d7c#10", Greater);
+    test("1", 10, Down, "0.84180", "0x0.d78#10", Less);
+    test("1", 10, Up, "0.84277", "0x0.d7c#10", Greater);
+    test("1", 10, Nearest, "0.84277", "0x0.d7c#10", Greater);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.84270079294971486934122063508229",
+        "0x0.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("-1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, Floor, "-0.84277", "-0x0.d7c#10", Less);
+    test("-1", 10, Ceiling, "-0.84180", "-0x0.d78#10", Greater);
+    test("-1", 10, Down, "-0.84180", "-0x0.d78#10", Greater);
+    test("-1", 10, Up, "-0.84277", "-0x0.d7c#10", Less);
+    test("-1", 10, Nearest, "-0.84277", "-0x0.d7c#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.84270079294971486934122063508229",
+        "-0x0.d7bb3d3a0844563680887edd8#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1/2", 10, Floor, "0.51953", "0x0.850#10", Less);
+    test("1/2", 10, Ceiling, "0.52051", "0x0.854#10", Greater);
+    test("1/2", 10, Down, "0.51953", "0x0.850#10", Less);
+    test("1/2", 10, Up, "0.52051", "0x0.854#10", Greater);
+    test("1/2", 10, Nearest, "0.52051", "0x0.854#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.52049987781304653768274665389184",
+        "0x0.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test("-1/2", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1/2", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1/2", 1, Nearest, "-0.50", "-0x0.8#1", Greater);
+    test("-1/2", 10, Floor, "-0.52051", "-0x0.854#10", Less);
+    test("-1/2", 10, Ceiling, "-0.51953", "-0x0.850#10", Greater);
+    test("-1/2", 10, Down, "-0.51953", "-0x0.850#10", Greater);
+    test("-1/2", 10, Up, "-0.52051", "-0x0.854#10", Less);
+    test("-1/2", 10, Nearest, "-0.52051", "-0x0.854#10", Less);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "-0.52049987781304653768274665389184",
+        "-0x0.853f7ae0c76e915e809f1a31a#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.36230", "0x0.5cc#10", Less);
+    test("1/3", 10, Ceiling, "0.36279", "0x0.5ce#10", Greater);
+    test("1/3", 10, Down, "0.36230", "0x0.5cc#10", Less);
+    test("1/3", 10, Up, "0.36279", "0x0.5ce#10", Greater);
+    test("1/3", 10, Nearest, "0.36279", "0x0.5ce#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.36264811176606293340817864014792",
+        "0x0.5cd681b3fdcbf03522a128a788#100",
+        Greater,
+    );
+    test("-1/3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, Floor, "-0.36279", "-0x0.5ce#10", Less);
+    test("-1/3", 10, Ceiling, "-0.36230", "-0x0.5cc#10", Greater);
+    test("-1/3", 10, Down, "-0.36230", "-0x0.5cc#10", Greater);
+    test("-1/3", 10, Up, "-0.36279", "-0x0.5ce#10", Less);
+    test("-1/3", 10, Nearest, "-0.36279", "-0x0.5ce#10", Less);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-0.36264811176606293340817864014792",
+        "-0x0.5cd681b3fdcbf03522a128a788#100",
+        Less,
+    );
+    test("22/7", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("22/7", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("22/7", 1, Down, "0.50", "0x0.8#1", Less);
+    test("22/7", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("22/7", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test("22/7", 10, Ceiling, "1.0000", "0x1.000#10", Greater);
+    test("22/7", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("22/7", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test("22/7", 10, Nearest, "1.0000", "0x1.000#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.99999119736104709969501852267557",
+        "0x0.ffff6c50f41dbd75723c0f68e#100",
+        Less,
+    );
+    test("5", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("5", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("5", 1, Down, "0.50", "0x0.8#1", Less);
+    test("5", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("5", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("5", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test("5", 10, Ceiling, "1.0000", "0x1.000#10", Greater);
+    test("5", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("5", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test("5", 10, Nearest, "1.0000", "0x1.000#10", Greater);
+    test(
+        "5",
+        100,
+        Nearest,
+        "0.99999999999846254020557196515010",
+        "0x0.fffffffffe4f3e58a6088c76d#100",
+        Greater,
+    );
+    test("100", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("100", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("100", 1, Down, "0.50", "0x0.8#1", Less);
+    test("100", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("100", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("100", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test("100", 10, Ceiling, "1.0000", "0x1.000#10", Greater);
+    test("100", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("100", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test("100", 10, Nearest, "1.0000", "0x1.000#10", Greater);
+    test(
+        "100",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-7/2", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-7/2", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-7/2", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-7/2", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-7/2", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-7/2", 10, Floor, "-1.0000", "-0x1.000#10", Less);
+    test("-7/2", 10, Ceiling, "-0.99902", "-0x0.ffc#10", Greater);
+    test("-7/2", 10, Down, "-0.99902", "-0x0.ffc#10", Greater);
+    test("-7/2", 10, Up, "-1.0000", "-0x1.000#10", Less);
+    test("-7/2", 10, Nearest, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "-0.99999925690162765858725447631622",
+        "-0x0.fffff3886ab2f393d4a5aedb8#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn erf_rational_prec_round_fail_1() {
+    Float::erf_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn erf_rational_prec_round_fail_2() {
+    Float::erf_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn erf_rational_prec_round_ref_fail() {
+    Float::erf_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erf_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (erf, o) = x.clone().erf_prec_round(prec, rm);
+    assert!(erf.is_valid());
+
+    let (erf_alt, o_alt) = x.erf_prec_round_ref(prec, rm);
+    assert!(erf_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.erf_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_erf, rug_o) = rug_erf_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erf)),
+            ComparableFloatRef(&erf)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(erf.is_nan(), x.is_nan());
+    assert!(erf.is_nan() || (erf >= -1i32 && erf <= 1u32));
+
+    if erf.is_normal() {
+        assert_eq!(erf.get_prec(), Some(prec));
+    }
+
+    let (erf_alt, o_alt) = (-&x).erf_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-erf_alt), ComparableFloat(erf.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (erf_alt, o_alt) = Float::erf_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.erf_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(erf.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.erf_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn erf_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        erf_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (erf, o) = Float::NAN.erf_prec_round(prec, rm);
+        assert!(erf.is_nan());
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::INFINITY.erf_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::NEGATIVE_INFINITY.erf_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(erf),
+            ComparableFloat(-Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::ZERO.erf_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::NEGATIVE_ZERO.erf_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erf_prec_properties_helper(x: Float, prec: u64) {
+    let (erf, o) = x.clone().erf_prec(prec);
+    assert!(erf.is_valid());
+
+    let (erf_alt, o_alt) = x.erf_prec_ref(prec);
+    assert!(erf_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.erf_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let (erf_alt, o_alt) = x.erf_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let (rug_erf, rug_o) = rug_erf_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_erf)),
+        ComparableFloatRef(&erf)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(erf.is_nan() || (erf >= -1i32 && erf <= 1u32));
+
+    if erf.is_normal() {
+        assert_eq!(erf.get_prec(), Some(prec));
+    }
+
+    let (erf_alt, o_alt) = (-&x).erf_prec(prec);
+    assert_eq!(ComparableFloat(-erf_alt), ComparableFloat(erf.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn erf_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        erf_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (erf, o) = Float::NAN.erf_prec(prec);
+        assert!(erf.is_nan());
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::INFINITY.erf_prec(prec);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::NEGATIVE_INFINITY.erf_prec(prec);
+        assert_eq!(
+            ComparableFloat(erf),
+            ComparableFloat(-Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::ZERO.erf_prec(prec);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::NEGATIVE_ZERO.erf_prec(prec);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erf_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (erf, o) = x.clone().erf_round(rm);
+    assert!(erf.is_valid());
+
+    let (erf_alt, o_alt) = x.erf_round_ref(rm);
+    assert!(erf_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.erf_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let (erf_alt, o_alt) = x.erf_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    assert!(erf.is_nan() || (erf >= -1i32 && erf <= 1u32));
+
+    let (erf_alt, o_alt) = (-&x).erf_round(-rm);
+    assert_eq!(ComparableFloat(-erf_alt), ComparableFloat(erf.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_erf, rug_o) = rug_erf_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erf)),
+            ComparableFloatRef(&erf)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.erf_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(erf.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.erf_round_ref(Exact));
+    }
+}
+
+#[test]
+fn erf_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        erf_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (erf, o) = Float::NAN.erf_round(rm);
+        assert!(erf.is_nan());
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::INFINITY.erf_round(rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::NEGATIVE_INFINITY.erf_round(rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::NEGATIVE_ONE));
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::ZERO.erf_round(rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (erf, o) = Float::NEGATIVE_ZERO.erf_round(rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erf_properties_helper(x: Float) {
+    let erf = x.clone().erf();
+    assert!(erf.is_valid());
+
+    let erf_alt = (&x).erf();
+    assert!(erf_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+
+    let mut x_alt = x.clone();
+    x_alt.erf_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erf));
+
+    let erf_alt = x.erf_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    let erf_alt = x.erf_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+
+    assert_eq!(erf.is_nan(), x.is_nan());
+    assert!(erf.is_nan() || (erf >= -1i32 && erf <= 1u32));
+
+    let rug_erf = rug_erf(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_erf)),
+        ComparableFloatRef(&erf),
+    );
+}
+
+#[test]
+fn erf_properties() {
+    float_gen().test_properties(|x| {
+        erf_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erf_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (erf, o) = Float::erf_rational_prec_round(x.clone(), prec, rm);
+    assert!(erf.is_valid());
+
+    let (erf_alt, o_alt) = Float::erf_rational_prec_round_ref(&x, prec, rm);
+    assert!(erf_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_erf, rug_o) = rug_erf_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erf)),
+            ComparableFloatRef(&erf)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!erf.is_nan());
+    assert!(erf.is_nan() || (erf >= -1i32 && erf <= 1u32));
+
+    if erf.is_normal() {
+        assert_eq!(erf.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (erf_alt, o_alt) = Float::erf_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-erf_alt), ComparableFloat(erf.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::erf_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(erf.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::erf_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn erf_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        erf_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (erf, o) = Float::erf_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erf_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (erf, o) = Float::erf_rational_prec(x.clone(), prec);
+    assert!(erf.is_valid());
+
+    let (erf_alt, o_alt) = Float::erf_rational_prec_ref(&x, prec);
+    assert!(erf_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let (erf_alt, o_alt) = Float::erf_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&erf_alt), ComparableFloatRef(&erf));
+    assert_eq!(o_alt, o);
+
+    let (rug_erf, rug_o) = rug_erf_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_erf)),
+        ComparableFloatRef(&erf)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(erf.is_nan() || (erf >= -1i32 && erf <= 1u32));
+
+    if erf.is_normal() {
+        assert_eq!(erf.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn erf_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        erf_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (erf, o) = Float::erf_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(erf), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_erf() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_erf(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 1.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, -1.0);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, 0.8427008);
+    test::<f32>(-1.0, -0.8427008);
+    test::<f32>(0.5, 0.5204999);
+    test::<f32>(-0.5, -0.5204999);
+    test::<f32>(2.0, 0.9953223);
+    test::<f32>(5.0, 1.0);
+    test::<f32>(-2.5, -0.999593);
+    test::<f32>(10.0, 1.0);
+    test::<f32>(0.001, 0.0011283788);
+    test::<f32>(10000000000.0, 1.0);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 1.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, -1.0);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, 0.8427007929497149);
+    test::<f64>(-1.0, -0.8427007929497149);
+    test::<f64>(0.5, 0.5204998778130465);
+    test::<f64>(-0.5, -0.5204998778130465);
+    test::<f64>(2.0, 0.9953222650189527);
+    test::<f64>(5.0, 0.9999999999984626);
+    test::<f64>(-2.5, -0.999593047982555);
+    test::<f64>(10.0, 1.0);
+    test::<f64>(0.001, 0.0011283787909692365);
+    test::<f64>(10000000000.0, 1.0);
+    test::<f64>(1.0e300, 1.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_erf_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let erf = primitive_float_erf(x);
+        assert_eq!(erf.is_nan(), x.is_nan());
+        assert!(erf.is_nan() || erf >= -T::ONE && erf <= T::ONE);
+        assert_eq!(NiceFloat(primitive_float_erf(-x)), NiceFloat(-erf));
+    });
+}
+
+#[test]
+fn primitive_float_erf_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_erf_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_erf_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_erf_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", 0.8427008);
+    test::<f32>("-1", -0.8427008);
+    test::<f32>("1/2", 0.5204999);
+    test::<f32>("-1/2", -0.5204999);
+    test::<f32>("1/3", 0.3626481);
+    test::<f32>("22/7", 0.9999912);
+    test::<f32>("3/2", 0.96610516);
+    test::<f32>("-7/2", -0.9999993);
+    test::<f32>("1000000", 1.0);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", 0.8427007929497149);
+    test::<f64>("-1", -0.8427007929497149);
+    test::<f64>("1/2", 0.5204998778130465);
+    test::<f64>("-1/2", -0.5204998778130465);
+    test::<f64>("1/3", 0.36264811176606293);
+    test::<f64>("22/7", 0.9999911973610471);
+    test::<f64>("3/2", 0.9661051464753108);
+    test::<f64>("-7/2", -0.9999992569016276);
+    test::<f64>("1000000", 1.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_erf_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let erf = primitive_float_erf_rational::<T>(&x);
+        assert!(!erf.is_nan());
+        assert!(erf.is_nan() || erf >= -T::ONE && erf <= T::ONE);
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_erf_rational::<T>(&-&x)),
+                NiceFloat(-erf)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_erf_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_erf(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_erf_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_erf_rational_properties_helper);
+}
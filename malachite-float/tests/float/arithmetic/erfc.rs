@@ -0,0 +1,3463 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Erfc, ErfcAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Two, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::erfc::{
+    primitive_float_erfc, primitive_float_erfc_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::erfc::{
+    rug_erfc, rug_erfc_prec, rug_erfc_prec_round, rug_erfc_rational_prec,
+    rug_erfc_rational_prec_round, rug_erfc_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_erfc() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let erfc = x.clone().erfc();
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+
+        let erfc_alt = (&x).erfc();
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+
+        let mut erfc_alt = x.clone();
+        erfc_alt.erfc_assign();
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erfc(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&erfc)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "2.0", "0x2.0#1");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "0.12", "0x0.2#1");
+    test("-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+    );
+    test("3.0", "0x3.0#2", "0.000023", "0x0.00018#2");
+    test("5.0", "0x5.0#3", "1.6e-12", "0x1.cE-10#3");
+    test("0.50", "0x0.8#1", "0.50", "0x0.8#1");
+    test("-0.50", "-0x0.8#1", "2.0", "0x2.0#1");
+    test("123.0", "0x7b.0#7", "1.663e-6573", "0x3.40E-5459#7");
+    test("-123.50", "-0x7b.8#10", "2.0000", "0x2.00#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "8.8761463676416137e-6",
+        "0x0.000094eac2241cbf8#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.00012093104009276267",
+        "0x0.0007ece2dcbdb4ae0#53",
+    );
+    test("1.00000000e10", "0x2.540be4E+8#24", "0.0", "0x0.0");
+    test("27.0", "0x1b.0#5", "5.26e-319", "0x6.8E-265#5");
+    test("-6.0", "-0x6.0#2", "2.0", "0x2.0#2");
+    test("7.9e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.63735188823393706659182135985169",
+        "0x0.a3297e4c02340fcadd5ed7587#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+    );
+}
+
+#[test]
+fn test_erfc_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (erfc, o) = x.clone().erfc_prec(prec);
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+        assert_eq!(o, o_out);
+
+        let (erfc_alt, o_alt) = x.erfc_prec_ref(prec);
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut erfc_alt = x.clone();
+        let o_alt = erfc_alt.erfc_prec_assign(prec);
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_erfc, rug_o) = rug_erfc_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erfc)),
+            ComparableFloatRef(&erfc)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "2.0", "0x2.0#1", Equal);
+    test("-Infinity", "-Infinity", 10, "2.0000", "0x2.00#10", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.15723", "0x0.284#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "1.8418", "0x1.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "1.8427007929497148693412206350823",
+        "0x1.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.15723",
+        "0x0.284#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, "0.000015", "0x0.0001#1", Less);
+    test("3.0", "0x3.0#2", 10, "0.000022084", "0x0.0001728#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.000022090496998585441372776129582332",
+        "0x0.0001729df6503422a0bc26526214e#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, "1.8e-12", "0x2.0E-10#1", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        "1.5383e-12",
+        "0x1.b10E-10#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "1.5374597944280348501883434853838e-12",
+        "0x1.b0c1a759f7738935ea5dea8e2E-10#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, "0.50", "0x0.8#1", Greater);
+    test("0.50", "0x0.8#1", 10, "0.47949", "0x0.7ac#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "0.47950012218695346231725334610816",
+        "0x0.7ac0851f38916ea17f60e5ce60#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, "2.0", "0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 10, "1.5195", "0x1.850#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "1.5204998778130465376827466538918",
+        "0x1.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "2.0e-6573",
+        "0x4.0E-5459#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.6610e-6573",
+        "0x3.3fE-5459#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.6606912065400207552876696414597e-6573",
+        "0x3.3ed575a78faf1a8e745db06acE-5459#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "2.0", "0x2.0#1", Greater);
+    test("-123.50", "-0x7b.8#10", 10, "2.0000", "0x2.00#10", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "8.8811e-6",
+        "0x0.0000950#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "8.8761463676416124158301263443150e-6",
+        "0x0.000094eac2241cbf7a10cd83a7eb7#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.00012",
+        "0x0.0008#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.00012088",
+        "0x0.0007ec#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.00012093104009276267521300940515821",
+        "0x0.0007ece2dcbdb4ae106917b494c08#100",
+        Less,
+    );
+    test("1.00000000e10", "0x2.540be4E+8#24", 1, "0.0", "0x0.0", Less);
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", 1, "6.5e-319", "0x8.0E-265#1", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        "5.2363e-319",
+        "0x6.78E-265#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        "5.2370489237892556850160676828476e-319",
+        "0x6.783c337e0e9d7e84c2c582430E-265#100",
+        Less,
+    );
+    test("-6.0", "-0x6.0#2", 1, "2.0", "0x2.0#1", Greater);
+    test("-6.0", "-0x6.0#2", 10, "2.0000", "0x2.00#10", Greater);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        "1.9999999999999999784802632875016",
+        "0x1.fffffffffffffe7307eaa82e0#100",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.63770",
+        "0x0.a34#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.63735188823393706659182135985169",
+        "0x0.a3297e4c02340fcadd5ed7587#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "1.3633",
+        "0x1.5d0#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+}
+
+#[test]
+fn erfc_prec_fail() {
+    assert_panic!(Float::NAN.erfc_prec(0));
+    assert_panic!(Float::NAN.erfc_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.erfc_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_erfc_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (erfc, o) = x.clone().erfc_round(rm);
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+        assert_eq!(o, o_out);
+
+        let (erfc_alt, o_alt) = x.erfc_round_ref(rm);
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut erfc_alt = x.clone();
+        let o_alt = erfc_alt.erfc_round_assign(rm);
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_erfc, rug_o) = rug_erfc_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_erfc)),
+                ComparableFloatRef(&erfc),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "2.0", "0x2.0#1", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "2.0", "0x2.0#1", Equal);
+    test("-Infinity", "-Infinity", Down, "2.0", "0x2.0#1", Equal);
+    test("-Infinity", "-Infinity", Up, "2.0", "0x2.0#1", Equal);
+    test("-Infinity", "-Infinity", Nearest, "2.0", "0x2.0#1", Equal);
+    test("-Infinity", "-Infinity", Exact, "2.0", "0x2.0#1", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", Up, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.12", "0x0.2#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.15729920705028513065877936491752",
+        "0x0.2844c2c5f7bba9c97f7781227c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.15729920705028513065877936491752",
+        "0x0.2844c2c5f7bba9c97f7781227c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.000015", "0x0.00010#2", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        Ceiling,
+        "0.000023",
+        "0x0.00018#2",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", Down, "0.000015", "0x0.00010#2", Less);
+    test("3.0", "0x3.0#2", Up, "0.000023", "0x0.00018#2", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        Nearest,
+        "0.000023",
+        "0x0.00018#2",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", Floor, "1.4e-12", "0x1.8E-10#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "1.6e-12", "0x1.cE-10#3", Greater);
+    test("5.0", "0x5.0#3", Down, "1.4e-12", "0x1.8E-10#3", Less);
+    test("5.0", "0x5.0#3", Up, "1.6e-12", "0x1.cE-10#3", Greater);
+    test("5.0", "0x5.0#3", Nearest, "1.6e-12", "0x1.cE-10#3", Greater);
+    test("0.50", "0x0.8#1", Floor, "0.25", "0x0.4#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "0.50", "0x0.8#1", Greater);
+    test("0.50", "0x0.8#1", Down, "0.25", "0x0.4#1", Less);
+    test("0.50", "0x0.8#1", Up, "0.50", "0x0.8#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "0.50", "0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", Floor, "1.0", "0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "1.0", "0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", Up, "2.0", "0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "1.647e-6573",
+        "0x3.38E-5459#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "1.663e-6573",
+        "0x3.40E-5459#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "1.647e-6573",
+        "0x3.38E-5459#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "1.663e-6573",
+        "0x3.40E-5459#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "1.663e-6573",
+        "0x3.40E-5459#7",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", Floor, "1.9980", "0x1.ff8#10", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", Down, "1.9980", "0x1.ff8#10", Less);
+    test("-123.50", "-0x7b.8#10", Up, "2.0000", "0x2.00#10", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "8.8761463676416001e-6",
+        "0x0.000094eac2241cbf4#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "8.8761463676416137e-6",
+        "0x0.000094eac2241cbf8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "8.8761463676416001e-6",
+        "0x0.000094eac2241cbf4#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "8.8761463676416137e-6",
+        "0x0.000094eac2241cbf8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "8.8761463676416137e-6",
+        "0x0.000094eac2241cbf8#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.00012093104009276267",
+        "0x0.0007ece2dcbdb4ae0#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.00012093104009276269",
+        "0x0.0007ece2dcbdb4ae4#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.00012093104009276267",
+        "0x0.0007ece2dcbdb4ae0#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.00012093104009276269",
+        "0x0.0007ece2dcbdb4ae4#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.00012093104009276267",
+        "0x0.0007ece2dcbdb4ae0#53",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "2.38256490e-323228497",
+        "0x1.000000E-268435456#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "2.38256490e-323228497",
+        "0x1.000000E-268435456#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("27.0", "0x1b.0#5", Floor, "5.06e-319", "0x6.4E-265#5", Less);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        Ceiling,
+        "5.26e-319",
+        "0x6.8E-265#5",
+        Greater,
+    );
+    test("27.0", "0x1b.0#5", Down, "5.06e-319", "0x6.4E-265#5", Less);
+    test("27.0", "0x1b.0#5", Up, "5.26e-319", "0x6.8E-265#5", Greater);
+    test(
+        "27.0",
+        "0x1b.0#5",
+        Nearest,
+        "5.26e-319",
+        "0x6.8E-265#5",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", Floor, "1.5", "0x1.8#2", Less);
+    test("-6.0", "-0x6.0#2", Ceiling, "2.0", "0x2.0#2", Greater);
+    test("-6.0", "-0x6.0#2", Down, "1.5", "0x1.8#2", Less);
+    test("-6.0", "-0x6.0#2", Up, "2.0", "0x2.0#2", Greater);
+    test("-6.0", "-0x6.0#2", Nearest, "2.0", "0x2.0#2", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Floor, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Up, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.63735188823393706659182135985169",
+        "0x0.a3297e4c02340fcadd5ed7587#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.63735188823393706659182135985248",
+        "0x0.a3297e4c02340fcadd5ed7588#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.63735188823393706659182135985169",
+        "0x0.a3297e4c02340fcadd5ed7587#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.63735188823393706659182135985248",
+        "0x0.a3297e4c02340fcadd5ed7588#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.63735188823393706659182135985169",
+        "0x0.a3297e4c02340fcadd5ed7587#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "1.3626481117660629334081786401491",
+        "0x1.5cd681b3fdcbf03522a128a7a#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "1.3626481117660629334081786401491",
+        "0x1.5cd681b3fdcbf03522a128a7a#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+}
+
+#[test]
+fn erfc_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.erfc_round(Exact));
+    assert_panic!(X.erfc_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.erfc_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_erfc_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (erfc, o) = x.clone().erfc_prec_round(prec, rm);
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+        assert_eq!(o, o_out);
+
+        let (erfc_alt, o_alt) = x.erfc_prec_round_ref(prec, rm);
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut erfc_alt = x.clone();
+        let o_alt = erfc_alt.erfc_prec_round_assign(prec, rm);
+        assert!(erfc_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&erfc), ComparableFloatRef(&erfc_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_erfc, rug_o) = rug_erfc_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_erfc)),
+                ComparableFloatRef(&erfc)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "2.0", "0x2.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Down, "2.0", "0x2.0#1", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "2.0", "0x2.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Exact, "2.0", "0x2.0#1", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.15723", "0x0.284#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.15747",
+        "0x0.285#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.15723", "0x0.284#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.15747", "0x0.285#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.15723", "0x0.284#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.15729920705028513065877936491752",
+        "0x0.2844c2c5f7bba9c97f7781227c#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, Floor, "1.8418", "0x1.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "1.8438",
+        "0x1.d80#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "1.8418", "0x1.d78#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "1.8438", "0x1.d80#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "1.8418",
+        "0x1.d78#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "1.8427007929497148693412206350823",
+        "0x1.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "1.8427007929497148693412206350839",
+        "0x1.d7bb3d3a0844563680887edda#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "1.8427007929497148693412206350823",
+        "0x1.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.15723",
+        "0x0.284#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.15747",
+        "0x0.285#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.15723",
+        "0x0.284#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.15747",
+        "0x0.285#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.15723",
+        "0x0.284#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.15729920705028513065877936491752",
+        "0x0.2844c2c5f7bba9c97f7781227c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.000015", "0x0.0001#1", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        Ceiling,
+        "0.000031",
+        "0x0.0002#1",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, Down, "0.000015", "0x0.0001#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "0.000031", "0x0.0002#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "0.000015", "0x0.0001#1", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "0.000022084",
+        "0x0.0001728#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.000022113",
+        "0x0.0001730#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Down,
+        "0.000022084",
+        "0x0.0001728#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Up,
+        "0.000022113",
+        "0x0.0001730#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.000022084",
+        "0x0.0001728#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Floor,
+        "0.000022090496998585441372776129582308",
+        "0x0.0001729df6503422a0bc26526214c#100",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Ceiling,
+        "0.000022090496998585441372776129582332",
+        "0x0.0001729df6503422a0bc26526214e#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.000022090496998585441372776129582332",
+        "0x0.0001729df6503422a0bc26526214e#100",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "9.1e-13", "0x1.0E-10#1", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        1,
+        Ceiling,
+        "1.8e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Down, "9.1e-13", "0x1.0E-10#1", Less);
+    test("5.0", "0x5.0#3", 1, Up, "1.8e-12", "0x2.0E-10#1", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        1,
+        Nearest,
+        "1.8e-12",
+        "0x2.0E-10#1",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Floor,
+        "1.5365e-12",
+        "0x1.b08E-10#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "1.5383e-12",
+        "0x1.b10E-10#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Down,
+        "1.5365e-12",
+        "0x1.b08E-10#10",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Up,
+        "1.5383e-12",
+        "0x1.b10E-10#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "1.5383e-12",
+        "0x1.b10E-10#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Floor,
+        "1.5374597944280348501883434853823e-12",
+        "0x1.b0c1a759f7738935ea5dea8e0E-10#100",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Ceiling,
+        "1.5374597944280348501883434853838e-12",
+        "0x1.b0c1a759f7738935ea5dea8e2E-10#100",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "1.5374597944280348501883434853838e-12",
+        "0x1.b0c1a759f7738935ea5dea8e2E-10#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "0.25", "0x0.4#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("0.50", "0x0.8#1", 10, Floor, "0.47949", "0x0.7ac#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.47998",
+        "0x0.7ae#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "0.47949", "0x0.7ac#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "0.47998", "0x0.7ae#10", Greater);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "0.47949",
+        "0x0.7ac#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "0.47950012218695346231725334610776",
+        "0x0.7ac0851f38916ea17f60e5ce58#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "0.47950012218695346231725334610816",
+        "0x0.7ac0851f38916ea17f60e5ce60#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.47950012218695346231725334610816",
+        "0x0.7ac0851f38916ea17f60e5ce60#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 10, Floor, "1.5195", "0x1.850#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "1.5215",
+        "0x1.858#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Down, "1.5195", "0x1.850#10", Less);
+    test("-0.50", "-0x0.8#1", 10, Up, "1.5215", "0x1.858#10", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "1.5195",
+        "0x1.850#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "1.5204998778130465376827466538918",
+        "0x1.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "1.5204998778130465376827466538934",
+        "0x1.853f7ae0c76e915e809f1a31c#100",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "1.5204998778130465376827466538918",
+        "0x1.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "1.0e-6573",
+        "0x2.0E-5459#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "2.0e-6573",
+        "0x4.0E-5459#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "1.0e-6573",
+        "0x2.0E-5459#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "2.0e-6573",
+        "0x4.0E-5459#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "2.0e-6573",
+        "0x4.0E-5459#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.6590e-6573",
+        "0x3.3eE-5459#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.6610e-6573",
+        "0x3.3fE-5459#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "1.6590e-6573",
+        "0x3.3eE-5459#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "1.6610e-6573",
+        "0x3.3fE-5459#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.6610e-6573",
+        "0x3.3fE-5459#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "1.6606912065400207552876696414597e-6573",
+        "0x3.3ed575a78faf1a8e745db06acE-5459#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "1.6606912065400207552876696414613e-6573",
+        "0x3.3ed575a78faf1a8e745db06b0E-5459#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "1.6606912065400207552876696414597e-6573",
+        "0x3.3ed575a78faf1a8e745db06acE-5459#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("-123.50", "-0x7b.8#10", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-123.50", "-0x7b.8#10", 1, Up, "2.0", "0x2.0#1", Greater);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "1.9980",
+        "0x1.ff8#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "1.9980",
+        "0x1.ff8#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Floor,
+        "1.9999999999999999999999999999984",
+        "0x1.ffffffffffffffffffffffffe#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Ceiling,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.000015",
+        "0x0.0001#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.000015",
+        "0x0.0001#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "7.6e-6",
+        "0x0.00008#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "8.8662e-6",
+        "0x0.000094c#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "8.8811e-6",
+        "0x0.0000950#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "8.8662e-6",
+        "0x0.000094c#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "8.8811e-6",
+        "0x0.0000950#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "8.8811e-6",
+        "0x0.0000950#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "8.8761463676416124158301263443029e-6",
+        "0x0.000094eac2241cbf7a10cd83a7eb6#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "8.8761463676416124158301263443150e-6",
+        "0x0.000094eac2241cbf7a10cd83a7eb7#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "8.8761463676416124158301263443150e-6",
+        "0x0.000094eac2241cbf7a10cd83a7eb7#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.000061",
+        "0x0.0004#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.00012",
+        "0x0.0008#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.000061",
+        "0x0.0004#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.00012",
+        "0x0.0008#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.00012",
+        "0x0.0008#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.00012088",
+        "0x0.0007ec#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.00012100",
+        "0x0.0007ee#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.00012088",
+        "0x0.0007ec#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.00012100",
+        "0x0.0007ee#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.00012088",
+        "0x0.0007ec#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.00012093104009276267521300940515821",
+        "0x0.0007ece2dcbdb4ae106917b494c08#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.00012093104009276267521300940515830",
+        "0x0.0007ece2dcbdb4ae106917b494c10#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.00012093104009276267521300940515821",
+        "0x0.0007ece2dcbdb4ae106917b494c08#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Ceiling,
+        "2.3825649048879510732161697817327e-323228497",
+        "0x1.0000000000000000000000000E-268435456#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        1,
+        Floor,
+        "3.2e-319",
+        "0x4.0E-265#1",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        1,
+        Ceiling,
+        "6.5e-319",
+        "0x8.0E-265#1",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        1,
+        Down,
+        "3.2e-319",
+        "0x4.0E-265#1",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        1,
+        Up,
+        "6.5e-319",
+        "0x8.0E-265#1",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        1,
+        Nearest,
+        "6.5e-319",
+        "0x8.0E-265#1",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Floor,
+        "5.2363e-319",
+        "0x6.78E-265#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Ceiling,
+        "5.2426e-319",
+        "0x6.7aE-265#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Down,
+        "5.2363e-319",
+        "0x6.78E-265#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Up,
+        "5.2426e-319",
+        "0x6.7aE-265#10",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        10,
+        Nearest,
+        "5.2363e-319",
+        "0x6.78E-265#10",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Floor,
+        "5.2370489237892556850160676828476e-319",
+        "0x6.783c337e0e9d7e84c2c582430E-265#100",
+        Less,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Ceiling,
+        "5.2370489237892556850160676828527e-319",
+        "0x6.783c337e0e9d7e84c2c582438E-265#100",
+        Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        100,
+        Nearest,
+        "5.2370489237892556850160676828476e-319",
+        "0x6.783c337e0e9d7e84c2c582430E-265#100",
+        Less,
+    );
+    test("-6.0", "-0x6.0#2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-6.0", "-0x6.0#2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-6.0", "-0x6.0#2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-6.0", "-0x6.0#2", 10, Floor, "1.9980", "0x1.ff8#10", Less);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Ceiling,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test("-6.0", "-0x6.0#2", 10, Down, "1.9980", "0x1.ff8#10", Less);
+    test("-6.0", "-0x6.0#2", 10, Up, "2.0000", "0x2.00#10", Greater);
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        10,
+        Nearest,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Floor,
+        "1.9999999999999999784802632875001",
+        "0x1.fffffffffffffe7307eaa82de#100",
+        Less,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Ceiling,
+        "1.9999999999999999784802632875016",
+        "0x1.fffffffffffffe7307eaa82e0#100",
+        Greater,
+    );
+    test(
+        "-6.0",
+        "-0x6.0#2",
+        100,
+        Nearest,
+        "1.9999999999999999784802632875016",
+        "0x1.fffffffffffffe7307eaa82e0#100",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "0.99999999999999999999999999999842",
+        "0x0.ffffffffffffffffffffffffe#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.63672",
+        "0x0.a30#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.63770",
+        "0x0.a34#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.63672",
+        "0x0.a30#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.63770",
+        "0x0.a34#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.63770",
+        "0x0.a34#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.63735188823393706659182135985169",
+        "0x0.a3297e4c02340fcadd5ed7587#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.63735188823393706659182135985248",
+        "0x0.a3297e4c02340fcadd5ed7588#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.63735188823393706659182135985169",
+        "0x0.a3297e4c02340fcadd5ed7587#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "1.3613",
+        "0x1.5c8#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "1.3633",
+        "0x1.5d0#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "1.3613",
+        "0x1.5c8#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "1.3633",
+        "0x1.5d0#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "1.3633",
+        "0x1.5d0#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "1.3626481117660629334081786401491",
+        "0x1.5cd681b3fdcbf03522a128a7a#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+}
+
+#[test]
+fn erfc_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).erfc_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).erfc_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.erfc_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.erfc_prec_round(1, Exact));
+    assert_panic!(X.erfc_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.erfc_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_erfc_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (erfc, o) = Float::erfc_rational_prec(x.clone(), prec);
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+        assert_eq!(o, out_o);
+
+        let (erfc, o) = Float::erfc_rational_prec_ref(&x, prec);
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_erfc, rug_o) = rug_erfc_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erfc)),
+            ComparableFloatRef(&erfc)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "1.0", "0x1.0#1", Equal);
+    test("0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, "0.12", "0x0.2#1", Less);
+    test("1", 10, "0.15723", "0x0.284#10", Less);
+    test(
+        "1",
+        100,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test("-1", 1, "2.0", "0x2.0#1", Greater);
+    test("-1", 10, "1.8418", "0x1.d78#10", Less);
+    test(
+        "-1",
+        100,
+        "1.8427007929497148693412206350823",
+        "0x1.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, "0.47949", "0x0.7ac#10", Less);
+    test(
+        "1/2",
+        100,
+        "0.47950012218695346231725334610816",
+        "0x0.7ac0851f38916ea17f60e5ce60#100",
+        Greater,
+    );
+    test("-1/2", 1, "2.0", "0x2.0#1", Greater);
+    test("-1/2", 10, "1.5195", "0x1.850#10", Less);
+    test(
+        "-1/2",
+        100,
+        "1.5204998778130465376827466538918",
+        "0x1.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test("1/3", 1, "0.50", "0x0.8#1", Less);
+    test("1/3", 10, "0.63770", "0x0.a34#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.63735188823393706659182135985248",
+        "0x0.a3297e4c02340fcadd5ed7588#100",
+        Greater,
+    );
+    test("-1/3", 1, "1.0", "0x1.0#1", Less);
+    test("-1/3", 10, "1.3633", "0x1.5d0#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+    test("22/7", 1, "7.6e-6", "0x0.00008#1", Less);
+    test("22/7", 10, "8.8066e-6", "0x0.000093c#10", Greater);
+    test(
+        "22/7",
+        100,
+        "8.8026389529003049814773243163448e-6",
+        "0x0.000093af0be2428a8dc3f0971dbbb#100",
+        Greater,
+    );
+    test("5", 1, "1.8e-12", "0x2.0E-10#1", Greater);
+    test("5", 10, "1.5383e-12", "0x1.b10E-10#10", Greater);
+    test(
+        "5",
+        100,
+        "1.5374597944280348501883434853838e-12",
+        "0x1.b0c1a759f7738935ea5dea8e2E-10#100",
+        Greater,
+    );
+    test("100", 1, "4.3e-4346", "0x2.0E-3609#1", Less);
+    test("100", 10, "6.4033e-4346", "0x2.fdE-3609#10", Less);
+    test(
+        "100",
+        100,
+        "6.4059614249217320390213391485833e-4346",
+        "0x2.fd514cef7750e58906601ff34E-3609#100",
+        Less,
+    );
+    test("-7/2", 1, "2.0", "0x2.0#1", Greater);
+    test("-7/2", 10, "2.0000", "0x2.00#10", Greater);
+    test(
+        "-7/2",
+        100,
+        "1.9999992569016276585872544763162",
+        "0x1.fffff3886ab2f393d4a5aedb8#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn erfc_rational_prec_fail() {
+    Float::erfc_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn erfc_rational_prec_ref_fail() {
+    Float::erfc_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_erfc_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (erfc, o) = Float::erfc_rational_prec_round(x.clone(), prec, rm);
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+        assert_eq!(o, out_o);
+
+        let (erfc, o) = Float::erfc_rational_prec_round_ref(&x, prec, rm);
+        assert!(erfc.is_valid());
+        assert_eq!(erfc.to_string(), out);
+        assert_eq!(to_hex_string(&erfc), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_erfc, rug_o) = rug_erfc_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_erfc)),
+                ComparableFloatRef(&erfc)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, Floor, "0.12", "0x0.2#1", Less);
+    test("1", 1, Ceiling, "0.25", "0x0.4#1", Greater);
+    test("1", 1, Down, "0.12", "0x0.2#1", Less);
+    test("1", 1, Up, "0.25", "0x0.4#1", Greater);
+    test("1", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("1", 10, Floor, "0.15723", "0x0.284#10", Less);
+    test("1", 10, Ceiling, "0.15747", "0x0.285#10", Greater);
+    test("1", 10, Down, "0.15723", "0x0.284#10", Less);
+    test("1", 10, Up, "0.15747", "0x0.285#10", Greater);
+    test("1", 10, Nearest, "0.15723", "0x0.284#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.15729920705028513065877936491732",
+        "0x0.2844c2c5f7bba9c97f77812278#100",
+        Less,
+    );
+    test("-1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1", 10, Floor, "1.8418", "0x1.d78#10", Less);
+    test("-1", 10, Ceiling, "1.8438", "0x1.d80#10", Greater);
+    test("-1", 10, Down, "1.8418", "0x1.d78#10", Less);
+    test("-1", 10, Up, "1.8438", "0x1.d80#10", Greater);
+    test("-1", 10, Nearest, "1.8418", "0x1.d78#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "1.8427007929497148693412206350823",
+        "0x1.d7bb3d3a0844563680887edd8#100",
+        Less,
+    );
+    test("1/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, Floor, "0.47949", "0x0.7ac#10", Less);
+    test("1/2", 10, Ceiling, "0.47998", "0x0.7ae#10", Greater);
+    test("1/2", 10, Down, "0.47949", "0x0.7ac#10", Less);
+    test("1/2", 10, Up, "0.47998", "0x0.7ae#10", Greater);
+    test("1/2", 10, Nearest, "0.47949", "0x0.7ac#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.47950012218695346231725334610816",
+        "0x0.7ac0851f38916ea17f60e5ce60#100",
+        Greater,
+    );
+    test("-1/2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1/2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1/2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1/2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1/2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1/2", 10, Floor, "1.5195", "0x1.850#10", Less);
+    test("-1/2", 10, Ceiling, "1.5215", "0x1.858#10", Greater);
+    test("-1/2", 10, Down, "1.5195", "0x1.850#10", Less);
+    test("-1/2", 10, Up, "1.5215", "0x1.858#10", Greater);
+    test("-1/2", 10, Nearest, "1.5195", "0x1.850#10", Less);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "1.5204998778130465376827466538918",
+        "0x1.853f7ae0c76e915e809f1a31a#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1/3", 10, Floor, "0.63672", "0x0.a30#10", Less);
+    test("1/3", 10, Ceiling, "0.63770", "0x0.a34#10", Greater);
+    test("1/3", 10, Down, "0.63672", "0x0.a30#10", Less);
+    test("1/3", 10, Up, "0.63770", "0x0.a34#10", Greater);
+    test("1/3", 10, Nearest, "0.63770", "0x0.a34#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.63735188823393706659182135985248",
+        "0x0.a3297e4c02340fcadd5ed7588#100",
+        Greater,
+    );
+    test("-1/3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-1/3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-1/3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-1/3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("-1/3", 10, Floor, "1.3613", "0x1.5c8#10", Less);
+    test("-1/3", 10, Ceiling, "1.3633", "0x1.5d0#10", Greater);
+    test("-1/3", 10, Down, "1.3613", "0x1.5c8#10", Less);
+    test("-1/3", 10, Up, "1.3633", "0x1.5d0#10", Greater);
+    test("-1/3", 10, Nearest, "1.3633", "0x1.5d0#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "1.3626481117660629334081786401475",
+        "0x1.5cd681b3fdcbf03522a128a78#100",
+        Less,
+    );
+    test("22/7", 1, Floor, "7.6e-6", "0x0.00008#1", Less);
+    test("22/7", 1, Ceiling, "0.000015", "0x0.0001#1", Greater);
+    test("22/7", 1, Down, "7.6e-6", "0x0.00008#1", Less);
+    test("22/7", 1, Up, "0.000015", "0x0.0001#1", Greater);
+    test("22/7", 1, Nearest, "7.6e-6", "0x0.00008#1", Less);
+    test("22/7", 10, Floor, "8.7917e-6", "0x0.0000938#10", Less);
+    test("22/7", 10, Ceiling, "8.8066e-6", "0x0.000093c#10", Greater);
+    test("22/7", 10, Down, "8.7917e-6", "0x0.0000938#10", Less);
+    test("22/7", 10, Up, "8.8066e-6", "0x0.000093c#10", Greater);
+    test("22/7", 10, Nearest, "8.8066e-6", "0x0.000093c#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "8.8026389529003049814773243163448e-6",
+        "0x0.000093af0be2428a8dc3f0971dbbb#100",
+        Greater,
+    );
+    test("5", 1, Floor, "9.1e-13", "0x1.0E-10#1", Less);
+    test("5", 1, Ceiling, "1.8e-12", "0x2.0E-10#1", Greater);
+    test("5", 1, Down, "9.1e-13", "0x1.0E-10#1", Less);
+    test("5", 1, Up, "1.8e-12", "0x2.0E-10#1", Greater);
+    test("5", 1, Nearest, "1.8e-12", "0x2.0E-10#1", Greater);
+    test("5", 10, Floor, "1.5365e-12", "0x1.b08E-10#10", Less);
+    test("5", 10, Ceiling, "1.5383e-12", "0x1.b10E-10#10", Greater);
+    test("5", 10, Down, "1.5365e-12", "0x1.b08E-10#10", Less);
+    test("5", 10, Up, "1.5383e-12", "0x1.b10E-10#10", Greater);
+    test("5", 10, Nearest, "1.5383e-12", "0x1.b10E-10#10", Greater);
+    test(
+        "5",
+        100,
+        Nearest,
+        "1.5374597944280348501883434853838e-12",
+        "0x1.b0c1a759f7738935ea5dea8e2E-10#100",
+        Greater,
+    );
+    test("100", 1, Floor, "4.3e-4346", "0x2.0E-3609#1", Less);
+    test("100", 1, Ceiling, "8.6e-4346", "0x4.0E-3609#1", Greater);
+    test("100", 1, Down, "4.3e-4346", "0x2.0E-3609#1", Less);
+    test("100", 1, Up, "8.6e-4346", "0x4.0E-3609#1", Greater);
+    test("100", 1, Nearest, "4.3e-4346", "0x2.0E-3609#1", Less);
+    test("100", 10, Floor, "6.4033e-4346", "0x2.fdE-3609#10", Less);
+    test(
+        "100",
+        10,
+        Ceiling,
+        "6.4117e-4346",
+        "0x2.feE-3609#10",
+        Greater,
+    );
+    test("100", 10, Down, "6.4033e-4346", "0x2.fdE-3609#10", Less);
+    test("100", 10, Up, "6.4117e-4346", "0x2.feE-3609#10", Greater);
+    test("100", 10, Nearest, "6.4033e-4346", "0x2.fdE-3609#10", Less);
+    test(
+        "100",
+        100,
+        Nearest,
+        "6.4059614249217320390213391485833e-4346",
+        "0x2.fd514cef7750e58906601ff34E-3609#100",
+        Less,
+    );
+    test("-7/2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("-7/2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-7/2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("-7/2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("-7/2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("-7/2", 10, Floor, "1.9980", "0x1.ff8#10", Less);
+    test("-7/2", 10, Ceiling, "2.0000", "0x2.00#10", Greater);
+    test("-7/2", 10, Down, "1.9980", "0x1.ff8#10", Less);
+    test("-7/2", 10, Up, "2.0000", "0x2.00#10", Greater);
+    test("-7/2", 10, Nearest, "2.0000", "0x2.00#10", Greater);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "1.9999992569016276585872544763162",
+        "0x1.fffff3886ab2f393d4a5aedb8#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn erfc_rational_prec_round_fail_1() {
+    Float::erfc_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn erfc_rational_prec_round_fail_2() {
+    Float::erfc_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn erfc_rational_prec_round_ref_fail() {
+    Float::erfc_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erfc_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (erfc, o) = x.clone().erfc_prec_round(prec, rm);
+    assert!(erfc.is_valid());
+
+    let (erfc_alt, o_alt) = x.erfc_prec_round_ref(prec, rm);
+    assert!(erfc_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.erfc_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_erfc, rug_o) = rug_erfc_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erfc)),
+            ComparableFloatRef(&erfc)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(erfc.is_nan(), x.is_nan());
+    assert!(erfc.is_nan() || (erfc >= 0u32 && erfc <= 2u32));
+
+    if erfc.is_normal() {
+        assert_eq!(erfc.get_prec(), Some(prec));
+    }
+    if x.is_normal() {
+        let (erfc_alt, o_alt) = Float::erfc_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.erfc_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(erfc.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.erfc_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn erfc_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        erfc_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (erfc, o) = Float::NAN.erfc_prec_round(prec, rm);
+        assert!(erfc.is_nan());
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::INFINITY.erfc_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(erfc), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::NEGATIVE_INFINITY.erfc_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::from_unsigned_prec(2u32, prec).0)
+        );
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::ZERO.erfc_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::NEGATIVE_ZERO.erfc_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erfc_prec_properties_helper(x: Float, prec: u64) {
+    let (erfc, o) = x.clone().erfc_prec(prec);
+    assert!(erfc.is_valid());
+
+    let (erfc_alt, o_alt) = x.erfc_prec_ref(prec);
+    assert!(erfc_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.erfc_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let (erfc_alt, o_alt) = x.erfc_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let (rug_erfc, rug_o) = rug_erfc_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_erfc)),
+        ComparableFloatRef(&erfc)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(erfc.is_nan() || (erfc >= 0u32 && erfc <= 2u32));
+
+    if erfc.is_normal() {
+        assert_eq!(erfc.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn erfc_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        erfc_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (erfc, o) = Float::NAN.erfc_prec(prec);
+        assert!(erfc.is_nan());
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::INFINITY.erfc_prec(prec);
+        assert_eq!(ComparableFloat(erfc), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::NEGATIVE_INFINITY.erfc_prec(prec);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::from_unsigned_prec(2u32, prec).0)
+        );
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::ZERO.erfc_prec(prec);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::NEGATIVE_ZERO.erfc_prec(prec);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erfc_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (erfc, o) = x.clone().erfc_round(rm);
+    assert!(erfc.is_valid());
+
+    let (erfc_alt, o_alt) = x.erfc_round_ref(rm);
+    assert!(erfc_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.erfc_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let (erfc_alt, o_alt) = x.erfc_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    assert!(erfc.is_nan() || (erfc >= 0u32 && erfc <= 2u32));
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_erfc, rug_o) = rug_erfc_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erfc)),
+            ComparableFloatRef(&erfc)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.erfc_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(erfc.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.erfc_round_ref(Exact));
+    }
+}
+
+#[test]
+fn erfc_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        erfc_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (erfc, o) = Float::NAN.erfc_round(rm);
+        assert!(erfc.is_nan());
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::INFINITY.erfc_round(rm);
+        assert_eq!(ComparableFloat(erfc), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::NEGATIVE_INFINITY.erfc_round(rm);
+        assert_eq!(ComparableFloat(erfc), ComparableFloat(Float::TWO));
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::ZERO.erfc_round(rm);
+        assert_eq!(ComparableFloat(erfc), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+
+        let (erfc, o) = Float::NEGATIVE_ZERO.erfc_round(rm);
+        assert_eq!(ComparableFloat(erfc), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erfc_properties_helper(x: Float) {
+    let erfc = x.clone().erfc();
+    assert!(erfc.is_valid());
+
+    let erfc_alt = (&x).erfc();
+    assert!(erfc_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+
+    let mut x_alt = x.clone();
+    x_alt.erfc_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&erfc));
+
+    let erfc_alt = x.erfc_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    let erfc_alt = x.erfc_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+
+    assert_eq!(erfc.is_nan(), x.is_nan());
+    assert!(erfc.is_nan() || (erfc >= 0u32 && erfc <= 2u32));
+
+    let rug_erfc = rug_erfc(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_erfc)),
+        ComparableFloatRef(&erfc),
+    );
+}
+
+#[test]
+fn erfc_properties() {
+    float_gen().test_properties(|x| {
+        erfc_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erfc_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (erfc, o) = Float::erfc_rational_prec_round(x.clone(), prec, rm);
+    assert!(erfc.is_valid());
+
+    let (erfc_alt, o_alt) = Float::erfc_rational_prec_round_ref(&x, prec, rm);
+    assert!(erfc_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_erfc, rug_o) = rug_erfc_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_erfc)),
+            ComparableFloatRef(&erfc)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!erfc.is_nan());
+    assert!(erfc.is_nan() || (erfc >= 0u32 && erfc <= 2u32));
+
+    if erfc.is_normal() {
+        assert_eq!(erfc.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::erfc_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(erfc.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::erfc_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn erfc_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        erfc_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (erfc, o) = Float::erfc_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn erfc_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (erfc, o) = Float::erfc_rational_prec(x.clone(), prec);
+    assert!(erfc.is_valid());
+
+    let (erfc_alt, o_alt) = Float::erfc_rational_prec_ref(&x, prec);
+    assert!(erfc_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let (erfc_alt, o_alt) = Float::erfc_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&erfc_alt), ComparableFloatRef(&erfc));
+    assert_eq!(o_alt, o);
+
+    let (rug_erfc, rug_o) = rug_erfc_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_erfc)),
+        ComparableFloatRef(&erfc)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(erfc.is_nan() || (erfc >= 0u32 && erfc <= 2u32));
+
+    if erfc.is_normal() {
+        assert_eq!(erfc.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn erfc_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        erfc_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (erfc, o) = Float::erfc_rational_prec(Rational::ZERO, prec);
+        assert_eq!(
+            ComparableFloat(erfc),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_erfc() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_erfc(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, 2.0);
+    test::<f32>(0.0, 1.0);
+    test::<f32>(-0.0, 1.0);
+    test::<f32>(1.0, 0.1572992);
+    test::<f32>(-1.0, 1.8427008);
+    test::<f32>(0.5, 0.47950011);
+    test::<f32>(-0.5, 1.5204998);
+    test::<f32>(2.0, 0.004677735);
+    test::<f32>(5.0, 1.5374598e-12);
+    test::<f32>(-2.5, 1.999593);
+    test::<f32>(10.0, 1.0e-45);
+    test::<f32>(0.001, 0.9988716);
+    test::<f32>(10000000000.0, 0.0);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, 2.0);
+    test::<f64>(0.0, 1.0);
+    test::<f64>(-0.0, 1.0);
+    test::<f64>(1.0, 0.15729920705028513);
+    test::<f64>(-1.0, 1.8427007929497148);
+    test::<f64>(0.5, 0.4795001221869535);
+    test::<f64>(-0.5, 1.5204998778130465);
+    test::<f64>(2.0, 0.004677734981047266);
+    test::<f64>(5.0, 1.537459794428035e-12);
+    test::<f64>(-2.5, 1.999593047982555);
+    test::<f64>(10.0, 2.088487583762545e-45);
+    test::<f64>(0.001, 0.9988716212090307);
+    test::<f64>(10000000000.0, 0.0);
+    test::<f64>(1.0e300, 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_erfc_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let erfc = primitive_float_erfc(x);
+        assert_eq!(erfc.is_nan(), x.is_nan());
+        assert!(erfc.is_nan() || erfc >= T::ZERO && erfc <= T::TWO);
+    });
+}
+
+#[test]
+fn primitive_float_erfc_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_erfc_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_erfc_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_erfc_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 1.0);
+    test::<f32>("1", 0.1572992);
+    test::<f32>("-1", 1.8427008);
+    test::<f32>("1/2", 0.47950011);
+    test::<f32>("-1/2", 1.5204998);
+    test::<f32>("1/3", 0.6373519);
+    test::<f32>("22/7", 0.000008802639);
+    test::<f32>("3/2", 0.03389485);
+    test::<f32>("-7/2", 1.9999993);
+    test::<f32>("1000000", 0.0);
+
+    test::<f64>("0", 1.0);
+    test::<f64>("1", 0.15729920705028513);
+    test::<f64>("-1", 1.8427007929497148);
+    test::<f64>("1/2", 0.4795001221869535);
+    test::<f64>("-1/2", 1.5204998778130465);
+    test::<f64>("1/3", 0.6373518882339371);
+    test::<f64>("22/7", 8.802638952900305e-6);
+    test::<f64>("3/2", 0.033894853524689274);
+    test::<f64>("-7/2", 1.9999992569016276);
+    test::<f64>("1000000", 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_erfc_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let erfc = primitive_float_erfc_rational::<T>(&x);
+        assert!(!erfc.is_nan());
+        assert!(erfc.is_nan() || erfc >= T::ZERO && erfc <= T::TWO);
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_erfc_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_erfc(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_erfc_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_erfc_rational_properties_helper);
+}
@@ -0,0 +1,3209 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Gamma, GammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::gamma::{
+    primitive_float_gamma, primitive_float_gamma_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::gamma::{
+    rug_gamma, rug_gamma_prec, rug_gamma_prec_round, rug_gamma_rational_prec,
+    rug_gamma_rational_prec_round, rug_gamma_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_gamma() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let gamma = x.clone().gamma();
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+
+        let gamma_alt = (&x).gamma();
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+
+        let mut gamma_alt = x.clone();
+        gamma_alt.gamma_assign();
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&gamma)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+    );
+    test("3.0", "0x3.0#2", "2.0", "0x2.0#2");
+    test("5.0", "0x5.0#3", "24.0", "0x18.0#3");
+    test("0.50", "0x0.8#1", "2.0", "0x2.0#1");
+    test("-0.50", "-0x0.8#1", "-4.0", "-0x4.0#1");
+    test("123.0", "0x7b.0#7", "9.920e202", "0x5.1E+168#7");
+    test("-123.50", "-0x7b.8#10", "2.3239e-206", "0x1.dd8E-171#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.5674682557740529",
+        "0x1.9145998011562#53",
+    );
+    test("1.00000000e10", "0x2.540be4E+8#24", "Infinity", "Infinity");
+    test("7.9e-31", "0x1.0E-25#1", "1.3e30", "0x1.0E+25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+    );
+}
+
+#[test]
+fn test_gamma_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (gamma, o) = x.clone().gamma_prec(prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = x.gamma_prec_ref(prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut gamma_alt = x.clone();
+        let o_alt = gamma_alt.gamma_prec_assign(prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_gamma, rug_o) = rug_gamma_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 10, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 100, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 100, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", 1, "2.0", "0x2.0#1", Equal);
+    test("3.0", "0x3.0#2", 10, "2.0000", "0x2.00#10", Equal);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("5.0", "0x5.0#3", 1, "32.0", "0x2.0E+1#1", Greater);
+    test("5.0", "0x5.0#3", 10, "24.000", "0x18.00#10", Equal);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "24.000000000000000000000000000000",
+        "0x18.000000000000000000000000#100",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", 1, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, "1.7715", "0x1.c58#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, "-4.0", "-0x4.0#1", Less);
+    test("-0.50", "-0x0.8#1", 10, "-3.5430", "-0x3.8b#10", Greater);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "-3.5449077018110320545963349666825",
+        "-0x3.8b7f12369ded54f38760a41ac#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "7.8e202", "0x4.0E+168#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "9.8743e202",
+        "0x5.0aE+168#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "9.8750442008336013624115798714518e202",
+        "0x5.0a187fb942200b319c67dccd0E+168#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        "2.5e-206",
+        "0x2.0E-171#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        "2.3239e-206",
+        "0x1.dd8E-171#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "2.3250519773157764666249527864978e-206",
+        "0x1.ddbce4cf51d7918d3097156c2E-171#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "2.2880377953400321441406907440419",
+        "0x2.49bcd84eff543f41b598fe364#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.5684",
+        "0x1.918#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.5674682557740528924665948786645",
+        "0x1.9145998011561ebf35b8777ce#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1267650600228229401496703205375.0",
+        "0xfffffffffffffffffffffffff.0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "-4.0625",
+        "-0x4.10#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Less,
+    );
+}
+
+#[test]
+fn gamma_prec_fail() {
+    assert_panic!(Float::NAN.gamma_prec(0));
+    assert_panic!(Float::NAN.gamma_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.gamma_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_gamma_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (gamma, o) = x.clone().gamma_round(rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = x.gamma_round_ref(rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut gamma_alt = x.clone();
+        let o_alt = gamma_alt.gamma_round_assign(rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_gamma, rug_o) = rug_gamma_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Down, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Up, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Exact, "1.0", "0x1.0#1", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", Floor, "2.0", "0x2.0#2", Equal);
+    test("3.0", "0x3.0#2", Ceiling, "2.0", "0x2.0#2", Equal);
+    test("3.0", "0x3.0#2", Down, "2.0", "0x2.0#2", Equal);
+    test("3.0", "0x3.0#2", Up, "2.0", "0x2.0#2", Equal);
+    test("3.0", "0x3.0#2", Nearest, "2.0", "0x2.0#2", Equal);
+    test("3.0", "0x3.0#2", Exact, "2.0", "0x2.0#2", Equal);
+    test("5.0", "0x5.0#3", Floor, "24.0", "0x18.0#3", Equal);
+    test("5.0", "0x5.0#3", Ceiling, "24.0", "0x18.0#3", Equal);
+    test("5.0", "0x5.0#3", Down, "24.0", "0x18.0#3", Equal);
+    test("5.0", "0x5.0#3", Up, "24.0", "0x18.0#3", Equal);
+    test("5.0", "0x5.0#3", Nearest, "24.0", "0x18.0#3", Equal);
+    test("5.0", "0x5.0#3", Exact, "24.0", "0x18.0#3", Equal);
+    test("0.50", "0x0.8#1", Floor, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", Up, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Floor, "-4.0", "-0x4.0#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "-2.0", "-0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Up, "-4.0", "-0x4.0#1", Less);
+    test("-0.50", "-0x0.8#1", Nearest, "-4.0", "-0x4.0#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "9.798e202",
+        "0x5.0E+168#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "9.920e202",
+        "0x5.1E+168#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "9.798e202", "0x5.0E+168#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "9.920e202",
+        "0x5.1E+168#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "9.920e202",
+        "0x5.1E+168#7",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Floor,
+        "2.3239e-206",
+        "0x1.dd8E-171#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "2.3263e-206",
+        "0x1.de0E-171#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Down,
+        "2.3239e-206",
+        "0x1.dd8E-171#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Up,
+        "2.3263e-206",
+        "0x1.de0E-171#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "2.3239e-206",
+        "0x1.dd8E-171#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "2.2880377953400348",
+        "0x2.49bcd84eff55#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "2.2880377953400348",
+        "0x2.49bcd84eff55#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.5674682557740527",
+        "0x1.9145998011561#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.5674682557740529",
+        "0x1.9145998011562#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.5674682557740527",
+        "0x1.9145998011561#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.5674682557740529",
+        "0x1.9145998011562#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.5674682557740529",
+        "0x1.9145998011562#53",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "2.09857859e323228496",
+        "0x7.fffff8E+268435455#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "2.09857859e323228496",
+        "0x7.fffff8E+268435455#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "2.6789385347077476336556929409705",
+        "0x2.adceea72905e2ceec8d3e92c8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "2.6789385347077476336556929409705",
+        "0x2.adceea72905e2ceec8d3e92c8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-4.0623538182792012508358640844572",
+        "-0x4.0ff66b7a4a36a6018defc2500#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "-4.0623538182792012508358640844572",
+        "-0x4.0ff66b7a4a36a6018defc2500#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Less,
+    );
+}
+
+#[test]
+fn gamma_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.gamma_round(Exact));
+    assert_panic!(X.gamma_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.gamma_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_gamma_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (gamma, o) = x.clone().gamma_prec_round(prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = x.gamma_prec_round_ref(prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut gamma_alt = x.clone();
+        let o_alt = gamma_alt.gamma_prec_round_assign(prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_gamma, rug_o) = rug_gamma_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Down, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Up, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, Nearest, "NaN", "NaN", Equal);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Exact,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Exact,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "2.0", "0x2.0#1", Equal);
+    test("3.0", "0x3.0#2", 1, Ceiling, "2.0", "0x2.0#1", Equal);
+    test("3.0", "0x3.0#2", 1, Down, "2.0", "0x2.0#1", Equal);
+    test("3.0", "0x3.0#2", 1, Up, "2.0", "0x2.0#1", Equal);
+    test("3.0", "0x3.0#2", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test("3.0", "0x3.0#2", 1, Exact, "2.0", "0x2.0#1", Equal);
+    test("3.0", "0x3.0#2", 10, Floor, "2.0000", "0x2.00#10", Equal);
+    test("3.0", "0x3.0#2", 10, Ceiling, "2.0000", "0x2.00#10", Equal);
+    test("3.0", "0x3.0#2", 10, Down, "2.0000", "0x2.00#10", Equal);
+    test("3.0", "0x3.0#2", 10, Up, "2.0000", "0x2.00#10", Equal);
+    test("3.0", "0x3.0#2", 10, Nearest, "2.0000", "0x2.00#10", Equal);
+    test("3.0", "0x3.0#2", 10, Exact, "2.0000", "0x2.00#10", Equal);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Floor,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Ceiling,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "16.0", "0x1.0E+1#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "32.0", "0x2.0E+1#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "16.0", "0x1.0E+1#1", Less);
+    test("5.0", "0x5.0#3", 1, Up, "32.0", "0x2.0E+1#1", Greater);
+    test("5.0", "0x5.0#3", 1, Nearest, "32.0", "0x2.0E+1#1", Greater);
+    test("5.0", "0x5.0#3", 10, Floor, "24.000", "0x18.00#10", Equal);
+    test("5.0", "0x5.0#3", 10, Ceiling, "24.000", "0x18.00#10", Equal);
+    test("5.0", "0x5.0#3", 10, Down, "24.000", "0x18.00#10", Equal);
+    test("5.0", "0x5.0#3", 10, Up, "24.000", "0x18.00#10", Equal);
+    test("5.0", "0x5.0#3", 10, Nearest, "24.000", "0x18.00#10", Equal);
+    test("5.0", "0x5.0#3", 10, Exact, "24.000", "0x18.00#10", Equal);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Floor,
+        "24.000000000000000000000000000000",
+        "0x18.000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Ceiling,
+        "24.000000000000000000000000000000",
+        "0x18.000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "24.000000000000000000000000000000",
+        "0x18.000000000000000000000000#100",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, Floor, "1.7715", "0x1.c58#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "1.7734",
+        "0x1.c60#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Down, "1.7715", "0x1.c58#10", Less);
+    test("0.50", "0x0.8#1", 10, Up, "1.7734", "0x1.c60#10", Greater);
+    test("0.50", "0x0.8#1", 10, Nearest, "1.7715", "0x1.c58#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "1.7724538509055160272981674833397",
+        "0x1.c5bf891b4ef6aa79c3b0520d4#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "-4.0", "-0x4.0#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Down, "-2.0", "-0x2.0#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Up, "-4.0", "-0x4.0#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "-4.0", "-0x4.0#1", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "-3.5469",
+        "-0x3.8c#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-3.5430",
+        "-0x3.8b#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Down,
+        "-3.5430",
+        "-0x3.8b#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Up, "-3.5469", "-0x3.8c#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-3.5430",
+        "-0x3.8b#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "-3.5449077018110320545963349666825",
+        "-0x3.8b7f12369ded54f38760a41ac#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "-3.5449077018110320545963349666794",
+        "-0x3.8b7f12369ded54f38760a41a8#100",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "-3.5449077018110320545963349666825",
+        "-0x3.8b7f12369ded54f38760a41ac#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "7.8e202",
+        "0x4.0E+168#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.6e203",
+        "0x8.0E+168#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "7.8e202",
+        "0x4.0E+168#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "1.6e203",
+        "0x8.0E+168#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "7.8e202",
+        "0x4.0E+168#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "9.8743e202",
+        "0x5.0aE+168#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "9.8896e202",
+        "0x5.0cE+168#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "9.8743e202",
+        "0x5.0aE+168#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "9.8896e202",
+        "0x5.0cE+168#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "9.8743e202",
+        "0x5.0aE+168#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "9.8750442008336013624115798714394e202",
+        "0x5.0a187fb942200b319c67dccc8E+168#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "9.8750442008336013624115798714518e202",
+        "0x5.0a187fb942200b319c67dccd0E+168#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "9.8750442008336013624115798714518e202",
+        "0x5.0a187fb942200b319c67dccd0E+168#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Floor,
+        "1.2e-206",
+        "0x1.0E-171#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "2.5e-206",
+        "0x2.0E-171#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Down,
+        "1.2e-206",
+        "0x1.0E-171#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Up,
+        "2.5e-206",
+        "0x2.0E-171#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "2.5e-206",
+        "0x2.0E-171#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "2.3239e-206",
+        "0x1.dd8E-171#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "2.3263e-206",
+        "0x1.de0E-171#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "2.3239e-206",
+        "0x1.dd8E-171#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "2.3263e-206",
+        "0x1.de0E-171#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "2.3239e-206",
+        "0x1.dd8E-171#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Floor,
+        "2.3250519773157764666249527864978e-206",
+        "0x1.ddbce4cf51d7918d3097156c2E-171#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Ceiling,
+        "2.3250519773157764666249527864998e-206",
+        "0x1.ddbce4cf51d7918d3097156c4E-171#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "2.3250519773157764666249527864978e-206",
+        "0x1.ddbce4cf51d7918d3097156c2E-171#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "2.2852",
+        "0x2.49#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "2.2852",
+        "0x2.49#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "2.2880377953400321441406907440419",
+        "0x2.49bcd84eff543f41b598fe364#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "2.2880377953400321441406907440451",
+        "0x2.49bcd84eff543f41b598fe368#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "2.2880377953400321441406907440419",
+        "0x2.49bcd84eff543f41b598fe364#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.5664",
+        "0x1.910#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.5684",
+        "0x1.918#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.5664",
+        "0x1.910#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.5684",
+        "0x1.918#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.5684",
+        "0x1.918#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "1.5674682557740528924665948786645",
+        "0x1.9145998011561ebf35b8777ce#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "1.5674682557740528924665948786660",
+        "0x1.9145998011561ebf35b8777d0#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "1.5674682557740528924665948786645",
+        "0x1.9145998011561ebf35b8777ce#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Floor,
+        "2.0985787164673876924043581168822e323228496",
+        "0x7.ffffffffffffffffffffffff8E+268435455#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.2664e30",
+        "0xf.fcE+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "1.2664e30",
+        "0xf.fcE+24#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "1267650600228229401496703205375.0",
+        "0xfffffffffffffffffffffffff.0#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1267650600228229401496703205375.0",
+        "0xfffffffffffffffffffffffff.0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "2.6758",
+        "0x2.ad#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "2.6758",
+        "0x2.ad#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "2.6789385347077476336556929409705",
+        "0x2.adceea72905e2ceec8d3e92c8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-8.0",
+        "-0x8.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-8.0",
+        "-0x8.0#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-4.0625",
+        "-0x4.10#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-4.0547",
+        "-0x4.0e#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-4.0547",
+        "-0x4.0e#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-4.0625",
+        "-0x4.10#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-4.0625",
+        "-0x4.10#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "-4.0623538182792012508358640844572",
+        "-0x4.0ff66b7a4a36a6018defc2500#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Less,
+    );
+}
+
+#[test]
+fn gamma_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).gamma_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).gamma_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.gamma_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.gamma_prec_round(1, Exact));
+    assert_panic!(X.gamma_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.gamma_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_gamma_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (gamma, o) = Float::gamma_rational_prec(x.clone(), prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::gamma_rational_prec_ref(&x, prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_gamma, rug_o) = rug_gamma_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "Infinity", "Infinity", Equal);
+    test("0", 10, "Infinity", "Infinity", Equal);
+    test("0", 100, "Infinity", "Infinity", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Equal);
+    test("1", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1", 1, "NaN", "NaN", Equal);
+    test("-1", 10, "NaN", "NaN", Equal);
+    test("-1", 100, "NaN", "NaN", Equal);
+    test("1/2", 1, "2.0", "0x2.0#1", Greater);
+    test("1/2", 10, "1.7715", "0x1.c58#10", Less);
+    test(
+        "1/2",
+        100,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("-1/2", 1, "-4.0", "-0x4.0#1", Less);
+    test("-1/2", 10, "-3.5430", "-0x3.8b#10", Greater);
+    test(
+        "-1/2",
+        100,
+        "-3.5449077018110320545963349666825",
+        "-0x3.8b7f12369ded54f38760a41ac#100",
+        Less,
+    );
+    test("1/3", 1, "2.0", "0x2.0#1", Less);
+    test("1/3", 10, "2.6797", "0x2.ae#10", Greater);
+    test(
+        "1/3",
+        100,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Less,
+    );
+    test("-1/3", 1, "-4.0", "-0x4.0#1", Greater);
+    test("-1/3", 10, "-4.0625", "-0x4.10#10", Less);
+    test(
+        "-1/3",
+        100,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Greater,
+    );
+    test("22/7", 1, "2.0", "0x2.0#1", Less);
+    test("22/7", 10, "2.2891", "0x2.4a#10", Less);
+    test(
+        "22/7",
+        100,
+        "2.2908675009613379957017236207921",
+        "0x2.4a764ae4192352f3b59fbc0cc#100",
+        Greater,
+    );
+    test("5", 1, "32.0", "0x2.0E+1#1", Greater);
+    test("5", 10, "24.000", "0x18.00#10", Equal);
+    test(
+        "5",
+        100,
+        "24.000000000000000000000000000000",
+        "0x18.000000000000000000000000#100",
+        Equal,
+    );
+    test("100", 1, "8.6e155", "0x4.0E+129#1", Less);
+    test("100", 10, "9.3352e155", "0x4.5aE+129#10", Greater);
+    test(
+        "100",
+        100,
+        "9.3326215443944152681699238856218e155",
+        "0x4.59b1a633c60ebe15888169ce8E+129#100",
+        Less,
+    );
+    test("-7/2", 1, "0.25", "0x0.4#1", Less);
+    test("-7/2", 10, "0.27002", "0x0.452#10", Less);
+    test(
+        "-7/2",
+        100,
+        "0.27008820585226910892162552127095",
+        "0x0.4524802b2bba4f9d86a8470478#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn gamma_rational_prec_fail() {
+    Float::gamma_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn gamma_rational_prec_ref_fail() {
+    Float::gamma_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_gamma_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (gamma, o) = Float::gamma_rational_prec_round(x.clone(), prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::gamma_rational_prec_round_ref(&x, prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_gamma, rug_o) = rug_gamma_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "Infinity", "Infinity", Equal);
+    test("0", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", 1, Down, "Infinity", "Infinity", Equal);
+    test("0", 1, Up, "Infinity", "Infinity", Equal);
+    test("0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("0", 10, Floor, "Infinity", "Infinity", Equal);
+    test("0", 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", 10, Down, "Infinity", "Infinity", Equal);
+    test("0", 10, Up, "Infinity", "Infinity", Equal);
+    test("0", 10, Nearest, "Infinity", "Infinity", Equal);
+    test("0", 10, Exact, "Infinity", "Infinity", Equal);
+    test("0", 100, Nearest, "Infinity", "Infinity", Equal);
+    test("1", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("1", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("1", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("1", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("1", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("1", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 1, Down, "NaN", "NaN", Equal);
+    test("-1", 1, Up, "NaN", "NaN", Equal);
+    test("-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 10, Down, "NaN", "NaN", Equal);
+    test("-1", 10, Up, "NaN", "NaN", Equal);
+    test("-1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1", 10, Exact, "NaN", "NaN", Equal);
+    test("-1", 100, Nearest, "NaN", "NaN", Equal);
+    test("1/2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1/2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1/2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1/2", 10, Floor, "1.7715", "0x1.c58#10", Less);
+    test("1/2", 10, Ceiling, "1.7734", "0x1.c60#10", Greater);
+    test("1/2", 10, Down, "1.7715", "0x1.c58#10", Less);
+    test("1/2", 10, Up, "1.7734", "0x1.c60#10", Greater);
+    test("1/2", 10, Nearest, "1.7715", "0x1.c58#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("-1/2", 1, Floor, "-4.0", "-0x4.0#1", Less);
+    test("-1/2", 1, Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("-1/2", 1, Down, "-2.0", "-0x2.0#1", Greater);
+    test("-1/2", 1, Up, "-4.0", "-0x4.0#1", Less);
+    test("-1/2", 1, Nearest, "-4.0", "-0x4.0#1", Less);
+    test("-1/2", 10, Floor, "-3.5469", "-0x3.8c#10", Less);
+    test("-1/2", 10, Ceiling, "-3.5430", "-0x3.8b#10", Greater);
+    test("-1/2", 10, Down, "-3.5430", "-0x3.8b#10", Greater);
+    test("-1/2", 10, Up, "-3.5469", "-0x3.8c#10", Less);
+    test("-1/2", 10, Nearest, "-3.5430", "-0x3.8b#10", Greater);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "-3.5449077018110320545963349666825",
+        "-0x3.8b7f12369ded54f38760a41ac#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1/3", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1/3", 1, Down, "2.0", "0x2.0#1", Less);
+    test("1/3", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("1/3", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/3", 10, Floor, "2.6758", "0x2.ad#10", Less);
+    test("1/3", 10, Ceiling, "2.6797", "0x2.ae#10", Greater);
+    test("1/3", 10, Down, "2.6758", "0x2.ad#10", Less);
+    test("1/3", 10, Up, "2.6797", "0x2.ae#10", Greater);
+    test("1/3", 10, Nearest, "2.6797", "0x2.ae#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "-8.0", "-0x8.0#1", Less);
+    test("-1/3", 1, Ceiling, "-4.0", "-0x4.0#1", Greater);
+    test("-1/3", 1, Down, "-4.0", "-0x4.0#1", Greater);
+    test("-1/3", 1, Up, "-8.0", "-0x8.0#1", Less);
+    test("-1/3", 1, Nearest, "-4.0", "-0x4.0#1", Greater);
+    test("-1/3", 10, Floor, "-4.0625", "-0x4.10#10", Less);
+    test("-1/3", 10, Ceiling, "-4.0547", "-0x4.0e#10", Greater);
+    test("-1/3", 10, Down, "-4.0547", "-0x4.0e#10", Greater);
+    test("-1/3", 10, Up, "-4.0625", "-0x4.10#10", Less);
+    test("-1/3", 10, Nearest, "-4.0625", "-0x4.10#10", Less);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-4.0623538182792012508358640844635",
+        "-0x4.0ff66b7a4a36a6018defc2508#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("22/7", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("22/7", 1, Down, "2.0", "0x2.0#1", Less);
+    test("22/7", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("22/7", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("22/7", 10, Floor, "2.2891", "0x2.4a#10", Less);
+    test("22/7", 10, Ceiling, "2.2930", "0x2.4b#10", Greater);
+    test("22/7", 10, Down, "2.2891", "0x2.4a#10", Less);
+    test("22/7", 10, Up, "2.2930", "0x2.4b#10", Greater);
+    test("22/7", 10, Nearest, "2.2891", "0x2.4a#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "2.2908675009613379957017236207921",
+        "0x2.4a764ae4192352f3b59fbc0cc#100",
+        Greater,
+    );
+    test("5", 1, Floor, "16.0", "0x1.0E+1#1", Less);
+    test("5", 1, Ceiling, "32.0", "0x2.0E+1#1", Greater);
+    test("5", 1, Down, "16.0", "0x1.0E+1#1", Less);
+    test("5", 1, Up, "32.0", "0x2.0E+1#1", Greater);
+    test("5", 1, Nearest, "32.0", "0x2.0E+1#1", Greater);
+    test("5", 10, Floor, "24.000", "0x18.00#10", Equal);
+    test("5", 10, Ceiling, "24.000", "0x18.00#10", Equal);
+    test("5", 10, Down, "24.000", "0x18.00#10", Equal);
+    test("5", 10, Up, "24.000", "0x18.00#10", Equal);
+    test("5", 10, Nearest, "24.000", "0x18.00#10", Equal);
+    test("5", 10, Exact, "24.000", "0x18.00#10", Equal);
+    test(
+        "5",
+        100,
+        Nearest,
+        "24.000000000000000000000000000000",
+        "0x18.000000000000000000000000#100",
+        Equal,
+    );
+    test("100", 1, Floor, "8.6e155", "0x4.0E+129#1", Less);
+    test("100", 1, Ceiling, "1.7e156", "0x8.0E+129#1", Greater);
+    test("100", 1, Down, "8.6e155", "0x4.0E+129#1", Less);
+    test("100", 1, Up, "1.7e156", "0x8.0E+129#1", Greater);
+    test("100", 1, Nearest, "8.6e155", "0x4.0E+129#1", Less);
+    test("100", 10, Floor, "9.3184e155", "0x4.58E+129#10", Less);
+    test("100", 10, Ceiling, "9.3352e155", "0x4.5aE+129#10", Greater);
+    test("100", 10, Down, "9.3184e155", "0x4.58E+129#10", Less);
+    test("100", 10, Up, "9.3352e155", "0x4.5aE+129#10", Greater);
+    test("100", 10, Nearest, "9.3352e155", "0x4.5aE+129#10", Greater);
+    test(
+        "100",
+        100,
+        Nearest,
+        "9.3326215443944152681699238856218e155",
+        "0x4.59b1a633c60ebe15888169ce8E+129#100",
+        Less,
+    );
+    test("-7/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("-7/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("-7/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("-7/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("-7/2", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("-7/2", 10, Floor, "0.27002", "0x0.452#10", Less);
+    test("-7/2", 10, Ceiling, "0.27051", "0x0.454#10", Greater);
+    test("-7/2", 10, Down, "0.27002", "0x0.452#10", Less);
+    test("-7/2", 10, Up, "0.27051", "0x0.454#10", Greater);
+    test("-7/2", 10, Nearest, "0.27002", "0x0.452#10", Less);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "0.27008820585226910892162552127095",
+        "0x0.4524802b2bba4f9d86a8470478#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn gamma_rational_prec_round_fail_1() {
+    Float::gamma_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn gamma_rational_prec_round_fail_2() {
+    Float::gamma_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn gamma_rational_prec_round_ref_fail() {
+    Float::gamma_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn gamma_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (gamma, o) = x.clone().gamma_prec_round(prec, rm);
+    assert!(gamma.is_valid());
+
+    let (gamma_alt, o_alt) = x.gamma_prec_round_ref(prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.gamma_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_gamma, rug_o) = rug_gamma_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(
+        gamma.is_nan(),
+        x.is_nan() || x == Float::NEGATIVE_INFINITY || (x < 0u32 && x.is_integer())
+    );
+
+    if gamma.is_normal() {
+        assert_eq!(gamma.get_prec(), Some(prec));
+    }
+    if x.is_normal() {
+        let (gamma_alt, o_alt) =
+            Float::gamma_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.gamma_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(gamma.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.gamma_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn gamma_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        gamma_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (gamma, o) = Float::NAN.gamma_prec_round(prec, rm);
+        assert!(gamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::INFINITY.gamma_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::NEGATIVE_INFINITY.gamma_prec_round(prec, rm);
+        assert!(gamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::ZERO.gamma_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::NEGATIVE_ZERO.gamma_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(gamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::ONE.gamma_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(gamma),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn gamma_prec_properties_helper(x: Float, prec: u64) {
+    let (gamma, o) = x.clone().gamma_prec(prec);
+    assert!(gamma.is_valid());
+
+    let (gamma_alt, o_alt) = x.gamma_prec_ref(prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.gamma_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let (gamma_alt, o_alt) = x.gamma_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let (rug_gamma, rug_o) = rug_gamma_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_gamma)),
+        ComparableFloatRef(&gamma)
+    );
+    assert_eq!(rug_o, o);
+
+    if gamma.is_normal() {
+        assert_eq!(gamma.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn gamma_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        gamma_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (gamma, o) = Float::NAN.gamma_prec(prec);
+        assert!(gamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::INFINITY.gamma_prec(prec);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::NEGATIVE_INFINITY.gamma_prec(prec);
+        assert!(gamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::ZERO.gamma_prec(prec);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::NEGATIVE_ZERO.gamma_prec(prec);
+        assert_eq!(
+            ComparableFloat(gamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::ONE.gamma_prec(prec);
+        assert_eq!(
+            ComparableFloat(gamma),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn gamma_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (gamma, o) = x.clone().gamma_round(rm);
+    assert!(gamma.is_valid());
+
+    let (gamma_alt, o_alt) = x.gamma_round_ref(rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.gamma_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let (gamma_alt, o_alt) = x.gamma_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_gamma, rug_o) = rug_gamma_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.gamma_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(gamma.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.gamma_round_ref(Exact));
+    }
+}
+
+#[test]
+fn gamma_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        gamma_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (gamma, o) = Float::NAN.gamma_round(rm);
+        assert!(gamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::INFINITY.gamma_round(rm);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::NEGATIVE_INFINITY.gamma_round(rm);
+        assert!(gamma.is_nan());
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::ZERO.gamma_round(rm);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::NEGATIVE_ZERO.gamma_round(rm);
+        assert_eq!(
+            ComparableFloat(gamma),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::ONE.gamma_round(rm);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn gamma_properties_helper(x: Float) {
+    let gamma = x.clone().gamma();
+    assert!(gamma.is_valid());
+
+    let gamma_alt = (&x).gamma();
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    let mut x_alt = x.clone();
+    x_alt.gamma_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&gamma));
+
+    let gamma_alt = x.gamma_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let gamma_alt = x.gamma_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    assert_eq!(
+        gamma.is_nan(),
+        x.is_nan() || x == Float::NEGATIVE_INFINITY || (x < 0u32 && x.is_integer())
+    );
+
+    let rug_gamma = rug_gamma(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_gamma)),
+        ComparableFloatRef(&gamma),
+    );
+}
+
+#[test]
+fn gamma_properties() {
+    float_gen().test_properties(|x| {
+        gamma_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn gamma_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (gamma, o) = Float::gamma_rational_prec_round(x.clone(), prec, rm);
+    assert!(gamma.is_valid());
+
+    let (gamma_alt, o_alt) = Float::gamma_rational_prec_round_ref(&x, prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_gamma, rug_o) = rug_gamma_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(gamma.is_nan(), x < 0u32 && x.is_integer());
+
+    if gamma.is_normal() {
+        assert_eq!(gamma.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::gamma_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(gamma.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::gamma_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn gamma_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        gamma_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (gamma, o) = Float::gamma_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::gamma_rational_prec_round(Rational::ONE, prec, rm);
+        assert_eq!(
+            ComparableFloat(gamma),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn gamma_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (gamma, o) = Float::gamma_rational_prec(x.clone(), prec);
+    assert!(gamma.is_valid());
+
+    let (gamma_alt, o_alt) = Float::gamma_rational_prec_ref(&x, prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let (gamma_alt, o_alt) = Float::gamma_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let (rug_gamma, rug_o) = rug_gamma_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_gamma)),
+        ComparableFloatRef(&gamma)
+    );
+    assert_eq!(rug_o, o);
+
+    if gamma.is_normal() {
+        assert_eq!(gamma.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn gamma_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        gamma_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (gamma, o) = Float::gamma_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+
+        let (gamma, o) = Float::gamma_rational_prec(Rational::ONE, prec);
+        assert_eq!(
+            ComparableFloat(gamma),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_gamma() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_gamma(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::INFINITY);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, f32::INFINITY);
+    test::<f32>(-0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(1.0, 1.0);
+    test::<f32>(-1.0, f32::NAN);
+    test::<f32>(0.5, 1.7724539);
+    test::<f32>(-0.5, -3.5449078);
+    test::<f32>(2.0, 1.0);
+    test::<f32>(5.0, 24.0);
+    test::<f32>(-2.5, -0.94530874);
+    test::<f32>(10.0, 362880.0);
+    test::<f32>(0.001, 999.4237);
+    test::<f32>(10000000000.0, f32::INFINITY);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::INFINITY);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, f64::INFINITY);
+    test::<f64>(-0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(1.0, 1.0);
+    test::<f64>(-1.0, f64::NAN);
+    test::<f64>(0.5, 1.772453850905516);
+    test::<f64>(-0.5, -3.544907701811032);
+    test::<f64>(2.0, 1.0);
+    test::<f64>(5.0, 24.0);
+    test::<f64>(-2.5, -0.9453087204829419);
+    test::<f64>(10.0, 362880.0);
+    test::<f64>(0.001, 999.4237724845955);
+    test::<f64>(10000000000.0, f64::INFINITY);
+    test::<f64>(1.0e300, f64::INFINITY);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_gamma_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let gamma = primitive_float_gamma(x);
+        assert_eq!(
+            gamma.is_nan(),
+            x.is_nan() || x == T::NEGATIVE_INFINITY || (x < T::ZERO && x.is_integer())
+        );
+    });
+}
+
+#[test]
+fn primitive_float_gamma_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_gamma_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_gamma_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_gamma_rational(&x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", f32::INFINITY);
+    test::<f32>("1", 1.0);
+    test::<f32>("-1", f32::NAN);
+    test::<f32>("1/2", 1.7724539);
+    test::<f32>("-1/2", -3.5449078);
+    test::<f32>("1/3", 2.6789386);
+    test::<f32>("22/7", 2.2908676);
+    test::<f32>("3/2", 0.88622695);
+    test::<f32>("-7/2", 0.2700882);
+    test::<f32>("1000000", f32::INFINITY);
+
+    test::<f64>("0", f64::INFINITY);
+    test::<f64>("1", 1.0);
+    test::<f64>("-1", f64::NAN);
+    test::<f64>("1/2", 1.772453850905516);
+    test::<f64>("-1/2", -3.544907701811032);
+    test::<f64>("1/3", 2.6789385347077475);
+    test::<f64>("22/7", 2.290867500961338);
+    test::<f64>("3/2", 0.886226925452758);
+    test::<f64>("-7/2", 0.2700882058522691);
+    test::<f64>("1000000", f64::INFINITY);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_gamma_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let gamma = primitive_float_gamma_rational::<T>(&x);
+        assert_eq!(gamma.is_nan(), x < 0u32 && x.is_integer());
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_gamma_rational::<T>(&Rational::exact_from(
+                    x
+                ))),
+                NiceFloat(primitive_float_gamma(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_gamma_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_gamma_rational_properties_helper);
+}
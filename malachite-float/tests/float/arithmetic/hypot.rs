@@ -1014,11 +1014,16 @@ fn verify_against_exact(x: &Float, y: &Float, prec: u64, rm: RoundingMode, h: &F
             assert_ne!(o, Less);
             assert!((hr - ulp_below).square() < r);
         }
-        Nearest => match o {
+        Nearest | NearestTiesAway => match o {
             Less => assert!((hr + (ulp_above >> 1u64)).square() >= r),
             Greater => assert!((hr - (ulp_below >> 1u64)).square() <= r),
             Equal => {}
         },
+        Odd => match o {
+            Less => assert!((hr + ulp_above).square() > r),
+            Greater => assert!((hr - ulp_below).square() < r),
+            Equal => {}
+        },
         Exact => assert_eq!(o, Equal),
     }
 }
@@ -0,0 +1,3907 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{LnGamma, LnGammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::ln_gamma::{
+    primitive_float_ln_gamma, primitive_float_ln_gamma_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::ln_gamma::{
+    rug_ln_gamma, rug_ln_gamma_prec, rug_ln_gamma_prec_round, rug_ln_gamma_rational_prec,
+    rug_ln_gamma_rational_prec_round, rug_ln_gamma_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_ln_gamma() {
+    let test = |s, s_hex, out: &str, out_hex: &str, sign_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln_gamma, sign) = x.clone().ln_gamma();
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+
+        let (ln_gamma_alt, sign_alt) = (&x).ln_gamma();
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+
+        let mut ln_gamma_alt = x.clone();
+        let sign_alt = ln_gamma_alt.ln_gamma_assign();
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+
+        let (rug_ln_gamma, rug_sign) = rug_ln_gamma(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(rug_sign, sign);
+    };
+    test("NaN", "NaN", "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", "Infinity", "Infinity", Greater);
+    test("-Infinity", "-Infinity", "Infinity", "Infinity", Less);
+    test("0.0", "0x0.0", "Infinity", "Infinity", Greater);
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", Less);
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", Greater);
+    test("-1.0", "-0x1.0#1", "Infinity", "Infinity", Greater);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.0",
+        "0x0.0",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "0.75", "0x0.c#2", Greater);
+    test("5.0", "0x5.0#3", "3.0", "0x3.0#3", Greater);
+    test("0.50", "0x0.8#1", "0.50", "0x0.8#1", Greater);
+    test("-0.50", "-0x0.8#1", "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", "468.0", "0x1d4.0#7", Greater);
+    test("-123.50", "-0x7b.8#10", "-473.50", "-0x1d9.8#10", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.82769459232343667",
+        "0x0.d3e3caf51aef4#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.44946174182006754",
+        "0x0.730fecb9eb9e64#53",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "2.20258517e11",
+        "0x3.3486e4E+9#24",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", "64.0", "0x4.0E+1#1", Greater);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+    );
+}
+
+#[test]
+fn test_ln_gamma_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, sign_out, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln_gamma, sign, o) = x.clone().ln_gamma_prec(prec);
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+        assert_eq!(o, o_out);
+
+        let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_prec_ref(prec);
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_gamma_alt = x.clone();
+        let (sign_alt, o_alt) = ln_gamma_alt.ln_gamma_prec_assign(prec);
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+        assert_eq!(o_alt, o_out);
+
+        let (rug_ln_gamma, rug_sign, rug_o) = rug_ln_gamma_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(rug_sign, sign);
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal, Equal);
+    test(
+        "Infinity", "Infinity", 1, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 100, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Greater, Equal);
+    test("0.0", "0x0.0", 10, "Infinity", "Infinity", Greater, Equal);
+    test("0.0", "0x0.0", 100, "Infinity", "Infinity", Greater, Equal);
+    test("-0.0", "-0x0.0", 1, "Infinity", "Infinity", Less, Equal);
+    test("-0.0", "-0x0.0", 10, "Infinity", "Infinity", Less, Equal);
+    test("-0.0", "-0x0.0", 100, "Infinity", "Infinity", Less, Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Greater, Equal);
+    test(
+        "-1.0", "-0x1.0#1", 1, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 10, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 100, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test("3.0", "0x3.0#2", 1, "0.50", "0x0.8#1", Greater, Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.69336",
+        "0x0.b18#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.69314718055994530941723212145798",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Greater,
+        Less,
+    );
+    test("5.0", "0x5.0#3", 1, "4.0", "0x4.0#1", Greater, Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        "3.1797",
+        "0x3.2e#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "3.1780538303479456196469416012962",
+        "0x3.2d94ef92459f167a01c4a81d0#100",
+        Greater,
+        Less,
+    );
+    test("0.50", "0x0.8#1", 1, "0.50", "0x0.8#1", Greater, Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        "0.57227",
+        "0x0.928#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "0.57236494292470008707171367567642",
+        "0x0.928682473d0de85eafcab6354#100",
+        Greater,
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, "1.0", "0x1.0#1", Less, Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        "1.2656",
+        "0x1.440#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "1.2655121234846453964889457971344",
+        "0x1.43f89a3f0edd620a79ae69cd4#100",
+        Less,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "467.50",
+        "0x1d3.8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "467.41219957160817874468376251198",
+        "0x1d3.6985e93f7b7a7e08f807b06#100",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        "-5.1e2",
+        "-0x2.0E+2#1",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        "-473.50",
+        "-0x1d9.8#10",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "-473.48878676214923665616692410653",
+        "-0x1d9.7d21211626115769957d65c#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.82812",
+        "0x0.d40#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.82769459232343698185546359291468",
+        "0x0.d3e3caf51aef563f919f55472#100",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.44922",
+        "0x0.730#10",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.44946174182006755063859114161884",
+        "0x0.730fecb9eb9e64cb70cf3d3918#100",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "2.7e11",
+        "0x4.0E+9#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "2.2039e11",
+        "0x3.35E+9#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "220258509288.81058147004192312350",
+        "0x33486e21e8.cf82446892db9d88#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "64.0",
+        "0x4.0E+1#1",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "69.375",
+        "0x45.6#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "69.314718055994530941723212145798",
+        "0x45.50915ccdf50b871adcf22760#100",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.98535",
+        "0x0.fc4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "1.4023",
+        "0x1.670#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+}
+
+#[test]
+fn ln_gamma_prec_fail() {
+    assert_panic!(Float::NAN.ln_gamma_prec(0));
+    assert_panic!(Float::NAN.ln_gamma_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.ln_gamma_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_ln_gamma_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, sign_out, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln_gamma, sign, o) = x.clone().ln_gamma_round(rm);
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+        assert_eq!(o, o_out);
+
+        let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_round_ref(rm);
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_gamma_alt = x.clone();
+        let (sign_alt, o_alt) = ln_gamma_alt.ln_gamma_round_assign(rm);
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln_gamma, rug_sign, rug_o) =
+                rug_ln_gamma_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+                ComparableFloatRef(&ln_gamma),
+            );
+            assert_eq!(rug_sign, sign);
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal, Equal);
+    test(
+        "Infinity", "Infinity", Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", Down, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", Up, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", Exact, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Down,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Up,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test("0.0", "0x0.0", Down, "Infinity", "Infinity", Greater, Equal);
+    test("0.0", "0x0.0", Up, "Infinity", "Infinity", Greater, Equal);
+    test(
+        "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "0.0", "0x0.0", Exact, "Infinity", "Infinity", Greater, Equal,
+    );
+    test("-0.0", "-0x0.0", Floor, "Infinity", "Infinity", Less, Equal);
+    test(
+        "-0.0", "-0x0.0", Ceiling, "Infinity", "Infinity", Less, Equal,
+    );
+    test("-0.0", "-0x0.0", Down, "Infinity", "Infinity", Less, Equal);
+    test("-0.0", "-0x0.0", Up, "Infinity", "Infinity", Less, Equal);
+    test(
+        "-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Less, Equal,
+    );
+    test("-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Less, Equal);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Greater, Equal);
+    test(
+        "-1.0", "-0x1.0#1", Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Down, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Up, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Exact, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test("3.0", "0x3.0#2", Floor, "0.50", "0x0.8#2", Greater, Less);
+    test(
+        "3.0", "0x3.0#2", Ceiling, "0.75", "0x0.c#2", Greater, Greater,
+    );
+    test("3.0", "0x3.0#2", Down, "0.50", "0x0.8#2", Greater, Less);
+    test("3.0", "0x3.0#2", Up, "0.75", "0x0.c#2", Greater, Greater);
+    test(
+        "3.0", "0x3.0#2", Nearest, "0.75", "0x0.c#2", Greater, Greater,
+    );
+    test("5.0", "0x5.0#3", Floor, "3.0", "0x3.0#3", Greater, Less);
+    test(
+        "5.0", "0x5.0#3", Ceiling, "3.5", "0x3.8#3", Greater, Greater,
+    );
+    test("5.0", "0x5.0#3", Down, "3.0", "0x3.0#3", Greater, Less);
+    test("5.0", "0x5.0#3", Up, "3.5", "0x3.8#3", Greater, Greater);
+    test("5.0", "0x5.0#3", Nearest, "3.0", "0x3.0#3", Greater, Less);
+    test("0.50", "0x0.8#1", Floor, "0.50", "0x0.8#1", Greater, Less);
+    test(
+        "0.50", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test("0.50", "0x0.8#1", Down, "0.50", "0x0.8#1", Greater, Less);
+    test("0.50", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater, Greater);
+    test("0.50", "0x0.8#1", Nearest, "0.50", "0x0.8#1", Greater, Less);
+    test("-0.50", "-0x0.8#1", Floor, "1.0", "0x1.0#1", Less, Less);
+    test(
+        "-0.50", "-0x0.8#1", Ceiling, "2.0", "0x2.0#1", Less, Greater,
+    );
+    test("-0.50", "-0x0.8#1", Down, "1.0", "0x1.0#1", Less, Less);
+    test("-0.50", "-0x0.8#1", Up, "2.0", "0x2.0#1", Less, Greater);
+    test("-0.50", "-0x0.8#1", Nearest, "1.0", "0x1.0#1", Less, Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "464.0",
+        "0x1d0.0#7",
+        Greater,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "468.0",
+        "0x1d4.0#7",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "464.0",
+        "0x1d0.0#7",
+        Greater,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "468.0",
+        "0x1d4.0#7",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "468.0",
+        "0x1d4.0#7",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Floor,
+        "-473.50",
+        "-0x1d9.8#10",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "-473.00",
+        "-0x1d9.0#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Down,
+        "-473.00",
+        "-0x1d9.0#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Up,
+        "-473.50",
+        "-0x1d9.8#10",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "-473.50",
+        "-0x1d9.8#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.82769459232343667",
+        "0x0.d3e3caf51aef4#50",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.82769459232343756",
+        "0x0.d3e3caf51aef8#50",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.82769459232343667",
+        "0x0.d3e3caf51aef4#50",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.82769459232343756",
+        "0x0.d3e3caf51aef8#50",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.82769459232343667",
+        "0x0.d3e3caf51aef4#50",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.44946174182006754",
+        "0x0.730fecb9eb9e64#53",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.44946174182006760",
+        "0x0.730fecb9eb9e68#53",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.44946174182006754",
+        "0x0.730fecb9eb9e64#53",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.44946174182006760",
+        "0x0.730fecb9eb9e68#53",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.44946174182006754",
+        "0x0.730fecb9eb9e64#53",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "2.20258501e11",
+        "0x3.3486e0E+9#24",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "2.20258517e11",
+        "0x3.3486e4E+9#24",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "2.20258501e11",
+        "0x3.3486e0E+9#24",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "2.20258517e11",
+        "0x3.3486e4E+9#24",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "2.20258517e11",
+        "0x3.3486e4E+9#24",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "64.0",
+        "0x4.0E+1#1",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "64.0",
+        "0x4.0E+1#1",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "64.0",
+        "0x4.0E+1#1",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.98542064692776706918717403697689",
+        "0x0.fc44870b5ba0ce071ed58f21f#100",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.98542064692776706918717403697689",
+        "0x0.fc44870b5ba0ce071ed58f21f#100",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "1.4017625638156332600711080542959",
+        "0x1.66d9e9505862d11d5cb0578b2#100",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "1.4017625638156332600711080542959",
+        "0x1.66d9e9505862d11d5cb0578b2#100",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+}
+
+#[test]
+fn ln_gamma_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.ln_gamma_round(Exact));
+    assert_panic!(X.ln_gamma_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.ln_gamma_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_ln_gamma_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, sign_out, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln_gamma, sign, o) = x.clone().ln_gamma_prec_round(prec, rm);
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+        assert_eq!(o, o_out);
+
+        let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_prec_round_ref(prec, rm);
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_gamma_alt = x.clone();
+        let (sign_alt, o_alt) = ln_gamma_alt.ln_gamma_prec_round_assign(prec, rm);
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma),
+            ComparableFloatRef(&ln_gamma_alt)
+        );
+        assert_eq!(sign_alt, sign_out);
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln_gamma, rug_sign, rug_o) =
+                rug_ln_gamma_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+                ComparableFloatRef(&ln_gamma)
+            );
+            assert_eq!(rug_sign, sign);
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal, Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal, Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Down,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Up,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "Infinity",
+        "Infinity",
+        Less,
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Down, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Up, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Floor, "Infinity", "Infinity", Less, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Ceiling, "Infinity", "Infinity", Less, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Down, "Infinity", "Infinity", Less, Equal,
+    );
+    test("-0.0", "-0x0.0", 1, Up, "Infinity", "Infinity", Less, Equal);
+    test(
+        "-0.0", "-0x0.0", 1, Nearest, "Infinity", "Infinity", Less, Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Exact, "Infinity", "Infinity", Less, Equal,
+    );
+    test("1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 1, Down, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 1, Up, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Greater, Equal);
+    test(
+        "1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Greater, Equal,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 10, Up, "0.0", "0x0.0", Greater, Equal);
+    test(
+        "1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Greater, Equal,
+    );
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Greater, Equal);
+    test("1.0", "0x1.0#1", 100, Floor, "0.0", "0x0.0", Greater, Equal);
+    test(
+        "1.0", "0x1.0#1", 100, Ceiling, "0.0", "0x0.0", Greater, Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 100, Nearest, "0.0", "0x0.0", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Down, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Up, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Exact, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 10, Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 10, Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 10, Down, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 10, Up, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 10, Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 10, Exact, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 100, Floor, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 100, Ceiling, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 100, Nearest, "Infinity", "Infinity", Greater, Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Greater,
+        Equal,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "0.50", "0x0.8#1", Greater, Less);
+    test(
+        "3.0", "0x3.0#2", 1, Ceiling, "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test("3.0", "0x3.0#2", 1, Down, "0.50", "0x0.8#1", Greater, Less);
+    test("3.0", "0x3.0#2", 1, Up, "1.0", "0x1.0#1", Greater, Greater);
+    test(
+        "3.0", "0x3.0#2", 1, Nearest, "0.50", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "0.69238",
+        "0x0.b14#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.69336",
+        "0x0.b18#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Down,
+        "0.69238",
+        "0x0.b14#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Up,
+        "0.69336",
+        "0x0.b18#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.69336",
+        "0x0.b18#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Floor,
+        "0.69314718055994530941723212145798",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Greater,
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Ceiling,
+        "0.69314718055994530941723212145877",
+        "0x0.b17217f7d1cf79abc9e3b3981#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "0.69314718055994530941723212145798",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Greater,
+        Less,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "2.0", "0x2.0#1", Greater, Less);
+    test(
+        "5.0", "0x5.0#3", 1, Ceiling, "4.0", "0x4.0#1", Greater, Greater,
+    );
+    test("5.0", "0x5.0#3", 1, Down, "2.0", "0x2.0#1", Greater, Less);
+    test("5.0", "0x5.0#3", 1, Up, "4.0", "0x4.0#1", Greater, Greater);
+    test(
+        "5.0", "0x5.0#3", 1, Nearest, "4.0", "0x4.0#1", Greater, Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Floor,
+        "3.1758",
+        "0x3.2d#10",
+        Greater,
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "3.1797",
+        "0x3.2e#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Down,
+        "3.1758",
+        "0x3.2d#10",
+        Greater,
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Up,
+        "3.1797",
+        "0x3.2e#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "3.1797",
+        "0x3.2e#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Floor,
+        "3.1780538303479456196469416012962",
+        "0x3.2d94ef92459f167a01c4a81d0#100",
+        Greater,
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Ceiling,
+        "3.1780538303479456196469416012994",
+        "0x3.2d94ef92459f167a01c4a81d4#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "3.1780538303479456196469416012962",
+        "0x3.2d94ef92459f167a01c4a81d0#100",
+        Greater,
+        Less,
+    );
+    test(
+        "0.50", "0x0.8#1", 1, Floor, "0.50", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "0.50", "0x0.8#1", 1, Ceiling, "1.0", "0x1.0#1", Greater, Greater,
+    );
+    test("0.50", "0x0.8#1", 1, Down, "0.50", "0x0.8#1", Greater, Less);
+    test("0.50", "0x0.8#1", 1, Up, "1.0", "0x1.0#1", Greater, Greater);
+    test(
+        "0.50", "0x0.8#1", 1, Nearest, "0.50", "0x0.8#1", Greater, Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Floor,
+        "0.57227",
+        "0x0.928#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.57324",
+        "0x0.92c#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Down,
+        "0.57227",
+        "0x0.928#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Up,
+        "0.57324",
+        "0x0.92c#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "0.57227",
+        "0x0.928#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "0.57236494292470008707171367567642",
+        "0x0.928682473d0de85eafcab6354#100",
+        Greater,
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "0.57236494292470008707171367567721",
+        "0x0.928682473d0de85eafcab6355#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "0.57236494292470008707171367567642",
+        "0x0.928682473d0de85eafcab6354#100",
+        Greater,
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "1.0", "0x1.0#1", Less, Less);
+    test(
+        "-0.50", "-0x0.8#1", 1, Ceiling, "2.0", "0x2.0#1", Less, Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Down, "1.0", "0x1.0#1", Less, Less);
+    test("-0.50", "-0x0.8#1", 1, Up, "2.0", "0x2.0#1", Less, Greater);
+    test(
+        "-0.50", "-0x0.8#1", 1, Nearest, "1.0", "0x1.0#1", Less, Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "1.2637",
+        "0x1.438#10",
+        Less,
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "1.2656",
+        "0x1.440#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Down,
+        "1.2637",
+        "0x1.438#10",
+        Less,
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Up,
+        "1.2656",
+        "0x1.440#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "1.2656",
+        "0x1.440#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "1.2655121234846453964889457971344",
+        "0x1.43f89a3f0edd620a79ae69cd4#100",
+        Less,
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "1.2655121234846453964889457971360",
+        "0x1.43f89a3f0edd620a79ae69cd6#100",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "1.2655121234846453964889457971344",
+        "0x1.43f89a3f0edd620a79ae69cd4#100",
+        Less,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "2.6e2",
+        "0x1.0E+2#1",
+        Greater,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "2.6e2",
+        "0x1.0E+2#1",
+        Greater,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "5.1e2",
+        "0x2.0E+2#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "467.00",
+        "0x1d3.0#10",
+        Greater,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "467.50",
+        "0x1d3.8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "467.00",
+        "0x1d3.0#10",
+        Greater,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "467.50",
+        "0x1d3.8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "467.50",
+        "0x1d3.8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "467.41219957160817874468376251198",
+        "0x1d3.6985e93f7b7a7e08f807b06#100",
+        Greater,
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "467.41219957160817874468376251238",
+        "0x1d3.6985e93f7b7a7e08f807b08#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "467.41219957160817874468376251198",
+        "0x1d3.6985e93f7b7a7e08f807b06#100",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Floor,
+        "-5.1e2",
+        "-0x2.0E+2#1",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "-2.6e2",
+        "-0x1.0E+2#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Down,
+        "-2.6e2",
+        "-0x1.0E+2#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Up,
+        "-5.1e2",
+        "-0x2.0E+2#1",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "-5.1e2",
+        "-0x2.0E+2#1",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "-473.50",
+        "-0x1d9.8#10",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "-473.00",
+        "-0x1d9.0#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "-473.00",
+        "-0x1d9.0#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "-473.50",
+        "-0x1d9.8#10",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "-473.50",
+        "-0x1d9.8#10",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Floor,
+        "-473.48878676214923665616692410694",
+        "-0x1d9.7d21211626115769957d65e#100",
+        Greater,
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Ceiling,
+        "-473.48878676214923665616692410653",
+        "-0x1d9.7d21211626115769957d65c#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "-473.48878676214923665616692410653",
+        "-0x1d9.7d21211626115769957d65c#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.82715",
+        "0x0.d3c#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.82812",
+        "0x0.d40#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.82715",
+        "0x0.d3c#10",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.82812",
+        "0x0.d40#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.82812",
+        "0x0.d40#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "0.82769459232343698185546359291468",
+        "0x0.d3e3caf51aef563f919f55472#100",
+        Greater,
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "0.82769459232343698185546359291547",
+        "0x0.d3e3caf51aef563f919f55473#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.82769459232343698185546359291468",
+        "0x0.d3e3caf51aef563f919f55472#100",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.44922",
+        "0x0.730#10",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.44971",
+        "0x0.732#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.44922",
+        "0x0.730#10",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.44971",
+        "0x0.732#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.44922",
+        "0x0.730#10",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.44946174182006755063859114161884",
+        "0x0.730fecb9eb9e64cb70cf3d3918#100",
+        Greater,
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.44946174182006755063859114161924",
+        "0x0.730fecb9eb9e64cb70cf3d3920#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.44946174182006755063859114161884",
+        "0x0.730fecb9eb9e64cb70cf3d3918#100",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "1.4e11",
+        "0x2.0E+9#1",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "2.7e11",
+        "0x4.0E+9#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "1.4e11",
+        "0x2.0E+9#1",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "2.7e11",
+        "0x4.0E+9#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "2.7e11",
+        "0x4.0E+9#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "2.2012e11",
+        "0x3.34E+9#10",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "2.2039e11",
+        "0x3.35E+9#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "2.2012e11",
+        "0x3.34E+9#10",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "2.2039e11",
+        "0x3.35E+9#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "2.2039e11",
+        "0x3.35E+9#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Floor,
+        "220258509288.81058147004192312329",
+        "0x33486e21e8.cf82446892db9d84#100",
+        Greater,
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Ceiling,
+        "220258509288.81058147004192312350",
+        "0x33486e21e8.cf82446892db9d88#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "220258509288.81058147004192312350",
+        "0x33486e21e8.cf82446892db9d88#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "64.0",
+        "0x4.0E+1#1",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "64.0",
+        "0x4.0E+1#1",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.3e2",
+        "0x8.0E+1#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "64.0",
+        "0x4.0E+1#1",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "69.250",
+        "0x45.4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "69.375",
+        "0x45.6#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "69.250",
+        "0x45.4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "69.375",
+        "0x45.6#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "69.375",
+        "0x45.6#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "69.314718055994530941723212145798",
+        "0x45.50915ccdf50b871adcf22760#100",
+        Greater,
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "69.314718055994530941723212145899",
+        "0x45.50915ccdf50b871adcf22768#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "69.314718055994530941723212145798",
+        "0x45.50915ccdf50b871adcf22760#100",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.98535",
+        "0x0.fc4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.98633",
+        "0x0.fc8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.98535",
+        "0x0.fc4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.98633",
+        "0x0.fc8#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.98535",
+        "0x0.fc4#10",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.98542064692776706918717403697689",
+        "0x0.fc44870b5ba0ce071ed58f21f#100",
+        Greater,
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "1.4004",
+        "0x1.668#10",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "1.4023",
+        "0x1.670#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "1.4004",
+        "0x1.668#10",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "1.4023",
+        "0x1.670#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "1.4023",
+        "0x1.670#10",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "1.4017625638156332600711080542959",
+        "0x1.66d9e9505862d11d5cb0578b2#100",
+        Less,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+}
+
+#[test]
+fn ln_gamma_prec_round_fail() {
+    assert_panic!(Float::ONE.ln_gamma_prec_round(0, Floor));
+    assert_panic!(Float::ONE.ln_gamma_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.ln_gamma_prec_round_assign(0, Floor)
+    });
+
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.ln_gamma_prec_round(1, Exact));
+    assert_panic!(X.ln_gamma_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.ln_gamma_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_ln_gamma_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, sign_out, o_out| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec(x.clone(), prec);
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+        assert_eq!(o, o_out);
+
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_ref(&x, prec);
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+        assert_eq!(o, o_out);
+
+        let (rug_ln_gamma, rug_sign, rug_o) = rug_ln_gamma_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(rug_sign, sign);
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "Infinity", "Infinity", Greater, Equal);
+    test("0", 10, "Infinity", "Infinity", Greater, Equal);
+    test("0", 100, "Infinity", "Infinity", Greater, Equal);
+    test("1", 1, "0.0", "0x0.0", Greater, Equal);
+    test("1", 10, "0.0", "0x0.0", Greater, Equal);
+    test("1", 100, "0.0", "0x0.0", Greater, Equal);
+    test("-1", 1, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 10, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 100, "Infinity", "Infinity", Greater, Equal);
+    test("1/2", 1, "0.50", "0x0.8#1", Greater, Less);
+    test("1/2", 10, "0.57227", "0x0.928#10", Greater, Less);
+    test(
+        "1/2",
+        100,
+        "0.57236494292470008707171367567642",
+        "0x0.928682473d0de85eafcab6354#100",
+        Greater,
+        Less,
+    );
+    test("-1/2", 1, "1.0", "0x1.0#1", Less, Less);
+    test("-1/2", 10, "1.2656", "0x1.440#10", Less, Greater);
+    test(
+        "-1/2",
+        100,
+        "1.2655121234846453964889457971344",
+        "0x1.43f89a3f0edd620a79ae69cd4#100",
+        Less,
+        Less,
+    );
+    test("1/3", 1, "1.0", "0x1.0#1", Greater, Greater);
+    test("1/3", 10, "0.98535", "0x0.fc4#10", Greater, Less);
+    test(
+        "1/3",
+        100,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Less,
+    );
+    test("-1/3", 1, "1.0", "0x1.0#1", Less, Less);
+    test("-1/3", 10, "1.4023", "0x1.670#10", Less, Greater);
+    test(
+        "-1/3",
+        100,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+    test("22/7", 1, "1.0", "0x1.0#1", Greater, Greater);
+    test("22/7", 10, "0.82910", "0x0.d44#10", Greater, Greater);
+    test(
+        "22/7",
+        100,
+        "0.82893056721194191538759911353884",
+        "0x0.d434cb2cd47c37fe03742ce71#100",
+        Greater,
+        Greater,
+    );
+    test("5", 1, "4.0", "0x4.0#1", Greater, Greater);
+    test("5", 10, "3.1797", "0x3.2e#10", Greater, Greater);
+    test(
+        "5",
+        100,
+        "3.1780538303479456196469416012962",
+        "0x3.2d94ef92459f167a01c4a81d0#100",
+        Greater,
+        Less,
+    );
+    test("100", 1, "2.6e2", "0x1.0E+2#1", Greater, Less);
+    test("100", 10, "359.00", "0x167.0#10", Greater, Less);
+    test(
+        "100",
+        100,
+        "359.13420536957539877604401046023",
+        "0x167.225b487946205a0f759b9e0#100",
+        Greater,
+        Less,
+    );
+    test("-7/2", 1, "-1.0", "-0x1.0#1", Greater, Greater);
+    test("-7/2", 10, "-1.3086", "-0x1.4f0#10", Greater, Greater);
+    test(
+        "-7/2",
+        100,
+        "-1.3090066849930420463607151520826",
+        "-0x1.4f1b0fe64a5d865fa2cc44a4e#100",
+        Greater,
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn ln_gamma_rational_prec_fail() {
+    Float::ln_gamma_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn ln_gamma_rational_prec_ref_fail() {
+    Float::ln_gamma_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_ln_gamma_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, sign_out, o_out| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(x.clone(), prec, rm);
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+        assert_eq!(o, o_out);
+
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round_ref(&x, prec, rm);
+        assert!(ln_gamma.is_valid());
+        assert_eq!(ln_gamma.to_string(), out);
+        assert_eq!(to_hex_string(&ln_gamma), out_hex);
+        assert_eq!(sign, sign_out);
+        assert_eq!(o, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln_gamma, rug_sign, rug_o) = rug_ln_gamma_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+                ComparableFloatRef(&ln_gamma)
+            );
+            assert_eq!(rug_sign, sign);
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "Infinity", "Infinity", Greater, Equal);
+    test("0", 1, Ceiling, "Infinity", "Infinity", Greater, Equal);
+    test("0", 1, Down, "Infinity", "Infinity", Greater, Equal);
+    test("0", 1, Up, "Infinity", "Infinity", Greater, Equal);
+    test("0", 1, Nearest, "Infinity", "Infinity", Greater, Equal);
+    test("0", 1, Exact, "Infinity", "Infinity", Greater, Equal);
+    test("0", 10, Floor, "Infinity", "Infinity", Greater, Equal);
+    test("0", 10, Ceiling, "Infinity", "Infinity", Greater, Equal);
+    test("0", 10, Down, "Infinity", "Infinity", Greater, Equal);
+    test("0", 10, Up, "Infinity", "Infinity", Greater, Equal);
+    test("0", 10, Nearest, "Infinity", "Infinity", Greater, Equal);
+    test("0", 10, Exact, "Infinity", "Infinity", Greater, Equal);
+    test("0", 100, Nearest, "Infinity", "Infinity", Greater, Equal);
+    test("1", 1, Floor, "0.0", "0x0.0", Greater, Equal);
+    test("1", 1, Ceiling, "0.0", "0x0.0", Greater, Equal);
+    test("1", 1, Down, "0.0", "0x0.0", Greater, Equal);
+    test("1", 1, Up, "0.0", "0x0.0", Greater, Equal);
+    test("1", 1, Nearest, "0.0", "0x0.0", Greater, Equal);
+    test("1", 1, Exact, "0.0", "0x0.0", Greater, Equal);
+    test("1", 10, Floor, "0.0", "0x0.0", Greater, Equal);
+    test("1", 10, Ceiling, "0.0", "0x0.0", Greater, Equal);
+    test("1", 10, Down, "0.0", "0x0.0", Greater, Equal);
+    test("1", 10, Up, "0.0", "0x0.0", Greater, Equal);
+    test("1", 10, Nearest, "0.0", "0x0.0", Greater, Equal);
+    test("1", 10, Exact, "0.0", "0x0.0", Greater, Equal);
+    test("1", 100, Nearest, "0.0", "0x0.0", Greater, Equal);
+    test("-1", 1, Floor, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 1, Ceiling, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 1, Down, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 1, Up, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 1, Nearest, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 1, Exact, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 10, Floor, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 10, Ceiling, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 10, Down, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 10, Up, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 10, Nearest, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 10, Exact, "Infinity", "Infinity", Greater, Equal);
+    test("-1", 100, Nearest, "Infinity", "Infinity", Greater, Equal);
+    test("1/2", 1, Floor, "0.50", "0x0.8#1", Greater, Less);
+    test("1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater, Greater);
+    test("1/2", 1, Down, "0.50", "0x0.8#1", Greater, Less);
+    test("1/2", 1, Up, "1.0", "0x1.0#1", Greater, Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Greater, Less);
+    test("1/2", 10, Floor, "0.57227", "0x0.928#10", Greater, Less);
+    test(
+        "1/2",
+        10,
+        Ceiling,
+        "0.57324",
+        "0x0.92c#10",
+        Greater,
+        Greater,
+    );
+    test("1/2", 10, Down, "0.57227", "0x0.928#10", Greater, Less);
+    test("1/2", 10, Up, "0.57324", "0x0.92c#10", Greater, Greater);
+    test("1/2", 10, Nearest, "0.57227", "0x0.928#10", Greater, Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.57236494292470008707171367567642",
+        "0x0.928682473d0de85eafcab6354#100",
+        Greater,
+        Less,
+    );
+    test("-1/2", 1, Floor, "1.0", "0x1.0#1", Less, Less);
+    test("-1/2", 1, Ceiling, "2.0", "0x2.0#1", Less, Greater);
+    test("-1/2", 1, Down, "1.0", "0x1.0#1", Less, Less);
+    test("-1/2", 1, Up, "2.0", "0x2.0#1", Less, Greater);
+    test("-1/2", 1, Nearest, "1.0", "0x1.0#1", Less, Less);
+    test("-1/2", 10, Floor, "1.2637", "0x1.438#10", Less, Less);
+    test("-1/2", 10, Ceiling, "1.2656", "0x1.440#10", Less, Greater);
+    test("-1/2", 10, Down, "1.2637", "0x1.438#10", Less, Less);
+    test("-1/2", 10, Up, "1.2656", "0x1.440#10", Less, Greater);
+    test("-1/2", 10, Nearest, "1.2656", "0x1.440#10", Less, Greater);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "1.2655121234846453964889457971344",
+        "0x1.43f89a3f0edd620a79ae69cd4#100",
+        Less,
+        Less,
+    );
+    test("1/3", 1, Floor, "0.50", "0x0.8#1", Greater, Less);
+    test("1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater, Greater);
+    test("1/3", 1, Down, "0.50", "0x0.8#1", Greater, Less);
+    test("1/3", 1, Up, "1.0", "0x1.0#1", Greater, Greater);
+    test("1/3", 1, Nearest, "1.0", "0x1.0#1", Greater, Greater);
+    test("1/3", 10, Floor, "0.98535", "0x0.fc4#10", Greater, Less);
+    test(
+        "1/3",
+        10,
+        Ceiling,
+        "0.98633",
+        "0x0.fc8#10",
+        Greater,
+        Greater,
+    );
+    test("1/3", 10, Down, "0.98535", "0x0.fc4#10", Greater, Less);
+    test("1/3", 10, Up, "0.98633", "0x0.fc8#10", Greater, Greater);
+    test("1/3", 10, Nearest, "0.98535", "0x0.fc4#10", Greater, Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.98542064692776706918717403697768",
+        "0x0.fc44870b5ba0ce071ed58f220#100",
+        Greater,
+        Less,
+    );
+    test("-1/3", 1, Floor, "1.0", "0x1.0#1", Less, Less);
+    test("-1/3", 1, Ceiling, "2.0", "0x2.0#1", Less, Greater);
+    test("-1/3", 1, Down, "1.0", "0x1.0#1", Less, Less);
+    test("-1/3", 1, Up, "2.0", "0x2.0#1", Less, Greater);
+    test("-1/3", 1, Nearest, "1.0", "0x1.0#1", Less, Less);
+    test("-1/3", 10, Floor, "1.4004", "0x1.668#10", Less, Less);
+    test("-1/3", 10, Ceiling, "1.4023", "0x1.670#10", Less, Greater);
+    test("-1/3", 10, Down, "1.4004", "0x1.668#10", Less, Less);
+    test("-1/3", 10, Up, "1.4023", "0x1.670#10", Less, Greater);
+    test("-1/3", 10, Nearest, "1.4023", "0x1.670#10", Less, Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "1.4017625638156332600711080542943",
+        "0x1.66d9e9505862d11d5cb0578b0#100",
+        Less,
+        Less,
+    );
+    test("22/7", 1, Floor, "0.50", "0x0.8#1", Greater, Less);
+    test("22/7", 1, Ceiling, "1.0", "0x1.0#1", Greater, Greater);
+    test("22/7", 1, Down, "0.50", "0x0.8#1", Greater, Less);
+    test("22/7", 1, Up, "1.0", "0x1.0#1", Greater, Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Greater, Greater);
+    test("22/7", 10, Floor, "0.82812", "0x0.d40#10", Greater, Less);
+    test(
+        "22/7",
+        10,
+        Ceiling,
+        "0.82910",
+        "0x0.d44#10",
+        Greater,
+        Greater,
+    );
+    test("22/7", 10, Down, "0.82812", "0x0.d40#10", Greater, Less);
+    test("22/7", 10, Up, "0.82910", "0x0.d44#10", Greater, Greater);
+    test(
+        "22/7",
+        10,
+        Nearest,
+        "0.82910",
+        "0x0.d44#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.82893056721194191538759911353884",
+        "0x0.d434cb2cd47c37fe03742ce71#100",
+        Greater,
+        Greater,
+    );
+    test("5", 1, Floor, "2.0", "0x2.0#1", Greater, Less);
+    test("5", 1, Ceiling, "4.0", "0x4.0#1", Greater, Greater);
+    test("5", 1, Down, "2.0", "0x2.0#1", Greater, Less);
+    test("5", 1, Up, "4.0", "0x4.0#1", Greater, Greater);
+    test("5", 1, Nearest, "4.0", "0x4.0#1", Greater, Greater);
+    test("5", 10, Floor, "3.1758", "0x3.2d#10", Greater, Less);
+    test("5", 10, Ceiling, "3.1797", "0x3.2e#10", Greater, Greater);
+    test("5", 10, Down, "3.1758", "0x3.2d#10", Greater, Less);
+    test("5", 10, Up, "3.1797", "0x3.2e#10", Greater, Greater);
+    test("5", 10, Nearest, "3.1797", "0x3.2e#10", Greater, Greater);
+    test(
+        "5",
+        100,
+        Nearest,
+        "3.1780538303479456196469416012962",
+        "0x3.2d94ef92459f167a01c4a81d0#100",
+        Greater,
+        Less,
+    );
+    test("100", 1, Floor, "2.6e2", "0x1.0E+2#1", Greater, Less);
+    test("100", 1, Ceiling, "5.1e2", "0x2.0E+2#1", Greater, Greater);
+    test("100", 1, Down, "2.6e2", "0x1.0E+2#1", Greater, Less);
+    test("100", 1, Up, "5.1e2", "0x2.0E+2#1", Greater, Greater);
+    test("100", 1, Nearest, "2.6e2", "0x1.0E+2#1", Greater, Less);
+    test("100", 10, Floor, "359.00", "0x167.0#10", Greater, Less);
+    test("100", 10, Ceiling, "359.50", "0x167.8#10", Greater, Greater);
+    test("100", 10, Down, "359.00", "0x167.0#10", Greater, Less);
+    test("100", 10, Up, "359.50", "0x167.8#10", Greater, Greater);
+    test("100", 10, Nearest, "359.00", "0x167.0#10", Greater, Less);
+    test(
+        "100",
+        100,
+        Nearest,
+        "359.13420536957539877604401046023",
+        "0x167.225b487946205a0f759b9e0#100",
+        Greater,
+        Less,
+    );
+    test("-7/2", 1, Floor, "-2.0", "-0x2.0#1", Greater, Less);
+    test("-7/2", 1, Ceiling, "-1.0", "-0x1.0#1", Greater, Greater);
+    test("-7/2", 1, Down, "-1.0", "-0x1.0#1", Greater, Greater);
+    test("-7/2", 1, Up, "-2.0", "-0x2.0#1", Greater, Less);
+    test("-7/2", 1, Nearest, "-1.0", "-0x1.0#1", Greater, Greater);
+    test("-7/2", 10, Floor, "-1.3105", "-0x1.4f8#10", Greater, Less);
+    test(
+        "-7/2",
+        10,
+        Ceiling,
+        "-1.3086",
+        "-0x1.4f0#10",
+        Greater,
+        Greater,
+    );
+    test("-7/2", 10, Down, "-1.3086", "-0x1.4f0#10", Greater, Greater);
+    test("-7/2", 10, Up, "-1.3105", "-0x1.4f8#10", Greater, Less);
+    test(
+        "-7/2",
+        10,
+        Nearest,
+        "-1.3086",
+        "-0x1.4f0#10",
+        Greater,
+        Greater,
+    );
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "-1.3090066849930420463607151520826",
+        "-0x1.4f1b0fe64a5d865fa2cc44a4e#100",
+        Greater,
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn ln_gamma_rational_prec_round_fail_1() {
+    Float::ln_gamma_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn ln_gamma_rational_prec_round_fail_2() {
+    Float::ln_gamma_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn ln_gamma_rational_prec_round_ref_fail() {
+    Float::ln_gamma_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_gamma_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (ln_gamma, sign, o) = x.clone().ln_gamma_prec_round(prec, rm);
+    assert!(ln_gamma.is_valid());
+
+    let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_prec_round_ref(prec, rm);
+    assert!(ln_gamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let (sign_alt, o_alt) = x_alt.ln_gamma_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln_gamma));
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln_gamma, rug_sign, rug_o) =
+            rug_ln_gamma_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(rug_sign, sign);
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(ln_gamma.is_nan(), x.is_nan());
+    assert_eq!(sign == Equal, x.is_nan());
+    if ln_gamma.is_normal() {
+        assert_eq!(ln_gamma.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, ss, oo) = x.ln_gamma_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(ln_gamma.clone()));
+            assert_eq!(ss, sign);
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.ln_gamma_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn ln_gamma_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        ln_gamma_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (ln_gamma, sign, o) = Float::NAN.ln_gamma_prec_round(prec, rm);
+        assert!(ln_gamma.is_nan());
+        assert_eq!(sign, Equal);
+        assert_eq!(o, Equal);
+
+        for (x, sign_out) in [
+            (Float::INFINITY, Greater),
+            (Float::NEGATIVE_INFINITY, Less),
+            (Float::ZERO, Greater),
+            (Float::NEGATIVE_ZERO, Less),
+        ] {
+            let (ln_gamma, sign, o) = x.ln_gamma_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::INFINITY));
+            assert_eq!(sign, sign_out);
+            assert_eq!(o, Equal);
+        }
+
+        let (ln_gamma, sign, o) = Float::ONE.ln_gamma_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::ZERO));
+        assert_eq!(sign, Greater);
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_gamma_prec_properties_helper(x: Float, prec: u64) {
+    let (ln_gamma, sign, o) = x.clone().ln_gamma_prec(prec);
+    assert!(ln_gamma.is_valid());
+
+    let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_prec_ref(prec);
+    assert!(ln_gamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let (sign_alt, o_alt) = x_alt.ln_gamma_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln_gamma));
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_prec_round_ref(prec, Nearest);
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let (rug_ln_gamma, rug_sign, rug_o) = rug_ln_gamma_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(rug_sign, sign);
+    assert_eq!(rug_o, o);
+
+    if ln_gamma.is_normal() {
+        assert_eq!(ln_gamma.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn ln_gamma_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        ln_gamma_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (ln_gamma, sign, o) = Float::NAN.ln_gamma_prec(prec);
+        assert!(ln_gamma.is_nan());
+        assert_eq!(sign, Equal);
+        assert_eq!(o, Equal);
+
+        let (ln_gamma, sign, o) = Float::NEGATIVE_ZERO.ln_gamma_prec(prec);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(sign, Less);
+        assert_eq!(o, Equal);
+
+        let (ln_gamma, sign, o) = Float::ONE.ln_gamma_prec(prec);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::ZERO));
+        assert_eq!(sign, Greater);
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_gamma_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (ln_gamma, sign, o) = x.clone().ln_gamma_round(rm);
+    assert!(ln_gamma.is_valid());
+
+    let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_round_ref(rm);
+    assert!(ln_gamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let (sign_alt, o_alt) = x_alt.ln_gamma_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln_gamma));
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let (ln_gamma_alt, sign_alt, o_alt) = x.ln_gamma_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln_gamma, rug_sign, rug_o) =
+            rug_ln_gamma_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(rug_sign, sign);
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, ss, oo) = x.ln_gamma_round_ref(rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(ln_gamma.clone()));
+            assert_eq!(ss, sign);
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.ln_gamma_round_ref(Exact));
+    }
+}
+
+#[test]
+fn ln_gamma_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        ln_gamma_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (ln_gamma, sign, o) = Float::NAN.ln_gamma_round(rm);
+        assert!(ln_gamma.is_nan());
+        assert_eq!(sign, Equal);
+        assert_eq!(o, Equal);
+
+        let (ln_gamma, sign, o) = Float::NEGATIVE_INFINITY.ln_gamma_round(rm);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(sign, Less);
+        assert_eq!(o, Equal);
+
+        let (ln_gamma, sign, o) = Float::ONE.ln_gamma_round(rm);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::ZERO));
+        assert_eq!(sign, Greater);
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+fn ln_gamma_properties() {
+    float_gen().test_properties(|x| {
+        let (ln_gamma, sign) = x.clone().ln_gamma();
+        assert!(ln_gamma.is_valid());
+
+        let (ln_gamma_alt, sign_alt) = (&x).ln_gamma();
+        assert!(ln_gamma_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma_alt),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(sign_alt, sign);
+
+        let mut x_alt = x.clone();
+        let sign_alt = x_alt.ln_gamma_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln_gamma));
+        assert_eq!(sign_alt, sign);
+
+        let (ln_gamma_alt, sign_alt, _) = x.ln_gamma_round_ref(Nearest);
+        assert_eq!(
+            ComparableFloatRef(&ln_gamma_alt),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(sign_alt, sign);
+
+        let (rug_ln_gamma, rug_sign) = rug_ln_gamma(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(rug_sign, sign);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_gamma_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(x.clone(), prec, rm);
+    assert!(ln_gamma.is_valid());
+
+    let (ln_gamma_alt, sign_alt, o_alt) = Float::ln_gamma_rational_prec_round_ref(&x, prec, rm);
+    assert!(ln_gamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln_gamma, rug_sign, rug_o) = rug_ln_gamma_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+            ComparableFloatRef(&ln_gamma)
+        );
+        assert_eq!(rug_sign, sign);
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!ln_gamma.is_nan());
+    assert_ne!(sign, Equal);
+    if ln_gamma.is_normal() {
+        assert_eq!(ln_gamma.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, ss, oo) = Float::ln_gamma_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(ln_gamma.clone()));
+            assert_eq!(ss, sign);
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::ln_gamma_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn ln_gamma_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        ln_gamma_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(sign, Greater);
+        assert_eq!(o, Equal);
+
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec_round(Rational::ONE, prec, rm);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::ZERO));
+        assert_eq!(sign, Greater);
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_gamma_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec(x.clone(), prec);
+    assert!(ln_gamma.is_valid());
+
+    let (ln_gamma_alt, sign_alt, o_alt) = Float::ln_gamma_rational_prec_ref(&x, prec);
+    assert!(ln_gamma_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let (ln_gamma_alt, sign_alt, o_alt) =
+        Float::ln_gamma_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(
+        ComparableFloatRef(&ln_gamma_alt),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(sign_alt, sign);
+    assert_eq!(o_alt, o);
+
+    let (rug_ln_gamma, rug_sign, rug_o) = rug_ln_gamma_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln_gamma)),
+        ComparableFloatRef(&ln_gamma)
+    );
+    assert_eq!(rug_sign, sign);
+    assert_eq!(rug_o, o);
+
+    if ln_gamma.is_normal() {
+        assert_eq!(ln_gamma.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn ln_gamma_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        ln_gamma_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::INFINITY));
+        assert_eq!(sign, Greater);
+        assert_eq!(o, Equal);
+
+        let (ln_gamma, sign, o) = Float::ln_gamma_rational_prec(Rational::ONE, prec);
+        assert_eq!(ComparableFloat(ln_gamma), ComparableFloat(Float::ZERO));
+        assert_eq!(sign, Greater);
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_ln_gamma() {
+    fn test<T: PrimitiveFloat>(x: T, out: T, sign_out: Ordering)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let (ln_gamma, sign) = primitive_float_ln_gamma(x);
+        assert_eq!(NiceFloat(ln_gamma), NiceFloat(out));
+        assert_eq!(sign, sign_out);
+    }
+    test::<f32>(f32::NAN, f32::NAN, Equal);
+    test::<f32>(f32::INFINITY, f32::INFINITY, Greater);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::INFINITY, Less);
+    test::<f32>(0.0, f32::INFINITY, Greater);
+    test::<f32>(-0.0, f32::INFINITY, Less);
+    test::<f32>(1.0, 0.0, Greater);
+    test::<f32>(-1.0, f32::INFINITY, Greater);
+    test::<f32>(0.5, 0.5723649, Greater);
+    test::<f32>(-0.5, 1.2655121, Less);
+    test::<f32>(2.0, 0.0, Greater);
+    test::<f32>(5.0, 3.1780539, Greater);
+    test::<f32>(-2.5, -0.056243718, Less);
+    test::<f32>(10.0, 12.801827, Greater);
+    test::<f32>(0.001, 6.907179, Greater);
+    test::<f32>(10000000000.0, 220258520000.0, Greater);
+
+    test::<f64>(f64::NAN, f64::NAN, Equal);
+    test::<f64>(f64::INFINITY, f64::INFINITY, Greater);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::INFINITY, Less);
+    test::<f64>(0.0, f64::INFINITY, Greater);
+    test::<f64>(-0.0, f64::INFINITY, Less);
+    test::<f64>(1.0, 0.0, Greater);
+    test::<f64>(-1.0, f64::INFINITY, Greater);
+    test::<f64>(0.5, 0.5723649429247001, Greater);
+    test::<f64>(-0.5, 1.2655121234846454, Less);
+    test::<f64>(2.0, 0.0, Greater);
+    test::<f64>(5.0, 3.1780538303479458, Greater);
+    test::<f64>(-2.5, -0.056243716497674054, Less);
+    test::<f64>(10.0, 12.801827480081469, Greater);
+    test::<f64>(0.001, 6.907178885383853, Greater);
+    test::<f64>(10000000000.0, 220258509288.81058, Greater);
+    test::<f64>(1.0e300, 6.897755278982137e302, Greater);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_ln_gamma_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let (ln_gamma, sign) = primitive_float_ln_gamma(x);
+        assert_eq!(ln_gamma.is_nan(), x.is_nan());
+        assert_eq!(sign == Equal, x.is_nan());
+    });
+}
+
+#[test]
+fn primitive_float_ln_gamma_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_ln_gamma_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_ln_gamma_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T, sign_out: Ordering)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        let (ln_gamma, sign) = primitive_float_ln_gamma_rational(&x);
+        assert_eq!(NiceFloat(ln_gamma), NiceFloat(out));
+        assert_eq!(sign, sign_out);
+    }
+    test::<f32>("0", f32::INFINITY, Greater);
+    test::<f32>("1", 0.0, Greater);
+    test::<f32>("-1", f32::INFINITY, Greater);
+    test::<f32>("1/2", 0.5723649, Greater);
+    test::<f32>("-1/2", 1.2655121, Less);
+    test::<f32>("1/3", 0.98542064, Greater);
+    test::<f32>("22/7", 0.82893056, Greater);
+    test::<f32>("3/2", -0.12078224, Greater);
+    test::<f32>("-7/2", -1.3090067, Greater);
+    test::<f32>("1000000", 12815505.0, Greater);
+
+    test::<f64>("0", f64::INFINITY, Greater);
+    test::<f64>("1", 0.0, Greater);
+    test::<f64>("-1", f64::INFINITY, Greater);
+    test::<f64>("1/2", 0.5723649429247001, Greater);
+    test::<f64>("-1/2", 1.2655121234846454, Less);
+    test::<f64>("1/3", 0.9854206469277671, Greater);
+    test::<f64>("22/7", 0.8289305672119419, Greater);
+    test::<f64>("3/2", -0.12078223763524522, Greater);
+    test::<f64>("-7/2", -1.309006684993042, Greater);
+    test::<f64>("1000000", 12815504.569147611, Greater);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_ln_gamma_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let (ln_gamma, sign) = primitive_float_ln_gamma_rational::<T>(&x);
+        assert!(!ln_gamma.is_nan());
+        assert_ne!(sign, Equal);
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            let (ln_gamma, sign) = primitive_float_ln_gamma_rational::<T>(&Rational::exact_from(x));
+            let (ln_gamma_alt, sign_alt) = primitive_float_ln_gamma(x);
+            assert_eq!(NiceFloat(ln_gamma), NiceFloat(ln_gamma_alt));
+            assert_eq!(sign, sign_alt);
+        }
+    });
+}
+
+#[test]
+fn primitive_float_ln_gamma_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_ln_gamma_rational_properties_helper);
+}
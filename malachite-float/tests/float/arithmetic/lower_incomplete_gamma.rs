@@ -0,0 +1,6290 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{LowerIncompleteGamma, LowerIncompleteGammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_pair_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::lower_incomplete_gamma::{
+    primitive_float_lower_incomplete_gamma, primitive_float_lower_incomplete_gamma_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::lower_incomplete_gamma::{
+    rug_lower_incomplete_gamma, rug_lower_incomplete_gamma_prec,
+    rug_lower_incomplete_gamma_prec_round, rug_lower_incomplete_gamma_rational_prec,
+    rug_lower_incomplete_gamma_rational_prec_round, rug_lower_incomplete_gamma_round,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_48,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_29, float_float_unsigned_triple_gen_var_1,
+    float_pair_gen, rational_rational_unsigned_rounding_mode_quadruple_gen_var_6,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{
+    rational_pair_gen, rational_rational_unsigned_triple_gen_var_2,
+};
+use std::cmp::max;
+use std::panic::catch_unwind;
+
+// The oracle computes Γ(s) - Γ(s, x) with MPFR, which needs a lot of extra precision when x is
+// tiny, so it is only consulted for positive arguments with moderate exponents.
+fn rug_lower_incomplete_gamma_applies(s: &Float, x: &Float) -> bool {
+    *s > 0u32
+        && *x > 0u32
+        && s.get_exponent().is_some_and(|e| (-16..=6).contains(&e))
+        && x.get_exponent().is_some_and(|e| (-16..=10).contains(&e))
+}
+
+fn rug_lower_incomplete_gamma_rational_applies(s: &Rational, x: &Rational) -> bool {
+    *s > 0u32
+        && *x > 0u32
+        && s.significant_bits() <= 64
+        && x.significant_bits() <= 64
+        && s.lt_abs(&64u32)
+        && x.lt_abs(&1024u32)
+        && s.gt_abs(&Rational::from_unsigneds(1u32, 1 << 16))
+        && x.gt_abs(&Rational::from_unsigneds(1u32, 1 << 16))
+}
+
+fn lower_incomplete_gamma_identity_properties(x: &Float, prec: u64, rm: RoundingMode) {
+    // γ(1, x) = 1 - e^(-x)
+    if rm != Exact && *x > 0u32 && x.is_finite() {
+        let (gamma, o) = Float::ONE.lower_incomplete_gamma_prec_round_val_ref(x, prec, rm);
+        let (gamma_alt, o_alt) = (-x).exp_x_minus_1_prec_round(prec, -rm);
+        assert_eq!(ComparableFloat(-gamma_alt), ComparableFloat(gamma));
+        assert_eq!(o_alt.reverse(), o);
+    }
+}
+
+fn lower_incomplete_gamma_special_values_properties(prec: u64, rm: RoundingMode) {
+    let f = |s: Float, x: Float| s.lower_incomplete_gamma_prec_round(x, prec, rm);
+    assert!(f(Float::NAN, Float::ONE).0.is_nan());
+    assert!(f(Float::ONE, Float::NAN).0.is_nan());
+    assert!(f(Float::ZERO, Float::ONE).0.is_nan());
+    assert!(f(Float::NEGATIVE_ZERO, Float::ONE).0.is_nan());
+    assert!(f(Float::NEGATIVE_ONE, Float::ONE).0.is_nan());
+    assert!(f(Float::NEGATIVE_INFINITY, Float::ONE).0.is_nan());
+    assert!(f(Float::ONE, Float::NEGATIVE_ONE).0.is_nan());
+    assert!(f(Float::ONE, Float::NEGATIVE_INFINITY).0.is_nan());
+    assert!(f(Float::INFINITY, Float::INFINITY).0.is_nan());
+    assert_eq!(f(Float::ONE, Float::ZERO), (Float::ZERO, Equal));
+    assert_eq!(f(Float::ONE, Float::NEGATIVE_ZERO), (Float::ZERO, Equal));
+    assert_eq!(f(Float::INFINITY, Float::ONE), (Float::ZERO, Equal));
+    assert_eq!(f(Float::INFINITY, Float::from(2)), (Float::INFINITY, Equal));
+    assert_eq!(f(Float::ONE, Float::INFINITY), (Float::ONE, Equal));
+}
+
+fn lower_incomplete_gamma_rational_special_values_properties(prec: u64, rm: RoundingMode) {
+    let f = |s: Rational, x: Rational| {
+        Float::lower_incomplete_gamma_rational_prec_round(s, x, prec, rm)
+    };
+    assert!(f(Rational::ZERO, Rational::ONE).0.is_nan());
+    assert!(f(Rational::NEGATIVE_ONE, Rational::ONE).0.is_nan());
+    assert!(f(Rational::ONE, Rational::NEGATIVE_ONE).0.is_nan());
+    assert_eq!(f(Rational::ONE, Rational::ZERO), (Float::ZERO, Equal));
+}
+
+#[test]
+fn test_lower_incomplete_gamma() {
+    let test = |a: &str, a_hex: &str, b: &str, b_hex: &str, out: &str, out_hex: &str| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let gamma = s.clone().lower_incomplete_gamma(x.clone());
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+
+        let gamma_alt = s.clone().lower_incomplete_gamma(&x);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        let gamma_alt = (&s).lower_incomplete_gamma(x.clone());
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        let gamma_alt = (&s).lower_incomplete_gamma(&x);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+
+        let mut gamma_alt = s.clone();
+        gamma_alt.lower_incomplete_gamma_assign(x.clone());
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        let mut gamma_alt = s.clone();
+        gamma_alt.lower_incomplete_gamma_assign(&x);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+
+        if rug_lower_incomplete_gamma_applies(&s, &x) {
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_lower_incomplete_gamma(
+                    &rug::Float::exact_from(&s),
+                    &rug::Float::exact_from(&x)
+                ))),
+                ComparableFloatRef(&gamma),
+            );
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "0.0", "0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("NaN", "NaN", "123.0", "0x7b.0#7", "NaN", "NaN");
+    test("NaN", "NaN", "7.9e-31", "0x1.0E-25#1", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("Infinity", "Infinity", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("Infinity", "Infinity", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "0.0",
+        "0x0.0",
+    );
+    test("-Infinity", "-Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test("-Infinity", "-Infinity", "0.0", "0x0.0", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("-Infinity", "-Infinity", "123.0", "0x7b.0#7", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "NaN",
+        "NaN",
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("0.0", "0x0.0", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("0.0", "0x0.0", "123.0", "0x7b.0#7", "NaN", "NaN");
+    test("0.0", "0x0.0", "7.9e-31", "0x1.0E-25#1", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("-0.0", "-0x0.0", "123.0", "0x7b.0#7", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "7.9e-31", "0x1.0E-25#1", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "0.50", "0x0.8#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.93401196415468746",
+        "0x0.ef1b68781dfab8#53",
+    );
+    test("1.0", "0x1.0#1", "123.0", "0x7b.0#7", "1.000", "0x1.00#7");
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "Infinity", "Infinity", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("-1.0", "-0x1.0#1", "123.0", "0x7b.0#7", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "7.9e-31", "0x1.0E-25#1", "NaN", "NaN");
+    test("0.50", "0x0.8#1", "NaN", "NaN", "NaN", "NaN");
+    test("0.50", "0x0.8#1", "Infinity", "Infinity", "2.0", "0x2.0#1");
+    test("0.50", "0x0.8#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.50", "0x0.8#1", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.50", "0x0.8#1", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("0.50", "0x0.8#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("0.50", "0x0.8#1", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.7375020747256571",
+        "0x1.bcccef9badc8d#53",
+    );
+    test("0.50", "0x0.8#1", "123.0", "0x7b.0#7", "1.766", "0x1.c4#7");
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.8e-15",
+        "0x8.0E-13#1",
+    );
+    test("3.0", "0x3.0#2", "NaN", "NaN", "NaN", "NaN");
+    test("3.0", "0x3.0#2", "Infinity", "Infinity", "2.0", "0x2.0#2");
+    test("3.0", "0x3.0#2", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("3.0", "0x3.0#2", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("3.0", "0x3.0#2", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("3.0", "0x3.0#2", "1.0", "0x1.0#1", "0.19", "0x0.3#2");
+    test("3.0", "0x3.0#2", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.0216864721220795",
+        "0x1.058d3ea087aaa#53",
+    );
+    test("3.0", "0x3.0#2", "123.0", "0x7b.0#7", "2.000", "0x2.00#7");
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.8e-91",
+        "0x6.0E-76#2",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        "0.15191084279750133",
+        "0x0.26e3a105b91ad#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.0901075872069494",
+        "0x1.17114a742ce18#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "8.5405893095242570e-96",
+        "0x1.23e1a400488b0E-79#50",
+    );
+    test("-3.5", "-0x3.8#3", "NaN", "NaN", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "Infinity", "Infinity", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "0.0", "0x0.0", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("-3.5", "-0x3.8#3", "123.0", "0x7b.0#7", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "7.9e-31", "0x1.0E-25#1", "NaN", "NaN");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "Infinity",
+        "Infinity",
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        "2.4225335464190143585749995501401",
+        "0x2.6c2b28935522f3d6389981c18#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-1.0",
+        "-0x1.0#1",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "2.6505827372064428263565134552275",
+        "0x2.a68c971ba4d253c9082bc0708#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.7719670649993922702590451330004e-10",
+        "0x1.30c7efbee12ad620443334064E-8#100",
+    );
+}
+
+#[test]
+fn test_lower_incomplete_gamma_prec() {
+    let test = |a: &str,
+                a_hex: &str,
+                b: &str,
+                b_hex: &str,
+                prec: u64,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let (gamma, o) = s.clone().lower_incomplete_gamma_prec(x.clone(), prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = s.clone().lower_incomplete_gamma_prec_val_ref(&x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_ref_val(x.clone(), prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_ref_ref(&x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.lower_incomplete_gamma_prec_assign(x.clone(), prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.lower_incomplete_gamma_prec_assign_ref(&x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        if rug_lower_incomplete_gamma_applies(&s, &x) {
+            let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_prec(
+                &rug::Float::exact_from(&s),
+                &rug::Float::exact_from(&x),
+                prec,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "123.0", "0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("Infinity", "Infinity", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "123.0", "0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", 1, "0.0", "0x0.0", Equal);
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.63184",
+        "0x0.a1c#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.63212055882855767840447622983856",
+        "0x0.a1d2a7274c4320e54521387d7#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.93359",
+        "0x0.ef0#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.93401196415468745338366992129366",
+        "0x0.ef1b68781dfab7b666e778a8d#100",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("0.50", "0x0.8#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.50", "0x0.8#1", "0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test(
+        "0.50", "0x0.8#1", "-0.0", "-0x0.0", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.4941",
+        "0x1.7e8#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.4936482656248540507989348722634",
+        "0x1.7e5fbb9495fb8553accd243a8#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.7383",
+        "0x1.bd0#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.7375020747256571082144286506401",
+        "0x1.bcccef9badc8d0c6e8e6f4494#100",
+        Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.7715",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.7764e-15",
+        "0x8.00E-13#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.7763568394002504646778106689453e-15",
+        "0x8.000000000000000000000000E-13#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "3.0", "0x3.0#2", "Infinity", "Infinity", 1, "2.0", "0x2.0#1", Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", "0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("3.0", "0x3.0#2", "-0.0", "-0x0.0", 1, "0.0", "0x0.0", Equal);
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.16064",
+        "0x0.292#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.16060279414278839202238114919278",
+        "0x0.291d43c47d4fa47a59a61a7330#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.0215",
+        "0x1.058#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.0216864721220795240772077018014",
+        "0x1.058d3ea087aa9fe88ba2c5750#100",
+        Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.2e-91",
+        "0x4.0E-76#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.6372e-91",
+        "0x5.56E-76#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.6363644884325755176985906516608e-91",
+        "0x5.5555555555555555555555550E-76#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.15186",
+        "0x0.26e#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.15191084279750143538790533390639",
+        "0x0.26e3a105b91ad75e721afc7e9c#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.0898",
+        "0x1.170#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.0901075872069494540098034899630",
+        "0x1.17114a742ce180894850a4c3e#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.2880377953400321441406907440419",
+        "0x2.49bcd84eff543f41b598fe364#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.5e-96",
+        "0x1.0E-79#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "8.5441e-96",
+        "0x1.240E-79#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "8.5405893095242551009759647599782e-96",
+        "0x1.23e1a400488aedd0428d4390aE-79#100",
+        Less,
+    );
+    test("-3.5", "-0x3.8#3", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-3.5", "-0x3.8#3", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-3.5", "-0x3.8#3", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("-3.5", "-0x3.8#3", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "Infinity",
+        "Infinity",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.0",
+        "0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "2.4219",
+        "0x2.6c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.4225335464190143585749995501401",
+        "0x2.6c2b28935522f3d6389981c18#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "2.6523",
+        "0x2.a7#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "2.6505827372064428263565134552275",
+        "0x2.a68c971ba4d253c9082bc0708#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.3e-10",
+        "0x1.0E-8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "2.7740e-10",
+        "0x1.310E-8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "2.7719670649993922702590451330004e-10",
+        "0x1.30c7efbee12ad620443334064E-8#100",
+        Less,
+    );
+}
+
+#[test]
+fn lower_incomplete_gamma_prec_fail() {
+    assert_panic!(Float::from(3).lower_incomplete_gamma_prec(Float::from(4), 0));
+    assert_panic!(Float::from(3).lower_incomplete_gamma_prec_ref_ref(&Float::from(4), 0));
+}
+
+#[test]
+fn test_lower_incomplete_gamma_round() {
+    let test = |a: &str,
+                a_hex: &str,
+                b: &str,
+                b_hex: &str,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let (gamma, o) = s.clone().lower_incomplete_gamma_round(x.clone(), rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = s.clone().lower_incomplete_gamma_round_val_ref(&x, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.lower_incomplete_gamma_round_ref_val(x.clone(), rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.lower_incomplete_gamma_round_ref_ref(&x, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.lower_incomplete_gamma_round_assign(x.clone(), rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.lower_incomplete_gamma_round_assign_ref(&x, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        if rug_lower_incomplete_gamma_applies(&s, &x)
+            && let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+        {
+            let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_round(
+                &rug::Float::exact_from(&s),
+                &rug::Float::exact_from(&x),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("1.0", "0x1.0#1", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", Nearest, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", Exact, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Nearest, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.93401196415468735",
+        "0x0.ef1b68781dfab0#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.93401196415468746",
+        "0x0.ef1b68781dfab8#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.93401196415468735",
+        "0x0.ef1b68781dfab0#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.93401196415468746",
+        "0x0.ef1b68781dfab8#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.93401196415468746",
+        "0x0.ef1b68781dfab8#53",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", Floor, "0.9922", "0x0.fe#7", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", Ceiling, "1.000", "0x1.00#7", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", Down, "0.9922", "0x0.fe#7", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", Up, "1.000", "0x1.00#7", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", Nearest, "1.000", "0x1.00#7", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.7375020747256571",
+        "0x1.bcccef9badc8d#53",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.7375020747256573",
+        "0x1.bcccef9badc8e#53",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.7375020747256571",
+        "0x1.bcccef9badc8d#53",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.7375020747256573",
+        "0x1.bcccef9badc8e#53",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.7375020747256571",
+        "0x1.bcccef9badc8d#53",
+        Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", Floor, "1.766", "0x1.c4#7", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", Ceiling, "1.781", "0x1.c8#7", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", Down, "1.766", "0x1.c4#7", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", Up, "1.781", "0x1.c8#7", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", Nearest, "1.766", "0x1.c4#7", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "8.9e-16",
+        "0x4.0E-13#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "8.9e-16",
+        "0x4.0E-13#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("3.0", "0x3.0#2", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.0", "0x3.0#2", "Infinity", "Infinity", Nearest, "2.0", "0x2.0#2", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "Infinity", "Infinity", Exact, "2.0", "0x2.0#2", Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Floor, "0.12", "0x0.2#2", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Ceiling, "0.19", "0x0.3#2", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Down, "0.12", "0x0.2#2", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Up, "0.19", "0x0.3#2", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Nearest, "0.19", "0x0.3#2", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.0216864721220793",
+        "0x1.058d3ea087aa9#53",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.0216864721220795",
+        "0x1.058d3ea087aaa#53",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.0216864721220793",
+        "0x1.058d3ea087aa9#53",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.0216864721220795",
+        "0x1.058d3ea087aaa#53",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.0216864721220795",
+        "0x1.058d3ea087aaa#53",
+        Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", Floor, "1.984", "0x1.fc#7", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", Ceiling, "2.000", "0x2.00#7", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", Down, "1.984", "0x1.fc#7", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", Up, "2.000", "0x2.00#7", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", Nearest, "2.000", "0x2.00#7", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "1.2e-91",
+        "0x4.0E-76#2",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.8e-91",
+        "0x6.0E-76#2",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "1.2e-91",
+        "0x4.0E-76#2",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.8e-91",
+        "0x6.0E-76#2",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "1.8e-91",
+        "0x6.0E-76#2",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        Nearest,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "0.15191084279750133",
+        "0x0.26e3a105b91ad#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Ceiling,
+        "0.15191084279750156",
+        "0x0.26e3a105b91ae#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Down,
+        "0.15191084279750133",
+        "0x0.26e3a105b91ad#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Up,
+        "0.15191084279750156",
+        "0x0.26e3a105b91ae#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "0.15191084279750133",
+        "0x0.26e3a105b91ad#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.0901075872069494",
+        "0x1.17114a742ce18#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.0901075872069497",
+        "0x1.17114a742ce19#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.0901075872069494",
+        "0x1.17114a742ce18#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.0901075872069497",
+        "0x1.17114a742ce19#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.0901075872069494",
+        "0x1.17114a742ce18#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "2.2880377953400348",
+        "0x2.49bcd84eff55#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "2.2880377953400348",
+        "0x2.49bcd84eff55#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "8.5405893095242437e-96",
+        "0x1.23e1a400488a8E-79#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "8.5405893095242570e-96",
+        "0x1.23e1a400488b0E-79#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "8.5405893095242437e-96",
+        "0x1.23e1a400488a8E-79#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "8.5405893095242570e-96",
+        "0x1.23e1a400488b0E-79#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "8.5405893095242570e-96",
+        "0x1.23e1a400488b0E-79#50",
+        Greater,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test("-3.5", "-0x3.8#3", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "-3.5", "-0x3.8#3", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "Infinity",
+        "Infinity",
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.0",
+        "0x0.0",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.0",
+        "0x0.0",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.0",
+        "-0x0.0",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.0",
+        "-0x0.0",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "2.4225335464190143585749995501401",
+        "0x2.6c2b28935522f3d6389981c18#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Ceiling,
+        "2.4225335464190143585749995501432",
+        "0x2.6c2b28935522f3d6389981c1c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Down,
+        "2.4225335464190143585749995501401",
+        "0x2.6c2b28935522f3d6389981c18#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Up,
+        "2.4225335464190143585749995501432",
+        "0x2.6c2b28935522f3d6389981c1c#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "2.4225335464190143585749995501401",
+        "0x2.6c2b28935522f3d6389981c18#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "2.6505827372064428263565134552243",
+        "0x2.a68c971ba4d253c9082bc0704#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "2.6505827372064428263565134552275",
+        "0x2.a68c971ba4d253c9082bc0708#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "2.6505827372064428263565134552243",
+        "0x2.a68c971ba4d253c9082bc0704#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "2.6505827372064428263565134552275",
+        "0x2.a68c971ba4d253c9082bc0708#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "2.6505827372064428263565134552275",
+        "0x2.a68c971ba4d253c9082bc0708#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "2.6789385347077476336556929409705",
+        "0x2.adceea72905e2ceec8d3e92c8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "2.6789385347077476336556929409705",
+        "0x2.adceea72905e2ceec8d3e92c8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "2.7719670649993922702590451330004e-10",
+        "0x1.30c7efbee12ad620443334064E-8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "2.7719670649993922702590451330041e-10",
+        "0x1.30c7efbee12ad620443334066E-8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "2.7719670649993922702590451330004e-10",
+        "0x1.30c7efbee12ad620443334064E-8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "2.7719670649993922702590451330041e-10",
+        "0x1.30c7efbee12ad620443334066E-8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "2.7719670649993922702590451330004e-10",
+        "0x1.30c7efbee12ad620443334064E-8#100",
+        Less,
+    );
+}
+
+#[test]
+fn lower_incomplete_gamma_round_fail() {
+    assert_panic!(Float::ONE_HALF.lower_incomplete_gamma_round(Float::ONE_HALF, Exact));
+    assert_panic!(Float::ONE_HALF.lower_incomplete_gamma_round_ref_ref(&Float::ONE_HALF, Exact));
+    assert_panic!({
+        let mut s = Float::ONE_HALF;
+        s.lower_incomplete_gamma_round_assign(Float::ONE_HALF, Exact)
+    });
+}
+
+#[test]
+fn test_lower_incomplete_gamma_prec_round() {
+    let test = |a: &str,
+                a_hex: &str,
+                b: &str,
+                b_hex: &str,
+                prec: u64,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let (gamma, o) = s
+            .clone()
+            .lower_incomplete_gamma_prec_round(x.clone(), prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = s
+            .clone()
+            .lower_incomplete_gamma_prec_round_val_ref(&x, prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_round_ref_val(x.clone(), prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.lower_incomplete_gamma_prec_round_assign(x.clone(), prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.lower_incomplete_gamma_prec_round_assign_ref(&x, prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        if rug_lower_incomplete_gamma_applies(&s, &x)
+            && let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+        {
+            let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_prec_round(
+                &rug::Float::exact_from(&s),
+                &rug::Float::exact_from(&x),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Nearest, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.63184",
+        "0x0.a1c#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.63281",
+        "0x0.a20#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.63184",
+        "0x0.a1c#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.93359",
+        "0x0.ef0#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.93457",
+        "0x0.ef4#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.93359",
+        "0x0.ef0#10",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, Floor, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, Down, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "1.4922",
+        "0x1.7e0#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.4941",
+        "0x1.7e8#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "1.4941",
+        "0x1.7e8#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.7363",
+        "0x1.bc8#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.7383",
+        "0x1.bd0#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.7383",
+        "0x1.bd0#10",
+        Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", 1, Down, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", 1, Up, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "123.0", "0x7b.0#7", 1, Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.7715",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.7734",
+        "0x1.c60#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.7715",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "8.9e-16",
+        "0x4.0E-13#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "8.9e-16",
+        "0x4.0E-13#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.8e-15",
+        "0x8.0E-13#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.7746e-15",
+        "0x7.feE-13#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.7764e-15",
+        "0x8.00E-13#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.7764e-15",
+        "0x8.00E-13#10",
+        Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Floor, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Ceiling, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Down, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Up, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Nearest, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.16040",
+        "0x0.291#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.16064",
+        "0x0.292#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.16064",
+        "0x0.292#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.0215",
+        "0x1.058#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.0234",
+        "0x1.060#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.0215",
+        "0x1.058#10",
+        Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", 1, Down, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", 1, Up, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "123.0", "0x7b.0#7", 1, Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.9980",
+        "0x1.ff8#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "1.2e-91",
+        "0x4.0E-76#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "2.5e-91",
+        "0x8.0E-76#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "1.2e-91",
+        "0x4.0E-76#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "2.5e-91",
+        "0x8.0E-76#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.2e-91",
+        "0x4.0E-76#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.6348e-91",
+        "0x5.54E-76#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.6372e-91",
+        "0x5.56E-76#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.6372e-91",
+        "0x5.56E-76#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Nearest,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.15186",
+        "0x0.26e#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.15210",
+        "0x0.26f#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.15186",
+        "0x0.26e#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.0898",
+        "0x1.170#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.0918",
+        "0x1.178#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.0898",
+        "0x1.170#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "2.2852",
+        "0x2.49#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "7.5e-96",
+        "0x1.0E-79#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.5e-95",
+        "0x2.0E-79#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "7.5e-96",
+        "0x1.0E-79#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.5e-95",
+        "0x2.0E-79#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.5e-96",
+        "0x1.0E-79#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "8.5294e-96",
+        "0x1.238E-79#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "8.5441e-96",
+        "0x1.240E-79#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "8.5441e-96",
+        "0x1.240E-79#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "2.4219",
+        "0x2.6c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "2.4258",
+        "0x2.6d#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "2.4219",
+        "0x2.6c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "2.6484",
+        "0x2.a6#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "2.6523",
+        "0x2.a7#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "2.6523",
+        "0x2.a7#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "2.6758",
+        "0x2.ad#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "2.3e-10",
+        "0x1.0E-8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "4.7e-10",
+        "0x2.0E-8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "2.3e-10",
+        "0x1.0E-8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "4.7e-10",
+        "0x2.0E-8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "2.3e-10",
+        "0x1.0E-8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "2.7694e-10",
+        "0x1.308E-8#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "2.7740e-10",
+        "0x1.310E-8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "2.7740e-10",
+        "0x1.310E-8#10",
+        Greater,
+    );
+}
+
+#[test]
+fn lower_incomplete_gamma_prec_round_fail() {
+    assert_panic!(Float::from(3).lower_incomplete_gamma_prec_round(Float::from(4), 0, Floor));
+    assert_panic!(Float::from(3).lower_incomplete_gamma_prec_round_ref_ref(
+        &Float::from(4),
+        0,
+        Floor
+    ));
+    assert_panic!(Float::ONE_HALF.lower_incomplete_gamma_prec_round(Float::ONE_HALF, 10, Exact));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn lower_incomplete_gamma_prec_round_properties_helper(
+    s: Float,
+    x: Float,
+    prec: u64,
+    rm: RoundingMode,
+) {
+    let (gamma, o) = s
+        .clone()
+        .lower_incomplete_gamma_prec_round(x.clone(), prec, rm);
+    assert!(gamma.is_valid());
+    let (gamma_alt, o_alt) = s
+        .clone()
+        .lower_incomplete_gamma_prec_round_val_ref(&x, prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_round_ref_val(x.clone(), prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.lower_incomplete_gamma_prec_round_assign(x.clone(), prec, rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.lower_incomplete_gamma_prec_round_assign_ref(&x, prec, rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if rug_lower_incomplete_gamma_applies(&s, &x)
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_prec_round(
+            &rug::Float::exact_from(&s),
+            &rug::Float::exact_from(&x),
+            prec,
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if s.is_nan() || x.is_nan() || x < 0u32 && s != Float::INFINITY {
+        assert!(gamma.is_nan());
+    }
+    if gamma.is_normal() {
+        assert_eq!(gamma.get_prec(), Some(prec));
+    }
+    // For a positive x, both incomplete gamma functions are integrals of a positive function
+    if x > 0u32 && !gamma.is_nan() {
+        assert!(gamma.is_sign_positive());
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (g, oo) = s.lower_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(g), ComparableFloat(gamma.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(s.lower_incomplete_gamma_prec_round_ref_ref(&x, prec, Exact));
+    }
+
+    lower_incomplete_gamma_identity_properties(&x, prec, rm);
+}
+
+#[test]
+fn lower_incomplete_gamma_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_29().test_properties(
+        |(s, x, prec, rm)| {
+            lower_incomplete_gamma_prec_round_properties_helper(s, x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        lower_incomplete_gamma_special_values_properties(prec, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn lower_incomplete_gamma_prec_properties_helper(s: Float, x: Float, prec: u64) {
+    let (gamma, o) = s.clone().lower_incomplete_gamma_prec(x.clone(), prec);
+    assert!(gamma.is_valid());
+    let (gamma_alt, o_alt) = s.clone().lower_incomplete_gamma_prec_val_ref(&x, prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_ref_val(x.clone(), prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_ref_ref(&x, prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.lower_incomplete_gamma_prec_assign(x.clone(), prec);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.lower_incomplete_gamma_prec_assign_ref(&x, prec);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_round_ref_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if rug_lower_incomplete_gamma_applies(&s, &x) {
+        let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_prec(
+            &rug::Float::exact_from(&s),
+            &rug::Float::exact_from(&x),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn lower_incomplete_gamma_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(s, x, prec)| {
+        lower_incomplete_gamma_prec_properties_helper(s, x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn lower_incomplete_gamma_round_properties_helper(s: Float, x: Float, rm: RoundingMode) {
+    let (gamma, o) = s.clone().lower_incomplete_gamma_round(x.clone(), rm);
+    assert!(gamma.is_valid());
+    let (gamma_alt, o_alt) = s.clone().lower_incomplete_gamma_round_val_ref(&x, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_round_ref_val(x.clone(), rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_round_ref_ref(&x, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.lower_incomplete_gamma_round_assign(x.clone(), rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.lower_incomplete_gamma_round_assign_ref(&x, rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let prec = max(s.significant_bits(), x.significant_bits());
+    let (gamma_alt, o_alt) = s.lower_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if rug_lower_incomplete_gamma_applies(&s, &x)
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_round(
+            &rug::Float::exact_from(&s),
+            &rug::Float::exact_from(&x),
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn lower_incomplete_gamma_round_properties() {
+    float_float_rounding_mode_triple_gen_var_48().test_properties(|(s, x, rm)| {
+        lower_incomplete_gamma_round_properties_helper(s, x, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn lower_incomplete_gamma_properties_helper(s: Float, x: Float) {
+    let gamma = s.clone().lower_incomplete_gamma(x.clone());
+    assert!(gamma.is_valid());
+    let gamma_alt = s.clone().lower_incomplete_gamma(&x);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let gamma_alt = (&s).lower_incomplete_gamma(x.clone());
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let gamma_alt = (&s).lower_incomplete_gamma(&x);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    let mut gamma_alt = s.clone();
+    gamma_alt.lower_incomplete_gamma_assign(x.clone());
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let mut gamma_alt = s.clone();
+    gamma_alt.lower_incomplete_gamma_assign(&x);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    let prec = max(s.significant_bits(), x.significant_bits());
+    let (gamma_alt, _) = s.lower_incomplete_gamma_prec_round_ref_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let (gamma_alt, _) = s.clone().lower_incomplete_gamma_prec(x.clone(), prec);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let (gamma_alt, _) = s.clone().lower_incomplete_gamma_round(x.clone(), Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    if rug_lower_incomplete_gamma_applies(&s, &x) {
+        let rug_gamma =
+            rug_lower_incomplete_gamma(&rug::Float::exact_from(&s), &rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+    }
+}
+
+#[test]
+fn lower_incomplete_gamma_properties() {
+    float_pair_gen().test_properties(|(s, x)| {
+        lower_incomplete_gamma_properties_helper(s, x);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_lower_incomplete_gamma() {
+    fn test<T: PrimitiveFloat>(s: T, x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(
+            NiceFloat(primitive_float_lower_incomplete_gamma(s, x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>(f32::NAN, 1.0, f32::NAN);
+    test::<f32>(1.0, f32::NAN, f32::NAN);
+    test::<f32>(1.0, 1.0, 0.63212055);
+    test::<f32>(0.5, 0.5, 1.2100356);
+    test::<f32>(2.0, 3.0, 0.8008517);
+    test::<f32>(0.0, 1.0, f32::NAN);
+    test::<f32>(-0.5, 0.25, f32::NAN);
+    test::<f32>(-2.0, 1.0, f32::NAN);
+    test::<f32>(3.0, 0.0, 0.0);
+    test::<f32>(-0.0, 0.0, f32::NAN);
+    test::<f32>(1.0, -1.0, f32::NAN);
+    test::<f32>(f32::INFINITY, 2.0, f32::INFINITY);
+    test::<f32>(2.0, f32::INFINITY, 1.0);
+    test::<f32>(1000.0, 1000.0, f32::INFINITY);
+    test::<f32>(0.001, 1.0e-10, 977.2372);
+    test::<f32>(5.0, 100.0, 24.0);
+
+    test::<f64>(f64::NAN, 1.0, f64::NAN);
+    test::<f64>(1.0, f64::NAN, f64::NAN);
+    test::<f64>(1.0, 1.0, 0.6321205588285577);
+    test::<f64>(0.5, 0.5, 1.2100356193111088);
+    test::<f64>(2.0, 3.0, 0.8008517265285442);
+    test::<f64>(0.0, 1.0, f64::NAN);
+    test::<f64>(-0.5, 0.25, f64::NAN);
+    test::<f64>(-2.0, 1.0, f64::NAN);
+    test::<f64>(3.0, 0.0, 0.0);
+    test::<f64>(-0.0, 0.0, f64::NAN);
+    test::<f64>(1.0, -1.0, f64::NAN);
+    test::<f64>(f64::INFINITY, 2.0, f64::INFINITY);
+    test::<f64>(2.0, f64::INFINITY, 1.0);
+    test::<f64>(1000.0, 1000.0, f64::INFINITY);
+    test::<f64>(0.001, 1.0e-10, 977.237220955713);
+    test::<f64>(5.0, 100.0, 24.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_lower_incomplete_gamma_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_pair_gen::<T>().test_properties(|(s, x)| {
+        let gamma = primitive_float_lower_incomplete_gamma(s, x);
+        if s.is_nan() || x.is_nan() || x < T::ZERO && s != T::INFINITY {
+            assert!(gamma.is_nan());
+        }
+        if x > T::ZERO && !gamma.is_nan() {
+            assert!(gamma.is_sign_positive());
+        }
+    });
+}
+
+#[test]
+fn primitive_float_lower_incomplete_gamma_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_lower_incomplete_gamma_properties_helper);
+}
+
+#[test]
+fn test_lower_incomplete_gamma_rational_prec() {
+    let test = |s, x, prec, out: &str, out_hex: &str, out_o| {
+        let s = Rational::from_str(s).unwrap();
+        let x = Rational::from_str(x).unwrap();
+
+        let (gamma, o) = Float::lower_incomplete_gamma_rational_prec(s.clone(), x.clone(), prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_val_ref(s.clone(), &x, prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_ref_val(&s, x.clone(), prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::lower_incomplete_gamma_rational_prec_ref_ref(&s, &x, prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        if rug_lower_incomplete_gamma_rational_applies(&s, &x) {
+            let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_rational_prec(&s, &x, prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", "0", 1, "NaN", "NaN", Equal);
+    test("0", "1", 1, "NaN", "NaN", Equal);
+    test("0", "-1", 1, "NaN", "NaN", Equal);
+    test("0", "1/3", 1, "NaN", "NaN", Equal);
+    test("0", "5/2", 1, "NaN", "NaN", Equal);
+    test("0", "100", 1, "NaN", "NaN", Equal);
+    test("1", "0", 1, "0.0", "0x0.0", Equal);
+    test("1", "0", 10, "0.0", "0x0.0", Equal);
+    test("1", "0", 100, "0.0", "0x0.0", Equal);
+    test("1", "1", 1, "0.50", "0x0.8#1", Less);
+    test("1", "1", 10, "0.63184", "0x0.a1c#10", Less);
+    test(
+        "1",
+        "1",
+        100,
+        "0.63212055882855767840447622983856",
+        "0x0.a1d2a7274c4320e54521387d7#100",
+        Greater,
+    );
+    test("1", "-1", 1, "NaN", "NaN", Equal);
+    test("1", "1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1", "1/3", 10, "0.28369", "0x0.48a#10", Greater);
+    test(
+        "1",
+        "1/3",
+        100,
+        "0.28346868942621074957439590307467",
+        "0x0.4891676e868ad9763a67b363b0#100",
+        Greater,
+    );
+    test("1", "5/2", 1, "1.0", "0x1.0#1", Greater);
+    test("1", "5/2", 10, "0.91797", "0x0.eb0#10", Greater);
+    test(
+        "1",
+        "5/2",
+        100,
+        "0.91791500137610120483047132553319",
+        "0x0.eafc7a3f6b0bdb58a1b7cb543#100",
+        Greater,
+    );
+    test("1", "100", 1, "1.0", "0x1.0#1", Greater);
+    test("1", "100", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "1",
+        "100",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-1", "0", 1, "NaN", "NaN", Equal);
+    test("-1", "1", 1, "NaN", "NaN", Equal);
+    test("-1", "-1", 1, "NaN", "NaN", Equal);
+    test("-1", "1/3", 1, "NaN", "NaN", Equal);
+    test("-1", "5/2", 1, "NaN", "NaN", Equal);
+    test("-1", "100", 1, "NaN", "NaN", Equal);
+    test("1/2", "0", 1, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 10, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 100, "0.0", "0x0.0", Equal);
+    test("1/2", "1", 1, "1.0", "0x1.0#1", Less);
+    test("1/2", "1", 10, "1.4941", "0x1.7e8#10", Greater);
+    test(
+        "1/2",
+        "1",
+        100,
+        "1.4936482656248540507989348722634",
+        "0x1.7e5fbb9495fb8553accd243a8#100",
+        Less,
+    );
+    test("1/2", "-1", 1, "NaN", "NaN", Equal);
+    test("1/2", "1/3", 1, "1.0", "0x1.0#1", Less);
+    test("1/2", "1/3", 10, "1.0391", "0x1.0a0#10", Greater);
+    test(
+        "1/2",
+        "1/3",
+        100,
+        "1.0382747906721867624018320981025",
+        "0x1.09cc606e32c300a0dbcb11c5e#100",
+        Greater,
+    );
+    test("1/2", "5/2", 1, "2.0", "0x2.0#1", Greater);
+    test("1/2", "5/2", 10, "1.7266", "0x1.ba0#10", Less);
+    test(
+        "1/2",
+        "5/2",
+        100,
+        "1.7275268983055080913282986730507",
+        "0x1.ba3f33eb2eb6afa55708be73e#100",
+        Less,
+    );
+    test("1/2", "100", 1, "2.0", "0x2.0#1", Greater);
+    test("1/2", "100", 10, "1.7715", "0x1.c58#10", Less);
+    test(
+        "1/2",
+        "100",
+        100,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("1/3", "0", 1, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 10, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 100, "0.0", "0x0.0", Equal);
+    test("1/3", "1", 1, "2.0", "0x2.0#1", Less);
+    test("1/3", "1", 10, "2.4219", "0x2.6c#10", Less);
+    test(
+        "1/3",
+        "1",
+        100,
+        "2.4225335464190143585749995501432",
+        "0x2.6c2b28935522f3d6389981c1c#100",
+        Greater,
+    );
+    test("1/3", "-1", 1, "NaN", "NaN", Equal);
+    test("1/3", "1/3", 1, "2.0", "0x2.0#1", Greater);
+    test("1/3", "1/3", 10, "1.9219", "0x1.ec0#10", Less);
+    test(
+        "1/3",
+        "1/3",
+        100,
+        "1.9220461536805955249331524766221",
+        "0x1.ec0b377b75f391850aa6a1472#100",
+        Greater,
+    );
+    test("1/3", "5/2", 1, "2.0", "0x2.0#1", Less);
+    test("1/3", "5/2", 10, "2.6406", "0x2.a4#10", Less);
+    test(
+        "1/3",
+        "5/2",
+        100,
+        "2.6420780137892211533385816695629",
+        "0x2.a45f3986b4914941eabb1ec6c#100",
+        Greater,
+    );
+    test("1/3", "100", 1, "2.0", "0x2.0#1", Less);
+    test("1/3", "100", 10, "2.6797", "0x2.ae#10", Greater);
+    test(
+        "1/3",
+        "100",
+        100,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Less,
+    );
+    test("3", "0", 1, "0.0", "0x0.0", Equal);
+    test("3", "0", 10, "0.0", "0x0.0", Equal);
+    test("3", "0", 100, "0.0", "0x0.0", Equal);
+    test("3", "1", 1, "0.12", "0x0.2#1", Less);
+    test("3", "1", 10, "0.16064", "0x0.292#10", Greater);
+    test(
+        "3",
+        "1",
+        100,
+        "0.16060279414278839202238114919278",
+        "0x0.291d43c47d4fa47a59a61a7330#100",
+        Greater,
+    );
+    test("3", "-1", 1, "NaN", "NaN", Equal);
+    test("3", "1/3", 1, "0.0078", "0x0.02#1", Less);
+    test("3", "1/3", 10, "0.0096283", "0x0.0277#10", Less);
+    test(
+        "3",
+        "1/3",
+        100,
+        "0.0096352484061409710399886196517288",
+        "0x0.027774a4cb0fea486958f24dca0#100",
+        Greater,
+    );
+    test("3", "5/2", 1, "1.0", "0x1.0#1", Greater);
+    test("3", "5/2", 10, "0.91211", "0x0.e98#10", Less);
+    test(
+        "3",
+        "5/2",
+        100,
+        "0.91237376823334096400374506331018",
+        "0x0.e99153c84a5d1a565ec3461b2#100",
+        Greater,
+    );
+    test("3", "100", 1, "2.0", "0x2.0#1", Greater);
+    test("3", "100", 10, "2.0000", "0x2.00#10", Greater);
+    test(
+        "3",
+        "100",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-7/2", "0", 1, "NaN", "NaN", Equal);
+    test("-7/2", "1", 1, "NaN", "NaN", Equal);
+    test("-7/2", "-1", 1, "NaN", "NaN", Equal);
+    test("-7/2", "1/3", 1, "NaN", "NaN", Equal);
+    test("-7/2", "5/2", 1, "NaN", "NaN", Equal);
+    test("-7/2", "100", 1, "NaN", "NaN", Equal);
+    test("22/7", "0", 1, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 10, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 100, "0.0", "0x0.0", Equal);
+    test("22/7", "1", 1, "0.12", "0x0.2#1", Less);
+    test("22/7", "1", 10, "0.15186", "0x0.26e#10", Greater);
+    test(
+        "22/7",
+        "1",
+        100,
+        "0.15183725470638779397011641564508",
+        "0x0.26dece6b4742728b1a8cd11b20#100",
+        Less,
+    );
+    test("22/7", "-1", 1, "NaN", "NaN", Equal);
+    test("22/7", "1/3", 1, "0.0078", "0x0.02#1", Less);
+    test("22/7", "1/3", 10, "0.0078430", "0x0.0202#10", Greater);
+    test(
+        "22/7",
+        "1/3",
+        100,
+        "0.0078379956600271292194774073716511",
+        "0x0.0201abbf06a85be76f749e7f6b0#100",
+        Less,
+    );
+    test("22/7", "5/2", 1, "1.0", "0x1.0#1", Greater);
+    test("22/7", "5/2", 10, "0.96582", "0x0.f74#10", Greater);
+    test(
+        "22/7",
+        "5/2",
+        100,
+        "0.96540657014592551529053222355758",
+        "0x0.f724e28e1ecaac243aa9e67b4#100",
+        Greater,
+    );
+    test("22/7", "100", 1, "2.0", "0x2.0#1", Less);
+    test("22/7", "100", 10, "2.2891", "0x2.4a#10", Less);
+    test(
+        "22/7",
+        "100",
+        100,
+        "2.2908675009613379957017236207921",
+        "0x2.4a764ae4192352f3b59fbc0cc#100",
+        Greater,
+    );
+}
+
+#[test]
+fn lower_incomplete_gamma_rational_prec_fail() {
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec(
+        Rational::ONE,
+        Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec_val_ref(
+        Rational::ONE,
+        &Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec_ref_val(
+        &Rational::ONE,
+        Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec_ref_ref(
+        &Rational::ONE,
+        &Rational::ONE,
+        0
+    ));
+}
+
+#[test]
+fn test_lower_incomplete_gamma_rational_prec_round() {
+    let test = |s, x, prec, rm, out: &str, out_hex: &str, out_o| {
+        let s = Rational::from_str(s).unwrap();
+        let x = Rational::from_str(x).unwrap();
+
+        let (gamma, o) =
+            Float::lower_incomplete_gamma_rational_prec_round(s.clone(), x.clone(), prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) =
+            Float::lower_incomplete_gamma_rational_prec_round_val_ref(s.clone(), &x, prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) =
+            Float::lower_incomplete_gamma_rational_prec_round_ref_val(&s, x.clone(), prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) =
+            Float::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        if rug_lower_incomplete_gamma_rational_applies(&s, &x)
+            && let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+        {
+            let (rug_gamma, rug_o) =
+                rug_lower_incomplete_gamma_rational_prec_round(&s, &x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "1", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "1/3", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "5/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "100", 1, Nearest, "NaN", "NaN", Equal);
+    test("1", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1", "0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1", "0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1", "0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1", "0", 10, Down, "0.0", "0x0.0", Equal);
+    test("1", "0", 10, Up, "0.0", "0x0.0", Equal);
+    test("1", "0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1", "0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1", "0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", "0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("1", "1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1", "1", 10, Floor, "0.63184", "0x0.a1c#10", Less);
+    test("1", "1", 10, Ceiling, "0.63281", "0x0.a20#10", Greater);
+    test("1", "1", 10, Down, "0.63184", "0x0.a1c#10", Less);
+    test("1", "1", 10, Up, "0.63281", "0x0.a20#10", Greater);
+    test("1", "1", 10, Nearest, "0.63184", "0x0.a1c#10", Less);
+    test(
+        "1",
+        "1",
+        100,
+        Nearest,
+        "0.63212055882855767840447622983856",
+        "0x0.a1d2a7274c4320e54521387d7#100",
+        Greater,
+    );
+    test("1", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("1", "1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1", "1/3", 10, Floor, "0.28320", "0x0.488#10", Less);
+    test("1", "1/3", 10, Ceiling, "0.28369", "0x0.48a#10", Greater);
+    test("1", "1/3", 10, Down, "0.28320", "0x0.488#10", Less);
+    test("1", "1/3", 10, Up, "0.28369", "0x0.48a#10", Greater);
+    test("1", "1/3", 10, Nearest, "0.28369", "0x0.48a#10", Greater);
+    test(
+        "1",
+        "1/3",
+        100,
+        Nearest,
+        "0.28346868942621074957439590307467",
+        "0x0.4891676e868ad9763a67b363b0#100",
+        Greater,
+    );
+    test("1", "5/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", "5/2", 10, Floor, "0.91699", "0x0.eac#10", Less);
+    test("1", "5/2", 10, Ceiling, "0.91797", "0x0.eb0#10", Greater);
+    test("1", "5/2", 10, Down, "0.91699", "0x0.eac#10", Less);
+    test("1", "5/2", 10, Up, "0.91797", "0x0.eb0#10", Greater);
+    test("1", "5/2", 10, Nearest, "0.91797", "0x0.eb0#10", Greater);
+    test(
+        "1",
+        "5/2",
+        100,
+        Nearest,
+        "0.91791500137610120483047132553319",
+        "0x0.eafc7a3f6b0bdb58a1b7cb543#100",
+        Greater,
+    );
+    test("1", "100", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", "100", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test("1", "100", 10, Ceiling, "1.0000", "0x1.000#10", Greater);
+    test("1", "100", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("1", "100", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test("1", "100", 10, Nearest, "1.0000", "0x1.000#10", Greater);
+    test(
+        "1",
+        "100",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-1", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", "1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", "1/3", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", "5/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", "100", 1, Nearest, "NaN", "NaN", Equal);
+    test("1/2", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 10, Down, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 10, Up, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1/2", "0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("1/2", "1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1/2", "1", 10, Floor, "1.4922", "0x1.7e0#10", Less);
+    test("1/2", "1", 10, Ceiling, "1.4941", "0x1.7e8#10", Greater);
+    test("1/2", "1", 10, Down, "1.4922", "0x1.7e0#10", Less);
+    test("1/2", "1", 10, Up, "1.4941", "0x1.7e8#10", Greater);
+    test("1/2", "1", 10, Nearest, "1.4941", "0x1.7e8#10", Greater);
+    test(
+        "1/2",
+        "1",
+        100,
+        Nearest,
+        "1.4936482656248540507989348722634",
+        "0x1.7e5fbb9495fb8553accd243a8#100",
+        Less,
+    );
+    test("1/2", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("1/2", "1/3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("1/2", "1/3", 10, Floor, "1.0371", "0x1.098#10", Less);
+    test("1/2", "1/3", 10, Ceiling, "1.0391", "0x1.0a0#10", Greater);
+    test("1/2", "1/3", 10, Down, "1.0371", "0x1.098#10", Less);
+    test("1/2", "1/3", 10, Up, "1.0391", "0x1.0a0#10", Greater);
+    test("1/2", "1/3", 10, Nearest, "1.0391", "0x1.0a0#10", Greater);
+    test(
+        "1/2",
+        "1/3",
+        100,
+        Nearest,
+        "1.0382747906721867624018320981025",
+        "0x1.09cc606e32c300a0dbcb11c5e#100",
+        Greater,
+    );
+    test("1/2", "5/2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1/2", "5/2", 10, Floor, "1.7266", "0x1.ba0#10", Less);
+    test("1/2", "5/2", 10, Ceiling, "1.7285", "0x1.ba8#10", Greater);
+    test("1/2", "5/2", 10, Down, "1.7266", "0x1.ba0#10", Less);
+    test("1/2", "5/2", 10, Up, "1.7285", "0x1.ba8#10", Greater);
+    test("1/2", "5/2", 10, Nearest, "1.7266", "0x1.ba0#10", Less);
+    test(
+        "1/2",
+        "5/2",
+        100,
+        Nearest,
+        "1.7275268983055080913282986730507",
+        "0x1.ba3f33eb2eb6afa55708be73e#100",
+        Less,
+    );
+    test("1/2", "100", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1/2", "100", 10, Floor, "1.7715", "0x1.c58#10", Less);
+    test("1/2", "100", 10, Ceiling, "1.7734", "0x1.c60#10", Greater);
+    test("1/2", "100", 10, Down, "1.7715", "0x1.c58#10", Less);
+    test("1/2", "100", 10, Up, "1.7734", "0x1.c60#10", Greater);
+    test("1/2", "100", 10, Nearest, "1.7715", "0x1.c58#10", Less);
+    test(
+        "1/2",
+        "100",
+        100,
+        Nearest,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("1/3", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 10, Down, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 10, Up, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1/3", "0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("1/3", "1", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/3", "1", 10, Floor, "2.4219", "0x2.6c#10", Less);
+    test("1/3", "1", 10, Ceiling, "2.4258", "0x2.6d#10", Greater);
+    test("1/3", "1", 10, Down, "2.4219", "0x2.6c#10", Less);
+    test("1/3", "1", 10, Up, "2.4258", "0x2.6d#10", Greater);
+    test("1/3", "1", 10, Nearest, "2.4219", "0x2.6c#10", Less);
+    test(
+        "1/3",
+        "1",
+        100,
+        Nearest,
+        "2.4225335464190143585749995501432",
+        "0x2.6c2b28935522f3d6389981c1c#100",
+        Greater,
+    );
+    test("1/3", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("1/3", "1/3", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1/3", "1/3", 10, Floor, "1.9219", "0x1.ec0#10", Less);
+    test("1/3", "1/3", 10, Ceiling, "1.9238", "0x1.ec8#10", Greater);
+    test("1/3", "1/3", 10, Down, "1.9219", "0x1.ec0#10", Less);
+    test("1/3", "1/3", 10, Up, "1.9238", "0x1.ec8#10", Greater);
+    test("1/3", "1/3", 10, Nearest, "1.9219", "0x1.ec0#10", Less);
+    test(
+        "1/3",
+        "1/3",
+        100,
+        Nearest,
+        "1.9220461536805955249331524766221",
+        "0x1.ec0b377b75f391850aa6a1472#100",
+        Greater,
+    );
+    test("1/3", "5/2", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/3", "5/2", 10, Floor, "2.6406", "0x2.a4#10", Less);
+    test("1/3", "5/2", 10, Ceiling, "2.6445", "0x2.a5#10", Greater);
+    test("1/3", "5/2", 10, Down, "2.6406", "0x2.a4#10", Less);
+    test("1/3", "5/2", 10, Up, "2.6445", "0x2.a5#10", Greater);
+    test("1/3", "5/2", 10, Nearest, "2.6406", "0x2.a4#10", Less);
+    test(
+        "1/3",
+        "5/2",
+        100,
+        Nearest,
+        "2.6420780137892211533385816695629",
+        "0x2.a45f3986b4914941eabb1ec6c#100",
+        Greater,
+    );
+    test("1/3", "100", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/3", "100", 10, Floor, "2.6758", "0x2.ad#10", Less);
+    test("1/3", "100", 10, Ceiling, "2.6797", "0x2.ae#10", Greater);
+    test("1/3", "100", 10, Down, "2.6758", "0x2.ad#10", Less);
+    test("1/3", "100", 10, Up, "2.6797", "0x2.ae#10", Greater);
+    test("1/3", "100", 10, Nearest, "2.6797", "0x2.ae#10", Greater);
+    test(
+        "1/3",
+        "100",
+        100,
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Less,
+    );
+    test("3", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("3", "0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("3", "0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("3", "0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("3", "0", 10, Down, "0.0", "0x0.0", Equal);
+    test("3", "0", 10, Up, "0.0", "0x0.0", Equal);
+    test("3", "0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("3", "0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("3", "0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("3", "0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("3", "1", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("3", "1", 10, Floor, "0.16040", "0x0.291#10", Less);
+    test("3", "1", 10, Ceiling, "0.16064", "0x0.292#10", Greater);
+    test("3", "1", 10, Down, "0.16040", "0x0.291#10", Less);
+    test("3", "1", 10, Up, "0.16064", "0x0.292#10", Greater);
+    test("3", "1", 10, Nearest, "0.16064", "0x0.292#10", Greater);
+    test(
+        "3",
+        "1",
+        100,
+        Nearest,
+        "0.16060279414278839202238114919278",
+        "0x0.291d43c47d4fa47a59a61a7330#100",
+        Greater,
+    );
+    test("3", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("3", "1/3", 1, Nearest, "0.0078", "0x0.02#1", Less);
+    test("3", "1/3", 10, Floor, "0.0096283", "0x0.0277#10", Less);
+    test("3", "1/3", 10, Ceiling, "0.0096436", "0x0.0278#10", Greater);
+    test("3", "1/3", 10, Down, "0.0096283", "0x0.0277#10", Less);
+    test("3", "1/3", 10, Up, "0.0096436", "0x0.0278#10", Greater);
+    test("3", "1/3", 10, Nearest, "0.0096283", "0x0.0277#10", Less);
+    test(
+        "3",
+        "1/3",
+        100,
+        Nearest,
+        "0.0096352484061409710399886196517288",
+        "0x0.027774a4cb0fea486958f24dca0#100",
+        Greater,
+    );
+    test("3", "5/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("3", "5/2", 10, Floor, "0.91211", "0x0.e98#10", Less);
+    test("3", "5/2", 10, Ceiling, "0.91309", "0x0.e9c#10", Greater);
+    test("3", "5/2", 10, Down, "0.91211", "0x0.e98#10", Less);
+    test("3", "5/2", 10, Up, "0.91309", "0x0.e9c#10", Greater);
+    test("3", "5/2", 10, Nearest, "0.91211", "0x0.e98#10", Less);
+    test(
+        "3",
+        "5/2",
+        100,
+        Nearest,
+        "0.91237376823334096400374506331018",
+        "0x0.e99153c84a5d1a565ec3461b2#100",
+        Greater,
+    );
+    test("3", "100", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("3", "100", 10, Floor, "1.9980", "0x1.ff8#10", Less);
+    test("3", "100", 10, Ceiling, "2.0000", "0x2.00#10", Greater);
+    test("3", "100", 10, Down, "1.9980", "0x1.ff8#10", Less);
+    test("3", "100", 10, Up, "2.0000", "0x2.00#10", Greater);
+    test("3", "100", 10, Nearest, "2.0000", "0x2.00#10", Greater);
+    test(
+        "3",
+        "100",
+        100,
+        Nearest,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-7/2", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", "1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", "1/3", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", "5/2", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", "100", 1, Nearest, "NaN", "NaN", Equal);
+    test("22/7", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 10, Down, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 10, Up, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("22/7", "0", 100, Exact, "0.0", "0x0.0", Equal);
+    test("22/7", "1", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("22/7", "1", 10, Floor, "0.15161", "0x0.26d#10", Less);
+    test("22/7", "1", 10, Ceiling, "0.15186", "0x0.26e#10", Greater);
+    test("22/7", "1", 10, Down, "0.15161", "0x0.26d#10", Less);
+    test("22/7", "1", 10, Up, "0.15186", "0x0.26e#10", Greater);
+    test("22/7", "1", 10, Nearest, "0.15186", "0x0.26e#10", Greater);
+    test(
+        "22/7",
+        "1",
+        100,
+        Nearest,
+        "0.15183725470638779397011641564508",
+        "0x0.26dece6b4742728b1a8cd11b20#100",
+        Less,
+    );
+    test("22/7", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("22/7", "1/3", 1, Nearest, "0.0078", "0x0.02#1", Less);
+    test("22/7", "1/3", 10, Floor, "0.0078278", "0x0.0201#10", Less);
+    test(
+        "22/7",
+        "1/3",
+        10,
+        Ceiling,
+        "0.0078430",
+        "0x0.0202#10",
+        Greater,
+    );
+    test("22/7", "1/3", 10, Down, "0.0078278", "0x0.0201#10", Less);
+    test("22/7", "1/3", 10, Up, "0.0078430", "0x0.0202#10", Greater);
+    test(
+        "22/7",
+        "1/3",
+        10,
+        Nearest,
+        "0.0078430",
+        "0x0.0202#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        "1/3",
+        100,
+        Nearest,
+        "0.0078379956600271292194774073716511",
+        "0x0.0201abbf06a85be76f749e7f6b0#100",
+        Less,
+    );
+    test("22/7", "5/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("22/7", "5/2", 10, Floor, "0.96484", "0x0.f70#10", Less);
+    test("22/7", "5/2", 10, Ceiling, "0.96582", "0x0.f74#10", Greater);
+    test("22/7", "5/2", 10, Down, "0.96484", "0x0.f70#10", Less);
+    test("22/7", "5/2", 10, Up, "0.96582", "0x0.f74#10", Greater);
+    test("22/7", "5/2", 10, Nearest, "0.96582", "0x0.f74#10", Greater);
+    test(
+        "22/7",
+        "5/2",
+        100,
+        Nearest,
+        "0.96540657014592551529053222355758",
+        "0x0.f724e28e1ecaac243aa9e67b4#100",
+        Greater,
+    );
+    test("22/7", "100", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("22/7", "100", 10, Floor, "2.2891", "0x2.4a#10", Less);
+    test("22/7", "100", 10, Ceiling, "2.2930", "0x2.4b#10", Greater);
+    test("22/7", "100", 10, Down, "2.2891", "0x2.4a#10", Less);
+    test("22/7", "100", 10, Up, "2.2930", "0x2.4b#10", Greater);
+    test("22/7", "100", 10, Nearest, "2.2891", "0x2.4a#10", Less);
+    test(
+        "22/7",
+        "100",
+        100,
+        Nearest,
+        "2.2908675009613379957017236207921",
+        "0x2.4a764ae4192352f3b59fbc0cc#100",
+        Greater,
+    );
+}
+
+#[test]
+fn lower_incomplete_gamma_rational_prec_round_fail() {
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec_round(
+        Rational::ONE,
+        Rational::ONE,
+        0,
+        Floor
+    ));
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+        &Rational::ONE,
+        &Rational::ONE,
+        0,
+        Floor
+    ));
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec_round(
+        Rational::ONE_HALF,
+        Rational::ONE_HALF,
+        10,
+        Exact
+    ));
+    assert_panic!(Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+        &Rational::ONE_HALF,
+        &Rational::ONE_HALF,
+        10,
+        Exact
+    ));
+}
+
+#[test]
+fn lower_incomplete_gamma_rational_prec_round_properties() {
+    rational_rational_unsigned_rounding_mode_quadruple_gen_var_6().test_properties(
+        |(s, x, prec, rm)| {
+            let (gamma, o) =
+                Float::lower_incomplete_gamma_rational_prec_round(s.clone(), x.clone(), prec, rm);
+            assert!(gamma.is_valid());
+            let (gamma_alt, o_alt) =
+                Float::lower_incomplete_gamma_rational_prec_round_val_ref(s.clone(), &x, prec, rm);
+            assert!(gamma_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+            assert_eq!(o_alt, o);
+            let (gamma_alt, o_alt) =
+                Float::lower_incomplete_gamma_rational_prec_round_ref_val(&s, x.clone(), prec, rm);
+            assert!(gamma_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+            assert_eq!(o_alt, o);
+            let (gamma_alt, o_alt) =
+                Float::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm);
+            assert!(gamma_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+            assert_eq!(o_alt, o);
+
+            if rug_lower_incomplete_gamma_rational_applies(&s, &x)
+                && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+            {
+                let (rug_gamma, rug_o) =
+                    rug_lower_incomplete_gamma_rational_prec_round(&s, &x, prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_gamma)),
+                    ComparableFloatRef(&gamma)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if gamma.is_normal() {
+                assert_eq!(gamma.get_prec(), Some(prec));
+            }
+            if x < 0u32 {
+                assert!(gamma.is_nan());
+            }
+
+            if let Ok(s_alt) = Float::try_from(&s)
+                && let Ok(x_alt) = Float::try_from(&x)
+            {
+                let (gamma_alt, o_alt) = s_alt.lower_incomplete_gamma_prec_round(x_alt, prec, rm);
+                assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+                assert_eq!(o_alt, o);
+            }
+
+            if o == Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (g, oo) =
+                        Float::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm);
+                    assert_eq!(ComparableFloat(g), ComparableFloat(gamma.clone()));
+                    assert_eq!(oo, Equal);
+                }
+            } else {
+                assert_panic!(Float::lower_incomplete_gamma_rational_prec_round_ref_ref(
+                    &s, &x, prec, Exact
+                ));
+            }
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        lower_incomplete_gamma_rational_special_values_properties(prec, rm);
+    });
+}
+
+#[test]
+fn lower_incomplete_gamma_rational_prec_properties() {
+    rational_rational_unsigned_triple_gen_var_2().test_properties(|(s, x, prec)| {
+        let (gamma, o) = Float::lower_incomplete_gamma_rational_prec(s.clone(), x.clone(), prec);
+        assert!(gamma.is_valid());
+        let (gamma_alt, o_alt) =
+            Float::lower_incomplete_gamma_rational_prec_val_ref(s.clone(), &x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) =
+            Float::lower_incomplete_gamma_rational_prec_ref_val(&s, x.clone(), prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = Float::lower_incomplete_gamma_rational_prec_ref_ref(&s, &x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+
+        let (gamma_alt, o_alt) =
+            Float::lower_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, Nearest);
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+
+        if rug_lower_incomplete_gamma_rational_applies(&s, &x) {
+            let (rug_gamma, rug_o) = rug_lower_incomplete_gamma_rational_prec(&s, &x, prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_lower_incomplete_gamma_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, x: &str, out: T)
+    where
+        Float: PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let s = Rational::from_str(s).unwrap();
+        let x = Rational::from_str(x).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_lower_incomplete_gamma_rational::<T>(&s, &x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", "1", f32::NAN);
+    test::<f32>("1", "0", 0.0);
+    test::<f32>("1", "1", 0.63212055);
+    test::<f32>("1/2", "1/2", 1.2100356);
+    test::<f32>("1/3", "5/2", 2.642078);
+    test::<f32>("3", "7/2", 1.3583056);
+    test::<f32>("-7/2", "3", f32::NAN);
+    test::<f32>("-1", "1/3", f32::NAN);
+    test::<f32>("22/7", "100", 2.2908676);
+    test::<f32>("1", "-1", f32::NAN);
+
+    test::<f64>("0", "1", f64::NAN);
+    test::<f64>("1", "0", 0.0);
+    test::<f64>("1", "1", 0.6321205588285577);
+    test::<f64>("1/2", "1/2", 1.2100356193111088);
+    test::<f64>("1/3", "5/2", 2.642078013789221);
+    test::<f64>("3", "7/2", 1.3583056022757318);
+    test::<f64>("-7/2", "3", f64::NAN);
+    test::<f64>("-1", "1/3", f64::NAN);
+    test::<f64>("22/7", "100", 2.290867500961338);
+    test::<f64>("1", "-1", f64::NAN);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_lower_incomplete_gamma_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_pair_gen().test_properties(|(s, x)| {
+        let gamma = primitive_float_lower_incomplete_gamma_rational::<T>(&s, &x);
+        if x < 0u32 {
+            assert!(gamma.is_nan());
+        } else if x > 0u32 && !gamma.is_nan() {
+            assert!(gamma.is_sign_positive());
+        }
+    });
+
+    primitive_float_pair_gen::<T>().test_properties(|(s, x)| {
+        if s.is_finite() && x.is_finite() && s != T::ZERO && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_lower_incomplete_gamma_rational::<T>(
+                    &Rational::exact_from(s),
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_lower_incomplete_gamma(s, x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_lower_incomplete_gamma_rational_properties() {
+    apply_fn_to_primitive_floats!(
+        primitive_float_lower_incomplete_gamma_rational_properties_helper
+    );
+}
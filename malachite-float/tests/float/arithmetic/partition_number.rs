@@ -0,0 +1,149 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::PartitionNumber;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_float::float::arithmetic::partition_number::partition_number_hrr;
+use malachite_float::test_util::common::to_hex_string;
+use malachite_float::test_util::generators::unsigned_unsigned_rounding_mode_triple_gen_var_11;
+use malachite_float::{ComparableFloatRef, Float};
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::partition_number::partition_numbers_prefix;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_partition_number_hrr() {
+    let test = |n: u64, out: &str| {
+        let p = partition_number_hrr(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(Natural::partition_number(n), p);
+    };
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(5, "7");
+    test(10, "42");
+    test(100, "190569292");
+    test(1000, "24061467864032622473692149727991");
+    test(1459, "348259884310914705271679879631949049780");
+    test(2000, "4720819175619413888601432406799959512200344166");
+}
+
+#[test]
+fn partition_number_hrr_agrees_with_pentagonal() {
+    // - every n up to 300, where the Rademacher series needs only a few terms
+    // - a sparse sample beyond the u128 range, where Natural::partition_number switches strategies
+    let prefix = partition_numbers_prefix(5001);
+    for n in (0..=300)
+        .chain((1400..=1500).step_by(7))
+        .chain([3000, 4096, 5000])
+    {
+        assert_eq!(partition_number_hrr(n), prefix[n as usize], "n = {n}");
+    }
+}
+
+#[test]
+fn test_partition_number() {
+    let test = |n: u64, prec, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let (f, o) = Float::partition_number_prec_round(n, prec, rm);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(to_hex_string(&f), out_hex);
+        assert_eq!(o, o_out);
+
+        let (f_alt, o_alt) = Float::from_natural_prec_round(Natural::partition_number(n), prec, rm);
+        assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+        assert_eq!(o_alt, o);
+
+        if rm == Nearest {
+            let (f_alt, o_alt) = Float::partition_number_prec(n, prec);
+            assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test(0, 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test(1, 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test(10, 10, Exact, "42.000", "0x2a.0#10", Equal);
+    test(10, 3, Floor, "40.0", "0x28.0#3", Less);
+    test(10, 3, Ceiling, "48.0", "0x30.0#3", Greater);
+    test(10, 3, Nearest, "40.0", "0x28.0#3", Less);
+    test(100, 10, Down, "1.9032e8", "0xb.58E+6#10", Less);
+    test(100, 10, Up, "1.9058e8", "0xb.5cE+6#10", Greater);
+    test(100, 10, Nearest, "1.9058e8", "0xb.5cE+6#10", Greater);
+    test(1000, 20, Floor, "2.4061454e31", "0x1.2fb2cE+26#20", Less);
+    test(
+        1000,
+        20,
+        Ceiling,
+        "2.4061492e31",
+        "0x1.2fb2eE+26#20",
+        Greater,
+    );
+    // - beyond the u128 range, where the Hardy–Ramanujan–Rademacher sum is used
+    test(
+        2000,
+        100,
+        Nearest,
+        "4.7208191756194138886014324068019e45",
+        "0xd.3b058ae344a18b753cc2a1f0E+37#100",
+        Greater,
+    );
+    test(2000, 10, Floor, "4.7166e45", "0xd.38E+37#10", Less);
+    test(
+        10000,
+        30,
+        Nearest,
+        "3.6167251312e106",
+        "0x3.f13ef39E+88#30",
+        Less,
+    );
+}
+
+#[test]
+fn partition_number_fail() {
+    assert_panic!(Float::partition_number_prec_round(5, 0, Nearest));
+    assert_panic!(Float::partition_number_prec(5, 0));
+    assert_panic!(Float::partition_number_prec_round(10, 3, Exact));
+}
+
+#[test]
+fn partition_number_prec_round_properties() {
+    unsigned_unsigned_rounding_mode_triple_gen_var_11().test_properties(|(n, prec, rm)| {
+        if rm == Exact {
+            return;
+        }
+        let (f, o) = Float::partition_number_prec_round(n, prec, rm);
+        assert!(f.is_valid());
+        assert_eq!(f.get_prec(), Some(prec));
+        assert!(f > 0u32);
+
+        let (f_alt, o_alt) = Float::from_natural_prec_round(Natural::partition_number(n), prec, rm);
+        assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+        assert_eq!(o_alt, o);
+
+        if rm == Nearest {
+            let (f_alt, o_alt) = Float::partition_number_prec(n, prec);
+            assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+            assert_eq!(o_alt, o);
+        }
+
+        if o == Equal {
+            for rm2 in exhaustive_rounding_modes() {
+                let (f_alt, o_alt) = Float::partition_number_prec_round(n, prec, rm2);
+                assert_eq!(ComparableFloatRef(&f_alt), ComparableFloatRef(&f));
+                assert_eq!(o_alt, Equal);
+            }
+        } else {
+            assert_panic!(Float::partition_number_prec_round(n, prec, Exact));
+        }
+    });
+}
@@ -66,7 +66,7 @@ const fn mpfr_rnd(rm: RoundingMode) -> rnd_t {
         Down => rnd_t::RNDZ,
         Up => rnd_t::RNDA,
         Nearest => rnd_t::RNDN,
-        Exact => panic!(),
+        NearestTiesAway | Odd | Exact => panic!(),
     }
 }
 
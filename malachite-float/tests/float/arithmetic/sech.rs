@@ -0,0 +1,2844 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Sech, SechAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::sech::{
+    primitive_float_sech, primitive_float_sech_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::sech::{
+    rug_sech, rug_sech_prec, rug_sech_prec_round, rug_sech_rational_prec,
+    rug_sech_rational_prec_round, rug_sech_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sech() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sech = x.clone().sech();
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+
+        let sech_alt = (&x).sech();
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+
+        let mut sech_alt = x.clone();
+        sech_alt.sech_assign();
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sech(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&sech)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "0.50", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "0.50", "0x0.8#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+    );
+    test("123.0", "0x7b.0#7", "7.667e-54", "0xb.cE-45#7");
+    test("-123.0", "-0x7b.0#7", "7.667e-54", "0xb.cE-45#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.086266738334054405",
+        "0x0.161593b3e09a38#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.13140388387565735",
+        "0x0.21a3af57d03666#53",
+    );
+    test("355.0", "0x163.0#9", "1.337e-154", "0x1.cbE-128#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "0.0",
+        "0x0.0",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+    );
+}
+
+#[test]
+fn test_sech_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sech, o) = x.clone().sech_prec(prec);
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sech_alt, o_alt) = x.sech_prec_ref(prec);
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sech_alt = x.clone();
+        let o_alt = sech_alt.sech_prec_assign(prec);
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sech, rug_o) = rug_sech_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sech)),
+            ComparableFloatRef(&sech)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 10, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 100, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 100, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "5.2e-54", "0x8.0E-45#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "5.2e-54", "0x8.0E-45#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.086304",
+        "0x0.1618#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.086266738334054425221798413905217",
+        "0x0.161593b3e09a397a1c92696e3a#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.13135",
+        "0x0.21a#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.13140388387565735191886911224506",
+        "0x0.21a3af57d036660ba413877288#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, "1.5e-154", "0x2.0E-128#1", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "1.3381010762532298264025680134556e-154",
+        "0x1.cb4a26f3c63f58179849f6458E-128#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.94727",
+        "0x0.f28#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sech_prec_fail() {
+    assert_panic!(Float::NAN.sech_prec(0));
+    assert_panic!(Float::NAN.sech_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.sech_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_sech_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sech, o) = x.clone().sech_round(rm);
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sech_alt, o_alt) = x.sech_round_ref(rm);
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sech_alt = x.clone();
+        let o_alt = sech_alt.sech_round_assign(rm);
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sech, rug_o) = rug_sech_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sech)),
+                ComparableFloatRef(&sech),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "0.50", "0x0.8#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.64805427366388539957497735322564",
+        "0x0.a5e6e2876b5f3b99e515f5ceb#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.64805427366388539957497735322564",
+        "0x0.a5e6e2876b5f3b99e515f5ceb#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "7.586e-54", "0xb.aE-45#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "7.667e-54",
+        "0xb.cE-45#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "7.586e-54", "0xb.aE-45#7", Less);
+    test("123.0", "0x7b.0#7", Up, "7.667e-54", "0xb.cE-45#7", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "7.667e-54",
+        "0xb.cE-45#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "7.586e-54",
+        "0xb.aE-45#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "7.667e-54",
+        "0xb.cE-45#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "7.586e-54",
+        "0xb.aE-45#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "7.667e-54",
+        "0xb.cE-45#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "7.667e-54",
+        "0xb.cE-45#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.086266738334054405",
+        "0x0.161593b3e09a38#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.086266738334054516",
+        "0x0.161593b3e09a40#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.086266738334054405",
+        "0x0.161593b3e09a38#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.086266738334054516",
+        "0x0.161593b3e09a40#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.086266738334054405",
+        "0x0.161593b3e09a38#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.13140388387565735",
+        "0x0.21a3af57d03666#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.13140388387565738",
+        "0x0.21a3af57d03668#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.13140388387565735",
+        "0x0.21a3af57d03666#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.13140388387565738",
+        "0x0.21a3af57d03668#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.13140388387565735",
+        "0x0.21a3af57d03666#53",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "1.337e-154",
+        "0x1.cbE-128#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "1.340e-154",
+        "0x1.ccE-128#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "1.337e-154",
+        "0x1.cbE-128#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "1.340e-154",
+        "0x1.ccE-128#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "1.337e-154",
+        "0x1.cbE-128#9",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "2.3825649048879511e-323228497",
+        "0x1.0000000000000E-268435456#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "2.3825649048879511e-323228497",
+        "0x1.0000000000000E-268435456#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Floor, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", Up, "1.0", "0x1.0#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.94690525376349792655895228013934",
+        "0x0.f26861f95326637981841f9c9#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.94690525376349792655895228013934",
+        "0x0.f26861f95326637981841f9c9#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sech_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.sech_round(Exact));
+    assert_panic!(X.sech_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.sech_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_sech_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sech, o) = x.clone().sech_prec_round(prec, rm);
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sech_alt, o_alt) = x.sech_prec_round_ref(prec, rm);
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sech_alt = x.clone();
+        let o_alt = sech_alt.sech_prec_round_assign(prec, rm);
+        assert!(sech_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sech), ComparableFloatRef(&sech_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sech, rug_o) = rug_sech_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sech)),
+                ComparableFloatRef(&sech)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.64746", "0x0.a5c#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.64746", "0x0.a5c#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.64805427366388539957497735322564",
+        "0x0.a5e6e2876b5f3b99e515f5ceb#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "0.64746", "0x0.a5c#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "0.64746", "0x0.a5c#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "0.64805427366388539957497735322564",
+        "0x0.a5e6e2876b5f3b99e515f5ceb#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.64746",
+        "0x0.a5c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.64746",
+        "0x0.a5c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.64844",
+        "0x0.a60#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.64805427366388539957497735322564",
+        "0x0.a5e6e2876b5f3b99e515f5ceb#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.0e-53",
+        "0x1.0E-44#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "5.2e-54", "0x8.0E-45#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "1.0e-53",
+        "0x1.0E-44#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "7.6264e-54",
+        "0xb.b0E-45#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "7.6264e-54",
+        "0xb.b0E-45#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "7.6349943773423492514662264337907e-54",
+        "0xb.b35a2eec278be3598774c213E-45#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "1.0e-53",
+        "0x1.0E-44#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Down,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Up,
+        "1.0e-53",
+        "0x1.0E-44#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "7.6264e-54",
+        "0xb.b0E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "7.6264e-54",
+        "0xb.b0E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "7.6366e-54",
+        "0xb.b4E-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "7.6349943773423492514662264337907e-54",
+        "0xb.b35a2eec278be3598774c213E-45#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "7.6349943773423492514662264337825e-54",
+        "0xb.b35a2eec278be3598774c212E-45#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.086182",
+        "0x0.1610#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.086304",
+        "0x0.1618#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.086182",
+        "0x0.1610#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.086304",
+        "0x0.1618#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.086304",
+        "0x0.1618#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "0.086266738334054425221798413905217",
+        "0x0.161593b3e09a397a1c92696e3a#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "0.086266738334054425221798413905316",
+        "0x0.161593b3e09a397a1c92696e3c#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.086266738334054425221798413905217",
+        "0x0.161593b3e09a397a1c92696e3a#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.25",
+        "0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.12",
+        "0x0.2#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.13135",
+        "0x0.21a#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.13159",
+        "0x0.21b#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.13135",
+        "0x0.21a#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.13159",
+        "0x0.21b#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.13135",
+        "0x0.21a#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.13140388387565735191886911224506",
+        "0x0.21a3af57d036660ba413877288#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.13140388387565735191886911224526",
+        "0x0.21a3af57d036660ba41387728c#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.13140388387565735191886911224506",
+        "0x0.21a3af57d036660ba413877288#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Floor,
+        "7.5e-155",
+        "0x1.0E-128#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "1.5e-154",
+        "0x2.0E-128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Down,
+        "7.5e-155",
+        "0x1.0E-128#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Up,
+        "1.5e-154",
+        "0x2.0E-128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Nearest,
+        "1.5e-154",
+        "0x2.0E-128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "1.3373e-154",
+        "0x1.cb0E-128#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "1.3373e-154",
+        "0x1.cb0E-128#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "1.3387e-154",
+        "0x1.cb8E-128#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "1.3381010762532298264025680134556e-154",
+        "0x1.cb4a26f3c63f58179849f6458E-128#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "1.3381010762532298264025680134568e-154",
+        "0x1.cb4a26f3c63f58179849f645aE-128#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "1.3381010762532298264025680134556e-154",
+        "0x1.cb4a26f3c63f58179849f6458E-128#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "2.3826e-323228497",
+        "0x1.000E-268435456#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "2.3825649048879510732161697817327e-323228497",
+        "0x1.0000000000000000000000000E-268435456#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.94629",
+        "0x0.f24#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.94727",
+        "0x0.f28#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.94629",
+        "0x0.f24#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.94727",
+        "0x0.f28#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.94727",
+        "0x0.f28#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.94690525376349792655895228013934",
+        "0x0.f26861f95326637981841f9c9#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sech_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).sech_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).sech_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sech_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.sech_prec_round(1, Exact));
+    assert_panic!(X.sech_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.sech_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_sech_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (sech, o) = Float::sech_rational_prec(x.clone(), prec);
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+        assert_eq!(o, out_o);
+
+        let (sech, o) = Float::sech_rational_prec_ref(&x, prec);
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_sech, rug_o) = rug_sech_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sech)),
+            ComparableFloatRef(&sech)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "1.0", "0x1.0#1", Equal);
+    test("0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, "0.50", "0x0.8#1", Less);
+    test("1", 10, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "1",
+        100,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("-1", 1, "0.50", "0x0.8#1", Less);
+    test("-1", 10, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "-1",
+        100,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("1/2", 1, "1.0", "0x1.0#1", Greater);
+    test("1/2", 10, "0.88672", "0x0.e30#10", Less);
+    test(
+        "1/2",
+        100,
+        "0.88681888397007390865889779778367",
+        "0x0.e3068ff8206e7fbc254fca8de#100",
+        Greater,
+    );
+    test("1/3", 1, "1.0", "0x1.0#1", Greater);
+    test("1/3", 10, "0.94727", "0x0.f28#10", Greater);
+    test(
+        "1/3",
+        100,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+    test("-1/3", 1, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 10, "0.94727", "0x0.f28#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+    test("22/7", 1, "0.062", "0x0.1#1", Less);
+    test("22/7", 10, "0.086182", "0x0.1610#10", Greater);
+    test(
+        "22/7",
+        100,
+        "0.086158129536979492276196616870947",
+        "0x0.160e758ca556fc438352ae2a42#100",
+        Less,
+    );
+    test("1000000", 1, "6.8e-434295", "0x4.0E-360674#1", Greater);
+    test("1000000", 10, "6.5911e-434295", "0x3.e3E-360674#10", Less);
+    test(
+        "1000000",
+        100,
+        "6.5936629561771171579378159382151e-434295",
+        "0x3.e362986bdaa297eea886313f4E-360674#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sech_rational_prec_fail() {
+    Float::sech_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn sech_rational_prec_ref_fail() {
+    Float::sech_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_sech_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (sech, o) = Float::sech_rational_prec_round(x.clone(), prec, rm);
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+        assert_eq!(o, out_o);
+
+        let (sech, o) = Float::sech_rational_prec_round_ref(&x, prec, rm);
+        assert!(sech.is_valid());
+        assert_eq!(sech.to_string(), out);
+        assert_eq!(to_hex_string(&sech), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sech, rug_o) = rug_sech_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sech)),
+                ComparableFloatRef(&sech)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1", 10, Floor, "0.64746", "0x0.a5c#10", Less);
+    test("1", 10, Ceiling, "0.64844", "0x0.a60#10", Greater);
+    test("1", 10, Down, "0.64746", "0x0.a5c#10", Less);
+    test("1", 10, Up, "0.64844", "0x0.a60#10", Greater);
+    test("1", 10, Nearest, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("-1", 10, Floor, "0.64746", "0x0.a5c#10", Less);
+    test("-1", 10, Ceiling, "0.64844", "0x0.a60#10", Greater);
+    test("-1", 10, Down, "0.64746", "0x0.a5c#10", Less);
+    test("-1", 10, Up, "0.64844", "0x0.a60#10", Greater);
+    test("-1", 10, Nearest, "0.64844", "0x0.a60#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "0.64805427366388539957497735322643",
+        "0x0.a5e6e2876b5f3b99e515f5cec#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1/2", 10, Floor, "0.88672", "0x0.e30#10", Less);
+    test("1/2", 10, Ceiling, "0.88770", "0x0.e34#10", Greater);
+    test("1/2", 10, Down, "0.88672", "0x0.e30#10", Less);
+    test("1/2", 10, Up, "0.88770", "0x0.e34#10", Greater);
+    test("1/2", 10, Nearest, "0.88672", "0x0.e30#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.88681888397007390865889779778367",
+        "0x0.e3068ff8206e7fbc254fca8de#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1/3", 10, Floor, "0.94629", "0x0.f24#10", Less);
+    test("1/3", 10, Ceiling, "0.94727", "0x0.f28#10", Greater);
+    test("1/3", 10, Down, "0.94629", "0x0.f24#10", Less);
+    test("1/3", 10, Up, "0.94727", "0x0.f28#10", Greater);
+    test("1/3", 10, Nearest, "0.94727", "0x0.f28#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+    test("-1/3", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 1, Down, "0.50", "0x0.8#1", Less);
+    test("-1/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1/3", 10, Floor, "0.94629", "0x0.f24#10", Less);
+    test("-1/3", 10, Ceiling, "0.94727", "0x0.f28#10", Greater);
+    test("-1/3", 10, Down, "0.94629", "0x0.f24#10", Less);
+    test("-1/3", 10, Up, "0.94727", "0x0.f28#10", Greater);
+    test("-1/3", 10, Nearest, "0.94727", "0x0.f28#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "0.94690525376349792655895228014013",
+        "0x0.f26861f95326637981841f9ca#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.062", "0x0.1#1", Less);
+    test("22/7", 1, Ceiling, "0.12", "0x0.2#1", Greater);
+    test("22/7", 1, Down, "0.062", "0x0.1#1", Less);
+    test("22/7", 1, Up, "0.12", "0x0.2#1", Greater);
+    test("22/7", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test("22/7", 10, Floor, "0.086060", "0x0.1608#10", Less);
+    test("22/7", 10, Ceiling, "0.086182", "0x0.1610#10", Greater);
+    test("22/7", 10, Down, "0.086060", "0x0.1608#10", Less);
+    test("22/7", 10, Up, "0.086182", "0x0.1610#10", Greater);
+    test("22/7", 10, Nearest, "0.086182", "0x0.1610#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.086158129536979492276196616870947",
+        "0x0.160e758ca556fc438352ae2a42#100",
+        Less,
+    );
+    test("1000000", 1, Floor, "3.4e-434295", "0x2.0E-360674#1", Less);
+    test(
+        "1000000",
+        1,
+        Ceiling,
+        "6.8e-434295",
+        "0x4.0E-360674#1",
+        Greater,
+    );
+    test("1000000", 1, Down, "3.4e-434295", "0x2.0E-360674#1", Less);
+    test("1000000", 1, Up, "6.8e-434295", "0x4.0E-360674#1", Greater);
+    test(
+        "1000000",
+        1,
+        Nearest,
+        "6.8e-434295",
+        "0x4.0E-360674#1",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Floor,
+        "6.5911e-434295",
+        "0x3.e3E-360674#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "6.5977e-434295",
+        "0x3.e4E-360674#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Down,
+        "6.5911e-434295",
+        "0x3.e3E-360674#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Up,
+        "6.5977e-434295",
+        "0x3.e4E-360674#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "6.5911e-434295",
+        "0x3.e3E-360674#10",
+        Less,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "6.5936629561771171579378159382151e-434295",
+        "0x3.e362986bdaa297eea886313f4E-360674#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sech_rational_prec_round_fail_1() {
+    Float::sech_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn sech_rational_prec_round_fail_2() {
+    Float::sech_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn sech_rational_prec_round_ref_fail() {
+    Float::sech_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sech_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (sech, o) = x.clone().sech_prec_round(prec, rm);
+    assert!(sech.is_valid());
+
+    let (sech_alt, o_alt) = x.sech_prec_round_ref(prec, rm);
+    assert!(sech_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sech_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sech, rug_o) = rug_sech_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sech)),
+            ComparableFloatRef(&sech)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(sech.is_nan(), x.is_nan());
+    assert!(sech.is_nan() || sech >= 0u32 && sech <= 1u32);
+
+    if sech.is_normal() {
+        assert_eq!(sech.get_prec(), Some(prec));
+    }
+
+    let (sech_alt, o_alt) = (-&x).sech_prec_round(prec, rm);
+    assert_eq!(ComparableFloat(sech_alt), ComparableFloat(sech.clone()));
+    assert_eq!(o_alt, o);
+
+    if x.is_normal() {
+        let (sech_alt, o_alt) = Float::sech_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sech_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(sech.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sech_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn sech_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        sech_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sech, o) = Float::NAN.sech_prec_round(prec, rm);
+        assert!(sech.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::INFINITY.sech_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::NEGATIVE_INFINITY.sech_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::ZERO.sech_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(sech),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::NEGATIVE_ZERO.sech_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(sech),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sech_prec_properties_helper(x: Float, prec: u64) {
+    let (sech, o) = x.clone().sech_prec(prec);
+    assert!(sech.is_valid());
+
+    let (sech_alt, o_alt) = x.sech_prec_ref(prec);
+    assert!(sech_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sech_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let (sech_alt, o_alt) = x.sech_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let (rug_sech, rug_o) = rug_sech_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sech)),
+        ComparableFloatRef(&sech)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(sech.is_nan() || sech >= 0u32 && sech <= 1u32);
+
+    if sech.is_normal() {
+        assert_eq!(sech.get_prec(), Some(prec));
+    }
+
+    let (sech_alt, o_alt) = (-&x).sech_prec(prec);
+    assert_eq!(ComparableFloat(sech_alt), ComparableFloat(sech.clone()));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn sech_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        sech_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sech, o) = Float::NAN.sech_prec(prec);
+        assert!(sech.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::INFINITY.sech_prec(prec);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::NEGATIVE_INFINITY.sech_prec(prec);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::ZERO.sech_prec(prec);
+        assert_eq!(
+            ComparableFloat(sech),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::NEGATIVE_ZERO.sech_prec(prec);
+        assert_eq!(
+            ComparableFloat(sech),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sech_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (sech, o) = x.clone().sech_round(rm);
+    assert!(sech.is_valid());
+
+    let (sech_alt, o_alt) = x.sech_round_ref(rm);
+    assert!(sech_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sech_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let (sech_alt, o_alt) = x.sech_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    assert!(sech.is_nan() || sech >= 0u32 && sech <= 1u32);
+
+    let (sech_alt, o_alt) = (-&x).sech_round(rm);
+    assert_eq!(ComparableFloat(sech_alt), ComparableFloat(sech.clone()));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sech, rug_o) = rug_sech_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sech)),
+            ComparableFloatRef(&sech)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sech_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(sech.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sech_round_ref(Exact));
+    }
+}
+
+#[test]
+fn sech_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        sech_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (sech, o) = Float::NAN.sech_round(rm);
+        assert!(sech.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::INFINITY.sech_round(rm);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::NEGATIVE_INFINITY.sech_round(rm);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::ZERO.sech_round(rm);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+
+        let (sech, o) = Float::NEGATIVE_ZERO.sech_round(rm);
+        assert_eq!(ComparableFloat(sech), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sech_properties_helper(x: Float) {
+    let sech = x.clone().sech();
+    assert!(sech.is_valid());
+
+    let sech_alt = (&x).sech();
+    assert!(sech_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+
+    let mut x_alt = x.clone();
+    x_alt.sech_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sech));
+
+    let sech_alt = x.sech_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    let sech_alt = x.sech_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+
+    assert_eq!(sech.is_nan(), x.is_nan());
+    assert!(sech.is_nan() || sech >= 0u32 && sech <= 1u32);
+
+    let rug_sech = rug_sech(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sech)),
+        ComparableFloatRef(&sech),
+    );
+}
+
+#[test]
+fn sech_properties() {
+    float_gen().test_properties(|x| {
+        sech_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sech_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (sech, o) = Float::sech_rational_prec_round(x.clone(), prec, rm);
+    assert!(sech.is_valid());
+
+    let (sech_alt, o_alt) = Float::sech_rational_prec_round_ref(&x, prec, rm);
+    assert!(sech_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sech, rug_o) = rug_sech_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sech)),
+            ComparableFloatRef(&sech)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!sech.is_nan());
+    assert!(sech.is_nan() || sech >= 0u32 && sech <= 1u32);
+
+    if sech.is_normal() {
+        assert_eq!(sech.get_prec(), Some(prec));
+    }
+
+    let (sech_alt, o_alt) = Float::sech_rational_prec_round(-&x, prec, rm);
+    assert_eq!(ComparableFloat(sech_alt), ComparableFloat(sech.clone()));
+    assert_eq!(o_alt, o);
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::sech_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(sech.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::sech_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn sech_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        sech_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sech, o) = Float::sech_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(
+            ComparableFloat(sech),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sech_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (sech, o) = Float::sech_rational_prec(x.clone(), prec);
+    assert!(sech.is_valid());
+
+    let (sech_alt, o_alt) = Float::sech_rational_prec_ref(&x, prec);
+    assert!(sech_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let (sech_alt, o_alt) = Float::sech_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sech_alt), ComparableFloatRef(&sech));
+    assert_eq!(o_alt, o);
+
+    let (rug_sech, rug_o) = rug_sech_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sech)),
+        ComparableFloatRef(&sech)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(sech.is_nan() || sech >= 0u32 && sech <= 1u32);
+
+    if sech.is_normal() {
+        assert_eq!(sech.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn sech_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        sech_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sech, o) = Float::sech_rational_prec(Rational::ZERO, prec);
+        assert_eq!(
+            ComparableFloat(sech),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_sech() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_sech(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 0.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, 0.0);
+    test::<f32>(0.0, 1.0);
+    test::<f32>(-0.0, 1.0);
+    test::<f32>(1.0, 0.6480543);
+    test::<f32>(-1.0, 0.6480543);
+    test::<f32>(0.5, 0.8868189);
+    test::<f32>(-0.5, 0.8868189);
+    test::<f32>(2.0, 0.26580223);
+    test::<f32>(10.0, 0.00009079986);
+    test::<f32>(0.001, 0.9999995);
+    test::<f32>(10000000000.0, 0.0);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 0.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, 0.0);
+    test::<f64>(0.0, 1.0);
+    test::<f64>(-0.0, 1.0);
+    test::<f64>(1.0, 0.6480542736638853);
+    test::<f64>(-1.0, 0.6480542736638853);
+    test::<f64>(0.5, 0.8868188839700739);
+    test::<f64>(-0.5, 0.8868188839700739);
+    test::<f64>(2.0, 0.26580222883407967);
+    test::<f64>(10.0, 0.00009079985933781725);
+    test::<f64>(0.001, 0.9999995000002083);
+    test::<f64>(10000000000.0, 0.0);
+    test::<f64>(1.0e300, 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_sech_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let sech = primitive_float_sech(x);
+        assert_eq!(sech.is_nan(), x.is_nan());
+        assert!(sech.is_nan() || sech >= T::ZERO && sech <= T::ONE);
+        assert_eq!(NiceFloat(primitive_float_sech(-x)), NiceFloat(sech));
+    });
+}
+
+#[test]
+fn primitive_float_sech_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_sech_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_sech_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_sech_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 1.0);
+    test::<f32>("1", 0.6480543);
+    test::<f32>("-1", 0.6480543);
+    test::<f32>("1/2", 0.8868189);
+    test::<f32>("-1/2", 0.8868189);
+    test::<f32>("1/3", 0.94690526);
+    test::<f32>("22/7", 0.08615813);
+    test::<f32>("3/2", 0.42509604);
+    test::<f32>("1000000", 0.0);
+
+    test::<f64>("0", 1.0);
+    test::<f64>("1", 0.6480542736638853);
+    test::<f64>("-1", 0.6480542736638853);
+    test::<f64>("1/2", 0.8868188839700739);
+    test::<f64>("-1/2", 0.8868188839700739);
+    test::<f64>("1/3", 0.9469052537634979);
+    test::<f64>("22/7", 0.08615812953697949);
+    test::<f64>("3/2", 0.4250960349422805);
+    test::<f64>("1000000", 0.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_sech_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let sech = primitive_float_sech_rational::<T>(&x);
+        assert!(!sech.is_nan());
+        assert!(sech.is_nan() || sech >= T::ZERO && sech <= T::ONE);
+        assert_eq!(
+            NiceFloat(primitive_float_sech_rational::<T>(&-&x)),
+            NiceFloat(sech)
+        );
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_sech_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_sech(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_sech_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_sech_rational_properties_helper);
+}
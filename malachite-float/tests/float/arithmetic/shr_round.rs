@@ -544,6 +544,26 @@ fn test_shr_prec_round_unsigned_helper<
         "0x0.0",
         Less,
     );
+    test_r(
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        1,
+        1,
+        NearestTiesAway,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test_r(
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        1,
+        1,
+        Odd,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
 
     test_r(
         "-2.4e-323228497",
@@ -595,6 +615,26 @@ fn test_shr_prec_round_unsigned_helper<
         "-0x0.0",
         Greater,
     );
+    test_r(
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        1,
+        1,
+        NearestTiesAway,
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        Less,
+    );
+    test_r(
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        1,
+        1,
+        Odd,
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        Less,
+    );
 
     test_r(
         "1.857667082873663089629e-323228495",
@@ -1465,6 +1505,26 @@ fn test_shr_prec_round_signed_helper<
         "0x0.0",
         Less,
     );
+    test_r(
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        1,
+        1,
+        NearestTiesAway,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
+    test_r(
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        1,
+        1,
+        Odd,
+        "2.4e-323228497",
+        "0x1.0E-268435456#1",
+        Greater,
+    );
 
     test_r(
         "-2.4e-323228497",
@@ -1516,6 +1576,26 @@ fn test_shr_prec_round_signed_helper<
         "-0x0.0",
         Greater,
     );
+    test_r(
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        1,
+        1,
+        NearestTiesAway,
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        Less,
+    );
+    test_r(
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        1,
+        1,
+        Odd,
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        Less,
+    );
 
     test_r(
         "1.857667082873663089629e-323228495",
@@ -0,0 +1,2730 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Sin, SinAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::sin::{primitive_float_sin, primitive_float_sin_rational};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::sin::{
+    rug_sin, rug_sin_prec, rug_sin_prec_round, rug_sin_rational_prec, rug_sin_rational_prec_round,
+    rug_sin_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sin() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sin = x.clone().sin();
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+
+        let sin_alt = (&x).sin();
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+
+        let mut sin_alt = x.clone();
+        sin_alt.sin_assign();
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&sin)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+    );
+    test("123.0", "0x7b.0#7", "-0.4609", "-0x0.76#7");
+    test("-123.0", "-0x7b.0#7", "0.4609", "0x0.76#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+    );
+    test("355.0", "0x163.0#9", "-0.00003016", "-0x0.0001fa#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "7.9e-31", "0x1.0E-25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+    );
+}
+
+#[test]
+fn test_sin_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_prec(prec);
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_prec_ref(prec);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_prec_assign(prec);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sin, rug_o) = rug_sin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.84180", "0x0.d78#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.84180", "-0x0.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.84147098480789650665250232163005",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "-0.50", "-0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 10, "-0.45996", "-0x0.75c#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-0.45990349068959125129243571529336",
+        "-0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "0.50", "0x0.8#1", Greater);
+    test("-123.0", "-0x7b.0#7", 10, "0.45996", "0x0.75c#10", Greater);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "0.45990349068959125129243571529336",
+        "0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "1.2246467991473531772260659322748e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.41078129050290882728053949161020",
+        "0x0.6928f67084c77a976544888ac8#100",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 1, "-0.000031", "-0x0.0002#1", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "-0.000030144353359488449214330280008641",
+        "-0x0.0001f9bd0307d1de29dade2cb6b5c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "-0.81788191211590859704588528275571",
+        "-0x0.d160b580871c2b720143ea9da#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sin_prec_fail() {
+    assert_panic!(Float::NAN.sin_prec(0));
+    assert_panic!(Float::NAN.sin_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.sin_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_sin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_round(rm);
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_round_ref(rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_round_assign(rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.84147098480789650665250232163084",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.84147098480789650665250232163084",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", Floor, "-0.4609", "-0x0.76#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "-0.4570",
+        "-0x0.75#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "-0.4570", "-0x0.75#7", Greater);
+    test("123.0", "0x7b.0#7", Up, "-0.4609", "-0x0.76#7", Less);
+    test("123.0", "0x7b.0#7", Nearest, "-0.4609", "-0x0.76#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "0.4570", "0x0.75#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "0.4609",
+        "0x0.76#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "0.4570", "0x0.75#7", Less);
+    test("-123.0", "-0x7b.0#7", Up, "0.4609", "0x0.76#7", Greater);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "0.4609",
+        "0x0.76#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "1.2246467991473515e-16",
+        "0x8.d313198a2e00E-14#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "1.2246467991473515e-16",
+        "0x8.d313198a2e00E-14#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "-0.00003010",
+        "-0x0.0001f9#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "-0.00003010",
+        "-0x0.0001f9#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "-0.81788191211590888",
+        "-0x0.d160b580871c4#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "-0.81788191211590888",
+        "-0x0.d160b580871c4#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sin_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(X.sin_round(Exact));
+    assert_panic!(X.sin_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.sin_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_sin_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_prec_round_assign(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.84082", "0x0.d74#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.84082", "0x0.d74#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.84180", "0x0.d78#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.84147098480789650665250232163084",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.84082",
+        "-0x0.d74#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.84082",
+        "-0x0.d74#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.84180", "-0x0.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.84147098480789650665250232163084",
+        "-0x0.d76aa47848677020c6e9e909d#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.84147098480789650665250232163005",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.84147098480789650665250232163005",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.84147098480789650665250232163084",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test(
+        "123.0", "0x7b.0#7", 1, Ceiling, "-0.25", "-0x0.4#1", Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 1, Nearest, "-0.50", "-0x0.8#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "-0.45996",
+        "-0x0.75c#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.45947",
+        "-0x0.75a#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "-0.45947",
+        "-0x0.75a#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Up, "-0.45996", "-0x0.75c#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "-0.45996",
+        "-0x0.75c#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "-0.45990349068959125129243571529336",
+        "-0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "-0.45990349068959125129243571529296",
+        "-0x0.75bc3c33d3fa1892981f9fcdf0#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "-0.45990349068959125129243571529336",
+        "-0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Floor, "0.25", "0x0.4#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Down, "0.25", "0x0.4#1", Less);
+    test("-123.0", "-0x7b.0#7", 1, Up, "0.50", "0x0.8#1", Greater);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "0.45947",
+        "0x0.75a#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "0.45996",
+        "0x0.75c#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "0.45947",
+        "0x0.75a#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "0.45996",
+        "0x0.75c#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "0.45996",
+        "0x0.75c#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "0.45990349068959125129243571529296",
+        "0x0.75bc3c33d3fa1892981f9fcdf0#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "0.45990349068959125129243571529336",
+        "0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "0.45990349068959125129243571529336",
+        "0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "2.2e-16",
+        "0x1.0E-13#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "2.2e-16",
+        "0x1.0E-13#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "1.2230e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "1.2230e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "1.2246467991473531772260659322748e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "1.2246467991473531772260659322765e-16",
+        "0x8.d313198a2e03707344a40939E-14#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "1.2246467991473531772260659322748e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.41113",
+        "0x0.694#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.41113",
+        "0x0.694#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.41078129050290882728053949160981",
+        "0x0.6928f67084c77a976544888ac0#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.41078129050290882728053949161020",
+        "0x0.6928f67084c77a976544888ac8#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.41078129050290882728053949161020",
+        "0x0.6928f67084c77a976544888ac8#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Floor,
+        "-0.000031",
+        "-0x0.0002#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "-0.000015",
+        "-0x0.0001#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Down,
+        "-0.000015",
+        "-0x0.0001#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Up,
+        "-0.000031",
+        "-0x0.0002#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Nearest,
+        "-0.000031",
+        "-0x0.0002#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "-0.000030160",
+        "-0x0.0001fa0#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "-0.000030160",
+        "-0x0.0001fa0#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "-0.000030144353359488449214330280008666",
+        "-0x0.0001f9bd0307d1de29dade2cb6b5e#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "-0.000030144353359488449214330280008641",
+        "-0x0.0001f9bd0307d1de29dade2cb6b5c#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "-0.000030144353359488449214330280008641",
+        "-0x0.0001f9bd0307d1de29dade2cb6b5c#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "-0.81738",
+        "-0x0.d14#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "-0.81738",
+        "-0x0.d14#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "-0.81788191211590859704588528275571",
+        "-0x0.d160b580871c2b720143ea9da#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "-0.81788191211590859704588528275492",
+        "-0x0.d160b580871c2b720143ea9d9#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "-0.81788191211590859704588528275571",
+        "-0x0.d160b580871c2b720143ea9da#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "7.8886090522101180541172856528216e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sin_prec_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).sin_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).sin_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sin_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.sin_prec_round(1, Exact));
+    assert_panic!(X.sin_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.sin_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_sin_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (sin, o) = Float::sin_rational_prec(x.clone(), prec);
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, out_o);
+
+        let (sin, o) = Float::sin_rational_prec_ref(&x, prec);
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_sin, rug_o) = rug_sin_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Greater);
+    test("1", 10, "0.84180", "0x0.d78#10", Greater);
+    test(
+        "1",
+        100,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("-1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, "-0.84180", "-0x0.d78#10", Less);
+    test(
+        "-1",
+        100,
+        "-0.84147098480789650665250232163005",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, "0.47949", "0x0.7ac#10", Greater);
+    test(
+        "1/2",
+        100,
+        "0.47942553860420300027328793521567",
+        "0x0.7abba1d12c17bfa1d92f0d93f8#100",
+        Greater,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.32715", "0x0.53c#10", Less);
+    test(
+        "1/3",
+        100,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.32715", "-0x0.53c#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "-0.32719469679615224417334408526753",
+        "-0x0.53c3081a2a031ab144c484c790#100",
+        Greater,
+    );
+    test("22/7", 1, "-0.00098", "-0x0.004#1", Greater);
+    test("22/7", 10, "-0.0012646", "-0x0.0052e#10", Less);
+    test(
+        "22/7",
+        100,
+        "-0.0012644889303773534003603504756467",
+        "-0x0.0052de9a9a24d90da7cdffe30130#100",
+        Less,
+    );
+    test("1000000", 1, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 10, "-0.35010", "-0x0.59a#10", Less);
+    test(
+        "1000000",
+        100,
+        "-0.34999350217129295211765248678059",
+        "-0x0.59992c95a3619d264732d26e98#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sin_rational_prec_fail() {
+    Float::sin_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn sin_rational_prec_ref_fail() {
+    Float::sin_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_sin_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (sin, o) = Float::sin_rational_prec_round(x.clone(), prec, rm);
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, out_o);
+
+        let (sin, o) = Float::sin_rational_prec_round_ref(&x, prec, rm);
+        assert!(sin.is_valid());
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", 10, Floor, "0.84082", "0x0.d74#10", Less);
+    test("1", 10, Ceiling, "0.84180", "0x0.d78#10", Greater);
+    test("1", 10, Down, "0.84082", "0x0.d74#10", Less);
+    test("1", 10, Up, "0.84180", "0x0.d78#10", Greater);
+    test("1", 10, Nearest, "0.84180", "0x0.d78#10", Greater);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("-1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, Floor, "-0.84180", "-0x0.d78#10", Less);
+    test("-1", 10, Ceiling, "-0.84082", "-0x0.d74#10", Greater);
+    test("-1", 10, Down, "-0.84082", "-0x0.d74#10", Greater);
+    test("-1", 10, Up, "-0.84180", "-0x0.d78#10", Less);
+    test("-1", 10, Nearest, "-0.84180", "-0x0.d78#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.84147098480789650665250232163005",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, Floor, "0.47900", "0x0.7aa#10", Less);
+    test("1/2", 10, Ceiling, "0.47949", "0x0.7ac#10", Greater);
+    test("1/2", 10, Down, "0.47900", "0x0.7aa#10", Less);
+    test("1/2", 10, Up, "0.47949", "0x0.7ac#10", Greater);
+    test("1/2", 10, Nearest, "0.47949", "0x0.7ac#10", Greater);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.47942553860420300027328793521567",
+        "0x0.7abba1d12c17bfa1d92f0d93f8#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.32715", "0x0.53c#10", Less);
+    test("1/3", 10, Ceiling, "0.32764", "0x0.53e#10", Greater);
+    test("1/3", 10, Down, "0.32715", "0x0.53c#10", Less);
+    test("1/3", 10, Up, "0.32764", "0x0.53e#10", Greater);
+    test("1/3", 10, Nearest, "0.32715", "0x0.53c#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, Floor, "-0.32764", "-0x0.53e#10", Less);
+    test("-1/3", 10, Ceiling, "-0.32715", "-0x0.53c#10", Greater);
+    test("-1/3", 10, Down, "-0.32715", "-0x0.53c#10", Greater);
+    test("-1/3", 10, Up, "-0.32764", "-0x0.53e#10", Less);
+    test("-1/3", 10, Nearest, "-0.32715", "-0x0.53c#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-0.32719469679615224417334408526753",
+        "-0x0.53c3081a2a031ab144c484c790#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "-0.0020", "-0x0.008#1", Less);
+    test("22/7", 1, Ceiling, "-0.00098", "-0x0.004#1", Greater);
+    test("22/7", 1, Down, "-0.00098", "-0x0.004#1", Greater);
+    test("22/7", 1, Up, "-0.0020", "-0x0.008#1", Less);
+    test("22/7", 1, Nearest, "-0.00098", "-0x0.004#1", Greater);
+    test("22/7", 10, Floor, "-0.0012646", "-0x0.0052e#10", Less);
+    test("22/7", 10, Ceiling, "-0.0012627", "-0x0.0052c#10", Greater);
+    test("22/7", 10, Down, "-0.0012627", "-0x0.0052c#10", Greater);
+    test("22/7", 10, Up, "-0.0012646", "-0x0.0052e#10", Less);
+    test("22/7", 10, Nearest, "-0.0012646", "-0x0.0052e#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "-0.0012644889303773534003603504756467",
+        "-0x0.0052de9a9a24d90da7cdffe30130#100",
+        Less,
+    );
+    test("1000000", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("1000000", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("1000000", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 10, Floor, "-0.35010", "-0x0.59a#10", Less);
+    test("1000000", 10, Ceiling, "-0.34961", "-0x0.598#10", Greater);
+    test("1000000", 10, Down, "-0.34961", "-0x0.598#10", Greater);
+    test("1000000", 10, Up, "-0.35010", "-0x0.59a#10", Less);
+    test("1000000", 10, Nearest, "-0.35010", "-0x0.59a#10", Less);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "-0.34999350217129295211765248678059",
+        "-0x0.59992c95a3619d264732d26e98#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sin_rational_prec_round_fail_1() {
+    Float::sin_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn sin_rational_prec_round_fail_2() {
+    Float::sin_rational_prec_round(Rational::ONE, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn sin_rational_prec_round_ref_fail() {
+    Float::sin_rational_prec_round_ref(&Rational::ONE, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sin_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (sin, o) = x.clone().sin_prec_round(prec, rm);
+    assert!(sin.is_valid());
+
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, rm);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_o) = rug_sin_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(sin.is_nan(), !x.is_finite());
+    assert!(sin.is_nan() || (sin >= -1i32 && sin <= 1u32));
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+
+    let (sin_alt, o_alt) = (-&x).sin_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-sin_alt), ComparableFloat(sin.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (sin_alt, o_alt) = Float::sin_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sin_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(sin.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sin_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn sin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        sin_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sin, o) = Float::NAN.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::INFINITY.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::NEGATIVE_INFINITY.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::ZERO.sin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::NEGATIVE_ZERO.sin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sin_prec_properties_helper(x: Float, prec: u64) {
+    let (sin, o) = x.clone().sin_prec(prec);
+    assert!(sin.is_valid());
+
+    let (sin_alt, o_alt) = x.sin_prec_ref(prec);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (rug_sin, rug_o) = rug_sin_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(sin.is_nan() || (sin >= -1i32 && sin <= 1u32));
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+
+    let (sin_alt, o_alt) = (-&x).sin_prec(prec);
+    assert_eq!(ComparableFloat(-sin_alt), ComparableFloat(sin.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn sin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        sin_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sin, o) = Float::NAN.sin_prec(prec);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::INFINITY.sin_prec(prec);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::NEGATIVE_INFINITY.sin_prec(prec);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::ZERO.sin_prec(prec);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::NEGATIVE_ZERO.sin_prec(prec);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sin_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (sin, o) = x.clone().sin_round(rm);
+    assert!(sin.is_valid());
+
+    let (sin_alt, o_alt) = x.sin_round_ref(rm);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    assert!(sin.is_nan() || (sin >= -1i32 && sin <= 1u32));
+
+    let (sin_alt, o_alt) = (-&x).sin_round(-rm);
+    assert_eq!(ComparableFloat(-sin_alt), ComparableFloat(sin.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_o) = rug_sin_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sin_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(sin.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sin_round_ref(Exact));
+    }
+}
+
+#[test]
+fn sin_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        sin_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (sin, o) = Float::NAN.sin_round(rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::INFINITY.sin_round(rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::NEGATIVE_INFINITY.sin_round(rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::ZERO.sin_round(rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sin, o) = Float::NEGATIVE_ZERO.sin_round(rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sin_properties_helper(x: Float) {
+    let sin = x.clone().sin();
+    assert!(sin.is_valid());
+
+    let sin_alt = (&x).sin();
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+
+    let mut x_alt = x.clone();
+    x_alt.sin_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+
+    let sin_alt = x.sin_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    let sin_alt = x.sin_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+
+    assert_eq!(sin.is_nan(), !x.is_finite());
+    assert!(sin.is_nan() || (sin >= -1i32 && sin <= 1u32));
+
+    let rug_sin = rug_sin(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin),
+    );
+}
+
+#[test]
+fn sin_properties() {
+    float_gen().test_properties(|x| {
+        sin_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sin_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (sin, o) = Float::sin_rational_prec_round(x.clone(), prec, rm);
+    assert!(sin.is_valid());
+
+    let (sin_alt, o_alt) = Float::sin_rational_prec_round_ref(&x, prec, rm);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_o) = rug_sin_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!sin.is_nan());
+    assert!(sin.is_nan() || (sin >= -1i32 && sin <= 1u32));
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (sin_alt, o_alt) = Float::sin_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-sin_alt), ComparableFloat(sin.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::sin_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(sin.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::sin_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn sin_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        sin_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sin, o) = Float::sin_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn sin_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (sin, o) = Float::sin_rational_prec(x.clone(), prec);
+    assert!(sin.is_valid());
+
+    let (sin_alt, o_alt) = Float::sin_rational_prec_ref(&x, prec);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (sin_alt, o_alt) = Float::sin_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (rug_sin, rug_o) = rug_sin_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(sin.is_nan() || (sin >= -1i32 && sin <= 1u32));
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn sin_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        sin_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sin, o) = Float::sin_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_sin() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_sin(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::NAN);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, 0.84147096);
+    test::<f32>(-1.0, -0.84147096);
+    test::<f32>(0.5, 0.47942555);
+    test::<f32>(-0.5, -0.47942555);
+    test::<f32>(2.0, 0.9092974);
+    test::<f32>(10.0, -0.5440211);
+    test::<f32>(0.001, 0.0009999999);
+    test::<f32>(10000000000.0, -0.48750603);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::NAN);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, 0.8414709848078965);
+    test::<f64>(-1.0, -0.8414709848078965);
+    test::<f64>(0.5, 0.479425538604203);
+    test::<f64>(-0.5, -0.479425538604203);
+    test::<f64>(2.0, 0.9092974268256817);
+    test::<f64>(10.0, -0.5440211108893698);
+    test::<f64>(0.001, 0.0009999998333333417);
+    test::<f64>(10000000000.0, -0.4875060250875107);
+    test::<f64>(1.0e300, -0.8178819121159085);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_sin_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let sin = primitive_float_sin(x);
+        assert_eq!(sin.is_nan(), !x.is_finite());
+        assert!(sin.is_nan() || (sin >= -T::ONE && sin <= T::ONE));
+        assert_eq!(NiceFloat(primitive_float_sin(-x)), NiceFloat(-sin));
+    });
+}
+
+#[test]
+fn primitive_float_sin_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_sin_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_sin_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_sin_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", 0.84147096);
+    test::<f32>("-1", -0.84147096);
+    test::<f32>("1/2", 0.47942555);
+    test::<f32>("-1/2", -0.47942555);
+    test::<f32>("1/3", 0.3271947);
+    test::<f32>("22/7", -0.0012644889);
+    test::<f32>("3/2", 0.997495);
+    test::<f32>("1000000", -0.3499935);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", 0.8414709848078965);
+    test::<f64>("-1", -0.8414709848078965);
+    test::<f64>("1/2", 0.479425538604203);
+    test::<f64>("-1/2", -0.479425538604203);
+    test::<f64>("1/3", 0.32719469679615226);
+    test::<f64>("22/7", -0.0012644889303773533);
+    test::<f64>("3/2", 0.9974949866040544);
+    test::<f64>("1000000", -0.34999350217129294);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_sin_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let sin = primitive_float_sin_rational::<T>(&x);
+        assert!(!sin.is_nan());
+        assert!(sin >= -T::ONE && sin <= T::ONE);
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_sin_rational::<T>(&-&x)),
+                NiceFloat(-sin)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_sin_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_sin(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_sin_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_sin_rational_properties_helper);
+}
@@ -0,0 +1,3758 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Cos, Sin, SinCos};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::cos::primitive_float_cos;
+use malachite_float::float::arithmetic::sin::primitive_float_sin;
+use malachite_float::float::arithmetic::sin_cos::primitive_float_sin_cos;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::sin_cos::{
+    rug_sin_cos, rug_sin_cos_prec, rug_sin_cos_prec_round, rug_sin_cos_rational_prec,
+    rug_sin_cos_rational_prec_round, rug_sin_cos_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::rational_unsigned_pair_gen_var_3;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sin_cos() {
+    let test = |s, s_hex, sin_out: &str, sin_out_hex: &str, cos_out: &str, cos_out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos) = x.clone().sin_cos();
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_out_hex);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_out_hex);
+
+        let (sin_alt, cos_alt) = (&x).sin_cos();
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        let (rug_sin, rug_cos) = rug_sin_cos(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "0.50", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", "0.50", "0x0.8#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.4609",
+        "-0x0.76#7",
+        "-0.8906",
+        "-0x0.e4#7",
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0.4609",
+        "0x0.76#7",
+        "-0.8906",
+        "-0x0.e4#7",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        "-1.000",
+        "-0x1.00#9",
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+    );
+}
+
+#[test]
+fn test_sin_cos_prec() {
+    let test = |s,
+                s_hex,
+                prec: u64,
+                sin_out: &str,
+                sin_out_hex: &str,
+                sin_o_out: Ordering,
+                cos_out: &str,
+                cos_out_hex: &str,
+                cos_o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ((sin, sin_o), (cos, cos_o)) = x.clone().sin_cos_prec(prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_out_hex);
+        assert_eq!(sin_o, sin_o_out);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_out_hex);
+        assert_eq!(cos_o, cos_o_out);
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) = x.sin_cos_prec_ref(prec);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(cos_o_alt, cos_o);
+
+        let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+            rug_sin_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_sin_o, sin_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_cos_o, cos_o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 100, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.84147098480789650665250232163005",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", 1, "-0.50", "-0x0.8#1", Less, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "-0.45996",
+        "-0x0.75c#10",
+        Less,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "-0.45990349068959125129243571529336",
+        "-0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Less,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "0.45996",
+        "0x0.75c#10",
+        Greater,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "0.45990349068959125129243571529336",
+        "0x0.75bc3c33d3fa1892981f9fcdf8#100",
+        Greater,
+        "-0.88796890669185542897832256944273",
+        "-0x0.e351ee261b3cad7894677374e#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "1.2246467991473531772260659322748e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.41078129050290882728053949161020",
+        "0x0.6928f67084c77a976544888ac8#100",
+        Greater,
+        "-0.91173391478696503850924773819963",
+        "-0x0.e96764d2aa0034ce60b62d755#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        "-0.000031",
+        "-0x0.0002#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "-0.000030144353359488449214330280008641",
+        "-0x0.0001f9bd0307d1de29dade2cb6b5c#100",
+        Greater,
+        "-0.99999999954565898016593584169260",
+        "-0x0.fffffffe0c725f3ceecd7f4cb#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "-0.81788191211590859704588528275571",
+        "-0x0.d160b580871c2b720143ea9da#100",
+        Less,
+        "-0.57538611195754904668824427596616",
+        "-0x0.934c81156e2606122aa6b4a3b#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+}
+
+#[test]
+fn sin_cos_prec_fail() {
+    assert_panic!(Float::NAN.sin_cos_prec(0));
+    assert_panic!(Float::NAN.sin_cos_prec_ref(0));
+}
+
+#[test]
+fn test_sin_cos_round() {
+    let test = |s,
+                s_hex,
+                rm,
+                sin_out: &str,
+                sin_out_hex: &str,
+                sin_o_out: Ordering,
+                cos_out: &str,
+                cos_out_hex: &str,
+                cos_o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ((sin, sin_o), (cos, cos_o)) = x.clone().sin_cos_round(rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_out_hex);
+        assert_eq!(sin_o, sin_o_out);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_out_hex);
+        assert_eq!(cos_o, cos_o_out);
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) = x.sin_cos_round_ref(rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(cos_o_alt, cos_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+                rug_sin_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(rug_sin_o, sin_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_cos_o, cos_o);
+        }
+    };
+    test(
+        "NaN", "NaN", Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", Ceiling, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", Nearest, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", Exact, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Ceiling, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Down, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Up, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Exact, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Down, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Up, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+        "0.54030230586813971740093660744256",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.84147098480789650665250232163084",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+        "0.54030230586813971740093660744256",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.84147098480789650665250232163084",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "-0.4609",
+        "-0x0.76#7",
+        Less,
+        "-0.8906",
+        "-0x0.e4#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "-0.4570",
+        "-0x0.75#7",
+        Greater,
+        "-0.8828",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "-0.4570",
+        "-0x0.75#7",
+        Greater,
+        "-0.8828",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "-0.4609",
+        "-0x0.76#7",
+        Less,
+        "-0.8906",
+        "-0x0.e4#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "-0.4609",
+        "-0x0.76#7",
+        Less,
+        "-0.8906",
+        "-0x0.e4#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "0.4570",
+        "0x0.75#7",
+        Less,
+        "-0.8906",
+        "-0x0.e4#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "0.4609",
+        "0x0.76#7",
+        Greater,
+        "-0.8828",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "0.4570",
+        "0x0.75#7",
+        Less,
+        "-0.8828",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "0.4609",
+        "0x0.76#7",
+        Greater,
+        "-0.8906",
+        "-0x0.e4#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "0.4609",
+        "0x0.76#7",
+        Greater,
+        "-0.8906",
+        "-0x0.e4#7",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "1.2246467991473515e-16",
+        "0x8.d313198a2e00E-14#50",
+        Less,
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+        Greater,
+        "-0.99999999999999911",
+        "-0x0.ffffffffffffc#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "1.2246467991473515e-16",
+        "0x8.d313198a2e00E-14#50",
+        Less,
+        "-0.99999999999999911",
+        "-0x0.ffffffffffffc#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+        Greater,
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "1.2246467991473535e-16",
+        "0x8.d313198a2e04E-14#50",
+        Greater,
+        "-1.0000000000000000",
+        "-0x1.0000000000000#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        Less,
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+        "-0.91173391478696497",
+        "-0x0.e96764d2aa0030#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        Less,
+        "-0.91173391478696497",
+        "-0x0.e96764d2aa0030#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+        "-0.91173391478696508",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+        "-1.000",
+        "-0x1.00#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "-0.00003010",
+        "-0x0.0001f9#9",
+        Greater,
+        "-0.9980",
+        "-0x0.ff8#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "-0.00003010",
+        "-0x0.0001f9#9",
+        Greater,
+        "-0.9980",
+        "-0x0.ff8#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+        "-1.000",
+        "-0x1.00#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+        "-1.000",
+        "-0x1.00#9",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "-0.81788191211590888",
+        "-0x0.d160b580871c4#51",
+        Less,
+        "-0.57538611195754941",
+        "-0x0.934c81156e262#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+        Greater,
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+        Greater,
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "-0.81788191211590888",
+        "-0x0.d160b580871c4#51",
+        Less,
+        "-0.57538611195754941",
+        "-0x0.934c81156e262#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "-0.81788191211590844",
+        "-0x0.d160b580871c2#51",
+        Greater,
+        "-0.57538611195754896",
+        "-0x0.934c81156e260#51",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+        "0.94495694631473766438828400767662",
+        "0x0.f1e8b2cc8cc1656b6998d964e#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+        "0.94495694631473766438828400767662",
+        "0x0.f1e8b2cc8cc1656b6998d964e#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.32719469679615224417334408526792",
+        "0x0.53c3081a2a031ab144c484c798#100",
+        Greater,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+}
+
+#[test]
+fn sin_cos_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.sin_cos_round(Exact));
+    assert_panic!(THREE.sin_cos_round_ref(Exact));
+}
+
+#[test]
+fn test_sin_cos_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: u64,
+                rm,
+                sin_out: &str,
+                sin_out_hex: &str,
+                sin_o_out: Ordering,
+                cos_out: &str,
+                cos_out_hex: &str,
+                cos_o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ((sin, sin_o), (cos, cos_o)) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_out_hex);
+        assert_eq!(sin_o, sin_o_out);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_out_hex);
+        assert_eq!(cos_o, cos_o_out);
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) = x.sin_cos_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(cos_o_alt, cos_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(rug_sin_o, sin_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_cos_o, cos_o);
+        }
+    };
+    test(
+        "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Down, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Up, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Up, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Down,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Up,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.84082",
+        "-0x0.d74#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.84082",
+        "-0x0.d74#10",
+        Greater,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", 1, Floor, "-0.50", "-0x0.8#1", Less, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", 1, Ceiling, "-0.25", "-0x0.4#1", Greater, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", 1, Down, "-0.25", "-0x0.4#1", Greater, "-0.50", "-0x0.8#1", Greater,
+    );
+    test(
+        "123.0", "0x7b.0#7", 1, Up, "-0.50", "-0x0.8#1", Less, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "123.0", "0x7b.0#7", 1, Nearest, "-0.50", "-0x0.8#1", Less, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "-0.45996",
+        "-0x0.75c#10",
+        Less,
+        "-0.88867",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.45947",
+        "-0x0.75a#10",
+        Greater,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "-0.45947",
+        "-0x0.75a#10",
+        Greater,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "-0.45996",
+        "-0x0.75c#10",
+        Less,
+        "-0.88867",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "-0.45996",
+        "-0x0.75c#10",
+        Less,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "0.45947",
+        "0x0.75a#10",
+        Less,
+        "-0.88867",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "0.45996",
+        "0x0.75c#10",
+        Greater,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "0.45947",
+        "0x0.75a#10",
+        Less,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "0.45996",
+        "0x0.75c#10",
+        Greater,
+        "-0.88867",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "0.45996",
+        "0x0.75c#10",
+        Greater,
+        "-0.88770",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "2.2e-16",
+        "0x1.0E-13#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "2.2e-16",
+        "0x1.0E-13#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.1e-16",
+        "0x8.0E-14#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "1.2230e-16",
+        "0x8.d0E-14#10",
+        Less,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "1.2230e-16",
+        "0x8.d0E-14#10",
+        Less,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.2251e-16",
+        "0x8.d4E-14#10",
+        Greater,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.41113",
+        "0x0.694#10",
+        Greater,
+        "-0.91113",
+        "-0x0.e94#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+        "-0.91113",
+        "-0x0.e94#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.41113",
+        "0x0.694#10",
+        Greater,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.41064",
+        "0x0.692#10",
+        Less,
+        "-0.91211",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Floor,
+        "-0.000031",
+        "-0x0.0002#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "-0.000015",
+        "-0x0.0001#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Down,
+        "-0.000015",
+        "-0x0.0001#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Up,
+        "-0.000031",
+        "-0x0.0002#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Nearest,
+        "-0.000031",
+        "-0x0.0002#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "-0.000030160",
+        "-0x0.0001fa0#10",
+        Less,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "-0.000030160",
+        "-0x0.0001fa0#10",
+        Less,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "-0.000030130",
+        "-0x0.0001f98#10",
+        Greater,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+        "-0.57617",
+        "-0x0.938#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "-0.81738",
+        "-0x0.d14#10",
+        Greater,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "-0.81738",
+        "-0x0.d14#10",
+        Greater,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+        "-0.57617",
+        "-0x0.938#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "-0.81836",
+        "-0x0.d18#10",
+        Less,
+        "-0.57520",
+        "-0x0.934#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+        "0.94434",
+        "0x0.f1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+        "0.94434",
+        "0x0.f1c#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+}
+
+#[test]
+fn sin_cos_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).sin_cos_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).sin_cos_prec_round_ref(0, Floor));
+    assert_panic!(THREE.sin_cos_prec_round(1, Exact));
+    assert_panic!(THREE.sin_cos_prec_round_ref(1, Exact));
+}
+
+#[test]
+fn test_sin_cos_rational_prec_round() {
+    let test = |s,
+                prec,
+                rm,
+                sin_out: &str,
+                sin_out_hex: &str,
+                sin_o_out: Ordering,
+                cos_out: &str,
+                cos_out_hex: &str,
+                cos_o_out: Ordering| {
+        let x = Rational::from_str(s).unwrap();
+
+        let ((sin, sin_o), (cos, cos_o)) = Float::sin_cos_rational_prec_round(x.clone(), prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(sin.to_string(), sin_out);
+        assert_eq!(to_hex_string(&sin), sin_out_hex);
+        assert_eq!(sin_o, sin_o_out);
+        assert_eq!(cos.to_string(), cos_out);
+        assert_eq!(to_hex_string(&cos), cos_out_hex);
+        assert_eq!(cos_o, cos_o_out);
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) =
+            Float::sin_cos_rational_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(cos_o_alt, cos_o);
+
+        if rm == Nearest {
+            let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) =
+                Float::sin_cos_rational_prec_ref(&x, prec);
+            assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+            assert_eq!(sin_o_alt, sin_o);
+            assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+            assert_eq!(cos_o_alt, cos_o);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+                rug_sin_cos_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(rug_sin_o, sin_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_cos_o, cos_o);
+        }
+    };
+    test(
+        "0", 1, Floor, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0", 1, Ceiling, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test("0", 1, Down, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal);
+    test(
+        "0", 1, Nearest, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0", 1, Exact, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1", 1, Floor, "0.50", "0x0.8#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1", 1, Ceiling, "1.0", "0x1.0#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1", 1, Down, "0.50", "0x0.8#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1", 1, Up, "1.0", "0x1.0#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1", 1, Nearest, "1.0", "0x1.0#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1",
+        10,
+        Floor,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1",
+        10,
+        Ceiling,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1",
+        10,
+        Down,
+        "0.84082",
+        "0x0.d74#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1",
+        10,
+        Up,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1",
+        10,
+        Nearest,
+        "0.84180",
+        "0x0.d78#10",
+        Greater,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.84147098480789650665250232163005",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "-1", 1, Floor, "-1.0", "-0x1.0#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "-1", 1, Down, "-0.50", "-0x0.8#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1", 1, Up, "-1.0", "-0x1.0#1", Less, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "-1", 1, Nearest, "-1.0", "-0x1.0#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "-1",
+        10,
+        Floor,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1",
+        10,
+        Ceiling,
+        "-0.84082",
+        "-0x0.d74#10",
+        Greater,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "-1",
+        10,
+        Down,
+        "-0.84082",
+        "-0x0.d74#10",
+        Greater,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1",
+        10,
+        Up,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+        "0.54102",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "-1",
+        10,
+        Nearest,
+        "-0.84180",
+        "-0x0.d78#10",
+        Less,
+        "0.54004",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.84147098480789650665250232163005",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Greater,
+        "0.54030230586813971740093660744335",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1/2", 1, Floor, "0.25", "0x0.4#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1/2", 1, Ceiling, "0.50", "0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1/2", 1, Down, "0.25", "0x0.4#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1/2", 1, Up, "0.50", "0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1/2", 1, Nearest, "0.50", "0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1/2",
+        10,
+        Floor,
+        "0.47900",
+        "0x0.7aa#10",
+        Less,
+        "0.87695",
+        "0x0.e08#10",
+        Less,
+    );
+    test(
+        "1/2",
+        10,
+        Ceiling,
+        "0.47949",
+        "0x0.7ac#10",
+        Greater,
+        "0.87793",
+        "0x0.e0c#10",
+        Greater,
+    );
+    test(
+        "1/2",
+        10,
+        Down,
+        "0.47900",
+        "0x0.7aa#10",
+        Less,
+        "0.87695",
+        "0x0.e08#10",
+        Less,
+    );
+    test(
+        "1/2",
+        10,
+        Up,
+        "0.47949",
+        "0x0.7ac#10",
+        Greater,
+        "0.87793",
+        "0x0.e0c#10",
+        Greater,
+    );
+    test(
+        "1/2",
+        10,
+        Nearest,
+        "0.47949",
+        "0x0.7ac#10",
+        Greater,
+        "0.87793",
+        "0x0.e0c#10",
+        Greater,
+    );
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.47942553860420300027328793521567",
+        "0x0.7abba1d12c17bfa1d92f0d93f8#100",
+        Greater,
+        "0.87758256189037271611628158260408",
+        "0x0.e0a94032dbea7cedbddd9da30#100",
+        Greater,
+    );
+    test(
+        "1/3", 1, Floor, "0.25", "0x0.4#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1/3", 1, Down, "0.25", "0x0.4#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1/3", 1, Up, "0.50", "0x0.8#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1/3", 1, Nearest, "0.25", "0x0.4#1", Less, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1/3",
+        10,
+        Floor,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+        "0.94434",
+        "0x0.f1c#10",
+        Less,
+    );
+    test(
+        "1/3",
+        10,
+        Ceiling,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        10,
+        Down,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+        "0.94434",
+        "0x0.f1c#10",
+        Less,
+    );
+    test(
+        "1/3",
+        10,
+        Up,
+        "0.32764",
+        "0x0.53e#10",
+        Greater,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        10,
+        Nearest,
+        "0.32715",
+        "0x0.53c#10",
+        Less,
+        "0.94531",
+        "0x0.f20#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.32719469679615224417334408526753",
+        "0x0.53c3081a2a031ab144c484c790#100",
+        Less,
+        "0.94495694631473766438828400767583",
+        "0x0.f1e8b2cc8cc1656b6998d964d#100",
+        Less,
+    );
+    test(
+        "22/7",
+        1,
+        Floor,
+        "-0.0020",
+        "-0x0.008#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "22/7",
+        1,
+        Ceiling,
+        "-0.00098",
+        "-0x0.004#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "22/7",
+        1,
+        Down,
+        "-0.00098",
+        "-0x0.004#1",
+        Greater,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "22/7",
+        1,
+        Up,
+        "-0.0020",
+        "-0x0.008#1",
+        Less,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "22/7",
+        1,
+        Nearest,
+        "-0.00098",
+        "-0x0.004#1",
+        Greater,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "22/7",
+        10,
+        Floor,
+        "-0.0012646",
+        "-0x0.0052e#10",
+        Less,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "22/7",
+        10,
+        Ceiling,
+        "-0.0012627",
+        "-0x0.0052c#10",
+        Greater,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        10,
+        Down,
+        "-0.0012627",
+        "-0x0.0052c#10",
+        Greater,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        10,
+        Up,
+        "-0.0012646",
+        "-0x0.0052e#10",
+        Less,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "22/7",
+        10,
+        Nearest,
+        "-0.0012646",
+        "-0x0.0052e#10",
+        Less,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "-0.0012644889303773534003603504756467",
+        "-0x0.0052de9a9a24d90da7cdffe30130#100",
+        Less,
+        "-0.99999920053355290326833573965634",
+        "-0x0.fffff29651586c28bc01f9fb2#100",
+        Greater,
+    );
+    test(
+        "1000000", 1, Floor, "-0.50", "-0x0.8#1", Less, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1000000", 1, Ceiling, "-0.25", "-0x0.4#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1000000", 1, Down, "-0.25", "-0x0.4#1", Greater, "0.50", "0x0.8#1", Less,
+    );
+    test(
+        "1000000", 1, Up, "-0.50", "-0x0.8#1", Less, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1000000", 1, Nearest, "-0.25", "-0x0.4#1", Greater, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Floor,
+        "-0.35010",
+        "-0x0.59a#10",
+        Less,
+        "0.93652",
+        "0x0.efc#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "-0.34961",
+        "-0x0.598#10",
+        Greater,
+        "0.93750",
+        "0x0.f00#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Down,
+        "-0.34961",
+        "-0x0.598#10",
+        Greater,
+        "0.93652",
+        "0x0.efc#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Up,
+        "-0.35010",
+        "-0x0.59a#10",
+        Less,
+        "0.93750",
+        "0x0.f00#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "-0.35010",
+        "-0x0.59a#10",
+        Less,
+        "0.93652",
+        "0x0.efc#10",
+        Less,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "-0.34999350217129295211765248678059",
+        "-0x0.59992c95a3619d264732d26e98#100",
+        Greater,
+        "0.93675212753314478693853253507492",
+        "0x0.efcefcc836996357644d418cd#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sin_cos_rational_prec_fail() {
+    Float::sin_cos_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn sin_cos_rational_prec_round_fail_1() {
+    Float::sin_cos_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn sin_cos_rational_prec_round_fail_2() {
+    Float::sin_cos_rational_prec_round(Rational::ONE, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn sin_cos_rational_prec_round_ref_fail() {
+    Float::sin_cos_rational_prec_round_ref(&Rational::ONE, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_cos_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let ((sin, sin_o), (cos, cos_o)) = x.clone().sin_cos_prec_round(prec, rm);
+    assert!(sin.is_valid());
+    assert!(cos.is_valid());
+
+    let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) = x.sin_cos_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(sin_o_alt, sin_o);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(cos_o_alt, cos_o);
+
+    // sin_cos agrees with sin and cos
+    let (sin_alt, sin_o_alt) = x.sin_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(sin_o_alt, sin_o);
+    let (cos_alt, cos_o_alt) = x.cos_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(cos_o_alt, cos_o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+            rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_sin_o, sin_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_cos_o, cos_o);
+    }
+
+    if sin_o == Equal && cos_o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let ((s, s_o), (c, c_o)) = x.sin_cos_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sin.clone()));
+            assert_eq!(s_o, Equal);
+            assert_eq!(ComparableFloat(c), ComparableFloat(cos.clone()));
+            assert_eq!(c_o, Equal);
+        }
+    } else {
+        assert_panic!(x.sin_cos_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn sin_cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        sin_cos_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        for x in [Float::NAN, Float::INFINITY, Float::NEGATIVE_INFINITY] {
+            let ((sin, sin_o), (cos, cos_o)) = x.sin_cos_prec_round(prec, rm);
+            assert!(sin.is_nan());
+            assert_eq!(sin_o, Equal);
+            assert!(cos.is_nan());
+            assert_eq!(cos_o, Equal);
+        }
+        for x in [Float::ZERO, Float::NEGATIVE_ZERO] {
+            let ((sin, sin_o), (cos, cos_o)) = x.sin_cos_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(sin), ComparableFloat(x));
+            assert_eq!(sin_o, Equal);
+            assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+            assert_eq!(cos_o, Equal);
+        }
+    });
+}
+
+#[test]
+fn sin_cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let ((sin, sin_o), (cos, cos_o)) = x.clone().sin_cos_prec(prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) = x.sin_cos_prec_ref(prec);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(cos_o_alt, cos_o);
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) = x.sin_cos_prec_round_ref(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(cos_o_alt, cos_o);
+
+        let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+            rug_sin_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_sin_o, sin_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_cos_o, cos_o);
+    });
+}
+
+#[test]
+fn sin_cos_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        let ((sin, sin_o), (cos, cos_o)) = x.clone().sin_cos_round(rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) = x.sin_cos_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(cos_o_alt, cos_o);
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) =
+            x.sin_cos_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(cos_o_alt, cos_o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+                rug_sin_cos_round(&rug::Float::exact_from(&x), rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(rug_sin_o, sin_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_cos_o, cos_o);
+        }
+    });
+}
+
+#[test]
+fn sin_cos_properties() {
+    float_gen().test_properties(|x| {
+        let (sin, cos) = x.clone().sin_cos();
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let (sin_alt, cos_alt) = (&x).sin_cos();
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+        assert_eq!(ComparableFloatRef(&(&x).sin()), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&(&x).cos()), ComparableFloatRef(&cos));
+
+        let (rug_sin, rug_cos) = rug_sin_cos(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_cos_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let ((sin, sin_o), (cos, cos_o)) = Float::sin_cos_rational_prec_round(x.clone(), prec, rm);
+    assert!(sin.is_valid());
+    assert!(cos.is_valid());
+
+    let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) =
+        Float::sin_cos_rational_prec_round_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(sin_o_alt, sin_o);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(cos_o_alt, cos_o);
+
+    let (sin_alt, sin_o_alt) = Float::sin_rational_prec_round_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(sin_o_alt, sin_o);
+    let (cos_alt, cos_o_alt) = Float::cos_rational_prec_round_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(cos_o_alt, cos_o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) =
+            rug_sin_cos_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_sin_o, sin_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_cos_o, cos_o);
+    }
+
+    if sin_o == Equal && cos_o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let ((s, s_o), (c, c_o)) = Float::sin_cos_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sin.clone()));
+            assert_eq!(s_o, Equal);
+            assert_eq!(ComparableFloat(c), ComparableFloat(cos.clone()));
+            assert_eq!(c_o, Equal);
+        }
+    } else {
+        assert_panic!(Float::sin_cos_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn sin_cos_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        sin_cos_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let ((sin, sin_o), (cos, cos_o)) =
+            Float::sin_cos_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(sin_o, Equal);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(cos_o, Equal);
+    });
+}
+
+#[test]
+fn sin_cos_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        let ((sin, sin_o), (cos, cos_o)) = Float::sin_cos_rational_prec(x.clone(), prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) =
+            Float::sin_cos_rational_prec_ref(&x, prec);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(cos_o_alt, cos_o);
+
+        let ((sin_alt, sin_o_alt), (cos_alt, cos_o_alt)) =
+            Float::sin_cos_rational_prec_round_ref(&x, prec, Nearest);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(sin_o_alt, sin_o);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(cos_o_alt, cos_o);
+
+        let ((rug_sin, rug_sin_o), (rug_cos, rug_cos_o)) = rug_sin_cos_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(rug_sin_o, sin_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_cos_o, cos_o);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_sin_cos() {
+    fn test<T: PrimitiveFloat>(x: T, sin: T, cos: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let (sin_out, cos_out) = primitive_float_sin_cos(x);
+        assert_eq!(NiceFloat(sin_out), NiceFloat(sin));
+        assert_eq!(NiceFloat(cos_out), NiceFloat(cos));
+    }
+    test::<f32>(f32::NAN, f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::NAN, f32::NAN);
+    test::<f32>(0.0, 0.0, 1.0);
+    test::<f32>(-0.0, -0.0, 1.0);
+    test::<f32>(1.0, 0.84147096, 0.5403023);
+    test::<f32>(-1.0, -0.84147096, 0.5403023);
+    test::<f32>(0.5, 0.47942555, 0.87758255);
+    test::<f32>(10.0, -0.5440211, -0.8390715);
+
+    test::<f64>(f64::NAN, f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::NAN, f64::NAN);
+    test::<f64>(0.0, 0.0, 1.0);
+    test::<f64>(-0.0, -0.0, 1.0);
+    test::<f64>(1.0, 0.8414709848078965, 0.5403023058681398);
+    test::<f64>(-1.0, -0.8414709848078965, 0.5403023058681398);
+    test::<f64>(0.5, 0.479425538604203, 0.8775825618903728);
+    test::<f64>(10.0, -0.5440211108893698, -0.8390715290764524);
+    test::<f64>(1.0e300, -0.8178819121159085, -0.5753861119575491);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_sin_cos_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let (sin, cos) = primitive_float_sin_cos(x);
+        assert_eq!(NiceFloat(sin), NiceFloat(primitive_float_sin(x)));
+        assert_eq!(NiceFloat(cos), NiceFloat(primitive_float_cos(x)));
+    });
+}
+
+#[test]
+fn primitive_float_sin_cos_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_sin_cos_properties_helper);
+}
@@ -0,0 +1,3857 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Cosh, Sinh, SinhCosh};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::cosh::primitive_float_cosh;
+use malachite_float::float::arithmetic::sinh::primitive_float_sinh;
+use malachite_float::float::arithmetic::sinh_cosh::primitive_float_sinh_cosh;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::sinh_cosh::{
+    rug_sinh_cosh, rug_sinh_cosh_prec, rug_sinh_cosh_prec_round, rug_sinh_cosh_rational_prec,
+    rug_sinh_cosh_rational_prec_round, rug_sinh_cosh_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::rational_unsigned_pair_gen_var_3;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sinh_cosh() {
+    let test =
+        |s, s_hex, sinh_out: &str, sinh_out_hex: &str, cosh_out: &str, cosh_out_hex: &str| {
+            let x = parse_hex_string(s_hex);
+            assert_eq!(x.to_string(), s);
+
+            let (sinh, cosh) = x.clone().sinh_cosh();
+            assert!(sinh.is_valid());
+            assert!(cosh.is_valid());
+            assert_eq!(sinh.to_string(), sinh_out);
+            assert_eq!(to_hex_string(&sinh), sinh_out_hex);
+            assert_eq!(cosh.to_string(), cosh_out);
+            assert_eq!(to_hex_string(&cosh), cosh_out_hex);
+
+            let (sinh_alt, cosh_alt) = (&x).sinh_cosh();
+            assert!(sinh_alt.is_valid());
+            assert!(cosh_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+            assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+
+            let (rug_sinh, rug_cosh) = rug_sinh_cosh(&rug::Float::exact_from(&x));
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+        };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "Infinity", "Infinity",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "1.1752011936438014568823818505953",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.317e53",
+        "0x1.60E+44#7",
+        "1.317e53",
+        "0x1.60E+44#7",
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "-1.317e53",
+        "-0x1.60E+44#7",
+        "1.317e53",
+        "0x1.60E+44#7",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "11.548739357257745",
+        "0xb.8c7a2eb97338#50",
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        "7.463e153",
+        "0x8.e8E+127#9",
+        "7.463e153",
+        "0x8.e8E+127#9",
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.33954055725615013910126061133886",
+        "0x0.56ec214514ae05405cea1145d8#100",
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+    );
+}
+
+#[test]
+fn test_sinh_cosh_prec() {
+    let test = |s,
+                s_hex,
+                prec: u64,
+                sinh_out: &str,
+                sinh_out_hex: &str,
+                sinh_o_out: Ordering,
+                cosh_out: &str,
+                cosh_out_hex: &str,
+                cosh_o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ((sinh, sinh_o), (cosh, cosh_o)) = x.clone().sinh_cosh_prec(prec);
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+        assert_eq!(sinh.to_string(), sinh_out);
+        assert_eq!(to_hex_string(&sinh), sinh_out_hex);
+        assert_eq!(sinh_o, sinh_o_out);
+        assert_eq!(cosh.to_string(), cosh_out);
+        assert_eq!(to_hex_string(&cosh), cosh_out_hex);
+        assert_eq!(cosh_o, cosh_o_out);
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) = x.sinh_cosh_prec_ref(prec);
+        assert!(sinh_alt.is_valid());
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+            rug_sinh_cosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(rug_sinh_o, sinh_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_cosh_o, cosh_o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 100, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Less, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.1752011936438014568823818505953",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Less,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.1758",
+        "-0x1.2d0#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.1752011936438014568823818505953",
+        "-0x1.2cd9fc44eb9825a80249487f0#100",
+        Greater,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.1752011936438014568823818505953",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Less,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "-9.6e52",
+        "-0x1.0E+44#1",
+        Greater,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "-1.3095e53",
+        "-0x1.5e0E+44#10",
+        Greater,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-1.3097586593745313380944905126876e53",
+        "-0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Less,
+        "1.3097586593745313380944905126876e53",
+        "0x1.5e1152f9a8119393a111d9f34E+44#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Less,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "11.547",
+        "0xb.8c#10",
+        Less,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "11.548739357257746958372486842080",
+        "0xb.8c7a2eb97338aae765800b8c#100",
+        Greater,
+        "11.591953275521519213439083247288",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "7.5469",
+        "0x7.8c#10",
+        Greater,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "7.5441371028169747261864448047412",
+        "0x7.8b4c91b5239bca56b32f58188#100",
+        Greater,
+        "7.6101251386622872728027748834452",
+        "0x7.9c31293d05a112a04c47df6f8#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "7.4732770023626700693523672142916e153",
+        "0x8.eb09df85401ac1b9555c160fE+127#100",
+        Less,
+        "7.4732770023626700693523672142916e153",
+        "0x8.eb09df85401ac1b9555c160fE+127#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Less,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.33954055725615013910126061133886",
+        "0x0.56ec214514ae05405cea1145d8#100",
+        Greater,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+}
+
+#[test]
+fn sinh_cosh_prec_fail() {
+    assert_panic!(Float::NAN.sinh_cosh_prec(0));
+    assert_panic!(Float::NAN.sinh_cosh_prec_ref(0));
+}
+
+#[test]
+fn test_sinh_cosh_round() {
+    let test = |s,
+                s_hex,
+                rm,
+                sinh_out: &str,
+                sinh_out_hex: &str,
+                sinh_o_out: Ordering,
+                cosh_out: &str,
+                cosh_out_hex: &str,
+                cosh_o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ((sinh, sinh_o), (cosh, cosh_o)) = x.clone().sinh_cosh_round(rm);
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+        assert_eq!(sinh.to_string(), sinh_out);
+        assert_eq!(to_hex_string(&sinh), sinh_out_hex);
+        assert_eq!(sinh_o, sinh_o_out);
+        assert_eq!(cosh.to_string(), cosh_out);
+        assert_eq!(to_hex_string(&cosh), cosh_out_hex);
+        assert_eq!(cosh_o, cosh_o_out);
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) = x.sinh_cosh_round_ref(rm);
+        assert!(sinh_alt.is_valid());
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+                rug_sinh_cosh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_sinh_o, sinh_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_cosh_o, cosh_o);
+        }
+    };
+    test(
+        "NaN", "NaN", Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", Ceiling, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", Nearest, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", Exact, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Equal, "Infinity", "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", Down, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Up, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal, "Infinity", "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Up,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Down, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Up, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Less, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.1752011936438014568823818505953",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Less,
+        "1.5430806348152437784779056207559",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.1752011936438014568823818505969",
+        "0x1.2cd9fc44eb9825a80249487f2#100",
+        Greater,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.1752011936438014568823818505953",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Less,
+        "1.5430806348152437784779056207559",
+        "0x1.8b07551d9f5504c2bd2810018#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.1752011936438014568823818505969",
+        "0x1.2cd9fc44eb9825a80249487f2#100",
+        Greater,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.1752011936438014568823818505953",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Less,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "-1.317e53",
+        "-0x1.60E+44#7",
+        Less,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-1.302e53",
+        "-0x1.5cE+44#7",
+        Greater,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Down,
+        "-1.302e53",
+        "-0x1.5cE+44#7",
+        Greater,
+        "1.302e53",
+        "0x1.5cE+44#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "-1.317e53",
+        "-0x1.60E+44#7",
+        Less,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-1.317e53",
+        "-0x1.60E+44#7",
+        Less,
+        "1.317e53",
+        "0x1.60E+44#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "11.548739357257745",
+        "0xb.8c7a2eb97338#50",
+        Less,
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "11.548739357257759",
+        "0xb.8c7a2eb9733c#50",
+        Greater,
+        "11.591953275521533",
+        "0xb.978a3ff72004#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "11.548739357257745",
+        "0xb.8c7a2eb97338#50",
+        Less,
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "11.548739357257759",
+        "0xb.8c7a2eb9733c#50",
+        Greater,
+        "11.591953275521533",
+        "0xb.978a3ff72004#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "11.548739357257745",
+        "0xb.8c7a2eb97338#50",
+        Less,
+        "11.591953275521519",
+        "0xb.978a3ff72000#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+        Less,
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "7.5441371028169755",
+        "0x7.8b4c91b5239c0#53",
+        Greater,
+        "7.6101251386622879",
+        "0x7.9c31293d05a14#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+        Less,
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "7.5441371028169755",
+        "0x7.8b4c91b5239c0#53",
+        Greater,
+        "7.6101251386622879",
+        "0x7.9c31293d05a14#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+        Less,
+        "7.6101251386622870",
+        "0x7.9c31293d05a10#53",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "7.490e153",
+        "0x8.f0E+127#9",
+        Greater,
+        "7.490e153",
+        "0x8.f0E+127#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "7.490e153",
+        "0x8.f0E+127#9",
+        Greater,
+        "7.490e153",
+        "0x8.f0E+127#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+        "7.463e153",
+        "0x8.e8E+127#9",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "2.0985787164673868e323228496",
+        "0x7.ffffffffffffE+268435455#51",
+        Less,
+        "2.0985787164673868e323228496",
+        "0x7.ffffffffffffE+268435455#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "2.0985787164673868e323228496",
+        "0x7.ffffffffffffE+268435455#51",
+        Less,
+        "2.0985787164673868e323228496",
+        "0x7.ffffffffffffE+268435455#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.33954055725615013910126061133846",
+        "0x0.56ec214514ae05405cea1145d0#100",
+        Less,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.33954055725615013910126061133886",
+        "0x0.56ec214514ae05405cea1145d8#100",
+        Greater,
+        "1.0560718678299393895268647082654",
+        "0x1.0e5ab9d68e232bca22825de24#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.33954055725615013910126061133846",
+        "0x0.56ec214514ae05405cea1145d0#100",
+        Less,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.33954055725615013910126061133886",
+        "0x0.56ec214514ae05405cea1145d8#100",
+        Greater,
+        "1.0560718678299393895268647082654",
+        "0x1.0e5ab9d68e232bca22825de24#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.33954055725615013910126061133886",
+        "0x0.56ec214514ae05405cea1145d8#100",
+        Greater,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+}
+
+#[test]
+fn sinh_cosh_round_fail() {
+    assert_panic!(Float::ONE_HALF.sinh_cosh_round(Exact));
+    assert_panic!(Float::ONE_HALF.sinh_cosh_round_ref(Exact));
+}
+
+#[test]
+fn test_sinh_cosh_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: u64,
+                rm,
+                sinh_out: &str,
+                sinh_out_hex: &str,
+                sinh_o_out: Ordering,
+                cosh_out: &str,
+                cosh_out_hex: &str,
+                cosh_o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ((sinh, sinh_o), (cosh, cosh_o)) = x.clone().sinh_cosh_prec_round(prec, rm);
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+        assert_eq!(sinh.to_string(), sinh_out);
+        assert_eq!(to_hex_string(&sinh), sinh_out_hex);
+        assert_eq!(sinh_o, sinh_o_out);
+        assert_eq!(cosh.to_string(), cosh_out);
+        assert_eq!(to_hex_string(&cosh), cosh_out_hex);
+        assert_eq!(cosh_o, cosh_o_out);
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) = x.sinh_cosh_prec_round_ref(prec, rm);
+        assert!(sinh_alt.is_valid());
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+                rug_sinh_cosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_sinh_o, sinh_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_cosh_o, cosh_o);
+        }
+    };
+    test(
+        "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Down, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Up, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal, "Infinity", "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Equal, "Infinity", "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Equal, "Infinity", "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Equal, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal, "Infinity", "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal, "Infinity", "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Up,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Less, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "1.1738",
+        "0x1.2c8#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Down,
+        "1.1738",
+        "0x1.2c8#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Up,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Floor, "-2.0", "-0x2.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Down, "-1.0", "-0x1.0#1", Greater, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Up, "-2.0", "-0x2.0#1", Less, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-1.1758",
+        "-0x1.2d0#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-1.1738",
+        "-0x1.2c8#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-1.1738",
+        "-0x1.2c8#10",
+        Greater,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "-1.1758",
+        "-0x1.2d0#10",
+        Less,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-1.1758",
+        "-0x1.2d0#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.1738",
+        "0x1.2c8#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.1738",
+        "0x1.2c8#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "-1.9e53",
+        "-0x2.0E+44#1",
+        Less,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-9.6e52",
+        "-0x1.0E+44#1",
+        Greater,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Down,
+        "-9.6e52",
+        "-0x1.0E+44#1",
+        Greater,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Up,
+        "-1.9e53",
+        "-0x2.0E+44#1",
+        Less,
+        "1.9e53",
+        "0x2.0E+44#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "-9.6e52",
+        "-0x1.0E+44#1",
+        Greater,
+        "9.6e52",
+        "0x1.0E+44#1",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-1.3114e53",
+        "-0x1.5e8E+44#10",
+        Less,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-1.3095e53",
+        "-0x1.5e0E+44#10",
+        Greater,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-1.3095e53",
+        "-0x1.5e0E+44#10",
+        Greater,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "-1.3114e53",
+        "-0x1.5e8E+44#10",
+        Less,
+        "1.3114e53",
+        "0x1.5e8E+44#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-1.3095e53",
+        "-0x1.5e0E+44#10",
+        Greater,
+        "1.3095e53",
+        "0x1.5e0E+44#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "8.0",
+        "0x8.0#1",
+        Less,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "8.0",
+        "0x8.0#1",
+        Less,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "8.0",
+        "0x8.0#1",
+        Less,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "11.547",
+        "0xb.8c#10",
+        Less,
+        "11.578",
+        "0xb.94#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "11.562",
+        "0xb.90#10",
+        Greater,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "11.547",
+        "0xb.8c#10",
+        Less,
+        "11.578",
+        "0xb.94#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "11.562",
+        "0xb.90#10",
+        Greater,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "11.547",
+        "0xb.8c#10",
+        Less,
+        "11.594",
+        "0xb.98#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "4.0",
+        "0x4.0#1",
+        Less,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "4.0",
+        "0x4.0#1",
+        Less,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "7.5391",
+        "0x7.8a#10",
+        Less,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "7.5469",
+        "0x7.8c#10",
+        Greater,
+        "7.6172",
+        "0x7.9e#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "7.5391",
+        "0x7.8a#10",
+        Less,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "7.5469",
+        "0x7.8c#10",
+        Greater,
+        "7.6172",
+        "0x7.9e#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "7.5469",
+        "0x7.8c#10",
+        Greater,
+        "7.6094",
+        "0x7.9c#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Floor,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "1.3e154",
+        "0x1.0E+128#1",
+        Greater,
+        "1.3e154",
+        "0x1.0E+128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Down,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Up,
+        "1.3e154",
+        "0x1.0E+128#1",
+        Greater,
+        "1.3e154",
+        "0x1.0E+128#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Nearest,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+        "6.7e153",
+        "0x8.0E+127#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "7.4633e153",
+        "0x8.e8E+127#10",
+        Less,
+        "7.4633e153",
+        "0x8.e8E+127#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "7.4633e153",
+        "0x8.e8E+127#10",
+        Less,
+        "7.4633e153",
+        "0x8.e8E+127#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+        "7.4764e153",
+        "0x8.ecE+127#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+        "1.0e323228496",
+        "0x4.0E+268435455#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+        "2.0965e323228496",
+        "0x7.feE+268435455#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Greater,
+        "Infinity",
+        "Infinity",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.9040e-31",
+        "0x1.008E-25#10",
+        Greater,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.9040e-31",
+        "0x1.008E-25#10",
+        Greater,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+        "1.0547",
+        "0x1.0e0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+        "1.0547",
+        "0x1.0e0#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+}
+
+#[test]
+fn sinh_cosh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).sinh_cosh_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).sinh_cosh_prec_round_ref(0, Floor));
+    assert_panic!(Float::ONE_HALF.sinh_cosh_prec_round(1, Exact));
+    assert_panic!(Float::ONE_HALF.sinh_cosh_prec_round_ref(1, Exact));
+}
+
+#[test]
+fn test_sinh_cosh_rational_prec_round() {
+    let test = |s,
+                prec,
+                rm,
+                sinh_out: &str,
+                sinh_out_hex: &str,
+                sinh_o_out: Ordering,
+                cosh_out: &str,
+                cosh_out_hex: &str,
+                cosh_o_out: Ordering| {
+        let x = Rational::from_str(s).unwrap();
+
+        let ((sinh, sinh_o), (cosh, cosh_o)) =
+            Float::sinh_cosh_rational_prec_round(x.clone(), prec, rm);
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+        assert_eq!(sinh.to_string(), sinh_out);
+        assert_eq!(to_hex_string(&sinh), sinh_out_hex);
+        assert_eq!(sinh_o, sinh_o_out);
+        assert_eq!(cosh.to_string(), cosh_out);
+        assert_eq!(to_hex_string(&cosh), cosh_out_hex);
+        assert_eq!(cosh_o, cosh_o_out);
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) =
+            Float::sinh_cosh_rational_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        if rm == Nearest {
+            let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) =
+                Float::sinh_cosh_rational_prec_ref(&x, prec);
+            assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+            assert_eq!(sinh_o_alt, sinh_o);
+            assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+            assert_eq!(cosh_o_alt, cosh_o);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+                rug_sinh_cosh_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_sinh_o, sinh_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_cosh_o, cosh_o);
+        }
+    };
+    test(
+        "0", 1, Floor, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0", 1, Ceiling, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test("0", 1, Down, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal);
+    test(
+        "0", 1, Nearest, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0", 1, Exact, "0.0", "0x0.0", Equal, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "0",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Ceiling,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Down,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Up,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        10,
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0",
+        100,
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1", 1, Floor, "1.0", "0x1.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1", 1, Ceiling, "2.0", "0x2.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test("1", 1, Down, "1.0", "0x1.0#1", Less, "1.0", "0x1.0#1", Less);
+    test(
+        "1", 1, Up, "2.0", "0x2.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1", 1, Nearest, "1.0", "0x1.0#1", Less, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1",
+        10,
+        Floor,
+        "1.1738",
+        "0x1.2c8#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1",
+        10,
+        Ceiling,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1",
+        10,
+        Down,
+        "1.1738",
+        "0x1.2c8#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1",
+        10,
+        Up,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "1",
+        10,
+        Nearest,
+        "1.1758",
+        "0x1.2d0#10",
+        Greater,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "1",
+        100,
+        Nearest,
+        "1.1752011936438014568823818505953",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Less,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "-1", 1, Floor, "-2.0", "-0x2.0#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "-1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1", 1, Down, "-1.0", "-0x1.0#1", Greater, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "-1", 1, Up, "-2.0", "-0x2.0#1", Less, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1", 1, Nearest, "-1.0", "-0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "-1",
+        10,
+        Floor,
+        "-1.1758",
+        "-0x1.2d0#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1",
+        10,
+        Ceiling,
+        "-1.1738",
+        "-0x1.2c8#10",
+        Greater,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "-1",
+        10,
+        Down,
+        "-1.1738",
+        "-0x1.2c8#10",
+        Greater,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1",
+        10,
+        Up,
+        "-1.1758",
+        "-0x1.2d0#10",
+        Less,
+        "1.5449",
+        "0x1.8b8#10",
+        Greater,
+    );
+    test(
+        "-1",
+        10,
+        Nearest,
+        "-1.1758",
+        "-0x1.2d0#10",
+        Less,
+        "1.5430",
+        "0x1.8b0#10",
+        Less,
+    );
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-1.1752011936438014568823818505953",
+        "-0x1.2cd9fc44eb9825a80249487f0#100",
+        Greater,
+        "1.5430806348152437784779056207575",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Greater,
+    );
+    test(
+        "1/2", 1, Floor, "0.50", "0x0.8#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1/2", 1, Down, "0.50", "0x0.8#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1/2", 1, Up, "1.0", "0x1.0#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1/2", 1, Nearest, "0.50", "0x0.8#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1/2",
+        10,
+        Floor,
+        "0.52051",
+        "0x0.854#10",
+        Less,
+        "1.1270",
+        "0x1.208#10",
+        Less,
+    );
+    test(
+        "1/2",
+        10,
+        Ceiling,
+        "0.52148",
+        "0x0.858#10",
+        Greater,
+        "1.1289",
+        "0x1.210#10",
+        Greater,
+    );
+    test(
+        "1/2",
+        10,
+        Down,
+        "0.52051",
+        "0x0.854#10",
+        Less,
+        "1.1270",
+        "0x1.208#10",
+        Less,
+    );
+    test(
+        "1/2",
+        10,
+        Up,
+        "0.52148",
+        "0x0.858#10",
+        Greater,
+        "1.1289",
+        "0x1.210#10",
+        Greater,
+    );
+    test(
+        "1/2",
+        10,
+        Nearest,
+        "0.52148",
+        "0x0.858#10",
+        Greater,
+        "1.1270",
+        "0x1.208#10",
+        Less,
+    );
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.52109530549374736162242562641114",
+        "0x0.8566807f31dcb651abc4abb0d#100",
+        Less,
+        "1.1276259652063807852262251614023",
+        "0x1.20ac1862ae8d0645823a4f060#100",
+        Less,
+    );
+    test(
+        "1/3", 1, Floor, "0.25", "0x0.4#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1/3", 1, Down, "0.25", "0x0.4#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1/3", 1, Up, "0.50", "0x0.8#1", Greater, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "1/3", 1, Nearest, "0.25", "0x0.4#1", Less, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1/3",
+        10,
+        Floor,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+        "1.0547",
+        "0x1.0e0#10",
+        Less,
+    );
+    test(
+        "1/3",
+        10,
+        Ceiling,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        10,
+        Down,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+        "1.0547",
+        "0x1.0e0#10",
+        Less,
+    );
+    test(
+        "1/3",
+        10,
+        Up,
+        "0.33984",
+        "0x0.570#10",
+        Greater,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        10,
+        Nearest,
+        "0.33936",
+        "0x0.56e#10",
+        Less,
+        "1.0566",
+        "0x1.0e8#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.33954055725615013910126061133846",
+        "0x0.56ec214514ae05405cea1145d0#100",
+        Less,
+        "1.0560718678299393895268647082638",
+        "0x1.0e5ab9d68e232bca22825de22#100",
+        Less,
+    );
+    test(
+        "22/7", 1, Floor, "8.0", "0x8.0#1", Less, "8.0", "0x8.0#1", Less,
+    );
+    test(
+        "22/7",
+        1,
+        Ceiling,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "22/7", 1, Down, "8.0", "0x8.0#1", Less, "8.0", "0x8.0#1", Less,
+    );
+    test(
+        "22/7",
+        1,
+        Up,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+        "16.0",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "22/7", 1, Nearest, "8.0", "0x8.0#1", Less, "8.0", "0x8.0#1", Less,
+    );
+    test(
+        "22/7",
+        10,
+        Floor,
+        "11.562",
+        "0xb.90#10",
+        Less,
+        "11.594",
+        "0xb.98#10",
+        Less,
+    );
+    test(
+        "22/7",
+        10,
+        Ceiling,
+        "11.578",
+        "0xb.94#10",
+        Greater,
+        "11.609",
+        "0xb.9c#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        10,
+        Down,
+        "11.562",
+        "0xb.90#10",
+        Less,
+        "11.594",
+        "0xb.98#10",
+        Less,
+    );
+    test(
+        "22/7",
+        10,
+        Up,
+        "11.578",
+        "0xb.94#10",
+        Greater,
+        "11.609",
+        "0xb.9c#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        10,
+        Nearest,
+        "11.562",
+        "0xb.90#10",
+        Less,
+        "11.609",
+        "0xb.9c#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "11.563406494500513816416460850618",
+        "0xb.903b68743bd476d7129c12b9#100",
+        Greater,
+        "11.606565803761966414401052945153",
+        "0xb.9b47e5820793d583f2840c93#100",
+        Greater,
+    );
+    test(
+        "1000000",
+        1,
+        Floor,
+        "1.5e434294",
+        "0x4.0E+360673#1",
+        Less,
+        "1.5e434294",
+        "0x4.0E+360673#1",
+        Less,
+    );
+    test(
+        "1000000",
+        1,
+        Ceiling,
+        "2.9e434294",
+        "0x8.0E+360673#1",
+        Greater,
+        "2.9e434294",
+        "0x8.0E+360673#1",
+        Greater,
+    );
+    test(
+        "1000000",
+        1,
+        Down,
+        "1.5e434294",
+        "0x4.0E+360673#1",
+        Less,
+        "1.5e434294",
+        "0x4.0E+360673#1",
+        Less,
+    );
+    test(
+        "1000000",
+        1,
+        Up,
+        "2.9e434294",
+        "0x8.0E+360673#1",
+        Greater,
+        "2.9e434294",
+        "0x8.0E+360673#1",
+        Greater,
+    );
+    test(
+        "1000000",
+        1,
+        Nearest,
+        "1.5e434294",
+        "0x4.0E+360673#1",
+        Less,
+        "1.5e434294",
+        "0x4.0E+360673#1",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Floor,
+        "1.5145e434294",
+        "0x4.1cE+360673#10",
+        Less,
+        "1.5145e434294",
+        "0x4.1cE+360673#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Ceiling,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Down,
+        "1.5145e434294",
+        "0x4.1cE+360673#10",
+        Less,
+        "1.5145e434294",
+        "0x4.1cE+360673#10",
+        Less,
+    );
+    test(
+        "1000000",
+        10,
+        Up,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        10,
+        Nearest,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+        "1.5174e434294",
+        "0x4.1eE+360673#10",
+        Greater,
+    );
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "1.5166076984010437725432010707093e434294",
+        "0x4.1d6ffdeb8a9180f8c69cf6170E+360673#100",
+        Greater,
+        "1.5166076984010437725432010707093e434294",
+        "0x4.1d6ffdeb8a9180f8c69cf6170E+360673#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn sinh_cosh_rational_prec_fail() {
+    Float::sinh_cosh_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn sinh_cosh_rational_prec_round_fail_1() {
+    Float::sinh_cosh_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn sinh_cosh_rational_prec_round_fail_2() {
+    Float::sinh_cosh_rational_prec_round(Rational::ONE, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn sinh_cosh_rational_prec_round_ref_fail() {
+    Float::sinh_cosh_rational_prec_round_ref(&Rational::ONE, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sinh_cosh_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let ((sinh, sinh_o), (cosh, cosh_o)) = x.clone().sinh_cosh_prec_round(prec, rm);
+    assert!(sinh.is_valid());
+    assert!(cosh.is_valid());
+
+    let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) = x.sinh_cosh_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+    assert_eq!(sinh_o_alt, sinh_o);
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(cosh_o_alt, cosh_o);
+
+    // sinh_cosh agrees with sinh and cosh
+    let (sinh_alt, sinh_o_alt) = x.sinh_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+    assert_eq!(sinh_o_alt, sinh_o);
+    let (cosh_alt, cosh_o_alt) = x.cosh_prec_round_ref(prec, rm);
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(cosh_o_alt, cosh_o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+            rug_sinh_cosh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(rug_sinh_o, sinh_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_cosh_o, cosh_o);
+    }
+
+    if sinh_o == Equal && cosh_o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let ((s, s_o), (c, c_o)) = x.sinh_cosh_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sinh.clone()));
+            assert_eq!(s_o, Equal);
+            assert_eq!(ComparableFloat(c), ComparableFloat(cosh.clone()));
+            assert_eq!(c_o, Equal);
+        }
+    } else {
+        assert_panic!(x.sinh_cosh_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn sinh_cosh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        sinh_cosh_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let ((sinh, sinh_o), (cosh, cosh_o)) = Float::NAN.sinh_cosh_prec_round(prec, rm);
+        assert!(sinh.is_nan());
+        assert_eq!(sinh_o, Equal);
+        assert!(cosh.is_nan());
+        assert_eq!(cosh_o, Equal);
+
+        for x in [Float::INFINITY, Float::NEGATIVE_INFINITY] {
+            let ((sinh, sinh_o), (cosh, cosh_o)) = x.sinh_cosh_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(sinh), ComparableFloat(x));
+            assert_eq!(sinh_o, Equal);
+            assert_eq!(ComparableFloat(cosh), ComparableFloat(Float::INFINITY));
+            assert_eq!(cosh_o, Equal);
+        }
+
+        for x in [Float::ZERO, Float::NEGATIVE_ZERO] {
+            let ((sinh, sinh_o), (cosh, cosh_o)) = x.sinh_cosh_prec_round_ref(prec, rm);
+            assert_eq!(ComparableFloat(sinh), ComparableFloat(x));
+            assert_eq!(sinh_o, Equal);
+            assert_eq!(
+                ComparableFloat(cosh),
+                ComparableFloat(Float::one_prec(prec))
+            );
+            assert_eq!(cosh_o, Equal);
+        }
+    });
+}
+
+#[test]
+fn sinh_cosh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let ((sinh, sinh_o), (cosh, cosh_o)) = x.clone().sinh_cosh_prec(prec);
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) = x.sinh_cosh_prec_ref(prec);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) =
+            x.sinh_cosh_prec_round_ref(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+            rug_sinh_cosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(rug_sinh_o, sinh_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_cosh_o, cosh_o);
+    });
+}
+
+#[test]
+fn sinh_cosh_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        let ((sinh, sinh_o), (cosh, cosh_o)) = x.clone().sinh_cosh_round(rm);
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) = x.sinh_cosh_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) =
+            x.sinh_cosh_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+                rug_sinh_cosh_round(&rug::Float::exact_from(&x), rug_rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_sinh_o, sinh_o);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_cosh_o, cosh_o);
+        }
+    });
+}
+
+#[test]
+fn sinh_cosh_properties() {
+    float_gen().test_properties(|x| {
+        let (sinh, cosh) = x.clone().sinh_cosh();
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+
+        let (sinh_alt, cosh_alt) = (&x).sinh_cosh();
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+
+        assert_eq!(ComparableFloatRef(&(&x).sinh()), ComparableFloatRef(&sinh));
+        assert_eq!(ComparableFloatRef(&(&x).cosh()), ComparableFloatRef(&cosh));
+
+        let (rug_sinh, rug_cosh) = rug_sinh_cosh(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sinh_cosh_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let ((sinh, sinh_o), (cosh, cosh_o)) =
+        Float::sinh_cosh_rational_prec_round(x.clone(), prec, rm);
+    assert!(sinh.is_valid());
+    assert!(cosh.is_valid());
+
+    let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) =
+        Float::sinh_cosh_rational_prec_round_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+    assert_eq!(sinh_o_alt, sinh_o);
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(cosh_o_alt, cosh_o);
+
+    let (sinh_alt, sinh_o_alt) = Float::sinh_rational_prec_round_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+    assert_eq!(sinh_o_alt, sinh_o);
+    let (cosh_alt, cosh_o_alt) = Float::cosh_rational_prec_round_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+    assert_eq!(cosh_o_alt, cosh_o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+            rug_sinh_cosh_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(rug_sinh_o, sinh_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_cosh_o, cosh_o);
+    }
+
+    if sinh_o == Equal && cosh_o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let ((s, s_o), (c, c_o)) = Float::sinh_cosh_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sinh.clone()));
+            assert_eq!(s_o, Equal);
+            assert_eq!(ComparableFloat(c), ComparableFloat(cosh.clone()));
+            assert_eq!(c_o, Equal);
+        }
+    } else {
+        assert_panic!(Float::sinh_cosh_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn sinh_cosh_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        sinh_cosh_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let ((sinh, sinh_o), (cosh, cosh_o)) =
+            Float::sinh_cosh_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(sinh), ComparableFloat(Float::ZERO));
+        assert_eq!(sinh_o, Equal);
+        assert_eq!(
+            ComparableFloat(cosh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(cosh_o, Equal);
+    });
+}
+
+#[test]
+fn sinh_cosh_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        let ((sinh, sinh_o), (cosh, cosh_o)) = Float::sinh_cosh_rational_prec(x.clone(), prec);
+        assert!(sinh.is_valid());
+        assert!(cosh.is_valid());
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) =
+            Float::sinh_cosh_rational_prec_ref(&x, prec);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        let ((sinh_alt, sinh_o_alt), (cosh_alt, cosh_o_alt)) =
+            Float::sinh_cosh_rational_prec_round_ref(&x, prec, Nearest);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(sinh_o_alt, sinh_o);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(cosh_o_alt, cosh_o);
+
+        let ((rug_sinh, rug_sinh_o), (rug_cosh, rug_cosh_o)) =
+            rug_sinh_cosh_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(rug_sinh_o, sinh_o);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_cosh_o, cosh_o);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_sinh_cosh() {
+    fn test<T: PrimitiveFloat>(x: T, sinh: T, cosh: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let (sinh_out, cosh_out) = primitive_float_sinh_cosh(x);
+        assert_eq!(NiceFloat(sinh_out), NiceFloat(sinh));
+        assert_eq!(NiceFloat(cosh_out), NiceFloat(cosh));
+    }
+    test::<f32>(f32::NAN, f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+    test::<f32>(0.0, 0.0, 1.0);
+    test::<f32>(-0.0, -0.0, 1.0);
+    test::<f32>(1.0, 1.1752012, 1.5430807);
+    test::<f32>(-1.0, -1.1752012, 1.5430807);
+    test::<f32>(0.5, 0.5210953, 1.127626);
+    test::<f32>(10.0, 11013.232, 11013.233);
+
+    test::<f64>(f64::NAN, f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    test::<f64>(0.0, 0.0, 1.0);
+    test::<f64>(-0.0, -0.0, 1.0);
+    test::<f64>(1.0, 1.1752011936438014, 1.5430806348152437);
+    test::<f64>(-1.0, -1.1752011936438014, 1.5430806348152437);
+    test::<f64>(0.5, 0.5210953054937474, 1.1276259652063807);
+    test::<f64>(10.0, 11013.232874703393, 11013.232920103323);
+    test::<f64>(1.0e300, f64::INFINITY, f64::INFINITY);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_sinh_cosh_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let (sinh, cosh) = primitive_float_sinh_cosh(x);
+        assert_eq!(NiceFloat(sinh), NiceFloat(primitive_float_sinh(x)));
+        assert_eq!(NiceFloat(cosh), NiceFloat(primitive_float_cosh(x)));
+    });
+}
+
+#[test]
+fn primitive_float_sinh_cosh_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_sinh_cosh_properties_helper);
+}
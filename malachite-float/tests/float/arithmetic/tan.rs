@@ -0,0 +1,2702 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Tan, TanAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::tan::{primitive_float_tan, primitive_float_tan_rational};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::tan::{
+    rug_tan, rug_tan_prec, rug_tan_prec_round, rug_tan_rational_prec, rug_tan_rational_prec_round,
+    rug_tan_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_tan() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let tan = x.clone().tan();
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+
+        let tan_alt = (&x).tan();
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+
+        let mut tan_alt = x.clone();
+        tan_alt.tan_assign();
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&tan)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+    );
+    test("123.0", "0x7b.0#7", "0.5156", "0x0.84#7");
+    test("-123.0", "-0x7b.0#7", "-0.5156", "-0x0.84#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.2246467991473535e-16",
+        "-0x8.d313198a2e04E-14#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+    );
+    test("355.0", "0x163.0#9", "0.00003016", "0x0.0001fa#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "1.4214488238747247",
+        "0x1.6be411f37ac78#51",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "7.9e-31", "0x1.0E-25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+    );
+}
+
+#[test]
+fn test_tan_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec(prec);
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_assign(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 100, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.5566", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-1.5566", "-0x1.8e8#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.5574077246549022305069748074591",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.5566",
+        "0x1.8e8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "0.50", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 10, "0.51758", "0x0.848#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "0.51792747158565518313192407563912",
+        "0x0.8496e510290d3d67b1e7f6bce#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "-0.50", "-0x0.8#1", Greater);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "-0.51758",
+        "-0x0.848#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-0.51792747158565518313192407563912",
+        "-0x0.8496e510290d3d67b1e7f6bce#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "-1.1e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "-1.2251e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "-1.2246467991473531772260659322748e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.45068",
+        "-0x0.736#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.45054953406980766962121546469312",
+        "-0x0.735736da0ed0069bd28756eab8#100",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 1, "0.000031", "0x0.0002#1", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "0.000030130",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "0.000030144353373184265468141231180125",
+        "0x0.0001f9bd030bacc1617ccc31be7b4#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "1.4219",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "1.4214488238747244123668408839478",
+        "0x1.6be411f37ac76a9660c73bd84#100",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "7.9e-31", "0x1.0E-25#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.34619",
+        "0x0.58a#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+}
+
+#[test]
+fn tan_prec_fail() {
+    assert_panic!(Float::NAN.tan_prec(0));
+    assert_panic!(Float::NAN.tan_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.tan_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_tan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_round(rm);
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_round_ref(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_round_assign(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.5574077246549022305069748074575",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.5574077246549022305069748074575",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Floor, "0.5156", "0x0.84#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "0.5234", "0x0.86#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "0.5156", "0x0.84#7", Less);
+    test("123.0", "0x7b.0#7", Up, "0.5234", "0x0.86#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "0.5156", "0x0.84#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-0.5234", "-0x0.86#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.5156",
+        "-0x0.84#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.5156", "-0x0.84#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-0.5234", "-0x0.86#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-0.5156",
+        "-0x0.84#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "-1.2246467991473535e-16",
+        "-0x8.d313198a2e04E-14#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "-1.2246467991473515e-16",
+        "-0x8.d313198a2e00E-14#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "-1.2246467991473515e-16",
+        "-0x8.d313198a2e00E-14#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "-1.2246467991473535e-16",
+        "-0x8.d313198a2e04E-14#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "-1.2246467991473535e-16",
+        "-0x8.d313198a2e04E-14#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "-0.45054953406980763",
+        "-0x0.735736da0ed004#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "-0.45054953406980763",
+        "-0x0.735736da0ed004#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "0.00003010",
+        "0x0.0001f9#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "0.00003016",
+        "0x0.0001fa#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "0.00003010",
+        "0x0.0001f9#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "0.00003016",
+        "0x0.0001fa#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "0.00003016",
+        "0x0.0001fa#9",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "1.4214488238747238",
+        "0x1.6be411f37ac74#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "1.4214488238747247",
+        "0x1.6be411f37ac78#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "1.4214488238747238",
+        "0x1.6be411f37ac74#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "1.4214488238747247",
+        "0x1.6be411f37ac78#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "1.4214488238747247",
+        "0x1.6be411f37ac78#51",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.34625354951057549103854356561009",
+        "0x0.58a41297459735e7bc3b8169f8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.34625354951057549103854356561009",
+        "0x0.58a41297459735e7bc3b8169f8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+}
+
+#[test]
+fn tan_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(X.tan_round(Exact));
+    assert_panic!(X.tan_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.tan_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_tan_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec_round(prec, rm);
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_round_assign(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "1.5566", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.5586",
+        "0x1.8f0#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.5566", "0x1.8e8#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.5586", "0x1.8f0#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.5566", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "1.5574077246549022305069748074575",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-1.5586",
+        "-0x1.8f0#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-1.5566",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-1.5566",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-1.5586", "-0x1.8f0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-1.5566",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-1.5574077246549022305069748074591",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-1.5574077246549022305069748074575",
+        "-0x1.8eb245cbee3a5b8acc7d41322#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-1.5574077246549022305069748074591",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.5566",
+        "0x1.8e8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.5586",
+        "0x1.8f0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.5566",
+        "0x1.8e8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.5586",
+        "0x1.8f0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.5566",
+        "0x1.8e8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "1.5574077246549022305069748074575",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "0.50", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "0.51758",
+        "0x0.848#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "0.51855",
+        "0x0.84c#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "0.51758", "0x0.848#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "0.51855",
+        "0x0.84c#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "0.51758",
+        "0x0.848#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "0.51792747158565518313192407563912",
+        "0x0.8496e510290d3d67b1e7f6bce#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "0.51792747158565518313192407563991",
+        "0x0.8496e510290d3d67b1e7f6bcf#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "0.51792747158565518313192407563912",
+        "0x0.8496e510290d3d67b1e7f6bce#100",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-123.0", "-0x7b.0#7", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-0.51855",
+        "-0x0.84c#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.51758",
+        "-0x0.848#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-0.51758",
+        "-0x0.848#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "-0.51855",
+        "-0x0.84c#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-0.51758",
+        "-0x0.848#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "-0.51792747158565518313192407563991",
+        "-0x0.8496e510290d3d67b1e7f6bcf#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "-0.51792747158565518313192407563912",
+        "-0x0.8496e510290d3d67b1e7f6bce#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "-0.51792747158565518313192407563912",
+        "-0x0.8496e510290d3d67b1e7f6bce#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "-2.2e-16",
+        "-0x1.0E-13#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "-1.1e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "-1.1e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "-2.2e-16",
+        "-0x1.0E-13#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "-1.1e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "-1.2251e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "-1.2230e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "-1.2230e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "-1.2251e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "-1.2251e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "-1.2246467991473531772260659322765e-16",
+        "-0x8.d313198a2e03707344a40939E-14#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "-1.2246467991473531772260659322748e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "-1.2246467991473531772260659322748e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-0.45068",
+        "-0x0.736#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-0.45020",
+        "-0x0.734#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "-0.45020",
+        "-0x0.734#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "-0.45068",
+        "-0x0.736#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-0.45068",
+        "-0x0.736#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "-0.45054953406980766962121546469351",
+        "-0x0.735736da0ed0069bd28756eac0#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "-0.45054953406980766962121546469312",
+        "-0x0.735736da0ed0069bd28756eab8#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "-0.45054953406980766962121546469312",
+        "-0x0.735736da0ed0069bd28756eab8#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Floor,
+        "0.000015",
+        "0x0.0001#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Ceiling,
+        "0.000031",
+        "0x0.0002#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Down,
+        "0.000015",
+        "0x0.0001#1",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Up,
+        "0.000031",
+        "0x0.0002#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        1,
+        Nearest,
+        "0.000031",
+        "0x0.0002#1",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "0.000030130",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "0.000030160",
+        "0x0.0001fa0#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "0.000030130",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "0.000030160",
+        "0x0.0001fa0#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "0.000030130",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "0.000030144353373184265468141231180125",
+        "0x0.0001f9bd030bacc1617ccc31be7b4#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "0.000030144353373184265468141231180149",
+        "0x0.0001f9bd030bacc1617ccc31be7b6#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "0.000030144353373184265468141231180125",
+        "0x0.0001f9bd030bacc1617ccc31be7b4#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "1.4199",
+        "0x1.6b8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "1.4219",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "1.4199",
+        "0x1.6b8#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "1.4219",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "1.4219",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "1.4214488238747244123668408839478",
+        "0x1.6be411f37ac76a9660c73bd84#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "1.4214488238747244123668408839493",
+        "0x1.6be411f37ac76a9660c73bd86#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "1.4214488238747244123668408839478",
+        "0x1.6be411f37ac76a9660c73bd84#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.6e-30",
+        "0x2.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.9040e-31",
+        "0x1.008E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.9040e-31",
+        "0x1.008E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "7.8886090522101180541172856528403e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.34619",
+        "0x0.58a#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.34668",
+        "0x0.58c#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.34619",
+        "0x0.58a#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.34668",
+        "0x0.58c#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.34619",
+        "0x0.58a#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.34625354951057549103854356561009",
+        "0x0.58a41297459735e7bc3b8169f8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+}
+
+#[test]
+fn tan_prec_round_fail() {
+    const X: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).tan_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).tan_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.tan_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.tan_prec_round(1, Exact));
+    assert_panic!(X.tan_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.tan_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_tan_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (tan, o) = Float::tan_rational_prec(x.clone(), prec);
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, out_o);
+
+        let (tan, o) = Float::tan_rational_prec_ref(&x, prec);
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_tan, rug_o) = rug_tan_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "2.0", "0x2.0#1", Greater);
+    test("1", 10, "1.5566", "0x1.8e8#10", Less);
+    test(
+        "1",
+        100,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("-1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1", 10, "-1.5566", "-0x1.8e8#10", Greater);
+    test(
+        "-1",
+        100,
+        "-1.5574077246549022305069748074591",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Less);
+    test("1/2", 10, "0.54590", "0x0.8bc#10", Less);
+    test(
+        "1/2",
+        100,
+        "0.54630248984379051325517946578023",
+        "0x0.8bda7adf9a3a5218bcb2403c4#100",
+        Less,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.34619", "0x0.58a#10", Less);
+    test(
+        "1/3",
+        100,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.34619", "-0x0.58a#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "-0.34625354951057549103854356560970",
+        "-0x0.58a41297459735e7bc3b8169f0#100",
+        Greater,
+    );
+    test("22/7", 1, "0.00098", "0x0.004#1", Less);
+    test("22/7", 10, "0.0012646", "0x0.0052e#10", Greater);
+    test(
+        "22/7",
+        100,
+        "0.0012644899412946341567363163883632",
+        "0x0.0052de9ef1a8c413757001f149c8#100",
+        Less,
+    );
+    test("1000000", 1, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 10, "-0.37354", "-0x0.5fa#10", Greater);
+    test(
+        "1000000",
+        100,
+        "-0.37362445398759902917349708857555",
+        "-0x0.5fa5da2adcd3004202c27b19e0#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn tan_rational_prec_fail() {
+    Float::tan_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn tan_rational_prec_ref_fail() {
+    Float::tan_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_tan_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (tan, o) = Float::tan_rational_prec_round(x.clone(), prec, rm);
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, out_o);
+
+        let (tan, o) = Float::tan_rational_prec_round_ref(&x, prec, rm);
+        assert!(tan.is_valid());
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1", 10, Floor, "1.5566", "0x1.8e8#10", Less);
+    test("1", 10, Ceiling, "1.5586", "0x1.8f0#10", Greater);
+    test("1", 10, Down, "1.5566", "0x1.8e8#10", Less);
+    test("1", 10, Up, "1.5586", "0x1.8f0#10", Greater);
+    test("1", 10, Nearest, "1.5566", "0x1.8e8#10", Less);
+    test(
+        "1",
+        100,
+        Nearest,
+        "1.5574077246549022305069748074591",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("-1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test("-1", 10, Floor, "-1.5586", "-0x1.8f0#10", Less);
+    test("-1", 10, Ceiling, "-1.5566", "-0x1.8e8#10", Greater);
+    test("-1", 10, Down, "-1.5566", "-0x1.8e8#10", Greater);
+    test("-1", 10, Up, "-1.5586", "-0x1.8f0#10", Less);
+    test("-1", 10, Nearest, "-1.5566", "-0x1.8e8#10", Greater);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-1.5574077246549022305069748074591",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Less,
+    );
+    test("1/2", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1/2", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1/2", 10, Floor, "0.54590", "0x0.8bc#10", Less);
+    test("1/2", 10, Ceiling, "0.54688", "0x0.8c0#10", Greater);
+    test("1/2", 10, Down, "0.54590", "0x0.8bc#10", Less);
+    test("1/2", 10, Up, "0.54688", "0x0.8c0#10", Greater);
+    test("1/2", 10, Nearest, "0.54590", "0x0.8bc#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.54630248984379051325517946578023",
+        "0x0.8bda7adf9a3a5218bcb2403c4#100",
+        Less,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.34619", "0x0.58a#10", Less);
+    test("1/3", 10, Ceiling, "0.34668", "0x0.58c#10", Greater);
+    test("1/3", 10, Down, "0.34619", "0x0.58a#10", Less);
+    test("1/3", 10, Up, "0.34668", "0x0.58c#10", Greater);
+    test("1/3", 10, Nearest, "0.34619", "0x0.58a#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.34625354951057549103854356560970",
+        "0x0.58a41297459735e7bc3b8169f0#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, Floor, "-0.34668", "-0x0.58c#10", Less);
+    test("-1/3", 10, Ceiling, "-0.34619", "-0x0.58a#10", Greater);
+    test("-1/3", 10, Down, "-0.34619", "-0x0.58a#10", Greater);
+    test("-1/3", 10, Up, "-0.34668", "-0x0.58c#10", Less);
+    test("-1/3", 10, Nearest, "-0.34619", "-0x0.58a#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-0.34625354951057549103854356560970",
+        "-0x0.58a41297459735e7bc3b8169f0#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.00098", "0x0.004#1", Less);
+    test("22/7", 1, Ceiling, "0.0020", "0x0.008#1", Greater);
+    test("22/7", 1, Down, "0.00098", "0x0.004#1", Less);
+    test("22/7", 1, Up, "0.0020", "0x0.008#1", Greater);
+    test("22/7", 1, Nearest, "0.00098", "0x0.004#1", Less);
+    test("22/7", 10, Floor, "0.0012627", "0x0.0052c#10", Less);
+    test("22/7", 10, Ceiling, "0.0012646", "0x0.0052e#10", Greater);
+    test("22/7", 10, Down, "0.0012627", "0x0.0052c#10", Less);
+    test("22/7", 10, Up, "0.0012646", "0x0.0052e#10", Greater);
+    test("22/7", 10, Nearest, "0.0012646", "0x0.0052e#10", Greater);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.0012644899412946341567363163883632",
+        "0x0.0052de9ef1a8c413757001f149c8#100",
+        Less,
+    );
+    test("1000000", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("1000000", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("1000000", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("1000000", 10, Floor, "-0.37402", "-0x0.5fc#10", Less);
+    test("1000000", 10, Ceiling, "-0.37354", "-0x0.5fa#10", Greater);
+    test("1000000", 10, Down, "-0.37354", "-0x0.5fa#10", Greater);
+    test("1000000", 10, Up, "-0.37402", "-0x0.5fc#10", Less);
+    test("1000000", 10, Nearest, "-0.37354", "-0x0.5fa#10", Greater);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "-0.37362445398759902917349708857555",
+        "-0x0.5fa5da2adcd3004202c27b19e0#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn tan_rational_prec_round_fail_1() {
+    Float::tan_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn tan_rational_prec_round_fail_2() {
+    Float::tan_rational_prec_round(Rational::ONE, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn tan_rational_prec_round_ref_fail() {
+    Float::tan_rational_prec_round_ref(&Rational::ONE, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (tan, o) = x.clone().tan_prec_round(prec, rm);
+    assert!(tan.is_valid());
+
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(tan.is_nan(), !x.is_finite());
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(prec));
+    }
+
+    let (tan_alt, o_alt) = (-&x).tan_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-tan_alt), ComparableFloat(tan.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (tan_alt, o_alt) = Float::tan_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.tan_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(tan.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.tan_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn tan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        tan_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (tan, o) = Float::NAN.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::INFINITY.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::NEGATIVE_INFINITY.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::ZERO.tan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::NEGATIVE_ZERO.tan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_prec_properties_helper(x: Float, prec: u64) {
+    let (tan, o) = x.clone().tan_prec(prec);
+    assert!(tan.is_valid());
+
+    let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tan)),
+        ComparableFloatRef(&tan)
+    );
+    assert_eq!(rug_o, o);
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(prec));
+    }
+
+    let (tan_alt, o_alt) = (-&x).tan_prec(prec);
+    assert_eq!(ComparableFloat(-tan_alt), ComparableFloat(tan.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn tan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        tan_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (tan, o) = Float::NAN.tan_prec(prec);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::INFINITY.tan_prec(prec);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::NEGATIVE_INFINITY.tan_prec(prec);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::ZERO.tan_prec(prec);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::NEGATIVE_ZERO.tan_prec(prec);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (tan, o) = x.clone().tan_round(rm);
+    assert!(tan.is_valid());
+
+    let (tan_alt, o_alt) = x.tan_round_ref(rm);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (tan_alt, o_alt) = (-&x).tan_round(-rm);
+    assert_eq!(ComparableFloat(-tan_alt), ComparableFloat(tan.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.tan_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(tan.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.tan_round_ref(Exact));
+    }
+}
+
+#[test]
+fn tan_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        tan_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (tan, o) = Float::NAN.tan_round(rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::INFINITY.tan_round(rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::NEGATIVE_INFINITY.tan_round(rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::ZERO.tan_round(rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (tan, o) = Float::NEGATIVE_ZERO.tan_round(rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_properties_helper(x: Float) {
+    let tan = x.clone().tan();
+    assert!(tan.is_valid());
+
+    let tan_alt = (&x).tan();
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+
+    let mut x_alt = x.clone();
+    x_alt.tan_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+
+    let tan_alt = x.tan_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    let tan_alt = x.tan_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+
+    assert_eq!(tan.is_nan(), !x.is_finite());
+
+    let rug_tan = rug_tan(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tan)),
+        ComparableFloatRef(&tan),
+    );
+}
+
+#[test]
+fn tan_properties() {
+    float_gen().test_properties(|x| {
+        tan_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (tan, o) = Float::tan_rational_prec_round(x.clone(), prec, rm);
+    assert!(tan.is_valid());
+
+    let (tan_alt, o_alt) = Float::tan_rational_prec_round_ref(&x, prec, rm);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tan, rug_o) = rug_tan_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!tan.is_nan());
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (tan_alt, o_alt) = Float::tan_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-tan_alt), ComparableFloat(tan.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::tan_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(tan.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::tan_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn tan_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        tan_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (tan, o) = Float::tan_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (tan, o) = Float::tan_rational_prec(x.clone(), prec);
+    assert!(tan.is_valid());
+
+    let (tan_alt, o_alt) = Float::tan_rational_prec_ref(&x, prec);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (tan_alt, o_alt) = Float::tan_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (rug_tan, rug_o) = rug_tan_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tan)),
+        ComparableFloatRef(&tan)
+    );
+    assert_eq!(rug_o, o);
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn tan_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        tan_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (tan, o) = Float::tan_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_tan() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_tan(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, f32::NAN);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, 1.5574077);
+    test::<f32>(-1.0, -1.5574077);
+    test::<f32>(0.5, 0.5463025);
+    test::<f32>(-0.5, -0.5463025);
+    test::<f32>(2.0, -2.1850398);
+    test::<f32>(10.0, 0.64836085);
+    test::<f32>(0.001, 0.0010000004);
+    test::<f32>(10000000000.0, -0.5583496);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, f64::NAN);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, 1.5574077246549023);
+    test::<f64>(-1.0, -1.5574077246549023);
+    test::<f64>(0.5, 0.5463024898437905);
+    test::<f64>(-0.5, -0.5463024898437905);
+    test::<f64>(2.0, -2.185039863261519);
+    test::<f64>(10.0, 0.6483608274590866);
+    test::<f64>(0.001, 0.0010000003333334668);
+    test::<f64>(10000000000.0, -0.5583496378112418);
+    test::<f64>(1.0e300, 1.4214488238747245);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_tan_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let tan = primitive_float_tan(x);
+        assert_eq!(tan.is_nan(), !x.is_finite());
+        assert_eq!(NiceFloat(primitive_float_tan(-x)), NiceFloat(-tan));
+    });
+}
+
+#[test]
+fn primitive_float_tan_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_tan_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_tan_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_tan_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", 1.5574077);
+    test::<f32>("-1", -1.5574077);
+    test::<f32>("1/2", 0.5463025);
+    test::<f32>("-1/2", -0.5463025);
+    test::<f32>("1/3", 0.34625354);
+    test::<f32>("22/7", 0.00126449);
+    test::<f32>("3/2", 14.10142);
+    test::<f32>("1000000", -0.37362444);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", 1.5574077246549023);
+    test::<f64>("-1", -1.5574077246549023);
+    test::<f64>("1/2", 0.5463024898437905);
+    test::<f64>("-1/2", -0.5463024898437905);
+    test::<f64>("1/3", 0.34625354951057546);
+    test::<f64>("22/7", 0.0012644899412946342);
+    test::<f64>("3/2", 14.101419947171719);
+    test::<f64>("1000000", -0.373624453987599);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_tan_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let tan = primitive_float_tan_rational::<T>(&x);
+        assert!(!tan.is_nan());
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_tan_rational::<T>(&-&x)),
+                NiceFloat(-tan)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_tan_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_tan(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_tan_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_tan_rational_properties_helper);
+}
@@ -0,0 +1,2683 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Tanh, TanhAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::tanh::{
+    primitive_float_tanh, primitive_float_tanh_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::tanh::{
+    rug_tanh, rug_tanh_prec, rug_tanh_prec_round, rug_tanh_rational_prec,
+    rug_tanh_rational_prec_round, rug_tanh_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_tanh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let tanh = x.clone().tanh();
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+
+        let tanh_alt = (&x).tanh();
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+
+        let mut tanh_alt = x.clone();
+        tanh_alt.tanh_assign();
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tanh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&tanh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1");
+    test("-Infinity", "-Infinity", "-1.0", "-0x1.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+    );
+    test("123.0", "0x7b.0#7", "1.000", "0x1.00#7");
+    test("-123.0", "-0x7b.0#7", "-1.000", "-0x1.00#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.99627207622074998",
+        "0x0.ff0bafd149408#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.99132891580059979",
+        "0x0.fdc7bb58f155b0#53",
+    );
+    test("355.0", "0x163.0#9", "1.000", "0x1.00#9");
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "7.9e-31", "0x1.0E-25#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+    );
+}
+
+#[test]
+fn test_tanh_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tanh, o) = x.clone().tanh_prec(prec);
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tanh_alt, o_alt) = x.tanh_prec_ref(prec);
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tanh_alt = x.clone();
+        let o_alt = tanh_alt.tanh_prec_assign(prec);
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_tanh, rug_o) = rug_tanh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tanh)),
+            ComparableFloatRef(&tanh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.0000",
+        "-0x1.000#10",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        100,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.76172", "0x0.c30#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.76172", "-0x0.c30#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.76159415595576488811945828260469",
+        "-0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.76172",
+        "0x0.c30#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "-1.0", "-0x1.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 10, "-1.0000", "-0x1.000#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "0.99609",
+        "0x0.ff0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "0.99627207622074994335331453783341",
+        "0x0.ff0bafd149407d56d371649f5#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.99121",
+        "0x0.fdc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.99132891580059983529936191819485",
+        "0x0.fdc7bb58f155b38fd8dd218c8#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, "1.0", "0x1.0#1", Greater);
+    test("355.0", "0x163.0#9", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "0.32129",
+        "0x0.524#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+}
+
+#[test]
+fn tanh_prec_fail() {
+    assert_panic!(Float::NAN.tanh_prec(0));
+    assert_panic!(Float::NAN.tanh_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.tanh_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_tanh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tanh, o) = x.clone().tanh_round(rm);
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tanh_alt, o_alt) = x.tanh_round_ref(rm);
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tanh_alt = x.clone();
+        let o_alt = tanh_alt.tanh_round_assign(rm);
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tanh, rug_o) = rug_tanh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tanh)),
+                ComparableFloatRef(&tanh),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Exact, "1.0", "0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Floor, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Down, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Up, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Nearest, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Exact, "-1.0", "-0x1.0#1", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.76159415595576488811945828260548",
+        "0x0.c2f7d5a8a79ca2ac3195f149f#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.76159415595576488811945828260548",
+        "0x0.c2f7d5a8a79ca2ac3195f149f#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", Floor, "0.9922", "0x0.fe#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "1.000", "0x1.00#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "0.9922", "0x0.fe#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.000", "0x1.00#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "1.000", "0x1.00#7", Greater);
+    test("-123.0", "-0x7b.0#7", Floor, "-1.000", "-0x1.00#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.9922",
+        "-0x0.fe#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.9922", "-0x0.fe#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-1.000", "-0x1.00#7", Less);
+    test("-123.0", "-0x7b.0#7", Nearest, "-1.000", "-0x1.00#7", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "0.99627207622074909",
+        "0x0.ff0bafd149404#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "0.99627207622074998",
+        "0x0.ff0bafd149408#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "0.99627207622074909",
+        "0x0.ff0bafd149404#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "0.99627207622074998",
+        "0x0.ff0bafd149408#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "0.99627207622074998",
+        "0x0.ff0bafd149408#50",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.99132891580059979",
+        "0x0.fdc7bb58f155b0#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.99132891580059990",
+        "0x0.fdc7bb58f155b8#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.99132891580059979",
+        "0x0.fdc7bb58f155b0#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.99132891580059990",
+        "0x0.fdc7bb58f155b8#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.99132891580059979",
+        "0x0.fdc7bb58f155b0#53",
+        Less,
+    );
+    test("355.0", "0x163.0#9", Floor, "0.9980", "0x0.ff8#9", Less);
+    test("355.0", "0x163.0#9", Ceiling, "1.000", "0x1.00#9", Greater);
+    test("355.0", "0x163.0#9", Down, "0.9980", "0x0.ff8#9", Less);
+    test("355.0", "0x163.0#9", Up, "1.000", "0x1.00#9", Greater);
+    test("355.0", "0x163.0#9", Nearest, "1.000", "0x1.00#9", Greater);
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Floor,
+        "0.99999999999999956",
+        "0x0.ffffffffffffe#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Ceiling,
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Down,
+        "0.99999999999999956",
+        "0x0.ffffffffffffe#51",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Up,
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        Nearest,
+        "1.0000000000000000",
+        "0x1.0000000000000#51",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "0.32151273753163434471940622242555",
+        "0x0.524ea8a4f220084cefa449a990#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "0.32151273753163434471940622242555",
+        "0x0.524ea8a4f220084cefa449a990#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+}
+
+#[test]
+fn tanh_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.tanh_round(Exact));
+    assert_panic!(X.tanh_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.tanh_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_tanh_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tanh, o) = x.clone().tanh_prec_round(prec, rm);
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tanh_alt, o_alt) = x.tanh_prec_round_ref(prec, rm);
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tanh_alt = x.clone();
+        let o_alt = tanh_alt.tanh_prec_round_assign(prec, rm);
+        assert!(tanh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tanh), ComparableFloatRef(&tanh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tanh, rug_o) = rug_tanh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tanh)),
+                ComparableFloatRef(&tanh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Down, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.76074", "0x0.c2c#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.76172",
+        "0x0.c30#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.76074", "0x0.c2c#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.76172", "0x0.c30#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.76172",
+        "0x0.c30#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Floor,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Ceiling,
+        "0.76159415595576488811945828260548",
+        "0x0.c2f7d5a8a79ca2ac3195f149f#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        Nearest,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.76172",
+        "-0x0.c30#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.76074",
+        "-0x0.c2c#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.76074",
+        "-0x0.c2c#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.76172", "-0x0.c30#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.76172",
+        "-0x0.c30#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.76159415595576488811945828260548",
+        "-0x0.c2f7d5a8a79ca2ac3195f149f#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.76159415595576488811945828260469",
+        "-0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.76159415595576488811945828260469",
+        "-0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.76074",
+        "0x0.c2c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.76172",
+        "0x0.c30#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.76074",
+        "0x0.c2c#10",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.76172",
+        "0x0.c30#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.76172",
+        "0x0.c30#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "0.76159415595576488811945828260548",
+        "0x0.c2f7d5a8a79ca2ac3195f149f#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "0.50", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-123.0", "-0x7b.0#7", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-0.99902",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-1.0000",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Floor,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Ceiling,
+        "-0.99999999999999999999999999999921",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        100,
+        Nearest,
+        "-1.0000000000000000000000000000000",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "0.99609",
+        "0x0.ff0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "0.99707",
+        "0x0.ff4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "0.99609",
+        "0x0.ff0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "0.99707",
+        "0x0.ff4#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "0.99609",
+        "0x0.ff0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "0.99627207622074994335331453783341",
+        "0x0.ff0bafd149407d56d371649f5#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "0.99627207622074994335331453783420",
+        "0x0.ff0bafd149407d56d371649f6#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "0.99627207622074994335331453783341",
+        "0x0.ff0bafd149407d56d371649f5#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.99121",
+        "0x0.fdc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.99219",
+        "0x0.fe0#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.99121",
+        "0x0.fdc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.99219",
+        "0x0.fe0#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.99121",
+        "0x0.fdc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "0.99132891580059983529936191819485",
+        "0x0.fdc7bb58f155b38fd8dd218c8#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "0.99132891580059983529936191819564",
+        "0x0.fdc7bb58f155b38fd8dd218c9#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "0.99132891580059983529936191819485",
+        "0x0.fdc7bb58f155b38fd8dd218c8#100",
+        Less,
+    );
+    test("355.0", "0x163.0#9", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("355.0", "0x163.0#9", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("355.0", "0x163.0#9", 1, Down, "0.50", "0x0.8#1", Less);
+    test("355.0", "0x163.0#9", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("355.0", "0x163.0#9", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Down,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Up,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Floor,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "1.0000000000000001e300",
+        "0x1.7e43c8800759cE+249#51",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "3.9e-31",
+        "0x8.0E-26#1",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "7.8809e-31",
+        "0xf.fcE-26#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "7.8886e-31",
+        "0x1.000E-25#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "7.8886090522101180541172856528216e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "7.8886090522101180541172856528279e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "0.32129",
+        "0x0.524#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "0.32178",
+        "0x0.526#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "0.32129",
+        "0x0.524#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "0.32178",
+        "0x0.526#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "0.32129",
+        "0x0.524#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "0.32151273753163434471940622242555",
+        "0x0.524ea8a4f220084cefa449a990#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+}
+
+#[test]
+fn tanh_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).tanh_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).tanh_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.tanh_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.tanh_prec_round(1, Exact));
+    assert_panic!(X.tanh_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.tanh_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_tanh_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (tanh, o) = Float::tanh_rational_prec(x.clone(), prec);
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (tanh, o) = Float::tanh_rational_prec_ref(&x, prec);
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_tanh, rug_o) = rug_tanh_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tanh)),
+            ComparableFloatRef(&tanh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "0.0", "0x0.0", Equal);
+    test("0", 10, "0.0", "0x0.0", Equal);
+    test("0", 100, "0.0", "0x0.0", Equal);
+    test("1", 1, "1.0", "0x1.0#1", Greater);
+    test("1", 10, "0.76172", "0x0.c30#10", Greater);
+    test(
+        "1",
+        100,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test("-1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, "-0.76172", "-0x0.c30#10", Less);
+    test(
+        "-1",
+        100,
+        "-0.76159415595576488811945828260469",
+        "-0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Greater,
+    );
+    test("1/2", 1, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, "0.46191", "0x0.764#10", Less);
+    test(
+        "1/2",
+        100,
+        "0.46211715726000975850231848364373",
+        "0x0.764d4f5d5a2bcd944a3b887198#100",
+        Greater,
+    );
+    test("1/3", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, "0.32129", "0x0.524#10", Less);
+    test(
+        "1/3",
+        100,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.32129", "-0x0.524#10", Greater);
+    test(
+        "-1/3",
+        100,
+        "-0.32151273753163434471940622242516",
+        "-0x0.524ea8a4f220084cefa449a988#100",
+        Greater,
+    );
+    test("22/7", 1, "1.0", "0x1.0#1", Greater);
+    test("22/7", 10, "0.99609", "0x0.ff0#10", Less);
+    test(
+        "22/7",
+        100,
+        "0.99628147464192520836125220785407",
+        "0x0.ff0c4d7f329dea0cf862f9f9c#100",
+        Less,
+    );
+    test("1000000", 1, "1.0", "0x1.0#1", Greater);
+    test("1000000", 10, "1.0000", "0x1.000#10", Greater);
+    test(
+        "1000000",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn tanh_rational_prec_fail() {
+    Float::tanh_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn tanh_rational_prec_ref_fail() {
+    Float::tanh_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_tanh_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (tanh, o) = Float::tanh_rational_prec_round(x.clone(), prec, rm);
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+        assert_eq!(o, out_o);
+
+        let (tanh, o) = Float::tanh_rational_prec_round_ref(&x, prec, rm);
+        assert!(tanh.is_valid());
+        assert_eq!(tanh.to_string(), out);
+        assert_eq!(to_hex_string(&tanh), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tanh, rug_o) = rug_tanh_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tanh)),
+                ComparableFloatRef(&tanh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1", 10, Floor, "0.76074", "0x0.c2c#10", Less);
+    test("1", 10, Ceiling, "0.76172", "0x0.c30#10", Greater);
+    test("1", 10, Down, "0.76074", "0x0.c2c#10", Less);
+    test("1", 10, Up, "0.76172", "0x0.c30#10", Greater);
+    test("1", 10, Nearest, "0.76172", "0x0.c30#10", Greater);
+    test(
+        "1",
+        100,
+        Nearest,
+        "0.76159415595576488811945828260469",
+        "0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Less,
+    );
+    test("-1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("-1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1", 10, Floor, "-0.76172", "-0x0.c30#10", Less);
+    test("-1", 10, Ceiling, "-0.76074", "-0x0.c2c#10", Greater);
+    test("-1", 10, Down, "-0.76074", "-0x0.c2c#10", Greater);
+    test("-1", 10, Up, "-0.76172", "-0x0.c30#10", Less);
+    test("-1", 10, Nearest, "-0.76172", "-0x0.c30#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.76159415595576488811945828260469",
+        "-0x0.c2f7d5a8a79ca2ac3195f149e#100",
+        Greater,
+    );
+    test("1/2", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/2", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/2", 1, Nearest, "0.50", "0x0.8#1", Greater);
+    test("1/2", 10, Floor, "0.46191", "0x0.764#10", Less);
+    test("1/2", 10, Ceiling, "0.46240", "0x0.766#10", Greater);
+    test("1/2", 10, Down, "0.46191", "0x0.764#10", Less);
+    test("1/2", 10, Up, "0.46240", "0x0.766#10", Greater);
+    test("1/2", 10, Nearest, "0.46191", "0x0.764#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "0.46211715726000975850231848364373",
+        "0x0.764d4f5d5a2bcd944a3b887198#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Ceiling, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Down, "0.25", "0x0.4#1", Less);
+    test("1/3", 1, Up, "0.50", "0x0.8#1", Greater);
+    test("1/3", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", 10, Floor, "0.32129", "0x0.524#10", Less);
+    test("1/3", 10, Ceiling, "0.32178", "0x0.526#10", Greater);
+    test("1/3", 10, Down, "0.32129", "0x0.524#10", Less);
+    test("1/3", 10, Up, "0.32178", "0x0.526#10", Greater);
+    test("1/3", 10, Nearest, "0.32129", "0x0.524#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.32151273753163434471940622242516",
+        "0x0.524ea8a4f220084cefa449a988#100",
+        Less,
+    );
+    test("-1/3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, Floor, "-0.32178", "-0x0.526#10", Less);
+    test("-1/3", 10, Ceiling, "-0.32129", "-0x0.524#10", Greater);
+    test("-1/3", 10, Down, "-0.32129", "-0x0.524#10", Greater);
+    test("-1/3", 10, Up, "-0.32178", "-0x0.526#10", Less);
+    test("-1/3", 10, Nearest, "-0.32129", "-0x0.524#10", Greater);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-0.32151273753163434471940622242516",
+        "-0x0.524ea8a4f220084cefa449a988#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("22/7", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("22/7", 1, Down, "0.50", "0x0.8#1", Less);
+    test("22/7", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("22/7", 10, Floor, "0.99609", "0x0.ff0#10", Less);
+    test("22/7", 10, Ceiling, "0.99707", "0x0.ff4#10", Greater);
+    test("22/7", 10, Down, "0.99609", "0x0.ff0#10", Less);
+    test("22/7", 10, Up, "0.99707", "0x0.ff4#10", Greater);
+    test("22/7", 10, Nearest, "0.99609", "0x0.ff0#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "0.99628147464192520836125220785407",
+        "0x0.ff0c4d7f329dea0cf862f9f9c#100",
+        Less,
+    );
+    test("1000000", 1, Floor, "0.50", "0x0.8#1", Less);
+    test("1000000", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1000000", 1, Down, "0.50", "0x0.8#1", Less);
+    test("1000000", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1000000", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1000000", 10, Floor, "0.99902", "0x0.ffc#10", Less);
+    test("1000000", 10, Ceiling, "1.0000", "0x1.000#10", Greater);
+    test("1000000", 10, Down, "0.99902", "0x0.ffc#10", Less);
+    test("1000000", 10, Up, "1.0000", "0x1.000#10", Greater);
+    test("1000000", 10, Nearest, "1.0000", "0x1.000#10", Greater);
+    test(
+        "1000000",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn tanh_rational_prec_round_fail_1() {
+    Float::tanh_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn tanh_rational_prec_round_fail_2() {
+    Float::tanh_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn tanh_rational_prec_round_ref_fail() {
+    Float::tanh_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn tanh_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (tanh, o) = x.clone().tanh_prec_round(prec, rm);
+    assert!(tanh.is_valid());
+
+    let (tanh_alt, o_alt) = x.tanh_prec_round_ref(prec, rm);
+    assert!(tanh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tanh_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tanh, rug_o) = rug_tanh_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tanh)),
+            ComparableFloatRef(&tanh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(tanh.is_nan(), x.is_nan());
+    assert!(tanh.is_nan() || tanh >= -1i32 && tanh <= 1u32);
+
+    if tanh.is_normal() {
+        assert_eq!(tanh.get_prec(), Some(prec));
+    }
+
+    let (tanh_alt, o_alt) = (-&x).tanh_prec_round(prec, -rm);
+    assert_eq!(ComparableFloat(-tanh_alt), ComparableFloat(tanh.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if x.is_normal() {
+        let (tanh_alt, o_alt) = Float::tanh_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.tanh_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(tanh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.tanh_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn tanh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        tanh_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (tanh, o) = Float::NAN.tanh_prec_round(prec, rm);
+        assert!(tanh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::INFINITY.tanh_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(tanh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::NEGATIVE_INFINITY.tanh_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(tanh),
+            ComparableFloat(-Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::ZERO.tanh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::NEGATIVE_ZERO.tanh_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn tanh_prec_properties_helper(x: Float, prec: u64) {
+    let (tanh, o) = x.clone().tanh_prec(prec);
+    assert!(tanh.is_valid());
+
+    let (tanh_alt, o_alt) = x.tanh_prec_ref(prec);
+    assert!(tanh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tanh_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let (tanh_alt, o_alt) = x.tanh_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let (rug_tanh, rug_o) = rug_tanh_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tanh)),
+        ComparableFloatRef(&tanh)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(tanh.is_nan() || tanh >= -1i32 && tanh <= 1u32);
+
+    if tanh.is_normal() {
+        assert_eq!(tanh.get_prec(), Some(prec));
+    }
+
+    let (tanh_alt, o_alt) = (-&x).tanh_prec(prec);
+    assert_eq!(ComparableFloat(-tanh_alt), ComparableFloat(tanh.clone()));
+    assert_eq!(o_alt.reverse(), o);
+}
+
+#[test]
+fn tanh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        tanh_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (tanh, o) = Float::NAN.tanh_prec(prec);
+        assert!(tanh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::INFINITY.tanh_prec(prec);
+        assert_eq!(
+            ComparableFloat(tanh),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::NEGATIVE_INFINITY.tanh_prec(prec);
+        assert_eq!(
+            ComparableFloat(tanh),
+            ComparableFloat(-Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::ZERO.tanh_prec(prec);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::NEGATIVE_ZERO.tanh_prec(prec);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn tanh_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (tanh, o) = x.clone().tanh_round(rm);
+    assert!(tanh.is_valid());
+
+    let (tanh_alt, o_alt) = x.tanh_round_ref(rm);
+    assert!(tanh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tanh_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let (tanh_alt, o_alt) = x.tanh_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    assert!(tanh.is_nan() || tanh >= -1i32 && tanh <= 1u32);
+
+    let (tanh_alt, o_alt) = (-&x).tanh_round(-rm);
+    assert_eq!(ComparableFloat(-tanh_alt), ComparableFloat(tanh.clone()));
+    assert_eq!(o_alt.reverse(), o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tanh, rug_o) = rug_tanh_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tanh)),
+            ComparableFloatRef(&tanh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.tanh_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(tanh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.tanh_round_ref(Exact));
+    }
+}
+
+#[test]
+fn tanh_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        tanh_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (tanh, o) = Float::NAN.tanh_round(rm);
+        assert!(tanh.is_nan());
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::INFINITY.tanh_round(rm);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::ONE));
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::NEGATIVE_INFINITY.tanh_round(rm);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::NEGATIVE_ONE));
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::ZERO.tanh_round(rm);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (tanh, o) = Float::NEGATIVE_ZERO.tanh_round(rm);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn tanh_properties_helper(x: Float) {
+    let tanh = x.clone().tanh();
+    assert!(tanh.is_valid());
+
+    let tanh_alt = (&x).tanh();
+    assert!(tanh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+
+    let mut x_alt = x.clone();
+    x_alt.tanh_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tanh));
+
+    let tanh_alt = x.tanh_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    let tanh_alt = x.tanh_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+
+    assert_eq!(tanh.is_nan(), x.is_nan());
+    assert!(tanh.is_nan() || tanh >= -1i32 && tanh <= 1u32);
+
+    let rug_tanh = rug_tanh(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tanh)),
+        ComparableFloatRef(&tanh),
+    );
+}
+
+#[test]
+fn tanh_properties() {
+    float_gen().test_properties(|x| {
+        tanh_properties_helper(x);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn tanh_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (tanh, o) = Float::tanh_rational_prec_round(x.clone(), prec, rm);
+    assert!(tanh.is_valid());
+
+    let (tanh_alt, o_alt) = Float::tanh_rational_prec_round_ref(&x, prec, rm);
+    assert!(tanh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tanh, rug_o) = rug_tanh_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tanh)),
+            ComparableFloatRef(&tanh)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!tanh.is_nan());
+    assert!(tanh.is_nan() || tanh >= -1i32 && tanh <= 1u32);
+
+    if tanh.is_normal() {
+        assert_eq!(tanh.get_prec(), Some(prec));
+    }
+
+    if x != 0u32 {
+        let (tanh_alt, o_alt) = Float::tanh_rational_prec_round(-&x, prec, -rm);
+        assert_eq!(ComparableFloat(-tanh_alt), ComparableFloat(tanh.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::tanh_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(tanh.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::tanh_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn tanh_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        tanh_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (tanh, o) = Float::tanh_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::manual_range_contains, clippy::needless_pass_by_value)]
+fn tanh_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (tanh, o) = Float::tanh_rational_prec(x.clone(), prec);
+    assert!(tanh.is_valid());
+
+    let (tanh_alt, o_alt) = Float::tanh_rational_prec_ref(&x, prec);
+    assert!(tanh_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let (tanh_alt, o_alt) = Float::tanh_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&tanh_alt), ComparableFloatRef(&tanh));
+    assert_eq!(o_alt, o);
+
+    let (rug_tanh, rug_o) = rug_tanh_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tanh)),
+        ComparableFloatRef(&tanh)
+    );
+    assert_eq!(rug_o, o);
+
+    assert!(tanh.is_nan() || tanh >= -1i32 && tanh <= 1u32);
+
+    if tanh.is_normal() {
+        assert_eq!(tanh.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn tanh_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        tanh_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (tanh, o) = Float::tanh_rational_prec(Rational::ZERO, prec);
+        assert_eq!(ComparableFloat(tanh), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_tanh() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_tanh(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 1.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, -1.0);
+    test::<f32>(0.0, 0.0);
+    test::<f32>(-0.0, -0.0);
+    test::<f32>(1.0, 0.7615942);
+    test::<f32>(-1.0, -0.7615942);
+    test::<f32>(0.5, 0.46211717);
+    test::<f32>(-0.5, -0.46211717);
+    test::<f32>(2.0, 0.9640276);
+    test::<f32>(10.0, 1.0);
+    test::<f32>(0.001, 0.0009999997);
+    test::<f32>(10000000000.0, 1.0);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 1.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, -1.0);
+    test::<f64>(0.0, 0.0);
+    test::<f64>(-0.0, -0.0);
+    test::<f64>(1.0, 0.7615941559557649);
+    test::<f64>(-1.0, -0.7615941559557649);
+    test::<f64>(0.5, 0.46211715726000974);
+    test::<f64>(-0.5, -0.46211715726000974);
+    test::<f64>(2.0, 0.9640275800758169);
+    test::<f64>(10.0, 0.9999999958776927);
+    test::<f64>(0.001, 0.0009999996666668);
+    test::<f64>(10000000000.0, 1.0);
+    test::<f64>(1.0e300, 1.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_tanh_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let tanh = primitive_float_tanh(x);
+        assert_eq!(tanh.is_nan(), x.is_nan());
+        assert!(tanh.is_nan() || tanh >= -T::ONE && tanh <= T::ONE);
+        assert_eq!(NiceFloat(primitive_float_tanh(-x)), NiceFloat(-tanh));
+    });
+}
+
+#[test]
+fn primitive_float_tanh_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_tanh_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_tanh_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_tanh_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", 0.0);
+    test::<f32>("1", 0.7615942);
+    test::<f32>("-1", -0.7615942);
+    test::<f32>("1/2", 0.46211717);
+    test::<f32>("-1/2", -0.46211717);
+    test::<f32>("1/3", 0.32151273);
+    test::<f32>("22/7", 0.99628145);
+    test::<f32>("3/2", 0.90514827);
+    test::<f32>("1000000", 1.0);
+
+    test::<f64>("0", 0.0);
+    test::<f64>("1", 0.7615941559557649);
+    test::<f64>("-1", -0.7615941559557649);
+    test::<f64>("1/2", 0.46211715726000974);
+    test::<f64>("-1/2", -0.46211715726000974);
+    test::<f64>("1/3", 0.32151273753163434);
+    test::<f64>("22/7", 0.9962814746419252);
+    test::<f64>("3/2", 0.9051482536448664);
+    test::<f64>("1000000", 1.0);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_tanh_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let tanh = primitive_float_tanh_rational::<T>(&x);
+        assert!(!tanh.is_nan());
+        assert!(tanh.is_nan() || tanh >= -T::ONE && tanh <= T::ONE);
+        if x != 0u32 {
+            assert_eq!(
+                NiceFloat(primitive_float_tanh_rational::<T>(&-&x)),
+                NiceFloat(-tanh)
+            );
+        }
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_tanh_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_tanh(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_tanh_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_tanh_rational_properties_helper);
+}
@@ -0,0 +1,8424 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{UpperIncompleteGamma, UpperIncompleteGammaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_pair_gen, unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::upper_incomplete_gamma::{
+    primitive_float_upper_incomplete_gamma, primitive_float_upper_incomplete_gamma_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::upper_incomplete_gamma::{
+    rug_upper_incomplete_gamma, rug_upper_incomplete_gamma_prec,
+    rug_upper_incomplete_gamma_prec_round, rug_upper_incomplete_gamma_rational_prec,
+    rug_upper_incomplete_gamma_rational_prec_round, rug_upper_incomplete_gamma_round,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_47,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_28, float_float_unsigned_triple_gen_var_1,
+    float_pair_gen, rational_rational_unsigned_rounding_mode_quadruple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{
+    rational_pair_gen, rational_rational_unsigned_triple_gen_var_2,
+};
+use std::cmp::max;
+use std::panic::catch_unwind;
+
+// MPFR's `mpfr_gamma_inc` becomes very slow when either argument is large or tiny, so the oracle is
+// only consulted for moderate exponents.
+fn rug_upper_incomplete_gamma_applies(s: &Float, x: &Float) -> bool {
+    [s, x]
+        .into_iter()
+        .all(|a| a.get_exponent().is_none_or(|e| (-64..=10).contains(&e)))
+}
+
+fn rug_upper_incomplete_gamma_rational_applies(s: &Rational, x: &Rational) -> bool {
+    [s, x]
+        .into_iter()
+        .all(|a| a.significant_bits() <= 64 && a.lt_abs(&1024u32))
+}
+
+fn upper_incomplete_gamma_identity_properties(x: &Float, prec: u64, rm: RoundingMode) {
+    // Γ(1, x) = e^(-x)
+    if rm != Exact && *x > 0u32 && x.is_finite() {
+        let (gamma, o) = Float::ONE.upper_incomplete_gamma_prec_round_val_ref(x, prec, rm);
+        let (gamma_alt, o_alt) = (-x).exp_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(gamma_alt), ComparableFloat(gamma));
+        assert_eq!(o_alt, o);
+    }
+}
+
+fn upper_incomplete_gamma_special_values_properties(prec: u64, rm: RoundingMode) {
+    let f = |s: Float, x: Float| s.upper_incomplete_gamma_prec_round(x, prec, rm);
+    assert!(f(Float::NAN, Float::ONE).0.is_nan());
+    assert!(f(Float::ONE, Float::NAN).0.is_nan());
+    assert!(f(Float::ONE, Float::NEGATIVE_ONE).0.is_nan());
+    assert!(f(Float::ONE, Float::NEGATIVE_INFINITY).0.is_nan());
+    assert!(f(Float::INFINITY, Float::INFINITY).0.is_nan());
+    assert!(f(Float::NEGATIVE_INFINITY, Float::ZERO).0.is_nan());
+    assert_eq!(f(Float::ONE, Float::INFINITY), (Float::ZERO, Equal));
+    assert_eq!(
+        f(Float::INFINITY, Float::NEGATIVE_ONE),
+        (Float::INFINITY, Equal)
+    );
+    assert_eq!(f(Float::INFINITY, Float::ONE), (Float::INFINITY, Equal));
+    assert_eq!(
+        f(Float::NEGATIVE_INFINITY, Float::ONE_HALF),
+        (Float::INFINITY, Equal)
+    );
+    assert_eq!(
+        f(Float::NEGATIVE_INFINITY, Float::ONE),
+        (Float::ZERO, Equal)
+    );
+    assert_eq!(f(Float::ZERO, Float::ZERO), (Float::INFINITY, Equal));
+    assert_eq!(
+        f(Float::NEGATIVE_ZERO, Float::ZERO),
+        (Float::NEGATIVE_INFINITY, Equal)
+    );
+    assert_eq!(f(Float::ONE, Float::ZERO), (Float::ONE, Equal));
+    assert!(f(Float::NEGATIVE_ONE, Float::ZERO).0.is_nan());
+}
+
+fn upper_incomplete_gamma_rational_special_values_properties(prec: u64, rm: RoundingMode) {
+    let f = |s: Rational, x: Rational| {
+        Float::upper_incomplete_gamma_rational_prec_round(s, x, prec, rm)
+    };
+    assert!(f(Rational::ONE, Rational::NEGATIVE_ONE).0.is_nan());
+    assert_eq!(f(Rational::ZERO, Rational::ZERO), (Float::INFINITY, Equal));
+    assert_eq!(f(Rational::ONE, Rational::ZERO), (Float::ONE, Equal));
+    assert!(f(Rational::NEGATIVE_ONE, Rational::ZERO).0.is_nan());
+}
+
+#[test]
+fn test_upper_incomplete_gamma() {
+    let test = |a: &str, a_hex: &str, b: &str, b_hex: &str, out: &str, out_hex: &str| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let gamma = s.clone().upper_incomplete_gamma(x.clone());
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+
+        let gamma_alt = s.clone().upper_incomplete_gamma(&x);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        let gamma_alt = (&s).upper_incomplete_gamma(x.clone());
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        let gamma_alt = (&s).upper_incomplete_gamma(&x);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+
+        let mut gamma_alt = s.clone();
+        gamma_alt.upper_incomplete_gamma_assign(x.clone());
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        let mut gamma_alt = s.clone();
+        gamma_alt.upper_incomplete_gamma_assign(&x);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+
+        if rug_upper_incomplete_gamma_applies(&s, &x) {
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_upper_incomplete_gamma(
+                    &rug::Float::exact_from(&s),
+                    &rug::Float::exact_from(&x)
+                ))),
+                ComparableFloatRef(&gamma),
+            );
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("NaN", "NaN", "0.0", "0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "NaN",
+        "NaN",
+    );
+    test("NaN", "NaN", "123.0", "0x7b.0#7", "NaN", "NaN");
+    test("NaN", "NaN", "7.9e-31", "0x1.0E-25#1", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "Infinity",
+        "Infinity",
+    );
+    test("-Infinity", "-Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test("-Infinity", "-Infinity", "0.0", "0x0.0", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "Infinity",
+        "Infinity",
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "Infinity", "Infinity");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "Infinity", "Infinity");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.25", "0x0.4#1");
+    test("0.0", "0x0.0", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.018732469573308260",
+        "0x0.04cba6b030d003c#53",
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        "3.091e-56",
+        "0xc.2E-47#7",
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "64.0",
+        "0x4.0E+1#1",
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1", "0.25", "0x0.4#1");
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.018732469573308260",
+        "0x0.04cba6b030d003c#53",
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        "3.091e-56",
+        "0xc.2E-47#7",
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "64.0",
+        "0x4.0E+1#1",
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "0.25", "0x0.4#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.065988035845312543",
+        "0x0.10e49787e20548#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        "3.834e-54",
+        "0x5.eE-45#7",
+    );
+    test("1.0", "0x1.0#1", "7.9e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-1.0", "-0x1.0#1", "1.0", "0x1.0#1", "0.12", "0x0.2#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.0055431721774664251",
+        "0x0.016b46ff37e384b#53",
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        "2.489e-58",
+        "0x1.90E-48#7",
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "1.3e30",
+        "0x1.0E+25#1",
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN", "NaN", "NaN");
+    test("0.50", "0x0.8#1", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("0.50", "0x0.8#1", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.50", "0x0.8#1", "0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("0.50", "0x0.8#1", "-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("0.50", "0x0.8#1", "1.0", "0x1.0#1", "0.25", "0x0.4#1");
+    test("0.50", "0x0.8#1", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.034951776179858916",
+        "0x0.08f2997fa12dd98#53",
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        "3.416e-55",
+        "0x8.6E-46#7",
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.0",
+        "0x2.0#1",
+    );
+    test("3.0", "0x3.0#2", "NaN", "NaN", "NaN", "NaN");
+    test("3.0", "0x3.0#2", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("3.0", "0x3.0#2", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("3.0", "0x3.0#2", "0.0", "0x0.0", "2.0", "0x2.0#2");
+    test("3.0", "0x3.0#2", "-0.0", "-0x0.0", "2.0", "0x2.0#2");
+    test("3.0", "0x3.0#2", "1.0", "0x1.0#1", "2.0", "0x2.0#2");
+    test("3.0", "0x3.0#2", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.97831352787792047",
+        "0x0.fa72c15f785560#53",
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        "5.880e-50",
+        "0x1.60E-41#7",
+    );
+    test("3.0", "0x3.0#2", "7.9e-31", "0x1.0E-25#1", "2.0", "0x2.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        "2.1361269525425293",
+        "0x2.22d937494639#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-1.0",
+        "-0x1.0#1",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.1979302081330827",
+        "0x1.32ab8ddad272c#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        "1.1616352423461038e-49",
+        "0x2.b7641538fcbfE-41#50",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+    );
+    test("-3.5", "-0x3.8#3", "NaN", "NaN", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "Infinity", "Infinity", "0.0", "0x0.0");
+    test("-3.5", "-0x3.8#3", "-Infinity", "-Infinity", "NaN", "NaN");
+    test("-3.5", "-0x3.8#3", "0.0", "0x0.0", "0.25", "0x0.4#3");
+    test("-3.5", "-0x3.8#3", "-0.0", "-0x0.0", "0.25", "0x0.4#3");
+    test("-3.5", "-0x3.8#3", "1.0", "0x1.0#1", "0.078", "0x0.14#3");
+    test("-3.5", "-0x3.8#3", "-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.00029923774198334976",
+        "0x0.00139c6050c7b671#53",
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        "1.443e-63",
+        "0x9.8E-53#7",
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "7.2e104",
+        "0x1.4E+87#3",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.0",
+        "0x0.0",
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.0",
+        "-0x0.0",
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        "0.25640498828873327508069339083195",
+        "0x0.41a3c1df3b3b3918903a676b20#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-1.0",
+        "-0x1.0#1",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.028355797501304807299179485747628",
+        "0x0.07425356eb8bd925c0a828bc5e0#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        "1.5352522507273256767659665885462e-55",
+        "0x3.c3b18661572b110c875357ef4E-46#100",
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        "2.6789385344305509271557537139479",
+        "0x2.adceea715f963d2fe7a9130c8#100",
+    );
+}
+
+#[test]
+fn test_upper_incomplete_gamma_prec() {
+    let test = |a: &str,
+                a_hex: &str,
+                b: &str,
+                b_hex: &str,
+                prec: u64,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let (gamma, o) = s.clone().upper_incomplete_gamma_prec(x.clone(), prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = s.clone().upper_incomplete_gamma_prec_val_ref(&x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_ref_val(x.clone(), prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_ref_ref(&x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.upper_incomplete_gamma_prec_assign(x.clone(), prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.upper_incomplete_gamma_prec_assign_ref(&x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        if rug_upper_incomplete_gamma_applies(&s, &x) {
+            let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_prec(
+                &rug::Float::exact_from(&s),
+                &rug::Float::exact_from(&x),
+                prec,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "Infinity", "Infinity", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "123.0", "0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("Infinity", "Infinity", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, "0.25", "0x0.4#1", Greater,
+    );
+    test("0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "4.1e-56",
+        "0x1.0E-46#1",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "64.0",
+        "0x4.0E+1#1",
+        Less,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, "0.25", "0x0.4#1", Greater,
+    );
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "4.1e-56",
+        "0x1.0E-46#1",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "64.0",
+        "0x4.0E+1#1",
+        Less,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", 1, "1.0", "0x1.0#1", Equal);
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.36768",
+        "0x0.5e2#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.36787944117144232159552377016144",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.066040",
+        "0x0.10e8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.065988035845312546616330078706240",
+        "0x0.10e49787e2054849991887572e#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "2.6e-54",
+        "0x4.0E-45#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "3.8183e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "3.8174971886711746257331132168912e-54",
+        "0x5.d9ad177613c5f1acc3ba61090E-45#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.99999999999999999999999999999921",
+        "0x0.fffffffffffffffffffffffff#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", 1, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.14844",
+        "0x0.260#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.14849550677592204791835999470131",
+        "0x0.2603cd31349db7fb5980efa5dc#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.0055466",
+        "0x0.016b8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.0055431721774664250655052405297130",
+        "0x0.016b46ff37e384af82887a1a372#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "3.2e-58",
+        "0x2.0E-48#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "2.4830e-58",
+        "0x1.8f0E-48#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "2.4832379643383588714124709928254e-58",
+        "0x1.8f0a7c140a63f59211890093cE-48#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1267650600228229401496703205306.0",
+        "0xfffffffffffffffffffffffba.0#100",
+        Less,
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.27881",
+        "0x0.476#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.27880558528066197649923261107751",
+        "0x0.475fcd86b8fb252616e32dd2d8#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.031",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.034973",
+        "0x0.08f4#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.034951776179858919083738832701428",
+        "0x0.08f2997fa12dd9b2dac95dc426#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "3.3e-55",
+        "0x8.0E-46#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "3.4283e-55",
+        "0x8.68E-46#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "3.4282961980060159281682841049297e-55",
+        "0x8.67f9f5710fbff978d010814bE-46#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.7715",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.7724538509055142509413280830908",
+        "0x1.c5bf891b4ef62a79c3b0520d6#100",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "3.0", "0x3.0#2", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", "0.0", "0x0.0", 1, "2.0", "0x2.0#1", Equal);
+    test(
+        "3.0", "0x3.0#2", "-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.8398",
+        "0x1.d70#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.8393972058572116079776188508080",
+        "0x1.d6e2bc3b82b05b85a659e58ce#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.97852",
+        "0x0.fa8#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.97831352787792047592279229819860",
+        "0x0.fa72c15f78556017745d3a8b0#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "4.3e-50",
+        "0x1.0E-41#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "5.8717e-50",
+        "0x1.5f8E-41#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "5.8701654270196652219898081936175e-50",
+        "0x1.5f6805fe3b79b4c98a58ae1eaE-41#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "2.1367",
+        "0x2.23#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.1361269525425307087527854101369",
+        "0x2.22d93749463967e3437e01b7c#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.1973",
+        "0x1.328#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.1979302081330826901308872540789",
+        "0x1.32ab8ddad272beb86d4859726#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "8.6e-50",
+        "0x2.0E-41#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.1610e-49",
+        "0x2.b7E-41#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.1616352423461030863703838658524e-49",
+        "0x2.b7641538fcbe80dd24b645b8cE-41#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "2.2880377953400321441406907440419",
+        "0x2.49bcd84eff543f41b598fe364#100",
+        Less,
+    );
+    test("-3.5", "-0x3.8#3", "NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test(
+        "-3.5", "-0x3.8#3", "Infinity", "Infinity", 1, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "0.0", "0x0.0", 1, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "-0.0", "-0x0.0", 1, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", 1, "0.062", "0x0.1#1", Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.077515",
+        "0x0.13d8#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.077520797868619188951837709322382",
+        "0x0.13d8672b9b050eb7e485da97d6#100",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.00024",
+        "0x0.001#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.00029945",
+        "0x0.0013a0#10",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.00029923774198334976380112666602230",
+        "0x0.00139c6050c7b67124bae646109a#100",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "1.2e-63",
+        "0x8.0E-53#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.4504e-63",
+        "0x9.8cE-53#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.4511727247274716834384682547705e-63",
+        "0x9.8d38a0d2bd2b11cfb2e1b276E-53#100",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "5.7e104",
+        "0x1.0E+87#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "6.5513e104",
+        "0x1.248E+87#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "6.5528531885430614617452025579956e104",
+        "0x1.2492492492492492492492490E+87#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "Infinity",
+        "Infinity",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.0",
+        "0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.0",
+        "-0x0.0",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.25635",
+        "0x0.41a#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.25640498828873327508069339083195",
+        "0x0.41a3c1df3b3b3918903a676b20#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.028351",
+        "0x0.0742#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.028355797501304807299179485747628",
+        "0x0.07425356eb8bd925c0a828bc5e0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        "1.6e-55",
+        "0x4.0E-46#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        "1.5357e-55",
+        "0x3.c4E-46#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.5352522507273256767659665885462e-55",
+        "0x3.c3b18661572b110c875357ef4E-46#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "2.6789385344305509271557537139479",
+        "0x2.adceea715f963d2fe7a9130c8#100",
+        Greater,
+    );
+}
+
+#[test]
+fn upper_incomplete_gamma_prec_fail() {
+    assert_panic!(Float::from(3).upper_incomplete_gamma_prec(Float::from(4), 0));
+    assert_panic!(Float::from(3).upper_incomplete_gamma_prec_ref_ref(&Float::from(4), 0));
+}
+
+#[test]
+fn test_upper_incomplete_gamma_round() {
+    let test = |a: &str,
+                a_hex: &str,
+                b: &str,
+                b_hex: &str,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let (gamma, o) = s.clone().upper_incomplete_gamma_round(x.clone(), rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = s.clone().upper_incomplete_gamma_round_val_ref(&x, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.upper_incomplete_gamma_round_ref_val(x.clone(), rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.upper_incomplete_gamma_round_ref_ref(&x, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.upper_incomplete_gamma_round_assign(x.clone(), rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.upper_incomplete_gamma_round_assign_ref(&x, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        if rug_upper_incomplete_gamma_applies(&s, &x)
+            && let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+        {
+            let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_round(
+                &rug::Float::exact_from(&s),
+                &rug::Float::exact_from(&x),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", Nearest, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.018732469573308260",
+        "0x0.04cba6b030d003c#53",
+        Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "3.091e-56",
+        "0xc.2E-47#7",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "64.0",
+        "0x4.0E+1#1",
+        Less,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Nearest, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.018732469573308260",
+        "0x0.04cba6b030d003c#53",
+        Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "3.091e-56",
+        "0xc.2E-47#7",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "64.0",
+        "0x4.0E+1#1",
+        Less,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("1.0", "0x1.0#1", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", Nearest, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", Nearest, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Floor, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Ceiling, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Down, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Up, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Nearest, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.065988035845312543",
+        "0x0.10e49787e20548#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.065988035845312557",
+        "0x0.10e49787e20549#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.065988035845312543",
+        "0x0.10e49787e20548#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.065988035845312557",
+        "0x0.10e49787e20549#53",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.065988035845312543",
+        "0x0.10e49787e20548#53",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "3.793e-54",
+        "0x5.dE-45#7",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "3.834e-54",
+        "0x5.eE-45#7",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "3.793e-54",
+        "0x5.dE-45#7",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "3.834e-54",
+        "0x5.eE-45#7",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "3.834e-54",
+        "0x5.eE-45#7",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Floor, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Ceiling, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Down, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Up, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Nearest, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.0055431721774664242",
+        "0x0.016b46ff37e384a#53",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.0055431721774664251",
+        "0x0.016b46ff37e384b#53",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.0055431721774664242",
+        "0x0.016b46ff37e384a#53",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.0055431721774664251",
+        "0x0.016b46ff37e384b#53",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.0055431721774664251",
+        "0x0.016b46ff37e384b#53",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "2.464e-58",
+        "0x1.8cE-48#7",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "2.489e-58",
+        "0x1.90E-48#7",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "2.464e-58",
+        "0x1.8cE-48#7",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "2.489e-58",
+        "0x1.90E-48#7",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "2.489e-58",
+        "0x1.90E-48#7",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test("0.50", "0x0.8#1", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.50", "0x0.8#1", "0.0", "0x0.0", Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "-0.0", "-0x0.0", Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Floor, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Ceiling, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Down, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Up, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", Nearest, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.034951776179858916",
+        "0x0.08f2997fa12dd98#53",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.034951776179858923",
+        "0x0.08f2997fa12dda0#53",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.034951776179858916",
+        "0x0.08f2997fa12dd98#53",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.034951776179858923",
+        "0x0.08f2997fa12dda0#53",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.034951776179858916",
+        "0x0.08f2997fa12dd98#53",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "3.416e-55",
+        "0x8.6E-46#7",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "3.467e-55",
+        "0x8.8E-46#7",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "3.416e-55",
+        "0x8.6E-46#7",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "3.467e-55",
+        "0x8.8E-46#7",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "3.416e-55",
+        "0x8.6E-46#7",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("3.0", "0x3.0#2", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.0", "0x3.0#2", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "0.0", "0x0.0", Nearest, "2.0", "0x2.0#2", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "0.0", "0x0.0", Exact, "2.0", "0x2.0#2", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "-0.0", "-0x0.0", Nearest, "2.0", "0x2.0#2", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "-0.0", "-0x0.0", Exact, "2.0", "0x2.0#2", Equal,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Floor, "1.5", "0x1.8#2", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#2", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Down, "1.5", "0x1.8#2", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Up, "2.0", "0x2.0#2", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#2", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.97831352787792047",
+        "0x0.fa72c15f785560#53",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.97831352787792059",
+        "0x0.fa72c15f785568#53",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.97831352787792047",
+        "0x0.fa72c15f785560#53",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.97831352787792059",
+        "0x0.fa72c15f785568#53",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.97831352787792047",
+        "0x0.fa72c15f785560#53",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "5.813e-50",
+        "0x1.5cE-41#7",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "5.880e-50",
+        "0x1.60E-41#7",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "5.813e-50",
+        "0x1.5cE-41#7",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "5.880e-50",
+        "0x1.60E-41#7",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "5.880e-50",
+        "0x1.60E-41#7",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "1.5",
+        "0x1.8#2",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "2.0",
+        "0x2.0#2",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "1.5",
+        "0x1.8#2",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "2.0",
+        "0x2.0#2",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "2.0",
+        "0x2.0#2",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "NaN",
+        "NaN",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "Infinity",
+        "Infinity",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "0.0",
+        "0x0.0",
+        Nearest,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "-0.0",
+        "-0x0.0",
+        Nearest,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "2.1361269525425293",
+        "0x2.22d937494639#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Ceiling,
+        "2.1361269525425328",
+        "0x2.22d93749463a#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Down,
+        "2.1361269525425293",
+        "0x2.22d937494639#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Up,
+        "2.1361269525425328",
+        "0x2.22d93749463a#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "2.1361269525425293",
+        "0x2.22d937494639#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.1979302081330825",
+        "0x1.32ab8ddad272b#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.1979302081330827",
+        "0x1.32ab8ddad272c#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.1979302081330825",
+        "0x1.32ab8ddad272b#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.1979302081330827",
+        "0x1.32ab8ddad272c#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.1979302081330827",
+        "0x1.32ab8ddad272c#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "1.1616352423461023e-49",
+        "0x2.b7641538fcbeE-41#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "1.1616352423461038e-49",
+        "0x2.b7641538fcbfE-41#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "1.1616352423461023e-49",
+        "0x2.b7641538fcbeE-41#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "1.1616352423461038e-49",
+        "0x2.b7641538fcbfE-41#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "1.1616352423461038e-49",
+        "0x2.b7641538fcbfE-41#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "2.2880377953400348",
+        "0x2.49bcd84eff55#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "2.2880377953400348",
+        "0x2.49bcd84eff55#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "2.2880377953400313",
+        "0x2.49bcd84eff54#50",
+        Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
+    );
+    test("-3.5", "-0x3.8#3", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test(
+        "-3.5", "-0x3.8#3", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "0.0", "0x0.0", Nearest, "0.25", "0x0.4#3", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "-0.0", "-0x0.0", Nearest, "0.25", "0x0.4#3", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", Floor, "0.062", "0x0.10#3", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", Ceiling, "0.078", "0x0.14#3", Greater,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", Down, "0.062", "0x0.10#3", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", Up, "0.078", "0x0.14#3", Greater,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", Nearest, "0.078", "0x0.14#3", Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.00029923774198334976",
+        "0x0.00139c6050c7b671#53",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.00029923774198334981",
+        "0x0.00139c6050c7b672#53",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.00029923774198334976",
+        "0x0.00139c6050c7b671#53",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.00029923774198334981",
+        "0x0.00139c6050c7b672#53",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.00029923774198334976",
+        "0x0.00139c6050c7b671#53",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "1.443e-63",
+        "0x9.8E-53#7",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "1.462e-63",
+        "0x9.aE-53#7",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "1.443e-63",
+        "0x9.8E-53#7",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "1.462e-63",
+        "0x9.aE-53#7",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "1.443e-63",
+        "0x9.8E-53#7",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "5.7e104",
+        "0x1.0E+87#3",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "7.2e104",
+        "0x1.4E+87#3",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "5.7e104",
+        "0x1.0E+87#3",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "7.2e104",
+        "0x1.4E+87#3",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "7.2e104",
+        "0x1.4E+87#3",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "NaN",
+        "NaN",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "Infinity",
+        "Infinity",
+        Nearest,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "Infinity",
+        "Infinity",
+        Exact,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-Infinity",
+        "-Infinity",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "0.0",
+        "0x0.0",
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.0",
+        "-0x0.0",
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "0.25640498828873327508069339083195",
+        "0x0.41a3c1df3b3b3918903a676b20#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Ceiling,
+        "0.25640498828873327508069339083234",
+        "0x0.41a3c1df3b3b3918903a676b28#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Down,
+        "0.25640498828873327508069339083195",
+        "0x0.41a3c1df3b3b3918903a676b20#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Up,
+        "0.25640498828873327508069339083234",
+        "0x0.41a3c1df3b3b3918903a676b28#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        Nearest,
+        "0.25640498828873327508069339083195",
+        "0x0.41a3c1df3b3b3918903a676b20#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.028355797501304807299179485747603",
+        "0x0.07425356eb8bd925c0a828bc5d8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.028355797501304807299179485747628",
+        "0x0.07425356eb8bd925c0a828bc5e0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.028355797501304807299179485747603",
+        "0x0.07425356eb8bd925c0a828bc5d8#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.028355797501304807299179485747628",
+        "0x0.07425356eb8bd925c0a828bc5e0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.028355797501304807299179485747628",
+        "0x0.07425356eb8bd925c0a828bc5e0#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Floor,
+        "1.5352522507273256767659665885450e-55",
+        "0x3.c3b18661572b110c875357ef0E-46#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "1.5352522507273256767659665885462e-55",
+        "0x3.c3b18661572b110c875357ef4E-46#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Down,
+        "1.5352522507273256767659665885450e-55",
+        "0x3.c3b18661572b110c875357ef0E-46#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Up,
+        "1.5352522507273256767659665885462e-55",
+        "0x3.c3b18661572b110c875357ef4E-46#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "1.5352522507273256767659665885462e-55",
+        "0x3.c3b18661572b110c875357ef4E-46#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Floor,
+        "2.6789385344305509271557537139448",
+        "0x2.adceea715f963d2fe7a9130c4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "2.6789385344305509271557537139479",
+        "0x2.adceea715f963d2fe7a9130c8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Down,
+        "2.6789385344305509271557537139448",
+        "0x2.adceea715f963d2fe7a9130c4#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Up,
+        "2.6789385344305509271557537139479",
+        "0x2.adceea715f963d2fe7a9130c8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "2.6789385344305509271557537139479",
+        "0x2.adceea715f963d2fe7a9130c8#100",
+        Greater,
+    );
+}
+
+#[test]
+fn upper_incomplete_gamma_round_fail() {
+    assert_panic!(Float::ONE_HALF.upper_incomplete_gamma_round(Float::ONE_HALF, Exact));
+    assert_panic!(Float::ONE_HALF.upper_incomplete_gamma_round_ref_ref(&Float::ONE_HALF, Exact));
+    assert_panic!({
+        let mut s = Float::ONE_HALF;
+        s.upper_incomplete_gamma_round_assign(Float::ONE_HALF, Exact)
+    });
+}
+
+#[test]
+fn test_upper_incomplete_gamma_prec_round() {
+    let test = |a: &str,
+                a_hex: &str,
+                b: &str,
+                b_hex: &str,
+                prec: u64,
+                rm: RoundingMode,
+                out: &str,
+                out_hex: &str,
+                o_out: Ordering| {
+        let s = parse_hex_string(a_hex);
+        assert_eq!(s.to_string(), a);
+        let x = parse_hex_string(b_hex);
+        assert_eq!(x.to_string(), b);
+
+        let (gamma, o) = s
+            .clone()
+            .upper_incomplete_gamma_prec_round(x.clone(), prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, o_out);
+
+        let (gamma_alt, o_alt) = s
+            .clone()
+            .upper_incomplete_gamma_prec_round_val_ref(&x, prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_round_ref_val(x.clone(), prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.upper_incomplete_gamma_prec_round_assign(x.clone(), prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+        let mut gamma_alt = s.clone();
+        let o_alt = gamma_alt.upper_incomplete_gamma_prec_round_assign_ref(&x, prec, rm);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma), ComparableFloatRef(&gamma_alt));
+        assert_eq!(o_alt, o);
+
+        if rug_upper_incomplete_gamma_applies(&s, &x)
+            && let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+        {
+            let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_prec_round(
+                &rug::Float::exact_from(&s),
+                &rug::Float::exact_from(&x),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Floor, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Ceiling, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Down, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Up, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Nearest, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.36768",
+        "0x0.5e2#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.36816",
+        "0x0.5e4#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.36768",
+        "0x0.5e2#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.12",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.062",
+        "0x0.1#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.065918",
+        "0x0.10e0#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.066040",
+        "0x0.10e8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.066040",
+        "0x0.10e8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "2.6e-54",
+        "0x4.0E-45#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "2.6e-54",
+        "0x4.0E-45#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "5.2e-54",
+        "0x8.0E-45#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "2.6e-54",
+        "0x4.0E-45#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "3.8132e-54",
+        "0x5.d8E-45#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "3.8183e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "3.8183e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "0.99902",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Floor, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Ceiling, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Down, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Up, "0.25", "0x0.4#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", 1, Nearest, "0.12", "0x0.2#1", Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.14844",
+        "0x0.260#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.14868",
+        "0x0.261#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.14844",
+        "0x0.260#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.0078",
+        "0x0.02#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.0078",
+        "0x0.02#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.0039",
+        "0x0.01#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.0055389",
+        "0x0.016b0#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.0055466",
+        "0x0.016b8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.0055466",
+        "0x0.016b8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "1.6e-58",
+        "0x1.0E-48#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "3.2e-58",
+        "0x2.0E-48#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "1.6e-58",
+        "0x1.0E-48#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "3.2e-58",
+        "0x2.0E-48#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "3.2e-58",
+        "0x2.0E-48#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "2.4830e-58",
+        "0x1.8f0E-48#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "2.4861e-58",
+        "0x1.8f8E-48#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "2.4830e-58",
+        "0x1.8f0E-48#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "6.3e29",
+        "0x8.0E+24#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "1.3e30",
+        "0x1.0E+25#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.2664e30",
+        "0xf.fcE+24#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.2677e30",
+        "0x1.000E+25#10",
+        Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Floor, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Ceiling, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Down, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Up, "0.50", "0x0.8#1", Greater,
+    );
+    test(
+        "0.50", "0x0.8#1", "1.0", "0x1.0#1", 1, Nearest, "0.25", "0x0.4#1", Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.27832",
+        "0x0.474#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.27881",
+        "0x0.476#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.27881",
+        "0x0.476#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.031",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.062",
+        "0x0.1#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.031",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.062",
+        "0x0.1#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.031",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.034912",
+        "0x0.08f0#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.034973",
+        "0x0.08f4#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.034973",
+        "0x0.08f4#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "3.3e-55",
+        "0x8.0E-46#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "6.5e-55",
+        "0x1.0E-45#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "3.3e-55",
+        "0x8.0E-46#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "6.5e-55",
+        "0x1.0E-45#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "3.3e-55",
+        "0x8.0E-46#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "3.4220e-55",
+        "0x8.64E-46#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "3.4283e-55",
+        "0x8.68E-46#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "3.4283e-55",
+        "0x8.68E-46#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.7715",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.7734",
+        "0x1.c60#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.7715",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "1.8379",
+        "0x1.d68#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.8398",
+        "0x1.d70#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "1.8398",
+        "0x1.d70#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.50",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.97754",
+        "0x0.fa4#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.97852",
+        "0x0.fa8#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.97852",
+        "0x0.fa8#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "4.3e-50",
+        "0x1.0E-41#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "8.6e-50",
+        "0x2.0E-41#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "4.3e-50",
+        "0x1.0E-41#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "8.6e-50",
+        "0x2.0E-41#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "4.3e-50",
+        "0x1.0E-41#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "5.8634e-50",
+        "0x1.5f0E-41#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "5.8717e-50",
+        "0x1.5f8E-41#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "5.8717e-50",
+        "0x1.5f8E-41#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.9980",
+        "0x1.ff8#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "2.0000",
+        "0x2.00#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "2.1328",
+        "0x2.22#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "2.1367",
+        "0x2.23#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "2.1367",
+        "0x2.23#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.1973",
+        "0x1.328#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.1992",
+        "0x1.330#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.1973",
+        "0x1.328#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "8.6e-50",
+        "0x2.0E-41#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.7e-49",
+        "0x4.0E-41#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "8.6e-50",
+        "0x2.0E-41#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "1.7e-49",
+        "0x4.0E-41#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "8.6e-50",
+        "0x2.0E-41#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.1610e-49",
+        "0x2.b7E-41#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.1627e-49",
+        "0x2.b8E-41#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.1610e-49",
+        "0x2.b7E-41#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "2.2852",
+        "0x2.49#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "2.2891",
+        "0x2.4a#10",
+        Greater,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", 1, Floor, "0.062", "0x0.1#1", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", 1, Ceiling, "0.12", "0x0.2#1", Greater,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", 1, Down, "0.062", "0x0.1#1", Less,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", 1, Up, "0.12", "0x0.2#1", Greater,
+    );
+    test(
+        "-3.5", "-0x3.8#3", "1.0", "0x1.0#1", 1, Nearest, "0.062", "0x0.1#1", Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.077515",
+        "0x0.13d8#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.077637",
+        "0x0.13e0#10",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.077515",
+        "0x0.13d8#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.00024",
+        "0x0.001#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.00049",
+        "0x0.002#1",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.00024",
+        "0x0.001#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.00049",
+        "0x0.002#1",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.00024",
+        "0x0.001#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.00029898",
+        "0x0.001398#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.00029945",
+        "0x0.0013a0#10",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.00029945",
+        "0x0.0013a0#10",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "1.2e-63",
+        "0x8.0E-53#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "2.4e-63",
+        "0x1.0E-52#1",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "1.2e-63",
+        "0x8.0E-53#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "2.4e-63",
+        "0x1.0E-52#1",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "1.2e-63",
+        "0x8.0E-53#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.4504e-63",
+        "0x9.8cE-53#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.4528e-63",
+        "0x9.90E-53#10",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.4504e-63",
+        "0x9.8cE-53#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "5.7e104",
+        "0x1.0E+87#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "1.1e105",
+        "0x2.0E+87#1",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "5.7e104",
+        "0x1.0E+87#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "1.1e105",
+        "0x2.0E+87#1",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "5.7e104",
+        "0x1.0E+87#1",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "6.5513e104",
+        "0x1.248E+87#10",
+        Less,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "6.5625e104",
+        "0x1.250E+87#10",
+        Greater,
+    );
+    test(
+        "-3.5",
+        "-0x3.8#3",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "6.5513e104",
+        "0x1.248E+87#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Floor,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Ceiling,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Down,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Up,
+        "0.50",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Nearest,
+        "0.25",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.25635",
+        "0x0.41a#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.25684",
+        "0x0.41c#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.25635",
+        "0x0.41a#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.016",
+        "0x0.04#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "0.031",
+        "0x0.08#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.028351",
+        "0x0.0742#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.028381",
+        "0x0.0744#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.028351",
+        "0x0.0742#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Floor,
+        "8.2e-56",
+        "0x2.0E-46#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "1.6e-55",
+        "0x4.0E-46#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Down,
+        "8.2e-56",
+        "0x2.0E-46#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Up,
+        "1.6e-55",
+        "0x4.0E-46#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Nearest,
+        "1.6e-55",
+        "0x4.0E-46#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "1.5341e-55",
+        "0x3.c3E-46#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.5357e-55",
+        "0x3.c4E-46#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.5357e-55",
+        "0x3.c4E-46#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "2.6758",
+        "0x2.ad#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "2.6797",
+        "0x2.ae#10",
+        Greater,
+    );
+}
+
+#[test]
+fn upper_incomplete_gamma_prec_round_fail() {
+    assert_panic!(Float::from(3).upper_incomplete_gamma_prec_round(Float::from(4), 0, Floor));
+    assert_panic!(Float::from(3).upper_incomplete_gamma_prec_round_ref_ref(
+        &Float::from(4),
+        0,
+        Floor
+    ));
+    assert_panic!(Float::ONE_HALF.upper_incomplete_gamma_prec_round(Float::ONE_HALF, 10, Exact));
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn upper_incomplete_gamma_prec_round_properties_helper(
+    s: Float,
+    x: Float,
+    prec: u64,
+    rm: RoundingMode,
+) {
+    let (gamma, o) = s
+        .clone()
+        .upper_incomplete_gamma_prec_round(x.clone(), prec, rm);
+    assert!(gamma.is_valid());
+    let (gamma_alt, o_alt) = s
+        .clone()
+        .upper_incomplete_gamma_prec_round_val_ref(&x, prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_round_ref_val(x.clone(), prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.upper_incomplete_gamma_prec_round_assign(x.clone(), prec, rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.upper_incomplete_gamma_prec_round_assign_ref(&x, prec, rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if rug_upper_incomplete_gamma_applies(&s, &x)
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_prec_round(
+            &rug::Float::exact_from(&s),
+            &rug::Float::exact_from(&x),
+            prec,
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if s.is_nan() || x.is_nan() || x < 0u32 && s != Float::INFINITY {
+        assert!(gamma.is_nan());
+    }
+    if gamma.is_normal() {
+        assert_eq!(gamma.get_prec(), Some(prec));
+    }
+    // For a positive x, both incomplete gamma functions are integrals of a positive function
+    if x > 0u32 && !gamma.is_nan() {
+        assert!(gamma.is_sign_positive());
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (g, oo) = s.upper_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(g), ComparableFloat(gamma.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(s.upper_incomplete_gamma_prec_round_ref_ref(&x, prec, Exact));
+    }
+
+    upper_incomplete_gamma_identity_properties(&x, prec, rm);
+}
+
+#[test]
+fn upper_incomplete_gamma_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_28().test_properties(
+        |(s, x, prec, rm)| {
+            upper_incomplete_gamma_prec_round_properties_helper(s, x, prec, rm);
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        upper_incomplete_gamma_special_values_properties(prec, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn upper_incomplete_gamma_prec_properties_helper(s: Float, x: Float, prec: u64) {
+    let (gamma, o) = s.clone().upper_incomplete_gamma_prec(x.clone(), prec);
+    assert!(gamma.is_valid());
+    let (gamma_alt, o_alt) = s.clone().upper_incomplete_gamma_prec_val_ref(&x, prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_ref_val(x.clone(), prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_ref_ref(&x, prec);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.upper_incomplete_gamma_prec_assign(x.clone(), prec);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.upper_incomplete_gamma_prec_assign_ref(&x, prec);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_round_ref_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if rug_upper_incomplete_gamma_applies(&s, &x) {
+        let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_prec(
+            &rug::Float::exact_from(&s),
+            &rug::Float::exact_from(&x),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn upper_incomplete_gamma_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(s, x, prec)| {
+        upper_incomplete_gamma_prec_properties_helper(s, x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn upper_incomplete_gamma_round_properties_helper(s: Float, x: Float, rm: RoundingMode) {
+    let (gamma, o) = s.clone().upper_incomplete_gamma_round(x.clone(), rm);
+    assert!(gamma.is_valid());
+    let (gamma_alt, o_alt) = s.clone().upper_incomplete_gamma_round_val_ref(&x, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_round_ref_val(x.clone(), rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_round_ref_ref(&x, rm);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.upper_incomplete_gamma_round_assign(x.clone(), rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+    let mut s_alt = s.clone();
+    let o_alt = s_alt.upper_incomplete_gamma_round_assign_ref(&x, rm);
+    assert!(s_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&s_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    let prec = max(s.significant_bits(), x.significant_bits());
+    let (gamma_alt, o_alt) = s.upper_incomplete_gamma_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    assert_eq!(o_alt, o);
+
+    if rug_upper_incomplete_gamma_applies(&s, &x)
+        && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+    {
+        let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_round(
+            &rug::Float::exact_from(&s),
+            &rug::Float::exact_from(&x),
+            rug_rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn upper_incomplete_gamma_round_properties() {
+    float_float_rounding_mode_triple_gen_var_47().test_properties(|(s, x, rm)| {
+        upper_incomplete_gamma_round_properties_helper(s, x, rm);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn upper_incomplete_gamma_properties_helper(s: Float, x: Float) {
+    let gamma = s.clone().upper_incomplete_gamma(x.clone());
+    assert!(gamma.is_valid());
+    let gamma_alt = s.clone().upper_incomplete_gamma(&x);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let gamma_alt = (&s).upper_incomplete_gamma(x.clone());
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let gamma_alt = (&s).upper_incomplete_gamma(&x);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    let mut gamma_alt = s.clone();
+    gamma_alt.upper_incomplete_gamma_assign(x.clone());
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let mut gamma_alt = s.clone();
+    gamma_alt.upper_incomplete_gamma_assign(&x);
+    assert!(gamma_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    let prec = max(s.significant_bits(), x.significant_bits());
+    let (gamma_alt, _) = s.upper_incomplete_gamma_prec_round_ref_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let (gamma_alt, _) = s.clone().upper_incomplete_gamma_prec(x.clone(), prec);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+    let (gamma_alt, _) = s.clone().upper_incomplete_gamma_round(x.clone(), Nearest);
+    assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+
+    if rug_upper_incomplete_gamma_applies(&s, &x) {
+        let rug_gamma =
+            rug_upper_incomplete_gamma(&rug::Float::exact_from(&s), &rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+    }
+}
+
+#[test]
+fn upper_incomplete_gamma_properties() {
+    float_pair_gen().test_properties(|(s, x)| {
+        upper_incomplete_gamma_properties_helper(s, x);
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_upper_incomplete_gamma() {
+    fn test<T: PrimitiveFloat>(s: T, x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(
+            NiceFloat(primitive_float_upper_incomplete_gamma(s, x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>(f32::NAN, 1.0, f32::NAN);
+    test::<f32>(1.0, f32::NAN, f32::NAN);
+    test::<f32>(1.0, 1.0, 0.36787945);
+    test::<f32>(0.5, 0.5, 0.5624182);
+    test::<f32>(2.0, 3.0, 0.19914827);
+    test::<f32>(0.0, 1.0, 0.21938394);
+    test::<f32>(-0.5, 0.25, 1.4154195);
+    test::<f32>(-2.0, 1.0, 0.10969197);
+    test::<f32>(3.0, 0.0, 2.0);
+    test::<f32>(-0.0, 0.0, f32::NEGATIVE_INFINITY);
+    test::<f32>(1.0, -1.0, f32::NAN);
+    test::<f32>(f32::INFINITY, 2.0, f32::INFINITY);
+    test::<f32>(2.0, f32::INFINITY, 0.0);
+    test::<f32>(1000.0, 1000.0, f32::INFINITY);
+    test::<f32>(0.001, 1.0e-10, 22.186552);
+    test::<f32>(5.0, 100.0, 3.8734333e-36);
+
+    test::<f64>(f64::NAN, 1.0, f64::NAN);
+    test::<f64>(1.0, f64::NAN, f64::NAN);
+    test::<f64>(1.0, 1.0, 0.36787944117144233);
+    test::<f64>(0.5, 0.5, 0.5624182315944071);
+    test::<f64>(2.0, 3.0, 0.19914827347145578);
+    test::<f64>(0.0, 1.0, 0.21938393439552029);
+    test::<f64>(-0.5, 0.25, 1.4154194561257571);
+    test::<f64>(-2.0, 1.0, 0.10969196719776014);
+    test::<f64>(3.0, 0.0, 2.0);
+    test::<f64>(-0.0, 0.0, f64::NEGATIVE_INFINITY);
+    test::<f64>(1.0, -1.0, f64::NAN);
+    test::<f64>(f64::INFINITY, 2.0, f64::INFINITY);
+    test::<f64>(2.0, f64::INFINITY, 0.0);
+    test::<f64>(1000.0, 1000.0, f64::INFINITY);
+    test::<f64>(0.001, 1.0e-10, 22.186551528882408);
+    test::<f64>(5.0, 100.0, 3.8734332808745534e-36);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_upper_incomplete_gamma_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_pair_gen::<T>().test_properties(|(s, x)| {
+        let gamma = primitive_float_upper_incomplete_gamma(s, x);
+        if s.is_nan() || x.is_nan() || x < T::ZERO && s != T::INFINITY {
+            assert!(gamma.is_nan());
+        }
+        if x > T::ZERO && !gamma.is_nan() {
+            assert!(gamma.is_sign_positive());
+        }
+    });
+}
+
+#[test]
+fn primitive_float_upper_incomplete_gamma_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_upper_incomplete_gamma_properties_helper);
+}
+
+#[test]
+fn test_upper_incomplete_gamma_rational_prec() {
+    let test = |s, x, prec, out: &str, out_hex: &str, out_o| {
+        let s = Rational::from_str(s).unwrap();
+        let x = Rational::from_str(x).unwrap();
+
+        let (gamma, o) = Float::upper_incomplete_gamma_rational_prec(s.clone(), x.clone(), prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_val_ref(s.clone(), &x, prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_ref_val(&s, x.clone(), prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) = Float::upper_incomplete_gamma_rational_prec_ref_ref(&s, &x, prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        if rug_upper_incomplete_gamma_rational_applies(&s, &x) {
+            let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_rational_prec(&s, &x, prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", "0", 1, "Infinity", "Infinity", Equal);
+    test("0", "0", 10, "Infinity", "Infinity", Equal);
+    test("0", "0", 100, "Infinity", "Infinity", Equal);
+    test("0", "1", 1, "0.25", "0x0.4#1", Greater);
+    test("0", "1", 10, "0.21948", "0x0.383#10", Greater);
+    test(
+        "0",
+        "1",
+        100,
+        "0.21938393439552027367716377546013",
+        "0x0.38298ba77f1f271f615dd7dcb4#100",
+        Greater,
+    );
+    test("0", "-1", 1, "NaN", "NaN", Equal);
+    test("0", "1/3", 1, "1.0", "0x1.0#1", Greater);
+    test("0", "1/3", 10, "0.82910", "0x0.d44#10", Greater);
+    test(
+        "0",
+        "1/3",
+        100,
+        "0.82888774534858663612020698116354",
+        "0x0.d431fcbe53cd95a7f8dcfc5fe#100",
+        Greater,
+    );
+    test("0", "5/2", 1, "0.031", "0x0.08#1", Greater);
+    test("0", "5/2", 10, "0.024902", "0x0.0660#10", Less);
+    test(
+        "0",
+        "5/2",
+        100,
+        "0.024914917870269735495628012274611",
+        "0x0.0660d2f56f7214ae67118267128#100",
+        Greater,
+    );
+    test("0", "100", 1, "3.5e-46", "0x2.0E-38#1", Less);
+    test("0", "100", 10, "3.6811e-46", "0x2.1aE-38#10", Less);
+    test(
+        "0",
+        "100",
+        100,
+        "3.6835977616820321802351926205091e-46",
+        "0x2.1a5bc1269813e4e3516b10e98E-38#100",
+        Greater,
+    );
+    test("1", "0", 1, "1.0", "0x1.0#1", Equal);
+    test("1", "0", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1",
+        "0",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", "1", 1, "0.25", "0x0.4#1", Less);
+    test("1", "1", 10, "0.36768", "0x0.5e2#10", Less);
+    test(
+        "1",
+        "1",
+        100,
+        "0.36787944117144232159552377016144",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Less,
+    );
+    test("1", "-1", 1, "NaN", "NaN", Equal);
+    test("1", "1/3", 1, "0.50", "0x0.8#1", Less);
+    test("1", "1/3", 10, "0.71680", "0x0.b78#10", Greater);
+    test(
+        "1",
+        "1/3",
+        100,
+        "0.71653131057378925042560409692533",
+        "0x0.b76e989179752689c5984c9c5#100",
+        Less,
+    );
+    test("1", "5/2", 1, "0.062", "0x0.1#1", Less);
+    test("1", "5/2", 10, "0.082031", "0x0.1500#10", Less);
+    test(
+        "1",
+        "5/2",
+        100,
+        "0.082084998623898795169528674467209",
+        "0x0.150385c094f424a75e4834abd8#100",
+        Greater,
+    );
+    test("1", "100", 1, "4.5e-44", "0x1.0E-36#1", Greater);
+    test("1", "100", 10, "3.7222e-44", "0xd.48E-37#10", Greater);
+    test(
+        "1",
+        "100",
+        100,
+        "3.7200759760208359629596958038643e-44",
+        "0xd.460f8a7157ae579eec89bae4E-37#100",
+        Greater,
+    );
+    test("-1", "0", 1, "NaN", "NaN", Equal);
+    test("-1", "1", 1, "0.12", "0x0.2#1", Less);
+    test("-1", "1", 10, "0.14844", "0x0.260#10", Less);
+    test(
+        "-1",
+        "1",
+        100,
+        "0.14849550677592204791835999470131",
+        "0x0.2603cd31349db7fb5980efa5dc#100",
+        Less,
+    );
+    test("-1", "-1", 1, "NaN", "NaN", Equal);
+    test("-1", "1/3", 1, "1.0", "0x1.0#1", Less);
+    test("-1", "1/3", 10, "1.3203", "0x1.520#10", Less);
+    test(
+        "-1",
+        "1/3",
+        100,
+        "1.3207061863727811151566053096133",
+        "0x1.5219ccf61891ddf557ebe9752#100",
+        Greater,
+    );
+    test("-1", "5/2", 1, "0.0078", "0x0.02#1", Less);
+    test("-1", "5/2", 10, "0.0079193", "0x0.0207#10", Greater);
+    test(
+        "-1",
+        "5/2",
+        100,
+        "0.0079190815792897825721834575122553",
+        "0x0.0206fc2465bc6061583e92aa76c#100",
+        Greater,
+    );
+    test("-1", "100", 1, "2.7e-48", "0x4.0E-40#1", Less);
+    test("-1", "100", 10, "3.6457e-48", "0x5.54E-40#10", Less);
+    test(
+        "-1",
+        "100",
+        100,
+        "3.6478214338803782724503183354987e-48",
+        "0x5.54cfe14cfdc2bb3ad3ee45358E-40#100",
+        Less,
+    );
+    test("1/2", "0", 1, "2.0", "0x2.0#1", Greater);
+    test("1/2", "0", 10, "1.7715", "0x1.c58#10", Less);
+    test(
+        "1/2",
+        "0",
+        100,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("1/2", "1", 1, "0.25", "0x0.4#1", Less);
+    test("1/2", "1", 10, "0.27881", "0x0.476#10", Greater);
+    test(
+        "1/2",
+        "1",
+        100,
+        "0.27880558528066197649923261107751",
+        "0x0.475fcd86b8fb252616e32dd2d8#100",
+        Greater,
+    );
+    test("1/2", "-1", 1, "NaN", "NaN", Equal);
+    test("1/2", "1/3", 1, "0.50", "0x0.8#1", Less);
+    test("1/2", "1/3", 10, "0.73438", "0x0.bc0#10", Greater);
+    test(
+        "1/2",
+        "1/3",
+        100,
+        "0.73417906023332926489633538523952",
+        "0x0.bbf328ad1c33a9d8e7e540479#100",
+        Greater,
+    );
+    test("1/2", "5/2", 1, "0.031", "0x0.08#1", Less);
+    test("1/2", "5/2", 10, "0.044922", "0x0.0b80#10", Less);
+    test(
+        "1/2",
+        "5/2",
+        100,
+        "0.044926952600007935969868810290244",
+        "0x0.0b805530203ffad46ca7939979#100",
+        Greater,
+    );
+    test("1/2", "100", 1, "2.8e-45", "0x1.0E-37#1", Less);
+    test("1/2", "100", 10, "3.7003e-45", "0x1.520E-37#10", Less);
+    test(
+        "1/2",
+        "100",
+        100,
+        "3.7017478604082789202535664481333e-45",
+        "0x1.5221c4d97c4bd77c681768f28E-37#100",
+        Less,
+    );
+    test("1/3", "0", 1, "2.0", "0x2.0#1", Less);
+    test("1/3", "0", 10, "2.6797", "0x2.ae#10", Greater);
+    test(
+        "1/3",
+        "0",
+        100,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Less,
+    );
+    test("1/3", "1", 1, "0.25", "0x0.4#1", Less);
+    test("1/3", "1", 10, "0.25635", "0x0.41a#10", Less);
+    test(
+        "1/3",
+        "1",
+        100,
+        "0.25640498828873327508069339083195",
+        "0x0.41a3c1df3b3b3918903a676b20#100",
+        Less,
+    );
+    test("1/3", "-1", 1, "NaN", "NaN", Equal);
+    test("1/3", "1/3", 1, "1.0", "0x1.0#1", Greater);
+    test("1/3", "1/3", 10, "0.75684", "0x0.c1c#10", Less);
+    test(
+        "1/3",
+        "1/3",
+        100,
+        "0.75689238102715210872254046435312",
+        "0x0.c1c3b2f71a6a9b69be2d47e5c#100",
+        Greater,
+    );
+    test("1/3", "5/2", 1, "0.031", "0x0.08#1", Less);
+    test("1/3", "5/2", 10, "0.036865", "0x0.0970#10", Greater);
+    test(
+        "1/3",
+        "5/2",
+        100,
+        "0.036860520918526480317111271411924",
+        "0x0.096fb0ebdbcce3acde18ca6619#100",
+        Less,
+    );
+    test("1/3", "100", 1, "1.4e-45", "0x8.0E-38#1", Less);
+    test("1/3", "100", 10, "1.7160e-45", "0x9.ccE-38#10", Greater);
+    test(
+        "1/3",
+        "100",
+        100,
+        "1.7153818550356861187222969236824e-45",
+        "0x9.cb088c3b9754389adb745662E-38#100",
+        Less,
+    );
+    test("3", "0", 1, "2.0", "0x2.0#1", Equal);
+    test("3", "0", 10, "2.0000", "0x2.00#10", Equal);
+    test(
+        "3",
+        "0",
+        100,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("3", "1", 1, "2.0", "0x2.0#1", Greater);
+    test("3", "1", 10, "1.8398", "0x1.d70#10", Greater);
+    test(
+        "3",
+        "1",
+        100,
+        "1.8393972058572116079776188508080",
+        "0x1.d6e2bc3b82b05b85a659e58ce#100",
+        Greater,
+    );
+    test("3", "-1", 1, "NaN", "NaN", Equal);
+    test("3", "1/3", 1, "2.0", "0x2.0#1", Greater);
+    test("3", "1/3", 10, "1.9902", "0x1.fd8#10", Less);
+    test(
+        "3",
+        "1/3",
+        100,
+        "1.9903647515938590289600113803488",
+        "0x1.fd888b5b34f015b796a70db24#100",
+        Greater,
+    );
+    test("3", "5/2", 1, "1.0", "0x1.0#1", Less);
+    test("3", "5/2", 10, "1.0879", "0x1.168#10", Greater);
+    test(
+        "3",
+        "5/2",
+        100,
+        "1.0876262317666590359962549366898",
+        "0x1.166eac37b5a2e5a9a13cb9e4e#100",
+        Less,
+    );
+    test("3", "100", 1, "3.7e-40", "0x2.0E-33#1", Less);
+    test("3", "100", 10, "3.7954e-40", "0x2.11E-33#10", Greater);
+    test(
+        "3",
+        "100",
+        100,
+        "3.7952215107364568494114816590993e-40",
+        "0x2.10fa075328e039cfd35e68c1cE-33#100",
+        Less,
+    );
+    test("-7/2", "0", 1, "0.25", "0x0.4#1", Less);
+    test("-7/2", "0", 10, "0.27002", "0x0.452#10", Less);
+    test(
+        "-7/2",
+        "0",
+        100,
+        "0.27008820585226910892162552127095",
+        "0x0.4524802b2bba4f9d86a8470478#100",
+        Less,
+    );
+    test("-7/2", "1", 1, "0.062", "0x0.1#1", Less);
+    test("-7/2", "1", 10, "0.077515", "0x0.13d8#10", Less);
+    test(
+        "-7/2",
+        "1",
+        100,
+        "0.077520797868619188951837709322382",
+        "0x0.13d8672b9b050eb7e485da97d6#100",
+        Less,
+    );
+    test("-7/2", "-1", 1, "NaN", "NaN", Equal);
+    test("-7/2", "1/3", 1, "8.0", "0x8.0#1", Less);
+    test("-7/2", "1/3", 10, "8.5000", "0x8.80#10", Less);
+    test(
+        "-7/2",
+        "1/3",
+        100,
+        "8.5038628181372650767792857284538",
+        "0x8.80fd275591e82fe45c1b29ea#100",
+        Greater,
+    );
+    test("-7/2", "5/2", 1, "0.00049", "0x0.002#1", Less);
+    test("-7/2", "5/2", 10, "0.00051689", "0x0.0021e#10", Less);
+    test(
+        "-7/2",
+        "5/2",
+        100,
+        "0.00051731252094152607721426771759364",
+        "0x0.0021e7105bf825947ac993d08bac#100",
+        Less,
+    );
+    test("-7/2", "100", 1, "4.2e-53", "0x4.0E-44#1", Greater);
+    test("-7/2", "100", 10, "3.5604e-53", "0x3.69E-44#10", Less);
+    test(
+        "-7/2",
+        "100",
+        100,
+        "3.5613226760149162315510011022617e-53",
+        "0x3.693bd8f5af4b62db3707a5e40E-44#100",
+        Less,
+    );
+    test("22/7", "0", 1, "2.0", "0x2.0#1", Less);
+    test("22/7", "0", 10, "2.2891", "0x2.4a#10", Less);
+    test(
+        "22/7",
+        "0",
+        100,
+        "2.2908675009613379957017236207921",
+        "0x2.4a764ae4192352f3b59fbc0cc#100",
+        Greater,
+    );
+    test("22/7", "1", 1, "2.0", "0x2.0#1", Less);
+    test("22/7", "1", 10, "2.1406", "0x2.24#10", Greater);
+    test(
+        "22/7",
+        "1",
+        100,
+        "2.1390302462549502017316072051454",
+        "0x2.23977c78d1e0e0689b12eaf18#100",
+        Less,
+    );
+    test("22/7", "-1", 1, "NaN", "NaN", Equal);
+    test("22/7", "1/3", 1, "2.0", "0x2.0#1", Less);
+    test("22/7", "1/3", 10, "2.2812", "0x2.48#10", Less);
+    test(
+        "22/7",
+        "1/3",
+        100,
+        "2.2830295053013108664822462134194",
+        "0x2.48749f25127af70c462b1d8d4#100",
+        Less,
+    );
+    test("22/7", "5/2", 1, "1.0", "0x1.0#1", Less);
+    test("22/7", "5/2", 10, "1.3262", "0x1.538#10", Greater);
+    test(
+        "22/7",
+        "5/2",
+        100,
+        "1.3254609308154124804111913972345",
+        "0x1.53516855fa58a6cf7af5d5918#100",
+        Greater,
+    );
+    test("22/7", "100", 1, "7.3e-40", "0x4.0E-33#1", Greater);
+    test("22/7", "100", 10, "7.3397e-40", "0x3.ffE-33#10", Greater);
+    test(
+        "22/7",
+        "100",
+        100,
+        "7.3380110044317953089159182253240e-40",
+        "0x3.fec4fb5c5de6da04c8bd44450E-33#100",
+        Greater,
+    );
+}
+
+#[test]
+fn upper_incomplete_gamma_rational_prec_fail() {
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec(
+        Rational::ONE,
+        Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec_val_ref(
+        Rational::ONE,
+        &Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec_ref_val(
+        &Rational::ONE,
+        Rational::ONE,
+        0
+    ));
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec_ref_ref(
+        &Rational::ONE,
+        &Rational::ONE,
+        0
+    ));
+}
+
+#[test]
+fn test_upper_incomplete_gamma_rational_prec_round() {
+    let test = |s, x, prec, rm, out: &str, out_hex: &str, out_o| {
+        let s = Rational::from_str(s).unwrap();
+        let x = Rational::from_str(x).unwrap();
+
+        let (gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round(s.clone(), x.clone(), prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round_val_ref(s.clone(), &x, prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round_ref_val(&s, x.clone(), prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        let (gamma, o) =
+            Float::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.to_string(), out);
+        assert_eq!(to_hex_string(&gamma), out_hex);
+        assert_eq!(o, out_o);
+
+        if rug_upper_incomplete_gamma_rational_applies(&s, &x)
+            && let Ok(rm) = rug_round_try_from_rounding_mode(rm)
+        {
+            let (rug_gamma, rug_o) =
+                rug_upper_incomplete_gamma_rational_prec_round(&s, &x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", "0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("0", "0", 1, Exact, "Infinity", "Infinity", Equal);
+    test("0", "0", 10, Floor, "Infinity", "Infinity", Equal);
+    test("0", "0", 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("0", "0", 10, Down, "Infinity", "Infinity", Equal);
+    test("0", "0", 10, Up, "Infinity", "Infinity", Equal);
+    test("0", "0", 10, Nearest, "Infinity", "Infinity", Equal);
+    test("0", "0", 10, Exact, "Infinity", "Infinity", Equal);
+    test("0", "0", 100, Nearest, "Infinity", "Infinity", Equal);
+    test("0", "0", 100, Exact, "Infinity", "Infinity", Equal);
+    test("0", "1", 1, Nearest, "0.25", "0x0.4#1", Greater);
+    test("0", "1", 10, Floor, "0.21924", "0x0.382#10", Less);
+    test("0", "1", 10, Ceiling, "0.21948", "0x0.383#10", Greater);
+    test("0", "1", 10, Down, "0.21924", "0x0.382#10", Less);
+    test("0", "1", 10, Up, "0.21948", "0x0.383#10", Greater);
+    test("0", "1", 10, Nearest, "0.21948", "0x0.383#10", Greater);
+    test(
+        "0",
+        "1",
+        100,
+        Nearest,
+        "0.21938393439552027367716377546013",
+        "0x0.38298ba77f1f271f615dd7dcb4#100",
+        Greater,
+    );
+    test("0", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "1/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("0", "1/3", 10, Floor, "0.82812", "0x0.d40#10", Less);
+    test("0", "1/3", 10, Ceiling, "0.82910", "0x0.d44#10", Greater);
+    test("0", "1/3", 10, Down, "0.82812", "0x0.d40#10", Less);
+    test("0", "1/3", 10, Up, "0.82910", "0x0.d44#10", Greater);
+    test("0", "1/3", 10, Nearest, "0.82910", "0x0.d44#10", Greater);
+    test(
+        "0",
+        "1/3",
+        100,
+        Nearest,
+        "0.82888774534858663612020698116354",
+        "0x0.d431fcbe53cd95a7f8dcfc5fe#100",
+        Greater,
+    );
+    test("0", "5/2", 1, Nearest, "0.031", "0x0.08#1", Greater);
+    test("0", "5/2", 10, Floor, "0.024902", "0x0.0660#10", Less);
+    test("0", "5/2", 10, Ceiling, "0.024933", "0x0.0662#10", Greater);
+    test("0", "5/2", 10, Down, "0.024902", "0x0.0660#10", Less);
+    test("0", "5/2", 10, Up, "0.024933", "0x0.0662#10", Greater);
+    test("0", "5/2", 10, Nearest, "0.024902", "0x0.0660#10", Less);
+    test(
+        "0",
+        "5/2",
+        100,
+        Nearest,
+        "0.024914917870269735495628012274611",
+        "0x0.0660d2f56f7214ae67118267128#100",
+        Greater,
+    );
+    test("0", "100", 1, Nearest, "3.5e-46", "0x2.0E-38#1", Less);
+    test("0", "100", 10, Floor, "3.6811e-46", "0x2.1aE-38#10", Less);
+    test(
+        "0",
+        "100",
+        10,
+        Ceiling,
+        "3.6880e-46",
+        "0x2.1bE-38#10",
+        Greater,
+    );
+    test("0", "100", 10, Down, "3.6811e-46", "0x2.1aE-38#10", Less);
+    test("0", "100", 10, Up, "3.6880e-46", "0x2.1bE-38#10", Greater);
+    test("0", "100", 10, Nearest, "3.6811e-46", "0x2.1aE-38#10", Less);
+    test(
+        "0",
+        "100",
+        100,
+        Nearest,
+        "3.6835977616820321802351926205091e-46",
+        "0x2.1a5bc1269813e4e3516b10e98E-38#100",
+        Greater,
+    );
+    test("1", "0", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("1", "0", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1", "0", 10, Floor, "1.0000", "0x1.000#10", Equal);
+    test("1", "0", 10, Ceiling, "1.0000", "0x1.000#10", Equal);
+    test("1", "0", 10, Down, "1.0000", "0x1.000#10", Equal);
+    test("1", "0", 10, Up, "1.0000", "0x1.000#10", Equal);
+    test("1", "0", 10, Nearest, "1.0000", "0x1.000#10", Equal);
+    test("1", "0", 10, Exact, "1.0000", "0x1.000#10", Equal);
+    test(
+        "1",
+        "0",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1",
+        "0",
+        100,
+        Exact,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("1", "1", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1", "1", 10, Floor, "0.36768", "0x0.5e2#10", Less);
+    test("1", "1", 10, Ceiling, "0.36816", "0x0.5e4#10", Greater);
+    test("1", "1", 10, Down, "0.36768", "0x0.5e2#10", Less);
+    test("1", "1", 10, Up, "0.36816", "0x0.5e4#10", Greater);
+    test("1", "1", 10, Nearest, "0.36768", "0x0.5e2#10", Less);
+    test(
+        "1",
+        "1",
+        100,
+        Nearest,
+        "0.36787944117144232159552377016144",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Less,
+    );
+    test("1", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("1", "1/3", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1", "1/3", 10, Floor, "0.71582", "0x0.b74#10", Less);
+    test("1", "1/3", 10, Ceiling, "0.71680", "0x0.b78#10", Greater);
+    test("1", "1/3", 10, Down, "0.71582", "0x0.b74#10", Less);
+    test("1", "1/3", 10, Up, "0.71680", "0x0.b78#10", Greater);
+    test("1", "1/3", 10, Nearest, "0.71680", "0x0.b78#10", Greater);
+    test(
+        "1",
+        "1/3",
+        100,
+        Nearest,
+        "0.71653131057378925042560409692533",
+        "0x0.b76e989179752689c5984c9c5#100",
+        Less,
+    );
+    test("1", "5/2", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test("1", "5/2", 10, Floor, "0.082031", "0x0.1500#10", Less);
+    test("1", "5/2", 10, Ceiling, "0.082153", "0x0.1508#10", Greater);
+    test("1", "5/2", 10, Down, "0.082031", "0x0.1500#10", Less);
+    test("1", "5/2", 10, Up, "0.082153", "0x0.1508#10", Greater);
+    test("1", "5/2", 10, Nearest, "0.082031", "0x0.1500#10", Less);
+    test(
+        "1",
+        "5/2",
+        100,
+        Nearest,
+        "0.082084998623898795169528674467209",
+        "0x0.150385c094f424a75e4834abd8#100",
+        Greater,
+    );
+    test("1", "100", 1, Nearest, "4.5e-44", "0x1.0E-36#1", Greater);
+    test("1", "100", 10, Floor, "3.7178e-44", "0xd.44E-37#10", Less);
+    test(
+        "1",
+        "100",
+        10,
+        Ceiling,
+        "3.7222e-44",
+        "0xd.48E-37#10",
+        Greater,
+    );
+    test("1", "100", 10, Down, "3.7178e-44", "0xd.44E-37#10", Less);
+    test("1", "100", 10, Up, "3.7222e-44", "0xd.48E-37#10", Greater);
+    test(
+        "1",
+        "100",
+        10,
+        Nearest,
+        "3.7222e-44",
+        "0xd.48E-37#10",
+        Greater,
+    );
+    test(
+        "1",
+        "100",
+        100,
+        Nearest,
+        "3.7200759760208359629596958038643e-44",
+        "0xd.460f8a7157ae579eec89bae4E-37#100",
+        Greater,
+    );
+    test("-1", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", "1", 1, Nearest, "0.12", "0x0.2#1", Less);
+    test("-1", "1", 10, Floor, "0.14844", "0x0.260#10", Less);
+    test("-1", "1", 10, Ceiling, "0.14868", "0x0.261#10", Greater);
+    test("-1", "1", 10, Down, "0.14844", "0x0.260#10", Less);
+    test("-1", "1", 10, Up, "0.14868", "0x0.261#10", Greater);
+    test("-1", "1", 10, Nearest, "0.14844", "0x0.260#10", Less);
+    test(
+        "-1",
+        "1",
+        100,
+        Nearest,
+        "0.14849550677592204791835999470131",
+        "0x0.2603cd31349db7fb5980efa5dc#100",
+        Less,
+    );
+    test("-1", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", "1/3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("-1", "1/3", 10, Floor, "1.3203", "0x1.520#10", Less);
+    test("-1", "1/3", 10, Ceiling, "1.3223", "0x1.528#10", Greater);
+    test("-1", "1/3", 10, Down, "1.3203", "0x1.520#10", Less);
+    test("-1", "1/3", 10, Up, "1.3223", "0x1.528#10", Greater);
+    test("-1", "1/3", 10, Nearest, "1.3203", "0x1.520#10", Less);
+    test(
+        "-1",
+        "1/3",
+        100,
+        Nearest,
+        "1.3207061863727811151566053096133",
+        "0x1.5219ccf61891ddf557ebe9752#100",
+        Greater,
+    );
+    test("-1", "5/2", 1, Nearest, "0.0078", "0x0.02#1", Less);
+    test("-1", "5/2", 10, Floor, "0.0079041", "0x0.0206#10", Less);
+    test(
+        "-1",
+        "5/2",
+        10,
+        Ceiling,
+        "0.0079193",
+        "0x0.0207#10",
+        Greater,
+    );
+    test("-1", "5/2", 10, Down, "0.0079041", "0x0.0206#10", Less);
+    test("-1", "5/2", 10, Up, "0.0079193", "0x0.0207#10", Greater);
+    test(
+        "-1",
+        "5/2",
+        10,
+        Nearest,
+        "0.0079193",
+        "0x0.0207#10",
+        Greater,
+    );
+    test(
+        "-1",
+        "5/2",
+        100,
+        Nearest,
+        "0.0079190815792897825721834575122553",
+        "0x0.0206fc2465bc6061583e92aa76c#100",
+        Greater,
+    );
+    test("-1", "100", 1, Nearest, "2.7e-48", "0x4.0E-40#1", Less);
+    test("-1", "100", 10, Floor, "3.6457e-48", "0x5.54E-40#10", Less);
+    test(
+        "-1",
+        "100",
+        10,
+        Ceiling,
+        "3.6510e-48",
+        "0x5.56E-40#10",
+        Greater,
+    );
+    test("-1", "100", 10, Down, "3.6457e-48", "0x5.54E-40#10", Less);
+    test("-1", "100", 10, Up, "3.6510e-48", "0x5.56E-40#10", Greater);
+    test(
+        "-1",
+        "100",
+        10,
+        Nearest,
+        "3.6457e-48",
+        "0x5.54E-40#10",
+        Less,
+    );
+    test(
+        "-1",
+        "100",
+        100,
+        Nearest,
+        "3.6478214338803782724503183354987e-48",
+        "0x5.54cfe14cfdc2bb3ad3ee45358E-40#100",
+        Less,
+    );
+    test("1/2", "0", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1/2", "0", 10, Floor, "1.7715", "0x1.c58#10", Less);
+    test("1/2", "0", 10, Ceiling, "1.7734", "0x1.c60#10", Greater);
+    test("1/2", "0", 10, Down, "1.7715", "0x1.c58#10", Less);
+    test("1/2", "0", 10, Up, "1.7734", "0x1.c60#10", Greater);
+    test("1/2", "0", 10, Nearest, "1.7715", "0x1.c58#10", Less);
+    test(
+        "1/2",
+        "0",
+        100,
+        Nearest,
+        "1.7724538509055160272981674833413",
+        "0x1.c5bf891b4ef6aa79c3b0520d6#100",
+        Greater,
+    );
+    test("1/2", "1", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/2", "1", 10, Floor, "0.27832", "0x0.474#10", Less);
+    test("1/2", "1", 10, Ceiling, "0.27881", "0x0.476#10", Greater);
+    test("1/2", "1", 10, Down, "0.27832", "0x0.474#10", Less);
+    test("1/2", "1", 10, Up, "0.27881", "0x0.476#10", Greater);
+    test("1/2", "1", 10, Nearest, "0.27881", "0x0.476#10", Greater);
+    test(
+        "1/2",
+        "1",
+        100,
+        Nearest,
+        "0.27880558528066197649923261107751",
+        "0x0.475fcd86b8fb252616e32dd2d8#100",
+        Greater,
+    );
+    test("1/2", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("1/2", "1/3", 1, Nearest, "0.50", "0x0.8#1", Less);
+    test("1/2", "1/3", 10, Floor, "0.73340", "0x0.bbc#10", Less);
+    test("1/2", "1/3", 10, Ceiling, "0.73438", "0x0.bc0#10", Greater);
+    test("1/2", "1/3", 10, Down, "0.73340", "0x0.bbc#10", Less);
+    test("1/2", "1/3", 10, Up, "0.73438", "0x0.bc0#10", Greater);
+    test("1/2", "1/3", 10, Nearest, "0.73438", "0x0.bc0#10", Greater);
+    test(
+        "1/2",
+        "1/3",
+        100,
+        Nearest,
+        "0.73417906023332926489633538523952",
+        "0x0.bbf328ad1c33a9d8e7e540479#100",
+        Greater,
+    );
+    test("1/2", "5/2", 1, Nearest, "0.031", "0x0.08#1", Less);
+    test("1/2", "5/2", 10, Floor, "0.044922", "0x0.0b80#10", Less);
+    test(
+        "1/2",
+        "5/2",
+        10,
+        Ceiling,
+        "0.044983",
+        "0x0.0b84#10",
+        Greater,
+    );
+    test("1/2", "5/2", 10, Down, "0.044922", "0x0.0b80#10", Less);
+    test("1/2", "5/2", 10, Up, "0.044983", "0x0.0b84#10", Greater);
+    test("1/2", "5/2", 10, Nearest, "0.044922", "0x0.0b80#10", Less);
+    test(
+        "1/2",
+        "5/2",
+        100,
+        Nearest,
+        "0.044926952600007935969868810290244",
+        "0x0.0b805530203ffad46ca7939979#100",
+        Greater,
+    );
+    test("1/2", "100", 1, Nearest, "2.8e-45", "0x1.0E-37#1", Less);
+    test(
+        "1/2",
+        "100",
+        10,
+        Floor,
+        "3.7003e-45",
+        "0x1.520E-37#10",
+        Less,
+    );
+    test(
+        "1/2",
+        "100",
+        10,
+        Ceiling,
+        "3.7058e-45",
+        "0x1.528E-37#10",
+        Greater,
+    );
+    test("1/2", "100", 10, Down, "3.7003e-45", "0x1.520E-37#10", Less);
+    test(
+        "1/2",
+        "100",
+        10,
+        Up,
+        "3.7058e-45",
+        "0x1.528E-37#10",
+        Greater,
+    );
+    test(
+        "1/2",
+        "100",
+        10,
+        Nearest,
+        "3.7003e-45",
+        "0x1.520E-37#10",
+        Less,
+    );
+    test(
+        "1/2",
+        "100",
+        100,
+        Nearest,
+        "3.7017478604082789202535664481333e-45",
+        "0x1.5221c4d97c4bd77c681768f28E-37#100",
+        Less,
+    );
+    test("1/3", "0", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1/3", "0", 10, Floor, "2.6758", "0x2.ad#10", Less);
+    test("1/3", "0", 10, Ceiling, "2.6797", "0x2.ae#10", Greater);
+    test("1/3", "0", 10, Down, "2.6758", "0x2.ad#10", Less);
+    test("1/3", "0", 10, Up, "2.6797", "0x2.ae#10", Greater);
+    test("1/3", "0", 10, Nearest, "2.6797", "0x2.ae#10", Greater);
+    test(
+        "1/3",
+        "0",
+        100,
+        Nearest,
+        "2.6789385347077476336556929409736",
+        "0x2.adceea72905e2ceec8d3e92cc#100",
+        Less,
+    );
+    test("1/3", "1", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("1/3", "1", 10, Floor, "0.25635", "0x0.41a#10", Less);
+    test("1/3", "1", 10, Ceiling, "0.25684", "0x0.41c#10", Greater);
+    test("1/3", "1", 10, Down, "0.25635", "0x0.41a#10", Less);
+    test("1/3", "1", 10, Up, "0.25684", "0x0.41c#10", Greater);
+    test("1/3", "1", 10, Nearest, "0.25635", "0x0.41a#10", Less);
+    test(
+        "1/3",
+        "1",
+        100,
+        Nearest,
+        "0.25640498828873327508069339083195",
+        "0x0.41a3c1df3b3b3918903a676b20#100",
+        Less,
+    );
+    test("1/3", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("1/3", "1/3", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1/3", "1/3", 10, Floor, "0.75684", "0x0.c1c#10", Less);
+    test("1/3", "1/3", 10, Ceiling, "0.75781", "0x0.c20#10", Greater);
+    test("1/3", "1/3", 10, Down, "0.75684", "0x0.c1c#10", Less);
+    test("1/3", "1/3", 10, Up, "0.75781", "0x0.c20#10", Greater);
+    test("1/3", "1/3", 10, Nearest, "0.75684", "0x0.c1c#10", Less);
+    test(
+        "1/3",
+        "1/3",
+        100,
+        Nearest,
+        "0.75689238102715210872254046435312",
+        "0x0.c1c3b2f71a6a9b69be2d47e5c#100",
+        Greater,
+    );
+    test("1/3", "5/2", 1, Nearest, "0.031", "0x0.08#1", Less);
+    test("1/3", "5/2", 10, Floor, "0.036804", "0x0.096c#10", Less);
+    test(
+        "1/3",
+        "5/2",
+        10,
+        Ceiling,
+        "0.036865",
+        "0x0.0970#10",
+        Greater,
+    );
+    test("1/3", "5/2", 10, Down, "0.036804", "0x0.096c#10", Less);
+    test("1/3", "5/2", 10, Up, "0.036865", "0x0.0970#10", Greater);
+    test(
+        "1/3",
+        "5/2",
+        10,
+        Nearest,
+        "0.036865",
+        "0x0.0970#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        "5/2",
+        100,
+        Nearest,
+        "0.036860520918526480317111271411924",
+        "0x0.096fb0ebdbcce3acde18ca6619#100",
+        Less,
+    );
+    test("1/3", "100", 1, Nearest, "1.4e-45", "0x8.0E-38#1", Less);
+    test("1/3", "100", 10, Floor, "1.7133e-45", "0x9.c8E-38#10", Less);
+    test(
+        "1/3",
+        "100",
+        10,
+        Ceiling,
+        "1.7160e-45",
+        "0x9.ccE-38#10",
+        Greater,
+    );
+    test("1/3", "100", 10, Down, "1.7133e-45", "0x9.c8E-38#10", Less);
+    test("1/3", "100", 10, Up, "1.7160e-45", "0x9.ccE-38#10", Greater);
+    test(
+        "1/3",
+        "100",
+        10,
+        Nearest,
+        "1.7160e-45",
+        "0x9.ccE-38#10",
+        Greater,
+    );
+    test(
+        "1/3",
+        "100",
+        100,
+        Nearest,
+        "1.7153818550356861187222969236824e-45",
+        "0x9.cb088c3b9754389adb745662E-38#100",
+        Less,
+    );
+    test("3", "0", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test("3", "0", 1, Exact, "2.0", "0x2.0#1", Equal);
+    test("3", "0", 10, Floor, "2.0000", "0x2.00#10", Equal);
+    test("3", "0", 10, Ceiling, "2.0000", "0x2.00#10", Equal);
+    test("3", "0", 10, Down, "2.0000", "0x2.00#10", Equal);
+    test("3", "0", 10, Up, "2.0000", "0x2.00#10", Equal);
+    test("3", "0", 10, Nearest, "2.0000", "0x2.00#10", Equal);
+    test("3", "0", 10, Exact, "2.0000", "0x2.00#10", Equal);
+    test(
+        "3",
+        "0",
+        100,
+        Nearest,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "3",
+        "0",
+        100,
+        Exact,
+        "2.0000000000000000000000000000000",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("3", "1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("3", "1", 10, Floor, "1.8379", "0x1.d68#10", Less);
+    test("3", "1", 10, Ceiling, "1.8398", "0x1.d70#10", Greater);
+    test("3", "1", 10, Down, "1.8379", "0x1.d68#10", Less);
+    test("3", "1", 10, Up, "1.8398", "0x1.d70#10", Greater);
+    test("3", "1", 10, Nearest, "1.8398", "0x1.d70#10", Greater);
+    test(
+        "3",
+        "1",
+        100,
+        Nearest,
+        "1.8393972058572116079776188508080",
+        "0x1.d6e2bc3b82b05b85a659e58ce#100",
+        Greater,
+    );
+    test("3", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("3", "1/3", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("3", "1/3", 10, Floor, "1.9902", "0x1.fd8#10", Less);
+    test("3", "1/3", 10, Ceiling, "1.9922", "0x1.fe0#10", Greater);
+    test("3", "1/3", 10, Down, "1.9902", "0x1.fd8#10", Less);
+    test("3", "1/3", 10, Up, "1.9922", "0x1.fe0#10", Greater);
+    test("3", "1/3", 10, Nearest, "1.9902", "0x1.fd8#10", Less);
+    test(
+        "3",
+        "1/3",
+        100,
+        Nearest,
+        "1.9903647515938590289600113803488",
+        "0x1.fd888b5b34f015b796a70db24#100",
+        Greater,
+    );
+    test("3", "5/2", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("3", "5/2", 10, Floor, "1.0859", "0x1.160#10", Less);
+    test("3", "5/2", 10, Ceiling, "1.0879", "0x1.168#10", Greater);
+    test("3", "5/2", 10, Down, "1.0859", "0x1.160#10", Less);
+    test("3", "5/2", 10, Up, "1.0879", "0x1.168#10", Greater);
+    test("3", "5/2", 10, Nearest, "1.0879", "0x1.168#10", Greater);
+    test(
+        "3",
+        "5/2",
+        100,
+        Nearest,
+        "1.0876262317666590359962549366898",
+        "0x1.166eac37b5a2e5a9a13cb9e4e#100",
+        Less,
+    );
+    test("3", "100", 1, Nearest, "3.7e-40", "0x2.0E-33#1", Less);
+    test("3", "100", 10, Floor, "3.7882e-40", "0x2.10E-33#10", Less);
+    test(
+        "3",
+        "100",
+        10,
+        Ceiling,
+        "3.7954e-40",
+        "0x2.11E-33#10",
+        Greater,
+    );
+    test("3", "100", 10, Down, "3.7882e-40", "0x2.10E-33#10", Less);
+    test("3", "100", 10, Up, "3.7954e-40", "0x2.11E-33#10", Greater);
+    test(
+        "3",
+        "100",
+        10,
+        Nearest,
+        "3.7954e-40",
+        "0x2.11E-33#10",
+        Greater,
+    );
+    test(
+        "3",
+        "100",
+        100,
+        Nearest,
+        "3.7952215107364568494114816590993e-40",
+        "0x2.10fa075328e039cfd35e68c1cE-33#100",
+        Less,
+    );
+    test("-7/2", "0", 1, Nearest, "0.25", "0x0.4#1", Less);
+    test("-7/2", "0", 10, Floor, "0.27002", "0x0.452#10", Less);
+    test("-7/2", "0", 10, Ceiling, "0.27051", "0x0.454#10", Greater);
+    test("-7/2", "0", 10, Down, "0.27002", "0x0.452#10", Less);
+    test("-7/2", "0", 10, Up, "0.27051", "0x0.454#10", Greater);
+    test("-7/2", "0", 10, Nearest, "0.27002", "0x0.452#10", Less);
+    test(
+        "-7/2",
+        "0",
+        100,
+        Nearest,
+        "0.27008820585226910892162552127095",
+        "0x0.4524802b2bba4f9d86a8470478#100",
+        Less,
+    );
+    test("-7/2", "1", 1, Nearest, "0.062", "0x0.1#1", Less);
+    test("-7/2", "1", 10, Floor, "0.077515", "0x0.13d8#10", Less);
+    test("-7/2", "1", 10, Ceiling, "0.077637", "0x0.13e0#10", Greater);
+    test("-7/2", "1", 10, Down, "0.077515", "0x0.13d8#10", Less);
+    test("-7/2", "1", 10, Up, "0.077637", "0x0.13e0#10", Greater);
+    test("-7/2", "1", 10, Nearest, "0.077515", "0x0.13d8#10", Less);
+    test(
+        "-7/2",
+        "1",
+        100,
+        Nearest,
+        "0.077520797868619188951837709322382",
+        "0x0.13d8672b9b050eb7e485da97d6#100",
+        Less,
+    );
+    test("-7/2", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-7/2", "1/3", 1, Nearest, "8.0", "0x8.0#1", Less);
+    test("-7/2", "1/3", 10, Floor, "8.5000", "0x8.80#10", Less);
+    test("-7/2", "1/3", 10, Ceiling, "8.5156", "0x8.84#10", Greater);
+    test("-7/2", "1/3", 10, Down, "8.5000", "0x8.80#10", Less);
+    test("-7/2", "1/3", 10, Up, "8.5156", "0x8.84#10", Greater);
+    test("-7/2", "1/3", 10, Nearest, "8.5000", "0x8.80#10", Less);
+    test(
+        "-7/2",
+        "1/3",
+        100,
+        Nearest,
+        "8.5038628181372650767792857284538",
+        "0x8.80fd275591e82fe45c1b29ea#100",
+        Greater,
+    );
+    test("-7/2", "5/2", 1, Nearest, "0.00049", "0x0.002#1", Less);
+    test("-7/2", "5/2", 10, Floor, "0.00051689", "0x0.0021e#10", Less);
+    test(
+        "-7/2",
+        "5/2",
+        10,
+        Ceiling,
+        "0.00051785",
+        "0x0.0021f#10",
+        Greater,
+    );
+    test("-7/2", "5/2", 10, Down, "0.00051689", "0x0.0021e#10", Less);
+    test("-7/2", "5/2", 10, Up, "0.00051785", "0x0.0021f#10", Greater);
+    test(
+        "-7/2",
+        "5/2",
+        10,
+        Nearest,
+        "0.00051689",
+        "0x0.0021e#10",
+        Less,
+    );
+    test(
+        "-7/2",
+        "5/2",
+        100,
+        Nearest,
+        "0.00051731252094152607721426771759364",
+        "0x0.0021e7105bf825947ac993d08bac#100",
+        Less,
+    );
+    test("-7/2", "100", 1, Nearest, "4.2e-53", "0x4.0E-44#1", Greater);
+    test(
+        "-7/2",
+        "100",
+        10,
+        Floor,
+        "3.5604e-53",
+        "0x3.69E-44#10",
+        Less,
+    );
+    test(
+        "-7/2",
+        "100",
+        10,
+        Ceiling,
+        "3.5644e-53",
+        "0x3.6aE-44#10",
+        Greater,
+    );
+    test("-7/2", "100", 10, Down, "3.5604e-53", "0x3.69E-44#10", Less);
+    test(
+        "-7/2",
+        "100",
+        10,
+        Up,
+        "3.5644e-53",
+        "0x3.6aE-44#10",
+        Greater,
+    );
+    test(
+        "-7/2",
+        "100",
+        10,
+        Nearest,
+        "3.5604e-53",
+        "0x3.69E-44#10",
+        Less,
+    );
+    test(
+        "-7/2",
+        "100",
+        100,
+        Nearest,
+        "3.5613226760149162315510011022617e-53",
+        "0x3.693bd8f5af4b62db3707a5e40E-44#100",
+        Less,
+    );
+    test("22/7", "0", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("22/7", "0", 10, Floor, "2.2891", "0x2.4a#10", Less);
+    test("22/7", "0", 10, Ceiling, "2.2930", "0x2.4b#10", Greater);
+    test("22/7", "0", 10, Down, "2.2891", "0x2.4a#10", Less);
+    test("22/7", "0", 10, Up, "2.2930", "0x2.4b#10", Greater);
+    test("22/7", "0", 10, Nearest, "2.2891", "0x2.4a#10", Less);
+    test(
+        "22/7",
+        "0",
+        100,
+        Nearest,
+        "2.2908675009613379957017236207921",
+        "0x2.4a764ae4192352f3b59fbc0cc#100",
+        Greater,
+    );
+    test("22/7", "1", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("22/7", "1", 10, Floor, "2.1367", "0x2.23#10", Less);
+    test("22/7", "1", 10, Ceiling, "2.1406", "0x2.24#10", Greater);
+    test("22/7", "1", 10, Down, "2.1367", "0x2.23#10", Less);
+    test("22/7", "1", 10, Up, "2.1406", "0x2.24#10", Greater);
+    test("22/7", "1", 10, Nearest, "2.1406", "0x2.24#10", Greater);
+    test(
+        "22/7",
+        "1",
+        100,
+        Nearest,
+        "2.1390302462549502017316072051454",
+        "0x2.23977c78d1e0e0689b12eaf18#100",
+        Less,
+    );
+    test("22/7", "-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("22/7", "1/3", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("22/7", "1/3", 10, Floor, "2.2812", "0x2.48#10", Less);
+    test("22/7", "1/3", 10, Ceiling, "2.2852", "0x2.49#10", Greater);
+    test("22/7", "1/3", 10, Down, "2.2812", "0x2.48#10", Less);
+    test("22/7", "1/3", 10, Up, "2.2852", "0x2.49#10", Greater);
+    test("22/7", "1/3", 10, Nearest, "2.2812", "0x2.48#10", Less);
+    test(
+        "22/7",
+        "1/3",
+        100,
+        Nearest,
+        "2.2830295053013108664822462134194",
+        "0x2.48749f25127af70c462b1d8d4#100",
+        Less,
+    );
+    test("22/7", "5/2", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("22/7", "5/2", 10, Floor, "1.3242", "0x1.530#10", Less);
+    test("22/7", "5/2", 10, Ceiling, "1.3262", "0x1.538#10", Greater);
+    test("22/7", "5/2", 10, Down, "1.3242", "0x1.530#10", Less);
+    test("22/7", "5/2", 10, Up, "1.3262", "0x1.538#10", Greater);
+    test("22/7", "5/2", 10, Nearest, "1.3262", "0x1.538#10", Greater);
+    test(
+        "22/7",
+        "5/2",
+        100,
+        Nearest,
+        "1.3254609308154124804111913972345",
+        "0x1.53516855fa58a6cf7af5d5918#100",
+        Greater,
+    );
+    test("22/7", "100", 1, Nearest, "7.3e-40", "0x4.0E-33#1", Greater);
+    test(
+        "22/7",
+        "100",
+        10,
+        Floor,
+        "7.3325e-40",
+        "0x3.feE-33#10",
+        Less,
+    );
+    test(
+        "22/7",
+        "100",
+        10,
+        Ceiling,
+        "7.3397e-40",
+        "0x3.ffE-33#10",
+        Greater,
+    );
+    test("22/7", "100", 10, Down, "7.3325e-40", "0x3.feE-33#10", Less);
+    test(
+        "22/7",
+        "100",
+        10,
+        Up,
+        "7.3397e-40",
+        "0x3.ffE-33#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        "100",
+        10,
+        Nearest,
+        "7.3397e-40",
+        "0x3.ffE-33#10",
+        Greater,
+    );
+    test(
+        "22/7",
+        "100",
+        100,
+        Nearest,
+        "7.3380110044317953089159182253240e-40",
+        "0x3.fec4fb5c5de6da04c8bd44450E-33#100",
+        Greater,
+    );
+}
+
+#[test]
+fn upper_incomplete_gamma_rational_prec_round_fail() {
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec_round(
+        Rational::ONE,
+        Rational::ONE,
+        0,
+        Floor
+    ));
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+        &Rational::ONE,
+        &Rational::ONE,
+        0,
+        Floor
+    ));
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec_round(
+        Rational::ONE_HALF,
+        Rational::ONE_HALF,
+        10,
+        Exact
+    ));
+    assert_panic!(Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+        &Rational::ONE_HALF,
+        &Rational::ONE_HALF,
+        10,
+        Exact
+    ));
+}
+
+#[test]
+fn upper_incomplete_gamma_rational_prec_round_properties() {
+    rational_rational_unsigned_rounding_mode_quadruple_gen_var_5().test_properties(
+        |(s, x, prec, rm)| {
+            let (gamma, o) =
+                Float::upper_incomplete_gamma_rational_prec_round(s.clone(), x.clone(), prec, rm);
+            assert!(gamma.is_valid());
+            let (gamma_alt, o_alt) =
+                Float::upper_incomplete_gamma_rational_prec_round_val_ref(s.clone(), &x, prec, rm);
+            assert!(gamma_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+            assert_eq!(o_alt, o);
+            let (gamma_alt, o_alt) =
+                Float::upper_incomplete_gamma_rational_prec_round_ref_val(&s, x.clone(), prec, rm);
+            assert!(gamma_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+            assert_eq!(o_alt, o);
+            let (gamma_alt, o_alt) =
+                Float::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm);
+            assert!(gamma_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+            assert_eq!(o_alt, o);
+
+            if rug_upper_incomplete_gamma_rational_applies(&s, &x)
+                && let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm)
+            {
+                let (rug_gamma, rug_o) =
+                    rug_upper_incomplete_gamma_rational_prec_round(&s, &x, prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_gamma)),
+                    ComparableFloatRef(&gamma)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if gamma.is_normal() {
+                assert_eq!(gamma.get_prec(), Some(prec));
+            }
+            if x < 0u32 {
+                assert!(gamma.is_nan());
+            }
+
+            if let Ok(s_alt) = Float::try_from(&s)
+                && let Ok(x_alt) = Float::try_from(&x)
+            {
+                let (gamma_alt, o_alt) = s_alt.upper_incomplete_gamma_prec_round(x_alt, prec, rm);
+                assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+                assert_eq!(o_alt, o);
+            }
+
+            if o == Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (g, oo) =
+                        Float::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, rm);
+                    assert_eq!(ComparableFloat(g), ComparableFloat(gamma.clone()));
+                    assert_eq!(oo, Equal);
+                }
+            } else {
+                assert_panic!(Float::upper_incomplete_gamma_rational_prec_round_ref_ref(
+                    &s, &x, prec, Exact
+                ));
+            }
+        },
+    );
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        upper_incomplete_gamma_rational_special_values_properties(prec, rm);
+    });
+}
+
+#[test]
+fn upper_incomplete_gamma_rational_prec_properties() {
+    rational_rational_unsigned_triple_gen_var_2().test_properties(|(s, x, prec)| {
+        let (gamma, o) = Float::upper_incomplete_gamma_rational_prec(s.clone(), x.clone(), prec);
+        assert!(gamma.is_valid());
+        let (gamma_alt, o_alt) =
+            Float::upper_incomplete_gamma_rational_prec_val_ref(s.clone(), &x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) =
+            Float::upper_incomplete_gamma_rational_prec_ref_val(&s, x.clone(), prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+        let (gamma_alt, o_alt) = Float::upper_incomplete_gamma_rational_prec_ref_ref(&s, &x, prec);
+        assert!(gamma_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+
+        let (gamma_alt, o_alt) =
+            Float::upper_incomplete_gamma_rational_prec_round_ref_ref(&s, &x, prec, Nearest);
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+
+        if rug_upper_incomplete_gamma_rational_applies(&s, &x) {
+            let (rug_gamma, rug_o) = rug_upper_incomplete_gamma_rational_prec(&s, &x, prec);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_upper_incomplete_gamma_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, x: &str, out: T)
+    where
+        Float: PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let s = Rational::from_str(s).unwrap();
+        let x = Rational::from_str(x).unwrap();
+        assert_eq!(
+            NiceFloat(primitive_float_upper_incomplete_gamma_rational::<T>(&s, &x)),
+            NiceFloat(out)
+        );
+    }
+    test::<f32>("0", "1", 0.21938394);
+    test::<f32>("1", "0", 1.0);
+    test::<f32>("1", "1", 0.36787945);
+    test::<f32>("1/2", "1/2", 0.5624182);
+    test::<f32>("1/3", "5/2", 0.036860522);
+    test::<f32>("3", "7/2", 0.6416944);
+    test::<f32>("-7/2", "3", 0.00015290889);
+    test::<f32>("-1", "1/3", 1.3207061);
+    test::<f32>("22/7", "100", 7.33801e-40);
+    test::<f32>("1", "-1", f32::NAN);
+
+    test::<f64>("0", "1", 0.21938393439552029);
+    test::<f64>("1", "0", 1.0);
+    test::<f64>("1", "1", 0.36787944117144233);
+    test::<f64>("1/2", "1/2", 0.5624182315944071);
+    test::<f64>("1/3", "5/2", 0.03686052091852648);
+    test::<f64>("3", "7/2", 0.6416943977242682);
+    test::<f64>("-7/2", "3", 0.0001529088984213892);
+    test::<f64>("-1", "1/3", 1.3207061863727811);
+    test::<f64>("22/7", "100", 7.3380110044317955e-40);
+    test::<f64>("1", "-1", f64::NAN);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_upper_incomplete_gamma_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_pair_gen().test_properties(|(s, x)| {
+        let gamma = primitive_float_upper_incomplete_gamma_rational::<T>(&s, &x);
+        if x < 0u32 {
+            assert!(gamma.is_nan());
+        } else if x > 0u32 && !gamma.is_nan() {
+            assert!(gamma.is_sign_positive());
+        }
+    });
+
+    primitive_float_pair_gen::<T>().test_properties(|(s, x)| {
+        if s.is_finite() && x.is_finite() && s != T::ZERO && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_upper_incomplete_gamma_rational::<T>(
+                    &Rational::exact_from(s),
+                    &Rational::exact_from(x)
+                )),
+                NiceFloat(primitive_float_upper_incomplete_gamma(s, x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_upper_incomplete_gamma_rational_properties() {
+    apply_fn_to_primitive_floats!(
+        primitive_float_upper_incomplete_gamma_rational_properties_helper
+    );
+}
@@ -0,0 +1,3665 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Zeta, ZetaAssign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::float::arithmetic::zeta::{
+    primitive_float_zeta, primitive_float_zeta_rational,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::float::arithmetic::zeta::{
+    rug_zeta, rug_zeta_prec, rug_zeta_prec_round, rug_zeta_rational_prec,
+    rug_zeta_rational_prec_round, rug_zeta_round, rug_zeta_u64_prec_round,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_47, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_36,
+    rational_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use rug::float::Round;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_zeta() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let zeta = x.clone().zeta();
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+
+        let zeta_alt = (&x).zeta();
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+
+        let mut zeta_alt = x.clone();
+        zeta_alt.zeta_assign();
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_zeta(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&zeta)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-0.50", "-0x0.8#1");
+    test("-0.0", "-0x0.0", "-0.50", "-0x0.8#1");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity");
+    test("-1.0", "-0x1.0#1", "-0.062", "-0x0.1#1");
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        "Infinity",
+        "Infinity",
+    );
+    test("3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test("5.0", "0x5.0#3", "1.0", "0x1.0#3");
+    test("0.50", "0x0.8#1", "-1.0", "-0x1.0#1");
+    test("-0.50", "-0x0.8#1", "-0.25", "-0x0.4#1");
+    test("123.0", "0x7b.0#7", "1.000", "0x1.00#7");
+    test("-123.50", "-0x7b.8#10", "8.0846e106", "0x8.d0E+88#10");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        "1.1762417383825827",
+        "0x1.2d1e2db68b1b0#50",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.2690096043357171",
+        "0x1.44ddd03cee8ac#53",
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        "1.00000000",
+        "0x1.000000#24",
+    );
+    test("7.9e-31", "0x1.0E-25#1", "-0.50", "-0x0.8#1");
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        "-0.97336024835078271546888686244851",
+        "-0x0.f92e2321e49ee7b2a76c1bcd9#100",
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+    );
+    test("2.0", "0x2.0#1", "2.0", "0x2.0#1");
+    test("-2.0", "-0x2.0#1", "0.0", "0x0.0");
+    test("-3.0", "-0x3.0#2", "0.0078", "0x0.02#2");
+    test("1.5", "0x1.8#2", "3.0", "0x3.0#2");
+    test("100.0", "0x64.0#7", "1.000", "0x1.00#7");
+    test("-100.5", "-0x64.8#8", "-1.281e78", "-0xb.1E+64#8");
+    test("-0.50", "-0x0.8#1", "-0.25", "-0x0.4#1");
+}
+
+#[test]
+fn test_zeta_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (zeta, o) = x.clone().zeta_prec(prec);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, o_out);
+
+        let (zeta_alt, o_alt) = x.zeta_prec_ref(prec);
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut zeta_alt = x.clone();
+        let o_alt = zeta_alt.zeta_prec_assign(prec);
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_zeta, rug_o) = rug_zeta_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_zeta)),
+            ComparableFloatRef(&zeta)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 10, "1.0000", "0x1.000#10", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", 10, "-0.50000", "-0x0.800#10", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        100,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.50000", "-0x0.800#10", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        100,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 10, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 100, "Infinity", "Infinity", Equal);
+    test("-1.0", "-0x1.0#1", 1, "-0.062", "-0x0.1#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "-0.083374", "-0x0.1558#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.083333333333333333333333333333366",
+        "-0x0.15555555555555555555555556#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 10, "1.2012", "0x1.338#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 1, "1.0", "0x1.0#1", Less);
+    test("5.0", "0x5.0#3", 10, "1.0371", "0x1.098#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "1.0369277551433699263313654864573",
+        "0x1.097418eca7ccdb7a2304e3d1a#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, "-1.4609", "-0x1.760#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        "-1.4603545088095868128894991525155",
+        "-0x1.75d9cb07e73fee37e834e422c#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", 10, "-0.20801", "-0x0.354#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        "-0.20788622497735456601730672539696",
+        "-0x0.35380819910f11f2c6abf837c0#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "1.0000", "0x1.000#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test("-123.50", "-0x7b.8#10", 1, "7.3e106", "0x8.0E+88#1", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        "8.0846e106",
+        "0x8.d0E+88#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        "8.0879525335120283922806250388588e106",
+        "0x8.d0f0bc460420a4f1066eb250E+88#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        "1.1758",
+        "0x1.2d0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        "1.1762417383825827793976101232702",
+        "0x1.2d1e2db68b1b03f68f8cc7cb8#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.2695",
+        "0x1.450#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.2690096043357171568074372132825",
+        "0x1.44ddd03cee8ac5a404973d4fa#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, "-0.50", "-0x0.8#1", Greater);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        "-0.50000",
+        "-0x0.800#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        "-0.50000000000000000000000000000079",
+        "-0x0.8000000000000000000000001#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        "-0.97363",
+        "-0x0.f94#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        "-0.97336024835078271546888686244851",
+        "-0x0.f92e2321e49ee7b2a76c1bcd9#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        "-0.27734",
+        "-0x0.470#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, "1.6445", "0x1.a50#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.6449340668482264364724151666460",
+        "0x1.a51a6625307d3230e7b122440#100",
+        Less,
+    );
+    test("-2.0", "-0x2.0#1", 1, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 10, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-3.0", "-0x3.0#2", 1, "0.0078", "0x0.02#1", Less);
+    test("-3.0", "-0x3.0#2", 10, "0.0083313", "0x0.0222#10", Less);
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        "0.0083333333333333333333333333333391",
+        "0x0.022222222222222222222222224#100",
+        Greater,
+    );
+    test("1.5", "0x1.8#2", 1, "2.0", "0x2.0#1", Less);
+    test("1.5", "0x1.8#2", 10, "2.6133", "0x2.9d#10", Greater);
+    test(
+        "1.5",
+        "0x1.8#2",
+        100,
+        "2.6123753486854883433485675679243",
+        "0x2.9cc4a17f7b13adc508bcb5c40#100",
+        Greater,
+    );
+}
+
+#[test]
+fn zeta_prec_fail() {
+    assert_panic!(Float::NAN.zeta_prec(0));
+    assert_panic!(Float::NAN.zeta_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.zeta_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_zeta_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (zeta, o) = x.clone().zeta_round(rm);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, o_out);
+
+        let (zeta_alt, o_alt) = x.zeta_round_ref(rm);
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut zeta_alt = x.clone();
+        let o_alt = zeta_alt.zeta_round_assign(rm);
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_zeta, rug_o) = rug_zeta_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_zeta)),
+                ComparableFloatRef(&zeta),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", Exact, "1.0", "0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", Ceiling, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", Down, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", Up, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", Nearest, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", Exact, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.50", "-0x0.8#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", Down, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", Up, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", Nearest, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", Exact, "Infinity", "Infinity", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "-0.12", "-0x0.2#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.062", "-0x0.1#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.062", "-0x0.1#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-0.12", "-0x0.2#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-0.062", "-0x0.1#1", Greater);
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", Floor, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Down, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Up, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.0", "0x1.0#2", Less);
+    test("5.0", "0x5.0#3", Floor, "1.0", "0x1.0#3", Less);
+    test("5.0", "0x5.0#3", Ceiling, "1.2", "0x1.4#3", Greater);
+    test("5.0", "0x5.0#3", Down, "1.0", "0x1.0#3", Less);
+    test("5.0", "0x5.0#3", Up, "1.2", "0x1.4#3", Greater);
+    test("5.0", "0x5.0#3", Nearest, "1.0", "0x1.0#3", Less);
+    test("0.50", "0x0.8#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("-0.50", "-0x0.8#1", Floor, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", Ceiling, "-0.12", "-0x0.2#1", Greater);
+    test("-0.50", "-0x0.8#1", Down, "-0.12", "-0x0.2#1", Greater);
+    test("-0.50", "-0x0.8#1", Up, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", Nearest, "-0.25", "-0x0.4#1", Less);
+    test("123.0", "0x7b.0#7", Floor, "1.000", "0x1.00#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "1.016", "0x1.04#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "1.000", "0x1.00#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.016", "0x1.04#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "1.000", "0x1.00#7", Less);
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Floor,
+        "8.0846e106",
+        "0x8.d0E+88#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Ceiling,
+        "8.0989e106",
+        "0x8.d4E+88#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Down,
+        "8.0846e106",
+        "0x8.d0E+88#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Up,
+        "8.0989e106",
+        "0x8.d4E+88#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        Nearest,
+        "8.0846e106",
+        "0x8.d0E+88#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Floor,
+        "1.1762417383825827",
+        "0x1.2d1e2db68b1b0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Ceiling,
+        "1.1762417383825845",
+        "0x1.2d1e2db68b1b8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Down,
+        "1.1762417383825827",
+        "0x1.2d1e2db68b1b0#50",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Up,
+        "1.1762417383825845",
+        "0x1.2d1e2db68b1b8#50",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        Nearest,
+        "1.1762417383825827",
+        "0x1.2d1e2db68b1b0#50",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.2690096043357171",
+        "0x1.44ddd03cee8ac#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.2690096043357173",
+        "0x1.44ddd03cee8ad#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.2690096043357171",
+        "0x1.44ddd03cee8ac#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.2690096043357173",
+        "0x1.44ddd03cee8ad#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.2690096043357171",
+        "0x1.44ddd03cee8ac#53",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Floor,
+        "1.00000000",
+        "0x1.000000#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Ceiling,
+        "1.00000012",
+        "0x1.000002#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Down,
+        "1.00000000",
+        "0x1.000000#24",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Up,
+        "1.00000012",
+        "0x1.000002#24",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        Nearest,
+        "1.00000000",
+        "0x1.000000#24",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", Down, "-0.50", "-0x0.8#1", Greater);
+    test("7.9e-31", "0x1.0E-25#1", Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Floor,
+        "-0.97336024835078271546888686244851",
+        "-0x0.f92e2321e49ee7b2a76c1bcd9#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-0.97336024835078271546888686244772",
+        "-0x0.f92e2321e49ee7b2a76c1bcd8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Down,
+        "-0.97336024835078271546888686244772",
+        "-0x0.f92e2321e49ee7b2a76c1bcd8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Up,
+        "-0.97336024835078271546888686244851",
+        "-0x0.f92e2321e49ee7b2a76c1bcd9#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        Nearest,
+        "-0.97336024835078271546888686244851",
+        "-0x0.f92e2321e49ee7b2a76c1bcd9#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Floor,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Ceiling,
+        "-0.27734304784012952697609147677475",
+        "-0x0.46fff4383f0ecfbb1470480678#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Down,
+        "-0.27734304784012952697609147677475",
+        "-0x0.46fff4383f0ecfbb1470480678#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Up,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        Nearest,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-2.0", "-0x2.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", Down, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", Up, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("-3.0", "-0x3.0#2", Floor, "0.0078", "0x0.02#2", Less);
+    test("-3.0", "-0x3.0#2", Ceiling, "0.012", "0x0.03#2", Greater);
+    test("-3.0", "-0x3.0#2", Down, "0.0078", "0x0.02#2", Less);
+    test("-3.0", "-0x3.0#2", Up, "0.012", "0x0.03#2", Greater);
+    test("-3.0", "-0x3.0#2", Nearest, "0.0078", "0x0.02#2", Less);
+}
+
+#[test]
+fn zeta_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(X.zeta_round(Exact));
+    assert_panic!(X.zeta_round_ref(Exact));
+    assert_panic!({
+        let mut x = X;
+        x.zeta_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_zeta_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (zeta, o) = x.clone().zeta_prec_round(prec, rm);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, o_out);
+
+        let (zeta_alt, o_alt) = x.zeta_prec_round_ref(prec, rm);
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut zeta_alt = x.clone();
+        let o_alt = zeta_alt.zeta_prec_round_assign(prec, rm);
+        assert!(zeta_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&zeta), ComparableFloatRef(&zeta_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_zeta, rug_o) = rug_zeta_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_zeta)),
+                ComparableFloatRef(&zeta)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("Infinity", "Infinity", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", 1, Down, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", 1, Up, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "-0.50", "-0x0.8#1", Equal);
+    test("0.0", "0x0.0", 1, Exact, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.50", "-0x0.8#1", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.50", "-0x0.8#1", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Down, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Up, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 10, Down, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 10, Up, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "Infinity", "Infinity", Equal);
+    test("1.0", "0x1.0#1", 100, Floor, "Infinity", "Infinity", Equal);
+    test(
+        "1.0", "0x1.0#1", 100, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", 100, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-0.12", "-0x0.2#1", Less);
+    test(
+        "-1.0", "-0x1.0#1", 1, Ceiling, "-0.062", "-0x0.1#1", Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.062", "-0x0.1#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-0.12", "-0x0.2#1", Less);
+    test(
+        "-1.0", "-0x1.0#1", 1, Nearest, "-0.062", "-0x0.1#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.083374",
+        "-0x0.1558#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.083252",
+        "-0x0.1550#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.083252",
+        "-0x0.1550#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "-0.083374",
+        "-0x0.1558#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.083374",
+        "-0x0.1558#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Floor,
+        "-0.083333333333333333333333333333366",
+        "-0x0.15555555555555555555555556#100",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Ceiling,
+        "-0.083333333333333333333333333333268",
+        "-0x0.15555555555555555555555554#100",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        Nearest,
+        "-0.083333333333333333333333333333366",
+        "-0x0.15555555555555555555555556#100",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        100,
+        Nearest,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 10, Floor, "1.2012", "0x1.338#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "1.2031",
+        "0x1.340#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "1.2012", "0x1.338#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "1.2031", "0x1.340#10", Greater);
+    test("3.0", "0x3.0#2", 10, Nearest, "1.2012", "0x1.338#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Floor,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Ceiling,
+        "1.2020569031595942853997381615127",
+        "0x1.33ba004f0062138371715c5a0#100",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        Nearest,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test("5.0", "0x5.0#3", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("5.0", "0x5.0#3", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("5.0", "0x5.0#3", 1, Down, "1.0", "0x1.0#1", Less);
+    test("5.0", "0x5.0#3", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("5.0", "0x5.0#3", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("5.0", "0x5.0#3", 10, Floor, "1.0352", "0x1.090#10", Less);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Ceiling,
+        "1.0371",
+        "0x1.098#10",
+        Greater,
+    );
+    test("5.0", "0x5.0#3", 10, Down, "1.0352", "0x1.090#10", Less);
+    test("5.0", "0x5.0#3", 10, Up, "1.0371", "0x1.098#10", Greater);
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        Nearest,
+        "1.0371",
+        "0x1.098#10",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Floor,
+        "1.0369277551433699263313654864558",
+        "0x1.097418eca7ccdb7a2304e3d18#100",
+        Less,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Ceiling,
+        "1.0369277551433699263313654864573",
+        "0x1.097418eca7ccdb7a2304e3d1a#100",
+        Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        Nearest,
+        "1.0369277551433699263313654864573",
+        "0x1.097418eca7ccdb7a2304e3d1a#100",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("0.50", "0x0.8#1", 1, Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("0.50", "0x0.8#1", 10, Floor, "-1.4609", "-0x1.760#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "-1.4590",
+        "-0x1.758#10",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Down,
+        "-1.4590",
+        "-0x1.758#10",
+        Greater,
+    );
+    test("0.50", "0x0.8#1", 10, Up, "-1.4609", "-0x1.760#10", Less);
+    test(
+        "0.50",
+        "0x0.8#1",
+        10,
+        Nearest,
+        "-1.4609",
+        "-0x1.760#10",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Floor,
+        "-1.4603545088095868128894991525155",
+        "-0x1.75d9cb07e73fee37e834e422c#100",
+        Less,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Ceiling,
+        "-1.4603545088095868128894991525139",
+        "-0x1.75d9cb07e73fee37e834e422a#100",
+        Greater,
+    );
+    test(
+        "0.50",
+        "0x0.8#1",
+        100,
+        Nearest,
+        "-1.4603545088095868128894991525155",
+        "-0x1.75d9cb07e73fee37e834e422c#100",
+        Less,
+    );
+    test("-0.50", "-0x0.8#1", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test(
+        "-0.50", "-0x0.8#1", 1, Ceiling, "-0.12", "-0x0.2#1", Greater,
+    );
+    test("-0.50", "-0x0.8#1", 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("-0.50", "-0x0.8#1", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test("-0.50", "-0x0.8#1", 1, Nearest, "-0.25", "-0x0.4#1", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Floor,
+        "-0.20801",
+        "-0x0.354#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-0.20776",
+        "-0x0.353#10",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Down,
+        "-0.20776",
+        "-0x0.353#10",
+        Greater,
+    );
+    test("-0.50", "-0x0.8#1", 10, Up, "-0.20801", "-0x0.354#10", Less);
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-0.20801",
+        "-0x0.354#10",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Floor,
+        "-0.20788622497735456601730672539715",
+        "-0x0.35380819910f11f2c6abf837c4#100",
+        Less,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Ceiling,
+        "-0.20788622497735456601730672539696",
+        "-0x0.35380819910f11f2c6abf837c0#100",
+        Greater,
+    );
+    test(
+        "-0.50",
+        "-0x0.8#1",
+        100,
+        Nearest,
+        "-0.20788622497735456601730672539696",
+        "-0x0.35380819910f11f2c6abf837c0#100",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "1.0000", "0x1.000#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "1.0000", "0x1.000#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "1.0020", "0x1.008#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Floor,
+        "7.3e106",
+        "0x8.0E+88#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Ceiling,
+        "1.5e107",
+        "0x1.0E+89#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Down,
+        "7.3e106",
+        "0x8.0E+88#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Up,
+        "1.5e107",
+        "0x1.0E+89#1",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        1,
+        Nearest,
+        "7.3e106",
+        "0x8.0E+88#1",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Floor,
+        "8.0846e106",
+        "0x8.d0E+88#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Ceiling,
+        "8.0989e106",
+        "0x8.d4E+88#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Down,
+        "8.0846e106",
+        "0x8.d0E+88#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Up,
+        "8.0989e106",
+        "0x8.d4E+88#10",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        10,
+        Nearest,
+        "8.0846e106",
+        "0x8.d0E+88#10",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Floor,
+        "8.0879525335120283922806250388588e106",
+        "0x8.d0f0bc460420a4f1066eb250E+88#100",
+        Less,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Ceiling,
+        "8.0879525335120283922806250388704e106",
+        "0x8.d0f0bc460420a4f1066eb251E+88#100",
+        Greater,
+    );
+    test(
+        "-123.50",
+        "-0x7b.8#10",
+        100,
+        Nearest,
+        "8.0879525335120283922806250388588e106",
+        "0x8.d0f0bc460420a4f1066eb250E+88#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Floor,
+        "1.1758",
+        "0x1.2d0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Ceiling,
+        "1.1777",
+        "0x1.2d8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Down,
+        "1.1758",
+        "0x1.2d0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Up,
+        "1.1777",
+        "0x1.2d8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        10,
+        Nearest,
+        "1.1758",
+        "0x1.2d0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Floor,
+        "1.1762417383825827793976101232702",
+        "0x1.2d1e2db68b1b03f68f8cc7cb8#100",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Ceiling,
+        "1.1762417383825827793976101232717",
+        "0x1.2d1e2db68b1b03f68f8cc7cba#100",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a3#50",
+        100,
+        Nearest,
+        "1.1762417383825827793976101232702",
+        "0x1.2d1e2db68b1b03f68f8cc7cb8#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.2676",
+        "0x1.448#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.2695",
+        "0x1.450#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.2676",
+        "0x1.448#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.2695",
+        "0x1.450#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.2695",
+        "0x1.450#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Floor,
+        "1.2690096043357171568074372132825",
+        "0x1.44ddd03cee8ac5a404973d4fa#100",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Ceiling,
+        "1.2690096043357171568074372132841",
+        "0x1.44ddd03cee8ac5a404973d4fc#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        Nearest,
+        "1.2690096043357171568074372132825",
+        "0x1.44ddd03cee8ac5a404973d4fa#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Floor,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Ceiling,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Down,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Up,
+        "1.0020",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        10,
+        Nearest,
+        "1.0000",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Floor,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Ceiling,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test(
+        "1.00000000e10",
+        "0x2.540be4E+8#24",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000000",
+        "0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("7.9e-31", "0x1.0E-25#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        1,
+        Nearest,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "-0.50098",
+        "-0x0.804#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "-0.50000",
+        "-0x0.800#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Down,
+        "-0.50000",
+        "-0x0.800#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Up,
+        "-0.50098",
+        "-0x0.804#10",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "-0.50000",
+        "-0x0.800#10",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Floor,
+        "-0.50000000000000000000000000000079",
+        "-0x0.8000000000000000000000001#100",
+        Less,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Ceiling,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Greater,
+    );
+    test(
+        "7.9e-31",
+        "0x1.0E-25#1",
+        100,
+        Nearest,
+        "-0.50000000000000000000000000000079",
+        "-0x0.8000000000000000000000001#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-0.50",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-0.97363",
+        "-0x0.f94#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-0.97266",
+        "-0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-0.97266",
+        "-0x0.f90#10",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-0.97363",
+        "-0x0.f94#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-0.97363",
+        "-0x0.f94#10",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "-0.97336024835078271546888686244851",
+        "-0x0.f92e2321e49ee7b2a76c1bcd9#100",
+        Less,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "-0.97336024835078271546888686244772",
+        "-0x0.f92e2321e49ee7b2a76c1bcd8#100",
+        Greater,
+    );
+    test(
+        "0.33333333333333333333333333333346",
+        "0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-0.97336024835078271546888686244851",
+        "-0x0.f92e2321e49ee7b2a76c1bcd9#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Floor,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Ceiling,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Down,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Up,
+        "-0.50",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        1,
+        Nearest,
+        "-0.25",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Floor,
+        "-0.27734",
+        "-0x0.470#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Ceiling,
+        "-0.27686",
+        "-0x0.46e#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Down,
+        "-0.27686",
+        "-0x0.46e#10",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Up,
+        "-0.27734",
+        "-0x0.470#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        10,
+        Nearest,
+        "-0.27734",
+        "-0x0.470#10",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Floor,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Less,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Ceiling,
+        "-0.27734304784012952697609147677475",
+        "-0x0.46fff4383f0ecfbb1470480678#100",
+        Greater,
+    );
+    test(
+        "-0.33333333333333333333333333333346",
+        "-0x0.55555555555555555555555558#100",
+        100,
+        Nearest,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, Floor, "1.6445", "0x1.a50#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "1.6465",
+        "0x1.a58#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Nearest, "1.6445", "0x1.a50#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        Floor,
+        "1.6449340668482264364724151666460",
+        "0x1.a51a6625307d3230e7b122440#100",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        Ceiling,
+        "1.6449340668482264364724151666475",
+        "0x1.a51a6625307d3230e7b122442#100",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        Nearest,
+        "1.6449340668482264364724151666460",
+        "0x1.a51a6625307d3230e7b122440#100",
+        Less,
+    );
+    test("-2.0", "-0x2.0#1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 100, Floor, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 100, Ceiling, "0.0", "0x0.0", Equal);
+    test("-2.0", "-0x2.0#1", 100, Nearest, "0.0", "0x0.0", Equal);
+    test("-3.0", "-0x3.0#2", 1, Floor, "0.0078", "0x0.02#1", Less);
+    test("-3.0", "-0x3.0#2", 1, Ceiling, "0.016", "0x0.04#1", Greater);
+    test("-3.0", "-0x3.0#2", 1, Nearest, "0.0078", "0x0.02#1", Less);
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        Floor,
+        "0.0083313",
+        "0x0.0222#10",
+        Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        Ceiling,
+        "0.0083466",
+        "0x0.0223#10",
+        Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        Nearest,
+        "0.0083313",
+        "0x0.0222#10",
+        Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        Floor,
+        "0.0083333333333333333333333333333268",
+        "0x0.022222222222222222222222220#100",
+        Less,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        Ceiling,
+        "0.0083333333333333333333333333333391",
+        "0x0.022222222222222222222222224#100",
+        Greater,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        Nearest,
+        "0.0083333333333333333333333333333391",
+        "0x0.022222222222222222222222224#100",
+        Greater,
+    );
+}
+
+#[test]
+fn zeta_prec_round_fail() {
+    const X: Float = Float::ONE_HALF;
+    assert_panic!(Float::one_prec(1).zeta_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).zeta_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.zeta_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(X.zeta_prec_round(1, Exact));
+    assert_panic!(X.zeta_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = X;
+        x.zeta_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_zeta_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (zeta, o) = Float::zeta_rational_prec(x.clone(), prec);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (zeta, o) = Float::zeta_rational_prec_ref(&x, prec);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rug_zeta, rug_o) = rug_zeta_rational_prec(&x, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_zeta)),
+            ComparableFloatRef(&zeta)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("0", 1, "-0.50", "-0x0.8#1", Equal);
+    test("0", 10, "-0.50000", "-0x0.800#10", Equal);
+    test(
+        "0",
+        100,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, "Infinity", "Infinity", Equal);
+    test("1", 10, "Infinity", "Infinity", Equal);
+    test("1", 100, "Infinity", "Infinity", Equal);
+    test("-1", 1, "-0.062", "-0x0.1#1", Greater);
+    test("-1", 10, "-0.083374", "-0x0.1558#10", Less);
+    test(
+        "-1",
+        100,
+        "-0.083333333333333333333333333333366",
+        "-0x0.15555555555555555555555556#100",
+        Less,
+    );
+    test("1/2", 1, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 10, "-1.4609", "-0x1.760#10", Less);
+    test(
+        "1/2",
+        100,
+        "-1.4603545088095868128894991525155",
+        "-0x1.75d9cb07e73fee37e834e422c#100",
+        Less,
+    );
+    test("-1/2", 1, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 10, "-0.20801", "-0x0.354#10", Less);
+    test(
+        "-1/2",
+        100,
+        "-0.20788622497735456601730672539696",
+        "-0x0.35380819910f11f2c6abf837c0#100",
+        Greater,
+    );
+    test("1/3", 1, "-1.0", "-0x1.0#1", Less);
+    test("1/3", 10, "-0.97363", "-0x0.f94#10", Less);
+    test(
+        "1/3",
+        100,
+        "-0.97336024835078271546888686244772",
+        "-0x0.f92e2321e49ee7b2a76c1bcd8#100",
+        Greater,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.27734", "-0x0.470#10", Less);
+    test(
+        "-1/3",
+        100,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Greater,
+    );
+    test("22/7", 1, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, "1.1758", "0x1.2d0#10", Less);
+    test(
+        "22/7",
+        100,
+        "1.1760299606731194305819991365651",
+        "0x1.2d104cac350bfbb991d4f7492#100",
+        Greater,
+    );
+    test("5", 1, "1.0", "0x1.0#1", Less);
+    test("5", 10, "1.0371", "0x1.098#10", Greater);
+    test(
+        "5",
+        100,
+        "1.0369277551433699263313654864573",
+        "0x1.097418eca7ccdb7a2304e3d1a#100",
+        Greater,
+    );
+    test("100", 1, "1.0", "0x1.0#1", Less);
+    test("100", 10, "1.0000", "0x1.000#10", Less);
+    test(
+        "100",
+        100,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test("-7/2", 1, "0.0039", "0x0.01#1", Less);
+    test("-7/2", 10, "0.0044403", "0x0.01230#10", Less);
+    test(
+        "-7/2",
+        100,
+        "0.0044410113354794319585346580178180",
+        "0x0.01230bce7271d518dd40c800e3e#100",
+        Less,
+    );
+    test("2", 1, "2.0", "0x2.0#1", Greater);
+    test("2", 10, "1.6445", "0x1.a50#10", Less);
+    test(
+        "2",
+        100,
+        "1.6449340668482264364724151666460",
+        "0x1.a51a6625307d3230e7b122440#100",
+        Less,
+    );
+    test("-2", 1, "0.0", "0x0.0", Equal);
+    test("-2", 10, "0.0", "0x0.0", Equal);
+    test("-2", 100, "0.0", "0x0.0", Equal);
+    test("-3", 1, "0.0078", "0x0.02#1", Less);
+    test("-3", 10, "0.0083313", "0x0.0222#10", Less);
+    test(
+        "-3",
+        100,
+        "0.0083333333333333333333333333333391",
+        "0x0.022222222222222222222222224#100",
+        Greater,
+    );
+    test("3/2", 1, "2.0", "0x2.0#1", Less);
+    test("3/2", 10, "2.6133", "0x2.9d#10", Greater);
+    test(
+        "3/2",
+        100,
+        "2.6123753486854883433485675679243",
+        "0x2.9cc4a17f7b13adc508bcb5c40#100",
+        Greater,
+    );
+    test("-1/3", 1, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, "-0.27734", "-0x0.470#10", Less);
+    test(
+        "-1/3",
+        100,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn zeta_rational_prec_fail() {
+    Float::zeta_rational_prec(Rational::ONE, 0);
+}
+
+#[test]
+#[should_panic]
+fn zeta_rational_prec_ref_fail() {
+    Float::zeta_rational_prec_ref(&Rational::ONE, 0);
+}
+
+#[test]
+fn test_zeta_rational_prec_round() {
+    let test = |s, prec, rm, out: &str, out_hex: &str, out_o| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (zeta, o) = Float::zeta_rational_prec_round(x.clone(), prec, rm);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, out_o);
+
+        let (zeta, o) = Float::zeta_rational_prec_round_ref(&x, prec, rm);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, out_o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_zeta, rug_o) = rug_zeta_rational_prec_round(&x, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_zeta)),
+                ComparableFloatRef(&zeta)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("0", 1, Floor, "-0.50", "-0x0.8#1", Equal);
+    test("0", 1, Ceiling, "-0.50", "-0x0.8#1", Equal);
+    test("0", 1, Down, "-0.50", "-0x0.8#1", Equal);
+    test("0", 1, Up, "-0.50", "-0x0.8#1", Equal);
+    test("0", 1, Nearest, "-0.50", "-0x0.8#1", Equal);
+    test("0", 1, Exact, "-0.50", "-0x0.8#1", Equal);
+    test("0", 10, Floor, "-0.50000", "-0x0.800#10", Equal);
+    test("0", 10, Ceiling, "-0.50000", "-0x0.800#10", Equal);
+    test("0", 10, Down, "-0.50000", "-0x0.800#10", Equal);
+    test("0", 10, Up, "-0.50000", "-0x0.800#10", Equal);
+    test("0", 10, Nearest, "-0.50000", "-0x0.800#10", Equal);
+    test("0", 10, Exact, "-0.50000", "-0x0.800#10", Equal);
+    test(
+        "0",
+        100,
+        Nearest,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test("1", 1, Floor, "Infinity", "Infinity", Equal);
+    test("1", 1, Ceiling, "Infinity", "Infinity", Equal);
+    test("1", 1, Down, "Infinity", "Infinity", Equal);
+    test("1", 1, Up, "Infinity", "Infinity", Equal);
+    test("1", 1, Nearest, "Infinity", "Infinity", Equal);
+    test("1", 1, Exact, "Infinity", "Infinity", Equal);
+    test("1", 10, Floor, "Infinity", "Infinity", Equal);
+    test("1", 10, Ceiling, "Infinity", "Infinity", Equal);
+    test("1", 10, Down, "Infinity", "Infinity", Equal);
+    test("1", 10, Up, "Infinity", "Infinity", Equal);
+    test("1", 10, Nearest, "Infinity", "Infinity", Equal);
+    test("1", 10, Exact, "Infinity", "Infinity", Equal);
+    test("1", 100, Nearest, "Infinity", "Infinity", Equal);
+    test("-1", 1, Floor, "-0.12", "-0x0.2#1", Less);
+    test("-1", 1, Ceiling, "-0.062", "-0x0.1#1", Greater);
+    test("-1", 1, Down, "-0.062", "-0x0.1#1", Greater);
+    test("-1", 1, Up, "-0.12", "-0x0.2#1", Less);
+    test("-1", 1, Nearest, "-0.062", "-0x0.1#1", Greater);
+    test("-1", 10, Floor, "-0.083374", "-0x0.1558#10", Less);
+    test("-1", 10, Ceiling, "-0.083252", "-0x0.1550#10", Greater);
+    test("-1", 10, Down, "-0.083252", "-0x0.1550#10", Greater);
+    test("-1", 10, Up, "-0.083374", "-0x0.1558#10", Less);
+    test("-1", 10, Nearest, "-0.083374", "-0x0.1558#10", Less);
+    test(
+        "-1",
+        100,
+        Nearest,
+        "-0.083333333333333333333333333333366",
+        "-0x0.15555555555555555555555556#100",
+        Less,
+    );
+    test("1/2", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("1/2", 1, Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("1/2", 10, Floor, "-1.4609", "-0x1.760#10", Less);
+    test("1/2", 10, Ceiling, "-1.4590", "-0x1.758#10", Greater);
+    test("1/2", 10, Down, "-1.4590", "-0x1.758#10", Greater);
+    test("1/2", 10, Up, "-1.4609", "-0x1.760#10", Less);
+    test("1/2", 10, Nearest, "-1.4609", "-0x1.760#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "-1.4603545088095868128894991525155",
+        "-0x1.75d9cb07e73fee37e834e422c#100",
+        Less,
+    );
+    test("-1/2", 1, Floor, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 1, Ceiling, "-0.12", "-0x0.2#1", Greater);
+    test("-1/2", 1, Down, "-0.12", "-0x0.2#1", Greater);
+    test("-1/2", 1, Up, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 1, Nearest, "-0.25", "-0x0.4#1", Less);
+    test("-1/2", 10, Floor, "-0.20801", "-0x0.354#10", Less);
+    test("-1/2", 10, Ceiling, "-0.20776", "-0x0.353#10", Greater);
+    test("-1/2", 10, Down, "-0.20776", "-0x0.353#10", Greater);
+    test("-1/2", 10, Up, "-0.20801", "-0x0.354#10", Less);
+    test("-1/2", 10, Nearest, "-0.20801", "-0x0.354#10", Less);
+    test(
+        "-1/2",
+        100,
+        Nearest,
+        "-0.20788622497735456601730672539696",
+        "-0x0.35380819910f11f2c6abf837c0#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("1/3", 1, Ceiling, "-0.50", "-0x0.8#1", Greater);
+    test("1/3", 1, Down, "-0.50", "-0x0.8#1", Greater);
+    test("1/3", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("1/3", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("1/3", 10, Floor, "-0.97363", "-0x0.f94#10", Less);
+    test("1/3", 10, Ceiling, "-0.97266", "-0x0.f90#10", Greater);
+    test("1/3", 10, Down, "-0.97266", "-0x0.f90#10", Greater);
+    test("1/3", 10, Up, "-0.97363", "-0x0.f94#10", Less);
+    test("1/3", 10, Nearest, "-0.97363", "-0x0.f94#10", Less);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "-0.97336024835078271546888686244772",
+        "-0x0.f92e2321e49ee7b2a76c1bcd8#100",
+        Greater,
+    );
+    test("-1/3", 1, Floor, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Ceiling, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Down, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 1, Up, "-0.50", "-0x0.8#1", Less);
+    test("-1/3", 1, Nearest, "-0.25", "-0x0.4#1", Greater);
+    test("-1/3", 10, Floor, "-0.27734", "-0x0.470#10", Less);
+    test("-1/3", 10, Ceiling, "-0.27686", "-0x0.46e#10", Greater);
+    test("-1/3", 10, Down, "-0.27686", "-0x0.46e#10", Greater);
+    test("-1/3", 10, Up, "-0.27734", "-0x0.470#10", Less);
+    test("-1/3", 10, Nearest, "-0.27734", "-0x0.470#10", Less);
+    test(
+        "-1/3",
+        100,
+        Nearest,
+        "-0.27734304784012952697609147677514",
+        "-0x0.46fff4383f0ecfbb1470480680#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, Floor, "1.1758", "0x1.2d0#10", Less);
+    test("22/7", 10, Ceiling, "1.1777", "0x1.2d8#10", Greater);
+    test("22/7", 10, Down, "1.1758", "0x1.2d0#10", Less);
+    test("22/7", 10, Up, "1.1777", "0x1.2d8#10", Greater);
+    test("22/7", 10, Nearest, "1.1758", "0x1.2d0#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "1.1760299606731194305819991365651",
+        "0x1.2d104cac350bfbb991d4f7492#100",
+        Greater,
+    );
+    test("5", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("5", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("5", 1, Down, "1.0", "0x1.0#1", Less);
+    test("5", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("5", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("5", 10, Floor, "1.0352", "0x1.090#10", Less);
+    test("5", 10, Ceiling, "1.0371", "0x1.098#10", Greater);
+    test("5", 10, Down, "1.0352", "0x1.090#10", Less);
+    test("5", 10, Up, "1.0371", "0x1.098#10", Greater);
+    test("5", 10, Nearest, "1.0371", "0x1.098#10", Greater);
+    test(
+        "5",
+        100,
+        Nearest,
+        "1.0369277551433699263313654864573",
+        "0x1.097418eca7ccdb7a2304e3d1a#100",
+        Greater,
+    );
+    test("100", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("100", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("100", 1, Down, "1.0", "0x1.0#1", Less);
+    test("100", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("100", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("100", 10, Floor, "1.0000", "0x1.000#10", Less);
+    test("100", 10, Ceiling, "1.0020", "0x1.008#10", Greater);
+    test("100", 10, Down, "1.0000", "0x1.000#10", Less);
+    test("100", 10, Up, "1.0020", "0x1.008#10", Greater);
+    test("100", 10, Nearest, "1.0000", "0x1.000#10", Less);
+    test(
+        "100",
+        100,
+        Nearest,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+    test("-7/2", 1, Floor, "0.0039", "0x0.01#1", Less);
+    test("-7/2", 1, Ceiling, "0.0078", "0x0.02#1", Greater);
+    test("-7/2", 1, Down, "0.0039", "0x0.01#1", Less);
+    test("-7/2", 1, Up, "0.0078", "0x0.02#1", Greater);
+    test("-7/2", 1, Nearest, "0.0039", "0x0.01#1", Less);
+    test("-7/2", 10, Floor, "0.0044403", "0x0.01230#10", Less);
+    test("-7/2", 10, Ceiling, "0.0044479", "0x0.01238#10", Greater);
+    test("-7/2", 10, Down, "0.0044403", "0x0.01230#10", Less);
+    test("-7/2", 10, Up, "0.0044479", "0x0.01238#10", Greater);
+    test("-7/2", 10, Nearest, "0.0044403", "0x0.01230#10", Less);
+    test(
+        "-7/2",
+        100,
+        Nearest,
+        "0.0044410113354794319585346580178180",
+        "0x0.01230bce7271d518dd40c800e3e#100",
+        Less,
+    );
+    test("2", 10, Floor, "1.6445", "0x1.a50#10", Less);
+    test("2", 10, Ceiling, "1.6465", "0x1.a58#10", Greater);
+    test("2", 10, Nearest, "1.6445", "0x1.a50#10", Less);
+    test("-2", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-2", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("-2", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-3", 10, Floor, "0.0083313", "0x0.0222#10", Less);
+    test("-3", 10, Ceiling, "0.0083466", "0x0.0223#10", Greater);
+    test("-3", 10, Nearest, "0.0083313", "0x0.0222#10", Less);
+    test("3/2", 10, Floor, "2.6094", "0x2.9c#10", Less);
+    test("3/2", 10, Ceiling, "2.6133", "0x2.9d#10", Greater);
+    test("3/2", 10, Nearest, "2.6133", "0x2.9d#10", Greater);
+    test("-1/3", 10, Floor, "-0.27734", "-0x0.470#10", Less);
+    test("-1/3", 10, Ceiling, "-0.27686", "-0x0.46e#10", Greater);
+    test("-1/3", 10, Nearest, "-0.27734", "-0x0.470#10", Less);
+}
+
+#[test]
+#[should_panic]
+fn zeta_rational_prec_round_fail_1() {
+    Float::zeta_rational_prec_round(Rational::ONE, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn zeta_rational_prec_round_fail_2() {
+    Float::zeta_rational_prec_round(Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn zeta_rational_prec_round_ref_fail() {
+    Float::zeta_rational_prec_round_ref(&Rational::ONE_HALF, 10, Exact);
+}
+
+#[test]
+fn test_zeta_u64_prec() {
+    let test = |n: u64, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let (zeta, o) = Float::zeta_u64_prec(n, prec);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, o_out);
+
+        let (rug_zeta, rug_o) = rug_zeta_u64_prec_round(n, prec, Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_zeta)),
+            ComparableFloatRef(&zeta)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(0, 1, "-0.50", "-0x0.8#1", Equal);
+    test(0, 10, "-0.50000", "-0x0.800#10", Equal);
+    test(
+        0,
+        100,
+        "-0.50000000000000000000000000000000",
+        "-0x0.8000000000000000000000000#100",
+        Equal,
+    );
+    test(1, 1, "Infinity", "Infinity", Equal);
+    test(1, 10, "Infinity", "Infinity", Equal);
+    test(1, 100, "Infinity", "Infinity", Equal);
+    test(2, 1, "2.0", "0x2.0#1", Greater);
+    test(2, 10, "1.6445", "0x1.a50#10", Less);
+    test(
+        2,
+        100,
+        "1.6449340668482264364724151666460",
+        "0x1.a51a6625307d3230e7b122440#100",
+        Less,
+    );
+    test(3, 1, "1.0", "0x1.0#1", Less);
+    test(3, 10, "1.2012", "0x1.338#10", Less);
+    test(
+        3,
+        100,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test(4, 1, "1.0", "0x1.0#1", Less);
+    test(4, 10, "1.0820", "0x1.150#10", Less);
+    test(
+        4,
+        100,
+        "1.0823232337111381915160036965413",
+        "0x1.151322ac7d84836bf2242232e#100",
+        Greater,
+    );
+    test(5, 1, "1.0", "0x1.0#1", Less);
+    test(5, 10, "1.0371", "0x1.098#10", Greater);
+    test(
+        5,
+        100,
+        "1.0369277551433699263313654864573",
+        "0x1.097418eca7ccdb7a2304e3d1a#100",
+        Greater,
+    );
+    test(10, 1, "1.0", "0x1.0#1", Less);
+    test(10, 10, "1.0020", "0x1.008#10", Greater);
+    test(
+        10,
+        100,
+        "1.0009945751278180853371459589011",
+        "0x1.00412e33a5bb97e1811f30544#100",
+        Greater,
+    );
+    test(100, 1, "1.0", "0x1.0#1", Less);
+    test(100, 10, "1.0000", "0x1.000#10", Less);
+    test(
+        100,
+        100,
+        "1.0000000000000000000000000000016",
+        "0x1.0000000000000000000000002#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn zeta_u64_prec_fail() {
+    Float::zeta_u64_prec(3, 0);
+}
+
+#[test]
+fn test_zeta_u64_prec_round() {
+    let test = |n: u64, prec: u64, rm: RoundingMode, out: &str, out_hex: &str, o_out: Ordering| {
+        let (zeta, o) = Float::zeta_u64_prec_round(n, prec, rm);
+        assert!(zeta.is_valid());
+        assert_eq!(zeta.to_string(), out);
+        assert_eq!(to_hex_string(&zeta), out_hex);
+        assert_eq!(o, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_zeta, rug_o) = rug_zeta_u64_prec_round(n, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_zeta)),
+                ComparableFloatRef(&zeta)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(0, 10, Floor, "-0.50000", "-0x0.800#10", Equal);
+    test(0, 10, Ceiling, "-0.50000", "-0x0.800#10", Equal);
+    test(0, 10, Down, "-0.50000", "-0x0.800#10", Equal);
+    test(0, 10, Up, "-0.50000", "-0x0.800#10", Equal);
+    test(0, 10, Nearest, "-0.50000", "-0x0.800#10", Equal);
+    test(1, 10, Floor, "Infinity", "Infinity", Equal);
+    test(1, 10, Ceiling, "Infinity", "Infinity", Equal);
+    test(1, 10, Down, "Infinity", "Infinity", Equal);
+    test(1, 10, Up, "Infinity", "Infinity", Equal);
+    test(1, 10, Nearest, "Infinity", "Infinity", Equal);
+    test(2, 10, Floor, "1.6445", "0x1.a50#10", Less);
+    test(2, 10, Ceiling, "1.6465", "0x1.a58#10", Greater);
+    test(2, 10, Down, "1.6445", "0x1.a50#10", Less);
+    test(2, 10, Up, "1.6465", "0x1.a58#10", Greater);
+    test(2, 10, Nearest, "1.6445", "0x1.a50#10", Less);
+    test(3, 1, Floor, "1.0", "0x1.0#1", Less);
+    test(3, 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test(3, 1, Down, "1.0", "0x1.0#1", Less);
+    test(3, 1, Up, "2.0", "0x2.0#1", Greater);
+    test(3, 1, Nearest, "1.0", "0x1.0#1", Less);
+    test(3, 10, Floor, "1.2012", "0x1.338#10", Less);
+    test(3, 10, Ceiling, "1.2031", "0x1.340#10", Greater);
+    test(3, 10, Down, "1.2012", "0x1.338#10", Less);
+    test(3, 10, Up, "1.2031", "0x1.340#10", Greater);
+    test(3, 10, Nearest, "1.2012", "0x1.338#10", Less);
+    test(
+        3,
+        100,
+        Floor,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test(
+        3,
+        100,
+        Ceiling,
+        "1.2020569031595942853997381615127",
+        "0x1.33ba004f0062138371715c5a0#100",
+        Greater,
+    );
+    test(
+        3,
+        100,
+        Down,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test(
+        3,
+        100,
+        Up,
+        "1.2020569031595942853997381615127",
+        "0x1.33ba004f0062138371715c5a0#100",
+        Greater,
+    );
+    test(
+        3,
+        100,
+        Nearest,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test(10, 1, Floor, "1.0", "0x1.0#1", Less);
+    test(10, 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test(10, 1, Down, "1.0", "0x1.0#1", Less);
+    test(10, 1, Up, "2.0", "0x2.0#1", Greater);
+    test(10, 1, Nearest, "1.0", "0x1.0#1", Less);
+    test(10, 10, Floor, "1.0000", "0x1.000#10", Less);
+    test(10, 10, Ceiling, "1.0020", "0x1.008#10", Greater);
+    test(10, 10, Down, "1.0000", "0x1.000#10", Less);
+    test(10, 10, Up, "1.0020", "0x1.008#10", Greater);
+    test(10, 10, Nearest, "1.0020", "0x1.008#10", Greater);
+    test(
+        10,
+        100,
+        Floor,
+        "1.0009945751278180853371459588995",
+        "0x1.00412e33a5bb97e1811f30542#100",
+        Less,
+    );
+    test(
+        10,
+        100,
+        Ceiling,
+        "1.0009945751278180853371459589011",
+        "0x1.00412e33a5bb97e1811f30544#100",
+        Greater,
+    );
+    test(
+        10,
+        100,
+        Down,
+        "1.0009945751278180853371459588995",
+        "0x1.00412e33a5bb97e1811f30542#100",
+        Less,
+    );
+    test(
+        10,
+        100,
+        Up,
+        "1.0009945751278180853371459589011",
+        "0x1.00412e33a5bb97e1811f30544#100",
+        Greater,
+    );
+    test(
+        10,
+        100,
+        Nearest,
+        "1.0009945751278180853371459589011",
+        "0x1.00412e33a5bb97e1811f30544#100",
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn zeta_u64_prec_round_fail_1() {
+    Float::zeta_u64_prec_round(3, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn zeta_u64_prec_round_fail_2() {
+    Float::zeta_u64_prec_round(3, 10, Exact);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn zeta_prec_round_properties_helper(x: Float, prec: u64, rm: RoundingMode) {
+    let (zeta, o) = x.clone().zeta_prec_round(prec, rm);
+    assert!(zeta.is_valid());
+
+    let (zeta_alt, o_alt) = x.zeta_prec_round_ref(prec, rm);
+    assert!(zeta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.zeta_prec_round_assign(prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_zeta, rug_o) = rug_zeta_prec_round(&rug::Float::exact_from(&x), prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_zeta)),
+            ComparableFloatRef(&zeta)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert_eq!(zeta.is_nan(), x.is_nan() || x == Float::NEGATIVE_INFINITY);
+
+    if zeta.is_normal() {
+        assert_eq!(zeta.get_prec(), Some(prec));
+    }
+    if x.is_normal() {
+        let (zeta_alt, o_alt) = Float::zeta_rational_prec_round(Rational::exact_from(&x), prec, rm);
+        assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+        assert_eq!(o_alt, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.zeta_prec_round_ref(prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(zeta.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.zeta_prec_round_ref(prec, Exact));
+    }
+}
+
+#[test]
+fn zeta_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_36().test_properties(|(x, prec, rm)| {
+        zeta_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (zeta, o) = Float::NAN.zeta_prec_round(prec, rm);
+        assert!(zeta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::INFINITY.zeta_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::NEGATIVE_INFINITY.zeta_prec_round(prec, rm);
+        assert!(zeta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::ZERO.zeta_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::NEGATIVE_ZERO.zeta_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::ONE.zeta_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(zeta), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn zeta_prec_properties_helper(x: Float, prec: u64) {
+    let (zeta, o) = x.clone().zeta_prec(prec);
+    assert!(zeta.is_valid());
+
+    let (zeta_alt, o_alt) = x.zeta_prec_ref(prec);
+    assert!(zeta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.zeta_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let (zeta_alt, o_alt) = x.zeta_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let (rug_zeta, rug_o) = rug_zeta_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_zeta)),
+        ComparableFloatRef(&zeta)
+    );
+    assert_eq!(rug_o, o);
+
+    if zeta.is_normal() {
+        assert_eq!(zeta.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn zeta_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        zeta_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (zeta, o) = Float::NAN.zeta_prec(prec);
+        assert!(zeta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::INFINITY.zeta_prec(prec);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::NEGATIVE_INFINITY.zeta_prec(prec);
+        assert!(zeta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::ZERO.zeta_prec(prec);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::NEGATIVE_ZERO.zeta_prec(prec);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::ONE.zeta_prec(prec);
+        assert_eq!(ComparableFloat(zeta), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn zeta_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (zeta, o) = x.clone().zeta_round(rm);
+    assert!(zeta.is_valid());
+
+    let (zeta_alt, o_alt) = x.zeta_round_ref(rm);
+    assert!(zeta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.zeta_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let (zeta_alt, o_alt) = x.zeta_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_zeta, rug_o) = rug_zeta_round(&rug::Float::exact_from(&x), rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_zeta)),
+            ComparableFloatRef(&zeta)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.zeta_round_ref(rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(zeta.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.zeta_round_ref(Exact));
+    }
+}
+
+#[test]
+fn zeta_round_properties() {
+    float_rounding_mode_pair_gen_var_47().test_properties(|(x, rm)| {
+        zeta_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (zeta, o) = Float::NAN.zeta_round(rm);
+        assert!(zeta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::INFINITY.zeta_round(rm);
+        assert_eq!(ComparableFloat(zeta), ComparableFloat(Float::one_prec(1)));
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::NEGATIVE_INFINITY.zeta_round(rm);
+        assert!(zeta.is_nan());
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::ZERO.zeta_round(rm);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(1))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::NEGATIVE_ZERO.zeta_round(rm);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(1))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::ONE.zeta_round(rm);
+        assert_eq!(ComparableFloat(zeta), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn zeta_properties_helper(x: Float) {
+    let zeta = x.clone().zeta();
+    assert!(zeta.is_valid());
+
+    let zeta_alt = (&x).zeta();
+    assert!(zeta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+
+    let mut x_alt = x.clone();
+    x_alt.zeta_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&zeta));
+
+    let zeta_alt = x.zeta_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    let zeta_alt = x.zeta_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+
+    assert_eq!(zeta.is_nan(), x.is_nan() || x == Float::NEGATIVE_INFINITY);
+
+    let rug_zeta = rug_zeta(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_zeta)),
+        ComparableFloatRef(&zeta),
+    );
+}
+
+#[test]
+fn zeta_properties() {
+    float_gen().test_properties(|x| {
+        zeta_properties_helper(x);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn zeta_rational_prec_round_properties_helper(x: Rational, prec: u64, rm: RoundingMode) {
+    let (zeta, o) = Float::zeta_rational_prec_round(x.clone(), prec, rm);
+    assert!(zeta.is_valid());
+
+    let (zeta_alt, o_alt) = Float::zeta_rational_prec_round_ref(&x, prec, rm);
+    assert!(zeta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_zeta, rug_o) = rug_zeta_rational_prec_round(&x, prec, rug_rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_zeta)),
+            ComparableFloatRef(&zeta)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    assert!(!zeta.is_nan());
+
+    if zeta.is_normal() {
+        assert_eq!(zeta.get_prec(), Some(prec));
+    }
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = Float::zeta_rational_prec_round_ref(&x, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero_ref()),
+                ComparableFloat(zeta.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(Float::zeta_rational_prec_round_ref(&x, prec, Exact));
+    }
+}
+
+#[test]
+fn zeta_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        zeta_rational_prec_round_properties_helper(x, prec, rm);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (zeta, o) = Float::zeta_rational_prec_round(Rational::ZERO, prec, rm);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::zeta_rational_prec_round(Rational::ONE, prec, rm);
+        assert_eq!(ComparableFloat(zeta), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn zeta_rational_prec_properties_helper(x: Rational, prec: u64) {
+    let (zeta, o) = Float::zeta_rational_prec(x.clone(), prec);
+    assert!(zeta.is_valid());
+
+    let (zeta_alt, o_alt) = Float::zeta_rational_prec_ref(&x, prec);
+    assert!(zeta_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let (zeta_alt, o_alt) = Float::zeta_rational_prec_round_ref(&x, prec, Nearest);
+    assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+    assert_eq!(o_alt, o);
+
+    let (rug_zeta, rug_o) = rug_zeta_rational_prec(&x, prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_zeta)),
+        ComparableFloatRef(&zeta)
+    );
+    assert_eq!(rug_o, o);
+
+    if zeta.is_normal() {
+        assert_eq!(zeta.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn zeta_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        zeta_rational_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (zeta, o) = Float::zeta_rational_prec(Rational::ZERO, prec);
+        assert_eq!(
+            ComparableFloat(zeta),
+            ComparableFloat(-Float::one_half_prec(prec))
+        );
+        assert_eq!(o, Equal);
+
+        let (zeta, o) = Float::zeta_rational_prec(Rational::ONE, prec);
+        assert_eq!(ComparableFloat(zeta), ComparableFloat(Float::INFINITY));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+fn zeta_u64_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        for n in [0, 1, 2, 3, 4, 5, 10, 100] {
+            if rm == Exact && n > 1 {
+                assert_panic!(Float::zeta_u64_prec_round(n, prec, rm));
+                continue;
+            }
+            let (zeta, o) = Float::zeta_u64_prec_round(n, prec, rm);
+            assert!(zeta.is_valid());
+
+            let (zeta_alt, o_alt) = Float::zeta_rational_prec_round(Rational::from(n), prec, rm);
+            assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_zeta, rug_o) = rug_zeta_u64_prec_round(n, prec, rug_rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_zeta)),
+                    ComparableFloatRef(&zeta)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if zeta.is_normal() {
+                assert_eq!(zeta.get_prec(), Some(prec));
+            }
+        }
+    });
+}
+
+#[test]
+fn zeta_u64_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        for n in [0, 1, 2, 3, 4, 5, 10, 100] {
+            let (zeta, o) = Float::zeta_u64_prec(n, prec);
+            assert!(zeta.is_valid());
+
+            let (zeta_alt, o_alt) = Float::zeta_u64_prec_round(n, prec, Nearest);
+            assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+            assert_eq!(o_alt, o);
+
+            let (zeta_alt, o_alt) = Float::zeta_rational_prec(Rational::from(n), prec);
+            assert_eq!(ComparableFloatRef(&zeta_alt), ComparableFloatRef(&zeta));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_zeta() {
+    fn test<T: PrimitiveFloat>(x: T, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        assert_eq!(NiceFloat(primitive_float_zeta(x)), NiceFloat(out));
+    }
+    test::<f32>(f32::NAN, f32::NAN);
+    test::<f32>(f32::INFINITY, 1.0);
+    test::<f32>(f32::NEGATIVE_INFINITY, f32::NAN);
+    test::<f32>(0.0, -0.5);
+    test::<f32>(-0.0, -0.5);
+    test::<f32>(1.0, f32::INFINITY);
+    test::<f32>(-1.0, -0.083333336);
+    test::<f32>(0.5, -1.4603546);
+    test::<f32>(-0.5, -0.20788622);
+    test::<f32>(2.0, 1.644934);
+    test::<f32>(5.0, 1.0369277);
+    test::<f32>(-2.5, 0.008516929);
+    test::<f32>(10.0, 1.0009946);
+    test::<f32>(0.001, -0.50091994);
+    test::<f32>(10000000000.0, 1.0);
+    test::<f32>(-2.0, 0.0);
+    test::<f32>(3.0, 1.2020569);
+    test::<f32>(-3.0, 0.008333334);
+    test::<f32>(1.5, 2.6123753);
+    test::<f32>(-0.25, -0.32045126);
+
+    test::<f64>(f64::NAN, f64::NAN);
+    test::<f64>(f64::INFINITY, 1.0);
+    test::<f64>(f64::NEGATIVE_INFINITY, f64::NAN);
+    test::<f64>(0.0, -0.5);
+    test::<f64>(-0.0, -0.5);
+    test::<f64>(1.0, f64::INFINITY);
+    test::<f64>(-1.0, -0.08333333333333333);
+    test::<f64>(0.5, -1.4603545088095868);
+    test::<f64>(-0.5, -0.20788622497735457);
+    test::<f64>(2.0, 1.6449340668482264);
+    test::<f64>(5.0, 1.03692775514337);
+    test::<f64>(-2.5, 0.008516928777850331);
+    test::<f64>(10.0, 1.000994575127818);
+    test::<f64>(0.001, -0.5009199427132187);
+    test::<f64>(10000000000.0, 1.0);
+    test::<f64>(1.0e300, 1.0);
+    test::<f64>(-2.0, 0.0);
+    test::<f64>(3.0, 1.2020569031595942);
+    test::<f64>(-3.0, 0.008333333333333333);
+    test::<f64>(1.5, 2.612375348685488);
+    test::<f64>(-0.25, -0.3204512642285773);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_zeta_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let zeta = primitive_float_zeta(x);
+        assert_eq!(zeta.is_nan(), x.is_nan() || x == T::NEGATIVE_INFINITY);
+    });
+}
+
+#[test]
+fn primitive_float_zeta_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_zeta_properties_helper);
+}
+
+#[test]
+#[allow(clippy::type_repetition_in_bounds)]
+fn test_primitive_float_zeta_rational() {
+    fn test<T: PrimitiveFloat>(s: &str, out: T)
+    where
+        Float: From<T> + PartialOrd<T>,
+        for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+    {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(NiceFloat(primitive_float_zeta_rational(&x)), NiceFloat(out));
+    }
+    test::<f32>("0", -0.5);
+    test::<f32>("1", f32::INFINITY);
+    test::<f32>("-1", -0.083333336);
+    test::<f32>("1/2", -1.4603546);
+    test::<f32>("-1/2", -0.20788622);
+    test::<f32>("1/3", -0.97336024);
+    test::<f32>("22/7", 1.1760299);
+    test::<f32>("3/2", 2.6123753);
+    test::<f32>("-7/2", 0.004441011);
+    test::<f32>("1000000", 1.0);
+    test::<f32>("2", 1.644934);
+    test::<f32>("-2", 0.0);
+    test::<f32>("3", 1.2020569);
+    test::<f32>("-3", 0.008333334);
+    test::<f32>("-1/3", -0.27734303);
+
+    test::<f64>("0", -0.5);
+    test::<f64>("1", f64::INFINITY);
+    test::<f64>("-1", -0.08333333333333333);
+    test::<f64>("1/2", -1.4603545088095868);
+    test::<f64>("-1/2", -0.20788622497735457);
+    test::<f64>("1/3", -0.9733602483507827);
+    test::<f64>("22/7", 1.1760299606731195);
+    test::<f64>("3/2", 2.612375348685488);
+    test::<f64>("-7/2", 0.004441011335479432);
+    test::<f64>("1000000", 1.0);
+    test::<f64>("2", 1.6449340668482264);
+    test::<f64>("-2", 0.0);
+    test::<f64>("3", 1.2020569031595942);
+    test::<f64>("-3", 0.008333333333333333);
+    test::<f64>("-1/3", -0.27734304784012953);
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn primitive_float_zeta_rational_properties_helper<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    Rational: ExactFrom<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    rational_gen().test_properties(|x| {
+        let zeta = primitive_float_zeta_rational::<T>(&x);
+        assert!(!zeta.is_nan());
+    });
+
+    primitive_float_gen::<T>().test_properties(|x| {
+        if x.is_finite() && x != T::ZERO {
+            assert_eq!(
+                NiceFloat(primitive_float_zeta_rational::<T>(&Rational::exact_from(x))),
+                NiceFloat(primitive_float_zeta(x))
+            );
+        }
+    });
+}
+
+#[test]
+fn primitive_float_zeta_rational_properties() {
+    apply_fn_to_primitive_floats!(primitive_float_zeta_rational_properties_helper);
+}
@@ -23,7 +23,7 @@ const fn mpfr_rnd(rm: RoundingMode) -> rnd_t {
         Down => rnd_t::RNDZ,
         Up => rnd_t::RNDA,
         Nearest => rnd_t::RNDN,
-        Exact => panic!(),
+        NearestTiesAway | Odd | Exact => panic!(),
     }
 }
 
@@ -93,7 +93,7 @@ fn test_can_round_vs_mpfr() {
 // round to the same result: check the endpoints of the error interval.
 #[test]
 fn can_round_soundness() {
-    let rms = [Floor, Ceiling, Down, Up, Nearest];
+    let rms = [Floor, Ceiling, Down, Up, Nearest, NearestTiesAway, Odd];
     for prec_x in [10u64, 64] {
         let mut sigs = vec![Natural::power_of_2(prec_x - 1), Natural::low_mask(prec_x)];
         for t in [1, prec_x / 2, prec_x - 2] {
@@ -121,7 +121,7 @@ fn can_round_soundness() {
                                 // approximations mean the true value is beyond x, and so on
                                 let towards_zero = match rnd1 {
                                     Down => true,
-                                    Up | Nearest => false,
+                                    Up | Nearest | NearestTiesAway | Odd => false,
                                     Floor => !sign,
                                     Ceiling => sign,
                                     Exact => unreachable!(),
@@ -129,7 +129,7 @@ fn can_round_soundness() {
                                 let mut candidates = vec![x.clone()];
                                 let away = if sign { &x - &eps } else { &x + &eps };
                                 let toward = if sign { &x + &eps } else { &x - &eps };
-                                if rnd1 == Nearest {
+                                if rnd1 == Nearest || rnd1 == NearestTiesAway || rnd1 == Odd {
                                     candidates.push(away);
                                     candidates.push(toward);
                                 } else if towards_zero {
@@ -7,13 +7,14 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use malachite_base::num::arithmetic::traits::DivisibleByPowerOf2;
+use malachite_base::num::comparison::traits::PartialOrdAbs;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::traits::{ExactFrom, SciMantissaAndExponent};
 use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_base::strings::ToDebugString;
 use malachite_float::test_util::common::{
-    parse_hex_string, rug_round_exact_from_rounding_mode, to_hex_string,
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
 };
 use malachite_float::test_util::generators::{
     float_gen, float_gen_var_12, float_unsigned_pair_gen_var_1, float_unsigned_pair_gen_var_4,
@@ -441,15 +442,9 @@ fn test_set_prec_round() {
         assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
         assert_eq!(o_alt, o);
 
-        if rm != Exact {
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
             let mut rug_x = rug::Float::exact_from(&old_x);
-            assert_eq!(
-                rug_x.set_prec_round(
-                    u32::exact_from(prec),
-                    rug_round_exact_from_rounding_mode(rm)
-                ),
-                o
-            );
+            assert_eq!(rug_x.set_prec_round(u32::exact_from(prec), rm), o);
             assert_eq!(ComparableFloat(x), ComparableFloat(Float::from(&rug_x)));
         }
     };
@@ -976,6 +971,123 @@ fn test_set_prec_round() {
         "-0x2.0E-268435456#1",
         Less,
     );
+    test(
+        "1.5",
+        "0x1.8#2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.5",
+        "0x1.8#2",
+        1,
+        Odd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        Nearest,
+        "2.0",
+        "0x2.0#2",
+        Less,
+    );
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Greater,
+    );
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        Odd,
+        "3.0",
+        "0x3.0#2",
+        Greater,
+    );
+    test(
+        "-2.5",
+        "-0x2.8#3",
+        2,
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.0#2",
+        Less,
+    );
+    test(
+        "-2.5",
+        "-0x2.8#3",
+        2,
+        Odd,
+        "-3.0",
+        "-0x3.0#2",
+        Less,
+    );
+    test(
+        "3.5",
+        "0x3.8#3",
+        2,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#2",
+        Greater,
+    );
+    test(
+        "3.5",
+        "0x3.8#3",
+        2,
+        Odd,
+        "3.0",
+        "0x3.0#2",
+        Less,
+    );
+    test(
+        "2.25",
+        "0x2.4#4",
+        2,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#2",
+        Less,
+    );
+    test(
+        "2.25",
+        "0x2.4#4",
+        2,
+        Odd,
+        "3.0",
+        "0x3.0#2",
+        Greater,
+    );
+    test(
+        "-3.6e-323228497",
+        "-0x1.8E-268435456#2",
+        1,
+        NearestTiesAway,
+        "-4.8e-323228497",
+        "-0x2.0E-268435456#1",
+        Less,
+    );
+    test(
+        "-3.6e-323228497",
+        "-0x1.8E-268435456#2",
+        1,
+        Odd,
+        "-2.4e-323228497",
+        "-0x1.0E-268435456#1",
+        Greater,
+    );
 }
 
 #[test]
@@ -1009,21 +1121,47 @@ fn set_prec_round_properties_helper(mut x: Float, prec: u64, rm: RoundingMode) {
 
     if rm == Exact {
         assert_eq!(o, Equal);
-    } else {
+    } else if let Ok(rug_rm) = rug_round_try_from_rounding_mode(rm) {
         let mut rug_x = rug::Float::exact_from(&old_x);
-        assert_eq!(
-            rug_x.set_prec_round(
-                u32::exact_from(prec),
-                rug_round_exact_from_rounding_mode(rm)
-            ),
-            o
-        );
+        assert_eq!(rug_x.set_prec_round(u32::exact_from(prec), rug_rm), o);
         assert_eq!(
             ComparableFloatRef(&x),
             ComparableFloatRef(&Float::from(&rug_x))
         );
     }
 
+    match rm {
+        NearestTiesAway => {
+            // the result only differs from the `Nearest` result at a tie, which it breaks away from
+            // zero
+            let (x_nearest, o_nearest) = Float::from_float_prec_round_ref(&old_x, prec, Nearest);
+            if ComparableFloatRef(&x_nearest) != ComparableFloatRef(&x) {
+                assert_eq!(old_x.get_min_prec(), Some(prec + 1));
+                assert!(x.gt_abs(&x_nearest));
+                assert_ne!(o, o_nearest);
+            }
+        }
+        Odd => {
+            // an inexact result is one of the two neighbors of x, and it has an odd significand
+            let (x_down, o_down) = Float::from_float_prec_round_ref(&old_x, prec, Down);
+            if o_down == Equal {
+                assert_eq!(ComparableFloatRef(&x), ComparableFloatRef(&x_down));
+            } else if x.is_normal() && x_down.is_normal() {
+                let (x_up, _) = Float::from_float_prec_round_ref(&old_x, prec, Up);
+                assert!(
+                    ComparableFloatRef(&x) == ComparableFloatRef(&x_down)
+                        || ComparableFloatRef(&x) == ComparableFloatRef(&x_up)
+                );
+                if prec == 1 {
+                    assert_eq!(ComparableFloatRef(&x), ComparableFloatRef(&x_down));
+                } else {
+                    assert_eq!(x.get_min_prec(), Some(prec));
+                }
+            }
+        }
+        _ => {}
+    }
+
     if o == Equal
         && let Some(old_precision) = old_x.get_prec()
     {
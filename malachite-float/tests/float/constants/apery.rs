@@ -0,0 +1,325 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::num::basic::traits::Apery;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_4,
+};
+use malachite_float::test_util::common::{
+    rug_round_try_from_rounding_mode, test_constant, to_hex_string,
+};
+use malachite_float::test_util::float::constants::apery::rug_apery_prec_round;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+fn test_apery_prec_helper(prec: u64, out: &str, out_hex: &str, out_o: Ordering) {
+    let (x, o) = Float::apery_prec(prec);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    let (x_alt, o_alt) = Float::zeta_u64_prec(3, prec);
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+    assert_eq!(o_alt, o);
+
+    let (rug_x, rug_o) =
+        rug_apery_prec_round(prec, rug_round_try_from_rounding_mode(Nearest).unwrap());
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+pub fn test_apery_prec() {
+    test_apery_prec_helper(1, "1.0", "0x1.0#1", Less);
+    test_apery_prec_helper(2, "1.0", "0x1.0#2", Less);
+    test_apery_prec_helper(3, "1.2", "0x1.4#3", Greater);
+    test_apery_prec_helper(4, "1.25", "0x1.4#4", Greater);
+    test_apery_prec_helper(5, "1.19", "0x1.3#5", Less);
+    test_apery_prec_helper(6, "1.19", "0x1.30#6", Less);
+    test_apery_prec_helper(7, "1.203", "0x1.34#7", Greater);
+    test_apery_prec_helper(8, "1.203", "0x1.34#8", Greater);
+    test_apery_prec_helper(9, "1.203", "0x1.34#9", Greater);
+    test_apery_prec_helper(10, "1.2012", "0x1.338#10", Less);
+    test_apery_prec_helper(
+        100,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test_apery_prec_helper(
+        1000,
+        "1.202056903159594285399738161511449990764986292340498881792271555341838205786313090186455\
+        873609335258146199157795260719418491995998673283213776396837207900161453941782949360066719\
+        191575522242494243961563909664103291159095780965514651279918405105715255988015437109781102\
+        03982753256678760352233698494166172",
+        "0x1.33ba004f0062138371715c59e6907f1b180b7db17493405dd149c7ab12d7f6b8d04e653afb2ce61e94a06\
+        c300998821917556a352a65193f75c783f0c7a8bc2ed00d6c2b64aa702dcc359617f1feb0efa4ba636e66a2921\
+        3b6cdcda294963778de6e99e6f9d5d3f0df49f4a690221131afcf79353b7bb3cadba7ece780#1000",
+        Less,
+    );
+
+    let apery_f32 = Float::apery_prec(u64::from(f32::MANTISSA_DIGITS)).0;
+    assert_eq!(apery_f32.to_string(), "1.20205688");
+    assert_eq!(to_hex_string(&apery_f32), "0x1.33ba00#24");
+    assert_eq!(apery_f32, f32::APERY);
+
+    let apery_f64 = Float::apery_prec(u64::from(f64::MANTISSA_DIGITS)).0;
+    assert_eq!(apery_f64.to_string(), "1.2020569031595942");
+    assert_eq!(to_hex_string(&apery_f64), "0x1.33ba004f00621#53");
+    assert_eq!(apery_f64, f64::APERY);
+}
+
+#[test]
+#[should_panic]
+fn apery_prec_fail_1() {
+    Float::apery_prec(0);
+}
+
+fn test_apery_prec_round_helper(
+    prec: u64,
+    rm: RoundingMode,
+    out: &str,
+    out_hex: &str,
+    out_o: Ordering,
+) {
+    let (x, o) = Float::apery_prec_round(prec, rm);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    let (x_alt, o_alt) = Float::zeta_u64_prec_round(3, prec, rm);
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_x, rug_o) = rug_apery_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+pub fn test_apery_prec_round() {
+    test_apery_prec_round_helper(1, Floor, "1.0", "0x1.0#1", Less);
+    test_apery_prec_round_helper(1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test_apery_prec_round_helper(1, Down, "1.0", "0x1.0#1", Less);
+    test_apery_prec_round_helper(1, Up, "2.0", "0x2.0#1", Greater);
+    test_apery_prec_round_helper(1, Nearest, "1.0", "0x1.0#1", Less);
+
+    test_apery_prec_round_helper(2, Floor, "1.0", "0x1.0#2", Less);
+    test_apery_prec_round_helper(2, Ceiling, "1.5", "0x1.8#2", Greater);
+    test_apery_prec_round_helper(2, Down, "1.0", "0x1.0#2", Less);
+    test_apery_prec_round_helper(2, Up, "1.5", "0x1.8#2", Greater);
+    test_apery_prec_round_helper(2, Nearest, "1.0", "0x1.0#2", Less);
+
+    test_apery_prec_round_helper(3, Floor, "1.0", "0x1.0#3", Less);
+    test_apery_prec_round_helper(3, Ceiling, "1.2", "0x1.4#3", Greater);
+    test_apery_prec_round_helper(3, Down, "1.0", "0x1.0#3", Less);
+    test_apery_prec_round_helper(3, Up, "1.2", "0x1.4#3", Greater);
+    test_apery_prec_round_helper(3, Nearest, "1.2", "0x1.4#3", Greater);
+
+    test_apery_prec_round_helper(4, Floor, "1.12", "0x1.2#4", Less);
+    test_apery_prec_round_helper(4, Ceiling, "1.25", "0x1.4#4", Greater);
+    test_apery_prec_round_helper(4, Down, "1.12", "0x1.2#4", Less);
+    test_apery_prec_round_helper(4, Up, "1.25", "0x1.4#4", Greater);
+    test_apery_prec_round_helper(4, Nearest, "1.25", "0x1.4#4", Greater);
+
+    test_apery_prec_round_helper(5, Floor, "1.19", "0x1.3#5", Less);
+    test_apery_prec_round_helper(5, Ceiling, "1.25", "0x1.4#5", Greater);
+    test_apery_prec_round_helper(5, Down, "1.19", "0x1.3#5", Less);
+    test_apery_prec_round_helper(5, Up, "1.25", "0x1.4#5", Greater);
+    test_apery_prec_round_helper(5, Nearest, "1.19", "0x1.3#5", Less);
+
+    test_apery_prec_round_helper(6, Floor, "1.19", "0x1.30#6", Less);
+    test_apery_prec_round_helper(6, Ceiling, "1.22", "0x1.38#6", Greater);
+    test_apery_prec_round_helper(6, Down, "1.19", "0x1.30#6", Less);
+    test_apery_prec_round_helper(6, Up, "1.22", "0x1.38#6", Greater);
+    test_apery_prec_round_helper(6, Nearest, "1.19", "0x1.30#6", Less);
+
+    test_apery_prec_round_helper(7, Floor, "1.188", "0x1.30#7", Less);
+    test_apery_prec_round_helper(7, Ceiling, "1.203", "0x1.34#7", Greater);
+    test_apery_prec_round_helper(7, Down, "1.188", "0x1.30#7", Less);
+    test_apery_prec_round_helper(7, Up, "1.203", "0x1.34#7", Greater);
+    test_apery_prec_round_helper(7, Nearest, "1.203", "0x1.34#7", Greater);
+
+    test_apery_prec_round_helper(8, Floor, "1.195", "0x1.32#8", Less);
+    test_apery_prec_round_helper(8, Ceiling, "1.203", "0x1.34#8", Greater);
+    test_apery_prec_round_helper(8, Down, "1.195", "0x1.32#8", Less);
+    test_apery_prec_round_helper(8, Up, "1.203", "0x1.34#8", Greater);
+    test_apery_prec_round_helper(8, Nearest, "1.203", "0x1.34#8", Greater);
+
+    test_apery_prec_round_helper(9, Floor, "1.199", "0x1.33#9", Less);
+    test_apery_prec_round_helper(9, Ceiling, "1.203", "0x1.34#9", Greater);
+    test_apery_prec_round_helper(9, Down, "1.199", "0x1.33#9", Less);
+    test_apery_prec_round_helper(9, Up, "1.203", "0x1.34#9", Greater);
+    test_apery_prec_round_helper(9, Nearest, "1.203", "0x1.34#9", Greater);
+
+    test_apery_prec_round_helper(10, Floor, "1.2012", "0x1.338#10", Less);
+    test_apery_prec_round_helper(10, Ceiling, "1.2031", "0x1.340#10", Greater);
+    test_apery_prec_round_helper(10, Down, "1.2012", "0x1.338#10", Less);
+    test_apery_prec_round_helper(10, Up, "1.2031", "0x1.340#10", Greater);
+    test_apery_prec_round_helper(10, Nearest, "1.2012", "0x1.338#10", Less);
+
+    test_apery_prec_round_helper(
+        100,
+        Floor,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test_apery_prec_round_helper(
+        100,
+        Ceiling,
+        "1.2020569031595942853997381615127",
+        "0x1.33ba004f0062138371715c5a0#100",
+        Greater,
+    );
+    test_apery_prec_round_helper(
+        100,
+        Down,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+    test_apery_prec_round_helper(
+        100,
+        Up,
+        "1.2020569031595942853997381615127",
+        "0x1.33ba004f0062138371715c5a0#100",
+        Greater,
+    );
+    test_apery_prec_round_helper(
+        100,
+        Nearest,
+        "1.2020569031595942853997381615111",
+        "0x1.33ba004f0062138371715c59e#100",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn apery_prec_round_fail_1() {
+    Float::apery_prec_round(0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn apery_prec_round_fail_2() {
+    Float::apery_prec_round(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn apery_prec_round_fail_3() {
+    Float::apery_prec_round(1000, Exact);
+}
+
+#[test]
+fn apery_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (apery, o) = Float::apery_prec(prec);
+        assert!(apery.is_valid());
+        assert_eq!(apery.get_prec(), Some(prec));
+        assert_eq!(apery.get_exponent(), Some(1));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (apery_alt, o_alt) = Float::apery_prec_round(prec, Ceiling);
+            let mut next_upper = apery.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(apery_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !apery.is_power_of_2() {
+            let (apery_alt, o_alt) = Float::apery_prec_round(prec, Floor);
+            let mut next_lower = apery.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(apery_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+        let (apery_alt, o_alt) = Float::apery_prec_round(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&apery_alt), ComparableFloatRef(&apery));
+        assert_eq!(o_alt, o);
+
+        let (apery_alt, o_alt) = Float::zeta_u64_prec(3, prec);
+        assert_eq!(ComparableFloatRef(&apery_alt), ComparableFloatRef(&apery));
+        assert_eq!(o_alt, o);
+
+        let (rug_apery, rug_o) =
+            rug_apery_prec_round(prec, rug_round_try_from_rounding_mode(Nearest).unwrap());
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_apery)),
+            ComparableFloatRef(&apery)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn apery_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (apery, o) = Float::apery_prec_round(prec, rm);
+        assert!(apery.is_valid());
+        assert_eq!(apery.get_prec(), Some(prec));
+        assert_eq!(
+            apery.get_exponent(),
+            Some(if prec == 1 && (rm == Ceiling || rm == Up) {
+                2
+            } else {
+                1
+            })
+        );
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (apery_alt, o_alt) = Float::apery_prec_round(prec, Ceiling);
+            let mut next_upper = apery.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(apery_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !apery.is_power_of_2() {
+            let (apery_alt, o_alt) = Float::apery_prec_round(prec, Floor);
+            let mut next_lower = apery.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(apery_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+
+        let (apery_alt, o_alt) = Float::zeta_u64_prec_round(3, prec, rm);
+        assert_eq!(ComparableFloatRef(&apery_alt), ComparableFloatRef(&apery));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_apery, rug_o) = rug_apery_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_apery)),
+                ComparableFloatRef(&apery)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::apery_prec_round(prec, Exact));
+    });
+
+    test_constant(Float::apery_prec_round, 10000);
+}
@@ -346,7 +346,7 @@ fn champernowne_constant_base_prec_round_properties() {
         match rm {
             Floor | Down => assert_eq!(o, Less),
             Ceiling | Up => assert_eq!(o, Greater),
-            Nearest => {}
+            Nearest | NearestTiesAway | Odd => {}
             Exact => unreachable!(),
         }
         let (x_alt, o_alt) = Float::champernowne_constant_base_prec_round(
@@ -363,7 +363,7 @@ fn liouvilles_constant_base_prec_round_properties() {
         match rm {
             Floor | Down => assert_eq!(o, Less),
             Ceiling | Up => assert_eq!(o, Greater),
-            Nearest => {}
+            Nearest | NearestTiesAway | Odd => {}
             Exact => unreachable!(),
         }
         let (x_alt, o_alt) = Float::liouvilles_constant_base_prec_round(
@@ -126,6 +126,8 @@ fn test_rounding_from_float() {
     test("0.0", "0x0.0", Down, "0", Equal);
     test("0.0", "0x0.0", Up, "0", Equal);
     test("0.0", "0x0.0", Nearest, "0", Equal);
+    test("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+    test("0.0", "0x0.0", Odd, "0", Equal);
     test("0.0", "0x0.0", Exact, "0", Equal);
 
     test("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -133,6 +135,8 @@ fn test_rounding_from_float() {
     test("-0.0", "-0x0.0", Down, "0", Equal);
     test("-0.0", "-0x0.0", Up, "0", Equal);
     test("-0.0", "-0x0.0", Nearest, "0", Equal);
+    test("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+    test("-0.0", "-0x0.0", Odd, "0", Equal);
     test("-0.0", "-0x0.0", Exact, "0", Equal);
 
     test("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -140,6 +144,8 @@ fn test_rounding_from_float() {
     test("1.0", "0x1.0#1", Down, "1", Equal);
     test("1.0", "0x1.0#1", Up, "1", Equal);
     test("1.0", "0x1.0#1", Nearest, "1", Equal);
+    test("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+    test("1.0", "0x1.0#1", Odd, "1", Equal);
     test("1.0", "0x1.0#1", Exact, "1", Equal);
 
     test("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -147,6 +153,8 @@ fn test_rounding_from_float() {
     test("2.0", "0x2.0#1", Down, "2", Equal);
     test("2.0", "0x2.0#1", Up, "2", Equal);
     test("2.0", "0x2.0#1", Nearest, "2", Equal);
+    test("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+    test("2.0", "0x2.0#1", Odd, "2", Equal);
     test("2.0", "0x2.0#1", Exact, "2", Equal);
 
     test("0.50", "0x0.8#1", Floor, "0", Less);
@@ -154,6 +162,8 @@ fn test_rounding_from_float() {
     test("0.50", "0x0.8#1", Down, "0", Less);
     test("0.50", "0x0.8#1", Up, "1", Greater);
     test("0.50", "0x0.8#1", Nearest, "0", Less);
+    test("0.50", "0x0.8#1", NearestTiesAway, "1", Greater);
+    test("0.50", "0x0.8#1", Odd, "1", Greater);
 
     test(
         "0.33333333333333331",
@@ -232,18 +242,24 @@ fn test_rounding_from_float() {
     test("1.5", "0x1.8#2", Down, "1", Less);
     test("1.5", "0x1.8#2", Up, "2", Greater);
     test("1.5", "0x1.8#2", Nearest, "2", Greater);
+    test("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+    test("1.5", "0x1.8#2", Odd, "1", Less);
 
     test("2.5", "0x2.8#3", Floor, "2", Less);
     test("2.5", "0x2.8#3", Ceiling, "3", Greater);
     test("2.5", "0x2.8#3", Down, "2", Less);
     test("2.5", "0x2.8#3", Up, "3", Greater);
     test("2.5", "0x2.8#3", Nearest, "2", Less);
+    test("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+    test("2.5", "0x2.8#3", Odd, "3", Greater);
 
     test("123.0", "0x7b.0#7", Floor, "123", Equal);
     test("123.0", "0x7b.0#7", Ceiling, "123", Equal);
     test("123.0", "0x7b.0#7", Down, "123", Equal);
     test("123.0", "0x7b.0#7", Up, "123", Equal);
     test("123.0", "0x7b.0#7", Nearest, "123", Equal);
+    test("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+    test("123.0", "0x7b.0#7", Odd, "123", Equal);
     test("123.0", "0x7b.0#7", Exact, "123", Equal);
 
     test("-1.0", "-0x1.0#1", Floor, "-1", Equal);
@@ -251,6 +267,8 @@ fn test_rounding_from_float() {
     test("-1.0", "-0x1.0#1", Down, "-1", Equal);
     test("-1.0", "-0x1.0#1", Up, "-1", Equal);
     test("-1.0", "-0x1.0#1", Nearest, "-1", Equal);
+    test("-1.0", "-0x1.0#1", NearestTiesAway, "-1", Equal);
+    test("-1.0", "-0x1.0#1", Odd, "-1", Equal);
     test("-1.0", "-0x1.0#1", Exact, "-1", Equal);
 
     test("-2.0", "-0x2.0#1", Floor, "-2", Equal);
@@ -258,6 +276,8 @@ fn test_rounding_from_float() {
     test("-2.0", "-0x2.0#1", Down, "-2", Equal);
     test("-2.0", "-0x2.0#1", Up, "-2", Equal);
     test("-2.0", "-0x2.0#1", Nearest, "-2", Equal);
+    test("-2.0", "-0x2.0#1", NearestTiesAway, "-2", Equal);
+    test("-2.0", "-0x2.0#1", Odd, "-2", Equal);
     test("-2.0", "-0x2.0#1", Exact, "-2", Equal);
 
     test("-0.50", "-0x0.8#1", Floor, "-1", Less);
@@ -265,6 +285,8 @@ fn test_rounding_from_float() {
     test("-0.50", "-0x0.8#1", Down, "0", Greater);
     test("-0.50", "-0x0.8#1", Up, "-1", Less);
     test("-0.50", "-0x0.8#1", Nearest, "0", Greater);
+    test("-0.50", "-0x0.8#1", NearestTiesAway, "-1", Less);
+    test("-0.50", "-0x0.8#1", Odd, "-1", Less);
 
     test(
         "-0.33333333333333331",
@@ -343,18 +365,24 @@ fn test_rounding_from_float() {
     test("-1.5", "-0x1.8#2", Down, "-1", Greater);
     test("-1.5", "-0x1.8#2", Up, "-2", Less);
     test("-1.5", "-0x1.8#2", Nearest, "-2", Less);
+    test("-1.5", "-0x1.8#2", NearestTiesAway, "-2", Less);
+    test("-1.5", "-0x1.8#2", Odd, "-1", Greater);
 
     test("-2.5", "-0x2.8#3", Floor, "-3", Less);
     test("-2.5", "-0x2.8#3", Ceiling, "-2", Greater);
     test("-2.5", "-0x2.8#3", Down, "-2", Greater);
     test("-2.5", "-0x2.8#3", Up, "-3", Less);
     test("-2.5", "-0x2.8#3", Nearest, "-2", Greater);
+    test("-2.5", "-0x2.8#3", NearestTiesAway, "-3", Less);
+    test("-2.5", "-0x2.8#3", Odd, "-3", Less);
 
     test("-123.0", "-0x7b.0#7", Floor, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Ceiling, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Down, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Up, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Nearest, "-123", Equal);
+    test("-123.0", "-0x7b.0#7", NearestTiesAway, "-123", Equal);
+    test("-123.0", "-0x7b.0#7", Odd, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Exact, "-123", Equal);
 }
 
@@ -365,6 +393,8 @@ fn integer_rounding_from_float_fail() {
     assert_panic!(Integer::rounding_from(Float::NAN, Down));
     assert_panic!(Integer::rounding_from(Float::NAN, Up));
     assert_panic!(Integer::rounding_from(Float::NAN, Nearest));
+    assert_panic!(Integer::rounding_from(Float::NAN, NearestTiesAway));
+    assert_panic!(Integer::rounding_from(Float::NAN, Odd));
     assert_panic!(Integer::rounding_from(Float::NAN, Exact));
 
     assert_panic!(Integer::rounding_from(Float::INFINITY, Floor));
@@ -392,6 +422,8 @@ fn integer_rounding_from_float_ref_fail() {
     assert_panic!(Integer::rounding_from(&Float::NAN, Down));
     assert_panic!(Integer::rounding_from(&Float::NAN, Up));
     assert_panic!(Integer::rounding_from(&Float::NAN, Nearest));
+    assert_panic!(Integer::rounding_from(&Float::NAN, NearestTiesAway));
+    assert_panic!(Integer::rounding_from(&Float::NAN, Odd));
     assert_panic!(Integer::rounding_from(&Float::NAN, Exact));
 
     assert_panic!(Integer::rounding_from(&Float::INFINITY, Floor));
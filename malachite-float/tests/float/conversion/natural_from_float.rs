@@ -127,6 +127,8 @@ fn test_rounding_from_float() {
     test("0.0", "0x0.0", Down, "0", Equal);
     test("0.0", "0x0.0", Up, "0", Equal);
     test("0.0", "0x0.0", Nearest, "0", Equal);
+    test("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+    test("0.0", "0x0.0", Odd, "0", Equal);
     test("0.0", "0x0.0", Exact, "0", Equal);
 
     test("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -134,6 +136,8 @@ fn test_rounding_from_float() {
     test("-0.0", "-0x0.0", Down, "0", Equal);
     test("-0.0", "-0x0.0", Up, "0", Equal);
     test("-0.0", "-0x0.0", Nearest, "0", Equal);
+    test("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+    test("-0.0", "-0x0.0", Odd, "0", Equal);
     test("-0.0", "-0x0.0", Exact, "0", Equal);
 
     test("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -141,6 +145,8 @@ fn test_rounding_from_float() {
     test("1.0", "0x1.0#1", Down, "1", Equal);
     test("1.0", "0x1.0#1", Up, "1", Equal);
     test("1.0", "0x1.0#1", Nearest, "1", Equal);
+    test("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+    test("1.0", "0x1.0#1", Odd, "1", Equal);
     test("1.0", "0x1.0#1", Exact, "1", Equal);
 
     test("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -148,6 +154,8 @@ fn test_rounding_from_float() {
     test("2.0", "0x2.0#1", Down, "2", Equal);
     test("2.0", "0x2.0#1", Up, "2", Equal);
     test("2.0", "0x2.0#1", Nearest, "2", Equal);
+    test("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+    test("2.0", "0x2.0#1", Odd, "2", Equal);
     test("2.0", "0x2.0#1", Exact, "2", Equal);
 
     test("0.50", "0x0.8#1", Floor, "0", Less);
@@ -155,6 +163,8 @@ fn test_rounding_from_float() {
     test("0.50", "0x0.8#1", Down, "0", Less);
     test("0.50", "0x0.8#1", Up, "1", Greater);
     test("0.50", "0x0.8#1", Nearest, "0", Less);
+    test("0.50", "0x0.8#1", NearestTiesAway, "1", Greater);
+    test("0.50", "0x0.8#1", Odd, "1", Greater);
 
     test(
         "0.33333333333333331",
@@ -233,31 +243,40 @@ fn test_rounding_from_float() {
     test("1.5", "0x1.8#2", Down, "1", Less);
     test("1.5", "0x1.8#2", Up, "2", Greater);
     test("1.5", "0x1.8#2", Nearest, "2", Greater);
+    test("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+    test("1.5", "0x1.8#2", Odd, "1", Less);
 
     test("2.5", "0x2.8#3", Floor, "2", Less);
     test("2.5", "0x2.8#3", Ceiling, "3", Greater);
     test("2.5", "0x2.8#3", Down, "2", Less);
     test("2.5", "0x2.8#3", Up, "3", Greater);
     test("2.5", "0x2.8#3", Nearest, "2", Less);
+    test("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+    test("2.5", "0x2.8#3", Odd, "3", Greater);
 
     test("123.0", "0x7b.0#7", Floor, "123", Equal);
     test("123.0", "0x7b.0#7", Ceiling, "123", Equal);
     test("123.0", "0x7b.0#7", Down, "123", Equal);
     test("123.0", "0x7b.0#7", Up, "123", Equal);
     test("123.0", "0x7b.0#7", Nearest, "123", Equal);
+    test("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+    test("123.0", "0x7b.0#7", Odd, "123", Equal);
     test("123.0", "0x7b.0#7", Exact, "123", Equal);
 
     test("-1.0", "-0x1.0#1", Ceiling, "0", Greater);
     test("-1.0", "-0x1.0#1", Down, "0", Greater);
     test("-1.0", "-0x1.0#1", Nearest, "0", Greater);
+    test("-1.0", "-0x1.0#1", NearestTiesAway, "0", Greater);
 
     test("-2.0", "-0x2.0#1", Ceiling, "0", Greater);
     test("-2.0", "-0x2.0#1", Down, "0", Greater);
     test("-2.0", "-0x2.0#1", Nearest, "0", Greater);
+    test("-2.0", "-0x2.0#1", NearestTiesAway, "0", Greater);
 
     test("-0.50", "-0x0.8#1", Ceiling, "0", Greater);
     test("-0.50", "-0x0.8#1", Down, "0", Greater);
     test("-0.50", "-0x0.8#1", Nearest, "0", Greater);
+    test("-0.50", "-0x0.8#1", NearestTiesAway, "0", Greater);
 
     test(
         "-0.33333333333333331",
@@ -306,14 +325,17 @@ fn test_rounding_from_float() {
     test("-1.5", "-0x1.8#2", Ceiling, "0", Greater);
     test("-1.5", "-0x1.8#2", Down, "0", Greater);
     test("-1.5", "-0x1.8#2", Nearest, "0", Greater);
+    test("-1.5", "-0x1.8#2", NearestTiesAway, "0", Greater);
 
     test("-2.5", "-0x2.8#3", Ceiling, "0", Greater);
     test("-2.5", "-0x2.8#3", Down, "0", Greater);
     test("-2.5", "-0x2.8#3", Nearest, "0", Greater);
+    test("-2.5", "-0x2.8#3", NearestTiesAway, "0", Greater);
 
     test("-123.0", "-0x7b.0#7", Ceiling, "0", Greater);
     test("-123.0", "-0x7b.0#7", Down, "0", Greater);
     test("-123.0", "-0x7b.0#7", Nearest, "0", Greater);
+    test("-123.0", "-0x7b.0#7", NearestTiesAway, "0", Greater);
 }
 
 #[test]
@@ -323,6 +345,8 @@ fn natural_rounding_from_float_fail() {
     assert_panic!(Natural::rounding_from(Float::NAN, Down));
     assert_panic!(Natural::rounding_from(Float::NAN, Up));
     assert_panic!(Natural::rounding_from(Float::NAN, Nearest));
+    assert_panic!(Natural::rounding_from(Float::NAN, NearestTiesAway));
+    assert_panic!(Natural::rounding_from(Float::NAN, Odd));
     assert_panic!(Natural::rounding_from(Float::NAN, Exact));
 
     assert_panic!(Natural::rounding_from(Float::INFINITY, Floor));
@@ -334,10 +358,12 @@ fn natural_rounding_from_float_fail() {
 
     assert_panic!(Natural::rounding_from(Float::NEGATIVE_INFINITY, Floor));
     assert_panic!(Natural::rounding_from(Float::NEGATIVE_INFINITY, Up));
+    assert_panic!(Natural::rounding_from(Float::NEGATIVE_INFINITY, Odd));
     assert_panic!(Natural::rounding_from(Float::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(Natural::rounding_from(Float::NEGATIVE_ONE, Floor));
     assert_panic!(Natural::rounding_from(Float::NEGATIVE_ONE, Up));
+    assert_panic!(Natural::rounding_from(Float::NEGATIVE_ONE, Odd));
     assert_panic!(Natural::rounding_from(Float::NEGATIVE_ONE, Exact));
 
     assert_panic!(Natural::rounding_from(Float::from(3u8) >> 1, Exact));
@@ -350,6 +376,8 @@ fn natural_rounding_from_float_ref_fail() {
     assert_panic!(Natural::rounding_from(&Float::NAN, Down));
     assert_panic!(Natural::rounding_from(&Float::NAN, Up));
     assert_panic!(Natural::rounding_from(&Float::NAN, Nearest));
+    assert_panic!(Natural::rounding_from(&Float::NAN, NearestTiesAway));
+    assert_panic!(Natural::rounding_from(&Float::NAN, Odd));
     assert_panic!(Natural::rounding_from(&Float::NAN, Exact));
 
     assert_panic!(Natural::rounding_from(&Float::INFINITY, Floor));
@@ -361,10 +389,12 @@ fn natural_rounding_from_float_ref_fail() {
 
     assert_panic!(Natural::rounding_from(&Float::NEGATIVE_INFINITY, Floor));
     assert_panic!(Natural::rounding_from(&Float::NEGATIVE_INFINITY, Up));
+    assert_panic!(Natural::rounding_from(&Float::NEGATIVE_INFINITY, Odd));
     assert_panic!(Natural::rounding_from(&Float::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(Natural::rounding_from(&Float::NEGATIVE_ONE, Floor));
     assert_panic!(Natural::rounding_from(&Float::NEGATIVE_ONE, Up));
+    assert_panic!(Natural::rounding_from(&Float::NEGATIVE_ONE, Odd));
     assert_panic!(Natural::rounding_from(&Float::NEGATIVE_ONE, Exact));
 
     assert_panic!(Natural::rounding_from(&(Float::from(3u8) >> 1), Exact));
@@ -294,6 +294,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.0", "0x0.0", Down, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Up, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", Odd, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -301,6 +303,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-0.0", "-0x0.0", Down, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Up, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", Odd, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -308,6 +312,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("1.0", "0x1.0#1", Down, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Up, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Nearest, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", Odd, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Exact, "1", Equal);
 
         test_helper::<T>("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -315,6 +321,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("2.0", "0x2.0#1", Down, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Up, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Nearest, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", Odd, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Exact, "2", Equal);
 
         test_helper::<T>("0.50", "0x0.8#1", Floor, "0", Less);
@@ -322,6 +330,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.50", "0x0.8#1", Down, "0", Less);
         test_helper::<T>("0.50", "0x0.8#1", Up, "1", Greater);
         test_helper::<T>("0.50", "0x0.8#1", Nearest, "0", Less);
+        test_helper::<T>("0.50", "0x0.8#1", NearestTiesAway, "1", Greater);
+        test_helper::<T>("0.50", "0x0.8#1", Odd, "1", Greater);
 
         test_helper::<T>(
             "0.33333333333333331",
@@ -400,18 +410,24 @@ fn test_rounding_from_float() {
         test_helper::<T>("1.5", "0x1.8#2", Down, "1", Less);
         test_helper::<T>("1.5", "0x1.8#2", Up, "2", Greater);
         test_helper::<T>("1.5", "0x1.8#2", Nearest, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", Odd, "1", Less);
 
         test_helper::<T>("2.5", "0x2.8#3", Floor, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Ceiling, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Down, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Up, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Nearest, "2", Less);
+        test_helper::<T>("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+        test_helper::<T>("2.5", "0x2.8#3", Odd, "3", Greater);
 
         test_helper::<T>("123.0", "0x7b.0#7", Floor, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Ceiling, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Down, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Up, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Nearest, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", Odd, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Exact, "123", Equal);
 
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Floor, "0", Less);
@@ -425,18 +441,23 @@ fn test_rounding_from_float() {
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Down, "0", Less);
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Up, "1", Greater);
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Nearest, "0", Less);
+        test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", NearestTiesAway, "0", Less);
+        test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Odd, "1", Greater);
 
         test_helper::<T>("-1.0", "-0x1.0#1", Ceiling, "0", Greater);
         test_helper::<T>("-1.0", "-0x1.0#1", Down, "0", Greater);
         test_helper::<T>("-1.0", "-0x1.0#1", Nearest, "0", Greater);
+        test_helper::<T>("-1.0", "-0x1.0#1", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-2.0", "-0x2.0#1", Ceiling, "0", Greater);
         test_helper::<T>("-2.0", "-0x2.0#1", Down, "0", Greater);
         test_helper::<T>("-2.0", "-0x2.0#1", Nearest, "0", Greater);
+        test_helper::<T>("-2.0", "-0x2.0#1", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-0.50", "-0x0.8#1", Ceiling, "0", Greater);
         test_helper::<T>("-0.50", "-0x0.8#1", Down, "0", Greater);
         test_helper::<T>("-0.50", "-0x0.8#1", Nearest, "0", Greater);
+        test_helper::<T>("-0.50", "-0x0.8#1", NearestTiesAway, "0", Greater);
 
         test_helper::<T>(
             "-0.33333333333333331",
@@ -485,14 +506,17 @@ fn test_rounding_from_float() {
         test_helper::<T>("-1.5", "-0x1.8#2", Ceiling, "0", Greater);
         test_helper::<T>("-1.5", "-0x1.8#2", Down, "0", Greater);
         test_helper::<T>("-1.5", "-0x1.8#2", Nearest, "0", Greater);
+        test_helper::<T>("-1.5", "-0x1.8#2", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-2.5", "-0x2.8#3", Ceiling, "0", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Down, "0", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Nearest, "0", Greater);
+        test_helper::<T>("-2.5", "-0x2.8#3", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-123.0", "-0x7b.0#7", Ceiling, "0", Greater);
         test_helper::<T>("-123.0", "-0x7b.0#7", Down, "0", Greater);
         test_helper::<T>("-123.0", "-0x7b.0#7", Nearest, "0", Greater);
+        test_helper::<T>("-123.0", "-0x7b.0#7", NearestTiesAway, "0", Greater);
 
         test_helper::<T>(
             "-1.0e323228496",
@@ -541,6 +565,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.0", "0x0.0", Down, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Up, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", Odd, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -548,6 +574,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-0.0", "-0x0.0", Down, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Up, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", Odd, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -555,6 +583,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("1.0", "0x1.0#1", Down, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Up, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Nearest, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", Odd, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Exact, "1", Equal);
 
         test_helper::<T>("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -562,6 +592,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("2.0", "0x2.0#1", Down, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Up, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Nearest, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", Odd, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Exact, "2", Equal);
 
         test_helper::<T>("0.50", "0x0.8#1", Floor, "0", Less);
@@ -569,6 +601,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.50", "0x0.8#1", Down, "0", Less);
         test_helper::<T>("0.50", "0x0.8#1", Up, "1", Greater);
         test_helper::<T>("0.50", "0x0.8#1", Nearest, "0", Less);
+        test_helper::<T>("0.50", "0x0.8#1", NearestTiesAway, "1", Greater);
+        test_helper::<T>("0.50", "0x0.8#1", Odd, "1", Greater);
 
         test_helper::<T>(
             "0.33333333333333331",
@@ -647,18 +681,24 @@ fn test_rounding_from_float() {
         test_helper::<T>("1.5", "0x1.8#2", Down, "1", Less);
         test_helper::<T>("1.5", "0x1.8#2", Up, "2", Greater);
         test_helper::<T>("1.5", "0x1.8#2", Nearest, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", Odd, "1", Less);
 
         test_helper::<T>("2.5", "0x2.8#3", Floor, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Ceiling, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Down, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Up, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Nearest, "2", Less);
+        test_helper::<T>("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+        test_helper::<T>("2.5", "0x2.8#3", Odd, "3", Greater);
 
         test_helper::<T>("123.0", "0x7b.0#7", Floor, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Ceiling, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Down, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Up, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Nearest, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", Odd, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Exact, "123", Equal);
 
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Floor, "0", Less);
@@ -672,12 +712,16 @@ fn test_rounding_from_float() {
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Down, "0", Less);
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Up, "1", Greater);
         test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Nearest, "0", Less);
+        test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", NearestTiesAway, "0", Less);
+        test_helper::<T>("2.4e-323228497", "0x1.0E-268435456#1", Odd, "1", Greater);
 
         test_helper::<T>("-1.0", "-0x1.0#1", Floor, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Ceiling, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Down, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Up, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Nearest, "-1", Equal);
+        test_helper::<T>("-1.0", "-0x1.0#1", NearestTiesAway, "-1", Equal);
+        test_helper::<T>("-1.0", "-0x1.0#1", Odd, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Exact, "-1", Equal);
 
         test_helper::<T>("-2.0", "-0x2.0#1", Floor, "-2", Equal);
@@ -685,6 +729,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-2.0", "-0x2.0#1", Down, "-2", Equal);
         test_helper::<T>("-2.0", "-0x2.0#1", Up, "-2", Equal);
         test_helper::<T>("-2.0", "-0x2.0#1", Nearest, "-2", Equal);
+        test_helper::<T>("-2.0", "-0x2.0#1", NearestTiesAway, "-2", Equal);
+        test_helper::<T>("-2.0", "-0x2.0#1", Odd, "-2", Equal);
         test_helper::<T>("-2.0", "-0x2.0#1", Exact, "-2", Equal);
 
         test_helper::<T>("-0.50", "-0x0.8#1", Floor, "-1", Less);
@@ -692,6 +738,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-0.50", "-0x0.8#1", Down, "0", Greater);
         test_helper::<T>("-0.50", "-0x0.8#1", Up, "-1", Less);
         test_helper::<T>("-0.50", "-0x0.8#1", Nearest, "0", Greater);
+        test_helper::<T>("-0.50", "-0x0.8#1", NearestTiesAway, "-1", Less);
+        test_helper::<T>("-0.50", "-0x0.8#1", Odd, "-1", Less);
 
         test_helper::<T>(
             "-0.33333333333333331",
@@ -770,18 +818,24 @@ fn test_rounding_from_float() {
         test_helper::<T>("-1.5", "-0x1.8#2", Down, "-1", Greater);
         test_helper::<T>("-1.5", "-0x1.8#2", Up, "-2", Less);
         test_helper::<T>("-1.5", "-0x1.8#2", Nearest, "-2", Less);
+        test_helper::<T>("-1.5", "-0x1.8#2", NearestTiesAway, "-2", Less);
+        test_helper::<T>("-1.5", "-0x1.8#2", Odd, "-1", Greater);
 
         test_helper::<T>("-2.5", "-0x2.8#3", Floor, "-3", Less);
         test_helper::<T>("-2.5", "-0x2.8#3", Ceiling, "-2", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Down, "-2", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Up, "-3", Less);
         test_helper::<T>("-2.5", "-0x2.8#3", Nearest, "-2", Greater);
+        test_helper::<T>("-2.5", "-0x2.8#3", NearestTiesAway, "-3", Less);
+        test_helper::<T>("-2.5", "-0x2.8#3", Odd, "-3", Less);
 
         test_helper::<T>("-123.0", "-0x7b.0#7", Floor, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Ceiling, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Down, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Up, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Nearest, "-123", Equal);
+        test_helper::<T>("-123.0", "-0x7b.0#7", NearestTiesAway, "-123", Equal);
+        test_helper::<T>("-123.0", "-0x7b.0#7", Odd, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Exact, "-123", Equal);
 
         test_helper::<T>("-2.4e-323228497", "-0x1.0E-268435456#1", Floor, "-1", Less);
@@ -836,6 +890,8 @@ fn unsigned_rounding_from_float_fail_helper<T: PrimitiveUnsigned + RoundingFrom<
     assert_panic!(T::rounding_from(Float::NAN, Down));
     assert_panic!(T::rounding_from(Float::NAN, Up));
     assert_panic!(T::rounding_from(Float::NAN, Nearest));
+    assert_panic!(T::rounding_from(Float::NAN, NearestTiesAway));
+    assert_panic!(T::rounding_from(Float::NAN, Odd));
     assert_panic!(T::rounding_from(Float::NAN, Exact));
 
     assert_panic!(T::rounding_from(Float::INFINITY, Ceiling));
@@ -844,10 +900,12 @@ fn unsigned_rounding_from_float_fail_helper<T: PrimitiveUnsigned + RoundingFrom<
 
     assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Floor));
     assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Up));
+    assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Odd));
     assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(T::rounding_from(Float::NEGATIVE_ONE, Floor));
     assert_panic!(T::rounding_from(Float::NEGATIVE_ONE, Up));
+    assert_panic!(T::rounding_from(Float::NEGATIVE_ONE, Odd));
     assert_panic!(T::rounding_from(Float::NEGATIVE_ONE, Exact));
 
     assert_panic!(T::rounding_from(Float::from(3u8) >> 1, Exact));
@@ -859,6 +917,8 @@ fn signed_rounding_from_float_fail_helper<T: PrimitiveSigned + RoundingFrom<Floa
     assert_panic!(T::rounding_from(Float::NAN, Down));
     assert_panic!(T::rounding_from(Float::NAN, Up));
     assert_panic!(T::rounding_from(Float::NAN, Nearest));
+    assert_panic!(T::rounding_from(Float::NAN, NearestTiesAway));
+    assert_panic!(T::rounding_from(Float::NAN, Odd));
     assert_panic!(T::rounding_from(Float::NAN, Exact));
 
     assert_panic!(T::rounding_from(Float::INFINITY, Ceiling));
@@ -867,6 +927,7 @@ fn signed_rounding_from_float_fail_helper<T: PrimitiveSigned + RoundingFrom<Floa
 
     assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Floor));
     assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Up));
+    assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Odd));
     assert_panic!(T::rounding_from(Float::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(T::rounding_from(Float::from(3u8) >> 1, Exact));
@@ -889,6 +950,8 @@ where
     assert_panic!(T::rounding_from(&Float::NAN, Down));
     assert_panic!(T::rounding_from(&Float::NAN, Up));
     assert_panic!(T::rounding_from(&Float::NAN, Nearest));
+    assert_panic!(T::rounding_from(&Float::NAN, NearestTiesAway));
+    assert_panic!(T::rounding_from(&Float::NAN, Odd));
     assert_panic!(T::rounding_from(&Float::NAN, Exact));
 
     assert_panic!(T::rounding_from(&Float::INFINITY, Ceiling));
@@ -897,10 +960,12 @@ where
 
     assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Floor));
     assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Up));
+    assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Odd));
     assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(T::rounding_from(&Float::NEGATIVE_ONE, Floor));
     assert_panic!(T::rounding_from(&Float::NEGATIVE_ONE, Up));
+    assert_panic!(T::rounding_from(&Float::NEGATIVE_ONE, Odd));
     assert_panic!(T::rounding_from(&Float::NEGATIVE_ONE, Exact));
 
     assert_panic!(T::rounding_from(&(Float::from(3u8) >> 1), Exact));
@@ -916,6 +981,8 @@ where
     assert_panic!(T::rounding_from(&Float::NAN, Down));
     assert_panic!(T::rounding_from(&Float::NAN, Up));
     assert_panic!(T::rounding_from(&Float::NAN, Nearest));
+    assert_panic!(T::rounding_from(&Float::NAN, NearestTiesAway));
+    assert_panic!(T::rounding_from(&Float::NAN, Odd));
     assert_panic!(T::rounding_from(&Float::NAN, Exact));
 
     assert_panic!(T::rounding_from(&Float::INFINITY, Ceiling));
@@ -924,6 +991,7 @@ where
 
     assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Floor));
     assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Up));
+    assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Odd));
     assert_panic!(T::rounding_from(&Float::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(T::rounding_from(&(Float::from(3u8) >> 1), Exact));
@@ -39,7 +39,7 @@ fn ordering_valid_for(o: Ordering, rm: RoundingMode, sign: bool) -> bool {
         // away from zero
         Up => o == Equal || (o == Greater) == sign,
         Exact => o == Equal,
-        Nearest => true,
+        Nearest | NearestTiesAway | Odd => true,
     }
 }
 
@@ -0,0 +1,222 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{
+    interval_sample_points, interval_to_hex_string, parse_interval_hex,
+};
+use malachite_q::Rational;
+
+#[test]
+fn test_add() {
+    let test = |xl, xu, yl, yu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let sum = x.clone() + y.clone();
+        assert!(sum.is_valid());
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(interval_to_hex_string(&sum), out_hex);
+
+        let sum_alt = x.clone() + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&sum_alt), out_hex);
+        let sum_alt = &x + y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&sum_alt), out_hex);
+        let sum_alt = &x + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&sum_alt), out_hex);
+
+        let mut sum_alt = x.clone();
+        sum_alt += y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&sum_alt), out_hex);
+        let mut sum_alt = x.clone();
+        sum_alt += &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&sum_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "[2.0, 2.0]",
+        "[0x2.0#1, 0x2.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        "[1.3320, 2.3359]",
+        "[0x1.550#10, 0x2.56#10]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "-0x2.0#1",
+        "0x1.0#1",
+        "[-0.50, 3.5]",
+        "[-0x0.8#3, 0x3.8#3]",
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "0x1.8#2",
+        "0x2.8#3",
+        "[2.5, Infinity]",
+        "[0x2.8#3, Infinity]",
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "[-Infinity, Infinity]",
+        "[-Infinity, Infinity]",
+    );
+    test(
+        "0x0.0",
+        "0x0.0",
+        "-0x0.0",
+        "-0x0.0",
+        "[-0.0, 0.0]",
+        "[-0x0.0, 0x0.0]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        "[1.0, 2.0]",
+        "[0x1.0#1, 0x2.0#1]",
+    );
+}
+
+#[test]
+fn test_add_prec() {
+    let test = |xl, xu, yl, yu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let sum = x.clone().add_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(interval_to_hex_string(&sum), out_hex);
+
+        let sum_alt = x.add_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&sum_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        1,
+        "[2.0, 2.0]",
+        "[0x2.0#1, 0x2.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        3,
+        "[1.2, 2.5]",
+        "[0x1.4#3, 0x2.8#3]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        20,
+        "[1.3330078, 2.3334961]",
+        "[0x1.55400#20, 0x2.55600#20]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        10,
+        "[1.0000, 1.0020]",
+        "[0x1.000#10, 0x1.008#10]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn add_prec_fail() {
+    Interval::ONE.add_prec(Interval::ONE, 0);
+}
+
+#[test]
+fn add_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in [1, 10, 100] {
+            let sum = x.clone().add_prec(y.clone(), prec);
+            assert!(sum.is_valid());
+            let sum_alt = x.add_prec_ref_ref(&y, prec);
+            assert!(sum_alt.is_valid());
+            assert_eq!(
+                interval_to_hex_string(&sum_alt),
+                interval_to_hex_string(&sum)
+            );
+            let sum_alt = y.add_prec_ref_ref(&x, prec);
+            assert_eq!(
+                interval_to_hex_string(&sum_alt),
+                interval_to_hex_string(&sum)
+            );
+            for a in interval_sample_points(&x) {
+                for b in interval_sample_points(&y) {
+                    assert!(sum.contains_rational(&(&a + &b)));
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn add_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let sum = x.clone() + y.clone();
+        assert!(sum.is_valid());
+        let sum_alt = &x + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&sum_alt),
+            interval_to_hex_string(&sum)
+        );
+        let sum_alt = x.add_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(
+            interval_to_hex_string(&sum_alt),
+            interval_to_hex_string(&sum)
+        );
+        assert_eq!(&y + &x, sum);
+        assert_eq!(-&sum, -&x + -&y);
+        for a in interval_sample_points(&x) {
+            for b in interval_sample_points(&y) {
+                assert!(sum.contains_rational(&(&a + &b)));
+            }
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(&x + &Interval::ZERO, x);
+        assert_eq!(&Interval::ZERO + &x, x);
+        assert!((&x + -&x).contains_rational(&Rational::ZERO));
+    });
+}
@@ -0,0 +1,225 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::Float;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{
+    interval_sample_points, interval_to_hex_string, parse_interval_hex,
+};
+
+#[test]
+fn test_div() {
+    let test = |xl, xu, yl, yu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(interval_to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&quotient_alt), out_hex);
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&quotient_alt), out_hex);
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&quotient_alt), out_hex);
+
+        let mut quotient_alt = x.clone();
+        quotient_alt /= y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&quotient_alt), out_hex);
+        let mut quotient_alt = x.clone();
+        quotient_alt /= &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&quotient_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "[1.0, 1.0]",
+        "[0x1.0#1, 0x1.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        "[2.9961, 6.0078]",
+        "[0x2.ff#10, 0x6.02#10]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "-0x2.0#1",
+        "0x1.0#1",
+        "[-Infinity, Infinity]",
+        "[-Infinity, Infinity]",
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "0x1.8#2",
+        "0x2.8#3",
+        "[0.38, Infinity]",
+        "[0x0.6#3, Infinity]",
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "[-Infinity, 1.0]",
+        "[-Infinity, 0x1.0#1]",
+    );
+    test(
+        "0x0.0",
+        "0x0.0",
+        "-0x0.0",
+        "-0x0.0",
+        "[-Infinity, Infinity]",
+        "[-Infinity, Infinity]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        "[1.6e60, 1.6e60]",
+        "[0x1.0E+50#1, 0x1.0E+50#1]",
+    );
+}
+
+#[test]
+fn test_div_prec() {
+    let test = |xl, xu, yl, yu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let quotient = x.clone().div_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(interval_to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.div_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&quotient_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        1,
+        "[1.0, 1.0]",
+        "[0x1.0#1, 0x1.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        3,
+        "[2.5, 7.0]",
+        "[0x2.8#3, 0x7.0#3]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        20,
+        "[2.9985352, 6.0058670]",
+        "[0x2.ffa00#20, 0x6.01808#20]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        10,
+        "[1.6069e60, 1.6069e60]",
+        "[0x1.000E+50#10, 0x1.000E+50#10]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_prec_fail() {
+    Interval::ONE.div_prec(Interval::ONE, 0);
+}
+
+#[test]
+fn div_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in [1, 10, 100] {
+            let quotient = x.clone().div_prec(y.clone(), prec);
+            assert!(quotient.is_valid());
+            let quotient_alt = x.div_prec_ref_ref(&y, prec);
+            assert!(quotient_alt.is_valid());
+            assert_eq!(
+                interval_to_hex_string(&quotient_alt),
+                interval_to_hex_string(&quotient)
+            );
+            for a in interval_sample_points(&x) {
+                for b in interval_sample_points(&y) {
+                    if b != 0u32 {
+                        assert!(quotient.contains_rational(&(&a / &b)));
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn div_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&quotient_alt),
+            interval_to_hex_string(&quotient)
+        );
+        let quotient_alt = x.div_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(
+            interval_to_hex_string(&quotient_alt),
+            interval_to_hex_string(&quotient)
+        );
+        assert_eq!(-&quotient, -&x / &y);
+        assert_eq!(-&quotient, &x / -&y);
+        if *y.lower() < 0u32 && *y.upper() > 0u32 {
+            assert_eq!(quotient, Interval::ENTIRE);
+        }
+        for a in interval_sample_points(&x) {
+            for b in interval_sample_points(&y) {
+                if b != 0u32 {
+                    assert!(quotient.contains_rational(&(&a / &b)));
+                }
+            }
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(&x / &Interval::ONE, x);
+        if !x.contains(&Float::ZERO) {
+            assert!((&x / &x).contains(&Float::ONE));
+        }
+    });
+}
@@ -0,0 +1,125 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::test_util::generators::{interval_gen, interval_unsigned_pair_gen_var_1};
+use malachite_float::test_util::interval::{
+    interval_may_contain, interval_sample_floats, interval_to_hex_string, parse_interval_hex,
+};
+
+#[test]
+fn test_exp() {
+    let test = |xl, xu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let y = x.clone().exp();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(interval_to_hex_string(&y), out_hex);
+
+        let y_alt = (&x).exp();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+
+        let mut y_alt = x.clone();
+        y_alt.exp_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x0.0", "0x0.0", "[1.0, 1.0]", "[0x1.0#1, 0x1.0#1]");
+    test("0x1.0#1", "0x2.0#1", "[2.0, 8.0]", "[0x2.0#1, 0x8.0#1]");
+    test(
+        "-0x2.0#1",
+        "0x0.554#10",
+        "[0.13525, 1.3965]",
+        "[0x0.22a#10, 0x1.658#10]",
+    );
+    test("-Infinity", "0x1.0#1", "[0.0, 4.0]", "[0x0.0, 0x4.0#1]");
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "[2.0, Infinity]",
+        "[0x2.0#1, Infinity]",
+    );
+    test(
+        "-Infinity",
+        "Infinity",
+        "[0.0, Infinity]",
+        "[0x0.0, Infinity]",
+    );
+}
+
+#[test]
+fn test_exp_prec() {
+    let test = |xl, xu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let y = x.clone().exp_prec(prec);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(interval_to_hex_string(&y), out_hex);
+
+        let y_alt = x.exp_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x1.0#1", "0x2.0#1", 1, "[2.0, 8.0]", "[0x2.0#1, 0x8.0#1]");
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        10,
+        "[2.7148, 7.3906]",
+        "[0x2.b7#10, 0x7.64#10]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        100,
+        "[4.4816890703380648226020554601141, 12.182493960703473438070175951177]",
+        "[0x4.7b4ff993f15055f6e4658d438#100, 0xc.2eb7ec98f05d8ea6f61a6f43#100]",
+    );
+}
+
+#[test]
+fn exp_prec_properties() {
+    interval_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let y = x.clone().exp_prec(prec);
+        assert!(y.is_valid());
+        let y_alt = x.exp_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        for a in interval_sample_floats(&x) {
+            assert!(interval_may_contain(&y, |rm| a
+                .exp_prec_round_ref(prec + 10, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+fn exp_properties() {
+    interval_gen().test_properties_with_limit(2000, |x| {
+        let y = x.clone().exp();
+        assert!(y.is_valid());
+        let y_alt = (&x).exp();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        let mut y_alt = x.clone();
+        y_alt.exp_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        let y_alt = x.exp_prec_ref(x.significant_bits());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        for a in interval_sample_floats(&x) {
+            assert!(interval_may_contain(&y, |rm| a
+                .exp_prec_round_ref(100, rm)
+                .0));
+        }
+    });
+}
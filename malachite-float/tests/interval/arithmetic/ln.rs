@@ -0,0 +1,146 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Ln, LnAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{
+    interval_gen_var_2, interval_unsigned_pair_gen_var_1,
+};
+use malachite_float::test_util::interval::{
+    interval_may_contain, interval_sample_floats, interval_to_hex_string, parse_interval_hex,
+};
+
+#[test]
+fn test_ln() {
+    let test = |xl, xu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let y = x.clone().ln();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(interval_to_hex_string(&y), out_hex);
+
+        let y_alt = (&x).ln();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+
+        let mut y_alt = x.clone();
+        y_alt.ln_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x1.0#1", "0x1.0#1", "[0.0, 0.0]", "[0x0.0, 0x0.0]");
+    test("0x1.0#1", "0x2.0#1", "[0.0, 1.0]", "[0x0.0, 0x1.0#1]");
+    test(
+        "0x0.554#10",
+        "0x0.556#10",
+        "[-1.0996, -1.0977]",
+        "[-0x1.198#10, -0x1.190#10]",
+    );
+    test(
+        "0x0.0",
+        "0x4.0#1",
+        "[-Infinity, 2.0]",
+        "[-Infinity, 0x2.0#1]",
+    );
+    test(
+        "-0x0.0",
+        "Infinity",
+        "[-Infinity, Infinity]",
+        "[-Infinity, Infinity]",
+    );
+}
+
+#[test]
+fn test_ln_prec() {
+    let test = |xl, xu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let y = x.clone().ln_prec(prec);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(interval_to_hex_string(&y), out_hex);
+
+        let y_alt = x.ln_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x1.0#1", "0x2.0#1", 1, "[0.0, 1.0]", "[0x0.0, 0x1.0#1]");
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        10,
+        "[0.0, 0.69336]",
+        "[0x0.0, 0x0.b18#10]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        100,
+        "[0.40546510810816438197801311546429, 0.91629073187415506518352721176863]",
+        "[0x0.67cc8fb2fe612fcada35d9bd00#100, 0x0.ea9207870703bd041593f17e1#100]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn ln_fail_1() {
+    (-Interval::ONE).ln();
+}
+
+#[test]
+fn ln_prec_properties() {
+    interval_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        if *x.lower() < 0u32 || *x.upper() == 0u32 {
+            return;
+        }
+        let y = x.clone().ln_prec(prec);
+        assert!(y.is_valid());
+        let y_alt = x.ln_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        for a in interval_sample_floats(&x) {
+            assert!(interval_may_contain(&y, |rm| a
+                .ln_prec_round_ref(prec + 10, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+fn ln_properties() {
+    interval_gen_var_2().test_properties_with_limit(2000, |x| {
+        if *x.upper() == 0u32 {
+            return;
+        }
+        let y = x.clone().ln();
+        assert!(y.is_valid());
+        let y_alt = (&x).ln();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        let mut y_alt = x.clone();
+        y_alt.ln_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        let y_alt = x.ln_prec_ref(x.significant_bits());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        for a in interval_sample_floats(&x) {
+            assert!(interval_may_contain(&y, |rm| a
+                .ln_prec_round_ref(100, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+#[should_panic]
+fn ln_fail_2() {
+    Interval::ZERO.ln();
+}
@@ -0,0 +1,219 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{
+    interval_sample_points, interval_to_hex_string, parse_interval_hex,
+};
+
+#[test]
+fn test_mul() {
+    let test = |xl, xu, yl, yu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+        assert_eq!(product.to_string(), out);
+        assert_eq!(interval_to_hex_string(&product), out_hex);
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&product_alt), out_hex);
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&product_alt), out_hex);
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&product_alt), out_hex);
+
+        let mut product_alt = x.clone();
+        product_alt *= y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&product_alt), out_hex);
+        let mut product_alt = x.clone();
+        product_alt *= &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&product_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "[1.0, 1.0]",
+        "[0x1.0#1, 0x1.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        "[0.33301, 0.66699]",
+        "[0x0.554#10, 0x0.aac#10]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "-0x2.0#1",
+        "0x1.0#1",
+        "[-5.0, 2.5]",
+        "[-0x5.0#3, 0x2.8#3]",
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "0x1.8#2",
+        "0x2.8#3",
+        "[1.5, Infinity]",
+        "[0x1.8#3, Infinity]",
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "[-Infinity, Infinity]",
+        "[-Infinity, Infinity]",
+    );
+    test(
+        "0x0.0",
+        "0x0.0",
+        "-0x0.0",
+        "-0x0.0",
+        "[-0.0, -0.0]",
+        "[-0x0.0, -0x0.0]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        "[6.2e-61, 6.2e-61]",
+        "[0x1.0E-50#1, 0x1.0E-50#1]",
+    );
+}
+
+#[test]
+fn test_mul_prec() {
+    let test = |xl, xu, yl, yu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let product = x.clone().mul_prec(y.clone(), prec);
+        assert!(product.is_valid());
+        assert_eq!(product.to_string(), out);
+        assert_eq!(interval_to_hex_string(&product), out_hex);
+
+        let product_alt = x.mul_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&product_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        1,
+        "[1.0, 1.0]",
+        "[0x1.0#1, 0x1.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        3,
+        "[0.31, 0.75]",
+        "[0x0.5#3, 0x0.c#3]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        20,
+        "[0.33300781, 0.66699219]",
+        "[0x0.554000#20, 0x0.aac00#20]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        10,
+        "[6.2230e-61, 6.2230e-61]",
+        "[0x1.000E-50#10, 0x1.000E-50#10]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_prec_fail() {
+    Interval::ONE.mul_prec(Interval::ONE, 0);
+}
+
+#[test]
+fn mul_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in [1, 10, 100] {
+            let product = x.clone().mul_prec(y.clone(), prec);
+            assert!(product.is_valid());
+            let product_alt = x.mul_prec_ref_ref(&y, prec);
+            assert!(product_alt.is_valid());
+            assert_eq!(
+                interval_to_hex_string(&product_alt),
+                interval_to_hex_string(&product)
+            );
+            assert_eq!(y.mul_prec_ref_ref(&x, prec), product);
+            for a in interval_sample_points(&x) {
+                for b in interval_sample_points(&y) {
+                    assert!(product.contains_rational(&(&a * &b)));
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn mul_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&product_alt),
+            interval_to_hex_string(&product)
+        );
+        let product_alt = x.mul_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(
+            interval_to_hex_string(&product_alt),
+            interval_to_hex_string(&product)
+        );
+        assert_eq!(&y * &x, product);
+        assert_eq!(-&product, -&x * &y);
+        assert_eq!(-&product, &x * -&y);
+        for a in interval_sample_points(&x) {
+            for b in interval_sample_points(&y) {
+                assert!(product.contains_rational(&(&a * &b)));
+            }
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(&x * &Interval::ONE, x);
+        assert_eq!(&Interval::ONE * &x, x);
+        assert_eq!(&x * &Interval::ZERO, Interval::ZERO);
+        assert_eq!(&Interval::ZERO * &x, Interval::ZERO);
+    });
+}
@@ -0,0 +1,79 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_float::test_util::generators::interval_gen;
+use malachite_float::test_util::interval::{
+    interval_sample_points, interval_to_hex_string, parse_interval_hex,
+};
+
+#[test]
+fn test_neg() {
+    let test = |xl, xu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+        assert_eq!(interval_to_hex_string(&neg), out_hex);
+
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&neg_alt), out_hex);
+
+        let mut neg_alt = x.clone();
+        neg_alt.neg_assign();
+        assert!(neg_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&neg_alt), out_hex);
+    };
+    test("0x1.0#1", "0x1.0#1", "[-1.0, -1.0]", "[-0x1.0#1, -0x1.0#1]");
+    test("0x1.8#2", "0x2.8#3", "[-2.5, -1.5]", "[-0x2.8#3, -0x1.8#2]");
+    test("-0x2.0#1", "0x1.0#1", "[-1.0, 2.0]", "[-0x1.0#1, 0x2.0#1]");
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "[-Infinity, -1.0]",
+        "[-Infinity, -0x1.0#1]",
+    );
+    test(
+        "-Infinity",
+        "Infinity",
+        "[-Infinity, Infinity]",
+        "[-Infinity, Infinity]",
+    );
+    test("0x0.0", "0x0.0", "[-0.0, -0.0]", "[-0x0.0, -0x0.0]");
+    test("-0x0.0", "0x0.0", "[-0.0, 0.0]", "[-0x0.0, 0x0.0]");
+}
+
+#[test]
+fn neg_properties() {
+    interval_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&neg_alt),
+            interval_to_hex_string(&neg)
+        );
+        let mut neg_alt = x.clone();
+        neg_alt.neg_assign();
+        assert!(neg_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&neg_alt),
+            interval_to_hex_string(&neg)
+        );
+
+        assert_eq!(interval_to_hex_string(&-&neg), interval_to_hex_string(&x));
+        assert_eq!(neg.lower(), &-x.upper());
+        assert_eq!(neg.upper(), &-x.lower());
+        for a in interval_sample_points(&x) {
+            assert!(neg.contains_rational(&-a));
+        }
+    });
+}
@@ -0,0 +1,360 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Parity, Pow, PowAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::Float;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{
+    interval_gen, interval_pair_gen, interval_unsigned_pair_gen_var_1,
+};
+use malachite_float::test_util::interval::{
+    interval_may_contain, interval_sample_floats, interval_sample_points, interval_to_hex_string,
+    parse_interval_hex,
+};
+
+#[test]
+fn test_pow() {
+    let test = |xl, xu, yl, yu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let p = x.clone().pow(y.clone());
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(interval_to_hex_string(&p), out_hex);
+
+        let p_alt = x.clone().pow(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+        let p_alt = (&x).pow(y.clone());
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+        let p_alt = (&x).pow(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(y.clone());
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+    };
+    test(
+        "0x2.0#1",
+        "0x2.0#1",
+        "0x2.0#1",
+        "0x2.0#1",
+        "[4.0, 4.0]",
+        "[0x4.0#1, 0x4.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x1.8#2",
+        "0x2.8#3",
+        "[1.0, 6.0]",
+        "[0x1.0#3, 0x6.0#3]",
+    );
+    test(
+        "0x0.8#1",
+        "0x2.0#1",
+        "-0x2.0#1",
+        "0x1.0#1",
+        "[0.25, 4.0]",
+        "[0x0.4#1, 0x4.0#1]",
+    );
+    test(
+        "0x0.0",
+        "0x2.0#1",
+        "-0x1.0#1",
+        "0x1.0#1",
+        "[0.0, Infinity]",
+        "[0x0.0, Infinity]",
+    );
+    test(
+        "-0x0.0",
+        "0x2.0#1",
+        "0x0.0",
+        "0x0.0",
+        "[1.0, 1.0]",
+        "[0x1.0#1, 0x1.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        "0x2.0#1",
+        "[1.0, Infinity]",
+        "[0x1.0#1, Infinity]",
+    );
+}
+
+#[test]
+fn test_pow_prec() {
+    let test = |xl, xu, yl, yu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let p = x.clone().pow_prec(y.clone(), prec);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(interval_to_hex_string(&p), out_hex);
+
+        let p_alt = x.pow_prec_ref_ref(&y, prec);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x1.8#2",
+        "0x2.8#3",
+        1,
+        "[1.0, 8.0]",
+        "[0x1.0#1, 0x8.0#1]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x1.8#2",
+        "0x2.8#3",
+        10,
+        "[1.0000, 5.6641]",
+        "[0x1.000#10, 0x5.aa#10]",
+    );
+    test(
+        "0x0.8#1",
+        "0x2.0#1",
+        "-0x2.0#1",
+        "0x1.0#1",
+        20,
+        "[0.25000000, 4.0000000]",
+        "[0x0.400000#20, 0x4.00000#20]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn pow_fail_1() {
+    (-Interval::ONE).pow(Interval::ONE);
+}
+
+#[test]
+#[should_panic]
+fn pow_fail_2() {
+    Interval::ZERO.pow(-Interval::ONE);
+}
+
+#[test]
+fn test_pow_u() {
+    let test = |xl, xu, n: u64, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let p = x.clone().pow(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(interval_to_hex_string(&p), out_hex);
+
+        let p_alt = (&x).pow(n);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(n);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+    };
+    test("0x1.8#2", "0x2.8#3", 0, "[1.0, 1.0]", "[0x1.0#3, 0x1.0#3]");
+    test("0x1.8#2", "0x2.8#3", 2, "[2.0, 7.0]", "[0x2.0#3, 0x7.0#3]");
+    test(
+        "-0x2.8#3",
+        "-0x1.8#2",
+        2,
+        "[2.0, 7.0]",
+        "[0x2.0#3, 0x7.0#3]",
+    );
+    test(
+        "-0x2.8#3",
+        "-0x1.8#2",
+        3,
+        "[-16.0, -3.0]",
+        "[-0x10.0#3, -0x3.0#3]",
+    );
+    test("-0x1.8#2", "0x2.8#3", 2, "[0.0, 7.0]", "[0x0.0, 0x7.0#3]");
+    test(
+        "-0x1.8#2",
+        "0x2.8#3",
+        3,
+        "[-3.5, 16.0]",
+        "[-0x3.8#3, 0x10.0#3]",
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        2,
+        "[0.0, Infinity]",
+        "[0x0.0, Infinity]",
+    );
+    test(
+        "0x0.554#10",
+        "0x0.556#10",
+        5,
+        "[0.0040894, 0.0041275]",
+        "[0x0.010c0#10, 0x0.010e8#10]",
+    );
+}
+
+#[test]
+fn test_pow_u_prec() {
+    let test = |xl, xu, n, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let p = x.clone().pow_u_prec(n, prec);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(interval_to_hex_string(&p), out_hex);
+
+        let p_alt = x.pow_u_prec_ref(n, prec);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), out_hex);
+    };
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        0,
+        10,
+        "[1.0000, 1.0000]",
+        "[0x1.000#10, 0x1.000#10]",
+    );
+    test(
+        "-0x1.8#2",
+        "0x2.8#3",
+        2,
+        1,
+        "[0.0, 8.0]",
+        "[0x0.0, 0x8.0#1]",
+    );
+    test(
+        "0x0.554#10",
+        "0x0.556#10",
+        5,
+        20,
+        "[0.0040951669, 0.0041252896]",
+        "[0x0.010c618#20, 0x0.010e5ae#20]",
+    );
+}
+
+// Float's pow distinguishes -0.0 from 0.0, but as elements of an Interval they are the same number.
+fn positive_zero(x: Float) -> Float {
+    if x == 0u32 { Float::ZERO } else { x }
+}
+
+#[test]
+fn pow_prec_properties() {
+    interval_pair_gen().test_properties_with_limit(2000, |(x, y)| {
+        if *x.lower() < 0u32 || *x.upper() == 0u32 && *y.upper() < 0u32 {
+            return;
+        }
+        for prec in [1, 10, 100] {
+            let p = x.clone().pow_prec(y.clone(), prec);
+            assert!(p.is_valid());
+            let p_alt = x.pow_prec_ref_ref(&y, prec);
+            assert!(p_alt.is_valid());
+            assert_eq!(interval_to_hex_string(&p_alt), interval_to_hex_string(&p));
+            for a in interval_sample_floats(&x) {
+                let a = positive_zero(a);
+                for b in interval_sample_floats(&y) {
+                    assert!(interval_may_contain(&p, |rm| a
+                        .pow_prec_round_ref_ref(&b, prec + 10, rm)
+                        .0));
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn pow_properties() {
+    interval_pair_gen().test_properties_with_limit(2000, |(x, y)| {
+        if *x.lower() < 0u32 || *x.upper() == 0u32 && *y.upper() < 0u32 {
+            return;
+        }
+        let p = x.clone().pow(y.clone());
+        assert!(p.is_valid());
+        let p_alt = (&x).pow(&y);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), interval_to_hex_string(&p));
+        let p_alt = x.pow_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(interval_to_hex_string(&p_alt), interval_to_hex_string(&p));
+        for a in interval_sample_floats(&x) {
+            let a = positive_zero(a);
+            for b in interval_sample_floats(&y) {
+                assert!(interval_may_contain(&p, |rm| a
+                    .pow_prec_round_ref_ref(&b, 100, rm)
+                    .0));
+            }
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        if *x.lower() >= 0u32 {
+            assert!((&x).pow(&Interval::ZERO).contains(&Float::ONE));
+            assert_eq!((&x).pow(&Interval::ONE), x);
+        }
+    });
+}
+
+#[test]
+fn pow_u_prec_properties() {
+    interval_unsigned_pair_gen_var_1::<u64>().test_properties(|(x, n)| {
+        for prec in [1, 10, 100] {
+            let p = x.clone().pow_u_prec(n, prec);
+            assert!(p.is_valid());
+            let p_alt = x.pow_u_prec_ref(n, prec);
+            assert!(p_alt.is_valid());
+            assert_eq!(interval_to_hex_string(&p_alt), interval_to_hex_string(&p));
+            for a in interval_sample_points(&x) {
+                assert!(p.contains_rational(&(&a).pow(n)));
+            }
+        }
+    });
+}
+
+#[test]
+fn pow_u_properties() {
+    interval_unsigned_pair_gen_var_1::<u64>().test_properties(|(x, n)| {
+        let p = x.clone().pow(n);
+        assert!(p.is_valid());
+        let p_alt = (&x).pow(n);
+        assert!(p_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&p_alt), interval_to_hex_string(&p));
+        let mut p_alt = x.clone();
+        p_alt.pow_assign(n);
+        assert_eq!(interval_to_hex_string(&p_alt), interval_to_hex_string(&p));
+        let p_alt = x.pow_u_prec_ref(n, x.significant_bits());
+        assert_eq!(interval_to_hex_string(&p_alt), interval_to_hex_string(&p));
+        if n.even() {
+            assert!(*p.lower() >= 0u32);
+        }
+        for a in interval_sample_points(&x) {
+            assert!(p.contains_rational(&(&a).pow(n)));
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!((&x).pow(1u64), x);
+        assert_eq!((&x).pow(2u64), x.pow_u_prec_ref(2, x.significant_bits()));
+        assert!((&x * &x).contains_interval(&(&x).pow(2u64)));
+    });
+}
@@ -0,0 +1,132 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{
+    interval_gen_var_2, interval_unsigned_pair_gen_var_1,
+};
+use malachite_float::test_util::interval::{
+    interval_may_contain, interval_sample_floats, interval_to_hex_string, parse_interval_hex,
+};
+
+#[test]
+fn test_sqrt() {
+    let test = |xl, xu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let y = x.clone().sqrt();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(interval_to_hex_string(&y), out_hex);
+
+        let y_alt = (&x).sqrt();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+
+        let mut y_alt = x.clone();
+        y_alt.sqrt_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x1.0#1", "0x1.0#1", "[1.0, 1.0]", "[0x1.0#1, 0x1.0#1]");
+    test("0x1.0#1", "0x2.0#1", "[1.0, 2.0]", "[0x1.0#1, 0x2.0#1]");
+    test(
+        "0x0.554#10",
+        "0x0.556#10",
+        "[0.57617, 0.57812]",
+        "[0x0.938#10, 0x0.940#10]",
+    );
+    test("0x0.0", "0x4.0#1", "[0.0, 2.0]", "[0x0.0, 0x2.0#1]");
+    test(
+        "-0x0.0",
+        "Infinity",
+        "[-0.0, Infinity]",
+        "[-0x0.0, Infinity]",
+    );
+}
+
+#[test]
+fn test_sqrt_prec() {
+    let test = |xl, xu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+
+        let y = x.clone().sqrt_prec(prec);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(interval_to_hex_string(&y), out_hex);
+
+        let y_alt = x.sqrt_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), out_hex);
+    };
+    test("0x1.0#1", "0x2.0#1", 1, "[1.0, 2.0]", "[0x1.0#1, 0x2.0#1]");
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        10,
+        "[1.0000, 1.4160]",
+        "[0x1.000#10, 0x1.6a8#10]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        100,
+        "[1.2247448713915890490986420373516, 1.5811388300841896659994467722173]",
+        "[0x1.3988e1409212e7d0321914320#100, 0x1.94c583ada5b529204a2bc830e#100]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn sqrt_fail() {
+    (-Interval::ONE).sqrt();
+}
+
+#[test]
+fn sqrt_prec_properties() {
+    interval_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        if *x.lower() < 0u32 {
+            return;
+        }
+        let y = x.clone().sqrt_prec(prec);
+        assert!(y.is_valid());
+        let y_alt = x.sqrt_prec_ref(prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        for a in interval_sample_floats(&x) {
+            assert!(interval_may_contain(&y, |rm| a
+                .sqrt_prec_round_ref(prec + 10, rm)
+                .0));
+        }
+    });
+}
+
+#[test]
+fn sqrt_properties() {
+    interval_gen_var_2().test_properties(|x| {
+        let y = x.clone().sqrt();
+        assert!(y.is_valid());
+        let y_alt = (&x).sqrt();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        let mut y_alt = x.clone();
+        y_alt.sqrt_assign();
+        assert!(y_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        let y_alt = x.sqrt_prec_ref(x.significant_bits());
+        assert_eq!(interval_to_hex_string(&y_alt), interval_to_hex_string(&y));
+        for a in interval_sample_floats(&x) {
+            assert!(interval_may_contain(&y, |rm| a
+                .sqrt_prec_round_ref(100, rm)
+                .0));
+        }
+    });
+}
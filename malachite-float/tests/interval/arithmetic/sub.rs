@@ -0,0 +1,222 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{
+    interval_sample_points, interval_to_hex_string, parse_interval_hex,
+};
+use malachite_q::Rational;
+
+#[test]
+fn test_sub() {
+    let test = |xl, xu, yl, yu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let diff = x.clone() - y.clone();
+        assert!(diff.is_valid());
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(interval_to_hex_string(&diff), out_hex);
+
+        let diff_alt = x.clone() - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&diff_alt), out_hex);
+        let diff_alt = &x - y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&diff_alt), out_hex);
+        let diff_alt = &x - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&diff_alt), out_hex);
+
+        let mut diff_alt = x.clone();
+        diff_alt -= y.clone();
+        assert!(diff_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&diff_alt), out_hex);
+        let mut diff_alt = x.clone();
+        diff_alt -= &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&diff_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "[-0.0, 0.0]",
+        "[-0x0.0, 0x0.0]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        "[0.66602, 1.6680]",
+        "[0x0.aa8#10, 0x1.ab0#10]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "-0x2.0#1",
+        "0x1.0#1",
+        "[0.50, 5.0]",
+        "[0x0.8#3, 0x5.0#3]",
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        "0x1.8#2",
+        "0x2.8#3",
+        "[-1.5, Infinity]",
+        "[-0x1.8#3, Infinity]",
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "[-Infinity, 0.0]",
+        "[-Infinity, 0x0.0]",
+    );
+    test(
+        "0x0.0",
+        "0x0.0",
+        "-0x0.0",
+        "-0x0.0",
+        "[0.0, 0.0]",
+        "[0x0.0, 0x0.0]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        "[0.50, 1.0]",
+        "[0x0.8#1, 0x1.0#1]",
+    );
+}
+
+#[test]
+fn test_sub_prec() {
+    let test = |xl, xu, yl, yu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+
+        let diff = x.clone().sub_prec(y.clone(), prec);
+        assert!(diff.is_valid());
+        assert_eq!(diff.to_string(), out);
+        assert_eq!(interval_to_hex_string(&diff), out_hex);
+
+        let diff_alt = x.sub_prec_ref_ref(&y, prec);
+        assert!(diff_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&diff_alt), out_hex);
+    };
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        1,
+        "[-0.0, 0.0]",
+        "[-0x0.0, 0x0.0]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        3,
+        "[0.62, 1.8]",
+        "[0x0.a#3, 0x1.c#3]",
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x0.554#10",
+        "0x0.556#10",
+        20,
+        "[0.66650391, 1.6669922]",
+        "[0x0.aaa00#20, 0x1.aac00#20]",
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0E-50#1",
+        "0x1.0E-50#1",
+        10,
+        "[0.99902, 1.0000]",
+        "[0x0.ffc#10, 0x1.000#10]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_prec_fail() {
+    Interval::ONE.sub_prec(Interval::ONE, 0);
+}
+
+#[test]
+fn sub_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in [1, 10, 100] {
+            let diff = x.clone().sub_prec(y.clone(), prec);
+            assert!(diff.is_valid());
+            let diff_alt = x.sub_prec_ref_ref(&y, prec);
+            assert!(diff_alt.is_valid());
+            assert_eq!(
+                interval_to_hex_string(&diff_alt),
+                interval_to_hex_string(&diff)
+            );
+            let diff_alt = -y.sub_prec_ref_ref(&x, prec);
+            assert_eq!(
+                interval_to_hex_string(&diff_alt),
+                interval_to_hex_string(&diff)
+            );
+            for a in interval_sample_points(&x) {
+                for b in interval_sample_points(&y) {
+                    assert!(diff.contains_rational(&(&a - &b)));
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn sub_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let diff = x.clone() - y.clone();
+        assert!(diff.is_valid());
+        let diff_alt = &x - &y;
+        assert!(diff_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&diff_alt),
+            interval_to_hex_string(&diff)
+        );
+        let diff_alt = x.sub_prec_ref_ref(&y, max(x.significant_bits(), y.significant_bits()));
+        assert_eq!(
+            interval_to_hex_string(&diff_alt),
+            interval_to_hex_string(&diff)
+        );
+        assert_eq!(-(&y - &x), diff);
+        assert_eq!(&x + -&y, diff);
+        for a in interval_sample_points(&x) {
+            for b in interval_sample_points(&y) {
+                assert!(diff.contains_rational(&(&a - &b)));
+            }
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(&x - &Interval::ZERO, x);
+        assert_eq!(&Interval::ZERO - &x, -&x);
+        assert!((&x - &x).contains_rational(&Rational::ZERO));
+    });
+}
@@ -0,0 +1,38 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_float::interval::Interval;
+use malachite_float::test_util::interval::interval_to_hex_string;
+
+#[test]
+fn test_zero() {
+    let zero = Interval::ZERO;
+    assert!(zero.is_valid());
+    assert_eq!(zero.to_string(), "[0.0, 0.0]");
+    assert_eq!(interval_to_hex_string(&zero), "[0x0.0, 0x0.0]");
+    assert!(zero.is_point());
+}
+
+#[test]
+fn test_one() {
+    let one = Interval::ONE;
+    assert!(one.is_valid());
+    assert_eq!(one.to_string(), "[1.0, 1.0]");
+    assert_eq!(interval_to_hex_string(&one), "[0x1.0#1, 0x1.0#1]");
+    assert!(one.is_point());
+}
+
+#[test]
+fn test_entire() {
+    let entire = Interval::ENTIRE;
+    assert!(entire.is_valid());
+    assert_eq!(entire.to_string(), "[-Infinity, Infinity]");
+    assert_eq!(interval_to_hex_string(&entire), "[-Infinity, Infinity]");
+    assert!(!entire.is_bounded());
+}
@@ -0,0 +1,193 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, One};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_float::Float;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{float_pair_gen, interval_gen};
+use malachite_float::test_util::interval::parse_interval_hex;
+use malachite_q::Rational;
+
+#[test]
+fn test_new() {
+    let test = |xl, xu, out: &str| {
+        let lower = parse_hex_string(xl);
+        let upper = parse_hex_string(xu);
+        let x = Interval::new(lower.clone(), upper.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(x.lower()), xl);
+        assert_eq!(to_hex_string(x.upper()), xu);
+        let (lower_alt, upper_alt) = x.into_endpoints();
+        assert_eq!(to_hex_string(&lower_alt), xl);
+        assert_eq!(to_hex_string(&upper_alt), xu);
+    };
+    test("0x0.0", "0x0.0", "[0.0, 0.0]");
+    test("-0x0.0", "0x0.0", "[-0.0, 0.0]");
+    test("0x0.0", "-0x0.0", "[0.0, -0.0]");
+    test("0x1.8#2", "0x2.8#3", "[1.5, 2.5]");
+    test("-Infinity", "0x1.0#1", "[-Infinity, 1.0]");
+    test("0x1.0#1", "Infinity", "[1.0, Infinity]");
+    test("-Infinity", "Infinity", "[-Infinity, Infinity]");
+}
+
+#[test]
+#[should_panic]
+fn new_fail_1() {
+    Interval::new(Float::NAN, Float::ONE);
+}
+
+#[test]
+#[should_panic]
+fn new_fail_2() {
+    Interval::new(Float::ONE, Float::NAN);
+}
+
+#[test]
+#[should_panic]
+fn new_fail_3() {
+    Interval::new(Float::from(2), Float::ONE);
+}
+
+#[test]
+#[should_panic]
+fn new_fail_4() {
+    Interval::new(Float::INFINITY, Float::INFINITY);
+}
+
+#[test]
+#[should_panic]
+fn new_fail_5() {
+    Interval::new(Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+}
+
+#[test]
+fn new_properties() {
+    float_pair_gen().test_properties(|(lower, upper)| {
+        if lower.is_nan()
+            || upper.is_nan()
+            || lower > upper
+            || lower == Float::INFINITY
+            || upper == Float::NEGATIVE_INFINITY
+        {
+            return;
+        }
+        let x = Interval::new(lower.clone(), upper.clone());
+        assert!(x.is_valid());
+        assert_eq!(to_hex_string(x.lower()), to_hex_string(&lower));
+        assert_eq!(to_hex_string(x.upper()), to_hex_string(&upper));
+        assert_eq!(x.is_point(), lower == upper);
+        assert_eq!(x.is_bounded(), lower.is_finite() && upper.is_finite());
+    });
+}
+
+#[test]
+fn test_is_point() {
+    let test = |xl, xu, out| {
+        assert_eq!(parse_interval_hex(xl, xu).is_point(), out);
+    };
+    test("0x0.0", "0x0.0", true);
+    test("-0x0.0", "0x0.0", true);
+    test("0x1.0#1", "0x1.000#10", true);
+    test("0x1.8#2", "0x2.8#3", false);
+    test("-Infinity", "Infinity", false);
+}
+
+#[test]
+fn test_is_bounded() {
+    let test = |xl, xu, out| {
+        assert_eq!(parse_interval_hex(xl, xu).is_bounded(), out);
+    };
+    test("0x0.0", "0x0.0", true);
+    test("0x1.8#2", "0x2.8#3", true);
+    test("-Infinity", "0x1.0#1", false);
+    test("0x1.0#1", "Infinity", false);
+    test("-Infinity", "Infinity", false);
+}
+
+#[test]
+fn test_width() {
+    let test = |xl, xu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let width = x.width();
+        assert!(width.is_valid());
+        assert_eq!(width.to_string(), out);
+        assert_eq!(to_hex_string(&width), out_hex);
+    };
+    test("0x0.0", "0x0.0", "0.0", "0x0.0");
+    test("0x1.8#2", "0x2.8#3", "1.0", "0x1.0#3");
+    test("0x0.554#10", "0x0.556#10", "0.00048828", "0x0.00200#10");
+    test("0x1.0#1", "0x3.e8#10", "2.9062", "0x2.e8#10");
+    test("0x1.0#1", "Infinity", "Infinity", "Infinity");
+}
+
+#[test]
+fn test_width_prec() {
+    let test = |xl, xu, prec, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let width = x.width_prec(prec);
+        assert!(width.is_valid());
+        assert_eq!(width.to_string(), out);
+        assert_eq!(to_hex_string(&width), out_hex);
+    };
+    test("0x1.8#2", "0x2.8#3", 1, "1.0", "0x1.0#1");
+    test("0x1.8#2", "0x3e8.0#10", 2, "1.0e3", "0x4.0E+2#2");
+    test("0x1.8#2", "0x3e8.0#10", 20, "998.50000", "0x3e6.800#20");
+    test("0x0.554#10", "0x0.556#10", 5, "0.000488", "0x0.0020#5");
+}
+
+#[test]
+fn width_properties() {
+    interval_gen().test_properties(|x| {
+        let width = x.width();
+        assert!(width.is_valid());
+        assert!(width >= 0u32);
+        assert_eq!(width == 0u32, x.is_point());
+        assert_eq!(width.is_finite(), x.is_bounded());
+        if x.is_bounded() {
+            let exact = Rational::exact_from(x.upper()) - Rational::exact_from(x.lower());
+            assert!(width >= exact);
+            for prec in [1, 10, 100] {
+                assert!(x.width_prec(prec) >= exact);
+            }
+        }
+    });
+}
+
+#[test]
+fn test_midpoint() {
+    let test = |xl, xu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let midpoint = x.midpoint();
+        assert!(midpoint.is_valid());
+        assert_eq!(midpoint.to_string(), out);
+        assert_eq!(to_hex_string(&midpoint), out_hex);
+    };
+    test("0x0.0", "0x0.0", "0.0", "0x0.0");
+    test("0x1.8#2", "0x2.8#3", "2.0", "0x2.0#3");
+    test("0x0.554#10", "0x0.556#10", "0.33301", "0x0.554#10");
+    test("-0x2.0#1", "0x1.0#1", "-0.50", "-0x0.8#1");
+    test("0x1.0#1", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "0x1.0#1", "-Infinity", "-Infinity");
+    test("-Infinity", "Infinity", "0.0", "0x0.0");
+}
+
+#[test]
+fn midpoint_properties() {
+    interval_gen().test_properties(|x| {
+        let midpoint = x.midpoint();
+        assert!(midpoint.is_valid());
+        assert!(x.contains(&midpoint));
+        if x.is_point() {
+            assert_eq!(midpoint, *x.lower());
+        }
+        assert_eq!(-&midpoint, (-&x).midpoint());
+    });
+}
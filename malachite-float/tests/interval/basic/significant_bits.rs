@@ -0,0 +1,40 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::interval_gen;
+use malachite_float::test_util::interval::parse_interval_hex;
+use std::cmp::max;
+
+#[test]
+fn test_significant_bits() {
+    let test = |xl, xu, out| {
+        assert_eq!(parse_interval_hex(xl, xu).significant_bits(), out);
+    };
+    test("0x0.0", "0x0.0", 1);
+    test("0x1.0#1", "0x1.0#1", 1);
+    test("0x1.8#2", "0x2.8#3", 3);
+    test("0x0.554#10", "Infinity", 10);
+    test("-Infinity", "Infinity", 1);
+    assert_eq!(Interval::ZERO.significant_bits(), 1);
+    assert_eq!(Interval::ONE.significant_bits(), 1);
+}
+
+#[test]
+fn significant_bits_properties() {
+    interval_gen().test_properties(|x| {
+        let bits = x.significant_bits();
+        assert_ne!(bits, 0);
+        assert_eq!(
+            bits,
+            max(x.lower().significant_bits(), x.upper().significant_bits())
+        );
+    });
+}
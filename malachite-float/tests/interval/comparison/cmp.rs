@@ -0,0 +1,107 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{interval_sample_points, parse_interval_hex};
+
+#[test]
+fn test_cmp() {
+    let test = |xl, xu, yl, yu, lt, le, gt, ge, eq| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+        assert_eq!(x.less_than(&y), lt);
+        assert_eq!(x.less_than_or_equal(&y), le);
+        assert_eq!(x.greater_than(&y), gt);
+        assert_eq!(x.greater_than_or_equal(&y), ge);
+        assert_eq!(x.equal(&y), eq);
+        assert_eq!(y.equal(&x), eq);
+    };
+    let t = Some(true);
+    let f = Some(false);
+    test("0x1.0#1", "0x1.0#1", "0x1.0#1", "0x1.0#1", f, t, f, t, t);
+    test("0x1.0#1", "0x1.0#1", "0x2.0#1", "0x2.0#1", t, t, f, f, f);
+    test("0x2.0#1", "0x2.0#1", "0x1.0#1", "0x1.0#1", f, f, t, t, f);
+    test(
+        "0x1.8#2", "0x2.8#3", "0x2.0#1", "0x2.0#1", None, None, None, None, None,
+    );
+    test(
+        "0x1.8#2", "0x2.8#3", "0x2.8#3", "0x4.0#1", None, t, f, None, None,
+    );
+    test("0x1.8#2", "0x2.8#3", "0x3.0#2", "0x4.0#1", t, t, f, f, f);
+    test("-0x0.0", "-0x0.0", "0x0.0", "0x0.0", f, t, f, t, t);
+    test("-Infinity", "0x1.0#1", "0x2.0#1", "Infinity", t, t, f, f, f);
+    test(
+        "-Infinity",
+        "Infinity",
+        "0x1.0#1",
+        "0x1.0#1",
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+}
+
+#[test]
+fn cmp_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let lt = x.less_than(&y);
+        let le = x.less_than_or_equal(&y);
+        let gt = x.greater_than(&y);
+        let ge = x.greater_than_or_equal(&y);
+        let eq = x.equal(&y);
+        assert_eq!(y.greater_than(&x), lt);
+        assert_eq!(y.greater_than_or_equal(&x), le);
+        assert_eq!(y.less_than(&x), gt);
+        assert_eq!(y.less_than_or_equal(&x), ge);
+        assert_eq!(y.equal(&x), eq);
+        assert_eq!((-&y).less_than(&-&x), lt);
+        if lt == Some(true) {
+            assert_eq!(le, Some(true));
+            assert_eq!(eq, Some(false));
+        }
+        if le == Some(false) {
+            assert_eq!(lt, Some(false));
+        }
+        if eq == Some(false) {
+            assert!(x.intersection(&y).is_none());
+        }
+        for a in interval_sample_points(&x) {
+            for b in interval_sample_points(&y) {
+                if let Some(lt) = lt {
+                    assert_eq!(a < b, lt);
+                }
+                if let Some(le) = le {
+                    assert_eq!(a <= b, le);
+                }
+                if let Some(gt) = gt {
+                    assert_eq!(a > b, gt);
+                }
+                if let Some(ge) = ge {
+                    assert_eq!(a >= b, ge);
+                }
+                if let Some(eq) = eq {
+                    assert_eq!(a == b, eq);
+                }
+            }
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(
+            x.less_than(&x),
+            if x.is_point() { Some(false) } else { None }
+        );
+        assert_eq!(
+            x.less_than_or_equal(&x),
+            if x.is_point() { Some(true) } else { None }
+        );
+        assert_eq!(x.equal(&x), if x.is_point() { Some(true) } else { None });
+    });
+}
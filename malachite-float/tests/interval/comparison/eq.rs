@@ -0,0 +1,43 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::parse_interval_hex;
+
+#[test]
+fn test_eq() {
+    let test = |xl, xu, yl, yu, out| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+        assert_eq!(x == y, out);
+        assert_eq!(y == x, out);
+    };
+    test("0x1.0#1", "0x1.0#1", "0x1.0#1", "0x1.0#1", true);
+    test("0x1.0#1", "0x1.0#1", "0x1.000#10", "0x1.0#2", true);
+    test("-0x0.0", "0x0.0", "0x0.0", "-0x0.0", true);
+    test("0x1.8#2", "0x2.8#3", "0x1.8#2", "0x2.0#1", false);
+    test("0x1.8#2", "0x2.8#3", "0x1.0#1", "0x2.8#3", false);
+    test("-Infinity", "Infinity", "-Infinity", "Infinity", true);
+    test("-Infinity", "0x1.0#1", "0x1.0#1", "Infinity", false);
+}
+
+#[test]
+fn eq_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let eq = x == y;
+        assert_eq!(y == x, eq);
+        assert_eq!(eq, x.lower() == y.lower() && x.upper() == y.upper());
+        assert_eq!(eq, x.contains_interval(&y) && y.contains_interval(&x));
+        assert_eq!(-&x == -&y, eq);
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(x, x);
+        assert_eq!(x.clone(), x);
+    });
+}
@@ -0,0 +1,67 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+use malachite_float::Float;
+use malachite_float::interval::Interval;
+use malachite_float::interval::conversion::from_float::IntervalFromFloatError;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::test_util::interval::interval_to_hex_string;
+
+#[test]
+fn test_try_from_float() {
+    let test = |s_hex, out: &str| {
+        let x = parse_hex_string(s_hex);
+        let interval = Interval::try_from(x.clone()).unwrap();
+        assert!(interval.is_valid());
+        assert_eq!(interval.to_string(), out);
+        assert_eq!(to_hex_string(interval.lower()), s_hex);
+        assert_eq!(to_hex_string(interval.upper()), s_hex);
+        let interval_alt = Interval::try_from(&x).unwrap();
+        assert!(interval_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&interval_alt),
+            interval_to_hex_string(&interval)
+        );
+    };
+    test("0x0.0", "[0.0, 0.0]");
+    test("-0x0.0", "[-0.0, -0.0]");
+    test("0x1.0#1", "[1.0, 1.0]");
+    test("-0x1.8#2", "[-1.5, -1.5]");
+    test(
+        "0x0.555555555555554#57",
+        "[0.3333333333333333322, 0.3333333333333333322]",
+    );
+
+    for x in [Float::NAN, Float::INFINITY, Float::NEGATIVE_INFINITY] {
+        assert_eq!(Interval::try_from(&x), Err(IntervalFromFloatError));
+        assert_eq!(Interval::try_from(x), Err(IntervalFromFloatError));
+    }
+}
+
+#[test]
+fn try_from_float_properties() {
+    float_gen().test_properties(|x| {
+        let interval = Interval::try_from(&x);
+        assert_eq!(interval.is_ok(), x.is_finite());
+        let interval_alt = Interval::try_from(x.clone());
+        assert_eq!(interval_alt.is_ok(), x.is_finite());
+        if let Ok(interval) = interval {
+            assert!(interval.is_valid());
+            assert!(interval.is_point());
+            assert!(interval.contains(&x));
+            assert_eq!(to_hex_string(interval.lower()), to_hex_string(&x));
+            assert_eq!(to_hex_string(interval.upper()), to_hex_string(&x));
+            assert_eq!(
+                interval_to_hex_string(&interval_alt.unwrap()),
+                interval_to_hex_string(&interval)
+            );
+        }
+    });
+}
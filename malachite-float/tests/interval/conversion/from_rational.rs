@@ -0,0 +1,101 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::*;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_float::Float;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::interval::interval_to_hex_string;
+use malachite_q::Rational;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_3};
+use std::str::FromStr;
+
+#[test]
+fn test_from_rational_prec() {
+    let test = |s, prec, out: &str, out_hex: &str| {
+        let x = Rational::from_str(s).unwrap();
+        let interval = Interval::from_rational_prec(x.clone(), prec);
+        assert!(interval.is_valid());
+        assert_eq!(interval.to_string(), out);
+        assert_eq!(interval_to_hex_string(&interval), out_hex);
+        let interval_alt = Interval::from_rational_prec_ref(&x, prec);
+        assert!(interval_alt.is_valid());
+        assert_eq!(interval_to_hex_string(&interval_alt), out_hex);
+        assert!(interval.contains_rational(&x));
+    };
+    test("0", 1, "[0.0, 0.0]", "[0x0.0, 0x0.0]");
+    test("0", 10, "[0.0, 0.0]", "[0x0.0, 0x0.0]");
+    test("1", 1, "[1.0, 1.0]", "[0x1.0#1, 0x1.0#1]");
+    test("1/3", 1, "[0.25, 0.50]", "[0x0.4#1, 0x0.8#1]");
+    test("1/3", 10, "[0.33301, 0.33350]", "[0x0.554#10, 0x0.556#10]");
+    test(
+        "-1/3",
+        10,
+        "[-0.33350, -0.33301]",
+        "[-0x0.556#10, -0x0.554#10]",
+    );
+    test(
+        "22/7",
+        100,
+        "[3.1428571428571428571428571428553, 3.1428571428571428571428571428585]",
+        "[0x3.2492492492492492492492490#100, 0x3.2492492492492492492492494#100]",
+    );
+    test("3/8", 2, "[0.38, 0.38]", "[0x0.6#2, 0x0.6#2]");
+    test(
+        "1000000000000",
+        10,
+        "[9.9965e11, 1.0007e12]",
+        "[0xe.8cE+9#10, 0xe.90E+9#10]",
+    );
+    test(
+        "-1/1000000000000",
+        10,
+        "[-1.0001e-12, -9.9831e-13]",
+        "[-0x1.198E-10#10, -0x1.190E-10#10]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn from_rational_prec_fail() {
+    Interval::from_rational_prec(Rational::from(1), 0);
+}
+
+#[test]
+fn from_rational_prec_properties() {
+    rational_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        let interval = Interval::from_rational_prec(x.clone(), prec);
+        assert!(interval.is_valid());
+        let interval_alt = Interval::from_rational_prec_ref(&x, prec);
+        assert!(interval_alt.is_valid());
+        assert_eq!(
+            interval_to_hex_string(&interval_alt),
+            interval_to_hex_string(&interval)
+        );
+        assert!(interval.contains_rational(&x));
+        let (lower, upper) = interval.clone().into_endpoints();
+        assert!(lower.get_prec().is_none_or(|p| p == prec));
+        assert!(upper.get_prec().is_none_or(|p| p == prec));
+        let (lower_alt, upper_alt) = Interval::from_rational_prec(-&x, prec).into_endpoints();
+        assert_eq!(lower, -upper_alt);
+        assert_eq!(upper, -lower_alt);
+        // Each endpoint is the closest Float of its precision on its side of x
+        let (lower_alt, o) = Float::from_rational_prec_round_ref(&x, prec, Floor);
+        assert_eq!(lower, lower_alt);
+        assert_eq!(interval.is_point(), o == Equal);
+        if interval.is_point() {
+            assert_eq!(Rational::exact_from(&lower), x);
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        let interval = Interval::from_rational_prec_ref(&x, 100);
+        assert!(interval.contains_rational(&x));
+    });
+}
@@ -0,0 +1,41 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::test_util::generators::interval_gen;
+use malachite_float::test_util::interval::parse_interval_hex;
+
+#[test]
+fn test_to_string() {
+    let test = |xl, xu, out| {
+        let x = parse_interval_hex(xl, xu);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(format!("{x:?}"), out);
+    };
+    test("0x0.0", "0x0.0", "[0.0, 0.0]");
+    test("-0x0.0", "0x0.0", "[-0.0, 0.0]");
+    test("0x1.0#1", "0x1.0#1", "[1.0, 1.0]");
+    test("0x1.8#2", "0x2.8#3", "[1.5, 2.5]");
+    test("0x0.554#10", "0x0.556#10", "[0.33301, 0.33350]");
+    test("-Infinity", "0x1.0#1", "[-Infinity, 1.0]");
+    test("0x1.0#1", "Infinity", "[1.0, Infinity]");
+    test("-Infinity", "Infinity", "[-Infinity, Infinity]");
+    test(
+        "0x4.0E+268435455#1",
+        "Infinity",
+        "[1.0e323228496, Infinity]",
+    );
+}
+
+#[test]
+fn to_string_properties() {
+    interval_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(format!("{x:?}"), s);
+        assert_eq!(s, format!("[{}, {}]", x.lower(), x.upper()));
+    });
+}
@@ -0,0 +1,111 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_float::Float;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::generators::{interval_float_pair_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{interval_sample_points, parse_interval_hex};
+use malachite_q::Rational;
+
+#[test]
+fn test_contains() {
+    let test = |xl, xu, y, out| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_hex_string(y);
+        assert_eq!(x.contains(&y), out);
+        if y.is_finite() {
+            assert_eq!(x.contains_rational(&Rational::exact_from(&y)), out);
+        }
+    };
+    test("0x1.8#2", "0x2.8#3", "0x2.0#1", true);
+    test("0x1.8#2", "0x2.8#3", "0x1.800#10", true);
+    test("0x1.8#2", "0x2.8#3", "0x2.8#3", true);
+    test("0x1.8#2", "0x2.8#3", "0x1.0#1", false);
+    test("0x1.8#2", "0x2.8#3", "0x3.0#2", false);
+    test("0x1.8#2", "0x2.8#3", "NaN", false);
+    test("0x0.0", "0x0.0", "-0x0.0", true);
+    test("-0x0.0", "0x1.0#1", "0x0.0", true);
+    test("-Infinity", "0x1.0#1", "-Infinity", true);
+    test("-Infinity", "0x1.0#1", "Infinity", false);
+    test("-Infinity", "Infinity", "Infinity", true);
+    test("-Infinity", "Infinity", "NaN", false);
+}
+
+#[test]
+fn test_contains_rational() {
+    let test = |xl, xu, y, out| {
+        let x = parse_interval_hex(xl, xu);
+        assert_eq!(x.contains_rational(&Rational::exact_from(y)), out);
+    };
+    test("0x0.554#10", "0x0.556#10", 1.0 / 3.0, true);
+    test("0x0.554#10", "0x0.556#10", 0.5, false);
+    test("-Infinity", "0x1.0#1", -1.0e100, true);
+    test("0x0.0", "Infinity", -1.0e-100, false);
+    assert!(
+        Interval::from_rational_prec(Rational::from_signeds(1, 3), 10)
+            .contains_rational(&Rational::from_signeds(1, 3))
+    );
+    assert!(
+        !Interval::from_rational_prec(Rational::from_signeds(1, 3), 10)
+            .contains_rational(&Rational::from_signeds(1, 2))
+    );
+}
+
+#[test]
+fn test_contains_interval() {
+    let test = |xl, xu, yl, yu, out| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+        assert_eq!(x.contains_interval(&y), out);
+    };
+    test("0x1.8#2", "0x2.8#3", "0x1.8#2", "0x2.8#3", true);
+    test("0x1.8#2", "0x2.8#3", "0x2.0#1", "0x2.0#1", true);
+    test("0x1.8#2", "0x2.8#3", "0x1.0#1", "0x2.0#1", false);
+    test("0x1.8#2", "0x2.8#3", "0x2.0#1", "0x3.0#2", false);
+    test("-Infinity", "Infinity", "0x2.0#1", "Infinity", true);
+    test("0x2.0#1", "Infinity", "-Infinity", "Infinity", false);
+}
+
+#[test]
+fn contains_properties() {
+    interval_float_pair_gen().test_properties(|(x, y)| {
+        let contains = x.contains(&y);
+        assert_eq!(contains, !y.is_nan() && *x.lower() <= y && y <= *x.upper());
+        assert_eq!((-&x).contains(&-&y), contains);
+        if y.is_finite() {
+            assert_eq!(x.contains_rational(&Rational::exact_from(&y)), contains);
+            assert_eq!(
+                x.contains_interval(&Interval::try_from(&y).unwrap()),
+                contains
+            );
+        }
+        assert!(!x.contains(&Float::NAN));
+        assert!(Interval::ENTIRE.contains(&Float::ZERO));
+        assert_eq!(x.contains(&Float::INFINITY), *x.upper() == Float::INFINITY);
+        assert_eq!(
+            x.contains(&Float::NEGATIVE_INFINITY),
+            *x.lower() == Float::NEGATIVE_INFINITY
+        );
+    });
+
+    interval_pair_gen().test_properties(|(x, y)| {
+        let contains = x.contains_interval(&y);
+        assert_eq!(contains, x.contains(y.lower()) && x.contains(y.upper()));
+        assert_eq!(contains, x.hull(&y) == x);
+        if contains {
+            for a in interval_sample_points(&y) {
+                assert!(x.contains_rational(&a));
+            }
+        }
+        assert!(x.contains_interval(&x));
+        assert!(Interval::ENTIRE.contains_interval(&x));
+    });
+}
@@ -0,0 +1,99 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{interval_to_hex_string, parse_interval_hex};
+
+#[test]
+fn test_hull() {
+    let test = |xl, xu, yl, yu, out: &str, out_hex: &str| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+        let hull = x.hull(&y);
+        assert!(hull.is_valid());
+        assert_eq!(hull.to_string(), out);
+        assert_eq!(interval_to_hex_string(&hull), out_hex);
+    };
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "0x2.0#1",
+        "0x2.0#1",
+        "[1.5, 2.5]",
+        "[0x1.8#2, 0x2.8#3]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "0x3.0#2",
+        "0x4.0#1",
+        "[1.5, 4.0]",
+        "[0x1.8#2, 0x4.0#1]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "-0x1.0#1",
+        "0x1.0#1",
+        "[-1.0, 2.5]",
+        "[-0x1.0#1, 0x2.8#3]",
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "0x0.554#10",
+        "Infinity",
+        "[0.33301, Infinity]",
+        "[0x0.554#10, Infinity]",
+    );
+    test(
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        "-0x0.0",
+        "[-0.0, 0.0]",
+        "[-0x0.0, 0x0.0]",
+    );
+}
+
+#[test]
+fn hull_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let hull = x.hull(&y);
+        assert!(hull.is_valid());
+        assert!(hull.contains_interval(&x));
+        assert!(hull.contains_interval(&y));
+        assert_eq!(y.hull(&x), hull);
+        assert_eq!((-&x).hull(&-&y), -&hull);
+        assert_eq!(
+            hull.lower(),
+            if x.lower() <= y.lower() {
+                x.lower()
+            } else {
+                y.lower()
+            }
+        );
+        assert_eq!(
+            hull.upper(),
+            if x.upper() >= y.upper() {
+                x.upper()
+            } else {
+                y.upper()
+            }
+        );
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(
+            interval_to_hex_string(&x.hull(&x)),
+            interval_to_hex_string(&x)
+        );
+        assert_eq!(x.hull(&Interval::ENTIRE), Interval::ENTIRE);
+    });
+}
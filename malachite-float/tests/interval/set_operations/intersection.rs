@@ -0,0 +1,96 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::interval::Interval;
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::test_util::interval::{interval_to_hex_string, parse_interval_hex};
+
+#[test]
+fn test_intersection() {
+    let test = |xl, xu, yl, yu, out: Option<&str>, out_hex: Option<&str>| {
+        let x = parse_interval_hex(xl, xu);
+        let y = parse_interval_hex(yl, yu);
+        let intersection = x.intersection(&y);
+        if let Some(intersection) = &intersection {
+            assert!(intersection.is_valid());
+        }
+        assert_eq!(
+            intersection.as_ref().map(ToString::to_string).as_deref(),
+            out
+        );
+        assert_eq!(
+            intersection.as_ref().map(interval_to_hex_string).as_deref(),
+            out_hex
+        );
+    };
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "0x2.0#1",
+        "0x2.0#1",
+        Some("[2.0, 2.0]"),
+        Some("[0x2.0#1, 0x2.0#1]"),
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "0x2.0#1",
+        "0x4.0#1",
+        Some("[2.0, 2.5]"),
+        Some("[0x2.0#1, 0x2.8#3]"),
+    );
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "0x2.8#3",
+        "0x4.0#1",
+        Some("[2.5, 2.5]"),
+        Some("[0x2.8#3, 0x2.8#3]"),
+    );
+    test("0x1.8#2", "0x2.8#3", "0x3.0#2", "0x4.0#1", None, None);
+    test(
+        "0x1.8#2",
+        "0x2.8#3",
+        "-Infinity",
+        "Infinity",
+        Some("[1.5, 2.5]"),
+        Some("[0x1.8#2, 0x2.8#3]"),
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        "0x0.554#10",
+        "Infinity",
+        Some("[0.33301, 1.0]"),
+        Some("[0x0.554#10, 0x1.0#1]"),
+    );
+}
+
+#[test]
+fn intersection_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let intersection = x.intersection(&y);
+        assert_eq!(y.intersection(&x), intersection);
+        assert_eq!((-&x).intersection(&-&y), intersection.as_ref().map(|z| -z));
+        assert_eq!(intersection.is_none(), x.equal(&y) == Some(false));
+        if let Some(intersection) = intersection {
+            assert!(intersection.is_valid());
+            assert!(x.contains_interval(&intersection));
+            assert!(y.contains_interval(&intersection));
+            assert!(x.hull(&y).contains_interval(&intersection));
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(
+            x.intersection(&x).as_ref().map(interval_to_hex_string),
+            Some(interval_to_hex_string(&x))
+        );
+        assert_eq!(x.intersection(&Interval::ENTIRE), Some(x));
+    });
+}
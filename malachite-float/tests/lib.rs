@@ -61,23 +61,78 @@
 #[macro_use]
 extern crate malachite_base;
 
+pub mod ball {
+    pub mod arithmetic {
+        pub mod add;
+        pub mod cos;
+        pub mod div;
+        pub mod exp;
+        pub mod ln;
+        pub mod mul;
+        pub mod neg;
+        pub mod pow;
+        pub mod sin;
+        pub mod sqrt;
+        pub mod sub;
+    }
+    pub mod basic {
+        pub mod constants;
+        pub mod get_and_set;
+        pub mod significant_bits;
+    }
+    pub mod conversion {
+        pub mod from_float;
+        pub mod from_interval;
+        pub mod from_rational;
+        pub mod to_interval;
+        pub mod to_string;
+        pub mod try_round;
+    }
+    pub mod set_operations {
+        pub mod contains;
+    }
+}
 pub mod float {
     pub mod arithmetic {
         pub mod abs;
+        pub mod acos;
+        pub mod acosh;
         pub mod add;
         pub mod add_mul;
         pub mod agm;
+        pub mod airy_ai;
+        pub mod asin;
+        pub mod asinh;
+        pub mod atan;
+        pub mod atan2;
+        pub mod atanh;
         pub mod average;
+        pub mod bessel_j0;
+        pub mod bessel_j1;
+        pub mod bessel_jn;
+        pub mod bessel_y0;
+        pub mod bessel_y1;
+        pub mod bessel_yn;
+        pub mod beta;
         pub mod cbrt;
+        pub mod cos;
+        pub mod cosh;
+        pub mod coth;
+        pub mod csch;
+        pub mod digamma;
         pub mod div;
+        pub mod erf;
+        pub mod erfc;
         pub mod exp;
         pub mod exp_x_minus_1;
         pub mod factorial;
         pub mod fractional_part;
+        pub mod gamma;
         pub mod hypot;
         pub mod is_power_of_2;
         pub mod ln;
         pub mod ln_1_plus_x;
+        pub mod ln_gamma;
         pub mod log_base;
         pub mod log_base_10;
         pub mod log_base_10_1_plus_x;
@@ -92,10 +147,12 @@ pub mod float {
         pub mod log_base_rational_base_1_plus_x;
         pub mod log_base_rational_float_base;
         pub mod log_base_rational_rational_base;
+        pub mod lower_incomplete_gamma;
         pub mod mul;
         pub mod mul_add_mul;
         pub mod mul_sub_mul;
         pub mod neg;
+        pub mod partition_number;
         pub mod positive_difference;
         pub mod pow;
         pub mod power_of_10;
@@ -108,15 +165,24 @@ pub mod float {
         pub mod rem;
         pub mod root;
         pub mod round_to_integer;
+        pub mod sech;
         pub mod shl;
         pub mod shl_round;
         pub mod shr;
         pub mod shr_round;
         pub mod sign;
+        pub mod sin;
+        pub mod sin_cos;
+        pub mod sinh;
+        pub mod sinh_cosh;
         pub mod sqrt;
         pub mod square;
         pub mod sub;
         pub mod sub_mul;
+        pub mod tan;
+        pub mod tanh;
+        pub mod upper_incomplete_gamma;
+        pub mod zeta;
     }
     pub mod basic {
         pub mod can_round;
@@ -159,10 +225,13 @@ pub mod float {
         pub mod partial_eq_rational;
     }
     pub mod constants {
+        pub mod apery;
+        pub mod catalan;
         pub mod cbrt_2;
         pub mod champernowne_constant;
         pub mod copeland_erdos_constant;
         pub mod e;
+        pub mod euler_gamma;
         pub mod gauss_constant;
         pub mod gelfond_schneider_constant;
         pub mod gelfonds_constant;
@@ -260,3 +329,35 @@ pub mod float {
         pub mod uniform_random_non_negative_floats_less_than_one;
     }
 }
+pub mod interval {
+    pub mod arithmetic {
+        pub mod add;
+        pub mod div;
+        pub mod exp;
+        pub mod ln;
+        pub mod mul;
+        pub mod neg;
+        pub mod pow;
+        pub mod sqrt;
+        pub mod sub;
+    }
+    pub mod basic {
+        pub mod constants;
+        pub mod get_and_set;
+        pub mod significant_bits;
+    }
+    pub mod comparison {
+        pub mod cmp;
+        pub mod eq;
+    }
+    pub mod conversion {
+        pub mod from_float;
+        pub mod from_rational;
+        pub mod to_string;
+    }
+    pub mod set_operations {
+        pub mod contains;
+        pub mod hull;
+        pub mod intersection;
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::bench::bucketers::triple_3_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen, integer_integer_unsigned_triple_gen_var_1,
+};
+use malachite_nz::test_util::integer::arithmetic::lucas_sequence::lucas_sequence_naive;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_lucas_sequence);
+    register_demo!(runner, demo_integer_mod_lucas_sequence);
+    register_bench!(runner, benchmark_integer_lucas_sequence_algorithms);
+    register_bench!(runner, benchmark_integer_mod_lucas_sequence);
+}
+
+fn demo_integer_lucas_sequence(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (p, q, n) in integer_integer_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Integer::lucas_sequence({}, {}, {}) = {:?}",
+            p,
+            q,
+            n,
+            Integer::lucas_sequence(&p, &q, n)
+        );
+    }
+}
+
+fn demo_integer_mod_lucas_sequence(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (p, q, m) in integer_integer_natural_triple_gen()
+        .get(gm, config)
+        .take(limit)
+    {
+        if m == 0u32 {
+            continue;
+        }
+        // Use m + 1 as the index, as in a Lucas probable-prime test.
+        let n = &m + Natural::ONE;
+        println!(
+            "Integer::mod_lucas_sequence({}, {}, {}, {}) = {:?}",
+            p,
+            q,
+            n,
+            m,
+            Integer::mod_lucas_sequence(&p, &q, &n, &m)
+        );
+    }
+}
+
+fn benchmark_integer_lucas_sequence_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer::lucas_sequence(&Integer, &Integer, u64)",
+        BenchmarkType::Algorithms,
+        integer_integer_unsigned_triple_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bucketer("n"),
+        &mut [
+            ("default", &mut |(p, q, n)| {
+                no_out!(Integer::lucas_sequence(&p, &q, n));
+            }),
+            ("naive", &mut |(p, q, n)| {
+                no_out!(lucas_sequence_naive(&p, &q, n));
+            }),
+        ],
+    );
+}
+
+fn benchmark_integer_mod_lucas_sequence(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer::mod_lucas_sequence(&Integer, &Integer, &Natural, &Natural)",
+        BenchmarkType::Single,
+        integer_integer_natural_triple_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(p, q, m)| {
+            if m != 0u32 {
+                no_out!(Integer::mod_lucas_sequence(
+                    &p,
+                    &q,
+                    &(&m + Natural::ONE),
+                    &m
+                ));
+            }
+        })],
+    );
+}
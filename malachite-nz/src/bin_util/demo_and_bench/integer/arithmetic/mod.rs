@@ -27,8 +27,10 @@ pub(crate) fn register(runner: &mut Runner) {
     divisible_by_power_of_2::register(runner);
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
+    euler_number::register(runner);
     extended_gcd::register(runner);
     kronecker_symbol::register(runner);
+    lucas_sequence::register(runner);
     mod_euclidean::register(runner);
     mod_op::register(runner);
     mod_power_of_2::register(runner);
@@ -74,8 +76,10 @@ mod divisible_by;
 mod divisible_by_power_of_2;
 mod eq_mod;
 mod eq_mod_power_of_2;
+mod euler_number;
 mod extended_gcd;
 mod kronecker_symbol;
+mod lucas_sequence;
 mod mod_euclidean;
 mod mod_op;
 mod mod_power_of_2;
@@ -0,0 +1,42 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::integer_bit_bucketer;
+use malachite_nz::test_util::generators::integer_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_is_prime);
+    register_bench!(runner, benchmark_integer_is_prime);
+}
+
+fn demo_integer_is_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in integer_gen().get(gm, config).take(limit) {
+        if n.is_prime() {
+            println!("{n} is prime");
+        } else {
+            println!("{n} is not prime");
+        }
+    }
+}
+
+fn benchmark_integer_is_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Integer.is_prime()",
+        BenchmarkType::Single,
+        integer_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &integer_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.is_prime()))],
+    );
+}
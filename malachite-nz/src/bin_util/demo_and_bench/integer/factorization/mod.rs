@@ -10,8 +10,10 @@ use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
     is_power::register(runner);
+    is_prime::register(runner);
     remove_power::register(runner);
 }
 
 mod is_power;
+mod is_prime;
 mod remove_power;
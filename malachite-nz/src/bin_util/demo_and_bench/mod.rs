@@ -47,8 +47,10 @@ macro_rules! natural_signed_single_arg_bench_with_trait {
 
 pub(crate) fn register(runner: &mut Runner) {
     integer::register(runner);
+    mod_natural::register(runner);
     natural::register(runner);
 }
 
 mod integer;
+mod mod_natural;
 mod natural;
@@ -0,0 +1,154 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{CoprimeWith, ModDiv};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::test_util::bench::bucketers::{
+    pair_1_mod_natural_modulus_bit_bucketer, triple_3_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::natural_triple_gen_var_3;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_mod_natural_div_assign);
+    register_demo!(runner, demo_mod_natural_div);
+    register_demo!(runner, demo_mod_natural_div_ref_ref);
+
+    register_bench!(runner, benchmark_mod_natural_div_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_mod_natural_div_library_comparison);
+    register_bench!(runner, benchmark_mod_natural_div_evaluation_strategy);
+}
+
+fn demo_mod_natural_div_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3()
+        .get(gm, config)
+        .filter(|(_, y, m)| y.coprime_with(m))
+        .take(limit)
+    {
+        let mut x = ModNatural::new(x, m);
+        let y = x.with_value(y);
+        let x_old = x.clone();
+        x /= &y;
+        println!("x := {x_old}; x /= {y}; x = {x}");
+    }
+}
+
+fn demo_mod_natural_div(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3()
+        .get(gm, config)
+        .filter(|(_, y, m)| y.coprime_with(m))
+        .take(limit)
+    {
+        let x = ModNatural::new(x, m);
+        let y = x.with_value(y);
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!("({x_old}) / ({y_old}) = {}", x / y);
+    }
+}
+
+fn demo_mod_natural_div_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3()
+        .get(gm, config)
+        .filter(|(_, y, m)| y.coprime_with(m))
+        .take(limit)
+    {
+        let x = ModNatural::new(x, m);
+        let y = x.with_value(y);
+        println!("&({x}) / &({y}) = {}", &x / &y);
+    }
+}
+
+fn benchmark_mod_natural_div_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural /= ModNatural",
+        BenchmarkType::EvaluationStrategy,
+        natural_triple_gen_var_3()
+            .get(gm, config)
+            .filter(|(_, y, m)| y.coprime_with(m))
+            .map(|(x, y, m)| {
+                let x = ModNatural::new(x, m);
+                let y = x.with_value(y);
+                (x, y)
+            }),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_mod_natural_modulus_bit_bucketer(),
+        &mut [
+            ("ModNatural /= ModNatural", &mut |(mut x, y)| x /= y),
+            ("ModNatural /= &ModNatural", &mut |(mut x, y)| x /= &y),
+        ],
+    );
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_mod_natural_div_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural / ModNatural",
+        BenchmarkType::LibraryComparison,
+        natural_triple_gen_var_3()
+            .get(gm, config)
+            .filter(|(_, y, m)| y.coprime_with(m)),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("ModNatural", &mut |(x, y, m)| {
+                let x = ModNatural::new(x, m);
+                let y = x.with_value(y);
+                no_out!(x / y);
+            }),
+            ("Natural.mod_div", &mut |(x, y, m)| no_out!(x.mod_div(y, m))),
+        ],
+    );
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_mod_natural_div_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural / ModNatural",
+        BenchmarkType::EvaluationStrategy,
+        natural_triple_gen_var_3()
+            .get(gm, config)
+            .filter(|(_, y, m)| y.coprime_with(m))
+            .map(|(x, y, m)| {
+                let x = ModNatural::new(x, m);
+                let y = x.with_value(y);
+                (x, y)
+            }),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_mod_natural_modulus_bit_bucketer(),
+        &mut [
+            ("ModNatural / ModNatural", &mut |(x, y)| no_out!(x / y)),
+            ("ModNatural / &ModNatural", &mut |(x, y)| no_out!(x / &y)),
+            ("&ModNatural / ModNatural", &mut |(x, y)| no_out!(&x / y)),
+            ("&ModNatural / &ModNatural", &mut |(x, y)| no_out!(&x / &y)),
+        ],
+    );
+}
@@ -0,0 +1,117 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModNeg, NegAssign};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::test_util::bench::bucketers::{
+    pair_1_mod_natural_modulus_bit_bucketer, pair_2_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::natural_pair_gen_var_11;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_mod_natural_neg_assign);
+    register_demo!(runner, demo_mod_natural_neg);
+    register_demo!(runner, demo_mod_natural_neg_ref);
+
+    register_bench!(runner, benchmark_mod_natural_neg_assign);
+    register_bench!(runner, benchmark_mod_natural_neg_library_comparison);
+    register_bench!(runner, benchmark_mod_natural_neg_evaluation_strategy);
+}
+
+fn demo_mod_natural_neg_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_11().get(gm, config).take(limit) {
+        let mut x = ModNatural::new(x, m);
+        let x_old = x.clone();
+        x.neg_assign();
+        println!("x := {x_old}; x.neg_assign(); x = {x}");
+    }
+}
+
+fn demo_mod_natural_neg(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_11().get(gm, config).take(limit) {
+        let x = ModNatural::new(x, m);
+        let x_old = x.clone();
+        println!("-({x_old}) = {}", -x);
+    }
+}
+
+fn demo_mod_natural_neg_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_11().get(gm, config).take(limit) {
+        let x = ModNatural::new(x, m);
+        println!("-&({x}) = {}", -&x);
+    }
+}
+
+fn benchmark_mod_natural_neg_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural.neg_assign()",
+        BenchmarkType::Single,
+        natural_pair_gen_var_11()
+            .get(gm, config)
+            .map(|(x, m)| (ModNatural::new(x, m), ())),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_mod_natural_modulus_bit_bucketer(),
+        &mut [("Malachite", &mut |(mut x, ())| x.neg_assign())],
+    );
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_mod_natural_neg_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "-ModNatural",
+        BenchmarkType::LibraryComparison,
+        natural_pair_gen_var_11().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("ModNatural", &mut |(x, m)| no_out!(-ModNatural::new(x, m))),
+            ("Natural.mod_neg", &mut |(x, m)| no_out!(x.mod_neg(m))),
+        ],
+    );
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_mod_natural_neg_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "-ModNatural",
+        BenchmarkType::EvaluationStrategy,
+        natural_pair_gen_var_11()
+            .get(gm, config)
+            .map(|(x, m)| (ModNatural::new(x, m), ())),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_mod_natural_modulus_bit_bucketer(),
+        &mut [
+            ("-ModNatural", &mut |(x, ())| no_out!(-x)),
+            ("-&ModNatural", &mut |(x, ())| no_out!(-&x)),
+        ],
+    );
+}
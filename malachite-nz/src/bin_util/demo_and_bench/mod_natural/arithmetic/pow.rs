@@ -0,0 +1,123 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModPow, Pow, PowAssign};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::test_util::bench::bucketers::{
+    pair_1_mod_natural_modulus_bit_bucketer, triple_3_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::natural_triple_gen_var_5;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_mod_natural_pow_assign);
+    register_demo!(runner, demo_mod_natural_pow);
+    register_demo!(runner, demo_mod_natural_pow_ref);
+
+    register_bench!(runner, benchmark_mod_natural_pow_assign);
+    register_bench!(runner, benchmark_mod_natural_pow_library_comparison);
+    register_bench!(runner, benchmark_mod_natural_pow_evaluation_strategy);
+}
+
+fn demo_mod_natural_pow_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let mut x = ModNatural::new(x, m);
+        let x_old = x.clone();
+        x.pow_assign(&exp);
+        println!("x := {x_old}; x.pow_assign(&{exp}); x = {x}");
+    }
+}
+
+fn demo_mod_natural_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let x = ModNatural::new(x, m);
+        let x_old = x.clone();
+        println!("({x_old}).pow(&{exp}) = {}", x.pow(&exp));
+    }
+}
+
+fn demo_mod_natural_pow_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let x = ModNatural::new(x, m);
+        println!("(&({x})).pow(&{exp}) = {}", (&x).pow(&exp));
+    }
+}
+
+fn benchmark_mod_natural_pow_assign(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural.pow_assign(&Natural)",
+        BenchmarkType::Single,
+        natural_triple_gen_var_5()
+            .get(gm, config)
+            .map(|(x, exp, m)| (ModNatural::new(x, m), exp)),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_mod_natural_modulus_bit_bucketer(),
+        &mut [("Malachite", &mut |(mut x, exp)| x.pow_assign(&exp))],
+    );
+}
+
+fn benchmark_mod_natural_pow_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural.pow(&Natural)",
+        BenchmarkType::LibraryComparison,
+        natural_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("ModNatural", &mut |(x, exp, m)| {
+                no_out!(ModNatural::new(x, m).pow(&exp));
+            }),
+            ("Natural.mod_pow", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(exp, m));
+            }),
+        ],
+    );
+}
+
+fn benchmark_mod_natural_pow_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModNatural.pow(&Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_triple_gen_var_5()
+            .get(gm, config)
+            .map(|(x, exp, m)| (ModNatural::new(x, m), exp)),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_mod_natural_modulus_bit_bucketer(),
+        &mut [
+            ("ModNatural.pow(&Natural)", &mut |(x, exp)| {
+                no_out!(x.pow(&exp));
+            }),
+            ("(&ModNatural).pow(&Natural)", &mut |(x, exp)| {
+                no_out!((&x).pow(&exp));
+            }),
+        ],
+    );
+}
@@ -0,0 +1,62 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModPow;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPow;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_5;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_fixed_base_mod_pow);
+    register_bench!(runner, benchmark_fixed_base_mod_pow_algorithms);
+}
+
+fn demo_fixed_base_mod_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let g = FixedBaseModPow::new(&x, m.clone(), exp.significant_bits());
+        println!(
+            "FixedBaseModPow::new({x}, {m}).pow({exp}) = {}",
+            g.pow(&exp)
+        );
+    }
+}
+
+fn benchmark_fixed_base_mod_pow_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "FixedBaseModPow.pow(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("default", &mut |(x, exp, m)| {
+                for i in 0..10u32 {
+                    (&x).mod_pow(&exp + Natural::from(i), &m);
+                }
+            }),
+            ("fixed base", &mut |(x, exp, m)| {
+                let g = FixedBaseModPow::new(&x, m, exp.significant_bits() + 1);
+                for i in 0..10u32 {
+                    g.pow(&(&exp + Natural::from(i)));
+                }
+            }),
+        ],
+    );
+}
@@ -16,6 +16,7 @@ pub(crate) fn register(runner: &mut Runner) {
     bell_number::register(runner);
     landau_function::register(runner);
     binomial_coefficient::register(runner);
+    catalan_number::register(runner);
     checked_mul_sub_mul::register(runner);
     checked_sub::register(runner);
     checked_sub_mul::register(runner);
@@ -36,6 +37,7 @@ pub(crate) fn register(runner: &mut Runner) {
     extended_gcd_partial::register(runner);
     factorial::register(runner);
     fibonacci::register(runner);
+    fixed_base_mod_pow::register(runner);
     #[cfg(feature = "float_helpers")]
     float::register(runner);
     gcd::register(runner);
@@ -46,12 +48,15 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base_2::register(runner);
     log_base_power_of_2::register(runner);
     mod_add::register(runner);
+    mod_context::register(runner);
     mod_div::register(runner);
     mod_div_list::register(runner);
     mod_euclidean::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_mul::register(runner);
+    mod_multiplicative_order::register(runner);
     mod_neg::register(runner);
     mod_op::register(runner);
     mod_pow::register(runner);
@@ -76,9 +81,12 @@ pub(crate) fn register(runner: &mut Runner) {
     mul_shr_round::register(runner);
     mul_sub_mul::register(runner);
     multi_crt::register(runner);
+    multi_mod_pow::register(runner);
+    multinomial_coefficient::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
     parity::register(runner);
+    partition_number::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
     primorial::register(runner);
@@ -96,6 +104,7 @@ pub(crate) fn register(runner: &mut Runner) {
     sign::register(runner);
     sqrt::register(runner);
     square::register(runner);
+    stirling_number::register(runner);
     sub::register(runner);
     sub_mul::register(runner);
 }
@@ -106,6 +115,7 @@ mod add_mul;
 mod average;
 mod bell_number;
 mod binomial_coefficient;
+mod catalan_number;
 mod checked_mul_sub_mul;
 mod checked_sub;
 mod checked_sub_mul;
@@ -126,6 +136,7 @@ mod extended_gcd;
 mod extended_gcd_partial;
 mod factorial;
 mod fibonacci;
+mod fixed_base_mod_pow;
 #[cfg(feature = "float_helpers")]
 mod float;
 mod gcd;
@@ -137,12 +148,15 @@ mod log_base;
 mod log_base_2;
 mod log_base_power_of_2;
 mod mod_add;
+mod mod_context;
 mod mod_div;
 mod mod_div_list;
 mod mod_euclidean;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
+mod mod_multiplicative_order;
 mod mod_neg;
 mod mod_op;
 mod mod_pow;
@@ -167,9 +181,12 @@ mod mul_add_mul;
 mod mul_shr_round;
 mod mul_sub_mul;
 mod multi_crt;
+mod multi_mod_pow;
+mod multinomial_coefficient;
 mod neg;
 mod next_power_of_2;
 mod parity;
+mod partition_number;
 mod pow;
 mod power_of_2;
 mod primorial;
@@ -187,5 +204,6 @@ mod shr_round;
 mod sign;
 mod sqrt;
 mod square;
+mod stirling_number;
 mod sub;
 mod sub_mul;
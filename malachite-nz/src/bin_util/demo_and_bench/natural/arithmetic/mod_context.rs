@@ -0,0 +1,106 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::arithmetic::traits::{ModMul, ModMulPrecomputed, ModPow};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::mod_context::ModContext;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::{natural_triple_gen_var_3, natural_triple_gen_var_5};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_mod_context_mul);
+    register_demo!(runner, demo_mod_context_pow);
+    register_bench!(runner, benchmark_mod_context_mul_algorithms);
+    register_bench!(runner, benchmark_mod_context_pow_algorithms);
+}
+
+fn demo_mod_context_mul(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3().get(gm, config).take(limit) {
+        let ctx = ModContext::new(m.clone());
+        let product = ctx.mul(&ctx.to_residue(&x), &ctx.to_residue(&y));
+        println!(
+            "ModContext::new({m}): {x} * {y} = {}",
+            ctx.from_residue(&product)
+        );
+    }
+}
+
+fn demo_mod_context_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let ctx = ModContext::new(m.clone());
+        let power = ctx.pow(&ctx.to_residue(&x), &exp);
+        println!(
+            "ModContext::new({m}): {x} ^ {exp} = {}",
+            ctx.from_residue(&power)
+        );
+    }
+}
+
+fn benchmark_mod_context_mul_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModContext.mul(&Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_3().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("default", &mut |(x, y, m)| {
+                for _ in 0..10 {
+                    (&x).mod_mul(&y, &m);
+                }
+            }),
+            ("precomputed", &mut |(x, y, m)| {
+                let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(&m);
+                for _ in 0..10 {
+                    (&x).mod_mul_precomputed(&y, &m, &data);
+                }
+            }),
+            ("context", &mut |(x, y, m)| {
+                let ctx = ModContext::new(m);
+                let x = ctx.to_residue(&x);
+                let y = ctx.to_residue(&y);
+                for _ in 0..10 {
+                    ctx.mul(&x, &y);
+                }
+            }),
+        ],
+    );
+}
+
+fn benchmark_mod_context_pow_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModContext.pow(&Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("default", &mut |(x, exp, m)| no_out!(x.mod_pow(exp, m))),
+            ("context", &mut |(x, exp, m)| {
+                let ctx = ModContext::new(m);
+                no_out!(ctx.from_residue(&ctx.pow(&ctx.to_residue(&x), &exp)));
+            }),
+        ],
+    );
+}
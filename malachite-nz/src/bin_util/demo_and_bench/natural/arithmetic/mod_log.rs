@@ -0,0 +1,50 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModLog;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_3;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_log);
+    register_demo!(runner, demo_natural_mod_log_ref_ref_ref);
+    register_bench!(runner, benchmark_natural_mod_log);
+}
+
+fn demo_natural_mod_log(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, b, m) in natural_triple_gen_var_3().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let b_old = b.clone();
+        let m_old = m.clone();
+        println!("{x_old}.mod_log({b_old}, {m_old}) = {:?}", x.mod_log(b, m));
+    }
+}
+
+fn demo_natural_mod_log_ref_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, b, m) in natural_triple_gen_var_3().get(gm, config).take(limit) {
+        println!("(&{x}).mod_log(&{b}, &{m}) = {:?}", (&x).mod_log(&b, &m));
+    }
+}
+
+fn benchmark_natural_mod_log(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.mod_log(Natural, Natural)",
+        BenchmarkType::Single,
+        natural_triple_gen_var_3().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(x, b, m)| {
+            no_out!(x.mod_log(b, m));
+        })],
+    );
+}
@@ -0,0 +1,73 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_1_vec_len_times_pair_2_natural_bits_bucketer;
+use malachite_nz::test_util::generators::natural_vec_natural_pair_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_multi_mod_pow);
+    register_bench!(runner, benchmark_natural_multi_mod_pow_algorithms);
+}
+
+// Pairs each element of `xs` with the element the same distance from the other end, which serves
+// as its exponent.
+fn pairs_from_vec(xs: &[Natural]) -> Vec<(Natural, Natural)> {
+    xs.iter().cloned().zip(xs.iter().rev().cloned()).collect()
+}
+
+fn demo_natural_multi_mod_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (xs, m) in natural_vec_natural_pair_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let pairs = pairs_from_vec(&xs);
+        println!(
+            "Natural::multi_mod_pow({:?}, {}) = {}",
+            pairs,
+            m,
+            Natural::multi_mod_pow(&pairs, &m)
+        );
+    }
+}
+
+fn benchmark_natural_multi_mod_pow_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::multi_mod_pow(&[(Natural, Natural)], &Natural)",
+        BenchmarkType::Algorithms,
+        natural_vec_natural_pair_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_vec_len_times_pair_2_natural_bits_bucketer("xs", "m"),
+        &mut [
+            ("default", &mut |(xs, m)| {
+                no_out!(Natural::multi_mod_pow(&pairs_from_vec(&xs), &m));
+            }),
+            ("naive", &mut |(xs, m)| {
+                no_out!(
+                    pairs_from_vec(&xs)
+                        .into_iter()
+                        .fold(Natural::ONE % &m, |acc, (x, exp)| {
+                            acc.mod_mul(x.mod_pow(exp, &m), &m)
+                        })
+                );
+            }),
+        ],
+    );
+}
@@ -0,0 +1,52 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::MultinomialCoefficient;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_multinomial_coefficient);
+    register_bench!(runner, benchmark_natural_multinomial_coefficient);
+}
+
+fn demo_natural_multinomial_coefficient(gm: GenMode, config: &GenConfig, limit: usize) {
+    for ks in unsigned_vec_gen::<u8>().get(gm, config).take(limit) {
+        let ks: Vec<u64> = ks.into_iter().map(u64::from).collect();
+        println!(
+            "multinomial_coefficient({:?}) = {}",
+            ks,
+            Natural::multinomial_coefficient(&ks)
+        );
+    }
+}
+
+fn benchmark_natural_multinomial_coefficient(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::multinomial_coefficient(&[u64])",
+        BenchmarkType::Single,
+        unsigned_vec_gen::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |ks| {
+            let ks: Vec<u64> = ks.into_iter().map(u64::from).collect();
+            no_out!(Natural::multinomial_coefficient(&ks));
+        })],
+    );
+}
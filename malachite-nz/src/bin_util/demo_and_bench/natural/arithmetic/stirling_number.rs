@@ -0,0 +1,171 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{StirlingNumber1, StirlingNumber2};
+use malachite_base::test_util::bench::bucketers::{pair_1_bucketer, unsigned_direct_bucketer};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_28};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::stirling_number::{
+    stirling_number_1_row, stirling_number_2_row, stirling_number_2_row_convolution,
+    stirling_number_2_row_triangle,
+};
+use malachite_nz::test_util::natural::arithmetic::stirling_number::{
+    stirling_number_1_row_naive, stirling_number_2_row_naive,
+};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_stirling_number_1);
+    register_demo!(runner, demo_natural_stirling_number_2);
+    register_demo!(runner, demo_stirling_number_1_row);
+    register_demo!(runner, demo_stirling_number_2_row);
+    register_bench!(runner, benchmark_natural_stirling_number_1);
+    register_bench!(runner, benchmark_natural_stirling_number_2);
+    register_bench!(runner, benchmark_stirling_number_1_row_algorithms);
+    register_bench!(runner, benchmark_stirling_number_2_row_algorithms);
+}
+
+fn demo_natural_stirling_number_1(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, k) in unsigned_pair_gen_var_28().get(gm, config).take(limit) {
+        println!(
+            "stirling_number_1({}, {}) = {}",
+            n,
+            k,
+            Natural::stirling_number_1(n, k)
+        );
+    }
+}
+
+fn demo_natural_stirling_number_2(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, k) in unsigned_pair_gen_var_28().get(gm, config).take(limit) {
+        println!(
+            "stirling_number_2({}, {}) = {}",
+            n,
+            k,
+            Natural::stirling_number_2(n, k)
+        );
+    }
+}
+
+fn demo_stirling_number_1_row(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<u8>().get(gm, config).take(limit) {
+        println!(
+            "stirling_number_1_row({}) = {:?}",
+            n,
+            stirling_number_1_row(u64::from(n))
+        );
+    }
+}
+
+fn demo_stirling_number_2_row(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<u8>().get(gm, config).take(limit) {
+        println!(
+            "stirling_number_2_row({}) = {:?}",
+            n,
+            stirling_number_2_row(u64::from(n))
+        );
+    }
+}
+
+fn benchmark_natural_stirling_number_1(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::stirling_number_1(u64, u64)",
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [("Malachite", &mut |(n, k)| {
+            no_out!(Natural::stirling_number_1(n, k));
+        })],
+    );
+}
+
+fn benchmark_natural_stirling_number_2(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::stirling_number_2(u64, u64)",
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [("Malachite", &mut |(n, k)| {
+            no_out!(Natural::stirling_number_2(n, k));
+        })],
+    );
+}
+
+fn benchmark_stirling_number_1_row_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "stirling_number_1_row(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<u16>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| {
+                no_out!(stirling_number_1_row(u64::from(n)));
+            }),
+            ("naive", &mut |n| {
+                no_out!(stirling_number_1_row_naive(u64::from(n)));
+            }),
+        ],
+    );
+}
+
+fn benchmark_stirling_number_2_row_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "stirling_number_2_row(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<u16>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| {
+                no_out!(stirling_number_2_row(u64::from(n)));
+            }),
+            ("triangle", &mut |n| {
+                no_out!(stirling_number_2_row_triangle(u64::from(n)));
+            }),
+            ("convolution", &mut |n| {
+                no_out!(stirling_number_2_row_convolution(u64::from(n)));
+            }),
+            ("naive", &mut |n| {
+                no_out!(stirling_number_2_row_naive(u64::from(n)));
+            }),
+        ],
+    );
+}
@@ -0,0 +1,58 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::factorization::traits::{DivisorCount, DivisorSigma};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_divisor_sigma);
+    register_demo!(runner, demo_natural_divisor_count);
+    register_bench!(runner, benchmark_natural_divisor_sigma);
+    register_bench!(runner, benchmark_natural_divisor_count);
+}
+
+fn demo_natural_divisor_sigma(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, config).take(limit) {
+        println!("{n}.divisor_sigma(1) = {}", n.divisor_sigma(1));
+    }
+}
+
+fn demo_natural_divisor_count(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, config).take(limit) {
+        println!("{n}.divisor_count() = {}", n.divisor_count());
+    }
+}
+
+fn benchmark_natural_divisor_sigma(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisor_sigma(1)",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_sigma(1)))],
+    );
+}
+
+fn benchmark_natural_divisor_count(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisor_count()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_count()))],
+    );
+}
@@ -0,0 +1,38 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::Divisors;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_divisors);
+    register_bench!(runner, benchmark_natural_divisors);
+}
+
+fn demo_natural_divisors(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, config).take(limit) {
+        println!("{n}.divisors() = {:?}", n.divisors().take(20).collect_vec());
+    }
+}
+
+fn benchmark_natural_divisors(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisors()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisors().count()))],
+    );
+}
@@ -0,0 +1,99 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::factorization::factor::{FactorOptions, NaturalFactors};
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::fmt::Write;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_factor);
+    register_demo!(runner, demo_natural_factor_with_options);
+    register_bench!(runner, benchmark_natural_factor);
+    register_bench!(runner, benchmark_natural_factor_with_options);
+}
+
+fn factors_to_string(factors: NaturalFactors) -> String {
+    let mut s = String::new();
+    for (p, e) in factors {
+        if !s.is_empty() {
+            write!(s, "×").ok();
+        }
+        if e == 1 {
+            write!(s, "{p}").ok();
+        } else {
+            write!(s, "{p}^{e}").ok();
+        }
+    }
+    if s.is_empty() {
+        s = "1".to_string();
+    }
+    s
+}
+
+fn demo_natural_factor(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, config).take(limit) {
+        println!("factor({}) = {}", n, factors_to_string(n.factor()));
+    }
+}
+
+fn demo_natural_factor_with_options(gm: GenMode, config: &GenConfig, limit: usize) {
+    let mut options = FactorOptions::default();
+    options.set_max_digits(Some(20));
+    for n in natural_gen_var_2().get(gm, config).take(limit) {
+        let factors = n.factor_with_options(&options);
+        let unfactored = factors.unfactored().to_debug_string();
+        println!(
+            "factor_with_options({}, max_digits = 20) = {}, unfactored: {}",
+            n,
+            factors_to_string(factors),
+            unfactored
+        );
+    }
+}
+
+fn benchmark_natural_factor(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.factor()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.factor()))],
+    );
+}
+
+fn benchmark_natural_factor_with_options(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    let mut options = FactorOptions::default();
+    options.set_max_digits(Some(20));
+    run_benchmark(
+        "Natural.factor_with_options(&FactorOptions)",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n: Natural| {
+            no_out!(n.factor_with_options(&options));
+        })],
+    );
+}
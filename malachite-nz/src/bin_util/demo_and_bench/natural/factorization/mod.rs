@@ -9,11 +9,27 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    divisor_sigma::register(runner);
+    divisors::register(runner);
+    factor::register(runner);
     is_power::register(runner);
+    is_prime::register(runner);
     is_square::register(runner);
+    moebius_mu::register(runner);
+    next_prime::register(runner);
     primes::register(runner);
+    primitive_root::register(runner);
+    totient::register(runner);
 }
 
+mod divisor_sigma;
+mod divisors;
+mod factor;
 mod is_power;
+mod is_prime;
 mod is_square;
+mod moebius_mu;
+mod next_prime;
 mod primes;
+mod primitive_root;
+mod totient;
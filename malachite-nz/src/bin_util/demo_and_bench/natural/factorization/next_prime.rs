@@ -0,0 +1,110 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::{NextPrime, PrevPrime};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::{
+    natural_bit_bucketer, pair_1_natural_bit_bucketer, pair_2_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_gen_rm, natural_unsigned_pair_gen_var_4,
+};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_next_prime);
+    register_demo!(runner, demo_natural_prev_prime);
+    register_demo!(runner, demo_natural_primes_in_range);
+    register_bench!(runner, benchmark_natural_next_prime_library_comparison);
+    register_bench!(runner, benchmark_natural_prev_prime);
+    register_bench!(runner, benchmark_natural_primes_in_range);
+}
+
+fn demo_natural_next_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        println!("next_prime({}) = {}", n, n.next_prime());
+    }
+}
+
+fn demo_natural_prev_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        println!("prev_prime({}) = {:?}", n, n.prev_prime());
+    }
+}
+
+fn demo_natural_primes_in_range(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (a, width) in natural_unsigned_pair_gen_var_4::<u32>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let b = &a + Natural::from(width);
+        println!(
+            "primes_in_range({}, {}) = {:?}",
+            a,
+            b,
+            Natural::primes_in_range(&a, &b).collect::<Vec<_>>()
+        );
+    }
+}
+
+fn benchmark_natural_next_prime_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.next_prime()",
+        BenchmarkType::LibraryComparison,
+        natural_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("n"),
+        &mut [
+            ("Malachite", &mut |(_, n)| no_out!(n.next_prime())),
+            ("rug", &mut |(mut n, _)| n.next_prime_mut()),
+        ],
+    );
+}
+
+fn benchmark_natural_prev_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.prev_prime()",
+        BenchmarkType::Single,
+        natural_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.prev_prime()))],
+    );
+}
+
+fn benchmark_natural_primes_in_range(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::primes_in_range(&Natural, &Natural)",
+        BenchmarkType::Single,
+        natural_unsigned_pair_gen_var_4::<u32>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("a"),
+        &mut [("Malachite", &mut |(a, width)| {
+            let b = &a + Natural::from(width);
+            no_out!(Natural::primes_in_range(&a, &b).count());
+        })],
+    );
+}
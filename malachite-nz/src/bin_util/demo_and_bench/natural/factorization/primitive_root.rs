@@ -0,0 +1,86 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::factorization::traits::{IsPrimitiveRoot, PrimitiveRoot};
+use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::{
+    natural_bit_bucketer, pair_2_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{natural_gen_var_2, natural_pair_gen_var_11};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_primitive_root);
+    register_demo!(runner, demo_natural_is_primitive_root);
+    register_demo!(runner, demo_natural_is_primitive_root_ref_ref);
+    register_bench!(runner, benchmark_natural_primitive_root);
+    register_bench!(runner, benchmark_natural_is_primitive_root);
+}
+
+fn demo_natural_primitive_root(gm: GenMode, config: &GenConfig, limit: usize) {
+    for m in natural_gen_var_2().get(gm, config).take(limit) {
+        println!("{m}.primitive_root() = {:?}", m.primitive_root());
+    }
+}
+
+fn demo_natural_is_primitive_root(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_11().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        if x.is_primitive_root(m) {
+            println!("{x_old} is a primitive root mod {m_old}");
+        } else {
+            println!("{x_old} is not a primitive root mod {m_old}");
+        }
+    }
+}
+
+fn demo_natural_is_primitive_root_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_11().get(gm, config).take(limit) {
+        println!(
+            "(&{x}).is_primitive_root(&{m}) = {}",
+            (&x).is_primitive_root(&m)
+        );
+    }
+}
+
+fn benchmark_natural_primitive_root(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.primitive_root()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("m"),
+        &mut [("Malachite", &mut |m| no_out!(m.primitive_root()))],
+    );
+}
+
+fn benchmark_natural_is_primitive_root(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.is_primitive_root(Natural)",
+        BenchmarkType::Single,
+        natural_pair_gen_var_11().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(x, m)| no_out!(x.is_primitive_root(m)))],
+    );
+}
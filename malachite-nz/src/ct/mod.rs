@@ -0,0 +1,564 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::platform::{DoubleLimb, Limb};
+use alloc::vec::Vec;
+use core::hint::black_box;
+use core::ops::{BitAnd, BitOr, Not};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+#[cfg(feature = "test_build")]
+use std::cell::Cell;
+
+// The number of limb operations performed by the current thread since the count was last reset.
+// Every loop in this module goes through the counted primitives below, so two computations that
+// follow the same instruction trace perform the same number of operations.
+#[cfg(feature = "test_build")]
+std::thread_local! {
+    static OP_COUNT: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Returns the number of limb operations that constant-time functions have performed on the current
+/// thread since the last call to [`reset_op_count`].
+///
+/// Two calls to the same function with inputs of the same widths should always perform the same
+/// number of operations, whatever the values of the inputs.
+#[cfg(feature = "test_build")]
+pub fn op_count() -> u64 {
+    OP_COUNT.with(Cell::get)
+}
+
+/// Resets the count returned by [`op_count`] to zero.
+#[cfg(feature = "test_build")]
+pub fn reset_op_count() {
+    OP_COUNT.with(|c| c.set(0));
+}
+
+#[inline]
+fn count_op() {
+    #[cfg(feature = "test_build")]
+    OP_COUNT.with(|c| c.set(c.get() + 1));
+}
+
+// Returns a `Limb` with all bits set if `bit` is 1, and 0 if `bit` is 0. `bit` must be 0 or 1. The
+// `black_box` keeps the compiler from turning code that uses the mask back into a branch.
+#[inline]
+pub(crate) const fn limb_mask(bit: Limb) -> Limb {
+    black_box(bit).wrapping_neg()
+}
+
+// Returns 1 if `x` is 0, and 0 otherwise.
+#[inline]
+pub(crate) const fn limb_is_zero(x: Limb) -> Limb {
+    ((x | x.wrapping_neg()) >> (Limb::WIDTH - 1)) ^ 1
+}
+
+// Returns `y` if `mask` has all bits set, and `x` if `mask` is 0.
+#[inline]
+pub(crate) fn limb_select(x: Limb, y: Limb, mask: Limb) -> Limb {
+    count_op();
+    x ^ (mask & (x ^ y))
+}
+
+// Returns `x + y + carry` and the carry out. `carry` must be 0 or 1.
+#[inline]
+pub(crate) fn limb_add_with_carry(x: Limb, y: Limb, carry: Limb) -> (Limb, Limb) {
+    count_op();
+    let (sum, c_1) = x.overflowing_add(y);
+    let (sum, c_2) = sum.overflowing_add(carry);
+    (sum, Limb::from(c_1 | c_2))
+}
+
+// Returns `x - y - borrow` and the borrow out. `borrow` must be 0 or 1.
+#[inline]
+pub(crate) fn limb_sub_with_borrow(x: Limb, y: Limb, borrow: Limb) -> (Limb, Limb) {
+    count_op();
+    let (difference, b_1) = x.overflowing_sub(y);
+    let (difference, b_2) = difference.overflowing_sub(borrow);
+    (difference, Limb::from(b_1 | b_2))
+}
+
+// Returns the low and high limbs of `x * y + z + carry`, which always fits in two limbs.
+#[inline]
+pub(crate) fn limb_mul_add_with_carry(x: Limb, y: Limb, z: Limb, carry: Limb) -> (Limb, Limb) {
+    count_op();
+    let product =
+        DoubleLimb::from(x) * DoubleLimb::from(y) + DoubleLimb::from(z) + DoubleLimb::from(carry);
+    (
+        Limb::wrapping_from(product),
+        Limb::wrapping_from(product >> Limb::WIDTH),
+    )
+}
+
+// Returns 1 if `xs` and `ys` are equal, and 0 otherwise. `xs` and `ys` must have the same length.
+pub(crate) fn limbs_ct_eq(xs: &[Limb], ys: &[Limb]) -> Limb {
+    let mut difference = 0;
+    for (&x, &y) in xs.iter().zip(ys) {
+        count_op();
+        difference |= x ^ y;
+    }
+    limb_is_zero(difference)
+}
+
+// Returns 1 if `xs` is less than `ys`, and 0 otherwise. `xs` and `ys` must have the same length.
+pub(crate) fn limbs_ct_lt(xs: &[Limb], ys: &[Limb]) -> Limb {
+    let mut borrow = 0;
+    for (&x, &y) in xs.iter().zip(ys) {
+        borrow = limb_sub_with_borrow(x, y, borrow).1;
+    }
+    borrow
+}
+
+// Sets `xs` to `ys` if `mask` has all bits set, and leaves it unchanged if `mask` is 0. `xs` and
+// `ys` must have the same length.
+pub(crate) fn limbs_ct_assign(xs: &mut [Limb], ys: &[Limb], mask: Limb) {
+    for (x, &y) in xs.iter_mut().zip(ys) {
+        *x = limb_select(*x, y, mask);
+    }
+}
+
+// Swaps `xs` and `ys` if `mask` has all bits set, and leaves them unchanged if `mask` is 0. `xs`
+// and `ys` must have the same length.
+pub(crate) fn limbs_ct_swap(xs: &mut [Limb], ys: &mut [Limb], mask: Limb) {
+    for (x, y) in xs.iter_mut().zip(ys.iter_mut()) {
+        count_op();
+        let t = mask & (*x ^ *y);
+        *x ^= t;
+        *y ^= t;
+    }
+}
+
+// Adds `ys` to `xs` in place if `mask` has all bits set, returning the carry; otherwise, leaves
+// `xs` unchanged and returns 0. `xs` and `ys` must have the same length.
+pub(crate) fn limbs_ct_add_in_place(xs: &mut [Limb], ys: &[Limb], mask: Limb) -> Limb {
+    let mut carry = 0;
+    for (x, &y) in xs.iter_mut().zip(ys) {
+        (*x, carry) = limb_add_with_carry(*x, y & mask, carry);
+    }
+    carry
+}
+
+// Subtracts `ys` from `xs` in place if `mask` has all bits set, returning the borrow; otherwise,
+// leaves `xs` unchanged and returns 0. `xs` and `ys` must have the same length.
+pub(crate) fn limbs_ct_sub_in_place(xs: &mut [Limb], ys: &[Limb], mask: Limb) -> Limb {
+    let mut borrow = 0;
+    for (x, &y) in xs.iter_mut().zip(ys) {
+        (*x, borrow) = limb_sub_with_borrow(*x, y & mask, borrow);
+    }
+    borrow
+}
+
+// Negates `xs` modulo $2^{Wn}$ in place if `mask` has all bits set, and leaves it unchanged if
+// `mask` is 0.
+pub(crate) fn limbs_ct_neg_in_place(xs: &mut [Limb], mask: Limb) {
+    let mut carry = mask & 1;
+    for x in xs.iter_mut() {
+        (*x, carry) = limb_add_with_carry(*x ^ mask, 0, carry);
+    }
+}
+
+// Shifts `xs` right by one bit in place.
+pub(crate) fn limbs_ct_shr_1_in_place(xs: &mut [Limb]) {
+    let mut high = 0;
+    for x in xs.iter_mut().rev() {
+        count_op();
+        let low = *x & 1;
+        *x = (*x >> 1) | (high << (Limb::WIDTH - 1));
+        high = low;
+    }
+}
+
+/// The result of a constant-time test, either true or false.
+///
+/// A `Choice` is produced by functions such as [`CtNatural::ct_eq`] and consumed by
+/// [`CtNatural::conditional_select`] and its relatives without branching on its value. Converting
+/// it to a [`bool`] is allowed, but any code that then branches on the [`bool`] is no longer
+/// constant-time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Choice(Limb);
+
+impl Choice {
+    /// The constant true.
+    pub const TRUE: Self = Self(1);
+
+    /// The constant false.
+    pub const FALSE: Self = Self(0);
+
+    // Returns a `Limb` with all bits set if `self` is true, and 0 otherwise.
+    #[inline]
+    pub(crate) const fn mask(self) -> Limb {
+        limb_mask(self.0)
+    }
+}
+
+impl From<bool> for Choice {
+    /// Converts a [`bool`] to a [`Choice`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::Choice;
+    ///
+    /// assert_eq!(Choice::from(true), Choice::TRUE);
+    /// assert_eq!(Choice::from(false), Choice::FALSE);
+    /// ```
+    #[inline]
+    fn from(b: bool) -> Self {
+        Self(Limb::from(b))
+    }
+}
+
+impl From<Choice> for bool {
+    /// Converts a [`Choice`] to a [`bool`].
+    ///
+    /// Branching on the result is not constant-time.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::Choice;
+    ///
+    /// assert!(bool::from(Choice::TRUE));
+    /// assert!(!bool::from(Choice::FALSE));
+    /// ```
+    #[inline]
+    fn from(c: Choice) -> Self {
+        c.0 != 0
+    }
+}
+
+impl Not for Choice {
+    type Output = Self;
+
+    /// Negates a [`Choice`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::Choice;
+    ///
+    /// assert_eq!(!Choice::TRUE, Choice::FALSE);
+    /// assert_eq!(!Choice::FALSE, Choice::TRUE);
+    /// ```
+    #[inline]
+    fn not(self) -> Self {
+        Self(self.0 ^ 1)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Self;
+
+    /// Takes the logical and of two [`Choice`]s.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::Choice;
+    ///
+    /// assert_eq!(Choice::TRUE & Choice::FALSE, Choice::FALSE);
+    /// assert_eq!(Choice::TRUE & Choice::TRUE, Choice::TRUE);
+    /// ```
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Self;
+
+    /// Takes the logical or of two [`Choice`]s.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::Choice;
+    ///
+    /// assert_eq!(Choice::TRUE | Choice::FALSE, Choice::TRUE);
+    /// assert_eq!(Choice::FALSE | Choice::FALSE, Choice::FALSE);
+    /// ```
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// A [`Natural`] padded to a declared bit length, for use in constant-time computations.
+///
+/// A `CtNatural` always occupies the number of limbs needed to hold its declared bit length, so
+/// the cost of operating on it depends on that length but not on its value. The declared length
+/// is treated as public. Functions that combine two `CtNatural`s require their declared lengths to
+/// be equal, and panic otherwise.
+///
+/// Converting between [`Natural`]s and `CtNatural`s is not constant-time: a [`Natural`]'s
+/// representation already reveals its size.
+#[derive(Clone)]
+pub struct CtNatural {
+    pub(crate) limbs: Vec<Limb>,
+    pub(crate) bits: u64,
+}
+
+impl CtNatural {
+    // Creates a `CtNatural` of the given bit length from little-endian limbs, which must already
+    // have the right length.
+    pub(crate) fn from_limbs(limbs: Vec<Limb>, bits: u64) -> Self {
+        debug_assert_eq!(limbs.len(), Self::limb_count(bits));
+        Self { limbs, bits }
+    }
+
+    // Returns the number of limbs needed to hold `bits` bits.
+    pub(crate) fn limb_count(bits: u64) -> usize {
+        usize::exact_from(bits.div_ceil(Limb::WIDTH))
+    }
+
+    /// Creates a [`CtNatural`] with a declared bit length from a [`Natural`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+    ///
+    /// # Panics
+    /// Panics if `bits` is zero or if `x` has more than `bits` significant bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::CtNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = CtNatural::from_natural(&Natural::from(123u32), 256);
+    /// assert_eq!(x.bits(), 256);
+    /// assert_eq!(x.to_natural(), 123);
+    /// ```
+    pub fn from_natural(x: &Natural, bits: u64) -> Self {
+        assert_ne!(bits, 0, "The bit length must be positive");
+        assert!(
+            x.significant_bits() <= bits,
+            "{x} does not fit in {bits} bits"
+        );
+        let mut limbs = x.to_limbs_asc();
+        limbs.resize(Self::limb_count(bits), 0);
+        Self { limbs, bits }
+    }
+
+    /// Converts a [`CtNatural`] to a [`Natural`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::CtNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     CtNatural::from_natural(&Natural::from(123u32), 100).to_natural(),
+    ///     123
+    /// );
+    /// ```
+    pub fn to_natural(&self) -> Natural {
+        Natural::from_limbs_asc(&self.limbs)
+    }
+
+    /// Returns the declared bit length of a [`CtNatural`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::CtNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(CtNatural::from_natural(&Natural::from(123u32), 100).bits(), 100);
+    /// ```
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    pub(crate) fn assert_same_bits(&self, other: &Self) {
+        assert_eq!(
+            self.bits, other.bits,
+            "Bit lengths must be equal, but {} != {}",
+            self.bits, other.bits
+        );
+    }
+
+    /// Determines, in constant time, whether two [`CtNatural`]s are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different declared bit lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::{Choice, CtNatural};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = CtNatural::from_natural(&Natural::from(123u32), 100);
+    /// let y = CtNatural::from_natural(&Natural::from(456u32), 100);
+    /// assert_eq!(x.ct_eq(&x), Choice::TRUE);
+    /// assert_eq!(x.ct_eq(&y), Choice::FALSE);
+    /// ```
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        self.assert_same_bits(other);
+        Choice(limbs_ct_eq(&self.limbs, &other.limbs))
+    }
+
+    /// Determines, in constant time, whether a [`CtNatural`] is less than another.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different declared bit lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::{Choice, CtNatural};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = CtNatural::from_natural(&Natural::from(123u32), 100);
+    /// let y = CtNatural::from_natural(&Natural::from(456u32), 100);
+    /// assert_eq!(x.ct_lt(&y), Choice::TRUE);
+    /// assert_eq!(y.ct_lt(&x), Choice::FALSE);
+    /// assert_eq!(x.ct_lt(&x), Choice::FALSE);
+    /// ```
+    pub fn ct_lt(&self, other: &Self) -> Choice {
+        self.assert_same_bits(other);
+        Choice(limbs_ct_lt(&self.limbs, &other.limbs))
+    }
+
+    /// Returns, in constant time, a copy of `a` if `choice` is false and a copy of `b` if it is
+    /// true.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `a.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `a` and `b` have different declared bit lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::{Choice, CtNatural};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = CtNatural::from_natural(&Natural::from(123u32), 100);
+    /// let y = CtNatural::from_natural(&Natural::from(456u32), 100);
+    /// assert_eq!(
+    ///     CtNatural::conditional_select(&x, &y, Choice::FALSE).to_natural(),
+    ///     123
+    /// );
+    /// assert_eq!(
+    ///     CtNatural::conditional_select(&x, &y, Choice::TRUE).to_natural(),
+    ///     456
+    /// );
+    /// ```
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut out = a.clone();
+        out.conditional_assign(b, choice);
+        out
+    }
+
+    /// Sets, in constant time, a [`CtNatural`] to another if `choice` is true, and leaves it
+    /// unchanged otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different declared bit lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::{Choice, CtNatural};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = CtNatural::from_natural(&Natural::from(123u32), 100);
+    /// let y = CtNatural::from_natural(&Natural::from(456u32), 100);
+    /// x.conditional_assign(&y, Choice::FALSE);
+    /// assert_eq!(x.to_natural(), 123);
+    /// x.conditional_assign(&y, Choice::TRUE);
+    /// assert_eq!(x.to_natural(), 456);
+    /// ```
+    pub fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+        self.assert_same_bits(other);
+        limbs_ct_assign(&mut self.limbs, &other.limbs, choice.mask());
+    }
+
+    /// Swaps, in constant time, two [`CtNatural`]s if `choice` is true, and leaves them unchanged
+    /// otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `a.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `a` and `b` have different declared bit lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::{Choice, CtNatural};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = CtNatural::from_natural(&Natural::from(123u32), 100);
+    /// let mut y = CtNatural::from_natural(&Natural::from(456u32), 100);
+    /// CtNatural::conditional_swap(&mut x, &mut y, Choice::TRUE);
+    /// assert_eq!(x.to_natural(), 456);
+    /// assert_eq!(y.to_natural(), 123);
+    /// ```
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        a.assert_same_bits(b);
+        limbs_ct_swap(&mut a.limbs, &mut b.limbs, choice.mask());
+    }
+}
+
+/// [`CtModulus`](modulus::CtModulus), a fixed odd modulus for constant-time modular
+/// multiplication, exponentiation, and inversion.
+pub mod modulus;
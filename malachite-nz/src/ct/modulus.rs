@@ -0,0 +1,342 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// Uses code adopted from the GNU MP Library.
+//
+//      Copyright © 2013, 2015 Free Software Foundation, Inc.
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::ct::{
+    Choice, CtNatural, limb_add_with_carry, limb_is_zero, limb_mask, limb_mul_add_with_carry,
+    limbs_ct_add_in_place, limbs_ct_assign, limbs_ct_eq, limbs_ct_neg_in_place,
+    limbs_ct_shr_1_in_place, limbs_ct_sub_in_place, limbs_ct_swap,
+};
+use crate::natural::Natural;
+use crate::natural::arithmetic::div_exact::limbs_modular_invert_limb;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{Parity, PowerOf2, Square};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+
+// The width, in bits, of the exponent windows used by `CtModulus::mod_pow`. It must divide
+// `Limb::WIDTH`, so that no window straddles two limbs.
+const WINDOW_WIDTH: u64 = 4;
+
+/// An odd modulus $m > 1$ with a declared bit length, together with precomputed data for
+/// constant-time arithmetic modulo $m$.
+///
+/// The functions of a `CtModulus` take and return [`CtNatural`]s with the modulus's declared bit
+/// length, whose values must be less than $m$. Their running times, memory-access patterns, and
+/// instruction traces depend on the declared bit lengths of their inputs but not on the values of
+/// the inputs. The modulus itself is treated as public.
+///
+/// Multiplication uses Montgomery's method, with $R = 2^{Wn}$, where $W$ is the limb width and $n$
+/// is the number of limbs of the declared bit length; this is why $m$ must be odd.
+#[derive(Clone)]
+pub struct CtModulus {
+    m: CtNatural,
+    // -m^{-1} mod 2^W
+    m_inv: Limb,
+    // R mod m
+    one: Vec<Limb>,
+    // R^2 mod m
+    r_squared: Vec<Limb>,
+    // (m + 1) / 2
+    half: Vec<Limb>,
+}
+
+impl CtModulus {
+    /// Creates a [`CtModulus`] from an odd [`Natural`] $m > 1$ and a declared bit length.
+    ///
+    /// This function is not constant-time, since the modulus is public.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+    ///
+    /// # Panics
+    /// Panics if `m` is even or 1, or if `m` has more than `bits` significant bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::modulus::CtModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = CtModulus::new(&Natural::from(497u32), 64);
+    /// assert_eq!(m.bits(), 64);
+    /// assert_eq!(m.modulus().to_natural(), 497);
+    /// ```
+    pub fn new(m: &Natural, bits: u64) -> Self {
+        assert!(
+            m.odd() && *m != 1u32,
+            "The modulus must be odd and greater than 1, but it is {m}"
+        );
+        let ct_m = CtNatural::from_natural(m, bits);
+        let n = ct_m.limbs.len();
+        let pad = |x: Natural| CtNatural::from_natural(&x, bits).limbs;
+        let one = Natural::power_of_2(Limb::WIDTH * u64::exact_from(n)) % m;
+        let r_squared = pad((&one).square() % m);
+        Self {
+            m_inv: limbs_modular_invert_limb(ct_m.limbs[0]).wrapping_neg(),
+            m: ct_m,
+            one: pad(one),
+            r_squared,
+            half: pad((m + Natural::ONE) >> 1),
+        }
+    }
+
+    /// Returns the modulus of a [`CtModulus`], padded to its declared bit length.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::modulus::CtModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = CtModulus::new(&Natural::from(497u32), 64);
+    /// assert_eq!(m.modulus().to_natural(), 497);
+    /// assert_eq!(m.modulus().bits(), 64);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &CtNatural {
+        &self.m
+    }
+
+    /// Returns the declared bit length of a [`CtModulus`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::modulus::CtModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(CtModulus::new(&Natural::from(497u32), 64).bits(), 64);
+    /// ```
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.m.bits
+    }
+
+    // Panics unless `x` has the declared bit length of `self` and is less than the modulus. The
+    // comparison is constant-time; only its outcome, which is always true for valid inputs, is
+    // branched on.
+    fn assert_reduced(&self, x: &CtNatural) {
+        x.assert_same_bits(&self.m);
+        assert!(
+            bool::from(x.ct_lt(&self.m)),
+            "The input must be reduced modulo the modulus"
+        );
+    }
+
+    // Returns `xs * ys / R mod m`. `xs` and `ys` must have `n` limbs each and be less than `m`.
+    //
+    // This is the coarsely integrated operand scanning (CIOS) form of Montgomery multiplication,
+    // followed by a masked final subtraction.
+    fn mont_mul(&self, xs: &[Limb], ys: &[Limb]) -> Vec<Limb> {
+        let ms = &self.m.limbs;
+        let n = ms.len();
+        let mut ts = vec![0; n + 2];
+        for &y in ys {
+            let mut carry = 0;
+            for (t, &x) in ts[..n].iter_mut().zip(xs) {
+                (*t, carry) = limb_mul_add_with_carry(x, y, *t, carry);
+            }
+            (ts[n], ts[n + 1]) = limb_add_with_carry(ts[n], carry, 0);
+            let q = ts[0].wrapping_mul(self.m_inv);
+            carry = limb_mul_add_with_carry(q, ms[0], ts[0], 0).1;
+            for j in 1..n {
+                (ts[j - 1], carry) = limb_mul_add_with_carry(q, ms[j], ts[j], carry);
+            }
+            let high;
+            (ts[n - 1], high) = limb_add_with_carry(ts[n], carry, 0);
+            ts[n] = ts[n + 1].wrapping_add(high);
+        }
+        // Now ts < 2m; subtract m unless ts < m.
+        let high = ts[n];
+        ts.truncate(n);
+        let mut ds = ts.clone();
+        let borrow = limbs_ct_sub_in_place(&mut ds, ms, Limb::MAX);
+        limbs_ct_assign(&mut ts, &ds, limb_mask(high | (borrow ^ 1)));
+        ts
+    }
+
+    // Returns 1, padded to `n` limbs.
+    fn padded_one(&self) -> Vec<Limb> {
+        let mut xs = vec![0; self.m.limbs.len()];
+        xs[0] = 1;
+        xs
+    }
+
+    /// Multiplies two [`CtNatural`]s modulo the modulus of a [`CtModulus`], in constant time.
+    ///
+    /// $f(x, y) = z$, where $x, y, z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` do not have the declared bit length of `self`, or if they are greater
+    /// than or equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::CtNatural;
+    /// use malachite_nz::ct::modulus::CtModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = CtModulus::new(&Natural::from(497u32), 64);
+    /// let x = CtNatural::from_natural(&Natural::from(100u32), 64);
+    /// let y = CtNatural::from_natural(&Natural::from(200u32), 64);
+    /// assert_eq!(m.mod_mul(&x, &y).to_natural(), 120);
+    /// ```
+    pub fn mod_mul(&self, x: &CtNatural, y: &CtNatural) -> CtNatural {
+        self.assert_reduced(x);
+        self.assert_reduced(y);
+        let product = self.mont_mul(&x.limbs, &y.limbs);
+        CtNatural::from_limbs(self.mont_mul(&product, &self.r_squared), self.m.bits)
+    }
+
+    /// Raises a [`CtNatural`] to a [`CtNatural`] power modulo the modulus of a [`CtModulus`], in
+    /// constant time.
+    ///
+    /// The exponent may have any declared bit length, which, unlike its value, affects the running
+    /// time. It is processed in fixed-width windows, and each window's table entry is found by
+    /// reading the whole table.
+    ///
+    /// $f(x, e) = y$, where $x, y < m$ and $x^e \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^2 k)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.bits()`, and $k$ is `exp.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have the declared bit length of `self`, or if it is greater than or
+    /// equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::CtNatural;
+    /// use malachite_nz::ct::modulus::CtModulus;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = CtModulus::new(&Natural::from(497u32), 64);
+    /// let x = CtNatural::from_natural(&Natural::from(4u32), 64);
+    /// let exp = CtNatural::from_natural(&Natural::from(13u32), 32);
+    /// assert_eq!(m.mod_pow(&x, &exp).to_natural(), 445);
+    /// ```
+    pub fn mod_pow(&self, x: &CtNatural, exp: &CtNatural) -> CtNatural {
+        self.assert_reduced(x);
+        let n = self.m.limbs.len();
+        let table_len = 1 << WINDOW_WIDTH;
+        let x_mont = self.mont_mul(&x.limbs, &self.r_squared);
+        let mut table = Vec::with_capacity(table_len);
+        table.push(self.one.clone());
+        table.push(x_mont.clone());
+        for i in 2..table_len {
+            let entry = self.mont_mul(&table[i - 1], &x_mont);
+            table.push(entry);
+        }
+        let mut acc = self.one.clone();
+        let mut entry = vec![0; n];
+        for window in (0..exp.bits.div_ceil(WINDOW_WIDTH)).rev() {
+            for _ in 0..WINDOW_WIDTH {
+                acc = self.mont_mul(&acc, &acc);
+            }
+            let bit = window * WINDOW_WIDTH;
+            let digit = (exp.limbs[usize::exact_from(bit / Limb::WIDTH)] >> (bit % Limb::WIDTH))
+                & ((1 << WINDOW_WIDTH) - 1);
+            for (i, t) in table.iter().enumerate() {
+                let mask = limb_mask(limb_is_zero(digit ^ Limb::wrapping_from(i)));
+                limbs_ct_assign(&mut entry, t, mask);
+            }
+            acc = self.mont_mul(&acc, &entry);
+        }
+        CtNatural::from_limbs(self.mont_mul(&acc, &self.padded_one()), self.m.bits)
+    }
+
+    /// Computes the multiplicative inverse of a [`CtNatural`] modulo the modulus of a
+    /// [`CtModulus`], in constant time.
+    ///
+    /// Returns the inverse and [`Choice::TRUE`] if the inverse exists. Otherwise, returns 0 and
+    /// [`Choice::FALSE`].
+    ///
+    /// $f(x) = (y, \text{true})$, where $x, y < m$ and $xy \equiv 1 \mod m$, if such a $y$ exists,
+    /// and $f(x) = (0, \text{false})$ otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have the declared bit length of `self`, or if it is greater than or
+    /// equal to the modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::ct::modulus::CtModulus;
+    /// use malachite_nz::ct::{Choice, CtNatural};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let m = CtModulus::new(&Natural::from(497u32), 64);
+    /// let (inverse, exists) = m.mod_inverse(&CtNatural::from_natural(&Natural::from(100u32), 64));
+    /// assert_eq!(exists, Choice::TRUE);
+    /// assert_eq!(inverse.to_natural(), 333);
+    ///
+    /// let (inverse, exists) = m.mod_inverse(&CtNatural::from_natural(&Natural::from(71u32), 64));
+    /// assert_eq!(exists, Choice::FALSE);
+    /// assert_eq!(inverse.to_natural(), 0);
+    /// ```
+    ///
+    /// This is equivalent to `mpn_sec_invert` from `mpn/generic/sec_invert.c`, GMP 6.2.1.
+    pub fn mod_inverse(&self, x: &CtNatural) -> (CtNatural, Choice) {
+        self.assert_reduced(x);
+        let ms = &self.m.limbs;
+        // Invariants: a ≡ ux and b ≡ vx mod m, and b is odd. Each step makes a even, possibly by
+        // subtracting b from it, and then halves it; after 2 * bits steps, a is 0 and b is
+        // gcd(x, m).
+        let mut a = x.limbs.clone();
+        let mut b = ms.clone();
+        let mut u = self.padded_one();
+        let mut v = vec![0; ms.len()];
+        for _ in 0..self.m.bits << 1 {
+            let odd = limb_mask(a[0] & 1);
+            let swap = limb_mask(limbs_ct_sub_in_place(&mut a, &b, odd));
+            limbs_ct_add_in_place(&mut b, &a, swap);
+            limbs_ct_neg_in_place(&mut a, swap);
+            limbs_ct_swap(&mut u, &mut v, swap);
+            let borrow = limbs_ct_sub_in_place(&mut u, &v, odd);
+            limbs_ct_add_in_place(&mut u, ms, limb_mask(borrow));
+            limbs_ct_shr_1_in_place(&mut a);
+            let u_odd = limb_mask(u[0] & 1);
+            limbs_ct_shr_1_in_place(&mut u);
+            limbs_ct_add_in_place(&mut u, &self.half, u_odd);
+        }
+        let exists = limbs_ct_eq(&b, &self.padded_one());
+        let zero = vec![0; ms.len()];
+        limbs_ct_assign(&mut v, &zero, limb_mask(exists ^ 1));
+        (CtNatural::from_limbs(v, self.m.bits), Choice(exists))
+    }
+}
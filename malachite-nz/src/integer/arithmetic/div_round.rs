@@ -50,6 +50,21 @@ impl DivRound<Self> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -193,6 +208,21 @@ impl DivRound<&Self> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -336,6 +366,21 @@ impl DivRound<Integer> for &Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -481,6 +526,21 @@ impl DivRound<&Integer> for &Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -0,0 +1,121 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{AddMulAssign, Parity};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// The secant numbers S_0 through S_{len - 1}, where S_k = |E_{2k}|, computed in place by the
+// integer-only algorithm of Brent and Harvey, "Fast computation of Bernoulli, Tangent and Secant
+// numbers" (2011). The table starts out as k!; pass k then applies one step of the boustrophedon
+// transform to the entries from k + 1 on, after which S_k is final.
+fn secant_numbers(len: usize) -> Vec<Natural> {
+    let mut s = Vec::with_capacity(len);
+    if len == 0 {
+        return s;
+    }
+    s.push(Natural::ONE);
+    for k in 1..len {
+        let next = &s[k - 1] * Natural::from(k);
+        s.push(next);
+    }
+    for k in 1..len {
+        for j in k + 1..len {
+            let (lo, hi) = s.split_at_mut(j);
+            let x = &mut hi[0];
+            *x *= Natural::from(j - k + 1);
+            x.add_mul_assign(&lo[j - 1], Natural::from(j - k));
+        }
+    }
+    s
+}
+
+/// Computes the first `len` Euler numbers: $E_0$ through $E_{\mathrm{len} - 1}$.
+///
+/// The Euler numbers are the coefficients of the Taylor series
+/// $$
+/// \operatorname{sech} x = \sum_{n=0}^\infty \frac{E_n}{n!} x^n.
+/// $$
+/// They vanish at odd $n$ and alternate in sign at even $n$: $E_0 = 1$, $E_2 = -1$, $E_4 = 5$, and
+/// so on. The absolute values $|E_{2k}|$, the secant numbers, are computed together with the
+/// integer-only boustrophedon recurrence of Brent and Harvey.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n^3 \log n)$
+///
+/// $M(n) = O(n^2 \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `len`.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::integer::arithmetic::euler_number::euler_numbers_prefix;
+///
+/// assert_eq!(
+///     euler_numbers_prefix(11)
+///         .iter()
+///         .map(|e| e.to_string())
+///         .collect::<Vec<_>>(),
+///     ["1", "0", "-1", "0", "5", "0", "-61", "0", "1385", "0", "-50521"]
+/// );
+/// assert!(euler_numbers_prefix(0).is_empty());
+/// ```
+pub fn euler_numbers_prefix(len: u64) -> Vec<Integer> {
+    let len = usize::exact_from(len);
+    let mut out = Vec::with_capacity(len);
+    for (k, s) in secant_numbers(len.div_ceil(2)).into_iter().enumerate() {
+        out.push(Integer::from_sign_and_abs(k.even(), s));
+        if out.len() < len {
+            out.push(Integer::ZERO);
+        }
+    }
+    out
+}
+
+impl Integer {
+    /// Computes the $n$th Euler number $E_n$.
+    ///
+    /// The Euler numbers are the coefficients of the Taylor series
+    /// $$
+    /// \operatorname{sech} x = \sum_{n=0}^\infty \frac{E_n}{n!} x^n.
+    /// $$
+    /// $E_n$ is zero when $n$ is odd, and $(-1)^{n/2}$ times the number of alternating
+    /// permutations of $n$ elements when $n$ is even. $|E_n|$ has $\Theta(n \log n)$ bits.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::euler_number(0), 1);
+    /// assert_eq!(Integer::euler_number(1), 0);
+    /// assert_eq!(Integer::euler_number(2), -1);
+    /// assert_eq!(Integer::euler_number(10), -50521);
+    /// assert_eq!(
+    ///     Integer::euler_number(50).to_string(),
+    ///     "-6053285248188621896314383785111649088103498225146815121"
+    /// );
+    /// ```
+    pub fn euler_number(n: u64) -> Self {
+        if n.odd() {
+            return Self::ZERO;
+        }
+        let k = n >> 1;
+        let s = secant_numbers(usize::exact_from(k + 1)).pop().unwrap();
+        Self::from_sign_and_abs(k.even(), s)
+    }
+}
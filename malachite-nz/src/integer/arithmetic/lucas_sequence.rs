@@ -0,0 +1,167 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModMulPrecomputed, ModShl, ModSquarePrecomputed, ModSub, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::BitIterable;
+
+impl Integer {
+    /// Computes the $n$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$, returning $(U_n,
+    /// V_n)$.
+    ///
+    /// The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and the recurrence
+    /// $X_{k+1} = PX_k - QX_{k-1}$, which both sequences satisfy. With $P = 1$ and $Q = -1$ they
+    /// are the Fibonacci and Lucas numbers.
+    ///
+    /// The terms are computed by walking down the bits of $n$ while keeping $U_k$ and $U_{k+1}$,
+    /// using
+    /// $$
+    /// U_{2k} = U_k(2U_{k+1} - PU_k), \quad U_{2k+1} = U_{k+1}^2 - QU_k^2, \quad U_{2k+2} =
+    /// U_{k+1}(PU_{k+1} - 2QU_k),
+    /// $$
+    /// and finally $V_n = 2U_{n+1} - PU_n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm \log (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is
+    /// `max(p.significant_bits(), q.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// // Fibonacci and Lucas numbers
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(&Integer::from(1), &Integer::from(-1), 10).to_debug_string(),
+    ///     "(55, 123)"
+    /// );
+    /// // Mersenne numbers
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(&Integer::from(3), &Integer::from(2), 10).to_debug_string(),
+    ///     "(1023, 1025)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(&Integer::from(-4), &Integer::from(7), 5).to_debug_string(),
+    ///     "(-31, 236)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::lucas_sequence(&Integer::from(5), &Integer::from(3), 0).to_debug_string(),
+    ///     "(0, 2)"
+    /// );
+    /// ```
+    pub fn lucas_sequence(p: &Self, q: &Self, n: u64) -> (Self, Self) {
+        // Invariant: u = U_k and u_next = U_{k + 1}, where k is the prefix of n that has been
+        // processed.
+        let mut u = Self::ZERO;
+        let mut u_next = Self::ONE;
+        for bit in n.bits().rev() {
+            let u_square = (&u).square();
+            let u_next_square = (&u_next).square();
+            let product = u * &u_next;
+            let u_odd = &u_next_square - q * &u_square;
+            if bit {
+                u_next = p * u_next_square - ((q * product) << 1u32);
+                u = u_odd;
+            } else {
+                u = (product << 1u32) - p * u_square;
+                u_next = u_odd;
+            }
+        }
+        let v = (u_next << 1u32) - p * &u;
+        (u, v)
+    }
+
+    /// Computes the $n$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo a
+    /// [`Natural`] $m$, returning $(U_n \bmod m, V_n \bmod m)$.
+    ///
+    /// See [`Integer::lucas_sequence`] for the definitions. The computation uses no divisions, so
+    /// $m$ need not be odd or coprime to anything, and $n$ may be a large [`Natural`], as in the
+    /// Lucas probable-prime test and in the $n + 1$ primality proofs.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(p.significant_bits(), q.significant_bits(), m.significant_bits())`, and $k$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::mod_lucas_sequence(
+    ///         &Integer::from(1),
+    ///         &Integer::from(-1),
+    ///         &Natural::from(10u32),
+    ///         &Natural::from(7u32)
+    ///     )
+    ///     .to_debug_string(),
+    ///     "(6, 4)"
+    /// );
+    /// // U_{p + 1} ≡ 0 mod p for a prime p with (D/p) = -1, where D = P^2 - 4Q
+    /// assert_eq!(
+    ///     Integer::mod_lucas_sequence(
+    ///         &Integer::from(1),
+    ///         &Integer::from(-1),
+    ///         &Natural::from(1000000008u32),
+    ///         &Natural::from(1000000007u32)
+    ///     )
+    ///     .to_debug_string(),
+    ///     "(0, 1000000005)"
+    /// );
+    /// ```
+    pub fn mod_lucas_sequence(p: &Self, q: &Self, n: &Natural, m: &Natural) -> (Natural, Natural) {
+        assert_ne!(*m, 0u32, "division by zero");
+        let m_integer = Self::from(m);
+        let p = p.mod_op(&m_integer).unsigned_abs();
+        let q = q.mod_op(m_integer).unsigned_abs();
+        let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+        // Invariant: u = U_k and u_next = U_{k + 1} mod m, where k is the prefix of n that has been
+        // processed.
+        let mut u = Natural::ZERO;
+        let mut u_next = Natural::ONE % m;
+        for bit in n.bits().rev() {
+            let u_square = (&u).mod_square_precomputed(m, &data);
+            let u_next_square = (&u_next).mod_square_precomputed(m, &data);
+            let product = u.mod_mul_precomputed(&u_next, m, &data);
+            let u_odd = (&u_next_square).mod_sub((&q).mod_mul_precomputed(&u_square, m, &data), m);
+            if bit {
+                u_next = (&p).mod_mul_precomputed(u_next_square, m, &data).mod_sub(
+                    (&q).mod_mul_precomputed(product, m, &data).mod_shl(1u32, m),
+                    m,
+                );
+                u = u_odd;
+            } else {
+                u = product
+                    .mod_shl(1u32, m)
+                    .mod_sub((&p).mod_mul_precomputed(u_square, m, &data), m);
+                u_next = u_odd;
+            }
+        }
+        let v = u_next
+            .mod_shl(1u32, m)
+            .mod_sub(p.mod_mul_precomputed(&u, m, &data), m);
+        (u, v)
+    }
+}
@@ -82,6 +82,10 @@ pub mod eq_mod;
 /// Implementations of [`EqModPowerOf2`](malachite_base::num::arithmetic::traits::EqModPowerOf2), a
 /// trait for determining whether one number is equal to another modulo $2^k$.
 pub mod eq_mod_power_of_2;
+/// [`Integer::euler_number`](super::Integer::euler_number) and
+/// [`euler_numbers_prefix`](euler_number::euler_numbers_prefix), functions for computing Euler
+/// numbers.
+pub mod euler_number;
 /// Implementations of [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd), a
 /// trait for computing the extended GCD of two numbers.
 pub mod extended_gcd;
@@ -90,6 +94,10 @@ pub mod extended_gcd;
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
 /// computing the Legendre, Jacobi, and Kronecker symbols of two numbers.
 pub mod kronecker_symbol;
+/// [`Integer::lucas_sequence`](super::Integer::lucas_sequence) and
+/// [`Integer::mod_lucas_sequence`](super::Integer::mod_lucas_sequence), functions for computing
+/// terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$.
+pub mod lucas_sequence;
 /// Implementations of [`ModEuclidean`](malachite_base::num::arithmetic::traits::ModEuclidean) and
 /// [`ModEuclideanAssign`](malachite_base::num::arithmetic::traits::ModEuclideanAssign), traits for
 /// finding the remainder of two numbers, where the remainder is nonnegative.
@@ -875,6 +883,10 @@ pub mod sqrt;
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
 /// number.
 pub mod square;
+/// Implementations of [`StirlingNumber1`](malachite_base::num::arithmetic::traits::StirlingNumber1)
+/// and [`StirlingNumber2`](malachite_base::num::arithmetic::traits::StirlingNumber2), traits for
+/// computing Stirling numbers of the first and second kinds.
+pub mod stirling_number;
 /// Subtraction of [`Integer`](super::Integer)s.
 pub mod sub;
 /// Implementations of [`SubMul`](malachite_base::num::arithmetic::traits::SubMul) and
@@ -44,6 +44,21 @@ impl RoundToMultiple<Self> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -204,6 +219,21 @@ impl RoundToMultiple<&Self> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -364,6 +394,21 @@ impl RoundToMultiple<Integer> for &Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -526,6 +571,21 @@ impl RoundToMultiple<&Integer> for &Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
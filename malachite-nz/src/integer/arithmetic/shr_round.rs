@@ -107,6 +107,21 @@ macro_rules! impl_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{Odd}) = \begin{cases}
+            ///     x & \text{if} \\quad q \in \Z, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -169,6 +184,21 @@ macro_rules! impl_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{Odd}) = \begin{cases}
+            ///     x & \text{if} \\quad q \in \Z, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -293,6 +323,21 @@ macro_rules! impl_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{Odd}) = \begin{cases}
+            ///     x & \text{if} \\quad q \in \Z, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
@@ -358,6 +403,21 @@ macro_rules! impl_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) =
+            ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{Odd}) = \begin{cases}
+            ///     x & \text{if} \\quad q \in \Z, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
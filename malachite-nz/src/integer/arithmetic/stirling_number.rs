@@ -0,0 +1,66 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Parity, StirlingNumber1, StirlingNumber2};
+
+impl StirlingNumber1 for Integer {
+    /// Computes the signed Stirling number of the first kind $s(n, k) = (-1)^{n-k} \left[{n \atop
+    /// k}\right]$, where $\left[{n \atop k}\right]$ is the number of permutations of $n$ elements
+    /// with exactly $k$ cycles.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingNumber1;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::stirling_number_1(0, 0), 1);
+    /// assert_eq!(Integer::stirling_number_1(4, 2), 11);
+    /// assert_eq!(Integer::stirling_number_1(5, 2), -50);
+    /// assert_eq!(Integer::stirling_number_1(10, 3), -1172700);
+    /// ```
+    ///
+    /// This is equivalent to `arith_stirling_number_1` from FLINT 3.6.0.
+    fn stirling_number_1(n: u64, k: u64) -> Self {
+        let c = Self::from(Natural::stirling_number_1(n, k));
+        if k <= n && (n - k).odd() { -c } else { c }
+    }
+}
+
+impl StirlingNumber2 for Integer {
+    /// Computes the Stirling number of the second kind $\left\\{{n \atop k}\right\\}$: the number
+    /// of ways to partition a set of $n$ elements into $k$ nonempty subsets.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingNumber2;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::stirling_number_2(5, 2), 15);
+    /// assert_eq!(Integer::stirling_number_2(10, 3), 9330);
+    /// ```
+    #[inline]
+    fn stirling_number_2(n: u64, k: u64) -> Self {
+        Self::from(Natural::stirling_number_2(n, k))
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use malachite_base::num::factorization::traits::{IsPrime, ProvePrime};
+
+impl IsPrime for Integer {
+    /// Tests whether an [`Integer`] is prime.
+    ///
+    /// Negative numbers are never prime. For nonnegative numbers, this has the same behavior as the
+    /// [`Natural`](crate::natural::Natural) implementation: the result is always correct for values
+    /// less than $2^{64}$, and larger values are checked with the Baillie–PSW test, for which no
+    /// counterexample is known.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::IsPrime;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(5).is_prime(), true);
+    /// assert_eq!(Integer::from(6).is_prime(), false);
+    /// assert_eq!(Integer::from(-5).is_prime(), false);
+    /// ```
+    #[inline]
+    fn is_prime(&self) -> bool {
+        *self > 0u32 && self.unsigned_abs_ref().is_prime()
+    }
+}
+
+impl ProvePrime for Integer {
+    /// Determines, with a proof, whether an [`Integer`] is prime.
+    ///
+    /// Negative numbers are never prime. For nonnegative numbers, this has the same behavior as the
+    /// [`Natural`](crate::natural::Natural) implementation, returning `None` when no proof could be
+    /// found either way.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::ProvePrime;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(5).prove_prime(), Some(true));
+    /// assert_eq!(Integer::from(6).prove_prime(), Some(false));
+    /// assert_eq!(Integer::from(-5).prove_prime(), Some(false));
+    /// ```
+    #[inline]
+    fn prove_prime(&self) -> Option<bool> {
+        if *self > 0u32 {
+            self.unsigned_abs_ref().prove_prime()
+        } else {
+            Some(false)
+        }
+    }
+}
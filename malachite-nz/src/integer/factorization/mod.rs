@@ -10,6 +10,10 @@
 /// [`ExpressAsPower`](malachite_base::num::factorization::traits::ExpressAsPower), traits for
 /// testing if a number is a perfect power and, if it is, expressing it as such.
 pub mod is_power;
+/// Implementations of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime) and
+/// [`ProvePrime`](malachite_base::num::factorization::traits::ProvePrime), traits for testing a
+/// number for primality.
+pub mod is_prime;
 /// Implementations of [`RemovePower`](malachite_base::num::factorization::traits::RemovePower) and
 /// [`RemovePowerAssign`](malachite_base::num::factorization::traits::RemovePowerAssign), traits for
 /// dividing out the largest power of a factor.
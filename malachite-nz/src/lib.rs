@@ -227,6 +227,13 @@ pub mod natural;
 /// [`Integer`](integer::Integer), a type representing integers with arbitrarily large absolute
 /// values.
 pub mod integer;
+/// [`ModNatural`](mod_natural::ModNatural), a type representing residue classes modulo a positive
+/// [`Natural`](natural::Natural).
+pub mod mod_natural;
+/// Constant-time modular arithmetic, comparison, and selection on
+/// [`Natural`](natural::Natural)s padded to a declared bit length, for cryptographic use.
+#[cfg(feature = "constant_time")]
+pub mod ct;
 
 #[cfg(feature = "test_build")]
 pub mod test_util;
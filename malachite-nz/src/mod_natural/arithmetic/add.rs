@@ -0,0 +1,217 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use core::ops::{Add, AddAssign};
+use malachite_base::num::arithmetic::traits::{ModAdd, ModAddAssign};
+
+impl Add<Self> for ModNatural {
+    type Output = Self;
+
+    /// Adds two [`ModNatural`]s. Both [`ModNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((x + y).to_string(), "2 mod 7");
+    /// ```
+    #[inline]
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl Add<&Self> for ModNatural {
+    type Output = Self;
+
+    /// Adds two [`ModNatural`]s. The first [`ModNatural`] is taken by value and the second by
+    /// reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((x + &y).to_string(), "2 mod 7");
+    /// ```
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl Add<ModNatural> for &ModNatural {
+    type Output = ModNatural;
+
+    /// Adds two [`ModNatural`]s. The first [`ModNatural`] is taken by reference and the second by
+    /// value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((&x + y).to_string(), "2 mod 7");
+    /// ```
+    #[inline]
+    fn add(self, mut other: ModNatural) -> ModNatural {
+        other += self;
+        other
+    }
+}
+
+impl Add<&ModNatural> for &ModNatural {
+    type Output = ModNatural;
+
+    /// Adds two [`ModNatural`]s. Both [`ModNatural`]s are taken by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((&x + &y).to_string(), "2 mod 7");
+    /// ```
+    fn add(self, other: &ModNatural) -> ModNatural {
+        self.assert_same_modulus(other);
+        ModNatural {
+            value: (&self.value).mod_add(&other.value, &*self.m),
+            m: self.m.clone(),
+        }
+    }
+}
+
+impl AddAssign<Self> for ModNatural {
+    /// Adds a [`ModNatural`] to another [`ModNatural`] in place. The [`ModNatural`] on the
+    /// right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// x += y;
+    /// assert_eq!(x.to_string(), "2 mod 7");
+    /// ```
+    fn add_assign(&mut self, other: Self) {
+        self.assert_same_modulus(&other);
+        self.value.mod_add_assign(other.value, &*self.m);
+    }
+}
+
+impl AddAssign<&Self> for ModNatural {
+    /// Adds a [`ModNatural`] to another [`ModNatural`] in place. The [`ModNatural`] on the
+    /// right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// x += &y;
+    /// assert_eq!(x.to_string(), "2 mod 7");
+    /// ```
+    fn add_assign(&mut self, other: &Self) {
+        self.assert_same_modulus(other);
+        self.value.mod_add_assign(&other.value, &*self.m);
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use core::ops::{Div, DivAssign};
+
+impl Div<Self> for ModNatural {
+    type Output = Self;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`]. Both [`ModNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli or if the value of `other` is not
+    /// invertible modulo $m$; see [`ModNatural::inverse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(6u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((x / y).to_string(), "5 mod 7");
+    /// ```
+    #[inline]
+    fn div(mut self, other: Self) -> Self {
+        self /= other;
+        self
+    }
+}
+
+impl Div<&Self> for ModNatural {
+    type Output = Self;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`]. The first [`ModNatural`] is taken by
+    /// value and the second by reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli or if the value of `other` is not
+    /// invertible modulo $m$; see [`ModNatural::inverse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(6u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((x / &y).to_string(), "5 mod 7");
+    /// ```
+    #[inline]
+    fn div(mut self, other: &Self) -> Self {
+        self /= other;
+        self
+    }
+}
+
+impl Div<ModNatural> for &ModNatural {
+    type Output = ModNatural;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`]. The first [`ModNatural`] is taken by
+    /// reference and the second by value.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli or if the value of `other` is not
+    /// invertible modulo $m$; see [`ModNatural::inverse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(6u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((&x / y).to_string(), "5 mod 7");
+    /// ```
+    #[inline]
+    fn div(self, other: ModNatural) -> ModNatural {
+        self / &other
+    }
+}
+
+impl Div<&ModNatural> for &ModNatural {
+    type Output = ModNatural;
+
+    /// Divides a [`ModNatural`] by another [`ModNatural`]. Both [`ModNatural`]s are taken by
+    /// reference.
+    ///
+    /// $f(x, y) = z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli or if the value of `other` is not
+    /// invertible modulo $m$; see [`ModNatural::inverse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(6u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// assert_eq!((&x / &y).to_string(), "5 mod 7");
+    /// ```
+    fn div(self, other: &ModNatural) -> ModNatural {
+        self.assert_same_modulus(other);
+        self * other
+            .inverse()
+            .expect("Division by a non-invertible residue")
+    }
+}
+
+impl DivAssign<Self> for ModNatural {
+    /// Divides a [`ModNatural`] by another [`ModNatural`] in place. The [`ModNatural`] on the
+    /// right-hand side is taken by value.
+    ///
+    /// $x \gets z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli or if the value of `other` is not
+    /// invertible modulo $m$; see [`ModNatural::inverse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = ModNatural::new(Natural::from(6u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// x /= y;
+    /// assert_eq!(x.to_string(), "5 mod 7");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self /= &other;
+    }
+}
+
+impl DivAssign<&Self> for ModNatural {
+    /// Divides a [`ModNatural`] by another [`ModNatural`] in place. The [`ModNatural`] on the
+    /// right-hand side is taken by reference.
+    ///
+    /// $x \gets z$, where $z < m$ and $yz \equiv x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different moduli or if the value of `other` is not
+    /// invertible modulo $m$; see [`ModNatural::inverse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = ModNatural::new(Natural::from(6u32), Natural::from(7u32));
+    /// let y = x.with_value(Natural::from(4u32));
+    /// x /= &y;
+    /// assert_eq!(x.to_string(), "5 mod 7");
+    /// ```
+    fn div_assign(&mut self, other: &Self) {
+        self.assert_same_modulus(other);
+        *self *= other
+            .inverse()
+            .expect("Division by a non-invertible residue");
+    }
+}
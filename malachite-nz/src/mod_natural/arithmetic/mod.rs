@@ -0,0 +1,22 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Addition of [`ModNatural`](super::ModNatural)s.
+pub mod add;
+/// Division of [`ModNatural`](super::ModNatural)s, via multiplication by a modular inverse.
+pub mod div;
+/// Multiplication of [`ModNatural`](super::ModNatural)s.
+pub mod mul;
+/// Negation of a [`ModNatural`](super::ModNatural).
+pub mod neg;
+/// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
+/// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
+/// to a power.
+pub mod pow;
+/// Subtraction of [`ModNatural`](super::ModNatural)s.
+pub mod sub;
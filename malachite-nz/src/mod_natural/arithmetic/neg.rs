@@ -0,0 +1,113 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::{ModNeg, ModNegAssign, NegAssign};
+
+impl Neg for ModNatural {
+    type Output = Self;
+
+    /// Negates a [`ModNatural`], taking it by value.
+    ///
+    /// $f(x) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (-ModNatural::new(Natural::from(3u32), Natural::from(7u32))).to_string(),
+    ///     "4 mod 7"
+    /// );
+    /// assert_eq!(
+    ///     (-ModNatural::new(Natural::from(0u32), Natural::from(7u32))).to_string(),
+    ///     "0 mod 7"
+    /// );
+    /// ```
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &ModNatural {
+    type Output = ModNatural;
+
+    /// Negates a [`ModNatural`], taking it by reference.
+    ///
+    /// $f(x) = y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (-&ModNatural::new(Natural::from(3u32), Natural::from(7u32))).to_string(),
+    ///     "4 mod 7"
+    /// );
+    /// assert_eq!(
+    ///     (-&ModNatural::new(Natural::from(0u32), Natural::from(7u32))).to_string(),
+    ///     "0 mod 7"
+    /// );
+    /// ```
+    fn neg(self) -> ModNatural {
+        ModNatural {
+            value: (&self.value).mod_neg(&*self.m),
+            m: self.m.clone(),
+        }
+    }
+}
+
+impl NegAssign for ModNatural {
+    /// Negates a [`ModNatural`] in place.
+    ///
+    /// $x \gets y$, where $y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "4 mod 7");
+    /// ```
+    #[inline]
+    fn neg_assign(&mut self) {
+        self.value.mod_neg_assign(&*self.m);
+    }
+}
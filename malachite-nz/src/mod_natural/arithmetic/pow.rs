@@ -0,0 +1,197 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPow, ModPowAssign, Pow, PowAssign};
+
+impl Pow<u64> for ModNatural {
+    type Output = Self;
+
+    /// Raises a [`ModNatural`] to a [`u64`] power, taking the [`ModNatural`] by value.
+    ///
+    /// $f(x, n) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus_ref().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// assert_eq!(x.pow(100).to_string(), "4 mod 7");
+    /// ```
+    #[inline]
+    fn pow(mut self, exp: u64) -> Self {
+        self.pow_assign(exp);
+        self
+    }
+}
+
+impl Pow<u64> for &ModNatural {
+    type Output = ModNatural;
+
+    /// Raises a [`ModNatural`] to a [`u64`] power, taking the [`ModNatural`] by reference.
+    ///
+    /// $f(x, n) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus_ref().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// assert_eq!((&x).pow(100).to_string(), "4 mod 7");
+    /// ```
+    fn pow(self, exp: u64) -> ModNatural {
+        ModNatural {
+            value: (&self.value).mod_pow(Natural::from(exp), &*self.m),
+            m: self.m.clone(),
+        }
+    }
+}
+
+impl Pow<&Natural> for ModNatural {
+    type Output = Self;
+
+    /// Raises a [`ModNatural`] to a [`Natural`] power, taking the [`ModNatural`] by value and the
+    /// [`Natural`] by reference.
+    ///
+    /// $f(x, n) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus_ref().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// assert_eq!(x.pow(&Natural::from(100u32)).to_string(), "4 mod 7");
+    /// ```
+    #[inline]
+    fn pow(mut self, exp: &Natural) -> Self {
+        self.pow_assign(exp);
+        self
+    }
+}
+
+impl Pow<&Natural> for &ModNatural {
+    type Output = ModNatural;
+
+    /// Raises a [`ModNatural`] to a [`Natural`] power, taking both by reference.
+    ///
+    /// $f(x, n) = y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus_ref().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// assert_eq!((&x).pow(&Natural::from(100u32)).to_string(), "4 mod 7");
+    /// ```
+    fn pow(self, exp: &Natural) -> ModNatural {
+        ModNatural {
+            value: (&self.value).mod_pow(exp, &*self.m),
+            m: self.m.clone(),
+        }
+    }
+}
+
+impl PowAssign<u64> for ModNatural {
+    /// Raises a [`ModNatural`] to a [`u64`] power in place.
+    ///
+    /// $x \gets y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus_ref().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// x.pow_assign(100);
+    /// assert_eq!(x.to_string(), "4 mod 7");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: u64) {
+        self.value.mod_pow_assign(Natural::from(exp), &*self.m);
+    }
+}
+
+impl PowAssign<&Natural> for ModNatural {
+    /// Raises a [`ModNatural`] to a [`Natural`] power in place, taking the [`Natural`] by
+    /// reference.
+    ///
+    /// $x \gets y$, where $y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus_ref().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// x.pow_assign(&Natural::from(100u32));
+    /// assert_eq!(x.to_string(), "4 mod 7");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: &Natural) {
+        self.value.mod_pow_assign(exp, &*self.m);
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use core::fmt::{Debug, Display, Formatter, Result};
+
+impl Display for ModNatural {
+    /// Converts a [`ModNatural`] to a [`String`](alloc::string::String).
+    ///
+    /// The value is written first, followed by `" mod "` and the modulus. Any width or fill
+    /// options apply to the value only.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(3u32), Natural::from(7u32)).to_string(),
+    ///     "3 mod 7"
+    /// );
+    /// assert_eq!(
+    ///     format!(
+    ///         "{:03}",
+    ///         ModNatural::new(Natural::from(3u32), Natural::from(7u32))
+    ///     ),
+    ///     "003 mod 7"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.value, f)?;
+        write!(f, " mod {}", self.m)
+    }
+}
+
+impl Debug for ModNatural {
+    /// Converts a [`ModNatural`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(3u32), Natural::from(7u32)).to_debug_string(),
+    ///     "3 mod 7"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::sync::Arc;
+use malachite_base::named::Named;
+use malachite_base::num::arithmetic::traits::{ModInverse, ModIsReduced};
+
+/// A residue class of the integers modulo a positive [`Natural`] $m$.
+///
+/// A `ModNatural` stores a [`Natural`] reduced modulo $m$ together with $m$ itself, so that the
+/// usual arithmetic operators can be used in place of [`ModAdd`], [`ModMul`], and the other
+/// `Mod*` traits. The modulus is held in an [`Arc`], so values created from one another with
+/// [`ModNatural::with_value`] or by arithmetic share a single copy of it.
+///
+/// Combining two `ModNatural`s with different moduli panics.
+///
+/// [`ModAdd`]: malachite_base::num::arithmetic::traits::ModAdd
+/// [`ModMul`]: malachite_base::num::arithmetic::traits::ModMul
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct ModNatural {
+    pub(crate) value: Natural,
+    pub(crate) m: Arc<Natural>,
+}
+
+impl ModNatural {
+    /// Creates a [`ModNatural`] from a [`Natural`] and a modulus $m$, reducing the [`Natural`]
+    /// modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(3u32), Natural::from(7u32)).to_string(),
+    ///     "3 mod 7"
+    /// );
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(10u32), Natural::from(7u32)).to_string(),
+    ///     "3 mod 7"
+    /// );
+    /// ```
+    pub fn new(x: Natural, m: Natural) -> Self {
+        assert_ne!(m, 0u32, "The modulus must be positive");
+        Self {
+            value: if x.mod_is_reduced(&m) { x } else { x % &m },
+            m: Arc::new(m),
+        }
+    }
+
+    /// Creates a [`ModNatural`] with the same modulus as `self`, reducing the given [`Natural`]
+    /// modulo that modulus. The new value shares the modulus of `self` rather than copying it.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// assert_eq!(x.with_value(Natural::from(5u32)).to_string(), "5 mod 7");
+    /// assert_eq!(x.with_value(Natural::from(100u32)).to_string(), "2 mod 7");
+    /// ```
+    pub fn with_value(&self, x: Natural) -> Self {
+        Self {
+            value: if x.mod_is_reduced(&*self.m) {
+                x
+            } else {
+                x % &*self.m
+            },
+            m: self.m.clone(),
+        }
+    }
+
+    /// Returns a reference to the value of a [`ModNatural`], which is less than its modulus.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     *ModNatural::new(Natural::from(10u32), Natural::from(7u32)).value_ref(),
+    ///     3
+    /// );
+    /// ```
+    #[inline]
+    pub const fn value_ref(&self) -> &Natural {
+        &self.value
+    }
+
+    /// Returns a reference to the modulus of a [`ModNatural`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     *ModNatural::new(Natural::from(10u32), Natural::from(7u32)).modulus_ref(),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    pub fn modulus_ref(&self) -> &Natural {
+        &self.m
+    }
+
+    /// Converts a [`ModNatural`] into its value, which is less than its modulus, taking the
+    /// [`ModNatural`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(10u32), Natural::from(7u32)).into_value(),
+    ///     3
+    /// );
+    /// ```
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_value(self) -> Natural {
+        self.value
+    }
+
+    /// Computes the multiplicative inverse of a [`ModNatural`], returning `None` if it has none;
+    /// that is, if its value is not coprime to its modulus.
+    ///
+    /// $f(x) = \operatorname{Some}(y)$, where $xy \equiv 1 \mod m$, if such a $y$ exists.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus_ref().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::mod_natural::ModNatural;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(3u32), Natural::from(10u32))
+    ///         .inverse()
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "7 mod 10"
+    /// );
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(4u32), Natural::from(10u32)).inverse(),
+    ///     None
+    /// );
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        if *self.m == 1u32 {
+            // Modulo 1, every residue is 0, and 0 * 0 ≡ 1.
+            return Some(self.clone());
+        }
+        if self.value == 0u32 {
+            return None;
+        }
+        (&self.value).mod_inverse(&*self.m).map(|value| Self {
+            value,
+            m: self.m.clone(),
+        })
+    }
+
+    // Panics unless `self` and `other` have the same modulus. Moduli that are shared through the
+    // same `Arc` are recognized without being compared.
+    pub(crate) fn assert_same_modulus(&self, other: &Self) {
+        assert!(
+            Arc::ptr_eq(&self.m, &other.m) || self.m == other.m,
+            "Moduli must be equal, but {} != {}",
+            self.m,
+            other.m
+        );
+    }
+
+    // Returns true iff `self` is valid. To be valid, the modulus must be positive, and the value
+    // must be valid and less than the modulus. All `ModNatural`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.value.is_valid() && self.m.is_valid() && *self.m != 0u32 && self.value < *self.m
+    }
+}
+
+// Implements `Named` for `ModNatural`.
+impl_named!(ModNatural);
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Traits for converting [`ModNatural`]s to strings.
+pub mod conversion;
@@ -0,0 +1,92 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, CatalanNumber, DivExact};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+/// Computes the first `len` Catalan numbers: $C_0$ through $C_{\mathrm{len} - 1}$.
+///
+/// The numbers are generated by the recurrence $C_{i+1} = \frac{2(2i+1)}{i+2}C_i$, so each step
+/// is one small multiplication and one small exact division.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n^2)$
+///
+/// $M(n) = O(n^2)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `len`.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::arithmetic::catalan_number::catalan_numbers_prefix;
+///
+/// assert_eq!(
+///     catalan_numbers_prefix(8)
+///         .iter()
+///         .map(|c| c.to_string())
+///         .collect::<Vec<_>>(),
+///     ["1", "1", "2", "5", "14", "42", "132", "429"]
+/// );
+/// assert!(catalan_numbers_prefix(0).is_empty());
+/// ```
+pub fn catalan_numbers_prefix(len: u64) -> Vec<Natural> {
+    let mut cs = Vec::with_capacity(usize::exact_from(len));
+    if len == 0 {
+        return cs;
+    }
+    let mut c = Natural::ONE;
+    for i in 1..len {
+        let next = (&c * Natural::from((i << 2) - 2)).div_exact(Natural::from(i + 1));
+        cs.push(c);
+        c = next;
+    }
+    cs.push(c);
+    cs
+}
+
+impl CatalanNumber for Natural {
+    /// Computes the $n$th Catalan number.
+    ///
+    /// $$
+    /// C_n = \frac{1}{n+1}\binom{2n}{n}.
+    /// $$
+    ///
+    /// $C_n$ counts, among many other things, the binary trees with $n$ internal nodes and the
+    /// sequences of $n$ balanced pairs of parentheses.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Panics
+    /// Panics if $2n$ does not fit in a `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CatalanNumber;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::catalan_number(0), 1);
+    /// assert_eq!(Natural::catalan_number(4), 14);
+    /// assert_eq!(Natural::catalan_number(10), 16796);
+    /// assert_eq!(
+    ///     Natural::catalan_number(100).to_string(),
+    ///     "896519947090131496687170070074100632420837521538745909320"
+    /// );
+    /// ```
+    fn catalan_number(n: u64) -> Self {
+        Self::binomial_coefficient(Self::from(n.checked_mul(2).unwrap()), Self::from(n))
+            .div_exact(Self::from(n + 1))
+    }
+}
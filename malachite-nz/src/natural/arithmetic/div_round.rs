@@ -48,6 +48,15 @@ private_test_fn! {limbs_limb_div_round_limbs(n: Limb, ds: &[Limb], rm: RoundingM
                     (0, Less)
                 },
             ),
+            // 1 if 2 * n >= Natural::from_limbs_asc(ds); otherwise, 0
+            NearestTiesAway => Some(
+                if ds.len() == 2 && ds[1] == 1 && n.get_highest_bit() && (n << 1) >= ds[0] {
+                    (1, Greater)
+                } else {
+                    (0, Less)
+                },
+            ),
+            Odd => Some((1, Greater)),
         }
     }
 }}
@@ -80,6 +89,44 @@ fn div_round_assign_nearest(q: &mut Natural, r: &Natural, d: &Natural) -> Orderi
     }
 }
 
+// assumes r != 0
+fn div_round_nearest_ties_away(q: Natural, r: &Natural, d: &Natural) -> (Natural, Ordering) {
+    if double_cmp(r, d) == Less {
+        (q, Less)
+    } else {
+        (q.add_limb(1), Greater)
+    }
+}
+
+// assumes r != 0
+fn div_round_assign_nearest_ties_away(q: &mut Natural, r: &Natural, d: &Natural) -> Ordering {
+    if double_cmp(r, d) == Less {
+        Less
+    } else {
+        *q += Natural::ONE;
+        Greater
+    }
+}
+
+// assumes r != 0
+fn div_round_odd(q: Natural) -> (Natural, Ordering) {
+    if q.odd() {
+        (q, Less)
+    } else {
+        (q.add_limb(1), Greater)
+    }
+}
+
+// assumes r != 0
+fn div_round_assign_odd(q: &mut Natural) -> Ordering {
+    if q.odd() {
+        Less
+    } else {
+        *q += Natural::ONE;
+        Greater
+    }
+}
+
 impl DivRound<Self> for Natural {
     type Output = Self;
 
@@ -111,6 +158,21 @@ impl DivRound<Self> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -213,6 +275,21 @@ impl<'a> DivRound<&'a Self> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -315,6 +392,21 @@ impl DivRound<Natural> for &Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -384,6 +476,8 @@ impl DivRound<Natural> for &Natural {
                 Ceiling | Up => (q.add_limb(1), Greater),
                 Exact => panic!("Division is not exact"),
                 Nearest => div_round_nearest(q, &r, &other),
+                NearestTiesAway => div_round_nearest_ties_away(q, &r, &other),
+                Odd => div_round_odd(q),
             }
         }
     }
@@ -420,6 +514,21 @@ impl DivRound<&Natural> for &Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -489,6 +598,8 @@ impl DivRound<&Natural> for &Natural {
                 Ceiling | Up => (q.add_limb(1), Greater),
                 Exact => panic!("Division is not exact: {self} / {other}"),
                 Nearest => div_round_nearest(q, &r, other),
+                NearestTiesAway => div_round_nearest_ties_away(q, &r, other),
+                Odd => div_round_odd(q),
             }
         }
     }
@@ -568,6 +679,8 @@ impl DivRoundAssign<Self> for Natural {
                 }
                 Exact => panic!("Division is not exact"),
                 Nearest => div_round_assign_nearest(self, &r, &other),
+                NearestTiesAway => div_round_assign_nearest_ties_away(self, &r, &other),
+                Odd => div_round_assign_odd(self),
             }
         }
     }
@@ -647,6 +760,8 @@ impl<'a> DivRoundAssign<&'a Self> for Natural {
                 }
                 Exact => panic!("Division is not exact"),
                 Nearest => div_round_assign_nearest(self, &r, other),
+                NearestTiesAway => div_round_assign_nearest_ties_away(self, &r, other),
+                Odd => div_round_assign_odd(self),
             }
         }
     }
@@ -0,0 +1,221 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_context::ModContext;
+use crate::natural::arithmetic::mod_pow::get_window_size;
+use crate::platform::Limb;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use malachite_base::num::arithmetic::traits::DivRound;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode::*;
+
+/// A precomputation for raising a fixed [`Natural`] base to many different powers modulo a fixed
+/// [`Natural`] $m$.
+///
+/// The precomputation uses the comb method of Lim and Lee. An exponent of up to $L$ bits is
+/// written as $w$ interleaved pieces of $a = \lceil L/w \rceil$ bits each, where the $k$th piece
+/// consists of bits $ka, ka + 1, \ldots, ka + a - 1$. For each of the $2^w$ subsets of the pieces,
+/// the product of $g^{2^{ka}}$ over the pieces $k$ in the subset is stored. An exponentiation then
+/// takes only $a$ squarings and at most $a$ multiplications, rather than the roughly $L$ squarings
+/// that [`mod_pow`](malachite_base::num::arithmetic::traits::ModPow) performs. The number of pieces
+/// is chosen from $L$ so that the table stays small compared to the work it saves.
+///
+/// Arithmetic is done in a [`ModContext`], so residues are kept in Montgomery form when $m$ is odd.
+/// Exponents longer than $L$ bits are still accepted, but they are handled without the table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBaseModPow {
+    ctx: ModContext,
+    // The number of pieces, $w$.
+    teeth: u64,
+    // The number of bits in each piece, $a$.
+    spacing: u64,
+    // The $2^w$ table entries, each padded to the length of $m$ and stored consecutively. Entry $j$
+    // is the product of $g^{2^{ka}}$ over the set bits $k$ of $j$, so entry 0 is 1 and entry 1 is
+    // $g$.
+    table: Vec<Limb>,
+}
+
+impl FixedBaseModPow {
+    /// Creates a precomputation for raising `base` to powers modulo $m$, optimized for exponents of
+    /// up to `max_exp_bits` bits.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n, k) = O(n \max(1, k / \log k))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `max_exp_bits`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0 or if `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPow;
+    ///
+    /// let g = FixedBaseModPow::new(&Natural::from(4u32), Natural::from(497u32), 64);
+    /// assert_eq!(*g.modulus(), 497);
+    /// assert_eq!(g.max_exp_bits(), 66);
+    /// ```
+    pub fn new(base: &Natural, m: Natural, max_exp_bits: u64) -> Self {
+        let ctx = ModContext::new(m);
+        ctx.assert_reduced(base);
+        let max_exp_bits = max(max_exp_bits, 1);
+        let teeth = get_window_size(max_exp_bits);
+        let spacing = max_exp_bits.div_round(teeth, Ceiling).0;
+        let n = ctx.limb_len();
+        let mut scratch = vec![0; n << 1];
+        // The powers g^(2^(ka)) for k < teeth.
+        let mut tooth_powers = Vec::with_capacity(usize::exact_from(teeth));
+        tooth_powers.push(ctx.to_padded_limbs(&ctx.to_residue(base)));
+        for k in 1..tooth_powers.capacity() {
+            let mut power = tooth_powers[k - 1].clone();
+            let mut temp = vec![0; n];
+            for _ in 0..spacing {
+                ctx.limbs_square(&mut temp, &power, &mut scratch);
+                power.copy_from_slice(&temp);
+            }
+            tooth_powers.push(power);
+        }
+        let table_len = 1usize << teeth;
+        let mut table = vec![0; table_len * n];
+        table[..n].copy_from_slice(&ctx.to_padded_limbs(&ctx.one()));
+        for j in 1..table_len {
+            // Entry j is entry j' times the power for the highest set bit of j, where j' is j with
+            // that bit cleared.
+            let high_bit = usize::BITS - 1 - j.leading_zeros();
+            let rest = j ^ (1 << high_bit);
+            let (table_lo, table_hi) = table.split_at_mut(j * n);
+            ctx.limbs_mul(
+                &mut table_hi[..n],
+                &table_lo[rest * n..(rest + 1) * n],
+                &tooth_powers[usize::exact_from(high_bit)],
+                &mut scratch,
+            );
+        }
+        Self {
+            ctx,
+            teeth,
+            spacing,
+            table,
+        }
+    }
+
+    /// Returns the modulus of the precomputation.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPow;
+    ///
+    /// let g = FixedBaseModPow::new(&Natural::from(3u32), Natural::from(10u32), 8);
+    /// assert_eq!(*g.modulus(), 10);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        self.ctx.modulus()
+    }
+
+    /// Returns the number of exponent bits covered by the table. This is at least the
+    /// `max_exp_bits` that the precomputation was created with, and may be slightly greater.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPow;
+    ///
+    /// let g = FixedBaseModPow::new(&Natural::from(3u32), Natural::from(10u32), 100);
+    /// assert_eq!(g.max_exp_bits(), 100);
+    /// let g = FixedBaseModPow::new(&Natural::from(3u32), Natural::from(10u32), 101);
+    /// assert_eq!(g.max_exp_bits(), 104);
+    /// ```
+    #[inline]
+    pub const fn max_exp_bits(&self) -> u64 {
+        self.teeth * self.spacing
+    }
+
+    /// Raises the base to a [`Natural`] power modulo $m$.
+    ///
+    /// $f(k) = y$, where $y < m$ and $g^k \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(k n \log n \log\log n / \log k)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`,
+    /// and $k$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModPow, Pow};
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPow;
+    ///
+    /// let g = FixedBaseModPow::new(&Natural::from(4u32), Natural::from(497u32), 64);
+    /// assert_eq!(g.pow(&Natural::from(13u32)), 445);
+    /// assert_eq!(g.pow(&Natural::from(0u32)), 1);
+    ///
+    /// let g = FixedBaseModPow::new(&Natural::from(10u32), Natural::from(30u32), 16);
+    /// assert_eq!(g.pow(&Natural::from(1000u32)), 10);
+    ///
+    /// // Exponents longer than the table are handled too.
+    /// let e = Natural::from(10u32).pow(30);
+    /// assert_eq!(g.pow(&e), Natural::from(10u32).mod_pow(&e, Natural::from(30u32)));
+    /// ```
+    pub fn pow(&self, exp: &Natural) -> Natural {
+        let n = self.ctx.limb_len();
+        let exp_bits = exp.significant_bits();
+        if exp_bits > self.max_exp_bits() {
+            let base = Natural::from_limbs_asc(&self.table[n..n << 1]);
+            return self.ctx.from_residue(&self.ctx.pow(&base, exp));
+        }
+        let mut out = self.table[..n].to_vec();
+        let mut temp = vec![0; n];
+        let mut scratch = vec![0; n << 1];
+        let mut started = false;
+        // Column i holds bits i, a + i, 2a + i, and so on, so columns at or above the length of
+        // `exp` are empty.
+        for i in (0..min(exp_bits, self.spacing)).rev() {
+            if started {
+                self.ctx.limbs_square(&mut temp, &out, &mut scratch);
+                out.copy_from_slice(&temp);
+            }
+            let mut j = 0;
+            for k in (0..self.teeth).rev() {
+                j <<= 1;
+                if exp.get_bit(k * self.spacing + i) {
+                    j |= 1;
+                }
+            }
+            if j != 0 {
+                let entry = &self.table[j * n..(j + 1) * n];
+                if started {
+                    self.ctx.limbs_mul(&mut temp, &out, entry, &mut scratch);
+                    out.copy_from_slice(&temp);
+                } else {
+                    out.copy_from_slice(entry);
+                    started = true;
+                }
+            }
+        }
+        self.ctx.from_residue(&Natural::from_owned_limbs_asc(out))
+    }
+}
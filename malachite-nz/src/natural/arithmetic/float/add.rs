@@ -886,8 +886,8 @@ fn add_float_significands_same_prec_lt_w(
     } else {
         match rm {
             Exact => panic!("Inexact float addition"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && (sum & shift_bit) == 0) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && sum & shift_bit == 0) {
                     (sum, x_exp, Less)
                 } else if sum.overflowing_add_assign(shift_bit) {
                     (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater)
@@ -896,6 +896,13 @@ fn add_float_significands_same_prec_lt_w(
                 }
             }
             Floor | Down => (sum, x_exp, Less),
+            Odd => {
+                if sum & shift_bit == 0 {
+                    (sum | shift_bit, x_exp, Greater)
+                } else {
+                    (sum, x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if sum.overflowing_add_assign(shift_bit) {
                     (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater)
@@ -958,8 +965,8 @@ fn add_float_significands_same_prec_w(
     } else {
         match rm {
             Exact => panic!("Inexact float addition"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && sum.even()) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && sum.even()) {
                     (sum, x_exp, Less)
                 } else if sum.overflowing_add_assign(1) {
                     (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater)
@@ -968,6 +975,13 @@ fn add_float_significands_same_prec_w(
                 }
             }
             Floor | Down => (sum, x_exp, Less),
+            Odd => {
+                if sum.even() {
+                    (sum | 1, x_exp, Greater)
+                } else {
+                    (sum, x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if sum.overflowing_add_assign(1) {
                     (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater)
@@ -1090,8 +1104,8 @@ fn add_float_significands_same_prec_gt_w_lt_2w(
     } else {
         match rm {
             Exact => panic!("Inexact float addition"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && (sum_0 & shift_bit) == 0) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && sum_0 & shift_bit == 0) {
                     (sum_0, sum_1, x_exp, Less)
                 } else if sum_0.overflowing_add_assign(shift_bit) && sum_1.overflowing_add_assign(1)
                 {
@@ -1101,6 +1115,13 @@ fn add_float_significands_same_prec_gt_w_lt_2w(
                 }
             }
             Floor | Down => (sum_0, sum_1, x_exp, Less),
+            Odd => {
+                if sum_0 & shift_bit == 0 {
+                    (sum_0 | shift_bit, sum_1, x_exp, Greater)
+                } else {
+                    (sum_0, sum_1, x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if sum_0.overflowing_add_assign(shift_bit) && sum_1.overflowing_add_assign(1) {
                     (sum_0, LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater)
@@ -1202,8 +1223,8 @@ fn add_float_significands_same_prec_2w(
     } else {
         match rm {
             Exact => panic!("Inexact float addition"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && sum_0.even()) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && sum_0.even()) {
                     (sum_0, sum_1, x_exp, Less)
                 } else if sum_0.overflowing_add_assign(1) && sum_1.overflowing_add_assign(1) {
                     (sum_0, LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater)
@@ -1212,6 +1233,13 @@ fn add_float_significands_same_prec_2w(
                 }
             }
             Floor | Down => (sum_0, sum_1, x_exp, Less),
+            Odd => {
+                if sum_0.even() {
+                    (sum_0 | 1, sum_1, x_exp, Greater)
+                } else {
+                    (sum_0, sum_1, x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if sum_0.overflowing_add_assign(1) && sum_1.overflowing_add_assign(1) {
                     (sum_0, LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater)
@@ -1388,8 +1416,8 @@ fn add_float_significands_same_prec_gt_2w_lt_3w(
     } else {
         match rm {
             Exact => panic!("Inexact float addition"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && (sum_0 & shift_bit) == 0) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && sum_0 & shift_bit == 0) {
                     (sum_0, sum_1, sum_2, x_exp, Less)
                 } else {
                     if sum_0.overflowing_add_assign(shift_bit) {
@@ -1412,6 +1440,13 @@ fn add_float_significands_same_prec_gt_2w_lt_3w(
                 }
             }
             Floor | Down => (sum_0, sum_1, sum_2, x_exp, Less),
+            Odd => {
+                if sum_0 & shift_bit == 0 {
+                    (sum_0 | shift_bit, sum_1, sum_2, x_exp, Greater)
+                } else {
+                    (sum_0, sum_1, sum_2, x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if sum_0.overflowing_add_assign(shift_bit) {
                     sum_1.wrapping_add_assign(1);
@@ -1759,8 +1794,8 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
         } else {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest => {
-                    if out[0] & shift_bit == 0 {
+                Nearest | NearestTiesAway => {
+                    if rm == Nearest && out[0] & shift_bit == 0 {
                         (x_exp, Less)
                     } else {
                         if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -1771,6 +1806,14 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
                     }
                 }
                 Floor | Down => (x_exp, Less),
+                Odd => {
+                    if out[0] & shift_bit == 0 {
+                        out[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     if limbs_slice_add_limb_in_place(out, shift_bit) {
                         x_exp.saturating_add_assign(1);
@@ -1784,10 +1827,19 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
         if exp_diff > prec {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest | Floor | Down => {
+                Nearest | NearestTiesAway | Floor | Down => {
                     out.copy_from_slice(xs);
                     (x_exp, Less)
                 }
+                Odd => {
+                    out.copy_from_slice(xs);
+                    if out[0] & shift_bit == 0 {
+                        out[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     out.copy_from_slice(xs);
                     if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -1800,9 +1852,9 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
         } else {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     // Check if y was a power of 2
-                    if limbs_is_power_of_2(ys) && xs[0] & shift_bit == 0 {
+                    if rm == Nearest && limbs_is_power_of_2(ys) && xs[0] & shift_bit == 0 {
                         out.copy_from_slice(xs);
                         (x_exp, Less)
                     } else {
@@ -1818,6 +1870,15 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
                     out.copy_from_slice(xs);
                     (x_exp, Less)
                 }
+                Odd => {
+                    out.copy_from_slice(xs);
+                    if out[0] & shift_bit == 0 {
+                        out[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     out.copy_from_slice(xs);
                     if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -1857,10 +1918,10 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
             out[0] &= mask;
         }
         match rm {
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 if round_bit == 0 {
                     (x_exp, if sticky_bit != 0 { Less } else { Equal })
-                } else if sticky_bit == 0 && out[0] & shift_bit == 0 {
+                } else if rm == Nearest && sticky_bit == 0 && out[0] & shift_bit == 0 {
                     (x_exp, Less)
                 } else {
                     if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -1878,6 +1939,16 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
                     (x_exp, if inexact { Less } else { Equal })
                 }
             }
+            Odd => {
+                if round_bit == 0 && sticky_bit == 0 {
+                    (x_exp, Equal)
+                } else if out[0] & shift_bit == 0 {
+                    out[0] |= shift_bit;
+                    (x_exp, Greater)
+                } else {
+                    (x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if round_bit != 0 || sticky_bit != 0 {
                     if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -1941,8 +2012,8 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
         } else {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest => {
-                    if xs[0] & shift_bit == 0 {
+                Nearest | NearestTiesAway => {
+                    if rm == Nearest && xs[0] & shift_bit == 0 {
                         (x_exp, Less)
                     } else {
                         if limbs_slice_add_limb_in_place(xs, shift_bit) {
@@ -1953,6 +2024,14 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
                     }
                 }
                 Floor | Down => (x_exp, Less),
+                Odd => {
+                    if xs[0] & shift_bit == 0 {
+                        xs[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     if limbs_slice_add_limb_in_place(xs, shift_bit) {
                         x_exp.saturating_add_assign(1);
@@ -1966,7 +2045,15 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
         if exp_diff > prec {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest | Floor | Down => (x_exp, Less),
+                Nearest | NearestTiesAway | Floor | Down => (x_exp, Less),
+                Odd => {
+                    if xs[0] & shift_bit == 0 {
+                        xs[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     if limbs_slice_add_limb_in_place(xs, shift_bit) {
                         x_exp.saturating_add_assign(1);
@@ -1978,9 +2065,9 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
         } else {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     // Check if y was a power of 2
-                    if limbs_is_power_of_2(ys) && xs[0] & shift_bit == 0 {
+                    if rm == Nearest && limbs_is_power_of_2(ys) && xs[0] & shift_bit == 0 {
                         (x_exp, Less)
                     } else {
                         if limbs_slice_add_limb_in_place(xs, shift_bit) {
@@ -1991,6 +2078,14 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
                     }
                 }
                 Floor | Down => (x_exp, Less),
+                Odd => {
+                    if xs[0] & shift_bit == 0 {
+                        xs[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     if limbs_slice_add_limb_in_place(xs, shift_bit) {
                         x_exp.saturating_add_assign(1);
@@ -2029,10 +2124,10 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
             xs[0] &= mask;
         }
         match rm {
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 if round_bit == 0 {
                     (x_exp, if sticky_bit != 0 { Less } else { Equal })
-                } else if sticky_bit == 0 && xs[0] & shift_bit == 0 {
+                } else if rm == Nearest && sticky_bit == 0 && xs[0] & shift_bit == 0 {
                     (x_exp, Less)
                 } else {
                     if limbs_slice_add_limb_in_place(xs, shift_bit) {
@@ -2050,6 +2145,16 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
                     (x_exp, if inexact { Less } else { Equal })
                 }
             }
+            Odd => {
+                if round_bit == 0 && sticky_bit == 0 {
+                    (x_exp, Equal)
+                } else if xs[0] & shift_bit == 0 {
+                    xs[0] |= shift_bit;
+                    (x_exp, Greater)
+                } else {
+                    (x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if round_bit != 0 || sticky_bit != 0 {
                     if limbs_slice_add_limb_in_place(xs, shift_bit) {
@@ -2094,8 +2199,8 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
         } else {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest => {
-                    if ys[0] & shift_bit == 0 {
+                Nearest | NearestTiesAway => {
+                    if rm == Nearest && ys[0] & shift_bit == 0 {
                         (x_exp, Less)
                     } else {
                         if limbs_slice_add_limb_in_place(ys, shift_bit) {
@@ -2106,6 +2211,14 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
                     }
                 }
                 Floor | Down => (x_exp, Less),
+                Odd => {
+                    if ys[0] & shift_bit == 0 {
+                        ys[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     if limbs_slice_add_limb_in_place(ys, shift_bit) {
                         x_exp.saturating_add_assign(1);
@@ -2119,10 +2232,19 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
         if exp_diff > prec {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest | Floor | Down => {
+                Nearest | NearestTiesAway | Floor | Down => {
                     ys.copy_from_slice(xs);
                     (x_exp, Less)
                 }
+                Odd => {
+                    ys.copy_from_slice(xs);
+                    if ys[0] & shift_bit == 0 {
+                        ys[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     ys.copy_from_slice(xs);
                     if limbs_slice_add_limb_in_place(ys, shift_bit) {
@@ -2135,9 +2257,9 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
         } else {
             match rm {
                 Exact => panic!("Inexact float addition"),
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     // Check if y was a power of 2
-                    if limbs_is_power_of_2(ys) && xs[0] & shift_bit == 0 {
+                    if rm == Nearest && limbs_is_power_of_2(ys) && xs[0] & shift_bit == 0 {
                         ys.copy_from_slice(xs);
                         (x_exp, Less)
                     } else {
@@ -2153,6 +2275,15 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
                     ys.copy_from_slice(xs);
                     (x_exp, Less)
                 }
+                Odd => {
+                    ys.copy_from_slice(xs);
+                    if ys[0] & shift_bit == 0 {
+                        ys[0] |= shift_bit;
+                        (x_exp, Greater)
+                    } else {
+                        (x_exp, Less)
+                    }
+                }
                 Ceiling | Up => {
                     ys.copy_from_slice(xs);
                     if limbs_slice_add_limb_in_place(ys, shift_bit) {
@@ -2192,10 +2323,10 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
             ys[0] &= mask;
         }
         match rm {
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 if round_bit == 0 {
                     (x_exp, if sticky_bit != 0 { Less } else { Equal })
-                } else if sticky_bit == 0 && ys[0] & shift_bit == 0 {
+                } else if rm == Nearest && sticky_bit == 0 && ys[0] & shift_bit == 0 {
                     (x_exp, Less)
                 } else {
                     if limbs_slice_add_limb_in_place(ys, shift_bit) {
@@ -2213,6 +2344,16 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
                     (x_exp, if inexact { Less } else { Equal })
                 }
             }
+            Odd => {
+                if round_bit == 0 && sticky_bit == 0 {
+                    (x_exp, Equal)
+                } else if ys[0] & shift_bit == 0 {
+                    ys[0] |= shift_bit;
+                    (x_exp, Greater)
+                } else {
+                    (x_exp, Less)
+                }
+            }
             Ceiling | Up => {
                 if round_bit != 0 || sticky_bit != 0 {
                     if limbs_slice_add_limb_in_place(ys, shift_bit) {
@@ -2266,8 +2407,8 @@ fn add_float_significands_general_round(
     }
     match rm {
         Exact => panic!("Inexact float addition"),
-        Nearest => {
-            if following_bits == False {
+        Nearest | NearestTiesAway => {
+            if rm == Nearest && following_bits == False {
                 if out[0] & shift_bit != 0 {
                     if limbs_slice_add_limb_in_place(out, shift_bit) {
                         x_exp.saturating_add_assign(1);
@@ -2288,6 +2429,14 @@ fn add_float_significands_general_round(
             }
         }
         Floor | Down => (x_exp, Less),
+        Odd => {
+            if out[0] & shift_bit == 0 {
+                out[0] |= shift_bit;
+                (x_exp, Greater)
+            } else {
+                (x_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if limbs_slice_add_limb_in_place(out, shift_bit) {
                 x_exp.saturating_add_assign(1);
@@ -415,14 +415,21 @@ fn div_float_significands_same_prec_lt_w(
     }
     match rm {
         Exact => panic!("Inexact float division"),
-        Nearest => {
-            if round_bit == 0 || sticky_bit == 0 && quotient & shift_bit == 0 {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && quotient & shift_bit == 0) {
                 (quotient, increment_exp, Less)
             } else {
                 (quotient.wrapping_add(shift_bit), increment_exp, Greater)
             }
         }
         Floor | Down => (quotient, increment_exp, Less),
+        Odd => {
+            if quotient & shift_bit == 0 {
+                (quotient | shift_bit, increment_exp, Greater)
+            } else {
+                (quotient, increment_exp, Less)
+            }
+        }
         Ceiling | Up => (quotient.wrapping_add(shift_bit), increment_exp, Greater),
     }
 }
@@ -495,14 +502,21 @@ fn div_float_significands_same_prec_w(
     }
     match rm {
         Exact => panic!("Inexact float division"),
-        Nearest => {
-            if !round_bit || sticky_bit == 0 && quotient.even() {
+        Nearest | NearestTiesAway => {
+            if !round_bit || (rm == Nearest && sticky_bit == 0 && quotient.even()) {
                 (quotient, increment_exp, Less)
             } else {
                 (quotient.wrapping_add(1), increment_exp, Greater)
             }
         }
         Floor | Down => (quotient, increment_exp, Less),
+        Odd => {
+            if quotient.even() {
+                (quotient | 1, increment_exp, Greater)
+            } else {
+                (quotient, increment_exp, Less)
+            }
+        }
         Ceiling | Up => (quotient.wrapping_add(1), increment_exp, Greater),
     }
 }
@@ -646,8 +660,8 @@ fn div_float_significands_same_prec_gt_w_lt_2w(
     }
     match rm {
         Exact => panic!("Inexact float division"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && (z_0 & shift_bit) == 0) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && z_0 & shift_bit == 0) {
                 (z_0, z_1, increment_exp, Less)
             } else if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -656,6 +670,13 @@ fn div_float_significands_same_prec_gt_w_lt_2w(
             }
         }
         Floor | Down => (z_0, z_1, increment_exp, Less),
+        Odd => {
+            if z_0 & shift_bit == 0 {
+                (z_0 | shift_bit, z_1, increment_exp, Greater)
+            } else {
+                (z_0, z_1, increment_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -870,8 +891,8 @@ fn div_float_significands_long_by_short_to_out(
     let out = out.split_last_mut().unwrap().1;
     match rm {
         Exact => panic!("Inexact float division"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && (out[0] & shift_bit) == 0) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && out[0] & shift_bit == 0) {
                 (exp_offset, Less)
             } else {
                 if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -882,6 +903,14 @@ fn div_float_significands_long_by_short_to_out(
             }
         }
         Floor | Down => (exp_offset, Less),
+        Odd => {
+            if out[0] & shift_bit == 0 {
+                out[0] |= shift_bit;
+                (exp_offset, Greater)
+            } else {
+                (exp_offset, Less)
+            }
+        }
         Ceiling | Up => {
             if limbs_slice_add_limb_in_place(out, shift_bit) {
                 exp_offset += 1;
@@ -1020,8 +1049,8 @@ fn div_float_significands_long_by_short_in_place(
     }
     match rm {
         Exact => panic!("Inexact float division"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && (xs[0] & shift_bit) == 0) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && xs[0] & shift_bit == 0) {
                 (exp_offset, Less)
             } else {
                 if limbs_slice_add_limb_in_place(xs, shift_bit) {
@@ -1032,6 +1061,14 @@ fn div_float_significands_long_by_short_in_place(
             }
         }
         Floor | Down => (exp_offset, Less),
+        Odd => {
+            if xs[0] & shift_bit == 0 {
+                xs[0] |= shift_bit;
+                (exp_offset, Greater)
+            } else {
+                (exp_offset, Less)
+            }
+        }
         Ceiling | Up => {
             if limbs_slice_add_limb_in_place(xs, shift_bit) {
                 exp_offset += 1;
@@ -1284,6 +1321,17 @@ fn div_float_significands_general_to_out(
     prec: u64,
     rm: RoundingMode,
 ) -> (u64, Ordering) {
+    if rm == Odd {
+        // Round toward zero, then set the last bit of an inexact even result
+        let (exp_offset, o) = div_float_significands_general_to_out(qs, ns, ds, prec, Down);
+        let shift_bit = Limb::power_of_2(prec.neg_mod_power_of_2(Limb::LOG_WIDTH));
+        return if o == Less && qs[0] & shift_bit == 0 {
+            qs[0] |= shift_bit;
+            (exp_offset, Greater)
+        } else {
+            (exp_offset, o)
+        };
+    }
     let ns_len = ns.len();
     let ds_len = ds.len();
     let qs_len = bit_to_limb_count_ceiling(prec);
@@ -1346,18 +1394,23 @@ fn div_float_significands_general_to_out(
             i64::exact_from(n << Limb::LOG_WIDTH)
                 - i64::exact_from((two_n + 2).ceiling_log_base_2()),
         );
-        // If rm == Nearest, we need to be able to round with a directed rounding and one more bit.
+        // If rounding to nearest, we need to be able to round with a directed rounding and one more
+        // bit.
         if q_high {
             let qs_2_lo = &mut qs_2[..n];
             limbs_slice_shr_in_place(qs_2_lo, 1);
             *qs_2_lo.last_mut().unwrap() |= LIMB_HIGH_BIT;
-            if round_helper_2(qs_2_lo, p, prec + u64::from(rm == Nearest)) {
+            if round_helper_2(
+                qs_2_lo,
+                p,
+                prec + u64::from(rm == Nearest || rm == NearestTiesAway),
+            ) {
                 // We can round correctly whatever the rounding mode
                 qs.copy_from_slice(&qs_2[1..=qs_len]);
                 qs[0] &= !shift_mask; // put to zero low `shift` bits
                 return match rm {
                     Exact => panic!("Inexact float division"),
-                    Nearest => {
+                    Nearest | NearestTiesAway => {
                         // round to nearest
                         //
                         // We know we can round, thus we are never in the even rule case:
@@ -1389,13 +1442,14 @@ fn div_float_significands_general_to_out(
                         (exp_offset, Greater)
                     }
                     Down | Floor => (exp_offset, Less),
+                    Odd => unreachable!(),
                 };
             }
         }
     }
     // Mulders' short division failed: we revert to integer division
     let mut qs_2_vec = vec![];
-    let mut qs_2: &mut [Limb] = if rm == Nearest && shift == 0 {
+    let mut qs_2: &mut [Limb] = if (rm == Nearest || rm == NearestTiesAway) && shift == 0 {
         // We compute the quotient with one more limb, in order to get the round bit in the
         // quotient, and the remainder only contains sticky bits. Need to allocate memory for the
         // quotient
@@ -1456,7 +1510,7 @@ fn div_float_significands_general_to_out(
     //                 |    y1     |    y0  |      y0 has ds_len - qs_2_len limbs
     //                 |___________|________|
     // ```
-    //
+    // 
     //  We divide x1 by y1, with quotient in q_high + {qs_2, qs_2_len} and remainder (denoted r
     //  below) stored in place of the low qs_2_len limbs of x1.
     //
@@ -1478,7 +1532,7 @@ fn div_float_significands_general_to_out(
         // does nothing when shift = 0
         (qs_2[0] & shift_mask, shift)
     } else {
-        // qs_2_len = qs_len + 1: only happens when rm == Nearest and shift = 0
+        // qs_2_len = qs_len + 1: only happens when rounding to nearest and shift = 0
         qs.copy_from_slice(&qs_2_vec[1..=qs_len]);
         qs_2 = &mut qs_2_vec;
         (qs_2[0], Limb::WIDTH)
@@ -1498,7 +1552,7 @@ fn div_float_significands_general_to_out(
     let mut cleanup = Cleanup::None;
     if ds_len <= qs_2_len {
         // use the full divisor
-        sticky_bit = if rm == Nearest {
+        sticky_bit = if rm == Nearest || rm == NearestTiesAway {
             round_bit = sticky_3 & Limb::power_of_2(shift_2 - 1);
             (sticky_3 ^ round_bit) | Limb::from(sticky_x)
         } else if rm == Floor || rm == Down || inex == Equal {
@@ -1519,7 +1573,7 @@ fn div_float_significands_general_to_out(
         // rounding to nearest. (For rounding to nearest, we cannot determine the inexact flag for
         // 000...000 or 000...001.)
         let sticky_3_orig = sticky_3;
-        if rm == Nearest {
+        if rm == Nearest || rm == NearestTiesAway {
             round_bit = sticky_3 & Limb::power_of_2(shift_2 - 1);
             sticky_3 ^= round_bit;
         }
@@ -1641,7 +1695,9 @@ fn div_float_significands_general_to_out(
                         } else {
                             Equal
                         };
-                        cleanup = if rm == Nearest || ((rm == Ceiling || rm == Up) && inex != Equal)
+                        cleanup = if rm == Nearest
+                            || rm == NearestTiesAway
+                            || ((rm == Ceiling || rm == Up) && inex != Equal)
                         {
                             inex = Greater;
                             Cleanup::TruncateCheckQHigh
@@ -1663,7 +1719,7 @@ fn div_float_significands_general_to_out(
                         Exact => {
                             panic!("Inexact float division");
                         }
-                        Nearest => {
+                        Nearest | NearestTiesAway => {
                             // shift > 0
                             //
                             // Case shift = 1: sticky_bit = 0 always, and q1 - round_bit is exactly
@@ -1707,6 +1763,7 @@ fn div_float_significands_general_to_out(
                                 },
                             )
                         }
+                        Odd => unreachable!(),
                         Ceiling | Up => (
                             Greater,
                             if shift == 0 {
@@ -1734,7 +1791,7 @@ fn div_float_significands_general_to_out(
                 )
             } else if rm == Exact {
                 panic!("Inexact float division");
-            } else if rm == Nearest {
+            } else if rm == Nearest || rm == NearestTiesAway {
                 // sticky_bit != 0 or round != 0
                 if round_bit == 0 {
                     // necessarily sticky_bit != 0
@@ -1748,7 +1805,7 @@ fn div_float_significands_general_to_out(
                     (exp_offset, Greater)
                 } else {
                     // round_bit = 1, sticky_bit = 0
-                    if qs[0] & shift_bit == 0 {
+                    if rm == Nearest && qs[0] & shift_bit == 0 {
                         (exp_offset, Less)
                     } else {
                         if limbs_slice_add_limb_in_place(qs, shift_bit) {
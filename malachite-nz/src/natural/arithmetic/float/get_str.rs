@@ -89,7 +89,7 @@ private_test_fn! {limbs_get_str_aux(
         || round_helper_2(
             xs,
             i32::exact_from(i64::exact_from(n_width) - e),
-            n_width - neg_f + u64::from(rm == Nearest),
+            n_width - neg_f + u64::from(rm == Nearest || rm == NearestTiesAway),
         )
     {
         // compute the nearest integer to xs
@@ -99,7 +99,10 @@ private_test_fn! {limbs_get_str_aux(
         let j0 = neg_f & Limb::WIDTH_MASK;
         // mpfr_round_raw writes the rounded high limbs of xs back into xs starting at index i0,
         // while reading the original xs. Malachite uses a special function to handle this aliasing.
-        let (mut dir, carry) = round_helper_raw_aliased(i0, n_width - neg_f, xs, n_width, rm);
+        //
+        // When rounding to odd, the integer is truncated, and the last digit is fixed up below.
+        let int_rm = if rm == Odd { Down } else { rm };
+        let (mut dir, carry) = round_helper_raw_aliased(i0, n_width - neg_f, xs, n_width, int_rm);
         assert_ne!(dir, MPFR_ROUND_FAILED);
         if carry {
             // Y is a power of 2
@@ -131,13 +134,13 @@ private_test_fn! {limbs_get_str_aux(
         let size_s1_m1 = size_s1 - 1;
         if size_s1 == digit_len + 1 && (dir != 0 || str1[size_s1_m1] != 0) {
             // rounding mode
-            let rnd1 = if rm == Nearest {
+            let rnd1 = if rm == Nearest || rm == NearestTiesAway {
                 let twice_last = u64::from(str1[size_s1_m1]) << 1;
                 match twice_last.cmp(&b) {
                     Equal => {
                         if dir == 0 && exact {
-                            // exact: even rounding
-                            if str1[size_s1 - 2].even() {
+                            // exact: even rounding, or away from zero
+                            if rm == Nearest && str1[size_s1 - 2].even() {
                                 Floor
                             } else {
                                 Ceiling
@@ -152,6 +155,8 @@ private_test_fn! {limbs_get_str_aux(
                     Less => Floor,
                     Greater => Ceiling,
                 }
+            } else if rm == Odd {
+                Floor
             } else {
                 rm
             };
@@ -187,6 +192,24 @@ private_test_fn! {limbs_get_str_aux(
             // `dir != 0` (an inexact flag) and the sign is incidental; Malachite returns the
             // direction as an `Ordering`, so it must be correct.
         }
+        if rm == Odd && dir != 0 && str1[digit_len - 1].even() {
+            // round to odd: an inexact truncation whose last digit is even is rounded away from
+            // zero. In an odd base, a last digit of `b - 1` carries into the digits above it.
+            let target = u8::exact_from(b - 1);
+            let mut i = digit_len - 1;
+            while str1[i] == target && i != 0 {
+                str1[i] = 0;
+                i -= 1;
+            }
+            if str1[i] == target {
+                // all digits were `b - 1`, so the result is `b ^ digit_len`
+                str1[0] = 1;
+                exp += 1;
+            } else {
+                str1[i] += 1;
+            }
+            dir = 1;
+        }
         // copy str1 into out and convert to characters (digits and letters from the source
         // character set)
         let num_to_text = if (2..=36).contains(&base) {
@@ -322,8 +322,8 @@ fn mul_float_significands_same_prec_lt_w(
     }
     match rm {
         Exact => panic!("Inexact float multiplication"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && (product & shift_bit) == 0) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && product & shift_bit == 0) {
                 (product, decrement_exp, Less)
             } else if product.overflowing_add_assign(shift_bit) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -332,6 +332,13 @@ fn mul_float_significands_same_prec_lt_w(
             }
         }
         Floor | Down => (product, decrement_exp, Less),
+        Odd => {
+            if product & shift_bit == 0 {
+                (product | shift_bit, decrement_exp, Greater)
+            } else {
+                (product, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if product.overflowing_add_assign(shift_bit) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -363,8 +370,8 @@ fn mul_float_significands_same_prec_w(
     }
     match rm {
         Exact => panic!("Inexact float multiplication"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && product.even()) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && product.even()) {
                 (product, decrement_exp, Less)
             } else if product.overflowing_add_assign(1) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -373,6 +380,13 @@ fn mul_float_significands_same_prec_w(
             }
         }
         Floor | Down => (product, decrement_exp, Less),
+        Odd => {
+            if product.even() {
+                (product | 1, decrement_exp, Greater)
+            } else {
+                (product, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if product.overflowing_add_assign(1) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -447,8 +461,8 @@ fn mul_float_significands_same_prec_gt_w_lt_2w(
     }
     match rm {
         Exact => panic!("Inexact float multiplication"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && (z_0 & shift_bit) == 0) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && z_0 & shift_bit == 0) {
                 (z_0, z_1, decrement_exp, Less)
             } else if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -457,6 +471,13 @@ fn mul_float_significands_same_prec_gt_w_lt_2w(
             }
         }
         Floor | Down => (z_0, z_1, decrement_exp, Less),
+        Odd => {
+            if z_0 & shift_bit == 0 {
+                (z_0 | shift_bit, z_1, decrement_exp, Greater)
+            } else {
+                (z_0, z_1, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -573,8 +594,8 @@ fn mul_float_significands_same_prec_gt_2w_lt_3w(
     }
     match rm {
         Exact => panic!("Inexact float multiplication"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && z_0 & shift_bit == 0) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && z_0 & shift_bit == 0) {
                 (z_0, z_1, z_2, decrement_exp, Less)
             } else {
                 if z_0.overflowing_add_assign(shift_bit) {
@@ -591,6 +612,13 @@ fn mul_float_significands_same_prec_gt_2w_lt_3w(
             }
         }
         Floor | Down => (z_0, z_1, z_2, decrement_exp, Less),
+        Odd => {
+            if z_0 & shift_bit == 0 {
+                (z_0 | shift_bit, z_1, z_2, decrement_exp, Greater)
+            } else {
+                (z_0, z_1, z_2, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if z_0.overflowing_add_assign(shift_bit) {
                 z_1.wrapping_add_assign(1);
@@ -187,14 +187,21 @@ fn reciprocal_float_significand_same_prec_lt_w(
     let reciprocal = (LIMB_HIGH_BIT | q) & !mask;
     match rm {
         Exact => panic!("Inexact float reciprocation"),
-        Nearest => {
-            if round_bit == 0 || sticky_bit == 0 && reciprocal & shift_bit == 0 {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && reciprocal & shift_bit == 0) {
                 (reciprocal, false, Less)
             } else {
                 (reciprocal.wrapping_add(shift_bit), false, Greater)
             }
         }
         Floor | Down => (reciprocal, false, Less),
+        Odd => {
+            if reciprocal & shift_bit == 0 {
+                (reciprocal | shift_bit, false, Greater)
+            } else {
+                (reciprocal, false, Less)
+            }
+        }
         Ceiling | Up => (reciprocal.wrapping_add(shift_bit), false, Greater),
     }
 }
@@ -229,8 +236,8 @@ fn reciprocal_float_significand_same_prec_w(x: Limb, rm: RoundingMode) -> (Limb,
     };
     match rm {
         Exact => panic!("Inexact float reciprocation"),
-        Nearest => {
-            if !round_bit || sticky_bit == 0 && reciprocal.even() {
+        Nearest | NearestTiesAway => {
+            if !round_bit || (rm == Nearest && sticky_bit == 0 && reciprocal.even()) {
                 (reciprocal, false, Less)
             } else {
                 reciprocal.wrapping_add_assign(1);
@@ -238,6 +245,13 @@ fn reciprocal_float_significand_same_prec_w(x: Limb, rm: RoundingMode) -> (Limb,
             }
         }
         Floor | Down => (reciprocal, false, Less),
+        Odd => {
+            if reciprocal.even() {
+                (reciprocal | 1, false, Greater)
+            } else {
+                (reciprocal, false, Less)
+            }
+        }
         Ceiling | Up => {
             reciprocal.wrapping_add_assign(1);
             (reciprocal, false, Greater)
@@ -370,8 +384,8 @@ fn reciprocal_float_significand_same_prec_gt_w_lt_2w(
     let mut z_0 = q_0 & !mask;
     match rm {
         Exact => panic!("Inexact float reciprocation"),
-        Nearest => {
-            if round_bit == 0 || sticky_bit == 0 && z_0 & shift_bit == 0 {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && z_0 & shift_bit == 0) {
                 (z_0, z_1, false, Less)
             } else if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -380,6 +394,13 @@ fn reciprocal_float_significand_same_prec_gt_w_lt_2w(
             }
         }
         Floor | Down => (z_0, z_1, false, Less),
+        Odd => {
+            if z_0 & shift_bit == 0 {
+                (z_0 | shift_bit, z_1, false, Greater)
+            } else {
+                (z_0, z_1, false, Less)
+            }
+        }
         Ceiling | Up => {
             if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -486,8 +507,8 @@ fn reciprocal_float_significand_short_to_out(
     let out = out.split_last_mut().unwrap().1;
     match rm {
         Exact => panic!("Inexact float reciprocation"),
-        Nearest => {
-            if round_bit == 0 || sticky_bit == 0 && out[0] & shift_bit == 0 {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && out[0] & shift_bit == 0) {
                 (exp_offset, Less)
             } else {
                 if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -498,6 +519,14 @@ fn reciprocal_float_significand_short_to_out(
             }
         }
         Floor | Down => (exp_offset, Less),
+        Odd => {
+            if out[0] & shift_bit == 0 {
+                out[0] |= shift_bit;
+                (exp_offset, Greater)
+            } else {
+                (exp_offset, Less)
+            }
+        }
         Ceiling | Up => {
             if limbs_slice_add_limb_in_place(out, shift_bit) {
                 exp_offset += 1;
@@ -529,6 +558,17 @@ fn reciprocal_float_significand_general_to_out(
     prec: u64,
     rm: RoundingMode,
 ) -> (u64, Ordering) {
+    if rm == Odd {
+        // Round toward zero, then set the last bit of an inexact even result
+        let (exp_offset, o) = reciprocal_float_significand_general_to_out(qs, ds, prec, Down);
+        let shift_bit = Limb::power_of_2(prec.neg_mod_power_of_2(Limb::LOG_WIDTH));
+        return if o == Less && qs[0] & shift_bit == 0 {
+            qs[0] |= shift_bit;
+            (exp_offset, Greater)
+        } else {
+            (exp_offset, o)
+        };
+    }
     let ds_len = ds.len();
     let qs_len = bit_to_limb_count_ceiling(prec);
     let qs = &mut qs[..qs_len];
@@ -572,7 +612,8 @@ fn reciprocal_float_significand_general_to_out(
         let q_high = limbs_float_div_high(qs_2, &mut xs, &ys[..n], scratch);
         // In all cases, the error is at most (2 * n + 2) ulps on q_high * B ^ n + {qs_2, n}.
         //
-        // If rm == Nearest, we need to be able to round with a directed rounding and one more bit.
+        // If rounding to nearest, we need to be able to round with a directed rounding and one more
+        // bit.
         if q_high {
             let qs_2_lo = &mut qs_2[..n];
             limbs_slice_shr_in_place(qs_2_lo, 1);
@@ -582,7 +623,7 @@ fn reciprocal_float_significand_general_to_out(
     }
     // Mulders' short division failed: we revert to integer division
     let mut qs_2_vec = vec![];
-    let mut qs_2: &mut [Limb] = if rm == Nearest && shift == 0 {
+    let mut qs_2: &mut [Limb] = if (rm == Nearest || rm == NearestTiesAway) && shift == 0 {
         // We compute the reciprocal with one more limb, in order to get the round bit in the
         // reciprocal, and the remainder only contains sticky bits. Need to allocate memory for the
         // reciprocal
@@ -625,7 +666,7 @@ fn reciprocal_float_significand_general_to_out(
         // does nothing when shift = 0
         (qs_2[0] & shift_mask, shift)
     } else {
-        // qs_2_len = qs_len + 1: only happens when rm == Nearest and shift = 0
+        // qs_2_len = qs_len + 1: only happens when rounding to nearest and shift = 0
         qs.copy_from_slice(&qs_2_vec[1..=qs_len]);
         qs_2 = &mut qs_2_vec;
         (qs_2[0], Limb::WIDTH)
@@ -640,7 +681,7 @@ fn reciprocal_float_significand_general_to_out(
     let mut cleanup = Cleanup::None;
     if ds_len <= qs_2_len {
         // use the full divisor
-        sticky_bit = if rm == Nearest {
+        sticky_bit = if rm == Nearest || rm == NearestTiesAway {
             round_bit = sticky_3 & Limb::power_of_2(shift_2 - 1);
             (sticky_3 ^ round_bit) | Limb::from(sticky_x)
         } else if rm == Exact {
@@ -657,7 +698,7 @@ fn reciprocal_float_significand_general_to_out(
         // rounding to nearest. (For rounding to nearest, we cannot determine the inexact flag for
         // 000...000 or 000...001.)
         let sticky_3_orig = sticky_3;
-        if rm == Nearest {
+        if rm == Nearest || rm == NearestTiesAway {
             round_bit = sticky_3 & Limb::power_of_2(shift_2 - 1);
             sticky_3 ^= round_bit;
         }
@@ -730,7 +771,9 @@ fn reciprocal_float_significand_general_to_out(
                         } else {
                             Equal
                         };
-                        cleanup = if rm == Nearest || ((rm == Ceiling || rm == Up) && inex != Equal)
+                        cleanup = if rm == Nearest
+                            || rm == NearestTiesAway
+                            || ((rm == Ceiling || rm == Up) && inex != Equal)
                         {
                             inex = Greater;
                             Cleanup::TruncateCheckQHigh
@@ -750,7 +793,7 @@ fn reciprocal_float_significand_general_to_out(
                     // shift >= 2, otherwise (shift = 1) it is q1 - 2
                     (inex, cleanup) = if rm == Exact {
                         panic!("Inexact float reciprocation");
-                    } else if rm == Nearest {
+                    } else if rm == Nearest || rm == NearestTiesAway {
                         // shift > 0
                         //
                         // Case shift = 1: sticky_bit = 0 always, and q1 - round_bit is exactly
@@ -820,7 +863,7 @@ fn reciprocal_float_significand_general_to_out(
                 )
             } else if rm == Exact {
                 panic!("Inexact float reciprocation");
-            } else if rm == Nearest {
+            } else if rm == Nearest || rm == NearestTiesAway {
                 // sticky_bit != 0 or round != 0
                 if round_bit == 0 {
                     // necessarily sticky_bit != 0
@@ -837,7 +880,7 @@ fn reciprocal_float_significand_general_to_out(
                         "div_float_significands_long_by_short, round_bit != 0 && sticky_bit != 0",
                     );
                     // round_bit = 1, sticky_bit = 0
-                    if qs[0] & shift_bit == 0 {
+                    if rm == Nearest && qs[0] & shift_bit == 0 {
                         (exp_offset, Less)
                     } else {
                         if limbs_slice_add_limb_in_place(qs, shift_bit) {
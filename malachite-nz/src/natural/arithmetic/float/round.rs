@@ -39,7 +39,7 @@ pub fn float_can_round(x: &Natural, err0: u64, prec: u64, rm: RoundingMode) -> b
 }
 
 pub(crate) fn limb_float_can_round(x: Limb, err0: u64, mut prec: u64, rm: RoundingMode) -> bool {
-    if rm == Nearest {
+    if rm == Nearest || rm == NearestTiesAway {
         prec += 1;
     }
     assert!(x.get_highest_bit());
@@ -71,7 +71,7 @@ pub(crate) fn limb_float_can_round(x: Limb, err0: u64, mut prec: u64, rm: Roundi
 }
 
 pub fn limbs_float_can_round(xs: &[Limb], err0: u64, mut prec: u64, rm: RoundingMode) -> bool {
-    if rm == Nearest {
+    if rm == Nearest || rm == NearestTiesAway {
         prec += 1;
     }
     let len = xs.len();
@@ -349,6 +349,33 @@ fn round_helper<F: Fn(&mut [Limb], &[Limb], Limb) -> (i8, bool)>(
                     (1, increment)
                 }
             }
+            NearestTiesAway => {
+                if round_bit == 0 {
+                    out.copy_from_slice(xs_hi);
+                    out[0] &= ulp_mask;
+                    (if sticky_bit != 0 { -1 } else { 0 }, false)
+                } else {
+                    let increment = limbs_add_limb_to_out(out, xs_hi, ulp);
+                    if increment {
+                        out[out_len - 1] = LIMB_HIGH_BIT;
+                    }
+                    out[0] &= ulp_mask;
+                    (1, increment)
+                }
+            }
+            Odd => {
+                out.copy_from_slice(xs_hi);
+                out[0] &= ulp_mask;
+                if sticky_bit | round_bit == 0 {
+                    (0, false)
+                } else if out[0] & ulp == 0 {
+                    // Setting the ulp bit of an even significand can't carry
+                    out[0] |= ulp;
+                    (1, false)
+                } else {
+                    (-1, false)
+                }
+            }
         }
     }
 }
@@ -445,6 +472,31 @@ fn round_helper_aliased<F: Fn(&mut [Limb], Limb) -> (i8, bool)>(
                     (1, increment)
                 }
             }
+            NearestTiesAway => {
+                if round_bit == 0 {
+                    out[0] &= ulp_mask;
+                    (if sticky_bit != 0 { -1 } else { 0 }, false)
+                } else {
+                    let increment = limbs_slice_add_limb_in_place(out, ulp);
+                    if increment {
+                        out[out_len - 1] = LIMB_HIGH_BIT;
+                    }
+                    out[0] &= ulp_mask;
+                    (1, increment)
+                }
+            }
+            Odd => {
+                out[0] &= ulp_mask;
+                if sticky_bit | round_bit == 0 {
+                    (0, false)
+                } else if out[0] & ulp == 0 {
+                    // Setting the ulp bit of an even significand can't carry
+                    out[0] |= ulp;
+                    (1, false)
+                } else {
+                    (-1, false)
+                }
+            }
         }
     }
 }
@@ -525,9 +577,9 @@ const fn is_like_rounding_toward_zero(rm: RoundingMode, neg: bool) -> bool {
 
 // This is mpfr_round_raw2 (mpfr_round_raw_2, that is, round_raw_generic with flag = 1 and use_inexp
 // = 0) from round_raw_generic.c, MPFR 4.2.2. All bits of `xs` are considered significant. `rm` must
-// already be sign-normalized: `Down` means toward zero, `Up` away from zero, and `Nearest` ties to
-// even. Returns whether rounding to `prec` bits with `rm` would increment the significand at the
-// ulp position of `prec`.
+// already be sign-normalized: `Down` means toward zero, `Up` away from zero, `Nearest` ties to
+// even, and `NearestTiesAway` ties away from zero. Returns whether rounding to `prec` bits with
+// `rm` would increment the significand at the ulp position of `prec`.
 pub fn limbs_round_would_increment(xs: &[Limb], prec: u64, rm: RoundingMode) -> bool {
     let x_len = xs.len();
     if limb_to_bit_count(x_len) <= prec || rm == Down {
@@ -564,6 +616,7 @@ pub fn limbs_round_would_increment(xs: &[Limb], prec: u64, rm: RoundingMode) ->
                 }
             }
         }
+        NearestTiesAway => sb & Limb::power_of_2(WIDTH_MINUS_1 - rw) != 0,
         Up => {
             while sb == 0 && k > 0 {
                 k -= 1;
@@ -581,6 +634,10 @@ pub fn limbs_round_would_increment(xs: &[Limb], prec: u64, rm: RoundingMode) ->
 // `rnd1` with error at most 2^(EXP - err), where EXP is the raw exponent; the result is whether x
 // can be correctly rounded to `prec` bits in the direction `rnd2`, meaning that every real
 // consistent with the approximation rounds to the same value.
+//
+// An approximation obtained with `NearestTiesAway` or `Odd` may lie on either side of x, so as
+// `rnd1` these are treated like `Nearest`. As `rnd2`, `Odd` requires that both the truncation and
+// the ceiling of x be determined, since that is what tells whether x is exactly representable.
 pub fn limbs_float_can_round_raw(
     xs: &[Limb],
     neg: bool,
@@ -590,18 +647,22 @@ pub fn limbs_float_can_round_raw(
     prec: u64,
 ) -> bool {
     assert_ne!(prec, 0);
+    if rnd2 == Odd {
+        return limbs_float_can_round_raw(xs, neg, err, rnd1, Down, prec)
+            && limbs_float_can_round_raw(xs, neg, err, rnd1, Up, prec);
+    }
     let mut bn = xs.len();
     assert!(xs[bn - 1].get_highest_bit());
     // Transform Floor and Ceiling to Down (toward zero) and Up (away from zero) using the sign
-    let rnd1 = if rnd1 == Nearest {
+    let rnd1 = if rnd1 == Nearest || rnd1 == NearestTiesAway || rnd1 == Odd {
         Nearest
     } else if is_like_rounding_toward_zero(rnd1, neg) {
         Down
     } else {
         Up
     };
-    let rnd2 = if rnd2 == Nearest {
-        Nearest
+    let rnd2 = if rnd2 == Nearest || rnd2 == NearestTiesAway {
+        rnd2
     } else if is_like_rounding_toward_zero(rnd2, neg) {
         Down
     } else {
@@ -616,12 +677,13 @@ pub fn limbs_float_can_round_raw(
         return false;
     }
     let err = u64::exact_from(err);
+    let nearest2 = rnd2 == Nearest || rnd2 == NearestTiesAway;
     let bits = limb_to_bit_count(bn);
     if prec > bits {
         // prec exceeds the precision of xs; we can round iff rnd2 is compatible with rnd1 and the
         // error is at most half an ulp of xs, except at the boundary when a change of binade could
         // occur
-        return if (rnd1 == rnd2 || rnd2 == Nearest) && err > prec {
+        return if (rnd1 == rnd2 || nearest2) && err > prec {
             !(rnd1 != Down && err == prec + 1 && limbs_is_power_of_2_significand(xs))
         } else {
             false
@@ -637,7 +699,7 @@ pub fn limbs_float_can_round_raw(
             } else {
                 err > prec + 1
             }
-        } else if rnd2 == Nearest {
+        } else if nearest2 {
             if err == prec + 1 && xs[0].odd() {
                 false
             } else if prec < bits {
@@ -647,6 +709,9 @@ pub fn limbs_float_can_round_raw(
                     // xs is exactly in the middle of two numbers representable at prec
                     if rnd1 == Nearest {
                         false
+                    } else if rnd2 == NearestTiesAway {
+                        // the tie rounds away from zero, as does everything beyond it
+                        rnd1 == Down
                     } else {
                         let k1 = usize::exact_from(prec.shr_round(Limb::LOG_WIDTH, Ceiling).0);
                         let s1 = prec.neg_mod_power_of_2(Limb::LOG_WIDTH);
@@ -782,18 +847,19 @@ pub fn float_can_round_raw(
 
 // The integer-part rounding core of mpfr_rint from rint.c, MPFR 4.2.2, for the case exp > 0 (that
 // is, |u| >= 1). `up` is the significand of the input, `exp` its raw exponent, `prec` the target
-// precision; `rnd_away` is the magnitude direction (`None` for the nearest modes, decided here),
-// with `ties_away` selecting MPFR_RNDNA tie behavior. `neg` only affects which nearest tie rule is
-// even. Returns the rounded significand (aligned for `prec`), whether the exponent must be
-// incremented (a carry into the next binade), the MPFR uflags value (0 for an integer representable
-// at `prec`, 1 for an integer not representable, 2 for a non-integer), and the decided `rnd_away`.
+// precision; `rnd_away` is the magnitude direction (`None` for `Nearest`, `NearestTiesAway`, and
+// `Odd`, decided here according to `rm`). `rm` is only consulted when `rnd_away` is `None`. Returns
+// the rounded significand (aligned for `prec`), whether the exponent must be incremented (a carry
+// into the next binade), the MPFR uflags value (0 for an integer representable at `prec`, 1 for an
+// integer not representable, 2 for a non-integer), and the decided `rnd_away`.
 pub fn limbs_float_round_to_integer(
     up: &[Limb],
     exp: u64,
     prec: u64,
     rnd_away: Option<bool>,
-    ties_away: bool,
+    rm: RoundingMode,
 ) -> (Vec<Limb>, bool, u8, bool) {
+    let ties_away = rm == NearestTiesAway;
     let un = up.len();
     let rn =
         usize::exact_from((prec + prec.neg_mod_power_of_2(Limb::LOG_WIDTH)) >> Limb::LOG_WIDTH);
@@ -828,7 +894,10 @@ pub fn limbs_float_round_to_integer(
         rp.copy_from_slice(&up[un - rn..]);
         rp_offset = 0;
         if rnd_away.is_none() {
-            rnd_away = Some(if !ties_away && !rp[0].get_bit(sh) {
+            rnd_away = Some(if rm == Odd {
+                // an inexact result must end in a 1
+                !rp[0].get_bit(sh)
+            } else if !ties_away && !rp[0].get_bit(sh) {
                 // a halfway case rounds toward zero: the kept low bit is even
                 let (a, b) = if sh != 0 {
                     (rp[0].mod_power_of_2(sh), Limb::power_of_2(sh - 1))
@@ -868,7 +937,10 @@ pub fn limbs_float_round_to_integer(
             sh = ush;
         }
         if rnd_away.is_none() {
-            rnd_away = Some(if uj == 0 && sh == 0 {
+            rnd_away = Some(if rm == Odd {
+                // an inexact result must end in a 1
+                !rp[rp_offset].get_bit(sh)
+            } else if uj == 0 && sh == 0 {
                 // the rounding bit is 0 (not represented in u)
                 false
             } else if !ties_away && !rp[rp_offset].get_bit(sh) {
@@ -397,14 +397,15 @@ pub fn limbs_set_str(
         // `product[result_offset..]` is an approximation, rounded toward zero, of the pstr_size
         // most significant digits, with equality when `exact`.
         let result = &product[result_offset..result_offset + ysize];
-        // Test whether rounding is possible. The precx + (rnd == RNDN) trick is needed because the
+        // Test whether rounding is possible. The precx + (rnd == RNDN) trick, also used for
+        // NearestTiesAway, is needed because the
         // ternary value must be determined too: for xxx...xxx111...111 under Nearest the correct
         // rounding is known but the ternary value is not.
         if exact
             || round_helper_2(
                 result,
                 i32::exact_from(ysize_bits_i - err - 1),
-                prec_x + u64::from(rm == Nearest),
+                prec_x + u64::from(rm == Nearest || rm == NearestTiesAway),
             )
         {
             break (result.to_vec(), ysize_bits, exp);
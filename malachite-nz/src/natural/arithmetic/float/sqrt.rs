@@ -452,6 +452,13 @@ fn sqrt_float_significand_same_prec_lt_w(
     }
     match rm {
         Floor | Down => (r0, exp_r, Less),
+        Odd => {
+            if r0 & shift_bit == 0 {
+                (r0 | shift_bit, exp_r, Greater)
+            } else {
+                (r0, exp_r, Less)
+            }
+        }
         Ceiling | Up => {
             r0.wrapping_add_assign(shift_bit);
             if r0 == 0 {
@@ -460,7 +467,7 @@ fn sqrt_float_significand_same_prec_lt_w(
             }
             (r0, exp_r, Greater)
         }
-        Nearest => {
+        Nearest | NearestTiesAway => {
             // since sticky_bit != 0, only round_bit is needed to decide how to round, and the exact
             // middle is not possible
             if round_bit == 0 {
@@ -534,6 +541,13 @@ fn sqrt_float_significand_same_prec_w(
     }
     match rm {
         Floor | Down => (r0, exp_r, Less),
+        Odd => {
+            if r0.even() {
+                (r0 | 1, exp_r, Greater)
+            } else {
+                (r0, exp_r, Less)
+            }
+        }
         Ceiling | Up => {
             r0.wrapping_add_assign(1);
             if r0 == 0 {
@@ -542,7 +556,7 @@ fn sqrt_float_significand_same_prec_w(
             }
             (r0, exp_r, Greater)
         }
-        Nearest => {
+        Nearest | NearestTiesAway => {
             // we can't have sticky_bit = 0, thus round_bit is enough
             if round_bit == 0 {
                 (r0, exp_r, Less)
@@ -754,6 +768,13 @@ fn sqrt_float_significand_same_prec_gt_w_lt_2w(
     }
     match rm {
         Floor | Down => (r0, r1, exp_r, Less),
+        Odd => {
+            if r0 & shift_bit == 0 {
+                (r0 | shift_bit, r1, exp_r, Greater)
+            } else {
+                (r0, r1, exp_r, Less)
+            }
+        }
         Ceiling | Up => {
             r0.wrapping_add_assign(shift_bit);
             if r0 == 0 {
@@ -765,7 +786,7 @@ fn sqrt_float_significand_same_prec_gt_w_lt_2w(
             }
             (r0, r1, exp_r, Greater)
         }
-        Nearest => {
+        Nearest | NearestTiesAway => {
             // since sticky_bit != 0 now, only round_bit is needed
             if round_bit == 0 {
                 (r0, r1, exp_r, Less)
@@ -792,7 +813,7 @@ fn sqrt_float_significands_general(
     rm: RoundingMode,
 ) -> (Natural, i32, Ordering) {
     let mut shift = out_prec.neg_mod_power_of_2(Limb::LOG_WIDTH);
-    if shift == 0 && rm == Nearest {
+    if shift == 0 && (rm == Nearest || rm == NearestTiesAway) {
         // ugly case
         shift = Limb::WIDTH;
     }
@@ -859,7 +880,9 @@ fn sqrt_float_significands_general(
     let increment = match rm {
         Floor | Down => false,
         Ceiling | Up => true,
-        Nearest => {
+        // Adding one ulp to an even truncation just sets its last bit
+        Odd => out[0] & shift_bit == 0,
+        Nearest | NearestTiesAway => {
             // if shift < Limb::WIDTH: the round bit is bit (shift-1) of sticky1 and the sticky bit
             // is formed by the low shift-1 bits from sticky1, together with the sqrtrem remainder
             // and sticky0.
@@ -875,7 +898,7 @@ fn sqrt_float_significands_general(
                 false
             } else {
                 // round bit is set
-                if sticky1 == lower_bit && !sqrt_inexact && sticky0 == 0 {
+                if rm == Nearest && sticky1 == lower_bit && !sqrt_inexact && sticky0 == 0 {
                     if shift_bit == 0 {
                         out[1].odd()
                     } else {
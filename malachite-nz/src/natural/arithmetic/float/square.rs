@@ -179,8 +179,8 @@ fn square_float_significand_same_prec_lt_w(
     }
     match rm {
         Exact => panic!("Inexact float squaring"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && square & shift_bit == 0) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && square & shift_bit == 0) {
                 (square, decrement_exp, Less)
             } else if square.overflowing_add_assign(shift_bit) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -189,6 +189,13 @@ fn square_float_significand_same_prec_lt_w(
             }
         }
         Floor | Down => (square, decrement_exp, Less),
+        Odd => {
+            if square & shift_bit == 0 {
+                (square | shift_bit, decrement_exp, Greater)
+            } else {
+                (square, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if square.overflowing_add_assign(shift_bit) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -216,8 +223,8 @@ fn square_float_significand_same_prec_w(x: Limb, rm: RoundingMode) -> (Limb, boo
     }
     match rm {
         Exact => panic!("Inexact float squaring"),
-        Nearest => {
-            if round_bit == 0 || (sticky_bit == 0 && square.even()) {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && square.even()) {
                 (square, decrement_exp, Less)
             } else if square.overflowing_add_assign(1) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -226,6 +233,13 @@ fn square_float_significand_same_prec_w(x: Limb, rm: RoundingMode) -> (Limb, boo
             }
         }
         Floor | Down => (square, decrement_exp, Less),
+        Odd => {
+            if square.even() {
+                (square | 1, decrement_exp, Greater)
+            } else {
+                (square, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if square.overflowing_add_assign(1) {
                 (LIMB_HIGH_BIT, false, Greater)
@@ -297,8 +311,8 @@ fn square_float_significand_same_prec_gt_w_lt_2w(
     }
     match rm {
         Exact => panic!("Inexact float squaring"),
-        Nearest => {
-            if round_bit == 0 || sticky_bit == 0 && (z_0 & shift_bit) == 0 {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && z_0 & shift_bit == 0) {
                 (z_0, z_1, decrement_exp, Less)
             } else if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -307,6 +321,13 @@ fn square_float_significand_same_prec_gt_w_lt_2w(
             }
         }
         Floor | Down => (z_0, z_1, decrement_exp, Less),
+        Odd => {
+            if z_0 & shift_bit == 0 {
+                (z_0 | shift_bit, z_1, decrement_exp, Greater)
+            } else {
+                (z_0, z_1, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if z_0.overflowing_add_assign(shift_bit) && z_1.overflowing_add_assign(1) {
                 (z_0, LIMB_HIGH_BIT, false, Greater)
@@ -415,8 +436,8 @@ fn square_float_significand_same_prec_gt_2w_lt_3w(
     }
     match rm {
         Exact => panic!("Inexact float squaring"),
-        Nearest => {
-            if round_bit == 0 || sticky_bit == 0 && z_0 & shift_bit == 0 {
+        Nearest | NearestTiesAway => {
+            if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && z_0 & shift_bit == 0) {
                 (z_0, z_1, z_2, decrement_exp, Less)
             } else {
                 if z_0.overflowing_add_assign(shift_bit) {
@@ -433,6 +454,13 @@ fn square_float_significand_same_prec_gt_2w_lt_3w(
             }
         }
         Floor | Down => (z_0, z_1, z_2, decrement_exp, Less),
+        Odd => {
+            if z_0 & shift_bit == 0 {
+                (z_0 | shift_bit, z_1, z_2, decrement_exp, Greater)
+            } else {
+                (z_0, z_1, z_2, decrement_exp, Less)
+            }
+        }
         Ceiling | Up => {
             if z_0.overflowing_add_assign(shift_bit) {
                 z_1.wrapping_add_assign(1);
@@ -890,8 +890,9 @@ fn sub_float_significands_same_prec_lt_w(
             }
             match rm {
                 Exact => panic!("Inexact float subtraction"),
-                Nearest => {
-                    if round_bit == 0 || (sticky_bit == 0 && (diff & shift_bit) == 0) {
+                Nearest | NearestTiesAway => {
+                    if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && diff & shift_bit == 0)
+                    {
                         (diff, x_exp, Less, neg)
                     } else if diff.overflowing_add_assign(shift_bit) {
                         (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater, neg)
@@ -900,6 +901,13 @@ fn sub_float_significands_same_prec_lt_w(
                     }
                 }
                 Floor | Down => (diff, x_exp, Less, neg),
+                Odd => {
+                    if diff & shift_bit == 0 {
+                        (diff | shift_bit, x_exp, Greater, neg)
+                    } else {
+                        (diff, x_exp, Less, neg)
+                    }
+                }
                 Ceiling | Up => {
                     if diff.overflowing_add_assign(shift_bit) {
                         (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater, neg)
@@ -1018,8 +1026,8 @@ fn sub_float_significands_same_prec_w(
         }
         match rm {
             Exact => panic!("Inexact float subtraction"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && diff.even()) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && diff.even()) {
                     (diff, x_exp, Less, neg)
                 } else if diff.overflowing_add_assign(1) {
                     (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater, neg)
@@ -1028,6 +1036,13 @@ fn sub_float_significands_same_prec_w(
                 }
             }
             Floor | Down => (diff, x_exp, Less, neg),
+            Odd => {
+                if diff.even() {
+                    (diff | 1, x_exp, Greater, neg)
+                } else {
+                    (diff, x_exp, Less, neg)
+                }
+            }
             Ceiling | Up => {
                 if diff.overflowing_add_assign(1) {
                     (LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater, neg)
@@ -1238,8 +1253,8 @@ fn sub_float_significands_same_prec_gt_w_lt_2w(
         }
         match rm {
             Exact => panic!("Inexact float subtraction"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && (diff_0 & shift_bit) == 0) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && diff_0 & shift_bit == 0) {
                     (diff_0, diff_1, x_exp, Less, neg)
                 } else if diff_0.overflowing_add_assign(shift_bit)
                     && diff_1.overflowing_add_assign(1)
@@ -1250,6 +1265,13 @@ fn sub_float_significands_same_prec_gt_w_lt_2w(
                 }
             }
             Floor | Down => (diff_0, diff_1, x_exp, Less, neg),
+            Odd => {
+                if diff_0 & shift_bit == 0 {
+                    (diff_0 | shift_bit, diff_1, x_exp, Greater, neg)
+                } else {
+                    (diff_0, diff_1, x_exp, Less, neg)
+                }
+            }
             Ceiling | Up => {
                 if diff_0.overflowing_add_assign(shift_bit) && diff_1.overflowing_add_assign(1) {
                     (diff_0, LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater, neg)
@@ -1486,8 +1508,8 @@ fn sub_float_significands_same_prec_2w(
         }
         match rm {
             Exact => panic!("Inexact float subtraction"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && diff_0.even()) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && diff_0.even()) {
                     (diff_0, diff_1, x_exp, Less, neg)
                 } else if diff_0.overflowing_add_assign(1) && diff_1.overflowing_add_assign(1) {
                     (diff_0, LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater, neg)
@@ -1496,6 +1518,13 @@ fn sub_float_significands_same_prec_2w(
                 }
             }
             Floor | Down => (diff_0, diff_1, x_exp, Less, neg),
+            Odd => {
+                if diff_0.even() {
+                    (diff_0 | 1, diff_1, x_exp, Greater, neg)
+                } else {
+                    (diff_0, diff_1, x_exp, Less, neg)
+                }
+            }
             Ceiling | Up => {
                 if diff_0.overflowing_add_assign(1) && diff_1.overflowing_add_assign(1) {
                     (diff_0, LIMB_HIGH_BIT, x_exp.saturating_add(1), Greater, neg)
@@ -1818,8 +1847,8 @@ fn sub_float_significands_same_prec_gt_2w_lt_3w(
         }
         match rm {
             Exact => panic!("Inexact float subtraction"),
-            Nearest => {
-                if round_bit == 0 || (sticky_bit == 0 && (diff_0 & shift_bit) == 0) {
+            Nearest | NearestTiesAway => {
+                if round_bit == 0 || (rm == Nearest && sticky_bit == 0 && diff_0 & shift_bit == 0) {
                     (diff_0, diff_1, diff_2, x_exp, Less, neg)
                 } else {
                     if diff_0.overflowing_add_assign(shift_bit) {
@@ -1843,6 +1872,13 @@ fn sub_float_significands_same_prec_gt_2w_lt_3w(
                 }
             }
             Floor | Down => (diff_0, diff_1, diff_2, x_exp, Less, neg),
+            Odd => {
+                if diff_0 & shift_bit == 0 {
+                    (diff_0 | shift_bit, diff_1, diff_2, x_exp, Greater, neg)
+                } else {
+                    (diff_0, diff_1, diff_2, x_exp, Less, neg)
+                }
+            }
             Ceiling | Up => {
                 if diff_0.overflowing_add_assign(shift_bit) {
                     diff_1.wrapping_add_assign(1);
@@ -2332,7 +2368,7 @@ fn sub_float_significands_same_prec_ge_3w_ref_ref<'a>(
     }
     match rm {
         Exact => panic!("Inexact float subtraction"),
-        Nearest => {
+        Nearest | NearestTiesAway => {
             if out_power_of_2 {
                 assert_eq!(round_bit, 0);
                 // Since we are at the end of the binade, we have in fact round_bit = round_bit_2
@@ -2340,7 +2376,7 @@ fn sub_float_significands_same_prec_ge_3w_ref_ref<'a>(
                 round_bit = round_bit_2;
                 sticky_bit = sticky_bit_2;
             }
-            if (prec == 1 || out[0] & shift_bit == 0 || round_bit == 0)
+            if (rm == NearestTiesAway || prec == 1 || out[0] & shift_bit == 0 || round_bit == 0)
                 && (sticky_bit == 0 || round_bit == 0)
             {
                 (x_exp, Greater, neg)
@@ -2363,6 +2399,21 @@ fn sub_float_significands_same_prec_ge_3w_ref_ref<'a>(
             }
             (x_exp, Less, neg)
         }
+        Odd => {
+            // The exact result is in (pred(out), out), so the result is whichever of the two is
+            // odd. When out is a power of 2, pred(out) is odd (both are, if prec is 1).
+            if !out_power_of_2 && out[0] & shift_bit != 0 {
+                (x_exp, Greater, neg)
+            } else {
+                limbs_sub_limb_in_place(out, shift_bit);
+                if out_power_of_2 {
+                    // deal with cancellation
+                    out[nm1] |= LIMB_HIGH_BIT;
+                    x_exp.saturating_sub_assign(1);
+                }
+                (x_exp, Less, neg)
+            }
+        }
         Ceiling | Up => (x_exp, Greater, neg),
     }
 }
@@ -2826,7 +2877,7 @@ fn sub_float_significands_same_prec_ge_3w_val_ref_helper(
     // in (pred(xs), xs), and the bounds cannot be attained
     match rm {
         Exact => panic!("Inexact float subtraction"),
-        Nearest => {
+        Nearest | NearestTiesAway => {
             if out_power_of_2 {
                 assert_eq!(round_bit, 0);
                 // Since we are at the end of the binade, we have in fact round_bit = round_bit_2
@@ -2834,7 +2885,7 @@ fn sub_float_significands_same_prec_ge_3w_val_ref_helper(
                 round_bit = round_bit_2;
                 sticky_bit = sticky_bit_2;
             }
-            if (prec == 1 || xs[0] & shift_bit == 0 || round_bit == 0)
+            if (rm == NearestTiesAway || prec == 1 || xs[0] & shift_bit == 0 || round_bit == 0)
                 && (sticky_bit == 0 || round_bit == 0)
             {
                 (x_exp, Greater)
@@ -2857,6 +2908,21 @@ fn sub_float_significands_same_prec_ge_3w_val_ref_helper(
             }
             (x_exp, Less)
         }
+        Odd => {
+            // The exact result is in (pred(xs), xs), so the result is whichever of the two is odd.
+            // When xs is a power of 2, pred(xs) is odd (both are, if prec is 1).
+            if !out_power_of_2 && xs[0] & shift_bit != 0 {
+                (x_exp, Greater)
+            } else {
+                limbs_sub_limb_in_place(xs, shift_bit);
+                if out_power_of_2 {
+                    // deal with cancellation
+                    xs[nm1] |= LIMB_HIGH_BIT;
+                    x_exp.saturating_sub_assign(1);
+                }
+                (x_exp, Less)
+            }
+        }
         Ceiling | Up => (x_exp, Greater),
     }
 }
@@ -3263,7 +3329,7 @@ fn sub_float_significands_same_prec_ge_3w_ref_val_helper(
     // in (pred(ys), ys), and the bounds cannot be attained
     match rm {
         Exact => panic!("Inexact float subtraction"),
-        Nearest => {
+        Nearest | NearestTiesAway => {
             if ys_power_of_2 {
                 assert_eq!(round_bit, 0);
                 // Since we are at the end of the binade, we have in fact round_bit = round_bit_2
@@ -3271,7 +3337,7 @@ fn sub_float_significands_same_prec_ge_3w_ref_val_helper(
                 round_bit = round_bit_2;
                 sticky_bit = sticky_bit_2;
             }
-            if (prec == 1 || ys[0] & shift_bit == 0 || round_bit == 0)
+            if (rm == NearestTiesAway || prec == 1 || ys[0] & shift_bit == 0 || round_bit == 0)
                 && (sticky_bit == 0 || round_bit == 0)
             {
                 (x_exp, Greater)
@@ -3294,6 +3360,21 @@ fn sub_float_significands_same_prec_ge_3w_ref_val_helper(
             }
             (x_exp, Less)
         }
+        Odd => {
+            // The exact result is in (pred(ys), ys), so the result is whichever of the two is odd.
+            // When ys is a power of 2, pred(ys) is odd (both are, if prec is 1).
+            if !ys_power_of_2 && ys[0] & shift_bit != 0 {
+                (x_exp, Greater)
+            } else {
+                limbs_sub_limb_in_place(ys, shift_bit);
+                if ys_power_of_2 {
+                    // deal with cancellation
+                    ys[nm1] |= LIMB_HIGH_BIT;
+                    x_exp.saturating_sub_assign(1);
+                }
+                (x_exp, Less)
+            }
+        }
         Ceiling | Up => (x_exp, Greater),
     }
 }
@@ -3645,11 +3726,15 @@ fn sub_float_significands_general<'a>(
         if increment_exp {
             exp_a += 1;
         }
-        if inexact == 0 && rm != Down && rm != Floor {
+        if inexact == 0
+            && rm != Down
+            && rm != Floor
+            && (rm != Odd || out[0] & Limb::power_of_2(limb_to_bit_count(out_len) - out_prec) != 0)
+        {
             // out = x, but the exact value of x - y is a bit below. Then, except for directed
-            // rounding similar to toward zero and before overflow checking: a is the correctly
-            // rounded value and since |x| - |y| < |out|, the ternary value is given by the sign of
-            // out.
+            // rounding similar to toward zero (or rounding to odd when x is even) and before
+            // overflow checking: a is the correctly rounded value and since |x| - |y| < |out|, the
+            // ternary value is given by the sign of out.
             inexact = 1;
         } else if inexact != 0 && inexact != MPFR_EVEN_INEX {
             // ```
@@ -3829,7 +3914,7 @@ fn sub_float_significands_general<'a>(
     let mut cmp_low = 0;
     let mut goto_truncate = false;
     let mut goto_end_of_sub = false;
-    if rm == Nearest {
+    if rm == Nearest || rm == NearestTiesAway {
         if shift != 0 {
             let half_shift_bit = shift_bit >> 1;
             // Can decide except when carry = 2 ^ (sh - 1) [middle] or carry = 0 [truncate, but
@@ -3853,7 +3938,7 @@ fn sub_float_significands_general<'a>(
             }
         }
     } else if carry != 0 {
-        inexact = if rm == Floor || rm == Down || rm == Exact {
+        inexact = if rm == Floor || rm == Down || rm == Odd || rm == Exact {
             -1
         } else {
             if limbs_slice_add_limb_in_place(out, shift_bit) {
@@ -3938,7 +4023,7 @@ fn sub_float_significands_general<'a>(
             //
             // The case rounding to nearest with sh=0 is special since one couldn't subtract above
             // 1/2 ulp in the trailing limb of the result
-            if rm == Nearest && shift == 0 && k == 0 {
+            if (rm == Nearest || rm == NearestTiesAway) && shift == 0 && k == 0 {
                 // case 1 for k = 0
                 // - add one ulp if xx > yy + half
                 // - truncate if yy - half < xx < yy + half
@@ -3973,7 +4058,7 @@ fn sub_float_significands_general<'a>(
                 Less => {
                     // low(x) - low(y) < 0: either truncate or subtract one ulp
                     match rm {
-                        Floor | Down => {
+                        Floor | Down | Odd => {
                             limbs_sub_limb_in_place(out, shift_bit);
                             inexact = -1;
                             goto_end_of_sub = true;
@@ -3982,7 +4067,7 @@ fn sub_float_significands_general<'a>(
                             inexact = 1;
                             goto_truncate = true;
                         }
-                        Nearest => {
+                        Nearest | NearestTiesAway => {
                             // - If cmp_low < 0 and xx > yy, then -0.5 ulp < low(x) - low(y) < 0,
                             //   whatever the value of shift.
                             // - If shift > 0, then cmp_low < 0 implies that the initial neglected
@@ -4023,7 +4108,7 @@ fn sub_float_significands_general<'a>(
                 Greater => {
                     // 0 < low(x) - low(y): either truncate or add one ulp
                     match rm {
-                        Floor | Down | Exact => {
+                        Floor | Down | Odd | Exact => {
                             inexact = -1;
                             goto_truncate = true;
                         }
@@ -4037,7 +4122,7 @@ fn sub_float_significands_general<'a>(
                             inexact = 1;
                             goto_truncate = true;
                         }
-                        Nearest => {
+                        Nearest | NearestTiesAway => {
                             match xx.cmp(&yy) {
                                 Greater => {
                                     // If sh = 0, then xx > yy means that low(x) - low(y) > 0.5 ulp,
@@ -4109,9 +4194,15 @@ fn sub_float_significands_general<'a>(
             k = 1;
         }
         if !goto_truncate && !goto_end_of_sub {
-            inexact = if rm == Nearest && cmp_low != 0 {
-                // Even rounding rule
-                if (out[0] >> shift).odd() {
+            inexact = if (rm == Nearest || rm == NearestTiesAway) && cmp_low != 0 {
+                // Even rounding rule, or rounding away from zero: when cmp_low < 0, truncating
+                // rounds away from zero, and when cmp_low > 0, adding one ulp does
+                let round_up = if rm == Nearest {
+                    (out[0] >> shift).odd()
+                } else {
+                    cmp_low > 0
+                };
+                if round_up {
                     if cmp_low < 0 {
                         limbs_sub_limb_in_place(out, shift_bit);
                         goto_end_of_sub = true;
@@ -4141,6 +4232,12 @@ fn sub_float_significands_general<'a>(
         *last_out = LIMB_HIGH_BIT;
         add_exp = true;
     }
+    if rm == Odd && inexact < 0 && out[0] & shift_bit == 0 {
+        // Odd was rounded like Down; the truncated result is even, so the odd neighbor is the one
+        // above it
+        out[0] |= shift_bit;
+        inexact = 1;
+    }
     // We have to set MPFR_EXP(out) to MPFR_EXP(x) - cancel + diff_exp, taking care of
     // underflows/overflows in that computation, and of the allowed exponent range
     let exp_a = if cancel != 0 {
@@ -4160,6 +4257,6 @@ fn sub_float_significands_general<'a>(
         x_exp
     };
     // check that result is msb-normalized
-    assert!(last_out.get_highest_bit());
+    assert!(out[out_len - 1].get_highest_bit());
     (exp_a, inexact.sign(), neg)
 }
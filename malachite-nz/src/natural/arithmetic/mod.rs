@@ -30,6 +30,9 @@ pub mod balanced_mod;
 /// for computing the binomial coefficient of two numbers.
 pub mod bell_number;
 pub mod binomial_coefficient;
+/// An implementation of [`CatalanNumber`](malachite_base::num::arithmetic::traits::CatalanNumber),
+/// a trait for computing Catalan numbers, and a function for computing a prefix of the sequence.
+pub mod catalan_number;
 pub mod checked_mul_sub_mul;
 /// Implementations of [`CheckedSub`](malachite_base::num::arithmetic::traits::CheckedSub), a trait
 /// for subtracting two numbers and checking whether the result is representable.
@@ -108,6 +111,10 @@ pub mod factorial;
 /// [`LucasNumber`](malachite_base::num::arithmetic::traits::LucasNumber), traits for computing
 /// Fibonacci and Lucas numbers.
 pub mod fibonacci;
+/// [`FixedBaseModPow`](fixed_base_mod_pow::FixedBaseModPow), a precomputation for raising a fixed
+/// [`Natural`](crate::natural::Natural) to many powers modulo another
+/// [`Natural`](crate::natural::Natural).
+pub mod fixed_base_mod_pow;
 #[cfg(feature = "float_helpers")]
 pub mod float;
 /// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
@@ -152,6 +159,10 @@ pub mod log_base_power_of_2;
 /// [`ModAddAssign`](malachite_base::num::arithmetic::traits::ModAddAssign), traits for adding two
 /// numbers modulo another number.
 pub mod mod_add;
+/// [`ModContext`](mod_context::ModContext), a reusable context for arithmetic modulo a fixed
+/// [`Natural`](crate::natural::Natural), which keeps residues in Montgomery form when the modulus is
+/// odd.
+pub mod mod_context;
 /// Implementations of [`ModDiv`](malachite_base::num::arithmetic::traits::ModDiv), a trait for
 /// dividing two numbers modulo another number.
 pub mod mod_div;
@@ -168,6 +179,10 @@ pub mod mod_inverse;
 /// Implementations of [`ModIsReduced`](malachite_base::num::arithmetic::traits::ModIsReduced), a
 /// trait for checking whether a number is reduced modulo another number.
 pub mod mod_is_reduced;
+/// Implementations of [`ModLog`](malachite_base::num::arithmetic::traits::ModLog) and
+/// [`ModLogWithOrderFactorization`](malachite_base::num::arithmetic::traits::ModLogWithOrderFactorization),
+/// traits for computing the discrete logarithm of a number modulo another number.
+pub mod mod_log;
 /// Implementations of traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](malachite_base::num::arithmetic::traits::ModMul),
@@ -178,6 +193,10 @@ pub mod mod_is_reduced;
 /// [`ModMulPrecomputedAssign`](malachite_base::num::arithmetic::traits::ModMulPrecomputedAssign)
 /// are useful when having to make several multiplications modulo the same modulus.
 pub mod mod_mul;
+/// Implementations of
+/// [`ModMultiplicativeOrder`](malachite_base::num::arithmetic::traits::ModMultiplicativeOrder), a
+/// trait for computing the multiplicative order of a number modulo another number.
+pub mod mod_multiplicative_order;
 /// Implementations of [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg) and
 /// [`ModNegAssign`](malachite_base::num::arithmetic::traits::ModNegAssign), traits for negating a
 /// number modulo another number.
@@ -583,6 +602,13 @@ crate_test_mod! {
     /// for future multimodular algorithms, public only under `test_build`.
     multi_crt
 }
+/// [`Natural::multi_mod_pow`](super::Natural::multi_mod_pow), which computes a product of several
+/// powers modulo a [`Natural`](super::Natural).
+pub mod multi_mod_pow;
+/// An implementation of
+/// [`MultinomialCoefficient`](malachite_base::num::arithmetic::traits::MultinomialCoefficient), a
+/// trait for computing multinomial coefficients.
+pub mod multinomial_coefficient;
 /// Negation of a [`Natural`](super::Natural), returning an [`Integer`](crate::integer::Integer).
 pub mod neg;
 /// Implementations of [`NextPowerOf2`](malachite_base::num::arithmetic::traits::NextPowerOf2) and
@@ -592,6 +618,11 @@ pub mod next_power_of_2;
 /// Implementations of [`Parity`](malachite_base::num::arithmetic::traits::Parity), a trait for
 /// determining whether a number is even or odd.
 pub mod parity;
+/// An implementation of
+/// [`PartitionNumber`](malachite_base::num::arithmetic::traits::PartitionNumber), a trait for
+/// computing the number of partitions of an integer, and a function for computing a prefix of the
+/// sequence.
+pub mod partition_number;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -1296,6 +1327,11 @@ pub mod sqrt;
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
 /// number.
 pub mod square;
+/// Implementations of [`StirlingNumber1`](malachite_base::num::arithmetic::traits::StirlingNumber1)
+/// and [`StirlingNumber2`](malachite_base::num::arithmetic::traits::StirlingNumber2), traits for
+/// computing Stirling numbers of the first and second kinds, and functions for computing whole rows
+/// of them.
+pub mod stirling_number;
 /// Subtraction of [`Natural`](super::Natural)s.
 pub mod sub;
 /// Implementations of [`SubMul`](malachite_base::num::arithmetic::traits::SubMul) and
@@ -0,0 +1,621 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_mul::{ModMulData, precompute_mod_mul_data_helper};
+use crate::natural::arithmetic::mod_pow::{REDC_1_TO_REDC_N_THRESHOLD, select_fns, to_redc};
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::logic::bit_access::limbs_get_bit;
+use crate::platform::Limb;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering::*;
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModMulPrecomputed, ModNeg, ModPowPrecomputed, ModSquarePrecomputed, ModSub,
+    Parity,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::slices::slice_set_zero;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum ModContextData {
+    // The modulus is odd and greater than 1, and residues are kept in Montgomery form: $x$ is
+    // represented by $xR \mod m$, where $R = B^n$ and $m$ has $n$ limbs. `is` holds the modular
+    // inverse that the reduction function chosen by `select_fns` expects, `one` is $R \mod m$, and
+    // `r_squared` is $R^2 \mod m$.
+    Montgomery {
+        ms: Vec<Limb>,
+        is: Vec<Limb>,
+        one: Natural,
+        r_squared: Natural,
+    },
+    // The modulus is even or 1, and residues are ordinary reduced values, multiplied using the
+    // division data that `ModMulPrecomputed` uses.
+    Plain(ModMulData),
+}
+
+/// A context for modular arithmetic with a fixed [`Natural`] modulus $m$, built once and then
+/// reused for any number of operations.
+///
+/// The context stores everything that the operations would otherwise recompute for each call. When
+/// $m$ is odd and greater than 1, residues are kept in Montgomery form: $x$ is represented by $xR
+/// \mod m$, where $R = 2^{Wn}$, $W$ is the limb width, and $n$ is the number of limbs of $m$.
+/// Multiplication then reduces by Montgomery's method rather than by division. When $m$ is even or
+/// 1, Montgomery form is unavailable, residues are ordinary values less than $m$, and
+/// multiplication divides using a precomputed inverse of $m$, as
+/// [`mod_mul_precomputed`](malachite_base::num::arithmetic::traits::ModMulPrecomputed) does.
+///
+/// Either way, a residue is a [`Natural`] less than $m$, and values enter and leave the context
+/// through [`to_residue`](Self::to_residue) and [`from_residue`](Self::from_residue). The other
+/// functions take and return residues, and their results are only meaningful when their inputs
+/// came from the same context. Addition, subtraction, and negation act on Montgomery forms the same
+/// way they act on ordinary values, so residues may also be compared with each other and tested for
+/// zero directly.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ModContext {
+    m: Natural,
+    data: ModContextData,
+}
+
+impl ModContext {
+    /// Creates a context for arithmetic modulo a [`Natural`] $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// assert_eq!(*ctx.modulus(), 497);
+    /// assert!(ctx.is_montgomery());
+    ///
+    /// assert!(!ModContext::new(Natural::from(30u32)).is_montgomery());
+    /// ```
+    pub fn new(m: Natural) -> Self {
+        assert_ne!(m, 0u32, "division by zero");
+        if m.even() || m == 1u32 {
+            let data = ModContextData::Plain(precompute_mod_mul_data_helper(&m));
+            return Self { m, data };
+        }
+        let ms = m.to_limbs_asc();
+        let n = ms.len();
+        let is = if n < REDC_1_TO_REDC_N_THRESHOLD {
+            vec![limbs_modular_invert_limb(ms[0]).wrapping_neg()]
+        } else {
+            let mut is = vec![0; n];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(n)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            is
+        };
+        let one = redc_form(&Natural::ONE, &ms);
+        let r_squared = redc_form(&one, &ms);
+        Self {
+            m,
+            data: ModContextData::Montgomery {
+                ms,
+                is,
+                one,
+                r_squared,
+            },
+        }
+    }
+
+    /// Returns the modulus of the context.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// assert_eq!(*ModContext::new(Natural::from(10u32)).modulus(), 10);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.m
+    }
+
+    /// Returns whether the context keeps its residues in Montgomery form, which is the case when
+    /// the modulus is odd and greater than 1.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// assert!(ModContext::new(Natural::from(7u32)).is_montgomery());
+    /// assert!(!ModContext::new(Natural::from(8u32)).is_montgomery());
+    /// assert!(!ModContext::new(Natural::from(1u32)).is_montgomery());
+    /// ```
+    #[inline]
+    pub const fn is_montgomery(&self) -> bool {
+        matches!(self.data, ModContextData::Montgomery { .. })
+    }
+
+    /// Converts a [`Natural`] to a residue of the context. The [`Natural`] need not be reduced
+    /// modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(1000u32));
+    /// assert_eq!(ctx.from_residue(&x), 6);
+    /// ```
+    pub fn to_residue(&self, x: &Natural) -> Natural {
+        match &self.data {
+            ModContextData::Montgomery { ms, .. } => redc_form(x, ms),
+            ModContextData::Plain(_) => x % &self.m,
+        }
+    }
+
+    /// Converts a residue of the context back to an ordinary [`Natural`] less than $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(123u32));
+    /// assert_ne!(x, 123);
+    /// assert_eq!(ctx.from_residue(&x), 123);
+    /// ```
+    pub fn from_residue(&self, x: &Natural) -> Natural {
+        self.assert_reduced(x);
+        match &self.data {
+            ModContextData::Montgomery { ms, is, .. } => {
+                let n = ms.len();
+                let mut scratch = padded_limbs(x, n << 1);
+                let mut out = vec![0; n];
+                redc(&mut out, &mut scratch, ms, is);
+                Natural::from_owned_limbs_asc(out)
+            }
+            ModContextData::Plain(_) => x.clone(),
+        }
+    }
+
+    /// Returns the residue of the context that represents 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// assert_eq!(ctx.from_residue(&ctx.one()), 1);
+    /// assert_eq!(ModContext::new(Natural::from(1u32)).one(), 0);
+    /// ```
+    pub fn one(&self) -> Natural {
+        match &self.data {
+            ModContextData::Montgomery { one, .. } => one.clone(),
+            ModContextData::Plain(_) => Natural::ONE % &self.m,
+        }
+    }
+
+    /// Adds two residues of the context.
+    ///
+    /// $f(x, y) = z$, where $x, y, z < m$ and $x + y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(400u32));
+    /// let y = ctx.to_residue(&Natural::from(200u32));
+    /// assert_eq!(ctx.from_residue(&ctx.add(&x, &y)), 103);
+    /// ```
+    pub fn add(&self, x: &Natural, y: &Natural) -> Natural {
+        self.assert_reduced(x);
+        self.assert_reduced(y);
+        x.mod_add(y, &self.m)
+    }
+
+    /// Subtracts a residue of the context from another.
+    ///
+    /// $f(x, y) = z$, where $x, y, z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(100u32));
+    /// let y = ctx.to_residue(&Natural::from(200u32));
+    /// assert_eq!(ctx.from_residue(&ctx.sub(&x, &y)), 397);
+    /// ```
+    pub fn sub(&self, x: &Natural, y: &Natural) -> Natural {
+        self.assert_reduced(x);
+        self.assert_reduced(y);
+        x.mod_sub(y, &self.m)
+    }
+
+    /// Negates a residue of the context.
+    ///
+    /// $f(x) = y$, where $x, y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(100u32));
+    /// assert_eq!(ctx.from_residue(&ctx.neg(&x)), 397);
+    /// ```
+    pub fn neg(&self, x: &Natural) -> Natural {
+        self.assert_reduced(x);
+        x.mod_neg(&self.m)
+    }
+
+    /// Multiplies two residues of the context.
+    ///
+    /// $f(x, y) = z$, where $x, y, z < m$ and $xy \equiv z \mod m$, when the inputs and output are
+    /// taken to be the values that the residues represent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` or `y` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(100u32));
+    /// let y = ctx.to_residue(&Natural::from(200u32));
+    /// assert_eq!(ctx.from_residue(&ctx.mul(&x, &y)), 120);
+    ///
+    /// let ctx = ModContext::new(Natural::from(30u32));
+    /// let x = ctx.to_residue(&Natural::from(7u32));
+    /// let y = ctx.to_residue(&Natural::from(11u32));
+    /// assert_eq!(ctx.from_residue(&ctx.mul(&x, &y)), 17);
+    /// ```
+    pub fn mul(&self, x: &Natural, y: &Natural) -> Natural {
+        self.assert_reduced(x);
+        self.assert_reduced(y);
+        match &self.data {
+            ModContextData::Montgomery { ms, is, .. } => {
+                let n = ms.len();
+                let (mul_fn, _, _) = select_fns(n);
+                let mut scratch = vec![0; n << 1];
+                mul_fn(&mut scratch, &padded_limbs(x, n), &padded_limbs(y, n));
+                let mut out = vec![0; n];
+                redc(&mut out, &mut scratch, ms, is);
+                Natural::from_owned_limbs_asc(out)
+            }
+            ModContextData::Plain(data) => x.mod_mul_precomputed(y, &self.m, data),
+        }
+    }
+
+    /// Squares a residue of the context.
+    ///
+    /// $f(x) = y$, where $x, y < m$ and $x^2 \equiv y \mod m$, when the input and output are taken
+    /// to be the values that the residues represent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(100u32));
+    /// assert_eq!(ctx.from_residue(&ctx.square(&x)), 60);
+    /// ```
+    pub fn square(&self, x: &Natural) -> Natural {
+        self.assert_reduced(x);
+        match &self.data {
+            ModContextData::Montgomery { ms, is, .. } => {
+                let n = ms.len();
+                let (_, square_fn, _) = select_fns(n);
+                let mut scratch = vec![0; n << 1];
+                square_fn(&mut scratch, &padded_limbs(x, n));
+                let mut out = vec![0; n];
+                redc(&mut out, &mut scratch, ms, is);
+                Natural::from_owned_limbs_asc(out)
+            }
+            ModContextData::Plain(data) => x.mod_square_precomputed(&self.m, data),
+        }
+    }
+
+    /// Raises a residue of the context to a [`Natural`] power.
+    ///
+    /// $f(x, k) = y$, where $x, y < m$ and $x^k \equiv y \mod m$, when the input and output are
+    /// taken to be the values that the residues represent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(4u32));
+    /// assert_eq!(ctx.from_residue(&ctx.pow(&x, &Natural::from(13u32))), 445);
+    ///
+    /// let ctx = ModContext::new(Natural::from(30u32));
+    /// let x = ctx.to_residue(&Natural::from(10u32));
+    /// assert_eq!(ctx.from_residue(&ctx.pow(&x, &Natural::from(1000u32))), 10);
+    /// ```
+    pub fn pow(&self, x: &Natural, exp: &Natural) -> Natural {
+        self.assert_reduced(x);
+        match &self.data {
+            ModContextData::Montgomery { ms, is, one, .. } => {
+                if *exp == 0u32 {
+                    return one.clone();
+                }
+                let n = ms.len();
+                let (mul_fn, square_fn, _) = select_fns(n);
+                let es = exp.as_limbs_asc();
+                let xs = padded_limbs(x, n);
+                let mut out = xs.clone();
+                let mut scratch = vec![0; n << 1];
+                for i in (0..exp.significant_bits() - 1).rev() {
+                    square_fn(&mut scratch, &out);
+                    redc(&mut out, &mut scratch, ms, is);
+                    if limbs_get_bit(es, i) {
+                        mul_fn(&mut scratch, &out, &xs);
+                        redc(&mut out, &mut scratch, ms, is);
+                    }
+                }
+                Natural::from_owned_limbs_asc(out)
+            }
+            ModContextData::Plain(data) => x.mod_pow_precomputed(exp, &self.m, data),
+        }
+    }
+
+    /// Computes the multiplicative inverse of a residue of the context, or returns `None` if the
+    /// value it represents is not coprime to $m$.
+    ///
+    /// $f(x) = y$, where $x, y < m$ and $xy \equiv 1 \mod m$, when the input and output are taken
+    /// to be the values that the residues represent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `x` is 0 or if `x` is greater than or equal to $m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_nz::natural::arithmetic::mod_context::ModContext;
+    ///
+    /// let ctx = ModContext::new(Natural::from(10u32));
+    /// let x = ctx.to_residue(&Natural::from(3u32));
+    /// assert_eq!(ctx.inverse(&x).map(|y| ctx.from_residue(&y)), Some(Natural::from(7u32)));
+    /// let x = ctx.to_residue(&Natural::from(4u32));
+    /// assert_eq!(ctx.inverse(&x), None);
+    ///
+    /// let ctx = ModContext::new(Natural::from(497u32));
+    /// let x = ctx.to_residue(&Natural::from(100u32));
+    /// assert_eq!(ctx.inverse(&x).map(|y| ctx.from_residue(&y)), Some(Natural::from(333u32)));
+    /// ```
+    pub fn inverse(&self, x: &Natural) -> Option<Natural> {
+        self.assert_reduced(x);
+        let y = x.mod_inverse(&self.m)?;
+        Some(match &self.data {
+            // If x represents a, then x = aR and y = 1 / (aR), so multiplying by R^2 twice gives
+            // R / a, which represents 1 / a.
+            ModContextData::Montgomery { r_squared, .. } => {
+                self.mul(&self.mul(&y, r_squared), r_squared)
+            }
+            ModContextData::Plain(_) => y,
+        })
+    }
+
+    pub(crate) fn assert_reduced(&self, x: &Natural) {
+        assert!(
+            *x < self.m,
+            "x must be reduced mod m, but {x} >= {}",
+            self.m
+        );
+    }
+
+    // The number of limbs of $m$, which is the length of the padded residues that `limbs_mul` and
+    // `limbs_square` act on.
+    pub(crate) fn limb_len(&self) -> usize {
+        match &self.data {
+            ModContextData::Montgomery { ms, .. } => ms.len(),
+            ModContextData::Plain(_) => usize::exact_from(self.m.limb_count()),
+        }
+    }
+
+    // Returns the limbs of a residue, padded with zeros to the length of $m$.
+    pub(crate) fn to_padded_limbs(&self, x: &Natural) -> Vec<Limb> {
+        padded_limbs(x, self.limb_len())
+    }
+
+    // Writes the product of two residues, given as limbs padded to the length of $m$, to `out`.
+    // `scratch` must have at least twice the length of $m$.
+    pub(crate) fn limbs_mul(
+        &self,
+        out: &mut [Limb],
+        xs: &[Limb],
+        ys: &[Limb],
+        scratch: &mut [Limb],
+    ) {
+        match &self.data {
+            ModContextData::Montgomery { ms, is, .. } => {
+                let (mul_fn, _, _) = select_fns(ms.len());
+                mul_fn(scratch, xs, ys);
+                redc(out, scratch, ms, is);
+            }
+            ModContextData::Plain(data) => {
+                let product = Natural::from_limbs_asc(xs).mod_mul_precomputed(
+                    Natural::from_limbs_asc(ys),
+                    &self.m,
+                    data,
+                );
+                write_padded(out, &product);
+            }
+        }
+    }
+
+    // Writes the square of a residue, given as limbs padded to the length of $m$, to `out`.
+    // `scratch` must have at least twice the length of $m$.
+    pub(crate) fn limbs_square(&self, out: &mut [Limb], xs: &[Limb], scratch: &mut [Limb]) {
+        match &self.data {
+            ModContextData::Montgomery { ms, is, .. } => {
+                let (_, square_fn, _) = select_fns(ms.len());
+                square_fn(scratch, xs);
+                redc(out, scratch, ms, is);
+            }
+            ModContextData::Plain(data) => {
+                let square = Natural::from_limbs_asc(xs).mod_square_precomputed(&self.m, data);
+                write_padded(out, &square);
+            }
+        }
+    }
+}
+
+// Writes the limbs of `x` to `out`, padding with zeros.
+fn write_padded(out: &mut [Limb], x: &Natural) {
+    let xs = x.as_limbs_asc();
+    let (out_lo, out_hi) = out.split_at_mut(xs.len());
+    out_lo.copy_from_slice(xs);
+    slice_set_zero(out_hi);
+}
+
+// Returns the limbs of `x`, padded with zeros to length `len`.
+fn padded_limbs(x: &Natural, len: usize) -> Vec<Limb> {
+    let mut xs = x.to_limbs_asc();
+    xs.resize(len, 0);
+    xs
+}
+
+// Writes $xR^{-1} \mod m$ to `out`, given the $2n$ limbs of $x < mR$, fully reducing the result.
+// `xs` is overwritten.
+fn redc(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], is: &[Limb]) {
+    let (_, _, reduce_fn) = select_fns(ms.len());
+    reduce_fn(out, xs, ms, is);
+    if limbs_cmp_same_length(out, ms) != Less {
+        limbs_sub_same_length_in_place_left(out, ms);
+    }
+}
+
+// Returns $xR \mod m$.
+fn redc_form(x: &Natural, ms: &[Limb]) -> Natural {
+    if *x == 0u32 {
+        return Natural::ZERO;
+    }
+    let mut out = vec![0; ms.len()];
+    to_redc(&mut out, &x.to_limbs_asc(), ms);
+    Natural::from_owned_limbs_asc(out)
+}
@@ -0,0 +1,634 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, DivisibleBy, Gcd, ModAddAssign, ModInverse, ModLog, ModLogWithOrderFactorization,
+    ModMul, ModMulPrecomputed, ModMulPrecomputedAssign, ModNeg, ModPowPrecomputed, ModShlAssign,
+    ModSquarePrecomputedAssign, ModSub, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// A prime-order subproblem whose order has at most this many bits is solved by baby-step
+// giant-step, which stores about $\sqrt q$ values; larger ones are solved by Pollard rho, which
+// stores none.
+const MAX_BSGS_ORDER_BITS: u64 = 32;
+
+// When the group may not be cyclic, the number of times Pollard rho is restarted after a collision
+// that gives no information before the target is taken to lie outside the subgroup.
+const RHO_ATTEMPTS: u64 = 8;
+
+const THREE: Natural = Natural::const_from(3);
+
+// Finds the $d < q$ with $\gamma^d = h$, where $\gamma$ has prime order $q < 2^{32}$, by baby-step
+// giant-step, returning `None` if there is none.
+fn bsgs(
+    gamma: &Natural,
+    h: &Natural,
+    q: &Natural,
+    m: &Natural,
+    data: &ModMulData,
+) -> Option<Natural> {
+    let s = q.ceiling_sqrt();
+    let s_u64 = u64::exact_from(&s);
+    let mut baby = Vec::with_capacity(usize::exact_from(s_u64));
+    let mut y = Natural::ONE;
+    for j in 0..s_u64 {
+        if y == *h {
+            return Some(Natural::from(j));
+        }
+        let next = (&y).mod_mul_precomputed(gamma, m, data);
+        baby.push((y, j));
+        y = next;
+    }
+    baby.sort_unstable();
+    // gamma ^ (-s) = gamma ^ (q - s), since s <= q
+    let giant = gamma.mod_pow_precomputed(q - &s, m, data);
+    let mut z = h.clone();
+    for i in 1..=s_u64 {
+        z.mod_mul_precomputed_assign(&giant, m, data);
+        if let Ok(index) = baby.binary_search_by(|(y, _)| y.cmp(&z)) {
+            return Some(Natural::from(i) * &s + Natural::from(baby[index].1));
+        }
+    }
+    None
+}
+
+// Finds the $d < q$ with $\gamma^d = h$, where $\gamma$ has prime order $q$ and $h^q = 1$, by
+// Pollard rho with Floyd cycle detection.
+//
+// The walk is the one used by the primitive implementation: it multiplies by $\gamma$, multiplies
+// by $h$, or squares, depending on the current element modulo 3, and a collision that gives no
+// information restarts the walk, or, if the group may not be cyclic, ends it after `RHO_ATTEMPTS`
+// restarts.
+fn rho(
+    gamma: &Natural,
+    h: &Natural,
+    q: &Natural,
+    m: &Natural,
+    data: &ModMulData,
+    cyclic: bool,
+) -> Option<Natural> {
+    let step = |(y, a, b): &mut (Natural, Natural, Natural)| {
+        let r = &*y % &THREE;
+        if r == 0u32 {
+            y.mod_square_precomputed_assign(m, data);
+            a.mod_shl_assign(1, q);
+            b.mod_shl_assign(1, q);
+        } else if r == 1u32 {
+            y.mod_mul_precomputed_assign(gamma, m, data);
+            a.mod_add_assign(Natural::ONE, q);
+        } else {
+            y.mod_mul_precomputed_assign(h, m, data);
+            b.mod_add_assign(Natural::ONE, q);
+        }
+    };
+    let mut attempt = 0u64;
+    loop {
+        attempt += 1;
+        // Since q > 2 ^ 32, the starting exponents differ between attempts.
+        let a = Natural::from(attempt);
+        let y = gamma
+            .mod_pow_precomputed(&a, m, data)
+            .mod_mul_precomputed(h, m, data);
+        let mut tortoise = (y, a, Natural::ONE);
+        step(&mut tortoise);
+        let mut hare = tortoise.clone();
+        step(&mut hare);
+        while tortoise.0 != hare.0 {
+            step(&mut tortoise);
+            step(&mut hare);
+            step(&mut hare);
+        }
+        let db = hare.2.mod_sub(tortoise.2, q);
+        if db != 0u32 {
+            let da = tortoise.1.mod_sub(hare.1, q);
+            return Some(da.mod_mul(db.mod_inverse(q).unwrap(), q));
+        }
+        if !cyclic && attempt == RHO_ATTEMPTS {
+            return None;
+        }
+    }
+}
+
+// Combines $x \equiv x_1 \pmod {n_1}$ and $x \equiv x_2 \pmod {n_2}$, where the moduli need not be
+// coprime, into a congruence modulo $\operatorname{lcm}(n_1, n_2)$, or returns `None` if they are
+// inconsistent.
+fn combine(x_1: Natural, n_1: Natural, x_2: Natural, n_2: &Natural) -> Option<(Natural, Natural)> {
+    let g = (&n_1).gcd(n_2);
+    if &x_1 % &g != &x_2 % &g {
+        return None;
+    }
+    let n_2_g = n_2 / &g;
+    if n_2_g == 1u32 {
+        return Some((x_1, n_1));
+    }
+    // Solve (n_1 / g) * k = (x_2 - x_1) / g mod n_2 / g; then x = x_1 + n_1 * k.
+    let d = if x_2 >= x_1 {
+        ((x_2 - &x_1) / &g) % &n_2_g
+    } else {
+        (((&x_1 - x_2) / &g) % &n_2_g).mod_neg(&n_2_g)
+    };
+    let k = d.mod_mul((&n_1 / &g % &n_2_g).mod_inverse(&n_2_g).unwrap(), &n_2_g);
+    Some((x_1 + &n_1 * k, n_1 * n_2_g))
+}
+
+// Computes the discrete logarithm of `x` to the base `b` modulo `m`, where `b` is coprime to `m`
+// and `b ^ N = 1`, with `N` the product described by `order_factors`. Returns the logarithm, which
+// is less than the order of `b`, together with that order.
+//
+// This is the Pohlig-Hellman algorithm of the primitive implementation: the order of `b` is found
+// by removing prime factors from `N`, the logarithm is found modulo each prime power dividing the
+// order one digit at a time, the results are combined, and a final check discards a result when
+// `x` is not a power of `b`.
+fn mod_log_coprime(
+    x: &Natural,
+    b: &Natural,
+    m: &Natural,
+    order_factors: &[(Natural, u64)],
+    cyclic: bool,
+) -> Option<(Natural, Natural)> {
+    if *m == 1u32 {
+        return Some((Natural::ZERO, Natural::ONE));
+    }
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+    let mut n = Natural::ONE;
+    for (q, e) in order_factors {
+        n *= q.pow(*e);
+    }
+    assert!(
+        b.mod_pow_precomputed(&n, m, &data) == 1u32,
+        "order_factors must describe a multiple of the order of the base"
+    );
+    let mut factors = Vec::with_capacity(order_factors.len());
+    for (q, e) in order_factors {
+        let mut e = *e;
+        while e != 0 {
+            let quotient = &n / q;
+            if b.mod_pow_precomputed(&quotient, m, &data) != 1u32 {
+                break;
+            }
+            n = quotient;
+            e -= 1;
+        }
+        if e != 0 {
+            factors.push((q, e));
+        }
+    }
+    let mut log = Natural::ZERO;
+    let mut modulus = Natural::ONE;
+    for (q, e) in factors {
+        let qe = q.pow(e);
+        let cofactor = &n / &qe;
+        let g = b.mod_pow_precomputed(&cofactor, m, &data);
+        let h = x.mod_pow_precomputed(&cofactor, m, &data);
+        let gamma = (&g).mod_pow_precomputed(&qe / q, m, &data);
+        let g_inverse = (&g).mod_inverse(m).unwrap();
+        let mut log_q = Natural::ZERO;
+        let mut q_power = Natural::ONE;
+        for k in 0..e {
+            // (g ^ (-log_q) * h) ^ (q ^ (e - 1 - k)) has order dividing q if x is a power of b
+            let y = (&g_inverse)
+                .mod_pow_precomputed(&log_q, m, &data)
+                .mod_mul_precomputed(&h, m, &data);
+            let h_k = y.mod_pow_precomputed(q.pow(e - 1 - k), m, &data);
+            let d = if q.significant_bits() <= MAX_BSGS_ORDER_BITS {
+                bsgs(&gamma, &h_k, q, m, &data)?
+            } else {
+                if (&h_k).mod_pow_precomputed(q, m, &data) != 1u32 {
+                    return None;
+                }
+                rho(&gamma, &h_k, q, m, &data, cyclic)?
+            };
+            log_q += d * &q_power;
+            if k != e - 1 {
+                q_power *= q;
+            }
+        }
+        (log, modulus) = combine(log, modulus, log_q, &qe).unwrap();
+    }
+    if b.mod_pow_precomputed(&log, m, &data) == *x {
+        Some((log, n))
+    } else {
+        None
+    }
+}
+
+// Computes the smallest $k$ with $b^k \equiv x \pmod m$, where `x` and `b` must be reduced modulo
+// `m`.
+//
+// The algorithm is the one used by the primitive implementation: common factors of $b$ and $m$ are
+// removed one step at a time, and then the modulus is split into prime powers, in whose
+// multiplicative groups the logarithm is found by Pohlig-Hellman, with the results combined by the
+// Chinese remainder theorem.
+fn mod_log_ref_ref_ref(x: &Natural, b: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    assert!(b < m, "b must be reduced mod m, but {b} >= {m}");
+    // Since x, b < m, a `Small` modulus implies a `Small` x and b. The `Limb` implementation is the
+    // same algorithm, so this delegation does not change any output.
+    if let Natural(Small(m_small)) = m {
+        let (Natural(Small(x_small)), Natural(Small(b_small))) = (x, b) else {
+            unreachable!();
+        };
+        return x_small.mod_log(*b_small, *m_small).map(Natural::from);
+    }
+    let mut x = x.clone();
+    let mut m = m.clone();
+    // k = b ^ add / (the product of the gs removed so far), modulo m
+    let mut k = Natural::ONE;
+    let mut add = 0u64;
+    loop {
+        let g = b.gcd(&m);
+        if g == 1u32 {
+            break;
+        }
+        if x == k {
+            return Some(Natural::from(add));
+        }
+        if !(&x).divisible_by(&g) {
+            return None;
+        }
+        x /= &g;
+        m /= &g;
+        add += 1;
+        k = (k % &m).mod_mul((b / &g) % &m, &m);
+    }
+    if m == 1u32 {
+        return Some(Natural::from(add));
+    }
+    let b = b % &m;
+    let x = x.mod_mul(k.mod_inverse(&m).unwrap(), &m);
+    let mut log = Natural::ZERO;
+    let mut order = Natural::ONE;
+    for (p, e) in m.factor() {
+        let pe = (&p).pow(e);
+        let mut order_factors: Vec<(Natural, u64)> =
+            (&p - Natural::ONE).factor().into_iter().collect();
+        if e > 1 {
+            order_factors.push((p, e - 1));
+        }
+        let (log_p, order_p) =
+            mod_log_coprime(&(&x % &pe), &(&b % &pe), &pe, &order_factors, true)?;
+        (log, order) = combine(log, order, log_p, &order_p)?;
+    }
+    Some(log + Natural::from(add))
+}
+
+fn mod_log_with_order_factorization_ref_ref_ref(
+    x: &Natural,
+    b: &Natural,
+    m: &Natural,
+    order_factors: &[(Natural, u64)],
+) -> Option<Natural> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    assert!(b < m, "b must be reduced mod m, but {b} >= {m}");
+    mod_log_coprime(x, b, m, order_factors, false).map(|(log, _)| log)
+}
+
+macro_rules! natural_mod_log_doc {
+    ($f:item) => {
+        /// Computes the discrete logarithm of a [`Natural`] to a base $b$, modulo another
+        /// [`Natural`] $m$: the smallest $k$ with $b^k \equiv x \pmod m$, or `None` if there is
+        /// none. The [`Natural`] and the base must be already reduced modulo $m$.
+        ///
+        /// The base need not be coprime to $m$. The modulus is factored, and the logarithm is found
+        /// in the multiplicative group modulo each prime-power factor by the Pohlig-Hellman
+        /// algorithm, with baby-step giant-step or Pollard rho for the subproblems of prime order.
+        /// Apart from factoring $m$ and $p - 1$ for each prime $p$ dividing $m$, the cost is
+        /// dominated by the largest prime factor of the order of $b$.
+        ///
+        /// $f(x, b, m) = \min \\{k \in \N : b^k \equiv x \pmod m\\}$, if the set is nonempty.
+        ///
+        /// # Worst-case complexity
+        /// $T(n) = O(2^{n/2} n^3 \log n \log\log n)$
+        ///
+        /// $M(n) = O(2^{n/4} n)$
+        ///
+        /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`. The
+        /// bounds are reached when the order of `b` has a large prime factor; the time bound for
+        /// Pollard rho is heuristic.
+        ///
+        /// # Panics
+        /// Panics if `self` or `base` is greater than or equal to `m`.
+        ///
+        /// # Examples
+        /// ```
+        /// use malachite_base::num::arithmetic::traits::ModLog;
+        /// use malachite_nz::natural::Natural;
+        /// use std::str::FromStr;
+        ///
+        /// assert_eq!(
+        ///     (&Natural::from(13u32)).mod_log(&Natural::from(3u32), &Natural::from(17u32)),
+        ///     Some(Natural::from(4u32))
+        /// );
+        /// assert_eq!(
+        ///     (&Natural::from(3u32)).mod_log(&Natural::from(2u32), &Natural::from(8u32)),
+        ///     None
+        /// );
+        /// // 2 ^ 127 - 1
+        /// let m = Natural::from_str("170141183460469231731687303715884105727").unwrap();
+        /// assert_eq!(
+        ///     (&Natural::from_str("100000000000000000000").unwrap())
+        ///         .mod_log(&Natural::from(43u32), &m)
+        ///         .unwrap()
+        ///         .to_string(),
+        ///     "62819794551481979830613316005264074640"
+        /// );
+        /// ```
+        $f
+    };
+}
+
+impl ModLog<Self, Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: Self, m: Self) -> Option<Self> {
+            mod_log_ref_ref_ref(&self, &base, &m)
+        }
+    }
+}
+
+impl<'a> ModLog<Self, &'a Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: Self, m: &'a Self) -> Option<Self> {
+            mod_log_ref_ref_ref(&self, &base, m)
+        }
+    }
+}
+
+impl<'a> ModLog<&'a Self, Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: &'a Self, m: Self) -> Option<Self> {
+            mod_log_ref_ref_ref(&self, base, &m)
+        }
+    }
+}
+
+impl<'a, 'b> ModLog<&'a Self, &'b Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: &'a Self, m: &'b Self) -> Option<Self> {
+            mod_log_ref_ref_ref(&self, base, m)
+        }
+    }
+}
+
+impl ModLog<Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: Natural, m: Natural) -> Option<Natural> {
+            mod_log_ref_ref_ref(self, &base, &m)
+        }
+    }
+}
+
+impl ModLog<Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: Natural, m: &Natural) -> Option<Natural> {
+            mod_log_ref_ref_ref(self, &base, m)
+        }
+    }
+}
+
+impl ModLog<&Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: &Natural, m: Natural) -> Option<Natural> {
+            mod_log_ref_ref_ref(self, base, &m)
+        }
+    }
+}
+
+impl ModLog<&Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_doc! {
+        #[inline]
+        fn mod_log(self, base: &Natural, m: &Natural) -> Option<Natural> {
+            mod_log_ref_ref_ref(self, base, m)
+        }
+    }
+}
+
+macro_rules! natural_mod_log_with_order_factorization_doc {
+    ($f:item) => {
+        /// Computes the discrete logarithm of a [`Natural`] to a base $b$, modulo another
+        /// [`Natural`] $m$, given the prime factorization of a multiple of the multiplicative
+        /// order of $b$: the smallest $k$ with $b^k \equiv x \pmod m$, or `None` if there is none.
+        /// The [`Natural`] and the base must be already reduced modulo $m$.
+        ///
+        /// The factorization is given as pairs $(q, e)$ of distinct primes and their exponents.
+        /// Their product $N$ must satisfy $b^N \equiv 1 \pmod m$; if $m$ is prime, $m - 1$ always
+        /// works. No factoring is done, so this is the function to use when many logarithms are
+        /// taken with the same base, or when the modulus is too large to factor. The logarithm is
+        /// found by the Pohlig-Hellman algorithm, with baby-step giant-step or Pollard rho for the
+        /// subproblems of prime order.
+        ///
+        /// If the multiplicative group modulo $m$ is cyclic, which it is when $m$ is 2, 4, or a
+        /// power or twice a power of an odd prime, the result is exact. Otherwise, when the order
+        /// of $b$ has a prime factor $q$ greater than $2^{32}$, a logarithm may be missed with
+        /// probability about $q^{-8}$. [`ModLog`] has no such caveat.
+        ///
+        /// $f(x, b, m) = \min \\{k \in \N : b^k \equiv x \pmod m\\}$, if the set is nonempty.
+        ///
+        /// # Worst-case complexity
+        /// $T(n, k) = O(2^{k/2} n^2 \log n \log\log n + k n^2 \log n \log\log n)$
+        ///
+        /// $M(n, k) = O(2^{k/4} n)$
+        ///
+        /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+        /// the number of bits of the product of the factors.
+        ///
+        /// # Panics
+        /// Panics if `self` or `base` is greater than or equal to `m`, or if `base` raised to the
+        /// product of the factors is not 1 modulo $m$.
+        ///
+        /// # Examples
+        /// ```
+        /// use malachite_base::num::arithmetic::traits::ModLogWithOrderFactorization;
+        /// use malachite_nz::natural::Natural;
+        /// use std::str::FromStr;
+        ///
+        /// // 65537 - 1 = 2 ^ 16
+        /// assert_eq!(
+        ///     (&Natural::from(12345u32)).mod_log_with_order_factorization(
+        ///         &Natural::from(5u32),
+        ///         &Natural::from(65537u32),
+        ///         &[(Natural::from(2u32), 16)]
+        ///     ),
+        ///     Some(Natural::from(51713u32))
+        /// );
+        /// // 2 ^ 521 - 1 is prime, and 2 ^ 521 - 2 is divisible by the order of 2, which is 521
+        /// let m = Natural::from_str(
+        ///     "6864797660130609714981900799081393217269435300143305409394463459185543183397656052122\
+        ///     559640661454554977296311391480858037121987999716643812574028291115057151",
+        /// )
+        /// .unwrap();
+        /// assert_eq!(
+        ///     (&Natural::from(1024u32)).mod_log_with_order_factorization(
+        ///         &Natural::from(2u32),
+        ///         &m,
+        ///         &[(Natural::from(521u32), 1)]
+        ///     ),
+        ///     Some(Natural::from(10u32))
+        /// );
+        /// ```
+        $f
+    };
+}
+
+impl ModLogWithOrderFactorization<Self, Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: Self,
+            m: Self,
+            order_factors: &[(Self, u64)],
+        ) -> Option<Self> {
+            mod_log_with_order_factorization_ref_ref_ref(&self, &base, &m, order_factors)
+        }
+    }
+}
+
+impl<'a> ModLogWithOrderFactorization<Self, &'a Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: Self,
+            m: &'a Self,
+            order_factors: &[(Self, u64)],
+        ) -> Option<Self> {
+            mod_log_with_order_factorization_ref_ref_ref(&self, &base, m, order_factors)
+        }
+    }
+}
+
+impl<'a> ModLogWithOrderFactorization<&'a Self, Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: &'a Self,
+            m: Self,
+            order_factors: &[(Self, u64)],
+        ) -> Option<Self> {
+            mod_log_with_order_factorization_ref_ref_ref(&self, base, &m, order_factors)
+        }
+    }
+}
+
+impl<'a, 'b> ModLogWithOrderFactorization<&'a Self, &'b Self> for Natural {
+    type Output = Self;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: &'a Self,
+            m: &'b Self,
+            order_factors: &[(Self, u64)],
+        ) -> Option<Self> {
+            mod_log_with_order_factorization_ref_ref_ref(&self, base, m, order_factors)
+        }
+    }
+}
+
+impl ModLogWithOrderFactorization<Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: Natural,
+            m: Natural,
+            order_factors: &[(Natural, u64)],
+        ) -> Option<Natural> {
+            mod_log_with_order_factorization_ref_ref_ref(self, &base, &m, order_factors)
+        }
+    }
+}
+
+impl ModLogWithOrderFactorization<Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: Natural,
+            m: &Natural,
+            order_factors: &[(Natural, u64)],
+        ) -> Option<Natural> {
+            mod_log_with_order_factorization_ref_ref_ref(self, &base, m, order_factors)
+        }
+    }
+}
+
+impl ModLogWithOrderFactorization<&Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: &Natural,
+            m: Natural,
+            order_factors: &[(Natural, u64)],
+        ) -> Option<Natural> {
+            mod_log_with_order_factorization_ref_ref_ref(self, base, &m, order_factors)
+        }
+    }
+}
+
+impl ModLogWithOrderFactorization<&Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_log_with_order_factorization_doc! {
+        #[inline]
+        fn mod_log_with_order_factorization(
+            self,
+            base: &Natural,
+            m: &Natural,
+            order_factors: &[(Natural, u64)],
+        ) -> Option<Natural> {
+            mod_log_with_order_factorization_ref_ref_ref(self, base, m, order_factors)
+        }
+    }
+}
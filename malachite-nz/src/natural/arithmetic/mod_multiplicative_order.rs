@@ -0,0 +1,176 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    Gcd, ModMulPrecomputed, ModMultiplicativeOrder, ModPowPrecomputed, Pow,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Factor;
+
+// Given the prime factorization of $m$, returns the prime factorization of $\varphi(m)$, Euler's
+// totient function, which is the product of $p^{e-1}(p-1)$ over the prime powers $p^e$ exactly
+// dividing $m$. The primes are not sorted.
+pub(crate) fn totient_factors(m_factors: &[(Natural, u64)]) -> Vec<(Natural, u64)> {
+    let mut factors: Vec<(Natural, u64)> = Vec::new();
+    let mut insert = |q: Natural, e: u64| {
+        if let Some((_, f)) = factors.iter_mut().find(|(r, _)| *r == q) {
+            *f += e;
+        } else {
+            factors.push((q, e));
+        }
+    };
+    for (p, e) in m_factors {
+        if *e > 1 {
+            insert(p.clone(), e - 1);
+        }
+        for (q, f) in (p - Natural::ONE).factor() {
+            insert(q, f);
+        }
+    }
+    factors
+}
+
+// The product of $q^e$ over a list of prime powers.
+pub(crate) fn factors_product(factors: &[(Natural, u64)]) -> Natural {
+    let mut n = Natural::ONE;
+    for (q, e) in factors {
+        n *= q.pow(*e);
+    }
+    n
+}
+
+// Computes the multiplicative order of `x` modulo `m`, where `x` must be reduced modulo `m`.
+//
+// As in the primitive implementation, the order divides $\varphi(m)$, so starting from
+// $\varphi(m)$, each prime factor is divided out for as long as `x` raised to the quotient is still
+// 1.
+fn mod_multiplicative_order_ref_ref(x: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    // Since x < m, a `Small` modulus implies a `Small` x.
+    if let Natural(Small(m_small)) = m {
+        let Natural(Small(x_small)) = x else {
+            unreachable!();
+        };
+        return x_small
+            .mod_multiplicative_order(*m_small)
+            .map(Natural::from);
+    }
+    if x.gcd(m) != 1u32 {
+        return None;
+    }
+    let m_factors: Vec<(Natural, u64)> = m.factor().into_iter().collect();
+    let phi_factors = totient_factors(&m_factors);
+    let mut order = factors_product(&phi_factors);
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+    for (q, e) in &phi_factors {
+        for _ in 0..*e {
+            let quotient = &order / q;
+            if x.mod_pow_precomputed(&quotient, m, &data) != 1u32 {
+                break;
+            }
+            order = quotient;
+        }
+    }
+    Some(order)
+}
+
+macro_rules! natural_mod_multiplicative_order_doc {
+    ($f:item) => {
+        /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$: the
+        /// smallest positive $k$ with $x^k \equiv 1 \pmod m$. Returns `None` if $x$ is not coprime
+        /// to $m$, since then no such $k$ exists. The [`Natural`] must be already reduced modulo
+        /// $m$.
+        ///
+        /// The modulus is factored to find $\varphi(m)$, which the order divides, and each of the
+        /// prime factors of $\varphi(m)$ is then removed for as long as the remaining exponent
+        /// still takes $x$ to 1. Modulo 1, the order of 0 is 1.
+        ///
+        /// $f(x, m) = \min \\{k \in \Z^+ : x^k \equiv 1 \pmod m\\}$, if $\gcd(x, m) = 1$.
+        ///
+        /// # Worst-case complexity
+        /// $T(n) = O(2^{n/2})$
+        ///
+        /// $M(n) = O(n)$
+        ///
+        /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`. The cost
+        /// is dominated by factoring $m$ and $p - 1$ for each prime $p$ dividing $m$.
+        ///
+        /// # Panics
+        /// Panics if `self` is greater than or equal to `m`.
+        ///
+        /// # Examples
+        /// ```
+        /// use malachite_base::num::arithmetic::traits::{ModMultiplicativeOrder, Pow};
+        /// use malachite_base::num::basic::traits::One;
+        /// use malachite_nz::natural::Natural;
+        ///
+        /// assert_eq!(
+        ///     Natural::from(3u32).mod_multiplicative_order(Natural::from(7u32)),
+        ///     Some(Natural::from(6u32))
+        /// );
+        /// assert_eq!(
+        ///     Natural::from(6u32).mod_multiplicative_order(Natural::from(9u32)),
+        ///     None
+        /// );
+        /// // 2 ^ 127 - 1 is prime, and the order of 2 is 127
+        /// let m = Natural::from(2u32).pow(127) - Natural::ONE;
+        /// assert_eq!(
+        ///     Natural::from(2u32).mod_multiplicative_order(&m),
+        ///     Some(Natural::from(127u32))
+        /// );
+        /// ```
+        $f
+    };
+}
+
+impl ModMultiplicativeOrder<Self> for Natural {
+    type Output = Self;
+
+    natural_mod_multiplicative_order_doc! {
+        #[inline]
+        fn mod_multiplicative_order(self, m: Self) -> Option<Self> {
+            mod_multiplicative_order_ref_ref(&self, &m)
+        }
+    }
+}
+
+impl<'a> ModMultiplicativeOrder<&'a Self> for Natural {
+    type Output = Self;
+
+    natural_mod_multiplicative_order_doc! {
+        #[inline]
+        fn mod_multiplicative_order(self, m: &'a Self) -> Option<Self> {
+            mod_multiplicative_order_ref_ref(&self, m)
+        }
+    }
+}
+
+impl ModMultiplicativeOrder<Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_multiplicative_order_doc! {
+        #[inline]
+        fn mod_multiplicative_order(self, m: Natural) -> Option<Natural> {
+            mod_multiplicative_order_ref_ref(self, &m)
+        }
+    }
+}
+
+impl ModMultiplicativeOrder<&Natural> for &Natural {
+    type Output = Natural;
+
+    natural_mod_multiplicative_order_doc! {
+        #[inline]
+        fn mod_multiplicative_order(self, m: &Natural) -> Option<Natural> {
+            mod_multiplicative_order_ref_ref(self, m)
+        }
+    }
+}
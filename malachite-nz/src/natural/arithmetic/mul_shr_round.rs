@@ -94,7 +94,18 @@ crate_test_fn! {mul_shr_round_ref_ref(
         // The product is below 2^bits, so the exact value is in (0, 1).
         return match rm {
             Down | Floor => (Natural::ZERO, Less),
-            Up | Ceiling => (Natural::ONE, Greater),
+            Up | Ceiling | Odd => (Natural::ONE, Greater),
+            NearestTiesAway => {
+                if bits > total_limb_bits
+                    || x.significant_bits() + y.significant_bits() < total_limb_bits
+                    || (x * y).significant_bits() < bits
+                {
+                    // The product is below 2^(bits - 1), so the value is below one half.
+                    (Natural::ZERO, Less)
+                } else {
+                    (Natural::ONE, Greater)
+                }
+            }
             Nearest => {
                 if bits > total_limb_bits
                     || x.significant_bits() + y.significant_bits() < total_limb_bits
@@ -159,6 +170,20 @@ crate_test_fn! {mul_shr_round_ref_ref(
                     (k, Less)
                 }
             }
+            NearestTiesAway => {
+                if half {
+                    (k + Natural::ONE, Greater)
+                } else {
+                    (k, Less)
+                }
+            }
+            Odd => {
+                if k.odd() {
+                    (k, Less)
+                } else {
+                    (k + Natural::ONE, Greater)
+                }
+            }
             Exact => unreachable!(),
         }
     } else {
@@ -182,7 +207,9 @@ impl MulShrRound<Self, u64> for Natural {
     ///
     /// $f(x, y, k, \mathrm{Down}) = f(x, y, k, \mathrm{Floor}) = \lfloor xy/2^k \rfloor$, and $f(x,
     /// y, k, \mathrm{Up}) = f(x, y, k, \mathrm{Ceiling}) = \lceil xy/2^k \rceil$;
-    /// $\mathrm{Nearest}$ rounds to the closer integer, breaking ties toward the even one.
+    /// $\mathrm{Nearest}$ rounds to the closer integer, breaking ties toward the even one;
+    /// $\mathrm{NearestTiesAway}$ breaks ties upward instead; and $\mathrm{Odd}$ rounds an inexact
+    /// result to whichever neighboring integer is odd.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n \log n \log\log n)$
@@ -0,0 +1,260 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_context::ModContext;
+use crate::natural::arithmetic::mod_pow::{get_bits, get_window_size};
+use crate::natural::bit_to_limb_count_ceiling;
+use crate::platform::Limb;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{Reverse, max};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{DivRound, FloorLogBase2};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode::*;
+
+// TODO tune
+pub(crate) const MULTI_MOD_POW_PIPPENGER_THRESHOLD: usize = 32;
+
+// Multiplies `acc` by `xs` in `ctx`, where a `None` accumulator stands for 1. `temp` must have the
+// length of $m$, and `scratch` twice that.
+fn mul_into(
+    ctx: &ModContext,
+    acc: &mut Option<Vec<Limb>>,
+    xs: &[Limb],
+    temp: &mut Vec<Limb>,
+    scratch: &mut [Limb],
+) {
+    if let Some(acc) = acc {
+        ctx.limbs_mul(temp, acc, xs, scratch);
+        swap(acc, temp);
+    } else {
+        *acc = Some(xs.to_vec());
+    }
+}
+
+// Squares `acc` in `ctx`, where a `None` accumulator stands for 1.
+fn square_into(
+    ctx: &ModContext,
+    acc: &mut Option<Vec<Limb>>,
+    temp: &mut Vec<Limb>,
+    scratch: &mut [Limb],
+) {
+    if let Some(acc) = acc {
+        ctx.limbs_square(temp, acc, scratch);
+        swap(acc, temp);
+    }
+}
+
+// Given residues of `ctx` and positive exponents, returns the residue of the product of the powers,
+// or `None` if there are no powers.
+//
+// This uses Straus's method with interleaved sliding windows: each base gets a table of odd powers
+// sized for its own exponent, each exponent is split into windows that begin and end with a 1 bit,
+// and all the windows share a single chain of squarings.
+//
+// # Worst-case complexity
+// $T(n, k, l) = O(kln \log n \log\log n)$
+//
+// $M(n, k, l) = O(kn + kl)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `ctx.modulus().significant_bits()`, $k$ is
+// `pairs.len()`, and $l$ is the maximum number of significant bits of the exponents.
+fn multi_mod_pow_straus(ctx: &ModContext, pairs: &[(Vec<Limb>, &Natural)]) -> Option<Vec<Limb>> {
+    let n = ctx.limb_len();
+    let mut temp = vec![0; n];
+    let mut scratch = vec![0; n << 1];
+    let mut tables = Vec::with_capacity(pairs.len());
+    // Each window is a triple: the index of its lowest bit, the index of its pair, and the odd
+    // value of its bits.
+    let mut windows = Vec::new();
+    for (i, (xs, exp)) in pairs.iter().enumerate() {
+        let exp_bits = exp.significant_bits();
+        let window_size = get_window_size(exp_bits);
+        let mut x_squared = vec![0; n];
+        ctx.limbs_square(&mut x_squared, xs, &mut scratch);
+        let table_len = 1 << (window_size - 1);
+        let mut table = vec![0; table_len * n];
+        table[..n].copy_from_slice(xs);
+        for j in 1..table_len {
+            let (table_lo, table_hi) = table.split_at_mut(j * n);
+            ctx.limbs_mul(
+                &mut table_hi[..n],
+                &table_lo[(j - 1) * n..],
+                &x_squared,
+                &mut scratch,
+            );
+        }
+        tables.push(table);
+        let es = exp.as_limbs_asc();
+        let mut bit_index = exp_bits;
+        while bit_index != 0 {
+            if !exp.get_bit(bit_index - 1) {
+                bit_index -= 1;
+                continue;
+            }
+            let bits = get_bits(es, bit_index, window_size);
+            bit_index = bit_index.saturating_sub(window_size);
+            let trailing_zeros = bits.trailing_zeros();
+            windows.push((
+                bit_index + u64::from(trailing_zeros),
+                i,
+                bits >> trailing_zeros,
+            ));
+        }
+    }
+    windows.sort_unstable_by_key(|w| Reverse(w.0));
+    let top = windows.first()?.0;
+    let mut windows = windows.into_iter().peekable();
+    let mut out = None;
+    for bit_index in (0..=top).rev() {
+        square_into(ctx, &mut out, &mut temp, &mut scratch);
+        while windows.peek().is_some_and(|w| w.0 == bit_index) {
+            let (_, i, bits) = windows.next().unwrap();
+            let j = bits >> 1;
+            mul_into(
+                ctx,
+                &mut out,
+                &tables[i][j * n..(j + 1) * n],
+                &mut temp,
+                &mut scratch,
+            );
+        }
+    }
+    out
+}
+
+// Given residues of `ctx` and positive exponents, returns the residue of the product of the powers,
+// or `None` if there are no powers.
+//
+// This uses Pippenger's bucket method. The exponents are cut into $c$-bit digits, and for each
+// digit position, every base is multiplied into the bucket for its digit. If $B_d$ is the product
+// of bucket $d$, the position contributes $\prod_d B_d^d$, which is computed with about $2^{c+1}$
+// multiplications by accumulating running products of the buckets from the top down. With many
+// bases, this costs far fewer multiplications per base than Straus's method.
+//
+// # Worst-case complexity
+// $T(n, k, l) = O(kln \log n \log\log n / \log k)$
+//
+// $M(n, k, l) = O(kn + kl)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `ctx.modulus().significant_bits()`, $k$ is
+// `pairs.len()`, and $l$ is the maximum number of significant bits of the exponents.
+fn multi_mod_pow_pippenger(ctx: &ModContext, pairs: &[(Vec<Limb>, &Natural)]) -> Option<Vec<Limb>> {
+    let n = ctx.limb_len();
+    let mut temp = vec![0; n];
+    let mut scratch = vec![0; n << 1];
+    let digit_size = max(pairs.len().floor_log_base_2().saturating_sub(2), 1);
+    let max_bits = pairs.iter().map(|(_, exp)| exp.significant_bits()).max()?;
+    let digit_count = max_bits.div_round(digit_size, Ceiling).0;
+    // Pad the exponents so that every digit can be read with `get_bits`.
+    let es_len = bit_to_limb_count_ceiling(digit_count * digit_size) + 1;
+    let es: Vec<Vec<Limb>> = pairs
+        .iter()
+        .map(|(_, exp)| {
+            let mut es = exp.to_limbs_asc();
+            es.resize(es_len, 0);
+            es
+        })
+        .collect();
+    let mut buckets = vec![None; (1 << digit_size) - 1];
+    let mut out = None;
+    for d in (0..digit_count).rev() {
+        for _ in 0..digit_size {
+            square_into(ctx, &mut out, &mut temp, &mut scratch);
+        }
+        let end = (d + 1) * digit_size;
+        for ((xs, _), es) in pairs.iter().zip(es.iter()) {
+            let digit = get_bits(es, end, digit_size);
+            if digit != 0 {
+                mul_into(ctx, &mut buckets[digit - 1], xs, &mut temp, &mut scratch);
+            }
+        }
+        // After visiting bucket d, `running` is $\prod_{e \geq d} B_e$ and `total` is
+        // $\prod_{e \geq d} B_e^{e - d + 1}$.
+        let mut running = None;
+        let mut total = None;
+        for bucket in buckets.iter_mut().rev() {
+            if let Some(b) = bucket.take() {
+                mul_into(ctx, &mut running, &b, &mut temp, &mut scratch);
+            }
+            if let Some(r) = &running {
+                mul_into(ctx, &mut total, r, &mut temp, &mut scratch);
+            }
+        }
+        if let Some(t) = total {
+            mul_into(ctx, &mut out, &t, &mut temp, &mut scratch);
+        }
+    }
+    out
+}
+
+impl Natural {
+    /// Computes the product of several powers modulo a [`Natural`] $m$. The bases must be already
+    /// reduced modulo $m$.
+    ///
+    /// $f(((x_1, k_1), \ldots, (x_j, k_j)), m) = y$, where $y < m$ and $\prod_{i=1}^j x_i^{k_i}
+    /// \equiv y \mod m$.
+    ///
+    /// All the powers share a single chain of squarings, so this is faster than computing each
+    /// power with [`mod_pow`](malachite_base::num::arithmetic::traits::ModPow) and multiplying the
+    /// results. A few powers are combined using Straus's method with sliding windows; many are
+    /// combined using Pippenger's bucket method. The computation is done in a [`ModContext`], so
+    /// residues are kept in Montgomery form when $m$ is odd.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k, l) = O(kln \log n \log\log n)$
+    ///
+    /// $M(n, k, l) = O(kn + kl)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, $k$ is
+    /// `pairs.len()`, and $l$ is the maximum number of significant bits of the exponents.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0 or if any base is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // 4^13 * 3^5 = 445 * 243 ≡ 286 mod 497
+    /// assert_eq!(
+    ///     Natural::multi_mod_pow(
+    ///         &[
+    ///             (Natural::from(4u32), Natural::from(13u32)),
+    ///             (Natural::from(3u32), Natural::from(5u32))
+    ///         ],
+    ///         &Natural::from(497u32)
+    ///     ),
+    ///     286
+    /// );
+    /// assert_eq!(Natural::multi_mod_pow(&[], &Natural::from(497u32)), 1);
+    /// ```
+    pub fn multi_mod_pow(pairs: &[(Self, Self)], m: &Self) -> Self {
+        let ctx = ModContext::new(m.clone());
+        for (x, _) in pairs {
+            ctx.assert_reduced(x);
+        }
+        // Powers with exponent 0 are 1, and contribute nothing.
+        let pairs: Vec<(Vec<Limb>, &Self)> = pairs
+            .iter()
+            .filter(|(_, exp)| *exp != 0u32)
+            .map(|(x, exp)| (ctx.to_padded_limbs(&ctx.to_residue(x)), exp))
+            .collect();
+        let out = if pairs.len() < MULTI_MOD_POW_PIPPENGER_THRESHOLD {
+            multi_mod_pow_straus(&ctx, &pairs)
+        } else {
+            multi_mod_pow_pippenger(&ctx, &pairs)
+        };
+        out.map_or_else(
+            || ctx.from_residue(&ctx.one()),
+            |out| ctx.from_residue(&Self::from_owned_limbs_asc(out)),
+        )
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, MultinomialCoefficient};
+use malachite_base::num::basic::traits::One;
+
+impl MultinomialCoefficient for Natural {
+    /// Computes the multinomial coefficient of $k_1, \ldots, k_m$: the number of ways to divide
+    /// $k_1 + \cdots + k_m$ distinct objects into groups of sizes $k_1, \ldots, k_m$.
+    ///
+    /// $$
+    /// f(k_1, \ldots, k_m) = \binom{k_1 + \cdots + k_m}{k_1, \ldots, k_m} = \frac{(k_1 + \cdots +
+    /// k_m)!}{k_1! \cdots k_m!}.
+    /// $$
+    ///
+    /// The empty list has multinomial coefficient 1. The result is computed as the product of the
+    /// binomial coefficients $\binom{k_1 + \cdots + k_i}{k_i}$, with the largest part taken first.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the elements of `ks`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultinomialCoefficient;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::multinomial_coefficient(&[]), 1);
+    /// assert_eq!(Natural::multinomial_coefficient(&[5, 3, 2]), 2520);
+    /// assert_eq!(Natural::multinomial_coefficient(&[4, 4, 4]), 34650);
+    /// assert_eq!(
+    ///     Natural::multinomial_coefficient(&[30, 30, 30]).to_string(),
+    ///     "79607789567531236214574346454361782651136"
+    /// );
+    /// ```
+    fn multinomial_coefficient(ks: &[u64]) -> Self {
+        let mut ks: Vec<u64> = ks.iter().copied().filter(|&k| k != 0).collect();
+        ks.sort_unstable();
+        let Some(largest) = ks.pop() else {
+            return Self::ONE;
+        };
+        let mut sum = Self::from(largest);
+        ks.into_iter()
+            .rev()
+            .map(|k| {
+                let k = Self::from(k);
+                sum += &k;
+                Self::binomial_coefficient(sum.clone(), k)
+            })
+            .product()
+    }
+}
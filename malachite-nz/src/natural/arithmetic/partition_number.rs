@@ -0,0 +1,117 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::PartitionNumber;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+/// Computes the first `len` partition numbers: $p(0)$ through $p(\mathrm{len} - 1)$.
+///
+/// The numbers are generated with Euler's pentagonal number theorem,
+/// $$
+/// p(n) = \sum_{j \geq 1} (-1)^{j+1} \left ( p\left (n - \frac{j(3j-1)}{2}\right ) + p\left (n -
+/// \frac{j(3j+1)}{2}\right ) \right ),
+/// $$
+/// which uses $O(\sqrt n)$ additions and subtractions for each entry.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n^2)$
+///
+/// $M(n) = O(n^{3/2})$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `len`.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::arithmetic::partition_number::partition_numbers_prefix;
+///
+/// assert_eq!(
+///     partition_numbers_prefix(10)
+///         .iter()
+///         .map(|p| p.to_string())
+///         .collect::<Vec<_>>(),
+///     ["1", "1", "2", "3", "5", "7", "11", "15", "22", "30"]
+/// );
+/// assert!(partition_numbers_prefix(0).is_empty());
+/// ```
+///
+/// This is equivalent to `arith_number_of_partitions_vec` from FLINT 3.6.0, using the pentagonal
+/// recurrence rather than power series inversion.
+pub fn partition_numbers_prefix(len: u64) -> Vec<Natural> {
+    let len = usize::exact_from(len);
+    let mut ps: Vec<Natural> = Vec::with_capacity(len);
+    for i in 0..len {
+        if i == 0 {
+            ps.push(Natural::ONE);
+            continue;
+        }
+        // The positive and negative terms are summed separately, so that only one subtraction is
+        // needed.
+        let mut positive = Natural::ZERO;
+        let mut negative = Natural::ZERO;
+        let mut j = 1;
+        loop {
+            let g = j * (3 * j - 1) / 2;
+            if g > i {
+                break;
+            }
+            let sum = if j & 1 == 1 {
+                &mut positive
+            } else {
+                &mut negative
+            };
+            *sum += &ps[i - g];
+            if g + j <= i {
+                *sum += &ps[i - g - j];
+            }
+            j += 1;
+        }
+        ps.push(positive - negative);
+    }
+    ps
+}
+
+impl PartitionNumber for Natural {
+    /// Computes the number of partitions of $n$: the number of ways to write $n$ as a sum of
+    /// positive integers, disregarding order.
+    ///
+    /// $p(n)$ has $\Theta(\sqrt n)$ bits. When it fits in a `u128` it is computed with primitive
+    /// arithmetic; otherwise all the values up to $p(n)$ are computed with Euler's pentagonal
+    /// number theorem. For large $n$, the Hardy–Ramanujan–Rademacher formula, as implemented by
+    /// `malachite_float::arithmetic::partition_number::partition_number_hrr`, is much faster.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n^{3/2})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PartitionNumber;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::partition_number(0), 1);
+    /// assert_eq!(Natural::partition_number(5), 7);
+    /// assert_eq!(Natural::partition_number(100), 190569292);
+    /// assert_eq!(
+    ///     Natural::partition_number(2000).to_string(),
+    ///     "4720819175619413888601432406799959512200344166"
+    /// );
+    /// ```
+    fn partition_number(n: u64) -> Self {
+        if n <= 1458 {
+            Self::from(u128::partition_number(n))
+        } else {
+            partition_numbers_prefix(n + 1).pop().unwrap()
+        }
+    }
+}
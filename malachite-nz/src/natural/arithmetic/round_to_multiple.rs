@@ -8,7 +8,9 @@
 
 use crate::natural::Natural;
 use core::cmp::Ordering::{self, *};
-use malachite_base::num::arithmetic::traits::{RoundToMultiple, RoundToMultipleAssign};
+use malachite_base::num::arithmetic::traits::{
+    DivExact, Parity, RoundToMultiple, RoundToMultipleAssign,
+};
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::comparison::traits::OrdDouble;
 use malachite_base::rounding_modes::RoundingMode::{self, *};
@@ -39,6 +41,21 @@ impl RoundToMultiple<Self> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -150,6 +167,21 @@ impl RoundToMultiple<&Self> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -261,6 +293,21 @@ impl RoundToMultiple<Natural> for &Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -342,7 +389,7 @@ impl RoundToMultiple<Natural> for &Natural {
         match (self, other) {
             (x, y) if *x == y => (y, Equal),
             (x, Natural::ZERO) => match rm {
-                Down | Floor | Nearest => (Natural::ZERO, Less),
+                Down | Floor | Nearest | NearestTiesAway => (Natural::ZERO, Less),
                 _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
             },
             (x, y) => {
@@ -373,6 +420,20 @@ impl RoundToMultiple<Natural> for &Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if y.cmp_double(&r) == Greater {
+                                (floor, Less)
+                            } else {
+                                (floor + y, Greater)
+                            }
+                        }
+                        Odd => {
+                            if (&floor).div_exact(&y).odd() {
+                                (floor, Less)
+                            } else {
+                                (floor + y, Greater)
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -409,6 +470,21 @@ impl RoundToMultiple<&Natural> for &Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -490,7 +566,7 @@ impl RoundToMultiple<&Natural> for &Natural {
         match (self, other) {
             (x, y) if x == y => (x.clone(), Equal),
             (x, &Natural::ZERO) => match rm {
-                Down | Floor | Nearest => (Natural::ZERO, Less),
+                Down | Floor | Nearest | NearestTiesAway => (Natural::ZERO, Less),
                 _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
             },
             (x, y) => {
@@ -521,6 +597,20 @@ impl RoundToMultiple<&Natural> for &Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if y.cmp_double(&r) == Greater {
+                                (floor, Less)
+                            } else {
+                                (floor + y, Greater)
+                            }
+                        }
+                        Odd => {
+                            if (&floor).div_exact(y).odd() {
+                                (floor, Less)
+                            } else {
+                                (floor + y, Greater)
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -615,7 +705,7 @@ impl RoundToMultipleAssign<Self> for Natural {
         match (&mut *self, other) {
             (x, y) if *x == y => Equal,
             (x, Self::ZERO) => match rm {
-                Down | Floor | Nearest => {
+                Down | Floor | Nearest | NearestTiesAway => {
                     *self = Self::ZERO;
                     Less
                 }
@@ -656,6 +746,22 @@ impl RoundToMultipleAssign<Self> for Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if y.cmp_double(&r) == Greater {
+                                Less
+                            } else {
+                                *x += y;
+                                Greater
+                            }
+                        }
+                        Odd => {
+                            if (&*x).div_exact(&y).odd() {
+                                Less
+                            } else {
+                                *x += y;
+                                Greater
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -753,7 +859,7 @@ impl RoundToMultipleAssign<&Self> for Natural {
         match (&mut *self, other) {
             (x, y) if *x == *y => Equal,
             (x, &Self::ZERO) => match rm {
-                Down | Floor | Nearest => {
+                Down | Floor | Nearest | NearestTiesAway => {
                     *self = Self::ZERO;
                     Less
                 }
@@ -794,6 +900,22 @@ impl RoundToMultipleAssign<&Self> for Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if y.cmp_double(&r) == Greater {
+                                Less
+                            } else {
+                                *x += y;
+                                Greater
+                            }
+                        }
+                        Odd => {
+                            if (&*x).div_exact(y).odd() {
+                                Less
+                            } else {
+                                *x += y;
+                                Greater
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
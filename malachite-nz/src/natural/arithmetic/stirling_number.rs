@@ -0,0 +1,327 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, Factorial, Parity, Pow, PowerOf2, Square, StirlingNumber1, StirlingNumber2,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The number of limbs in a Kronecker slot wide enough to hold any value with at most `bits` bits.
+fn slot_len(bits: u64) -> usize {
+    usize::exact_from(bits.div_ceil(Limb::WIDTH).max(1))
+}
+
+// Packs a sequence of coefficients into a single Natural, each coefficient occupying `slot` limbs.
+// Every coefficient must fit in its slot.
+fn kronecker_pack(xs: &[Natural], slot: usize) -> Natural {
+    let mut limbs = vec![0; xs.len() * slot];
+    for (chunk, x) in limbs.chunks_mut(slot).zip(xs) {
+        for (out, limb) in chunk.iter_mut().zip(x.limbs()) {
+            *out = limb;
+        }
+    }
+    Natural::from_owned_limbs_asc(limbs)
+}
+
+// The inverse of `kronecker_pack`: splits a Natural into `len` coefficients of `slot` limbs each.
+fn kronecker_unpack(x: &Natural, slot: usize, len: usize) -> Vec<Natural> {
+    let limbs = x.to_limbs_asc();
+    let mut chunks = limbs.chunks(slot);
+    (0..len)
+        .map(|_| chunks.next().map_or(Natural::ZERO, Natural::from_limbs_asc))
+        .collect()
+}
+
+// Computes an unsigned Stirling number of the first kind (if `first_kind` is true) or a Stirling
+// number of the second kind along the diagonal band between (k, k) and (n, k), using X(i + 1, j) =
+// f * X(i, j) + X(i, j - 1), with f = i for the first kind and f = j for the second. This takes
+// O((n - k) k) small multiplications and additions, so it is the method of choice when n - k is
+// small. Requires 0 < k <= n.
+fn stirling_number_band(n: u64, k: u64, first_kind: bool) -> Natural {
+    // row[j] holds X(j + t, j) for the current diagonal offset t, starting from X(j, j) = 1.
+    let uk = usize::exact_from(k);
+    let mut row = vec![Natural::ONE; uk + 1];
+    for t in 1..=n - k {
+        row[0] = Natural::ZERO;
+        for j in 1..=uk {
+            let j_64 = u64::exact_from(j);
+            row[j] *= Natural::from(if first_kind { j_64 + t - 1 } else { j_64 });
+            let (lo, hi) = row.split_at_mut(j);
+            hi[0] += &lo[j - 1];
+        }
+    }
+    row.pop().unwrap()
+}
+
+// Whether the band recurrence is expected to beat the alternative (the whole row, for the first
+// kind, or the alternating power sum, for the second). The band costs about d^2 k n-bit-word
+// operations, where d = n - k, since its values have O(d log n) bits.
+fn use_band(n: u64, k: u64, first_kind: bool) -> bool {
+    let d = u128::from(n - k);
+    if first_kind {
+        d * d * u128::from(k) <= u128::from(n) * u128::from(n)
+    } else {
+        d < u128::from(k) && d * d <= u128::from(n) << 4
+    }
+}
+
+/// Computes a whole row of unsigned Stirling numbers of the first kind: $\left[{n \atop
+/// 0}\right]$ through $\left[{n \atop n}\right]$.
+///
+/// The row is the list of coefficients of the rising factorial $x(x + 1) \cdots (x + n - 1)$. All
+/// of them are at most their sum, $n!$, so the polynomial can be evaluated at a power of 2 large
+/// enough that the coefficients don't overlap (Kronecker substitution), reducing the whole
+/// computation to a single product of $n - 1$ integers.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n^2 \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::arithmetic::stirling_number::stirling_number_1_row;
+///
+/// assert_eq!(
+///     stirling_number_1_row(5)
+///         .iter()
+///         .map(|c| c.to_string())
+///         .collect::<Vec<_>>(),
+///     ["0", "24", "50", "35", "10", "1"]
+/// );
+/// assert_eq!(stirling_number_1_row(0).len(), 1);
+/// ```
+///
+/// This is equivalent to `arith_stirling_number_1u_vec` from FLINT 3.6.0.
+pub fn stirling_number_1_row(n: u64) -> Vec<Natural> {
+    if n == 0 {
+        return vec![Natural::ONE];
+    }
+    let slot = slot_len(Natural::factorial(n).significant_bits());
+    let base = Natural::power_of_2(u64::exact_from(slot) * Limb::WIDTH);
+    // x(x + 1)...(x + n - 1) has no constant term, so only the product of the other factors is
+    // formed, and its coefficients are shifted up by one.
+    let p: Natural = (1..n).map(|i| &base + Natural::from(i)).product();
+    let mut row = Vec::with_capacity(usize::exact_from(n) + 1);
+    row.push(Natural::ZERO);
+    row.extend(kronecker_unpack(&p, slot, usize::exact_from(n)));
+    row
+}
+
+// Computes a row of Stirling numbers of the second kind with the triangle recurrence, updating a
+// single row in place. This takes O(n^2) additions and small multiplications.
+crate_test_fn! {stirling_number_2_row_triangle(n: u64) -> Vec<Natural> {
+    let mut row = vec![Natural::ONE];
+    for _ in 0..n {
+        row.push(Natural::ZERO);
+        for j in (1..row.len()).rev() {
+            row[j] *= Natural::from(u64::exact_from(j));
+            let (lo, hi) = row.split_at_mut(j);
+            hi[0] += &lo[j - 1];
+        }
+        row[0] = Natural::ZERO;
+    }
+    row
+}}
+
+// Computes a row of Stirling numbers of the second kind from the convolution
+//
+// S(n, k) = sum_{j + i = k} (j^n / j!) ((-1)^i / i!).
+//
+// After scaling both sequences by n!, the terms are integers; the terms with even and odd i are
+// convolved separately, each with a single Kronecker-substituted multiplication, and the difference
+// is divided by (n!)^2.
+crate_test_fn! {stirling_number_2_row_convolution(n: u64) -> Vec<Natural> {
+    let un = usize::exact_from(n);
+    // bs[i] = n! / i!
+    let mut bs = vec![Natural::ONE; un + 1];
+    for i in (0..un).rev() {
+        bs[i] = &bs[i + 1] * Natural::from(u64::exact_from(i) + 1);
+    }
+    // as_[j] = j^n n! / j!
+    let as_: Vec<Natural> = bs
+        .iter()
+        .enumerate()
+        .map(|(j, b)| Natural::from(u64::exact_from(j)).pow(n) * b)
+        .collect();
+    let mut evens = Vec::with_capacity(un + 1);
+    let mut odds = Vec::with_capacity(un + 1);
+    for (i, b) in bs.iter().enumerate() {
+        if i.even() {
+            evens.push(b.clone());
+            odds.push(Natural::ZERO);
+        } else {
+            evens.push(Natural::ZERO);
+            odds.push(b.clone());
+        }
+    }
+    // Each coefficient of either product is a sum of at most n + 1 products of an a and a b.
+    let max_a_bits = as_.iter().map(SignificantBits::significant_bits).max().unwrap();
+    let slot = slot_len(max_a_bits + bs[0].significant_bits() + (n + 1).significant_bits());
+    let packed_as = kronecker_pack(&as_, slot);
+    let ps = kronecker_unpack(&(&packed_as * kronecker_pack(&evens, slot)), slot, un + 1);
+    let qs = kronecker_unpack(&(packed_as * kronecker_pack(&odds, slot)), slot, un + 1);
+    let f_squared = bs.swap_remove(0).square();
+    ps.into_iter()
+        .zip(qs)
+        .map(|(p, q)| (p - q).div_exact(&f_squared))
+        .collect()
+}}
+
+// Below this n, rows of Stirling numbers of the second kind are computed by the triangle
+// recurrence; at or above it, by convolution.
+const STIRLING_2_ROW_CONVOLUTION_THRESHOLD: u64 = 64;
+
+/// Computes a whole row of Stirling numbers of the second kind: $\left\\{{n \atop 0}\right\\}$
+/// through $\left\\{{n \atop n}\right\\}$.
+///
+/// Short rows come from the triangle recurrence; long ones from the convolution $\left\\{{n \atop
+/// k}\right\\} = \sum_{i+j=k} \frac{j^n}{j!} \frac{(-1)^i}{i!}$, evaluated with two large
+/// multiplications.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+///
+/// $M(n) = O(n^2 \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::arithmetic::stirling_number::stirling_number_2_row;
+///
+/// assert_eq!(
+///     stirling_number_2_row(5)
+///         .iter()
+///         .map(|c| c.to_string())
+///         .collect::<Vec<_>>(),
+///     ["0", "1", "15", "25", "10", "1"]
+/// );
+/// assert_eq!(stirling_number_2_row(0).len(), 1);
+/// ```
+///
+/// This is equivalent to `arith_stirling_number_2_vec` from FLINT 3.6.0.
+pub fn stirling_number_2_row(n: u64) -> Vec<Natural> {
+    if n < STIRLING_2_ROW_CONVOLUTION_THRESHOLD {
+        stirling_number_2_row_triangle(n)
+    } else {
+        stirling_number_2_row_convolution(n)
+    }
+}
+
+impl StirlingNumber1 for Natural {
+    /// Computes the unsigned Stirling number of the first kind $\left[{n \atop k}\right]$: the
+    /// number of permutations of $n$ elements with exactly $k$ cycles.
+    ///
+    /// Values near the diagonal are computed with the triangle recurrence restricted to a narrow
+    /// band; the others are read off the whole row, which is found with a single large
+    /// multiplication.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingNumber1;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_number_1(0, 0), 1);
+    /// assert_eq!(Natural::stirling_number_1(5, 2), 50);
+    /// assert_eq!(Natural::stirling_number_1(10, 3), 1172700);
+    /// assert_eq!(
+    ///     Natural::stirling_number_1(30, 10).to_string(),
+    ///     "215760462268683520394805979744"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `arith_stirling_number_1u` from FLINT 3.6.0.
+    fn stirling_number_1(n: u64, k: u64) -> Self {
+        if k > n || k == 0 && n != 0 {
+            Self::ZERO
+        } else if k == n {
+            Self::ONE
+        } else if k == 1 {
+            Self::factorial(n - 1)
+        } else if use_band(n, k, true) {
+            stirling_number_band(n, k, true)
+        } else {
+            stirling_number_1_row(n).swap_remove(usize::exact_from(k))
+        }
+    }
+}
+
+impl StirlingNumber2 for Natural {
+    /// Computes the Stirling number of the second kind $\left\\{{n \atop k}\right\\}$: the number
+    /// of ways to partition a set of $n$ elements into $k$ nonempty subsets.
+    ///
+    /// Values near the diagonal are computed with the triangle recurrence restricted to a narrow
+    /// band; the others use the alternating sum
+    /// $$
+    /// \left\\{{n \atop k}\right\\} = \frac{1}{k!} \sum_{j=0}^k (-1)^{k-j} \binom{k}{j} j^n.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingNumber2;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_number_2(0, 0), 1);
+    /// assert_eq!(Natural::stirling_number_2(5, 2), 15);
+    /// assert_eq!(Natural::stirling_number_2(10, 3), 9330);
+    /// assert_eq!(
+    ///     Natural::stirling_number_2(40, 20).to_string(),
+    ///     "162188909527975750487887236507181"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `arith_stirling_number_2` from FLINT 3.6.0.
+    fn stirling_number_2(n: u64, k: u64) -> Self {
+        if k > n || k == 0 && n != 0 {
+            Self::ZERO
+        } else if k == n || k == 1 {
+            Self::ONE
+        } else if use_band(n, k, false) {
+            stirling_number_band(n, k, false)
+        } else {
+            let mut positive = Self::ZERO;
+            let mut negative = Self::ZERO;
+            // binomial = C(k, j); the j = 0 term vanishes, since n > 0.
+            let mut binomial = Self::from(k);
+            for j in 1..=k {
+                let term = &binomial * Self::from(j).pow(n);
+                if (k - j).even() {
+                    positive += term;
+                } else {
+                    negative += term;
+                }
+                binomial = (binomial * Self::from(k - j)).div_exact(Self::from(j + 1));
+            }
+            (positive - negative).div_exact(Self::factorial(k))
+        }
+    }
+}
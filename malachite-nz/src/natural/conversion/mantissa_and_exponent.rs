@@ -155,7 +155,7 @@ impl Natural {
                     if rm == Exact {
                         return None;
                     }
-                    if rm == Nearest {
+                    if rm == Nearest || rm == NearestTiesAway {
                         // If `exact` is true here, that means all lower limbs are 0
                         half_compare = if exact && one_index == shift - 1 {
                             Equal
@@ -178,7 +178,7 @@ impl Natural {
                 }
             }
             Equal => {
-                if !exact && rm == Nearest {
+                if !exact && (rm == Nearest || rm == NearestTiesAway) {
                     // len is at least 4, since the only way `exact` is false at this point is if
                     // xs[..len - 3] is nonzero
                     half_compare = highest_discarded_limb.cmp(&LIMB_HIGH_BIT);
@@ -193,7 +193,9 @@ impl Natural {
             && (rm == Up
                 || rm == Ceiling
                 || rm == Nearest
-                    && (half_compare == Greater || half_compare == Equal && raw_mantissa.odd()));
+                    && (half_compare == Greater || half_compare == Equal && raw_mantissa.odd())
+                || rm == NearestTiesAway && half_compare != Less
+                || rm == Odd && raw_mantissa.even());
         Some(if increment {
             let next_mantissa = mantissa.next_higher();
             if next_mantissa == T::TWO {
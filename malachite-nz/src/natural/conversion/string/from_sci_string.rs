@@ -98,11 +98,11 @@ where
         if neg_exponent > sig_len {
             let s = if sign { &s[1..] } else { &s[..] };
             return match rm {
-                Down | Floor | Nearest => {
+                Down | Floor | Nearest | NearestTiesAway => {
                     validate_helper(s, options.get_base())?;
                     Some(T::ZERO)
                 }
-                Up | Ceiling => {
+                Up | Ceiling | Odd => {
                     if is_zero_helper(s, options.get_base())? {
                         Some(T::ZERO)
                     } else {
@@ -150,6 +150,17 @@ where
                     }
                 }
             },
+            NearestTiesAway => match cmp_half_helper(after_e, options.get_base())? {
+                Less => Some(x),
+                Greater | Equal => x.up_1(neg),
+            },
+            Odd => {
+                if is_zero_helper(after_e, options.get_base())? || x.odd() {
+                    Some(x)
+                } else {
+                    x.up_1(neg)
+                }
+            }
         }
     }
 }
@@ -0,0 +1,181 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::factorization::factor::NaturalFactors;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{DivisorCount, DivisorSigma, Factor};
+
+impl NaturalFactors {
+    /// Computes the divisor function $\sigma_k(n)$ of the [`Natural`] $n$ whose prime factorization
+    /// this is.
+    ///
+    /// $$
+    /// \sigma_k(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m \sum_{j=0}^{e_i} p_i^{jk}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(nk \log (nk) \log\log (nk))$
+    ///
+    /// $M(n, k) = O(nk)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of bits of the factored
+    /// number, and $k$ is `k`.
+    ///
+    /// # Panics
+    /// Panics if the factorization is not complete.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).factor().divisor_sigma(5), 1);
+    /// assert_eq!(Natural::from(12u32).factor().divisor_sigma(1), 28);
+    /// assert_eq!(
+    ///     Natural::from(12u32).factor().divisor_sigma(10).to_string(),
+    ///     "61978939050"
+    /// );
+    /// ```
+    pub fn divisor_sigma(&self, k: u64) -> Natural {
+        assert!(self.is_complete(), "the factorization is not complete");
+        let mut sigma = Natural::ONE;
+        for (p, e) in self.prime_factors() {
+            let pk = p.pow(k);
+            let mut s = Natural::ONE;
+            for _ in 0..*e {
+                s *= &pk;
+                s += Natural::ONE;
+            }
+            sigma *= s;
+        }
+        sigma
+    }
+
+    /// Counts the divisors of the [`Natural`] $n$ whose prime factorization this is; this is
+    /// $\tau(n) = \sigma_0(n)$.
+    ///
+    /// $$
+    /// \tau(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m (e_i + 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of distinct prime factors.
+    ///
+    /// # Panics
+    /// Panics if the factorization is not complete.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).factor().divisor_count(), 1);
+    /// assert_eq!(Natural::from(12u32).factor().divisor_count(), 6);
+    /// assert_eq!(Natural::from(720720u32).factor().divisor_count(), 240);
+    /// ```
+    pub fn divisor_count(&self) -> Natural {
+        assert!(self.is_complete(), "the factorization is not complete");
+        let mut tau = Natural::ONE;
+        for (_, e) in self.prime_factors() {
+            tau *= Natural::from(*e) + Natural::ONE;
+        }
+        tau
+    }
+}
+
+impl DivisorSigma for Natural {
+    type Output = Self;
+
+    /// Computes the divisor function $\sigma_k(n)$ of a [`Natural`]: the sum of the $k$th powers
+    /// of the divisors of $n$.
+    ///
+    /// $$
+    /// \sigma_k(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m \sum_{j=0}^{e_i} p_i^{jk}.
+    /// $$
+    ///
+    /// $\sigma_0(n)$ is the number of divisors of $n$, and $\sigma_1(n)$ is their sum.
+    ///
+    /// If the factorization of $n$ is already known, use [`NaturalFactors::divisor_sigma`]
+    /// instead.
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: for
+    /// small `k`, the cost is dominated by factoring $n$; see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::DivisorSigma;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).divisor_sigma(1), 1);
+    /// assert_eq!(Natural::from(12u32).divisor_sigma(0), 6);
+    /// assert_eq!(Natural::from(12u32).divisor_sigma(1), 28);
+    /// assert_eq!(Natural::from(12u32).divisor_sigma(2), 210);
+    /// assert_eq!(
+    ///     Natural::from(2u32).pow(100).divisor_sigma(1),
+    ///     Natural::from(2u32).pow(101) - Natural::from(1u32)
+    /// );
+    /// ```
+    #[inline]
+    fn divisor_sigma(&self, k: u64) -> Self {
+        self.factor().divisor_sigma(k)
+    }
+}
+
+impl DivisorCount for Natural {
+    type Output = Self;
+
+    /// Counts the divisors of a [`Natural`] $n$; this is $\tau(n) = \sigma_0(n)$.
+    ///
+    /// $$
+    /// \tau(p_1^{e_1} \cdots p_m^{e_m}) = \prod_{i=1}^m (e_i + 1).
+    /// $$
+    ///
+    /// If the factorization of $n$ is already known, use [`NaturalFactors::divisor_count`]
+    /// instead.
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: the cost
+    /// is dominated by factoring $n$; see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::DivisorCount;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).divisor_count(), 1);
+    /// assert_eq!(Natural::from(12u32).divisor_count(), 6);
+    /// assert_eq!(Natural::from(10u32).pow(30).divisor_count(), 961);
+    /// ```
+    #[inline]
+    fn divisor_count(&self) -> Self {
+        self.factor().divisor_count()
+    }
+}
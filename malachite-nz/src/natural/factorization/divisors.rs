@@ -0,0 +1,103 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::factorization::factor::NaturalFactors;
+use malachite_base::num::factorization::divisors::DivisorsIterator;
+use malachite_base::num::factorization::traits::{Divisors, Factor};
+
+impl NaturalFactors {
+    /// Returns an iterator over the divisors of the [`Natural`] whose prime factorization this is,
+    /// in ascending order.
+    ///
+    /// The divisors are generated lazily, so it is cheap to find the smallest few divisors of a
+    /// number with very many divisors.
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n, m) = O(m n \log n \log\log n)$
+    ///
+    /// $M(n, m) = O(mn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of bits of the factored
+    /// number, and $m$ is the number of distinct prime factors.
+    ///
+    /// # Panics
+    /// Panics if the factorization is not complete.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(60u32)
+    ///         .factor()
+    ///         .divisors()
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]"
+    /// );
+    /// ```
+    pub fn divisors(&self) -> DivisorsIterator<Natural> {
+        assert!(self.is_complete(), "the factorization is not complete");
+        DivisorsIterator::from_prime_factors(self.prime_factors().iter().cloned())
+    }
+}
+
+impl Divisors for Natural {
+    type I = DivisorsIterator<Self>;
+
+    /// Returns an iterator over the divisors of a [`Natural`], in ascending order.
+    ///
+    /// The divisors are generated lazily, so it is cheap to find the smallest few divisors of a
+    /// number with very many divisors. Each iteration takes time logarithmic in the number of
+    /// divisors, plus one multiplication for each distinct prime factor.
+    ///
+    /// If the factorization of $n$ is already known, use [`NaturalFactors::divisors`] instead.
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: the cost
+    /// is dominated by factoring $n$; see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_base::num::factorization::traits::Divisors;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(12u32).divisors().collect_vec().to_debug_string(),
+    ///     "[1, 2, 3, 4, 6, 12]"
+    /// );
+    /// // 10 ^ 30 has 961 divisors
+    /// assert_eq!(
+    ///     Natural::from(10u32)
+    ///         .pow(30)
+    ///         .divisors()
+    ///         .take(12)
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[1, 2, 4, 5, 8, 10, 16, 20, 25, 32, 40, 50]"
+    /// );
+    /// ```
+    #[inline]
+    fn divisors(&self) -> DivisorsIterator<Self> {
+        self.factor().divisors()
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    Gcd, ModAdd, ModInverse, ModMulPrecomputed, ModMulPrecomputedAssign, ModSquarePrecomputed,
+    ModSub,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::BitIterable;
+
+// The giant step of stage 2. Since 2310 = 2 * 3 * 5 * 7 * 11, only the 240 baby steps j < 1155
+// coprime to it are needed, which is about a tenth of the giant step.
+const ECM_STAGE_2_GIANT_STEP: u64 = 2310;
+
+// A point on a Montgomery curve $By^2 = x^3 + Ax^2 + x$, in projective coordinates $(X : Z)$; the
+// $y$-coordinate is not needed. The point at infinity is the one with $Z = 0$.
+#[derive(Clone)]
+struct MontgomeryPoint {
+    x: Natural,
+    z: Natural,
+}
+
+// A Montgomery curve modulo the composite n, represented by $(A + 2) / 4$.
+struct MontgomeryCurve<'a> {
+    n: &'a Natural,
+    data: &'a ModMulData,
+    a24: Natural,
+}
+
+impl MontgomeryCurve<'_> {
+    fn mul(&self, x: &Natural, y: &Natural) -> Natural {
+        x.mod_mul_precomputed(y, self.n, self.data)
+    }
+
+    fn square(&self, x: &Natural) -> Natural {
+        x.mod_square_precomputed(self.n, self.data)
+    }
+
+    // Returns 2P.
+    fn double(&self, p: &MontgomeryPoint) -> MontgomeryPoint {
+        let sum = (&p.x).mod_add(&p.z, self.n);
+        let diff = (&p.x).mod_sub(&p.z, self.n);
+        let sum_squared = self.square(&sum);
+        let diff_squared = self.square(&diff);
+        let t = (&sum_squared).mod_sub(&diff_squared, self.n);
+        let z = self.mul(
+            &t,
+            &(self.mul(&self.a24, &t)).mod_add(&diff_squared, self.n),
+        );
+        MontgomeryPoint {
+            x: self.mul(&sum_squared, &diff_squared),
+            z,
+        }
+    }
+
+    // Returns P + Q, given P - Q.
+    fn add(
+        &self,
+        p: &MontgomeryPoint,
+        q: &MontgomeryPoint,
+        diff: &MontgomeryPoint,
+    ) -> MontgomeryPoint {
+        let u = self.mul(&(&p.x).mod_sub(&p.z, self.n), &(&q.x).mod_add(&q.z, self.n));
+        let v = self.mul(&(&p.x).mod_add(&p.z, self.n), &(&q.x).mod_sub(&q.z, self.n));
+        MontgomeryPoint {
+            x: self.mul(&diff.z, &self.square(&(&u).mod_add(&v, self.n))),
+            z: self.mul(&diff.x, &self.square(&u.mod_sub(v, self.n))),
+        }
+    }
+
+    // Returns kP, for k > 0, using the Montgomery ladder.
+    fn multiply(&self, p: &MontgomeryPoint, k: u64) -> MontgomeryPoint {
+        let mut r_0 = p.clone();
+        let mut r_1 = self.double(p);
+        for bit in k.bits().rev().skip(1) {
+            if bit {
+                r_0 = self.add(&r_1, &r_0, p);
+                r_1 = self.double(&r_1);
+            } else {
+                r_1 = self.add(&r_1, &r_0, p);
+                r_0 = self.double(&r_0);
+            }
+        }
+        r_0
+    }
+}
+
+// Returns the gcd of `x` and `n` if it is a nontrivial factor of `n`.
+fn nontrivial_gcd(x: &Natural, n: &Natural) -> Option<Natural> {
+    let g = x.gcd(n);
+    if g == 1u32 || g == *n { None } else { Some(g) }
+}
+
+// Runs one curve of Lenstra's elliptic curve method on the odd composite `n`, which has no small
+// prime factors, returning a nontrivial factor if one is found.
+//
+// The curve and starting point come from Suyama's parametrization with parameter `sigma`, which
+// guarantees that the group order is divisible by 12. Stage 1 multiplies the point by every prime
+// power up to `b1`. Stage 2 is the standard baby-step giant-step continuation: it finds a factor
+// $p$ if the group order modulo $p$ is `b1`-smooth apart from a single prime up to `b2`.
+pub(crate) fn ecm(n: &Natural, data: &ModMulData, sigma: u64, b1: u64, b2: u64) -> Option<Natural> {
+    let mul = |x: &Natural, y: &Natural| x.mod_mul_precomputed(y, n, data);
+    // u = sigma ^ 2 - 5, v = 4 * sigma
+    // n has no small prime factors, so it is larger than these constants.
+    let sigma = Natural::from(sigma);
+    let u = (&sigma)
+        .mod_square_precomputed(n, data)
+        .mod_sub(Natural::from(5u32), n);
+    let v = mul(&sigma, &Natural::from(4u32));
+    let u_cubed = mul(&u, &(&u).mod_square_precomputed(n, data));
+    let v_cubed = mul(&v, &(&v).mod_square_precomputed(n, data));
+    // (A + 2) / 4 = (v - u) ^ 3 * (3u + v) / (16 * u ^ 3 * v)
+    let v_minus_u = (&v).mod_sub(&u, n);
+    let numerator = mul(
+        &mul(&v_minus_u, &(&v_minus_u).mod_square_precomputed(n, data)),
+        &mul(&Natural::from(3u32), &u).mod_add(&v, n),
+    );
+    let denominator = mul(&mul(&Natural::from(16u32), &u_cubed), &v);
+    if denominator == 0u32 {
+        return None;
+    }
+    let Some(inverse) = (&denominator).mod_inverse(n) else {
+        return nontrivial_gcd(&denominator, n);
+    };
+    let curve = MontgomeryCurve {
+        n,
+        data,
+        a24: mul(&numerator, &inverse),
+    };
+    let mut q = MontgomeryPoint {
+        x: u_cubed,
+        z: v_cubed,
+    };
+    // Stage 1
+    for p in u64::primes_less_than_or_equal_to(&b1) {
+        let mut power = p;
+        while let Some(next) = power.checked_mul(p).filter(|&next| next <= b1) {
+            power = next;
+        }
+        q = curve.multiply(&q, power);
+    }
+    let g = (&q.z).gcd(n);
+    if g == *n {
+        return None;
+    } else if g != 1u32 {
+        return Some(g);
+    }
+    if b2 <= b1 {
+        return None;
+    }
+    // Stage 2. The baby steps are jQ for odd j < D / 2 coprime to D. Since the x-coordinates of jQ
+    // and -jQ agree, comparing them with those of the giant steps mDQ detects mD + j and mD - j.
+    const D: u64 = ECM_STAGE_2_GIANT_STEP;
+    let q_2 = curve.double(&q);
+    let mut baby_steps = Vec::new();
+    let mut previous = q.clone();
+    let mut current = curve.add(&q_2, &q, &q);
+    baby_steps.push(q.clone());
+    let mut j = 3;
+    while j < D >> 1 {
+        if D.gcd(j) == 1 {
+            baby_steps.push(current.clone());
+        }
+        let next = curve.add(&current, &q_2, &previous);
+        previous = current;
+        current = next;
+        j += 2;
+    }
+    let q_d = curve.multiply(&q, D);
+    let m_0 = (b1 / D).max(1);
+    let mut r = curve.multiply(&q, m_0 * D);
+    let mut r_next = curve.multiply(&q, (m_0 + 1) * D);
+    let mut product = Natural::ONE;
+    for _ in m_0..=b2 / D {
+        for step in &baby_steps {
+            let difference = mul(&r.x, &step.z).mod_sub(mul(&step.x, &r.z), n);
+            product.mod_mul_precomputed_assign(difference, n, data);
+        }
+        let r_next_next = curve.add(&r_next, &q_d, &r);
+        r = r_next;
+        r_next = r_next_next;
+    }
+    nontrivial_gcd(&product, n)
+}
@@ -0,0 +1,654 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::factorization::factor::ecm::ecm;
+use crate::natural::factorization::factor::siqs::siqs;
+use crate::platform::{DoubleLimb, Limb};
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    Gcd, ModAdd, ModMulPrecomputed, ModMulPrecomputedAssign, ModPow, ModSquarePrecomputed, ModSub,
+    Pow,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{
+    ExpressAsPower, Factor, IsPrime, Primes, RemovePowerAssign,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+
+/// Code for Lenstra's elliptic curve method.
+pub(crate) mod ecm;
+/// Code for the self-initializing quadratic sieve.
+pub(crate) mod siqs;
+
+// Primes below this bound are found by trial division.
+const FACTOR_TRIAL_LIMIT: Limb = 1 << 16;
+
+// The number of iterations of Pollard's rho method run on each composite cofactor. This finds most
+// prime factors below about 2 ^ 28.
+const FACTOR_RHO_ITERATIONS: u64 = 1 << 14;
+
+// The stage 1 bound of Pollard's $p - 1$ method, run on each composite cofactor.
+const FACTOR_P_MINUS_1_BOUND: u64 = 20000;
+
+// Composites with at most this many bits are split with the quadratic sieve, after a few elliptic
+// curves have been tried. This is about 100 decimal digits.
+const FACTOR_SIQS_MAX_BITS: u64 = 333;
+
+// The elliptic curve method's levels: each is the size, in decimal digits, of the prime factors it
+// is meant to find, the stage 1 bound $B_1$ suited to that size, and the number of curves to run
+// with that bound. Composites too large for the quadratic sieve step through the levels in order.
+// Composites small enough for the sieve only run the levels targeting factors of at most a quarter
+// of their size, since beyond that the sieve is faster.
+const FACTOR_ECM_LEVELS: [(u64, u64, u64); 9] = [
+    (15, 2000, 25),
+    (20, 11000, 90),
+    (25, 50000, 300),
+    (30, 250000, 700),
+    (35, 1000000, 1800),
+    (40, 3000000, 5100),
+    (45, 11000000, 10600),
+    (50, 43000000, 19300),
+    (55, 110000000, 49000),
+];
+
+/// The prime factorization of a [`Natural`], or as much of it as could be found.
+///
+/// This `struct` is created by [`Natural::factor`] and [`Natural::factor_with_options`]; see their
+/// documentation for more. It is iterable, producing pairs $(p, e)$ of type `(Natural, u64)`, where
+/// $p$ is prime and $e$ is the exponent of $p$, with the primes in ascending order. If the work was
+/// bounded and some composite factors could not be split, those are available from
+/// [`unfactored`](Self::unfactored).
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NaturalFactors {
+    factors: Vec<(Natural, u64)>,
+    unfactored: Vec<(Natural, u64)>,
+}
+
+impl NaturalFactors {
+    /// Returns the prime factors found, paired with their exponents, in ascending order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .factor()
+    ///         .prime_factors()
+    ///         .to_debug_string(),
+    ///     "[(2, 3), (3, 2), (5, 1)]"
+    /// );
+    /// ```
+    pub fn prime_factors(&self) -> &[(Natural, u64)] {
+        &self.factors
+    }
+
+    /// Returns the composite factors that could not be split within the work bounds, paired with
+    /// their exponents, in ascending order. The product of the prime factors and the unfactored
+    /// composites, raised to their exponents, is the factored number. The composites are pairwise
+    /// coprime and coprime to the prime factors.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_nz::natural::factorization::factor::FactorOptions;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // The product of two 16-digit primes
+    /// let n = Natural::from_str("1000000000000037").unwrap()
+    ///     * Natural::from_str("1000000000000091").unwrap();
+    /// assert!(n.factor().unfactored().is_empty());
+    ///
+    /// let mut options = FactorOptions::default();
+    /// options.set_max_digits(Some(20));
+    /// let factors = n.factor_with_options(&options);
+    /// assert!(factors.prime_factors().is_empty());
+    /// assert_eq!(factors.unfactored(), &[(n, 1)]);
+    /// ```
+    pub fn unfactored(&self) -> &[(Natural, u64)] {
+        &self.unfactored
+    }
+
+    /// Returns whether the factorization is complete; that is, whether every factor is prime.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::from(360u32).factor().is_complete());
+    /// ```
+    pub const fn is_complete(&self) -> bool {
+        self.unfactored.is_empty()
+    }
+
+    // Inserts a factor into a list that is kept sorted, combining equal factors.
+    fn insert_into(list: &mut Vec<(Natural, u64)>, factor: Natural, exp: u64) {
+        match list.binary_search_by(|(f, _)| f.cmp(&factor)) {
+            Ok(i) => list[i].1 += exp,
+            Err(i) => list.insert(i, (factor, exp)),
+        }
+    }
+
+    fn insert_prime(&mut self, p: Natural, exp: u64) {
+        Self::insert_into(&mut self.factors, p, exp);
+    }
+
+    fn insert_unfactored(&mut self, c: Natural, exp: u64) {
+        Self::insert_into(&mut self.unfactored, c, exp);
+    }
+}
+
+impl IntoIterator for NaturalFactors {
+    type Item = (Natural, u64);
+    type IntoIter = alloc::vec::IntoIter<(Natural, u64)>;
+
+    /// Returns an iterator over the prime factors, paired with their exponents, in ascending order.
+    /// Any unfactored composites are not included.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .factor()
+    ///         .into_iter()
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[(2, 3), (3, 2), (5, 1)]"
+    /// );
+    /// ```
+    #[inline]
+    fn into_iter(self) -> alloc::vec::IntoIter<(Natural, u64)> {
+        self.factors.into_iter()
+    }
+}
+
+/// Options bounding the work done by [`Natural::factor_with_options`].
+///
+/// The work is measured in operations rather than in elapsed time, so that the result for a given
+/// input and given options is always the same.
+///
+/// - The maximum number of digits bounds the size of the composites that the expensive methods,
+///   the elliptic curve method and the quadratic sieve, are applied to. A composite factor with more
+///   decimal digits than this is only given to trial division, Pollard's rho method, and Pollard's
+///   $p - 1$ method, which quickly find small factors and factors $p$ for which $p - 1$ is smooth;
+///   if these fail, the composite is returned unfactored. The default is no maximum.
+/// - The maximum number of elliptic curves bounds the total number of curves run, over all
+///   composite factors, by the elliptic curve method. A composite factor that is too large for the
+///   quadratic sieve, and that could not be split before this number is reached, is returned
+///   unfactored. The default is no maximum.
+///
+/// With the default options, the factorization is always complete, but it may take an impractical
+/// amount of time if the number has two or more prime factors of more than 50 or so digits.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FactorOptions {
+    max_digits: Option<u64>,
+    max_ecm_curves: Option<u64>,
+}
+
+impl FactorOptions {
+    /// Returns the maximum number of decimal digits of a composite that the elliptic curve method
+    /// and the quadratic sieve are applied to, or `None` if there is no maximum.
+    #[inline]
+    pub const fn get_max_digits(&self) -> Option<u64> {
+        self.max_digits
+    }
+
+    /// Returns the maximum total number of elliptic curves to run, or `None` if there is no
+    /// maximum.
+    #[inline]
+    pub const fn get_max_ecm_curves(&self) -> Option<u64> {
+        self.max_ecm_curves
+    }
+
+    /// Sets the maximum number of decimal digits of a composite that the elliptic curve method and
+    /// the quadratic sieve are applied to. `None` means that there is no maximum.
+    #[inline]
+    pub const fn set_max_digits(&mut self, max_digits: Option<u64>) {
+        self.max_digits = max_digits;
+    }
+
+    /// Sets the maximum total number of elliptic curves to run. `None` means that there is no
+    /// maximum.
+    #[inline]
+    pub const fn set_max_ecm_curves(&mut self, max_ecm_curves: Option<u64>) {
+        self.max_ecm_curves = max_ecm_curves;
+    }
+}
+
+// Removes every prime factor less than `FACTOR_TRIAL_LIMIT` from `n`, recording them in `factors`.
+// The odd primes are grouped into products that fit in a limb, so that each group costs a single
+// pass over `n`, and only the members of a group sharing a factor with `n` are divided out.
+fn factor_trial(factors: &mut NaturalFactors, n: &mut Natural) {
+    let twos = n.trailing_zeros().unwrap();
+    if twos != 0 {
+        *n >>= twos;
+        factors.insert_prime(Natural::TWO, twos);
+    }
+    let mut group = Vec::new();
+    let mut product: Limb = 1;
+    for p in Limb::primes_less_than(&FACTOR_TRIAL_LIMIT).skip(1) {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+            group.push(p);
+        } else {
+            factor_trial_group(factors, n, &group, product);
+            if *n == 1u32 {
+                return;
+            }
+            group.clear();
+            group.push(p);
+            product = p;
+        }
+    }
+    factor_trial_group(factors, n, &group, product);
+}
+
+fn factor_trial_group(
+    factors: &mut NaturalFactors,
+    n: &mut Natural,
+    group: &[Limb],
+    product: Limb,
+) {
+    let r = match &*n {
+        Natural(Small(x)) => x % product,
+        Natural(Large(xs)) => limbs_mod_limb::<DoubleLimb, Limb>(xs, product),
+    };
+    for &p in group {
+        if r % p == 0 {
+            let exp = n.remove_power_assign(Natural::from(p));
+            factors.insert_prime(Natural::from(p), exp);
+        }
+    }
+}
+
+// Pollard's rho method with Brent's cycle detection, iterating $x \mapsto x^2 + c$ modulo the odd
+// composite `n`. The gcds are batched: the differences are multiplied together and one gcd is taken
+// every `BATCH` iterations, backtracking through the last batch if the product is divisible by
+// every prime factor of `n` at once. Returns a nontrivial factor, or `None` if none was found
+// within `max_iterations` iterations.
+fn pollard_brent_rho(
+    n: &Natural,
+    c: u64,
+    max_iterations: u64,
+    data: &ModMulData,
+) -> Option<Natural> {
+    const BATCH: u64 = 128;
+    let c = Natural::from(c);
+    let f = |x: &Natural| x.mod_square_precomputed(n, data).mod_add(&c, n);
+    let mut y = Natural::TWO;
+    let mut x = y.clone();
+    let mut ys = y.clone();
+    let mut q = Natural::ONE;
+    let mut g;
+    let mut r = 1;
+    let mut iterations = 0;
+    'outer: loop {
+        x.clone_from(&y);
+        for _ in 0..r {
+            y = f(&y);
+        }
+        iterations += r;
+        let mut k = 0;
+        while k < r {
+            ys.clone_from(&y);
+            let m = BATCH.min(r - k);
+            for _ in 0..m {
+                y = f(&y);
+                let diff = if x > y { &x - &y } else { &y - &x };
+                q.mod_mul_precomputed_assign(diff, n, data);
+            }
+            iterations += m;
+            g = (&q).gcd(n);
+            if g != 1u32 {
+                break 'outer;
+            }
+            k += m;
+        }
+        if iterations >= max_iterations {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == *n {
+        // Backtrack one step at a time from the start of the last batch.
+        loop {
+            ys = f(&ys);
+            let diff = if x > ys { &x - &ys } else { &ys - &x };
+            g = diff.gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+    if g == *n { None } else { Some(g) }
+}
+
+// Pollard's $p - 1$ method with base 2 and stage 1 bound `bound`, applied to the odd composite `n`.
+// The gcd is checked after every `BATCH` primes; if at some check every prime factor of `n` has
+// been found at once, the last batch is redone one prime at a time. Returns a nontrivial factor, or
+// `None` if none was found.
+fn pollard_p_minus_1(n: &Natural, bound: u64) -> Option<Natural> {
+    const BATCH: usize = 64;
+    let primes: Vec<u64> = u64::primes_less_than_or_equal_to(&bound).collect();
+    let prime_power = |p: u64| {
+        let mut q = p;
+        while let Some(next) = q.checked_mul(p).filter(|&next| next <= bound) {
+            q = next;
+        }
+        Natural::from(q)
+    };
+    let mut a = Natural::TWO;
+    for chunk in primes.chunks(BATCH) {
+        let checkpoint = a.clone();
+        for &p in chunk {
+            a = a.mod_pow(prime_power(p), n);
+        }
+        let g = (&a).mod_sub(Natural::ONE, n).gcd(n);
+        if g == *n {
+            a = checkpoint;
+            for &p in chunk {
+                a = a.mod_pow(prime_power(p), n);
+                let g = (&a).mod_sub(Natural::ONE, n).gcd(n);
+                if g == *n {
+                    return None;
+                } else if g != 1u32 {
+                    return Some(g);
+                }
+            }
+            return None;
+        } else if g != 1u32 {
+            return Some(g);
+        }
+    }
+    None
+}
+
+// Finds a nontrivial factor of the odd composite `n`, which is not a perfect power and has no prime
+// factors below `FACTOR_TRIAL_LIMIT`, or returns `None` if the bounds in `options` were reached
+// first. `curves` counts the elliptic curves run so far, across all calls.
+fn find_factor(n: &Natural, options: &FactorOptions, curves: &mut u64) -> Option<Natural> {
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(n);
+    if let Some(d) = pollard_brent_rho(n, 1, FACTOR_RHO_ITERATIONS, &data) {
+        return Some(d);
+    }
+    if let Some(d) = pollard_p_minus_1(n, FACTOR_P_MINUS_1_BOUND) {
+        return Some(d);
+    }
+    if let Some(max_digits) = options.max_digits
+        && *n >= Natural::from(10u32).pow(max_digits)
+    {
+        return None;
+    }
+    let bits = n.significant_bits();
+    let sieve = bits <= FACTOR_SIQS_MAX_BITS;
+    // An approximation of the number of decimal digits of `n`
+    let digits = bits * 3 / 10;
+    let mut sigma = 6;
+    let run_curves = |b1: u64, count: u64, curves: &mut u64, sigma: &mut u64| {
+        for _ in 0..count {
+            if options.max_ecm_curves.is_some_and(|max| *curves >= max) {
+                return Err(());
+            }
+            *curves += 1;
+            *sigma += 1;
+            if let Some(d) = ecm(n, &data, *sigma, b1, 100 * b1) {
+                return Ok(Some(d));
+            }
+        }
+        Ok(None)
+    };
+    for (level_digits, b1, count) in FACTOR_ECM_LEVELS {
+        if sieve && level_digits << 2 > digits {
+            break;
+        }
+        match run_curves(b1, count, curves, &mut sigma) {
+            Ok(None) => {}
+            Ok(d) => return d,
+            // Running out of curves does not stop a composite small enough for the sieve from
+            // being sieved.
+            Err(()) if sieve => break,
+            Err(()) => return None,
+        }
+    }
+    if sieve && let Some(d) = siqs(n) {
+        return Some(d);
+    }
+    // Keep running curves at the largest level. This is also the fallback in the unlikely event
+    // that the sieve runs out of polynomials.
+    let (_, b1, _) = FACTOR_ECM_LEVELS[FACTOR_ECM_LEVELS.len() - 1];
+    loop {
+        match run_curves(b1, u64::MAX, curves, &mut sigma) {
+            Ok(None) => {}
+            Ok(d) => return d,
+            Err(()) => return None,
+        }
+    }
+}
+
+fn factor_natural(n: &Natural, options: &FactorOptions) -> NaturalFactors {
+    assert_ne!(*n, 0u32);
+    let mut factors = NaturalFactors::default();
+    let mut n = n.clone();
+    factor_trial(&mut factors, &mut n);
+    // Composite factors still to be split, each with its exponent. The entries are kept pairwise
+    // coprime, so that no prime is counted twice.
+    let mut pending = Vec::new();
+    if n != 1u32 {
+        pending.push((n, 1));
+    }
+    let mut curves = 0;
+    while let Some((c, exp)) = pending.pop() {
+        if let Ok(small_c) = u64::try_from(&c) {
+            for (p, e) in small_c.factor() {
+                factors.insert_prime(Natural::from(p), u64::from(e) * exp);
+            }
+            continue;
+        }
+        if c.is_prime() {
+            factors.insert_prime(c, exp);
+            continue;
+        }
+        if let Some((root, power)) = c.express_as_power() {
+            pending.push((root, exp * power));
+            continue;
+        }
+        let Some(d) = find_factor(&c, options, &mut curves) else {
+            factors.insert_unfactored(c, exp);
+            continue;
+        };
+        // Split c into pairwise coprime parts: divide out the powers of g = gcd(d, c / d) from both
+        // d and c / d, and handle g separately.
+        let mut e = &c / &d;
+        let mut d = d;
+        let g = (&d).gcd(&e);
+        if g != 1u32 {
+            let k_d = d.remove_power_assign(&g);
+            let k_e = e.remove_power_assign(&g);
+            pending.push((g, exp * (k_d + k_e)));
+        }
+        for part in [d, e] {
+            if part != 1u32 {
+                pending.push((part, exp));
+            }
+        }
+        coprime_refine(&mut pending);
+    }
+    factors
+}
+
+// Restores the invariant that the entries of `pending` are pairwise coprime, by replacing any two
+// entries sharing a factor with their gcd and their cofactors.
+fn coprime_refine(pending: &mut Vec<(Natural, u64)>) {
+    'outer: loop {
+        for i in 0..pending.len() {
+            for j in i + 1..pending.len() {
+                let g = (&pending[i].0).gcd(&pending[j].0);
+                if g == 1u32 {
+                    continue;
+                }
+                let (mut b, exp_b) = pending.swap_remove(j);
+                let (mut a, exp_a) = pending.swap_remove(i);
+                let k_a = a.remove_power_assign(&g);
+                let k_b = b.remove_power_assign(&g);
+                pending.push((g, exp_a * k_a + exp_b * k_b));
+                for (part, exp) in [(a, exp_a), (b, exp_b)] {
+                    if part != 1u32 {
+                        pending.push((part, exp));
+                    }
+                }
+                continue 'outer;
+            }
+        }
+        return;
+    }
+}
+
+impl Factor for Natural {
+    type FACTORS = NaturalFactors;
+
+    /// Returns the prime factorization of a [`Natural`]. The return value is iterable, and produces
+    /// pairs $(p,e)$ of type `(Natural, u64)`, where $p$ is prime and $e$ is the exponent of $p$.
+    /// The primes are in ascending order.
+    ///
+    /// Primes less than $2^{16}$ are removed by trial division, and cofactors less than $2^{64}$
+    /// are handled by the `u64` implementation. Each remaining composite factor is checked for
+    /// being a perfect power and attacked with Pollard's rho method, Pollard's $p - 1$ method,
+    /// Lenstra's elliptic curve method, and, if it has at most about 100 digits, the
+    /// self-initializing quadratic sieve. The factors are identified as prime using
+    /// [`is_prime`](IsPrime::is_prime), a Baillie–PSW test.
+    ///
+    /// To bound the work done and get a partial factorization back, use
+    /// [`factor_with_options`](Natural::factor_with_options).
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: this is
+    /// the heuristic running time of the quadratic sieve on an $n$-bit composite, whose factor base
+    /// and relations dominate the memory. For numbers too large for the sieve, the elliptic curve
+    /// method takes time subexponential in the size of the second-largest prime factor instead.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(360u32)
+    ///         .factor()
+    ///         .into_iter()
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[(2, 3), (3, 2), (5, 1)]"
+    /// );
+    /// // 2 ^ 128 + 1
+    /// assert_eq!(
+    ///     Natural::from_str("340282366920938463463374607431768211457")
+    ///         .unwrap()
+    ///         .factor()
+    ///         .into_iter()
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[(59649589127497217, 1), (5704689200685129054721, 1)]"
+    /// );
+    /// ```
+    #[inline]
+    fn factor(&self) -> NaturalFactors {
+        factor_natural(self, &FactorOptions::default())
+    }
+}
+
+impl Natural {
+    /// Returns the prime factorization of a [`Natural`], or as much of it as can be found within
+    /// the bounds given by `options`. See [`FactorOptions`] for the meaning of the bounds.
+    ///
+    /// The prime factors found are available by iterating over the result, or from
+    /// [`prime_factors`](NaturalFactors::prime_factors), and the composite factors that could not
+    /// be split from [`unfactored`](NaturalFactors::unfactored).
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`. Bounding
+    /// the number of digits to $d$ replaces $n$ by $\min(n, d \log_2 10)$ in these bounds, apart
+    /// from the polynomial cost of the cheap methods.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::factorization::factor::FactorOptions;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // 10 ^ 30 + 1 = 61 * 101 * 3541 * 9901 * 27961 * 4188901 * 39526741
+    /// let mut options = FactorOptions::default();
+    /// options.set_max_digits(Some(0));
+    /// let factors = (Natural::from(10u32).pow(30) + Natural::from(1u32))
+    ///     .factor_with_options(&options);
+    /// assert!(factors.is_complete());
+    /// assert_eq!(
+    ///     factors.prime_factors().to_debug_string(),
+    ///     "[(61, 1), (101, 1), (3541, 1), (9901, 1), (27961, 1), (4188901, 1), (39526741, 1)]"
+    /// );
+    ///
+    /// // A 7-digit prime times the product of two 16-digit primes, which is too large to split
+    /// let n = Natural::from(1000003u32)
+    ///     * Natural::from_str("1000000000000037").unwrap()
+    ///     * Natural::from_str("1000000000000091").unwrap();
+    /// options.set_max_digits(Some(20));
+    /// let factors = n.factor_with_options(&options);
+    /// assert_eq!(factors.prime_factors().to_debug_string(), "[(1000003, 1)]");
+    /// assert_eq!(
+    ///     factors.unfactored().to_debug_string(),
+    ///     "[(1000000000000128000000000003367, 1)]"
+    /// );
+    /// ```
+    #[inline]
+    pub fn factor_with_options(&self, options: &FactorOptions) -> NaturalFactors {
+        factor_natural(self, options)
+    }
+}
@@ -0,0 +1,646 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivExactAssign, DivisibleBy, Gcd, JacobiSymbol, ModInverse, ModMul, ModPow, ModSqrt,
+    ModSub, Parity, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The sieve parameters, by the number of bits of kn: the size of the factor base, and the half-width
+// M of the sieve interval [-M, M).
+const SIQS_PARAMETERS: [(u64, usize, u64); 14] = [
+    (100, 100, 1 << 14),
+    (120, 200, 1 << 15),
+    (140, 400, 1 << 15),
+    (160, 900, 1 << 15),
+    (180, 2000, 1 << 15),
+    (200, 3000, 1 << 15),
+    (210, 4000, 1 << 16),
+    (220, 5000, 3 << 15),
+    (240, 8000, 3 << 15),
+    (260, 12000, 1 << 17),
+    (280, 15000, 1 << 17),
+    (300, 18000, 3 << 16),
+    (320, 20000, 3 << 16),
+    (u64::MAX, 20000, 1 << 18),
+];
+
+// The multipliers k considered. Each is squarefree, so that kn is not a square.
+const SIQS_MULTIPLIERS: [u64; 20] =
+    [1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47];
+
+// The number of odd primes used to score the multipliers.
+const SIQS_MULTIPLIER_PRIMES: usize = 100;
+
+// Factor base primes below this bound are not sieved with; their contribution is made up for by
+// lowering the sieve threshold.
+const SIQS_SMALL_PRIME_BOUND: u64 = 30;
+
+// The sieve threshold is lowered by this many bits below the size of a value that is smooth apart
+// from a large prime, to account for the unsieved small primes and prime powers and for rounding.
+const SIQS_THRESHOLD_SLACK: u64 = 14;
+
+// A value left with a cofactor below this multiple of the largest factor base prime after trial
+// division is kept as a partial relation.
+const SIQS_LARGE_PRIME_MULTIPLIER: u64 = 64;
+
+// The number of relations collected beyond the number of factor base primes, so that the matrix is
+// guaranteed to have that many dependencies.
+const SIQS_EXTRA_RELATIONS: usize = 64;
+
+// The largest difference allowed between the natural logarithms of A and its target, until the
+// selection is widened to the whole factor base.
+const SIQS_A_TOLERANCE: f64 = 0.5;
+
+// How many randomly chosen values of A may be rejected, for duplicating an earlier one or for
+// missing the target, before the selection is widened to the whole factor base.
+const SIQS_MAX_A_RETRIES: usize = 1000;
+
+struct FactorBasePrime {
+    p: u64,
+    // A square root of kn modulo p; 0 if p divides k.
+    sqrt: u64,
+    // The base-2 logarithm of p, rounded.
+    log: u8,
+}
+
+// A relation $Y^2 \equiv (-1)^{e_0} \prod_i p_i^{e_i} L^2 \pmod n$, where the $p_i$ are the factor
+// base primes and $L$ is a large prime, or 1.
+#[derive(Clone)]
+struct Relation {
+    y: Natural,
+    // Pairs of a column and an exponent, sorted by column. Column 0 is the sign, and column i + 1
+    // is the ith factor base prime.
+    exponents: Vec<(usize, u64)>,
+    large_prime: u64,
+}
+
+impl Relation {
+    // Combines two partial relations with the same large prime into a full relation.
+    fn combine(&self, other: &Self, n: &Natural) -> Self {
+        let mut exponents = self.exponents.clone();
+        for &(column, e) in &other.exponents {
+            match exponents.binary_search_by_key(&column, |&(c, _)| c) {
+                Ok(i) => exponents[i].1 += e,
+                Err(i) => exponents.insert(i, (column, e)),
+            }
+        }
+        Self {
+            y: (&self.y).mod_mul(&other.y, n),
+            exponents,
+            large_prime: self.large_prime,
+        }
+    }
+}
+
+fn natural_mod_u64(x: &Natural, p: u64) -> u64 {
+    u64::exact_from(&(x % Natural::from(p)))
+}
+
+fn integer_mod_u64(x: &Integer, p: u64) -> u64 {
+    let r = natural_mod_u64(x.unsigned_abs_ref(), p);
+    if *x < 0u32 && r != 0 { p - r } else { r }
+}
+
+// A small deterministic generator of pseudorandom numbers (xorshift64*).
+struct SiqsRandom(u64);
+
+impl SiqsRandom {
+    const fn next(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        (self.0.wrapping_mul(0x2545f4914f6cdd1d) >> 32) as usize % bound
+    }
+}
+
+// Chooses the multiplier k that maximizes the Knuth–Schroeppel function, which estimates how much
+// the small primes contribute to the smoothness of values of $Y^2 - kn$.
+fn choose_multiplier(n: &Natural) -> u64 {
+    let primes: Vec<u64> = u64::primes().skip(1).take(SIQS_MULTIPLIER_PRIMES).collect();
+    let n_mods: Vec<u64> = primes.iter().map(|&p| natural_mod_u64(n, p)).collect();
+    let n_mod_8 = natural_mod_u64(n, 8);
+    let mut best_k = 1;
+    let mut best_score = f64::NEG_INFINITY;
+    for k in SIQS_MULTIPLIERS {
+        let ln_2 = core::f64::consts::LN_2;
+        let mut score = -0.5 * libm::log(k as f64)
+            + match (k * n_mod_8) & 7 {
+                1 => 2.0 * ln_2,
+                5 => ln_2,
+                _ => 0.5 * ln_2,
+            };
+        for (&p, &n_mod_p) in primes.iter().zip(n_mods.iter()) {
+            let ln_p = libm::log(p as f64);
+            if k.divisible_by(p) {
+                score += ln_p / p as f64;
+            } else if (k % p * n_mod_p % p).jacobi_symbol(p) == 1 {
+                score += 2.0 * ln_p / (p - 1) as f64;
+            }
+        }
+        if score > best_score {
+            best_score = score;
+            best_k = k;
+        }
+    }
+    best_k
+}
+
+// Builds the factor base for kn: 2, followed by the odd primes p for which kn is a square modulo p.
+// Returns `Err` with a factor of n if one of the primes divides n.
+fn factor_base(
+    n: &Natural,
+    kn: &Natural,
+    k: u64,
+    size: usize,
+) -> Result<Vec<FactorBasePrime>, u64> {
+    let mut base = vec![FactorBasePrime {
+        p: 2,
+        sqrt: 1,
+        log: 1,
+    }];
+    for p in u64::primes().skip(1) {
+        if base.len() == size {
+            break;
+        }
+        let r = natural_mod_u64(kn, p);
+        let sqrt = if r == 0 {
+            if !k.divisible_by(p) {
+                return Err(p);
+            }
+            0
+        } else if let Some(sqrt) = r.mod_sqrt(p) {
+            sqrt
+        } else {
+            continue;
+        };
+        if natural_mod_u64(n, p) == 0 {
+            return Err(p);
+        }
+        base.push(FactorBasePrime {
+            p,
+            sqrt,
+            log: libm::round(libm::log2(p as f64)) as u8,
+        });
+    }
+    Ok(base)
+}
+
+// The state of the sieve for one value of A: A's prime factors, the $B_l$, and, for each factor
+// base prime, A's inverse and the adjustments to the roots made when switching polynomials.
+struct SiqsPolynomialFamily {
+    a: Natural,
+    a_indices: Vec<usize>,
+    bs: Vec<Natural>,
+    // For each l and each factor base prime p, $2B_lA^{-1} \bmod p$.
+    b_a_inverses: Vec<Vec<u64>>,
+}
+
+// Returns the sorted factor base indices of a fresh value of A whose logarithm is close to
+// `target_ln`, or `None` if no fresh value could be found.
+//
+// A is a product of s primes, with s chosen so that the sth root of the target lies in the upper
+// part of the factor base. All but the last prime are drawn at random from the primes near that
+// root, and the last is chosen to bring the product as close to the target as possible.
+fn choose_a(
+    base: &[FactorBasePrime],
+    k: u64,
+    target_ln: f64,
+    used: &mut BTreeSet<Vec<usize>>,
+    random: &mut SiqsRandom,
+) -> Option<Vec<usize>> {
+    // Primes dividing k, and the primes that are not sieved with, are never used.
+    let usable: Vec<(usize, f64)> = base
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, fp)| fp.p >= SIQS_SMALL_PRIME_BOUND && !k.divisible_by(fp.p))
+        .map(|(i, fp)| (i, libm::log(fp.p as f64)))
+        .collect();
+    let &(_, largest_ln) = usable.last()?;
+    let s = (libm::ceil(target_ln / (largest_ln - 1.0)) as usize).clamp(1, usable.len());
+    let ideal_ln = target_ln / s as f64;
+    let near: Vec<(usize, f64)> = usable
+        .iter()
+        .copied()
+        .filter(|&(_, ln)| libm::fabs(ln - ideal_ln) <= 1.0)
+        .collect();
+    for attempt in 0..SIQS_MAX_A_RETRIES << 1 {
+        let widened = attempt >= SIQS_MAX_A_RETRIES;
+        let pool = if !widened && near.len() >= s << 1 {
+            &near
+        } else {
+            &usable
+        };
+        let mut indices = Vec::with_capacity(s);
+        let mut ln_product = 0.0;
+        while indices.len() < s - 1 {
+            let (i, ln) = pool[random.next(pool.len())];
+            if !indices.contains(&i) {
+                indices.push(i);
+                ln_product += ln;
+            }
+        }
+        let wanted = target_ln - ln_product;
+        let Some(&(last, last_ln)) = usable
+            .iter()
+            .filter(|(i, _)| !indices.contains(i))
+            .min_by(|(_, x), (_, y)| libm::fabs(x - wanted).total_cmp(&libm::fabs(y - wanted)))
+        else {
+            continue;
+        };
+        if !widened && libm::fabs(last_ln - wanted) > SIQS_A_TOLERANCE {
+            continue;
+        }
+        indices.push(last);
+        indices.sort_unstable();
+        if used.insert(indices.clone()) {
+            return Some(indices);
+        }
+    }
+    None
+}
+
+fn polynomial_family(
+    kn: &Natural,
+    base: &[FactorBasePrime],
+    a_indices: Vec<usize>,
+) -> SiqsPolynomialFamily {
+    let mut a = Natural::ONE;
+    for &i in &a_indices {
+        a *= Natural::from(base[i].p);
+    }
+    // B_l = (A / q_l) * gamma_l, where gamma_l = sqrt(kn) * (A / q_l) ^ -1 mod q_l, chosen at most
+    // q_l / 2, so that B = sum_l B_l satisfies B ^ 2 = kn mod A.
+    let mut bs = Vec::with_capacity(a_indices.len());
+    for &i in &a_indices {
+        let q = base[i].p;
+        let a_over_q = (&a).div_exact(Natural::from(q));
+        let inverse = natural_mod_u64(&a_over_q, q).mod_inverse(q).unwrap();
+        let mut gamma = base[i].sqrt.mod_mul(inverse, q);
+        if gamma > q >> 1 {
+            gamma = q - gamma;
+        }
+        bs.push(a_over_q * Natural::from(gamma));
+    }
+    let mut b_a_inverses = vec![vec![0; base.len()]; bs.len()];
+    for (j, fp) in base.iter().enumerate().skip(1) {
+        let p = fp.p;
+        let a_mod_p = natural_mod_u64(&a, p);
+        // The primes dividing A are not sieved with, so they need no adjustments.
+        if a_mod_p == 0 {
+            continue;
+        }
+        let a_inverse = a_mod_p.mod_inverse(p).unwrap();
+        for (l, b) in bs.iter().enumerate() {
+            b_a_inverses[l][j] = ((natural_mod_u64(b, p) << 1) % p).mod_mul(a_inverse, p);
+        }
+    }
+    debug_assert!({
+        let b: Natural = bs.iter().sum();
+        (b.square() % &a) == (kn % &a)
+    });
+    SiqsPolynomialFamily {
+        a,
+        a_indices,
+        bs,
+        b_a_inverses,
+    }
+}
+
+// Finds the dependencies among the relations' exponent vectors modulo 2, returning each as a list
+// of relation indices. Relations containing a column that appears in no other relation are removed
+// first, since they can take part in no dependency; the rest are reduced by dense Gaussian
+// elimination over GF(2), tracking the combinations in a history matrix.
+fn find_dependencies(relations: &[Relation], columns: usize) -> Vec<Vec<usize>> {
+    let odd_columns: Vec<Vec<usize>> = relations
+        .iter()
+        .map(|r| {
+            r.exponents
+                .iter()
+                .filter(|&&(_, e)| e.odd())
+                .map(|&(c, _)| c)
+                .collect()
+        })
+        .collect();
+    let mut alive = vec![true; relations.len()];
+    loop {
+        let mut counts = vec![0usize; columns];
+        for (row, &a) in odd_columns.iter().zip(alive.iter()) {
+            if a {
+                for &c in row {
+                    counts[c] += 1;
+                }
+            }
+        }
+        let mut changed = false;
+        for (row, a) in odd_columns.iter().zip(alive.iter_mut()) {
+            if *a && row.iter().any(|&c| counts[c] == 1) {
+                *a = false;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let rows: Vec<usize> = (0..relations.len()).filter(|&i| alive[i]).collect();
+    let mut column_map = vec![usize::MAX; columns];
+    let mut active_columns = 0;
+    for &i in &rows {
+        for &c in &odd_columns[i] {
+            if column_map[c] == usize::MAX {
+                column_map[c] = active_columns;
+                active_columns += 1;
+            }
+        }
+    }
+    let row_words = active_columns.div_ceil(64);
+    let history_words = rows.len().div_ceil(64);
+    let mut matrix: Vec<Vec<u64>> = Vec::with_capacity(rows.len());
+    let mut history: Vec<Vec<u64>> = Vec::with_capacity(rows.len());
+    for (j, &i) in rows.iter().enumerate() {
+        let mut row = vec![0u64; row_words];
+        for &c in &odd_columns[i] {
+            let c = column_map[c];
+            row[c >> 6] |= 1 << (c & 63);
+        }
+        matrix.push(row);
+        let mut h = vec![0u64; history_words];
+        h[j >> 6] |= 1 << (j & 63);
+        history.push(h);
+    }
+    let mut is_pivot = vec![false; rows.len()];
+    for c in 0..active_columns {
+        let (word, bit) = (c >> 6, 1u64 << (c & 63));
+        let Some(pivot) = (0..rows.len()).find(|&r| !is_pivot[r] && matrix[r][word] & bit != 0)
+        else {
+            continue;
+        };
+        is_pivot[pivot] = true;
+        let pivot_row = matrix[pivot].clone();
+        let pivot_history = history[pivot].clone();
+        for r in 0..rows.len() {
+            if r != pivot && matrix[r][word] & bit != 0 {
+                for (x, y) in matrix[r].iter_mut().zip(pivot_row.iter()) {
+                    *x ^= y;
+                }
+                for (x, y) in history[r].iter_mut().zip(pivot_history.iter()) {
+                    *x ^= y;
+                }
+            }
+        }
+    }
+    let mut dependencies = Vec::new();
+    for r in 0..rows.len() {
+        if !is_pivot[r] {
+            let mut dependency = Vec::new();
+            for (j, &i) in rows.iter().enumerate() {
+                if history[r][j >> 6] >> (j & 63) & 1 != 0 {
+                    dependency.push(i);
+                }
+            }
+            dependencies.push(dependency);
+        }
+    }
+    dependencies
+}
+
+// Given a dependency, computes X = prod Y and Z = sqrt(prod Y ^ 2) modulo n and returns gcd(X - Z,
+// n) if it is a nontrivial factor of n.
+fn square_root_step(
+    n: &Natural,
+    base: &[FactorBasePrime],
+    relations: &[Relation],
+    dependency: &[usize],
+) -> Option<Natural> {
+    let mut x = Natural::ONE;
+    let mut z = Natural::ONE;
+    let mut exponents = vec![0u64; base.len() + 1];
+    for &i in dependency {
+        let relation = &relations[i];
+        x = x.mod_mul(&relation.y, n);
+        if relation.large_prime != 1 {
+            z = z.mod_mul(Natural::from(relation.large_prime) % n, n);
+        }
+        for &(c, e) in &relation.exponents {
+            exponents[c] += e;
+        }
+    }
+    for (c, &e) in exponents.iter().enumerate().skip(1) {
+        debug_assert!(e.even());
+        if e != 0 {
+            let p = Natural::from(base[c - 1].p);
+            z = z.mod_mul(p.mod_pow(Natural::from(e >> 1), n), n);
+        }
+    }
+    let g = x.mod_sub(z, n).gcd(n);
+    if g == 1u32 || g == *n { None } else { Some(g) }
+}
+
+// Finds a nontrivial factor of the odd composite `n` with the self-initializing quadratic sieve.
+// `n` must not be a perfect power and must have no prime factors below 2 ^ 16. Returns `None` in
+// the unlikely event that every usable polynomial has been exhausted.
+//
+// A polynomial is $g(x) = Ax^2 + 2Bx + C$, where $A = q_1 \cdots q_s$ is a product of factor base
+// primes close to $\sqrt{2kn}/M$, $B^2 \equiv kn \pmod A$, and $C = (B^2 - kn)/A$; then $(Ax + B)^2 -
+// kn = Ag(x)$, and $|g(x)|$ is about $M\sqrt{kn/2}$ on $[-M, M)$. Each A gives $2^{s-1}$ values of
+// B, the sums $\pm B_1 \pm \cdots \pm B_s$ with the sign of $B_1$ fixed, which are stepped through
+// in Gray-code order, so that the sieve roots are updated with one addition per prime. Values of
+// $g(x)$ that are smooth over the factor base, or smooth apart from one large prime, are found by
+// sieving with logarithms and confirmed by trial division; partial relations sharing a large prime
+// are combined. Once there are more relations than factor base primes, dependencies modulo 2 give
+// congruences of squares $X^2 \equiv Z^2 \pmod n$, each of which splits n with probability at
+// least 1/2.
+pub(crate) fn siqs(n: &Natural) -> Option<Natural> {
+    let k = choose_multiplier(n);
+    let kn = n * Natural::from(k);
+    let bits = kn.significant_bits();
+    let &(_, base_size, m) = SIQS_PARAMETERS
+        .iter()
+        .find(|&&(b, _, _)| bits <= b)
+        .unwrap();
+    let base = match factor_base(n, &kn, k, base_size) {
+        Ok(base) => base,
+        Err(p) => return Some(Natural::from(p)),
+    };
+    let p_max = base[base.len() - 1].p;
+    let large_prime_bound = p_max * SIQS_LARGE_PRIME_MULTIPLIER;
+    let sieve_len = usize::exact_from(m << 1);
+    let threshold = (m.significant_bits() + (bits >> 1))
+        .saturating_sub(large_prime_bound.significant_bits() + SIQS_THRESHOLD_SLACK);
+    let threshold = u8::try_from(threshold).unwrap_or(u8::MAX);
+    let target_ln = 0.5 * (libm::log(2.0) + kn.approx_ln()) - libm::log(m as f64);
+    let mut random = SiqsRandom(0x9e3779b97f4a7c15);
+    let mut used_a = BTreeSet::new();
+    let mut relations: Vec<Relation> = Vec::new();
+    let mut partials: BTreeMap<u64, Relation> = BTreeMap::new();
+    let mut wanted = base.len() + 1 + SIQS_EXTRA_RELATIONS;
+    let mut sieve = vec![0u8; sieve_len];
+    let mut roots_1 = vec![0u64; base.len()];
+    let mut roots_2 = vec![0u64; base.len()];
+    let kn_integer = Integer::from(&kn);
+    loop {
+        while relations.len() < wanted {
+            let a_indices = choose_a(&base, k, target_ln, &mut used_a, &mut random)?;
+            let family = polynomial_family(&kn, &base, a_indices);
+            let s = family.bs.len();
+            let mut in_a = vec![false; base.len()];
+            for &i in &family.a_indices {
+                in_a[i] = true;
+            }
+            let mut b = Integer::from(family.bs.iter().sum::<Natural>());
+            // Initial roots, shifted by M so that sieve position i corresponds to x = i - M
+            for (j, fp) in base.iter().enumerate().skip(1) {
+                let p = fp.p;
+                if in_a[j] || p < SIQS_SMALL_PRIME_BOUND {
+                    continue;
+                }
+                let a_inverse = natural_mod_u64(&family.a, p).mod_inverse(p).unwrap();
+                let b_mod_p = integer_mod_u64(&b, p);
+                let shift = m % p;
+                let root_1 = ((fp.sqrt + p - b_mod_p) % p).mod_mul(a_inverse, p);
+                let root_2 = ((p - fp.sqrt + p - b_mod_p) % p).mod_mul(a_inverse, p);
+                roots_1[j] = (root_1 + shift) % p;
+                roots_2[j] = (root_2 + shift) % p;
+            }
+            let polynomial_count = 1u64 << (s - 1);
+            for i in 0..polynomial_count {
+                if i != 0 {
+                    // Switch from B to B -/+ 2B_l, where l is determined by the Gray code.
+                    let v = usize::exact_from(i.trailing_zeros());
+                    let l = v + 1;
+                    let subtract = ((i ^ (i >> 1)) >> v) & 1 == 1;
+                    let two_b_l = Integer::from(&family.bs[l]) << 1;
+                    if subtract {
+                        b -= two_b_l;
+                    } else {
+                        b += two_b_l;
+                    }
+                    for (j, fp) in base.iter().enumerate().skip(1) {
+                        let p = fp.p;
+                        if in_a[j] || p < SIQS_SMALL_PRIME_BOUND {
+                            continue;
+                        }
+                        let delta = family.b_a_inverses[l][j];
+                        // Subtracting 2B_l from B adds 2B_l / A to the roots.
+                        let delta = if subtract { delta } else { (p - delta) % p };
+                        roots_1[j] = (roots_1[j] + delta) % p;
+                        roots_2[j] = (roots_2[j] + delta) % p;
+                    }
+                }
+                // C = (B ^ 2 - kn) / A
+                let c = ((&b).square() - &kn_integer).div_exact(Integer::from(&family.a));
+                sieve.fill(0);
+                for (j, fp) in base.iter().enumerate().skip(1) {
+                    let p = fp.p;
+                    if in_a[j] || p < SIQS_SMALL_PRIME_BOUND {
+                        continue;
+                    }
+                    let p = usize::exact_from(p);
+                    let mut position = usize::exact_from(roots_1[j]);
+                    while position < sieve_len {
+                        sieve[position] = sieve[position].saturating_add(fp.log);
+                        position += p;
+                    }
+                    if roots_2[j] != roots_1[j] {
+                        let mut position = usize::exact_from(roots_2[j]);
+                        while position < sieve_len {
+                            sieve[position] = sieve[position].saturating_add(fp.log);
+                            position += p;
+                        }
+                    }
+                }
+                for (position, &value) in sieve.iter().enumerate() {
+                    if value < threshold {
+                        continue;
+                    }
+                    let x = Integer::from(position) - Integer::from(m);
+                    // g(x) = Ax ^ 2 + 2Bx + C
+                    let g = (Integer::from(&family.a) * &x + (&b << 1u32)) * &x + &c;
+                    if g == 0u32 {
+                        continue;
+                    }
+                    let mut exponents = Vec::new();
+                    if g < 0u32 {
+                        exponents.push((0, 1));
+                    }
+                    let mut v = g.unsigned_abs();
+                    let twos = v.trailing_zeros().unwrap();
+                    if twos != 0 {
+                        v >>= twos;
+                        exponents.push((1, twos));
+                    }
+                    let position = u64::exact_from(position);
+                    for (j, fp) in base.iter().enumerate().skip(1) {
+                        let p = fp.p;
+                        let a_exponent = u64::from(in_a[j]);
+                        let divides = if in_a[j] || fp.sqrt == 0 || p < SIQS_SMALL_PRIME_BOUND {
+                            v != 1u32 && natural_mod_u64(&v, p) == 0
+                        } else {
+                            let r = position % p;
+                            r == roots_1[j] || r == roots_2[j]
+                        };
+                        let mut e = a_exponent;
+                        if divides {
+                            let p = Natural::from(p);
+                            while natural_mod_u64(&v, fp.p) == 0 {
+                                v.div_exact_assign(&p);
+                                e += 1;
+                            }
+                        }
+                        if e != 0 {
+                            exponents.push((j + 1, e));
+                        }
+                    }
+                    // Y = Ax + B
+                    let y = (Integer::from(&family.a) * &x + &b).unsigned_abs() % n;
+                    if v == 1u32 {
+                        relations.push(Relation {
+                            y,
+                            exponents,
+                            large_prime: 1,
+                        });
+                    } else if let Ok(large_prime) = u64::try_from(&v)
+                        && large_prime < large_prime_bound
+                    {
+                        if natural_mod_u64(n, large_prime) == 0 {
+                            return Some(v);
+                        }
+                        let relation = Relation {
+                            y,
+                            exponents,
+                            large_prime,
+                        };
+                        if let Some(other) = partials.get(&large_prime) {
+                            relations.push(relation.combine(other, n));
+                        } else {
+                            partials.insert(large_prime, relation);
+                        }
+                    }
+                }
+            }
+        }
+        for dependency in find_dependencies(&relations, base.len() + 1) {
+            if let Some(d) = square_root_step(n, &base, &relations, &dependency) {
+                return Some(d);
+            }
+        }
+        // Every dependency gave a trivial congruence; collect more relations and try again.
+        wanted += SIQS_EXTRA_RELATIONS;
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::platform::{DoubleLimb, Limb};
+use malachite_base::num::arithmetic::traits::{
+    CoprimeWith, DivExactAssign, DivMod, DivisibleBy, Gcd, JacobiSymbol, ModAdd, ModMulPrecomputed,
+    ModMulPrecomputedAssign, ModPow, ModSquarePrecomputed, ModSquarePrecomputedAssign, ModSub,
+    Parity, Square,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{Factor, IsPrime, IsSquare, Primes, ProvePrime};
+use malachite_base::num::logic::traits::BitIterable;
+
+// Odd primes below this bound are trial-divided out before any probable-prime test is run.
+const IS_PRIME_TRIAL_LIMIT: Limb = 1000;
+
+// Primes below this bound are trial-divided out of $n - 1$ when looking for a primality proof.
+const PROVE_PRIME_TRIAL_LIMIT: Limb = 1 << 16;
+
+// The number of bases tried when searching for a Pocklington witness for a prime factor of $n - 1$.
+// If $n$ is prime, a base fails for the factor $q$ with probability about $1/q$, so for a prime $n$
+// running out of bases is astronomically unlikely.
+const PROVE_PRIME_MAX_WITNESSES: u32 = 100;
+
+// How many levels deep the cofactor of $n - 1$ that is left after trial division may itself be
+// proven prime recursively.
+const PROVE_PRIME_MAX_DEPTH: u64 = 8;
+
+// Returns whether the `Natural` whose limbs are `xs` (at least two of them) is divisible by an odd
+// prime less than `IS_PRIME_TRIAL_LIMIT`. The primes are grouped into products that fit in a limb,
+// so that each group costs a single pass over `xs`.
+fn limbs_has_small_odd_prime_factor(xs: &[Limb]) -> bool {
+    let mut product: Limb = 1;
+    for p in Limb::primes_less_than(&IS_PRIME_TRIAL_LIMIT).skip(1) {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+        } else {
+            if !limbs_mod_limb::<DoubleLimb, Limb>(xs, product).coprime_with(product) {
+                return true;
+            }
+            product = p;
+        }
+    }
+    !limbs_mod_limb::<DoubleLimb, Limb>(xs, product).coprime_with(product)
+}
+
+// Returns whether the odd `Natural` n = d * 2 ^ s + 1, with d odd, is a strong probable prime to
+// base `a`, where 1 < a < n - 1.
+fn is_strong_probable_prime(
+    n: &Natural,
+    n_minus_1: &Natural,
+    d: &Natural,
+    s: u64,
+    a: &Natural,
+    data: &ModMulData,
+) -> bool {
+    let mut y = a.mod_pow(d, n);
+    if y == 1u32 || y == *n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        y.mod_square_precomputed_assign(n, data);
+        if y == *n_minus_1 {
+            return true;
+        } else if y == 1u32 {
+            return false;
+        }
+    }
+    false
+}
+
+// Returns whether the odd `Natural` n, which is greater than `IS_PRIME_TRIAL_LIMIT` and not a
+// perfect square, is a strong Lucas probable prime with the parameters chosen by Selfridge's method
+// A: $D$ is the first of $5, -7, 9, -11, 13, \ldots$ with Jacobi symbol $(D/n) = -1$, $P = 1$, and
+// $Q = (1 - D)/4$.
+//
+// Only the $V$ sequence is computed. Writing $n + 1 = d 2^s$ with $d$ odd, $U_d$ is recovered from
+// the identity $D U_d = 2 V_{d+1} - P V_d$, and $D$ is invertible modulo $n$, so $U_d \equiv 0$ if
+// and only if $2 V_{d+1} \equiv V_d$.
+fn is_strong_lucas_probable_prime(n: &Natural, data: &ModMulData) -> bool {
+    let mut d_abs: Limb = 5;
+    let mut d_negative = false;
+    loop {
+        let d = Natural::from(d_abs);
+        let jacobi = if d_negative {
+            (n - d).jacobi_symbol(n)
+        } else {
+            d.jacobi_symbol(n)
+        };
+        match jacobi {
+            -1 => break,
+            // n > |D|, so n and D have a nontrivial common factor
+            0 => return false,
+            _ => {}
+        }
+        // Since n is not a perfect square, a suitable D exists, so the search terminates.
+        d_abs += 2;
+        d_negative = !d_negative;
+    }
+    // Q = (1 - D) / 4, reduced modulo n
+    let q = if d_negative {
+        Natural::from((d_abs + 1) >> 2)
+    } else {
+        n - Natural::from((d_abs - 1) >> 2)
+    };
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let d = n_plus_1 >> s;
+    // Invariant: v = V_k, v_next = V_{k + 1}, and q_k = Q ^ k, where k is the prefix of d that has
+    // been processed.
+    let mut v = Natural::TWO;
+    let mut v_next = Natural::ONE;
+    let mut q_k = Natural::ONE;
+    for bit in d.bits().rev() {
+        // V_{2k + 1} = V_k V_{k + 1} - P Q ^ k
+        let v_odd = (&v).mod_mul_precomputed(&v_next, n, data).mod_sub(&q_k, n);
+        if bit {
+            // V_{2k + 2} = V_{k + 1} ^ 2 - 2 Q ^ {k + 1}
+            let q_k_plus_1 = (&q_k).mod_mul_precomputed(&q, n, data);
+            v_next = (&v_next)
+                .mod_square_precomputed(n, data)
+                .mod_sub((&q_k_plus_1).mod_add(&q_k_plus_1, n), n);
+            v = v_odd;
+            q_k.mod_square_precomputed_assign(n, data);
+            q_k.mod_mul_precomputed_assign(&q, n, data);
+        } else {
+            // V_{2k} = V_k ^ 2 - 2 Q ^ k
+            v = (&v)
+                .mod_square_precomputed(n, data)
+                .mod_sub((&q_k).mod_add(&q_k, n), n);
+            v_next = v_odd;
+            q_k.mod_square_precomputed_assign(n, data);
+        }
+    }
+    if (&v_next).mod_add(&v_next, n) == v || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        // V_{2k} = V_k ^ 2 - 2 Q ^ k
+        v = (&v)
+            .mod_square_precomputed(n, data)
+            .mod_sub((&q_k).mod_add(&q_k, n), n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k.mod_square_precomputed_assign(n, data);
+    }
+    false
+}
+
+// The Baillie-PSW test, for an odd `Natural` with at least two limbs: trial division by small
+// primes, a strong probable-prime test to base 2, and a strong Lucas probable-prime test. A `false`
+// result is always correct; no composite passing the test is known.
+fn limbs_is_probable_prime_bpsw(n: &Natural, xs: &[Limb]) -> bool {
+    if limbs_has_small_odd_prime_factor(xs) {
+        return false;
+    }
+    let n_minus_1 = n - Natural::ONE;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> s;
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(n);
+    is_strong_probable_prime(n, &n_minus_1, &d, s, &Natural::TWO, &data)
+        && !n.is_square()
+        && is_strong_lucas_probable_prime(n, &data)
+}
+
+// Searches for a base that proves, in the sense of Pocklington's theorem, that every prime factor
+// of n is congruent to 1 modulo the largest power of q dividing n - 1. Returns `Some(true)` if one
+// is found, `Some(false)` if n was shown to be composite along the way, and `None` if the search
+// gives up.
+fn pocklington_witness(n: &Natural, n_minus_1: &Natural, q: &Natural) -> Option<bool> {
+    let e = n_minus_1 / q;
+    let mut a = Natural::TWO;
+    for _ in 0..PROVE_PRIME_MAX_WITNESSES {
+        let b = (&a).mod_pow(&e, n);
+        if (&b).mod_pow(q, n) != 1u32 {
+            // a is a Fermat witness
+            return Some(false);
+        }
+        if b != 1u32 {
+            return Some((b - Natural::ONE).gcd(n) == 1u32);
+        }
+        a += Natural::ONE;
+    }
+    None
+}
+
+// Attempts to prove that a `Natural` n, which is greater than 2 ^ 64 and a Baillie-PSW probable
+// prime, is prime, using a partial factorization $n - 1 = FR$ in which $F$ is completely factored.
+//
+// $F$ consists of the prime factors of $n - 1$ below `PROVE_PRIME_TRIAL_LIMIT`, together with the
+// remaining cofactor if that cofactor fits in a `u64` (in which case it is factored completely) or
+// can itself be proven prime recursively. If each prime factor of $F$ has a Pocklington witness,
+// then every prime factor of $n$ is congruent to 1 modulo $F$, and:
+// - if $F^2 > n$, $n$ is prime (Pocklington's theorem);
+// - if $F^3 > n$, writing $n = c_2 F^2 + c_1 F + 1$ with $0 \leq c_1, c_2 < F$, $n$ is prime if
+//   and only if $c_1^2 - 4c_2$ is not a perfect square (Brillhart, Lehmer, and Selfridge, 1975).
+//
+// Otherwise too little of $n - 1$ is known, and `None` is returned.
+fn prove_prime_n_minus_1(n: &Natural, depth: u64) -> Option<bool> {
+    let n_minus_1 = n - Natural::ONE;
+    let mut r = n_minus_1.clone();
+    let twos = r.trailing_zeros().unwrap();
+    r >>= twos;
+    let mut f = Natural::ONE << twos;
+    let mut factors = vec![Natural::TWO];
+    for p in Limb::primes_less_than(&PROVE_PRIME_TRIAL_LIMIT).skip(1) {
+        if r == 1u32 {
+            break;
+        }
+        let p = Natural::from(p);
+        if (&r).divisible_by(&p) {
+            while (&r).divisible_by(&p) {
+                r.div_exact_assign(&p);
+                f *= &p;
+            }
+            factors.push(p);
+        }
+    }
+    if r != 1u32 {
+        if let Ok(small_r) = u64::try_from(&r) {
+            for (p, _) in small_r.factor() {
+                factors.push(Natural::from(p));
+            }
+            f *= &r;
+        } else if depth < PROVE_PRIME_MAX_DEPTH
+            && r.is_prime()
+            && prove_prime_n_minus_1(&r, depth + 1) == Some(true)
+        {
+            f *= &r;
+            factors.push(r.clone());
+        }
+    }
+    let f_squared = (&f).square();
+    let pocklington = f_squared > *n;
+    if !pocklington && &f_squared * &f <= *n {
+        return None;
+    }
+    for q in &factors {
+        if pocklington_witness(n, &n_minus_1, q)? {
+            continue;
+        }
+        return Some(false);
+    }
+    if pocklington {
+        return Some(true);
+    }
+    let (c_2, c_1) = (&n_minus_1 / &f).div_mod(&f);
+    let c_1_squared: Natural = c_1.square();
+    let four_c_2: Natural = c_2 << 2;
+    Some(c_1_squared < four_c_2 || !(c_1_squared - four_c_2).is_square())
+}
+
+impl IsPrime for Natural {
+    /// Tests whether a [`Natural`] is prime.
+    ///
+    /// Values less than $2^{64}$ are handled by the `u64` implementation, which is deterministic.
+    /// Larger values are checked by trial division by small primes, followed by the Baillie–PSW
+    /// test: a strong probable-prime test to base 2 and a strong Lucas probable-prime test with
+    /// Selfridge's parameters. A `false` result is always correct. No composite number passing the
+    /// Baillie–PSW test is known, although it is believed that infinitely many exist; to obtain a
+    /// proof of primality, use [`prove_prime`](ProvePrime::prove_prime).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: each of
+    /// the two probable-prime tests is a modular powering to an $O(n)$-bit exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::factorization::traits::IsPrime;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(5u32).is_prime(), true);
+    /// assert_eq!(Natural::from(6u32).is_prime(), false);
+    /// assert_eq!((Natural::power_of_2(127) - Natural::from(1u32)).is_prime(), true);
+    /// assert_eq!((Natural::power_of_2(128) + Natural::from(1u32)).is_prime(), false);
+    /// ```
+    fn is_prime(&self) -> bool {
+        match self {
+            Self(Small(x)) => x.is_prime(),
+            Self(Large(xs)) => {
+                if let Ok(x) = u64::try_from(self) {
+                    x.is_prime()
+                } else {
+                    self.odd() && limbs_is_probable_prime_bpsw(self, xs)
+                }
+            }
+        }
+    }
+}
+
+impl ProvePrime for Natural {
+    /// Determines, with a proof, whether a [`Natural`] is prime.
+    ///
+    /// Values less than $2^{64}$ are handled by the `u64` implementation, which is deterministic,
+    /// so the result is always known. A larger value that fails the Baillie–PSW test of
+    /// [`is_prime`](IsPrime::is_prime) is proven composite. Otherwise, $n - 1$ is partially
+    /// factored, by trial division and by recursively proving the remaining cofactor prime, and
+    /// the Pocklington and Brillhart–Lehmer–Selfridge $n - 1$ tests are applied; these succeed
+    /// whenever the completely factored part of $n - 1$ exceeds $n^{1/3}$. When too little of
+    /// $n - 1$ can be factored, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: each of
+    /// the $O(n)$ distinct prime factors found in $n - 1$ costs a bounded number of modular
+    /// powerings, and the recursion into the cofactor has bounded depth.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::factorization::traits::ProvePrime;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(5u32).prove_prime(), Some(true));
+    /// assert_eq!(Natural::from(6u32).prove_prime(), Some(false));
+    /// assert_eq!(
+    ///     (Natural::power_of_2(127) - Natural::from(1u32)).prove_prime(),
+    ///     Some(true)
+    /// );
+    /// // Too little of 2 ^ 521 - 2 can be factored
+    /// assert_eq!(
+    ///     (Natural::power_of_2(521) - Natural::from(1u32)).prove_prime(),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     (Natural::power_of_2(128) + Natural::from(1u32)).prove_prime(),
+    ///     Some(false)
+    /// );
+    /// ```
+    fn prove_prime(&self) -> Option<bool> {
+        if let Ok(x) = u64::try_from(self) {
+            Some(x.is_prime())
+        } else if self.is_prime() {
+            prove_prime_n_minus_1(self, 0)
+        } else {
+            Some(false)
+        }
+    }
+}
@@ -6,15 +6,32 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait
+/// for finding the prime factorization of a number, along with [`Natural::factor_with_options`](
+/// crate::natural::Natural::factor_with_options), which bounds the work done.
+pub mod divisor_sigma;
+pub mod divisors;
+pub mod factor;
 /// Implementations of [`IsPower`](malachite_base::num::factorization::traits::IsPower) and
 /// [`ExpressAsPower`](malachite_base::num::factorization::traits::ExpressAsPower), traits for
 /// testing if a number is a perfect power and, if it is, expressing it as such.
 pub mod is_power;
+/// Implementations of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime) and
+/// [`ProvePrime`](malachite_base::num::factorization::traits::ProvePrime), traits for testing a
+/// number for primality.
+pub mod is_prime;
 /// An implementation of [`IsSquare`](malachite_base::num::factorization::traits::IsSquare), a trait
 /// for testing if a number if a perfect square.
 pub mod is_square;
+pub mod moebius_mu;
+pub mod next_prime;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait for
 /// generating prime numbers.
 pub mod primes;
+/// Implementations of [`PrimitiveRoot`](malachite_base::num::factorization::traits::PrimitiveRoot)
+/// and [`IsPrimitiveRoot`](malachite_base::num::factorization::traits::IsPrimitiveRoot), traits for
+/// finding and recognizing generators of the multiplicative group modulo a number.
+pub mod primitive_root;
 #[doc(hidden)]
 pub mod remove_power;
+pub mod totient;
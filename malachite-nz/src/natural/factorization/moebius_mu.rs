@@ -0,0 +1,95 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::factorization::factor::NaturalFactors;
+use malachite_base::num::factorization::traits::{Factor, MoebiusMu};
+
+impl NaturalFactors {
+    /// Computes the Möbius function $\mu(n)$ of the [`Natural`] $n$ whose prime factorization this
+    /// is.
+    ///
+    /// $\mu(n)$ is 0 if $n$ is divisible by the square of a prime, and $(-1)^k$ if $n$ is the
+    /// product of $k$ distinct primes.
+    ///
+    /// Unlike the other multiplicative functions, this may be computed from an incomplete
+    /// factorization if one of the primes found is repeated, since the result is then 0 regardless
+    /// of the unfactored part.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of distinct prime factors.
+    ///
+    /// # Panics
+    /// Panics if the factorization is not complete and no prime found has an exponent greater than
+    /// 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).factor().moebius_mu(), 1);
+    /// assert_eq!(Natural::from(30u32).factor().moebius_mu(), -1);
+    /// assert_eq!(Natural::from(12u32).factor().moebius_mu(), 0);
+    /// ```
+    pub fn moebius_mu(&self) -> i8 {
+        if self.prime_factors().iter().any(|(_, e)| *e > 1) {
+            return 0;
+        }
+        assert!(self.is_complete(), "the factorization is not complete");
+        if self.prime_factors().len() & 1 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+impl MoebiusMu for Natural {
+    /// Computes the Möbius function $\mu(n)$ of a [`Natural`].
+    ///
+    /// $\mu(n)$ is 0 if $n$ is divisible by the square of a prime, and $(-1)^k$ if $n$ is the
+    /// product of $k$ distinct primes. In particular, $\mu(1) = 1$.
+    ///
+    /// If the factorization of $n$ is already known, use [`NaturalFactors::moebius_mu`] instead.
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: the cost
+    /// is dominated by factoring $n$; see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::MoebiusMu;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).moebius_mu(), 1);
+    /// assert_eq!(Natural::from(30u32).moebius_mu(), -1);
+    /// assert_eq!(Natural::from(12u32).moebius_mu(), 0);
+    /// // 2 ^ 128 + 1 = 59649589127497217 * 5704689200685129054721
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(128) + Natural::from(1u32)).moebius_mu(),
+    ///     1
+    /// );
+    /// ```
+    #[inline]
+    fn moebius_mu(&self) -> i8 {
+        self.factor().moebius_mu()
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::factorization::primes::{NaturalPrimesInRangeIterator, SegmentSieve};
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{NextPrime, PrevPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// When searching for a single prime, the candidates are sieved by the odd primes up to the square
+// of the number of bits of the starting point, clamped to this interval.
+const MIN_SEARCH_SIEVE_BOUND: u64 = 1 << 8;
+const MAX_SEARCH_SIEVE_BOUND: u64 = 1 << 20;
+
+// Returns the sieve bound and the number of odd numbers per segment to use when searching for a
+// prime near a number with the given number of bits. The average gap between primes near $2^n$ is
+// about $0.69n$, so a segment of $n$ odd numbers usually contains a prime.
+fn search_parameters(bits: u64) -> (u64, usize) {
+    (
+        bits.saturating_mul(bits)
+            .clamp(MIN_SEARCH_SIEVE_BOUND, MAX_SEARCH_SIEVE_BOUND),
+        usize::try_from(bits.max(64)).unwrap_or(usize::MAX),
+    )
+}
+
+impl NextPrime for Natural {
+    type Output = Self;
+
+    /// Finds the smallest prime greater than a [`Natural`].
+    ///
+    /// Values whose next prime is less than $2^{64}$ are handled by the `u64` implementation,
+    /// which is deterministic. Otherwise, windows of odd numbers above `self` are sieved by small
+    /// primes, and the survivors are tested in order with `is_prime`; so, as with `is_prime`, the
+    /// result is a probable prime that has passed the Baillie–PSW test.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+    /// heuristically, $O(n)$ candidates are examined, and a constant fraction of them are tested
+    /// with `is_prime`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::num::factorization::traits::NextPrime;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.next_prime(), 2);
+    /// assert_eq!(Natural::from(100u32).next_prime(), 101);
+    /// assert_eq!(
+    ///     Natural::from(u64::MAX).next_prime(),
+    ///     Natural::power_of_2(64) + Natural::from(13u32)
+    /// );
+    /// assert_eq!(
+    ///     Natural::power_of_2(512).next_prime(),
+    ///     Natural::power_of_2(512) + Natural::from(75u32)
+    /// );
+    /// ```
+    fn next_prime(&self) -> Self {
+        if let Ok(x) = u64::try_from(self)
+            && let Some(p) = x.next_prime()
+        {
+            return Self::from(p);
+        }
+        let start = self + Self::ONE;
+        let (sieve_bound, segment_len) = search_parameters(start.significant_bits());
+        NaturalPrimesInRangeIterator::new(&start, None, sieve_bound, segment_len)
+            .next()
+            .unwrap()
+    }
+}
+
+impl PrevPrime for Natural {
+    type Output = Option<Self>;
+
+    /// Finds the largest prime less than a [`Natural`].
+    ///
+    /// Returns `None` if `self` is less than or equal to 2.
+    ///
+    /// Values less than $2^{64}$ are handled by the `u64` implementation, which is deterministic.
+    /// Otherwise, windows of odd numbers below `self` are sieved by small primes, and the survivors
+    /// are tested in decreasing order with `is_prime`; so, as with `is_prime`, the result is a
+    /// probable prime that has passed the Baillie–PSW test.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`:
+    /// heuristically, $O(n)$ candidates are examined, and a constant fraction of them are tested
+    /// with `is_prime`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::Two;
+    /// use malachite_base::num::factorization::traits::PrevPrime;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::TWO.prev_prime(), None);
+    /// assert_eq!(Natural::from(100u32).prev_prime().unwrap(), 97);
+    /// assert_eq!(
+    ///     Natural::power_of_2(64).prev_prime().unwrap(),
+    ///     Natural::power_of_2(64) - Natural::from(59u32)
+    /// );
+    /// assert_eq!(
+    ///     Natural::power_of_2(512).prev_prime().unwrap(),
+    ///     Natural::power_of_2(512) - Natural::from(569u32)
+    /// );
+    /// ```
+    fn prev_prime(&self) -> Option<Self> {
+        if let Ok(x) = u64::try_from(self) {
+            return x.prev_prime().map(Self::from);
+        }
+        let (sieve_bound, segment_len) = search_parameters(self.significant_bits());
+        let sieve = SegmentSieve::new(sieve_bound);
+        let segment_width = Self::from(segment_len << 1);
+        // The largest odd number less than self. Since self is at least 2^64, and there is a prime
+        // between 2^63 and 2^64, the segments never reach small numbers.
+        let mut segment_end = self - if self.even() { Self::ONE } else { Self::TWO };
+        loop {
+            let segment_start = &segment_end + Self::TWO - &segment_width;
+            let survivors = sieve.sieve(&segment_start, segment_len);
+            for (i, &survivor) in survivors.iter().enumerate().rev() {
+                if survivor {
+                    let n = &segment_start + Self::from(i << 1);
+                    if sieve.is_prime(&n) {
+                        return Some(n);
+                    }
+                }
+            }
+            segment_end = segment_start - Self::TWO;
+        }
+    }
+}
@@ -6,10 +6,332 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
-use malachite_base::num::conversion::traits::SaturatingFrom;
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::platform::{DoubleLimb, Limb};
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{FloorSqrt, Parity, Square};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::{ExactFrom, SaturatingFrom, WrappingFrom};
 use malachite_base::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The number of odd numbers sieved at once by `NaturalPrimesInRangeIterator`.
+const SEGMENT_LEN: usize = 1 << 15;
+
+// When generating primes in a range, the segments are sieved by the odd primes up to the square of
+// the number of bits of the range's upper end, clamped to this interval. Larger bounds leave fewer
+// candidates for the probable-prime test, at the cost of more work per segment. For a short range,
+// the bound is further limited to the range's width, but not below `MIN_SHORT_RANGE_SIEVE_BOUND`.
+const MIN_RANGE_SIEVE_BOUND: u64 = 1 << 16;
+const MAX_RANGE_SIEVE_BOUND: u64 = 1 << 20;
+const MIN_SHORT_RANGE_SIEVE_BOUND: u64 = 1 << 8;
+
+// A sieve for segments of odd numbers: each segment is sieved by the odd primes up to some bound,
+// and the survivors are tested with `is_prime` unless they are small enough to be known prime.
+#[derive(Clone, Debug)]
+pub(crate) struct SegmentSieve {
+    // the odd primes up to the bound
+    primes: Vec<Limb>,
+    // the square of one more than the bound. Any survivor less than this is prime.
+    proven_limit: Natural,
+}
+
+impl SegmentSieve {
+    pub(crate) fn new(bound: u64) -> Self {
+        Self {
+            primes: u64::primes_less_than_or_equal_to(&bound)
+                .skip(1)
+                .map(Limb::wrapping_from)
+                .collect(),
+            proven_limit: Natural::from(bound + 1).square(),
+        }
+    }
+
+    // Sieves the odd numbers `start`, `start + 2`, ..., `start + 2 * (len - 1)`, where `start` is
+    // odd. The returned `Vec` is `true` at the indices of numbers with no odd prime factor below
+    // the bound, not counting the primes themselves.
+    pub(crate) fn sieve(&self, start: &Natural, len: usize) -> Vec<bool> {
+        let mut survivors = vec![true; len];
+        let small_start = u64::try_from(start).ok();
+        for &p in &self.primes {
+            let r = match start {
+                Natural(Small(x)) => *x % p,
+                Natural(Large(xs)) => limbs_mod_limb::<DoubleLimb, Limb>(xs, p),
+            };
+            let p = u64::wrapping_from(p);
+            let p_squared = p * p;
+            // The index of the first odd multiple of p that is at least `start`: solve start + 2i =
+            // 0 mod p by multiplying -start by the inverse of 2. If p itself is in the segment,
+            // skip ahead to p^2, the first multiple that isn't prime.
+            let first = match small_start {
+                Some(s) if s < p_squared => (p_squared - s) >> 1,
+                _ => (p - u64::wrapping_from(r)) % p * ((p + 1) >> 1) % p,
+            };
+            let Ok(first) = usize::try_from(first) else {
+                continue;
+            };
+            let p = usize::exact_from(p);
+            for i in (first..len).step_by(p) {
+                survivors[i] = false;
+            }
+        }
+        survivors
+    }
+
+    // Tests whether a number that survived sieving is prime.
+    pub(crate) fn is_prime(&self, n: &Natural) -> bool {
+        *n < self.proven_limit || n.is_prime()
+    }
+}
+
+/// An iterator that generates all prime [`Natural`]s in a range.
+///
+/// This `struct` is created by [`Natural::primes_in_range`],
+/// [`Natural::primes_in_inclusive_range`], and [`Natural::primes_greater_than_or_equal_to`]; see
+/// their documentation for more.
+#[derive(Clone, Debug)]
+pub struct NaturalPrimesInRangeIterator {
+    sieve: SegmentSieve,
+    segment_len: usize,
+    // whether 2 remains to be generated
+    two: bool,
+    // the first number of the current segment, which is odd
+    segment_start: Natural,
+    survivors: Vec<bool>,
+    index: usize,
+    // the exclusive upper end of the range, if any
+    end: Option<Natural>,
+}
+
+impl NaturalPrimesInRangeIterator {
+    pub(crate) fn new(
+        start: &Natural,
+        end: Option<Natural>,
+        sieve_bound: u64,
+        segment_len: usize,
+    ) -> Self {
+        let two = *start <= 2u32 && end.as_ref().is_none_or(|end| *end > 2u32);
+        let mut segment_start = if *start < 3u32 {
+            Natural::from(3u32)
+        } else {
+            start.clone()
+        };
+        if segment_start.even() {
+            segment_start += Natural::ONE;
+        }
+        Self {
+            sieve: SegmentSieve::new(sieve_bound),
+            segment_len,
+            two,
+            segment_start,
+            survivors: Vec::new(),
+            index: 0,
+            end,
+        }
+    }
+}
+
+impl Iterator for NaturalPrimesInRangeIterator {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        if self.two {
+            self.two = false;
+            return Some(Natural::TWO);
+        }
+        loop {
+            while self.index < self.survivors.len() {
+                let i = self.index;
+                self.index += 1;
+                if self.survivors[i] {
+                    let n = &self.segment_start + Natural::from(i << 1);
+                    if self.sieve.is_prime(&n) {
+                        return Some(n);
+                    }
+                }
+            }
+            self.segment_start += Natural::from(self.survivors.len() << 1);
+            let len = if let Some(end) = &self.end {
+                if self.segment_start >= *end {
+                    self.survivors.clear();
+                    self.index = 0;
+                    return None;
+                }
+                min(
+                    self.segment_len,
+                    usize::saturating_from(&((end - &self.segment_start + Natural::ONE) >> 1)),
+                )
+            } else {
+                self.segment_len
+            };
+            self.survivors = self.sieve.sieve(&self.segment_start, len);
+            self.index = 0;
+        }
+    }
+}
+
+fn range_sieve_bound(start: Option<&Natural>, end: &Natural) -> u64 {
+    let bits = end.significant_bits();
+    let mut bound = bits
+        .saturating_mul(bits)
+        .clamp(MIN_RANGE_SIEVE_BOUND, MAX_RANGE_SIEVE_BOUND);
+    if let Some(start) = start
+        && start < end
+        && let Ok(width) = u64::try_from(&(end - start))
+    {
+        bound = min(bound, width.max(MIN_SHORT_RANGE_SIEVE_BOUND));
+    }
+    if let Ok(sqrt) = u64::try_from(&end.floor_sqrt()) {
+        min(bound, sqrt)
+    } else {
+        bound
+    }
+}
+
+impl Natural {
+    /// Returns an iterator that generates all primes in the range $[a, b)$.
+    ///
+    /// The range is processed in segments, each of which is sieved by small primes; the numbers
+    /// that survive are tested with [`is_prime`](IsPrime::is_prime), unless they are small enough
+    /// that the sieve has already proven them prime. Primes are generated lazily, so it is cheap
+    /// to take only the first few primes of a huge range.
+    ///
+    /// # Worst-case complexity (amortized)
+    /// $T(i, n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $i$ is the iteration index, and $n$ is
+    /// `b.significant_bits()`: heuristically, $O(n)$ candidates are examined for each prime
+    /// generated, and a constant fraction of them must be tested with
+    /// [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::primes_in_range(&Natural::from(10u32), &Natural::from(50u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]"
+    /// );
+    ///
+    /// let a = Natural::from(10u32).pow(20);
+    /// let b = &a + Natural::from(200u32);
+    /// assert_eq!(
+    ///     Natural::primes_in_range(&a, &b)
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[100000000000000000039, 100000000000000000129, 100000000000000000151]"
+    /// );
+    ///
+    /// let a = Natural::power_of_2(100);
+    /// assert_eq!(
+    ///     Natural::primes_in_range(&a, &(&a << 1)).next().unwrap(),
+    ///     a + Natural::from(277u32)
+    /// );
+    /// ```
+    pub fn primes_in_range(a: &Self, b: &Self) -> NaturalPrimesInRangeIterator {
+        NaturalPrimesInRangeIterator::new(
+            a,
+            Some(b.clone()),
+            range_sieve_bound(Some(a), b),
+            SEGMENT_LEN,
+        )
+    }
+
+    /// Returns an iterator that generates all primes in the range $[a, b]$.
+    ///
+    /// The range is processed in segments, each of which is sieved by small primes; the numbers
+    /// that survive are tested with [`is_prime`](IsPrime::is_prime), unless they are small enough
+    /// that the sieve has already proven them prime. Primes are generated lazily, so it is cheap
+    /// to take only the first few primes of a huge range.
+    ///
+    /// # Worst-case complexity (amortized)
+    /// $T(i, n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $i$ is the iteration index, and $n$ is
+    /// `b.significant_bits()`: heuristically, $O(n)$ candidates are examined for each prime
+    /// generated, and a constant fraction of them must be tested with
+    /// [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::primes_in_inclusive_range(&Natural::from(11u32), &Natural::from(47u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::primes_in_inclusive_range(&Natural::from(24u32), &Natural::from(28u32))
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[]"
+    /// );
+    /// ```
+    pub fn primes_in_inclusive_range(a: &Self, b: &Self) -> NaturalPrimesInRangeIterator {
+        let b = b + Self::ONE;
+        let bound = range_sieve_bound(Some(a), &b);
+        NaturalPrimesInRangeIterator::new(a, Some(b), bound, SEGMENT_LEN)
+    }
+
+    /// Returns an iterator that generates all primes greater than or equal to a given value.
+    ///
+    /// The numbers are processed in segments, each of which is sieved by small primes; the numbers
+    /// that survive are tested with [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity (amortized)
+    /// $T(i, n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $i$ is the iteration index, and $n$ is the
+    /// number of significant bits of the $i$th prime generated: heuristically, $O(n)$ candidates
+    /// are examined for each prime generated, and a constant fraction of them must be tested with
+    /// [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::primes_greater_than_or_equal_to(&Natural::from(100u32))
+    ///         .take(5)
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[101, 103, 107, 109, 113]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::primes_greater_than_or_equal_to(&Natural::power_of_2(64))
+    ///         .take(3)
+    ///         .collect_vec()
+    ///         .to_debug_string(),
+    ///     "[18446744073709551629, 18446744073709551653, 18446744073709551667]"
+    /// );
+    /// ```
+    pub fn primes_greater_than_or_equal_to(a: &Self) -> NaturalPrimesInRangeIterator {
+        // Size the sieve for numbers a little larger than a
+        let bound = range_sieve_bound(None, &(a << 1u32));
+        NaturalPrimesInRangeIterator::new(a, None, bound, SEGMENT_LEN)
+    }
+}
 
 /// An iterator over that generates all prime [`Natural`]s less than a given value.
 ///
@@ -0,0 +1,192 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::arithmetic::mod_multiplicative_order::{factors_product, totient_factors};
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{Gcd, ModMulPrecomputed, ModPowPrecomputed};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{Factor, IsPrimitiveRoot, PrimitiveRoot};
+
+// Whether `x`, which must be coprime to `m`, has multiplicative order $\varphi(m)$, given
+// $\varphi(m)$ and its prime factors: this holds exactly when no $x^{\varphi(m)/q}$ is 1.
+fn has_full_order(
+    x: &Natural,
+    m: &Natural,
+    phi: &Natural,
+    phi_factors: &[(Natural, u64)],
+    data: &ModMulData,
+) -> bool {
+    phi_factors
+        .iter()
+        .all(|(q, _)| x.mod_pow_precomputed(phi / q, m, data) != 1u32)
+}
+
+fn primitive_root_ref(m: &Natural) -> Option<Natural> {
+    // The `Limb` implementation is the same search, so this delegation does not change any output.
+    if let Natural(Small(m_small)) = m {
+        return m_small.primitive_root().map(Natural::from);
+    }
+    // m is now larger than 4, so the group is cyclic exactly when m is p ^ k or 2 * p ^ k for an
+    // odd prime p.
+    let m_factors: Vec<(Natural, u64)> = m.factor().into_iter().collect();
+    let odd_factors = if m_factors[0].0 == 2u32 {
+        if m_factors[0].1 != 1 {
+            return None;
+        }
+        &m_factors[1..]
+    } else {
+        &m_factors[..]
+    };
+    if odd_factors.len() != 1 {
+        return None;
+    }
+    let phi_factors = totient_factors(&m_factors);
+    let phi = factors_product(&phi_factors);
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+    let mut a = Natural::TWO;
+    loop {
+        if (&a).gcd(m) == 1u32 && has_full_order(&a, m, &phi, &phi_factors, &data) {
+            return Some(a);
+        }
+        a += Natural::ONE;
+    }
+}
+
+fn is_primitive_root_ref_ref(x: &Natural, m: &Natural) -> bool {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    // Since x < m, a `Small` modulus implies a `Small` x.
+    if let Natural(Small(m_small)) = m {
+        let Natural(Small(x_small)) = x else {
+            unreachable!();
+        };
+        return x_small.is_primitive_root(*m_small);
+    }
+    if x.gcd(m) != 1u32 {
+        return false;
+    }
+    let m_factors: Vec<(Natural, u64)> = m.factor().into_iter().collect();
+    let phi_factors = totient_factors(&m_factors);
+    let phi = factors_product(&phi_factors);
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+    has_full_order(x, m, &phi, &phi_factors, &data)
+}
+
+impl PrimitiveRoot for Natural {
+    type Output = Self;
+
+    /// Finds a primitive root modulo a [`Natural`] $m$: a generator of the multiplicative group
+    /// $(\mathbb{Z}/m\mathbb{Z})^\times$. Returns `None` if that group is not cyclic, which is the
+    /// case unless $m$ is 1, 2, 4, $p^k$, or $2p^k$ for an odd prime $p$.
+    ///
+    /// The smallest primitive root is returned. Modulo 1, this is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2})$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`. The cost
+    /// is dominated by factoring $m$ and $p - 1$; each candidate root is then tested with one
+    /// modular powering per prime factor of $\varphi(m)$.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::num::factorization::traits::PrimitiveRoot;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(7u32).primitive_root(),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Natural::from(8u32).primitive_root(), None);
+    /// // 2 ^ 127 - 1
+    /// let m = Natural::from(2u32).pow(127) - Natural::ONE;
+    /// assert_eq!(m.primitive_root(), Some(Natural::from(43u32)));
+    /// ```
+    #[inline]
+    fn primitive_root(&self) -> Option<Self> {
+        primitive_root_ref(self)
+    }
+}
+
+macro_rules! natural_is_primitive_root_doc {
+    ($f:item) => {
+        /// Determines whether a [`Natural`] is a primitive root modulo another [`Natural`] $m$;
+        /// that is, whether its multiplicative order modulo $m$ is $\varphi(m)$. The [`Natural`]
+        /// must be already reduced modulo $m$.
+        ///
+        /// If the multiplicative group modulo $m$ is not cyclic, there are no primitive roots and
+        /// this function always returns `false`. Modulo 1, 0 is a primitive root.
+        ///
+        /// # Worst-case complexity
+        /// $T(n) = O(2^{n/2})$
+        ///
+        /// $M(n) = O(n)$
+        ///
+        /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`. The cost
+        /// is dominated by factoring $m$ and $p - 1$ for each prime $p$ dividing $m$.
+        ///
+        /// # Panics
+        /// Panics if `self` is greater than or equal to `m`.
+        ///
+        /// # Examples
+        /// ```
+        /// use malachite_base::num::factorization::traits::IsPrimitiveRoot;
+        /// use malachite_nz::natural::Natural;
+        ///
+        /// assert!(Natural::from(3u32).is_primitive_root(Natural::from(7u32)));
+        /// assert!(!Natural::from(2u32).is_primitive_root(Natural::from(7u32)));
+        /// assert!(!Natural::from(3u32).is_primitive_root(Natural::from(8u32)));
+        /// ```
+        $f
+    };
+}
+
+impl IsPrimitiveRoot<Self> for Natural {
+    natural_is_primitive_root_doc! {
+        #[inline]
+        fn is_primitive_root(self, m: Self) -> bool {
+            is_primitive_root_ref_ref(&self, &m)
+        }
+    }
+}
+
+impl<'a> IsPrimitiveRoot<&'a Self> for Natural {
+    natural_is_primitive_root_doc! {
+        #[inline]
+        fn is_primitive_root(self, m: &'a Self) -> bool {
+            is_primitive_root_ref_ref(&self, m)
+        }
+    }
+}
+
+impl IsPrimitiveRoot<Natural> for &Natural {
+    natural_is_primitive_root_doc! {
+        #[inline]
+        fn is_primitive_root(self, m: Natural) -> bool {
+            is_primitive_root_ref_ref(self, &m)
+        }
+    }
+}
+
+impl IsPrimitiveRoot<&Natural> for &Natural {
+    natural_is_primitive_root_doc! {
+        #[inline]
+        fn is_primitive_root(self, m: &Natural) -> bool {
+            is_primitive_root_ref_ref(self, m)
+        }
+    }
+}
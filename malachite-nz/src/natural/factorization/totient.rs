@@ -0,0 +1,190 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::natural::factorization::factor::NaturalFactors;
+use malachite_base::num::arithmetic::traits::{LcmAssign, Pow, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor, Totient};
+
+// $\varphi(p^e) = p^{e-1}(p-1)$.
+fn prime_power_totient(p: &Natural, e: u64) -> Natural {
+    p.pow(e - 1) * (p - Natural::ONE)
+}
+
+impl NaturalFactors {
+    /// Computes Euler's totient function $\varphi(n)$ of the [`Natural`] $n$ whose prime
+    /// factorization this is.
+    ///
+    /// $$
+    /// \varphi(p_1^{e_1} \cdots p_k^{e_k}) = \prod_{i=1}^k p_i^{e_i - 1}(p_i - 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the factored
+    /// number.
+    ///
+    /// # Panics
+    /// Panics if the factorization is not complete.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).factor().totient(), 1);
+    /// assert_eq!(Natural::from(36u32).factor().totient(), 12);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(20).factor().totient(),
+    ///     Natural::from(4u32) * Natural::from(10u32).pow(19)
+    /// );
+    /// ```
+    pub fn totient(&self) -> Natural {
+        assert!(self.is_complete(), "the factorization is not complete");
+        let mut phi = Natural::ONE;
+        for (p, e) in self.prime_factors() {
+            phi *= prime_power_totient(p, *e);
+        }
+        phi
+    }
+
+    /// Computes the Carmichael function $\lambda(n)$ of the [`Natural`] $n$ whose prime
+    /// factorization this is: the exponent of the multiplicative group
+    /// $(\mathbb{Z}/n\mathbb{Z})^\times$.
+    ///
+    /// $\lambda(n)$ is the least common multiple of $\lambda(p^e)$ over the prime powers $p^e$
+    /// exactly dividing $n$, where $\lambda(p^e) = \varphi(p^e)$, except that $\lambda(2^e) =
+    /// 2^{e-2}$ when $e \geq 3$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the factored
+    /// number.
+    ///
+    /// # Panics
+    /// Panics if the factorization is not complete.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::Factor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).factor().carmichael_lambda(), 1);
+    /// assert_eq!(Natural::from(561u32).factor().carmichael_lambda(), 80);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(20).factor().carmichael_lambda(),
+    ///     Natural::from(2u32).pow(18) * Natural::from(5u32).pow(19)
+    /// );
+    /// ```
+    pub fn carmichael_lambda(&self) -> Natural {
+        assert!(self.is_complete(), "the factorization is not complete");
+        let mut lambda = Natural::ONE;
+        for (p, e) in self.prime_factors() {
+            lambda.lcm_assign(if *p == 2u32 && *e >= 3 {
+                Natural::power_of_2(e - 2)
+            } else {
+                prime_power_totient(p, *e)
+            });
+        }
+        lambda
+    }
+}
+
+impl Totient for Natural {
+    type Output = Self;
+
+    /// Computes Euler's totient function $\varphi(n)$ of a [`Natural`]: the number of integers in
+    /// $[1, n]$ that are coprime to $n$.
+    ///
+    /// $$
+    /// \varphi(p_1^{e_1} \cdots p_k^{e_k}) = \prod_{i=1}^k p_i^{e_i - 1}(p_i - 1).
+    /// $$
+    ///
+    /// If the factorization of $n$ is already known, use [`NaturalFactors::totient`] instead.
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: the cost
+    /// is dominated by factoring $n$; see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::Totient;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).totient(), 1);
+    /// assert_eq!(Natural::from(36u32).totient(), 12);
+    /// // 2 ^ 128 + 1 = 59649589127497217 * 5704689200685129054721
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(128) + Natural::from(1u32))
+    ///         .totient()
+    ///         .to_string(),
+    ///     "340282366920938457758625757157511659520"
+    /// );
+    /// ```
+    #[inline]
+    fn totient(&self) -> Self {
+        self.factor().totient()
+    }
+}
+
+impl CarmichaelLambda for Natural {
+    type Output = Self;
+
+    /// Computes the Carmichael function $\lambda(n)$ of a [`Natural`]: the smallest positive $m$
+    /// such that $a^m \equiv 1 \pmod n$ for every $a$ coprime to $n$.
+    ///
+    /// $\lambda(n)$ is the least common multiple of $\lambda(p^e)$ over the prime powers $p^e$
+    /// exactly dividing $n$, where $\lambda(p^e) = \varphi(p^e)$, except that $\lambda(2^e) =
+    /// 2^{e-2}$ when $e \geq 3$. It always divides $\varphi(n)$.
+    ///
+    /// If the factorization of $n$ is already known, use [`NaturalFactors::carmichael_lambda`]
+    /// instead.
+    ///
+    /// # Expected complexity
+    /// $T(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// $M(n) = \exp(O(\sqrt{n \log n}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`: the cost
+    /// is dominated by factoring $n$; see [`Natural::factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::CarmichaelLambda;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(1u32).carmichael_lambda(), 1);
+    /// assert_eq!(Natural::from(561u32).carmichael_lambda(), 80);
+    /// assert_eq!(Natural::from(2u32).pow(100).carmichael_lambda(), Natural::from(2u32).pow(98));
+    /// ```
+    #[inline]
+    fn carmichael_lambda(&self) -> Self {
+        self.factor().carmichael_lambda()
+    }
+}
@@ -63,7 +63,7 @@ crate_test_fn! {limbs_slice_set_bit(xs: &mut [Limb], index: u64) {
 // where $T$ is time, $M$ is additional memory, and $n$ is `index`.
 //
 // This is equivalent to `mpz_setbit` from `mpz/setbit.c`, GMP 6.2.1, where `d` is non-negative.
-private_test_fn! {limbs_vec_set_bit(xs: &mut Vec<Limb>, index: u64) {
+crate_test_fn! {limbs_vec_set_bit(xs: &mut Vec<Limb>, index: u64) {
     let small_index = bit_to_limb_count_floor(index);
     if small_index >= xs.len() {
         xs.resize(small_index + 1, 0);
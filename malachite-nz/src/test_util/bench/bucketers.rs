@@ -7,6 +7,7 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::integer::Integer;
+use crate::mod_natural::ModNatural;
 use crate::natural::Natural;
 use crate::natural::logic::significant_bits::limbs_significant_bits;
 use crate::platform::Limb;
@@ -874,3 +875,10 @@ pub fn triple_3_vec_natural_sum_bits_bucketer<'a, T, U>() -> Bucketer<'a, (T, U,
         bucketing_label: "xs.map(|x| x.significant_bits()).sum()".to_string(),
     }
 }
+
+pub fn pair_1_mod_natural_modulus_bit_bucketer<'a, T>() -> Bucketer<'a, (ModNatural, T)> {
+    Bucketer {
+        bucketing_function: &|(x, _)| usize::exact_from(x.modulus_ref().significant_bits()),
+        bucketing_label: "x.modulus_ref().significant_bits()".to_string(),
+    }
+}
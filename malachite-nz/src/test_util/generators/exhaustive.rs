@@ -443,7 +443,11 @@ pub(crate) fn round_to_multiple_integer_filter_map(
     if x == y {
         Some((x, y, rm))
     } else if y == 0 {
-        if rm == Down || rm == if x >= 0 { Floor } else { Ceiling } || rm == Nearest {
+        if rm == Down
+            || rm == if x >= 0 { Floor } else { Ceiling }
+            || rm == Nearest
+            || rm == NearestTiesAway
+        {
             Some((x, y, rm))
         } else {
             None
@@ -1536,7 +1540,7 @@ pub(crate) fn round_to_multiple_natural_filter_map(
     if x == y {
         Some((x, y, rm))
     } else if y == 0 {
-        if rm == Down || rm == Floor || rm == Nearest {
+        if rm == Down || rm == Floor || rm == Nearest || rm == NearestTiesAway {
             Some((x, y, rm))
         } else {
             None
@@ -0,0 +1,38 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::num::basic::traits::{One, Zero};
+
+// The first len Euler numbers from the defining identity sum_{j even} C(n, j) E_j = 0 for even
+// n > 0, the reference the fast implementation is checked against.
+pub fn euler_numbers_prefix_naive(len: u64) -> Vec<Integer> {
+    let mut es: Vec<Integer> = Vec::new();
+    for n in 0..len {
+        let e = if n == 0 {
+            Integer::ONE
+        } else if n & 1 == 1 {
+            Integer::ZERO
+        } else {
+            let n_nat = Natural::from(n);
+            -(0..n)
+                .step_by(2)
+                .map(|j| {
+                    Integer::from(Natural::binomial_coefficient(
+                        n_nat.clone(),
+                        Natural::from(j),
+                    )) * &es[usize::try_from(j).unwrap()]
+                })
+                .sum::<Integer>()
+        };
+        es.push(e);
+    }
+    es
+}
@@ -0,0 +1,26 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+
+pub fn lucas_sequence_naive(p: &Integer, q: &Integer, n: u64) -> (Integer, Integer) {
+    let mut u = Integer::ZERO;
+    let mut u_next = Integer::ONE;
+    let mut v = Integer::TWO;
+    let mut v_next = p.clone();
+    for _ in 0..n {
+        let u_new = p * &u_next - q * &u;
+        u = u_next;
+        u_next = u_new;
+        let v_new = p * &v_next - q * &v;
+        v = v_next;
+        v_next = v_new;
+    }
+    (u, v)
+}
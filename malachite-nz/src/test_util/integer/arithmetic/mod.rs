@@ -9,5 +9,7 @@
 pub mod add;
 pub mod crt;
 pub mod divisible_by;
+pub mod euler_number;
+pub mod lucas_sequence;
 pub mod mul;
 pub mod sign;
@@ -32,9 +32,11 @@ pub mod mod_power_of_2_pow;
 pub mod mod_power_of_2_square;
 pub mod mul;
 pub mod neg;
+pub mod partition_number;
 pub mod pow;
 pub mod primorial;
 pub mod root;
 pub mod sqrt;
 pub mod square;
+pub mod stirling_number;
 pub mod sub;
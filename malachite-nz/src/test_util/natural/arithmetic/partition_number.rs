@@ -0,0 +1,25 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Counts partitions by the largest part allowed, one part size at a time.
+pub fn partition_numbers_prefix_naive(len: usize) -> Vec<Natural> {
+    let mut ps = vec![Natural::ZERO; len];
+    if len != 0 {
+        ps[0] = Natural::ONE;
+    }
+    for part in 1..len {
+        for i in part..len {
+            let (lo, hi) = ps.split_at_mut(i);
+            hi[0] += &lo[i - part];
+        }
+    }
+    ps
+}
@@ -0,0 +1,34 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Builds row n of the triangle of unsigned Stirling numbers of the first kind (if `first_kind` is
+// true) or of Stirling numbers of the second kind, one row at a time.
+fn stirling_row_naive(n: u64, first_kind: bool) -> Vec<Natural> {
+    let mut row = vec![Natural::ONE];
+    for i in 0..n {
+        let mut next = vec![Natural::ZERO; row.len() + 1];
+        for (j, x) in row.iter().enumerate() {
+            let f = if first_kind { i } else { j as u64 };
+            next[j] += Natural::from(f) * x;
+            next[j + 1] += x;
+        }
+        row = next;
+    }
+    row
+}
+
+pub fn stirling_number_1_row_naive(n: u64) -> Vec<Natural> {
+    stirling_row_naive(n, true)
+}
+
+pub fn stirling_number_2_row_naive(n: u64) -> Vec<Natural> {
+    stirling_row_naive(n, false)
+}
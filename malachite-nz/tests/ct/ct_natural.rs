@@ -0,0 +1,156 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::LowMask;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::ct::{Choice, CtNatural, op_count, reset_op_count};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen;
+use std::cmp::max;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_choice() {
+    assert_eq!(!Choice::TRUE, Choice::FALSE);
+    assert_eq!(!Choice::FALSE, Choice::TRUE);
+    for x in [false, true] {
+        assert_eq!(bool::from(Choice::from(x)), x);
+        for y in [false, true] {
+            assert_eq!(Choice::from(x) & Choice::from(y), Choice::from(x && y));
+            assert_eq!(Choice::from(x) | Choice::from(y), Choice::from(x || y));
+        }
+    }
+}
+
+#[test]
+fn test_ct_natural() {
+    let test = |x, y, bits, eq: bool, lt: bool| {
+        let x = Natural::from_str(x).unwrap();
+        let y = Natural::from_str(y).unwrap();
+        let mut a = CtNatural::from_natural(&x, bits);
+        let mut b = CtNatural::from_natural(&y, bits);
+        assert_eq!(a.bits(), bits);
+        assert_eq!(a.to_natural(), x);
+        assert_eq!(bool::from(a.ct_eq(&b)), eq);
+        assert_eq!(bool::from(a.ct_lt(&b)), lt);
+        assert_eq!(
+            CtNatural::conditional_select(&a, &b, Choice::FALSE).to_natural(),
+            x
+        );
+        assert_eq!(
+            CtNatural::conditional_select(&a, &b, Choice::TRUE).to_natural(),
+            y
+        );
+        CtNatural::conditional_swap(&mut a, &mut b, Choice::FALSE);
+        assert_eq!(a.to_natural(), x);
+        assert_eq!(b.to_natural(), y);
+        CtNatural::conditional_swap(&mut a, &mut b, Choice::TRUE);
+        assert_eq!(a.to_natural(), y);
+        assert_eq!(b.to_natural(), x);
+        a.conditional_assign(&b, Choice::FALSE);
+        assert_eq!(a.to_natural(), y);
+        a.conditional_assign(&b, Choice::TRUE);
+        assert_eq!(a.to_natural(), x);
+    };
+    test("0", "0", 1, true, false);
+    test("0", "1", 1, false, true);
+    test("123", "456", 64, false, true);
+    test("456", "123", 100, false, false);
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105727",
+        127,
+        true,
+        false,
+    );
+    test(
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105727",
+        200,
+        false,
+        true,
+    );
+}
+
+#[test]
+fn ct_natural_fail() {
+    assert_panic!(CtNatural::from_natural(&Natural::ZERO, 0));
+    assert_panic!(CtNatural::from_natural(&Natural::from(8u32), 3));
+    let x = CtNatural::from_natural(&Natural::from(8u32), 64);
+    let y = CtNatural::from_natural(&Natural::from(8u32), 65);
+    assert_panic!(x.ct_eq(&y));
+    assert_panic!(x.ct_lt(&y));
+    assert_panic!(CtNatural::conditional_select(&x, &y, Choice::TRUE));
+    assert_panic!({
+        let mut y = y.clone();
+        y.conditional_assign(&x, Choice::TRUE);
+    });
+}
+
+#[test]
+fn ct_natural_properties() {
+    natural_pair_gen().test_properties(|(x, y)| {
+        let bits = max(max(x.significant_bits(), y.significant_bits()), 1);
+        let a = CtNatural::from_natural(&x, bits);
+        let b = CtNatural::from_natural(&y, bits);
+        assert_eq!(a.to_natural(), x);
+        assert_eq!(bool::from(a.ct_eq(&b)), x == y);
+        assert_eq!(bool::from(a.ct_lt(&b)), x < y);
+        assert_eq!(a.ct_eq(&b), b.ct_eq(&a));
+        let choice = a.ct_lt(&b);
+        assert_eq!(
+            CtNatural::conditional_select(&a, &b, choice).to_natural(),
+            max(&x, &y).clone()
+        );
+    });
+}
+
+// The harness for instruction-trace independence: every limb operation performed by a `ct`
+// function is counted, and the count must depend on the declared widths of the inputs but not
+// on their values.
+fn op_count_of<F: FnOnce()>(f: F) -> u64 {
+    reset_op_count();
+    f();
+    op_count()
+}
+
+#[test]
+fn ct_natural_trace_independence() {
+    for bits in [1, 64, 65, 1000] {
+        let max_value = Natural::low_mask(bits);
+        let values = [Natural::ZERO, Natural::from(1u32), max_value.clone(), max_value >> 1];
+        let mut counts = Vec::new();
+        for x in &values {
+            for y in &values {
+                let a = CtNatural::from_natural(x, bits);
+                let mut b = CtNatural::from_natural(y, bits);
+                let choice = a.ct_lt(&b);
+                counts.push((
+                    op_count_of(|| {
+                        a.ct_eq(&b);
+                    }),
+                    op_count_of(|| {
+                        a.ct_lt(&b);
+                    }),
+                    op_count_of(|| {
+                        CtNatural::conditional_select(&a, &b, choice);
+                    }),
+                    op_count_of(|| {
+                        let mut a = a.clone();
+                        CtNatural::conditional_swap(&mut a, &mut b, choice);
+                    }),
+                ));
+            }
+        }
+        assert!(counts.iter().all(|c| *c == counts[0]));
+        assert_ne!(counts[0].0, 0);
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{Gcd, ModInverse, ModMul, ModPow, Parity, Pow};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::{LowMask, SignificantBits};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_nz::ct::modulus::CtModulus;
+use malachite_nz::ct::{Choice, CtNatural, op_count, reset_op_count};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_triple_gen_var_3, natural_triple_gen_var_5};
+use std::cmp::max;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_ct_modulus() {
+    let test = |x, y, m, bits, product, power, inverse: Option<&str>| {
+        let m = CtModulus::new(&Natural::from_str(m).unwrap(), bits);
+        assert_eq!(m.bits(), bits);
+        let x = CtNatural::from_natural(&Natural::from_str(x).unwrap(), bits);
+        let y_natural = Natural::from_str(y).unwrap();
+        let y = CtNatural::from_natural(&y_natural, bits);
+        assert_eq!(m.mod_mul(&x, &y).to_natural().to_string(), product);
+        let exp = CtNatural::from_natural(&y_natural, max(y_natural.significant_bits(), 1));
+        assert_eq!(m.mod_pow(&x, &exp).to_natural().to_string(), power);
+        let (z, exists) = m.mod_inverse(&x);
+        assert_eq!(bool::from(exists), inverse.is_some());
+        assert_eq!(z.to_natural().to_string(), inverse.unwrap_or("0"));
+    };
+    test("0", "0", "3", 2, "0", "1", None);
+    test("3", "5", "7", 3, "1", "5", Some("5"));
+    test("3", "5", "7", 1000, "1", "5", Some("5"));
+    test("4", "13", "497", 9, "52", "445", Some("373"));
+    test("71", "7", "497", 64, "0", "71", None);
+    // - m = 2 ^ 127 - 1
+    test(
+        "123456789012345678901234567890",
+        "98765432109876543210987654321",
+        "170141183460469231731687303715884105727",
+        127,
+        "82544020355360328516762341607148724078",
+        "18380315435097344224164758837907565596",
+        Some("48464825753085841100438376607502766223"),
+    );
+    // - m = 3 ^ 80
+    test(
+        "123456789012345678901234567",
+        "987654321098765432109876543",
+        "147808829414345923316083210206383297601",
+        256,
+        "23981319749122748130929269129170553338",
+        "108263967232635457517871377256520956079",
+        Some("6671373458431714984068250392523930465"),
+    );
+}
+
+#[test]
+fn ct_modulus_fail() {
+    assert_panic!(CtModulus::new(&Natural::ZERO, 64));
+    assert_panic!(CtModulus::new(&Natural::ONE, 64));
+    assert_panic!(CtModulus::new(&Natural::from(10u32), 64));
+    assert_panic!(CtModulus::new(&Natural::from(9u32), 3));
+    let m = CtModulus::new(&Natural::from(7u32), 64);
+    let x = CtNatural::from_natural(&Natural::from(7u32), 64);
+    let y = CtNatural::from_natural(&Natural::from(3u32), 65);
+    let one = CtNatural::from_natural(&Natural::ONE, 64);
+    assert_panic!(m.mod_mul(&x, &one));
+    assert_panic!(m.mod_mul(&one, &y));
+    assert_panic!(m.mod_pow(&x, &one));
+    assert_panic!(m.mod_inverse(&x));
+    assert_panic!(m.mod_inverse(&y));
+}
+
+#[test]
+fn ct_modulus_properties() {
+    natural_triple_gen_var_3().test_properties_with_limit(TINY_LIMIT, |(x, y, m)| {
+        if m.even() || m == 1u32 {
+            return;
+        }
+        let bits = m.significant_bits();
+        for bits in [bits, bits + 1, bits + 100] {
+            let ct_m = CtModulus::new(&m, bits);
+            let a = CtNatural::from_natural(&x, bits);
+            let b = CtNatural::from_natural(&y, bits);
+            let product = ct_m.mod_mul(&a, &b);
+            assert_eq!(product.bits(), bits);
+            assert_eq!(product.to_natural(), (&x).mod_mul(&y, &m));
+
+            let (inverse, exists) = ct_m.mod_inverse(&a);
+            assert_eq!(inverse.bits(), bits);
+            if x == 0u32 {
+                assert_eq!(exists, Choice::FALSE);
+                assert_eq!(inverse.to_natural(), 0u32);
+            } else {
+                let expected = (&x).mod_inverse(&m);
+                assert_eq!(bool::from(exists), expected.is_some());
+                assert_eq!(inverse.to_natural(), expected.unwrap_or(Natural::ZERO));
+                assert_eq!(bool::from(exists), (&x).gcd(&m) == 1u32);
+            }
+        }
+    });
+
+    natural_triple_gen_var_5().test_properties_with_limit(TINY_LIMIT, |(x, exp, m)| {
+        if m.even() || m == 1u32 {
+            return;
+        }
+        let bits = m.significant_bits();
+        let ct_m = CtModulus::new(&m, bits);
+        let a = CtNatural::from_natural(&x, bits);
+        let exp_bits = max(exp.significant_bits(), 1);
+        let power = ct_m.mod_pow(&a, &CtNatural::from_natural(&exp, exp_bits));
+        assert_eq!(power.bits(), bits);
+        assert_eq!(power.to_natural(), (&x).mod_pow(&exp, &m));
+        let padded_power = ct_m.mod_pow(&a, &CtNatural::from_natural(&exp, exp_bits + 100));
+        assert_eq!(padded_power.to_natural(), power.to_natural());
+    });
+}
+
+// The harness for instruction-trace independence: every limb operation performed by a `ct`
+// function is counted, and the count must depend on the declared widths of the inputs but not
+// on their values.
+fn op_count_of<F: FnOnce()>(f: F) -> u64 {
+    reset_op_count();
+    f();
+    op_count()
+}
+
+fn assert_all_equal(counts: &[u64]) {
+    assert_ne!(counts[0], 0);
+    assert!(counts.iter().all(|&c| c == counts[0]), "{counts:?}");
+}
+
+#[test]
+fn ct_modulus_trace_independence() {
+    let moduli = [
+        (Natural::from(497u32), 64),
+        (
+            Natural::from_str("170141183460469231731687303715884105727").unwrap(),
+            127,
+        ),
+        (Natural::from(3u32).pow(600) + Natural::TWO, 1024),
+    ];
+    for (m, bits) in moduli {
+        let ct_m = CtModulus::new(&m, bits);
+        // Values chosen to take every branch that a non-constant-time implementation might: zero,
+        // one, the largest residue, a small value, a non-invertible value, and powers of 2.
+        let mut values = vec![
+            Natural::ZERO,
+            Natural::ONE,
+            &m - Natural::ONE,
+            Natural::TWO,
+            Natural::from(7u32),
+            Natural::low_mask(bits - 1) % &m,
+            (Natural::ONE << (bits - 1)) % &m,
+        ];
+        if let Some(p) = (2u32..1000)
+            .map(Natural::from)
+            .find(|p| (&m).gcd(p) != 1u32)
+        {
+            values.push(p);
+        }
+        let values: Vec<CtNatural> = values
+            .iter()
+            .map(|x| CtNatural::from_natural(x, bits))
+            .collect();
+        let exps: Vec<CtNatural> = [
+            Natural::ZERO,
+            Natural::ONE,
+            Natural::low_mask(bits),
+            Natural::ONE << (bits - 1),
+            Natural::from(65537u32),
+        ]
+        .iter()
+        .map(|x| CtNatural::from_natural(x, bits))
+        .collect();
+
+        let mut mul_counts = Vec::new();
+        let mut pow_counts = Vec::new();
+        let mut inverse_counts = Vec::new();
+        for x in &values {
+            for y in &values {
+                mul_counts.push(op_count_of(|| {
+                    ct_m.mod_mul(x, y);
+                }));
+            }
+            for e in &exps {
+                pow_counts.push(op_count_of(|| {
+                    ct_m.mod_pow(x, e);
+                }));
+            }
+            inverse_counts.push(op_count_of(|| {
+                ct_m.mod_inverse(x);
+            }));
+        }
+        assert_all_equal(&mul_counts);
+        assert_all_equal(&pow_counts);
+        assert_all_equal(&inverse_counts);
+    }
+
+    // The count does depend on the declared widths.
+    let m = Natural::from(497u32);
+    let x = Natural::from(100u32);
+    let count_at = |bits| {
+        let ct_m = CtModulus::new(&m, bits);
+        let x = CtNatural::from_natural(&x, bits);
+        op_count_of(|| {
+            ct_m.mod_inverse(&x);
+        })
+    };
+    assert!(count_at(64) < count_at(128));
+}
@@ -0,0 +1,81 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::integer::Integer;
+use malachite_nz::integer::arithmetic::euler_number::euler_numbers_prefix;
+use malachite_nz::test_util::integer::arithmetic::euler_number::euler_numbers_prefix_naive;
+
+#[test]
+fn test_euler_number() {
+    let test = |n, out| {
+        assert_eq!(Integer::euler_number(n).to_string(), out);
+    };
+    test(0, "1");
+    test(1, "0");
+    test(2, "-1");
+    test(4, "5");
+    test(6, "-61");
+    test(8, "1385");
+    test(99, "0");
+    test(
+        100,
+        "2903528346661097497054603834764435875077553006646158945080492319146997643370625023889353\
+        447129967354174648294748510553528692457632980625125",
+    );
+    assert_eq!(Integer::euler_number(u64::MAX), 0);
+}
+
+#[test]
+fn test_euler_numbers_prefix() {
+    let test = |len, out: &[&str]| {
+        assert_eq!(
+            euler_numbers_prefix(len)
+                .iter()
+                .map(Integer::to_string)
+                .collect::<Vec<_>>(),
+            out
+        );
+    };
+    test(0, &[]);
+    test(1, &["1"]);
+    test(2, &["1", "0"]);
+    test(3, &["1", "0", "-1"]);
+    test(
+        13,
+        &["1", "0", "-1", "0", "5", "0", "-61", "0", "1385", "0", "-50521", "0", "2702765"],
+    );
+    assert_eq!(euler_numbers_prefix(80), euler_numbers_prefix_naive(80));
+}
+
+#[test]
+fn euler_number_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let e = Integer::euler_number(n);
+        assert!(e.is_valid());
+        let prefix = euler_numbers_prefix(n + 1);
+        assert_eq!(prefix.len(), usize::try_from(n + 1).unwrap());
+        assert_eq!(prefix.last(), Some(&e));
+        if n.odd() {
+            assert_eq!(e, 0);
+        } else {
+            assert_ne!(e, 0);
+            // the signs alternate
+            assert_eq!(e > 0, (n >> 1).even());
+            // E_n is odd for even n
+            assert!(e.odd());
+        }
+    });
+
+    for n in 0..40 {
+        let e = euler_numbers_prefix_naive(n + 1).pop().unwrap();
+        assert_eq!(Integer::euler_number(n), e);
+    }
+}
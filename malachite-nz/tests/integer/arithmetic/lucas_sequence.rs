@@ -0,0 +1,185 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{
+    Fibonacci, LucasNumber, Mod, ModMul, ModPow, ModSquare, ModSub, Parity, Pow, Square,
+    UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen, integer_integer_unsigned_triple_gen_var_1,
+};
+use malachite_nz::test_util::integer::arithmetic::lucas_sequence::lucas_sequence_naive;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_lucas_sequence() {
+    let test = |p, q, n, u_out, v_out| {
+        let p = Integer::from_str(p).unwrap();
+        let q = Integer::from_str(q).unwrap();
+        let (u, v) = Integer::lucas_sequence(&p, &q, n);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!(u.to_string(), u_out);
+        assert_eq!(v.to_string(), v_out);
+        assert_eq!((u, v), lucas_sequence_naive(&p, &q, n));
+    };
+    test("0", "0", 0, "0", "2");
+    test("0", "0", 1, "1", "0");
+    test("0", "0", 5, "0", "0");
+    test("5", "3", 0, "0", "2");
+    test("5", "3", 1, "1", "5");
+    test("1", "-1", 10, "55", "123");
+    test(
+        "1",
+        "-1",
+        100,
+        "354224848179261915075",
+        "792070839848372253127",
+    );
+    test("3", "2", 10, "1023", "1025");
+    test("2", "1", 10, "10", "2");
+    test("-4", "7", 5, "-31", "236");
+    test("0", "-1", 7, "1", "0");
+    test("0", "-1", 8, "0", "2");
+    test(
+        "123456789",
+        "-987654321",
+        6,
+        "28679726036780739638852779473296631365592",
+        "3540707344776237047716798460166312013897722907698",
+    );
+}
+
+#[test]
+fn test_mod_lucas_sequence() {
+    let test = |p, q, n, m, u_out, v_out| {
+        let p = Integer::from_str(p).unwrap();
+        let q = Integer::from_str(q).unwrap();
+        let n = Natural::from_str(n).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let (u, v) = Integer::mod_lucas_sequence(&p, &q, &n, &m);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!(u.to_string(), u_out);
+        assert_eq!(v.to_string(), v_out);
+    };
+    test("1", "-1", "10", "1", "0", "0");
+    test("1", "-1", "0", "7", "0", "2");
+    test("1", "-1", "0", "2", "0", "0");
+    test("1", "-1", "10", "7", "6", "4");
+    test("-4", "7", "5", "10", "9", "6");
+    test("1", "-1", "1000000008", "1000000007", "0", "1000000005");
+    test("1", "-1", "1000000006", "1000000007", "1", "1000000004");
+    // - m = 2 ^ 127 - 1 is prime, and D = 5 is a quadratic nonresidue mod m, so m + 1 divides the
+    //   rank of apparition
+    test(
+        "1",
+        "-1",
+        "170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+        "0",
+        "170141183460469231731687303715884105725",
+    );
+    test(
+        "-123456789",
+        "987654321",
+        "1000000000000000000000000000000",
+        "1000000000000000000000000000000",
+        "932517661964099379944201963309",
+        "999999999999999999999999999999",
+    );
+}
+
+#[test]
+fn mod_lucas_sequence_fail() {
+    assert_panic!(Integer::mod_lucas_sequence(
+        &Integer::ONE,
+        &Integer::NEGATIVE_ONE,
+        &Natural::from(10u32),
+        &Natural::ZERO
+    ));
+}
+
+#[test]
+fn lucas_sequence_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_small_n", 16);
+    integer_integer_unsigned_triple_gen_var_1::<u64>().test_properties_with_config(
+        &config,
+        |(p, q, n)| {
+            let (u, v) = Integer::lucas_sequence(&p, &q, n);
+            assert!(u.is_valid());
+            assert!(v.is_valid());
+            assert_eq!((u.clone(), v.clone()), lucas_sequence_naive(&p, &q, n));
+
+            // V_n ^ 2 - D * U_n ^ 2 = 4 * Q ^ n
+            let d = (&p).square() - (&q << 2u32);
+            assert_eq!((&v).square() - d * (&u).square(), (&q).pow(n) << 2u32);
+
+            // U(-P, Q) and V(-P, Q) differ from U(P, Q) and V(P, Q) by the sign (-1) ^ n
+            let (u_neg, v_neg) = Integer::lucas_sequence(&-&p, &q, n);
+            if n.even() {
+                assert_eq!(u_neg, -&u);
+                assert_eq!(v_neg, v);
+            } else {
+                assert_eq!(u_neg, u);
+                assert_eq!(v_neg, -&v);
+            }
+
+            for m in [1u32, 2, 7, 10, 1000000007] {
+                let m = Natural::from(m);
+                let m_integer = Integer::from(&m);
+                assert_eq!(
+                    Integer::mod_lucas_sequence(&p, &q, &Natural::from(n), &m),
+                    (
+                        (&u).mod_op(&m_integer).unsigned_abs(),
+                        (&v).mod_op(&m_integer).unsigned_abs()
+                    )
+                );
+            }
+        },
+    );
+}
+
+#[test]
+fn mod_lucas_sequence_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(p, q, k)| {
+        for m in [1u32, 2, 10, 1000000007] {
+            let m = Natural::from(m);
+            let (u, v) = Integer::mod_lucas_sequence(&p, &q, &k, &m);
+            assert!(u < m || m == 1u32 && u == 0u32);
+            assert!(v < m || m == 1u32 && v == 0u32);
+            // U_{2k} = U_k * V_k and V_{2k} = V_k ^ 2 - 2 * Q ^ k
+            let (u_double, v_double) = Integer::mod_lucas_sequence(&p, &q, &(&k << 1u32), &m);
+            assert_eq!(u_double, (&u).mod_mul(&v, &m));
+            let q_power = (&q)
+                .mod_op(Integer::from(&m))
+                .unsigned_abs()
+                .mod_pow(&k, &m);
+            assert_eq!(
+                v_double,
+                (&v).mod_square(&m).mod_sub((&q_power << 1u32) % &m, &m)
+            );
+        }
+    });
+}
+
+#[test]
+fn lucas_sequence_fibonacci_properties() {
+    for n in 0..200u64 {
+        let (u, v) = Integer::lucas_sequence(&Integer::ONE, &Integer::NEGATIVE_ONE, n);
+        assert_eq!(u, Natural::fibonacci(n));
+        assert_eq!(v, Natural::lucas_number(n));
+    }
+}
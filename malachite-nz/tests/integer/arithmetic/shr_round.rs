@@ -58,6 +58,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("0", 0, Floor, "0", Equal);
         test("0", 0, Ceiling, "0", Equal);
         test("0", 0, Nearest, "0", Equal);
+        test("0", 0, NearestTiesAway, "0", Equal);
+        test("0", 0, Odd, "0", Equal);
         test("0", 0, Exact, "0", Equal);
 
         test("0", 10, Down, "0", Equal);
@@ -65,6 +67,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("0", 10, Floor, "0", Equal);
         test("0", 10, Ceiling, "0", Equal);
         test("0", 10, Nearest, "0", Equal);
+        test("0", 10, NearestTiesAway, "0", Equal);
+        test("0", 10, Odd, "0", Equal);
         test("0", 10, Exact, "0", Equal);
 
         test("123", 0, Down, "123", Equal);
@@ -72,6 +76,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("123", 0, Floor, "123", Equal);
         test("123", 0, Ceiling, "123", Equal);
         test("123", 0, Nearest, "123", Equal);
+        test("123", 0, NearestTiesAway, "123", Equal);
+        test("123", 0, Odd, "123", Equal);
         test("123", 0, Exact, "123", Equal);
 
         test("245", 1, Down, "122", Less);
@@ -79,12 +85,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("245", 1, Floor, "122", Less);
         test("245", 1, Ceiling, "123", Greater);
         test("245", 1, Nearest, "122", Less);
+        test("245", 1, NearestTiesAway, "123", Greater);
+        test("245", 1, Odd, "123", Greater);
 
         test("246", 1, Down, "123", Equal);
         test("246", 1, Up, "123", Equal);
         test("246", 1, Floor, "123", Equal);
         test("246", 1, Ceiling, "123", Equal);
         test("246", 1, Nearest, "123", Equal);
+        test("246", 1, NearestTiesAway, "123", Equal);
+        test("246", 1, Odd, "123", Equal);
         test("246", 1, Exact, "123", Equal);
 
         test("247", 1, Down, "123", Less);
@@ -92,18 +102,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("247", 1, Floor, "123", Less);
         test("247", 1, Ceiling, "124", Greater);
         test("247", 1, Nearest, "124", Greater);
+        test("247", 1, NearestTiesAway, "124", Greater);
+        test("247", 1, Odd, "123", Less);
 
         test("491", 2, Down, "122", Less);
         test("491", 2, Up, "123", Greater);
         test("491", 2, Floor, "122", Less);
         test("491", 2, Ceiling, "123", Greater);
         test("491", 2, Nearest, "123", Greater);
+        test("491", 2, NearestTiesAway, "123", Greater);
+        test("491", 2, Odd, "123", Greater);
 
         test("492", 2, Down, "123", Equal);
         test("492", 2, Up, "123", Equal);
         test("492", 2, Floor, "123", Equal);
         test("492", 2, Ceiling, "123", Equal);
         test("492", 2, Nearest, "123", Equal);
+        test("492", 2, NearestTiesAway, "123", Equal);
+        test("492", 2, Odd, "123", Equal);
         test("492", 2, Exact, "123", Equal);
 
         test("493", 2, Down, "123", Less);
@@ -111,18 +127,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("493", 2, Floor, "123", Less);
         test("493", 2, Ceiling, "124", Greater);
         test("493", 2, Nearest, "123", Less);
+        test("493", 2, NearestTiesAway, "123", Less);
+        test("493", 2, Odd, "123", Less);
 
         test("4127195135", 25, Down, "122", Less);
         test("4127195135", 25, Up, "123", Greater);
         test("4127195135", 25, Floor, "122", Less);
         test("4127195135", 25, Ceiling, "123", Greater);
         test("4127195135", 25, Nearest, "123", Greater);
+        test("4127195135", 25, NearestTiesAway, "123", Greater);
+        test("4127195135", 25, Odd, "123", Greater);
 
         test("4127195136", 25, Down, "123", Equal);
         test("4127195136", 25, Up, "123", Equal);
         test("4127195136", 25, Floor, "123", Equal);
         test("4127195136", 25, Ceiling, "123", Equal);
         test("4127195136", 25, Nearest, "123", Equal);
+        test("4127195136", 25, NearestTiesAway, "123", Equal);
+        test("4127195136", 25, Odd, "123", Equal);
         test("4127195136", 25, Exact, "123", Equal);
 
         test("4127195137", 25, Down, "123", Less);
@@ -130,18 +152,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("4127195137", 25, Floor, "123", Less);
         test("4127195137", 25, Ceiling, "124", Greater);
         test("4127195137", 25, Nearest, "123", Less);
+        test("4127195137", 25, NearestTiesAway, "123", Less);
+        test("4127195137", 25, Odd, "123", Less);
 
         test("8254390271", 26, Down, "122", Less);
         test("8254390271", 26, Up, "123", Greater);
         test("8254390271", 26, Floor, "122", Less);
         test("8254390271", 26, Ceiling, "123", Greater);
         test("8254390271", 26, Nearest, "123", Greater);
+        test("8254390271", 26, NearestTiesAway, "123", Greater);
+        test("8254390271", 26, Odd, "123", Greater);
 
         test("8254390272", 26, Down, "123", Equal);
         test("8254390272", 26, Up, "123", Equal);
         test("8254390272", 26, Floor, "123", Equal);
         test("8254390272", 26, Ceiling, "123", Equal);
         test("8254390272", 26, Nearest, "123", Equal);
+        test("8254390272", 26, NearestTiesAway, "123", Equal);
+        test("8254390272", 26, Odd, "123", Equal);
         test("8254390272", 26, Exact, "123", Equal);
 
         test("8254390273", 26, Down, "123", Less);
@@ -149,6 +177,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("8254390273", 26, Floor, "123", Less);
         test("8254390273", 26, Ceiling, "124", Greater);
         test("8254390273", 26, Nearest, "123", Less);
+        test("8254390273", 26, NearestTiesAway, "123", Less);
+        test("8254390273", 26, Odd, "123", Less);
 
         test("155921023828072216384094494261247", 100, Down, "122", Less);
         test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -222,12 +252,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("4294967295", 1, Floor, "2147483647", Less);
         test("4294967295", 1, Ceiling, "2147483648", Greater);
         test("4294967295", 1, Nearest, "2147483648", Greater);
+        test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+        test("4294967295", 1, Odd, "2147483647", Less);
 
         test("4294967296", 1, Down, "2147483648", Equal);
         test("4294967296", 1, Up, "2147483648", Equal);
         test("4294967296", 1, Floor, "2147483648", Equal);
         test("4294967296", 1, Ceiling, "2147483648", Equal);
         test("4294967296", 1, Nearest, "2147483648", Equal);
+        test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+        test("4294967296", 1, Odd, "2147483648", Equal);
         test("4294967296", 1, Exact, "2147483648", Equal);
 
         test("4294967297", 1, Down, "2147483648", Less);
@@ -235,12 +269,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("4294967297", 1, Floor, "2147483648", Less);
         test("4294967297", 1, Ceiling, "2147483649", Greater);
         test("4294967297", 1, Nearest, "2147483648", Less);
+        test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+        test("4294967297", 1, Odd, "2147483649", Greater);
 
         test("1000000000000", 0, Down, "1000000000000", Equal);
         test("1000000000000", 0, Up, "1000000000000", Equal);
         test("1000000000000", 0, Floor, "1000000000000", Equal);
         test("1000000000000", 0, Ceiling, "1000000000000", Equal);
         test("1000000000000", 0, Nearest, "1000000000000", Equal);
+        test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+        test("1000000000000", 0, Odd, "1000000000000", Equal);
         test("1000000000000", 0, Exact, "1000000000000", Equal);
 
         test("7999999999999", 3, Down, "999999999999", Less);
@@ -248,12 +286,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("7999999999999", 3, Floor, "999999999999", Less);
         test("7999999999999", 3, Ceiling, "1000000000000", Greater);
         test("7999999999999", 3, Nearest, "1000000000000", Greater);
+        test("7999999999999", 3, NearestTiesAway, "1000000000000", Greater);
+        test("7999999999999", 3, Odd, "999999999999", Less);
 
         test("8000000000000", 3, Down, "1000000000000", Equal);
         test("8000000000000", 3, Up, "1000000000000", Equal);
         test("8000000000000", 3, Floor, "1000000000000", Equal);
         test("8000000000000", 3, Ceiling, "1000000000000", Equal);
         test("8000000000000", 3, Nearest, "1000000000000", Equal);
+        test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+        test("8000000000000", 3, Odd, "1000000000000", Equal);
         test("8000000000000", 3, Exact, "1000000000000", Equal);
 
         test("8000000000001", 3, Down, "1000000000000", Less);
@@ -261,12 +303,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("8000000000001", 3, Floor, "1000000000000", Less);
         test("8000000000001", 3, Ceiling, "1000000000001", Greater);
         test("8000000000001", 3, Nearest, "1000000000000", Less);
+        test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+        test("8000000000001", 3, Odd, "1000000000001", Greater);
 
         test("16777216000000000000", 24, Down, "1000000000000", Equal);
         test("16777216000000000000", 24, Up, "1000000000000", Equal);
         test("16777216000000000000", 24, Floor, "1000000000000", Equal);
         test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
         test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+        test("16777216000000000000", 24, NearestTiesAway, "1000000000000", Equal);
+        test("16777216000000000000", 24, Odd, "1000000000000", Equal);
         test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
         test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -274,6 +320,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("33554432000000000000", 25, Floor, "1000000000000", Equal);
         test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
         test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+        test("33554432000000000000", 25, NearestTiesAway, "1000000000000", Equal);
+        test("33554432000000000000", 25, Odd, "1000000000000", Equal);
         test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
         test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -381,6 +429,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("1000000000000", 10, Floor, "976562500", Equal);
         test("1000000000000", 10, Ceiling, "976562500", Equal);
         test("1000000000000", 10, Nearest, "976562500", Equal);
+        test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+        test("1000000000000", 10, Odd, "976562500", Equal);
         test("1000000000000", 10, Exact, "976562500", Equal);
 
         test("980657949", 72, Down, "0", Less);
@@ -388,24 +438,32 @@ macro_rules! test_shr_round_unsigned_helper {
         test("980657949", 72, Floor, "0", Less);
         test("980657949", 72, Ceiling, "1", Greater);
         test("980657949", 72, Nearest, "0", Less);
+        test("980657949", 72, NearestTiesAway, "0", Less);
+        test("980657949", 72, Odd, "1", Greater);
 
         test("4294967295", 31, Down, "1", Less);
         test("4294967295", 31, Up, "2", Greater);
         test("4294967295", 31, Floor, "1", Less);
         test("4294967295", 31, Ceiling, "2", Greater);
         test("4294967295", 31, Nearest, "2", Greater);
+        test("4294967295", 31, NearestTiesAway, "2", Greater);
+        test("4294967295", 31, Odd, "1", Less);
 
         test("4294967295", 32, Down, "0", Less);
         test("4294967295", 32, Up, "1", Greater);
         test("4294967295", 32, Floor, "0", Less);
         test("4294967295", 32, Ceiling, "1", Greater);
         test("4294967295", 32, Nearest, "1", Greater);
+        test("4294967295", 32, NearestTiesAway, "1", Greater);
+        test("4294967295", 32, Odd, "1", Greater);
 
         test("4294967296", 32, Down, "1", Equal);
         test("4294967296", 32, Up, "1", Equal);
         test("4294967296", 32, Floor, "1", Equal);
         test("4294967296", 32, Ceiling, "1", Equal);
         test("4294967296", 32, Nearest, "1", Equal);
+        test("4294967296", 32, NearestTiesAway, "1", Equal);
+        test("4294967296", 32, Odd, "1", Equal);
         test("4294967296", 32, Exact, "1", Equal);
 
         test("4294967296", 33, Down, "0", Less);
@@ -413,12 +471,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("4294967296", 33, Floor, "0", Less);
         test("4294967296", 33, Ceiling, "1", Greater);
         test("4294967296", 33, Nearest, "0", Less);
+        test("4294967296", 33, NearestTiesAway, "1", Greater);
+        test("4294967296", 33, Odd, "1", Greater);
 
         test("-123", 0, Down, "-123", Equal);
         test("-123", 0, Up, "-123", Equal);
         test("-123", 0, Floor, "-123", Equal);
         test("-123", 0, Ceiling, "-123", Equal);
         test("-123", 0, Nearest, "-123", Equal);
+        test("-123", 0, NearestTiesAway, "-123", Equal);
+        test("-123", 0, Odd, "-123", Equal);
         test("-123", 0, Exact, "-123", Equal);
 
         test("-245", 1, Down, "-122", Greater);
@@ -426,12 +488,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-245", 1, Floor, "-123", Less);
         test("-245", 1, Ceiling, "-122", Greater);
         test("-245", 1, Nearest, "-122", Greater);
+        test("-245", 1, NearestTiesAway, "-123", Less);
+        test("-245", 1, Odd, "-123", Less);
 
         test("-246", 1, Down, "-123", Equal);
         test("-246", 1, Up, "-123", Equal);
         test("-246", 1, Floor, "-123", Equal);
         test("-246", 1, Ceiling, "-123", Equal);
         test("-246", 1, Nearest, "-123", Equal);
+        test("-246", 1, NearestTiesAway, "-123", Equal);
+        test("-246", 1, Odd, "-123", Equal);
         test("-246", 1, Exact, "-123", Equal);
 
         test("-247", 1, Down, "-123", Greater);
@@ -439,18 +505,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-247", 1, Floor, "-124", Less);
         test("-247", 1, Ceiling, "-123", Greater);
         test("-247", 1, Nearest, "-124", Less);
+        test("-247", 1, NearestTiesAway, "-124", Less);
+        test("-247", 1, Odd, "-123", Greater);
 
         test("-491", 2, Down, "-122", Greater);
         test("-491", 2, Up, "-123", Less);
         test("-491", 2, Floor, "-123", Less);
         test("-491", 2, Ceiling, "-122", Greater);
         test("-491", 2, Nearest, "-123", Less);
+        test("-491", 2, NearestTiesAway, "-123", Less);
+        test("-491", 2, Odd, "-123", Less);
 
         test("-492", 2, Down, "-123", Equal);
         test("-492", 2, Up, "-123", Equal);
         test("-492", 2, Floor, "-123", Equal);
         test("-492", 2, Ceiling, "-123", Equal);
         test("-492", 2, Nearest, "-123", Equal);
+        test("-492", 2, NearestTiesAway, "-123", Equal);
+        test("-492", 2, Odd, "-123", Equal);
         test("-492", 2, Exact, "-123", Equal);
 
         test("-493", 2, Down, "-123", Greater);
@@ -458,18 +530,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-493", 2, Floor, "-124", Less);
         test("-493", 2, Ceiling, "-123", Greater);
         test("-493", 2, Nearest, "-123", Greater);
+        test("-493", 2, NearestTiesAway, "-123", Greater);
+        test("-493", 2, Odd, "-123", Greater);
 
         test("-4127195135", 25, Down, "-122", Greater);
         test("-4127195135", 25, Up, "-123", Less);
         test("-4127195135", 25, Floor, "-123", Less);
         test("-4127195135", 25, Ceiling, "-122", Greater);
         test("-4127195135", 25, Nearest, "-123", Less);
+        test("-4127195135", 25, NearestTiesAway, "-123", Less);
+        test("-4127195135", 25, Odd, "-123", Less);
 
         test("-4127195136", 25, Down, "-123", Equal);
         test("-4127195136", 25, Up, "-123", Equal);
         test("-4127195136", 25, Floor, "-123", Equal);
         test("-4127195136", 25, Ceiling, "-123", Equal);
         test("-4127195136", 25, Nearest, "-123", Equal);
+        test("-4127195136", 25, NearestTiesAway, "-123", Equal);
+        test("-4127195136", 25, Odd, "-123", Equal);
         test("-4127195136", 25, Exact, "-123", Equal);
 
         test("-4127195137", 25, Down, "-123", Greater);
@@ -477,18 +555,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-4127195137", 25, Floor, "-124", Less);
         test("-4127195137", 25, Ceiling, "-123", Greater);
         test("-4127195137", 25, Nearest, "-123", Greater);
+        test("-4127195137", 25, NearestTiesAway, "-123", Greater);
+        test("-4127195137", 25, Odd, "-123", Greater);
 
         test("-8254390271", 26, Down, "-122", Greater);
         test("-8254390271", 26, Up, "-123", Less);
         test("-8254390271", 26, Floor, "-123", Less);
         test("-8254390271", 26, Ceiling, "-122", Greater);
         test("-8254390271", 26, Nearest, "-123", Less);
+        test("-8254390271", 26, NearestTiesAway, "-123", Less);
+        test("-8254390271", 26, Odd, "-123", Less);
 
         test("-8254390272", 26, Down, "-123", Equal);
         test("-8254390272", 26, Up, "-123", Equal);
         test("-8254390272", 26, Floor, "-123", Equal);
         test("-8254390272", 26, Ceiling, "-123", Equal);
         test("-8254390272", 26, Nearest, "-123", Equal);
+        test("-8254390272", 26, NearestTiesAway, "-123", Equal);
+        test("-8254390272", 26, Odd, "-123", Equal);
         test("-8254390272", 26, Exact, "-123", Equal);
 
         test("-8254390273", 26, Down, "-123", Greater);
@@ -496,6 +580,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-8254390273", 26, Floor, "-124", Less);
         test("-8254390273", 26, Ceiling, "-123", Greater);
         test("-8254390273", 26, Nearest, "-123", Greater);
+        test("-8254390273", 26, NearestTiesAway, "-123", Greater);
+        test("-8254390273", 26, Odd, "-123", Greater);
 
         test(
             "-155921023828072216384094494261247",
@@ -599,12 +685,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-4294967295", 1, Floor, "-2147483648", Less);
         test("-4294967295", 1, Ceiling, "-2147483647", Greater);
         test("-4294967295", 1, Nearest, "-2147483648", Less);
+        test("-4294967295", 1, NearestTiesAway, "-2147483648", Less);
+        test("-4294967295", 1, Odd, "-2147483647", Greater);
 
         test("-4294967296", 1, Down, "-2147483648", Equal);
         test("-4294967296", 1, Up, "-2147483648", Equal);
         test("-4294967296", 1, Floor, "-2147483648", Equal);
         test("-4294967296", 1, Ceiling, "-2147483648", Equal);
         test("-4294967296", 1, Nearest, "-2147483648", Equal);
+        test("-4294967296", 1, NearestTiesAway, "-2147483648", Equal);
+        test("-4294967296", 1, Odd, "-2147483648", Equal);
         test("-4294967296", 1, Exact, "-2147483648", Equal);
 
         test("-4294967297", 1, Down, "-2147483648", Greater);
@@ -612,12 +702,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-4294967297", 1, Floor, "-2147483649", Less);
         test("-4294967297", 1, Ceiling, "-2147483648", Greater);
         test("-4294967297", 1, Nearest, "-2147483648", Greater);
+        test("-4294967297", 1, NearestTiesAway, "-2147483649", Less);
+        test("-4294967297", 1, Odd, "-2147483649", Less);
 
         test("-1000000000000", 0, Down, "-1000000000000", Equal);
         test("-1000000000000", 0, Up, "-1000000000000", Equal);
         test("-1000000000000", 0, Floor, "-1000000000000", Equal);
         test("-1000000000000", 0, Ceiling, "-1000000000000", Equal);
         test("-1000000000000", 0, Nearest, "-1000000000000", Equal);
+        test("-1000000000000", 0, NearestTiesAway, "-1000000000000", Equal);
+        test("-1000000000000", 0, Odd, "-1000000000000", Equal);
         test("-1000000000000", 0, Exact, "-1000000000000", Equal);
 
         test("-7999999999999", 3, Down, "-999999999999", Greater);
@@ -625,12 +719,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-7999999999999", 3, Floor, "-1000000000000", Less);
         test("-7999999999999", 3, Ceiling, "-999999999999", Greater);
         test("-7999999999999", 3, Nearest, "-1000000000000", Less);
+        test("-7999999999999", 3, NearestTiesAway, "-1000000000000", Less);
+        test("-7999999999999", 3, Odd, "-999999999999", Greater);
 
         test("-8000000000000", 3, Down, "-1000000000000", Equal);
         test("-8000000000000", 3, Up, "-1000000000000", Equal);
         test("-8000000000000", 3, Floor, "-1000000000000", Equal);
         test("-8000000000000", 3, Ceiling, "-1000000000000", Equal);
         test("-8000000000000", 3, Nearest, "-1000000000000", Equal);
+        test("-8000000000000", 3, NearestTiesAway, "-1000000000000", Equal);
+        test("-8000000000000", 3, Odd, "-1000000000000", Equal);
         test("-8000000000000", 3, Exact, "-1000000000000", Equal);
 
         test("-8000000000001", 3, Down, "-1000000000000", Greater);
@@ -638,6 +736,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-8000000000001", 3, Floor, "-1000000000001", Less);
         test("-8000000000001", 3, Ceiling, "-1000000000000", Greater);
         test("-8000000000001", 3, Nearest, "-1000000000000", Greater);
+        test("-8000000000001", 3, NearestTiesAway, "-1000000000000", Greater);
+        test("-8000000000001", 3, Odd, "-1000000000001", Less);
 
         test("-16777216000000000000", 24, Down, "-1000000000000", Equal);
         test("-16777216000000000000", 24, Up, "-1000000000000", Equal);
@@ -818,6 +918,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-1000000000000", 10, Floor, "-976562500", Equal);
         test("-1000000000000", 10, Ceiling, "-976562500", Equal);
         test("-1000000000000", 10, Nearest, "-976562500", Equal);
+        test("-1000000000000", 10, NearestTiesAway, "-976562500", Equal);
+        test("-1000000000000", 10, Odd, "-976562500", Equal);
         test("-1000000000000", 10, Exact, "-976562500", Equal);
 
         test("-980657949", 72, Down, "0", Greater);
@@ -825,24 +927,32 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-980657949", 72, Floor, "-1", Less);
         test("-980657949", 72, Ceiling, "0", Greater);
         test("-980657949", 72, Nearest, "0", Greater);
+        test("-980657949", 72, NearestTiesAway, "0", Greater);
+        test("-980657949", 72, Odd, "-1", Less);
 
         test("-4294967295", 31, Down, "-1", Greater);
         test("-4294967295", 31, Up, "-2", Less);
         test("-4294967295", 31, Floor, "-2", Less);
         test("-4294967295", 31, Ceiling, "-1", Greater);
         test("-4294967295", 31, Nearest, "-2", Less);
+        test("-4294967295", 31, NearestTiesAway, "-2", Less);
+        test("-4294967295", 31, Odd, "-1", Greater);
 
         test("-4294967295", 32, Down, "0", Greater);
         test("-4294967295", 32, Up, "-1", Less);
         test("-4294967295", 32, Floor, "-1", Less);
         test("-4294967295", 32, Ceiling, "0", Greater);
         test("-4294967295", 32, Nearest, "-1", Less);
+        test("-4294967295", 32, NearestTiesAway, "-1", Less);
+        test("-4294967295", 32, Odd, "-1", Less);
 
         test("-4294967296", 32, Down, "-1", Equal);
         test("-4294967296", 32, Up, "-1", Equal);
         test("-4294967296", 32, Floor, "-1", Equal);
         test("-4294967296", 32, Ceiling, "-1", Equal);
         test("-4294967296", 32, Nearest, "-1", Equal);
+        test("-4294967296", 32, NearestTiesAway, "-1", Equal);
+        test("-4294967296", 32, Odd, "-1", Equal);
         test("-4294967296", 32, Exact, "-1", Equal);
 
         test("-4294967296", 33, Down, "0", Greater);
@@ -850,6 +960,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-4294967296", 33, Floor, "-1", Less);
         test("-4294967296", 33, Ceiling, "0", Greater);
         test("-4294967296", 33, Nearest, "0", Greater);
+        test("-4294967296", 33, NearestTiesAway, "-1", Less);
+        test("-4294967296", 33, Odd, "-1", Less);
     };
 }
 
@@ -923,6 +1035,8 @@ macro_rules! test_shr_round_signed_helper {
         test("0", 0, Floor, "0", Equal);
         test("0", 0, Ceiling, "0", Equal);
         test("0", 0, Nearest, "0", Equal);
+        test("0", 0, NearestTiesAway, "0", Equal);
+        test("0", 0, Odd, "0", Equal);
         test("0", 0, Exact, "0", Equal);
 
         test("0", 10, Down, "0", Equal);
@@ -930,6 +1044,8 @@ macro_rules! test_shr_round_signed_helper {
         test("0", 10, Floor, "0", Equal);
         test("0", 10, Ceiling, "0", Equal);
         test("0", 10, Nearest, "0", Equal);
+        test("0", 10, NearestTiesAway, "0", Equal);
+        test("0", 10, Odd, "0", Equal);
         test("0", 10, Exact, "0", Equal);
 
         test("123", 0, Down, "123", Equal);
@@ -937,6 +1053,8 @@ macro_rules! test_shr_round_signed_helper {
         test("123", 0, Floor, "123", Equal);
         test("123", 0, Ceiling, "123", Equal);
         test("123", 0, Nearest, "123", Equal);
+        test("123", 0, NearestTiesAway, "123", Equal);
+        test("123", 0, Odd, "123", Equal);
         test("123", 0, Exact, "123", Equal);
 
         test("245", 1, Down, "122", Less);
@@ -944,12 +1062,16 @@ macro_rules! test_shr_round_signed_helper {
         test("245", 1, Floor, "122", Less);
         test("245", 1, Ceiling, "123", Greater);
         test("245", 1, Nearest, "122", Less);
+        test("245", 1, NearestTiesAway, "123", Greater);
+        test("245", 1, Odd, "123", Greater);
 
         test("246", 1, Down, "123", Equal);
         test("246", 1, Up, "123", Equal);
         test("246", 1, Floor, "123", Equal);
         test("246", 1, Ceiling, "123", Equal);
         test("246", 1, Nearest, "123", Equal);
+        test("246", 1, NearestTiesAway, "123", Equal);
+        test("246", 1, Odd, "123", Equal);
         test("246", 1, Exact, "123", Equal);
 
         test("247", 1, Down, "123", Less);
@@ -957,18 +1079,24 @@ macro_rules! test_shr_round_signed_helper {
         test("247", 1, Floor, "123", Less);
         test("247", 1, Ceiling, "124", Greater);
         test("247", 1, Nearest, "124", Greater);
+        test("247", 1, NearestTiesAway, "124", Greater);
+        test("247", 1, Odd, "123", Less);
 
         test("491", 2, Down, "122", Less);
         test("491", 2, Up, "123", Greater);
         test("491", 2, Floor, "122", Less);
         test("491", 2, Ceiling, "123", Greater);
         test("491", 2, Nearest, "123", Greater);
+        test("491", 2, NearestTiesAway, "123", Greater);
+        test("491", 2, Odd, "123", Greater);
 
         test("492", 2, Down, "123", Equal);
         test("492", 2, Up, "123", Equal);
         test("492", 2, Floor, "123", Equal);
         test("492", 2, Ceiling, "123", Equal);
         test("492", 2, Nearest, "123", Equal);
+        test("492", 2, NearestTiesAway, "123", Equal);
+        test("492", 2, Odd, "123", Equal);
         test("492", 2, Exact, "123", Equal);
 
         test("493", 2, Down, "123", Less);
@@ -976,18 +1104,24 @@ macro_rules! test_shr_round_signed_helper {
         test("493", 2, Floor, "123", Less);
         test("493", 2, Ceiling, "124", Greater);
         test("493", 2, Nearest, "123", Less);
+        test("493", 2, NearestTiesAway, "123", Less);
+        test("493", 2, Odd, "123", Less);
 
         test("4127195135", 25, Down, "122", Less);
         test("4127195135", 25, Up, "123", Greater);
         test("4127195135", 25, Floor, "122", Less);
         test("4127195135", 25, Ceiling, "123", Greater);
         test("4127195135", 25, Nearest, "123", Greater);
+        test("4127195135", 25, NearestTiesAway, "123", Greater);
+        test("4127195135", 25, Odd, "123", Greater);
 
         test("4127195136", 25, Down, "123", Equal);
         test("4127195136", 25, Up, "123", Equal);
         test("4127195136", 25, Floor, "123", Equal);
         test("4127195136", 25, Ceiling, "123", Equal);
         test("4127195136", 25, Nearest, "123", Equal);
+        test("4127195136", 25, NearestTiesAway, "123", Equal);
+        test("4127195136", 25, Odd, "123", Equal);
         test("4127195136", 25, Exact, "123", Equal);
 
         test("4127195137", 25, Down, "123", Less);
@@ -995,18 +1129,24 @@ macro_rules! test_shr_round_signed_helper {
         test("4127195137", 25, Floor, "123", Less);
         test("4127195137", 25, Ceiling, "124", Greater);
         test("4127195137", 25, Nearest, "123", Less);
+        test("4127195137", 25, NearestTiesAway, "123", Less);
+        test("4127195137", 25, Odd, "123", Less);
 
         test("8254390271", 26, Down, "122", Less);
         test("8254390271", 26, Up, "123", Greater);
         test("8254390271", 26, Floor, "122", Less);
         test("8254390271", 26, Ceiling, "123", Greater);
         test("8254390271", 26, Nearest, "123", Greater);
+        test("8254390271", 26, NearestTiesAway, "123", Greater);
+        test("8254390271", 26, Odd, "123", Greater);
 
         test("8254390272", 26, Down, "123", Equal);
         test("8254390272", 26, Up, "123", Equal);
         test("8254390272", 26, Floor, "123", Equal);
         test("8254390272", 26, Ceiling, "123", Equal);
         test("8254390272", 26, Nearest, "123", Equal);
+        test("8254390272", 26, NearestTiesAway, "123", Equal);
+        test("8254390272", 26, Odd, "123", Equal);
         test("8254390272", 26, Exact, "123", Equal);
 
         test("8254390273", 26, Down, "123", Less);
@@ -1014,6 +1154,8 @@ macro_rules! test_shr_round_signed_helper {
         test("8254390273", 26, Floor, "123", Less);
         test("8254390273", 26, Ceiling, "124", Greater);
         test("8254390273", 26, Nearest, "123", Less);
+        test("8254390273", 26, NearestTiesAway, "123", Less);
+        test("8254390273", 26, Odd, "123", Less);
 
         test("155921023828072216384094494261247", 100, Down, "122", Less);
         test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -1087,12 +1229,16 @@ macro_rules! test_shr_round_signed_helper {
         test("4294967295", 1, Floor, "2147483647", Less);
         test("4294967295", 1, Ceiling, "2147483648", Greater);
         test("4294967295", 1, Nearest, "2147483648", Greater);
+        test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+        test("4294967295", 1, Odd, "2147483647", Less);
 
         test("4294967296", 1, Down, "2147483648", Equal);
         test("4294967296", 1, Up, "2147483648", Equal);
         test("4294967296", 1, Floor, "2147483648", Equal);
         test("4294967296", 1, Ceiling, "2147483648", Equal);
         test("4294967296", 1, Nearest, "2147483648", Equal);
+        test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+        test("4294967296", 1, Odd, "2147483648", Equal);
         test("4294967296", 1, Exact, "2147483648", Equal);
 
         test("4294967297", 1, Down, "2147483648", Less);
@@ -1100,12 +1246,16 @@ macro_rules! test_shr_round_signed_helper {
         test("4294967297", 1, Floor, "2147483648", Less);
         test("4294967297", 1, Ceiling, "2147483649", Greater);
         test("4294967297", 1, Nearest, "2147483648", Less);
+        test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+        test("4294967297", 1, Odd, "2147483649", Greater);
 
         test("1000000000000", 0, Down, "1000000000000", Equal);
         test("1000000000000", 0, Up, "1000000000000", Equal);
         test("1000000000000", 0, Floor, "1000000000000", Equal);
         test("1000000000000", 0, Ceiling, "1000000000000", Equal);
         test("1000000000000", 0, Nearest, "1000000000000", Equal);
+        test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+        test("1000000000000", 0, Odd, "1000000000000", Equal);
         test("1000000000000", 0, Exact, "1000000000000", Equal);
 
         test("7999999999999", 3, Down, "999999999999", Less);
@@ -1113,12 +1263,16 @@ macro_rules! test_shr_round_signed_helper {
         test("7999999999999", 3, Floor, "999999999999", Less);
         test("7999999999999", 3, Ceiling, "1000000000000", Greater);
         test("7999999999999", 3, Nearest, "1000000000000", Greater);
+        test("7999999999999", 3, NearestTiesAway, "1000000000000", Greater);
+        test("7999999999999", 3, Odd, "999999999999", Less);
 
         test("8000000000000", 3, Down, "1000000000000", Equal);
         test("8000000000000", 3, Up, "1000000000000", Equal);
         test("8000000000000", 3, Floor, "1000000000000", Equal);
         test("8000000000000", 3, Ceiling, "1000000000000", Equal);
         test("8000000000000", 3, Nearest, "1000000000000", Equal);
+        test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+        test("8000000000000", 3, Odd, "1000000000000", Equal);
         test("8000000000000", 3, Exact, "1000000000000", Equal);
 
         test("8000000000001", 3, Down, "1000000000000", Less);
@@ -1126,12 +1280,16 @@ macro_rules! test_shr_round_signed_helper {
         test("8000000000001", 3, Floor, "1000000000000", Less);
         test("8000000000001", 3, Ceiling, "1000000000001", Greater);
         test("8000000000001", 3, Nearest, "1000000000000", Less);
+        test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+        test("8000000000001", 3, Odd, "1000000000001", Greater);
 
         test("16777216000000000000", 24, Down, "1000000000000", Equal);
         test("16777216000000000000", 24, Up, "1000000000000", Equal);
         test("16777216000000000000", 24, Floor, "1000000000000", Equal);
         test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
         test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+        test("16777216000000000000", 24, NearestTiesAway, "1000000000000", Equal);
+        test("16777216000000000000", 24, Odd, "1000000000000", Equal);
         test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
         test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -1139,6 +1297,8 @@ macro_rules! test_shr_round_signed_helper {
         test("33554432000000000000", 25, Floor, "1000000000000", Equal);
         test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
         test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+        test("33554432000000000000", 25, NearestTiesAway, "1000000000000", Equal);
+        test("33554432000000000000", 25, Odd, "1000000000000", Equal);
         test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
         test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -1246,6 +1406,8 @@ macro_rules! test_shr_round_signed_helper {
         test("1000000000000", 10, Floor, "976562500", Equal);
         test("1000000000000", 10, Ceiling, "976562500", Equal);
         test("1000000000000", 10, Nearest, "976562500", Equal);
+        test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+        test("1000000000000", 10, Odd, "976562500", Equal);
         test("1000000000000", 10, Exact, "976562500", Equal);
 
         test("980657949", 72, Down, "0", Less);
@@ -1253,24 +1415,32 @@ macro_rules! test_shr_round_signed_helper {
         test("980657949", 72, Floor, "0", Less);
         test("980657949", 72, Ceiling, "1", Greater);
         test("980657949", 72, Nearest, "0", Less);
+        test("980657949", 72, NearestTiesAway, "0", Less);
+        test("980657949", 72, Odd, "1", Greater);
 
         test("4294967295", 31, Down, "1", Less);
         test("4294967295", 31, Up, "2", Greater);
         test("4294967295", 31, Floor, "1", Less);
         test("4294967295", 31, Ceiling, "2", Greater);
         test("4294967295", 31, Nearest, "2", Greater);
+        test("4294967295", 31, NearestTiesAway, "2", Greater);
+        test("4294967295", 31, Odd, "1", Less);
 
         test("4294967295", 32, Down, "0", Less);
         test("4294967295", 32, Up, "1", Greater);
         test("4294967295", 32, Floor, "0", Less);
         test("4294967295", 32, Ceiling, "1", Greater);
         test("4294967295", 32, Nearest, "1", Greater);
+        test("4294967295", 32, NearestTiesAway, "1", Greater);
+        test("4294967295", 32, Odd, "1", Greater);
 
         test("4294967296", 32, Down, "1", Equal);
         test("4294967296", 32, Up, "1", Equal);
         test("4294967296", 32, Floor, "1", Equal);
         test("4294967296", 32, Ceiling, "1", Equal);
         test("4294967296", 32, Nearest, "1", Equal);
+        test("4294967296", 32, NearestTiesAway, "1", Equal);
+        test("4294967296", 32, Odd, "1", Equal);
         test("4294967296", 32, Exact, "1", Equal);
 
         test("4294967296", 33, Down, "0", Less);
@@ -1278,12 +1448,16 @@ macro_rules! test_shr_round_signed_helper {
         test("4294967296", 33, Floor, "0", Less);
         test("4294967296", 33, Ceiling, "1", Greater);
         test("4294967296", 33, Nearest, "0", Less);
+        test("4294967296", 33, NearestTiesAway, "1", Greater);
+        test("4294967296", 33, Odd, "1", Greater);
 
         test("-123", 0, Down, "-123", Equal);
         test("-123", 0, Up, "-123", Equal);
         test("-123", 0, Floor, "-123", Equal);
         test("-123", 0, Ceiling, "-123", Equal);
         test("-123", 0, Nearest, "-123", Equal);
+        test("-123", 0, NearestTiesAway, "-123", Equal);
+        test("-123", 0, Odd, "-123", Equal);
         test("-123", 0, Exact, "-123", Equal);
 
         test("-245", 1, Down, "-122", Greater);
@@ -1291,12 +1465,16 @@ macro_rules! test_shr_round_signed_helper {
         test("-245", 1, Floor, "-123", Less);
         test("-245", 1, Ceiling, "-122", Greater);
         test("-245", 1, Nearest, "-122", Greater);
+        test("-245", 1, NearestTiesAway, "-123", Less);
+        test("-245", 1, Odd, "-123", Less);
 
         test("-246", 1, Down, "-123", Equal);
         test("-246", 1, Up, "-123", Equal);
         test("-246", 1, Floor, "-123", Equal);
         test("-246", 1, Ceiling, "-123", Equal);
         test("-246", 1, Nearest, "-123", Equal);
+        test("-246", 1, NearestTiesAway, "-123", Equal);
+        test("-246", 1, Odd, "-123", Equal);
         test("-246", 1, Exact, "-123", Equal);
 
         test("-247", 1, Down, "-123", Greater);
@@ -1304,18 +1482,24 @@ macro_rules! test_shr_round_signed_helper {
         test("-247", 1, Floor, "-124", Less);
         test("-247", 1, Ceiling, "-123", Greater);
         test("-247", 1, Nearest, "-124", Less);
+        test("-247", 1, NearestTiesAway, "-124", Less);
+        test("-247", 1, Odd, "-123", Greater);
 
         test("-491", 2, Down, "-122", Greater);
         test("-491", 2, Up, "-123", Less);
         test("-491", 2, Floor, "-123", Less);
         test("-491", 2, Ceiling, "-122", Greater);
         test("-491", 2, Nearest, "-123", Less);
+        test("-491", 2, NearestTiesAway, "-123", Less);
+        test("-491", 2, Odd, "-123", Less);
 
         test("-492", 2, Down, "-123", Equal);
         test("-492", 2, Up, "-123", Equal);
         test("-492", 2, Floor, "-123", Equal);
         test("-492", 2, Ceiling, "-123", Equal);
         test("-492", 2, Nearest, "-123", Equal);
+        test("-492", 2, NearestTiesAway, "-123", Equal);
+        test("-492", 2, Odd, "-123", Equal);
         test("-492", 2, Exact, "-123", Equal);
 
         test("-493", 2, Down, "-123", Greater);
@@ -1323,18 +1507,24 @@ macro_rules! test_shr_round_signed_helper {
         test("-493", 2, Floor, "-124", Less);
         test("-493", 2, Ceiling, "-123", Greater);
         test("-493", 2, Nearest, "-123", Greater);
+        test("-493", 2, NearestTiesAway, "-123", Greater);
+        test("-493", 2, Odd, "-123", Greater);
 
         test("-4127195135", 25, Down, "-122", Greater);
         test("-4127195135", 25, Up, "-123", Less);
         test("-4127195135", 25, Floor, "-123", Less);
         test("-4127195135", 25, Ceiling, "-122", Greater);
         test("-4127195135", 25, Nearest, "-123", Less);
+        test("-4127195135", 25, NearestTiesAway, "-123", Less);
+        test("-4127195135", 25, Odd, "-123", Less);
 
         test("-4127195136", 25, Down, "-123", Equal);
         test("-4127195136", 25, Up, "-123", Equal);
         test("-4127195136", 25, Floor, "-123", Equal);
         test("-4127195136", 25, Ceiling, "-123", Equal);
         test("-4127195136", 25, Nearest, "-123", Equal);
+        test("-4127195136", 25, NearestTiesAway, "-123", Equal);
+        test("-4127195136", 25, Odd, "-123", Equal);
         test("-4127195136", 25, Exact, "-123", Equal);
 
         test("-4127195137", 25, Down, "-123", Greater);
@@ -1342,18 +1532,24 @@ macro_rules! test_shr_round_signed_helper {
         test("-4127195137", 25, Floor, "-124", Less);
         test("-4127195137", 25, Ceiling, "-123", Greater);
         test("-4127195137", 25, Nearest, "-123", Greater);
+        test("-4127195137", 25, NearestTiesAway, "-123", Greater);
+        test("-4127195137", 25, Odd, "-123", Greater);
 
         test("-8254390271", 26, Down, "-122", Greater);
         test("-8254390271", 26, Up, "-123", Less);
         test("-8254390271", 26, Floor, "-123", Less);
         test("-8254390271", 26, Ceiling, "-122", Greater);
         test("-8254390271", 26, Nearest, "-123", Less);
+        test("-8254390271", 26, NearestTiesAway, "-123", Less);
+        test("-8254390271", 26, Odd, "-123", Less);
 
         test("-8254390272", 26, Down, "-123", Equal);
         test("-8254390272", 26, Up, "-123", Equal);
         test("-8254390272", 26, Floor, "-123", Equal);
         test("-8254390272", 26, Ceiling, "-123", Equal);
         test("-8254390272", 26, Nearest, "-123", Equal);
+        test("-8254390272", 26, NearestTiesAway, "-123", Equal);
+        test("-8254390272", 26, Odd, "-123", Equal);
         test("-8254390272", 26, Exact, "-123", Equal);
 
         test("-8254390273", 26, Down, "-123", Greater);
@@ -1361,6 +1557,8 @@ macro_rules! test_shr_round_signed_helper {
         test("-8254390273", 26, Floor, "-124", Less);
         test("-8254390273", 26, Ceiling, "-123", Greater);
         test("-8254390273", 26, Nearest, "-123", Greater);
+        test("-8254390273", 26, NearestTiesAway, "-123", Greater);
+        test("-8254390273", 26, Odd, "-123", Greater);
 
         test(
             "-155921023828072216384094494261247",
@@ -1464,12 +1662,16 @@ macro_rules! test_shr_round_signed_helper {
         test("-4294967295", 1, Floor, "-2147483648", Less);
         test("-4294967295", 1, Ceiling, "-2147483647", Greater);
         test("-4294967295", 1, Nearest, "-2147483648", Less);
+        test("-4294967295", 1, NearestTiesAway, "-2147483648", Less);
+        test("-4294967295", 1, Odd, "-2147483647", Greater);
 
         test("-4294967296", 1, Down, "-2147483648", Equal);
         test("-4294967296", 1, Up, "-2147483648", Equal);
         test("-4294967296", 1, Floor, "-2147483648", Equal);
         test("-4294967296", 1, Ceiling, "-2147483648", Equal);
         test("-4294967296", 1, Nearest, "-2147483648", Equal);
+        test("-4294967296", 1, NearestTiesAway, "-2147483648", Equal);
+        test("-4294967296", 1, Odd, "-2147483648", Equal);
         test("-4294967296", 1, Exact, "-2147483648", Equal);
 
         test("-4294967297", 1, Down, "-2147483648", Greater);
@@ -1477,12 +1679,16 @@ macro_rules! test_shr_round_signed_helper {
         test("-4294967297", 1, Floor, "-2147483649", Less);
         test("-4294967297", 1, Ceiling, "-2147483648", Greater);
         test("-4294967297", 1, Nearest, "-2147483648", Greater);
+        test("-4294967297", 1, NearestTiesAway, "-2147483649", Less);
+        test("-4294967297", 1, Odd, "-2147483649", Less);
 
         test("-1000000000000", 0, Down, "-1000000000000", Equal);
         test("-1000000000000", 0, Up, "-1000000000000", Equal);
         test("-1000000000000", 0, Floor, "-1000000000000", Equal);
         test("-1000000000000", 0, Ceiling, "-1000000000000", Equal);
         test("-1000000000000", 0, Nearest, "-1000000000000", Equal);
+        test("-1000000000000", 0, NearestTiesAway, "-1000000000000", Equal);
+        test("-1000000000000", 0, Odd, "-1000000000000", Equal);
         test("-1000000000000", 0, Exact, "-1000000000000", Equal);
 
         test("-7999999999999", 3, Down, "-999999999999", Greater);
@@ -1490,12 +1696,16 @@ macro_rules! test_shr_round_signed_helper {
         test("-7999999999999", 3, Floor, "-1000000000000", Less);
         test("-7999999999999", 3, Ceiling, "-999999999999", Greater);
         test("-7999999999999", 3, Nearest, "-1000000000000", Less);
+        test("-7999999999999", 3, NearestTiesAway, "-1000000000000", Less);
+        test("-7999999999999", 3, Odd, "-999999999999", Greater);
 
         test("-8000000000000", 3, Down, "-1000000000000", Equal);
         test("-8000000000000", 3, Up, "-1000000000000", Equal);
         test("-8000000000000", 3, Floor, "-1000000000000", Equal);
         test("-8000000000000", 3, Ceiling, "-1000000000000", Equal);
         test("-8000000000000", 3, Nearest, "-1000000000000", Equal);
+        test("-8000000000000", 3, NearestTiesAway, "-1000000000000", Equal);
+        test("-8000000000000", 3, Odd, "-1000000000000", Equal);
         test("-8000000000000", 3, Exact, "-1000000000000", Equal);
 
         test("-8000000000001", 3, Down, "-1000000000000", Greater);
@@ -1503,6 +1713,8 @@ macro_rules! test_shr_round_signed_helper {
         test("-8000000000001", 3, Floor, "-1000000000001", Less);
         test("-8000000000001", 3, Ceiling, "-1000000000000", Greater);
         test("-8000000000001", 3, Nearest, "-1000000000000", Greater);
+        test("-8000000000001", 3, NearestTiesAway, "-1000000000000", Greater);
+        test("-8000000000001", 3, Odd, "-1000000000001", Less);
 
         test("-16777216000000000000", 24, Down, "-1000000000000", Equal);
         test("-16777216000000000000", 24, Up, "-1000000000000", Equal);
@@ -1683,6 +1895,8 @@ macro_rules! test_shr_round_signed_helper {
         test("-1000000000000", 10, Floor, "-976562500", Equal);
         test("-1000000000000", 10, Ceiling, "-976562500", Equal);
         test("-1000000000000", 10, Nearest, "-976562500", Equal);
+        test("-1000000000000", 10, NearestTiesAway, "-976562500", Equal);
+        test("-1000000000000", 10, Odd, "-976562500", Equal);
         test("-1000000000000", 10, Exact, "-976562500", Equal);
 
         test("-980657949", 72, Down, "0", Greater);
@@ -1690,24 +1904,32 @@ macro_rules! test_shr_round_signed_helper {
         test("-980657949", 72, Floor, "-1", Less);
         test("-980657949", 72, Ceiling, "0", Greater);
         test("-980657949", 72, Nearest, "0", Greater);
+        test("-980657949", 72, NearestTiesAway, "0", Greater);
+        test("-980657949", 72, Odd, "-1", Less);
 
         test("-4294967295", 31, Down, "-1", Greater);
         test("-4294967295", 31, Up, "-2", Less);
         test("-4294967295", 31, Floor, "-2", Less);
         test("-4294967295", 31, Ceiling, "-1", Greater);
         test("-4294967295", 31, Nearest, "-2", Less);
+        test("-4294967295", 31, NearestTiesAway, "-2", Less);
+        test("-4294967295", 31, Odd, "-1", Greater);
 
         test("-4294967295", 32, Down, "0", Greater);
         test("-4294967295", 32, Up, "-1", Less);
         test("-4294967295", 32, Floor, "-1", Less);
         test("-4294967295", 32, Ceiling, "0", Greater);
         test("-4294967295", 32, Nearest, "-1", Less);
+        test("-4294967295", 32, NearestTiesAway, "-1", Less);
+        test("-4294967295", 32, Odd, "-1", Less);
 
         test("-4294967296", 32, Down, "-1", Equal);
         test("-4294967296", 32, Up, "-1", Equal);
         test("-4294967296", 32, Floor, "-1", Equal);
         test("-4294967296", 32, Ceiling, "-1", Equal);
         test("-4294967296", 32, Nearest, "-1", Equal);
+        test("-4294967296", 32, NearestTiesAway, "-1", Equal);
+        test("-4294967296", 32, Odd, "-1", Equal);
         test("-4294967296", 32, Exact, "-1", Equal);
 
         test("-4294967296", 33, Down, "0", Greater);
@@ -1715,6 +1937,8 @@ macro_rules! test_shr_round_signed_helper {
         test("-4294967296", 33, Floor, "-1", Less);
         test("-4294967296", 33, Ceiling, "0", Greater);
         test("-4294967296", 33, Nearest, "0", Greater);
+        test("-4294967296", 33, NearestTiesAway, "-1", Less);
+        test("-4294967296", 33, Odd, "-1", Less);
 
         test("0", -10, Exact, "0", Equal);
         test("123", -1, Exact, "246", Equal);
@@ -0,0 +1,49 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{StirlingNumber1, StirlingNumber2, UnsignedAbs};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_28;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+#[test]
+fn test_stirling_number_1() {
+    let test = |n, k, out: &str| {
+        assert_eq!(Integer::stirling_number_1(n, k).to_string(), out);
+    };
+    test(0, 0, "1");
+    test(3, 5, "0");
+    test(4, 2, "11");
+    test(5, 2, "-50");
+    test(6, 3, "-225");
+    test(10, 3, "-1172700");
+    test(30, 10, "215760462268683520394805979744");
+    test(30, 11, "-39539238727270799376544542000");
+}
+
+#[test]
+fn stirling_number_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties_with_limit(TINY_LIMIT, |(n, k)| {
+        let s = Integer::stirling_number_1(n, k);
+        let c = Natural::stirling_number_1(n, k);
+        assert_eq!((&s).unsigned_abs(), c);
+        if c != 0u32 {
+            assert_eq!(s > 0u32, (n - k) & 1 == 0);
+        }
+        assert_eq!(
+            Integer::stirling_number_2(n, k),
+            Integer::from(Natural::stirling_number_2(n, k))
+        );
+    });
+    // sum_k s(n, k) x^k = x(x - 1)...(x - n + 1), which vanishes at x = 1 when n >= 2
+    for n in 0..40 {
+        let total: Integer = (0..=n).map(|k| Integer::stirling_number_1(n, k)).sum();
+        assert_eq!(total, if n < 2 { 1 } else { 0 });
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::factorization::traits::{IsPrime, ProvePrime};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |s, out| {
+        let n = Integer::from_str(s).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.prove_prime(), Some(out));
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("4", false);
+    test("97", true);
+    test("18446744073709551629", true);
+    test("340282366920938463463374607431768211457", false);
+    test("-1", false);
+    test("-2", false);
+    test("-97", false);
+    test("-18446744073709551629", false);
+}
+
+#[test]
+fn is_prime_properties() {
+    integer_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        if n <= 0u32 {
+            assert!(!is_prime);
+            assert_eq!(n.prove_prime(), Some(false));
+        } else {
+            // GMP tests the absolute value, so only compare positive values
+            assert_eq!(
+                is_prime,
+                rug::Integer::from(&n).is_probably_prime(30) != rug::integer::IsPrime::No
+            );
+            assert_eq!(n.prove_prime(), n.unsigned_abs().prove_prime());
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        assert_eq!(Integer::from(&n).is_prime(), n.is_prime());
+        assert!(!(-Integer::from(n)).is_prime());
+    });
+}
@@ -65,6 +65,10 @@ extern crate malachite_nz;
 extern crate num;
 extern crate rug;
 
+pub mod ct {
+    pub mod ct_natural;
+    pub mod modulus;
+}
 pub mod integer {
     pub mod arithmetic {
         pub mod abs;
@@ -85,8 +89,10 @@ pub mod integer {
         pub mod divisible_by_power_of_2;
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
+        pub mod euler_number;
         pub mod extended_gcd;
         pub mod kronecker_symbol;
+        pub mod lucas_sequence;
         pub mod mod_euclidean;
         pub mod mod_op;
         pub mod mod_power_of_2;
@@ -109,6 +115,7 @@ pub mod integer {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling_number;
         pub mod sub;
         pub mod sub_mul;
     }
@@ -177,6 +184,7 @@ pub mod integer {
     }
     pub mod factorization {
         pub mod is_power;
+        pub mod is_prime;
         pub mod remove_power;
     }
     pub mod logic {
@@ -234,6 +242,23 @@ pub mod integer {
         pub mod uniform_random_integer_range;
     }
 }
+pub mod mod_natural {
+    pub mod arithmetic {
+        pub mod add;
+        pub mod div;
+        pub mod mul;
+        pub mod neg;
+        pub mod pow;
+        pub mod sub;
+    }
+    pub mod basic {
+        pub mod named;
+        pub mod new;
+    }
+    pub mod conversion {
+        pub mod to_string;
+    }
+}
 pub mod natural {
     pub mod arithmetic {
         pub mod abs_diff;
@@ -242,6 +267,7 @@ pub mod natural {
         pub mod average;
         pub mod bell_number;
         pub mod binomial_coefficient;
+        pub mod catalan_number;
         pub mod checked_sub;
         pub mod checked_sub_mul;
         pub mod coprime_with;
@@ -261,6 +287,7 @@ pub mod natural {
         pub mod extended_gcd_partial;
         pub mod factorial;
         pub mod fibonacci;
+        pub mod fixed_base_mod_pow;
         #[cfg(feature = "float_helpers")]
         pub mod float {
             pub mod exp;
@@ -275,12 +302,15 @@ pub mod natural {
         pub mod log_base_2;
         pub mod log_base_power_of_2;
         pub mod mod_add;
+        pub mod mod_context;
         pub mod mod_div;
         pub mod mod_div_list;
         pub mod mod_euclidean;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_log;
         pub mod mod_mul;
+        pub mod mod_multiplicative_order;
         pub mod mod_neg;
         pub mod mod_op;
         pub mod mod_pow;
@@ -307,9 +337,12 @@ pub mod natural {
         pub mod mul_shr_round;
         pub mod mul_sub_mul;
         pub mod multi_crt;
+        pub mod multi_mod_pow;
+        pub mod multinomial_coefficient;
         pub mod neg;
         pub mod next_power_of_2;
         pub mod parity;
+        pub mod partition_number;
         pub mod pow;
         pub mod power_of_2;
         pub mod primorial;
@@ -327,6 +360,7 @@ pub mod natural {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling_number;
         pub mod sub;
         pub mod sub_mul;
     }
@@ -389,9 +423,17 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod divisor_sigma;
+        pub mod divisors;
+        pub mod factor;
         pub mod is_power;
+        pub mod is_prime;
         pub mod is_square;
+        pub mod moebius_mu;
+        pub mod next_prime;
         pub mod primes;
+        pub mod primitive_root;
+        pub mod totient;
     }
     pub mod logic {
         pub mod and;
@@ -0,0 +1,58 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModNeg, NegAssign};
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_11;
+use std::str::FromStr;
+
+#[test]
+fn test_neg() {
+    let test = |x, m, out| {
+        let x = ModNatural::new(Natural::from_str(x).unwrap(), Natural::from_str(m).unwrap());
+
+        let n = -x.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = -&x;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = x;
+        n.neg_assign();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "1", "0 mod 1");
+    test("0", "5", "0 mod 5");
+    test("3", "7", "4 mod 7");
+    test(
+        "123456789012345678901234567890",
+        "170141183460469231731687303715884105727",
+        "170141183337012442719341624814649537837 mod 170141183460469231731687303715884105727",
+    );
+}
+
+#[test]
+fn neg_properties() {
+    natural_pair_gen_var_11().test_properties(|(x, m)| {
+        let a = ModNatural::new(x.clone(), m.clone());
+        let negative = -&a;
+        assert!(negative.is_valid());
+        assert_eq!(*negative.value_ref(), (&x).mod_neg(&m));
+        assert_eq!(-a.clone(), negative);
+        let mut mut_a = a.clone();
+        mut_a.neg_assign();
+        assert_eq!(mut_a, negative);
+
+        assert_eq!(-&negative, a);
+        assert_eq!(*(&a + &negative).value_ref(), 0u32);
+    });
+}
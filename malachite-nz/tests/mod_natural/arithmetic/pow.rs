@@ -0,0 +1,79 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModPow, Pow, PowAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_triple_gen_var_5;
+use std::str::FromStr;
+
+#[test]
+fn test_pow() {
+    let test = |x, exp, m, out| {
+        let x = ModNatural::new(Natural::from_str(x).unwrap(), Natural::from_str(m).unwrap());
+        let exp = Natural::from_str(exp).unwrap();
+
+        let n = x.clone().pow(&exp);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&x).pow(&exp);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = x.clone();
+        n.pow_assign(&exp);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        if let Ok(exp) = u64::try_from(&exp) {
+            let n = x.clone().pow(exp);
+            assert_eq!(n.to_string(), out);
+            assert!(n.is_valid());
+
+            let n = (&x).pow(exp);
+            assert_eq!(n.to_string(), out);
+            assert!(n.is_valid());
+
+            let mut n = x;
+            n.pow_assign(exp);
+            assert_eq!(n.to_string(), out);
+            assert!(n.is_valid());
+        }
+    };
+    test("0", "0", "1", "0 mod 1");
+    test("0", "0", "5", "1 mod 5");
+    test("0", "3", "5", "0 mod 5");
+    test("3", "100", "7", "4 mod 7");
+    test(
+        "123456789012345678901234567890",
+        "98765432109876543210987654321",
+        "170141183460469231731687303715884105727",
+        "18380315435097344224164758837907565596 mod 170141183460469231731687303715884105727",
+    );
+}
+
+#[test]
+fn pow_properties() {
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        let a = ModNatural::new(x.clone(), m.clone());
+        let power = (&a).pow(&exp);
+        assert!(power.is_valid());
+        assert_eq!(*power.value_ref(), (&x).mod_pow(&exp, &m));
+        assert_eq!(a.clone().pow(&exp), power);
+        let mut mut_a = a.clone();
+        mut_a.pow_assign(&exp);
+        assert_eq!(mut_a, power);
+        if let Ok(small_exp) = u64::try_from(&exp) {
+            assert_eq!((&a).pow(small_exp), power);
+        }
+
+        assert_eq!((&a).pow(&(&exp + Natural::ONE)), &power * &a);
+    });
+}
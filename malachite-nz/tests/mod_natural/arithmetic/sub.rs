@@ -0,0 +1,108 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::ModSub;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_triple_gen_var_3;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_sub() {
+    let test = |x, y, m, out| {
+        let x = ModNatural::new(Natural::from_str(x).unwrap(), Natural::from_str(m).unwrap());
+        let y = x.with_value(Natural::from_str(y).unwrap());
+
+        let mut n = x.clone();
+        n -= y.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = x.clone();
+        n -= &y;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = x.clone() - y.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &x - y.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = x.clone() - &y;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &x - &y;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "1", "0 mod 1");
+    test("0", "3", "5", "2 mod 5");
+    test("7", "5", "10", "2 mod 10");
+    test("5", "7", "10", "8 mod 10");
+    test(
+        "123456789012345678901234567890",
+        "98765432109876543210987654321",
+        "170141183460469231731687303715884105727",
+        "24691356902469135690246913569 mod 170141183460469231731687303715884105727",
+    );
+    test(
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105727",
+        "0 mod 170141183460469231731687303715884105727",
+    );
+}
+
+#[test]
+fn sub_fail() {
+    let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    let y = ModNatural::new(Natural::from(3u32), Natural::from(8u32));
+    assert_panic!(x.clone() - y.clone());
+    assert_panic!(&x - &y);
+    assert_panic!({
+        let mut x = x.clone();
+        x -= &y;
+    });
+}
+
+#[test]
+fn sub_properties() {
+    natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        let a = ModNatural::new(x.clone(), m.clone());
+        let b = a.with_value(y.clone());
+        let difference = &a - &b;
+        assert!(difference.is_valid());
+        assert_eq!(*difference.value_ref(), (&x).mod_sub(&y, &m));
+        assert_eq!(*difference.modulus_ref(), m);
+        assert_eq!(a.clone() - b.clone(), difference);
+        assert_eq!(&a - b.clone(), difference);
+        assert_eq!(a.clone() - &b, difference);
+        assert_eq!(-(&b - &a), difference);
+
+        // Values with equal but separately allocated moduli can also be combined.
+        let c = ModNatural::new(y, m);
+        assert_eq!(&a - &c, difference);
+
+        let mut mut_a = a.clone();
+        mut_a -= b.clone();
+        assert_eq!(mut_a, difference);
+        let mut mut_a = a.clone();
+        mut_a -= &b;
+        assert_eq!(mut_a, difference);
+
+        assert_eq!(&difference + &b, a);
+        assert_eq!(&a - &a, a.with_value(Natural::ZERO));
+    });
+}
@@ -0,0 +1,15 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::named::Named;
+use malachite_nz::mod_natural::ModNatural;
+
+#[test]
+fn test_named() {
+    assert_eq!(ModNatural::NAME, "ModNatural");
+}
@@ -0,0 +1,89 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::Gcd;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_5, natural_pair_gen_var_11};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_new() {
+    let test = |x, m, out| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let n = ModNatural::new(x.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(*n.value_ref(), out);
+        assert_eq!(*n.modulus_ref(), m);
+        let n = n.with_value(x);
+        assert!(n.is_valid());
+        assert_eq!(n.into_value(), out);
+    };
+    test("0", "1", 0u32);
+    test("5", "1", 0u32);
+    test("3", "7", 3u32);
+    test("10", "7", 3u32);
+    test(
+        "170141183460469231731687303715884105728",
+        "170141183460469231731687303715884105727",
+        1u32,
+    );
+}
+
+#[test]
+fn new_fail() {
+    assert_panic!(ModNatural::new(Natural::ZERO, Natural::ZERO));
+}
+
+#[test]
+fn test_inverse() {
+    let test = |x, m, out: Option<&str>| {
+        let x = ModNatural::new(Natural::from_str(x).unwrap(), Natural::from_str(m).unwrap());
+        let inverse = x.inverse();
+        assert_eq!(inverse.as_ref().map(ToString::to_string).as_deref(), out);
+        if let Some(inverse) = inverse {
+            assert!(inverse.is_valid());
+        }
+    };
+    test("0", "1", Some("0 mod 1"));
+    test("0", "5", None);
+    test("3", "10", Some("7 mod 10"));
+    test("4", "10", None);
+    test(
+        "123456789012345678901234567890",
+        "170141183460469231731687303715884105727",
+        Some("48464825753085841100438376607502766223 mod 170141183460469231731687303715884105727"),
+    );
+}
+
+#[test]
+fn new_properties() {
+    natural_pair_gen_var_5().test_properties(|(x, m)| {
+        let n = ModNatural::new(x.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(*n.value_ref(), &x % &m);
+        assert_eq!(*n.modulus_ref(), m);
+        assert_eq!(n.with_value(x.clone()), n);
+        assert_eq!(ModNatural::new(&x + &m, m.clone()), n);
+    });
+
+    natural_pair_gen_var_11().test_properties(|(x, m)| {
+        let n = ModNatural::new(x.clone(), m.clone());
+        let inverse = n.inverse();
+        assert_eq!(inverse.is_some(), (&x).gcd(&m) == 1u32);
+        if let Some(inverse) = inverse {
+            assert!(inverse.is_valid());
+            assert_eq!(*(&n * &inverse).value_ref(), Natural::from(1u32) % &m);
+            assert_eq!(inverse.inverse().unwrap(), n);
+        }
+    });
+}
@@ -0,0 +1,46 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::ToDebugString;
+use malachite_nz::mod_natural::ModNatural;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_11;
+use std::str::FromStr;
+
+#[test]
+fn test_to_string() {
+    let test = |x, m, out| {
+        let x = ModNatural::new(Natural::from_str(x).unwrap(), Natural::from_str(m).unwrap());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test("0", "1", "0 mod 1");
+    test("3", "7", "3 mod 7");
+    test(
+        "1000000000000",
+        "1000000000001",
+        "1000000000000 mod 1000000000001",
+    );
+    assert_eq!(
+        format!(
+            "{:05}",
+            ModNatural::new(Natural::from(3u32), Natural::from(7u32))
+        ),
+        "00003 mod 7"
+    );
+}
+
+#[test]
+fn to_string_properties() {
+    natural_pair_gen_var_11().test_properties(|(x, m)| {
+        let n = ModNatural::new(x.clone(), m.clone());
+        let s = n.to_string();
+        assert_eq!(s, format!("{x} mod {m}"));
+        assert_eq!(n.to_debug_string(), s);
+    });
+}
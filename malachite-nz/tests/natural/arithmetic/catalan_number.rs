@@ -0,0 +1,59 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, CatalanNumber};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::catalan_number::catalan_numbers_prefix;
+
+#[test]
+fn test_catalan_number() {
+    let test = |n, out: &str| {
+        assert_eq!(Natural::catalan_number(n).to_string(), out);
+    };
+    test(0, "1");
+    test(1, "1");
+    test(4, "14");
+    test(10, "16796");
+    test(69, "337485502510215975556783793455058624700");
+    test(
+        100,
+        "896519947090131496687170070074100632420837521538745909320",
+    );
+}
+
+#[test]
+fn catalan_number_properties() {
+    unsigned_gen_var_5::<u64>().test_properties_with_limit(TINY_LIMIT, |n| {
+        let c = Natural::catalan_number(n);
+        assert_eq!(
+            &c * Natural::from(n + 1),
+            Natural::binomial_coefficient(Natural::from(n << 1), Natural::from(n))
+        );
+        // C_n = C(2n, n) - C(2n, n + 1)
+        assert_eq!(
+            c,
+            Natural::binomial_coefficient(Natural::from(n << 1), Natural::from(n))
+                - Natural::binomial_coefficient(Natural::from(n << 1), Natural::from(n + 1))
+        );
+    });
+}
+
+#[test]
+fn test_catalan_numbers_prefix() {
+    assert!(catalan_numbers_prefix(0).is_empty());
+    assert_eq!(catalan_numbers_prefix(1), [Natural::from(1u32)]);
+    let prefix = catalan_numbers_prefix(200);
+    // C_{n+1} = sum_{i=0}^{n} C_i C_{n-i}
+    for n in 0..199 {
+        assert_eq!(prefix[n], Natural::catalan_number(n as u64), "C_{n}");
+        let sum: Natural = (0..=n).map(|i| &prefix[i] * &prefix[n - i]).sum();
+        assert_eq!(prefix[n + 1], sum, "C_{}", n + 1);
+    }
+}
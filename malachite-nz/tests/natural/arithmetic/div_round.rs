@@ -109,6 +109,8 @@ fn test_div_round() {
     test("0", "1", Up, "0", Equal);
     test("0", "1", Ceiling, "0", Equal);
     test("0", "1", Nearest, "0", Equal);
+    test("0", "1", NearestTiesAway, "0", Equal);
+    test("0", "1", Odd, "0", Equal);
     test("0", "1", Exact, "0", Equal);
 
     test("0", "123", Down, "0", Equal);
@@ -116,6 +118,8 @@ fn test_div_round() {
     test("0", "123", Up, "0", Equal);
     test("0", "123", Ceiling, "0", Equal);
     test("0", "123", Nearest, "0", Equal);
+    test("0", "123", NearestTiesAway, "0", Equal);
+    test("0", "123", Odd, "0", Equal);
     test("0", "123", Exact, "0", Equal);
 
     test("1", "1", Down, "1", Equal);
@@ -123,6 +127,8 @@ fn test_div_round() {
     test("1", "1", Up, "1", Equal);
     test("1", "1", Ceiling, "1", Equal);
     test("1", "1", Nearest, "1", Equal);
+    test("1", "1", NearestTiesAway, "1", Equal);
+    test("1", "1", Odd, "1", Equal);
     test("1", "1", Exact, "1", Equal);
 
     test("123", "1", Down, "123", Equal);
@@ -130,6 +136,8 @@ fn test_div_round() {
     test("123", "1", Up, "123", Equal);
     test("123", "1", Ceiling, "123", Equal);
     test("123", "1", Nearest, "123", Equal);
+    test("123", "1", NearestTiesAway, "123", Equal);
+    test("123", "1", Odd, "123", Equal);
     test("123", "1", Exact, "123", Equal);
 
     test("123", "2", Down, "61", Less);
@@ -137,18 +145,24 @@ fn test_div_round() {
     test("123", "2", Up, "62", Greater);
     test("123", "2", Ceiling, "62", Greater);
     test("123", "2", Nearest, "62", Greater);
+    test("123", "2", NearestTiesAway, "62", Greater);
+    test("123", "2", Odd, "61", Less);
 
     test("125", "2", Down, "62", Less);
     test("125", "2", Floor, "62", Less);
     test("125", "2", Up, "63", Greater);
     test("125", "2", Ceiling, "63", Greater);
     test("125", "2", Nearest, "62", Less);
+    test("125", "2", NearestTiesAway, "63", Greater);
+    test("125", "2", Odd, "63", Greater);
 
     test("123", "123", Down, "1", Equal);
     test("123", "123", Floor, "1", Equal);
     test("123", "123", Up, "1", Equal);
     test("123", "123", Ceiling, "1", Equal);
     test("123", "123", Nearest, "1", Equal);
+    test("123", "123", NearestTiesAway, "1", Equal);
+    test("123", "123", Odd, "1", Equal);
     test("123", "123", Exact, "1", Equal);
 
     test("123", "456", Down, "0", Less);
@@ -156,12 +170,16 @@ fn test_div_round() {
     test("123", "456", Up, "1", Greater);
     test("123", "456", Ceiling, "1", Greater);
     test("123", "456", Nearest, "0", Less);
+    test("123", "456", NearestTiesAway, "0", Less);
+    test("123", "456", Odd, "1", Greater);
 
     test("1000000000000", "1", Down, "1000000000000", Equal);
     test("1000000000000", "1", Floor, "1000000000000", Equal);
     test("1000000000000", "1", Up, "1000000000000", Equal);
     test("1000000000000", "1", Ceiling, "1000000000000", Equal);
     test("1000000000000", "1", Nearest, "1000000000000", Equal);
+    test("1000000000000", "1", NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", "1", Odd, "1000000000000", Equal);
     test("1000000000000", "1", Exact, "1000000000000", Equal);
 
     test("1000000000000", "3", Down, "333333333333", Less);
@@ -169,18 +187,24 @@ fn test_div_round() {
     test("1000000000000", "3", Up, "333333333334", Greater);
     test("1000000000000", "3", Ceiling, "333333333334", Greater);
     test("1000000000000", "3", Nearest, "333333333333", Less);
+    test("1000000000000", "3", NearestTiesAway, "333333333333", Less);
+    test("1000000000000", "3", Odd, "333333333333", Less);
 
     test("999999999999", "2", Down, "499999999999", Less);
     test("999999999999", "2", Floor, "499999999999", Less);
     test("999999999999", "2", Up, "500000000000", Greater);
     test("999999999999", "2", Ceiling, "500000000000", Greater);
     test("999999999999", "2", Nearest, "500000000000", Greater);
+    test("999999999999", "2", NearestTiesAway, "500000000000", Greater);
+    test("999999999999", "2", Odd, "499999999999", Less);
 
     test("1000000000001", "2", Down, "500000000000", Less);
     test("1000000000001", "2", Floor, "500000000000", Less);
     test("1000000000001", "2", Up, "500000000001", Greater);
     test("1000000000001", "2", Ceiling, "500000000001", Greater);
     test("1000000000001", "2", Nearest, "500000000000", Less);
+    test("1000000000001", "2", NearestTiesAway, "500000000001", Greater);
+    test("1000000000001", "2", Odd, "500000000001", Greater);
 
     test(
         "1000000000000000000000000",
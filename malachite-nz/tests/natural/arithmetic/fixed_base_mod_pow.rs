@@ -0,0 +1,116 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::{LowMask, SignificantBits};
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPow;
+use malachite_nz::test_util::generators::{natural_quadruple_gen_var_3, natural_triple_gen_var_5};
+use std::cmp::max;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_fixed_base_mod_pow() {
+    let test = |x, exp, m, max_exp_bits, out| {
+        let x = Natural::from_str(x).unwrap();
+        let exp = Natural::from_str(exp).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let g = FixedBaseModPow::new(&x, m.clone(), max_exp_bits);
+        assert_eq!(*g.modulus(), m);
+        assert!(g.max_exp_bits() >= max_exp_bits);
+        let power = g.pow(&exp);
+        assert!(power.is_valid());
+        assert_eq!(power.to_string(), out);
+        assert_eq!(power, x.mod_pow(exp, m));
+    };
+    test("0", "0", "1", 0, "0");
+    test("0", "0", "10", 0, "1");
+    test("0", "5", "10", 8, "0");
+    test("4", "13", "497", 4, "445");
+    test("4", "13", "497", 64, "445");
+    // - exponent longer than the table
+    test("4", "13", "497", 1, "445");
+    test("7", "1000", "30", 10, "1");
+    test("10", "1000", "30", 1000, "10");
+    // - m = 2 ^ 127 - 1
+    test(
+        "123456789012345678901234567890",
+        "98765432109876543210987654321",
+        "170141183460469231731687303715884105727",
+        128,
+        "18380315435097344224164758837907565596",
+    );
+    // - m = 2 ^ 100
+    test(
+        "2",
+        "987654321098765432109876543",
+        "1267650600228229401496703205376",
+        100,
+        "0",
+    );
+    // - m = 3 ^ 80
+    test(
+        "123456789012345678901234567",
+        "987654321098765432109876543",
+        "147808829414345923316083210206383297601",
+        1000,
+        "108263967232635457517871377256520956079",
+    );
+}
+
+#[test]
+fn fixed_base_mod_pow_fail() {
+    assert_panic!(FixedBaseModPow::new(&Natural::ZERO, Natural::ZERO, 10));
+    assert_panic!(FixedBaseModPow::new(
+        &Natural::from(7u32),
+        Natural::from(7u32),
+        10
+    ));
+}
+
+#[test]
+fn fixed_base_mod_pow_properties() {
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        let expected = (&x).mod_pow(&exp, &m);
+        let bits = exp.significant_bits();
+        for max_exp_bits in [bits, bits + 1, bits >> 1, 2 * bits + 10] {
+            let g = FixedBaseModPow::new(&x, m.clone(), max_exp_bits);
+            assert!(g.max_exp_bits() >= max_exp_bits);
+            let power = g.pow(&exp);
+            assert!(power.is_valid());
+            assert!(power < m);
+            assert_eq!(power, expected);
+        }
+    });
+
+    natural_quadruple_gen_var_3().test_properties(|(x, e, f, m)| {
+        let g = FixedBaseModPow::new(&x, m.clone(), max(e.significant_bits(), 1));
+        assert_eq!(g.pow(&Natural::ZERO), Natural::ONE % &m);
+        assert_eq!(g.pow(&Natural::ONE), x);
+        assert_eq!(g.pow(&(&e + &f)), g.pow(&e).mod_mul(g.pow(&f), &m));
+    });
+
+    // 3 ^ 5000 has 7925 bits, enough for the reduction to use `limbs_redc` rather than
+    // `limbs_redc_limb`.
+    let m = Natural::from(3u32).pow(5000);
+    let x = Natural::from(12345u32);
+    let g = FixedBaseModPow::new(&x, m.clone(), 200);
+    for exp in [
+        Natural::ZERO,
+        Natural::ONE,
+        Natural::low_mask(200),
+        Natural::from(3u32).pow(100),
+        // - exponent longer than the table
+        Natural::ONE << 200u32,
+    ] {
+        assert_eq!(g.pow(&exp), (&x).mod_pow(&exp, &m));
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModMul, ModNeg, ModPow, ModSquare, ModSub, Parity, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::mod_context::ModContext;
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_gen_var_2, natural_triple_gen_var_3, natural_triple_gen_var_5,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_context() {
+    let test = |x, y, m, montgomery, product, power, inverse: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let y = Natural::from_str(y).unwrap();
+        let ctx = ModContext::new(Natural::from_str(m).unwrap());
+        assert!(ctx.modulus().is_valid());
+        assert_eq!(ctx.is_montgomery(), montgomery);
+        let x_r = ctx.to_residue(&x);
+        let y_r = ctx.to_residue(&y);
+        assert!(x_r < *ctx.modulus());
+        assert_eq!(ctx.from_residue(&x_r), x);
+        assert_eq!(ctx.from_residue(&ctx.mul(&x_r, &y_r)).to_string(), product);
+        assert_eq!(ctx.from_residue(&ctx.pow(&x_r, &y)).to_string(), power);
+        if x != 0u32 {
+            assert_eq!(
+                ctx.inverse(&x_r)
+                    .map(|z| ctx.from_residue(&z).to_string())
+                    .as_deref(),
+                inverse
+            );
+        }
+    };
+    test("0", "0", "1", false, "0", "0", None);
+    test("3", "5", "7", true, "1", "5", Some("5"));
+    test("7", "11", "30", false, "17", "13", Some("13"));
+    test("4", "13", "497", true, "52", "445", Some("373"));
+    // - m = 2 ^ 127 - 1
+    test(
+        "123456789012345678901234567890",
+        "98765432109876543210987654321",
+        "170141183460469231731687303715884105727",
+        true,
+        "82544020355360328516762341607148724078",
+        "18380315435097344224164758837907565596",
+        Some("48464825753085841100438376607502766223"),
+    );
+    // - m = 2 ^ 100
+    test(
+        "123456789012345678901234567",
+        "987654321098765432109876543",
+        "1267650600228229401496703205376",
+        false,
+        "685130049050040639553141995833",
+        "1141369327394881529710959807543",
+        Some("764158624489567473195518298679"),
+    );
+    test(
+        "2",
+        "987654321098765432109876543",
+        "1267650600228229401496703205376",
+        false,
+        "1975308642197530864219753086",
+        "0",
+        None,
+    );
+    // - m = 3 ^ 80
+    test(
+        "123456789012345678901234567",
+        "987654321098765432109876543",
+        "147808829414345923316083210206383297601",
+        true,
+        "23981319749122748130929269129170553338",
+        "108263967232635457517871377256520956079",
+        Some("6671373458431714984068250392523930465"),
+    );
+    test(
+        "3",
+        "5",
+        "147808829414345923316083210206383297601",
+        true,
+        "15",
+        "243",
+        None,
+    );
+}
+
+#[test]
+fn mod_context_fail() {
+    assert_panic!(ModContext::new(Natural::ZERO));
+    let ctx = ModContext::new(Natural::from(7u32));
+    assert_panic!(ctx.from_residue(&Natural::from(7u32)));
+    assert_panic!(ctx.add(&Natural::from(7u32), &Natural::ONE));
+    assert_panic!(ctx.sub(&Natural::ONE, &Natural::from(7u32)));
+    assert_panic!(ctx.neg(&Natural::from(7u32)));
+    assert_panic!(ctx.mul(&Natural::from(7u32), &Natural::ONE));
+    assert_panic!(ctx.square(&Natural::from(7u32)));
+    assert_panic!(ctx.pow(&Natural::from(7u32), &Natural::ONE));
+    assert_panic!(ctx.inverse(&Natural::from(7u32)));
+    assert_panic!(ctx.inverse(&Natural::ZERO));
+}
+
+fn mod_context_properties_helper(ctx: &ModContext, x: &Natural, y: &Natural) {
+    let m = ctx.modulus();
+    let x_r = ctx.to_residue(x);
+    let y_r = ctx.to_residue(y);
+    assert!(x_r.is_valid());
+    assert!(x_r < *m);
+    assert_eq!(ctx.from_residue(&x_r), *x);
+    assert_eq!(x_r == 0u32, *x == 0u32);
+    assert_eq!(ctx.to_residue(&(x + m)), x_r);
+
+    let sum = ctx.add(&x_r, &y_r);
+    assert!(sum.is_valid());
+    assert_eq!(ctx.from_residue(&sum), x.mod_add(y, m));
+    let difference = ctx.sub(&x_r, &y_r);
+    assert!(difference.is_valid());
+    assert_eq!(ctx.from_residue(&difference), x.mod_sub(y, m));
+    let negative = ctx.neg(&x_r);
+    assert!(negative.is_valid());
+    assert_eq!(ctx.from_residue(&negative), x.mod_neg(m));
+
+    let product = ctx.mul(&x_r, &y_r);
+    assert!(product.is_valid());
+    assert!(product < *m);
+    assert_eq!(ctx.from_residue(&product), x.mod_mul(y, m));
+    assert_eq!(ctx.mul(&y_r, &x_r), product);
+    assert_eq!(ctx.mul(&x_r, &ctx.one()), x_r);
+    let square = ctx.square(&x_r);
+    assert!(square.is_valid());
+    assert_eq!(ctx.mul(&x_r, &x_r), square);
+    assert_eq!(ctx.from_residue(&square), x.mod_square(m));
+
+    if *x != 0u32 {
+        let inverse = ctx.inverse(&x_r);
+        assert_eq!(
+            inverse.as_ref().map(|z| ctx.from_residue(z)),
+            x.mod_inverse(m)
+        );
+        if let Some(z) = inverse {
+            assert!(z.is_valid());
+            assert_eq!(ctx.mul(&x_r, &z), ctx.one());
+        }
+    }
+}
+
+#[test]
+fn mod_context_properties() {
+    natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        let ctx = ModContext::new(m.clone());
+        assert_eq!(ctx.modulus(), &m);
+        mod_context_properties_helper(&ctx, &x, &y);
+        assert_eq!(ModContext::new(m.clone()), ctx);
+    });
+
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        let ctx = ModContext::new(m.clone());
+        let x_r = ctx.to_residue(&x);
+        let power = ctx.pow(&x_r, &exp);
+        assert!(power.is_valid());
+        assert!(power < m);
+        assert_eq!(ctx.from_residue(&power), (&x).mod_pow(&exp, &m));
+        assert_eq!(ctx.pow(&x_r, &(&exp + Natural::ONE)), ctx.mul(&power, &x_r));
+    });
+
+    natural_gen_var_2().test_properties(|m| {
+        let ctx = ModContext::new(m.clone());
+        assert_eq!(ctx.is_montgomery(), m.odd() && m != 1u32);
+        assert_eq!(ctx.from_residue(&ctx.one()), Natural::ONE % &m);
+        assert_eq!(ctx.to_residue(&Natural::ZERO), 0u32);
+        assert_eq!(ctx.pow(&ctx.one(), &m), ctx.one());
+    });
+
+    // 3 ^ 5000 has 7925 bits, enough for the reduction to use `limbs_redc` rather than
+    // `limbs_redc_limb`.
+    let ctx = ModContext::new(Natural::from(3u32).pow(5000));
+    natural_gen().test_properties_with_limit(TINY_LIMIT, |x| {
+        let y = ctx.modulus() - Natural::ONE - (&x % ctx.modulus());
+        mod_context_properties_helper(&ctx, &(x % ctx.modulus()), &y);
+    });
+}
@@ -0,0 +1,259 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{
+    ModLog, ModLogWithOrderFactorization, ModPow, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_log() {
+    let test = |x, b, m, out: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        assert_eq!((&x).mod_log(&b, &m), out);
+        assert_eq!((&x).mod_log(&b, m.clone()), out);
+        assert_eq!((&x).mod_log(b.clone(), &m), out);
+        assert_eq!((&x).mod_log(b.clone(), m.clone()), out);
+        assert_eq!(x.clone().mod_log(&b, &m), out);
+        assert_eq!(x.clone().mod_log(&b, m.clone()), out);
+        assert_eq!(x.clone().mod_log(b.clone(), &m), out);
+        assert_eq!(x.mod_log(b, m), out);
+    };
+    // - small moduli use the primitive implementation
+    test("0", "0", "1", Some("0"));
+    test("13", "3", "17", Some("4"));
+    test("0", "6", "8", Some("3"));
+    test("3", "2", "8", None);
+    test("12345", "5", "65537", Some("51713"));
+    // - m = 2 ^ 100: b shares a factor with m, and a solution is found while removing it
+    test("0", "2", "1267650600228229401496703205376", Some("100"));
+    test(
+        "137438953472",
+        "2",
+        "1267650600228229401496703205376",
+        Some("37"),
+    );
+    // - m = 2 ^ 100: the multiplicative group is not cyclic
+    test(
+        "667325118745031122717068899875",
+        "3",
+        "1267650600228229401496703205376",
+        Some("12345"),
+    );
+    test("5", "3", "1267650600228229401496703205376", None);
+    // - m = 3 * 2 ^ 189 + 1 is prime, with a smooth group order
+    test(
+        "1297267186670505376121743759242194038809553206350152490454",
+        "3",
+        "2353913150770005286438421033702874906038383291674012942337",
+        Some("123456789012345678901234567890"),
+    );
+    test(
+        "1000000000000000000000",
+        "3",
+        "2353913150770005286438421033702874906038383291674012942337",
+        None,
+    );
+    // - m = (2 ^ 61 - 1) * (2 ^ 89 - 1): the orders modulo the two primes are combined
+    test(
+        "445080654140813196519853571661635686659941295",
+        "7",
+        "1427247692705959880439315947500961989719490561",
+        Some("98765432109876543210987654321"),
+    );
+    test(
+        "2",
+        "7",
+        "1427247692705959880439315947500961989719490561",
+        None,
+    );
+    // - m = 2 ^ 64 * (2 ^ 61 - 1): b shares a factor with m, and the solution is larger
+    test(
+        "6957501683007094587600346782617305088",
+        "6",
+        "42535295865117307914475081855261474816",
+        Some("1000000000000000"),
+    );
+    test(
+        "13367494538843734067838845976576",
+        "6",
+        "42535295865117307914475081855261474816",
+        Some("40"),
+    );
+    test("5", "6", "42535295865117307914475081855261474816", None);
+}
+
+#[test]
+fn mod_log_fail() {
+    assert_panic!(Natural::from(3u32).mod_log(Natural::ONE, Natural::from(3u32)));
+    assert_panic!(Natural::ONE.mod_log(Natural::from(3u32), Natural::from(3u32)));
+    assert_panic!(Natural::ZERO.mod_log(Natural::ZERO, Natural::ZERO));
+    assert_panic!(Natural::power_of_2(100).mod_log(Natural::TWO, Natural::power_of_2(100)));
+}
+
+#[test]
+fn test_mod_log_with_order_factorization() {
+    let test = |x, b, m, order_factors: &[(&str, u64)], out: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let order_factors: Vec<(Natural, u64)> = order_factors
+            .iter()
+            .map(|&(q, e)| (Natural::from_str(q).unwrap(), e))
+            .collect();
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        assert_eq!(
+            (&x).mod_log_with_order_factorization(&b, &m, &order_factors),
+            out
+        );
+        assert_eq!(
+            (&x).mod_log_with_order_factorization(&b, m.clone(), &order_factors),
+            out
+        );
+        assert_eq!(
+            (&x).mod_log_with_order_factorization(b.clone(), &m, &order_factors),
+            out
+        );
+        assert_eq!(
+            (&x).mod_log_with_order_factorization(b.clone(), m.clone(), &order_factors),
+            out
+        );
+        assert_eq!(
+            x.clone()
+                .mod_log_with_order_factorization(&b, &m, &order_factors),
+            out
+        );
+        assert_eq!(
+            x.clone()
+                .mod_log_with_order_factorization(&b, m.clone(), &order_factors),
+            out
+        );
+        assert_eq!(
+            x.clone()
+                .mod_log_with_order_factorization(b.clone(), &m, &order_factors),
+            out
+        );
+        assert_eq!(
+            x.mod_log_with_order_factorization(b, m, &order_factors),
+            out
+        );
+    };
+    test("0", "0", "1", &[], Some("0"));
+    test("1", "1", "5", &[], Some("0"));
+    test("3", "1", "5", &[], None);
+    test("13", "3", "17", &[("2", 4)], Some("4"));
+    // the order of 9 modulo 17 is 8, and 5 is not a power of 9
+    test("5", "9", "17", &[("2", 4)], None);
+    test("12345", "5", "65537", &[("2", 16)], Some("51713"));
+    // - m = 3 * 2 ^ 189 + 1
+    test(
+        "1297267186670505376121743759242194038809553206350152490454",
+        "3",
+        "2353913150770005286438421033702874906038383291674012942337",
+        &[("2", 189), ("3", 1)],
+        Some("123456789012345678901234567890"),
+    );
+    test(
+        "1000000000000000000000",
+        "3",
+        "2353913150770005286438421033702874906038383291674012942337",
+        &[("2", 189), ("3", 1)],
+        None,
+    );
+    // - m = 2 ^ 127 - 1, whose group order has a prime factor above 2 ^ 32, found by Pollard rho
+    test(
+        "28795000797543931029456248689815799974",
+        "5",
+        "170141183460469231731687303715884105727",
+        &[
+            ("2", 1),
+            ("3", 3),
+            ("7", 2),
+            ("19", 1),
+            ("43", 1),
+            ("73", 1),
+            ("127", 1),
+            ("337", 1),
+            ("5419", 1),
+            ("92737", 1),
+            ("649657", 1),
+            ("77158673929", 1),
+        ],
+        Some("123456789012345678901234567890123"),
+    );
+}
+
+#[test]
+fn mod_log_with_order_factorization_fail() {
+    assert_panic!(Natural::from(3u32).mod_log_with_order_factorization(
+        Natural::ONE,
+        Natural::from(3u32),
+        &[]
+    ));
+    assert_panic!(Natural::ONE.mod_log_with_order_factorization(
+        Natural::from(3u32),
+        Natural::from(3u32),
+        &[]
+    ));
+    // 3 ^ 2 != 1 mod 5
+    assert_panic!(Natural::ONE.mod_log_with_order_factorization(
+        Natural::from(3u32),
+        Natural::from(5u32),
+        &[(Natural::TWO, 1)]
+    ));
+    // not coprime, so no power of the base is 1
+    assert_panic!(Natural::ONE.mod_log_with_order_factorization(
+        Natural::TWO,
+        Natural::power_of_2(100),
+        &[(Natural::TWO, 100)]
+    ));
+}
+
+#[test]
+fn mod_log_properties() {
+    // For prime moduli whose group orders are smooth, the logarithm of a power of the base is found
+    // and is no larger than the exponent.
+    let primes = [
+        (Natural::from(65537u32), vec![(Natural::TWO, 16)]),
+        (
+            Natural::from(3u32) * Natural::power_of_2(66) + Natural::ONE,
+            vec![(Natural::TWO, 66), (Natural::from(3u32), 1)],
+        ),
+    ];
+    for (p, order_factors) in primes {
+        natural_gen().test_properties_with_limit(TINY_LIMIT, |e| {
+            for b in [Natural::TWO, Natural::from(3u32), Natural::from(10u32)] {
+                let x = (&b).mod_pow(&e, &p);
+                let k = (&x).mod_log(&b, &p).unwrap();
+                assert!(k <= e);
+                assert_eq!((&b).mod_pow(&k, &p), x);
+                assert_eq!(
+                    (&x).mod_log_with_order_factorization(&b, &p, &order_factors),
+                    Some(k)
+                );
+            }
+        });
+    }
+
+    unsigned_triple_gen_var_12::<u32>().test_properties(|(x, b, m)| {
+        assert_eq!(
+            Natural::from(x).mod_log(Natural::from(b), Natural::from(m)),
+            x.mod_log(b, m).map(Natural::from)
+        );
+    });
+}
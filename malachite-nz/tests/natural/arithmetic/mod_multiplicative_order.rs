@@ -0,0 +1,130 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{
+    DivisibleBy, Gcd, ModMultiplicativeOrder, ModPow, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::PrimitiveRoot;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_multiplicative_order() {
+    let test = |x, m, out: Option<&str>| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        assert_eq!((&x).mod_multiplicative_order(&m), out);
+        assert_eq!((&x).mod_multiplicative_order(m.clone()), out);
+        assert_eq!(x.clone().mod_multiplicative_order(&m), out);
+        assert_eq!(x.mod_multiplicative_order(m), out);
+    };
+    // - small moduli use the primitive implementation
+    test("0", "1", Some("1"));
+    test("1", "2", Some("1"));
+    test("6", "9", None);
+    test("3", "7", Some("6"));
+    test("3", "65537", Some("65536"));
+    // - m = 2 ^ 127 - 1 is prime
+    test("2", "170141183460469231731687303715884105727", Some("127"));
+    test(
+        "3",
+        "170141183460469231731687303715884105727",
+        Some("56713727820156410577229101238628035242"),
+    );
+    test(
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105727",
+        Some("2"),
+    );
+    // - m = 2 ^ 100: the multiplicative group is not cyclic
+    test("2", "1267650600228229401496703205376", None);
+    test(
+        "3",
+        "1267650600228229401496703205376",
+        Some("316912650057057350374175801344"),
+    );
+    test(
+        "7",
+        "1267650600228229401496703205376",
+        Some("158456325028528675187087900672"),
+    );
+    // - m = 3 * 2 ^ 66 + 1 is prime
+    test("2", "221360928884514619393", Some("110680464442257309696"));
+    test("3", "221360928884514619393", Some("18446744073709551616"));
+    // - m = (2 ^ 61 - 1) * (2 ^ 89 - 1): the orders modulo the two primes are combined
+    test(
+        "2",
+        "1427247692705959880439315947500961989719490561",
+        Some("5429"),
+    );
+    test(
+        "3",
+        "1427247692705959880439315947500961989719490561",
+        Some("15858307696732887553559399172804764922847050"),
+    );
+    // - m = (2 ^ 61 - 1) ^ 2
+    test(
+        "10",
+        "5316911983139663487003542222693990401",
+        Some("2658455991569831742348849606740148225"),
+    );
+}
+
+#[test]
+fn mod_multiplicative_order_fail() {
+    assert_panic!(Natural::ZERO.mod_multiplicative_order(Natural::ZERO));
+    assert_panic!(Natural::from(3u32).mod_multiplicative_order(Natural::from(3u32)));
+    assert_panic!(Natural::power_of_2(100).mod_multiplicative_order(Natural::power_of_2(100)));
+}
+
+#[test]
+fn mod_multiplicative_order_properties() {
+    // For moduli with known factorizations and smooth group orders, the order of a random element
+    // is found and divides the group order.
+    let moduli = [
+        (Natural::from(65537u32), Natural::from(65536u32)),
+        (
+            Natural::from(3u32) * Natural::power_of_2(66) + Natural::ONE,
+            Natural::from(3u32) * Natural::power_of_2(66),
+        ),
+        (Natural::power_of_2(100), Natural::power_of_2(99)),
+    ];
+    for (m, phi) in moduli {
+        let g = m.primitive_root();
+        natural_gen().test_properties_with_limit(TINY_LIMIT, |x| {
+            let x = x % &m;
+            let order = (&x).mod_multiplicative_order(&m);
+            assert_eq!(order.is_some(), (&x).gcd(&m) == 1u32);
+            if let Some(k) = order {
+                assert_ne!(k, 0u32);
+                assert!((&phi).divisible_by(&k));
+                assert_eq!((&x).mod_pow(&k, &m), 1u32);
+                // x ^ 2 has order k / gcd(k, 2)
+                let square_order = (&x).mod_pow(Natural::TWO, &m).mod_multiplicative_order(&m);
+                assert_eq!(square_order, Some(&k / (&k).gcd(Natural::TWO)));
+            }
+        });
+        if let Some(g) = g {
+            assert_eq!(g.mod_multiplicative_order(&m), Some(phi));
+        }
+    }
+
+    unsigned_pair_gen_var_16::<u32>().test_properties(|(x, m)| {
+        assert_eq!(
+            Natural::from(x).mod_multiplicative_order(Natural::from(m)),
+            x.mod_multiplicative_order(m).map(Natural::from)
+        );
+    });
+}
@@ -0,0 +1,150 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_pair_gen_var_5, natural_quadruple_gen_var_2, natural_triple_gen_var_5,
+    natural_vec_natural_pair_gen_var_2,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn multi_mod_pow_naive(pairs: &[(Natural, Natural)], m: &Natural) -> Natural {
+    pairs.iter().fold(Natural::ONE % m, |acc, (x, exp)| {
+        acc.mod_mul(x.mod_pow(exp, m), m)
+    })
+}
+
+#[test]
+fn test_multi_mod_pow() {
+    let test = |pairs: &[(&str, &str)], m, out| {
+        let pairs: Vec<(Natural, Natural)> = pairs
+            .iter()
+            .map(|(x, exp)| {
+                (
+                    Natural::from_str(x).unwrap(),
+                    Natural::from_str(exp).unwrap(),
+                )
+            })
+            .collect();
+        let m = Natural::from_str(m).unwrap();
+        let product = Natural::multi_mod_pow(&pairs, &m);
+        assert!(product.is_valid());
+        assert_eq!(product.to_string(), out);
+        assert_eq!(product, multi_mod_pow_naive(&pairs, &m));
+    };
+    test(&[], "1", "0");
+    test(&[], "10", "1");
+    test(&[("0", "0")], "10", "1");
+    test(&[("0", "5")], "10", "0");
+    test(&[("4", "13"), ("3", "5")], "497", "286");
+    test(&[("4", "13"), ("3", "0")], "497", "445");
+    test(&[("7", "1000"), ("7", "0")], "30", "1");
+    // - m = 2 ^ 127 - 1
+    test(
+        &[
+            (
+                "123456789012345678901234567890",
+                "98765432109876543210987654321",
+            ),
+            (
+                "98765432109876543210987654321",
+                "123456789012345678901234567890",
+            ),
+        ],
+        "170141183460469231731687303715884105727",
+        "40974912050503293578373238710301702045",
+    );
+
+    // - many pairs, so that Pippenger's method is used
+    let pairs: Vec<(Natural, Natural)> = (1u32..=40)
+        .map(|i| (Natural::from(i), Natural::from(1000 + i)))
+        .collect();
+    let m = Natural::from_str("170141183460469231731687303715884105727").unwrap();
+    assert_eq!(
+        Natural::multi_mod_pow(&pairs, &m).to_string(),
+        "151197732153894420424819176003327782740"
+    );
+    // - m = 2 ^ 100
+    let pairs: Vec<(Natural, Natural)> = (0u32..40)
+        .map(|i| (Natural::from((i << 1) + 1), Natural::from(1000 + i)))
+        .collect();
+    let m = Natural::from_str("1267650600228229401496703205376").unwrap();
+    assert_eq!(
+        Natural::multi_mod_pow(&pairs, &m).to_string(),
+        "484627124652005951738916826393"
+    );
+}
+
+#[test]
+fn multi_mod_pow_fail() {
+    assert_panic!(Natural::multi_mod_pow(&[], &Natural::ZERO));
+    assert_panic!(Natural::multi_mod_pow(
+        &[(Natural::from(7u32), Natural::ONE)],
+        &Natural::from(7u32)
+    ));
+}
+
+#[test]
+fn multi_mod_pow_properties() {
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        let power = (&x).mod_pow(&exp, &m);
+        let pairs = [(x, exp)];
+        let product = Natural::multi_mod_pow(&pairs, &m);
+        assert!(product.is_valid());
+        assert_eq!(product, power);
+        let pairs = [pairs[0].clone(), (Natural::ONE % &m, pairs[0].1.clone())];
+        assert_eq!(Natural::multi_mod_pow(&pairs, &m), power);
+    });
+
+    natural_quadruple_gen_var_2().test_properties(|(x, y, exp, m)| {
+        let product =
+            Natural::multi_mod_pow(&[(x.clone(), exp.clone()), (y.clone(), exp.clone())], &m);
+        assert!(product.is_valid());
+        assert!(product < m);
+        assert_eq!(product, x.mod_mul(y, &m).mod_pow(exp, &m));
+    });
+
+    natural_vec_natural_pair_gen_var_2().test_properties(|(xs, m)| {
+        let pairs: Vec<(Natural, Natural)> =
+            xs.iter().cloned().zip(xs.iter().rev().cloned()).collect();
+        let product = Natural::multi_mod_pow(&pairs, &m);
+        assert!(product.is_valid());
+        assert_eq!(product, multi_mod_pow_naive(&pairs, &m));
+    });
+
+    natural_vec_natural_pair_gen_var_2().test_properties_with_limit(TINY_LIMIT, |(xs, m)| {
+        if xs.is_empty() {
+            return;
+        }
+        // Repeat the pairs until there are enough of them for Pippenger's method to be used.
+        let pairs: Vec<(Natural, Natural)> = xs
+            .iter()
+            .cloned()
+            .zip(xs.iter().rev().cloned())
+            .cycle()
+            .take(40)
+            .collect();
+        assert_eq!(
+            Natural::multi_mod_pow(&pairs, &m),
+            multi_mod_pow_naive(&pairs, &m)
+        );
+    });
+
+    natural_pair_gen_var_5().test_properties(|(x, m)| {
+        assert_eq!(Natural::multi_mod_pow(&[], &m), Natural::ONE % &m);
+        assert_eq!(
+            Natural::multi_mod_pow(&[(x % &m, Natural::ZERO)], &m),
+            Natural::ONE % &m
+        );
+    });
+}
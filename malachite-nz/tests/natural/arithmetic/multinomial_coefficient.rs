@@ -0,0 +1,56 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, Factorial, MultinomialCoefficient,
+};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_vec_gen_var_6;
+use malachite_nz::natural::Natural;
+
+#[test]
+fn test_multinomial_coefficient() {
+    let test = |ks: &[u64], out: &str| {
+        assert_eq!(Natural::multinomial_coefficient(ks).to_string(), out);
+    };
+    test(&[], "1");
+    test(&[7], "1");
+    test(&[0, 0, 3], "1");
+    test(&[2, 2], "6");
+    test(&[5, 3, 2], "2520");
+    test(&[4, 4, 4], "34650");
+    test(&[10, 10, 10], "5550996791340");
+    test(&[1; 20], "2432902008176640000");
+    test(&[30, 30, 30], "79607789567531236214574346454361782651136");
+}
+
+#[test]
+fn multinomial_coefficient_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 4);
+    config.insert("mean_stripe_n", 4);
+    unsigned_vec_gen_var_6::<u8>().test_properties_with_config(&config, |ks| {
+        let ks: Vec<u64> = ks.into_iter().map(|k| u64::from(k) & 0x1f).collect();
+        let m = Natural::multinomial_coefficient(&ks);
+        // the factorial formula
+        let mut q = Natural::factorial(ks.iter().sum());
+        for &k in &ks {
+            q /= Natural::factorial(k);
+        }
+        assert_eq!(m, q);
+        // order doesn't matter
+        let mut reversed = ks.clone();
+        reversed.reverse();
+        assert_eq!(Natural::multinomial_coefficient(&reversed), m);
+        // two parts give a binomial coefficient
+        assert_eq!(
+            Natural::multinomial_coefficient(&ks[..2]),
+            Natural::binomial_coefficient(Natural::from(ks[0] + ks[1]), Natural::from(ks[0]))
+        );
+    });
+}
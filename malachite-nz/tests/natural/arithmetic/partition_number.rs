@@ -0,0 +1,41 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::PartitionNumber;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::partition_number::partition_numbers_prefix;
+use malachite_nz::test_util::natural::arithmetic::partition_number::partition_numbers_prefix_naive;
+
+#[test]
+fn test_partition_number() {
+    let test = |n, out: &str| {
+        assert_eq!(Natural::partition_number(n).to_string(), out);
+    };
+    // (expected values computed independently with python, checked against OEIS A000041)
+    // - the u128 fast path, including its edge (1458)
+    test(0, "1");
+    test(1, "1");
+    test(5, "7");
+    test(100, "190569292");
+    test(1458, "336988065393447621514574974879775699372");
+    // - the recurrence over Naturals
+    test(1459, "348259884310914705271679879631949049780");
+    test(1500, "1329461690763193888825263136701886891117");
+    test(2000, "4720819175619413888601432406799959512200344166");
+}
+
+#[test]
+fn test_partition_numbers_prefix() {
+    assert!(partition_numbers_prefix(0).is_empty());
+    assert_eq!(partition_numbers_prefix(1), [Natural::from(1u32)]);
+    let prefix = partition_numbers_prefix(1600);
+    assert_eq!(prefix, partition_numbers_prefix_naive(1600));
+    for n in [0, 1, 2, 100, 1458, 1459, 1599] {
+        assert_eq!(prefix[n], Natural::partition_number(n as u64), "p({n})");
+    }
+}
@@ -194,6 +194,8 @@ where
     test("0", 0, Floor, "0", Equal);
     test("0", 0, Ceiling, "0", Equal);
     test("0", 0, Nearest, "0", Equal);
+    test("0", 0, NearestTiesAway, "0", Equal);
+    test("0", 0, Odd, "0", Equal);
     test("0", 0, Exact, "0", Equal);
 
     test("0", 10, Down, "0", Equal);
@@ -201,6 +203,8 @@ where
     test("0", 10, Floor, "0", Equal);
     test("0", 10, Ceiling, "0", Equal);
     test("0", 10, Nearest, "0", Equal);
+    test("0", 10, NearestTiesAway, "0", Equal);
+    test("0", 10, Odd, "0", Equal);
     test("0", 10, Exact, "0", Equal);
 
     test("123", 0, Down, "123", Equal);
@@ -208,6 +212,8 @@ where
     test("123", 0, Floor, "123", Equal);
     test("123", 0, Ceiling, "123", Equal);
     test("123", 0, Nearest, "123", Equal);
+    test("123", 0, NearestTiesAway, "123", Equal);
+    test("123", 0, Odd, "123", Equal);
     test("123", 0, Exact, "123", Equal);
 
     test("245", 1, Down, "122", Less);
@@ -215,12 +221,16 @@ where
     test("245", 1, Floor, "122", Less);
     test("245", 1, Ceiling, "123", Greater);
     test("245", 1, Nearest, "122", Less);
+    test("245", 1, NearestTiesAway, "123", Greater);
+    test("245", 1, Odd, "123", Greater);
 
     test("246", 1, Down, "123", Equal);
     test("246", 1, Up, "123", Equal);
     test("246", 1, Floor, "123", Equal);
     test("246", 1, Ceiling, "123", Equal);
     test("246", 1, Nearest, "123", Equal);
+    test("246", 1, NearestTiesAway, "123", Equal);
+    test("246", 1, Odd, "123", Equal);
     test("246", 1, Exact, "123", Equal);
 
     test("247", 1, Down, "123", Less);
@@ -228,18 +238,24 @@ where
     test("247", 1, Floor, "123", Less);
     test("247", 1, Ceiling, "124", Greater);
     test("247", 1, Nearest, "124", Greater);
+    test("247", 1, NearestTiesAway, "124", Greater);
+    test("247", 1, Odd, "123", Less);
 
     test("491", 2, Down, "122", Less);
     test("491", 2, Up, "123", Greater);
     test("491", 2, Floor, "122", Less);
     test("491", 2, Ceiling, "123", Greater);
     test("491", 2, Nearest, "123", Greater);
+    test("491", 2, NearestTiesAway, "123", Greater);
+    test("491", 2, Odd, "123", Greater);
 
     test("492", 2, Down, "123", Equal);
     test("492", 2, Up, "123", Equal);
     test("492", 2, Floor, "123", Equal);
     test("492", 2, Ceiling, "123", Equal);
     test("492", 2, Nearest, "123", Equal);
+    test("492", 2, NearestTiesAway, "123", Equal);
+    test("492", 2, Odd, "123", Equal);
     test("492", 2, Exact, "123", Equal);
 
     test("493", 2, Down, "123", Less);
@@ -247,18 +263,24 @@ where
     test("493", 2, Floor, "123", Less);
     test("493", 2, Ceiling, "124", Greater);
     test("493", 2, Nearest, "123", Less);
+    test("493", 2, NearestTiesAway, "123", Less);
+    test("493", 2, Odd, "123", Less);
 
     test("4127195135", 25, Down, "122", Less);
     test("4127195135", 25, Up, "123", Greater);
     test("4127195135", 25, Floor, "122", Less);
     test("4127195135", 25, Ceiling, "123", Greater);
     test("4127195135", 25, Nearest, "123", Greater);
+    test("4127195135", 25, NearestTiesAway, "123", Greater);
+    test("4127195135", 25, Odd, "123", Greater);
 
     test("4127195136", 25, Down, "123", Equal);
     test("4127195136", 25, Up, "123", Equal);
     test("4127195136", 25, Floor, "123", Equal);
     test("4127195136", 25, Ceiling, "123", Equal);
     test("4127195136", 25, Nearest, "123", Equal);
+    test("4127195136", 25, NearestTiesAway, "123", Equal);
+    test("4127195136", 25, Odd, "123", Equal);
     test("4127195136", 25, Exact, "123", Equal);
 
     test("4127195137", 25, Down, "123", Less);
@@ -266,18 +288,24 @@ where
     test("4127195137", 25, Floor, "123", Less);
     test("4127195137", 25, Ceiling, "124", Greater);
     test("4127195137", 25, Nearest, "123", Less);
+    test("4127195137", 25, NearestTiesAway, "123", Less);
+    test("4127195137", 25, Odd, "123", Less);
 
     test("8254390271", 26, Down, "122", Less);
     test("8254390271", 26, Up, "123", Greater);
     test("8254390271", 26, Floor, "122", Less);
     test("8254390271", 26, Ceiling, "123", Greater);
     test("8254390271", 26, Nearest, "123", Greater);
+    test("8254390271", 26, NearestTiesAway, "123", Greater);
+    test("8254390271", 26, Odd, "123", Greater);
 
     test("8254390272", 26, Down, "123", Equal);
     test("8254390272", 26, Up, "123", Equal);
     test("8254390272", 26, Floor, "123", Equal);
     test("8254390272", 26, Ceiling, "123", Equal);
     test("8254390272", 26, Nearest, "123", Equal);
+    test("8254390272", 26, NearestTiesAway, "123", Equal);
+    test("8254390272", 26, Odd, "123", Equal);
     test("8254390272", 26, Exact, "123", Equal);
 
     test("8254390273", 26, Down, "123", Less);
@@ -285,6 +313,8 @@ where
     test("8254390273", 26, Floor, "123", Less);
     test("8254390273", 26, Ceiling, "124", Greater);
     test("8254390273", 26, Nearest, "123", Less);
+    test("8254390273", 26, NearestTiesAway, "123", Less);
+    test("8254390273", 26, Odd, "123", Less);
 
     test("155921023828072216384094494261247", 100, Down, "122", Less);
     test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -358,12 +388,16 @@ where
     test("4294967295", 1, Floor, "2147483647", Less);
     test("4294967295", 1, Ceiling, "2147483648", Greater);
     test("4294967295", 1, Nearest, "2147483648", Greater);
+    test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+    test("4294967295", 1, Odd, "2147483647", Less);
 
     test("4294967296", 1, Down, "2147483648", Equal);
     test("4294967296", 1, Up, "2147483648", Equal);
     test("4294967296", 1, Floor, "2147483648", Equal);
     test("4294967296", 1, Ceiling, "2147483648", Equal);
     test("4294967296", 1, Nearest, "2147483648", Equal);
+    test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+    test("4294967296", 1, Odd, "2147483648", Equal);
     test("4294967296", 1, Exact, "2147483648", Equal);
 
     test("4294967297", 1, Down, "2147483648", Less);
@@ -371,12 +405,16 @@ where
     test("4294967297", 1, Floor, "2147483648", Less);
     test("4294967297", 1, Ceiling, "2147483649", Greater);
     test("4294967297", 1, Nearest, "2147483648", Less);
+    test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+    test("4294967297", 1, Odd, "2147483649", Greater);
 
     test("1000000000000", 0, Down, "1000000000000", Equal);
     test("1000000000000", 0, Up, "1000000000000", Equal);
     test("1000000000000", 0, Floor, "1000000000000", Equal);
     test("1000000000000", 0, Ceiling, "1000000000000", Equal);
     test("1000000000000", 0, Nearest, "1000000000000", Equal);
+    test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 0, Odd, "1000000000000", Equal);
     test("1000000000000", 0, Exact, "1000000000000", Equal);
 
     test("7999999999999", 3, Down, "999999999999", Less);
@@ -384,12 +422,16 @@ where
     test("7999999999999", 3, Floor, "999999999999", Less);
     test("7999999999999", 3, Ceiling, "1000000000000", Greater);
     test("7999999999999", 3, Nearest, "1000000000000", Greater);
+    test("7999999999999", 3, NearestTiesAway, "1000000000000", Greater);
+    test("7999999999999", 3, Odd, "999999999999", Less);
 
     test("8000000000000", 3, Down, "1000000000000", Equal);
     test("8000000000000", 3, Up, "1000000000000", Equal);
     test("8000000000000", 3, Floor, "1000000000000", Equal);
     test("8000000000000", 3, Ceiling, "1000000000000", Equal);
     test("8000000000000", 3, Nearest, "1000000000000", Equal);
+    test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+    test("8000000000000", 3, Odd, "1000000000000", Equal);
     test("8000000000000", 3, Exact, "1000000000000", Equal);
 
     test("8000000000001", 3, Down, "1000000000000", Less);
@@ -397,12 +439,16 @@ where
     test("8000000000001", 3, Floor, "1000000000000", Less);
     test("8000000000001", 3, Ceiling, "1000000000001", Greater);
     test("8000000000001", 3, Nearest, "1000000000000", Less);
+    test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+    test("8000000000001", 3, Odd, "1000000000001", Greater);
 
     test("16777216000000000000", 24, Down, "1000000000000", Equal);
     test("16777216000000000000", 24, Up, "1000000000000", Equal);
     test("16777216000000000000", 24, Floor, "1000000000000", Equal);
     test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
     test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+    test("16777216000000000000", 24, NearestTiesAway, "1000000000000", Equal);
+    test("16777216000000000000", 24, Odd, "1000000000000", Equal);
     test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
     test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -410,6 +456,8 @@ where
     test("33554432000000000000", 25, Floor, "1000000000000", Equal);
     test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
     test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+    test("33554432000000000000", 25, NearestTiesAway, "1000000000000", Equal);
+    test("33554432000000000000", 25, Odd, "1000000000000", Equal);
     test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
     test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -517,6 +565,8 @@ where
     test("1000000000000", 10, Floor, "976562500", Equal);
     test("1000000000000", 10, Ceiling, "976562500", Equal);
     test("1000000000000", 10, Nearest, "976562500", Equal);
+    test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+    test("1000000000000", 10, Odd, "976562500", Equal);
     test("1000000000000", 10, Exact, "976562500", Equal);
 
     test("980657949", 72, Down, "0", Less);
@@ -524,24 +574,32 @@ where
     test("980657949", 72, Floor, "0", Less);
     test("980657949", 72, Ceiling, "1", Greater);
     test("980657949", 72, Nearest, "0", Less);
+    test("980657949", 72, NearestTiesAway, "0", Less);
+    test("980657949", 72, Odd, "1", Greater);
 
     test("4294967295", 31, Down, "1", Less);
     test("4294967295", 31, Up, "2", Greater);
     test("4294967295", 31, Floor, "1", Less);
     test("4294967295", 31, Ceiling, "2", Greater);
     test("4294967295", 31, Nearest, "2", Greater);
+    test("4294967295", 31, NearestTiesAway, "2", Greater);
+    test("4294967295", 31, Odd, "1", Less);
 
     test("4294967295", 32, Down, "0", Less);
     test("4294967295", 32, Up, "1", Greater);
     test("4294967295", 32, Floor, "0", Less);
     test("4294967295", 32, Ceiling, "1", Greater);
     test("4294967295", 32, Nearest, "1", Greater);
+    test("4294967295", 32, NearestTiesAway, "1", Greater);
+    test("4294967295", 32, Odd, "1", Greater);
 
     test("4294967296", 32, Down, "1", Equal);
     test("4294967296", 32, Up, "1", Equal);
     test("4294967296", 32, Floor, "1", Equal);
     test("4294967296", 32, Ceiling, "1", Equal);
     test("4294967296", 32, Nearest, "1", Equal);
+    test("4294967296", 32, NearestTiesAway, "1", Equal);
+    test("4294967296", 32, Odd, "1", Equal);
     test("4294967296", 32, Exact, "1", Equal);
 
     test("4294967296", 33, Down, "0", Less);
@@ -549,6 +607,8 @@ where
     test("4294967296", 33, Floor, "0", Less);
     test("4294967296", 33, Ceiling, "1", Greater);
     test("4294967296", 33, Nearest, "0", Less);
+    test("4294967296", 33, NearestTiesAway, "1", Greater);
+    test("4294967296", 33, Odd, "1", Greater);
 }
 
 #[test]
@@ -625,6 +685,8 @@ where
     test("0", 0, Floor, "0", Equal);
     test("0", 0, Ceiling, "0", Equal);
     test("0", 0, Nearest, "0", Equal);
+    test("0", 0, NearestTiesAway, "0", Equal);
+    test("0", 0, Odd, "0", Equal);
     test("0", 0, Exact, "0", Equal);
 
     test("0", 10, Down, "0", Equal);
@@ -632,6 +694,8 @@ where
     test("0", 10, Floor, "0", Equal);
     test("0", 10, Ceiling, "0", Equal);
     test("0", 10, Nearest, "0", Equal);
+    test("0", 10, NearestTiesAway, "0", Equal);
+    test("0", 10, Odd, "0", Equal);
     test("0", 10, Exact, "0", Equal);
 
     test("123", 0, Down, "123", Equal);
@@ -639,6 +703,8 @@ where
     test("123", 0, Floor, "123", Equal);
     test("123", 0, Ceiling, "123", Equal);
     test("123", 0, Nearest, "123", Equal);
+    test("123", 0, NearestTiesAway, "123", Equal);
+    test("123", 0, Odd, "123", Equal);
     test("123", 0, Exact, "123", Equal);
 
     test("245", 1, Down, "122", Less);
@@ -646,12 +712,16 @@ where
     test("245", 1, Floor, "122", Less);
     test("245", 1, Ceiling, "123", Greater);
     test("245", 1, Nearest, "122", Less);
+    test("245", 1, NearestTiesAway, "123", Greater);
+    test("245", 1, Odd, "123", Greater);
 
     test("246", 1, Down, "123", Equal);
     test("246", 1, Up, "123", Equal);
     test("246", 1, Floor, "123", Equal);
     test("246", 1, Ceiling, "123", Equal);
     test("246", 1, Nearest, "123", Equal);
+    test("246", 1, NearestTiesAway, "123", Equal);
+    test("246", 1, Odd, "123", Equal);
     test("246", 1, Exact, "123", Equal);
 
     test("247", 1, Down, "123", Less);
@@ -659,18 +729,24 @@ where
     test("247", 1, Floor, "123", Less);
     test("247", 1, Ceiling, "124", Greater);
     test("247", 1, Nearest, "124", Greater);
+    test("247", 1, NearestTiesAway, "124", Greater);
+    test("247", 1, Odd, "123", Less);
 
     test("491", 2, Down, "122", Less);
     test("491", 2, Up, "123", Greater);
     test("491", 2, Floor, "122", Less);
     test("491", 2, Ceiling, "123", Greater);
     test("491", 2, Nearest, "123", Greater);
+    test("491", 2, NearestTiesAway, "123", Greater);
+    test("491", 2, Odd, "123", Greater);
 
     test("492", 2, Down, "123", Equal);
     test("492", 2, Up, "123", Equal);
     test("492", 2, Floor, "123", Equal);
     test("492", 2, Ceiling, "123", Equal);
     test("492", 2, Nearest, "123", Equal);
+    test("492", 2, NearestTiesAway, "123", Equal);
+    test("492", 2, Odd, "123", Equal);
     test("492", 2, Exact, "123", Equal);
 
     test("493", 2, Down, "123", Less);
@@ -678,18 +754,24 @@ where
     test("493", 2, Floor, "123", Less);
     test("493", 2, Ceiling, "124", Greater);
     test("493", 2, Nearest, "123", Less);
+    test("493", 2, NearestTiesAway, "123", Less);
+    test("493", 2, Odd, "123", Less);
 
     test("4127195135", 25, Down, "122", Less);
     test("4127195135", 25, Up, "123", Greater);
     test("4127195135", 25, Floor, "122", Less);
     test("4127195135", 25, Ceiling, "123", Greater);
     test("4127195135", 25, Nearest, "123", Greater);
+    test("4127195135", 25, NearestTiesAway, "123", Greater);
+    test("4127195135", 25, Odd, "123", Greater);
 
     test("4127195136", 25, Down, "123", Equal);
     test("4127195136", 25, Up, "123", Equal);
     test("4127195136", 25, Floor, "123", Equal);
     test("4127195136", 25, Ceiling, "123", Equal);
     test("4127195136", 25, Nearest, "123", Equal);
+    test("4127195136", 25, NearestTiesAway, "123", Equal);
+    test("4127195136", 25, Odd, "123", Equal);
     test("4127195136", 25, Exact, "123", Equal);
 
     test("4127195137", 25, Down, "123", Less);
@@ -697,18 +779,24 @@ where
     test("4127195137", 25, Floor, "123", Less);
     test("4127195137", 25, Ceiling, "124", Greater);
     test("4127195137", 25, Nearest, "123", Less);
+    test("4127195137", 25, NearestTiesAway, "123", Less);
+    test("4127195137", 25, Odd, "123", Less);
 
     test("8254390271", 26, Down, "122", Less);
     test("8254390271", 26, Up, "123", Greater);
     test("8254390271", 26, Floor, "122", Less);
     test("8254390271", 26, Ceiling, "123", Greater);
     test("8254390271", 26, Nearest, "123", Greater);
+    test("8254390271", 26, NearestTiesAway, "123", Greater);
+    test("8254390271", 26, Odd, "123", Greater);
 
     test("8254390272", 26, Down, "123", Equal);
     test("8254390272", 26, Up, "123", Equal);
     test("8254390272", 26, Floor, "123", Equal);
     test("8254390272", 26, Ceiling, "123", Equal);
     test("8254390272", 26, Nearest, "123", Equal);
+    test("8254390272", 26, NearestTiesAway, "123", Equal);
+    test("8254390272", 26, Odd, "123", Equal);
     test("8254390272", 26, Exact, "123", Equal);
 
     test("8254390273", 26, Down, "123", Less);
@@ -716,6 +804,8 @@ where
     test("8254390273", 26, Floor, "123", Less);
     test("8254390273", 26, Ceiling, "124", Greater);
     test("8254390273", 26, Nearest, "123", Less);
+    test("8254390273", 26, NearestTiesAway, "123", Less);
+    test("8254390273", 26, Odd, "123", Less);
 
     test("155921023828072216384094494261247", 100, Down, "122", Less);
     test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -789,12 +879,16 @@ where
     test("4294967295", 1, Floor, "2147483647", Less);
     test("4294967295", 1, Ceiling, "2147483648", Greater);
     test("4294967295", 1, Nearest, "2147483648", Greater);
+    test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+    test("4294967295", 1, Odd, "2147483647", Less);
 
     test("4294967296", 1, Down, "2147483648", Equal);
     test("4294967296", 1, Up, "2147483648", Equal);
     test("4294967296", 1, Floor, "2147483648", Equal);
     test("4294967296", 1, Ceiling, "2147483648", Equal);
     test("4294967296", 1, Nearest, "2147483648", Equal);
+    test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+    test("4294967296", 1, Odd, "2147483648", Equal);
     test("4294967296", 1, Exact, "2147483648", Equal);
 
     test("4294967297", 1, Down, "2147483648", Less);
@@ -802,12 +896,16 @@ where
     test("4294967297", 1, Floor, "2147483648", Less);
     test("4294967297", 1, Ceiling, "2147483649", Greater);
     test("4294967297", 1, Nearest, "2147483648", Less);
+    test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+    test("4294967297", 1, Odd, "2147483649", Greater);
 
     test("1000000000000", 0, Down, "1000000000000", Equal);
     test("1000000000000", 0, Up, "1000000000000", Equal);
     test("1000000000000", 0, Floor, "1000000000000", Equal);
     test("1000000000000", 0, Ceiling, "1000000000000", Equal);
     test("1000000000000", 0, Nearest, "1000000000000", Equal);
+    test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 0, Odd, "1000000000000", Equal);
     test("1000000000000", 0, Exact, "1000000000000", Equal);
 
     test("7999999999999", 3, Down, "999999999999", Less);
@@ -815,12 +913,16 @@ where
     test("7999999999999", 3, Floor, "999999999999", Less);
     test("7999999999999", 3, Ceiling, "1000000000000", Greater);
     test("7999999999999", 3, Nearest, "1000000000000", Greater);
+    test("7999999999999", 3, NearestTiesAway, "1000000000000", Greater);
+    test("7999999999999", 3, Odd, "999999999999", Less);
 
     test("8000000000000", 3, Down, "1000000000000", Equal);
     test("8000000000000", 3, Up, "1000000000000", Equal);
     test("8000000000000", 3, Floor, "1000000000000", Equal);
     test("8000000000000", 3, Ceiling, "1000000000000", Equal);
     test("8000000000000", 3, Nearest, "1000000000000", Equal);
+    test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+    test("8000000000000", 3, Odd, "1000000000000", Equal);
     test("8000000000000", 3, Exact, "1000000000000", Equal);
 
     test("8000000000001", 3, Down, "1000000000000", Less);
@@ -828,12 +930,16 @@ where
     test("8000000000001", 3, Floor, "1000000000000", Less);
     test("8000000000001", 3, Ceiling, "1000000000001", Greater);
     test("8000000000001", 3, Nearest, "1000000000000", Less);
+    test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+    test("8000000000001", 3, Odd, "1000000000001", Greater);
 
     test("16777216000000000000", 24, Down, "1000000000000", Equal);
     test("16777216000000000000", 24, Up, "1000000000000", Equal);
     test("16777216000000000000", 24, Floor, "1000000000000", Equal);
     test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
     test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+    test("16777216000000000000", 24, NearestTiesAway, "1000000000000", Equal);
+    test("16777216000000000000", 24, Odd, "1000000000000", Equal);
     test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
     test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -841,6 +947,8 @@ where
     test("33554432000000000000", 25, Floor, "1000000000000", Equal);
     test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
     test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+    test("33554432000000000000", 25, NearestTiesAway, "1000000000000", Equal);
+    test("33554432000000000000", 25, Odd, "1000000000000", Equal);
     test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
     test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -948,6 +1056,8 @@ where
     test("1000000000000", 10, Floor, "976562500", Equal);
     test("1000000000000", 10, Ceiling, "976562500", Equal);
     test("1000000000000", 10, Nearest, "976562500", Equal);
+    test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+    test("1000000000000", 10, Odd, "976562500", Equal);
     test("1000000000000", 10, Exact, "976562500", Equal);
 
     test("980657949", 72, Down, "0", Less);
@@ -955,24 +1065,32 @@ where
     test("980657949", 72, Floor, "0", Less);
     test("980657949", 72, Ceiling, "1", Greater);
     test("980657949", 72, Nearest, "0", Less);
+    test("980657949", 72, NearestTiesAway, "0", Less);
+    test("980657949", 72, Odd, "1", Greater);
 
     test("4294967295", 31, Down, "1", Less);
     test("4294967295", 31, Up, "2", Greater);
     test("4294967295", 31, Floor, "1", Less);
     test("4294967295", 31, Ceiling, "2", Greater);
     test("4294967295", 31, Nearest, "2", Greater);
+    test("4294967295", 31, NearestTiesAway, "2", Greater);
+    test("4294967295", 31, Odd, "1", Less);
 
     test("4294967295", 32, Down, "0", Less);
     test("4294967295", 32, Up, "1", Greater);
     test("4294967295", 32, Floor, "0", Less);
     test("4294967295", 32, Ceiling, "1", Greater);
     test("4294967295", 32, Nearest, "1", Greater);
+    test("4294967295", 32, NearestTiesAway, "1", Greater);
+    test("4294967295", 32, Odd, "1", Greater);
 
     test("4294967296", 32, Down, "1", Equal);
     test("4294967296", 32, Up, "1", Equal);
     test("4294967296", 32, Floor, "1", Equal);
     test("4294967296", 32, Ceiling, "1", Equal);
     test("4294967296", 32, Nearest, "1", Equal);
+    test("4294967296", 32, NearestTiesAway, "1", Equal);
+    test("4294967296", 32, Odd, "1", Equal);
     test("4294967296", 32, Exact, "1", Equal);
 
     test("4294967296", 33, Down, "0", Less);
@@ -980,6 +1098,8 @@ where
     test("4294967296", 33, Floor, "0", Less);
     test("4294967296", 33, Ceiling, "1", Greater);
     test("4294967296", 33, Nearest, "0", Less);
+    test("4294967296", 33, NearestTiesAway, "1", Greater);
+    test("4294967296", 33, Odd, "1", Greater);
 
     test("0", -10, Exact, "0", Equal);
     test("123", -1, Exact, "246", Equal);
@@ -0,0 +1,153 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, Factorial, PowerOf2, StirlingNumber1, StirlingNumber2,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_28;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::arithmetic::stirling_number::{
+    stirling_number_1_row, stirling_number_2_row, stirling_number_2_row_convolution,
+    stirling_number_2_row_triangle,
+};
+use malachite_nz::test_util::natural::arithmetic::stirling_number::{
+    stirling_number_1_row_naive, stirling_number_2_row_naive,
+};
+
+#[test]
+fn test_stirling_number_1() {
+    let test = |n, k, out: &str| {
+        assert_eq!(Natural::stirling_number_1(n, k).to_string(), out);
+    };
+    // (expected values computed independently with python)
+    test(0, 0, "1");
+    test(1, 0, "0");
+    test(3, 5, "0");
+    test(5, 2, "50");
+    test(10, 3, "1172700");
+    test(25, 23, "42550");
+    test(30, 10, "215760462268683520394805979744");
+    test(40, 20, "1083606530591509770261650763430379569");
+}
+
+#[test]
+fn test_stirling_number_2() {
+    let test = |n, k, out: &str| {
+        assert_eq!(Natural::stirling_number_2(n, k).to_string(), out);
+    };
+    test(0, 0, "1");
+    test(1, 0, "0");
+    test(3, 5, "0");
+    test(5, 2, "15");
+    test(10, 3, "9330");
+    test(25, 2, "16777215");
+    test(30, 28, "86275");
+    test(30, 10, "173373343599189364594756");
+    test(40, 20, "162188909527975750487887236507181");
+}
+
+#[test]
+fn stirling_number_agrees_with_naive() {
+    // Every entry of the first 61 rows, which reaches all three single-value strategies: the band
+    // recurrence near the diagonal, the whole row (first kind) or alternating power sum (second
+    // kind) elsewhere, and the closed forms at the edges.
+    for n in 0..=60 {
+        let row_1 = stirling_number_1_row_naive(n);
+        let row_2 = stirling_number_2_row_naive(n);
+        for (k, (c, s)) in row_1.iter().zip(row_2.iter()).enumerate() {
+            let k = k as u64;
+            assert_eq!(Natural::stirling_number_1(n, k), *c, "c({n}, {k})");
+            assert_eq!(Natural::stirling_number_2(n, k), *s, "S({n}, {k})");
+        }
+        assert_eq!(Natural::stirling_number_1(n, n + 1), 0);
+        assert_eq!(Natural::stirling_number_2(n, n + 1), 0);
+    }
+    // - a wider row, where the band recurrence is chosen only very near the diagonal
+    let n = 200;
+    let row_1 = stirling_number_1_row_naive(n);
+    let row_2 = stirling_number_2_row_naive(n);
+    for k in [2, 3, 50, 100, 150, 185, 190, 196, 197, 198] {
+        assert_eq!(
+            Natural::stirling_number_1(n, k),
+            row_1[k as usize],
+            "c({n}, {k})"
+        );
+        assert_eq!(
+            Natural::stirling_number_2(n, k),
+            row_2[k as usize],
+            "S({n}, {k})"
+        );
+    }
+}
+
+#[test]
+fn test_stirling_number_rows() {
+    assert_eq!(stirling_number_1_row(0), [Natural::ONE]);
+    assert_eq!(stirling_number_2_row(0), [Natural::ONE]);
+    assert_eq!(stirling_number_1_row(1), [Natural::ZERO, Natural::ONE]);
+    assert_eq!(stirling_number_2_row(1), [Natural::ZERO, Natural::ONE]);
+    // - both row strategies for the second kind, on either side of the dispatch threshold (64)
+    for n in [2, 5, 17, 63, 64, 65, 100] {
+        assert_eq!(
+            stirling_number_1_row(n),
+            stirling_number_1_row_naive(n),
+            "n = {n}"
+        );
+        assert_eq!(
+            stirling_number_2_row(n),
+            stirling_number_2_row_naive(n),
+            "n = {n}"
+        );
+    }
+    for n in 0..40 {
+        assert_eq!(
+            stirling_number_2_row_convolution(n),
+            stirling_number_2_row_triangle(n),
+            "n = {n}"
+        );
+    }
+}
+
+#[test]
+fn stirling_number_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties_with_limit(TINY_LIMIT, |(n, k)| {
+        let c = Natural::stirling_number_1(n, k);
+        let s = Natural::stirling_number_2(n, k);
+        assert_eq!(c == 0u32, k > n || k == 0 && n != 0);
+        assert_eq!(s == 0u32, k > n || k == 0 && n != 0);
+        assert!(s <= c);
+        if k != 0 {
+            // the defining recurrences
+            assert_eq!(
+                Natural::stirling_number_1(n + 1, k),
+                Natural::from(n) * &c + Natural::stirling_number_1(n, k - 1)
+            );
+            assert_eq!(
+                Natural::stirling_number_2(n + 1, k),
+                Natural::from(k) * &s + Natural::stirling_number_2(n, k - 1)
+            );
+        }
+    });
+
+    // - closed forms far from the naive range
+    let n = 300;
+    assert_eq!(Natural::stirling_number_1(n, 1), Natural::factorial(n - 1));
+    assert_eq!(Natural::stirling_number_2(n, 1), 1);
+    let b = Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32));
+    assert_eq!(Natural::stirling_number_1(n, n - 1), b);
+    assert_eq!(Natural::stirling_number_2(n, n - 1), b);
+    assert_eq!(
+        Natural::stirling_number_2(n, 2),
+        Natural::power_of_2(n - 1) - Natural::ONE
+    );
+    // - the first-kind row sums to n!
+    let row: Natural = stirling_number_1_row(n).into_iter().sum();
+    assert_eq!(row, Natural::factorial(n));
+}
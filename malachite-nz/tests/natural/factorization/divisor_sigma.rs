@@ -0,0 +1,134 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{Gcd, Pow};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{
+    CheckedDivisorSigma, DivisorCount, DivisorSigma, Divisors, Factor,
+};
+use malachite_base::test_util::generators::common::{GenConfig, TINY_LIMIT};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_12, unsigned_pair_gen_var_27,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::factorization::factor::FactorOptions;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_sigma() {
+    let test = |n, sigma_1, sigma_2, tau| {
+        let n = Natural::from_str(n).unwrap();
+        let sigma_1 = Natural::from_str(sigma_1).unwrap();
+        let sigma_2 = Natural::from_str(sigma_2).unwrap();
+        let tau = Natural::from_str(tau).unwrap();
+        assert_eq!(n.divisor_sigma(1), sigma_1);
+        assert_eq!(n.divisor_sigma(2), sigma_2);
+        assert_eq!(n.divisor_sigma(0), tau);
+        assert_eq!(n.divisor_count(), tau);
+        let factors = n.factor();
+        assert_eq!(factors.divisor_sigma(1), sigma_1);
+        assert_eq!(factors.divisor_sigma(2), sigma_2);
+        assert_eq!(factors.divisor_count(), tau);
+    };
+    test("1", "1", "1", "1");
+    test("12", "28", "210", "6");
+    // - 2 ^ 128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        "340282366920938469168123457706024763396",
+        "115792089237316195423570985008687940397429429371092562661215782888834021195780",
+        "4",
+    );
+    // - 10 ^ 30
+    test(
+        "1000000000000000000000000000000",
+        "2499999998835846781730114984557",
+        "1388888888888888888587721618754026546109040824596043713112451",
+        "961",
+    );
+    // - 3 * 2 ^ 66
+    test(
+        "221360928884514619392",
+        "590295810358705651708",
+        "72593571609800205538853249585443885110610",
+        "134",
+    );
+    // - 2 ^ 100 * 3 ^ 50
+    test(
+        "910043815000214977332758527534256632492715260325658624",
+        "2730131445000644931998274314951092822267206505390615123",
+        "12422696178302182538761263539360527470241842177805738301889261045090551108088758354499780\
+        62338345966982155751",
+        "5151",
+    );
+}
+
+#[test]
+fn divisor_sigma_fail() {
+    assert_panic!(Natural::ZERO.divisor_sigma(1));
+    assert_panic!(Natural::ZERO.divisor_count());
+    let n = Natural::from_str("1000000000000037").unwrap()
+        * Natural::from_str("1000000000000091").unwrap();
+    let mut options = FactorOptions::default();
+    options.set_max_digits(Some(20));
+    let factors = n.factor_with_options(&options);
+    assert_panic!(factors.divisor_sigma(1));
+    assert_panic!(factors.divisor_count());
+}
+
+#[test]
+fn divisor_sigma_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    natural_gen_var_2().test_properties_with_config_and_limit(&config, TINY_LIMIT, |n| {
+        let tau = n.divisor_count();
+        let sigma = n.divisor_sigma(1);
+        assert_eq!(n.divisor_sigma(0), tau);
+        assert!(sigma >= n);
+        assert_eq!(sigma == &n + Natural::from(1u32), tau == 2u32);
+        let factors = n.factor();
+        assert_eq!(factors.divisor_count(), tau);
+        assert_eq!(factors.divisor_sigma(1), sigma);
+        if tau <= 1000u32 {
+            assert_eq!(Natural::from(n.divisors().count()), tau);
+            assert_eq!(n.divisors().sum::<Natural>(), sigma);
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u16>().test_properties_with_limit(TINY_LIMIT, |(n, k)| {
+        let n = u64::from(n) + 1;
+        let k = u64::from(k % 8);
+        let sigma = Natural::from(n).divisor_sigma(k);
+        assert_eq!(
+            sigma,
+            Natural::from(n)
+                .divisors()
+                .map(|d| d.pow(k))
+                .sum::<Natural>()
+        );
+        assert_eq!(u64::try_from(&sigma).ok(), n.checked_divisor_sigma(k));
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(TINY_LIMIT, |n| {
+        assert_eq!(Natural::from(n).divisor_count(), n.divisor_count());
+    });
+
+    unsigned_pair_gen_var_12::<u32, u32>().test_properties_with_limit(TINY_LIMIT, |(a, b)| {
+        if a == 0 || a.gcd(b) != 1 {
+            return;
+        }
+        let a = Natural::from(a);
+        let b = Natural::from(b);
+        let ab = &a * &b;
+        assert_eq!(ab.divisor_sigma(2), a.divisor_sigma(2) * b.divisor_sigma(2));
+        assert_eq!(ab.divisor_count(), a.divisor_count() * b.divisor_count());
+    });
+}
@@ -0,0 +1,91 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::DivisibleBy;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{DivisorCount, Divisors, Factor};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::{GenConfig, TINY_LIMIT};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::factorization::factor::FactorOptions;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisors() {
+    let test = |n, limit, out| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(
+            n.divisors().take(limit).collect_vec().to_debug_string(),
+            out
+        );
+        assert_eq!(
+            n.factor()
+                .divisors()
+                .take(limit)
+                .collect_vec()
+                .to_debug_string(),
+            out
+        );
+    };
+    test("1", 10, "[1]");
+    test("12", 10, "[1, 2, 3, 4, 6, 12]");
+    // - 2 ^ 128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        10,
+        "[1, 59649589127497217, 5704689200685129054721, 340282366920938463463374607431768211457]",
+    );
+    // - 10 ^ 30
+    test(
+        "1000000000000000000000000000000",
+        20,
+        "[1, 2, 4, 5, 8, 10, 16, 20, 25, 32, 40, 50, 64, 80, 100, 125, 128, 160, 200, 250]",
+    );
+}
+
+#[test]
+fn divisors_fail() {
+    assert_panic!(Natural::ZERO.divisors());
+    let n = Natural::from_str("1000000000000037").unwrap()
+        * Natural::from_str("1000000000000091").unwrap();
+    let mut options = FactorOptions::default();
+    options.set_max_digits(Some(20));
+    assert_panic!(n.factor_with_options(&options).divisors());
+}
+
+#[test]
+fn divisors_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    natural_gen_var_2().test_properties_with_config_and_limit(&config, TINY_LIMIT, |n| {
+        let divisors = n.divisors().take(1000).collect_vec();
+        assert_eq!(divisors[0], 1u32);
+        assert!(divisors.iter().tuple_windows().all(|(d, e)| d < e));
+        assert!(divisors.iter().all(|d| (&n).divisible_by(d)));
+        if n.divisor_count() == divisors.len() {
+            assert_eq!(*divisors.last().unwrap(), n);
+            // divisors come in pairs d, n / d
+            for (d, e) in divisors.iter().zip(divisors.iter().rev()) {
+                assert_eq!(d * e, n);
+            }
+        }
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(TINY_LIMIT, |n| {
+        assert!(
+            Natural::from(n)
+                .divisors()
+                .eq(n.divisors().map(Natural::from))
+        );
+    });
+}
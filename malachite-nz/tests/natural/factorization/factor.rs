@@ -0,0 +1,220 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::factorization::factor::{FactorOptions, NaturalFactors};
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::str::FromStr;
+
+fn factors_product(factors: &NaturalFactors) -> Natural {
+    factors
+        .prime_factors()
+        .iter()
+        .chain(factors.unfactored().iter())
+        .map(|(p, e)| p.pow(*e))
+        .product()
+}
+
+fn verify_factors(n: &Natural, factors: &NaturalFactors) {
+    assert_eq!(factors_product(factors), *n);
+    for (p, _) in factors.prime_factors() {
+        assert!(p.is_prime());
+    }
+    for (c, _) in factors.unfactored() {
+        assert!(!c.is_prime());
+        assert!(
+            factors
+                .prime_factors()
+                .iter()
+                .all(|(p, _)| c.coprime_with(p))
+        );
+    }
+    for ((x, _), (y, _)) in factors.unfactored().iter().tuple_combinations() {
+        assert!(x.coprime_with(y));
+    }
+    assert!(
+        factors
+            .prime_factors()
+            .iter()
+            .tuple_windows()
+            .all(|((p, _), (q, _))| p < q)
+    );
+    assert_eq!(factors.is_complete(), factors.unfactored().is_empty());
+}
+
+#[test]
+fn test_factor() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let factors = n.factor();
+        verify_factors(&n, &factors);
+        assert!(factors.is_complete());
+        assert_eq!(factors.into_iter().collect_vec().to_debug_string(), out);
+    };
+    test("1", "[]");
+    test("2", "[(2, 1)]");
+    test("360", "[(2, 3), (3, 2), (5, 1)]");
+    // - fits in a u64
+    test(
+        "18446744073709551615",
+        "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]",
+    );
+    // - a power of 2
+    test("18446744073709551616", "[(2, 64)]");
+    // - cofactor after trial division fits in a u64
+    test("18446744073709551617", "[(274177, 1), (67280421310721, 1)]");
+    test(
+        "1000000000000000000000000000001",
+        "[(61, 1), (101, 1), (3541, 1), (9901, 1), (27961, 1), (4188901, 1), (39526741, 1)]",
+    );
+    // - the cofactor is prime
+    test(
+        "340282366920938463463374607431768211507",
+        "[(340282366920938463463374607431768211507, 1)]",
+    );
+    // - the cofactor is a perfect power
+    test(
+        "340282366920938463942989953348216553641",
+        "[(18446744073709551629, 2)]",
+    );
+    test(
+        "717897987691852589610189645599467529188768181783792336298449609431893003713262400431",
+        "[(3, 50), (100000000000000000039, 3)]",
+    );
+    // - 2 ^ 128 + 1, split by the quadratic sieve
+    test(
+        "340282366920938463463374607431768211457",
+        "[(59649589127497217, 1), (5704689200685129054721, 1)]",
+    );
+    // - with a repeated prime factor that is only found by the quadratic sieve
+    test(
+        "158311987837183797720271444523788973832037889",
+        "[(4755228997, 2), (679322646091, 1), (10306111517531, 1)]",
+    );
+    // - split by the quadratic sieve
+    test(
+        "115043108207770270658126883118736219",
+        "[(294940133838111043, 1), (390055794410522633, 1)]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn factor_fail() {
+    Natural::ZERO.factor();
+}
+
+#[test]
+fn test_factor_with_options() {
+    let test = |s, max_digits, max_ecm_curves, out_primes, out_unfactored| {
+        let n = Natural::from_str(s).unwrap();
+        let mut options = FactorOptions::default();
+        options.set_max_digits(max_digits);
+        options.set_max_ecm_curves(max_ecm_curves);
+        assert_eq!(options.get_max_digits(), max_digits);
+        assert_eq!(options.get_max_ecm_curves(), max_ecm_curves);
+        let factors = n.factor_with_options(&options);
+        verify_factors(&n, &factors);
+        assert_eq!(factors.prime_factors().to_debug_string(), out_primes);
+        assert_eq!(factors.unfactored().to_debug_string(), out_unfactored);
+    };
+    test("360", Some(0), Some(0), "[(2, 3), (3, 2), (5, 1)]", "[]");
+    // - the cheap methods find one factor, leaving a composite with a repeated prime factor
+    test(
+        "158311987837183797720271444523788973832037889",
+        Some(0),
+        None,
+        "[(679322646091, 1)]",
+        "[(233043883857180884102703657063779, 1)]",
+    );
+    // - too many digits for the expensive methods
+    test(
+        "1000003000000128000384000003367010101",
+        Some(20),
+        None,
+        "[(1000003, 1)]",
+        "[(1000000000000128000000000003367, 1)]",
+    );
+    test(
+        "1000000000000128000000000003367",
+        Some(31),
+        None,
+        "[(1000000000000037, 1), (1000000000000091, 1)]",
+        "[]",
+    );
+    // - out of elliptic curves, and too large for the quadratic sieve
+    test(
+        "100000000000003700000000000000000000000000000000000000000000000000000000000000000000000000\
+        0000012561000000000464757",
+        None,
+        Some(0),
+        "[]",
+        "[(1000000000000037000000000000000000000000000000000000000000000000000000000000000000000000\
+        000000012561000000000464757, 1)]",
+    );
+    // - out of elliptic curves, but small enough for the quadratic sieve
+    test(
+        "115043108207770270658126883118736219",
+        None,
+        Some(0),
+        "[(294940133838111043, 1), (390055794410522633, 1)]",
+        "[]",
+    );
+}
+
+#[test]
+fn factor_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    natural_gen_var_2().test_properties_with_config(&config, |n| {
+        let factors = n.factor();
+        verify_factors(&n, &factors);
+        assert!(factors.is_complete());
+
+        let mut options = FactorOptions::default();
+        options.set_max_digits(Some(0));
+        options.set_max_ecm_curves(Some(0));
+        let bounded_factors = n.factor_with_options(&options);
+        verify_factors(&n, &bounded_factors);
+        for (p, e) in bounded_factors.prime_factors() {
+            assert!(factors.prime_factors().contains(&(p.clone(), *e)));
+        }
+        if bounded_factors.is_complete() {
+            assert_eq!(bounded_factors, factors);
+        }
+
+        let squared = (&n).pow(2);
+        assert_eq!(
+            squared.factor().into_iter().collect_vec(),
+            factors
+                .clone()
+                .into_iter()
+                .map(|(p, e)| (p, e << 1))
+                .collect_vec()
+        );
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|u| {
+        assert_eq!(
+            Natural::from(u).factor().into_iter().collect_vec(),
+            u.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), u64::from(e)))
+                .collect_vec()
+        );
+    });
+
+    assert_eq!(Natural::ONE.factor(), NaturalFactors::default());
+}
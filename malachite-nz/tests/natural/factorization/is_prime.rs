@@ -0,0 +1,130 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{PowerOf2, Square};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{IsPrime, ProvePrime};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen_var_3};
+use std::str::FromStr;
+
+fn rug_is_prime(n: &rug::Integer) -> bool {
+    n.is_probably_prime(30) != rug::integer::IsPrime::No
+}
+
+#[test]
+fn test_is_prime() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(rug_is_prime(&rug::Integer::from_str(s).unwrap()), out);
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("3", true);
+    test("4", false);
+    test("5", true);
+    test("6", false);
+    test("97", true);
+    test("1000", false);
+    // - fits in a u64
+    test("18446744073709551557", true);
+    test("18446744073709551615", false);
+    test("4294967311", true);
+    // - Large, has a small odd prime factor
+    test("18446744073709551621", false);
+    // - Large, passes trial division and the base-2 strong test, fails the strong Lucas test
+    test("340282366920938463463374607431768211457", false);
+    // - Large, passes every stage
+    test("18446744073709551629", true);
+    test("170141183460469231731687303715884105727", true);
+    test(
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        true,
+    );
+    // - the square of a prime above the trial-division bound
+    test("340282366920938463942989953348216553641", false);
+}
+
+#[test]
+fn test_is_prime_strong_pseudoprimes() {
+    // Strong pseudoprimes to base 2 must be caught by the strong Lucas test
+    for s in ["3825123056546413051", "318665857834031151167461", "3317044064679887385961981"] {
+        assert!(!Natural::from_str(s).unwrap().is_prime(), "{s}");
+    }
+}
+
+#[test]
+fn test_prove_prime() {
+    let test = |s, out| {
+        assert_eq!(Natural::from_str(s).unwrap().prove_prime(), out);
+    };
+    test("0", Some(false));
+    test("1", Some(false));
+    test("2", Some(true));
+    test("91", Some(false));
+    test("18446744073709551557", Some(true));
+    test("18446744073709551629", Some(true));
+    test("18446744073709551621", Some(false));
+    // - n - 1 = 2 * (2 ^ 126 - 1), whose factors are all found
+    test("170141183460469231731687303715884105727", Some(true));
+    test("340282366920938463463374607431768211457", Some(false));
+    // - too little of n - 1 can be factored
+    test(
+        "6864797660130609714981900799081393217269435300143305409394463459185543183397656052122559\
+        640661454554977296311391480858037121987999716643812574028291115057151",
+        None,
+    );
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(rug_is_prime(&rug::Integer::from(&n)), is_prime);
+        if let Some(proven) = n.prove_prime() {
+            assert_eq!(proven, is_prime);
+        } else {
+            assert!(is_prime);
+        }
+        if n > 1u32 {
+            assert!(!(&n).square().is_prime());
+        }
+    });
+
+    natural_pair_gen_var_3().test_properties(|(a, b)| {
+        if a > 1u32 && b > 1u32 {
+            let product = a * b;
+            assert!(!product.is_prime());
+            assert_eq!(product.prove_prime(), Some(false));
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        assert_eq!(Natural::from(u).is_prime(), u.is_prime());
+        assert_eq!(Natural::from(u).prove_prime(), Some(u.is_prime()));
+    });
+}
+
+#[test]
+fn is_prime_properties_near_powers_of_2() {
+    // Check odd values just above 2 ^ 64, 2 ^ 96, and 2 ^ 128
+    for pow in [64, 96, 128] {
+        let mut n = Natural::power_of_2(pow) + Natural::ONE;
+        for _ in 0..500 {
+            let is_prime = n.is_prime();
+            assert_eq!(rug_is_prime(&rug::Integer::from(&n)), is_prime);
+            if !is_prime {
+                assert_eq!(n.prove_prime(), Some(false));
+            }
+            n += Natural::TWO;
+        }
+    }
+}
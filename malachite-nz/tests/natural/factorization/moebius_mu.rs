@@ -0,0 +1,91 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{Gcd, Square};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{Factor, MoebiusMu};
+use malachite_base::test_util::generators::common::{GenConfig, TINY_LIMIT};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_12};
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::factorization::factor::FactorOptions;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_moebius_mu() {
+    let test = |n, out| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.moebius_mu(), out);
+        assert_eq!(n.factor().moebius_mu(), out);
+    };
+    test("1", 1);
+    test("2", -1);
+    test("12", 0);
+    test("30", -1);
+    // - 2 ^ 128 + 1
+    test("340282366920938463463374607431768211457", 1);
+    // - 10 ^ 30
+    test("1000000000000000000000000000000", 0);
+    // - 2 ^ 127 - 1 is prime
+    test("170141183460469231731687303715884105727", -1);
+    // - (2 ^ 61 - 1) ^ 2
+    test("5316911983139663487003542222693990401", 0);
+    test("1427247692705959880439315947500961989719490561", 1);
+}
+
+#[test]
+fn test_moebius_mu_incomplete() {
+    let mut options = FactorOptions::default();
+    options.set_max_digits(Some(20));
+    // - a repeated prime was found, so the result is 0 even though the factorization is incomplete
+    let n = Natural::from(4u32)
+        * Natural::from_str("1000000000000037").unwrap()
+        * Natural::from_str("1000000000000091").unwrap();
+    let factors = n.factor_with_options(&options);
+    assert!(!factors.is_complete());
+    assert_eq!(factors.moebius_mu(), 0);
+}
+
+#[test]
+fn moebius_mu_fail() {
+    assert_panic!(Natural::ZERO.moebius_mu());
+    let n = Natural::from_str("1000000000000037").unwrap()
+        * Natural::from_str("1000000000000091").unwrap();
+    let mut options = FactorOptions::default();
+    options.set_max_digits(Some(20));
+    assert_panic!(n.factor_with_options(&options).moebius_mu());
+}
+
+#[test]
+fn moebius_mu_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    natural_gen_var_2().test_properties_with_config_and_limit(&config, TINY_LIMIT, |n| {
+        let mu = n.moebius_mu();
+        assert!((-1..=1).contains(&mu));
+        assert_eq!(n.factor().moebius_mu(), mu);
+        if mu != 0 {
+            assert_eq!((&n).square().moebius_mu(), i8::from(n == 1u32));
+        }
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(TINY_LIMIT, |n| {
+        assert_eq!(Natural::from(n).moebius_mu(), n.moebius_mu());
+    });
+
+    unsigned_pair_gen_var_12::<u32, u32>().test_properties_with_limit(TINY_LIMIT, |(a, b)| {
+        if a == 0 || a.gcd(b) != 1 {
+            return;
+        }
+        let a = Natural::from(a);
+        let b = Natural::from(b);
+        assert_eq!((&a * &b).moebius_mu(), a.moebius_mu() * b.moebius_mu());
+    });
+}
@@ -0,0 +1,149 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrevPrime};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_next_prime() {
+    let test = |s, out| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(
+            rug::Integer::from_str(s).unwrap().next_prime().to_string(),
+            out
+        );
+    };
+    test("0", "2");
+    test("1", "2");
+    test("2", "3");
+    test("3", "5");
+    test("100", "101");
+    test("1000000", "1000003");
+    // - the next prime does not fit in a u64
+    test("18446744073709551557", "18446744073709551629");
+    test("18446744073709551615", "18446744073709551629");
+    test(
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211507",
+    );
+    test("100000000000000000000", "100000000000000000039");
+    // - a gap of 1132, spanning more than one segment
+    test("1693182318746371", "1693182318747503");
+    test(
+        "1340780792994259709957402499820584612747936582059239337772356144372176403007354697680187\
+        4298166903427690031858186486050853753882811946569946433649006084096",
+        "1340780792994259709957402499820584612747936582059239337772356144372176403007354697680187\
+        4298166903427690031858186486050853753882811946569946433649006084171",
+    );
+}
+
+#[test]
+fn test_prev_prime() {
+    let test = |s, out: Option<&str>| {
+        let n = Natural::from_str(s).unwrap();
+        let p = n.prev_prime();
+        assert!(p.as_ref().is_none_or(Natural::is_valid));
+        assert_eq!(p.map(|p| p.to_string()).as_deref(), out);
+        if let Some(out) = out {
+            // there must be no prime between the result and n
+            assert!(rug::Integer::from_str(out).unwrap().next_prime() >= rug::Integer::from(&n));
+        }
+    };
+    test("0", None);
+    test("1", None);
+    test("2", None);
+    test("3", Some("2"));
+    test("100", Some("97"));
+    test("1000000", Some("999983"));
+    test("18446744073709551629", Some("18446744073709551557"));
+    test("18446744073709551616", Some("18446744073709551557"));
+    test(
+        "340282366920938463463374607431768211456",
+        Some("340282366920938463463374607431768211297"),
+    );
+    test("100000000000000000039", Some("99999999999999999989"));
+    test(
+        "1340780792994259709957402499820584612747936582059239337772356144372176403007354697680187\
+        4298166903427690031858186486050853753882811946569946433649006084096",
+        Some(
+            "134078079299425970995740249982058461274793658205923933777235614437217640300735469768\
+            01874298166903427690031858186486050853753882811946569946433649006083527",
+        ),
+    );
+}
+
+#[test]
+fn next_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert_eq!(Natural::exact_from(&rug::Integer::from(&n).next_prime()), p);
+        assert_eq!(
+            Natural::primes_in_range(&(&n + Natural::ONE), &p).next(),
+            None
+        );
+        assert!(p.prev_prime().is_none_or(|q| q <= n));
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        if let Some(p) = u.next_prime() {
+            assert_eq!(Natural::from(u).next_prime(), p);
+        }
+    });
+}
+
+#[test]
+fn prev_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.prev_prime();
+        if let Some(p) = p {
+            assert!(p.is_valid());
+            assert!(p < n);
+            assert!(p.is_prime());
+            assert!(rug::Integer::from(&p).next_prime() >= rug::Integer::from(&n));
+            assert_eq!(
+                Natural::primes_in_range(&(&p + Natural::ONE), &n).next(),
+                None
+            );
+            assert!(p.next_prime() >= n);
+        } else {
+            assert!(n <= 2u32);
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        assert_eq!(
+            Natural::from(u).prev_prime(),
+            u.prev_prime().map(Natural::from)
+        );
+    });
+}
+
+#[test]
+fn next_prime_and_prev_prime_properties_near_powers_of_2() {
+    for pow in [64, 65, 128, 256, 1024] {
+        let n = Natural::power_of_2(pow);
+        let p = n.prev_prime().unwrap();
+        let q = n.next_prime();
+        assert_eq!(Natural::exact_from(&rug::Integer::from(&p).next_prime()), q);
+        assert_eq!(Natural::exact_from(&rug::Integer::from(&n).next_prime()), q);
+        assert_eq!(p.next_prime(), q);
+        assert_eq!(q.prev_prime(), Some(p));
+    }
+}
@@ -8,12 +8,13 @@
 
 use itertools::Itertools;
 use malachite_base::iterators::comparison::is_strictly_ascending;
-use malachite_base::num::arithmetic::traits::SaturatingSub;
+use malachite_base::num::arithmetic::traits::{PowerOf2, SaturatingSub};
 use malachite_base::num::basic::traits::One;
-use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, Primes};
 use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
 use malachite_nz::natural::Natural;
-use malachite_nz::test_util::generators::natural_gen_var_9;
+use malachite_nz::test_util::generators::{natural_gen_var_9, natural_unsigned_pair_gen_var_4};
 use std::str::FromStr;
 
 #[test]
@@ -160,6 +161,87 @@ fn test_primes() {
     assert_eq!(ps.to_debug_string(), expected);
 }
 
+#[test]
+fn test_primes_in_range() {
+    let test = |a: &str, b: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let ps = Natural::primes_in_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+        assert_eq!(
+            Natural::primes_in_inclusive_range(&a, &(&b).saturating_sub(Natural::ONE))
+                .collect_vec(),
+            ps
+        );
+    };
+    test("0", "0", "[]");
+    test("0", "2", "[]");
+    test("0", "3", "[2]");
+    test("2", "10", "[2, 3, 5, 7]");
+    test("3", "10", "[3, 5, 7]");
+    test("10", "3", "[]");
+    test("24", "29", "[]");
+    test("90", "110", "[97, 101, 103, 107, 109]");
+    test(
+        "100000000000000000000",
+        "100000000000000000200",
+        "[100000000000000000039, 100000000000000000129, 100000000000000000151, \
+        100000000000000000193]",
+    );
+    test(
+        "340282366920938463463374607431768211456",
+        "340282366920938463463374607431768211600",
+        "[340282366920938463463374607431768211507, 340282366920938463463374607431768211537]",
+    );
+}
+
+#[test]
+fn test_primes_in_inclusive_range() {
+    let test = |a: &str, b: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let ps = Natural::primes_in_inclusive_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+        assert_eq!(
+            Natural::primes_in_range(&a, &(&b + Natural::ONE)).collect_vec(),
+            ps
+        );
+    };
+    test("0", "0", "[]");
+    test("0", "2", "[2]");
+    test("2", "11", "[2, 3, 5, 7, 11]");
+    test("11", "11", "[11]");
+    test("12", "12", "[]");
+    test(
+        "18446744073709551557",
+        "18446744073709551629",
+        "[18446744073709551557, 18446744073709551629]",
+    );
+}
+
+#[test]
+fn test_primes_greater_than_or_equal_to() {
+    let test = |a: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let ps = Natural::primes_greater_than_or_equal_to(&a)
+            .take(5)
+            .collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+    };
+    test("0", "[2, 3, 5, 7, 11]");
+    test("2", "[2, 3, 5, 7, 11]");
+    test("3", "[3, 5, 7, 11, 13]");
+    test("1000", "[1009, 1013, 1019, 1021, 1031]");
+    test(
+        "18446744073709551557",
+        "[18446744073709551557, 18446744073709551629, 18446744073709551653, \
+        18446744073709551667, 18446744073709551697]",
+    );
+}
+
 #[test]
 fn primes_less_than_properties() {
     natural_gen_var_9().test_properties(|n| {
@@ -187,3 +269,48 @@ fn primes_less_than_or_equal_to_properties() {
         assert_eq!(Natural::primes().take_while(|p| *p <= n).collect_vec(), ps);
     });
 }
+
+#[test]
+fn primes_in_range_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 32);
+    natural_unsigned_pair_gen_var_4::<u32>().test_properties_with_config(&config, |(a, width)| {
+        let b = &a + Natural::from(width);
+        let ps = Natural::primes_in_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert!(is_strictly_ascending(ps.iter()));
+        assert!(ps.iter().all(|p| *p >= a && *p < b));
+        let mut n = a.clone();
+        let mut expected = Vec::new();
+        while n < b {
+            if n.is_prime() {
+                expected.push(n.clone());
+            }
+            n += Natural::ONE;
+        }
+        assert_eq!(ps, expected);
+        assert_eq!(
+            Natural::primes_greater_than_or_equal_to(&a)
+                .take_while(|p| *p < b)
+                .collect_vec(),
+            ps
+        );
+        if let Some(p) = ps.first() {
+            assert_eq!(*p, (&a).saturating_sub(Natural::ONE).next_prime());
+        }
+    });
+
+    natural_gen_var_9().test_properties(|n| {
+        assert_eq!(
+            Natural::primes_in_range(&Natural::ONE, &n).collect_vec(),
+            Natural::primes_less_than(&n).collect_vec()
+        );
+    });
+
+    // A range spanning more than one segment
+    let a = Natural::power_of_2(64);
+    let b = &a + Natural::from(200000u32);
+    let ps = Natural::primes_in_range(&a, &b).collect_vec();
+    assert_eq!(ps.len(), 4335);
+    assert!(ps.iter().all(IsPrime::is_prime));
+}
@@ -0,0 +1,137 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{Gcd, ModMultiplicativeOrder, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{IsPrimitiveRoot, PrimitiveRoot};
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_16};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_primitive_root() {
+    let test = |m, out: Option<&str>| {
+        let m = Natural::from_str(m).unwrap();
+        let out = out.map(|s| Natural::from_str(s).unwrap());
+        assert_eq!(m.primitive_root(), out);
+    };
+    // - small moduli use the primitive implementation
+    test("1", Some("0"));
+    test("2", Some("1"));
+    test("4", Some("3"));
+    test("7", Some("3"));
+    test("18", Some("5"));
+    test("8", None);
+    test("65537", Some("3"));
+    // - m = 2 ^ 127 - 1 is prime
+    test("170141183460469231731687303715884105727", Some("43"));
+    // - m = 2 * (2 ^ 127 - 1)
+    test("340282366920938463463374607431768211454", Some("43"));
+    // - m = 3 * 2 ^ 66 + 1 is prime
+    test("221360928884514619393", Some("10"));
+    test("442721857769029238786", Some("13"));
+    // - m = (2 ^ 61 - 1) ^ 2
+    test("5316911983139663487003542222693990401", Some("37"));
+    // - the multiplicative group is not cyclic
+    test("1267650600228229401496703205376", None);
+    test("1427247692705959880439315947500961989719490561", None);
+}
+
+#[test]
+fn primitive_root_fail() {
+    assert_panic!(Natural::ZERO.primitive_root());
+}
+
+#[test]
+fn test_is_primitive_root() {
+    let test = |x, m, out| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        assert_eq!((&x).is_primitive_root(&m), out);
+        assert_eq!((&x).is_primitive_root(m.clone()), out);
+        assert_eq!(x.clone().is_primitive_root(&m), out);
+        assert_eq!(x.is_primitive_root(m), out);
+    };
+    // - small moduli use the primitive implementation
+    test("0", "1", true);
+    test("3", "7", true);
+    test("2", "7", false);
+    test("3", "8", false);
+    // - m = 2 ^ 127 - 1
+    test("43", "170141183460469231731687303715884105727", true);
+    test("3", "170141183460469231731687303715884105727", false);
+    test("2", "170141183460469231731687303715884105727", false);
+    // - m = 3 * 2 ^ 66 + 1
+    test("10", "221360928884514619393", true);
+    test("5", "221360928884514619393", false);
+    test("3", "221360928884514619393", false);
+    // - m = (2 ^ 61 - 1) ^ 2
+    test("37", "5316911983139663487003542222693990401", true);
+    test("10", "5316911983139663487003542222693990401", false);
+    // - the multiplicative group is not cyclic
+    test("3", "1267650600228229401496703205376", false);
+    test("2", "1267650600228229401496703205376", false);
+}
+
+#[test]
+fn is_primitive_root_fail() {
+    assert_panic!(Natural::ZERO.is_primitive_root(Natural::ZERO));
+    assert_panic!(Natural::power_of_2(100).is_primitive_root(Natural::power_of_2(100)));
+}
+
+#[test]
+fn primitive_root_properties() {
+    // For moduli with known factorizations and smooth group orders, an element is a primitive root
+    // exactly when its order is the group order.
+    let moduli = [
+        (Natural::from(65537u32), Natural::from(65536u32)),
+        (
+            Natural::from(3u32) * Natural::power_of_2(66) + Natural::ONE,
+            Natural::from(3u32) * Natural::power_of_2(66),
+        ),
+        (
+            Natural::from(6u32) * Natural::power_of_2(66) + Natural::from(2u32),
+            Natural::from(3u32) * Natural::power_of_2(66),
+        ),
+    ];
+    for (m, phi) in moduli {
+        let g = m.primitive_root().unwrap();
+        assert!(g < m);
+        assert!((&g).is_primitive_root(&m));
+        natural_gen().test_properties_with_limit(TINY_LIMIT, |x| {
+            let x = x % &m;
+            let is_root = (&x).is_primitive_root(&m);
+            assert_eq!(
+                is_root,
+                (&x).mod_multiplicative_order(&m).as_ref() == Some(&phi)
+            );
+            if is_root {
+                assert_eq!((&x).gcd(&m), 1u32);
+                assert!(x >= g);
+            }
+        });
+    }
+
+    unsigned_gen_var_1::<u32>().test_properties(|m| {
+        assert_eq!(
+            Natural::from(m).primitive_root(),
+            m.primitive_root().map(Natural::from)
+        );
+    });
+
+    unsigned_pair_gen_var_16::<u32>().test_properties(|(x, m)| {
+        assert_eq!(
+            Natural::from(x).is_primitive_root(Natural::from(m)),
+            x.is_primitive_root(m)
+        );
+    });
+}
@@ -0,0 +1,134 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::assert_panic;
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Gcd, Lcm, ModPow, Parity};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor, Totient};
+use malachite_base::test_util::generators::common::{GenConfig, TINY_LIMIT};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_12};
+use malachite_nz::natural::Natural;
+use malachite_nz::natural::factorization::factor::FactorOptions;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_totient() {
+    let test = |n, phi, lambda| {
+        let n = Natural::from_str(n).unwrap();
+        let phi = Natural::from_str(phi).unwrap();
+        let lambda = Natural::from_str(lambda).unwrap();
+        assert_eq!(n.totient(), phi);
+        assert_eq!(n.carmichael_lambda(), lambda);
+        let factors = n.factor();
+        assert_eq!(factors.totient(), phi);
+        assert_eq!(factors.carmichael_lambda(), lambda);
+    };
+    // - small numbers
+    test("1", "1", "1");
+    test("2", "1", "1");
+    test("8", "4", "2");
+    test("561", "320", "80");
+    // - 2 ^ 128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        "340282366920938457758625757157511659520",
+        "664613997892457925309815931948264960",
+    );
+    // - 10 ^ 30
+    test(
+        "1000000000000000000000000000000",
+        "400000000000000000000000000000",
+        "50000000000000000000000000000",
+    );
+    // - 2 ^ 127 - 1 is prime
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105726",
+    );
+    // - (2 ^ 61 - 1) ^ 2
+    test(
+        "5316911983139663487003542222693990401",
+        "5316911983139663484697699213480296450",
+        "5316911983139663484697699213480296450",
+    );
+    // - 3 * 2 ^ 66
+    test(
+        "221360928884514619392",
+        "73786976294838206464",
+        "18446744073709551616",
+    );
+    // - 2 ^ 100 * 3 ^ 50
+    test(
+        "910043815000214977332758527534256632492715260325658624",
+        "303347938333404992444252842511418877497571753441886208",
+        "75836984583351248111063210627854719374392938360471552",
+    );
+}
+
+#[test]
+fn totient_fail() {
+    assert_panic!(Natural::ZERO.totient());
+    assert_panic!(Natural::ZERO.carmichael_lambda());
+    // - the factorization is incomplete
+    let n = Natural::from_str("1000000000000037").unwrap()
+        * Natural::from_str("1000000000000091").unwrap();
+    let mut options = FactorOptions::default();
+    options.set_max_digits(Some(20));
+    let factors = n.factor_with_options(&options);
+    assert_panic!(factors.totient());
+    assert_panic!(factors.carmichael_lambda());
+}
+
+#[test]
+fn totient_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    natural_gen_var_2().test_properties_with_config_and_limit(&config, TINY_LIMIT, |n| {
+        let phi = n.totient();
+        let lambda = n.carmichael_lambda();
+        assert!(phi <= n);
+        assert_ne!(lambda, 0u32);
+        assert!((&phi).divisible_by(&lambda));
+        let factors = n.factor();
+        assert_eq!(factors.totient(), phi);
+        assert_eq!(factors.carmichael_lambda(), lambda);
+        for a in [2u32, 3, 5, 7] {
+            let a = Natural::from(a);
+            if (&a).gcd(&n) == 1u32 && n != 1u32 {
+                assert_eq!((a % &n).mod_pow(&lambda, &n), 1u32);
+            }
+        }
+        if n > 2u32 {
+            assert!(phi.even());
+        } else {
+            assert_eq!(phi, Natural::ONE);
+        }
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(TINY_LIMIT, |n| {
+        assert_eq!(Natural::from(n).totient(), n.totient());
+        assert_eq!(Natural::from(n).carmichael_lambda(), n.carmichael_lambda());
+    });
+
+    unsigned_pair_gen_var_12::<u32, u32>().test_properties_with_limit(TINY_LIMIT, |(a, b)| {
+        if a == 0 || a.gcd(b) != 1 {
+            return;
+        }
+        let a = Natural::from(a);
+        let b = Natural::from(b);
+        let ab = &a * &b;
+        assert_eq!(ab.totient(), a.totient() * b.totient());
+        assert_eq!(
+            ab.carmichael_lambda(),
+            a.carmichael_lambda().lcm(b.carmichael_lambda())
+        );
+    });
+}
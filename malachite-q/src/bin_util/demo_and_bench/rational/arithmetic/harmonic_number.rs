@@ -6,17 +6,24 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
-use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::bucketers::{pair_1_bucketer, unsigned_direct_bucketer};
 use malachite_base::test_util::bench::{BenchmarkType, run_benchmark};
 use malachite_base::test_util::generators::common::{GenConfig, GenMode};
-use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_28};
 use malachite_base::test_util::runner::Runner;
 use malachite_q::Rational;
-use malachite_q::test_util::rational::arithmetic::harmonic_number::harmonic_number_naive;
+use malachite_q::test_util::rational::arithmetic::harmonic_number::{
+    harmonic_number_naive, harmonic_number_of_order_naive,
+};
 
 pub(crate) fn register(runner: &mut Runner) {
     register_demo!(runner, demo_rational_harmonic_number);
+    register_demo!(runner, demo_rational_harmonic_number_of_order);
     register_bench!(runner, benchmark_rational_harmonic_number_algorithms);
+    register_bench!(
+        runner,
+        benchmark_rational_harmonic_number_of_order_algorithms
+    );
 }
 
 fn demo_rational_harmonic_number(gm: GenMode, config: &GenConfig, limit: usize) {
@@ -29,6 +36,17 @@ fn demo_rational_harmonic_number(gm: GenMode, config: &GenConfig, limit: usize)
     }
 }
 
+fn demo_rational_harmonic_number_of_order(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, k) in unsigned_pair_gen_var_28().get(gm, config).take(limit) {
+        println!(
+            "harmonic_number_of_order({}, {}) = {}",
+            n,
+            k,
+            Rational::harmonic_number_of_order(n, k)
+        );
+    }
+}
+
 fn benchmark_rational_harmonic_number_algorithms(
     gm: GenMode,
     config: &GenConfig,
@@ -53,3 +71,28 @@ fn benchmark_rational_harmonic_number_algorithms(
         ],
     );
 }
+
+fn benchmark_rational_harmonic_number_of_order_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational::harmonic_number_of_order(u64, u64)",
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen_var_28().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [
+            ("default", &mut |(n, k)| {
+                no_out!(Rational::harmonic_number_of_order(n, k));
+            }),
+            ("naive", &mut |(n, k)| {
+                no_out!(harmonic_number_of_order_naive(n, k));
+            }),
+        ],
+    );
+}
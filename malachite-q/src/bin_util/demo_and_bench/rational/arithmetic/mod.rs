@@ -15,6 +15,7 @@ pub(crate) fn register(runner: &mut Runner) {
     add_mul::register(runner);
     approximate::register(runner);
     average::register(runner);
+    bernoulli_number::register(runner);
     ceiling::register(runner);
     denominators_in_closed_interval::register(runner);
     div::register(runner);
@@ -58,6 +59,7 @@ mod add;
 mod add_mul;
 mod approximate;
 mod average;
+mod bernoulli_number;
 mod ceiling;
 mod dedekind_sum;
 mod denominators_in_closed_interval;
@@ -0,0 +1,145 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{AddMulAssign, Parity, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+// The tangent numbers T_1 through T_len, stored at indices 0 through len - 1, computed in place by
+// the integer-only algorithm of Brent and Harvey, "Fast computation of Bernoulli, Tangent and
+// Secant numbers" (2011). The table starts out as (k - 1)!; pass k then applies one step of the
+// boustrophedon transform to the entries from k on, after which T_k is final.
+fn tangent_numbers(len: usize) -> Vec<Natural> {
+    let mut t = Vec::with_capacity(len);
+    if len == 0 {
+        return t;
+    }
+    t.push(Natural::ONE);
+    for k in 1..len {
+        let next = &t[k - 1] * Natural::from(k);
+        t.push(next);
+    }
+    for k in 2..=len {
+        for j in k..=len {
+            let (lo, hi) = t.split_at_mut(j - 1);
+            let x = &mut hi[0];
+            *x *= Natural::from(j - k + 2);
+            x.add_mul_assign(&lo[j - 2], Natural::from(j - k));
+        }
+    }
+    t
+}
+
+// B_{2k} = (-1)^(k - 1) 2k T_k / (2^(2k) (2^(2k) - 1)), for k >= 1.
+fn bernoulli_from_tangent(k: u64, t: Natural) -> Rational {
+    let two_k = k << 1;
+    let power = Natural::power_of_2(two_k);
+    let denominator = (&power - Natural::ONE) * power;
+    Rational::from_sign_and_naturals(k.odd(), t * Natural::from(two_k), denominator)
+}
+
+/// Computes the first `len` Bernoulli numbers: $B_0$ through $B_{\mathrm{len} - 1}$.
+///
+/// The Bernoulli numbers are the coefficients of the Taylor series
+/// $$
+/// \frac{x}{e^x - 1} = \sum_{n=0}^\infty \frac{B_n}{n!} x^n,
+/// $$
+/// so that $B_1 = -1/2$ and $B_n = 0$ for every other odd $n$. The even-index values are obtained
+/// from the tangent numbers, which are computed together with an integer-only recurrence; each
+/// value then costs one reduction to lowest terms. This is much faster than computing the values
+/// one at a time.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n^3 \log n)$
+///
+/// $M(n) = O(n^2 \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `len`.
+///
+/// # Examples
+/// ```
+/// use malachite_q::rational::arithmetic::bernoulli_number::bernoulli_numbers_prefix;
+///
+/// assert_eq!(
+///     bernoulli_numbers_prefix(11)
+///         .iter()
+///         .map(|b| b.to_string())
+///         .collect::<Vec<_>>(),
+///     ["1", "-1/2", "1/6", "0", "-1/30", "0", "1/42", "0", "-1/30", "0", "5/66"]
+/// );
+/// assert!(bernoulli_numbers_prefix(0).is_empty());
+/// ```
+pub fn bernoulli_numbers_prefix(len: u64) -> Vec<Rational> {
+    let len = usize::exact_from(len);
+    let mut out = Vec::with_capacity(len);
+    if len == 0 {
+        return out;
+    }
+    out.push(Rational::ONE);
+    if len == 1 {
+        return out;
+    }
+    out.push(Rational::from_signeds(-1i8, 2));
+    for (k, t) in (1..).zip(tangent_numbers((len - 1) >> 1)) {
+        out.push(bernoulli_from_tangent(k, t));
+        if out.len() < len {
+            out.push(Rational::ZERO);
+        }
+    }
+    out
+}
+
+impl Rational {
+    /// Computes the $n$th Bernoulli number $B_n$.
+    ///
+    /// The Bernoulli numbers are the coefficients of the Taylor series
+    /// $$
+    /// \frac{x}{e^x - 1} = \sum_{n=0}^\infty \frac{B_n}{n!} x^n,
+    /// $$
+    /// so that $B_1 = -1/2$ and $B_n = 0$ for every other odd $n$. The nonzero values alternate in
+    /// sign, and the numerator of $B_n$ has $\Theta(n \log n)$ bits.
+    ///
+    /// If you need several Bernoulli numbers, [`bernoulli_numbers_prefix`] computes all of $B_0$
+    /// through $B_n$ for about the cost of $B_n$ alone.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::bernoulli_number(0), 1);
+    /// assert_eq!(Rational::bernoulli_number(1).to_string(), "-1/2");
+    /// assert_eq!(Rational::bernoulli_number(3), 0);
+    /// assert_eq!(Rational::bernoulli_number(12).to_string(), "-691/2730");
+    /// assert_eq!(
+    ///     Rational::bernoulli_number(50).to_string(),
+    ///     "495057205241079648212477525/66"
+    /// );
+    /// ```
+    pub fn bernoulli_number(n: u64) -> Self {
+        match n {
+            0 => Self::ONE,
+            1 => Self::from_signeds(-1i8, 2),
+            n if n.odd() => Self::ZERO,
+            n => {
+                let k = n >> 1;
+                let t = tangent_numbers(usize::exact_from(k)).pop().unwrap();
+                bernoulli_from_tangent(k, t)
+            }
+        }
+    }
+}
@@ -11,7 +11,7 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::Rational;
-use malachite_base::num::arithmetic::traits::{AddMulAssign, Parity, PowerOf2};
+use malachite_base::num::arithmetic::traits::{AddMulAssign, Parity, Pow, PowerOf2};
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{SplitInHalf, WrappingFrom};
 use malachite_base::num::logic::traits::LowMask;
@@ -144,6 +144,30 @@ fn harmonic_odd(a: u64, b: u64, n: u64, mut d: u32) -> (Natural, Natural) {
     (big_p, big_q)
 }
 
+// The sum of 1/j^k for j in [a, b] as an unreduced fraction. Short ranges are summed directly;
+// longer ones are split in half and merged, so that the operands of every multiplication stay
+// balanced and the single reduction happens at the top.
+fn harmonic_of_order_sum(a: u64, b: u64, k: u64) -> (Natural, Natural) {
+    if b - a >= HARMONIC_DIRECT_THRESHOLD {
+        let m = a + ((b - a) >> 1);
+        let (mut p, mut q) = harmonic_of_order_sum(a, m, k);
+        let (r, s) = harmonic_of_order_sum(m + 1, b, k);
+        p *= &s;
+        p.add_mul_assign(&q, r);
+        q *= s;
+        return (p, q);
+    }
+    let mut p = Natural::ZERO;
+    let mut q = Natural::ONE;
+    for j in a..=b {
+        let d = Natural::from(j).pow(k);
+        p *= &d;
+        p += &q;
+        q *= d;
+    }
+    (p, q)
+}
+
 impl Rational {
     /// Computes the $n$th harmonic number:
     ///
@@ -194,4 +218,54 @@ impl Rational {
             Self::from_naturals(p, q)
         }
     }
+
+    /// Computes the $n$th generalized harmonic number of order $k$:
+    ///
+    /// $$
+    /// H_n^{(k)} = \sum_{j=1}^n \frac{1}{j^k}.
+    /// $$
+    ///
+    /// $H_n^{(0)} = n$, $H_n^{(1)}$ is the ordinary harmonic number $H_n$, and for $k \geq 2$,
+    /// $H_n^{(k)}$ approaches $\zeta(k)$ as $n$ grows. $H_0^{(k)}$ is the empty sum, 0.
+    ///
+    /// The terms are summed by binary splitting, with a single reduction to lowest terms at the
+    /// end.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(nk (\log nk)^3 \log\log nk)$
+    ///
+    /// $M(n, k) = O(nk \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+    ///
+    /// # Panics
+    /// Panics if $k = 1$ and $n \geq 2^{63}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::harmonic_number_of_order(5, 0), 5);
+    /// assert_eq!(Rational::harmonic_number_of_order(4, 1).to_string(), "25/12");
+    /// assert_eq!(
+    ///     Rational::harmonic_number_of_order(4, 2).to_string(),
+    ///     "205/144"
+    /// );
+    /// assert_eq!(
+    ///     Rational::harmonic_number_of_order(10, 3).to_string(),
+    ///     "19164113947/16003008000"
+    /// );
+    /// assert_eq!(Rational::harmonic_number_of_order(0, 5), 0);
+    /// ```
+    pub fn harmonic_number_of_order(n: u64, k: u64) -> Self {
+        match (n, k) {
+            (_, 0) => Self::from(n),
+            (_, 1) => Self::harmonic_number(n),
+            (0, _) => Self::ZERO,
+            _ => {
+                let (p, q) = harmonic_of_order_sum(1, n, k);
+                Self::from_naturals(p, q)
+            }
+        }
+    }
 }
@@ -26,6 +26,10 @@ pub mod approximate;
 /// [`AverageAssign`](malachite_base::num::arithmetic::traits::AverageAssign), traits for computing
 /// the average (arithmetic mean) of two numbers.
 pub mod average;
+/// [`Rational::bernoulli_number`](super::Rational::bernoulli_number) and
+/// [`bernoulli_numbers_prefix`](bernoulli_number::bernoulli_numbers_prefix), functions for
+/// computing Bernoulli numbers.
+pub mod bernoulli_number;
 /// Implementations of [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) and
 /// [`CeilingAssign`](malachite_base::num::arithmetic::traits::CeilingAssign), traits for taking the
 /// ceiling of a number.
@@ -60,7 +64,7 @@ pub mod floor;
 /// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
 /// (greatest common divisor) of two numbers.
 pub mod gcd;
-/// A function for computing harmonic numbers.
+/// Functions for computing harmonic numbers and generalized harmonic numbers.
 pub mod harmonic_number;
 /// An implementation of the height of a [`Rational`](crate::Rational): the larger of the absolute
 /// value of its numerator and its denominator.
@@ -41,6 +41,21 @@ impl RoundToMultiple<Self> for Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -137,6 +152,21 @@ impl RoundToMultiple<&Self> for Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -233,6 +263,21 @@ impl RoundToMultiple<Rational> for &Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -295,7 +340,11 @@ impl RoundToMultiple<Rational> for &Rational {
             return (self.clone(), Equal);
         }
         if other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 return (Rational::ZERO, if *self >= 0u32 { Less } else { Greater });
             }
             panic!("Cannot round {self} to zero using RoundingMode {rm}");
@@ -338,6 +387,21 @@ impl RoundToMultiple<&Rational> for &Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) =
+    ///     \operatorname{sgn}(q) |y| \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{Odd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is even.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -397,7 +461,11 @@ impl RoundToMultiple<&Rational> for &Rational {
             return (self.clone(), Equal);
         }
         if *other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 return (Rational::ZERO, if *self >= 0 { Less } else { Greater });
             }
             panic!("Cannot round {self} to zero using RoundingMode {rm}");
@@ -476,7 +544,11 @@ impl RoundToMultipleAssign<Self> for Rational {
             return Equal;
         }
         if other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 let o = if *self >= 0 { Less } else { Greater };
                 *self = Self::ZERO;
                 return o;
@@ -554,7 +626,11 @@ impl RoundToMultipleAssign<&Self> for Rational {
             return Equal;
         }
         if *other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 let o = if *self >= 0u32 { Less } else { Greater };
                 *self = Self::ZERO;
                 return o;
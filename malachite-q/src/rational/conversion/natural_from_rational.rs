@@ -184,7 +184,7 @@ impl RoundingFrom<Rational> for Natural {
     fn rounding_from(x: Rational, rm: RoundingMode) -> (Self, Ordering) {
         if x.sign {
             x.numerator.div_round(x.denominator, rm)
-        } else if rm == Down || rm == Ceiling || rm == Nearest {
+        } else if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
             (Self::ZERO, Greater)
         } else {
             panic!("Cannot round negative Rational to Natural using RoundingMode {rm}");
@@ -261,7 +261,7 @@ impl RoundingFrom<&Rational> for Natural {
     fn rounding_from(x: &Rational, rm: RoundingMode) -> (Self, Ordering) {
         if x.sign {
             (&x.numerator).div_round(&x.denominator, rm)
-        } else if rm == Down || rm == Ceiling || rm == Nearest {
+        } else if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
             (Self::ZERO, Greater)
         } else {
             panic!("Cannot round negative Rational to Natural using RoundingMode {rm}");
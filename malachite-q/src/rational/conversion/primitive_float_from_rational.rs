@@ -63,6 +63,17 @@ macro_rules! float_impls {
             ///   If the [`Rational`] is closer to zero than to any other float (or if there is a
             ///   tie between zero and another float), then positive or negative zero is returned,
             ///   depending on the [`Rational`]'s sign.
+            /// - If the rounding mode is `NearestTiesAway`, then the rounding proceeds as with
+            ///   `Nearest`, except that if the [`Rational`] is exactly between two floats, the one
+            ///   with the larger absolute value is selected. In particular, a tie between zero and
+            ///   another float is resolved in favor of the nonzero float.
+            /// - If the rounding mode is `Odd`, then an exactly representable [`Rational`] is
+            ///   returned unchanged; otherwise, of the two floats surrounding the [`Rational`], the
+            ///   one with the odd least-significant bit in its representation is selected. If the
+            ///   [`Rational`] is greater than the maximum finite float, then the maximum finite
+            ///   float is returned, and if it is smaller than the minimum finite float, then the
+            ///   minimum finite float is returned. Zero is only returned when the [`Rational`] is
+            ///   zero.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -90,7 +101,7 @@ macro_rules! float_impls {
                             Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            Floor | Down => ($f::MAX_FINITE, Less),
+                            Floor | Down | Odd => ($f::MAX_FINITE, Less),
                             _ => ($f::INFINITY, Greater),
                         }
                     } else if exponent >= $f::MIN_NORMAL_EXPONENT {
@@ -113,7 +124,7 @@ macro_rules! float_impls {
                                 Exact => {
                                     panic!("Value cannot be represented exactly as a float")
                                 }
-                                Floor | Down => ($f::MAX_FINITE, Less),
+                                Floor | Down | Odd => ($f::MAX_FINITE, Less),
                                 _ => ($f::INFINITY, Greater),
                             }
                         } else {
@@ -148,9 +159,9 @@ macro_rules! float_impls {
                                 panic!("Value cannot be represented exactly as a float")
                             }
                             Floor | Down => (0.0, Less),
-                            Nearest => {
+                            Nearest | NearestTiesAway => {
                                 if exponent == $f::MIN_EXPONENT - 1
-                                    && !abs_is_neg_power_of_2(&value)
+                                    && (rm == NearestTiesAway || !abs_is_neg_power_of_2(&value))
                                 {
                                     ($f::MIN_POSITIVE_SUBNORMAL, Greater)
                                 } else {
@@ -289,7 +300,7 @@ macro_rules! float_impls {
                             Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            Floor | Down => ($f::MAX_FINITE, Less),
+                            Floor | Down | Odd => ($f::MAX_FINITE, Less),
                             _ => ($f::INFINITY, Greater),
                         }
                     } else if exponent >= $f::MIN_NORMAL_EXPONENT {
@@ -312,7 +323,7 @@ macro_rules! float_impls {
                                 Exact => {
                                     panic!("Value cannot be represented exactly as a float")
                                 }
-                                Floor | Down => ($f::MAX_FINITE, Less),
+                                Floor | Down | Odd => ($f::MAX_FINITE, Less),
                                 _ => ($f::INFINITY, Greater),
                             }
                         } else {
@@ -347,9 +358,9 @@ macro_rules! float_impls {
                                 panic!("Value cannot be represented exactly as a float")
                             }
                             Floor | Down => (0.0, Less),
-                            Nearest => {
+                            Nearest | NearestTiesAway => {
                                 if exponent == $f::MIN_EXPONENT - 1
-                                    && !abs_is_neg_power_of_2(&value)
+                                    && (rm == NearestTiesAway || !abs_is_neg_power_of_2(&value))
                                 {
                                     ($f::MIN_POSITIVE_SUBNORMAL, Greater)
                                 } else {
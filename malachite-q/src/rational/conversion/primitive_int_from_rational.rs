@@ -46,7 +46,7 @@ fn rounding_from_unsigned<'a, T: for<'b> TryFrom<&'b Natural> + Max + Named + Ze
         let (n, o) = (&x.numerator).div_round(&x.denominator, rm);
         let out = if let Ok(q) = T::try_from(&n) {
             (q, o)
-        } else if rm == Down || rm == Floor || rm == Nearest {
+        } else if rm == Down || rm == Floor || rm == Nearest || rm == NearestTiesAway || rm == Odd {
             (T::MAX, Less)
         } else {
             panic!(
@@ -56,7 +56,7 @@ fn rounding_from_unsigned<'a, T: for<'b> TryFrom<&'b Natural> + Max + Named + Ze
             );
         };
         out
-    } else if rm == Down || rm == Ceiling || rm == Nearest {
+    } else if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
         (T::ZERO, Greater)
     } else {
         panic!(
@@ -141,7 +141,7 @@ where
 {
     let (i, o) = Integer::rounding_from(x, rm);
     if i > T::MAX {
-        if rm == Down || rm == Floor || rm == Nearest {
+        if rm == Down || rm == Floor || rm == Nearest || rm == NearestTiesAway || rm == Odd {
             (T::MAX, Less)
         } else {
             panic!(
@@ -151,7 +151,7 @@ where
             );
         }
     } else if i < T::MIN {
-        if rm == Down || rm == Ceiling || rm == Nearest {
+        if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
             (T::MIN, Greater)
         } else {
             panic!(
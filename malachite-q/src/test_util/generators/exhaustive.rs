@@ -551,7 +551,10 @@ pub(crate) fn round_to_multiple_rational_filter(t: &(Rational, Rational, Roundin
     if x == y {
         true
     } else if *y == 0u32 {
-        rm == Down || rm == (if *x >= 0 { Floor } else { Ceiling }) || rm == Nearest
+        rm == Down
+            || rm == (if *x >= 0 { Floor } else { Ceiling })
+            || rm == Nearest
+            || rm == NearestTiesAway
     } else {
         rm != Exact || (x / y).is_integer()
     }
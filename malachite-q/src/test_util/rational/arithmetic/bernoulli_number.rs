@@ -0,0 +1,34 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::num::basic::traits::One;
+use malachite_nz::natural::Natural;
+
+// The first len Bernoulli numbers from the defining identity sum_{j=0}^{n} C(n + 1, j) B_j = 0 for
+// n > 0, the reference the fast implementation is checked against.
+pub fn bernoulli_numbers_prefix_naive(len: u64) -> Vec<Rational> {
+    let mut bs: Vec<Rational> = Vec::new();
+    for n in 0..len {
+        let b = if n == 0 {
+            Rational::ONE
+        } else {
+            let m = Natural::from(n + 1);
+            -(0..n)
+                .map(|j| {
+                    Rational::from(Natural::binomial_coefficient(m.clone(), Natural::from(j)))
+                        * &bs[usize::try_from(j).unwrap()]
+                })
+                .sum::<Rational>()
+                / Rational::from(n + 1)
+        };
+        bs.push(b);
+    }
+    bs
+}
@@ -7,7 +7,9 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Pow, Reciprocal};
 use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
 
 // Term-by-term summation, the reference the fast implementation is checked against.
 pub fn harmonic_number_naive(n: u64) -> Rational {
@@ -17,3 +19,12 @@ pub fn harmonic_number_naive(n: u64) -> Rational {
     }
     sum
 }
+
+// Term-by-term summation of 1/j^k, the reference the fast implementation is checked against.
+pub fn harmonic_number_of_order_naive(n: u64, k: u64) -> Rational {
+    let mut sum = Rational::ZERO;
+    for j in 1..=n {
+        sum += Rational::from(Natural::from(j).pow(k)).reciprocal();
+    }
+    sum
+}
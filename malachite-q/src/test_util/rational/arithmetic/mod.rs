@@ -9,6 +9,7 @@
 pub mod add;
 pub mod add_mul;
 pub mod approximate;
+pub mod bernoulli_number;
 pub mod dedekind_sum;
 pub mod div;
 pub mod harmonic_number;
@@ -73,6 +73,7 @@ pub mod rational {
         pub mod add_mul;
         pub mod approximate;
         pub mod average;
+        pub mod bernoulli_number;
         pub mod ceiling;
         pub mod cfrac_helpers;
         pub mod dedekind_sum;
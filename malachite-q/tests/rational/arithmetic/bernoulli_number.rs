@@ -0,0 +1,112 @@
+// Copyright © 2026 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Abs, Parity};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use malachite_q::rational::arithmetic::bernoulli_number::bernoulli_numbers_prefix;
+use malachite_q::test_util::rational::arithmetic::bernoulli_number::bernoulli_numbers_prefix_naive;
+
+#[test]
+fn test_bernoulli_number() {
+    let test = |n, out| {
+        assert_eq!(Rational::bernoulli_number(n).to_string(), out);
+    };
+    test(0, "1");
+    test(1, "-1/2");
+    test(2, "1/6");
+    test(3, "0");
+    test(4, "-1/30");
+    test(6, "1/42");
+    test(12, "-691/2730");
+    test(101, "0");
+    test(
+        100,
+        "-94598037819122125295227433069493721872702841533066936133385696204311395415197247711/\
+        33330",
+    );
+}
+
+#[test]
+fn test_bernoulli_numbers_prefix() {
+    let test = |len, out: &[&str]| {
+        assert_eq!(
+            bernoulli_numbers_prefix(len)
+                .iter()
+                .map(Rational::to_string)
+                .collect::<Vec<_>>(),
+            out
+        );
+    };
+    test(0, &[]);
+    test(1, &["1"]);
+    test(2, &["1", "-1/2"]);
+    test(3, &["1", "-1/2", "1/6"]);
+    test(4, &["1", "-1/2", "1/6", "0"]);
+    test(
+        15,
+        &[
+            "1",
+            "-1/2",
+            "1/6",
+            "0",
+            "-1/30",
+            "0",
+            "1/42",
+            "0",
+            "-1/30",
+            "0",
+            "5/66",
+            "0",
+            "-691/2730",
+            "0",
+            "7/6",
+        ],
+    );
+    assert_eq!(
+        bernoulli_numbers_prefix(60),
+        bernoulli_numbers_prefix_naive(60)
+    );
+}
+
+#[test]
+fn bernoulli_number_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let b = Rational::bernoulli_number(n);
+        assert!(b.is_valid());
+        let prefix = bernoulli_numbers_prefix(n + 1);
+        assert_eq!(prefix.len(), usize::try_from(n + 1).unwrap());
+        assert_eq!(prefix.last(), Some(&b));
+        assert_eq!(b == 0u32, n.odd() && n != 1);
+        if n.even() && n != 0 {
+            // the signs alternate
+            assert_eq!(b > 0u32, (n >> 1).odd());
+            // von Staudt–Clausen: the denominator is the product of the primes p with (p - 1) | n
+            let mut d = Natural::from(1u32);
+            for p in 2..=n + 1 {
+                if (2..p).take_while(|q| q * q <= p).all(|q| p % q != 0) && n % (p - 1) == 0 {
+                    d *= Natural::from(p);
+                }
+            }
+            assert_eq!(*b.denominator_ref(), d);
+            // |B_n| grows roughly like 2 n! / (2 pi)^n, so it eventually exceeds 1
+            if n >= 14 {
+                assert!(b.abs() > 1u32);
+            }
+        }
+    });
+
+    for n in 0..40 {
+        let b = bernoulli_numbers_prefix_naive(n + 1).pop().unwrap();
+        assert_eq!(Rational::bernoulli_number(n), b);
+    }
+    assert_eq!(Rational::bernoulli_number(u64::MAX), Rational::ZERO);
+}
@@ -6,10 +6,15 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use malachite_base::num::arithmetic::traits::{Pow, Reciprocal};
 use malachite_base::num::basic::traits::One;
-use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_28};
+use malachite_nz::natural::Natural;
 use malachite_q::Rational;
-use malachite_q::test_util::rational::arithmetic::harmonic_number::harmonic_number_naive;
+use malachite_q::test_util::rational::arithmetic::harmonic_number::{
+    harmonic_number_naive, harmonic_number_of_order_naive,
+};
 
 #[test]
 fn test_harmonic_number() {
@@ -96,3 +101,56 @@ fn harmonic_number_properties() {
         }
     });
 }
+
+#[test]
+fn test_harmonic_number_of_order() {
+    let test = |n, k, out| {
+        assert_eq!(Rational::harmonic_number_of_order(n, k).to_string(), out);
+    };
+    // - k = 0 counts the terms, and k = 1 is the ordinary harmonic number
+    test(0, 0, "0");
+    test(7, 0, "7");
+    test(4, 1, "25/12");
+    test(0, 3, "0");
+    test(1, 3, "1");
+    test(4, 2, "205/144");
+    test(
+        20,
+        5,
+        "1890436618011579623252753178278440286521/1823115626175044929657606134530546073600",
+    );
+    // - a value long enough to be split
+    test(
+        100,
+        2,
+        "1589508694133037873112297928517553859702383498543709859889432834803818131090369901/\
+        972186144434381030589657976672623144161975583995746241782720354705517986165248000",
+    );
+}
+
+#[test]
+fn harmonic_number_of_order_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties_with_limit(TINY_LIMIT, |(n, k)| {
+        let h = Rational::harmonic_number_of_order(n, k);
+        assert!(h.is_valid());
+        assert_eq!(h, harmonic_number_of_order_naive(n, k));
+        if n != 0 {
+            // the defining recurrence
+            assert_eq!(
+                &h - Rational::harmonic_number_of_order(n - 1, k),
+                Rational::from(Natural::from(n).pow(k)).reciprocal()
+            );
+            // the terms decrease as the order grows
+            assert!(Rational::harmonic_number_of_order(n, k + 1) <= h);
+        }
+    });
+
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        assert_eq!(Rational::harmonic_number_of_order(n, 0), n);
+        assert_eq!(
+            Rational::harmonic_number_of_order(n, 1),
+            Rational::harmonic_number(n)
+        );
+    });
+}
@@ -676,7 +676,7 @@ fn round_to_multiple_properties() {
                 Down => assert!(r.le_abs(&x)),
                 Up => assert!(r.ge_abs(&x)),
                 Exact => assert_eq!(r, x),
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     let closest;
                     let second_closest;
                     if r <= x {
@@ -684,11 +684,20 @@ fn round_to_multiple_properties() {
                         second_closest = &r + (&y).abs() - &x;
                     } else {
                         closest = &r - &x;
-                        second_closest = x + (&y).abs() - &r;
+                        second_closest = &x + (&y).abs() - &r;
                     }
                     assert!(closest <= second_closest);
                     if closest == second_closest {
-                        assert!(Integer::exact_from(r / y).even());
+                        if rm == Nearest {
+                            assert!(Integer::exact_from(r / y).even());
+                        } else {
+                            assert!(r.gt_abs(&x));
+                        }
+                    }
+                }
+                Odd => {
+                    if r != x {
+                        assert!(Integer::exact_from(r / y).odd());
                     }
                 }
             }
@@ -345,20 +345,29 @@ fn round_to_multiple_of_power_of_2_properties() {
             Down => assert!(r.le_abs(&n)),
             Up => assert!(r.ge_abs(&n)),
             Exact => assert_eq!(r, n),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 let k = Rational::power_of_2(pow);
                 let closest;
                 let second_closest;
                 if r <= n {
                     closest = &n - &r;
-                    second_closest = &r + k - n;
+                    second_closest = &r + k - &n;
                 } else {
                     closest = &r - &n;
-                    second_closest = n + k - &r;
+                    second_closest = &n + k - &r;
                 }
                 assert!(closest <= second_closest);
                 if closest == second_closest {
-                    assert!(Integer::exact_from(r >> pow).even());
+                    if rm == Nearest {
+                        assert!(Integer::exact_from(r >> pow).even());
+                    } else {
+                        assert!(r.gt_abs(&n));
+                    }
+                }
+            }
+            Odd => {
+                if r != n {
+                    assert!(Integer::exact_from(r >> pow).odd());
                 }
             }
         }
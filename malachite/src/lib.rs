@@ -22,6 +22,9 @@
 //! - `Float`, arbitrary-precision floating-point numbers, is re-exported from
 //!   [`malachite-float`](https://docs.rs/malachite-float). (Requires the `floats` feature; floats
 //!   are not yet feature-complete.)
+//! - `Complex`, arbitrary-precision complex numbers, is re-exported from
+//!   [`malachite-complex`](https://docs.rs/malachite-complex). (Requires the `complexes` feature;
+//!   complex numbers are not yet feature-complete.)
 //! - The [`base`] module re-exports [`malachite-base`](https://docs.rs/malachite-base), which
 //!   provides the numeric traits (and their implementations for primitive types) used throughout
 //!   Malachite, along with tools for generating values for tests and benchmarks.
@@ -66,6 +69,10 @@ pub mod base {
 #[cfg(feature = "floats")]
 pub use malachite_float::Float;
 #[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "complexes")]
+pub use malachite_complex::Complex;
+#[cfg(feature = "naturals_and_integers")]
 pub use malachite_nz::integer::Integer;
 #[cfg(feature = "naturals_and_integers")]
 pub use malachite_nz::natural::Natural;